@@ -0,0 +1,184 @@
+//! Throughput of the blocking and `diesel-async` message repositories under
+//! concurrent load.
+//!
+//! Each iteration runs one task per concurrency slot. Every task appends a
+//! message to its own conversation and reads it back by ID, so the tasks
+//! contend for pool connections and runtime threads but not for rows.
+//!
+//! Set `CORBUSIER_BENCH_DATABASE_URL` to a `PostgreSQL` database the
+//! benchmark may write to. An empty database is migrated first. Without the
+//! variable the benchmark does nothing.
+//!
+//! ```text
+//! CORBUSIER_BENCH_DATABASE_URL=postgres://localhost/corbusier_bench \
+//!     cargo bench --features async-postgres --bench message_repository_throughput
+//! ```
+
+#[path = "../tests/postgres/migrations.rs"]
+mod migrations;
+
+use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+use corbusier::message::{
+    adapters::postgres::{
+        AsyncPostgresMessageRepository, PostgresConversationRepository, PostgresMessageRepository,
+    },
+    domain::{ContentPart, Conversation, ConversationId, Message, Role, SequenceNumber, TextPart},
+    ports::{ConversationRepository, MessageRepository},
+};
+use criterion::{BenchmarkId, Criterion, Throughput};
+use diesel::connection::SimpleConnection;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::{Connection, PgConnection, RunQueryDsl};
+use diesel_async::AsyncPgConnection;
+use diesel_async::pooled_connection::{AsyncDieselConnectionManager, deadpool};
+use futures::future::try_join_all;
+use mockable::DefaultClock;
+use tokio::runtime::Runtime;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Connections in each adapter's pool.
+const POOL_SIZE: usize = 16;
+
+/// Concurrent tasks per iteration.
+const CONCURRENCY: [usize; 3] = [1, 16, 64];
+
+/// Runtime worker threads, so both adapters compete for the same executor.
+const WORKER_THREADS: usize = 4;
+
+struct Fixture {
+    ctx: RequestContext,
+    conversations: Vec<ConversationId>,
+    blocking: PostgresMessageRepository,
+    pooled_async: AsyncPostgresMessageRepository,
+}
+
+fn migrate(url: &str) -> Result<(), BoxError> {
+    let mut conn = PgConnection::establish(url)?;
+    let migrated: bool = diesel::select(diesel::dsl::sql::<diesel::sql_types::Bool>(
+        "to_regclass('public.messages') IS NOT NULL",
+    ))
+    .get_result(&mut conn)?;
+    if !migrated {
+        for (_, sql) in migrations::MIGRATIONS {
+            conn.batch_execute(sql)?;
+        }
+    }
+    Ok(())
+}
+
+async fn prepare(url: &str) -> Result<Fixture, BoxError> {
+    migrate(url)?;
+    let blocking_pool = Pool::builder()
+        .max_size(u32::try_from(POOL_SIZE)?)
+        .build(ConnectionManager::<PgConnection>::new(url))?;
+    let async_pool =
+        deadpool::Pool::builder(AsyncDieselConnectionManager::<AsyncPgConnection>::new(url))
+            .max_size(POOL_SIZE)
+            .build()?;
+    let ctx = RequestContext::new(
+        TenantId::new(),
+        CorrelationId::new(),
+        UserId::new(),
+        SessionId::new(),
+    );
+    let conversation_repository = PostgresConversationRepository::new(blocking_pool.clone());
+    let mut conversations = Vec::new();
+    for _ in 0..CONCURRENCY.into_iter().max().unwrap_or_default() {
+        let conversation = Conversation::new(&DefaultClock);
+        conversation_repository.store(&ctx, &conversation).await?;
+        conversations.push(conversation.id());
+    }
+    Ok(Fixture {
+        ctx,
+        conversations,
+        blocking: PostgresMessageRepository::new(blocking_pool),
+        pooled_async: AsyncPostgresMessageRepository::new(async_pool),
+    })
+}
+
+/// Appends a message to each of the first `concurrency` conversations and
+/// reads every message back, all at once.
+async fn store_and_find(
+    repository: &impl MessageRepository,
+    fixture: &Fixture,
+    concurrency: usize,
+) -> Result<(), BoxError> {
+    try_join_all(fixture.conversations.iter().take(concurrency).map(
+        |conversation_id| async move {
+            let message = Message::new(
+                *conversation_id,
+                Role::User,
+                vec![ContentPart::Text(TextPart::new("Deploy the fix"))],
+                SequenceNumber::new(1),
+                &DefaultClock,
+            )?;
+            let stored = repository.append(&fixture.ctx, &message).await?;
+            repository.find_by_id(&fixture.ctx, stored.id()).await?;
+            Ok::<_, BoxError>(())
+        },
+    ))
+    .await?;
+    Ok(())
+}
+
+#[expect(
+    clippy::expect_used,
+    reason = "a benchmark cannot report results once its database is unusable"
+)]
+fn concurrent_store_find(c: &mut Criterion) {
+    let Ok(url) = std::env::var("CORBUSIER_BENCH_DATABASE_URL") else {
+        return;
+    };
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(WORKER_THREADS)
+        .enable_all()
+        .build()
+        .expect("benchmark runtime");
+    let fixture = runtime.block_on(prepare(&url)).expect("benchmark database");
+
+    let mut group = c.benchmark_group("message_repository/concurrent_store_find");
+    for concurrency in CONCURRENCY {
+        group.throughput(Throughput::Elements(
+            u64::try_from(concurrency).unwrap_or(u64::MAX),
+        ));
+        bench_adapter(
+            &mut group,
+            &runtime,
+            BenchmarkId::new("r2d2_blocking", concurrency),
+            || store_and_find(&fixture.blocking, &fixture, concurrency),
+        );
+        bench_adapter(
+            &mut group,
+            &runtime,
+            BenchmarkId::new("deadpool_async", concurrency),
+            || store_and_find(&fixture.pooled_async, &fixture, concurrency),
+        );
+    }
+    group.finish();
+}
+
+#[expect(
+    clippy::expect_used,
+    reason = "a failed iteration would make the measurement meaningless"
+)]
+fn bench_adapter<F, Fut>(
+    group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
+    runtime: &Runtime,
+    id: BenchmarkId,
+    iteration: F,
+) where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<(), BoxError>>,
+{
+    group.bench_function(id, |b| {
+        b.to_async(runtime)
+            .iter(|| async { iteration().await.expect("store and find") });
+    });
+}
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+    concurrent_store_find(&mut criterion);
+    criterion.final_summary();
+}
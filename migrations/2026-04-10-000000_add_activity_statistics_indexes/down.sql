@@ -0,0 +1,3 @@
+-- Drop conversation activity statistics indexes.
+DROP INDEX IF EXISTS idx_handoffs_tenant_conversation_initiated_at;
+DROP INDEX IF EXISTS idx_messages_tenant_conversation_created_at;
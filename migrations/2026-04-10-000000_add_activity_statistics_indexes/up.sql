@@ -0,0 +1,7 @@
+-- Time-ordered indexes backing conversation activity statistics, so bucketed
+-- aggregation can range-scan one conversation instead of the whole table.
+CREATE INDEX idx_messages_tenant_conversation_created_at
+    ON messages (tenant_id, conversation_id, created_at);
+
+CREATE INDEX idx_handoffs_tenant_conversation_initiated_at
+    ON handoffs (tenant_id, conversation_id, initiated_at);
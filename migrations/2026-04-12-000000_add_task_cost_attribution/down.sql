@@ -0,0 +1,3 @@
+-- Drop task cost attribution tables.
+DROP TABLE IF EXISTS usage_records;
+DROP TABLE IF EXISTS task_conversation_links;
@@ -0,0 +1,48 @@
+-- Turn-level usage records and conversation-to-task attribution.
+--
+-- Usage is recorded per conversation; reports resolve the owning task through
+-- task_conversation_links so conversations may be linked before or after
+-- their turns are recorded. Handoff and delegation sub-conversations link to
+-- the parent task with their originating conversation for provenance.
+
+CREATE TABLE task_conversation_links (
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    conversation_id UUID NOT NULL,
+    task_id UUID NOT NULL,
+    link_kind VARCHAR(20) NOT NULL
+        CHECK (link_kind IN ('primary', 'handoff', 'delegation')),
+    parent_conversation_id UUID,
+    -- clock_timestamp() keeps links created in one transaction ordered.
+    linked_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
+    PRIMARY KEY (tenant_id, conversation_id),
+    CONSTRAINT task_conversation_links_task_fk
+        FOREIGN KEY (task_id, tenant_id)
+        REFERENCES tasks (id, tenant_id),
+    CONSTRAINT task_conversation_links_conversation_fk
+        FOREIGN KEY (conversation_id, tenant_id)
+        REFERENCES conversations (id, tenant_id),
+    CONSTRAINT task_conversation_links_parent_fk
+        FOREIGN KEY (parent_conversation_id, tenant_id)
+        REFERENCES conversations (id, tenant_id)
+);
+
+CREATE INDEX idx_task_conversation_links_tenant_task
+    ON task_conversation_links (tenant_id, task_id, linked_at);
+
+CREATE TABLE usage_records (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    conversation_id UUID NOT NULL,
+    turn_id UUID NOT NULL,
+    agent_backend VARCHAR(100) NOT NULL,
+    input_tokens BIGINT NOT NULL CHECK (input_tokens >= 0),
+    output_tokens BIGINT NOT NULL CHECK (output_tokens >= 0),
+    cost_micros BIGINT NOT NULL CHECK (cost_micros >= 0),
+    recorded_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT usage_records_conversation_fk
+        FOREIGN KEY (conversation_id, tenant_id)
+        REFERENCES conversations (id, tenant_id)
+);
+
+CREATE INDEX idx_usage_records_tenant_conversation
+    ON usage_records (tenant_id, conversation_id);
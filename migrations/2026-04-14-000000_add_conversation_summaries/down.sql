@@ -0,0 +1,9 @@
+-- Drop the conversation summary projection.
+DROP TRIGGER IF EXISTS task_conversation_links_summary_trigger ON task_conversation_links;
+DROP TRIGGER IF EXISTS messages_summary_trigger ON messages;
+DROP TRIGGER IF EXISTS conversations_summary_trigger ON conversations;
+DROP FUNCTION IF EXISTS project_task_link_summary();
+DROP FUNCTION IF EXISTS project_message_summary();
+DROP FUNCTION IF EXISTS project_conversation_summary();
+DROP FUNCTION IF EXISTS task_issue_labels(UUID);
+DROP TABLE IF EXISTS conversation_summaries;
@@ -0,0 +1,148 @@
+-- Conversation summary projection backing conversation listings.
+--
+-- One row per conversation, maintained by triggers on conversations,
+-- messages, and task_conversation_links so listings can filter, sort, and
+-- keyset-paginate without aggregating message histories.
+
+CREATE TABLE conversation_summaries (
+    conversation_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    state VARCHAR(50) NOT NULL,
+    task_id UUID,
+    labels TEXT[] NOT NULL DEFAULT '{}',
+    agent_backend VARCHAR(100),
+    message_count BIGINT NOT NULL DEFAULT 0 CHECK (message_count >= 0),
+    created_at TIMESTAMPTZ NOT NULL,
+    last_activity_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT conversation_summaries_conversation_fk
+        FOREIGN KEY (conversation_id, tenant_id)
+        REFERENCES conversations (id, tenant_id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_conversation_summaries_tenant_last_activity
+    ON conversation_summaries (tenant_id, last_activity_at, conversation_id);
+
+CREATE INDEX idx_conversation_summaries_tenant_created_at
+    ON conversation_summaries (tenant_id, created_at, conversation_id);
+
+CREATE INDEX idx_conversation_summaries_labels
+    ON conversation_summaries USING GIN (labels);
+
+-- Labels recorded on a task's issue origin.
+CREATE OR REPLACE FUNCTION task_issue_labels(p_task_id UUID)
+RETURNS TEXT[] AS $$
+    SELECT COALESCE(
+        ARRAY(
+            SELECT jsonb_array_elements_text(t.origin->'metadata'->'labels')
+            FROM tasks t
+            WHERE t.id = p_task_id
+              AND jsonb_typeof(t.origin->'metadata'->'labels') = 'array'
+        ),
+        '{}'
+    );
+$$ LANGUAGE sql STABLE;
+
+CREATE OR REPLACE FUNCTION project_conversation_summary()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO conversation_summaries (
+        conversation_id, tenant_id, state, task_id, labels, created_at, last_activity_at
+    ) VALUES (
+        NEW.id,
+        NEW.tenant_id,
+        NEW.state,
+        NEW.task_id,
+        CASE WHEN NEW.task_id IS NULL THEN '{}' ELSE task_issue_labels(NEW.task_id) END,
+        NEW.created_at,
+        NEW.updated_at
+    )
+    ON CONFLICT (conversation_id) DO UPDATE SET
+        state = EXCLUDED.state,
+        task_id = COALESCE(EXCLUDED.task_id, conversation_summaries.task_id),
+        labels = CASE
+            WHEN EXCLUDED.task_id IS NULL THEN conversation_summaries.labels
+            ELSE EXCLUDED.labels
+        END,
+        last_activity_at = GREATEST(
+            conversation_summaries.last_activity_at,
+            EXCLUDED.last_activity_at
+        );
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION project_message_summary()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE conversation_summaries
+    SET message_count = message_count + 1,
+        last_activity_at = GREATEST(last_activity_at, NEW.created_at),
+        agent_backend = COALESCE(NEW.metadata->>'agent_backend', agent_backend)
+    WHERE conversation_id = NEW.conversation_id
+      AND tenant_id = NEW.tenant_id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION project_task_link_summary()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE conversation_summaries
+    SET task_id = NEW.task_id,
+        labels = task_issue_labels(NEW.task_id)
+    WHERE conversation_id = NEW.conversation_id
+      AND tenant_id = NEW.tenant_id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER conversations_summary_trigger
+    AFTER INSERT OR UPDATE ON conversations
+    FOR EACH ROW
+    EXECUTE FUNCTION project_conversation_summary();
+
+CREATE TRIGGER messages_summary_trigger
+    AFTER INSERT ON messages
+    FOR EACH ROW
+    EXECUTE FUNCTION project_message_summary();
+
+CREATE TRIGGER task_conversation_links_summary_trigger
+    AFTER INSERT OR UPDATE ON task_conversation_links
+    FOR EACH ROW
+    EXECUTE FUNCTION project_task_link_summary();
+
+-- Backfill summaries for existing conversations.
+INSERT INTO conversation_summaries (
+    conversation_id, tenant_id, state, task_id, labels, agent_backend,
+    message_count, created_at, last_activity_at
+)
+SELECT
+    c.id,
+    c.tenant_id,
+    c.state,
+    COALESCE(l.task_id, c.task_id),
+    CASE
+        WHEN COALESCE(l.task_id, c.task_id) IS NULL THEN '{}'
+        ELSE task_issue_labels(COALESCE(l.task_id, c.task_id))
+    END,
+    (
+        SELECT m.metadata->>'agent_backend'
+        FROM messages m
+        WHERE m.tenant_id = c.tenant_id
+          AND m.conversation_id = c.id
+          AND m.metadata->>'agent_backend' IS NOT NULL
+        ORDER BY m.sequence_number DESC
+        LIMIT 1
+    ),
+    COALESCE(stats.message_count, 0),
+    c.created_at,
+    GREATEST(c.updated_at, COALESCE(stats.last_message_at, c.updated_at))
+FROM conversations c
+LEFT JOIN task_conversation_links l
+    ON l.tenant_id = c.tenant_id AND l.conversation_id = c.id
+LEFT JOIN LATERAL (
+    SELECT COUNT(*) AS message_count, MAX(m.created_at) AS last_message_at
+    FROM messages m
+    WHERE m.tenant_id = c.tenant_id AND m.conversation_id = c.id
+) stats ON TRUE;
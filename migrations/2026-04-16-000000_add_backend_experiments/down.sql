@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS experiment_observations;
+DROP TABLE IF EXISTS backend_experiments;
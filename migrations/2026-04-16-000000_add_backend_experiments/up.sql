@@ -0,0 +1,41 @@
+-- Backend A/B experiments and their outcome observations.
+--
+-- Arm assignment is derived by hashing the experiment and conversation
+-- identifiers, so assignments are not stored. Observations record turn
+-- outcomes, attributed cost, and human feedback per arm; the signal column
+-- holds the serialized ExperimentSignal.
+
+CREATE TABLE backend_experiments (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    name TEXT NOT NULL CHECK (btrim(name) <> ''),
+    control_arm JSONB NOT NULL,
+    treatment_arm JSONB NOT NULL,
+    treatment_percent SMALLINT NOT NULL
+        CHECK (treatment_percent BETWEEN 1 AND 99),
+    status VARCHAR(20) NOT NULL CHECK (status IN ('running', 'concluded')),
+    created_at TIMESTAMPTZ NOT NULL,
+    concluded_at TIMESTAMPTZ,
+    CONSTRAINT backend_experiments_id_tenant_unique UNIQUE (id, tenant_id)
+);
+
+CREATE INDEX idx_backend_experiments_tenant_running
+    ON backend_experiments (tenant_id, created_at)
+    WHERE status = 'running';
+
+CREATE TABLE experiment_observations (
+    id BIGSERIAL PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    experiment_id UUID NOT NULL,
+    arm VARCHAR(20) NOT NULL CHECK (arm IN ('control', 'treatment')),
+    conversation_id UUID NOT NULL,
+    signal JSONB NOT NULL,
+    observed_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT experiment_observations_experiment_fk
+        FOREIGN KEY (experiment_id, tenant_id)
+        REFERENCES backend_experiments (id, tenant_id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_experiment_observations_tenant_experiment
+    ON experiment_observations (tenant_id, experiment_id, observed_at);
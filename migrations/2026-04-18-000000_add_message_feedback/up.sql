@@ -0,0 +1,33 @@
+-- Human feedback on assistant messages.
+--
+-- Each reviewer holds one row per message; resubmitting replaces the rating
+-- in place. The agent backend and instruction-set version are copied from
+-- the rated message so summaries group without reading message metadata.
+
+CREATE TABLE message_feedback (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    conversation_id UUID NOT NULL,
+    message_id UUID NOT NULL,
+    author_id UUID NOT NULL,
+    rating VARCHAR(20) NOT NULL
+        CHECK (rating IN ('thumbs_up', 'thumbs_down', 'stars')),
+    stars SMALLINT CHECK (stars BETWEEN 1 AND 5),
+    comment TEXT,
+    category VARCHAR(20)
+        CHECK (category IN ('accuracy', 'helpfulness', 'safety', 'style', 'other')),
+    agent_backend VARCHAR(100),
+    instruction_set_version TEXT,
+    submitted_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT message_feedback_stars_match_rating
+        CHECK ((rating = 'stars') = (stars IS NOT NULL)),
+    CONSTRAINT message_feedback_author_unique
+        UNIQUE (tenant_id, message_id, author_id),
+    CONSTRAINT message_feedback_message_fk
+        FOREIGN KEY (message_id, tenant_id)
+        REFERENCES messages (id, tenant_id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_message_feedback_tenant_backend
+    ON message_feedback (tenant_id, agent_backend, instruction_set_version);
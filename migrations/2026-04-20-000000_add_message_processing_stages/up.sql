@@ -0,0 +1,28 @@
+-- Processing pipeline status for stored messages.
+--
+-- Each post-ingestion stage (validation, moderation, embedding, projection)
+-- holds one row per message, written when the stage picks the message up and
+-- updated when it finishes. The partial index serves the stuck-message query,
+-- which only reads rows that have not succeeded.
+
+CREATE TABLE message_processing_stages (
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    message_id UUID NOT NULL,
+    conversation_id UUID NOT NULL,
+    stage VARCHAR(20) NOT NULL
+        CHECK (stage IN ('validation', 'moderation', 'embedding', 'projection')),
+    state VARCHAR(20) NOT NULL
+        CHECK (state IN ('in_progress', 'succeeded', 'failed')),
+    attempts INTEGER NOT NULL CHECK (attempts > 0),
+    last_error TEXT,
+    updated_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, message_id, stage),
+    CONSTRAINT message_processing_stages_message_fk
+        FOREIGN KEY (message_id, tenant_id)
+        REFERENCES messages (id, tenant_id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_message_processing_stages_unfinished
+    ON message_processing_stages (tenant_id, stage, updated_at)
+    WHERE state <> 'succeeded';
@@ -0,0 +1,28 @@
+-- Token and cost budgets for conversations and their delegated children.
+--
+-- A delegated budget records the conversation it was split from. The parent
+-- holds the child's slice in its reserved columns until the child is
+-- reconciled, when the slice is released and the child's consumption is
+-- added to the parent's absorbed columns. Consumption itself is derived from
+-- usage_records rather than stored here.
+
+CREATE TABLE conversation_budgets (
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    conversation_id UUID NOT NULL,
+    parent_conversation_id UUID,
+    limit_tokens BIGINT NOT NULL CHECK (limit_tokens >= 0),
+    limit_cost_micros BIGINT NOT NULL CHECK (limit_cost_micros >= 0),
+    reserved_tokens BIGINT NOT NULL CHECK (reserved_tokens >= 0),
+    reserved_cost_micros BIGINT NOT NULL CHECK (reserved_cost_micros >= 0),
+    absorbed_tokens BIGINT NOT NULL CHECK (absorbed_tokens >= 0),
+    absorbed_cost_micros BIGINT NOT NULL CHECK (absorbed_cost_micros >= 0),
+    created_at TIMESTAMPTZ NOT NULL,
+    reconciled_at TIMESTAMPTZ,
+    PRIMARY KEY (tenant_id, conversation_id),
+    CONSTRAINT conversation_budgets_conversation_fk
+        FOREIGN KEY (conversation_id, tenant_id)
+        REFERENCES conversations (id, tenant_id),
+    CONSTRAINT conversation_budgets_parent_fk
+        FOREIGN KEY (tenant_id, parent_conversation_id)
+        REFERENCES conversation_budgets (tenant_id, conversation_id)
+);
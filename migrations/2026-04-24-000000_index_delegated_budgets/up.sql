@@ -0,0 +1,6 @@
+-- Index delegated budgets by parent so a conversation's delegations can be
+-- listed without scanning every budget in the tenant.
+
+CREATE INDEX idx_conversation_budgets_tenant_parent
+    ON conversation_budgets (tenant_id, parent_conversation_id)
+    WHERE parent_conversation_id IS NOT NULL;
@@ -0,0 +1,23 @@
+-- Long-term agent memory shared across a backend's conversations.
+--
+-- Facts are scoped to a tenant and backend. Confidence decays from
+-- reinforced_at, so the stored value is the confidence at that instant;
+-- decay and expiry are applied when facts are read. The embedding is the
+-- content's vector from the configured embedder.
+
+CREATE TABLE agent_memory_facts (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    backend_id UUID NOT NULL,
+    content TEXT NOT NULL CHECK (btrim(content) <> ''),
+    confidence_percent SMALLINT NOT NULL
+        CHECK (confidence_percent BETWEEN 0 AND 100),
+    embedding REAL[] NOT NULL CHECK (cardinality(embedding) > 0),
+    source_conversation_id UUID,
+    created_at TIMESTAMPTZ NOT NULL,
+    reinforced_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_agent_memory_facts_tenant_backend
+    ON agent_memory_facts (tenant_id, backend_id, created_at);
@@ -0,0 +1,21 @@
+-- Rolling conversation summaries maintained as messages are stored.
+--
+-- Each conversation holds one summary covering its messages up to
+-- covered_through. Incremental updates are counted so the summary can be
+-- rebuilt from the full conversation once they reach the recompute
+-- interval.
+
+CREATE TABLE conversation_rolling_summaries (
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    conversation_id UUID NOT NULL,
+    summary TEXT NOT NULL,
+    covered_through BIGINT NOT NULL CHECK (covered_through > 0),
+    incremental_updates INTEGER NOT NULL CHECK (incremental_updates >= 0),
+    recomputed_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, conversation_id),
+    CONSTRAINT conversation_rolling_summaries_conversation_fk
+        FOREIGN KEY (conversation_id, tenant_id)
+        REFERENCES conversations (id, tenant_id)
+        ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+DROP TRIGGER IF EXISTS message_redactions_scrub_trigger ON message_redactions;
+DROP FUNCTION IF EXISTS scrub_redacted_message();
+DROP TABLE IF EXISTS message_redactions;
@@ -0,0 +1,66 @@
+-- Tombstones for redacted messages.
+--
+-- Redacting a message rewrites its content in place with a placeholder, so
+-- its sequence number is kept, and inserts one row here recording who
+-- redacted it and why. Inserting the tombstone scrubs the copies of the
+-- original content the database keeps elsewhere: the audit trigger stored
+-- whole message rows in audit_logs, and rolling summaries may quote it.
+
+CREATE TABLE message_redactions (
+    message_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    conversation_id UUID NOT NULL,
+    sequence_number BIGINT NOT NULL,
+    reason TEXT NOT NULL CHECK (btrim(reason) <> ''),
+    redacted_by UUID NOT NULL,
+    correlation_id UUID NOT NULL,
+    redacted_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT message_redactions_message_fk
+        FOREIGN KEY (message_id, tenant_id)
+        REFERENCES messages (id, tenant_id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_message_redactions_conversation
+    ON message_redactions (tenant_id, conversation_id, sequence_number);
+
+-- Replace the original content and metadata in audit rows with the message
+-- as redacted, and drop rolling summaries that covered the message so they
+-- are rebuilt without it.
+CREATE OR REPLACE FUNCTION scrub_redacted_message()
+RETURNS TRIGGER AS $$
+DECLARE
+    redacted messages%ROWTYPE;
+BEGIN
+    SELECT * INTO redacted FROM messages WHERE id = NEW.message_id;
+
+    UPDATE audit_logs
+    SET old_values = CASE
+            WHEN old_values IS NULL THEN NULL
+            ELSE old_values || jsonb_build_object(
+                'content', redacted.content,
+                'metadata', redacted.metadata
+            )
+        END,
+        new_values = CASE
+            WHEN new_values IS NULL THEN NULL
+            ELSE new_values || jsonb_build_object(
+                'content', redacted.content,
+                'metadata', redacted.metadata
+            )
+        END
+    WHERE table_name = 'messages' AND row_id = NEW.message_id;
+
+    DELETE FROM conversation_rolling_summaries
+    WHERE tenant_id = NEW.tenant_id
+      AND conversation_id = NEW.conversation_id
+      AND covered_through >= NEW.sequence_number;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER message_redactions_scrub_trigger
+    AFTER INSERT ON message_redactions
+    FOR EACH ROW
+    EXECUTE FUNCTION scrub_redacted_message();
@@ -0,0 +1,47 @@
+-- Audit records for privileged operator actions.
+--
+-- Each row names the acting user, the reason they gave, and the subject of
+-- the action. conversation_id and task_id hold the timelines the action
+-- belongs to; a handoff action also records the handoff it cancelled. Rows
+-- are append-only and deliberately carry no foreign keys to their subjects,
+-- so the audit trail outlives them.
+
+CREATE TABLE operator_actions (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    kind VARCHAR(50) NOT NULL,
+    conversation_id UUID,
+    task_id UUID,
+    handoff_id UUID,
+    reason TEXT NOT NULL CHECK (btrim(reason) <> ''),
+    actor_id UUID NOT NULL,
+    correlation_id UUID NOT NULL,
+    from_state VARCHAR(50),
+    to_state VARCHAR(50),
+    occurred_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT chk_operator_actions_kind CHECK (
+        kind IN (
+            'conversation_paused',
+            'conversation_resumed',
+            'handoff_cancelled',
+            'task_force_transitioned'
+        )
+    ),
+    CONSTRAINT chk_operator_actions_subject CHECK (
+        conversation_id IS NOT NULL OR task_id IS NOT NULL OR handoff_id IS NOT NULL
+    ),
+    CONSTRAINT chk_operator_actions_transition CHECK (
+        (from_state IS NULL) = (to_state IS NULL)
+    )
+);
+
+CREATE INDEX idx_operator_actions_tenant_conversation_occurred_at
+    ON operator_actions (tenant_id, conversation_id, occurred_at)
+    WHERE conversation_id IS NOT NULL;
+
+CREATE INDEX idx_operator_actions_tenant_task_occurred_at
+    ON operator_actions (tenant_id, task_id, occurred_at)
+    WHERE task_id IS NOT NULL;
+
+CREATE INDEX idx_operator_actions_tenant_actor_occurred_at
+    ON operator_actions (tenant_id, actor_id, occurred_at);
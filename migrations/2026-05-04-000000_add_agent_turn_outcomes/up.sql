@@ -0,0 +1,31 @@
+-- Outcomes of orchestrated agent turns.
+--
+-- One row is appended per turn. The kind column holds the outcome taxonomy
+-- so policies and metrics can filter on it; the outcome column holds the
+-- full serialized outcome, including the details of non-completion.
+
+CREATE TABLE agent_turn_outcomes (
+    id BIGSERIAL PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    backend_id UUID NOT NULL,
+    conversation_id UUID NOT NULL,
+    session_id UUID,
+    kind VARCHAR(32) NOT NULL CHECK (
+        kind IN (
+            'completed',
+            'needs_human',
+            'budget_exhausted',
+            'tool_blocked',
+            'backend_failed',
+            'handed_off'
+        )
+    ),
+    outcome JSONB NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_agent_turn_outcomes_tenant_conversation
+    ON agent_turn_outcomes (tenant_id, conversation_id, recorded_at);
+
+CREATE INDEX idx_agent_turn_outcomes_tenant_kind
+    ON agent_turn_outcomes (tenant_id, kind, recorded_at);
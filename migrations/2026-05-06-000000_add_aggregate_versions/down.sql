@@ -0,0 +1,3 @@
+ALTER TABLE agent_sessions DROP COLUMN IF EXISTS version;
+
+ALTER TABLE conversations DROP COLUMN IF EXISTS version;
@@ -0,0 +1,11 @@
+-- Optimistic concurrency versions for conversations and agent sessions.
+--
+-- Each update must name the version it read and bumps it by one, so a
+-- writer holding a stale copy is rejected instead of silently overwriting
+-- a concurrent change. Existing rows start at version zero.
+
+ALTER TABLE conversations
+    ADD COLUMN version BIGINT NOT NULL DEFAULT 0 CHECK (version >= 0);
+
+ALTER TABLE agent_sessions
+    ADD COLUMN version BIGINT NOT NULL DEFAULT 0 CHECK (version >= 0);
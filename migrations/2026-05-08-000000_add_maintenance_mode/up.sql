@@ -0,0 +1,16 @@
+-- Cluster-wide read-only maintenance mode.
+--
+-- A single row holds the flag, so every instance sharing the database sees
+-- the same state. The row is deliberately not tenant-scoped: maintenance
+-- applies to the whole cluster. Enabling maintenance requires a reason.
+
+CREATE TABLE maintenance_mode (
+    singleton BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (singleton),
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    reason TEXT CHECK (reason IS NULL OR btrim(reason) <> ''),
+    changed_by UUID,
+    changed_at TIMESTAMPTZ,
+    CONSTRAINT chk_maintenance_mode_reason CHECK (NOT enabled OR reason IS NOT NULL)
+);
+
+INSERT INTO maintenance_mode (singleton, enabled) VALUES (TRUE, FALSE);
@@ -0,0 +1,44 @@
+DELETE FROM operator_actions
+WHERE kind IN ('conversation_transferred_out', 'conversation_transferred_in');
+
+ALTER TABLE operator_actions
+    DROP CONSTRAINT chk_operator_actions_kind,
+    ADD CONSTRAINT chk_operator_actions_kind CHECK (
+        kind IN (
+            'conversation_paused',
+            'conversation_resumed',
+            'handoff_cancelled',
+            'task_force_transitioned'
+        )
+    );
+
+ALTER TABLE messages
+    ALTER CONSTRAINT messages_conversation_tenant_fkey NOT DEFERRABLE;
+ALTER TABLE agent_sessions
+    ALTER CONSTRAINT agent_sessions_conversation_fk NOT DEFERRABLE;
+ALTER TABLE agent_sessions
+    ALTER CONSTRAINT agent_sessions_initiated_by_handoff_fk NOT DEFERRABLE;
+ALTER TABLE agent_sessions
+    ALTER CONSTRAINT agent_sessions_terminated_by_handoff_fk NOT DEFERRABLE;
+ALTER TABLE handoffs
+    ALTER CONSTRAINT handoffs_conversation_fk NOT DEFERRABLE;
+ALTER TABLE handoffs
+    ALTER CONSTRAINT handoffs_source_session_fk NOT DEFERRABLE;
+ALTER TABLE handoffs
+    ALTER CONSTRAINT handoffs_target_session_fk NOT DEFERRABLE;
+ALTER TABLE context_snapshots
+    ALTER CONSTRAINT context_snapshots_conversation_fk NOT DEFERRABLE;
+ALTER TABLE context_snapshots
+    ALTER CONSTRAINT context_snapshots_session_fk NOT DEFERRABLE;
+ALTER TABLE conversation_summaries
+    ALTER CONSTRAINT conversation_summaries_conversation_fk NOT DEFERRABLE;
+ALTER TABLE conversation_rolling_summaries
+    ALTER CONSTRAINT conversation_rolling_summaries_conversation_fk NOT DEFERRABLE;
+ALTER TABLE message_feedback
+    ALTER CONSTRAINT message_feedback_message_fk NOT DEFERRABLE;
+ALTER TABLE message_processing_stages
+    ALTER CONSTRAINT message_processing_stages_message_fk NOT DEFERRABLE;
+ALTER TABLE message_redactions
+    ALTER CONSTRAINT message_redactions_message_fk NOT DEFERRABLE;
+ALTER TABLE usage_records
+    ALTER CONSTRAINT usage_records_conversation_fk NOT DEFERRABLE;
@@ -0,0 +1,52 @@
+-- Conversation transfer between tenants.
+--
+-- A transfer rewrites tenant_id on a conversation and on every row that
+-- references it, directly or through its messages, sessions, and handoffs,
+-- by a composite (id, tenant_id) foreign key. Those keys become deferrable
+-- so one transaction can rewrite parents and children in any order; they
+-- remain INITIALLY IMMEDIATE, so every other statement is checked as before.
+-- Transfers are audited in both tenants with two new operator action kinds.
+
+ALTER TABLE messages
+    ALTER CONSTRAINT messages_conversation_tenant_fkey DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE agent_sessions
+    ALTER CONSTRAINT agent_sessions_conversation_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE agent_sessions
+    ALTER CONSTRAINT agent_sessions_initiated_by_handoff_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE agent_sessions
+    ALTER CONSTRAINT agent_sessions_terminated_by_handoff_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE handoffs
+    ALTER CONSTRAINT handoffs_conversation_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE handoffs
+    ALTER CONSTRAINT handoffs_source_session_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE handoffs
+    ALTER CONSTRAINT handoffs_target_session_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE context_snapshots
+    ALTER CONSTRAINT context_snapshots_conversation_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE context_snapshots
+    ALTER CONSTRAINT context_snapshots_session_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE conversation_summaries
+    ALTER CONSTRAINT conversation_summaries_conversation_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE conversation_rolling_summaries
+    ALTER CONSTRAINT conversation_rolling_summaries_conversation_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE message_feedback
+    ALTER CONSTRAINT message_feedback_message_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE message_processing_stages
+    ALTER CONSTRAINT message_processing_stages_message_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE message_redactions
+    ALTER CONSTRAINT message_redactions_message_fk DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE usage_records
+    ALTER CONSTRAINT usage_records_conversation_fk DEFERRABLE INITIALLY IMMEDIATE;
+
+ALTER TABLE operator_actions
+    DROP CONSTRAINT chk_operator_actions_kind,
+    ADD CONSTRAINT chk_operator_actions_kind CHECK (
+        kind IN (
+            'conversation_paused',
+            'conversation_resumed',
+            'handoff_cancelled',
+            'task_force_transitioned',
+            'conversation_transferred_out',
+            'conversation_transferred_in'
+        )
+    );
@@ -0,0 +1,7 @@
+DROP TABLE IF EXISTS projection_checkpoints;
+
+DROP TRIGGER IF EXISTS domain_events_position_trigger ON domain_events;
+DROP FUNCTION IF EXISTS assign_domain_event_position();
+DROP INDEX IF EXISTS idx_domain_events_position;
+ALTER TABLE domain_events DROP COLUMN IF EXISTS position;
+DROP SEQUENCE IF EXISTS domain_events_position_seq;
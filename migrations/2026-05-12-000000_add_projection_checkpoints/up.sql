@@ -0,0 +1,55 @@
+-- Event-sourced projections over the domain event stream.
+--
+-- Every domain event receives a global stream position. Positions are drawn
+-- from a sequence while holding a transaction-scoped advisory lock, so
+-- event-writing transactions commit in position order and a reader that has
+-- seen position N never later finds an event below N. Existing events are
+-- numbered in the order they occurred.
+--
+-- Projection checkpoints follow the stream, which spans every tenant, so
+-- they are not tenant-scoped.
+
+CREATE SEQUENCE domain_events_position_seq AS BIGINT;
+
+ALTER TABLE domain_events ADD COLUMN position BIGINT;
+
+UPDATE domain_events
+SET position = numbered.position
+FROM (
+    SELECT id, row_number() OVER (ORDER BY occurred_at, id) AS position
+    FROM domain_events
+) AS numbered
+WHERE domain_events.id = numbered.id;
+
+SELECT setval(
+    'domain_events_position_seq',
+    COALESCE((SELECT max(position) FROM domain_events), 0) + 1,
+    false
+);
+
+ALTER TABLE domain_events ALTER COLUMN position SET NOT NULL;
+ALTER SEQUENCE domain_events_position_seq OWNED BY domain_events.position;
+
+CREATE UNIQUE INDEX idx_domain_events_position ON domain_events(position);
+
+CREATE OR REPLACE FUNCTION assign_domain_event_position()
+RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM pg_advisory_xact_lock(hashtext('domain_events_position'));
+    NEW.position := nextval('domain_events_position_seq');
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER domain_events_position_trigger
+    BEFORE INSERT ON domain_events
+    FOR EACH ROW
+    EXECUTE FUNCTION assign_domain_event_position();
+
+CREATE TABLE projection_checkpoints (
+    projection_name VARCHAR(100) PRIMARY KEY
+        CHECK (btrim(projection_name) <> ''),
+    position BIGINT NOT NULL DEFAULT 0 CHECK (position >= 0),
+    events_applied BIGINT NOT NULL DEFAULT 0 CHECK (events_applied >= 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,18 @@
+-- Explanations of how each orchestrated turn's prompt was assembled.
+--
+-- One row is appended per turn that consulted long-term memory. The
+-- memories column holds one decision per remembered fact the backend had:
+-- whether it was folded into the prompt and, when it was not, why.
+
+CREATE TABLE context_assembly_reports (
+    id BIGSERIAL PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    backend_id UUID NOT NULL,
+    conversation_id UUID NOT NULL,
+    recall_limit INTEGER NOT NULL CHECK (recall_limit >= 0),
+    memories JSONB NOT NULL,
+    assembled_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_context_assembly_reports_tenant_conversation
+    ON context_assembly_reports (tenant_id, conversation_id, assembled_at);
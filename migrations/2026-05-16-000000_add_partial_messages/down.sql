@@ -0,0 +1 @@
+DROP TABLE IF EXISTS partial_messages;
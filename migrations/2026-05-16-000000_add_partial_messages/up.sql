@@ -0,0 +1,27 @@
+-- Assistant messages staged while their content streams in.
+--
+-- One row per stream, keyed by the identifier the finished message will
+-- carry. Each chunk rewrites the row only while it still holds the expected
+-- chunk count, and finalising a stream inserts the message and deletes the
+-- row in one transaction. The updated_at index serves crash recovery, which
+-- looks for streams that have stopped receiving chunks.
+
+CREATE TABLE partial_messages (
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    message_id UUID NOT NULL,
+    conversation_id UUID NOT NULL,
+    metadata JSONB NOT NULL,
+    content TEXT NOT NULL,
+    chunk_count INTEGER NOT NULL CHECK (chunk_count >= 0),
+    started_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, message_id),
+    CONSTRAINT partial_messages_conversation_fk
+        FOREIGN KEY (conversation_id, tenant_id)
+        REFERENCES conversations (id, tenant_id)
+        ON DELETE CASCADE
+        DEFERRABLE INITIALLY IMMEDIATE
+);
+
+CREATE INDEX idx_partial_messages_tenant_updated
+    ON partial_messages (tenant_id, updated_at);
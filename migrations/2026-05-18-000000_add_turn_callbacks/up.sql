@@ -0,0 +1,18 @@
+-- One-shot callbacks waiting for a conversation's next turn to end.
+--
+-- The orchestrator deletes every row for a conversation when a turn there
+-- records its outcome, and delivers a completion notice to each deleted
+-- row's target. Targets are webhook URLs or event-bus reply subjects.
+
+CREATE TABLE agent_turn_callbacks (
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    id UUID NOT NULL,
+    conversation_id UUID NOT NULL,
+    target_kind VARCHAR(32) NOT NULL CHECK (target_kind IN ('url', 'reply_subject')),
+    target TEXT NOT NULL CHECK (target <> ''),
+    registered_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, id)
+);
+
+CREATE INDEX idx_agent_turn_callbacks_tenant_conversation
+    ON agent_turn_callbacks (tenant_id, conversation_id, registered_at);
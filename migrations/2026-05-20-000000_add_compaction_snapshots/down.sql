@@ -0,0 +1,19 @@
+-- Remove compaction snapshots and their summary text.
+
+DROP INDEX IF EXISTS idx_context_snapshots_tenant_conversation_compaction;
+
+ALTER TABLE context_snapshots
+    DROP CONSTRAINT IF EXISTS context_snapshots_compaction_summary_check;
+
+ALTER TABLE context_snapshots
+    DROP CONSTRAINT IF EXISTS context_snapshots_type_check;
+
+DELETE FROM context_snapshots WHERE snapshot_type = 'compaction';
+
+ALTER TABLE context_snapshots
+    ADD CONSTRAINT context_snapshots_type_check CHECK (
+        snapshot_type IN ('session_start', 'handoff_initiated', 'truncation', 'checkpoint')
+    );
+
+ALTER TABLE context_snapshots
+    DROP COLUMN IF EXISTS summary_text;
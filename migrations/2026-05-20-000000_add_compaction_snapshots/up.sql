@@ -0,0 +1,27 @@
+-- Compaction snapshots replace a summarised range of conversation history.
+--
+-- A compaction is a context snapshot of type 'compaction' whose sequence
+-- range covers the messages its summary stands in for. The summary text is
+-- stored alongside; other snapshot types leave it NULL.
+
+ALTER TABLE context_snapshots
+    ADD COLUMN summary_text TEXT;
+
+ALTER TABLE context_snapshots
+    DROP CONSTRAINT IF EXISTS context_snapshots_type_check;
+
+ALTER TABLE context_snapshots
+    ADD CONSTRAINT context_snapshots_type_check CHECK (
+        snapshot_type IN (
+            'session_start', 'handoff_initiated', 'truncation', 'checkpoint', 'compaction'
+        )
+    );
+
+ALTER TABLE context_snapshots
+    ADD CONSTRAINT context_snapshots_compaction_summary_check CHECK (
+        (snapshot_type = 'compaction') = (summary_text IS NOT NULL)
+    );
+
+CREATE INDEX idx_context_snapshots_tenant_conversation_compaction
+    ON context_snapshots (tenant_id, conversation_id, captured_at DESC)
+    WHERE snapshot_type = 'compaction';
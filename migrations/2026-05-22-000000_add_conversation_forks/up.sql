@@ -0,0 +1,30 @@
+-- Conversation forks.
+--
+-- Forking a conversation creates a new conversation that starts with a copy
+-- of the parent's messages up to and including a fork point. The copies get
+-- fresh identifiers but keep their sequence numbers, so the fork continues
+-- from the next one. One row here records where each fork came from: its
+-- parent, the fork point, and the parent's latest context snapshot ending at
+-- or before the fork point. The row moves with the fork when it is
+-- transferred to another tenant; the parent is referenced by identifier
+-- alone, so transferring the parent leaves its forks' provenance intact.
+-- Deleting the parent deletes the provenance but not the fork.
+
+CREATE TABLE conversation_forks (
+    conversation_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    parent_conversation_id UUID NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    forked_at_sequence BIGINT NOT NULL CHECK (forked_at_sequence > 0),
+    parent_snapshot_id UUID REFERENCES context_snapshots(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT conversation_forks_not_self
+        CHECK (conversation_id <> parent_conversation_id),
+    CONSTRAINT conversation_forks_conversation_fk
+        FOREIGN KEY (conversation_id, tenant_id)
+        REFERENCES conversations (id, tenant_id)
+        ON DELETE CASCADE
+        DEFERRABLE INITIALLY IMMEDIATE
+);
+
+CREATE INDEX idx_conversation_forks_parent
+    ON conversation_forks (tenant_id, parent_conversation_id, created_at);
@@ -0,0 +1,7 @@
+DROP TABLE IF EXISTS conversation_label_events;
+
+DROP INDEX IF EXISTS idx_conversations_labels;
+
+ALTER TABLE conversations
+    DROP CONSTRAINT IF EXISTS chk_conversations_labels_object,
+    DROP COLUMN IF EXISTS labels;
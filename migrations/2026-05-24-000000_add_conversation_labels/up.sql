@@ -0,0 +1,37 @@
+-- Conversation labels and their audit history.
+--
+-- Labels are key/value pairs that slice conversations by project, customer,
+-- or experiment. They live on the conversation as a JSONB object of string
+-- values; the GIN index serves containment queries such as
+-- labels @> '{"project": "apollo"}'.
+--
+-- Every change to a conversation's labels appends a row to
+-- conversation_label_events naming the key, the values before and after,
+-- and who made the change. A NULL value means the label was absent on that
+-- side. Rows are append-only and deliberately carry no foreign key to the
+-- conversation, so the audit trail outlives it.
+
+ALTER TABLE conversations
+    ADD COLUMN labels JSONB NOT NULL DEFAULT '{}'::jsonb,
+    ADD CONSTRAINT chk_conversations_labels_object
+        CHECK (jsonb_typeof(labels) = 'object');
+
+CREATE INDEX idx_conversations_labels
+    ON conversations USING GIN (labels jsonb_path_ops);
+
+CREATE TABLE conversation_label_events (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    conversation_id UUID NOT NULL,
+    label_key VARCHAR(63) NOT NULL,
+    previous_value TEXT,
+    label_value TEXT,
+    actor_id UUID NOT NULL,
+    correlation_id UUID NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT chk_conversation_label_events_change
+        CHECK (previous_value IS DISTINCT FROM label_value)
+);
+
+CREATE INDEX idx_conversation_label_events_conversation
+    ON conversation_label_events (tenant_id, conversation_id, occurred_at);
@@ -0,0 +1,7 @@
+DROP TRIGGER IF EXISTS messages_deletion_summary_trigger ON messages;
+
+DROP FUNCTION IF EXISTS project_message_deletion_summary();
+
+DROP INDEX IF EXISTS idx_messages_tenant_created_at;
+
+DROP TABLE IF EXISTS conversation_retention_policies;
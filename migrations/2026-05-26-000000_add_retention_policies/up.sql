@@ -0,0 +1,48 @@
+-- Conversation retention policies.
+--
+-- A conversation may carry its own retention policy, overriding the
+-- default the purge service is configured with: how many days its data is
+-- kept, and whether expired data is deleted or anonymised. The row belongs
+-- to the conversation, is removed with it, and moves with it when it is
+-- transferred to another tenant.
+--
+-- Purges delete messages, so the conversation summary projection now counts
+-- message deletions as well as insertions.
+
+CREATE TABLE conversation_retention_policies (
+    conversation_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    retention_days INTEGER NOT NULL
+        CHECK (retention_days BETWEEN 1 AND 36500),
+    purge_mode VARCHAR(20) NOT NULL
+        CHECK (purge_mode IN ('delete', 'anonymise')),
+    set_by UUID NOT NULL,
+    set_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT conversation_retention_policies_conversation_fk
+        FOREIGN KEY (conversation_id, tenant_id)
+        REFERENCES conversations (id, tenant_id)
+        ON DELETE CASCADE
+        DEFERRABLE INITIALLY IMMEDIATE
+);
+
+CREATE INDEX idx_conversation_retention_policies_tenant
+    ON conversation_retention_policies (tenant_id, set_at, conversation_id);
+
+CREATE INDEX idx_messages_tenant_created_at
+    ON messages (tenant_id, created_at, id);
+
+CREATE OR REPLACE FUNCTION project_message_deletion_summary()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE conversation_summaries
+    SET message_count = GREATEST(message_count - 1, 0)
+    WHERE conversation_id = OLD.conversation_id
+      AND tenant_id = OLD.tenant_id;
+    RETURN OLD;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER messages_deletion_summary_trigger
+    AFTER DELETE ON messages
+    FOR EACH ROW
+    EXECUTE FUNCTION project_message_deletion_summary();
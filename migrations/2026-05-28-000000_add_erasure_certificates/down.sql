@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_audit_logs_user_id;
+
+DROP TABLE IF EXISTS erasure_certificates;
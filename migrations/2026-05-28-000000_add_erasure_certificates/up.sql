@@ -0,0 +1,32 @@
+-- Subject erasure certificates.
+--
+-- Erasing a data subject redacts the messages they wrote, deletes the
+-- sessions, handoffs, and snapshots of conversations they started,
+-- anonymises the domain events they caused, and strips their audit log
+-- entries. Each erasure leaves a certificate recording who asked for it,
+-- why, and how many rows of each kind it changed. Certificates name the
+-- subject only by user ID, so they carry no foreign keys to the data they
+-- describe and outlive it.
+
+CREATE TABLE erasure_certificates (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    subject_id UUID NOT NULL,
+    requested_by UUID NOT NULL,
+    reason TEXT NOT NULL CHECK (btrim(reason) <> ''),
+    correlation_id UUID NOT NULL,
+    erased_at TIMESTAMPTZ NOT NULL,
+    messages BIGINT NOT NULL CHECK (messages >= 0),
+    events BIGINT NOT NULL CHECK (events >= 0),
+    sessions BIGINT NOT NULL CHECK (sessions >= 0),
+    handoffs BIGINT NOT NULL CHECK (handoffs >= 0),
+    snapshots BIGINT NOT NULL CHECK (snapshots >= 0),
+    audit_logs BIGINT NOT NULL CHECK (audit_logs >= 0)
+);
+
+CREATE INDEX idx_erasure_certificates_subject
+    ON erasure_certificates (tenant_id, subject_id, erased_at, id);
+
+CREATE INDEX idx_audit_logs_user_id
+    ON audit_logs (user_id)
+    WHERE user_id IS NOT NULL;
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_encryption_key_rotations_running;
+DROP TABLE IF EXISTS encryption_key_rotations;
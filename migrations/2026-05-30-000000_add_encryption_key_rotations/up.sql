@@ -0,0 +1,26 @@
+-- Encryption key rotations.
+--
+-- A rotation reseals a tenant's messages under a new encryption key in
+-- batches, each committed on its own. The row records the target key and
+-- the progress so far; which messages still need resealing is read from
+-- the key ID in each message's sealed envelope, so an interrupted rotation
+-- resumes where it stopped. A tenant runs at most one rotation at a time.
+
+CREATE TABLE encryption_key_rotations (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    target_key_id TEXT NOT NULL CHECK (btrim(target_key_id) <> ''),
+    status TEXT NOT NULL CHECK (status IN ('running', 'completed', 'superseded')),
+    started_by UUID NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL,
+    finished_at TIMESTAMPTZ,
+    batches BIGINT NOT NULL CHECK (batches >= 0),
+    resealed BIGINT NOT NULL CHECK (resealed >= 0),
+    remaining BIGINT NOT NULL CHECK (remaining >= 0),
+    CHECK ((status = 'running') = (finished_at IS NULL))
+);
+
+CREATE UNIQUE INDEX idx_encryption_key_rotations_running
+    ON encryption_key_rotations (tenant_id)
+    WHERE status = 'running';
@@ -0,0 +1,4 @@
+-- Sealed content cannot be turned back into text; it is kept as the JSON
+-- text of its envelope.
+ALTER TABLE partial_messages
+    ALTER COLUMN content TYPE TEXT USING content #>> '{}';
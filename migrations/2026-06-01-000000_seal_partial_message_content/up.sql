@@ -0,0 +1,9 @@
+-- Staged stream content as JSONB.
+--
+-- With a content cipher configured, a staged stream's text is sealed like
+-- a stored message's content, which needs room for the envelope object.
+-- Unsealed text becomes a JSON string, so existing streams keep their
+-- content.
+
+ALTER TABLE partial_messages
+    ALTER COLUMN content TYPE JSONB USING to_jsonb(content);
@@ -0,0 +1,12 @@
+-- Index of the attachment blobs each message refers to.
+--
+-- Identical attachment data within a tenant shares one blob, so a blob is
+-- only deleted once no message of the tenant names it. Unsealed content
+-- names blobs in its parts; sealed content keeps the names beside the
+-- envelope in `$parts`. The expression covers both, and must match the one
+-- the message repositories query.
+
+CREATE INDEX idx_messages_attachment_blobs ON messages USING GIN ((
+    jsonb_path_query_array(content, '$[*].blob')
+    || jsonb_path_query_array(content, '$."$parts"[*].blob')
+));
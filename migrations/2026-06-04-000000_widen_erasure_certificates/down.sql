@@ -0,0 +1,7 @@
+ALTER TABLE erasure_certificates
+    DROP COLUMN IF EXISTS blobs,
+    DROP COLUMN IF EXISTS summaries,
+    DROP COLUMN IF EXISTS operator_actions,
+    DROP COLUMN IF EXISTS memory_facts,
+    DROP COLUMN IF EXISTS partial_messages,
+    DROP COLUMN IF EXISTS feedback;
@@ -0,0 +1,16 @@
+-- Count everything a subject erasure reaches on its certificate.
+--
+-- Erasures also delete the feedback the subject gave and clear comments on
+-- their messages, delete staged streams, agent memory facts, rolling
+-- summaries, and compactions derived from their conversations, replace the
+-- reasons recorded for operator actions they took or that concern their
+-- conversations, and delete attachment blobs only their messages referred
+-- to. Certificates written before these were erased count none of them.
+
+ALTER TABLE erasure_certificates
+    ADD COLUMN feedback BIGINT NOT NULL DEFAULT 0 CHECK (feedback >= 0),
+    ADD COLUMN partial_messages BIGINT NOT NULL DEFAULT 0 CHECK (partial_messages >= 0),
+    ADD COLUMN memory_facts BIGINT NOT NULL DEFAULT 0 CHECK (memory_facts >= 0),
+    ADD COLUMN operator_actions BIGINT NOT NULL DEFAULT 0 CHECK (operator_actions >= 0),
+    ADD COLUMN summaries BIGINT NOT NULL DEFAULT 0 CHECK (summaries >= 0),
+    ADD COLUMN blobs BIGINT NOT NULL DEFAULT 0 CHECK (blobs >= 0);
@@ -0,0 +1,6 @@
+ALTER TABLE experiment_observations
+    DROP CONSTRAINT experiment_observations_experiment_fk,
+    ADD CONSTRAINT experiment_observations_experiment_fk
+        FOREIGN KEY (experiment_id, tenant_id)
+        REFERENCES backend_experiments (id, tenant_id)
+        ON DELETE CASCADE;
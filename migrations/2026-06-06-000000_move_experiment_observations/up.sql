@@ -0,0 +1,14 @@
+-- Let experiment observations move with their conversation.
+--
+-- Observations referenced their experiment through (experiment_id,
+-- tenant_id), so a conversation transferred to another tenant could not
+-- take its observations along. They now reference the experiment by ID
+-- alone. A moved observation stops counting towards the source tenant's
+-- experiment, whose results are read within that tenant.
+
+ALTER TABLE experiment_observations
+    DROP CONSTRAINT experiment_observations_experiment_fk,
+    ADD CONSTRAINT experiment_observations_experiment_fk
+        FOREIGN KEY (experiment_id)
+        REFERENCES backend_experiments (id)
+        ON DELETE CASCADE;
@@ -0,0 +1,17 @@
+-- Audit trail of secrets injected into MCP server processes.
+--
+-- One row is appended each time a STDIO server is spawned with secrets in
+-- its environment. The event column holds the serialized event: the server,
+-- the injected variable names, and the references they were resolved from.
+-- Secret values are never written here.
+
+CREATE TABLE secret_injection_audits (
+    id BIGSERIAL PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    server_id UUID NOT NULL,
+    event JSONB NOT NULL,
+    injected_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_secret_injection_audits_tenant_server
+    ON secret_injection_audits (tenant_id, server_id, injected_at);
@@ -0,0 +1,17 @@
+-- Context packages prepared for the target sessions of completed handoffs.
+--
+-- Each target session holds at most one package. The package column holds
+-- the serialized package: the initiation snapshot reference, the
+-- conversation summary, pinned messages, and open tool calls.
+
+CREATE TABLE handoff_context_packages (
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    target_session_id UUID NOT NULL REFERENCES agent_sessions(id) ON DELETE CASCADE,
+    handoff_id UUID NOT NULL REFERENCES handoffs(id) ON DELETE CASCADE,
+    package JSONB NOT NULL,
+    prepared_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, target_session_id)
+);
+
+CREATE INDEX idx_handoff_context_packages_handoff
+    ON handoff_context_packages (handoff_id);
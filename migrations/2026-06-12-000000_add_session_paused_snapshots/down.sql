@@ -0,0 +1,13 @@
+-- Remove session pause snapshots.
+
+ALTER TABLE context_snapshots
+    DROP CONSTRAINT IF EXISTS context_snapshots_type_check;
+
+DELETE FROM context_snapshots WHERE snapshot_type = 'session_paused';
+
+ALTER TABLE context_snapshots
+    ADD CONSTRAINT context_snapshots_type_check CHECK (
+        snapshot_type IN (
+            'session_start', 'handoff_initiated', 'truncation', 'checkpoint', 'compaction'
+        )
+    );
@@ -0,0 +1,13 @@
+-- Session pause snapshots record the context window a paused agent session
+-- held, so it can be restored when the session resumes.
+
+ALTER TABLE context_snapshots
+    DROP CONSTRAINT IF EXISTS context_snapshots_type_check;
+
+ALTER TABLE context_snapshots
+    ADD CONSTRAINT context_snapshots_type_check CHECK (
+        snapshot_type IN (
+            'session_start', 'handoff_initiated', 'truncation', 'checkpoint', 'compaction',
+            'session_paused'
+        )
+    );
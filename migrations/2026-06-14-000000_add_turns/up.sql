@@ -0,0 +1,27 @@
+-- Turns record each exchange between a user and an agent session: the
+-- message that started it, the messages and tool calls written in
+-- response, and when it started and ended.
+
+CREATE TABLE turns (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    conversation_id UUID NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    session_id UUID NOT NULL REFERENCES agent_sessions(id) ON DELETE CASCADE,
+    initiating_message_id UUID NOT NULL,
+    response_message_ids JSONB NOT NULL DEFAULT '[]',
+    tool_calls JSONB NOT NULL DEFAULT '[]',
+    status VARCHAR(20) NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ,
+    CONSTRAINT turns_status_check CHECK (
+        status IN ('in_progress', 'completed', 'failed')
+    ),
+    CONSTRAINT turns_ended_at_check CHECK (
+        (status = 'in_progress') = (ended_at IS NULL)
+    )
+);
+
+CREATE INDEX idx_turns_tenant_conversation_started
+    ON turns (tenant_id, conversation_id, started_at, id);
+
+CREATE INDEX idx_turns_session ON turns (session_id);
@@ -0,0 +1,8 @@
+-- Remove incremental snapshots, which record only part of their context
+-- window and cannot stand alone once their parent link is gone.
+
+DELETE FROM context_snapshots WHERE parent_snapshot_id IS NOT NULL;
+
+DROP INDEX IF EXISTS idx_context_snapshots_parent;
+
+ALTER TABLE context_snapshots DROP COLUMN parent_snapshot_id;
@@ -0,0 +1,11 @@
+-- Incremental snapshots name the snapshot they extend and record only the
+-- messages after its range. An increment cannot be resolved without its
+-- parent, so deleting a snapshot deletes the increments built on it.
+
+ALTER TABLE context_snapshots
+    ADD COLUMN parent_snapshot_id UUID
+        REFERENCES context_snapshots(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_context_snapshots_parent
+    ON context_snapshots (parent_snapshot_id)
+    WHERE parent_snapshot_id IS NOT NULL;
@@ -0,0 +1,16 @@
+-- Remove periodic snapshots and their policies.
+
+DROP TABLE IF EXISTS periodic_snapshot_policies;
+
+ALTER TABLE context_snapshots
+    DROP CONSTRAINT IF EXISTS context_snapshots_type_check;
+
+DELETE FROM context_snapshots WHERE snapshot_type = 'periodic';
+
+ALTER TABLE context_snapshots
+    ADD CONSTRAINT context_snapshots_type_check CHECK (
+        snapshot_type IN (
+            'session_start', 'handoff_initiated', 'truncation', 'checkpoint', 'compaction',
+            'session_paused'
+        )
+    );
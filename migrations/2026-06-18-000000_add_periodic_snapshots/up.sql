@@ -0,0 +1,27 @@
+-- Periodic snapshots capture long-running agent sessions on a schedule set
+-- per conversation.
+
+ALTER TABLE context_snapshots
+    DROP CONSTRAINT IF EXISTS context_snapshots_type_check;
+
+ALTER TABLE context_snapshots
+    ADD CONSTRAINT context_snapshots_type_check CHECK (
+        snapshot_type IN (
+            'session_start', 'handoff_initiated', 'truncation', 'checkpoint', 'compaction',
+            'session_paused', 'periodic'
+        )
+    );
+
+CREATE TABLE periodic_snapshot_policies (
+    conversation_id UUID PRIMARY KEY REFERENCES conversations(id) ON DELETE CASCADE,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    every_messages INTEGER,
+    every_minutes INTEGER,
+    CONSTRAINT periodic_snapshot_policies_every_messages_check
+        CHECK (every_messages IS NULL OR every_messages > 0),
+    CONSTRAINT periodic_snapshot_policies_every_minutes_check
+        CHECK (every_minutes IS NULL OR every_minutes > 0)
+);
+
+CREATE INDEX idx_periodic_snapshot_policies_tenant
+    ON periodic_snapshot_policies (tenant_id);
@@ -0,0 +1,3 @@
+-- Remove versioned slash-command definitions.
+
+DROP TABLE IF EXISTS slash_command_definitions;
@@ -0,0 +1,15 @@
+-- Versioned slash-command definitions managed at runtime.
+--
+-- Registering a command again adds a row with the next version, so earlier
+-- definitions stay available. The definition column holds the serialized
+-- definition: parameters, tool-call templates, and expansion template.
+
+CREATE TABLE slash_command_definitions (
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    command VARCHAR(100) NOT NULL,
+    version INTEGER NOT NULL,
+    definition JSONB NOT NULL,
+    registered_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, command, version),
+    CONSTRAINT slash_command_definitions_version_check CHECK (version > 0)
+);
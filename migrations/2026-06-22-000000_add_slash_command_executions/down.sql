@@ -0,0 +1,3 @@
+-- Remove the slash-command execution audit trail.
+
+DROP TABLE IF EXISTS slash_command_executions;
@@ -0,0 +1,18 @@
+-- Audit trail of slash-command executions.
+--
+-- Each row records who ran which command in which conversation. The
+-- execution column holds the serialized execution: the parsed invocation,
+-- the expansion attached to resulting messages, and the planned tool calls.
+
+CREATE TABLE slash_command_executions (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    conversation_id UUID NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    invoked_by UUID NOT NULL,
+    command VARCHAR(100) NOT NULL,
+    execution JSONB NOT NULL,
+    executed_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_slash_command_executions_tenant_conversation_executed
+    ON slash_command_executions (tenant_id, conversation_id, executed_at, id);
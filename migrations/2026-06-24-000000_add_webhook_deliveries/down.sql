@@ -0,0 +1,3 @@
+-- Remove the webhook delivery log.
+
+DROP TABLE IF EXISTS webhook_deliveries;
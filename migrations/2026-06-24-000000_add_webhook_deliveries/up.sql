@@ -0,0 +1,13 @@
+-- Webhook deliveries claimed for processing.
+--
+-- Issue providers redeliver webhooks on timeouts and on request. A delivery
+-- is claimed by inserting its row before its event is applied, so a
+-- redelivery finds the row and is skipped.
+
+CREATE TABLE webhook_deliveries (
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    provider VARCHAR(20) NOT NULL,
+    delivery_id VARCHAR(255) NOT NULL,
+    received_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, provider, delivery_id)
+);
@@ -0,0 +1,3 @@
+-- Remove the issue status notification queue.
+
+DROP TABLE IF EXISTS issue_status_notifications;
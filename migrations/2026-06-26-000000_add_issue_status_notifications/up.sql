@@ -0,0 +1,25 @@
+-- Task status updates awaiting delivery to their originating issues.
+--
+-- An update is queued before it is sent and deleted once delivered, so an
+-- update whose delivery fails transiently stays queued for retry. A task
+-- holds at most one queued update, its latest.
+
+CREATE TABLE issue_status_notifications (
+    tenant_id UUID NOT NULL REFERENCES tenants(id),
+    task_id UUID NOT NULL,
+    provider VARCHAR(20) NOT NULL,
+    repository VARCHAR(255) NOT NULL,
+    issue_number BIGINT NOT NULL,
+    state VARCHAR(50) NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0 CHECK (attempts >= 0),
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    last_error TEXT,
+    PRIMARY KEY (tenant_id, task_id),
+    CONSTRAINT issue_status_notifications_task_fk
+        FOREIGN KEY (task_id, tenant_id)
+        REFERENCES tasks (id, tenant_id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_issue_status_notifications_tenant_due
+    ON issue_status_notifications (tenant_id, next_attempt_at);
@@ -0,0 +1,8 @@
+-- Remove task scheduling.
+
+DROP INDEX IF EXISTS idx_tasks_claim_order;
+
+ALTER TABLE tasks
+    DROP COLUMN IF EXISTS required_capabilities,
+    DROP COLUMN IF EXISTS due_at,
+    DROP COLUMN IF EXISTS priority;
@@ -0,0 +1,16 @@
+-- Task priority, due dates, and required worker capabilities.
+--
+-- Draft tasks form the work queue. Workers claim them by descending
+-- priority, then earliest due date, then creation order, locking the
+-- claimed row with FOR UPDATE SKIP LOCKED; the partial index serves that
+-- ordering.
+
+ALTER TABLE tasks
+    ADD COLUMN priority SMALLINT NOT NULL DEFAULT 1
+        CHECK (priority BETWEEN 0 AND 3),
+    ADD COLUMN due_at TIMESTAMPTZ,
+    ADD COLUMN required_capabilities TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_tasks_claim_order
+    ON tasks (tenant_id, priority DESC, due_at ASC NULLS LAST, created_at, id)
+    WHERE state = 'draft';
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_handoff_context_packages_conversation;
+
+ALTER TABLE handoff_context_packages
+    DROP COLUMN IF EXISTS conversation_id;
@@ -0,0 +1,21 @@
+-- Let handoff context packages move with their conversation.
+--
+-- Packages were keyed by target session alone, so transferring a
+-- conversation to another tenant left its packages under the source tenant,
+-- where the target agent could no longer read them. Each package now
+-- records its conversation, taken from the target session for existing
+-- rows.
+
+ALTER TABLE handoff_context_packages
+    ADD COLUMN conversation_id UUID REFERENCES conversations(id) ON DELETE CASCADE;
+
+UPDATE handoff_context_packages AS package
+SET conversation_id = session.conversation_id
+FROM agent_sessions AS session
+WHERE session.id = package.target_session_id;
+
+ALTER TABLE handoff_context_packages
+    ALTER COLUMN conversation_id SET NOT NULL;
+
+CREATE INDEX idx_handoff_context_packages_conversation
+    ON handoff_context_packages (conversation_id);
@@ -0,0 +1,98 @@
+//! Object store adapter for tool invocation datasets.
+//!
+//! Each append writes one JSON Lines blob under
+//! `tool_datasets/{tenant_id}/`, named by its recording time so that a
+//! lexicographic listing returns blobs oldest first. Exports concatenate the
+//! tenant's blobs in that order.
+
+use crate::agent_backend::{
+    domain::{ToolInvocationSample, encode_jsonl},
+    ports::{ToolDatasetError, ToolDatasetResult, ToolDatasetStore},
+};
+use crate::context::RequestContext;
+use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use futures::TryStreamExt;
+use object_store::{ObjectStore, path::Path};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Adapter wrapping an [`ObjectStore`] backend for dataset storage.
+///
+/// Supports any `object_store` backend: `InMemory` for tests,
+/// `LocalFileSystem` for development, and cloud backends for production
+/// collection.
+#[derive(Debug, Clone)]
+pub struct ObjectStoreToolDataset {
+    store: Arc<dyn ObjectStore>,
+}
+
+impl ObjectStoreToolDataset {
+    /// Creates a dataset adapter from any [`ObjectStore`] implementation.
+    #[must_use]
+    pub fn new(store: Arc<dyn ObjectStore>) -> Self {
+        Self { store }
+    }
+
+    /// Creates an in-memory backed adapter for tests.
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self::new(Arc::new(object_store::memory::InMemory::new()))
+    }
+}
+
+fn tenant_prefix(ctx: &RequestContext) -> Path {
+    Path::from(format!("tool_datasets/{}/", ctx.tenant_id()))
+}
+
+#[async_trait]
+impl ToolDatasetStore for ObjectStoreToolDataset {
+    async fn append_samples(
+        &self,
+        ctx: &RequestContext,
+        samples: &[ToolInvocationSample],
+    ) -> ToolDatasetResult<()> {
+        let Some(first) = samples.first() else {
+            return Ok(());
+        };
+        let encoded =
+            encode_jsonl(samples).map_err(|err| ToolDatasetError::Encode(err.to_string()))?;
+        let path = Path::from(format!(
+            "tool_datasets/{}/{}-{}.jsonl",
+            ctx.tenant_id(),
+            first.recorded_at.format("%Y%m%dT%H%M%S%.6fZ"),
+            Uuid::new_v4(),
+        ));
+        self.store
+            .put(&path, Bytes::from(encoded).into())
+            .await
+            .map_err(|err| ToolDatasetError::Write(err.to_string()))?;
+        Ok(())
+    }
+
+    async fn export_jsonl(&self, ctx: &RequestContext) -> ToolDatasetResult<Bytes> {
+        let prefix = tenant_prefix(ctx);
+        let mut paths: Vec<Path> = self
+            .store
+            .list(Some(&prefix))
+            .map_ok(|meta| meta.location)
+            .try_collect()
+            .await
+            .map_err(|err| ToolDatasetError::Read(err.to_string()))?;
+        paths.sort();
+
+        let mut exported = BytesMut::new();
+        for path in paths {
+            let blob = self
+                .store
+                .get(&path)
+                .await
+                .map_err(|err| ToolDatasetError::Read(err.to_string()))?
+                .bytes()
+                .await
+                .map_err(|err| ToolDatasetError::Read(err.to_string()))?;
+            exported.extend_from_slice(&blob);
+        }
+        Ok(exported.freeze())
+    }
+}
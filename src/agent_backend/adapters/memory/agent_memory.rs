@@ -0,0 +1,104 @@
+//! In-memory repository for long-term agent memory tests.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::agent_backend::{
+    domain::{BackendId, MemoryFact, MemoryFactId},
+    ports::{AgentMemoryRepository, AgentMemoryRepositoryError, AgentMemoryRepositoryResult},
+};
+use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
+
+type TenantFacts = HashMap<TenantId, HashMap<MemoryFactId, MemoryFact>>;
+
+/// Thread-safe in-memory agent memory repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAgentMemoryRepository {
+    state: Arc<RwLock<TenantFacts>>,
+}
+
+impl InMemoryAgentMemoryRepository {
+    /// Creates an empty in-memory repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn read_state(&self) -> AgentMemoryRepositoryResult<RwLockReadGuard<'_, TenantFacts>> {
+        self.state.read().map_err(|err| {
+            AgentMemoryRepositoryError::persistence(std::io::Error::other(err.to_string()))
+        })
+    }
+
+    fn write_state(&self) -> AgentMemoryRepositoryResult<RwLockWriteGuard<'_, TenantFacts>> {
+        self.state.write().map_err(|err| {
+            AgentMemoryRepositoryError::persistence(std::io::Error::other(err.to_string()))
+        })
+    }
+}
+
+#[async_trait]
+impl AgentMemoryRepository for InMemoryAgentMemoryRepository {
+    async fn upsert(
+        &self,
+        ctx: &RequestContext,
+        fact: &MemoryFact,
+    ) -> AgentMemoryRepositoryResult<()> {
+        let mut tenants = self.write_state()?;
+        tenants
+            .entry(ctx.tenant_id())
+            .or_default()
+            .insert(fact.id(), fact.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: MemoryFactId,
+    ) -> AgentMemoryRepositoryResult<Option<MemoryFact>> {
+        let tenants = self.read_state()?;
+        Ok(tenants
+            .get(&ctx.tenant_id())
+            .and_then(|facts| facts.get(&id))
+            .cloned())
+    }
+
+    async fn list_for_backend(
+        &self,
+        ctx: &RequestContext,
+        backend_id: BackendId,
+        page: PageRequest,
+    ) -> AgentMemoryRepositoryResult<Page<MemoryFact>> {
+        let tenants = self.read_state()?;
+        let mut facts = tenants
+            .get(&ctx.tenant_id())
+            .map(|facts| {
+                facts
+                    .values()
+                    .filter(|fact| fact.backend_id() == backend_id)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        facts.sort_by_key(|fact| (fact.created_at(), fact.id().into_inner()));
+        Ok(Page::from_ordered(facts, page, |fact| {
+            Cursor::at_timestamp(fact.created_at(), fact.id().into_inner())
+        }))
+    }
+
+    async fn delete(
+        &self,
+        ctx: &RequestContext,
+        id: MemoryFactId,
+    ) -> AgentMemoryRepositoryResult<()> {
+        let mut tenants = self.write_state()?;
+        tenants
+            .get_mut(&ctx.tenant_id())
+            .and_then(|facts| facts.remove(&id))
+            .map(|_| ())
+            .ok_or(AgentMemoryRepositoryError::NotFound(id))
+    }
+}
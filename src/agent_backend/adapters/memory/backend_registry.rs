@@ -9,6 +9,7 @@ use crate::agent_backend::{
     ports::{BackendRegistryError, BackendRegistryRepository, BackendRegistryResult},
 };
 use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
 
 /// Thread-safe in-memory backend registry repository.
 #[derive(Debug, Clone, Default)]
@@ -44,6 +45,32 @@ impl InMemoryBackendRegistry {
             BackendRegistryError::persistence(std::io::Error::other(err.to_string()))
         })
     }
+
+    /// Returns a page of the tenant's backends matching `filter`, in
+    /// registration order.
+    fn page_in_registration_order(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+        filter: impl Fn(&AgentBackendRegistration) -> bool,
+    ) -> BackendRegistryResult<Page<AgentBackendRegistration>> {
+        let tenants = self.read_state()?;
+        let mut backends: Vec<_> = tenants
+            .get(&ctx.tenant_id())
+            .map(|state| {
+                state
+                    .backends
+                    .values()
+                    .filter(|backend| filter(backend))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        backends.sort_by_key(|backend| (backend.created_at(), backend.id().into_inner()));
+        Ok(Page::from_ordered(backends, page, |backend| {
+            Cursor::at_timestamp(backend.created_at(), backend.id().into_inner())
+        }))
+    }
 }
 
 #[async_trait]
@@ -140,30 +167,18 @@ impl BackendRegistryRepository for InMemoryBackendRegistry {
     async fn list_active(
         &self,
         ctx: &RequestContext,
-    ) -> BackendRegistryResult<Vec<AgentBackendRegistration>> {
-        let tenants = self.read_state()?;
-        let active = tenants
-            .get(&ctx.tenant_id())
-            .map(|state| {
-                state
-                    .backends
-                    .values()
-                    .filter(|b| b.status() == BackendStatus::Active)
-                    .cloned()
-                    .collect()
-            })
-            .unwrap_or_default();
-        Ok(active)
+        page: PageRequest,
+    ) -> BackendRegistryResult<Page<AgentBackendRegistration>> {
+        self.page_in_registration_order(ctx, page, |backend| {
+            backend.status() == BackendStatus::Active
+        })
     }
 
     async fn list_all(
         &self,
         ctx: &RequestContext,
-    ) -> BackendRegistryResult<Vec<AgentBackendRegistration>> {
-        let tenants = self.read_state()?;
-        Ok(tenants
-            .get(&ctx.tenant_id())
-            .map(|state| state.backends.values().cloned().collect())
-            .unwrap_or_default())
+        page: PageRequest,
+    ) -> BackendRegistryResult<Page<AgentBackendRegistration>> {
+        self.page_in_registration_order(ctx, page, |_| true)
     }
 }
@@ -0,0 +1,92 @@
+//! In-memory repository for context assembly report tests.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use uuid::Uuid;
+
+use crate::agent_backend::{
+    domain::ContextAssemblyReport,
+    ports::{
+        ContextAssemblyReportRepository, ContextAssemblyReportRepositoryError,
+        ContextAssemblyReportRepositoryResult,
+    },
+};
+use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
+
+type TenantReports = HashMap<TenantId, Vec<ContextAssemblyReport>>;
+
+/// Thread-safe in-memory context assembly report repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryContextAssemblyReportRepository {
+    state: Arc<RwLock<TenantReports>>,
+}
+
+impl InMemoryContextAssemblyReportRepository {
+    /// Creates an empty in-memory repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn read_state(
+        &self,
+    ) -> ContextAssemblyReportRepositoryResult<RwLockReadGuard<'_, TenantReports>> {
+        self.state.read().map_err(|err| {
+            ContextAssemblyReportRepositoryError::persistence(std::io::Error::other(
+                err.to_string(),
+            ))
+        })
+    }
+
+    fn write_state(
+        &self,
+    ) -> ContextAssemblyReportRepositoryResult<RwLockWriteGuard<'_, TenantReports>> {
+        self.state.write().map_err(|err| {
+            ContextAssemblyReportRepositoryError::persistence(std::io::Error::other(
+                err.to_string(),
+            ))
+        })
+    }
+}
+
+#[async_trait]
+impl ContextAssemblyReportRepository for InMemoryContextAssemblyReportRepository {
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        report: &ContextAssemblyReport,
+    ) -> ContextAssemblyReportRepositoryResult<()> {
+        let mut tenants = self.write_state()?;
+        tenants
+            .entry(ctx.tenant_id())
+            .or_default()
+            .push(report.clone());
+        Ok(())
+    }
+
+    async fn list_for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+        page: PageRequest,
+    ) -> ContextAssemblyReportRepositoryResult<Page<ContextAssemblyReport>> {
+        let tenants = self.read_state()?;
+        let recorded = tenants
+            .get(&ctx.tenant_id())
+            .map(|reports| {
+                reports
+                    .iter()
+                    .zip(0_i64..)
+                    .filter(|(report, _)| report.conversation_id == conversation_id)
+                    .map(|(report, position)| (report.clone(), position))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Page::from_ordered(recorded, page, |(_, position)| {
+            Cursor::at_number(*position, conversation_id)
+        })
+        .map(|(report, _)| report))
+    }
+}
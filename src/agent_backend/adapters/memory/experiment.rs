@@ -0,0 +1,161 @@
+//! In-memory repository for backend experiment tests.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::agent_backend::{
+    domain::{BackendExperiment, ExperimentId, ExperimentObservation, ExperimentStatus},
+    ports::{ExperimentRepository, ExperimentRepositoryError, ExperimentRepositoryResult},
+};
+use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
+
+/// Thread-safe in-memory experiment repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryExperimentRepository {
+    state: Arc<RwLock<HashMap<TenantId, TenantExperimentState>>>,
+}
+
+#[derive(Debug, Default)]
+struct TenantExperimentState {
+    experiments: HashMap<ExperimentId, BackendExperiment>,
+    observations: Vec<ExperimentObservation>,
+}
+
+impl InMemoryExperimentRepository {
+    /// Creates an empty in-memory repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn read_state(
+        &self,
+    ) -> ExperimentRepositoryResult<RwLockReadGuard<'_, HashMap<TenantId, TenantExperimentState>>>
+    {
+        self.state.read().map_err(|err| {
+            ExperimentRepositoryError::persistence(std::io::Error::other(err.to_string()))
+        })
+    }
+
+    fn write_state(
+        &self,
+    ) -> ExperimentRepositoryResult<RwLockWriteGuard<'_, HashMap<TenantId, TenantExperimentState>>>
+    {
+        self.state.write().map_err(|err| {
+            ExperimentRepositoryError::persistence(std::io::Error::other(err.to_string()))
+        })
+    }
+}
+
+#[async_trait]
+impl ExperimentRepository for InMemoryExperimentRepository {
+    async fn create(
+        &self,
+        ctx: &RequestContext,
+        experiment: &BackendExperiment,
+    ) -> ExperimentRepositoryResult<()> {
+        let mut tenants = self.write_state()?;
+        let state = tenants.entry(ctx.tenant_id()).or_default();
+        if state.experiments.contains_key(&experiment.id()) {
+            return Err(ExperimentRepositoryError::DuplicateExperiment(
+                experiment.id(),
+            ));
+        }
+        state
+            .experiments
+            .insert(experiment.id(), experiment.clone());
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        ctx: &RequestContext,
+        experiment: &BackendExperiment,
+    ) -> ExperimentRepositoryResult<()> {
+        let mut tenants = self.write_state()?;
+        let stored = tenants
+            .get_mut(&ctx.tenant_id())
+            .and_then(|state| state.experiments.get_mut(&experiment.id()))
+            .ok_or(ExperimentRepositoryError::NotFound(experiment.id()))?;
+        *stored = experiment.clone();
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: ExperimentId,
+    ) -> ExperimentRepositoryResult<Option<BackendExperiment>> {
+        let tenants = self.read_state()?;
+        Ok(tenants
+            .get(&ctx.tenant_id())
+            .and_then(|state| state.experiments.get(&id))
+            .cloned())
+    }
+
+    async fn list_running(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> ExperimentRepositoryResult<Page<BackendExperiment>> {
+        let tenants = self.read_state()?;
+        let mut running = tenants
+            .get(&ctx.tenant_id())
+            .map(|state| {
+                state
+                    .experiments
+                    .values()
+                    .filter(|experiment| experiment.status() == ExperimentStatus::Running)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        running.sort_by_key(|experiment| (experiment.created_at(), experiment.id().into_inner()));
+        Ok(Page::from_ordered(running, page, |experiment| {
+            Cursor::at_timestamp(experiment.created_at(), experiment.id().into_inner())
+        }))
+    }
+
+    async fn record_observation(
+        &self,
+        ctx: &RequestContext,
+        observation: &ExperimentObservation,
+    ) -> ExperimentRepositoryResult<()> {
+        let mut tenants = self.write_state()?;
+        let state = tenants
+            .get_mut(&ctx.tenant_id())
+            .filter(|state| state.experiments.contains_key(&observation.experiment_id))
+            .ok_or(ExperimentRepositoryError::NotFound(
+                observation.experiment_id,
+            ))?;
+        state.observations.push(*observation);
+        Ok(())
+    }
+
+    async fn list_observations(
+        &self,
+        ctx: &RequestContext,
+        id: ExperimentId,
+        page: PageRequest,
+    ) -> ExperimentRepositoryResult<Page<ExperimentObservation>> {
+        let tenants = self.read_state()?;
+        let recorded = tenants
+            .get(&ctx.tenant_id())
+            .map(|state| {
+                state
+                    .observations
+                    .iter()
+                    .zip(0_i64..)
+                    .filter(|(observation, _)| observation.experiment_id == id)
+                    .map(|(observation, position)| (*observation, position))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Page::from_ordered(recorded, page, |(_, position)| {
+            Cursor::at_number(*position, id.into_inner())
+        })
+        .map(|(observation, _)| observation))
+    }
+}
@@ -1,11 +1,21 @@
 //! In-memory adapters for agent backend orchestration.
 
+mod agent_memory;
 mod backend_registry;
+mod context_assembly;
+mod experiment;
 mod runtime;
 mod tool_router;
+mod turn_callback;
+mod turn_outcome;
 mod turn_session;
 
+pub use agent_memory::InMemoryAgentMemoryRepository;
 pub use backend_registry::InMemoryBackendRegistry;
+pub use context_assembly::InMemoryContextAssemblyReportRepository;
+pub use experiment::InMemoryExperimentRepository;
 pub use runtime::{InMemoryAgentRuntime, RuntimeExecutionRecord};
 pub use tool_router::InMemoryToolRouter;
+pub use turn_callback::{InMemoryTurnCallbackRepository, InMemoryTurnCallbackSender};
+pub use turn_outcome::InMemoryTurnOutcomeRepository;
 pub use turn_session::InMemoryTurnSessionRepository;
@@ -0,0 +1,186 @@
+//! In-memory turn callback repository and sender for tests.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use uuid::Uuid;
+
+use crate::agent_backend::{
+    domain::{TurnCallback, TurnCallbackTarget, TurnCompletionNotice},
+    ports::{
+        TurnCallbackDeliveryError, TurnCallbackDeliveryResult, TurnCallbackRepository,
+        TurnCallbackRepositoryError, TurnCallbackRepositoryResult, TurnCallbackSender,
+    },
+};
+use crate::context::{RequestContext, TenantId};
+use crate::pagination::Limit;
+
+type TenantCallbacks = HashMap<TenantId, Vec<TurnCallback>>;
+
+/// Thread-safe in-memory turn callback repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTurnCallbackRepository {
+    state: Arc<RwLock<TenantCallbacks>>,
+}
+
+impl InMemoryTurnCallbackRepository {
+    /// Creates an empty in-memory repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the callbacks still waiting on `conversation_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnCallbackRepositoryError::Persistence`] when the state
+    /// lock is poisoned.
+    pub fn pending_for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+    ) -> TurnCallbackRepositoryResult<Vec<TurnCallback>> {
+        let tenants = self.read_state()?;
+        Ok(tenants
+            .get(&ctx.tenant_id())
+            .map(|callbacks| {
+                callbacks
+                    .iter()
+                    .filter(|callback| callback.conversation_id() == conversation_id)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn read_state(&self) -> TurnCallbackRepositoryResult<RwLockReadGuard<'_, TenantCallbacks>> {
+        self.state.read().map_err(|err| {
+            TurnCallbackRepositoryError::persistence(std::io::Error::other(err.to_string()))
+        })
+    }
+
+    fn write_state(&self) -> TurnCallbackRepositoryResult<RwLockWriteGuard<'_, TenantCallbacks>> {
+        self.state.write().map_err(|err| {
+            TurnCallbackRepositoryError::persistence(std::io::Error::other(err.to_string()))
+        })
+    }
+}
+
+#[async_trait]
+impl TurnCallbackRepository for InMemoryTurnCallbackRepository {
+    async fn register(
+        &self,
+        ctx: &RequestContext,
+        callback: &TurnCallback,
+    ) -> TurnCallbackRepositoryResult<()> {
+        let mut tenants = self.write_state()?;
+        let callbacks = tenants.entry(ctx.tenant_id()).or_default();
+        if callbacks
+            .iter()
+            .any(|existing| existing.id() == callback.id())
+        {
+            return Err(TurnCallbackRepositoryError::Duplicate(callback.id()));
+        }
+        callbacks.push(callback.clone());
+        Ok(())
+    }
+
+    async fn take_for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+        limit: Limit,
+    ) -> TurnCallbackRepositoryResult<Vec<TurnCallback>> {
+        let mut tenants = self.write_state()?;
+        let Some(callbacks) = tenants.get_mut(&ctx.tenant_id()) else {
+            return Ok(Vec::new());
+        };
+        let limit = usize::try_from(limit.get()).unwrap_or(usize::MAX);
+        let mut taken = Vec::new();
+        let kept = std::mem::take(callbacks)
+            .into_iter()
+            .filter_map(|callback| {
+                if taken.len() < limit && callback.conversation_id() == conversation_id {
+                    taken.push(callback);
+                    None
+                } else {
+                    Some(callback)
+                }
+            })
+            .collect();
+        *callbacks = kept;
+        Ok(taken)
+    }
+}
+
+#[derive(Debug, Default)]
+struct SenderState {
+    failures_remaining: usize,
+    attempts: usize,
+    delivered: Vec<(TurnCallbackTarget, TurnCompletionNotice)>,
+}
+
+/// Turn callback sender that records delivered notices.
+///
+/// Failures can be injected with [`Self::fail_next`] to exercise retries.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTurnCallbackSender {
+    state: Arc<RwLock<SenderState>>,
+}
+
+impl InMemoryTurnCallbackSender {
+    /// Creates a sender that accepts every notice.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes the next `count` deliveries fail.
+    pub fn fail_next(&self, count: usize) {
+        self.state
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .failures_remaining = count;
+    }
+
+    /// Returns the number of delivery attempts made, including failures.
+    #[must_use]
+    pub fn attempts(&self) -> usize {
+        self.state
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .attempts
+    }
+
+    /// Returns the notices delivered so far, with their targets.
+    #[must_use]
+    pub fn delivered(&self) -> Vec<(TurnCallbackTarget, TurnCompletionNotice)> {
+        self.state
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .delivered
+            .clone()
+    }
+}
+
+#[async_trait]
+impl TurnCallbackSender for InMemoryTurnCallbackSender {
+    async fn send(
+        &self,
+        target: &TurnCallbackTarget,
+        notice: &TurnCompletionNotice,
+    ) -> TurnCallbackDeliveryResult<()> {
+        let mut state = self.state.write().unwrap_or_else(PoisonError::into_inner);
+        state.attempts += 1;
+        if state.failures_remaining > 0 {
+            state.failures_remaining -= 1;
+            return Err(TurnCallbackDeliveryError::delivery_failed(
+                notice,
+                std::io::Error::other("injected delivery failure"),
+            ));
+        }
+        state.delivered.push((target.clone(), notice.clone()));
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+//! In-memory repository for turn outcome tests.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use uuid::Uuid;
+
+use crate::agent_backend::{
+    domain::TurnOutcomeRecord,
+    ports::{TurnOutcomeRepository, TurnOutcomeRepositoryError, TurnOutcomeRepositoryResult},
+};
+use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
+
+type TenantOutcomes = HashMap<TenantId, Vec<TurnOutcomeRecord>>;
+
+/// Thread-safe in-memory turn outcome repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTurnOutcomeRepository {
+    state: Arc<RwLock<TenantOutcomes>>,
+}
+
+impl InMemoryTurnOutcomeRepository {
+    /// Creates an empty in-memory repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn read_state(&self) -> TurnOutcomeRepositoryResult<RwLockReadGuard<'_, TenantOutcomes>> {
+        self.state.read().map_err(|err| {
+            TurnOutcomeRepositoryError::persistence(std::io::Error::other(err.to_string()))
+        })
+    }
+
+    fn write_state(&self) -> TurnOutcomeRepositoryResult<RwLockWriteGuard<'_, TenantOutcomes>> {
+        self.state.write().map_err(|err| {
+            TurnOutcomeRepositoryError::persistence(std::io::Error::other(err.to_string()))
+        })
+    }
+}
+
+#[async_trait]
+impl TurnOutcomeRepository for InMemoryTurnOutcomeRepository {
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        record: &TurnOutcomeRecord,
+    ) -> TurnOutcomeRepositoryResult<()> {
+        let mut tenants = self.write_state()?;
+        tenants
+            .entry(ctx.tenant_id())
+            .or_default()
+            .push(record.clone());
+        Ok(())
+    }
+
+    async fn list_for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+        page: PageRequest,
+    ) -> TurnOutcomeRepositoryResult<Page<TurnOutcomeRecord>> {
+        let tenants = self.read_state()?;
+        let recorded = tenants
+            .get(&ctx.tenant_id())
+            .map(|records| {
+                records
+                    .iter()
+                    .zip(0_i64..)
+                    .filter(|(record, _)| record.conversation_id == conversation_id)
+                    .map(|(record, position)| (record.clone(), position))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Page::from_ordered(recorded, page, |(_, position)| {
+            Cursor::at_number(*position, conversation_id)
+        })
+        .map(|(record, _)| record))
+    }
+}
@@ -1,4 +1,7 @@
 //! Adapter implementations for agent backend orchestration ports.
 
+mod dataset_store;
 pub mod memory;
 pub mod postgres;
+
+pub use dataset_store::ObjectStoreToolDataset;
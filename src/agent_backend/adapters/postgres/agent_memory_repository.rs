@@ -0,0 +1,203 @@
+//! `PostgreSQL` repository implementation for long-term agent memory.
+
+use super::{models::AgentMemoryFactRow, repository::BackendPgPool, schema::agent_memory_facts};
+use crate::agent_backend::{
+    domain::{BackendId, MemoryFact, MemoryFactId, PersistedMemoryFactData},
+    ports::{AgentMemoryRepository, AgentMemoryRepositoryError, AgentMemoryRepositoryResult},
+};
+use crate::context::RequestContext;
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{
+    FromTxError, TxError, ensure_tenant_exists, keyset_page, with_tenant_read_tx, with_tenant_tx,
+};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::pg::upsert::excluded;
+use diesel::prelude::*;
+
+impl FromTxError<Self> for AgentMemoryRepositoryError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed agent memory repository.
+#[derive(Debug, Clone)]
+pub struct PostgresAgentMemoryRepository {
+    pool: BackendPgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresAgentMemoryRepository,
+    "agent_memory_repository"
+);
+
+impl PostgresAgentMemoryRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: BackendPgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn run_blocking<F, T>(&self, f: F) -> AgentMemoryRepositoryResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> AgentMemoryRepositoryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = pool
+                .get()
+                .map_err(AgentMemoryRepositoryError::persistence)?;
+            f(&mut connection)
+        })
+        .await
+        .map_err(AgentMemoryRepositoryError::persistence)?
+    }
+}
+
+#[async_trait]
+impl AgentMemoryRepository for PostgresAgentMemoryRepository {
+    async fn upsert(
+        &self,
+        ctx: &RequestContext,
+        fact: &MemoryFact,
+    ) -> AgentMemoryRepositoryResult<()> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = to_row(fact, tenant_uuid);
+
+        self.run_blocking(move |connection| {
+            with_tenant_tx(connection, tenant_uuid, |tx| {
+                ensure_tenant_exists(tx, tenant_uuid)
+                    .map_err(AgentMemoryRepositoryError::persistence)?;
+                diesel::insert_into(agent_memory_facts::table)
+                    .values(&row)
+                    .on_conflict(agent_memory_facts::id)
+                    .do_update()
+                    .set((
+                        agent_memory_facts::content.eq(excluded(agent_memory_facts::content)),
+                        agent_memory_facts::confidence_percent
+                            .eq(excluded(agent_memory_facts::confidence_percent)),
+                        agent_memory_facts::embedding.eq(excluded(agent_memory_facts::embedding)),
+                        agent_memory_facts::reinforced_at
+                            .eq(excluded(agent_memory_facts::reinforced_at)),
+                        agent_memory_facts::expires_at.eq(excluded(agent_memory_facts::expires_at)),
+                    ))
+                    .execute(tx)
+                    .map_err(AgentMemoryRepositoryError::persistence)
+            })
+            .map(|_| ())
+        })
+        .await
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: MemoryFactId,
+    ) -> AgentMemoryRepositoryResult<Option<MemoryFact>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_read_tx(connection, tenant_uuid, |tx| {
+                let row = agent_memory_facts::table
+                    .filter(agent_memory_facts::id.eq(id.into_inner()))
+                    .filter(agent_memory_facts::tenant_id.eq(tenant_uuid))
+                    .select(AgentMemoryFactRow::as_select())
+                    .first::<AgentMemoryFactRow>(tx)
+                    .optional()
+                    .map_err(AgentMemoryRepositoryError::persistence)?;
+                row.map(row_to_fact).transpose()
+            })
+        })
+        .await
+    }
+
+    async fn list_for_backend(
+        &self,
+        ctx: &RequestContext,
+        backend_id: BackendId,
+        page: PageRequest,
+    ) -> AgentMemoryRepositoryResult<Page<MemoryFact>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_read_tx(connection, tenant_uuid, |tx| {
+                let query = agent_memory_facts::table
+                    .filter(agent_memory_facts::tenant_id.eq(tenant_uuid))
+                    .filter(agent_memory_facts::backend_id.eq(backend_id.into_inner()))
+                    .select(AgentMemoryFactRow::as_select())
+                    .into_boxed();
+                let rows = keyset_page!(
+                    query,
+                    page,
+                    after_timestamp,
+                    (agent_memory_facts::created_at, agent_memory_facts::id)
+                )
+                .load::<AgentMemoryFactRow>(tx)
+                .map_err(AgentMemoryRepositoryError::persistence)?;
+                Page::from_overfetched(rows, page, |row| {
+                    Cursor::at_timestamp(row.created_at, row.id)
+                })
+                .try_map(row_to_fact)
+            })
+        })
+        .await
+    }
+
+    async fn delete(
+        &self,
+        ctx: &RequestContext,
+        id: MemoryFactId,
+    ) -> AgentMemoryRepositoryResult<()> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_tx(connection, tenant_uuid, |tx| {
+                let deleted_count = diesel::delete(
+                    agent_memory_facts::table
+                        .filter(agent_memory_facts::id.eq(id.into_inner()))
+                        .filter(agent_memory_facts::tenant_id.eq(tenant_uuid)),
+                )
+                .execute(tx)
+                .map_err(AgentMemoryRepositoryError::persistence)?;
+                if deleted_count == 0 {
+                    return Err(AgentMemoryRepositoryError::NotFound(id));
+                }
+                Ok(())
+            })
+        })
+        .await
+    }
+}
+
+fn to_row(fact: &MemoryFact, tenant_id: uuid::Uuid) -> AgentMemoryFactRow {
+    AgentMemoryFactRow {
+        id: fact.id().into_inner(),
+        tenant_id,
+        backend_id: fact.backend_id().into_inner(),
+        content: fact.content().to_owned(),
+        confidence_percent: i16::from(fact.confidence_percent()),
+        embedding: fact.embedding().to_vec(),
+        source_conversation_id: fact.source_conversation_id(),
+        created_at: fact.created_at(),
+        reinforced_at: fact.reinforced_at(),
+        expires_at: fact.expires_at(),
+    }
+}
+
+fn row_to_fact(row: AgentMemoryFactRow) -> AgentMemoryRepositoryResult<MemoryFact> {
+    let confidence_percent = u8::try_from(row.confidence_percent)
+        .map_err(AgentMemoryRepositoryError::invalid_persisted_data)?;
+    Ok(MemoryFact::from_persisted(PersistedMemoryFactData {
+        id: MemoryFactId::from_uuid(row.id),
+        backend_id: BackendId::from_uuid(row.backend_id),
+        content: row.content,
+        confidence_percent,
+        embedding: row.embedding,
+        source_conversation_id: row.source_conversation_id,
+        created_at: row.created_at,
+        reinforced_at: row.reinforced_at,
+        expires_at: row.expires_at,
+    }))
+}
@@ -0,0 +1,163 @@
+//! `PostgreSQL` repository implementation for context assembly reports.
+
+use super::{
+    models::ContextAssemblyReportRow, repository::BackendPgPool, schema::context_assembly_reports,
+};
+use crate::agent_backend::{
+    domain::{BackendId, ContextAssemblyReport, MemoryRecallDecision},
+    ports::{
+        ContextAssemblyReportRepository, ContextAssemblyReportRepositoryError,
+        ContextAssemblyReportRepositoryResult,
+    },
+};
+use crate::context::RequestContext;
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{
+    FromTxError, TxError, ensure_tenant_exists, keyset_page, with_tenant_read_tx, with_tenant_tx,
+};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+impl FromTxError<Self> for ContextAssemblyReportRepositoryError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed context assembly report repository.
+#[derive(Debug, Clone)]
+pub struct PostgresContextAssemblyReportRepository {
+    pool: BackendPgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresContextAssemblyReportRepository,
+    "context_assembly_report_repository"
+);
+
+impl PostgresContextAssemblyReportRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: BackendPgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn run_blocking<F, T>(&self, f: F) -> ContextAssemblyReportRepositoryResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> ContextAssemblyReportRepositoryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = pool
+                .get()
+                .map_err(ContextAssemblyReportRepositoryError::persistence)?;
+            f(&mut connection)
+        })
+        .await
+        .map_err(ContextAssemblyReportRepositoryError::persistence)?
+    }
+}
+
+#[async_trait]
+impl ContextAssemblyReportRepository for PostgresContextAssemblyReportRepository {
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        report: &ContextAssemblyReport,
+    ) -> ContextAssemblyReportRepositoryResult<()> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = to_row(report, tenant_uuid)?;
+
+        self.run_blocking(move |connection| {
+            with_tenant_tx(connection, tenant_uuid, |tx| {
+                ensure_tenant_exists(tx, tenant_uuid)
+                    .map_err(ContextAssemblyReportRepositoryError::persistence)?;
+                diesel::insert_into(context_assembly_reports::table)
+                    .values(&row)
+                    .execute(tx)
+                    .map_err(ContextAssemblyReportRepositoryError::persistence)
+            })
+            .map(|_| ())
+        })
+        .await
+    }
+
+    async fn list_for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+        page: PageRequest,
+    ) -> ContextAssemblyReportRepositoryResult<Page<ContextAssemblyReport>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_read_tx(connection, tenant_uuid, |tx| {
+                let query = context_assembly_reports::table
+                    .filter(context_assembly_reports::tenant_id.eq(tenant_uuid))
+                    .filter(context_assembly_reports::conversation_id.eq(conversation_id))
+                    .select((
+                        context_assembly_reports::id,
+                        ContextAssemblyReportRow::as_select(),
+                    ))
+                    .into_boxed();
+                // Every row shares the conversation id, so the surrogate key
+                // alone orders the listing.
+                let rows = keyset_page!(
+                    query,
+                    page,
+                    after_number,
+                    (
+                        context_assembly_reports::id,
+                        context_assembly_reports::conversation_id
+                    )
+                )
+                .load::<(i64, ContextAssemblyReportRow)>(tx)
+                .map_err(ContextAssemblyReportRepositoryError::persistence)?;
+                Page::from_overfetched(rows, page, |(id, _)| {
+                    Cursor::at_number(*id, conversation_id)
+                })
+                .try_map(|(_, row)| row_to_report(row))
+            })
+        })
+        .await
+    }
+}
+
+fn to_row(
+    report: &ContextAssemblyReport,
+    tenant_id: Uuid,
+) -> ContextAssemblyReportRepositoryResult<ContextAssemblyReportRow> {
+    let memories = serde_json::to_value(&report.memories)
+        .map_err(ContextAssemblyReportRepositoryError::persistence)?;
+    let recall_limit = i32::try_from(report.recall_limit)
+        .map_err(ContextAssemblyReportRepositoryError::persistence)?;
+    Ok(ContextAssemblyReportRow {
+        tenant_id,
+        backend_id: report.backend_id.into_inner(),
+        conversation_id: report.conversation_id,
+        recall_limit,
+        memories,
+        assembled_at: report.assembled_at,
+    })
+}
+
+fn row_to_report(
+    row: ContextAssemblyReportRow,
+) -> ContextAssemblyReportRepositoryResult<ContextAssemblyReport> {
+    let memories = serde_json::from_value::<Vec<MemoryRecallDecision>>(row.memories)
+        .map_err(ContextAssemblyReportRepositoryError::invalid_persisted_data)?;
+    let recall_limit = usize::try_from(row.recall_limit)
+        .map_err(ContextAssemblyReportRepositoryError::invalid_persisted_data)?;
+    Ok(ContextAssemblyReport {
+        backend_id: BackendId::from_uuid(row.backend_id),
+        conversation_id: row.conversation_id,
+        recall_limit,
+        memories,
+        assembled_at: row.assembled_at,
+    })
+}
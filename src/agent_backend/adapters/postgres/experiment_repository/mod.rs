@@ -0,0 +1,238 @@
+//! `PostgreSQL` repository implementation for backend experiments.
+
+mod row_mapping;
+
+use super::{
+    models::{BackendExperimentRow, ExperimentObservationRow},
+    repository::BackendPgPool,
+    schema::{backend_experiments, experiment_observations},
+};
+use crate::agent_backend::{
+    domain::{BackendExperiment, ExperimentId, ExperimentObservation, ExperimentStatus},
+    ports::{ExperimentRepository, ExperimentRepositoryError, ExperimentRepositoryResult},
+};
+use crate::context::RequestContext;
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{
+    FromTxError, TxError, ensure_tenant_exists, keyset_page, with_tenant_read_tx, with_tenant_tx,
+};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use row_mapping::{row_to_experiment, row_to_observation, to_experiment_row, to_observation_row};
+
+impl FromTxError<Self> for ExperimentRepositoryError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed experiment repository.
+#[derive(Debug, Clone)]
+pub struct PostgresExperimentRepository {
+    pool: BackendPgPool,
+}
+
+crate::postgres_support::pool_health_check!(PostgresExperimentRepository, "experiment_repository");
+
+impl PostgresExperimentRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: BackendPgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn run_blocking<F, T>(&self, f: F) -> ExperimentRepositoryResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> ExperimentRepositoryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = pool.get().map_err(ExperimentRepositoryError::persistence)?;
+            f(&mut connection)
+        })
+        .await
+        .map_err(ExperimentRepositoryError::persistence)?
+    }
+}
+
+#[async_trait]
+impl ExperimentRepository for PostgresExperimentRepository {
+    async fn create(
+        &self,
+        ctx: &RequestContext,
+        experiment: &BackendExperiment,
+    ) -> ExperimentRepositoryResult<()> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let experiment_id = experiment.id();
+        let row = to_experiment_row(experiment, tenant_uuid)?;
+
+        self.run_blocking(move |connection| {
+            with_tenant_tx(connection, tenant_uuid, |tx| {
+                ensure_tenant_exists(tx, tenant_uuid)
+                    .map_err(ExperimentRepositoryError::persistence)?;
+                diesel::insert_into(backend_experiments::table)
+                    .values(&row)
+                    .execute(tx)
+                    .map_err(|err| match err {
+                        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                            ExperimentRepositoryError::DuplicateExperiment(experiment_id)
+                        }
+                        _ => ExperimentRepositoryError::persistence(err),
+                    })
+            })
+            .map(|_| ())
+        })
+        .await
+    }
+
+    async fn update(
+        &self,
+        ctx: &RequestContext,
+        experiment: &BackendExperiment,
+    ) -> ExperimentRepositoryResult<()> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let experiment_id = experiment.id();
+        let status = experiment.status().as_str().to_owned();
+        let concluded_at = experiment.concluded_at();
+
+        self.run_blocking(move |connection| {
+            with_tenant_tx(connection, tenant_uuid, |tx| {
+                let updated_count = diesel::update(
+                    backend_experiments::table
+                        .filter(backend_experiments::id.eq(experiment_id.into_inner()))
+                        .filter(backend_experiments::tenant_id.eq(tenant_uuid)),
+                )
+                .set((
+                    backend_experiments::status.eq(&status),
+                    backend_experiments::concluded_at.eq(concluded_at),
+                ))
+                .execute(tx)
+                .map_err(ExperimentRepositoryError::persistence)?;
+                if updated_count == 0 {
+                    return Err(ExperimentRepositoryError::NotFound(experiment_id));
+                }
+                Ok(())
+            })
+        })
+        .await
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: ExperimentId,
+    ) -> ExperimentRepositoryResult<Option<BackendExperiment>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_read_tx(connection, tenant_uuid, |tx| {
+                let row = backend_experiments::table
+                    .filter(backend_experiments::id.eq(id.into_inner()))
+                    .filter(backend_experiments::tenant_id.eq(tenant_uuid))
+                    .select(BackendExperimentRow::as_select())
+                    .first::<BackendExperimentRow>(tx)
+                    .optional()
+                    .map_err(ExperimentRepositoryError::persistence)?;
+                row.map(row_to_experiment).transpose()
+            })
+        })
+        .await
+    }
+
+    async fn list_running(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> ExperimentRepositoryResult<Page<BackendExperiment>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_read_tx(connection, tenant_uuid, |tx| {
+                let query = backend_experiments::table
+                    .filter(backend_experiments::tenant_id.eq(tenant_uuid))
+                    .filter(backend_experiments::status.eq(ExperimentStatus::Running.as_str()))
+                    .select(BackendExperimentRow::as_select())
+                    .into_boxed();
+                let rows = keyset_page!(
+                    query,
+                    page,
+                    after_timestamp,
+                    (backend_experiments::created_at, backend_experiments::id)
+                )
+                .load::<BackendExperimentRow>(tx)
+                .map_err(ExperimentRepositoryError::persistence)?;
+                Page::from_overfetched(rows, page, |row| {
+                    Cursor::at_timestamp(row.created_at, row.id)
+                })
+                .try_map(row_to_experiment)
+            })
+        })
+        .await
+    }
+
+    async fn record_observation(
+        &self,
+        ctx: &RequestContext,
+        observation: &ExperimentObservation,
+    ) -> ExperimentRepositoryResult<()> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let experiment_id = observation.experiment_id;
+        let row = to_observation_row(observation, tenant_uuid)?;
+
+        self.run_blocking(move |connection| {
+            with_tenant_tx(connection, tenant_uuid, |tx| {
+                diesel::insert_into(experiment_observations::table)
+                    .values(&row)
+                    .execute(tx)
+                    .map_err(|err| match err {
+                        DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+                            ExperimentRepositoryError::NotFound(experiment_id)
+                        }
+                        _ => ExperimentRepositoryError::persistence(err),
+                    })
+            })
+            .map(|_| ())
+        })
+        .await
+    }
+
+    async fn list_observations(
+        &self,
+        ctx: &RequestContext,
+        id: ExperimentId,
+        page: PageRequest,
+    ) -> ExperimentRepositoryResult<Page<ExperimentObservation>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_read_tx(connection, tenant_uuid, |tx| {
+                let query = experiment_observations::table
+                    .filter(experiment_observations::tenant_id.eq(tenant_uuid))
+                    .filter(experiment_observations::experiment_id.eq(id.into_inner()))
+                    .select(ExperimentObservationRow::as_select())
+                    .into_boxed();
+                // Every row shares the experiment id, so the surrogate key
+                // alone orders the listing.
+                let rows = keyset_page!(
+                    query,
+                    page,
+                    after_number,
+                    (
+                        experiment_observations::id,
+                        experiment_observations::experiment_id
+                    )
+                )
+                .load::<ExperimentObservationRow>(tx)
+                .map_err(ExperimentRepositoryError::persistence)?;
+                Page::from_overfetched(rows, page, |row| {
+                    Cursor::at_number(row.id, row.experiment_id)
+                })
+                .try_map(row_to_observation)
+            })
+        })
+        .await
+    }
+}
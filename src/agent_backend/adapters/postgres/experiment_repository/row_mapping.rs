@@ -0,0 +1,86 @@
+//! Row mapping helpers for experiment persistence.
+
+use super::super::models::{
+    BackendExperimentRow, ExperimentObservationRow, NewExperimentObservationRow,
+};
+use crate::agent_backend::domain::{
+    BackendExperiment, ExperimentArm, ExperimentArmKey, ExperimentId, ExperimentObservation,
+    ExperimentSignal, ExperimentStatus, PersistedExperimentData,
+};
+use crate::agent_backend::ports::{ExperimentRepositoryError, ExperimentRepositoryResult};
+
+pub(super) fn to_experiment_row(
+    experiment: &BackendExperiment,
+    tenant_id: uuid::Uuid,
+) -> ExperimentRepositoryResult<BackendExperimentRow> {
+    let control_arm = serde_json::to_value(experiment.control())
+        .map_err(ExperimentRepositoryError::persistence)?;
+    let treatment_arm = serde_json::to_value(experiment.treatment())
+        .map_err(ExperimentRepositoryError::persistence)?;
+    Ok(BackendExperimentRow {
+        id: experiment.id().into_inner(),
+        tenant_id,
+        name: experiment.name().to_owned(),
+        control_arm,
+        treatment_arm,
+        treatment_percent: i16::from(experiment.treatment_percent()),
+        status: experiment.status().as_str().to_owned(),
+        created_at: experiment.created_at(),
+        concluded_at: experiment.concluded_at(),
+    })
+}
+
+pub(super) fn row_to_experiment(
+    row: BackendExperimentRow,
+) -> ExperimentRepositoryResult<BackendExperiment> {
+    let control: ExperimentArm = serde_json::from_value(row.control_arm)
+        .map_err(ExperimentRepositoryError::invalid_persisted_data)?;
+    let treatment: ExperimentArm = serde_json::from_value(row.treatment_arm)
+        .map_err(ExperimentRepositoryError::invalid_persisted_data)?;
+    let treatment_percent = u8::try_from(row.treatment_percent)
+        .map_err(ExperimentRepositoryError::invalid_persisted_data)?;
+    let status = ExperimentStatus::try_from(row.status.as_str())
+        .map_err(ExperimentRepositoryError::invalid_persisted_data)?;
+    Ok(BackendExperiment::from_persisted(PersistedExperimentData {
+        id: ExperimentId::from_uuid(row.id),
+        name: row.name,
+        control,
+        treatment,
+        treatment_percent,
+        status,
+        created_at: row.created_at,
+        concluded_at: row.concluded_at,
+    }))
+}
+
+pub(super) fn to_observation_row(
+    observation: &ExperimentObservation,
+    tenant_id: uuid::Uuid,
+) -> ExperimentRepositoryResult<NewExperimentObservationRow> {
+    let signal =
+        serde_json::to_value(observation.signal).map_err(ExperimentRepositoryError::persistence)?;
+    Ok(NewExperimentObservationRow {
+        tenant_id,
+        experiment_id: observation.experiment_id.into_inner(),
+        arm: observation.arm.as_str().to_owned(),
+        conversation_id: observation.conversation_id,
+        signal,
+        observed_at: observation.observed_at,
+    })
+}
+
+pub(super) fn row_to_observation(
+    row: ExperimentObservationRow,
+) -> ExperimentRepositoryResult<ExperimentObservation> {
+    let arm = ExperimentArmKey::try_from(row.arm.as_str())
+        .map_err(ExperimentRepositoryError::invalid_persisted_data)?;
+    let signal: ExperimentSignal = serde_json::from_value(row.signal)
+        .map_err(ExperimentRepositoryError::invalid_persisted_data)?;
+    Ok(ExperimentObservation {
+        experiment_id: ExperimentId::from_uuid(row.experiment_id),
+        arm,
+        conversation_id: row.conversation_id,
+        signal,
+        observed_at: row.observed_at,
+    })
+}
@@ -1,9 +1,19 @@
 //! `PostgreSQL` adapters for agent backend orchestration persistence.
 
+mod agent_memory_repository;
+mod context_assembly_repository;
+mod experiment_repository;
 mod models;
 mod repository;
 mod schema;
+mod turn_callback_repository;
+mod turn_outcome_repository;
 mod turn_session_repository;
 
+pub use agent_memory_repository::PostgresAgentMemoryRepository;
+pub use context_assembly_repository::PostgresContextAssemblyReportRepository;
+pub use experiment_repository::PostgresExperimentRepository;
 pub use repository::{BackendPgPool, PostgresBackendRegistry};
+pub use turn_callback_repository::PostgresTurnCallbackRepository;
+pub use turn_outcome_repository::PostgresTurnOutcomeRepository;
 pub use turn_session_repository::PostgresTurnSessionRepository;
@@ -1,6 +1,9 @@
 //! Diesel row models for agent backend orchestration persistence.
 
-use super::schema::{agent_turn_sessions, backend_registrations};
+use super::schema::{
+    agent_memory_facts, agent_turn_callbacks, agent_turn_outcomes, agent_turn_sessions,
+    backend_experiments, backend_registrations, context_assembly_reports, experiment_observations,
+};
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use serde_json::Value;
@@ -130,3 +133,151 @@ pub struct NewAgentTurnSessionRow {
     /// Number of successful turns in the session.
     pub turn_count: i64,
 }
+
+/// Row model for backend experiment records.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = backend_experiments)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct BackendExperimentRow {
+    /// Experiment identifier.
+    pub id: uuid::Uuid,
+    /// Tenant identifier owning this experiment.
+    pub tenant_id: uuid::Uuid,
+    /// Human-readable experiment name.
+    pub name: String,
+    /// Control arm JSON payload.
+    pub control_arm: Value,
+    /// Treatment arm JSON payload.
+    pub treatment_arm: Value,
+    /// Share of conversations assigned to the treatment arm.
+    pub treatment_percent: i16,
+    /// Lifecycle status.
+    pub status: String,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Conclusion timestamp.
+    pub concluded_at: Option<DateTime<Utc>>,
+}
+
+/// Query result row for experiment observations.
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = experiment_observations)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ExperimentObservationRow {
+    /// Surrogate key preserving insertion order.
+    pub id: i64,
+    /// Observed experiment identifier.
+    pub experiment_id: uuid::Uuid,
+    /// Arm the conversation was assigned to.
+    pub arm: String,
+    /// Observed conversation identifier.
+    pub conversation_id: uuid::Uuid,
+    /// Serialized observation signal.
+    pub signal: Value,
+    /// Observation timestamp.
+    pub observed_at: DateTime<Utc>,
+}
+
+/// Insert model for experiment observations.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = experiment_observations)]
+pub struct NewExperimentObservationRow {
+    /// Tenant identifier owning this observation.
+    pub tenant_id: uuid::Uuid,
+    /// Observed experiment identifier.
+    pub experiment_id: uuid::Uuid,
+    /// Arm the conversation was assigned to.
+    pub arm: String,
+    /// Observed conversation identifier.
+    pub conversation_id: uuid::Uuid,
+    /// Serialized observation signal.
+    pub signal: Value,
+    /// Observation timestamp.
+    pub observed_at: DateTime<Utc>,
+}
+
+/// Query and insert row for long-term agent memory facts.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = agent_memory_facts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AgentMemoryFactRow {
+    /// Fact identifier.
+    pub id: uuid::Uuid,
+    /// Tenant identifier owning this fact.
+    pub tenant_id: uuid::Uuid,
+    /// Backend the fact is remembered for.
+    pub backend_id: uuid::Uuid,
+    /// The fact itself.
+    pub content: String,
+    /// Confidence when last reinforced, in percent.
+    pub confidence_percent: i16,
+    /// Embedding of the content.
+    pub embedding: Vec<f32>,
+    /// Conversation the fact was first extracted from.
+    pub source_conversation_id: Option<uuid::Uuid>,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp decay is measured from.
+    pub reinforced_at: DateTime<Utc>,
+    /// Expiry timestamp.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Query and insert row for turn outcomes.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = agent_turn_outcomes)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AgentTurnOutcomeRow {
+    /// Tenant identifier owning this outcome.
+    pub tenant_id: uuid::Uuid,
+    /// Backend the turn ran on.
+    pub backend_id: uuid::Uuid,
+    /// Conversation the turn belongs to.
+    pub conversation_id: uuid::Uuid,
+    /// Session the turn ran in.
+    pub session_id: Option<uuid::Uuid>,
+    /// Outcome kind.
+    pub kind: String,
+    /// Serialized outcome.
+    pub outcome: Value,
+    /// Recording timestamp.
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Query and insert row for turn callbacks.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = agent_turn_callbacks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AgentTurnCallbackRow {
+    /// Tenant identifier owning this callback.
+    pub tenant_id: uuid::Uuid,
+    /// Callback identifier.
+    pub id: uuid::Uuid,
+    /// Conversation the callback waits on.
+    pub conversation_id: uuid::Uuid,
+    /// Target kind.
+    pub target_kind: String,
+    /// Webhook URL or reply subject.
+    pub target: String,
+    /// Registration timestamp.
+    pub registered_at: DateTime<Utc>,
+}
+
+/// Query and insert row for context assembly reports.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = context_assembly_reports)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ContextAssemblyReportRow {
+    /// Tenant identifier owning this report.
+    pub tenant_id: uuid::Uuid,
+    /// Backend the turn ran on.
+    pub backend_id: uuid::Uuid,
+    /// Conversation the turn belongs to.
+    pub conversation_id: uuid::Uuid,
+    /// How many facts could be recalled.
+    pub recall_limit: i32,
+    /// Serialized memory recall decisions.
+    pub memories: Value,
+    /// Assembly timestamp.
+    pub assembled_at: DateTime<Utc>,
+}
@@ -12,8 +12,9 @@ use crate::agent_backend::{
     ports::{BackendRegistryError, BackendRegistryRepository, BackendRegistryResult},
 };
 use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
 use crate::postgres_support::{
-    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+    FromTxError, TxError, ensure_tenant_exists, keyset_page, with_tenant_read_tx, with_tenant_tx,
 };
 use async_trait::async_trait;
 use diesel::pg::PgConnection;
@@ -39,6 +40,8 @@ pub struct PostgresBackendRegistry {
     pool: BackendPgPool,
 }
 
+crate::postgres_support::pool_health_check!(PostgresBackendRegistry, "backend_registry");
+
 impl PostgresBackendRegistry {
     /// Creates a new repository from a `PostgreSQL` connection pool.
     #[must_use]
@@ -59,6 +62,23 @@ impl PostgresBackendRegistry {
         .await
         .map_err(BackendRegistryError::persistence)?
     }
+
+    /// Loads a page of the tenant's backends in registration order,
+    /// optionally restricted to one status.
+    async fn list_page(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+        status: Option<BackendStatus>,
+    ) -> BackendRegistryResult<Page<AgentBackendRegistration>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_read_tx(connection, tenant_uuid, |tx| {
+                load_backend_page(tx, tenant_uuid, page, status)
+            })
+        })
+        .await
+    }
 }
 
 #[async_trait]
@@ -183,41 +203,48 @@ impl BackendRegistryRepository for PostgresBackendRegistry {
     async fn list_active(
         &self,
         ctx: &RequestContext,
-    ) -> BackendRegistryResult<Vec<AgentBackendRegistration>> {
-        let tenant_id = ctx.tenant_id();
-        let tenant_uuid = tenant_id.into_inner();
-        self.run_blocking(move |connection| {
-            with_tenant_read_tx(connection, tenant_uuid, |tx| {
-                let rows = backend_registrations::table
-                    .filter(backend_registrations::tenant_id.eq(tenant_uuid))
-                    .filter(backend_registrations::status.eq(BackendStatus::Active.as_str()))
-                    .select(BackendRegistrationRow::as_select())
-                    .load::<BackendRegistrationRow>(tx)
-                    .map_err(BackendRegistryError::persistence)?;
-                rows.into_iter().map(row_to_registration).collect()
-            })
-        })
-        .await
+        page: PageRequest,
+    ) -> BackendRegistryResult<Page<AgentBackendRegistration>> {
+        self.list_page(ctx, page, Some(BackendStatus::Active)).await
     }
 
     async fn list_all(
         &self,
         ctx: &RequestContext,
-    ) -> BackendRegistryResult<Vec<AgentBackendRegistration>> {
-        let tenant_id = ctx.tenant_id();
-        let tenant_uuid = tenant_id.into_inner();
-        self.run_blocking(move |connection| {
-            with_tenant_read_tx(connection, tenant_uuid, |tx| {
-                let rows = backend_registrations::table
-                    .filter(backend_registrations::tenant_id.eq(tenant_uuid))
-                    .select(BackendRegistrationRow::as_select())
-                    .load::<BackendRegistrationRow>(tx)
-                    .map_err(BackendRegistryError::persistence)?;
-                rows.into_iter().map(row_to_registration).collect()
-            })
-        })
-        .await
+        page: PageRequest,
+    ) -> BackendRegistryResult<Page<AgentBackendRegistration>> {
+        self.list_page(ctx, page, None).await
+    }
+}
+
+/// Loads a page of a tenant's backends in registration order, optionally
+/// restricted to one status.
+fn load_backend_page(
+    tx: &mut PgConnection,
+    tenant_uuid: uuid::Uuid,
+    page: PageRequest,
+    status: Option<BackendStatus>,
+) -> BackendRegistryResult<Page<AgentBackendRegistration>> {
+    let mut query = backend_registrations::table
+        .filter(backend_registrations::tenant_id.eq(tenant_uuid))
+        .select(BackendRegistrationRow::as_select())
+        .into_boxed();
+    if let Some(required) = status {
+        query = query.filter(backend_registrations::status.eq(required.as_str()));
     }
+    let paged = keyset_page!(
+        query,
+        page,
+        after_timestamp,
+        (backend_registrations::created_at, backend_registrations::id)
+    );
+    let rows = paged
+        .load::<BackendRegistrationRow>(tx)
+        .map_err(BackendRegistryError::persistence)?;
+    Page::from_overfetched(rows, page, |row| {
+        Cursor::at_timestamp(row.created_at, row.id)
+    })
+    .try_map(row_to_registration)
 }
 
 /// Mutable fields serialized to database-compatible types.
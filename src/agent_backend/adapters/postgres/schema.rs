@@ -55,3 +55,137 @@ diesel::table! {
         turn_count -> BigInt,
     }
 }
+
+diesel::table! {
+    /// Backend A/B experiment records.
+    backend_experiments (id) {
+        /// Experiment identifier.
+        id -> Uuid,
+        /// Tenant identifier owning this experiment.
+        tenant_id -> Uuid,
+        /// Human-readable experiment name.
+        name -> Text,
+        /// Control arm as JSONB.
+        control_arm -> Jsonb,
+        /// Treatment arm as JSONB.
+        treatment_arm -> Jsonb,
+        /// Share of conversations assigned to the treatment arm.
+        treatment_percent -> SmallInt,
+        /// Lifecycle status (`running` or `concluded`).
+        #[max_length = 20]
+        status -> Varchar,
+        /// Creation timestamp.
+        created_at -> Timestamptz,
+        /// Conclusion timestamp.
+        concluded_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    /// Experiment outcome observations.
+    experiment_observations (id) {
+        /// Surrogate key preserving insertion order.
+        id -> BigInt,
+        /// Tenant identifier owning this observation.
+        tenant_id -> Uuid,
+        /// Observed experiment identifier.
+        experiment_id -> Uuid,
+        /// Arm the conversation was assigned to.
+        #[max_length = 20]
+        arm -> Varchar,
+        /// Observed conversation identifier.
+        conversation_id -> Uuid,
+        /// Serialized observation signal.
+        signal -> Jsonb,
+        /// Observation timestamp.
+        observed_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// Long-term agent memory facts.
+    agent_memory_facts (id) {
+        /// Fact identifier.
+        id -> Uuid,
+        /// Tenant identifier owning this fact.
+        tenant_id -> Uuid,
+        /// Backend the fact is remembered for.
+        backend_id -> Uuid,
+        /// The fact itself.
+        content -> Text,
+        /// Confidence when last reinforced, in percent.
+        confidence_percent -> SmallInt,
+        /// Embedding of the content.
+        embedding -> Array<Float4>,
+        /// Conversation the fact was first extracted from.
+        source_conversation_id -> Nullable<Uuid>,
+        /// Creation timestamp.
+        created_at -> Timestamptz,
+        /// Timestamp decay is measured from.
+        reinforced_at -> Timestamptz,
+        /// Expiry timestamp.
+        expires_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    /// One-shot callbacks waiting for a conversation's next turn to end.
+    agent_turn_callbacks (tenant_id, id) {
+        /// Tenant identifier owning this callback.
+        tenant_id -> Uuid,
+        /// Callback identifier.
+        id -> Uuid,
+        /// Conversation the callback waits on.
+        conversation_id -> Uuid,
+        /// Target kind.
+        #[max_length = 32]
+        target_kind -> Varchar,
+        /// Webhook URL or reply subject.
+        target -> Text,
+        /// Registration timestamp.
+        registered_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// Outcomes of orchestrated agent turns.
+    agent_turn_outcomes (id) {
+        /// Surrogate key preserving insertion order.
+        id -> BigInt,
+        /// Tenant identifier owning this outcome.
+        tenant_id -> Uuid,
+        /// Backend the turn ran on.
+        backend_id -> Uuid,
+        /// Conversation the turn belongs to.
+        conversation_id -> Uuid,
+        /// Session the turn ran in.
+        session_id -> Nullable<Uuid>,
+        /// Outcome kind.
+        #[max_length = 32]
+        kind -> Varchar,
+        /// Serialized outcome.
+        outcome -> Jsonb,
+        /// Recording timestamp.
+        recorded_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// Explanations of how orchestrated turns' prompts were assembled.
+    context_assembly_reports (id) {
+        /// Surrogate key preserving insertion order.
+        id -> BigInt,
+        /// Tenant identifier owning this report.
+        tenant_id -> Uuid,
+        /// Backend the turn ran on.
+        backend_id -> Uuid,
+        /// Conversation the turn belongs to.
+        conversation_id -> Uuid,
+        /// How many facts could be recalled.
+        recall_limit -> Integer,
+        /// Serialized memory recall decisions.
+        memories -> Jsonb,
+        /// Assembly timestamp.
+        assembled_at -> Timestamptz,
+    }
+}
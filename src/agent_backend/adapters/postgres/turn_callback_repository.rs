@@ -0,0 +1,147 @@
+//! `PostgreSQL` repository implementation for turn completion callbacks.
+
+use super::{
+    models::AgentTurnCallbackRow, repository::BackendPgPool, schema::agent_turn_callbacks,
+};
+use crate::agent_backend::{
+    domain::{TurnCallback, TurnCallbackId, TurnCallbackTarget},
+    ports::{TurnCallbackRepository, TurnCallbackRepositoryError, TurnCallbackRepositoryResult},
+};
+use crate::context::RequestContext;
+use crate::pagination::Limit;
+use crate::postgres_support::{FromTxError, TxError, ensure_tenant_exists, with_tenant_tx};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+impl FromTxError<Self> for TurnCallbackRepositoryError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed turn callback repository.
+#[derive(Debug, Clone)]
+pub struct PostgresTurnCallbackRepository {
+    pool: BackendPgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresTurnCallbackRepository,
+    "turn_callback_repository"
+);
+
+impl PostgresTurnCallbackRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: BackendPgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn run_blocking<F, T>(&self, f: F) -> TurnCallbackRepositoryResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> TurnCallbackRepositoryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = pool
+                .get()
+                .map_err(TurnCallbackRepositoryError::persistence)?;
+            f(&mut connection)
+        })
+        .await
+        .map_err(TurnCallbackRepositoryError::persistence)?
+    }
+}
+
+#[async_trait]
+impl TurnCallbackRepository for PostgresTurnCallbackRepository {
+    async fn register(
+        &self,
+        ctx: &RequestContext,
+        callback: &TurnCallback,
+    ) -> TurnCallbackRepositoryResult<()> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let callback_id = callback.id();
+        let row = to_row(callback, tenant_uuid);
+
+        self.run_blocking(move |connection| {
+            with_tenant_tx(connection, tenant_uuid, |tx| {
+                ensure_tenant_exists(tx, tenant_uuid)
+                    .map_err(TurnCallbackRepositoryError::persistence)?;
+                diesel::insert_into(agent_turn_callbacks::table)
+                    .values(&row)
+                    .execute(tx)
+                    .map_err(|err| match err {
+                        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                            TurnCallbackRepositoryError::Duplicate(callback_id)
+                        }
+                        _ => TurnCallbackRepositoryError::persistence(err),
+                    })
+            })
+            .map(|_| ())
+        })
+        .await
+    }
+
+    async fn take_for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+        limit: Limit,
+    ) -> TurnCallbackRepositoryResult<Vec<TurnCallback>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_tx(connection, tenant_uuid, |tx| {
+                let oldest = agent_turn_callbacks::table
+                    .filter(agent_turn_callbacks::tenant_id.eq(tenant_uuid))
+                    .filter(agent_turn_callbacks::conversation_id.eq(conversation_id))
+                    .order((
+                        agent_turn_callbacks::registered_at.asc(),
+                        agent_turn_callbacks::id.asc(),
+                    ))
+                    .select(agent_turn_callbacks::id)
+                    .limit(i64::from(limit.get()));
+                let mut rows = diesel::delete(
+                    agent_turn_callbacks::table
+                        .filter(agent_turn_callbacks::tenant_id.eq(tenant_uuid))
+                        .filter(agent_turn_callbacks::id.eq_any(oldest)),
+                )
+                .returning(AgentTurnCallbackRow::as_returning())
+                .get_results::<AgentTurnCallbackRow>(tx)
+                .map_err(TurnCallbackRepositoryError::persistence)?;
+                rows.sort_by_key(|row| (row.registered_at, row.id));
+                rows.into_iter().map(row_to_callback).collect()
+            })
+        })
+        .await
+    }
+}
+
+fn to_row(callback: &TurnCallback, tenant_id: Uuid) -> AgentTurnCallbackRow {
+    AgentTurnCallbackRow {
+        tenant_id,
+        id: callback.id().into_inner(),
+        conversation_id: callback.conversation_id(),
+        target_kind: callback.target().kind().to_owned(),
+        target: callback.target().address().to_owned(),
+        registered_at: callback.registered_at(),
+    }
+}
+
+fn row_to_callback(row: AgentTurnCallbackRow) -> TurnCallbackRepositoryResult<TurnCallback> {
+    let target = TurnCallbackTarget::from_parts(&row.target_kind, &row.target)
+        .map_err(TurnCallbackRepositoryError::invalid_persisted_data)?;
+    Ok(TurnCallback::from_persisted(
+        TurnCallbackId::from_uuid(row.id),
+        row.conversation_id,
+        target,
+        row.registered_at,
+    ))
+}
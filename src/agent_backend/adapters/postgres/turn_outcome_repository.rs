@@ -0,0 +1,160 @@
+//! `PostgreSQL` repository implementation for turn outcomes.
+
+use super::{models::AgentTurnOutcomeRow, repository::BackendPgPool, schema::agent_turn_outcomes};
+use crate::agent_backend::{
+    domain::{BackendId, TurnOutcome, TurnOutcomeKind, TurnOutcomeRecord, TurnSessionId},
+    ports::{TurnOutcomeRepository, TurnOutcomeRepositoryError, TurnOutcomeRepositoryResult},
+};
+use crate::context::RequestContext;
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{
+    FromTxError, TxError, ensure_tenant_exists, keyset_page, with_tenant_read_tx, with_tenant_tx,
+};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+impl FromTxError<Self> for TurnOutcomeRepositoryError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed turn outcome repository.
+#[derive(Debug, Clone)]
+pub struct PostgresTurnOutcomeRepository {
+    pool: BackendPgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresTurnOutcomeRepository,
+    "turn_outcome_repository"
+);
+
+impl PostgresTurnOutcomeRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: BackendPgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn run_blocking<F, T>(&self, f: F) -> TurnOutcomeRepositoryResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> TurnOutcomeRepositoryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = pool
+                .get()
+                .map_err(TurnOutcomeRepositoryError::persistence)?;
+            f(&mut connection)
+        })
+        .await
+        .map_err(TurnOutcomeRepositoryError::persistence)?
+    }
+}
+
+#[async_trait]
+impl TurnOutcomeRepository for PostgresTurnOutcomeRepository {
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        record: &TurnOutcomeRecord,
+    ) -> TurnOutcomeRepositoryResult<()> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = to_row(record, tenant_uuid)?;
+
+        self.run_blocking(move |connection| {
+            with_tenant_tx(connection, tenant_uuid, |tx| {
+                ensure_tenant_exists(tx, tenant_uuid)
+                    .map_err(TurnOutcomeRepositoryError::persistence)?;
+                diesel::insert_into(agent_turn_outcomes::table)
+                    .values(&row)
+                    .execute(tx)
+                    .map_err(TurnOutcomeRepositoryError::persistence)
+            })
+            .map(|_| ())
+        })
+        .await
+    }
+
+    async fn list_for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+        page: PageRequest,
+    ) -> TurnOutcomeRepositoryResult<Page<TurnOutcomeRecord>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_read_tx(connection, tenant_uuid, |tx| {
+                let query = agent_turn_outcomes::table
+                    .filter(agent_turn_outcomes::tenant_id.eq(tenant_uuid))
+                    .filter(agent_turn_outcomes::conversation_id.eq(conversation_id))
+                    .select((agent_turn_outcomes::id, AgentTurnOutcomeRow::as_select()))
+                    .into_boxed();
+                // Every row shares the conversation id, so the surrogate key
+                // alone orders the listing.
+                let rows = keyset_page!(
+                    query,
+                    page,
+                    after_number,
+                    (
+                        agent_turn_outcomes::id,
+                        agent_turn_outcomes::conversation_id
+                    )
+                )
+                .load::<(i64, AgentTurnOutcomeRow)>(tx)
+                .map_err(TurnOutcomeRepositoryError::persistence)?;
+                Page::from_overfetched(rows, page, |(id, _)| {
+                    Cursor::at_number(*id, conversation_id)
+                })
+                .try_map(|(_, row)| row_to_record(row))
+            })
+        })
+        .await
+    }
+}
+
+fn to_row(
+    record: &TurnOutcomeRecord,
+    tenant_id: Uuid,
+) -> TurnOutcomeRepositoryResult<AgentTurnOutcomeRow> {
+    let outcome =
+        serde_json::to_value(&record.outcome).map_err(TurnOutcomeRepositoryError::persistence)?;
+    Ok(AgentTurnOutcomeRow {
+        tenant_id,
+        backend_id: record.backend_id.into_inner(),
+        conversation_id: record.conversation_id,
+        session_id: record.session_id.map(TurnSessionId::into_inner),
+        kind: record.outcome.kind().as_str().to_owned(),
+        outcome,
+        recorded_at: record.recorded_at,
+    })
+}
+
+fn row_to_record(row: AgentTurnOutcomeRow) -> TurnOutcomeRepositoryResult<TurnOutcomeRecord> {
+    let kind = TurnOutcomeKind::try_from(row.kind.as_str())
+        .map_err(TurnOutcomeRepositoryError::invalid_persisted_data)?;
+    let outcome = serde_json::from_value::<TurnOutcome>(row.outcome)
+        .map_err(TurnOutcomeRepositoryError::invalid_persisted_data)?;
+    if outcome.kind() != kind {
+        return Err(TurnOutcomeRepositoryError::invalid_persisted_data(
+            std::io::Error::other(format!(
+                "turn outcome kind {kind} does not match serialized {}",
+                outcome.kind()
+            )),
+        ));
+    }
+    Ok(TurnOutcomeRecord {
+        backend_id: BackendId::from_uuid(row.backend_id),
+        conversation_id: row.conversation_id,
+        session_id: row.session_id.map(TurnSessionId::from_uuid),
+        outcome,
+        recorded_at: row.recorded_at,
+    })
+}
@@ -28,6 +28,11 @@ pub struct PostgresTurnSessionRepository {
     pool: BackendPgPool,
 }
 
+crate::postgres_support::pool_health_check!(
+    PostgresTurnSessionRepository,
+    "turn_session_repository"
+);
+
 impl PostgresTurnSessionRepository {
     /// Creates a new repository from a `PostgreSQL` connection pool.
     #[must_use]
@@ -0,0 +1,90 @@
+//! Confidence decay for remembered facts.
+
+use super::{MemoryDomainError, checked_confidence};
+use chrono::Duration;
+
+/// How quickly remembered facts fade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryDecayPolicy {
+    half_life: Duration,
+    forget_below_percent: u8,
+}
+
+impl MemoryDecayPolicy {
+    /// Creates a decay policy.
+    ///
+    /// Facts whose decayed confidence falls below `forget_below_percent` are
+    /// treated as forgotten.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MemoryDomainError::InvalidHalfLife`] when `half_life` is not
+    /// at least one second, or [`MemoryDomainError::InvalidConfidence`] when
+    /// `forget_below_percent` exceeds 100.
+    pub fn new(half_life: Duration, forget_below_percent: u8) -> Result<Self, MemoryDomainError> {
+        let seconds = half_life.num_seconds();
+        if seconds <= 0 {
+            return Err(MemoryDomainError::InvalidHalfLife(seconds));
+        }
+        Ok(Self {
+            half_life,
+            forget_below_percent: checked_confidence(forget_below_percent)?,
+        })
+    }
+
+    /// Returns the time it takes a fact's confidence to halve.
+    #[must_use]
+    pub const fn half_life(self) -> Duration {
+        self.half_life
+    }
+
+    /// Returns the confidence below which facts are forgotten.
+    #[must_use]
+    pub const fn forget_below_percent(self) -> u8 {
+        self.forget_below_percent
+    }
+
+    /// Returns `confidence` after decaying for `elapsed`.
+    ///
+    /// Confidence halves once per half-life and falls linearly in between,
+    /// so it never increases with time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use corbusier::agent_backend::domain::MemoryDecayPolicy;
+    ///
+    /// let policy = MemoryDecayPolicy::new(Duration::days(10), 5).expect("valid policy");
+    /// assert_eq!(policy.decay(80, Duration::days(10)), 40);
+    /// assert_eq!(policy.decay(80, Duration::days(15)), 30);
+    /// ```
+    #[must_use]
+    pub fn decay(self, confidence: u8, elapsed: Duration) -> u8 {
+        let half_life = self.half_life.num_seconds().max(1).unsigned_abs();
+        let elapsed_seconds = elapsed.num_seconds().max(0).unsigned_abs();
+        let halvings = elapsed_seconds.checked_div(half_life).unwrap_or(0);
+        let Some(upper) = u32::try_from(halvings)
+            .ok()
+            .and_then(|shift| u64::from(confidence).checked_shr(shift))
+        else {
+            return 0;
+        };
+        let lower = upper >> 1;
+        let into_half_life = elapsed_seconds.checked_rem(half_life).unwrap_or(0);
+        let faded = (upper - lower)
+            .saturating_mul(into_half_life)
+            .checked_div(half_life)
+            .unwrap_or(0);
+        u8::try_from(upper - faded).unwrap_or(0)
+    }
+}
+
+impl Default for MemoryDecayPolicy {
+    fn default() -> Self {
+        Self {
+            half_life: Duration::days(30),
+            forget_below_percent: 5,
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Facts held in long-term agent memory.
+
+use super::{
+    MAX_MEMORY_CONFIDENCE, MemoryDecayPolicy, MemoryDomainError, MemoryFactDraft,
+    checked_confidence, checked_content, checked_embedding, recall::cosine_similarity_percent,
+};
+use crate::agent_backend::domain::{BackendId, MemoryFactId};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use uuid::Uuid;
+
+/// Persisted memory fields used to rebuild a [`MemoryFact`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersistedMemoryFactData {
+    /// Fact identifier.
+    pub id: MemoryFactId,
+    /// Backend the fact is remembered for.
+    pub backend_id: BackendId,
+    /// The fact itself.
+    pub content: String,
+    /// Confidence when last reinforced, in percent.
+    pub confidence_percent: u8,
+    /// Embedding of the content.
+    pub embedding: Vec<f32>,
+    /// Conversation the fact was first extracted from.
+    pub source_conversation_id: Option<Uuid>,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp decay is measured from.
+    pub reinforced_at: DateTime<Utc>,
+    /// Timestamp after which the fact is forgotten.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// A fact held in long-term agent memory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryFact {
+    id: MemoryFactId,
+    backend_id: BackendId,
+    content: String,
+    confidence_percent: u8,
+    embedding: Vec<f32>,
+    source_conversation_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    reinforced_at: DateTime<Utc>,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl MemoryFact {
+    /// Remembers a drafted fact for a backend.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MemoryDomainError::EmptyEmbedding`] when `embedding` is
+    /// empty.
+    pub fn new(
+        backend_id: BackendId,
+        draft: MemoryFactDraft,
+        embedding: Vec<f32>,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<Self, MemoryDomainError> {
+        let now = clock.utc();
+        Ok(Self {
+            id: MemoryFactId::new(),
+            backend_id,
+            content: checked_content(draft.content)?,
+            confidence_percent: checked_confidence(draft.confidence_percent)?,
+            embedding: checked_embedding(embedding)?,
+            source_conversation_id: None,
+            created_at: now,
+            reinforced_at: now,
+            expires_at: draft.expires_after.map(|lifetime| now + lifetime),
+        })
+    }
+
+    /// Rebuilds a fact from persisted data.
+    #[must_use]
+    pub fn from_persisted(data: PersistedMemoryFactData) -> Self {
+        Self {
+            id: data.id,
+            backend_id: data.backend_id,
+            content: data.content,
+            confidence_percent: data.confidence_percent,
+            embedding: data.embedding,
+            source_conversation_id: data.source_conversation_id,
+            created_at: data.created_at,
+            reinforced_at: data.reinforced_at,
+            expires_at: data.expires_at,
+        }
+    }
+
+    /// Records the conversation the fact was extracted from.
+    #[must_use]
+    pub const fn with_source_conversation(mut self, conversation_id: Uuid) -> Self {
+        self.source_conversation_id = Some(conversation_id);
+        self
+    }
+
+    /// Returns the confidence the fact holds at `now`, in percent.
+    #[must_use]
+    pub fn confidence_at(&self, policy: MemoryDecayPolicy, now: DateTime<Utc>) -> u8 {
+        policy.decay(self.confidence_percent, now - self.reinforced_at)
+    }
+
+    /// Returns whether the fact has expired or decayed past recall at `now`.
+    #[must_use]
+    pub fn is_forgotten(&self, policy: MemoryDecayPolicy, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+            || self.confidence_at(policy, now) < policy.forget_below_percent()
+    }
+
+    /// Returns how closely the fact matches a query embedding, in percent.
+    ///
+    /// Embeddings of different lengths, and opposing embeddings, score zero.
+    #[must_use]
+    pub fn relevance_to(&self, query_embedding: &[f32]) -> u8 {
+        cosine_similarity_percent(&self.embedding, query_embedding)
+    }
+
+    /// Returns whether `content` states the same fact, ignoring case and
+    /// surrounding whitespace.
+    #[must_use]
+    pub fn states(&self, content: &str) -> bool {
+        self.content.to_lowercase() == content.trim().to_lowercase()
+    }
+
+    /// Restates the fact, restarting its decay at `confidence_percent` or
+    /// at its decayed confidence, whichever is higher.
+    pub fn reinforce(
+        &mut self,
+        draft: &MemoryFactDraft,
+        policy: MemoryDecayPolicy,
+        clock: &(impl Clock + ?Sized),
+    ) {
+        let now = clock.utc();
+        self.confidence_percent = self
+            .confidence_at(policy, now)
+            .max(draft.confidence_percent.min(MAX_MEMORY_CONFIDENCE));
+        self.reinforced_at = now;
+        if let Some(lifetime) = draft.expires_after {
+            self.expires_at = Some(now + lifetime);
+        }
+    }
+
+    /// Replaces the fact's content and embedding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MemoryDomainError::EmptyContent`] or
+    /// [`MemoryDomainError::EmptyEmbedding`] when either is empty.
+    pub fn revise(
+        &mut self,
+        content: impl Into<String>,
+        embedding: Vec<f32>,
+    ) -> Result<(), MemoryDomainError> {
+        let revised_content = checked_content(content)?;
+        self.embedding = checked_embedding(embedding)?;
+        self.content = revised_content;
+        Ok(())
+    }
+
+    /// Sets the fact's confidence and restarts its decay.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MemoryDomainError::InvalidConfidence`] when
+    /// `confidence_percent` exceeds 100.
+    pub fn set_confidence(
+        &mut self,
+        confidence_percent: u8,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<(), MemoryDomainError> {
+        self.confidence_percent = checked_confidence(confidence_percent)?;
+        self.reinforced_at = clock.utc();
+        Ok(())
+    }
+
+    /// Sets or clears the timestamp after which the fact is forgotten.
+    pub const fn set_expiry(&mut self, expires_at: Option<DateTime<Utc>>) {
+        self.expires_at = expires_at;
+    }
+
+    /// Returns the fact identifier.
+    #[must_use]
+    pub const fn id(&self) -> MemoryFactId {
+        self.id
+    }
+
+    /// Returns the backend the fact is remembered for.
+    #[must_use]
+    pub const fn backend_id(&self) -> BackendId {
+        self.backend_id
+    }
+
+    /// Returns the fact itself.
+    #[must_use]
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Returns the confidence when the fact was last reinforced, in percent.
+    #[must_use]
+    pub const fn confidence_percent(&self) -> u8 {
+        self.confidence_percent
+    }
+
+    /// Returns the embedding of the content.
+    #[must_use]
+    pub fn embedding(&self) -> &[f32] {
+        &self.embedding
+    }
+
+    /// Returns the conversation the fact was first extracted from.
+    #[must_use]
+    pub const fn source_conversation_id(&self) -> Option<Uuid> {
+        self.source_conversation_id
+    }
+
+    /// Returns the creation timestamp.
+    #[must_use]
+    pub const fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Returns the timestamp decay is measured from.
+    #[must_use]
+    pub const fn reinforced_at(&self) -> DateTime<Utc> {
+        self.reinforced_at
+    }
+
+    /// Returns the timestamp after which the fact is forgotten.
+    #[must_use]
+    pub const fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+}
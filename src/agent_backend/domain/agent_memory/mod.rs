@@ -0,0 +1,116 @@
+//! Long-term agent memory shared across a backend's conversations.
+//!
+//! Facts extracted from completed turns are remembered per tenant and
+//! backend. Each fact carries a confidence that halves with every half-life
+//! since it was last reinforced, and may carry an expiry. Facts whose
+//! confidence has decayed below the forgetting threshold, or whose expiry has
+//! passed, are no longer recalled.
+
+mod decay;
+mod fact;
+mod recall;
+
+pub use decay::MemoryDecayPolicy;
+pub use fact::{MemoryFact, PersistedMemoryFactData};
+pub use recall::{
+    MemoryRecall, MemoryRecallDecision, MemoryRecallQuery, MemoryRecallVerdict, RecalledMemory,
+    assemble_memory_prompt,
+};
+
+use super::BackendId;
+use chrono::Duration;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Highest confidence a fact can carry, in percent.
+pub const MAX_MEMORY_CONFIDENCE: u8 = 100;
+
+/// Errors returned while constructing or revising memory facts.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum MemoryDomainError {
+    /// Fact content must not be empty.
+    #[error("memory fact content must not be empty")]
+    EmptyContent,
+    /// Confidence is a percentage.
+    #[error("memory confidence must be at most 100 percent, got {0}")]
+    InvalidConfidence(u8),
+    /// Facts must be embedded before they can be recalled.
+    #[error("memory fact embedding must not be empty")]
+    EmptyEmbedding,
+    /// Confidence must take time to decay.
+    #[error("memory half-life must be positive, got {0} seconds")]
+    InvalidHalfLife(i64),
+}
+
+/// A fact proposed for memory, before it is embedded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryFactDraft {
+    /// The fact, phrased so that it stands on its own.
+    pub content: String,
+    /// How sure the extractor is of the fact, in percent.
+    pub confidence_percent: u8,
+    /// How long the fact stays true, when it is known to lapse.
+    pub expires_after: Option<Duration>,
+}
+
+impl MemoryFactDraft {
+    /// Creates a draft fact that does not expire.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MemoryDomainError::EmptyContent`] when `content` is blank,
+    /// or [`MemoryDomainError::InvalidConfidence`] when `confidence_percent`
+    /// exceeds 100.
+    pub fn new(
+        content: impl Into<String>,
+        confidence_percent: u8,
+    ) -> Result<Self, MemoryDomainError> {
+        Ok(Self {
+            content: checked_content(content)?,
+            confidence_percent: checked_confidence(confidence_percent)?,
+            expires_after: None,
+        })
+    }
+
+    /// Makes the fact lapse `expires_after` once remembered.
+    #[must_use]
+    pub const fn with_expiry(mut self, expires_after: Duration) -> Self {
+        self.expires_after = Some(expires_after);
+        self
+    }
+}
+
+/// A finished turn handed to fact extraction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletedTurn {
+    /// Backend that ran the turn.
+    pub backend_id: BackendId,
+    /// Conversation the turn belongs to.
+    pub conversation_id: Uuid,
+    /// The prompt as the user wrote it, without recalled memories.
+    pub prompt: String,
+    /// The backend's reply.
+    pub assistant_response: String,
+}
+
+fn checked_content(content: impl Into<String>) -> Result<String, MemoryDomainError> {
+    let trimmed = content.into().trim().to_owned();
+    if trimmed.is_empty() {
+        return Err(MemoryDomainError::EmptyContent);
+    }
+    Ok(trimmed)
+}
+
+const fn checked_confidence(confidence_percent: u8) -> Result<u8, MemoryDomainError> {
+    if confidence_percent > MAX_MEMORY_CONFIDENCE {
+        return Err(MemoryDomainError::InvalidConfidence(confidence_percent));
+    }
+    Ok(confidence_percent)
+}
+
+fn checked_embedding(embedding: Vec<f32>) -> Result<Vec<f32>, MemoryDomainError> {
+    if embedding.is_empty() {
+        return Err(MemoryDomainError::EmptyEmbedding);
+    }
+    Ok(embedding)
+}
@@ -0,0 +1,254 @@
+//! Ranking recalled facts and folding them into prompts.
+
+use super::{MemoryDecayPolicy, MemoryFact};
+use crate::agent_backend::domain::MemoryFactId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::fmt;
+
+/// A fact recalled for a prompt, with the scores it was ranked by.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecalledMemory {
+    /// The recalled fact.
+    pub fact: MemoryFact,
+    /// How closely the fact matches the prompt, in percent.
+    pub relevance_percent: u8,
+    /// The fact's decayed confidence, in percent.
+    pub confidence_percent: u8,
+}
+
+impl RecalledMemory {
+    /// Returns the ranking score: relevance weighted by confidence.
+    #[must_use]
+    pub fn score(&self) -> u16 {
+        u16::from(self.relevance_percent) * u16::from(self.confidence_percent)
+    }
+
+    fn decision(&self, verdict: MemoryRecallVerdict) -> MemoryRecallDecision {
+        MemoryRecallDecision {
+            fact_id: self.fact.id(),
+            content: self.fact.content().to_owned(),
+            relevance_percent: Some(self.relevance_percent),
+            confidence_percent: self.confidence_percent,
+            verdict,
+        }
+    }
+}
+
+/// Why a remembered fact was, or was not, folded into a prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryRecallVerdict {
+    /// The fact was folded into the prompt.
+    Included,
+    /// The fact's expiry had passed.
+    Expired,
+    /// The fact's confidence had decayed below the forgetting threshold.
+    Decayed,
+    /// The fact bore no relation to the prompt.
+    NotRelevant,
+    /// Better-ranked facts filled the recall limit first.
+    OverRecallLimit,
+}
+
+impl MemoryRecallVerdict {
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Included => "included",
+            Self::Expired => "expired",
+            Self::Decayed => "decayed",
+            Self::NotRelevant => "not_relevant",
+            Self::OverRecallLimit => "over_recall_limit",
+        }
+    }
+}
+
+impl fmt::Display for MemoryRecallVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The recall verdict for one remembered fact.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryRecallDecision {
+    /// The fact considered.
+    pub fact_id: MemoryFactId,
+    /// The fact's content when it was considered.
+    pub content: String,
+    /// How closely the fact matched the prompt, in percent, when it was
+    /// compared with the prompt at all.
+    pub relevance_percent: Option<u8>,
+    /// The fact's decayed confidence, in percent.
+    pub confidence_percent: u8,
+    /// Whether the fact was folded into the prompt, and why not.
+    pub verdict: MemoryRecallVerdict,
+}
+
+/// What a recall is ranked against.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryRecallQuery<'a> {
+    /// The prompt's embedding, or `None` when the prompt was not embedded
+    /// because nothing could be recalled.
+    pub embedding: Option<&'a [f32]>,
+    /// The instant confidence is decayed to.
+    pub now: DateTime<Utc>,
+    /// How many facts may be recalled.
+    pub limit: usize,
+}
+
+/// The facts recalled for a prompt, with a verdict for every fact
+/// considered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryRecall {
+    /// The recalled facts, best first.
+    pub recalled: Vec<RecalledMemory>,
+    /// One decision per fact considered: the recalled facts first, in rank
+    /// order, then those ranked past the limit, then those never ranked.
+    pub decisions: Vec<MemoryRecallDecision>,
+}
+
+impl MemoryRecall {
+    /// Ranks `facts` against `query`, keeping at most `query.limit` of them.
+    ///
+    /// Expired and decayed facts, and facts with no relevance, are never
+    /// recalled. Facts that are not compared with the prompt because it was
+    /// not embedded are judged over the recall limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use corbusier::agent_backend::domain::{MemoryDecayPolicy, MemoryRecall, MemoryRecallQuery};
+    /// use chrono::Utc;
+    ///
+    /// let query = MemoryRecallQuery { embedding: None, now: Utc::now(), limit: 5 };
+    /// let recall = MemoryRecall::rank(Vec::new(), &query, MemoryDecayPolicy::default());
+    /// assert!(recall.recalled.is_empty() && recall.decisions.is_empty());
+    /// ```
+    #[must_use]
+    pub fn rank(
+        facts: Vec<MemoryFact>,
+        query: &MemoryRecallQuery<'_>,
+        policy: MemoryDecayPolicy,
+    ) -> Self {
+        let mut screened_out = Vec::new();
+        let mut ranked = Vec::new();
+        for fact in facts {
+            match screen(fact, query, policy) {
+                Ok(memory) => ranked.push(memory),
+                Err(decision) => screened_out.push(decision),
+            }
+        }
+        ranked.sort_by_key(|memory| {
+            (
+                Reverse(memory.score()),
+                Reverse(memory.fact.reinforced_at()),
+            )
+        });
+        let over_limit = ranked.split_off(query.limit.min(ranked.len()));
+        let decisions = ranked
+            .iter()
+            .map(|memory| memory.decision(MemoryRecallVerdict::Included))
+            .chain(
+                over_limit
+                    .iter()
+                    .map(|memory| memory.decision(MemoryRecallVerdict::OverRecallLimit)),
+            )
+            .chain(screened_out)
+            .collect();
+        Self {
+            recalled: ranked,
+            decisions,
+        }
+    }
+}
+
+/// Ranks a fact for recall, or returns why it cannot be ranked.
+fn screen(
+    fact: MemoryFact,
+    query: &MemoryRecallQuery<'_>,
+    policy: MemoryDecayPolicy,
+) -> Result<RecalledMemory, MemoryRecallDecision> {
+    let confidence_percent = fact.confidence_at(policy, query.now);
+    let relevance_percent = query
+        .embedding
+        .map(|embedding| fact.relevance_to(embedding));
+    let verdict = if fact
+        .expires_at()
+        .is_some_and(|expires_at| expires_at <= query.now)
+    {
+        MemoryRecallVerdict::Expired
+    } else if confidence_percent < policy.forget_below_percent() {
+        MemoryRecallVerdict::Decayed
+    } else {
+        match relevance_percent {
+            None => MemoryRecallVerdict::OverRecallLimit,
+            Some(0) => MemoryRecallVerdict::NotRelevant,
+            Some(relevance_percent) => {
+                return Ok(RecalledMemory {
+                    fact,
+                    relevance_percent,
+                    confidence_percent,
+                });
+            }
+        }
+    };
+    Err(MemoryRecallDecision {
+        fact_id: fact.id(),
+        content: fact.content().to_owned(),
+        relevance_percent,
+        confidence_percent,
+        verdict,
+    })
+}
+
+/// Prefixes `prompt` with the recalled facts, most relevant first.
+///
+/// The prompt is returned unchanged when nothing was recalled.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::domain::assemble_memory_prompt;
+///
+/// assert_eq!(assemble_memory_prompt(&[], "Fix the build."), "Fix the build.");
+/// ```
+#[must_use]
+pub fn assemble_memory_prompt(memories: &[RecalledMemory], prompt: &str) -> String {
+    if memories.is_empty() {
+        return prompt.to_owned();
+    }
+    let mut lines = vec!["Facts remembered from earlier conversations:".to_owned()];
+    lines.extend(
+        memories
+            .iter()
+            .map(|memory| format!("- {}", memory.fact.content())),
+    );
+    lines.push(String::new());
+    lines.push(prompt.to_owned());
+    lines.join("\n")
+}
+
+#[expect(
+    clippy::float_arithmetic,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "embeddings are floating-point vectors; the similarity is clamped to a whole percentage"
+)]
+pub(super) fn cosine_similarity_percent(left: &[f32], right: &[f32]) -> u8 {
+    if left.len() != right.len() {
+        return 0;
+    }
+    let (dot, left_norm, right_norm) = left.iter().zip(right).fold(
+        (0.0_f32, 0.0_f32, 0.0_f32),
+        |(dot, left_norm, right_norm), (l, r)| (dot + l * r, left_norm + l * l, right_norm + r * r),
+    );
+    let similarity = dot / (left_norm * right_norm).sqrt();
+    if !similarity.is_finite() {
+        return 0;
+    }
+    (similarity * 100.0).round().clamp(0.0, 100.0) as u8
+}
@@ -0,0 +1,69 @@
+//! Explanations of how each turn's prompt was assembled.
+//!
+//! When long-term memory is attached, the orchestrator folds recalled facts
+//! into each turn's prompt. A [`ContextAssemblyReport`] records the verdict
+//! for every fact the backend remembered at that moment, so a question such
+//! as "why didn't the agent know X?" can be answered from the record rather
+//! than by replaying the recall. Conversation history is kept by the agent
+//! runtime, not assembled by the orchestrator, so it does not appear here;
+//! runtimes that replay history should take it from
+//! [`ConversationCompactionService::history`], which substitutes the latest
+//! compaction summary for the messages it covers.
+//!
+//! [`ConversationCompactionService::history`]: crate::message::services::ConversationCompactionService::history
+
+use super::{BackendId, MemoryFactId, MemoryRecallDecision, MemoryRecallVerdict};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How one turn's prompt was assembled from long-term memory.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use corbusier::agent_backend::domain::{BackendId, ContextAssemblyReport, MemoryFactId};
+/// use uuid::Uuid;
+///
+/// let report = ContextAssemblyReport {
+///     backend_id: BackendId::new(),
+///     conversation_id: Uuid::new_v4(),
+///     recall_limit: 5,
+///     memories: Vec::new(),
+///     assembled_at: Utc::now(),
+/// };
+/// assert!(report.memory_decision(MemoryFactId::new()).is_none());
+/// assert_eq!(report.included_memories().count(), 0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextAssemblyReport {
+    /// Backend the turn ran on.
+    pub backend_id: BackendId,
+    /// Conversation the turn belongs to.
+    pub conversation_id: Uuid,
+    /// How many facts could be recalled into the prompt.
+    pub recall_limit: usize,
+    /// One decision per remembered fact, recalled facts first.
+    pub memories: Vec<MemoryRecallDecision>,
+    /// When the prompt was assembled.
+    pub assembled_at: DateTime<Utc>,
+}
+
+impl ContextAssemblyReport {
+    /// Returns the decision recorded for a fact, if the backend remembered
+    /// it when the prompt was assembled.
+    #[must_use]
+    pub fn memory_decision(&self, fact_id: MemoryFactId) -> Option<&MemoryRecallDecision> {
+        self.memories
+            .iter()
+            .find(|decision| decision.fact_id == fact_id)
+    }
+
+    /// Returns the decisions for facts folded into the prompt, best first.
+    pub fn included_memories(&self) -> impl Iterator<Item = &MemoryRecallDecision> {
+        self.memories
+            .iter()
+            .filter(|decision| decision.verdict == MemoryRecallVerdict::Included)
+    }
+}
@@ -0,0 +1,169 @@
+//! Tool invocation samples recorded into evaluation datasets.
+//!
+//! When dataset recording is enabled, the orchestrator captures each routed
+//! tool call together with the prompt that led to it, the tool's result, and
+//! whether the surrounding turn completed. Samples are scrubbed of personal
+//! data before they leave the turn and are exported as JSON Lines for
+//! fine-tuning or offline evaluation.
+
+use super::{BackendId, PiiScrubber};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors returned when constructing a [`DatasetRecordingPolicy`].
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+pub enum DatasetRecordingPolicyError {
+    /// The sampling rate must lie in `1..=100` percent of turns.
+    #[error("dataset sampling rate must be between 1 and 100 percent, got {0}")]
+    InvalidSampleRate(u8),
+}
+
+/// Policy controlling which turns are recorded and how samples are
+/// sanitised.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::domain::DatasetRecordingPolicy;
+///
+/// let policy = DatasetRecordingPolicy::new(25)
+///     .expect("valid sample rate")
+///     .with_max_prompt_chars(2_000);
+/// assert_eq!(policy.sample_percent(), 25);
+/// assert_eq!(policy.max_prompt_chars(), 2_000);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatasetRecordingPolicy {
+    sample_percent: u8,
+    max_prompt_chars: usize,
+    scrubber: PiiScrubber,
+}
+
+impl DatasetRecordingPolicy {
+    /// Default number of prompt characters kept as sample context.
+    pub const DEFAULT_MAX_PROMPT_CHARS: usize = 4_000;
+
+    /// Creates a policy recording the given share of turns with the default
+    /// scrubber.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatasetRecordingPolicyError::InvalidSampleRate`] when
+    /// `sample_percent` is outside `1..=100`.
+    pub fn new(sample_percent: u8) -> Result<Self, DatasetRecordingPolicyError> {
+        if sample_percent == 0 || sample_percent > 100 {
+            return Err(DatasetRecordingPolicyError::InvalidSampleRate(
+                sample_percent,
+            ));
+        }
+        Ok(Self {
+            sample_percent,
+            max_prompt_chars: Self::DEFAULT_MAX_PROMPT_CHARS,
+            scrubber: PiiScrubber::default(),
+        })
+    }
+
+    /// Sets how many prompt characters are kept as sample context.
+    #[must_use]
+    pub const fn with_max_prompt_chars(mut self, max_prompt_chars: usize) -> Self {
+        self.max_prompt_chars = max_prompt_chars;
+        self
+    }
+
+    /// Replaces the scrubber applied to prompts, arguments, and results.
+    #[must_use]
+    pub fn with_scrubber(mut self, scrubber: PiiScrubber) -> Self {
+        self.scrubber = scrubber;
+        self
+    }
+
+    /// Returns the share of turns that are recorded.
+    #[must_use]
+    pub const fn sample_percent(&self) -> u8 {
+        self.sample_percent
+    }
+
+    /// Returns how many prompt characters are kept as sample context.
+    #[must_use]
+    pub const fn max_prompt_chars(&self) -> usize {
+        self.max_prompt_chars
+    }
+
+    /// Returns the scrubber applied to recorded samples.
+    #[must_use]
+    pub const fn scrubber(&self) -> &PiiScrubber {
+        &self.scrubber
+    }
+
+    /// Returns `true` when a turn whose sampling roll is `roll` should be
+    /// recorded.
+    ///
+    /// Any `u128` may be supplied; only its remainder modulo 100 is used.
+    #[must_use]
+    pub fn samples(&self, roll: u128) -> bool {
+        roll.rem_euclid(100) < u128::from(self.sample_percent)
+    }
+
+    /// Returns the scrubbed, truncated prompt kept as sample context.
+    #[must_use]
+    pub fn prompt_context(&self, prompt: &str) -> String {
+        let truncated = prompt
+            .chars()
+            .take(self.max_prompt_chars)
+            .collect::<String>();
+        self.scrubber.scrub_text(&truncated)
+    }
+}
+
+/// How the turn that issued a recorded tool call ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DatasetTurnOutcome {
+    /// Every tool call was routed and the turn was persisted.
+    Completed,
+    /// Tool routing or turn persistence failed.
+    Failed,
+}
+
+/// One recorded tool invocation with its prompt context and turn outcome.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolInvocationSample {
+    /// Unique identifier of the sample.
+    pub sample_id: Uuid,
+    /// When the sample was recorded.
+    pub recorded_at: DateTime<Utc>,
+    /// Backend that requested the tool call.
+    pub backend_id: BackendId,
+    /// Conversation the turn belongs to.
+    pub conversation_id: Uuid,
+    /// Scrubbed prompt that led to the tool call.
+    pub prompt_context: String,
+    /// Deterministic tool-call identifier.
+    pub call_id: String,
+    /// Name of the invoked tool.
+    pub tool_name: String,
+    /// Scrubbed tool-call arguments.
+    pub arguments: Value,
+    /// Scrubbed tool output, or `None` when the call produced no result.
+    pub result: Option<Value>,
+    /// How the surrounding turn ended.
+    pub turn_outcome: DatasetTurnOutcome,
+}
+
+/// Encodes samples as JSON Lines, one sample per line.
+///
+/// # Errors
+///
+/// Returns [`serde_json::Error`] when a sample cannot be serialized.
+pub fn encode_jsonl(samples: &[ToolInvocationSample]) -> Result<String, serde_json::Error> {
+    samples
+        .iter()
+        .try_fold(String::new(), |mut encoded, sample| {
+            encoded.push_str(&serde_json::to_string(sample)?);
+            encoded.push('\n');
+            Ok(encoded)
+        })
+}
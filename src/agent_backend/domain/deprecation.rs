@@ -0,0 +1,72 @@
+//! Impact of retiring an agent backend.
+//!
+//! When a provider retires the model behind a backend, operators need to know
+//! what still depends on it before moving that work to a successor. The
+//! [`DeprecationImpactReport`] collects those dependants in one place.
+
+use super::{BackendName, ExperimentId};
+use crate::message::domain::ConversationId;
+use crate::task::domain::BackendUsage;
+use serde::{Deserialize, Serialize};
+
+/// What still depends on an agent backend that is being retired.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::domain::{BackendName, DeprecationImpactReport};
+/// use corbusier::message::domain::ConversationId;
+/// use corbusier::task::domain::{BackendUsage, CostMicros, TokenUsage};
+///
+/// let report = DeprecationImpactReport {
+///     backend: BackendName::new("legacy_model").expect("valid name"),
+///     pinned_conversations: vec![ConversationId::new()],
+///     templates: Vec::new(),
+///     experiments: Vec::new(),
+///     projected_migration_cost: BackendUsage {
+///         turn_count: 12,
+///         usage: TokenUsage::new(90_000, 4_000),
+///         cost: CostMicros::new(1_250_000),
+///     },
+/// };
+/// assert!(!report.is_clear());
+/// assert_eq!(report.projected_migration_cost.cost.to_string(), "$1.25");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeprecationImpactReport {
+    /// The backend being retired.
+    pub backend: BackendName,
+    /// Conversations whose active agent session runs on the backend, oldest
+    /// session first.
+    pub pinned_conversations: Vec<ConversationId>,
+    /// Slash commands whose templates name the backend, in registry order.
+    pub templates: Vec<String>,
+    /// Running experiments with an arm on the backend, oldest first.
+    pub experiments: Vec<ExperimentId>,
+    /// Usage the pinned conversations have recorded on the backend.
+    ///
+    /// Re-pinning moves this run rate onto the successor, so it
+    /// approximates the spend a migration re-prices.
+    pub projected_migration_cost: BackendUsage,
+}
+
+impl DeprecationImpactReport {
+    /// Returns whether nothing depends on the backend any more.
+    #[must_use]
+    pub const fn is_clear(&self) -> bool {
+        self.pinned_conversations.is_empty()
+            && self.templates.is_empty()
+            && self.experiments.is_empty()
+    }
+}
+
+/// Conversations moved from a retired backend to its successor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepinOutcome {
+    /// The retired backend.
+    pub backend: BackendName,
+    /// The backend now handling the conversations.
+    pub successor: BackendName,
+    /// Conversations re-pinned to the successor, oldest session first.
+    pub repinned: Vec<ConversationId>,
+}
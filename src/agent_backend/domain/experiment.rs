@@ -0,0 +1,374 @@
+//! Backend A/B experiments splitting conversations between two arms.
+//!
+//! An experiment compares a control arm with a treatment arm. Each arm names
+//! a backend and an optional prompt variant. Turns addressed to the control
+//! backend are split between the arms by conversation, so every turn of a
+//! conversation runs on the same arm and the arm's session stays warm.
+
+use super::{BackendId, ExperimentId};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors returned while constructing or transitioning experiments.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ExperimentDomainError {
+    /// Experiment names must not be empty.
+    #[error("experiment name must not be empty")]
+    EmptyName,
+    /// The treatment arm must differ from the control arm.
+    #[error("experiment arms must differ in backend or prompt variant")]
+    IdenticalArms,
+    /// The treatment share must lie in `1..=99` percent of conversations.
+    #[error("treatment share must be between 1 and 99 percent, got {0}")]
+    InvalidTreatmentPercent(u8),
+    /// The experiment has already been concluded.
+    #[error("experiment {0} is already concluded")]
+    AlreadyConcluded(ExperimentId),
+}
+
+/// Error returned while parsing experiment values from persistence.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("unknown experiment value: {0}")]
+pub struct ParseExperimentValueError(pub String);
+
+/// One side of an experiment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExperimentArmKey {
+    /// The current backend and prompt.
+    Control,
+    /// The candidate backend or prompt under evaluation.
+    Treatment,
+}
+
+impl ExperimentArmKey {
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Control => "control",
+            Self::Treatment => "treatment",
+        }
+    }
+}
+
+impl fmt::Display for ExperimentArmKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<&str> for ExperimentArmKey {
+    type Error = ParseExperimentValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "control" => Ok(Self::Control),
+            "treatment" => Ok(Self::Treatment),
+            _ => Err(ParseExperimentValueError(value.to_owned())),
+        }
+    }
+}
+
+/// Backend and prompt variant used by one experiment arm.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ExperimentArm {
+    backend_id: BackendId,
+    prompt_variant: Option<String>,
+}
+
+impl ExperimentArm {
+    /// Creates an arm running turns unchanged on `backend_id`.
+    #[must_use]
+    pub const fn new(backend_id: BackendId) -> Self {
+        Self {
+            backend_id,
+            prompt_variant: None,
+        }
+    }
+
+    /// Prepends `preamble` to every prompt routed to this arm.
+    #[must_use]
+    pub fn with_prompt_variant(mut self, preamble: impl Into<String>) -> Self {
+        self.prompt_variant = Some(preamble.into());
+        self
+    }
+
+    /// Returns the backend that executes this arm's turns.
+    #[must_use]
+    pub const fn backend_id(&self) -> BackendId {
+        self.backend_id
+    }
+
+    /// Returns the prompt preamble, if the arm varies the prompt.
+    #[must_use]
+    pub fn prompt_variant(&self) -> Option<&str> {
+        self.prompt_variant.as_deref()
+    }
+
+    /// Returns `prompt` as this arm sends it to the backend.
+    #[must_use]
+    pub fn apply_prompt(&self, prompt: &str) -> String {
+        self.prompt_variant.as_ref().map_or_else(
+            || prompt.to_owned(),
+            |preamble| format!("{preamble}\n\n{prompt}"),
+        )
+    }
+}
+
+/// Lifecycle status of an experiment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExperimentStatus {
+    /// Eligible turns are being split between the arms.
+    Running,
+    /// The experiment no longer routes turns; its results remain reportable.
+    Concluded,
+}
+
+impl ExperimentStatus {
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Concluded => "concluded",
+        }
+    }
+}
+
+impl TryFrom<&str> for ExperimentStatus {
+    type Error = ParseExperimentValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "running" => Ok(Self::Running),
+            "concluded" => Ok(Self::Concluded),
+            _ => Err(ParseExperimentValueError(value.to_owned())),
+        }
+    }
+}
+
+/// Persisted experiment fields used to rebuild a [`BackendExperiment`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersistedExperimentData {
+    /// Experiment identifier.
+    pub id: ExperimentId,
+    /// Human-readable experiment name.
+    pub name: String,
+    /// Control arm.
+    pub control: ExperimentArm,
+    /// Treatment arm.
+    pub treatment: ExperimentArm,
+    /// Share of conversations assigned to the treatment arm.
+    pub treatment_percent: u8,
+    /// Lifecycle status.
+    pub status: ExperimentStatus,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Conclusion timestamp, once concluded.
+    pub concluded_at: Option<DateTime<Utc>>,
+}
+
+/// A/B experiment comparing two backend or prompt arms.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::domain::{
+///     BackendExperiment, BackendId, ExperimentArm, ExperimentArmKey,
+/// };
+/// use mockable::DefaultClock;
+/// use uuid::Uuid;
+///
+/// let control = ExperimentArm::new(BackendId::new());
+/// let treatment = ExperimentArm::new(BackendId::new());
+/// let experiment = BackendExperiment::new("codex-vs-sdk", control, treatment, &DefaultClock)
+///     .expect("valid experiment")
+///     .with_treatment_percent(20)
+///     .expect("valid treatment share");
+///
+/// let conversation_id = Uuid::new_v4();
+/// let arm = experiment.assign(conversation_id);
+/// assert_eq!(experiment.assign(conversation_id), arm);
+/// assert!(matches!(arm, ExperimentArmKey::Control | ExperimentArmKey::Treatment));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendExperiment {
+    id: ExperimentId,
+    name: String,
+    control: ExperimentArm,
+    treatment: ExperimentArm,
+    treatment_percent: u8,
+    status: ExperimentStatus,
+    created_at: DateTime<Utc>,
+    concluded_at: Option<DateTime<Utc>>,
+}
+
+impl BackendExperiment {
+    /// Default share of conversations assigned to the treatment arm.
+    pub const DEFAULT_TREATMENT_PERCENT: u8 = 50;
+
+    /// Creates a running experiment splitting conversations evenly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentDomainError::EmptyName`] when `name` is blank, or
+    /// [`ExperimentDomainError::IdenticalArms`] when both arms use the same
+    /// backend and prompt variant.
+    pub fn new(
+        name: impl Into<String>,
+        control: ExperimentArm,
+        treatment: ExperimentArm,
+        clock: &impl Clock,
+    ) -> Result<Self, ExperimentDomainError> {
+        let trimmed_name = name.into().trim().to_owned();
+        if trimmed_name.is_empty() {
+            return Err(ExperimentDomainError::EmptyName);
+        }
+        if control == treatment {
+            return Err(ExperimentDomainError::IdenticalArms);
+        }
+        Ok(Self {
+            id: ExperimentId::new(),
+            name: trimmed_name,
+            control,
+            treatment,
+            treatment_percent: Self::DEFAULT_TREATMENT_PERCENT,
+            status: ExperimentStatus::Running,
+            created_at: clock.utc(),
+            concluded_at: None,
+        })
+    }
+
+    /// Rebuilds an experiment from persisted data.
+    #[must_use]
+    pub fn from_persisted(data: PersistedExperimentData) -> Self {
+        Self {
+            id: data.id,
+            name: data.name,
+            control: data.control,
+            treatment: data.treatment,
+            treatment_percent: data.treatment_percent,
+            status: data.status,
+            created_at: data.created_at,
+            concluded_at: data.concluded_at,
+        }
+    }
+
+    /// Sets the share of conversations assigned to the treatment arm.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentDomainError::InvalidTreatmentPercent`] when
+    /// `treatment_percent` is outside `1..=99`.
+    pub fn with_treatment_percent(
+        mut self,
+        treatment_percent: u8,
+    ) -> Result<Self, ExperimentDomainError> {
+        if treatment_percent == 0 || treatment_percent > 99 {
+            return Err(ExperimentDomainError::InvalidTreatmentPercent(
+                treatment_percent,
+            ));
+        }
+        self.treatment_percent = treatment_percent;
+        Ok(self)
+    }
+
+    /// Stops routing turns through the experiment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentDomainError::AlreadyConcluded`] when the
+    /// experiment has already been concluded.
+    pub fn conclude(&mut self, clock: &(impl Clock + ?Sized)) -> Result<(), ExperimentDomainError> {
+        if self.status == ExperimentStatus::Concluded {
+            return Err(ExperimentDomainError::AlreadyConcluded(self.id));
+        }
+        self.status = ExperimentStatus::Concluded;
+        self.concluded_at = Some(clock.utc());
+        Ok(())
+    }
+
+    /// Returns the arm a conversation is assigned to.
+    ///
+    /// Assignment hashes the experiment and conversation identifiers, so it
+    /// is stable across turns and processes without storing assignments.
+    #[must_use]
+    pub fn assign(&self, conversation_id: Uuid) -> ExperimentArmKey {
+        let digest = Sha256::new()
+            .chain_update(self.id.into_inner().as_bytes())
+            .chain_update(conversation_id.as_bytes())
+            .finalize();
+        let bucket = digest.iter().take(8).fold(0_u64, |remainder, byte| {
+            (remainder * 256 + u64::from(*byte)).rem_euclid(100)
+        });
+        if bucket < u64::from(self.treatment_percent) {
+            ExperimentArmKey::Treatment
+        } else {
+            ExperimentArmKey::Control
+        }
+    }
+
+    /// Returns the arm identified by `key`.
+    #[must_use]
+    pub const fn arm(&self, key: ExperimentArmKey) -> &ExperimentArm {
+        match key {
+            ExperimentArmKey::Control => &self.control,
+            ExperimentArmKey::Treatment => &self.treatment,
+        }
+    }
+
+    /// Returns the experiment identifier.
+    #[must_use]
+    pub const fn id(&self) -> ExperimentId {
+        self.id
+    }
+
+    /// Returns the experiment name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the control arm.
+    #[must_use]
+    pub const fn control(&self) -> &ExperimentArm {
+        &self.control
+    }
+
+    /// Returns the treatment arm.
+    #[must_use]
+    pub const fn treatment(&self) -> &ExperimentArm {
+        &self.treatment
+    }
+
+    /// Returns the share of conversations assigned to the treatment arm.
+    #[must_use]
+    pub const fn treatment_percent(&self) -> u8 {
+        self.treatment_percent
+    }
+
+    /// Returns the lifecycle status.
+    #[must_use]
+    pub const fn status(&self) -> ExperimentStatus {
+        self.status
+    }
+
+    /// Returns the creation timestamp.
+    #[must_use]
+    pub const fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Returns the conclusion timestamp, once concluded.
+    #[must_use]
+    pub const fn concluded_at(&self) -> Option<DateTime<Utc>> {
+        self.concluded_at
+    }
+}
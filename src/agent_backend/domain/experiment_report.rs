@@ -0,0 +1,209 @@
+//! Outcome observations and per-arm statistics for backend experiments.
+//!
+//! Observations arrive at different times: turn success and latency when a
+//! turn finishes, cost once usage has been priced, and human feedback
+//! whenever a reviewer rates the conversation. Reports fold every
+//! observation recorded for an experiment into statistics for each arm.
+
+use super::{ExperimentArmKey, ExperimentId};
+use crate::message::domain::FeedbackSentiment;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// A reviewer's rating of an experiment conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HumanFeedback {
+    /// The conversation met the reviewer's expectations.
+    Positive,
+    /// The conversation fell short of the reviewer's expectations.
+    Negative,
+}
+
+impl HumanFeedback {
+    /// Maps the sentiment of a message rating onto experiment feedback.
+    ///
+    /// Neutral ratings carry no preference between arms and map to `None`.
+    #[must_use]
+    pub const fn from_sentiment(sentiment: FeedbackSentiment) -> Option<Self> {
+        match sentiment {
+            FeedbackSentiment::Positive => Some(Self::Positive),
+            FeedbackSentiment::Neutral => None,
+            FeedbackSentiment::Negative => Some(Self::Negative),
+        }
+    }
+}
+
+/// One outcome metric observed for an experiment conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExperimentSignal {
+    /// A turn finished.
+    Turn {
+        /// Whether the turn completed without error.
+        succeeded: bool,
+        /// Wall-clock duration of the turn.
+        latency: Duration,
+    },
+    /// Usage cost attributed to the conversation.
+    Cost {
+        /// Cost in millionths of the billing currency unit.
+        cost_micros: u64,
+    },
+    /// Human feedback on the conversation.
+    Feedback {
+        /// The reviewer's rating.
+        feedback: HumanFeedback,
+    },
+}
+
+/// An outcome metric recorded against an experiment arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExperimentObservation {
+    /// Experiment the observation belongs to.
+    pub experiment_id: ExperimentId,
+    /// Arm the conversation was assigned to.
+    pub arm: ExperimentArmKey,
+    /// Conversation the observation describes.
+    pub conversation_id: Uuid,
+    /// Observed metric.
+    pub signal: ExperimentSignal,
+    /// When the observation was recorded.
+    pub observed_at: DateTime<Utc>,
+}
+
+/// Aggregated outcome metrics for one experiment arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArmStatistics {
+    /// The summarised arm.
+    pub arm: ExperimentArmKey,
+    /// Distinct conversations with at least one observation.
+    pub conversations: u64,
+    /// Turns executed.
+    pub turns: u64,
+    /// Turns that completed without error.
+    pub successful_turns: u64,
+    /// Median turn latency, when any turn was observed.
+    pub median_latency: Option<Duration>,
+    /// 95th percentile turn latency, when any turn was observed.
+    pub p95_latency: Option<Duration>,
+    /// Total attributed cost in micros.
+    pub total_cost_micros: u64,
+    /// Positive human ratings.
+    pub positive_feedback: u64,
+    /// Negative human ratings.
+    pub negative_feedback: u64,
+}
+
+impl ArmStatistics {
+    /// Returns the turn success rate in basis points (hundredths of a
+    /// percent), or `None` when no turn was observed.
+    #[must_use]
+    pub const fn success_rate_bps(&self) -> Option<u64> {
+        self.successful_turns
+            .saturating_mul(10_000)
+            .checked_div(self.turns)
+    }
+
+    /// Returns the mean cost per conversation in micros, or `None` when no
+    /// conversation was observed.
+    #[must_use]
+    pub const fn cost_per_conversation_micros(&self) -> Option<u64> {
+        self.total_cost_micros.checked_div(self.conversations)
+    }
+
+    /// Folds the observations recorded for `arm` into statistics.
+    ///
+    /// Observations for other arms are ignored.
+    #[must_use]
+    pub fn from_observations(
+        arm: ExperimentArmKey,
+        observations: &[ExperimentObservation],
+    ) -> Self {
+        let mut conversations = HashSet::new();
+        let mut latencies = Vec::new();
+        let mut stats = Self {
+            arm,
+            conversations: 0,
+            turns: 0,
+            successful_turns: 0,
+            median_latency: None,
+            p95_latency: None,
+            total_cost_micros: 0,
+            positive_feedback: 0,
+            negative_feedback: 0,
+        };
+        for observation in observations.iter().filter(|candidate| candidate.arm == arm) {
+            conversations.insert(observation.conversation_id);
+            if let ExperimentSignal::Turn { latency, .. } = observation.signal {
+                latencies.push(latency);
+            }
+            stats.fold_signal(observation.signal);
+        }
+        latencies.sort_unstable();
+        stats.conversations = u64::try_from(conversations.len()).unwrap_or(u64::MAX);
+        stats.median_latency = nearest_rank(&latencies, 50);
+        stats.p95_latency = nearest_rank(&latencies, 95);
+        stats
+    }
+
+    const fn fold_signal(&mut self, signal: ExperimentSignal) {
+        match signal {
+            ExperimentSignal::Turn { succeeded, .. } => {
+                self.turns = self.turns.saturating_add(1);
+                if succeeded {
+                    self.successful_turns = self.successful_turns.saturating_add(1);
+                }
+            }
+            ExperimentSignal::Cost { cost_micros } => {
+                self.total_cost_micros = self.total_cost_micros.saturating_add(cost_micros);
+            }
+            ExperimentSignal::Feedback {
+                feedback: HumanFeedback::Positive,
+            } => self.positive_feedback = self.positive_feedback.saturating_add(1),
+            ExperimentSignal::Feedback {
+                feedback: HumanFeedback::Negative,
+            } => self.negative_feedback = self.negative_feedback.saturating_add(1),
+        }
+    }
+}
+
+/// Returns the nearest-rank percentile of sorted samples.
+fn nearest_rank(sorted: &[Duration], percentile: usize) -> Option<Duration> {
+    let rank = sorted
+        .len()
+        .saturating_mul(percentile)
+        .div_ceil(100)
+        .saturating_sub(1);
+    sorted.get(rank).copied()
+}
+
+/// Per-arm statistics for an experiment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExperimentReport {
+    /// The reported experiment.
+    pub experiment_id: ExperimentId,
+    /// Control arm statistics.
+    pub control: ArmStatistics,
+    /// Treatment arm statistics.
+    pub treatment: ArmStatistics,
+}
+
+impl ExperimentReport {
+    /// Builds the report from every observation recorded for the
+    /// experiment.
+    #[must_use]
+    pub fn from_observations(
+        experiment_id: ExperimentId,
+        observations: &[ExperimentObservation],
+    ) -> Self {
+        Self {
+            experiment_id,
+            control: ArmStatistics::from_observations(ExperimentArmKey::Control, observations),
+            treatment: ArmStatistics::from_observations(ExperimentArmKey::Treatment, observations),
+        }
+    }
+}
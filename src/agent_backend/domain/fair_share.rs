@@ -0,0 +1,258 @@
+//! Weighted fair sharing of rate-limited provider capacity across tenants.
+//!
+//! Tenants whose backends share one provider key also share its rate limit.
+//! Each tenant draws invocations from its own token bucket, and the buckets
+//! split the provider rate by weight among the tenants currently contending
+//! for it. A tenant whose bucket is full is idle and its share goes to the
+//! others, so spare capacity is not wasted, but a noisy tenant cannot take
+//! more than its weighted share while others are waiting.
+
+use crate::context::TenantId;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+/// One token, in the micro-token units buckets are kept in.
+const TOKEN: u128 = 1_000_000;
+
+/// Nanoseconds per second divided by micro-tokens per token: a bucket
+/// refilling at one token per second gains one micro-token every this many
+/// nanoseconds.
+const NANOS_PER_MICRO_TOKEN_AT_ONE_PER_SECOND: u128 = 1_000;
+
+/// Policy for sharing one provider's rate limit across tenants.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::domain::FairSharePolicy;
+/// use corbusier::context::TenantId;
+/// use std::num::NonZeroU32;
+/// use std::time::Duration;
+///
+/// let premium = TenantId::new();
+/// let policy = FairSharePolicy::new(NonZeroU32::new(10).expect("non-zero rate"))
+///     .with_max_wait(Duration::from_secs(5))
+///     .with_tenant_weight(premium, NonZeroU32::new(3).expect("non-zero weight"));
+/// assert_eq!(policy.weight(premium).get(), 3);
+/// assert_eq!(policy.weight(TenantId::new()).get(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FairSharePolicy {
+    rate_per_second: NonZeroU32,
+    burst: NonZeroU32,
+    max_wait: Duration,
+    default_weight: NonZeroU32,
+    weights: HashMap<TenantId, NonZeroU32>,
+}
+
+impl FairSharePolicy {
+    /// Default longest time an invocation waits for capacity.
+    pub const DEFAULT_MAX_WAIT: Duration = Duration::from_secs(30);
+
+    /// Creates a policy sharing `rate_per_second` invocations between
+    /// equally weighted tenants.
+    ///
+    /// Each tenant may burst up to one second's worth of the provider rate.
+    #[must_use]
+    pub fn new(rate_per_second: NonZeroU32) -> Self {
+        Self {
+            rate_per_second,
+            burst: rate_per_second,
+            max_wait: Self::DEFAULT_MAX_WAIT,
+            default_weight: NonZeroU32::MIN,
+            weights: HashMap::new(),
+        }
+    }
+
+    /// Sets how many invocations a tenant may make back to back once its
+    /// bucket is full.
+    #[must_use]
+    pub const fn with_burst(mut self, burst: NonZeroU32) -> Self {
+        self.burst = burst;
+        self
+    }
+
+    /// Sets the longest time an invocation waits for capacity before it is
+    /// rejected.
+    #[must_use]
+    pub const fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// Sets the weight of tenants without an explicit weight.
+    #[must_use]
+    pub const fn with_default_weight(mut self, weight: NonZeroU32) -> Self {
+        self.default_weight = weight;
+        self
+    }
+
+    /// Sets the weight of one tenant.
+    #[must_use]
+    pub fn with_tenant_weight(mut self, tenant_id: TenantId, weight: NonZeroU32) -> Self {
+        self.weights.insert(tenant_id, weight);
+        self
+    }
+
+    /// Returns the provider rate shared between tenants, in invocations per
+    /// second.
+    #[must_use]
+    pub const fn rate_per_second(&self) -> NonZeroU32 {
+        self.rate_per_second
+    }
+
+    /// Returns the burst allowed to each tenant.
+    #[must_use]
+    pub const fn burst(&self) -> NonZeroU32 {
+        self.burst
+    }
+
+    /// Returns the longest time an invocation waits for capacity.
+    #[must_use]
+    pub const fn max_wait(&self) -> Duration {
+        self.max_wait
+    }
+
+    /// Returns the weight of `tenant_id`.
+    #[must_use]
+    pub fn weight(&self, tenant_id: TenantId) -> NonZeroU32 {
+        self.weights
+            .get(&tenant_id)
+            .copied()
+            .unwrap_or(self.default_weight)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TenantBucket {
+    weight: u128,
+    micro_tokens: u128,
+    refilled_at: Duration,
+}
+
+/// Weighted token buckets for the tenants sharing one provider.
+///
+/// Time is passed in as the offset from an arbitrary fixed instant, so the
+/// buckets can be driven by any monotonic clock.
+#[derive(Debug, Clone)]
+pub struct FairShareBuckets {
+    policy: FairSharePolicy,
+    buckets: HashMap<TenantId, TenantBucket>,
+}
+
+impl FairShareBuckets {
+    /// Creates empty buckets governed by `policy`.
+    #[must_use]
+    pub fn new(policy: FairSharePolicy) -> Self {
+        Self {
+            policy,
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Returns the policy governing the buckets.
+    #[must_use]
+    pub const fn policy(&self) -> &FairSharePolicy {
+        &self.policy
+    }
+
+    /// Takes one token from `tenant_id`'s bucket at time `now`.
+    ///
+    /// A tenant seen for the first time starts with a full bucket.
+    ///
+    /// # Errors
+    ///
+    /// Returns how long the tenant must wait for its next token, at its
+    /// current share of the provider rate, when its bucket is empty.
+    pub fn try_acquire(&mut self, tenant_id: TenantId, now: Duration) -> Result<(), Duration> {
+        self.refill(now);
+        let total_weight = self.contending_weight();
+        let rate = u128::from(self.policy.rate_per_second.get());
+        let full = self.full();
+        let weight = u128::from(self.policy.weight(tenant_id).get());
+        let bucket = self.buckets.entry(tenant_id).or_insert(TenantBucket {
+            weight,
+            micro_tokens: full,
+            refilled_at: now,
+        });
+        if let Some(remaining) = bucket.micro_tokens.checked_sub(TOKEN) {
+            bucket.micro_tokens = remaining;
+            return Ok(());
+        }
+        // An empty bucket is refilling, so its weight is already counted in
+        // `total_weight`.
+        let missing = TOKEN.saturating_sub(bucket.micro_tokens);
+        let nanos = missing
+            .saturating_mul(total_weight.max(bucket.weight))
+            .saturating_mul(NANOS_PER_MICRO_TOKEN_AT_ONE_PER_SECOND)
+            .div_ceil(rate.saturating_mul(bucket.weight));
+        Err(Duration::from_nanos(
+            u64::try_from(nanos).unwrap_or(u64::MAX),
+        ))
+    }
+
+    fn full(&self) -> u128 {
+        u128::from(self.policy.burst.get()).saturating_mul(TOKEN)
+    }
+
+    /// Total weight of the tenants whose buckets are still refilling.
+    fn contending_weight(&self) -> u128 {
+        let full = self.full();
+        self.buckets
+            .values()
+            .filter(|bucket| bucket.micro_tokens < full)
+            .map(|bucket| bucket.weight)
+            .sum()
+    }
+
+    /// Credits every refilling bucket with its weighted share of the
+    /// provider rate since it was last refilled.
+    fn refill(&mut self, now: Duration) {
+        let full = self.full();
+        let total_weight = self.contending_weight();
+        let rate = u128::from(self.policy.rate_per_second.get());
+        for bucket in self.buckets.values_mut() {
+            let elapsed = now.saturating_sub(bucket.refilled_at).as_nanos();
+            bucket.refilled_at = now;
+            if bucket.micro_tokens >= full {
+                continue;
+            }
+            let earned = elapsed
+                .saturating_mul(rate)
+                .saturating_mul(bucket.weight)
+                .checked_div(total_weight.saturating_mul(NANOS_PER_MICRO_TOKEN_AT_ONE_PER_SECOND))
+                .unwrap_or_default();
+            bucket.micro_tokens = bucket.micro_tokens.saturating_add(earned).min(full);
+        }
+    }
+}
+
+/// Wait-time counters for one tenant on one provider, for metrics export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TenantWaitMetrics {
+    /// Provider whose capacity the tenant draws on.
+    pub provider: String,
+    /// The tenant.
+    pub tenant_id: TenantId,
+    /// Invocations admitted since start-up.
+    pub admitted: u64,
+    /// Invocations rejected after waiting too long since start-up.
+    pub rejected: u64,
+    /// Time admitted invocations spent waiting for capacity.
+    pub total_wait: Duration,
+    /// Longest time one admitted invocation waited for capacity.
+    pub longest_wait: Duration,
+}
+
+impl TenantWaitMetrics {
+    /// Returns the mean wait of admitted invocations.
+    #[must_use]
+    pub fn mean_wait(&self) -> Duration {
+        u32::try_from(self.admitted)
+            .ok()
+            .and_then(|admitted| self.total_wait.checked_div(admitted))
+            .unwrap_or_default()
+    }
+}
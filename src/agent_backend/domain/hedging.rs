@@ -0,0 +1,198 @@
+//! Request-hedging policy and turn metadata for slow backend invocations.
+//!
+//! When a backend invocation runs past a latency percentile threshold, the
+//! orchestrator may issue a second attempt and keep whichever response
+//! completes first. Hedging is opt-in per turn (the turn must be marked
+//! idempotent) and bounded by a budget so that hedges cannot multiply load on
+//! an already slow backend.
+
+use super::BackendId;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Errors returned when constructing a [`HedgingPolicy`].
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+pub enum HedgingPolicyError {
+    /// The latency percentile must lie in `1..=99`.
+    #[error("hedging latency percentile must be between 1 and 99, got {0}")]
+    InvalidPercentile(u8),
+    /// The hedge budget must lie in `1..=100` percent of turns.
+    #[error("hedging budget must be between 1 and 100 percent, got {0}")]
+    InvalidBudget(u8),
+}
+
+/// Backend used for the hedged attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "backend_id", rename_all = "snake_case")]
+pub enum HedgeTarget {
+    /// Re-issue the turn on the same backend using a short-lived session.
+    SameBackend,
+    /// Issue the turn on a fallback backend using a short-lived session.
+    Fallback(BackendId),
+}
+
+/// Policy controlling when a slow backend invocation is hedged.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::domain::HedgingPolicy;
+/// use std::time::Duration;
+///
+/// let policy = HedgingPolicy::new(95)
+///     .expect("valid percentile")
+///     .with_min_delay(Duration::from_millis(250))
+///     .with_budget_percent(5)
+///     .expect("valid budget");
+/// assert_eq!(policy.latency_percentile(), 95);
+/// assert_eq!(policy.budget_percent(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HedgingPolicy {
+    latency_percentile: u8,
+    min_delay: Duration,
+    min_samples: usize,
+    budget_percent: u8,
+    target: HedgeTarget,
+}
+
+impl HedgingPolicy {
+    /// Default number of latency samples required before hedging starts.
+    pub const DEFAULT_MIN_SAMPLES: usize = 20;
+
+    /// Default share of turns that may be hedged.
+    pub const DEFAULT_BUDGET_PERCENT: u8 = 10;
+
+    /// Creates a policy hedging on the same backend once an invocation
+    /// exceeds the given latency percentile.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HedgingPolicyError::InvalidPercentile`] when
+    /// `latency_percentile` is outside `1..=99`.
+    pub const fn new(latency_percentile: u8) -> Result<Self, HedgingPolicyError> {
+        if latency_percentile == 0 || latency_percentile > 99 {
+            return Err(HedgingPolicyError::InvalidPercentile(latency_percentile));
+        }
+        Ok(Self {
+            latency_percentile,
+            min_delay: Duration::ZERO,
+            min_samples: Self::DEFAULT_MIN_SAMPLES,
+            budget_percent: Self::DEFAULT_BUDGET_PERCENT,
+            target: HedgeTarget::SameBackend,
+        })
+    }
+
+    /// Sets the floor applied to the percentile-derived hedge delay.
+    #[must_use]
+    pub const fn with_min_delay(mut self, min_delay: Duration) -> Self {
+        self.min_delay = min_delay;
+        self
+    }
+
+    /// Sets how many latency samples a backend needs before it is hedged.
+    #[must_use]
+    pub const fn with_min_samples(mut self, min_samples: usize) -> Self {
+        self.min_samples = min_samples;
+        self
+    }
+
+    /// Sets the maximum share of turns that may be hedged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HedgingPolicyError::InvalidBudget`] when `budget_percent`
+    /// is outside `1..=100`.
+    pub const fn with_budget_percent(
+        mut self,
+        budget_percent: u8,
+    ) -> Result<Self, HedgingPolicyError> {
+        if budget_percent == 0 || budget_percent > 100 {
+            return Err(HedgingPolicyError::InvalidBudget(budget_percent));
+        }
+        self.budget_percent = budget_percent;
+        Ok(self)
+    }
+
+    /// Sends hedged attempts to `backend_id` instead of the same backend.
+    #[must_use]
+    pub const fn with_fallback_backend(mut self, backend_id: BackendId) -> Self {
+        self.target = HedgeTarget::Fallback(backend_id);
+        self
+    }
+
+    /// Returns the latency percentile that triggers a hedge.
+    #[must_use]
+    pub const fn latency_percentile(self) -> u8 {
+        self.latency_percentile
+    }
+
+    /// Returns the minimum hedge delay.
+    #[must_use]
+    pub const fn min_delay(self) -> Duration {
+        self.min_delay
+    }
+
+    /// Returns the number of samples required before hedging starts.
+    #[must_use]
+    pub const fn min_samples(self) -> usize {
+        self.min_samples
+    }
+
+    /// Returns the maximum share of turns that may be hedged.
+    #[must_use]
+    pub const fn budget_percent(self) -> u8 {
+        self.budget_percent
+    }
+
+    /// Returns the backend used for hedged attempts.
+    #[must_use]
+    pub const fn target(self) -> HedgeTarget {
+        self.target
+    }
+}
+
+/// Which attempt supplied the turn response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HedgeWinner {
+    /// The original invocation completed first.
+    Primary,
+    /// The hedged invocation completed first.
+    Hedge,
+}
+
+/// Why a turn that crossed the hedge threshold was not hedged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HedgeSuppression {
+    /// The turn was not marked idempotent.
+    NotIdempotent,
+    /// The hedge budget was exhausted.
+    BudgetExhausted,
+    /// The hedge target backend could not be prepared.
+    TargetUnavailable,
+}
+
+/// Hedging metadata recorded for a turn that crossed the hedge threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum TurnHedging {
+    /// A second attempt was issued.
+    Issued {
+        /// Backend that ran the hedged attempt.
+        hedge_backend_id: BackendId,
+        /// Delay after which the hedge was issued.
+        triggered_after: Duration,
+        /// Which attempt supplied the response.
+        winner: HedgeWinner,
+    },
+    /// The threshold was crossed but no second attempt was issued.
+    Suppressed {
+        /// Delay after which the hedge would have been issued.
+        triggered_after: Duration,
+        /// Guard that prevented the hedge.
+        reason: HedgeSuppression,
+    },
+}
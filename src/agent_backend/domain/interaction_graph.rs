@@ -0,0 +1,296 @@
+//! Graphs of the agents, handoffs, delegations, and tools in a conversation.
+//!
+//! An [`InteractionGraph`] is a renderer-neutral description of who did what
+//! in a conversation. It renders to Graphviz DOT or Mermaid flowchart source
+//! for embedding in postmortems and design documents.
+
+use crate::message::domain::{AgentSession, AgentSessionId, ConversationId, HandoffMetadata};
+use serde::{Deserialize, Serialize};
+
+/// Diagram languages an [`InteractionGraph`] can be rendered to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphFormat {
+    /// Graphviz DOT.
+    Dot,
+    /// Mermaid flowchart.
+    Mermaid,
+}
+
+/// What a node in an [`InteractionGraph`] stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InteractionNodeKind {
+    /// A conversation, either the one graphed or a delegated child.
+    Conversation,
+    /// An agent session.
+    Session,
+    /// The target of a handoff that never produced a session.
+    PendingHandoff,
+    /// A tool called by an agent.
+    Tool,
+}
+
+/// What an edge in an [`InteractionGraph`] stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InteractionEdgeKind {
+    /// A conversation started a session without a handoff.
+    Started,
+    /// Control passed from one session to another.
+    Handoff,
+    /// Work was delegated to a child conversation.
+    Delegation,
+    /// An agent called a tool.
+    ToolUse,
+}
+
+/// A node in an [`InteractionGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InteractionNode {
+    /// Identifier that is valid in both DOT and Mermaid.
+    pub id: String,
+    /// Text shown on the node; may span several lines.
+    pub label: String,
+    /// What the node stands for.
+    pub kind: InteractionNodeKind,
+}
+
+impl InteractionNode {
+    /// Creates a node for a conversation.
+    #[must_use]
+    pub fn conversation(conversation_id: ConversationId) -> Self {
+        Self {
+            id: conversation_node_id(conversation_id),
+            label: format!("conversation\n{conversation_id}"),
+            kind: InteractionNodeKind::Conversation,
+        }
+    }
+
+    /// Creates a node for an agent session.
+    #[must_use]
+    pub fn session(session: &AgentSession) -> Self {
+        Self {
+            id: session_node_id(session.session_id),
+            label: format!(
+                "{}\n{}, {}",
+                session.agent_backend,
+                session.state,
+                plural(session.turn_count(), "turn")
+            ),
+            kind: InteractionNodeKind::Session,
+        }
+    }
+
+    /// Creates a node for the target of an unfinished handoff.
+    #[must_use]
+    pub fn pending_handoff(handoff: &HandoffMetadata) -> Self {
+        Self {
+            id: format!("handoff_{}", handoff.handoff_id.into_inner().simple()),
+            label: format!("{}\nhandoff {}", handoff.target_agent, handoff.status),
+            kind: InteractionNodeKind::PendingHandoff,
+        }
+    }
+
+    /// Creates a node for a tool.
+    #[must_use]
+    pub fn tool(tool_name: &str) -> Self {
+        let encoded: String = tool_name
+            .chars()
+            .map(|ch| {
+                if ch.is_ascii_alphanumeric() {
+                    ch.to_string()
+                } else {
+                    format!("_{:x}", u32::from(ch))
+                }
+            })
+            .collect();
+        Self {
+            id: format!("tool_{encoded}"),
+            label: tool_name.to_owned(),
+            kind: InteractionNodeKind::Tool,
+        }
+    }
+}
+
+/// Returns the node identifier of a conversation.
+pub(crate) fn conversation_node_id(conversation_id: ConversationId) -> String {
+    format!("conversation_{}", conversation_id.into_inner().simple())
+}
+
+/// Returns the node identifier of an agent session.
+pub(crate) fn session_node_id(session_id: AgentSessionId) -> String {
+    format!("session_{}", session_id.into_inner().simple())
+}
+
+/// Returns `"1 call"`, `"2 calls"`, and so on.
+pub(crate) fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {noun}")
+    } else {
+        format!("{count} {noun}s")
+    }
+}
+
+/// A directed edge in an [`InteractionGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InteractionEdge {
+    /// Identifier of the source node.
+    pub from: String,
+    /// Identifier of the target node.
+    pub to: String,
+    /// Text shown on the edge.
+    pub label: String,
+    /// What the edge stands for.
+    pub kind: InteractionEdgeKind,
+}
+
+impl InteractionEdge {
+    /// Creates an edge.
+    #[must_use]
+    pub fn new(
+        from: impl Into<String>,
+        to: impl Into<String>,
+        label: impl Into<String>,
+        kind: InteractionEdgeKind,
+    ) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            label: label.into(),
+            kind,
+        }
+    }
+}
+
+/// The multi-agent interactions of one conversation.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::domain::{
+///     GraphFormat, InteractionEdge, InteractionEdgeKind, InteractionGraph, InteractionNode,
+/// };
+/// use corbusier::message::domain::ConversationId;
+///
+/// let mut graph = InteractionGraph::new(ConversationId::new());
+/// let root = graph.nodes[0].id.clone();
+/// let tool = InteractionNode::tool("search_code");
+/// graph.edges.push(InteractionEdge::new(
+///     root,
+///     tool.id.clone(),
+///     "2 calls",
+///     InteractionEdgeKind::ToolUse,
+/// ));
+/// graph.nodes.push(tool);
+///
+/// assert!(graph.render(GraphFormat::Dot).starts_with("digraph interactions {"));
+/// assert!(graph.render(GraphFormat::Mermaid).contains("tool_search_5fcode([\"search_code\"])"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InteractionGraph {
+    /// The conversation graphed.
+    pub conversation_id: ConversationId,
+    /// Nodes in the order they are drawn; the first is the conversation.
+    pub nodes: Vec<InteractionNode>,
+    /// Edges in the order they are drawn.
+    pub edges: Vec<InteractionEdge>,
+}
+
+impl InteractionGraph {
+    /// Creates a graph holding only the conversation's node.
+    #[must_use]
+    pub fn new(conversation_id: ConversationId) -> Self {
+        Self {
+            conversation_id,
+            nodes: vec![InteractionNode::conversation(conversation_id)],
+            edges: Vec::new(),
+        }
+    }
+
+    /// Renders the graph as diagram source.
+    #[must_use]
+    pub fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.to_dot(),
+            GraphFormat::Mermaid => self.to_mermaid(),
+        }
+    }
+
+    /// Renders the graph as a Graphviz DOT digraph.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec![
+            "digraph interactions {".to_owned(),
+            "  rankdir=LR;".to_owned(),
+        ];
+        lines.extend(self.nodes.iter().map(|node| {
+            let shape = match node.kind {
+                InteractionNodeKind::Conversation => "folder",
+                InteractionNodeKind::Session => "box",
+                InteractionNodeKind::PendingHandoff => "box, style=dashed",
+                InteractionNodeKind::Tool => "ellipse",
+            };
+            format!(
+                "  {} [label=\"{}\", shape={shape}];",
+                node.id,
+                dot_escape(&node.label)
+            )
+        }));
+        lines.extend(self.edges.iter().map(|edge| {
+            let style = match edge.kind {
+                InteractionEdgeKind::Started => "solid",
+                InteractionEdgeKind::Handoff => "bold",
+                InteractionEdgeKind::Delegation => "dashed",
+                InteractionEdgeKind::ToolUse => "dotted",
+            };
+            format!(
+                "  {} -> {} [label=\"{}\", style={style}];",
+                edge.from,
+                edge.to,
+                dot_escape(&edge.label)
+            )
+        }));
+        lines.push("}".to_owned());
+        lines.join("\n")
+    }
+
+    /// Renders the graph as a Mermaid flowchart.
+    #[must_use]
+    pub fn to_mermaid(&self) -> String {
+        let mut lines = vec!["flowchart LR".to_owned()];
+        lines.extend(self.nodes.iter().map(|node| {
+            let label = mermaid_escape(&node.label);
+            match node.kind {
+                InteractionNodeKind::Conversation => format!("  {}{{{{\"{label}\"}}}}", node.id),
+                InteractionNodeKind::Session => format!("  {}[\"{label}\"]", node.id),
+                InteractionNodeKind::PendingHandoff => format!("  {}>\"{label}\"]", node.id),
+                InteractionNodeKind::Tool => format!("  {}([\"{label}\"])", node.id),
+            }
+        }));
+        lines.extend(self.edges.iter().map(|edge| {
+            let arrow = match edge.kind {
+                InteractionEdgeKind::Started => "-->",
+                InteractionEdgeKind::Handoff => "==>",
+                InteractionEdgeKind::Delegation | InteractionEdgeKind::ToolUse => "-.->",
+            };
+            format!(
+                "  {} {arrow}|\"{}\"| {}",
+                edge.from,
+                mermaid_escape(&edge.label),
+                edge.to
+            )
+        }));
+        lines.join("\n")
+    }
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn mermaid_escape(text: &str) -> String {
+    text.replace('"', "#quot;").replace('\n', "<br/>")
+}
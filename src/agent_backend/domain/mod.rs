@@ -1,24 +1,69 @@
 //! Domain model for agent backend registration, turn execution, and sessions.
 //!
 //! The agent backend domain models registration metadata, turn execution value
-//! objects, and session lifecycle state for pluggable AI agent backends. All
-//! infrastructure concerns are kept outside the domain boundary.
+//! objects, turn outcomes and their completion callbacks, session lifecycle
+//! state, long-term memory, and context assembly reports for pluggable AI
+//! agent backends. All infrastructure concerns are kept outside the domain
+//! boundary.
 
+mod agent_memory;
 mod capabilities;
+mod context_assembly;
+mod dataset;
+mod deprecation;
 mod error;
+mod experiment;
+mod experiment_report;
+mod fair_share;
+mod hedging;
 mod ids;
 mod info;
+mod interaction_graph;
 mod name;
+mod outcome;
+mod pii;
 mod registration;
 mod session;
 mod status;
 mod turn;
+mod turn_callback;
 
+pub use agent_memory::{
+    CompletedTurn, MAX_MEMORY_CONFIDENCE, MemoryDecayPolicy, MemoryDomainError, MemoryFact,
+    MemoryFactDraft, MemoryRecall, MemoryRecallDecision, MemoryRecallQuery, MemoryRecallVerdict,
+    PersistedMemoryFactData, RecalledMemory, assemble_memory_prompt,
+};
 pub use capabilities::AgentCapabilities;
+pub use context_assembly::ContextAssemblyReport;
+pub use dataset::{
+    DatasetRecordingPolicy, DatasetRecordingPolicyError, DatasetTurnOutcome, ToolInvocationSample,
+    encode_jsonl,
+};
+pub use deprecation::{DeprecationImpactReport, RepinOutcome};
 pub use error::{BackendDomainError, ParseBackendStatusError};
-pub use ids::BackendId;
+pub use experiment::{
+    BackendExperiment, ExperimentArm, ExperimentArmKey, ExperimentDomainError, ExperimentStatus,
+    ParseExperimentValueError, PersistedExperimentData,
+};
+pub use experiment_report::{
+    ArmStatistics, ExperimentObservation, ExperimentReport, ExperimentSignal, HumanFeedback,
+};
+pub use fair_share::{FairShareBuckets, FairSharePolicy, TenantWaitMetrics};
+pub use hedging::{
+    HedgeSuppression, HedgeTarget, HedgeWinner, HedgingPolicy, HedgingPolicyError, TurnHedging,
+};
+pub use ids::{BackendId, ExperimentId, MemoryFactId, TurnCallbackId};
 pub use info::BackendInfo;
+pub use interaction_graph::{
+    GraphFormat, InteractionEdge, InteractionEdgeKind, InteractionGraph, InteractionNode,
+    InteractionNodeKind,
+};
+pub(crate) use interaction_graph::{conversation_node_id, plural, session_node_id};
 pub use name::BackendName;
+pub use outcome::{
+    ParseTurnOutcomeKindError, TurnEscalation, TurnOutcome, TurnOutcomeKind, TurnOutcomeRecord,
+};
+pub use pii::{PiiScrubber, REDACTED_EMAIL, REDACTED_NUMBER, REDACTED_VALUE};
 pub use registration::{AgentBackendRegistration, PersistedBackendData};
 pub use session::{
     ParseTurnSessionStatusError, PersistedTurnSessionData, ReservedTurnSessionCreateParams,
@@ -30,3 +75,7 @@ pub use turn::{
     ToolCallAudit, ToolCallAuditStatus, ToolCallRequest, ToolCallResult, TurnDomainError,
     TurnExecutionRequest, TurnExecutionResult, deterministic_tool_call_id,
 };
+pub use turn_callback::{
+    TurnCallback, TurnCallbackDomainError, TurnCallbackRetryPolicy, TurnCallbackTarget,
+    TurnCompletionNotice,
+};
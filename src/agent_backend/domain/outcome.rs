@@ -0,0 +1,181 @@
+//! Structured outcomes of orchestrated agent turns.
+//!
+//! Every turn the orchestrator runs ends in exactly one [`TurnOutcome`].
+//! Policies, metrics, and retry logic key off its [`TurnOutcomeKind`] rather
+//! than matching on orchestration errors.
+
+use super::{BackendId, TurnSessionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Stable taxonomy of turn outcomes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TurnOutcomeKind {
+    /// The turn ran to completion.
+    Completed,
+    /// The agent stopped to ask a human for input.
+    NeedsHuman,
+    /// The agent stopped because its budget ran out.
+    BudgetExhausted,
+    /// A tool call could not be routed, so the turn did not complete.
+    ToolBlocked,
+    /// The backend could not be reached or failed to run the turn.
+    BackendFailed,
+    /// The agent handed the conversation to another agent.
+    HandedOff,
+}
+
+impl TurnOutcomeKind {
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Completed => "completed",
+            Self::NeedsHuman => "needs_human",
+            Self::BudgetExhausted => "budget_exhausted",
+            Self::ToolBlocked => "tool_blocked",
+            Self::BackendFailed => "backend_failed",
+            Self::HandedOff => "handed_off",
+        }
+    }
+
+    /// Returns `true` when rerunning the same turn may succeed.
+    ///
+    /// Only backend failures are transient; every other outcome either
+    /// succeeded or needs something to change before the turn is retried.
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        matches!(self, Self::BackendFailed)
+    }
+}
+
+impl fmt::Display for TurnOutcomeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an invalid turn outcome kind.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("unknown turn outcome kind: {0}")]
+pub struct ParseTurnOutcomeKindError(pub String);
+
+impl TryFrom<&str> for TurnOutcomeKind {
+    type Error = ParseTurnOutcomeKindError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "completed" => Ok(Self::Completed),
+            "needs_human" => Ok(Self::NeedsHuman),
+            "budget_exhausted" => Ok(Self::BudgetExhausted),
+            "tool_blocked" => Ok(Self::ToolBlocked),
+            "backend_failed" => Ok(Self::BackendFailed),
+            "handed_off" => Ok(Self::HandedOff),
+            other => Err(ParseTurnOutcomeKindError(other.to_owned())),
+        }
+    }
+}
+
+/// How an orchestrated turn ended, with the details of non-completion.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TurnOutcome {
+    /// The turn ran to completion.
+    Completed,
+    /// The agent stopped to ask a human for input.
+    NeedsHuman {
+        /// What the agent needs from the human.
+        reason: String,
+    },
+    /// The agent stopped because its budget ran out.
+    BudgetExhausted {
+        /// Which budget ran out.
+        reason: String,
+    },
+    /// A tool call could not be routed.
+    ToolBlocked {
+        /// Deterministic identifier of the blocked call.
+        call_id: String,
+        /// Name of the blocked tool.
+        tool_name: String,
+        /// Why the call was blocked.
+        reason: String,
+    },
+    /// The backend could not be reached or failed to run the turn.
+    BackendFailed {
+        /// The backend failure.
+        reason: String,
+    },
+    /// The agent handed the conversation to another agent.
+    HandedOff {
+        /// The agent that takes over the conversation.
+        target_agent: String,
+    },
+}
+
+impl TurnOutcome {
+    /// Returns the outcome's kind.
+    #[must_use]
+    pub const fn kind(&self) -> TurnOutcomeKind {
+        match self {
+            Self::Completed => TurnOutcomeKind::Completed,
+            Self::NeedsHuman { .. } => TurnOutcomeKind::NeedsHuman,
+            Self::BudgetExhausted { .. } => TurnOutcomeKind::BudgetExhausted,
+            Self::ToolBlocked { .. } => TurnOutcomeKind::ToolBlocked,
+            Self::BackendFailed { .. } => TurnOutcomeKind::BackendFailed,
+            Self::HandedOff { .. } => TurnOutcomeKind::HandedOff,
+        }
+    }
+}
+
+/// A runtime's report that the agent stopped before finishing its turn.
+///
+/// Escalated turns route none of the tool calls the runtime returned.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TurnEscalation {
+    /// The agent needs a human to answer before it can continue.
+    NeedsHuman {
+        /// What the agent needs from the human.
+        reason: String,
+    },
+    /// The agent ran out of budget.
+    BudgetExhausted {
+        /// Which budget ran out.
+        reason: String,
+    },
+    /// The agent handed the conversation to another agent.
+    HandedOff {
+        /// The agent that takes over the conversation.
+        target_agent: String,
+    },
+}
+
+impl From<TurnEscalation> for TurnOutcome {
+    fn from(escalation: TurnEscalation) -> Self {
+        match escalation {
+            TurnEscalation::NeedsHuman { reason } => Self::NeedsHuman { reason },
+            TurnEscalation::BudgetExhausted { reason } => Self::BudgetExhausted { reason },
+            TurnEscalation::HandedOff { target_agent } => Self::HandedOff { target_agent },
+        }
+    }
+}
+
+/// A turn outcome persisted for one orchestrated turn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TurnOutcomeRecord {
+    /// Backend the turn ran on.
+    pub backend_id: BackendId,
+    /// Conversation the turn belongs to.
+    pub conversation_id: Uuid,
+    /// Session the turn ran in, when one was resolved.
+    pub session_id: Option<TurnSessionId>,
+    /// How the turn ended.
+    pub outcome: TurnOutcome,
+    /// When the outcome was recorded.
+    pub recorded_at: DateTime<Utc>,
+}
@@ -0,0 +1,172 @@
+//! Scrubbing of personal data from recorded tool invocations.
+//!
+//! The scrubber redacts JSON values stored under sensitive keys outright and
+//! masks email addresses and long digit runs (phone, card, and account
+//! numbers) found inside free text. It is deliberately conservative: a false
+//! positive costs one token of training context, a false negative leaks data
+//! into a dataset.
+
+use serde_json::{Map, Value};
+
+/// Replacement for values stored under sensitive keys.
+pub const REDACTED_VALUE: &str = "[redacted]";
+
+/// Replacement for email addresses found in text.
+pub const REDACTED_EMAIL: &str = "[redacted-email]";
+
+/// Replacement for long digit runs found in text.
+pub const REDACTED_NUMBER: &str = "[redacted-number]";
+
+/// Minimum digit count for a token to be treated as a personal number.
+const MIN_REDACTED_DIGITS: usize = 7;
+
+/// Key fragments whose values are always redacted.
+const DEFAULT_SENSITIVE_KEYS: &[&str] = &[
+    "password",
+    "passwd",
+    "secret",
+    "token",
+    "apikey",
+    "authorization",
+    "cookie",
+    "credential",
+    "email",
+    "phone",
+    "ssn",
+];
+
+/// Redacts personal data from prompts and JSON payloads.
+///
+/// Keys are compared case-insensitively with `-` and `_` ignored, and match
+/// when they contain any configured fragment, so `X-Api-Key` and
+/// `github_token` are both redacted.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::domain::PiiScrubber;
+/// use serde_json::json;
+///
+/// let scrubber = PiiScrubber::default();
+/// assert_eq!(
+///     scrubber.scrub_value(&json!({"api_key": "sk-123", "path": "src/lib.rs"})),
+///     json!({"api_key": "[redacted]", "path": "src/lib.rs"}),
+/// );
+/// assert_eq!(
+///     scrubber.scrub_text("mail ada@example.com"),
+///     "mail [redacted-email]",
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiiScrubber {
+    sensitive_keys: Vec<String>,
+}
+
+impl Default for PiiScrubber {
+    fn default() -> Self {
+        Self {
+            sensitive_keys: DEFAULT_SENSITIVE_KEYS
+                .iter()
+                .map(|key| (*key).to_owned())
+                .collect(),
+        }
+    }
+}
+
+impl PiiScrubber {
+    /// Adds a key fragment whose values are always redacted.
+    #[must_use]
+    pub fn with_sensitive_key(mut self, key: &str) -> Self {
+        self.sensitive_keys.push(normalize_key(key));
+        self
+    }
+
+    /// Returns `true` when values under `key` are redacted.
+    #[must_use]
+    pub fn is_sensitive_key(&self, key: &str) -> bool {
+        let normalized = normalize_key(key);
+        self.sensitive_keys
+            .iter()
+            .any(|fragment| normalized.contains(fragment.as_str()))
+    }
+
+    /// Returns a copy of `value` with personal data redacted.
+    #[must_use]
+    pub fn scrub_value(&self, value: &Value) -> Value {
+        match value {
+            Value::String(text) => Value::String(self.scrub_text(text)),
+            Value::Array(items) => {
+                Value::Array(items.iter().map(|item| self.scrub_value(item)).collect())
+            }
+            Value::Object(fields) => Value::Object(self.scrub_fields(fields)),
+            Value::Null | Value::Bool(_) | Value::Number(_) => value.clone(),
+        }
+    }
+
+    /// Returns `text` with email addresses and long digit runs masked.
+    ///
+    /// Whitespace and punctuation surrounding a masked token are preserved.
+    #[must_use]
+    pub fn scrub_text(&self, text: &str) -> String {
+        text.split_inclusive(char::is_whitespace)
+            .map(scrub_token)
+            .collect()
+    }
+
+    fn scrub_fields(&self, fields: &Map<String, Value>) -> Map<String, Value> {
+        fields
+            .iter()
+            .map(|(key, field)| {
+                let scrubbed = if self.is_sensitive_key(key) {
+                    Value::String(REDACTED_VALUE.to_owned())
+                } else {
+                    self.scrub_value(field)
+                };
+                (key.clone(), scrubbed)
+            })
+            .collect()
+    }
+}
+
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|character| *character != '-' && *character != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Masks one whitespace-terminated token, keeping its surrounding
+/// punctuation.
+fn scrub_token(token: &str) -> String {
+    let core = token.trim_matches(|character: char| {
+        !character.is_alphanumeric() && character != '+' && character != '@'
+    });
+    let replacement = if is_email(core) {
+        REDACTED_EMAIL
+    } else if is_personal_number(core) {
+        REDACTED_NUMBER
+    } else {
+        return token.to_owned();
+    };
+    let start = token.find(core).unwrap_or_default();
+    let (prefix, rest) = token.split_at(start);
+    let suffix = rest.get(core.len()..).unwrap_or_default();
+    format!("{prefix}{replacement}{suffix}")
+}
+
+fn is_email(token: &str) -> bool {
+    let Some((local, domain)) = token.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && domain
+            .split_once('.')
+            .is_some_and(|(host, tld)| !host.is_empty() && !tld.is_empty())
+}
+
+fn is_personal_number(token: &str) -> bool {
+    token
+        .chars()
+        .all(|character| character.is_ascii_digit() || "+-().".contains(character))
+        && token.chars().filter(char::is_ascii_digit).count() >= MIN_REDACTED_DIGITS
+}
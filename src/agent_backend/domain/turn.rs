@@ -1,5 +1,6 @@
 //! Turn-execution domain types for agent backend orchestration.
 
+use super::{TurnEscalation, TurnOutcome};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use sha2::{Digest, Sha256};
@@ -205,6 +206,8 @@ impl TurnExecutionRequest {
 pub struct TurnExecutionResult {
     assistant_response: String,
     tool_calls: Vec<ToolCallRequest>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    escalation: Option<TurnEscalation>,
 }
 
 impl TurnExecutionResult {
@@ -214,9 +217,17 @@ impl TurnExecutionResult {
         Self {
             assistant_response: assistant_response.into(),
             tool_calls,
+            escalation: None,
         }
     }
 
+    /// Reports that the agent stopped before finishing its turn.
+    #[must_use]
+    pub fn with_escalation(mut self, escalation: TurnEscalation) -> Self {
+        self.escalation = Some(escalation);
+        self
+    }
+
     /// Returns assistant response text.
     #[must_use]
     pub fn assistant_response(&self) -> &str {
@@ -228,12 +239,41 @@ impl TurnExecutionResult {
     pub fn tool_calls(&self) -> &[ToolCallRequest] {
         &self.tool_calls
     }
+
+    /// Returns the reported escalation, if the agent stopped early.
+    #[must_use]
+    pub const fn escalation(&self) -> Option<&TurnEscalation> {
+        self.escalation.as_ref()
+    }
+
+    /// Returns the tool calls to route: none when the turn escalated.
+    #[must_use]
+    pub fn routable_tool_calls(&self) -> &[ToolCallRequest] {
+        if self.escalation.is_some() {
+            &[]
+        } else {
+            &self.tool_calls
+        }
+    }
+
+    /// Returns the outcome of a turn whose tool calls all routed.
+    #[must_use]
+    pub fn outcome(&self) -> TurnOutcome {
+        self.escalation
+            .clone()
+            .map_or(TurnOutcome::Completed, TurnOutcome::from)
+    }
 }
 
 /// Computes a deterministic call identifier for `tool_call` at `index`.
+///
+/// Call identifiers are persisted with tool results and datasets, so the
+/// hashed payload only sorts object keys. Numbers are hashed as written,
+/// unlike [`canonicalize`](crate::message::canonical::canonicalize), so
+/// identifiers stay stable across releases.
 #[must_use]
 pub fn deterministic_tool_call_id(tool_call: &ToolCallRequest, index: usize) -> String {
-    let payload = canonical_json_value(&Value::Object(Map::from_iter([
+    let payload = Value::Object(Map::from_iter([
         ("index".to_owned(), Value::from(index)),
         (
             "tool_name".to_owned(),
@@ -241,40 +281,28 @@ pub fn deterministic_tool_call_id(tool_call: &ToolCallRequest, index: usize) ->
         ),
         (
             "parameters".to_owned(),
-            canonical_json_value(tool_call.parameters()),
+            with_sorted_keys(tool_call.parameters()),
         ),
-    ])))
-    .to_string();
+    ]));
     let mut hasher = Sha256::new();
-    hasher.update(payload.as_bytes());
+    hasher.update(with_sorted_keys(&payload).to_string().as_bytes());
     format!("call-{:x}", hasher.finalize())
 }
 
-fn canonical_json_value(value: &Value) -> Value {
-    as_json_object(value).map_or_else(
-        || as_json_array(value).map_or_else(|| value.clone(), canonical_array_value),
-        canonical_object_value,
-    )
-}
-
-fn canonical_object_value(map: &Map<String, Value>) -> Value {
-    let mut keys = map.keys().collect::<Vec<_>>();
-    keys.sort_unstable();
-    let canonical_map = keys
-        .into_iter()
-        .map(|key| (key.to_owned(), canonical_json_value(&map[key])))
-        .collect::<Map<String, Value>>();
-    Value::Object(canonical_map)
-}
-
-fn canonical_array_value(values: &[Value]) -> Value {
-    Value::Array(values.iter().map(canonical_json_value).collect())
-}
-
-fn as_json_object(value: &Value) -> Option<&Map<String, Value>> {
-    value.as_object()
-}
-
-fn as_json_array(value: &Value) -> Option<&[Value]> {
-    value.as_array().map(Vec::as_slice)
+/// Sorts object keys at every depth, leaving every other value unchanged.
+fn with_sorted_keys(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_unstable_by_key(|(key, _)| *key);
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, item)| (key.clone(), with_sorted_keys(item)))
+                    .collect(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.iter().map(with_sorted_keys).collect()),
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => value.clone(),
+    }
 }
@@ -0,0 +1,304 @@
+//! One-shot callbacks fired when an orchestrated turn ends.
+//!
+//! A caller that starts a turn without waiting for it registers a
+//! [`TurnCallback`] against the conversation. When the next turn in that
+//! conversation records its [`TurnOutcome`], the callback is consumed and a
+//! [`TurnCompletionNotice`] is delivered to its target, retried under a
+//! [`TurnCallbackRetryPolicy`] until the target accepts it.
+
+use super::{BackendId, TurnCallbackId, TurnOutcome, TurnOutcomeRecord, TurnSessionId};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors returned when constructing turn callback values.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum TurnCallbackDomainError {
+    /// Callback URLs must be absolute `http` or `https` URLs.
+    #[error("callback URL must start with http:// or https://, got {0:?}")]
+    InvalidUrl(String),
+    /// Reply subjects must be non-empty and free of whitespace.
+    #[error("reply subject must be non-empty and contain no whitespace, got {0:?}")]
+    InvalidReplySubject(String),
+    /// The stored target kind is not recognised.
+    #[error("unknown turn callback target kind: {0}")]
+    UnknownTargetKind(String),
+    /// Retry policies must allow at least one delivery attempt.
+    #[error("turn callback retry policy must allow at least one attempt")]
+    NoAttempts,
+}
+
+/// Where a turn completion notice is delivered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TurnCallbackTarget {
+    /// POST the notice to a webhook URL.
+    Url {
+        /// Absolute `http` or `https` URL.
+        url: String,
+    },
+    /// Publish the notice on an event-bus reply subject.
+    ReplySubject {
+        /// Subject the caller is listening on.
+        subject: String,
+    },
+}
+
+impl TurnCallbackTarget {
+    /// Creates a webhook URL target.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnCallbackDomainError::InvalidUrl`] unless `url` is an
+    /// absolute `http` or `https` URL.
+    pub fn url(url: impl Into<String>) -> Result<Self, TurnCallbackDomainError> {
+        let url = url.into();
+        let host = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"));
+        match host {
+            Some(rest) if !rest.is_empty() && !url.contains(char::is_whitespace) => {
+                Ok(Self::Url { url })
+            }
+            _ => Err(TurnCallbackDomainError::InvalidUrl(url)),
+        }
+    }
+
+    /// Creates an event-bus reply subject target.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnCallbackDomainError::InvalidReplySubject`] when
+    /// `subject` is empty or contains whitespace.
+    pub fn reply_subject(subject: impl Into<String>) -> Result<Self, TurnCallbackDomainError> {
+        let subject = subject.into();
+        if subject.is_empty() || subject.contains(char::is_whitespace) {
+            return Err(TurnCallbackDomainError::InvalidReplySubject(subject));
+        }
+        Ok(Self::ReplySubject { subject })
+    }
+
+    /// Returns the canonical storage representation of the target's kind.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Url { .. } => "url",
+            Self::ReplySubject { .. } => "reply_subject",
+        }
+    }
+
+    /// Returns the URL or subject the notice is delivered to.
+    #[must_use]
+    pub fn address(&self) -> &str {
+        match self {
+            Self::Url { url } => url,
+            Self::ReplySubject { subject } => subject,
+        }
+    }
+
+    /// Reconstructs a target from its storage representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnCallbackDomainError`] when `kind` is unknown or the
+    /// address is invalid for it.
+    pub fn from_parts(kind: &str, address: &str) -> Result<Self, TurnCallbackDomainError> {
+        match kind {
+            "url" => Self::url(address),
+            "reply_subject" => Self::reply_subject(address),
+            other => Err(TurnCallbackDomainError::UnknownTargetKind(other.to_owned())),
+        }
+    }
+}
+
+/// A one-shot callback awaiting the end of a conversation's next turn.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::domain::{TurnCallback, TurnCallbackTarget};
+/// use mockable::DefaultClock;
+/// use uuid::Uuid;
+///
+/// let target = TurnCallbackTarget::url("https://client.example/turns").expect("valid URL");
+/// let callback = TurnCallback::new(Uuid::new_v4(), target, &DefaultClock);
+/// assert_eq!(callback.target().address(), "https://client.example/turns");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TurnCallback {
+    id: TurnCallbackId,
+    conversation_id: Uuid,
+    target: TurnCallbackTarget,
+    registered_at: DateTime<Utc>,
+}
+
+impl TurnCallback {
+    /// Creates a callback for the next turn in `conversation_id`.
+    #[must_use]
+    pub fn new(
+        conversation_id: Uuid,
+        target: TurnCallbackTarget,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        Self {
+            id: TurnCallbackId::new(),
+            conversation_id,
+            target,
+            registered_at: clock.utc(),
+        }
+    }
+
+    /// Reconstructs a callback from persisted fields.
+    #[must_use]
+    pub const fn from_persisted(
+        id: TurnCallbackId,
+        conversation_id: Uuid,
+        target: TurnCallbackTarget,
+        registered_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            conversation_id,
+            target,
+            registered_at,
+        }
+    }
+
+    /// Returns the callback identifier.
+    #[must_use]
+    pub const fn id(&self) -> TurnCallbackId {
+        self.id
+    }
+
+    /// Returns the conversation the callback waits on.
+    #[must_use]
+    pub const fn conversation_id(&self) -> Uuid {
+        self.conversation_id
+    }
+
+    /// Returns where the notice is delivered.
+    #[must_use]
+    pub const fn target(&self) -> &TurnCallbackTarget {
+        &self.target
+    }
+
+    /// Returns when the callback was registered.
+    #[must_use]
+    pub const fn registered_at(&self) -> DateTime<Utc> {
+        self.registered_at
+    }
+}
+
+/// The body delivered to a callback target when its turn ends.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TurnCompletionNotice {
+    /// The callback being answered.
+    pub callback_id: TurnCallbackId,
+    /// Conversation the turn belongs to.
+    pub conversation_id: Uuid,
+    /// Backend the turn ran on.
+    pub backend_id: BackendId,
+    /// Session the turn ran in, when one was resolved.
+    pub session_id: Option<TurnSessionId>,
+    /// How the turn ended.
+    pub outcome: TurnOutcome,
+    /// When the outcome was recorded.
+    pub completed_at: DateTime<Utc>,
+}
+
+impl TurnCompletionNotice {
+    /// Builds the notice answering `callback` with `record`.
+    #[must_use]
+    pub fn new(callback: &TurnCallback, record: &TurnOutcomeRecord) -> Self {
+        Self {
+            callback_id: callback.id(),
+            conversation_id: record.conversation_id,
+            backend_id: record.backend_id,
+            session_id: record.session_id,
+            outcome: record.outcome.clone(),
+            completed_at: record.recorded_at,
+        }
+    }
+}
+
+/// How failed callback deliveries are retried.
+///
+/// The delay before each retry doubles from the initial backoff up to the
+/// maximum. A notice is abandoned once it has failed `max_attempts` times.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::domain::TurnCallbackRetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = TurnCallbackRetryPolicy::new(3).expect("at least one attempt");
+/// assert_eq!(policy.backoff_after(1), Duration::from_secs(1));
+/// assert_eq!(policy.backoff_after(2), Duration::from_secs(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnCallbackRetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl TurnCallbackRetryPolicy {
+    /// Delivery attempts made unless configured otherwise.
+    pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+    /// Creates a policy making up to `max_attempts` attempts with the
+    /// default backoff.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnCallbackDomainError::NoAttempts`] when `max_attempts`
+    /// is zero.
+    pub const fn new(max_attempts: u32) -> Result<Self, TurnCallbackDomainError> {
+        if max_attempts == 0 {
+            return Err(TurnCallbackDomainError::NoAttempts);
+        }
+        Ok(Self {
+            max_attempts,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        })
+    }
+
+    /// Replaces the delay before the first retry and the cap on later ones.
+    #[must_use]
+    pub const fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Returns the number of delivery attempts made before giving up.
+    #[must_use]
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the delay before retrying a notice that has failed
+    /// `attempts` times.
+    #[must_use]
+    pub fn backoff_after(&self, attempts: u32) -> Duration {
+        let doublings = attempts.saturating_sub(1).min(31);
+        self.initial_backoff
+            .saturating_mul(1_u32 << doublings)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for TurnCallbackRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: Self::DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
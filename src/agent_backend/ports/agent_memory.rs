@@ -0,0 +1,122 @@
+//! Port contracts for long-term agent memory.
+//!
+//! Memory needs three collaborators: a repository holding facts per tenant
+//! and backend, an embedder turning text into vectors for recall, and an
+//! extractor proposing facts from completed turns.
+
+use crate::agent_backend::domain::{
+    BackendId, CompletedTurn, MemoryFact, MemoryFactDraft, MemoryFactId,
+};
+use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for agent memory repository operations.
+pub type AgentMemoryRepositoryResult<T> = Result<T, AgentMemoryRepositoryError>;
+
+/// Memory fact persistence contract.
+///
+/// All queries and mutations are scoped to the tenant identified by
+/// [`RequestContext::tenant_id`](crate::context::RequestContext).
+#[async_trait]
+pub trait AgentMemoryRepository: Send + Sync {
+    /// Stores a fact, replacing any stored fact with the same identifier.
+    async fn upsert(
+        &self,
+        ctx: &RequestContext,
+        fact: &MemoryFact,
+    ) -> AgentMemoryRepositoryResult<()>;
+
+    /// Finds a fact by identifier.
+    ///
+    /// Returns `None` when the fact does not exist.
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: MemoryFactId,
+    ) -> AgentMemoryRepositoryResult<Option<MemoryFact>>;
+
+    /// Returns a page of the facts remembered for a backend, oldest first.
+    async fn list_for_backend(
+        &self,
+        ctx: &RequestContext,
+        backend_id: BackendId,
+        page: PageRequest,
+    ) -> AgentMemoryRepositoryResult<Page<MemoryFact>>;
+
+    /// Deletes a fact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AgentMemoryRepositoryError::NotFound`] when the fact does
+    /// not exist.
+    async fn delete(
+        &self,
+        ctx: &RequestContext,
+        id: MemoryFactId,
+    ) -> AgentMemoryRepositoryResult<()>;
+}
+
+/// Errors returned by agent memory repository implementations.
+#[derive(Debug, Clone, Error)]
+pub enum AgentMemoryRepositoryError {
+    /// The fact was not found.
+    #[error("memory fact not found: {0}")]
+    NotFound(MemoryFactId),
+
+    /// Persisted data could not be reconstructed into domain types.
+    #[error("invalid persisted data: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Persistence-layer failure.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl AgentMemoryRepositoryError {
+    /// Wraps a data-quality or deserialization error from persisted rows.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+
+    /// Wraps a persistence error.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
+
+/// Turns text into embedding vectors for memory recall.
+///
+/// Facts and prompts must be embedded by the same model for their
+/// similarity to be meaningful.
+#[async_trait]
+pub trait MemoryEmbedder: Send + Sync {
+    /// Embeds `text`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MemoryModelError`] when the embedding model fails.
+    async fn embed(&self, ctx: &RequestContext, text: &str) -> Result<Vec<f32>, MemoryModelError>;
+}
+
+/// Proposes facts worth remembering from completed turns.
+#[async_trait]
+pub trait MemoryFactExtractor: Send + Sync {
+    /// Extracts facts from a completed turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MemoryModelError`] when extraction fails.
+    async fn extract(
+        &self,
+        ctx: &RequestContext,
+        turn: &CompletedTurn,
+    ) -> Result<Vec<MemoryFactDraft>, MemoryModelError>;
+}
+
+/// Error returned by the embedding and extraction models behind memory.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("memory model failed: {0}")]
+pub struct MemoryModelError(pub String);
@@ -0,0 +1,59 @@
+//! Port contract for persisting context assembly reports.
+
+use crate::agent_backend::domain::ContextAssemblyReport;
+use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Result type for context assembly report repository operations.
+pub type ContextAssemblyReportRepositoryResult<T> = Result<T, ContextAssemblyReportRepositoryError>;
+
+/// Append-only store of context assembly reports.
+///
+/// All queries and mutations are scoped to the tenant identified by
+/// [`RequestContext::tenant_id`](crate::context::RequestContext).
+#[async_trait]
+pub trait ContextAssemblyReportRepository: Send + Sync {
+    /// Records how one turn's prompt was assembled.
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        report: &ContextAssemblyReport,
+    ) -> ContextAssemblyReportRepositoryResult<()>;
+
+    /// Returns a page of the reports recorded for a conversation, in the
+    /// order they were recorded.
+    async fn list_for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+        page: PageRequest,
+    ) -> ContextAssemblyReportRepositoryResult<Page<ContextAssemblyReport>>;
+}
+
+/// Errors returned by context assembly report repository implementations.
+#[derive(Debug, Clone, Error)]
+pub enum ContextAssemblyReportRepositoryError {
+    /// Persisted data could not be reconstructed into domain types.
+    #[error("invalid persisted data: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Persistence-layer failure.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl ContextAssemblyReportRepositoryError {
+    /// Wraps a data-quality or deserialization error from persisted rows.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+
+    /// Wraps a persistence error.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
@@ -0,0 +1,53 @@
+//! Port contract for tool invocation dataset storage.
+
+use crate::agent_backend::domain::ToolInvocationSample;
+use crate::context::RequestContext;
+use async_trait::async_trait;
+use bytes::Bytes;
+use thiserror::Error;
+
+/// Result type for dataset store operations.
+pub type ToolDatasetResult<T> = Result<T, ToolDatasetError>;
+
+/// Storage contract for recorded tool invocation samples.
+///
+/// Stores are append-only and tenant-scoped: samples appended under one
+/// tenant are never exported under another.
+#[async_trait]
+pub trait ToolDatasetStore: Send + Sync {
+    /// Appends samples recorded for one turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolDatasetError::Encode`] when a sample cannot be
+    /// serialized, or [`ToolDatasetError::Write`] when the write fails.
+    async fn append_samples(
+        &self,
+        ctx: &RequestContext,
+        samples: &[ToolInvocationSample],
+    ) -> ToolDatasetResult<()>;
+
+    /// Exports every sample recorded for the tenant as JSON Lines, oldest
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolDatasetError::Read`] when the samples cannot be read.
+    async fn export_jsonl(&self, ctx: &RequestContext) -> ToolDatasetResult<Bytes>;
+}
+
+/// Errors returned by dataset store operations.
+#[derive(Debug, Error)]
+pub enum ToolDatasetError {
+    /// A sample could not be serialized.
+    #[error("dataset sample encoding failed: {0}")]
+    Encode(String),
+
+    /// Writing samples failed.
+    #[error("dataset store write failed: {0}")]
+    Write(String),
+
+    /// Reading samples failed.
+    #[error("dataset store read failed: {0}")]
+    Read(String),
+}
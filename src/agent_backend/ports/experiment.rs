@@ -0,0 +1,110 @@
+//! Repository port for backend experiments and their outcome observations.
+
+use crate::agent_backend::domain::{BackendExperiment, ExperimentId, ExperimentObservation};
+use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for experiment repository operations.
+pub type ExperimentRepositoryResult<T> = Result<T, ExperimentRepositoryError>;
+
+/// Experiment persistence contract.
+///
+/// All queries and mutations are scoped to the tenant identified by
+/// [`RequestContext::tenant_id`](crate::context::RequestContext).
+#[async_trait]
+pub trait ExperimentRepository: Send + Sync {
+    /// Stores a new experiment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentRepositoryError::DuplicateExperiment`] when the
+    /// experiment identifier already exists.
+    async fn create(
+        &self,
+        ctx: &RequestContext,
+        experiment: &BackendExperiment,
+    ) -> ExperimentRepositoryResult<()>;
+
+    /// Persists a status change to an existing experiment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentRepositoryError::NotFound`] when the experiment
+    /// does not exist.
+    async fn update(
+        &self,
+        ctx: &RequestContext,
+        experiment: &BackendExperiment,
+    ) -> ExperimentRepositoryResult<()>;
+
+    /// Finds an experiment by identifier.
+    ///
+    /// Returns `None` when the experiment does not exist.
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: ExperimentId,
+    ) -> ExperimentRepositoryResult<Option<BackendExperiment>>;
+
+    /// Returns a page of running experiments, oldest first.
+    async fn list_running(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> ExperimentRepositoryResult<Page<BackendExperiment>>;
+
+    /// Appends an outcome observation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentRepositoryError::NotFound`] when the observed
+    /// experiment does not exist.
+    async fn record_observation(
+        &self,
+        ctx: &RequestContext,
+        observation: &ExperimentObservation,
+    ) -> ExperimentRepositoryResult<()>;
+
+    /// Returns a page of an experiment's observations, in recording order.
+    async fn list_observations(
+        &self,
+        ctx: &RequestContext,
+        id: ExperimentId,
+        page: PageRequest,
+    ) -> ExperimentRepositoryResult<Page<ExperimentObservation>>;
+}
+
+/// Errors returned by experiment repository implementations.
+#[derive(Debug, Clone, Error)]
+pub enum ExperimentRepositoryError {
+    /// An experiment with the same identifier already exists.
+    #[error("duplicate experiment identifier: {0}")]
+    DuplicateExperiment(ExperimentId),
+
+    /// The experiment was not found.
+    #[error("experiment not found: {0}")]
+    NotFound(ExperimentId),
+
+    /// Persisted data could not be reconstructed into domain types.
+    #[error("invalid persisted data: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Persistence-layer failure.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl ExperimentRepositoryError {
+    /// Wraps a data-quality or deserialization error from persisted rows.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+
+    /// Wraps a persistence error.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
@@ -1,13 +1,31 @@
 //! Port contracts for agent backend orchestration.
 //!
 //! Ports define infrastructure-agnostic interfaces for backend registration,
-//! runtime execution, tool routing, and session persistence.
+//! runtime execution, tool routing, session persistence, backend experiments,
+//! tool invocation dataset storage, long-term agent memory, turn outcomes,
+//! turn completion callbacks, and context assembly reports.
 
+pub mod agent_memory;
+pub mod context_assembly;
+pub mod dataset;
+pub mod experiment;
 pub mod repository;
 pub mod runtime;
 pub mod session;
 pub mod tool_router;
+pub mod turn_callback;
+pub mod turn_outcome;
 
+pub use agent_memory::{
+    AgentMemoryRepository, AgentMemoryRepositoryError, AgentMemoryRepositoryResult, MemoryEmbedder,
+    MemoryFactExtractor, MemoryModelError,
+};
+pub use context_assembly::{
+    ContextAssemblyReportRepository, ContextAssemblyReportRepositoryError,
+    ContextAssemblyReportRepositoryResult,
+};
+pub use dataset::{ToolDatasetError, ToolDatasetResult, ToolDatasetStore};
+pub use experiment::{ExperimentRepository, ExperimentRepositoryError, ExperimentRepositoryResult};
 pub use repository::{BackendRegistryError, BackendRegistryRepository, BackendRegistryResult};
 pub use runtime::{AgentRuntimeError, AgentRuntimePort, AgentRuntimeResult};
 pub use session::{
@@ -15,3 +33,10 @@ pub use session::{
     TurnSessionRepositoryError, TurnSessionRepositoryResult,
 };
 pub use tool_router::{ToolRouterPort, ToolRoutingContext, ToolRoutingError, ToolRoutingResult};
+pub use turn_callback::{
+    TurnCallbackDeliveryError, TurnCallbackDeliveryResult, TurnCallbackRepository,
+    TurnCallbackRepositoryError, TurnCallbackRepositoryResult, TurnCallbackSender,
+};
+pub use turn_outcome::{
+    TurnOutcomeRepository, TurnOutcomeRepositoryError, TurnOutcomeRepositoryResult,
+};
@@ -2,6 +2,7 @@
 
 use crate::agent_backend::domain::{AgentBackendRegistration, BackendId, BackendName};
 use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
 use async_trait::async_trait;
 use std::sync::Arc;
 use thiserror::Error;
@@ -65,17 +66,21 @@ pub trait BackendRegistryRepository: Send + Sync {
         name: &BackendName,
     ) -> BackendRegistryResult<Option<AgentBackendRegistration>>;
 
-    /// Returns all backend registrations with `Active` status.
+    /// Returns a page of backend registrations with `Active` status,
+    /// in registration order.
     async fn list_active(
         &self,
         ctx: &RequestContext,
-    ) -> BackendRegistryResult<Vec<AgentBackendRegistration>>;
+        page: PageRequest,
+    ) -> BackendRegistryResult<Page<AgentBackendRegistration>>;
 
-    /// Returns all backend registrations regardless of status.
+    /// Returns a page of backend registrations regardless of status,
+    /// in registration order.
     async fn list_all(
         &self,
         ctx: &RequestContext,
-    ) -> BackendRegistryResult<Vec<AgentBackendRegistration>>;
+        page: PageRequest,
+    ) -> BackendRegistryResult<Page<AgentBackendRegistration>>;
 }
 
 /// Errors returned by backend registry repository implementations.
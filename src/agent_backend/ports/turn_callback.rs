@@ -0,0 +1,127 @@
+//! Port contracts for registering and delivering turn completion callbacks.
+
+use crate::agent_backend::domain::{
+    TurnCallback, TurnCallbackId, TurnCallbackTarget, TurnCompletionNotice,
+};
+use crate::context::RequestContext;
+use crate::maintenance::domain::MaintenanceMode;
+use crate::pagination::Limit;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Result type for turn callback repository operations.
+pub type TurnCallbackRepositoryResult<T> = Result<T, TurnCallbackRepositoryError>;
+
+/// Store of callbacks waiting for a conversation's next turn to end.
+///
+/// All queries and mutations are scoped to the tenant identified by
+/// [`RequestContext::tenant_id`](crate::context::RequestContext).
+#[async_trait]
+pub trait TurnCallbackRepository: Send + Sync {
+    /// Registers a callback.
+    async fn register(
+        &self,
+        ctx: &RequestContext,
+        callback: &TurnCallback,
+    ) -> TurnCallbackRepositoryResult<()>;
+
+    /// Removes and returns up to `limit` of the oldest callbacks registered
+    /// for a conversation, oldest first.
+    ///
+    /// Callbacks are one-shot: a callback returned here is never returned
+    /// again, even to a concurrent caller. Taking removes what it returns,
+    /// so callers take again until fewer than `limit` come back rather
+    /// than following a cursor.
+    async fn take_for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+        limit: Limit,
+    ) -> TurnCallbackRepositoryResult<Vec<TurnCallback>>;
+}
+
+/// Errors returned by turn callback repository implementations.
+#[derive(Debug, Clone, Error)]
+pub enum TurnCallbackRepositoryError {
+    /// A callback with the same identifier is already registered.
+    #[error("turn callback already registered: {0}")]
+    Duplicate(TurnCallbackId),
+
+    /// Persisted data could not be reconstructed into domain types.
+    #[error("invalid persisted data: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Persistence-layer failure.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// The cluster is in read-only maintenance mode.
+    #[error(transparent)]
+    MaintenanceMode(#[from] MaintenanceMode),
+}
+
+impl TurnCallbackRepositoryError {
+    /// Wraps a data-quality or deserialization error from persisted rows.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+
+    /// Wraps a persistence error.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
+
+/// Result type for turn callback delivery.
+pub type TurnCallbackDeliveryResult<T> = Result<T, TurnCallbackDeliveryError>;
+
+/// Delivers turn completion notices through the webhook subsystem.
+///
+/// # Implementation Notes
+///
+/// Implementations make a single attempt and return only once the target
+/// has accepted the notice; retries are scheduled by the caller. Targets
+/// may receive a notice more than once and should deduplicate on
+/// [`TurnCompletionNotice::callback_id`].
+#[async_trait]
+pub trait TurnCallbackSender: Send + Sync {
+    /// Delivers `notice` to `target`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnCallbackDeliveryError`] when the target cannot be
+    /// reached or does not accept the notice.
+    async fn send(
+        &self,
+        target: &TurnCallbackTarget,
+        notice: &TurnCompletionNotice,
+    ) -> TurnCallbackDeliveryResult<()>;
+}
+
+/// Errors returned by turn callback sender implementations.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TurnCallbackDeliveryError {
+    /// The sender does not support the target's kind.
+    #[error("unsupported turn callback target kind: {0}")]
+    UnsupportedTarget(&'static str),
+    /// The target did not accept the notice.
+    #[error("failed to deliver turn callback {callback_id}: {reason}")]
+    DeliveryFailed {
+        /// The callback whose notice was not delivered.
+        callback_id: TurnCallbackId,
+        /// Human-readable reason from the transport.
+        reason: String,
+    },
+}
+
+impl TurnCallbackDeliveryError {
+    /// Creates a delivery failure for `notice`.
+    pub fn delivery_failed(notice: &TurnCompletionNotice, err: impl std::error::Error) -> Self {
+        Self::DeliveryFailed {
+            callback_id: notice.callback_id,
+            reason: err.to_string(),
+        }
+    }
+}
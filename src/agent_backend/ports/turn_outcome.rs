@@ -0,0 +1,59 @@
+//! Port contract for persisting the outcomes of orchestrated turns.
+
+use crate::agent_backend::domain::TurnOutcomeRecord;
+use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Result type for turn outcome repository operations.
+pub type TurnOutcomeRepositoryResult<T> = Result<T, TurnOutcomeRepositoryError>;
+
+/// Append-only store of turn outcomes.
+///
+/// All queries and mutations are scoped to the tenant identified by
+/// [`RequestContext::tenant_id`](crate::context::RequestContext).
+#[async_trait]
+pub trait TurnOutcomeRepository: Send + Sync {
+    /// Records the outcome of one turn.
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        record: &TurnOutcomeRecord,
+    ) -> TurnOutcomeRepositoryResult<()>;
+
+    /// Returns a page of the outcomes recorded for a conversation, in the
+    /// order they were recorded.
+    async fn list_for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+        page: PageRequest,
+    ) -> TurnOutcomeRepositoryResult<Page<TurnOutcomeRecord>>;
+}
+
+/// Errors returned by turn outcome repository implementations.
+#[derive(Debug, Clone, Error)]
+pub enum TurnOutcomeRepositoryError {
+    /// Persisted data could not be reconstructed into domain types.
+    #[error("invalid persisted data: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Persistence-layer failure.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl TurnOutcomeRepositoryError {
+    /// Wraps a data-quality or deserialization error from persisted rows.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+
+    /// Wraps a persistence error.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
@@ -0,0 +1,360 @@
+//! Long-term agent memory: remembering, recalling, and curating facts.
+
+use crate::agent_backend::{
+    domain::{
+        BackendId, CompletedTurn, MemoryDecayPolicy, MemoryDomainError, MemoryFact,
+        MemoryFactDraft, MemoryFactId, MemoryRecall, MemoryRecallQuery, RecalledMemory,
+        assemble_memory_prompt,
+    },
+    ports::{
+        AgentMemoryRepository, AgentMemoryRepositoryError, MemoryEmbedder, MemoryFactExtractor,
+        MemoryModelError,
+    },
+};
+use crate::context::RequestContext;
+use crate::pagination::collect_pages;
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Number of facts recalled into a prompt unless configured otherwise.
+pub const DEFAULT_MEMORY_RECALL_LIMIT: usize = 5;
+
+/// Result type for agent memory operations.
+pub type AgentMemoryResult<T> = Result<T, AgentMemoryError>;
+
+/// Errors returned by [`AgentMemoryService`].
+#[derive(Debug, Clone, Error)]
+pub enum AgentMemoryError {
+    /// The fact does not exist.
+    #[error("memory fact not found: {0}")]
+    NotFound(MemoryFactId),
+
+    /// The fact rejected the requested change.
+    #[error(transparent)]
+    Domain(#[from] MemoryDomainError),
+
+    /// The memory repository failed.
+    #[error(transparent)]
+    Repository(#[from] AgentMemoryRepositoryError),
+
+    /// The embedder or fact extractor failed.
+    #[error(transparent)]
+    Model(#[from] MemoryModelError),
+}
+
+/// Ports consulted by [`AgentMemoryService`].
+#[derive(Clone)]
+pub struct AgentMemoryPorts {
+    /// Remembered facts.
+    pub repository: Arc<dyn AgentMemoryRepository>,
+    /// Embeds facts and prompts for recall.
+    pub embedder: Arc<dyn MemoryEmbedder>,
+    /// Proposes facts from completed turns.
+    pub extractor: Arc<dyn MemoryFactExtractor>,
+}
+
+/// Changes applied to a remembered fact by [`AgentMemoryService::edit`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryFactEdit {
+    /// Replacement content, which is re-embedded.
+    pub content: Option<String>,
+    /// Replacement confidence, which restarts decay.
+    pub confidence_percent: Option<u8>,
+    /// Replacement expiry; `Some(None)` makes the fact permanent.
+    pub expires_at: Option<Option<DateTime<Utc>>>,
+}
+
+impl MemoryFactEdit {
+    /// Replaces the fact's content.
+    #[must_use]
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Replaces the fact's confidence.
+    #[must_use]
+    pub const fn with_confidence(mut self, confidence_percent: u8) -> Self {
+        self.confidence_percent = Some(confidence_percent);
+        self
+    }
+
+    /// Replaces the fact's expiry.
+    #[must_use]
+    pub const fn with_expiry(mut self, expires_at: Option<DateTime<Utc>>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+}
+
+/// Service that remembers facts across a backend's conversations.
+///
+/// Facts are scoped to the request's tenant and to a backend. After each
+/// turn the extractor proposes facts; a proposal restating a remembered fact
+/// reinforces it, and any other proposal is embedded and stored. Recall
+/// embeds the prompt and ranks live facts by relevance weighted by decayed
+/// confidence.
+#[derive(Clone)]
+pub struct AgentMemoryService {
+    ports: AgentMemoryPorts,
+    clock: Arc<dyn Clock + Send + Sync>,
+    policy: MemoryDecayPolicy,
+    recall_limit: usize,
+}
+
+impl AgentMemoryService {
+    /// Creates a memory service with the default decay policy and recall
+    /// limit.
+    #[must_use]
+    pub fn new(ports: AgentMemoryPorts, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self {
+            ports,
+            clock,
+            policy: MemoryDecayPolicy::default(),
+            recall_limit: DEFAULT_MEMORY_RECALL_LIMIT,
+        }
+    }
+
+    /// Replaces the decay policy.
+    #[must_use]
+    pub const fn with_decay_policy(mut self, policy: MemoryDecayPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets how many facts are recalled into a prompt.
+    #[must_use]
+    pub const fn with_recall_limit(mut self, recall_limit: usize) -> Self {
+        self.recall_limit = recall_limit;
+        self
+    }
+
+    /// Returns the decay policy.
+    #[must_use]
+    pub const fn decay_policy(&self) -> MemoryDecayPolicy {
+        self.policy
+    }
+
+    /// Returns how many facts are recalled into a prompt.
+    #[must_use]
+    pub const fn recall_limit(&self) -> usize {
+        self.recall_limit
+    }
+
+    /// Extracts facts from a completed turn and remembers them.
+    ///
+    /// Returns the facts that were stored or reinforced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AgentMemoryError::Model`] when extraction or embedding
+    /// fails, or [`AgentMemoryError::Repository`] when facts cannot be read
+    /// or stored.
+    pub async fn remember_turn(
+        &self,
+        ctx: &RequestContext,
+        turn: &CompletedTurn,
+    ) -> AgentMemoryResult<Vec<MemoryFact>> {
+        let drafts = self.ports.extractor.extract(ctx, turn).await?;
+        if drafts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut known = self.all_facts(ctx, turn.backend_id).await?;
+        let mut remembered = Vec::with_capacity(drafts.len());
+        for draft in drafts {
+            let fact = self.remember(ctx, (turn, draft), &mut known).await?;
+            remembered.push(fact);
+        }
+        Ok(remembered)
+    }
+
+    async fn remember(
+        &self,
+        ctx: &RequestContext,
+        (turn, draft): (&CompletedTurn, MemoryFactDraft),
+        known: &mut Vec<MemoryFact>,
+    ) -> AgentMemoryResult<MemoryFact> {
+        let fact = if let Some(existing) = known.iter_mut().find(|fact| fact.states(&draft.content))
+        {
+            existing.reinforce(&draft, self.policy, &*self.clock);
+            existing.clone()
+        } else {
+            let embedding = self.ports.embedder.embed(ctx, &draft.content).await?;
+            let fact = MemoryFact::new(turn.backend_id, draft, embedding, &*self.clock)?
+                .with_source_conversation(turn.conversation_id);
+            known.push(fact.clone());
+            fact
+        };
+        self.ports.repository.upsert(ctx, &fact).await?;
+        Ok(fact)
+    }
+
+    async fn all_facts(
+        &self,
+        ctx: &RequestContext,
+        backend_id: BackendId,
+    ) -> AgentMemoryResult<Vec<MemoryFact>> {
+        let repository = &self.ports.repository;
+        Ok(collect_pages(|page| repository.list_for_backend(ctx, backend_id, page)).await?)
+    }
+
+    /// Returns the facts most relevant to `prompt`, best first.
+    ///
+    /// Forgotten facts and facts with no relevance are never recalled. The
+    /// prompt is only embedded when the backend has live facts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AgentMemoryError::Repository`] when facts cannot be read,
+    /// or [`AgentMemoryError::Model`] when the prompt cannot be embedded.
+    pub async fn recall(
+        &self,
+        ctx: &RequestContext,
+        backend_id: BackendId,
+        prompt: &str,
+    ) -> AgentMemoryResult<Vec<RecalledMemory>> {
+        Ok(self
+            .recall_explained(ctx, backend_id, prompt)
+            .await?
+            .recalled)
+    }
+
+    /// Recalls the facts most relevant to `prompt`, recording a verdict for
+    /// every fact the backend remembers, forgotten ones included.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Self::recall`].
+    pub async fn recall_explained(
+        &self,
+        ctx: &RequestContext,
+        backend_id: BackendId,
+        prompt: &str,
+    ) -> AgentMemoryResult<MemoryRecall> {
+        let now = self.clock.utc();
+        let facts = self.all_facts(ctx, backend_id).await?;
+        let any_live = facts
+            .iter()
+            .any(|fact| !fact.is_forgotten(self.policy, now));
+        let embedding = if any_live && self.recall_limit > 0 {
+            Some(self.ports.embedder.embed(ctx, prompt).await?)
+        } else {
+            None
+        };
+        let query = MemoryRecallQuery {
+            embedding: embedding.as_deref(),
+            now,
+            limit: self.recall_limit,
+        };
+        Ok(MemoryRecall::rank(facts, &query, self.policy))
+    }
+
+    /// Prefixes `prompt` with the facts recalled for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Self::recall`].
+    pub async fn assemble_prompt(
+        &self,
+        ctx: &RequestContext,
+        backend_id: BackendId,
+        prompt: &str,
+    ) -> AgentMemoryResult<String> {
+        let recalled = self.recall(ctx, backend_id, prompt).await?;
+        Ok(assemble_memory_prompt(&recalled, prompt))
+    }
+
+    /// Returns the facts a backend still remembers, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AgentMemoryError::Repository`] when facts cannot be read.
+    pub async fn list(
+        &self,
+        ctx: &RequestContext,
+        backend_id: BackendId,
+    ) -> AgentMemoryResult<Vec<MemoryFact>> {
+        let now = self.clock.utc();
+        let mut facts = self.all_facts(ctx, backend_id).await?;
+        facts.retain(|fact| !fact.is_forgotten(self.policy, now));
+        Ok(facts)
+    }
+
+    /// Applies an edit to a remembered fact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AgentMemoryError::NotFound`] when the fact does not exist,
+    /// [`AgentMemoryError::Domain`] when the edit is invalid, or the
+    /// embedder's or repository's error.
+    pub async fn edit(
+        &self,
+        ctx: &RequestContext,
+        id: MemoryFactId,
+        edit: MemoryFactEdit,
+    ) -> AgentMemoryResult<MemoryFact> {
+        let mut fact = self
+            .ports
+            .repository
+            .find_by_id(ctx, id)
+            .await?
+            .ok_or(AgentMemoryError::NotFound(id))?;
+        if let Some(content) = edit.content {
+            let embedding = self.ports.embedder.embed(ctx, &content).await?;
+            fact.revise(content, embedding)?;
+        }
+        if let Some(confidence_percent) = edit.confidence_percent {
+            fact.set_confidence(confidence_percent, &*self.clock)?;
+        }
+        if let Some(expires_at) = edit.expires_at {
+            fact.set_expiry(expires_at);
+        }
+        self.ports.repository.upsert(ctx, &fact).await?;
+        Ok(fact)
+    }
+
+    /// Forgets a fact immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AgentMemoryError::NotFound`] when the fact does not exist.
+    pub async fn forget(&self, ctx: &RequestContext, id: MemoryFactId) -> AgentMemoryResult<()> {
+        self.ports
+            .repository
+            .delete(ctx, id)
+            .await
+            .map_err(|error| match error {
+                AgentMemoryRepositoryError::NotFound(missing) => {
+                    AgentMemoryError::NotFound(missing)
+                }
+                other => AgentMemoryError::Repository(other),
+            })
+    }
+
+    /// Deletes a backend's expired and decayed facts, returning how many
+    /// were deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AgentMemoryError::Repository`] when facts cannot be read or
+    /// deleted.
+    pub async fn purge_forgotten(
+        &self,
+        ctx: &RequestContext,
+        backend_id: BackendId,
+    ) -> AgentMemoryResult<usize> {
+        let now = self.clock.utc();
+        let facts = self.all_facts(ctx, backend_id).await?;
+        let mut purged = 0;
+        for fact in facts
+            .iter()
+            .filter(|fact| fact.is_forgotten(self.policy, now))
+        {
+            self.ports.repository.delete(ctx, fact.id()).await?;
+            purged += 1;
+        }
+        Ok(purged)
+    }
+}
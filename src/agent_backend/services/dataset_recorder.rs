@@ -0,0 +1,119 @@
+//! Opt-in recording of tool invocations into evaluation datasets.
+
+use crate::agent_backend::{
+    domain::{
+        BackendId, DatasetRecordingPolicy, DatasetTurnOutcome, ToolCallRequest, ToolCallResult,
+        ToolInvocationSample, TurnExecutionRequest, deterministic_tool_call_id,
+    },
+    ports::{ToolDatasetResult, ToolDatasetStore},
+};
+use crate::context::RequestContext;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Tool calls and results of one finished turn, ready for recording.
+#[derive(Debug, Clone, Copy)]
+pub struct RecordedTurn<'a> {
+    /// Backend that executed the turn.
+    pub backend_id: BackendId,
+    /// Turn request carrying the prompt and conversation.
+    pub turn: &'a TurnExecutionRequest,
+    /// Tool calls the backend requested, in emission order.
+    pub tool_calls: &'a [ToolCallRequest],
+    /// Results of the calls that were routed successfully.
+    pub results: &'a [ToolCallResult],
+    /// How the turn ended.
+    pub outcome: DatasetTurnOutcome,
+    /// When the turn finished.
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Service that samples finished turns into a tool invocation dataset.
+///
+/// Each turn is sampled as a whole, so a recorded turn contributes every
+/// one of its tool calls. Prompts, arguments, and results are scrubbed with
+/// the policy's [`crate::agent_backend::domain::PiiScrubber`] before they
+/// reach the store.
+#[derive(Clone)]
+pub struct ToolDatasetRecorder {
+    store: Arc<dyn ToolDatasetStore>,
+    policy: DatasetRecordingPolicy,
+}
+
+impl ToolDatasetRecorder {
+    /// Creates a recorder writing to `store` under `policy`.
+    #[must_use]
+    pub fn new(store: Arc<dyn ToolDatasetStore>, policy: DatasetRecordingPolicy) -> Self {
+        Self { store, policy }
+    }
+
+    /// Returns the recording policy.
+    #[must_use]
+    pub const fn policy(&self) -> &DatasetRecordingPolicy {
+        &self.policy
+    }
+
+    /// Samples and records a finished turn, returning the number of samples
+    /// written.
+    ///
+    /// Turns without tool calls and turns not selected by the sampling rate
+    /// write nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::agent_backend::ports::ToolDatasetError`] when the
+    /// store rejects the samples.
+    pub async fn record_turn(
+        &self,
+        ctx: &RequestContext,
+        turn: &RecordedTurn<'_>,
+    ) -> ToolDatasetResult<usize> {
+        if turn.tool_calls.is_empty() || !self.policy.samples(Uuid::new_v4().as_u128()) {
+            return Ok(0);
+        }
+        let samples = self.samples_for(turn);
+        self.store.append_samples(ctx, &samples).await?;
+        Ok(samples.len())
+    }
+
+    /// Exports the tenant's recorded samples as JSON Lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::agent_backend::ports::ToolDatasetError`] when the
+    /// store cannot be read.
+    pub async fn export_jsonl(&self, ctx: &RequestContext) -> ToolDatasetResult<Bytes> {
+        self.store.export_jsonl(ctx).await
+    }
+
+    fn samples_for(&self, turn: &RecordedTurn<'_>) -> Vec<ToolInvocationSample> {
+        let scrubber = self.policy.scrubber();
+        let prompt_context = self.policy.prompt_context(turn.turn.prompt());
+        turn.tool_calls
+            .iter()
+            .enumerate()
+            .map(|(index, tool_call)| {
+                let call_id = deterministic_tool_call_id(tool_call, index);
+                let result = turn
+                    .results
+                    .iter()
+                    .find(|routed| routed.call_id() == call_id)
+                    .map(|routed| scrubber.scrub_value(routed.output()));
+                ToolInvocationSample {
+                    sample_id: Uuid::new_v4(),
+                    recorded_at: turn.recorded_at,
+                    backend_id: turn.backend_id,
+                    conversation_id: turn.turn.conversation_id(),
+                    prompt_context: prompt_context.clone(),
+                    call_id,
+                    tool_name: tool_call.tool_name().to_owned(),
+                    arguments: scrubber.scrub_value(tool_call.parameters()),
+                    result,
+                    turn_outcome: turn.outcome,
+                }
+            })
+            .collect()
+    }
+}
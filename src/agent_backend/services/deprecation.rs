@@ -0,0 +1,264 @@
+//! Impact reports and bulk re-pinning for retiring agent backends.
+
+use crate::agent_backend::{
+    domain::{
+        AgentBackendRegistration, BackendExperiment, BackendName, BackendStatus,
+        DeprecationImpactReport, RepinOutcome,
+    },
+    ports::{
+        BackendRegistryError, BackendRegistryRepository, ExperimentRepository,
+        ExperimentRepositoryError,
+    },
+};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{AgentSession, ConversationId},
+    error::RepositoryError,
+    ports::{
+        AgentSessionRepository, MessageRepository, SessionError, SlashCommandRegistry,
+        SlashCommandRegistryError,
+    },
+};
+use crate::pagination::collect_pages;
+use crate::task::ports::{TaskCostError, TaskCostLedger};
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for backend deprecation operations.
+pub type DeprecationServiceResult<T> = Result<T, DeprecationServiceError>;
+
+/// Errors returned by [`BackendDeprecationService`].
+#[derive(Debug, Error)]
+pub enum DeprecationServiceError {
+    /// No backend is registered under the name.
+    #[error("backend not found: {0}")]
+    BackendNotFound(BackendName),
+
+    /// The successor is not an active backend.
+    #[error("successor backend {0} is not active")]
+    SuccessorInactive(BackendName),
+
+    /// The successor is the backend being retired.
+    #[error("backend {0} cannot succeed itself")]
+    SelfSuccession(BackendName),
+
+    /// The backend registry failed.
+    #[error(transparent)]
+    Registry(#[from] BackendRegistryError),
+
+    /// The agent session repository failed.
+    #[error(transparent)]
+    Sessions(#[from] SessionError),
+
+    /// The message repository failed.
+    #[error(transparent)]
+    Messages(#[from] RepositoryError),
+
+    /// The slash-command registry failed.
+    #[error(transparent)]
+    Templates(#[from] SlashCommandRegistryError),
+
+    /// The experiment repository failed.
+    #[error(transparent)]
+    Experiments(#[from] ExperimentRepositoryError),
+
+    /// The usage ledger failed.
+    #[error(transparent)]
+    Usage(#[from] TaskCostError),
+}
+
+/// Request to move every conversation pinned to a backend onto a successor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepinRequest {
+    /// The backend being retired.
+    pub backend: BackendName,
+    /// The backend taking over its conversations.
+    pub successor: BackendName,
+}
+
+impl RepinRequest {
+    /// Creates a re-pin request.
+    #[must_use]
+    pub const fn new(backend: BackendName, successor: BackendName) -> Self {
+        Self { backend, successor }
+    }
+}
+
+/// Ports consulted by [`BackendDeprecationService`].
+#[derive(Clone)]
+pub struct BackendDeprecationPorts {
+    /// Backend registration repository.
+    pub backend_registry: Arc<dyn BackendRegistryRepository>,
+    /// Agent sessions, which pin conversations to backends.
+    pub sessions: Arc<dyn AgentSessionRepository>,
+    /// Messages, used to place re-pinned session boundaries.
+    pub messages: Arc<dyn MessageRepository>,
+    /// Slash-command definitions and their templates.
+    pub slash_commands: Arc<dyn SlashCommandRegistry>,
+    /// Backend experiments.
+    pub experiments: Arc<dyn ExperimentRepository>,
+    /// Usage ledger, used to project migration cost.
+    pub usage: Arc<dyn TaskCostLedger>,
+    /// Clock used to timestamp re-pinned sessions.
+    pub clock: Arc<dyn Clock + Send + Sync>,
+}
+
+/// Service that reports what depends on a retiring backend and moves its
+/// conversations to a successor.
+///
+/// A conversation is pinned to the backend of its active agent session.
+/// Re-pinning completes that session and opens a new one on the successor at
+/// the conversation's next sequence number, so the session history records
+/// where the retired backend stopped.
+#[derive(Clone)]
+pub struct BackendDeprecationService {
+    ports: BackendDeprecationPorts,
+}
+
+impl BackendDeprecationService {
+    /// Creates a deprecation service.
+    #[must_use]
+    pub const fn new(ports: BackendDeprecationPorts) -> Self {
+        Self { ports }
+    }
+
+    /// Reports what depends on `backend`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeprecationServiceError::BackendNotFound`] when no backend
+    /// is registered under the name, or the failing port's error.
+    pub async fn impact_report(
+        &self,
+        ctx: &RequestContext,
+        backend: &BackendName,
+    ) -> DeprecationServiceResult<DeprecationImpactReport> {
+        let registration = self.require_backend(ctx, backend).await?;
+        let pinned_conversations = self
+            .pinned_sessions(ctx, backend)
+            .await?
+            .iter()
+            .map(|session| session.conversation_id)
+            .collect::<Vec<_>>();
+        let templates = self
+            .ports
+            .slash_commands
+            .list()?
+            .into_iter()
+            .filter(|definition| definition.template_mentions(backend.as_str()))
+            .map(|definition| definition.command)
+            .collect();
+        let experiments_port = &self.ports.experiments;
+        let experiments = collect_pages(|page| experiments_port.list_running(ctx, page))
+            .await?
+            .iter()
+            .filter(|experiment| {
+                experiment.control().backend_id() == registration.id()
+                    || experiment.treatment().backend_id() == registration.id()
+            })
+            .map(BackendExperiment::id)
+            .collect();
+        let projected_migration_cost = self
+            .ports
+            .usage
+            .backend_usage(ctx, backend.as_str(), &pinned_conversations)
+            .await?;
+        Ok(DeprecationImpactReport {
+            backend: backend.clone(),
+            pinned_conversations,
+            templates,
+            experiments,
+            projected_migration_cost,
+        })
+    }
+
+    /// Moves every conversation pinned to the retiring backend onto the
+    /// successor.
+    ///
+    /// Templates and experiments are left untouched; they name backends
+    /// explicitly and need an operator's review.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeprecationServiceError::SelfSuccession`] when the
+    /// successor is the retiring backend,
+    /// [`DeprecationServiceError::BackendNotFound`] when either backend is
+    /// not registered, [`DeprecationServiceError::SuccessorInactive`] when
+    /// the successor is not active, or the failing port's error. A port
+    /// failure stops the operation; conversations re-pinned before it stay
+    /// re-pinned, and repeating the request resumes with the rest.
+    pub async fn repin(
+        &self,
+        ctx: &RequestContext,
+        request: RepinRequest,
+    ) -> DeprecationServiceResult<RepinOutcome> {
+        if request.backend == request.successor {
+            return Err(DeprecationServiceError::SelfSuccession(request.backend));
+        }
+        self.require_backend(ctx, &request.backend).await?;
+        let successor = self.require_backend(ctx, &request.successor).await?;
+        if successor.status() != BackendStatus::Active {
+            return Err(DeprecationServiceError::SuccessorInactive(
+                request.successor,
+            ));
+        }
+        let mut repinned = Vec::new();
+        for session in self.pinned_sessions(ctx, &request.backend).await? {
+            repinned.push(self.repin_session(ctx, session, &request.successor).await?);
+        }
+        Ok(RepinOutcome {
+            backend: request.backend,
+            successor: request.successor,
+            repinned,
+        })
+    }
+
+    async fn repin_session(
+        &self,
+        ctx: &RequestContext,
+        mut session: AgentSession,
+        successor: &BackendName,
+    ) -> DeprecationServiceResult<ConversationId> {
+        let conversation_id = session.conversation_id;
+        let boundary = self
+            .ports
+            .messages
+            .next_sequence_number(ctx, conversation_id)
+            .await?;
+        session.complete(boundary, &*self.ports.clock);
+        self.ports.sessions.update(ctx, &session).await?;
+        let replacement = AgentSession::new(
+            conversation_id,
+            successor.as_str(),
+            boundary,
+            &*self.ports.clock,
+        );
+        self.ports.sessions.store(ctx, &replacement).await?;
+        Ok(conversation_id)
+    }
+
+    async fn pinned_sessions(
+        &self,
+        ctx: &RequestContext,
+        backend: &BackendName,
+    ) -> DeprecationServiceResult<Vec<AgentSession>> {
+        let sessions = &self.ports.sessions;
+        Ok(
+            collect_pages(|page| sessions.find_active_by_backend(ctx, backend.as_str(), page))
+                .await?,
+        )
+    }
+
+    async fn require_backend(
+        &self,
+        ctx: &RequestContext,
+        name: &BackendName,
+    ) -> DeprecationServiceResult<AgentBackendRegistration> {
+        self.ports
+            .backend_registry
+            .find_by_name(ctx, name)
+            .await?
+            .ok_or_else(|| DeprecationServiceError::BackendNotFound(name.clone()))
+    }
+}
@@ -0,0 +1,218 @@
+//! Backend A/B experiment routing and outcome tracking.
+
+use super::ExecuteAgentTurnRequest;
+use crate::agent_backend::{
+    domain::{
+        BackendExperiment, ExperimentArmKey, ExperimentDomainError, ExperimentId,
+        ExperimentObservation, ExperimentReport, ExperimentSignal, TurnExecutionRequest,
+    },
+    ports::{ExperimentRepository, ExperimentRepositoryError},
+};
+use crate::context::RequestContext;
+use crate::pagination::collect_pages;
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Result type for experiment service operations.
+pub type ExperimentServiceResult<T> = Result<T, ExperimentServiceError>;
+
+/// Errors returned by [`BackendExperimentService`].
+#[derive(Debug, Clone, Error)]
+pub enum ExperimentServiceError {
+    /// The experiment does not exist.
+    #[error("experiment not found: {0}")]
+    NotFound(ExperimentId),
+
+    /// The experiment rejected the requested transition.
+    #[error(transparent)]
+    Domain(#[from] ExperimentDomainError),
+
+    /// The experiment repository failed.
+    #[error(transparent)]
+    Repository(#[from] ExperimentRepositoryError),
+}
+
+/// The arm a conversation was assigned to within an experiment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExperimentAssignment {
+    /// Experiment the conversation takes part in.
+    pub experiment_id: ExperimentId,
+    /// Arm the conversation was assigned to.
+    pub arm: ExperimentArmKey,
+    /// The assigned conversation.
+    pub conversation_id: Uuid,
+}
+
+/// Service that splits turns between experiment arms and tracks outcomes.
+///
+/// A running experiment applies to turns addressed to its control backend.
+/// Each such conversation is assigned to an arm, and its turns are rewritten
+/// to run on that arm's backend with that arm's prompt variant.
+#[derive(Clone)]
+pub struct BackendExperimentService {
+    repository: Arc<dyn ExperimentRepository>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl BackendExperimentService {
+    /// Creates an experiment service.
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn ExperimentRepository>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Starts an experiment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentServiceError::Repository`] when the experiment
+    /// cannot be stored.
+    pub async fn start_experiment(
+        &self,
+        ctx: &RequestContext,
+        experiment: &BackendExperiment,
+    ) -> ExperimentServiceResult<()> {
+        Ok(self.repository.create(ctx, experiment).await?)
+    }
+
+    /// Concludes an experiment so that it stops routing turns.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentServiceError::NotFound`] when the experiment does
+    /// not exist, or [`ExperimentServiceError::Domain`] when it is already
+    /// concluded.
+    pub async fn conclude_experiment(
+        &self,
+        ctx: &RequestContext,
+        id: ExperimentId,
+    ) -> ExperimentServiceResult<BackendExperiment> {
+        let mut experiment = self.find(ctx, id).await?;
+        experiment.conclude(&*self.clock)?;
+        self.repository.update(ctx, &experiment).await?;
+        Ok(experiment)
+    }
+
+    /// Rewrites a turn request for the experiment arm its conversation is
+    /// assigned to.
+    ///
+    /// Requests addressed to a backend that is not the control arm of a
+    /// running experiment are returned unchanged with no assignment. When
+    /// several experiments share a control backend, the oldest applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentServiceError::Repository`] when running
+    /// experiments cannot be listed.
+    pub async fn route_turn(
+        &self,
+        ctx: &RequestContext,
+        request: ExecuteAgentTurnRequest,
+    ) -> ExperimentServiceResult<(ExecuteAgentTurnRequest, Option<ExperimentAssignment>)> {
+        let running = collect_pages(|page| self.repository.list_running(ctx, page)).await?;
+        let Some(experiment) = running
+            .iter()
+            .find(|candidate| candidate.control().backend_id() == request.backend_id)
+        else {
+            return Ok((request, None));
+        };
+
+        let conversation_id = request.turn.conversation_id();
+        let arm_key = experiment.assign(conversation_id);
+        let arm = experiment.arm(arm_key);
+        let turn = TurnExecutionRequest::new(
+            conversation_id,
+            arm.apply_prompt(request.turn.prompt()),
+            request.turn.tool_calls().to_vec(),
+        );
+        let routed = ExecuteAgentTurnRequest::new(arm.backend_id(), turn)
+            .with_idempotent(request.idempotent);
+        let assignment = ExperimentAssignment {
+            experiment_id: experiment.id(),
+            arm: arm_key,
+            conversation_id,
+        };
+        Ok((routed, Some(assignment)))
+    }
+
+    /// Returns the arm a conversation is assigned to.
+    ///
+    /// Use this to attribute cost or human feedback that arrives after the
+    /// conversation's turns have run.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentServiceError::NotFound`] when the experiment does
+    /// not exist.
+    pub async fn assignment(
+        &self,
+        ctx: &RequestContext,
+        id: ExperimentId,
+        conversation_id: Uuid,
+    ) -> ExperimentServiceResult<ExperimentAssignment> {
+        let experiment = self.find(ctx, id).await?;
+        Ok(ExperimentAssignment {
+            experiment_id: id,
+            arm: experiment.assign(conversation_id),
+            conversation_id,
+        })
+    }
+
+    /// Records an outcome observation for an assigned conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentServiceError::Repository`] when the observation
+    /// cannot be stored.
+    pub async fn record_signal(
+        &self,
+        ctx: &RequestContext,
+        assignment: &ExperimentAssignment,
+        signal: ExperimentSignal,
+    ) -> ExperimentServiceResult<()> {
+        let observation = ExperimentObservation {
+            experiment_id: assignment.experiment_id,
+            arm: assignment.arm,
+            conversation_id: assignment.conversation_id,
+            signal,
+            observed_at: self.clock.utc(),
+        };
+        Ok(self
+            .repository
+            .record_observation(ctx, &observation)
+            .await?)
+    }
+
+    /// Reports per-arm statistics for an experiment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExperimentServiceError::NotFound`] when the experiment does
+    /// not exist.
+    pub async fn report(
+        &self,
+        ctx: &RequestContext,
+        id: ExperimentId,
+    ) -> ExperimentServiceResult<ExperimentReport> {
+        self.find(ctx, id).await?;
+        let observations =
+            collect_pages(|page| self.repository.list_observations(ctx, id, page)).await?;
+        Ok(ExperimentReport::from_observations(id, &observations))
+    }
+
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        id: ExperimentId,
+    ) -> ExperimentServiceResult<BackendExperiment> {
+        self.repository
+            .find_by_id(ctx, id)
+            .await?
+            .ok_or(ExperimentServiceError::NotFound(id))
+    }
+}
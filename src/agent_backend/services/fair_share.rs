@@ -0,0 +1,188 @@
+//! Fair-share scheduling of backend invocations across tenants.
+//!
+//! [`FairShareScheduler`] admits each backend invocation against the
+//! weighted token buckets of the provider it calls, so tenants sharing a
+//! provider key share its rate limit by weight. An invocation waits for its
+//! tenant's next token, up to the policy's maximum wait, and
+//! [`FairShareScheduler::metrics`] exposes per-tenant wait times for export.
+
+use crate::agent_backend::domain::{FairShareBuckets, FairSharePolicy, TenantWaitMetrics};
+use crate::context::TenantId;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::time::Instant;
+
+/// Error returned when an invocation cannot be admitted in time.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error(
+    "tenant {tenant_id} waited {waited:?} for {provider} capacity, exceeding the {max_wait:?} limit"
+)]
+pub struct FairShareWaitExceeded {
+    /// Provider whose capacity was exhausted.
+    pub provider: String,
+    /// Tenant whose invocation was rejected.
+    pub tenant_id: TenantId,
+    /// Time spent waiting before the rejection.
+    pub waited: Duration,
+    /// Longest wait the policy allows.
+    pub max_wait: Duration,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct WaitCounters {
+    admitted: u64,
+    rejected: u64,
+    total_wait: Duration,
+    longest_wait: Duration,
+}
+
+#[derive(Debug, Default)]
+struct SchedulerState {
+    providers: HashMap<String, FairShareBuckets>,
+    counters: HashMap<(String, TenantId), WaitCounters>,
+}
+
+/// Shares rate-limited provider capacity between tenants by weight.
+///
+/// One scheduler applies its policy to every provider separately: the
+/// tenants calling one provider share that provider's rate.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::agent_backend::{domain::FairSharePolicy, services::FairShareScheduler};
+/// use corbusier::context::TenantId;
+/// use std::num::NonZeroU32;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let scheduler =
+///     FairShareScheduler::new(FairSharePolicy::new(NonZeroU32::new(5).expect("non-zero")));
+/// let tenant_id = TenantId::new();
+/// scheduler.acquire("anthropic", tenant_id).await?;
+///
+/// let metrics = scheduler.metrics();
+/// assert_eq!(metrics.first().map(|tenant| tenant.admitted), Some(1));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FairShareScheduler {
+    policy: FairSharePolicy,
+    epoch: Instant,
+    state: Mutex<SchedulerState>,
+}
+
+impl FairShareScheduler {
+    /// Creates a scheduler applying `policy` to every provider.
+    #[must_use]
+    pub fn new(policy: FairSharePolicy) -> Self {
+        Self {
+            policy,
+            epoch: Instant::now(),
+            state: Mutex::new(SchedulerState::default()),
+        }
+    }
+
+    /// Returns the policy applied to every provider.
+    #[must_use]
+    pub const fn policy(&self) -> &FairSharePolicy {
+        &self.policy
+    }
+
+    /// Waits until `tenant_id` may invoke `provider`, and returns how long
+    /// it waited.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FairShareWaitExceeded`] when the tenant's next token would
+    /// arrive after the policy's maximum wait.
+    pub async fn acquire(
+        &self,
+        provider: &str,
+        tenant_id: TenantId,
+    ) -> Result<Duration, FairShareWaitExceeded> {
+        let started = Instant::now();
+        loop {
+            let Err(next_token) = self.try_acquire(provider, tenant_id) else {
+                let waited = started.elapsed();
+                self.record(provider, tenant_id, |counters| {
+                    counters.admitted = counters.admitted.saturating_add(1);
+                    counters.total_wait = counters.total_wait.saturating_add(waited);
+                    counters.longest_wait = counters.longest_wait.max(waited);
+                });
+                return Ok(waited);
+            };
+            let waited = started.elapsed();
+            if waited.saturating_add(next_token) > self.policy.max_wait() {
+                self.record(provider, tenant_id, |counters| {
+                    counters.rejected = counters.rejected.saturating_add(1);
+                });
+                tracing::warn!(
+                    provider,
+                    tenant_id = %tenant_id,
+                    waited_ms = waited.as_millis(),
+                    "rejecting backend invocation after waiting for fair-share capacity"
+                );
+                return Err(FairShareWaitExceeded {
+                    provider: provider.to_owned(),
+                    tenant_id,
+                    waited,
+                    max_wait: self.policy.max_wait(),
+                });
+            }
+            tokio::time::sleep(next_token).await;
+        }
+    }
+
+    /// Returns wait-time counters for every tenant seen, ordered by provider
+    /// and tenant.
+    #[must_use]
+    pub fn metrics(&self) -> Vec<TenantWaitMetrics> {
+        let state = self.lock();
+        let mut metrics: Vec<TenantWaitMetrics> = state
+            .counters
+            .iter()
+            .map(|((provider, tenant_id), counters)| TenantWaitMetrics {
+                provider: provider.clone(),
+                tenant_id: *tenant_id,
+                admitted: counters.admitted,
+                rejected: counters.rejected,
+                total_wait: counters.total_wait,
+                longest_wait: counters.longest_wait,
+            })
+            .collect();
+        metrics.sort_by(|left, right| {
+            (&left.provider, left.tenant_id.into_inner())
+                .cmp(&(&right.provider, right.tenant_id.into_inner()))
+        });
+        metrics
+    }
+
+    fn try_acquire(&self, provider: &str, tenant_id: TenantId) -> Result<(), Duration> {
+        let now = self.epoch.elapsed();
+        let mut state = self.lock();
+        state
+            .providers
+            .entry(provider.to_owned())
+            .or_insert_with(|| FairShareBuckets::new(self.policy.clone()))
+            .try_acquire(tenant_id, now)
+    }
+
+    fn record(&self, provider: &str, tenant_id: TenantId, update: impl FnOnce(&mut WaitCounters)) {
+        let mut state = self.lock();
+        update(
+            state
+                .counters
+                .entry((provider.to_owned(), tenant_id))
+                .or_default(),
+        );
+    }
+
+    /// The state only holds buckets and counters, so a panic while the lock
+    /// was held cannot leave it inconsistent.
+    fn lock(&self) -> MutexGuard<'_, SchedulerState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,271 @@
+//! Export of a conversation's multi-agent interactions as a diagram.
+
+use crate::agent_backend::domain::{
+    GraphFormat, InteractionEdge, InteractionEdgeKind, InteractionGraph, InteractionNode,
+    conversation_node_id, plural, session_node_id,
+};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{AgentSession, ContentPart, ConversationId, HandoffMetadata, Message, SequenceNumber},
+    error::RepositoryError,
+    ports::{
+        AgentHandoffPort, AgentSessionRepository, HandoffError, MessageRepository, SessionError,
+    },
+};
+use crate::pagination::collect_pages;
+use crate::task::{
+    domain::ConversationBudget,
+    ports::{UsageBudgetError, UsageBudgetRepository},
+};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for interaction graph exports.
+pub type InteractionGraphResult<T> = Result<T, InteractionGraphError>;
+
+/// Errors returned by [`InteractionGraphService`].
+#[derive(Debug, Error)]
+pub enum InteractionGraphError {
+    /// The agent session repository failed.
+    #[error(transparent)]
+    Sessions(#[from] SessionError),
+
+    /// The handoff port failed.
+    #[error(transparent)]
+    Handoffs(#[from] HandoffError),
+
+    /// The message repository failed.
+    #[error(transparent)]
+    Messages(#[from] RepositoryError),
+
+    /// The budget repository failed.
+    #[error(transparent)]
+    Budgets(#[from] UsageBudgetError),
+}
+
+/// Ports consulted by [`InteractionGraphService`].
+#[derive(Clone)]
+pub struct InteractionGraphPorts {
+    /// Agent sessions, drawn as the graph's agents.
+    pub sessions: Arc<dyn AgentSessionRepository>,
+    /// Handoffs between sessions.
+    pub handoffs: Arc<dyn AgentHandoffPort>,
+    /// Messages, scanned for tool calls and their results.
+    pub messages: Arc<dyn MessageRepository>,
+    /// Budgets, which record delegations to child conversations.
+    pub budgets: Arc<dyn UsageBudgetRepository>,
+}
+
+/// Service that draws who did what in a conversation.
+///
+/// The graph holds the conversation's agent sessions, the handoffs between
+/// them, the child conversations it delegated work to, and the tools each
+/// session called. Tool calls and delegations are attributed to the session
+/// that was running when they happened, or to the conversation itself when
+/// no session was.
+#[derive(Clone)]
+pub struct InteractionGraphService {
+    ports: InteractionGraphPorts,
+}
+
+impl InteractionGraphService {
+    /// Creates an interaction graph service.
+    #[must_use]
+    pub const fn new(ports: InteractionGraphPorts) -> Self {
+        Self { ports }
+    }
+
+    /// Builds the interaction graph of a conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the failing port's error.
+    pub async fn graph(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> InteractionGraphResult<InteractionGraph> {
+        let sessions_port = &self.ports.sessions;
+        let mut sessions =
+            collect_pages(|page| sessions_port.find_by_conversation(ctx, conversation_id, page))
+                .await?;
+        sessions.sort_by_key(|session| session.start_sequence);
+        let handoffs_port = &self.ports.handoffs;
+        let handoffs = collect_pages(|page| {
+            handoffs_port.list_handoffs_for_conversation(ctx, conversation_id, page)
+        })
+        .await?;
+        let messages_port = &self.ports.messages;
+        let messages =
+            collect_pages(|page| messages_port.find_by_conversation(ctx, conversation_id, page))
+                .await?;
+        let budgets_port = &self.ports.budgets;
+        let delegations =
+            collect_pages(|page| budgets_port.find_delegated(ctx, conversation_id, page)).await?;
+
+        let mut graph = InteractionGraph::new(conversation_id);
+        add_sessions(&mut graph, &sessions, &handoffs);
+        add_handoffs(&mut graph, &handoffs);
+        add_delegations(&mut graph, &sessions, &delegations);
+        add_tool_use(&mut graph, &sessions, &messages);
+        Ok(graph)
+    }
+
+    /// Renders the interaction graph of a conversation as diagram source.
+    ///
+    /// # Errors
+    ///
+    /// Returns the failing port's error.
+    pub async fn export(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        format: GraphFormat,
+    ) -> InteractionGraphResult<String> {
+        Ok(self.graph(ctx, conversation_id).await?.render(format))
+    }
+}
+
+fn add_sessions(
+    graph: &mut InteractionGraph,
+    sessions: &[AgentSession],
+    handoffs: &[HandoffMetadata],
+) {
+    let handed_over: HashSet<_> = handoffs
+        .iter()
+        .filter_map(|handoff| handoff.target_session_id)
+        .collect();
+    let root = conversation_node_id(graph.conversation_id);
+    for session in sessions {
+        if !handed_over.contains(&session.session_id) {
+            graph.edges.push(InteractionEdge::new(
+                root.clone(),
+                session_node_id(session.session_id),
+                "started",
+                InteractionEdgeKind::Started,
+            ));
+        }
+        graph.nodes.push(InteractionNode::session(session));
+    }
+}
+
+fn add_handoffs(graph: &mut InteractionGraph, handoffs: &[HandoffMetadata]) {
+    for handoff in handoffs {
+        let target = if let Some(target_session_id) = handoff.target_session_id {
+            session_node_id(target_session_id)
+        } else {
+            let node = InteractionNode::pending_handoff(handoff);
+            let id = node.id.clone();
+            graph.nodes.push(node);
+            id
+        };
+        let label = handoff.reason.as_ref().map_or_else(
+            || "handoff".to_owned(),
+            |reason| format!("handoff: {reason}"),
+        );
+        graph.edges.push(InteractionEdge::new(
+            session_node_id(handoff.source_session_id),
+            target,
+            label,
+            InteractionEdgeKind::Handoff,
+        ));
+    }
+}
+
+fn add_delegations(
+    graph: &mut InteractionGraph,
+    sessions: &[AgentSession],
+    delegations: &[ConversationBudget],
+) {
+    for child in delegations {
+        let owner = session_running_at(sessions, child.created_at).map_or_else(
+            || conversation_node_id(graph.conversation_id),
+            |session| session_node_id(session.session_id),
+        );
+        let node = InteractionNode::conversation(child.conversation_id);
+        let label = if child.reconciled_at.is_some() {
+            "delegated, reconciled"
+        } else {
+            "delegated"
+        };
+        graph.edges.push(InteractionEdge::new(
+            owner,
+            node.id.clone(),
+            label,
+            InteractionEdgeKind::Delegation,
+        ));
+        graph.nodes.push(node);
+    }
+}
+
+#[derive(Default)]
+struct ToolTally {
+    calls: usize,
+    failures: usize,
+}
+
+/// Tool calls counted per caller and tool. Callers are indices into the
+/// sessions, or `None` for calls made outside any session, so the tallies
+/// iterate in the order the callers ran.
+type ToolTallies<'a> = BTreeMap<(Option<usize>, &'a str), ToolTally>;
+
+fn tally_tool_calls<'a>(sessions: &[AgentSession], messages: &'a [Message]) -> ToolTallies<'a> {
+    let failed: HashSet<&str> = messages
+        .iter()
+        .flat_map(Message::content)
+        .filter_map(|part| match part {
+            ContentPart::ToolResult(result) if !result.success => Some(result.call_id.as_str()),
+            _ => None,
+        })
+        .collect();
+    let mut tallies = ToolTallies::new();
+    for message in messages {
+        let caller = session_holding(sessions, message.sequence_number());
+        for part in message.content() {
+            if let ContentPart::ToolCall(call) = part {
+                let tally = tallies.entry((caller, call.name.as_str())).or_default();
+                tally.calls += 1;
+                tally.failures += usize::from(failed.contains(call.call_id.as_str()));
+            }
+        }
+    }
+    tallies
+}
+
+fn add_tool_use(graph: &mut InteractionGraph, sessions: &[AgentSession], messages: &[Message]) {
+    let mut tools: BTreeMap<&str, InteractionNode> = BTreeMap::new();
+    for ((caller, tool_name), tally) in tally_tool_calls(sessions, messages) {
+        let node = tools
+            .entry(tool_name)
+            .or_insert_with(|| InteractionNode::tool(tool_name));
+        let mut label = plural(tally.calls, "call");
+        if tally.failures > 0 {
+            label = format!("{label}, {} failed", tally.failures);
+        }
+        let from = caller.and_then(|index| sessions.get(index)).map_or_else(
+            || conversation_node_id(graph.conversation_id),
+            |session| session_node_id(session.session_id),
+        );
+        graph.edges.push(InteractionEdge::new(
+            from,
+            node.id.clone(),
+            label,
+            InteractionEdgeKind::ToolUse,
+        ));
+    }
+    graph.nodes.extend(tools.into_values());
+}
+
+fn session_holding(sessions: &[AgentSession], sequence: SequenceNumber) -> Option<usize> {
+    sessions.iter().rposition(|session| {
+        session.start_sequence <= sequence && session.end_sequence.is_none_or(|end| sequence <= end)
+    })
+}
+
+fn session_running_at(sessions: &[AgentSession], instant: DateTime<Utc>) -> Option<&AgentSession> {
+    sessions.iter().rev().find(|session| {
+        session.started_at <= instant && session.ended_at.is_none_or(|end| instant <= end)
+    })
+}
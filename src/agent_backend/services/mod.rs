@@ -1,11 +1,37 @@
 //! Application services for agent backend orchestration.
 
+mod agent_memory;
+mod dataset_recorder;
+mod deprecation;
+mod experiments;
+mod fair_share;
+mod interaction_graph;
 mod orchestrator;
 mod registry;
+mod turn_callbacks;
 
+pub use agent_memory::{
+    AgentMemoryError, AgentMemoryPorts, AgentMemoryResult, AgentMemoryService,
+    DEFAULT_MEMORY_RECALL_LIMIT, MemoryFactEdit,
+};
+pub use dataset_recorder::{RecordedTurn, ToolDatasetRecorder};
+pub use deprecation::{
+    BackendDeprecationPorts, BackendDeprecationService, DeprecationServiceError,
+    DeprecationServiceResult, RepinRequest,
+};
+pub use experiments::{
+    BackendExperimentService, ExperimentAssignment, ExperimentServiceError, ExperimentServiceResult,
+};
+pub use fair_share::{FairShareScheduler, FairShareWaitExceeded};
+pub use interaction_graph::{
+    InteractionGraphError, InteractionGraphPorts, InteractionGraphResult, InteractionGraphService,
+};
 pub use orchestrator::{
     AgentTurnOrchestrationError, AgentTurnOrchestrationResult, AgentTurnOrchestratorConfig,
     AgentTurnOrchestratorPorts, AgentTurnOrchestratorService, ExecuteAgentTurnRequest,
     ExecuteAgentTurnResponse,
 };
 pub use registry::{BackendRegistryService, BackendRegistryServiceError, RegisterBackendRequest};
+pub use turn_callbacks::{
+    DEFAULT_TURN_CALLBACK_POLL_INTERVAL, TurnCallbackMetrics, TurnCallbackService,
+};
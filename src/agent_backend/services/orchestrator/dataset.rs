@@ -0,0 +1,65 @@
+//! Dataset recording hook for orchestrated turns.
+
+use super::{AgentTurnOrchestratorService, TurnCompletionParams};
+use crate::agent_backend::{
+    domain::{DatasetTurnOutcome, ToolCallResult},
+    ports::{
+        AgentRuntimePort, BackendRegistryRepository, ToolDatasetError, ToolRouterPort,
+        TurnSessionRepository,
+    },
+    services::{RecordedTurn, ToolDatasetRecorder},
+};
+use mockable::Clock;
+use std::sync::Arc;
+
+impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
+where
+    R: BackendRegistryRepository,
+    S: TurnSessionRepository,
+    RT: AgentRuntimePort,
+    TR: ToolRouterPort,
+    C: Clock + Send + Sync,
+{
+    /// Records tool invocations from executed turns into an evaluation
+    /// dataset.
+    ///
+    /// Recording is best-effort: store failures are logged and never fail
+    /// the turn.
+    #[must_use]
+    pub fn with_dataset_recorder(mut self, recorder: Arc<ToolDatasetRecorder>) -> Self {
+        self.dataset_recorder = Some(recorder);
+        self
+    }
+
+    /// Hands the finished turn to the dataset recorder, if one is attached.
+    pub(super) async fn record_dataset_turn(
+        &self,
+        params: &TurnCompletionParams<'_>,
+        results: &[ToolCallResult],
+        outcome: DatasetTurnOutcome,
+    ) {
+        let Some(recorder) = self.dataset_recorder.as_deref() else {
+            return;
+        };
+        let turn = RecordedTurn {
+            backend_id: params.backend.id(),
+            turn: &params.request.turn,
+            tool_calls: params.tool_calls,
+            results,
+            outcome,
+            recorded_at: self.clock.utc(),
+        };
+        if let Err(error) = recorder.record_turn(params.ctx, &turn).await {
+            warn_dataset_recording_failed(&turn, &error);
+        }
+    }
+}
+
+fn warn_dataset_recording_failed(turn: &RecordedTurn<'_>, error: &ToolDatasetError) {
+    tracing::warn!(
+        error = %error,
+        backend_id = %turn.backend_id,
+        conversation_id = %turn.turn.conversation_id(),
+        "failed to record tool invocations into dataset"
+    );
+}
@@ -1,11 +1,13 @@
 //! Error types for agent turn orchestration.
 
 use crate::agent_backend::{
-    domain::{BackendId, TurnSessionDomainError},
+    domain::{BackendId, TurnOutcome, TurnSessionDomainError},
     ports::{
         AgentRuntimeError, BackendRegistryError, ToolRoutingError, TurnSessionRepositoryError,
     },
+    services::FairShareWaitExceeded,
 };
+use crate::maintenance::domain::MaintenanceMode;
 use thiserror::Error;
 
 /// Service-level errors for turn orchestration.
@@ -31,6 +33,15 @@ pub enum AgentTurnOrchestrationError {
     #[error(transparent)]
     Runtime(#[from] AgentRuntimeError),
 
+    /// The tenant's share of the backend's provider capacity did not free
+    /// up in time.
+    #[error(transparent)]
+    CapacityExhausted(#[from] FairShareWaitExceeded),
+
+    /// The cluster is in read-only maintenance mode.
+    #[error(transparent)]
+    MaintenanceMode(#[from] MaintenanceMode),
+
     /// Session repository operation failed.
     #[error(transparent)]
     SessionRepository(#[from] TurnSessionRepositoryError),
@@ -51,5 +62,39 @@ pub enum AgentTurnOrchestrationError {
     },
 }
 
+impl AgentTurnOrchestrationError {
+    /// Returns the turn outcome this error represents.
+    ///
+    /// Backend and tool routing failures end the turn with an outcome.
+    /// Registry, session, and configuration failures are infrastructure
+    /// faults rather than turn outcomes, and return `None`, as do turns
+    /// refused for maintenance, which never started.
+    #[must_use]
+    pub fn outcome(&self) -> Option<TurnOutcome> {
+        match self {
+            Self::BackendNotFound(_)
+            | Self::BackendInactive(_)
+            | Self::Runtime(_)
+            | Self::CapacityExhausted(_) => Some(TurnOutcome::BackendFailed {
+                reason: self.to_string(),
+            }),
+            Self::ToolRouting {
+                call_id,
+                tool_name,
+                source,
+            } => Some(TurnOutcome::ToolBlocked {
+                call_id: call_id.clone(),
+                tool_name: tool_name.clone(),
+                reason: source.to_string(),
+            }),
+            Self::InvalidSessionTtl(_)
+            | Self::BackendRegistry(_)
+            | Self::MaintenanceMode(_)
+            | Self::SessionRepository(_)
+            | Self::SessionDomain(_) => None,
+        }
+    }
+}
+
 /// Result type for orchestration operations.
 pub type AgentTurnOrchestrationResult<T> = Result<T, AgentTurnOrchestrationError>;
@@ -0,0 +1,69 @@
+//! Experiment routing hook for orchestrated turns.
+
+use super::{AgentTurnOrchestratorService, ExecuteAgentTurnRequest};
+use crate::agent_backend::{
+    domain::ExperimentSignal,
+    ports::{AgentRuntimePort, BackendRegistryRepository, ToolRouterPort, TurnSessionRepository},
+    services::{BackendExperimentService, ExperimentAssignment, ExperimentServiceError},
+};
+use crate::context::RequestContext;
+use mockable::Clock;
+use std::sync::Arc;
+
+impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
+where
+    R: BackendRegistryRepository,
+    S: TurnSessionRepository,
+    RT: AgentRuntimePort,
+    TR: ToolRouterPort,
+    C: Clock + Send + Sync,
+{
+    /// Routes turns through running backend experiments and records each
+    /// experiment turn's success and latency.
+    ///
+    /// Experiment failures are logged and never fail the turn; a turn whose
+    /// experiments cannot be loaded runs on the backend it was addressed to.
+    #[must_use]
+    pub fn with_experiments(mut self, experiments: Arc<BackendExperimentService>) -> Self {
+        self.experiments = Some(experiments);
+        self
+    }
+
+    /// Rewrites the request for its experiment arm, if any experiment
+    /// applies.
+    pub(super) async fn route_experiment(
+        &self,
+        ctx: &RequestContext,
+        request: ExecuteAgentTurnRequest,
+    ) -> (ExecuteAgentTurnRequest, Option<ExperimentAssignment>) {
+        let Some(experiments) = self.experiments.as_deref() else {
+            return (request, None);
+        };
+        match experiments.route_turn(ctx, request.clone()).await {
+            Ok(routed) => routed,
+            Err(error) => {
+                warn_experiment_failure(&error, "failed to route turn through experiments");
+                (request, None)
+            }
+        }
+    }
+
+    /// Records a turn outcome against the conversation's experiment arm.
+    pub(super) async fn observe_experiment_turn(
+        &self,
+        ctx: &RequestContext,
+        assignment: &ExperimentAssignment,
+        signal: ExperimentSignal,
+    ) {
+        let Some(experiments) = self.experiments.as_deref() else {
+            return;
+        };
+        if let Err(error) = experiments.record_signal(ctx, assignment, signal).await {
+            warn_experiment_failure(&error, "failed to record experiment turn outcome");
+        }
+    }
+}
+
+fn warn_experiment_failure(error: &ExperimentServiceError, message: &str) {
+    tracing::warn!(error = %error, "{message}");
+}
@@ -0,0 +1,56 @@
+//! Fair-share admission of orchestrated turns to shared provider capacity.
+
+use super::{AgentTurnOrchestrationResult, AgentTurnOrchestratorService};
+use crate::agent_backend::{
+    domain::AgentBackendRegistration,
+    ports::{AgentRuntimePort, BackendRegistryRepository, ToolRouterPort, TurnSessionRepository},
+    services::FairShareScheduler,
+};
+use crate::context::RequestContext;
+use mockable::Clock;
+use std::sync::Arc;
+
+impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
+where
+    R: BackendRegistryRepository,
+    S: TurnSessionRepository,
+    RT: AgentRuntimePort,
+    TR: ToolRouterPort,
+    C: Clock + Send + Sync,
+{
+    /// Admits every turn through `scheduler` before the backend is invoked,
+    /// so tenants sharing a provider share its rate limit by weight.
+    ///
+    /// A turn waits for its tenant's share of the backend's provider and
+    /// fails with
+    /// [`AgentTurnOrchestrationError::CapacityExhausted`](super::AgentTurnOrchestrationError::CapacityExhausted)
+    /// when it would wait longer than the scheduler's policy allows.
+    #[must_use]
+    pub fn with_fair_share(mut self, scheduler: Arc<FairShareScheduler>) -> Self {
+        self.fair_share = Some(scheduler);
+        self
+    }
+
+    /// Waits for the tenant's share of the backend's provider, if a
+    /// scheduler is attached.
+    pub(super) async fn admit_fair_share(
+        &self,
+        ctx: &RequestContext,
+        backend: &AgentBackendRegistration,
+    ) -> AgentTurnOrchestrationResult<()> {
+        let Some(scheduler) = self.fair_share.as_deref() else {
+            return Ok(());
+        };
+        let provider = backend.backend_info().provider();
+        let waited = scheduler.acquire(provider, ctx.tenant_id()).await?;
+        if !waited.is_zero() {
+            tracing::debug!(
+                provider,
+                tenant_id = %ctx.tenant_id(),
+                waited_ms = waited.as_millis(),
+                "turn waited for fair-share capacity"
+            );
+        }
+        Ok(())
+    }
+}
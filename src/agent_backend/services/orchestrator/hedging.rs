@@ -0,0 +1,289 @@
+//! Hedged runtime invocation for slow backend turns.
+//!
+//! Latency samples are kept per backend in a bounded window. Once a backend
+//! has enough samples, an invocation that runs past the configured percentile
+//! is raced against a second attempt; the first successful response wins and
+//! the other attempt is dropped, which cancels it. The hedge always runs in a
+//! short-lived runtime session of its own, since a session runs one turn at a
+//! time.
+
+use super::AgentTurnOrchestratorService;
+use super::types::ExecuteAgentTurnRequest;
+use crate::agent_backend::{
+    domain::{
+        AgentBackendRegistration, BackendId, HedgeSuppression, HedgeTarget, HedgeWinner,
+        HedgingPolicy, RuntimeSessionId, TurnExecutionResult, TurnHedging,
+    },
+    ports::{
+        AgentRuntimePort, AgentRuntimeResult, BackendRegistryRepository, ToolRouterPort,
+        TurnSessionRepository,
+    },
+};
+use crate::context::RequestContext;
+use futures::future::{self, Either};
+use mockable::Clock;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::{Pin, pin};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Number of latency samples retained per backend.
+const LATENCY_WINDOW: usize = 128;
+
+/// Runtime result paired with any hedging metadata for the turn.
+pub(super) type HedgedTurn = (TurnExecutionResult, Option<TurnHedging>);
+
+/// Inputs for one runtime invocation.
+pub(super) struct RuntimeTurnParams<'a> {
+    pub(super) ctx: &'a RequestContext,
+    pub(super) backend: &'a AgentBackendRegistration,
+    pub(super) runtime_session_id: &'a RuntimeSessionId,
+    pub(super) request: &'a ExecuteAgentTurnRequest,
+}
+
+/// Shared latency and budget state for hedged invocations.
+#[derive(Debug)]
+pub(super) struct HedgingState {
+    policy: HedgingPolicy,
+    latencies: Mutex<HashMap<BackendId, VecDeque<Duration>>>,
+    turns: AtomicU64,
+    hedges: AtomicU64,
+}
+
+/// Hedge delay resolved for one invocation.
+struct HedgeTrigger<'a> {
+    state: &'a HedgingState,
+    delay: Duration,
+}
+
+/// Backend and session prepared for a hedged attempt.
+struct PreparedHedge {
+    backend: AgentBackendRegistration,
+    runtime_session_id: RuntimeSessionId,
+    triggered_after: Duration,
+}
+
+impl HedgingState {
+    pub(super) fn new(policy: HedgingPolicy) -> Self {
+        Self {
+            policy,
+            latencies: Mutex::new(HashMap::new()),
+            turns: AtomicU64::new(0),
+            hedges: AtomicU64::new(0),
+        }
+    }
+
+    /// Counts a turn towards the hedge budget and returns its hedge delay,
+    /// if the backend has enough latency samples.
+    fn trigger(&self, backend_id: BackendId) -> Option<HedgeTrigger<'_>> {
+        self.turns.fetch_add(1, Ordering::Relaxed);
+        let latencies = self
+            .latencies
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let samples = latencies.get(&backend_id)?;
+        if samples.len() < self.policy.min_samples().max(1) {
+            return None;
+        }
+        let mut sorted = samples.iter().copied().collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let rank = sorted
+            .len()
+            .saturating_mul(usize::from(self.policy.latency_percentile()))
+            .div_ceil(100)
+            .saturating_sub(1);
+        let percentile = sorted.get(rank).copied()?;
+        Some(HedgeTrigger {
+            state: self,
+            delay: percentile.max(self.policy.min_delay()),
+        })
+    }
+
+    fn record_latency(&self, backend_id: BackendId, latency: Duration) {
+        let mut latencies = self
+            .latencies
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let samples = latencies.entry(backend_id).or_default();
+        if samples.len() == LATENCY_WINDOW {
+            samples.pop_front();
+        }
+        samples.push_back(latency);
+    }
+
+    /// Returns the guard that blocks a hedge, consuming budget otherwise.
+    fn suppression(&self, idempotent: bool) -> Option<HedgeSuppression> {
+        if !idempotent {
+            return Some(HedgeSuppression::NotIdempotent);
+        }
+        let allowance = self
+            .turns
+            .load(Ordering::Relaxed)
+            .saturating_mul(u64::from(self.policy.budget_percent()));
+        self.hedges
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |hedges| {
+                let next = hedges.saturating_add(1);
+                (next.saturating_mul(100) <= allowance).then_some(next)
+            })
+            .err()
+            .map(|_| HedgeSuppression::BudgetExhausted)
+    }
+}
+
+impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
+where
+    R: BackendRegistryRepository,
+    S: TurnSessionRepository,
+    RT: AgentRuntimePort,
+    TR: ToolRouterPort,
+    C: Clock + Send + Sync,
+{
+    /// Executes the runtime turn, hedging it when the policy allows.
+    pub(super) async fn execute_runtime_turn(
+        &self,
+        params: &RuntimeTurnParams<'_>,
+    ) -> AgentRuntimeResult<HedgedTurn> {
+        let primary = pin!(self.runtime.execute_turn(
+            params.backend,
+            params.runtime_session_id,
+            &params.request.turn,
+        ));
+        let Some(state) = self.hedging.as_deref() else {
+            return primary.await.map(|result| (result, None));
+        };
+
+        let started = Instant::now();
+        let outcome = match state.trigger(params.backend.id()) {
+            Some(trigger) => self.race_with_hedge(params, primary, trigger).await,
+            None => primary.await.map(|result| (result, None)),
+        };
+        state.record_latency(params.backend.id(), started.elapsed());
+        outcome
+    }
+
+    async fn race_with_hedge<F>(
+        &self,
+        params: &RuntimeTurnParams<'_>,
+        mut primary: Pin<&mut F>,
+        trigger: HedgeTrigger<'_>,
+    ) -> AgentRuntimeResult<HedgedTurn>
+    where
+        F: Future<Output = AgentRuntimeResult<TurnExecutionResult>>,
+    {
+        let timer = pin!(tokio::time::sleep(trigger.delay));
+        if let Either::Left((result, _)) = future::select(primary.as_mut(), timer).await {
+            return result.map(|response| (response, None));
+        }
+
+        let reason = match trigger.state.suppression(params.request.idempotent) {
+            Some(reason) => reason,
+            None => match self.prepare_hedge(params, &trigger).await {
+                Some(hedge) => return self.run_hedge(params, primary, hedge).await,
+                None => HedgeSuppression::TargetUnavailable,
+            },
+        };
+        let hedging = TurnHedging::Suppressed {
+            triggered_after: trigger.delay,
+            reason,
+        };
+        primary.await.map(|response| (response, Some(hedging)))
+    }
+
+    async fn run_hedge<F>(
+        &self,
+        params: &RuntimeTurnParams<'_>,
+        primary: Pin<&mut F>,
+        hedge: PreparedHedge,
+    ) -> AgentRuntimeResult<HedgedTurn>
+    where
+        F: Future<Output = AgentRuntimeResult<TurnExecutionResult>>,
+    {
+        let attempt = pin!(self.runtime.execute_turn(
+            &hedge.backend,
+            &hedge.runtime_session_id,
+            &params.request.turn,
+        ));
+        let (result, winner) = first_success(primary, attempt).await;
+        self.release_hedge(&hedge).await;
+        let hedging = TurnHedging::Issued {
+            hedge_backend_id: hedge.backend.id(),
+            triggered_after: hedge.triggered_after,
+            winner,
+        };
+        result.map(|response| (response, Some(hedging)))
+    }
+
+    async fn prepare_hedge(
+        &self,
+        params: &RuntimeTurnParams<'_>,
+        trigger: &HedgeTrigger<'_>,
+    ) -> Option<PreparedHedge> {
+        let backend = match trigger.state.policy.target() {
+            HedgeTarget::SameBackend => params.backend.clone(),
+            HedgeTarget::Fallback(backend_id) => self
+                .resolve_backend(params.ctx, backend_id)
+                .await
+                .inspect_err(|error| warn_hedge_unavailable(backend_id, error))
+                .ok()?,
+        };
+        let runtime_session_id = self
+            .runtime
+            .create_session(&backend, params.request.turn.conversation_id())
+            .await
+            .inspect_err(|error| warn_hedge_unavailable(backend.id(), error))
+            .ok()?;
+        Some(PreparedHedge {
+            backend,
+            runtime_session_id,
+            triggered_after: trigger.delay,
+        })
+    }
+
+    async fn release_hedge(&self, hedge: &PreparedHedge) {
+        if let Err(error) = self
+            .runtime
+            .teardown_session(&hedge.backend, &hedge.runtime_session_id)
+            .await
+        {
+            warn_hedge_teardown_failed(hedge.backend.id(), &error);
+        }
+    }
+}
+
+/// Awaits both attempts, returning the first successful response.
+///
+/// When the first attempt to finish fails, the other attempt is awaited and
+/// its result returned instead.
+async fn first_success<T, P, H>(
+    primary: Pin<&mut P>,
+    hedge: Pin<&mut H>,
+) -> (AgentRuntimeResult<T>, HedgeWinner)
+where
+    P: Future<Output = AgentRuntimeResult<T>>,
+    H: Future<Output = AgentRuntimeResult<T>>,
+{
+    match future::select(primary, hedge).await {
+        Either::Left((Ok(response), _)) => (Ok(response), HedgeWinner::Primary),
+        Either::Right((Ok(response), _)) => (Ok(response), HedgeWinner::Hedge),
+        Either::Left((Err(_), remaining)) => (remaining.await, HedgeWinner::Hedge),
+        Either::Right((Err(_), remaining)) => (remaining.await, HedgeWinner::Primary),
+    }
+}
+
+fn warn_hedge_unavailable(backend_id: BackendId, error: &impl std::fmt::Display) {
+    tracing::warn!(
+        error = %error,
+        backend_id = %backend_id,
+        "hedge target unavailable"
+    );
+}
+
+fn warn_hedge_teardown_failed(backend_id: BackendId, error: &impl std::fmt::Display) {
+    tracing::warn!(
+        error = %error,
+        backend_id = %backend_id,
+        "failed to tear down hedge session"
+    );
+}
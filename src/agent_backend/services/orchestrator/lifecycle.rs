@@ -0,0 +1,45 @@
+//! Conversation lifecycle announcements for orchestrated turns.
+
+use super::AgentTurnOrchestratorService;
+use crate::agent_backend::ports::{
+    AgentRuntimePort, BackendRegistryRepository, ToolRouterPort, TurnSessionRepository,
+};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationId, ConversationLifecycleChange, ConversationLifecycleEvent},
+    services::ConversationLifecycleHooks,
+};
+use mockable::Clock;
+use std::sync::Arc;
+use uuid::Uuid;
+
+impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
+where
+    R: BackendRegistryRepository,
+    S: TurnSessionRepository,
+    RT: AgentRuntimePort,
+    TR: ToolRouterPort,
+    C: Clock + Send + Sync,
+{
+    /// Attaches hooks that are told when a turn starts.
+    ///
+    /// Hooks run once the backend and session are resolved, just before the
+    /// runtime is invoked. Hook failures never fail the turn.
+    #[must_use]
+    pub fn with_lifecycle_hooks(mut self, hooks: Arc<ConversationLifecycleHooks>) -> Self {
+        self.lifecycle_hooks = Some(hooks);
+        self
+    }
+
+    /// Announces a starting turn to the attached hooks, if any.
+    pub(super) async fn announce_turn_started(&self, ctx: &RequestContext, conversation_id: Uuid) {
+        if let Some(hooks) = &self.lifecycle_hooks {
+            let event = ConversationLifecycleEvent::new(
+                ConversationId::from_uuid(conversation_id),
+                ConversationLifecycleChange::TurnStarted,
+                self.clock.utc(),
+            );
+            hooks.dispatch(ctx, &event).await;
+        }
+    }
+}
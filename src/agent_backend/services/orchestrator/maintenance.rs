@@ -0,0 +1,38 @@
+//! Read-only maintenance mode for orchestrated turns.
+
+use super::{AgentTurnOrchestrationResult, AgentTurnOrchestratorService};
+use crate::agent_backend::ports::{
+    AgentRuntimePort, BackendRegistryRepository, ToolRouterPort, TurnSessionRepository,
+};
+use crate::maintenance::services::MaintenanceGate;
+use mockable::Clock;
+use std::sync::Arc;
+
+impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
+where
+    R: BackendRegistryRepository,
+    S: TurnSessionRepository,
+    RT: AgentRuntimePort,
+    TR: ToolRouterPort,
+    C: Clock + Send + Sync,
+{
+    /// Refuses new turns while `gate` reports maintenance.
+    ///
+    /// A refused turn fails with
+    /// [`AgentTurnOrchestrationError::MaintenanceMode`](super::AgentTurnOrchestrationError::MaintenanceMode)
+    /// before any session, outcome, or memory is written. Turns already
+    /// running when maintenance is enabled finish normally.
+    #[must_use]
+    pub fn with_maintenance(mut self, gate: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(gate);
+        self
+    }
+
+    /// Fails when a maintenance gate is attached and reports maintenance.
+    pub(super) async fn ensure_writable(&self) -> AgentTurnOrchestrationResult<()> {
+        if let Some(gate) = self.maintenance.as_deref() {
+            gate.ensure_writable().await?;
+        }
+        Ok(())
+    }
+}
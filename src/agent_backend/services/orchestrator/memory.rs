@@ -0,0 +1,143 @@
+//! Long-term memory recall and extraction for orchestrated turns.
+
+use super::{AgentTurnOrchestratorService, ExecuteAgentTurnRequest, ExecuteAgentTurnResponse};
+use crate::agent_backend::{
+    domain::{
+        CompletedTurn, ContextAssemblyReport, MemoryRecall, TurnExecutionRequest,
+        assemble_memory_prompt,
+    },
+    ports::{
+        AgentRuntimePort, BackendRegistryRepository, ContextAssemblyReportRepository,
+        ToolRouterPort, TurnSessionRepository,
+    },
+    services::{AgentMemoryError, AgentMemoryService},
+};
+use crate::context::RequestContext;
+use mockable::Clock;
+use std::sync::Arc;
+
+/// The turn as it was before recalled facts were folded into its prompt.
+pub(super) struct RememberedPrompt(CompletedTurn);
+
+impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
+where
+    R: BackendRegistryRepository,
+    S: TurnSessionRepository,
+    RT: AgentRuntimePort,
+    TR: ToolRouterPort,
+    C: Clock + Send + Sync,
+{
+    /// Recalls long-term memories into each turn's prompt and remembers
+    /// facts from each successful turn.
+    ///
+    /// Memory is best-effort: recall and extraction failures are logged and
+    /// never fail the turn. A turn whose memories cannot be recalled runs
+    /// with its prompt unchanged.
+    #[must_use]
+    pub fn with_memory(mut self, memory: Arc<AgentMemoryService>) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Records, for every turn whose memories are recalled, which facts were
+    /// folded into its prompt and why the others were not.
+    ///
+    /// Reports are only recorded while memory is attached. Recording is
+    /// best-effort: store failures are logged and never fail the turn.
+    #[must_use]
+    pub fn with_context_reports(
+        mut self,
+        context_reports: Arc<dyn ContextAssemblyReportRepository>,
+    ) -> Self {
+        self.context_reports = Some(context_reports);
+        self
+    }
+
+    /// Prefixes the request's prompt with the facts recalled for it.
+    pub(super) async fn recall_memories(
+        &self,
+        ctx: &RequestContext,
+        request: ExecuteAgentTurnRequest,
+    ) -> (ExecuteAgentTurnRequest, Option<RememberedPrompt>) {
+        let Some(memory) = self.memory.as_deref() else {
+            return (request, None);
+        };
+        let turn = &request.turn;
+        let remembered = RememberedPrompt(CompletedTurn {
+            backend_id: request.backend_id,
+            conversation_id: turn.conversation_id(),
+            prompt: turn.prompt().to_owned(),
+            assistant_response: String::new(),
+        });
+        let recall = match memory
+            .recall_explained(ctx, request.backend_id, turn.prompt())
+            .await
+        {
+            Ok(recall) => recall,
+            Err(error) => {
+                warn_memory_failure(&error, "failed to recall memories into turn prompt");
+                return (request, Some(remembered));
+            }
+        };
+        self.record_context_report(ctx, &remembered, (memory, &recall))
+            .await;
+        let recalled = ExecuteAgentTurnRequest {
+            turn: TurnExecutionRequest::new(
+                turn.conversation_id(),
+                assemble_memory_prompt(&recall.recalled, turn.prompt()),
+                turn.tool_calls().to_vec(),
+            ),
+            ..request
+        };
+        (recalled, Some(remembered))
+    }
+
+    /// Hands the recall's verdicts to the report repository, if one is
+    /// attached.
+    async fn record_context_report(
+        &self,
+        ctx: &RequestContext,
+        RememberedPrompt(turn): &RememberedPrompt,
+        (memory, recall): (&AgentMemoryService, &MemoryRecall),
+    ) {
+        let Some(repository) = self.context_reports.as_deref() else {
+            return;
+        };
+        let report = ContextAssemblyReport {
+            backend_id: turn.backend_id,
+            conversation_id: turn.conversation_id,
+            recall_limit: memory.recall_limit(),
+            memories: recall.decisions.clone(),
+            assembled_at: self.clock.utc(),
+        };
+        if let Err(error) = repository.record(ctx, &report).await {
+            tracing::warn!(
+                error = %error,
+                conversation_id = %turn.conversation_id,
+                "failed to record context assembly report"
+            );
+        }
+    }
+
+    /// Hands a successful turn to fact extraction.
+    pub(super) async fn remember_turn(
+        &self,
+        ctx: &RequestContext,
+        RememberedPrompt(mut turn): RememberedPrompt,
+        response: &ExecuteAgentTurnResponse,
+    ) {
+        let Some(memory) = self.memory.as_deref() else {
+            return;
+        };
+        response
+            .assistant_response()
+            .clone_into(&mut turn.assistant_response);
+        if let Err(error) = memory.remember_turn(ctx, &turn).await {
+            warn_memory_failure(&error, "failed to remember facts from turn");
+        }
+    }
+}
+
+fn warn_memory_failure(error: &AgentMemoryError, message: &str) {
+    tracing::warn!(error = %error, "{message}");
+}
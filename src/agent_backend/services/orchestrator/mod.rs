@@ -1,29 +1,44 @@
 //! Service layer for agent turn orchestration and session continuity.
 
+mod dataset;
 mod errors;
 mod execution_locks;
+mod experiments;
+mod fair_share;
+mod hedging;
+mod lifecycle;
+mod maintenance;
+mod memory;
+mod outcomes;
+mod sessions;
+mod tool_routing;
 mod types;
 
 pub use errors::{AgentTurnOrchestrationError, AgentTurnOrchestrationResult};
 use execution_locks::SessionExecutionLocks;
+use hedging::{HedgingState, RuntimeTurnParams};
+use sessions::{SessionPersistenceParams, SessionResolutionParams};
+use tool_routing::TurnCompletionParams;
 use types::ExecuteAgentTurnResponseParts;
 pub use types::{AgentTurnOrchestratorConfig, ExecuteAgentTurnRequest, ExecuteAgentTurnResponse};
 
 use crate::agent_backend::{
-    domain::{
-        AgentBackendRegistration, BackendId, BackendStatus, ToolCallAudit, ToolCallAuditStatus,
-        ToolCallRequest, ToolCallResult, TurnSession, deterministic_tool_call_id,
-    },
+    domain::{AgentBackendRegistration, BackendId, BackendStatus, ExperimentSignal},
     ports::{
-        AgentRuntimePort, BackendRegistryRepository, SessionSlotArbitration, SessionSlotKey,
-        SessionSlotReservation, ToolRouterPort, ToolRoutingContext, TurnSessionRepository,
+        AgentRuntimePort, BackendRegistryRepository, ContextAssemblyReportRepository,
+        ToolRouterPort, TurnOutcomeRepository, TurnSessionRepository,
+    },
+    services::{
+        AgentMemoryService, BackendExperimentService, FairShareScheduler, ToolDatasetRecorder,
+        TurnCallbackService,
     },
 };
 use crate::context::RequestContext;
-use chrono::Utc;
+use crate::maintenance::services::MaintenanceGate;
+use crate::message::services::ConversationLifecycleHooks;
 use mockable::Clock;
 use std::sync::Arc;
-use uuid::Uuid;
+use std::time::Instant;
 
 /// Dependency bundle for [`AgentTurnOrchestratorService`].
 pub struct AgentTurnOrchestratorPorts<R, S, RT, TR, C>
@@ -46,19 +61,6 @@ where
     pub clock: Arc<C>,
 }
 
-struct SessionResolutionParams<'a> {
-    ctx: &'a RequestContext,
-    backend: &'a AgentBackendRegistration,
-    conversation_id: Uuid,
-    now: chrono::DateTime<Utc>,
-}
-
-struct SessionPersistenceParams<'a> {
-    ctx: &'a RequestContext,
-    backend: &'a AgentBackendRegistration,
-    reused_session: bool,
-}
-
 /// Service that orchestrates backend turns and session lifecycle.
 #[derive(Clone)]
 pub struct AgentTurnOrchestratorService<R, S, RT, TR, C>
@@ -76,6 +78,16 @@ where
     clock: Arc<C>,
     config: AgentTurnOrchestratorConfig,
     execution_locks: Arc<SessionExecutionLocks>,
+    hedging: Option<Arc<HedgingState>>,
+    dataset_recorder: Option<Arc<ToolDatasetRecorder>>,
+    experiments: Option<Arc<BackendExperimentService>>,
+    fair_share: Option<Arc<FairShareScheduler>>,
+    lifecycle_hooks: Option<Arc<ConversationLifecycleHooks>>,
+    maintenance: Option<Arc<MaintenanceGate>>,
+    memory: Option<Arc<AgentMemoryService>>,
+    turn_outcomes: Option<Arc<dyn TurnOutcomeRepository>>,
+    turn_callbacks: Option<Arc<TurnCallbackService>>,
+    context_reports: Option<Arc<dyn ContextAssemblyReportRepository>>,
 }
 
 impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
@@ -92,6 +104,9 @@ where
         ports: AgentTurnOrchestratorPorts<R, S, RT, TR, C>,
         config: AgentTurnOrchestratorConfig,
     ) -> Self {
+        let hedging = config
+            .hedging()
+            .map(|policy| Arc::new(HedgingState::new(policy)));
         Self {
             backend_registry: ports.backend_registry,
             turn_sessions: ports.turn_sessions,
@@ -100,6 +115,16 @@ where
             clock: ports.clock,
             config,
             execution_locks: Arc::new(SessionExecutionLocks::new()),
+            hedging,
+            dataset_recorder: None,
+            experiments: None,
+            fair_share: None,
+            lifecycle_hooks: None,
+            maintenance: None,
+            memory: None,
+            turn_outcomes: None,
+            turn_callbacks: None,
+            context_reports: None,
         }
     }
 
@@ -111,18 +136,63 @@ where
 
     /// Executes one agent turn with deterministic tool routing.
     ///
+    /// When experiments are attached, the turn first runs through
+    /// [`BackendExperimentService::route_turn`] and may execute on a
+    /// different backend or with a varied prompt. When memory is attached,
+    /// recalled facts are folded into the prompt and facts from the
+    /// completed turn are remembered; with a context assembly report
+    /// repository attached as well, the verdict for every remembered fact is
+    /// recorded. When a maintenance gate is attached,
+    /// turns are refused before anything is recorded while maintenance is
+    /// enabled.
+    ///
+    /// The response carries the turn's
+    /// [`TurnOutcome`](crate::agent_backend::domain::TurnOutcome); failures
+    /// that end the turn map to an outcome through
+    /// [`AgentTurnOrchestrationError::outcome`]. When a turn outcome
+    /// repository is attached, every outcome is recorded; when turn
+    /// callbacks are attached, callbacks waiting on the conversation are
+    /// notified.
+    ///
     /// # Errors
     ///
     /// Returns [`AgentTurnOrchestrationError`] when backend lookup fails,
-    /// session lifecycle operations fail, runtime execution fails, or tool
-    /// routing fails.
+    /// session lifecycle operations fail, runtime execution fails, tool
+    /// routing fails, or the cluster is in maintenance.
     pub async fn execute_turn(
         &self,
         ctx: &RequestContext,
         request: ExecuteAgentTurnRequest,
+    ) -> AgentTurnOrchestrationResult<ExecuteAgentTurnResponse> {
+        self.ensure_writable().await?;
+        let (routed, assignment) = self.route_experiment(ctx, request).await;
+        let (recalled, remembered) = self.recall_memories(ctx, routed).await;
+        let turn_key = (recalled.backend_id, recalled.turn.conversation_id());
+        let started = Instant::now();
+        let result = self.execute_routed_turn(ctx, recalled).await;
+        self.record_turn_outcome(ctx, turn_key, &result).await;
+        if let (Some(turn), Ok(response)) = (remembered, &result) {
+            self.remember_turn(ctx, turn, response).await;
+        }
+        if let Some(experiment_assignment) = assignment {
+            let signal = ExperimentSignal::Turn {
+                succeeded: result.is_ok(),
+                latency: started.elapsed(),
+            };
+            self.observe_experiment_turn(ctx, &experiment_assignment, signal)
+                .await;
+        }
+        result
+    }
+
+    async fn execute_routed_turn(
+        &self,
+        ctx: &RequestContext,
+        request: ExecuteAgentTurnRequest,
     ) -> AgentTurnOrchestrationResult<ExecuteAgentTurnResponse> {
         let conversation_id = request.turn.conversation_id();
         let backend = self.resolve_backend(ctx, request.backend_id).await?;
+        self.admit_fair_share(ctx, &backend).await?;
 
         // Acquire the per-conversation in-process lock and hold it for the
         // entire turn.  Session arbitration, runtime execution, tool routing,
@@ -144,11 +214,14 @@ where
         let (mut session, reused_session, rotated_session) =
             self.resolve_session(&resolution_params).await?;
 
-        let runtime_result = match self
-            .runtime
-            .execute_turn(&backend, session.runtime_session_handle(), &request.turn)
-            .await
-        {
+        self.announce_turn_started(ctx, conversation_id).await;
+        let runtime_params = RuntimeTurnParams {
+            ctx,
+            backend: &backend,
+            runtime_session_id: session.runtime_session_handle(),
+            request: &request,
+        };
+        let (runtime_result, hedging) = match self.execute_runtime_turn(&runtime_params).await {
             Ok(result) => result,
             Err(error) => {
                 self.expire_persist_and_teardown(ctx, &backend, &mut session)
@@ -157,27 +230,16 @@ where
             }
         };
 
-        let (tool_results, tool_call_audits) = match self
-            .route_tool_calls(ctx.tenant_id(), &session, runtime_result.tool_calls())
-            .await
-        {
-            Ok(routed) => routed,
-            Err(error) => {
-                self.expire_persist_and_teardown(ctx, &backend, &mut session)
-                    .await?;
-                return Err(error);
-            }
+        let completion = TurnCompletionParams {
+            ctx,
+            backend: &backend,
+            request: &request,
+            tool_calls: runtime_result.routable_tool_calls(),
+            reused_session,
         };
-
-        self.persist_completed_turn(
-            &SessionPersistenceParams {
-                ctx,
-                backend: &backend,
-                reused_session,
-            },
-            &mut session,
-        )
-        .await?;
+        let (tool_results, tool_call_audits) = self
+            .route_and_persist_turn(&completion, &mut session)
+            .await?;
 
         Ok(ExecuteAgentTurnResponse::new(
             &session,
@@ -187,6 +249,8 @@ where
                 tool_call_audits,
                 reused_session,
                 rotated_session,
+                hedging,
+                outcome: runtime_result.outcome(),
             },
         ))
     }
@@ -206,195 +270,4 @@ where
         }
         Ok(backend)
     }
-
-    async fn resolve_session(
-        &self,
-        params: &SessionResolutionParams<'_>,
-    ) -> AgentTurnOrchestrationResult<(TurnSession, bool, bool)> {
-        match self
-            .turn_sessions
-            .arbitrate_session_slot(
-                params.ctx,
-                SessionSlotReservation::new(
-                    SessionSlotKey::new(params.backend.id(), params.conversation_id),
-                    params.now,
-                    self.config.session_ttl(),
-                ),
-            )
-            .await?
-        {
-            SessionSlotArbitration::Reused(existing) => Ok((existing, true, false)),
-            SessionSlotArbitration::Reserved {
-                reservation,
-                prior_expired,
-            } => {
-                let rotated_session = prior_expired.is_some();
-                let activated = self.activate_reserved_session(params, reservation).await?;
-                if let Some(expired_session) = prior_expired {
-                    self.runtime
-                        .teardown_session(params.backend, expired_session.runtime_session_handle())
-                        .await?;
-                }
-                Ok((activated, false, rotated_session))
-            }
-        }
-    }
-
-    async fn expire_persist_and_teardown(
-        &self,
-        ctx: &RequestContext,
-        backend: &AgentBackendRegistration,
-        session: &mut TurnSession,
-    ) -> AgentTurnOrchestrationResult<()> {
-        let expire_time = self.clock.utc();
-        session.mark_expired(expire_time);
-        self.turn_sessions
-            .upsert_session(ctx, session)
-            .await
-            .map_err(AgentTurnOrchestrationError::SessionRepository)?;
-        self.runtime
-            .teardown_session(backend, session.runtime_session_handle())
-            .await
-            .map_err(AgentTurnOrchestrationError::Runtime)
-    }
-
-    async fn persist_completed_turn(
-        &self,
-        params: &SessionPersistenceParams<'_>,
-        session: &mut TurnSession,
-    ) -> AgentTurnOrchestrationResult<()> {
-        let completion_time = self.clock.utc();
-        session.record_turn(completion_time)?;
-        self.persist_session_or_cleanup(params, session).await
-    }
-
-    async fn persist_session_or_cleanup(
-        &self,
-        params: &SessionPersistenceParams<'_>,
-        session: &mut TurnSession,
-    ) -> AgentTurnOrchestrationResult<()> {
-        if let Err(error) = self.turn_sessions.upsert_session(params.ctx, session).await {
-            self.cleanup_after_upsert_failure(params, session).await;
-            return Err(AgentTurnOrchestrationError::SessionRepository(error));
-        }
-        Ok(())
-    }
-
-    async fn cleanup_after_upsert_failure(
-        &self,
-        params: &SessionPersistenceParams<'_>,
-        session: &mut TurnSession,
-    ) {
-        if params.reused_session {
-            return;
-        }
-        if let Err(cleanup_err) = self
-            .expire_persist_and_teardown(params.ctx, params.backend, session)
-            .await
-        {
-            Self::warn_cleanup_failure(&cleanup_err, session);
-        }
-    }
-
-    fn warn_cleanup_failure(error: &AgentTurnOrchestrationError, session: &TurnSession) {
-        tracing::warn!(
-            error = ?error,
-            backend_id = %session.backend_id(),
-            conversation_id = %session.conversation_id(),
-            session_id = ?session.id(),
-            "cleanup failed after session upsert failure; session may leak"
-        );
-    }
-
-    async fn expire_session(
-        &self,
-        ctx: &RequestContext,
-        mut reservation: TurnSession,
-    ) -> AgentTurnOrchestrationResult<()> {
-        reservation.mark_expired(self.clock.utc());
-        self.turn_sessions.upsert_session(ctx, &reservation).await?;
-        Ok(())
-    }
-
-    async fn activate_reserved_session(
-        &self,
-        params: &SessionResolutionParams<'_>,
-        mut reservation: TurnSession,
-    ) -> AgentTurnOrchestrationResult<TurnSession> {
-        let runtime_session_id = match self
-            .runtime
-            .create_session(params.backend, params.conversation_id)
-            .await
-        {
-            Ok(runtime_session_id) => runtime_session_id,
-            Err(error) => {
-                self.expire_session(params.ctx, reservation).await?;
-                return Err(error.into());
-            }
-        };
-        if let Err(error) = reservation.activate(runtime_session_id.clone()) {
-            self.runtime
-                .teardown_session(params.backend, &runtime_session_id)
-                .await?;
-            self.expire_session(params.ctx, reservation).await?;
-            return Err(AgentTurnOrchestrationError::SessionDomain(error));
-        }
-
-        if let Err(error) = self
-            .turn_sessions
-            .upsert_session(params.ctx, &reservation)
-            .await
-        {
-            self.runtime
-                .teardown_session(params.backend, &runtime_session_id)
-                .await?;
-            self.expire_session(params.ctx, reservation).await?;
-            return Err(AgentTurnOrchestrationError::SessionRepository(error));
-        }
-
-        Ok(reservation)
-    }
-
-    async fn route_tool_calls(
-        &self,
-        tenant_id: crate::context::TenantId,
-        session: &TurnSession,
-        tool_calls: &[ToolCallRequest],
-    ) -> AgentTurnOrchestrationResult<(Vec<ToolCallResult>, Vec<ToolCallAudit>)> {
-        let mut tool_results = Vec::with_capacity(tool_calls.len());
-        let mut audits = Vec::with_capacity(tool_calls.len());
-
-        for (index, tool_call) in tool_calls.iter().enumerate() {
-            let call_id = deterministic_tool_call_id(tool_call, index);
-            let context = ToolRoutingContext::new(
-                tenant_id,
-                session.backend_id(),
-                session.conversation_id(),
-                session.id(),
-            );
-            match self
-                .tool_router
-                .route_tool_call(&call_id, tool_call, context)
-                .await
-            {
-                Ok(result) => {
-                    tool_results.push(result);
-                    audits.push(ToolCallAudit::new(
-                        call_id,
-                        tool_call.tool_name(),
-                        ToolCallAuditStatus::Succeeded,
-                    ));
-                }
-                Err(source) => {
-                    return Err(AgentTurnOrchestrationError::ToolRouting {
-                        call_id,
-                        tool_name: tool_call.tool_name().to_owned(),
-                        source,
-                    });
-                }
-            }
-        }
-
-        Ok((tool_results, audits))
-    }
 }
@@ -0,0 +1,115 @@
+//! Turn outcome recording and completion callbacks for orchestrated turns.
+
+use super::{AgentTurnOrchestrationResult, AgentTurnOrchestratorService, ExecuteAgentTurnResponse};
+use crate::agent_backend::{
+    domain::{BackendId, TurnOutcomeRecord},
+    ports::{
+        AgentRuntimePort, BackendRegistryRepository, ToolRouterPort, TurnCallbackRepositoryError,
+        TurnOutcomeRepository, TurnOutcomeRepositoryError, TurnSessionRepository,
+    },
+    services::TurnCallbackService,
+};
+use crate::context::RequestContext;
+use mockable::Clock;
+use std::sync::Arc;
+use uuid::Uuid;
+
+impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
+where
+    R: BackendRegistryRepository,
+    S: TurnSessionRepository,
+    RT: AgentRuntimePort,
+    TR: ToolRouterPort,
+    C: Clock + Send + Sync,
+{
+    /// Records the outcome of every turn.
+    ///
+    /// Recording is best-effort: store failures are logged and never fail
+    /// the turn. Infrastructure failures that are not turn outcomes are not
+    /// recorded.
+    #[must_use]
+    pub fn with_turn_outcomes(mut self, turn_outcomes: Arc<dyn TurnOutcomeRepository>) -> Self {
+        self.turn_outcomes = Some(turn_outcomes);
+        self
+    }
+
+    /// Notifies turn completion callbacks when each turn ends.
+    ///
+    /// Callbacks registered against a conversation are consumed by the
+    /// next turn that ends there, whatever its outcome. Notification is
+    /// best-effort: failures to take the callbacks are logged and never
+    /// fail the turn.
+    #[must_use]
+    pub fn with_turn_callbacks(mut self, turn_callbacks: Arc<TurnCallbackService>) -> Self {
+        self.turn_callbacks = Some(turn_callbacks);
+        self
+    }
+
+    /// Hands the finished turn's outcome to the repository and the turn
+    /// callbacks, if either is attached.
+    pub(super) async fn record_turn_outcome(
+        &self,
+        ctx: &RequestContext,
+        turn_key: (BackendId, Uuid),
+        result: &AgentTurnOrchestrationResult<ExecuteAgentTurnResponse>,
+    ) {
+        if self.turn_outcomes.is_none() && self.turn_callbacks.is_none() {
+            return;
+        }
+        let Some(record) = self.outcome_record(turn_key, result) else {
+            return;
+        };
+        if let Some(repository) = self.turn_outcomes.as_deref()
+            && let Err(error) = repository.record(ctx, &record).await
+        {
+            warn_outcome_recording_failed(&record, &error);
+        }
+        if let Some(callbacks) = self.turn_callbacks.as_deref()
+            && let Err(error) = callbacks.notify(ctx, &record).await
+        {
+            warn_callback_notification_failed(&record, &error);
+        }
+    }
+
+    /// Builds the record for a finished turn; infrastructure failures that
+    /// are not turn outcomes have none.
+    fn outcome_record(
+        &self,
+        (backend_id, conversation_id): (BackendId, Uuid),
+        result: &AgentTurnOrchestrationResult<ExecuteAgentTurnResponse>,
+    ) -> Option<TurnOutcomeRecord> {
+        let (outcome, session_id) = match result {
+            Ok(response) => (response.outcome().clone(), Some(response.session_id())),
+            Err(error) => (error.outcome()?, None),
+        };
+        Some(TurnOutcomeRecord {
+            backend_id,
+            conversation_id,
+            session_id,
+            outcome,
+            recorded_at: self.clock.utc(),
+        })
+    }
+}
+
+fn warn_callback_notification_failed(
+    record: &TurnOutcomeRecord,
+    error: &TurnCallbackRepositoryError,
+) {
+    tracing::warn!(
+        error = %error,
+        conversation_id = %record.conversation_id,
+        outcome = %record.outcome.kind(),
+        "failed to notify turn completion callbacks"
+    );
+}
+
+fn warn_outcome_recording_failed(record: &TurnOutcomeRecord, error: &TurnOutcomeRepositoryError) {
+    tracing::warn!(
+        error = %error,
+        backend_id = %record.backend_id,
+        conversation_id = %record.conversation_id,
+        outcome = %record.outcome.kind(),
+        "failed to record turn outcome"
+    );
+}
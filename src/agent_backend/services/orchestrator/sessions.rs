@@ -0,0 +1,186 @@
+//! Turn session resolution, activation, persistence, and cleanup.
+
+use super::{
+    AgentTurnOrchestrationError, AgentTurnOrchestrationResult, AgentTurnOrchestratorService,
+};
+use crate::agent_backend::{
+    domain::{AgentBackendRegistration, TurnSession},
+    ports::{
+        AgentRuntimePort, BackendRegistryRepository, SessionSlotArbitration, SessionSlotKey,
+        SessionSlotReservation, ToolRouterPort, TurnSessionRepository,
+    },
+};
+use crate::context::RequestContext;
+use chrono::Utc;
+use mockable::Clock;
+use uuid::Uuid;
+
+pub(super) struct SessionResolutionParams<'a> {
+    pub(super) ctx: &'a RequestContext,
+    pub(super) backend: &'a AgentBackendRegistration,
+    pub(super) conversation_id: Uuid,
+    pub(super) now: chrono::DateTime<Utc>,
+}
+
+pub(super) struct SessionPersistenceParams<'a> {
+    pub(super) ctx: &'a RequestContext,
+    pub(super) backend: &'a AgentBackendRegistration,
+    pub(super) reused_session: bool,
+}
+
+impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
+where
+    R: BackendRegistryRepository,
+    S: TurnSessionRepository,
+    RT: AgentRuntimePort,
+    TR: ToolRouterPort,
+    C: Clock + Send + Sync,
+{
+    pub(super) async fn resolve_session(
+        &self,
+        params: &SessionResolutionParams<'_>,
+    ) -> AgentTurnOrchestrationResult<(TurnSession, bool, bool)> {
+        match self
+            .turn_sessions
+            .arbitrate_session_slot(
+                params.ctx,
+                SessionSlotReservation::new(
+                    SessionSlotKey::new(params.backend.id(), params.conversation_id),
+                    params.now,
+                    self.config.session_ttl(),
+                ),
+            )
+            .await?
+        {
+            SessionSlotArbitration::Reused(existing) => Ok((existing, true, false)),
+            SessionSlotArbitration::Reserved {
+                reservation,
+                prior_expired,
+            } => {
+                let rotated_session = prior_expired.is_some();
+                let activated = self.activate_reserved_session(params, reservation).await?;
+                if let Some(expired_session) = prior_expired {
+                    self.runtime
+                        .teardown_session(params.backend, expired_session.runtime_session_handle())
+                        .await?;
+                }
+                Ok((activated, false, rotated_session))
+            }
+        }
+    }
+
+    pub(super) async fn expire_persist_and_teardown(
+        &self,
+        ctx: &RequestContext,
+        backend: &AgentBackendRegistration,
+        session: &mut TurnSession,
+    ) -> AgentTurnOrchestrationResult<()> {
+        let expire_time = self.clock.utc();
+        session.mark_expired(expire_time);
+        self.turn_sessions
+            .upsert_session(ctx, session)
+            .await
+            .map_err(AgentTurnOrchestrationError::SessionRepository)?;
+        self.runtime
+            .teardown_session(backend, session.runtime_session_handle())
+            .await
+            .map_err(AgentTurnOrchestrationError::Runtime)
+    }
+
+    pub(super) async fn persist_completed_turn(
+        &self,
+        params: &SessionPersistenceParams<'_>,
+        session: &mut TurnSession,
+    ) -> AgentTurnOrchestrationResult<()> {
+        let completion_time = self.clock.utc();
+        session.record_turn(completion_time)?;
+        self.persist_session_or_cleanup(params, session).await
+    }
+
+    async fn persist_session_or_cleanup(
+        &self,
+        params: &SessionPersistenceParams<'_>,
+        session: &mut TurnSession,
+    ) -> AgentTurnOrchestrationResult<()> {
+        if let Err(error) = self.turn_sessions.upsert_session(params.ctx, session).await {
+            self.cleanup_after_upsert_failure(params, session).await;
+            return Err(AgentTurnOrchestrationError::SessionRepository(error));
+        }
+        Ok(())
+    }
+
+    async fn cleanup_after_upsert_failure(
+        &self,
+        params: &SessionPersistenceParams<'_>,
+        session: &mut TurnSession,
+    ) {
+        if params.reused_session {
+            return;
+        }
+        if let Err(cleanup_err) = self
+            .expire_persist_and_teardown(params.ctx, params.backend, session)
+            .await
+        {
+            Self::warn_cleanup_failure(&cleanup_err, session);
+        }
+    }
+
+    fn warn_cleanup_failure(error: &AgentTurnOrchestrationError, session: &TurnSession) {
+        tracing::warn!(
+            error = ?error,
+            backend_id = %session.backend_id(),
+            conversation_id = %session.conversation_id(),
+            session_id = ?session.id(),
+            "cleanup failed after session upsert failure; session may leak"
+        );
+    }
+
+    async fn expire_session(
+        &self,
+        ctx: &RequestContext,
+        mut reservation: TurnSession,
+    ) -> AgentTurnOrchestrationResult<()> {
+        reservation.mark_expired(self.clock.utc());
+        self.turn_sessions.upsert_session(ctx, &reservation).await?;
+        Ok(())
+    }
+
+    async fn activate_reserved_session(
+        &self,
+        params: &SessionResolutionParams<'_>,
+        mut reservation: TurnSession,
+    ) -> AgentTurnOrchestrationResult<TurnSession> {
+        let runtime_session_id = match self
+            .runtime
+            .create_session(params.backend, params.conversation_id)
+            .await
+        {
+            Ok(runtime_session_id) => runtime_session_id,
+            Err(error) => {
+                self.expire_session(params.ctx, reservation).await?;
+                return Err(error.into());
+            }
+        };
+        if let Err(error) = reservation.activate(runtime_session_id.clone()) {
+            self.runtime
+                .teardown_session(params.backend, &runtime_session_id)
+                .await?;
+            self.expire_session(params.ctx, reservation).await?;
+            return Err(AgentTurnOrchestrationError::SessionDomain(error));
+        }
+
+        if let Err(error) = self
+            .turn_sessions
+            .upsert_session(params.ctx, &reservation)
+            .await
+        {
+            self.runtime
+                .teardown_session(params.backend, &runtime_session_id)
+                .await?;
+            self.expire_session(params.ctx, reservation).await?;
+            return Err(AgentTurnOrchestrationError::SessionRepository(error));
+        }
+
+        Ok(reservation)
+    }
+}
@@ -0,0 +1,122 @@
+//! Deterministic routing of backend tool calls for orchestrated turns.
+
+use super::{
+    AgentTurnOrchestrationError, AgentTurnOrchestrationResult, AgentTurnOrchestratorService,
+    ExecuteAgentTurnRequest, SessionPersistenceParams,
+};
+use crate::agent_backend::{
+    domain::{
+        AgentBackendRegistration, DatasetTurnOutcome, ToolCallAudit, ToolCallAuditStatus,
+        ToolCallRequest, ToolCallResult, TurnSession, deterministic_tool_call_id,
+    },
+    ports::{
+        AgentRuntimePort, BackendRegistryRepository, ToolRouterPort, ToolRoutingContext,
+        TurnSessionRepository,
+    },
+};
+use crate::context::{RequestContext, TenantId};
+use mockable::Clock;
+
+/// Inputs for routing a turn's tool calls and persisting its completion.
+pub(super) struct TurnCompletionParams<'a> {
+    pub(super) ctx: &'a RequestContext,
+    pub(super) backend: &'a AgentBackendRegistration,
+    pub(super) request: &'a ExecuteAgentTurnRequest,
+    pub(super) tool_calls: &'a [ToolCallRequest],
+    pub(super) reused_session: bool,
+}
+
+impl<R, S, RT, TR, C> AgentTurnOrchestratorService<R, S, RT, TR, C>
+where
+    R: BackendRegistryRepository,
+    S: TurnSessionRepository,
+    RT: AgentRuntimePort,
+    TR: ToolRouterPort,
+    C: Clock + Send + Sync,
+{
+    /// Routes the turn's tool calls, persists the completed session, and
+    /// hands the outcome to the dataset recorder.
+    ///
+    /// A routing failure expires and tears down the session.
+    pub(super) async fn route_and_persist_turn(
+        &self,
+        params: &TurnCompletionParams<'_>,
+        session: &mut TurnSession,
+    ) -> AgentTurnOrchestrationResult<(Vec<ToolCallResult>, Vec<ToolCallAudit>)> {
+        let (tool_results, audits) = match self
+            .route_tool_calls(params.ctx.tenant_id(), session, params.tool_calls)
+            .await
+        {
+            Ok(routed) => routed,
+            Err(error) => {
+                self.record_dataset_turn(params, &[], DatasetTurnOutcome::Failed)
+                    .await;
+                self.expire_persist_and_teardown(params.ctx, params.backend, session)
+                    .await?;
+                return Err(error);
+            }
+        };
+
+        let persisted = self
+            .persist_completed_turn(
+                &SessionPersistenceParams {
+                    ctx: params.ctx,
+                    backend: params.backend,
+                    reused_session: params.reused_session,
+                },
+                session,
+            )
+            .await;
+        let outcome = if persisted.is_ok() {
+            DatasetTurnOutcome::Completed
+        } else {
+            DatasetTurnOutcome::Failed
+        };
+        self.record_dataset_turn(params, &tool_results, outcome)
+            .await;
+        persisted.map(|()| (tool_results, audits))
+    }
+
+    pub(super) async fn route_tool_calls(
+        &self,
+        tenant_id: TenantId,
+        session: &TurnSession,
+        tool_calls: &[ToolCallRequest],
+    ) -> AgentTurnOrchestrationResult<(Vec<ToolCallResult>, Vec<ToolCallAudit>)> {
+        let mut tool_results = Vec::with_capacity(tool_calls.len());
+        let mut audits = Vec::with_capacity(tool_calls.len());
+
+        for (index, tool_call) in tool_calls.iter().enumerate() {
+            let call_id = deterministic_tool_call_id(tool_call, index);
+            let context = ToolRoutingContext::new(
+                tenant_id,
+                session.backend_id(),
+                session.conversation_id(),
+                session.id(),
+            );
+            match self
+                .tool_router
+                .route_tool_call(&call_id, tool_call, context)
+                .await
+            {
+                Ok(result) => {
+                    tool_results.push(result);
+                    audits.push(ToolCallAudit::new(
+                        call_id,
+                        tool_call.tool_name(),
+                        ToolCallAuditStatus::Succeeded,
+                    ));
+                }
+                Err(source) => {
+                    return Err(AgentTurnOrchestrationError::ToolRouting {
+                        call_id,
+                        tool_name: tool_call.tool_name().to_owned(),
+                        source,
+                    });
+                }
+            }
+        }
+
+        Ok((tool_results, audits))
+    }
+}
@@ -2,7 +2,8 @@
 
 use super::AgentTurnOrchestrationError;
 use crate::agent_backend::domain::{
-    BackendId, ToolCallAudit, ToolCallResult, TurnExecutionRequest, TurnSession, TurnSessionId,
+    BackendId, HedgingPolicy, ToolCallAudit, ToolCallResult, TurnExecutionRequest, TurnHedging,
+    TurnOutcome, TurnSession, TurnSessionId,
 };
 use chrono::Duration;
 
@@ -10,6 +11,7 @@ use chrono::Duration;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AgentTurnOrchestratorConfig {
     session_ttl: Duration,
+    hedging: Option<HedgingPolicy>,
 }
 
 impl AgentTurnOrchestratorConfig {
@@ -28,7 +30,17 @@ impl AgentTurnOrchestratorConfig {
         if ttl_seconds <= 0 {
             return Err(AgentTurnOrchestrationError::InvalidSessionTtl(ttl_seconds));
         }
-        Ok(Self { session_ttl })
+        Ok(Self {
+            session_ttl,
+            hedging: None,
+        })
+    }
+
+    /// Enables request hedging for slow backend invocations.
+    #[must_use]
+    pub const fn with_hedging(mut self, policy: HedgingPolicy) -> Self {
+        self.hedging = Some(policy);
+        self
     }
 
     /// Returns configured session TTL.
@@ -36,12 +48,19 @@ impl AgentTurnOrchestratorConfig {
     pub const fn session_ttl(self) -> Duration {
         self.session_ttl
     }
+
+    /// Returns the hedging policy, when hedging is enabled.
+    #[must_use]
+    pub const fn hedging(self) -> Option<HedgingPolicy> {
+        self.hedging
+    }
 }
 
 impl Default for AgentTurnOrchestratorConfig {
     fn default() -> Self {
         Self {
             session_ttl: Duration::minutes(30),
+            hedging: None,
         }
     }
 }
@@ -53,13 +72,27 @@ pub struct ExecuteAgentTurnRequest {
     pub backend_id: BackendId,
     /// Canonical turn request payload.
     pub turn: TurnExecutionRequest,
+    /// Whether the turn may safely be executed more than once, making it
+    /// eligible for hedging.
+    pub idempotent: bool,
 }
 
 impl ExecuteAgentTurnRequest {
     /// Creates an execute-turn request.
     #[must_use]
     pub const fn new(backend_id: BackendId, turn: TurnExecutionRequest) -> Self {
-        Self { backend_id, turn }
+        Self {
+            backend_id,
+            turn,
+            idempotent: false,
+        }
+    }
+
+    /// Marks whether the turn may safely be executed more than once.
+    #[must_use]
+    pub const fn with_idempotent(mut self, idempotent: bool) -> Self {
+        self.idempotent = idempotent;
+        self
     }
 }
 
@@ -73,6 +106,8 @@ pub struct ExecuteAgentTurnResponse {
     tool_call_audits: Vec<ToolCallAudit>,
     reused_session: bool,
     rotated_session: bool,
+    hedging: Option<TurnHedging>,
+    outcome: TurnOutcome,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -82,6 +117,8 @@ pub(super) struct ExecuteAgentTurnResponseParts {
     pub(super) tool_call_audits: Vec<ToolCallAudit>,
     pub(super) reused_session: bool,
     pub(super) rotated_session: bool,
+    pub(super) hedging: Option<TurnHedging>,
+    pub(super) outcome: TurnOutcome,
 }
 
 impl ExecuteAgentTurnResponse {
@@ -95,6 +132,8 @@ impl ExecuteAgentTurnResponse {
             tool_call_audits: parts.tool_call_audits,
             reused_session: parts.reused_session,
             rotated_session: parts.rotated_session,
+            hedging: parts.hedging,
+            outcome: parts.outcome,
         }
     }
 
@@ -139,4 +178,17 @@ impl ExecuteAgentTurnResponse {
     pub const fn rotated_session(&self) -> bool {
         self.rotated_session
     }
+
+    /// Returns hedging metadata when the invocation crossed the hedge
+    /// threshold.
+    #[must_use]
+    pub const fn hedging(&self) -> Option<TurnHedging> {
+        self.hedging
+    }
+
+    /// Returns how the turn ended.
+    #[must_use]
+    pub const fn outcome(&self) -> &TurnOutcome {
+        &self.outcome
+    }
 }
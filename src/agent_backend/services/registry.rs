@@ -11,6 +11,7 @@ use crate::agent_backend::{
     ports::{BackendRegistryError, BackendRegistryRepository},
 };
 use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
 use mockable::Clock;
 use std::sync::Arc;
 use thiserror::Error;
@@ -193,7 +194,8 @@ where
         Ok(self.repository.find_by_name(ctx, &backend_name).await?)
     }
 
-    /// Returns all backend registrations with `Active` status.
+    /// Returns a page of backend registrations with `Active` status, in
+    /// registration order.
     ///
     /// # Errors
     ///
@@ -202,11 +204,13 @@ where
     pub async fn list_active(
         &self,
         ctx: &RequestContext,
-    ) -> BackendRegistryServiceResult<Vec<AgentBackendRegistration>> {
-        Ok(self.repository.list_active(ctx).await?)
+        page: PageRequest,
+    ) -> BackendRegistryServiceResult<Page<AgentBackendRegistration>> {
+        Ok(self.repository.list_active(ctx, page).await?)
     }
 
-    /// Returns all backend registrations regardless of status.
+    /// Returns a page of backend registrations regardless of status, in
+    /// registration order.
     ///
     /// # Errors
     ///
@@ -215,8 +219,9 @@ where
     pub async fn list_all(
         &self,
         ctx: &RequestContext,
-    ) -> BackendRegistryServiceResult<Vec<AgentBackendRegistration>> {
-        Ok(self.repository.list_all(ctx).await?)
+        page: PageRequest,
+    ) -> BackendRegistryServiceResult<Page<AgentBackendRegistration>> {
+        Ok(self.repository.list_all(ctx, page).await?)
     }
 
     /// Deactivates a backend, setting its status to `Inactive`.
@@ -0,0 +1,311 @@
+//! One-shot turn completion callbacks and their delivery with retries.
+//!
+//! Callers that start a turn without waiting for it register a callback
+//! instead of polling conversation state. When the orchestrator records the
+//! conversation's next [`TurnOutcome`](crate::agent_backend::domain::TurnOutcome),
+//! [`TurnCallbackService::notify`] consumes every waiting callback and
+//! queues a notice for each. [`TurnCallbackService::run_until`] delivers
+//! queued notices through the [`TurnCallbackSender`], backing off between
+//! failed attempts until the retry policy gives up. Queued notices live in
+//! memory, so a crash loses notices that have not yet been delivered.
+//!
+//! With a maintenance gate attached, callbacks are neither registered nor
+//! consumed while the cluster is in maintenance, and queued notices are
+//! held until it ends.
+
+use crate::agent_backend::{
+    domain::{
+        TurnCallback, TurnCallbackRetryPolicy, TurnCallbackTarget, TurnCompletionNotice,
+        TurnOutcomeRecord,
+    },
+    ports::{
+        TurnCallbackDeliveryError, TurnCallbackRepository, TurnCallbackRepositoryResult,
+        TurnCallbackSender,
+    },
+};
+use crate::context::RequestContext;
+use crate::maintenance::services::MaintenanceGate;
+use crate::pagination::Limit;
+use chrono::{DateTime, TimeDelta, Utc};
+use futures::future;
+use mockable::Clock;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+use uuid::Uuid;
+
+/// How often queued notices are checked for delivery unless configured
+/// otherwise.
+pub const DEFAULT_TURN_CALLBACK_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Counters describing the work done by a [`TurnCallbackService`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TurnCallbackMetrics {
+    /// Notices queued for delivery.
+    pub notices_queued: usize,
+    /// Notices the target accepted.
+    pub notices_delivered: usize,
+    /// Failed attempts that were scheduled for retry.
+    pub attempts_retried: usize,
+    /// Notices given up on after the final failed attempt.
+    pub notices_abandoned: usize,
+}
+
+/// A notice waiting for its next delivery attempt.
+struct PendingNotice {
+    target: TurnCallbackTarget,
+    notice: TurnCompletionNotice,
+    failed_attempts: u32,
+    due_at: DateTime<Utc>,
+}
+
+/// Service that registers turn callbacks and delivers their notices.
+pub struct TurnCallbackService {
+    callbacks: Arc<dyn TurnCallbackRepository>,
+    sender: Arc<dyn TurnCallbackSender>,
+    clock: Arc<dyn Clock + Send + Sync>,
+    policy: TurnCallbackRetryPolicy,
+    poll_interval: Duration,
+    pending: Mutex<Vec<PendingNotice>>,
+    metrics: Mutex<TurnCallbackMetrics>,
+    maintenance: Option<Arc<MaintenanceGate>>,
+}
+
+impl TurnCallbackService {
+    /// Creates a service with the default retry policy and poll interval.
+    #[must_use]
+    pub fn new(
+        callbacks: Arc<dyn TurnCallbackRepository>,
+        sender: Arc<dyn TurnCallbackSender>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            callbacks,
+            sender,
+            clock,
+            policy: TurnCallbackRetryPolicy::default(),
+            poll_interval: DEFAULT_TURN_CALLBACK_POLL_INTERVAL,
+            pending: Mutex::new(Vec::new()),
+            metrics: Mutex::new(TurnCallbackMetrics::default()),
+            maintenance: None,
+        }
+    }
+
+    /// Replaces the retry policy.
+    #[must_use]
+    pub const fn with_retry_policy(mut self, policy: TurnCallbackRetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Replaces how often queued notices are checked for delivery.
+    #[must_use]
+    pub const fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Refuses to register or consume callbacks, and holds queued notices,
+    /// while `gate` reports maintenance.
+    #[must_use]
+    pub fn with_maintenance(mut self, gate: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(gate);
+        self
+    }
+
+    /// Registers a callback fired when the next turn in `conversation_id`
+    /// ends.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository's error when the callback cannot be stored,
+    /// or
+    /// [`TurnCallbackRepositoryError::MaintenanceMode`](crate::agent_backend::ports::TurnCallbackRepositoryError::MaintenanceMode)
+    /// during maintenance.
+    pub async fn register(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+        target: TurnCallbackTarget,
+    ) -> TurnCallbackRepositoryResult<TurnCallback> {
+        self.ensure_writable().await?;
+        let callback = TurnCallback::new(conversation_id, target, &*self.clock);
+        self.callbacks.register(ctx, &callback).await?;
+        Ok(callback)
+    }
+
+    /// Consumes the callbacks waiting on the turn described by `record` and
+    /// queues a notice for each, returning how many were queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository's error when the callbacks cannot be taken,
+    /// or the maintenance error during maintenance; either way they stay
+    /// registered for the next turn.
+    pub async fn notify(
+        &self,
+        ctx: &RequestContext,
+        record: &TurnOutcomeRecord,
+    ) -> TurnCallbackRepositoryResult<usize> {
+        self.ensure_writable().await?;
+        let callbacks = self.take_all(ctx, record.conversation_id).await?;
+        let due_at = self.clock.utc();
+        let queued = callbacks.len();
+        self.lock_pending()
+            .extend(callbacks.into_iter().map(|callback| PendingNotice {
+                notice: TurnCompletionNotice::new(&callback, record),
+                target: callback.target().clone(),
+                failed_attempts: 0,
+                due_at,
+            }));
+        self.lock_metrics().notices_queued += queued;
+        Ok(queued)
+    }
+
+    /// Attempts every queued notice whose retry delay has elapsed,
+    /// returning how many were delivered. Nothing is attempted during
+    /// maintenance.
+    pub async fn deliver_due(&self) -> usize {
+        if self.paused_for_maintenance().await {
+            return 0;
+        }
+        let now = self.clock.utc();
+        let due = {
+            let mut pending = self.lock_pending();
+            let (due, waiting) = std::mem::take(&mut *pending)
+                .into_iter()
+                .partition::<Vec<_>, _>(|entry| entry.due_at <= now);
+            *pending = waiting;
+            due
+        };
+        let mut delivered = 0;
+        for entry in due {
+            match self.sender.send(&entry.target, &entry.notice).await {
+                Ok(()) => {
+                    delivered += 1;
+                    self.lock_metrics().notices_delivered += 1;
+                }
+                Err(err) => self.reschedule(entry, &err),
+            }
+        }
+        delivered
+    }
+
+    /// Delivers due notices every poll interval until `shutdown` completes,
+    /// then makes one final delivery pass.
+    ///
+    /// Notices still waiting for a retry when `shutdown` completes are lost.
+    pub async fn run_until(&self, shutdown: impl Future<Output = ()>) {
+        future::select(pin!(self.run_forever()), pin!(shutdown)).await;
+        self.deliver_due().await;
+    }
+
+    async fn run_forever(&self) {
+        let mut ticker = tokio::time::interval(self.poll_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            self.deliver_due().await;
+        }
+    }
+
+    /// Returns the retry policy in effect.
+    #[must_use]
+    pub const fn retry_policy(&self) -> TurnCallbackRetryPolicy {
+        self.policy
+    }
+
+    /// Returns the number of notices waiting for delivery.
+    #[must_use]
+    pub fn pending_len(&self) -> usize {
+        self.lock_pending().len()
+    }
+
+    /// Returns the counters accumulated so far.
+    #[must_use]
+    pub fn metrics(&self) -> TurnCallbackMetrics {
+        *self.lock_metrics()
+    }
+
+    async fn ensure_writable(&self) -> TurnCallbackRepositoryResult<()> {
+        if let Some(gate) = self.maintenance.as_deref() {
+            gate.ensure_writable().await?;
+        }
+        Ok(())
+    }
+
+    /// Takes every callback registered for a conversation, a batch at a
+    /// time.
+    async fn take_all(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: Uuid,
+    ) -> TurnCallbackRepositoryResult<Vec<TurnCallback>> {
+        let batch_len = usize::try_from(Limit::MAX.get()).unwrap_or(usize::MAX);
+        let mut taken = Vec::new();
+        loop {
+            let batch = self
+                .callbacks
+                .take_for_conversation(ctx, conversation_id, Limit::MAX)
+                .await?;
+            let exhausted = batch.len() < batch_len;
+            taken.extend(batch);
+            if exhausted {
+                return Ok(taken);
+            }
+        }
+    }
+
+    async fn paused_for_maintenance(&self) -> bool {
+        let Some(gate) = self.maintenance.as_deref() else {
+            return false;
+        };
+        let paused = gate.is_active().await;
+        if paused {
+            tracing::debug!("turn callback delivery held during maintenance");
+        }
+        paused
+    }
+
+    /// Queues a failed notice for retry, or abandons it once the policy's
+    /// attempts are spent.
+    fn reschedule(&self, mut entry: PendingNotice, err: &TurnCallbackDeliveryError) {
+        entry.failed_attempts = entry.failed_attempts.saturating_add(1);
+        if entry.failed_attempts >= self.policy.max_attempts() {
+            tracing::warn!(
+                error = %err,
+                callback_id = %entry.notice.callback_id,
+                conversation_id = %entry.notice.conversation_id,
+                attempts = entry.failed_attempts,
+                "abandoning turn callback after final delivery attempt"
+            );
+            self.lock_metrics().notices_abandoned += 1;
+            return;
+        }
+        let backoff = TimeDelta::from_std(self.policy.backoff_after(entry.failed_attempts))
+            .unwrap_or(TimeDelta::MAX);
+        entry.due_at = self
+            .clock
+            .utc()
+            .checked_add_signed(backoff)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        tracing::debug!(
+            error = %err,
+            callback_id = %entry.notice.callback_id,
+            attempts = entry.failed_attempts,
+            "turn callback delivery failed; retrying"
+        );
+        self.lock_metrics().attempts_retried += 1;
+        self.lock_pending().push(entry);
+    }
+
+    fn lock_pending(&self) -> MutexGuard<'_, Vec<PendingNotice>> {
+        self.pending.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn lock_metrics(&self) -> MutexGuard<'_, TurnCallbackMetrics> {
+        self.metrics.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,123 @@
+//! Fakes and builders shared by the agent memory tests.
+
+use crate::agent_backend::{
+    adapters::memory::InMemoryAgentMemoryRepository,
+    domain::{BackendId, CompletedTurn, MemoryDecayPolicy, MemoryFactDraft},
+    ports::{MemoryEmbedder, MemoryFactExtractor, MemoryModelError},
+    services::{AgentMemoryPorts, AgentMemoryService},
+};
+use crate::context::RequestContext;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Local, Utc};
+use mockable::{Clock, DefaultClock};
+use std::sync::{Arc, Mutex, PoisonError};
+use uuid::Uuid;
+
+const VOCABULARY: [&str; 4] = ["rust", "postgres", "tabs", "deploy"];
+
+/// Embeds text as keyword counts over a small vocabulary.
+struct KeywordEmbedder;
+
+#[async_trait]
+impl MemoryEmbedder for KeywordEmbedder {
+    async fn embed(&self, _ctx: &RequestContext, text: &str) -> Result<Vec<f32>, MemoryModelError> {
+        let lowered = text.to_lowercase();
+        Ok(VOCABULARY
+            .iter()
+            .map(|word| if lowered.contains(word) { 1.0 } else { 0.0 })
+            .collect())
+    }
+}
+
+/// Proposes the same facts after every turn, or fails when empty-handed.
+#[derive(Default)]
+pub(super) struct ScriptedExtractor {
+    drafts: Mutex<Option<Vec<MemoryFactDraft>>>,
+    turns: Mutex<Vec<CompletedTurn>>,
+}
+
+impl ScriptedExtractor {
+    pub(super) fn proposing(drafts: Vec<MemoryFactDraft>) -> Self {
+        Self {
+            drafts: Mutex::new(Some(drafts)),
+            turns: Mutex::default(),
+        }
+    }
+
+    pub(super) fn turns(&self) -> Vec<CompletedTurn> {
+        self.turns
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+#[async_trait]
+impl MemoryFactExtractor for ScriptedExtractor {
+    async fn extract(
+        &self,
+        _ctx: &RequestContext,
+        turn: &CompletedTurn,
+    ) -> Result<Vec<MemoryFactDraft>, MemoryModelError> {
+        self.turns
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(turn.clone());
+        self.drafts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+            .ok_or_else(|| MemoryModelError("extractor offline".to_owned()))
+    }
+}
+
+/// Clock that only moves when told to.
+pub(super) struct ManualClock(Mutex<DateTime<Utc>>);
+
+impl ManualClock {
+    pub(super) fn new() -> Self {
+        Self(Mutex::new(DefaultClock.utc()))
+    }
+
+    pub(super) fn advance(&self, by: Duration) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn local(&self) -> DateTime<Local> {
+        self.utc().with_timezone(&Local)
+    }
+
+    fn utc(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+pub(super) fn draft(content: &str, confidence_percent: u8) -> MemoryFactDraft {
+    MemoryFactDraft::new(content, confidence_percent).expect("valid draft")
+}
+
+pub(super) fn memory(
+    extractor: Arc<ScriptedExtractor>,
+    clock: Arc<ManualClock>,
+) -> AgentMemoryService {
+    AgentMemoryService::new(
+        AgentMemoryPorts {
+            repository: Arc::new(InMemoryAgentMemoryRepository::new()),
+            embedder: Arc::new(KeywordEmbedder),
+            extractor,
+        },
+        clock,
+    )
+    .with_decay_policy(MemoryDecayPolicy::new(Duration::days(10), 20).expect("valid policy"))
+}
+
+pub(super) fn completed_turn(backend_id: BackendId) -> CompletedTurn {
+    CompletedTurn {
+        backend_id,
+        conversation_id: Uuid::new_v4(),
+        prompt: "Set up the project".to_owned(),
+        assistant_response: "Done".to_owned(),
+    }
+}
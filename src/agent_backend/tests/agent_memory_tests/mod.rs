@@ -0,0 +1,5 @@
+//! Unit tests for long-term agent memory.
+
+mod common;
+mod service_tests;
+mod turn_tests;
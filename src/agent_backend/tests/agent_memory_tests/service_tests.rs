@@ -0,0 +1,251 @@
+//! Tests for recalling, reinforcing, decaying and editing memory facts.
+
+use super::common::{ManualClock, ScriptedExtractor, completed_turn, draft, memory};
+use crate::agent_backend::{
+    domain::{BackendId, MemoryDecayPolicy, MemoryFact, MemoryRecallVerdict},
+    services::{AgentMemoryError, MemoryFactEdit},
+};
+use crate::test_support::test_request_ctx;
+use chrono::Duration;
+use mockable::Clock;
+use rstest::rstest;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[rstest]
+#[case::fresh(Duration::zero(), 80)]
+#[case::part_way(Duration::days(5), 60)]
+#[case::one_half_life(Duration::days(10), 40)]
+#[case::two_half_lives(Duration::days(20), 20)]
+#[case::long_gone(Duration::days(10_000), 0)]
+#[case::clock_skew(Duration::days(-1), 80)]
+fn confidence_halves_every_half_life(#[case] elapsed: Duration, #[case] expected: u8) {
+    let policy = MemoryDecayPolicy::new(Duration::days(10), 5).expect("valid policy");
+    assert_eq!(policy.decay(80, elapsed), expected);
+}
+
+#[rstest]
+#[tokio::test]
+async fn recall_ranks_a_backends_facts_by_relevance_and_confidence() {
+    let extractor = Arc::new(ScriptedExtractor::proposing(vec![
+        draft("The team deploys Rust services", 90),
+        draft("The team writes Rust with tabs", 60),
+        draft("The database is Postgres", 90),
+    ]));
+    let service = memory(extractor, Arc::new(ManualClock::new()));
+    let ctx = test_request_ctx();
+    let backend_id = BackendId::new();
+    service
+        .remember_turn(&ctx, &completed_turn(backend_id))
+        .await
+        .expect("facts remembered");
+
+    let recalled = service
+        .recall(&ctx, backend_id, "How should Rust code be formatted?")
+        .await
+        .expect("facts recalled");
+    let other_backend = service
+        .recall(&ctx, BackendId::new(), "Rust")
+        .await
+        .expect("facts recalled");
+    let other_tenant = service
+        .recall(&test_request_ctx(), backend_id, "Rust")
+        .await
+        .expect("facts recalled");
+
+    let ranked: Vec<_> = recalled
+        .iter()
+        .map(|memory| (memory.fact.content(), memory.relevance_percent))
+        .collect();
+    assert_eq!(
+        ranked,
+        vec![
+            ("The team deploys Rust services", 71),
+            ("The team writes Rust with tabs", 71),
+        ]
+    );
+    assert!(other_backend.is_empty());
+    assert!(other_tenant.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn explained_recall_gives_every_remembered_fact_a_verdict() {
+    let extractor = Arc::new(ScriptedExtractor::proposing(vec![
+        draft("The team deploys Rust services", 90),
+        draft("The team writes Rust with tabs", 60),
+        draft("The database is Postgres", 90),
+        draft("Rust builds are cached", 30),
+        draft("Rust nightly is pinned", 90).with_expiry(Duration::hours(1)),
+    ]));
+    let clock = Arc::new(ManualClock::new());
+    let service = memory(extractor, clock.clone()).with_recall_limit(1);
+    let ctx = test_request_ctx();
+    let backend_id = BackendId::new();
+    service
+        .remember_turn(&ctx, &completed_turn(backend_id))
+        .await
+        .expect("facts remembered");
+    clock.advance(Duration::days(10));
+
+    let recall = service
+        .recall_explained(&ctx, backend_id, "How should Rust code be formatted?")
+        .await
+        .expect("facts recalled");
+
+    let recalled: Vec<_> = recall
+        .recalled
+        .iter()
+        .map(|memory| memory.fact.content())
+        .collect();
+    assert_eq!(recalled, vec!["The team deploys Rust services"]);
+    let verdicts: HashMap<_, _> = recall
+        .decisions
+        .iter()
+        .map(|decision| {
+            (
+                decision.content.as_str(),
+                (
+                    decision.verdict,
+                    decision.relevance_percent,
+                    decision.confidence_percent,
+                ),
+            )
+        })
+        .collect();
+    assert_eq!(
+        verdicts,
+        HashMap::from([
+            (
+                "The team deploys Rust services",
+                (MemoryRecallVerdict::Included, Some(71), 45),
+            ),
+            (
+                "The team writes Rust with tabs",
+                (MemoryRecallVerdict::OverRecallLimit, Some(71), 30),
+            ),
+            (
+                "The database is Postgres",
+                (MemoryRecallVerdict::NotRelevant, Some(0), 45),
+            ),
+            (
+                "Rust builds are cached",
+                (MemoryRecallVerdict::Decayed, Some(100), 15),
+            ),
+            (
+                "Rust nightly is pinned",
+                (MemoryRecallVerdict::Expired, Some(100), 45),
+            ),
+        ])
+    );
+    let ranked: Vec<_> = recall
+        .decisions
+        .iter()
+        .take(2)
+        .map(|decision| decision.verdict)
+        .collect();
+    assert_eq!(
+        ranked,
+        vec![
+            MemoryRecallVerdict::Included,
+            MemoryRecallVerdict::OverRecallLimit
+        ]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn restated_facts_are_reinforced_and_decayed_facts_forgotten() {
+    let extractor = Arc::new(ScriptedExtractor::proposing(vec![draft(
+        "The team writes Rust with tabs",
+        50,
+    )]));
+    let clock = Arc::new(ManualClock::new());
+    let service = memory(extractor, clock.clone());
+    let ctx = test_request_ctx();
+    let backend_id = BackendId::new();
+    let turn = completed_turn(backend_id);
+    service
+        .remember_turn(&ctx, &turn)
+        .await
+        .expect("fact remembered");
+
+    clock.advance(Duration::days(10));
+    let reinforced = service
+        .remember_turn(&ctx, &turn)
+        .await
+        .expect("fact reinforced");
+    let facts = service.list(&ctx, backend_id).await.expect("facts listed");
+    assert_eq!(facts.len(), 1);
+    assert_eq!(reinforced, facts);
+    assert_eq!(facts.first().map(MemoryFact::confidence_percent), Some(50));
+
+    clock.advance(Duration::days(20));
+    assert!(
+        service
+            .list(&ctx, backend_id)
+            .await
+            .expect("facts listed")
+            .is_empty()
+    );
+    assert_eq!(
+        service
+            .purge_forgotten(&ctx, backend_id)
+            .await
+            .expect("facts purged"),
+        1
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn facts_can_be_edited_expired_and_forgotten() {
+    let extractor = Arc::new(ScriptedExtractor::proposing(vec![draft(
+        "The team writes Rust with tabs",
+        50,
+    )]));
+    let clock = Arc::new(ManualClock::new());
+    let service = memory(extractor, clock.clone());
+    let ctx = test_request_ctx();
+    let backend_id = BackendId::new();
+    let remembered = service
+        .remember_turn(&ctx, &completed_turn(backend_id))
+        .await
+        .expect("fact remembered");
+    let id = remembered.first().map(MemoryFact::id).expect("one fact");
+
+    let edited = service
+        .edit(
+            &ctx,
+            id,
+            MemoryFactEdit::default()
+                .with_content("The team deploys with Postgres migrations")
+                .with_confidence(95)
+                .with_expiry(Some(clock.utc() + Duration::hours(1))),
+        )
+        .await
+        .expect("fact edited");
+    assert_eq!(
+        edited.content(),
+        "The team deploys with Postgres migrations"
+    );
+    let recalled = service
+        .recall(&ctx, backend_id, "postgres")
+        .await
+        .expect("facts recalled");
+    assert_eq!(recalled.len(), 1);
+
+    clock.advance(Duration::hours(1));
+    assert!(
+        service
+            .recall(&ctx, backend_id, "postgres")
+            .await
+            .expect("facts recalled")
+            .is_empty()
+    );
+    service.forget(&ctx, id).await.expect("fact forgotten");
+    assert!(matches!(
+        service.forget(&ctx, id).await,
+        Err(AgentMemoryError::NotFound(missing)) if missing == id
+    ));
+}
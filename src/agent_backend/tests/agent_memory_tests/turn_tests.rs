@@ -0,0 +1,157 @@
+//! Tests for memory recall and extraction around orchestrated turns.
+
+use super::common::{ManualClock, ScriptedExtractor, draft, memory};
+use crate::agent_backend::{
+    adapters::memory::InMemoryContextAssemblyReportRepository,
+    domain::{TurnExecutionRequest, TurnExecutionResult},
+    ports::ContextAssemblyReportRepository,
+    services::ExecuteAgentTurnRequest,
+    tests::turn_orchestration_tests::common::{OrchestrationContext, context, register_backend},
+};
+use crate::pagination::PageRequest;
+use rstest::rstest;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn turns_recall_memories_and_remember_new_facts(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "claude_code_sdk").await?;
+    let extractor = Arc::new(ScriptedExtractor::proposing(vec![draft(
+        "The team writes Rust with tabs",
+        80,
+    )]));
+    let memory = Arc::new(memory(extractor.clone(), Arc::new(ManualClock::new())));
+    let service = context.service.clone().with_memory(memory);
+    for response in ["first", "second"] {
+        context
+            .runtime
+            .queue_turn_result(TurnExecutionResult::new(response, Vec::new()))?;
+    }
+
+    for _ in 0..2 {
+        let turn = TurnExecutionRequest::new(Uuid::new_v4(), "Format the Rust code", Vec::new());
+        service
+            .execute_turn(&context.ctx, ExecuteAgentTurnRequest::new(backend_id, turn))
+            .await?;
+    }
+
+    let prompts: Vec<_> = context
+        .runtime
+        .execution_records()?
+        .into_iter()
+        .map(|record| record.request.prompt().to_owned())
+        .collect();
+    assert_eq!(
+        prompts,
+        vec![
+            "Format the Rust code".to_owned(),
+            concat!(
+                "Facts remembered from earlier conversations:\n",
+                "- The team writes Rust with tabs\n",
+                "\n",
+                "Format the Rust code",
+            )
+            .to_owned(),
+        ]
+    );
+    let extracted: Vec<_> = extractor
+        .turns()
+        .into_iter()
+        .map(|turn| (turn.prompt, turn.assistant_response))
+        .collect();
+    assert_eq!(
+        extracted,
+        vec![
+            ("Format the Rust code".to_owned(), "first".to_owned()),
+            ("Format the Rust code".to_owned(), "second".to_owned()),
+        ]
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn turns_record_which_memories_were_recalled(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "claude_code_sdk").await?;
+    let extractor = Arc::new(ScriptedExtractor::proposing(vec![draft(
+        "The team writes Rust with tabs",
+        80,
+    )]));
+    let memory = Arc::new(memory(extractor, Arc::new(ManualClock::new())));
+    let reports = Arc::new(InMemoryContextAssemblyReportRepository::new());
+    let service = context
+        .service
+        .clone()
+        .with_memory(memory)
+        .with_context_reports(reports.clone());
+    let conversation_id = Uuid::new_v4();
+    for response in ["first", "second"] {
+        context
+            .runtime
+            .queue_turn_result(TurnExecutionResult::new(response, Vec::new()))?;
+        let turn = TurnExecutionRequest::new(conversation_id, "Format the Rust code", Vec::new());
+        service
+            .execute_turn(&context.ctx, ExecuteAgentTurnRequest::new(backend_id, turn))
+            .await?;
+    }
+
+    let recorded = reports
+        .list_for_conversation(&context.ctx, conversation_id, PageRequest::default())
+        .await?;
+    let included: Vec<Vec<_>> = recorded
+        .iter()
+        .map(|report| {
+            report
+                .included_memories()
+                .map(|decision| decision.content.clone())
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        included,
+        vec![
+            Vec::new(),
+            vec!["The team writes Rust with tabs".to_owned()]
+        ]
+    );
+    assert!(
+        recorded
+            .iter()
+            .all(|report| report.backend_id == backend_id)
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn memory_failures_do_not_fail_turns(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "claude_code_sdk").await?;
+    let memory = Arc::new(memory(
+        Arc::new(ScriptedExtractor::default()),
+        Arc::new(ManualClock::new()),
+    ));
+    let service = context.service.clone().with_memory(memory);
+    context
+        .runtime
+        .queue_turn_result(TurnExecutionResult::new("done", Vec::new()))?;
+
+    let response = service
+        .execute_turn(
+            &context.ctx,
+            ExecuteAgentTurnRequest::new(
+                backend_id,
+                TurnExecutionRequest::new(Uuid::new_v4(), "Plan the fix", Vec::new()),
+            ),
+        )
+        .await?;
+
+    assert_eq!(response.assistant_response(), "done");
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Unit tests for dataset recording policy and PII scrubbing.
+
+use crate::agent_backend::domain::{
+    DatasetRecordingPolicy, DatasetRecordingPolicyError, PiiScrubber,
+};
+use rstest::rstest;
+use serde_json::json;
+
+#[rstest]
+#[case::email("contact ada@example.com today", "contact [redacted-email] today")]
+#[case::punctuated_email("(ada@example.com),", "([redacted-email]),")]
+#[case::phone("call +44-20-7946-0958 now", "call [redacted-number] now")]
+#[case::short_number("retry 3 times in 2024", "retry 3 times in 2024")]
+#[case::handle("ping @ada on the issue", "ping @ada on the issue")]
+fn scrub_text_masks_personal_tokens(#[case] input: &str, #[case] expected: &str) {
+    assert_eq!(PiiScrubber::default().scrub_text(input), expected);
+}
+
+#[rstest]
+fn scrub_value_redacts_sensitive_keys_recursively() {
+    let scrubber = PiiScrubber::default().with_sensitive_key("Customer-Id");
+    let value = json!({
+        "headers": {"Authorization": "Bearer abc", "Accept": "text/plain"},
+        "customer_id": 42,
+        "notes": ["mail ada@example.com", 7],
+    });
+
+    assert_eq!(
+        scrubber.scrub_value(&value),
+        json!({
+            "headers": {"Authorization": "[redacted]", "Accept": "text/plain"},
+            "customer_id": "[redacted]",
+            "notes": ["mail [redacted-email]", 7],
+        })
+    );
+}
+
+#[rstest]
+#[case(0)]
+#[case(101)]
+fn sample_rate_outside_range_is_rejected(#[case] percent: u8) {
+    assert_eq!(
+        DatasetRecordingPolicy::new(percent),
+        Err(DatasetRecordingPolicyError::InvalidSampleRate(percent))
+    );
+}
+
+#[rstest]
+fn sampling_selects_the_configured_share_of_rolls() {
+    let policy = DatasetRecordingPolicy::new(30).expect("valid sample rate");
+
+    let sampled = (0..1_000_u128).filter(|roll| policy.samples(*roll)).count();
+
+    assert_eq!(sampled, 300);
+}
+
+#[rstest]
+fn prompt_context_is_truncated_before_scrubbing() {
+    let policy = DatasetRecordingPolicy::new(100)
+        .expect("valid sample rate")
+        .with_max_prompt_chars(12);
+
+    assert_eq!(
+        policy.prompt_context("Write to ada@example.com"),
+        "Write to ada"
+    );
+}
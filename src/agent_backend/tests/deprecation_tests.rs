@@ -0,0 +1,262 @@
+//! Unit tests for backend deprecation impact reports and re-pinning.
+
+use crate::agent_backend::{
+    adapters::memory::{InMemoryBackendRegistry, InMemoryExperimentRepository},
+    domain::{
+        AgentBackendRegistration, AgentCapabilities, BackendExperiment, BackendInfo, BackendName,
+        ExperimentArm,
+    },
+    ports::{BackendRegistryRepository, ExperimentRepository},
+    services::{
+        BackendDeprecationPorts, BackendDeprecationService, DeprecationServiceError, RepinRequest,
+    },
+};
+use crate::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+use crate::message::{
+    adapters::memory::{
+        InMemoryAgentSessionRepository, InMemoryMessageRepository, InMemorySlashCommandRegistry,
+    },
+    domain::{
+        AgentSession, AgentSessionState, ConversationId, SequenceNumber, SlashCommandDefinition,
+        TurnId,
+    },
+    ports::AgentSessionRepository,
+};
+use crate::pagination::PageRequest;
+use crate::task::{
+    adapters::memory::InMemoryTaskCostLedger,
+    domain::{CostMicros, TokenUsage, UsageRecord, UsageRecordParams},
+    ports::TaskCostLedger,
+};
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+const LEGACY: &str = "legacy_model";
+const SUCCESSOR: &str = "successor_model";
+
+#[fixture]
+fn ctx() -> RequestContext {
+    RequestContext::new(
+        TenantId::new(),
+        CorrelationId::new(),
+        UserId::new(),
+        SessionId::new(),
+    )
+}
+
+fn name(value: &str) -> BackendName {
+    BackendName::new(value).expect("valid backend name")
+}
+
+struct Harness {
+    service: BackendDeprecationService,
+    registry: Arc<InMemoryBackendRegistry>,
+    sessions: Arc<InMemoryAgentSessionRepository>,
+    experiments: Arc<InMemoryExperimentRepository>,
+    usage: Arc<InMemoryTaskCostLedger>,
+}
+
+fn harness() -> Harness {
+    let registry = Arc::new(InMemoryBackendRegistry::new());
+    let sessions = Arc::new(InMemoryAgentSessionRepository::new());
+    let experiments = Arc::new(InMemoryExperimentRepository::new());
+    let usage = Arc::new(InMemoryTaskCostLedger::new());
+    let slash_commands = InMemorySlashCommandRegistry::with_commands([
+        SlashCommandDefinition::new("legacy", "Ask the legacy model", "Route to legacy_model."),
+        SlashCommandDefinition::new("plan", "Plan the work", "Plan {{ goal }}."),
+    ])
+    .expect("valid commands");
+    let service = BackendDeprecationService::new(BackendDeprecationPorts {
+        backend_registry: registry.clone(),
+        sessions: sessions.clone(),
+        messages: Arc::new(InMemoryMessageRepository::new()),
+        slash_commands: Arc::new(slash_commands),
+        experiments: experiments.clone(),
+        usage: usage.clone(),
+        clock: Arc::new(DefaultClock),
+    });
+    Harness {
+        service,
+        registry,
+        sessions,
+        experiments,
+        usage,
+    }
+}
+
+async fn register(
+    harness: &Harness,
+    ctx: &RequestContext,
+    backend: &str,
+) -> AgentBackendRegistration {
+    let registration = AgentBackendRegistration::new(
+        name(backend),
+        AgentCapabilities::new(true, true),
+        BackendInfo::new(backend, "1.0.0", "Example").expect("valid info"),
+        &DefaultClock,
+    );
+    harness
+        .registry
+        .register(ctx, &registration)
+        .await
+        .expect("register backend");
+    registration
+}
+
+async fn pin(harness: &Harness, ctx: &RequestContext, backend: &str) -> ConversationId {
+    let conversation_id = ConversationId::new();
+    let session = AgentSession::new(
+        conversation_id,
+        backend,
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+    harness
+        .sessions
+        .store(ctx, &session)
+        .await
+        .expect("store session");
+    conversation_id
+}
+
+async fn record_usage(
+    harness: &Harness,
+    ctx: &RequestContext,
+    (conversation_id, backend, cost): (ConversationId, &str, u64),
+) {
+    let record = UsageRecord::new(
+        UsageRecordParams {
+            conversation_id,
+            turn_id: TurnId::new(),
+            agent_backend: backend.to_owned(),
+            usage: TokenUsage::new(1_000, 100),
+            cost: CostMicros::new(cost),
+        },
+        &DefaultClock,
+    );
+    harness
+        .usage
+        .record_usage(ctx, &record)
+        .await
+        .expect("record usage");
+}
+
+#[rstest]
+#[tokio::test]
+async fn impact_report_lists_everything_that_depends_on_the_backend(ctx: RequestContext) {
+    let harness = harness();
+    let legacy = register(&harness, &ctx, LEGACY).await;
+    let successor = register(&harness, &ctx, SUCCESSOR).await;
+    let pinned = pin(&harness, &ctx, LEGACY).await;
+    let elsewhere = pin(&harness, &ctx, SUCCESSOR).await;
+    record_usage(&harness, &ctx, (pinned, LEGACY, 40_000)).await;
+    record_usage(&harness, &ctx, (pinned, LEGACY, 60_000)).await;
+    record_usage(&harness, &ctx, (elsewhere, SUCCESSOR, 999_000)).await;
+    let experiment = BackendExperiment::new(
+        "legacy-vs-successor",
+        ExperimentArm::new(legacy.id()),
+        ExperimentArm::new(successor.id()),
+        &DefaultClock,
+    )
+    .expect("valid experiment");
+    harness
+        .experiments
+        .create(&ctx, &experiment)
+        .await
+        .expect("create experiment");
+
+    let report = harness
+        .service
+        .impact_report(&ctx, &name(LEGACY))
+        .await
+        .expect("impact report");
+
+    assert_eq!(report.pinned_conversations, vec![pinned]);
+    assert_eq!(report.templates, vec!["legacy".to_owned()]);
+    assert_eq!(report.experiments, vec![experiment.id()]);
+    assert_eq!(report.projected_migration_cost.turn_count, 2);
+    assert_eq!(
+        report.projected_migration_cost.cost,
+        CostMicros::new(100_000)
+    );
+    assert!(!report.is_clear());
+}
+
+#[rstest]
+#[tokio::test]
+async fn repin_moves_pinned_conversations_to_the_successor(ctx: RequestContext) {
+    let harness = harness();
+    register(&harness, &ctx, LEGACY).await;
+    register(&harness, &ctx, SUCCESSOR).await;
+    let pinned = pin(&harness, &ctx, LEGACY).await;
+
+    let outcome = harness
+        .service
+        .repin(&ctx, RepinRequest::new(name(LEGACY), name(SUCCESSOR)))
+        .await
+        .expect("repin");
+    let history = harness
+        .sessions
+        .find_by_conversation(&ctx, pinned, PageRequest::default())
+        .await
+        .expect("sessions");
+    let remaining = harness
+        .service
+        .impact_report(&ctx, &name(LEGACY))
+        .await
+        .expect("impact report");
+
+    assert_eq!(outcome.repinned, vec![pinned]);
+    assert_eq!(
+        history
+            .iter()
+            .map(|session| (session.agent_backend.as_str(), session.state))
+            .collect::<Vec<_>>(),
+        vec![
+            (LEGACY, AgentSessionState::Completed),
+            (SUCCESSOR, AgentSessionState::Active),
+        ]
+    );
+    assert!(remaining.pinned_conversations.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn repin_requires_a_distinct_active_successor(ctx: RequestContext) {
+    let harness = harness();
+    register(&harness, &ctx, LEGACY).await;
+    let mut successor = register(&harness, &ctx, SUCCESSOR).await;
+    successor.deactivate(&DefaultClock);
+    harness
+        .registry
+        .update(&ctx, &successor)
+        .await
+        .expect("deactivate successor");
+
+    let inactive = harness
+        .service
+        .repin(&ctx, RepinRequest::new(name(LEGACY), name(SUCCESSOR)))
+        .await;
+    let itself = harness
+        .service
+        .repin(&ctx, RepinRequest::new(name(LEGACY), name(LEGACY)))
+        .await;
+    let unknown = harness
+        .service
+        .impact_report(&ctx, &name("unknown_model"))
+        .await;
+
+    assert!(matches!(
+        inactive,
+        Err(DeprecationServiceError::SuccessorInactive(backend)) if backend.as_str() == SUCCESSOR
+    ));
+    assert!(matches!(
+        itself,
+        Err(DeprecationServiceError::SelfSuccession(_))
+    ));
+    assert!(matches!(
+        unknown,
+        Err(DeprecationServiceError::BackendNotFound(_))
+    ));
+}
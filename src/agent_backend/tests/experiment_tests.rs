@@ -0,0 +1,178 @@
+//! Unit tests for backend experiment assignment and reporting.
+
+use crate::agent_backend::domain::{
+    BackendExperiment, BackendId, ExperimentArm, ExperimentArmKey, ExperimentDomainError,
+    ExperimentId, ExperimentObservation, ExperimentReport, ExperimentSignal, HumanFeedback,
+};
+use crate::message::domain::FeedbackSentiment;
+use chrono::Utc;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::time::Duration;
+use uuid::Uuid;
+
+fn experiment() -> BackendExperiment {
+    BackendExperiment::new(
+        "codex-vs-sdk",
+        ExperimentArm::new(BackendId::new()),
+        ExperimentArm::new(BackendId::new()),
+        &DefaultClock,
+    )
+    .expect("valid experiment")
+}
+
+fn observation(
+    experiment_id: ExperimentId,
+    arm: ExperimentArmKey,
+    conversation_id: Uuid,
+    signal: ExperimentSignal,
+) -> ExperimentObservation {
+    ExperimentObservation {
+        experiment_id,
+        arm,
+        conversation_id,
+        signal,
+        observed_at: Utc::now(),
+    }
+}
+
+const fn turn(succeeded: bool, latency_ms: u64) -> ExperimentSignal {
+    ExperimentSignal::Turn {
+        succeeded,
+        latency: Duration::from_millis(latency_ms),
+    }
+}
+
+#[rstest]
+fn identical_arms_are_rejected() {
+    let arm = ExperimentArm::new(BackendId::new());
+
+    let result = BackendExperiment::new("noop", arm.clone(), arm, &DefaultClock);
+
+    assert_eq!(result, Err(ExperimentDomainError::IdenticalArms));
+}
+
+#[rstest]
+fn prompt_variant_distinguishes_arms_on_one_backend() {
+    let backend_id = BackendId::new();
+    let treatment = ExperimentArm::new(backend_id).with_prompt_variant("Be terse.");
+
+    let result = BackendExperiment::new(
+        "terse-prompt",
+        ExperimentArm::new(backend_id),
+        treatment.clone(),
+        &DefaultClock,
+    );
+
+    assert!(result.is_ok());
+    assert_eq!(
+        treatment.apply_prompt("Plan the fix"),
+        "Be terse.\n\nPlan the fix"
+    );
+}
+
+#[rstest]
+#[case(0)]
+#[case(100)]
+fn treatment_share_outside_range_is_rejected(#[case] percent: u8) {
+    assert_eq!(
+        experiment().with_treatment_percent(percent),
+        Err(ExperimentDomainError::InvalidTreatmentPercent(percent))
+    );
+}
+
+#[rstest]
+fn assignment_is_stable_and_follows_the_treatment_share() {
+    let subject = experiment()
+        .with_treatment_percent(20)
+        .expect("valid treatment share");
+    let conversations = (0..2_000).map(|_| Uuid::new_v4()).collect::<Vec<_>>();
+
+    let treated = conversations
+        .iter()
+        .filter(|id| subject.assign(**id) == ExperimentArmKey::Treatment)
+        .count();
+
+    assert!(
+        conversations
+            .iter()
+            .all(|id| subject.assign(*id) == subject.assign(*id))
+    );
+    assert!((300..500).contains(&treated), "treated {treated} of 2000");
+}
+
+#[rstest]
+fn concluding_twice_is_rejected() {
+    let mut subject = experiment();
+    subject.conclude(&DefaultClock).expect("first conclusion");
+
+    assert_eq!(
+        subject.conclude(&DefaultClock),
+        Err(ExperimentDomainError::AlreadyConcluded(subject.id()))
+    );
+}
+
+#[rstest]
+fn report_aggregates_signals_per_arm() {
+    let id = ExperimentId::new();
+    let first = Uuid::new_v4();
+    let second = Uuid::new_v4();
+    let mut observations = (1..=20)
+        .map(|latency| {
+            observation(
+                id,
+                ExperimentArmKey::Control,
+                first,
+                turn(latency != 20, latency * 10),
+            )
+        })
+        .collect::<Vec<_>>();
+    observations.extend([
+        observation(
+            id,
+            ExperimentArmKey::Control,
+            second,
+            ExperimentSignal::Cost { cost_micros: 900 },
+        ),
+        observation(
+            id,
+            ExperimentArmKey::Control,
+            first,
+            ExperimentSignal::Cost { cost_micros: 300 },
+        ),
+        observation(
+            id,
+            ExperimentArmKey::Treatment,
+            Uuid::new_v4(),
+            ExperimentSignal::Feedback {
+                feedback: HumanFeedback::Negative,
+            },
+        ),
+    ]);
+
+    let report = ExperimentReport::from_observations(id, &observations);
+
+    assert_eq!(report.control.conversations, 2);
+    assert_eq!(report.control.turns, 20);
+    assert_eq!(report.control.success_rate_bps(), Some(9_500));
+    assert_eq!(
+        report.control.median_latency,
+        Some(Duration::from_millis(100))
+    );
+    assert_eq!(report.control.p95_latency, Some(Duration::from_millis(190)));
+    assert_eq!(report.control.cost_per_conversation_micros(), Some(600));
+    assert_eq!(report.treatment.turns, 0);
+    assert_eq!(report.treatment.success_rate_bps(), None);
+    assert_eq!(report.treatment.negative_feedback, 1);
+}
+
+#[rstest]
+#[case(FeedbackSentiment::Positive, Some(HumanFeedback::Positive))]
+#[case(FeedbackSentiment::Neutral, None)]
+#[case(FeedbackSentiment::Negative, Some(HumanFeedback::Negative))]
+fn message_rating_sentiment_maps_to_experiment_feedback(
+    #[case] sentiment: FeedbackSentiment,
+    #[case] expected: Option<HumanFeedback>,
+) {
+    assert_eq!(HumanFeedback::from_sentiment(sentiment), expected);
+}
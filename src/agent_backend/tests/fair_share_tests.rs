@@ -0,0 +1,192 @@
+//! Unit tests for weighted fair sharing of provider capacity.
+
+use crate::agent_backend::{
+    domain::{FairShareBuckets, FairSharePolicy},
+    services::{FairShareScheduler, FairShareWaitExceeded},
+};
+use crate::context::TenantId;
+use rstest::rstest;
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+fn non_zero(value: u32) -> NonZeroU32 {
+    NonZeroU32::new(value).expect("non-zero test value")
+}
+
+/// Drains `tenant_id`'s bucket, returning how many tokens it held.
+fn drain(buckets: &mut FairShareBuckets, tenant_id: TenantId, now: Duration) -> u32 {
+    let mut taken = 0;
+    while buckets.try_acquire(tenant_id, now).is_ok() {
+        taken += 1;
+    }
+    taken
+}
+
+/// Counts how many tokens each tenant takes when all of them ask for one
+/// every millisecond over `window`, starting at `start`.
+fn admitted_over(
+    buckets: &mut FairShareBuckets,
+    tenants: &[TenantId],
+    start: Duration,
+    window: Duration,
+) -> Vec<u32> {
+    let mut admitted = vec![0; tenants.len()];
+    let mut now = start;
+    while now < start + window {
+        now += Duration::from_millis(1);
+        for (tenant_id, count) in tenants.iter().zip(admitted.iter_mut()) {
+            if buckets.try_acquire(*tenant_id, now).is_ok() {
+                *count += 1;
+            }
+        }
+    }
+    admitted
+}
+
+#[rstest]
+fn new_tenants_start_with_a_full_burst() {
+    let policy = FairSharePolicy::new(non_zero(10)).with_burst(non_zero(3));
+    let mut buckets = FairShareBuckets::new(policy);
+
+    assert_eq!(drain(&mut buckets, TenantId::new(), Duration::ZERO), 3);
+}
+
+#[rstest]
+fn a_lone_tenant_refills_at_the_full_provider_rate() {
+    let policy = FairSharePolicy::new(non_zero(10)).with_burst(non_zero(1));
+    let mut buckets = FairShareBuckets::new(policy);
+    let tenant_id = TenantId::new();
+    drain(&mut buckets, tenant_id, Duration::ZERO);
+
+    let wait = buckets
+        .try_acquire(tenant_id, Duration::ZERO)
+        .expect_err("bucket is empty");
+
+    assert_eq!(wait, Duration::from_millis(100));
+    assert!(buckets.try_acquire(tenant_id, wait).is_ok());
+}
+
+#[rstest]
+fn contending_tenants_share_the_rate_by_weight() {
+    let heavy = TenantId::new();
+    let light = TenantId::new();
+    let policy = FairSharePolicy::new(non_zero(50))
+        .with_burst(non_zero(1))
+        .with_tenant_weight(heavy, non_zero(4));
+    let mut buckets = FairShareBuckets::new(policy);
+    drain(&mut buckets, heavy, Duration::ZERO);
+    drain(&mut buckets, light, Duration::ZERO);
+
+    let admitted = admitted_over(
+        &mut buckets,
+        &[heavy, light],
+        Duration::ZERO,
+        Duration::from_secs(1),
+    );
+
+    assert_eq!(admitted, vec![40, 10]);
+}
+
+#[rstest]
+fn a_noisy_tenant_cannot_starve_a_quiet_one() {
+    let noisy = TenantId::new();
+    let quiet = TenantId::new();
+    let policy = FairSharePolicy::new(non_zero(20)).with_burst(non_zero(1));
+    let mut buckets = FairShareBuckets::new(policy);
+    drain(&mut buckets, noisy, Duration::ZERO);
+    drain(&mut buckets, quiet, Duration::ZERO);
+
+    let wait = buckets
+        .try_acquire(quiet, Duration::ZERO)
+        .expect_err("quiet tenant's bucket is empty");
+
+    // Each of the two contending tenants refills at half the provider rate.
+    assert_eq!(wait, Duration::from_millis(100));
+    assert!(buckets.try_acquire(noisy, wait).is_ok());
+    assert!(buckets.try_acquire(quiet, wait).is_ok());
+}
+
+#[rstest]
+fn idle_tenants_give_up_their_share() {
+    let busy = TenantId::new();
+    let idle = TenantId::new();
+    let policy = FairSharePolicy::new(non_zero(20)).with_burst(non_zero(1));
+    let mut buckets = FairShareBuckets::new(policy);
+    assert!(buckets.try_acquire(idle, Duration::ZERO).is_ok());
+    // The idle tenant's bucket refills completely and stops contending.
+    assert!(buckets.try_acquire(busy, Duration::from_secs(1)).is_ok());
+
+    let wait = buckets
+        .try_acquire(busy, Duration::from_secs(1))
+        .expect_err("busy tenant's bucket is empty");
+
+    assert_eq!(wait, Duration::from_millis(50));
+}
+
+#[rstest]
+#[tokio::test]
+async fn scheduler_waits_for_the_next_token() -> Result<(), FairShareWaitExceeded> {
+    let policy = FairSharePolicy::new(non_zero(50)).with_burst(non_zero(1));
+    let scheduler = FairShareScheduler::new(policy);
+    let tenant_id = TenantId::new();
+
+    let first = scheduler.acquire("provider", tenant_id).await?;
+    let second = scheduler.acquire("provider", tenant_id).await?;
+
+    assert_eq!(first, Duration::ZERO);
+    assert!(second >= Duration::from_millis(15), "waited {second:?}");
+    let metrics = scheduler.metrics();
+    let tenant = metrics.first().expect("tenant metrics");
+    assert_eq!(tenant.admitted, 2);
+    assert_eq!(tenant.rejected, 0);
+    assert_eq!(tenant.longest_wait, second);
+    assert_eq!(Some(tenant.mean_wait()), second.checked_div(2));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn scheduler_rejects_invocations_that_would_wait_too_long() {
+    let policy = FairSharePolicy::new(non_zero(1))
+        .with_burst(non_zero(1))
+        .with_max_wait(Duration::from_millis(10));
+    let scheduler = FairShareScheduler::new(policy);
+    let tenant_id = TenantId::new();
+    scheduler
+        .acquire("provider", tenant_id)
+        .await
+        .expect("first invocation uses the burst");
+
+    let result = scheduler.acquire("provider", tenant_id).await;
+
+    assert!(matches!(
+        result,
+        Err(FairShareWaitExceeded { ref provider, tenant_id: rejected, .. })
+            if provider == "provider" && rejected == tenant_id
+    ));
+    let metrics = scheduler.metrics();
+    let tenant = metrics.first().expect("tenant metrics");
+    assert_eq!((tenant.admitted, tenant.rejected), (1, 1));
+}
+
+#[rstest]
+#[tokio::test]
+async fn providers_are_scheduled_independently() {
+    let policy = FairSharePolicy::new(non_zero(1))
+        .with_burst(non_zero(1))
+        .with_max_wait(Duration::ZERO);
+    let scheduler = FairShareScheduler::new(policy);
+    let tenant_id = TenantId::new();
+
+    let first = scheduler.acquire("anthropic", tenant_id).await;
+    let second = scheduler.acquire("openai", tenant_id).await;
+
+    assert!(first.is_ok());
+    assert!(second.is_ok());
+    let providers: Vec<String> = scheduler
+        .metrics()
+        .into_iter()
+        .map(|tenant| tenant.provider)
+        .collect();
+    assert_eq!(providers, vec!["anthropic", "openai"]);
+}
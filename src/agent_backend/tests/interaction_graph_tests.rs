@@ -0,0 +1,352 @@
+//! Unit tests for interaction graph exports.
+
+use crate::agent_backend::{
+    domain::{
+        GraphFormat, InteractionEdge, InteractionEdgeKind, InteractionGraph, InteractionNode,
+        InteractionNodeKind,
+    },
+    services::{InteractionGraphPorts, InteractionGraphService},
+};
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{
+        InMemoryAgentSessionRepository, InMemoryHandoffAdapter, InMemoryMessageRepository,
+    },
+    domain::{
+        AgentSession, ContentPart, ConversationId, HandoffSessionParams, Message, Role,
+        SequenceNumber, ToolCallPart, ToolResultPart, TurnId,
+    },
+    ports::{
+        AgentHandoffPort, AgentSessionRepository, MessageRepository, handoff::InitiateHandoffParams,
+    },
+};
+use crate::task::{
+    adapters::memory::InMemoryUsageBudgetRepository,
+    domain::{
+        BackendUsage, BudgetDelegation, BudgetShare, ConversationBudget, CostMicros, UsageAllowance,
+    },
+    ports::UsageBudgetRepository,
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use serde_json::json;
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+struct Harness {
+    service: InteractionGraphService,
+    sessions: Arc<InMemoryAgentSessionRepository>,
+    handoffs: Arc<InMemoryHandoffAdapter<DefaultClock>>,
+    messages: Arc<InMemoryMessageRepository>,
+    budgets: Arc<InMemoryUsageBudgetRepository>,
+}
+
+fn harness() -> Harness {
+    let sessions = Arc::new(InMemoryAgentSessionRepository::new());
+    let handoffs = Arc::new(InMemoryHandoffAdapter::new(DefaultClock));
+    let messages = Arc::new(InMemoryMessageRepository::new());
+    let budgets = Arc::new(InMemoryUsageBudgetRepository::new());
+    let service = InteractionGraphService::new(InteractionGraphPorts {
+        sessions: sessions.clone(),
+        handoffs: handoffs.clone(),
+        messages: messages.clone(),
+        budgets: budgets.clone(),
+    });
+    Harness {
+        service,
+        sessions,
+        handoffs,
+        messages,
+        budgets,
+    }
+}
+
+async fn store_message(
+    harness: &Harness,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+    (sequence, role, content): (u64, Role, Vec<ContentPart>),
+) {
+    let message = Message::new(
+        conversation_id,
+        role,
+        content,
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )
+    .expect("valid message");
+    harness
+        .messages
+        .store(ctx, &message)
+        .await
+        .expect("message stored");
+}
+
+fn edge_summary(graph: &InteractionGraph) -> Vec<(InteractionEdgeKind, String, String, String)> {
+    graph
+        .edges
+        .iter()
+        .map(|edge| {
+            (
+                edge.kind,
+                edge.from.clone(),
+                edge.to.clone(),
+                edge.label.clone(),
+            )
+        })
+        .collect()
+}
+
+fn node_id(session: &AgentSession) -> String {
+    InteractionNode::session(session).id
+}
+
+#[rstest]
+#[tokio::test]
+async fn graph_links_sessions_handoffs_delegations_and_tools(ctx: RequestContext) {
+    let harness = harness();
+    let conversation_id = ConversationId::new();
+    let mut planner = AgentSession::new(
+        conversation_id,
+        "planner",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )
+    .with_turn(TurnId::new());
+    let handoff = harness
+        .handoffs
+        .initiate_handoff(
+            &ctx,
+            InitiateHandoffParams::new(conversation_id, &planner, "coder", TurnId::new())
+                .with_reason("needs code"),
+        )
+        .await
+        .expect("handoff initiated");
+    planner.handoff(SequenceNumber::new(3), handoff.handoff_id, &DefaultClock);
+    let coder = AgentSession::from_handoff(
+        HandoffSessionParams::new(
+            conversation_id,
+            "coder",
+            SequenceNumber::new(4),
+            handoff.handoff_id,
+        ),
+        &DefaultClock,
+    );
+    harness
+        .handoffs
+        .complete_handoff(&ctx, handoff.handoff_id, coder.session_id)
+        .await
+        .expect("handoff completed");
+    for session in [&planner, &coder] {
+        harness
+            .sessions
+            .store(&ctx, session)
+            .await
+            .expect("session stored");
+    }
+    for message in [
+        (
+            2,
+            Role::Assistant,
+            vec![
+                ContentPart::ToolCall(ToolCallPart::new("c1", "read_file", json!({}))),
+                ContentPart::ToolCall(ToolCallPart::new("c2", "read_file", json!({}))),
+            ],
+        ),
+        (
+            3,
+            Role::Tool,
+            vec![
+                ContentPart::ToolResult(ToolResultPart::success("c1", json!("ok"))),
+                ContentPart::ToolResult(ToolResultPart::failure("c2", "missing")),
+            ],
+        ),
+        (
+            5,
+            Role::Assistant,
+            vec![ContentPart::ToolCall(ToolCallPart::new(
+                "c3",
+                "read_file",
+                json!({}),
+            ))],
+        ),
+    ] {
+        store_message(&harness, &ctx, conversation_id, message).await;
+    }
+    let child = ConversationId::new();
+    let mut parent_budget = ConversationBudget::new(
+        conversation_id,
+        UsageAllowance::new(10_000, CostMicros::new(1_000_000)),
+        &DefaultClock,
+    );
+    let child_budget = parent_budget
+        .delegate(
+            &BackendUsage::default(),
+            BudgetDelegation {
+                conversation_id: child,
+                share: BudgetShare::percent(50).expect("valid share"),
+            },
+            &DefaultClock,
+        )
+        .expect("delegated");
+    harness
+        .budgets
+        .save(&ctx, &[parent_budget, child_budget])
+        .await
+        .expect("budgets saved");
+
+    let graph = harness
+        .service
+        .graph(&ctx, conversation_id)
+        .await
+        .expect("graph built");
+
+    let root = InteractionNode::conversation(conversation_id).id;
+    let tool = InteractionNode::tool("read_file").id;
+    assert_eq!(
+        edge_summary(&graph),
+        vec![
+            (
+                InteractionEdgeKind::Started,
+                root,
+                node_id(&planner),
+                "started".to_owned()
+            ),
+            (
+                InteractionEdgeKind::Handoff,
+                node_id(&planner),
+                node_id(&coder),
+                "handoff: needs code".to_owned()
+            ),
+            (
+                InteractionEdgeKind::Delegation,
+                node_id(&coder),
+                InteractionNode::conversation(child).id,
+                "delegated".to_owned()
+            ),
+            (
+                InteractionEdgeKind::ToolUse,
+                node_id(&planner),
+                tool.clone(),
+                "2 calls, 1 failed".to_owned()
+            ),
+            (
+                InteractionEdgeKind::ToolUse,
+                node_id(&coder),
+                tool,
+                "1 call".to_owned()
+            ),
+        ]
+    );
+    assert_eq!(
+        graph
+            .nodes
+            .iter()
+            .filter(|node| node.kind == InteractionNodeKind::Tool)
+            .count(),
+        1
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn unfinished_handoffs_point_at_their_target_agent(ctx: RequestContext) {
+    let harness = harness();
+    let conversation_id = ConversationId::new();
+    let session = AgentSession::new(
+        conversation_id,
+        "planner",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+    harness
+        .sessions
+        .store(&ctx, &session)
+        .await
+        .expect("session stored");
+    harness
+        .handoffs
+        .initiate_handoff(
+            &ctx,
+            InitiateHandoffParams::new(conversation_id, &session, "reviewer", TurnId::new()),
+        )
+        .await
+        .expect("handoff initiated");
+
+    let mermaid = harness
+        .service
+        .export(&ctx, conversation_id, GraphFormat::Mermaid)
+        .await
+        .expect("graph exported");
+
+    assert!(mermaid.starts_with("flowchart LR"));
+    assert!(mermaid.contains(">\"reviewer<br/>handoff initiated\"]"));
+    assert!(mermaid.contains(&format!("{} ==>|\"handoff\"| handoff_", node_id(&session))));
+}
+
+fn fixed_graph() -> InteractionGraph {
+    InteractionGraph {
+        conversation_id: ConversationId::new(),
+        nodes: vec![
+            InteractionNode {
+                id: "conversation_a".to_owned(),
+                label: "conversation\na".to_owned(),
+                kind: InteractionNodeKind::Conversation,
+            },
+            InteractionNode {
+                id: "session_b".to_owned(),
+                label: "say \"hi\"".to_owned(),
+                kind: InteractionNodeKind::Session,
+            },
+            InteractionNode::tool("fs.read"),
+        ],
+        edges: vec![
+            InteractionEdge::new(
+                "conversation_a",
+                "session_b",
+                "started",
+                InteractionEdgeKind::Started,
+            ),
+            InteractionEdge::new(
+                "session_b",
+                "tool_fs_2eread",
+                "1 call",
+                InteractionEdgeKind::ToolUse,
+            ),
+        ],
+    }
+}
+
+#[rstest]
+#[case::dot(
+    GraphFormat::Dot,
+    concat!(
+        "digraph interactions {\n",
+        "  rankdir=LR;\n",
+        "  conversation_a [label=\"conversation\\na\", shape=folder];\n",
+        "  session_b [label=\"say \\\"hi\\\"\", shape=box];\n",
+        "  tool_fs_2eread [label=\"fs.read\", shape=ellipse];\n",
+        "  conversation_a -> session_b [label=\"started\", style=solid];\n",
+        "  session_b -> tool_fs_2eread [label=\"1 call\", style=dotted];\n",
+        "}",
+    )
+)]
+#[case::mermaid(
+    GraphFormat::Mermaid,
+    concat!(
+        "flowchart LR\n",
+        "  conversation_a{{\"conversation<br/>a\"}}\n",
+        "  session_b[\"say #quot;hi#quot;\"]\n",
+        "  tool_fs_2eread([\"fs.read\"])\n",
+        "  conversation_a -->|\"started\"| session_b\n",
+        "  session_b -.->|\"1 call\"| tool_fs_2eread",
+    )
+)]
+fn graphs_render_with_escaped_labels(#[case] format: GraphFormat, #[case] expected: &str) {
+    assert_eq!(fixed_graph().render(format), expected);
+}
@@ -1,5 +1,12 @@
 //! Unit tests for agent backend orchestration domain and service logic.
 
+mod agent_memory_tests;
+mod dataset_tests;
+mod deprecation_tests;
 mod domain_tests;
+mod experiment_tests;
+mod fair_share_tests;
+mod interaction_graph_tests;
 mod service_tests;
+mod turn_callback_tests;
 mod turn_orchestration_tests;
@@ -9,6 +9,7 @@ use crate::agent_backend::{
     services::{BackendRegistryService, BackendRegistryServiceError, RegisterBackendRequest},
 };
 use crate::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+use crate::pagination::PageRequest;
 use mockable::DefaultClock;
 use rstest::{fixture, rstest};
 
@@ -135,7 +136,7 @@ async fn list_active_excludes_inactive(service: TestService, ctx: RequestContext
         .expect("setup should succeed");
 
     let active = service
-        .list_active(&ctx)
+        .list_active(&ctx, PageRequest::default())
         .await
         .expect("listing should succeed");
 
@@ -154,7 +155,7 @@ async fn list_all_includes_inactive(service: TestService, ctx: RequestContext) {
         .expect("setup should succeed");
 
     let all = service
-        .list_all(&ctx)
+        .list_all(&ctx, PageRequest::default())
         .await
         .expect("listing should succeed");
 
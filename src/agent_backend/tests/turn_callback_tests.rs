@@ -0,0 +1,301 @@
+//! Unit tests for one-shot turn completion callbacks.
+
+use crate::agent_backend::{
+    adapters::memory::{InMemoryTurnCallbackRepository, InMemoryTurnCallbackSender},
+    domain::{
+        BackendId, TurnCallbackDomainError, TurnCallbackRetryPolicy, TurnCallbackTarget,
+        TurnExecutionRequest, TurnOutcome, TurnOutcomeRecord,
+    },
+    ports::TurnCallbackRepositoryError,
+    services::{ExecuteAgentTurnRequest, TurnCallbackMetrics, TurnCallbackService},
+    tests::turn_orchestration_tests::common::{OrchestrationContext, context, register_backend},
+};
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::operator::domain::OperatorReason;
+use crate::test_support::test_request_ctx;
+use chrono::{DateTime, Duration, Local, Utc};
+use mockable::{Clock, DefaultClock};
+use rstest::rstest;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration as StdDuration;
+use uuid::Uuid;
+
+/// Clock that only moves when told to.
+struct ManualClock(Mutex<DateTime<Utc>>);
+
+impl ManualClock {
+    fn new() -> Self {
+        Self(Mutex::new(DefaultClock.utc()))
+    }
+
+    fn advance(&self, by: Duration) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn local(&self) -> DateTime<Local> {
+        self.utc().with_timezone(&Local)
+    }
+
+    fn utc(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+struct CallbackFixture {
+    repository: Arc<InMemoryTurnCallbackRepository>,
+    sender: Arc<InMemoryTurnCallbackSender>,
+    clock: Arc<ManualClock>,
+    service: TurnCallbackService,
+}
+
+fn fixture(max_attempts: u32) -> CallbackFixture {
+    let repository = Arc::new(InMemoryTurnCallbackRepository::new());
+    let sender = Arc::new(InMemoryTurnCallbackSender::new());
+    let clock = Arc::new(ManualClock::new());
+    let policy = TurnCallbackRetryPolicy::new(max_attempts)
+        .expect("valid policy")
+        .with_backoff(StdDuration::from_secs(10), StdDuration::from_secs(15));
+    let service = TurnCallbackService::new(
+        Arc::clone(&repository) as _,
+        Arc::clone(&sender) as _,
+        Arc::clone(&clock) as _,
+    )
+    .with_retry_policy(policy);
+    CallbackFixture {
+        repository,
+        sender,
+        clock,
+        service,
+    }
+}
+
+fn webhook() -> TurnCallbackTarget {
+    TurnCallbackTarget::url("https://client.example/turns").expect("valid URL")
+}
+
+fn completed(conversation_id: Uuid, recorded_at: DateTime<Utc>) -> TurnOutcomeRecord {
+    TurnOutcomeRecord {
+        backend_id: BackendId::new(),
+        conversation_id,
+        session_id: None,
+        outcome: TurnOutcome::Completed,
+        recorded_at,
+    }
+}
+
+#[rstest]
+#[case::https(TurnCallbackTarget::url("https://client.example/turns"), true)]
+#[case::http(TurnCallbackTarget::url("http://localhost:8080/hook"), true)]
+#[case::no_scheme(TurnCallbackTarget::url("client.example/turns"), false)]
+#[case::no_host(TurnCallbackTarget::url("https://"), false)]
+#[case::whitespace(TurnCallbackTarget::url("https://client.example/a b"), false)]
+#[case::subject(TurnCallbackTarget::reply_subject("_INBOX.turns.42"), true)]
+#[case::empty_subject(TurnCallbackTarget::reply_subject(""), false)]
+#[case::spaced_subject(TurnCallbackTarget::reply_subject("turns reply"), false)]
+fn callback_targets_are_validated(
+    #[case] target: Result<TurnCallbackTarget, TurnCallbackDomainError>,
+    #[case] valid: bool,
+) {
+    assert_eq!(target.is_ok(), valid);
+    if let Ok(parsed) = target {
+        assert_eq!(
+            TurnCallbackTarget::from_parts(parsed.kind(), parsed.address()),
+            Ok(parsed)
+        );
+    }
+}
+
+#[rstest]
+fn retry_backoff_doubles_up_to_the_cap() {
+    let policy = TurnCallbackRetryPolicy::new(6)
+        .expect("valid policy")
+        .with_backoff(StdDuration::from_secs(2), StdDuration::from_secs(10));
+
+    let delays: Vec<_> = (1..=5)
+        .map(|attempts| policy.backoff_after(attempts))
+        .collect();
+
+    assert_eq!(
+        delays,
+        [2, 4, 8, 10, 10].map(StdDuration::from_secs).to_vec()
+    );
+    assert_eq!(
+        TurnCallbackRetryPolicy::new(0),
+        Err(TurnCallbackDomainError::NoAttempts)
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn callbacks_fire_once_for_the_next_turn() -> Result<(), eyre::Report> {
+    let fixture = fixture(3);
+    let ctx = test_request_ctx();
+    let conversation_id = Uuid::new_v4();
+    let callback = fixture
+        .service
+        .register(&ctx, conversation_id, webhook())
+        .await?;
+    let record = completed(conversation_id, fixture.clock.utc());
+
+    let queued = fixture.service.notify(&ctx, &record).await?;
+    let requeued = fixture.service.notify(&ctx, &record).await?;
+    let delivered = fixture.service.deliver_due().await;
+
+    assert_eq!((queued, requeued, delivered), (1, 0, 1));
+    let deliveries = fixture.sender.delivered();
+    let [(target, notice)] = deliveries.as_slice() else {
+        return Err(eyre::eyre!(
+            "expected one delivery, got {}",
+            deliveries.len()
+        ));
+    };
+    assert_eq!(target, callback.target());
+    assert_eq!(notice.callback_id, callback.id());
+    assert_eq!(notice.outcome, TurnOutcome::Completed);
+    assert!(
+        fixture
+            .repository
+            .pending_for_conversation(&ctx, conversation_id)?
+            .is_empty()
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn failed_deliveries_are_retried_after_backoff() -> Result<(), eyre::Report> {
+    let fixture = fixture(3);
+    let ctx = test_request_ctx();
+    let conversation_id = Uuid::new_v4();
+    fixture
+        .service
+        .register(&ctx, conversation_id, webhook())
+        .await?;
+    fixture
+        .service
+        .notify(&ctx, &completed(conversation_id, fixture.clock.utc()))
+        .await?;
+    fixture.sender.fail_next(1);
+
+    let first = fixture.service.deliver_due().await;
+    let too_early = fixture.service.deliver_due().await;
+    fixture.clock.advance(Duration::seconds(10));
+    let retried = fixture.service.deliver_due().await;
+
+    assert_eq!((first, too_early, retried), (0, 0, 1));
+    assert_eq!(fixture.sender.attempts(), 2);
+    assert_eq!(fixture.service.pending_len(), 0);
+    assert_eq!(
+        fixture.service.metrics(),
+        TurnCallbackMetrics {
+            notices_queued: 1,
+            notices_delivered: 1,
+            attempts_retried: 1,
+            notices_abandoned: 0,
+        }
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn notices_are_abandoned_after_the_final_attempt() -> Result<(), eyre::Report> {
+    let fixture = fixture(2);
+    let ctx = test_request_ctx();
+    let conversation_id = Uuid::new_v4();
+    fixture
+        .service
+        .register(&ctx, conversation_id, webhook())
+        .await?;
+    fixture
+        .service
+        .notify(&ctx, &completed(conversation_id, fixture.clock.utc()))
+        .await?;
+    fixture.sender.fail_next(5);
+
+    fixture.service.deliver_due().await;
+    fixture.clock.advance(Duration::seconds(10));
+    fixture.service.deliver_due().await;
+    fixture.clock.advance(Duration::minutes(5));
+    fixture.service.deliver_due().await;
+
+    assert_eq!(fixture.sender.attempts(), 2);
+    assert_eq!(fixture.service.pending_len(), 0);
+    assert_eq!(fixture.service.metrics().notices_abandoned, 1);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn notices_are_held_during_maintenance() -> Result<(), eyre::Report> {
+    let fixture = fixture(3);
+    let gate = Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ));
+    let service = fixture.service.with_maintenance(Arc::clone(&gate));
+    let ctx = test_request_ctx();
+    let conversation_id = Uuid::new_v4();
+    service.register(&ctx, conversation_id, webhook()).await?;
+    service
+        .notify(&ctx, &completed(conversation_id, fixture.clock.utc()))
+        .await?;
+    gate.enable(OperatorReason::new("failover")?, None).await?;
+
+    let refused = service.register(&ctx, conversation_id, webhook()).await;
+    let held = service.deliver_due().await;
+    gate.disable(None).await?;
+    let delivered = service.deliver_due().await;
+
+    assert!(matches!(
+        refused,
+        Err(TurnCallbackRepositoryError::MaintenanceMode(_))
+    ));
+    assert_eq!((held, delivered), (0, 1));
+    assert_eq!(fixture.sender.attempts(), 1);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn orchestrated_turns_notify_waiting_callbacks(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "claude_code_sdk").await?;
+    let sender = Arc::new(InMemoryTurnCallbackSender::new());
+    let callbacks = Arc::new(TurnCallbackService::new(
+        Arc::new(InMemoryTurnCallbackRepository::new()),
+        Arc::clone(&sender) as _,
+        Arc::clone(&context.clock) as _,
+    ));
+    let service = context
+        .service
+        .clone()
+        .with_turn_callbacks(Arc::clone(&callbacks));
+    let conversation_id = Uuid::new_v4();
+    let subject = TurnCallbackTarget::reply_subject("client.turns.reply")?;
+    callbacks
+        .register(&context.ctx, conversation_id, subject.clone())
+        .await?;
+
+    let turn = TurnExecutionRequest::new(conversation_id, "Fix the build", Vec::new());
+    let response = service
+        .execute_turn(&context.ctx, ExecuteAgentTurnRequest::new(backend_id, turn))
+        .await?;
+    callbacks.deliver_due().await;
+
+    let deliveries = sender.delivered();
+    let [(target, notice)] = deliveries.as_slice() else {
+        return Err(eyre::eyre!(
+            "expected one delivery, got {}",
+            deliveries.len()
+        ));
+    };
+    assert_eq!(target, &subject);
+    assert_eq!(notice.conversation_id, conversation_id);
+    assert_eq!(notice.session_id, Some(response.session_id()));
+    assert_eq!(&notice.outcome, response.outcome());
+    Ok(())
+}
@@ -0,0 +1,115 @@
+//! Tool invocation dataset recording tests.
+
+use super::common::{OrchestrationContext, context, register_backend};
+use crate::agent_backend::{
+    adapters::ObjectStoreToolDataset,
+    domain::{
+        BackendId, DatasetRecordingPolicy, DatasetTurnOutcome, ToolCallRequest,
+        ToolInvocationSample, TurnExecutionRequest, TurnExecutionResult,
+    },
+    services::{ExecuteAgentTurnRequest, ToolDatasetRecorder},
+};
+use rstest::rstest;
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn recorder() -> Result<Arc<ToolDatasetRecorder>, eyre::Report> {
+    Ok(Arc::new(ToolDatasetRecorder::new(
+        Arc::new(ObjectStoreToolDataset::in_memory()),
+        DatasetRecordingPolicy::new(100)?,
+    )))
+}
+
+async fn run_turn_with_tool_calls(
+    context: &OrchestrationContext,
+    recorder: &Arc<ToolDatasetRecorder>,
+    backend_id: BackendId,
+) -> Result<(), eyre::Report> {
+    context.runtime.queue_turn_result(TurnExecutionResult::new(
+        "done",
+        vec![
+            ToolCallRequest::new("search_docs", json!({"query": "roadmap"}))?,
+            ToolCallRequest::new("notify", json!({"email": "ada@example.com"}))?,
+        ],
+    ))?;
+    let service = context
+        .service
+        .clone()
+        .with_dataset_recorder(Arc::clone(recorder));
+    let turn = TurnExecutionRequest::new(
+        Uuid::new_v4(),
+        "Find the roadmap and mail ada@example.com",
+        Vec::new(),
+    );
+    service
+        .execute_turn(&context.ctx, ExecuteAgentTurnRequest::new(backend_id, turn))
+        .await?;
+    Ok(())
+}
+
+async fn exported_samples(
+    context: &OrchestrationContext,
+    recorder: &ToolDatasetRecorder,
+) -> Result<Vec<ToolInvocationSample>, eyre::Report> {
+    let exported = recorder.export_jsonl(&context.ctx).await?;
+    let samples = std::str::from_utf8(&exported)?
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<Vec<ToolInvocationSample>, _>>()?;
+    Ok(samples)
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn completed_turn_records_scrubbed_samples(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "codex_cli").await?;
+    let recorder = recorder()?;
+    context
+        .tool_router
+        .set_tool_response("search_docs", json!({"matches": 4}))?;
+
+    run_turn_with_tool_calls(&context, &recorder, backend_id).await?;
+
+    let samples = exported_samples(&context, &recorder).await?;
+    let tool_names = samples
+        .iter()
+        .map(|sample| sample.tool_name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(tool_names, vec!["search_docs", "notify"]);
+    assert!(samples.iter().all(|sample| {
+        sample.turn_outcome == DatasetTurnOutcome::Completed
+            && sample.backend_id == backend_id
+            && sample.prompt_context == "Find the roadmap and mail [redacted-email]"
+    }));
+    let [search, notify] = samples.as_slice() else {
+        return Err(eyre::eyre!("expected two samples"));
+    };
+    assert_eq!(search.result, Some(json!({"matches": 4})));
+    assert_eq!(notify.arguments, json!({"email": "[redacted]"}));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn failed_routing_records_unrouted_calls_as_failed(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "codex_cli").await?;
+    let recorder = recorder()?;
+    context
+        .tool_router
+        .fail_tool("search_docs", "simulated router failure")?;
+
+    let outcome = run_turn_with_tool_calls(&context, &recorder, backend_id).await;
+
+    assert!(outcome.is_err());
+    let samples = exported_samples(&context, &recorder).await?;
+    assert_eq!(samples.len(), 2);
+    assert!(samples.iter().all(|sample| {
+        sample.turn_outcome == DatasetTurnOutcome::Failed && sample.result.is_none()
+    }));
+    Ok(())
+}
@@ -2,7 +2,9 @@
 
 use super::common::{OrchestrationContext, context, register_backend};
 use crate::agent_backend::{
-    domain::{ToolCallRequest, TurnExecutionRequest, TurnExecutionResult},
+    domain::{
+        ToolCallRequest, TurnExecutionRequest, TurnExecutionResult, deterministic_tool_call_id,
+    },
     services::ExecuteAgentTurnRequest,
 };
 use rstest::rstest;
@@ -69,3 +71,15 @@ async fn execute_turn_produces_deterministic_tool_call_order_for_identical_input
     assert_eq!(first_half, second_half);
     Ok(())
 }
+
+#[rstest]
+fn tool_call_ids_match_those_already_persisted() -> Result<(), eyre::Report> {
+    let tool_call =
+        ToolCallRequest::new("read_file", json!({"path": "src/lib.rs", "limit": 10.0}))?;
+
+    assert_eq!(
+        deterministic_tool_call_id(&tool_call, 0),
+        "call-9fb45b89698161ee31702288ae53572a4b48a2f1c7ddce1893279f7ce614a58a"
+    );
+    Ok(())
+}
@@ -0,0 +1,164 @@
+//! Backend experiment routing tests.
+
+use super::common::{OrchestrationContext, context, register_backend};
+use crate::agent_backend::{
+    adapters::memory::InMemoryExperimentRepository,
+    domain::{
+        BackendExperiment, BackendId, ExperimentArm, ExperimentArmKey, ExperimentSignal,
+        HumanFeedback, TurnExecutionRequest, TurnExecutionResult,
+    },
+    services::{BackendExperimentService, ExecuteAgentTurnRequest},
+};
+use rstest::rstest;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct ExperimentFixture {
+    service: Arc<BackendExperimentService>,
+    experiment: BackendExperiment,
+    control_id: BackendId,
+    treatment_id: BackendId,
+}
+
+async fn start_experiment(
+    context: &OrchestrationContext,
+) -> Result<ExperimentFixture, eyre::Report> {
+    let control_id = register_backend(context, "claude_code_sdk").await?;
+    let treatment_id = register_backend(context, "codex_cli").await?;
+    let service = Arc::new(BackendExperimentService::new(
+        Arc::new(InMemoryExperimentRepository::new()),
+        context.clock.clone(),
+    ));
+    let experiment = BackendExperiment::new(
+        "codex-vs-sdk",
+        ExperimentArm::new(control_id),
+        ExperimentArm::new(treatment_id).with_prompt_variant("Answer briefly."),
+        context.clock.as_ref(),
+    )?;
+    service.start_experiment(&context.ctx, &experiment).await?;
+    Ok(ExperimentFixture {
+        service,
+        experiment,
+        control_id,
+        treatment_id,
+    })
+}
+
+fn conversation_on(experiment: &BackendExperiment, arm: ExperimentArmKey) -> Uuid {
+    std::iter::repeat_with(Uuid::new_v4)
+        .find(|id| experiment.assign(*id) == arm)
+        .unwrap_or_default()
+}
+
+#[rstest]
+#[case::control(ExperimentArmKey::Control, "Plan the fix")]
+#[case::treatment(ExperimentArmKey::Treatment, "Answer briefly.\n\nPlan the fix")]
+#[tokio::test(flavor = "multi_thread")]
+async fn turns_run_on_the_assigned_arm(
+    context: OrchestrationContext,
+    #[case] arm: ExperimentArmKey,
+    #[case] expected_prompt: &str,
+) -> Result<(), eyre::Report> {
+    let fixture = start_experiment(&context).await?;
+    context
+        .runtime
+        .queue_turn_result(TurnExecutionResult::new("done", Vec::new()))?;
+    let service = context
+        .service
+        .clone()
+        .with_experiments(Arc::clone(&fixture.service));
+    let conversation_id = conversation_on(&fixture.experiment, arm);
+    let turn = TurnExecutionRequest::new(conversation_id, "Plan the fix", Vec::new());
+
+    service
+        .execute_turn(
+            &context.ctx,
+            ExecuteAgentTurnRequest::new(fixture.control_id, turn),
+        )
+        .await?;
+
+    let expected_backend = match arm {
+        ExperimentArmKey::Control => fixture.control_id,
+        ExperimentArmKey::Treatment => fixture.treatment_id,
+    };
+    let records = context.runtime.execution_records()?;
+    let [record] = records.as_slice() else {
+        return Err(eyre::eyre!("expected one runtime execution"));
+    };
+    assert_eq!(record.backend_id, expected_backend);
+    assert_eq!(record.request.prompt(), expected_prompt);
+
+    let report = fixture
+        .service
+        .report(&context.ctx, fixture.experiment.id())
+        .await?;
+    let stats = match arm {
+        ExperimentArmKey::Control => report.control,
+        ExperimentArmKey::Treatment => report.treatment,
+    };
+    assert_eq!(stats.turns, 1);
+    assert_eq!(stats.success_rate_bps(), Some(10_000));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn late_signals_are_attributed_to_the_assigned_arm(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let fixture = start_experiment(&context).await?;
+    let conversation_id = conversation_on(&fixture.experiment, ExperimentArmKey::Treatment);
+    let id = fixture.experiment.id();
+
+    let assignment = fixture
+        .service
+        .assignment(&context.ctx, id, conversation_id)
+        .await?;
+    fixture
+        .service
+        .record_signal(
+            &context.ctx,
+            &assignment,
+            ExperimentSignal::Cost { cost_micros: 1_250 },
+        )
+        .await?;
+    fixture
+        .service
+        .record_signal(
+            &context.ctx,
+            &assignment,
+            ExperimentSignal::Feedback {
+                feedback: HumanFeedback::Positive,
+            },
+        )
+        .await?;
+
+    let report = fixture.service.report(&context.ctx, id).await?;
+    assert_eq!(report.treatment.total_cost_micros, 1_250);
+    assert_eq!(report.treatment.positive_feedback, 1);
+    assert_eq!(report.control.conversations, 0);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn concluded_experiments_stop_routing(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let fixture = start_experiment(&context).await?;
+    fixture
+        .service
+        .conclude_experiment(&context.ctx, fixture.experiment.id())
+        .await?;
+    let conversation_id = conversation_on(&fixture.experiment, ExperimentArmKey::Treatment);
+    let request = ExecuteAgentTurnRequest::new(
+        fixture.control_id,
+        TurnExecutionRequest::new(conversation_id, "Plan the fix", Vec::new()),
+    );
+
+    let (routed, assignment) = fixture.service.route_turn(&context.ctx, request).await?;
+
+    assert_eq!(routed.backend_id, fixture.control_id);
+    assert!(assignment.is_none());
+    Ok(())
+}
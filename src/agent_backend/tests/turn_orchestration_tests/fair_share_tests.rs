@@ -0,0 +1,57 @@
+//! Fair-share admission orchestration tests.
+
+use super::common::{OrchestrationContext, context, register_backend};
+use crate::agent_backend::{
+    domain::{FairSharePolicy, TurnExecutionRequest},
+    services::{AgentTurnOrchestrationError, ExecuteAgentTurnRequest, FairShareScheduler},
+};
+use rstest::rstest;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+fn single_token_scheduler() -> Result<Arc<FairShareScheduler>, eyre::Report> {
+    let one = NonZeroU32::new(1).ok_or_else(|| eyre::eyre!("one is non-zero"))?;
+    Ok(Arc::new(FairShareScheduler::new(
+        FairSharePolicy::new(one)
+            .with_burst(one)
+            .with_max_wait(Duration::ZERO),
+    )))
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn turns_beyond_the_tenant_share_are_rejected(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "codex_cli").await?;
+    let scheduler = single_token_scheduler()?;
+    let service = context.service.clone().with_fair_share(scheduler.clone());
+    let conversation_id = Uuid::new_v4();
+    let request = |prompt: &str| {
+        ExecuteAgentTurnRequest::new(
+            backend_id,
+            TurnExecutionRequest::new(conversation_id, prompt, Vec::new()),
+        )
+    };
+
+    service
+        .execute_turn(&context.ctx, request("First turn"))
+        .await?;
+    let result = service
+        .execute_turn(&context.ctx, request("Second turn"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AgentTurnOrchestrationError::CapacityExhausted(ref error))
+            if error.provider == "test-provider" && error.tenant_id == context.ctx.tenant_id()
+    ));
+    let metrics = scheduler.metrics();
+    let tenant = metrics
+        .first()
+        .ok_or_else(|| eyre::eyre!("tenant metrics"))?;
+    assert_eq!((tenant.admitted, tenant.rejected), (1, 1));
+    Ok(())
+}
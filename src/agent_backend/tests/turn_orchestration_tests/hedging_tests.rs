@@ -0,0 +1,175 @@
+//! Hedged runtime invocation orchestration tests.
+
+use super::common::{OrchestrationContext, TestOrchestrator, context, register_backend};
+use crate::agent_backend::{
+    domain::{
+        BackendId, HedgeSuppression, HedgeWinner, HedgingPolicy, TurnExecutionRequest,
+        TurnExecutionResult, TurnHedging,
+    },
+    services::{
+        AgentTurnOrchestratorConfig, AgentTurnOrchestratorPorts, AgentTurnOrchestratorService,
+        ExecuteAgentTurnRequest, ExecuteAgentTurnResponse,
+    },
+};
+use rstest::rstest;
+use std::time::Duration;
+use uuid::Uuid;
+
+const HEDGE_DELAY: Duration = Duration::from_millis(20);
+const SLOW_PRIMARY: Duration = Duration::from_millis(300);
+
+fn hedging_policy() -> Result<HedgingPolicy, eyre::Report> {
+    Ok(HedgingPolicy::new(50)?
+        .with_min_samples(1)
+        .with_min_delay(HEDGE_DELAY)
+        .with_budget_percent(100)?)
+}
+
+fn hedged_service(context: &OrchestrationContext, policy: HedgingPolicy) -> TestOrchestrator {
+    AgentTurnOrchestratorService::with_config(
+        AgentTurnOrchestratorPorts {
+            backend_registry: context.backend_registry.clone(),
+            turn_sessions: context.session_repository.clone(),
+            runtime: context.runtime.clone(),
+            tool_router: context.tool_router.clone(),
+            clock: context.clock.clone(),
+        },
+        AgentTurnOrchestratorConfig::default().with_hedging(policy),
+    )
+}
+
+/// Runs a fast warm-up turn so the backend has a latency sample, then a turn
+/// whose primary invocation is slow.
+async fn run_slow_turn(
+    context: &OrchestrationContext,
+    service: &TestOrchestrator,
+    request: ExecuteAgentTurnRequest,
+) -> Result<ExecuteAgentTurnResponse, eyre::Report> {
+    let warm_up = ExecuteAgentTurnRequest::new(
+        request.backend_id,
+        TurnExecutionRequest::new(request.turn.conversation_id(), "Warm up", Vec::new()),
+    );
+    let warm_up_response = service.execute_turn(&context.ctx, warm_up).await?;
+    assert_eq!(warm_up_response.hedging(), None);
+
+    context.runtime.queue_execute_delay(SLOW_PRIMARY)?;
+    context
+        .runtime
+        .queue_turn_result(TurnExecutionResult::new("primary", Vec::new()))?;
+    context
+        .runtime
+        .queue_turn_result(TurnExecutionResult::new("hedge", Vec::new()))?;
+    Ok(service.execute_turn(&context.ctx, request).await?)
+}
+
+fn slow_request(backend_id: BackendId) -> ExecuteAgentTurnRequest {
+    ExecuteAgentTurnRequest::new(
+        backend_id,
+        TurnExecutionRequest::new(Uuid::new_v4(), "Slow turn", Vec::new()),
+    )
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn slow_idempotent_turn_takes_first_hedged_response(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "codex_cli").await?;
+    let service = hedged_service(&context, hedging_policy()?);
+
+    let response = run_slow_turn(
+        &context,
+        &service,
+        slow_request(backend_id).with_idempotent(true),
+    )
+    .await?;
+
+    assert_eq!(response.assistant_response(), "hedge");
+    assert!(matches!(
+        response.hedging(),
+        Some(TurnHedging::Issued {
+            hedge_backend_id,
+            triggered_after: HEDGE_DELAY,
+            winner: HedgeWinner::Hedge,
+        }) if hedge_backend_id == backend_id
+    ));
+    // The cancelled primary never completes, so only the warm-up and hedge
+    // invocations are recorded.
+    let records = context.runtime.execution_records()?;
+    let [warm_up, hedge] = records.as_slice() else {
+        return Err(eyre::eyre!(
+            "expected two invocations, got {}",
+            records.len()
+        ));
+    };
+    assert_ne!(hedge.runtime_session_id, warm_up.runtime_session_id);
+    assert_eq!(
+        context.runtime.created_session_ids()?,
+        vec![warm_up.runtime_session_id.clone()]
+    );
+    Ok(())
+}
+
+#[rstest]
+#[case::not_idempotent(false, 100, HedgeSuppression::NotIdempotent)]
+#[case::budget_exhausted(true, 1, HedgeSuppression::BudgetExhausted)]
+#[tokio::test(flavor = "multi_thread")]
+async fn slow_turn_waits_for_primary_when_hedge_is_guarded(
+    context: OrchestrationContext,
+    #[case] idempotent: bool,
+    #[case] budget_percent: u8,
+    #[case] expected_reason: HedgeSuppression,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "codex_cli").await?;
+    let policy = hedging_policy()?.with_budget_percent(budget_percent)?;
+    let service = hedged_service(&context, policy);
+
+    let response = run_slow_turn(
+        &context,
+        &service,
+        slow_request(backend_id).with_idempotent(idempotent),
+    )
+    .await?;
+
+    assert_eq!(response.assistant_response(), "primary");
+    assert_eq!(
+        response.hedging(),
+        Some(TurnHedging::Suppressed {
+            triggered_after: HEDGE_DELAY,
+            reason: expected_reason,
+        })
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn fallback_hedge_uses_short_lived_session(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "codex_cli").await?;
+    let fallback_id = register_backend(&context, "claude_code_sdk").await?;
+    let policy = hedging_policy()?.with_fallback_backend(fallback_id);
+    let service = hedged_service(&context, policy);
+
+    let response = run_slow_turn(
+        &context,
+        &service,
+        slow_request(backend_id).with_idempotent(true),
+    )
+    .await?;
+
+    assert_eq!(response.assistant_response(), "hedge");
+    assert!(matches!(
+        response.hedging(),
+        Some(TurnHedging::Issued { hedge_backend_id, .. }) if hedge_backend_id == fallback_id
+    ));
+    let sessions = context.runtime.created_session_ids()?;
+    assert_eq!(sessions.len(), 1);
+    assert!(
+        sessions
+            .iter()
+            .all(|id| id.as_str().starts_with("codex_cli"))
+    );
+    Ok(())
+}
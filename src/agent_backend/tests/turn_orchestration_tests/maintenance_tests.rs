@@ -0,0 +1,54 @@
+//! Maintenance mode orchestration tests.
+
+use super::common::{OrchestrationContext, context, register_backend};
+use crate::agent_backend::{
+    domain::TurnExecutionRequest,
+    services::{AgentTurnOrchestrationError, ExecuteAgentTurnRequest},
+};
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::operator::domain::OperatorReason;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn turns_are_refused_during_maintenance_and_resume_afterwards(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "codex_cli").await?;
+    let gate = Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ));
+    let service = context.service.clone().with_maintenance(gate.clone());
+    let conversation_id = Uuid::new_v4();
+    let request = || {
+        ExecuteAgentTurnRequest::new(
+            backend_id,
+            TurnExecutionRequest::new(conversation_id, "Deploy", Vec::new()),
+        )
+    };
+    gate.enable(OperatorReason::new("database failover")?, None)
+        .await?;
+
+    let refused = service.execute_turn(&context.ctx, request()).await;
+
+    assert!(matches!(
+        refused,
+        Err(AgentTurnOrchestrationError::MaintenanceMode(ref error))
+            if error.reason.as_ref().map(OperatorReason::as_str) == Some("database failover")
+    ));
+    assert!(
+        refused
+            .as_ref()
+            .err()
+            .and_then(AgentTurnOrchestrationError::outcome)
+            .is_none(),
+        "refused turns have no outcome"
+    );
+    gate.disable(None).await?;
+    service.execute_turn(&context.ctx, request()).await?;
+    Ok(())
+}
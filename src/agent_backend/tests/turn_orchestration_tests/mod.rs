@@ -1,7 +1,13 @@
 //! Unit tests for agent turn orchestration service behaviour.
 
-mod common;
+pub(super) mod common;
+mod dataset_tests;
 mod determinism_tests;
+mod experiment_tests;
 mod failure_tests;
+mod fair_share_tests;
+mod hedging_tests;
+mod maintenance_tests;
+mod outcome_tests;
 mod routing_tests;
 mod session_tests;
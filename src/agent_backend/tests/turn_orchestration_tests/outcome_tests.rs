@@ -0,0 +1,180 @@
+//! Turn outcome classification and recording tests.
+
+use super::common::{OrchestrationContext, context, register_backend};
+use crate::agent_backend::{
+    adapters::memory::InMemoryTurnOutcomeRepository,
+    domain::{
+        BackendId, ToolCallRequest, TurnEscalation, TurnExecutionRequest, TurnExecutionResult,
+        TurnOutcome, TurnOutcomeKind, TurnOutcomeRecord, deterministic_tool_call_id,
+    },
+    ports::TurnOutcomeRepository,
+    services::{AgentTurnOrchestrationResult, ExecuteAgentTurnRequest, ExecuteAgentTurnResponse},
+};
+use crate::pagination::PageRequest;
+use rstest::rstest;
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+async fn run_turn(
+    context: &OrchestrationContext,
+    outcomes: &Arc<InMemoryTurnOutcomeRepository>,
+    backend_id: BackendId,
+    conversation_id: Uuid,
+) -> AgentTurnOrchestrationResult<ExecuteAgentTurnResponse> {
+    let service = context
+        .service
+        .clone()
+        .with_turn_outcomes(Arc::clone(outcomes) as Arc<dyn TurnOutcomeRepository>);
+    let turn = TurnExecutionRequest::new(conversation_id, "Fix the build", Vec::new());
+    service
+        .execute_turn(&context.ctx, ExecuteAgentTurnRequest::new(backend_id, turn))
+        .await
+}
+
+async fn recorded(
+    context: &OrchestrationContext,
+    outcomes: &InMemoryTurnOutcomeRepository,
+    conversation_id: Uuid,
+) -> Result<Vec<TurnOutcomeRecord>, eyre::Report> {
+    Ok(outcomes
+        .list_for_conversation(&context.ctx, conversation_id, PageRequest::default())
+        .await?
+        .into_items())
+}
+
+#[rstest]
+#[case(TurnOutcomeKind::Completed, false)]
+#[case(TurnOutcomeKind::NeedsHuman, false)]
+#[case(TurnOutcomeKind::BudgetExhausted, false)]
+#[case(TurnOutcomeKind::ToolBlocked, false)]
+#[case(TurnOutcomeKind::BackendFailed, true)]
+#[case(TurnOutcomeKind::HandedOff, false)]
+fn outcome_kinds_round_trip_and_classify_retries(
+    #[case] kind: TurnOutcomeKind,
+    #[case] retryable: bool,
+) {
+    assert_eq!(TurnOutcomeKind::try_from(kind.as_str()), Ok(kind));
+    assert_eq!(kind.is_retryable(), retryable);
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn completed_turns_report_and_record_completion(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "claude_code_sdk").await?;
+    let outcomes = Arc::new(InMemoryTurnOutcomeRepository::new());
+    let conversation_id = Uuid::new_v4();
+
+    let response = run_turn(&context, &outcomes, backend_id, conversation_id).await?;
+
+    assert_eq!(response.outcome(), &TurnOutcome::Completed);
+    let records = recorded(&context, &outcomes, conversation_id).await?;
+    let [record] = records.as_slice() else {
+        return Err(eyre::eyre!("expected one outcome, got {}", records.len()));
+    };
+    assert_eq!(record.backend_id, backend_id);
+    assert_eq!(record.session_id, Some(response.session_id()));
+    assert_eq!(record.outcome, TurnOutcome::Completed);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn escalated_turns_skip_tool_routing(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "claude_code_sdk").await?;
+    let outcomes = Arc::new(InMemoryTurnOutcomeRepository::new());
+    let conversation_id = Uuid::new_v4();
+    context.runtime.queue_turn_result(
+        TurnExecutionResult::new(
+            "Which branch should I deploy?",
+            vec![ToolCallRequest::new("deploy", json!({"env": "prod"}))?],
+        )
+        .with_escalation(TurnEscalation::NeedsHuman {
+            reason: "deployment target is ambiguous".to_owned(),
+        }),
+    )?;
+
+    let response = run_turn(&context, &outcomes, backend_id, conversation_id).await?;
+
+    let expected = TurnOutcome::NeedsHuman {
+        reason: "deployment target is ambiguous".to_owned(),
+    };
+    assert_eq!(response.outcome(), &expected);
+    assert!(response.tool_results().is_empty());
+    assert!(context.tool_router.routed_call_ids()?.is_empty());
+    let records = recorded(&context, &outcomes, conversation_id).await?;
+    assert_eq!(
+        records
+            .iter()
+            .map(|record| &record.outcome)
+            .collect::<Vec<_>>(),
+        [&expected]
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn runtime_failures_are_recorded_as_backend_failures(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "codex_cli").await?;
+    let outcomes = Arc::new(InMemoryTurnOutcomeRepository::new());
+    let conversation_id = Uuid::new_v4();
+    context.runtime.fail_next_execute("backend unavailable")?;
+
+    let result = run_turn(&context, &outcomes, backend_id, conversation_id).await;
+
+    let outcome = result
+        .err()
+        .and_then(|error| error.outcome())
+        .ok_or_else(|| eyre::eyre!("expected a failed turn with an outcome"))?;
+    assert_eq!(outcome.kind(), TurnOutcomeKind::BackendFailed);
+    assert!(outcome.kind().is_retryable());
+    let records = recorded(&context, &outcomes, conversation_id).await?;
+    let [record] = records.as_slice() else {
+        return Err(eyre::eyre!("expected one outcome, got {}", records.len()));
+    };
+    assert_eq!(record.outcome, outcome);
+    assert_eq!(record.session_id, None);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn tool_routing_failures_are_recorded_as_blocked_tools(
+    context: OrchestrationContext,
+) -> Result<(), eyre::Report> {
+    let backend_id = register_backend(&context, "claude_code_sdk").await?;
+    let outcomes = Arc::new(InMemoryTurnOutcomeRepository::new());
+    let conversation_id = Uuid::new_v4();
+    let failing_call = ToolCallRequest::new("failing_tool", json!({"arg": 1}))?;
+    context.runtime.queue_turn_result(TurnExecutionResult::new(
+        "assistant response",
+        vec![failing_call.clone()],
+    ))?;
+    context
+        .tool_router
+        .fail_tool("failing_tool", "simulated router failure")?;
+
+    let result = run_turn(&context, &outcomes, backend_id, conversation_id).await;
+
+    assert!(result.is_err());
+    let records = recorded(&context, &outcomes, conversation_id).await?;
+    let [record] = records.as_slice() else {
+        return Err(eyre::eyre!("expected one outcome, got {}", records.len()));
+    };
+    assert_eq!(
+        record.outcome,
+        TurnOutcome::ToolBlocked {
+            call_id: deterministic_tool_call_id(&failing_call, 0),
+            tool_name: "failing_tool".to_owned(),
+            reason: "tool execution failed: simulated router failure".to_owned(),
+        }
+    );
+    Ok(())
+}
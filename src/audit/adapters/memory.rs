@@ -0,0 +1,119 @@
+//! In-memory audit trail for tests.
+
+use crate::audit::domain::{AuditLogEntry, AuditQuery};
+use crate::audit::ports::{AuditLogRepository, AuditLogResult};
+use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::projection::domain::RecordedEvent;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// A tenant's recorded changes and events.
+#[derive(Debug, Default)]
+struct TenantTrail {
+    changes: Vec<AuditLogEntry>,
+    events: Vec<RecordedEvent>,
+}
+
+/// Thread-safe in-memory audit trail.
+///
+/// Nothing records into it on its own; tests add the changes and events
+/// they expect with [`Self::record_change`] and [`Self::record_event`].
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAuditLogRepository {
+    trails: Arc<RwLock<HashMap<TenantId, TenantTrail>>>,
+}
+
+impl InMemoryAuditLogRepository {
+    /// Creates an empty audit trail.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a row change for the tenant of `ctx`.
+    pub async fn record_change(&self, ctx: &RequestContext, entry: AuditLogEntry) {
+        let mut trails = self.trails.write().await;
+        trails
+            .entry(ctx.tenant_id())
+            .or_default()
+            .changes
+            .push(entry);
+    }
+
+    /// Records a domain event for the tenant of `ctx`.
+    pub async fn record_event(&self, ctx: &RequestContext, event: RecordedEvent) {
+        let mut trails = self.trails.write().await;
+        trails
+            .entry(ctx.tenant_id())
+            .or_default()
+            .events
+            .push(event);
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for InMemoryAuditLogRepository {
+    async fn changes(
+        &self,
+        ctx: &RequestContext,
+        query: AuditQuery,
+        page: PageRequest,
+    ) -> AuditLogResult<Page<AuditLogEntry>> {
+        let trails = self.trails.read().await;
+        let matching = trails
+            .get(&ctx.tenant_id())
+            .map(|trail| {
+                trail
+                    .changes
+                    .iter()
+                    .filter(|entry| query.matches_entry(entry))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(oldest_first(matching, page, |entry| {
+            (entry.occurred_at, entry.id)
+        }))
+    }
+
+    async fn events(
+        &self,
+        ctx: &RequestContext,
+        query: AuditQuery,
+        page: PageRequest,
+    ) -> AuditLogResult<Page<RecordedEvent>> {
+        let trails = self.trails.read().await;
+        let matching = trails
+            .get(&ctx.tenant_id())
+            .map(|trail| {
+                trail
+                    .events
+                    .iter()
+                    .filter(|event| query.matches_event(event))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(oldest_first(matching, page, |event| {
+            (event.occurred_at, event.id)
+        }))
+    }
+}
+
+/// Sorts `items` by time and id and returns the requested page.
+fn oldest_first<T>(
+    mut items: Vec<T>,
+    page: PageRequest,
+    key: impl Fn(&T) -> (DateTime<Utc>, Uuid),
+) -> Page<T> {
+    items.sort_by_key(&key);
+    Page::from_ordered(items, page, |item| {
+        let (occurred_at, id) = key(item);
+        Cursor::at_timestamp(occurred_at, id)
+    })
+}
@@ -0,0 +1,7 @@
+//! Adapter implementations for the audit trail ports.
+
+pub mod memory;
+pub mod postgres;
+
+pub use memory::InMemoryAuditLogRepository;
+pub use postgres::PostgresAuditLogRepository;
@@ -0,0 +1,6 @@
+//! `PostgreSQL` adapter for reading the audit trail.
+
+mod models;
+mod repository;
+
+pub use repository::PostgresAuditLogRepository;
@@ -0,0 +1,38 @@
+//! Diesel models for reading the audit trail.
+
+use crate::message::adapters::schema::audit_logs;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Row representation of an audited change.
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = audit_logs)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AuditLogRow {
+    /// Entry identifier.
+    pub id: Uuid,
+    /// Table the changed row belongs to.
+    pub table_name: String,
+    /// `INSERT`, `UPDATE` or `DELETE`.
+    pub operation: String,
+    /// Primary key of the changed row.
+    pub row_id: Option<Uuid>,
+    /// Row before the change.
+    pub old_values: Option<Value>,
+    /// Row after the change.
+    pub new_values: Option<Value>,
+    /// User who made the change.
+    pub user_id: Option<Uuid>,
+    /// Session the change was made in.
+    pub session_id: Option<Uuid>,
+    /// Correlation identifier of the request.
+    pub correlation_id: Option<Uuid>,
+    /// Identifier of the cause.
+    pub causation_id: Option<Uuid>,
+    /// Application name of the database session.
+    pub application_name: Option<String>,
+    /// When the change was made.
+    pub occurred_at: DateTime<Utc>,
+}
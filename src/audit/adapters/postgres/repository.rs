@@ -0,0 +1,199 @@
+//! `PostgreSQL` repository for reading the audit trail.
+
+use super::models::AuditLogRow;
+use crate::audit::domain::{AuditLogEntry, AuditOperation, AuditQuery};
+use crate::audit::ports::{AuditLogError, AuditLogRepository, AuditLogResult};
+use crate::context::{CorrelationId, RequestContext, SessionId, UserId};
+use crate::message::adapters::models::DomainEventRow;
+use crate::message::adapters::schema::{audit_logs, domain_events};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{
+    FromTxError, PgPool, TxError, get_conn_with, keyset_page, run_blocking_with,
+    with_tenant_read_tx,
+};
+use crate::projection::adapters::postgres::row_to_event;
+use crate::projection::domain::RecordedEvent;
+use async_trait::async_trait;
+use diesel::dsl::sql;
+use diesel::pg::{Pg, PgConnection};
+use diesel::prelude::*;
+use diesel::sql_types::{Bool, Text, Uuid as SqlUuid};
+
+impl FromTxError<Self> for AuditLogError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(error) => error,
+            TxError::Diesel(error) => Self::persistence_failed(error),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed audit trail over the `audit_logs` and
+/// `domain_events` tables.
+///
+/// Neither table has a tenant column. Changes belong to the tenant named
+/// by the changed row's `tenant_id`, and events to the tenant owning the
+/// conversation or message they are about. Entries stripped by erasure, and
+/// events about purged rows, therefore no longer appear.
+#[derive(Debug, Clone)]
+pub struct PostgresAuditLogRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(PostgresAuditLogRepository, "audit_log_repository");
+
+impl PostgresAuditLogRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn read<T, F>(&self, ctx: &RequestContext, load: F) -> AuditLogResult<Vec<T>>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut PgConnection, uuid::Uuid) -> AuditLogResult<Vec<T>> + Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, AuditLogError::persistence_failed)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| load(tx, tenant_uuid))
+            },
+            AuditLogError::persistence_failed,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for PostgresAuditLogRepository {
+    async fn changes(
+        &self,
+        ctx: &RequestContext,
+        query: AuditQuery,
+        page: PageRequest,
+    ) -> AuditLogResult<Page<AuditLogEntry>> {
+        let rows = self
+            .read(ctx, move |tx, tenant_uuid| {
+                keyset_page!(
+                    changes_query(tenant_uuid, query),
+                    page,
+                    after_timestamp,
+                    (audit_logs::occurred_at, audit_logs::id)
+                )
+                .select(AuditLogRow::as_select())
+                .load::<AuditLogRow>(tx)
+                .map_err(AuditLogError::persistence_failed)
+            })
+            .await?;
+        Page::from_overfetched(rows, page, |row| {
+            Cursor::at_timestamp(row.occurred_at, row.id)
+        })
+        .try_map(row_to_entry)
+    }
+
+    async fn events(
+        &self,
+        ctx: &RequestContext,
+        query: AuditQuery,
+        page: PageRequest,
+    ) -> AuditLogResult<Page<RecordedEvent>> {
+        let rows = self
+            .read(ctx, move |tx, tenant_uuid| {
+                keyset_page!(
+                    events_query(tenant_uuid, query),
+                    page,
+                    after_timestamp,
+                    (domain_events::occurred_at, domain_events::id)
+                )
+                .select(DomainEventRow::as_select())
+                .load::<DomainEventRow>(tx)
+                .map_err(AuditLogError::persistence_failed)
+            })
+            .await?;
+        Page::from_overfetched(rows, page, |row| {
+            Cursor::at_timestamp(row.occurred_at, row.id)
+        })
+        .try_map(|row| {
+            row_to_event(row).map_err(|err| AuditLogError::invalid_persisted_data(err.to_string()))
+        })
+    }
+}
+
+fn changes_query(
+    tenant_uuid: uuid::Uuid,
+    query: AuditQuery,
+) -> audit_logs::BoxedQuery<'static, Pg> {
+    let mut boxed = audit_logs::table.into_boxed::<Pg>().filter(
+        sql::<Bool>("COALESCE(audit_logs.new_values, audit_logs.old_values) ->> 'tenant_id' = ")
+            .bind::<Text, _>(tenant_uuid.to_string()),
+    );
+    if let Some(correlation_id) = query.correlation_id() {
+        boxed = boxed.filter(audit_logs::correlation_id.eq(correlation_id.into_inner()));
+    }
+    if let Some(user_id) = query.user_id() {
+        boxed = boxed.filter(audit_logs::user_id.eq(user_id.into_inner()));
+    }
+    if let Some(aggregate_id) = query.aggregate_id() {
+        boxed = boxed.filter(audit_logs::row_id.eq(aggregate_id));
+    }
+    if let Some(from) = query.occurred_from() {
+        boxed = boxed.filter(audit_logs::occurred_at.ge(from));
+    }
+    if let Some(until) = query.occurred_until() {
+        boxed = boxed.filter(audit_logs::occurred_at.lt(until));
+    }
+    boxed
+}
+
+fn events_query(
+    tenant_uuid: uuid::Uuid,
+    query: AuditQuery,
+) -> domain_events::BoxedQuery<'static, Pg> {
+    let mut boxed = domain_events::table.into_boxed::<Pg>().filter(
+        sql::<Bool>(
+            "domain_events.aggregate_id IN (SELECT id FROM conversations WHERE tenant_id = ",
+        )
+        .bind::<SqlUuid, _>(tenant_uuid)
+        .sql(" UNION ALL SELECT id FROM messages WHERE tenant_id = ")
+        .bind::<SqlUuid, _>(tenant_uuid)
+        .sql(")"),
+    );
+    if let Some(correlation_id) = query.correlation_id() {
+        boxed = boxed.filter(domain_events::correlation_id.eq(correlation_id.into_inner()));
+    }
+    if let Some(user_id) = query.user_id() {
+        boxed = boxed.filter(domain_events::user_id.eq(user_id.into_inner()));
+    }
+    if let Some(aggregate_id) = query.aggregate_id() {
+        boxed = boxed.filter(domain_events::aggregate_id.eq(aggregate_id));
+    }
+    if let Some(from) = query.occurred_from() {
+        boxed = boxed.filter(domain_events::occurred_at.ge(from));
+    }
+    if let Some(until) = query.occurred_until() {
+        boxed = boxed.filter(domain_events::occurred_at.lt(until));
+    }
+    boxed
+}
+
+fn row_to_entry(row: AuditLogRow) -> AuditLogResult<AuditLogEntry> {
+    let operation = AuditOperation::try_from(row.operation.as_str())
+        .map_err(|err| AuditLogError::invalid_persisted_data(err.to_string()))?;
+    Ok(AuditLogEntry {
+        id: row.id,
+        table_name: row.table_name,
+        operation,
+        row_id: row.row_id,
+        old_values: row.old_values,
+        new_values: row.new_values,
+        user_id: row.user_id.map(UserId::from_uuid),
+        session_id: row.session_id.map(SessionId::from_uuid),
+        correlation_id: row.correlation_id.map(CorrelationId::from_uuid),
+        causation_id: row.causation_id,
+        application_name: row.application_name,
+        occurred_at: row.occurred_at,
+    })
+}
@@ -0,0 +1,208 @@
+//! Causal trees of the domain events recorded for one request.
+
+use crate::context::CorrelationId;
+use crate::projection::domain::RecordedEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// What a node of a causation graph is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CausationNodeKind {
+    /// A message was stored or changed.
+    Message,
+    /// A tool was called or answered.
+    ToolCall,
+    /// Work was handed between agent sessions.
+    Handoff,
+    /// A task changed.
+    Task,
+    /// Any other event.
+    Other,
+}
+
+/// Event and aggregate type prefixes, checked in order, that give a node's
+/// kind.
+const KIND_PREFIXES: [(&str, CausationNodeKind); 4] = [
+    ("Tool", CausationNodeKind::ToolCall),
+    ("Handoff", CausationNodeKind::Handoff),
+    ("Task", CausationNodeKind::Task),
+    ("Message", CausationNodeKind::Message),
+];
+
+impl CausationNodeKind {
+    /// Classifies `event` by its event type, falling back to its aggregate
+    /// type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use corbusier::audit::domain::CausationNodeKind;
+    /// use corbusier::projection::domain::RecordedEvent;
+    /// use mockable::{Clock, DefaultClock};
+    /// use uuid::Uuid;
+    ///
+    /// let event = RecordedEvent::new("Task", Uuid::new_v4(), "TaskStateChanged", DefaultClock.utc());
+    /// assert_eq!(CausationNodeKind::of(&event), CausationNodeKind::Task);
+    /// ```
+    #[must_use]
+    pub fn of(event: &RecordedEvent) -> Self {
+        [event.event_type.as_str(), event.aggregate_type.as_str()]
+            .into_iter()
+            .find_map(|name| {
+                KIND_PREFIXES
+                    .iter()
+                    .find(|(prefix, _)| name.starts_with(prefix))
+                    .map(|&(_, kind)| kind)
+            })
+            .unwrap_or(Self::Other)
+    }
+}
+
+/// One event in a causation graph, with the events it caused.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CausationNode {
+    /// What the event is about.
+    pub kind: CausationNodeKind,
+    /// The event itself.
+    pub event: RecordedEvent,
+    /// Events whose causation identifier names this event, oldest first.
+    pub children: Vec<Self>,
+}
+
+impl CausationNode {
+    /// Returns the number of events in this subtree, including this one.
+    #[must_use]
+    pub fn event_count(&self) -> usize {
+        1 + self.children.iter().map(Self::event_count).sum::<usize>()
+    }
+}
+
+/// The causal forest of every event recorded for one correlation id.
+///
+/// An event is placed under the event its causation identifier names. Events
+/// with no cause, or whose cause was recorded under another correlation id,
+/// are roots. Siblings and roots are ordered oldest first, so walking the
+/// graph depth first gives a timeline. Should the recorded causes form a
+/// cycle, the oldest event of the cycle becomes a root so every event still
+/// appears exactly once.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::audit::domain::{CausationGraph, CausationNodeKind};
+/// use corbusier::context::CorrelationId;
+/// use corbusier::projection::domain::RecordedEvent;
+/// use mockable::{Clock, DefaultClock};
+/// use uuid::Uuid;
+///
+/// let message = RecordedEvent::new("Message", Uuid::new_v4(), "MessageCreated", DefaultClock.utc());
+/// let mut tool_call =
+///     RecordedEvent::new("Message", Uuid::new_v4(), "ToolCallCompleted", DefaultClock.utc());
+/// tool_call.causation_id = Some(message.id);
+///
+/// let graph = CausationGraph::from_events(CorrelationId::new(), vec![tool_call, message]);
+/// let [root] = graph.roots() else { panic!("expected one root") };
+/// assert_eq!(root.kind, CausationNodeKind::Message);
+/// assert_eq!(root.children.first().map(|child| child.kind), Some(CausationNodeKind::ToolCall));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CausationGraph {
+    correlation_id: CorrelationId,
+    roots: Vec<CausationNode>,
+}
+
+impl CausationGraph {
+    /// Builds the graph of `events`, all recorded under `correlation_id`.
+    #[must_use]
+    pub fn from_events(correlation_id: CorrelationId, mut events: Vec<RecordedEvent>) -> Self {
+        events.sort_by_key(|event| (event.occurred_at, event.position));
+        let known: HashSet<Uuid> = events.iter().map(|event| event.id).collect();
+        let mut caused: HashMap<Uuid, Vec<RecordedEvent>> = HashMap::new();
+        let mut roots = Vec::new();
+        for event in events {
+            match event
+                .causation_id
+                .filter(|cause| *cause != event.id && known.contains(cause))
+            {
+                Some(cause) => caused.entry(cause).or_default().push(event),
+                None => roots.push(event),
+            }
+        }
+        let mut nodes: Vec<CausationNode> = roots
+            .into_iter()
+            .map(|event| grow(event, &mut caused))
+            .collect();
+        while let Some(event) = take_oldest(&mut caused) {
+            nodes.push(grow(event, &mut caused));
+        }
+        Self {
+            correlation_id,
+            roots: nodes,
+        }
+    }
+
+    /// Returns the correlation id the graph was built for.
+    #[must_use]
+    pub const fn correlation_id(&self) -> CorrelationId {
+        self.correlation_id
+    }
+
+    /// Returns the events with no recorded cause in the graph, oldest
+    /// first.
+    #[must_use]
+    pub fn roots(&self) -> &[CausationNode] {
+        &self.roots
+    }
+
+    /// Returns the number of events in the graph.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.roots.iter().map(CausationNode::event_count).sum()
+    }
+
+    /// Returns whether the graph holds no events.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+}
+
+/// Builds the node for `event`, moving the events it caused out of
+/// `caused`.
+fn grow(event: RecordedEvent, caused: &mut HashMap<Uuid, Vec<RecordedEvent>>) -> CausationNode {
+    let children = caused
+        .remove(&event.id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|child| grow(child, caused))
+        .collect();
+    CausationNode {
+        kind: CausationNodeKind::of(&event),
+        event,
+        children,
+    }
+}
+
+/// Removes and returns the oldest event left in `caused`.
+///
+/// Only events on a cycle of causes are left once every root has grown.
+fn take_oldest(caused: &mut HashMap<Uuid, Vec<RecordedEvent>>) -> Option<RecordedEvent> {
+    let (cause, index) = caused
+        .iter()
+        .flat_map(|(cause, events)| {
+            events
+                .iter()
+                .enumerate()
+                .map(move |(index, event)| (event.occurred_at, event.position, *cause, index))
+        })
+        .min()
+        .map(|(_, _, cause, index)| (cause, index))?;
+    let events = caused.get_mut(&cause)?;
+    let event = events.remove(index);
+    if events.is_empty() {
+        caused.remove(&cause);
+    }
+    Some(event)
+}
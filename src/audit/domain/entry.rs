@@ -0,0 +1,125 @@
+//! Row changes captured by the audit trigger.
+
+use crate::context::{CorrelationId, SessionId, UserId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// The kind of change made to an audited row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOperation {
+    /// The row was created.
+    Insert,
+    /// The row was changed.
+    Update,
+    /// The row was removed.
+    Delete,
+}
+
+impl AuditOperation {
+    /// Returns the operation as the trigger records it.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Insert => "INSERT",
+            Self::Update => "UPDATE",
+            Self::Delete => "DELETE",
+        }
+    }
+}
+
+impl fmt::Display for AuditOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when an audit operation string is not recognised.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown audit operation: {0}")]
+pub struct ParseAuditOperationError(pub String);
+
+impl TryFrom<&str> for AuditOperation {
+    type Error = ParseAuditOperationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "INSERT" => Ok(Self::Insert),
+            "UPDATE" => Ok(Self::Update),
+            "DELETE" => Ok(Self::Delete),
+            other => Err(ParseAuditOperationError(other.to_owned())),
+        }
+    }
+}
+
+/// One audited change to a row.
+///
+/// The row's values before and after the change are kept as JSON. Erasure
+/// and retention purges strip them, leaving only who changed which row and
+/// when.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    /// Unique entry identifier.
+    pub id: Uuid,
+    /// The table the row belongs to, such as `messages`.
+    pub table_name: String,
+    /// What was done to the row.
+    pub operation: AuditOperation,
+    /// Primary key of the row.
+    pub row_id: Option<Uuid>,
+    /// The row before an update or delete.
+    pub old_values: Option<Value>,
+    /// The row after an insert or update.
+    pub new_values: Option<Value>,
+    /// User who made the change.
+    pub user_id: Option<UserId>,
+    /// Session the change was made in.
+    pub session_id: Option<SessionId>,
+    /// Correlation identifier of the request that made the change.
+    pub correlation_id: Option<CorrelationId>,
+    /// Identifier of the event or command that caused the change.
+    pub causation_id: Option<Uuid>,
+    /// Application name of the database session.
+    pub application_name: Option<String>,
+    /// When the change was made.
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl AuditLogEntry {
+    /// Creates an entry for `operation` on `row_id` of `table_name` with no
+    /// values or context recorded.
+    #[must_use]
+    pub fn new(
+        table_name: impl Into<String>,
+        operation: AuditOperation,
+        row_id: Uuid,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            table_name: table_name.into(),
+            operation,
+            row_id: Some(row_id),
+            old_values: None,
+            new_values: None,
+            user_id: None,
+            session_id: None,
+            correlation_id: None,
+            causation_id: None,
+            application_name: None,
+            occurred_at,
+        }
+    }
+
+    /// Records the user and correlation identifier of the change.
+    #[must_use]
+    pub const fn made_by(mut self, user_id: UserId, correlation_id: CorrelationId) -> Self {
+        self.user_id = Some(user_id);
+        self.correlation_id = Some(correlation_id);
+        self
+    }
+}
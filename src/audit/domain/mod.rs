@@ -0,0 +1,9 @@
+//! Domain types for reading the audit trail.
+
+pub mod causation;
+pub mod entry;
+pub mod query;
+
+pub use causation::{CausationGraph, CausationNode, CausationNodeKind};
+pub use entry::{AuditLogEntry, AuditOperation, ParseAuditOperationError};
+pub use query::AuditQuery;
@@ -0,0 +1,144 @@
+//! Filters for reading the audit trail.
+
+use super::AuditLogEntry;
+use crate::context::{CorrelationId, UserId};
+use crate::projection::domain::RecordedEvent;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Filter over a tenant's audit trail.
+///
+/// Every set criterion must match. The aggregate is the changed row for an
+/// [`AuditLogEntry`] and the event's aggregate for a [`RecordedEvent`]. The
+/// time range includes its start and excludes its end. The default query
+/// matches everything.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, Utc};
+/// use corbusier::audit::domain::AuditQuery;
+/// use corbusier::context::{CorrelationId, UserId};
+///
+/// let correlation_id = CorrelationId::new();
+/// let until = Utc::now();
+/// let query = AuditQuery::for_correlation(correlation_id)
+///     .with_user(UserId::new())
+///     .between(until - Duration::hours(1), until);
+/// assert_eq!(query.correlation_id(), Some(correlation_id));
+/// assert_eq!(query.occurred_until(), Some(until));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AuditQuery {
+    correlation_id: Option<CorrelationId>,
+    user_id: Option<UserId>,
+    aggregate_id: Option<Uuid>,
+    occurred_from: Option<DateTime<Utc>>,
+    occurred_until: Option<DateTime<Utc>>,
+}
+
+impl AuditQuery {
+    /// Matches everything recorded for one request.
+    #[must_use]
+    pub fn for_correlation(correlation_id: CorrelationId) -> Self {
+        Self::default().with_correlation(correlation_id)
+    }
+
+    /// Matches everything recorded about one aggregate, such as a message
+    /// or conversation.
+    #[must_use]
+    pub fn for_aggregate(aggregate_id: Uuid) -> Self {
+        Self::default().with_aggregate(aggregate_id)
+    }
+
+    /// Restricts the query to one request.
+    #[must_use]
+    pub const fn with_correlation(mut self, correlation_id: CorrelationId) -> Self {
+        self.correlation_id = Some(correlation_id);
+        self
+    }
+
+    /// Restricts the query to changes made by `user_id`.
+    #[must_use]
+    pub const fn with_user(mut self, user_id: UserId) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Restricts the query to one aggregate.
+    #[must_use]
+    pub const fn with_aggregate(mut self, aggregate_id: Uuid) -> Self {
+        self.aggregate_id = Some(aggregate_id);
+        self
+    }
+
+    /// Restricts the query to records made from `from` up to but excluding
+    /// `until`.
+    #[must_use]
+    pub const fn between(mut self, from: DateTime<Utc>, until: DateTime<Utc>) -> Self {
+        self.occurred_from = Some(from);
+        self.occurred_until = Some(until);
+        self
+    }
+
+    /// Returns the correlation filter, if set.
+    #[must_use]
+    pub const fn correlation_id(&self) -> Option<CorrelationId> {
+        self.correlation_id
+    }
+
+    /// Returns the user filter, if set.
+    #[must_use]
+    pub const fn user_id(&self) -> Option<UserId> {
+        self.user_id
+    }
+
+    /// Returns the aggregate filter, if set.
+    #[must_use]
+    pub const fn aggregate_id(&self) -> Option<Uuid> {
+        self.aggregate_id
+    }
+
+    /// Returns the inclusive start of the time range, if set.
+    #[must_use]
+    pub const fn occurred_from(&self) -> Option<DateTime<Utc>> {
+        self.occurred_from
+    }
+
+    /// Returns the exclusive end of the time range, if set.
+    #[must_use]
+    pub const fn occurred_until(&self) -> Option<DateTime<Utc>> {
+        self.occurred_until
+    }
+
+    /// Returns whether `entry` satisfies every criterion.
+    #[must_use]
+    pub fn matches_entry(&self, entry: &AuditLogEntry) -> bool {
+        self.matches_cause(entry.correlation_id, entry.user_id)
+            && self.matches_subject(entry.row_id, entry.occurred_at)
+    }
+
+    /// Returns whether `event` satisfies every criterion.
+    #[must_use]
+    pub fn matches_event(&self, event: &RecordedEvent) -> bool {
+        self.matches_cause(event.correlation_id, event.user_id)
+            && self.matches_subject(Some(event.aggregate_id), event.occurred_at)
+    }
+
+    fn matches_cause(
+        &self,
+        correlation_id: Option<CorrelationId>,
+        user_id: Option<UserId>,
+    ) -> bool {
+        self.correlation_id
+            .is_none_or(|wanted| correlation_id == Some(wanted))
+            && self.user_id.is_none_or(|wanted| user_id == Some(wanted))
+    }
+
+    fn matches_subject(&self, aggregate_id: Option<Uuid>, occurred_at: DateTime<Utc>) -> bool {
+        self.aggregate_id
+            .is_none_or(|wanted| aggregate_id == Some(wanted))
+            && self.occurred_from.is_none_or(|from| occurred_at >= from)
+            && self.occurred_until.is_none_or(|until| occurred_at < until)
+    }
+}
@@ -0,0 +1,24 @@
+//! Reading the audit trail recorded by the database.
+//!
+//! Every write to the `messages` and `conversations` tables is captured by a
+//! trigger into `audit_logs`, together with the correlation, causation, user
+//! and session identifiers the adapters set as session variables, and every
+//! change of note is appended to `domain_events`. An
+//! [`ports::AuditLogRepository`] reads both back for a tenant, filtered by
+//! an [`domain::AuditQuery`] on correlation id, user, aggregate and time
+//! range. [`services::CausationService`] rebuilds the causal tree of the
+//! events recorded for one request. The module follows hexagonal
+//! architecture:
+//!
+//! - Domain types in [`domain`]
+//! - Port contracts in [`ports`]
+//! - Adapter implementations in [`adapters`]
+//! - Application services in [`services`]
+
+pub mod adapters;
+pub mod domain;
+pub mod ports;
+pub mod services;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,5 @@
+//! Port contracts for reading the audit trail.
+
+pub mod repository;
+
+pub use repository::{AuditLogError, AuditLogRepository, AuditLogResult};
@@ -0,0 +1,63 @@
+//! Port contract for querying audited changes and domain events.
+
+use crate::audit::domain::{AuditLogEntry, AuditQuery};
+use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
+use crate::projection::domain::RecordedEvent;
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type for audit log repository operations.
+pub type AuditLogResult<T> = Result<T, AuditLogError>;
+
+/// Read-only view of a tenant's audit trail.
+///
+/// The trail is written by database triggers and the adapters that record
+/// domain events, never through this port.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    /// Returns a page of the row changes matching `query`, oldest first.
+    async fn changes(
+        &self,
+        ctx: &RequestContext,
+        query: AuditQuery,
+        page: PageRequest,
+    ) -> AuditLogResult<Page<AuditLogEntry>>;
+
+    /// Returns a page of the domain events matching `query`, oldest first.
+    async fn events(
+        &self,
+        ctx: &RequestContext,
+        query: AuditQuery,
+        page: PageRequest,
+    ) -> AuditLogResult<Page<RecordedEvent>>;
+}
+
+/// Errors returned by audit log repository implementations.
+#[derive(Debug, Clone, Error)]
+pub enum AuditLogError {
+    /// Persistence-layer failure.
+    #[error("audit log persistence operation failed: {reason}")]
+    PersistenceFailed {
+        /// Human-readable reason from the failing dependency.
+        reason: String,
+    },
+    /// Persisted data failed validation.
+    #[error("invalid persisted audit log data: {0}")]
+    InvalidPersistedData(String),
+}
+
+impl AuditLogError {
+    /// Creates a persistence failure from an infrastructure error.
+    pub fn persistence_failed(err: impl std::error::Error) -> Self {
+        Self::PersistenceFailed {
+            reason: err.to_string(),
+        }
+    }
+
+    /// Creates an invalid persisted data error.
+    pub fn invalid_persisted_data(err: impl Into<String>) -> Self {
+        Self::InvalidPersistedData(err.into())
+    }
+}
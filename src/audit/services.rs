@@ -0,0 +1,61 @@
+//! Services over the audit trail.
+
+use super::domain::{AuditQuery, CausationGraph};
+use super::ports::{AuditLogRepository, AuditLogResult};
+use crate::context::{CorrelationId, RequestContext};
+use crate::pagination::{Limit, PageRequest};
+use std::sync::Arc;
+
+/// Rebuilds what happened during one request from its domain events.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::audit::{adapters::InMemoryAuditLogRepository, services::CausationService};
+/// use corbusier::context::RequestContext;
+/// use std::sync::Arc;
+///
+/// # async fn example(ctx: &RequestContext) -> Result<(), Box<dyn std::error::Error>> {
+/// let service = CausationService::new(Arc::new(InMemoryAuditLogRepository::new()));
+/// let graph = service.causation_graph(ctx, ctx.correlation_id()).await?;
+/// assert!(graph.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub struct CausationService {
+    audit_log: Arc<dyn AuditLogRepository>,
+}
+
+impl CausationService {
+    /// Creates a service reading events from `audit_log`.
+    #[must_use]
+    pub fn new(audit_log: Arc<dyn AuditLogRepository>) -> Self {
+        Self { audit_log }
+    }
+
+    /// Returns the causal tree of every event the tenant recorded under
+    /// `correlation_id`, such as a message that led to a tool call, a
+    /// handoff and a task update.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the events cannot be read.
+    pub async fn causation_graph(
+        &self,
+        ctx: &RequestContext,
+        correlation_id: CorrelationId,
+    ) -> AuditLogResult<CausationGraph> {
+        let query = AuditQuery::for_correlation(correlation_id);
+        let mut request = PageRequest::new(Limit::MAX);
+        let mut events = Vec::new();
+        loop {
+            let page = self.audit_log.events(ctx, query, request).await?;
+            let next = page.next_cursor();
+            events.extend(page.into_items());
+            let Some(cursor) = next else {
+                return Ok(CausationGraph::from_events(correlation_id, events));
+            };
+            request = request.with_cursor(cursor);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Tests for causation graphs and the service that builds them.
+
+use crate::audit::{
+    adapters::InMemoryAuditLogRepository,
+    domain::{CausationGraph, CausationNode, CausationNodeKind},
+    services::CausationService,
+};
+use crate::context::{CorrelationId, RequestContext};
+use crate::projection::domain::RecordedEvent;
+use crate::test_support::test_request_ctx;
+use chrono::{Duration, Utc};
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+fn event(event_type: &str, minutes: i64, cause: Option<&RecordedEvent>) -> RecordedEvent {
+    let mut recorded = RecordedEvent::new(
+        "Conversation",
+        Uuid::new_v4(),
+        event_type,
+        Utc::now() + Duration::minutes(minutes),
+    );
+    recorded.causation_id = cause.map(|parent| parent.id);
+    recorded
+}
+
+fn event_types(nodes: &[CausationNode]) -> Vec<&str> {
+    nodes
+        .iter()
+        .map(|node| node.event.event_type.as_str())
+        .collect()
+}
+
+#[rstest]
+#[case("MessageCreated", "Conversation", CausationNodeKind::Message)]
+#[case("ToolCallCompleted", "Message", CausationNodeKind::ToolCall)]
+#[case("HandoffCompleted", "Conversation", CausationNodeKind::Handoff)]
+#[case("TaskStateChanged", "Task", CausationNodeKind::Task)]
+#[case("Updated", "Task", CausationNodeKind::Task)]
+#[case("LabelsChanged", "Conversation", CausationNodeKind::Other)]
+fn kinds_follow_the_event_then_aggregate_type(
+    #[case] event_type: &str,
+    #[case] aggregate_type: &str,
+    #[case] expected: CausationNodeKind,
+) {
+    let recorded = RecordedEvent::new(aggregate_type, Uuid::new_v4(), event_type, Utc::now());
+
+    assert_eq!(CausationNodeKind::of(&recorded), expected);
+}
+
+#[rstest]
+fn events_nest_under_their_causes_oldest_first() {
+    let message = event("MessageCreated", 0, None);
+    let tool_call = event("ToolCallCompleted", 1, Some(&message));
+    let handoff = event("HandoffCompleted", 3, Some(&message));
+    let task = event("TaskStateChanged", 2, Some(&tool_call));
+
+    let graph = CausationGraph::from_events(
+        CorrelationId::new(),
+        vec![task, handoff, tool_call, message],
+    );
+
+    let [root] = graph.roots() else {
+        panic!("expected one root, got {}", graph.roots().len());
+    };
+    assert_eq!(root.kind, CausationNodeKind::Message);
+    assert_eq!(
+        event_types(&root.children),
+        ["ToolCallCompleted", "HandoffCompleted"]
+    );
+    let [tool_node, _] = root.children.as_slice() else {
+        panic!("expected two children");
+    };
+    assert_eq!(event_types(&tool_node.children), ["TaskStateChanged"]);
+    assert_eq!(graph.len(), 4);
+}
+
+#[rstest]
+fn events_caused_outside_the_request_are_roots() {
+    let elsewhere = event("MessageCreated", 0, None);
+    let reply = event("MessageCreated", 1, Some(&elsewhere));
+    let unrelated = event("TaskStateChanged", 2, None);
+
+    let graph = CausationGraph::from_events(CorrelationId::new(), vec![unrelated, reply]);
+
+    assert_eq!(
+        event_types(graph.roots()),
+        ["MessageCreated", "TaskStateChanged"]
+    );
+    assert!(graph.roots().iter().all(|root| root.children.is_empty()));
+}
+
+#[rstest]
+fn a_cycle_of_causes_is_broken_at_its_oldest_event() {
+    let mut first = event("MessageCreated", 0, None);
+    let second = event("ToolCallCompleted", 1, Some(&first));
+    first.causation_id = Some(second.id);
+
+    let graph = CausationGraph::from_events(CorrelationId::new(), vec![second, first]);
+
+    assert_eq!(event_types(graph.roots()), ["MessageCreated"]);
+    assert_eq!(graph.len(), 2);
+}
+
+#[rstest]
+#[tokio::test]
+async fn the_service_builds_the_graph_of_one_correlation(ctx: RequestContext) {
+    let trail = InMemoryAuditLogRepository::new();
+    let mut message = event("MessageCreated", 0, None);
+    message.correlation_id = Some(ctx.correlation_id());
+    let mut tool_call = event("ToolCallCompleted", 1, Some(&message));
+    tool_call.correlation_id = Some(ctx.correlation_id());
+    let other_request = event("MessageCreated", 2, None);
+    for recorded in [message, tool_call, other_request] {
+        trail.record_event(&ctx, recorded).await;
+    }
+    let service = CausationService::new(Arc::new(trail));
+
+    let graph = service
+        .causation_graph(&ctx, ctx.correlation_id())
+        .await
+        .expect("graph");
+
+    assert_eq!(graph.correlation_id(), ctx.correlation_id());
+    assert_eq!(event_types(graph.roots()), ["MessageCreated"]);
+    assert_eq!(graph.len(), 2);
+}
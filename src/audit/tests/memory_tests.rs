@@ -0,0 +1,89 @@
+//! Tests for the in-memory audit trail.
+
+use crate::audit::{
+    adapters::InMemoryAuditLogRepository,
+    domain::{AuditLogEntry, AuditOperation, AuditQuery},
+    ports::AuditLogRepository,
+};
+use crate::context::RequestContext;
+use crate::pagination::{Limit, PageRequest};
+use crate::projection::domain::RecordedEvent;
+use crate::test_support::test_request_ctx;
+use chrono::{Duration, Utc};
+use rstest::{fixture, rstest};
+use uuid::Uuid;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+#[rstest]
+#[tokio::test]
+async fn changes_are_returned_oldest_first_a_page_at_a_time(ctx: RequestContext) {
+    let trail = InMemoryAuditLogRepository::new();
+    let row_id = Uuid::new_v4();
+    let start = Utc::now();
+    for (minutes, operation) in [
+        (2, AuditOperation::Delete),
+        (0, AuditOperation::Insert),
+        (1, AuditOperation::Update),
+    ] {
+        let entry = AuditLogEntry::new(
+            "messages",
+            operation,
+            row_id,
+            start + Duration::minutes(minutes),
+        )
+        .made_by(ctx.user_id(), ctx.correlation_id());
+        trail.record_change(&ctx, entry).await;
+    }
+    let query = AuditQuery::for_aggregate(row_id);
+    let limit = Limit::new(2).expect("valid limit");
+
+    let first = trail
+        .changes(&ctx, query, PageRequest::new(limit))
+        .await
+        .expect("first page");
+    let cursor = first.next_cursor().expect("a second page");
+    let second = trail
+        .changes(&ctx, query, PageRequest::new(limit).with_cursor(cursor))
+        .await
+        .expect("second page");
+
+    let operations: Vec<_> = first
+        .iter()
+        .chain(second.iter())
+        .map(|entry| entry.operation)
+        .collect();
+    assert_eq!(
+        operations,
+        vec![
+            AuditOperation::Insert,
+            AuditOperation::Update,
+            AuditOperation::Delete
+        ]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn tenants_see_only_their_own_trail(ctx: RequestContext) {
+    let trail = InMemoryAuditLogRepository::new();
+    let mut event = RecordedEvent::new("Message", Uuid::new_v4(), "MessageCreated", Utc::now());
+    event.correlation_id = Some(ctx.correlation_id());
+    trail.record_event(&ctx, event.clone()).await;
+    let query = AuditQuery::for_correlation(ctx.correlation_id());
+
+    let own = trail
+        .events(&ctx, query, PageRequest::default())
+        .await
+        .expect("own events");
+    let other = trail
+        .events(&test_request_ctx(), query, PageRequest::default())
+        .await
+        .expect("other tenant's events");
+
+    assert_eq!(own.items(), &[event]);
+    assert!(other.is_empty());
+}
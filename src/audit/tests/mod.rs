@@ -0,0 +1,5 @@
+//! Unit tests for reading the audit trail.
+
+mod causation_tests;
+mod memory_tests;
+mod query_tests;
@@ -0,0 +1,87 @@
+//! Tests for matching audit entries and events against a query.
+
+use crate::audit::domain::{AuditLogEntry, AuditOperation, AuditQuery};
+use crate::context::{CorrelationId, UserId};
+use crate::projection::domain::RecordedEvent;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use rstest::{fixture, rstest};
+use uuid::Uuid;
+
+#[fixture]
+fn noon() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0)
+        .single()
+        .expect("valid timestamp")
+}
+
+#[rstest]
+#[case::insert("INSERT", AuditOperation::Insert)]
+#[case::update("UPDATE", AuditOperation::Update)]
+#[case::delete("DELETE", AuditOperation::Delete)]
+fn operations_round_trip_through_their_trigger_names(
+    #[case] name: &str,
+    #[case] operation: AuditOperation,
+) {
+    assert_eq!(AuditOperation::try_from(name), Ok(operation));
+    assert_eq!(operation.as_str(), name);
+}
+
+#[rstest]
+fn unknown_operations_are_rejected() {
+    let result = AuditOperation::try_from("TRUNCATE");
+    assert!(matches!(result, Err(error) if error.0 == "TRUNCATE"));
+}
+
+#[rstest]
+fn entries_match_on_every_set_criterion(noon: DateTime<Utc>) {
+    let (user_id, correlation_id, row_id) = (UserId::new(), CorrelationId::new(), Uuid::new_v4());
+    let entry = AuditLogEntry::new("messages", AuditOperation::Insert, row_id, noon)
+        .made_by(user_id, correlation_id);
+
+    assert!(AuditQuery::default().matches_entry(&entry));
+    assert!(
+        AuditQuery::for_correlation(correlation_id)
+            .with_user(user_id)
+            .with_aggregate(row_id)
+            .matches_entry(&entry)
+    );
+    assert!(!AuditQuery::for_correlation(CorrelationId::new()).matches_entry(&entry));
+    assert!(
+        !AuditQuery::default()
+            .with_user(UserId::new())
+            .matches_entry(&entry)
+    );
+    assert!(!AuditQuery::for_aggregate(Uuid::new_v4()).matches_entry(&entry));
+}
+
+#[rstest]
+#[case::inside(-1, 1, true)]
+#[case::at_start(0, 1, true)]
+#[case::at_end(-1, 0, false)]
+#[case::after(1, 2, false)]
+fn the_time_range_includes_its_start_and_excludes_its_end(
+    noon: DateTime<Utc>,
+    #[case] from_hours: i64,
+    #[case] until_hours: i64,
+    #[case] expected: bool,
+) {
+    let event = RecordedEvent::new("Message", Uuid::new_v4(), "MessageCreated", noon);
+    let query = AuditQuery::default().between(
+        noon + Duration::hours(from_hours),
+        noon + Duration::hours(until_hours),
+    );
+    assert_eq!(query.matches_event(&event), expected);
+}
+
+#[rstest]
+fn events_without_a_user_do_not_match_a_user_filter(noon: DateTime<Utc>) {
+    let aggregate_id = Uuid::new_v4();
+    let event = RecordedEvent::new("Conversation", aggregate_id, "ConversationCreated", noon);
+
+    assert!(AuditQuery::for_aggregate(aggregate_id).matches_event(&event));
+    assert!(
+        !AuditQuery::for_aggregate(aggregate_id)
+            .with_user(UserId::new())
+            .matches_event(&event)
+    );
+}
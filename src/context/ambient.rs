@@ -0,0 +1,68 @@
+//! The request context of the task currently running.
+//!
+//! [`RequestContext::scope`] makes a context ambient for the duration of a
+//! future and opens a `request` tracing span carrying its identifiers.
+//! Code with no context in hand, such as the persistence layer attributing
+//! audit rows, reads it back with [`RequestContext::current`].
+
+use super::{CausationId, RequestContext};
+use std::future::Future;
+use tracing::Instrument;
+
+tokio::task_local! {
+    static CURRENT: RequestContext;
+}
+
+impl RequestContext {
+    /// Runs `future` with this context as the ambient request context.
+    ///
+    /// The future runs inside a `request` span recording the tenant,
+    /// correlation, causation, user and session identifiers. Work spawned
+    /// onto other tasks does not inherit the context; scope it again there.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+    ///
+    /// # async fn example() {
+    /// let ctx = RequestContext::new(
+    ///     TenantId::new(),
+    ///     CorrelationId::new(),
+    ///     UserId::new(),
+    ///     SessionId::new(),
+    /// );
+    /// let seen = ctx.clone().scope(async { RequestContext::current() }).await;
+    /// assert_eq!(seen, Some(ctx));
+    /// # }
+    /// ```
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        let span = tracing::info_span!(
+            "request",
+            tenant_id = %self.tenant_id(),
+            correlation_id = %self.correlation_id(),
+            causation_id = ?self.causation_id().map(CausationId::into_inner),
+            user_id = %self.user_id(),
+            session_id = %self.session_id(),
+        );
+        CURRENT.scope(self, future.instrument(span)).await
+    }
+
+    /// Runs `f` with `ctx`, if any, as the ambient request context.
+    ///
+    /// Blocking work handed to another thread uses this to keep the context
+    /// of the task that handed it over.
+    pub(crate) fn scope_blocking<T>(ctx: Option<Self>, f: impl FnOnce() -> T) -> T {
+        match ctx {
+            Some(ambient) => CURRENT.sync_scope(ambient, f),
+            None => f(),
+        }
+    }
+
+    /// Returns the ambient request context, if the current task runs
+    /// inside [`RequestContext::scope`].
+    #[must_use]
+    pub fn current() -> Option<Self> {
+        CURRENT.try_with(Clone::clone).ok()
+    }
+}
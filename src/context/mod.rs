@@ -3,8 +3,11 @@
 //! This module provides the [`RequestContext`] struct that carries tenant
 //! identity, distributed tracing identifiers, and authenticated principal
 //! information through every repository and service call. It also provides
-//! the newtype identifiers used across bounded contexts.
+//! the newtype identifiers used across bounded contexts, and lets a task carry
+//! its context ambiently so that layers without one in hand, such as audit
+//! attribution, can still read it.
 
+mod ambient;
 pub mod ids;
 mod request_context;
 
@@ -134,3 +134,51 @@ fn request_context_equality_requires_matching_fields() {
     let ctx_c = RequestContext::new(TenantId::new(), correlation_id, user_id, session_id);
     assert_ne!(ctx_a, ctx_c);
 }
+
+// ── Ambient context tests ────────────────────────────────────────
+
+fn sample_context() -> RequestContext {
+    RequestContext::new(
+        TenantId::new(),
+        CorrelationId::new(),
+        UserId::new(),
+        SessionId::new(),
+    )
+}
+
+#[rstest]
+#[tokio::test]
+async fn current_is_none_outside_a_scope() {
+    assert_eq!(RequestContext::current(), None);
+}
+
+#[rstest]
+#[tokio::test]
+async fn scope_makes_the_context_current() {
+    let ctx = sample_context();
+
+    let seen = ctx.clone().scope(async { RequestContext::current() }).await;
+
+    assert_eq!(seen, Some(ctx));
+    assert_eq!(RequestContext::current(), None);
+}
+
+#[rstest]
+#[tokio::test]
+async fn blocking_work_keeps_the_scoped_context() {
+    let ctx = sample_context();
+
+    let seen = ctx
+        .clone()
+        .scope(async {
+            let ambient = RequestContext::current();
+            tokio::task::spawn_blocking(move || {
+                RequestContext::scope_blocking(ambient, RequestContext::current)
+            })
+            .await
+        })
+        .await
+        .expect("blocking task should complete");
+
+    assert_eq!(seen, Some(ctx));
+}
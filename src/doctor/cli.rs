@@ -0,0 +1,88 @@
+//! Parsing and execution of the `corbusier doctor` command.
+//!
+//! The command probes the adapters configured for the process on behalf of
+//! one tenant, whose scratch conversation receives the probe message:
+//!
+//! ```text
+//! corbusier doctor <tenant-id>
+//! ```
+
+use super::domain::ReadinessReport;
+use super::services::DoctorService;
+use crate::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Usage text for the `doctor` command.
+pub const DOCTOR_USAGE: &str = "usage: corbusier doctor <tenant-id>";
+
+/// A parsed `doctor` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoctorCommand {
+    tenant_id: TenantId,
+}
+
+/// Errors returned while parsing a `doctor` command line.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DoctorCommandError {
+    /// The arguments are not a single tenant ID.
+    #[error("{DOCTOR_USAGE}")]
+    Usage,
+    /// The tenant ID is not a UUID.
+    #[error("invalid tenant ID: {0}")]
+    InvalidTenant(#[from] uuid::Error),
+}
+
+impl DoctorCommand {
+    /// Parses the arguments following `doctor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoctorCommandError::Usage`] unless exactly one argument is
+    /// given, or [`DoctorCommandError::InvalidTenant`] when it is not a
+    /// UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use corbusier::doctor::cli::DoctorCommand;
+    ///
+    /// let command = DoctorCommand::parse(["7f9c24e5-2b4e-4c5a-9a55-2d2b7a8f4c11"])
+    ///     .expect("valid command");
+    /// assert_eq!(
+    ///     command.tenant_id().to_string(),
+    ///     "7f9c24e5-2b4e-4c5a-9a55-2d2b7a8f4c11"
+    /// );
+    /// ```
+    pub fn parse<I, S>(args: I) -> Result<Self, DoctorCommandError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut words = args.into_iter();
+        let (Some(tenant), None) = (words.next(), words.next()) else {
+            return Err(DoctorCommandError::Usage);
+        };
+        let tenant_id = TenantId::from_uuid(Uuid::parse_str(tenant.as_ref().trim())?);
+        Ok(Self { tenant_id })
+    }
+
+    /// Returns the tenant the probes run for.
+    #[must_use]
+    pub const fn tenant_id(&self) -> TenantId {
+        self.tenant_id
+    }
+
+    /// Runs `doctor` for the tenant and returns its report.
+    ///
+    /// The probes run under a fresh request context with no acting user.
+    pub async fn run(self, doctor: &DoctorService) -> ReadinessReport {
+        let ctx = RequestContext::new(
+            self.tenant_id,
+            CorrelationId::new(),
+            UserId::new(),
+            SessionId::new(),
+        );
+        doctor.run(&ctx).await
+    }
+}
@@ -0,0 +1,160 @@
+//! Readiness report types produced by the doctor.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::time::Duration;
+
+/// The kind of adapter a probe exercised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProbeComponent {
+    /// Conversation and message persistence.
+    MessageStore,
+    /// An agent backend runtime.
+    AgentBackend,
+    /// A hosted MCP server.
+    McpServer,
+}
+
+impl ProbeComponent {
+    /// Returns the serialised name of the component.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::MessageStore => "message_store",
+            Self::AgentBackend => "agent_backend",
+            Self::McpServer => "mcp_server",
+        }
+    }
+}
+
+/// Whether a probe succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProbeStatus {
+    /// The adapter did what was asked of it.
+    Passed,
+    /// The adapter failed or did not answer in time.
+    Failed,
+}
+
+/// The outcome of probing one adapter.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::doctor::domain::{ProbeComponent, ProbeResult, ProbeStatus};
+///
+/// let result = ProbeResult::failed(ProbeComponent::McpServer, "git", "not running");
+/// assert_eq!(result.status, ProbeStatus::Failed);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProbeResult {
+    /// The kind of adapter probed.
+    pub component: ProbeComponent,
+    /// Which adapter of that kind, such as a backend or server name.
+    pub target: String,
+    /// Whether the probe succeeded.
+    pub status: ProbeStatus,
+    /// What the probe did, or why it failed.
+    pub detail: String,
+    /// How long the probe took, in milliseconds.
+    pub elapsed_ms: u64,
+}
+
+impl ProbeResult {
+    /// Creates a passed result.
+    #[must_use]
+    pub fn passed(
+        component: ProbeComponent,
+        target: impl Into<String>,
+        detail: impl Into<String>,
+    ) -> Self {
+        Self::new(component, target.into(), ProbeStatus::Passed, detail.into())
+    }
+
+    /// Creates a failed result.
+    #[must_use]
+    pub fn failed(
+        component: ProbeComponent,
+        target: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::new(component, target.into(), ProbeStatus::Failed, reason.into())
+    }
+
+    const fn new(
+        component: ProbeComponent,
+        target: String,
+        status: ProbeStatus,
+        detail: String,
+    ) -> Self {
+        Self {
+            component,
+            target,
+            status,
+            detail,
+            elapsed_ms: 0,
+        }
+    }
+
+    /// Records how long the probe took.
+    #[must_use]
+    pub fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        self
+    }
+
+    /// Returns `true` if the probe passed.
+    #[must_use]
+    pub const fn is_passed(&self) -> bool {
+        matches!(self.status, ProbeStatus::Passed)
+    }
+}
+
+/// The results of one doctor run.
+///
+/// A deployment is ready when every probe passed. A run that probed
+/// nothing is ready too, since nothing configured is broken.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReadinessReport {
+    checked_at: DateTime<Utc>,
+    ready: bool,
+    probes: Vec<ProbeResult>,
+}
+
+impl ReadinessReport {
+    /// Creates a report from the results of a run started at `checked_at`.
+    #[must_use]
+    pub fn new(checked_at: DateTime<Utc>, probes: Vec<ProbeResult>) -> Self {
+        let ready = probes.iter().all(ProbeResult::is_passed);
+        Self {
+            checked_at,
+            ready,
+            probes,
+        }
+    }
+
+    /// Returns when the run started.
+    #[must_use]
+    pub const fn checked_at(&self) -> DateTime<Utc> {
+        self.checked_at
+    }
+
+    /// Returns `true` if every probe passed.
+    #[must_use]
+    pub const fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    /// Returns every probe result, in the order the probes ran.
+    #[must_use]
+    pub fn probes(&self) -> &[ProbeResult] {
+        &self.probes
+    }
+
+    /// Returns the probes that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &ProbeResult> {
+        self.probes.iter().filter(|probe| !probe.is_passed())
+    }
+}
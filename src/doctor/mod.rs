@@ -0,0 +1,22 @@
+//! Self-test of a deployed configuration.
+//!
+//! Health checks say whether the process is up and its database answers; the
+//! doctor says whether the adapters it was configured with actually work. A
+//! [`services::DoctorService`] exercises each configured adapter end to end
+//! — writing and reading back a probe message in a scratch conversation,
+//! opening and closing a session on each active agent backend, and checking
+//! the health and tool list of each running MCP server — and gathers the
+//! results into a [`domain::ReadinessReport`]. Operators run it with the
+//! `corbusier doctor` command or through the admin HTTP API. The module
+//! follows hexagonal architecture:
+//!
+//! - Report types in [`domain`]
+//! - The probing service in [`services`]
+//! - Command-line parsing in [`cli`]
+
+pub mod cli;
+pub mod domain;
+pub mod services;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,379 @@
+//! The doctor service that probes each configured adapter.
+//!
+//! Probes run one after another, each bounded by the probe timeout, so a
+//! hung adapter fails its own probe without holding up the report forever.
+//! Only adapters attached with the `with_*` builders are probed.
+
+use super::domain::{ProbeComponent, ProbeResult, ReadinessReport};
+use crate::agent_backend::{
+    domain::AgentBackendRegistration,
+    ports::{AgentRuntimePort, BackendRegistryRepository},
+};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ContentPart, Conversation, Message, Role, SequenceNumber, TextPart},
+    ports::{ConversationRepository, MessageRepository},
+};
+use crate::pagination::{Page, PageRequest};
+use crate::tool_registry::{
+    domain::{McpServerHealthStatus, McpServerRegistration},
+    ports::{McpServerHost, McpServerRegistryRepository},
+};
+use mockable::Clock;
+use serde_json::json;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// How long each probe may take unless configured otherwise.
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Target name reported for the message store probe.
+const MESSAGE_STORE_TARGET: &str = "conversations";
+
+/// Target name reported when an adapter's registry cannot be listed.
+const REGISTRY_TARGET: &str = "registry";
+
+/// Text of the probe message written to the scratch conversation.
+const PROBE_TEXT: &str = "corbusier doctor probe";
+
+struct MessageStore {
+    conversations: Arc<dyn ConversationRepository>,
+    messages: Arc<dyn MessageRepository>,
+}
+
+struct AgentBackends {
+    registry: Arc<dyn BackendRegistryRepository>,
+    runtime: Arc<dyn AgentRuntimePort>,
+}
+
+struct McpServers {
+    registry: Arc<dyn McpServerRegistryRepository>,
+    host: Arc<dyn McpServerHost>,
+}
+
+/// Service that exercises configured adapters and reports readiness.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::doctor::services::DoctorService;
+/// use corbusier::message::adapters::memory::{
+///     InMemoryConversationRepository, InMemoryMessageRepository,
+/// };
+/// use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// # async fn example() {
+/// let doctor = DoctorService::new(Arc::new(DefaultClock)).with_message_store(
+///     Arc::new(InMemoryConversationRepository::new()),
+///     Arc::new(InMemoryMessageRepository::new()),
+/// );
+/// let ctx = RequestContext::new(
+///     TenantId::new(),
+///     CorrelationId::new(),
+///     UserId::new(),
+///     SessionId::new(),
+/// );
+///
+/// let report = doctor.run(&ctx).await;
+/// assert!(report.is_ready());
+/// assert_eq!(report.probes().len(), 1);
+/// # }
+/// ```
+pub struct DoctorService {
+    clock: Arc<dyn Clock + Send + Sync>,
+    probe_timeout: Duration,
+    message_store: Option<MessageStore>,
+    agent_backends: Option<AgentBackends>,
+    mcp_servers: Option<McpServers>,
+}
+
+impl DoctorService {
+    /// Creates a service that probes nothing until adapters are attached.
+    #[must_use]
+    pub fn new(clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self {
+            clock,
+            probe_timeout: DEFAULT_PROBE_TIMEOUT,
+            message_store: None,
+            agent_backends: None,
+            mcp_servers: None,
+        }
+    }
+
+    /// Replaces how long each probe may take.
+    #[must_use]
+    pub const fn with_probe_timeout(mut self, timeout: Duration) -> Self {
+        self.probe_timeout = timeout;
+        self
+    }
+
+    /// Probes conversation and message persistence by writing and reading
+    /// back a message in a scratch conversation, which is then archived.
+    #[must_use]
+    pub fn with_message_store(
+        mut self,
+        conversations: Arc<dyn ConversationRepository>,
+        messages: Arc<dyn MessageRepository>,
+    ) -> Self {
+        self.message_store = Some(MessageStore {
+            conversations,
+            messages,
+        });
+        self
+    }
+
+    /// Probes each active backend by creating and tearing down a runtime
+    /// session for a scratch conversation.
+    #[must_use]
+    pub fn with_agent_backends(
+        mut self,
+        registry: Arc<dyn BackendRegistryRepository>,
+        runtime: Arc<dyn AgentRuntimePort>,
+    ) -> Self {
+        self.agent_backends = Some(AgentBackends { registry, runtime });
+        self
+    }
+
+    /// Probes each running MCP server by checking its health and listing
+    /// its tools. Servers that are not running are not probed.
+    #[must_use]
+    pub fn with_mcp_servers(
+        mut self,
+        registry: Arc<dyn McpServerRegistryRepository>,
+        host: Arc<dyn McpServerHost>,
+    ) -> Self {
+        self.mcp_servers = Some(McpServers { registry, host });
+        self
+    }
+
+    /// Returns how long each probe may take.
+    #[must_use]
+    pub const fn probe_timeout(&self) -> Duration {
+        self.probe_timeout
+    }
+
+    /// Probes every attached adapter on behalf of `ctx`'s tenant.
+    ///
+    /// Failures are recorded in the report rather than returned.
+    pub async fn run(&self, ctx: &RequestContext) -> ReadinessReport {
+        let checked_at = self.clock.utc();
+        let mut probes = Vec::new();
+        if let Some(store) = &self.message_store {
+            let probe = self.probe_message_store(ctx, store);
+            probes.push(
+                self.timed(ProbeComponent::MessageStore, MESSAGE_STORE_TARGET, probe)
+                    .await,
+            );
+        }
+        if let Some(backends) = &self.agent_backends {
+            probes.extend(self.probe_agent_backends(ctx, backends).await);
+        }
+        if let Some(servers) = &self.mcp_servers {
+            probes.extend(self.probe_mcp_servers(ctx, servers).await);
+        }
+        ReadinessReport::new(checked_at, probes)
+    }
+
+    async fn probe_message_store(
+        &self,
+        ctx: &RequestContext,
+        store: &MessageStore,
+    ) -> Result<String, String> {
+        let clock = &*self.clock;
+        let mut conversation = Conversation::new(clock).with_context(json!({"doctor_probe": true}));
+        store
+            .conversations
+            .store(ctx, &conversation)
+            .await
+            .map_err(|err| format!("storing the scratch conversation failed: {err}"))?;
+        let message = Message::new(
+            conversation.id(),
+            Role::System,
+            vec![ContentPart::Text(TextPart::new(PROBE_TEXT))],
+            SequenceNumber::new(1),
+            clock,
+        )
+        .map_err(|err| err.to_string())?;
+        store
+            .messages
+            .store(ctx, &message)
+            .await
+            .map_err(|err| format!("storing the probe message failed: {err}"))?;
+        let read_back = store
+            .messages
+            .find_by_id(ctx, message.id())
+            .await
+            .map_err(|err| format!("reading the probe message failed: {err}"))?;
+        if read_back.as_ref().map(Message::content) != Some(message.content()) {
+            return Err(format!("probe message {} did not read back", message.id()));
+        }
+        conversation.archive(clock).map_err(|err| err.to_string())?;
+        store
+            .conversations
+            .update(ctx, &conversation)
+            .await
+            .map_err(|err| format!("archiving the scratch conversation failed: {err}"))?;
+        Ok(format!(
+            "wrote and read back message {} in conversation {}",
+            message.id(),
+            conversation.id()
+        ))
+    }
+
+    async fn probe_agent_backends(
+        &self,
+        ctx: &RequestContext,
+        backends: &AgentBackends,
+    ) -> Vec<ProbeResult> {
+        let component = ProbeComponent::AgentBackend;
+        let started = Instant::now();
+        let listed = self
+            .bounded(async {
+                all_pages(|page| backends.registry.list_active(ctx, page))
+                    .await
+                    .map_err(|err| format!("listing active backends failed: {err}"))
+            })
+            .await;
+        let registrations = match listed {
+            Ok(registrations) => registrations,
+            Err(reason) => {
+                return vec![
+                    ProbeResult::failed(component, REGISTRY_TARGET, reason)
+                        .with_elapsed(started.elapsed()),
+                ];
+            }
+        };
+        let mut probes = Vec::with_capacity(registrations.len());
+        for backend in &registrations {
+            let probe = ping_backend(backends.runtime.as_ref(), backend);
+            probes.push(self.timed(component, backend.name().as_str(), probe).await);
+        }
+        probes
+    }
+
+    async fn probe_mcp_servers(
+        &self,
+        ctx: &RequestContext,
+        servers: &McpServers,
+    ) -> Vec<ProbeResult> {
+        let component = ProbeComponent::McpServer;
+        let started = Instant::now();
+        let listed = self
+            .bounded(async {
+                all_pages(|page| servers.registry.list_all(ctx, page))
+                    .await
+                    .map_err(|err| format!("listing MCP servers failed: {err}"))
+            })
+            .await;
+        let registrations = match listed {
+            Ok(registrations) => registrations,
+            Err(reason) => {
+                return vec![
+                    ProbeResult::failed(component, REGISTRY_TARGET, reason)
+                        .with_elapsed(started.elapsed()),
+                ];
+            }
+        };
+        let mut probes = Vec::new();
+        for server in registrations
+            .iter()
+            .filter(|server| server.lifecycle_state().can_query_tools())
+        {
+            let probe = handshake_mcp_server(servers.host.as_ref(), ctx, server);
+            probes.push(self.timed(component, server.name().as_str(), probe).await);
+        }
+        probes
+    }
+
+    /// Runs `probe` under the probe timeout and records its outcome.
+    async fn timed(
+        &self,
+        component: ProbeComponent,
+        target: &str,
+        probe: impl Future<Output = Result<String, String>>,
+    ) -> ProbeResult {
+        let started = Instant::now();
+        let result = match self.bounded(probe).await {
+            Ok(detail) => ProbeResult::passed(component, target, detail),
+            Err(reason) => ProbeResult::failed(component, target, reason),
+        };
+        result.with_elapsed(started.elapsed())
+    }
+
+    /// Runs `probe`, failing it when it outlasts the probe timeout.
+    async fn bounded<T>(
+        &self,
+        probe: impl Future<Output = Result<T, String>>,
+    ) -> Result<T, String> {
+        tokio::time::timeout(self.probe_timeout, probe)
+            .await
+            .unwrap_or_else(|_| {
+                Err(format!(
+                    "no answer within {} ms",
+                    self.probe_timeout.as_millis()
+                ))
+            })
+    }
+}
+
+/// Opens and closes a runtime session on `backend`.
+async fn ping_backend(
+    runtime: &dyn AgentRuntimePort,
+    backend: &AgentBackendRegistration,
+) -> Result<String, String> {
+    let session = runtime
+        .create_session(backend, Uuid::new_v4())
+        .await
+        .map_err(|err| err.to_string())?;
+    runtime
+        .teardown_session(backend, &session)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok("created and tore down a scratch session".to_owned())
+}
+
+/// Checks that `server` reports itself healthy and lists its tools.
+async fn handshake_mcp_server(
+    host: &dyn McpServerHost,
+    ctx: &RequestContext,
+    server: &McpServerRegistration,
+) -> Result<String, String> {
+    let health = host
+        .health(ctx, server)
+        .await
+        .map_err(|err| err.to_string())?;
+    if health.status() != McpServerHealthStatus::Healthy {
+        return Err(health.message().map_or_else(
+            || format!("server reports {}", health.status()),
+            |message| format!("server reports {}: {message}", health.status()),
+        ));
+    }
+    let tools = host
+        .list_tools(ctx, server)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(format!("healthy with {} tools", tools.len()))
+}
+
+/// Collects every page `fetch` returns, starting from the first.
+async fn all_pages<T, E, F, Fut>(mut fetch: F) -> Result<Vec<T>, E>
+where
+    F: FnMut(PageRequest) -> Fut,
+    Fut: Future<Output = Result<Page<T>, E>>,
+{
+    let mut request = PageRequest::default();
+    let mut items = Vec::new();
+    loop {
+        let page = fetch(request).await?;
+        let next = page.next_cursor();
+        items.extend(page.into_items());
+        let Some(cursor) = next else {
+            return Ok(items);
+        };
+        request = request.with_cursor(cursor);
+    }
+}
@@ -0,0 +1,27 @@
+//! Unit tests for `corbusier doctor` argument parsing.
+
+use crate::doctor::cli::{DoctorCommand, DoctorCommandError};
+use rstest::rstest;
+
+#[rstest]
+fn parses_a_single_tenant_id() -> Result<(), eyre::Report> {
+    let command = DoctorCommand::parse([" 7f9c24e5-2b4e-4c5a-9a55-2d2b7a8f4c11 "])?;
+
+    eyre::ensure!(command.tenant_id().to_string() == "7f9c24e5-2b4e-4c5a-9a55-2d2b7a8f4c11");
+    Ok(())
+}
+
+#[rstest]
+#[case::no_arguments(&[])]
+#[case::extra_argument(&["7f9c24e5-2b4e-4c5a-9a55-2d2b7a8f4c11", "now"])]
+fn rejects_anything_but_one_argument(#[case] args: &[&str]) {
+    assert_eq!(DoctorCommand::parse(args), Err(DoctorCommandError::Usage));
+}
+
+#[rstest]
+fn rejects_a_malformed_tenant_id() {
+    assert!(matches!(
+        DoctorCommand::parse(["tenant-a"]),
+        Err(DoctorCommandError::InvalidTenant(_))
+    ));
+}
@@ -0,0 +1,4 @@
+//! Unit tests for the doctor self-test.
+
+mod cli_tests;
+mod service_tests;
@@ -0,0 +1,170 @@
+//! Unit tests for the doctor service.
+
+use crate::agent_backend::{
+    adapters::memory::{InMemoryAgentRuntime, InMemoryBackendRegistry},
+    domain::{AgentBackendRegistration, AgentCapabilities, BackendInfo, BackendName},
+    ports::BackendRegistryRepository,
+};
+use crate::context::RequestContext;
+use crate::doctor::{
+    domain::{ProbeComponent, ProbeStatus},
+    services::DoctorService,
+};
+use crate::message::adapters::memory::{InMemoryConversationRepository, InMemoryMessageRepository};
+use crate::test_support::test_request_ctx;
+use crate::tool_registry::{
+    adapters::{InMemoryMcpServerHost, memory::InMemoryMcpServerRegistry},
+    domain::{McpServerHealthSnapshot, McpServerName, McpServerRegistration, McpTransport},
+    ports::{McpServerHost, McpServerRegistryRepository},
+};
+use chrono::Utc;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+fn doctor() -> DoctorService {
+    DoctorService::new(Arc::new(DefaultClock))
+}
+
+async fn register_backend(
+    registry: &InMemoryBackendRegistry,
+    ctx: &RequestContext,
+    name: &str,
+) -> Result<AgentBackendRegistration, eyre::Report> {
+    let registration = AgentBackendRegistration::new(
+        BackendName::new(name)?,
+        AgentCapabilities::new(true, true),
+        BackendInfo::new(name, "1.0.0", "test-provider")?,
+        &DefaultClock,
+    );
+    registry.register(ctx, &registration).await?;
+    Ok(registration)
+}
+
+async fn register_server(
+    registry: &InMemoryMcpServerRegistry,
+    host: &InMemoryMcpServerHost,
+    ctx: &RequestContext,
+    name: &str,
+) -> Result<McpServerRegistration, eyre::Report> {
+    let mut server = McpServerRegistration::new(
+        McpServerName::new(name)?,
+        McpTransport::stdio("mcp-server")?,
+        &DefaultClock,
+    );
+    host.start(ctx, &server).await?;
+    server.mark_started(McpServerHealthSnapshot::healthy(Utc::now()), &DefaultClock)?;
+    registry.register(ctx, &server).await?;
+    Ok(server)
+}
+
+#[rstest]
+#[tokio::test]
+async fn a_doctor_with_nothing_attached_is_ready(ctx: RequestContext) {
+    let report = doctor().run(&ctx).await;
+
+    assert!(report.is_ready());
+    assert!(report.probes().is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn message_store_probe_writes_and_reads_back(
+    ctx: RequestContext,
+) -> Result<(), eyre::Report> {
+    let report = doctor()
+        .with_message_store(
+            Arc::new(InMemoryConversationRepository::new()),
+            Arc::new(InMemoryMessageRepository::new()),
+        )
+        .run(&ctx)
+        .await;
+
+    let [probe] = report.probes() else {
+        eyre::bail!("expected one probe, got {:?}", report.probes());
+    };
+    eyre::ensure!(probe.component == ProbeComponent::MessageStore);
+    eyre::ensure!(probe.status == ProbeStatus::Passed, "{}", probe.detail);
+    eyre::ensure!(report.is_ready());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn failing_backend_fails_only_its_own_probe(ctx: RequestContext) -> Result<(), eyre::Report> {
+    let registry = Arc::new(InMemoryBackendRegistry::new());
+    let runtime = Arc::new(InMemoryAgentRuntime::new());
+    register_backend(&registry, &ctx, "healthy_backend").await?;
+    let broken = register_backend(&registry, &ctx, "broken_backend").await?;
+    runtime.fail_session_creation_for(broken.id())?;
+
+    let report = doctor()
+        .with_agent_backends(registry, runtime)
+        .run(&ctx)
+        .await;
+
+    eyre::ensure!(!report.is_ready());
+    eyre::ensure!(report.probes().len() == 2);
+    let failed: Vec<&str> = report
+        .failures()
+        .map(|probe| probe.target.as_str())
+        .collect();
+    eyre::ensure!(failed == ["broken_backend"], "failures: {failed:?}");
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn unhealthy_mcp_server_fails_with_its_diagnostic(
+    ctx: RequestContext,
+) -> Result<(), eyre::Report> {
+    let registry = Arc::new(InMemoryMcpServerRegistry::new());
+    let host = Arc::new(InMemoryMcpServerHost::new());
+    register_server(&registry, &host, &ctx, "workspace_tools").await?;
+    let broken = register_server(&registry, &host, &ctx, "git_tools").await?;
+    host.set_unhealthy(broken.id(), "socket closed")?;
+
+    let report = doctor().with_mcp_servers(registry, host).run(&ctx).await;
+
+    eyre::ensure!(!report.is_ready());
+    eyre::ensure!(report.probes().len() == 2);
+    let failures: Vec<_> = report.failures().collect();
+    let [failure] = failures.as_slice() else {
+        eyre::bail!("expected one failure, got {:?}", report.probes());
+    };
+    eyre::ensure!(failure.target == "git_tools");
+    eyre::ensure!(
+        failure.detail.contains("socket closed"),
+        "{}",
+        failure.detail
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn mcp_servers_that_are_not_running_are_skipped(
+    ctx: RequestContext,
+) -> Result<(), eyre::Report> {
+    let registry = Arc::new(InMemoryMcpServerRegistry::new());
+    let server = McpServerRegistration::new(
+        McpServerName::new("idle_tools")?,
+        McpTransport::stdio("mcp-server")?,
+        &DefaultClock,
+    );
+    registry.register(&ctx, &server).await?;
+
+    let report = doctor()
+        .with_mcp_servers(registry, Arc::new(InMemoryMcpServerHost::new()))
+        .run(&ctx)
+        .await;
+
+    eyre::ensure!(report.is_ready());
+    eyre::ensure!(report.probes().is_empty());
+    Ok(())
+}
@@ -0,0 +1,13 @@
+//! Errors raised when converting request DTOs into domain values.
+
+use crate::message::domain::ContentHashError;
+use thiserror::Error;
+
+/// Errors returned when a request DTO cannot be converted into a domain
+/// value.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DtoError {
+    /// A blob reference is not a valid content hash.
+    #[error(transparent)]
+    InvalidContentHash(#[from] ContentHashError),
+}
@@ -0,0 +1,20 @@
+//! Versioned wire types for the public API surfaces.
+//!
+//! Adapters that speak to the outside world (HTTP today, and any future
+//! gRPC or CLI surface) serialise these types rather than domain types, so
+//! domain refactors do not change what clients see. Each version lives in
+//! its own module and its wire format is frozen once released: a change
+//! that would alter the JSON adds a new version module alongside the old
+//! one.
+//!
+//! Domain values convert into response types with [`From`]. Request types
+//! convert into domain values with [`TryFrom`], failing with [`DtoError`]
+//! when a field cannot be represented in the domain.
+
+mod error;
+pub mod v1;
+
+pub use error::DtoError;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,6 @@
+//! Unit tests for the versioned wire types.
+//!
+//! Each version's tests pin its JSON to fixed fixtures, so any change to
+//! the wire format fails here rather than in a client.
+
+mod v1_compatibility_tests;
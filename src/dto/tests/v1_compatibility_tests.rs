@@ -0,0 +1,210 @@
+//! Compatibility tests pinning the v1 wire format.
+
+use crate::dto::{
+    DtoError,
+    v1::{
+        AppendMessageRequestDto, ContentPartDto, ConversationHistoryResponseDto,
+        ConversationResponseDto, MessageResponseDto, RoleDto,
+    },
+};
+use crate::message::domain::{
+    AttachmentPart, CitationPart, CitationSource, CitationSpan, ContentHash, ContentHashError,
+    ContentPart, Conversation, ConversationId, ConversationState, ImagePart, ImageThumbnail,
+    Message, MessageId, MessageMetadata, ReasoningPart, Role, SequenceNumber, TextPart,
+    ToolCallPart, ToolResultPart,
+};
+use crate::message::services::AppendMessageRequest;
+use crate::pagination::{Cursor, Page};
+use chrono::{DateTime, TimeZone, Utc};
+use rstest::rstest;
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+const CONVERSATION_ID: &str = "7f9c24e5-2b4e-4c5a-9a55-2d2b7a8f4c11";
+const MESSAGE_ID: &str = "0b6f3c2d-8e1a-4f7b-b6c4-5d9e8a7f6b01";
+
+fn conversation_id() -> ConversationId {
+    ConversationId::from_uuid(Uuid::parse_str(CONVERSATION_ID).expect("valid uuid"))
+}
+
+fn timestamp() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0)
+        .single()
+        .expect("valid timestamp")
+}
+
+fn message() -> Message {
+    Message::from_persisted(
+        MessageId::from_uuid(Uuid::parse_str(MESSAGE_ID).expect("valid uuid")),
+        conversation_id(),
+        Role::Assistant,
+        vec![
+            ContentPart::Text(TextPart::new("Running the tests")),
+            ContentPart::ToolCall(ToolCallPart::new(
+                "call-1",
+                "run_tests",
+                json!({"filter": "dto"}),
+            )),
+        ],
+        MessageMetadata::with_agent_backend("claude_code_sdk"),
+        timestamp(),
+        SequenceNumber::new(3),
+    )
+    .expect("valid message")
+}
+
+fn message_json() -> Value {
+    json!({
+        "id": MESSAGE_ID,
+        "conversation_id": CONVERSATION_ID,
+        "role": "assistant",
+        "content": [
+            {"type": "text", "text": "Running the tests"},
+            {"type": "tool_call", "call_id": "call-1", "name": "run_tests",
+             "arguments": {"filter": "dto"}}
+        ],
+        "metadata": {"agent_backend": "claude_code_sdk"},
+        "created_at": "2026-03-01T12:00:00Z",
+        "sequence_number": 3
+    })
+}
+
+#[rstest]
+fn conversation_response_matches_v1_fixture() {
+    let conversation = Conversation::from_persisted(
+        conversation_id(),
+        ConversationState::Archived,
+        timestamp(),
+        timestamp(),
+    );
+
+    let value = serde_json::to_value(ConversationResponseDto::from(conversation))
+        .expect("serialise conversation");
+
+    assert_eq!(
+        value,
+        json!({
+            "conversation": {
+                "id": CONVERSATION_ID,
+                "state": "archived",
+                "created_at": "2026-03-01T12:00:00Z",
+                "updated_at": "2026-03-01T12:00:00Z"
+            }
+        })
+    );
+}
+
+#[rstest]
+fn message_response_matches_v1_fixture() {
+    let value =
+        serde_json::to_value(MessageResponseDto::from(message())).expect("serialise message");
+
+    assert_eq!(value, json!({"message": message_json()}));
+}
+
+#[rstest]
+fn history_response_matches_v1_fixture() {
+    let page = Page::new(vec![message()], Some(Cursor::at_number(3, Uuid::nil())));
+
+    let value = serde_json::to_value(ConversationHistoryResponseDto::new(conversation_id(), page))
+        .expect("serialise history");
+
+    assert_eq!(
+        value,
+        json!({
+            "conversation_id": CONVERSATION_ID,
+            "messages": [message_json()],
+            "next_cursor": Cursor::at_number(3, Uuid::nil()).to_string()
+        })
+    );
+}
+
+#[rstest]
+#[case::tool_result(
+    ContentPart::ToolResult(ToolResultPart::success("call-1", json!({"passed": 12}))),
+    json!({"type": "tool_result", "call_id": "call-1", "content": {"passed": 12}, "success": true})
+)]
+#[case::reasoning(
+    ContentPart::Reasoning(ReasoningPart::new("opaque").into_redacted()),
+    json!({"type": "reasoning", "text": "opaque", "redacted": true})
+)]
+#[case::attachment(
+    ContentPart::Attachment(AttachmentPart::new("text/plain", "SGk=").with_name("hi.txt")),
+    json!({"type": "attachment", "mime_type": "text/plain", "name": "hi.txt", "data": "SGk="})
+)]
+#[case::image(
+    ContentPart::Image(
+        ImagePart::new("image/png", "iVBORw0KGgo=", 640, 480)
+            .with_thumbnail(ImageThumbnail::new(ContentHash::of(b"thumb"), 64, 48))
+    ),
+    json!({
+        "type": "image", "mime_type": "image/png", "data": "iVBORw0KGgo=",
+        "width": 640, "height": 480,
+        "thumbnail": {"blob": ContentHash::of(b"thumb").to_string(), "width": 64, "height": 48}
+    })
+)]
+#[case::citation(
+    ContentPart::Citation(
+        CitationPart::new(CitationSource::uri("https://example.com/rfc"), vec![CitationSpan::new(0, 4, 9)])
+            .with_title("RFC")
+    ),
+    json!({
+        "type": "citation", "source": {"kind": "uri", "uri": "https://example.com/rfc"},
+        "spans": [{"part_index": 0, "start": 4, "end": 9}], "title": "RFC"
+    })
+)]
+fn content_parts_round_trip_through_v1(#[case] part: ContentPart, #[case] expected: Value) {
+    let value = serde_json::to_value(ContentPartDto::from(part.clone())).expect("serialise part");
+
+    assert_eq!(value, expected);
+    let dto: ContentPartDto = serde_json::from_value(value).expect("deserialise part");
+    assert_eq!(ContentPart::try_from(dto), Ok(part));
+}
+
+#[rstest]
+fn append_request_accepts_v1_body() {
+    let body: AppendMessageRequestDto = serde_json::from_value(json!({
+        "role": "user",
+        "content": [
+            {"type": "text", "text": "Hello"},
+            {"type": "tool_result", "call_id": "call-1", "content": "ok"}
+        ]
+    }))
+    .expect("valid v1 body");
+
+    let request = body
+        .into_request(conversation_id())
+        .expect("convertible body");
+
+    assert_eq!(
+        request,
+        AppendMessageRequest::new(
+            conversation_id(),
+            Role::User,
+            vec![
+                ContentPart::Text(TextPart::new("Hello")),
+                ContentPart::ToolResult(ToolResultPart::success("call-1", json!("ok"))),
+            ],
+        )
+    );
+}
+
+#[rstest]
+fn append_request_rejects_malformed_blob_references() {
+    let body = AppendMessageRequestDto {
+        role: RoleDto::User,
+        content: vec![
+            serde_json::from_value(json!({
+                "type": "attachment", "mime_type": "image/png", "data": "", "blob": "not-a-hash"
+            }))
+            .expect("valid v1 part"),
+        ],
+    };
+
+    let result = body.into_request(conversation_id());
+
+    assert!(matches!(
+        result,
+        Err(DtoError::InvalidContentHash(ContentHashError(ref hash))) if hash == "not-a-hash"
+    ));
+}
@@ -0,0 +1,391 @@
+//! Version 1 message content parts.
+
+use crate::dto::DtoError;
+use crate::message::domain::{
+    AttachmentPart, CitationPart, CitationSource, CitationSpan, ContentHash, ContentPart,
+    CustomPart, ImagePart, ImageThumbnail, MessageId, ReasoningPart, RedactedPart, TextPart,
+    ToolCallPart, ToolResultPart,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// A single content part of a message, tagged by `type`.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::dto::v1::ContentPartDto;
+/// use serde_json::json;
+///
+/// let part: ContentPartDto = serde_json::from_value(json!({"type": "text", "text": "Hi"}))
+///     .expect("valid text part");
+/// assert_eq!(part, ContentPartDto::Text { text: "Hi".to_owned() });
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPartDto {
+    /// Plain text.
+    Text {
+        /// The text content.
+        text: String,
+    },
+    /// Reasoning an assistant produced before its answer.
+    Reasoning {
+        /// The reasoning, or the backend's opaque form when redacted.
+        text: String,
+        /// Whether the backend redacted the reasoning; absent means `false`.
+        #[serde(default)]
+        redacted: bool,
+    },
+    /// A tool call requested by an assistant.
+    ToolCall {
+        /// Identifier correlating the call with its result.
+        call_id: String,
+        /// Name of the tool.
+        name: String,
+        /// Arguments passed to the tool.
+        arguments: Value,
+    },
+    /// The result of a tool call.
+    ToolResult {
+        /// Identifier of the call this result answers.
+        call_id: String,
+        /// The tool's output.
+        content: Value,
+        /// Whether the call succeeded; absent means `true`.
+        #[serde(default = "default_success")]
+        success: bool,
+    },
+    /// A file attachment.
+    Attachment(AttachmentDto),
+    /// An image with its dimensions.
+    Image(ImageDto),
+    /// A source supporting spans of the message's text.
+    Citation(CitationDto),
+    /// A payload of a downstream-registered kind.
+    Custom {
+        /// Registered kind of the payload.
+        kind: String,
+        /// The payload.
+        data: Value,
+    },
+    /// Placeholder for redacted content.
+    Redacted {
+        /// Why the content was redacted.
+        reason: String,
+        /// When the content was redacted.
+        redacted_at: DateTime<Utc>,
+    },
+}
+
+const fn default_success() -> bool {
+    true
+}
+
+/// The fields of [`ContentPartDto::Attachment`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttachmentDto {
+    /// MIME type of the attachment.
+    pub mime_type: String,
+    /// Display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Base64 or plain-text data.
+    pub data: String,
+    /// Size in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
+    /// Content hash of externally stored data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
+}
+
+/// The fields of [`ContentPartDto::Image`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageDto {
+    /// MIME type of the image.
+    pub mime_type: String,
+    /// Display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Base64 image data.
+    pub data: String,
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// Text describing the image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt_text: Option<String>,
+    /// A reduced copy of the image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<ImageThumbnailDto>,
+    /// Content hash of externally stored data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
+}
+
+/// The fields of [`ContentPartDto::Citation`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CitationDto {
+    /// Where the cited material comes from.
+    pub source: CitationSourceDto,
+    /// The cited spans of sibling text parts.
+    pub spans: Vec<CitationSpanDto>,
+    /// Display title of the source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// The source of a [`CitationDto`], tagged by `kind`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CitationSourceDto {
+    /// A document addressed by URI.
+    Uri {
+        /// The document's URI.
+        uri: String,
+    },
+    /// Another message in the conversation.
+    Message {
+        /// Identifier of the cited message.
+        message_id: Uuid,
+    },
+}
+
+/// A cited character range within a [`CitationDto`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CitationSpanDto {
+    /// Index of the text part in the message content.
+    pub part_index: usize,
+    /// Offset of the first cited character.
+    pub start: usize,
+    /// Offset one past the last cited character.
+    pub end: usize,
+}
+
+/// A thumbnail reference within an [`ImageDto`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageThumbnailDto {
+    /// Content hash of the thumbnail blob.
+    pub blob: String,
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+}
+
+impl From<ImageThumbnail> for ImageThumbnailDto {
+    fn from(thumbnail: ImageThumbnail) -> Self {
+        Self {
+            blob: thumbnail.blob.to_string(),
+            width: thumbnail.width,
+            height: thumbnail.height,
+        }
+    }
+}
+
+impl TryFrom<ImageThumbnailDto> for ImageThumbnail {
+    type Error = DtoError;
+
+    fn try_from(dto: ImageThumbnailDto) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            ContentHash::parse(&dto.blob)?,
+            dto.width,
+            dto.height,
+        ))
+    }
+}
+
+impl From<ContentPart> for ContentPartDto {
+    fn from(part: ContentPart) -> Self {
+        match part {
+            ContentPart::Text(TextPart { text }) => Self::Text { text },
+            ContentPart::Reasoning(ReasoningPart { text, redacted }) => {
+                Self::Reasoning { text, redacted }
+            }
+            ContentPart::ToolCall(ToolCallPart {
+                call_id,
+                name,
+                arguments,
+            }) => Self::ToolCall {
+                call_id,
+                name,
+                arguments,
+            },
+            ContentPart::ToolResult(ToolResultPart {
+                call_id,
+                content,
+                success,
+            }) => Self::ToolResult {
+                call_id,
+                content,
+                success,
+            },
+            ContentPart::Attachment(attachment) => Self::Attachment(attachment.into()),
+            ContentPart::Image(image) => Self::Image(image.into()),
+            ContentPart::Citation(citation) => Self::Citation(citation.into()),
+            ContentPart::Custom(CustomPart { kind, data }) => Self::Custom { kind, data },
+            ContentPart::Redacted(RedactedPart {
+                reason,
+                redacted_at,
+            }) => Self::Redacted {
+                reason,
+                redacted_at,
+            },
+        }
+    }
+}
+
+impl From<AttachmentPart> for AttachmentDto {
+    fn from(attachment: AttachmentPart) -> Self {
+        Self {
+            mime_type: attachment.mime_type,
+            name: attachment.name,
+            data: attachment.data,
+            size_bytes: attachment.size_bytes,
+            blob: attachment.blob.map(|hash| hash.to_string()),
+        }
+    }
+}
+
+impl TryFrom<AttachmentDto> for AttachmentPart {
+    type Error = DtoError;
+
+    fn try_from(dto: AttachmentDto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            mime_type: dto.mime_type,
+            name: dto.name,
+            data: dto.data,
+            size_bytes: dto.size_bytes,
+            blob: parse_blob(dto.blob)?,
+        })
+    }
+}
+
+impl From<ImagePart> for ImageDto {
+    fn from(image: ImagePart) -> Self {
+        Self {
+            mime_type: image.mime_type,
+            name: image.name,
+            data: image.data,
+            width: image.width,
+            height: image.height,
+            alt_text: image.alt_text,
+            thumbnail: image.thumbnail.map(ImageThumbnailDto::from),
+            blob: image.blob.map(|hash| hash.to_string()),
+        }
+    }
+}
+
+impl TryFrom<ImageDto> for ImagePart {
+    type Error = DtoError;
+
+    fn try_from(dto: ImageDto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            mime_type: dto.mime_type,
+            name: dto.name,
+            data: dto.data,
+            width: dto.width,
+            height: dto.height,
+            alt_text: dto.alt_text,
+            thumbnail: dto.thumbnail.map(ImageThumbnail::try_from).transpose()?,
+            blob: parse_blob(dto.blob)?,
+        })
+    }
+}
+
+impl From<CitationPart> for CitationDto {
+    fn from(citation: CitationPart) -> Self {
+        Self {
+            source: match citation.source {
+                CitationSource::Uri { uri } => CitationSourceDto::Uri { uri },
+                CitationSource::Message { message_id } => CitationSourceDto::Message {
+                    message_id: message_id.into_inner(),
+                },
+            },
+            spans: citation
+                .spans
+                .into_iter()
+                .map(|span| CitationSpanDto {
+                    part_index: span.part_index,
+                    start: span.start,
+                    end: span.end,
+                })
+                .collect(),
+            title: citation.title,
+        }
+    }
+}
+
+impl From<CitationDto> for CitationPart {
+    fn from(dto: CitationDto) -> Self {
+        let source = match dto.source {
+            CitationSourceDto::Uri { uri } => CitationSource::Uri { uri },
+            CitationSourceDto::Message { message_id } => {
+                CitationSource::message(MessageId::from_uuid(message_id))
+            }
+        };
+        let spans = dto
+            .spans
+            .into_iter()
+            .map(|span| CitationSpan::new(span.part_index, span.start, span.end))
+            .collect();
+        Self {
+            source,
+            spans,
+            title: dto.title,
+        }
+    }
+}
+
+fn parse_blob(blob: Option<String>) -> Result<Option<ContentHash>, DtoError> {
+    blob.map(|hash| ContentHash::parse(&hash))
+        .transpose()
+        .map_err(DtoError::from)
+}
+
+impl TryFrom<ContentPartDto> for ContentPart {
+    type Error = DtoError;
+
+    fn try_from(dto: ContentPartDto) -> Result<Self, Self::Error> {
+        let part = match dto {
+            ContentPartDto::Text { text } => Self::Text(TextPart { text }),
+            ContentPartDto::Reasoning { text, redacted } => {
+                Self::Reasoning(ReasoningPart { text, redacted })
+            }
+            ContentPartDto::ToolCall {
+                call_id,
+                name,
+                arguments,
+            } => Self::ToolCall(ToolCallPart {
+                call_id,
+                name,
+                arguments,
+            }),
+            ContentPartDto::ToolResult {
+                call_id,
+                content,
+                success,
+            } => Self::ToolResult(ToolResultPart {
+                call_id,
+                content,
+                success,
+            }),
+            ContentPartDto::Attachment(attachment) => Self::Attachment(attachment.try_into()?),
+            ContentPartDto::Image(image) => Self::Image(image.try_into()?),
+            ContentPartDto::Citation(citation) => Self::Citation(citation.into()),
+            ContentPartDto::Custom { kind, data } => Self::Custom(CustomPart { kind, data }),
+            ContentPartDto::Redacted {
+                reason,
+                redacted_at,
+            } => Self::Redacted(RedactedPart {
+                reason,
+                redacted_at,
+            }),
+        };
+        Ok(part)
+    }
+}
@@ -0,0 +1,231 @@
+//! Version 1 conversation and message resources.
+
+use super::ContentPartDto;
+use crate::dto::DtoError;
+use crate::message::domain::{
+    ContentPart, Conversation, ConversationId, ConversationState, Message, MessageMetadata, Role,
+};
+use crate::message::services::AppendMessageRequest;
+use crate::pagination::Page;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+/// The role of a message author.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RoleDto {
+    /// A human user.
+    User,
+    /// An AI assistant.
+    Assistant,
+    /// Tool execution output.
+    Tool,
+    /// System instructions.
+    System,
+}
+
+impl From<Role> for RoleDto {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::User => Self::User,
+            Role::Assistant => Self::Assistant,
+            Role::Tool => Self::Tool,
+            Role::System => Self::System,
+        }
+    }
+}
+
+impl From<RoleDto> for Role {
+    fn from(role: RoleDto) -> Self {
+        match role {
+            RoleDto::User => Self::User,
+            RoleDto::Assistant => Self::Assistant,
+            RoleDto::Tool => Self::Tool,
+            RoleDto::System => Self::System,
+        }
+    }
+}
+
+/// The lifecycle state of a conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationStateDto {
+    /// Accepts new messages.
+    Active,
+    /// Paused.
+    Paused,
+    /// Reached its goal.
+    Completed,
+    /// Closed to writes.
+    Archived,
+}
+
+impl From<ConversationState> for ConversationStateDto {
+    fn from(state: ConversationState) -> Self {
+        match state {
+            ConversationState::Active => Self::Active,
+            ConversationState::Paused => Self::Paused,
+            ConversationState::Completed => Self::Completed,
+            ConversationState::Archived => Self::Archived,
+        }
+    }
+}
+
+/// A conversation resource.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationDto {
+    /// Conversation identifier.
+    pub id: Uuid,
+    /// Lifecycle state.
+    pub state: ConversationStateDto,
+    /// When the conversation was created.
+    pub created_at: DateTime<Utc>,
+    /// When the conversation last changed.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Conversation> for ConversationDto {
+    fn from(conversation: Conversation) -> Self {
+        Self {
+            id: conversation.id().into_inner(),
+            state: conversation.state().into(),
+            created_at: conversation.created_at(),
+            updated_at: conversation.updated_at(),
+        }
+    }
+}
+
+/// A message resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageDto {
+    /// Message identifier.
+    pub id: Uuid,
+    /// Conversation the message belongs to.
+    pub conversation_id: Uuid,
+    /// Author role.
+    pub role: RoleDto,
+    /// Content parts, in order.
+    pub content: Vec<ContentPartDto>,
+    /// Open metadata object.
+    pub metadata: Map<String, Value>,
+    /// When the message was created.
+    pub created_at: DateTime<Utc>,
+    /// Position of the message in its conversation.
+    pub sequence_number: u64,
+}
+
+impl From<Message> for MessageDto {
+    fn from(message: Message) -> Self {
+        Self {
+            id: message.id().into_inner(),
+            conversation_id: message.conversation_id().into_inner(),
+            role: message.role().into(),
+            content: message
+                .content()
+                .iter()
+                .cloned()
+                .map(ContentPartDto::from)
+                .collect(),
+            metadata: metadata_object(message.metadata()),
+            created_at: message.created_at(),
+            sequence_number: message.sequence_number().value(),
+        }
+    }
+}
+
+/// Serialises metadata to a JSON object; metadata that fails to serialise
+/// is reported as empty.
+fn metadata_object(metadata: &MessageMetadata) -> Map<String, Value> {
+    match serde_json::to_value(metadata) {
+        Ok(Value::Object(object)) => object,
+        _ => Map::new(),
+    }
+}
+
+/// Body of a request to append a message to a conversation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppendMessageRequestDto {
+    /// Author role.
+    pub role: RoleDto,
+    /// Content parts, in order.
+    pub content: Vec<ContentPartDto>,
+}
+
+impl AppendMessageRequestDto {
+    /// Converts the body into a request to append to `conversation_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtoError`] when a content part cannot be represented in
+    /// the domain.
+    pub fn into_request(
+        self,
+        conversation_id: ConversationId,
+    ) -> Result<AppendMessageRequest, DtoError> {
+        let content = self
+            .content
+            .into_iter()
+            .map(ContentPart::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(AppendMessageRequest::new(
+            conversation_id,
+            self.role.into(),
+            content,
+        ))
+    }
+}
+
+/// Response carrying a single conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationResponseDto {
+    /// The conversation.
+    pub conversation: ConversationDto,
+}
+
+impl From<Conversation> for ConversationResponseDto {
+    fn from(conversation: Conversation) -> Self {
+        Self {
+            conversation: conversation.into(),
+        }
+    }
+}
+
+/// Response carrying a page of conversation history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationHistoryResponseDto {
+    /// Conversation the messages belong to.
+    pub conversation_id: Uuid,
+    /// Messages in sequence order.
+    pub messages: Vec<MessageDto>,
+    /// Token for the next page, when there is one.
+    pub next_cursor: Option<String>,
+}
+
+impl ConversationHistoryResponseDto {
+    /// Builds the response for a page of `conversation_id`'s history.
+    #[must_use]
+    pub fn new(conversation_id: ConversationId, page: Page<Message>) -> Self {
+        Self {
+            conversation_id: conversation_id.into_inner(),
+            next_cursor: page.next_cursor().map(String::from),
+            messages: page.into_iter().map(MessageDto::from).collect(),
+        }
+    }
+}
+
+/// Response carrying a single message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageResponseDto {
+    /// The message.
+    pub message: MessageDto,
+}
+
+impl From<Message> for MessageResponseDto {
+    fn from(message: Message) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Version 1 of the public wire format.
+//!
+//! These types match the JSON the HTTP API has served since its first
+//! release. Message metadata is carried as an open JSON object, since its
+//! keys are added by individual features rather than versioned here.
+
+mod content;
+mod conversation;
+
+pub use content::{
+    AttachmentDto, CitationDto, CitationSourceDto, CitationSpanDto, ContentPartDto, ImageDto,
+    ImageThumbnailDto,
+};
+pub use conversation::{
+    AppendMessageRequestDto, ConversationDto, ConversationHistoryResponseDto,
+    ConversationResponseDto, ConversationStateDto, MessageDto, MessageResponseDto, RoleDto,
+};
@@ -0,0 +1,171 @@
+//! In-memory erasure repository.
+
+use crate::context::{RequestContext, TenantId, UserId};
+use crate::erasure::domain::{
+    ErasureCertificate, ErasureCertificateId, ErasureCounts, SubjectErasure,
+};
+use crate::erasure::ports::{ErasureError, ErasureResult, SubjectErasureRepository};
+use crate::message::adapters::memory::{
+    InMemoryAgentSessionRepository, InMemoryContextSnapshotAdapter, InMemoryConversationRepository,
+    InMemoryHandoffAdapter, InMemoryMessageRepository,
+};
+use crate::message::ports::repository::MessageRepository;
+use crate::pagination::{Cursor, Page, PageRequest};
+use async_trait::async_trait;
+use mockable::Clock;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// The in-memory adapters an [`InMemoryErasureRepository`] erases from.
+#[derive(Debug, Clone)]
+pub struct InMemoryErasureSources<C: Clock + Send + Sync> {
+    /// Conversations, which record who started each one.
+    pub conversations: InMemoryConversationRepository,
+    /// Messages, which record who wrote each one.
+    pub messages: InMemoryMessageRepository,
+    /// Agent sessions.
+    pub sessions: InMemoryAgentSessionRepository,
+    /// Agent handoffs.
+    pub handoffs: InMemoryHandoffAdapter<C>,
+    /// Context snapshots.
+    pub snapshots: InMemoryContextSnapshotAdapter,
+}
+
+/// Thread-safe in-memory erasure repository.
+///
+/// Certificates are kept here; erasures act on the attached adapters. The
+/// subject's conversations and messages are those stored under their user
+/// ID. The in-memory adapters keep no domain events or audit log, and the
+/// sources hold no feedback, streams, memory facts, operator actions,
+/// summaries, or blobs, so erasures never count any of those.
+#[derive(Debug, Clone)]
+pub struct InMemoryErasureRepository<C: Clock + Send + Sync> {
+    certificates: Arc<RwLock<HashMap<TenantId, Vec<ErasureCertificate>>>>,
+    sources: InMemoryErasureSources<C>,
+}
+
+impl<C: Clock + Send + Sync> InMemoryErasureRepository<C> {
+    /// Creates a repository with no certificates over the given adapters.
+    #[must_use]
+    pub fn new(sources: InMemoryErasureSources<C>) -> Self {
+        Self {
+            certificates: Arc::default(),
+            sources,
+        }
+    }
+
+    async fn redact_messages(
+        &self,
+        ctx: &RequestContext,
+        erasure: &SubjectErasure,
+    ) -> ErasureResult<u64> {
+        let conversations = self
+            .sources
+            .conversations
+            .conversation_ids(ctx.tenant_id())
+            .map_err(ErasureError::persistence_failed)?;
+        let written = self
+            .sources
+            .messages
+            .written_by(&conversations, erasure.subject)
+            .map_err(ErasureError::persistence_failed)?;
+        for message_id in &written {
+            self.sources
+                .messages
+                .redact(ctx, &erasure.redaction(*message_id))
+                .await
+                .map_err(ErasureError::persistence_failed)?;
+        }
+        Ok(count(written.len()))
+    }
+}
+
+#[async_trait]
+impl<C: Clock + Send + Sync> SubjectErasureRepository for InMemoryErasureRepository<C> {
+    async fn erase(
+        &self,
+        ctx: &RequestContext,
+        erasure: &SubjectErasure,
+    ) -> ErasureResult<ErasureCertificate> {
+        let mut certificates = self.certificates.write().await;
+        let started = self
+            .sources
+            .conversations
+            .created_by(ctx.tenant_id(), erasure.subject)
+            .map_err(ErasureError::persistence_failed)?;
+        let messages = self.redact_messages(ctx, erasure).await?;
+        let snapshots = self
+            .sources
+            .snapshots
+            .remove_for_conversations(&started)
+            .map_err(ErasureError::persistence_failed)?;
+        let handoffs = self
+            .sources
+            .handoffs
+            .remove_for_conversations(&started)
+            .map_err(ErasureError::persistence_failed)?;
+        let sessions = self
+            .sources
+            .sessions
+            .remove_for_conversations(&started)
+            .map_err(ErasureError::persistence_failed)?;
+        let certificate = ErasureCertificate::new(
+            erasure,
+            ErasureCounts {
+                messages,
+                sessions: count(sessions),
+                handoffs: count(handoffs),
+                snapshots: count(snapshots),
+                ..ErasureCounts::default()
+            },
+        );
+        certificates
+            .entry(ctx.tenant_id())
+            .or_default()
+            .push(certificate.clone());
+        Ok(certificate)
+    }
+
+    async fn find_certificate(
+        &self,
+        ctx: &RequestContext,
+        id: ErasureCertificateId,
+    ) -> ErasureResult<Option<ErasureCertificate>> {
+        Ok(self
+            .certificates
+            .read()
+            .await
+            .get(&ctx.tenant_id())
+            .and_then(|certificates| {
+                certificates
+                    .iter()
+                    .find(|certificate| certificate.id == id)
+                    .cloned()
+            }))
+    }
+
+    async fn list_certificates(
+        &self,
+        ctx: &RequestContext,
+        subject: UserId,
+        page: PageRequest,
+    ) -> ErasureResult<Page<ErasureCertificate>> {
+        let certificates = self.certificates.read().await;
+        let mut listed = certificates
+            .get(&ctx.tenant_id())
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .filter(|certificate| certificate.subject == subject)
+            .cloned()
+            .collect::<Vec<_>>();
+        listed.sort_by_key(|certificate| (certificate.erased_at, certificate.id));
+        Ok(Page::from_ordered(listed, page, |certificate| {
+            Cursor::at_timestamp(certificate.erased_at, certificate.id.into_inner())
+        }))
+    }
+}
+
+fn count(records: usize) -> u64 {
+    u64::try_from(records).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,7 @@
+//! Adapter implementations for the erasure ports.
+
+pub mod memory;
+pub mod postgres;
+
+pub use memory::{InMemoryErasureRepository, InMemoryErasureSources};
+pub use postgres::PostgresErasureRepository;
@@ -0,0 +1,330 @@
+//! Erasure statements for the `PostgreSQL` erasure repository.
+//!
+//! The subject is found through the audit log: the conversations they
+//! started are those whose `INSERT` audit row carries their user ID, and
+//! likewise for the messages they wrote. Every selection binds the tenant as
+//! `$1` and the subject as `$2`. Domain events and audit rows carry no
+//! tenant, so they are confined to the tenant through the conversations and
+//! messages they belong to.
+//!
+//! Audit rows keep the subject's user ID, which the certificate records
+//! anyway, so a later erasure still finds what the subject started. Their
+//! values and session are cleared before messages are redacted and events
+//! anonymised, whose own audit rows are then scrubbed by the usual means.
+//!
+//! Beyond the messages themselves, the erasure reaches what was derived from
+//! them or recorded alongside: feedback the subject gave, and comments on
+//! their messages; rolling summaries and agent memory facts of conversations
+//! they started or wrote in; compactions summarising their messages; streams
+//! still staging into conversations they started; and the reasons operators
+//! gave for actions the subject took or took on those conversations.
+//! Rolling summaries and compactions are deleted before the messages are
+//! redacted, as the redaction trigger would otherwise drop some of them
+//! uncounted.
+//!
+//! Redacting the subject's messages drops their references to attachment
+//! blobs. The erasure returns the blobs no message of the tenant refers to
+//! any more, for the repository to delete once the erasure commits.
+
+use crate::erasure::domain::{ErasureCounts, SubjectErasure};
+use crate::erasure::ports::{ErasureError, ErasureResult};
+use crate::message::adapters::schema::{
+    agent_sessions, context_snapshots, conversation_rolling_summaries, domain_events, handoffs,
+    message_feedback, messages, partial_messages,
+};
+use crate::message::domain::{ContentHash, MessageId};
+use crate::postgres_support::{
+    RowScope, load_blobs, message_blobs_query, redact_message, scrub_audit_values,
+    unreferenced_blobs_query,
+};
+use diesel::pg::{Pg, PgConnection};
+use diesel::prelude::*;
+use diesel::query_builder::{BoxedSqlQuery, SqlQuery};
+use diesel::sql_types::{Array, Text, Uuid as SqlUuid};
+use serde_json::json;
+use uuid::Uuid;
+
+/// Conversations the subject started.
+const STARTED_CONVERSATIONS_SQL: &str = "SELECT DISTINCT c.id FROM conversations c \
+     JOIN audit_logs a ON a.row_id = c.id \
+     WHERE c.tenant_id = $1 AND a.table_name = 'conversations' \
+     AND a.operation = 'INSERT' AND a.user_id = $2";
+
+/// Messages the subject wrote that are not yet redacted, oldest first.
+const WRITTEN_MESSAGES_SQL: &str = "SELECT m.id FROM messages m \
+     WHERE m.tenant_id = $1 \
+     AND EXISTS (SELECT 1 FROM audit_logs a WHERE a.table_name = 'messages' \
+         AND a.operation = 'INSERT' AND a.row_id = m.id AND a.user_id = $2) \
+     AND NOT EXISTS (SELECT 1 FROM message_redactions r WHERE r.message_id = m.id) \
+     ORDER BY m.created_at, m.id";
+
+/// Events the subject caused, or recorded against the messages being
+/// redacted (`$3`), that are not yet anonymised.
+const CAUSED_EVENTS_SQL: &str = "SELECT e.id FROM domain_events e \
+     WHERE (e.aggregate_id = ANY($3) OR (e.user_id = $2 AND e.aggregate_id IN ( \
+         SELECT id FROM conversations WHERE tenant_id = $1 \
+         UNION ALL SELECT id FROM messages WHERE tenant_id = $1))) \
+     AND NOT (e.event_data = '{}'::jsonb AND e.user_id IS NULL AND e.session_id IS NULL)";
+
+/// Clears the values and session of the subject's audit rows on the
+/// tenant's conversations, messages, and their events.
+const STRIP_AUDIT_SQL: &str = "WITH tenant_aggregates AS ( \
+         SELECT id FROM conversations WHERE tenant_id = $1 \
+         UNION ALL SELECT id FROM messages WHERE tenant_id = $1), \
+     tenant_rows AS ( \
+         SELECT id FROM tenant_aggregates \
+         UNION ALL SELECT e.id FROM domain_events e \
+             WHERE e.aggregate_id IN (SELECT id FROM tenant_aggregates)) \
+     UPDATE audit_logs a \
+     SET old_values = NULL, new_values = NULL, session_id = NULL \
+     WHERE a.user_id = $2 AND a.row_id IN (SELECT id FROM tenant_rows) \
+     AND (a.old_values IS NOT NULL OR a.new_values IS NOT NULL OR a.session_id IS NOT NULL)";
+
+/// Compactions summarising any of the messages `$2`.
+const COMPACTIONS_SQL: &str = "DELETE FROM context_snapshots s \
+     WHERE s.tenant_id = $1 AND s.snapshot_type = 'compaction' \
+     AND EXISTS (SELECT 1 FROM messages m \
+         WHERE m.tenant_id = $1 AND m.id = ANY($2) AND m.conversation_id = s.conversation_id \
+         AND m.sequence_number BETWEEN s.sequence_start AND s.sequence_end)";
+
+/// Agent memory facts learnt from the conversations `$2`.
+const MEMORY_FACTS_SQL: &str = "DELETE FROM agent_memory_facts \
+     WHERE tenant_id = $1 AND source_conversation_id = ANY($2)";
+
+/// Replaces with `$3` the reasons given for operator actions the subject
+/// took, or took on the conversations `$4`.
+const OPERATOR_ACTIONS_SQL: &str = "UPDATE operator_actions SET reason = $3 \
+     WHERE tenant_id = $1 AND (actor_id = $2 OR conversation_id = ANY($4)) \
+     AND reason <> $3";
+
+/// The subject's records the erasure acts on.
+struct Selection<'a> {
+    tenant_id: Uuid,
+    subject: Uuid,
+    /// Conversations the subject started.
+    started: &'a [Uuid],
+    /// Messages the subject wrote that are not yet redacted.
+    written: &'a [Uuid],
+}
+
+/// Counts of the records derived from the subject's messages.
+struct DerivedCounts {
+    feedback: usize,
+    partial_messages: usize,
+    memory_facts: usize,
+    operator_actions: usize,
+    summaries: usize,
+}
+
+#[derive(QueryableByName)]
+struct IdRow {
+    #[diesel(sql_type = SqlUuid)]
+    id: Uuid,
+}
+
+/// Erases the subject inside the caller's transaction, returning what it
+/// erased and the blobs left unreferenced.
+pub(super) fn erase_subject(
+    conn: &mut PgConnection,
+    scope: RowScope<'_>,
+    correlation_id: Uuid,
+    erasure: &SubjectErasure,
+) -> ErasureResult<(ErasureCounts, Vec<ContentHash>)> {
+    let tenant_id = scope.tenant_id;
+    let subject = erasure.subject.into_inner();
+    let started = select_ids(
+        conn,
+        subject_query(STARTED_CONVERSATIONS_SQL, tenant_id, subject),
+    )?;
+    let audit_logs = subject_query(STRIP_AUDIT_SQL, tenant_id, subject)
+        .execute(conn)
+        .map_err(ErasureError::persistence_failed)?;
+    let message_ids = select_ids(
+        conn,
+        subject_query(WRITTEN_MESSAGES_SQL, tenant_id, subject),
+    )?;
+    let blobs = load_blobs(conn, message_blobs_query(tenant_id, &message_ids))
+        .map_err(ErasureError::persistence_failed)?;
+    let selection = Selection {
+        tenant_id,
+        subject,
+        started: &started,
+        written: &message_ids,
+    };
+    let derived = erase_derived(conn, &selection, erasure)?;
+    for id in &message_ids {
+        redact_message(
+            conn,
+            scope,
+            correlation_id,
+            &erasure.redaction(MessageId::from_uuid(*id)),
+        )
+        .map_err(ErasureError::persistence_failed)?;
+    }
+    let events = anonymise_events(conn, tenant_id, subject, &message_ids)?;
+    let snapshots = diesel::delete(
+        context_snapshots::table
+            .filter(context_snapshots::tenant_id.eq(tenant_id))
+            .filter(context_snapshots::conversation_id.eq_any(&started)),
+    )
+    .execute(conn)
+    .map_err(ErasureError::persistence_failed)?;
+    let handoffs = diesel::delete(
+        handoffs::table
+            .filter(handoffs::tenant_id.eq(tenant_id))
+            .filter(handoffs::conversation_id.eq_any(&started)),
+    )
+    .execute(conn)
+    .map_err(ErasureError::persistence_failed)?;
+    let sessions = diesel::delete(
+        agent_sessions::table
+            .filter(agent_sessions::tenant_id.eq(tenant_id))
+            .filter(agent_sessions::conversation_id.eq_any(&started)),
+    )
+    .execute(conn)
+    .map_err(ErasureError::persistence_failed)?;
+    let unreferenced = load_blobs(conn, unreferenced_blobs_query(tenant_id, &blobs))
+        .map_err(ErasureError::persistence_failed)?;
+    let counts = ErasureCounts {
+        messages: to_count(message_ids.len()),
+        events: to_count(events),
+        sessions: to_count(sessions),
+        handoffs: to_count(handoffs),
+        snapshots: to_count(snapshots),
+        audit_logs: to_count(audit_logs),
+        feedback: to_count(derived.feedback),
+        partial_messages: to_count(derived.partial_messages),
+        memory_facts: to_count(derived.memory_facts),
+        operator_actions: to_count(derived.operator_actions),
+        summaries: to_count(derived.summaries),
+        blobs: 0,
+    };
+    Ok((counts, unreferenced))
+}
+
+/// Erases what was derived from, or recorded beside, the subject's
+/// messages and conversations.
+fn erase_derived(
+    conn: &mut PgConnection,
+    selection: &Selection<'_>,
+    erasure: &SubjectErasure,
+) -> ErasureResult<DerivedCounts> {
+    let tenant_id = selection.tenant_id;
+    let mut conversations = messages::table
+        .filter(messages::tenant_id.eq(tenant_id))
+        .filter(messages::id.eq_any(selection.written))
+        .select(messages::conversation_id)
+        .distinct()
+        .load::<Uuid>(conn)
+        .map_err(ErasureError::persistence_failed)?;
+    conversations.extend_from_slice(selection.started);
+    let rolling = diesel::delete(
+        conversation_rolling_summaries::table
+            .filter(conversation_rolling_summaries::tenant_id.eq(tenant_id))
+            .filter(conversation_rolling_summaries::conversation_id.eq_any(&conversations)),
+    )
+    .execute(conn)
+    .map_err(ErasureError::persistence_failed)?;
+    let compactions = diesel::sql_query(COMPACTIONS_SQL)
+        .bind::<SqlUuid, _>(tenant_id)
+        .bind::<Array<SqlUuid>, _>(selection.written.to_vec())
+        .execute(conn)
+        .map_err(ErasureError::persistence_failed)?;
+    let memory_facts = diesel::sql_query(MEMORY_FACTS_SQL)
+        .bind::<SqlUuid, _>(tenant_id)
+        .bind::<Array<SqlUuid>, _>(conversations)
+        .execute(conn)
+        .map_err(ErasureError::persistence_failed)?;
+    let partial_messages = diesel::delete(
+        partial_messages::table
+            .filter(partial_messages::tenant_id.eq(tenant_id))
+            .filter(partial_messages::conversation_id.eq_any(selection.started)),
+    )
+    .execute(conn)
+    .map_err(ErasureError::persistence_failed)?;
+    let operator_actions = subject_query(OPERATOR_ACTIONS_SQL, tenant_id, selection.subject)
+        .bind::<Text, _>(erasure.reason.as_str().to_owned())
+        .bind::<Array<SqlUuid>, _>(selection.started.to_vec())
+        .execute(conn)
+        .map_err(ErasureError::persistence_failed)?;
+    Ok(DerivedCounts {
+        feedback: erase_feedback(conn, selection)?,
+        partial_messages,
+        memory_facts,
+        operator_actions,
+        summaries: rolling.saturating_add(compactions),
+    })
+}
+
+/// Deletes the feedback the subject gave and clears the comments others
+/// left on the subject's messages.
+fn erase_feedback(conn: &mut PgConnection, selection: &Selection<'_>) -> ErasureResult<usize> {
+    let given = diesel::delete(
+        message_feedback::table
+            .filter(message_feedback::tenant_id.eq(selection.tenant_id))
+            .filter(message_feedback::author_id.eq(selection.subject)),
+    )
+    .execute(conn)
+    .map_err(ErasureError::persistence_failed)?;
+    let commented = diesel::update(
+        message_feedback::table
+            .filter(message_feedback::tenant_id.eq(selection.tenant_id))
+            .filter(message_feedback::message_id.eq_any(selection.written))
+            .filter(message_feedback::comment.is_not_null()),
+    )
+    .set(message_feedback::comment.eq(None::<String>))
+    .execute(conn)
+    .map_err(ErasureError::persistence_failed)?;
+    Ok(given.saturating_add(commented))
+}
+
+/// Strips the subject's events and those of their redacted messages, then
+/// clears the audit rows the stripping wrote.
+fn anonymise_events(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    subject: Uuid,
+    message_ids: &[Uuid],
+) -> ErasureResult<usize> {
+    let query = subject_query(CAUSED_EVENTS_SQL, tenant_id, subject)
+        .bind::<Array<SqlUuid>, _>(message_ids.to_vec());
+    let event_ids = select_ids(conn, query)?;
+    let anonymised =
+        diesel::update(domain_events::table.filter(domain_events::id.eq_any(&event_ids)))
+            .set((
+                domain_events::event_data.eq(json!({})),
+                domain_events::user_id.eq(None::<Uuid>),
+                domain_events::session_id.eq(None::<Uuid>),
+            ))
+            .execute(conn)
+            .map_err(ErasureError::persistence_failed)?;
+    scrub_audit_values(conn, "domain_events", &event_ids)
+        .map_err(ErasureError::persistence_failed)?;
+    Ok(anonymised)
+}
+
+fn select_ids(
+    conn: &mut PgConnection,
+    query: BoxedSqlQuery<'static, Pg, SqlQuery>,
+) -> ErasureResult<Vec<Uuid>> {
+    query
+        .load::<IdRow>(conn)
+        .map(|rows| rows.into_iter().map(|row| row.id).collect())
+        .map_err(ErasureError::persistence_failed)
+}
+
+/// Binds the tenant and subject shared by every erasure statement.
+fn subject_query(
+    sql: &'static str,
+    tenant_id: Uuid,
+    subject: Uuid,
+) -> BoxedSqlQuery<'static, Pg, SqlQuery> {
+    diesel::sql_query(sql)
+        .into_boxed()
+        .bind::<SqlUuid, _>(tenant_id)
+        .bind::<SqlUuid, _>(subject)
+}
+
+fn to_count(value: usize) -> u64 {
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,8 @@
+//! `PostgreSQL` adapters for subject erasure.
+
+mod erase;
+mod models;
+mod repository;
+mod schema;
+
+pub use repository::PostgresErasureRepository;
@@ -0,0 +1,50 @@
+//! Diesel models for erasure certificate persistence.
+
+use super::schema::erasure_certificates;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+/// Row representation for an erasure certificate.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = erasure_certificates)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ErasureCertificateRow {
+    /// Certificate identifier.
+    pub id: uuid::Uuid,
+    /// Tenant identifier.
+    pub tenant_id: uuid::Uuid,
+    /// User whose data was erased.
+    pub subject_id: uuid::Uuid,
+    /// User who requested the erasure.
+    pub requested_by: uuid::Uuid,
+    /// Why the data was erased.
+    pub reason: String,
+    /// Correlation identifier of the requesting call.
+    pub correlation_id: uuid::Uuid,
+    /// When the data was erased.
+    pub erased_at: DateTime<Utc>,
+    /// Messages redacted.
+    pub messages: i64,
+    /// Domain events anonymised.
+    pub events: i64,
+    /// Agent sessions deleted.
+    pub sessions: i64,
+    /// Handoffs deleted.
+    pub handoffs: i64,
+    /// Context snapshots deleted.
+    pub snapshots: i64,
+    /// Audit log entries stripped.
+    pub audit_logs: i64,
+    /// Feedback deleted or stripped of its comment.
+    pub feedback: i64,
+    /// Staged streams deleted.
+    pub partial_messages: i64,
+    /// Agent memory facts deleted.
+    pub memory_facts: i64,
+    /// Operator actions stripped of their reason.
+    pub operator_actions: i64,
+    /// Rolling summaries and compactions deleted.
+    pub summaries: i64,
+    /// Attachment blobs deleted.
+    pub blobs: i64,
+}
@@ -0,0 +1,312 @@
+//! `PostgreSQL` repository implementation for subject erasure.
+
+use super::erase;
+use super::models::ErasureCertificateRow;
+use super::schema::erasure_certificates;
+use crate::context::{CorrelationId, RequestContext, TenantId, UserId};
+use crate::erasure::domain::{
+    ErasureCertificate, ErasureCertificateId, ErasureCounts, SubjectErasure,
+};
+use crate::erasure::ports::{ErasureError, ErasureResult, SubjectErasureRepository};
+use crate::message::adapters::audit_context::AuditContext;
+use crate::message::domain::{ContentHash, RedactionReason};
+use crate::message::ports::{BlobStore, ContentCipher};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{
+    FromTxError, MessageCodec, PgPool, RowScope, TxError, ensure_tenant_exists, get_conn_with,
+    keyset_page, run_blocking_with, set_audit_context, with_tenant_read_tx, with_tenant_tx,
+};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+impl FromTxError<Self> for ErasureError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(error) => error,
+            TxError::Diesel(error) => Self::persistence_failed(error),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed subject erasure repository.
+///
+/// Each erasure runs in one transaction with its certificate, so either
+/// everything listed on the certificate was erased or nothing was.
+/// Attachment blobs are deleted after that transaction commits, and the
+/// certificate then updated with the number deleted.
+#[derive(Clone)]
+pub struct PostgresErasureRepository {
+    pool: PgPool,
+    codec: MessageCodec,
+    blobs: Option<Arc<dyn BlobStore>>,
+}
+
+crate::postgres_support::pool_health_check!(PostgresErasureRepository, "erasure_repository");
+
+impl PostgresErasureRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            codec: MessageCodec::plain(),
+            blobs: None,
+        }
+    }
+
+    /// Deletes from `blobs` the attachment blobs the subject's redacted
+    /// messages leave unreferenced. Pass the store the message repository
+    /// externalises attachments to.
+    #[must_use]
+    pub fn with_blob_store(mut self, blobs: Arc<dyn BlobStore>) -> Self {
+        self.blobs = Some(blobs);
+        self
+    }
+
+    /// Reads and rewrites encrypted messages with `cipher` when erasures
+    /// redact them. Pass the cipher the message repository was given.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Arc<dyn ContentCipher>) -> Self {
+        self.codec = MessageCodec::sealed(cipher);
+        self
+    }
+
+    /// Deletes `hashes` from the blob store, if one is configured, and
+    /// records on the certificate how many were deleted, even when a later
+    /// deletion fails.
+    async fn erase_blobs(
+        &self,
+        ctx: &RequestContext,
+        certificate: &mut ErasureCertificate,
+        hashes: &[ContentHash],
+    ) -> ErasureResult<()> {
+        let Some(blobs) = &self.blobs else {
+            return Ok(());
+        };
+        let mut outcome = Ok(());
+        for hash in hashes {
+            if let Err(err) = blobs.delete(ctx, hash).await {
+                outcome = Err(ErasureError::persistence_failed(err));
+                break;
+            }
+            certificate.erased.blobs = certificate.erased.blobs.saturating_add(1);
+        }
+        if certificate.erased.blobs > 0 {
+            let id = certificate.id.into_inner();
+            let tenant_uuid = ctx.tenant_id().into_inner();
+            let erased = to_column(certificate.erased.blobs);
+            self.write(ctx, move |tx| {
+                diesel::update(
+                    erasure_certificates::table
+                        .filter(erasure_certificates::tenant_id.eq(tenant_uuid))
+                        .filter(erasure_certificates::id.eq(id)),
+                )
+                .set(erasure_certificates::blobs.eq(erased))
+                .execute(tx)
+                .map(drop)
+                .map_err(ErasureError::persistence_failed)
+            })
+            .await?;
+        }
+        outcome
+    }
+
+    async fn read<F, T>(&self, tenant_id: TenantId, query_fn: F) -> ErasureResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> ErasureResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ErasureError::persistence_failed)?;
+                with_tenant_read_tx(&mut conn, tenant_id.into_inner(), query_fn)
+            },
+            ErasureError::persistence_failed,
+        )
+        .await
+    }
+
+    async fn write<F, T>(&self, ctx: &RequestContext, write_fn: F) -> ErasureResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> ErasureResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let audit = AuditContext::from(ctx);
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ErasureError::persistence_failed)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid)
+                        .map_err(ErasureError::persistence_failed)?;
+                    set_audit_context(tx, &audit).map_err(ErasureError::persistence_failed)?;
+                    write_fn(tx)
+                })
+            },
+            ErasureError::persistence_failed,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl SubjectErasureRepository for PostgresErasureRepository {
+    async fn erase(
+        &self,
+        ctx: &RequestContext,
+        erasure: &SubjectErasure,
+    ) -> ErasureResult<ErasureCertificate> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let correlation_id = ctx.correlation_id().into_inner();
+        let erasure = erasure.clone();
+        let codec = self.codec.clone();
+        let (mut certificate, unreferenced) = self
+            .write(ctx, move |tx| {
+                let scope = RowScope {
+                    tenant_id: tenant_uuid,
+                    codec: &codec,
+                };
+                let (counts, unreferenced) =
+                    erase::erase_subject(tx, scope, correlation_id, &erasure)?;
+                let certificate = ErasureCertificate::new(&erasure, counts);
+                diesel::insert_into(erasure_certificates::table)
+                    .values(to_row(&certificate, tenant_uuid))
+                    .execute(tx)
+                    .map_err(ErasureError::persistence_failed)?;
+                Ok((certificate, unreferenced))
+            })
+            .await?;
+        self.erase_blobs(ctx, &mut certificate, &unreferenced)
+            .await?;
+        Ok(certificate)
+    }
+
+    async fn find_certificate(
+        &self,
+        ctx: &RequestContext,
+        id: ErasureCertificateId,
+    ) -> ErasureResult<Option<ErasureCertificate>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = self
+            .read(ctx.tenant_id(), move |conn| {
+                erasure_certificates::table
+                    .filter(erasure_certificates::tenant_id.eq(tenant_uuid))
+                    .filter(erasure_certificates::id.eq(id.into_inner()))
+                    .select(ErasureCertificateRow::as_select())
+                    .first::<ErasureCertificateRow>(conn)
+                    .optional()
+                    .map_err(ErasureError::persistence_failed)
+            })
+            .await?;
+        row.map(row_to_certificate).transpose()
+    }
+
+    async fn list_certificates(
+        &self,
+        ctx: &RequestContext,
+        subject: UserId,
+        page: PageRequest,
+    ) -> ErasureResult<Page<ErasureCertificate>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let rows = self
+            .read(ctx.tenant_id(), move |conn| {
+                let filtered = erasure_certificates::table
+                    .filter(erasure_certificates::tenant_id.eq(tenant_uuid))
+                    .filter(erasure_certificates::subject_id.eq(subject.into_inner()))
+                    .into_boxed();
+                let paged = keyset_page!(
+                    filtered,
+                    page,
+                    after_timestamp,
+                    (erasure_certificates::erased_at, erasure_certificates::id)
+                );
+                paged
+                    .select(ErasureCertificateRow::as_select())
+                    .load::<ErasureCertificateRow>(conn)
+                    .map_err(ErasureError::persistence_failed)
+            })
+            .await?;
+        Page::from_overfetched(rows, page, |row| {
+            Cursor::at_timestamp(row.erased_at, row.id)
+        })
+        .try_map(row_to_certificate)
+    }
+}
+
+impl std::fmt::Debug for PostgresErasureRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PostgresErasureRepository")
+            .field("codec", &self.codec)
+            .field("blobs", &self.blobs.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+fn to_row(certificate: &ErasureCertificate, tenant_id: uuid::Uuid) -> ErasureCertificateRow {
+    let erased = certificate.erased;
+    ErasureCertificateRow {
+        id: certificate.id.into_inner(),
+        tenant_id,
+        subject_id: certificate.subject.into_inner(),
+        requested_by: certificate.requested_by.into_inner(),
+        reason: certificate.reason.as_str().to_owned(),
+        correlation_id: certificate.correlation_id.into_inner(),
+        erased_at: certificate.erased_at,
+        messages: to_column(erased.messages),
+        events: to_column(erased.events),
+        sessions: to_column(erased.sessions),
+        handoffs: to_column(erased.handoffs),
+        snapshots: to_column(erased.snapshots),
+        audit_logs: to_column(erased.audit_logs),
+        feedback: to_column(erased.feedback),
+        partial_messages: to_column(erased.partial_messages),
+        memory_facts: to_column(erased.memory_facts),
+        operator_actions: to_column(erased.operator_actions),
+        summaries: to_column(erased.summaries),
+        blobs: to_column(erased.blobs),
+    }
+}
+
+fn row_to_certificate(row: ErasureCertificateRow) -> ErasureResult<ErasureCertificate> {
+    let reason = RedactionReason::new(row.reason)
+        .map_err(|err| ErasureError::invalid_persisted_data(err.to_string()))?;
+    let count = |column: &str, value: i64| {
+        u64::try_from(value).map_err(|_| {
+            ErasureError::invalid_persisted_data(format!(
+                "erasure certificate {} has {value} {column}",
+                row.id
+            ))
+        })
+    };
+    let erased = ErasureCounts {
+        messages: count("messages", row.messages)?,
+        events: count("events", row.events)?,
+        sessions: count("sessions", row.sessions)?,
+        handoffs: count("handoffs", row.handoffs)?,
+        snapshots: count("snapshots", row.snapshots)?,
+        audit_logs: count("audit logs", row.audit_logs)?,
+        feedback: count("feedback", row.feedback)?,
+        partial_messages: count("partial messages", row.partial_messages)?,
+        memory_facts: count("memory facts", row.memory_facts)?,
+        operator_actions: count("operator actions", row.operator_actions)?,
+        summaries: count("summaries", row.summaries)?,
+        blobs: count("blobs", row.blobs)?,
+    };
+    Ok(ErasureCertificate {
+        id: ErasureCertificateId::from_uuid(row.id),
+        subject: UserId::from_uuid(row.subject_id),
+        requested_by: UserId::from_uuid(row.requested_by),
+        reason,
+        correlation_id: CorrelationId::from_uuid(row.correlation_id),
+        erased_at: row.erased_at,
+        erased,
+    })
+}
+
+fn to_column(count: u64) -> i64 {
+    i64::try_from(count).unwrap_or(i64::MAX)
+}
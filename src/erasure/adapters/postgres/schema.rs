@@ -0,0 +1,45 @@
+//! Diesel schema for erasure certificate persistence.
+
+diesel::table! {
+    /// Certificates of completed subject erasures.
+    erasure_certificates (id) {
+        /// Certificate identifier.
+        id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// User whose data was erased.
+        subject_id -> Uuid,
+        /// User who requested the erasure.
+        requested_by -> Uuid,
+        /// Why the data was erased.
+        reason -> Text,
+        /// Correlation identifier of the requesting call.
+        correlation_id -> Uuid,
+        /// When the data was erased.
+        erased_at -> Timestamptz,
+        /// Messages redacted.
+        messages -> Int8,
+        /// Domain events anonymised.
+        events -> Int8,
+        /// Agent sessions deleted.
+        sessions -> Int8,
+        /// Handoffs deleted.
+        handoffs -> Int8,
+        /// Context snapshots deleted.
+        snapshots -> Int8,
+        /// Audit log entries stripped.
+        audit_logs -> Int8,
+        /// Feedback deleted or stripped of its comment.
+        feedback -> Int8,
+        /// Staged streams deleted.
+        partial_messages -> Int8,
+        /// Agent memory facts deleted.
+        memory_facts -> Int8,
+        /// Operator actions stripped of their reason.
+        operator_actions -> Int8,
+        /// Rolling summaries and compactions deleted.
+        summaries -> Int8,
+        /// Attachment blobs deleted.
+        blobs -> Int8,
+    }
+}
@@ -0,0 +1,194 @@
+//! Domain types for subject erasure.
+
+use crate::context::{CorrelationId, RequestContext, UserId};
+use crate::message::domain::{MessageId, MessageRedaction, RedactionReason};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+/// Unique identifier for an erasure certificate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ErasureCertificateId(Uuid);
+
+impl ErasureCertificateId {
+    /// Creates a new random certificate identifier.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Creates an identifier from an existing UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Returns the wrapped UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for ErasureCertificateId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ErasureCertificateId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A request to erase everything recorded about `subject`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectErasure {
+    /// Identifier of the certificate the erasure will produce.
+    pub id: ErasureCertificateId,
+    /// The user whose data is erased.
+    pub subject: UserId,
+    /// The user who requested the erasure.
+    pub requested_by: UserId,
+    /// Why the data is erased, recorded on every redacted message.
+    pub reason: RedactionReason,
+    /// Correlation identifier of the requesting call.
+    pub correlation_id: CorrelationId,
+    /// When the erasure was requested.
+    pub requested_at: DateTime<Utc>,
+}
+
+impl SubjectErasure {
+    /// Records a request, made now by the user in `ctx`, to erase
+    /// `subject` for `reason`.
+    #[must_use]
+    pub fn new(
+        ctx: &RequestContext,
+        subject: UserId,
+        reason: RedactionReason,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        Self {
+            id: ErasureCertificateId::new(),
+            subject,
+            requested_by: ctx.user_id(),
+            reason,
+            correlation_id: ctx.correlation_id(),
+            requested_at: clock.utc(),
+        }
+    }
+
+    /// Returns the redaction that erases `message_id`.
+    #[must_use]
+    pub fn redaction(&self, message_id: MessageId) -> MessageRedaction {
+        MessageRedaction::made_at(
+            message_id,
+            self.reason.clone(),
+            self.requested_by,
+            self.requested_at,
+        )
+    }
+}
+
+/// Numbers of records an erasure changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErasureCounts {
+    /// Messages the subject wrote, redacted in place.
+    pub messages: u64,
+    /// Domain events the subject caused, anonymised.
+    pub events: u64,
+    /// Agent sessions of conversations the subject started, deleted.
+    pub sessions: u64,
+    /// Handoffs of conversations the subject started, deleted.
+    pub handoffs: u64,
+    /// Context snapshots of conversations the subject started, deleted.
+    pub snapshots: u64,
+    /// Audit log entries attributed to the subject, stripped.
+    pub audit_logs: u64,
+    /// Feedback the subject gave, deleted, and comments others left on
+    /// their messages, cleared.
+    pub feedback: u64,
+    /// Streams still staging into conversations the subject started,
+    /// deleted.
+    pub partial_messages: u64,
+    /// Agent memory facts learnt from conversations the subject started or
+    /// wrote in, deleted.
+    pub memory_facts: u64,
+    /// Operator actions the subject took, or took on conversations they
+    /// started, whose recorded reason was replaced.
+    pub operator_actions: u64,
+    /// Rolling summaries of conversations the subject started or wrote in,
+    /// and compactions summarising their messages, deleted.
+    pub summaries: u64,
+    /// Attachment blobs no message but the subject's referred to, deleted.
+    pub blobs: u64,
+}
+
+impl ErasureCounts {
+    /// Returns the total number of records.
+    #[must_use]
+    pub const fn total(self) -> u64 {
+        self.messages
+            .saturating_add(self.events)
+            .saturating_add(self.sessions)
+            .saturating_add(self.handoffs)
+            .saturating_add(self.snapshots)
+            .saturating_add(self.audit_logs)
+            .saturating_add(self.feedback)
+            .saturating_add(self.partial_messages)
+            .saturating_add(self.memory_facts)
+            .saturating_add(self.operator_actions)
+            .saturating_add(self.summaries)
+            .saturating_add(self.blobs)
+    }
+
+    /// Returns `true` when nothing was erased.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.total() == 0
+    }
+}
+
+/// Proof that a subject's data was erased.
+///
+/// The certificate names the subject only by user ID and records how many
+/// records of each kind were erased, so it can be kept after everything it
+/// describes is gone. Erasing a subject again yields a new certificate,
+/// which counts nothing when no data was recorded since.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErasureCertificate {
+    /// Certificate identifier.
+    pub id: ErasureCertificateId,
+    /// The user whose data was erased.
+    pub subject: UserId,
+    /// The user who requested the erasure.
+    pub requested_by: UserId,
+    /// Why the data was erased.
+    pub reason: RedactionReason,
+    /// Correlation identifier of the requesting call.
+    pub correlation_id: CorrelationId,
+    /// When the data was erased.
+    pub erased_at: DateTime<Utc>,
+    /// What was erased.
+    pub erased: ErasureCounts,
+}
+
+impl ErasureCertificate {
+    /// Certifies that `erasure` erased `erased`.
+    #[must_use]
+    pub fn new(erasure: &SubjectErasure, erased: ErasureCounts) -> Self {
+        Self {
+            id: erasure.id,
+            subject: erasure.subject,
+            requested_by: erasure.requested_by,
+            reason: erasure.reason.clone(),
+            correlation_id: erasure.correlation_id,
+            erased_at: erasure.requested_at,
+            erased,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Erasure of everything recorded about a data subject.
+//!
+//! Data protection law lets a person have their data erased. Conversation
+//! data does not name its author, so the subject is identified by the user
+//! ID the audit context recorded when their data was written. The
+//! [`services::ErasureService`] erases it in one step through a
+//! [`ports::SubjectErasureRepository`]: it redacts the subject's messages,
+//! deletes the sessions, handoffs, and context snapshots of conversations
+//! they started, anonymises the domain events they caused, and strips them
+//! from the audit log. Each erasure leaves a [`domain::ErasureCertificate`]
+//! counting what was erased, which outlives the data it describes. The
+//! module follows hexagonal architecture:
+//!
+//! - Domain types in [`domain`]
+//! - Port contracts in [`ports`]
+//! - Adapter implementations in [`adapters`]
+//! - The erasure service in [`services`]
+
+pub mod adapters;
+pub mod domain;
+pub mod ports;
+pub mod services;
+
+#[cfg(test)]
+mod tests;
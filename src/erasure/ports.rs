@@ -0,0 +1,78 @@
+//! Port contracts for subject erasure.
+
+use crate::context::{RequestContext, UserId};
+use crate::erasure::domain::{ErasureCertificate, ErasureCertificateId, SubjectErasure};
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type for erasure operations.
+pub type ErasureResult<T> = Result<T, ErasureError>;
+
+/// Store that erases a subject's data and keeps the resulting certificates.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Only the tenant's data is touched
+/// - The subject's messages are redacted in place, so conversations keep
+///   their sequence
+/// - The sessions, handoffs, and snapshots of conversations the subject
+///   started are deleted, and the conversations themselves are kept
+/// - The erasure and its certificate are recorded atomically, so a failed
+///   erasure can be rerun
+/// - Data already erased is not counted again
+#[async_trait]
+pub trait SubjectErasureRepository: Send + Sync {
+    /// Erases the subject's data and records the certificate that
+    /// describes what was erased.
+    async fn erase(
+        &self,
+        ctx: &RequestContext,
+        erasure: &SubjectErasure,
+    ) -> ErasureResult<ErasureCertificate>;
+
+    /// Returns the tenant's certificate, if it exists.
+    async fn find_certificate(
+        &self,
+        ctx: &RequestContext,
+        id: ErasureCertificateId,
+    ) -> ErasureResult<Option<ErasureCertificate>>;
+
+    /// Returns the tenant's certificates for `subject`, oldest first in
+    /// natural order.
+    async fn list_certificates(
+        &self,
+        ctx: &RequestContext,
+        subject: UserId,
+        page: PageRequest,
+    ) -> ErasureResult<Page<ErasureCertificate>>;
+}
+
+/// Errors returned by erasure repository implementations.
+#[derive(Debug, Clone, Error)]
+pub enum ErasureError {
+    /// Persistence-layer failure.
+    #[error("erasure persistence operation failed: {reason}")]
+    PersistenceFailed {
+        /// Human-readable reason from the failing dependency.
+        reason: String,
+    },
+    /// Persisted data failed validation.
+    #[error("invalid persisted erasure data: {0}")]
+    InvalidPersistedData(String),
+}
+
+impl ErasureError {
+    /// Creates a persistence failure from an infrastructure error.
+    pub fn persistence_failed(err: impl std::error::Error) -> Self {
+        Self::PersistenceFailed {
+            reason: err.to_string(),
+        }
+    }
+
+    /// Creates an invalid persisted data error.
+    pub fn invalid_persisted_data(err: impl Into<String>) -> Self {
+        Self::InvalidPersistedData(err.into())
+    }
+}
@@ -0,0 +1,119 @@
+//! The erasure service, the single entry point for erasing a subject.
+
+use super::domain::{ErasureCertificate, ErasureCertificateId, SubjectErasure};
+use super::ports::{ErasureResult, SubjectErasureRepository};
+use crate::context::{RequestContext, UserId};
+use crate::message::domain::RedactionReason;
+use crate::pagination::{Page, PageRequest};
+use mockable::Clock;
+use std::sync::Arc;
+
+/// Erases data subjects and keeps the certificates that prove it.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::{RequestContext, UserId};
+/// use corbusier::erasure::{
+///     adapters::{InMemoryErasureRepository, InMemoryErasureSources},
+///     services::ErasureService,
+/// };
+/// use corbusier::message::adapters::memory::{
+///     InMemoryAgentSessionRepository, InMemoryContextSnapshotAdapter,
+///     InMemoryConversationRepository, InMemoryHandoffAdapter, InMemoryMessageRepository,
+/// };
+/// use corbusier::message::domain::RedactionReason;
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// # async fn example(ctx: &RequestContext) -> Result<(), Box<dyn std::error::Error>> {
+/// let repository = InMemoryErasureRepository::new(InMemoryErasureSources {
+///     conversations: InMemoryConversationRepository::new(),
+///     messages: InMemoryMessageRepository::new(),
+///     sessions: InMemoryAgentSessionRepository::new(),
+///     handoffs: InMemoryHandoffAdapter::new(DefaultClock),
+///     snapshots: InMemoryContextSnapshotAdapter::new(),
+/// });
+/// let service = ErasureService::new(Arc::new(repository), Arc::new(DefaultClock));
+/// let reason = RedactionReason::new("erasure request #42")?;
+/// let certificate = service.erase_subject(ctx, UserId::new(), reason).await?;
+/// assert!(certificate.erased.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub struct ErasureService {
+    repository: Arc<dyn SubjectErasureRepository>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl ErasureService {
+    /// Creates a service over `repository`.
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn SubjectErasureRepository>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Erases everything the tenant recorded about `subject` and returns
+    /// the certificate describing what was erased.
+    ///
+    /// Messages the subject wrote are redacted with `reason`. Erasing a
+    /// subject again is harmless and certifies only what was recorded
+    /// since.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the erasure fails. Nothing is
+    /// certified then, so the erasure can simply be rerun.
+    pub async fn erase_subject(
+        &self,
+        ctx: &RequestContext,
+        subject: UserId,
+        reason: RedactionReason,
+    ) -> ErasureResult<ErasureCertificate> {
+        let erasure = SubjectErasure::new(ctx, subject, reason, &*self.clock);
+        let certificate = self.repository.erase(ctx, &erasure).await?;
+        tracing::info!(
+            certificate_id = %certificate.id,
+            subject = %subject,
+            messages = certificate.erased.messages,
+            events = certificate.erased.events,
+            sessions = certificate.erased.sessions,
+            handoffs = certificate.erased.handoffs,
+            snapshots = certificate.erased.snapshots,
+            audit_logs = certificate.erased.audit_logs,
+            "subject erased"
+        );
+        Ok(certificate)
+    }
+
+    /// Returns the tenant's certificate, if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the certificate cannot be loaded.
+    pub async fn certificate(
+        &self,
+        ctx: &RequestContext,
+        id: ErasureCertificateId,
+    ) -> ErasureResult<Option<ErasureCertificate>> {
+        self.repository.find_certificate(ctx, id).await
+    }
+
+    /// Returns the tenant's certificates for `subject`, oldest first in
+    /// natural order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the certificates cannot be loaded.
+    pub async fn certificates_for(
+        &self,
+        ctx: &RequestContext,
+        subject: UserId,
+        page: PageRequest,
+    ) -> ErasureResult<Page<ErasureCertificate>> {
+        self.repository.list_certificates(ctx, subject, page).await
+    }
+}
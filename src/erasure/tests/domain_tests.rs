@@ -0,0 +1,91 @@
+//! Unit tests for erasure domain values.
+
+use crate::context::UserId;
+use crate::erasure::domain::{ErasureCertificate, ErasureCounts, SubjectErasure};
+use crate::message::domain::{MessageId, RedactionReason};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::rstest;
+
+fn erasure() -> SubjectErasure {
+    SubjectErasure::new(
+        &test_request_ctx(),
+        UserId::new(),
+        RedactionReason::new("erasure request").expect("valid reason"),
+        &DefaultClock,
+    )
+}
+
+#[rstest]
+fn counts_total_every_kind_of_record() {
+    let counts = ErasureCounts {
+        messages: 1,
+        events: 2,
+        sessions: 3,
+        handoffs: 4,
+        snapshots: 5,
+        audit_logs: 6,
+        feedback: 7,
+        partial_messages: 8,
+        memory_facts: 9,
+        operator_actions: 10,
+        summaries: 11,
+        blobs: 12,
+    };
+
+    assert_eq!(counts.total(), 78);
+    assert!(!counts.is_empty());
+    assert!(ErasureCounts::default().is_empty());
+}
+
+#[rstest]
+fn counts_saturate_instead_of_overflowing() {
+    let counts = ErasureCounts {
+        messages: u64::MAX,
+        events: 1,
+        ..ErasureCounts::default()
+    };
+
+    assert_eq!(counts.total(), u64::MAX);
+}
+
+#[rstest]
+fn redactions_are_made_by_the_requester_when_requested() {
+    let erasure = erasure();
+    let message_id = MessageId::new();
+
+    let redaction = erasure.redaction(message_id);
+
+    assert_eq!(redaction.message_id(), message_id);
+    assert_eq!(redaction.reason(), &erasure.reason);
+    assert_eq!(redaction.redacted_by(), erasure.requested_by);
+    assert_eq!(redaction.redacted_at(), erasure.requested_at);
+}
+
+#[rstest]
+fn certificates_describe_the_erasure() {
+    let erasure = erasure();
+    let counts = ErasureCounts {
+        messages: 2,
+        ..ErasureCounts::default()
+    };
+
+    let certificate = ErasureCertificate::new(&erasure, counts);
+
+    assert_eq!(certificate.id, erasure.id);
+    assert_eq!(certificate.subject, erasure.subject);
+    assert_eq!(certificate.requested_by, erasure.requested_by);
+    assert_eq!(certificate.correlation_id, erasure.correlation_id);
+    assert_eq!(certificate.erased_at, erasure.requested_at);
+    assert_eq!(certificate.erased, counts);
+}
+
+#[rstest]
+fn certificates_round_trip_through_json() {
+    let certificate = ErasureCertificate::new(&erasure(), ErasureCounts::default());
+
+    let json = serde_json::to_string(&certificate).expect("serialise certificate");
+    let parsed: ErasureCertificate = serde_json::from_str(&json).expect("parse certificate");
+
+    assert_eq!(parsed, certificate);
+}
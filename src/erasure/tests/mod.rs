@@ -0,0 +1,4 @@
+//! Unit tests for subject erasure.
+
+mod domain_tests;
+mod service_tests;
@@ -0,0 +1,326 @@
+//! Unit tests for the erasure service.
+
+use crate::context::{CorrelationId, RequestContext, SessionId, UserId};
+use crate::erasure::{
+    adapters::{InMemoryErasureRepository, InMemoryErasureSources},
+    domain::ErasureCounts,
+    services::ErasureService,
+};
+use crate::message::{
+    adapters::memory::{
+        InMemoryAgentSessionRepository, InMemoryContextSnapshotAdapter,
+        InMemoryConversationRepository, InMemoryHandoffAdapter, InMemoryMessageRepository,
+    },
+    domain::{
+        AgentSession, ContentPart, ContextWindowSnapshot, Conversation, ConversationId, Message,
+        MessageId, MessageSummary, RedactionReason, Role, SequenceNumber, SequenceRange,
+        SnapshotParams, SnapshotType, TextPart, TurnId,
+    },
+    ports::{
+        AgentHandoffPort, AgentSessionRepository, ContextSnapshotPort, ConversationRepository,
+        MessageRepository, handoff::InitiateHandoffParams,
+    },
+};
+use crate::pagination::PageRequest;
+use crate::test_support::test_request_ctx;
+use chrono::{DateTime, Local, TimeDelta, Utc};
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+use std::sync::{Arc, Mutex, PoisonError};
+use uuid::Uuid;
+
+/// Clock that only moves when told to.
+struct ManualClock(Mutex<DateTime<Utc>>);
+
+impl ManualClock {
+    fn advance(&self, by: TimeDelta) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn local(&self) -> DateTime<Local> {
+        self.utc().with_timezone(&Local)
+    }
+
+    fn utc(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Conversation data recorded for one user.
+struct Recorded {
+    conversation_id: ConversationId,
+    message_id: MessageId,
+    session: AgentSession,
+    snapshot_id: Uuid,
+}
+
+struct Harness {
+    clock: Arc<ManualClock>,
+    sources: InMemoryErasureSources<DefaultClock>,
+    service: ErasureService,
+}
+
+impl Harness {
+    /// Records a conversation started by the user in `ctx`, with a message,
+    /// a session, a handoff, and a snapshot.
+    async fn record(&self, ctx: &RequestContext) -> Recorded {
+        let conversation = Conversation::new(&*self.clock);
+        let conversation_id = conversation.id();
+        self.sources
+            .conversations
+            .store(ctx, &conversation)
+            .await
+            .expect("store conversation");
+        let message_id = self.message(ctx, conversation_id, 1).await;
+        let session = AgentSession::new(
+            conversation_id,
+            "claude-code",
+            SequenceNumber::new(1),
+            &*self.clock,
+        );
+        self.sources
+            .sessions
+            .store(ctx, &session)
+            .await
+            .expect("store session");
+        self.sources
+            .handoffs
+            .initiate_handoff(
+                ctx,
+                InitiateHandoffParams::new(conversation_id, &session, "opus-agent", TurnId::new()),
+            )
+            .await
+            .expect("initiate handoff");
+        let snapshot = ContextWindowSnapshot::new(
+            SnapshotParams {
+                conversation_id,
+                session_id: session.session_id,
+                sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(1)),
+                message_summary: MessageSummary::default(),
+                snapshot_type: SnapshotType::SessionStart,
+            },
+            &*self.clock,
+        );
+        self.sources
+            .snapshots
+            .store_snapshot(ctx, &snapshot)
+            .await
+            .expect("store snapshot");
+        Recorded {
+            conversation_id,
+            message_id,
+            session,
+            snapshot_id: snapshot.snapshot_id,
+        }
+    }
+
+    async fn message(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        sequence: u64,
+    ) -> MessageId {
+        let message = Message::new(
+            conversation_id,
+            Role::User,
+            vec![ContentPart::Text(TextPart::new("my home address"))],
+            SequenceNumber::new(sequence),
+            &*self.clock,
+        )
+        .expect("valid message");
+        self.sources
+            .messages
+            .store(ctx, &message)
+            .await
+            .expect("store message");
+        message.id()
+    }
+
+    async fn is_redacted(&self, ctx: &RequestContext, id: MessageId) -> bool {
+        self.sources
+            .messages
+            .find_by_id(ctx, id)
+            .await
+            .expect("find message")
+            .expect("message is kept")
+            .is_redacted()
+    }
+
+    async fn is_intact(&self, ctx: &RequestContext, recorded: &Recorded) -> bool {
+        let sessions = self
+            .sources
+            .sessions
+            .find_by_conversation(ctx, recorded.conversation_id, PageRequest::default())
+            .await
+            .expect("find sessions");
+        let handoffs = self
+            .sources
+            .handoffs
+            .list_handoffs_for_conversation(ctx, recorded.conversation_id, PageRequest::default())
+            .await
+            .expect("list handoffs");
+        let snapshot = self
+            .sources
+            .snapshots
+            .find_by_id(ctx, recorded.snapshot_id)
+            .await
+            .expect("find snapshot");
+        sessions
+            .items()
+            .iter()
+            .any(|session| session.session_id == recorded.session.session_id)
+            && !handoffs.is_empty()
+            && snapshot.is_some()
+    }
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let clock = Arc::new(ManualClock(Mutex::new(DefaultClock.utc())));
+    let sources = InMemoryErasureSources {
+        conversations: InMemoryConversationRepository::new(),
+        messages: InMemoryMessageRepository::new(),
+        sessions: InMemoryAgentSessionRepository::new(),
+        handoffs: InMemoryHandoffAdapter::new(DefaultClock),
+        snapshots: InMemoryContextSnapshotAdapter::new(),
+    };
+    let repository = Arc::new(InMemoryErasureRepository::new(sources.clone()));
+    Harness {
+        service: ErasureService::new(repository, clock.clone()),
+        clock,
+        sources,
+    }
+}
+
+/// Another user of the same tenant.
+fn colleague(ctx: &RequestContext) -> RequestContext {
+    RequestContext::new(
+        ctx.tenant_id(),
+        CorrelationId::new(),
+        UserId::new(),
+        SessionId::new(),
+    )
+}
+
+fn reason() -> RedactionReason {
+    RedactionReason::new("erasure request").expect("valid reason")
+}
+
+#[rstest]
+#[tokio::test]
+async fn erasing_redacts_the_subjects_messages_only(harness: Harness) {
+    let subject = test_request_ctx();
+    let colleague = colleague(&subject);
+    let theirs = harness.record(&subject).await;
+    let reply = harness.message(&colleague, theirs.conversation_id, 2).await;
+    let in_colleagues = harness.record(&colleague).await;
+    let written = harness
+        .message(&subject, in_colleagues.conversation_id, 2)
+        .await;
+
+    let certificate = harness
+        .service
+        .erase_subject(&colleague, subject.user_id(), reason())
+        .await
+        .expect("erase subject");
+
+    assert_eq!(certificate.erased.messages, 2);
+    assert!(harness.is_redacted(&subject, theirs.message_id).await);
+    assert!(harness.is_redacted(&subject, written).await);
+    assert!(!harness.is_redacted(&subject, reply).await);
+    assert!(
+        !harness
+            .is_redacted(&subject, in_colleagues.message_id)
+            .await
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn erasing_deletes_the_agent_data_of_conversations_the_subject_started(harness: Harness) {
+    let subject = test_request_ctx();
+    let colleague = colleague(&subject);
+    let theirs = harness.record(&subject).await;
+    let others = harness.record(&colleague).await;
+
+    let certificate = harness
+        .service
+        .erase_subject(&colleague, subject.user_id(), reason())
+        .await
+        .expect("erase subject");
+
+    assert_eq!(
+        certificate.erased,
+        ErasureCounts {
+            messages: 1,
+            sessions: 1,
+            handoffs: 1,
+            snapshots: 1,
+            ..ErasureCounts::default()
+        }
+    );
+    assert!(!harness.is_intact(&subject, &theirs).await);
+    assert!(harness.is_intact(&colleague, &others).await);
+}
+
+#[rstest]
+#[tokio::test]
+async fn certificates_are_recorded_and_erasing_again_counts_nothing(harness: Harness) {
+    let subject = test_request_ctx();
+    let requester = colleague(&subject);
+    harness.record(&subject).await;
+
+    let first = harness
+        .service
+        .erase_subject(&requester, subject.user_id(), reason())
+        .await
+        .expect("erase subject");
+    harness.clock.advance(TimeDelta::minutes(5));
+    let second = harness
+        .service
+        .erase_subject(&requester, subject.user_id(), reason())
+        .await
+        .expect("erase subject again");
+
+    assert!(!first.erased.is_empty());
+    assert!(second.erased.is_empty());
+    assert_eq!(first.requested_by, requester.user_id());
+    let found = harness
+        .service
+        .certificate(&requester, first.id)
+        .await
+        .expect("find certificate");
+    assert_eq!(found, Some(first.clone()));
+    let listed = harness
+        .service
+        .certificates_for(&requester, subject.user_id(), PageRequest::default())
+        .await
+        .expect("list certificates");
+    assert_eq!(listed.items(), &[first, second]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn erasure_is_confined_to_the_tenant(harness: Harness) {
+    let subject = test_request_ctx();
+    let other_tenant = test_request_ctx();
+    let recorded = harness.record(&subject).await;
+
+    let certificate = harness
+        .service
+        .erase_subject(&other_tenant, subject.user_id(), reason())
+        .await
+        .expect("erase subject");
+
+    assert!(certificate.erased.is_empty());
+    assert!(!harness.is_redacted(&subject, recorded.message_id).await);
+    assert!(harness.is_intact(&subject, &recorded).await);
+    let elsewhere = harness
+        .service
+        .certificate(&subject, certificate.id)
+        .await
+        .expect("find certificate");
+    assert!(elsewhere.is_none());
+}
@@ -0,0 +1,89 @@
+//! Kafka event publisher (feature `kafka`).
+//!
+//! Events are produced to a single topic keyed by the identifier of the
+//! aggregate they belong to, so the events of one conversation or task stay
+//! in order within their partition. The event identifier and type travel as
+//! record headers, letting consumers deduplicate and route without decoding
+//! the payload.
+
+use crate::events::domain::DomainEvent;
+use crate::events::ports::{EventPublishError, EventPublishResult, EventPublisher};
+use async_trait::async_trait;
+use rdkafka::message::{Header, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::util::Timeout;
+use std::time::Duration;
+
+/// Record header carrying the event identifier.
+pub const EVENT_ID_HEADER: &str = "event_id";
+
+/// Record header carrying the envelope's event type.
+pub const EVENT_TYPE_HEADER: &str = "event_type";
+
+/// Publishes domain events to a Kafka topic.
+///
+/// Configure the producer with `enable.idempotence=true` so retries inside
+/// the client cannot duplicate records.
+#[derive(Clone)]
+pub struct KafkaEventPublisher {
+    producer: FutureProducer,
+    topic: String,
+    delivery_timeout: Duration,
+}
+
+impl KafkaEventPublisher {
+    /// Default time to wait for the broker to acknowledge a record.
+    pub const DEFAULT_DELIVERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Creates a publisher producing to `topic`.
+    #[must_use]
+    pub fn new(producer: FutureProducer, topic: impl Into<String>) -> Self {
+        Self {
+            producer,
+            topic: topic.into(),
+            delivery_timeout: Self::DEFAULT_DELIVERY_TIMEOUT,
+        }
+    }
+
+    /// Sets how long to wait for the broker to acknowledge a record.
+    #[must_use]
+    pub const fn with_delivery_timeout(mut self, delivery_timeout: Duration) -> Self {
+        self.delivery_timeout = delivery_timeout;
+        self
+    }
+
+    /// Returns the topic events are produced to.
+    #[must_use]
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+}
+
+#[async_trait]
+impl EventPublisher for KafkaEventPublisher {
+    async fn publish(&self, event: &DomainEvent) -> EventPublishResult<()> {
+        let payload = event
+            .encode()
+            .map_err(|err| EventPublishError::serialization(event, err))?;
+        let key = event.payload.aggregate_id().to_string();
+        let event_id = event.event_id.to_string();
+        let headers = OwnedHeaders::new()
+            .insert(Header {
+                key: EVENT_ID_HEADER,
+                value: Some(event_id.as_str()),
+            })
+            .insert(Header {
+                key: EVENT_TYPE_HEADER,
+                value: Some(event.event_type()),
+            });
+        let record = FutureRecord::to(&self.topic)
+            .key(&key)
+            .payload(&payload)
+            .headers(headers);
+        self.producer
+            .send(record, Timeout::After(self.delivery_timeout))
+            .await
+            .map_err(|(err, _)| EventPublishError::delivery_failed(event, err))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+//! In-memory event publisher for tests and embedding.
+
+use crate::events::domain::DomainEvent;
+use crate::events::ports::{EventPublishError, EventPublishResult, EventPublisher};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Thread-safe publisher that records every event it is given.
+///
+/// Events are encoded exactly as broker adapters encode them, so
+/// serialisation failures surface here too. Clones share the same record.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryEventPublisher {
+    published: Arc<RwLock<Vec<(DomainEvent, Vec<u8>)>>>,
+}
+
+impl InMemoryEventPublisher {
+    /// Creates a publisher with no recorded events.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the published events in publication order.
+    pub async fn published(&self) -> Vec<DomainEvent> {
+        self.published
+            .read()
+            .await
+            .iter()
+            .map(|(event, _)| event.clone())
+            .collect()
+    }
+
+    /// Returns the encoded envelopes in publication order.
+    pub async fn encoded(&self) -> Vec<Vec<u8>> {
+        self.published
+            .read()
+            .await
+            .iter()
+            .map(|(_, bytes)| bytes.clone())
+            .collect()
+    }
+}
+
+#[async_trait]
+impl EventPublisher for InMemoryEventPublisher {
+    async fn publish(&self, event: &DomainEvent) -> EventPublishResult<()> {
+        let bytes = event
+            .encode()
+            .map_err(|err| EventPublishError::serialization(event, err))?;
+        self.published.write().await.push((event.clone(), bytes));
+        Ok(())
+    }
+}
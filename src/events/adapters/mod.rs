@@ -0,0 +1,13 @@
+//! Adapter implementations for the event publisher port.
+
+#[cfg(feature = "kafka")]
+pub mod kafka;
+pub mod memory;
+#[cfg(feature = "nats")]
+pub mod nats;
+
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaEventPublisher;
+pub use memory::InMemoryEventPublisher;
+#[cfg(feature = "nats")]
+pub use nats::NatsJetStreamEventPublisher;
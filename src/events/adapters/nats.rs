@@ -0,0 +1,77 @@
+//! NATS `JetStream` event publisher (feature `nats`).
+//!
+//! Events are published to `{prefix}.{tenant_id}.{event_token}`, so
+//! consumers can subscribe to one tenant or one event type with subject
+//! wildcards. Each publication waits for the stream's acknowledgement and
+//! carries the event identifier in the `Nats-Msg-Id` header, so `JetStream`
+//! discards a retried duplicate within the stream's deduplication window.
+
+use crate::events::domain::DomainEvent;
+use crate::events::ports::{EventPublishError, EventPublishResult, EventPublisher};
+use async_nats::{HeaderMap, header::NATS_MESSAGE_ID, jetstream};
+use async_trait::async_trait;
+
+/// Header carrying the envelope's event type.
+pub const EVENT_TYPE_HEADER: &str = "Corbusier-Event-Type";
+
+/// Publishes domain events to a NATS `JetStream` stream.
+#[derive(Clone)]
+pub struct NatsJetStreamEventPublisher {
+    context: jetstream::Context,
+    subject_prefix: String,
+}
+
+impl NatsJetStreamEventPublisher {
+    /// Default prefix of published subjects.
+    pub const DEFAULT_SUBJECT_PREFIX: &'static str = "corbusier.events";
+
+    /// Creates a publisher using `context`, publishing under
+    /// [`Self::DEFAULT_SUBJECT_PREFIX`].
+    ///
+    /// A stream capturing the published subjects must already exist.
+    #[must_use]
+    pub fn new(context: jetstream::Context) -> Self {
+        Self {
+            context,
+            subject_prefix: Self::DEFAULT_SUBJECT_PREFIX.to_owned(),
+        }
+    }
+
+    /// Sets the prefix of published subjects.
+    #[must_use]
+    pub fn with_subject_prefix(mut self, subject_prefix: impl Into<String>) -> Self {
+        self.subject_prefix = subject_prefix.into();
+        self
+    }
+
+    /// Returns the subject `event` is published to.
+    #[must_use]
+    pub fn subject(&self, event: &DomainEvent) -> String {
+        format!(
+            "{}.{}.{}",
+            self.subject_prefix,
+            event.tenant_id,
+            event.payload.event_token()
+        )
+    }
+}
+
+#[async_trait]
+impl EventPublisher for NatsJetStreamEventPublisher {
+    async fn publish(&self, event: &DomainEvent) -> EventPublishResult<()> {
+        let payload = event
+            .encode()
+            .map_err(|err| EventPublishError::serialization(event, err))?;
+        let mut headers = HeaderMap::new();
+        headers.insert(NATS_MESSAGE_ID, event.event_id.to_string().as_str());
+        headers.insert(EVENT_TYPE_HEADER, event.event_type());
+        let ack = self
+            .context
+            .publish_with_headers(self.subject(event), headers, payload.into())
+            .await
+            .map_err(|err| EventPublishError::delivery_failed(event, err))?;
+        ack.await
+            .map_err(|err| EventPublishError::delivery_failed(event, err))?;
+        Ok(())
+    }
+}
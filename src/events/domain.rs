@@ -0,0 +1,250 @@
+//! Domain events announced to downstream systems.
+
+use crate::context::{CorrelationId, RequestContext, TenantId};
+use crate::message::{
+    canonical,
+    domain::{ConversationId, HandoffId, MessageId, Role, SequenceNumber},
+    versioning::{EventFormat, EventMetadata, VersionedEvent},
+};
+use crate::task::domain::{TaskId, TaskState};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+/// Schema version of published event data.
+pub const DOMAIN_EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Source recorded in the metadata of published events.
+pub const DOMAIN_EVENT_SOURCE: &str = "corbusier";
+
+/// Unique identifier of a published event.
+///
+/// Brokers that deduplicate deliveries key on this identifier, so a retried
+/// publication of the same event is delivered once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DomainEventId(Uuid);
+
+impl DomainEventId {
+    /// Creates a new random identifier.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Creates an identifier from an existing UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Returns the wrapped UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for DomainEventId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for DomainEventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// What happened, with the identifiers downstream systems need to react.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DomainEventPayload {
+    /// A message was stored in a conversation.
+    MessageCreated {
+        /// The conversation the message belongs to.
+        conversation_id: ConversationId,
+        /// The stored message.
+        message_id: MessageId,
+        /// The message's position in the conversation.
+        sequence_number: SequenceNumber,
+        /// The message author's role.
+        role: Role,
+    },
+    /// A handoff between agent sessions completed.
+    HandoffCompleted {
+        /// The conversation the handoff happened in.
+        conversation_id: ConversationId,
+        /// The completed handoff.
+        handoff_id: HandoffId,
+        /// The agent backend that took over.
+        target_agent: String,
+    },
+    /// A task moved between lifecycle states.
+    TaskStateChanged {
+        /// The task that changed.
+        task_id: TaskId,
+        /// The state the task left.
+        from: TaskState,
+        /// The state the task entered.
+        to: TaskState,
+    },
+}
+
+impl DomainEventPayload {
+    /// Returns the event type carried in the envelope.
+    #[must_use]
+    pub const fn event_type(&self) -> &'static str {
+        match self {
+            Self::MessageCreated { .. } => "MessageCreated",
+            Self::HandoffCompleted { .. } => "HandoffCompleted",
+            Self::TaskStateChanged { .. } => "TaskStateChanged",
+        }
+    }
+
+    /// Returns the event type as a lowercase token for subjects and
+    /// headers.
+    #[must_use]
+    pub const fn event_token(&self) -> &'static str {
+        match self {
+            Self::MessageCreated { .. } => "message_created",
+            Self::HandoffCompleted { .. } => "handoff_completed",
+            Self::TaskStateChanged { .. } => "task_state_changed",
+        }
+    }
+
+    /// Returns the identifier of the aggregate the event belongs to.
+    ///
+    /// Brokers that partition by key keep the events of one aggregate in
+    /// order.
+    #[must_use]
+    pub const fn aggregate_id(&self) -> Uuid {
+        match self {
+            Self::MessageCreated {
+                conversation_id, ..
+            }
+            | Self::HandoffCompleted {
+                conversation_id, ..
+            } => conversation_id.into_inner(),
+            Self::TaskStateChanged { task_id, .. } => task_id.into_inner(),
+        }
+    }
+}
+
+/// A domain event ready for publication.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+/// use corbusier::events::domain::{DomainEvent, DomainEventPayload};
+/// use corbusier::task::domain::{TaskId, TaskState};
+/// use chrono::Utc;
+///
+/// let ctx = RequestContext::new(
+///     TenantId::new(),
+///     CorrelationId::new(),
+///     UserId::new(),
+///     SessionId::new(),
+/// );
+/// let event = DomainEvent::new(
+///     &ctx,
+///     DomainEventPayload::TaskStateChanged {
+///         task_id: TaskId::new(),
+///         from: TaskState::Draft,
+///         to: TaskState::InProgress,
+///     },
+///     Utc::now(),
+/// );
+/// let envelope = event.to_versioned_event().expect("serialisable");
+/// assert_eq!(envelope.event_type(), "TaskStateChanged");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainEvent {
+    /// Unique identifier of the event.
+    pub event_id: DomainEventId,
+    /// The tenant that owns the changed aggregate.
+    pub tenant_id: TenantId,
+    /// Correlation identifier of the request that caused the event.
+    pub correlation_id: CorrelationId,
+    /// When the change happened.
+    pub occurred_at: DateTime<Utc>,
+    /// What happened.
+    pub payload: DomainEventPayload,
+}
+
+/// The envelope data of a published event.
+#[derive(Serialize)]
+struct EventData<'a> {
+    event_id: DomainEventId,
+    tenant_id: TenantId,
+    #[serde(flatten)]
+    payload: &'a DomainEventPayload,
+}
+
+impl DomainEvent {
+    /// Creates an event for a change made under `ctx`.
+    #[must_use]
+    pub fn new(
+        ctx: &RequestContext,
+        payload: DomainEventPayload,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            event_id: DomainEventId::new(),
+            tenant_id: ctx.tenant_id(),
+            correlation_id: ctx.correlation_id(),
+            occurred_at,
+            payload,
+        }
+    }
+
+    /// Returns the event type carried in the envelope.
+    #[must_use]
+    pub const fn event_type(&self) -> &'static str {
+        self.payload.event_type()
+    }
+
+    /// Wraps the event in the versioned envelope.
+    ///
+    /// The envelope data carries the event and tenant identifiers alongside
+    /// the payload fields; the metadata carries the time, source, and
+    /// correlation identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serialisation error when the payload cannot be
+    /// represented as JSON.
+    pub fn to_versioned_event(&self) -> Result<VersionedEvent, serde_json::Error> {
+        let data = serde_json::to_value(EventData {
+            event_id: self.event_id,
+            tenant_id: self.tenant_id,
+            payload: &self.payload,
+        })?;
+        let metadata = EventMetadata {
+            occurred_at: self.occurred_at,
+            source: Some(DOMAIN_EVENT_SOURCE.to_owned()),
+            correlation_id: Some(self.correlation_id.to_string()),
+            format: EventFormat::Json,
+        };
+        Ok(VersionedEvent::with_metadata(
+            DOMAIN_EVENT_SCHEMA_VERSION,
+            self.event_type(),
+            data,
+            metadata,
+        ))
+    }
+
+    /// Serialises the versioned envelope to canonical JSON bytes, the wire
+    /// format every publisher adapter sends.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serialisation error when the payload cannot be
+    /// represented as JSON.
+    pub fn encode(&self) -> Result<Vec<u8>, serde_json::Error> {
+        canonical::to_canonical_vec(&self.to_versioned_event()?)
+    }
+}
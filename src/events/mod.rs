@@ -0,0 +1,22 @@
+//! Publication of domain events to downstream systems.
+//!
+//! Downstream systems react to messages being created, handoffs completing,
+//! and tasks changing state. Workflows describe each of these as a
+//! [`domain::DomainEvent`] and hand it to an [`ports::EventPublisher`], which
+//! delivers it wrapped in the [`crate::message::versioning::VersionedEvent`]
+//! envelope and serialised as canonical JSON. The module follows hexagonal
+//! architecture:
+//!
+//! - Domain types in [`domain`]
+//! - Port contracts in [`ports`]
+//! - Adapter implementations in [`adapters`], with NATS `JetStream` behind
+//!   the `nats` feature and Kafka behind the `kafka` feature
+//! - The lifecycle hook that publishes conversation events in [`services`]
+
+pub mod adapters;
+pub mod domain;
+pub mod ports;
+pub mod services;
+
+#[cfg(test)]
+mod tests;
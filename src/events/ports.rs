@@ -0,0 +1,67 @@
+//! Port contract for publishing domain events.
+
+use crate::events::domain::{DomainEvent, DomainEventId};
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type for event publisher operations.
+pub type EventPublishResult<T> = Result<T, EventPublishError>;
+
+/// Delivers domain events to a message broker.
+///
+/// # Implementation Notes
+///
+/// Events are published after the change they describe has been persisted,
+/// so a failed publication cannot undo the change. Implementations should
+/// return only once the broker has accepted the event, and should pass
+/// [`DomainEvent::event_id`] to brokers that deduplicate, so callers may
+/// retry safely.
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    /// Publishes one event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EventPublishError`] when the event cannot be serialised or
+    /// the broker does not accept it.
+    async fn publish(&self, event: &DomainEvent) -> EventPublishResult<()>;
+}
+
+/// Errors returned by event publisher implementations.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EventPublishError {
+    /// The event could not be serialised into its envelope.
+    #[error("failed to serialise event {event_id}: {reason}")]
+    Serialization {
+        /// The event that could not be serialised.
+        event_id: DomainEventId,
+        /// Human-readable reason from the serialiser.
+        reason: String,
+    },
+    /// The broker did not accept the event.
+    #[error("failed to deliver event {event_id}: {reason}")]
+    DeliveryFailed {
+        /// The event that was not delivered.
+        event_id: DomainEventId,
+        /// Human-readable reason from the broker client.
+        reason: String,
+    },
+}
+
+impl EventPublishError {
+    /// Creates a serialisation failure for `event`.
+    pub fn serialization(event: &DomainEvent, err: impl std::error::Error) -> Self {
+        Self::Serialization {
+            event_id: event.event_id,
+            reason: err.to_string(),
+        }
+    }
+
+    /// Creates a delivery failure for `event`.
+    pub fn delivery_failed(event: &DomainEvent, err: impl std::error::Error) -> Self {
+        Self::DeliveryFailed {
+            event_id: event.event_id,
+            reason: err.to_string(),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Services that turn workflow changes into published domain events.
+//!
+//! [`EventPublishingHook`] is a [`ConversationLifecycleHook`]: register it
+//! with [`crate::message::services::ConversationLifecycleHooks`] for
+//! [`EventPublishingHook::POINTS`] and every stored message and completed
+//! handoff is published. Task state changes are published by
+//! [`crate::task::services::TaskLifecycleService::with_event_publisher`].
+
+use crate::context::RequestContext;
+use crate::events::{
+    domain::{DomainEvent, DomainEventPayload},
+    ports::EventPublisher,
+};
+use crate::message::{
+    domain::{ConversationLifecycleChange, ConversationLifecycleEvent, ConversationLifecyclePoint},
+    ports::{ConversationLifecycleHook, LifecycleHookError, LifecycleHookResult},
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Lifecycle hook that publishes conversation events.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::events::{adapters::InMemoryEventPublisher, services::EventPublishingHook};
+/// use corbusier::message::services::ConversationLifecycleHooks;
+/// use std::sync::Arc;
+///
+/// let mut hooks = ConversationLifecycleHooks::new();
+/// hooks.register(
+///     "event-publisher",
+///     EventPublishingHook::POINTS,
+///     Arc::new(EventPublishingHook::new(Arc::new(InMemoryEventPublisher::new()))),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct EventPublishingHook {
+    publisher: Arc<dyn EventPublisher>,
+}
+
+impl EventPublishingHook {
+    /// The lifecycle points whose events are published.
+    pub const POINTS: [ConversationLifecyclePoint; 2] = [
+        ConversationLifecyclePoint::MessageStored,
+        ConversationLifecyclePoint::HandoffCompleted,
+    ];
+
+    /// Creates a hook publishing through `publisher`.
+    #[must_use]
+    pub fn new(publisher: Arc<dyn EventPublisher>) -> Self {
+        Self { publisher }
+    }
+}
+
+#[async_trait]
+impl ConversationLifecycleHook for EventPublishingHook {
+    async fn on_event(
+        &self,
+        ctx: &RequestContext,
+        event: &ConversationLifecycleEvent,
+    ) -> LifecycleHookResult {
+        let Some(domain_event) = domain_event_for(ctx, event) else {
+            return Ok(());
+        };
+        self.publisher
+            .publish(&domain_event)
+            .await
+            .map_err(|err| LifecycleHookError::new(err.to_string()))
+    }
+}
+
+/// Maps a lifecycle event to the domain event it publishes, if any.
+fn domain_event_for(
+    ctx: &RequestContext,
+    event: &ConversationLifecycleEvent,
+) -> Option<DomainEvent> {
+    let payload = match &event.change {
+        ConversationLifecycleChange::MessageStored {
+            message_id,
+            sequence_number,
+            role,
+        } => DomainEventPayload::MessageCreated {
+            conversation_id: event.conversation_id,
+            message_id: *message_id,
+            sequence_number: *sequence_number,
+            role: *role,
+        },
+        ConversationLifecycleChange::HandoffCompleted {
+            handoff_id,
+            target_agent,
+        } => DomainEventPayload::HandoffCompleted {
+            conversation_id: event.conversation_id,
+            handoff_id: *handoff_id,
+            target_agent: target_agent.clone(),
+        },
+        ConversationLifecycleChange::Created
+        | ConversationLifecycleChange::TurnStarted
+        | ConversationLifecycleChange::HandoffRejected { .. }
+        | ConversationLifecycleChange::Archived => return None,
+    };
+    Some(DomainEvent::new(ctx, payload, event.occurred_at))
+}
@@ -0,0 +1,125 @@
+//! Unit tests for domain events and their versioned envelope.
+
+use crate::context::RequestContext;
+use crate::events::domain::{
+    DOMAIN_EVENT_SCHEMA_VERSION, DOMAIN_EVENT_SOURCE, DomainEvent, DomainEventPayload,
+};
+use crate::message::domain::{ConversationId, HandoffId, MessageId, Role, SequenceNumber};
+use crate::task::domain::{TaskId, TaskState};
+use crate::test_support::test_request_ctx;
+use chrono::{TimeZone, Utc};
+use rstest::{fixture, rstest};
+use serde_json::{Value, json};
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+fn message_created(conversation_id: ConversationId, message_id: MessageId) -> DomainEventPayload {
+    DomainEventPayload::MessageCreated {
+        conversation_id,
+        message_id,
+        sequence_number: SequenceNumber::new(4),
+        role: Role::Assistant,
+    }
+}
+
+#[rstest]
+fn envelopes_carry_the_payload_and_request_metadata(ctx: RequestContext) {
+    let conversation_id = ConversationId::new();
+    let message_id = MessageId::new();
+    let occurred_at = Utc
+        .with_ymd_and_hms(2026, 5, 1, 12, 0, 0)
+        .single()
+        .expect("valid timestamp");
+    let event = DomainEvent::new(
+        &ctx,
+        message_created(conversation_id, message_id),
+        occurred_at,
+    );
+
+    let envelope = event.to_versioned_event().expect("serialisable");
+
+    assert_eq!(envelope.version(), DOMAIN_EVENT_SCHEMA_VERSION);
+    assert_eq!(envelope.event_type(), "MessageCreated");
+    assert_eq!(
+        envelope.data(),
+        &json!({
+            "event_id": event.event_id,
+            "tenant_id": ctx.tenant_id(),
+            "conversation_id": conversation_id,
+            "message_id": message_id,
+            "sequence_number": 4,
+            "role": "assistant",
+        })
+    );
+    let metadata = envelope.metadata();
+    assert_eq!(metadata.occurred_at, occurred_at);
+    assert_eq!(metadata.source.as_deref(), Some(DOMAIN_EVENT_SOURCE));
+    assert_eq!(
+        metadata.correlation_id,
+        Some(ctx.correlation_id().to_string())
+    );
+}
+
+#[rstest]
+fn encoded_events_are_canonical_json(ctx: RequestContext) {
+    let event = DomainEvent::new(
+        &ctx,
+        DomainEventPayload::TaskStateChanged {
+            task_id: TaskId::new(),
+            from: TaskState::InProgress,
+            to: TaskState::InReview,
+        },
+        Utc::now(),
+    );
+
+    let bytes = event.encode().expect("encodable");
+    let text = String::from_utf8(bytes).expect("utf-8");
+    let parsed: Value = serde_json::from_str(&text).expect("json");
+
+    assert!(text.starts_with(r#"{"data":{"event_id":"#));
+    assert_eq!(parsed.to_string(), text);
+    assert_eq!(parsed.get("event_type"), Some(&json!("TaskStateChanged")));
+    assert_eq!(parsed.pointer("/data/from"), Some(&json!("in_progress")));
+    assert_eq!(parsed.pointer("/data/to"), Some(&json!("in_review")));
+}
+
+#[rstest]
+#[case(
+    DomainEventPayload::HandoffCompleted {
+        conversation_id: ConversationId::new(),
+        handoff_id: HandoffId::new(),
+        target_agent: "codex_cli".to_owned(),
+    },
+    "HandoffCompleted",
+    "handoff_completed"
+)]
+#[case(
+    message_created(ConversationId::new(), MessageId::new()),
+    "MessageCreated",
+    "message_created"
+)]
+fn payloads_name_their_event_type(
+    #[case] payload: DomainEventPayload,
+    #[case] event_type: &str,
+    #[case] token: &str,
+) {
+    assert_eq!(payload.event_type(), event_type);
+    assert_eq!(payload.event_token(), token);
+}
+
+#[rstest]
+fn conversation_events_are_keyed_by_conversation() {
+    let conversation_id = ConversationId::new();
+    let handoff = DomainEventPayload::HandoffCompleted {
+        conversation_id,
+        handoff_id: HandoffId::new(),
+        target_agent: "codex_cli".to_owned(),
+    };
+    let message = message_created(conversation_id, MessageId::new());
+
+    assert_eq!(handoff.aggregate_id(), conversation_id.into_inner());
+    assert_eq!(message.aggregate_id(), conversation_id.into_inner());
+}
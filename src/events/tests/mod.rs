@@ -0,0 +1,4 @@
+//! Unit tests for domain event publication.
+
+mod domain_tests;
+mod service_tests;
@@ -0,0 +1,236 @@
+//! Unit tests for publishing conversation and task events.
+
+use crate::context::RequestContext;
+use crate::events::{
+    adapters::InMemoryEventPublisher,
+    domain::{DomainEvent, DomainEventPayload},
+    ports::{EventPublishError, EventPublishResult, EventPublisher},
+    services::EventPublishingHook,
+};
+use crate::message::{
+    domain::{
+        ConversationId, ConversationLifecycleChange, ConversationLifecycleEvent, HandoffId,
+        MessageId, Role, SequenceNumber,
+    },
+    ports::ConversationLifecycleHook,
+};
+use crate::task::{
+    adapters::memory::InMemoryTaskRepository,
+    domain::TaskState,
+    services::{
+        AssociatePullRequestRequest, CreateTaskFromIssueRequest, TaskLifecycleService,
+        TransitionTaskRequest,
+    },
+};
+use crate::test_support::test_request_ctx;
+use async_trait::async_trait;
+use chrono::Utc;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+#[fixture]
+fn publisher() -> Arc<InMemoryEventPublisher> {
+    Arc::new(InMemoryEventPublisher::new())
+}
+
+struct UnreachableBroker;
+
+#[async_trait]
+impl EventPublisher for UnreachableBroker {
+    async fn publish(&self, event: &DomainEvent) -> EventPublishResult<()> {
+        Err(EventPublishError::DeliveryFailed {
+            event_id: event.event_id,
+            reason: "broker unreachable".to_owned(),
+        })
+    }
+}
+
+fn lifecycle_event(change: ConversationLifecycleChange) -> ConversationLifecycleEvent {
+    ConversationLifecycleEvent::new(ConversationId::new(), change, Utc::now())
+}
+
+#[rstest]
+#[tokio::test]
+async fn stored_messages_are_published_as_message_created(
+    ctx: RequestContext,
+    publisher: Arc<InMemoryEventPublisher>,
+) {
+    let hook = EventPublishingHook::new(Arc::clone(&publisher) as Arc<dyn EventPublisher>);
+    let message_id = MessageId::new();
+    let event = lifecycle_event(ConversationLifecycleChange::MessageStored {
+        message_id,
+        sequence_number: SequenceNumber::new(2),
+        role: Role::User,
+    });
+
+    hook.on_event(&ctx, &event).await.expect("published");
+
+    let published = publisher.published().await;
+    assert_eq!(published.len(), 1);
+    let domain_event = published.first().expect("one event");
+    assert_eq!(domain_event.tenant_id, ctx.tenant_id());
+    assert_eq!(domain_event.occurred_at, event.occurred_at);
+    assert_eq!(
+        domain_event.payload,
+        DomainEventPayload::MessageCreated {
+            conversation_id: event.conversation_id,
+            message_id,
+            sequence_number: SequenceNumber::new(2),
+            role: Role::User,
+        }
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn completed_handoffs_are_published(
+    ctx: RequestContext,
+    publisher: Arc<InMemoryEventPublisher>,
+) {
+    let hook = EventPublishingHook::new(Arc::clone(&publisher) as Arc<dyn EventPublisher>);
+    let handoff_id = HandoffId::new();
+    let event = lifecycle_event(ConversationLifecycleChange::HandoffCompleted {
+        handoff_id,
+        target_agent: "codex_cli".to_owned(),
+    });
+
+    hook.on_event(&ctx, &event).await.expect("published");
+
+    let payloads: Vec<_> = publisher
+        .published()
+        .await
+        .into_iter()
+        .map(|published| published.payload)
+        .collect();
+    assert_eq!(
+        payloads,
+        [DomainEventPayload::HandoffCompleted {
+            conversation_id: event.conversation_id,
+            handoff_id,
+            target_agent: "codex_cli".to_owned(),
+        }]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn other_lifecycle_points_are_not_published(
+    ctx: RequestContext,
+    publisher: Arc<InMemoryEventPublisher>,
+) {
+    let hook = EventPublishingHook::new(Arc::clone(&publisher) as Arc<dyn EventPublisher>);
+
+    for change in [
+        ConversationLifecycleChange::Created,
+        ConversationLifecycleChange::TurnStarted,
+        ConversationLifecycleChange::Archived,
+    ] {
+        hook.on_event(&ctx, &lifecycle_event(change))
+            .await
+            .expect("ignored");
+    }
+
+    assert!(publisher.published().await.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn delivery_failures_are_reported_to_the_hook_registry(ctx: RequestContext) {
+    let hook = EventPublishingHook::new(Arc::new(UnreachableBroker));
+    let event = lifecycle_event(ConversationLifecycleChange::MessageStored {
+        message_id: MessageId::new(),
+        sequence_number: SequenceNumber::new(1),
+        role: Role::User,
+    });
+
+    let err = hook
+        .on_event(&ctx, &event)
+        .await
+        .expect_err("delivery fails");
+
+    assert!(err.reason().contains("broker unreachable"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn task_state_changes_are_published(
+    ctx: RequestContext,
+    publisher: Arc<InMemoryEventPublisher>,
+) {
+    let service = TaskLifecycleService::new(
+        Arc::new(InMemoryTaskRepository::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_event_publisher(Arc::clone(&publisher) as Arc<dyn EventPublisher>);
+    let task = service
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "owner/repo", 12, "Publish events"),
+        )
+        .await
+        .expect("create");
+
+    service
+        .transition_task(&ctx, TransitionTaskRequest::new(task.id(), "in_progress"))
+        .await
+        .expect("start");
+    service
+        .associate_pull_request(
+            &ctx,
+            AssociatePullRequestRequest::new(task.id(), "github", "owner/repo", 34),
+        )
+        .await
+        .expect("open pull request");
+
+    let transitions: Vec<_> = publisher
+        .published()
+        .await
+        .into_iter()
+        .map(|published| published.payload)
+        .collect();
+    assert_eq!(
+        transitions,
+        [
+            DomainEventPayload::TaskStateChanged {
+                task_id: task.id(),
+                from: TaskState::Draft,
+                to: TaskState::InProgress,
+            },
+            DomainEventPayload::TaskStateChanged {
+                task_id: task.id(),
+                from: TaskState::InProgress,
+                to: TaskState::InReview,
+            },
+        ]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn task_transitions_succeed_when_publication_fails(ctx: RequestContext) {
+    let service = TaskLifecycleService::new(
+        Arc::new(InMemoryTaskRepository::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_event_publisher(Arc::new(UnreachableBroker));
+    let task = service
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "owner/repo", 13, "Broker outage"),
+        )
+        .await
+        .expect("create");
+
+    let started = service
+        .transition_task(&ctx, TransitionTaskRequest::new(task.id(), "in_progress"))
+        .await
+        .expect("transition is persisted despite the outage");
+
+    assert_eq!(started.state(), TaskState::InProgress);
+}
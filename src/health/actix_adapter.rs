@@ -31,11 +31,11 @@ pub fn health_routes(cfg: &mut web::ServiceConfig) {
 }
 
 async fn liveness(check: web::Data<dyn HealthCheck>) -> HttpResponse {
-    liveness_status_to_response(check.liveness())
+    liveness_status_to_response(check.liveness().await)
 }
 
 async fn readiness(check: web::Data<dyn HealthCheck>) -> HttpResponse {
-    readiness_status_to_response(check.readiness())
+    readiness_status_to_response(check.readiness().await)
 }
 
 fn liveness_status_to_response(status: HealthStatus) -> HttpResponse {
@@ -62,6 +62,7 @@ mod tests {
 
     use actix_web::App;
     use actix_web::test as actix_test;
+    use async_trait::async_trait;
     use rstest::rstest;
 
     use super::*;
@@ -71,12 +72,13 @@ mod tests {
         readiness: HealthStatus,
     }
 
+    #[async_trait]
     impl HealthCheck for TestHealthCheck {
-        fn liveness(&self) -> HealthStatus {
+        async fn liveness(&self) -> HealthStatus {
             self.liveness
         }
 
-        fn readiness(&self) -> HealthStatus {
+        async fn readiness(&self) -> HealthStatus {
             self.readiness
         }
     }
@@ -4,15 +4,25 @@
 //! (`HealthStatus`) and a port trait (`HealthCheck`) that adapters
 //! (HTTP, gRPC, CLI) call to obtain liveness and readiness status.
 //!
-//! The `SimpleHealthCheck` implementation always reports `Healthy`,
-//! suitable for the initial deployment. Future milestones can inject
-//! real dependency probes (Postgres connectivity, Valkey ping) via
-//! the same port.
+//! The `SimpleHealthCheck` implementation always reports `Healthy`. A
+//! deployment with dependencies uses [`report::ReadinessProbe`] instead,
+//! which asks each [`report::ComponentHealthCheck`] — every Postgres
+//! adapter implements one — and combines the answers into a
+//! [`report::HealthReport`].
 
 pub mod actix_adapter;
+pub mod report;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+pub use report::{
+    ComponentHealth, ComponentHealthCheck, HealthReport, PoolStatistics, ReadinessProbe,
+};
 
 /// Health status reported by the application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum HealthStatus {
     /// The application is fully operational.
     Healthy,
@@ -22,15 +32,39 @@ pub enum HealthStatus {
     Unhealthy,
 }
 
+impl HealthStatus {
+    /// Returns the worse of two statuses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use corbusier::health::HealthStatus;
+    ///
+    /// assert_eq!(
+    ///     HealthStatus::Degraded.worst(HealthStatus::Healthy),
+    ///     HealthStatus::Degraded
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn worst(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Unhealthy, _) | (_, Self::Unhealthy) => Self::Unhealthy,
+            (Self::Degraded, _) | (_, Self::Degraded) => Self::Degraded,
+            (Self::Healthy, Self::Healthy) => Self::Healthy,
+        }
+    }
+}
+
 /// Port for health observation.
 ///
 /// Adapters (HTTP, gRPC, CLI) call this to obtain the application's
 /// liveness and readiness status.
+#[async_trait]
 pub trait HealthCheck: Send + Sync {
     /// Report whether the process is alive.
-    fn liveness(&self) -> HealthStatus;
+    async fn liveness(&self) -> HealthStatus;
     /// Report whether the process is ready to serve traffic.
-    fn readiness(&self) -> HealthStatus;
+    async fn readiness(&self) -> HealthStatus;
 }
 
 /// Simple health check that always reports [`HealthStatus::Healthy`].
@@ -40,17 +74,20 @@ pub trait HealthCheck: Send + Sync {
 /// ```
 /// use corbusier::health::{HealthCheck, HealthStatus, SimpleHealthCheck};
 ///
+/// # async fn example() {
 /// let check = SimpleHealthCheck;
-/// assert_eq!(check.liveness(), HealthStatus::Healthy);
-/// assert_eq!(check.readiness(), HealthStatus::Healthy);
+/// assert_eq!(check.liveness().await, HealthStatus::Healthy);
+/// assert_eq!(check.readiness().await, HealthStatus::Healthy);
+/// # }
 /// ```
 pub struct SimpleHealthCheck;
 
+#[async_trait]
 impl HealthCheck for SimpleHealthCheck {
-    fn liveness(&self) -> HealthStatus {
+    async fn liveness(&self) -> HealthStatus {
         HealthStatus::Healthy
     }
-    fn readiness(&self) -> HealthStatus {
+    async fn readiness(&self) -> HealthStatus {
         HealthStatus::Healthy
     }
 }
@@ -60,11 +97,34 @@ mod tests {
     //! Tests for health-check response and state transitions.
 
     use super::*;
+    use rstest::rstest;
 
-    #[test]
-    fn simple_health_check_reports_healthy() {
+    #[tokio::test]
+    async fn simple_health_check_reports_healthy() {
         let check = SimpleHealthCheck;
-        assert_eq!(check.liveness(), HealthStatus::Healthy);
-        assert_eq!(check.readiness(), HealthStatus::Healthy);
+        assert_eq!(check.liveness().await, HealthStatus::Healthy);
+        assert_eq!(check.readiness().await, HealthStatus::Healthy);
+    }
+
+    #[rstest]
+    #[case(HealthStatus::Healthy, HealthStatus::Healthy, HealthStatus::Healthy)]
+    #[case(HealthStatus::Healthy, HealthStatus::Degraded, HealthStatus::Degraded)]
+    #[case(
+        HealthStatus::Unhealthy,
+        HealthStatus::Degraded,
+        HealthStatus::Unhealthy
+    )]
+    #[case(
+        HealthStatus::Degraded,
+        HealthStatus::Unhealthy,
+        HealthStatus::Unhealthy
+    )]
+    fn worst_prefers_the_less_healthy_status(
+        #[case] left: HealthStatus,
+        #[case] right: HealthStatus,
+        #[case] expected: HealthStatus,
+    ) {
+        assert_eq!(left.worst(right), expected);
+        assert_eq!(right.worst(left), expected);
     }
 }
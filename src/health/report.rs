@@ -0,0 +1,354 @@
+//! Readiness of the components a deployment depends on.
+//!
+//! Each adapter holding a connection pool implements
+//! [`ComponentHealthCheck`], answering with a cheap round trip and the
+//! state of its pool. [`ReadinessProbe`] asks every registered check,
+//! gathers the answers into a [`HealthReport`], and serves the combined
+//! status through the [`HealthCheck`] port, so the HTTP readiness route
+//! fails while a database is unreachable.
+
+use super::{HealthCheck, HealthStatus};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use mockable::Clock;
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How long a component may take to answer before it counts as unhealthy.
+pub const DEFAULT_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Connection counts of a pool at the time of a check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PoolStatistics {
+    /// Connections currently open, idle or in use.
+    pub connections: u32,
+    /// Open connections waiting for work.
+    pub idle_connections: u32,
+    /// Most connections the pool will open.
+    pub max_size: u32,
+}
+
+impl PoolStatistics {
+    /// Returns `true` when every connection the pool may open is in use.
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        self.idle_connections == 0 && self.connections >= self.max_size
+    }
+}
+
+/// The answer of one component to a health check.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::health::{ComponentHealth, HealthStatus, PoolStatistics};
+///
+/// let health = ComponentHealth::healthy("message_repository").with_pool(PoolStatistics {
+///     connections: 10,
+///     idle_connections: 0,
+///     max_size: 10,
+/// });
+/// assert_eq!(health.status, HealthStatus::Degraded);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ComponentHealth {
+    /// Which component answered, such as `message_repository`.
+    pub component: String,
+    /// How the component is doing.
+    pub status: HealthStatus,
+    /// Why the component is unhealthy, when it is.
+    pub detail: Option<String>,
+    /// The state of the component's connection pool, when it has one.
+    pub pool: Option<PoolStatistics>,
+    /// How long the check took, in milliseconds.
+    pub elapsed_ms: u64,
+}
+
+impl ComponentHealth {
+    /// Creates a healthy answer.
+    #[must_use]
+    pub fn healthy(component: impl Into<String>) -> Self {
+        Self {
+            component: component.into(),
+            status: HealthStatus::Healthy,
+            detail: None,
+            pool: None,
+            elapsed_ms: 0,
+        }
+    }
+
+    /// Creates an unhealthy answer explaining what failed.
+    #[must_use]
+    pub fn unhealthy(component: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            status: HealthStatus::Unhealthy,
+            detail: Some(reason.into()),
+            ..Self::healthy(component)
+        }
+    }
+
+    /// Records the state of the component's pool, marking a healthy
+    /// component degraded when the pool has no connection to spare.
+    #[must_use]
+    pub fn with_pool(mut self, pool: PoolStatistics) -> Self {
+        if pool.is_exhausted() {
+            self.status = self.status.worst(HealthStatus::Degraded);
+        }
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Records how long the check took.
+    #[must_use]
+    pub fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        self
+    }
+}
+
+/// Port for checking one dependency, implemented by each Postgres adapter.
+#[async_trait]
+pub trait ComponentHealthCheck: Send + Sync {
+    /// Returns the name the component reports under.
+    fn component(&self) -> &str;
+
+    /// Checks the component with a cheap round trip.
+    async fn check_health(&self) -> ComponentHealth;
+}
+
+/// The answers of every component checked at one time.
+///
+/// The report's status is the worst of its components', and a report with
+/// no components is healthy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthReport {
+    checked_at: DateTime<Utc>,
+    status: HealthStatus,
+    components: Vec<ComponentHealth>,
+}
+
+impl HealthReport {
+    /// Creates a report from the answers gathered at `checked_at`.
+    #[must_use]
+    pub fn new(checked_at: DateTime<Utc>, components: Vec<ComponentHealth>) -> Self {
+        let status = components
+            .iter()
+            .fold(HealthStatus::Healthy, |status, component| {
+                status.worst(component.status)
+            });
+        Self {
+            checked_at,
+            status,
+            components,
+        }
+    }
+
+    /// Returns when the checks started.
+    #[must_use]
+    pub const fn checked_at(&self) -> DateTime<Utc> {
+        self.checked_at
+    }
+
+    /// Returns the combined status.
+    #[must_use]
+    pub const fn status(&self) -> HealthStatus {
+        self.status
+    }
+
+    /// Returns `true` if every component is healthy.
+    #[must_use]
+    pub const fn is_ready(&self) -> bool {
+        matches!(self.status, HealthStatus::Healthy)
+    }
+
+    /// Returns every answer, in the order the checks were registered.
+    #[must_use]
+    pub fn components(&self) -> &[ComponentHealth] {
+        &self.components
+    }
+
+    /// Returns the answers of components that are not healthy.
+    pub fn problems(&self) -> impl Iterator<Item = &ComponentHealth> {
+        self.components
+            .iter()
+            .filter(|component| component.status != HealthStatus::Healthy)
+    }
+}
+
+/// [`HealthCheck`] that is ready only while every registered component is.
+///
+/// Checks run concurrently, and a component that does not answer within
+/// the check timeout counts as unhealthy.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::health::{HealthCheck, HealthStatus, ReadinessProbe};
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// # async fn example() {
+/// let probe = ReadinessProbe::new(Arc::new(DefaultClock));
+/// assert_eq!(probe.readiness().await, HealthStatus::Healthy);
+/// assert!(probe.report().await.components().is_empty());
+/// # }
+/// ```
+pub struct ReadinessProbe {
+    clock: Arc<dyn Clock + Send + Sync>,
+    check_timeout: Duration,
+    checks: Vec<Arc<dyn ComponentHealthCheck>>,
+}
+
+impl ReadinessProbe {
+    /// Creates a probe with no components, which is always ready.
+    #[must_use]
+    pub fn new(clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self {
+            clock,
+            check_timeout: DEFAULT_CHECK_TIMEOUT,
+            checks: Vec::new(),
+        }
+    }
+
+    /// Adds a component to check.
+    #[must_use]
+    pub fn with_check(mut self, check: Arc<dyn ComponentHealthCheck>) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    /// Replaces how long each component may take to answer.
+    #[must_use]
+    pub const fn with_check_timeout(mut self, timeout: Duration) -> Self {
+        self.check_timeout = timeout;
+        self
+    }
+
+    /// Checks every component and reports the answers.
+    pub async fn report(&self) -> HealthReport {
+        let checked_at = self.clock.utc();
+        let answers = join_all(self.checks.iter().map(|check| self.timed(check.as_ref()))).await;
+        HealthReport::new(checked_at, answers)
+    }
+
+    async fn timed(&self, check: &dyn ComponentHealthCheck) -> ComponentHealth {
+        let started = Instant::now();
+        let answer = tokio::time::timeout(self.check_timeout, check.check_health())
+            .await
+            .unwrap_or_else(|_| {
+                ComponentHealth::unhealthy(check.component(), "health check timed out")
+            });
+        answer.with_elapsed(started.elapsed())
+    }
+}
+
+#[async_trait]
+impl HealthCheck for ReadinessProbe {
+    async fn liveness(&self) -> HealthStatus {
+        HealthStatus::Healthy
+    }
+
+    async fn readiness(&self) -> HealthStatus {
+        self.report().await.status()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Tests for combining component answers into readiness.
+
+    use super::*;
+    use mockable::DefaultClock;
+    use rstest::rstest;
+
+    struct FixedCheck {
+        answer: ComponentHealth,
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl ComponentHealthCheck for FixedCheck {
+        fn component(&self) -> &str {
+            &self.answer.component
+        }
+
+        async fn check_health(&self) -> ComponentHealth {
+            tokio::time::sleep(self.delay).await;
+            self.answer.clone()
+        }
+    }
+
+    fn check(answer: ComponentHealth) -> Arc<dyn ComponentHealthCheck> {
+        Arc::new(FixedCheck {
+            answer,
+            delay: Duration::ZERO,
+        })
+    }
+
+    fn pool(idle_connections: u32) -> PoolStatistics {
+        PoolStatistics {
+            connections: 4,
+            idle_connections,
+            max_size: 4,
+        }
+    }
+
+    #[rstest]
+    #[case(1, HealthStatus::Healthy)]
+    #[case(0, HealthStatus::Degraded)]
+    fn an_exhausted_pool_degrades_a_healthy_component(
+        #[case] idle_connections: u32,
+        #[case] expected: HealthStatus,
+    ) {
+        let health = ComponentHealth::healthy("messages").with_pool(pool(idle_connections));
+        assert_eq!(health.status, expected);
+        assert_eq!(health.pool, Some(pool(idle_connections)));
+    }
+
+    #[rstest]
+    fn an_exhausted_pool_leaves_an_unhealthy_component_unhealthy() {
+        let health = ComponentHealth::unhealthy("messages", "refused").with_pool(pool(0));
+        assert_eq!(health.status, HealthStatus::Unhealthy);
+    }
+
+    #[tokio::test]
+    async fn probe_reports_the_worst_component() {
+        let probe = ReadinessProbe::new(Arc::new(DefaultClock))
+            .with_check(check(ComponentHealth::healthy("messages")))
+            .with_check(check(ComponentHealth::healthy("tasks").with_pool(pool(0))));
+
+        let report = probe.report().await;
+
+        assert_eq!(report.status(), HealthStatus::Degraded);
+        assert!(!report.is_ready());
+        let problems: Vec<_> = report
+            .problems()
+            .map(|component| component.component.as_str())
+            .collect();
+        assert_eq!(problems, vec!["tasks"]);
+        assert_eq!(probe.readiness().await, HealthStatus::Degraded);
+        assert_eq!(probe.liveness().await, HealthStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn a_slow_component_counts_as_unhealthy() {
+        let slow = Arc::new(FixedCheck {
+            answer: ComponentHealth::healthy("catalog"),
+            delay: Duration::from_secs(5),
+        });
+        let probe = ReadinessProbe::new(Arc::new(DefaultClock))
+            .with_check(slow)
+            .with_check_timeout(Duration::from_millis(10));
+
+        let report = probe.report().await;
+
+        let [component] = report.components() else {
+            panic!("expected one component, got {report:?}");
+        };
+        assert_eq!(component.component, "catalog");
+        assert_eq!(component.status, HealthStatus::Unhealthy);
+        assert_eq!(component.detail.as_deref(), Some("health check timed out"));
+    }
+}
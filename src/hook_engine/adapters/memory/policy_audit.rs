@@ -4,6 +4,7 @@ use crate::context::{RequestContext, TenantId};
 use crate::hook_engine::domain::{PolicyAuditEvent, TriggerContextId};
 use crate::hook_engine::ports::{HookPolicyAuditRepository, HookPolicyAuditResult};
 use crate::message::domain::ConversationId;
+use crate::pagination::{Cursor, Page, PageRequest};
 use crate::task::domain::TaskId;
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -46,7 +47,7 @@ impl InMemoryHookPolicyAuditRepository {
         events.sort_by(|left, right| {
             left.recorded_at()
                 .cmp(&right.recorded_at())
-                .then_with(|| left.action_id().cmp(right.action_id()))
+                .then_with(|| left.id().into_inner().cmp(&right.id().into_inner()))
         });
     }
 
@@ -73,12 +74,14 @@ impl InMemoryHookPolicyAuditRepository {
         &self,
         ctx: &RequestContext,
         key: QueryKey,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>> {
-        self.filter_tenant_events(ctx, {
-            let query_key = key.clone();
-            move |event| query_key.matches(event)
-        })
-        .await
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>> {
+        let events = self
+            .filter_tenant_events(ctx, move |event| key.matches(event))
+            .await?;
+        Ok(Page::from_ordered(events, page, |event| {
+            Cursor::at_timestamp(event.recorded_at(), event.id().into_inner())
+        }))
     }
 }
 
@@ -105,16 +108,18 @@ impl HookPolicyAuditRepository for InMemoryHookPolicyAuditRepository {
         &self,
         ctx: &RequestContext,
         task_id: TaskId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>> {
-        self.query_by_key(ctx, QueryKey::Task(task_id)).await
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>> {
+        self.query_by_key(ctx, QueryKey::Task(task_id), page).await
     }
 
     async fn find_by_conversation(
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>> {
-        self.query_by_key(ctx, QueryKey::Conversation(conversation_id))
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>> {
+        self.query_by_key(ctx, QueryKey::Conversation(conversation_id), page)
             .await
     }
 
@@ -122,8 +127,9 @@ impl HookPolicyAuditRepository for InMemoryHookPolicyAuditRepository {
         &self,
         ctx: &RequestContext,
         trigger_context_id: TriggerContextId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>> {
-        self.query_by_key(ctx, QueryKey::Trigger(trigger_context_id))
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>> {
+        self.query_by_key(ctx, QueryKey::Trigger(trigger_context_id), page)
             .await
     }
 }
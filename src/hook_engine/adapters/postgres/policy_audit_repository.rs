@@ -12,6 +12,8 @@ use crate::hook_engine::ports::{
 };
 use crate::message::adapters::postgres::tenant_tx::{FromTxError, TxError, with_tenant_tx};
 use crate::message::domain::ConversationId;
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
 use crate::task::domain::TaskId;
 use async_trait::async_trait;
 use diesel::pg::Pg;
@@ -28,6 +30,11 @@ pub struct PostgresHookPolicyAuditRepository {
     pool: HookPolicyAuditPgPool,
 }
 
+crate::postgres_support::pool_health_check!(
+    PostgresHookPolicyAuditRepository,
+    "hook_policy_audit_repository"
+);
+
 impl PostgresHookPolicyAuditRepository {
     /// Creates a new repository from a `PostgreSQL` connection pool.
     #[must_use]
@@ -58,8 +65,9 @@ impl PostgresHookPolicyAuditRepository {
     async fn query_events_filtered<F>(
         &self,
         tenant_id: TenantId,
+        page: PageRequest,
         extra_filter: F,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>>
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>>
     where
         F: FnOnce(
                 hook_policy_audit_events::BoxedQuery<'static, Pg>,
@@ -71,15 +79,24 @@ impl PostgresHookPolicyAuditRepository {
             let query = hook_policy_audit_events::table
                 .filter(hook_policy_audit_events::tenant_id.eq(tenant_id.into_inner()))
                 .into_boxed::<Pg>();
-            let rows = extra_filter(query)
-                .order_by((
-                    hook_policy_audit_events::recorded_at.asc(),
-                    hook_policy_audit_events::action_id.asc(),
-                ))
+            let filtered = extra_filter(query);
+            let paged = keyset_page!(
+                filtered,
+                page,
+                after_timestamp,
+                (
+                    hook_policy_audit_events::recorded_at,
+                    hook_policy_audit_events::id
+                )
+            );
+            let rows = paged
                 .select(PolicyAuditEventRow::as_select())
                 .load::<PolicyAuditEventRow>(connection)
                 .map_err(HookPolicyAuditError::persistence_failed)?;
-            rows.into_iter().map(row_to_event).collect()
+            Page::from_overfetched(rows, page, |row| {
+                Cursor::at_timestamp(row.recorded_at, row.id)
+            })
+            .try_map(row_to_event)
         })
         .await
     }
@@ -142,10 +159,11 @@ impl HookPolicyAuditRepository for PostgresHookPolicyAuditRepository {
         &self,
         ctx: &RequestContext,
         task_id: TaskId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>> {
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>> {
         let tenant_id = ctx.tenant_id();
         let task_uuid = task_id.into_inner();
-        self.query_events_filtered(tenant_id, move |query| {
+        self.query_events_filtered(tenant_id, page, move |query| {
             query.filter(hook_policy_audit_events::task_id.eq(task_uuid))
         })
         .await
@@ -155,10 +173,11 @@ impl HookPolicyAuditRepository for PostgresHookPolicyAuditRepository {
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>> {
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>> {
         let tenant_id = ctx.tenant_id();
         let conversation_uuid = conversation_id.into_inner();
-        self.query_events_filtered(tenant_id, move |query| {
+        self.query_events_filtered(tenant_id, page, move |query| {
             query.filter(hook_policy_audit_events::conversation_id.eq(conversation_uuid))
         })
         .await
@@ -168,10 +187,11 @@ impl HookPolicyAuditRepository for PostgresHookPolicyAuditRepository {
         &self,
         ctx: &RequestContext,
         trigger_context_id: TriggerContextId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>> {
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>> {
         let tenant_id = ctx.tenant_id();
         let trigger_context_uuid = trigger_context_id.into_inner();
-        self.query_events_filtered(tenant_id, move |query| {
+        self.query_events_filtered(tenant_id, page, move |query| {
             query.filter(hook_policy_audit_events::trigger_context_id.eq(trigger_context_uuid))
         })
         .await
@@ -29,6 +29,11 @@ pub struct PostgresHookExecutionLogRepository {
     pool: HookExecutionPgPool,
 }
 
+crate::postgres_support::pool_health_check!(
+    PostgresHookExecutionLogRepository,
+    "hook_execution_log_repository"
+);
+
 impl PostgresHookExecutionLogRepository {
     /// Creates a new repository from a `PostgreSQL` connection pool.
     ///
@@ -3,6 +3,7 @@
 use crate::context::RequestContext;
 use crate::hook_engine::domain::{PolicyAuditEvent, TriggerContextId};
 use crate::message::domain::ConversationId;
+use crate::pagination::{Page, PageRequest};
 use crate::task::domain::TaskId;
 use async_trait::async_trait;
 use thiserror::Error;
@@ -21,26 +22,32 @@ pub trait HookPolicyAuditRepository: Send + Sync {
         event: &PolicyAuditEvent,
     ) -> HookPolicyAuditResult<()>;
 
-    /// Returns policy audit events associated with a task.
+    /// Returns a page of policy audit events associated with a task,
+    /// oldest first.
     async fn find_by_task(
         &self,
         ctx: &RequestContext,
         task_id: TaskId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>>;
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>>;
 
-    /// Returns policy audit events associated with a conversation.
+    /// Returns a page of policy audit events associated with a conversation,
+    /// oldest first.
     async fn find_by_conversation(
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>>;
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>>;
 
-    /// Returns policy audit events associated with a trigger occurrence.
+    /// Returns a page of policy audit events associated with a trigger occurrence,
+    /// oldest first.
     async fn find_by_trigger_context(
         &self,
         ctx: &RequestContext,
         trigger_context_id: TriggerContextId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>>;
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>>;
 }
 
 /// Errors returned by hook policy audit implementations.
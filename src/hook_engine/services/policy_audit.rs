@@ -4,6 +4,7 @@ use crate::context::RequestContext;
 use crate::hook_engine::domain::{PolicyAuditEvent, TriggerContextId};
 use crate::hook_engine::ports::{HookPolicyAuditRepository, HookPolicyAuditResult};
 use crate::message::domain::ConversationId;
+use crate::pagination::{Page, PageRequest};
 use crate::task::domain::TaskId;
 use std::sync::Arc;
 
@@ -26,7 +27,7 @@ where
         Self { repository }
     }
 
-    /// Returns policy audit events associated with a task.
+    /// Returns a page of policy audit events associated with a task.
     ///
     /// # Errors
     ///
@@ -35,11 +36,12 @@ where
         &self,
         ctx: &RequestContext,
         task_id: TaskId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>> {
-        self.repository.find_by_task(ctx, task_id).await
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>> {
+        self.repository.find_by_task(ctx, task_id, page).await
     }
 
-    /// Returns policy audit events associated with a conversation.
+    /// Returns a page of policy audit events associated with a conversation.
     ///
     /// # Errors
     ///
@@ -48,13 +50,14 @@ where
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>> {
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>> {
         self.repository
-            .find_by_conversation(ctx, conversation_id)
+            .find_by_conversation(ctx, conversation_id, page)
             .await
     }
 
-    /// Returns policy audit events associated with a trigger occurrence.
+    /// Returns a page of policy audit events associated with a trigger occurrence.
     ///
     /// # Errors
     ///
@@ -63,9 +66,10 @@ where
         &self,
         ctx: &RequestContext,
         trigger_context_id: TriggerContextId,
-    ) -> HookPolicyAuditResult<Vec<PolicyAuditEvent>> {
+        page: PageRequest,
+    ) -> HookPolicyAuditResult<Page<PolicyAuditEvent>> {
         self.repository
-            .find_by_trigger_context(ctx, trigger_context_id)
+            .find_by_trigger_context(ctx, trigger_context_id, page)
             .await
     }
 }
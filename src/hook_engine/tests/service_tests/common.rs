@@ -12,6 +12,7 @@ use crate::hook_engine::domain::{
 use crate::hook_engine::ports::HookPolicyAuditRepository;
 use crate::hook_engine::services::{HookEngineService, HookEngineServiceDeps};
 use crate::message::domain::ConversationId;
+use crate::pagination::PageRequest;
 use crate::task::domain::TaskId;
 use eyre::{Result, WrapErr, eyre};
 use mockable::{Clock, DefaultClock};
@@ -214,7 +215,7 @@ pub(crate) async fn assert_policy_audit_indexed_once(
     keys: PolicyAuditQueryKeys,
 ) -> Result<()> {
     let by_task = policy_audit
-        .find_by_task(ctx, keys.task_id)
+        .find_by_task(ctx, keys.task_id, PageRequest::default())
         .await
         .wrap_err("query policy events by task")?;
     eyre::ensure!(
@@ -223,7 +224,7 @@ pub(crate) async fn assert_policy_audit_indexed_once(
         by_task.len()
     );
     let by_conversation = policy_audit
-        .find_by_conversation(ctx, keys.conversation_id)
+        .find_by_conversation(ctx, keys.conversation_id, PageRequest::default())
         .await
         .wrap_err("query policy events by conversation")?;
     eyre::ensure!(
@@ -232,7 +233,7 @@ pub(crate) async fn assert_policy_audit_indexed_once(
         by_conversation.len()
     );
     let by_trigger = policy_audit
-        .find_by_trigger_context(ctx, keys.trigger_context_id)
+        .find_by_trigger_context(ctx, keys.trigger_context_id, PageRequest::default())
         .await
         .wrap_err("query policy events by trigger")?;
     eyre::ensure!(
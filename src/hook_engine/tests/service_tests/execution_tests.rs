@@ -11,6 +11,7 @@ use crate::hook_engine::domain::{
 use crate::hook_engine::ports::{
     HookEngine, HookExecutionLogError, HookExecutionLogRepository, HookPolicyAuditRepository,
 };
+use crate::pagination::PageRequest;
 use eyre::Result;
 use mockable::DefaultClock;
 use serde_json::json;
@@ -97,7 +98,7 @@ async fn execute_persists_results_and_failure_status(
     assert_eq!(stored_result.status(), HookExecutionStatus::Failed);
 
     let audit_events = policy_audit
-        .find_by_trigger_context(&ctx, trigger_context_id)
+        .find_by_trigger_context(&ctx, trigger_context_id, PageRequest::default())
         .await
         .expect("querying policy audit events should succeed");
     assert_eq!(audit_events.len(), 1);
@@ -175,7 +176,7 @@ async fn execute_reuses_existing_execution_without_replaying_actions(
         .expect("querying stored retry execution should succeed");
     assert_eq!(stored.len(), 1);
     let audit_events = policy_audit
-        .find_by_trigger_context(&ctx, trigger_context_id)
+        .find_by_trigger_context(&ctx, trigger_context_id, PageRequest::default())
         .await
         .expect("querying retry audit events should succeed");
     assert_eq!(audit_events.len(), 1);
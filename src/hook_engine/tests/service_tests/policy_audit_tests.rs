@@ -14,6 +14,7 @@ use crate::hook_engine::ports::{
 };
 use crate::hook_engine::services::{HookEngineService, HookEngineServiceDeps};
 use crate::message::domain::ConversationId;
+use crate::pagination::{Page, PageRequest};
 use crate::task::domain::TaskId;
 use eyre::Result;
 use mockable::DefaultClock;
@@ -83,7 +84,7 @@ async fn policy_audit_projection_error_surfaces_without_persisting_events(
     assert!(matches!(error, HookEngineError::PolicyAuditProjection(_)));
 
     let audit_events = policy_audit
-        .find_by_trigger_context(&ctx, trigger_context_id)
+        .find_by_trigger_context(&ctx, trigger_context_id, PageRequest::default())
         .await
         .expect("querying policy audit events should succeed");
     assert!(
@@ -132,13 +133,13 @@ async fn policy_audit_persistence_error_surfaces_without_persisting_events(
         });
     failing_policy_audit
         .expect_find_by_task()
-        .returning(|_, _| Ok(vec![]));
+        .returning(|_, _, _| Ok(Page::default()));
     failing_policy_audit
         .expect_find_by_conversation()
-        .returning(|_, _| Ok(vec![]));
+        .returning(|_, _, _| Ok(Page::default()));
     failing_policy_audit
         .expect_find_by_trigger_context()
-        .returning(|_, _| Ok(vec![]));
+        .returning(|_, _, _| Ok(Page::default()));
     let repo = Arc::new(failing_policy_audit);
     let service = HookEngineService::new(HookEngineServiceDeps {
         definition_repository: Arc::new(definition_repo.clone()),
@@ -158,7 +159,7 @@ async fn policy_audit_persistence_error_surfaces_without_persisting_events(
     assert!(matches!(error, HookEngineError::PolicyAudit(_)));
 
     let audit_events = repo
-        .find_by_trigger_context(&ctx, trigger_context_id)
+        .find_by_trigger_context(&ctx, trigger_context_id, PageRequest::default())
         .await
         .expect("querying policy audit events should succeed");
     assert!(
@@ -1,7 +1,13 @@
 //! Authentication and request-context extraction for the HTTP API.
 
 use super::{error::ApiError, state::ApiState};
-use actix_web::{FromRequest, HttpMessage, HttpRequest, dev::Payload, web};
+use actix_web::{
+    FromRequest, HttpMessage, HttpRequest,
+    body::MessageBody,
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    middleware::Next,
+    web,
+};
 use chrono::Utc;
 use futures::future::{Ready, ready};
 use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode};
@@ -40,6 +46,13 @@ impl JwtClaims {
             tenant_kind: Some("user".to_owned()),
         }
     }
+
+    /// Sets the role claim.
+    #[must_use]
+    pub fn with_role(mut self, role: impl Into<String>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
 }
 
 /// HS256 bearer-token authenticator.
@@ -65,7 +78,7 @@ impl BearerTokenAuthenticator {
         &self,
         token: &str,
         correlation_id: CorrelationId,
-    ) -> Result<RequestContext, ApiError> {
+    ) -> Result<AuthenticatedRequestContext, ApiError> {
         let token_data = decode::<JwtClaims>(token, &self.decoding_key, &self.validation)
             .map_err(|_| ApiError::unauthorised("invalid_bearer_token", "invalid bearer token"))?;
         let claims = token_data.claims;
@@ -80,12 +93,13 @@ impl BearerTokenAuthenticator {
         let tenant_id = parse_uuid_claim(&claims.tenant_id, "tenant_id")?;
         let user_id = parse_uuid_claim(&claims.sub, "sub")?;
         let session_id = parse_uuid_claim(&claims.session_id, "session_id")?;
-        Ok(RequestContext::new(
+        let context = RequestContext::new(
             TenantId::from_uuid(tenant_id),
             correlation_id,
             UserId::from_uuid(user_id),
             SessionId::from_uuid(session_id),
-        ))
+        );
+        Ok(AuthenticatedRequestContext(context, claims.role))
     }
 }
 
@@ -142,9 +156,14 @@ pub fn request_correlation_id(request: &HttpRequest) -> CorrelationId {
     })
 }
 
+/// Role claim granting elevated access, such as unarchiving conversations.
+pub const ADMIN_ROLE: &str = "admin";
+
 /// Request extractor carrying the authenticated request context.
+///
+/// The second field holds the token's optional role claim.
 #[derive(Debug, Clone)]
-pub struct AuthenticatedRequestContext(pub RequestContext);
+pub struct AuthenticatedRequestContext(pub RequestContext, Option<String>);
 
 impl AuthenticatedRequestContext {
     /// Returns the shared request identifier.
@@ -158,6 +177,12 @@ impl AuthenticatedRequestContext {
     pub const fn context(&self) -> &RequestContext {
         &self.0
     }
+
+    /// Returns the caller's role claim, when the token carries one.
+    #[must_use]
+    pub fn role(&self) -> Option<&str> {
+        self.1.as_deref()
+    }
 }
 
 impl FromRequest for AuthenticatedRequestContext {
@@ -165,21 +190,44 @@ impl FromRequest for AuthenticatedRequestContext {
     type Future = Ready<Result<Self, Self::Error>>;
 
     fn from_request(request: &HttpRequest, _payload: &mut Payload) -> Self::Future {
-        let correlation_id = request_correlation_id(request);
-        let result = request
-            .app_data::<web::Data<ApiState>>()
-            .ok_or_else(|| {
-                tracing::error!("API state not configured");
-                ApiError::internal()
-            })
-            .and_then(|state| {
-                let token = extract_bearer_token(request)?;
-                state
-                    .authenticator
-                    .authenticate_token(token, correlation_id)
-            })
-            .map(Self);
-        ready(result)
+        ready(authenticate(request))
+    }
+}
+
+fn authenticate(request: &HttpRequest) -> Result<AuthenticatedRequestContext, ApiError> {
+    let correlation_id = request_correlation_id(request);
+    request
+        .app_data::<web::Data<ApiState>>()
+        .ok_or_else(|| {
+            tracing::error!("API state not configured");
+            ApiError::internal()
+        })
+        .and_then(|state| {
+            let token = extract_bearer_token(request)?;
+            state
+                .authenticator
+                .authenticate_token(token, correlation_id)
+        })
+}
+
+/// Runs the rest of an authenticated request inside its request context.
+///
+/// Requests carrying a valid bearer token are handled inside
+/// [`RequestContext::scope`], so repository writes they make are attributed
+/// in the audit trail without an audit context passed by hand. Other
+/// requests pass through untouched, and handlers that need authentication
+/// still reject them.
+///
+/// # Errors
+///
+/// Propagates errors from the wrapped service.
+pub async fn scope_request_context(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    match authenticate(request.request()) {
+        Ok(AuthenticatedRequestContext(ctx, _)) => ctx.scope(next.call(request)).await,
+        Err(_) => next.call(request).await,
     }
 }
 
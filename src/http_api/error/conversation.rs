@@ -1,7 +1,12 @@
 //! Conversation and message HTTP error mappings.
 
 use super::ApiError;
-use crate::message::error::RepositoryError;
+use crate::message::{
+    domain::{ConversationLifecycleError, TurnStateError},
+    error::RepositoryError,
+    ports::TurnError,
+};
+use actix_web::http::StatusCode;
 
 pub(crate) fn map_conversation_repository_error(
     error: crate::message::ports::ConversationRepositoryError,
@@ -10,6 +15,12 @@ pub(crate) fn map_conversation_repository_error(
         crate::message::ports::ConversationRepositoryError::DuplicateConversation(id) => {
             ApiError::conflict("duplicate_conversation", id.to_string())
         }
+        crate::message::ports::ConversationRepositoryError::NotFound(id) => {
+            ApiError::not_found("conversation_not_found", id.to_string())
+        }
+        error @ crate::message::ports::ConversationRepositoryError::ConcurrentModification {
+            ..
+        } => ApiError::conflict("concurrent_modification", error.to_string()),
         crate::message::ports::ConversationRepositoryError::Persistence(err) => {
             tracing::error!(error = %err, "conversation repository persistence error");
             ApiError::internal()
@@ -26,12 +37,18 @@ pub(crate) fn map_message_repository_error(error: RepositoryError) -> ApiError {
         RepositoryError::ConversationNotFound(conversation_id) => {
             ApiError::not_found("conversation_not_found", conversation_id.to_string())
         }
+        RepositoryError::ConversationArchived(conversation_id) => {
+            conversation_archived(conversation_id)
+        }
         RepositoryError::NotFound(message_id) => {
             ApiError::not_found("message_not_found", message_id.to_string())
         }
         RepositoryError::DuplicateMessage(message_id) => {
             ApiError::conflict("duplicate_message", message_id.to_string())
         }
+        RepositoryError::AlreadyRedacted(message_id) => {
+            ApiError::conflict("message_already_redacted", message_id.to_string())
+        }
         RepositoryError::DuplicateSequence {
             conversation_id,
             sequence,
@@ -39,6 +56,9 @@ pub(crate) fn map_message_repository_error(error: RepositoryError) -> ApiError {
             "duplicate_sequence",
             format!("conversation {conversation_id} already has sequence {sequence}"),
         ),
+        conflicts @ RepositoryError::BatchConflicts(_) => {
+            ApiError::conflict("duplicate_message_batch", conflicts.to_string())
+        }
         RepositoryError::Database(err) => {
             tracing::error!(error = %err, "message database error");
             ApiError::internal()
@@ -51,5 +71,62 @@ pub(crate) fn map_message_repository_error(error: RepositoryError) -> ApiError {
             tracing::error!(error = %message, "message serialization error");
             ApiError::internal()
         }
+        RepositoryError::Cipher(err) => {
+            tracing::error!(error = %err, "message content encryption error");
+            ApiError::internal()
+        }
+        RepositoryError::InvalidSequenceNumber(err) => {
+            tracing::error!(error = %err, "stored message has an invalid sequence number");
+            ApiError::internal()
+        }
+        RepositoryError::MaintenanceMode(err) => err.into(),
+    }
+}
+
+pub(crate) fn conversation_archived(
+    conversation_id: crate::message::domain::ConversationId,
+) -> ApiError {
+    ApiError::conflict(
+        "conversation_archived",
+        format!("conversation {conversation_id} is archived"),
+    )
+}
+
+pub(crate) fn map_conversation_lifecycle_error(error: ConversationLifecycleError) -> ApiError {
+    match error {
+        ConversationLifecycleError::AlreadyArchived(_) => {
+            ApiError::conflict("conversation_already_archived", error.to_string())
+        }
+        ConversationLifecycleError::NotArchived(_) => {
+            ApiError::conflict("conversation_not_archived", error.to_string())
+        }
+        ConversationLifecycleError::ElevatedAccessRequired(_) => ApiError::new(
+            StatusCode::FORBIDDEN,
+            "elevated_access_required",
+            error.to_string(),
+        ),
+        ConversationLifecycleError::InvalidTransition { .. } => {
+            ApiError::conflict("invalid_conversation_transition", error.to_string())
+        }
+    }
+}
+
+pub(crate) fn map_turn_state_error(error: &TurnStateError) -> ApiError {
+    let reason = match error {
+        TurnStateError::Ended { .. } => "turn_ended",
+        TurnStateError::ConversationMismatch { .. } => "turn_conversation_mismatch",
+    };
+    ApiError::conflict(reason, error.to_string())
+}
+
+pub(crate) fn map_turn_repository_error(error: TurnError) -> ApiError {
+    match error {
+        TurnError::NotFound(turn_id) => ApiError::not_found("turn_not_found", turn_id.to_string()),
+        TurnError::Duplicate(turn_id) => ApiError::conflict("duplicate_turn", turn_id.to_string()),
+        TurnError::State(err) => map_turn_state_error(&err),
+        TurnError::InvalidPersistedData(_) | TurnError::Persistence(_) => {
+            tracing::error!(error = %error, "turn repository error");
+            ApiError::internal()
+        }
     }
 }
@@ -0,0 +1,38 @@
+//! Message feedback HTTP error mappings.
+
+use super::{ApiError, map_message_repository_error};
+use crate::message::{
+    domain::FeedbackDomainError, ports::FeedbackError, services::FeedbackServiceError,
+};
+
+impl From<FeedbackServiceError> for ApiError {
+    fn from(error: FeedbackServiceError) -> Self {
+        match error {
+            FeedbackServiceError::MessageNotFound { message_id, .. } => Self::not_found(
+                "message_not_found",
+                format!("message {message_id} was not found"),
+            ),
+            FeedbackServiceError::Domain(domain_error) => map_feedback_domain_error(&domain_error),
+            FeedbackServiceError::MessageRepository(repository_error) => {
+                map_message_repository_error(repository_error)
+            }
+            FeedbackServiceError::Feedback(repository_error) => {
+                map_feedback_repository_error(&repository_error)
+            }
+        }
+    }
+}
+
+fn map_feedback_repository_error(error: &FeedbackError) -> ApiError {
+    tracing::error!(error = %error, "message feedback repository error");
+    ApiError::internal()
+}
+
+fn map_feedback_domain_error(error: &FeedbackDomainError) -> ApiError {
+    let reason = match error {
+        FeedbackDomainError::NotAssistantMessage(_) => "not_assistant_message",
+        FeedbackDomainError::InvalidStarRating(_) => "invalid_rating",
+        FeedbackDomainError::CommentTooLong { .. } => "comment_too_long",
+    };
+    ApiError::bad_request(reason, error.to_string())
+}
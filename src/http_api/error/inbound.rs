@@ -0,0 +1,43 @@
+//! Inbound gateway HTTP error mappings.
+
+use super::ApiError;
+use crate::message::{
+    adapters::inbound::{InboundPayloadError, SignatureError},
+    services::InboundServiceError,
+};
+use actix_web::http::StatusCode;
+
+impl From<InboundServiceError> for ApiError {
+    fn from(error: InboundServiceError) -> Self {
+        match error {
+            InboundServiceError::UnmappedUser { .. } => Self::new(
+                StatusCode::FORBIDDEN,
+                "unmapped_external_user",
+                error.to_string(),
+            ),
+            InboundServiceError::Identity(identity_error) => {
+                tracing::error!(error = %identity_error, "inbound identity mapping error");
+                Self::internal()
+            }
+            InboundServiceError::Conversation(conversation_error) => conversation_error.into(),
+            InboundServiceError::Task(task_error) => task_error.into(),
+            InboundServiceError::Mailbox(mailbox_error) => {
+                tracing::error!(error = %mailbox_error, "inbound mailbox error");
+                Self::internal()
+            }
+            InboundServiceError::MaintenanceMode(maintenance) => maintenance.into(),
+        }
+    }
+}
+
+impl From<SignatureError> for ApiError {
+    fn from(error: SignatureError) -> Self {
+        Self::unauthorised("invalid_signature", error.to_string())
+    }
+}
+
+impl From<InboundPayloadError> for ApiError {
+    fn from(error: InboundPayloadError) -> Self {
+        Self::bad_request("invalid_inbound_payload", error.to_string())
+    }
+}
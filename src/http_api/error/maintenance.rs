@@ -0,0 +1,27 @@
+//! Maintenance mode HTTP error mappings.
+
+use super::ApiError;
+use crate::maintenance::{domain::MaintenanceMode, ports::MaintenanceStateError};
+use actix_web::http::StatusCode;
+use serde_json::json;
+
+impl From<MaintenanceMode> for ApiError {
+    fn from(error: MaintenanceMode) -> Self {
+        Self::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "maintenance_mode",
+            error.to_string(),
+        )
+        .with_details(json!({
+            "maintenance_reason": error.reason,
+            "since": error.since,
+        }))
+    }
+}
+
+impl From<MaintenanceStateError> for ApiError {
+    fn from(error: MaintenanceStateError) -> Self {
+        tracing::error!(error = %error, "maintenance state repository error");
+        Self::internal()
+    }
+}
@@ -5,8 +5,14 @@
 //! route-level correlation IDs.
 
 mod conversation;
+mod feedback;
+mod inbound;
+mod maintenance;
+mod operator;
+mod processing;
 mod task;
 mod tool;
+mod transfer;
 
 use actix_v2a::{Error as SharedApiError, ErrorCode, TRACE_ID_HEADER};
 use actix_web::{
@@ -18,8 +24,11 @@ use std::fmt;
 use uuid::Uuid;
 
 pub(crate) use self::{
-    conversation::{map_conversation_repository_error, map_message_repository_error},
-    task::{map_task_domain_error, map_task_repository_error},
+    conversation::{
+        conversation_archived, map_conversation_lifecycle_error, map_conversation_repository_error,
+        map_message_repository_error, map_turn_repository_error, map_turn_state_error,
+    },
+    task::{map_task_domain_error, map_task_queue_error, map_task_repository_error},
     tool::map_tool_service_error,
 };
 
@@ -156,6 +165,12 @@ impl From<ConversationServiceError> for ApiError {
                 "conversation_not_found",
                 format!("conversation {conversation_id} was not found"),
             ),
+            ConversationServiceError::ConversationArchived(conversation_id) => {
+                conversation_archived(conversation_id)
+            }
+            ConversationServiceError::Lifecycle(lifecycle_error) => {
+                map_conversation_lifecycle_error(lifecycle_error)
+            }
             ConversationServiceError::ConversationRepository(repository_error) => {
                 map_conversation_repository_error(repository_error)
             }
@@ -163,10 +178,12 @@ impl From<ConversationServiceError> for ApiError {
                 map_message_repository_error(repository_error)
             }
             ConversationServiceError::Validation(validation_error) => validation_error.into(),
-            ConversationServiceError::RetryExhausted => {
-                tracing::error!("conversation service retry exhausted");
-                Self::internal()
+            ConversationServiceError::OperatorActions(audit_error) => audit_error.into(),
+            ConversationServiceError::TurnNotFound(turn_id) => {
+                Self::not_found("turn_not_found", format!("turn {turn_id} was not found"))
             }
+            ConversationServiceError::TurnState(state_error) => map_turn_state_error(&state_error),
+            ConversationServiceError::Turns(turn_error) => map_turn_repository_error(turn_error),
         }
     }
 }
@@ -181,6 +198,15 @@ impl From<TaskLifecycleError> for ApiError {
             TaskLifecycleError::Repository(repository_error) => {
                 map_task_repository_error(repository_error)
             }
+            TaskLifecycleError::OperatorActions(audit_error) => audit_error.into(),
+            TaskLifecycleError::InvalidPriority(parse_error) => {
+                Self::bad_request("invalid_task_priority", parse_error.to_string())
+            }
+            TaskLifecycleError::Queue(queue_error) => map_task_queue_error(&queue_error),
+            TaskLifecycleError::QueueNotConfigured => {
+                tracing::error!("task queue is not configured");
+                Self::internal()
+            }
         }
     }
 }
@@ -0,0 +1,11 @@
+//! Operator action audit HTTP error mappings.
+
+use super::ApiError;
+use crate::operator::ports::OperatorActionError;
+
+impl From<OperatorActionError> for ApiError {
+    fn from(error: OperatorActionError) -> Self {
+        tracing::error!(error = %error, "operator action repository error");
+        Self::internal()
+    }
+}
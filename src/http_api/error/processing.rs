@@ -0,0 +1,36 @@
+//! Message processing status HTTP error mappings.
+
+use super::{ApiError, map_message_repository_error};
+use crate::message::{domain::ProcessingDomainError, services::ProcessingServiceError};
+
+impl From<ProcessingServiceError> for ApiError {
+    fn from(error: ProcessingServiceError) -> Self {
+        match error {
+            ProcessingServiceError::MessageNotFound { message_id, .. } => Self::not_found(
+                "message_not_found",
+                format!("message {message_id} was not found"),
+            ),
+            ProcessingServiceError::StageNotStarted { .. } => {
+                Self::conflict("stage_not_started", error.to_string())
+            }
+            ProcessingServiceError::Domain(domain_error) => {
+                map_processing_domain_error(domain_error)
+            }
+            ProcessingServiceError::MessageRepository(repository_error) => {
+                map_message_repository_error(repository_error)
+            }
+            ProcessingServiceError::Processing(repository_error) => {
+                tracing::error!(error = %repository_error, "message processing repository error");
+                Self::internal()
+            }
+        }
+    }
+}
+
+fn map_processing_domain_error(error: ProcessingDomainError) -> ApiError {
+    let reason = match error {
+        ProcessingDomainError::StageInProgress(_) => "stage_in_progress",
+        ProcessingDomainError::StageNotInProgress(_) => "stage_not_in_progress",
+    };
+    ApiError::conflict(reason, error.to_string())
+}
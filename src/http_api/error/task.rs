@@ -1,7 +1,10 @@
 //! Task HTTP error mappings.
 
 use super::ApiError;
-use crate::task::{domain::TaskDomainError, ports::TaskRepositoryError};
+use crate::task::{
+    domain::TaskDomainError,
+    ports::{TaskQueueError, TaskRepositoryError},
+};
 use serde_json::json;
 
 pub(crate) fn map_task_domain_error(error: &TaskDomainError) -> ApiError {
@@ -14,9 +17,18 @@ pub(crate) fn map_task_domain_error(error: &TaskDomainError) -> ApiError {
         | TaskDomainError::InvalidPullRequestNumber(_)
         | TaskDomainError::InvalidBranchRefFormat(_)
         | TaskDomainError::InvalidPullRequestRefFormat(_)
-        | TaskDomainError::CanonicalRefTooLong(_) => {
+        | TaskDomainError::CanonicalRefTooLong(_)
+        | TaskDomainError::InvalidWebhookDeliveryId(_)
+        | TaskDomainError::InvalidJiraIssueKey(_)
+        | TaskDomainError::InvalidJiraProjectKey(_)
+        | TaskDomainError::InvalidBudgetShare(_) => {
             ApiError::bad_request("task_validation_failed", error.to_string())
         }
+        TaskDomainError::InsufficientBudget(_)
+        | TaskDomainError::OpenBudgetDelegations(_)
+        | TaskDomainError::BudgetNotDelegatedFrom { .. } => {
+            ApiError::conflict("budget_conflict", error.to_string())
+        }
         TaskDomainError::BranchAlreadyAssociated(task_id) => {
             ApiError::conflict("branch_already_associated", error.to_string())
                 .with_details(json!({ "taskId": task_id }))
@@ -56,3 +68,12 @@ pub(crate) fn map_task_repository_error(error: TaskRepositoryError) -> ApiError
         }
     }
 }
+
+pub(crate) fn map_task_queue_error(error: &TaskQueueError) -> ApiError {
+    match error {
+        TaskQueueError::Persistence(err) => {
+            tracing::error!(error = %err, "task queue persistence error");
+            ApiError::internal()
+        }
+    }
+}
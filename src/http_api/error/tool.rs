@@ -79,6 +79,9 @@ pub(crate) fn map_tool_domain_error(error: ToolRegistryDomainError) -> ApiError
         | ToolRegistryDomainError::ServerNameTooLong(_)
         | ToolRegistryDomainError::EmptyStdioCommand
         | ToolRegistryDomainError::EmptyWorkingDirectory
+        | ToolRegistryDomainError::EmptySecretReference
+        | ToolRegistryDomainError::EmptySecretVariable
+        | ToolRegistryDomainError::SecretVariableConflict(_)
         | ToolRegistryDomainError::EmptyHttpSseBaseUrl
         | ToolRegistryDomainError::InvalidHttpSseBaseUrl(_)
         | ToolRegistryDomainError::EmptyToolName
@@ -0,0 +1,57 @@
+//! Conversation transfer HTTP error mappings.
+
+use super::ApiError;
+use crate::message::{
+    domain::ConversationTransferRefused, ports::ConversationTransferError,
+    services::ConversationTransferServiceError,
+};
+use actix_web::http::StatusCode;
+use serde_json::json;
+
+impl From<ConversationTransferServiceError> for ApiError {
+    fn from(error: ConversationTransferServiceError) -> Self {
+        match error {
+            ConversationTransferServiceError::Refused(refused) => refused.into(),
+            ConversationTransferServiceError::Repository(repository) => repository.into(),
+        }
+    }
+}
+
+impl From<ConversationTransferRefused> for ApiError {
+    fn from(error: ConversationTransferRefused) -> Self {
+        match error {
+            ConversationTransferRefused::ElevatedAccessRequired(_) => Self::new(
+                StatusCode::FORBIDDEN,
+                "elevated_access_required",
+                error.to_string(),
+            ),
+            ConversationTransferRefused::SameTenant { .. } => {
+                Self::bad_request("same_tenant_transfer", error.to_string())
+            }
+        }
+    }
+}
+
+impl From<ConversationTransferError> for ApiError {
+    fn from(error: ConversationTransferError) -> Self {
+        match error {
+            ConversationTransferError::NotFound(conversation_id) => {
+                Self::not_found("conversation_not_found", conversation_id.to_string())
+            }
+            ConversationTransferError::LinkedToTask { task_id, .. } => {
+                Self::conflict("conversation_linked_to_task", error.to_string())
+                    .with_details(json!({ "task_id": task_id }))
+            }
+            ConversationTransferError::Budgeted(_) => {
+                Self::conflict("conversation_budgeted", error.to_string())
+            }
+            ConversationTransferError::TargetTenantSuspended(_) => {
+                Self::conflict("target_tenant_suspended", error.to_string())
+            }
+            ConversationTransferError::Persistence(err) => {
+                tracing::error!(error = %err, "conversation transfer persistence error");
+                Self::internal()
+            }
+        }
+    }
+}
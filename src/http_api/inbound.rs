@@ -0,0 +1,136 @@
+//! Configuration for the inbound message gateway routes.
+//!
+//! External chat systems cannot present Corbusier bearer tokens, so the
+//! gateway routes authenticate requests by their webhook signature instead.
+//! [`InboundGateway`] pairs the [`InboundApplication`] that stores messages
+//! with a signature verifier for Slack, one for inbound email, and one for
+//! each named custom webhook. A source without a verifier is not accepted.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::context::CorrelationId;
+use crate::message::{
+    adapters::inbound::WebhookSignatureVerifier,
+    domain::{InboundEmail, InboundMessage},
+    ports::{ConversationRepository, InboundIdentityMapping, MessageRepository, MessageValidator},
+    services::{EmailReceipt, InboundMessageService, InboundReceipt, InboundServiceError},
+};
+use mockable::Clock;
+
+/// Inbound message ingestion exposed to the HTTP adapter.
+#[async_trait]
+pub trait InboundApplication: Send + Sync {
+    /// Stores a message received from an external chat system.
+    async fn ingest(
+        &self,
+        correlation_id: CorrelationId,
+        message: InboundMessage,
+    ) -> Result<InboundReceipt, InboundServiceError>;
+
+    /// Stores an email in the conversation fed by its thread.
+    async fn ingest_email(
+        &self,
+        correlation_id: CorrelationId,
+        email: InboundEmail,
+    ) -> Result<EmailReceipt, InboundServiceError>;
+}
+
+#[async_trait]
+impl<Mapping, ConvoRepo, MessageRepo, Validator, C> InboundApplication
+    for InboundMessageService<Mapping, ConvoRepo, MessageRepo, Validator, C>
+where
+    Mapping: InboundIdentityMapping + 'static,
+    ConvoRepo: ConversationRepository + 'static,
+    MessageRepo: MessageRepository + 'static,
+    Validator: MessageValidator + 'static,
+    C: Clock + Send + Sync + 'static,
+{
+    async fn ingest(
+        &self,
+        correlation_id: CorrelationId,
+        message: InboundMessage,
+    ) -> Result<InboundReceipt, InboundServiceError> {
+        Self::ingest(self, correlation_id, message).await
+    }
+
+    async fn ingest_email(
+        &self,
+        correlation_id: CorrelationId,
+        email: InboundEmail,
+    ) -> Result<EmailReceipt, InboundServiceError> {
+        Self::ingest_email(self, correlation_id, email).await
+    }
+}
+
+/// Inbound gateway service and the verifiers for each accepted source.
+#[derive(Clone)]
+pub struct InboundGateway {
+    application: Arc<dyn InboundApplication>,
+    slack: Option<WebhookSignatureVerifier>,
+    email: Option<WebhookSignatureVerifier>,
+    webhooks: HashMap<String, WebhookSignatureVerifier>,
+}
+
+impl InboundGateway {
+    /// Creates a gateway that accepts no sources yet.
+    #[must_use]
+    pub fn new(application: Arc<dyn InboundApplication>) -> Self {
+        Self {
+            application,
+            slack: None,
+            email: None,
+            webhooks: HashMap::new(),
+        }
+    }
+
+    /// Accepts Slack events signed for `verifier`.
+    #[must_use]
+    pub const fn with_slack(mut self, verifier: WebhookSignatureVerifier) -> Self {
+        self.slack = Some(verifier);
+        self
+    }
+
+    /// Accepts inbound email deliveries signed for `verifier`.
+    #[must_use]
+    pub const fn with_email(mut self, verifier: WebhookSignatureVerifier) -> Self {
+        self.email = Some(verifier);
+        self
+    }
+
+    /// Accepts custom webhook deliveries for `name` signed for `verifier`.
+    #[must_use]
+    pub fn with_webhook(
+        mut self,
+        name: impl Into<String>,
+        verifier: WebhookSignatureVerifier,
+    ) -> Self {
+        self.webhooks.insert(name.into(), verifier);
+        self
+    }
+
+    /// Returns the ingestion service.
+    #[must_use]
+    pub fn application(&self) -> &dyn InboundApplication {
+        self.application.as_ref()
+    }
+
+    /// Returns the Slack verifier, when Slack is accepted.
+    #[must_use]
+    pub const fn slack(&self) -> Option<&WebhookSignatureVerifier> {
+        self.slack.as_ref()
+    }
+
+    /// Returns the email verifier, when email is accepted.
+    #[must_use]
+    pub const fn email(&self) -> Option<&WebhookSignatureVerifier> {
+        self.email.as_ref()
+    }
+
+    /// Returns the verifier for a named custom webhook.
+    #[must_use]
+    pub fn webhook(&self, name: &str) -> Option<&WebhookSignatureVerifier> {
+        self.webhooks.get(name)
+    }
+}
@@ -2,10 +2,14 @@
 
 pub mod auth;
 pub mod error;
+pub mod inbound;
+pub mod processing;
 pub mod response;
 pub mod routes;
 pub mod state;
 
-pub use auth::{AuthenticatedRequestContext, BearerTokenAuthenticator, JwtClaims};
+pub use auth::{ADMIN_ROLE, AuthenticatedRequestContext, BearerTokenAuthenticator, JwtClaims};
+pub use inbound::InboundGateway;
+pub use processing::ProcessingApplication;
 pub use routes::api_routes;
 pub use state::{ApiConfig, ApiState};
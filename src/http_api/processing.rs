@@ -0,0 +1,87 @@
+//! Message processing status operations exposed to the HTTP adapter.
+//!
+//! [`ProcessingApplication`] lets the processing routes report a message's
+//! pipeline progress, list messages stuck at a stage, send a message back
+//! through a stage, and report load-shedding metrics without depending on the
+//! concrete service.
+
+use async_trait::async_trait;
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        ConversationId, LoadSheddingMetrics, MessageId, MessageProcessingStatus, StageStatus,
+        StuckMessagesQuery,
+    },
+    ports::{MessageProcessingRepository, MessageRepository},
+    services::{MessageProcessingService, ProcessingServiceError, ReprocessRequest},
+};
+use crate::pagination::Page;
+use mockable::Clock;
+
+/// Message processing status operations exposed to the HTTP adapter.
+#[async_trait]
+pub trait ProcessingApplication: Send + Sync {
+    /// Returns a message's progress across the pipeline.
+    async fn processing_status(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        message_id: MessageId,
+    ) -> Result<MessageProcessingStatus, ProcessingServiceError>;
+
+    /// Lists statuses of messages stuck at a stage.
+    async fn stuck_messages(
+        &self,
+        ctx: &RequestContext,
+        query: StuckMessagesQuery,
+    ) -> Result<Page<StageStatus>, ProcessingServiceError>;
+
+    /// Sends a message back through a stage.
+    async fn reprocess(
+        &self,
+        ctx: &RequestContext,
+        request: ReprocessRequest,
+    ) -> Result<MessageProcessingStatus, ProcessingServiceError>;
+
+    /// Returns the load-shedding metrics, when load shedding is configured.
+    fn load_shedding(&self) -> Option<LoadSheddingMetrics>;
+}
+
+#[async_trait]
+impl<MessageRepo, ProcessingRepo, C> ProcessingApplication
+    for MessageProcessingService<MessageRepo, ProcessingRepo, C>
+where
+    MessageRepo: MessageRepository + 'static,
+    ProcessingRepo: MessageProcessingRepository + 'static,
+    C: Clock + Send + Sync + 'static,
+{
+    async fn processing_status(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        message_id: MessageId,
+    ) -> Result<MessageProcessingStatus, ProcessingServiceError> {
+        self.status(ctx, conversation_id, message_id).await
+    }
+
+    async fn stuck_messages(
+        &self,
+        ctx: &RequestContext,
+        query: StuckMessagesQuery,
+    ) -> Result<Page<StageStatus>, ProcessingServiceError> {
+        Self::stuck_messages(self, ctx, query).await
+    }
+
+    async fn reprocess(
+        &self,
+        ctx: &RequestContext,
+        request: ReprocessRequest,
+    ) -> Result<MessageProcessingStatus, ProcessingServiceError> {
+        Self::reprocess(self, ctx, request).await
+    }
+
+    fn load_shedding(&self) -> Option<LoadSheddingMetrics> {
+        self.load_shedding_metrics()
+    }
+}
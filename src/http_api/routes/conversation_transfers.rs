@@ -0,0 +1,128 @@
+//! Registers the conversation transfer endpoint.
+//!
+//! `POST /api/v1/admin/conversations/{conversation_id}/transfer` with a body
+//! of `{"target_tenant_id": "...", "reason": "..."}` moves a conversation
+//! out of the caller's tenant. Transfers require a bearer token carrying the
+//! [`ADMIN_ROLE`] role claim, and the endpoint answers
+//! `503 Service Unavailable` when the API state has no transfer service
+//! attached.
+
+use super::super::{
+    auth::{ADMIN_ROLE, AuthenticatedRequestContext},
+    error::ApiError,
+    response::json_success,
+    state::ApiState,
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::context::TenantId;
+use crate::message::domain::{
+    ConversationAccess, ConversationId, ConversationTransfer, ConversationTransferRequest,
+};
+use crate::message::services::ConversationTransferService;
+use crate::operator::domain::{OperatorActionId, OperatorReason};
+
+#[derive(Debug, Deserialize)]
+struct TransferBody {
+    target_tenant_id: Uuid,
+    reason: String,
+}
+
+#[derive(Debug, Serialize)]
+struct TransferDto {
+    conversation_id: ConversationId,
+    source_tenant_id: TenantId,
+    target_tenant_id: TenantId,
+    outgoing_action_id: OperatorActionId,
+    incoming_action_id: OperatorActionId,
+    occurred_at: DateTime<Utc>,
+}
+
+impl From<ConversationTransfer> for TransferDto {
+    fn from(transfer: ConversationTransfer) -> Self {
+        Self {
+            conversation_id: transfer.conversation_id,
+            source_tenant_id: transfer.source_tenant_id,
+            target_tenant_id: transfer.target_tenant_id,
+            outgoing_action_id: transfer.outgoing.id,
+            incoming_action_id: transfer.incoming.id,
+            occurred_at: transfer.outgoing.occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TransferResponse {
+    transfer: TransferDto,
+}
+
+/// Registers the conversation transfer routes under `/api/v1`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/admin/conversations/{conversation_id}/transfer")
+            .route(web::post().to(transfer_conversation)),
+    );
+}
+
+async fn transfer_conversation(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    path: web::Path<String>,
+    body: web::Json<TransferBody>,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let result = async {
+        let transfers = service(&state)?;
+        if auth.role() != Some(ADMIN_ROLE) {
+            return Err(ApiError::new(
+                StatusCode::FORBIDDEN,
+                "admin_role_required",
+                "transferring conversations requires the admin role",
+            ));
+        }
+        let conversation_id = Uuid::parse_str(&path.into_inner())
+            .map(ConversationId::from_uuid)
+            .map_err(|_| {
+                ApiError::bad_request("invalid_conversation_id", "invalid conversation id")
+            })?;
+        let TransferBody {
+            target_tenant_id,
+            reason,
+        } = body.into_inner();
+        let parsed = OperatorReason::new(reason)
+            .map_err(|err| ApiError::bad_request("invalid_transfer_reason", err.to_string()))?;
+        let request = ConversationTransferRequest {
+            conversation_id,
+            target_tenant_id: TenantId::from_uuid(target_tenant_id),
+            reason: parsed,
+        };
+        Ok(transfers
+            .transfer(auth.context(), request, ConversationAccess::Elevated)
+            .await?)
+    }
+    .await;
+    match result {
+        Ok(transfer) => json_success(
+            &*state.clock,
+            StatusCode::OK,
+            TransferResponse {
+                transfer: transfer.into(),
+            },
+            request_id,
+        ),
+        Err(err) => err.into_response(&*state.clock, request_id),
+    }
+}
+
+fn service(state: &ApiState) -> Result<&ConversationTransferService, ApiError> {
+    state.conversation_transfers.as_deref().ok_or_else(|| {
+        ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "conversation_transfers_unavailable",
+            "conversation transfers are not configured",
+        )
+    })
+}
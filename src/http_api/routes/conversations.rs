@@ -3,91 +3,33 @@
 //! together manage conversation resources and return conversation data.
 //! [`routes`] is the public entrypoint used to mount these handlers on the
 //! API router.
+//!
+//! `POST /conversations/{id}/archive` closes a conversation to writes and
+//! `POST /conversations/{id}/unarchive` reopens it. Unarchiving requires a
+//! bearer token carrying the [`ADMIN_ROLE`] role claim.
 
 use super::super::{
-    auth::AuthenticatedRequestContext, error::ApiError, response::json_success, state::ApiState,
+    auth::{ADMIN_ROLE, AuthenticatedRequestContext},
+    error::ApiError,
+    response::json_success,
+    state::ApiState,
 };
-use actix_web::{HttpResponse, http::StatusCode, web};
-use serde::{Deserialize, Serialize};
+use super::parse_page_query;
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, web};
+use serde::Deserialize;
 use uuid::Uuid;
 
-use crate::message::domain::{
-    ContentPart, Conversation, ConversationId, ConversationState, Message, MessageId,
-    MessageMetadata, Role, SequenceNumber,
+use crate::dto::v1::{
+    AppendMessageRequestDto, ConversationHistoryResponseDto, ConversationResponseDto,
+    MessageResponseDto,
 };
-use crate::message::services::AppendMessageRequest;
+use crate::message::domain::{Conversation, ConversationAccess, ConversationId};
 
 #[derive(Debug, Deserialize)]
 struct ConversationPath {
     conversation_id: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct AppendMessageBody {
-    role: Role,
-    content: Vec<ContentPart>,
-}
-
-#[derive(Debug, Serialize)]
-struct ConversationDto {
-    id: ConversationId,
-    state: ConversationState,
-    created_at: chrono::DateTime<chrono::Utc>,
-    updated_at: chrono::DateTime<chrono::Utc>,
-}
-
-impl From<Conversation> for ConversationDto {
-    fn from(conversation: Conversation) -> Self {
-        Self {
-            id: conversation.id(),
-            state: conversation.state(),
-            created_at: conversation.created_at(),
-            updated_at: conversation.updated_at(),
-        }
-    }
-}
-
-#[derive(Debug, Serialize)]
-struct MessageDto {
-    id: MessageId,
-    conversation_id: ConversationId,
-    role: Role,
-    content: Vec<ContentPart>,
-    metadata: MessageMetadata,
-    created_at: chrono::DateTime<chrono::Utc>,
-    sequence_number: SequenceNumber,
-}
-
-impl From<Message> for MessageDto {
-    fn from(message: Message) -> Self {
-        Self {
-            id: message.id(),
-            conversation_id: message.conversation_id(),
-            role: message.role(),
-            content: message.content().to_vec(),
-            metadata: message.metadata().clone(),
-            created_at: message.created_at(),
-            sequence_number: message.sequence_number(),
-        }
-    }
-}
-
-#[derive(Debug, Serialize)]
-struct ConversationResponse {
-    conversation: ConversationDto,
-}
-
-#[derive(Debug, Serialize)]
-struct ConversationHistoryResponse {
-    conversation_id: ConversationId,
-    messages: Vec<MessageDto>,
-}
-
-#[derive(Debug, Serialize)]
-struct MessageResponse {
-    message: MessageDto,
-}
-
 /// Registers the conversation routes under `/api/v1`.
 pub fn routes(cfg: &mut web::ServiceConfig) {
     cfg.service(web::resource("/conversations").route(web::post().to(create_conversation)))
@@ -98,6 +40,14 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
         .service(
             web::resource("/conversations/{conversation_id}/messages")
                 .route(web::post().to(append_message)),
+        )
+        .service(
+            web::resource("/conversations/{conversation_id}/archive")
+                .route(web::post().to(archive_conversation)),
+        )
+        .service(
+            web::resource("/conversations/{conversation_id}/unarchive")
+                .route(web::post().to(unarchive_conversation)),
         );
 }
 
@@ -114,9 +64,7 @@ async fn create_conversation(
         Ok(conversation) => json_success(
             &*state.clock,
             StatusCode::CREATED,
-            ConversationResponse {
-                conversation: conversation.into(),
-            },
+            ConversationResponseDto::from(conversation),
             request_id,
         ),
         Err(err) => ApiError::from(err).into_response(&*state.clock, request_id),
@@ -127,24 +75,24 @@ async fn get_history(
     state: web::Data<ApiState>,
     auth: AuthenticatedRequestContext,
     path: web::Path<ConversationPath>,
+    request: HttpRequest,
 ) -> HttpResponse {
     let request_id = auth.request_id();
-    let conversation_id = match parse_conversation_id(&path.conversation_id) {
-        Ok(id) => id,
+    let parsed = parse_conversation_id(&path.conversation_id)
+        .and_then(|id| Ok((id, parse_page_query(&request)?)));
+    let (conversation_id, page) = match parsed {
+        Ok(parsed_request) => parsed_request,
         Err(err) => return err.into_response(&*state.clock, request_id),
     };
     match state
         .conversations
-        .history(auth.context(), conversation_id)
+        .history(auth.context(), conversation_id, page)
         .await
     {
         Ok(messages) => json_success(
             &*state.clock,
             StatusCode::OK,
-            ConversationHistoryResponse {
-                conversation_id,
-                messages: messages.into_iter().map(MessageDto::from).collect(),
-            },
+            ConversationHistoryResponseDto::new(conversation_id, messages),
             request_id,
         ),
         Err(err) => ApiError::from(err).into_response(&*state.clock, request_id),
@@ -155,34 +103,92 @@ async fn append_message(
     state: web::Data<ApiState>,
     auth: AuthenticatedRequestContext,
     path: web::Path<ConversationPath>,
-    body: web::Json<AppendMessageBody>,
+    body: web::Json<AppendMessageRequestDto>,
 ) -> HttpResponse {
     let request_id = auth.request_id();
-    let conversation_id = match parse_conversation_id(&path.conversation_id) {
-        Ok(id) => id,
+    let parsed = parse_conversation_id(&path.conversation_id).and_then(|conversation_id| {
+        body.into_inner()
+            .into_request(conversation_id)
+            .map_err(|err| ApiError::bad_request("invalid_content", err.to_string()))
+    });
+    let append_request = match parsed {
+        Ok(append_request) => append_request,
         Err(err) => return err.into_response(&*state.clock, request_id),
     };
-    let payload = body.into_inner();
     match state
         .conversations
-        .append_message(
-            auth.context(),
-            AppendMessageRequest::new(conversation_id, payload.role, payload.content),
-        )
+        .append_message(auth.context(), append_request)
         .await
     {
         Ok(message) => json_success(
             &*state.clock,
             StatusCode::CREATED,
-            MessageResponse {
-                message: message.into(),
-            },
+            MessageResponseDto::from(message),
             request_id,
         ),
         Err(err) => ApiError::from(err).into_response(&*state.clock, request_id),
     }
 }
 
+async fn archive_conversation(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    path: web::Path<ConversationPath>,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let result = async {
+        let conversation_id = parse_conversation_id(&path.conversation_id)?;
+        Ok::<_, ApiError>(
+            state
+                .conversations
+                .archive_conversation(auth.context(), conversation_id)
+                .await?,
+        )
+    }
+    .await;
+    conversation_response(&state, result, request_id)
+}
+
+async fn unarchive_conversation(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    path: web::Path<ConversationPath>,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let access = if auth.role() == Some(ADMIN_ROLE) {
+        ConversationAccess::Elevated
+    } else {
+        ConversationAccess::Standard
+    };
+    let result = async {
+        let conversation_id = parse_conversation_id(&path.conversation_id)?;
+        Ok::<_, ApiError>(
+            state
+                .conversations
+                .unarchive_conversation(auth.context(), conversation_id, access)
+                .await?,
+        )
+    }
+    .await;
+    conversation_response(&state, result, request_id)
+}
+
+fn conversation_response(
+    state: &ApiState,
+    result: Result<Conversation, ApiError>,
+    request_id: String,
+) -> HttpResponse {
+    match result {
+        Ok(conversation) => json_success(
+            &*state.clock,
+            StatusCode::OK,
+            ConversationResponseDto::from(conversation),
+            request_id,
+        ),
+        Err(err) => err.into_response(&*state.clock, request_id),
+    }
+}
+
 fn parse_conversation_id(raw: &str) -> Result<ConversationId, ApiError> {
     Uuid::parse_str(raw)
         .map(ConversationId::from_uuid)
@@ -0,0 +1,57 @@
+//! Registers the doctor endpoint.
+//!
+//! `GET /api/v1/admin/doctor` probes the adapters configured for the
+//! process on behalf of the caller's tenant and returns the readiness
+//! report. It requires a bearer token carrying the [`ADMIN_ROLE`] role
+//! claim, answers `200 OK` when every probe passed and
+//! `503 Service Unavailable` with the same report when any failed, and
+//! answers `503 Service Unavailable` with the `doctor_unavailable` reason
+//! when the API state has no doctor attached.
+
+use super::super::{
+    auth::{ADMIN_ROLE, AuthenticatedRequestContext},
+    error::ApiError,
+    response::json_success,
+    state::ApiState,
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+use serde::Serialize;
+
+use crate::doctor::domain::ReadinessReport;
+
+#[derive(Debug, Serialize)]
+struct DoctorResponse {
+    report: ReadinessReport,
+}
+
+/// Registers the doctor route under `/api/v1`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/admin/doctor").route(web::get().to(run_doctor)));
+}
+
+async fn run_doctor(state: web::Data<ApiState>, auth: AuthenticatedRequestContext) -> HttpResponse {
+    let request_id = auth.request_id();
+    let Some(doctor) = state.doctor.as_deref() else {
+        return ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "doctor_unavailable",
+            "the doctor is not configured",
+        )
+        .into_response(&*state.clock, request_id);
+    };
+    if auth.role() != Some(ADMIN_ROLE) {
+        return ApiError::new(
+            StatusCode::FORBIDDEN,
+            "admin_role_required",
+            "running the doctor requires the admin role",
+        )
+        .into_response(&*state.clock, request_id);
+    }
+    let report = doctor.run(auth.context()).await;
+    let status = if report.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    json_success(&*state.clock, status, DoctorResponse { report }, request_id)
+}
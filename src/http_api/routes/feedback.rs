@@ -0,0 +1,212 @@
+//! Registers the message feedback endpoints.
+//!
+//! `POST /api/v1/conversations/{conversation_id}/messages/{message_id}/feedback`
+//! records the caller's rating of an assistant message, replacing their
+//! earlier rating of the same message, and `GET` on the same path lists every
+//! reviewer's feedback on it. `GET /api/v1/feedback/summaries` returns the
+//! tenant's feedback totals per agent backend and instruction-set version.
+//! The endpoints answer `503 Service Unavailable` when the API state has no
+//! feedback service attached.
+
+use super::super::{
+    auth::AuthenticatedRequestContext,
+    error::ApiError,
+    response::json_success,
+    state::{ApiState, FeedbackApplication},
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::message::domain::{
+    ConversationId, FeedbackCategory, FeedbackId, FeedbackRating, FeedbackSubmission,
+    FeedbackSummary, MessageFeedback, MessageId,
+};
+use crate::message::services::SubmitFeedbackRequest;
+
+#[derive(Debug, Deserialize)]
+struct MessagePath {
+    conversation_id: String,
+    message_id: String,
+}
+
+#[derive(Debug, Serialize)]
+struct FeedbackDto {
+    id: FeedbackId,
+    conversation_id: ConversationId,
+    message_id: MessageId,
+    author_id: Uuid,
+    rating: FeedbackRating,
+    comment: Option<String>,
+    category: Option<FeedbackCategory>,
+    agent_backend: Option<String>,
+    instruction_set_version: Option<String>,
+    submitted_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<MessageFeedback> for FeedbackDto {
+    fn from(feedback: MessageFeedback) -> Self {
+        Self {
+            id: feedback.id(),
+            conversation_id: feedback.conversation_id(),
+            message_id: feedback.message_id(),
+            author_id: feedback.author().into_inner(),
+            rating: feedback.rating(),
+            comment: feedback.comment().map(str::to_owned),
+            category: feedback.category(),
+            agent_backend: feedback.agent_backend().map(str::to_owned),
+            instruction_set_version: feedback.instruction_set_version().map(str::to_owned),
+            submitted_at: feedback.submitted_at(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct FeedbackSummaryDto {
+    #[serde(flatten)]
+    summary: FeedbackSummary,
+    approval_rate_bps: Option<u64>,
+    average_stars_centi: Option<u64>,
+}
+
+impl From<FeedbackSummary> for FeedbackSummaryDto {
+    fn from(summary: FeedbackSummary) -> Self {
+        Self {
+            approval_rate_bps: summary.approval_rate_bps(),
+            average_stars_centi: summary.average_stars_centi(),
+            summary,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct FeedbackResponse {
+    feedback: FeedbackDto,
+}
+
+#[derive(Debug, Serialize)]
+struct FeedbackListResponse {
+    message_id: MessageId,
+    feedback: Vec<FeedbackDto>,
+}
+
+#[derive(Debug, Serialize)]
+struct FeedbackSummariesResponse {
+    summaries: Vec<FeedbackSummaryDto>,
+}
+
+/// Registers the feedback routes under `/api/v1`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/conversations/{conversation_id}/messages/{message_id}/feedback")
+            .route(web::post().to(submit_feedback))
+            .route(web::get().to(list_feedback)),
+    )
+    .service(web::resource("/feedback/summaries").route(web::get().to(feedback_summaries)));
+}
+
+async fn submit_feedback(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    path: web::Path<MessagePath>,
+    body: web::Json<FeedbackSubmission>,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let (service, conversation_id, message_id) = match resolve_message(&state, &path) {
+        Ok(resolved) => resolved,
+        Err(err) => return err.into_response(&*state.clock, request_id),
+    };
+    let request = SubmitFeedbackRequest::new(conversation_id, message_id, body.into_inner());
+    match service.submit_feedback(auth.context(), request).await {
+        Ok(feedback) => json_success(
+            &*state.clock,
+            StatusCode::CREATED,
+            FeedbackResponse {
+                feedback: feedback.into(),
+            },
+            request_id,
+        ),
+        Err(err) => ApiError::from(err).into_response(&*state.clock, request_id),
+    }
+}
+
+async fn list_feedback(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    path: web::Path<MessagePath>,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let (service, conversation_id, message_id) = match resolve_message(&state, &path) {
+        Ok(resolved) => resolved,
+        Err(err) => return err.into_response(&*state.clock, request_id),
+    };
+    match service
+        .message_feedback(auth.context(), conversation_id, message_id)
+        .await
+    {
+        Ok(feedback) => json_success(
+            &*state.clock,
+            StatusCode::OK,
+            FeedbackListResponse {
+                message_id,
+                feedback: feedback.into_iter().map(FeedbackDto::from).collect(),
+            },
+            request_id,
+        ),
+        Err(err) => ApiError::from(err).into_response(&*state.clock, request_id),
+    }
+}
+
+async fn feedback_summaries(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let service = match feedback_service(&state) {
+        Ok(service) => service,
+        Err(err) => return err.into_response(&*state.clock, request_id),
+    };
+    match service.feedback_summaries(auth.context()).await {
+        Ok(summaries) => json_success(
+            &*state.clock,
+            StatusCode::OK,
+            FeedbackSummariesResponse {
+                summaries: summaries
+                    .into_iter()
+                    .map(FeedbackSummaryDto::from)
+                    .collect(),
+            },
+            request_id,
+        ),
+        Err(err) => ApiError::from(err).into_response(&*state.clock, request_id),
+    }
+}
+
+fn feedback_service(state: &ApiState) -> Result<&dyn FeedbackApplication, ApiError> {
+    state.feedback.as_deref().ok_or_else(|| {
+        ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "feedback_unavailable",
+            "message feedback is not configured",
+        )
+    })
+}
+
+fn resolve_message<'a>(
+    state: &'a ApiState,
+    path: &MessagePath,
+) -> Result<(&'a dyn FeedbackApplication, ConversationId, MessageId), ApiError> {
+    let service = feedback_service(state)?;
+    let (conversation_id, message_id) = parse_path(path)?;
+    Ok((service, conversation_id, message_id))
+}
+
+fn parse_path(path: &MessagePath) -> Result<(ConversationId, MessageId), ApiError> {
+    let conversation_id = Uuid::parse_str(&path.conversation_id)
+        .map(ConversationId::from_uuid)
+        .map_err(|_| ApiError::bad_request("invalid_conversation_id", "invalid conversation id"))?;
+    let message_id = Uuid::parse_str(&path.message_id)
+        .map(MessageId::from_uuid)
+        .map_err(|_| ApiError::bad_request("invalid_message_id", "invalid message id"))?;
+    Ok((conversation_id, message_id))
+}
@@ -0,0 +1,248 @@
+//! Registers the inbound message gateway endpoints.
+//!
+//! `POST /api/v1/inbound/slack/events` receives Slack Events API deliveries,
+//! `POST /api/v1/inbound/email` receives emails forwarded by a mail
+//! provider, and `POST /api/v1/inbound/webhooks/{source}` receives custom
+//! webhook payloads for a named source. Neither route takes a bearer token: each
+//! request must carry a valid signature for its source, and the external
+//! author is mapped to a principal before anything is stored.
+//!
+//! Ingested messages answer `201 Created` with the conversation and message
+//! identifiers, plus the task identifier when an email opened a task. Slack
+//! URL verification challenges are echoed, and Slack
+//! events that carry no user message are acknowledged with `200 OK`. The
+//! routes answer `503 Service Unavailable` when no gateway is attached and
+//! `404 Not Found` for sources without a verifier.
+
+use super::super::{
+    auth::request_correlation_id, error::ApiError, inbound::InboundGateway, response::json_success,
+    state::ApiState,
+};
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, web};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::context::CorrelationId;
+use crate::message::{
+    adapters::inbound::{
+        SignedRequest, SlackDelivery, parse_email_webhook, parse_slack_delivery,
+        parse_webhook_message,
+    },
+    domain::{ConversationId, EMAIL_SOURCE, InboundMessage, MessageId},
+    services::{EmailReceipt, InboundReceipt},
+};
+use crate::task::domain::TaskId;
+
+/// Header carrying Slack's request signature.
+pub const SLACK_SIGNATURE_HEADER: &str = "X-Slack-Signature";
+/// Header carrying Slack's request timestamp.
+pub const SLACK_TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+/// Header carrying a custom webhook signature.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Corbusier-Signature";
+/// Header carrying a custom webhook timestamp.
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Corbusier-Timestamp";
+
+#[derive(Debug, Serialize)]
+struct InboundResponse {
+    ingested: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conversation_id: Option<ConversationId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_conversation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task_id: Option<TaskId>,
+}
+
+impl InboundResponse {
+    const IGNORED: Self = Self {
+        ingested: false,
+        conversation_id: None,
+        message_id: None,
+        created_conversation: None,
+        task_id: None,
+    };
+}
+
+impl From<InboundReceipt> for InboundResponse {
+    fn from(receipt: InboundReceipt) -> Self {
+        Self {
+            ingested: true,
+            conversation_id: Some(receipt.conversation_id),
+            message_id: Some(receipt.message.id()),
+            created_conversation: Some(receipt.created_conversation),
+            task_id: None,
+        }
+    }
+}
+
+impl From<EmailReceipt> for InboundResponse {
+    fn from(email: EmailReceipt) -> Self {
+        Self {
+            task_id: email.task.map(|task| task.id()),
+            ..Self::from(email.receipt)
+        }
+    }
+}
+
+/// Registers the inbound gateway routes under `/api/v1`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/inbound/slack/events").route(web::post().to(slack_events)))
+        .service(web::resource("/inbound/email").route(web::post().to(email_message)))
+        .service(
+            web::resource("/inbound/webhooks/{source}").route(web::post().to(webhook_message)),
+        );
+}
+
+async fn slack_events(
+    state: web::Data<ApiState>,
+    request: HttpRequest,
+    body: web::Bytes,
+) -> HttpResponse {
+    let correlation_id = request_correlation_id(&request);
+    handle_slack(&state, &request, &body, correlation_id)
+        .await
+        .unwrap_or_else(|err| err.into_response(&*state.clock, correlation_id.to_string()))
+}
+
+async fn webhook_message(
+    state: web::Data<ApiState>,
+    request: HttpRequest,
+    source: web::Path<String>,
+    body: web::Bytes,
+) -> HttpResponse {
+    let correlation_id = request_correlation_id(&request);
+    let result = async {
+        let gateway = inbound_gateway(&state)?;
+        let verifier = gateway
+            .webhook(&source)
+            .ok_or_else(|| source_not_configured(&source))?;
+        let signed = signed_request(
+            &request,
+            &body,
+            (WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER),
+        );
+        verifier.verify(&signed, &*state.clock)?;
+        let message = parse_webhook_message(&source, &body)?;
+        ingest(&state, gateway, correlation_id, message).await
+    }
+    .await;
+    result.unwrap_or_else(|err| err.into_response(&*state.clock, correlation_id.to_string()))
+}
+
+async fn email_message(
+    state: web::Data<ApiState>,
+    request: HttpRequest,
+    body: web::Bytes,
+) -> HttpResponse {
+    let correlation_id = request_correlation_id(&request);
+    let result = async {
+        let gateway = inbound_gateway(&state)?;
+        let verifier = gateway
+            .email()
+            .ok_or_else(|| source_not_configured(EMAIL_SOURCE))?;
+        let signed = signed_request(
+            &request,
+            &body,
+            (WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER),
+        );
+        verifier.verify(&signed, &*state.clock)?;
+        let email = parse_email_webhook(&body)?;
+        let receipt = gateway
+            .application()
+            .ingest_email(correlation_id, email)
+            .await?;
+        Ok::<_, ApiError>(json_success(
+            &*state.clock,
+            StatusCode::CREATED,
+            InboundResponse::from(receipt),
+            correlation_id.to_string(),
+        ))
+    }
+    .await;
+    result.unwrap_or_else(|err| err.into_response(&*state.clock, correlation_id.to_string()))
+}
+
+async fn handle_slack(
+    state: &ApiState,
+    request: &HttpRequest,
+    body: &[u8],
+    correlation_id: CorrelationId,
+) -> Result<HttpResponse, ApiError> {
+    let gateway = inbound_gateway(state)?;
+    let verifier = gateway
+        .slack()
+        .ok_or_else(|| source_not_configured("slack"))?;
+    let signed = signed_request(
+        request,
+        body,
+        (SLACK_SIGNATURE_HEADER, SLACK_TIMESTAMP_HEADER),
+    );
+    verifier.verify(&signed, &*state.clock)?;
+    match parse_slack_delivery(body)? {
+        SlackDelivery::UrlVerification { challenge } => {
+            Ok(HttpResponse::Ok().json(json!({ "challenge": challenge })))
+        }
+        SlackDelivery::Ignored => Ok(json_success(
+            &*state.clock,
+            StatusCode::OK,
+            InboundResponse::IGNORED,
+            correlation_id.to_string(),
+        )),
+        SlackDelivery::Message(message) => ingest(state, gateway, correlation_id, message).await,
+    }
+}
+
+async fn ingest(
+    state: &ApiState,
+    gateway: &InboundGateway,
+    correlation_id: CorrelationId,
+    message: InboundMessage,
+) -> Result<HttpResponse, ApiError> {
+    let receipt = gateway
+        .application()
+        .ingest(correlation_id, message)
+        .await?;
+    Ok(json_success(
+        &*state.clock,
+        StatusCode::CREATED,
+        InboundResponse::from(receipt),
+        correlation_id.to_string(),
+    ))
+}
+
+fn inbound_gateway(state: &ApiState) -> Result<&InboundGateway, ApiError> {
+    state.inbound.as_deref().ok_or_else(|| {
+        ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "inbound_unavailable",
+            "the inbound message gateway is not configured",
+        )
+    })
+}
+
+fn source_not_configured(source: &str) -> ApiError {
+    ApiError::not_found(
+        "inbound_source_not_found",
+        format!("inbound source {source} is not configured"),
+    )
+}
+
+fn signed_request<'a>(
+    request: &'a HttpRequest,
+    body: &'a [u8],
+    (signature_header, timestamp_header): (&str, &str),
+) -> SignedRequest<'a> {
+    let header = |name: &str| {
+        request
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    };
+    SignedRequest {
+        signature: header(signature_header),
+        timestamp: header(timestamp_header),
+        body,
+    }
+}
@@ -0,0 +1,150 @@
+//! Registers the maintenance mode endpoints and the write guard.
+//!
+//! `GET /api/v1/admin/maintenance` reports the cluster-wide maintenance
+//! flag, and `PUT /api/v1/admin/maintenance` with a body of
+//! `{"enabled": true, "reason": "..."}` or `{"enabled": false}` toggles it.
+//! Toggling requires a bearer token carrying the [`ADMIN_ROLE`] role claim.
+//! The endpoints answer `503 Service Unavailable` when the API state has no
+//! maintenance gate attached.
+//!
+//! While maintenance is enabled, [`reject_writes_during_maintenance`]
+//! answers every request other than `GET`, `HEAD`, `OPTIONS`, and the
+//! toggle itself with `503 Service Unavailable` and the `maintenance_mode`
+//! reason.
+
+use super::super::{
+    auth::{ADMIN_ROLE, AuthenticatedRequestContext, request_correlation_id},
+    error::ApiError,
+    response::json_success,
+    state::ApiState,
+};
+use actix_web::{
+    HttpResponse,
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::StatusCode,
+    middleware::Next,
+    web,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::maintenance::{domain::MaintenanceState, services::MaintenanceGate};
+use crate::operator::domain::OperatorReason;
+
+/// Full path of the toggle, which stays writable during maintenance.
+const MAINTENANCE_PATH: &str = "/api/v1/admin/maintenance";
+
+#[derive(Debug, Deserialize)]
+struct MaintenanceBody {
+    enabled: bool,
+    reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct MaintenanceResponse {
+    maintenance: MaintenanceState,
+}
+
+/// Registers the maintenance routes under `/api/v1`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/admin/maintenance")
+            .route(web::get().to(maintenance_status))
+            .route(web::put().to(set_maintenance)),
+    );
+}
+
+/// Refuses writes while the attached maintenance gate reports maintenance.
+///
+/// # Errors
+///
+/// Propagates errors from the wrapped service.
+pub async fn reject_writes_during_maintenance(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, actix_web::Error> {
+    if let Some(rejection) = maintenance_rejection(&request).await {
+        return Ok(request.into_response(rejection).map_into_right_body());
+    }
+    next.call(request)
+        .await
+        .map(ServiceResponse::map_into_left_body)
+}
+
+async fn maintenance_rejection(request: &ServiceRequest) -> Option<HttpResponse> {
+    if request.method().is_safe() || request.path() == MAINTENANCE_PATH {
+        return None;
+    }
+    let state = request.app_data::<web::Data<ApiState>>()?;
+    let error = state
+        .maintenance
+        .as_deref()?
+        .ensure_writable()
+        .await
+        .err()?;
+    let request_id = request_correlation_id(request.request()).to_string();
+    Some(ApiError::from(error).into_response(&*state.clock, request_id))
+}
+
+async fn maintenance_status(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let result = async { Ok::<_, ApiError>(gate(&state)?.state().await?) }.await;
+    maintenance_response(&state, result, request_id)
+}
+
+async fn set_maintenance(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    body: web::Json<MaintenanceBody>,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let result = async {
+        let maintenance = gate(&state)?;
+        if auth.role() != Some(ADMIN_ROLE) {
+            return Err(ApiError::new(
+                StatusCode::FORBIDDEN,
+                "admin_role_required",
+                "changing maintenance mode requires the admin role",
+            ));
+        }
+        let changed_by = Some(auth.context().user_id());
+        let MaintenanceBody { enabled, reason } = body.into_inner();
+        if !enabled {
+            return Ok(maintenance.disable(changed_by).await?);
+        }
+        let parsed = OperatorReason::new(reason.unwrap_or_default())
+            .map_err(|err| ApiError::bad_request("invalid_maintenance_reason", err.to_string()))?;
+        Ok(maintenance.enable(parsed, changed_by).await?)
+    }
+    .await;
+    maintenance_response(&state, result, request_id)
+}
+
+fn maintenance_response(
+    state: &ApiState,
+    result: Result<MaintenanceState, ApiError>,
+    request_id: String,
+) -> HttpResponse {
+    match result {
+        Ok(maintenance) => json_success(
+            &*state.clock,
+            StatusCode::OK,
+            MaintenanceResponse { maintenance },
+            request_id,
+        ),
+        Err(err) => err.into_response(&*state.clock, request_id),
+    }
+}
+
+fn gate(state: &ApiState) -> Result<&MaintenanceGate, ApiError> {
+    state.maintenance.as_deref().ok_or_else(|| {
+        ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "maintenance_unavailable",
+            "maintenance mode is not configured",
+        )
+    })
+}
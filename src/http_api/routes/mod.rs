@@ -1,17 +1,47 @@
 //! Route registration for the HTTP API.
 
-use actix_web::web;
+use super::{auth::scope_request_context, error::ApiError};
+use crate::pagination::PageRequest;
+use actix_web::{HttpRequest, middleware::from_fn, web};
 
+pub mod conversation_transfers;
 pub mod conversations;
+pub mod doctor;
+pub mod feedback;
+pub mod inbound;
+pub mod maintenance;
+pub mod operator_actions;
+pub mod processing;
 pub mod tasks;
 pub mod tools;
 
 /// Registers the versioned API routes.
+///
+/// Writes are refused while the state's maintenance gate reports
+/// maintenance; see [`maintenance`]. Authenticated requests run inside
+/// their request context; see [`scope_request_context`].
 pub fn api_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/api/v1")
+            .wrap(from_fn(maintenance::reject_writes_during_maintenance))
+            .wrap(from_fn(scope_request_context))
+            .configure(conversation_transfers::routes)
             .configure(conversations::routes)
+            .configure(doctor::routes)
+            .configure(feedback::routes)
+            .configure(inbound::routes)
+            .configure(maintenance::routes)
+            .configure(operator_actions::routes)
+            .configure(processing::routes)
             .configure(tasks::routes)
             .configure(tools::routes),
     );
 }
+
+/// Reads `limit`, `cursor`, and `order` query parameters into a page
+/// request, defaulting any that are absent.
+fn parse_page_query(request: &HttpRequest) -> Result<PageRequest, ApiError> {
+    web::Query::<PageRequest>::from_query(request.query_string())
+        .map(web::Query::into_inner)
+        .map_err(|err| ApiError::bad_request("invalid_page_request", err.to_string()))
+}
@@ -0,0 +1,115 @@
+//! Registers the operator action timeline endpoints.
+//!
+//! `GET /api/v1/conversations/{conversation_id}/operator-actions` and
+//! `GET /api/v1/tasks/{task_id}/operator-actions` list the audited operator
+//! actions in a conversation's or task's timeline, oldest first, paginated by
+//! the usual `limit`, `cursor`, and `order` parameters. The endpoints answer
+//! `503 Service Unavailable` when the API state has no operator action
+//! repository attached.
+
+use super::super::{
+    auth::AuthenticatedRequestContext, error::ApiError, response::json_success, state::ApiState,
+};
+use super::parse_page_query;
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, web};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::message::domain::ConversationId;
+use crate::operator::{
+    domain::{OperatorAction, OperatorActionQuery},
+    ports::OperatorActionRepository,
+};
+use crate::pagination::Cursor;
+use crate::task::domain::TaskId;
+
+#[derive(Debug, Deserialize)]
+struct ConversationPath {
+    conversation_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TaskPath {
+    task_id: String,
+}
+
+#[derive(Debug, Serialize)]
+struct OperatorActionsResponse {
+    operator_actions: Vec<OperatorAction>,
+    next_cursor: Option<Cursor>,
+}
+
+/// Registers the operator action routes under `/api/v1`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/conversations/{conversation_id}/operator-actions")
+            .route(web::get().to(conversation_operator_actions)),
+    )
+    .service(
+        web::resource("/tasks/{task_id}/operator-actions")
+            .route(web::get().to(task_operator_actions)),
+    );
+}
+
+async fn conversation_operator_actions(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    path: web::Path<ConversationPath>,
+    request: HttpRequest,
+) -> HttpResponse {
+    let query = Uuid::parse_str(&path.conversation_id)
+        .map(|id| OperatorActionQuery::for_conversation(ConversationId::from_uuid(id)))
+        .map_err(|_| ApiError::bad_request("invalid_conversation_id", "invalid conversation id"));
+    list_operator_actions(&state, &auth, query, &request).await
+}
+
+async fn task_operator_actions(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    path: web::Path<TaskPath>,
+    request: HttpRequest,
+) -> HttpResponse {
+    let query = Uuid::parse_str(&path.task_id)
+        .map(|id| OperatorActionQuery::for_task(TaskId::from_uuid(id)))
+        .map_err(|_| ApiError::bad_request("invalid_task_id", "invalid task id"));
+    list_operator_actions(&state, &auth, query, &request).await
+}
+
+async fn list_operator_actions(
+    state: &ApiState,
+    auth: &AuthenticatedRequestContext,
+    query: Result<OperatorActionQuery, ApiError>,
+    request: &HttpRequest,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let parsed = operator_actions(state).and_then(|repository| {
+        let page = parse_page_query(request)?;
+        Ok((repository, query?, page))
+    });
+    let (repository, query, page) = match parsed {
+        Ok(resolved) => resolved,
+        Err(err) => return err.into_response(&*state.clock, request_id),
+    };
+    match repository.query(auth.context(), query, page).await {
+        Ok(actions) => json_success(
+            &*state.clock,
+            StatusCode::OK,
+            OperatorActionsResponse {
+                next_cursor: actions.next_cursor(),
+                operator_actions: actions.into_items(),
+            },
+            request_id,
+        ),
+        Err(err) => ApiError::from(err).into_response(&*state.clock, request_id),
+    }
+}
+
+fn operator_actions(state: &ApiState) -> Result<&dyn OperatorActionRepository, ApiError> {
+    state.operator_actions.as_deref().ok_or_else(|| {
+        ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "operator_actions_unavailable",
+            "operator action auditing is not configured",
+        )
+    })
+}
@@ -0,0 +1,270 @@
+//! Registers the message processing status endpoints.
+//!
+//! `GET /api/v1/conversations/{conversation_id}/messages/{message_id}/processing`
+//! reports a message's progress through the post-ingestion pipeline, and
+//! `POST` on the same path's `/reprocess` sub-resource sends the message back
+//! through the stage named in the body. `GET /api/v1/processing/stuck` lists
+//! messages whose `stage` has failed or stayed in progress for at least
+//! `idle_secs` seconds, one page at a time, and `GET /api/v1/processing/load` reports the current
+//! load level and how each background workload is being shed. The endpoints
+//! answer `503 Service Unavailable` when the API state has no processing
+//! service attached, or, for the load report, when load shedding is not
+//! configured.
+
+use super::super::{
+    auth::AuthenticatedRequestContext, error::ApiError, processing::ProcessingApplication,
+    response::json_success, state::ApiState,
+};
+use super::parse_page_query;
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, web};
+use chrono::TimeDelta;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::message::domain::{
+    ConversationId, LoadLevel, LoadSheddingMetrics, MessageId, MessageProcessingStatus,
+    ProcessingStage, StageStatus, StuckMessagesQuery, WorkloadSheddingMetrics,
+};
+use crate::message::services::{ProcessingServiceError, ReprocessRequest};
+use crate::pagination::Cursor;
+
+/// Idle time after which an unfinished stage counts as stuck, when the
+/// caller does not say.
+const DEFAULT_STUCK_IDLE_SECS: u32 = 300;
+
+#[derive(Debug, Deserialize)]
+struct MessagePath {
+    conversation_id: String,
+    message_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReprocessBody {
+    stage: ProcessingStage,
+}
+
+#[derive(Debug, Deserialize)]
+struct StuckParams {
+    stage: ProcessingStage,
+    idle_secs: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProcessingStatusDto {
+    #[serde(flatten)]
+    status: MessageProcessingStatus,
+    current_stage: Option<ProcessingStage>,
+    complete: bool,
+}
+
+impl From<MessageProcessingStatus> for ProcessingStatusDto {
+    fn from(status: MessageProcessingStatus) -> Self {
+        Self {
+            current_stage: status.current_stage(),
+            complete: status.is_complete(),
+            status,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ProcessingStatusResponse {
+    processing: ProcessingStatusDto,
+}
+
+#[derive(Debug, Serialize)]
+struct StuckMessagesResponse {
+    stage: ProcessingStage,
+    stuck: Vec<StageStatus>,
+    next_cursor: Option<Cursor>,
+}
+
+#[derive(Debug, Serialize)]
+struct LoadSheddingDto {
+    level: LoadLevel,
+    turn_latency_p95_ms: Option<u64>,
+    max_queue_depth: usize,
+    queue_depths: BTreeMap<String, usize>,
+    level_changes: u64,
+    workloads: Vec<WorkloadSheddingMetrics>,
+}
+
+impl From<LoadSheddingMetrics> for LoadSheddingDto {
+    fn from(metrics: LoadSheddingMetrics) -> Self {
+        Self {
+            level: metrics.level,
+            turn_latency_p95_ms: metrics
+                .signals
+                .turn_latency_p95
+                .map(|latency| u64::try_from(latency.as_millis()).unwrap_or(u64::MAX)),
+            max_queue_depth: metrics.signals.max_queue_depth,
+            queue_depths: metrics.queue_depths,
+            level_changes: metrics.level_changes,
+            workloads: metrics.workloads,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct LoadSheddingResponse {
+    load: LoadSheddingDto,
+}
+
+/// Registers the processing status routes under `/api/v1`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/conversations/{conversation_id}/messages/{message_id}/processing")
+            .route(web::get().to(processing_status)),
+    )
+    .service(
+        web::resource(
+            "/conversations/{conversation_id}/messages/{message_id}/processing/reprocess",
+        )
+        .route(web::post().to(reprocess)),
+    )
+    .service(web::resource("/processing/stuck").route(web::get().to(stuck_messages)))
+    .service(web::resource("/processing/load").route(web::get().to(load_shedding)));
+}
+
+async fn processing_status(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    path: web::Path<MessagePath>,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let (service, conversation_id, message_id) = match resolve_message(&state, &path) {
+        Ok(resolved) => resolved,
+        Err(err) => return err.into_response(&*state.clock, request_id),
+    };
+    let result = service
+        .processing_status(auth.context(), conversation_id, message_id)
+        .await;
+    status_response(&state, result, request_id)
+}
+
+async fn reprocess(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    path: web::Path<MessagePath>,
+    body: web::Json<ReprocessBody>,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let (service, conversation_id, message_id) = match resolve_message(&state, &path) {
+        Ok(resolved) => resolved,
+        Err(err) => return err.into_response(&*state.clock, request_id),
+    };
+    let request = ReprocessRequest::new(conversation_id, message_id, body.stage);
+    let result = service.reprocess(auth.context(), request).await;
+    status_response(&state, result, request_id)
+}
+
+async fn stuck_messages(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    request: HttpRequest,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let lookup = processing_service(&state).and_then(|service| {
+        let query = parse_stuck_query(&state, &request)?;
+        Ok((service, query))
+    });
+    let (service, query) = match lookup {
+        Ok(resolved) => resolved,
+        Err(err) => return err.into_response(&*state.clock, request_id),
+    };
+    match service.stuck_messages(auth.context(), query).await {
+        Ok(stuck) => json_success(
+            &*state.clock,
+            StatusCode::OK,
+            StuckMessagesResponse {
+                stage: query.stage,
+                next_cursor: stuck.next_cursor(),
+                stuck: stuck.into_items(),
+            },
+            request_id,
+        ),
+        Err(err) => ApiError::from(err).into_response(&*state.clock, request_id),
+    }
+}
+
+async fn load_shedding(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+) -> HttpResponse {
+    let request_id = auth.request_id();
+    let metrics = processing_service(&state).and_then(|service| {
+        service.load_shedding().ok_or_else(|| {
+            ApiError::new(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "load_shedding_unavailable",
+                "load shedding is not configured",
+            )
+        })
+    });
+    match metrics {
+        Ok(load) => json_success(
+            &*state.clock,
+            StatusCode::OK,
+            LoadSheddingResponse { load: load.into() },
+            request_id,
+        ),
+        Err(err) => err.into_response(&*state.clock, request_id),
+    }
+}
+
+fn status_response(
+    state: &ApiState,
+    result: Result<MessageProcessingStatus, ProcessingServiceError>,
+    request_id: String,
+) -> HttpResponse {
+    match result {
+        Ok(status) => json_success(
+            &*state.clock,
+            StatusCode::OK,
+            ProcessingStatusResponse {
+                processing: status.into(),
+            },
+            request_id,
+        ),
+        Err(err) => ApiError::from(err).into_response(&*state.clock, request_id),
+    }
+}
+
+fn parse_stuck_query(
+    state: &ApiState,
+    request: &HttpRequest,
+) -> Result<StuckMessagesQuery, ApiError> {
+    let params = web::Query::<StuckParams>::from_query(request.query_string())
+        .map_err(|err| ApiError::bad_request("invalid_stuck_query", err.to_string()))?
+        .into_inner();
+    let idle = TimeDelta::seconds(i64::from(
+        params.idle_secs.unwrap_or(DEFAULT_STUCK_IDLE_SECS),
+    ));
+    let page = parse_page_query(request)?;
+    Ok(StuckMessagesQuery::new(params.stage, state.clock.utc() - idle).with_page(page))
+}
+
+fn processing_service(state: &ApiState) -> Result<&dyn ProcessingApplication, ApiError> {
+    state.processing.as_deref().ok_or_else(|| {
+        ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "processing_unavailable",
+            "message processing status is not configured",
+        )
+    })
+}
+
+fn resolve_message<'a>(
+    state: &'a ApiState,
+    path: &MessagePath,
+) -> Result<(&'a dyn ProcessingApplication, ConversationId, MessageId), ApiError> {
+    let service = processing_service(state)?;
+    let conversation_id = Uuid::parse_str(&path.conversation_id)
+        .map(ConversationId::from_uuid)
+        .map_err(|_| ApiError::bad_request("invalid_conversation_id", "invalid conversation id"))?;
+    let message_id = Uuid::parse_str(&path.message_id)
+        .map(MessageId::from_uuid)
+        .map_err(|_| ApiError::bad_request("invalid_message_id", "invalid message id"))?;
+    Ok((service, conversation_id, message_id))
+}
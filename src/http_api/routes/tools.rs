@@ -11,10 +11,12 @@
 use super::super::{
     auth::AuthenticatedRequestContext, error::ApiError, response::json_success, state::ApiState,
 };
-use actix_web::{HttpResponse, http::StatusCode, web};
+use super::parse_page_query;
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, web};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::pagination::Cursor;
 use crate::tool_registry::domain::{
     CatalogEntry, ToolCallOutcome, ToolCallRequest, ToolCallResult,
 };
@@ -28,6 +30,7 @@ struct ToolCallBody {
 #[derive(Debug, Serialize)]
 struct ToolCatalogResponse {
     tools: Vec<CatalogEntry>,
+    next_cursor: Option<Cursor>,
 }
 
 #[derive(Debug, Serialize)]
@@ -46,13 +49,24 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
         .service(web::resource("/tools/calls").route(web::post().to(call_tool)));
 }
 
-async fn list_tools(state: web::Data<ApiState>, auth: AuthenticatedRequestContext) -> HttpResponse {
+async fn list_tools(
+    state: web::Data<ApiState>,
+    auth: AuthenticatedRequestContext,
+    request: HttpRequest,
+) -> HttpResponse {
     let request_id = auth.request_id();
-    match state.tools.list_tools(auth.context()).await {
+    let page = match parse_page_query(&request) {
+        Ok(page) => page,
+        Err(err) => return err.into_response(&*state.clock, request_id),
+    };
+    match state.tools.list_tools(auth.context(), page).await {
         Ok(tools) => json_success(
             &*state.clock,
             StatusCode::OK,
-            ToolCatalogResponse { tools },
+            ToolCatalogResponse {
+                next_cursor: tools.next_cursor(),
+                tools: tools.into_items(),
+            },
             request_id,
         ),
         Err(err) => ApiError::from(err).into_response(&*state.clock, request_id),
@@ -1,36 +1,24 @@
-//! Shared application state and adapter-local service traits.
-//!
-//! The [`ApiState`] struct bundles the three application-service adapters used by
-//! the HTTP API, namely `Arc<dyn ConversationApplication>`,
-//! `Arc<dyn TaskApplication>`, and `Arc<dyn ToolApplication>`, alongside the
-//! `BearerTokenAuthenticator` and injectable `Clock` instances needed by
-//! request handling. It is injected into every Actix handler through
-//! `web::Data<ApiState>`, giving route modules a single shared access point for
-//! request-scoped orchestration.
-//!
-//! The [`ConversationApplication`], [`TaskApplication`], and
-//! [`ToolApplication`] traits decouple HTTP route handlers from concrete
-//! service implementations. That separation lets in-memory and Postgres-backed
-//! implementations be swapped in for tests and runtime wiring without altering
-//! handler code.
-//!
-//! All three traits are `Send + Sync`, and the concrete services are stored
-//! behind [`Arc`] so [`ApiState`] can be cloned cheaply and shared safely across
-//! the Actix worker pool.
-
-use super::auth::BearerTokenAuthenticator;
+//! The application traits route handlers call, and their implementations
+//! for the concrete services.
+
 use async_trait::async_trait;
-use std::sync::Arc;
 
 use crate::context::RequestContext;
 use crate::message::{
-    domain::{Conversation, ConversationId, Message},
-    ports::{ConversationRepository, MessageRepository, MessageValidator},
+    domain::{
+        Conversation, ConversationAccess, ConversationId, FeedbackSummary, Message,
+        MessageFeedback, MessageId,
+    },
+    ports::{
+        ConversationRepository, MessageFeedbackRepository, MessageRepository, MessageValidator,
+    },
     services::{
         AppendMessageRequest as AppendConversationMessageRequest, ConversationService,
-        ConversationServiceError,
+        ConversationServiceError, FeedbackServiceError, MessageFeedbackService,
+        SubmitFeedbackRequest,
     },
 };
+use crate::pagination::{Page, PageRequest};
 use crate::task::{
     domain::{Task, TaskId},
     ports::TaskRepository,
@@ -58,12 +46,13 @@ pub trait ConversationApplication: Send + Sync {
         ctx: &RequestContext,
     ) -> Result<Conversation, ConversationServiceError>;
 
-    /// Returns ordered conversation history.
+    /// Returns a page of ordered conversation history.
     async fn history(
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> Result<Vec<Message>, ConversationServiceError>;
+        page: PageRequest,
+    ) -> Result<Page<Message>, ConversationServiceError>;
 
     /// Appends a message to an existing conversation.
     async fn append_message(
@@ -71,6 +60,46 @@ pub trait ConversationApplication: Send + Sync {
         ctx: &RequestContext,
         request: AppendConversationMessageRequest,
     ) -> Result<Message, ConversationServiceError>;
+
+    /// Archives a conversation.
+    async fn archive_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> Result<Conversation, ConversationServiceError>;
+
+    /// Reopens an archived conversation.
+    async fn unarchive_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        access: ConversationAccess,
+    ) -> Result<Conversation, ConversationServiceError>;
+}
+
+/// Message feedback operations exposed to the HTTP adapter.
+#[async_trait]
+pub trait FeedbackApplication: Send + Sync {
+    /// Records the caller's feedback on an assistant message.
+    async fn submit_feedback(
+        &self,
+        ctx: &RequestContext,
+        request: SubmitFeedbackRequest,
+    ) -> Result<MessageFeedback, FeedbackServiceError>;
+
+    /// Lists feedback on a message.
+    async fn message_feedback(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        message_id: MessageId,
+    ) -> Result<Vec<MessageFeedback>, FeedbackServiceError>;
+
+    /// Returns feedback totals per backend and instruction-set version.
+    async fn feedback_summaries(
+        &self,
+        ctx: &RequestContext,
+    ) -> Result<Vec<FeedbackSummary>, FeedbackServiceError>;
 }
 
 /// Task operations exposed to the HTTP adapter.
@@ -115,11 +144,12 @@ pub trait TaskApplication: Send + Sync {
 /// Tool operations exposed to the HTTP adapter.
 #[async_trait]
 pub trait ToolApplication: Send + Sync {
-    /// Lists the persisted tool catalog.
+    /// Lists a page of the persisted tool catalog.
     async fn list_tools(
         &self,
         ctx: &RequestContext,
-    ) -> Result<Vec<CatalogEntry>, ToolDiscoveryRoutingServiceError>;
+        page: PageRequest,
+    ) -> Result<Page<CatalogEntry>, ToolDiscoveryRoutingServiceError>;
 
     /// Routes a tool call.
     async fn call_tool(
@@ -129,48 +159,6 @@ pub trait ToolApplication: Send + Sync {
     ) -> Result<ToolCallResult, ToolDiscoveryRoutingServiceError>;
 }
 
-/// Shared state injected into the Actix application.
-#[derive(Clone)]
-pub struct ApiState {
-    /// Conversation application service.
-    pub conversations: Arc<dyn ConversationApplication>,
-    /// Task application service.
-    pub tasks: Arc<dyn TaskApplication>,
-    /// Tool application service.
-    pub tools: Arc<dyn ToolApplication>,
-    /// Bearer-token authenticator.
-    pub authenticator: BearerTokenAuthenticator,
-    /// Clock for time-dependent operations.
-    pub clock: Arc<dyn Clock + Send + Sync>,
-}
-
-/// Infrastructure configuration for the HTTP API adapter.
-pub struct ApiConfig {
-    /// Bearer-token authenticator.
-    pub authenticator: BearerTokenAuthenticator,
-    /// Clock for time-dependent operations.
-    pub clock: Arc<dyn Clock + Send + Sync>,
-}
-
-impl ApiState {
-    /// Creates a new shared API state bundle.
-    #[must_use]
-    pub fn new(
-        conversations: Arc<dyn ConversationApplication>,
-        tasks: Arc<dyn TaskApplication>,
-        tools: Arc<dyn ToolApplication>,
-        config: ApiConfig,
-    ) -> Self {
-        Self {
-            conversations,
-            tasks,
-            tools,
-            authenticator: config.authenticator,
-            clock: config.clock,
-        }
-    }
-}
-
 #[async_trait]
 impl<ConvoRepo, MessageRepo, Validator, C> ConversationApplication
     for ConversationService<ConvoRepo, MessageRepo, Validator, C>
@@ -191,8 +179,9 @@ where
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> Result<Vec<Message>, ConversationServiceError> {
-        self.history(ctx, conversation_id).await
+        page: PageRequest,
+    ) -> Result<Page<Message>, ConversationServiceError> {
+        self.history(ctx, conversation_id, page).await
     }
 
     async fn append_message(
@@ -202,6 +191,58 @@ where
     ) -> Result<Message, ConversationServiceError> {
         self.append_message(ctx, request).await
     }
+
+    async fn archive_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> Result<Conversation, ConversationServiceError> {
+        self.archive_conversation(ctx, conversation_id).await
+    }
+
+    async fn unarchive_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        access: ConversationAccess,
+    ) -> Result<Conversation, ConversationServiceError> {
+        self.unarchive_conversation(ctx, conversation_id, access)
+            .await
+    }
+}
+
+#[async_trait]
+impl<MessageRepo, FeedbackRepo, C> FeedbackApplication
+    for MessageFeedbackService<MessageRepo, FeedbackRepo, C>
+where
+    MessageRepo: MessageRepository + 'static,
+    FeedbackRepo: MessageFeedbackRepository + 'static,
+    C: Clock + Send + Sync + 'static,
+{
+    async fn submit_feedback(
+        &self,
+        ctx: &RequestContext,
+        request: SubmitFeedbackRequest,
+    ) -> Result<MessageFeedback, FeedbackServiceError> {
+        self.submit(ctx, request).await
+    }
+
+    async fn message_feedback(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        message_id: MessageId,
+    ) -> Result<Vec<MessageFeedback>, FeedbackServiceError> {
+        self.feedback_for_message(ctx, conversation_id, message_id)
+            .await
+    }
+
+    async fn feedback_summaries(
+        &self,
+        ctx: &RequestContext,
+    ) -> Result<Vec<FeedbackSummary>, FeedbackServiceError> {
+        self.summaries(ctx).await
+    }
 }
 
 #[async_trait]
@@ -265,8 +306,9 @@ where
     async fn list_tools(
         &self,
         ctx: &RequestContext,
-    ) -> Result<Vec<CatalogEntry>, ToolDiscoveryRoutingServiceError> {
-        self.list_catalog(ctx).await
+        page: PageRequest,
+    ) -> Result<Page<CatalogEntry>, ToolDiscoveryRoutingServiceError> {
+        self.list_catalog(ctx, page).await
     }
 
     async fn call_tool(
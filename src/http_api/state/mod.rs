@@ -0,0 +1,149 @@
+//! Shared application state and adapter-local service traits.
+//!
+//! [`ApiState`] bundles the application services with the bearer-token
+//! authenticator and injectable clock, and reaches every Actix handler through
+//! `web::Data<ApiState>`. The application traits decouple route handlers from
+//! concrete services, so in-memory and Postgres-backed implementations can be
+//! swapped in. Feedback, processing status, the inbound gateway, operator
+//! action timelines, maintenance mode, conversation transfers, and the
+//! doctor are optional; their routes answer `503 Service Unavailable` until
+//! attached with [`ApiState::with_feedback`], [`ApiState::with_processing`],
+//! [`ApiState::with_inbound`], [`ApiState::with_operator_actions`],
+//! [`ApiState::with_maintenance`],
+//! [`ApiState::with_conversation_transfers`], or [`ApiState::with_doctor`].
+//! Without a maintenance gate, writes are never refused for maintenance.
+
+mod applications;
+
+pub use applications::{
+    ConversationApplication, FeedbackApplication, TaskApplication, ToolApplication,
+};
+
+use super::{
+    auth::BearerTokenAuthenticator, inbound::InboundGateway, processing::ProcessingApplication,
+};
+use std::sync::Arc;
+
+use crate::doctor::services::DoctorService;
+use crate::maintenance::services::MaintenanceGate;
+use crate::message::services::ConversationTransferService;
+use crate::operator::ports::OperatorActionRepository;
+use mockable::Clock;
+
+/// Shared state injected into the Actix application.
+#[derive(Clone)]
+pub struct ApiState {
+    /// Conversation application service.
+    pub conversations: Arc<dyn ConversationApplication>,
+    /// Task application service.
+    pub tasks: Arc<dyn TaskApplication>,
+    /// Tool application service.
+    pub tools: Arc<dyn ToolApplication>,
+    /// Message feedback application service, when configured.
+    pub feedback: Option<Arc<dyn FeedbackApplication>>,
+    /// Message processing status application service, when configured.
+    pub processing: Option<Arc<dyn ProcessingApplication>>,
+    /// Inbound message gateway, when configured.
+    pub inbound: Option<Arc<InboundGateway>>,
+    /// Operator action repository backing the timelines, when configured.
+    pub operator_actions: Option<Arc<dyn OperatorActionRepository>>,
+    /// Maintenance gate refusing writes during maintenance, when configured.
+    pub maintenance: Option<Arc<MaintenanceGate>>,
+    /// Conversation transfer service, when configured.
+    pub conversation_transfers: Option<Arc<ConversationTransferService>>,
+    /// Doctor probing the configured adapters, when configured.
+    pub doctor: Option<Arc<DoctorService>>,
+    /// Bearer-token authenticator.
+    pub authenticator: BearerTokenAuthenticator,
+    /// Clock for time-dependent operations.
+    pub clock: Arc<dyn Clock + Send + Sync>,
+}
+
+/// Infrastructure configuration for the HTTP API adapter.
+pub struct ApiConfig {
+    /// Bearer-token authenticator.
+    pub authenticator: BearerTokenAuthenticator,
+    /// Clock for time-dependent operations.
+    pub clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl ApiState {
+    /// Creates a new shared API state bundle.
+    #[must_use]
+    pub fn new(
+        conversations: Arc<dyn ConversationApplication>,
+        tasks: Arc<dyn TaskApplication>,
+        tools: Arc<dyn ToolApplication>,
+        config: ApiConfig,
+    ) -> Self {
+        Self {
+            conversations,
+            tasks,
+            tools,
+            feedback: None,
+            processing: None,
+            inbound: None,
+            operator_actions: None,
+            maintenance: None,
+            conversation_transfers: None,
+            doctor: None,
+            authenticator: config.authenticator,
+            clock: config.clock,
+        }
+    }
+
+    /// Attaches the message feedback application service.
+    #[must_use]
+    pub fn with_feedback(mut self, feedback: Arc<dyn FeedbackApplication>) -> Self {
+        self.feedback = Some(feedback);
+        self
+    }
+
+    /// Attaches the message processing status application service.
+    #[must_use]
+    pub fn with_processing(mut self, processing: Arc<dyn ProcessingApplication>) -> Self {
+        self.processing = Some(processing);
+        self
+    }
+
+    /// Attaches the inbound message gateway.
+    #[must_use]
+    pub fn with_inbound(mut self, inbound: InboundGateway) -> Self {
+        self.inbound = Some(Arc::new(inbound));
+        self
+    }
+
+    /// Attaches the operator action repository served by the timelines.
+    #[must_use]
+    pub fn with_operator_actions(
+        mut self,
+        operator_actions: Arc<dyn OperatorActionRepository>,
+    ) -> Self {
+        self.operator_actions = Some(operator_actions);
+        self
+    }
+
+    /// Attaches the maintenance gate consulted before every write.
+    #[must_use]
+    pub fn with_maintenance(mut self, maintenance: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(maintenance);
+        self
+    }
+
+    /// Attaches the service behind the conversation transfer endpoint.
+    #[must_use]
+    pub fn with_conversation_transfers(
+        mut self,
+        conversation_transfers: Arc<ConversationTransferService>,
+    ) -> Self {
+        self.conversation_transfers = Some(conversation_transfers);
+        self
+    }
+
+    /// Attaches the doctor behind the readiness report endpoint.
+    #[must_use]
+    pub fn with_doctor(mut self, doctor: Arc<DoctorService>) -> Self {
+        self.doctor = Some(doctor);
+        self
+    }
+}
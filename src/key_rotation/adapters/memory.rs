@@ -0,0 +1,69 @@
+//! In-memory key rotation repository.
+
+use crate::context::{RequestContext, TenantId};
+use crate::key_rotation::domain::{KeyRotation, KeyRotationId};
+use crate::key_rotation::ports::{KeyRotationRepository, KeyRotationResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Thread-safe in-memory store of key rotation progress.
+///
+/// The in-memory message adapters store messages unencrypted, so there is
+/// no in-memory resealer; pair this with a [`MessageResealer`] over the
+/// store that holds the sealed messages.
+///
+/// [`MessageResealer`]: crate::key_rotation::ports::MessageResealer
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryKeyRotationRepository {
+    rotations: Arc<RwLock<HashMap<TenantId, Vec<KeyRotation>>>>,
+}
+
+impl InMemoryKeyRotationRepository {
+    /// Creates an empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    async fn find_where(
+        &self,
+        ctx: &RequestContext,
+        predicate: impl Fn(&KeyRotation) -> bool,
+    ) -> Option<KeyRotation> {
+        self.rotations
+            .read()
+            .await
+            .get(&ctx.tenant_id())
+            .and_then(|rotations| {
+                rotations
+                    .iter()
+                    .find(|rotation| predicate(rotation))
+                    .cloned()
+            })
+    }
+}
+
+#[async_trait]
+impl KeyRotationRepository for InMemoryKeyRotationRepository {
+    async fn save(&self, ctx: &RequestContext, rotation: &KeyRotation) -> KeyRotationResult<()> {
+        let mut rotations = self.rotations.write().await;
+        let tenant = rotations.entry(ctx.tenant_id()).or_default();
+        tenant.retain(|stored| stored.id != rotation.id);
+        tenant.push(rotation.clone());
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        id: KeyRotationId,
+    ) -> KeyRotationResult<Option<KeyRotation>> {
+        Ok(self.find_where(ctx, |rotation| rotation.id == id).await)
+    }
+
+    async fn find_running(&self, ctx: &RequestContext) -> KeyRotationResult<Option<KeyRotation>> {
+        Ok(self.find_where(ctx, KeyRotation::is_running).await)
+    }
+}
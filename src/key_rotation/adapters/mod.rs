@@ -0,0 +1,7 @@
+//! Adapter implementations for the key rotation ports.
+
+pub mod memory;
+pub mod postgres;
+
+pub use memory::InMemoryKeyRotationRepository;
+pub use postgres::PostgresKeyRotationRepository;
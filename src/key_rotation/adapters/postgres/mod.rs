@@ -0,0 +1,8 @@
+//! `PostgreSQL` adapters for encryption key rotation.
+
+mod models;
+mod repository;
+mod reseal;
+mod schema;
+
+pub use repository::PostgresKeyRotationRepository;
@@ -0,0 +1,35 @@
+//! Diesel models for key rotation persistence.
+
+use super::schema::encryption_key_rotations;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+/// Row representation for a key rotation.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = encryption_key_rotations)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(treat_none_as_null = true)]
+pub struct KeyRotationRow {
+    /// Rotation identifier.
+    pub id: uuid::Uuid,
+    /// Tenant identifier.
+    pub tenant_id: uuid::Uuid,
+    /// The key messages are resealed under.
+    pub target_key_id: String,
+    /// Rotation status.
+    pub status: String,
+    /// User who started the rotation.
+    pub started_by: uuid::Uuid,
+    /// When the rotation started.
+    pub started_at: DateTime<Utc>,
+    /// When the rotation last made progress.
+    pub updated_at: DateTime<Utc>,
+    /// When the rotation completed or was superseded.
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Batches run so far.
+    pub batches: i64,
+    /// Messages resealed so far.
+    pub resealed: i64,
+    /// Messages left to reseal when last counted.
+    pub remaining: i64,
+}
@@ -0,0 +1,243 @@
+//! `PostgreSQL` repository implementation for encryption key rotation.
+
+use super::models::KeyRotationRow;
+use super::reseal;
+use super::schema::encryption_key_rotations;
+use crate::context::{RequestContext, TenantId, UserId};
+use crate::key_rotation::domain::{KeyRotation, KeyRotationId, KeyRotationStatus};
+use crate::key_rotation::ports::{
+    KeyRotationError, KeyRotationRepository, KeyRotationResult, MessageResealer,
+};
+use crate::message::adapters::audit_context::AuditContext;
+use crate::message::domain::EncryptionKeyId;
+use crate::message::ports::ContentCipher;
+use crate::postgres_support::{
+    FromTxError, MessageCodec, PgPool, RowScope, TxError, ensure_tenant_exists, get_conn_with,
+    run_blocking_with, set_audit_context, with_tenant_read_tx, with_tenant_tx,
+};
+use async_trait::async_trait;
+use diesel::pg::{Pg, PgConnection};
+use diesel::prelude::*;
+use std::sync::Arc;
+
+impl FromTxError<Self> for KeyRotationError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(error) => error,
+            TxError::Diesel(error) => Self::persistence_failed(error),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed key rotation repository.
+///
+/// Records rotation progress and reseals the tenant's messages and staged
+/// streams with the given cipher, so it serves as both the
+/// [`KeyRotationRepository`] and the [`MessageResealer`] of a rotation. Each
+/// batch runs in its own transaction.
+#[derive(Debug, Clone)]
+pub struct PostgresKeyRotationRepository {
+    pool: PgPool,
+    codec: MessageCodec,
+    target_key: EncryptionKeyId,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresKeyRotationRepository,
+    "key_rotation_repository"
+);
+
+impl PostgresKeyRotationRepository {
+    /// Creates a repository resealing messages under `cipher`'s active key.
+    ///
+    /// The cipher must also hold every key messages are currently sealed
+    /// under, or batches containing them fail.
+    #[must_use]
+    pub fn new(pool: PgPool, cipher: Arc<dyn ContentCipher>) -> Self {
+        Self {
+            pool,
+            target_key: cipher.active_key_id().clone(),
+            codec: MessageCodec::sealed(cipher),
+        }
+    }
+
+    async fn read<F, T>(&self, tenant_id: TenantId, query_fn: F) -> KeyRotationResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> KeyRotationResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, KeyRotationError::persistence_failed)?;
+                with_tenant_read_tx(&mut conn, tenant_id.into_inner(), query_fn)
+            },
+            KeyRotationError::persistence_failed,
+        )
+        .await
+    }
+
+    async fn write<F, T>(&self, ctx: &RequestContext, write_fn: F) -> KeyRotationResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> KeyRotationResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let audit = AuditContext::from(ctx);
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, KeyRotationError::persistence_failed)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid)
+                        .map_err(KeyRotationError::persistence_failed)?;
+                    set_audit_context(tx, &audit).map_err(KeyRotationError::persistence_failed)?;
+                    write_fn(tx)
+                })
+            },
+            KeyRotationError::persistence_failed,
+        )
+        .await
+    }
+
+    async fn find_one<F>(
+        &self,
+        ctx: &RequestContext,
+        filter_fn: F,
+    ) -> KeyRotationResult<Option<KeyRotation>>
+    where
+        F: FnOnce(
+                encryption_key_rotations::BoxedQuery<'static, Pg>,
+            ) -> encryption_key_rotations::BoxedQuery<'static, Pg>
+            + Send
+            + 'static,
+    {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = self
+            .read(ctx.tenant_id(), move |conn| {
+                filter_fn(
+                    encryption_key_rotations::table
+                        .filter(encryption_key_rotations::tenant_id.eq(tenant_uuid))
+                        .into_boxed::<Pg>(),
+                )
+                .select(KeyRotationRow::as_select())
+                .first::<KeyRotationRow>(conn)
+                .optional()
+                .map_err(KeyRotationError::persistence_failed)
+            })
+            .await?;
+        row.map(row_to_rotation).transpose()
+    }
+}
+
+#[async_trait]
+impl KeyRotationRepository for PostgresKeyRotationRepository {
+    async fn save(&self, ctx: &RequestContext, rotation: &KeyRotation) -> KeyRotationResult<()> {
+        let row = to_row(rotation, ctx.tenant_id().into_inner());
+        self.write(ctx, move |tx| {
+            diesel::insert_into(encryption_key_rotations::table)
+                .values(&row)
+                .on_conflict(encryption_key_rotations::id)
+                .do_update()
+                .set(&row)
+                .execute(tx)
+                .map(|_| ())
+                .map_err(KeyRotationError::persistence_failed)
+        })
+        .await
+    }
+
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        id: KeyRotationId,
+    ) -> KeyRotationResult<Option<KeyRotation>> {
+        self.find_one(ctx, move |query| {
+            query.filter(encryption_key_rotations::id.eq(id.into_inner()))
+        })
+        .await
+    }
+
+    async fn find_running(&self, ctx: &RequestContext) -> KeyRotationResult<Option<KeyRotation>> {
+        self.find_one(ctx, |query| {
+            query.filter(encryption_key_rotations::status.eq(KeyRotationStatus::Running.as_str()))
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl MessageResealer for PostgresKeyRotationRepository {
+    fn target_key_id(&self) -> &EncryptionKeyId {
+        &self.target_key
+    }
+
+    async fn count_remaining(&self, ctx: &RequestContext) -> KeyRotationResult<u64> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let target_key = self.target_key.clone();
+        self.read(ctx.tenant_id(), move |conn| {
+            reseal::count_remaining(conn, tenant_uuid, &target_key)
+        })
+        .await
+    }
+
+    async fn reseal_batch(&self, ctx: &RequestContext, limit: usize) -> KeyRotationResult<u64> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let target_key = self.target_key.clone();
+        let codec = self.codec.clone();
+        self.write(ctx, move |tx| {
+            let scope = RowScope {
+                tenant_id: tenant_uuid,
+                codec: &codec,
+            };
+            reseal::reseal_batch(tx, scope, &target_key, limit)
+        })
+        .await
+    }
+}
+
+fn to_row(rotation: &KeyRotation, tenant_id: uuid::Uuid) -> KeyRotationRow {
+    KeyRotationRow {
+        id: rotation.id.into_inner(),
+        tenant_id,
+        target_key_id: rotation.target_key.as_str().to_owned(),
+        status: rotation.status.as_str().to_owned(),
+        started_by: rotation.started_by.into_inner(),
+        started_at: rotation.started_at,
+        updated_at: rotation.updated_at,
+        finished_at: rotation.finished_at,
+        batches: to_column(rotation.batches),
+        resealed: to_column(rotation.resealed),
+        remaining: to_column(rotation.remaining),
+    }
+}
+
+fn row_to_rotation(row: KeyRotationRow) -> KeyRotationResult<KeyRotation> {
+    let invalid = |err: &dyn std::fmt::Display| {
+        KeyRotationError::invalid_persisted_data(format!("key rotation {}: {err}", row.id))
+    };
+    let count = |column: &str, value: i64| {
+        u64::try_from(value).map_err(|_| {
+            KeyRotationError::invalid_persisted_data(format!(
+                "key rotation {} has {value} {column}",
+                row.id
+            ))
+        })
+    };
+    Ok(KeyRotation {
+        id: KeyRotationId::from_uuid(row.id),
+        target_key: EncryptionKeyId::new(row.target_key_id).map_err(|err| invalid(&err))?,
+        status: KeyRotationStatus::try_from(row.status.as_str()).map_err(|err| invalid(&err))?,
+        started_by: UserId::from_uuid(row.started_by),
+        started_at: row.started_at,
+        updated_at: row.updated_at,
+        finished_at: row.finished_at,
+        batches: count("batches", row.batches)?,
+        resealed: count("resealed messages", row.resealed)?,
+        remaining: count("remaining messages", row.remaining)?,
+    })
+}
+
+fn to_column(count: u64) -> i64 {
+    i64::try_from(count).unwrap_or(i64::MAX)
+}
@@ -0,0 +1,188 @@
+//! Resealing statements for the `PostgreSQL` key rotation repository.
+//!
+//! A message, or a staged stream in `partial_messages`, remains to be
+//! resealed while either of its sealed columns lacks an envelope naming the
+//! target key, so unencrypted rows are sealed as well. The envelope's
+//! `$sealed` key is the one [`MessageCodec`] writes. Every statement binds
+//! the tenant as `$1` and the target key ID as `$2`.
+//!
+//! A batch locks the rows it reseals with `SKIP LOCKED`, so it neither
+//! waits for nor blocks out writers holding other rows; rows it skips are
+//! picked up by a later batch. Messages are resealed first, then staged
+//! streams fill what is left of the batch.
+//!
+//! [`MessageCodec`]: crate::postgres_support::MessageCodec
+
+use crate::key_rotation::ports::{KeyRotationError, KeyRotationResult};
+use crate::message::adapters::models::{MessageRow, PartialMessageRow};
+use crate::message::adapters::schema::{messages, partial_messages};
+use crate::message::domain::EncryptionKeyId;
+use crate::message::error::RepositoryError;
+use crate::postgres_support::RowScope;
+use diesel::pg::{Pg, PgConnection};
+use diesel::prelude::*;
+use diesel::query_builder::{BoxedSqlQuery, SqlQuery};
+use diesel::sql_types::{Int8, Text, Uuid as SqlUuid};
+use uuid::Uuid;
+
+/// The tenant's messages and staged streams not yet sealed under the
+/// target key.
+const COUNT_REMAINING_SQL: &str = "SELECT \
+     (SELECT count(*) FROM messages m \
+         WHERE m.tenant_id = $1 \
+         AND (m.content->'$sealed'->>'key_id' IS DISTINCT FROM $2 \
+             OR m.metadata->'$sealed'->>'key_id' IS DISTINCT FROM $2)) \
+     + (SELECT count(*) FROM partial_messages p \
+         WHERE p.tenant_id = $1 \
+         AND (p.content->'$sealed'->>'key_id' IS DISTINCT FROM $2 \
+             OR p.metadata->'$sealed'->>'key_id' IS DISTINCT FROM $2)) AS count";
+
+/// Locks up to `$3` remaining messages that no other writer holds.
+const LOCK_MESSAGES_SQL: &str = "SELECT m.id FROM messages m \
+     WHERE m.tenant_id = $1 \
+     AND (m.content->'$sealed'->>'key_id' IS DISTINCT FROM $2 \
+         OR m.metadata->'$sealed'->>'key_id' IS DISTINCT FROM $2) \
+     ORDER BY m.id LIMIT $3 FOR UPDATE SKIP LOCKED";
+
+/// Locks up to `$3` remaining staged streams that no other writer holds.
+const LOCK_PARTIALS_SQL: &str = "SELECT p.message_id AS id FROM partial_messages p \
+     WHERE p.tenant_id = $1 \
+     AND (p.content->'$sealed'->>'key_id' IS DISTINCT FROM $2 \
+         OR p.metadata->'$sealed'->>'key_id' IS DISTINCT FROM $2) \
+     ORDER BY p.message_id LIMIT $3 FOR UPDATE SKIP LOCKED";
+
+#[derive(QueryableByName)]
+struct IdRow {
+    #[diesel(sql_type = SqlUuid)]
+    id: Uuid,
+}
+
+#[derive(QueryableByName)]
+struct CountRow {
+    #[diesel(sql_type = Int8)]
+    count: i64,
+}
+
+/// Counts the tenant's messages and staged streams not yet sealed under
+/// `target_key`.
+pub(super) fn count_remaining(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    target_key: &EncryptionKeyId,
+) -> KeyRotationResult<u64> {
+    key_query(COUNT_REMAINING_SQL, tenant_id, target_key)
+        .get_result::<CountRow>(conn)
+        .map(|row| u64::try_from(row.count).unwrap_or_default())
+        .map_err(KeyRotationError::persistence_failed)
+}
+
+/// Reseals up to `limit` remaining rows inside the caller's transaction,
+/// returning how many were resealed.
+pub(super) fn reseal_batch(
+    conn: &mut PgConnection,
+    scope: RowScope<'_>,
+    target_key: &EncryptionKeyId,
+    limit: usize,
+) -> KeyRotationResult<u64> {
+    let messages = reseal_messages(conn, scope, target_key, limit)?;
+    let partials = reseal_partials(conn, scope, target_key, limit.saturating_sub(messages))?;
+    Ok(u64::try_from(messages.saturating_add(partials)).unwrap_or(u64::MAX))
+}
+
+fn reseal_messages(
+    conn: &mut PgConnection,
+    scope: RowScope<'_>,
+    target_key: &EncryptionKeyId,
+    limit: usize,
+) -> KeyRotationResult<usize> {
+    let ids = lock_remaining(conn, LOCK_MESSAGES_SQL, scope.tenant_id, target_key, limit)?;
+    let rows = messages::table
+        .filter(messages::tenant_id.eq(scope.tenant_id))
+        .filter(messages::id.eq_any(&ids))
+        .select(MessageRow::as_select())
+        .load::<MessageRow>(conn)
+        .map_err(KeyRotationError::persistence_failed)?;
+    for row in rows {
+        let resealed = scope.codec.reseal(row).map_err(from_repository)?;
+        diesel::update(
+            messages::table
+                .filter(messages::id.eq(resealed.id))
+                .filter(messages::tenant_id.eq(scope.tenant_id)),
+        )
+        .set((
+            messages::content.eq(resealed.content),
+            messages::metadata.eq(resealed.metadata),
+        ))
+        .execute(conn)
+        .map_err(KeyRotationError::persistence_failed)?;
+    }
+    Ok(ids.len())
+}
+
+fn reseal_partials(
+    conn: &mut PgConnection,
+    scope: RowScope<'_>,
+    target_key: &EncryptionKeyId,
+    limit: usize,
+) -> KeyRotationResult<usize> {
+    if limit == 0 {
+        return Ok(0);
+    }
+    let ids = lock_remaining(conn, LOCK_PARTIALS_SQL, scope.tenant_id, target_key, limit)?;
+    let rows = partial_messages::table
+        .filter(partial_messages::tenant_id.eq(scope.tenant_id))
+        .filter(partial_messages::message_id.eq_any(&ids))
+        .select(PartialMessageRow::as_select())
+        .load::<PartialMessageRow>(conn)
+        .map_err(KeyRotationError::persistence_failed)?;
+    for row in rows {
+        let resealed = scope.codec.reseal_partial(row).map_err(from_repository)?;
+        diesel::update(
+            partial_messages::table
+                .filter(partial_messages::message_id.eq(resealed.message_id))
+                .filter(partial_messages::tenant_id.eq(scope.tenant_id)),
+        )
+        .set((
+            partial_messages::content.eq(resealed.content),
+            partial_messages::metadata.eq(resealed.metadata),
+        ))
+        .execute(conn)
+        .map_err(KeyRotationError::persistence_failed)?;
+    }
+    Ok(ids.len())
+}
+
+fn lock_remaining(
+    conn: &mut PgConnection,
+    sql: &'static str,
+    tenant_id: Uuid,
+    target_key: &EncryptionKeyId,
+    limit: usize,
+) -> KeyRotationResult<Vec<Uuid>> {
+    Ok(key_query(sql, tenant_id, target_key)
+        .bind::<Int8, _>(i64::try_from(limit).unwrap_or(i64::MAX))
+        .load::<IdRow>(conn)
+        .map_err(KeyRotationError::persistence_failed)?
+        .into_iter()
+        .map(|row| row.id)
+        .collect())
+}
+
+/// Binds the tenant and target key shared by every resealing statement.
+fn key_query(
+    sql: &'static str,
+    tenant_id: Uuid,
+    target_key: &EncryptionKeyId,
+) -> BoxedSqlQuery<'static, Pg, SqlQuery> {
+    diesel::sql_query(sql)
+        .into_boxed()
+        .bind::<SqlUuid, _>(tenant_id)
+        .bind::<Text, _>(target_key.as_str().to_owned())
+}
+
+fn from_repository(err: RepositoryError) -> KeyRotationError {
+    match err {
+        RepositoryError::Cipher(error) => KeyRotationError::Cipher(error),
+        other => KeyRotationError::persistence_failed(other),
+    }
+}
@@ -0,0 +1,29 @@
+//! Diesel schema for key rotation persistence.
+
+diesel::table! {
+    /// Progress of encryption key rotations.
+    encryption_key_rotations (id) {
+        /// Rotation identifier.
+        id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// The key messages are resealed under.
+        target_key_id -> Text,
+        /// Rotation status: running, completed, or superseded.
+        status -> Text,
+        /// User who started the rotation.
+        started_by -> Uuid,
+        /// When the rotation started.
+        started_at -> Timestamptz,
+        /// When the rotation last made progress.
+        updated_at -> Timestamptz,
+        /// When the rotation completed or was superseded.
+        finished_at -> Nullable<Timestamptz>,
+        /// Batches run so far.
+        batches -> Int8,
+        /// Messages resealed so far.
+        resealed -> Int8,
+        /// Messages left to reseal when last counted.
+        remaining -> Int8,
+    }
+}
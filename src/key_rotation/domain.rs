@@ -0,0 +1,211 @@
+//! Domain types for encryption key rotation.
+
+use crate::context::{RequestContext, UserId};
+use crate::message::domain::EncryptionKeyId;
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Number of messages resealed per batch unless configured otherwise.
+pub const DEFAULT_ROTATION_BATCH_SIZE: usize = 500;
+
+/// Errors raised when rebuilding key rotation values.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum KeyRotationDomainError {
+    /// The rotation status is not recognised.
+    #[error("unknown key rotation status '{0}'")]
+    UnknownStatus(String),
+}
+
+/// Unique identifier for a key rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KeyRotationId(Uuid);
+
+impl KeyRotationId {
+    /// Creates a new random rotation identifier.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Creates an identifier from an existing UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Returns the wrapped UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for KeyRotationId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for KeyRotationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Where a key rotation stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyRotationStatus {
+    /// Messages remain to be resealed under the target key.
+    Running,
+    /// Every message was sealed under the target key.
+    Completed,
+    /// A rotation to another key replaced this one before it finished.
+    Superseded,
+}
+
+impl KeyRotationStatus {
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Superseded => "superseded",
+        }
+    }
+}
+
+impl fmt::Display for KeyRotationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<&str> for KeyRotationStatus {
+    type Error = KeyRotationDomainError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "running" => Ok(Self::Running),
+            "completed" => Ok(Self::Completed),
+            "superseded" => Ok(Self::Superseded),
+            _ => Err(KeyRotationDomainError::UnknownStatus(value.to_owned())),
+        }
+    }
+}
+
+/// The outcome of resealing one batch of messages and staged streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RotationBatch {
+    /// Messages resealed under the target key.
+    pub resealed: u64,
+    /// Messages still sealed under another key, or not sealed at all,
+    /// after the batch.
+    pub remaining: u64,
+}
+
+/// Progress of resealing a tenant's messages under a new key.
+///
+/// The counters are a record of the work done; which messages still need
+/// resealing is decided from the stored messages themselves, so a rotation
+/// interrupted at any point resumes where it stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRotation {
+    /// Rotation identifier.
+    pub id: KeyRotationId,
+    /// The key messages are resealed under.
+    pub target_key: EncryptionKeyId,
+    /// Where the rotation stands.
+    pub status: KeyRotationStatus,
+    /// The user who started the rotation.
+    pub started_by: UserId,
+    /// When the rotation started.
+    pub started_at: DateTime<Utc>,
+    /// When the rotation last made progress.
+    pub updated_at: DateTime<Utc>,
+    /// When the rotation completed or was superseded.
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Batches run so far.
+    pub batches: u64,
+    /// Messages resealed so far.
+    pub resealed: u64,
+    /// Messages left to reseal when last counted.
+    pub remaining: u64,
+}
+
+impl KeyRotation {
+    /// Starts a rotation to `target_key`, by the user in `ctx`, with
+    /// `remaining` messages to reseal. A rotation with nothing to reseal is
+    /// complete from the start.
+    #[must_use]
+    pub fn new(
+        ctx: &RequestContext,
+        target_key: EncryptionKeyId,
+        remaining: u64,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        let now = clock.utc();
+        let mut rotation = Self {
+            id: KeyRotationId::new(),
+            target_key,
+            status: KeyRotationStatus::Running,
+            started_by: ctx.user_id(),
+            started_at: now,
+            updated_at: now,
+            finished_at: None,
+            batches: 0,
+            resealed: 0,
+            remaining,
+        };
+        if remaining == 0 {
+            rotation.finish(KeyRotationStatus::Completed, now);
+        }
+        rotation
+    }
+
+    /// Returns whether messages may remain to be resealed.
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        matches!(self.status, KeyRotationStatus::Running)
+    }
+
+    /// Records a batch, completing the rotation when nothing remains.
+    pub fn record_batch(&mut self, batch: RotationBatch, clock: &(impl Clock + ?Sized)) {
+        let now = clock.utc();
+        self.batches = self.batches.saturating_add(1);
+        self.resealed = self.resealed.saturating_add(batch.resealed);
+        self.remaining = batch.remaining;
+        self.updated_at = now;
+        if batch.remaining == 0 {
+            self.finish(KeyRotationStatus::Completed, now);
+        }
+    }
+
+    /// Marks the rotation as replaced by a rotation to another key.
+    pub fn supersede(&mut self, clock: &(impl Clock + ?Sized)) {
+        self.finish(KeyRotationStatus::Superseded, clock.utc());
+    }
+
+    fn finish(&mut self, status: KeyRotationStatus, at: DateTime<Utc>) {
+        self.status = status;
+        self.updated_at = at;
+        self.finished_at = Some(at);
+    }
+}
+
+/// Live figures for monitoring key rotation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRotationMetrics {
+    /// The key new messages are sealed under.
+    pub target_key: EncryptionKeyId,
+    /// Messages not yet sealed under the target key, counted now.
+    pub remaining: u64,
+    /// The tenant's running rotation, if any.
+    pub rotation: Option<KeyRotation>,
+}
@@ -0,0 +1,23 @@
+//! Rotation of the key message content is encrypted under.
+//!
+//! Once a [`crate::message::ports::ContentCipher`] seals new messages under
+//! a new key, the messages sealed under the old one still need rewriting
+//! before the old key can be retired. The [`services::KeyRotationService`]
+//! reseals them in batches through a [`ports::MessageResealer`] while the
+//! repository stays online, and records the progress of each
+//! [`domain::KeyRotation`] through a [`ports::KeyRotationRepository`] so an
+//! interrupted rotation resumes where it stopped. The module follows
+//! hexagonal architecture:
+//!
+//! - Domain types in [`domain`]
+//! - Port contracts in [`ports`]
+//! - Adapter implementations in [`adapters`]
+//! - The rotation service in [`services`]
+
+pub mod adapters;
+pub mod domain;
+pub mod ports;
+pub mod services;
+
+#[cfg(test)]
+mod tests;
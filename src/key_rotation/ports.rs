@@ -0,0 +1,108 @@
+//! Port contracts for encryption key rotation.
+
+use crate::context::RequestContext;
+use crate::key_rotation::domain::{KeyRotation, KeyRotationId};
+use crate::maintenance::domain::MaintenanceMode;
+use crate::message::domain::EncryptionKeyId;
+use crate::message::ports::CipherError;
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type for key rotation operations.
+pub type KeyRotationResult<T> = Result<T, KeyRotationError>;
+
+/// Store that keeps the progress of key rotations.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Only the tenant's rotations are visible
+/// - A tenant has at most one running rotation
+#[async_trait]
+pub trait KeyRotationRepository: Send + Sync {
+    /// Inserts the rotation, or replaces the stored rotation with its ID.
+    async fn save(&self, ctx: &RequestContext, rotation: &KeyRotation) -> KeyRotationResult<()>;
+
+    /// Returns the tenant's rotation, if it exists.
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        id: KeyRotationId,
+    ) -> KeyRotationResult<Option<KeyRotation>>;
+
+    /// Returns the tenant's running rotation, if any.
+    async fn find_running(&self, ctx: &RequestContext) -> KeyRotationResult<Option<KeyRotation>>;
+}
+
+/// Rewrites stored messages under the active encryption key.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Only the tenant's messages and staged streams are touched
+/// - A message or staged stream counts as remaining until both its content
+///   and metadata are sealed under [`MessageResealer::target_key_id`], so
+///   unencrypted rows are sealed too
+/// - Each batch is committed on its own and skips rows other writers hold,
+///   so the repository stays online throughout
+#[async_trait]
+pub trait MessageResealer: Send + Sync {
+    /// Returns the key messages are resealed under.
+    fn target_key_id(&self) -> &EncryptionKeyId;
+
+    /// Counts the tenant's messages and staged streams not yet sealed under
+    /// the target key.
+    async fn count_remaining(&self, ctx: &RequestContext) -> KeyRotationResult<u64>;
+
+    /// Reseals up to `limit` of the tenant's remaining rows and returns how
+    /// many were resealed.
+    ///
+    /// Counting is left to [`MessageResealer::count_remaining`], so a batch
+    /// costs no more than the rows it reseals.
+    async fn reseal_batch(&self, ctx: &RequestContext, limit: usize) -> KeyRotationResult<u64>;
+}
+
+/// Errors returned by key rotation operations.
+#[derive(Debug, Clone, Error)]
+pub enum KeyRotationError {
+    /// The tenant has no such rotation.
+    #[error("key rotation {0} not found")]
+    NotFound(KeyRotationId),
+    /// The rotation targets another key than the resealer seals under.
+    #[error("key rotation targets key '{rotation}' but messages are sealed under '{cipher}'")]
+    TargetKeyMismatch {
+        /// The key the rotation targets.
+        rotation: EncryptionKeyId,
+        /// The key the resealer seals under.
+        cipher: EncryptionKeyId,
+    },
+    /// A message could not be opened or sealed.
+    #[error(transparent)]
+    Cipher(#[from] CipherError),
+    /// Persistence-layer failure.
+    #[error("key rotation persistence operation failed: {reason}")]
+    PersistenceFailed {
+        /// Human-readable reason from the failing dependency.
+        reason: String,
+    },
+    /// Persisted data failed validation.
+    #[error("invalid persisted key rotation data: {0}")]
+    InvalidPersistedData(String),
+    /// The cluster is in read-only maintenance mode.
+    #[error(transparent)]
+    MaintenanceMode(#[from] MaintenanceMode),
+}
+
+impl KeyRotationError {
+    /// Creates a persistence failure from an infrastructure error.
+    pub fn persistence_failed(err: impl std::error::Error) -> Self {
+        Self::PersistenceFailed {
+            reason: err.to_string(),
+        }
+    }
+
+    /// Creates an invalid persisted data error.
+    pub fn invalid_persisted_data(err: impl Into<String>) -> Self {
+        Self::InvalidPersistedData(err.into())
+    }
+}
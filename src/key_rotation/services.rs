@@ -0,0 +1,260 @@
+//! The key rotation service.
+//!
+//! [`KeyRotationService`] reseals a tenant's messages, and the streams
+//! staged for them, under the key the configured [`MessageResealer`] seals
+//! with. Each batch commits on its own and records its progress, so a
+//! rotation stopped part-way, by an error or a restart, resumes when it is
+//! started again. The remaining rows are counted when a rotation starts
+//! and when it looks finished; in between, each batch subtracts what it
+//! resealed. Messages keep being read and written throughout: the message
+//! repository's cipher must hold the retired keys as well as the new one
+//! until the rotation completes. With a maintenance gate attached, no
+//! rotation starts and no batch runs while the cluster is in maintenance.
+
+use super::domain::{
+    DEFAULT_ROTATION_BATCH_SIZE, KeyRotation, KeyRotationId, KeyRotationMetrics, RotationBatch,
+};
+use super::ports::{KeyRotationError, KeyRotationRepository, KeyRotationResult, MessageResealer};
+use crate::context::RequestContext;
+use crate::maintenance::services::MaintenanceGate;
+use mockable::Clock;
+use std::sync::Arc;
+
+/// Reseals stored messages under a new encryption key.
+///
+/// # Examples
+///
+/// ```no_run
+/// use corbusier::context::RequestContext;
+/// use corbusier::key_rotation::{
+///     adapters::PostgresKeyRotationRepository, services::KeyRotationService,
+/// };
+/// use corbusier::message::adapters::{encryption::AesGcmContentCipher, postgres::PgPool};
+/// use corbusier::message::domain::EncryptionKeyId;
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// # async fn example(ctx: &RequestContext, pool: PgPool) -> Result<(), Box<dyn std::error::Error>> {
+/// let cipher = AesGcmContentCipher::new(EncryptionKeyId::new("content-2026-11")?, [9; 32])
+///     .with_key(EncryptionKeyId::new("content-2026-10")?, [7; 32]);
+/// let repository = Arc::new(PostgresKeyRotationRepository::new(pool, Arc::new(cipher)));
+/// let service = KeyRotationService::new(repository.clone(), repository, Arc::new(DefaultClock));
+/// let rotation = service.start(ctx).await?;
+/// let rotation = service.run(ctx, rotation.id).await?;
+/// assert_eq!(rotation.remaining, 0);
+/// # Ok(())
+/// # }
+/// ```
+pub struct KeyRotationService {
+    rotations: Arc<dyn KeyRotationRepository>,
+    resealer: Arc<dyn MessageResealer>,
+    clock: Arc<dyn Clock + Send + Sync>,
+    batch_size: usize,
+    maintenance: Option<Arc<MaintenanceGate>>,
+}
+
+impl KeyRotationService {
+    /// Creates a service resealing [`DEFAULT_ROTATION_BATCH_SIZE`] messages
+    /// per batch.
+    #[must_use]
+    pub fn new(
+        rotations: Arc<dyn KeyRotationRepository>,
+        resealer: Arc<dyn MessageResealer>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            rotations,
+            resealer,
+            clock,
+            batch_size: DEFAULT_ROTATION_BATCH_SIZE,
+            maintenance: None,
+        }
+    }
+
+    /// Reseals up to `batch_size` messages per batch. Zero is raised to one.
+    #[must_use]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Refuses to start rotations or reseal batches while `gate` reports
+    /// maintenance.
+    #[must_use]
+    pub fn with_maintenance(mut self, gate: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(gate);
+        self
+    }
+
+    /// Starts a rotation to the resealer's key, or resumes the tenant's
+    /// running rotation to it.
+    ///
+    /// A running rotation to another key is superseded: the new rotation
+    /// reseals its messages too. A rotation with nothing to reseal is
+    /// complete when it is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KeyRotationError::MaintenanceMode`] during maintenance, or
+    /// the repository error when the rotation cannot be loaded, counted, or
+    /// stored.
+    pub async fn start(&self, ctx: &RequestContext) -> KeyRotationResult<KeyRotation> {
+        self.ensure_writable().await?;
+        let target_key = self.resealer.target_key_id();
+        if let Some(mut running) = self.rotations.find_running(ctx).await? {
+            if running.target_key == *target_key {
+                tracing::info!(
+                    rotation_id = %running.id,
+                    target_key = %target_key,
+                    "key rotation resumed"
+                );
+                return Ok(running);
+            }
+            running.supersede(&*self.clock);
+            self.rotations.save(ctx, &running).await?;
+            tracing::info!(
+                rotation_id = %running.id,
+                target_key = %running.target_key,
+                "key rotation superseded"
+            );
+        }
+        let remaining = self.resealer.count_remaining(ctx).await?;
+        let rotation = KeyRotation::new(ctx, target_key.clone(), remaining, &*self.clock);
+        self.rotations.save(ctx, &rotation).await?;
+        tracing::info!(
+            rotation_id = %rotation.id,
+            target_key = %rotation.target_key,
+            remaining,
+            "key rotation started"
+        );
+        Ok(rotation)
+    }
+
+    /// Reseals one batch of the rotation's messages and records it.
+    ///
+    /// A rotation that is no longer running is returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KeyRotationError::NotFound`] when the tenant has no such
+    /// rotation, [`KeyRotationError::TargetKeyMismatch`] when the resealer
+    /// seals under another key, [`KeyRotationError::MaintenanceMode`]
+    /// during maintenance, or the repository error when the batch fails. A
+    /// failed batch leaves the rotation where it stood.
+    pub async fn run_batch(
+        &self,
+        ctx: &RequestContext,
+        id: KeyRotationId,
+    ) -> KeyRotationResult<KeyRotation> {
+        self.ensure_writable().await?;
+        let mut rotation = self
+            .rotations
+            .find(ctx, id)
+            .await?
+            .ok_or(KeyRotationError::NotFound(id))?;
+        if !rotation.is_running() {
+            return Ok(rotation);
+        }
+        let target_key = self.resealer.target_key_id();
+        if rotation.target_key != *target_key {
+            return Err(KeyRotationError::TargetKeyMismatch {
+                rotation: rotation.target_key,
+                cipher: target_key.clone(),
+            });
+        }
+        let resealed = self.resealer.reseal_batch(ctx, self.batch_size).await?;
+        let batch = RotationBatch {
+            resealed,
+            remaining: self.remaining_after(ctx, &rotation, resealed).await?,
+        };
+        rotation.record_batch(batch, &*self.clock);
+        self.rotations.save(ctx, &rotation).await?;
+        tracing::debug!(
+            rotation_id = %rotation.id,
+            resealed = batch.resealed,
+            remaining = batch.remaining,
+            "key rotation batch resealed"
+        );
+        if !rotation.is_running() {
+            tracing::info!(
+                rotation_id = %rotation.id,
+                target_key = %rotation.target_key,
+                batches = rotation.batches,
+                resealed = rotation.resealed,
+                "key rotation completed"
+            );
+        }
+        Ok(rotation)
+    }
+
+    /// Runs batches until the rotation completes.
+    ///
+    /// Stops early when a batch reseals nothing, which happens while other
+    /// writers hold every remaining message; running the rotation again
+    /// later picks them up.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of [`KeyRotationService::run_batch`].
+    /// Batches completed before it stay resealed.
+    pub async fn run(
+        &self,
+        ctx: &RequestContext,
+        id: KeyRotationId,
+    ) -> KeyRotationResult<KeyRotation> {
+        let mut resealed = self
+            .rotations
+            .find(ctx, id)
+            .await?
+            .ok_or(KeyRotationError::NotFound(id))?
+            .resealed;
+        loop {
+            let rotation = self.run_batch(ctx, id).await?;
+            if !rotation.is_running() || rotation.resealed == resealed {
+                return Ok(rotation);
+            }
+            resealed = rotation.resealed;
+        }
+    }
+
+    /// Estimates what remains after a batch from the rotation's own count,
+    /// recounting only when the estimate says the rotation is done or the
+    /// batch made no progress. Messages written or deleted under other keys
+    /// meanwhile make the estimate drift, so it is never trusted to finish a
+    /// rotation alone.
+    async fn remaining_after(
+        &self,
+        ctx: &RequestContext,
+        rotation: &KeyRotation,
+        resealed: u64,
+    ) -> KeyRotationResult<u64> {
+        let estimate = rotation.remaining.saturating_sub(resealed);
+        if estimate > 0 && resealed > 0 {
+            return Ok(estimate);
+        }
+        self.resealer.count_remaining(ctx).await
+    }
+
+    async fn ensure_writable(&self) -> KeyRotationResult<()> {
+        if let Some(gate) = self.maintenance.as_deref() {
+            gate.ensure_writable().await?;
+        }
+        Ok(())
+    }
+
+    /// Returns the messages still to reseal, counted now, and the tenant's
+    /// running rotation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the figures cannot be loaded.
+    pub async fn metrics(&self, ctx: &RequestContext) -> KeyRotationResult<KeyRotationMetrics> {
+        let remaining = self.resealer.count_remaining(ctx).await?;
+        let rotation = self.rotations.find_running(ctx).await?;
+        Ok(KeyRotationMetrics {
+            target_key: self.resealer.target_key_id().clone(),
+            remaining,
+            rotation,
+        })
+    }
+}
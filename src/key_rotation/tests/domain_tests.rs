@@ -0,0 +1,83 @@
+//! Unit tests for key rotation domain values.
+
+use crate::key_rotation::domain::{KeyRotation, KeyRotationStatus, RotationBatch};
+use crate::message::domain::EncryptionKeyId;
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::rstest;
+
+fn rotation(remaining: u64) -> KeyRotation {
+    KeyRotation::new(
+        &test_request_ctx(),
+        EncryptionKeyId::new("content-2026-11").expect("valid key id"),
+        remaining,
+        &DefaultClock,
+    )
+}
+
+#[rstest]
+#[case(KeyRotationStatus::Running)]
+#[case(KeyRotationStatus::Completed)]
+#[case(KeyRotationStatus::Superseded)]
+fn statuses_round_trip_through_storage(#[case] status: KeyRotationStatus) {
+    assert_eq!(KeyRotationStatus::try_from(status.as_str()), Ok(status));
+}
+
+#[rstest]
+fn unknown_statuses_are_rejected() {
+    assert!(KeyRotationStatus::try_from("paused").is_err());
+}
+
+#[rstest]
+fn rotations_with_messages_to_reseal_start_running() {
+    let started = rotation(3);
+
+    assert!(started.is_running());
+    assert_eq!(started.remaining, 3);
+    assert_eq!(started.finished_at, None);
+}
+
+#[rstest]
+fn rotations_with_nothing_to_reseal_are_complete_at_once() {
+    let started = rotation(0);
+
+    assert_eq!(started.status, KeyRotationStatus::Completed);
+    assert_eq!(started.finished_at, Some(started.started_at));
+}
+
+#[rstest]
+fn batches_accumulate_until_nothing_remains() {
+    let mut rotation = rotation(5);
+
+    rotation.record_batch(
+        RotationBatch {
+            resealed: 3,
+            remaining: 2,
+        },
+        &DefaultClock,
+    );
+    assert!(rotation.is_running());
+    rotation.record_batch(
+        RotationBatch {
+            resealed: 2,
+            remaining: 0,
+        },
+        &DefaultClock,
+    );
+
+    assert_eq!(rotation.status, KeyRotationStatus::Completed);
+    assert_eq!(rotation.batches, 2);
+    assert_eq!(rotation.resealed, 5);
+    assert_eq!(rotation.remaining, 0);
+    assert!(rotation.finished_at.is_some());
+}
+
+#[rstest]
+fn superseded_rotations_stop_running() {
+    let mut rotation = rotation(5);
+
+    rotation.supersede(&DefaultClock);
+
+    assert_eq!(rotation.status, KeyRotationStatus::Superseded);
+    assert!(rotation.finished_at.is_some());
+}
@@ -0,0 +1,4 @@
+//! Unit tests for encryption key rotation.
+
+mod domain_tests;
+mod service_tests;
@@ -0,0 +1,301 @@
+//! Unit tests for the key rotation service.
+
+use crate::context::RequestContext;
+use crate::key_rotation::{
+    adapters::InMemoryKeyRotationRepository,
+    domain::{KeyRotationId, KeyRotationStatus},
+    ports::{KeyRotationError, KeyRotationRepository, KeyRotationResult, MessageResealer},
+    services::KeyRotationService,
+};
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::message::domain::EncryptionKeyId;
+use crate::message::ports::CipherError;
+use crate::operator::domain::OperatorReason;
+use crate::test_support::test_request_ctx;
+use async_trait::async_trait;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::{Arc, Mutex, PoisonError};
+
+fn key_id(value: &str) -> EncryptionKeyId {
+    EncryptionKeyId::new(value).expect("valid key id")
+}
+
+/// Resealer over a count of messages sealed under other keys.
+struct FakeResealer {
+    target_key: EncryptionKeyId,
+    state: Mutex<FakeState>,
+}
+
+#[derive(Default)]
+struct FakeState {
+    remaining: u64,
+    /// Messages other writers hold, which batches skip.
+    held: u64,
+    /// Batches that fail before the resealer recovers.
+    failures: u32,
+    /// Times the remaining messages were counted.
+    counts: u32,
+}
+
+impl FakeResealer {
+    fn new(target_key: &str, remaining: u64) -> Self {
+        Self {
+            target_key: key_id(target_key),
+            state: Mutex::new(FakeState {
+                remaining,
+                ..FakeState::default()
+            }),
+        }
+    }
+
+    fn update(&self, change: impl FnOnce(&mut FakeState)) {
+        change(&mut self.state.lock().unwrap_or_else(PoisonError::into_inner));
+    }
+
+    fn remaining(&self) -> u64 {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remaining
+    }
+
+    fn counts(&self) -> u32 {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .counts
+    }
+}
+
+#[async_trait]
+impl MessageResealer for FakeResealer {
+    fn target_key_id(&self) -> &EncryptionKeyId {
+        &self.target_key
+    }
+
+    async fn count_remaining(&self, _ctx: &RequestContext) -> KeyRotationResult<u64> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.counts = state.counts.saturating_add(1);
+        Ok(state.remaining)
+    }
+
+    async fn reseal_batch(&self, _ctx: &RequestContext, limit: usize) -> KeyRotationResult<u64> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.failures > 0 {
+            state.failures = state.failures.saturating_sub(1);
+            return Err(KeyRotationError::Cipher(CipherError::UnknownKey(key_id(
+                "retired",
+            ))));
+        }
+        let free = state.remaining.saturating_sub(state.held);
+        let resealed = free.min(u64::try_from(limit).unwrap_or(u64::MAX));
+        state.remaining = state.remaining.saturating_sub(resealed);
+        Ok(resealed)
+    }
+}
+
+struct Harness {
+    rotations: Arc<InMemoryKeyRotationRepository>,
+    resealer: Arc<FakeResealer>,
+    service: KeyRotationService,
+}
+
+fn harness_with(
+    target_key: &str,
+    remaining: u64,
+    rotations: InMemoryKeyRotationRepository,
+) -> Harness {
+    let rotations = Arc::new(rotations);
+    let resealer = Arc::new(FakeResealer::new(target_key, remaining));
+    let service =
+        KeyRotationService::new(rotations.clone(), resealer.clone(), Arc::new(DefaultClock))
+            .with_batch_size(2);
+    Harness {
+        rotations,
+        resealer,
+        service,
+    }
+}
+
+#[fixture]
+fn harness() -> Harness {
+    harness_with("content-2026-11", 5, InMemoryKeyRotationRepository::new())
+}
+
+#[rstest]
+#[tokio::test]
+async fn rotations_reseal_every_message_in_batches(harness: Harness) {
+    let ctx = test_request_ctx();
+    let started = harness.service.start(&ctx).await.expect("start");
+
+    let finished = harness.service.run(&ctx, started.id).await.expect("run");
+
+    assert_eq!(started.remaining, 5);
+    assert_eq!(finished.status, KeyRotationStatus::Completed);
+    assert_eq!(finished.batches, 3);
+    assert_eq!(finished.resealed, 5);
+    assert_eq!(harness.resealer.remaining(), 0);
+    assert_eq!(
+        harness.resealer.counts(),
+        2,
+        "counted at start and finish only"
+    );
+    let stored = harness
+        .rotations
+        .find(&ctx, started.id)
+        .await
+        .expect("find");
+    assert_eq!(stored, Some(finished));
+}
+
+#[rstest]
+#[tokio::test]
+async fn interrupted_rotations_resume_where_they_stopped(harness: Harness) {
+    let ctx = test_request_ctx();
+    let started = harness.service.start(&ctx).await.expect("start");
+    harness
+        .service
+        .run_batch(&ctx, started.id)
+        .await
+        .expect("batch");
+    harness.resealer.update(|state| state.failures = 1);
+
+    let failed = harness.service.run(&ctx, started.id).await;
+    let resumed = harness.service.start(&ctx).await.expect("resume");
+    let finished = harness.service.run(&ctx, resumed.id).await.expect("run");
+
+    assert!(matches!(failed, Err(KeyRotationError::Cipher(_))));
+    assert_eq!(resumed.id, started.id);
+    assert_eq!(resumed.resealed, 2);
+    assert_eq!(finished.status, KeyRotationStatus::Completed);
+    assert_eq!(finished.resealed, 5);
+}
+
+#[rstest]
+#[tokio::test]
+async fn runs_stop_when_other_writers_hold_the_remaining_messages(harness: Harness) {
+    let ctx = test_request_ctx();
+    harness.resealer.update(|state| state.held = 1);
+    let started = harness.service.start(&ctx).await.expect("start");
+
+    let stalled = harness.service.run(&ctx, started.id).await.expect("run");
+    harness.resealer.update(|state| state.held = 0);
+    let finished = harness.service.run(&ctx, started.id).await.expect("rerun");
+
+    assert!(stalled.is_running());
+    assert_eq!(stalled.remaining, 1);
+    assert_eq!(finished.status, KeyRotationStatus::Completed);
+}
+
+#[rstest]
+#[tokio::test]
+async fn starting_a_rotation_to_another_key_supersedes_the_running_one(harness: Harness) {
+    let ctx = test_request_ctx();
+    let first = harness.service.start(&ctx).await.expect("start");
+    let next = harness_with("content-2026-12", 5, (*harness.rotations).clone());
+
+    let second = next.service.start(&ctx).await.expect("start");
+    let stale = harness.service.run_batch(&ctx, second.id).await;
+
+    let superseded = next
+        .rotations
+        .find(&ctx, first.id)
+        .await
+        .expect("find")
+        .expect("stored rotation");
+    assert_eq!(superseded.status, KeyRotationStatus::Superseded);
+    assert_eq!(second.target_key, key_id("content-2026-12"));
+    assert!(matches!(
+        stale,
+        Err(KeyRotationError::TargetKeyMismatch { .. })
+    ));
+    let unchanged = harness
+        .service
+        .run_batch(&ctx, first.id)
+        .await
+        .expect("batch");
+    assert_eq!(unchanged, superseded);
+}
+
+#[rstest]
+#[tokio::test]
+async fn rotations_with_nothing_to_reseal_complete_at_once() {
+    let ctx = test_request_ctx();
+    let harness = harness_with("content-2026-11", 0, InMemoryKeyRotationRepository::new());
+
+    let rotation = harness.service.start(&ctx).await.expect("start");
+
+    assert_eq!(rotation.status, KeyRotationStatus::Completed);
+    assert_eq!(
+        harness.rotations.find_running(&ctx).await.expect("find"),
+        None
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn metrics_report_the_live_count_and_the_running_rotation(harness: Harness) {
+    let ctx = test_request_ctx();
+    let started = harness.service.start(&ctx).await.expect("start");
+    harness
+        .service
+        .run_batch(&ctx, started.id)
+        .await
+        .expect("batch");
+
+    let metrics = harness.service.metrics(&ctx).await.expect("metrics");
+
+    assert_eq!(metrics.target_key, key_id("content-2026-11"));
+    assert_eq!(metrics.remaining, 3);
+    assert_eq!(metrics.rotation.map(|rotation| rotation.resealed), Some(2));
+}
+
+#[rstest]
+#[tokio::test]
+async fn rotations_recount_before_finishing_on_a_drifted_estimate(harness: Harness) {
+    let ctx = test_request_ctx();
+    let started = harness.service.start(&ctx).await.expect("start");
+    harness.resealer.update(|state| state.remaining += 2);
+
+    let finished = harness.service.run(&ctx, started.id).await.expect("run");
+
+    assert_eq!(finished.status, KeyRotationStatus::Completed);
+    assert_eq!(finished.resealed, 7);
+    assert_eq!(harness.resealer.remaining(), 0);
+}
+
+#[rstest]
+#[tokio::test]
+async fn rotations_wait_out_maintenance(harness: Harness) {
+    let ctx = test_request_ctx();
+    let gate = Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ));
+    let service = harness.service.with_maintenance(Arc::clone(&gate));
+    let started = service.start(&ctx).await.expect("start");
+    let reason = OperatorReason::new("failover").expect("valid reason");
+    gate.enable(reason, None).await.expect("enable maintenance");
+
+    let batch = service.run_batch(&ctx, started.id).await;
+    let restart = service.start(&ctx).await;
+    gate.disable(None).await.expect("disable maintenance");
+    let finished = service.run(&ctx, started.id).await.expect("run");
+
+    assert!(matches!(batch, Err(KeyRotationError::MaintenanceMode(_))));
+    assert!(matches!(restart, Err(KeyRotationError::MaintenanceMode(_))));
+    assert_eq!(finished.status, KeyRotationStatus::Completed);
+    assert_eq!(harness.resealer.remaining(), 0);
+}
+
+#[rstest]
+#[tokio::test]
+async fn unknown_rotations_are_not_found(harness: Harness) {
+    let result = harness
+        .service
+        .run_batch(&test_request_ctx(), KeyRotationId::new())
+        .await;
+
+    assert!(matches!(result, Err(KeyRotationError::NotFound(_))));
+}
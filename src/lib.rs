@@ -15,25 +15,55 @@
 //! # Modules
 //!
 //! - [`context`]: Cross-cutting request context and identity types
+//! - [`doctor`]: Self-test of the configured adapters for readiness reports
+//! - [`dto`]: Versioned wire types for the public API surfaces
 //! - [`health`]: Health-check ports and the HTTP adapter used by the runtime
 //! - [`http_api`]: HTTP API surface for conversations, tasks, and tools
 //! - [`tenant`]: Tenant identity and lifecycle
 //! - [`agent_backend`]: Agent backend registration and discovery
+//! - [`audit`]: Queries over the audit trail of row changes and domain
+//!   events
+//! - [`erasure`]: Erasure of everything recorded about a data subject
+//! - [`events`]: Domain event publication to message brokers
 //! - [`hook_engine`]: Governance hook definition and execution
+//! - [`key_rotation`]: Resealing stored messages under a new encryption key
+//! - [`maintenance`]: Cluster-wide read-only maintenance mode
 //! - [`message`]: Canonical message format and validation
+//! - [`operator`]: Audit records for privileged operator actions
+//! - [`pagination`]: Shared pagination and sorting primitives for list ports
+//! - [`projection`]: Event-sourced read models built from domain events
+//! - [`replication`]: Asynchronous replication of conversations and tasks
+//!   between deployments
+//! - [`retention`]: Retention policies and purging of expired conversation
+//!   data
+//! - [`schema_check`]: Startup check that the database schema matches the
+//!   binary
 //! - [`task`]: Issue-to-task creation and lifecycle tracking
 //! - [`tool_registry`]: MCP server lifecycle management and tool discovery
 //! - `test_support` (feature-gated): Shared fixtures and fakes for tests
 
 pub mod context;
+pub mod doctor;
+pub mod dto;
 pub mod health;
 pub mod http_api;
 pub mod tenant;
 
 pub mod agent_backend;
+pub mod audit;
+pub mod erasure;
+pub mod events;
 pub mod hook_engine;
+pub mod key_rotation;
+pub mod maintenance;
 pub mod message;
+pub mod operator;
+pub mod pagination;
 pub(crate) mod postgres_support;
+pub mod projection;
+pub mod replication;
+pub mod retention;
+pub mod schema_check;
 pub mod task;
 #[cfg(feature = "test-support")]
 pub mod test_support;
@@ -1,21 +1,39 @@
 //! Corbusier application entry point.
 //!
-//! Starts an HTTP server exposing health-check and core API routes.
+//! Starts an HTTP server exposing health-check and core API routes. Run as
+//! `corbusier maintenance <status | enable <reason>... | disable>` to inspect
+//! or toggle read-only maintenance mode instead, or as
+//! `corbusier doctor <tenant-id>` to probe the database adapters and report
+//! readiness.
 
 use std::sync::Arc;
 
 use actix_web::{App, HttpServer, web};
 use corbusier::{
-    health::{HealthCheck, SimpleHealthCheck, actix_adapter::health_routes},
+    doctor::{cli::DoctorCommand, services::DoctorService},
+    health::{HealthCheck, ReadinessProbe, actix_adapter::health_routes},
     http_api::{
         ApiConfig, ApiState, BearerTokenAuthenticator, api_routes, auth::request_correlation_id,
         error::ApiError,
     },
+    maintenance::{
+        adapters::PostgresMaintenanceStateRepository, cli::MaintenanceCommand,
+        services::MaintenanceGate,
+    },
     message::{
-        adapters::postgres::{PgPool, PostgresConversationRepository, PostgresMessageRepository},
-        services::ConversationService,
+        adapters::{
+            postgres::{
+                PgPool, PostgresConversationRepository, PostgresMessageFeedbackRepository,
+                PostgresMessageProcessingRepository, PostgresMessageRepository,
+            },
+            token_counting::{BpeTokenCounter, HeuristicTokenCounter},
+        },
+        ports::TokenCounter,
+        services::{ConversationService, MessageFeedbackService, MessageProcessingService},
         validation::service::DefaultMessageValidator,
     },
+    operator::domain::OperatorReason,
+    schema_check::verify_schema_compatibility,
     task::{adapters::postgres::PostgresTaskRepository, services::TaskLifecycleService},
     tool_registry::{
         adapters::{
@@ -31,7 +49,7 @@ use diesel::{
     r2d2::{ConnectionManager, Pool},
 };
 use mockable::{Clock, DefaultClock};
-use tracing::info;
+use tracing::{info, warn};
 
 /// Default HTTP listen port.
 const DEFAULT_PORT: u16 = 8080;
@@ -39,7 +57,8 @@ const DEFAULT_PORT: u16 = 8080;
 /// Application entry point.
 ///
 /// Starts an Actix Web server on the port specified by the
-/// `CORBUSIER_PORT` environment variable (default 8080).
+/// `CORBUSIER_PORT` environment variable (default 8080), or runs the
+/// `maintenance` command when given.
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     tracing_subscriber::fmt()
@@ -49,13 +68,26 @@ async fn main() -> std::io::Result<()> {
         )
         .init();
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let [command, rest @ ..] = args.as_slice()
+        && command == "maintenance"
+    {
+        return run_maintenance_command(rest).await;
+    }
+    if let [command, rest @ ..] = args.as_slice()
+        && command == "doctor"
+    {
+        return run_doctor_command(rest).await;
+    }
+
     let port = std::env::var("CORBUSIER_PORT")
         .ok()
         .and_then(|v| v.parse::<u16>().ok())
         .unwrap_or(DEFAULT_PORT);
 
-    let health: Arc<dyn HealthCheck> = Arc::new(SimpleHealthCheck);
-    let api_state = web::Data::new(build_api_state()?);
+    let pool = build_pg_pool(&required_env("DATABASE_URL")?)?;
+    let health: Arc<dyn HealthCheck> = Arc::new(readiness_probe(&pool));
+    let api_state = web::Data::new(build_api_state(pool)?);
     let api_clock = api_state.clock.clone();
 
     info!(port, "Starting Corbusier");
@@ -85,33 +117,53 @@ async fn main() -> std::io::Result<()> {
     .await
 }
 
-fn build_api_state() -> std::io::Result<ApiState> {
-    let database_url = required_env("DATABASE_URL")?;
+fn build_api_state(pool: PgPool) -> std::io::Result<ApiState> {
     let jwt_secret = required_env("CORBUSIER_JWT_SECRET")?;
-    let pool = build_pg_pool(&database_url)?;
+    check_schema_compatibility(&pool)?;
     let clock = Arc::new(DefaultClock);
+    let maintenance = Arc::new(maintenance_gate(&pool));
 
-    let conversation_service = Arc::new(ConversationService::new(
-        Arc::new(PostgresConversationRepository::new(pool.clone())),
+    let conversation_service = Arc::new(
+        ConversationService::new(
+            Arc::new(PostgresConversationRepository::new(pool.clone())),
+            Arc::new(PostgresMessageRepository::new(pool.clone())),
+            Arc::new(DefaultMessageValidator::new()),
+            clock.clone(),
+        )
+        .with_token_counter(token_counter()?),
+    );
+
+    let task_service = Arc::new(TaskLifecycleService::new(
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+        clock.clone(),
+    ));
+
+    let feedback_service = Arc::new(MessageFeedbackService::new(
         Arc::new(PostgresMessageRepository::new(pool.clone())),
-        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(PostgresMessageFeedbackRepository::new(pool.clone())),
         clock.clone(),
     ));
 
-    let task_service = Arc::new(TaskLifecycleService::new(
-        Arc::new(PostgresTaskRepository::new(pool.clone())),
+    let processing_service = Arc::new(MessageProcessingService::new(
+        Arc::new(PostgresMessageRepository::new(pool.clone())),
+        Arc::new(PostgresMessageProcessingRepository::new(pool.clone())),
         clock.clone(),
     ));
 
     // TODO: Replace InMemoryMcpServerHost with a persistent adapter (e.g.,
     // PostgresMcpServerHost) for production horizontal scalability.
+    // FIXME: InMemoryMcpServerHost is not persisted across restarts.
+    // Replace with a persistent host implementation before production.
+    let mcp_host = Arc::new(InMemoryMcpServerHost::new());
+    let doctor = Arc::new(doctor_service(&pool).with_mcp_servers(
+        Arc::new(PostgresMcpServerRegistry::new(pool.clone())),
+        mcp_host.clone(),
+    ));
     let tool_service = Arc::new(ToolDiscoveryRoutingService::new(
         ServicePorts {
             catalog: Arc::new(PostgresToolCatalog::new(pool.clone())),
             registry: Arc::new(PostgresMcpServerRegistry::new(pool)),
-            // FIXME: InMemoryMcpServerHost is not persisted across restarts.
-            // Replace with a persistent host implementation before production.
-            host: Arc::new(InMemoryMcpServerHost::new()),
+            host: mcp_host,
             // FIXME: AllowAllPolicy bypasses governance enforcement.
             // Replace with a production governance policy before release.
             governance: Arc::new(AllowAllPolicy::new()),
@@ -131,7 +183,107 @@ fn build_api_state() -> std::io::Result<ApiState> {
             authenticator: BearerTokenAuthenticator::new(jwt_secret),
             clock: clock as Arc<dyn Clock + Send + Sync>,
         },
-    ))
+    )
+    .with_feedback(feedback_service)
+    .with_processing(processing_service)
+    .with_maintenance(maintenance)
+    .with_doctor(doctor))
+}
+
+/// Builds the readiness probe behind `/health/ready`: the conversation,
+/// message, task and tool catalog stores must each answer `SELECT 1`.
+fn readiness_probe(pool: &PgPool) -> ReadinessProbe {
+    ReadinessProbe::new(Arc::new(DefaultClock))
+        .with_check(Arc::new(PostgresConversationRepository::new(pool.clone())))
+        .with_check(Arc::new(PostgresMessageRepository::new(pool.clone())))
+        .with_check(Arc::new(PostgresTaskRepository::new(pool.clone())))
+        .with_check(Arc::new(PostgresToolCatalog::new(pool.clone())))
+}
+
+/// Builds a doctor probing the Postgres conversation and message stores.
+///
+/// MCP servers are hosted in-process, so only the API server, which hosts
+/// them, adds their probes.
+fn doctor_service(pool: &PgPool) -> DoctorService {
+    DoctorService::new(Arc::new(DefaultClock)).with_message_store(
+        Arc::new(PostgresConversationRepository::new(pool.clone())),
+        Arc::new(PostgresMessageRepository::new(pool.clone())),
+    )
+}
+
+/// Builds the counter that measures appended messages: the byte-pair
+/// encoding whose tiktoken rank file `CORBUSIER_TOKEN_RANKS` names, or the
+/// heuristic estimate when it is unset. The encoding is named after the
+/// file, so `cl100k_base.tiktoken` counts in `cl100k_base`.
+fn token_counter() -> std::io::Result<Arc<dyn TokenCounter>> {
+    let Ok(ranks_path) = std::env::var("CORBUSIER_TOKEN_RANKS") else {
+        return Ok(Arc::new(HeuristicTokenCounter::new()));
+    };
+    let path = std::path::Path::new(&ranks_path);
+    let encoding = path.file_stem().map_or_else(
+        || ranks_path.clone(),
+        |stem| stem.to_string_lossy().into_owned(),
+    );
+    let counter = BpeTokenCounter::from_tiktoken(encoding, &std::fs::read_to_string(path)?)
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+    info!(encoding = counter.encoding(), "loaded token ranks");
+    Ok(Arc::new(counter))
+}
+
+fn maintenance_gate(pool: &PgPool) -> MaintenanceGate {
+    MaintenanceGate::new(
+        Arc::new(PostgresMaintenanceStateRepository::new(pool.clone())),
+        Arc::new(DefaultClock),
+    )
+}
+
+/// Runs `corbusier maintenance ...` against the database named by
+/// `DATABASE_URL` and logs the resulting state.
+async fn run_maintenance_command(args: &[String]) -> std::io::Result<()> {
+    let command = MaintenanceCommand::parse(args).map_err(|error| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, error.to_string())
+    })?;
+    let pool = build_pg_pool(&required_env("DATABASE_URL")?)?;
+    let state = command
+        .run(&maintenance_gate(&pool))
+        .await
+        .map_err(std::io::Error::other)?;
+    info!(
+        enabled = state.enabled,
+        reason = state.reason.as_ref().map(OperatorReason::as_str),
+        changed_at = ?state.changed_at,
+        "maintenance mode"
+    );
+    Ok(())
+}
+
+/// Runs `corbusier doctor <tenant-id>` against the database named by
+/// `DATABASE_URL`, logs each probe, and fails when any probe failed.
+async fn run_doctor_command(args: &[String]) -> std::io::Result<()> {
+    let command = DoctorCommand::parse(args).map_err(|error| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, error.to_string())
+    })?;
+    let pool = build_pg_pool(&required_env("DATABASE_URL")?)?;
+    let report = command.run(&doctor_service(&pool)).await;
+    for probe in report.probes() {
+        info!(
+            component = probe.component.as_str(),
+            target = %probe.target,
+            status = ?probe.status,
+            elapsed_ms = probe.elapsed_ms,
+            detail = %probe.detail,
+            "doctor probe"
+        );
+    }
+    let failures = report.failures().count();
+    if failures > 0 {
+        return Err(std::io::Error::other(format!(
+            "not ready: {failures} of {} probes failed",
+            report.probes().len()
+        )));
+    }
+    info!(probes = report.probes().len(), "ready");
+    Ok(())
 }
 
 fn required_env(name: &str) -> std::io::Result<String> {
@@ -151,6 +303,19 @@ fn required_env(name: &str) -> std::io::Result<String> {
     Ok(trimmed.to_owned())
 }
 
+/// Fails startup when the database schema does not match this binary.
+///
+/// Set `CORBUSIER_SKIP_SCHEMA_CHECK=1` to start anyway, for example when
+/// migrations are applied without Diesel's migration history.
+fn check_schema_compatibility(pool: &PgPool) -> std::io::Result<()> {
+    if std::env::var("CORBUSIER_SKIP_SCHEMA_CHECK").is_ok_and(|value| value.trim() == "1") {
+        warn!("Skipping database schema compatibility check");
+        return Ok(());
+    }
+    verify_schema_compatibility(pool)
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error.to_string()))
+}
+
 fn build_pg_pool(database_url: &str) -> std::io::Result<PgPool> {
     let manager = ConnectionManager::<PgConnection>::new(database_url);
     Pool::builder()
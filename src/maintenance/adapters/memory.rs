@@ -0,0 +1,35 @@
+//! In-memory repository for the maintenance flag.
+
+use crate::maintenance::domain::MaintenanceState;
+use crate::maintenance::ports::{MaintenanceStateRepository, MaintenanceStateResult};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Thread-safe in-memory maintenance state repository.
+///
+/// Clones share the same flag, standing in for a shared database.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryMaintenanceStateRepository {
+    state: Arc<RwLock<MaintenanceState>>,
+}
+
+impl InMemoryMaintenanceStateRepository {
+    /// Creates a repository holding the inactive state.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl MaintenanceStateRepository for InMemoryMaintenanceStateRepository {
+    async fn load(&self) -> MaintenanceStateResult<MaintenanceState> {
+        Ok(self.state.read().await.clone())
+    }
+
+    async fn save(&self, state: &MaintenanceState) -> MaintenanceStateResult<()> {
+        *self.state.write().await = state.clone();
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+//! Adapter implementations for the maintenance state port.
+
+pub mod memory;
+pub mod postgres;
+
+pub use memory::InMemoryMaintenanceStateRepository;
+pub use postgres::PostgresMaintenanceStateRepository;
@@ -0,0 +1,7 @@
+//! `PostgreSQL` adapters for maintenance flag persistence.
+
+mod models;
+mod repository;
+mod schema;
+
+pub use repository::PostgresMaintenanceStateRepository;
@@ -0,0 +1,23 @@
+//! Diesel models for maintenance flag persistence.
+
+use super::schema::maintenance_mode;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+/// Row representation of the maintenance flag.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = maintenance_mode)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(treat_none_as_null = true)]
+pub struct MaintenanceModeRow {
+    /// Singleton key, always `true`.
+    pub singleton: bool,
+    /// Whether writes are refused.
+    pub enabled: bool,
+    /// Why maintenance was enabled.
+    pub reason: Option<String>,
+    /// User who last toggled the flag.
+    pub changed_by: Option<uuid::Uuid>,
+    /// When the flag was last toggled.
+    pub changed_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,103 @@
+//! `PostgreSQL` repository implementation for the maintenance flag.
+
+use super::models::MaintenanceModeRow;
+use super::schema::maintenance_mode;
+use crate::context::UserId;
+use crate::maintenance::domain::MaintenanceState;
+use crate::maintenance::ports::{
+    MaintenanceStateError, MaintenanceStateRepository, MaintenanceStateResult,
+};
+use crate::operator::domain::OperatorReason;
+use crate::postgres_support::{PgPool, get_conn_with, run_blocking_with};
+use async_trait::async_trait;
+use diesel::prelude::*;
+
+/// `PostgreSQL`-backed maintenance state repository.
+///
+/// The flag lives in a single row outside tenant scope, so every instance
+/// sharing the database reads the same state.
+#[derive(Debug, Clone)]
+pub struct PostgresMaintenanceStateRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresMaintenanceStateRepository,
+    "maintenance_state_repository"
+);
+
+impl PostgresMaintenanceStateRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MaintenanceStateRepository for PostgresMaintenanceStateRepository {
+    async fn load(&self) -> MaintenanceStateResult<MaintenanceState> {
+        let pool = self.pool.clone();
+        let row = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, MaintenanceStateError::persistence_failed)?;
+                maintenance_mode::table
+                    .find(true)
+                    .select(MaintenanceModeRow::as_select())
+                    .first::<MaintenanceModeRow>(&mut conn)
+                    .optional()
+                    .map_err(MaintenanceStateError::persistence_failed)
+            },
+            MaintenanceStateError::persistence_failed,
+        )
+        .await?;
+        row.map_or_else(|| Ok(MaintenanceState::default()), row_to_state)
+    }
+
+    async fn save(&self, state: &MaintenanceState) -> MaintenanceStateResult<()> {
+        let pool = self.pool.clone();
+        let row = to_row(state);
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, MaintenanceStateError::persistence_failed)?;
+                diesel::insert_into(maintenance_mode::table)
+                    .values(&row)
+                    .on_conflict(maintenance_mode::singleton)
+                    .do_update()
+                    .set(&row)
+                    .execute(&mut conn)
+                    .map(|_| ())
+                    .map_err(MaintenanceStateError::persistence_failed)
+            },
+            MaintenanceStateError::persistence_failed,
+        )
+        .await
+    }
+}
+
+fn to_row(state: &MaintenanceState) -> MaintenanceModeRow {
+    MaintenanceModeRow {
+        singleton: true,
+        enabled: state.enabled,
+        reason: state
+            .reason
+            .as_ref()
+            .map(|reason| reason.as_str().to_owned()),
+        changed_by: state.changed_by.map(UserId::into_inner),
+        changed_at: state.changed_at,
+    }
+}
+
+fn row_to_state(row: MaintenanceModeRow) -> MaintenanceStateResult<MaintenanceState> {
+    let reason = row
+        .reason
+        .map(OperatorReason::new)
+        .transpose()
+        .map_err(|err| MaintenanceStateError::invalid_persisted_data(err.to_string()))?;
+    Ok(MaintenanceState {
+        enabled: row.enabled,
+        reason,
+        changed_by: row.changed_by.map(UserId::from_uuid),
+        changed_at: row.changed_at,
+    })
+}
@@ -0,0 +1,17 @@
+//! Diesel schema for maintenance flag persistence.
+
+diesel::table! {
+    /// The single cluster-wide maintenance flag row.
+    maintenance_mode (singleton) {
+        /// Always `true`; keeps the table to one row.
+        singleton -> Bool,
+        /// Whether writes are refused.
+        enabled -> Bool,
+        /// Why maintenance was enabled.
+        reason -> Nullable<Text>,
+        /// User who last toggled the flag.
+        changed_by -> Nullable<Uuid>,
+        /// When the flag was last toggled.
+        changed_at -> Nullable<Timestamptz>,
+    }
+}
@@ -0,0 +1,105 @@
+//! Parsing and execution of the `corbusier maintenance` command.
+//!
+//! The command toggles the persisted flag directly, so it works while the
+//! HTTP API is down or already refusing writes:
+//!
+//! ```text
+//! corbusier maintenance status
+//! corbusier maintenance enable <reason>...
+//! corbusier maintenance disable
+//! ```
+
+use super::domain::MaintenanceState;
+use super::ports::MaintenanceStateResult;
+use super::services::MaintenanceGate;
+use crate::operator::domain::{OperatorReason, OperatorReasonError};
+use thiserror::Error;
+
+/// Usage text for the `maintenance` command.
+pub const MAINTENANCE_USAGE: &str =
+    "usage: corbusier maintenance <status | enable <reason>... | disable>";
+
+/// A parsed `maintenance` subcommand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaintenanceCommand {
+    /// Reports the current state.
+    Status,
+    /// Enables maintenance for a reason.
+    Enable(OperatorReason),
+    /// Disables maintenance.
+    Disable,
+}
+
+/// Errors returned while parsing a `maintenance` command line.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MaintenanceCommandError {
+    /// The arguments do not name a known subcommand.
+    #[error("{MAINTENANCE_USAGE}")]
+    Usage,
+    /// `enable` was given no usable reason.
+    #[error("maintenance needs a reason: {0}")]
+    InvalidReason(#[from] OperatorReasonError),
+}
+
+impl MaintenanceCommand {
+    /// Parses the arguments following `maintenance`.
+    ///
+    /// The words after `enable` are joined with spaces to form the reason.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaintenanceCommandError::Usage`] for an unknown or
+    /// malformed subcommand, or [`MaintenanceCommandError::InvalidReason`]
+    /// when `enable` has no reason.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use corbusier::maintenance::cli::MaintenanceCommand;
+    ///
+    /// let command = MaintenanceCommand::parse(["enable", "schema", "migration"])
+    ///     .expect("valid command");
+    /// assert!(matches!(command, MaintenanceCommand::Enable(reason)
+    ///     if reason.as_str() == "schema migration"));
+    /// ```
+    pub fn parse<I, S>(args: I) -> Result<Self, MaintenanceCommandError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut words = args.into_iter();
+        let Some(subcommand) = words.next() else {
+            return Err(MaintenanceCommandError::Usage);
+        };
+        let rest: Vec<S> = words.collect();
+        match (subcommand.as_ref(), rest.is_empty()) {
+            ("status", true) => Ok(Self::Status),
+            ("disable", true) => Ok(Self::Disable),
+            ("enable", _) => {
+                let reason = rest
+                    .iter()
+                    .map(AsRef::<str>::as_ref)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                Ok(Self::Enable(OperatorReason::new(reason)?))
+            }
+            _ => Err(MaintenanceCommandError::Usage),
+        }
+    }
+
+    /// Runs the command against `gate` and returns the resulting state.
+    ///
+    /// Changes made from the command line record no acting user.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the state cannot be loaded or
+    /// saved.
+    pub async fn run(self, gate: &MaintenanceGate) -> MaintenanceStateResult<MaintenanceState> {
+        match self {
+            Self::Status => gate.state().await,
+            Self::Enable(reason) => gate.enable(reason, None).await,
+            Self::Disable => gate.disable(None).await,
+        }
+    }
+}
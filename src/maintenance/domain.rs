@@ -0,0 +1,94 @@
+//! Domain types for read-only maintenance mode.
+
+use crate::context::UserId;
+use crate::operator::domain::OperatorReason;
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The persisted maintenance flag.
+///
+/// The default state is inactive and has never been changed.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::maintenance::domain::MaintenanceState;
+/// use corbusier::operator::domain::OperatorReason;
+/// use mockable::DefaultClock;
+///
+/// assert!(MaintenanceState::default().ensure_writable().is_ok());
+///
+/// let reason = OperatorReason::new("schema migration").expect("valid reason");
+/// let state = MaintenanceState::enabled(reason, None, &DefaultClock);
+/// assert!(state.ensure_writable().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaintenanceState {
+    /// Whether writes are currently refused.
+    pub enabled: bool,
+    /// Why maintenance was enabled; absent while inactive.
+    pub reason: Option<OperatorReason>,
+    /// User who last toggled the flag, when known.
+    pub changed_by: Option<UserId>,
+    /// When the flag was last toggled, if ever.
+    pub changed_at: Option<DateTime<Utc>>,
+}
+
+impl MaintenanceState {
+    /// Creates an active maintenance state entered now for `reason`.
+    #[must_use]
+    pub fn enabled(
+        reason: OperatorReason,
+        changed_by: Option<UserId>,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        Self {
+            enabled: true,
+            reason: Some(reason),
+            changed_by,
+            changed_at: Some(clock.utc()),
+        }
+    }
+
+    /// Creates an inactive maintenance state left now.
+    #[must_use]
+    pub fn disabled(changed_by: Option<UserId>, clock: &(impl Clock + ?Sized)) -> Self {
+        Self {
+            enabled: false,
+            reason: None,
+            changed_by,
+            changed_at: Some(clock.utc()),
+        }
+    }
+
+    /// Checks that writes are allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaintenanceMode`] while maintenance is enabled.
+    pub fn ensure_writable(&self) -> Result<(), MaintenanceMode> {
+        if !self.enabled {
+            return Ok(());
+        }
+        Err(MaintenanceMode {
+            reason: self.reason.clone(),
+            since: self.changed_at,
+        })
+    }
+}
+
+/// Error returned by write paths while the cluster is in maintenance.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("the cluster is in read-only maintenance mode{}", describe_reason(.reason.as_ref()))]
+pub struct MaintenanceMode {
+    /// Why maintenance was enabled.
+    pub reason: Option<OperatorReason>,
+    /// When maintenance was enabled.
+    pub since: Option<DateTime<Utc>>,
+}
+
+fn describe_reason(reason: Option<&OperatorReason>) -> String {
+    reason.map_or_else(String::new, |text| format!(": {text}"))
+}
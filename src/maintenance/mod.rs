@@ -0,0 +1,25 @@
+//! Cluster-wide read-only maintenance mode.
+//!
+//! Operators put the cluster into maintenance before schema migrations and
+//! failovers. While it is active, every write path answers with the typed
+//! [`domain::MaintenanceMode`] error, reads continue, scheduled workers skip
+//! their runs, and the turn orchestrator refuses new turns. The flag is
+//! persisted through a [`ports::MaintenanceStateRepository`], so all
+//! instances sharing a database observe it, and is toggled through the admin
+//! HTTP API or the `corbusier maintenance` command. The module follows
+//! hexagonal architecture:
+//!
+//! - Domain types in [`domain`]
+//! - Port contracts in [`ports`]
+//! - Adapter implementations in [`adapters`]
+//! - The cached gate consulted by write paths in [`services`]
+//! - Command-line parsing in [`cli`]
+
+pub mod adapters;
+pub mod cli;
+pub mod domain;
+pub mod ports;
+pub mod services;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,50 @@
+//! Port contract for persisting the maintenance flag.
+
+use crate::maintenance::domain::MaintenanceState;
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type for maintenance state repository operations.
+pub type MaintenanceStateResult<T> = Result<T, MaintenanceStateError>;
+
+/// Cluster-wide store of the maintenance flag.
+///
+/// The flag is not tenant-scoped, so the operations take no request
+/// context.
+#[async_trait]
+pub trait MaintenanceStateRepository: Send + Sync {
+    /// Returns the current state, or the default inactive state when none
+    /// was ever stored.
+    async fn load(&self) -> MaintenanceStateResult<MaintenanceState>;
+
+    /// Replaces the stored state.
+    async fn save(&self, state: &MaintenanceState) -> MaintenanceStateResult<()>;
+}
+
+/// Errors returned by maintenance state repository implementations.
+#[derive(Debug, Clone, Error)]
+pub enum MaintenanceStateError {
+    /// Persistence-layer failure.
+    #[error("maintenance state persistence operation failed: {reason}")]
+    PersistenceFailed {
+        /// Human-readable reason from the failing dependency.
+        reason: String,
+    },
+    /// Persisted data failed validation.
+    #[error("invalid persisted maintenance state: {0}")]
+    InvalidPersistedData(String),
+}
+
+impl MaintenanceStateError {
+    /// Creates a persistence failure from an infrastructure error.
+    pub fn persistence_failed(err: impl std::error::Error) -> Self {
+        Self::PersistenceFailed {
+            reason: err.to_string(),
+        }
+    }
+
+    /// Creates an invalid persisted data error.
+    pub fn invalid_persisted_data(err: impl Into<String>) -> Self {
+        Self::InvalidPersistedData(err.into())
+    }
+}
@@ -0,0 +1,184 @@
+//! The maintenance gate consulted by write paths and workers.
+//!
+//! [`MaintenanceGate`] caches the persisted flag for a short refresh
+//! interval, so checking it on every write costs a database read at most
+//! once per interval per instance. Toggling through the gate takes effect
+//! on this instance immediately and on other instances within one refresh
+//! interval.
+
+use super::domain::{MaintenanceMode, MaintenanceState};
+use super::ports::{MaintenanceStateRepository, MaintenanceStateResult};
+use crate::context::UserId;
+use crate::operator::domain::OperatorReason;
+use chrono::{DateTime, TimeDelta, Utc};
+use mockable::Clock;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Default time a loaded maintenance state is trusted before reloading.
+pub const DEFAULT_MAINTENANCE_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+struct CachedState {
+    state: MaintenanceState,
+    loaded_at: DateTime<Utc>,
+}
+
+/// Cached view of the cluster-wide maintenance flag.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::maintenance::{
+///     adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate,
+/// };
+/// use corbusier::operator::domain::OperatorReason;
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let gate = MaintenanceGate::new(
+///     Arc::new(InMemoryMaintenanceStateRepository::new()),
+///     Arc::new(DefaultClock),
+/// );
+/// gate.enable(OperatorReason::new("schema migration")?, None).await?;
+/// assert!(gate.ensure_writable().await.is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub struct MaintenanceGate {
+    repository: Arc<dyn MaintenanceStateRepository>,
+    clock: Arc<dyn Clock + Send + Sync>,
+    refresh_interval: TimeDelta,
+    cached: RwLock<Option<CachedState>>,
+}
+
+impl MaintenanceGate {
+    /// Creates a gate over `repository` that reloads the flag every
+    /// [`DEFAULT_MAINTENANCE_REFRESH_INTERVAL`].
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn MaintenanceStateRepository>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            repository,
+            clock,
+            refresh_interval: to_time_delta(DEFAULT_MAINTENANCE_REFRESH_INTERVAL),
+            cached: RwLock::new(None),
+        }
+    }
+
+    /// Sets how long a loaded state is trusted before reloading.
+    ///
+    /// A zero interval reloads the flag on every check.
+    #[must_use]
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = to_time_delta(interval);
+        self
+    }
+
+    /// Loads the current state from the repository, bypassing the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the state cannot be loaded.
+    pub async fn state(&self) -> MaintenanceStateResult<MaintenanceState> {
+        let state = self.repository.load().await?;
+        self.remember(state.clone()).await;
+        Ok(state)
+    }
+
+    /// Checks that writes are allowed.
+    ///
+    /// When the flag cannot be reloaded, the last known state is used, and
+    /// writes are allowed if the flag was never loaded: an unreachable
+    /// database rejects the writes on its own.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaintenanceMode`] while maintenance is enabled.
+    pub async fn ensure_writable(&self) -> Result<(), MaintenanceMode> {
+        self.current()
+            .await
+            .map_or(Ok(()), |state| state.ensure_writable())
+    }
+
+    /// Returns whether maintenance is enabled, as
+    /// [`MaintenanceGate::ensure_writable`] sees it.
+    pub async fn is_active(&self) -> bool {
+        self.ensure_writable().await.is_err()
+    }
+
+    /// Enables maintenance for `reason`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the state cannot be saved.
+    pub async fn enable(
+        &self,
+        reason: OperatorReason,
+        changed_by: Option<UserId>,
+    ) -> MaintenanceStateResult<MaintenanceState> {
+        let state = MaintenanceState::enabled(reason, changed_by, &*self.clock);
+        self.save(state).await
+    }
+
+    /// Disables maintenance.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the state cannot be saved.
+    pub async fn disable(
+        &self,
+        changed_by: Option<UserId>,
+    ) -> MaintenanceStateResult<MaintenanceState> {
+        let state = MaintenanceState::disabled(changed_by, &*self.clock);
+        self.save(state).await
+    }
+
+    async fn save(&self, state: MaintenanceState) -> MaintenanceStateResult<MaintenanceState> {
+        self.repository.save(&state).await?;
+        tracing::info!(
+            enabled = state.enabled,
+            reason = state.reason.as_ref().map(OperatorReason::as_str),
+            changed_by = ?state.changed_by,
+            "maintenance mode changed"
+        );
+        self.remember(state.clone()).await;
+        Ok(state)
+    }
+
+    async fn current(&self) -> Option<MaintenanceState> {
+        let now = self.clock.utc();
+        let cached = self.cached.read().await.clone();
+        if let Some(fresh) = cached
+            .as_ref()
+            .filter(|entry| now.signed_duration_since(entry.loaded_at) < self.refresh_interval)
+        {
+            return Some(fresh.state.clone());
+        }
+        match self.repository.load().await {
+            Ok(state) => {
+                self.remember(state.clone()).await;
+                Some(state)
+            }
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to reload maintenance mode; using last known state");
+                cached.map(|entry| entry.state)
+            }
+        }
+    }
+
+    async fn remember(&self, state: MaintenanceState) {
+        *self.cached.write().await = Some(CachedState {
+            state,
+            loaded_at: self.clock.utc(),
+        });
+    }
+}
+
+fn to_time_delta(interval: Duration) -> TimeDelta {
+    TimeDelta::from_std(interval).unwrap_or(TimeDelta::MAX)
+}
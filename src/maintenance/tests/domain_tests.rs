@@ -0,0 +1,75 @@
+//! Unit tests for maintenance domain types and command parsing.
+
+use crate::context::UserId;
+use crate::maintenance::{
+    cli::{MaintenanceCommand, MaintenanceCommandError},
+    domain::{MaintenanceMode, MaintenanceState},
+};
+use crate::operator::domain::{OperatorReason, OperatorReasonError};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+fn reason(text: &str) -> OperatorReason {
+    OperatorReason::new(text).expect("valid reason")
+}
+
+#[rstest]
+fn the_default_state_allows_writes() {
+    let state = MaintenanceState::default();
+
+    assert!(!state.enabled);
+    assert_eq!(state.ensure_writable(), Ok(()));
+}
+
+#[rstest]
+fn enabled_maintenance_refuses_writes_with_its_reason() {
+    let actor = UserId::new();
+    let state = MaintenanceState::enabled(reason("schema migration"), Some(actor), &DefaultClock);
+
+    let error = state.ensure_writable().expect_err("writes are refused");
+
+    assert_eq!(
+        error,
+        MaintenanceMode {
+            reason: Some(reason("schema migration")),
+            since: state.changed_at,
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "the cluster is in read-only maintenance mode: schema migration"
+    );
+    assert_eq!(state.changed_by, Some(actor));
+}
+
+#[rstest]
+fn disabling_maintenance_clears_the_reason() {
+    let state = MaintenanceState::disabled(None, &DefaultClock);
+
+    assert_eq!(state.ensure_writable(), Ok(()));
+    assert_eq!(state.reason, None);
+    assert!(state.changed_at.is_some());
+}
+
+#[rstest]
+#[case(&["status"], MaintenanceCommand::Status)]
+#[case(&["disable"], MaintenanceCommand::Disable)]
+#[case(
+    &["enable", "failover", "to", "eu-west"],
+    MaintenanceCommand::Enable(reason("failover to eu-west"))
+)]
+fn commands_parse(#[case] args: &[&str], #[case] expected: MaintenanceCommand) {
+    assert_eq!(MaintenanceCommand::parse(args), Ok(expected));
+}
+
+#[rstest]
+#[case(&[], MaintenanceCommandError::Usage)]
+#[case(&["status", "now"], MaintenanceCommandError::Usage)]
+#[case(&["pause"], MaintenanceCommandError::Usage)]
+#[case(&["enable"], MaintenanceCommandError::InvalidReason(OperatorReasonError::Empty))]
+fn malformed_commands_are_rejected(
+    #[case] args: &[&str],
+    #[case] expected: MaintenanceCommandError,
+) {
+    assert_eq!(MaintenanceCommand::parse(args), Err(expected));
+}
@@ -0,0 +1,4 @@
+//! Unit tests for read-only maintenance mode.
+
+mod domain_tests;
+mod service_tests;
@@ -0,0 +1,150 @@
+//! Unit tests for the cached maintenance gate.
+
+use crate::context::UserId;
+use crate::maintenance::{
+    adapters::InMemoryMaintenanceStateRepository,
+    domain::MaintenanceState,
+    ports::{MaintenanceStateError, MaintenanceStateRepository, MaintenanceStateResult},
+    services::{DEFAULT_MAINTENANCE_REFRESH_INTERVAL, MaintenanceGate},
+};
+use crate::operator::domain::OperatorReason;
+use async_trait::async_trait;
+use chrono::{DateTime, Local, TimeDelta, Utc};
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Clock that only moves when told to.
+struct ManualClock(Mutex<DateTime<Utc>>);
+
+impl ManualClock {
+    fn advance(&self, by: TimeDelta) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn local(&self) -> DateTime<Local> {
+        self.utc().with_timezone(&Local)
+    }
+
+    fn utc(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Repository whose loads fail on demand.
+#[derive(Default)]
+struct FlakyRepository {
+    inner: InMemoryMaintenanceStateRepository,
+    failing: AtomicBool,
+}
+
+#[async_trait]
+impl MaintenanceStateRepository for FlakyRepository {
+    async fn load(&self) -> MaintenanceStateResult<MaintenanceState> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(MaintenanceStateError::PersistenceFailed {
+                reason: "database unavailable".to_owned(),
+            });
+        }
+        self.inner.load().await
+    }
+
+    async fn save(&self, state: &MaintenanceState) -> MaintenanceStateResult<()> {
+        self.inner.save(state).await
+    }
+}
+
+#[fixture]
+fn clock() -> Arc<ManualClock> {
+    Arc::new(ManualClock(Mutex::new(DefaultClock.utc())))
+}
+
+fn reason() -> OperatorReason {
+    OperatorReason::new("schema migration").expect("valid reason")
+}
+
+fn refresh_interval() -> TimeDelta {
+    TimeDelta::from_std(DEFAULT_MAINTENANCE_REFRESH_INTERVAL).expect("small interval")
+}
+
+#[rstest]
+#[tokio::test]
+async fn enabling_refuses_writes_immediately(clock: Arc<ManualClock>) {
+    let repository = Arc::new(InMemoryMaintenanceStateRepository::new());
+    let gate = MaintenanceGate::new(repository.clone(), clock);
+    let actor = UserId::new();
+    assert!(gate.ensure_writable().await.is_ok());
+
+    let state = gate.enable(reason(), Some(actor)).await.expect("enable");
+
+    assert!(gate.is_active().await);
+    assert_eq!(repository.load().await.expect("load"), state);
+    assert_eq!(state.changed_by, Some(actor));
+}
+
+#[rstest]
+#[tokio::test]
+async fn disabling_allows_writes_again(clock: Arc<ManualClock>) {
+    let gate = MaintenanceGate::new(Arc::new(InMemoryMaintenanceStateRepository::new()), clock);
+    gate.enable(reason(), None).await.expect("enable");
+
+    gate.disable(None).await.expect("disable");
+
+    assert!(gate.ensure_writable().await.is_ok());
+}
+
+#[rstest]
+#[tokio::test]
+async fn other_instances_observe_the_flag_after_the_refresh_interval(clock: Arc<ManualClock>) {
+    let repository = Arc::new(InMemoryMaintenanceStateRepository::new());
+    let operator = MaintenanceGate::new(repository.clone(), clock.clone());
+    let worker = MaintenanceGate::new(repository, clock.clone());
+    assert!(!worker.is_active().await);
+
+    operator.enable(reason(), None).await.expect("enable");
+
+    assert!(!worker.is_active().await, "cached state is still fresh");
+    clock.advance(refresh_interval());
+    assert!(worker.is_active().await);
+}
+
+#[rstest]
+#[tokio::test]
+async fn state_bypasses_the_cache(clock: Arc<ManualClock>) {
+    let repository = Arc::new(InMemoryMaintenanceStateRepository::new());
+    let operator = MaintenanceGate::new(repository.clone(), clock.clone());
+    let observer = MaintenanceGate::new(repository, clock);
+    assert!(!observer.is_active().await);
+
+    operator.enable(reason(), None).await.expect("enable");
+    let state = observer.state().await.expect("state");
+
+    assert!(state.enabled);
+    assert!(observer.is_active().await);
+}
+
+#[rstest]
+#[tokio::test]
+async fn failed_reloads_keep_the_last_known_state(clock: Arc<ManualClock>) {
+    let repository = Arc::new(FlakyRepository::default());
+    let gate = MaintenanceGate::new(repository.clone(), clock.clone());
+    gate.enable(reason(), None).await.expect("enable");
+
+    repository.failing.store(true, Ordering::SeqCst);
+    clock.advance(refresh_interval());
+
+    assert!(gate.is_active().await);
+}
+
+#[rstest]
+#[tokio::test]
+async fn writes_are_allowed_when_the_flag_was_never_loaded(clock: Arc<ManualClock>) {
+    let repository = Arc::new(FlakyRepository::default());
+    repository.failing.store(true, Ordering::SeqCst);
+    let gate = MaintenanceGate::new(repository, clock);
+
+    assert!(gate.ensure_writable().await.is_ok());
+}
@@ -84,6 +84,15 @@ impl AuditContext {
         self
     }
 
+    /// Derives the audit context from the ambient request context.
+    ///
+    /// Returns an empty context when the current task does not run inside
+    /// [`RequestContext::scope`].
+    #[must_use]
+    pub fn from_current() -> Self {
+        RequestContext::current().map_or_else(Self::empty, |ctx| Self::from(&ctx))
+    }
+
     /// Returns `true` if all context fields are `None`.
     #[must_use]
     pub const fn is_empty(&self) -> bool {
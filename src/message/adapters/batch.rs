@@ -0,0 +1,61 @@
+//! Duplicate detection shared by batch message inserts.
+//!
+//! Adapters look up which IDs and sequence numbers of a batch are already
+//! stored, then [`find_batch_conflicts`] reports every conflicting message,
+//! including messages that clash with an earlier message in the same batch.
+
+use std::collections::HashSet;
+
+use crate::message::domain::{ConversationId, Message, MessageId, SequenceNumber};
+use crate::message::error::BatchConflict;
+
+/// Identifiers of a batch message that must be unique.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BatchKey {
+    pub(crate) message_id: MessageId,
+    pub(crate) conversation_id: ConversationId,
+    pub(crate) sequence: SequenceNumber,
+}
+
+impl From<&Message> for BatchKey {
+    fn from(message: &Message) -> Self {
+        Self {
+            message_id: message.id(),
+            conversation_id: message.conversation_id(),
+            sequence: message.sequence_number(),
+        }
+    }
+}
+
+/// Returns the conflicts in `keys`, in batch order.
+///
+/// `is_stored_id` and `is_stored_sequence` report whether an identifier is
+/// already taken by a stored message. A message can report both a
+/// duplicate ID and a duplicate sequence number.
+pub(crate) fn find_batch_conflicts(
+    keys: &[BatchKey],
+    is_stored_id: impl Fn(MessageId) -> bool,
+    is_stored_sequence: impl Fn(ConversationId, SequenceNumber) -> bool,
+) -> Vec<BatchConflict> {
+    let mut seen_ids = HashSet::new();
+    let mut seen_sequences = HashSet::new();
+    let mut conflicts = Vec::new();
+    for (index, key) in keys.iter().enumerate() {
+        if is_stored_id(key.message_id) || !seen_ids.insert(key.message_id) {
+            conflicts.push(BatchConflict::DuplicateMessage {
+                index,
+                message_id: key.message_id,
+            });
+        }
+        let position = (key.conversation_id, key.sequence);
+        if is_stored_sequence(key.conversation_id, key.sequence) || !seen_sequences.insert(position)
+        {
+            conflicts.push(BatchConflict::DuplicateSequence {
+                index,
+                conversation_id: key.conversation_id,
+                sequence: key.sequence,
+            });
+        }
+    }
+    conflicts
+}
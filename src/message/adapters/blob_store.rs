@@ -0,0 +1,123 @@
+//! Object store adapter for attachment blobs.
+//!
+//! Each blob is written to `message_blobs/{tenant_id}/{hash}`. Reads hash
+//! the returned bytes again, so a blob altered in the backend is reported
+//! rather than served.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::ContentHash,
+    ports::blob_store::{BlobStore, BlobStoreError, BlobStoreResult},
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use object_store::{ObjectStore, local::LocalFileSystem, path::Path};
+use std::sync::Arc;
+
+/// Adapter wrapping an [`ObjectStore`] backend for attachment blobs.
+///
+/// Supports any `object_store` backend: `InMemory` for tests,
+/// `LocalFileSystem` for single-node deployments, and S3 with the `s3`
+/// feature.
+#[derive(Debug, Clone)]
+pub struct ObjectStoreBlobStore {
+    store: Arc<dyn ObjectStore>,
+}
+
+impl ObjectStoreBlobStore {
+    /// Creates a blob store from any [`ObjectStore`] implementation.
+    #[must_use]
+    pub fn new(store: Arc<dyn ObjectStore>) -> Self {
+        Self { store }
+    }
+
+    /// Creates an in-memory backed blob store for tests.
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self::new(Arc::new(object_store::memory::InMemory::new()))
+    }
+
+    /// Creates a blob store writing beneath the existing directory `root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlobStoreError::Configuration`] when `root` cannot be
+    /// resolved.
+    pub fn local_filesystem(root: impl AsRef<std::path::Path>) -> BlobStoreResult<Self> {
+        let store = LocalFileSystem::new_with_prefix(root)
+            .map_err(|err| BlobStoreError::Configuration(err.to_string()))?;
+        Ok(Self::new(Arc::new(store)))
+    }
+
+    /// Creates a blob store writing to the S3 bucket `bucket`.
+    ///
+    /// Credentials, region, and endpoint are read from the standard `AWS_*`
+    /// environment variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlobStoreError::Configuration`] when the environment does
+    /// not describe a usable S3 client.
+    #[cfg(feature = "s3")]
+    pub fn s3(bucket: &str) -> BlobStoreResult<Self> {
+        let store = object_store::aws::AmazonS3Builder::from_env()
+            .with_bucket_name(bucket)
+            .build()
+            .map_err(|err| BlobStoreError::Configuration(err.to_string()))?;
+        Ok(Self::new(Arc::new(store)))
+    }
+}
+
+fn blob_path(ctx: &RequestContext, hash: &ContentHash) -> Path {
+    Path::from(format!("message_blobs/{}/{hash}", ctx.tenant_id()))
+}
+
+#[async_trait]
+impl BlobStore for ObjectStoreBlobStore {
+    async fn put(
+        &self,
+        ctx: &RequestContext,
+        hash: &ContentHash,
+        data: Bytes,
+    ) -> BlobStoreResult<()> {
+        let actual = ContentHash::of(&data);
+        if actual != *hash {
+            return Err(BlobStoreError::HashMismatch {
+                expected: hash.clone(),
+                actual,
+            });
+        }
+        self.store
+            .put(&blob_path(ctx, hash), data.into())
+            .await
+            .map_err(|err| BlobStoreError::Write(err.to_string()))?;
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        ctx: &RequestContext,
+        hash: &ContentHash,
+    ) -> BlobStoreResult<Option<Bytes>> {
+        let found = match self.store.get(&blob_path(ctx, hash)).await {
+            Ok(found) => found,
+            Err(object_store::Error::NotFound { .. }) => return Ok(None),
+            Err(err) => return Err(BlobStoreError::Read(err.to_string())),
+        };
+        let data = found
+            .bytes()
+            .await
+            .map_err(|err| BlobStoreError::Read(err.to_string()))?;
+        if ContentHash::of(&data) != *hash {
+            return Err(BlobStoreError::Corrupt(hash.clone()));
+        }
+        Ok(Some(data))
+    }
+
+    async fn delete(&self, ctx: &RequestContext, hash: &ContentHash) -> BlobStoreResult<()> {
+        match self.store.delete(&blob_path(ctx, hash)).await {
+            Ok(()) | Err(object_store::Error::NotFound { .. }) => Ok(()),
+            Err(err) => Err(BlobStoreError::Delete(err.to_string())),
+        }
+    }
+}
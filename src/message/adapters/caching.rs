@@ -0,0 +1,248 @@
+//! Repository decorator that caches conversation history in process.
+//!
+//! A multi-turn agent loop re-reads the same conversation every turn.
+//! [`CachingMessageRepository`] keeps the pages of each conversation it has
+//! read in a bounded [`moka`] cache keyed by tenant and conversation, and
+//! drops a conversation's pages whenever a message of it is written through
+//! the decorator. Writes made around the decorator, by another process or
+//! another repository, are seen once the entry's time to live expires.
+//!
+//! Only [`MessageRepository::find_by_conversation`] is cached; every other
+//! read passes straight through.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{
+        ContentHash, ConversationId, ConversationTokenUsage, Message, MessageId, MessageQuery,
+        MessageRedaction, SequenceNumber,
+    },
+    ports::repository::{MessageRepository, RepositoryResult},
+};
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use moka::future::Cache;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// Counters describing how well a [`CachingMessageRepository`] is doing.
+///
+/// The hit rate is `hits / (hits + misses)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MessageCacheMetrics {
+    /// History pages answered from the cache.
+    pub hits: u64,
+    /// History pages read from the wrapped repository.
+    pub misses: u64,
+    /// Times a conversation's history was dropped because one of its
+    /// messages was written.
+    pub invalidations: u64,
+}
+
+/// The pages of one conversation read so far.
+///
+/// Invalidation removes the whole entry from the cache, so a read that
+/// finishes after a write only fills a detached entry no later read sees.
+#[derive(Default)]
+struct CachedConversation {
+    pages: Mutex<HashMap<PageRequest, Page<Message>>>,
+}
+
+impl CachedConversation {
+    fn lock_pages(&self) -> MutexGuard<'_, HashMap<PageRequest, Page<Message>>> {
+        self.pages.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// [`MessageRepository`] decorator that serves repeated history reads from
+/// memory.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::adapters::{
+///     caching::CachingMessageRepository, memory::InMemoryMessageRepository,
+/// };
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let repository = CachingMessageRepository::new(
+///     Arc::new(InMemoryMessageRepository::new()),
+///     1_000,
+///     Duration::from_secs(60),
+/// );
+/// assert_eq!(repository.metrics().hits, 0);
+/// ```
+pub struct CachingMessageRepository<R: ?Sized> {
+    inner: Arc<R>,
+    conversations: Cache<(TenantId, ConversationId), Arc<CachedConversation>>,
+    metrics: Mutex<MessageCacheMetrics>,
+}
+
+impl<R: MessageRepository + ?Sized> CachingMessageRepository<R> {
+    /// Wraps `inner`, caching the history of at most `max_conversations`
+    /// conversations for at most `time_to_live` each.
+    #[must_use]
+    pub fn new(inner: Arc<R>, max_conversations: u64, time_to_live: Duration) -> Self {
+        Self {
+            inner,
+            conversations: Cache::builder()
+                .max_capacity(max_conversations)
+                .time_to_live(time_to_live)
+                .build(),
+            metrics: Mutex::new(MessageCacheMetrics::default()),
+        }
+    }
+
+    /// Returns the counters accumulated since the decorator was created.
+    #[must_use]
+    pub fn metrics(&self) -> MessageCacheMetrics {
+        *self.lock_metrics()
+    }
+
+    /// Returns the number of conversations currently cached.
+    ///
+    /// The count is approximate while evictions are pending.
+    #[must_use]
+    pub fn cached_conversations(&self) -> u64 {
+        self.conversations.entry_count()
+    }
+
+    /// Drops the cached history of `conversation_id`.
+    pub async fn invalidate(&self, ctx: &RequestContext, conversation_id: ConversationId) {
+        self.conversations
+            .invalidate(&(ctx.tenant_id(), conversation_id))
+            .await;
+        self.lock_metrics().invalidations += 1;
+    }
+
+    /// Drops the cached history of every conversation `messages` belong to.
+    async fn invalidate_all(&self, ctx: &RequestContext, messages: &[Message]) {
+        let conversations: HashSet<ConversationId> =
+            messages.iter().map(Message::conversation_id).collect();
+        for conversation_id in conversations {
+            self.invalidate(ctx, conversation_id).await;
+        }
+    }
+
+    fn lock_metrics(&self) -> MutexGuard<'_, MessageCacheMetrics> {
+        self.metrics.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl<R: MessageRepository + ?Sized> MessageRepository for CachingMessageRepository<R> {
+    async fn store(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<()> {
+        let stored = self.inner.store(ctx, message).await;
+        self.invalidate(ctx, message.conversation_id()).await;
+        stored
+    }
+
+    async fn append(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<Message> {
+        let appended = self.inner.append(ctx, message).await;
+        self.invalidate(ctx, message.conversation_id()).await;
+        appended
+    }
+
+    async fn store_batch(
+        &self,
+        ctx: &RequestContext,
+        messages: &[Message],
+    ) -> RepositoryResult<()> {
+        let stored = self.inner.store_batch(ctx, messages).await;
+        self.invalidate_all(ctx, messages).await;
+        stored
+    }
+
+    async fn redact(
+        &self,
+        ctx: &RequestContext,
+        redaction: &MessageRedaction,
+    ) -> RepositoryResult<Message> {
+        let redacted = self.inner.redact(ctx, redaction).await?;
+        self.invalidate(ctx, redacted.conversation_id()).await;
+        Ok(redacted)
+    }
+
+    async fn set_pinned(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+        pinned: bool,
+    ) -> RepositoryResult<Message> {
+        let updated = self.inner.set_pinned(ctx, id, pinned).await?;
+        self.invalidate(ctx, updated.conversation_id()).await;
+        Ok(updated)
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+    ) -> RepositoryResult<Option<Message>> {
+        self.inner.find_by_id(ctx, id).await
+    }
+
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> RepositoryResult<Page<Message>> {
+        let entry = self
+            .conversations
+            .get_with((ctx.tenant_id(), conversation_id), async {
+                Arc::new(CachedConversation::default())
+            })
+            .await;
+        let cached = entry.lock_pages().get(&page).cloned();
+        if let Some(hit) = cached {
+            self.lock_metrics().hits += 1;
+            return Ok(hit);
+        }
+        self.lock_metrics().misses += 1;
+        let loaded = self
+            .inner
+            .find_by_conversation(ctx, conversation_id, page)
+            .await?;
+        entry.lock_pages().insert(page, loaded.clone());
+        Ok(loaded)
+    }
+
+    async fn query(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        query: &MessageQuery,
+    ) -> RepositoryResult<Page<Message>> {
+        self.inner.query(ctx, conversation_id, query).await
+    }
+
+    async fn next_sequence_number(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<SequenceNumber> {
+        self.inner.next_sequence_number(ctx, conversation_id).await
+    }
+
+    async fn token_usage(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<ConversationTokenUsage> {
+        self.inner.token_usage(ctx, conversation_id).await
+    }
+
+    async fn exists(&self, ctx: &RequestContext, id: MessageId) -> RepositoryResult<bool> {
+        self.inner.exists(ctx, id).await
+    }
+
+    async fn unreferenced_blobs(
+        &self,
+        ctx: &RequestContext,
+        hashes: &[ContentHash],
+    ) -> RepositoryResult<Vec<ContentHash>> {
+        self.inner.unreferenced_blobs(ctx, hashes).await
+    }
+}
@@ -0,0 +1,138 @@
+//! [`ContentScanner`] adapter matching regular expressions.
+//!
+//! [`ContentScanner`]: crate::message::ports::ContentScanner
+
+use crate::message::{domain::SensitiveDataKind, ports::ContentScanner};
+use regex::Regex;
+use std::cmp::Reverse;
+use std::ops::Range;
+use thiserror::Error;
+
+/// Addresses of the form `local@domain.tld`.
+const EMAIL_ADDRESS_PATTERN: &str =
+    r"(?i)\b[a-z0-9._%+-]+@[a-z0-9-]+(?:\.[a-z0-9-]+)*\.[a-z]{2,}\b";
+
+/// International numbers written without separators, and numbers in two or
+/// three separated groups with an optional country code.
+const PHONE_NUMBER_PATTERN: &str = concat!(
+    r"(?:\+\d{8,15}\b",
+    r"|(?:\+\d{1,3}[\s.-]?)?(?:\(\d{2,4}\)[\s.-]?|\b\d{2,4}[\s.-])\d{3,4}[\s.-]\d{3,4}\b)",
+);
+
+/// Key formats of widely used services: `OpenAI` and Anthropic-style `sk-`
+/// keys, AWS access key IDs, GitHub tokens, Slack tokens and Google API keys.
+const API_KEY_PATTERN: &str = concat!(
+    r"\b(?:sk-[A-Za-z0-9_-]{20,}",
+    r"|AKIA[0-9A-Z]{16}",
+    r"|gh[pousr]_[A-Za-z0-9]{36,}",
+    r"|github_pat_[A-Za-z0-9_]{22,}",
+    r"|xox[abprs]-[A-Za-z0-9-]{10,}",
+    r"|AIza[0-9A-Za-z_-]{35})",
+);
+
+/// Error returned when a scan pattern does not compile.
+#[derive(Debug, Clone, Error)]
+#[error("invalid {kind} pattern: {source}")]
+pub struct ScanPatternError {
+    /// The kind of data the pattern was to find.
+    pub kind: SensitiveDataKind,
+    /// Why the pattern did not compile.
+    #[source]
+    pub source: regex::Error,
+}
+
+/// [`ContentScanner`] that reports the matches of a regular expression per
+/// kind of sensitive data.
+///
+/// Where matches overlap, the one starting first wins, and of those
+/// starting together the longest.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::adapters::content_scanning::RegexContentScanner;
+/// use corbusier::message::domain::SensitiveDataKind;
+/// use corbusier::message::ports::ContentScanner;
+///
+/// let scanner = RegexContentScanner::standard().expect("built-in patterns compile");
+/// let found = scanner.scan_text("Mail ada@example.com");
+/// assert_eq!(found, vec![(SensitiveDataKind::EmailAddress, 5..20)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegexContentScanner {
+    patterns: Vec<(SensitiveDataKind, Regex)>,
+}
+
+impl RegexContentScanner {
+    /// Creates a scanner that finds nothing until patterns are added.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            patterns: Vec::new(),
+        }
+    }
+
+    /// Creates a scanner for email addresses, phone numbers and common API
+    /// key formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScanPatternError`] if a built-in pattern fails to compile,
+    /// which only a broken build of the `regex` crate could cause.
+    pub fn standard() -> Result<Self, ScanPatternError> {
+        Self::empty()
+            .with_pattern(SensitiveDataKind::EmailAddress, EMAIL_ADDRESS_PATTERN)?
+            .with_pattern(SensitiveDataKind::PhoneNumber, PHONE_NUMBER_PATTERN)?
+            .with_pattern(SensitiveDataKind::ApiKey, API_KEY_PATTERN)
+    }
+
+    /// Adds a pattern reporting its matches as `kind`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScanPatternError`] if `pattern` is not a valid regular
+    /// expression.
+    pub fn with_pattern(
+        mut self,
+        kind: SensitiveDataKind,
+        pattern: &str,
+    ) -> Result<Self, ScanPatternError> {
+        let regex = Regex::new(pattern).map_err(|source| ScanPatternError { kind, source })?;
+        self.patterns.push((kind, regex));
+        Ok(self)
+    }
+}
+
+impl ContentScanner for RegexContentScanner {
+    fn scan_text(&self, text: &str) -> Vec<(SensitiveDataKind, Range<usize>)> {
+        let mut matches: Vec<(SensitiveDataKind, Range<usize>)> = self
+            .patterns
+            .iter()
+            .flat_map(|(kind, regex)| regex.find_iter(text).map(|found| (*kind, found.range())))
+            .collect();
+        matches.sort_by_key(|(_, range)| (range.start, Reverse(range.end)));
+
+        let mut covered_to = 0;
+        matches
+            .into_iter()
+            .filter(|(_, range)| {
+                let keep = range.start >= covered_to;
+                if keep {
+                    covered_to = range.end;
+                }
+                keep
+            })
+            .map(|(kind, range)| {
+                (
+                    kind,
+                    char_offset(text, range.start)..char_offset(text, range.end),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Returns the number of characters before byte `index` of `text`.
+fn char_offset(text: &str, index: usize) -> usize {
+    text.get(..index).map_or(0, |prefix| prefix.chars().count())
+}
@@ -0,0 +1,159 @@
+//! AES-GCM envelope encryption for message content.
+//!
+//! [`AesGcmContentCipher`] seals every value under a fresh 256-bit data key
+//! and wraps that data key with the active key-encryption key. The key
+//! identifier is the associated data of the wrapping, so a wrapped key
+//! cannot be passed off as belonging to another key.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+
+use crate::message::domain::{EncryptionKeyId, SealedValue};
+use crate::message::ports::content_cipher::{CipherError, CipherResult, ContentCipher};
+
+/// Length of an AES-GCM nonce in bytes.
+const NONCE_LEN: usize = 12;
+
+/// [`ContentCipher`] using AES-256-GCM with per-value data keys.
+///
+/// The cipher holds one active key-encryption key and any number of
+/// retired ones. New values are sealed under the active key; values
+/// sealed under a retired key still open.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::adapters::encryption::AesGcmContentCipher;
+/// use corbusier::message::domain::EncryptionKeyId;
+/// use corbusier::message::ports::ContentCipher;
+///
+/// let old = EncryptionKeyId::new("content-2026-04")?;
+/// let cipher = AesGcmContentCipher::new(EncryptionKeyId::new("content-2026-10")?, [7; 32])
+///     .with_key(old, [3; 32]);
+/// let sealed = cipher.seal(b"hello", b"message-1")?;
+/// assert_eq!(sealed.key_id, *cipher.active_key_id());
+/// assert_eq!(cipher.open(&sealed, b"message-1")?, b"hello");
+/// assert!(cipher.open(&sealed, b"message-2").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone)]
+pub struct AesGcmContentCipher {
+    active: EncryptionKeyId,
+    keys: HashMap<EncryptionKeyId, Aes256Gcm>,
+}
+
+impl AesGcmContentCipher {
+    /// Creates a cipher sealing under the 256-bit key `key`, named `id`.
+    #[must_use]
+    pub fn new(id: EncryptionKeyId, key: [u8; 32]) -> Self {
+        let keys = HashMap::from([(id.clone(), key_cipher(key))]);
+        Self { active: id, keys }
+    }
+
+    /// Adds a retired key that values may still be sealed under.
+    ///
+    /// Adding a key under the active key's identifier replaces the active
+    /// key material.
+    #[must_use]
+    pub fn with_key(mut self, id: EncryptionKeyId, key: [u8; 32]) -> Self {
+        self.keys.insert(id, key_cipher(key));
+        self
+    }
+
+    fn key(&self, id: &EncryptionKeyId) -> CipherResult<&Aes256Gcm> {
+        self.keys
+            .get(id)
+            .ok_or_else(|| CipherError::UnknownKey(id.clone()))
+    }
+}
+
+impl fmt::Debug for AesGcmContentCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut key_ids: Vec<&str> = self.keys.keys().map(EncryptionKeyId::as_str).collect();
+        key_ids.sort_unstable();
+        f.debug_struct("AesGcmContentCipher")
+            .field("active", &self.active.as_str())
+            .field("key_ids", &key_ids)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ContentCipher for AesGcmContentCipher {
+    fn active_key_id(&self) -> &EncryptionKeyId {
+        &self.active
+    }
+
+    fn seal(&self, plaintext: &[u8], aad: &[u8]) -> CipherResult<SealedValue> {
+        let data_key = Aes256Gcm::generate_key(OsRng);
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = Aes256Gcm::new(&data_key)
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: plaintext,
+                    aad,
+                },
+            )
+            .map_err(|err| CipherError::Encryption(err.to_string()))?;
+        let key_nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let wrapped_key = self
+            .key(&self.active)?
+            .encrypt(
+                &key_nonce,
+                Payload {
+                    msg: data_key.as_slice(),
+                    aad: self.active.as_str().as_bytes(),
+                },
+            )
+            .map_err(|err| CipherError::Encryption(err.to_string()))?;
+        Ok(SealedValue {
+            key_id: self.active.clone(),
+            wrapped_key,
+            key_nonce: key_nonce.to_vec(),
+            nonce: nonce.to_vec(),
+            ciphertext,
+        })
+    }
+
+    fn open(&self, sealed: &SealedValue, aad: &[u8]) -> CipherResult<Vec<u8>> {
+        let data_key = self
+            .key(&sealed.key_id)?
+            .decrypt(
+                nonce(&sealed.key_nonce)?,
+                Payload {
+                    msg: &sealed.wrapped_key,
+                    aad: sealed.key_id.as_str().as_bytes(),
+                },
+            )
+            .map_err(|_| decryption_failed("data key did not unwrap"))?;
+        Aes256Gcm::new_from_slice(&data_key)
+            .map_err(|_| decryption_failed("data key has the wrong length"))?
+            .decrypt(
+                nonce(&sealed.nonce)?,
+                Payload {
+                    msg: &sealed.ciphertext,
+                    aad,
+                },
+            )
+            .map_err(|_| decryption_failed("value failed authentication"))
+    }
+}
+
+fn key_cipher(key: [u8; 32]) -> Aes256Gcm {
+    Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key))
+}
+
+/// Checks a stored nonce's length before borrowing it as a nonce.
+fn nonce(bytes: &[u8]) -> CipherResult<&Nonce<<Aes256Gcm as AeadCore>::NonceSize>> {
+    if bytes.len() != NONCE_LEN {
+        return Err(decryption_failed("nonce has the wrong length"));
+    }
+    Ok(Nonce::from_slice(bytes))
+}
+
+fn decryption_failed(reason: &str) -> CipherError {
+    CipherError::Decryption(reason.to_owned())
+}
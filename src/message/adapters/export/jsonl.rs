@@ -0,0 +1,42 @@
+//! JSON Lines exports of canonical messages.
+
+use crate::message::{
+    domain::{ConversationId, Message},
+    ports::{ExportEncoder, ExportFormat, ExportFormatResult},
+};
+
+/// Writes each message as its canonical JSON on a line of its own.
+///
+/// Lines use the same serialisation as the message store, so an export can
+/// be read back into [`Message`]s.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonlExportFormat;
+
+impl JsonlExportFormat {
+    /// The name exports select the format by.
+    pub const NAME: &'static str = "jsonl";
+
+    /// Creates the format.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ExportFormat for JsonlExportFormat {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn encoder(&self, _conversation_id: ConversationId) -> Box<dyn ExportEncoder> {
+        Box::new(Self)
+    }
+}
+
+impl ExportEncoder for JsonlExportFormat {
+    fn message(&mut self, message: &Message) -> ExportFormatResult<Vec<u8>> {
+        let mut line = serde_json::to_vec(message)?;
+        line.push(b'\n');
+        Ok(line)
+    }
+}
@@ -0,0 +1,133 @@
+//! Markdown transcripts for people to read.
+
+use crate::message::{
+    domain::{CitationSource, ContentPart, ConversationId, Message, Role},
+    ports::{ExportEncoder, ExportFormat, ExportFormatResult},
+};
+use serde_json::Value;
+
+/// Writes a conversation as a Markdown transcript.
+///
+/// Each message becomes a section headed by its role, sequence number, and
+/// creation time. Tool calls and structured results are shown as JSON code
+/// blocks, reasoning as a quotation, and attachments, images, citations, and
+/// redactions as one-line notes; binary data is never written.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarkdownExportFormat;
+
+impl MarkdownExportFormat {
+    /// The name exports select the format by.
+    pub const NAME: &'static str = "markdown";
+
+    /// Creates the format.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ExportFormat for MarkdownExportFormat {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn encoder(&self, conversation_id: ConversationId) -> Box<dyn ExportEncoder> {
+        Box::new(MarkdownEncoder { conversation_id })
+    }
+}
+
+struct MarkdownEncoder {
+    conversation_id: ConversationId,
+}
+
+impl ExportEncoder for MarkdownEncoder {
+    fn begin(&mut self) -> ExportFormatResult<Vec<u8>> {
+        Ok(format!("# Conversation {}\n", self.conversation_id).into_bytes())
+    }
+
+    fn message(&mut self, message: &Message) -> ExportFormatResult<Vec<u8>> {
+        let heading = format!(
+            "## {} · #{} · {}",
+            role_heading(message.role()),
+            message.sequence_number().value(),
+            message.created_at().to_rfc3339(),
+        );
+        let body: Vec<String> = message.content().iter().map(render_part).collect();
+        Ok(format!("\n{heading}\n\n{}\n", body.join("\n\n")).into_bytes())
+    }
+}
+
+const fn role_heading(role: Role) -> &'static str {
+    match role {
+        Role::User => "User",
+        Role::Assistant => "Assistant",
+        Role::Tool => "Tool",
+        Role::System => "System",
+    }
+}
+
+fn render_part(part: &ContentPart) -> String {
+    match part {
+        ContentPart::Text(text) => text.text.clone(),
+        ContentPart::Reasoning(reasoning) if reasoning.redacted => {
+            "> *Reasoning redacted by the backend*".to_owned()
+        }
+        ContentPart::Reasoning(reasoning) => quote(&reasoning.text),
+        ContentPart::ToolCall(call) => format!(
+            "**Tool call** `{}` (`{}`)\n\n{}",
+            call.name,
+            call.call_id,
+            json_block(&call.arguments)
+        ),
+        ContentPart::ToolResult(result) => {
+            let outcome = if result.success { "" } else { ", failed" };
+            let content = match &result.content {
+                Value::String(text) => format!("```text\n{text}\n```"),
+                other => json_block(other),
+            };
+            format!(
+                "**Tool result** (`{}`{outcome})\n\n{content}",
+                result.call_id
+            )
+        }
+        ContentPart::Attachment(attachment) => attachment.name.as_ref().map_or_else(
+            || format!("*Attachment:* {}", attachment.mime_type),
+            |name| format!("*Attachment:* {name} ({})", attachment.mime_type),
+        ),
+        ContentPart::Image(image) => {
+            let label = image
+                .alt_text
+                .as_deref()
+                .or(image.name.as_deref())
+                .unwrap_or(&image.mime_type);
+            format!("*Image:* {label} ({}x{})", image.width, image.height)
+        }
+        ContentPart::Citation(citation) => {
+            let source = match &citation.source {
+                CitationSource::Uri { uri } => uri.clone(),
+                CitationSource::Message { message_id } => format!("message {message_id}"),
+            };
+            citation.title.as_ref().map_or_else(
+                || format!("*Source:* {source}"),
+                |title| format!("*Source:* [{title}]({source})"),
+            )
+        }
+        ContentPart::Custom(custom) => {
+            format!("*{}*\n\n{}", custom.kind, json_block(&custom.data))
+        }
+        ContentPart::Redacted(redacted) => format!("*Redacted: {}*", redacted.reason),
+    }
+}
+
+/// Quotes every line of `text`.
+fn quote(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("> {line}").trim_end().to_owned())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn json_block(value: &Value) -> String {
+    let json = serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string());
+    format!("```json\n{json}\n```")
+}
@@ -0,0 +1,29 @@
+//! Output formats for conversation exports.
+//!
+//! These adapters implement
+//! [`ExportFormat`](crate::message::ports::ExportFormat) for
+//! [`ConversationExporter`](crate::message::services::ConversationExporter):
+//!
+//! - [`JsonlExportFormat`]: one canonical message as JSON per line, for
+//!   analysis
+//! - [`MarkdownExportFormat`]: a transcript for people to read
+//! - [`OpenAiFineTuneExportFormat`]: one `OpenAI` chat fine-tuning example
+//!   per conversation
+
+mod jsonl;
+mod markdown;
+mod openai;
+
+pub use jsonl::JsonlExportFormat;
+pub use markdown::MarkdownExportFormat;
+pub use openai::OpenAiFineTuneExportFormat;
+
+use crate::message::domain::{ContentPart, Message};
+
+/// Returns the text parts of `message`, in order.
+fn text_parts(message: &Message) -> impl Iterator<Item = &str> {
+    message.content().iter().filter_map(|part| match part {
+        ContentPart::Text(text) => Some(text.text.as_str()),
+        _ => None,
+    })
+}
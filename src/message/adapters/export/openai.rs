@@ -0,0 +1,119 @@
+//! `OpenAI` chat fine-tuning examples.
+
+use super::text_parts;
+use crate::message::{
+    domain::{ContentPart, ConversationId, Message, Role},
+    ports::{ExportEncoder, ExportFormat, ExportFormatResult},
+};
+use serde_json::{Value, json};
+
+/// Writes each conversation as one line of `OpenAI` chat fine-tuning data:
+///
+/// ```json
+/// {"messages":[{"role":"user","content":"Hi"},{"role":"assistant","content":"Hello!"}]}
+/// ```
+///
+/// Text parts are joined into the message content. Assistant tool calls
+/// become `tool_calls`, and each tool result becomes a `tool` message
+/// answering its call. Reasoning, attachments, images, citations, custom
+/// parts, and redaction placeholders have no training equivalent and are
+/// left out, as are messages left with nothing to train on.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OpenAiFineTuneExportFormat;
+
+impl OpenAiFineTuneExportFormat {
+    /// The name exports select the format by.
+    pub const NAME: &'static str = "openai_fine_tune";
+
+    /// Creates the format.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ExportFormat for OpenAiFineTuneExportFormat {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn encoder(&self, _conversation_id: ConversationId) -> Box<dyn ExportEncoder> {
+        Box::new(FineTuneEncoder { written: 0 })
+    }
+}
+
+/// Streams the `messages` array of the example, counting the entries
+/// written so far to place the separators.
+struct FineTuneEncoder {
+    written: usize,
+}
+
+impl ExportEncoder for FineTuneEncoder {
+    fn begin(&mut self) -> ExportFormatResult<Vec<u8>> {
+        Ok(br#"{"messages":["#.to_vec())
+    }
+
+    fn message(&mut self, message: &Message) -> ExportFormatResult<Vec<u8>> {
+        let mut out = Vec::new();
+        for entry in training_messages(message) {
+            if self.written > 0 {
+                out.push(b',');
+            }
+            serde_json::to_writer(&mut out, &entry)?;
+            self.written += 1;
+        }
+        Ok(out)
+    }
+
+    fn finish(&mut self) -> ExportFormatResult<Vec<u8>> {
+        Ok(b"]}\n".to_vec())
+    }
+}
+
+/// Returns the training messages `message` becomes.
+fn training_messages(message: &Message) -> Vec<Value> {
+    let text = text_parts(message).collect::<Vec<_>>().join("\n\n");
+    match message.role() {
+        Role::Tool => message
+            .content()
+            .iter()
+            .filter_map(|part| match part {
+                ContentPart::ToolResult(result) => Some(json!({
+                    "role": "tool",
+                    "tool_call_id": result.call_id,
+                    "content": match &result.content {
+                        Value::String(content) => content.clone(),
+                        other => other.to_string(),
+                    },
+                })),
+                _ => None,
+            })
+            .collect(),
+        Role::Assistant => assistant_message(message, text).into_iter().collect(),
+        _ if text.is_empty() => Vec::new(),
+        role => vec![json!({ "role": role.as_str(), "content": text })],
+    }
+}
+
+fn assistant_message(message: &Message, text: String) -> Option<Value> {
+    let tool_calls: Vec<Value> = message
+        .content()
+        .iter()
+        .filter_map(|part| match part {
+            ContentPart::ToolCall(call) => Some(json!({
+                "id": call.call_id,
+                "type": "function",
+                "function": { "name": call.name, "arguments": call.arguments.to_string() },
+            })),
+            _ => None,
+        })
+        .collect();
+    if text.is_empty() && tool_calls.is_empty() {
+        return None;
+    }
+    let mut entry = json!({ "role": "assistant", "content": (!text.is_empty()).then_some(text) });
+    if !tool_calls.is_empty() {
+        entry["tool_calls"] = Value::Array(tool_calls);
+    }
+    Some(entry)
+}
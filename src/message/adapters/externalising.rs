@@ -0,0 +1,238 @@
+//! Repository decorator that keeps large attachment data in a blob store.
+//!
+//! Before a write, attachments and images larger than the inline limit have
+//! their data moved to the [`BlobStore`] and replaced by its
+//! [`ContentHash`]; after a read, the data is loaded back. Callers see whole
+//! attachments while the wrapped repository stores only references.
+//!
+//! Blobs are written before the message, so a failed write can leave an
+//! unreferenced blob behind but never a reference without its blob.
+//! Identical attachments within a tenant share one blob. Redacting a message
+//! deletes those of its blobs no other message of the tenant still refers
+//! to. A message storing the same data while the last message referring to
+//! it is redacted can still find the blob gone, and is then returned with
+//! its reference and no data.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        AttachmentBlob, ContentHash, ConversationId, ConversationTokenUsage, Message, MessageId,
+        MessageQuery, MessageRedaction, SequenceNumber,
+    },
+    error::RepositoryError,
+    ports::{
+        blob_store::BlobStore,
+        repository::{MessageRepository, RepositoryResult},
+    },
+};
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+/// [`MessageRepository`] decorator that moves large attachment data to a
+/// [`BlobStore`].
+pub struct ExternalisingMessageRepository<R: ?Sized> {
+    inner: Arc<R>,
+    blobs: Arc<dyn BlobStore>,
+    max_inline_bytes: usize,
+}
+
+impl<R: MessageRepository + ?Sized> ExternalisingMessageRepository<R> {
+    /// Wraps `inner` so attachments with more than `max_inline_bytes` of
+    /// data are kept in `blobs`.
+    ///
+    /// Use the same limit as
+    /// [`ValidationConfig::max_inline_attachment_bytes`](crate::message::ports::validator::ValidationConfig)
+    /// so validation measures messages as they will be stored.
+    #[must_use]
+    pub fn new(inner: Arc<R>, blobs: Arc<dyn BlobStore>, max_inline_bytes: usize) -> Self {
+        Self {
+            inner,
+            blobs,
+            max_inline_bytes,
+        }
+    }
+
+    /// Moves large attachment data to the blob store, returning the message
+    /// to store in its place.
+    async fn externalise(
+        &self,
+        ctx: &RequestContext,
+        message: &Message,
+    ) -> RepositoryResult<Message> {
+        let (externalised, blobs) = message
+            .clone()
+            .externalise_attachments(self.max_inline_bytes);
+        for AttachmentBlob { hash, data } in blobs {
+            self.blobs
+                .put(ctx, &hash, Bytes::from(data))
+                .await
+                .map_err(RepositoryError::database)?;
+        }
+        Ok(externalised)
+    }
+
+    /// Loads externalised attachment data back into `message`.
+    async fn rehydrate(&self, ctx: &RequestContext, message: Message) -> RepositoryResult<Message> {
+        let hashes: BTreeSet<ContentHash> = message.attachment_blobs().cloned().collect();
+        let mut blobs = BTreeMap::new();
+        for hash in hashes {
+            let Some(data) = self
+                .blobs
+                .get(ctx, &hash)
+                .await
+                .map_err(RepositoryError::database)?
+            else {
+                tracing::warn!(
+                    tenant_id = %ctx.tenant_id(),
+                    message_id = %message.id(),
+                    blob = %hash,
+                    "attachment blob is missing; returning its reference"
+                );
+                continue;
+            };
+            let text = String::from_utf8(data.to_vec()).map_err(|err| {
+                RepositoryError::serialization(format!("attachment blob {hash}: {err}"))
+            })?;
+            blobs.insert(hash, text);
+        }
+        Ok(message.with_attachment_data(&blobs))
+    }
+
+    async fn rehydrate_page(
+        &self,
+        ctx: &RequestContext,
+        page: Page<Message>,
+    ) -> RepositoryResult<Page<Message>> {
+        let next_cursor = page.next_cursor();
+        let mut messages = Vec::with_capacity(page.len());
+        for message in page.into_items() {
+            messages.push(self.rehydrate(ctx, message).await?);
+        }
+        Ok(Page::new(messages, next_cursor))
+    }
+}
+
+#[async_trait]
+impl<R: MessageRepository + ?Sized> MessageRepository for ExternalisingMessageRepository<R> {
+    async fn store(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<()> {
+        let externalised = self.externalise(ctx, message).await?;
+        self.inner.store(ctx, &externalised).await
+    }
+
+    async fn append(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<Message> {
+        let externalised = self.externalise(ctx, message).await?;
+        let stored = self.inner.append(ctx, &externalised).await?;
+        Ok(message
+            .clone()
+            .with_sequence_number(stored.sequence_number()))
+    }
+
+    async fn store_batch(
+        &self,
+        ctx: &RequestContext,
+        messages: &[Message],
+    ) -> RepositoryResult<()> {
+        let mut externalised = Vec::with_capacity(messages.len());
+        for message in messages {
+            externalised.push(self.externalise(ctx, message).await?);
+        }
+        self.inner.store_batch(ctx, &externalised).await
+    }
+
+    async fn redact(
+        &self,
+        ctx: &RequestContext,
+        redaction: &MessageRedaction,
+    ) -> RepositoryResult<Message> {
+        let blobs: Vec<ContentHash> = self
+            .inner
+            .find_by_id(ctx, redaction.message_id())
+            .await?
+            .map(|message| message.attachment_blobs().cloned().collect())
+            .unwrap_or_default();
+        let redacted = self.inner.redact(ctx, redaction).await?;
+        let unreferenced = self.inner.unreferenced_blobs(ctx, &blobs).await?;
+        for hash in &unreferenced {
+            self.blobs
+                .delete(ctx, hash)
+                .await
+                .map_err(RepositoryError::database)?;
+        }
+        Ok(redacted)
+    }
+
+    async fn set_pinned(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+        pinned: bool,
+    ) -> RepositoryResult<Message> {
+        let updated = self.inner.set_pinned(ctx, id, pinned).await?;
+        self.rehydrate(ctx, updated).await
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+    ) -> RepositoryResult<Option<Message>> {
+        let Some(message) = self.inner.find_by_id(ctx, id).await? else {
+            return Ok(None);
+        };
+        Ok(Some(self.rehydrate(ctx, message).await?))
+    }
+
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> RepositoryResult<Page<Message>> {
+        let found = self
+            .inner
+            .find_by_conversation(ctx, conversation_id, page)
+            .await?;
+        self.rehydrate_page(ctx, found).await
+    }
+
+    async fn query(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        query: &MessageQuery,
+    ) -> RepositoryResult<Page<Message>> {
+        let found = self.inner.query(ctx, conversation_id, query).await?;
+        self.rehydrate_page(ctx, found).await
+    }
+
+    async fn next_sequence_number(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<SequenceNumber> {
+        self.inner.next_sequence_number(ctx, conversation_id).await
+    }
+
+    async fn token_usage(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<ConversationTokenUsage> {
+        self.inner.token_usage(ctx, conversation_id).await
+    }
+
+    async fn exists(&self, ctx: &RequestContext, id: MessageId) -> RepositoryResult<bool> {
+        self.inner.exists(ctx, id).await
+    }
+
+    async fn unreferenced_blobs(
+        &self,
+        ctx: &RequestContext,
+        hashes: &[ContentHash],
+    ) -> RepositoryResult<Vec<ContentHash>> {
+        self.inner.unreferenced_blobs(ctx, hashes).await
+    }
+}
@@ -0,0 +1,123 @@
+//! Inbound email webhook payload parsing.
+//!
+//! Mail providers that forward received email over HTTP post a JSON
+//! document describing the email:
+//!
+//! ```json
+//! {
+//!   "message_id": "<a1@mail.example.com>",
+//!   "from": "Jane Doe <jdoe@example.com>",
+//!   "to": ["agent@corbusier.example"],
+//!   "subject": "Deploy the fix",
+//!   "text": "Please deploy the fix to staging.",
+//!   "in_reply_to": "<a0@mail.example.com>",
+//!   "references": ["<a0@mail.example.com>"],
+//!   "attachments": [
+//!     { "filename": "log.txt", "content_type": "text/plain", "content": "SGVsbG8=", "size": 5 }
+//!   ]
+//! }
+//! ```
+//!
+//! Only `message_id` and `from` are required. Attachment `content` is
+//! base64 encoded and kept encoded. A display name around the sender
+//! address is dropped.
+
+use super::InboundPayloadError;
+use crate::message::domain::{AttachmentPart, InboundEmail};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct EmailPayload {
+    message_id: String,
+    from: String,
+    #[serde(default)]
+    to: Vec<String>,
+    #[serde(default)]
+    subject: String,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    in_reply_to: Option<String>,
+    #[serde(default)]
+    references: Vec<String>,
+    #[serde(default)]
+    attachments: Vec<EmailAttachmentPayload>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmailAttachmentPayload {
+    #[serde(default)]
+    filename: Option<String>,
+    content_type: String,
+    content: String,
+    #[serde(default)]
+    size: Option<u64>,
+}
+
+impl From<EmailAttachmentPayload> for AttachmentPart {
+    fn from(payload: EmailAttachmentPayload) -> Self {
+        let part = Self::new(payload.content_type, payload.content);
+        let named = match payload.filename {
+            Some(filename) => part.with_name(filename),
+            None => part,
+        };
+        match payload.size {
+            Some(size) => named.with_size(size),
+            None => named,
+        }
+    }
+}
+
+/// Parses an inbound email webhook body.
+///
+/// # Errors
+///
+/// Returns [`InboundPayloadError::Malformed`] when the body does not match
+/// the email payload, or [`InboundPayloadError::EmptyText`] when the email
+/// has neither body text nor attachments.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::adapters::inbound::parse_email_webhook;
+///
+/// let body = br#"{"message_id":"<a1@example.com>","from":"Jane <jdoe@example.com>",
+///     "subject":"Deploy","text":"Ship it"}"#;
+/// let email = parse_email_webhook(body).expect("valid payload");
+/// assert_eq!(email.from, "jdoe@example.com");
+/// assert_eq!(email.thread_id(), "<a1@example.com>");
+/// ```
+pub fn parse_email_webhook(body: &[u8]) -> Result<InboundEmail, InboundPayloadError> {
+    let payload: EmailPayload = serde_json::from_slice(body)?;
+    if payload.text.trim().is_empty() && payload.attachments.is_empty() {
+        return Err(InboundPayloadError::EmptyText);
+    }
+    let mut email = InboundEmail::new(
+        payload.message_id,
+        bare_address(&payload.from),
+        payload.subject,
+        payload.text,
+    )
+    .with_to(payload.to.iter().map(|address| bare_address(address)))
+    .with_references(payload.references);
+    if let Some(parent) = payload.in_reply_to {
+        email = email.with_in_reply_to(parent);
+    }
+    Ok(payload
+        .attachments
+        .into_iter()
+        .fold(email, |built, attachment| {
+            built.with_attachment(attachment.into())
+        }))
+}
+
+/// Returns the address inside `Display Name <address>`, or the trimmed
+/// input when there are no angle brackets.
+fn bare_address(mailbox: &str) -> String {
+    mailbox
+        .rsplit_once('<')
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(mailbox, |(address, _)| address)
+        .trim()
+        .to_owned()
+}
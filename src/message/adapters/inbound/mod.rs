@@ -0,0 +1,34 @@
+//! Inbound gateway adapters for external chat systems and email.
+//!
+//! These adapters sit in front of
+//! [`InboundMessageService`](crate::message::services::InboundMessageService):
+//! they verify that a request was signed by the configured sender and turn
+//! Slack events or custom webhook payloads into
+//! [`InboundMessage`](crate::message::domain::InboundMessage)s, and email
+//! webhook payloads into
+//! [`InboundEmail`](crate::message::domain::InboundEmail)s.
+
+mod email;
+mod signature;
+mod slack;
+mod webhook;
+
+pub use email::parse_email_webhook;
+pub use signature::{
+    SignatureConfigError, SignatureError, SignatureScheme, SignedRequest, WebhookSignatureVerifier,
+};
+pub use slack::{SLACK_SOURCE, SlackDelivery, parse_slack_delivery};
+pub use webhook::parse_webhook_message;
+
+use thiserror::Error;
+
+/// Errors returned when an inbound payload cannot be parsed.
+#[derive(Debug, Error)]
+pub enum InboundPayloadError {
+    /// The body is not valid JSON of the expected shape.
+    #[error("malformed inbound payload: {0}")]
+    Malformed(#[from] serde_json::Error),
+    /// The message text is blank.
+    #[error("inbound message text must not be empty")]
+    EmptyText,
+}
@@ -0,0 +1,283 @@
+//! HMAC-SHA256 signature verification for inbound webhooks.
+//!
+//! The Slack and custom schemes sign a timestamp together with the raw
+//! request body, so a captured request cannot be replayed once the timestamp
+//! falls outside the verifier's tolerance:
+//!
+//! - [`SignatureScheme::Slack`] follows Slack's request signing: the
+//!   signature is `v0=` followed by the hex HMAC of `v0:{timestamp}:{body}`
+//! - [`SignatureScheme::HmacSha256`] is used for custom webhooks: the
+//!   signature is `sha256=` followed by the hex HMAC of `{timestamp}.{body}`
+//!
+//! [`SignatureScheme::GitHub`] follows GitHub's `X-Hub-Signature-256`: the
+//! signature is `sha256=` followed by the hex HMAC of the body alone. GitHub
+//! sends no timestamp, so receivers deduplicate deliveries by their delivery
+//! identifier instead.
+
+use crate::message::canonical;
+use hmac::{Hmac, Mac};
+use mockable::Clock;
+use serde::Serialize;
+use sha2::Sha256;
+use std::fmt;
+use std::time::Duration;
+use thiserror::Error;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// How a webhook sender signs its requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureScheme {
+    /// Slack request signing (`v0=` signatures).
+    Slack,
+    /// Timestamped HMAC-SHA256 for custom webhooks (`sha256=` signatures).
+    HmacSha256,
+    /// GitHub webhook signing (`sha256=` signatures over the body alone).
+    GitHub,
+}
+
+impl SignatureScheme {
+    const fn prefix(self) -> &'static str {
+        match self {
+            Self::Slack => "v0=",
+            Self::HmacSha256 | Self::GitHub => "sha256=",
+        }
+    }
+
+    /// Returns the text signed before and after the timestamp, or `None`
+    /// when the scheme signs the body alone.
+    const fn separators(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::Slack => Some(("v0:", ":")),
+            Self::HmacSha256 => Some(("", ".")),
+            Self::GitHub => None,
+        }
+    }
+}
+
+/// Errors returned when a verifier cannot be built.
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+pub enum SignatureConfigError {
+    /// Signing secrets must not be empty.
+    #[error("webhook signing secret must not be empty")]
+    EmptySecret,
+}
+
+/// Reasons a signed request is rejected.
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The request carried no signature.
+    #[error("request signature is missing")]
+    MissingSignature,
+    /// The request carried no timestamp.
+    #[error("request timestamp is missing")]
+    MissingTimestamp,
+    /// The timestamp is not a Unix time in seconds.
+    #[error("request timestamp is not a Unix time")]
+    InvalidTimestamp,
+    /// The timestamp lies outside the verifier's tolerance.
+    #[error("request timestamp is {age_secs} seconds away from the current time")]
+    StaleTimestamp {
+        /// Distance between the timestamp and the current time.
+        age_secs: u64,
+    },
+    /// The signature does not match the request.
+    #[error("request signature does not match")]
+    Mismatch,
+}
+
+/// The parts of a request covered by its signature.
+#[derive(Debug, Clone, Copy)]
+pub struct SignedRequest<'a> {
+    /// Signature header value, including its scheme prefix.
+    pub signature: Option<&'a str>,
+    /// Timestamp header value, in Unix seconds.
+    pub timestamp: Option<&'a str>,
+    /// Raw request body.
+    pub body: &'a [u8],
+}
+
+/// Verifies webhook signatures with a shared secret.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::adapters::inbound::{
+///     SignatureScheme, SignedRequest, WebhookSignatureVerifier,
+/// };
+/// use mockable::{Clock, DefaultClock};
+///
+/// let verifier = WebhookSignatureVerifier::new(SignatureScheme::HmacSha256, "s3cret")
+///     .expect("non-empty secret");
+/// let timestamp = DefaultClock.utc().timestamp().to_string();
+/// let body = br#"{"text":"hello"}"#;
+/// let signature = verifier.sign(&timestamp, body);
+///
+/// let request = SignedRequest {
+///     signature: Some(&signature),
+///     timestamp: Some(&timestamp),
+///     body,
+/// };
+/// assert!(verifier.verify(&request, &DefaultClock).is_ok());
+/// ```
+#[derive(Clone)]
+pub struct WebhookSignatureVerifier {
+    scheme: SignatureScheme,
+    key: HmacSha256,
+    tolerance: Duration,
+}
+
+impl WebhookSignatureVerifier {
+    /// Default window within which request timestamps are accepted.
+    pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+    /// Creates a verifier for `scheme` keyed with `secret`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SignatureConfigError::EmptySecret`] when `secret` is empty.
+    pub fn new(
+        scheme: SignatureScheme,
+        secret: impl AsRef<[u8]>,
+    ) -> Result<Self, SignatureConfigError> {
+        let secret_bytes = secret.as_ref();
+        if secret_bytes.is_empty() {
+            return Err(SignatureConfigError::EmptySecret);
+        }
+        let key = HmacSha256::new_from_slice(secret_bytes)
+            .map_err(|_| SignatureConfigError::EmptySecret)?;
+        Ok(Self {
+            scheme,
+            key,
+            tolerance: Self::DEFAULT_TOLERANCE,
+        })
+    }
+
+    /// Sets how far request timestamps may drift from the current time.
+    #[must_use]
+    pub const fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Returns the signing scheme.
+    #[must_use]
+    pub const fn scheme(&self) -> SignatureScheme {
+        self.scheme
+    }
+
+    /// Returns the signature header value for a request body.
+    ///
+    /// [`SignatureScheme::GitHub`] ignores `timestamp`.
+    #[must_use]
+    pub fn sign(&self, timestamp: &str, body: &[u8]) -> String {
+        let digest = self.mac(timestamp, body).finalize().into_bytes();
+        digest
+            .iter()
+            .fold(self.scheme.prefix().to_owned(), |mut signature, byte| {
+                for nibble in [byte >> 4, byte & 0x0f] {
+                    signature.extend(char::from_digit(u32::from(nibble), 16));
+                }
+                signature
+            })
+    }
+
+    /// Returns the signature header value for the canonical JSON of
+    /// `payload`.
+    ///
+    /// Senders and receivers that both sign the canonical form agree on
+    /// the signature however their JSON libraries order keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serialisation error when `payload` cannot be
+    /// represented as JSON.
+    pub fn sign_canonical<T: Serialize + ?Sized>(
+        &self,
+        timestamp: &str,
+        payload: &T,
+    ) -> Result<String, serde_json::Error> {
+        let body = canonical::to_canonical_vec(payload)?;
+        Ok(self.sign(timestamp, &body))
+    }
+
+    /// Checks the request's timestamp and signature.
+    ///
+    /// The signature is compared in constant time. Schemes that do not sign
+    /// a timestamp ignore [`SignedRequest::timestamp`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SignatureError`] describing why the request was rejected.
+    pub fn verify(
+        &self,
+        request: &SignedRequest<'_>,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<(), SignatureError> {
+        let timestamp = if self.scheme.separators().is_some() {
+            self.check_timestamp(request.timestamp, clock)?
+        } else {
+            ""
+        };
+        let signature = request.signature.ok_or(SignatureError::MissingSignature)?;
+        let expected = signature
+            .trim()
+            .strip_prefix(self.scheme.prefix())
+            .and_then(decode_hex)
+            .ok_or(SignatureError::Mismatch)?;
+        self.mac(timestamp, request.body)
+            .verify_slice(&expected)
+            .map_err(|_| SignatureError::Mismatch)
+    }
+
+    /// Returns the trimmed timestamp when it lies within the tolerance.
+    fn check_timestamp<'a>(
+        &self,
+        timestamp: Option<&'a str>,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<&'a str, SignatureError> {
+        let trimmed = timestamp.ok_or(SignatureError::MissingTimestamp)?.trim();
+        let sent_at: i64 = trimmed
+            .parse()
+            .map_err(|_| SignatureError::InvalidTimestamp)?;
+        let age_secs = clock.utc().timestamp().abs_diff(sent_at);
+        if age_secs > self.tolerance.as_secs() {
+            return Err(SignatureError::StaleTimestamp { age_secs });
+        }
+        Ok(trimmed)
+    }
+
+    fn mac(&self, timestamp: &str, body: &[u8]) -> HmacSha256 {
+        let mut mac = self.key.clone();
+        if let Some((lead, separator)) = self.scheme.separators() {
+            mac.update(lead.as_bytes());
+            mac.update(timestamp.as_bytes());
+            mac.update(separator.as_bytes());
+        }
+        mac.update(body);
+        mac
+    }
+}
+
+impl fmt::Debug for WebhookSignatureVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookSignatureVerifier")
+            .field("scheme", &self.scheme)
+            .field("key", &"<redacted>")
+            .field("tolerance", &self.tolerance)
+            .finish()
+    }
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    text.as_bytes()
+        .chunks_exact(2)
+        .map(|pair| {
+            let digits = std::str::from_utf8(pair).ok()?;
+            u8::from_str_radix(digits, 16).ok()
+        })
+        .collect()
+}
@@ -0,0 +1,104 @@
+//! Slack Events API payload parsing.
+//!
+//! Slack posts a `url_verification` challenge when an events URL is
+//! configured and `event_callback` envelopes afterwards. Only plain user
+//! messages become [`InboundMessage`]s; edits, joins, bot posts, and other
+//! event types are acknowledged and ignored so the gateway never feeds its
+//! own replies back into a conversation.
+
+use super::InboundPayloadError;
+use crate::message::domain::{InboundMessage, InboundOrigin};
+use serde::Deserialize;
+
+/// Source name recorded on messages received from Slack.
+pub const SLACK_SOURCE: &str = "slack";
+
+/// What a Slack delivery asks the gateway to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlackDelivery {
+    /// Echo the challenge to confirm the events URL.
+    UrlVerification {
+        /// Challenge token to return.
+        challenge: String,
+    },
+    /// Ingest a user message.
+    Message(InboundMessage),
+    /// Acknowledge without ingesting.
+    Ignored,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum SlackEnvelope {
+    UrlVerification {
+        challenge: String,
+    },
+    EventCallback {
+        #[serde(default)]
+        event_id: Option<String>,
+        event: SlackEvent,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    subtype: Option<String>,
+    #[serde(default)]
+    bot_id: Option<String>,
+    #[serde(default)]
+    channel: Option<String>,
+    #[serde(default)]
+    user: Option<String>,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+/// Parses a Slack Events API request body.
+///
+/// # Errors
+///
+/// Returns [`InboundPayloadError::Malformed`] when the body is not a Slack
+/// events envelope.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::adapters::inbound::{SlackDelivery, parse_slack_delivery};
+///
+/// let body = br#"{"type":"url_verification","challenge":"3eZbrw1a"}"#;
+/// assert_eq!(
+///     parse_slack_delivery(body).expect("valid payload"),
+///     SlackDelivery::UrlVerification { challenge: "3eZbrw1a".to_owned() },
+/// );
+/// ```
+pub fn parse_slack_delivery(body: &[u8]) -> Result<SlackDelivery, InboundPayloadError> {
+    let envelope: SlackEnvelope = serde_json::from_slice(body)?;
+    Ok(match envelope {
+        SlackEnvelope::UrlVerification { challenge } => {
+            SlackDelivery::UrlVerification { challenge }
+        }
+        SlackEnvelope::EventCallback { event_id, event } => {
+            user_message(event, event_id).map_or(SlackDelivery::Ignored, SlackDelivery::Message)
+        }
+        SlackEnvelope::Other => SlackDelivery::Ignored,
+    })
+}
+
+fn user_message(event: SlackEvent, event_id: Option<String>) -> Option<InboundMessage> {
+    if event.kind != "message" || event.subtype.is_some() || event.bot_id.is_some() {
+        return None;
+    }
+    let text = event.text.filter(|text| !text.trim().is_empty())?;
+    let origin = InboundOrigin {
+        source: SLACK_SOURCE.to_owned(),
+        channel_id: event.channel?,
+        external_user_id: event.user?,
+        external_message_id: event_id,
+    };
+    Some(InboundMessage::new(origin, text))
+}
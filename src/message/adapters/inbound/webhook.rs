@@ -0,0 +1,48 @@
+//! Custom webhook payload parsing.
+//!
+//! Custom integrations post a small JSON document naming the channel, the
+//! author, and the message text:
+//!
+//! ```json
+//! { "channel_id": "ops-room", "user_id": "jdoe", "message_id": "42", "text": "Deploy?" }
+//! ```
+//!
+//! `message_id` is optional. The webhook name configured on the gateway
+//! becomes the message source.
+
+use super::InboundPayloadError;
+use crate::message::domain::{InboundMessage, InboundOrigin};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct WebhookPayload {
+    channel_id: String,
+    user_id: String,
+    #[serde(default)]
+    message_id: Option<String>,
+    text: String,
+}
+
+/// Parses a custom webhook request body into a message from `source`.
+///
+/// # Errors
+///
+/// Returns [`InboundPayloadError::Malformed`] when the body does not match
+/// the webhook payload, or [`InboundPayloadError::EmptyText`] when the
+/// message text is blank.
+pub fn parse_webhook_message(
+    source: &str,
+    body: &[u8],
+) -> Result<InboundMessage, InboundPayloadError> {
+    let payload: WebhookPayload = serde_json::from_slice(body)?;
+    if payload.text.trim().is_empty() {
+        return Err(InboundPayloadError::EmptyText);
+    }
+    let origin = InboundOrigin {
+        source: source.to_owned(),
+        channel_id: payload.channel_id,
+        external_user_id: payload.user_id,
+        external_message_id: payload.message_id,
+    };
+    Ok(InboundMessage::new(origin, payload.text))
+}
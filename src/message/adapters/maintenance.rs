@@ -0,0 +1,137 @@
+//! Repository decorator that refuses writes during maintenance.
+//!
+//! Services that write messages outside an HTTP request, such as inbound
+//! pollers and workers, reach the repository without passing the API's
+//! maintenance check. Wrapping their repository here makes every write
+//! consult the [`MaintenanceGate`] first, while reads pass straight through.
+
+use crate::context::RequestContext;
+use crate::maintenance::services::MaintenanceGate;
+use crate::message::{
+    domain::{
+        ContentHash, ConversationId, ConversationTokenUsage, Message, MessageId, MessageQuery,
+        MessageRedaction, SequenceNumber,
+    },
+    ports::repository::{MessageRepository, RepositoryResult},
+};
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// [`MessageRepository`] decorator that fails writes with
+/// [`RepositoryError::MaintenanceMode`](crate::message::error::RepositoryError::MaintenanceMode)
+/// while the cluster is in maintenance.
+pub struct MaintenanceGatedMessageRepository<R: ?Sized> {
+    inner: Arc<R>,
+    gate: Arc<MaintenanceGate>,
+}
+
+impl<R: MessageRepository + ?Sized> MaintenanceGatedMessageRepository<R> {
+    /// Wraps `inner` so its writes are refused while `gate` reports
+    /// maintenance.
+    #[must_use]
+    pub const fn new(inner: Arc<R>, gate: Arc<MaintenanceGate>) -> Self {
+        Self { inner, gate }
+    }
+
+    async fn ensure_writable(&self) -> RepositoryResult<()> {
+        Ok(self.gate.ensure_writable().await?)
+    }
+}
+
+#[async_trait]
+impl<R: MessageRepository + ?Sized> MessageRepository for MaintenanceGatedMessageRepository<R> {
+    async fn store(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<()> {
+        self.ensure_writable().await?;
+        self.inner.store(ctx, message).await
+    }
+
+    async fn append(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<Message> {
+        self.ensure_writable().await?;
+        self.inner.append(ctx, message).await
+    }
+
+    async fn store_batch(
+        &self,
+        ctx: &RequestContext,
+        messages: &[Message],
+    ) -> RepositoryResult<()> {
+        self.ensure_writable().await?;
+        self.inner.store_batch(ctx, messages).await
+    }
+
+    async fn redact(
+        &self,
+        ctx: &RequestContext,
+        redaction: &MessageRedaction,
+    ) -> RepositoryResult<Message> {
+        self.ensure_writable().await?;
+        self.inner.redact(ctx, redaction).await
+    }
+
+    async fn set_pinned(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+        pinned: bool,
+    ) -> RepositoryResult<Message> {
+        self.ensure_writable().await?;
+        self.inner.set_pinned(ctx, id, pinned).await
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+    ) -> RepositoryResult<Option<Message>> {
+        self.inner.find_by_id(ctx, id).await
+    }
+
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> RepositoryResult<Page<Message>> {
+        self.inner
+            .find_by_conversation(ctx, conversation_id, page)
+            .await
+    }
+
+    async fn query(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        query: &MessageQuery,
+    ) -> RepositoryResult<Page<Message>> {
+        self.inner.query(ctx, conversation_id, query).await
+    }
+
+    async fn next_sequence_number(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<SequenceNumber> {
+        self.inner.next_sequence_number(ctx, conversation_id).await
+    }
+
+    async fn token_usage(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<ConversationTokenUsage> {
+        self.inner.token_usage(ctx, conversation_id).await
+    }
+
+    async fn exists(&self, ctx: &RequestContext, id: MessageId) -> RepositoryResult<bool> {
+        self.inner.exists(ctx, id).await
+    }
+
+    async fn unreferenced_blobs(
+        &self,
+        ctx: &RequestContext,
+        hashes: &[ContentHash],
+    ) -> RepositoryResult<Vec<ContentHash>> {
+        self.inner.unreferenced_blobs(ctx, hashes).await
+    }
+}
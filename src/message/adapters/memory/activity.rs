@@ -0,0 +1,71 @@
+//! In-memory implementation of the `ConversationActivityPort`.
+//!
+//! Computes activity statistics by scanning the messages and handoffs held by
+//! the companion in-memory adapters. Suitable for unit tests only.
+
+use async_trait::async_trait;
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ActivityQuery, ConversationActivity},
+    ports::{
+        activity::{ActivityError, ActivityResult, ConversationActivityPort},
+        handoff::AgentHandoffPort,
+        repository::MessageRepository,
+    },
+};
+use crate::pagination::collect_pages;
+
+/// In-memory implementation of [`ConversationActivityPort`].
+///
+/// Reads from a message repository and a handoff port, typically clones of
+/// [`InMemoryMessageRepository`](super::InMemoryMessageRepository) and
+/// [`InMemoryHandoffAdapter`](super::InMemoryHandoffAdapter) that share state
+/// with the adapters used by the code under test.
+#[derive(Debug, Clone)]
+pub struct InMemoryConversationActivityAdapter<M, H> {
+    messages: M,
+    handoffs: H,
+}
+
+impl<M, H> InMemoryConversationActivityAdapter<M, H>
+where
+    M: MessageRepository,
+    H: AgentHandoffPort,
+{
+    /// Creates a new adapter reading from the given stores.
+    #[must_use]
+    pub const fn new(messages: M, handoffs: H) -> Self {
+        Self { messages, handoffs }
+    }
+}
+
+#[async_trait]
+impl<M, H> ConversationActivityPort for InMemoryConversationActivityAdapter<M, H>
+where
+    M: MessageRepository,
+    H: AgentHandoffPort,
+{
+    async fn conversation_activity(
+        &self,
+        ctx: &RequestContext,
+        query: &ActivityQuery,
+    ) -> ActivityResult<ConversationActivity> {
+        ActivityError::check_window(query)?;
+
+        let messages = collect_pages(|page| {
+            self.messages
+                .find_by_conversation(ctx, query.conversation_id, page)
+        })
+        .await
+        .map_err(ActivityError::persistence)?;
+        let handoffs = collect_pages(|page| {
+            self.handoffs
+                .list_handoffs_for_conversation(ctx, query.conversation_id, page)
+        })
+        .await
+        .map_err(ActivityError::persistence)?;
+
+        Ok(ConversationActivity::aggregate(query, &messages, &handoffs))
+    }
+}
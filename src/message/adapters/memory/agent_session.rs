@@ -3,16 +3,18 @@
 //! Provides a simple, thread-safe repository for unit testing
 //! without database dependencies.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock, RwLockReadGuard};
 
 use async_trait::async_trait;
 
+use super::InMemoryConversationRepository;
 use crate::context::RequestContext;
 use crate::message::{
     domain::{AgentSession, AgentSessionId, AgentSessionState, ConversationId},
     ports::agent_session::{AgentSessionRepository, SessionError, SessionResult},
 };
+use crate::pagination::{Cursor, Page, PageRequest};
 
 /// In-memory implementation of [`AgentSessionRepository`].
 ///
@@ -20,6 +22,7 @@ use crate::message::{
 #[derive(Debug, Default, Clone)]
 pub struct InMemoryAgentSessionRepository {
     sessions: Arc<RwLock<HashMap<AgentSessionId, AgentSession>>>,
+    conversations: Option<InMemoryConversationRepository>,
 }
 
 impl InMemoryAgentSessionRepository {
@@ -29,6 +32,13 @@ impl InMemoryAgentSessionRepository {
         Self::default()
     }
 
+    /// Rejects new sessions for conversations archived in `conversations`.
+    #[must_use]
+    pub fn with_conversations(mut self, conversations: InMemoryConversationRepository) -> Self {
+        self.conversations = Some(conversations);
+        self
+    }
+
     /// Returns the number of stored sessions.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -51,7 +61,7 @@ impl InMemoryAgentSessionRepository {
             .map_err(|e| SessionError::persistence(std::io::Error::other(e.to_string())))
     }
 
-    fn upsert_with_check<F>(&self, session: &AgentSession, validate: F) -> SessionResult<()>
+    fn upsert_with_check<F>(&self, session: AgentSession, validate: F) -> SessionResult<()>
     where
         F: FnOnce(&HashMap<AgentSessionId, AgentSession>) -> SessionResult<()>,
     {
@@ -62,9 +72,24 @@ impl InMemoryAgentSessionRepository {
 
         validate(&guard)?;
 
-        guard.insert(session.session_id, session.clone());
+        guard.insert(session.session_id, session);
         Ok(())
     }
+
+    /// Removes every session of `conversations`, returning how many there
+    /// were.
+    pub(crate) fn remove_for_conversations(
+        &self,
+        conversations: &HashSet<ConversationId>,
+    ) -> SessionResult<usize> {
+        let mut guard = self
+            .sessions
+            .write()
+            .map_err(|e| SessionError::persistence(std::io::Error::other(e.to_string())))?;
+        let before = guard.len();
+        guard.retain(|_, s| !conversations.contains(&s.conversation_id));
+        Ok(before - guard.len())
+    }
 }
 
 /// Returns `Err(ActiveSessionExists)` when `sessions` already contains an
@@ -87,13 +112,25 @@ fn check_active_session(
     }
 }
 
+/// Keyset position of a session in start order.
+fn session_position(session: &AgentSession) -> Cursor {
+    Cursor::at_timestamp(session.started_at, session.session_id.into_inner())
+}
+
 #[async_trait]
 impl AgentSessionRepository for InMemoryAgentSessionRepository {
-    async fn store(&self, _ctx: &RequestContext, session: &AgentSession) -> SessionResult<()> {
+    async fn store(&self, ctx: &RequestContext, session: &AgentSession) -> SessionResult<()> {
         let session_id = session.session_id;
         let conversation_id = session.conversation_id;
+        if let Some(conversations) = &self.conversations
+            && conversations
+                .is_archived(ctx.tenant_id(), conversation_id)
+                .map_err(SessionError::persistence)?
+        {
+            return Err(SessionError::ConversationArchived(conversation_id));
+        }
         let is_active = session.state == AgentSessionState::Active;
-        self.upsert_with_check(session, |sessions| {
+        self.upsert_with_check(session.clone(), |sessions| {
             if sessions.contains_key(&session_id) {
                 return Err(SessionError::Duplicate(session_id));
             }
@@ -108,9 +145,21 @@ impl AgentSessionRepository for InMemoryAgentSessionRepository {
         let session_id = session.session_id;
         let conversation_id = session.conversation_id;
         let is_active = session.state == AgentSessionState::Active;
-        self.upsert_with_check(session, |sessions| {
-            if !sessions.contains_key(&session_id) {
-                return Err(SessionError::NotFound(session_id));
+        let expected = session.version;
+        let next = AgentSession {
+            version: expected.saturating_add(1),
+            ..session.clone()
+        };
+        self.upsert_with_check(next, |sessions| {
+            let stored = sessions
+                .get(&session_id)
+                .ok_or(SessionError::NotFound(session_id))?;
+            if stored.version != expected {
+                return Err(SessionError::ConcurrentModification {
+                    session_id,
+                    expected,
+                    actual: stored.version,
+                });
             }
             if is_active {
                 check_active_session(sessions, conversation_id, Some(session_id))?;
@@ -144,7 +193,8 @@ impl AgentSessionRepository for InMemoryAgentSessionRepository {
         &self,
         _ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> SessionResult<Vec<AgentSession>> {
+        page: PageRequest,
+    ) -> SessionResult<Page<AgentSession>> {
         let guard = self.read_locked()?;
         let mut sessions: Vec<AgentSession> = guard
             .values()
@@ -152,9 +202,23 @@ impl AgentSessionRepository for InMemoryAgentSessionRepository {
             .cloned()
             .collect();
 
-        // Sort by start time
-        sessions.sort_by_key(|s| s.started_at);
+        sessions.sort_by_key(|s| (s.started_at, s.session_id.into_inner()));
+        Ok(Page::from_ordered(sessions, page, session_position))
+    }
 
-        Ok(sessions)
+    async fn find_active_by_backend(
+        &self,
+        _ctx: &RequestContext,
+        agent_backend: &str,
+        page: PageRequest,
+    ) -> SessionResult<Page<AgentSession>> {
+        let guard = self.read_locked()?;
+        let mut sessions: Vec<AgentSession> = guard
+            .values()
+            .filter(|s| s.agent_backend == agent_backend && s.state == AgentSessionState::Active)
+            .cloned()
+            .collect();
+        sessions.sort_by_key(|s| (s.started_at, s.session_id.into_inner()));
+        Ok(Page::from_ordered(sessions, page, session_position))
     }
 }
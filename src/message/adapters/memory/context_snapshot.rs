@@ -3,17 +3,19 @@
 //! Provides a simple, thread-safe adapter for unit testing
 //! without database dependencies.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::context::RequestContext;
 use crate::message::{
-    domain::{AgentSessionId, ContextWindowSnapshot, ConversationId},
+    domain::{AgentSessionId, ContextWindowSnapshot, ConversationId, SequenceNumber, SnapshotType},
     ports::context_snapshot::{ContextSnapshotPort, SnapshotError, SnapshotResult},
 };
+use crate::pagination::{Cursor, Page, PageRequest};
 
 /// In-memory implementation of [`ContextSnapshotPort`].
 ///
@@ -49,6 +51,76 @@ impl InMemoryContextSnapshotAdapter {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns the conversation's latest snapshot whose range ends at or
+    /// before `sequence_number`.
+    pub(crate) fn latest_through(
+        &self,
+        conversation_id: ConversationId,
+        sequence_number: SequenceNumber,
+    ) -> SnapshotResult<Option<Uuid>> {
+        let guard = self
+            .snapshots
+            .read()
+            .map_err(|e| SnapshotError::persistence(std::io::Error::other(e.to_string())))?;
+
+        Ok(guard
+            .values()
+            .filter(|s| s.conversation_id == conversation_id)
+            .filter(|s| s.sequence_range.end <= sequence_number)
+            .max_by_key(|s| s.captured_at)
+            .map(|s| s.snapshot_id))
+    }
+
+    /// Returns the identifiers of the snapshots of `conversations` captured
+    /// before `cutoff`, oldest first.
+    pub(crate) fn captured_before(
+        &self,
+        conversations: &HashSet<ConversationId>,
+        cutoff: DateTime<Utc>,
+    ) -> SnapshotResult<Vec<Uuid>> {
+        let guard = self
+            .snapshots
+            .read()
+            .map_err(|e| SnapshotError::persistence(std::io::Error::other(e.to_string())))?;
+
+        let mut expired: Vec<(DateTime<Utc>, Uuid)> = guard
+            .values()
+            .filter(|s| conversations.contains(&s.conversation_id) && s.captured_at < cutoff)
+            .map(|s| (s.captured_at, s.snapshot_id))
+            .collect();
+        expired.sort_unstable();
+        Ok(expired.into_iter().map(|(_, id)| id).collect())
+    }
+
+    /// Removes the snapshots with the given identifiers, and the
+    /// increments built on them.
+    pub(crate) fn remove(&self, ids: &[Uuid]) -> SnapshotResult<()> {
+        let mut guard = self
+            .snapshots
+            .write()
+            .map_err(|e| SnapshotError::persistence(std::io::Error::other(e.to_string())))?;
+        for id in ids {
+            guard.remove(id);
+        }
+        remove_orphaned_increments(&mut guard);
+        Ok(())
+    }
+
+    /// Removes every snapshot of `conversations`, returning how many there
+    /// were.
+    pub(crate) fn remove_for_conversations(
+        &self,
+        conversations: &HashSet<ConversationId>,
+    ) -> SnapshotResult<usize> {
+        let mut guard = self
+            .snapshots
+            .write()
+            .map_err(|e| SnapshotError::persistence(std::io::Error::other(e.to_string())))?;
+        let before = guard.len();
+        guard.retain(|_, s| !conversations.contains(&s.conversation_id));
+        Ok(before - guard.len())
+    }
 }
 
 #[async_trait]
@@ -88,7 +160,8 @@ impl ContextSnapshotPort for InMemoryContextSnapshotAdapter {
         &self,
         _ctx: &RequestContext,
         session_id: AgentSessionId,
-    ) -> SnapshotResult<Vec<ContextWindowSnapshot>> {
+        page: PageRequest,
+    ) -> SnapshotResult<Page<ContextWindowSnapshot>> {
         let guard = self
             .snapshots
             .read()
@@ -100,8 +173,10 @@ impl ContextSnapshotPort for InMemoryContextSnapshotAdapter {
             .cloned()
             .collect();
 
-        snapshots.sort_by_key(|s| s.captured_at);
-        Ok(snapshots)
+        snapshots.sort_by_key(|s| (s.captured_at, s.snapshot_id));
+        Ok(Page::from_ordered(snapshots, page, |s| {
+            Cursor::at_timestamp(s.captured_at, s.snapshot_id)
+        }))
     }
 
     async fn find_latest_snapshot(
@@ -120,4 +195,90 @@ impl ContextSnapshotPort for InMemoryContextSnapshotAdapter {
             .max_by_key(|s| s.captured_at)
             .cloned())
     }
+
+    async fn find_latest_compaction(
+        &self,
+        _ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> SnapshotResult<Option<ContextWindowSnapshot>> {
+        let guard = self
+            .snapshots
+            .read()
+            .map_err(|e| SnapshotError::persistence(std::io::Error::other(e.to_string())))?;
+
+        Ok(guard
+            .values()
+            .filter(|s| s.conversation_id == conversation_id)
+            .filter(|s| s.snapshot_type == SnapshotType::Compaction)
+            .max_by_key(|s| s.captured_at)
+            .cloned())
+    }
+
+    async fn delete_snapshot(
+        &self,
+        _ctx: &RequestContext,
+        snapshot_id: Uuid,
+    ) -> SnapshotResult<()> {
+        let mut guard = self
+            .snapshots
+            .write()
+            .map_err(|e| SnapshotError::persistence(std::io::Error::other(e.to_string())))?;
+
+        guard
+            .remove(&snapshot_id)
+            .ok_or(SnapshotError::NotFound(snapshot_id))?;
+        remove_orphaned_increments(&mut guard);
+        Ok(())
+    }
+
+    async fn prune_before(
+        &self,
+        _ctx: &RequestContext,
+        snapshot_type: SnapshotType,
+        cutoff: DateTime<Utc>,
+    ) -> SnapshotResult<usize> {
+        let mut guard = self
+            .snapshots
+            .write()
+            .map_err(|e| SnapshotError::persistence(std::io::Error::other(e.to_string())))?;
+
+        let expired =
+            |s: &ContextWindowSnapshot| s.snapshot_type == snapshot_type && s.captured_at < cutoff;
+        let mut protected = HashSet::new();
+        let mut pending: Vec<Uuid> = guard
+            .values()
+            .filter(|s| !expired(s))
+            .filter_map(|s| s.parent_snapshot_id)
+            .collect();
+        while let Some(id) = pending.pop() {
+            if protected.insert(id) {
+                pending.extend(guard.get(&id).and_then(|s| s.parent_snapshot_id));
+            }
+        }
+        let before = guard.len();
+        guard.retain(|id, s| !expired(s) || protected.contains(id));
+        remove_orphaned_increments(&mut guard);
+        Ok(before - guard.len())
+    }
+}
+
+/// Removes increments whose parent is gone, as the `PostgreSQL` schema's
+/// cascading foreign key does.
+fn remove_orphaned_increments(snapshots: &mut HashMap<Uuid, ContextWindowSnapshot>) {
+    loop {
+        let orphans: Vec<Uuid> = snapshots
+            .values()
+            .filter(|s| {
+                s.parent_snapshot_id
+                    .is_some_and(|parent| !snapshots.contains_key(&parent))
+            })
+            .map(|s| s.snapshot_id)
+            .collect();
+        if orphans.is_empty() {
+            return;
+        }
+        for id in orphans {
+            snapshots.remove(&id);
+        }
+    }
 }
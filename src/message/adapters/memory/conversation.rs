@@ -1,20 +1,28 @@
 //! In-memory implementation of the conversation repository.
 
-use crate::context::{RequestContext, TenantId};
+use crate::context::{RequestContext, TenantId, UserId};
 use crate::message::{
-    domain::{Conversation, ConversationId},
-    ports::conversation::{
-        ConversationRepository, ConversationRepositoryError, ConversationRepositoryResult,
+    domain::{Conversation, ConversationId, ConversationLabel, ConversationLabelEvent},
+    ports::{
+        conversation::{
+            ConversationRepository, ConversationRepositoryError, ConversationRepositoryResult,
+        },
+        transfer::{ConversationTransferError, ConversationTransferResult},
     },
 };
+use crate::pagination::{Cursor, Page, PageRequest};
 use async_trait::async_trait;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
+type TenantConversations = HashMap<TenantId, HashMap<ConversationId, Conversation>>;
+
 /// Thread-safe in-memory conversation repository.
 #[derive(Debug, Clone, Default)]
 pub struct InMemoryConversationRepository {
-    conversations: Arc<RwLock<HashMap<TenantId, HashMap<ConversationId, Conversation>>>>,
+    conversations: Arc<RwLock<TenantConversations>>,
+    label_events: Arc<RwLock<HashMap<TenantId, Vec<ConversationLabelEvent>>>>,
+    creators: Arc<RwLock<HashMap<ConversationId, UserId>>>,
 }
 
 impl InMemoryConversationRepository {
@@ -23,6 +31,95 @@ impl InMemoryConversationRepository {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns `true` when the conversation exists and is archived.
+    ///
+    /// In-memory message, session, and handoff adapters attached to this
+    /// repository use this to reject writes against archived conversations.
+    pub(crate) fn is_archived(
+        &self,
+        tenant_id: TenantId,
+        conversation_id: ConversationId,
+    ) -> Result<bool, std::io::Error> {
+        let tenants = self
+            .conversations
+            .read()
+            .map_err(|err| std::io::Error::other(err.to_string()))?;
+        Ok(tenants
+            .get(&tenant_id)
+            .and_then(|conversations| conversations.get(&conversation_id))
+            .is_some_and(Conversation::is_archived))
+    }
+
+    /// Returns the identifiers of the tenant's conversations.
+    ///
+    /// The in-memory retention repository uses this to confine purges of
+    /// the adapters that are not tenant-scoped to one tenant.
+    pub(crate) fn conversation_ids(
+        &self,
+        tenant_id: TenantId,
+    ) -> Result<HashSet<ConversationId>, std::io::Error> {
+        let tenants = self
+            .conversations
+            .read()
+            .map_err(|err| std::io::Error::other(err.to_string()))?;
+        Ok(tenants
+            .get(&tenant_id)
+            .map(|conversations| conversations.keys().copied().collect())
+            .unwrap_or_default())
+    }
+
+    /// Returns the tenant's conversations stored by `user_id`.
+    ///
+    /// This stands in for the audit trail the `PostgreSQL` adapter keeps,
+    /// which the in-memory erasure repository uses to find a subject's
+    /// conversations.
+    pub(crate) fn created_by(
+        &self,
+        tenant_id: TenantId,
+        user_id: UserId,
+    ) -> Result<HashSet<ConversationId>, std::io::Error> {
+        let mut conversations = self.conversation_ids(tenant_id)?;
+        let creators = self
+            .creators
+            .read()
+            .map_err(|err| std::io::Error::other(err.to_string()))?;
+        conversations.retain(|id| creators.get(id) == Some(&user_id));
+        Ok(conversations)
+    }
+
+    /// Moves a conversation from `source` to `target`, refusing
+    /// conversations linked to a task.
+    pub(crate) fn move_to_tenant(
+        &self,
+        conversation_id: ConversationId,
+        source: TenantId,
+        target: TenantId,
+    ) -> ConversationTransferResult<()> {
+        let mut tenants = self.conversations.write().map_err(|err| {
+            ConversationTransferError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        let source_conversations = tenants
+            .get_mut(&source)
+            .ok_or(ConversationTransferError::NotFound(conversation_id))?;
+        let conversation = source_conversations
+            .get(&conversation_id)
+            .ok_or(ConversationTransferError::NotFound(conversation_id))?;
+        if let Some(task_id) = conversation.task_id() {
+            return Err(ConversationTransferError::LinkedToTask {
+                conversation_id,
+                task_id,
+            });
+        }
+        let moved = source_conversations
+            .remove(&conversation_id)
+            .ok_or(ConversationTransferError::NotFound(conversation_id))?;
+        tenants
+            .entry(target)
+            .or_default()
+            .insert(conversation_id, moved);
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -32,9 +129,7 @@ impl ConversationRepository for InMemoryConversationRepository {
         ctx: &RequestContext,
         conversation: &Conversation,
     ) -> ConversationRepositoryResult<()> {
-        let mut tenants = self.conversations.write().map_err(|err| {
-            ConversationRepositoryError::persistence(std::io::Error::other(err.to_string()))
-        })?;
+        let mut tenants = self.conversations.write().map_err(lock_error)?;
         let tenant_conversations = tenants.entry(ctx.tenant_id()).or_default();
         if tenant_conversations.contains_key(&conversation.id()) {
             return Err(ConversationRepositoryError::DuplicateConversation(
@@ -42,6 +137,35 @@ impl ConversationRepository for InMemoryConversationRepository {
             ));
         }
         tenant_conversations.insert(conversation.id(), conversation.clone());
+        self.creators
+            .write()
+            .map_err(lock_error)?
+            .insert(conversation.id(), ctx.user_id());
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        ctx: &RequestContext,
+        conversation: &Conversation,
+    ) -> ConversationRepositoryResult<()> {
+        let mut tenants = self.conversations.write().map_err(lock_error)?;
+        replace_stored(&mut tenants, ctx.tenant_id(), conversation)
+    }
+
+    async fn update_labels(
+        &self,
+        ctx: &RequestContext,
+        conversation: &Conversation,
+        event: &ConversationLabelEvent,
+    ) -> ConversationRepositoryResult<()> {
+        let mut tenants = self.conversations.write().map_err(lock_error)?;
+        let mut label_events = self.label_events.write().map_err(lock_error)?;
+        replace_stored(&mut tenants, ctx.tenant_id(), conversation)?;
+        label_events
+            .entry(ctx.tenant_id())
+            .or_default()
+            .push(event.clone());
         Ok(())
     }
 
@@ -50,11 +174,79 @@ impl ConversationRepository for InMemoryConversationRepository {
         ctx: &RequestContext,
         conversation_id: ConversationId,
     ) -> ConversationRepositoryResult<Option<Conversation>> {
-        let tenants = self.conversations.read().map_err(|err| {
-            ConversationRepositoryError::persistence(std::io::Error::other(err.to_string()))
-        })?;
+        let tenants = self.conversations.read().map_err(lock_error)?;
         Ok(tenants
             .get(&ctx.tenant_id())
             .and_then(|conversations| conversations.get(&conversation_id).cloned()))
     }
+
+    async fn find_by_label(
+        &self,
+        ctx: &RequestContext,
+        label: &ConversationLabel,
+        page: PageRequest,
+    ) -> ConversationRepositoryResult<Page<Conversation>> {
+        let tenants = self.conversations.read().map_err(lock_error)?;
+        let mut labelled = tenants
+            .get(&ctx.tenant_id())
+            .into_iter()
+            .flat_map(HashMap::values)
+            .filter(|conversation| conversation.label(label.key()) == Some(label.value()))
+            .cloned()
+            .collect::<Vec<_>>();
+        labelled.sort_by_key(|conversation| {
+            (conversation.created_at(), conversation.id().into_inner())
+        });
+        Ok(Page::from_ordered(labelled, page, |conversation| {
+            Cursor::at_timestamp(conversation.created_at(), conversation.id().into_inner())
+        }))
+    }
+
+    async fn find_label_events(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> ConversationRepositoryResult<Page<ConversationLabelEvent>> {
+        let label_events = self.label_events.read().map_err(lock_error)?;
+        let mut history = label_events
+            .get(&ctx.tenant_id())
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .filter(|event| event.change.conversation_id == conversation_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        history.sort_by_key(|event| (event.occurred_at, event.id));
+        Ok(Page::from_ordered(history, page, |event| {
+            Cursor::at_timestamp(event.occurred_at, event.id)
+        }))
+    }
+}
+
+/// Replaces the stored copy of `conversation` when its version matches,
+/// bumping the version.
+fn replace_stored(
+    tenants: &mut TenantConversations,
+    tenant_id: TenantId,
+    conversation: &Conversation,
+) -> ConversationRepositoryResult<()> {
+    let stored = tenants
+        .get_mut(&tenant_id)
+        .and_then(|conversations| conversations.get_mut(&conversation.id()))
+        .ok_or(ConversationRepositoryError::NotFound(conversation.id()))?;
+    if stored.version() != conversation.version() {
+        return Err(ConversationRepositoryError::ConcurrentModification {
+            conversation_id: conversation.id(),
+            expected: conversation.version(),
+            actual: stored.version(),
+        });
+    }
+    *stored = conversation
+        .clone()
+        .with_version(conversation.version().saturating_add(1));
+    Ok(())
+}
+
+fn lock_error(err: impl std::fmt::Display) -> ConversationRepositoryError {
+    ConversationRepositoryError::persistence(std::io::Error::other(err.to_string()))
 }
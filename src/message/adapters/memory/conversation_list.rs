@@ -0,0 +1,160 @@
+//! In-memory implementation of the `ConversationListPort`.
+//!
+//! Holds conversation summaries and task labels per tenant and mirrors the
+//! updates the `PostgreSQL` projection applies through triggers. Suitable for
+//! unit tests only.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{Conversation, ConversationId, ConversationListQuery, ConversationSummary, Message},
+    ports::conversation_list::{
+        ConversationListError, ConversationListPort, ConversationListResult,
+    },
+};
+use crate::pagination::Page;
+
+#[derive(Debug, Default)]
+struct TenantSummaries {
+    summaries: HashMap<ConversationId, ConversationSummary>,
+    task_labels: HashMap<Uuid, Vec<String>>,
+}
+
+/// In-memory implementation of [`ConversationListPort`].
+///
+/// Tests feed it the conversations, messages, task labels, and task links
+/// that the database projection would observe.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryConversationSummaries {
+    tenants: Arc<RwLock<HashMap<TenantId, TenantSummaries>>>,
+}
+
+impl InMemoryConversationSummaries {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a created or updated conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationListError::Persistence`] if the store lock is
+    /// poisoned.
+    pub fn record_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation: &Conversation,
+    ) -> ConversationListResult<()> {
+        self.with_tenant(ctx, |tenant| {
+            tenant
+                .summaries
+                .entry(conversation.id())
+                .and_modify(|summary| {
+                    summary.state = conversation.state();
+                    summary.last_activity_at =
+                        summary.last_activity_at.max(conversation.updated_at());
+                })
+                .or_insert_with(|| ConversationSummary::from_conversation(conversation));
+        })
+    }
+
+    /// Records a message appended to a known conversation.
+    ///
+    /// Messages for unknown conversations are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationListError::Persistence`] if the store lock is
+    /// poisoned.
+    pub fn record_message(
+        &self,
+        ctx: &RequestContext,
+        message: &Message,
+    ) -> ConversationListResult<()> {
+        self.with_tenant(ctx, |tenant| {
+            if let Some(summary) = tenant.summaries.get_mut(&message.conversation_id()) {
+                summary.record_message(message);
+            }
+        })
+    }
+
+    /// Records the issue labels of a task.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationListError::Persistence`] if the store lock is
+    /// poisoned.
+    pub fn record_task_labels(
+        &self,
+        ctx: &RequestContext,
+        task_id: Uuid,
+        labels: Vec<String>,
+    ) -> ConversationListResult<()> {
+        self.with_tenant(ctx, |tenant| {
+            tenant.task_labels.insert(task_id, labels);
+        })
+    }
+
+    /// Links a known conversation to a task, copying the task's labels.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationListError::Persistence`] if the store lock is
+    /// poisoned.
+    pub fn link_task(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        task_id: Uuid,
+    ) -> ConversationListResult<()> {
+        self.with_tenant(ctx, |tenant| {
+            let labels = tenant
+                .task_labels
+                .get(&task_id)
+                .cloned()
+                .unwrap_or_default();
+            if let Some(summary) = tenant.summaries.get_mut(&conversation_id) {
+                summary.link_task(task_id, labels);
+            }
+        })
+    }
+
+    fn with_tenant(
+        &self,
+        ctx: &RequestContext,
+        update: impl FnOnce(&mut TenantSummaries),
+    ) -> ConversationListResult<()> {
+        let mut tenants = self.tenants.write().map_err(|err| {
+            ConversationListError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        update(tenants.entry(ctx.tenant_id()).or_default());
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ConversationListPort for InMemoryConversationSummaries {
+    async fn list_conversations(
+        &self,
+        ctx: &RequestContext,
+        query: &ConversationListQuery,
+    ) -> ConversationListResult<Page<ConversationSummary>> {
+        ConversationListError::check_query(query)?;
+
+        let tenants = self.tenants.read().map_err(|err| {
+            ConversationListError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        let summaries = tenants
+            .get(&ctx.tenant_id())
+            .into_iter()
+            .flat_map(|tenant| tenant.summaries.values())
+            .cloned();
+        Ok(query.paginate(summaries))
+    }
+}
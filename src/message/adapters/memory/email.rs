@@ -0,0 +1,106 @@
+//! In-memory implementations of the `Mailbox` and `EmailNotifier` ports.
+//!
+//! [`InMemoryMailbox`] stands in for an IMAP inbox and
+//! [`InMemoryEmailNotifier`] records sent email instead of delivering it.
+
+use crate::message::{
+    domain::{InboundEmail, OutboundEmail},
+    ports::email::{EmailNotifier, EmailTransportError, EmailTransportResult, Mailbox},
+};
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+fn lock_error(err: impl std::fmt::Display) -> EmailTransportError {
+    EmailTransportError::transport(std::io::Error::other(err.to_string()))
+}
+
+#[derive(Debug, Default)]
+struct MailboxState {
+    emails: Vec<InboundEmail>,
+    seen: HashSet<String>,
+}
+
+/// Thread-safe in-memory mailbox.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::{adapters::memory::InMemoryMailbox, domain::InboundEmail};
+///
+/// let mailbox = InMemoryMailbox::new();
+/// mailbox.deliver(InboundEmail::new("<a1@example.com>", "jdoe@example.com", "Hi", "Hello"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryMailbox {
+    state: Arc<RwLock<MailboxState>>,
+}
+
+impl InMemoryMailbox {
+    /// Creates an empty mailbox.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a received email.
+    ///
+    /// A poisoned lock drops the email.
+    pub fn deliver(&self, email: InboundEmail) {
+        if let Ok(mut state) = self.state.write() {
+            state.emails.push(email);
+        }
+    }
+}
+
+#[async_trait]
+impl Mailbox for InMemoryMailbox {
+    async fn fetch_unseen(&self) -> EmailTransportResult<Vec<InboundEmail>> {
+        let state = self.state.read().map_err(lock_error)?;
+        Ok(state
+            .emails
+            .iter()
+            .filter(|email| !state.seen.contains(&email.message_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn mark_seen(&self, message_id: &str) -> EmailTransportResult<()> {
+        let mut state = self.state.write().map_err(lock_error)?;
+        state.seen.insert(message_id.to_owned());
+        Ok(())
+    }
+}
+
+/// Email notifier that records sent email.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryEmailNotifier {
+    sent: Arc<RwLock<Vec<OutboundEmail>>>,
+}
+
+impl InMemoryEmailNotifier {
+    /// Creates a notifier that has sent nothing.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the emails sent so far, oldest first.
+    ///
+    /// Returns an empty list if the internal lock is poisoned.
+    #[must_use]
+    pub fn sent(&self) -> Vec<OutboundEmail> {
+        self.sent
+            .read()
+            .map(|sent| sent.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl EmailNotifier for InMemoryEmailNotifier {
+    async fn send(&self, email: &OutboundEmail) -> EmailTransportResult<()> {
+        self.sent.write().map_err(lock_error)?.push(email.clone());
+        Ok(())
+    }
+}
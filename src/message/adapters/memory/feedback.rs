@@ -0,0 +1,79 @@
+//! In-memory implementation of the `MessageFeedbackRepository` port.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{FeedbackSummary, MessageFeedback, MessageId},
+    ports::feedback::{FeedbackError, FeedbackResult, MessageFeedbackRepository},
+};
+use crate::pagination::{Cursor, Page, PageRequest};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Thread-safe in-memory feedback repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryMessageFeedbackRepository {
+    feedback: Arc<RwLock<HashMap<TenantId, Vec<MessageFeedback>>>>,
+}
+
+impl InMemoryMessageFeedbackRepository {
+    /// Creates an empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn tenant_feedback(&self, ctx: &RequestContext) -> FeedbackResult<Vec<MessageFeedback>> {
+        let tenants = self
+            .feedback
+            .read()
+            .map_err(|err| FeedbackError::persistence(std::io::Error::other(err.to_string())))?;
+        Ok(tenants.get(&ctx.tenant_id()).cloned().unwrap_or_default())
+    }
+}
+
+#[async_trait]
+impl MessageFeedbackRepository for InMemoryMessageFeedbackRepository {
+    async fn upsert(
+        &self,
+        ctx: &RequestContext,
+        feedback: &MessageFeedback,
+    ) -> FeedbackResult<MessageFeedback> {
+        let mut tenants = self
+            .feedback
+            .write()
+            .map_err(|err| FeedbackError::persistence(std::io::Error::other(err.to_string())))?;
+        let records = tenants.entry(ctx.tenant_id()).or_default();
+        let earlier = records.iter().position(|record| {
+            record.message_id() == feedback.message_id() && record.author() == feedback.author()
+        });
+        let Some(index) = earlier else {
+            records.push(feedback.clone());
+            return Ok(feedback.clone());
+        };
+        let replaced = records.remove(index);
+        let stored = feedback.clone().with_id(replaced.id());
+        records.push(stored.clone());
+        Ok(stored)
+    }
+
+    async fn list_for_message(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+        page: PageRequest,
+    ) -> FeedbackResult<Page<MessageFeedback>> {
+        let mut records = self.tenant_feedback(ctx)?;
+        records.retain(|record| record.message_id() == message_id);
+        records.sort_by_key(|record| (record.submitted_at(), record.id().into_inner()));
+        Ok(Page::from_ordered(records, page, feedback_position))
+    }
+
+    async fn summarize(&self, ctx: &RequestContext) -> FeedbackResult<Vec<FeedbackSummary>> {
+        Ok(FeedbackSummary::aggregate(&self.tenant_feedback(ctx)?))
+    }
+}
+
+fn feedback_position(feedback: &MessageFeedback) -> Cursor {
+    Cursor::at_timestamp(feedback.submitted_at(), feedback.id().into_inner())
+}
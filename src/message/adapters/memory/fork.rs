@@ -0,0 +1,154 @@
+//! In-memory implementation of the conversation fork port.
+//!
+//! The fork's conversation and copied messages are written through the
+//! attached in-memory repositories, and the parent's snapshot is looked up
+//! in the attached snapshot adapter. Writes are not transactional, which is
+//! acceptable for tests.
+
+use super::{
+    InMemoryContextSnapshotAdapter, InMemoryConversationRepository, InMemoryMessageRepository,
+};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationFork, ConversationId, Message},
+    ports::{
+        conversation::ConversationRepository,
+        fork::{ConversationForkError, ConversationForkRepository, ConversationForkResult},
+        repository::MessageRepository,
+    },
+};
+use crate::pagination::{Cursor, Page, PageRequest, collect_pages};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// In-memory conversation fork adapter.
+///
+/// Like the message and snapshot adapters it writes through, fork
+/// provenance is not tenant-scoped.
+#[derive(Debug, Clone)]
+pub struct InMemoryConversationForkAdapter {
+    conversations: InMemoryConversationRepository,
+    messages: InMemoryMessageRepository,
+    snapshots: InMemoryContextSnapshotAdapter,
+    forks: Arc<RwLock<HashMap<ConversationId, ConversationFork>>>,
+}
+
+impl InMemoryConversationForkAdapter {
+    /// Creates an adapter that forks conversations held in
+    /// `conversations` and `messages`, linking snapshots from `snapshots`.
+    #[must_use]
+    pub fn new(
+        conversations: InMemoryConversationRepository,
+        messages: InMemoryMessageRepository,
+        snapshots: InMemoryContextSnapshotAdapter,
+    ) -> Self {
+        Self {
+            conversations,
+            messages,
+            snapshots,
+            forks: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the parent's messages the fork shares, refusing a fork point
+    /// the parent has no message at.
+    async fn shared_history(
+        &self,
+        ctx: &RequestContext,
+        fork: &ConversationFork,
+    ) -> ConversationForkResult<Vec<Message>> {
+        let parent_id = fork.parent_conversation_id;
+        let shared: Vec<Message> =
+            collect_pages(|page| self.messages.find_by_conversation(ctx, parent_id, page))
+                .await
+                .map_err(ConversationForkError::persistence)?
+                .into_iter()
+                .filter(|message| fork.shares(message))
+                .collect();
+        if !shared
+            .iter()
+            .any(|message| message.sequence_number() == fork.forked_at)
+        {
+            return Err(ConversationForkError::ForkPointNotFound {
+                conversation_id: parent_id,
+                forked_at: fork.forked_at,
+            });
+        }
+        Ok(shared)
+    }
+}
+
+fn lock_error(err: impl std::fmt::Display) -> ConversationForkError {
+    ConversationForkError::persistence(std::io::Error::other(err.to_string()))
+}
+
+#[async_trait]
+impl ConversationForkRepository for InMemoryConversationForkAdapter {
+    async fn fork(
+        &self,
+        ctx: &RequestContext,
+        fork: &ConversationFork,
+    ) -> ConversationForkResult<ConversationFork> {
+        let parent = self
+            .conversations
+            .find_by_id(ctx, fork.parent_conversation_id)
+            .await
+            .map_err(ConversationForkError::persistence)?
+            .ok_or(ConversationForkError::ParentNotFound(
+                fork.parent_conversation_id,
+            ))?;
+        let shared = self.shared_history(ctx, fork).await?;
+        let stored = self
+            .snapshots
+            .latest_through(fork.parent_conversation_id, fork.forked_at)
+            .map_err(ConversationForkError::persistence)?
+            .map_or(*fork, |snapshot_id| fork.with_parent_snapshot(snapshot_id));
+        self.conversations
+            .store(ctx, &stored.conversation(&parent))
+            .await
+            .map_err(ConversationForkError::persistence)?;
+        let copies: Vec<Message> = shared
+            .iter()
+            .map(|message| stored.copy_message(message))
+            .collect();
+        self.messages
+            .store_batch(ctx, &copies)
+            .await
+            .map_err(ConversationForkError::persistence)?;
+        self.forks
+            .write()
+            .map_err(lock_error)?
+            .insert(stored.conversation_id, stored);
+        Ok(stored)
+    }
+
+    async fn find_fork(
+        &self,
+        _ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> ConversationForkResult<Option<ConversationFork>> {
+        let forks = self.forks.read().map_err(lock_error)?;
+        Ok(forks.get(&conversation_id).copied())
+    }
+
+    async fn find_forks(
+        &self,
+        _ctx: &RequestContext,
+        parent_conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> ConversationForkResult<Page<ConversationFork>> {
+        let forks = self.forks.read().map_err(lock_error)?;
+        let mut children: Vec<ConversationFork> = forks
+            .values()
+            .filter(|fork| fork.parent_conversation_id == parent_conversation_id)
+            .copied()
+            .collect();
+        children.sort_by_key(|fork| (fork.created_at, fork.conversation_id.into_inner()));
+        Ok(Page::from_ordered(children, page, fork_position))
+    }
+}
+
+const fn fork_position(fork: &ConversationFork) -> Cursor {
+    Cursor::at_timestamp(fork.created_at, fork.conversation_id.into_inner())
+}
@@ -3,17 +3,19 @@
 //! Provides a simple, thread-safe adapter for unit testing
 //! without database dependencies.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
 use mockable::Clock;
 
+use super::InMemoryConversationRepository;
 use crate::context::RequestContext;
 use crate::message::{
     domain::{AgentSessionId, ConversationId, HandoffId, HandoffMetadata, HandoffParams},
     ports::handoff::{AgentHandoffPort, HandoffError, HandoffResult, InitiateHandoffParams},
 };
+use crate::pagination::{Cursor, Page, PageRequest};
 
 /// In-memory implementation of [`AgentHandoffPort`].
 ///
@@ -22,6 +24,7 @@ use crate::message::{
 pub struct InMemoryHandoffAdapter<C: Clock + Send + Sync> {
     store: Arc<RwLock<HandoffStore>>,
     clock: C,
+    conversations: Option<InMemoryConversationRepository>,
 }
 
 #[derive(Debug, Default)]
@@ -37,9 +40,17 @@ impl<C: Clock + Send + Sync> InMemoryHandoffAdapter<C> {
         Self {
             store: Arc::new(RwLock::new(HandoffStore::default())),
             clock,
+            conversations: None,
         }
     }
 
+    /// Rejects handoffs for conversations archived in `conversations`.
+    #[must_use]
+    pub fn with_conversations(mut self, conversations: InMemoryConversationRepository) -> Self {
+        self.conversations = Some(conversations);
+        self
+    }
+
     /// Returns the number of stored handoffs.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -55,6 +66,54 @@ impl<C: Clock + Send + Sync> InMemoryHandoffAdapter<C> {
         self.len() == 0
     }
 
+    fn ensure_conversation_open(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> HandoffResult<()> {
+        let Some(conversations) = &self.conversations else {
+            return Ok(());
+        };
+        if conversations
+            .is_archived(ctx.tenant_id(), conversation_id)
+            .map_err(HandoffError::persistence)?
+        {
+            return Err(HandoffError::ConversationArchived(conversation_id));
+        }
+        Ok(())
+    }
+
+    /// Removes every handoff of `conversations`, returning how many there
+    /// were.
+    pub(crate) fn remove_for_conversations(
+        &self,
+        conversations: &HashSet<ConversationId>,
+    ) -> HandoffResult<usize> {
+        let mut guard = self
+            .store
+            .write()
+            .map_err(|e| HandoffError::persistence(std::io::Error::other(e.to_string())))?;
+        let removed: Vec<HandoffId> = guard
+            .conversations
+            .iter()
+            .filter(|(_, conversation_id)| conversations.contains(conversation_id))
+            .map(|(handoff_id, _)| *handoff_id)
+            .collect();
+        for handoff_id in &removed {
+            guard.handoffs.remove(handoff_id);
+            guard.conversations.remove(handoff_id);
+        }
+        Ok(removed.len())
+    }
+
+    fn conversation_of(&self, handoff_id: HandoffId) -> HandoffResult<Option<ConversationId>> {
+        let guard = self
+            .store
+            .read()
+            .map_err(|e| HandoffError::persistence(std::io::Error::other(e.to_string())))?;
+        Ok(guard.conversations.get(&handoff_id).copied())
+    }
+
     /// Helper method to update a handoff with terminal state validation.
     ///
     /// Acquires a write lock, fetches the handoff, validates it's not terminal,
@@ -96,9 +155,10 @@ impl<C: Clock + Send + Sync> InMemoryHandoffAdapter<C> {
 impl<C: Clock + Send + Sync> AgentHandoffPort for InMemoryHandoffAdapter<C> {
     async fn initiate_handoff(
         &self,
-        _ctx: &RequestContext,
+        ctx: &RequestContext,
         params: InitiateHandoffParams<'_>,
     ) -> HandoffResult<HandoffMetadata> {
+        self.ensure_conversation_open(ctx, params.conversation_id)?;
         let handoff_params = HandoffParams::new(
             params.source_session.session_id,
             params.prior_turn_id,
@@ -126,10 +186,13 @@ impl<C: Clock + Send + Sync> AgentHandoffPort for InMemoryHandoffAdapter<C> {
 
     async fn complete_handoff(
         &self,
-        _ctx: &RequestContext,
+        ctx: &RequestContext,
         handoff_id: HandoffId,
         target_session_id: AgentSessionId,
     ) -> HandoffResult<HandoffMetadata> {
+        if let Some(conversation_id) = self.conversation_of(handoff_id)? {
+            self.ensure_conversation_open(ctx, conversation_id)?;
+        }
         let clock = &self.clock;
         self.update_handoff(
             handoff_id,
@@ -152,6 +215,19 @@ impl<C: Clock + Send + Sync> AgentHandoffPort for InMemoryHandoffAdapter<C> {
         Ok(())
     }
 
+    async fn reject_handoff(
+        &self,
+        _ctx: &RequestContext,
+        handoff_id: HandoffId,
+        reason: &str,
+    ) -> HandoffResult<HandoffMetadata> {
+        self.update_handoff(
+            handoff_id,
+            crate::message::domain::HandoffStatus::Failed,
+            |handoff| handoff.reject(reason),
+        )
+    }
+
     async fn find_handoff(
         &self,
         _ctx: &RequestContext,
@@ -169,7 +245,8 @@ impl<C: Clock + Send + Sync> AgentHandoffPort for InMemoryHandoffAdapter<C> {
         &self,
         _ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> HandoffResult<Vec<HandoffMetadata>> {
+        page: PageRequest,
+    ) -> HandoffResult<Page<HandoffMetadata>> {
         let guard = self
             .store
             .read()
@@ -184,7 +261,9 @@ impl<C: Clock + Send + Sync> AgentHandoffPort for InMemoryHandoffAdapter<C> {
             }
         }
 
-        handoffs.sort_by_key(|h| h.initiated_at);
-        Ok(handoffs)
+        handoffs.sort_by_key(|h| (h.initiated_at, h.handoff_id.into_inner()));
+        Ok(Page::from_ordered(handoffs, page, |h| {
+            Cursor::at_timestamp(h.initiated_at, h.handoff_id.into_inner())
+        }))
     }
 }
@@ -0,0 +1,89 @@
+//! In-memory implementation of the `HandoffChainPort`.
+//!
+//! Joins the handoffs, sessions, and snapshots held by the companion
+//! in-memory adapters. Suitable for unit tests only.
+
+use async_trait::async_trait;
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationId, HandoffChain},
+    ports::{
+        agent_session::AgentSessionRepository,
+        context_snapshot::ContextSnapshotPort,
+        handoff::{AgentHandoffPort, HandoffError, HandoffResult},
+        handoff_chain::HandoffChainPort,
+    },
+};
+use crate::pagination::collect_pages;
+
+/// In-memory implementation of [`HandoffChainPort`].
+///
+/// Reads from a session repository, a handoff port, and a snapshot port,
+/// typically clones of
+/// [`InMemoryAgentSessionRepository`](super::InMemoryAgentSessionRepository),
+/// [`InMemoryHandoffAdapter`](super::InMemoryHandoffAdapter), and
+/// [`InMemoryContextSnapshotAdapter`](super::InMemoryContextSnapshotAdapter)
+/// that share state with the adapters used by the code under test.
+#[derive(Debug, Clone)]
+pub struct InMemoryHandoffChainAdapter<S, H, C> {
+    sessions: S,
+    handoffs: H,
+    snapshots: C,
+}
+
+impl<S, H, C> InMemoryHandoffChainAdapter<S, H, C>
+where
+    S: AgentSessionRepository,
+    H: AgentHandoffPort,
+    C: ContextSnapshotPort,
+{
+    /// Creates a new adapter reading from the given stores.
+    #[must_use]
+    pub const fn new(sessions: S, handoffs: H, snapshots: C) -> Self {
+        Self {
+            sessions,
+            handoffs,
+            snapshots,
+        }
+    }
+}
+
+#[async_trait]
+impl<S, H, C> HandoffChainPort for InMemoryHandoffChainAdapter<S, H, C>
+where
+    S: AgentSessionRepository,
+    H: AgentHandoffPort,
+    C: ContextSnapshotPort,
+{
+    async fn handoff_chain(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> HandoffResult<HandoffChain> {
+        let handoffs = collect_pages(|page| {
+            self.handoffs
+                .list_handoffs_for_conversation(ctx, conversation_id, page)
+        })
+        .await?;
+        let sessions = collect_pages(|page| {
+            self.sessions
+                .find_by_conversation(ctx, conversation_id, page)
+        })
+        .await
+        .map_err(HandoffError::persistence)?;
+        let mut snapshots = Vec::new();
+        for session in &sessions {
+            let session_snapshots = collect_pages(|page| {
+                self.snapshots
+                    .find_snapshots_for_session(ctx, session.session_id, page)
+            })
+            .await
+            .map_err(HandoffError::persistence)?;
+            snapshots.extend(session_snapshots);
+        }
+
+        HandoffChain::assemble(conversation_id, handoffs, sessions, &snapshots)
+            .map_err(HandoffError::SessionNotFound)
+    }
+}
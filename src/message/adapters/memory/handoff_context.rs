@@ -0,0 +1,58 @@
+//! In-memory implementation of the `HandoffContextRepository` port.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{AgentSessionId, HandoffContextPackage},
+    ports::handoff_context::{HandoffContextError, HandoffContextRepository, HandoffContextResult},
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+type TenantPackages = HashMap<TenantId, HashMap<AgentSessionId, HandoffContextPackage>>;
+
+/// Thread-safe in-memory handoff context repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryHandoffContextRepository {
+    packages: Arc<RwLock<TenantPackages>>,
+}
+
+impl InMemoryHandoffContextRepository {
+    /// Creates an empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl HandoffContextRepository for InMemoryHandoffContextRepository {
+    async fn store(
+        &self,
+        ctx: &RequestContext,
+        package: &HandoffContextPackage,
+    ) -> HandoffContextResult<()> {
+        let mut tenants = self.packages.write().map_err(|err| {
+            HandoffContextError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        tenants
+            .entry(ctx.tenant_id())
+            .or_default()
+            .insert(package.target_session_id, package.clone());
+        Ok(())
+    }
+
+    async fn find_for_session(
+        &self,
+        ctx: &RequestContext,
+        target_session_id: AgentSessionId,
+    ) -> HandoffContextResult<Option<HandoffContextPackage>> {
+        let tenants = self.packages.read().map_err(|err| {
+            HandoffContextError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        Ok(tenants
+            .get(&ctx.tenant_id())
+            .and_then(|packages| packages.get(&target_session_id))
+            .cloned())
+    }
+}
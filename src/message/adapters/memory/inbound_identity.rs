@@ -0,0 +1,107 @@
+//! In-memory implementation of the `InboundIdentityMapping` port.
+//!
+//! External users are mapped up front with
+//! [`InMemoryInboundIdentityMapping::with_user`]; channel bindings are
+//! recorded as the gateway creates conversations.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{ConversationId, InboundOrigin, InboundPrincipal},
+    ports::inbound_identity::{
+        InboundIdentityError, InboundIdentityMapping, InboundIdentityResult,
+    },
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+type ExternalKey = (String, String);
+
+/// Thread-safe in-memory identity mapping.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::{TenantId, UserId};
+/// use corbusier::message::{
+///     adapters::memory::InMemoryInboundIdentityMapping, domain::InboundPrincipal,
+/// };
+///
+/// let mapping = InMemoryInboundIdentityMapping::new().with_user(
+///     "slack",
+///     "U2147483697",
+///     InboundPrincipal::new(TenantId::new(), UserId::new()),
+/// );
+/// # let _ = mapping;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryInboundIdentityMapping {
+    users: HashMap<ExternalKey, InboundPrincipal>,
+    channels: Arc<RwLock<HashMap<(TenantId, ExternalKey), ConversationId>>>,
+}
+
+impl InMemoryInboundIdentityMapping {
+    /// Creates a mapping with no users or channels.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps an external user of `source` to a principal.
+    #[must_use]
+    pub fn with_user(
+        mut self,
+        source: impl Into<String>,
+        external_user_id: impl Into<String>,
+        principal: InboundPrincipal,
+    ) -> Self {
+        self.users
+            .insert((source.into(), external_user_id.into()), principal);
+        self
+    }
+}
+
+fn lock_error(err: impl std::fmt::Display) -> InboundIdentityError {
+    InboundIdentityError::persistence(std::io::Error::other(err.to_string()))
+}
+
+fn channel_key(ctx: &RequestContext, origin: &InboundOrigin) -> (TenantId, ExternalKey) {
+    (
+        ctx.tenant_id(),
+        (origin.source.clone(), origin.channel_id.clone()),
+    )
+}
+
+#[async_trait]
+impl InboundIdentityMapping for InMemoryInboundIdentityMapping {
+    async fn principal_for(
+        &self,
+        origin: &InboundOrigin,
+    ) -> InboundIdentityResult<Option<InboundPrincipal>> {
+        Ok(self
+            .users
+            .get(&(origin.source.clone(), origin.external_user_id.clone()))
+            .copied())
+    }
+
+    async fn conversation_for(
+        &self,
+        ctx: &RequestContext,
+        origin: &InboundOrigin,
+    ) -> InboundIdentityResult<Option<ConversationId>> {
+        let channels = self.channels.read().map_err(lock_error)?;
+        Ok(channels.get(&channel_key(ctx, origin)).copied())
+    }
+
+    async fn bind_conversation(
+        &self,
+        ctx: &RequestContext,
+        origin: &InboundOrigin,
+        conversation_id: ConversationId,
+    ) -> InboundIdentityResult<ConversationId> {
+        let mut channels = self.channels.write().map_err(lock_error)?;
+        Ok(*channels
+            .entry(channel_key(ctx, origin))
+            .or_insert(conversation_id))
+    }
+}
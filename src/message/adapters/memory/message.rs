@@ -3,17 +3,24 @@
 //! Provides a simple, thread-safe repository for unit testing
 //! without database dependencies. Not suitable for production use.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
-use crate::context::RequestContext;
+use super::{InMemoryConversationRepository, message_position};
+use crate::context::{RequestContext, UserId};
+use crate::message::adapters::batch::{BatchKey, find_batch_conflicts};
 use crate::message::{
-    domain::{ConversationId, Message, MessageId, SequenceNumber},
+    domain::{
+        ContentHash, ConversationId, ConversationTokenUsage, Message, MessageId, MessageQuery,
+        MessageRedaction, SequenceNumber,
+    },
     error::RepositoryError,
     ports::repository::{MessageRepository, RepositoryResult},
 };
+use crate::pagination::{Page, PageRequest};
 
 /// In-memory implementation of [`MessageRepository`].
 ///
@@ -31,6 +38,8 @@ use crate::message::{
 #[derive(Debug, Default, Clone)]
 pub struct InMemoryMessageRepository {
     messages: Arc<RwLock<HashMap<MessageId, Message>>>,
+    authors: Arc<RwLock<HashMap<MessageId, UserId>>>,
+    conversations: Option<InMemoryConversationRepository>,
 }
 
 impl InMemoryMessageRepository {
@@ -40,6 +49,13 @@ impl InMemoryMessageRepository {
         Self::default()
     }
 
+    /// Rejects messages for conversations archived in `conversations`.
+    #[must_use]
+    pub fn with_conversations(mut self, conversations: InMemoryConversationRepository) -> Self {
+        self.conversations = Some(conversations);
+        self
+    }
+
     /// Returns the number of stored messages.
     ///
     /// Returns `0` if the internal lock is poisoned, matching the fallback
@@ -56,6 +72,88 @@ impl InMemoryMessageRepository {
         self.len() == 0
     }
 
+    /// Returns the messages of `conversations` created before `cutoff`,
+    /// oldest first.
+    pub(crate) fn created_before(
+        &self,
+        conversations: &HashSet<ConversationId>,
+        cutoff: DateTime<Utc>,
+    ) -> RepositoryResult<Vec<Message>> {
+        let mut expired: Vec<Message> = self
+            .read_locked()?
+            .values()
+            .filter(|m| conversations.contains(&m.conversation_id()) && m.created_at() < cutoff)
+            .cloned()
+            .collect();
+        expired.sort_by_key(|m| (m.created_at(), m.id().into_inner()));
+        Ok(expired)
+    }
+
+    /// Returns the unredacted messages of `conversations` stored by
+    /// `user_id`, oldest first.
+    ///
+    /// This stands in for the audit trail the `PostgreSQL` adapter keeps,
+    /// which the in-memory erasure repository uses to find a subject's
+    /// messages.
+    pub(crate) fn written_by(
+        &self,
+        conversations: &HashSet<ConversationId>,
+        user_id: UserId,
+    ) -> RepositoryResult<Vec<MessageId>> {
+        let guard = self.read_locked()?;
+        let authors = self.authors_locked()?;
+        let mut written: Vec<&Message> = guard
+            .values()
+            .filter(|m| {
+                conversations.contains(&m.conversation_id())
+                    && !m.is_redacted()
+                    && authors.get(&m.id()) == Some(&user_id)
+            })
+            .collect();
+        written.sort_by_key(|m| (m.created_at(), m.id().into_inner()));
+        Ok(written.into_iter().map(Message::id).collect())
+    }
+
+    /// Removes the messages with the given identifiers.
+    pub(crate) fn remove(&self, ids: &[MessageId]) -> RepositoryResult<()> {
+        let mut guard = self.write_locked()?;
+        let mut authors = self
+            .authors
+            .write()
+            .map_err(|e| RepositoryError::connection(format!("lock poisoned: {e}")))?;
+        for id in ids {
+            guard.remove(id);
+            authors.remove(id);
+        }
+        Ok(())
+    }
+
+    /// Acquires a read lock on the message authors.
+    fn authors_locked(
+        &self,
+    ) -> RepositoryResult<std::sync::RwLockReadGuard<'_, HashMap<MessageId, UserId>>> {
+        self.authors
+            .read()
+            .map_err(|e| RepositoryError::connection(format!("lock poisoned: {e}")))
+    }
+
+    /// Records the user in `ctx` as the author of `ids`.
+    ///
+    /// Callers hold the message write lock, so authors are always locked
+    /// after messages.
+    fn record_authors(
+        &self,
+        ctx: &RequestContext,
+        ids: impl IntoIterator<Item = MessageId>,
+    ) -> RepositoryResult<()> {
+        let mut authors = self
+            .authors
+            .write()
+            .map_err(|e| RepositoryError::connection(format!("lock poisoned: {e}")))?;
+        authors.extend(ids.into_iter().map(|id| (id, ctx.user_id())));
+        Ok(())
+    }
+
     /// Acquires a read lock on the message store.
     ///
     /// Maps a poisoned-lock error to [`RepositoryError::connection`] so that
@@ -67,15 +165,39 @@ impl InMemoryMessageRepository {
             .read()
             .map_err(|e| RepositoryError::connection(format!("lock poisoned: {e}")))
     }
+
+    /// Acquires a write lock on the message store.
+    fn write_locked(
+        &self,
+    ) -> RepositoryResult<std::sync::RwLockWriteGuard<'_, HashMap<MessageId, Message>>> {
+        self.messages
+            .write()
+            .map_err(|e| RepositoryError::connection(format!("lock poisoned: {e}")))
+    }
+
+    /// Rejects writes to a conversation archived in the attached
+    /// conversation repository.
+    fn ensure_writable(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<()> {
+        if let Some(conversations) = &self.conversations
+            && conversations
+                .is_archived(ctx.tenant_id(), conversation_id)
+                .map_err(|e| RepositoryError::connection(format!("lock poisoned: {e}")))?
+        {
+            return Err(RepositoryError::ConversationArchived(conversation_id));
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl MessageRepository for InMemoryMessageRepository {
-    async fn store(&self, _ctx: &RequestContext, message: &Message) -> RepositoryResult<()> {
-        let mut guard = self
-            .messages
-            .write()
-            .map_err(|e| RepositoryError::connection(format!("lock poisoned: {e}")))?;
+    async fn store(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<()> {
+        self.ensure_writable(ctx, message.conversation_id())?;
+        let mut guard = self.write_locked()?;
 
         // Check for duplicate message ID
         if guard.contains_key(&message.id()) {
@@ -96,7 +218,86 @@ impl MessageRepository for InMemoryMessageRepository {
         }
 
         guard.insert(message.id(), message.clone());
-        Ok(())
+        self.record_authors(ctx, [message.id()])
+    }
+
+    async fn append(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<Message> {
+        let conversation_id = message.conversation_id();
+        self.ensure_writable(ctx, conversation_id)?;
+        let mut guard = self.write_locked()?;
+
+        if guard.contains_key(&message.id()) {
+            return Err(RepositoryError::DuplicateMessage(message.id()));
+        }
+        let last = guard
+            .values()
+            .filter(|m| m.conversation_id() == conversation_id)
+            .map(Message::sequence_number)
+            .max();
+        let next = last.map_or(SequenceNumber::FIRST, |sequence| sequence.next());
+        let appended = message.clone().with_sequence_number(next);
+        guard.insert(appended.id(), appended.clone());
+        self.record_authors(ctx, [appended.id()])?;
+        Ok(appended)
+    }
+
+    async fn store_batch(
+        &self,
+        ctx: &RequestContext,
+        messages: &[Message],
+    ) -> RepositoryResult<()> {
+        for message in messages {
+            self.ensure_writable(ctx, message.conversation_id())?;
+        }
+        let mut guard = self.write_locked()?;
+
+        let keys: Vec<BatchKey> = messages.iter().map(BatchKey::from).collect();
+        let conflicts = find_batch_conflicts(
+            &keys,
+            |id| guard.contains_key(&id),
+            |conversation_id, sequence| {
+                guard.values().any(|m| {
+                    m.conversation_id() == conversation_id && m.sequence_number() == sequence
+                })
+            },
+        );
+        if !conflicts.is_empty() {
+            return Err(RepositoryError::BatchConflicts(conflicts));
+        }
+
+        guard.extend(messages.iter().map(|m| (m.id(), m.clone())));
+        self.record_authors(ctx, messages.iter().map(Message::id))
+    }
+
+    async fn redact(
+        &self,
+        _ctx: &RequestContext,
+        redaction: &MessageRedaction,
+    ) -> RepositoryResult<Message> {
+        let message_id = redaction.message_id();
+        let mut guard = self.write_locked()?;
+        let stored = guard
+            .get_mut(&message_id)
+            .ok_or(RepositoryError::NotFound(message_id))?;
+        if stored.is_redacted() {
+            return Err(RepositoryError::AlreadyRedacted(message_id));
+        }
+        let redacted = stored.clone().redacted(redaction);
+        stored.clone_from(&redacted);
+        Ok(redacted)
+    }
+
+    async fn set_pinned(
+        &self,
+        _ctx: &RequestContext,
+        id: MessageId,
+        pinned: bool,
+    ) -> RepositoryResult<Message> {
+        let mut guard = self.write_locked()?;
+        let stored = guard.get_mut(&id).ok_or(RepositoryError::NotFound(id))?;
+        let updated = stored.clone().with_pinned(pinned);
+        stored.clone_from(&updated);
+        Ok(updated)
     }
 
     async fn find_by_id(
@@ -111,7 +312,8 @@ impl MessageRepository for InMemoryMessageRepository {
         &self,
         _ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> RepositoryResult<Vec<Message>> {
+        page: PageRequest,
+    ) -> RepositoryResult<Page<Message>> {
         let mut messages: Vec<Message> = self
             .read_locked()?
             .values()
@@ -122,7 +324,24 @@ impl MessageRepository for InMemoryMessageRepository {
         // Sort by sequence number for consistent ordering
         messages.sort_by_key(|m| m.sequence_number().value());
 
-        Ok(messages)
+        Ok(Page::from_ordered(messages, page, message_position))
+    }
+
+    async fn query(
+        &self,
+        _ctx: &RequestContext,
+        conversation_id: ConversationId,
+        query: &MessageQuery,
+    ) -> RepositoryResult<Page<Message>> {
+        let mut messages: Vec<Message> = self
+            .read_locked()?
+            .values()
+            .filter(|m| m.conversation_id() == conversation_id && query.matches(m))
+            .cloned()
+            .collect();
+        messages.sort_by_key(|m| m.sequence_number().value());
+
+        Ok(Page::from_ordered(messages, query.page(), message_position))
     }
 
     async fn next_sequence_number(
@@ -134,14 +353,44 @@ impl MessageRepository for InMemoryMessageRepository {
             .read_locked()?
             .values()
             .filter(|m| m.conversation_id() == conversation_id)
-            .map(|m| m.sequence_number().value())
-            .max()
-            .unwrap_or(0);
+            .map(Message::sequence_number)
+            .max();
 
-        Ok(SequenceNumber::new(max_seq.saturating_add(1)))
+        Ok(max_seq.map_or(SequenceNumber::FIRST, |sequence| sequence.next()))
+    }
+
+    async fn token_usage(
+        &self,
+        _ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<ConversationTokenUsage> {
+        let mut usage = ConversationTokenUsage::default();
+        for message in self
+            .read_locked()?
+            .values()
+            .filter(|m| m.conversation_id() == conversation_id)
+        {
+            usage.record(message.metadata().token_count.as_ref());
+        }
+        Ok(usage)
     }
 
     async fn exists(&self, _ctx: &RequestContext, id: MessageId) -> RepositoryResult<bool> {
         Ok(self.read_locked()?.contains_key(&id))
     }
+
+    async fn unreferenced_blobs(
+        &self,
+        _ctx: &RequestContext,
+        hashes: &[ContentHash],
+    ) -> RepositoryResult<Vec<ContentHash>> {
+        let guard = self.read_locked()?;
+        let referenced: HashSet<&ContentHash> =
+            guard.values().flat_map(Message::attachment_blobs).collect();
+        Ok(hashes
+            .iter()
+            .filter(|hash| !referenced.contains(hash))
+            .cloned()
+            .collect())
+    }
 }
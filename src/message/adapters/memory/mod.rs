@@ -3,16 +3,59 @@
 //! These adapters provide simple, thread-safe implementations suitable for
 //! unit testing without database dependencies.
 
+mod activity;
 mod agent_session;
 mod context_snapshot;
 mod conversation;
+mod conversation_list;
+mod email;
+mod feedback;
+mod fork;
 mod handoff;
+mod handoff_chain;
+mod handoff_context;
+mod inbound_identity;
 mod message;
+mod processing;
+mod rolling_summary;
 mod slash_command;
+mod slash_command_authoriser;
+mod slash_command_definition;
+mod slash_command_execution;
+mod snapshot_policy;
+mod streaming;
+mod transfer;
+mod turn;
 
+use crate::message::domain::Message;
+use crate::pagination::Cursor;
+
+pub use activity::InMemoryConversationActivityAdapter;
 pub use agent_session::InMemoryAgentSessionRepository;
 pub use context_snapshot::InMemoryContextSnapshotAdapter;
 pub use conversation::InMemoryConversationRepository;
+pub use conversation_list::InMemoryConversationSummaries;
+pub use email::{InMemoryEmailNotifier, InMemoryMailbox};
+pub use feedback::InMemoryMessageFeedbackRepository;
+pub use fork::InMemoryConversationForkAdapter;
 pub use handoff::InMemoryHandoffAdapter;
+pub use handoff_chain::InMemoryHandoffChainAdapter;
+pub use handoff_context::InMemoryHandoffContextRepository;
+pub use inbound_identity::InMemoryInboundIdentityMapping;
 pub use message::InMemoryMessageRepository;
+pub use processing::InMemoryMessageProcessingRepository;
+pub use rolling_summary::InMemoryRollingSummaryRepository;
 pub use slash_command::InMemorySlashCommandRegistry;
+pub use slash_command_authoriser::InMemorySlashCommandAuthoriser;
+pub use slash_command_definition::InMemorySlashCommandDefinitionRepository;
+pub use slash_command_execution::InMemorySlashCommandExecutionRepository;
+pub use snapshot_policy::InMemorySnapshotPolicyRepository;
+pub use streaming::InMemoryPartialMessageRepository;
+pub use transfer::InMemoryConversationTransferAdapter;
+pub use turn::InMemoryTurnRepository;
+
+/// Keyset position of a message in sequence order.
+fn message_position(message: &Message) -> Cursor {
+    let sequence = i64::try_from(message.sequence_number().value()).unwrap_or(i64::MAX);
+    Cursor::at_number(sequence, message.id().into_inner())
+}
@@ -0,0 +1,98 @@
+//! In-memory implementation of the `MessageProcessingRepository` port.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{MessageId, ProcessingStage, StageStatus, StuckMessagesQuery},
+    ports::processing::{MessageProcessingRepository, ProcessingError, ProcessingResult},
+};
+use crate::pagination::Page;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockWriteGuard};
+
+type StageKey = (MessageId, ProcessingStage);
+
+/// Thread-safe in-memory processing status repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryMessageProcessingRepository {
+    statuses: Arc<RwLock<HashMap<TenantId, HashMap<StageKey, StageStatus>>>>,
+}
+
+impl InMemoryMessageProcessingRepository {
+    /// Creates an empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn write(
+        &self,
+    ) -> ProcessingResult<RwLockWriteGuard<'_, HashMap<TenantId, HashMap<StageKey, StageStatus>>>>
+    {
+        self.statuses
+            .write()
+            .map_err(|err| ProcessingError::persistence(std::io::Error::other(err.to_string())))
+    }
+
+    fn tenant_statuses(&self, ctx: &RequestContext) -> ProcessingResult<Vec<StageStatus>> {
+        let tenants = self
+            .statuses
+            .read()
+            .map_err(|err| ProcessingError::persistence(std::io::Error::other(err.to_string())))?;
+        Ok(tenants
+            .get(&ctx.tenant_id())
+            .map(|statuses| statuses.values().cloned().collect())
+            .unwrap_or_default())
+    }
+}
+
+#[async_trait]
+impl MessageProcessingRepository for InMemoryMessageProcessingRepository {
+    async fn upsert(&self, ctx: &RequestContext, status: &StageStatus) -> ProcessingResult<()> {
+        self.write()?
+            .entry(ctx.tenant_id())
+            .or_default()
+            .insert((status.message_id, status.stage), status.clone());
+        Ok(())
+    }
+
+    async fn restart_from(
+        &self,
+        ctx: &RequestContext,
+        status: &StageStatus,
+    ) -> ProcessingResult<()> {
+        let mut tenants = self.write()?;
+        let statuses = tenants.entry(ctx.tenant_id()).or_default();
+        for later in status.stage.later_stages() {
+            statuses.remove(&(status.message_id, later));
+        }
+        statuses.insert((status.message_id, status.stage), status.clone());
+        Ok(())
+    }
+
+    async fn find_for_message(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> ProcessingResult<Vec<StageStatus>> {
+        let mut statuses = self.tenant_statuses(ctx)?;
+        statuses.retain(|status| status.message_id == message_id);
+        statuses.sort_by_key(|status| status.stage);
+        Ok(statuses)
+    }
+
+    async fn find_stuck(
+        &self,
+        ctx: &RequestContext,
+        query: &StuckMessagesQuery,
+    ) -> ProcessingResult<Page<StageStatus>> {
+        let mut statuses = self.tenant_statuses(ctx)?;
+        statuses.retain(|status| query.matches(status));
+        statuses.sort_by_key(|status| (status.updated_at, status.message_id.into_inner()));
+        Ok(Page::from_ordered(
+            statuses,
+            query.page,
+            StuckMessagesQuery::position,
+        ))
+    }
+}
@@ -0,0 +1,58 @@
+//! In-memory implementation of the `RollingSummaryRepository` port.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{ConversationId, RollingSummary},
+    ports::summary::{RollingSummaryError, RollingSummaryRepository, RollingSummaryResult},
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+type TenantSummaries = HashMap<TenantId, HashMap<ConversationId, RollingSummary>>;
+
+/// Thread-safe in-memory rolling summary repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryRollingSummaryRepository {
+    summaries: Arc<RwLock<TenantSummaries>>,
+}
+
+impl InMemoryRollingSummaryRepository {
+    /// Creates an empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RollingSummaryRepository for InMemoryRollingSummaryRepository {
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RollingSummaryResult<Option<RollingSummary>> {
+        let tenants = self.summaries.read().map_err(|err| {
+            RollingSummaryError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        Ok(tenants
+            .get(&ctx.tenant_id())
+            .and_then(|summaries| summaries.get(&conversation_id))
+            .cloned())
+    }
+
+    async fn store(
+        &self,
+        ctx: &RequestContext,
+        summary: &RollingSummary,
+    ) -> RollingSummaryResult<()> {
+        let mut tenants = self.summaries.write().map_err(|err| {
+            RollingSummaryError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        tenants
+            .entry(ctx.tenant_id())
+            .or_default()
+            .insert(summary.conversation_id(), summary.clone());
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 //! In-memory slash-command registry adapter.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::message::domain::{
     CommandParameterSpec, CommandParameterType, SlashCommandDefinition, SlashCommandSchemaError,
@@ -29,8 +29,8 @@ impl InMemorySlashCommandRegistry {
                         definition.validate_schema().is_ok(),
                         "built-in slash command definitions must remain valid",
                     );
-                    definition.command = definition.command.to_ascii_lowercase();
-                    (definition.command.clone(), definition)
+                    definition.normalise_names();
+                    (definition.qualified_name(), definition)
                 })
                 .collect(),
         }
@@ -40,27 +40,31 @@ impl InMemorySlashCommandRegistry {
     ///
     /// # Errors
     ///
-    /// Returns [`SlashCommandRegistryError::InvalidDefinition`] when duplicate
-    /// command names are provided or when command schema validation fails.
+    /// Returns [`SlashCommandRegistryError::InvalidDefinition`] when command
+    /// schema validation fails, or when a command name or alias is claimed
+    /// twice within a namespace.
     pub fn with_commands(
         definitions: impl IntoIterator<Item = SlashCommandDefinition>,
     ) -> SlashCommandRegistryResult<Self> {
         let mut commands = HashMap::new();
+        let mut claimed = HashSet::new();
         for mut definition in definitions {
-            definition.command = definition.command.to_ascii_lowercase();
+            definition.normalise_names();
             definition
                 .validate_schema()
                 .map_err(SlashCommandRegistryError::InvalidDefinition)?;
-            let command_name = definition.command.clone();
-
-            if commands.insert(command_name.clone(), definition).is_some() {
-                return Err(SlashCommandRegistryError::InvalidDefinition(
-                    SlashCommandSchemaError::InvalidCommandDefinition {
-                        command: command_name,
-                        reason: "duplicate command definition".to_owned(),
-                    },
-                ));
+            let names = std::iter::once(&definition.command).chain(&definition.aliases);
+            for name in names {
+                if !claimed.insert((definition.namespace.clone(), name.clone())) {
+                    return Err(SlashCommandRegistryError::InvalidDefinition(
+                        SlashCommandSchemaError::InvalidCommandDefinition {
+                            command: definition.qualified_name(),
+                            reason: format!("'{name}' is already used by another command"),
+                        },
+                    ));
+                }
             }
+            commands.insert(definition.qualified_name(), definition);
         }
         Ok(Self { commands })
     }
@@ -82,7 +86,7 @@ impl SlashCommandRegistry for InMemorySlashCommandRegistry {
 
     fn list(&self) -> SlashCommandRegistryResult<Vec<SlashCommandDefinition>> {
         let mut commands: Vec<_> = self.commands.values().cloned().collect();
-        commands.sort_by(|left, right| left.command.cmp(&right.command));
+        commands.sort_by_key(SlashCommandDefinition::qualified_name);
         Ok(commands)
     }
 }
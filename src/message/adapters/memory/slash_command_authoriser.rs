@@ -0,0 +1,64 @@
+//! In-memory implementation of the `SlashCommandAuthoriser` port.
+
+use crate::context::{RequestContext, TenantId, UserId};
+use crate::message::{
+    domain::{CommandPermission, SlashCommandAuthorisationError},
+    ports::slash_command_authoriser::{SlashCommandAuthorisationResult, SlashCommandAuthoriser},
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+/// Permissions granted to each user, per tenant.
+type Grants = HashMap<(TenantId, UserId), HashSet<CommandPermission>>;
+
+/// Thread-safe in-memory authoriser backed by explicit grants.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySlashCommandAuthoriser {
+    grants: Arc<RwLock<Grants>>,
+}
+
+impl InMemorySlashCommandAuthoriser {
+    /// Creates an authoriser that grants nothing.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Grants `permission` to the user of `ctx` within its tenant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandAuthorisationError`] when the grant store lock
+    /// is poisoned.
+    pub fn grant(
+        &self,
+        ctx: &RequestContext,
+        permission: CommandPermission,
+    ) -> SlashCommandAuthorisationResult<()> {
+        let mut grants = self
+            .grants
+            .write()
+            .map_err(|err| SlashCommandAuthorisationError::new(err.to_string()))?;
+        grants
+            .entry((ctx.tenant_id(), ctx.user_id()))
+            .or_default()
+            .insert(permission);
+        Ok(())
+    }
+}
+
+impl SlashCommandAuthoriser for InMemorySlashCommandAuthoriser {
+    fn holds(
+        &self,
+        ctx: &RequestContext,
+        permission: &CommandPermission,
+    ) -> SlashCommandAuthorisationResult<bool> {
+        let grants = self
+            .grants
+            .read()
+            .map_err(|err| SlashCommandAuthorisationError::new(err.to_string()))?;
+        Ok(grants
+            .get(&(ctx.tenant_id(), ctx.user_id()))
+            .is_some_and(|granted| granted.contains(permission)))
+    }
+}
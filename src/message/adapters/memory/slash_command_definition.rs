@@ -0,0 +1,141 @@
+//! In-memory implementation of the `SlashCommandDefinitionRepository` port.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::VersionedSlashCommand,
+    ports::slash_command::{
+        SlashCommandDefinitionError, SlashCommandDefinitionRepository, SlashCommandDefinitionResult,
+    },
+};
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroU32;
+use std::sync::{Arc, RwLock};
+
+/// Versions of each command, oldest first, keyed by command name.
+type TenantCommands = HashMap<TenantId, BTreeMap<String, Vec<VersionedSlashCommand>>>;
+
+/// Thread-safe in-memory slash-command definition repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySlashCommandDefinitionRepository {
+    commands: Arc<RwLock<TenantCommands>>,
+}
+
+impl InMemorySlashCommandDefinitionRepository {
+    /// Creates an empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn read<T>(
+        &self,
+        ctx: &RequestContext,
+        read_fn: impl FnOnce(&BTreeMap<String, Vec<VersionedSlashCommand>>) -> T,
+    ) -> SlashCommandDefinitionResult<T> {
+        let tenants = self.commands.read().map_err(|err| {
+            SlashCommandDefinitionError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        let empty = BTreeMap::new();
+        Ok(read_fn(tenants.get(&ctx.tenant_id()).unwrap_or(&empty)))
+    }
+}
+
+#[async_trait]
+impl SlashCommandDefinitionRepository for InMemorySlashCommandDefinitionRepository {
+    async fn append(
+        &self,
+        ctx: &RequestContext,
+        command: &VersionedSlashCommand,
+    ) -> SlashCommandDefinitionResult<()> {
+        let mut tenants = self.commands.write().map_err(|err| {
+            SlashCommandDefinitionError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        let name = command.command();
+        let versions = tenants
+            .entry(ctx.tenant_id())
+            .or_default()
+            .entry(name.clone())
+            .or_default();
+        let expected = versions.last().map_or(Some(NonZeroU32::MIN), |latest| {
+            latest.version.checked_add(1)
+        });
+        if expected != Some(command.version) {
+            return Err(SlashCommandDefinitionError::VersionConflict {
+                command: name,
+                version: command.version,
+            });
+        }
+        let mut stored = command.clone();
+        stored.definition.normalise_names();
+        versions.push(stored);
+        Ok(())
+    }
+
+    async fn find_latest(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+    ) -> SlashCommandDefinitionResult<Option<VersionedSlashCommand>> {
+        let name = command.to_ascii_lowercase();
+        self.read(ctx, |commands| {
+            commands
+                .get(&name)
+                .and_then(|versions| versions.last().cloned())
+        })
+    }
+
+    async fn find_version(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+        version: NonZeroU32,
+    ) -> SlashCommandDefinitionResult<Option<VersionedSlashCommand>> {
+        let name = command.to_ascii_lowercase();
+        self.read(ctx, |commands| {
+            commands.get(&name).and_then(|versions| {
+                versions
+                    .iter()
+                    .find(|stored| stored.version == version)
+                    .cloned()
+            })
+        })
+    }
+
+    async fn history(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+    ) -> SlashCommandDefinitionResult<Vec<VersionedSlashCommand>> {
+        let name = command.to_ascii_lowercase();
+        self.read(ctx, |commands| {
+            commands.get(&name).cloned().unwrap_or_default()
+        })
+    }
+
+    async fn list_latest(
+        &self,
+        ctx: &RequestContext,
+    ) -> SlashCommandDefinitionResult<Vec<VersionedSlashCommand>> {
+        self.read(ctx, |commands| {
+            commands
+                .values()
+                .filter_map(|versions| versions.last().cloned())
+                .collect()
+        })
+    }
+
+    async fn remove(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+    ) -> SlashCommandDefinitionResult<usize> {
+        let mut tenants = self.commands.write().map_err(|err| {
+            SlashCommandDefinitionError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        Ok(tenants
+            .get_mut(&ctx.tenant_id())
+            .and_then(|commands| commands.remove(&command.to_ascii_lowercase()))
+            .map_or(0, |versions| versions.len()))
+    }
+}
@@ -0,0 +1,97 @@
+//! In-memory implementation of the `SlashCommandExecutionRepository` port.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{ConversationId, SlashCommandExecutionId, SlashCommandExecutionRecord},
+    ports::slash_command_execution::{
+        SlashCommandExecutionError, SlashCommandExecutionRepository, SlashCommandExecutionResult,
+    },
+};
+use crate::pagination::{Cursor, Page, PageRequest};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+type TenantExecutions = HashMap<TenantId, Vec<SlashCommandExecutionRecord>>;
+
+/// Thread-safe in-memory slash-command audit trail. Suitable for unit tests
+/// only.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySlashCommandExecutionRepository {
+    records: Arc<RwLock<TenantExecutions>>,
+}
+
+impl InMemorySlashCommandExecutionRepository {
+    /// Creates an empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn read<T>(
+        &self,
+        ctx: &RequestContext,
+        query: impl FnOnce(&[SlashCommandExecutionRecord]) -> T,
+    ) -> SlashCommandExecutionResult<T> {
+        let tenants = self.records.read().map_err(|err| {
+            SlashCommandExecutionError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        Ok(query(
+            tenants
+                .get(&ctx.tenant_id())
+                .map(Vec::as_slice)
+                .unwrap_or_default(),
+        ))
+    }
+}
+
+/// Keyset position of a record in execution order.
+fn record_position(record: &SlashCommandExecutionRecord) -> Cursor {
+    Cursor::at_timestamp(record.executed_at(), record.id().into_inner())
+}
+
+#[async_trait]
+impl SlashCommandExecutionRepository for InMemorySlashCommandExecutionRepository {
+    async fn store(
+        &self,
+        ctx: &RequestContext,
+        record: &SlashCommandExecutionRecord,
+    ) -> SlashCommandExecutionResult<()> {
+        let mut tenants = self.records.write().map_err(|err| {
+            SlashCommandExecutionError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        let stored = tenants.entry(ctx.tenant_id()).or_default();
+        if stored.iter().any(|existing| existing.id() == record.id()) {
+            return Err(SlashCommandExecutionError::Duplicate(record.id()));
+        }
+        stored.push(record.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: SlashCommandExecutionId,
+    ) -> SlashCommandExecutionResult<Option<SlashCommandExecutionRecord>> {
+        self.read(ctx, |records| {
+            records.iter().find(|record| record.id() == id).cloned()
+        })
+    }
+
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> SlashCommandExecutionResult<Page<SlashCommandExecutionRecord>> {
+        let mut records: Vec<SlashCommandExecutionRecord> = self.read(ctx, |records| {
+            records
+                .iter()
+                .filter(|record| record.conversation_id() == conversation_id)
+                .cloned()
+                .collect()
+        })?;
+        records.sort_by_key(|record| (record.executed_at(), record.id().into_inner()));
+        Ok(Page::from_ordered(records, page, record_position))
+    }
+}
@@ -0,0 +1,75 @@
+//! In-memory implementation of the `SnapshotPolicyRepository` port.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{ConversationId, PeriodicSnapshotPolicy},
+    ports::snapshot_policy::{SnapshotPolicyError, SnapshotPolicyRepository, SnapshotPolicyResult},
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+type TenantPolicies = HashMap<TenantId, HashMap<ConversationId, PeriodicSnapshotPolicy>>;
+
+/// Thread-safe in-memory snapshot policy repository.
+///
+/// Policies are not checked against any conversation store, so setting one
+/// never fails with [`SnapshotPolicyError::ConversationNotFound`].
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySnapshotPolicyRepository {
+    policies: Arc<RwLock<TenantPolicies>>,
+}
+
+impl InMemorySnapshotPolicyRepository {
+    /// Creates an empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SnapshotPolicyRepository for InMemorySnapshotPolicyRepository {
+    async fn set_policy(
+        &self,
+        ctx: &RequestContext,
+        policy: &PeriodicSnapshotPolicy,
+    ) -> SnapshotPolicyResult<()> {
+        let mut tenants = self.policies.write().map_err(|err| {
+            SnapshotPolicyError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        tenants
+            .entry(ctx.tenant_id())
+            .or_default()
+            .insert(policy.conversation_id, *policy);
+        Ok(())
+    }
+
+    async fn clear_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> SnapshotPolicyResult<bool> {
+        let mut tenants = self.policies.write().map_err(|err| {
+            SnapshotPolicyError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        Ok(tenants
+            .get_mut(&ctx.tenant_id())
+            .and_then(|policies| policies.remove(&conversation_id))
+            .is_some())
+    }
+
+    async fn find_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> SnapshotPolicyResult<Option<PeriodicSnapshotPolicy>> {
+        let tenants = self.policies.read().map_err(|err| {
+            SnapshotPolicyError::persistence(std::io::Error::other(err.to_string()))
+        })?;
+        Ok(tenants
+            .get(&ctx.tenant_id())
+            .and_then(|policies| policies.get(&conversation_id))
+            .copied())
+    }
+}
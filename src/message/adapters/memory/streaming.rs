@@ -0,0 +1,130 @@
+//! In-memory implementation of the `PartialMessageRepository` port.
+//!
+//! Like the in-memory message repository, staged streams are keyed by
+//! message identifier alone. Finalised messages are appended to the attached
+//! [`InMemoryMessageRepository`].
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use super::InMemoryMessageRepository;
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{Message, MessageId, PartialMessage},
+    ports::{
+        repository::MessageRepository,
+        streaming::{PartialMessageRepository, StreamingError, StreamingResult},
+    },
+};
+
+type Streams = HashMap<MessageId, PartialMessage>;
+
+/// In-memory implementation of [`PartialMessageRepository`].
+///
+/// Thread-safe via internal [`RwLock`]. Suitable for unit tests only.
+#[derive(Debug, Clone)]
+pub struct InMemoryPartialMessageRepository {
+    streams: Arc<RwLock<Streams>>,
+    messages: InMemoryMessageRepository,
+}
+
+impl InMemoryPartialMessageRepository {
+    /// Creates an empty repository that finalises streams into `messages`.
+    #[must_use]
+    pub fn new(messages: InMemoryMessageRepository) -> Self {
+        Self {
+            streams: Arc::default(),
+            messages,
+        }
+    }
+
+    fn read_streams(&self) -> StreamingResult<RwLockReadGuard<'_, Streams>> {
+        self.streams
+            .read()
+            .map_err(|err| StreamingError::persistence(std::io::Error::other(err.to_string())))
+    }
+
+    fn write_streams(&self) -> StreamingResult<RwLockWriteGuard<'_, Streams>> {
+        self.streams
+            .write()
+            .map_err(|err| StreamingError::persistence(std::io::Error::other(err.to_string())))
+    }
+}
+
+#[async_trait]
+impl PartialMessageRepository for InMemoryPartialMessageRepository {
+    async fn start(&self, _ctx: &RequestContext, partial: &PartialMessage) -> StreamingResult<()> {
+        let mut streams = self.write_streams()?;
+        if streams.contains_key(&partial.message_id) {
+            return Err(StreamingError::AlreadyStarted(partial.message_id));
+        }
+        streams.insert(partial.message_id, partial.clone());
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        _ctx: &RequestContext,
+        partial: &PartialMessage,
+        expected_chunk_count: u32,
+    ) -> StreamingResult<()> {
+        let mut streams = self.write_streams()?;
+        let staged = streams
+            .get_mut(&partial.message_id)
+            .ok_or(StreamingError::NotFound(partial.message_id))?;
+        if staged.chunk_count != expected_chunk_count {
+            return Err(StreamingError::Conflict(partial.message_id));
+        }
+        partial.clone_into(staged);
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        _ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> StreamingResult<Option<PartialMessage>> {
+        Ok(self.read_streams()?.get(&message_id).cloned())
+    }
+
+    async fn find_stalled(
+        &self,
+        _ctx: &RequestContext,
+        updated_before: DateTime<Utc>,
+        limit: usize,
+    ) -> StreamingResult<Vec<PartialMessage>> {
+        let mut stalled: Vec<PartialMessage> = self
+            .read_streams()?
+            .values()
+            .filter(|partial| partial.updated_at < updated_before)
+            .cloned()
+            .collect();
+        stalled.sort_by_key(|partial| partial.updated_at);
+        stalled.truncate(limit);
+        Ok(stalled)
+    }
+
+    async fn finalise(&self, ctx: &RequestContext, message: &Message) -> StreamingResult<Message> {
+        let staged = self
+            .write_streams()?
+            .remove(&message.id())
+            .ok_or(StreamingError::NotFound(message.id()))?;
+        match self.messages.append(ctx, message).await {
+            Ok(stored) => Ok(stored),
+            Err(error) => {
+                self.write_streams()?.insert(staged.message_id, staged);
+                Err(error.into())
+            }
+        }
+    }
+
+    async fn discard(&self, _ctx: &RequestContext, message_id: MessageId) -> StreamingResult<()> {
+        self.write_streams()?
+            .remove(&message_id)
+            .map(|_| ())
+            .ok_or(StreamingError::NotFound(message_id))
+    }
+}
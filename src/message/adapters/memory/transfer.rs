@@ -0,0 +1,72 @@
+//! In-memory implementation of the conversation transfer port.
+//!
+//! The in-memory message, session, handoff, and snapshot adapters are not
+//! tenant-scoped, so moving the conversation between tenants in the
+//! conversation repository moves everything recorded against it.
+
+use super::InMemoryConversationRepository;
+use crate::context::RequestContext;
+use crate::message::{
+    domain::ConversationTransfer,
+    ports::transfer::{
+        ConversationTransferError, ConversationTransferRepository, ConversationTransferResult,
+    },
+};
+use crate::operator::ports::OperatorActionRepository;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// In-memory conversation transfer adapter.
+///
+/// Conversations move between tenants in the attached conversation
+/// repository, and the transfer's operator actions are recorded in the
+/// attached operator action repository, one for each tenant.
+#[derive(Clone)]
+pub struct InMemoryConversationTransferAdapter {
+    conversations: InMemoryConversationRepository,
+    operator_actions: Arc<dyn OperatorActionRepository>,
+}
+
+impl InMemoryConversationTransferAdapter {
+    /// Creates an adapter over `conversations` that records transfers in
+    /// `operator_actions`.
+    #[must_use]
+    pub fn new(
+        conversations: InMemoryConversationRepository,
+        operator_actions: Arc<dyn OperatorActionRepository>,
+    ) -> Self {
+        Self {
+            conversations,
+            operator_actions,
+        }
+    }
+}
+
+#[async_trait]
+impl ConversationTransferRepository for InMemoryConversationTransferAdapter {
+    async fn transfer(
+        &self,
+        ctx: &RequestContext,
+        transfer: &ConversationTransfer,
+    ) -> ConversationTransferResult<()> {
+        self.conversations.move_to_tenant(
+            transfer.conversation_id,
+            transfer.source_tenant_id,
+            transfer.target_tenant_id,
+        )?;
+        let target_ctx = RequestContext::new(
+            transfer.target_tenant_id,
+            ctx.correlation_id(),
+            ctx.user_id(),
+            ctx.session_id(),
+        );
+        self.operator_actions
+            .record(ctx, &transfer.outgoing)
+            .await
+            .map_err(ConversationTransferError::persistence)?;
+        self.operator_actions
+            .record(&target_ctx, &transfer.incoming)
+            .await
+            .map_err(ConversationTransferError::persistence)
+    }
+}
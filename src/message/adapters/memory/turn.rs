@@ -0,0 +1,119 @@
+//! In-memory implementation of the `TurnRepository` port.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{ConversationId, Message, Turn, TurnId},
+    ports::turn::{TurnError, TurnRepository, TurnResult},
+};
+use crate::pagination::{Cursor, Page, PageRequest};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+type TenantTurns = HashMap<TenantId, HashMap<TurnId, Turn>>;
+
+/// Thread-safe in-memory turn repository. Suitable for unit tests only.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTurnRepository {
+    turns: Arc<RwLock<TenantTurns>>,
+}
+
+impl InMemoryTurnRepository {
+    /// Creates an empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn write<T>(
+        &self,
+        ctx: &RequestContext,
+        apply: impl FnOnce(&mut HashMap<TurnId, Turn>) -> TurnResult<T>,
+    ) -> TurnResult<T> {
+        let mut tenants = self
+            .turns
+            .write()
+            .map_err(|err| TurnError::persistence(std::io::Error::other(err.to_string())))?;
+        apply(tenants.entry(ctx.tenant_id()).or_default())
+    }
+
+    fn read<T>(
+        &self,
+        ctx: &RequestContext,
+        query: impl FnOnce(Option<&HashMap<TurnId, Turn>>) -> T,
+    ) -> TurnResult<T> {
+        let tenants = self
+            .turns
+            .read()
+            .map_err(|err| TurnError::persistence(std::io::Error::other(err.to_string())))?;
+        Ok(query(tenants.get(&ctx.tenant_id())))
+    }
+}
+
+/// Keyset position of a turn in start order.
+fn turn_position(turn: &Turn) -> Cursor {
+    Cursor::at_timestamp(turn.started_at, turn.turn_id.into_inner())
+}
+
+#[async_trait]
+impl TurnRepository for InMemoryTurnRepository {
+    async fn store(&self, ctx: &RequestContext, turn: &Turn) -> TurnResult<()> {
+        self.write(ctx, |turns| {
+            if turns.contains_key(&turn.turn_id) {
+                return Err(TurnError::Duplicate(turn.turn_id));
+            }
+            turns.insert(turn.turn_id, turn.clone());
+            Ok(())
+        })
+    }
+
+    async fn update(&self, ctx: &RequestContext, turn: &Turn) -> TurnResult<()> {
+        self.write(ctx, |turns| {
+            let stored = turns
+                .get_mut(&turn.turn_id)
+                .ok_or(TurnError::NotFound(turn.turn_id))?;
+            stored.status = turn.status;
+            stored.ended_at = turn.ended_at;
+            Ok(())
+        })
+    }
+
+    async fn record_response(
+        &self,
+        ctx: &RequestContext,
+        turn_id: TurnId,
+        message: &Message,
+    ) -> TurnResult<()> {
+        self.write(ctx, |turns| {
+            let stored = turns
+                .get_mut(&turn_id)
+                .ok_or(TurnError::NotFound(turn_id))?;
+            stored.record_response(message)?;
+            Ok(())
+        })
+    }
+
+    async fn find_by_id(&self, ctx: &RequestContext, turn_id: TurnId) -> TurnResult<Option<Turn>> {
+        self.read(ctx, |turns| {
+            turns.and_then(|found| found.get(&turn_id)).cloned()
+        })
+    }
+
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> TurnResult<Page<Turn>> {
+        let mut turns: Vec<Turn> = self.read(ctx, |turns| {
+            turns
+                .into_iter()
+                .flat_map(HashMap::values)
+                .filter(|turn| turn.conversation_id == conversation_id)
+                .cloned()
+                .collect()
+        })?;
+        turns.sort_by_key(|turn| (turn.started_at, turn.turn_id.into_inner()));
+        Ok(Page::from_ordered(turns, page, turn_position))
+    }
+}
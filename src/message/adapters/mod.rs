@@ -10,6 +10,30 @@
 //!   unit testing
 //! - [`postgres::PostgresMessageRepository`]: Production-grade `PostgreSQL`
 //!   persistence using Diesel ORM
+//! - `postgres::AsyncPostgresMessageRepository`: The same persistence over
+//!   `diesel-async` and deadpool, with the `async-postgres` feature
+//! - [`blob_store::ObjectStoreBlobStore`]: Content-addressed attachment
+//!   blobs on the local filesystem, S3 (with the `s3` feature), or memory
+//! - [`caching::CachingMessageRepository`]: Serves repeated conversation
+//!   history reads of any message repository from an in-process cache
+//! - [`content_scanning::RegexContentScanner`]: Regular-expression
+//!   detection of email addresses, phone numbers and API keys in message text
+//! - [`encryption::AesGcmContentCipher`]: AES-GCM envelope encryption of
+//!   message content and metadata for the `PostgreSQL` repository
+//! - [`export`]: JSON Lines, Markdown, and `OpenAI` fine-tuning formats for
+//!   conversation exports
+//! - [`externalising::ExternalisingMessageRepository`]: Keeps large
+//!   attachment data of any message repository in a blob store
+//! - [`maintenance::MaintenanceGatedMessageRepository`]: Refuses writes to
+//!   any message repository while the cluster is in maintenance
+//! - [`inbound`]: Signature verification and payload parsing for messages
+//!   arriving from external chat systems and email
+//! - [`smtp::SmtpEmailNotifier`]: Delivery of agent replies to email-driven
+//!   conversations through an SMTP relay
+//! - [`token_counting`]: Byte-pair and heuristic token counters for
+//!   measuring messages in model tokens
+//! - [`transcript`]: Conversion of `OpenAI` and Anthropic transcripts into
+//!   canonical messages
 //!
 //! # Audit Context
 //!
@@ -20,7 +44,19 @@
 //! [`MessageRepository`]: crate::message::ports::repository::MessageRepository
 
 pub mod audit_context;
+pub(crate) mod batch;
+pub mod blob_store;
+pub mod caching;
+pub mod content_scanning;
+pub mod encryption;
+pub mod export;
+pub mod externalising;
+pub mod inbound;
+pub mod maintenance;
 pub mod memory;
 pub mod models;
 pub mod postgres;
 pub mod schema;
+pub mod smtp;
+pub mod token_counting;
+pub mod transcript;
@@ -40,6 +40,8 @@ pub struct AgentSessionRow {
     pub ended_at: Option<DateTime<Utc>>,
     /// Session state.
     pub state: String,
+    /// Optimistic concurrency version.
+    pub version: i64,
 }
 
 /// Data for inserting a new agent session.
@@ -72,4 +74,6 @@ pub struct NewAgentSession {
     pub ended_at: Option<DateTime<Utc>>,
     /// Session state.
     pub state: String,
+    /// Optimistic concurrency version.
+    pub version: i64,
 }
@@ -36,6 +36,10 @@ pub struct ContextSnapshotRow {
     pub captured_at: DateTime<Utc>,
     /// Type of snapshot.
     pub snapshot_type: String,
+    /// Summary standing in for the range, for compactions.
+    pub summary_text: Option<String>,
+    /// Snapshot an incremental snapshot extends.
+    pub parent_snapshot_id: Option<Uuid>,
 }
 
 /// Data for inserting a new context snapshot.
@@ -64,4 +68,8 @@ pub struct NewContextSnapshot {
     pub captured_at: DateTime<Utc>,
     /// Type of snapshot.
     pub snapshot_type: String,
+    /// Summary standing in for the range, for compactions.
+    pub summary_text: Option<String>,
+    /// Snapshot an incremental snapshot extends.
+    pub parent_snapshot_id: Option<Uuid>,
 }
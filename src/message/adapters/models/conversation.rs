@@ -8,7 +8,7 @@ use serde_json::Value;
 use uuid::Uuid;
 
 use super::super::schema::conversations;
-use crate::message::domain::ConversationState;
+use crate::message::domain::{Conversation, ConversationState};
 
 /// Database row representation of a conversation.
 #[derive(Debug, Clone, Queryable, Selectable)]
@@ -25,10 +25,14 @@ pub struct ConversationRow {
     pub context: Value,
     /// Conversation state.
     pub state: String,
+    /// Key/value labels as a JSON object of strings.
+    pub labels: Value,
     /// When the conversation was created.
     pub created_at: DateTime<Utc>,
     /// When the conversation was last updated.
     pub updated_at: DateTime<Utc>,
+    /// Optimistic concurrency version.
+    pub version: i64,
 }
 
 /// Data for inserting a new conversation.
@@ -45,10 +49,14 @@ pub struct NewConversation {
     pub context: Value,
     /// Conversation state.
     pub state: String,
+    /// Key/value labels as a JSON object of strings.
+    pub labels: Value,
     /// When the conversation was created.
     pub created_at: DateTime<Utc>,
     /// When the conversation was last updated.
     pub updated_at: DateTime<Utc>,
+    /// Optimistic concurrency version.
+    pub version: i64,
 }
 
 impl NewConversation {
@@ -61,8 +69,46 @@ impl NewConversation {
             task_id: None,
             context: Value::Object(serde_json::Map::new()),
             state: ConversationState::Active.as_str().to_owned(),
+            labels: Value::Object(serde_json::Map::new()),
             created_at: now,
             updated_at: now,
+            version: 0,
         }
     }
+
+    /// Creates a record holding a conversation's current state, context,
+    /// task link, labels, and version.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`std::num::TryFromIntError`] when the version does not fit
+    /// the database column.
+    pub fn from_domain(
+        conversation: &Conversation,
+        tenant_id: Uuid,
+    ) -> Result<Self, std::num::TryFromIntError> {
+        Ok(Self {
+            id: conversation.id().into_inner(),
+            tenant_id,
+            task_id: conversation.task_id(),
+            context: conversation.context().clone(),
+            state: conversation.state().as_str().to_owned(),
+            labels: labels_column(conversation),
+            created_at: conversation.created_at(),
+            updated_at: conversation.updated_at(),
+            version: i64::try_from(conversation.version())?,
+        })
+    }
+}
+
+/// Returns a conversation's labels as the JSON object stored in the
+/// `labels` column.
+fn labels_column(conversation: &Conversation) -> Value {
+    Value::Object(
+        conversation
+            .labels()
+            .iter()
+            .map(|(key, value)| (key.clone(), Value::String(value.clone())))
+            .collect(),
+    )
 }
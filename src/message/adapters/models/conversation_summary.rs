@@ -0,0 +1,34 @@
+//! Diesel model for the conversation summary projection.
+//!
+//! Maps rows of the trigger-maintained `conversation_summaries` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::super::schema::conversation_summaries;
+
+/// Database row representation of a conversation summary.
+#[derive(Debug, Clone, Queryable, QueryableByName, Selectable)]
+#[diesel(table_name = conversation_summaries)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ConversationSummaryRow {
+    /// Summarised conversation identifier.
+    pub conversation_id: Uuid,
+    /// Tenant that owns the conversation.
+    pub tenant_id: Uuid,
+    /// Conversation state.
+    pub state: String,
+    /// Task the conversation works on, if linked.
+    pub task_id: Option<Uuid>,
+    /// Labels of the linked task's issue.
+    pub labels: Vec<String>,
+    /// Agent backend named by the most recent message carrying one.
+    pub agent_backend: Option<String>,
+    /// Number of messages in the conversation.
+    pub message_count: i64,
+    /// When the conversation was created.
+    pub created_at: DateTime<Utc>,
+    /// Latest conversation update or message creation time.
+    pub last_activity_at: DateTime<Utc>,
+}
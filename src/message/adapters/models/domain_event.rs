@@ -36,9 +36,13 @@ pub struct DomainEventRow {
     pub user_id: Option<Uuid>,
     /// Session context.
     pub session_id: Option<Uuid>,
+    /// Global stream position.
+    pub position: i64,
 }
 
 /// Data for inserting a new domain event.
+///
+/// The stream position is assigned by the database.
 #[derive(Debug, Clone, Insertable)]
 #[diesel(table_name = domain_events)]
 pub struct NewDomainEvent {
@@ -0,0 +1,40 @@
+//! Diesel model for message feedback persistence.
+//!
+//! Maps rows of the `message_feedback` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::super::schema::message_feedback;
+
+/// Database row representation of message feedback.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = message_feedback)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MessageFeedbackRow {
+    /// Unique feedback identifier.
+    pub id: Uuid,
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Conversation containing the rated message.
+    pub conversation_id: Uuid,
+    /// Rated message identifier.
+    pub message_id: Uuid,
+    /// Reviewer identifier.
+    pub author_id: Uuid,
+    /// Rating kind.
+    pub rating: String,
+    /// Star score for star ratings.
+    pub stars: Option<i16>,
+    /// Optional free-text comment.
+    pub comment: Option<String>,
+    /// Optional feedback category.
+    pub category: Option<String>,
+    /// Backend that produced the rated message.
+    pub agent_backend: Option<String>,
+    /// Instruction-set version the backend ran with.
+    pub instruction_set_version: Option<String>,
+    /// When the feedback was last submitted.
+    pub submitted_at: DateTime<Utc>,
+}
@@ -0,0 +1,28 @@
+//! Diesel model for conversation fork provenance.
+//!
+//! Maps rows of the `conversation_forks` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::super::schema::conversation_forks;
+
+/// Database row representation of a conversation fork.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = conversation_forks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ConversationForkRow {
+    /// The fork's conversation identifier.
+    pub conversation_id: Uuid,
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Conversation the fork was taken from.
+    pub parent_conversation_id: Uuid,
+    /// Last parent sequence number copied into the fork.
+    pub forked_at_sequence: i64,
+    /// Parent's latest context snapshot ending at or before the fork point.
+    pub parent_snapshot_id: Option<Uuid>,
+    /// When the fork was created.
+    pub created_at: DateTime<Utc>,
+}
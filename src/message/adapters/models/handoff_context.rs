@@ -0,0 +1,29 @@
+//! Diesel model for handoff context package persistence.
+//!
+//! Maps rows of the `handoff_context_packages` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::super::schema::handoff_context_packages;
+
+/// Database row representation of a handoff context package.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = handoff_context_packages)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct HandoffContextPackageRow {
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Session that took the conversation over.
+    pub target_session_id: Uuid,
+    /// Completed handoff the package was prepared for.
+    pub handoff_id: Uuid,
+    /// Serialized package.
+    pub package: Value,
+    /// When the package was prepared.
+    pub prepared_at: DateTime<Utc>,
+    /// Conversation handed over.
+    pub conversation_id: Uuid,
+}
@@ -0,0 +1,34 @@
+//! Diesel model for conversation label history.
+//!
+//! Maps rows of the `conversation_label_events` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::super::schema::conversation_label_events;
+
+/// Database row representation of a conversation label change.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = conversation_label_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ConversationLabelEventRow {
+    /// Unique event identifier.
+    pub id: Uuid,
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Relabelled conversation identifier.
+    pub conversation_id: Uuid,
+    /// The label key.
+    pub label_key: String,
+    /// Value before the change, if the label was set.
+    pub previous_value: Option<String>,
+    /// Value after the change, unless the label was removed.
+    pub label_value: Option<String>,
+    /// User who made the change.
+    pub actor_id: Uuid,
+    /// Correlation identifier of the request that made the change.
+    pub correlation_id: Uuid,
+    /// When the change was made.
+    pub occurred_at: DateTime<Utc>,
+}
@@ -6,13 +6,39 @@
 mod agent_session;
 mod context_snapshot;
 mod conversation;
+mod conversation_summary;
 mod domain_event;
+mod feedback;
+mod fork;
 mod handoff;
+mod handoff_context;
+mod label;
 mod message;
+mod partial_message;
+mod periodic_snapshot;
+mod processing;
+mod redaction;
+mod rolling_summary;
+mod slash_command_definition;
+mod slash_command_execution;
+mod turn;
 
 pub use agent_session::{AgentSessionRow, NewAgentSession};
 pub use context_snapshot::{ContextSnapshotRow, NewContextSnapshot};
 pub use conversation::{ConversationRow, NewConversation};
+pub use conversation_summary::ConversationSummaryRow;
 pub use domain_event::{DomainEventRow, NewDomainEvent};
+pub use feedback::MessageFeedbackRow;
+pub use fork::ConversationForkRow;
 pub use handoff::{HandoffRow, NewHandoff};
+pub use handoff_context::HandoffContextPackageRow;
+pub use label::ConversationLabelEventRow;
 pub use message::{MessageRow, NewMessage};
+pub use partial_message::PartialMessageRow;
+pub use periodic_snapshot::PeriodicSnapshotPolicyRow;
+pub use processing::MessageProcessingStageRow;
+pub use redaction::MessageRedactionRow;
+pub use rolling_summary::RollingSummaryRow;
+pub use slash_command_definition::SlashCommandDefinitionRow;
+pub use slash_command_execution::SlashCommandExecutionRow;
+pub use turn::TurnRow;
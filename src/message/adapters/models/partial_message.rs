@@ -0,0 +1,33 @@
+//! Diesel model for streamed message staging.
+//!
+//! Maps rows of the `partial_messages` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::super::schema::partial_messages;
+
+/// Database row representation of a staged stream.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = partial_messages)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PartialMessageRow {
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Identifier the finalised message will carry.
+    pub message_id: Uuid,
+    /// Conversation the message will join.
+    pub conversation_id: Uuid,
+    /// Metadata the finalised message will carry.
+    pub metadata: Value,
+    /// Text received so far, as a JSON string or a sealed envelope.
+    pub content: Value,
+    /// Number of chunks received so far.
+    pub chunk_count: i32,
+    /// When the stream started.
+    pub started_at: DateTime<Utc>,
+    /// When the stream last received a chunk.
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,25 @@
+//! Diesel model for periodic snapshot policy persistence.
+//!
+//! Maps rows of the `periodic_snapshot_policies` table.
+
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::super::schema::periodic_snapshot_policies;
+
+/// Database row representation of a conversation's periodic snapshot
+/// policy.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = periodic_snapshot_policies)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(treat_none_as_null = true)]
+pub struct PeriodicSnapshotPolicyRow {
+    /// The conversation the policy applies to.
+    pub conversation_id: Uuid,
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Messages between snapshots.
+    pub every_messages: Option<i32>,
+    /// Minutes between snapshots.
+    pub every_minutes: Option<i32>,
+}
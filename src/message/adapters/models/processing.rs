@@ -0,0 +1,32 @@
+//! Diesel model for message processing status persistence.
+//!
+//! Maps rows of the `message_processing_stages` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::super::schema::message_processing_stages;
+
+/// Database row representation of one stage's progress on a message.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = message_processing_stages)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MessageProcessingStageRow {
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Processed message identifier.
+    pub message_id: Uuid,
+    /// Conversation containing the message.
+    pub conversation_id: Uuid,
+    /// Stage name.
+    pub stage: String,
+    /// Stage state.
+    pub state: String,
+    /// Number of times the stage picked the message up.
+    pub attempts: i32,
+    /// Failure reason from the latest failed attempt.
+    pub last_error: Option<String>,
+    /// When the status last changed.
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,32 @@
+//! Diesel model for message redaction tombstones.
+//!
+//! Maps rows of the `message_redactions` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::super::schema::message_redactions;
+
+/// Database row representation of a redaction tombstone.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = message_redactions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MessageRedactionRow {
+    /// Redacted message identifier.
+    pub message_id: Uuid,
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Conversation containing the message.
+    pub conversation_id: Uuid,
+    /// Sequence number the message keeps.
+    pub sequence_number: i64,
+    /// Why the message was redacted.
+    pub reason: String,
+    /// User who redacted the message.
+    pub redacted_by: Uuid,
+    /// Correlation ID of the redacting request.
+    pub correlation_id: Uuid,
+    /// When the message was redacted.
+    pub redacted_at: DateTime<Utc>,
+}
@@ -0,0 +1,30 @@
+//! Diesel model for rolling conversation summary persistence.
+//!
+//! Maps rows of the `conversation_rolling_summaries` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::super::schema::conversation_rolling_summaries;
+
+/// Database row representation of a rolling conversation summary.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = conversation_rolling_summaries)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RollingSummaryRow {
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Summarised conversation identifier.
+    pub conversation_id: Uuid,
+    /// Summary text.
+    pub summary: String,
+    /// Sequence number of the last message the summary covers.
+    pub covered_through: i64,
+    /// Incremental updates since the last full recompute.
+    pub incremental_updates: i32,
+    /// When the summary was last rebuilt from the full conversation.
+    pub recomputed_at: DateTime<Utc>,
+    /// When the summary last changed.
+    pub updated_at: DateTime<Utc>,
+}
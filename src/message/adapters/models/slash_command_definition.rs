@@ -0,0 +1,28 @@
+//! Diesel model for slash-command definition persistence.
+//!
+//! Maps rows of the `slash_command_definitions` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::super::schema::slash_command_definitions;
+
+/// Database row representation of one version of a slash-command
+/// definition.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = slash_command_definitions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct SlashCommandDefinitionRow {
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Qualified command name without the leading slash.
+    pub command: String,
+    /// Definition version.
+    pub version: i32,
+    /// Serialized definition.
+    pub definition: Value,
+    /// When the version was registered.
+    pub registered_at: DateTime<Utc>,
+}
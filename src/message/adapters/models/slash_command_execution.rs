@@ -0,0 +1,31 @@
+//! Diesel model for the slash-command execution audit trail.
+//!
+//! Maps rows of the `slash_command_executions` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::super::schema::slash_command_executions;
+
+/// Database row representation of a recorded slash-command execution.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = slash_command_executions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct SlashCommandExecutionRow {
+    /// Unique execution record identifier.
+    pub id: Uuid,
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Conversation the command ran in.
+    pub conversation_id: Uuid,
+    /// User who ran the command.
+    pub invoked_by: Uuid,
+    /// Qualified command name without the leading slash.
+    pub command: String,
+    /// Serialized execution.
+    pub execution: Value,
+    /// When the command ran.
+    pub executed_at: DateTime<Utc>,
+}
@@ -0,0 +1,37 @@
+//! Diesel model for turn persistence.
+//!
+//! Maps rows of the `turns` table.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::super::schema::turns;
+
+/// Database row representation of a turn.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = turns)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TurnRow {
+    /// Unique turn identifier.
+    pub id: Uuid,
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Conversation the turn belongs to.
+    pub conversation_id: Uuid,
+    /// Agent session that handled the turn.
+    pub session_id: Uuid,
+    /// Message that started the turn.
+    pub initiating_message_id: Uuid,
+    /// Messages written in response, as JSONB.
+    pub response_message_ids: Value,
+    /// Tool calls the responses made, as JSONB.
+    pub tool_calls: Value,
+    /// Turn status.
+    pub status: String,
+    /// When the turn started.
+    pub started_at: DateTime<Utc>,
+    /// When the turn completed or failed.
+    pub ended_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,205 @@
+//! `PostgreSQL` implementation of the `ConversationActivityPort`.
+//!
+//! Activity statistics are computed in a single aggregate query: messages are
+//! grouped with `date_trunc` on `created_at`, tool calls and failed tool
+//! results are counted from the JSONB content array (or, for sealed rows,
+//! the part summaries beside the envelope), and handoffs are grouped
+//! on `initiated_at`. Only bucket rows cross the wire, so dashboards never
+//! load full conversation histories.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ActivityBucket, ActivityQuery, ConversationActivity, MessageSummary},
+    ports::activity::{ActivityError, ActivityResult, ConversationActivityPort},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Text, Timestamptz, Uuid as SqlUuid};
+
+use super::blocking_helpers::{PgPool, run_blocking_with};
+use super::replica::{ReadReplica, read_connection};
+use super::tenant_tx::{FromTxError, TxError, with_tenant_read_tx};
+
+impl FromTxError<Self> for ActivityError {
+    fn from_tx_error(tx_err: TxError<Self>) -> Self {
+        match tx_err {
+            TxError::Domain(domain_err) => domain_err,
+            TxError::Diesel(diesel_err) => Self::persistence(diesel_err),
+        }
+    }
+}
+
+/// Aggregates messages and handoffs into buckets for one conversation.
+///
+/// Bind parameters: `$1` bucket width, `$2` tenant, `$3` conversation,
+/// `$4` window start (inclusive), `$5` window end (exclusive).
+const ACTIVITY_SQL: &str = concat!(
+    "WITH message_stats AS (",
+    "SELECT date_trunc($1, m.created_at, 'UTC') AS bucket_start, ",
+    "COUNT(*) FILTER (WHERE m.role = 'user') AS user_count, ",
+    "COUNT(*) FILTER (WHERE m.role = 'assistant') AS assistant_count, ",
+    "COUNT(*) FILTER (WHERE m.role = 'tool') AS tool_count, ",
+    "COUNT(*) FILTER (WHERE m.role = 'system') AS system_count, ",
+    "COALESCE(SUM(parts.tool_calls), 0)::BIGINT AS tool_calls, ",
+    "COALESCE(SUM(parts.tool_errors), 0)::BIGINT AS tool_errors ",
+    "FROM messages m ",
+    "CROSS JOIN LATERAL (",
+    "SELECT COUNT(*) FILTER (WHERE part->>'type' = 'tool_call') AS tool_calls, ",
+    "COUNT(*) FILTER (WHERE part->>'type' = 'tool_result' ",
+    "AND part->>'success' = 'false') AS tool_errors ",
+    "FROM jsonb_array_elements(CASE WHEN jsonb_typeof(m.content) = 'array' ",
+    "THEN m.content ELSE COALESCE(m.content -> '$parts', '[]'::jsonb) END) AS part",
+    ") parts ",
+    "WHERE m.tenant_id = $2 AND m.conversation_id = $3 ",
+    "AND m.created_at >= $4 AND m.created_at < $5 ",
+    "GROUP BY 1",
+    "), handoff_stats AS (",
+    "SELECT date_trunc($1, h.initiated_at, 'UTC') AS bucket_start, ",
+    "COUNT(*) AS handoffs ",
+    "FROM handoffs h ",
+    "WHERE h.tenant_id = $2 AND h.conversation_id = $3 ",
+    "AND h.initiated_at >= $4 AND h.initiated_at < $5 ",
+    "GROUP BY 1",
+    ") ",
+    "SELECT COALESCE(ms.bucket_start, hs.bucket_start) AS bucket_start, ",
+    "COALESCE(ms.user_count, 0) AS user_count, ",
+    "COALESCE(ms.assistant_count, 0) AS assistant_count, ",
+    "COALESCE(ms.tool_count, 0) AS tool_count, ",
+    "COALESCE(ms.system_count, 0) AS system_count, ",
+    "COALESCE(ms.tool_calls, 0) AS tool_calls, ",
+    "COALESCE(ms.tool_errors, 0) AS tool_errors, ",
+    "COALESCE(hs.handoffs, 0) AS handoffs ",
+    "FROM message_stats ms ",
+    "FULL OUTER JOIN handoff_stats hs ON ms.bucket_start = hs.bucket_start ",
+    "ORDER BY 1",
+);
+
+/// Query result row for one activity bucket.
+#[derive(Debug, QueryableByName)]
+struct ActivityBucketRow {
+    #[diesel(sql_type = Timestamptz)]
+    bucket_start: DateTime<Utc>,
+    #[diesel(sql_type = BigInt)]
+    user_count: i64,
+    #[diesel(sql_type = BigInt)]
+    assistant_count: i64,
+    #[diesel(sql_type = BigInt)]
+    tool_count: i64,
+    #[diesel(sql_type = BigInt)]
+    system_count: i64,
+    #[diesel(sql_type = BigInt)]
+    tool_calls: i64,
+    #[diesel(sql_type = BigInt)]
+    tool_errors: i64,
+    #[diesel(sql_type = BigInt)]
+    handoffs: i64,
+}
+
+/// `PostgreSQL` implementation of [`ConversationActivityPort`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access. With a [`ReadReplica`], reads go to the replica
+/// while it keeps within its staleness tolerance.
+#[derive(Debug, Clone)]
+pub struct PostgresConversationActivityAdapter {
+    pool: PgPool,
+    replica: Option<ReadReplica>,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresConversationActivityAdapter,
+    "conversation_activity"
+);
+
+impl PostgresConversationActivityAdapter {
+    /// Creates a new adapter with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            replica: None,
+        }
+    }
+
+    /// Reads from `replica`, falling back to the primary pool when the
+    /// replica is exhausted or stale.
+    #[must_use]
+    pub fn with_read_replica(mut self, replica: ReadReplica) -> Self {
+        self.replica = Some(replica);
+        self
+    }
+}
+
+#[async_trait]
+impl ConversationActivityPort for PostgresConversationActivityAdapter {
+    async fn conversation_activity(
+        &self,
+        ctx: &RequestContext,
+        query: &ActivityQuery,
+    ) -> ActivityResult<ConversationActivity> {
+        ActivityError::check_window(query)?;
+
+        let pool = self.pool.clone();
+        let replica = self.replica.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let query = *query;
+
+        let rows = run_blocking_with(
+            move || {
+                let mut conn =
+                    read_connection(&pool, replica.as_ref()).map_err(ActivityError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    load_bucket_rows(tx, tenant_uuid, &query)
+                })
+            },
+            ActivityError::persistence,
+        )
+        .await?;
+
+        let buckets = rows
+            .iter()
+            .map(row_to_bucket)
+            .collect::<ActivityResult<Vec<_>>>()?;
+
+        Ok(ConversationActivity {
+            conversation_id: query.conversation_id,
+            bucket_width: query.bucket_width,
+            buckets,
+        })
+    }
+}
+
+fn load_bucket_rows(
+    conn: &mut PgConnection,
+    tenant_uuid: uuid::Uuid,
+    query: &ActivityQuery,
+) -> ActivityResult<Vec<ActivityBucketRow>> {
+    diesel::sql_query(ACTIVITY_SQL)
+        .bind::<Text, _>(query.bucket_width.as_str())
+        .bind::<SqlUuid, _>(tenant_uuid)
+        .bind::<SqlUuid, _>(query.conversation_id.into_inner())
+        .bind::<Timestamptz, _>(query.from)
+        .bind::<Timestamptz, _>(query.until)
+        .load::<ActivityBucketRow>(conn)
+        .map_err(ActivityError::persistence)
+}
+
+/// Converts an aggregate row to a domain [`ActivityBucket`].
+fn row_to_bucket(row: &ActivityBucketRow) -> ActivityResult<ActivityBucket> {
+    let count = |value: i64| u32::try_from(value).map_err(ActivityError::persistence);
+
+    Ok(ActivityBucket {
+        bucket_start: row.bucket_start,
+        message_summary: MessageSummary::new(
+            count(row.user_count)?,
+            count(row.assistant_count)?,
+            count(row.tool_count)?,
+            count(row.system_count)?,
+        ),
+        tool_calls: count(row.tool_calls)?,
+        tool_errors: count(row.tool_errors)?,
+        handoffs: count(row.handoffs)?,
+    })
+}
@@ -67,6 +67,34 @@ pub(super) fn map_update_error(
     SessionError::persistence(err)
 }
 
+/// Explains why an update guarded by `expected` matched no row: either the
+/// session does not exist, or another writer has bumped its version.
+pub(super) fn missing_or_stale(
+    conn: &mut PgConnection,
+    tenant_id: TenantId,
+    session_id: AgentSessionId,
+    expected: u64,
+) -> SessionError {
+    let stored = agent_sessions::table
+        .filter(agent_sessions::id.eq(session_id.into_inner()))
+        .filter(agent_sessions::tenant_id.eq(tenant_id.into_inner()))
+        .select(agent_sessions::version)
+        .first::<i64>(conn)
+        .optional();
+    match stored {
+        Ok(None) => SessionError::NotFound(session_id),
+        Ok(Some(actual)) => match u64::try_from(actual) {
+            Ok(actual) => SessionError::ConcurrentModification {
+                session_id,
+                expected,
+                actual,
+            },
+            Err(err) => SessionError::persistence(err),
+        },
+        Err(err) => SessionError::persistence(err),
+    }
+}
+
 /// Checks that no other active session exists for the given conversation.
 ///
 /// When `exclude_id` is `Some`, the check ignores the session with that ID
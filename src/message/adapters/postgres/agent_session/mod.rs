@@ -17,6 +17,7 @@ use diesel::pg::PgConnection;
 use diesel::prelude::*;
 
 use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::conversation_guard::conversation_is_archived;
 use super::tenant_tx::{with_tenant_read_tx, with_tenant_tx};
 use crate::context::{RequestContext, TenantId};
 use crate::message::{
@@ -25,9 +26,14 @@ use crate::message::{
     domain::{AgentSession, AgentSessionId, AgentSessionState, ConversationId},
     ports::agent_session::{AgentSessionRepository, SessionError, SessionResult},
 };
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
 
-use constraint_helpers::{check_no_active_session, map_insert_error, map_update_error};
-use row_mapping::{row_to_session, session_to_new_row, session_to_update_values};
+use constraint_helpers::{
+    check_no_active_session, map_insert_error, map_update_error, missing_or_stale,
+};
+pub(super) use row_mapping::row_to_session;
+use row_mapping::{session_to_new_row, session_to_update_values};
 
 // ---------------------------------------------------------------------------
 // Adapter
@@ -42,6 +48,11 @@ pub struct PostgresAgentSessionRepository {
     pool: PgPool,
 }
 
+crate::postgres_support::pool_health_check!(
+    PostgresAgentSessionRepository,
+    "agent_session_repository"
+);
+
 impl PostgresAgentSessionRepository {
     /// Creates a new repository with the given connection pool.
     #[must_use]
@@ -110,6 +121,36 @@ impl PostgresAgentSessionRepository {
         })
         .await
     }
+
+    /// Execute a query that returns one page of sessions ordered by start
+    /// time.
+    async fn find_page<F>(
+        &self,
+        tenant_id: TenantId,
+        page: PageRequest,
+        build_query: F,
+    ) -> SessionResult<Page<AgentSession>>
+    where
+        F: FnOnce(
+                agent_sessions::BoxedQuery<'static, diesel::pg::Pg>,
+            ) -> agent_sessions::BoxedQuery<'static, diesel::pg::Pg>
+            + Send
+            + 'static,
+    {
+        let sessions = self
+            .find_many(tenant_id, move |q| {
+                keyset_page!(
+                    build_query(q),
+                    page,
+                    after_timestamp,
+                    (agent_sessions::started_at, agent_sessions::id)
+                )
+            })
+            .await?;
+        Ok(Page::from_overfetched(sessions, page, |session| {
+            Cursor::at_timestamp(session.started_at, session.session_id.into_inner())
+        }))
+    }
 }
 
 #[async_trait]
@@ -121,11 +162,17 @@ impl AgentSessionRepository for PostgresAgentSessionRepository {
         let session_id = session.session_id;
         let conversation_id = session.conversation_id;
         let is_active = session.state == AgentSessionState::Active;
+        let ctx = ctx.clone();
 
         run_blocking_with(
             move || {
                 let mut conn = get_conn_with(&pool, SessionError::persistence)?;
                 with_tenant_tx(&mut conn, tenant_id.into_inner(), |tx| {
+                    if conversation_is_archived(tx, &ctx, conversation_id)
+                        .map_err(SessionError::persistence)?
+                    {
+                        return Err(SessionError::ConversationArchived(conversation_id));
+                    }
                     diesel::insert_into(agent_sessions::table)
                         .values(&new_session)
                         .execute(tx)
@@ -149,6 +196,8 @@ impl AgentSessionRepository for PostgresAgentSessionRepository {
         let session_id = session.session_id;
         let conversation_id = session.conversation_id;
         let is_active = session.state == AgentSessionState::Active;
+        let expected = session.version;
+        let expected_version = i64::try_from(expected).map_err(SessionError::persistence)?;
         let updated = session_to_update_values(session)?;
 
         run_blocking_with(
@@ -158,14 +207,15 @@ impl AgentSessionRepository for PostgresAgentSessionRepository {
                     let updated_rows = diesel::update(
                         agent_sessions::table
                             .filter(agent_sessions::id.eq(session_id.into_inner()))
-                            .filter(agent_sessions::tenant_id.eq(tenant_id.into_inner())),
+                            .filter(agent_sessions::tenant_id.eq(tenant_id.into_inner()))
+                            .filter(agent_sessions::version.eq(expected_version)),
                     )
                     .set(&updated)
                     .execute(tx)
                     .map_err(|err| map_update_error(err, session_id, conversation_id))?;
 
                     if updated_rows == 0 {
-                        return Err(SessionError::NotFound(session_id));
+                        return Err(missing_or_stale(tx, tenant_id, session_id, expected));
                     }
 
                     if is_active {
@@ -197,23 +247,43 @@ impl AgentSessionRepository for PostgresAgentSessionRepository {
         ctx: &RequestContext,
         conversation_id: ConversationId,
     ) -> SessionResult<Option<AgentSession>> {
-        let sessions = self.find_by_conversation(ctx, conversation_id).await?;
-        Ok(sessions
-            .into_iter()
-            .find(|s| s.state == AgentSessionState::Active))
+        let tenant_id = ctx.tenant_id();
+        let uuid = conversation_id.into_inner();
+
+        self.find_one(tenant_id, move |q| {
+            q.filter(agent_sessions::conversation_id.eq(uuid))
+                .filter(agent_sessions::state.eq(AgentSessionState::Active.as_str()))
+        })
+        .await
     }
 
     async fn find_by_conversation(
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> SessionResult<Vec<AgentSession>> {
+        page: PageRequest,
+    ) -> SessionResult<Page<AgentSession>> {
         let tenant_id = ctx.tenant_id();
         let uuid = conversation_id.into_inner();
 
-        self.find_many(tenant_id, move |q| {
+        self.find_page(tenant_id, page, move |q| {
             q.filter(agent_sessions::conversation_id.eq(uuid))
-                .order(agent_sessions::started_at.asc())
+        })
+        .await
+    }
+
+    async fn find_active_by_backend(
+        &self,
+        ctx: &RequestContext,
+        agent_backend: &str,
+        page: PageRequest,
+    ) -> SessionResult<Page<AgentSession>> {
+        let tenant_id = ctx.tenant_id();
+        let backend = agent_backend.to_owned();
+
+        self.find_page(tenant_id, page, move |q| {
+            q.filter(agent_sessions::agent_backend.eq(backend))
+                .filter(agent_sessions::state.eq(AgentSessionState::Active.as_str()))
         })
         .await
     }
@@ -27,6 +27,7 @@ pub(super) struct AgentSessionUpdate {
     pub context_snapshots: serde_json::Value,
     pub ended_at: Option<chrono::DateTime<chrono::Utc>>,
     pub state: String,
+    pub version: i64,
 }
 
 /// Converts a domain `AgentSession` to a `NewAgentSession` for insertion.
@@ -43,6 +44,8 @@ pub(super) fn session_to_new_row(
 
     let end_sequence = session_end_sequence(session)?;
 
+    let version = i64::try_from(session.version).map_err(SessionError::persistence)?;
+
     Ok(NewAgentSession {
         id: session.session_id.into_inner(),
         tenant_id,
@@ -57,10 +60,12 @@ pub(super) fn session_to_new_row(
         started_at: session.started_at,
         ended_at: session.ended_at,
         state: session.state.as_str().to_owned(),
+        version,
     })
 }
 
-/// Converts a domain `AgentSession` to update values.
+/// Converts a domain `AgentSession` to update values carrying the next
+/// version.
 pub(super) fn session_to_update_values(
     session: &AgentSession,
 ) -> SessionResult<AgentSessionUpdate> {
@@ -70,6 +75,9 @@ pub(super) fn session_to_update_values(
 
     let end_sequence = session_end_sequence(session)?;
 
+    let version =
+        i64::try_from(session.version.saturating_add(1)).map_err(SessionError::persistence)?;
+
     Ok(AgentSessionUpdate {
         end_sequence,
         turn_ids,
@@ -77,34 +85,32 @@ pub(super) fn session_to_update_values(
         context_snapshots,
         ended_at: session.ended_at,
         state: session.state.as_str().to_owned(),
+        version,
     })
 }
 
 /// Converts a database row to a domain `AgentSession`.
-pub(super) fn row_to_session(row: AgentSessionRow) -> SessionResult<AgentSession> {
+pub(crate) fn row_to_session(row: AgentSessionRow) -> SessionResult<AgentSession> {
     let turn_ids: Vec<TurnId> =
         serde_json::from_value(row.turn_ids).map_err(SessionError::persistence)?;
 
     let context_snapshots: Vec<ContextWindowSnapshot> =
         serde_json::from_value(row.context_snapshots).map_err(SessionError::persistence)?;
 
-    let start_sequence = u64::try_from(row.start_sequence).map_err(SessionError::persistence)?;
+    let start_sequence = stored_sequence(row.start_sequence)?;
 
-    let end_sequence = row
-        .end_sequence
-        .map(u64::try_from)
-        .transpose()
-        .map_err(SessionError::persistence)?
-        .map(SequenceNumber::new);
+    let end_sequence = row.end_sequence.map(stored_sequence).transpose()?;
 
     let state =
         AgentSessionState::try_from(row.state.as_str()).map_err(SessionError::persistence)?;
 
+    let version = u64::try_from(row.version).map_err(SessionError::persistence)?;
+
     Ok(AgentSession {
         session_id: AgentSessionId::from_uuid(row.id),
         conversation_id: ConversationId::from_uuid(row.conversation_id),
         agent_backend: row.agent_backend,
-        start_sequence: SequenceNumber::new(start_sequence),
+        start_sequence,
         end_sequence,
         turn_ids,
         initiated_by_handoff: row.initiated_by_handoff.map(HandoffId::from_uuid),
@@ -113,6 +119,7 @@ pub(super) fn row_to_session(row: AgentSessionRow) -> SessionResult<AgentSession
         started_at: row.started_at,
         ended_at: row.ended_at,
         state,
+        version,
     })
 }
 
@@ -127,3 +134,9 @@ fn session_end_sequence(session: &AgentSession) -> SessionResult<Option<i64>> {
         .transpose()
         .map_err(SessionError::persistence)
 }
+
+/// Reads a stored sequence column, rejecting negative and zero values.
+fn stored_sequence(value: i64) -> SessionResult<SequenceNumber> {
+    let unsigned = u64::try_from(value).map_err(SessionError::persistence)?;
+    SequenceNumber::try_new(unsigned).map_err(SessionError::persistence)
+}
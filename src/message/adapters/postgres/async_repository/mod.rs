@@ -0,0 +1,208 @@
+//! `diesel-async` implementation of the message repository port.
+//!
+//! [`AsyncPostgresMessageRepository`] runs the same queries as
+//! [`super::PostgresMessageRepository`] over a deadpool of
+//! [`AsyncPgConnection`]s, so calls await the database instead of occupying
+//! a blocking-pool thread each. Available with the `async-postgres` feature.
+
+mod port;
+mod sql_helpers;
+mod tenant_tx;
+
+use std::sync::Arc;
+
+use diesel_async::AsyncPgConnection;
+use diesel_async::pooled_connection::deadpool::Pool;
+use diesel_async::scoped_futures::{ScopedBoxFuture, ScopedFutureExt};
+
+use super::super::audit_context::AuditContext;
+use super::super::models::NewMessage;
+use super::sealing::MessageCodec;
+use super::sql_helpers::InsertIds;
+use crate::context::{RequestContext, TenantId};
+use crate::health::{ComponentHealth, ComponentHealthCheck, PoolStatistics};
+use crate::message::{
+    domain::Message,
+    error::RepositoryError,
+    ports::{content_cipher::ContentCipher, repository::RepositoryResult},
+};
+use sql_helpers::{insert_message, set_audit_context};
+use tenant_tx::{ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx};
+
+/// Deadpool of `diesel-async` `PostgreSQL` connections.
+pub type AsyncPgPool = Pool<AsyncPgConnection>;
+
+/// `PostgreSQL` implementation of [`MessageRepository`] using `diesel-async`.
+///
+/// Implements the port as [`super::PostgresMessageRepository`] does:
+/// tenant scoping, archive protection, atomic sequence allocation, sealing
+/// with a configured cipher, and audit context for audited writes. It
+/// never blocks a runtime thread while waiting on the database.
+///
+/// # Example
+///
+/// ```ignore
+/// use corbusier::message::adapters::postgres::AsyncPostgresMessageRepository;
+/// use diesel_async::AsyncPgConnection;
+/// use diesel_async::pooled_connection::{AsyncDieselConnectionManager, deadpool::Pool};
+///
+/// let manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new("postgres://...");
+/// let pool = Pool::builder(manager).max_size(16).build().expect("pool");
+/// let repo = AsyncPostgresMessageRepository::new(pool);
+/// ```
+#[derive(Clone)]
+pub struct AsyncPostgresMessageRepository {
+    pool: AsyncPgPool,
+    codec: MessageCodec,
+}
+
+impl std::fmt::Debug for AsyncPostgresMessageRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncPostgresMessageRepository")
+            .field("pool", &self.pool.status())
+            .field("codec", &self.codec)
+            .finish()
+    }
+}
+
+impl AsyncPostgresMessageRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: AsyncPgPool) -> Self {
+        Self {
+            pool,
+            codec: MessageCodec::plain(),
+        }
+    }
+
+    /// Encrypts message content and metadata with `cipher`, as
+    /// [`super::PostgresMessageRepository::with_cipher`] does.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Arc<dyn ContentCipher>) -> Self {
+        self.codec = MessageCodec::sealed(cipher);
+        self
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub const fn pool(&self) -> &AsyncPgPool {
+        &self.pool
+    }
+
+    /// Stores a message with audit context for tracking, as
+    /// [`super::PostgresMessageRepository::store_with_audit`] does.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError` if the database operation fails.
+    pub async fn store_with_audit(
+        &self,
+        ctx: &RequestContext,
+        message: &Message,
+    ) -> RepositoryResult<()> {
+        let audit_ctx = AuditContext::from(ctx);
+        let tenant_id = ctx.tenant_id();
+        let new_message = self.new_row(message, tenant_id)?;
+        let ids = InsertIds {
+            msg_id: message.id(),
+            conv_id: message.conversation_id(),
+            seq_num: message.sequence_number(),
+        };
+
+        self.execute_query(tenant_id, move |conn| {
+            async move {
+                set_audit_context(conn, &audit_ctx).await?;
+                insert_message(conn, ctx, &new_message, &ids).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Converts a domain message to the row to store, sealed if a cipher is
+    /// configured.
+    fn new_row(&self, message: &Message, tenant_id: TenantId) -> RepositoryResult<NewMessage> {
+        self.codec.seal(NewMessage::try_from_domain(
+            message,
+            tenant_id.into_inner(),
+        )?)
+    }
+
+    /// Runs `query` inside a tenant-scoped transaction, bootstrapping the
+    /// tenant row first.
+    async fn execute_query<'a, F, T>(&self, tenant_id: TenantId, query: F) -> RepositoryResult<T>
+    where
+        F: for<'r> FnOnce(
+                &'r mut AsyncPgConnection,
+            ) -> ScopedBoxFuture<'a, 'r, RepositoryResult<T>>
+            + Send
+            + 'a,
+        T: Send + 'a,
+    {
+        let tenant_uuid = tenant_id.into_inner();
+        let mut conn = self.pool.get().await.map_err(RepositoryError::database)?;
+        with_tenant_tx(&mut conn, tenant_uuid, move |tx| {
+            async move {
+                ensure_tenant_exists(tx, tenant_uuid)
+                    .await
+                    .map_err(RepositoryError::database)?;
+                query(tx).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Runs `query` inside a read-only tenant-scoped transaction.
+    async fn execute_read_query<'a, F, T>(
+        &self,
+        tenant_id: TenantId,
+        query: F,
+    ) -> RepositoryResult<T>
+    where
+        F: for<'r> FnOnce(
+                &'r mut AsyncPgConnection,
+            ) -> ScopedBoxFuture<'a, 'r, RepositoryResult<T>>
+            + Send
+            + 'a,
+        T: Send + 'a,
+    {
+        let mut conn = self.pool.get().await.map_err(RepositoryError::database)?;
+        with_tenant_read_tx(&mut conn, tenant_id.into_inner(), query).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ComponentHealthCheck for AsyncPostgresMessageRepository {
+    fn component(&self) -> &str {
+        "async_message_repository"
+    }
+
+    async fn check_health(&self) -> ComponentHealth {
+        use diesel_async::RunQueryDsl;
+
+        let status = self.pool.status();
+        let statistics = PoolStatistics {
+            connections: saturating_u32(status.size),
+            idle_connections: saturating_u32(status.available),
+            max_size: saturating_u32(status.max_size),
+        };
+        let outcome = match self.pool.get().await {
+            Ok(mut conn) => diesel::sql_query("SELECT 1")
+                .execute(&mut conn)
+                .await
+                .map(drop)
+                .map_err(|err| err.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+        match outcome {
+            Ok(()) => ComponentHealth::healthy(self.component()),
+            Err(reason) => ComponentHealth::unhealthy(self.component(), reason),
+        }
+        .with_pool(statistics)
+    }
+}
+
+fn saturating_u32(count: usize) -> u32 {
+    u32::try_from(count).unwrap_or(u32::MAX)
+}
@@ -0,0 +1,340 @@
+//! [`MessageRepository`] for [`AsyncPostgresMessageRepository`].
+
+use async_trait::async_trait;
+use diesel::prelude::{ExpressionMethods, OptionalExtension, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use diesel_async::scoped_futures::ScopedFutureExt;
+
+use super::AsyncPostgresMessageRepository;
+use super::sql_helpers::{
+    append_message, insert_message, insert_message_batch, pin_message, redact_message,
+    set_audit_context,
+};
+use crate::context::RequestContext;
+use crate::message::adapters::audit_context::AuditContext;
+use crate::message::adapters::batch::BatchKey;
+use crate::message::adapters::models::MessageRow;
+use crate::message::adapters::postgres::blob_references::{
+    BlobRow, to_hashes, unreferenced_blobs_query,
+};
+use crate::message::adapters::postgres::conversion_helpers::ser_err;
+use crate::message::adapters::postgres::message_query::filtered_messages;
+use crate::message::adapters::postgres::sealing::RowScope;
+use crate::message::adapters::postgres::sql_helpers::InsertIds;
+use crate::message::adapters::postgres::token_usage::{row_to_token_usage, token_usage_query};
+use crate::message::adapters::schema::{conversations, messages};
+use crate::message::{
+    domain::{
+        ContentHash, ConversationId, ConversationTokenUsage, Message, MessageId, MessageQuery,
+        MessageRedaction, SequenceNumber,
+    },
+    error::RepositoryError,
+    ports::repository::{MessageRepository, RepositoryResult},
+};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
+
+#[async_trait]
+impl MessageRepository for AsyncPostgresMessageRepository {
+    async fn store(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<()> {
+        let tenant_id = ctx.tenant_id();
+        let new_message = self.new_row(message, tenant_id)?;
+        let ids = InsertIds {
+            msg_id: message.id(),
+            conv_id: message.conversation_id(),
+            seq_num: message.sequence_number(),
+        };
+
+        let ctx = ctx.clone();
+        self.execute_query(tenant_id, move |conn| {
+            async move { insert_message(conn, &ctx, &new_message, &ids).await }.scope_boxed()
+        })
+        .await
+    }
+
+    async fn append(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<Message> {
+        let tenant_id = ctx.tenant_id();
+        let new_message = self.new_row(message, tenant_id)?;
+        let msg_id = message.id();
+
+        let ctx = ctx.clone();
+        let sequence = self
+            .execute_query(tenant_id, move |conn| {
+                async move { append_message(conn, &ctx, new_message, msg_id).await }.scope_boxed()
+            })
+            .await?;
+        Ok(message.clone().with_sequence_number(sequence))
+    }
+
+    async fn store_batch(
+        &self,
+        ctx: &RequestContext,
+        messages: &[Message],
+    ) -> RepositoryResult<()> {
+        if messages.is_empty() {
+            return Ok(());
+        }
+        let tenant_id = ctx.tenant_id();
+        let rows = messages
+            .iter()
+            .map(|message| self.new_row(message, tenant_id))
+            .collect::<RepositoryResult<Vec<_>>>()?;
+        let keys: Vec<BatchKey> = messages.iter().map(BatchKey::from).collect();
+
+        let ctx = ctx.clone();
+        self.execute_query(tenant_id, move |conn| {
+            async move { insert_message_batch(conn, &ctx, &keys, &rows).await }.scope_boxed()
+        })
+        .await
+    }
+
+    async fn redact(
+        &self,
+        ctx: &RequestContext,
+        redaction: &MessageRedaction,
+    ) -> RepositoryResult<Message> {
+        let audit_ctx = AuditContext::from(ctx);
+        let tenant_id = ctx.tenant_id();
+        let correlation_id = ctx.correlation_id().into_inner();
+        let codec = &self.codec;
+
+        self.execute_query(tenant_id, move |conn| {
+            async move {
+                set_audit_context(conn, &audit_ctx).await?;
+                let scope = RowScope {
+                    tenant_id: tenant_id.into_inner(),
+                    codec,
+                };
+                redact_message(conn, scope, correlation_id, redaction).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn set_pinned(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+        pinned: bool,
+    ) -> RepositoryResult<Message> {
+        let audit_ctx = AuditContext::from(ctx);
+        let tenant_id = ctx.tenant_id();
+        let codec = &self.codec;
+
+        self.execute_query(tenant_id, move |conn| {
+            async move {
+                set_audit_context(conn, &audit_ctx).await?;
+                let scope = RowScope {
+                    tenant_id: tenant_id.into_inner(),
+                    codec,
+                };
+                pin_message(conn, scope, id, pinned).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+    ) -> RepositoryResult<Option<Message>> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = id.into_inner();
+
+        let codec = &self.codec;
+        self.execute_read_query(tenant_id, move |conn| {
+            async move {
+                messages::table
+                    .filter(messages::id.eq(uuid))
+                    .filter(messages::tenant_id.eq(tenant_id.into_inner()))
+                    .select(MessageRow::as_select())
+                    .first::<MessageRow>(conn)
+                    .await
+                    .optional()
+                    .map_err(RepositoryError::database)?
+                    .map(|row| codec.to_message(row))
+                    .transpose()
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> RepositoryResult<Page<Message>> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = conversation_id.into_inner();
+
+        let codec = &self.codec;
+        self.execute_read_query(tenant_id, move |conn| {
+            async move {
+                let query = messages::table
+                    .filter(messages::tenant_id.eq(tenant_id.into_inner()))
+                    .filter(messages::conversation_id.eq(uuid))
+                    .select(MessageRow::as_select())
+                    .into_boxed();
+                let rows = keyset_page!(
+                    query,
+                    page,
+                    after_number,
+                    (messages::sequence_number, messages::id)
+                )
+                .load::<MessageRow>(conn)
+                .await
+                .map_err(RepositoryError::database)?;
+
+                Page::from_overfetched(rows, page, |row| {
+                    Cursor::at_number(row.sequence_number, row.id)
+                })
+                .try_map(|row| codec.to_message(row))
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn query(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        query: &MessageQuery,
+    ) -> RepositoryResult<Page<Message>> {
+        let tenant_id = ctx.tenant_id();
+        let filtered =
+            filtered_messages(tenant_id.into_inner(), conversation_id.into_inner(), query);
+        let page = query.page();
+
+        let codec = &self.codec;
+        self.execute_read_query(tenant_id, move |conn| {
+            async move {
+                let rows = keyset_page!(
+                    filtered,
+                    page,
+                    after_number,
+                    (messages::sequence_number, messages::id)
+                )
+                .select(MessageRow::as_select())
+                .load::<MessageRow>(conn)
+                .await
+                .map_err(RepositoryError::database)?;
+
+                Page::from_overfetched(rows, page, |row| {
+                    Cursor::at_number(row.sequence_number, row.id)
+                })
+                .try_map(|row| codec.to_message(row))
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn next_sequence_number(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<SequenceNumber> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = conversation_id.into_inner();
+
+        self.execute_read_query(tenant_id, move |conn| {
+            async move {
+                let conversation_exists = conversations::table
+                    .filter(conversations::id.eq(uuid))
+                    .filter(conversations::tenant_id.eq(tenant_id.into_inner()))
+                    .select(conversations::id)
+                    .first::<uuid::Uuid>(conn)
+                    .await
+                    .optional()
+                    .map_err(RepositoryError::database)?;
+                if conversation_exists.is_none() {
+                    return Err(RepositoryError::ConversationNotFound(conversation_id));
+                }
+
+                let max_seq: Option<i64> = messages::table
+                    .filter(messages::tenant_id.eq(tenant_id.into_inner()))
+                    .filter(messages::conversation_id.eq(uuid))
+                    .select(diesel::dsl::max(messages::sequence_number))
+                    .first(conn)
+                    .await
+                    .map_err(RepositoryError::database)?;
+                let next = max_seq.unwrap_or(0).checked_add(1).ok_or_else(|| {
+                    RepositoryError::serialization("sequence number overflow: maximum i64 reached")
+                })?;
+                Ok(SequenceNumber::try_new(
+                    u64::try_from(next).map_err(ser_err)?,
+                )?)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn token_usage(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<ConversationTokenUsage> {
+        let tenant_id = ctx.tenant_id();
+        let query = token_usage_query(tenant_id.into_inner(), conversation_id.into_inner());
+
+        self.execute_read_query(tenant_id, move |conn| {
+            async move {
+                let row = query
+                    .first::<(i64, i64, i64)>(conn)
+                    .await
+                    .map_err(RepositoryError::database)?;
+                row_to_token_usage(row)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn exists(&self, ctx: &RequestContext, id: MessageId) -> RepositoryResult<bool> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = id.into_inner();
+
+        self.execute_read_query(tenant_id, move |conn| {
+            async move {
+                let count: i64 = messages::table
+                    .filter(messages::id.eq(uuid))
+                    .filter(messages::tenant_id.eq(tenant_id.into_inner()))
+                    .count()
+                    .get_result(conn)
+                    .await
+                    .map_err(RepositoryError::database)?;
+                Ok(count > 0)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn unreferenced_blobs(
+        &self,
+        ctx: &RequestContext,
+        hashes: &[ContentHash],
+    ) -> RepositoryResult<Vec<ContentHash>> {
+        let tenant_id = ctx.tenant_id();
+        let query = unreferenced_blobs_query(tenant_id.into_inner(), hashes);
+
+        self.execute_read_query(tenant_id, move |conn| {
+            async move {
+                let rows = query
+                    .load::<BlobRow>(conn)
+                    .await
+                    .map_err(RepositoryError::database)?;
+                to_hashes(rows).map_err(ser_err)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
@@ -0,0 +1,285 @@
+//! Insert and redaction helpers for the `diesel-async` message repository.
+//!
+//! Async counterparts of [`super::super::sql_helpers`] and
+//! [`super::super::redaction`], sharing their constraint error mapping,
+//! batch conflict detection, and redaction rows so both adapters report the
+//! same errors and write the same rows.
+
+use std::collections::HashSet;
+
+use diesel::prelude::{ExpressionMethods, OptionalExtension, QueryDsl, SelectableHelper};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use uuid::Uuid;
+
+use super::super::super::audit_context::AuditContext;
+use super::super::super::batch::{BatchKey, find_batch_conflicts};
+use super::super::super::models::{MessageRow, NewMessage};
+use super::super::super::schema::{conversations, message_redactions, messages};
+use super::super::conversion_helpers::ser_err;
+use super::super::pinning::pinned_metadata;
+use super::super::redaction::prepare_redaction;
+use super::super::sealing::RowScope;
+use super::super::sql_helpers::{InsertIds, MAX_ROWS_PER_INSERT, map_insert_error};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        ConversationId, ConversationState, Message, MessageId, MessageRedaction, SequenceNumber,
+    },
+    error::RepositoryError,
+    ports::repository::RepositoryResult,
+};
+
+/// Returns the tenant's conversation state, locking the row until commit.
+///
+/// Appends take a `FOR UPDATE` lock, which serialises sequence allocation
+/// between concurrent appends; other writers take a `FOR SHARE` lock so a
+/// concurrent archive waits for them. Returns `None` when the tenant has no
+/// such conversation.
+async fn lock_conversation(
+    conn: &mut AsyncPgConnection,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+    for_append: bool,
+) -> diesel::QueryResult<Option<String>> {
+    let query = conversations::table
+        .filter(conversations::id.eq(conversation_id.into_inner()))
+        .filter(conversations::tenant_id.eq(ctx.tenant_id().into_inner()))
+        .select(conversations::state);
+    if for_append {
+        query.for_update().first::<String>(conn).await.optional()
+    } else {
+        query.for_share().first::<String>(conn).await.optional()
+    }
+}
+
+async fn conversation_is_archived(
+    conn: &mut AsyncPgConnection,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+) -> diesel::QueryResult<bool> {
+    let state = lock_conversation(conn, ctx, conversation_id, false).await?;
+    Ok(state.as_deref() == Some(ConversationState::Archived.as_str()))
+}
+
+/// Inserts a message, rejecting it when the conversation is archived.
+pub(super) async fn insert_message(
+    conn: &mut AsyncPgConnection,
+    ctx: &RequestContext,
+    new_message: &NewMessage,
+    ids: &InsertIds,
+) -> RepositoryResult<()> {
+    if conversation_is_archived(conn, ctx, ids.conv_id).await? {
+        return Err(RepositoryError::ConversationArchived(ids.conv_id));
+    }
+    diesel::insert_into(messages::table)
+        .values(new_message)
+        .execute(conn)
+        .await
+        .map_err(|e| map_insert_error(e, ids))?;
+    Ok(())
+}
+
+/// Appends a message at the next free sequence number of its conversation
+/// and returns the allocated number.
+pub(super) async fn append_message(
+    conn: &mut AsyncPgConnection,
+    ctx: &RequestContext,
+    mut new_message: NewMessage,
+    msg_id: MessageId,
+) -> RepositoryResult<SequenceNumber> {
+    let conv_id = ConversationId::from_uuid(new_message.conversation_id);
+    match lock_conversation(conn, ctx, conv_id, true)
+        .await?
+        .as_deref()
+    {
+        None => return Err(RepositoryError::ConversationNotFound(conv_id)),
+        Some(state) if state == ConversationState::Archived.as_str() => {
+            return Err(RepositoryError::ConversationArchived(conv_id));
+        }
+        Some(_) => {}
+    }
+    let last: Option<i64> = messages::table
+        .filter(messages::tenant_id.eq(ctx.tenant_id().into_inner()))
+        .filter(messages::conversation_id.eq(new_message.conversation_id))
+        .select(diesel::dsl::max(messages::sequence_number))
+        .first(conn)
+        .await?;
+    let next = last.unwrap_or(0).checked_add(1).ok_or_else(|| {
+        RepositoryError::serialization("sequence number overflow: maximum i64 reached")
+    })?;
+    new_message.sequence_number = next;
+    let ids = InsertIds {
+        msg_id,
+        conv_id,
+        seq_num: SequenceNumber::try_new(u64::try_from(next).map_err(ser_err)?)?,
+    };
+    diesel::insert_into(messages::table)
+        .values(&new_message)
+        .execute(conn)
+        .await
+        .map_err(|e| map_insert_error(e, &ids))?;
+    Ok(ids.seq_num)
+}
+
+/// Inserts a batch of messages, rejecting the whole batch when any
+/// conversation is archived or any ID or sequence number clashes.
+pub(super) async fn insert_message_batch(
+    conn: &mut AsyncPgConnection,
+    ctx: &RequestContext,
+    keys: &[BatchKey],
+    rows: &[NewMessage],
+) -> RepositoryResult<()> {
+    let conversation_ids: HashSet<ConversationId> =
+        keys.iter().map(|key| key.conversation_id).collect();
+    for conversation_id in &conversation_ids {
+        if conversation_is_archived(conn, ctx, *conversation_id).await? {
+            return Err(RepositoryError::ConversationArchived(*conversation_id));
+        }
+    }
+
+    let stored_ids = load_stored_ids(conn, rows).await?;
+    let stored_sequences = load_stored_sequences(conn, rows).await?;
+    let conflicts = find_batch_conflicts(
+        keys,
+        |id| stored_ids.contains(&id.into_inner()),
+        |conversation_id, sequence| {
+            stored_sequences.contains(&(conversation_id.into_inner(), sequence.value()))
+        },
+    );
+    if !conflicts.is_empty() {
+        return Err(RepositoryError::BatchConflicts(conflicts));
+    }
+
+    for chunk in rows.chunks(MAX_ROWS_PER_INSERT) {
+        diesel::insert_into(messages::table)
+            .values(chunk)
+            .execute(conn)
+            .await
+            .map_err(RepositoryError::database)?;
+    }
+    Ok(())
+}
+
+async fn load_stored_ids(
+    conn: &mut AsyncPgConnection,
+    rows: &[NewMessage],
+) -> RepositoryResult<HashSet<Uuid>> {
+    let ids: Vec<Uuid> = rows.iter().map(|row| row.id).collect();
+    let stored = messages::table
+        .filter(messages::id.eq_any(ids))
+        .select(messages::id)
+        .load::<Uuid>(conn)
+        .await
+        .map_err(RepositoryError::database)?;
+    Ok(stored.into_iter().collect())
+}
+
+async fn load_stored_sequences(
+    conn: &mut AsyncPgConnection,
+    rows: &[NewMessage],
+) -> RepositoryResult<HashSet<(Uuid, u64)>> {
+    let conversation_ids: Vec<Uuid> = rows.iter().map(|row| row.conversation_id).collect();
+    let sequences: Vec<i64> = rows.iter().map(|row| row.sequence_number).collect();
+    let stored = messages::table
+        .filter(messages::conversation_id.eq_any(conversation_ids))
+        .filter(messages::sequence_number.eq_any(sequences))
+        .select((messages::conversation_id, messages::sequence_number))
+        .load::<(Uuid, i64)>(conn)
+        .await
+        .map_err(RepositoryError::database)?;
+    stored
+        .into_iter()
+        .map(|(conversation_id, sequence)| {
+            u64::try_from(sequence)
+                .map(|value| (conversation_id, value))
+                .map_err(ser_err)
+        })
+        .collect()
+}
+
+/// Redacts a message inside the caller's transaction, locking the message
+/// row first so concurrent redactions of one message cannot both succeed.
+pub(super) async fn redact_message(
+    conn: &mut AsyncPgConnection,
+    scope: RowScope<'_>,
+    correlation_id: Uuid,
+    redaction: &MessageRedaction,
+) -> RepositoryResult<Message> {
+    let message_id = redaction.message_id();
+    let row = messages::table
+        .filter(messages::id.eq(message_id.into_inner()))
+        .filter(messages::tenant_id.eq(scope.tenant_id))
+        .select(MessageRow::as_select())
+        .for_update()
+        .first::<MessageRow>(conn)
+        .await
+        .optional()
+        .map_err(RepositoryError::database)?
+        .ok_or(RepositoryError::NotFound(message_id))?;
+    let write = prepare_redaction(scope.codec, row, redaction, correlation_id)?;
+
+    diesel::update(
+        messages::table
+            .filter(messages::id.eq(message_id.into_inner()))
+            .filter(messages::tenant_id.eq(scope.tenant_id)),
+    )
+    .set((
+        messages::content.eq(write.content),
+        messages::metadata.eq(write.metadata),
+    ))
+    .execute(conn)
+    .await
+    .map_err(RepositoryError::database)?;
+    diesel::insert_into(message_redactions::table)
+        .values(&write.tombstone)
+        .execute(conn)
+        .await
+        .map_err(RepositoryError::database)?;
+    Ok(write.message)
+}
+
+/// Pins or unpins a message inside the caller's transaction.
+pub(super) async fn pin_message(
+    conn: &mut AsyncPgConnection,
+    scope: RowScope<'_>,
+    id: MessageId,
+    pinned: bool,
+) -> RepositoryResult<Message> {
+    diesel::update(
+        messages::table
+            .filter(messages::id.eq(id.into_inner()))
+            .filter(messages::tenant_id.eq(scope.tenant_id)),
+    )
+    .set(messages::metadata.eq(pinned_metadata(pinned)))
+    .returning(MessageRow::as_returning())
+    .get_result::<MessageRow>(conn)
+    .await
+    .optional()
+    .map_err(RepositoryError::database)?
+    .ok_or(RepositoryError::NotFound(id))
+    .and_then(|row| scope.codec.to_message(row))
+}
+
+/// Sets the audit context session variables for the current transaction,
+/// as the blocking [`super::super::sql_helpers::set_audit_context`] does.
+pub(super) async fn set_audit_context(
+    conn: &mut AsyncPgConnection,
+    audit: &AuditContext,
+) -> RepositoryResult<()> {
+    let fields = [
+        ("correlation_id", audit.correlation_id),
+        ("causation_id", audit.causation_id),
+        ("user_id", audit.user_id),
+        ("session_id", audit.session_id),
+    ];
+    for (key, value) in fields {
+        let Some(value) = value else { continue };
+        // SET does not take bind parameters; a hyphenated UUID holds only
+        // hex digits and hyphens, so interpolating it cannot inject SQL.
+        diesel::sql_query(format!("SET LOCAL app.{key} = '{value}'"))
+            .execute(conn)
+            .await
+            .map_err(RepositoryError::database)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! Tenant-scoped transactions on `diesel-async` connections.
+//!
+//! Mirrors the blocking helpers in [`super::super::tenant_tx`]: each
+//! transaction sets the `app.tenant_id` session variable before running the
+//! caller's body, so Row-Level Security policies apply, and read
+//! transactions are additionally marked `READ ONLY`.
+
+use diesel_async::scoped_futures::{ScopedBoxFuture, ScopedFutureExt};
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use uuid::Uuid;
+
+use super::super::tenant_tx::{BOOTSTRAP_TENANT_SQL, FromTxError, TxError};
+
+/// Runs `body` inside a transaction that first sets `app.tenant_id`.
+///
+/// Like its blocking counterpart, this does not bootstrap the tenant row;
+/// writers call [`ensure_tenant_exists`] inside `body` first.
+pub(super) async fn with_tenant_tx<'a, T, E, F>(
+    conn: &mut AsyncPgConnection,
+    tenant_id: Uuid,
+    body: F,
+) -> Result<T, E>
+where
+    T: Send + 'a,
+    E: FromTxError<E> + Send + 'a,
+    F: for<'r> FnOnce(&'r mut AsyncPgConnection) -> ScopedBoxFuture<'a, 'r, Result<T, E>>
+        + Send
+        + 'a,
+{
+    conn.transaction::<T, TxError<E>, _>(|tx| {
+        async move {
+            set_tenant_context(tx, tenant_id).await?;
+            body(tx).await.map_err(TxError::Domain)
+        }
+        .scope_boxed()
+    })
+    .await
+    .map_err(E::from_tx_error)
+}
+
+/// Runs `body` inside a read-only transaction that sets `app.tenant_id`.
+pub(super) async fn with_tenant_read_tx<'a, T, E, F>(
+    conn: &mut AsyncPgConnection,
+    tenant_id: Uuid,
+    body: F,
+) -> Result<T, E>
+where
+    T: Send + 'a,
+    E: FromTxError<E> + Send + 'a,
+    F: for<'r> FnOnce(&'r mut AsyncPgConnection) -> ScopedBoxFuture<'a, 'r, Result<T, E>>
+        + Send
+        + 'a,
+{
+    conn.transaction::<T, TxError<E>, _>(|tx| {
+        async move {
+            diesel::sql_query("SET TRANSACTION READ ONLY")
+                .execute(tx)
+                .await?;
+            set_tenant_context(tx, tenant_id).await?;
+            body(tx).await.map_err(TxError::Domain)
+        }
+        .scope_boxed()
+    })
+    .await
+    .map_err(E::from_tx_error)
+}
+
+/// Inserts a placeholder tenant row if one does not already exist.
+pub(super) async fn ensure_tenant_exists(
+    conn: &mut AsyncPgConnection,
+    tenant_id: Uuid,
+) -> diesel::QueryResult<()> {
+    diesel::sql_query(BOOTSTRAP_TENANT_SQL)
+        .bind::<diesel::sql_types::Uuid, _>(tenant_id)
+        .bind::<diesel::sql_types::Text, _>(format!("tenant-{tenant_id}"))
+        .bind::<diesel::sql_types::Text, _>(format!("Tenant {tenant_id}"))
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+/// Sets `app.tenant_id` for the current transaction.
+///
+/// # Security
+///
+/// The hyphenated UUID format contains only hexadecimal digits and hyphens,
+/// so interpolating it cannot inject SQL.
+async fn set_tenant_context<E>(
+    conn: &mut AsyncPgConnection,
+    tenant_id: Uuid,
+) -> Result<(), TxError<E>> {
+    diesel::sql_query(format!("SET LOCAL app.tenant_id = '{tenant_id}'"))
+        .execute(conn)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,118 @@
+//! Attachment blob references held by stored messages.
+//!
+//! Identical attachment data within a tenant shares one blob, so a blob may
+//! only be deleted once none of the tenant's messages refers to it any more.
+//! Each externalised part names its blob in `blob`; sealed content keeps the
+//! name beside the envelope in `$parts`. The statements here read either
+//! form, through the expression the `idx_messages_attachment_blobs` index
+//! covers.
+
+use diesel::pg::{Pg, PgConnection};
+use diesel::prelude::*;
+use diesel::query_builder::{BoxedSqlQuery, SqlQuery};
+use diesel::sql_types::{Array, Text, Uuid as SqlUuid};
+use uuid::Uuid;
+
+use crate::context::RequestContext;
+use crate::message::domain::{ContentHash, ContentHashError};
+use crate::message::ports::blob_store::{BlobStore, BlobStoreResult};
+
+/// The blob names of a message's content, as a JSON array.
+///
+/// Must match the expression of the `idx_messages_attachment_blobs` index.
+const BLOBS_SQL: &str = "(jsonb_path_query_array(m.content, '$[*].blob') \
+     || jsonb_path_query_array(m.content, '$.\"$parts\"[*].blob'))";
+
+/// A blob named by a message.
+#[derive(Debug, QueryableByName)]
+pub(crate) struct BlobRow {
+    #[diesel(sql_type = Text)]
+    hash: String,
+}
+
+/// Selects the blobs the tenant's messages `message_ids` refer to.
+pub(crate) fn message_blobs_query(
+    tenant_id: Uuid,
+    message_ids: &[Uuid],
+) -> BoxedSqlQuery<'static, Pg, SqlQuery> {
+    diesel::sql_query(format!(
+        "SELECT DISTINCT blob.hash FROM messages m, \
+         jsonb_array_elements_text({BLOBS_SQL}) AS blob(hash) \
+         WHERE m.tenant_id = $1 AND m.id = ANY($2)"
+    ))
+    .into_boxed()
+    .bind::<SqlUuid, _>(tenant_id)
+    .bind::<Array<SqlUuid>, _>(message_ids.to_vec())
+}
+
+/// Selects the blobs the messages of the tenant's conversation refer to.
+pub(crate) fn conversation_blobs_query(
+    tenant_id: Uuid,
+    conversation_id: Uuid,
+) -> BoxedSqlQuery<'static, Pg, SqlQuery> {
+    diesel::sql_query(format!(
+        "SELECT DISTINCT blob.hash FROM messages m, \
+         jsonb_array_elements_text({BLOBS_SQL}) AS blob(hash) \
+         WHERE m.tenant_id = $1 AND m.conversation_id = $2"
+    ))
+    .into_boxed()
+    .bind::<SqlUuid, _>(tenant_id)
+    .bind::<SqlUuid, _>(conversation_id)
+}
+
+/// Selects those of `hashes` that none of the tenant's messages refers to.
+pub(crate) fn unreferenced_blobs_query(
+    tenant_id: Uuid,
+    hashes: &[ContentHash],
+) -> BoxedSqlQuery<'static, Pg, SqlQuery> {
+    let hashes: Vec<String> = hashes.iter().map(|hash| hash.as_str().to_owned()).collect();
+    diesel::sql_query(format!(
+        "SELECT DISTINCT h.hash FROM unnest($2::TEXT[]) AS h(hash) \
+         WHERE NOT EXISTS (SELECT 1 FROM messages m \
+             WHERE m.tenant_id = $1 AND {BLOBS_SQL} @> to_jsonb(h.hash))"
+    ))
+    .into_boxed()
+    .bind::<SqlUuid, _>(tenant_id)
+    .bind::<Array<Text>, _>(hashes)
+}
+
+/// Converts selected rows into content hashes.
+///
+/// # Errors
+///
+/// Returns [`ContentHashError`] when a stored blob name is malformed.
+pub(crate) fn to_hashes(rows: Vec<BlobRow>) -> Result<Vec<ContentHash>, ContentHashError> {
+    rows.into_iter()
+        .map(|row| ContentHash::try_from(row.hash))
+        .collect()
+}
+
+/// Runs a blob query on a blocking connection.
+///
+/// # Errors
+///
+/// Returns the query error, or a deserialisation error when a stored blob
+/// name is malformed.
+pub(crate) fn load_blobs(
+    conn: &mut PgConnection,
+    query: BoxedSqlQuery<'static, Pg, SqlQuery>,
+) -> QueryResult<Vec<ContentHash>> {
+    let rows = query.load::<BlobRow>(conn)?;
+    to_hashes(rows).map_err(|err| diesel::result::Error::DeserializationError(Box::new(err)))
+}
+
+/// Deletes `hashes` from the tenant's blobs, stopping at the first failure.
+///
+/// # Errors
+///
+/// Returns the first [`BlobStore::delete`] error.
+pub(crate) async fn delete_blobs(
+    blobs: &dyn BlobStore,
+    ctx: &RequestContext,
+    hashes: &[ContentHash],
+) -> BlobStoreResult<()> {
+    for hash in hashes {
+        blobs.delete(ctx, hash).await?;
+    }
+    Ok(())
+}
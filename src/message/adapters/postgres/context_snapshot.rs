@@ -13,10 +13,15 @@ use crate::message::{
     },
     ports::context_snapshot::{ContextSnapshotPort, SnapshotError, SnapshotResult},
 };
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use diesel::pg::Pg;
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
+use diesel::sql_types::{Text, Timestamptz, Uuid as SqlUuid};
+use uuid::Uuid;
 
 use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
 use super::tenant_tx::{FromTxError, TxError, with_tenant_read_tx, with_tenant_tx};
@@ -39,6 +44,8 @@ pub struct PostgresContextSnapshotAdapter {
     pool: PgPool,
 }
 
+crate::postgres_support::pool_health_check!(PostgresContextSnapshotAdapter, "context_snapshot");
+
 impl PostgresContextSnapshotAdapter {
     /// Creates a new adapter with the given connection pool.
     #[must_use]
@@ -68,6 +75,25 @@ impl PostgresContextSnapshotAdapter {
         .await
     }
 
+    /// Executes a write in a tenant transaction with standard error
+    /// handling.
+    async fn execute_write<F, T>(&self, tenant_uuid: Uuid, write_fn: F) -> SnapshotResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> SnapshotResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, SnapshotError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, write_fn)
+            },
+            SnapshotError::persistence,
+        )
+        .await
+    }
+
     async fn find_one<F>(
         &self,
         tenant_id: TenantId,
@@ -97,11 +123,12 @@ impl PostgresContextSnapshotAdapter {
         .await
     }
 
-    async fn find_many<F>(
+    async fn find_page<F>(
         &self,
         tenant_id: TenantId,
+        page: PageRequest,
         build_query: F,
-    ) -> SnapshotResult<Vec<ContextWindowSnapshot>>
+    ) -> SnapshotResult<Page<ContextWindowSnapshot>>
     where
         F: FnOnce(
                 context_snapshots::BoxedQuery<'static, Pg>,
@@ -114,12 +141,20 @@ impl PostgresContextSnapshotAdapter {
             let base = context_snapshots::table
                 .filter(context_snapshots::tenant_id.eq(tenant_uuid))
                 .into_boxed();
-            let rows = build_query(base)
-                .select(ContextSnapshotRow::as_select())
-                .load::<ContextSnapshotRow>(conn)
-                .map_err(SnapshotError::persistence)?;
-
-            rows.into_iter().map(row_to_snapshot).collect()
+            let rows = keyset_page!(
+                build_query(base),
+                page,
+                after_timestamp,
+                (context_snapshots::captured_at, context_snapshots::id)
+            )
+            .select(ContextSnapshotRow::as_select())
+            .load::<ContextSnapshotRow>(conn)
+            .map_err(SnapshotError::persistence)?;
+
+            Page::from_overfetched(rows, page, |row| {
+                Cursor::at_timestamp(row.captured_at, row.id)
+            })
+            .try_map(row_to_snapshot)
         })
         .await
     }
@@ -177,13 +212,13 @@ impl ContextSnapshotPort for PostgresContextSnapshotAdapter {
         &self,
         ctx: &RequestContext,
         session_id: AgentSessionId,
-    ) -> SnapshotResult<Vec<ContextWindowSnapshot>> {
+        page: PageRequest,
+    ) -> SnapshotResult<Page<ContextWindowSnapshot>> {
         let tenant_id = ctx.tenant_id();
         let uuid = session_id.into_inner();
 
-        self.find_many(tenant_id, move |q| {
+        self.find_page(tenant_id, page, move |q| {
             q.filter(context_snapshots::session_id.eq(uuid))
-                .order(context_snapshots::captured_at.asc())
         })
         .await
     }
@@ -202,8 +237,77 @@ impl ContextSnapshotPort for PostgresContextSnapshotAdapter {
         })
         .await
     }
+
+    async fn find_latest_compaction(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> SnapshotResult<Option<ContextWindowSnapshot>> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = conversation_id.into_inner();
+
+        self.find_one(tenant_id, move |q| {
+            q.filter(context_snapshots::conversation_id.eq(uuid))
+                .filter(context_snapshots::snapshot_type.eq(SnapshotType::Compaction.as_str()))
+                .order(context_snapshots::captured_at.desc())
+        })
+        .await
+    }
+
+    async fn delete_snapshot(&self, ctx: &RequestContext, snapshot_id: Uuid) -> SnapshotResult<()> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.execute_write(tenant_uuid, move |tx| {
+            let deleted = diesel::delete(
+                context_snapshots::table
+                    .filter(context_snapshots::tenant_id.eq(tenant_uuid))
+                    .filter(context_snapshots::id.eq(snapshot_id)),
+            )
+            .execute(tx)
+            .map_err(SnapshotError::persistence)?;
+            if deleted == 0 {
+                return Err(SnapshotError::NotFound(snapshot_id));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn prune_before(
+        &self,
+        ctx: &RequestContext,
+        snapshot_type: SnapshotType,
+        cutoff: DateTime<Utc>,
+    ) -> SnapshotResult<usize> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.execute_write(tenant_uuid, move |tx| {
+            diesel::sql_query(PRUNE_BEFORE_SQL)
+                .bind::<SqlUuid, _>(tenant_uuid)
+                .bind::<Text, _>(snapshot_type.as_str())
+                .bind::<Timestamptz, _>(cutoff)
+                .execute(tx)
+                .map_err(SnapshotError::persistence)
+        })
+        .await
+    }
 }
 
+/// Deletes the tenant's expired snapshots of one type, except the ancestors
+/// of the snapshots that remain.
+const PRUNE_BEFORE_SQL: &str = concat!(
+    "WITH RECURSIVE protected(id) AS (",
+    "SELECT parent_snapshot_id FROM context_snapshots ",
+    "WHERE tenant_id = $1 AND parent_snapshot_id IS NOT NULL ",
+    "AND NOT (snapshot_type = $2 AND captured_at < $3) ",
+    "UNION ",
+    "SELECT s.parent_snapshot_id FROM context_snapshots s ",
+    "JOIN protected p ON s.id = p.id ",
+    "WHERE s.parent_snapshot_id IS NOT NULL",
+    ") ",
+    "DELETE FROM context_snapshots ",
+    "WHERE tenant_id = $1 AND snapshot_type = $2 AND captured_at < $3 ",
+    "AND id NOT IN (SELECT id FROM protected)",
+);
+
 /// Converts a domain `ContextWindowSnapshot` to a `NewContextSnapshot` for insertion.
 fn snapshot_to_new_row(
     snapshot: &ContextWindowSnapshot,
@@ -239,20 +343,22 @@ fn snapshot_to_new_row(
         token_estimate,
         captured_at: snapshot.captured_at,
         snapshot_type: snapshot.snapshot_type.as_str().to_owned(),
+        summary_text: snapshot.summary.clone(),
+        parent_snapshot_id: snapshot.parent_snapshot_id,
     })
 }
 
 /// Converts a database row to a domain `ContextWindowSnapshot`.
-fn row_to_snapshot(row: ContextSnapshotRow) -> SnapshotResult<ContextWindowSnapshot> {
+pub(super) fn row_to_snapshot(row: ContextSnapshotRow) -> SnapshotResult<ContextWindowSnapshot> {
     let message_summary: MessageSummary =
         serde_json::from_value(row.message_summary).map_err(SnapshotError::persistence)?;
 
     let visible_tool_calls: Vec<ToolCallReference> =
         serde_json::from_value(row.visible_tool_calls).map_err(SnapshotError::persistence)?;
 
-    let start = u64::try_from(row.sequence_start).map_err(SnapshotError::persistence)?;
+    let start = stored_sequence(row.sequence_start)?;
 
-    let end = u64::try_from(row.sequence_end).map_err(SnapshotError::persistence)?;
+    let end = stored_sequence(row.sequence_end)?;
 
     let token_estimate = row
         .token_estimate
@@ -267,11 +373,19 @@ fn row_to_snapshot(row: ContextSnapshotRow) -> SnapshotResult<ContextWindowSnaps
         snapshot_id: row.id,
         conversation_id: ConversationId::from_uuid(row.conversation_id),
         session_id: AgentSessionId::from_uuid(row.session_id),
-        sequence_range: SequenceRange::new(SequenceNumber::new(start), SequenceNumber::new(end)),
+        sequence_range: SequenceRange::new(start, end),
         message_summary,
         visible_tool_calls,
         token_estimate,
         captured_at: row.captured_at,
         snapshot_type,
+        summary: row.summary_text,
+        parent_snapshot_id: row.parent_snapshot_id,
     })
 }
+
+/// Reads a stored sequence bound, rejecting negative and zero values.
+fn stored_sequence(value: i64) -> SnapshotResult<SequenceNumber> {
+    let unsigned = u64::try_from(value).map_err(SnapshotError::persistence)?;
+    SequenceNumber::try_new(unsigned).map_err(SnapshotError::persistence)
+}
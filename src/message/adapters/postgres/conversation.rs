@@ -1,24 +1,39 @@
 //! `PostgreSQL` implementation of the conversation repository.
+//!
+//! Labels are stored in the GIN-indexed `labels` JSONB column, so
+//! [`ConversationRepository::find_by_label`] is a containment query. Label
+//! changes append to `conversation_label_events` in the transaction that
+//! updates the conversation.
 
-use crate::context::{RequestContext, TenantId};
+use crate::context::{CorrelationId, RequestContext, TenantId, UserId};
 use crate::message::adapters::{
-    models::{ConversationRow, NewConversation},
-    schema::conversations,
+    audit_context::AuditContext,
+    models::{ConversationLabelEventRow, ConversationRow, NewConversation},
+    schema::{conversation_label_events, conversations},
 };
 use crate::message::{
-    domain::{Conversation, ConversationId, ConversationState},
+    domain::{
+        Conversation, ConversationId, ConversationLabel, ConversationLabelChange,
+        ConversationLabelEvent, ConversationState,
+    },
     ports::conversation::{
         ConversationRepository, ConversationRepositoryError, ConversationRepositoryResult,
     },
 };
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
 use async_trait::async_trait;
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use uuid::Uuid;
 
 use super::{
     PgPool,
     blocking_helpers::{get_conn_with, run_blocking_with},
-    tenant_tx::{FromTxError, TxError, ensure_tenant_exists, with_tenant_tx},
+    sql_helpers::set_audit_context,
+    tenant_tx::{FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx},
 };
 
 impl FromTxError<Self> for ConversationRepositoryError {
@@ -36,6 +51,11 @@ pub struct PostgresConversationRepository {
     pool: PgPool,
 }
 
+crate::postgres_support::pool_health_check!(
+    PostgresConversationRepository,
+    "conversation_repository"
+);
+
 impl PostgresConversationRepository {
     /// Creates a new repository with the given connection pool.
     #[must_use]
@@ -43,9 +63,11 @@ impl PostgresConversationRepository {
         Self { pool }
     }
 
+    /// Runs `query_fn` in a tenant transaction attributed to the caller, so
+    /// the audit trigger records who wrote the conversation.
     async fn execute_query<F, T>(
         &self,
-        tenant_id: TenantId,
+        ctx: &RequestContext,
         query_fn: F,
     ) -> ConversationRepositoryResult<T>
     where
@@ -53,12 +75,16 @@ impl PostgresConversationRepository {
         T: Send + 'static,
     {
         let pool = self.pool.clone();
+        let audit = AuditContext::from(ctx);
+        let tenant_id = ctx.tenant_id();
         run_blocking_with(
             move || {
                 let mut conn = get_conn_with(&pool, ConversationRepositoryError::persistence)?;
                 with_tenant_tx(&mut conn, tenant_id.into_inner(), |tx| {
                     ensure_tenant_exists(tx, tenant_id.into_inner())
                         .map_err(ConversationRepositoryError::persistence)?;
+                    set_audit_context(tx, &audit)
+                        .map_err(ConversationRepositoryError::persistence)?;
                     query_fn(tx)
                 })
             },
@@ -66,17 +92,45 @@ impl PostgresConversationRepository {
         )
         .await
     }
+
+    async fn read<F, T>(&self, tenant_id: TenantId, query_fn: F) -> ConversationRepositoryResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> ConversationRepositoryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ConversationRepositoryError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_id.into_inner(), query_fn)
+            },
+            ConversationRepositoryError::persistence,
+        )
+        .await
+    }
 }
 
-fn row_to_conversation(row: &ConversationRow) -> ConversationRepositoryResult<Conversation> {
+pub(super) fn row_to_conversation(
+    row: &ConversationRow,
+) -> ConversationRepositoryResult<Conversation> {
     let state = ConversationState::try_from(row.state.as_str())
         .map_err(|err| ConversationRepositoryError::persistence(std::io::Error::other(err)))?;
-    Ok(Conversation::from_persisted(
+    let version = u64::try_from(row.version).map_err(ConversationRepositoryError::persistence)?;
+    let labels = serde_json::from_value::<BTreeMap<String, String>>(row.labels.clone())
+        .map_err(ConversationRepositoryError::persistence)?;
+    let conversation = Conversation::from_persisted(
         ConversationId::from_uuid(row.id),
         state,
         row.created_at,
         row.updated_at,
-    ))
+    )
+    .with_context(row.context.clone())
+    .with_labels(labels)
+    .with_version(version);
+    Ok(match row.task_id {
+        Some(task_id) => conversation.with_task(task_id),
+        None => conversation,
+    })
 }
 
 #[async_trait]
@@ -88,14 +142,10 @@ impl ConversationRepository for PostgresConversationRepository {
     ) -> ConversationRepositoryResult<()> {
         let tenant_id = ctx.tenant_id();
         let conversation_id = conversation.id();
-        let tenant_uuid = tenant_id.into_inner();
-        let new_conversation = NewConversation::new(
-            conversation_id.into_inner(),
-            tenant_uuid,
-            conversation.created_at(),
-        );
+        let new_conversation = NewConversation::from_domain(conversation, tenant_id.into_inner())
+            .map_err(ConversationRepositoryError::persistence)?;
 
-        self.execute_query(tenant_id, move |conn| {
+        self.execute_query(ctx, move |conn| {
             // Use ON CONFLICT DO NOTHING for atomic insert-or-detect
             let inserted = diesel::insert_into(conversations::table)
                 .values(&new_conversation)
@@ -115,6 +165,39 @@ impl ConversationRepository for PostgresConversationRepository {
         .await
     }
 
+    async fn update(
+        &self,
+        ctx: &RequestContext,
+        conversation: &Conversation,
+    ) -> ConversationRepositoryResult<()> {
+        let tenant_id = ctx.tenant_id();
+        let record = NewConversation::from_domain(conversation, tenant_id.into_inner())
+            .map_err(ConversationRepositoryError::persistence)?;
+        self.execute_query(ctx, move |conn| update_record(conn, &record))
+            .await
+    }
+
+    async fn update_labels(
+        &self,
+        ctx: &RequestContext,
+        conversation: &Conversation,
+        event: &ConversationLabelEvent,
+    ) -> ConversationRepositoryResult<()> {
+        let tenant_id = ctx.tenant_id();
+        let record = NewConversation::from_domain(conversation, tenant_id.into_inner())
+            .map_err(ConversationRepositoryError::persistence)?;
+        let event_row = label_event_row(event, tenant_id.into_inner());
+        self.execute_query(ctx, move |conn| {
+            update_record(conn, &record)?;
+            diesel::insert_into(conversation_label_events::table)
+                .values(&event_row)
+                .execute(conn)
+                .map(|_| ())
+                .map_err(ConversationRepositoryError::persistence)
+        })
+        .await
+    }
+
     async fn find_by_id(
         &self,
         ctx: &RequestContext,
@@ -123,7 +206,7 @@ impl ConversationRepository for PostgresConversationRepository {
         let tenant_id = ctx.tenant_id();
         let uuid = conversation_id.into_inner();
         let tenant_uuid = tenant_id.into_inner();
-        self.execute_query(tenant_id, move |conn| {
+        self.execute_query(ctx, move |conn| {
             conversations::table
                 .filter(conversations::id.eq(uuid))
                 .filter(conversations::tenant_id.eq(tenant_uuid))
@@ -137,4 +220,151 @@ impl ConversationRepository for PostgresConversationRepository {
         })
         .await
     }
+
+    async fn find_by_label(
+        &self,
+        ctx: &RequestContext,
+        label: &ConversationLabel,
+        page: PageRequest,
+    ) -> ConversationRepositoryResult<Page<Conversation>> {
+        let tenant_id = ctx.tenant_id();
+        let tenant_uuid = tenant_id.into_inner();
+        let selector = Value::Object(
+            [(
+                label.key().to_owned(),
+                Value::String(label.value().to_owned()),
+            )]
+            .into_iter()
+            .collect::<Map<_, _>>(),
+        );
+        let rows = self
+            .read(tenant_id, move |conn| {
+                let labelled = conversations::table
+                    .filter(conversations::tenant_id.eq(tenant_uuid))
+                    .filter(conversations::labels.contains(selector))
+                    .into_boxed();
+                let paged = keyset_page!(
+                    labelled,
+                    page,
+                    after_timestamp,
+                    (conversations::created_at, conversations::id)
+                );
+                paged
+                    .select(ConversationRow::as_select())
+                    .load::<ConversationRow>(conn)
+                    .map_err(ConversationRepositoryError::persistence)
+            })
+            .await?;
+        Page::from_overfetched(rows, page, |row| {
+            Cursor::at_timestamp(row.created_at, row.id)
+        })
+        .try_map(|row| row_to_conversation(&row))
+    }
+
+    async fn find_label_events(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> ConversationRepositoryResult<Page<ConversationLabelEvent>> {
+        let tenant_id = ctx.tenant_id();
+        let tenant_uuid = tenant_id.into_inner();
+        let rows = self
+            .read(tenant_id, move |conn| {
+                let history = conversation_label_events::table
+                    .filter(conversation_label_events::tenant_id.eq(tenant_uuid))
+                    .filter(
+                        conversation_label_events::conversation_id.eq(conversation_id.into_inner()),
+                    )
+                    .into_boxed();
+                let paged = keyset_page!(
+                    history,
+                    page,
+                    after_timestamp,
+                    (
+                        conversation_label_events::occurred_at,
+                        conversation_label_events::id
+                    )
+                );
+                paged
+                    .select(ConversationLabelEventRow::as_select())
+                    .load::<ConversationLabelEventRow>(conn)
+                    .map_err(ConversationRepositoryError::persistence)
+            })
+            .await?;
+        Ok(Page::from_overfetched(rows, page, |row| {
+            Cursor::at_timestamp(row.occurred_at, row.id)
+        })
+        .map(row_to_label_event))
+    }
+}
+
+/// Writes `record` over the stored conversation when its version matches,
+/// bumping the version.
+fn update_record(
+    conn: &mut PgConnection,
+    record: &NewConversation,
+) -> ConversationRepositoryResult<()> {
+    let conversation_id = ConversationId::from_uuid(record.id);
+    let scope = || {
+        conversations::table
+            .filter(conversations::id.eq(record.id))
+            .filter(conversations::tenant_id.eq(record.tenant_id))
+    };
+    let updated = diesel::update(scope().filter(conversations::version.eq(record.version)))
+        .set((
+            conversations::state.eq(&record.state),
+            conversations::context.eq(&record.context),
+            conversations::task_id.eq(record.task_id),
+            conversations::labels.eq(&record.labels),
+            conversations::updated_at.eq(record.updated_at),
+            conversations::version.eq(conversations::version + 1),
+        ))
+        .execute(conn)
+        .map_err(ConversationRepositoryError::persistence)?;
+    if updated > 0 {
+        return Ok(());
+    }
+
+    let actual = scope()
+        .select(conversations::version)
+        .first::<i64>(conn)
+        .optional()
+        .map_err(ConversationRepositoryError::persistence)?
+        .ok_or(ConversationRepositoryError::NotFound(conversation_id))?;
+    Err(ConversationRepositoryError::ConcurrentModification {
+        conversation_id,
+        expected: u64::try_from(record.version)
+            .map_err(ConversationRepositoryError::persistence)?,
+        actual: u64::try_from(actual).map_err(ConversationRepositoryError::persistence)?,
+    })
+}
+
+fn label_event_row(event: &ConversationLabelEvent, tenant_id: Uuid) -> ConversationLabelEventRow {
+    ConversationLabelEventRow {
+        id: event.id,
+        tenant_id,
+        conversation_id: event.change.conversation_id.into_inner(),
+        label_key: event.change.key.clone(),
+        previous_value: event.change.previous.clone(),
+        label_value: event.change.value.clone(),
+        actor_id: event.actor.into_inner(),
+        correlation_id: event.correlation_id.into_inner(),
+        occurred_at: event.occurred_at,
+    }
+}
+
+fn row_to_label_event(row: ConversationLabelEventRow) -> ConversationLabelEvent {
+    ConversationLabelEvent {
+        id: row.id,
+        change: ConversationLabelChange {
+            conversation_id: ConversationId::from_uuid(row.conversation_id),
+            key: row.label_key,
+            previous: row.previous_value,
+            value: row.label_value,
+        },
+        actor: UserId::from_uuid(row.actor_id),
+        correlation_id: CorrelationId::from_uuid(row.correlation_id),
+        occurred_at: row.occurred_at,
+    }
 }
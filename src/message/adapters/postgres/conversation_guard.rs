@@ -0,0 +1,53 @@
+//! Write protection for archived conversations.
+//!
+//! Writers check the conversation state inside their own transaction and
+//! hold a `FOR SHARE` lock on the conversation row until commit, so a
+//! concurrent archive waits for in-flight writes instead of racing them.
+//! Appends take a `FOR UPDATE` lock instead, which also serialises sequence
+//! allocation between concurrent appends to the same conversation.
+
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+
+use crate::context::RequestContext;
+use crate::message::adapters::schema::conversations;
+use crate::message::domain::{ConversationId, ConversationState};
+
+/// Returns `true` when the tenant's conversation exists and is archived.
+///
+/// Missing conversations, including other tenants' conversations, are
+/// reported as not archived so that callers keep their existing not-found
+/// handling.
+pub(super) fn conversation_is_archived(
+    conn: &mut PgConnection,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+) -> QueryResult<bool> {
+    let state = conversations::table
+        .filter(conversations::id.eq(conversation_id.into_inner()))
+        .filter(conversations::tenant_id.eq(ctx.tenant_id().into_inner()))
+        .select(conversations::state)
+        .for_share()
+        .first::<String>(conn)
+        .optional()?;
+    Ok(state.as_deref() == Some(ConversationState::Archived.as_str()))
+}
+
+/// Locks the tenant's conversation row for an append and returns its state.
+///
+/// Returns `None` when the tenant has no such conversation. The lock is
+/// held until commit, so concurrent appends to the conversation allocate
+/// sequence numbers one at a time.
+pub(super) fn lock_conversation_for_append(
+    conn: &mut PgConnection,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+) -> QueryResult<Option<String>> {
+    conversations::table
+        .filter(conversations::id.eq(conversation_id.into_inner()))
+        .filter(conversations::tenant_id.eq(ctx.tenant_id().into_inner()))
+        .select(conversations::state)
+        .for_update()
+        .first::<String>(conn)
+        .optional()
+}
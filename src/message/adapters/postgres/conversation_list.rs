@@ -0,0 +1,189 @@
+//! `PostgreSQL` implementation of the `ConversationListPort`.
+//!
+//! Listings read the trigger-maintained `conversation_summaries` projection.
+//! Unset filters collapse to `IS NULL` checks on their bind parameters, and
+//! keyset pagination compares `(sort column, conversation_id)` row values so
+//! each page is a range scan on the tenant-leading sort indexes.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::models::ConversationSummaryRow,
+    domain::{
+        ConversationId, ConversationListQuery, ConversationSortKey, ConversationState,
+        ConversationSummary,
+    },
+    ports::conversation_list::{
+        ConversationListError, ConversationListPort, ConversationListResult,
+    },
+};
+use crate::pagination::{Page, Seek, SortOrder};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Nullable, Text, Timestamptz, Uuid as SqlUuid};
+
+use super::blocking_helpers::{PgPool, run_blocking_with};
+use super::replica::{ReadReplica, read_connection};
+use super::tenant_tx::{FromTxError, TxError, with_tenant_read_tx};
+
+impl FromTxError<Self> for ConversationListError {
+    fn from_tx_error(tx_err: TxError<Self>) -> Self {
+        match tx_err {
+            TxError::Domain(domain_err) => domain_err,
+            TxError::Diesel(diesel_err) => Self::persistence(diesel_err),
+        }
+    }
+}
+
+/// Builds the listing statement for a sort key and direction.
+///
+/// Bind parameters: `$1` tenant, `$2` state, `$3` agent backend, `$4` task,
+/// `$5` label, `$6` last-activity start (inclusive), `$7` last-activity end
+/// (exclusive), `$8` cursor sort value, `$9` cursor conversation, `$10`
+/// row limit.
+fn listing_sql(sort_key: ConversationSortKey, order: SortOrder) -> String {
+    let column = match sort_key {
+        ConversationSortKey::LastActivity => "last_activity_at",
+        ConversationSortKey::CreatedAt => "created_at",
+    };
+    let (comparison, direction) = match order {
+        SortOrder::Ascending => (">", "ASC"),
+        SortOrder::Descending => ("<", "DESC"),
+    };
+    [
+        "SELECT conversation_id, tenant_id, state, task_id, labels, agent_backend, ",
+        "message_count, created_at, last_activity_at ",
+        "FROM conversation_summaries ",
+        "WHERE tenant_id = $1 ",
+        "AND ($2::text IS NULL OR state = $2) ",
+        "AND ($3::text IS NULL OR agent_backend = $3) ",
+        "AND ($4::uuid IS NULL OR task_id = $4) ",
+        "AND ($5::text IS NULL OR labels @> ARRAY[$5::text]) ",
+        "AND ($6::timestamptz IS NULL OR last_activity_at >= $6) ",
+        "AND ($7::timestamptz IS NULL OR last_activity_at < $7) ",
+        &format!(
+            "AND ($8::timestamptz IS NULL OR ({column}, conversation_id) {comparison} ($8, $9)) "
+        ),
+        &format!("ORDER BY {column} {direction}, conversation_id {direction} "),
+        "LIMIT $10",
+    ]
+    .concat()
+}
+
+/// `PostgreSQL` implementation of [`ConversationListPort`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access. With a [`ReadReplica`], reads go to the replica
+/// while it keeps within its staleness tolerance.
+#[derive(Debug, Clone)]
+pub struct PostgresConversationListAdapter {
+    pool: PgPool,
+    replica: Option<ReadReplica>,
+}
+
+crate::postgres_support::pool_health_check!(PostgresConversationListAdapter, "conversation_list");
+
+impl PostgresConversationListAdapter {
+    /// Creates a new adapter with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            replica: None,
+        }
+    }
+
+    /// Reads from `replica`, falling back to the primary pool when the
+    /// replica is exhausted or stale.
+    #[must_use]
+    pub fn with_read_replica(mut self, replica: ReadReplica) -> Self {
+        self.replica = Some(replica);
+        self
+    }
+}
+
+#[async_trait]
+impl ConversationListPort for PostgresConversationListAdapter {
+    async fn list_conversations(
+        &self,
+        ctx: &RequestContext,
+        query: &ConversationListQuery,
+    ) -> ConversationListResult<Page<ConversationSummary>> {
+        ConversationListError::check_query(query)?;
+        let after = match query.page.after_timestamp() {
+            Seek::Start => None,
+            Seek::After(sort_value, conversation_id) => Some((sort_value, conversation_id)),
+            Seek::Foreign => return Ok(Page::default()),
+        };
+
+        let pool = self.pool.clone();
+        let replica = self.replica.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let owned_query = query.clone();
+
+        let rows = run_blocking_with(
+            move || {
+                let mut conn = read_connection(&pool, replica.as_ref())
+                    .map_err(ConversationListError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    load_summary_rows(tx, tenant_uuid, &owned_query, after)
+                })
+            },
+            ConversationListError::persistence,
+        )
+        .await?;
+
+        let summaries = rows
+            .into_iter()
+            .map(row_to_summary)
+            .collect::<ConversationListResult<Vec<_>>>()?;
+        Ok(Page::from_overfetched(summaries, query.page, |summary| {
+            query.position(summary)
+        }))
+    }
+}
+
+/// Loads one row more than the page limit, resuming after the `after`
+/// sort value and conversation, so the page can tell whether another page
+/// follows.
+fn load_summary_rows(
+    conn: &mut PgConnection,
+    tenant_uuid: uuid::Uuid,
+    query: &ConversationListQuery,
+    after: Option<(DateTime<Utc>, uuid::Uuid)>,
+) -> ConversationListResult<Vec<ConversationSummaryRow>> {
+    let filter = &query.filter;
+    diesel::sql_query(listing_sql(query.sort_key, query.page.order()))
+        .bind::<SqlUuid, _>(tenant_uuid)
+        .bind::<Nullable<Text>, _>(filter.state.map(ConversationState::as_str))
+        .bind::<Nullable<Text>, _>(filter.agent_backend.as_deref())
+        .bind::<Nullable<SqlUuid>, _>(filter.task_id)
+        .bind::<Nullable<Text>, _>(filter.label.as_deref())
+        .bind::<Nullable<Timestamptz>, _>(filter.last_activity_from)
+        .bind::<Nullable<Timestamptz>, _>(filter.last_activity_until)
+        .bind::<Nullable<Timestamptz>, _>(after.map(|(sort_value, _)| sort_value))
+        .bind::<Nullable<SqlUuid>, _>(after.map(|(_, conversation_id)| conversation_id))
+        .bind::<BigInt, _>(query.page.sql_fetch_limit())
+        .load::<ConversationSummaryRow>(conn)
+        .map_err(ConversationListError::persistence)
+}
+
+/// Converts a projection row to a domain [`ConversationSummary`].
+fn row_to_summary(row: ConversationSummaryRow) -> ConversationListResult<ConversationSummary> {
+    let state = ConversationState::try_from(row.state.as_str())
+        .map_err(ConversationListError::persistence)?;
+    let message_count =
+        u64::try_from(row.message_count).map_err(ConversationListError::persistence)?;
+
+    Ok(ConversationSummary {
+        conversation_id: ConversationId::from_uuid(row.conversation_id),
+        state,
+        task_id: row.task_id,
+        labels: row.labels,
+        agent_backend: row.agent_backend,
+        message_count,
+        created_at: row.created_at,
+        last_activity_at: row.last_activity_at,
+    })
+}
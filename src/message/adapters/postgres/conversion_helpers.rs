@@ -30,6 +30,9 @@ pub(super) fn ser_err<E: std::fmt::Display>(e: E) -> RepositoryError {
 /// - The metadata JSONB cannot be deserialized to [`MessageMetadata`]
 /// - The sequence number is negative (invalid for `u64`)
 /// - The content is empty (domain invariant violation)
+///
+/// Returns [`RepositoryError::InvalidSequenceNumber`] if the sequence number
+/// is zero.
 pub(crate) fn row_to_message(row: MessageRow) -> RepositoryResult<Message> {
     let role = Role::try_from(row.role.as_str()).map_err(ser_err)?;
     let content: Vec<ContentPart> = serde_json::from_value(row.content).map_err(ser_err)?;
@@ -43,7 +46,7 @@ pub(crate) fn row_to_message(row: MessageRow) -> RepositoryResult<Message> {
         content,
         metadata,
         row.created_at,
-        SequenceNumber::new(sequence_number),
+        SequenceNumber::try_new(sequence_number)?,
     )
     .map_err(ser_err)
 }
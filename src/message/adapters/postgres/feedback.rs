@@ -0,0 +1,237 @@
+//! `PostgreSQL` implementation of the `MessageFeedbackRepository` port.
+//!
+//! Feedback is upserted on the `(tenant_id, message_id, author_id)` unique
+//! constraint so a reviewer's resubmission replaces their earlier rating in
+//! place. Summaries load the tenant's feedback rows and fold them with
+//! [`FeedbackSummary::aggregate`].
+
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+
+use crate::context::{RequestContext, UserId};
+use crate::message::{
+    adapters::models::MessageFeedbackRow,
+    adapters::schema::message_feedback,
+    domain::{
+        ConversationId, FeedbackCategory, FeedbackId, FeedbackRating, FeedbackSubmission,
+        FeedbackSummary, MessageFeedback, MessageId, ParseFeedbackValueError,
+        PersistedFeedbackData,
+    },
+    ports::feedback::{FeedbackError, FeedbackResult, MessageFeedbackRepository},
+};
+
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+
+impl FromTxError<Self> for FeedbackError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL` implementation of [`MessageFeedbackRepository`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access.
+#[derive(Debug, Clone)]
+pub struct PostgresMessageFeedbackRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresMessageFeedbackRepository,
+    "message_feedback_repository"
+);
+
+impl PostgresMessageFeedbackRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn read_rows<F>(
+        &self,
+        ctx: &RequestContext,
+        query_fn: F,
+    ) -> FeedbackResult<Vec<MessageFeedback>>
+    where
+        F: FnOnce(&mut PgConnection, uuid::Uuid) -> QueryResult<Vec<MessageFeedbackRow>>
+            + Send
+            + 'static,
+    {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let rows = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, FeedbackError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    query_fn(tx, tenant_uuid).map_err(FeedbackError::persistence)
+                })
+            },
+            FeedbackError::persistence,
+        )
+        .await?;
+        rows.into_iter().map(row_to_feedback).collect()
+    }
+}
+
+#[async_trait]
+impl MessageFeedbackRepository for PostgresMessageFeedbackRepository {
+    async fn upsert(
+        &self,
+        ctx: &RequestContext,
+        feedback: &MessageFeedback,
+    ) -> FeedbackResult<MessageFeedback> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = to_row(feedback, tenant_uuid);
+        let stored = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, FeedbackError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid).map_err(FeedbackError::persistence)?;
+                    upsert_row(tx, &row).map_err(FeedbackError::persistence)
+                })
+            },
+            FeedbackError::persistence,
+        )
+        .await?;
+        row_to_feedback(stored)
+    }
+
+    async fn list_for_message(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+        page: PageRequest,
+    ) -> FeedbackResult<Page<MessageFeedback>> {
+        let message_uuid = message_id.into_inner();
+        let feedback = self
+            .read_rows(ctx, move |conn, tenant_uuid| {
+                let query = message_feedback::table
+                    .filter(message_feedback::tenant_id.eq(tenant_uuid))
+                    .filter(message_feedback::message_id.eq(message_uuid))
+                    .select(MessageFeedbackRow::as_select())
+                    .into_boxed();
+                keyset_page!(
+                    query,
+                    page,
+                    after_timestamp,
+                    (message_feedback::submitted_at, message_feedback::id)
+                )
+                .load(conn)
+            })
+            .await?;
+        Ok(Page::from_overfetched(feedback, page, |record| {
+            Cursor::at_timestamp(record.submitted_at(), record.id().into_inner())
+        }))
+    }
+
+    async fn summarize(&self, ctx: &RequestContext) -> FeedbackResult<Vec<FeedbackSummary>> {
+        let feedback = self
+            .read_rows(ctx, |conn, tenant_uuid| {
+                message_feedback::table
+                    .filter(message_feedback::tenant_id.eq(tenant_uuid))
+                    .select(MessageFeedbackRow::as_select())
+                    .load(conn)
+            })
+            .await?;
+        Ok(FeedbackSummary::aggregate(&feedback))
+    }
+}
+
+fn upsert_row(
+    conn: &mut PgConnection,
+    row: &MessageFeedbackRow,
+) -> QueryResult<MessageFeedbackRow> {
+    diesel::insert_into(message_feedback::table)
+        .values(row)
+        .on_conflict((
+            message_feedback::tenant_id,
+            message_feedback::message_id,
+            message_feedback::author_id,
+        ))
+        .do_update()
+        .set((
+            message_feedback::rating.eq(excluded(message_feedback::rating)),
+            message_feedback::stars.eq(excluded(message_feedback::stars)),
+            message_feedback::comment.eq(excluded(message_feedback::comment)),
+            message_feedback::category.eq(excluded(message_feedback::category)),
+            message_feedback::agent_backend.eq(excluded(message_feedback::agent_backend)),
+            message_feedback::instruction_set_version
+                .eq(excluded(message_feedback::instruction_set_version)),
+            message_feedback::submitted_at.eq(excluded(message_feedback::submitted_at)),
+        ))
+        .returning(MessageFeedbackRow::as_returning())
+        .get_result(conn)
+}
+
+fn to_row(feedback: &MessageFeedback, tenant_id: uuid::Uuid) -> MessageFeedbackRow {
+    let (rating, stars) = match feedback.rating() {
+        FeedbackRating::ThumbsUp => ("thumbs_up", None),
+        FeedbackRating::ThumbsDown => ("thumbs_down", None),
+        FeedbackRating::Stars(score) => ("stars", Some(i16::from(score))),
+    };
+    MessageFeedbackRow {
+        id: feedback.id().into_inner(),
+        tenant_id,
+        conversation_id: feedback.conversation_id().into_inner(),
+        message_id: feedback.message_id().into_inner(),
+        author_id: feedback.author().into_inner(),
+        rating: rating.to_owned(),
+        stars,
+        comment: feedback.comment().map(str::to_owned),
+        category: feedback
+            .category()
+            .map(|category| category.as_str().to_owned()),
+        agent_backend: feedback.agent_backend().map(str::to_owned),
+        instruction_set_version: feedback.instruction_set_version().map(str::to_owned),
+        submitted_at: feedback.submitted_at(),
+    }
+}
+
+fn row_to_feedback(row: MessageFeedbackRow) -> FeedbackResult<MessageFeedback> {
+    let rating = match (row.rating.as_str(), row.stars) {
+        ("thumbs_up", None) => FeedbackRating::ThumbsUp,
+        ("thumbs_down", None) => FeedbackRating::ThumbsDown,
+        ("stars", Some(score)) => FeedbackRating::Stars(
+            u8::try_from(score).map_err(FeedbackError::invalid_persisted_data)?,
+        ),
+        (other, _) => {
+            return Err(FeedbackError::invalid_persisted_data(
+                ParseFeedbackValueError(other.to_owned()),
+            ));
+        }
+    };
+    let category = row
+        .category
+        .as_deref()
+        .map(FeedbackCategory::try_from)
+        .transpose()
+        .map_err(FeedbackError::invalid_persisted_data)?;
+    Ok(MessageFeedback::from_persisted(PersistedFeedbackData {
+        id: FeedbackId::from_uuid(row.id),
+        conversation_id: ConversationId::from_uuid(row.conversation_id),
+        message_id: MessageId::from_uuid(row.message_id),
+        author: UserId::from_uuid(row.author_id),
+        submission: FeedbackSubmission {
+            rating,
+            comment: row.comment,
+            category,
+        },
+        agent_backend: row.agent_backend,
+        instruction_set_version: row.instruction_set_version,
+        submitted_at: row.submitted_at,
+    }))
+}
@@ -0,0 +1,314 @@
+//! `PostgreSQL` implementation of the `ConversationForkRepository` port.
+//!
+//! A fork runs in one transaction. It locks the parent conversation against
+//! concurrent changes, checks the fork point, inserts the fork's
+//! conversation, copies the shared messages under fresh identifiers, and
+//! records the provenance row. Sealed messages are opened and sealed again
+//! for their copies, since the associated data binds a sealed column to its
+//! message ID. The copies fire the usual message triggers, so audit rows
+//! and the conversation summary projection cover them.
+
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use std::sync::Arc;
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::conversation::row_to_conversation;
+use super::sealing::MessageCodec;
+use super::sql_helpers::{MAX_ROWS_PER_INSERT, set_audit_context};
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    adapters::{
+        audit_context::AuditContext,
+        models::{ConversationForkRow, ConversationRow, MessageRow, NewConversation, NewMessage},
+        schema::{context_snapshots, conversation_forks, conversations, messages},
+    },
+    domain::{Conversation, ConversationFork, ConversationId, SequenceNumber},
+    ports::{
+        content_cipher::ContentCipher,
+        fork::{ConversationForkError, ConversationForkRepository, ConversationForkResult},
+    },
+};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
+
+impl FromTxError<Self> for ConversationForkError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL` implementation of [`ConversationForkRepository`].
+#[derive(Debug, Clone)]
+pub struct PostgresConversationForkRepository {
+    pool: PgPool,
+    codec: MessageCodec,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresConversationForkRepository,
+    "conversation_fork_repository"
+);
+
+impl PostgresConversationForkRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            codec: MessageCodec::plain(),
+        }
+    }
+
+    /// Seals the copied messages with `cipher`, as
+    /// [`PostgresMessageRepository::with_cipher`] does.
+    ///
+    /// Configure the cipher the message repository uses: without it,
+    /// forking a conversation with sealed messages fails.
+    ///
+    /// [`PostgresMessageRepository::with_cipher`]: super::PostgresMessageRepository::with_cipher
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Arc<dyn ContentCipher>) -> Self {
+        self.codec = MessageCodec::sealed(cipher);
+        self
+    }
+
+    async fn read<F, T>(&self, tenant_id: TenantId, query_fn: F) -> ConversationForkResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> ConversationForkResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ConversationForkError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_id.into_inner(), query_fn)
+            },
+            ConversationForkError::persistence,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl ConversationForkRepository for PostgresConversationForkRepository {
+    async fn fork(
+        &self,
+        ctx: &RequestContext,
+        fork: &ConversationFork,
+    ) -> ConversationForkResult<ConversationFork> {
+        let pool = self.pool.clone();
+        let audit = AuditContext::from(ctx);
+        let tenant_id = ctx.tenant_id().into_inner();
+        let codec = self.codec.clone();
+        let fork = *fork;
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ConversationForkError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_id, |tx| {
+                    ensure_tenant_exists(tx, tenant_id)
+                        .map_err(ConversationForkError::persistence)?;
+                    set_audit_context(tx, &audit).map_err(ConversationForkError::persistence)?;
+                    create_fork(tx, &codec, tenant_id, fork)
+                })
+            },
+            ConversationForkError::persistence,
+        )
+        .await
+    }
+
+    async fn find_fork(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> ConversationForkResult<Option<ConversationFork>> {
+        let tenant_id = ctx.tenant_id();
+        let tenant_uuid = tenant_id.into_inner();
+        self.read(tenant_id, move |conn| {
+            conversation_forks::table
+                .filter(conversation_forks::tenant_id.eq(tenant_uuid))
+                .filter(conversation_forks::conversation_id.eq(conversation_id.into_inner()))
+                .select(ConversationForkRow::as_select())
+                .first::<ConversationForkRow>(conn)
+                .optional()
+                .map_err(ConversationForkError::persistence)?
+                .map(row_to_fork)
+                .transpose()
+        })
+        .await
+    }
+
+    async fn find_forks(
+        &self,
+        ctx: &RequestContext,
+        parent_conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> ConversationForkResult<Page<ConversationFork>> {
+        let tenant_id = ctx.tenant_id();
+        let tenant_uuid = tenant_id.into_inner();
+        self.read(tenant_id, move |conn| {
+            let query = conversation_forks::table
+                .filter(conversation_forks::tenant_id.eq(tenant_uuid))
+                .filter(
+                    conversation_forks::parent_conversation_id
+                        .eq(parent_conversation_id.into_inner()),
+                )
+                .select(ConversationForkRow::as_select())
+                .into_boxed();
+            let rows = keyset_page!(
+                query,
+                page,
+                after_timestamp,
+                (
+                    conversation_forks::created_at,
+                    conversation_forks::conversation_id
+                )
+            )
+            .load::<ConversationForkRow>(conn)
+            .map_err(ConversationForkError::persistence)?;
+            Page::from_overfetched(rows, page, |row| {
+                Cursor::at_timestamp(row.created_at, row.conversation_id)
+            })
+            .try_map(row_to_fork)
+        })
+        .await
+    }
+}
+
+/// Creates the fork's conversation, copies, and provenance.
+fn create_fork(
+    conn: &mut PgConnection,
+    codec: &MessageCodec,
+    tenant_id: Uuid,
+    fork: ConversationFork,
+) -> ConversationForkResult<ConversationFork> {
+    let parent = lock_parent(conn, tenant_id, fork.parent_conversation_id)?;
+    let forked_at = sequence_column(fork.forked_at)?;
+    ensure_fork_point(conn, tenant_id, &fork, forked_at)?;
+    let stored = context_snapshots::table
+        .filter(context_snapshots::tenant_id.eq(tenant_id))
+        .filter(context_snapshots::conversation_id.eq(fork.parent_conversation_id.into_inner()))
+        .filter(context_snapshots::sequence_end.le(forked_at))
+        .order(context_snapshots::captured_at.desc())
+        .select(context_snapshots::id)
+        .first::<Uuid>(conn)
+        .optional()
+        .map_err(ConversationForkError::persistence)?
+        .map_or(fork, |snapshot_id| fork.with_parent_snapshot(snapshot_id));
+    let conversation = NewConversation::from_domain(&stored.conversation(&parent), tenant_id)
+        .map_err(ConversationForkError::persistence)?;
+    diesel::insert_into(conversations::table)
+        .values(&conversation)
+        .execute(conn)
+        .map_err(ConversationForkError::persistence)?;
+    copy_shared_messages(conn, codec, tenant_id, &stored, forked_at)?;
+    diesel::insert_into(conversation_forks::table)
+        .values(ConversationForkRow {
+            conversation_id: stored.conversation_id.into_inner(),
+            tenant_id,
+            parent_conversation_id: stored.parent_conversation_id.into_inner(),
+            forked_at_sequence: forked_at,
+            parent_snapshot_id: stored.parent_snapshot_id,
+            created_at: stored.created_at,
+        })
+        .execute(conn)
+        .map_err(ConversationForkError::persistence)?;
+    Ok(stored)
+}
+
+/// Loads the parent conversation, locking it until the fork commits.
+fn lock_parent(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    parent_id: ConversationId,
+) -> ConversationForkResult<Conversation> {
+    let row = conversations::table
+        .filter(conversations::id.eq(parent_id.into_inner()))
+        .filter(conversations::tenant_id.eq(tenant_id))
+        .select(ConversationRow::as_select())
+        .for_share()
+        .first::<ConversationRow>(conn)
+        .optional()
+        .map_err(ConversationForkError::persistence)?
+        .ok_or(ConversationForkError::ParentNotFound(parent_id))?;
+    row_to_conversation(&row).map_err(ConversationForkError::persistence)
+}
+
+fn ensure_fork_point(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    fork: &ConversationFork,
+    forked_at: i64,
+) -> ConversationForkResult<()> {
+    let exists = diesel::select(diesel::dsl::exists(
+        messages::table
+            .filter(messages::tenant_id.eq(tenant_id))
+            .filter(messages::conversation_id.eq(fork.parent_conversation_id.into_inner()))
+            .filter(messages::sequence_number.eq(forked_at)),
+    ))
+    .get_result::<bool>(conn)
+    .map_err(ConversationForkError::persistence)?;
+    if exists {
+        return Ok(());
+    }
+    Err(ConversationForkError::ForkPointNotFound {
+        conversation_id: fork.parent_conversation_id,
+        forked_at: fork.forked_at,
+    })
+}
+
+/// Copies the parent's messages through the fork point into the fork,
+/// sealing each copy under its own ID.
+fn copy_shared_messages(
+    conn: &mut PgConnection,
+    codec: &MessageCodec,
+    tenant_id: Uuid,
+    fork: &ConversationFork,
+    forked_at: i64,
+) -> ConversationForkResult<()> {
+    let copies = messages::table
+        .filter(messages::tenant_id.eq(tenant_id))
+        .filter(messages::conversation_id.eq(fork.parent_conversation_id.into_inner()))
+        .filter(messages::sequence_number.le(forked_at))
+        .order(messages::sequence_number.asc())
+        .select(MessageRow::as_select())
+        .load::<MessageRow>(conn)
+        .map_err(ConversationForkError::persistence)?
+        .into_iter()
+        .map(|row| codec.seal_copy(row, Uuid::new_v4(), fork.conversation_id.into_inner()))
+        .collect::<Result<Vec<NewMessage>, _>>()
+        .map_err(ConversationForkError::persistence)?;
+    for chunk in copies.chunks(MAX_ROWS_PER_INSERT) {
+        diesel::insert_into(messages::table)
+            .values(chunk)
+            .execute(conn)
+            .map_err(ConversationForkError::persistence)?;
+    }
+    Ok(())
+}
+
+fn sequence_column(sequence: SequenceNumber) -> ConversationForkResult<i64> {
+    i64::try_from(sequence.value()).map_err(ConversationForkError::persistence)
+}
+
+fn row_to_fork(row: ConversationForkRow) -> ConversationForkResult<ConversationFork> {
+    let forked_at =
+        u64::try_from(row.forked_at_sequence).map_err(ConversationForkError::persistence)?;
+    Ok(ConversationFork {
+        conversation_id: ConversationId::from_uuid(row.conversation_id),
+        parent_conversation_id: ConversationId::from_uuid(row.parent_conversation_id),
+        forked_at: SequenceNumber::try_new(forked_at)
+            .map_err(ConversationForkError::persistence)?,
+        parent_snapshot_id: row.parent_snapshot_id,
+        created_at: row.created_at,
+    })
+}
@@ -0,0 +1,78 @@
+//! `PostgreSQL` implementation of the `HandoffChainPort`.
+//!
+//! Loads a conversation's handoffs, sessions, and handoff snapshots in one
+//! read transaction, so the chain reflects a single consistent state.
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+
+use super::PostgresHandoffAdapter;
+use super::conversion::row_to_handoff;
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::models::{AgentSessionRow, ContextSnapshotRow, HandoffRow},
+    adapters::postgres::{agent_session::row_to_session, context_snapshot::row_to_snapshot},
+    adapters::schema::{agent_sessions, context_snapshots, handoffs},
+    domain::{ConversationId, HandoffChain, SnapshotType},
+    ports::{
+        handoff::{HandoffError, HandoffResult},
+        handoff_chain::HandoffChainPort,
+    },
+};
+
+#[async_trait]
+impl HandoffChainPort for PostgresHandoffAdapter {
+    async fn handoff_chain(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> HandoffResult<HandoffChain> {
+        let tenant_id = ctx.tenant_id();
+        let tenant_uuid = tenant_id.into_inner();
+        let uuid = conversation_id.into_inner();
+
+        self.execute_read_query(tenant_id, move |conn| {
+            let handoff_rows = handoffs::table
+                .filter(handoffs::tenant_id.eq(tenant_uuid))
+                .filter(handoffs::conversation_id.eq(uuid))
+                .order((handoffs::initiated_at.asc(), handoffs::id.asc()))
+                .select(HandoffRow::as_select())
+                .load::<HandoffRow>(conn)
+                .map_err(HandoffError::persistence)?;
+            let session_rows = agent_sessions::table
+                .filter(agent_sessions::tenant_id.eq(tenant_uuid))
+                .filter(agent_sessions::conversation_id.eq(uuid))
+                .select(AgentSessionRow::as_select())
+                .load::<AgentSessionRow>(conn)
+                .map_err(HandoffError::persistence)?;
+            let snapshot_rows = context_snapshots::table
+                .filter(context_snapshots::tenant_id.eq(tenant_uuid))
+                .filter(context_snapshots::conversation_id.eq(uuid))
+                .filter(context_snapshots::snapshot_type.eq_any([
+                    SnapshotType::HandoffInitiated.as_str(),
+                    SnapshotType::SessionStart.as_str(),
+                ]))
+                .select(ContextSnapshotRow::as_select())
+                .load::<ContextSnapshotRow>(conn)
+                .map_err(HandoffError::persistence)?;
+
+            let handoffs = handoff_rows
+                .into_iter()
+                .map(row_to_handoff)
+                .collect::<HandoffResult<Vec<_>>>()?;
+            let sessions = session_rows
+                .into_iter()
+                .map(row_to_session)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(HandoffError::persistence)?;
+            let snapshots = snapshot_rows
+                .into_iter()
+                .map(row_to_snapshot)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(HandoffError::persistence)?;
+            HandoffChain::assemble(conversation_id, handoffs, sessions, &snapshots)
+                .map_err(HandoffError::SessionNotFound)
+        })
+        .await
+    }
+}
@@ -0,0 +1,59 @@
+//! Conversions between handoff domain values and database rows.
+
+use crate::context::TenantId;
+use crate::message::{
+    adapters::models::{HandoffRow, NewHandoff},
+    domain::{
+        AgentSessionId, ConversationId, HandoffId, HandoffMetadata, HandoffStatus,
+        ToolCallReference, TurnId,
+    },
+    ports::handoff::{HandoffError, HandoffResult},
+};
+
+/// Converts a domain `HandoffMetadata` to a `NewHandoff` for insertion.
+pub(super) fn handoff_to_new_row(
+    handoff: &HandoffMetadata,
+    conversation_id: ConversationId,
+    tenant_id: TenantId,
+) -> HandoffResult<NewHandoff> {
+    let triggering_tool_calls =
+        serde_json::to_value(&handoff.triggering_tool_calls).map_err(HandoffError::persistence)?;
+
+    Ok(NewHandoff {
+        id: handoff.handoff_id.into_inner(),
+        tenant_id: tenant_id.into_inner(),
+        source_session_id: handoff.source_session_id.into_inner(),
+        conversation_id: conversation_id.into_inner(),
+        target_session_id: handoff.target_session_id.map(AgentSessionId::into_inner),
+        prior_turn_id: handoff.prior_turn_id.into_inner(),
+        triggering_tool_calls,
+        source_agent: handoff.source_agent.clone(),
+        target_agent: handoff.target_agent.clone(),
+        reason: handoff.reason.clone(),
+        initiated_at: handoff.initiated_at,
+        completed_at: handoff.completed_at,
+        status: handoff.status.as_str().to_owned(),
+    })
+}
+
+/// Converts a database row to a domain `HandoffMetadata`.
+pub(super) fn row_to_handoff(row: HandoffRow) -> HandoffResult<HandoffMetadata> {
+    let triggering_tool_calls: Vec<ToolCallReference> =
+        serde_json::from_value(row.triggering_tool_calls).map_err(HandoffError::persistence)?;
+
+    let status = HandoffStatus::try_from(row.status.as_str()).map_err(HandoffError::persistence)?;
+
+    Ok(HandoffMetadata {
+        handoff_id: HandoffId::from_uuid(row.id),
+        source_session_id: AgentSessionId::from_uuid(row.source_session_id),
+        target_session_id: row.target_session_id.map(AgentSessionId::from_uuid),
+        prior_turn_id: TurnId::from_uuid(row.prior_turn_id),
+        triggering_tool_calls,
+        source_agent: row.source_agent,
+        target_agent: row.target_agent,
+        reason: row.reason,
+        initiated_at: row.initiated_at,
+        completed_at: row.completed_at,
+        status,
+    })
+}
@@ -9,6 +9,9 @@
 //! not enforce row isolation by itself; actual enforcement requires RLS
 //! policies on the `handoffs` table, which land in milestone 1.5.3.
 
+mod chain;
+mod conversion;
+
 use async_trait::async_trait;
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
@@ -16,16 +19,20 @@ use mockable::DefaultClock;
 
 use crate::context::{RequestContext, TenantId};
 use crate::message::{
-    adapters::models::{HandoffRow, NewHandoff},
+    adapters::models::HandoffRow,
     adapters::schema::handoffs,
     domain::{
         AgentSessionId, ConversationId, HandoffId, HandoffMetadata, HandoffParams, HandoffStatus,
-        ToolCallReference, TurnId,
     },
     ports::handoff::{AgentHandoffPort, HandoffError, HandoffResult, InitiateHandoffParams},
 };
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
+
+use conversion::{handoff_to_new_row, row_to_handoff};
 
 use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::conversation_guard::conversation_is_archived;
 use super::tenant_tx::{
     FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
 };
@@ -56,6 +63,8 @@ pub struct PostgresHandoffAdapter {
     pool: PgPool,
 }
 
+crate::postgres_support::pool_health_check!(PostgresHandoffAdapter, "handoff");
+
 impl PostgresHandoffAdapter {
     /// Creates a new adapter with the given connection pool.
     #[must_use]
@@ -160,9 +169,12 @@ impl AgentHandoffPort for PostgresHandoffAdapter {
             handoff = handoff.with_reason(r);
         }
 
-        let new_handoff = handoff_to_new_row(&handoff, params.conversation_id, tenant_id)?;
+        let conversation_id = params.conversation_id;
+        let new_handoff = handoff_to_new_row(&handoff, conversation_id, tenant_id)?;
 
+        let ctx = ctx.clone();
         self.execute_query_with_bootstrap(tenant_id, move |conn| {
+            ensure_conversation_open(conn, &ctx, conversation_id)?;
             diesel::insert_into(handoffs::table)
                 .values(&new_handoff)
                 .execute(conn)
@@ -181,11 +193,13 @@ impl AgentHandoffPort for PostgresHandoffAdapter {
     ) -> HandoffResult<HandoffMetadata> {
         let tenant_id = ctx.tenant_id();
         let clock = DefaultClock;
+        let ctx = ctx.clone();
 
         self.execute_query(tenant_id, move |conn| {
             // Lock the row for the duration of the transaction to
             // prevent concurrent state transitions from interleaving.
             let row = lock_handoff_row(conn, handoff_id, tenant_id)?;
+            ensure_conversation_open(conn, &ctx, ConversationId::from_uuid(row.conversation_id))?;
 
             let mut handoff = row_to_handoff(row)?;
 
@@ -258,6 +272,42 @@ impl AgentHandoffPort for PostgresHandoffAdapter {
         .await
     }
 
+    async fn reject_handoff(
+        &self,
+        ctx: &RequestContext,
+        handoff_id: HandoffId,
+        reason: &str,
+    ) -> HandoffResult<HandoffMetadata> {
+        let tenant_id = ctx.tenant_id();
+        let owned_reason = reason.to_owned();
+
+        self.execute_query(tenant_id, move |conn| {
+            let handoff = row_to_handoff(lock_handoff_row(conn, handoff_id, tenant_id)?)?;
+            if handoff.is_terminal() {
+                return Err(HandoffError::invalid_transition(
+                    handoff.status,
+                    HandoffStatus::Failed,
+                ));
+            }
+            let rejected = handoff.reject(&owned_reason);
+
+            diesel::update(
+                handoffs::table
+                    .filter(handoffs::id.eq(handoff_id.into_inner()))
+                    .filter(handoffs::tenant_id.eq(tenant_id.into_inner())),
+            )
+            .set((
+                handoffs::status.eq(HandoffStatus::Failed.as_str()),
+                handoffs::reason.eq(&rejected.reason),
+            ))
+            .execute(conn)
+            .map_err(HandoffError::persistence)?;
+
+            Ok(rejected)
+        })
+        .await
+    }
+
     async fn find_handoff(
         &self,
         ctx: &RequestContext,
@@ -284,75 +334,44 @@ impl AgentHandoffPort for PostgresHandoffAdapter {
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> HandoffResult<Vec<HandoffMetadata>> {
+        page: PageRequest,
+    ) -> HandoffResult<Page<HandoffMetadata>> {
         let tenant_id = ctx.tenant_id();
         let uuid = conversation_id.into_inner();
 
         self.execute_read_query(tenant_id, move |conn| {
-            let rows = handoffs::table
+            let query = handoffs::table
                 .filter(handoffs::tenant_id.eq(tenant_id.into_inner()))
                 .filter(handoffs::conversation_id.eq(uuid))
                 .select(HandoffRow::as_select())
-                .order(handoffs::initiated_at.asc())
-                .load::<HandoffRow>(conn)
-                .map_err(HandoffError::persistence)?;
+                .into_boxed();
+            let rows = keyset_page!(
+                query,
+                page,
+                after_timestamp,
+                (handoffs::initiated_at, handoffs::id)
+            )
+            .load::<HandoffRow>(conn)
+            .map_err(HandoffError::persistence)?;
 
-            rows.into_iter().map(row_to_handoff).collect()
+            Page::from_overfetched(rows, page, |row| {
+                Cursor::at_timestamp(row.initiated_at, row.id)
+            })
+            .try_map(row_to_handoff)
         })
         .await
     }
 }
 
-// ---------------------------------------------------------------------------
-// Conversion helpers
-// ---------------------------------------------------------------------------
-
-/// Converts a domain `HandoffMetadata` to a `NewHandoff` for insertion.
-fn handoff_to_new_row(
-    handoff: &HandoffMetadata,
+fn ensure_conversation_open(
+    conn: &mut PgConnection,
+    ctx: &RequestContext,
     conversation_id: ConversationId,
-    tenant_id: TenantId,
-) -> HandoffResult<NewHandoff> {
-    let triggering_tool_calls =
-        serde_json::to_value(&handoff.triggering_tool_calls).map_err(HandoffError::persistence)?;
-
-    Ok(NewHandoff {
-        id: handoff.handoff_id.into_inner(),
-        tenant_id: tenant_id.into_inner(),
-        source_session_id: handoff.source_session_id.into_inner(),
-        conversation_id: conversation_id.into_inner(),
-        target_session_id: handoff.target_session_id.map(AgentSessionId::into_inner),
-        prior_turn_id: handoff.prior_turn_id.into_inner(),
-        triggering_tool_calls,
-        source_agent: handoff.source_agent.clone(),
-        target_agent: handoff.target_agent.clone(),
-        reason: handoff.reason.clone(),
-        initiated_at: handoff.initiated_at,
-        completed_at: handoff.completed_at,
-        status: handoff.status.as_str().to_owned(),
-    })
-}
-
-/// Converts a database row to a domain `HandoffMetadata`.
-fn row_to_handoff(row: HandoffRow) -> HandoffResult<HandoffMetadata> {
-    let triggering_tool_calls: Vec<ToolCallReference> =
-        serde_json::from_value(row.triggering_tool_calls).map_err(HandoffError::persistence)?;
-
-    let status = HandoffStatus::try_from(row.status.as_str()).map_err(HandoffError::persistence)?;
-
-    Ok(HandoffMetadata {
-        handoff_id: HandoffId::from_uuid(row.id),
-        source_session_id: AgentSessionId::from_uuid(row.source_session_id),
-        target_session_id: row.target_session_id.map(AgentSessionId::from_uuid),
-        prior_turn_id: TurnId::from_uuid(row.prior_turn_id),
-        triggering_tool_calls,
-        source_agent: row.source_agent,
-        target_agent: row.target_agent,
-        reason: row.reason,
-        initiated_at: row.initiated_at,
-        completed_at: row.completed_at,
-        status,
-    })
+) -> HandoffResult<()> {
+    if conversation_is_archived(conn, ctx, conversation_id).map_err(HandoffError::persistence)? {
+        return Err(HandoffError::ConversationArchived(conversation_id));
+    }
+    Ok(())
 }
 
 fn lock_handoff_row(
@@ -0,0 +1,136 @@
+//! `PostgreSQL` implementation of the `HandoffContextRepository` port.
+//!
+//! Each target session holds one row, upserted on its
+//! `(tenant_id, target_session_id)` primary key. The package is stored as
+//! JSONB, alongside its conversation so transfers move it with the
+//! conversation.
+
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::models::HandoffContextPackageRow,
+    adapters::schema::handoff_context_packages,
+    domain::{AgentSessionId, HandoffContextPackage},
+    ports::handoff_context::{HandoffContextError, HandoffContextRepository, HandoffContextResult},
+};
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+
+impl FromTxError<Self> for HandoffContextError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL` implementation of [`HandoffContextRepository`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access.
+#[derive(Debug, Clone)]
+pub struct PostgresHandoffContextRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresHandoffContextRepository,
+    "handoff_context_repository"
+);
+
+impl PostgresHandoffContextRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HandoffContextRepository for PostgresHandoffContextRepository {
+    async fn store(
+        &self,
+        ctx: &RequestContext,
+        package: &HandoffContextPackage,
+    ) -> HandoffContextResult<()> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = HandoffContextPackageRow {
+            tenant_id: tenant_uuid,
+            target_session_id: package.target_session_id.into_inner(),
+            handoff_id: package.handoff_id.into_inner(),
+            package: serde_json::to_value(package).map_err(HandoffContextError::persistence)?,
+            prepared_at: package.prepared_at,
+            conversation_id: package.conversation_id.into_inner(),
+        };
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, HandoffContextError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid)
+                        .map_err(HandoffContextError::persistence)?;
+                    upsert_row(tx, &row).map_err(HandoffContextError::persistence)
+                })
+            },
+            HandoffContextError::persistence,
+        )
+        .await
+    }
+
+    async fn find_for_session(
+        &self,
+        ctx: &RequestContext,
+        target_session_id: AgentSessionId,
+    ) -> HandoffContextResult<Option<HandoffContextPackage>> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let session_uuid = target_session_id.into_inner();
+        let row = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, HandoffContextError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    handoff_context_packages::table
+                        .filter(handoff_context_packages::tenant_id.eq(tenant_uuid))
+                        .filter(handoff_context_packages::target_session_id.eq(session_uuid))
+                        .select(HandoffContextPackageRow::as_select())
+                        .first(tx)
+                        .optional()
+                        .map_err(HandoffContextError::persistence)
+                })
+            },
+            HandoffContextError::persistence,
+        )
+        .await?;
+        row.map(|stored| {
+            serde_json::from_value(stored.package)
+                .map_err(HandoffContextError::invalid_persisted_data)
+        })
+        .transpose()
+    }
+}
+
+fn upsert_row(conn: &mut PgConnection, row: &HandoffContextPackageRow) -> QueryResult<()> {
+    diesel::insert_into(handoff_context_packages::table)
+        .values(row)
+        .on_conflict((
+            handoff_context_packages::tenant_id,
+            handoff_context_packages::target_session_id,
+        ))
+        .do_update()
+        .set((
+            handoff_context_packages::handoff_id.eq(excluded(handoff_context_packages::handoff_id)),
+            handoff_context_packages::package.eq(excluded(handoff_context_packages::package)),
+            handoff_context_packages::prepared_at
+                .eq(excluded(handoff_context_packages::prepared_at)),
+        ))
+        .execute(conn)
+        .map(|_| ())
+}
@@ -0,0 +1,38 @@
+//! Keyset pagination for boxed Diesel queries.
+
+/// Orders a boxed query by `(sort, id)` in the page's direction, restricts
+/// it to rows after the page cursor and fetches one row more than the page
+/// holds.
+///
+/// `$seek` names the [`PageRequest`] accessor matching the sort column:
+/// `after_timestamp` or `after_number`. A cursor issued by a listing with a
+/// different kind of sort value matches no rows.
+///
+/// [`PageRequest`]: crate::pagination::PageRequest
+macro_rules! keyset_page {
+    ($query:expr, $page:expr, $seek:ident, ($sort:expr, $id:expr)) => {{
+        use diesel::prelude::{BoolExpressionMethods, ExpressionMethods, QueryDsl};
+        use $crate::pagination::{Seek, SortOrder};
+        let request: $crate::pagination::PageRequest = $page;
+        let boxed = $query;
+        let seeked = match (request.$seek(), request.order()) {
+            (Seek::Start, _) => boxed,
+            (Seek::After(value, last_id), SortOrder::Ascending) => {
+                boxed.filter($sort.gt(value).or($sort.eq(value).and($id.gt(last_id))))
+            }
+            (Seek::After(value, last_id), SortOrder::Descending) => {
+                boxed.filter($sort.lt(value).or($sort.eq(value).and($id.lt(last_id))))
+            }
+            (Seek::Foreign, _) => {
+                boxed.filter(diesel::dsl::sql::<diesel::sql_types::Bool>("FALSE"))
+            }
+        };
+        match request.order() {
+            SortOrder::Ascending => seeked.order_by(($sort.asc(), $id.asc())),
+            SortOrder::Descending => seeked.order_by(($sort.desc(), $id.desc())),
+        }
+        .limit(request.sql_fetch_limit())
+    }};
+}
+
+pub(crate) use keyset_page;
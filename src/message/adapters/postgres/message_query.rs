@@ -0,0 +1,67 @@
+//! SQL translation of [`MessageQuery`] shared by the blocking and async
+//! message repositories.
+
+use diesel::dsl::sql;
+use diesel::pg::Pg;
+use diesel::prelude::*;
+use diesel::sql_types::{Array, Bool, Text};
+
+use super::super::schema::messages;
+use crate::message::domain::{MessageQuery, Role};
+
+/// Builds the unordered, unpaged selection of a conversation's messages
+/// matching `query`.
+///
+/// Content-part kinds are matched against each part's serialised `type`
+/// tag. Metadata keys match top-level fields, extensions, and legacy
+/// flattened extension keys alike. Sealed rows are matched on the part
+/// summaries and key names kept beside their envelopes.
+pub(super) fn filtered_messages(
+    tenant_uuid: uuid::Uuid,
+    conversation_uuid: uuid::Uuid,
+    query: &MessageQuery,
+) -> messages::BoxedQuery<'static, Pg> {
+    let mut boxed = messages::table
+        .filter(messages::tenant_id.eq(tenant_uuid))
+        .filter(messages::conversation_id.eq(conversation_uuid))
+        .into_boxed::<Pg>();
+    if !query.roles().is_empty() {
+        let roles: Vec<&'static str> = query.roles().iter().map(Role::as_str).collect();
+        boxed = boxed.filter(messages::role.eq_any(roles));
+    }
+    if let Some(from) = query.created_from() {
+        boxed = boxed.filter(messages::created_at.ge(from));
+    }
+    if let Some(until) = query.created_until() {
+        boxed = boxed.filter(messages::created_at.lt(until));
+    }
+    if !query.content_kinds().is_empty() {
+        let kinds: Vec<String> = query
+            .content_kinds()
+            .iter()
+            .map(|kind| kind.as_str().to_owned())
+            .collect();
+        boxed = boxed.filter(
+            sql::<Bool>(
+                "EXISTS (SELECT 1 FROM jsonb_array_elements(CASE \
+                 WHEN jsonb_typeof(messages.content) = 'array' THEN messages.content \
+                 ELSE COALESCE(messages.content -> '$parts', '[]'::jsonb) END) AS part \
+                 WHERE part->>'type' = ANY(",
+            )
+            .bind::<Array<Text>, _>(kinds)
+            .sql("))"),
+        );
+    }
+    for key in query.metadata_keys() {
+        boxed = boxed.filter(
+            sql::<Bool>("(messages.metadata ? ")
+                .bind::<Text, _>(key.clone())
+                .sql(" OR messages.metadata -> 'extensions' ? ")
+                .bind::<Text, _>(key.clone())
+                .sql(" OR messages.metadata -> '$keys' ? ")
+                .bind::<Text, _>(key.clone())
+                .sql(")"),
+        );
+    }
+    boxed
+}
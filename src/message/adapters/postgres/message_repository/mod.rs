@@ -0,0 +1,221 @@
+//! The blocking `PostgreSQL` message repository.
+
+mod port;
+
+use std::sync::Arc;
+
+use diesel::pg::PgConnection;
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::replica::{ReadReplica, read_connection};
+use super::sealing::MessageCodec;
+use super::sql_helpers::{InsertIds, insert_message, set_audit_context};
+use super::tenant_tx::{ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx};
+use crate::context::{RequestContext, TenantId};
+use crate::message::adapters::audit_context::AuditContext;
+use crate::message::adapters::models::NewMessage;
+use crate::message::{
+    domain::Message,
+    error::RepositoryError,
+    ports::{content_cipher::ContentCipher, repository::RepositoryResult},
+};
+
+/// `PostgreSQL` implementation of
+/// [`MessageRepository`](crate::message::ports::repository::MessageRepository).
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access. All database operations are offloaded to a blocking
+/// thread pool via [`tokio::task::spawn_blocking`] to avoid blocking
+/// the async runtime.
+///
+/// # Example
+///
+/// ```ignore
+/// use diesel::r2d2::{ConnectionManager, Pool};
+/// use diesel::PgConnection;
+/// use corbusier::message::adapters::postgres::PostgresMessageRepository;
+///
+/// let manager = ConnectionManager::<PgConnection>::new("postgres://...");
+/// let pool = Pool::builder().build(manager).expect("pool");
+/// let repo = PostgresMessageRepository::new(pool);
+/// ```
+///
+/// # Encryption
+///
+/// With [`PostgresMessageRepository::with_cipher`], message content and
+/// metadata are sealed before they are written and opened as they are read.
+/// Queries that look inside those columns in SQL, such as content part
+/// filters, token usage totals, and activity buckets, see only the sealed
+/// envelope and so do not match encrypted rows.
+///
+/// # Read replicas
+///
+/// With [`PostgresMessageRepository::with_read_replica`], conversation
+/// history, message queries and token usage totals read from the replica
+/// while it keeps within its staleness tolerance. Lookups by id, existence
+/// checks and sequence allocation stay on the primary so a caller always
+/// sees its own writes.
+#[derive(Debug, Clone)]
+pub struct PostgresMessageRepository {
+    pool: PgPool,
+    codec: MessageCodec,
+    replica: Option<ReadReplica>,
+}
+
+crate::postgres_support::pool_health_check!(PostgresMessageRepository, "message_repository");
+
+impl PostgresMessageRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            codec: MessageCodec::plain(),
+            replica: None,
+        }
+    }
+
+    /// Encrypts message content and metadata with `cipher`.
+    ///
+    /// Rows stored without a cipher remain readable, so encryption can be
+    /// enabled on an existing database.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Arc<dyn ContentCipher>) -> Self {
+        self.codec = MessageCodec::sealed(cipher);
+        self
+    }
+
+    /// Sends listing queries to `replica`, falling back to the primary pool
+    /// when the replica is exhausted or stale.
+    #[must_use]
+    pub fn with_read_replica(mut self, replica: ReadReplica) -> Self {
+        self.replica = Some(replica);
+        self
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub const fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// Converts a message to the row stored for it.
+    fn new_row(&self, message: &Message, tenant_id: TenantId) -> RepositoryResult<NewMessage> {
+        self.codec.seal(NewMessage::try_from_domain(
+            message,
+            tenant_id.into_inner(),
+        )?)
+    }
+
+    async fn execute_inner<TxWrap, F, T>(
+        &self,
+        tenant_id: TenantId,
+        tx_wrap: TxWrap,
+        query_fn: F,
+    ) -> RepositoryResult<T>
+    where
+        TxWrap: FnOnce(&mut PgConnection, uuid::Uuid, F) -> RepositoryResult<T> + Send + 'static,
+        F: FnOnce(&mut PgConnection) -> RepositoryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, RepositoryError::database)?;
+                tx_wrap(&mut conn, tenant_id.into_inner(), query_fn)
+            },
+            RepositoryError::database,
+        )
+        .await
+    }
+
+    /// Executes a query inside a transaction with tenant context.
+    async fn execute_query<F, T>(&self, tenant_id: TenantId, query_fn: F) -> RepositoryResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> RepositoryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let bootstrapping_tx = |conn: &mut PgConnection, tenant_uuid: uuid::Uuid, qfn: F| {
+            with_tenant_tx(conn, tenant_uuid, |tx| {
+                ensure_tenant_exists(tx, tenant_uuid).map_err(RepositoryError::database)?;
+                qfn(tx)
+            })
+        };
+        self.execute_inner(tenant_id, bootstrapping_tx, query_fn)
+            .await
+    }
+
+    /// Executes a read-only query inside a transaction with tenant context.
+    async fn execute_read_query<F, T>(
+        &self,
+        tenant_id: TenantId,
+        query_fn: F,
+    ) -> RepositoryResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> RepositoryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.execute_inner(tenant_id, with_tenant_read_tx, query_fn)
+            .await
+    }
+
+    /// Executes a read-only query on the replica when one is configured and
+    /// fresh enough, and on the primary otherwise.
+    async fn execute_replica_read_query<F, T>(
+        &self,
+        tenant_id: TenantId,
+        query_fn: F,
+    ) -> RepositoryResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> RepositoryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let replica = self.replica.clone();
+        run_blocking_with(
+            move || {
+                let mut conn =
+                    read_connection(&pool, replica.as_ref()).map_err(RepositoryError::database)?;
+                with_tenant_read_tx(&mut conn, tenant_id.into_inner(), query_fn)
+            },
+            RepositoryError::database,
+        )
+        .await
+    }
+
+    /// Stores a message with audit context for tracking.
+    ///
+    /// This method wraps the store operation in a transaction that also:
+    /// 1. Sets `PostgreSQL` session variables for audit trigger capture
+    /// 2. Records a `MessageCreated` domain event
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError` if the database operation fails.
+    pub async fn store_with_audit(
+        &self,
+        ctx: &RequestContext,
+        message: &Message,
+    ) -> RepositoryResult<()> {
+        let audit_ctx = AuditContext::from(ctx);
+        let tenant_id = ctx.tenant_id();
+        let new_message = self.new_row(message, tenant_id)?;
+        let msg_id = message.id();
+        let conv_id = message.conversation_id();
+        let seq_num = message.sequence_number();
+
+        let ids = InsertIds {
+            msg_id,
+            conv_id,
+            seq_num,
+        };
+
+        let ctx = ctx.clone();
+        self.execute_query(tenant_id, move |tx_conn| {
+            set_audit_context(tx_conn, &audit_ctx)?;
+            insert_message(tx_conn, &ctx, &new_message, &ids)?;
+            Ok(())
+        })
+        .await
+    }
+}
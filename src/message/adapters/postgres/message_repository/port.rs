@@ -0,0 +1,309 @@
+//! [`MessageRepository`] for [`PostgresMessageRepository`].
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+
+use super::PostgresMessageRepository;
+use crate::context::RequestContext;
+use crate::message::adapters::audit_context::AuditContext;
+use crate::message::adapters::batch::BatchKey;
+use crate::message::adapters::models::MessageRow;
+use crate::message::adapters::postgres::blob_references::{load_blobs, unreferenced_blobs_query};
+use crate::message::adapters::postgres::conversion_helpers::ser_err;
+use crate::message::adapters::postgres::message_query::filtered_messages;
+use crate::message::adapters::postgres::pinning::pin_message;
+use crate::message::adapters::postgres::redaction::redact_message;
+use crate::message::adapters::postgres::sealing::RowScope;
+use crate::message::adapters::postgres::sql_helpers::{
+    InsertIds, append_message, insert_message, insert_message_batch, set_audit_context,
+};
+use crate::message::adapters::postgres::token_usage::{row_to_token_usage, token_usage_query};
+use crate::message::adapters::schema::{conversations, messages};
+use crate::message::{
+    domain::{
+        ContentHash, ConversationId, ConversationTokenUsage, Message, MessageId, MessageQuery,
+        MessageRedaction, SequenceNumber,
+    },
+    error::RepositoryError,
+    ports::repository::{MessageRepository, RepositoryResult},
+};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
+
+#[async_trait]
+impl MessageRepository for PostgresMessageRepository {
+    async fn store(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<()> {
+        let tenant_id = ctx.tenant_id();
+        let new_message = self.new_row(message, tenant_id)?;
+        let msg_id = message.id();
+        let conv_id = message.conversation_id();
+        let seq_num = message.sequence_number();
+
+        let ctx = ctx.clone();
+        self.execute_query(tenant_id, move |conn| {
+            let ids = InsertIds {
+                msg_id,
+                conv_id,
+                seq_num,
+            };
+            insert_message(conn, &ctx, &new_message, &ids)
+        })
+        .await
+    }
+
+    async fn append(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<Message> {
+        let tenant_id = ctx.tenant_id();
+        let new_message = self.new_row(message, tenant_id)?;
+        let msg_id = message.id();
+
+        let ctx = ctx.clone();
+        let sequence = self
+            .execute_query(tenant_id, move |conn| {
+                append_message(conn, &ctx, new_message, msg_id)
+            })
+            .await?;
+        Ok(message.clone().with_sequence_number(sequence))
+    }
+
+    async fn store_batch(
+        &self,
+        ctx: &RequestContext,
+        messages: &[Message],
+    ) -> RepositoryResult<()> {
+        if messages.is_empty() {
+            return Ok(());
+        }
+        let tenant_id = ctx.tenant_id();
+        let rows = messages
+            .iter()
+            .map(|message| self.new_row(message, tenant_id))
+            .collect::<RepositoryResult<Vec<_>>>()?;
+        let keys: Vec<BatchKey> = messages.iter().map(BatchKey::from).collect();
+
+        let ctx = ctx.clone();
+        self.execute_query(tenant_id, move |conn| {
+            insert_message_batch(conn, &ctx, &keys, &rows)
+        })
+        .await
+    }
+
+    async fn redact(
+        &self,
+        ctx: &RequestContext,
+        redaction: &MessageRedaction,
+    ) -> RepositoryResult<Message> {
+        let audit_ctx = AuditContext::from(ctx);
+        let tenant_id = ctx.tenant_id();
+        let correlation_id = ctx.correlation_id().into_inner();
+        let redaction = redaction.clone();
+        let codec = self.codec.clone();
+
+        self.execute_query(tenant_id, move |conn| {
+            set_audit_context(conn, &audit_ctx)?;
+            let scope = RowScope {
+                tenant_id: tenant_id.into_inner(),
+                codec: &codec,
+            };
+            redact_message(conn, scope, correlation_id, &redaction)
+        })
+        .await
+    }
+
+    async fn set_pinned(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+        pinned: bool,
+    ) -> RepositoryResult<Message> {
+        let audit_ctx = AuditContext::from(ctx);
+        let tenant_id = ctx.tenant_id();
+        let codec = self.codec.clone();
+
+        self.execute_query(tenant_id, move |conn| {
+            set_audit_context(conn, &audit_ctx)?;
+            let scope = RowScope {
+                tenant_id: tenant_id.into_inner(),
+                codec: &codec,
+            };
+            pin_message(conn, scope, id, pinned)
+        })
+        .await
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+    ) -> RepositoryResult<Option<Message>> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = id.into_inner();
+        let codec = self.codec.clone();
+
+        self.execute_read_query(tenant_id, move |conn| {
+            messages::table
+                .filter(messages::id.eq(uuid))
+                .filter(messages::tenant_id.eq(tenant_id.into_inner()))
+                .select(MessageRow::as_select())
+                .first::<MessageRow>(conn)
+                .optional()
+                .map_err(RepositoryError::database)?
+                .map(|row| codec.to_message(row))
+                .transpose()
+        })
+        .await
+    }
+
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> RepositoryResult<Page<Message>> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = conversation_id.into_inner();
+        let codec = self.codec.clone();
+
+        self.execute_replica_read_query(tenant_id, move |conn| {
+            let query = messages::table
+                .filter(messages::tenant_id.eq(tenant_id.into_inner()))
+                .filter(messages::conversation_id.eq(uuid))
+                .select(MessageRow::as_select())
+                .into_boxed();
+            let rows = keyset_page!(
+                query,
+                page,
+                after_number,
+                (messages::sequence_number, messages::id)
+            )
+            .load::<MessageRow>(conn)
+            .map_err(RepositoryError::database)?;
+
+            Page::from_overfetched(rows, page, |row| {
+                Cursor::at_number(row.sequence_number, row.id)
+            })
+            .try_map(|row| codec.to_message(row))
+        })
+        .await
+    }
+
+    async fn query(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        query: &MessageQuery,
+    ) -> RepositoryResult<Page<Message>> {
+        let tenant_id = ctx.tenant_id();
+        let filtered =
+            filtered_messages(tenant_id.into_inner(), conversation_id.into_inner(), query);
+        let page = query.page();
+        let codec = self.codec.clone();
+
+        self.execute_replica_read_query(tenant_id, move |conn| {
+            let rows = keyset_page!(
+                filtered,
+                page,
+                after_number,
+                (messages::sequence_number, messages::id)
+            )
+            .select(MessageRow::as_select())
+            .load::<MessageRow>(conn)
+            .map_err(RepositoryError::database)?;
+
+            Page::from_overfetched(rows, page, |row| {
+                Cursor::at_number(row.sequence_number, row.id)
+            })
+            .try_map(|row| codec.to_message(row))
+        })
+        .await
+    }
+
+    async fn next_sequence_number(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<SequenceNumber> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = conversation_id.into_inner();
+
+        self.execute_read_query(tenant_id, move |conn| {
+            let conversation_exists = conversations::table
+                .filter(conversations::id.eq(uuid))
+                .filter(conversations::tenant_id.eq(tenant_id.into_inner()))
+                .select(conversations::id)
+                .first::<uuid::Uuid>(conn)
+                .optional()
+                .map_err(RepositoryError::database)?;
+
+            if conversation_exists.is_none() {
+                return Err(RepositoryError::ConversationNotFound(
+                    ConversationId::from_uuid(uuid),
+                ));
+            }
+
+            let max_seq: Option<i64> = messages::table
+                .filter(messages::tenant_id.eq(tenant_id.into_inner()))
+                .filter(messages::conversation_id.eq(uuid))
+                .select(diesel::dsl::max(messages::sequence_number))
+                .first(conn)
+                .map_err(RepositoryError::database)?;
+
+            let current = max_seq.unwrap_or(0);
+            let next = current.checked_add(1).ok_or_else(|| {
+                RepositoryError::serialization("sequence number overflow: maximum i64 reached")
+            })?;
+            let next_u64 = u64::try_from(next).map_err(ser_err)?;
+
+            Ok(SequenceNumber::try_new(next_u64)?)
+        })
+        .await
+    }
+
+    async fn token_usage(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<ConversationTokenUsage> {
+        let tenant_id = ctx.tenant_id();
+        let query = token_usage_query(tenant_id.into_inner(), conversation_id.into_inner());
+
+        self.execute_replica_read_query(tenant_id, move |conn| {
+            let row = query
+                .first::<(i64, i64, i64)>(conn)
+                .map_err(RepositoryError::database)?;
+            row_to_token_usage(row)
+        })
+        .await
+    }
+
+    async fn exists(&self, ctx: &RequestContext, id: MessageId) -> RepositoryResult<bool> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = id.into_inner();
+
+        self.execute_read_query(tenant_id, move |conn| {
+            let count: i64 = messages::table
+                .filter(messages::id.eq(uuid))
+                .filter(messages::tenant_id.eq(tenant_id.into_inner()))
+                .count()
+                .get_result(conn)
+                .map_err(RepositoryError::database)?;
+
+            Ok(count > 0)
+        })
+        .await
+    }
+
+    async fn unreferenced_blobs(
+        &self,
+        ctx: &RequestContext,
+        hashes: &[ContentHash],
+    ) -> RepositoryResult<Vec<ContentHash>> {
+        let tenant_id = ctx.tenant_id();
+        let hashes = hashes.to_vec();
+
+        self.execute_read_query(tenant_id, move |conn| {
+            let query = unreferenced_blobs_query(tenant_id.into_inner(), &hashes);
+            load_blobs(conn, query).map_err(RepositoryError::database)
+        })
+        .await
+    }
+}
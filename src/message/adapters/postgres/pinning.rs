@@ -0,0 +1,51 @@
+//! Message pinning for the `PostgreSQL` repositories.
+//!
+//! The pinned flag is written with a JSONB operator rather than by rewriting
+//! the metadata document, so a pin cannot undo a concurrent redaction's
+//! scrubbed metadata. Unpinning removes the key, matching the serialised
+//! form, which omits the flag when it is unset. Encrypted metadata keeps
+//! the flag outside its sealed envelope, so the same operators apply.
+
+use diesel::dsl::sql;
+use diesel::expression::SqlLiteral;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::sql_types::Jsonb;
+
+use super::super::models::MessageRow;
+use super::super::schema::messages;
+use super::sealing::RowScope;
+use crate::message::{
+    domain::{Message, MessageId},
+    error::RepositoryError,
+    ports::repository::RepositoryResult,
+};
+
+/// Returns the message metadata with its pinned flag set or removed.
+pub(super) fn pinned_metadata(pinned: bool) -> SqlLiteral<Jsonb> {
+    if pinned {
+        return sql::<Jsonb>("messages.metadata || '{\"pinned\": true}'::jsonb");
+    }
+    sql::<Jsonb>("messages.metadata - 'pinned'")
+}
+
+/// Pins or unpins a message inside the caller's transaction.
+pub(super) fn pin_message(
+    conn: &mut PgConnection,
+    scope: RowScope<'_>,
+    id: MessageId,
+    pinned: bool,
+) -> RepositoryResult<Message> {
+    diesel::update(
+        messages::table
+            .filter(messages::id.eq(id.into_inner()))
+            .filter(messages::tenant_id.eq(scope.tenant_id)),
+    )
+    .set(messages::metadata.eq(pinned_metadata(pinned)))
+    .returning(MessageRow::as_returning())
+    .get_result::<MessageRow>(conn)
+    .optional()
+    .map_err(RepositoryError::database)?
+    .ok_or(RepositoryError::NotFound(id))
+    .and_then(|row| scope.codec.to_message(row))
+}
@@ -0,0 +1,239 @@
+//! `PostgreSQL` implementation of the `MessageProcessingRepository` port.
+//!
+//! Statuses are upserted on the `(tenant_id, message_id, stage)` primary key.
+//! Restarting a stage deletes the later stages' rows and upserts the stage in
+//! one transaction, so readers never see a restarted stage next to stale
+//! results from the stages after it.
+
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::models::MessageProcessingStageRow,
+    adapters::schema::message_processing_stages,
+    domain::{
+        ConversationId, MessageId, ProcessingStage, StageState, StageStatus, StuckMessagesQuery,
+    },
+    ports::processing::{MessageProcessingRepository, ProcessingError, ProcessingResult},
+};
+
+use crate::pagination::Page;
+use crate::postgres_support::keyset_page;
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+
+impl FromTxError<Self> for ProcessingError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL` implementation of [`MessageProcessingRepository`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access.
+#[derive(Debug, Clone)]
+pub struct PostgresMessageProcessingRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresMessageProcessingRepository,
+    "message_processing_repository"
+);
+
+impl PostgresMessageProcessingRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn write<F>(&self, ctx: &RequestContext, write_fn: F) -> ProcessingResult<()>
+    where
+        F: FnOnce(&mut PgConnection, uuid::Uuid) -> QueryResult<()> + Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ProcessingError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid).map_err(ProcessingError::persistence)?;
+                    write_fn(tx, tenant_uuid).map_err(ProcessingError::persistence)
+                })
+            },
+            ProcessingError::persistence,
+        )
+        .await
+    }
+
+    async fn read_rows<F>(
+        &self,
+        ctx: &RequestContext,
+        query_fn: F,
+    ) -> ProcessingResult<Vec<StageStatus>>
+    where
+        F: FnOnce(&mut PgConnection, uuid::Uuid) -> QueryResult<Vec<MessageProcessingStageRow>>
+            + Send
+            + 'static,
+    {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let rows = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ProcessingError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    query_fn(tx, tenant_uuid).map_err(ProcessingError::persistence)
+                })
+            },
+            ProcessingError::persistence,
+        )
+        .await?;
+        rows.into_iter().map(row_to_status).collect()
+    }
+}
+
+#[async_trait]
+impl MessageProcessingRepository for PostgresMessageProcessingRepository {
+    async fn upsert(&self, ctx: &RequestContext, status: &StageStatus) -> ProcessingResult<()> {
+        let row = to_row(status, ctx.tenant_id().into_inner());
+        self.write(ctx, move |conn, _| upsert_row(conn, &row)).await
+    }
+
+    async fn restart_from(
+        &self,
+        ctx: &RequestContext,
+        status: &StageStatus,
+    ) -> ProcessingResult<()> {
+        let row = to_row(status, ctx.tenant_id().into_inner());
+        let later: Vec<&'static str> = status
+            .stage
+            .later_stages()
+            .map(ProcessingStage::as_str)
+            .collect();
+        self.write(ctx, move |conn, tenant_uuid| {
+            diesel::delete(
+                message_processing_stages::table
+                    .filter(message_processing_stages::tenant_id.eq(tenant_uuid))
+                    .filter(message_processing_stages::message_id.eq(row.message_id))
+                    .filter(message_processing_stages::stage.eq_any(later)),
+            )
+            .execute(conn)?;
+            upsert_row(conn, &row)
+        })
+        .await
+    }
+
+    async fn find_for_message(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> ProcessingResult<Vec<StageStatus>> {
+        let message_uuid = message_id.into_inner();
+        let mut statuses = self
+            .read_rows(ctx, move |conn, tenant_uuid| {
+                message_processing_stages::table
+                    .filter(message_processing_stages::tenant_id.eq(tenant_uuid))
+                    .filter(message_processing_stages::message_id.eq(message_uuid))
+                    .select(MessageProcessingStageRow::as_select())
+                    .load(conn)
+            })
+            .await?;
+        statuses.sort_by_key(|status| status.stage);
+        Ok(statuses)
+    }
+
+    async fn find_stuck(
+        &self,
+        ctx: &RequestContext,
+        query: &StuckMessagesQuery,
+    ) -> ProcessingResult<Page<StageStatus>> {
+        let stage = query.stage.as_str();
+        let updated_before = query.updated_before;
+        let page = query.page;
+        let statuses = self
+            .read_rows(ctx, move |conn, tenant_uuid| {
+                let stuck = message_processing_stages::table
+                    .filter(message_processing_stages::tenant_id.eq(tenant_uuid))
+                    .filter(message_processing_stages::stage.eq(stage))
+                    .filter(message_processing_stages::state.ne(StageState::Succeeded.as_str()))
+                    .filter(message_processing_stages::updated_at.le(updated_before))
+                    .select(MessageProcessingStageRow::as_select())
+                    .into_boxed();
+                keyset_page!(
+                    stuck,
+                    page,
+                    after_timestamp,
+                    (
+                        message_processing_stages::updated_at,
+                        message_processing_stages::message_id
+                    )
+                )
+                .load(conn)
+            })
+            .await?;
+        Ok(Page::from_overfetched(
+            statuses,
+            page,
+            StuckMessagesQuery::position,
+        ))
+    }
+}
+
+fn upsert_row(conn: &mut PgConnection, row: &MessageProcessingStageRow) -> QueryResult<()> {
+    diesel::insert_into(message_processing_stages::table)
+        .values(row)
+        .on_conflict((
+            message_processing_stages::tenant_id,
+            message_processing_stages::message_id,
+            message_processing_stages::stage,
+        ))
+        .do_update()
+        .set((
+            message_processing_stages::state.eq(excluded(message_processing_stages::state)),
+            message_processing_stages::attempts.eq(excluded(message_processing_stages::attempts)),
+            message_processing_stages::last_error
+                .eq(excluded(message_processing_stages::last_error)),
+            message_processing_stages::updated_at
+                .eq(excluded(message_processing_stages::updated_at)),
+        ))
+        .execute(conn)
+        .map(|_| ())
+}
+
+fn to_row(status: &StageStatus, tenant_id: uuid::Uuid) -> MessageProcessingStageRow {
+    MessageProcessingStageRow {
+        tenant_id,
+        message_id: status.message_id.into_inner(),
+        conversation_id: status.conversation_id.into_inner(),
+        stage: status.stage.as_str().to_owned(),
+        state: status.state.as_str().to_owned(),
+        attempts: i32::try_from(status.attempts).unwrap_or(i32::MAX),
+        last_error: status.last_error.clone(),
+        updated_at: status.updated_at,
+    }
+}
+
+fn row_to_status(row: MessageProcessingStageRow) -> ProcessingResult<StageStatus> {
+    Ok(StageStatus {
+        message_id: MessageId::from_uuid(row.message_id),
+        conversation_id: ConversationId::from_uuid(row.conversation_id),
+        stage: ProcessingStage::try_from(row.stage.as_str())
+            .map_err(ProcessingError::invalid_persisted_data)?,
+        state: StageState::try_from(row.state.as_str())
+            .map_err(ProcessingError::invalid_persisted_data)?,
+        attempts: u32::try_from(row.attempts).map_err(ProcessingError::invalid_persisted_data)?,
+        last_error: row.last_error,
+        updated_at: row.updated_at,
+    })
+}
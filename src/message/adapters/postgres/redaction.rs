@@ -0,0 +1,108 @@
+//! Message redaction for the `PostgreSQL` repositories.
+//!
+//! A redaction rewrites the message row's content and metadata in place and
+//! inserts a `message_redactions` tombstone. The tombstone's insert trigger
+//! scrubs the original content from audit rows and stale rolling summaries,
+//! so both statements must run in one transaction.
+
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::super::models::{MessageRedactionRow, MessageRow, NewMessage};
+use super::super::schema::{message_redactions, messages};
+use super::sealing::{MessageCodec, RowScope};
+use crate::message::{
+    domain::{Message, MessageRedaction},
+    error::RepositoryError,
+    ports::repository::RepositoryResult,
+};
+
+/// Row changes that redact one message.
+pub(super) struct RedactionWrite {
+    /// The message as redacted.
+    pub message: Message,
+    /// Serialised placeholder content, sealed if the codec seals.
+    pub content: Value,
+    /// Serialised metadata after scrubbing, sealed if the codec seals.
+    pub metadata: Value,
+    /// The tombstone to insert.
+    pub tombstone: MessageRedactionRow,
+}
+
+/// Prepares the writes that redact the stored message `row`.
+///
+/// # Errors
+///
+/// Returns [`RepositoryError::AlreadyRedacted`] when the message is already
+/// redacted, or a serialisation error when the row cannot be converted.
+pub(super) fn prepare_redaction(
+    codec: &MessageCodec,
+    row: MessageRow,
+    redaction: &MessageRedaction,
+    correlation_id: Uuid,
+) -> RepositoryResult<RedactionWrite> {
+    let tenant_id = row.tenant_id;
+    let message = codec.to_message(row)?;
+    if message.is_redacted() {
+        return Err(RepositoryError::AlreadyRedacted(message.id()));
+    }
+    let redacted = message.redacted(redaction);
+    let new_row = codec.seal(NewMessage::try_from_domain(&redacted, tenant_id)?)?;
+    Ok(RedactionWrite {
+        tombstone: MessageRedactionRow {
+            message_id: new_row.id,
+            tenant_id,
+            conversation_id: new_row.conversation_id,
+            sequence_number: new_row.sequence_number,
+            reason: redaction.reason().as_str().to_owned(),
+            redacted_by: redaction.redacted_by().into_inner(),
+            correlation_id,
+            redacted_at: redaction.redacted_at(),
+        },
+        content: new_row.content,
+        metadata: new_row.metadata,
+        message: redacted,
+    })
+}
+
+/// Redacts a message inside the caller's transaction.
+///
+/// The message row is locked before it is read, so concurrent redactions of
+/// one message cannot both succeed.
+pub(crate) fn redact_message(
+    conn: &mut PgConnection,
+    scope: RowScope<'_>,
+    correlation_id: Uuid,
+    redaction: &MessageRedaction,
+) -> RepositoryResult<Message> {
+    let message_id = redaction.message_id();
+    let row = messages::table
+        .filter(messages::id.eq(message_id.into_inner()))
+        .filter(messages::tenant_id.eq(scope.tenant_id))
+        .select(MessageRow::as_select())
+        .for_update()
+        .first::<MessageRow>(conn)
+        .optional()
+        .map_err(RepositoryError::database)?
+        .ok_or(RepositoryError::NotFound(message_id))?;
+    let write = prepare_redaction(scope.codec, row, redaction, correlation_id)?;
+
+    diesel::update(
+        messages::table
+            .filter(messages::id.eq(message_id.into_inner()))
+            .filter(messages::tenant_id.eq(scope.tenant_id)),
+    )
+    .set((
+        messages::content.eq(write.content),
+        messages::metadata.eq(write.metadata),
+    ))
+    .execute(conn)
+    .map_err(RepositoryError::database)?;
+    diesel::insert_into(message_redactions::table)
+        .values(&write.tombstone)
+        .execute(conn)
+        .map_err(RepositoryError::database)?;
+    Ok(write.message)
+}
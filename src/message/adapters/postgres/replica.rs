@@ -0,0 +1,113 @@
+//! Routing of read-only queries to a streaming replica.
+//!
+//! A [`ReadReplica`] pairs a replica's pool with how far behind the primary
+//! its data may be. Adapters given one take an idle replica connection for
+//! their listing queries and fall back to their primary pool when the
+//! replica has no connection to spare, lags beyond the tolerance, or cannot
+//! report its lag.
+
+use std::time::Duration;
+
+use diesel::pg::PgConnection;
+use diesel::r2d2::PoolError;
+use diesel::sql_types::{BigInt, Nullable};
+use diesel::{QueryableByName, RunQueryDsl};
+
+use super::blocking_helpers::PgPool;
+use crate::postgres_support::PooledConn;
+
+/// Replay lag of the connected server in milliseconds; zero on a primary,
+/// and on a replica that has replayed everything it received.
+const REPLICATION_LAG_QUERY: &str = "SELECT CASE \
+     WHEN NOT pg_is_in_recovery() THEN 0 \
+     WHEN pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0 \
+     ELSE (EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp()) * 1000)::bigint \
+     END AS lag_ms";
+
+#[derive(QueryableByName)]
+struct LagRow {
+    #[diesel(sql_type = Nullable<BigInt>)]
+    lag_ms: Option<i64>,
+}
+
+/// A replica pool that serves reads while it keeps up with the primary.
+///
+/// # Examples
+///
+/// ```ignore
+/// use corbusier::message::adapters::postgres::{PostgresMessageRepository, ReadReplica};
+/// use std::time::Duration;
+///
+/// let repo = PostgresMessageRepository::new(primary_pool)
+///     .with_read_replica(ReadReplica::new(replica_pool, Duration::from_secs(2)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReadReplica {
+    pool: PgPool,
+    max_staleness: Duration,
+}
+
+impl ReadReplica {
+    /// Creates a replica whose data may trail the primary by at most
+    /// `max_staleness`.
+    #[must_use]
+    pub const fn new(pool: PgPool, max_staleness: Duration) -> Self {
+        Self {
+            pool,
+            max_staleness,
+        }
+    }
+
+    /// Returns the replica's connection pool.
+    #[must_use]
+    pub const fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// Returns how far the replica may trail the primary.
+    #[must_use]
+    pub const fn max_staleness(&self) -> Duration {
+        self.max_staleness
+    }
+
+    /// Takes an idle replica connection that is fresh enough, or else waits
+    /// for a connection from `primary`.
+    fn connection_or(&self, primary: &PgPool) -> Result<PooledConn, PoolError> {
+        let Some(mut conn) = self.pool.try_get() else {
+            tracing::debug!("replica pool exhausted; reading from the primary");
+            return primary.get();
+        };
+        if self.is_fresh(&mut conn) {
+            return Ok(conn);
+        }
+        tracing::debug!(
+            max_staleness = ?self.max_staleness,
+            "replica lags beyond tolerance; reading from the primary"
+        );
+        primary.get()
+    }
+
+    /// Returns `true` if the replica behind `conn` trails the primary by no
+    /// more than the tolerance. A replica that cannot say counts as stale.
+    fn is_fresh(&self, conn: &mut PgConnection) -> bool {
+        let lag = diesel::sql_query(REPLICATION_LAG_QUERY)
+            .get_result::<LagRow>(conn)
+            .ok()
+            .and_then(|row| row.lag_ms)
+            .map(|lag_ms| lag_ms.max(0).unsigned_abs());
+        lag.is_some_and(|lag_ms| u128::from(lag_ms) <= self.max_staleness.as_millis())
+    }
+}
+
+/// Takes a connection for a read-only query from `replica` when one is
+/// configured and fresh enough, and from `primary` otherwise.
+///
+/// # Errors
+///
+/// Returns [`PoolError`] if the primary pool has no connection to give.
+pub(crate) fn read_connection(
+    primary: &PgPool,
+    replica: Option<&ReadReplica>,
+) -> Result<PooledConn, PoolError> {
+    replica.map_or_else(|| primary.get(), |replica| replica.connection_or(primary))
+}
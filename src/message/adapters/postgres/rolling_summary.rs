@@ -0,0 +1,168 @@
+//! `PostgreSQL` implementation of the `RollingSummaryRepository` port.
+//!
+//! Each conversation holds one row, upserted on its
+//! `(tenant_id, conversation_id)` primary key so every update replaces the
+//! previous summary in place.
+
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::models::RollingSummaryRow,
+    adapters::schema::conversation_rolling_summaries,
+    domain::{ConversationId, PersistedRollingSummaryData, RollingSummary, SequenceNumber},
+    ports::summary::{RollingSummaryError, RollingSummaryRepository, RollingSummaryResult},
+};
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+
+impl FromTxError<Self> for RollingSummaryError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL` implementation of [`RollingSummaryRepository`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access.
+#[derive(Debug, Clone)]
+pub struct PostgresRollingSummaryRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresRollingSummaryRepository,
+    "rolling_summary_repository"
+);
+
+impl PostgresRollingSummaryRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RollingSummaryRepository for PostgresRollingSummaryRepository {
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RollingSummaryResult<Option<RollingSummary>> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let conversation_uuid = conversation_id.into_inner();
+        let row = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, RollingSummaryError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    conversation_rolling_summaries::table
+                        .filter(conversation_rolling_summaries::tenant_id.eq(tenant_uuid))
+                        .filter(
+                            conversation_rolling_summaries::conversation_id.eq(conversation_uuid),
+                        )
+                        .select(RollingSummaryRow::as_select())
+                        .first(tx)
+                        .optional()
+                        .map_err(RollingSummaryError::persistence)
+                })
+            },
+            RollingSummaryError::persistence,
+        )
+        .await?;
+        row.map(row_to_summary).transpose()
+    }
+
+    async fn store(
+        &self,
+        ctx: &RequestContext,
+        summary: &RollingSummary,
+    ) -> RollingSummaryResult<()> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = to_row(summary, tenant_uuid)?;
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, RollingSummaryError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid)
+                        .map_err(RollingSummaryError::persistence)?;
+                    upsert_row(tx, &row).map_err(RollingSummaryError::persistence)
+                })
+            },
+            RollingSummaryError::persistence,
+        )
+        .await
+    }
+}
+
+fn upsert_row(conn: &mut PgConnection, row: &RollingSummaryRow) -> QueryResult<()> {
+    diesel::insert_into(conversation_rolling_summaries::table)
+        .values(row)
+        .on_conflict((
+            conversation_rolling_summaries::tenant_id,
+            conversation_rolling_summaries::conversation_id,
+        ))
+        .do_update()
+        .set((
+            conversation_rolling_summaries::summary
+                .eq(excluded(conversation_rolling_summaries::summary)),
+            conversation_rolling_summaries::covered_through
+                .eq(excluded(conversation_rolling_summaries::covered_through)),
+            conversation_rolling_summaries::incremental_updates.eq(excluded(
+                conversation_rolling_summaries::incremental_updates,
+            )),
+            conversation_rolling_summaries::recomputed_at
+                .eq(excluded(conversation_rolling_summaries::recomputed_at)),
+            conversation_rolling_summaries::updated_at
+                .eq(excluded(conversation_rolling_summaries::updated_at)),
+        ))
+        .execute(conn)
+        .map(|_| ())
+}
+
+fn to_row(
+    summary: &RollingSummary,
+    tenant_id: uuid::Uuid,
+) -> RollingSummaryResult<RollingSummaryRow> {
+    Ok(RollingSummaryRow {
+        tenant_id,
+        conversation_id: summary.conversation_id().into_inner(),
+        summary: summary.text().to_owned(),
+        covered_through: i64::try_from(summary.covered_through().value())
+            .map_err(RollingSummaryError::persistence)?,
+        incremental_updates: i32::try_from(summary.incremental_updates())
+            .map_err(RollingSummaryError::persistence)?,
+        recomputed_at: summary.recomputed_at(),
+        updated_at: summary.updated_at(),
+    })
+}
+
+fn row_to_summary(row: RollingSummaryRow) -> RollingSummaryResult<RollingSummary> {
+    Ok(RollingSummary::from_persisted(
+        PersistedRollingSummaryData {
+            conversation_id: ConversationId::from_uuid(row.conversation_id),
+            text: row.summary,
+            covered_through: SequenceNumber::try_new(
+                u64::try_from(row.covered_through)
+                    .map_err(RollingSummaryError::invalid_persisted_data)?,
+            )
+            .map_err(RollingSummaryError::invalid_persisted_data)?,
+            incremental_updates: u32::try_from(row.incremental_updates)
+                .map_err(RollingSummaryError::invalid_persisted_data)?,
+            recomputed_at: row.recomputed_at,
+            updated_at: row.updated_at,
+        },
+    ))
+}
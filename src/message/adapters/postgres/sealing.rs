@@ -0,0 +1,400 @@
+//! Transparent encryption of message rows for the `PostgreSQL` repositories.
+//!
+//! With a [`ContentCipher`] configured, the `content` and `metadata` JSONB
+//! columns hold an envelope instead of the serialised value:
+//!
+//! ```json
+//! {"$sealed": {"key_id": "...", "wrapped_key": "...", "key_nonce": "...",
+//!              "nonce": "...", "ciphertext": "..."}}
+//! ```
+//!
+//! Binary fields are base64. The associated data is the column name and the
+//! message ID, so a sealed value copied to another row or column fails to
+//! open. Rows written before a cipher was configured are read as they are,
+//! so encryption can be switched on without migrating existing rows.
+//!
+//! A few fields stay beside the envelope, unsealed, because SQL reads them:
+//!
+//! - the metadata's `pinned` flag, for pinned-message queries and the
+//!   pinning operators;
+//! - the metadata's `agent_backend`, which the conversation summary
+//!   projection copies, so backend filters and deprecation reports work;
+//! - the metadata's `token_count`, summed for per-conversation token usage;
+//! - `$keys`, the names (not values) of the metadata's other fields and
+//!   extensions, for metadata-key filters;
+//! - `$parts`, each content part's `type` tag, for tool results the
+//!   `success` flag, for content-part filters and activity statistics, and
+//!   for externalised attachments the `blob` hash, so a shared blob is only
+//!   deleted once no message refers to it.
+//!
+//! Staged streams in `partial_messages` are sealed the same way, bound to
+//! that table, with nothing kept beside the envelope: no SQL reads them.
+
+use std::fmt;
+use std::sync::Arc;
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::super::models::{MessageRow, NewMessage, PartialMessageRow};
+use super::conversion_helpers::{row_to_message, ser_err};
+use crate::message::{
+    domain::{EncryptionKeyId, Message, SealedValue},
+    error::RepositoryError,
+    ports::{content_cipher::ContentCipher, repository::RepositoryResult},
+};
+
+/// Key of the envelope object holding a sealed value.
+const SEALED_KEY: &str = "$sealed";
+
+/// Metadata keys kept beside the envelope so SQL can filter and update them.
+const CLEAR_METADATA_KEYS: &[&str] = &["pinned", "agent_backend", "token_count"];
+
+/// Key of the list of metadata field names kept beside the envelope.
+const KEYS_INDEX: &str = "$keys";
+
+/// Key of the content part summaries kept beside the envelope.
+const PARTS_INDEX: &str = "$parts";
+
+/// Content part fields kept in the part summaries.
+const CLEAR_PART_KEYS: &[&str] = &["type", "success", "blob"];
+
+/// A sealed message column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SealedColumn {
+    /// The `messages.content` column.
+    Content,
+    /// The `messages.metadata` column.
+    Metadata,
+    /// The `partial_messages.content` column.
+    PartialContent,
+    /// The `partial_messages.metadata` column.
+    PartialMetadata,
+}
+
+impl SealedColumn {
+    const fn table(self) -> &'static str {
+        match self {
+            Self::Content | Self::Metadata => "messages",
+            Self::PartialContent | Self::PartialMetadata => "partial_messages",
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Content | Self::PartialContent => "content",
+            Self::Metadata | Self::PartialMetadata => "metadata",
+        }
+    }
+
+    fn aad(self, message_id: Uuid) -> Vec<u8> {
+        format!("{}.{}:{message_id}", self.table(), self.name()).into_bytes()
+    }
+}
+
+/// Serialised form of a [`SealedValue`].
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    key_id: String,
+    wrapped_key: String,
+    key_nonce: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Converts message rows to and from their stored form.
+///
+/// Without a cipher rows are stored as they are; reading a sealed row then
+/// fails rather than returning ciphertext.
+#[derive(Clone)]
+pub(crate) struct MessageCodec {
+    cipher: Option<Arc<dyn ContentCipher>>,
+}
+
+impl MessageCodec {
+    /// Returns a codec that stores rows unencrypted.
+    pub(crate) const fn plain() -> Self {
+        Self { cipher: None }
+    }
+
+    /// Returns a codec that seals rows with `cipher`.
+    pub(crate) fn sealed(cipher: Arc<dyn ContentCipher>) -> Self {
+        Self {
+            cipher: Some(cipher),
+        }
+    }
+
+    /// Seals the content and metadata of a row about to be written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RepositoryError::Cipher`] if sealing fails.
+    pub(crate) fn seal(&self, mut row: NewMessage) -> RepositoryResult<NewMessage> {
+        row.content = self.seal_value(row.id, SealedColumn::Content, &row.content)?;
+        row.metadata = self.seal_value(row.id, SealedColumn::Metadata, &row.metadata)?;
+        Ok(row)
+    }
+
+    /// Opens the content and metadata of a stored row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RepositoryError::Cipher`] if a sealed column fails to open,
+    /// or a serialisation error if it is malformed or no cipher is
+    /// configured.
+    pub(crate) fn open(&self, mut row: MessageRow) -> RepositoryResult<MessageRow> {
+        row.content = self.open_value(row.id, SealedColumn::Content, row.content)?;
+        row.metadata = self.open_value(row.id, SealedColumn::Metadata, row.metadata)?;
+        Ok(row)
+    }
+
+    /// Opens a stored row and converts it to a domain message.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`MessageCodec::open`] and [`row_to_message`].
+    pub(crate) fn to_message(&self, row: MessageRow) -> RepositoryResult<Message> {
+        row_to_message(self.open(row)?)
+    }
+
+    /// Opens a stored row and seals it again under the active key.
+    ///
+    /// Unsealed columns are sealed, so the result is fully encrypted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`MessageCodec::open`] and
+    /// [`MessageCodec::seal`].
+    pub(crate) fn reseal(&self, row: MessageRow) -> RepositoryResult<MessageRow> {
+        let mut opened = self.open(row)?;
+        opened.content = self.seal_value(opened.id, SealedColumn::Content, &opened.content)?;
+        opened.metadata = self.seal_value(opened.id, SealedColumn::Metadata, &opened.metadata)?;
+        Ok(opened)
+    }
+
+    /// Opens a stored row and seals it as a copy with ID `copy_id` in
+    /// conversation `conversation_id`.
+    ///
+    /// The associated data binds a sealed column to its message ID, so a
+    /// copy must be sealed afresh rather than copied byte for byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`MessageCodec::open`] and
+    /// [`MessageCodec::seal`].
+    pub(crate) fn seal_copy(
+        &self,
+        row: MessageRow,
+        copy_id: Uuid,
+        conversation_id: Uuid,
+    ) -> RepositoryResult<NewMessage> {
+        let opened = self.open(row)?;
+        self.seal(NewMessage {
+            id: copy_id,
+            tenant_id: opened.tenant_id,
+            conversation_id,
+            role: opened.role,
+            content: opened.content,
+            metadata: opened.metadata,
+            created_at: opened.created_at,
+            sequence_number: opened.sequence_number,
+        })
+    }
+
+    /// Seals the content and metadata of a staged stream about to be
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RepositoryError::Cipher`] if sealing fails.
+    pub(crate) fn seal_partial(
+        &self,
+        mut row: PartialMessageRow,
+    ) -> RepositoryResult<PartialMessageRow> {
+        row.content =
+            self.seal_value(row.message_id, SealedColumn::PartialContent, &row.content)?;
+        row.metadata =
+            self.seal_value(row.message_id, SealedColumn::PartialMetadata, &row.metadata)?;
+        Ok(row)
+    }
+
+    /// Opens the content and metadata of a stored staged stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`MessageCodec::open`].
+    pub(crate) fn open_partial(
+        &self,
+        mut row: PartialMessageRow,
+    ) -> RepositoryResult<PartialMessageRow> {
+        row.content = self.open_value(row.message_id, SealedColumn::PartialContent, row.content)?;
+        row.metadata =
+            self.open_value(row.message_id, SealedColumn::PartialMetadata, row.metadata)?;
+        Ok(row)
+    }
+
+    /// Opens a stored staged stream and seals it again under the active
+    /// key.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`MessageCodec::open_partial`] and
+    /// [`MessageCodec::seal_partial`].
+    pub(crate) fn reseal_partial(
+        &self,
+        row: PartialMessageRow,
+    ) -> RepositoryResult<PartialMessageRow> {
+        self.seal_partial(self.open_partial(row)?)
+    }
+
+    /// Seals one column value, or returns it unchanged without a cipher.
+    fn seal_value(
+        &self,
+        message_id: Uuid,
+        column: SealedColumn,
+        value: &Value,
+    ) -> RepositoryResult<Value> {
+        let Some(cipher) = &self.cipher else {
+            return Ok(value.clone());
+        };
+        let mut secret = value.clone();
+        let mut wrapper = match column {
+            SealedColumn::Content => content_index(&secret),
+            SealedColumn::Metadata => metadata_index(&secret),
+            SealedColumn::PartialContent | SealedColumn::PartialMetadata => serde_json::Map::new(),
+        };
+        if let (SealedColumn::Metadata, Value::Object(fields)) = (column, &mut secret) {
+            for key in CLEAR_METADATA_KEYS {
+                if let Some(flag) = fields.remove(*key) {
+                    wrapper.insert((*key).to_owned(), flag);
+                }
+            }
+        }
+        let plaintext = serde_json::to_vec(&secret).map_err(ser_err)?;
+        let sealed = cipher.seal(&plaintext, &column.aad(message_id))?;
+        let envelope = Envelope {
+            key_id: sealed.key_id.as_str().to_owned(),
+            wrapped_key: STANDARD.encode(&sealed.wrapped_key),
+            key_nonce: STANDARD.encode(&sealed.key_nonce),
+            nonce: STANDARD.encode(&sealed.nonce),
+            ciphertext: STANDARD.encode(&sealed.ciphertext),
+        };
+        wrapper.insert(
+            SEALED_KEY.to_owned(),
+            serde_json::to_value(envelope).map_err(ser_err)?,
+        );
+        Ok(Value::Object(wrapper))
+    }
+
+    /// Opens one column value, or returns it unchanged if it is not sealed.
+    fn open_value(
+        &self,
+        message_id: Uuid,
+        column: SealedColumn,
+        value: Value,
+    ) -> RepositoryResult<Value> {
+        let Some(envelope) = sealed_envelope(&value) else {
+            return Ok(value);
+        };
+        let Some(cipher) = &self.cipher else {
+            return Err(RepositoryError::serialization(format!(
+                "message {message_id} {}.{} is encrypted but no content cipher is configured",
+                column.table(),
+                column.name()
+            )));
+        };
+        let sealed = decode_envelope(envelope)?;
+        let plaintext = cipher.open(&sealed, &column.aad(message_id))?;
+        let mut opened: Value = serde_json::from_slice(&plaintext).map_err(ser_err)?;
+        if let (Value::Object(fields), Value::Object(wrapper)) = (&mut opened, &value) {
+            let clear = wrapper
+                .iter()
+                .filter(|(key, _)| ![SEALED_KEY, KEYS_INDEX].contains(&key.as_str()));
+            for (key, flag) in clear {
+                fields.insert(key.clone(), flag.clone());
+            }
+        }
+        Ok(opened)
+    }
+}
+
+impl fmt::Debug for MessageCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessageCodec")
+            .field(
+                "active_key_id",
+                &self.cipher.as_ref().map(|cipher| cipher.active_key_id()),
+            )
+            .finish()
+    }
+}
+
+/// Returns the content part summaries kept beside a sealed content column.
+fn content_index(content: &Value) -> serde_json::Map<String, Value> {
+    let Value::Array(parts) = content else {
+        return serde_json::Map::new();
+    };
+    let summaries = parts
+        .iter()
+        .filter_map(Value::as_object)
+        .map(|part| {
+            let clear = part
+                .iter()
+                .filter(|(key, _)| CLEAR_PART_KEYS.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()));
+            Value::Object(clear.collect())
+        })
+        .collect();
+    serde_json::Map::from_iter([(PARTS_INDEX.to_owned(), Value::Array(summaries))])
+}
+
+/// Returns the metadata field and extension names kept beside a sealed
+/// metadata column.
+fn metadata_index(metadata: &Value) -> serde_json::Map<String, Value> {
+    let Value::Object(fields) = metadata else {
+        return serde_json::Map::new();
+    };
+    let extensions = fields
+        .get("extensions")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flat_map(serde_json::Map::keys);
+    let mut names: Vec<&String> = fields
+        .keys()
+        .chain(extensions)
+        .filter(|name| !CLEAR_METADATA_KEYS.contains(&name.as_str()))
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    let names = names.into_iter().cloned().map(Value::String).collect();
+    serde_json::Map::from_iter([(KEYS_INDEX.to_owned(), Value::Array(names))])
+}
+
+/// Returns the envelope of a sealed column value.
+fn sealed_envelope(value: &Value) -> Option<&Value> {
+    value.as_object()?.get(SEALED_KEY)
+}
+
+fn decode_envelope(envelope: &Value) -> RepositoryResult<SealedValue> {
+    let decoded = Envelope::deserialize(envelope).map_err(ser_err)?;
+    let bytes = |field: &str| STANDARD.decode(field).map_err(ser_err);
+    Ok(SealedValue {
+        key_id: EncryptionKeyId::new(decoded.key_id).map_err(ser_err)?,
+        wrapped_key: bytes(&decoded.wrapped_key)?,
+        key_nonce: bytes(&decoded.key_nonce)?,
+        nonce: bytes(&decoded.nonce)?,
+        ciphertext: bytes(&decoded.ciphertext)?,
+    })
+}
+
+/// The tenant and codec a message row operation runs under.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RowScope<'a> {
+    /// The tenant that owns the row.
+    pub tenant_id: Uuid,
+    /// Converts the row to and from its stored form.
+    pub codec: &'a MessageCodec,
+}
@@ -0,0 +1,287 @@
+//! `PostgreSQL` implementation of the `SlashCommandDefinitionRepository`
+//! port.
+//!
+//! Each version of a command is one row keyed on
+//! `(tenant_id, command, version)`. The definition is stored as JSONB, so
+//! fields added to [`SlashCommandDefinition`] persist without a migration.
+
+use std::num::NonZeroU32;
+
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::models::SlashCommandDefinitionRow,
+    adapters::schema::slash_command_definitions,
+    domain::{SlashCommandDefinition, VersionedSlashCommand},
+    ports::slash_command::{
+        SlashCommandDefinitionError, SlashCommandDefinitionRepository, SlashCommandDefinitionResult,
+    },
+};
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+
+impl FromTxError<Self> for SlashCommandDefinitionError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL` implementation of [`SlashCommandDefinitionRepository`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access.
+#[derive(Debug, Clone)]
+pub struct PostgresSlashCommandDefinitionRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresSlashCommandDefinitionRepository,
+    "slash_command_definition_repository"
+);
+
+impl PostgresSlashCommandDefinitionRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn read<F>(
+        &self,
+        ctx: &RequestContext,
+        read_fn: F,
+    ) -> SlashCommandDefinitionResult<Vec<VersionedSlashCommand>>
+    where
+        F: FnOnce(&mut PgConnection, Uuid) -> QueryResult<Vec<SlashCommandDefinitionRow>>
+            + Send
+            + 'static,
+    {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let rows = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, SlashCommandDefinitionError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    read_fn(tx, tenant_uuid).map_err(SlashCommandDefinitionError::persistence)
+                })
+            },
+            SlashCommandDefinitionError::persistence,
+        )
+        .await?;
+        rows.into_iter().map(row_to_command).collect()
+    }
+
+    async fn write<F, T>(
+        &self,
+        ctx: &RequestContext,
+        write_fn: F,
+    ) -> SlashCommandDefinitionResult<T>
+    where
+        F: FnOnce(&mut PgConnection, Uuid) -> SlashCommandDefinitionResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, SlashCommandDefinitionError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid)
+                        .map_err(SlashCommandDefinitionError::persistence)?;
+                    write_fn(tx, tenant_uuid)
+                })
+            },
+            SlashCommandDefinitionError::persistence,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl SlashCommandDefinitionRepository for PostgresSlashCommandDefinitionRepository {
+    async fn append(
+        &self,
+        ctx: &RequestContext,
+        command: &VersionedSlashCommand,
+    ) -> SlashCommandDefinitionResult<()> {
+        let row = command_to_row(command, ctx.tenant_id().into_inner())?;
+        let version = command.version;
+        self.write(ctx, move |tx, tenant_uuid| {
+            let latest: Option<i32> = slash_command_definitions::table
+                .filter(slash_command_definitions::tenant_id.eq(tenant_uuid))
+                .filter(slash_command_definitions::command.eq(&row.command))
+                .select(diesel::dsl::max(slash_command_definitions::version))
+                .first(tx)
+                .map_err(SlashCommandDefinitionError::persistence)?;
+            let conflict = || SlashCommandDefinitionError::VersionConflict {
+                command: row.command.clone(),
+                version,
+            };
+            if latest.unwrap_or(0).checked_add(1) != Some(row.version) {
+                return Err(conflict());
+            }
+            diesel::insert_into(slash_command_definitions::table)
+                .values(&row)
+                .execute(tx)
+                .map(|_| ())
+                .map_err(|err| match err {
+                    DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => conflict(),
+                    other => SlashCommandDefinitionError::persistence(other),
+                })
+        })
+        .await
+    }
+
+    async fn find_latest(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+    ) -> SlashCommandDefinitionResult<Option<VersionedSlashCommand>> {
+        let name = command.to_ascii_lowercase();
+        let mut found = self
+            .read(ctx, move |tx, tenant_uuid| {
+                slash_command_definitions::table
+                    .filter(slash_command_definitions::tenant_id.eq(tenant_uuid))
+                    .filter(slash_command_definitions::command.eq(name))
+                    .order(slash_command_definitions::version.desc())
+                    .limit(1)
+                    .select(SlashCommandDefinitionRow::as_select())
+                    .load(tx)
+            })
+            .await?;
+        Ok(found.pop())
+    }
+
+    async fn find_version(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+        version: NonZeroU32,
+    ) -> SlashCommandDefinitionResult<Option<VersionedSlashCommand>> {
+        let name = command.to_ascii_lowercase();
+        let Ok(stored_version) = i32::try_from(version.get()) else {
+            return Ok(None);
+        };
+        let mut found = self
+            .read(ctx, move |tx, tenant_uuid| {
+                slash_command_definitions::table
+                    .filter(slash_command_definitions::tenant_id.eq(tenant_uuid))
+                    .filter(slash_command_definitions::command.eq(name))
+                    .filter(slash_command_definitions::version.eq(stored_version))
+                    .select(SlashCommandDefinitionRow::as_select())
+                    .load(tx)
+            })
+            .await?;
+        Ok(found.pop())
+    }
+
+    async fn history(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+    ) -> SlashCommandDefinitionResult<Vec<VersionedSlashCommand>> {
+        let name = command.to_ascii_lowercase();
+        self.read(ctx, move |tx, tenant_uuid| {
+            slash_command_definitions::table
+                .filter(slash_command_definitions::tenant_id.eq(tenant_uuid))
+                .filter(slash_command_definitions::command.eq(name))
+                .order(slash_command_definitions::version.asc())
+                .select(SlashCommandDefinitionRow::as_select())
+                .load(tx)
+        })
+        .await
+    }
+
+    async fn list_latest(
+        &self,
+        ctx: &RequestContext,
+    ) -> SlashCommandDefinitionResult<Vec<VersionedSlashCommand>> {
+        self.read(ctx, move |tx, tenant_uuid| {
+            slash_command_definitions::table
+                .filter(slash_command_definitions::tenant_id.eq(tenant_uuid))
+                .distinct_on(slash_command_definitions::command)
+                .order((
+                    slash_command_definitions::command.asc(),
+                    slash_command_definitions::version.desc(),
+                ))
+                .select(SlashCommandDefinitionRow::as_select())
+                .load(tx)
+        })
+        .await
+    }
+
+    async fn remove(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+    ) -> SlashCommandDefinitionResult<usize> {
+        let name = command.to_ascii_lowercase();
+        self.write(ctx, move |tx, tenant_uuid| {
+            diesel::delete(
+                slash_command_definitions::table
+                    .filter(slash_command_definitions::tenant_id.eq(tenant_uuid))
+                    .filter(slash_command_definitions::command.eq(name)),
+            )
+            .execute(tx)
+            .map_err(SlashCommandDefinitionError::persistence)
+        })
+        .await
+    }
+}
+
+fn command_to_row(
+    command: &VersionedSlashCommand,
+    tenant_id: Uuid,
+) -> SlashCommandDefinitionResult<SlashCommandDefinitionRow> {
+    let mut definition = command.definition.clone();
+    definition.normalise_names();
+    Ok(SlashCommandDefinitionRow {
+        tenant_id,
+        command: definition.qualified_name(),
+        version: i32::try_from(command.version.get())
+            .map_err(SlashCommandDefinitionError::persistence)?,
+        definition: serde_json::to_value(&definition)
+            .map_err(SlashCommandDefinitionError::persistence)?,
+        registered_at: command.registered_at,
+    })
+}
+
+fn row_to_command(
+    row: SlashCommandDefinitionRow,
+) -> SlashCommandDefinitionResult<VersionedSlashCommand> {
+    let SlashCommandDefinitionRow {
+        command,
+        version,
+        definition,
+        registered_at,
+        ..
+    } = row;
+    let invalid = |reason: String| {
+        SlashCommandDefinitionError::InvalidPersistedData(format!(
+            "slash command '{command}' version {version}: {reason}"
+        ))
+    };
+    let positive = u32::try_from(version)
+        .ok()
+        .and_then(NonZeroU32::new)
+        .ok_or_else(|| invalid("version must be positive".to_owned()))?;
+    let decoded: SlashCommandDefinition =
+        serde_json::from_value(definition).map_err(|err| invalid(err.to_string()))?;
+    Ok(VersionedSlashCommand {
+        definition: decoded,
+        version: positive,
+        registered_at,
+    })
+}
@@ -0,0 +1,215 @@
+//! `PostgreSQL` implementation of the `SlashCommandExecutionRepository` port.
+//!
+//! Each recorded execution is one row of the `slash_command_executions`
+//! table. The execution itself is stored as JSONB; the command name is
+//! copied into its own column so the trail can be read without decoding.
+
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+
+use crate::context::{RequestContext, TenantId, UserId};
+use crate::message::{
+    adapters::models::SlashCommandExecutionRow,
+    adapters::schema::slash_command_executions,
+    domain::{
+        ConversationId, PersistedSlashCommandExecution, SlashCommandExecution,
+        SlashCommandExecutionId, SlashCommandExecutionRecord,
+    },
+    ports::slash_command_execution::{
+        SlashCommandExecutionError, SlashCommandExecutionRepository, SlashCommandExecutionResult,
+    },
+};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+
+impl FromTxError<Self> for SlashCommandExecutionError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL` implementation of [`SlashCommandExecutionRepository`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access.
+#[derive(Debug, Clone)]
+pub struct PostgresSlashCommandExecutionRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresSlashCommandExecutionRepository,
+    "slash_command_execution_repository"
+);
+
+impl PostgresSlashCommandExecutionRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Runs `query_fn` in a read-only transaction.
+    async fn read_query<F, T>(
+        &self,
+        tenant_id: TenantId,
+        query_fn: F,
+    ) -> SlashCommandExecutionResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> SlashCommandExecutionResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, SlashCommandExecutionError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_id.into_inner(), query_fn)
+            },
+            SlashCommandExecutionError::persistence,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl SlashCommandExecutionRepository for PostgresSlashCommandExecutionRepository {
+    async fn store(
+        &self,
+        ctx: &RequestContext,
+        record: &SlashCommandExecutionRecord,
+    ) -> SlashCommandExecutionResult<()> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = record_to_row(record, tenant_uuid)?;
+        let record_id = record.id();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, SlashCommandExecutionError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid)
+                        .map_err(SlashCommandExecutionError::persistence)?;
+                    insert_row(tx, &row, record_id)
+                })
+            },
+            SlashCommandExecutionError::persistence,
+        )
+        .await
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: SlashCommandExecutionId,
+    ) -> SlashCommandExecutionResult<Option<SlashCommandExecutionRecord>> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = id.into_inner();
+        self.read_query(tenant_id, move |conn| {
+            slash_command_executions::table
+                .filter(slash_command_executions::tenant_id.eq(tenant_id.into_inner()))
+                .filter(slash_command_executions::id.eq(uuid))
+                .select(SlashCommandExecutionRow::as_select())
+                .first::<SlashCommandExecutionRow>(conn)
+                .optional()
+                .map_err(SlashCommandExecutionError::persistence)?
+                .map(row_to_record)
+                .transpose()
+        })
+        .await
+    }
+
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> SlashCommandExecutionResult<Page<SlashCommandExecutionRecord>> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = conversation_id.into_inner();
+        self.read_query(tenant_id, move |conn| {
+            let query = slash_command_executions::table
+                .filter(slash_command_executions::tenant_id.eq(tenant_id.into_inner()))
+                .filter(slash_command_executions::conversation_id.eq(uuid))
+                .into_boxed();
+            let rows = keyset_page!(
+                query,
+                page,
+                after_timestamp,
+                (
+                    slash_command_executions::executed_at,
+                    slash_command_executions::id
+                )
+            )
+            .select(SlashCommandExecutionRow::as_select())
+            .load::<SlashCommandExecutionRow>(conn)
+            .map_err(SlashCommandExecutionError::persistence)?;
+            Page::from_overfetched(rows, page, |row| {
+                Cursor::at_timestamp(row.executed_at, row.id)
+            })
+            .try_map(row_to_record)
+        })
+        .await
+    }
+}
+
+fn insert_row(
+    conn: &mut PgConnection,
+    row: &SlashCommandExecutionRow,
+    record_id: SlashCommandExecutionId,
+) -> SlashCommandExecutionResult<()> {
+    diesel::insert_into(slash_command_executions::table)
+        .values(row)
+        .execute(conn)
+        .map(|_| ())
+        .map_err(|err| match err {
+            DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                SlashCommandExecutionError::Duplicate(record_id)
+            }
+            other => SlashCommandExecutionError::persistence(other),
+        })
+}
+
+fn record_to_row(
+    record: &SlashCommandExecutionRecord,
+    tenant_id: uuid::Uuid,
+) -> SlashCommandExecutionResult<SlashCommandExecutionRow> {
+    Ok(SlashCommandExecutionRow {
+        id: record.id().into_inner(),
+        tenant_id,
+        conversation_id: record.conversation_id().into_inner(),
+        invoked_by: record.invoked_by().into_inner(),
+        command: record
+            .expansion()
+            .command
+            .trim_start_matches('/')
+            .to_owned(),
+        execution: serde_json::to_value(record.execution())
+            .map_err(SlashCommandExecutionError::persistence)?,
+        executed_at: record.executed_at(),
+    })
+}
+
+fn row_to_record(
+    row: SlashCommandExecutionRow,
+) -> SlashCommandExecutionResult<SlashCommandExecutionRecord> {
+    let execution: SlashCommandExecution = serde_json::from_value(row.execution)
+        .map_err(SlashCommandExecutionError::invalid_persisted_data)?;
+    Ok(SlashCommandExecutionRecord::from_persisted(
+        PersistedSlashCommandExecution {
+            id: SlashCommandExecutionId::from_uuid(row.id),
+            conversation_id: ConversationId::from_uuid(row.conversation_id),
+            invoked_by: UserId::from_uuid(row.invoked_by),
+            executed_at: row.executed_at,
+            execution,
+        },
+    ))
+}
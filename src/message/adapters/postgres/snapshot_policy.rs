@@ -0,0 +1,198 @@
+//! `PostgreSQL` implementation of the `SnapshotPolicyRepository` port.
+//!
+//! Each conversation holds at most one row, upserted on its
+//! `conversation_id` primary key. Unset bounds are stored as NULL.
+
+use std::num::NonZeroU32;
+
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::models::PeriodicSnapshotPolicyRow,
+    adapters::schema::{conversations, periodic_snapshot_policies},
+    domain::{ConversationId, PeriodicSnapshotPolicy, SnapshotInterval},
+    ports::snapshot_policy::{SnapshotPolicyError, SnapshotPolicyRepository, SnapshotPolicyResult},
+};
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+
+impl FromTxError<Self> for SnapshotPolicyError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL` implementation of [`SnapshotPolicyRepository`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access.
+#[derive(Debug, Clone)]
+pub struct PostgresSnapshotPolicyRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresSnapshotPolicyRepository,
+    "snapshot_policy_repository"
+);
+
+impl PostgresSnapshotPolicyRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn write<F, T>(&self, ctx: &RequestContext, write_fn: F) -> SnapshotPolicyResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> SnapshotPolicyResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, SnapshotPolicyError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid)
+                        .map_err(SnapshotPolicyError::persistence)?;
+                    write_fn(tx)
+                })
+            },
+            SnapshotPolicyError::persistence,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl SnapshotPolicyRepository for PostgresSnapshotPolicyRepository {
+    async fn set_policy(
+        &self,
+        ctx: &RequestContext,
+        policy: &PeriodicSnapshotPolicy,
+    ) -> SnapshotPolicyResult<()> {
+        let conversation_id = policy.conversation_id;
+        let row = policy_to_row(policy, ctx.tenant_id().into_inner());
+        self.write(ctx, move |tx| {
+            let exists = diesel::select(diesel::dsl::exists(
+                conversations::table
+                    .filter(conversations::id.eq(row.conversation_id))
+                    .filter(conversations::tenant_id.eq(row.tenant_id)),
+            ))
+            .get_result::<bool>(tx)
+            .map_err(SnapshotPolicyError::persistence)?;
+            if !exists {
+                return Err(SnapshotPolicyError::ConversationNotFound(conversation_id));
+            }
+            diesel::insert_into(periodic_snapshot_policies::table)
+                .values(&row)
+                .on_conflict(periodic_snapshot_policies::conversation_id)
+                .do_update()
+                .set(&row)
+                .execute(tx)
+                .map(|_| ())
+                .map_err(SnapshotPolicyError::persistence)
+        })
+        .await
+    }
+
+    async fn clear_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> SnapshotPolicyResult<bool> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.write(ctx, move |tx| {
+            diesel::delete(
+                periodic_snapshot_policies::table
+                    .filter(periodic_snapshot_policies::tenant_id.eq(tenant_uuid))
+                    .filter(
+                        periodic_snapshot_policies::conversation_id
+                            .eq(conversation_id.into_inner()),
+                    ),
+            )
+            .execute(tx)
+            .map(|deleted| deleted > 0)
+            .map_err(SnapshotPolicyError::persistence)
+        })
+        .await
+    }
+
+    async fn find_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> SnapshotPolicyResult<Option<PeriodicSnapshotPolicy>> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, SnapshotPolicyError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    periodic_snapshot_policies::table
+                        .filter(periodic_snapshot_policies::tenant_id.eq(tenant_uuid))
+                        .filter(
+                            periodic_snapshot_policies::conversation_id
+                                .eq(conversation_id.into_inner()),
+                        )
+                        .select(PeriodicSnapshotPolicyRow::as_select())
+                        .first(tx)
+                        .optional()
+                        .map_err(SnapshotPolicyError::persistence)
+                })
+            },
+            SnapshotPolicyError::persistence,
+        )
+        .await?;
+        row.map(row_to_policy).transpose()
+    }
+}
+
+fn policy_to_row(policy: &PeriodicSnapshotPolicy, tenant_id: Uuid) -> PeriodicSnapshotPolicyRow {
+    let bound = |every: Option<NonZeroU32>| {
+        every.map(|value| i32::try_from(value.get()).unwrap_or(i32::MAX))
+    };
+    PeriodicSnapshotPolicyRow {
+        conversation_id: policy.conversation_id.into_inner(),
+        tenant_id,
+        every_messages: bound(policy.interval.every_messages),
+        every_minutes: bound(policy.interval.every_minutes),
+    }
+}
+
+fn row_to_policy(row: PeriodicSnapshotPolicyRow) -> SnapshotPolicyResult<PeriodicSnapshotPolicy> {
+    let bound = |column: &str, value: Option<i32>| {
+        value
+            .map(|stored| {
+                u32::try_from(stored)
+                    .ok()
+                    .and_then(NonZeroU32::new)
+                    .ok_or_else(|| {
+                        SnapshotPolicyError::InvalidPersistedData(format!(
+                            "snapshot policy for conversation {} has {column} {stored}",
+                            row.conversation_id
+                        ))
+                    })
+            })
+            .transpose()
+    };
+    let interval = SnapshotInterval {
+        every_messages: bound("every_messages", row.every_messages)?,
+        every_minutes: bound("every_minutes", row.every_minutes)?,
+    };
+    Ok(PeriodicSnapshotPolicy::new(
+        ConversationId::from_uuid(row.conversation_id),
+        interval,
+    ))
+}
@@ -1,15 +1,22 @@
 //! SQL execution helpers for `PostgreSQL` repository.
 //!
-//! Contains database insert operations, constraint error mapping, and
+//! Contains single and batch database insert operations, constraint error mapping, and
 //! session variable management for audit context.
 
+use std::collections::HashSet;
+
 use diesel::prelude::*;
+use uuid::Uuid;
 
 use super::super::audit_context::AuditContext;
+use super::super::batch::{BatchKey, find_batch_conflicts};
 use super::super::models::NewMessage;
 use super::super::schema::messages;
+use super::conversation_guard::{conversation_is_archived, lock_conversation_for_append};
+use super::conversion_helpers::ser_err;
+use crate::context::RequestContext;
 use crate::message::{
-    domain::{ConversationId, MessageId, SequenceNumber},
+    domain::{ConversationId, ConversationState, MessageId, SequenceNumber},
     error::RepositoryError,
     ports::repository::RepositoryResult,
 };
@@ -23,15 +30,20 @@ pub(super) struct InsertIds {
 
 /// Inserts a message into the database.
 ///
-/// Maps constraint violations to semantic error types when possible.
+/// Rejects the insert with [`RepositoryError::ConversationArchived`] when the
+/// conversation is archived. Maps constraint violations to semantic error types when possible.
 /// Pre-checks in `store()` should catch most duplicates with proper IDs,
 /// but this handles race conditions where the constraint catches duplicates
 /// that slipped past the pre-check.
 pub(super) fn insert_message(
     conn: &mut PgConnection,
+    ctx: &RequestContext,
     new_message: &NewMessage,
     ids: &InsertIds,
 ) -> RepositoryResult<()> {
+    if conversation_is_archived(conn, ctx, ids.conv_id)? {
+        return Err(RepositoryError::ConversationArchived(ids.conv_id));
+    }
     diesel::insert_into(messages::table)
         .values(new_message)
         .execute(conn)
@@ -39,12 +51,138 @@ pub(super) fn insert_message(
     Ok(())
 }
 
+/// Appends a message at the next free sequence number of its conversation.
+///
+/// The conversation row is locked before the sequence number is read, so
+/// concurrent appends to one conversation allocate numbers one at a time
+/// and never collide. Returns the allocated sequence number.
+pub(super) fn append_message(
+    conn: &mut PgConnection,
+    ctx: &RequestContext,
+    mut new_message: NewMessage,
+    msg_id: MessageId,
+) -> RepositoryResult<SequenceNumber> {
+    let conv_id = ConversationId::from_uuid(new_message.conversation_id);
+    match lock_conversation_for_append(conn, ctx, conv_id)?.as_deref() {
+        None => return Err(RepositoryError::ConversationNotFound(conv_id)),
+        Some(state) if state == ConversationState::Archived.as_str() => {
+            return Err(RepositoryError::ConversationArchived(conv_id));
+        }
+        Some(_) => {}
+    }
+    let last: Option<i64> = messages::table
+        .filter(messages::tenant_id.eq(ctx.tenant_id().into_inner()))
+        .filter(messages::conversation_id.eq(new_message.conversation_id))
+        .select(diesel::dsl::max(messages::sequence_number))
+        .first(conn)?;
+    let next = last.unwrap_or(0).checked_add(1).ok_or_else(|| {
+        RepositoryError::serialization("sequence number overflow: maximum i64 reached")
+    })?;
+    new_message.sequence_number = next;
+    let ids = InsertIds {
+        msg_id,
+        conv_id,
+        seq_num: SequenceNumber::try_new(u64::try_from(next).map_err(ser_err)?)?,
+    };
+    diesel::insert_into(messages::table)
+        .values(&new_message)
+        .execute(conn)
+        .map_err(|e| map_insert_error(e, &ids))?;
+    Ok(ids.seq_num)
+}
+
+/// Most rows sent in one multi-row `INSERT`, keeping each statement under
+/// the `PostgreSQL` limit of 65,535 bind parameters.
+pub(super) const MAX_ROWS_PER_INSERT: usize = 4096;
+
+/// Inserts a batch of messages with multi-row inserts in one transaction.
+///
+/// Rejects the whole batch with [`RepositoryError::ConversationArchived`]
+/// when any conversation is archived, or with
+/// [`RepositoryError::BatchConflicts`] when any ID or sequence number is
+/// already stored or repeated within the batch. Duplicates inserted by a
+/// concurrent writer after the pre-check surface as database errors, since
+/// the constraint violation does not identify the conflicting row.
+pub(super) fn insert_message_batch(
+    conn: &mut PgConnection,
+    ctx: &RequestContext,
+    keys: &[BatchKey],
+    rows: &[NewMessage],
+) -> RepositoryResult<()> {
+    let conversation_ids: HashSet<ConversationId> =
+        keys.iter().map(|key| key.conversation_id).collect();
+    for conversation_id in &conversation_ids {
+        if conversation_is_archived(conn, ctx, *conversation_id)? {
+            return Err(RepositoryError::ConversationArchived(*conversation_id));
+        }
+    }
+
+    let stored_ids = load_stored_ids(conn, rows)?;
+    let stored_sequences = load_stored_sequences(conn, rows)?;
+    let conflicts = find_batch_conflicts(
+        keys,
+        |id| stored_ids.contains(&id.into_inner()),
+        |conversation_id, sequence| {
+            stored_sequences.contains(&(conversation_id.into_inner(), sequence.value()))
+        },
+    );
+    if !conflicts.is_empty() {
+        return Err(RepositoryError::BatchConflicts(conflicts));
+    }
+
+    for chunk in rows.chunks(MAX_ROWS_PER_INSERT) {
+        diesel::insert_into(messages::table)
+            .values(chunk)
+            .execute(conn)
+            .map_err(RepositoryError::database)?;
+    }
+    Ok(())
+}
+
+/// Returns which of the batch's message IDs are already stored.
+fn load_stored_ids(
+    conn: &mut PgConnection,
+    rows: &[NewMessage],
+) -> RepositoryResult<HashSet<Uuid>> {
+    let ids: Vec<Uuid> = rows.iter().map(|row| row.id).collect();
+    let stored = messages::table
+        .filter(messages::id.eq_any(ids))
+        .select(messages::id)
+        .load::<Uuid>(conn)
+        .map_err(RepositoryError::database)?;
+    Ok(stored.into_iter().collect())
+}
+
+/// Returns the stored `(conversation, sequence)` pairs that could clash
+/// with the batch.
+fn load_stored_sequences(
+    conn: &mut PgConnection,
+    rows: &[NewMessage],
+) -> RepositoryResult<HashSet<(Uuid, u64)>> {
+    let conversation_ids: Vec<Uuid> = rows.iter().map(|row| row.conversation_id).collect();
+    let sequences: Vec<i64> = rows.iter().map(|row| row.sequence_number).collect();
+    let stored = messages::table
+        .filter(messages::conversation_id.eq_any(conversation_ids))
+        .filter(messages::sequence_number.eq_any(sequences))
+        .select((messages::conversation_id, messages::sequence_number))
+        .load::<(Uuid, i64)>(conn)
+        .map_err(RepositoryError::database)?;
+    stored
+        .into_iter()
+        .map(|(conversation_id, sequence)| {
+            u64::try_from(sequence)
+                .map(|value| (conversation_id, value))
+                .map_err(ser_err)
+        })
+        .collect()
+}
+
 /// Maps Diesel errors to semantic repository errors.
 ///
 /// Inspects unique constraint violations to determine if they represent
 /// duplicate message IDs or duplicate sequence numbers, returning the
 /// appropriate error variant with the relevant identifiers.
-fn map_insert_error(err: diesel::result::Error, ids: &InsertIds) -> RepositoryError {
+pub(super) fn map_insert_error(err: diesel::result::Error, ids: &InsertIds) -> RepositoryError {
     use diesel::result::DatabaseErrorKind;
     let diesel::result::Error::DatabaseError(DatabaseErrorKind::UniqueViolation, info) = &err
     else {
@@ -88,13 +226,11 @@ fn map_constraint_to_duplicate_error(constraint: &str, ids: &InsertIds) -> Optio
 /// UUID values are formatted using their canonical hyphenated representation
 /// which contains only hexadecimal digits and hyphens, making SQL injection
 /// impossible.
-fn set_session_uuid(conn: &mut PgConnection, key: &str, value: uuid::Uuid) -> RepositoryResult<()> {
+fn set_session_uuid(conn: &mut PgConnection, key: &str, value: uuid::Uuid) -> QueryResult<()> {
     // PostgreSQL SET does not support parameter binding ($1).
     // UUID hyphenated format (xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx) contains
     // only hex digits and hyphens, so it is safe to interpolate directly.
-    diesel::sql_query(format!("SET LOCAL app.{key} = '{value}'"))
-        .execute(conn)
-        .map_err(RepositoryError::database)?;
+    diesel::sql_query(format!("SET LOCAL app.{key} = '{value}'")).execute(conn)?;
     Ok(())
 }
 
@@ -103,21 +239,49 @@ fn set_session_uuid(conn: &mut PgConnection, key: &str, value: uuid::Uuid) -> Re
 /// Each audit field is set via [`set_session_uuid`], which interpolates UUID
 /// values directly into the SET statement. This is safe because UUID formatting
 /// produces only hexadecimal digits and hyphens, preventing SQL injection.
-pub(super) fn set_audit_context(
+pub(crate) fn set_audit_context(
     conn: &mut PgConnection,
     audit: &AuditContext,
 ) -> RepositoryResult<()> {
-    if let Some(correlation_id) = audit.correlation_id {
-        set_session_uuid(conn, "correlation_id", correlation_id)?;
-    }
-    if let Some(causation_id) = audit.causation_id {
-        set_session_uuid(conn, "causation_id", causation_id)?;
-    }
-    if let Some(user_id) = audit.user_id {
-        set_session_uuid(conn, "user_id", user_id)?;
-    }
-    if let Some(session_id) = audit.session_id {
-        set_session_uuid(conn, "session_id", session_id)?;
+    apply_audit_context(conn, audit).map_err(RepositoryError::database)
+}
+
+/// Sets the session variables of every identifier present in `audit`.
+///
+/// Variables are set with `SET LOCAL`, so they end with the transaction and
+/// a later call overrides an earlier one.
+pub(crate) fn apply_audit_context(
+    conn: &mut PgConnection,
+    audit: &AuditContext,
+) -> QueryResult<()> {
+    let fields = [
+        ("correlation_id", audit.correlation_id),
+        ("causation_id", audit.causation_id),
+        ("user_id", audit.user_id),
+        ("session_id", audit.session_id),
+    ];
+    for (key, value) in fields {
+        if let Some(id) = value {
+            set_session_uuid(conn, key, id)?;
+        }
     }
     Ok(())
 }
+
+/// Clears the row values the audit trigger copied from rows of `table_name`.
+///
+/// The audit rows themselves are kept, so the trail still shows who changed
+/// what and when. Returns the number of audit rows cleared.
+pub(crate) fn scrub_audit_values(
+    conn: &mut PgConnection,
+    table_name: &str,
+    row_ids: &[Uuid],
+) -> QueryResult<usize> {
+    diesel::sql_query(
+        "UPDATE audit_logs SET old_values = NULL, new_values = NULL \
+         WHERE table_name = $1 AND row_id = ANY($2)",
+    )
+    .bind::<diesel::sql_types::Text, _>(table_name)
+    .bind::<diesel::sql_types::Array<diesel::sql_types::Uuid>, _>(row_ids.to_vec())
+    .execute(conn)
+}
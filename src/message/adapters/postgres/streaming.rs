@@ -0,0 +1,300 @@
+//! `PostgreSQL` implementation of the `PartialMessageRepository` port.
+//!
+//! Chunk updates are guarded by the stored chunk count, so a writer holding
+//! a stale copy of the stream changes nothing. Finalising deletes the staged
+//! row and appends the message in one transaction, allocating the sequence
+//! number under the same conversation lock as [`MessageRepository::append`].
+//!
+//! [`MessageRepository::append`]: crate::message::ports::repository::MessageRepository::append
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use serde_json::Value;
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::sealing::MessageCodec;
+use super::sql_helpers::append_message;
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::{
+        models::{NewMessage, PartialMessageRow},
+        schema::partial_messages,
+    },
+    domain::{ConversationId, Message, MessageId, MessageMetadata, PartialMessage},
+    ports::{
+        content_cipher::ContentCipher,
+        streaming::{PartialMessageRepository, StreamingError, StreamingResult},
+    },
+};
+
+impl FromTxError<Self> for StreamingError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL` implementation of [`PartialMessageRepository`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access.
+#[derive(Debug, Clone)]
+pub struct PostgresPartialMessageRepository {
+    pool: PgPool,
+    codec: MessageCodec,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresPartialMessageRepository,
+    "partial_message_repository"
+);
+
+impl PostgresPartialMessageRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            codec: MessageCodec::plain(),
+        }
+    }
+
+    /// Encrypts staged streams and finalised messages with `cipher`, as
+    /// [`PostgresMessageRepository::with_cipher`] does.
+    ///
+    /// [`PostgresMessageRepository::with_cipher`]: super::PostgresMessageRepository::with_cipher
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Arc<dyn ContentCipher>) -> Self {
+        self.codec = MessageCodec::sealed(cipher);
+        self
+    }
+
+    async fn write<F, T>(&self, ctx: &RequestContext, write_fn: F) -> StreamingResult<T>
+    where
+        F: FnOnce(&mut PgConnection, uuid::Uuid) -> StreamingResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, StreamingError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid).map_err(StreamingError::persistence)?;
+                    write_fn(tx, tenant_uuid)
+                })
+            },
+            StreamingError::persistence,
+        )
+        .await
+    }
+
+    async fn read_rows<F>(
+        &self,
+        ctx: &RequestContext,
+        query_fn: F,
+    ) -> StreamingResult<Vec<PartialMessage>>
+    where
+        F: FnOnce(&mut PgConnection, uuid::Uuid) -> QueryResult<Vec<PartialMessageRow>>
+            + Send
+            + 'static,
+    {
+        let pool = self.pool.clone();
+        let codec = self.codec.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let rows = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, StreamingError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    query_fn(tx, tenant_uuid).map_err(StreamingError::persistence)
+                })
+            },
+            StreamingError::persistence,
+        )
+        .await?;
+        rows.into_iter()
+            .map(|row| row_to_partial(codec.open_partial(row)?))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl PartialMessageRepository for PostgresPartialMessageRepository {
+    async fn start(&self, ctx: &RequestContext, partial: &PartialMessage) -> StreamingResult<()> {
+        let row = self
+            .codec
+            .seal_partial(to_row(partial, ctx.tenant_id().into_inner())?)?;
+        let message_id = partial.message_id;
+        self.write(ctx, move |conn, _| {
+            diesel::insert_into(partial_messages::table)
+                .values(&row)
+                .execute(conn)
+                .map(|_| ())
+                .map_err(|err| match err {
+                    DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                        StreamingError::AlreadyStarted(message_id)
+                    }
+                    other => StreamingError::persistence(other),
+                })
+        })
+        .await
+    }
+
+    async fn update(
+        &self,
+        ctx: &RequestContext,
+        partial: &PartialMessage,
+        expected_chunk_count: u32,
+    ) -> StreamingResult<()> {
+        let row = self
+            .codec
+            .seal_partial(to_row(partial, ctx.tenant_id().into_inner())?)?;
+        let expected = i32::try_from(expected_chunk_count).map_err(StreamingError::persistence)?;
+        let message_id = partial.message_id;
+        self.write(ctx, move |conn, tenant_uuid| {
+            let stream = partial_messages::table
+                .filter(partial_messages::tenant_id.eq(tenant_uuid))
+                .filter(partial_messages::message_id.eq(row.message_id));
+            let updated = diesel::update(stream.filter(partial_messages::chunk_count.eq(expected)))
+                .set((
+                    partial_messages::metadata.eq(&row.metadata),
+                    partial_messages::content.eq(&row.content),
+                    partial_messages::chunk_count.eq(row.chunk_count),
+                    partial_messages::updated_at.eq(row.updated_at),
+                ))
+                .execute(conn)
+                .map_err(StreamingError::persistence)?;
+            if updated > 0 {
+                return Ok(());
+            }
+            let staged: i64 = stream
+                .count()
+                .get_result(conn)
+                .map_err(StreamingError::persistence)?;
+            Err(if staged == 0 {
+                StreamingError::NotFound(message_id)
+            } else {
+                StreamingError::Conflict(message_id)
+            })
+        })
+        .await
+    }
+
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> StreamingResult<Option<PartialMessage>> {
+        let rows = self
+            .read_rows(ctx, move |conn, tenant_uuid| {
+                partial_messages::table
+                    .filter(partial_messages::tenant_id.eq(tenant_uuid))
+                    .filter(partial_messages::message_id.eq(message_id.into_inner()))
+                    .select(PartialMessageRow::as_select())
+                    .load(conn)
+            })
+            .await?;
+        Ok(rows.into_iter().next())
+    }
+
+    async fn find_stalled(
+        &self,
+        ctx: &RequestContext,
+        updated_before: DateTime<Utc>,
+        limit: usize,
+    ) -> StreamingResult<Vec<PartialMessage>> {
+        let limit = i64::try_from(limit).map_err(StreamingError::persistence)?;
+        self.read_rows(ctx, move |conn, tenant_uuid| {
+            partial_messages::table
+                .filter(partial_messages::tenant_id.eq(tenant_uuid))
+                .filter(partial_messages::updated_at.lt(updated_before))
+                .order((
+                    partial_messages::updated_at.asc(),
+                    partial_messages::message_id.asc(),
+                ))
+                .limit(limit)
+                .select(PartialMessageRow::as_select())
+                .load(conn)
+        })
+        .await
+    }
+
+    async fn finalise(&self, ctx: &RequestContext, message: &Message) -> StreamingResult<Message> {
+        let new_message = self.codec.seal(NewMessage::try_from_domain(
+            message,
+            ctx.tenant_id().into_inner(),
+        )?)?;
+        let message_id = message.id();
+        let owned_ctx = ctx.clone();
+        let sequence = self
+            .write(ctx, move |conn, tenant_uuid| {
+                delete_stream(conn, tenant_uuid, message_id)?;
+                Ok(append_message(conn, &owned_ctx, new_message, message_id)?)
+            })
+            .await?;
+        Ok(message.clone().with_sequence_number(sequence))
+    }
+
+    async fn discard(&self, ctx: &RequestContext, message_id: MessageId) -> StreamingResult<()> {
+        self.write(ctx, move |conn, tenant_uuid| {
+            delete_stream(conn, tenant_uuid, message_id)
+        })
+        .await
+    }
+}
+
+fn delete_stream(
+    conn: &mut PgConnection,
+    tenant_uuid: uuid::Uuid,
+    message_id: MessageId,
+) -> StreamingResult<()> {
+    let deleted = diesel::delete(
+        partial_messages::table
+            .filter(partial_messages::tenant_id.eq(tenant_uuid))
+            .filter(partial_messages::message_id.eq(message_id.into_inner())),
+    )
+    .execute(conn)
+    .map_err(StreamingError::persistence)?;
+    if deleted == 0 {
+        return Err(StreamingError::NotFound(message_id));
+    }
+    Ok(())
+}
+
+fn to_row(partial: &PartialMessage, tenant_id: uuid::Uuid) -> StreamingResult<PartialMessageRow> {
+    Ok(PartialMessageRow {
+        tenant_id,
+        message_id: partial.message_id.into_inner(),
+        conversation_id: partial.conversation_id.into_inner(),
+        metadata: serde_json::to_value(&partial.metadata).map_err(StreamingError::persistence)?,
+        content: Value::String(partial.content.clone()),
+        chunk_count: i32::try_from(partial.chunk_count).map_err(StreamingError::persistence)?,
+        started_at: partial.started_at,
+        updated_at: partial.updated_at,
+    })
+}
+
+fn row_to_partial(row: PartialMessageRow) -> StreamingResult<PartialMessage> {
+    Ok(PartialMessage {
+        message_id: MessageId::from_uuid(row.message_id),
+        conversation_id: ConversationId::from_uuid(row.conversation_id),
+        metadata: serde_json::from_value::<MessageMetadata>(row.metadata)
+            .map_err(StreamingError::invalid_persisted_data)?,
+        content: serde_json::from_value(row.content)
+            .map_err(StreamingError::invalid_persisted_data)?,
+        chunk_count: u32::try_from(row.chunk_count)
+            .map_err(StreamingError::invalid_persisted_data)?,
+        started_at: row.started_at,
+        updated_at: row.updated_at,
+    })
+}
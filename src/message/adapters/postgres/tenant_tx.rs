@@ -2,12 +2,15 @@
 //!
 //! Provides [`with_tenant_tx`] which wraps a closure in a database transaction
 //! that first sets the `app.tenant_id` session variable, preparing the
-//! connection for Row-Level Security (RLS) policies.
+//! connection for Row-Level Security (RLS) policies, and sets the audit
+//! session variables from the ambient request context.
 //!
 //! The adapter-local [`TxError`] wrapper satisfies Diesel's
 //! `From<diesel::result::Error>` bound on [`PgConnection::transaction`] without
 //! leaking Diesel types into the port layer.
 
+use super::sql_helpers::apply_audit_context;
+use crate::message::adapters::audit_context::AuditContext;
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
 use uuid::Uuid;
@@ -38,6 +41,12 @@ pub(crate) trait FromTxError<E> {
 
 /// Runs `body` inside a transaction that first sets `app.tenant_id`.
 ///
+/// When the calling task runs inside
+/// [`RequestContext::scope`](crate::context::RequestContext::scope), the
+/// audit session variables are set from the ambient context too, so the
+/// audit trigger attributes every write. Bodies that set an explicit
+/// [`AuditContext`] override them.
+///
 /// **IMPORTANT**: This function does NOT ensure the tenant exists. Callers
 /// performing write operations MUST call `ensure_tenant_exists` explicitly
 /// before calling this function to guarantee a valid foreign key target exists.
@@ -60,6 +69,7 @@ where
 {
     conn.transaction::<T, TxError<E>, _>(|tx| {
         set_tenant_context(tx, tenant_id)?;
+        apply_audit_context(tx, &AuditContext::from_current())?;
         body(tx).map_err(TxError::Domain)
     })
     .map_err(E::from_tx_error)
@@ -107,6 +117,15 @@ pub(crate) fn ensure_tenant_exists(
     Ok(())
 }
 
+/// Inserts a placeholder tenant row unless the tenant already exists.
+///
+/// Binds the tenant identifier, slug, and display name, in that order.
+pub(crate) const BOOTSTRAP_TENANT_SQL: &str = concat!(
+    "INSERT INTO tenants (id, slug, name, status, created_at, updated_at) ",
+    "VALUES ($1, $2, $3, 'active', NOW(), NOW()) ",
+    "ON CONFLICT (id) DO NOTHING",
+);
+
 /// Inserts a placeholder tenant row if one does not already exist.
 ///
 /// This is the single source of truth for lazy tenant bootstrapping. The
@@ -128,15 +147,11 @@ pub(crate) fn bootstrap_tenant_row(
     let tenant_slug = format!("tenant-{tenant_id}");
     let tenant_name = format!("Tenant {tenant_id}");
 
-    diesel::sql_query(concat!(
-        "INSERT INTO tenants (id, slug, name, status, created_at, updated_at) ",
-        "VALUES ($1, $2, $3, 'active', NOW(), NOW()) ",
-        "ON CONFLICT (id) DO NOTHING",
-    ))
-    .bind::<diesel::sql_types::Uuid, _>(tenant_id)
-    .bind::<diesel::sql_types::Text, _>(tenant_slug)
-    .bind::<diesel::sql_types::Text, _>(tenant_name)
-    .execute(conn)
+    diesel::sql_query(BOOTSTRAP_TENANT_SQL)
+        .bind::<diesel::sql_types::Uuid, _>(tenant_id)
+        .bind::<diesel::sql_types::Text, _>(tenant_slug)
+        .bind::<diesel::sql_types::Text, _>(tenant_name)
+        .execute(conn)
 }
 
 /// Sets the `PostgreSQL` session variable `app.tenant_id` for the current
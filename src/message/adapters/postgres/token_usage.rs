@@ -0,0 +1,45 @@
+//! SQL aggregation of per-message token counts shared by the blocking and
+//! async message repositories.
+
+use diesel::dsl::sql;
+use diesel::pg::Pg;
+use diesel::prelude::*;
+use diesel::sql_types::BigInt;
+
+use super::super::schema::messages;
+use super::conversion_helpers::ser_err;
+use crate::message::{domain::ConversationTokenUsage, error::RepositoryError};
+
+/// SQL type of a token usage row: message count, counted messages, tokens.
+pub(super) type TokenUsageSql = (BigInt, BigInt, BigInt);
+
+/// Builds the aggregation of one conversation's message token counts.
+///
+/// Counts are read from `metadata -> 'token_count'`, so messages stored
+/// before counting was enabled are reported as uncounted. Sealed metadata
+/// keeps the count beside its envelope, so encrypted messages are counted
+/// too.
+pub(super) fn token_usage_query(
+    tenant_uuid: uuid::Uuid,
+    conversation_uuid: uuid::Uuid,
+) -> messages::BoxedQuery<'static, Pg, TokenUsageSql> {
+    messages::table
+        .filter(messages::tenant_id.eq(tenant_uuid))
+        .filter(messages::conversation_id.eq(conversation_uuid))
+        .select(sql::<TokenUsageSql>(
+            "COUNT(*), COUNT(messages.metadata -> 'token_count'), \
+             COALESCE(SUM((messages.metadata -> 'token_count' ->> 'tokens')::BIGINT), 0)::BIGINT",
+        ))
+        .into_boxed()
+}
+
+/// Converts an aggregation row into domain usage.
+pub(super) fn row_to_token_usage(
+    (message_count, counted_messages, tokens): (i64, i64, i64),
+) -> Result<ConversationTokenUsage, RepositoryError> {
+    Ok(ConversationTokenUsage {
+        message_count: u64::try_from(message_count).map_err(ser_err)?,
+        counted_messages: u64::try_from(counted_messages).map_err(ser_err)?,
+        tokens: u64::try_from(tokens).map_err(ser_err)?,
+    })
+}
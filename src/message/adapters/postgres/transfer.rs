@@ -0,0 +1,394 @@
+//! `PostgreSQL` implementation of the `ConversationTransferRepository` port.
+//!
+//! Rows that belong to a conversation reference it, and each other, through
+//! composite `(id, tenant_id)` foreign keys. Those keys are deferrable, so
+//! the transfer defers them, rewrites `tenant_id` on the conversation and
+//! every table in
+//! [`CONVERSATION_TABLES`](PostgresConversationTransferRepository::CONVERSATION_TABLES),
+//! and lets the keys be checked when the transaction commits. Both operator actions are inserted in the
+//! same transaction, so a transfer is recorded exactly when it happens.
+//!
+//! Attachment blobs are kept per tenant. With a blob store configured, the
+//! conversation's blobs are copied to the target tenant before the rows
+//! move, and afterwards deleted from the source tenant unless its other
+//! messages still refer to them. A failed transfer can leave copies behind
+//! in the target tenant, but never a moved message without its blobs.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::sql_types::{Text, Uuid as SqlUuid};
+use uuid::Uuid;
+
+use super::blob_references::{
+    conversation_blobs_query, delete_blobs, load_blobs, unreferenced_blobs_query,
+};
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::sql_helpers::set_audit_context;
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    adapters::{audit_context::AuditContext, schema::conversations},
+    domain::{ContentHash, ConversationId, ConversationTransfer},
+    ports::{
+        blob_store::BlobStore,
+        transfer::{
+            ConversationTransferError, ConversationTransferRepository, ConversationTransferResult,
+        },
+    },
+};
+use crate::operator::adapters::postgres::insert_action;
+use crate::task::adapters::postgres::schema::{conversation_budgets, task_conversation_links};
+use crate::tenant::domain::TenantStatus;
+
+impl FromTxError<Self> for ConversationTransferError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+#[derive(QueryableByName)]
+struct TenantStatusRow {
+    #[diesel(sql_type = Text)]
+    status: String,
+}
+
+/// `PostgreSQL` implementation of [`ConversationTransferRepository`].
+///
+/// Target tenants are provisioned on first use, as for every other
+/// tenant-scoped write.
+#[derive(Clone)]
+pub struct PostgresConversationTransferRepository {
+    pool: PgPool,
+    blobs: Option<Arc<dyn BlobStore>>,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresConversationTransferRepository,
+    "conversation_transfer_repository"
+);
+
+impl PostgresConversationTransferRepository {
+    /// Tables whose rows belong to a conversation, keyed by
+    /// `conversation_id`, and move with it.
+    ///
+    /// Task links and budgets are not listed: conversations holding either
+    /// cannot be transferred. Operator actions, agent turn sessions, and
+    /// hook policy audit events stay with the tenant that recorded them.
+    pub const CONVERSATION_TABLES: &'static [&'static str] = &[
+        "messages",
+        "agent_sessions",
+        "agent_turn_callbacks",
+        "agent_turn_outcomes",
+        "handoffs",
+        "handoff_context_packages",
+        "context_assembly_reports",
+        "context_snapshots",
+        "conversation_forks",
+        "conversation_label_events",
+        "conversation_retention_policies",
+        "conversation_summaries",
+        "conversation_rolling_summaries",
+        "experiment_observations",
+        "message_feedback",
+        "message_processing_stages",
+        "message_redactions",
+        "partial_messages",
+        "periodic_snapshot_policies",
+        "slash_command_executions",
+        "turns",
+        "usage_records",
+    ];
+
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool, blobs: None }
+    }
+
+    /// Moves the conversation's attachment blobs in `blobs` along with it.
+    ///
+    /// Use the store the message repository externalises attachments to.
+    #[must_use]
+    pub fn with_blob_store(mut self, blobs: Arc<dyn BlobStore>) -> Self {
+        self.blobs = Some(blobs);
+        self
+    }
+
+    /// Copies the conversation's blobs to the target tenant, returning
+    /// their hashes.
+    async fn copy_blobs(
+        &self,
+        ctx: &RequestContext,
+        transfer: &ConversationTransfer,
+    ) -> ConversationTransferResult<Vec<ContentHash>> {
+        let Some(blobs) = &self.blobs else {
+            return Ok(Vec::new());
+        };
+        let source = transfer.source_tenant_id.into_inner();
+        let conversation = transfer.conversation_id.into_inner();
+        let hashes = self
+            .read_blobs(source, move |conn| {
+                load_blobs(conn, conversation_blobs_query(source, conversation))
+            })
+            .await?;
+        let target = RequestContext::new(
+            transfer.target_tenant_id,
+            ctx.correlation_id(),
+            ctx.user_id(),
+            ctx.session_id(),
+        );
+        for hash in &hashes {
+            let Some(data) = blobs
+                .get(ctx, hash)
+                .await
+                .map_err(ConversationTransferError::persistence)?
+            else {
+                continue;
+            };
+            blobs
+                .put(&target, hash, data)
+                .await
+                .map_err(ConversationTransferError::persistence)?;
+        }
+        Ok(hashes)
+    }
+
+    /// Deletes the copied blobs the source tenant no longer refers to.
+    async fn release_blobs(
+        &self,
+        ctx: &RequestContext,
+        hashes: Vec<ContentHash>,
+    ) -> ConversationTransferResult<()> {
+        let Some(blobs) = &self.blobs else {
+            return Ok(());
+        };
+        if hashes.is_empty() {
+            return Ok(());
+        }
+        let source = ctx.tenant_id().into_inner();
+        let unreferenced = self
+            .read_blobs(source, move |conn| {
+                load_blobs(conn, unreferenced_blobs_query(source, &hashes))
+            })
+            .await?;
+        delete_blobs(blobs.as_ref(), ctx, &unreferenced)
+            .await
+            .map_err(ConversationTransferError::persistence)
+    }
+
+    async fn read_blobs<F>(
+        &self,
+        tenant_id: Uuid,
+        query_fn: F,
+    ) -> ConversationTransferResult<Vec<ContentHash>>
+    where
+        F: FnOnce(&mut PgConnection) -> QueryResult<Vec<ContentHash>> + Send + 'static,
+    {
+        let pool = self.pool.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ConversationTransferError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_id, |tx| {
+                    query_fn(tx).map_err(ConversationTransferError::persistence)
+                })
+            },
+            ConversationTransferError::persistence,
+        )
+        .await
+    }
+
+    async fn move_conversation(
+        &self,
+        ctx: &RequestContext,
+        transfer: &ConversationTransfer,
+    ) -> ConversationTransferResult<()> {
+        let pool = self.pool.clone();
+        let audit = AuditContext::from(ctx);
+        let transfer = transfer.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ConversationTransferError::persistence)?;
+                let source = transfer.source_tenant_id.into_inner();
+                with_tenant_tx(&mut conn, source, |tx| {
+                    set_audit_context(tx, &audit)
+                        .map_err(ConversationTransferError::persistence)?;
+                    ensure_transferable(tx, &transfer)?;
+                    move_rows(tx, &transfer)?;
+                    insert_action(tx, &transfer.outgoing, source)
+                        .map_err(ConversationTransferError::persistence)?;
+                    insert_action(
+                        tx,
+                        &transfer.incoming,
+                        transfer.target_tenant_id.into_inner(),
+                    )
+                    .map_err(ConversationTransferError::persistence)
+                })
+            },
+            ConversationTransferError::persistence,
+        )
+        .await
+    }
+}
+
+impl std::fmt::Debug for PostgresConversationTransferRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PostgresConversationTransferRepository")
+            .field("blobs", &self.blobs.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl ConversationTransferRepository for PostgresConversationTransferRepository {
+    async fn transfer(
+        &self,
+        ctx: &RequestContext,
+        transfer: &ConversationTransfer,
+    ) -> ConversationTransferResult<()> {
+        let hashes = self.copy_blobs(ctx, transfer).await?;
+        self.move_conversation(ctx, transfer).await?;
+        self.release_blobs(ctx, hashes).await
+    }
+}
+
+/// Locks the conversation and refuses transfers the target cannot accept.
+fn ensure_transferable(
+    conn: &mut PgConnection,
+    transfer: &ConversationTransfer,
+) -> ConversationTransferResult<()> {
+    let conversation_id = transfer.conversation_id;
+    let conversation_uuid = conversation_id.into_inner();
+    let source = transfer.source_tenant_id.into_inner();
+    let direct_task = conversations::table
+        .filter(conversations::id.eq(conversation_uuid))
+        .filter(conversations::tenant_id.eq(source))
+        .select(conversations::task_id)
+        .for_update()
+        .first::<Option<Uuid>>(conn)
+        .optional()
+        .map_err(ConversationTransferError::persistence)?
+        .ok_or(ConversationTransferError::NotFound(conversation_id))?;
+    let linked_task = direct_task.map_or_else(
+        || linked_task_id(conn, source, conversation_uuid),
+        |task_id| Ok(Some(task_id)),
+    )?;
+    if let Some(task_id) = linked_task {
+        return Err(ConversationTransferError::LinkedToTask {
+            conversation_id,
+            task_id,
+        });
+    }
+    if is_budgeted(conn, source, conversation_uuid)? {
+        return Err(ConversationTransferError::Budgeted(conversation_id));
+    }
+    ensure_target_active(conn, transfer.target_tenant_id)
+}
+
+fn linked_task_id(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    conversation_id: Uuid,
+) -> ConversationTransferResult<Option<Uuid>> {
+    task_conversation_links::table
+        .filter(task_conversation_links::tenant_id.eq(tenant_id))
+        .filter(
+            task_conversation_links::conversation_id
+                .eq(conversation_id)
+                .or(task_conversation_links::parent_conversation_id.eq(conversation_id)),
+        )
+        .select(task_conversation_links::task_id)
+        .first::<Uuid>(conn)
+        .optional()
+        .map_err(ConversationTransferError::persistence)
+}
+
+fn is_budgeted(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    conversation_id: Uuid,
+) -> ConversationTransferResult<bool> {
+    let budgets: i64 = conversation_budgets::table
+        .filter(conversation_budgets::tenant_id.eq(tenant_id))
+        .filter(
+            conversation_budgets::conversation_id
+                .eq(conversation_id)
+                .or(conversation_budgets::parent_conversation_id.eq(conversation_id)),
+        )
+        .count()
+        .get_result(conn)
+        .map_err(ConversationTransferError::persistence)?;
+    Ok(budgets > 0)
+}
+
+fn ensure_target_active(
+    conn: &mut PgConnection,
+    target: TenantId,
+) -> ConversationTransferResult<()> {
+    let target_uuid = target.into_inner();
+    ensure_tenant_exists(conn, target_uuid).map_err(ConversationTransferError::persistence)?;
+    let row = diesel::sql_query("SELECT status FROM tenants WHERE id = $1")
+        .bind::<SqlUuid, _>(target_uuid)
+        .get_result::<TenantStatusRow>(conn)
+        .map_err(ConversationTransferError::persistence)?;
+    if row.status == TenantStatus::Suspended.as_str() {
+        return Err(ConversationTransferError::TargetTenantSuspended(target));
+    }
+    Ok(())
+}
+
+/// Rewrites the tenant of the conversation and the rows that belong to it.
+fn move_rows(
+    conn: &mut PgConnection,
+    transfer: &ConversationTransfer,
+) -> ConversationTransferResult<()> {
+    let ConversationTransfer {
+        conversation_id,
+        source_tenant_id,
+        target_tenant_id,
+        ..
+    } = *transfer;
+    let (source, target) = (source_tenant_id.into_inner(), target_tenant_id.into_inner());
+    diesel::sql_query("SET CONSTRAINTS ALL DEFERRED")
+        .execute(conn)
+        .map_err(ConversationTransferError::persistence)?;
+    diesel::update(
+        conversations::table
+            .filter(conversations::id.eq(conversation_id.into_inner()))
+            .filter(conversations::tenant_id.eq(source)),
+    )
+    .set((
+        conversations::tenant_id.eq(target),
+        conversations::version.eq(conversations::version + 1),
+    ))
+    .execute(conn)
+    .map_err(ConversationTransferError::persistence)?;
+    for table in PostgresConversationTransferRepository::CONVERSATION_TABLES {
+        move_conversation_rows(conn, table, conversation_id, (source, target))?;
+    }
+    Ok(())
+}
+
+fn move_conversation_rows(
+    conn: &mut PgConnection,
+    table: &str,
+    conversation_id: ConversationId,
+    (source, target): (Uuid, Uuid),
+) -> ConversationTransferResult<()> {
+    diesel::sql_query(format!(
+        "UPDATE {table} SET tenant_id = $1 WHERE tenant_id = $2 AND conversation_id = $3"
+    ))
+    .bind::<SqlUuid, _>(target)
+    .bind::<SqlUuid, _>(source)
+    .bind::<SqlUuid, _>(conversation_id.into_inner())
+    .execute(conn)
+    .map(|_| ())
+    .map_err(ConversationTransferError::persistence)
+}
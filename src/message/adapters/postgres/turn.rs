@@ -0,0 +1,269 @@
+//! `PostgreSQL` implementation of the `TurnRepository` port.
+//!
+//! Each turn is one row of the `turns` table. Response message ids and tool
+//! call references are stored as JSONB arrays, and a response is appended
+//! to them in a single `UPDATE` guarded by the turn's status.
+
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use diesel::sql_types::{Jsonb, Uuid as SqlUuid};
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    adapters::models::TurnRow,
+    adapters::schema::turns,
+    domain::{
+        AgentSessionId, ConversationId, Message, MessageId, ToolCallReference, Turn, TurnId,
+        TurnStatus,
+    },
+    ports::turn::{TurnError, TurnRepository, TurnResult},
+};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
+
+use super::blocking_helpers::{PgPool, get_conn_with, run_blocking_with};
+use super::tenant_tx::{
+    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+};
+
+/// Appends a response to an in-progress turn of the message's conversation
+/// that does not already contain it.
+const RECORD_RESPONSE_SQL: &str = concat!(
+    "UPDATE turns SET response_message_ids = response_message_ids || $5, ",
+    "tool_calls = tool_calls || $6 ",
+    "WHERE tenant_id = $1 AND id = $2 AND status = 'in_progress' ",
+    "AND conversation_id = $3 AND initiating_message_id <> $4 ",
+    "AND NOT response_message_ids @> $5",
+);
+
+impl FromTxError<Self> for TurnError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL` implementation of [`TurnRepository`].
+///
+/// Uses Diesel ORM with connection pooling via r2d2. Thread-safe for
+/// concurrent access.
+#[derive(Debug, Clone)]
+pub struct PostgresTurnRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(PostgresTurnRepository, "turn_repository");
+
+impl PostgresTurnRepository {
+    /// Creates a new repository with the given connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Runs `query_fn` in a write transaction, creating the tenant row
+    /// first if needed.
+    async fn write_query<F, T>(&self, tenant_id: TenantId, query_fn: F) -> TurnResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> TurnResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let tenant_uuid = tenant_id.into_inner();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, TurnError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid).map_err(TurnError::persistence)?;
+                    query_fn(tx)
+                })
+            },
+            TurnError::persistence,
+        )
+        .await
+    }
+
+    /// Runs `query_fn` in a read-only transaction.
+    async fn read_query<F, T>(&self, tenant_id: TenantId, query_fn: F) -> TurnResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> TurnResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, TurnError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_id.into_inner(), query_fn)
+            },
+            TurnError::persistence,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl TurnRepository for PostgresTurnRepository {
+    async fn store(&self, ctx: &RequestContext, turn: &Turn) -> TurnResult<()> {
+        let tenant_id = ctx.tenant_id();
+        let row = turn_to_row(turn, tenant_id)?;
+        let turn_id = turn.turn_id;
+        self.write_query(tenant_id, move |conn| {
+            diesel::insert_into(turns::table)
+                .values(&row)
+                .execute(conn)
+                .map(|_| ())
+                .map_err(|err| match err {
+                    DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                        TurnError::Duplicate(turn_id)
+                    }
+                    other => TurnError::persistence(other),
+                })
+        })
+        .await
+    }
+
+    async fn update(&self, ctx: &RequestContext, turn: &Turn) -> TurnResult<()> {
+        let tenant_id = ctx.tenant_id();
+        let row = turn_to_row(turn, tenant_id)?;
+        let turn_id = turn.turn_id;
+        self.write_query(tenant_id, move |conn| {
+            let updated = diesel::update(
+                turns::table
+                    .filter(turns::tenant_id.eq(row.tenant_id))
+                    .filter(turns::id.eq(row.id)),
+            )
+            .set((
+                turns::status.eq(&row.status),
+                turns::ended_at.eq(row.ended_at),
+            ))
+            .execute(conn)
+            .map_err(TurnError::persistence)?;
+            if updated == 0 {
+                return Err(TurnError::NotFound(turn_id));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn record_response(
+        &self,
+        ctx: &RequestContext,
+        turn_id: TurnId,
+        message: &Message,
+    ) -> TurnResult<()> {
+        let tenant_id = ctx.tenant_id();
+        let response_ids = serde_json::to_value([message.id()]).map_err(TurnError::persistence)?;
+        let tool_calls = serde_json::to_value(Turn::response_tool_calls(message))
+            .map_err(TurnError::persistence)?;
+        let message = message.clone();
+        self.write_query(tenant_id, move |conn| {
+            let appended = diesel::sql_query(RECORD_RESPONSE_SQL)
+                .bind::<SqlUuid, _>(tenant_id.into_inner())
+                .bind::<SqlUuid, _>(turn_id.into_inner())
+                .bind::<SqlUuid, _>(message.conversation_id().into_inner())
+                .bind::<SqlUuid, _>(message.id().into_inner())
+                .bind::<Jsonb, _>(response_ids)
+                .bind::<Jsonb, _>(tool_calls)
+                .execute(conn)
+                .map_err(TurnError::persistence)?;
+            if appended > 0 {
+                return Ok(());
+            }
+            // Nothing was appended: the turn as it stands says why, or
+            // already holds the message.
+            let mut turn =
+                load_turn(conn, tenant_id, turn_id)?.ok_or(TurnError::NotFound(turn_id))?;
+            turn.record_response(&message)?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn find_by_id(&self, ctx: &RequestContext, turn_id: TurnId) -> TurnResult<Option<Turn>> {
+        let tenant_id = ctx.tenant_id();
+        self.read_query(tenant_id, move |conn| load_turn(conn, tenant_id, turn_id))
+            .await
+    }
+
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> TurnResult<Page<Turn>> {
+        let tenant_id = ctx.tenant_id();
+        let uuid = conversation_id.into_inner();
+        self.read_query(tenant_id, move |conn| {
+            let query = turns::table
+                .filter(turns::tenant_id.eq(tenant_id.into_inner()))
+                .filter(turns::conversation_id.eq(uuid))
+                .into_boxed();
+            let rows = keyset_page!(query, page, after_timestamp, (turns::started_at, turns::id))
+                .select(TurnRow::as_select())
+                .load::<TurnRow>(conn)
+                .map_err(TurnError::persistence)?;
+            Page::from_overfetched(rows, page, |row| {
+                Cursor::at_timestamp(row.started_at, row.id)
+            })
+            .try_map(row_to_turn)
+        })
+        .await
+    }
+}
+
+fn load_turn(
+    conn: &mut PgConnection,
+    tenant_id: TenantId,
+    turn_id: TurnId,
+) -> TurnResult<Option<Turn>> {
+    turns::table
+        .filter(turns::tenant_id.eq(tenant_id.into_inner()))
+        .filter(turns::id.eq(turn_id.into_inner()))
+        .select(TurnRow::as_select())
+        .first::<TurnRow>(conn)
+        .optional()
+        .map_err(TurnError::persistence)?
+        .map(row_to_turn)
+        .transpose()
+}
+
+fn turn_to_row(turn: &Turn, tenant_id: TenantId) -> TurnResult<TurnRow> {
+    Ok(TurnRow {
+        id: turn.turn_id.into_inner(),
+        tenant_id: tenant_id.into_inner(),
+        conversation_id: turn.conversation_id.into_inner(),
+        session_id: turn.session_id.into_inner(),
+        initiating_message_id: turn.initiating_message_id.into_inner(),
+        response_message_ids: serde_json::to_value(&turn.response_message_ids)
+            .map_err(TurnError::persistence)?,
+        tool_calls: serde_json::to_value(&turn.tool_calls).map_err(TurnError::persistence)?,
+        status: turn.status.as_str().to_owned(),
+        started_at: turn.started_at,
+        ended_at: turn.ended_at,
+    })
+}
+
+fn row_to_turn(row: TurnRow) -> TurnResult<Turn> {
+    let response_message_ids: Vec<MessageId> = serde_json::from_value(row.response_message_ids)
+        .map_err(TurnError::invalid_persisted_data)?;
+    let tool_calls: Vec<ToolCallReference> =
+        serde_json::from_value(row.tool_calls).map_err(TurnError::invalid_persisted_data)?;
+    let status =
+        TurnStatus::try_from(row.status.as_str()).map_err(TurnError::invalid_persisted_data)?;
+    Ok(Turn {
+        turn_id: TurnId::from_uuid(row.id),
+        conversation_id: ConversationId::from_uuid(row.conversation_id),
+        session_id: AgentSessionId::from_uuid(row.session_id),
+        initiating_message_id: MessageId::from_uuid(row.initiating_message_id),
+        response_message_ids,
+        tool_calls,
+        status,
+        started_at: row.started_at,
+        ended_at: row.ended_at,
+    })
+}
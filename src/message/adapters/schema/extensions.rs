@@ -0,0 +1,328 @@
+//! Diesel schema definitions for the tables layered on the core message
+//! store: listing summaries, rolling summaries, feedback, processing
+//! stages, forks, labels, retention, redactions, partial messages, handoff
+//! context packages, turns, periodic snapshot policies, and slash-command
+//! definitions and executions.
+
+diesel::table! {
+    /// The `conversation_summaries` table projects one listing row per
+    /// conversation.
+    ///
+    /// Maintained by triggers on `conversations`, `messages`, and
+    /// `task_conversation_links`.
+    conversation_summaries (conversation_id) {
+        /// Summarised conversation identifier.
+        conversation_id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Conversation state: active, paused, completed, or archived.
+        #[max_length = 50]
+        state -> Varchar,
+        /// Task the conversation works on, if linked.
+        task_id -> Nullable<Uuid>,
+        /// Labels of the linked task's issue.
+        labels -> Array<Text>,
+        /// Agent backend named by the most recent message carrying one.
+        #[max_length = 100]
+        agent_backend -> Nullable<Varchar>,
+        /// Number of messages in the conversation.
+        message_count -> Int8,
+        /// When the conversation was created.
+        created_at -> Timestamptz,
+        /// Latest conversation update or message creation time.
+        last_activity_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// The `conversation_rolling_summaries` table stores the latest rolling
+    /// summary of each conversation.
+    conversation_rolling_summaries (tenant_id, conversation_id) {
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Summarised conversation identifier.
+        conversation_id -> Uuid,
+        /// Summary text.
+        summary -> Text,
+        /// Sequence number of the last message the summary covers.
+        covered_through -> Int8,
+        /// Incremental updates since the last full recompute.
+        incremental_updates -> Int4,
+        /// When the summary was last rebuilt from the full conversation.
+        recomputed_at -> Timestamptz,
+        /// When the summary last changed.
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// The `message_feedback` table stores reviewer ratings of assistant
+    /// messages, one row per message and author.
+    message_feedback (id) {
+        /// Unique feedback identifier.
+        id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Conversation containing the rated message.
+        conversation_id -> Uuid,
+        /// Rated message identifier.
+        message_id -> Uuid,
+        /// Reviewer identifier.
+        author_id -> Uuid,
+        /// Rating kind: `thumbs_up`, `thumbs_down`, or `stars`.
+        #[max_length = 20]
+        rating -> Varchar,
+        /// Star score for `stars` ratings.
+        stars -> Nullable<Int2>,
+        /// Optional free-text comment.
+        comment -> Nullable<Text>,
+        /// Optional feedback category.
+        #[max_length = 20]
+        category -> Nullable<Varchar>,
+        /// Backend that produced the rated message.
+        #[max_length = 100]
+        agent_backend -> Nullable<Varchar>,
+        /// Instruction-set version the backend ran with.
+        instruction_set_version -> Nullable<Text>,
+        /// When the feedback was last submitted.
+        submitted_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// The `message_processing_stages` table stores each pipeline stage's
+    /// progress on a message, one row per message and stage.
+    message_processing_stages (tenant_id, message_id, stage) {
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Processed message identifier.
+        message_id -> Uuid,
+        /// Conversation containing the message.
+        conversation_id -> Uuid,
+        /// Stage: `validation`, `moderation`, `embedding`, or `projection`.
+        #[max_length = 20]
+        stage -> Varchar,
+        /// Stage state: `in_progress`, `succeeded`, or `failed`.
+        #[max_length = 20]
+        state -> Varchar,
+        /// Number of times the stage picked the message up.
+        attempts -> Int4,
+        /// Failure reason from the latest failed attempt.
+        last_error -> Nullable<Text>,
+        /// When the status last changed.
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// The `conversation_forks` table records where each forked
+    /// conversation came from.
+    conversation_forks (conversation_id) {
+        /// The fork's conversation identifier.
+        conversation_id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Conversation the fork was taken from.
+        parent_conversation_id -> Uuid,
+        /// Last parent sequence number copied into the fork.
+        forked_at_sequence -> Int8,
+        /// Parent's latest context snapshot ending at or before the fork point.
+        parent_snapshot_id -> Nullable<Uuid>,
+        /// When the fork was created.
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// The `conversation_label_events` table is the append-only audit
+    /// history of conversation label changes.
+    conversation_label_events (id) {
+        /// Unique event identifier.
+        id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Relabelled conversation identifier.
+        conversation_id -> Uuid,
+        /// The label key.
+        #[max_length = 63]
+        label_key -> Varchar,
+        /// Value before the change, or NULL when the label was absent.
+        previous_value -> Nullable<Text>,
+        /// Value after the change, or NULL when the label was removed.
+        label_value -> Nullable<Text>,
+        /// User who made the change.
+        actor_id -> Uuid,
+        /// Correlation identifier of the request that made the change.
+        correlation_id -> Uuid,
+        /// When the change was made.
+        occurred_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// The `conversation_retention_policies` table holds the retention
+    /// policies set on individual conversations, overriding the default.
+    conversation_retention_policies (conversation_id) {
+        /// The conversation the policy applies to.
+        conversation_id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Days the conversation's data is kept.
+        retention_days -> Int4,
+        /// What happens to expired data: `delete` or `anonymise`.
+        #[max_length = 20]
+        purge_mode -> Varchar,
+        /// User who set the policy.
+        set_by -> Uuid,
+        /// When the policy was set.
+        set_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// The `message_redactions` table stores one tombstone per redacted
+    /// message.
+    message_redactions (message_id) {
+        /// Redacted message identifier.
+        message_id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Conversation containing the message.
+        conversation_id -> Uuid,
+        /// Sequence number the message keeps.
+        sequence_number -> Int8,
+        /// Why the message was redacted.
+        reason -> Text,
+        /// User who redacted the message.
+        redacted_by -> Uuid,
+        /// Correlation ID of the redacting request.
+        correlation_id -> Uuid,
+        /// When the message was redacted.
+        redacted_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// The `partial_messages` table stages assistant messages while their
+    /// content streams in, one row per stream.
+    partial_messages (tenant_id, message_id) {
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Identifier the finalised message will carry.
+        message_id -> Uuid,
+        /// Conversation the message will join.
+        conversation_id -> Uuid,
+        /// Metadata the finalised message will carry.
+        metadata -> Jsonb,
+        /// Text received so far, as a JSON string or a sealed envelope.
+        content -> Jsonb,
+        /// Number of chunks received so far.
+        chunk_count -> Int4,
+        /// When the stream started.
+        started_at -> Timestamptz,
+        /// When the stream last received a chunk.
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// The `handoff_context_packages` table stores the context prepared for
+    /// each handoff target session.
+    handoff_context_packages (tenant_id, target_session_id) {
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Session that took the conversation over.
+        target_session_id -> Uuid,
+        /// Completed handoff the package was prepared for.
+        handoff_id -> Uuid,
+        /// Serialized package.
+        package -> Jsonb,
+        /// When the package was prepared.
+        prepared_at -> Timestamptz,
+        /// Conversation handed over.
+        conversation_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    /// The `turns` table records each exchange between a user and an agent
+    /// session.
+    turns (id) {
+        /// Unique turn identifier.
+        id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Conversation the turn belongs to.
+        conversation_id -> Uuid,
+        /// Agent session that handled the turn.
+        session_id -> Uuid,
+        /// Message that started the turn.
+        initiating_message_id -> Uuid,
+        /// Messages written in response, as a JSONB array.
+        response_message_ids -> Jsonb,
+        /// Tool calls the responses made, as a JSONB array.
+        tool_calls -> Jsonb,
+        /// Turn status (in_progress, completed, failed).
+        #[max_length = 20]
+        status -> Varchar,
+        /// When the turn started.
+        started_at -> Timestamptz,
+        /// When the turn completed or failed.
+        ended_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    /// The `periodic_snapshot_policies` table holds the periodic snapshot
+    /// intervals set on individual conversations.
+    periodic_snapshot_policies (conversation_id) {
+        /// The conversation the policy applies to.
+        conversation_id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Messages between snapshots, or NULL for no message bound.
+        every_messages -> Nullable<Int4>,
+        /// Minutes between snapshots, or NULL for no time bound.
+        every_minutes -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    /// The `slash_command_definitions` table stores every registered version
+    /// of each slash command.
+    slash_command_definitions (tenant_id, command, version) {
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Qualified command name without the leading slash, lower-cased.
+        #[max_length = 100]
+        command -> Varchar,
+        /// Definition version, starting at one.
+        version -> Int4,
+        /// Serialized definition.
+        definition -> Jsonb,
+        /// When the version was registered.
+        registered_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// The `slash_command_executions` table is the audit trail of slash
+    /// commands run in conversations.
+    slash_command_executions (id) {
+        /// Unique execution record identifier.
+        id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Conversation the command ran in.
+        conversation_id -> Uuid,
+        /// User who ran the command.
+        invoked_by -> Uuid,
+        /// Qualified command name without the leading slash.
+        #[max_length = 100]
+        command -> Varchar,
+        /// Serialized execution.
+        execution -> Jsonb,
+        /// When the command ran.
+        executed_at -> Timestamptz,
+    }
+}
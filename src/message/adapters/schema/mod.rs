@@ -1,12 +1,22 @@
 //! Diesel schema definitions for message persistence.
 //!
-//! This file is generated by `diesel print-schema` and should not be modified
-//! manually. Run `diesel migration run && diesel print-schema > src/message/adapters/schema.rs`
-//! after creating or modifying migrations.
+//! These files follow the output of `diesel print-schema` and should not be
+//! modified by hand. Run `diesel migration run && diesel print-schema` after
+//! creating or modifying migrations; `diesel.toml` writes the core tables to
+//! this file, and the tables layered on them go in `extensions.rs`.
 //!
 //! The schema follows corbusier-design.md §6.2.3 with JSONB storage for
 //! flexible message content and metadata.
 
+mod extensions;
+
+pub use extensions::{
+    conversation_forks, conversation_label_events, conversation_retention_policies,
+    conversation_rolling_summaries, conversation_summaries, handoff_context_packages,
+    message_feedback, message_processing_stages, message_redactions, partial_messages,
+    periodic_snapshot_policies, slash_command_definitions, slash_command_executions, turns,
+};
+
 diesel::table! {
     /// The `conversations` table stores conversation metadata.
     ///
@@ -21,13 +31,17 @@ diesel::table! {
         task_id -> Nullable<Uuid>,
         /// Flexible context data stored as JSONB.
         context -> Jsonb,
-        /// Conversation state: active, paused, or archived.
+        /// Conversation state: active, paused, completed, or archived.
         #[max_length = 50]
         state -> Varchar,
+        /// Key/value labels stored as a GIN-indexed JSONB object.
+        labels -> Jsonb,
         /// When the conversation was created.
         created_at -> Timestamptz,
         /// When the conversation was last updated.
         updated_at -> Timestamptz,
+        /// Optimistic concurrency version, bumped on every update.
+        version -> Int8,
     }
 }
 
@@ -85,6 +99,8 @@ diesel::table! {
         user_id -> Nullable<Uuid>,
         /// Session context.
         session_id -> Nullable<Uuid>,
+        /// Global stream position, assigned in commit order on insert.
+        position -> Int8,
     }
 }
 
@@ -158,6 +174,8 @@ diesel::table! {
         /// Session state: `active`, `paused`, `handed_off`, `completed`, or `failed`.
         #[max_length = 20]
         state -> Varchar,
+        /// Optimistic concurrency version, bumped on every update.
+        version -> Int8,
     }
 }
 
@@ -227,9 +245,14 @@ diesel::table! {
         token_estimate -> Nullable<Int8>,
         /// When the snapshot was captured.
         captured_at -> Timestamptz,
-        /// Type of snapshot: `session_start`, `handoff_initiated`, `truncation`, `checkpoint`.
+        /// Type of snapshot: `session_start`, `handoff_initiated`, `truncation`,
+        /// `checkpoint`, `compaction`, `session_paused`, `periodic`.
         #[max_length = 30]
         snapshot_type -> Varchar,
+        /// Summary standing in for the range; set only on compactions.
+        summary_text -> Nullable<Text>,
+        /// Snapshot an incremental snapshot extends.
+        parent_snapshot_id -> Nullable<Uuid>,
     }
 }
 
@@ -243,8 +266,22 @@ diesel::allow_tables_to_appear_in_same_query!(
     agent_sessions,
     audit_logs,
     context_snapshots,
+    conversation_forks,
+    conversation_label_events,
+    conversation_retention_policies,
+    conversation_rolling_summaries,
+    conversation_summaries,
     conversations,
     domain_events,
+    handoff_context_packages,
     handoffs,
+    message_feedback,
+    message_processing_stages,
+    message_redactions,
     messages,
+    partial_messages,
+    periodic_snapshot_policies,
+    slash_command_definitions,
+    slash_command_executions,
+    turns,
 );
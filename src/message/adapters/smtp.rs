@@ -0,0 +1,151 @@
+//! SMTP implementation of the `EmailNotifier` port.
+//!
+//! [`SmtpEmailNotifier`] sends agent replies through an SMTP relay using
+//! STARTTLS, authenticating when credentials are configured. Replies carry
+//! `In-Reply-To` and `References` headers so mail clients thread them under
+//! the email they answer.
+
+use crate::message::{
+    domain::OutboundEmail,
+    ports::email::{EmailNotifier, EmailTransportError, EmailTransportResult},
+};
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Tokio1Executor,
+    message::{Mailbox, header::ContentType},
+    transport::smtp::authentication::Credentials,
+};
+use std::fmt;
+
+/// Connection settings for an SMTP relay.
+#[derive(Clone)]
+pub struct SmtpConfig {
+    relay: String,
+    port: Option<u16>,
+    credentials: Option<(String, String)>,
+    from: String,
+}
+
+impl SmtpConfig {
+    /// Creates settings for `relay`, sending as `from`.
+    #[must_use]
+    pub fn new(relay: impl Into<String>, from: impl Into<String>) -> Self {
+        Self {
+            relay: relay.into(),
+            port: None,
+            credentials: None,
+            from: from.into(),
+        }
+    }
+
+    /// Connects to `port` instead of the submission port.
+    #[must_use]
+    pub const fn with_port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Authenticates with the relay as `username`.
+    #[must_use]
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+}
+
+impl fmt::Debug for SmtpConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmtpConfig")
+            .field("relay", &self.relay)
+            .field("port", &self.port)
+            .field(
+                "username",
+                &self.credentials.as_ref().map(|(username, _)| username),
+            )
+            .field("from", &self.from)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Email notifier delivering through an SMTP relay.
+#[derive(Clone)]
+pub struct SmtpEmailNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpEmailNotifier {
+    /// Creates a notifier for the relay described by `config`.
+    ///
+    /// No connection is made until the first email is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmailTransportError::InvalidAddress`] when the sender
+    /// address is invalid, or [`EmailTransportError::Transport`] when the
+    /// relay's TLS parameters cannot be set up.
+    pub fn new(config: SmtpConfig) -> EmailTransportResult<Self> {
+        let SmtpConfig {
+            relay,
+            port,
+            credentials,
+            from: sender,
+        } = config;
+        let from = parse_mailbox(&sender)?;
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&relay)
+            .map_err(EmailTransportError::transport)?;
+        if let Some(relay_port) = port {
+            builder = builder.port(relay_port);
+        }
+        if let Some((username, password)) = credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+impl fmt::Debug for SmtpEmailNotifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmtpEmailNotifier")
+            .field("from", &self.from)
+            .finish_non_exhaustive()
+    }
+}
+
+fn parse_mailbox(address: &str) -> EmailTransportResult<Mailbox> {
+    address
+        .parse()
+        .map_err(|_| EmailTransportError::InvalidAddress(address.to_owned()))
+}
+
+#[async_trait]
+impl EmailNotifier for SmtpEmailNotifier {
+    async fn send(&self, email: &OutboundEmail) -> EmailTransportResult<()> {
+        let mut builder = lettre::Message::builder()
+            .from(self.from.clone())
+            .to(parse_mailbox(&email.to)?)
+            .subject(email.subject.clone())
+            .header(ContentType::TEXT_PLAIN);
+        if let Some(parent) = &email.in_reply_to {
+            builder = builder.in_reply_to(parent.clone());
+        }
+        if !email.references.is_empty() {
+            builder = builder.references(email.references.join(" "));
+        }
+        let message = builder
+            .body(email.body.clone())
+            .map_err(EmailTransportError::transport)?;
+        self.transport
+            .send(message)
+            .await
+            .map_err(EmailTransportError::transport)?;
+        Ok(())
+    }
+}
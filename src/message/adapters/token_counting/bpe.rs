@@ -0,0 +1,141 @@
+//! Exact counts from a tiktoken-style byte-pair encoding.
+
+use super::pieces::pieces;
+use crate::message::ports::TokenCounter;
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Errors returned while loading an encoding's rank file.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TokenRanksError {
+    /// A line is not a base64 token, a space, and a rank.
+    #[error("line {line} of the token ranks is not `<base64 token> <rank>`")]
+    MalformedLine {
+        /// One-based line number.
+        line: usize,
+    },
+    /// A token is ranked twice.
+    #[error("line {line} of the token ranks repeats a token")]
+    DuplicateToken {
+        /// One-based line number.
+        line: usize,
+    },
+    /// The file ranks no tokens.
+    #[error("the token ranks are empty")]
+    Empty,
+}
+
+/// [`TokenCounter`] that counts the tokens a byte-pair encoding produces.
+///
+/// Each piece of text is encoded from its bytes by repeatedly merging the
+/// adjacent pair whose concatenation has the lowest rank, as tiktoken does.
+/// Special tokens are not recognised; text that spells one is counted as
+/// ordinary text.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::adapters::token_counting::BpeTokenCounter;
+/// use corbusier::message::ports::TokenCounter;
+///
+/// // "a", "b", and "ab", base64 encoded and ranked.
+/// let counter = BpeTokenCounter::from_tiktoken("tiny", "YQ== 0\nYg== 1\nYWI= 2\n")
+///     .expect("valid ranks");
+/// assert_eq!(counter.count("ab"), 1);
+/// assert_eq!(counter.count("ba"), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BpeTokenCounter {
+    encoding: String,
+    ranks: HashMap<Vec<u8>, u32>,
+}
+
+impl BpeTokenCounter {
+    /// Creates a counter from token byte strings and their merge ranks.
+    #[must_use]
+    pub fn new(encoding: impl Into<String>, ranks: HashMap<Vec<u8>, u32>) -> Self {
+        Self {
+            encoding: encoding.into(),
+            ranks,
+        }
+    }
+
+    /// Creates a counter from the contents of a tiktoken rank file, which
+    /// holds one `<base64 token> <rank>` pair per line.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TokenRanksError`] when a line is malformed, a token is
+    /// ranked twice, or no tokens are ranked.
+    pub fn from_tiktoken(
+        encoding: impl Into<String>,
+        ranks: &str,
+    ) -> Result<Self, TokenRanksError> {
+        let mut parsed = HashMap::new();
+        for (index, line) in ranks.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let line_number = index + 1;
+            let (token, rank) = parse_rank_line(line)
+                .ok_or(TokenRanksError::MalformedLine { line: line_number })?;
+            if parsed.insert(token, rank).is_some() {
+                return Err(TokenRanksError::DuplicateToken { line: line_number });
+            }
+        }
+        if parsed.is_empty() {
+            return Err(TokenRanksError::Empty);
+        }
+        Ok(Self::new(encoding, parsed))
+    }
+
+    /// Returns the number of tokens one piece encodes to.
+    fn piece_tokens(&self, piece: &[u8]) -> usize {
+        if self.ranks.contains_key(piece) {
+            return 1;
+        }
+        let mut bounds: Vec<usize> = (0..=piece.len()).collect();
+        while let Some(at) = self.lowest_ranked_merge(piece, &bounds) {
+            bounds.remove(at);
+        }
+        bounds.len().saturating_sub(1)
+    }
+
+    /// Returns the boundary whose removal merges the lowest-ranked pair of
+    /// adjacent parts, or `None` when no pair is ranked.
+    fn lowest_ranked_merge(&self, piece: &[u8], bounds: &[usize]) -> Option<usize> {
+        bounds
+            .windows(3)
+            .enumerate()
+            .filter_map(|(index, window)| {
+                let [start, _, end] = window else {
+                    return None;
+                };
+                let rank = piece
+                    .get(*start..*end)
+                    .and_then(|pair| self.ranks.get(pair))?;
+                Some((*rank, index + 1))
+            })
+            .min()
+            .map(|(_, at)| at)
+    }
+}
+
+impl TokenCounter for BpeTokenCounter {
+    fn encoding(&self) -> &str {
+        &self.encoding
+    }
+
+    fn count(&self, text: &str) -> u64 {
+        pieces(text)
+            .map(|piece| self.piece_tokens(piece.as_bytes()))
+            .map(|tokens| u64::try_from(tokens).unwrap_or(u64::MAX))
+            .fold(0, u64::saturating_add)
+    }
+}
+
+fn parse_rank_line(line: &str) -> Option<(Vec<u8>, u32)> {
+    let (token, rank) = line.trim().split_once(' ')?;
+    Some((STANDARD.decode(token).ok()?, rank.parse().ok()?))
+}
@@ -0,0 +1,51 @@
+//! Vocabulary-free token estimates.
+
+use super::pieces::pieces;
+use crate::message::ports::TokenCounter;
+
+/// Encoding name reported by [`HeuristicTokenCounter`].
+pub const HEURISTIC_ENCODING: &str = "heuristic";
+
+/// Characters a token covers on average in English text and code.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// [`TokenCounter`] that estimates one token per four characters of each
+/// piece, and at least one per piece.
+///
+/// Estimates run close to byte-pair encodings on English prose and code
+/// and high on text with many short words or symbols, which errs on the
+/// side of a smaller context.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::adapters::token_counting::HeuristicTokenCounter;
+/// use corbusier::message::ports::TokenCounter;
+///
+/// let counter = HeuristicTokenCounter::new();
+/// assert_eq!(counter.count("Hello, world!"), 6);
+/// assert_eq!(counter.count(""), 0);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicTokenCounter;
+
+impl HeuristicTokenCounter {
+    /// Creates a heuristic counter.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl TokenCounter for HeuristicTokenCounter {
+    fn encoding(&self) -> &str {
+        HEURISTIC_ENCODING
+    }
+
+    fn count(&self, text: &str) -> u64 {
+        pieces(text)
+            .map(|piece| piece.chars().count().div_ceil(CHARS_PER_TOKEN))
+            .map(|tokens| u64::try_from(tokens).unwrap_or(u64::MAX))
+            .fold(0, u64::saturating_add)
+    }
+}
@@ -0,0 +1,19 @@
+//! [`TokenCounter`] adapters.
+//!
+//! [`BpeTokenCounter`] counts exactly as a tiktoken-style byte-pair encoding
+//! does, given the encoding's rank file. [`HeuristicTokenCounter`] needs no
+//! vocabulary and estimates roughly one token per four characters; use it as
+//! the fallback when no rank file is configured.
+//!
+//! Both split text into pieces first: runs of letters (with one leading
+//! space or symbol), up to three digits, runs of symbols, and whitespace.
+//! Tokens never span two pieces.
+//!
+//! [`TokenCounter`]: crate::message::ports::TokenCounter
+
+mod bpe;
+mod heuristic;
+mod pieces;
+
+pub use bpe::{BpeTokenCounter, TokenRanksError};
+pub use heuristic::{HEURISTIC_ENCODING, HeuristicTokenCounter};
@@ -0,0 +1,104 @@
+//! Splits text into the pieces byte-pair encoding works within.
+//!
+//! The rules follow the pre-tokenisation pattern of tiktoken's `cl100k_base`
+//! encoding closely enough for counting, without a regex engine.
+
+/// Broad character classes the splitting rules distinguish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Letter,
+    Digit,
+    Newline,
+    Space,
+    Symbol,
+}
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        if c.is_alphabetic() {
+            Self::Letter
+        } else if c.is_numeric() {
+            Self::Digit
+        } else if matches!(c, '\r' | '\n') {
+            Self::Newline
+        } else if c.is_whitespace() {
+            Self::Space
+        } else {
+            Self::Symbol
+        }
+    }
+}
+
+/// The most digits one piece holds.
+const MAX_DIGITS: usize = 3;
+
+/// Returns the pieces of `text`, in order. Concatenated, they are `text`.
+pub(super) fn pieces(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let (piece, tail) = rest.split_at_checked(piece_len(rest))?;
+        rest = tail;
+        (!piece.is_empty()).then_some(piece)
+    })
+}
+
+/// Returns the byte length of the piece `rest` starts with.
+fn piece_len(rest: &str) -> usize {
+    let mut chars = rest.chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+    let prefix = first.len_utf8();
+    let after_prefix = rest.get(prefix..).unwrap_or_default();
+    match (CharClass::of(first), chars.next().map(CharClass::of)) {
+        (CharClass::Letter, _) => run_len(rest, CharClass::Letter),
+        (CharClass::Digit, _) => digits_len(rest),
+        (CharClass::Space | CharClass::Symbol, Some(CharClass::Letter)) => {
+            prefix + run_len(after_prefix, CharClass::Letter)
+        }
+        (CharClass::Space, Some(CharClass::Symbol)) => prefix + symbols_len(after_prefix),
+        (CharClass::Symbol, _) => symbols_len(rest),
+        (CharClass::Space | CharClass::Newline, _) => whitespace_len(rest),
+    }
+}
+
+/// Returns the byte length of the run of `class` characters `rest` starts
+/// with.
+fn run_len(rest: &str, class: CharClass) -> usize {
+    prefix_len(rest, |c| CharClass::of(c) == class)
+}
+
+fn prefix_len(rest: &str, keep: impl Fn(char) -> bool) -> usize {
+    rest.char_indices()
+        .find(|&(_, c)| !keep(c))
+        .map_or(rest.len(), |(index, _)| index)
+}
+
+fn digits_len(rest: &str) -> usize {
+    rest.char_indices()
+        .take_while(|&(_, c)| CharClass::of(c) == CharClass::Digit)
+        .take(MAX_DIGITS)
+        .last()
+        .map_or(0, |(index, c)| index + c.len_utf8())
+}
+
+/// Returns the length of a run of symbols and the line breaks after it.
+fn symbols_len(rest: &str) -> usize {
+    let symbols = run_len(rest, CharClass::Symbol);
+    symbols + run_len(rest.get(symbols..).unwrap_or_default(), CharClass::Newline)
+}
+
+/// Returns the length of a whitespace run, ending at its last line break
+/// when it has one. Otherwise a run followed by more text leaves its last
+/// character to lead the next piece.
+fn whitespace_len(rest: &str) -> usize {
+    let run = prefix_len(rest, char::is_whitespace);
+    let whitespace = rest.get(..run).unwrap_or_default();
+    if let Some(newline) = whitespace.rfind(['\r', '\n']) {
+        return newline + 1;
+    }
+    match whitespace.char_indices().last() {
+        Some((last, _)) if last > 0 && run < rest.len() => last,
+        _ => run,
+    }
+}
@@ -0,0 +1,257 @@
+//! Anthropic messages transcripts.
+//!
+//! The payload is a messages request body. Its `system` prompt, when
+//! present, becomes the first message, followed by its `messages` in order:
+//!
+//! ```json
+//! {
+//!   "system": "You are terse.",
+//!   "messages": [
+//!     { "role": "user", "content": "Weather in Paris?" },
+//!     { "role": "assistant", "content": [
+//!       { "type": "tool_use", "id": "toolu_1", "name": "weather", "input": { "city": "Paris" } }
+//!     ]},
+//!     { "role": "user", "content": [
+//!       { "type": "tool_result", "tool_use_id": "toolu_1", "content": "18C, sunny" }
+//!     ]},
+//!     { "role": "assistant", "content": "18C and sunny." }
+//!   ]
+//! }
+//! ```
+//!
+//! Anthropic returns tool results inside user messages, while Corbusier
+//! keeps them in tool messages, so the tool results of a user message become
+//! a tool message stored before the rest of its content. Thinking blocks
+//! become reasoning, redacted thinking becomes redacted reasoning, and
+//! signatures and cache controls are dropped.
+
+use super::attachment_from_url;
+use crate::message::{
+    domain::{
+        AttachmentPart, ContentPart, ReasoningPart, Role, TextPart, ToolCallPart, ToolResultPart,
+        TranscriptFormat, TranscriptMessage,
+    },
+    ports::{TranscriptConverter, TranscriptError, TranscriptResult},
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+struct MessagesPayload {
+    #[serde(default)]
+    system: Option<SystemPrompt>,
+    messages: Vec<AnthropicMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum SystemPrompt {
+    Text(String),
+    Blocks(Vec<SystemBlock>),
+}
+
+#[derive(Debug, Deserialize)]
+struct SystemBlock {
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicMessage {
+    role: String,
+    content: AnthropicContent,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AnthropicContent {
+    Text(String),
+    Blocks(Vec<Block>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Block {
+    Text {
+        text: String,
+    },
+    Image {
+        source: Source,
+    },
+    Document {
+        source: Source,
+        #[serde(default)]
+        title: Option<String>,
+    },
+    Thinking {
+        thinking: String,
+    },
+    RedactedThinking {
+        data: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        input: Value,
+    },
+    ToolResult {
+        tool_use_id: String,
+        #[serde(default)]
+        content: Option<Value>,
+        #[serde(default)]
+        is_error: bool,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Source {
+    Base64 {
+        media_type: String,
+        data: String,
+    },
+    Text {
+        media_type: String,
+        data: String,
+    },
+    Url {
+        url: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// Converts Anthropic messages transcripts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnthropicMessagesConverter;
+
+impl AnthropicMessagesConverter {
+    /// Creates the converter.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl TranscriptConverter for AnthropicMessagesConverter {
+    fn format(&self) -> TranscriptFormat {
+        TranscriptFormat::AnthropicMessages
+    }
+
+    fn convert(&self, payload: &[u8]) -> TranscriptResult<Vec<TranscriptMessage>> {
+        let MessagesPayload { system, messages } = serde_json::from_slice(payload)?;
+        let mut transcript: Vec<TranscriptMessage> =
+            system.and_then(convert_system).into_iter().collect();
+        for (position, message) in messages.into_iter().enumerate() {
+            transcript.extend(convert_message(position, message)?);
+        }
+        if transcript.is_empty() {
+            return Err(TranscriptError::Empty);
+        }
+        Ok(transcript)
+    }
+}
+
+fn convert_system(system: SystemPrompt) -> Option<TranscriptMessage> {
+    let texts = match system {
+        SystemPrompt::Text(text) => vec![text],
+        SystemPrompt::Blocks(blocks) => blocks.into_iter().map(|block| block.text).collect(),
+    };
+    let content: Vec<ContentPart> = texts
+        .into_iter()
+        .filter(|text| !text.is_empty())
+        .map(|text| ContentPart::Text(TextPart::new(text)))
+        .collect();
+    (!content.is_empty()).then(|| TranscriptMessage::new(Role::System, content))
+}
+
+/// Converts one message, splitting the tool results of a user message into
+/// a tool message of their own.
+fn convert_message(
+    position: usize,
+    message: AnthropicMessage,
+) -> TranscriptResult<Vec<TranscriptMessage>> {
+    let role = match message.role.as_str() {
+        "user" => Role::User,
+        "assistant" => Role::Assistant,
+        _ => {
+            return Err(TranscriptError::UnsupportedRole {
+                position,
+                role: message.role,
+            });
+        }
+    };
+    let parts = match message.content {
+        AnthropicContent::Text(text) if text.is_empty() => Vec::new(),
+        AnthropicContent::Text(text) => vec![ContentPart::Text(TextPart::new(text))],
+        AnthropicContent::Blocks(blocks) => blocks
+            .into_iter()
+            .map(|block| convert_block(position, block))
+            .collect::<TranscriptResult<_>>()?,
+    };
+    let (results, content): (Vec<_>, Vec<_>) = parts
+        .into_iter()
+        .partition(|part| matches!(part, ContentPart::ToolResult(_)));
+    if role == Role::Assistant && !results.is_empty() {
+        return Err(TranscriptError::unsupported_content(
+            position,
+            "tool result in an assistant message",
+        ));
+    }
+    Ok([
+        TranscriptMessage::new(Role::Tool, results),
+        TranscriptMessage::new(role, content),
+    ]
+    .into_iter()
+    .filter(|converted| !converted.content.is_empty())
+    .collect())
+}
+
+fn convert_block(position: usize, block: Block) -> TranscriptResult<ContentPart> {
+    let part = match block {
+        Block::Text { text } => ContentPart::Text(TextPart::new(text)),
+        Block::Image { source } => ContentPart::Attachment(convert_source(position, source)?),
+        Block::Document { source, title } => {
+            let mut attachment = convert_source(position, source)?;
+            attachment.name = title;
+            ContentPart::Attachment(attachment)
+        }
+        Block::Thinking { thinking } => ContentPart::Reasoning(ReasoningPart::new(thinking)),
+        Block::RedactedThinking { data } => {
+            ContentPart::Reasoning(ReasoningPart::new(data).into_redacted())
+        }
+        Block::ToolUse { id, name, input } => {
+            ContentPart::ToolCall(ToolCallPart::new(id, name, input))
+        }
+        Block::ToolResult {
+            tool_use_id,
+            content,
+            is_error,
+        } => ContentPart::ToolResult(ToolResultPart {
+            call_id: tool_use_id,
+            content: content.unwrap_or_else(|| Value::String(String::new())),
+            success: !is_error,
+        }),
+        Block::Unknown => {
+            return Err(TranscriptError::unsupported_content(
+                position,
+                "content block of an unknown type",
+            ));
+        }
+    };
+    Ok(part)
+}
+
+fn convert_source(position: usize, source: Source) -> TranscriptResult<AttachmentPart> {
+    match source {
+        Source::Base64 { media_type, data } | Source::Text { media_type, data } => {
+            Ok(AttachmentPart::new(media_type, data))
+        }
+        Source::Url { url } => Ok(attachment_from_url(&url)),
+        Source::Unknown => Err(TranscriptError::unsupported_content(
+            position,
+            "file uploaded by reference",
+        )),
+    }
+}
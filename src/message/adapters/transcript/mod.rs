@@ -0,0 +1,40 @@
+//! Converters for transcripts recorded in other providers' wire formats.
+//!
+//! These adapters implement
+//! [`TranscriptConverter`](crate::message::ports::TranscriptConverter) for
+//! the `OpenAI` chat completions and Anthropic messages formats, feeding
+//! [`TranscriptIngestionService`](crate::message::services::TranscriptIngestionService).
+//!
+//! Inline files and images become attachments holding their base64 data.
+//! Files and images referenced by URL become `text/uri-list` attachments
+//! holding the URL, as nothing is fetched during conversion.
+
+mod anthropic;
+mod openai;
+
+pub use anthropic::AnthropicMessagesConverter;
+pub use openai::OpenAiChatConverter;
+
+use crate::message::domain::AttachmentPart;
+
+/// MIME type of attachments holding the URL of content that was not
+/// fetched.
+pub const URI_LIST_MIME_TYPE: &str = "text/uri-list";
+
+/// Returns an attachment for content at `url`: the media type and data of a
+/// `data:` URL, or the URL itself otherwise.
+fn attachment_from_url(url: &str) -> AttachmentPart {
+    url.strip_prefix("data:")
+        .and_then(|rest| rest.split_once(','))
+        .map_or_else(
+            || AttachmentPart::new(URI_LIST_MIME_TYPE, url),
+            |(media_type, data)| {
+                let mime_type = media_type
+                    .split(';')
+                    .next()
+                    .filter(|kind| !kind.is_empty())
+                    .unwrap_or("text/plain");
+                AttachmentPart::new(mime_type, data)
+            },
+        )
+}
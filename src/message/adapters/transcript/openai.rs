@@ -0,0 +1,248 @@
+//! `OpenAI` chat completions transcripts.
+//!
+//! The payload is a chat completions request body. Its `messages` are
+//! converted in order; when the body also carries the `choices` of the
+//! response, the first choice's message completes the transcript:
+//!
+//! ```json
+//! {
+//!   "messages": [
+//!     { "role": "system", "content": "You are terse." },
+//!     { "role": "user", "content": "Weather in Paris?" },
+//!     { "role": "assistant", "content": null, "tool_calls": [{
+//!       "id": "call_1", "type": "function",
+//!       "function": { "name": "weather", "arguments": "{\"city\":\"Paris\"}" }
+//!     }]},
+//!     { "role": "tool", "tool_call_id": "call_1", "content": "18C, sunny" }
+//!   ],
+//!   "choices": [{ "message": { "role": "assistant", "content": "18C and sunny." } }]
+//! }
+//! ```
+//!
+//! `developer` messages become system messages. Tool call arguments that are
+//! not valid JSON are kept as a JSON string.
+
+use super::attachment_from_url;
+use crate::message::{
+    domain::{
+        AttachmentPart, ContentPart, Role, TextPart, ToolCallPart, ToolResultPart,
+        TranscriptFormat, TranscriptMessage,
+    },
+    ports::{TranscriptConverter, TranscriptError, TranscriptResult},
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+struct ChatPayload {
+    messages: Vec<ChatMessage>,
+    #[serde(default)]
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatMessage {
+    role: String,
+    #[serde(default)]
+    content: Option<ChatContent>,
+    #[serde(default)]
+    refusal: Option<String>,
+    #[serde(default)]
+    tool_calls: Vec<ChatToolCall>,
+    #[serde(default)]
+    tool_call_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ChatContent {
+    Text(String),
+    Parts(Vec<ChatPart>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ChatPart {
+    Text {
+        text: String,
+    },
+    Refusal {
+        refusal: String,
+    },
+    ImageUrl {
+        image_url: ImageUrl,
+    },
+    InputAudio {
+        input_audio: InputAudio,
+    },
+    File {
+        file: FileInput,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+struct ImageUrl {
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct InputAudio {
+    data: String,
+    format: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileInput {
+    #[serde(default)]
+    file_data: Option<String>,
+    #[serde(default)]
+    filename: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatToolCall {
+    id: String,
+    function: ChatFunction,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatFunction {
+    name: String,
+    arguments: String,
+}
+
+/// Converts `OpenAI` chat completions transcripts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OpenAiChatConverter;
+
+impl OpenAiChatConverter {
+    /// Creates the converter.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl TranscriptConverter for OpenAiChatConverter {
+    fn format(&self) -> TranscriptFormat {
+        TranscriptFormat::OpenAiChat
+    }
+
+    fn convert(&self, payload: &[u8]) -> TranscriptResult<Vec<TranscriptMessage>> {
+        let ChatPayload { messages, choices } = serde_json::from_slice(payload)?;
+        let transcript = messages
+            .into_iter()
+            .chain(choices.into_iter().next().map(|choice| choice.message))
+            .enumerate()
+            .map(|(position, message)| convert_message(position, message))
+            .collect::<TranscriptResult<Vec<_>>>()?;
+        if transcript.is_empty() {
+            return Err(TranscriptError::Empty);
+        }
+        Ok(transcript)
+    }
+}
+
+fn convert_message(position: usize, message: ChatMessage) -> TranscriptResult<TranscriptMessage> {
+    let role = match message.role.as_str() {
+        "system" | "developer" => Role::System,
+        "user" => Role::User,
+        "assistant" => Role::Assistant,
+        "tool" => return convert_tool_result(position, message),
+        _ => {
+            return Err(TranscriptError::UnsupportedRole {
+                position,
+                role: message.role,
+            });
+        }
+    };
+    let mut content = convert_content(position, message.content)?;
+    content.extend(
+        message
+            .refusal
+            .map(|refusal| ContentPart::Text(TextPart::new(refusal))),
+    );
+    content.extend(message.tool_calls.into_iter().map(|call| {
+        let arguments = serde_json::from_str(&call.function.arguments)
+            .unwrap_or(Value::String(call.function.arguments));
+        ContentPart::ToolCall(ToolCallPart::new(call.id, call.function.name, arguments))
+    }));
+    Ok(TranscriptMessage::new(role, content))
+}
+
+/// Converts a `tool` message into a tool result holding its text.
+fn convert_tool_result(
+    position: usize,
+    message: ChatMessage,
+) -> TranscriptResult<TranscriptMessage> {
+    let call_id = message.tool_call_id.ok_or_else(|| {
+        TranscriptError::unsupported_content(position, "tool message without a tool_call_id")
+    })?;
+    let texts: Vec<String> = convert_content(position, message.content)?
+        .into_iter()
+        .filter_map(|part| match part {
+            ContentPart::Text(text) => Some(text.text),
+            _ => None,
+        })
+        .collect();
+    let result = ToolResultPart::success(call_id, Value::String(texts.join("\n")));
+    Ok(TranscriptMessage::new(
+        Role::Tool,
+        vec![ContentPart::ToolResult(result)],
+    ))
+}
+
+fn convert_content(
+    position: usize,
+    content: Option<ChatContent>,
+) -> TranscriptResult<Vec<ContentPart>> {
+    match content {
+        None => Ok(Vec::new()),
+        Some(ChatContent::Text(text)) if text.is_empty() => Ok(Vec::new()),
+        Some(ChatContent::Text(text)) => Ok(vec![ContentPart::Text(TextPart::new(text))]),
+        Some(ChatContent::Parts(parts)) => parts
+            .into_iter()
+            .map(|part| convert_part(position, part))
+            .collect(),
+    }
+}
+
+fn convert_part(position: usize, part: ChatPart) -> TranscriptResult<ContentPart> {
+    match part {
+        ChatPart::Text { text } | ChatPart::Refusal { refusal: text } => {
+            Ok(ContentPart::Text(TextPart::new(text)))
+        }
+        ChatPart::ImageUrl { image_url } => {
+            Ok(ContentPart::Attachment(attachment_from_url(&image_url.url)))
+        }
+        ChatPart::InputAudio { input_audio } => Ok(ContentPart::Attachment(AttachmentPart::new(
+            format!("audio/{}", input_audio.format),
+            input_audio.data,
+        ))),
+        ChatPart::File { file } => convert_file(position, file),
+        ChatPart::Unknown => Err(TranscriptError::unsupported_content(
+            position,
+            "content part of an unknown type",
+        )),
+    }
+}
+
+fn convert_file(position: usize, file: FileInput) -> TranscriptResult<ContentPart> {
+    let data = file.file_data.ok_or_else(|| {
+        TranscriptError::unsupported_content(position, "file uploaded by reference")
+    })?;
+    let mut attachment = if data.starts_with("data:") {
+        attachment_from_url(&data)
+    } else {
+        AttachmentPart::new("application/octet-stream", data)
+    };
+    attachment.name = file.filename;
+    Ok(ContentPart::Attachment(attachment))
+}
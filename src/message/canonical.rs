@@ -0,0 +1,98 @@
+//! Canonical JSON serialisation for signing, hashing, and export.
+//!
+//! `serde_json` writes struct fields in declaration order and makes no
+//! promise about how equal numbers are spelt, so two serialisations of the
+//! same value need not share their bytes. The canonical form fixes both:
+//!
+//! - object keys are sorted by their UTF-8 bytes, at every depth
+//! - floats with an integral value are written as integers, so `1.0` and
+//!   `1` (and `-0.0` and `0`) have one spelling
+//! - output is compact, with no insignificant whitespace
+//!
+//! Anything that signs, hashes, or exports a payload for another party to
+//! check should serialise it with [`to_canonical_vec`] rather than
+//! `serde_json::to_vec`.
+
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+use sha2::{Digest, Sha256};
+
+/// Returns the canonical form of `value`.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::canonical::canonicalize;
+/// use serde_json::json;
+///
+/// let value = canonicalize(&json!({"b": 2.0, "a": [{"d": -0.0, "c": 1.5}]}));
+/// assert_eq!(value.to_string(), r#"{"a":[{"c":1.5,"d":0}],"b":2}"#);
+/// ```
+#[must_use]
+pub fn canonicalize(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => canonical_object(map),
+        Value::Array(values) => Value::Array(values.iter().map(canonicalize).collect()),
+        Value::Number(number) => Value::Number(canonical_number(number)),
+        Value::Null | Value::Bool(_) | Value::String(_) => value.clone(),
+    }
+}
+
+/// Serialises `value` to canonical JSON text.
+///
+/// # Errors
+///
+/// Returns the serialisation error when `value` cannot be represented as
+/// JSON, for example a map with non-string keys.
+pub fn to_canonical_string<T: Serialize + ?Sized>(value: &T) -> Result<String, serde_json::Error> {
+    let json = serde_json::to_value(value)?;
+    Ok(canonicalize(&json).to_string())
+}
+
+/// Serialises `value` to canonical JSON bytes.
+///
+/// # Errors
+///
+/// Returns the serialisation error when `value` cannot be represented as
+/// JSON.
+pub fn to_canonical_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    to_canonical_string(value).map(String::into_bytes)
+}
+
+/// Returns the lowercase hex SHA-256 digest of `value`'s canonical JSON.
+///
+/// # Errors
+///
+/// Returns the serialisation error when `value` cannot be represented as
+/// JSON.
+pub fn canonical_sha256<T: Serialize + ?Sized>(value: &T) -> Result<String, serde_json::Error> {
+    let bytes = to_canonical_vec(value)?;
+    Ok(format!("{:x}", Sha256::digest(&bytes)))
+}
+
+fn canonical_object(map: &Map<String, Value>) -> Value {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_unstable_by(|(left, _), (right, _)| left.as_bytes().cmp(right.as_bytes()));
+    Value::Object(
+        entries
+            .into_iter()
+            .map(|(key, entry)| (key.clone(), canonicalize(entry)))
+            .collect(),
+    )
+}
+
+/// Rewrites integral floats as integers.
+///
+/// A float's shortest display form has no fraction or exponent when its
+/// value is integral, so parsing that form as an integer succeeds exactly
+/// when the float can be written as one without loss.
+fn canonical_number(number: &Number) -> Number {
+    let Some(float) = number.as_f64().filter(|_| number.is_f64()) else {
+        return number.clone();
+    };
+    let text = float.to_string();
+    text.parse::<i64>()
+        .map(Number::from)
+        .or_else(|_| text.parse::<u64>().map(Number::from))
+        .unwrap_or_else(|_| number.clone())
+}
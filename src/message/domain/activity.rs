@@ -0,0 +1,270 @@
+//! Conversation activity statistics grouped into time buckets.
+//!
+//! Activity statistics summarise what happened in a conversation over a time
+//! window: message counts by role, tool calls, failed tool results, and
+//! handoffs. Dashboards use them to render heat maps without loading full
+//! conversation histories.
+
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::{ContentPart, ConversationId, HandoffMetadata, Message, MessageSummary};
+
+/// Width of a single activity bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityBucketWidth {
+    /// One-hour buckets aligned to the start of each UTC hour.
+    Hour,
+    /// One-day buckets aligned to UTC midnight.
+    Day,
+}
+
+impl ActivityBucketWidth {
+    /// Returns the bucket width as a string slice.
+    ///
+    /// The value matches the `PostgreSQL` `date_trunc` field name.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Day => "day",
+        }
+    }
+
+    /// Returns the duration covered by one bucket.
+    #[must_use]
+    pub const fn duration(&self) -> TimeDelta {
+        match self {
+            Self::Hour => TimeDelta::hours(1),
+            Self::Day => TimeDelta::days(1),
+        }
+    }
+
+    /// Truncates a timestamp to the start of its bucket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use corbusier::message::domain::ActivityBucketWidth;
+    ///
+    /// let at = Utc.with_ymd_and_hms(2026, 4, 2, 13, 45, 10).unwrap();
+    /// let start = ActivityBucketWidth::Hour.bucket_start(at);
+    /// assert_eq!(start, Utc.with_ymd_and_hms(2026, 4, 2, 13, 0, 0).unwrap());
+    /// ```
+    #[must_use]
+    pub fn bucket_start(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        // Truncation can only fail for timestamps near the representable
+        // limits, in which case the original instant is the best answer.
+        at.duration_trunc(self.duration()).unwrap_or(at)
+    }
+}
+
+impl std::fmt::Display for ActivityBucketWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A request for activity statistics over a half-open time window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityQuery {
+    /// The conversation to summarise.
+    pub conversation_id: ConversationId,
+    /// Start of the window (inclusive).
+    pub from: DateTime<Utc>,
+    /// End of the window (exclusive).
+    pub until: DateTime<Utc>,
+    /// Width of each bucket.
+    pub bucket_width: ActivityBucketWidth,
+}
+
+impl ActivityQuery {
+    /// Creates a new activity query.
+    #[must_use]
+    pub const fn new(
+        conversation_id: ConversationId,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+        bucket_width: ActivityBucketWidth,
+    ) -> Self {
+        Self {
+            conversation_id,
+            from,
+            until,
+            bucket_width,
+        }
+    }
+
+    /// Returns `true` if the window ends after it starts.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.from < self.until
+    }
+
+    /// Returns `true` if the timestamp falls inside the window.
+    #[must_use]
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        at >= self.from && at < self.until
+    }
+}
+
+/// Activity counters for a single time bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityBucket {
+    /// Start of the bucket (inclusive).
+    pub bucket_start: DateTime<Utc>,
+
+    /// Message counts by role.
+    pub message_summary: MessageSummary,
+
+    /// Number of tool calls requested.
+    pub tool_calls: u32,
+
+    /// Number of tool results that reported failure.
+    pub tool_errors: u32,
+
+    /// Number of handoffs initiated.
+    pub handoffs: u32,
+}
+
+impl ActivityBucket {
+    /// Creates an empty bucket starting at the given instant.
+    #[must_use]
+    pub const fn empty(bucket_start: DateTime<Utc>) -> Self {
+        Self {
+            bucket_start,
+            message_summary: MessageSummary::new(0, 0, 0, 0),
+            tool_calls: 0,
+            tool_errors: 0,
+            handoffs: 0,
+        }
+    }
+
+    fn record_message(&mut self, message: &Message) {
+        self.message_summary.record(message.role());
+
+        for part in message.content() {
+            match part {
+                ContentPart::ToolCall(_) => self.tool_calls = self.tool_calls.saturating_add(1),
+                ContentPart::ToolResult(result) if !result.success => {
+                    self.tool_errors = self.tool_errors.saturating_add(1);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Activity statistics for one conversation, ordered by bucket start.
+///
+/// Buckets without any activity are omitted.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use corbusier::message::domain::{
+///     ActivityBucketWidth, ActivityQuery, ContentPart, ConversationActivity,
+///     ConversationId, Message, Role, SequenceNumber, TextPart,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let conversation_id = ConversationId::new();
+/// let message = Message::new(
+///     conversation_id,
+///     Role::User,
+///     vec![ContentPart::Text(TextPart::new("Hello"))],
+///     SequenceNumber::new(1),
+///     &DefaultClock,
+/// )
+/// .expect("valid message");
+/// let query = ActivityQuery::new(
+///     conversation_id,
+///     Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2100, 1, 1, 0, 0, 0).unwrap(),
+///     ActivityBucketWidth::Day,
+/// );
+///
+/// let activity = ConversationActivity::aggregate(&query, &[message], &[]);
+/// assert_eq!(activity.buckets.len(), 1);
+/// assert_eq!(activity.totals().message_summary.user_count, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationActivity {
+    /// The conversation these statistics describe.
+    pub conversation_id: ConversationId,
+
+    /// Width of each bucket.
+    pub bucket_width: ActivityBucketWidth,
+
+    /// Non-empty buckets in ascending order of start time.
+    pub buckets: Vec<ActivityBucket>,
+}
+
+impl ConversationActivity {
+    /// Aggregates messages and handoffs into activity buckets.
+    ///
+    /// Messages are bucketed by `created_at` and handoffs by `initiated_at`.
+    /// Records outside the query window are ignored. Callers are expected to
+    /// pass records for the queried conversation only.
+    #[must_use]
+    pub fn aggregate(
+        query: &ActivityQuery,
+        messages: &[Message],
+        handoffs: &[HandoffMetadata],
+    ) -> Self {
+        let width = query.bucket_width;
+        let mut buckets: BTreeMap<DateTime<Utc>, ActivityBucket> = BTreeMap::new();
+
+        for message in messages.iter().filter(|m| query.contains(m.created_at())) {
+            let start = width.bucket_start(message.created_at());
+            buckets
+                .entry(start)
+                .or_insert_with(|| ActivityBucket::empty(start))
+                .record_message(message);
+        }
+
+        for handoff in handoffs.iter().filter(|h| query.contains(h.initiated_at)) {
+            let start = width.bucket_start(handoff.initiated_at);
+            let bucket = buckets
+                .entry(start)
+                .or_insert_with(|| ActivityBucket::empty(start));
+            bucket.handoffs = bucket.handoffs.saturating_add(1);
+        }
+
+        Self {
+            conversation_id: query.conversation_id,
+            bucket_width: width,
+            buckets: buckets.into_values().collect(),
+        }
+    }
+
+    /// Returns the sum of all buckets.
+    ///
+    /// The returned bucket starts at the first bucket's start, or at the Unix
+    /// epoch when there is no activity.
+    #[must_use]
+    pub fn totals(&self) -> ActivityBucket {
+        let start = self
+            .buckets
+            .first()
+            .map_or(DateTime::<Utc>::UNIX_EPOCH, |b| b.bucket_start);
+        self.buckets
+            .iter()
+            .fold(ActivityBucket::empty(start), |mut acc, bucket| {
+                let sum = &mut acc.message_summary;
+                let add = &bucket.message_summary;
+                sum.user_count = sum.user_count.saturating_add(add.user_count);
+                sum.assistant_count = sum.assistant_count.saturating_add(add.assistant_count);
+                sum.tool_count = sum.tool_count.saturating_add(add.tool_count);
+                sum.system_count = sum.system_count.saturating_add(add.system_count);
+                acc.tool_calls = acc.tool_calls.saturating_add(bucket.tool_calls);
+                acc.tool_errors = acc.tool_errors.saturating_add(bucket.tool_errors);
+                acc.handoffs = acc.handoffs.saturating_add(bucket.handoffs);
+                acc
+            })
+    }
+}
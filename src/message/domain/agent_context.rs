@@ -0,0 +1,80 @@
+//! Policy for sharing a conversation's messages with another agent.
+//!
+//! When one agent's messages become context for another, for example after
+//! a handoff, an [`AgentContextPolicy`] decides what the receiving agent
+//! sees. Reasoning is stripped unless the policy keeps it: it explains how
+//! one agent reached its answer, and backends typically reject reasoning
+//! they did not produce.
+
+use super::Message;
+use serde::{Deserialize, Serialize};
+
+/// What happens to reasoning parts when messages are shared.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasoningSharing {
+    /// Remove reasoning parts, and drop messages that held nothing else.
+    #[default]
+    Strip,
+    /// Share reasoning parts unchanged.
+    Keep,
+}
+
+/// Policy applied when building context for another agent.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     AgentContextPolicy, ContentPart, ConversationId, Message, ReasoningPart, Role,
+///     SequenceNumber, TextPart,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let message = Message::new(
+///     ConversationId::new(),
+///     Role::Assistant,
+///     vec![
+///         ContentPart::Reasoning(ReasoningPart::new("The flaky test needs a retry.")),
+///         ContentPart::Text(TextPart::new("I've added a retry.")),
+///     ],
+///     SequenceNumber::new(1),
+///     &DefaultClock,
+/// )
+/// .expect("valid message");
+///
+/// let shared = AgentContextPolicy::default().apply(vec![message]);
+/// assert_eq!(shared.len(), 1);
+/// assert!(shared.iter().all(|message| message.content().len() == 1));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AgentContextPolicy {
+    reasoning: ReasoningSharing,
+}
+
+impl AgentContextPolicy {
+    /// Replaces how reasoning parts are shared.
+    #[must_use]
+    pub const fn with_reasoning(mut self, reasoning: ReasoningSharing) -> Self {
+        self.reasoning = reasoning;
+        self
+    }
+
+    /// Returns how reasoning parts are shared.
+    #[must_use]
+    pub const fn reasoning(&self) -> ReasoningSharing {
+        self.reasoning
+    }
+
+    /// Returns `messages` as the receiving agent should see them, in order.
+    #[must_use]
+    pub fn apply(&self, messages: impl IntoIterator<Item = Message>) -> Vec<Message> {
+        match self.reasoning {
+            ReasoningSharing::Keep => messages.into_iter().collect(),
+            ReasoningSharing::Strip => messages
+                .into_iter()
+                .filter_map(Message::without_reasoning)
+                .collect(),
+        }
+    }
+}
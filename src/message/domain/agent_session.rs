@@ -75,6 +75,14 @@ pub struct AgentSession {
 
     /// Session state.
     pub state: AgentSessionState,
+
+    /// Optimistic concurrency version: the number of updates persisted for
+    /// this session when it was loaded.
+    ///
+    /// [`AgentSessionRepository::update`](crate::message::ports::agent_session::AgentSessionRepository::update)
+    /// rejects a session whose version no longer matches the stored one.
+    #[serde(default)]
+    pub version: u64,
 }
 
 /// Parameters for creating a session initiated by a handoff.
@@ -115,7 +123,7 @@ impl AgentSession {
         conversation_id: ConversationId,
         agent_backend: impl Into<String>,
         start_sequence: SequenceNumber,
-        clock: &impl mockable::Clock,
+        clock: &(impl mockable::Clock + ?Sized),
     ) -> Self {
         Self {
             session_id: AgentSessionId::new(),
@@ -130,12 +138,16 @@ impl AgentSession {
             started_at: clock.utc(),
             ended_at: None,
             state: AgentSessionState::Active,
+            version: 0,
         }
     }
 
     /// Creates a session that was initiated by a handoff.
     #[must_use]
-    pub fn from_handoff(params: HandoffSessionParams, clock: &impl mockable::Clock) -> Self {
+    pub fn from_handoff(
+        params: HandoffSessionParams,
+        clock: &(impl mockable::Clock + ?Sized),
+    ) -> Self {
         Self {
             initiated_by_handoff: Some(params.handoff_id),
             ..Self::new(
@@ -198,7 +210,7 @@ impl AgentSession {
         &mut self,
         end_sequence: SequenceNumber,
         handoff_id: HandoffId,
-        clock: &impl mockable::Clock,
+        clock: &(impl mockable::Clock + ?Sized),
     ) {
         self.end_sequence = Some(end_sequence);
         self.terminated_by_handoff = Some(handoff_id);
@@ -207,7 +219,11 @@ impl AgentSession {
     }
 
     /// Completes the session normally.
-    pub fn complete(&mut self, end_sequence: SequenceNumber, clock: &impl mockable::Clock) {
+    pub fn complete(
+        &mut self,
+        end_sequence: SequenceNumber,
+        clock: &(impl mockable::Clock + ?Sized),
+    ) {
         self.end_sequence = Some(end_sequence);
         self.ended_at = Some(clock.utc());
         self.state = AgentSessionState::Completed;
@@ -215,7 +231,7 @@ impl AgentSession {
 
     /// Reverts a session from handed-off state back to active.
     ///
-    /// Used when a handoff is cancelled: clears the termination handoff,
+    /// Used when a handoff is cancelled or rejected: clears the termination handoff,
     /// end sequence, and ended-at timestamp, returning the session to active.
     /// Returns `true` if the revert succeeds (session was terminated by the
     /// given handoff), `false` otherwise.
@@ -233,7 +249,7 @@ impl AgentSession {
     }
 
     /// Marks the session as failed.
-    pub fn fail(&mut self, end_sequence: SequenceNumber, clock: &impl mockable::Clock) {
+    pub fn fail(&mut self, end_sequence: SequenceNumber, clock: &(impl mockable::Clock + ?Sized)) {
         self.end_sequence = Some(end_sequence);
         self.ended_at = Some(clock.utc());
         self.state = AgentSessionState::Failed;
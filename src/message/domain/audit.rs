@@ -141,6 +141,9 @@ pub struct AgentResponseAudit {
     /// Optional error details when response generation fails.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Optional version of the instruction set the agent ran with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instruction_set_version: Option<String>,
 }
 
 impl AgentResponseAudit {
@@ -162,6 +165,7 @@ impl AgentResponseAudit {
             response_id: None,
             model: None,
             error: None,
+            instruction_set_version: None,
         }
     }
 
@@ -215,4 +219,21 @@ impl AgentResponseAudit {
         self.error = Some(error.into());
         self
     }
+
+    /// Records the version of the instruction set the agent ran with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use corbusier::message::domain::{AgentResponseAudit, AgentResponseStatus};
+    ///
+    /// let audit = AgentResponseAudit::new(AgentResponseStatus::Completed)
+    ///     .with_instruction_set_version("reviewer-v3");
+    /// assert_eq!(audit.instruction_set_version.as_deref(), Some("reviewer-v3"));
+    /// ```
+    #[must_use]
+    pub fn with_instruction_set_version(mut self, version: impl Into<String>) -> Self {
+        self.instruction_set_version = Some(version.into());
+        self
+    }
 }
@@ -0,0 +1,96 @@
+//! Content-addressed references to attachment data held outside messages.
+//!
+//! Large attachments bloat message rows, so repositories may move their data
+//! to a blob store and keep only a [`ContentHash`] in the message. The hash
+//! is the SHA-256 digest of the data, so identical attachments share one
+//! blob and a blob can be checked against the reference that names it.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use thiserror::Error;
+
+/// Length of a hex-encoded SHA-256 digest.
+const CONTENT_HASH_LEN: usize = 64;
+
+/// Error returned when parsing a malformed content hash.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid content hash '{0}': expected 64 lowercase hex digits")]
+pub struct ContentHashError(pub String);
+
+/// Lowercase hex SHA-256 digest identifying a blob by its content.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::ContentHash;
+///
+/// let hash = ContentHash::of(b"hello");
+/// assert_eq!(
+///     hash.as_str(),
+///     "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+/// );
+/// assert_eq!(ContentHash::parse(hash.as_str()), Ok(hash));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ContentHash(String);
+
+impl ContentHash {
+    /// Returns the hash of `data`.
+    #[must_use]
+    pub fn of(data: &[u8]) -> Self {
+        Self(format!("{:x}", Sha256::digest(data)))
+    }
+
+    /// Parses a hash from its hex form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContentHashError`] unless `value` is 64 lowercase hex
+    /// digits.
+    pub fn parse(value: &str) -> Result<Self, ContentHashError> {
+        let is_hex = value
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte));
+        if value.len() != CONTENT_HASH_LEN || !is_hex {
+            return Err(ContentHashError(value.to_owned()));
+        }
+        Ok(Self(value.to_owned()))
+    }
+
+    /// Returns the hash as lowercase hex.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for ContentHash {
+    type Error = ContentHashError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<ContentHash> for String {
+    fn from(hash: ContentHash) -> Self {
+        hash.0
+    }
+}
+
+/// Attachment data moved out of a message, with the hash that now names it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachmentBlob {
+    /// Hash of `data`, kept in the attachment in its place.
+    pub hash: ContentHash,
+    /// The attachment's data.
+    pub data: String,
+}
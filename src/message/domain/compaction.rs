@@ -0,0 +1,203 @@
+//! Compaction of long conversation histories into summaries.
+//!
+//! Once enough messages have accumulated past a conversation's last
+//! compaction, the oldest of them are summarised and recorded as a
+//! [`SnapshotType::Compaction`] [`ContextWindowSnapshot`] whose sequence
+//! range covers every message the summary stands in for. Each compaction
+//! extends the one before it, so the latest compaction alone describes the
+//! whole compacted history. A [`CompactedHistory`] assembles a
+//! conversation's history as that summary followed by the messages after
+//! its range.
+//!
+//! Pinned messages are never summarised. A pinned message inside a
+//! compacted range is kept verbatim alongside the summary, so pins survive
+//! compaction however old they are.
+
+use super::{ContextWindowSnapshot, Message, SequenceNumber};
+
+/// Messages past the last compaction that trigger the next one, unless
+/// configured otherwise.
+pub const DEFAULT_COMPACTION_THRESHOLD: usize = 60;
+
+/// Most recent messages a compaction leaves verbatim, unless configured
+/// otherwise.
+pub const DEFAULT_COMPACTION_RETAIN_RECENT: usize = 20;
+
+/// When a conversation is compacted and how much of it stays verbatim.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::CompactionPolicy;
+///
+/// let policy = CompactionPolicy::new(10, 4);
+/// assert_eq!(policy.messages_to_compact(9), 0);
+/// assert_eq!(policy.messages_to_compact(10), 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactionPolicy {
+    threshold: usize,
+    retain_recent: usize,
+}
+
+impl CompactionPolicy {
+    /// Creates a policy that compacts once `threshold` messages follow the
+    /// last compaction, keeping the newest `retain_recent` of them verbatim.
+    ///
+    /// A threshold no greater than `retain_recent` is raised to one more
+    /// than it, so every compaction summarises at least one message.
+    #[must_use]
+    pub const fn new(threshold: usize, retain_recent: usize) -> Self {
+        let floor = retain_recent.saturating_add(1);
+        Self {
+            threshold: if threshold > floor { threshold } else { floor },
+            retain_recent,
+        }
+    }
+
+    /// Returns the number of messages past the last compaction that
+    /// triggers the next one.
+    #[must_use]
+    pub const fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the number of recent messages a compaction leaves verbatim.
+    #[must_use]
+    pub const fn retain_recent(&self) -> usize {
+        self.retain_recent
+    }
+
+    /// Returns how many of the oldest `uncompacted` messages to summarise
+    /// now, or zero when the conversation is not yet due.
+    #[must_use]
+    pub const fn messages_to_compact(&self, uncompacted: usize) -> usize {
+        if uncompacted < self.threshold {
+            return 0;
+        }
+        uncompacted.saturating_sub(self.retain_recent)
+    }
+}
+
+impl Default for CompactionPolicy {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_COMPACTION_THRESHOLD,
+            DEFAULT_COMPACTION_RETAIN_RECENT,
+        )
+    }
+}
+
+/// A conversation's history with its compacted range replaced by a
+/// summary.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     AgentSessionId, CompactedHistory, ContentPart, ContextWindowSnapshot, ConversationId,
+///     Message, MessageSummary, Role, SequenceNumber, SequenceRange, SnapshotParams,
+///     SnapshotType, TextPart,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let clock = DefaultClock;
+/// let conversation_id = ConversationId::new();
+/// let messages: Vec<Message> = (1..=3)
+///     .map(|sequence| {
+///         Message::new(
+///             conversation_id,
+///             Role::User,
+///             vec![ContentPart::Text(TextPart::new("Hello"))],
+///             SequenceNumber::new(sequence),
+///             &clock,
+///         )
+///         .expect("valid message")
+///     })
+///     .collect();
+/// let compaction = ContextWindowSnapshot::new(
+///     SnapshotParams {
+///         conversation_id,
+///         session_id: AgentSessionId::new(),
+///         sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(2)),
+///         message_summary: MessageSummary::new(2, 0, 0, 0),
+///         snapshot_type: SnapshotType::Compaction,
+///     },
+///     &clock,
+/// )
+/// .with_summary("The user said hello twice.");
+///
+/// let history = CompactedHistory::assemble(Some(compaction), messages);
+/// assert_eq!(history.summary_text(), Some("The user said hello twice."));
+/// assert!(history.pinned().is_empty());
+/// assert_eq!(history.messages().len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactedHistory {
+    compaction: Option<ContextWindowSnapshot>,
+    pinned: Vec<Message>,
+    messages: Vec<Message>,
+}
+
+impl CompactedHistory {
+    /// Assembles history from a conversation's latest compaction and its
+    /// messages in sequence order.
+    ///
+    /// Messages the compaction covers are dropped unless they are pinned.
+    /// A snapshot that is not a compaction with a summary is ignored,
+    /// leaving every message in place.
+    #[must_use]
+    pub fn assemble(
+        compaction: Option<ContextWindowSnapshot>,
+        messages: impl IntoIterator<Item = Message>,
+    ) -> Self {
+        let compaction = compaction.filter(ContextWindowSnapshot::is_compaction);
+        let compacted_through = compaction
+            .as_ref()
+            .map(|snapshot| snapshot.sequence_range.end);
+        let (messages, compacted): (Vec<_>, Vec<_>) = messages.into_iter().partition(|message| {
+            compacted_through.is_none_or(|end| message.sequence_number() > end)
+        });
+        let pinned = compacted.into_iter().filter(Message::is_pinned).collect();
+        Self {
+            compaction,
+            pinned,
+            messages,
+        }
+    }
+
+    /// Returns the compaction whose summary opens the history, if any.
+    #[must_use]
+    pub const fn compaction(&self) -> Option<&ContextWindowSnapshot> {
+        self.compaction.as_ref()
+    }
+
+    /// Returns the summary that stands in for the compacted messages.
+    #[must_use]
+    pub fn summary_text(&self) -> Option<&str> {
+        self.compaction
+            .as_ref()
+            .and_then(|snapshot| snapshot.summary.as_deref())
+    }
+
+    /// Returns the sequence number of the last compacted message.
+    #[must_use]
+    pub fn compacted_through(&self) -> Option<SequenceNumber> {
+        self.compaction
+            .as_ref()
+            .map(|snapshot| snapshot.sequence_range.end)
+    }
+
+    /// Returns the pinned messages inside the compacted range, in sequence
+    /// order. They belong between the summary and [`Self::messages`].
+    #[must_use]
+    pub fn pinned(&self) -> &[Message] {
+        &self.pinned
+    }
+
+    /// Returns the messages after the compacted range, in sequence order.
+    #[must_use]
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+}
@@ -0,0 +1,265 @@
+//! Attachment and image content parts.
+
+use crate::message::domain::{AttachmentBlob, ContentHash};
+use serde::{Deserialize, Serialize};
+
+/// An attachment within a message.
+///
+/// Attachments represent files, images, or other binary content embedded
+/// in a message. A repository may move large data to a blob store, leaving
+/// `data` empty and the blob's [`ContentHash`] in `blob`.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::AttachmentPart;
+///
+/// let attachment = AttachmentPart::new("text/plain", "SGVsbG8gV29ybGQ=")
+///     .with_name("hello.txt")
+///     .with_size(11);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttachmentPart {
+    /// The MIME type of the attachment.
+    pub mime_type: String,
+    /// A display name for the attachment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The content (base64 encoded for binary data, or plain text).
+    pub data: String,
+    /// Size in bytes (for validation and display).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
+    /// The blob holding the data, when it is stored outside the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob: Option<ContentHash>,
+}
+
+impl AttachmentPart {
+    /// Creates a new attachment part.
+    #[must_use]
+    pub fn new(mime_type: impl Into<String>, data: impl Into<String>) -> Self {
+        Self {
+            mime_type: mime_type.into(),
+            name: None,
+            data: data.into(),
+            size_bytes: None,
+            blob: None,
+        }
+    }
+
+    /// Sets the display name for the attachment.
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the size in bytes.
+    #[must_use]
+    pub const fn with_size(mut self, size_bytes: u64) -> Self {
+        self.size_bytes = Some(size_bytes);
+        self
+    }
+
+    /// Returns `true` if the data is held in a blob store.
+    #[must_use]
+    pub const fn is_externalised(&self) -> bool {
+        self.blob.is_some()
+    }
+
+    /// Moves the data out of the attachment, leaving its hash in `blob`.
+    pub fn externalise(&mut self) -> AttachmentBlob {
+        let data = std::mem::take(&mut self.data);
+        let hash = ContentHash::of(data.as_bytes());
+        self.blob = Some(hash.clone());
+        AttachmentBlob { hash, data }
+    }
+
+    /// Puts data loaded from the blob store back into the attachment.
+    pub fn restore(&mut self, data: String) {
+        self.data = data;
+        self.blob = None;
+    }
+
+    /// Returns `true` if the attachment has valid structure.
+    ///
+    /// A valid attachment must have a non-empty `mime_type`, and either
+    /// non-empty `data` or a blob reference.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "String::is_empty is not const-stable"
+    )]
+    pub fn is_valid(&self) -> bool {
+        !self.mime_type.is_empty() && (!self.data.is_empty() || self.is_externalised())
+    }
+}
+
+/// An image within a message.
+///
+/// Unlike an [`AttachmentPart`], an image records its pixel dimensions, so
+/// clients can lay it out before loading the data, and optional alt text
+/// and thumbnail.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{ContentHash, ImagePart, ImageThumbnail};
+///
+/// let image = ImagePart::new("image/png", "iVBORw0KGgo=", 1920, 1080)
+///     .with_alt_text("Build dashboard showing two failing jobs")
+///     .with_thumbnail(ImageThumbnail::new(ContentHash::of(b"thumbnail"), 320, 180));
+/// assert!(image.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImagePart {
+    /// The MIME type of the image.
+    pub mime_type: String,
+    /// A display name for the image, such as its file name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The base64-encoded image data.
+    pub data: String,
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// Text describing the image for readers who cannot see it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt_text: Option<String>,
+    /// A reduced copy of the image held in a blob store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<ImageThumbnail>,
+    /// The blob holding the data, when it is stored outside the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob: Option<ContentHash>,
+}
+
+impl ImagePart {
+    /// MIME types accepted for images by default.
+    pub const SUPPORTED_FORMATS: &'static [&'static str] =
+        &["image/png", "image/jpeg", "image/gif", "image/webp"];
+
+    /// Creates a new image part.
+    #[must_use]
+    pub fn new(
+        mime_type: impl Into<String>,
+        data: impl Into<String>,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        Self {
+            mime_type: mime_type.into(),
+            name: None,
+            data: data.into(),
+            width,
+            height,
+            alt_text: None,
+            thumbnail: None,
+            blob: None,
+        }
+    }
+
+    /// Converts an image attachment into an image part with the given
+    /// dimensions, keeping its MIME type, name, data, and blob reference.
+    #[must_use]
+    pub fn from_attachment(attachment: AttachmentPart, width: u32, height: u32) -> Self {
+        Self {
+            mime_type: attachment.mime_type,
+            name: attachment.name,
+            data: attachment.data,
+            width,
+            height,
+            alt_text: None,
+            thumbnail: None,
+            blob: attachment.blob,
+        }
+    }
+
+    /// Sets the display name for the image.
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the alt text for the image.
+    #[must_use]
+    pub fn with_alt_text(mut self, alt_text: impl Into<String>) -> Self {
+        self.alt_text = Some(alt_text.into());
+        self
+    }
+
+    /// Sets the thumbnail for the image.
+    #[must_use]
+    pub fn with_thumbnail(mut self, thumbnail: ImageThumbnail) -> Self {
+        self.thumbnail = Some(thumbnail);
+        self
+    }
+
+    /// Returns `true` if `mime_type` is one of [`Self::SUPPORTED_FORMATS`].
+    #[must_use]
+    pub fn is_supported_format(mime_type: &str) -> bool {
+        Self::SUPPORTED_FORMATS.contains(&mime_type)
+    }
+
+    /// Returns `true` if the data is held in a blob store.
+    #[must_use]
+    pub const fn is_externalised(&self) -> bool {
+        self.blob.is_some()
+    }
+
+    /// Moves the data out of the image, leaving its hash in `blob`.
+    pub fn externalise(&mut self) -> AttachmentBlob {
+        let data = std::mem::take(&mut self.data);
+        let hash = ContentHash::of(data.as_bytes());
+        self.blob = Some(hash.clone());
+        AttachmentBlob { hash, data }
+    }
+
+    /// Puts data loaded from the blob store back into the image.
+    pub fn restore(&mut self, data: String) {
+        self.data = data;
+        self.blob = None;
+    }
+
+    /// Returns `true` if the image has valid structure.
+    ///
+    /// A valid image has a non-empty `mime_type`, either non-empty `data`
+    /// or a blob reference, and non-zero dimensions.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "String::is_empty is not const-stable"
+    )]
+    pub fn is_valid(&self) -> bool {
+        !self.mime_type.is_empty()
+            && (!self.data.is_empty() || self.is_externalised())
+            && self.width > 0
+            && self.height > 0
+    }
+}
+
+/// A reduced copy of an [`ImagePart`], stored as a blob.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageThumbnail {
+    /// The blob holding the thumbnail data.
+    pub blob: ContentHash,
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+}
+
+impl ImageThumbnail {
+    /// Creates a thumbnail reference.
+    #[must_use]
+    pub const fn new(blob: ContentHash, width: u32, height: u32) -> Self {
+        Self {
+            blob,
+            width,
+            height,
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Citation content parts and the sources they cite.
+
+use crate::message::domain::MessageId;
+use serde::{Deserialize, Serialize};
+
+/// A source supporting spans of the text parts in the same message.
+///
+/// Retrieval-augmented agents attach one citation per source. Each
+/// [`CitationSpan`] names a text part by its index in the message content
+/// and a half-open range of characters within it, so clients can link the
+/// cited assertion to its source.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{CitationPart, CitationSource, CitationSpan};
+///
+/// let citation = CitationPart::new(
+///     CitationSource::uri("https://doc.rust-lang.org/std/sync/struct.Mutex.html"),
+///     vec![CitationSpan::new(0, 0, 42)],
+/// )
+/// .with_title("Mutex in std::sync");
+/// assert!(citation.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CitationPart {
+    /// Where the cited material comes from.
+    pub source: CitationSource,
+    /// The spans of sibling text parts the source supports.
+    pub spans: Vec<CitationSpan>,
+    /// A display title for the source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl CitationPart {
+    /// Creates a citation of `source` for `spans`.
+    #[must_use]
+    pub const fn new(source: CitationSource, spans: Vec<CitationSpan>) -> Self {
+        Self {
+            source,
+            spans,
+            title: None,
+        }
+    }
+
+    /// Sets the display title.
+    #[must_use]
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Returns `true` if the citation names a source and at least one
+    /// non-empty span.
+    ///
+    /// Whether the spans point at text in the message is checked by
+    /// message validation.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.source.is_valid()
+            && !self.spans.is_empty()
+            && self.spans.iter().all(|span| span.start < span.end)
+    }
+
+    /// Renumbers the spans after the parts at `removed` were taken out of
+    /// the message.
+    ///
+    /// `removed` holds indices into the content as it was before removal.
+    pub(crate) fn renumber_after_removal(&mut self, removed: &[usize]) {
+        for span in &mut self.spans {
+            let shift = removed
+                .iter()
+                .filter(|&&index| index < span.part_index)
+                .count();
+            span.part_index = span.part_index.saturating_sub(shift);
+        }
+    }
+}
+
+/// Where a [`CitationPart`]'s material comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CitationSource {
+    /// A document addressed by URI.
+    Uri {
+        /// The document's URI, including its scheme.
+        uri: String,
+    },
+    /// Another message in the conversation.
+    Message {
+        /// The cited message.
+        message_id: MessageId,
+    },
+}
+
+impl CitationSource {
+    /// Creates a source addressed by URI.
+    #[must_use]
+    pub fn uri(uri: impl Into<String>) -> Self {
+        Self::Uri { uri: uri.into() }
+    }
+
+    /// Creates a source citing another message.
+    #[must_use]
+    pub const fn message(message_id: MessageId) -> Self {
+        Self::Message { message_id }
+    }
+
+    /// Returns `true` if the URI has a scheme and no whitespace, or the
+    /// message ID is not nil.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Uri { uri } => uri.split_once(':').is_some_and(|(scheme, rest)| {
+                !scheme.is_empty()
+                    && scheme
+                        .chars()
+                        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '+' | '-' | '.'))
+                    && !rest.is_empty()
+                    && !uri.chars().any(char::is_whitespace)
+            }),
+            Self::Message { message_id } => !message_id.as_ref().is_nil(),
+        }
+    }
+}
+
+/// A range of characters within one text part of a message.
+///
+/// Offsets count Unicode scalar values, not bytes, and `end` is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CitationSpan {
+    /// Index of the text part in the message content.
+    pub part_index: usize,
+    /// Offset of the first cited character.
+    pub start: usize,
+    /// Offset one past the last cited character.
+    pub end: usize,
+}
+
+impl CitationSpan {
+    /// Creates a span of `start..end` in the part at `part_index`.
+    #[must_use]
+    pub const fn new(part_index: usize, start: usize, end: usize) -> Self {
+        Self {
+            part_index,
+            start,
+            end,
+        }
+    }
+}
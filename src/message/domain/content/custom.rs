@@ -0,0 +1,59 @@
+//! Custom and redacted content parts.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A content part whose type is defined outside this crate.
+///
+/// The `kind` names a type registered with a
+/// [`crate::message::domain::ContentTypeRegistry`], which validates and
+/// renders the opaque `data`. Use [`CustomPart::encode`] and
+/// [`CustomPart::decode`] to convert to and from the registered Rust type.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::CustomPart;
+/// use serde_json::json;
+///
+/// let part = CustomPart::new("spreadsheet", json!({"sheet": "Q3", "rows": 42}));
+/// assert!(part.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomPart {
+    /// The registered type tag.
+    pub kind: String,
+    /// The serialized payload.
+    pub data: Value,
+}
+
+impl CustomPart {
+    /// Creates a custom part from a type tag and serialized payload.
+    #[must_use]
+    pub fn new(kind: impl Into<String>, data: Value) -> Self {
+        Self {
+            kind: kind.into(),
+            data,
+        }
+    }
+
+    /// Returns `true` if the part has a non-blank `kind`.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        !self.kind.trim().is_empty()
+    }
+}
+
+/// Placeholder for the content of a redacted message.
+///
+/// Repositories write this part when a message is redacted; it is never
+/// accepted in new messages. It records why and when the content was
+/// removed, but nothing of the content itself.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactedPart {
+    /// Why the content was redacted.
+    pub reason: String,
+    /// When the content was redacted.
+    pub redacted_at: DateTime<Utc>,
+}
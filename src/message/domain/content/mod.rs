@@ -0,0 +1,151 @@
+//! Content part types representing the polymorphic content structure of messages.
+//!
+//! Messages contain a "parts" array that can include text, tool calls, and attachments.
+//! This module defines the typed representation of these content variants.
+//! Images are [`ImagePart`]s, which carry their dimensions and alt text in
+//! addition to the data an [`AttachmentPart`] holds.
+//! Assistant reasoning is kept apart from the answer as [`ReasoningPart`]s.
+//! A [`CitationPart`] ties spans of the message's text parts to the source
+//! that supports them.
+//! A redacted message holds a single [`RedactedPart`] in place of its
+//! original parts.
+//!
+//! Downstream crates add their own payloads as [`CustomPart`]s, whose `kind`
+//! is registered with a [`super::ContentTypeRegistry`].
+
+mod attachment;
+mod citation;
+mod custom;
+mod text;
+mod tool;
+
+pub use attachment::{AttachmentPart, ImagePart, ImageThumbnail};
+pub use citation::{CitationPart, CitationSource, CitationSpan};
+pub use custom::{CustomPart, RedactedPart};
+pub use text::{ReasoningPart, TextPart};
+pub use tool::{ToolCallPart, ToolResultPart};
+
+use serde::{Deserialize, Serialize};
+
+/// A single content part within a message.
+///
+/// Messages are composed of one or more content parts, allowing rich content
+/// that combines text, tool interactions, and attachments.
+///
+/// # Serialisation
+///
+/// Content parts are serialised with a `type` tag field:
+///
+/// ```json
+/// { "type": "text", "text": "Hello, world!" }
+/// { "type": "reasoning", "text": "The user wants...", "redacted": false }
+/// { "type": "tool_call", "call_id": "...", "name": "...", "arguments": {...} }
+/// { "type": "image", "mime_type": "image/png", "data": "...", "width": 800, "height": 600 }
+/// { "type": "citation", "source": {"kind": "uri", "uri": "..."}, "spans": [...] }
+/// { "type": "custom", "kind": "spreadsheet", "data": {...} }
+/// { "type": "redacted", "reason": "...", "redacted_at": "..." }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    /// Plain text content.
+    Text(TextPart),
+    /// Reasoning an assistant produced before its answer.
+    Reasoning(ReasoningPart),
+    /// A tool call request from an assistant.
+    ToolCall(ToolCallPart),
+    /// A tool execution result.
+    ToolResult(ToolResultPart),
+    /// An attachment (file, image, etc.).
+    Attachment(AttachmentPart),
+    /// An image with its dimensions.
+    Image(ImagePart),
+    /// A source supporting spans of the message's text.
+    Citation(CitationPart),
+    /// A payload of a type registered by a downstream crate.
+    Custom(CustomPart),
+    /// Placeholder left where redacted content used to be.
+    Redacted(RedactedPart),
+}
+
+impl ContentPart {
+    /// Returns the kind of this content part.
+    #[must_use]
+    pub const fn kind(&self) -> ContentPartKind {
+        match self {
+            Self::Text(_) => ContentPartKind::Text,
+            Self::Reasoning(_) => ContentPartKind::Reasoning,
+            Self::ToolCall(_) => ContentPartKind::ToolCall,
+            Self::ToolResult(_) => ContentPartKind::ToolResult,
+            Self::Attachment(_) => ContentPartKind::Attachment,
+            Self::Image(_) => ContentPartKind::Image,
+            Self::Citation(_) => ContentPartKind::Citation,
+            Self::Custom(_) => ContentPartKind::Custom,
+            Self::Redacted(_) => ContentPartKind::Redacted,
+        }
+    }
+}
+
+/// The variant of a [`ContentPart`], without its payload.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{ContentPart, ContentPartKind, TextPart};
+///
+/// let part = ContentPart::Text(TextPart::new("Hello"));
+/// assert_eq!(part.kind(), ContentPartKind::Text);
+/// assert_eq!(ContentPartKind::ToolResult.as_str(), "tool_result");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentPartKind {
+    /// Plain text content.
+    Text,
+    /// Assistant reasoning.
+    Reasoning,
+    /// A tool call request.
+    ToolCall,
+    /// A tool execution result.
+    ToolResult,
+    /// An attachment.
+    Attachment,
+    /// An image.
+    Image,
+    /// A source reference.
+    Citation,
+    /// A downstream-registered payload.
+    Custom,
+    /// A redaction placeholder.
+    Redacted,
+}
+
+impl ContentPartKind {
+    /// Every kind of content part.
+    pub const ALL: [Self; 9] = [
+        Self::Text,
+        Self::Reasoning,
+        Self::ToolCall,
+        Self::ToolResult,
+        Self::Attachment,
+        Self::Image,
+        Self::Citation,
+        Self::Custom,
+        Self::Redacted,
+    ];
+
+    /// Returns the serialised `type` tag of parts of this kind.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Reasoning => "reasoning",
+            Self::ToolCall => "tool_call",
+            Self::ToolResult => "tool_result",
+            Self::Attachment => "attachment",
+            Self::Image => "image",
+            Self::Citation => "citation",
+            Self::Custom => "custom",
+            Self::Redacted => "redacted",
+        }
+    }
+}
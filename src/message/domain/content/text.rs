@@ -0,0 +1,88 @@
+//! Text and reasoning content parts.
+
+use serde::{Deserialize, Serialize};
+
+/// Text content within a message.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::TextPart;
+///
+/// let text = TextPart::new("Hello, Corbusier!");
+/// assert!(!text.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextPart {
+    /// The text content.
+    pub text: String,
+}
+
+impl TextPart {
+    /// Creates a new text part.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+
+    /// Returns `true` if the text content is empty or whitespace-only.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Returns the length of the text content in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.text.len()
+    }
+}
+
+/// Reasoning an assistant produced before its answer.
+///
+/// Backends that expose chain-of-thought emit it separately from the
+/// answer. A redacted part holds the opaque form the backend returned in
+/// place of readable reasoning; it is kept so the backend can be handed its
+/// own reasoning back, but it must not be shown to users.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::ReasoningPart;
+///
+/// let reasoning = ReasoningPart::new("The tests fail on CI only, so check the env.");
+/// assert!(!reasoning.redacted);
+/// assert!(ReasoningPart::new("EqgBCkYIBh...").into_redacted().redacted);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReasoningPart {
+    /// The reasoning text, or the backend's opaque form when redacted.
+    pub text: String,
+    /// Whether the backend redacted the reasoning.
+    #[serde(default)]
+    pub redacted: bool,
+}
+
+impl ReasoningPart {
+    /// Creates a readable reasoning part.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            redacted: false,
+        }
+    }
+
+    /// Marks the reasoning as redacted by the backend.
+    #[must_use]
+    pub const fn into_redacted(mut self) -> Self {
+        self.redacted = true;
+        self
+    }
+
+    /// Returns `true` if the reasoning is empty or whitespace-only.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+}
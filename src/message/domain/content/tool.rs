@@ -0,0 +1,113 @@
+//! Tool call and tool result content parts.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A tool call request within an assistant message.
+///
+/// Tool calls represent requests from the assistant to invoke external tools.
+/// Each call has a unique identifier for matching with results.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::ToolCallPart;
+/// use serde_json::json;
+///
+/// let call = ToolCallPart::new("call-123", "read_file", json!({"path": "/tmp/test.txt"}));
+/// assert!(call.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolCallPart {
+    /// Unique identifier for this tool call (for matching with results).
+    pub call_id: String,
+    /// The name of the tool being invoked.
+    pub name: String,
+    /// Arguments passed to the tool as JSON.
+    pub arguments: Value,
+}
+
+impl ToolCallPart {
+    /// Creates a new tool call part.
+    #[must_use]
+    pub fn new(call_id: impl Into<String>, name: impl Into<String>, arguments: Value) -> Self {
+        Self {
+            call_id: call_id.into(),
+            name: name.into(),
+            arguments,
+        }
+    }
+
+    /// Returns `true` if the tool call has valid structure.
+    ///
+    /// A valid tool call must have non-empty `call_id` and `name` fields.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "String::is_empty is not const-stable"
+    )]
+    pub fn is_valid(&self) -> bool {
+        !self.call_id.is_empty() && !self.name.is_empty()
+    }
+}
+
+/// A tool execution result within a tool message.
+///
+/// Tool results carry the output of a tool invocation back to the assistant,
+/// matched by `call_id` to the originating request.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::ToolResultPart;
+/// use serde_json::json;
+///
+/// let result = ToolResultPart::success("call-123", json!({"content": "file data"}));
+/// assert!(result.success);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolResultPart {
+    /// The `call_id` this result corresponds to.
+    pub call_id: String,
+    /// The result content (can be structured JSON or plain text).
+    pub content: Value,
+    /// Whether the tool execution was successful.
+    #[serde(default = "default_success")]
+    pub success: bool,
+}
+
+const fn default_success() -> bool {
+    true
+}
+
+impl ToolResultPart {
+    /// Creates a successful tool result.
+    #[must_use]
+    pub fn success(call_id: impl Into<String>, content: Value) -> Self {
+        Self {
+            call_id: call_id.into(),
+            content,
+            success: true,
+        }
+    }
+
+    /// Creates a failed tool result.
+    #[must_use]
+    pub fn failure(call_id: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            call_id: call_id.into(),
+            content: Value::String(error.into()),
+            success: false,
+        }
+    }
+
+    /// Returns `true` if the tool call has a valid `call_id`.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "String::is_empty is not const-stable"
+    )]
+    pub fn is_valid(&self) -> bool {
+        !self.call_id.is_empty()
+    }
+}
@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::handoff::ToolCallReference;
-use super::{AgentSessionId, ConversationId, SequenceNumber};
+use super::{AgentSessionId, ConversationId, Role, SequenceNumber};
 
 /// A snapshot of the context window at a point in time.
 ///
@@ -66,6 +66,20 @@ pub struct ContextWindowSnapshot {
 
     /// Type of snapshot.
     pub snapshot_type: SnapshotType,
+
+    /// Summary that stands in for the messages in `sequence_range`.
+    ///
+    /// Set on [`SnapshotType::Compaction`] snapshots only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    /// The snapshot this one extends.
+    ///
+    /// Set on incremental snapshots, whose `sequence_range`, message
+    /// summary, and tool calls cover only the messages after the parent's
+    /// range.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_snapshot_id: Option<Uuid>,
 }
 
 /// Parameters for creating a context window snapshot.
@@ -86,7 +100,7 @@ pub struct SnapshotParams {
 impl ContextWindowSnapshot {
     /// Creates a new context window snapshot.
     #[must_use]
-    pub fn new(params: SnapshotParams, clock: &impl mockable::Clock) -> Self {
+    pub fn new(params: SnapshotParams, clock: &(impl mockable::Clock + ?Sized)) -> Self {
         Self {
             snapshot_id: Uuid::new_v4(),
             conversation_id: params.conversation_id,
@@ -97,6 +111,8 @@ impl ContextWindowSnapshot {
             token_estimate: None,
             captured_at: clock.utc(),
             snapshot_type: params.snapshot_type,
+            summary: None,
+            parent_snapshot_id: None,
         }
     }
 
@@ -123,6 +139,33 @@ impl ContextWindowSnapshot {
         self.token_estimate = Some(estimate);
         self
     }
+
+    /// Sets the summary that stands in for the snapshot's range.
+    #[must_use]
+    pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// Makes the snapshot an increment on `parent_snapshot_id`.
+    #[must_use]
+    pub const fn with_parent(mut self, parent_snapshot_id: Uuid) -> Self {
+        self.parent_snapshot_id = Some(parent_snapshot_id);
+        self
+    }
+
+    /// Returns `true` if this snapshot only records the messages after its
+    /// parent's range.
+    #[must_use]
+    pub const fn is_incremental(&self) -> bool {
+        self.parent_snapshot_id.is_some()
+    }
+
+    /// Returns `true` if this snapshot compacts its range into a summary.
+    #[must_use]
+    pub fn is_compaction(&self) -> bool {
+        self.snapshot_type == SnapshotType::Compaction && self.summary.is_some()
+    }
 }
 
 /// The sequence number range for a context window.
@@ -210,6 +253,17 @@ impl MessageSummary {
     pub const fn is_empty(&self) -> bool {
         self.total() == 0
     }
+
+    /// Counts one message with the given role.
+    pub const fn record(&mut self, role: Role) {
+        let counter = match role {
+            Role::User => &mut self.user_count,
+            Role::Assistant => &mut self.assistant_count,
+            Role::Tool => &mut self.tool_count,
+            Role::System => &mut self.system_count,
+        };
+        *counter = counter.saturating_add(1);
+    }
 }
 
 /// Type of context snapshot.
@@ -227,8 +281,17 @@ pub enum SnapshotType {
     /// Captured when context window is truncated.
     Truncation,
 
-    /// Periodic checkpoint.
+    /// Checkpoint requested by the caller.
     Checkpoint,
+
+    /// Captured when a range of history is replaced by a summary.
+    Compaction,
+
+    /// Captured when an agent session is paused.
+    SessionPaused,
+
+    /// Captured on a schedule while an agent session runs.
+    Periodic,
 }
 
 impl SnapshotType {
@@ -240,6 +303,9 @@ impl SnapshotType {
             Self::HandoffInitiated => "handoff_initiated",
             Self::Truncation => "truncation",
             Self::Checkpoint => "checkpoint",
+            Self::Compaction => "compaction",
+            Self::SessionPaused => "session_paused",
+            Self::Periodic => "periodic",
         }
     }
 }
@@ -271,88 +337,10 @@ impl TryFrom<&str> for SnapshotType {
             "handoff_initiated" => Ok(Self::HandoffInitiated),
             "truncation" => Ok(Self::Truncation),
             "checkpoint" => Ok(Self::Checkpoint),
+            "compaction" => Ok(Self::Compaction),
+            "session_paused" => Ok(Self::SessionPaused),
+            "periodic" => Ok(Self::Periodic),
             _ => Err(ParseSnapshotTypeError(s.to_owned())),
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    //! Tests for context snapshot construction and accessors.
-
-    use super::*;
-    use mockable::DefaultClock;
-
-    #[test]
-    fn context_snapshot_creation() {
-        let clock = DefaultClock;
-        let conv_id = ConversationId::new();
-        let session_id = AgentSessionId::new();
-        let range = SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(10));
-        let summary = MessageSummary::new(5, 4, 1, 0);
-
-        let params = SnapshotParams {
-            conversation_id: conv_id,
-            session_id,
-            sequence_range: range,
-            message_summary: summary,
-            snapshot_type: SnapshotType::SessionStart,
-        };
-        let snapshot = ContextWindowSnapshot::new(params, &clock);
-
-        assert_eq!(snapshot.conversation_id, conv_id);
-        assert_eq!(snapshot.session_id, session_id);
-        assert_eq!(snapshot.sequence_range, range);
-        assert_eq!(snapshot.message_summary.total(), 10);
-        assert!(snapshot.visible_tool_calls.is_empty());
-        assert!(snapshot.token_estimate.is_none());
-    }
-
-    #[test]
-    fn sequence_range_len() {
-        let range = SequenceRange::new(SequenceNumber::new(5), SequenceNumber::new(10));
-        assert_eq!(range.len(), 6);
-        assert!(!range.is_empty());
-
-        let single = SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(1));
-        assert_eq!(single.len(), 1);
-
-        let empty = SequenceRange::new(SequenceNumber::new(10), SequenceNumber::new(5));
-        assert_eq!(empty.len(), 0);
-        assert!(empty.is_empty());
-    }
-
-    #[test]
-    fn sequence_range_contains() {
-        let range = SequenceRange::new(SequenceNumber::new(5), SequenceNumber::new(10));
-
-        assert!(!range.contains(SequenceNumber::new(4)));
-        assert!(range.contains(SequenceNumber::new(5)));
-        assert!(range.contains(SequenceNumber::new(7)));
-        assert!(range.contains(SequenceNumber::new(10)));
-        assert!(!range.contains(SequenceNumber::new(11)));
-    }
-
-    #[test]
-    fn message_summary_total() {
-        let summary = MessageSummary::new(3, 2, 1, 0);
-        assert_eq!(summary.total(), 6);
-        assert!(!summary.is_empty());
-
-        let empty = MessageSummary::default();
-        assert_eq!(empty.total(), 0);
-        assert!(empty.is_empty());
-    }
-
-    #[test]
-    fn snapshot_type_serialization() {
-        assert_eq!(
-            serde_json::to_string(&SnapshotType::SessionStart).expect("serialization"),
-            "\"session_start\""
-        );
-        assert_eq!(
-            serde_json::to_string(&SnapshotType::HandoffInitiated).expect("serialization"),
-            "\"handoff_initiated\""
-        );
-    }
-}
@@ -0,0 +1,92 @@
+//! Tests for context snapshot construction and accessors.
+
+use super::{
+    AgentSessionId, ContextWindowSnapshot, ConversationId, MessageSummary, SequenceNumber,
+    SequenceRange, SnapshotParams, SnapshotType,
+};
+use mockable::DefaultClock;
+
+#[test]
+fn context_snapshot_creation() {
+    let clock = DefaultClock;
+    let conv_id = ConversationId::new();
+    let session_id = AgentSessionId::new();
+    let range = SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(10));
+    let summary = MessageSummary::new(5, 4, 1, 0);
+
+    let params = SnapshotParams {
+        conversation_id: conv_id,
+        session_id,
+        sequence_range: range,
+        message_summary: summary,
+        snapshot_type: SnapshotType::SessionStart,
+    };
+    let snapshot = ContextWindowSnapshot::new(params, &clock);
+
+    assert_eq!(snapshot.conversation_id, conv_id);
+    assert_eq!(snapshot.session_id, session_id);
+    assert_eq!(snapshot.sequence_range, range);
+    assert_eq!(snapshot.message_summary.total(), 10);
+    assert!(snapshot.visible_tool_calls.is_empty());
+    assert!(snapshot.token_estimate.is_none());
+}
+
+#[test]
+fn sequence_range_len() {
+    let range = SequenceRange::new(SequenceNumber::new(5), SequenceNumber::new(10));
+    assert_eq!(range.len(), 6);
+    assert!(!range.is_empty());
+
+    let single = SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(1));
+    assert_eq!(single.len(), 1);
+
+    let empty = SequenceRange::new(SequenceNumber::new(10), SequenceNumber::new(5));
+    assert_eq!(empty.len(), 0);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn sequence_range_contains() {
+    let range = SequenceRange::new(SequenceNumber::new(5), SequenceNumber::new(10));
+
+    assert!(!range.contains(SequenceNumber::new(4)));
+    assert!(range.contains(SequenceNumber::new(5)));
+    assert!(range.contains(SequenceNumber::new(7)));
+    assert!(range.contains(SequenceNumber::new(10)));
+    assert!(!range.contains(SequenceNumber::new(11)));
+}
+
+#[test]
+fn message_summary_total() {
+    let summary = MessageSummary::new(3, 2, 1, 0);
+    assert_eq!(summary.total(), 6);
+    assert!(!summary.is_empty());
+
+    let empty = MessageSummary::default();
+    assert_eq!(empty.total(), 0);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn snapshot_type_serialization() {
+    assert_eq!(
+        serde_json::to_string(&SnapshotType::SessionStart).expect("serialization"),
+        "\"session_start\""
+    );
+    assert_eq!(
+        serde_json::to_string(&SnapshotType::HandoffInitiated).expect("serialization"),
+        "\"handoff_initiated\""
+    );
+    assert_eq!(
+        serde_json::to_string(&SnapshotType::SessionPaused).expect("serialization"),
+        "\"session_paused\""
+    );
+    assert_eq!(
+        SnapshotType::try_from("session_paused"),
+        Ok(SnapshotType::SessionPaused)
+    );
+    assert_eq!(
+        SnapshotType::try_from("periodic"),
+        Ok(SnapshotType::Periodic)
+    );
+}
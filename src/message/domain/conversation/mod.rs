@@ -0,0 +1,343 @@
+//! Conversation aggregate root for message-history workflows.
+//!
+//! Conversations group immutable messages into a single thread and provide the
+//! anchor entity used by the HTTP conversation API.
+//!
+//! A conversation moves through a small state machine. Active conversations
+//! can be paused and resumed, and either active or paused conversations can
+//! be completed. Any conversation that is not yet archived can be archived.
+//!
+//! Archiving a conversation closes it to further writes: repositories reject
+//! new messages, agent sessions, and handoffs against archived conversations.
+//! Archiving is open to any caller, but reopening archived work requires
+//! [`ConversationAccess::Elevated`].
+//!
+//! Conversations also carry [labels](ConversationLabel): key/value pairs
+//! operators use to slice conversations by project, customer, or experiment.
+//! Setting or removing a label reports the [`ConversationLabelChange`] for
+//! the audit trail.
+
+mod state;
+
+pub use state::{
+    ConversationAccess, ConversationLifecycleError, ConversationState, ConversationStateParseError,
+};
+
+use super::{ConversationId, ConversationLabel, ConversationLabelChange};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// Conversation aggregate root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Conversation {
+    id: ConversationId,
+    state: ConversationState,
+    context: Value,
+    task_id: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    labels: BTreeMap<String, String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    #[serde(default)]
+    version: u64,
+}
+
+impl Conversation {
+    /// Creates a new active conversation with an empty context.
+    #[must_use]
+    pub fn new(clock: &(impl Clock + ?Sized)) -> Self {
+        let now = clock.utc();
+        Self {
+            id: ConversationId::new(),
+            state: ConversationState::Active,
+            context: Value::Object(serde_json::Map::new()),
+            task_id: None,
+            labels: BTreeMap::new(),
+            created_at: now,
+            updated_at: now,
+            version: 0,
+        }
+    }
+
+    /// Reconstructs a persisted conversation.
+    #[must_use]
+    pub fn from_persisted(
+        id: ConversationId,
+        state: ConversationState,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            state,
+            context: Value::Object(serde_json::Map::new()),
+            task_id: None,
+            labels: BTreeMap::new(),
+            created_at,
+            updated_at,
+            version: 0,
+        }
+    }
+
+    /// Sets the context data without touching the update timestamp.
+    ///
+    /// Intended for construction and reconstruction; use
+    /// [`Self::replace_context`] to change a live conversation.
+    #[must_use]
+    pub fn with_context(mut self, context: Value) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Sets the linked task without touching the update timestamp.
+    ///
+    /// Intended for construction and reconstruction; use
+    /// [`Self::link_task`] to change a live conversation.
+    #[must_use]
+    pub const fn with_task(mut self, task_id: Uuid) -> Self {
+        self.task_id = Some(task_id);
+        self
+    }
+
+    /// Sets the labels without touching the update timestamp.
+    ///
+    /// Intended for reconstruction by repositories; use
+    /// [`Self::set_label`] and [`Self::remove_label`] to change a live
+    /// conversation.
+    #[must_use]
+    pub fn with_labels(mut self, labels: BTreeMap<String, String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets the optimistic concurrency version without touching the update
+    /// timestamp.
+    ///
+    /// Intended for reconstruction by repositories.
+    #[must_use]
+    pub const fn with_version(mut self, version: u64) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Returns the conversation identifier.
+    #[must_use]
+    pub const fn id(&self) -> ConversationId {
+        self.id
+    }
+
+    /// Returns the conversation state.
+    #[must_use]
+    pub const fn state(&self) -> ConversationState {
+        self.state
+    }
+
+    /// Returns the conversation's context data.
+    #[must_use]
+    pub const fn context(&self) -> &Value {
+        &self.context
+    }
+
+    /// Returns the task the conversation works on, if linked.
+    #[must_use]
+    pub const fn task_id(&self) -> Option<Uuid> {
+        self.task_id
+    }
+
+    /// Returns the conversation's labels, ordered by key.
+    #[must_use]
+    pub const fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    /// Returns the value of the label with `key`, if set.
+    #[must_use]
+    pub fn label(&self, key: &str) -> Option<&str> {
+        self.labels.get(key).map(String::as_str)
+    }
+
+    /// Returns the creation timestamp.
+    #[must_use]
+    pub const fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Returns the latest update timestamp.
+    #[must_use]
+    pub const fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    /// Returns the optimistic concurrency version: the number of updates
+    /// persisted for this conversation when it was loaded.
+    ///
+    /// [`ConversationRepository::update`](crate::message::ports::conversation::ConversationRepository::update)
+    /// rejects a conversation whose version no longer matches the stored one.
+    #[must_use]
+    pub const fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns `true` when the conversation is closed to writes.
+    #[must_use]
+    pub const fn is_archived(&self) -> bool {
+        matches!(self.state, ConversationState::Archived)
+    }
+
+    /// Archives the conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationLifecycleError::AlreadyArchived`] when the
+    /// conversation is already archived.
+    pub fn archive(
+        &mut self,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<(), ConversationLifecycleError> {
+        if self.is_archived() {
+            return Err(ConversationLifecycleError::AlreadyArchived(self.id));
+        }
+        self.state = ConversationState::Archived;
+        self.updated_at = clock.utc();
+        Ok(())
+    }
+
+    /// Reopens an archived conversation as active.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationLifecycleError::ElevatedAccessRequired`] unless
+    /// `access` is elevated, or [`ConversationLifecycleError::NotArchived`]
+    /// when the conversation is not archived.
+    pub fn unarchive(
+        &mut self,
+        access: ConversationAccess,
+        clock: &impl Clock,
+    ) -> Result<(), ConversationLifecycleError> {
+        if access != ConversationAccess::Elevated {
+            return Err(ConversationLifecycleError::ElevatedAccessRequired(self.id));
+        }
+        if !self.is_archived() {
+            return Err(ConversationLifecycleError::NotArchived(self.id));
+        }
+        self.state = ConversationState::Active;
+        self.updated_at = clock.utc();
+        Ok(())
+    }
+
+    /// Pauses an active conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationLifecycleError::InvalidTransition`] unless the
+    /// conversation is active.
+    pub fn pause(&mut self, clock: &impl Clock) -> Result<(), ConversationLifecycleError> {
+        self.transition(
+            &[ConversationState::Active],
+            ConversationState::Paused,
+            clock,
+        )
+    }
+
+    /// Resumes a paused conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationLifecycleError::InvalidTransition`] unless the
+    /// conversation is paused.
+    pub fn resume(&mut self, clock: &impl Clock) -> Result<(), ConversationLifecycleError> {
+        self.transition(
+            &[ConversationState::Paused],
+            ConversationState::Active,
+            clock,
+        )
+    }
+
+    /// Marks an active or paused conversation as completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationLifecycleError::InvalidTransition`] when the
+    /// conversation is already completed or archived.
+    pub fn complete(&mut self, clock: &impl Clock) -> Result<(), ConversationLifecycleError> {
+        self.transition(
+            &[ConversationState::Active, ConversationState::Paused],
+            ConversationState::Completed,
+            clock,
+        )
+    }
+
+    /// Replaces the conversation's context data.
+    pub fn replace_context(&mut self, context: Value, clock: &impl Clock) {
+        self.context = context;
+        self.updated_at = clock.utc();
+    }
+
+    /// Links the conversation to a task, or unlinks it with `None`.
+    pub fn link_task(&mut self, task_id: Option<Uuid>, clock: &impl Clock) {
+        self.task_id = task_id;
+        self.updated_at = clock.utc();
+    }
+
+    /// Sets a label, replacing any value it held.
+    ///
+    /// Returns the change, or `None` when the label already held the value.
+    pub fn set_label(
+        &mut self,
+        label: ConversationLabel,
+        clock: &(impl Clock + ?Sized),
+    ) -> Option<ConversationLabelChange> {
+        let (key, value) = label.into_parts();
+        if self.label(&key) == Some(value.as_str()) {
+            return None;
+        }
+        let previous = self.labels.insert(key.clone(), value.clone());
+        self.updated_at = clock.utc();
+        Some(ConversationLabelChange {
+            conversation_id: self.id,
+            key,
+            previous,
+            value: Some(value),
+        })
+    }
+
+    /// Removes a label.
+    ///
+    /// Returns the change, or `None` when the label was not set.
+    pub fn remove_label(
+        &mut self,
+        key: &str,
+        clock: &(impl Clock + ?Sized),
+    ) -> Option<ConversationLabelChange> {
+        let previous = self.labels.remove(key)?;
+        self.updated_at = clock.utc();
+        Some(ConversationLabelChange {
+            conversation_id: self.id,
+            key: key.to_owned(),
+            previous: Some(previous),
+            value: None,
+        })
+    }
+
+    fn transition(
+        &mut self,
+        allowed_from: &[ConversationState],
+        to: ConversationState,
+        clock: &impl Clock,
+    ) -> Result<(), ConversationLifecycleError> {
+        if !allowed_from.contains(&self.state) {
+            return Err(ConversationLifecycleError::InvalidTransition {
+                conversation_id: self.id,
+                from: self.state,
+                to,
+            });
+        }
+        self.state = to;
+        self.updated_at = clock.utc();
+        Ok(())
+    }
+}
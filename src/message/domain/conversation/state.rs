@@ -0,0 +1,99 @@
+//! Conversation lifecycle states and the errors raised moving between them.
+
+use crate::message::domain::ConversationId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// Conversation lifecycle state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationState {
+    /// Conversation is active and accepts new messages.
+    Active,
+    /// Conversation is paused.
+    Paused,
+    /// Conversation reached its goal.
+    Completed,
+    /// Conversation is archived.
+    Archived,
+}
+
+impl ConversationState {
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Paused => "paused",
+            Self::Completed => "completed",
+            Self::Archived => "archived",
+        }
+    }
+}
+
+impl fmt::Display for ConversationState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Caller authority over a conversation's lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConversationAccess {
+    /// Ordinary participant access.
+    #[default]
+    Standard,
+    /// Elevated access, required to unarchive conversations.
+    Elevated,
+}
+
+/// Errors raised by conversation lifecycle transitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ConversationLifecycleError {
+    /// The conversation is already archived.
+    #[error("conversation {0} is already archived")]
+    AlreadyArchived(ConversationId),
+    /// The conversation is not archived.
+    #[error("conversation {0} is not archived")]
+    NotArchived(ConversationId),
+    /// Unarchiving requires elevated access.
+    #[error("unarchiving conversation {0} requires elevated access")]
+    ElevatedAccessRequired(ConversationId),
+    /// The conversation's state does not allow the transition.
+    #[error("conversation {conversation_id} cannot move from {from} to {to}")]
+    InvalidTransition {
+        /// The conversation.
+        conversation_id: ConversationId,
+        /// The conversation's current state.
+        from: ConversationState,
+        /// The requested state.
+        to: ConversationState,
+    },
+}
+
+/// Error type for invalid conversation state strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversationStateParseError(pub String);
+
+impl std::fmt::Display for ConversationStateParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown conversation state: {}", self.0)
+    }
+}
+
+impl std::error::Error for ConversationStateParseError {}
+
+impl TryFrom<&str> for ConversationState {
+    type Error = ConversationStateParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "active" => Ok(Self::Active),
+            "paused" => Ok(Self::Paused),
+            "completed" => Ok(Self::Completed),
+            "archived" => Ok(Self::Archived),
+            _ => Err(ConversationStateParseError(value.to_owned())),
+        }
+    }
+}
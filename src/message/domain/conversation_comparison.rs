@@ -0,0 +1,272 @@
+//! Side-by-side comparison of two branches of a forked conversation.
+//!
+//! Two conversations forked from the same history share a prefix of
+//! messages and then diverge. [`ConversationComparison`] finds the
+//! divergence point and reports, from there on, the messages that differ
+//! position by position, the tool calls only one branch made, and how each
+//! branch ended. Messages are matched on role and content, so copies of the
+//! shared history with fresh IDs and timestamps still line up.
+
+use super::{ContentPart, ConversationId, Message, Role, ToolCallPart};
+
+/// Alignment of two conversation branches from their divergence point.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     ContentPart, ConversationComparison, ConversationId, Message, Role, SequenceNumber,
+///     TextPart,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let say = |conversation_id, role, text: &str, seq| {
+///     Message::new(
+///         conversation_id,
+///         role,
+///         vec![ContentPart::Text(TextPart::new(text))],
+///         SequenceNumber::new(seq),
+///         &DefaultClock,
+///     )
+///     .expect("valid message")
+/// };
+/// let (left, right) = (ConversationId::new(), ConversationId::new());
+/// let comparison = ConversationComparison::compare(
+///     (left, &[say(left, Role::User, "Fix it", 1), say(left, Role::Assistant, "Patched", 2)]),
+///     (right, &[say(right, Role::User, "Fix it", 1), say(right, Role::Assistant, "Rewrote", 2)]),
+/// );
+///
+/// assert_eq!(comparison.shared_messages(), 1);
+/// assert_eq!(comparison.differing_messages().len(), 1);
+/// assert_eq!(comparison.right().outcome().final_response(), Some("Rewrote"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversationComparison {
+    shared_messages: usize,
+    differing_messages: Vec<MessageDifference>,
+    left: BranchDivergence,
+    right: BranchDivergence,
+}
+
+/// One branch of a conversation after the divergence point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchDivergence {
+    conversation_id: ConversationId,
+    messages: Vec<Message>,
+    unique_tool_calls: Vec<ToolCallPart>,
+    outcome: BranchOutcome,
+}
+
+/// How a branch ended, summarised from its messages after the divergence
+/// point.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BranchOutcome {
+    successful_tool_results: usize,
+    failed_tool_results: usize,
+    final_response: Option<String>,
+}
+
+/// Messages at the same position after the divergence point that differ.
+///
+/// A side is `None` when that branch has no message at the position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageDifference {
+    /// Position after the divergence point, starting at zero.
+    pub offset: usize,
+    /// The left branch's message.
+    pub left: Option<Message>,
+    /// The right branch's message.
+    pub right: Option<Message>,
+}
+
+impl ConversationComparison {
+    /// Compares two branches, each given as its conversation ID and its
+    /// messages in sequence order.
+    #[must_use]
+    pub fn compare(
+        left: (ConversationId, &[Message]),
+        right: (ConversationId, &[Message]),
+    ) -> Self {
+        let (left_id, left_messages) = left;
+        let (right_id, right_messages) = right;
+        let shared_messages = left_messages
+            .iter()
+            .zip(right_messages)
+            .take_while(|(l, r)| same_turn(l, r))
+            .count();
+        let left_tail = left_messages.get(shared_messages..).unwrap_or_default();
+        let right_tail = right_messages.get(shared_messages..).unwrap_or_default();
+        Self {
+            shared_messages,
+            differing_messages: align(left_tail, right_tail),
+            left: BranchDivergence::new(left_id, left_tail, right_tail),
+            right: BranchDivergence::new(right_id, right_tail, left_tail),
+        }
+    }
+
+    /// Returns how many leading messages both branches share.
+    #[must_use]
+    pub const fn shared_messages(&self) -> usize {
+        self.shared_messages
+    }
+
+    /// Returns `true` when the branches hold the same messages.
+    #[must_use]
+    pub const fn is_identical(&self) -> bool {
+        self.differing_messages.is_empty()
+    }
+
+    /// Returns the positions after the divergence point where the branches
+    /// differ.
+    #[must_use]
+    pub fn differing_messages(&self) -> &[MessageDifference] {
+        &self.differing_messages
+    }
+
+    /// Returns the left branch.
+    #[must_use]
+    pub const fn left(&self) -> &BranchDivergence {
+        &self.left
+    }
+
+    /// Returns the right branch.
+    #[must_use]
+    pub const fn right(&self) -> &BranchDivergence {
+        &self.right
+    }
+}
+
+impl BranchDivergence {
+    fn new(conversation_id: ConversationId, tail: &[Message], other_tail: &[Message]) -> Self {
+        let other_calls: Vec<&ToolCallPart> = other_tail.iter().flat_map(tool_calls).collect();
+        let unique_tool_calls = tail
+            .iter()
+            .flat_map(tool_calls)
+            .filter(|call| !other_calls.iter().any(|other| same_call(call, other)))
+            .cloned()
+            .collect();
+        Self {
+            conversation_id,
+            messages: tail.to_vec(),
+            unique_tool_calls,
+            outcome: BranchOutcome::from_messages(tail),
+        }
+    }
+
+    /// Returns the branch's conversation.
+    #[must_use]
+    pub const fn conversation_id(&self) -> ConversationId {
+        self.conversation_id
+    }
+
+    /// Returns the branch's messages after the divergence point.
+    #[must_use]
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Returns tool calls made after the divergence point that the other
+    /// branch did not make with the same name and arguments.
+    #[must_use]
+    pub fn unique_tool_calls(&self) -> &[ToolCallPart] {
+        &self.unique_tool_calls
+    }
+
+    /// Returns how the branch ended.
+    #[must_use]
+    pub const fn outcome(&self) -> &BranchOutcome {
+        &self.outcome
+    }
+}
+
+impl BranchOutcome {
+    fn from_messages(messages: &[Message]) -> Self {
+        let (successful_tool_results, failed_tool_results) = messages
+            .iter()
+            .flat_map(Message::content)
+            .filter_map(|part| match part {
+                ContentPart::ToolResult(result) => Some(result.success),
+                _ => None,
+            })
+            .fold((0, 0), |(ok, failed), success| {
+                if success {
+                    (ok + 1, failed)
+                } else {
+                    (ok, failed + 1)
+                }
+            });
+        Self {
+            successful_tool_results,
+            failed_tool_results,
+            final_response: messages.iter().rev().find_map(assistant_text),
+        }
+    }
+
+    /// Returns how many tool calls succeeded after the divergence point.
+    #[must_use]
+    pub const fn successful_tool_results(&self) -> usize {
+        self.successful_tool_results
+    }
+
+    /// Returns how many tool calls failed after the divergence point.
+    #[must_use]
+    pub const fn failed_tool_results(&self) -> usize {
+        self.failed_tool_results
+    }
+
+    /// Returns the text of the branch's last assistant reply after the
+    /// divergence point, if any.
+    #[must_use]
+    pub fn final_response(&self) -> Option<&str> {
+        self.final_response.as_deref()
+    }
+}
+
+fn same_turn(left: &Message, right: &Message) -> bool {
+    left.role() == right.role() && left.content() == right.content()
+}
+
+/// Tool call IDs are minted per call, so calls match on name and
+/// arguments.
+fn same_call(left: &ToolCallPart, right: &ToolCallPart) -> bool {
+    left.name == right.name && left.arguments == right.arguments
+}
+
+fn tool_calls(message: &Message) -> impl Iterator<Item = &ToolCallPart> {
+    message.content().iter().filter_map(|part| match part {
+        ContentPart::ToolCall(call) => Some(call),
+        _ => None,
+    })
+}
+
+fn assistant_text(message: &Message) -> Option<String> {
+    if message.role() != Role::Assistant {
+        return None;
+    }
+    let text: Vec<&str> = message
+        .content()
+        .iter()
+        .filter_map(|part| match part {
+            ContentPart::Text(text) => Some(text.text.as_str()),
+            _ => None,
+        })
+        .collect();
+    (!text.is_empty()).then(|| text.join("\n"))
+}
+
+fn align(left: &[Message], right: &[Message]) -> Vec<MessageDifference> {
+    let positions = left.len().max(right.len());
+    (0..positions)
+        .filter_map(|offset| {
+            let left_message = left.get(offset);
+            let right_message = right.get(offset);
+            let same =
+                matches!((left_message, right_message), (Some(l), Some(r)) if same_turn(l, r));
+            (!same).then(|| MessageDifference {
+                offset,
+                left: left_message.cloned(),
+                right: right_message.cloned(),
+            })
+        })
+        .collect()
+}
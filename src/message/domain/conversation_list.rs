@@ -0,0 +1,241 @@
+//! Conversation summaries and keyset-paginated listing queries.
+//!
+//! A conversation summary is a compact projection of one conversation: its
+//! state, the task it works on and that task's labels, the agent backend that
+//! last replied, and when it last saw activity. Listing queries filter and
+//! sort summaries and page through them with keyset cursors, so enumerating a
+//! tenant's conversations never loads message histories.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Conversation, ConversationId, ConversationState, Message};
+use crate::pagination::{Cursor, Limit, Page, PageRequest, SortOrder};
+
+/// Listing projection of a single conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationSummary {
+    /// The summarised conversation.
+    pub conversation_id: ConversationId,
+    /// Current lifecycle state.
+    pub state: ConversationState,
+    /// Task the conversation works on, if linked.
+    pub task_id: Option<Uuid>,
+    /// Labels of the linked task's issue.
+    pub labels: Vec<String>,
+    /// Agent backend named by the most recent message that carried one.
+    pub agent_backend: Option<String>,
+    /// Number of messages appended to the conversation.
+    pub message_count: u64,
+    /// When the conversation was created.
+    pub created_at: DateTime<Utc>,
+    /// Latest conversation update or message creation time.
+    pub last_activity_at: DateTime<Utc>,
+}
+
+impl ConversationSummary {
+    /// Creates the summary of a conversation with no messages.
+    #[must_use]
+    pub const fn from_conversation(conversation: &Conversation) -> Self {
+        Self {
+            conversation_id: conversation.id(),
+            state: conversation.state(),
+            task_id: None,
+            labels: Vec::new(),
+            agent_backend: None,
+            message_count: 0,
+            created_at: conversation.created_at(),
+            last_activity_at: conversation.updated_at(),
+        }
+    }
+
+    /// Folds an appended message into the summary.
+    pub fn record_message(&mut self, message: &Message) {
+        self.message_count = self.message_count.saturating_add(1);
+        self.last_activity_at = self.last_activity_at.max(message.created_at());
+        if let Some(backend) = message.metadata().agent_backend.as_ref() {
+            self.agent_backend = Some(backend.clone());
+        }
+    }
+
+    /// Links the conversation to a task, replacing any previous labels.
+    pub fn link_task(&mut self, task_id: Uuid, labels: impl IntoIterator<Item = String>) {
+        self.task_id = Some(task_id);
+        self.labels = labels.into_iter().collect();
+    }
+
+    /// Returns the timestamp the summary is ordered by for `key`.
+    #[must_use]
+    pub const fn sort_value(&self, key: ConversationSortKey) -> DateTime<Utc> {
+        match key {
+            ConversationSortKey::LastActivity => self.last_activity_at,
+            ConversationSortKey::CreatedAt => self.created_at,
+        }
+    }
+}
+
+/// Timestamp used to order conversation listings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationSortKey {
+    /// Order by the most recent activity.
+    #[default]
+    LastActivity,
+    /// Order by creation time.
+    CreatedAt,
+}
+
+/// Filters applied to a conversation listing.
+///
+/// Every filter that is set must match. Listings are always scoped to the
+/// tenant of the request context.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationListFilter {
+    /// Only conversations in this state.
+    pub state: Option<ConversationState>,
+    /// Only conversations whose latest agent backend matches.
+    pub agent_backend: Option<String>,
+    /// Only conversations linked to this task.
+    pub task_id: Option<Uuid>,
+    /// Only conversations whose task carries this label.
+    pub label: Option<String>,
+    /// Only conversations active at or after this instant.
+    pub last_activity_from: Option<DateTime<Utc>>,
+    /// Only conversations last active before this instant.
+    pub last_activity_until: Option<DateTime<Utc>>,
+}
+
+impl ConversationListFilter {
+    /// Returns `false` when both activity bounds are set and the range is
+    /// empty or inverted.
+    #[must_use]
+    pub fn has_valid_activity_range(&self) -> bool {
+        match (self.last_activity_from, self.last_activity_until) {
+            (Some(from), Some(until)) => from < until,
+            _ => true,
+        }
+    }
+
+    /// Returns `true` when the summary satisfies every filter.
+    #[must_use]
+    pub fn matches(&self, summary: &ConversationSummary) -> bool {
+        self.state.is_none_or(|state| summary.state == state)
+            && self
+                .agent_backend
+                .as_ref()
+                .is_none_or(|backend| summary.agent_backend.as_ref() == Some(backend))
+            && self
+                .task_id
+                .is_none_or(|task| summary.task_id == Some(task))
+            && self
+                .label
+                .as_ref()
+                .is_none_or(|label| summary.labels.contains(label))
+            && self
+                .last_activity_from
+                .is_none_or(|from| summary.last_activity_at >= from)
+            && self
+                .last_activity_until
+                .is_none_or(|until| summary.last_activity_at < until)
+    }
+}
+
+/// A filtered, sorted, paginated conversation listing request.
+///
+/// The natural order of a listing is ascending by the sort key, with ties
+/// broken by conversation identifier; [`ConversationListQuery::new`] asks
+/// for the reverse, so the most recently active conversations come first.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     ConversationListFilter, ConversationListQuery, ConversationSortKey, ConversationState,
+/// };
+/// use corbusier::pagination::{Limit, SortOrder};
+///
+/// let query = ConversationListQuery::new(Limit::new(50)?)
+///     .with_filter(ConversationListFilter {
+///         state: Some(ConversationState::Active),
+///         ..ConversationListFilter::default()
+///     })
+///     .sorted_by(ConversationSortKey::CreatedAt, SortOrder::Ascending);
+/// assert_eq!(query.page.limit().get(), 50);
+/// # Ok::<(), corbusier::pagination::PaginationError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationListQuery {
+    /// Filters every returned conversation satisfies.
+    pub filter: ConversationListFilter,
+    /// Timestamp the listing is ordered by.
+    pub sort_key: ConversationSortKey,
+    /// Page size, direction, and the cursor taken from the previous page.
+    pub page: PageRequest,
+}
+
+impl ConversationListQuery {
+    /// Creates an unfiltered query for the most recently active
+    /// conversations.
+    #[must_use]
+    pub fn new(limit: Limit) -> Self {
+        Self {
+            filter: ConversationListFilter::default(),
+            sort_key: ConversationSortKey::default(),
+            page: PageRequest::new(limit).with_order(SortOrder::Descending),
+        }
+    }
+
+    /// Replaces the query filters.
+    #[must_use]
+    pub fn with_filter(mut self, filter: ConversationListFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets the sort key and direction.
+    #[must_use]
+    pub const fn sorted_by(mut self, sort_key: ConversationSortKey, order: SortOrder) -> Self {
+        self.sort_key = sort_key;
+        self.page = self.page.with_order(order);
+        self
+    }
+
+    /// Resumes the listing after `cursor`.
+    #[must_use]
+    pub const fn with_cursor(mut self, cursor: Cursor) -> Self {
+        self.page = self.page.with_cursor(cursor);
+        self
+    }
+
+    /// Returns the keyset position of `summary` in this listing.
+    #[must_use]
+    pub const fn position(&self, summary: &ConversationSummary) -> Cursor {
+        Cursor::at_timestamp(
+            summary.sort_value(self.sort_key),
+            summary.conversation_id.into_inner(),
+        )
+    }
+
+    /// Filters, sorts, and pages a full set of summaries.
+    ///
+    /// Adapters that cannot push the query down to storage use this to
+    /// produce the same page a storage-backed listing would.
+    #[must_use]
+    pub fn paginate(
+        &self,
+        summaries: impl IntoIterator<Item = ConversationSummary>,
+    ) -> Page<ConversationSummary> {
+        let mut matching = summaries
+            .into_iter()
+            .filter(|summary| self.filter.matches(summary))
+            .collect::<Vec<_>>();
+        matching.sort_by_key(|summary| {
+            (
+                summary.sort_value(self.sort_key),
+                summary.conversation_id.into_inner(),
+            )
+        });
+        Page::from_ordered(matching, self.page, |summary| self.position(summary))
+    }
+}
@@ -0,0 +1,265 @@
+//! Registry of content part types defined by downstream crates.
+//!
+//! A downstream crate describes its payload with [`CustomContent`], giving it
+//! a type tag, a validation rule, and a prompt rendering, and registers it
+//! with a [`ContentTypeRegistry`]. Messages carry such payloads as
+//! [`CustomPart`]s, which are stored as ordinary JSON, so they round-trip
+//! through every repository unchanged. Validators consult the registry to
+//! check them, and prompt builders use it to render them as text.
+
+use super::CustomPart;
+use serde::{Serialize, de::DeserializeOwned};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Errors raised when encoding, decoding, or interpreting custom content.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CustomContentError {
+    /// No type is registered under the part's kind.
+    #[error("unregistered custom content kind '{0}'")]
+    UnknownKind(String),
+    /// A type is already registered under the kind.
+    #[error("custom content kind '{0}' is already registered")]
+    DuplicateKind(String),
+    /// The part holds a different kind from the one requested.
+    #[error("expected custom content kind '{expected}', found '{actual}'")]
+    KindMismatch {
+        /// The kind being decoded.
+        expected: String,
+        /// The kind the part holds.
+        actual: String,
+    },
+    /// The payload does not match its type's shape.
+    #[error("malformed '{kind}' content: {reason}")]
+    Malformed {
+        /// The part's kind.
+        kind: String,
+        /// Why (de)serialization failed.
+        reason: String,
+    },
+    /// The payload failed its type's validation rule.
+    #[error("invalid '{kind}' content: {reason}")]
+    Invalid {
+        /// The part's kind.
+        kind: String,
+        /// Why validation failed.
+        reason: String,
+    },
+}
+
+/// A content payload type defined outside this crate.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{ContentTypeRegistry, CustomContent, CustomPart};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Spreadsheet {
+///     sheet: String,
+///     rows: u32,
+/// }
+///
+/// impl CustomContent for Spreadsheet {
+///     const KIND: &'static str = "spreadsheet";
+///
+///     fn validate(&self) -> Result<(), String> {
+///         if self.sheet.is_empty() {
+///             return Err("sheet name is required".to_owned());
+///         }
+///         Ok(())
+///     }
+///
+///     fn render_prompt(&self) -> String {
+///         format!("[spreadsheet {} with {} rows]", self.sheet, self.rows)
+///     }
+/// }
+///
+/// let mut registry = ContentTypeRegistry::new();
+/// registry.register::<Spreadsheet>().expect("first registration");
+///
+/// let part = CustomPart::encode(&Spreadsheet { sheet: "Q3".to_owned(), rows: 42 })
+///     .expect("serializable");
+/// assert!(registry.validate(&part).is_ok());
+/// assert_eq!(
+///     registry.render_prompt(&part).expect("registered"),
+///     "[spreadsheet Q3 with 42 rows]"
+/// );
+/// ```
+pub trait CustomContent: Serialize + DeserializeOwned + Send + Sync + 'static {
+    /// Type tag stored in [`CustomPart::kind`].
+    const KIND: &'static str;
+
+    /// Checks the payload's invariants.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable reason when the payload is invalid.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Renders the payload as text for inclusion in an agent prompt.
+    fn render_prompt(&self) -> String;
+}
+
+impl CustomPart {
+    /// Serializes a typed payload into a custom part.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CustomContentError::Malformed`] when the payload cannot be
+    /// serialized.
+    pub fn encode<T: CustomContent>(content: &T) -> Result<Self, CustomContentError> {
+        let data = serde_json::to_value(content).map_err(|err| malformed(T::KIND, &err))?;
+        Ok(Self::new(T::KIND, data))
+    }
+
+    /// Deserializes the payload into its registered type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CustomContentError::KindMismatch`] when the part holds a
+    /// different kind, or [`CustomContentError::Malformed`] when the payload
+    /// does not match `T`.
+    pub fn decode<T: CustomContent>(&self) -> Result<T, CustomContentError> {
+        if self.kind != T::KIND {
+            return Err(CustomContentError::KindMismatch {
+                expected: T::KIND.to_owned(),
+                actual: self.kind.clone(),
+            });
+        }
+        T::deserialize(&self.data).map_err(|err| malformed(T::KIND, &err))
+    }
+}
+
+fn malformed(kind: &str, err: &serde_json::Error) -> CustomContentError {
+    CustomContentError::Malformed {
+        kind: kind.to_owned(),
+        reason: err.to_string(),
+    }
+}
+
+/// Validates and renders the payloads of one custom content kind.
+///
+/// [`ContentTypeRegistry::register`] derives a handler from a
+/// [`CustomContent`] type. Implement this trait directly for kinds whose
+/// shape is only known at runtime.
+pub trait ContentTypeHandler: Send + Sync {
+    /// Checks a payload of this kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CustomContentError`] when the payload is malformed or
+    /// invalid.
+    fn validate(&self, part: &CustomPart) -> Result<(), CustomContentError>;
+
+    /// Renders a payload of this kind for an agent prompt.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CustomContentError::Malformed`] when the payload cannot be
+    /// read.
+    fn render_prompt(&self, part: &CustomPart) -> Result<String, CustomContentError>;
+}
+
+struct TypedHandler<T>(PhantomData<fn() -> T>);
+
+impl<T: CustomContent> ContentTypeHandler for TypedHandler<T> {
+    fn validate(&self, part: &CustomPart) -> Result<(), CustomContentError> {
+        part.decode::<T>()?
+            .validate()
+            .map_err(|reason| CustomContentError::Invalid {
+                kind: T::KIND.to_owned(),
+                reason,
+            })
+    }
+
+    fn render_prompt(&self, part: &CustomPart) -> Result<String, CustomContentError> {
+        Ok(part.decode::<T>()?.render_prompt())
+    }
+}
+
+/// Content types registered by downstream crates, keyed by kind.
+#[derive(Clone, Default)]
+pub struct ContentTypeRegistry {
+    handlers: BTreeMap<String, Arc<dyn ContentTypeHandler>>,
+}
+
+impl fmt::Debug for ContentTypeRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.handlers.keys()).finish()
+    }
+}
+
+impl ContentTypeRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a typed content payload under [`CustomContent::KIND`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CustomContentError::DuplicateKind`] when the kind is taken.
+    pub fn register<T: CustomContent>(&mut self) -> Result<(), CustomContentError> {
+        self.register_handler(T::KIND, Arc::new(TypedHandler::<T>(PhantomData)))
+    }
+
+    /// Registers a handler for a kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CustomContentError::DuplicateKind`] when the kind is taken.
+    pub fn register_handler(
+        &mut self,
+        kind: impl Into<String>,
+        handler: Arc<dyn ContentTypeHandler>,
+    ) -> Result<(), CustomContentError> {
+        let registered_kind = kind.into();
+        if self.handlers.contains_key(&registered_kind) {
+            return Err(CustomContentError::DuplicateKind(registered_kind));
+        }
+        self.handlers.insert(registered_kind, handler);
+        Ok(())
+    }
+
+    /// Returns whether a kind is registered.
+    #[must_use]
+    pub fn is_registered(&self, kind: &str) -> bool {
+        self.handlers.contains_key(kind)
+    }
+
+    /// Validates a custom part against its registered type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CustomContentError::UnknownKind`] for unregistered kinds, or
+    /// the handler's error when the payload is malformed or invalid.
+    pub fn validate(&self, part: &CustomPart) -> Result<(), CustomContentError> {
+        self.handler(part)?.validate(part)
+    }
+
+    /// Renders a custom part for an agent prompt.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CustomContentError::UnknownKind`] for unregistered kinds, or
+    /// [`CustomContentError::Malformed`] when the payload cannot be read.
+    pub fn render_prompt(&self, part: &CustomPart) -> Result<String, CustomContentError> {
+        self.handler(part)?.render_prompt(part)
+    }
+
+    fn handler(&self, part: &CustomPart) -> Result<&dyn ContentTypeHandler, CustomContentError> {
+        self.handlers
+            .get(&part.kind)
+            .map(AsRef::as_ref)
+            .ok_or_else(|| CustomContentError::UnknownKind(part.kind.clone()))
+    }
+}
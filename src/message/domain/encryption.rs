@@ -0,0 +1,88 @@
+//! Values for field-level encryption of message content.
+//!
+//! A [`SealedValue`] is one encrypted field, tagged with the
+//! [`EncryptionKeyId`] of the key that sealed it so that rows written under
+//! an older key can still be opened once a new key becomes active.
+
+use std::fmt;
+use thiserror::Error;
+
+/// Maximum length of an encryption key identifier.
+pub const MAX_ENCRYPTION_KEY_ID_CHARS: usize = 64;
+
+/// Error returned when an encryption key identifier is malformed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error(
+    "invalid encryption key id '{0}': expected 1 to 64 ASCII letters, digits, '.', '_', or '-'"
+)]
+pub struct EncryptionKeyIdError(pub String);
+
+/// Names a key-encryption key, such as `content-2026-10`.
+///
+/// The identifier is stored beside every sealed value, so it must never be
+/// reused for different key material.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::EncryptionKeyId;
+///
+/// let id = EncryptionKeyId::new("content-2026-10")?;
+/// assert_eq!(id.as_str(), "content-2026-10");
+/// assert!(EncryptionKeyId::new("no spaces").is_err());
+/// # Ok::<(), corbusier::message::domain::EncryptionKeyIdError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EncryptionKeyId(String);
+
+impl EncryptionKeyId {
+    /// Validates and wraps a key identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncryptionKeyIdError`] unless `value` is 1 to 64 ASCII
+    /// letters, digits, dots, underscores, or hyphens.
+    pub fn new(value: impl Into<String>) -> Result<Self, EncryptionKeyIdError> {
+        let id = value.into();
+        let is_valid = !id.is_empty()
+            && id.len() <= MAX_ENCRYPTION_KEY_ID_CHARS
+            && id
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'_' | b'-'));
+        if !is_valid {
+            return Err(EncryptionKeyIdError(id));
+        }
+        Ok(Self(id))
+    }
+
+    /// Returns the identifier as a string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for EncryptionKeyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// One field encrypted under a per-value data key.
+///
+/// The data key is itself encrypted ("wrapped") by the key-encryption key
+/// named by `key_id`, so rotating that key means rewrapping data keys
+/// rather than re-encrypting every field with it directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SealedValue {
+    /// The key-encryption key that wrapped the data key.
+    pub key_id: EncryptionKeyId,
+    /// The data key, encrypted under `key_id`.
+    pub wrapped_key: Vec<u8>,
+    /// Nonce used to wrap the data key.
+    pub key_nonce: Vec<u8>,
+    /// Nonce used to encrypt the field.
+    pub nonce: Vec<u8>,
+    /// The encrypted field, including its authentication tag.
+    pub ciphertext: Vec<u8>,
+}
@@ -0,0 +1,102 @@
+//! Selection and outcome of conversation exports.
+//!
+//! An [`ExportFilter`] chooses which messages of a conversation an export
+//! writes, by role and by whether their content has been redacted. The
+//! export returns an [`ExportReport`] counting what it wrote and skipped.
+
+use super::{ConversationId, Message, Role};
+
+/// How an export treats messages whose content has been redacted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RedactedMessages {
+    /// Redacted messages are written with their redaction placeholder.
+    #[default]
+    Include,
+    /// Redacted messages are left out.
+    Exclude,
+}
+
+/// Chooses the messages an export writes.
+///
+/// The default filter writes every message.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     ContentPart, ConversationId, ExportFilter, Message, Role, SequenceNumber, TextPart,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let filter = ExportFilter::new()
+///     .with_roles([Role::User, Role::Assistant])
+///     .without_redacted();
+/// let system = Message::new(
+///     ConversationId::new(),
+///     Role::System,
+///     vec![ContentPart::Text(TextPart::new("Be terse."))],
+///     SequenceNumber::new(1),
+///     &DefaultClock,
+/// )
+/// .expect("valid message");
+/// assert!(!filter.allows(&system));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExportFilter {
+    roles: Vec<Role>,
+    redacted: RedactedMessages,
+}
+
+impl ExportFilter {
+    /// Creates a filter writing every message.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes only messages with one of `roles`.
+    #[must_use]
+    pub fn with_roles(mut self, roles: impl IntoIterator<Item = Role>) -> Self {
+        self.roles = roles.into_iter().collect();
+        self
+    }
+
+    /// Leaves out messages whose content has been redacted.
+    #[must_use]
+    pub const fn without_redacted(mut self) -> Self {
+        self.redacted = RedactedMessages::Exclude;
+        self
+    }
+
+    /// Returns the roles written, or an empty slice when every role is.
+    #[must_use]
+    pub fn roles(&self) -> &[Role] {
+        &self.roles
+    }
+
+    /// Returns how redacted messages are treated.
+    #[must_use]
+    pub const fn redacted(&self) -> RedactedMessages {
+        self.redacted
+    }
+
+    /// Returns `true` when the export writes `message`.
+    #[must_use]
+    pub fn allows(&self, message: &Message) -> bool {
+        let role_allowed = self.roles.is_empty() || self.roles.contains(&message.role());
+        let redaction_allowed =
+            self.redacted == RedactedMessages::Include || !message.is_redacted();
+        role_allowed && redaction_allowed
+    }
+}
+
+/// Outcome of exporting a conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportReport {
+    /// The exported conversation.
+    pub conversation_id: ConversationId,
+    /// Messages written.
+    pub exported: usize,
+    /// Messages the filter left out.
+    pub skipped: usize,
+}
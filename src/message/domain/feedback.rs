@@ -0,0 +1,400 @@
+//! Human feedback attached to assistant messages.
+//!
+//! Reviewers rate assistant messages with a thumbs up or down or a star
+//! score, optionally adding a comment and a category. Each record snapshots
+//! the agent backend and instruction-set version that produced the message.
+
+use super::{ConversationId, FeedbackId, Message, MessageId, Role};
+use crate::context::UserId;
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// Maximum length of a feedback comment, in characters.
+pub const MAX_FEEDBACK_COMMENT_CHARS: usize = 4_000;
+
+/// Highest star score a reviewer can give.
+pub const MAX_FEEDBACK_STARS: u8 = 5;
+
+/// Errors returned while constructing feedback.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum FeedbackDomainError {
+    /// Feedback can only be attached to assistant messages.
+    #[error("message {0} is not an assistant message")]
+    NotAssistantMessage(MessageId),
+    /// Star scores must lie in `1..=5`.
+    #[error("star rating must be between 1 and {MAX_FEEDBACK_STARS}, got {0}")]
+    InvalidStarRating(u8),
+    /// The comment exceeds [`MAX_FEEDBACK_COMMENT_CHARS`].
+    #[error("feedback comment has {actual} characters, the limit is {max}")]
+    CommentTooLong {
+        /// Maximum permitted characters.
+        max: usize,
+        /// Characters supplied.
+        actual: usize,
+    },
+}
+
+/// Error returned while parsing feedback values from persistence.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("unknown feedback value: {0}")]
+pub struct ParseFeedbackValueError(pub String);
+
+/// A reviewer's rating of an assistant message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackRating {
+    /// The message was helpful.
+    ThumbsUp,
+    /// The message was unhelpful.
+    ThumbsDown,
+    /// A score from one to five stars.
+    Stars(u8),
+}
+
+impl FeedbackRating {
+    /// Returns the rating's sentiment: four or five stars are positive,
+    /// three neutral, and one or two negative.
+    #[must_use]
+    pub const fn sentiment(self) -> FeedbackSentiment {
+        match self {
+            Self::ThumbsUp | Self::Stars(4..) => FeedbackSentiment::Positive,
+            Self::Stars(3) => FeedbackSentiment::Neutral,
+            Self::ThumbsDown | Self::Stars(_) => FeedbackSentiment::Negative,
+        }
+    }
+
+    const fn validate(self) -> Result<Self, FeedbackDomainError> {
+        match self {
+            Self::Stars(stars) if stars == 0 || stars > MAX_FEEDBACK_STARS => {
+                Err(FeedbackDomainError::InvalidStarRating(stars))
+            }
+            _ => Ok(self),
+        }
+    }
+}
+
+/// Direction of a rating, used to compare thumbs and star scores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackSentiment {
+    /// A thumbs up or four or more stars.
+    Positive,
+    /// Three stars.
+    Neutral,
+    /// A thumbs down or two or fewer stars.
+    Negative,
+}
+
+/// What the feedback is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackCategory {
+    /// Factual or technical correctness.
+    Accuracy,
+    /// Whether the message moved the work forward.
+    Helpfulness,
+    /// Harmful, insecure, or policy-violating content.
+    Safety,
+    /// Tone, length, or formatting.
+    Style,
+    /// Anything else.
+    Other,
+}
+
+impl FeedbackCategory {
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Accuracy => "accuracy",
+            Self::Helpfulness => "helpfulness",
+            Self::Safety => "safety",
+            Self::Style => "style",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for FeedbackCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<&str> for FeedbackCategory {
+    type Error = ParseFeedbackValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "accuracy" => Ok(Self::Accuracy),
+            "helpfulness" => Ok(Self::Helpfulness),
+            "safety" => Ok(Self::Safety),
+            "style" => Ok(Self::Style),
+            "other" => Ok(Self::Other),
+            _ => Err(ParseFeedbackValueError(value.to_owned())),
+        }
+    }
+}
+
+/// Rating, comment, and category submitted by a reviewer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedbackSubmission {
+    /// The reviewer's rating.
+    pub rating: FeedbackRating,
+    /// Optional free-text comment.
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// Optional category.
+    #[serde(default)]
+    pub category: Option<FeedbackCategory>,
+}
+
+impl FeedbackSubmission {
+    /// Creates a submission carrying only a rating.
+    #[must_use]
+    pub const fn new(rating: FeedbackRating) -> Self {
+        Self {
+            rating,
+            comment: None,
+            category: None,
+        }
+    }
+
+    /// Adds a free-text comment.
+    #[must_use]
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Sets the category.
+    #[must_use]
+    pub const fn with_category(mut self, category: FeedbackCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+}
+
+/// Persisted feedback fields used to rebuild a [`MessageFeedback`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersistedFeedbackData {
+    /// Feedback identifier.
+    pub id: FeedbackId,
+    /// Conversation containing the rated message.
+    pub conversation_id: ConversationId,
+    /// The rated message.
+    pub message_id: MessageId,
+    /// The reviewer.
+    pub author: UserId,
+    /// Rating, comment, and category.
+    pub submission: FeedbackSubmission,
+    /// Backend that produced the message.
+    pub agent_backend: Option<String>,
+    /// Instruction-set version the backend ran with.
+    pub instruction_set_version: Option<String>,
+    /// When the feedback was last submitted.
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// A reviewer's feedback on one assistant message.
+///
+/// Each reviewer holds at most one feedback record per message; submitting
+/// again replaces the earlier rating.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::UserId;
+/// use corbusier::message::domain::{
+///     ConversationId, FeedbackRating, FeedbackSentiment, FeedbackSubmission, Message,
+///     MessageFeedback, MessageMetadata, Role, SequenceNumber, TextPart, ContentPart,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let message = Message::builder(ConversationId::new(), Role::Assistant, SequenceNumber::new(2))
+///     .with_content(ContentPart::Text(TextPart::new("Done.")))
+///     .with_metadata(MessageMetadata::with_agent_backend("codex_cli"))
+///     .build(&DefaultClock)
+///     .expect("valid message");
+///
+/// let feedback = MessageFeedback::for_message(
+///     &message,
+///     UserId::new(),
+///     FeedbackSubmission::new(FeedbackRating::Stars(4)),
+///     &DefaultClock,
+/// )
+/// .expect("valid feedback");
+///
+/// assert_eq!(feedback.agent_backend(), Some("codex_cli"));
+/// assert_eq!(feedback.sentiment(), FeedbackSentiment::Positive);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageFeedback {
+    id: FeedbackId,
+    conversation_id: ConversationId,
+    message_id: MessageId,
+    author: UserId,
+    submission: FeedbackSubmission,
+    agent_backend: Option<String>,
+    instruction_set_version: Option<String>,
+    submitted_at: DateTime<Utc>,
+}
+
+impl MessageFeedback {
+    /// Creates feedback on `message`, attributing it to the backend and
+    /// instruction-set version recorded in its metadata. Blank comments are
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FeedbackDomainError::NotAssistantMessage`] when `message`
+    /// was not written by an assistant,
+    /// [`FeedbackDomainError::InvalidStarRating`] for star scores outside
+    /// `1..=5`, or [`FeedbackDomainError::CommentTooLong`] when the comment
+    /// exceeds [`MAX_FEEDBACK_COMMENT_CHARS`].
+    pub fn for_message(
+        message: &Message,
+        author: UserId,
+        submission: FeedbackSubmission,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<Self, FeedbackDomainError> {
+        if message.role() != Role::Assistant {
+            return Err(FeedbackDomainError::NotAssistantMessage(message.id()));
+        }
+        let metadata = message.metadata();
+        Ok(Self {
+            id: FeedbackId::new(),
+            conversation_id: message.conversation_id(),
+            message_id: message.id(),
+            author,
+            submission: validate_submission(submission)?,
+            agent_backend: metadata.agent_backend.clone(),
+            instruction_set_version: metadata
+                .agent_response_audit
+                .as_ref()
+                .and_then(|audit| audit.instruction_set_version.clone()),
+            submitted_at: clock.utc(),
+        })
+    }
+
+    /// Rebuilds feedback from persisted data.
+    #[must_use]
+    pub fn from_persisted(data: PersistedFeedbackData) -> Self {
+        Self {
+            id: data.id,
+            conversation_id: data.conversation_id,
+            message_id: data.message_id,
+            author: data.author,
+            submission: data.submission,
+            agent_backend: data.agent_backend,
+            instruction_set_version: data.instruction_set_version,
+            submitted_at: data.submitted_at,
+        }
+    }
+
+    /// Adopts the identifier of the author's superseded feedback.
+    #[must_use]
+    pub(crate) const fn with_id(mut self, id: FeedbackId) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Returns the feedback identifier.
+    #[must_use]
+    pub const fn id(&self) -> FeedbackId {
+        self.id
+    }
+
+    /// Returns the conversation containing the rated message.
+    #[must_use]
+    pub const fn conversation_id(&self) -> ConversationId {
+        self.conversation_id
+    }
+
+    /// Returns the rated message.
+    #[must_use]
+    pub const fn message_id(&self) -> MessageId {
+        self.message_id
+    }
+
+    /// Returns the reviewer.
+    #[must_use]
+    pub const fn author(&self) -> UserId {
+        self.author
+    }
+
+    /// Returns the rating.
+    #[must_use]
+    pub const fn rating(&self) -> FeedbackRating {
+        self.submission.rating
+    }
+
+    /// Returns the direction of the rating.
+    #[must_use]
+    pub const fn sentiment(&self) -> FeedbackSentiment {
+        self.submission.rating.sentiment()
+    }
+
+    /// Returns the comment, if any.
+    #[must_use]
+    pub fn comment(&self) -> Option<&str> {
+        self.submission.comment.as_deref()
+    }
+
+    /// Returns the category, if any.
+    #[must_use]
+    pub const fn category(&self) -> Option<FeedbackCategory> {
+        self.submission.category
+    }
+
+    /// Returns the rating, comment, and category.
+    #[must_use]
+    pub const fn submission(&self) -> &FeedbackSubmission {
+        &self.submission
+    }
+
+    /// Returns the backend that produced the rated message.
+    #[must_use]
+    pub fn agent_backend(&self) -> Option<&str> {
+        self.agent_backend.as_deref()
+    }
+
+    /// Returns the instruction-set version the backend ran with.
+    #[must_use]
+    pub fn instruction_set_version(&self) -> Option<&str> {
+        self.instruction_set_version.as_deref()
+    }
+
+    /// Returns when the feedback was submitted.
+    #[must_use]
+    pub const fn submitted_at(&self) -> DateTime<Utc> {
+        self.submitted_at
+    }
+}
+
+fn validate_submission(
+    submission: FeedbackSubmission,
+) -> Result<FeedbackSubmission, FeedbackDomainError> {
+    let rating = submission.rating.validate()?;
+    let comment = submission
+        .comment
+        .map(|text| text.trim().to_owned())
+        .filter(|text| !text.is_empty());
+    if let Some(text) = comment.as_deref() {
+        let actual = text.chars().count();
+        if actual > MAX_FEEDBACK_COMMENT_CHARS {
+            return Err(FeedbackDomainError::CommentTooLong {
+                max: MAX_FEEDBACK_COMMENT_CHARS,
+                actual,
+            });
+        }
+    }
+    Ok(FeedbackSubmission {
+        rating,
+        comment,
+        category: submission.category,
+    })
+}
@@ -0,0 +1,99 @@
+//! Feedback aggregated per agent backend and instruction-set version.
+
+use super::{FeedbackCategory, FeedbackRating, FeedbackSentiment, MessageFeedback};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Feedback totals for one agent backend and instruction-set version.
+///
+/// Messages without a recorded backend or version are grouped under `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedbackSummary {
+    /// Backend that produced the rated messages.
+    pub agent_backend: Option<String>,
+    /// Instruction-set version the backend ran with.
+    pub instruction_set_version: Option<String>,
+    /// Feedback records in the group.
+    pub total: u64,
+    /// Thumbs up and four- or five-star ratings.
+    pub positive: u64,
+    /// Three-star ratings.
+    pub neutral: u64,
+    /// Thumbs down and one- or two-star ratings.
+    pub negative: u64,
+    /// Ratings given as stars.
+    pub star_ratings: u64,
+    /// Sum of all star scores.
+    pub star_total: u64,
+    /// Feedback records per category; uncategorised feedback is omitted.
+    pub categories: BTreeMap<FeedbackCategory, u64>,
+}
+
+impl FeedbackSummary {
+    /// Returns the share of positive ratings among positive and negative
+    /// ratings in basis points, or `None` when there are none.
+    #[must_use]
+    pub const fn approval_rate_bps(&self) -> Option<u64> {
+        self.positive
+            .saturating_mul(10_000)
+            .checked_div(self.positive.saturating_add(self.negative))
+    }
+
+    /// Returns the mean star score in hundredths of a star, or `None` when
+    /// no star ratings were given.
+    #[must_use]
+    pub const fn average_stars_centi(&self) -> Option<u64> {
+        self.star_total
+            .saturating_mul(100)
+            .checked_div(self.star_ratings)
+    }
+
+    /// Groups feedback by backend and instruction-set version.
+    ///
+    /// Summaries are ordered by backend and then version, with ungrouped
+    /// feedback first.
+    #[must_use]
+    pub fn aggregate(feedback: &[MessageFeedback]) -> Vec<Self> {
+        let mut groups: BTreeMap<(Option<&str>, Option<&str>), Self> = BTreeMap::new();
+        for record in feedback {
+            let key = (record.agent_backend(), record.instruction_set_version());
+            groups
+                .entry(key)
+                .or_insert_with(|| Self::empty(key.0, key.1))
+                .add(record);
+        }
+        groups.into_values().collect()
+    }
+
+    fn empty(agent_backend: Option<&str>, instruction_set_version: Option<&str>) -> Self {
+        Self {
+            agent_backend: agent_backend.map(str::to_owned),
+            instruction_set_version: instruction_set_version.map(str::to_owned),
+            total: 0,
+            positive: 0,
+            neutral: 0,
+            negative: 0,
+            star_ratings: 0,
+            star_total: 0,
+            categories: BTreeMap::new(),
+        }
+    }
+
+    fn add(&mut self, record: &MessageFeedback) {
+        self.total = self.total.saturating_add(1);
+        let counter = match record.sentiment() {
+            FeedbackSentiment::Positive => &mut self.positive,
+            FeedbackSentiment::Neutral => &mut self.neutral,
+            FeedbackSentiment::Negative => &mut self.negative,
+        };
+        *counter = counter.saturating_add(1);
+        if let FeedbackRating::Stars(stars) = record.rating() {
+            self.star_ratings = self.star_ratings.saturating_add(1);
+            self.star_total = self.star_total.saturating_add(u64::from(stars));
+        }
+        if let Some(category) = record.category() {
+            let count = self.categories.entry(category).or_default();
+            *count = count.saturating_add(1);
+        }
+    }
+}
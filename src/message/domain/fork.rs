@@ -0,0 +1,119 @@
+//! Forking a conversation from a point in its history.
+//!
+//! A fork is a new conversation that starts with a copy of its parent's
+//! messages up to and including the fork point, so an alternative agent
+//! strategy can be tried from there while the parent carries on. Copies get
+//! fresh message identifiers but keep their sequence numbers, timestamps,
+//! content, and metadata, so the fork's next message follows the fork point
+//! and pinned messages stay pinned. The fork's context data is copied from
+//! the parent; its task link is not.
+//!
+//! A [`ConversationFork`] records the fork's provenance: its parent, the
+//! fork point, and the parent's latest context snapshot ending at or before
+//! the fork point, which describes what the parent's agent had seen there.
+//! Compare a fork with its parent through
+//! [`ConversationComparison`](super::ConversationComparison).
+
+use super::{Conversation, ConversationId, ConversationState, Message, SequenceNumber};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A request to fork a conversation after one of its messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConversationForkRequest {
+    /// The conversation to fork.
+    pub parent_conversation_id: ConversationId,
+    /// Sequence number of the last parent message the fork shares.
+    pub forked_at: SequenceNumber,
+}
+
+/// Where a forked conversation came from.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     Conversation, ConversationFork, ConversationForkRequest, SequenceNumber,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let parent = Conversation::new(&DefaultClock);
+/// let fork = ConversationFork::new(
+///     ConversationForkRequest {
+///         parent_conversation_id: parent.id(),
+///         forked_at: SequenceNumber::new(4),
+///     },
+///     &DefaultClock,
+/// );
+///
+/// let conversation = fork.conversation(&parent);
+/// assert_eq!(conversation.id(), fork.conversation_id);
+/// assert_ne!(fork.conversation_id, parent.id());
+/// assert!(fork.parent_snapshot_id.is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationFork {
+    /// The forked conversation.
+    pub conversation_id: ConversationId,
+    /// The conversation it was forked from.
+    pub parent_conversation_id: ConversationId,
+    /// Sequence number of the last parent message copied into the fork.
+    pub forked_at: SequenceNumber,
+    /// The parent's latest context snapshot ending at or before the fork
+    /// point, linked by the repository when the fork is stored.
+    pub parent_snapshot_id: Option<Uuid>,
+    /// When the fork was created.
+    pub created_at: DateTime<Utc>,
+}
+
+impl ConversationFork {
+    /// Creates the provenance of a new fork with a fresh conversation
+    /// identifier.
+    #[must_use]
+    pub fn new(request: ConversationForkRequest, clock: &(impl Clock + ?Sized)) -> Self {
+        Self {
+            conversation_id: ConversationId::new(),
+            parent_conversation_id: request.parent_conversation_id,
+            forked_at: request.forked_at,
+            parent_snapshot_id: None,
+            created_at: clock.utc(),
+        }
+    }
+
+    /// Links the parent's context snapshot at the fork point.
+    #[must_use]
+    pub const fn with_parent_snapshot(mut self, snapshot_id: Uuid) -> Self {
+        self.parent_snapshot_id = Some(snapshot_id);
+        self
+    }
+
+    /// Returns `true` when `message` belongs to the history the fork shares
+    /// with its parent.
+    #[must_use]
+    pub fn shares(&self, message: &Message) -> bool {
+        message.conversation_id() == self.parent_conversation_id
+            && message.sequence_number() <= self.forked_at
+    }
+
+    /// Builds the forked conversation: active, carrying `parent`'s context
+    /// data, and created when the fork was.
+    #[must_use]
+    pub fn conversation(&self, parent: &Conversation) -> Conversation {
+        Conversation::from_persisted(
+            self.conversation_id,
+            ConversationState::Active,
+            self.created_at,
+            self.created_at,
+        )
+        .with_context(parent.context().clone())
+    }
+
+    /// Returns the fork's copy of a shared parent message, with a fresh
+    /// identifier.
+    #[must_use]
+    pub fn copy_message(&self, message: &Message) -> Message {
+        message.copied_to(self.conversation_id)
+    }
+}
@@ -174,6 +174,17 @@ impl HandoffMetadata {
         self
     }
 
+    /// Records the target agent's rejection of the handoff.
+    ///
+    /// A rejected handoff ends [`HandoffStatus::Failed`], with the rejection
+    /// reason replacing the initiation reason.
+    #[must_use]
+    pub fn reject(mut self, reason: &str) -> Self {
+        self.reason = Some(reason.to_owned());
+        self.status = HandoffStatus::Failed;
+        self
+    }
+
     /// Cancels the handoff, optionally recording a reason.
     #[must_use]
     pub fn cancel(mut self, reason: Option<&str>) -> Self {
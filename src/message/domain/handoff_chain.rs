@@ -0,0 +1,169 @@
+//! Ordered chains of the handoffs within a conversation.
+//!
+//! A [`HandoffChain`] lists a conversation's handoffs in initiation order,
+//! each joined to the session that handed over, the session that took over,
+//! and the snapshots captured on either side, so callers can follow control
+//! from agent to agent without joining the records themselves.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::{
+    AgentSession, AgentSessionId, ContextWindowSnapshot, ConversationId, HandoffMetadata,
+    SnapshotType,
+};
+
+/// The handoffs of one conversation, oldest first.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     AgentSession, ConversationId, HandoffChain, HandoffMetadata, HandoffParams,
+///     SequenceNumber, TurnId,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let conversation_id = ConversationId::new();
+/// let source = AgentSession::new(conversation_id, "claude", SequenceNumber::new(1), &DefaultClock);
+/// let params = HandoffParams::new(source.session_id, TurnId::new(), "claude", "codex");
+/// let handoff = HandoffMetadata::new(params, &DefaultClock);
+///
+/// let chain = HandoffChain::assemble(conversation_id, vec![handoff], vec![source], &[])
+///     .expect("source session is known");
+///
+/// assert_eq!(chain.agents(), ["claude", "codex"]);
+/// assert!(chain.links[0].target_session.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HandoffChain {
+    /// The conversation the handoffs belong to.
+    pub conversation_id: ConversationId,
+    /// One link per handoff, in initiation order.
+    pub links: Vec<HandoffChainLink>,
+}
+
+/// A handoff joined to the sessions and snapshots on either side of it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HandoffChainLink {
+    /// The handoff.
+    pub handoff: HandoffMetadata,
+    /// The session that handed the conversation over.
+    pub source_session: AgentSession,
+    /// The session that took the conversation over, once one exists.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_session: Option<AgentSession>,
+    /// The snapshot of the source session captured when the handoff was
+    /// initiated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiation_snapshot_id: Option<Uuid>,
+    /// The snapshot captured when the target session started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_start_snapshot_id: Option<Uuid>,
+}
+
+impl HandoffChain {
+    /// Joins `handoffs`, given in initiation order, to their sessions and
+    /// snapshots.
+    ///
+    /// `sessions` and `snapshots` may hold records the chain does not
+    /// need; a target session or snapshot that is missing leaves its field
+    /// empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns the id of the first source session missing from `sessions`.
+    pub fn assemble(
+        conversation_id: ConversationId,
+        handoffs: Vec<HandoffMetadata>,
+        sessions: Vec<AgentSession>,
+        snapshots: &[ContextWindowSnapshot],
+    ) -> Result<Self, AgentSessionId> {
+        let sessions: HashMap<AgentSessionId, AgentSession> = sessions
+            .into_iter()
+            .map(|session| (session.session_id, session))
+            .collect();
+        let links = handoffs
+            .into_iter()
+            .map(|handoff| HandoffChainLink::join(handoff, &sessions, snapshots))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            conversation_id,
+            links,
+        })
+    }
+
+    /// Returns the agent backends control passed through: the first
+    /// handoff's source agent, then each handoff's target agent.
+    #[must_use]
+    pub fn agents(&self) -> Vec<&str> {
+        let first = self
+            .links
+            .first()
+            .map(|link| link.handoff.source_agent.as_str());
+        first
+            .into_iter()
+            .chain(
+                self.links
+                    .iter()
+                    .map(|link| link.handoff.target_agent.as_str()),
+            )
+            .collect()
+    }
+
+    /// Returns whether each handoff was initiated from the session the
+    /// previous handoff handed over to.
+    #[must_use]
+    pub fn is_linked(&self) -> bool {
+        self.links.windows(2).all(|pair| match pair {
+            [previous, next] => {
+                previous.handoff.target_session_id == Some(next.handoff.source_session_id)
+            }
+            _ => true,
+        })
+    }
+}
+
+impl HandoffChainLink {
+    fn join(
+        handoff: HandoffMetadata,
+        sessions: &HashMap<AgentSessionId, AgentSession>,
+        snapshots: &[ContextWindowSnapshot],
+    ) -> Result<Self, AgentSessionId> {
+        let source_session = sessions
+            .get(&handoff.source_session_id)
+            .cloned()
+            .ok_or(handoff.source_session_id)?;
+        let target_session = handoff
+            .target_session_id
+            .and_then(|id| sessions.get(&id))
+            .cloned();
+        let initiation_snapshot_id = snapshots
+            .iter()
+            .filter(|snapshot| {
+                snapshot.session_id == handoff.source_session_id
+                    && snapshot.snapshot_type == SnapshotType::HandoffInitiated
+                    && snapshot.captured_at <= handoff.initiated_at
+            })
+            .max_by_key(|snapshot| snapshot.captured_at)
+            .map(|snapshot| snapshot.snapshot_id);
+        let target_start_snapshot_id = handoff.target_session_id.and_then(|target| {
+            snapshots
+                .iter()
+                .filter(|snapshot| {
+                    snapshot.session_id == target
+                        && snapshot.snapshot_type == SnapshotType::SessionStart
+                })
+                .min_by_key(|snapshot| snapshot.captured_at)
+                .map(|snapshot| snapshot.snapshot_id)
+        });
+        Ok(Self {
+            handoff,
+            source_session,
+            target_session,
+            initiation_snapshot_id,
+            target_start_snapshot_id,
+        })
+    }
+}
@@ -0,0 +1,150 @@
+//! Context packages handed to the target agent of a completed handoff.
+//!
+//! A [`HandoffContextPackage`] gathers what the incoming agent needs to pick
+//! up the conversation without replaying its history: the window the source
+//! agent saw when the handoff was initiated, the conversation's summary,
+//! its pinned messages, and the tool calls still waiting for a result.
+
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::{
+    AgentSessionId, ContextWindowSnapshot, ConversationId, HandoffId, HandoffMetadata, Message,
+    MessageId, SequenceRange, ToolCallPairing, ToolCallPairingIssue, ToolCallReference,
+};
+
+/// Context prepared for the target session of a completed handoff.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     AgentSessionId, ConversationId, HandoffContextPackage, HandoffMetadata, HandoffParams,
+///     TurnId,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let params = HandoffParams::new(AgentSessionId::new(), TurnId::new(), "claude", "codex");
+/// let handoff = HandoffMetadata::new(params, &DefaultClock);
+/// let target = AgentSessionId::new();
+///
+/// let package = HandoffContextPackage::new(&handoff, ConversationId::new(), target, &DefaultClock)
+///     .with_summary("The user wants the flaky test fixed.");
+///
+/// assert_eq!(package.target_session_id, target);
+/// assert!(package.open_tool_calls.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandoffContextPackage {
+    /// The completed handoff.
+    pub handoff_id: HandoffId,
+    /// The conversation handed over.
+    pub conversation_id: ConversationId,
+    /// The session that handed the conversation over.
+    pub source_session_id: AgentSessionId,
+    /// The session that took the conversation over.
+    pub target_session_id: AgentSessionId,
+    /// The agent backend that handed the conversation over.
+    pub source_agent: String,
+    /// Why the handoff was initiated, if a reason was given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// The snapshot captured when the handoff was initiated, if one exists.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<Uuid>,
+    /// The messages the source agent saw when the handoff was initiated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence_range: Option<SequenceRange>,
+    /// Summary of the conversation so far, if one has been written.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// Pinned messages, in sequence order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pinned_messages: Vec<MessageId>,
+    /// Tool calls the source agent made that have no result yet.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub open_tool_calls: Vec<ToolCallReference>,
+    /// When the package was prepared.
+    pub prepared_at: DateTime<Utc>,
+}
+
+impl HandoffContextPackage {
+    /// Creates an empty package for `handoff`, taken over by
+    /// `target_session_id`.
+    #[must_use]
+    pub fn new(
+        handoff: &HandoffMetadata,
+        conversation_id: ConversationId,
+        target_session_id: AgentSessionId,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        Self {
+            handoff_id: handoff.handoff_id,
+            conversation_id,
+            source_session_id: handoff.source_session_id,
+            target_session_id,
+            source_agent: handoff.source_agent.clone(),
+            reason: handoff.reason.clone(),
+            snapshot_id: None,
+            sequence_range: None,
+            summary: None,
+            pinned_messages: Vec::new(),
+            open_tool_calls: Vec::new(),
+            prepared_at: clock.utc(),
+        }
+    }
+
+    /// Records the snapshot captured when the handoff was initiated.
+    #[must_use]
+    pub const fn with_snapshot(mut self, snapshot: &ContextWindowSnapshot) -> Self {
+        self.snapshot_id = Some(snapshot.snapshot_id);
+        self.sequence_range = Some(snapshot.sequence_range);
+        self
+    }
+
+    /// Sets the conversation summary.
+    #[must_use]
+    pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// Records the conversation's pinned messages.
+    #[must_use]
+    pub fn with_pinned_messages(mut self, pinned: impl IntoIterator<Item = MessageId>) -> Self {
+        self.pinned_messages.extend(pinned);
+        self
+    }
+
+    /// Records the tool calls in `history` that no message answers.
+    ///
+    /// `history` must be in sequence order.
+    #[must_use]
+    pub fn with_open_tool_calls(mut self, history: &[Message]) -> Self {
+        let sequence_numbers: HashMap<_, _> = history
+            .iter()
+            .map(|message| (message.id(), message.sequence_number()))
+            .collect();
+        let pairing = ToolCallPairing::check(history);
+        self.open_tool_calls
+            .extend(pairing.orphaned_calls().filter_map(|issue| match issue {
+                ToolCallPairingIssue::OrphanedCall {
+                    call_id,
+                    tool_name,
+                    call_message,
+                } => sequence_numbers.get(call_message).map(|sequence_number| {
+                    ToolCallReference::new(
+                        call_id.clone(),
+                        tool_name.clone(),
+                        *call_message,
+                        *sequence_number,
+                    )
+                }),
+                _ => None,
+            }));
+        self
+    }
+}
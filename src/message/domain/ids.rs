@@ -170,85 +170,83 @@ impl fmt::Display for TurnId {
     }
 }
 
-/// Sequence number for ordering messages within a conversation.
+/// Unique identifier for an agent handoff event.
 ///
-/// Sequence numbers are monotonically increasing within a conversation,
-/// ensuring deterministic message ordering.
+/// A handoff represents a transfer of conversation control from one agent
+/// backend to another, preserving context and audit trail.
 ///
 /// # Examples
 ///
 /// ```
-/// use corbusier::message::domain::SequenceNumber;
+/// use corbusier::message::domain::HandoffId;
 ///
-/// let seq = SequenceNumber::new(1);
-/// assert_eq!(seq.value(), 1);
-/// assert_eq!(seq.next().value(), 2);
+/// let id = HandoffId::new();
+/// assert!(!id.as_ref().is_nil());
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
-pub struct SequenceNumber(u64);
+pub struct HandoffId(Uuid);
 
-impl SequenceNumber {
-    /// Creates a sequence number from a value.
+impl HandoffId {
+    /// Creates a new random handoff identifier.
     #[must_use]
-    pub const fn new(value: u64) -> Self {
-        Self(value)
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
     }
 
-    /// Returns the underlying sequence value.
+    /// Creates a handoff identifier from an existing UUID.
     #[must_use]
-    pub const fn value(&self) -> u64 {
-        self.0
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
     }
 
-    /// Returns the next sequence number.
-    ///
-    /// Uses saturating arithmetic, so at `u64::MAX` it will not overflow
-    /// but return `u64::MAX`. This is practically unreachable in normal use
-    /// (would require 2^64 messages).
+    /// Returns the inner UUID value.
     #[must_use]
-    pub const fn next(&self) -> Self {
-        Self(self.0.saturating_add(1))
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for HandoffId {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl From<u64> for SequenceNumber {
-    fn from(value: u64) -> Self {
-        Self(value)
+impl AsRef<Uuid> for HandoffId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
     }
 }
 
-impl fmt::Display for SequenceNumber {
+impl fmt::Display for HandoffId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
     }
 }
 
-/// Unique identifier for an agent handoff event.
-///
-/// A handoff represents a transfer of conversation control from one agent
-/// backend to another, preserving context and audit trail.
+/// Unique identifier for human feedback on a message.
 ///
 /// # Examples
 ///
 /// ```
-/// use corbusier::message::domain::HandoffId;
+/// use corbusier::message::domain::FeedbackId;
 ///
-/// let id = HandoffId::new();
+/// let id = FeedbackId::new();
 /// assert!(!id.as_ref().is_nil());
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
-pub struct HandoffId(Uuid);
+pub struct FeedbackId(Uuid);
 
-impl HandoffId {
-    /// Creates a new random handoff identifier.
+impl FeedbackId {
+    /// Creates a new random feedback identifier.
     #[must_use]
     pub fn new() -> Self {
         Self(Uuid::new_v4())
     }
 
-    /// Creates a handoff identifier from an existing UUID.
+    /// Creates a feedback identifier from an existing UUID.
     #[must_use]
     pub const fn from_uuid(uuid: Uuid) -> Self {
         Self(uuid)
@@ -261,19 +259,19 @@ impl HandoffId {
     }
 }
 
-impl Default for HandoffId {
+impl Default for FeedbackId {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl AsRef<Uuid> for HandoffId {
+impl AsRef<Uuid> for FeedbackId {
     fn as_ref(&self) -> &Uuid {
         &self.0
     }
 }
 
-impl fmt::Display for HandoffId {
+impl fmt::Display for FeedbackId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
     }
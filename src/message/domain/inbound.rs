@@ -0,0 +1,145 @@
+//! Messages delivered by external chat systems.
+//!
+//! Inbound gateways turn Slack events or custom webhook payloads into an
+//! [`InboundMessage`]. The external channel and author identify the
+//! conversation and principal the message belongs to, and the
+//! [`InboundOrigin`] travels with the stored message so its provenance stays
+//! auditable.
+
+use super::{AttachmentPart, MessageMetadata};
+use crate::context::{TenantId, UserId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Extension key under which the [`InboundOrigin`] of a gateway-delivered
+/// message is stored in [`MessageMetadata::extensions`].
+pub const INBOUND_ORIGIN_EXTENSION_KEY: &str = "inbound.origin.v1";
+
+/// Where an inbound message came from in the external system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InboundOrigin {
+    /// External system name, such as `slack` or a configured webhook name.
+    pub source: String,
+    /// Channel, room, or thread identifier in the external system.
+    pub channel_id: String,
+    /// Author identifier in the external system.
+    pub external_user_id: String,
+    /// Message or event identifier in the external system, when supplied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_message_id: Option<String>,
+}
+
+impl InboundOrigin {
+    /// Creates an origin without an external message identifier.
+    #[must_use]
+    pub fn new(
+        source: impl Into<String>,
+        channel_id: impl Into<String>,
+        external_user_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            source: source.into(),
+            channel_id: channel_id.into(),
+            external_user_id: external_user_id.into(),
+            external_message_id: None,
+        }
+    }
+
+    /// Sets the external message or event identifier.
+    #[must_use]
+    pub fn with_external_message_id(mut self, id: impl Into<String>) -> Self {
+        self.external_message_id = Some(id.into());
+        self
+    }
+
+    /// Returns message metadata recording this origin under
+    /// [`INBOUND_ORIGIN_EXTENSION_KEY`].
+    #[must_use]
+    pub fn to_metadata(&self) -> MessageMetadata {
+        let mut metadata = MessageMetadata::empty();
+        metadata.extensions.insert(
+            INBOUND_ORIGIN_EXTENSION_KEY.to_owned(),
+            serde_json::json!(self),
+        );
+        metadata
+    }
+}
+
+/// A message received from an external system.
+///
+/// Besides its text, a message may carry attachments and extra metadata
+/// extensions recorded next to its [`InboundOrigin`], such as the envelope
+/// of an email.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{InboundMessage, InboundOrigin};
+///
+/// let message = InboundMessage::new(
+///     InboundOrigin::new("slack", "C024BE91L", "U2147483697").with_external_message_id("Ev1"),
+///     "Please rerun the failing job",
+/// );
+/// assert_eq!(message.origin.source, "slack");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InboundMessage {
+    /// External source, channel, and author.
+    pub origin: InboundOrigin,
+    /// Message text.
+    pub text: String,
+    /// Files attached to the message.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<AttachmentPart>,
+    /// Metadata extensions recorded on the stored message.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: HashMap<String, Value>,
+}
+
+impl InboundMessage {
+    /// Creates an inbound message without attachments.
+    #[must_use]
+    pub fn new(origin: InboundOrigin, text: impl Into<String>) -> Self {
+        Self {
+            origin,
+            text: text.into(),
+            attachments: Vec::new(),
+            extensions: HashMap::new(),
+        }
+    }
+
+    /// Sets the attachments.
+    #[must_use]
+    pub fn with_attachments(
+        mut self,
+        attachments: impl IntoIterator<Item = AttachmentPart>,
+    ) -> Self {
+        self.attachments = attachments.into_iter().collect();
+        self
+    }
+
+    /// Records a metadata extension on the stored message.
+    #[must_use]
+    pub fn with_extension(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.extensions.insert(key.into(), value);
+        self
+    }
+}
+
+/// The Corbusier principal an external user acts as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InboundPrincipal {
+    /// Tenant owning the conversations the user writes to.
+    pub tenant_id: TenantId,
+    /// User the external author is mapped to.
+    pub user_id: UserId,
+}
+
+impl InboundPrincipal {
+    /// Creates a principal.
+    #[must_use]
+    pub const fn new(tenant_id: TenantId, user_id: UserId) -> Self {
+        Self { tenant_id, user_id }
+    }
+}
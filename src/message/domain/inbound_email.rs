@@ -0,0 +1,238 @@
+//! Email received by, and replies sent from, the inbound email gateway.
+//!
+//! An [`InboundEmail`] is the parsed form of one email, whether it was
+//! pushed by a provider webhook or fetched from a mailbox. Each email
+//! thread feeds one conversation: the thread is identified by the first
+//! `Message-ID` in the email's `References` header, so every reply in the
+//! thread lands in the conversation the opening email created. The
+//! [`EmailEnvelope`] stored with each message keeps what is needed to
+//! answer on the same thread with an [`OutboundEmail`].
+
+use super::{AttachmentPart, InboundMessage, InboundOrigin, MessageMetadata};
+use serde::{Deserialize, Serialize};
+
+/// Source name recorded on messages received by email.
+pub const EMAIL_SOURCE: &str = "email";
+
+/// Extension key under which the [`EmailEnvelope`] of an email-delivered
+/// message is stored in [`MessageMetadata::extensions`].
+pub const INBOUND_EMAIL_EXTENSION_KEY: &str = "inbound.email.v1";
+
+/// An email received by the inbound email gateway.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::InboundEmail;
+///
+/// let reply = InboundEmail::new("<b@example.com>", "jdoe@example.com", "Re: Deploy", "Done?")
+///     .with_in_reply_to("<a@example.com>")
+///     .with_references(["<a@example.com>".to_owned()]);
+/// assert_eq!(reply.thread_id(), "<a@example.com>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InboundEmail {
+    /// The email's `Message-ID`.
+    pub message_id: String,
+    /// Sender address.
+    pub from: String,
+    /// Recipient addresses.
+    #[serde(default)]
+    pub to: Vec<String>,
+    /// Subject line.
+    #[serde(default)]
+    pub subject: String,
+    /// Plain-text body.
+    #[serde(default)]
+    pub text: String,
+    /// `Message-ID` of the email this one answers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_reply_to: Option<String>,
+    /// `Message-ID`s of the earlier emails in the thread, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<String>,
+    /// Files attached to the email.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<AttachmentPart>,
+}
+
+impl InboundEmail {
+    /// Creates an email that starts a new thread and has no attachments.
+    #[must_use]
+    pub fn new(
+        message_id: impl Into<String>,
+        from: impl Into<String>,
+        subject: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        Self {
+            message_id: message_id.into(),
+            from: from.into(),
+            to: Vec::new(),
+            subject: subject.into(),
+            text: text.into(),
+            in_reply_to: None,
+            references: Vec::new(),
+            attachments: Vec::new(),
+        }
+    }
+
+    /// Sets the recipient addresses.
+    #[must_use]
+    pub fn with_to(mut self, to: impl IntoIterator<Item = String>) -> Self {
+        self.to = to.into_iter().collect();
+        self
+    }
+
+    /// Sets the `Message-ID` this email answers.
+    #[must_use]
+    pub fn with_in_reply_to(mut self, message_id: impl Into<String>) -> Self {
+        self.in_reply_to = Some(message_id.into());
+        self
+    }
+
+    /// Sets the `Message-ID`s of the earlier emails in the thread.
+    #[must_use]
+    pub fn with_references(mut self, references: impl IntoIterator<Item = String>) -> Self {
+        self.references = references.into_iter().collect();
+        self
+    }
+
+    /// Adds an attachment.
+    #[must_use]
+    pub fn with_attachment(mut self, attachment: AttachmentPart) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    /// Returns the `Message-ID` of the email that opened the thread.
+    ///
+    /// This is the oldest reference when there is one, then the email
+    /// being answered, and otherwise this email itself.
+    #[must_use]
+    pub fn thread_id(&self) -> &str {
+        if let Some(root) = self.references.first() {
+            return root;
+        }
+        self.in_reply_to.as_deref().unwrap_or(&self.message_id)
+    }
+
+    /// Returns the envelope needed to reply to this email.
+    #[must_use]
+    pub fn envelope(&self) -> EmailEnvelope {
+        let mut references = self.references.clone();
+        if let Some(parent) = &self.in_reply_to
+            && !references.contains(parent)
+        {
+            references.push(parent.clone());
+        }
+        EmailEnvelope {
+            message_id: self.message_id.clone(),
+            from: self.from.clone(),
+            subject: self.subject.clone(),
+            references,
+        }
+    }
+
+    /// Converts the email into an inbound message on its thread's channel.
+    ///
+    /// The sender address, lower-cased, is the external user. The body
+    /// becomes the message text, attachments become attachment parts, and
+    /// the [`EmailEnvelope`] is recorded under
+    /// [`INBOUND_EMAIL_EXTENSION_KEY`].
+    #[must_use]
+    pub fn into_inbound_message(self) -> InboundMessage {
+        let envelope = self.envelope();
+        let origin = InboundOrigin::new(
+            EMAIL_SOURCE,
+            self.thread_id(),
+            self.from.trim().to_ascii_lowercase(),
+        )
+        .with_external_message_id(self.message_id);
+        InboundMessage::new(origin, self.text)
+            .with_attachments(self.attachments)
+            .with_extension(INBOUND_EMAIL_EXTENSION_KEY, serde_json::json!(envelope))
+    }
+}
+
+/// Headers of a received email needed to answer it on the same thread.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmailEnvelope {
+    /// The received email's `Message-ID`.
+    pub message_id: String,
+    /// Sender address, which the reply is sent to.
+    pub from: String,
+    /// Subject line.
+    pub subject: String,
+    /// `Message-ID`s of the emails before it in the thread, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<String>,
+}
+
+impl EmailEnvelope {
+    /// Reads the envelope recorded in message metadata, if any.
+    #[must_use]
+    pub fn from_metadata(metadata: &MessageMetadata) -> Option<Self> {
+        metadata
+            .extensions
+            .get(INBOUND_EMAIL_EXTENSION_KEY)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+}
+
+/// An email sent back to a thread's sender.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{InboundEmail, OutboundEmail};
+///
+/// let received = InboundEmail::new("<a@example.com>", "jdoe@example.com", "Deploy", "Go?");
+/// let reply = OutboundEmail::reply_to(&received.envelope(), "Deployed.");
+/// assert_eq!(reply.subject, "Re: Deploy");
+/// assert_eq!(reply.in_reply_to.as_deref(), Some("<a@example.com>"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutboundEmail {
+    /// Recipient address.
+    pub to: String,
+    /// Subject line.
+    pub subject: String,
+    /// Plain-text body.
+    pub body: String,
+    /// `Message-ID` of the email being answered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_reply_to: Option<String>,
+    /// `Message-ID`s of the thread so far, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<String>,
+}
+
+impl OutboundEmail {
+    /// Creates a reply to the email described by `envelope`.
+    ///
+    /// The subject gains a `Re: ` prefix unless it already has one, and the
+    /// threading headers point at the received email so mail clients keep
+    /// the reply in its thread.
+    #[must_use]
+    pub fn reply_to(envelope: &EmailEnvelope, body: impl Into<String>) -> Self {
+        let subject = if envelope
+            .subject
+            .get(..3)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("re:"))
+        {
+            envelope.subject.clone()
+        } else {
+            format!("Re: {}", envelope.subject)
+        };
+        let mut references = envelope.references.clone();
+        references.push(envelope.message_id.clone());
+        Self {
+            to: envelope.from.clone(),
+            subject,
+            body: body.into(),
+            in_reply_to: Some(envelope.message_id.clone()),
+            references,
+        }
+    }
+}
@@ -0,0 +1,326 @@
+//! Incremental context snapshots and their resolution into full views.
+//!
+//! An incremental snapshot names a parent snapshot and records only the
+//! messages after the parent's range. Resolving follows the chain back to a
+//! full capture and folds the increments onto it, giving the context window
+//! a full capture would have recorded. [`SnapshotStorage`] reports how many
+//! message references the chain stored against what full captures would
+//! have stored.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::{ContextWindowSnapshot, MessageSummary, SequenceNumber, SequenceRange};
+
+/// The full context window an incremental snapshot stands for.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     AgentSessionId, ContextWindowSnapshot, ConversationId, MessageSummary, ResolvedSnapshot,
+///     SequenceNumber, SequenceRange, SnapshotParams, SnapshotType,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let (conversation_id, session_id) = (ConversationId::new(), AgentSessionId::new());
+/// let params = |end, users| SnapshotParams {
+///     conversation_id,
+///     session_id,
+///     sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(end)),
+///     message_summary: MessageSummary::new(users, 0, 0, 0),
+///     snapshot_type: SnapshotType::Checkpoint,
+/// };
+/// let root = ContextWindowSnapshot::new(params(10, 10), &DefaultClock);
+/// let base = ResolvedSnapshot::resolve(root.clone(), [])?;
+///
+/// let increment = base.increment(ContextWindowSnapshot::new(params(14, 14), &DefaultClock))?;
+/// let resolved = ResolvedSnapshot::resolve(root, [increment])?;
+///
+/// assert_eq!(resolved.snapshot.sequence_range.end, SequenceNumber::new(14));
+/// assert_eq!(resolved.storage.saved_messages(), 10);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedSnapshot {
+    /// The full view, carrying the identity, type, and capture time of the
+    /// last snapshot in the chain and the range, message summary, and tool
+    /// calls of the whole chain.
+    pub snapshot: ContextWindowSnapshot,
+    /// The snapshots folded into the view, full capture first.
+    pub chain: Vec<Uuid>,
+    /// How much the chain stores compared with full captures.
+    pub storage: SnapshotStorage,
+}
+
+/// Storage used by a chain of snapshots, counted in message references.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SnapshotStorage {
+    /// Messages the chain's snapshots record between them.
+    pub stored_messages: u64,
+    /// Messages the chain would record if every snapshot in it were a full
+    /// capture.
+    pub full_messages: u64,
+}
+
+impl SnapshotStorage {
+    /// Measures the snapshots `snapshot_ids`, looking them and their
+    /// ancestors up in `known`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotChainError::MissingParent`] when a snapshot or an
+    /// ancestor is not in `known`, or [`SnapshotChainError::Cycle`] when
+    /// following parents loops.
+    pub fn measure<'a>(
+        snapshot_ids: impl IntoIterator<Item = &'a Uuid>,
+        known: &HashMap<Uuid, ContextWindowSnapshot>,
+    ) -> Result<Self, SnapshotChainError> {
+        snapshot_ids
+            .into_iter()
+            .try_fold(Self::default(), |storage, snapshot_id| {
+                let (stored, full) = chain_lengths(*snapshot_id, known)?;
+                Ok(Self {
+                    stored_messages: storage.stored_messages.saturating_add(stored),
+                    full_messages: storage.full_messages.saturating_add(full),
+                })
+            })
+    }
+
+    /// Returns how many message references the increments saved.
+    #[must_use]
+    pub const fn saved_messages(&self) -> u64 {
+        self.full_messages.saturating_sub(self.stored_messages)
+    }
+}
+
+impl ResolvedSnapshot {
+    /// Folds `increments`, each extending the one before it, onto the full
+    /// capture `root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotChainError`] when `root` is itself incremental, or
+    /// an increment does not extend the previous snapshot, belongs to
+    /// another conversation, or leaves a gap in the sequence.
+    pub fn resolve(
+        root: ContextWindowSnapshot,
+        increments: impl IntoIterator<Item = ContextWindowSnapshot>,
+    ) -> Result<Self, SnapshotChainError> {
+        if let Some(parent_snapshot_id) = root.parent_snapshot_id {
+            return Err(SnapshotChainError::MissingParent {
+                snapshot_id: root.snapshot_id,
+                parent_snapshot_id,
+            });
+        }
+        let stored = root.sequence_range.len();
+        let mut resolved = Self {
+            chain: vec![root.snapshot_id],
+            storage: SnapshotStorage {
+                stored_messages: stored,
+                full_messages: stored,
+            },
+            snapshot: root,
+        };
+        for increment in increments {
+            resolved.fold(increment)?;
+        }
+        Ok(resolved)
+    }
+
+    /// Diffs a full capture taken after this view against it, returning
+    /// the capture as an increment on the view's last snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotChainError::ConversationMismatch`] when `current`
+    /// belongs to another conversation, or
+    /// [`SnapshotChainError::Regressed`] when its range ends before the
+    /// view's.
+    pub fn increment(
+        &self,
+        current: ContextWindowSnapshot,
+    ) -> Result<ContextWindowSnapshot, SnapshotChainError> {
+        let base = &self.snapshot;
+        if current.conversation_id != base.conversation_id {
+            return Err(SnapshotChainError::ConversationMismatch {
+                snapshot_id: current.snapshot_id,
+            });
+        }
+        if current.sequence_range.end < base.sequence_range.end {
+            return Err(SnapshotChainError::Regressed {
+                snapshot_id: current.snapshot_id,
+                resolved_end: base.sequence_range.end,
+            });
+        }
+        let known: HashSet<&str> = base
+            .visible_tool_calls
+            .iter()
+            .map(|call| call.call_id.as_str())
+            .collect();
+        let visible_tool_calls = current
+            .visible_tool_calls
+            .iter()
+            .filter(|call| !known.contains(call.call_id.as_str()))
+            .cloned()
+            .collect();
+        Ok(ContextWindowSnapshot {
+            sequence_range: SequenceRange::new(
+                base.sequence_range.end.next(),
+                current.sequence_range.end,
+            ),
+            message_summary: subtract(current.message_summary, base.message_summary),
+            visible_tool_calls,
+            token_estimate: current
+                .token_estimate
+                .zip(base.token_estimate)
+                .map(|(now, before)| now.saturating_sub(before)),
+            parent_snapshot_id: Some(base.snapshot_id),
+            ..current
+        })
+    }
+
+    fn fold(&mut self, increment: ContextWindowSnapshot) -> Result<(), SnapshotChainError> {
+        let view = &mut self.snapshot;
+        if increment.parent_snapshot_id != Some(view.snapshot_id) {
+            return Err(SnapshotChainError::NotLinked {
+                snapshot_id: increment.snapshot_id,
+                expected_parent_id: view.snapshot_id,
+            });
+        }
+        if increment.conversation_id != view.conversation_id {
+            return Err(SnapshotChainError::ConversationMismatch {
+                snapshot_id: increment.snapshot_id,
+            });
+        }
+        let expected_start = view.sequence_range.end.next();
+        if increment.sequence_range.start != expected_start {
+            return Err(SnapshotChainError::NotContiguous {
+                snapshot_id: increment.snapshot_id,
+                expected_start,
+            });
+        }
+
+        let mut sequence_range = view.sequence_range;
+        if !increment.sequence_range.is_empty() {
+            sequence_range.end = increment.sequence_range.end;
+        }
+        let mut visible_tool_calls = std::mem::take(&mut view.visible_tool_calls);
+        visible_tool_calls.extend(increment.visible_tool_calls.iter().cloned());
+        let stored = increment.sequence_range.len();
+        *view = ContextWindowSnapshot {
+            sequence_range,
+            message_summary: add(view.message_summary, increment.message_summary),
+            visible_tool_calls,
+            token_estimate: view
+                .token_estimate
+                .zip(increment.token_estimate)
+                .map(|(before, delta)| before.saturating_add(delta)),
+            parent_snapshot_id: None,
+            ..increment
+        };
+        self.chain.push(view.snapshot_id);
+        self.storage.stored_messages = self.storage.stored_messages.saturating_add(stored);
+        self.storage.full_messages = self
+            .storage
+            .full_messages
+            .saturating_add(sequence_range.len());
+        Ok(())
+    }
+}
+
+/// Returns the messages `snapshot_id` records itself, and the messages of
+/// the full view its chain resolves to.
+fn chain_lengths(
+    snapshot_id: Uuid,
+    known: &HashMap<Uuid, ContextWindowSnapshot>,
+) -> Result<(u64, u64), SnapshotChainError> {
+    let mut visited = HashSet::new();
+    let mut lengths = Vec::new();
+    let mut next = Some(snapshot_id);
+    while let Some(id) = next {
+        if !visited.insert(id) {
+            return Err(SnapshotChainError::Cycle(id));
+        }
+        let snapshot = known.get(&id).ok_or(SnapshotChainError::MissingParent {
+            snapshot_id,
+            parent_snapshot_id: id,
+        })?;
+        lengths.push(snapshot.sequence_range.len());
+        next = snapshot.parent_snapshot_id;
+    }
+    let stored = lengths.first().copied().unwrap_or_default();
+    Ok((
+        stored,
+        lengths
+            .iter()
+            .fold(0, |total, len| total.saturating_add(*len)),
+    ))
+}
+
+const fn add(left: MessageSummary, right: MessageSummary) -> MessageSummary {
+    MessageSummary::new(
+        left.user_count.saturating_add(right.user_count),
+        left.assistant_count.saturating_add(right.assistant_count),
+        left.tool_count.saturating_add(right.tool_count),
+        left.system_count.saturating_add(right.system_count),
+    )
+}
+
+const fn subtract(left: MessageSummary, right: MessageSummary) -> MessageSummary {
+    MessageSummary::new(
+        left.user_count.saturating_sub(right.user_count),
+        left.assistant_count.saturating_sub(right.assistant_count),
+        left.tool_count.saturating_sub(right.tool_count),
+        left.system_count.saturating_sub(right.system_count),
+    )
+}
+
+/// Errors raised when snapshots do not form a resolvable chain.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SnapshotChainError {
+    /// A snapshot's parent is not stored.
+    #[error("parent snapshot {parent_snapshot_id} of snapshot {snapshot_id} is missing")]
+    MissingParent {
+        /// The incremental snapshot.
+        snapshot_id: Uuid,
+        /// The parent it names.
+        parent_snapshot_id: Uuid,
+    },
+    /// Following parents led back to a snapshot already visited.
+    #[error("snapshot {0} is its own ancestor")]
+    Cycle(Uuid),
+    /// An increment does not name the previous snapshot as its parent.
+    #[error("snapshot {snapshot_id} does not extend snapshot {expected_parent_id}")]
+    NotLinked {
+        /// The increment.
+        snapshot_id: Uuid,
+        /// The snapshot it should have extended.
+        expected_parent_id: Uuid,
+    },
+    /// An increment belongs to another conversation than its parent.
+    #[error("snapshot {snapshot_id} belongs to another conversation than its parent")]
+    ConversationMismatch {
+        /// The increment.
+        snapshot_id: Uuid,
+    },
+    /// An increment does not start right after its parent's range.
+    #[error("snapshot {snapshot_id} should start at sequence {expected_start}")]
+    NotContiguous {
+        /// The increment.
+        snapshot_id: Uuid,
+        /// The sequence number after the parent's range.
+        expected_start: SequenceNumber,
+    },
+    /// A capture ends before the view it was diffed against.
+    #[error("snapshot {snapshot_id} ends before sequence {resolved_end} already captured")]
+    Regressed {
+        /// The capture.
+        snapshot_id: Uuid,
+        /// Where the view it was diffed against ends.
+        resolved_end: SequenceNumber,
+    },
+}
@@ -0,0 +1,201 @@
+//! Key/value labels that slice conversations by project, customer, or
+//! experiment.
+//!
+//! A conversation carries at most one value per label key. Keys are short,
+//! lowercase identifiers so that they read the same in queries, dashboards,
+//! and the audit trail; values are free text of bounded length.
+//!
+//! Every change to a conversation's labels is described by a
+//! [`ConversationLabelChange`] and recorded, together with who made it, as a
+//! [`ConversationLabelEvent`]. Repositories store the event alongside the
+//! change, so the history of a conversation's labels can be replayed.
+
+use super::ConversationId;
+use crate::context::{CorrelationId, RequestContext, UserId};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Maximum length of a label key, in characters.
+pub const MAX_LABEL_KEY_CHARS: usize = 63;
+
+/// Maximum length of a label value, in characters.
+pub const MAX_LABEL_VALUE_CHARS: usize = 255;
+
+/// Errors raised while constructing a conversation label.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConversationLabelError {
+    /// The key is empty, too long, or uses characters outside the key
+    /// alphabet.
+    #[error(
+        "invalid label key {0:?}: keys hold 1 to {MAX_LABEL_KEY_CHARS} lowercase letters, digits, '-', '_', or '.', starting with a letter or digit"
+    )]
+    InvalidKey(String),
+    /// The value is empty.
+    #[error("label {key} must have a value")]
+    EmptyValue {
+        /// The label key.
+        key: String,
+    },
+    /// The value exceeds [`MAX_LABEL_VALUE_CHARS`].
+    #[error("label {key} has a value of {actual} characters, the limit is {max}")]
+    ValueTooLong {
+        /// The label key.
+        key: String,
+        /// Maximum permitted characters.
+        max: usize,
+        /// Characters supplied.
+        actual: usize,
+    },
+}
+
+/// A validated label key and value.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::ConversationLabel;
+///
+/// let label = ConversationLabel::new("project", "apollo").expect("valid label");
+/// assert_eq!(label.key(), "project");
+/// assert_eq!(label.value(), "apollo");
+/// assert_eq!(label.to_string(), "project=apollo");
+/// assert!(ConversationLabel::new("Project", "apollo").is_err());
+/// assert!(ConversationLabel::new("project", "").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConversationLabel {
+    key: String,
+    value: String,
+}
+
+impl ConversationLabel {
+    /// Creates a label.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationLabelError::InvalidKey`] when `key` is not a
+    /// valid label key, [`ConversationLabelError::EmptyValue`] when `value`
+    /// is empty, or [`ConversationLabelError::ValueTooLong`] when it exceeds
+    /// [`MAX_LABEL_VALUE_CHARS`].
+    pub fn new(
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<Self, ConversationLabelError> {
+        let key = validate_key(key.into())?;
+        let value = value.into();
+        if value.is_empty() {
+            return Err(ConversationLabelError::EmptyValue { key });
+        }
+        let actual = value.chars().count();
+        if actual > MAX_LABEL_VALUE_CHARS {
+            return Err(ConversationLabelError::ValueTooLong {
+                key,
+                max: MAX_LABEL_VALUE_CHARS,
+                actual,
+            });
+        }
+        Ok(Self { key, value })
+    }
+
+    /// Returns the label key.
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the label value.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Consumes the label, returning its key and value.
+    #[must_use]
+    pub fn into_parts(self) -> (String, String) {
+        (self.key, self.value)
+    }
+}
+
+impl fmt::Display for ConversationLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.key, self.value)
+    }
+}
+
+/// Returns `key` when it is a valid label key.
+fn validate_key(key: String) -> Result<String, ConversationLabelError> {
+    let starts_well = key
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_ascii_lowercase() || first.is_ascii_digit());
+    let alphabet = key.chars().all(|character| {
+        character.is_ascii_lowercase()
+            || character.is_ascii_digit()
+            || matches!(character, '-' | '_' | '.')
+    });
+    if starts_well && alphabet && key.len() <= MAX_LABEL_KEY_CHARS {
+        return Ok(key);
+    }
+    Err(ConversationLabelError::InvalidKey(key))
+}
+
+/// One change to a conversation's labels.
+///
+/// `previous` is the value the label held before the change and `value` the
+/// value it holds after; `None` on either side means the label was absent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationLabelChange {
+    /// The relabelled conversation.
+    pub conversation_id: ConversationId,
+    /// The label key.
+    pub key: String,
+    /// The value before the change.
+    pub previous: Option<String>,
+    /// The value after the change.
+    pub value: Option<String>,
+}
+
+impl ConversationLabelChange {
+    /// Returns `true` when the change removed the label.
+    #[must_use]
+    pub const fn is_removal(&self) -> bool {
+        self.value.is_none()
+    }
+}
+
+/// An audited change to a conversation's labels.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationLabelEvent {
+    /// Event identifier.
+    pub id: Uuid,
+    /// What changed.
+    pub change: ConversationLabelChange,
+    /// The user who made the change.
+    pub actor: UserId,
+    /// Correlation identifier of the request that made the change.
+    pub correlation_id: CorrelationId,
+    /// When the change was made.
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ConversationLabelEvent {
+    /// Records `change` as made now by the user in `ctx`.
+    #[must_use]
+    pub fn new(
+        ctx: &RequestContext,
+        change: ConversationLabelChange,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            change,
+            actor: ctx.user_id(),
+            correlation_id: ctx.correlation_id(),
+            occurred_at: clock.utc(),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Conversation lifecycle points observed by extension hooks.
+//!
+//! Services announce a [`ConversationLifecycleEvent`] after each lifecycle
+//! transition has been persisted, so hooks only ever see changes that
+//! actually happened.
+
+use super::{ConversationId, HandoffId, MessageId, Role, SequenceNumber};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A point in a conversation's lifecycle that hooks can subscribe to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationLifecyclePoint {
+    /// A conversation was created.
+    Created,
+    /// An agent turn is about to run in a conversation.
+    TurnStarted,
+    /// A message was stored in a conversation.
+    MessageStored,
+    /// A handoff between agent sessions completed.
+    HandoffCompleted,
+    /// The target agent of a handoff rejected it.
+    HandoffRejected,
+    /// A conversation was archived.
+    Archived,
+}
+
+impl ConversationLifecyclePoint {
+    /// Returns the stable string representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::TurnStarted => "turn_started",
+            Self::MessageStored => "message_stored",
+            Self::HandoffCompleted => "handoff_completed",
+            Self::HandoffRejected => "handoff_rejected",
+            Self::Archived => "archived",
+        }
+    }
+
+    /// Returns every lifecycle point.
+    #[must_use]
+    pub const fn all() -> [Self; 6] {
+        [
+            Self::Created,
+            Self::TurnStarted,
+            Self::MessageStored,
+            Self::HandoffCompleted,
+            Self::HandoffRejected,
+            Self::Archived,
+        ]
+    }
+}
+
+impl fmt::Display for ConversationLifecyclePoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What happened at a lifecycle point.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "point", rename_all = "snake_case")]
+pub enum ConversationLifecycleChange {
+    /// A conversation was created.
+    Created,
+    /// An agent turn is about to run.
+    TurnStarted,
+    /// A message was stored.
+    MessageStored {
+        /// The stored message.
+        message_id: MessageId,
+        /// The message's position in the conversation.
+        sequence_number: SequenceNumber,
+        /// The message author's role.
+        role: Role,
+    },
+    /// A handoff completed.
+    HandoffCompleted {
+        /// The completed handoff.
+        handoff_id: HandoffId,
+        /// The agent backend that took over.
+        target_agent: String,
+    },
+    /// The target agent rejected a handoff.
+    HandoffRejected {
+        /// The rejected handoff.
+        handoff_id: HandoffId,
+        /// The agent backend that rejected it.
+        target_agent: String,
+        /// Why the target agent rejected it.
+        reason: String,
+    },
+    /// A conversation was archived.
+    Archived,
+}
+
+/// A lifecycle transition announced to hooks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationLifecycleEvent {
+    /// The conversation the transition happened in.
+    pub conversation_id: ConversationId,
+    /// What happened.
+    pub change: ConversationLifecycleChange,
+    /// When the transition happened.
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ConversationLifecycleEvent {
+    /// Creates an event.
+    #[must_use]
+    pub const fn new(
+        conversation_id: ConversationId,
+        change: ConversationLifecycleChange,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            conversation_id,
+            change,
+            occurred_at,
+        }
+    }
+
+    /// Returns the lifecycle point the event belongs to.
+    #[must_use]
+    pub const fn point(&self) -> ConversationLifecyclePoint {
+        match self.change {
+            ConversationLifecycleChange::Created => ConversationLifecyclePoint::Created,
+            ConversationLifecycleChange::TurnStarted => ConversationLifecyclePoint::TurnStarted,
+            ConversationLifecycleChange::MessageStored { .. } => {
+                ConversationLifecyclePoint::MessageStored
+            }
+            ConversationLifecycleChange::HandoffCompleted { .. } => {
+                ConversationLifecyclePoint::HandoffCompleted
+            }
+            ConversationLifecycleChange::HandoffRejected { .. } => {
+                ConversationLifecyclePoint::HandoffRejected
+            }
+            ConversationLifecycleChange::Archived => ConversationLifecyclePoint::Archived,
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! Load-shedding policy for background message processing.
+//!
+//! Interactive turns must stay responsive under overload, so low-value
+//! background work is shed first. A [`LoadSheddingPolicy`] turns the observed
+//! [`LoadSignals`] — the latency of recent interactive turns and the depth of
+//! the processing queues — into a [`LoadLevel`], and says how each
+//! [`BackgroundWorkload`] runs at that level: in full, sampled, or paused.
+//! Critical work, such as validation and moderation, is never shed.
+
+use super::ProcessingStage;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+/// Default latency objective for interactive turns.
+pub const DEFAULT_TURN_LATENCY_SLO: Duration = Duration::from_secs(2);
+
+/// Default queue depth above which the system counts as elevated.
+pub const DEFAULT_ELEVATED_QUEUE_DEPTH: usize = 100;
+
+/// Default queue depth above which the system counts as overloaded.
+pub const DEFAULT_OVERLOADED_QUEUE_DEPTH: usize = 1_000;
+
+/// Default sampling ratio: one in this many background jobs runs while
+/// sampled.
+pub const DEFAULT_SAMPLE_ONE_IN: u32 = 4;
+
+/// Non-critical background work that may be shed under load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundWorkload {
+    /// Embedding generation for retrieval.
+    Embedding,
+    /// Rolling conversation summarisation.
+    Summarisation,
+    /// Usage and feedback analytics.
+    Analytics,
+}
+
+impl BackgroundWorkload {
+    /// Every workload, from most to least valuable.
+    pub const ALL: [Self; 3] = [Self::Embedding, Self::Summarisation, Self::Analytics];
+
+    /// Returns the canonical name used in logs and metrics.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Embedding => "embedding",
+            Self::Summarisation => "summarisation",
+            Self::Analytics => "analytics",
+        }
+    }
+
+    /// Returns the workload a processing stage belongs to, or `None` for
+    /// critical stages that are never shed.
+    #[must_use]
+    pub const fn for_stage(stage: ProcessingStage) -> Option<Self> {
+        match stage {
+            ProcessingStage::Embedding => Some(Self::Embedding),
+            ProcessingStage::Validation
+            | ProcessingStage::Moderation
+            | ProcessingStage::Projection => None,
+        }
+    }
+}
+
+impl fmt::Display for BackgroundWorkload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How loaded the system is, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadLevel {
+    /// Interactive turns meet their objective and the queues are short.
+    Normal,
+    /// Turns miss their objective or the queues are growing.
+    Elevated,
+    /// Turns miss their objective badly or the queues are backed up.
+    Overloaded,
+}
+
+impl LoadLevel {
+    /// Every level, from least to most loaded.
+    pub const ALL: [Self; 3] = [Self::Normal, Self::Elevated, Self::Overloaded];
+
+    /// Returns the canonical name used in logs and metrics.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Elevated => "elevated",
+            Self::Overloaded => "overloaded",
+        }
+    }
+}
+
+impl fmt::Display for LoadLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How a background workload runs at a load level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", content = "one_in", rename_all = "snake_case")]
+pub enum ShedMode {
+    /// Every job runs.
+    Run,
+    /// One job in the given number runs; the rest are deferred.
+    Sample(u32),
+    /// Every job is deferred.
+    Pause,
+}
+
+/// Whether a background job may run now.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SheddingDecision {
+    /// Run the job.
+    Run,
+    /// Leave the job for later, once load has fallen.
+    Defer,
+}
+
+impl SheddingDecision {
+    /// Returns whether the job may run now.
+    #[must_use]
+    pub const fn is_run(self) -> bool {
+        matches!(self, Self::Run)
+    }
+}
+
+/// Load observed across interactive turns and processing queues.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadSignals {
+    /// 95th percentile latency of recent interactive turns, if any ran.
+    pub turn_latency_p95: Option<Duration>,
+    /// Depth of the deepest processing queue.
+    pub max_queue_depth: usize,
+}
+
+/// Thresholds and per-workload shedding modes.
+///
+/// By default analytics pauses as soon as load is elevated, embedding and
+/// summarisation are sampled at one in [`DEFAULT_SAMPLE_ONE_IN`], and every
+/// workload pauses once the system is overloaded. Turns slower than twice
+/// the latency objective count as overloaded.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     BackgroundWorkload, LoadLevel, LoadSheddingPolicy, LoadSignals, ShedMode,
+/// };
+/// use std::time::Duration;
+///
+/// let policy = LoadSheddingPolicy::default()
+///     .with_turn_latency_slo(Duration::from_millis(800))
+///     .with_mode(BackgroundWorkload::Embedding, LoadLevel::Elevated, ShedMode::Run);
+///
+/// let signals = LoadSignals {
+///     turn_latency_p95: Some(Duration::from_secs(1)),
+///     max_queue_depth: 10,
+/// };
+/// assert_eq!(policy.level(signals), LoadLevel::Elevated);
+/// assert_eq!(
+///     policy.mode(BackgroundWorkload::Analytics, LoadLevel::Elevated),
+///     ShedMode::Pause
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadSheddingPolicy {
+    turn_latency_slo: Duration,
+    elevated_queue_depth: usize,
+    overloaded_queue_depth: usize,
+    modes: BTreeMap<(BackgroundWorkload, LoadLevel), ShedMode>,
+}
+
+impl Default for LoadSheddingPolicy {
+    fn default() -> Self {
+        let modes = BackgroundWorkload::ALL
+            .into_iter()
+            .flat_map(|workload| {
+                let elevated = match workload {
+                    BackgroundWorkload::Analytics => ShedMode::Pause,
+                    BackgroundWorkload::Embedding | BackgroundWorkload::Summarisation => {
+                        ShedMode::Sample(DEFAULT_SAMPLE_ONE_IN)
+                    }
+                };
+                [
+                    ((workload, LoadLevel::Elevated), elevated),
+                    ((workload, LoadLevel::Overloaded), ShedMode::Pause),
+                ]
+            })
+            .collect();
+        Self {
+            turn_latency_slo: DEFAULT_TURN_LATENCY_SLO,
+            elevated_queue_depth: DEFAULT_ELEVATED_QUEUE_DEPTH,
+            overloaded_queue_depth: DEFAULT_OVERLOADED_QUEUE_DEPTH,
+            modes,
+        }
+    }
+}
+
+impl LoadSheddingPolicy {
+    /// Sets the latency objective for interactive turns.
+    #[must_use]
+    pub const fn with_turn_latency_slo(mut self, slo: Duration) -> Self {
+        self.turn_latency_slo = slo;
+        self
+    }
+
+    /// Sets the queue depths above which load counts as elevated and as
+    /// overloaded. The overloaded depth is raised to the elevated one when
+    /// it is lower.
+    #[must_use]
+    pub fn with_queue_depths(mut self, elevated: usize, overloaded: usize) -> Self {
+        self.elevated_queue_depth = elevated;
+        self.overloaded_queue_depth = overloaded.max(elevated);
+        self
+    }
+
+    /// Sets how `workload` runs at `level`.
+    ///
+    /// Workloads always run in full at [`LoadLevel::Normal`], and a sample
+    /// of one in zero is treated as one in one.
+    #[must_use]
+    pub fn with_mode(
+        mut self,
+        workload: BackgroundWorkload,
+        level: LoadLevel,
+        mode: ShedMode,
+    ) -> Self {
+        let clamped = match mode {
+            ShedMode::Sample(one_in) => ShedMode::Sample(one_in.max(1)),
+            other => other,
+        };
+        self.modes.insert((workload, level), clamped);
+        self
+    }
+
+    /// Returns the latency objective for interactive turns.
+    #[must_use]
+    pub const fn turn_latency_slo(&self) -> Duration {
+        self.turn_latency_slo
+    }
+
+    /// Returns the load level the signals indicate.
+    #[must_use]
+    pub fn level(&self, signals: LoadSignals) -> LoadLevel {
+        let latency = signals.turn_latency_p95.unwrap_or_default();
+        if signals.max_queue_depth > self.overloaded_queue_depth
+            || latency > self.turn_latency_slo.saturating_mul(2)
+        {
+            LoadLevel::Overloaded
+        } else if signals.max_queue_depth > self.elevated_queue_depth
+            || latency > self.turn_latency_slo
+        {
+            LoadLevel::Elevated
+        } else {
+            LoadLevel::Normal
+        }
+    }
+
+    /// Returns how `workload` runs at `level`.
+    #[must_use]
+    pub fn mode(&self, workload: BackgroundWorkload, level: LoadLevel) -> ShedMode {
+        match level {
+            LoadLevel::Normal => ShedMode::Run,
+            LoadLevel::Elevated | LoadLevel::Overloaded => self
+                .modes
+                .get(&(workload, level))
+                .copied()
+                .unwrap_or(ShedMode::Run),
+        }
+    }
+}
+
+/// Shedding counters for one background workload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkloadSheddingMetrics {
+    /// The workload.
+    pub workload: BackgroundWorkload,
+    /// How the workload runs at the current load level.
+    #[serde(flatten)]
+    pub mode: ShedMode,
+    /// Jobs allowed to run since start-up.
+    pub admitted: u64,
+    /// Jobs deferred since start-up.
+    pub deferred: u64,
+}
+
+/// Point-in-time view of the load-shedding controller, for metrics export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadSheddingMetrics {
+    /// Current load level.
+    pub level: LoadLevel,
+    /// Signals the level was derived from.
+    pub signals: LoadSignals,
+    /// Latest reported depth of each processing queue.
+    pub queue_depths: BTreeMap<String, usize>,
+    /// Number of level changes since start-up.
+    pub level_changes: u64,
+    /// Counters for every workload, in [`BackgroundWorkload::ALL`] order.
+    pub workloads: Vec<WorkloadSheddingMetrics>,
+}
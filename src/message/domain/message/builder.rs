@@ -0,0 +1,100 @@
+//! Builder for messages with metadata or a chosen identifier.
+
+use super::Message;
+use crate::message::domain::{
+    ContentPart, ConversationId, MessageId, MessageMetadata, Role, SequenceNumber,
+};
+use mockable::Clock;
+
+/// Builder for constructing messages with full control over all fields.
+#[derive(Debug)]
+pub struct MessageBuilder {
+    id: Option<MessageId>,
+    conversation_id: ConversationId,
+    role: Role,
+    content: Vec<ContentPart>,
+    metadata: MessageMetadata,
+    sequence_number: SequenceNumber,
+}
+
+impl MessageBuilder {
+    /// Creates a new message builder.
+    #[must_use]
+    pub fn new(
+        conversation_id: ConversationId,
+        role: Role,
+        sequence_number: SequenceNumber,
+    ) -> Self {
+        Self {
+            id: None,
+            conversation_id,
+            role,
+            content: Vec::new(),
+            metadata: MessageMetadata::empty(),
+            sequence_number,
+        }
+    }
+
+    /// Sets a specific message ID.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "Option::Some with Copy type should be const but isn't stable"
+    )]
+    pub fn with_id(mut self, id: MessageId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Adds a content part.
+    #[must_use]
+    pub fn with_content(mut self, part: ContentPart) -> Self {
+        self.content.push(part);
+        self
+    }
+
+    /// Adds multiple content parts.
+    #[must_use]
+    pub fn with_content_parts(mut self, parts: impl IntoIterator<Item = ContentPart>) -> Self {
+        self.content.extend(parts);
+        self
+    }
+
+    /// Sets the metadata.
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: MessageMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Builds the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MessageBuilderError::EmptyContent`] if no content parts were added.
+    pub fn build(self, clock: &(impl Clock + ?Sized)) -> Result<Message, MessageBuilderError> {
+        if self.content.is_empty() {
+            return Err(MessageBuilderError::EmptyContent);
+        }
+
+        let id = self.id.unwrap_or_default();
+
+        Ok(Message {
+            id,
+            conversation_id: self.conversation_id,
+            role: self.role,
+            content: self.content,
+            metadata: self.metadata,
+            created_at: clock.utc(),
+            sequence_number: self.sequence_number,
+        })
+    }
+}
+
+/// Errors that can occur when building a message.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MessageBuilderError {
+    /// The message content is empty.
+    #[error("message must contain at least one content part")]
+    EmptyContent,
+}
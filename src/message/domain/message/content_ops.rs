@@ -0,0 +1,183 @@
+//! Transformations of a message's content: redaction, reasoning removal,
+//! masking sensitive data, and moving attachment data to and from a blob
+//! store.
+
+use super::Message;
+use crate::message::domain::{
+    AttachmentBlob, ContentHash, ContentPart, MessageRedaction, SENSITIVE_DATA_EXTENSION_KEY,
+    SensitiveSpan,
+};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+impl Message {
+    /// Returns `true` if the message's content has been redacted.
+    #[must_use]
+    pub fn is_redacted(&self) -> bool {
+        self.content
+            .iter()
+            .any(|part| matches!(part, ContentPart::Redacted(_)))
+    }
+
+    /// Returns the message with its content replaced by the redaction's
+    /// placeholder.
+    ///
+    /// The ID, role, sequence number, and timestamps are kept. Metadata is
+    /// kept as audit trail, except that a slash command expansion loses its
+    /// parameters and expanded text, which repeat what the user typed, and
+    /// the token count is dropped, since it measured the removed content.
+    #[must_use]
+    pub fn redacted(mut self, redaction: &MessageRedaction) -> Self {
+        self.content = vec![redaction.placeholder()];
+        if let Some(expansion) = self.metadata.slash_command_expansion.as_mut() {
+            expansion.parameters.clear();
+            expansion.expanded_content.clear();
+        }
+        self.metadata.token_count = None;
+        self
+    }
+
+    /// Returns the message without its reasoning parts, or `None` when
+    /// reasoning was all it held.
+    ///
+    /// Citation spans are renumbered to follow the parts they cite.
+    #[must_use]
+    pub fn without_reasoning(mut self) -> Option<Self> {
+        let removed: Vec<usize> = self
+            .content
+            .iter()
+            .enumerate()
+            .filter(|(_, part)| matches!(part, ContentPart::Reasoning(_)))
+            .map(|(index, _)| index)
+            .collect();
+        self.content
+            .retain(|part| !matches!(part, ContentPart::Reasoning(_)));
+        for part in &mut self.content {
+            if let ContentPart::Citation(citation) = part {
+                citation.renumber_after_removal(&removed);
+            }
+        }
+        (!self.content.is_empty()).then_some(self)
+    }
+
+    /// Returns the message with each span of sensitive data in its text and
+    /// reasoning parts replaced by the placeholder of its kind.
+    ///
+    /// Spans overlapping one already masked, or outside a text or reasoning
+    /// part, are ignored. The token count is dropped, since it measured the
+    /// unmasked text.
+    #[must_use]
+    pub fn with_sensitive_data_masked(mut self, spans: &[SensitiveSpan]) -> Self {
+        let mut ordered: Vec<&SensitiveSpan> = spans.iter().collect();
+        ordered.sort_by_key(|span| (span.part_index, Reverse(span.start)));
+        // Masking from the end of each part keeps earlier offsets valid.
+        let mut masked_from: Option<(usize, usize)> = None;
+        for span in ordered {
+            let overlaps = masked_from
+                .is_some_and(|(part, start)| part == span.part_index && span.end > start);
+            let Some(text) = self.content.get_mut(span.part_index).and_then(free_text) else {
+                continue;
+            };
+            if overlaps || span.start >= span.end {
+                continue;
+            }
+            let byte_at = |chars: usize| {
+                text.char_indices()
+                    .nth(chars)
+                    .map_or(text.len(), |(index, _)| index)
+            };
+            let range = byte_at(span.start)..byte_at(span.end);
+            text.replace_range(range, span.kind.placeholder());
+            masked_from = Some((span.part_index, span.start));
+        }
+        self.metadata.token_count = None;
+        self
+    }
+
+    /// Returns the message with `spans` recorded in its metadata under
+    /// [`SENSITIVE_DATA_EXTENSION_KEY`], replacing any recorded before.
+    ///
+    /// # Errors
+    ///
+    /// Returns `serde_json::Error` if the spans fail to serialise, which
+    /// their plain fields make impossible in practice.
+    pub fn with_sensitive_data_recorded(
+        mut self,
+        spans: &[SensitiveSpan],
+    ) -> Result<Self, serde_json::Error> {
+        let value = serde_json::to_value(spans)?;
+        self.metadata
+            .extensions
+            .insert(SENSITIVE_DATA_EXTENSION_KEY.to_owned(), value);
+        Ok(self)
+    }
+
+    /// Returns the message with the data of attachments and images larger
+    /// than `max_inline_bytes` moved out, together with the moved data.
+    ///
+    /// Each moved part keeps the [`ContentHash`] of its data in its `blob`
+    /// field, as in [`AttachmentPart::blob`](crate::message::domain::AttachmentPart::blob).
+    #[must_use]
+    pub fn externalise_attachments(
+        mut self,
+        max_inline_bytes: usize,
+    ) -> (Self, Vec<AttachmentBlob>) {
+        let blobs = self
+            .content
+            .iter_mut()
+            .filter_map(|part| match part {
+                ContentPart::Attachment(attachment) if attachment.data.len() > max_inline_bytes => {
+                    Some(attachment.externalise())
+                }
+                ContentPart::Image(image) if image.data.len() > max_inline_bytes => {
+                    Some(image.externalise())
+                }
+                _ => None,
+            })
+            .collect();
+        (self, blobs)
+    }
+
+    /// Returns the hashes of the blobs holding this message's attachment
+    /// and image data.
+    pub fn attachment_blobs(&self) -> impl Iterator<Item = &ContentHash> {
+        self.content.iter().filter_map(part_blob)
+    }
+
+    /// Returns the message with attachment and image data put back from
+    /// `blobs`.
+    ///
+    /// Parts whose blob is missing from `blobs` keep their reference.
+    #[must_use]
+    pub fn with_attachment_data(mut self, blobs: &BTreeMap<ContentHash, String>) -> Self {
+        for part in &mut self.content {
+            let Some(data) = part_blob(part).and_then(|hash| blobs.get(hash)).cloned() else {
+                continue;
+            };
+            match part {
+                ContentPart::Attachment(attachment) => attachment.restore(data),
+                ContentPart::Image(image) => image.restore(data),
+                _ => {}
+            }
+        }
+        self
+    }
+}
+
+/// Returns the free text of a text or reasoning part.
+const fn free_text(part: &mut ContentPart) -> Option<&mut String> {
+    match part {
+        ContentPart::Text(text) => Some(&mut text.text),
+        ContentPart::Reasoning(reasoning) => Some(&mut reasoning.text),
+        _ => None,
+    }
+}
+
+/// Returns the blob holding the data of an attachment or image part.
+const fn part_blob(part: &ContentPart) -> Option<&ContentHash> {
+    match part {
+        ContentPart::Attachment(attachment) => attachment.blob.as_ref(),
+        ContentPart::Image(image) => image.blob.as_ref(),
+        _ => None,
+    }
+}
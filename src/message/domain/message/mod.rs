@@ -1,9 +1,19 @@
 //! The Message aggregate root representing a single message in a conversation.
 //!
-//! Messages are immutable after creation and contain all information needed
-//! to reconstruct the conversation state.
+//! Messages are immutable after creation, except that their content can be
+//! redacted, and contain all information needed to reconstruct the
+//! conversation state.
 
-use super::{ContentPart, ConversationId, MessageId, MessageMetadata, Role, SequenceNumber};
+mod builder;
+mod content_ops;
+
+pub use builder::{MessageBuilder, MessageBuilderError};
+
+use super::{
+    ContentPart, ConversationId, MessageId, MessageMetadata, MessageTokenCount, Role,
+    SequenceNumber,
+};
+use crate::message::canonical;
 use chrono::{DateTime, Utc};
 use mockable::Clock;
 use serde::{Deserialize, Serialize};
@@ -19,7 +29,9 @@ use serde::{Deserialize, Serialize};
 /// - `id` is always a valid, non-nil UUID
 /// - `created_at` is always populated
 /// - `content` contains at least one part (enforced at construction)
-/// - Messages cannot be modified after creation
+/// - Messages cannot be modified after creation, other than by
+///   [`Message::redacted`] and by moving attachment data to and from a blob
+///   store
 ///
 /// # Examples
 ///
@@ -64,6 +76,14 @@ pub struct Message {
     sequence_number: SequenceNumber,
 }
 
+/// The fields of a message that [`Message::dedup_hash`] covers.
+#[derive(Serialize)]
+struct DedupKey<'a> {
+    conversation_id: ConversationId,
+    role: Role,
+    content: &'a [ContentPart],
+}
+
 impl Message {
     /// Creates a new message with the current timestamp.
     ///
@@ -98,7 +118,7 @@ impl Message {
         role: Role,
         content: Vec<ContentPart>,
         sequence_number: SequenceNumber,
-        clock: &impl Clock,
+        clock: &(impl Clock + ?Sized),
     ) -> Result<Self, MessageBuilderError> {
         Self::new_with_id(
             MessageId::new(),
@@ -125,7 +145,7 @@ impl Message {
         role: Role,
         content: Vec<ContentPart>,
         sequence_number: SequenceNumber,
-        clock: &impl Clock,
+        clock: &(impl Clock + ?Sized),
     ) -> Result<Self, MessageBuilderError> {
         if content.is_empty() {
             return Err(MessageBuilderError::EmptyContent);
@@ -184,6 +204,84 @@ impl Message {
         self.sequence_number
     }
 
+    /// Returns the message moved to `sequence_number`.
+    ///
+    /// Repositories use this when they allocate the sequence number on
+    /// append.
+    #[must_use]
+    pub const fn with_sequence_number(mut self, sequence_number: SequenceNumber) -> Self {
+        self.sequence_number = sequence_number;
+        self
+    }
+
+    /// Returns the message with its token count recorded in its metadata.
+    ///
+    /// Services use this when they measure a message at ingestion.
+    #[must_use]
+    pub fn with_token_count(mut self, count: MessageTokenCount) -> Self {
+        self.metadata.token_count = Some(count);
+        self
+    }
+
+    /// Returns `true` if the message is pinned into every context.
+    #[must_use]
+    pub const fn is_pinned(&self) -> bool {
+        self.metadata.pinned
+    }
+
+    /// Returns the message pinned or unpinned.
+    #[must_use]
+    pub const fn with_pinned(mut self, pinned: bool) -> Self {
+        self.metadata.pinned = pinned;
+        self
+    }
+
+    /// Returns a copy of the message in `conversation_id` under a fresh
+    /// identifier.
+    ///
+    /// Forks use this to copy shared history; the sequence number,
+    /// timestamp, content, and metadata are kept.
+    #[must_use]
+    pub fn copied_to(&self, conversation_id: ConversationId) -> Self {
+        Self {
+            id: MessageId::new(),
+            conversation_id,
+            ..self.clone()
+        }
+    }
+
+    /// Serialises the message to canonical JSON.
+    ///
+    /// See [`crate::message::canonical`] for the rules that make the bytes
+    /// stable.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serialisation error when custom content or metadata
+    /// cannot be represented as JSON.
+    pub fn to_canonical_json(&self) -> Result<String, serde_json::Error> {
+        canonical::to_canonical_string(self)
+    }
+
+    /// Returns a digest identifying the message's content within its
+    /// conversation, for detecting duplicate submissions.
+    ///
+    /// The digest covers the conversation, role, and content, but not the
+    /// ID, timestamp, sequence number, or metadata, so two submissions of
+    /// the same content by the same role share it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serialisation error when custom content cannot be
+    /// represented as JSON.
+    pub fn dedup_hash(&self) -> Result<String, serde_json::Error> {
+        canonical::canonical_sha256(&DedupKey {
+            conversation_id: self.conversation_id,
+            role: self.role,
+            content: &self.content,
+        })
+    }
+
     /// Returns a builder for constructing messages with metadata.
     ///
     /// # Examples
@@ -251,96 +349,3 @@ impl Message {
         })
     }
 }
-
-/// Builder for constructing messages with full control over all fields.
-#[derive(Debug)]
-pub struct MessageBuilder {
-    id: Option<MessageId>,
-    conversation_id: ConversationId,
-    role: Role,
-    content: Vec<ContentPart>,
-    metadata: MessageMetadata,
-    sequence_number: SequenceNumber,
-}
-
-impl MessageBuilder {
-    /// Creates a new message builder.
-    #[must_use]
-    pub fn new(
-        conversation_id: ConversationId,
-        role: Role,
-        sequence_number: SequenceNumber,
-    ) -> Self {
-        Self {
-            id: None,
-            conversation_id,
-            role,
-            content: Vec::new(),
-            metadata: MessageMetadata::empty(),
-            sequence_number,
-        }
-    }
-
-    /// Sets a specific message ID.
-    #[must_use]
-    #[expect(
-        clippy::missing_const_for_fn,
-        reason = "Option::Some with Copy type should be const but isn't stable"
-    )]
-    pub fn with_id(mut self, id: MessageId) -> Self {
-        self.id = Some(id);
-        self
-    }
-
-    /// Adds a content part.
-    #[must_use]
-    pub fn with_content(mut self, part: ContentPart) -> Self {
-        self.content.push(part);
-        self
-    }
-
-    /// Adds multiple content parts.
-    #[must_use]
-    pub fn with_content_parts(mut self, parts: impl IntoIterator<Item = ContentPart>) -> Self {
-        self.content.extend(parts);
-        self
-    }
-
-    /// Sets the metadata.
-    #[must_use]
-    pub fn with_metadata(mut self, metadata: MessageMetadata) -> Self {
-        self.metadata = metadata;
-        self
-    }
-
-    /// Builds the message.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`MessageBuilderError::EmptyContent`] if no content parts were added.
-    pub fn build(self, clock: &impl Clock) -> Result<Message, MessageBuilderError> {
-        if self.content.is_empty() {
-            return Err(MessageBuilderError::EmptyContent);
-        }
-
-        let id = self.id.unwrap_or_default();
-
-        Ok(Message {
-            id,
-            conversation_id: self.conversation_id,
-            role: self.role,
-            content: self.content,
-            metadata: self.metadata,
-            created_at: clock.utc(),
-            sequence_number: self.sequence_number,
-        })
-    }
-}
-
-/// Errors that can occur when building a message.
-#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
-pub enum MessageBuilderError {
-    /// The message content is empty.
-    #[error("message must contain at least one content part")]
-    EmptyContent,
-}
@@ -0,0 +1,163 @@
+//! Filter criteria for fetching a subset of a conversation's messages.
+
+use super::{ContentPartKind, Message, Role};
+use crate::pagination::PageRequest;
+use chrono::{DateTime, Utc};
+
+/// Criteria selecting messages within one conversation.
+///
+/// An empty query matches every message. Roles and content-part kinds match
+/// when a message has any of the listed values; metadata keys match only when
+/// the message carries all of them. Results are ordered by sequence number.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{ContentPartKind, MessageQuery, Role};
+///
+/// let query = MessageQuery::new()
+///     .with_role(Role::Tool)
+///     .with_content_part(ContentPartKind::ToolResult);
+/// assert_eq!(query.roles(), &[Role::Tool]);
+/// assert_eq!(query.content_kinds(), &[ContentPartKind::ToolResult]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageQuery {
+    roles: Vec<Role>,
+    created_from: Option<DateTime<Utc>>,
+    created_until: Option<DateTime<Utc>>,
+    content_kinds: Vec<ContentPartKind>,
+    metadata_keys: Vec<String>,
+    page: PageRequest,
+}
+
+impl MessageQuery {
+    /// Creates a query matching every message.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `role` to the roles a message may have.
+    #[must_use]
+    pub fn with_role(mut self, role: Role) -> Self {
+        if !self.roles.contains(&role) {
+            self.roles.push(role);
+        }
+        self
+    }
+
+    /// Restricts the query to messages created at or after `from`.
+    #[must_use]
+    pub const fn with_created_from(mut self, from: DateTime<Utc>) -> Self {
+        self.created_from = Some(from);
+        self
+    }
+
+    /// Restricts the query to messages created before `until`.
+    #[must_use]
+    pub const fn with_created_until(mut self, until: DateTime<Utc>) -> Self {
+        self.created_until = Some(until);
+        self
+    }
+
+    /// Adds `kind` to the content-part kinds a message may contain.
+    #[must_use]
+    pub fn with_content_part(mut self, kind: ContentPartKind) -> Self {
+        if !self.content_kinds.contains(&kind) {
+            self.content_kinds.push(kind);
+        }
+        self
+    }
+
+    /// Restricts the query to messages containing tool calls.
+    #[must_use]
+    pub fn with_tool_calls(self) -> Self {
+        self.with_content_part(ContentPartKind::ToolCall)
+    }
+
+    /// Requires messages to carry metadata under `key`.
+    ///
+    /// See [`MessageMetadata::has_key`](super::MessageMetadata::has_key) for
+    /// the keys that are recognised.
+    #[must_use]
+    pub fn with_metadata_key(mut self, key: impl Into<String>) -> Self {
+        let owned_key = key.into();
+        if !self.metadata_keys.contains(&owned_key) {
+            self.metadata_keys.push(owned_key);
+        }
+        self
+    }
+
+    /// Restricts the query to pinned messages.
+    #[must_use]
+    pub fn with_pinned(self) -> Self {
+        self.with_metadata_key("pinned")
+    }
+
+    /// Sets the page of matching messages to return.
+    #[must_use]
+    pub const fn with_page(mut self, page: PageRequest) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Returns the role filter; empty matches any role.
+    #[must_use]
+    pub fn roles(&self) -> &[Role] {
+        &self.roles
+    }
+
+    /// Returns the inclusive lower creation-time bound, if set.
+    #[must_use]
+    pub const fn created_from(&self) -> Option<DateTime<Utc>> {
+        self.created_from
+    }
+
+    /// Returns the exclusive upper creation-time bound, if set.
+    #[must_use]
+    pub const fn created_until(&self) -> Option<DateTime<Utc>> {
+        self.created_until
+    }
+
+    /// Returns the content-part filter; empty matches any content.
+    #[must_use]
+    pub fn content_kinds(&self) -> &[ContentPartKind] {
+        &self.content_kinds
+    }
+
+    /// Returns the metadata keys every matching message must carry.
+    #[must_use]
+    pub fn metadata_keys(&self) -> &[String] {
+        &self.metadata_keys
+    }
+
+    /// Returns the requested page.
+    #[must_use]
+    pub const fn page(&self) -> PageRequest {
+        self.page
+    }
+
+    /// Returns whether `message` satisfies every criterion.
+    #[must_use]
+    pub fn matches(&self, message: &Message) -> bool {
+        let role_matches = self.roles.is_empty() || self.roles.contains(&message.role());
+        let content_matches = self.content_kinds.is_empty()
+            || message
+                .content()
+                .iter()
+                .any(|part| self.content_kinds.contains(&part.kind()));
+        role_matches
+            && content_matches
+            && self
+                .created_from
+                .is_none_or(|from| message.created_at() >= from)
+            && self
+                .created_until
+                .is_none_or(|until| message.created_at() < until)
+            && self
+                .metadata_keys
+                .iter()
+                .all(|key| message.metadata().has_key(key))
+    }
+}
@@ -0,0 +1,102 @@
+//! Namespace rules for message metadata extension keys.
+//!
+//! Integrators record their own data under vendor-prefixed keys such as
+//! `"acme.trace_id"`: a lowercase vendor segment, a dot, and one or more
+//! further segments. Corbusier keeps the namespaces in
+//! [`RESERVED_EXTENSION_NAMESPACES`] for its own keys, which are written
+//! through dedicated builders rather than
+//! [`MessageMetadata::with_extension`](super::MessageMetadata::with_extension).
+
+use super::super::inbound::INBOUND_ORIGIN_EXTENSION_KEY;
+use super::super::inbound_email::INBOUND_EMAIL_EXTENSION_KEY;
+use super::super::sensitive_data::SENSITIVE_DATA_EXTENSION_KEY;
+use super::super::streaming::STREAM_INTERRUPTED_EXTENSION_KEY;
+use super::super::transcript::INBOUND_TRANSCRIPT_EXTENSION_KEY;
+
+/// Extension key under which [`ReviewLinkage`](super::ReviewLinkage) data is
+/// stored.
+pub const REVIEW_LINKAGE_EXTENSION_KEY: &str = "review.linkage.v1";
+
+/// Extension namespaces reserved for keys written by Corbusier itself.
+pub const RESERVED_EXTENSION_NAMESPACES: &[&str] = &["corbusier", "inbound", "review"];
+
+/// Keys Corbusier writes itself. `stream_interrupted` predates the
+/// namespace rules and is kept for compatibility with stored messages.
+const CORBUSIER_EXTENSION_KEYS: &[&str] = &[
+    INBOUND_EMAIL_EXTENSION_KEY,
+    INBOUND_ORIGIN_EXTENSION_KEY,
+    INBOUND_TRANSCRIPT_EXTENSION_KEY,
+    REVIEW_LINKAGE_EXTENSION_KEY,
+    SENSITIVE_DATA_EXTENSION_KEY,
+    STREAM_INTERRUPTED_EXTENSION_KEY,
+];
+
+/// Error returned when an extension key breaks the namespace rules.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ExtensionKeyError {
+    /// The key lies in a namespace reserved for Corbusier.
+    #[error("extension key {0:?} is reserved; use the dedicated builder method instead")]
+    Reserved(String),
+
+    /// The key is not of the form `vendor.name`.
+    #[error(
+        "extension key {0:?} must be vendor-prefixed, such as \"acme.name\", using lowercase \
+         letters, digits, '_' and '-'"
+    )]
+    NotNamespaced(String),
+}
+
+/// Checks that `key` may be set by an integrator.
+///
+/// # Errors
+///
+/// Returns [`ExtensionKeyError::NotNamespaced`] unless the key is a
+/// lowercase vendor segment followed by one or more dot-separated segments,
+/// and [`ExtensionKeyError::Reserved`] when the vendor segment is one of
+/// [`RESERVED_EXTENSION_NAMESPACES`].
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{ExtensionKeyError, check_extension_key};
+///
+/// assert!(check_extension_key("acme.trace_id").is_ok());
+/// assert!(matches!(
+///     check_extension_key("trace_id"),
+///     Err(ExtensionKeyError::NotNamespaced(_))
+/// ));
+/// assert!(matches!(
+///     check_extension_key("review.linkage.v2"),
+///     Err(ExtensionKeyError::Reserved(_))
+/// ));
+/// ```
+pub fn check_extension_key(key: &str) -> Result<(), ExtensionKeyError> {
+    let mut segments = key.split('.');
+    let vendor = segments.next().unwrap_or_default();
+    let rest: Vec<&str> = segments.collect();
+    let well_formed = vendor.starts_with(|c: char| c.is_ascii_lowercase())
+        && !rest.is_empty()
+        && std::iter::once(vendor)
+            .chain(rest.iter().copied())
+            .all(is_segment);
+    if !well_formed {
+        return Err(ExtensionKeyError::NotNamespaced(key.to_owned()));
+    }
+    if RESERVED_EXTENSION_NAMESPACES.contains(&vendor) {
+        return Err(ExtensionKeyError::Reserved(key.to_owned()));
+    }
+    Ok(())
+}
+
+/// Returns whether `key` is one Corbusier writes through its own builders.
+#[must_use]
+pub fn is_corbusier_extension_key(key: &str) -> bool {
+    CORBUSIER_EXTENSION_KEYS.contains(&key)
+}
+
+fn is_segment(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-'))
+}
@@ -1,23 +1,22 @@
 //! Message metadata types capturing contextual information about messages.
 
+mod extensions;
+mod payloads;
+
+pub use extensions::{
+    ExtensionKeyError, RESERVED_EXTENSION_NAMESPACES, REVIEW_LINKAGE_EXTENSION_KEY,
+    check_extension_key, is_corbusier_extension_key,
+};
+pub use payloads::{ReviewLinkage, SlashCommandExpansion};
+
 use super::handoff::HandoffMetadata;
+use super::token_count::MessageTokenCount;
 use super::{AgentSessionId, TurnId, audit::AgentResponseAudit, audit::ToolCallAudit};
 use serde::de::Deserializer;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
-/// Reserved extension key namespace prefix for review linkage data.
-const RESERVED_REVIEW_PREFIX: &str = "review.linkage.";
-
-/// Error returned when a caller attempts to set a reserved extension key
-/// via [`MessageMetadata::with_extension`].
-#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
-#[error("extension key {key:?} is reserved; use the dedicated builder method instead")]
-pub struct ReservedExtensionKeyError {
-    key: String,
-}
-
 /// Metadata associated with a message.
 ///
 /// Captures information about the message's origin, processing context,
@@ -62,13 +61,24 @@ pub struct MessageMetadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agent_session_id: Option<AgentSessionId>,
 
+    /// How many model tokens the message's content encodes to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_count: Option<MessageTokenCount>,
+
+    /// Whether the message is always included in a model's context, however
+    /// the rest of the history is truncated or compacted.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
+
     /// Extension data for custom metadata fields.
     ///
     /// Extensions are serialized under an explicit `"extensions"` key rather
     /// than being flattened into the top-level JSON object, preventing key
-    /// collisions with known struct fields.  Workflow-specific data should
-    /// use a reserved, versioned namespace key such as `"review.linkage.v1"`
-    /// so that schema evolution and deserialization remain predictable.
+    /// collisions with known struct fields.  Integrator keys are
+    /// vendor-prefixed, such as `"acme.trace_id"`, and Corbusier's own keys
+    /// use the namespaces in [`RESERVED_EXTENSION_NAMESPACES`] with a
+    /// version suffix, such as `"review.linkage.v1"`, so that schema
+    /// evolution and deserialization remain predictable.
     ///
     /// A compatibility deserializer merges legacy top-level extension keys
     /// (those not matching any known struct field) into this map, so older
@@ -101,6 +111,10 @@ struct MessageMetadataCompat {
     #[serde(default)]
     agent_session_id: Option<AgentSessionId>,
     #[serde(default)]
+    token_count: Option<MessageTokenCount>,
+    #[serde(default)]
+    pinned: bool,
+    #[serde(default)]
     extensions: HashMap<String, Value>,
     /// Catch-all for unrecognised top-level keys (legacy flat layout).
     #[serde(flatten)]
@@ -122,6 +136,8 @@ impl<'de> Deserialize<'de> for MessageMetadata {
             agent_response_audit: compat.agent_response_audit,
             handoff_metadata: compat.handoff_metadata,
             agent_session_id: compat.agent_session_id,
+            token_count: compat.token_count,
+            pinned: compat.pinned,
             extensions: compat.extensions,
         })
     }
@@ -218,22 +234,20 @@ impl MessageMetadata {
         self
     }
 
-    /// Adds an extension field.
+    /// Adds an extension field under a vendor-prefixed key.
     ///
     /// # Errors
     ///
-    /// Returns [`ReservedExtensionKeyError`] if `key` starts with the
-    /// reserved `"review.linkage."` namespace.  Use
+    /// Returns [`ExtensionKeyError`] if `key` is not vendor-prefixed or lies
+    /// in one of the [`RESERVED_EXTENSION_NAMESPACES`].  Use
     /// [`with_review_linkage`](Self::with_review_linkage) for review data.
     pub fn with_extension(
         mut self,
         key: impl Into<String>,
         value: Value,
-    ) -> Result<Self, ReservedExtensionKeyError> {
+    ) -> Result<Self, ExtensionKeyError> {
         let owned_key = key.into();
-        if owned_key.starts_with(RESERVED_REVIEW_PREFIX) {
-            return Err(ReservedExtensionKeyError { key: owned_key });
-        }
+        check_extension_key(&owned_key)?;
         self.extensions.insert(owned_key, value);
         Ok(self)
     }
@@ -273,7 +287,7 @@ impl MessageMetadata {
     ) -> Result<Self, serde_json::Error> {
         let value = serde_json::to_value(linkage)?;
         self.extensions
-            .insert("review.linkage.v1".to_owned(), value);
+            .insert(REVIEW_LINKAGE_EXTENSION_KEY.to_owned(), value);
         Ok(self)
     }
 
@@ -291,6 +305,20 @@ impl MessageMetadata {
         self
     }
 
+    /// Sets the message's token count.
+    #[must_use]
+    pub fn with_token_count(mut self, count: MessageTokenCount) -> Self {
+        self.token_count = Some(count);
+        self
+    }
+
+    /// Sets whether the message is pinned into every context.
+    #[must_use]
+    pub const fn with_pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
     /// Returns `true` if the metadata is empty (no fields set).
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -301,98 +329,43 @@ impl MessageMetadata {
             && self.agent_response_audit.is_none()
             && self.handoff_metadata.is_none()
             && self.agent_session_id.is_none()
+            && self.token_count.is_none()
+            && !self.pinned
             && self.extensions.is_empty()
     }
-}
-
-/// Details about a slash command expansion that produced a message.
-///
-/// When a user invokes a slash command (e.g., `/review`), the command is
-/// expanded into a template that generates one or more messages. This
-/// structure records the expansion details for audit and debugging.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct SlashCommandExpansion {
-    /// The original command string (e.g., "/review").
-    pub command: String,
-    /// Parameters passed to the command.
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub parameters: HashMap<String, Value>,
-    /// The expanded template result.
-    pub expanded_content: String,
-}
-
-impl SlashCommandExpansion {
-    /// Creates a new slash command expansion record.
-    #[must_use]
-    pub fn new(command: impl Into<String>, expanded_content: impl Into<String>) -> Self {
-        Self {
-            command: command.into(),
-            parameters: HashMap::new(),
-            expanded_content: expanded_content.into(),
-        }
-    }
-
-    /// Adds a parameter to the expansion.
-    #[must_use]
-    pub fn with_parameter(mut self, key: impl Into<String>, value: Value) -> Self {
-        self.parameters.insert(key.into(), value);
-        self
-    }
-}
-
-/// Structured review linkage data stored under the reserved, versioned
-/// namespace key `"review.linkage.v1"` inside `MessageMetadata.extensions`.
-///
-/// Groups review-comment anchoring fields into a single typed object so
-/// that schema evolution and deserialization remain predictable.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ReviewLinkage {
-    /// Identifier of the review comment in the external VCS provider.
-    pub review_comment_id: String,
-    /// Root comment identifier that anchors the review thread.
-    pub thread_root_id: String,
-    /// Login or display name of the reviewer.
-    pub reviewer: String,
-    /// Source file path the comment is anchored to (if applicable).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_path: Option<String>,
-    /// Commit SHA the comment is anchored to (if applicable).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub commit_sha: Option<String>,
-    /// Current verification status of the review linkage.
-    pub verification_status: String,
-}
 
-impl ReviewLinkage {
-    /// Creates a new review linkage with the required fields.
+    /// Returns `true` if the metadata carries a value under `key`.
+    ///
+    /// `key` names either a known field, such as `"agent_backend"` or
+    /// `"tool_call_audits"`, or an extension key. Known fields count as
+    /// present when they are set and, for lists, non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use corbusier::message::domain::MessageMetadata;
+    /// use serde_json::json;
+    ///
+    /// let metadata = MessageMetadata::with_agent_backend("codex_cli")
+    ///     .with_extension("replay.source", json!("ci"))
+    ///     .expect("extension key is not reserved");
+    /// assert!(metadata.has_key("agent_backend"));
+    /// assert!(metadata.has_key("replay.source"));
+    /// assert!(!metadata.has_key("turn_id"));
+    /// ```
     #[must_use]
-    pub fn new(
-        review_comment_id: impl Into<String>,
-        thread_root_id: impl Into<String>,
-        reviewer: impl Into<String>,
-        verification_status: impl Into<String>,
-    ) -> Self {
-        Self {
-            review_comment_id: review_comment_id.into(),
-            thread_root_id: thread_root_id.into(),
-            reviewer: reviewer.into(),
-            file_path: None,
-            commit_sha: None,
-            verification_status: verification_status.into(),
+    pub fn has_key(&self, key: &str) -> bool {
+        match key {
+            "agent_backend" => self.agent_backend.is_some(),
+            "turn_id" => self.turn_id.is_some(),
+            "slash_command_expansion" => self.slash_command_expansion.is_some(),
+            "tool_call_audits" => !self.tool_call_audits.is_empty(),
+            "agent_response_audit" => self.agent_response_audit.is_some(),
+            "handoff_metadata" => self.handoff_metadata.is_some(),
+            "agent_session_id" => self.agent_session_id.is_some(),
+            "token_count" => self.token_count.is_some(),
+            "pinned" => self.pinned,
+            other => self.extensions.contains_key(other),
         }
     }
-
-    /// Sets the file path anchor.
-    #[must_use]
-    pub fn with_file_path(mut self, path: impl Into<String>) -> Self {
-        self.file_path = Some(path.into());
-        self
-    }
-
-    /// Sets the commit SHA anchor.
-    #[must_use]
-    pub fn with_commit_sha(mut self, sha: impl Into<String>) -> Self {
-        self.commit_sha = Some(sha.into());
-        self
-    }
 }
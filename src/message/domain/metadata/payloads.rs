@@ -0,0 +1,105 @@
+//! Typed payloads carried in message metadata: slash command expansions
+//! and review linkage.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::message::domain::SlashCommandExecutionId;
+
+/// Details about a slash command expansion that produced a message.
+///
+/// When a user invokes a slash command (e.g., `/review`), the command is
+/// expanded into a template that generates one or more messages. This
+/// structure records the expansion details for audit and debugging.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlashCommandExpansion {
+    /// The original command string (e.g., "/review").
+    pub command: String,
+    /// Parameters passed to the command.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub parameters: HashMap<String, Value>,
+    /// The expanded template result.
+    pub expanded_content: String,
+    /// Recorded execution that produced the expansion, linking messages to
+    /// the slash-command audit trail.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_id: Option<SlashCommandExecutionId>,
+}
+
+impl SlashCommandExpansion {
+    /// Creates a new slash command expansion record.
+    #[must_use]
+    pub fn new(command: impl Into<String>, expanded_content: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            parameters: HashMap::new(),
+            expanded_content: expanded_content.into(),
+            execution_id: None,
+        }
+    }
+
+    /// Adds a parameter to the expansion.
+    #[must_use]
+    pub fn with_parameter(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.parameters.insert(key.into(), value);
+        self
+    }
+}
+
+/// Structured review linkage data stored under the reserved, versioned
+/// namespace key `"review.linkage.v1"` inside `MessageMetadata.extensions`.
+///
+/// Groups review-comment anchoring fields into a single typed object so
+/// that schema evolution and deserialization remain predictable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewLinkage {
+    /// Identifier of the review comment in the external VCS provider.
+    pub review_comment_id: String,
+    /// Root comment identifier that anchors the review thread.
+    pub thread_root_id: String,
+    /// Login or display name of the reviewer.
+    pub reviewer: String,
+    /// Source file path the comment is anchored to (if applicable).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    /// Commit SHA the comment is anchored to (if applicable).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_sha: Option<String>,
+    /// Current verification status of the review linkage.
+    pub verification_status: String,
+}
+
+impl ReviewLinkage {
+    /// Creates a new review linkage with the required fields.
+    #[must_use]
+    pub fn new(
+        review_comment_id: impl Into<String>,
+        thread_root_id: impl Into<String>,
+        reviewer: impl Into<String>,
+        verification_status: impl Into<String>,
+    ) -> Self {
+        Self {
+            review_comment_id: review_comment_id.into(),
+            thread_root_id: thread_root_id.into(),
+            reviewer: reviewer.into(),
+            file_path: None,
+            commit_sha: None,
+            verification_status: verification_status.into(),
+        }
+    }
+
+    /// Sets the file path anchor.
+    #[must_use]
+    pub fn with_file_path(mut self, path: impl Into<String>) -> Self {
+        self.file_path = Some(path.into());
+        self
+    }
+
+    /// Sets the commit SHA anchor.
+    #[must_use]
+    pub fn with_commit_sha(mut self, sha: impl Into<String>) -> Self {
+        self.commit_sha = Some(sha.into());
+        self
+    }
+}
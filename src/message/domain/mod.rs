@@ -3,44 +3,167 @@
 //! This module contains pure domain types with no infrastructure dependencies.
 //! All types are immutable after construction and serialisable via serde.
 
+mod activity;
+mod agent_context;
 mod agent_session;
 mod audit;
+mod blob;
+mod compaction;
 mod content;
 mod context_snapshot;
 mod conversation;
+mod conversation_comparison;
+mod conversation_list;
+mod custom_content;
+mod encryption;
+mod export;
+mod feedback;
+mod feedback_summary;
+mod fork;
 mod handoff;
+mod handoff_chain;
+mod handoff_context;
 mod ids;
+mod inbound;
+mod inbound_email;
+mod incremental_snapshot;
+mod label;
+mod lifecycle;
+mod load_shedding;
 mod message;
+mod message_query;
 mod metadata;
+mod periodic_snapshot;
+mod processing;
+mod redaction;
 mod role;
+mod rolling_summary;
+mod sensitive_data;
+mod sequence;
 mod slash_command;
+mod snapshot_retention;
+mod streaming;
+mod token_count;
+mod tool_call_pairing;
+mod transcript;
+mod transfer;
+mod turn;
 
 #[cfg(test)]
 mod agent_session_tests;
 #[cfg(test)]
+mod context_snapshot_tests;
+#[cfg(test)]
 mod handoff_tests;
 
+pub use activity::{ActivityBucket, ActivityBucketWidth, ActivityQuery, ConversationActivity};
+pub use agent_context::{AgentContextPolicy, ReasoningSharing};
 pub use agent_session::{
     AgentSession, AgentSessionState, HandoffSessionParams, ParseAgentSessionStateError,
 };
 pub use audit::{AgentResponseAudit, AgentResponseStatus, ToolCallAudit, ToolCallStatus};
-pub use content::{AttachmentPart, ContentPart, TextPart, ToolCallPart, ToolResultPart};
+pub use blob::{AttachmentBlob, ContentHash, ContentHashError};
+pub use compaction::{
+    CompactedHistory, CompactionPolicy, DEFAULT_COMPACTION_RETAIN_RECENT,
+    DEFAULT_COMPACTION_THRESHOLD,
+};
+pub use content::{
+    AttachmentPart, CitationPart, CitationSource, CitationSpan, ContentPart, ContentPartKind,
+    CustomPart, ImagePart, ImageThumbnail, ReasoningPart, RedactedPart, TextPart, ToolCallPart,
+    ToolResultPart,
+};
 pub use context_snapshot::{
     ContextWindowSnapshot, MessageSummary, ParseSnapshotTypeError, SequenceRange, SnapshotParams,
     SnapshotType,
 };
-pub use conversation::{Conversation, ConversationState};
+pub use conversation::{
+    Conversation, ConversationAccess, ConversationLifecycleError, ConversationState,
+};
+pub use conversation_comparison::{
+    BranchDivergence, BranchOutcome, ConversationComparison, MessageDifference,
+};
+pub use conversation_list::{
+    ConversationListFilter, ConversationListQuery, ConversationSortKey, ConversationSummary,
+};
+pub use custom_content::{
+    ContentTypeHandler, ContentTypeRegistry, CustomContent, CustomContentError,
+};
+pub use encryption::{
+    EncryptionKeyId, EncryptionKeyIdError, MAX_ENCRYPTION_KEY_ID_CHARS, SealedValue,
+};
+pub use export::{ExportFilter, ExportReport, RedactedMessages};
+pub use feedback::{
+    FeedbackCategory, FeedbackDomainError, FeedbackRating, FeedbackSentiment, FeedbackSubmission,
+    MAX_FEEDBACK_COMMENT_CHARS, MAX_FEEDBACK_STARS, MessageFeedback, ParseFeedbackValueError,
+    PersistedFeedbackData,
+};
+pub use feedback_summary::FeedbackSummary;
+pub use fork::{ConversationFork, ConversationForkRequest};
 pub use handoff::{
     HandoffMetadata, HandoffParams, HandoffStatus, ParseHandoffStatusError, ToolCallReference,
 };
-pub use ids::{AgentSessionId, ConversationId, HandoffId, MessageId, SequenceNumber, TurnId};
+pub use handoff_chain::{HandoffChain, HandoffChainLink};
+pub use handoff_context::HandoffContextPackage;
+pub use ids::{AgentSessionId, ConversationId, FeedbackId, HandoffId, MessageId, TurnId};
+pub use inbound::{INBOUND_ORIGIN_EXTENSION_KEY, InboundMessage, InboundOrigin, InboundPrincipal};
+pub use inbound_email::{
+    EMAIL_SOURCE, EmailEnvelope, INBOUND_EMAIL_EXTENSION_KEY, InboundEmail, OutboundEmail,
+};
+pub use incremental_snapshot::{ResolvedSnapshot, SnapshotChainError, SnapshotStorage};
+pub use label::{
+    ConversationLabel, ConversationLabelChange, ConversationLabelError, ConversationLabelEvent,
+    MAX_LABEL_KEY_CHARS, MAX_LABEL_VALUE_CHARS,
+};
+pub use lifecycle::{
+    ConversationLifecycleChange, ConversationLifecycleEvent, ConversationLifecyclePoint,
+};
+pub use load_shedding::{
+    BackgroundWorkload, DEFAULT_ELEVATED_QUEUE_DEPTH, DEFAULT_OVERLOADED_QUEUE_DEPTH,
+    DEFAULT_SAMPLE_ONE_IN, DEFAULT_TURN_LATENCY_SLO, LoadLevel, LoadSheddingMetrics,
+    LoadSheddingPolicy, LoadSignals, ShedMode, SheddingDecision, WorkloadSheddingMetrics,
+};
 pub use message::{Message, MessageBuilder, MessageBuilderError};
+pub use message_query::MessageQuery;
 pub use metadata::{
-    MessageMetadata, ReservedExtensionKeyError, ReviewLinkage, SlashCommandExpansion,
+    ExtensionKeyError, MessageMetadata, RESERVED_EXTENSION_NAMESPACES,
+    REVIEW_LINKAGE_EXTENSION_KEY, ReviewLinkage, SlashCommandExpansion, check_extension_key,
+    is_corbusier_extension_key,
+};
+pub use periodic_snapshot::{PeriodicSnapshotPolicy, SnapshotInterval, SnapshotMark};
+pub use processing::{
+    MessageProcessingStatus, ParseProcessingValueError, ProcessingDomainError, ProcessingStage,
+    StageState, StageStatus, StuckMessagesQuery,
+};
+pub use redaction::{
+    MAX_REDACTION_REASON_CHARS, MessageRedaction, RedactionDomainError, RedactionReason,
 };
 pub use role::{ParseRoleError, Role};
+pub use rolling_summary::{
+    DEFAULT_SUMMARY_RECOMPUTE_INTERVAL, PersistedRollingSummaryData, RollingSummary,
+};
+pub use sensitive_data::{
+    SENSITIVE_DATA_EXTENSION_KEY, SensitiveDataAction, SensitiveDataKind, SensitiveSpan,
+};
+pub use sequence::{SequenceNumber, SequenceNumberError};
 pub use slash_command::{
-    CommandParameterSpec, CommandParameterType, PlannedToolCall, SlashCommandDefinition,
-    SlashCommandError, SlashCommandExecution, SlashCommandInvocation,
-    SlashCommandRegistryUnavailableError, SlashCommandSchemaError, ToolCallTemplate,
+    ArgumentTemplate, ArgumentTemplateSegment, CommandParameterSpec, CommandParameterType,
+    CommandPermission, HELP_COMMAND, PersistedSlashCommandExecution, PlannedToolCall,
+    SlashCommandAuthorisationError, SlashCommandDefinition, SlashCommandError,
+    SlashCommandExecution, SlashCommandExecutionId, SlashCommandExecutionRecord,
+    SlashCommandInvocation, SlashCommandPack, SlashCommandPackFormat,
+    SlashCommandRegistryUnavailableError, SlashCommandSchemaError, TemplateExpression,
+    TemplateExpressionError, TokenSpan, ToolCallTemplate, VersionedSlashCommand, render_help_index,
+    resolve_command,
+};
+pub use snapshot_retention::SnapshotRetention;
+pub use streaming::{
+    ChunkAppend, MAX_STALLED_STREAMS, PartialMessage, STREAM_INTERRUPTED_EXTENSION_KEY,
+    StreamChunk, StreamingDomainError,
+};
+pub use token_count::{ConversationTokenUsage, MessageTokenCount};
+pub use tool_call_pairing::{ToolCallPairing, ToolCallPairingIssue};
+pub use transcript::{INBOUND_TRANSCRIPT_EXTENSION_KEY, TranscriptFormat, TranscriptMessage};
+pub use transfer::{
+    ConversationTransfer, ConversationTransferRefused, ConversationTransferRequest,
 };
+pub use turn::{ParseTurnStatusError, Turn, TurnStateError, TurnStatus};
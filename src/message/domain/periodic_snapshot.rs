@@ -0,0 +1,140 @@
+//! Policies for capturing periodic snapshots of long-running sessions.
+//!
+//! A [`SnapshotInterval`] says how often an active agent session's context
+//! window is captured: after a number of messages, after a number of
+//! minutes, or whichever comes first. A [`PeriodicSnapshotPolicy`] applies
+//! an interval to one conversation.
+
+use std::num::NonZeroU32;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{AgentSession, ConversationId, SequenceNumber};
+
+/// How often periodic snapshots are captured.
+///
+/// An interval with neither bound set never captures.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+///
+/// use chrono::{Duration, Utc};
+/// use corbusier::message::domain::{SequenceNumber, SnapshotInterval, SnapshotMark};
+///
+/// let interval = SnapshotInterval::new()
+///     .every_messages(NonZeroU32::new(20).expect("non-zero"))
+///     .every_minutes(NonZeroU32::new(15).expect("non-zero"));
+/// let last = SnapshotMark::new(SequenceNumber::new(10), Utc::now());
+///
+/// assert!(!interval.is_due(last, SnapshotMark::new(SequenceNumber::new(29), last.at)));
+/// assert!(interval.is_due(last, SnapshotMark::new(SequenceNumber::new(30), last.at)));
+/// assert!(interval.is_due(
+///     last,
+///     SnapshotMark::new(SequenceNumber::new(11), last.at + Duration::minutes(15)),
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SnapshotInterval {
+    /// Messages after the last snapshot that trigger the next one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every_messages: Option<NonZeroU32>,
+    /// Minutes of session activity after the last snapshot that trigger the
+    /// next one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every_minutes: Option<NonZeroU32>,
+}
+
+impl SnapshotInterval {
+    /// Creates an interval that never captures.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            every_messages: None,
+            every_minutes: None,
+        }
+    }
+
+    /// Captures once `messages` messages follow the last snapshot.
+    #[must_use]
+    pub const fn every_messages(mut self, messages: NonZeroU32) -> Self {
+        self.every_messages = Some(messages);
+        self
+    }
+
+    /// Captures once `minutes` minutes pass after the last snapshot.
+    #[must_use]
+    pub const fn every_minutes(mut self, minutes: NonZeroU32) -> Self {
+        self.every_minutes = Some(minutes);
+        self
+    }
+
+    /// Returns `true` if the interval ever captures.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.every_messages.is_some() || self.every_minutes.is_some()
+    }
+
+    /// Returns `true` if a snapshot is due at `current`, given the last one
+    /// was taken at `last`.
+    #[must_use]
+    pub fn is_due(&self, last: SnapshotMark, current: SnapshotMark) -> bool {
+        let by_messages = self.every_messages.is_some_and(|every| {
+            current
+                .sequence
+                .value()
+                .saturating_sub(last.sequence.value())
+                >= u64::from(every.get())
+        });
+        let by_time = self
+            .every_minutes
+            .is_some_and(|every| current.at - last.at >= Duration::minutes(i64::from(every.get())));
+        by_messages || by_time
+    }
+}
+
+/// The interval set on one conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeriodicSnapshotPolicy {
+    /// The conversation the policy applies to.
+    pub conversation_id: ConversationId,
+    /// How often the conversation's sessions are captured.
+    pub interval: SnapshotInterval,
+}
+
+impl PeriodicSnapshotPolicy {
+    /// Applies `interval` to a conversation.
+    #[must_use]
+    pub const fn new(conversation_id: ConversationId, interval: SnapshotInterval) -> Self {
+        Self {
+            conversation_id,
+            interval,
+        }
+    }
+}
+
+/// A point in a session: the last message seen, and when.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotMark {
+    /// Sequence number of the last message seen.
+    pub sequence: SequenceNumber,
+    /// When it was seen.
+    pub at: DateTime<Utc>,
+}
+
+impl SnapshotMark {
+    /// Creates a mark.
+    #[must_use]
+    pub const fn new(sequence: SequenceNumber, at: DateTime<Utc>) -> Self {
+        Self { sequence, at }
+    }
+
+    /// Marks the start of `session`, for sessions with no periodic snapshot
+    /// yet.
+    #[must_use]
+    pub const fn session_start(session: &AgentSession) -> Self {
+        Self::new(session.start_sequence, session.started_at)
+    }
+}
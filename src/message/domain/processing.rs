@@ -0,0 +1,331 @@
+//! Processing pipeline status for stored messages.
+//!
+//! After a message is stored, asynchronous stages validate, moderate, embed,
+//! and project it, in that order. Each stage records a [`StageStatus`] when it
+//! picks the message up and updates it when it finishes, so a message that
+//! stalls or fails at a stage can be found and sent through it again.
+
+use super::{ConversationId, Message, MessageId};
+use crate::pagination::{Cursor, Limit, PageRequest};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// Errors returned by processing stage transitions.
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+pub enum ProcessingDomainError {
+    /// The stage is already processing the message.
+    #[error("stage {0} is already in progress")]
+    StageInProgress(ProcessingStage),
+    /// The stage is not processing the message, so it cannot finish.
+    #[error("stage {0} is not in progress")]
+    StageNotInProgress(ProcessingStage),
+}
+
+/// Error returned while parsing processing values from persistence.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("unknown processing value: {0}")]
+pub struct ParseProcessingValueError(pub String);
+
+/// A post-ingestion processing stage, in pipeline order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessingStage {
+    /// Asynchronous content validation.
+    Validation,
+    /// Content moderation.
+    Moderation,
+    /// Embedding generation for retrieval.
+    Embedding,
+    /// Projection into read models.
+    Projection,
+}
+
+impl ProcessingStage {
+    /// Every stage, in pipeline order.
+    pub const ALL: [Self; 4] = [
+        Self::Validation,
+        Self::Moderation,
+        Self::Embedding,
+        Self::Projection,
+    ];
+
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Validation => "validation",
+            Self::Moderation => "moderation",
+            Self::Embedding => "embedding",
+            Self::Projection => "projection",
+        }
+    }
+
+    /// Returns the stages that run after this one.
+    pub fn later_stages(self) -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter().filter(move |stage| *stage > self)
+    }
+}
+
+impl fmt::Display for ProcessingStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<&str> for ProcessingStage {
+    type Error = ParseProcessingValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|stage| stage.as_str() == value)
+            .ok_or_else(|| ParseProcessingValueError(value.to_owned()))
+    }
+}
+
+/// Where a stage is with a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StageState {
+    /// The stage has picked the message up and not yet finished.
+    InProgress,
+    /// The stage finished processing the message.
+    Succeeded,
+    /// The stage gave up on the message.
+    Failed,
+}
+
+impl StageState {
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::InProgress => "in_progress",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl TryFrom<&str> for StageState {
+    type Error = ParseProcessingValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "in_progress" => Ok(Self::InProgress),
+            "succeeded" => Ok(Self::Succeeded),
+            "failed" => Ok(Self::Failed),
+            _ => Err(ParseProcessingValueError(value.to_owned())),
+        }
+    }
+}
+
+/// One stage's progress on one message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageStatus {
+    /// The processed message.
+    pub message_id: MessageId,
+    /// Conversation containing the message.
+    pub conversation_id: ConversationId,
+    /// The stage.
+    pub stage: ProcessingStage,
+    /// Where the stage is with the message.
+    pub state: StageState,
+    /// How many times the stage has picked the message up.
+    pub attempts: u32,
+    /// Failure reason from the latest failed attempt.
+    pub last_error: Option<String>,
+    /// When the status last changed.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl StageStatus {
+    /// Records `stage` picking up `message` for the first time.
+    #[must_use]
+    pub fn started(
+        message: &Message,
+        stage: ProcessingStage,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        Self {
+            message_id: message.id(),
+            conversation_id: message.conversation_id(),
+            stage,
+            state: StageState::InProgress,
+            attempts: 1,
+            last_error: None,
+            updated_at: clock.utc(),
+        }
+    }
+
+    /// Puts the stage back in progress for another attempt, clearing the
+    /// previous failure.
+    pub fn retry(&mut self, clock: &(impl Clock + ?Sized)) {
+        self.state = StageState::InProgress;
+        self.attempts = self.attempts.saturating_add(1);
+        self.last_error = None;
+        self.updated_at = clock.utc();
+    }
+
+    /// Marks the in-progress attempt as succeeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingDomainError::StageNotInProgress`] when no
+    /// attempt is in progress.
+    pub fn succeed(&mut self, clock: &(impl Clock + ?Sized)) -> Result<(), ProcessingDomainError> {
+        self.finish(StageState::Succeeded, None, clock)
+    }
+
+    /// Marks the in-progress attempt as failed with `reason`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingDomainError::StageNotInProgress`] when no
+    /// attempt is in progress.
+    pub fn fail(
+        &mut self,
+        reason: impl Into<String>,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<(), ProcessingDomainError> {
+        self.finish(StageState::Failed, Some(reason.into()), clock)
+    }
+
+    /// Returns whether the stage has not succeeded and has not changed
+    /// since `cutoff`.
+    #[must_use]
+    pub fn is_stuck(&self, cutoff: DateTime<Utc>) -> bool {
+        self.state != StageState::Succeeded && self.updated_at <= cutoff
+    }
+
+    fn finish(
+        &mut self,
+        state: StageState,
+        last_error: Option<String>,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<(), ProcessingDomainError> {
+        if self.state != StageState::InProgress {
+            return Err(ProcessingDomainError::StageNotInProgress(self.stage));
+        }
+        self.state = state;
+        self.last_error = last_error;
+        self.updated_at = clock.utc();
+        Ok(())
+    }
+}
+
+/// Pipeline progress of one message across every stage.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     ContentPart, ConversationId, Message, MessageProcessingStatus, ProcessingStage, Role,
+///     SequenceNumber, StageStatus, TextPart,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let message = Message::new(
+///     ConversationId::new(),
+///     Role::User,
+///     vec![ContentPart::Text(TextPart::new("Deploy?"))],
+///     SequenceNumber::new(1),
+///     &DefaultClock,
+/// )
+/// .expect("valid message");
+/// let mut validation = StageStatus::started(&message, ProcessingStage::Validation, &DefaultClock);
+/// validation.succeed(&DefaultClock).expect("in progress");
+///
+/// let status = MessageProcessingStatus::from_stages(message.id(), vec![validation]);
+///
+/// assert_eq!(status.current_stage(), Some(ProcessingStage::Moderation));
+/// assert!(!status.is_complete());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageProcessingStatus {
+    /// The processed message.
+    pub message_id: MessageId,
+    /// Recorded stage statuses, in pipeline order. Stages that have not
+    /// picked the message up yet are absent.
+    pub stages: Vec<StageStatus>,
+}
+
+impl MessageProcessingStatus {
+    /// Builds the status of `message_id` from its recorded stages.
+    #[must_use]
+    pub fn from_stages(message_id: MessageId, mut stages: Vec<StageStatus>) -> Self {
+        stages.retain(|status| status.message_id == message_id);
+        stages.sort_by_key(|status| status.stage);
+        Self { message_id, stages }
+    }
+
+    /// Returns the recorded status of `stage`.
+    #[must_use]
+    pub fn stage(&self, stage: ProcessingStage) -> Option<&StageStatus> {
+        self.stages.iter().find(|status| status.stage == stage)
+    }
+
+    /// Returns the earliest stage that has not succeeded, or `None` once
+    /// every stage has.
+    #[must_use]
+    pub fn current_stage(&self) -> Option<ProcessingStage> {
+        ProcessingStage::ALL.into_iter().find(|stage| {
+            self.stage(*stage)
+                .is_none_or(|status| status.state != StageState::Succeeded)
+        })
+    }
+
+    /// Returns whether every stage has succeeded.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.current_stage().is_none()
+    }
+}
+
+/// Query for messages stuck at one stage.
+///
+/// A message is stuck at a stage when the stage's latest attempt failed, or
+/// has stayed in progress, since before `updated_before`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StuckMessagesQuery {
+    /// Stage to inspect.
+    pub stage: ProcessingStage,
+    /// Only statuses unchanged since this instant are stuck.
+    pub updated_before: DateTime<Utc>,
+    /// Page size and the cursor taken from the previous page.
+    pub page: PageRequest,
+}
+
+impl StuckMessagesQuery {
+    /// Creates a query for the first [`Limit::MAX`] stuck records.
+    #[must_use]
+    pub const fn new(stage: ProcessingStage, updated_before: DateTime<Utc>) -> Self {
+        Self {
+            stage,
+            updated_before,
+            page: PageRequest::new(Limit::MAX),
+        }
+    }
+
+    /// Replaces the page request.
+    #[must_use]
+    pub const fn with_page(mut self, page: PageRequest) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Returns the keyset position of `status` in the listing.
+    #[must_use]
+    pub const fn position(status: &StageStatus) -> Cursor {
+        Cursor::at_timestamp(status.updated_at, status.message_id.into_inner())
+    }
+
+    /// Returns whether `status` matches the query.
+    #[must_use]
+    pub fn matches(&self, status: &StageStatus) -> bool {
+        status.stage == self.stage && status.is_stuck(self.updated_before)
+    }
+}
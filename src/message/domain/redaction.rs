@@ -0,0 +1,192 @@
+//! Redaction of message content.
+//!
+//! Redacting a message replaces its content with a single
+//! [`ContentPart::Redacted`] placeholder. The message keeps its ID, role,
+//! sequence number, and audit metadata, so conversation history stays
+//! contiguous and the audit trail still refers to it. Repositories record
+//! each redaction as a tombstone alongside the message.
+
+use super::{ContentPart, MessageId, RedactedPart};
+use crate::context::UserId;
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// Maximum length of a redaction reason, in characters.
+pub const MAX_REDACTION_REASON_CHARS: usize = 1_000;
+
+/// Errors returned while constructing a redaction.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum RedactionDomainError {
+    /// A redaction must say why it was made.
+    #[error("redaction reason must not be empty")]
+    EmptyReason,
+    /// The reason exceeds [`MAX_REDACTION_REASON_CHARS`].
+    #[error("redaction reason has {actual} characters, the limit is {max}")]
+    ReasonTooLong {
+        /// Maximum permitted characters.
+        max: usize,
+        /// Characters supplied.
+        actual: usize,
+    },
+}
+
+/// Why a message was redacted, trimmed and non-empty.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::RedactionReason;
+///
+/// let reason = RedactionReason::new("  pasted API key ").expect("valid reason");
+/// assert_eq!(reason.as_str(), "pasted API key");
+/// assert!(RedactionReason::new(" ").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RedactionReason(String);
+
+impl RedactionReason {
+    /// Creates a reason from free text.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RedactionDomainError::EmptyReason`] when the trimmed text is
+    /// empty, or [`RedactionDomainError::ReasonTooLong`] when it exceeds
+    /// [`MAX_REDACTION_REASON_CHARS`].
+    pub fn new(reason: impl Into<String>) -> Result<Self, RedactionDomainError> {
+        let raw = reason.into();
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            return Err(RedactionDomainError::EmptyReason);
+        }
+        let actual = trimmed.chars().count();
+        if actual > MAX_REDACTION_REASON_CHARS {
+            return Err(RedactionDomainError::ReasonTooLong {
+                max: MAX_REDACTION_REASON_CHARS,
+                actual,
+            });
+        }
+        Ok(Self(trimmed.to_owned()))
+    }
+
+    /// Returns the reason text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for RedactionReason {
+    type Error = RedactionDomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<RedactionReason> for String {
+    fn from(value: RedactionReason) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for RedactionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A request to scrub one message's content.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::UserId;
+/// use corbusier::message::domain::{MessageId, MessageRedaction, RedactionReason};
+/// use mockable::DefaultClock;
+///
+/// let reason = RedactionReason::new("pasted API key").expect("valid reason");
+/// let redaction = MessageRedaction::new(MessageId::new(), reason, UserId::new(), &DefaultClock);
+/// assert_eq!(redaction.reason().as_str(), "pasted API key");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageRedaction {
+    message_id: MessageId,
+    reason: RedactionReason,
+    redacted_by: UserId,
+    redacted_at: DateTime<Utc>,
+}
+
+impl MessageRedaction {
+    /// Creates a redaction of `message_id` by `redacted_by`, timestamped now.
+    #[must_use]
+    pub fn new(
+        message_id: MessageId,
+        reason: RedactionReason,
+        redacted_by: UserId,
+        clock: &impl Clock,
+    ) -> Self {
+        Self {
+            message_id,
+            reason,
+            redacted_by,
+            redacted_at: clock.utc(),
+        }
+    }
+
+    /// Creates a redaction of `message_id` by `redacted_by`, made at
+    /// `redacted_at`.
+    ///
+    /// Use this when one decision redacts many messages, so that they share
+    /// a timestamp.
+    #[must_use]
+    pub const fn made_at(
+        message_id: MessageId,
+        reason: RedactionReason,
+        redacted_by: UserId,
+        redacted_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            message_id,
+            reason,
+            redacted_by,
+            redacted_at,
+        }
+    }
+
+    /// Returns the redacted message's identifier.
+    #[must_use]
+    pub const fn message_id(&self) -> MessageId {
+        self.message_id
+    }
+
+    /// Returns why the message was redacted.
+    #[must_use]
+    pub const fn reason(&self) -> &RedactionReason {
+        &self.reason
+    }
+
+    /// Returns who redacted the message.
+    #[must_use]
+    pub const fn redacted_by(&self) -> UserId {
+        self.redacted_by
+    }
+
+    /// Returns when the message was redacted.
+    #[must_use]
+    pub const fn redacted_at(&self) -> DateTime<Utc> {
+        self.redacted_at
+    }
+
+    /// Returns the placeholder that replaces the message's content.
+    #[must_use]
+    pub fn placeholder(&self) -> ContentPart {
+        ContentPart::Redacted(RedactedPart {
+            reason: self.reason.as_str().to_owned(),
+            redacted_at: self.redacted_at,
+        })
+    }
+}
@@ -49,6 +49,9 @@ impl fmt::Display for Role {
 }
 
 impl Role {
+    /// Every role.
+    pub const ALL: [Self; 4] = [Self::User, Self::Assistant, Self::Tool, Self::System];
+
     /// Returns the string representation of this role.
     ///
     /// This matches the serialized form used in database storage.
@@ -0,0 +1,157 @@
+//! Rolling conversation summaries maintained as messages are stored.
+//!
+//! A [`RollingSummary`] records the last message it covers, so each newly
+//! stored message can be folded into it without re-reading the whole
+//! conversation. Summaries extended many times drift from what a fresh
+//! summary would say, so each one also counts its incremental updates and
+//! is rebuilt from the full conversation once the count reaches the
+//! recompute interval.
+
+use super::{ConversationId, SequenceNumber};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+
+/// Incremental updates a summary accepts before it is recomputed, unless
+/// configured otherwise.
+pub const DEFAULT_SUMMARY_RECOMPUTE_INTERVAL: u32 = 20;
+
+/// A conversation summary kept up to date as messages arrive.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{ConversationId, RollingSummary, SequenceNumber};
+/// use mockable::DefaultClock;
+///
+/// let clock = DefaultClock;
+/// let mut summary = RollingSummary::recomputed(
+///     ConversationId::new(),
+///     "The user asked for a fix.",
+///     SequenceNumber::new(1),
+///     &clock,
+/// );
+/// summary.extend("The user asked for a fix; it landed.", SequenceNumber::new(2), &clock);
+///
+/// assert_eq!(summary.covered_through(), SequenceNumber::new(2));
+/// assert_eq!(summary.incremental_updates(), 1);
+/// assert!(!summary.is_due_for_recompute(2));
+/// assert!(summary.is_due_for_recompute(1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollingSummary {
+    conversation_id: ConversationId,
+    text: String,
+    covered_through: SequenceNumber,
+    incremental_updates: u32,
+    recomputed_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Persisted rolling summary fields used for reconstruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersistedRollingSummaryData {
+    /// Summarised conversation.
+    pub conversation_id: ConversationId,
+    /// Summary text.
+    pub text: String,
+    /// Sequence number of the last message the summary covers.
+    pub covered_through: SequenceNumber,
+    /// Incremental updates since the last full recompute.
+    pub incremental_updates: u32,
+    /// When the summary was last rebuilt from the full conversation.
+    pub recomputed_at: DateTime<Utc>,
+    /// When the summary last changed.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RollingSummary {
+    /// Creates a summary rebuilt from every message up to `covered_through`.
+    #[must_use]
+    pub fn recomputed(
+        conversation_id: ConversationId,
+        text: impl Into<String>,
+        covered_through: SequenceNumber,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        let now = clock.utc();
+        Self {
+            conversation_id,
+            text: text.into(),
+            covered_through,
+            incremental_updates: 0,
+            recomputed_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Reconstructs a summary from persisted storage.
+    #[must_use]
+    pub fn from_persisted(data: PersistedRollingSummaryData) -> Self {
+        Self {
+            conversation_id: data.conversation_id,
+            text: data.text,
+            covered_through: data.covered_through,
+            incremental_updates: data.incremental_updates,
+            recomputed_at: data.recomputed_at,
+            updated_at: data.updated_at,
+        }
+    }
+
+    /// Replaces the text with one that also covers messages up to
+    /// `covered_through`, counting an incremental update.
+    pub fn extend(
+        &mut self,
+        text: impl Into<String>,
+        covered_through: SequenceNumber,
+        clock: &(impl Clock + ?Sized),
+    ) {
+        self.text = text.into();
+        self.covered_through = covered_through;
+        self.incremental_updates = self.incremental_updates.saturating_add(1);
+        self.updated_at = clock.utc();
+    }
+
+    /// Returns whether the summary has been extended `interval` or more
+    /// times since it was last recomputed.
+    #[must_use]
+    pub const fn is_due_for_recompute(&self, interval: u32) -> bool {
+        self.incremental_updates >= interval
+    }
+
+    /// Returns the summarised conversation.
+    #[must_use]
+    pub const fn conversation_id(&self) -> ConversationId {
+        self.conversation_id
+    }
+
+    /// Returns the summary text.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the sequence number of the last message the summary covers.
+    #[must_use]
+    pub const fn covered_through(&self) -> SequenceNumber {
+        self.covered_through
+    }
+
+    /// Returns the incremental updates since the last full recompute.
+    #[must_use]
+    pub const fn incremental_updates(&self) -> u32 {
+        self.incremental_updates
+    }
+
+    /// Returns when the summary was last rebuilt from the full conversation.
+    #[must_use]
+    pub const fn recomputed_at(&self) -> DateTime<Utc> {
+        self.recomputed_at
+    }
+
+    /// Returns when the summary last changed.
+    #[must_use]
+    pub const fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
@@ -0,0 +1,109 @@
+//! Sensitive data found in message text.
+//!
+//! A [`ContentScanner`](crate::message::ports::ContentScanner) reports each
+//! email address, phone number or API key it finds in a message as a
+//! [`SensitiveSpan`]: the kind of data, the part holding it, and its
+//! character range. Screening then rejects the message, masks the span, or
+//! records it in the message's metadata under
+//! [`SENSITIVE_DATA_EXTENSION_KEY`], as its [`SensitiveDataAction`] says.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Extension key under which annotated [`SensitiveSpan`]s are recorded.
+pub const SENSITIVE_DATA_EXTENSION_KEY: &str = "corbusier.sensitive_data.v1";
+
+/// A kind of sensitive data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SensitiveDataKind {
+    /// An email address.
+    EmailAddress,
+    /// A telephone number.
+    PhoneNumber,
+    /// A credential for an API, such as an access key or token.
+    ApiKey,
+}
+
+impl SensitiveDataKind {
+    /// Every kind of sensitive data.
+    pub const ALL: [Self; 3] = [Self::EmailAddress, Self::PhoneNumber, Self::ApiKey];
+
+    /// Returns the serialised name of this kind.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::EmailAddress => "email_address",
+            Self::PhoneNumber => "phone_number",
+            Self::ApiKey => "api_key",
+        }
+    }
+
+    /// Returns the text that replaces masked data of this kind.
+    #[must_use]
+    pub const fn placeholder(self) -> &'static str {
+        match self {
+            Self::EmailAddress => "[email address]",
+            Self::PhoneNumber => "[phone number]",
+            Self::ApiKey => "[api key]",
+        }
+    }
+}
+
+impl fmt::Display for SensitiveDataKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Sensitive data found in one content part.
+///
+/// `start` and `end` count characters, as [`CitationSpan`](super::CitationSpan)
+/// does, and the range is half-open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SensitiveSpan {
+    /// What was found.
+    pub kind: SensitiveDataKind,
+    /// Index of the content part holding the data.
+    pub part_index: usize,
+    /// First character of the data.
+    pub start: usize,
+    /// Character just past the data.
+    pub end: usize,
+}
+
+impl SensitiveSpan {
+    /// Creates a span.
+    #[must_use]
+    pub const fn new(kind: SensitiveDataKind, part_index: usize, start: usize, end: usize) -> Self {
+        Self {
+            kind,
+            part_index,
+            start,
+            end,
+        }
+    }
+}
+
+impl fmt::Display for SensitiveSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} in part {} at {}..{}",
+            self.kind, self.part_index, self.start, self.end
+        )
+    }
+}
+
+/// What screening does with a kind of sensitive data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SensitiveDataAction {
+    /// Refuse the message.
+    #[default]
+    Reject,
+    /// Replace the data with the placeholder of its kind.
+    Redact,
+    /// Keep the data and record where it is in the message's metadata.
+    Annotate,
+}
@@ -0,0 +1,111 @@
+//! Message sequence numbers.
+//!
+//! Sequence numbers order the messages of a conversation and start at 1.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::num::NonZeroU64;
+use thiserror::Error;
+
+/// Error returned when constructing a [`SequenceNumber`] from zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("sequence numbers start at 1, so 0 is not a valid sequence number")]
+pub struct SequenceNumberError;
+
+/// Sequence number for ordering messages within a conversation.
+///
+/// Sequence numbers are monotonically increasing within a conversation,
+/// ensuring deterministic message ordering. They start at 1; zero is never
+/// a valid sequence number, so snapshots and handoffs can rely on every
+/// message following the start of its conversation.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{SequenceNumber, SequenceNumberError};
+///
+/// let seq = SequenceNumber::try_new(1).expect("non-zero sequence number");
+/// assert_eq!(seq, SequenceNumber::FIRST);
+/// assert_eq!(seq.next().value(), 2);
+/// assert_eq!(seq.distance(SequenceNumber::new(5)), 4);
+/// assert_eq!(SequenceNumber::try_new(0), Err(SequenceNumberError));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u64", into = "u64")]
+pub struct SequenceNumber(NonZeroU64);
+
+impl SequenceNumber {
+    /// The sequence number of the first message in a conversation.
+    pub const FIRST: Self = Self(NonZeroU64::MIN);
+
+    /// Creates a sequence number from a value.
+    ///
+    /// Use [`SequenceNumber::try_new`] for values read from storage or
+    /// received from callers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is zero.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        match Self::try_new(value) {
+            Ok(sequence) => sequence,
+            Err(_) => panic!("sequence numbers start at 1, so 0 is not a valid sequence number"),
+        }
+    }
+
+    /// Creates a sequence number, rejecting zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SequenceNumberError`] if `value` is zero.
+    pub const fn try_new(value: u64) -> Result<Self, SequenceNumberError> {
+        match NonZeroU64::new(value) {
+            Some(non_zero) => Ok(Self(non_zero)),
+            None => Err(SequenceNumberError),
+        }
+    }
+
+    /// Returns the underlying sequence value.
+    #[must_use]
+    pub const fn value(&self) -> u64 {
+        self.0.get()
+    }
+
+    /// Returns the next sequence number.
+    ///
+    /// Uses saturating arithmetic, so at `u64::MAX` it will not overflow
+    /// but return `u64::MAX`. This is practically unreachable in normal use
+    /// (would require 2^64 messages).
+    #[must_use]
+    pub const fn next(&self) -> Self {
+        Self(self.0.saturating_add(1))
+    }
+
+    /// Returns how many positions apart this and `other` are, in either
+    /// direction.
+    #[must_use]
+    pub const fn distance(&self, other: Self) -> u64 {
+        self.0.get().abs_diff(other.0.get())
+    }
+}
+
+impl TryFrom<u64> for SequenceNumber {
+    type Error = SequenceNumberError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl From<SequenceNumber> for u64 {
+    fn from(sequence: SequenceNumber) -> Self {
+        sequence.value()
+    }
+}
+
+impl fmt::Display for SequenceNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
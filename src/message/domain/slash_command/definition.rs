@@ -1,73 +1,23 @@
 //! Slash-command definition and parameter validation.
 
 use serde::{Deserialize, Serialize};
-use serde_json::{Number, Value};
+use serde_json::Value;
 use std::collections::{BTreeMap, HashSet};
 
-use super::{SlashCommandError, SlashCommandSchemaError};
-
-/// Parameter type for slash-command validation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum CommandParameterType {
-    /// Free-form string value.
-    String,
-    /// Numeric value.
-    Number,
-    /// Boolean value (`true` or `false`).
-    Boolean,
-    /// Enumeration with allowed options.
-    Select,
-}
-
-/// Parameter specification for a slash command.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct CommandParameterSpec {
-    /// Parameter name.
-    pub name: String,
-    /// Parameter type.
-    pub parameter_type: CommandParameterType,
-    /// Whether the parameter is required.
-    pub required: bool,
-    /// Allowed options for `select` parameters.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub options: Vec<String>,
-}
-
-impl CommandParameterSpec {
-    /// Creates a parameter specification.
-    #[must_use]
-    pub fn new(
-        name: impl Into<String>,
-        parameter_type: CommandParameterType,
-        required: bool,
-    ) -> Self {
-        Self {
-            name: name.into().to_ascii_lowercase(),
-            parameter_type,
-            required,
-            options: Vec::new(),
-        }
-    }
-
-    /// Adds allowed options for `select` parameters.
-    #[must_use]
-    pub fn with_options(mut self, options: impl IntoIterator<Item = impl Into<String>>) -> Self {
-        self.options = options
-            .into_iter()
-            .map(Into::into)
-            .map(|option: String| option.to_ascii_lowercase())
-            .collect();
-        self
-    }
-}
+use super::expression::validate_tool_calls;
+use super::parser::is_valid_identifier;
+use super::{
+    CommandParameterSpec, CommandPermission, HELP_COMMAND, SlashCommandError,
+    SlashCommandInvocation, SlashCommandSchemaError, TokenSpan,
+};
 
 /// Tool call template associated with a command.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ToolCallTemplate {
     /// Target tool name.
     pub tool_name: String,
-    /// `minijinja` template rendering tool arguments as JSON.
+    /// `minijinja` template rendering tool arguments as JSON, with `${...}`
+    /// expressions as described on [`ArgumentTemplate`](super::ArgumentTemplate).
     pub arguments_template: String,
 }
 
@@ -85,10 +35,19 @@ impl ToolCallTemplate {
 /// Slash-command definition used by registries.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SlashCommandDefinition {
-    /// Command name without the leading slash.
+    /// Command name without the leading slash or namespace.
     pub command: String,
+    /// Namespace qualifying the command, as in `/gh:issue`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Alternative names resolving to this command within its namespace.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
     /// Human-readable description.
     pub description: String,
+    /// Longer usage text shown by help, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
     /// Expansion template rendered for metadata and audit.
     pub expansion_template: String,
     /// Parameter definitions.
@@ -97,6 +56,9 @@ pub struct SlashCommandDefinition {
     /// Deterministic tool call templates.
     #[serde(default)]
     pub tool_calls: Vec<ToolCallTemplate>,
+    /// Roles and scopes a caller must all hold to invoke the command.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<CommandPermission>,
 }
 
 impl SlashCommandDefinition {
@@ -109,13 +71,60 @@ impl SlashCommandDefinition {
     ) -> Self {
         Self {
             command: command.into().to_ascii_lowercase(),
+            namespace: None,
+            aliases: Vec::new(),
             description: description.into(),
+            help: None,
             expansion_template: expansion_template.into(),
             parameters: Vec::new(),
             tool_calls: Vec::new(),
+            permissions: Vec::new(),
         }
     }
 
+    /// Places the command in a namespace.
+    #[must_use]
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Adds an alternative name for the command.
+    #[must_use]
+    pub fn with_alias(mut self, alias: impl Into<String>) -> Self {
+        self.aliases.push(alias.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Returns the name that invokes the command without ambiguity:
+    /// `namespace:command`, or the bare command name outside a namespace.
+    #[must_use]
+    pub fn qualified_name(&self) -> String {
+        self.namespace.as_ref().map_or_else(
+            || self.command.to_ascii_lowercase(),
+            |namespace| format!("{namespace}:{}", self.command).to_ascii_lowercase(),
+        )
+    }
+
+    /// Lower-cases the command name, namespace, and aliases, as parsed from
+    /// invocations.
+    pub(crate) fn normalise_names(&mut self) {
+        self.command = self.command.to_ascii_lowercase();
+        if let Some(namespace) = &mut self.namespace {
+            namespace.make_ascii_lowercase();
+        }
+        for alias in &mut self.aliases {
+            alias.make_ascii_lowercase();
+        }
+    }
+
+    /// Sets the usage text shown by help.
+    #[must_use]
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
     /// Adds a parameter specification.
     #[must_use]
     pub fn with_parameter(mut self, parameter: CommandParameterSpec) -> Self {
@@ -130,18 +139,74 @@ impl SlashCommandDefinition {
         self
     }
 
+    /// Requires callers to hold `permission`.
+    #[must_use]
+    pub fn with_permission(mut self, permission: CommandPermission) -> Self {
+        self.permissions.push(permission);
+        self
+    }
+
+    /// Returns whether the expansion template or any tool call template
+    /// contains `text`.
+    #[must_use]
+    pub fn template_mentions(&self, text: &str) -> bool {
+        self.expansion_template.contains(text)
+            || self
+                .tool_calls
+                .iter()
+                .any(|tool_call| tool_call.arguments_template.contains(text))
+    }
+
     /// Validates the command definition schema.
     ///
     /// # Errors
     ///
-    /// Returns [`SlashCommandSchemaError`] when parameter definitions are
-    /// invalid.
+    /// Returns [`SlashCommandSchemaError`] when the command name, namespace,
+    /// or an alias cannot be invoked, a name is reserved, or parameter
+    /// definitions are invalid.
     pub(crate) fn validate_schema(&self) -> Result<(), SlashCommandSchemaError> {
-        validate_parameter_definitions(&self.command, &self.parameters)
+        let invalid = |reason: &str| SlashCommandSchemaError::InvalidCommandDefinition {
+            command: self.qualified_name(),
+            reason: reason.to_owned(),
+        };
+        let names = std::iter::once(&self.command)
+            .chain(&self.namespace)
+            .chain(&self.aliases);
+        for name in names {
+            if name.is_empty() || !is_valid_identifier(name) {
+                return Err(invalid(
+                    "command names, namespaces, and aliases may only contain letters, digits, '-' and '_'",
+                ));
+            }
+        }
+        let mut seen = HashSet::from([self.command.to_ascii_lowercase()]);
+        if !self
+            .aliases
+            .iter()
+            .all(|alias| seen.insert(alias.to_ascii_lowercase()))
+        {
+            return Err(invalid(
+                "aliases must differ from each other and the command name",
+            ));
+        }
+        if self.namespace.is_none() && seen.contains(HELP_COMMAND) {
+            return Err(invalid("'/help' is reserved for generated command help"));
+        }
+        if !self.permissions.iter().all(CommandPermission::is_valid) {
+            return Err(invalid(
+                "permission names must be non-empty and free of whitespace",
+            ));
+        }
+        validate_parameter_definitions(&self.command, &self.parameters)?;
+        validate_tool_calls(&self.qualified_name(), &self.parameters, &self.tool_calls)
     }
 
     /// Validates and converts raw invocation parameters.
     ///
+    /// Omitted optional parameters take their default, or `null` without
+    /// one. Errors carry no token spans; use
+    /// [`validate_invocation`](Self::validate_invocation) for parsed input.
+    ///
     /// # Errors
     ///
     /// Returns [`SlashCommandError`] when parameters are missing, unknown, or
@@ -150,36 +215,64 @@ impl SlashCommandDefinition {
         &self,
         provided: &BTreeMap<String, String>,
     ) -> Result<BTreeMap<String, Value>, SlashCommandError> {
-        for key in provided.keys() {
-            if !self
+        self.coerce_parameters(provided, &BTreeMap::new())
+    }
+
+    /// Validates and converts the parameters of a parsed invocation,
+    /// reporting the span of the offending token on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandError`] when the invocation has positional
+    /// arguments, or when parameters are missing, unknown, or invalid for the
+    /// declared schema.
+    pub fn validate_invocation(
+        &self,
+        invocation: &SlashCommandInvocation,
+    ) -> Result<BTreeMap<String, Value>, SlashCommandError> {
+        if let Some(argument) = invocation.arguments().first() {
+            return Err(SlashCommandError::UnexpectedArgument {
+                command: self.qualified_name(),
+                argument: argument.clone(),
+                span: invocation.argument_span(0),
+            });
+        }
+        self.coerce_parameters(invocation.parameters(), invocation.spans())
+    }
+
+    fn coerce_parameters(
+        &self,
+        provided: &BTreeMap<String, String>,
+        spans: &BTreeMap<String, TokenSpan>,
+    ) -> Result<BTreeMap<String, Value>, SlashCommandError> {
+        if let Some(unknown) = provided.keys().find(|key| {
+            !self
                 .parameters
                 .iter()
-                .any(|parameter| parameter.name == *key)
-            {
-                return Err(SlashCommandError::UnknownParameter {
-                    command: self.command.clone(),
-                    parameter: key.clone(),
-                });
-            }
+                .any(|parameter| parameter.name == **key)
+        }) {
+            return Err(SlashCommandError::UnknownParameter {
+                command: self.command.clone(),
+                parameter: unknown.clone(),
+                span: spans.get(unknown).copied(),
+            });
         }
 
         let mut typed = BTreeMap::new();
         for parameter in &self.parameters {
-            match provided.get(&parameter.name) {
-                Some(raw) => {
-                    let value = parse_parameter_value(&self.command, parameter, raw)?;
-                    typed.insert(parameter.name.clone(), value);
-                }
-                None if parameter.required => {
+            let span = spans.get(&parameter.name).copied();
+            let value = match (provided.get(&parameter.name), &parameter.default) {
+                (Some(raw), _) => parameter.coerce(&self.command, raw, span)?,
+                (None, _) if parameter.required => {
                     return Err(SlashCommandError::MissingRequiredParameter {
                         command: self.command.clone(),
                         parameter: parameter.name.clone(),
                     });
                 }
-                None => {
-                    typed.insert(parameter.name.clone(), Value::Null);
-                }
-            }
+                (None, Some(default)) => parameter.coerce(&self.command, default, None)?,
+                (None, None) => Value::Null,
+            };
+            typed.insert(parameter.name.clone(), value);
         }
 
         Ok(typed)
@@ -199,103 +292,7 @@ fn validate_parameter_definitions(
                 reason: "duplicate parameter definition".to_owned(),
             });
         }
-        if matches!(parameter.parameter_type, CommandParameterType::Select)
-            && parameter.options.is_empty()
-        {
-            return Err(SlashCommandSchemaError::InvalidParameterDefinition {
-                command: command.to_owned(),
-                parameter: parameter.name.clone(),
-                reason: "select parameters must provide options".to_owned(),
-            });
-        }
-        if !matches!(parameter.parameter_type, CommandParameterType::Select)
-            && !parameter.options.is_empty()
-        {
-            return Err(SlashCommandSchemaError::InvalidParameterDefinition {
-                command: command.to_owned(),
-                parameter: parameter.name.clone(),
-                reason: "only Select parameters may provide options".to_owned(),
-            });
-        }
+        parameter.validate(command)?;
     }
     Ok(())
 }
-
-fn parse_parameter_value(
-    command: &str,
-    parameter: &CommandParameterSpec,
-    raw: &str,
-) -> Result<Value, SlashCommandError> {
-    match parameter.parameter_type {
-        CommandParameterType::String => Ok(Value::String(raw.to_owned())),
-        CommandParameterType::Number => parse_number_parameter(command, parameter, raw),
-        CommandParameterType::Boolean => parse_boolean_parameter(command, parameter, raw),
-        CommandParameterType::Select => parse_select_parameter(command, parameter, raw),
-    }
-}
-
-fn parse_number_parameter(
-    command: &str,
-    parameter: &CommandParameterSpec,
-    raw: &str,
-) -> Result<Value, SlashCommandError> {
-    parse_json_number(raw)
-        .map(Value::Number)
-        .ok_or_else(|| invalid_parameter_value(command, parameter, "expected a number".to_owned()))
-}
-
-fn parse_json_number(raw: &str) -> Option<Number> {
-    raw.parse::<i64>()
-        .map(Number::from)
-        .ok()
-        .or_else(|| raw.parse::<u64>().map(Number::from).ok())
-        .or_else(|| raw.parse::<f64>().ok().and_then(Number::from_f64))
-}
-
-fn parse_boolean_parameter(
-    command: &str,
-    parameter: &CommandParameterSpec,
-    raw: &str,
-) -> Result<Value, SlashCommandError> {
-    match raw.to_ascii_lowercase().as_str() {
-        "true" => Ok(Value::Bool(true)),
-        "false" => Ok(Value::Bool(false)),
-        _ => Err(invalid_parameter_value(
-            command,
-            parameter,
-            "expected true or false (case-insensitive)".to_owned(),
-        )),
-    }
-}
-
-fn parse_select_parameter(
-    command: &str,
-    parameter: &CommandParameterSpec,
-    raw: &str,
-) -> Result<Value, SlashCommandError> {
-    parameter
-        .options
-        .iter()
-        .find(|option| option.eq_ignore_ascii_case(raw))
-        .cloned()
-        .map(Value::String)
-        .ok_or_else(|| {
-            invalid_parameter_value(
-                command,
-                parameter,
-                format!("expected one of [{}]", parameter.options.join(", ")),
-            )
-        })
-}
-
-fn invalid_parameter_value(
-    command: &str,
-    parameter: &CommandParameterSpec,
-    reason: String,
-) -> SlashCommandError {
-    SlashCommandError::InvalidParameterValue {
-        command: command.to_owned(),
-        parameter: parameter.name.clone(),
-        reason,
-    }
-}
@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use super::{CommandPermission, TokenSpan};
+
 /// Errors for slash-command definition schema validation.
 #[derive(Debug, Clone, Error, PartialEq, Eq)]
 pub enum SlashCommandSchemaError {
@@ -24,6 +26,45 @@ pub enum SlashCommandSchemaError {
         /// Validation reason.
         reason: String,
     },
+
+    /// A tool-call argument template has an invalid `${...}` expression.
+    #[error("invalid tool-call template for '{tool_name}' in command '/{command}': {source}")]
+    InvalidToolCallTemplate {
+        /// Command name.
+        command: String,
+        /// Tool name.
+        tool_name: String,
+        /// Expression failure.
+        source: TemplateExpressionError,
+    },
+
+    /// A command pack file could not be parsed.
+    #[error("invalid slash-command pack: {reason}")]
+    InvalidPack {
+        /// Parse failure reason.
+        reason: String,
+    },
+}
+
+/// Invalid `${...}` expression in a tool-call argument template.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("{reason} at byte {offset}")]
+pub struct TemplateExpressionError {
+    /// Byte offset of the offending expression in the template.
+    pub offset: usize,
+    /// Why the expression is invalid.
+    pub reason: String,
+}
+
+impl TemplateExpressionError {
+    /// Creates an expression error at `offset`.
+    #[must_use]
+    pub fn new(offset: usize, reason: impl Into<String>) -> Self {
+        Self {
+            offset,
+            reason: reason.into(),
+        }
+    }
 }
 
 /// Typed unavailable error for slash-command registries.
@@ -44,6 +85,24 @@ impl SlashCommandRegistryUnavailableError {
     }
 }
 
+/// Typed failure of a slash-command authoriser.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("{reason}")]
+pub struct SlashCommandAuthorisationError {
+    /// Authoriser failure reason.
+    pub reason: String,
+}
+
+impl SlashCommandAuthorisationError {
+    /// Creates a typed authoriser error.
+    #[must_use]
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
 /// Errors for slash-command parsing, validation, and execution.
 #[derive(Debug, Clone, Error, PartialEq, Eq)]
 pub enum SlashCommandError {
@@ -66,6 +125,8 @@ pub enum SlashCommandError {
     InvalidParameterToken {
         /// The malformed token text.
         token: String,
+        /// Where the token appears in the input.
+        span: TokenSpan,
     },
 
     /// A quoted string was not terminated.
@@ -80,6 +141,46 @@ pub enum SlashCommandError {
     #[error("command '/{0}' was not found")]
     UnknownCommand(String),
 
+    /// Command name matches several namespaced commands or aliases.
+    #[error("command '/{command}' is ambiguous; use one of: {}", candidates.join(", "))]
+    AmbiguousCommand {
+        /// Command name as invoked.
+        command: String,
+        /// Qualified names of the matching commands, in name order.
+        candidates: Vec<String>,
+    },
+
+    /// Caller lacks permissions the command requires.
+    #[error(
+        "permission denied for command '/{command}': missing {}",
+        missing.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    PermissionDenied {
+        /// Command name.
+        command: String,
+        /// Required permissions the caller does not hold, in declaration
+        /// order.
+        missing: Vec<CommandPermission>,
+    },
+
+    /// Authorisation could not be checked.
+    #[error("slash-command authorisation unavailable: {source}")]
+    AuthorisationUnavailable {
+        /// Authorisation failure.
+        source: SlashCommandAuthorisationError,
+    },
+
+    /// Command received a positional argument it does not accept.
+    #[error("unexpected argument '{argument}' for command '/{command}': expected key=value")]
+    UnexpectedArgument {
+        /// Command name.
+        command: String,
+        /// The argument text.
+        argument: String,
+        /// Where the argument appears in the input, when known.
+        span: Option<TokenSpan>,
+    },
+
     /// Parameter does not exist on command definition.
     #[error("unknown parameter '{parameter}' for command '/{command}'")]
     UnknownParameter {
@@ -87,6 +188,8 @@ pub enum SlashCommandError {
         command: String,
         /// Unknown parameter name.
         parameter: String,
+        /// Where the parameter appears in the input, when known.
+        span: Option<TokenSpan>,
     },
 
     /// Required parameter missing.
@@ -107,6 +210,21 @@ pub enum SlashCommandError {
         parameter: String,
         /// Validation reason.
         reason: String,
+        /// Where the parameter appears in the input, when known.
+        span: Option<TokenSpan>,
+    },
+
+    /// Parameter value violates the parameter's JSON Schema constraints.
+    #[error("parameter '{parameter}' in command '/{command}' violates its constraints: {reason}")]
+    ParameterConstraintViolation {
+        /// Command name.
+        command: String,
+        /// Parameter name.
+        parameter: String,
+        /// Every violated constraint.
+        reason: String,
+        /// Where the parameter appears in the input, when known.
+        span: Option<TokenSpan>,
     },
 
     /// Parameter schema is invalid.
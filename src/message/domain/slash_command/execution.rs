@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::parser::SlashCommandInvocation;
-use crate::message::domain::{SlashCommandExpansion, ToolCallAudit};
+use crate::message::domain::{SlashCommandExecutionId, SlashCommandExpansion, ToolCallAudit};
 
 /// A deterministic planned tool call.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -101,6 +101,12 @@ impl SlashCommandExecution {
         &self.tool_call_audits
     }
 
+    /// Stamps the expansion with the audit record that holds this execution.
+    pub(super) const fn linked_to(mut self, id: SlashCommandExecutionId) -> Self {
+        self.expansion.execution_id = Some(id);
+        self
+    }
+
     /// Consumes the execution and returns expansion metadata plus audits.
     #[must_use]
     pub fn into_expansion_and_audits(self) -> (SlashCommandExpansion, Vec<ToolCallAudit>) {
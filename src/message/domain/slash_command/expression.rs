@@ -0,0 +1,367 @@
+//! `${...}` expressions in tool-call argument templates.
+//!
+//! Argument templates are `minijinja` templates that render JSON. On top of
+//! that, `${name}`, `${name:-default}`, and `${name:+text}` are evaluated
+//! against the validated parameters when tool calls are planned. Results are
+//! bound as template variables rather than spliced into the source, so
+//! parameter values are never parsed as template syntax, and each result is
+//! JSON-encoded for its position, so values cannot add JSON structure.
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::parser::is_valid_identifier;
+use super::{
+    CommandParameterSpec, SlashCommandSchemaError, TemplateExpressionError, ToolCallTemplate,
+};
+
+/// Prefix of the template variables that hold expression results.
+const BINDING_PREFIX: &str = "__expression_";
+
+/// A `${...}` expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateExpression {
+    /// `${name}`: the parameter's value.
+    Parameter(String),
+    /// `${name:-text}`: the parameter's value, or `text` when it is unset or
+    /// empty.
+    Default {
+        /// Parameter name.
+        parameter: String,
+        /// Text used when the parameter is unset or empty.
+        fallback: String,
+    },
+    /// `${name:+text}`: `text` when the parameter is set, non-empty, and not
+    /// `false`, otherwise nothing.
+    Alternative {
+        /// Parameter name.
+        parameter: String,
+        /// Text used when the parameter is set.
+        replacement: String,
+    },
+}
+
+impl TemplateExpression {
+    /// Returns the parameter the expression reads.
+    #[must_use]
+    pub fn parameter(&self) -> &str {
+        match self {
+            Self::Parameter(parameter)
+            | Self::Default { parameter, .. }
+            | Self::Alternative { parameter, .. } => parameter,
+        }
+    }
+
+    /// Evaluates the expression to text. Unset parameters are `null`;
+    /// strings are used as-is and other values in their JSON form.
+    #[must_use]
+    pub fn evaluate(&self, parameters: &BTreeMap<String, Value>) -> String {
+        let parameter = parameters.get(self.parameter());
+        let value = match parameter {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(text)) => text.clone(),
+            Some(other) => other.to_string(),
+        };
+        match self {
+            Self::Default { fallback, .. } if value.is_empty() => fallback.clone(),
+            Self::Parameter(_) | Self::Default { .. } => value,
+            Self::Alternative { replacement, .. }
+                if !value.is_empty() && parameter != Some(&Value::Bool(false)) =>
+            {
+                replacement.clone()
+            }
+            Self::Alternative { .. } => String::new(),
+        }
+    }
+
+    fn parse(body: &str) -> Result<Self, &'static str> {
+        let (name, operator) = body
+            .split_once(':')
+            .map_or((body, None), |(head, tail)| (head, Some(tail)));
+        if name.is_empty() || !is_valid_identifier(name) {
+            return Err("expressions must start with a parameter name");
+        }
+        let parameter = name.to_owned();
+        let Some(suffix) = operator else {
+            return Ok(Self::Parameter(parameter));
+        };
+        let (text, is_default) = match (suffix.strip_prefix('-'), suffix.strip_prefix('+')) {
+            (Some(fallback), _) => (fallback, true),
+            (None, Some(replacement)) => (replacement, false),
+            (None, None) => return Err("expected ':-' or ':+' after the parameter name"),
+        };
+        if text.contains(['"', '\\', '$', '{']) || text.contains(char::is_control) {
+            return Err("expression text cannot contain quotes, backslashes, '$', or '{'");
+        }
+        Ok(if is_default {
+            Self::Default {
+                parameter,
+                fallback: text.to_owned(),
+            }
+        } else {
+            Self::Alternative {
+                parameter,
+                replacement: text.to_owned(),
+            }
+        })
+    }
+}
+
+/// A piece of a parsed argument template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgumentTemplateSegment {
+    /// `minijinja` source passed through unchanged.
+    Text(String),
+    /// An expression inside a JSON string; its result is escaped as string
+    /// content.
+    Quoted(TemplateExpression),
+    /// A `${name}` expression in value position; the parameter is inserted
+    /// as a JSON value, `null` when unset.
+    Value(String),
+}
+
+/// A tool-call argument template split into `minijinja` source and
+/// `${...}` expressions.
+///
+/// `$${` writes a literal `${`. Defaults and conditionals must sit inside a
+/// JSON string; only a bare `${name}` may stand in for a whole value.
+/// Expressions cannot appear inside `minijinja` tags.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::ArgumentTemplate;
+/// use serde_json::json;
+/// use std::collections::BTreeMap;
+///
+/// let template = ArgumentTemplate::parse(r#"{"branch": "${branch:-main}"}"#)?;
+/// let parameters = BTreeMap::from([("branch".to_owned(), json!(null))]);
+///
+/// let (source, bindings) = template.bind(&parameters);
+/// assert_eq!(source, r#"{"branch": "{{ __expression_0 }}"}"#);
+/// assert_eq!(bindings, [("__expression_0".to_owned(), "main".to_owned())]);
+/// # Ok::<(), corbusier::message::domain::TemplateExpressionError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgumentTemplate {
+    segments: Vec<ArgumentTemplateSegment>,
+}
+
+impl ArgumentTemplate {
+    /// Parses the expressions in an argument template.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TemplateExpressionError`] for unterminated or malformed
+    /// expressions, defaults or conditionals outside a JSON string, and
+    /// expressions inside `minijinja` tags.
+    pub fn parse(source: &str) -> Result<Self, TemplateExpressionError> {
+        let mut scanner = Scanner::new(source);
+        scanner.run()?;
+        scanner.flush();
+        Ok(Self {
+            segments: scanner.segments,
+        })
+    }
+
+    /// Returns the parsed segments in template order.
+    #[must_use]
+    pub fn segments(&self) -> &[ArgumentTemplateSegment] {
+        &self.segments
+    }
+
+    /// Returns the parameters the expressions read, in template order.
+    pub fn parameters(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().filter_map(|segment| match segment {
+            ArgumentTemplateSegment::Text(_) => None,
+            ArgumentTemplateSegment::Quoted(expression) => Some(expression.parameter()),
+            ArgumentTemplateSegment::Value(parameter) => Some(parameter.as_str()),
+        })
+    }
+
+    /// Evaluates the expressions against `parameters`.
+    ///
+    /// Returns `minijinja` source in which each expression is replaced by a
+    /// variable reference, and the JSON-encoded result bound to each
+    /// variable. Rendering the source with the bindings in its context
+    /// inserts the results verbatim.
+    #[must_use]
+    pub fn bind(&self, parameters: &BTreeMap<String, Value>) -> (String, Vec<(String, String)>) {
+        let mut source = String::new();
+        let mut bindings = Vec::new();
+        for segment in &self.segments {
+            let encoded = match segment {
+                ArgumentTemplateSegment::Text(text) => {
+                    source.push_str(text);
+                    continue;
+                }
+                ArgumentTemplateSegment::Quoted(expression) => {
+                    encode_string_content(expression.evaluate(parameters))
+                }
+                ArgumentTemplateSegment::Value(parameter) => parameters
+                    .get(parameter)
+                    .unwrap_or(&Value::Null)
+                    .to_string(),
+            };
+            let name = format!("{BINDING_PREFIX}{}", bindings.len());
+            source.push_str("{{ ");
+            source.push_str(&name);
+            source.push_str(" }}");
+            bindings.push((name, encoded));
+        }
+        (source, bindings)
+    }
+}
+
+/// Checks that every tool-call template parses and that its expressions
+/// read declared parameters.
+pub(super) fn validate_tool_calls(
+    command: &str,
+    parameters: &[CommandParameterSpec],
+    tool_calls: &[ToolCallTemplate],
+) -> Result<(), SlashCommandSchemaError> {
+    for tool_call in tool_calls {
+        let invalid = |source| SlashCommandSchemaError::InvalidToolCallTemplate {
+            command: command.to_owned(),
+            tool_name: tool_call.tool_name.clone(),
+            source,
+        };
+        let template = ArgumentTemplate::parse(&tool_call.arguments_template).map_err(invalid)?;
+        if let Some(unknown) = template
+            .parameters()
+            .find(|name| !parameters.iter().any(|spec| spec.name == *name))
+        {
+            let offset = tool_call
+                .arguments_template
+                .find(&format!("${{{unknown}"))
+                .unwrap_or_default();
+            return Err(invalid(TemplateExpressionError::new(
+                offset,
+                format!("expression reads undeclared parameter '{unknown}'"),
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Escapes `text` for use between the quotes of a JSON string.
+fn encode_string_content(text: String) -> String {
+    let quoted = Value::String(text).to_string();
+    quoted
+        .get(1..quoted.len().saturating_sub(1))
+        .unwrap_or_default()
+        .to_owned()
+}
+
+/// Splits an argument template into text and expressions, tracking whether
+/// each position is inside a JSON string literal.
+struct Scanner<'a> {
+    source: &'a str,
+    position: usize,
+    in_string: bool,
+    text: String,
+    segments: Vec<ArgumentTemplateSegment>,
+}
+
+impl<'a> Scanner<'a> {
+    const fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            position: 0,
+            in_string: false,
+            text: String::new(),
+            segments: Vec::new(),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        self.source.get(self.position..).unwrap_or_default()
+    }
+
+    fn run(&mut self) -> Result<(), TemplateExpressionError> {
+        while let Some(character) = self.rest().chars().next() {
+            let rest = self.rest();
+            if rest.starts_with("$${") {
+                self.text.push_str("${");
+                self.position += 3;
+            } else if rest.starts_with("${") {
+                self.expression()?;
+            } else if let Some(close) = tag_close(rest) {
+                self.tag(close)?;
+            } else {
+                self.character(character);
+            }
+        }
+        Ok(())
+    }
+
+    fn character(&mut self, character: char) {
+        self.text.push(character);
+        self.position += character.len_utf8();
+        if self.in_string && character == '\\' {
+            if let Some(escaped) = self.rest().chars().next() {
+                self.text.push(escaped);
+                self.position += escaped.len_utf8();
+            }
+        } else if character == '"' {
+            self.in_string = !self.in_string;
+        }
+    }
+
+    fn tag(&mut self, close: &str) -> Result<(), TemplateExpressionError> {
+        let rest = self.rest();
+        let length = rest.find(close).map_or(rest.len(), |end| end + close.len());
+        let tag = rest.get(..length).unwrap_or(rest);
+        if tag.contains("${") {
+            return Err(TemplateExpressionError::new(
+                self.position,
+                "expressions cannot appear inside template tags",
+            ));
+        }
+        self.text.push_str(tag);
+        self.position += length;
+        Ok(())
+    }
+
+    fn expression(&mut self) -> Result<(), TemplateExpressionError> {
+        let start = self.position;
+        let body_start = start + 2;
+        let rest = self.source.get(body_start..).unwrap_or_default();
+        let length = rest
+            .find('}')
+            .ok_or_else(|| TemplateExpressionError::new(start, "unterminated '${' expression"))?;
+        let body = rest.get(..length).unwrap_or_default();
+        let expression = TemplateExpression::parse(body)
+            .map_err(|reason| TemplateExpressionError::new(start, reason))?;
+        let segment = if self.in_string {
+            ArgumentTemplateSegment::Quoted(expression)
+        } else if let TemplateExpression::Parameter(parameter) = expression {
+            ArgumentTemplateSegment::Value(parameter)
+        } else {
+            return Err(TemplateExpressionError::new(
+                start,
+                "defaults and conditionals must be inside a JSON string",
+            ));
+        };
+        self.flush();
+        self.segments.push(segment);
+        self.position = body_start + length + 1;
+        Ok(())
+    }
+
+    fn flush(&mut self) {
+        if !self.text.is_empty() {
+            self.segments
+                .push(ArgumentTemplateSegment::Text(std::mem::take(
+                    &mut self.text,
+                )));
+        }
+    }
+}
+
+/// Returns the closing delimiter when `rest` opens a `minijinja` tag.
+fn tag_close(rest: &str) -> Option<&'static str> {
+    [("{{", "}}"), ("{%", "%}"), ("{#", "#}")]
+        .into_iter()
+        .find_map(|(open, close)| rest.starts_with(open).then_some(close))
+}
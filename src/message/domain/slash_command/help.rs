@@ -0,0 +1,108 @@
+//! Generated usage text for the built-in `/help` command.
+
+use super::{CommandParameterSpec, CommandParameterType, SlashCommandDefinition};
+
+/// Name of the built-in command that renders usage from definitions.
+///
+/// Definitions outside a namespace may not use it as a name or alias.
+pub const HELP_COMMAND: &str = "help";
+
+impl SlashCommandDefinition {
+    /// Returns a one-line usage synopsis, such as
+    /// `/task action=<start|create|status> [issue=<string>]`.
+    #[must_use]
+    pub fn usage(&self) -> String {
+        std::iter::once(format!("/{}", self.qualified_name()))
+            .chain(self.parameters.iter().map(parameter_usage))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Renders the help shown by `/help <command>`: the description,
+    /// usage, aliases, required permissions, defaults, and any longer help
+    /// text.
+    #[must_use]
+    pub fn render_help(&self) -> String {
+        let mut lines = vec![
+            format!("/{} - {}", self.qualified_name(), self.description),
+            format!("Usage: {}", self.usage()),
+        ];
+        if !self.aliases.is_empty() {
+            lines.push(format!("Aliases: {}", self.alias_names().join(", ")));
+        }
+        if !self.permissions.is_empty() {
+            let required: Vec<_> = self.permissions.iter().map(ToString::to_string).collect();
+            lines.push(format!("Requires: {}", required.join(", ")));
+        }
+        let defaults: Vec<_> = self
+            .parameters
+            .iter()
+            .filter_map(|parameter| {
+                let default = parameter.default.as_ref()?;
+                Some(format!("{}={default}", parameter.name))
+            })
+            .collect();
+        if !defaults.is_empty() {
+            lines.push(format!("Defaults: {}", defaults.join(", ")));
+        }
+        if let Some(help) = &self.help {
+            lines.push(String::new());
+            lines.push(help.clone());
+        }
+        lines.join("\n")
+    }
+
+    fn alias_names(&self) -> Vec<String> {
+        self.aliases
+            .iter()
+            .map(|alias| {
+                self.namespace.as_ref().map_or_else(
+                    || format!("/{alias}"),
+                    |namespace| format!("/{namespace}:{alias}"),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Renders the command index shown by `/help`, one line per command in
+/// qualified-name order.
+#[must_use]
+pub fn render_help_index(definitions: &[SlashCommandDefinition]) -> String {
+    let mut sorted: Vec<_> = definitions.iter().collect();
+    sorted.sort_by_key(|definition| definition.qualified_name());
+    let entries = sorted.into_iter().map(|definition| {
+        let entry = format!(
+            "  /{} - {}",
+            definition.qualified_name(),
+            definition.description
+        );
+        if definition.aliases.is_empty() {
+            entry
+        } else {
+            format!("{entry} (aliases: {})", definition.alias_names().join(", "))
+        }
+    });
+    std::iter::once("Available commands:".to_owned())
+        .chain(entries)
+        .chain(std::iter::once(format!(
+            "Run /{HELP_COMMAND} <command> for usage."
+        )))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parameter_usage(parameter: &CommandParameterSpec) -> String {
+    let value = match parameter.parameter_type {
+        CommandParameterType::String => "<string>".to_owned(),
+        CommandParameterType::Integer => "<integer>".to_owned(),
+        CommandParameterType::Number => "<number>".to_owned(),
+        CommandParameterType::Boolean => "<true|false>".to_owned(),
+        CommandParameterType::Select => format!("<{}>", parameter.options.join("|")),
+    };
+    if parameter.required {
+        format!("{}={value}", parameter.name)
+    } else {
+        format!("[{}={value}]", parameter.name)
+    }
+}
@@ -3,11 +3,30 @@
 mod definition;
 mod error;
 mod execution;
+mod expression;
+mod help;
+mod pack;
+mod parameter;
 mod parser;
+mod permission;
+mod record;
+mod resolution;
+mod version;
 
-pub use definition::{
-    CommandParameterSpec, CommandParameterType, SlashCommandDefinition, ToolCallTemplate,
+pub use definition::{SlashCommandDefinition, ToolCallTemplate};
+pub use error::{
+    SlashCommandAuthorisationError, SlashCommandError, SlashCommandRegistryUnavailableError,
+    SlashCommandSchemaError, TemplateExpressionError,
 };
-pub use error::{SlashCommandError, SlashCommandRegistryUnavailableError, SlashCommandSchemaError};
 pub use execution::{PlannedToolCall, SlashCommandExecution};
-pub use parser::SlashCommandInvocation;
+pub use expression::{ArgumentTemplate, ArgumentTemplateSegment, TemplateExpression};
+pub use help::{HELP_COMMAND, render_help_index};
+pub use pack::{SlashCommandPack, SlashCommandPackFormat};
+pub use parameter::{CommandParameterSpec, CommandParameterType};
+pub use parser::{SlashCommandInvocation, TokenSpan};
+pub use permission::CommandPermission;
+pub use record::{
+    PersistedSlashCommandExecution, SlashCommandExecutionId, SlashCommandExecutionRecord,
+};
+pub use resolution::resolve_command;
+pub use version::VersionedSlashCommand;
@@ -0,0 +1,228 @@
+//! Declarative slash-command packs loaded from TOML or YAML.
+//!
+//! A pack lists command definitions under a top-level `commands` key:
+//!
+//! ```toml
+//! [[commands]]
+//! name = "deploy"
+//! namespace = "ops"
+//! aliases = ["ship"]
+//! description = "Deploy a service"
+//! help = "Deploys the current branch to the chosen environment."
+//! template = "Deploy to {{ env }}."
+//! roles = ["maintainer"]
+//! scopes = ["deploy:write"]
+//!
+//! [[commands.parameters]]
+//! name = "env"
+//! type = "select"
+//! required = true
+//! options = ["staging", "production"]
+//!
+//! [[commands.parameters]]
+//! name = "replicas"
+//! type = "integer"
+//! default = 2
+//! schema = { minimum = 1, maximum = 10 }
+//!
+//! [[commands.tool_calls]]
+//! tool = "deployer"
+//! arguments = '{"env":{{ env | json_string }}}'
+//! ```
+//!
+//! Unknown keys are rejected so that typos fail loudly instead of silently
+//! dropping configuration.
+
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::{
+    CommandParameterSpec, CommandParameterType, CommandPermission, SlashCommandDefinition,
+    SlashCommandSchemaError, ToolCallTemplate,
+};
+
+/// File format of a slash-command pack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlashCommandPackFormat {
+    /// TOML document.
+    Toml,
+    /// YAML document.
+    Yaml,
+}
+
+impl SlashCommandPackFormat {
+    /// Returns the format for a file extension (`toml`, `yaml`, or `yml`),
+    /// ignoring case.
+    #[must_use]
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "toml" => Some(Self::Toml),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// Validated command definitions parsed from a pack file.
+///
+/// Definitions are ordered by qualified name, so installing a pack is
+/// deterministic regardless of the order commands appear in the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlashCommandPack {
+    definitions: Vec<SlashCommandDefinition>,
+}
+
+impl SlashCommandPack {
+    /// Parses and validates a pack.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandSchemaError::InvalidPack`] when the document
+    /// cannot be parsed, or the definition errors of schema validation when
+    /// a command is invalid or defined twice.
+    pub fn parse(
+        format: SlashCommandPackFormat,
+        source: &str,
+    ) -> Result<Self, SlashCommandSchemaError> {
+        let file: PackFile = match format {
+            SlashCommandPackFormat::Toml => toml::from_str(source).map_err(invalid_pack)?,
+            SlashCommandPackFormat::Yaml => serde_norway::from_str(source).map_err(invalid_pack)?,
+        };
+        let mut definitions = BTreeMap::new();
+        for command in file.commands {
+            let definition = command.into_definition()?;
+            definition.validate_schema()?;
+            let name = definition.qualified_name();
+            if definitions.insert(name.clone(), definition).is_some() {
+                return Err(SlashCommandSchemaError::InvalidCommandDefinition {
+                    command: name,
+                    reason: "duplicate command definition in pack".to_owned(),
+                });
+            }
+        }
+        Ok(Self {
+            definitions: definitions.into_values().collect(),
+        })
+    }
+
+    /// Returns the pack's definitions, ordered by qualified name.
+    #[must_use]
+    pub fn definitions(&self) -> &[SlashCommandDefinition] {
+        &self.definitions
+    }
+
+    /// Consumes the pack, returning its definitions ordered by qualified
+    /// name.
+    #[must_use]
+    pub fn into_definitions(self) -> Vec<SlashCommandDefinition> {
+        self.definitions
+    }
+}
+
+fn invalid_pack(err: impl std::fmt::Display) -> SlashCommandSchemaError {
+    SlashCommandSchemaError::InvalidPack {
+        reason: err.to_string(),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackFile {
+    #[serde(default)]
+    commands: Vec<PackCommand>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackCommand {
+    name: String,
+    #[serde(default)]
+    namespace: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    description: String,
+    #[serde(default)]
+    help: Option<String>,
+    template: String,
+    #[serde(default)]
+    parameters: Vec<PackParameter>,
+    #[serde(default)]
+    tool_calls: Vec<PackToolCall>,
+    #[serde(default)]
+    roles: Vec<String>,
+    #[serde(default)]
+    scopes: Vec<String>,
+}
+
+impl PackCommand {
+    fn into_definition(self) -> Result<SlashCommandDefinition, SlashCommandSchemaError> {
+        let mut definition =
+            SlashCommandDefinition::new(self.name, self.description, self.template);
+        definition.namespace = self.namespace;
+        definition.aliases = self.aliases;
+        definition.help = self.help;
+        definition.normalise_names();
+        definition.parameters = self
+            .parameters
+            .into_iter()
+            .map(|parameter| parameter.into_spec(&definition.command))
+            .collect::<Result<_, _>>()?;
+        definition.tool_calls = self
+            .tool_calls
+            .into_iter()
+            .map(|call| ToolCallTemplate::new(call.tool, call.arguments))
+            .collect();
+        definition.permissions = self
+            .roles
+            .into_iter()
+            .map(CommandPermission::Role)
+            .chain(self.scopes.into_iter().map(CommandPermission::Scope))
+            .collect();
+        Ok(definition)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackParameter {
+    name: String,
+    #[serde(rename = "type")]
+    kind: CommandParameterType,
+    #[serde(default)]
+    required: bool,
+    #[serde(default)]
+    options: Vec<String>,
+    #[serde(default)]
+    default: Option<Value>,
+    #[serde(default)]
+    schema: Option<Value>,
+}
+
+impl PackParameter {
+    fn into_spec(self, command: &str) -> Result<CommandParameterSpec, SlashCommandSchemaError> {
+        let mut spec = CommandParameterSpec::new(self.name, self.kind, self.required)
+            .with_options(self.options);
+        spec.default = match self.default {
+            None => None,
+            Some(Value::String(raw)) => Some(raw),
+            Some(scalar @ (Value::Number(_) | Value::Bool(_))) => Some(scalar.to_string()),
+            Some(_) => {
+                return Err(SlashCommandSchemaError::InvalidParameterDefinition {
+                    command: command.to_owned(),
+                    parameter: spec.name,
+                    reason: "defaults must be strings, numbers, or booleans".to_owned(),
+                });
+            }
+        };
+        spec.schema = self.schema;
+        Ok(spec)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackToolCall {
+    tool: String,
+    arguments: String,
+}
@@ -0,0 +1,216 @@
+//! Slash-command parameter specifications and argument coercion.
+//!
+//! Raw `key=value` arguments are coerced to the declared parameter type,
+//! then checked against the parameter's optional JSON Schema constraints.
+//! Failures name the parameter and, when the argument came from parsed
+//! input, the span of its token.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+use super::{SlashCommandError, SlashCommandSchemaError, TokenSpan};
+use crate::message::schema::SchemaFragment;
+
+/// Parameter type for slash-command validation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandParameterType {
+    /// Free-form string value.
+    String,
+    /// Whole number.
+    Integer,
+    /// Numeric value.
+    Number,
+    /// Boolean value (`true` or `false`).
+    Boolean,
+    /// Enumeration with allowed options.
+    Select,
+}
+
+/// Parameter specification for a slash command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandParameterSpec {
+    /// Parameter name.
+    pub name: String,
+    /// Parameter type.
+    pub parameter_type: CommandParameterType,
+    /// Whether the parameter is required.
+    pub required: bool,
+    /// Allowed options for `select` parameters.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+    /// Raw value used when an optional parameter is omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    /// JSON Schema the coerced value must match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Value>,
+}
+
+impl CommandParameterSpec {
+    /// Creates a parameter specification.
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        parameter_type: CommandParameterType,
+        required: bool,
+    ) -> Self {
+        Self {
+            name: name.into().to_ascii_lowercase(),
+            parameter_type,
+            required,
+            options: Vec::new(),
+            default: None,
+            schema: None,
+        }
+    }
+
+    /// Adds allowed options for `select` parameters.
+    #[must_use]
+    pub fn with_options(mut self, options: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.options = options
+            .into_iter()
+            .map(Into::into)
+            .map(|option: String| option.to_ascii_lowercase())
+            .collect();
+        self
+    }
+
+    /// Sets the raw value used when the parameter is omitted. Only optional
+    /// parameters may have a default.
+    #[must_use]
+    pub fn with_default(mut self, default: impl Into<String>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Constrains coerced values with a JSON Schema.
+    ///
+    /// The keywords `type`, `const`, `enum`, `minimum`, `maximum`,
+    /// `minLength`, `maxLength`, and `pattern` are checked; others are
+    /// ignored.
+    #[must_use]
+    pub fn with_schema(mut self, schema: Value) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Validates the specification.
+    pub(super) fn validate(&self, command: &str) -> Result<(), SlashCommandSchemaError> {
+        let invalid = |reason: String| SlashCommandSchemaError::InvalidParameterDefinition {
+            command: command.to_owned(),
+            parameter: self.name.clone(),
+            reason,
+        };
+        let is_select = self.parameter_type == CommandParameterType::Select;
+        if is_select && self.options.is_empty() {
+            return Err(invalid("select parameters must provide options".to_owned()));
+        }
+        if !is_select && !self.options.is_empty() {
+            return Err(invalid(
+                "only Select parameters may provide options".to_owned(),
+            ));
+        }
+        if let Some(schema) = &self.schema {
+            validate_constraints(schema).map_err(invalid)?;
+        }
+        match &self.default {
+            Some(_) if self.required => Err(invalid(
+                "required parameters cannot have a default".to_owned(),
+            )),
+            Some(default) => self
+                .coerce(command, default, None)
+                .map(|_| ())
+                .map_err(|err| invalid(format!("invalid default: {err}"))),
+            None => Ok(()),
+        }
+    }
+
+    /// Coerces a raw argument to the parameter type and checks its
+    /// constraints.
+    pub(super) fn coerce(
+        &self,
+        command: &str,
+        raw: &str,
+        span: Option<TokenSpan>,
+    ) -> Result<Value, SlashCommandError> {
+        let value =
+            self.parse_value(raw)
+                .map_err(|reason| SlashCommandError::InvalidParameterValue {
+                    command: command.to_owned(),
+                    parameter: self.name.clone(),
+                    reason,
+                    span,
+                })?;
+        let Some(schema) = &self.schema else {
+            return Ok(value);
+        };
+        let violations = SchemaFragment::compile(schema)
+            .map(|fragment| fragment.violations(&value))
+            .map_err(|err| vec![err.to_string()]);
+        let reasons: Vec<String> = match violations {
+            Ok(found) => found
+                .iter()
+                .map(|violation| violation.message.clone())
+                .collect(),
+            Err(reasons) => reasons,
+        };
+        if reasons.is_empty() {
+            return Ok(value);
+        }
+        Err(SlashCommandError::ParameterConstraintViolation {
+            command: command.to_owned(),
+            parameter: self.name.clone(),
+            reason: reasons.join("; "),
+            span,
+        })
+    }
+
+    fn parse_value(&self, raw: &str) -> Result<Value, String> {
+        match self.parameter_type {
+            CommandParameterType::String => Ok(Value::String(raw.to_owned())),
+            CommandParameterType::Integer => parse_integer(raw)
+                .map(Value::Number)
+                .ok_or_else(|| "expected a whole number".to_owned()),
+            CommandParameterType::Number => parse_integer(raw)
+                .or_else(|| raw.parse::<f64>().ok().and_then(Number::from_f64))
+                .map(Value::Number)
+                .ok_or_else(|| "expected a number".to_owned()),
+            CommandParameterType::Boolean => parse_boolean(raw),
+            CommandParameterType::Select => self.parse_select(raw),
+        }
+    }
+
+    fn parse_select(&self, raw: &str) -> Result<Value, String> {
+        self.options
+            .iter()
+            .find(|option| option.eq_ignore_ascii_case(raw))
+            .cloned()
+            .map(Value::String)
+            .ok_or_else(|| format!("expected one of [{}]", self.options.join(", ")))
+    }
+}
+
+fn parse_integer(raw: &str) -> Option<Number> {
+    raw.parse::<i64>()
+        .map(Number::from)
+        .ok()
+        .or_else(|| raw.parse::<u64>().map(Number::from).ok())
+}
+
+fn parse_boolean(raw: &str) -> Result<Value, String> {
+    match raw.to_ascii_lowercase().as_str() {
+        "true" => Ok(Value::Bool(true)),
+        "false" => Ok(Value::Bool(false)),
+        _ => Err("expected true or false (case-insensitive)".to_owned()),
+    }
+}
+
+fn validate_constraints(schema: &Value) -> Result<(), String> {
+    if !matches!(schema, Value::Object(_) | Value::Bool(_)) {
+        return Err("constraints must be a JSON Schema object".to_owned());
+    }
+    SchemaFragment::compile(schema)
+        .map(|_| ())
+        .map_err(|err| format!("invalid constraint pattern: {err}"))
+}
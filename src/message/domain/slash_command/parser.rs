@@ -5,15 +5,44 @@ use std::collections::BTreeMap;
 
 use super::SlashCommandError;
 
+/// Byte range of a token within raw slash-command input.
+///
+/// Spans index the input exactly as passed to
+/// [`SlashCommandInvocation::parse`], including any leading whitespace, so
+/// interfaces can highlight the offending text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenSpan {
+    /// Offset of the token's first byte.
+    pub start: usize,
+    /// Offset one past the token's last byte.
+    pub end: usize,
+}
+
+impl TokenSpan {
+    /// Creates a span covering `start..end`.
+    #[must_use]
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
+
 /// A parsed slash-command invocation.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SlashCommandInvocation {
     command: String,
     parameters: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    spans: BTreeMap<String, TokenSpan>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    arguments: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    argument_spans: Vec<TokenSpan>,
 }
 
 impl SlashCommandInvocation {
-    /// Parses `/<command> key=value key2="quoted value"` input.
+    /// Parses `/[namespace:]<command> key=value key2="quoted value"` input.
+    ///
+    /// Tokens without `=` are kept as positional arguments, in order.
     ///
     /// # Errors
     ///
@@ -24,75 +53,131 @@ impl SlashCommandInvocation {
             return Err(SlashCommandError::EmptyInput);
         }
 
-        let tokens = tokenize(trimmed)?;
-        let command_token = tokens.first().ok_or(SlashCommandError::EmptyInput)?;
+        let leading = raw_input.len().saturating_sub(raw_input.trim_start().len());
+        let tokens = tokenize(trimmed, leading)?;
+        let (command_token, _) = tokens.first().ok_or(SlashCommandError::EmptyInput)?;
         let command = parse_command_token(command_token)?;
 
-        let mut parameters = BTreeMap::new();
-        for token in tokens.iter().skip(1) {
-            let (key, value) =
-                token
-                    .split_once('=')
-                    .ok_or_else(|| SlashCommandError::InvalidParameterToken {
-                        token: token.to_owned(),
-                    })?;
-
-            if key.is_empty() || !is_valid_identifier(key) {
-                return Err(SlashCommandError::InvalidParameterToken {
-                    token: token.to_owned(),
-                });
-            }
-
-            let normalized_key = key.to_ascii_lowercase();
-            if parameters
-                .insert(normalized_key.clone(), value.to_owned())
-                .is_some()
-            {
-                return Err(SlashCommandError::DuplicateParameter(normalized_key));
-            }
+        let mut invocation = Self {
+            command,
+            parameters: BTreeMap::new(),
+            spans: BTreeMap::new(),
+            arguments: Vec::new(),
+            argument_spans: Vec::new(),
+        };
+        for (token, span) in tokens.into_iter().skip(1) {
+            invocation.push_token(token, span)?;
         }
+        Ok(invocation)
+    }
 
-        Ok(Self {
-            command,
-            parameters,
-        })
+    fn push_token(&mut self, token: String, span: TokenSpan) -> Result<(), SlashCommandError> {
+        if !token.contains('=') {
+            self.arguments.push(token);
+            self.argument_spans.push(span);
+            return Ok(());
+        }
+        let (key, value) = split_parameter_token(&token, span)?;
+        let normalized_key = key.to_ascii_lowercase();
+        if self
+            .parameters
+            .insert(normalized_key.clone(), value.to_owned())
+            .is_some()
+        {
+            return Err(SlashCommandError::DuplicateParameter(normalized_key));
+        }
+        self.spans.insert(normalized_key, span);
+        Ok(())
     }
 
-    /// Returns the command name without the leading slash.
+    /// Returns the command name as invoked, without the leading slash and
+    /// including any `namespace:` prefix.
     #[must_use]
     pub fn command(&self) -> &str {
         &self.command
     }
 
+    /// Returns positional arguments, in input order.
+    #[must_use]
+    pub fn arguments(&self) -> &[String] {
+        &self.arguments
+    }
+
+    /// Returns the span of the positional argument at `index`.
+    #[must_use]
+    pub fn argument_span(&self, index: usize) -> Option<TokenSpan> {
+        self.argument_spans.get(index).copied()
+    }
+
     /// Returns parsed parameter values as raw strings.
     #[must_use]
     pub const fn parameters(&self) -> &BTreeMap<String, String> {
         &self.parameters
     }
+
+    /// Returns the span of the `key=value` token that supplied `parameter`.
+    #[must_use]
+    pub fn parameter_span(&self, parameter: &str) -> Option<TokenSpan> {
+        self.spans.get(parameter).copied()
+    }
+
+    pub(super) const fn spans(&self) -> &BTreeMap<String, TokenSpan> {
+        &self.spans
+    }
+}
+
+fn split_parameter_token(token: &str, span: TokenSpan) -> Result<(&str, &str), SlashCommandError> {
+    let invalid = || SlashCommandError::InvalidParameterToken {
+        token: token.to_owned(),
+        span,
+    };
+    let (key, value) = token.split_once('=').ok_or_else(invalid)?;
+    if key.is_empty() || !is_valid_identifier(key) {
+        return Err(invalid());
+    }
+    Ok((key, value))
 }
 
 fn parse_command_token(token: &str) -> Result<String, SlashCommandError> {
     let command = token
         .strip_prefix('/')
         .ok_or(SlashCommandError::MissingLeadingSlash)?;
-    if command.is_empty() || !is_valid_identifier(command) {
+    if !is_valid_command_name(command) {
         return Err(SlashCommandError::InvalidCommandName(command.to_owned()));
     }
     Ok(command.to_ascii_lowercase())
 }
 
-fn tokenize(input: &str) -> Result<Vec<String>, SlashCommandError> {
+/// Returns whether `name` is a command name, optionally qualified as
+/// `namespace:command`.
+pub(super) fn is_valid_command_name(name: &str) -> bool {
+    let (namespace, command) = split_qualified(name);
+    namespace.is_none_or(|prefix| !prefix.is_empty() && is_valid_identifier(prefix))
+        && !command.is_empty()
+        && is_valid_identifier(command)
+}
+
+/// Splits `namespace:command` into its parts.
+pub(super) fn split_qualified(name: &str) -> (Option<&str>, &str) {
+    name.split_once(':')
+        .map_or((None, name), |(namespace, command)| {
+            (Some(namespace), command)
+        })
+}
+
+fn tokenize(input: &str, offset: usize) -> Result<Vec<(String, TokenSpan)>, SlashCommandError> {
     let mut state = TokenizeState::new();
 
-    for character in input.chars() {
+    for (index, character) in input.char_indices() {
+        let position = offset.saturating_add(index);
         if let Some(quote_char) = state.in_quotes {
             process_quoted_character(character, quote_char, &mut state);
         } else {
-            process_unquoted_character(character, &mut state)?;
+            process_unquoted_character(character, position, &mut state)?;
         }
     }
 
-    validate_final_state(state)
+    validate_final_state(state, offset.saturating_add(input.len()))
 }
 
 fn process_quoted_character(character: char, quote_char: char, state: &mut TokenizeState) {
@@ -111,15 +196,21 @@ fn process_quoted_character(character: char, quote_char: char, state: &mut Token
 
 fn process_unquoted_character(
     character: char,
+    position: usize,
     state: &mut TokenizeState,
 ) -> Result<(), SlashCommandError> {
+    if character.is_whitespace() {
+        state.flush_current(position);
+        return Ok(());
+    }
+    let start = *state.start.get_or_insert(position);
     match character {
         '"' | '\'' => state.in_quotes = Some(character),
-        _ if character.is_whitespace() => state.flush_current(),
         '\\' => {
             state.current.push(character);
             return Err(SlashCommandError::InvalidParameterToken {
                 token: state.current.clone(),
+                span: TokenSpan::new(start, position.saturating_add(1)),
             });
         }
         _ => state.current.push(character),
@@ -127,17 +218,21 @@ fn process_unquoted_character(
     Ok(())
 }
 
-fn validate_final_state(mut state: TokenizeState) -> Result<Vec<String>, SlashCommandError> {
+fn validate_final_state(
+    mut state: TokenizeState,
+    end: usize,
+) -> Result<Vec<(String, TokenSpan)>, SlashCommandError> {
     if state.in_quotes.is_some() || state.escaped {
         return Err(SlashCommandError::UnterminatedQuotedValue);
     }
-    state.flush_current();
+    state.flush_current(end);
     Ok(state.tokens)
 }
 
 struct TokenizeState {
-    tokens: Vec<String>,
+    tokens: Vec<(String, TokenSpan)>,
     current: String,
+    start: Option<usize>,
     in_quotes: Option<char>,
     escaped: bool,
 }
@@ -147,19 +242,23 @@ impl TokenizeState {
         Self {
             tokens: Vec::new(),
             current: String::new(),
+            start: None,
             in_quotes: None,
             escaped: false,
         }
     }
 
-    fn flush_current(&mut self) {
-        if !self.current.is_empty() {
-            self.tokens.push(std::mem::take(&mut self.current));
+    fn flush_current(&mut self, end: usize) {
+        if let Some(start) = self.start.take()
+            && !self.current.is_empty()
+        {
+            let token = std::mem::take(&mut self.current);
+            self.tokens.push((token, TokenSpan::new(start, end)));
         }
     }
 }
 
-fn is_valid_identifier(value: &str) -> bool {
+pub(super) fn is_valid_identifier(value: &str) -> bool {
     value
         .chars()
         .all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_'))
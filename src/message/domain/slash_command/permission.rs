@@ -0,0 +1,54 @@
+//! Permissions required to invoke slash commands.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A role or scope a caller must hold to invoke a command.
+///
+/// Displays as `role:<name>` or `scope:<name>`, the form shown to users when
+/// a permission is missing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandPermission {
+    /// A role assigned to the caller, such as `maintainer`.
+    Role(String),
+    /// A scope granted to the caller's credentials, such as `deploy:write`.
+    Scope(String),
+}
+
+impl CommandPermission {
+    /// Creates a role requirement.
+    #[must_use]
+    pub fn role(name: impl Into<String>) -> Self {
+        Self::Role(name.into())
+    }
+
+    /// Creates a scope requirement.
+    #[must_use]
+    pub fn scope(name: impl Into<String>) -> Self {
+        Self::Scope(name.into())
+    }
+
+    /// Returns the role or scope name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Role(name) | Self::Scope(name) => name,
+        }
+    }
+
+    /// Returns whether the name is non-empty and free of whitespace.
+    pub(super) fn is_valid(&self) -> bool {
+        let name = self.name();
+        !name.is_empty() && !name.chars().any(char::is_whitespace)
+    }
+}
+
+impl fmt::Display for CommandPermission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Role(name) => write!(f, "role:{name}"),
+            Self::Scope(name) => write!(f, "scope:{name}"),
+        }
+    }
+}
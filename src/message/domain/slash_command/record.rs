@@ -0,0 +1,162 @@
+//! Recorded slash-command executions for the audit trail.
+
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+use super::SlashCommandExecution;
+use crate::context::UserId;
+use crate::message::domain::{ConversationId, SlashCommandExpansion};
+
+/// Unique identifier for a recorded slash-command execution.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::SlashCommandExecutionId;
+///
+/// let id = SlashCommandExecutionId::new();
+/// assert!(!id.as_ref().is_nil());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SlashCommandExecutionId(Uuid);
+
+impl SlashCommandExecutionId {
+    /// Creates a new random execution identifier.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Creates an execution identifier from an existing UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Returns the inner UUID value.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for SlashCommandExecutionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsRef<Uuid> for SlashCommandExecutionId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl fmt::Display for SlashCommandExecutionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// An audited slash-command execution: who ran which command, with which
+/// arguments, in which conversation, and the tool calls it planned.
+///
+/// Recording stamps the execution's expansion with the record identifier,
+/// so messages that carry the expansion in their metadata link back to the
+/// record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlashCommandExecutionRecord {
+    id: SlashCommandExecutionId,
+    conversation_id: ConversationId,
+    invoked_by: UserId,
+    executed_at: DateTime<Utc>,
+    execution: SlashCommandExecution,
+}
+
+/// Stored fields of a [`SlashCommandExecutionRecord`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersistedSlashCommandExecution {
+    /// Record identifier.
+    pub id: SlashCommandExecutionId,
+    /// Conversation the command ran in.
+    pub conversation_id: ConversationId,
+    /// User who ran the command.
+    pub invoked_by: UserId,
+    /// When the command ran.
+    pub executed_at: DateTime<Utc>,
+    /// The execution, already linked to `id`.
+    pub execution: SlashCommandExecution,
+}
+
+impl SlashCommandExecutionRecord {
+    /// Records `execution` as run by `invoked_by` in `conversation_id`.
+    #[must_use]
+    pub fn new(
+        conversation_id: ConversationId,
+        invoked_by: UserId,
+        execution: SlashCommandExecution,
+        clock: &impl Clock,
+    ) -> Self {
+        let id = SlashCommandExecutionId::new();
+        Self {
+            id,
+            conversation_id,
+            invoked_by,
+            executed_at: clock.utc(),
+            execution: execution.linked_to(id),
+        }
+    }
+
+    /// Reconstructs a record from storage.
+    #[must_use]
+    pub fn from_persisted(data: PersistedSlashCommandExecution) -> Self {
+        Self {
+            id: data.id,
+            conversation_id: data.conversation_id,
+            invoked_by: data.invoked_by,
+            executed_at: data.executed_at,
+            execution: data.execution.linked_to(data.id),
+        }
+    }
+
+    /// Returns the record identifier.
+    #[must_use]
+    pub const fn id(&self) -> SlashCommandExecutionId {
+        self.id
+    }
+
+    /// Returns the conversation the command ran in.
+    #[must_use]
+    pub const fn conversation_id(&self) -> ConversationId {
+        self.conversation_id
+    }
+
+    /// Returns the user who ran the command.
+    #[must_use]
+    pub const fn invoked_by(&self) -> UserId {
+        self.invoked_by
+    }
+
+    /// Returns when the command ran.
+    #[must_use]
+    pub const fn executed_at(&self) -> DateTime<Utc> {
+        self.executed_at
+    }
+
+    /// Returns the audited execution.
+    #[must_use]
+    pub const fn execution(&self) -> &SlashCommandExecution {
+        &self.execution
+    }
+
+    /// Returns the expansion to attach to resulting messages; it carries
+    /// this record's identifier.
+    #[must_use]
+    pub const fn expansion(&self) -> &SlashCommandExpansion {
+        self.execution.expansion()
+    }
+}
@@ -0,0 +1,113 @@
+//! Resolution of invoked command names to definitions.
+//!
+//! A name resolves to the first matching tier, highest precedence first:
+//!
+//! 1. a command whose name matches, outside any namespace;
+//! 2. an alias of such a command;
+//! 3. a namespaced command whose name matches;
+//! 4. an alias of a namespaced command.
+//!
+//! A qualified `namespace:command` name only considers that namespace, name
+//! before alias. Several matches in the winning tier make the name
+//! ambiguous, so callers must qualify it.
+
+use super::parser::{is_valid_command_name, split_qualified};
+use super::{SlashCommandDefinition, SlashCommandError, SlashCommandInvocation};
+
+impl SlashCommandInvocation {
+    /// Resolves the invoked command among `definitions`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandError::UnknownCommand`] when nothing matches, or
+    /// [`SlashCommandError::AmbiguousCommand`] when the name matches several
+    /// commands of equal precedence.
+    pub fn resolve<'a>(
+        &self,
+        definitions: &'a [SlashCommandDefinition],
+    ) -> Result<&'a SlashCommandDefinition, SlashCommandError> {
+        resolve_command(definitions, self.command())
+    }
+}
+
+/// Resolves `name`, with or without a leading slash, among `definitions`.
+///
+/// # Errors
+///
+/// Returns [`SlashCommandError::UnknownCommand`] when `name` is not a
+/// command name or nothing matches, or
+/// [`SlashCommandError::AmbiguousCommand`] when the name matches several
+/// commands of equal precedence.
+pub fn resolve_command<'a>(
+    definitions: &'a [SlashCommandDefinition],
+    name: &str,
+) -> Result<&'a SlashCommandDefinition, SlashCommandError> {
+    let invoked = name.strip_prefix('/').unwrap_or(name).to_ascii_lowercase();
+    if !is_valid_command_name(&invoked) {
+        return Err(SlashCommandError::UnknownCommand(invoked));
+    }
+    let (namespace, command) = split_qualified(&invoked);
+    let scopes = if namespace.is_some() {
+        vec![Scope::Namespace(namespace)]
+    } else {
+        vec![Scope::Namespace(None), Scope::AnyNamespace]
+    };
+    let tiers = scopes
+        .into_iter()
+        .flat_map(|scope| [(scope, false), (scope, true)]);
+    for (scope, by_alias) in tiers {
+        let matches: Vec<_> = definitions
+            .iter()
+            .filter(|definition| scope.contains(definition))
+            .filter(|definition| names_match(definition, command, by_alias))
+            .collect();
+        if let [definition] = matches.as_slice() {
+            return Ok(*definition);
+        }
+        if !matches.is_empty() {
+            return Err(ambiguous(&invoked, &matches));
+        }
+    }
+    Err(SlashCommandError::UnknownCommand(invoked))
+}
+
+fn ambiguous(invoked: &str, matches: &[&SlashCommandDefinition]) -> SlashCommandError {
+    let mut candidates: Vec<_> = matches
+        .iter()
+        .map(|definition| definition.qualified_name())
+        .collect();
+    candidates.sort();
+    SlashCommandError::AmbiguousCommand {
+        command: invoked.to_owned(),
+        candidates,
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Scope<'a> {
+    Namespace(Option<&'a str>),
+    AnyNamespace,
+}
+
+impl Scope<'_> {
+    fn contains(self, definition: &SlashCommandDefinition) -> bool {
+        match self {
+            Self::Namespace(expected) => {
+                definition.namespace.as_deref().map(str::to_ascii_lowercase)
+                    == expected.map(str::to_owned)
+            }
+            Self::AnyNamespace => definition.namespace.is_some(),
+        }
+    }
+}
+
+fn names_match(definition: &SlashCommandDefinition, command: &str, by_alias: bool) -> bool {
+    if by_alias {
+        definition
+            .aliases
+            .iter()
+            .any(|alias| alias.eq_ignore_ascii_case(command))
+    } else {
+        definition.command.eq_ignore_ascii_case(command)
+    }
+}
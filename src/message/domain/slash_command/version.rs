@@ -0,0 +1,52 @@
+//! Versioned slash-command definitions.
+
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU32;
+
+use super::SlashCommandDefinition;
+
+/// A registered version of a slash-command definition.
+///
+/// Registering a command again stores a new version rather than replacing
+/// the old one, so earlier definitions remain available for audit and
+/// restore. Versions of a command count up from one without gaps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionedSlashCommand {
+    /// The command definition.
+    pub definition: SlashCommandDefinition,
+    /// Version of the definition, starting at one.
+    pub version: NonZeroU32,
+    /// When the version was registered.
+    pub registered_at: DateTime<Utc>,
+}
+
+impl VersionedSlashCommand {
+    /// Creates the first version of a definition.
+    #[must_use]
+    pub fn first(definition: SlashCommandDefinition, clock: &impl Clock) -> Self {
+        Self {
+            definition,
+            version: NonZeroU32::MIN,
+            registered_at: clock.utc(),
+        }
+    }
+
+    /// Creates the version of `definition` that follows this one, or `None`
+    /// when the version counter is exhausted.
+    #[must_use]
+    pub fn succeed(&self, definition: SlashCommandDefinition, clock: &impl Clock) -> Option<Self> {
+        Some(Self {
+            definition,
+            version: self.version.checked_add(1)?,
+            registered_at: clock.utc(),
+        })
+    }
+
+    /// Returns the qualified command name, which keys the version history.
+    #[must_use]
+    pub fn command(&self) -> String {
+        self.definition.qualified_name()
+    }
+}
@@ -0,0 +1,150 @@
+//! Retention rules for context snapshots.
+//!
+//! Snapshots accumulate for as long as a session runs. [`SnapshotRetention`]
+//! says which of them may be pruned: all but the latest few of each session,
+//! handoff snapshots excepted, and periodic snapshots once they reach a
+//! given age. A snapshot that a kept snapshot extends is always kept, since
+//! the increment cannot be resolved without it.
+
+use std::collections::{HashMap, HashSet};
+use std::num::{NonZeroU32, NonZeroUsize};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{ContextWindowSnapshot, SnapshotType};
+
+/// Which context snapshots are kept.
+///
+/// The default keeps every snapshot.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::{NonZeroU32, NonZeroUsize};
+///
+/// use chrono::{Duration, Utc};
+/// use corbusier::message::domain::SnapshotRetention;
+///
+/// let rules = SnapshotRetention::new()
+///     .keep_latest_per_session(NonZeroUsize::new(10).expect("non-zero"))
+///     .expire_periodic_after_days(NonZeroU32::new(30).expect("non-zero"));
+/// let now = Utc::now();
+///
+/// assert_eq!(rules.periodic_cutoff(now), Some(now - Duration::days(30)));
+/// assert!(rules.keeps_handoffs);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotRetention {
+    /// Snapshots kept per session, newest first; `None` keeps them all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep_latest: Option<NonZeroUsize>,
+    /// Whether handoff snapshots are kept regardless of the per-session
+    /// limit.
+    pub keeps_handoffs: bool,
+    /// Days after capture when periodic snapshots expire; `None` keeps them
+    /// until the per-session limit prunes them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub periodic_max_age_days: Option<NonZeroU32>,
+}
+
+impl Default for SnapshotRetention {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SnapshotRetention {
+    /// Creates rules that keep every snapshot.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            keep_latest: None,
+            keeps_handoffs: true,
+            periodic_max_age_days: None,
+        }
+    }
+
+    /// Keeps only the `count` latest snapshots of each session, besides
+    /// those kept by other rules.
+    #[must_use]
+    pub const fn keep_latest_per_session(mut self, count: NonZeroUsize) -> Self {
+        self.keep_latest = Some(count);
+        self
+    }
+
+    /// Lets the per-session limit prune handoff snapshots too.
+    #[must_use]
+    pub const fn prune_handoffs(mut self) -> Self {
+        self.keeps_handoffs = false;
+        self
+    }
+
+    /// Expires periodic snapshots `days` days after capture.
+    #[must_use]
+    pub const fn expire_periodic_after_days(mut self, days: NonZeroU32) -> Self {
+        self.periodic_max_age_days = Some(days);
+        self
+    }
+
+    /// Returns the capture time before which periodic snapshots have
+    /// expired at `now`, if they expire at all.
+    #[must_use]
+    pub fn periodic_cutoff(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.periodic_max_age_days
+            .map(|days| now - Duration::days(i64::from(days.get())))
+    }
+
+    /// Returns the snapshots of one session the per-session limit prunes,
+    /// oldest first.
+    ///
+    /// The latest snapshot of each type is kept, so the latest compaction
+    /// and pause snapshots stay available, as are handoff snapshots unless
+    /// [`prune_handoffs`](Self::prune_handoffs) was called, and the
+    /// ancestors of every kept increment.
+    #[must_use]
+    pub fn prunable(&self, session_snapshots: &[ContextWindowSnapshot]) -> Vec<Uuid> {
+        let Some(keep_latest) = self.keep_latest else {
+            return Vec::new();
+        };
+        let mut newest_first: Vec<&ContextWindowSnapshot> = session_snapshots.iter().collect();
+        newest_first.sort_by_key(|snapshot| (snapshot.captured_at, snapshot.snapshot_id));
+        newest_first.reverse();
+
+        let mut seen_types = HashSet::new();
+        let mut kept: HashSet<Uuid> = HashSet::new();
+        for (position, snapshot) in newest_first.iter().enumerate() {
+            let latest_of_type = seen_types.insert(snapshot.snapshot_type);
+            let handoff =
+                self.keeps_handoffs && snapshot.snapshot_type == SnapshotType::HandoffInitiated;
+            if position < keep_latest.get() || latest_of_type || handoff {
+                kept.insert(snapshot.snapshot_id);
+            }
+        }
+        keep_ancestors(&mut kept, session_snapshots);
+
+        newest_first
+            .iter()
+            .rev()
+            .map(|snapshot| snapshot.snapshot_id)
+            .filter(|id| !kept.contains(id))
+            .collect()
+    }
+}
+
+/// Adds to `kept` the snapshots in `snapshots` that a kept snapshot extends.
+fn keep_ancestors(kept: &mut HashSet<Uuid>, snapshots: &[ContextWindowSnapshot]) {
+    let parents: HashMap<Uuid, Uuid> = snapshots
+        .iter()
+        .filter_map(|snapshot| Some((snapshot.snapshot_id, snapshot.parent_snapshot_id?)))
+        .collect();
+    let mut pending: Vec<Uuid> = kept.iter().copied().collect();
+    while let Some(id) = pending.pop() {
+        if let Some(parent) = parents.get(&id)
+            && kept.insert(*parent)
+        {
+            pending.push(*parent);
+        }
+    }
+}
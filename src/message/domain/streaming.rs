@@ -0,0 +1,238 @@
+//! Assistant messages staged while their content streams in.
+//!
+//! A [`Message`] is immutable and is only stored once complete, but agent
+//! runtimes deliver responses a few tokens at a time. A [`PartialMessage`]
+//! stages the text received so far under the message's final identifier.
+//! Each [`StreamChunk`] carries its position in the stream, so a chunk
+//! delivered twice is recognised and ignored, and a chunk that skips ahead
+//! is refused rather than stored out of order. When the stream ends, the
+//! staged text becomes a canonical assistant message.
+//!
+//! A stream whose producer crashed stops receiving chunks. Such streams are
+//! finalised with the text they had, flagged under
+//! [`STREAM_INTERRUPTED_EXTENSION_KEY`], so readers can tell the answer was
+//! cut short.
+
+use super::{
+    ContentPart, ConversationId, Message, MessageBuilderError, MessageId, MessageMetadata, Role,
+    SequenceNumber, TextPart,
+};
+use chrono::{DateTime, Duration, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Metadata extension key set on messages finalised from interrupted
+/// streams.
+pub const STREAM_INTERRUPTED_EXTENSION_KEY: &str = "stream_interrupted";
+
+/// Largest number of stalled streams a recovery pass may return.
+pub const MAX_STALLED_STREAMS: usize = 500;
+
+/// Errors returned while staging or finalising a streamed message.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum StreamingDomainError {
+    /// The chunk does not follow the chunks already received.
+    #[error("stream {message_id} expected chunk {expected}, got chunk {received}")]
+    ChunkOutOfOrder {
+        /// The streamed message.
+        message_id: MessageId,
+        /// Index of the next chunk the stream accepts.
+        expected: u32,
+        /// Index of the chunk delivered.
+        received: u32,
+    },
+    /// The stream holds too many chunks to count.
+    #[error("stream {0} cannot accept more chunks")]
+    TooManyChunks(MessageId),
+    /// The stream ended without any text.
+    #[error("stream {0} ended without content")]
+    EmptyStream(MessageId),
+    /// The staged text could not form a message.
+    #[error(transparent)]
+    Message(#[from] MessageBuilderError),
+}
+
+/// One piece of a streamed response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamChunk {
+    /// Zero-based position of the chunk in the stream.
+    pub index: u32,
+    /// The text the chunk adds.
+    pub text: String,
+}
+
+impl StreamChunk {
+    /// Creates the chunk at position `index`.
+    #[must_use]
+    pub fn new(index: u32, text: impl Into<String>) -> Self {
+        Self {
+            index,
+            text: text.into(),
+        }
+    }
+}
+
+/// What happened to a chunk handed to [`PartialMessage::append`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkAppend {
+    /// The chunk's text was added to the stream.
+    Appended,
+    /// The stream already held the chunk, so nothing changed.
+    Duplicate,
+}
+
+/// An assistant message whose content is still streaming in.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     ChunkAppend, ConversationId, MessageMetadata, PartialMessage, Role, StreamChunk,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let mut partial = PartialMessage::start(
+///     ConversationId::new(),
+///     MessageMetadata::with_agent_backend("claude_code_sdk"),
+///     &DefaultClock,
+/// );
+/// partial.append(&StreamChunk::new(0, "Deploying "), &DefaultClock)?;
+/// partial.append(&StreamChunk::new(1, "now."), &DefaultClock)?;
+/// let resent = partial.append(&StreamChunk::new(1, "now."), &DefaultClock)?;
+///
+/// assert_eq!(resent, ChunkAppend::Duplicate);
+/// let message = partial.finalise(&DefaultClock)?;
+/// assert_eq!(message.id(), partial.message_id);
+/// assert_eq!(message.role(), Role::Assistant);
+/// # Ok::<(), corbusier::message::domain::StreamingDomainError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartialMessage {
+    /// Identifier the finalised message will carry.
+    pub message_id: MessageId,
+    /// Conversation the message will join.
+    pub conversation_id: ConversationId,
+    /// Metadata the finalised message will carry.
+    pub metadata: MessageMetadata,
+    /// Text received so far.
+    pub content: String,
+    /// Number of chunks received so far.
+    pub chunk_count: u32,
+    /// When the stream started.
+    pub started_at: DateTime<Utc>,
+    /// When the stream last received a chunk.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PartialMessage {
+    /// Starts an empty stream for a new assistant message.
+    #[must_use]
+    pub fn start(
+        conversation_id: ConversationId,
+        metadata: MessageMetadata,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        let now = clock.utc();
+        Self {
+            message_id: MessageId::new(),
+            conversation_id,
+            metadata,
+            content: String::new(),
+            chunk_count: 0,
+            started_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Adds `chunk` to the stream.
+    ///
+    /// A chunk the stream already holds is reported as
+    /// [`ChunkAppend::Duplicate`] and leaves the stream unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingDomainError::ChunkOutOfOrder`] when chunks before
+    /// `chunk` are missing, or [`StreamingDomainError::TooManyChunks`] when
+    /// the chunk count would overflow.
+    pub fn append(
+        &mut self,
+        chunk: &StreamChunk,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<ChunkAppend, StreamingDomainError> {
+        if chunk.index < self.chunk_count {
+            return Ok(ChunkAppend::Duplicate);
+        }
+        if chunk.index > self.chunk_count {
+            return Err(StreamingDomainError::ChunkOutOfOrder {
+                message_id: self.message_id,
+                expected: self.chunk_count,
+                received: chunk.index,
+            });
+        }
+        self.chunk_count = self
+            .chunk_count
+            .checked_add(1)
+            .ok_or(StreamingDomainError::TooManyChunks(self.message_id))?;
+        self.content.push_str(&chunk.text);
+        self.updated_at = clock.utc();
+        Ok(ChunkAppend::Appended)
+    }
+
+    /// Returns whether the stream has received nothing for `idle_for`.
+    #[must_use]
+    pub fn is_stalled(&self, idle_for: Duration, now: DateTime<Utc>) -> bool {
+        self.updated_at + idle_for <= now
+    }
+
+    /// Builds the canonical assistant message from the staged text.
+    ///
+    /// The message carries a placeholder sequence number; repositories
+    /// allocate the real one when they append it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingDomainError::EmptyStream`] when no text was
+    /// received.
+    pub fn finalise(&self, clock: &impl Clock) -> Result<Message, StreamingDomainError> {
+        self.build(self.metadata.clone(), clock)
+    }
+
+    /// Builds the message from a stream whose producer stopped before it
+    /// ended, flagging it under [`STREAM_INTERRUPTED_EXTENSION_KEY`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingDomainError::EmptyStream`] when no text was
+    /// received.
+    pub fn finalise_interrupted(
+        &self,
+        clock: &impl Clock,
+    ) -> Result<Message, StreamingDomainError> {
+        let mut metadata = self.metadata.clone();
+        metadata.extensions.insert(
+            STREAM_INTERRUPTED_EXTENSION_KEY.to_owned(),
+            serde_json::Value::Bool(true),
+        );
+        self.build(metadata, clock)
+    }
+
+    fn build(
+        &self,
+        metadata: MessageMetadata,
+        clock: &impl Clock,
+    ) -> Result<Message, StreamingDomainError> {
+        if self.content.is_empty() {
+            return Err(StreamingDomainError::EmptyStream(self.message_id));
+        }
+        Ok(Message::builder(
+            self.conversation_id,
+            Role::Assistant,
+            SequenceNumber::new(1),
+        )
+        .with_id(self.message_id)
+        .with_content(ContentPart::Text(TextPart::new(self.content.as_str())))
+        .with_metadata(metadata)
+        .build(clock)?)
+    }
+}
@@ -0,0 +1,87 @@
+//! Token counts of messages and conversations.
+//!
+//! Context windows are measured in model tokens rather than bytes, so each
+//! message records how many tokens its content encodes to and which encoding
+//! measured it. Conversations report the sum over their counted messages.
+
+use serde::{Deserialize, Serialize};
+
+/// How many model tokens a message's content encodes to.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::MessageTokenCount;
+///
+/// let count = MessageTokenCount::new(42, "cl100k_base");
+/// assert_eq!(count.tokens, 42);
+/// assert_eq!(count.encoding, "cl100k_base");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageTokenCount {
+    /// Number of tokens in the message's content.
+    pub tokens: u64,
+    /// Name of the encoding that produced the count, such as `cl100k_base`.
+    pub encoding: String,
+}
+
+impl MessageTokenCount {
+    /// Creates a count measured with `encoding`.
+    #[must_use]
+    pub fn new(tokens: u64, encoding: impl Into<String>) -> Self {
+        Self {
+            tokens,
+            encoding: encoding.into(),
+        }
+    }
+}
+
+/// Aggregate token usage of a conversation's messages.
+///
+/// Messages stored before token counting was enabled carry no count; they
+/// are reported as uncounted rather than as zero tokens.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{ConversationTokenUsage, MessageTokenCount};
+///
+/// let mut usage = ConversationTokenUsage::default();
+/// usage.record(Some(&MessageTokenCount::new(12, "cl100k_base")));
+/// usage.record(None);
+/// assert_eq!(usage.tokens, 12);
+/// assert_eq!(usage.uncounted_messages(), 1);
+/// assert!(!usage.is_complete());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationTokenUsage {
+    /// Number of messages in the conversation.
+    pub message_count: u64,
+    /// Number of messages that carry a token count.
+    pub counted_messages: u64,
+    /// Sum of the counted messages' tokens.
+    pub tokens: u64,
+}
+
+impl ConversationTokenUsage {
+    /// Adds one message, with its token count if it has one.
+    pub fn record(&mut self, count: Option<&MessageTokenCount>) {
+        self.message_count = self.message_count.saturating_add(1);
+        if let Some(counted) = count {
+            self.counted_messages = self.counted_messages.saturating_add(1);
+            self.tokens = self.tokens.saturating_add(counted.tokens);
+        }
+    }
+
+    /// Returns the number of messages without a token count.
+    #[must_use]
+    pub const fn uncounted_messages(&self) -> u64 {
+        self.message_count.saturating_sub(self.counted_messages)
+    }
+
+    /// Returns `true` if every message carries a token count.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.counted_messages == self.message_count
+    }
+}
@@ -0,0 +1,235 @@
+//! Consistency of tool calls and their results across a conversation.
+//!
+//! Each [`ToolResultPart`](super::ToolResultPart) answers the
+//! [`ToolCallPart`](super::ToolCallPart) with the same `call_id`, which an
+//! earlier message must have made. [`ToolCallPairing`] walks a
+//! conversation's history in order and reports every result that answers
+//! no earlier call, every call id used twice, every call answered twice,
+//! and every call left without an answer.
+
+use super::{ContentPart, Message, MessageId, ToolCallPart, ToolResultPart};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A break in the pairing of tool calls and results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolCallPairingIssue {
+    /// A result names a call no earlier message made.
+    UnknownCall {
+        /// The call id the result names.
+        call_id: String,
+        /// The message holding the result.
+        result_message: MessageId,
+    },
+    /// A call reuses the id of an earlier call.
+    DuplicateCall {
+        /// The repeated call id.
+        call_id: String,
+        /// The message holding the repeated call.
+        call_message: MessageId,
+    },
+    /// A call is answered more than once.
+    DuplicateResult {
+        /// The call id answered again.
+        call_id: String,
+        /// The message holding the extra result.
+        result_message: MessageId,
+    },
+    /// A call has no result.
+    OrphanedCall {
+        /// The unanswered call id.
+        call_id: String,
+        /// The name of the tool called.
+        tool_name: String,
+        /// The message holding the call.
+        call_message: MessageId,
+    },
+}
+
+impl fmt::Display for ToolCallPairingIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCall {
+                call_id,
+                result_message,
+            } => write!(
+                f,
+                "message {result_message} answers call {call_id}, which was never made"
+            ),
+            Self::DuplicateCall {
+                call_id,
+                call_message,
+            } => write!(f, "message {call_message} reuses call id {call_id}"),
+            Self::DuplicateResult {
+                call_id,
+                result_message,
+            } => write!(f, "message {result_message} answers call {call_id} again"),
+            Self::OrphanedCall {
+                call_id,
+                tool_name,
+                call_message,
+            } => write!(
+                f,
+                "call {call_id} to {tool_name} in message {call_message} has no result"
+            ),
+        }
+    }
+}
+
+/// Report on how a conversation's tool calls pair with their results.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     ContentPart, ConversationId, Message, Role, SequenceNumber, ToolCallPairing,
+///     ToolCallPart, ToolResultPart,
+/// };
+/// use mockable::DefaultClock;
+/// use serde_json::json;
+///
+/// let conversation_id = ConversationId::new();
+/// let say = |role, part, seq| {
+///     Message::new(conversation_id, role, vec![part], SequenceNumber::new(seq), &DefaultClock)
+///         .expect("valid message")
+/// };
+/// let history = [
+///     say(Role::Assistant, ContentPart::ToolCall(ToolCallPart::new("c1", "ls", json!({}))), 1),
+///     say(Role::Tool, ContentPart::ToolResult(ToolResultPart::success("c1", json!([]))), 2),
+///     say(Role::Assistant, ContentPart::ToolCall(ToolCallPart::new("c2", "cat", json!({}))), 3),
+/// ];
+///
+/// let pairing = ToolCallPairing::check(&history);
+/// assert_eq!(pairing.paired_calls(), 1);
+/// assert_eq!(pairing.orphaned_calls().count(), 1);
+/// assert!(!pairing.is_consistent());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ToolCallPairing {
+    paired_calls: usize,
+    issues: Vec<ToolCallPairingIssue>,
+}
+
+impl ToolCallPairing {
+    /// Checks the pairing of calls and results in `messages`, which must be
+    /// in sequence order.
+    #[must_use]
+    pub fn check(messages: &[Message]) -> Self {
+        let mut walk = HistoryWalk::default();
+        for message in messages {
+            for part in message.content() {
+                match part {
+                    ContentPart::ToolCall(call) => walk.call(call, message.id()),
+                    ContentPart::ToolResult(result) => walk.result(result, message.id()),
+                    _ => {}
+                }
+            }
+        }
+        walk.finish()
+    }
+
+    /// Returns the number of calls answered exactly once.
+    #[must_use]
+    pub const fn paired_calls(&self) -> usize {
+        self.paired_calls
+    }
+
+    /// Returns every issue found, in the order of the history, with
+    /// orphaned calls last.
+    #[must_use]
+    pub fn issues(&self) -> &[ToolCallPairingIssue] {
+        &self.issues
+    }
+
+    /// Returns the calls left without a result.
+    pub fn orphaned_calls(&self) -> impl Iterator<Item = &ToolCallPairingIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| matches!(issue, ToolCallPairingIssue::OrphanedCall { .. }))
+    }
+
+    /// Returns `true` when every call is answered exactly once and every
+    /// result answers an earlier call.
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// A call seen while walking the history, and whether it has been answered.
+struct IssuedCall<'a> {
+    tool_name: &'a str,
+    message: MessageId,
+    answered: bool,
+}
+
+/// State gathered while walking a history in order.
+#[derive(Default)]
+struct HistoryWalk<'a> {
+    calls: BTreeMap<&'a str, IssuedCall<'a>>,
+    order: Vec<&'a str>,
+    issues: Vec<ToolCallPairingIssue>,
+    paired_calls: usize,
+}
+
+impl<'a> HistoryWalk<'a> {
+    fn call(&mut self, call: &'a ToolCallPart, message: MessageId) {
+        if self.calls.contains_key(call.call_id.as_str()) {
+            self.issues.push(ToolCallPairingIssue::DuplicateCall {
+                call_id: call.call_id.clone(),
+                call_message: message,
+            });
+            return;
+        }
+        self.order.push(&call.call_id);
+        self.calls.insert(
+            &call.call_id,
+            IssuedCall {
+                tool_name: &call.name,
+                message,
+                answered: false,
+            },
+        );
+    }
+
+    fn result(&mut self, result: &ToolResultPart, message: MessageId) {
+        let call_id = result.call_id.clone();
+        match self.calls.get_mut(result.call_id.as_str()) {
+            None => self.issues.push(ToolCallPairingIssue::UnknownCall {
+                call_id,
+                result_message: message,
+            }),
+            Some(issued) if issued.answered => {
+                self.issues.push(ToolCallPairingIssue::DuplicateResult {
+                    call_id,
+                    result_message: message,
+                });
+            }
+            Some(issued) => {
+                issued.answered = true;
+                self.paired_calls += 1;
+            }
+        }
+    }
+
+    fn finish(self) -> ToolCallPairing {
+        let Self {
+            calls,
+            order,
+            mut issues,
+            paired_calls,
+        } = self;
+        issues.extend(order.into_iter().filter_map(|call_id| {
+            let issued = calls.get(call_id)?;
+            (!issued.answered).then(|| ToolCallPairingIssue::OrphanedCall {
+                call_id: call_id.to_owned(),
+                tool_name: issued.tool_name.to_owned(),
+                call_message: issued.message,
+            })
+        }));
+        ToolCallPairing {
+            paired_calls,
+            issues,
+        }
+    }
+}
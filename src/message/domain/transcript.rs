@@ -0,0 +1,84 @@
+//! Conversation transcripts recorded in a foreign wire format.
+//!
+//! Transcripts arrive in the request formats of other model providers. A
+//! converter turns each one into [`TranscriptMessage`]s, canonical roles and
+//! content parts ready to be stored, and every stored message records the
+//! [`TranscriptFormat`] it came from and its position in the transcript.
+
+use super::{ContentPart, MessageMetadata, Role};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Extension key under which the provenance of an ingested transcript
+/// message is stored in [`MessageMetadata::extensions`].
+pub const INBOUND_TRANSCRIPT_EXTENSION_KEY: &str = "inbound.transcript.v1";
+
+/// A wire format transcripts are ingested from.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{INBOUND_TRANSCRIPT_EXTENSION_KEY, TranscriptFormat};
+///
+/// let metadata = TranscriptFormat::OpenAiChat.to_metadata(2);
+/// let provenance = &metadata.extensions[INBOUND_TRANSCRIPT_EXTENSION_KEY];
+/// assert_eq!(provenance["format"], "openai_chat");
+/// assert_eq!(provenance["position"], 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TranscriptFormat {
+    /// The `messages` of an `OpenAI` chat completions request, optionally
+    /// followed by the `choices` of its response.
+    #[serde(rename = "openai_chat")]
+    OpenAiChat,
+    /// The `system` prompt and `messages` of an Anthropic messages request.
+    #[serde(rename = "anthropic_messages")]
+    AnthropicMessages,
+}
+
+impl TranscriptFormat {
+    /// Returns the format's stable name.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::OpenAiChat => "openai_chat",
+            Self::AnthropicMessages => "anthropic_messages",
+        }
+    }
+
+    /// Returns message metadata recording that a message sat at `position`
+    /// in a transcript of this format, under
+    /// [`INBOUND_TRANSCRIPT_EXTENSION_KEY`].
+    #[must_use]
+    pub fn to_metadata(self, position: usize) -> MessageMetadata {
+        let mut metadata = MessageMetadata::empty();
+        metadata.extensions.insert(
+            INBOUND_TRANSCRIPT_EXTENSION_KEY.to_owned(),
+            serde_json::json!({ "format": self, "position": position }),
+        );
+        metadata
+    }
+}
+
+impl fmt::Display for TranscriptFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One message of a converted transcript.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptMessage {
+    /// Canonical role of the message.
+    pub role: Role,
+    /// Canonical content of the message.
+    pub content: Vec<ContentPart>,
+}
+
+impl TranscriptMessage {
+    /// Creates a transcript message.
+    #[must_use]
+    pub const fn new(role: Role, content: Vec<ContentPart>) -> Self {
+        Self { role, content }
+    }
+}
@@ -0,0 +1,161 @@
+//! Moving a conversation from one tenant to another.
+//!
+//! A transfer is an administrative operation: it requires
+//! [`ConversationAccess::Elevated`] and an operator reason, and it is
+//! audited on both sides. The source tenant records a
+//! [`OperatorActionKind::ConversationTransferredOut`] action and the target
+//! tenant a [`OperatorActionKind::ConversationTransferredIn`] action, each
+//! carrying the tenant move as its transition. Repositories write both
+//! records in the same transaction that moves the conversation.
+//!
+//! Message content is stored unencrypted, under no per-tenant key, so a
+//! transfer moves rows without re-keying them.
+
+use super::{ConversationAccess, ConversationId};
+use crate::context::{RequestContext, TenantId};
+use crate::operator::domain::{
+    OperatorAction, OperatorActionDetails, OperatorActionKind, OperatorReason, OperatorSubject,
+    OperatorTransition,
+};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors raised when a transfer request is refused before any data moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ConversationTransferRefused {
+    /// Only elevated callers may move conversations between tenants.
+    #[error("transferring conversation {0} requires elevated access")]
+    ElevatedAccessRequired(ConversationId),
+    /// The target tenant is the tenant that already owns the conversation.
+    #[error("conversation {conversation_id} already belongs to tenant {tenant_id}")]
+    SameTenant {
+        /// The conversation.
+        conversation_id: ConversationId,
+        /// The tenant named as both source and target.
+        tenant_id: TenantId,
+    },
+}
+
+/// A request to move a conversation out of the caller's tenant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversationTransferRequest {
+    /// The conversation to move.
+    pub conversation_id: ConversationId,
+    /// The tenant that will own the conversation.
+    pub target_tenant_id: TenantId,
+    /// Why the operator is moving it.
+    pub reason: OperatorReason,
+}
+
+/// An accepted conversation transfer, with the operator actions recorded on
+/// each side.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+/// use corbusier::message::domain::{
+///     ConversationAccess, ConversationId, ConversationTransfer, ConversationTransferRequest,
+/// };
+/// use corbusier::operator::domain::{OperatorActionKind, OperatorReason};
+/// use mockable::DefaultClock;
+///
+/// let ctx = RequestContext::new(
+///     TenantId::new(),
+///     CorrelationId::new(),
+///     UserId::new(),
+///     SessionId::new(),
+/// );
+/// let target_tenant_id = TenantId::new();
+/// let transfer = ConversationTransfer::new(
+///     &ctx,
+///     ConversationTransferRequest {
+///         conversation_id: ConversationId::new(),
+///         target_tenant_id,
+///         reason: OperatorReason::new("customer account merge").expect("valid reason"),
+///     },
+///     ConversationAccess::Elevated,
+///     &DefaultClock,
+/// )
+/// .expect("elevated transfer to another tenant");
+///
+/// assert_eq!(transfer.source_tenant_id, ctx.tenant_id());
+/// assert_eq!(transfer.outgoing.kind, OperatorActionKind::ConversationTransferredOut);
+/// assert_eq!(transfer.incoming.kind, OperatorActionKind::ConversationTransferredIn);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationTransfer {
+    /// The conversation moved.
+    pub conversation_id: ConversationId,
+    /// The tenant the conversation leaves.
+    pub source_tenant_id: TenantId,
+    /// The tenant the conversation joins.
+    pub target_tenant_id: TenantId,
+    /// The action recorded for the source tenant.
+    pub outgoing: OperatorAction,
+    /// The action recorded for the target tenant.
+    pub incoming: OperatorAction,
+}
+
+impl ConversationTransfer {
+    /// Accepts `request` as made now by the user in `ctx`, moving the
+    /// conversation out of `ctx`'s tenant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationTransferRefused::ElevatedAccessRequired`] unless
+    /// `access` is elevated, or [`ConversationTransferRefused::SameTenant`]
+    /// when the target is the caller's own tenant.
+    pub fn new(
+        ctx: &RequestContext,
+        request: ConversationTransferRequest,
+        access: ConversationAccess,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<Self, ConversationTransferRefused> {
+        let ConversationTransferRequest {
+            conversation_id,
+            target_tenant_id,
+            reason,
+        } = request;
+        if access != ConversationAccess::Elevated {
+            return Err(ConversationTransferRefused::ElevatedAccessRequired(
+                conversation_id,
+            ));
+        }
+        let source_tenant_id = ctx.tenant_id();
+        if target_tenant_id == source_tenant_id {
+            return Err(ConversationTransferRefused::SameTenant {
+                conversation_id,
+                tenant_id: source_tenant_id,
+            });
+        }
+        let details = |kind| OperatorActionDetails {
+            kind,
+            subject: OperatorSubject::Conversation {
+                conversation_id,
+                task_id: None,
+            },
+            reason: reason.clone(),
+            transition: Some(OperatorTransition::new(source_tenant_id, target_tenant_id)),
+        };
+        let outgoing = OperatorAction::new(
+            ctx,
+            details(OperatorActionKind::ConversationTransferredOut),
+            clock,
+        );
+        let mut incoming = OperatorAction::new(
+            ctx,
+            details(OperatorActionKind::ConversationTransferredIn),
+            clock,
+        );
+        incoming.occurred_at = outgoing.occurred_at;
+        Ok(Self {
+            conversation_id,
+            source_tenant_id,
+            target_tenant_id,
+            outgoing,
+            incoming,
+        })
+    }
+}
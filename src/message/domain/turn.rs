@@ -0,0 +1,283 @@
+//! Turns: one exchange between a user and an agent session.
+//!
+//! A [`Turn`] starts with the message that prompted the agent and gathers
+//! the messages the agent wrote in response, together with the tool calls
+//! those responses made, until the turn completes or fails. Messages name
+//! their turn through [`MessageMetadata::turn_id`](super::MessageMetadata).
+
+use chrono::{DateTime, Duration, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{
+    AgentSession, AgentSessionId, ContentPart, ConversationId, Message, MessageId,
+    ToolCallReference, TurnId,
+};
+
+/// One exchange between a user and an agent session.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{
+///     AgentSession, ContentPart, ConversationId, Message, Role, SequenceNumber, TextPart,
+///     Turn, TurnStatus,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let conversation_id = ConversationId::new();
+/// let session = AgentSession::new(conversation_id, "claude", SequenceNumber::new(1), &DefaultClock);
+/// let prompt = Message::new(
+///     conversation_id,
+///     Role::User,
+///     vec![ContentPart::Text(TextPart::new("Fix the build"))],
+///     SequenceNumber::new(1),
+///     &DefaultClock,
+/// )?;
+///
+/// let mut turn = Turn::start(&session, &prompt, &DefaultClock)?;
+/// turn.complete(&DefaultClock)?;
+///
+/// assert_eq!(turn.status, TurnStatus::Completed);
+/// assert!(turn.duration().is_some());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Turn {
+    /// Unique identifier for this turn.
+    pub turn_id: TurnId,
+    /// The conversation the turn belongs to.
+    pub conversation_id: ConversationId,
+    /// The agent session that handled the turn.
+    pub session_id: AgentSessionId,
+    /// The message that started the turn.
+    pub initiating_message_id: MessageId,
+    /// The messages written in response, in the order they were recorded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub response_message_ids: Vec<MessageId>,
+    /// The tool calls the responses made.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<ToolCallReference>,
+    /// Where the turn is in its lifecycle.
+    pub status: TurnStatus,
+    /// When the turn started.
+    pub started_at: DateTime<Utc>,
+    /// When the turn completed or failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl Turn {
+    /// Starts a turn in `session`, prompted by `initiating_message`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnStateError::ConversationMismatch`] when the message
+    /// belongs to another conversation than the session.
+    pub fn start(
+        session: &AgentSession,
+        initiating_message: &Message,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<Self, TurnStateError> {
+        let turn_id = TurnId::new();
+        check_conversation(turn_id, session.conversation_id, initiating_message)?;
+        Ok(Self {
+            turn_id,
+            conversation_id: session.conversation_id,
+            session_id: session.session_id,
+            initiating_message_id: initiating_message.id(),
+            response_message_ids: Vec::new(),
+            tool_calls: Vec::new(),
+            status: TurnStatus::InProgress,
+            started_at: clock.utc(),
+            ended_at: None,
+        })
+    }
+
+    /// Records `message` as a response within the turn, along with the tool
+    /// calls it makes. Recording a message twice has no further effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnStateError::Ended`] when the turn is over, or
+    /// [`TurnStateError::ConversationMismatch`] when the message belongs to
+    /// another conversation.
+    pub fn record_response(&mut self, message: &Message) -> Result<(), TurnStateError> {
+        self.check_in_progress()?;
+        check_conversation(self.turn_id, self.conversation_id, message)?;
+        if self.contains_message(message.id()) {
+            return Ok(());
+        }
+        self.response_message_ids.push(message.id());
+        self.tool_calls.extend(Self::response_tool_calls(message));
+        Ok(())
+    }
+
+    /// Returns the references a turn records for the tool calls `message`
+    /// makes, in content order.
+    #[must_use]
+    pub fn response_tool_calls(message: &Message) -> Vec<ToolCallReference> {
+        message
+            .content()
+            .iter()
+            .filter_map(|part| match part {
+                ContentPart::ToolCall(call) => Some(ToolCallReference::new(
+                    &call.call_id,
+                    &call.name,
+                    message.id(),
+                    message.sequence_number(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Marks the turn completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnStateError::Ended`] when the turn is already over.
+    pub fn complete(&mut self, clock: &(impl Clock + ?Sized)) -> Result<(), TurnStateError> {
+        self.end(TurnStatus::Completed, clock)
+    }
+
+    /// Marks the turn failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnStateError::Ended`] when the turn is already over.
+    pub fn fail(&mut self, clock: &(impl Clock + ?Sized)) -> Result<(), TurnStateError> {
+        self.end(TurnStatus::Failed, clock)
+    }
+
+    /// Returns `true` if `message_id` started the turn or responded within
+    /// it.
+    #[must_use]
+    pub fn contains_message(&self, message_id: MessageId) -> bool {
+        self.initiating_message_id == message_id || self.response_message_ids.contains(&message_id)
+    }
+
+    /// Returns how long the turn took, once it has ended.
+    #[must_use]
+    pub fn duration(&self) -> Option<Duration> {
+        self.ended_at.map(|ended_at| ended_at - self.started_at)
+    }
+
+    fn end(
+        &mut self,
+        status: TurnStatus,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<(), TurnStateError> {
+        self.check_in_progress()?;
+        self.status = status;
+        self.ended_at = Some(clock.utc());
+        Ok(())
+    }
+
+    const fn check_in_progress(&self) -> Result<(), TurnStateError> {
+        if self.status.is_terminal() {
+            return Err(TurnStateError::Ended {
+                turn_id: self.turn_id,
+                status: self.status,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn check_conversation(
+    turn_id: TurnId,
+    conversation_id: ConversationId,
+    message: &Message,
+) -> Result<(), TurnStateError> {
+    if message.conversation_id() == conversation_id {
+        return Ok(());
+    }
+    Err(TurnStateError::ConversationMismatch {
+        turn_id,
+        message_id: message.id(),
+    })
+}
+
+/// Lifecycle status of a turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TurnStatus {
+    /// The agent is still responding.
+    InProgress,
+    /// The agent finished responding.
+    Completed,
+    /// The turn ended without the agent finishing.
+    Failed,
+}
+
+impl TurnStatus {
+    /// Returns `true` if the turn is over.
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+
+    /// Returns the status as a string slice.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::InProgress => "in_progress",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl std::fmt::Display for TurnStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Error returned when parsing an invalid turn status string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTurnStatusError(String);
+
+impl std::fmt::Display for ParseTurnStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid turn status: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseTurnStatusError {}
+
+impl TryFrom<&str> for TurnStatus {
+    type Error = ParseTurnStatusError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "in_progress" => Ok(Self::InProgress),
+            "completed" => Ok(Self::Completed),
+            "failed" => Ok(Self::Failed),
+            _ => Err(ParseTurnStatusError(s.to_owned())),
+        }
+    }
+}
+
+/// Errors raised when a turn refuses a change.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TurnStateError {
+    /// The turn has already completed or failed.
+    #[error("turn {turn_id} has already ended as {status}")]
+    Ended {
+        /// The turn.
+        turn_id: TurnId,
+        /// The status the turn ended with.
+        status: TurnStatus,
+    },
+    /// The message belongs to another conversation than the turn.
+    #[error("message {message_id} is not part of the conversation of turn {turn_id}")]
+    ConversationMismatch {
+        /// The turn.
+        turn_id: TurnId,
+        /// The message.
+        message_id: MessageId,
+    },
+}
@@ -0,0 +1,55 @@
+//! Errors raised while encoding or decoding events in a wire format.
+
+use crate::message::versioning::EventFormat;
+use thiserror::Error;
+
+/// Errors that can occur while encoding or decoding a versioned event.
+#[derive(Debug, Error)]
+pub enum EventCodecError {
+    /// The format's codec is not registered, usually because its cargo
+    /// feature is disabled in this build.
+    #[error("no codec is registered for the {0} format")]
+    UnsupportedFormat(EventFormat),
+
+    /// The bytes do not start like an event in any known format.
+    #[error("encoded event is in no recognised format")]
+    UnrecognisedFormat,
+
+    /// The event could not be encoded.
+    #[error("cannot encode event as {format}: {reason}")]
+    Encode {
+        /// The format asked for.
+        format: EventFormat,
+        /// Description of the failure.
+        reason: String,
+    },
+
+    /// The bytes could not be decoded as an event.
+    #[error("cannot decode {format} event: {reason}")]
+    Decode {
+        /// The format the bytes were detected as.
+        format: EventFormat,
+        /// Description of the failure.
+        reason: String,
+    },
+}
+
+impl EventCodecError {
+    /// Creates an encoding error.
+    #[must_use]
+    pub fn encode(format: EventFormat, reason: impl ToString) -> Self {
+        Self::Encode {
+            format,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Creates a decoding error.
+    #[must_use]
+    pub fn decode(format: EventFormat, reason: impl ToString) -> Self {
+        Self::Decode {
+            format,
+            reason: reason.to_string(),
+        }
+    }
+}
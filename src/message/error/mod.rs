@@ -3,10 +3,22 @@
 //! Uses `thiserror` for ergonomic error handling with typed variants
 //! that can be inspected by callers.
 
-use super::domain::{ConversationId, MessageId, SequenceNumber};
+use super::domain::{
+    ConversationId, MessageId, SensitiveSpan, SequenceNumber, SequenceNumberError,
+};
+use super::ports::content_cipher::CipherError;
+use crate::maintenance::domain::MaintenanceMode;
 use std::sync::Arc;
 use thiserror::Error;
 
+mod codec;
+mod schema;
+mod schema_upgrade;
+
+pub use codec::EventCodecError;
+pub use schema::{MessageSchemaError, SchemaViolation};
+pub use schema_upgrade::SchemaUpgradeError;
+
 /// Errors that can occur during message validation.
 #[derive(Debug, Clone, Error)]
 pub enum ValidationError {
@@ -73,6 +85,15 @@ pub enum ValidationError {
         limit_bytes: usize,
     },
 
+    /// The message's attachments together exceed the size limit.
+    #[error("attachments total {actual_bytes} bytes, exceeding the limit of {limit_bytes} bytes")]
+    AttachmentsTooLarge {
+        /// The combined decoded size of the attachments.
+        actual_bytes: usize,
+        /// The maximum allowed combined size.
+        limit_bytes: usize,
+    },
+
     /// The message has too many content parts.
     #[error("message has {actual} content parts, exceeds limit of {max}")]
     TooManyContentParts {
@@ -82,6 +103,10 @@ pub enum ValidationError {
         actual: usize,
     },
 
+    /// The message holds sensitive data that screening rejects.
+    #[error("message contains sensitive data: {}", format_spans(.0))]
+    SensitiveData(Vec<SensitiveSpan>),
+
     /// The message references a non-existent conversation.
     #[error("conversation not found")]
     ConversationNotFound,
@@ -91,6 +116,14 @@ pub enum ValidationError {
     Multiple(Vec<Self>),
 }
 
+fn format_spans(spans: &[SensitiveSpan]) -> String {
+    spans
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn format_errors(errors: &[ValidationError]) -> String {
     errors
         .iter()
@@ -158,6 +191,10 @@ pub enum RepositoryError {
     #[error("conversation not found: {0}")]
     ConversationNotFound(ConversationId),
 
+    /// The conversation is archived and rejects new messages.
+    #[error("conversation is archived: {0}")]
+    ConversationArchived(ConversationId),
+
     /// The message was not found.
     #[error("message not found: {0}")]
     NotFound(MessageId),
@@ -166,6 +203,10 @@ pub enum RepositoryError {
     #[error("duplicate message: {0}")]
     DuplicateMessage(MessageId),
 
+    /// The message has already been redacted.
+    #[error("message already redacted: {0}")]
+    AlreadyRedacted(MessageId),
+
     /// A message with this sequence number already exists in the conversation.
     #[error("duplicate sequence number {sequence} in conversation {conversation_id}")]
     DuplicateSequence {
@@ -175,6 +216,11 @@ pub enum RepositoryError {
         sequence: SequenceNumber,
     },
 
+    /// Messages in a batch conflict with stored messages or with each
+    /// other, so none of the batch was stored.
+    #[error("batch rejected: {}", format_conflicts(.0))]
+    BatchConflicts(Vec<BatchConflict>),
+
     /// A database error occurred.
     #[error("database error: {0}")]
     Database(Arc<dyn std::error::Error + Send + Sync>),
@@ -186,6 +232,18 @@ pub enum RepositoryError {
     /// A connection error occurred.
     #[error("connection error: {0}")]
     Connection(String),
+
+    /// A stored sequence number breaks the domain's non-zero invariant.
+    #[error(transparent)]
+    InvalidSequenceNumber(#[from] SequenceNumberError),
+
+    /// Sealing or opening encrypted message content failed.
+    #[error("content encryption error: {0}")]
+    Cipher(#[from] CipherError),
+
+    /// The cluster is in read-only maintenance mode.
+    #[error(transparent)]
+    MaintenanceMode(#[from] MaintenanceMode),
 }
 
 impl RepositoryError {
@@ -208,58 +266,58 @@ impl RepositoryError {
     }
 }
 
-impl From<diesel::result::Error> for RepositoryError {
-    fn from(err: diesel::result::Error) -> Self {
-        // All Diesel errors are converted to database errors.
-        // Unique constraint violations are identified but cannot provide
-        // semantic errors (DuplicateMessage/DuplicateSequence) since the
-        // constraint error doesn't include the specific IDs. Callers should
-        // use pre-check validation to get semantic errors with correct identifiers.
-        Self::database(err)
-    }
-}
-
-/// Errors that can occur during schema version upgrades.
-#[derive(Debug, Error)]
-pub enum SchemaUpgradeError {
-    /// The schema version is not supported.
-    #[error("unsupported schema version: {0}")]
-    UnsupportedVersion(u32),
-
-    /// The event type is not recognized.
-    #[error("unknown event type: {0}")]
-    UnknownEventType(String),
-
-    /// The upgrade failed.
-    #[error("upgrade from version {from} to {to} failed: {reason}")]
-    UpgradeFailed {
-        /// The source version.
-        from: u32,
-        /// The target version.
-        to: u32,
-        /// Description of the failure.
-        reason: String,
+/// A message in a batch whose ID or sequence number is already taken,
+/// either by a stored message or by an earlier message in the batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum BatchConflict {
+    /// The message ID is already taken.
+    #[error("message {index}: duplicate message ID {message_id}")]
+    DuplicateMessage {
+        /// Position of the message in the batch.
+        index: usize,
+        /// The conflicting message ID.
+        message_id: MessageId,
     },
 
-    /// The event data is malformed.
-    #[error("malformed event data: {0}")]
-    MalformedData(String),
+    /// The sequence number is already taken in the conversation.
+    #[error(
+        "message {index}: duplicate sequence number {sequence} in conversation {conversation_id}"
+    )]
+    DuplicateSequence {
+        /// Position of the message in the batch.
+        index: usize,
+        /// The conversation containing the conflict.
+        conversation_id: ConversationId,
+        /// The conflicting sequence number.
+        sequence: SequenceNumber,
+    },
 }
 
-impl SchemaUpgradeError {
-    /// Creates an upgrade failed error.
+impl BatchConflict {
+    /// Returns the position of the conflicting message in the batch.
     #[must_use]
-    pub fn upgrade_failed(from: u32, to: u32, reason: impl Into<String>) -> Self {
-        Self::UpgradeFailed {
-            from,
-            to,
-            reason: reason.into(),
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::DuplicateMessage { index, .. } | Self::DuplicateSequence { index, .. } => *index,
         }
     }
+}
 
-    /// Creates a malformed data error.
-    #[must_use]
-    pub fn malformed(message: impl Into<String>) -> Self {
-        Self::MalformedData(message.into())
+fn format_conflicts(conflicts: &[BatchConflict]) -> String {
+    conflicts
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl From<diesel::result::Error> for RepositoryError {
+    fn from(err: diesel::result::Error) -> Self {
+        // All Diesel errors are converted to database errors.
+        // Unique constraint violations are identified but cannot provide
+        // semantic errors (DuplicateMessage/DuplicateSequence) since the
+        // constraint error doesn't include the specific IDs. Callers should
+        // use pre-check validation to get semantic errors with correct identifiers.
+        Self::database(err)
     }
 }
@@ -0,0 +1,73 @@
+//! Errors raised while loading message schemas or validating against them.
+
+use std::fmt;
+use thiserror::Error;
+
+/// One way a payload fails to match a message schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// JSON pointer to the offending value; empty for the payload itself.
+    pub path: String,
+    /// What is wrong with the value.
+    pub message: String,
+}
+
+impl SchemaViolation {
+    /// Creates a violation at `path`.
+    #[must_use]
+    pub fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "payload: {}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Errors that can occur while loading a message schema or validating a
+/// payload against it.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MessageSchemaError {
+    /// No schema exists for the version.
+    #[error("no message schema has version {0}")]
+    UnknownVersion(u32),
+
+    /// The embedded schema document could not be loaded.
+    #[error("message schema version {version} is invalid: {reason}")]
+    InvalidSchema {
+        /// The schema version.
+        version: u32,
+        /// Description of the failure.
+        reason: String,
+    },
+
+    /// The schema version predates the requested type.
+    #[error("message schema version {version} does not define {schema_type}")]
+    UndefinedType {
+        /// The schema version.
+        version: u32,
+        /// The requested type.
+        schema_type: String,
+    },
+
+    /// The payload does not match the schema.
+    #[error("payload does not match the schema: {}", join_violations(.0))]
+    Violations(Vec<SchemaViolation>),
+}
+
+fn join_violations(violations: &[SchemaViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
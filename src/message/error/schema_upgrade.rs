@@ -0,0 +1,100 @@
+//! Errors raised while migrating events between schema versions.
+
+use thiserror::Error;
+
+/// Errors that can occur during schema version upgrades.
+#[derive(Debug, Error)]
+pub enum SchemaUpgradeError {
+    /// The schema version is not supported.
+    #[error("unsupported schema version: {0}")]
+    UnsupportedVersion(u32),
+
+    /// The event type is not recognized.
+    #[error("unknown event type: {0}")]
+    UnknownEventType(String),
+
+    /// The upgrade failed.
+    #[error("upgrade from version {from} to {to} failed: {reason}")]
+    UpgradeFailed {
+        /// The source version.
+        from: u32,
+        /// The target version.
+        to: u32,
+        /// Description of the failure.
+        reason: String,
+    },
+
+    /// The event data is malformed.
+    #[error("malformed event data: {0}")]
+    MalformedData(String),
+
+    /// An upgrade chain was built with no steps.
+    #[error("upgrade chain for {0} has no steps")]
+    EmptyUpgradeChain(String),
+
+    /// Two steps of an upgrade chain start at the same version.
+    #[error("upgrade chain for {event_type} has two steps from version {version}")]
+    ConflictingUpgradeSteps {
+        /// The event type the chain upgrades.
+        event_type: String,
+        /// The version both steps start at.
+        version: u32,
+    },
+
+    /// Following the steps of an upgrade chain revisits a version.
+    #[error("upgrade chain for {event_type} returns to version {version}")]
+    UpgradeCycle {
+        /// The event type the chain upgrades.
+        event_type: String,
+        /// The version reached twice.
+        version: u32,
+    },
+
+    /// The event cannot be brought back to an older version.
+    #[error("cannot downgrade from version {from} to {to}")]
+    UnsupportedDowngrade {
+        /// The event's version.
+        from: u32,
+        /// The version asked for.
+        to: u32,
+    },
+
+    /// The event is newer than the reader supports and cannot be
+    /// downgraded.
+    #[error("{event_type} version {version} is newer than the supported version {max_supported}")]
+    IncompatibleVersion {
+        /// The event type.
+        event_type: String,
+        /// The event's version.
+        version: u32,
+        /// The newest version the reader supports.
+        max_supported: u32,
+    },
+
+    /// Some steps of an upgrade chain stop short of the latest version.
+    #[error("upgrade chain for {event_type} has no step from version {version}")]
+    UpgradeGap {
+        /// The event type the chain upgrades.
+        event_type: String,
+        /// The version no step upgrades from.
+        version: u32,
+    },
+}
+
+impl SchemaUpgradeError {
+    /// Creates an upgrade failed error.
+    #[must_use]
+    pub fn upgrade_failed(from: u32, to: u32, reason: impl Into<String>) -> Self {
+        Self::UpgradeFailed {
+            from,
+            to,
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates a malformed data error.
+    #[must_use]
+    pub fn malformed(message: impl Into<String>) -> Self {
+        Self::MalformedData(message.into())
+    }
+}
@@ -12,6 +12,8 @@
 //! - **Adapters**: Concrete implementations ([`adapters::memory::InMemoryMessageRepository`], [`adapters::postgres::PostgresMessageRepository`])
 //! - **Validation**: Business rule enforcement at ingestion boundaries
 //! - **Versioning**: Schema migration support for evolving event formats
+//! - **Schema**: JSON Schemas of every message format version, for integrators
+//! - **Canonical JSON**: Stable byte representations for signing, hashing, and export
 //!
 //! # Example
 //!
@@ -34,9 +36,11 @@
 //! ```
 
 pub mod adapters;
+pub mod canonical;
 pub mod domain;
 pub mod error;
 pub mod ports;
+pub mod schema;
 pub mod services;
 pub mod validation;
 pub mod versioning;
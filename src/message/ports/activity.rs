@@ -0,0 +1,83 @@
+//! Port for conversation activity statistics.
+//!
+//! Defines the read-only interface used by dashboards to fetch per-conversation
+//! activity grouped into time buckets, without loading full message histories.
+
+use crate::context::RequestContext;
+use crate::message::domain::{ActivityQuery, ConversationActivity};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for activity statistics queries.
+pub type ActivityResult<T> = Result<T, ActivityError>;
+
+/// Port for conversation activity statistics.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Messages are bucketed by creation time and handoffs by initiation time
+/// - Buckets without activity are omitted and the rest are returned in
+///   ascending order of start time
+/// - All queries are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait ConversationActivityPort: Send + Sync {
+    /// Returns activity statistics for a conversation.
+    ///
+    /// Unknown conversations yield an empty result rather than an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActivityError::InvalidWindow`] if the query window does not
+    /// end after it starts, or [`ActivityError::Persistence`] if the
+    /// underlying store fails.
+    async fn conversation_activity(
+        &self,
+        ctx: &RequestContext,
+        query: &ActivityQuery,
+    ) -> ActivityResult<ConversationActivity>;
+}
+
+/// Errors that can occur when querying activity statistics.
+#[derive(Debug, Clone, Error)]
+pub enum ActivityError {
+    /// The query window is empty or inverted.
+    #[error("invalid activity window: {from} is not before {until}")]
+    InvalidWindow {
+        /// Requested window start.
+        from: DateTime<Utc>,
+        /// Requested window end.
+        until: DateTime<Utc>,
+    },
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl ActivityError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+
+    /// Validates a query window, returning [`Self::InvalidWindow`] when it
+    /// does not end after it starts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::InvalidWindow`] for empty or inverted windows.
+    pub fn check_window(query: &ActivityQuery) -> ActivityResult<()> {
+        if query.is_valid() {
+            Ok(())
+        } else {
+            Err(Self::InvalidWindow {
+                from: query.from,
+                until: query.until,
+            })
+        }
+    }
+}
@@ -5,6 +5,7 @@
 
 use crate::context::RequestContext;
 use crate::message::domain::{AgentSession, AgentSessionId, ConversationId};
+use crate::pagination::{Page, PageRequest};
 use async_trait::async_trait;
 use std::sync::Arc;
 use thiserror::Error;
@@ -41,15 +42,23 @@ pub trait AgentSessionRepository: Send + Sync {
     /// - A session with the same ID already exists ([`SessionError::Duplicate`])
     /// - An active session already exists for the conversation
     ///   ([`SessionError::ActiveSessionExists`])
+    /// - The conversation is archived, in implementations that track
+    ///   conversation state ([`SessionError::ConversationArchived`])
     /// - The database connection fails ([`SessionError::Persistence`])
     async fn store(&self, ctx: &RequestContext, session: &AgentSession) -> SessionResult<()>;
 
     /// Updates an existing session.
     ///
+    /// The update succeeds only when the stored session's version equals
+    /// [`AgentSession::version`]; the stored copy then carries the next
+    /// version. Callers must reload the session before updating it again.
+    ///
     /// # Errors
     ///
     /// Returns `SessionError` if:
     /// - The session does not exist ([`SessionError::NotFound`])
+    /// - Another writer updated the session since it was loaded
+    ///   ([`SessionError::ConcurrentModification`])
     /// - The update would create a second active session for the
     ///   conversation ([`SessionError::ActiveSessionExists`])
     /// - The database connection fails ([`SessionError::Persistence`])
@@ -73,14 +82,26 @@ pub trait AgentSessionRepository: Send + Sync {
         conversation_id: ConversationId,
     ) -> SessionResult<Option<AgentSession>>;
 
-    /// Lists all sessions for a conversation in chronological order.
+    /// Lists a page of a conversation's sessions in chronological order.
     ///
-    /// Returns an empty vector if no sessions exist.
+    /// Returns an empty page if no sessions exist.
     async fn find_by_conversation(
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> SessionResult<Vec<AgentSession>>;
+        page: PageRequest,
+    ) -> SessionResult<Page<AgentSession>>;
+
+    /// Lists a page of the active sessions handled by an agent backend,
+    /// oldest first.
+    ///
+    /// Returns an empty page if the backend handles no active sessions.
+    async fn find_active_by_backend(
+        &self,
+        ctx: &RequestContext,
+        agent_backend: &str,
+        page: PageRequest,
+    ) -> SessionResult<Page<AgentSession>>;
 }
 
 /// Errors that can occur during session repository operations.
@@ -98,10 +119,27 @@ pub enum SessionError {
     #[error("conversation not found: {0}")]
     ConversationNotFound(ConversationId),
 
+    /// The conversation is archived and rejects new sessions.
+    #[error("conversation is archived: {0}")]
+    ConversationArchived(ConversationId),
+
     /// An active session already exists for the conversation.
     #[error("active session already exists for conversation: {0}")]
     ActiveSessionExists(ConversationId),
 
+    /// The session changed since the caller loaded it.
+    #[error(
+        "session {session_id} was modified concurrently: expected version {expected}, found {actual}"
+    )]
+    ConcurrentModification {
+        /// Session that was modified.
+        session_id: AgentSessionId,
+        /// Version the caller loaded.
+        expected: u64,
+        /// Version currently stored.
+        actual: u64,
+    },
+
     /// Database or connection error.
     #[error("persistence error: {0}")]
     Persistence(Arc<dyn std::error::Error + Send + Sync>),
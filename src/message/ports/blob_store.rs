@@ -0,0 +1,89 @@
+//! Port for content-addressed storage of attachment data.
+//!
+//! Repositories move large attachment data to a [`BlobStore`] and keep only
+//! its [`ContentHash`] in the message. Blobs are named by the hash of their
+//! content, so storing the same data twice keeps one copy.
+
+use crate::context::RequestContext;
+use crate::message::domain::ContentHash;
+use async_trait::async_trait;
+use bytes::Bytes;
+use thiserror::Error;
+
+/// Result type for blob store operations.
+pub type BlobStoreResult<T> = Result<T, BlobStoreError>;
+
+/// Port for storing blobs by the hash of their content.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Blobs are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext), so a
+///   tenant cannot read another tenant's blob by guessing its hash
+/// - Storing a blob that already exists succeeds without changing it
+/// - Deleting a blob that does not exist succeeds
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Stores `data` under `hash`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlobStoreError::HashMismatch`] when `hash` is not the hash
+    /// of `data`, or [`BlobStoreError::Write`] if the backend fails.
+    async fn put(
+        &self,
+        ctx: &RequestContext,
+        hash: &ContentHash,
+        data: Bytes,
+    ) -> BlobStoreResult<()>;
+
+    /// Returns the blob stored under `hash`, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlobStoreError::Corrupt`] when the stored bytes no longer
+    /// match `hash`, or [`BlobStoreError::Read`] if the backend fails.
+    async fn get(&self, ctx: &RequestContext, hash: &ContentHash)
+    -> BlobStoreResult<Option<Bytes>>;
+
+    /// Removes the blob stored under `hash`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlobStoreError::Delete`] if the backend fails.
+    async fn delete(&self, ctx: &RequestContext, hash: &ContentHash) -> BlobStoreResult<()>;
+}
+
+/// Errors returned by [`BlobStore`] implementations.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BlobStoreError {
+    /// The data does not hash to the blob's name.
+    #[error("blob data does not match content hash {expected} (actual {actual})")]
+    HashMismatch {
+        /// The hash the caller named the blob by.
+        expected: ContentHash,
+        /// The hash of the data supplied.
+        actual: ContentHash,
+    },
+
+    /// The stored blob no longer matches its hash.
+    #[error("stored blob {0} is corrupt")]
+    Corrupt(ContentHash),
+
+    /// The backend could not be configured.
+    #[error("blob store configuration failed: {0}")]
+    Configuration(String),
+
+    /// Writing a blob failed.
+    #[error("blob store write failed: {0}")]
+    Write(String),
+
+    /// Reading a blob failed.
+    #[error("blob store read failed: {0}")]
+    Read(String),
+
+    /// Deleting a blob failed.
+    #[error("blob store delete failed: {0}")]
+    Delete(String),
+}
@@ -0,0 +1,64 @@
+//! Port for field-level encryption of message content.
+//!
+//! Some deployments must keep message content and metadata encrypted beyond
+//! what the disk provides. A [`ContentCipher`] seals each field before a
+//! repository writes it and opens it again on read, binding the ciphertext
+//! to associated data such as the message ID so that a sealed field cannot
+//! be moved to another row unnoticed.
+
+use crate::message::domain::{EncryptionKeyId, SealedValue};
+use thiserror::Error;
+
+/// Result type for content cipher operations.
+pub type CipherResult<T> = Result<T, CipherError>;
+
+/// Encrypts and decrypts message fields.
+///
+/// Sealing and opening run inside blocking database transactions, so the
+/// port is synchronous.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - [`ContentCipher::seal`] always uses the key named by
+///   [`ContentCipher::active_key_id`] and tags the value with it
+/// - [`ContentCipher::open`] accepts values sealed under any key the
+///   cipher still holds, so rows written before a rotation stay readable
+/// - Opening fails unless `aad` matches the associated data given when the
+///   value was sealed
+pub trait ContentCipher: Send + Sync {
+    /// Returns the key new values are sealed under.
+    fn active_key_id(&self) -> &EncryptionKeyId;
+
+    /// Encrypts `plaintext`, binding it to `aad`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CipherError::Encryption`] if encryption fails.
+    fn seal(&self, plaintext: &[u8], aad: &[u8]) -> CipherResult<SealedValue>;
+
+    /// Decrypts `sealed`, checking it against `aad`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CipherError::UnknownKey`] when the cipher does not hold the
+    /// key the value was sealed under, or [`CipherError::Decryption`] when
+    /// the value was tampered with or `aad` does not match.
+    fn open(&self, sealed: &SealedValue, aad: &[u8]) -> CipherResult<Vec<u8>>;
+}
+
+/// Errors returned by [`ContentCipher`] implementations.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CipherError {
+    /// The value was sealed under a key the cipher does not hold.
+    #[error("encryption key {0} is not available")]
+    UnknownKey(EncryptionKeyId),
+
+    /// Encrypting a value failed.
+    #[error("encryption failed: {0}")]
+    Encryption(String),
+
+    /// Decrypting a value failed or its authentication check did not pass.
+    #[error("decryption failed: {0}")]
+    Decryption(String),
+}
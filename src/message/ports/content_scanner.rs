@@ -0,0 +1,39 @@
+//! Port for finding sensitive data in message text.
+//!
+//! A [`ContentScanner`] looks for email addresses, phone numbers, API keys
+//! and other [`SensitiveDataKind`]s in free text. The provided
+//! [`ContentScanner::scan_message`] applies it to the text and reasoning
+//! parts of a message; structured payloads such as tool arguments are not
+//! scanned.
+
+use crate::message::domain::{ContentPart, Message, SensitiveDataKind, SensitiveSpan};
+use std::ops::Range;
+
+/// Finds sensitive data in text.
+///
+/// Scanning is pure computation, so the port is synchronous.
+pub trait ContentScanner: Send + Sync {
+    /// Returns the sensitive data in `text` as character ranges, in order
+    /// of position and without overlaps.
+    fn scan_text(&self, text: &str) -> Vec<(SensitiveDataKind, Range<usize>)>;
+
+    /// Returns the sensitive data in the text and reasoning parts of
+    /// `message`.
+    fn scan_message(&self, message: &Message) -> Vec<SensitiveSpan> {
+        message
+            .content()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, part)| match part {
+                ContentPart::Text(text) => Some((index, text.text.as_str())),
+                ContentPart::Reasoning(reasoning) => Some((index, reasoning.text.as_str())),
+                _ => None,
+            })
+            .flat_map(|(index, text)| {
+                self.scan_text(text).into_iter().map(move |(kind, range)| {
+                    SensitiveSpan::new(kind, index, range.start, range.end)
+                })
+            })
+            .collect()
+    }
+}
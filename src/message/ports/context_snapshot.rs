@@ -4,8 +4,10 @@
 //! snapshots, enabling audit and reconstruction of agent session state.
 
 use crate::context::RequestContext;
-use crate::message::domain::{AgentSessionId, ContextWindowSnapshot, ConversationId};
+use crate::message::domain::{AgentSessionId, ContextWindowSnapshot, ConversationId, SnapshotType};
+use crate::pagination::{Page, PageRequest};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use thiserror::Error;
 use uuid::Uuid;
@@ -50,7 +52,7 @@ pub trait ContextSnapshotPort: Send + Sync {
         snapshot_id: Uuid,
     ) -> SnapshotResult<Option<ContextWindowSnapshot>>;
 
-    /// Retrieves snapshots for a session.
+    /// Retrieves one page of a session's snapshots, oldest capture first.
     ///
     /// # Errors
     ///
@@ -59,7 +61,8 @@ pub trait ContextSnapshotPort: Send + Sync {
         &self,
         ctx: &RequestContext,
         session_id: AgentSessionId,
-    ) -> SnapshotResult<Vec<ContextWindowSnapshot>>;
+        page: PageRequest,
+    ) -> SnapshotResult<Page<ContextWindowSnapshot>>;
 
     /// Retrieves the most recent snapshot for a conversation.
     ///
@@ -71,6 +74,44 @@ pub trait ContextSnapshotPort: Send + Sync {
         ctx: &RequestContext,
         conversation_id: ConversationId,
     ) -> SnapshotResult<Option<ContextWindowSnapshot>>;
+
+    /// Retrieves the most recent compaction snapshot for a conversation.
+    ///
+    /// Each compaction covers the history before it, so the latest one is
+    /// the only one the context assembler needs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::Persistence`] if retrieval fails.
+    async fn find_latest_compaction(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> SnapshotResult<Option<ContextWindowSnapshot>>;
+
+    /// Deletes a snapshot, along with the increments built on it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::NotFound`] if the tenant has no such
+    /// snapshot, or [`SnapshotError::Persistence`] if deletion fails.
+    async fn delete_snapshot(&self, ctx: &RequestContext, snapshot_id: Uuid) -> SnapshotResult<()>;
+
+    /// Deletes the tenant's snapshots of `snapshot_type` captured before
+    /// `cutoff`, returning how many were deleted.
+    ///
+    /// Snapshots that a remaining snapshot extends are kept, so no
+    /// remaining increment loses its parent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::Persistence`] if deletion fails.
+    async fn prune_before(
+        &self,
+        ctx: &RequestContext,
+        snapshot_type: SnapshotType,
+        cutoff: DateTime<Utc>,
+    ) -> SnapshotResult<usize>;
 }
 
 /// Errors that can occur during snapshot operations.
@@ -1,7 +1,10 @@
 //! Repository port for conversation persistence.
 
 use crate::context::RequestContext;
-use crate::message::domain::{Conversation, ConversationId};
+use crate::message::domain::{
+    Conversation, ConversationId, ConversationLabel, ConversationLabelEvent,
+};
+use crate::pagination::{Page, PageRequest};
 use async_trait::async_trait;
 use std::sync::Arc;
 use thiserror::Error;
@@ -24,6 +27,43 @@ pub trait ConversationRepository: Send + Sync {
         conversation: &Conversation,
     ) -> ConversationRepositoryResult<()>;
 
+    /// Persists a conversation's state, context, task link, labels, and
+    /// update timestamp.
+    ///
+    /// The update succeeds only when the stored conversation's version
+    /// equals [`Conversation::version`]; the stored copy then carries the
+    /// next version. Callers must reload the conversation before updating it
+    /// again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationRepositoryError::NotFound`] when the
+    /// conversation does not exist, or
+    /// [`ConversationRepositoryError::ConcurrentModification`] when another
+    /// writer updated it since it was loaded.
+    async fn update(
+        &self,
+        ctx: &RequestContext,
+        conversation: &Conversation,
+    ) -> ConversationRepositoryResult<()>;
+
+    /// Persists a relabelled conversation as [`Self::update`] does and
+    /// appends `event` to its label history in the same write.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationRepositoryError::NotFound`] when the
+    /// conversation does not exist, or
+    /// [`ConversationRepositoryError::ConcurrentModification`] when another
+    /// writer updated it since it was loaded. The event is not recorded
+    /// when the update fails.
+    async fn update_labels(
+        &self,
+        ctx: &RequestContext,
+        conversation: &Conversation,
+        event: &ConversationLabelEvent,
+    ) -> ConversationRepositoryResult<()>;
+
     /// Finds a conversation by identifier.
     ///
     /// Returns `None` when the conversation does not exist.
@@ -32,6 +72,27 @@ pub trait ConversationRepository: Send + Sync {
         ctx: &RequestContext,
         conversation_id: ConversationId,
     ) -> ConversationRepositoryResult<Option<Conversation>>;
+
+    /// Finds the conversations carrying `label`, oldest first in natural
+    /// order.
+    async fn find_by_label(
+        &self,
+        ctx: &RequestContext,
+        label: &ConversationLabel,
+        page: PageRequest,
+    ) -> ConversationRepositoryResult<Page<Conversation>>;
+
+    /// Returns a conversation's label history, oldest first in natural
+    /// order.
+    ///
+    /// The history outlives the conversation's current labels: removed
+    /// labels remain in it.
+    async fn find_label_events(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> ConversationRepositoryResult<Page<ConversationLabelEvent>>;
 }
 
 /// Errors returned by conversation repositories.
@@ -41,6 +102,23 @@ pub enum ConversationRepositoryError {
     #[error("duplicate conversation identifier: {0}")]
     DuplicateConversation(ConversationId),
 
+    /// Conversation does not exist.
+    #[error("conversation not found: {0}")]
+    NotFound(ConversationId),
+
+    /// Conversation changed since the caller loaded it.
+    #[error(
+        "conversation {conversation_id} was modified concurrently: expected version {expected}, found {actual}"
+    )]
+    ConcurrentModification {
+        /// Conversation that was modified.
+        conversation_id: ConversationId,
+        /// Version the caller loaded.
+        expected: u64,
+        /// Version currently stored.
+        actual: u64,
+    },
+
     /// Persistence-layer failure.
     #[error("persistence error: {0}")]
     Persistence(Arc<dyn std::error::Error + Send + Sync>),
@@ -0,0 +1,86 @@
+//! Port for enumerating conversations.
+//!
+//! Defines the read-only interface used to list a tenant's conversations with
+//! filtering, sorting, and keyset pagination over conversation summaries.
+
+use crate::context::RequestContext;
+use crate::message::domain::{ConversationListQuery, ConversationSummary};
+use crate::pagination::Page;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for conversation listing queries.
+pub type ConversationListResult<T> = Result<T, ConversationListError>;
+
+/// Port for listing conversation summaries.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Conversations are ordered by the query sort key, with ties broken by
+///   conversation identifier in the same direction
+/// - A page holds at most `query.page.limit()` conversations and carries a
+///   cursor only when more conversations follow
+/// - A cursor issued by another kind of listing yields an empty page
+/// - All queries are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait ConversationListPort: Send + Sync {
+    /// Returns one page of conversation summaries matching the query.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationListError::InvalidActivityRange`] if the
+    /// last-activity range does not end after it starts, or
+    /// [`ConversationListError::Persistence`] if the underlying store fails.
+    async fn list_conversations(
+        &self,
+        ctx: &RequestContext,
+        query: &ConversationListQuery,
+    ) -> ConversationListResult<Page<ConversationSummary>>;
+}
+
+/// Errors that can occur when listing conversations.
+#[derive(Debug, Clone, Error)]
+pub enum ConversationListError {
+    /// The last-activity range is empty or inverted.
+    #[error("invalid last-activity range: {from} is not before {until}")]
+    InvalidActivityRange {
+        /// Requested range start.
+        from: DateTime<Utc>,
+        /// Requested range end.
+        until: DateTime<Utc>,
+    },
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl ConversationListError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+
+    /// Validates the query filters, returning
+    /// [`Self::InvalidActivityRange`] when the last-activity range is empty
+    /// or inverted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::InvalidActivityRange`] for empty or inverted ranges.
+    pub fn check_query(query: &ConversationListQuery) -> ConversationListResult<()> {
+        match (
+            query.filter.last_activity_from,
+            query.filter.last_activity_until,
+        ) {
+            (Some(from), Some(until)) if !query.filter.has_valid_activity_range() => {
+                Err(Self::InvalidActivityRange { from, until })
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Ports for receiving and sending email.
+//!
+//! The inbound email gateway can be fed by a provider webhook or by polling
+//! a [`Mailbox`], such as an IMAP inbox. Agent replies to email-driven
+//! conversations leave through an [`EmailNotifier`], such as an SMTP relay.
+
+use crate::message::domain::{InboundEmail, OutboundEmail};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for email transport operations.
+pub type EmailTransportResult<T> = Result<T, EmailTransportError>;
+
+/// Source of received email that has not been ingested yet.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - An email keeps being returned by [`Mailbox::fetch_unseen`] until it is
+///   marked seen, so an email whose ingestion failed is retried on the next
+///   poll
+/// - Marking an email seen twice is not an error
+#[async_trait]
+pub trait Mailbox: Send + Sync {
+    /// Returns the emails not yet marked seen, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmailTransportError::Transport`] if the mailbox cannot be
+    /// read.
+    async fn fetch_unseen(&self) -> EmailTransportResult<Vec<InboundEmail>>;
+
+    /// Marks the email with `message_id` as seen.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmailTransportError::Transport`] if the mailbox cannot be
+    /// updated.
+    async fn mark_seen(&self, message_id: &str) -> EmailTransportResult<()>;
+}
+
+/// Outbound email delivery.
+#[async_trait]
+pub trait EmailNotifier: Send + Sync {
+    /// Sends an email.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmailTransportError::InvalidAddress`] if an address cannot
+    /// be used, or [`EmailTransportError::Transport`] if delivery fails.
+    async fn send(&self, email: &OutboundEmail) -> EmailTransportResult<()>;
+}
+
+/// Errors that can occur when receiving or sending email.
+#[derive(Debug, Clone, Error)]
+pub enum EmailTransportError {
+    /// An address is not a valid mailbox.
+    #[error("invalid email address {0:?}")]
+    InvalidAddress(String),
+    /// The mail server or mailbox failed.
+    #[error("email transport error: {0}")]
+    Transport(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl EmailTransportError {
+    /// Creates a transport error from any error type.
+    pub fn transport(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Transport(Arc::new(err))
+    }
+}
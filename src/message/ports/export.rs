@@ -0,0 +1,59 @@
+//! Port for the output formats of conversation exports.
+//!
+//! An [`ExportFormat`] names a format and opens an [`ExportEncoder`] for
+//! each exported conversation. The encoder turns the conversation's
+//! messages into bytes one at a time, so an export streams to its writer
+//! without holding the conversation in memory. Encoding is pure
+//! computation, so both traits are synchronous.
+
+use crate::message::domain::{ConversationId, Message};
+use thiserror::Error;
+
+/// Result type for export encoding.
+pub type ExportFormatResult<T> = Result<T, ExportFormatError>;
+
+/// An output format for conversation exports.
+pub trait ExportFormat: Send + Sync {
+    /// Returns the name exports select the format by.
+    fn name(&self) -> &str;
+
+    /// Opens an encoder for one conversation.
+    fn encoder(&self, conversation_id: ConversationId) -> Box<dyn ExportEncoder>;
+}
+
+/// Encodes the messages of one conversation, oldest first.
+pub trait ExportEncoder: Send {
+    /// Returns the bytes written before the first message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExportFormatError`] when the preamble cannot be encoded.
+    fn begin(&mut self) -> ExportFormatResult<Vec<u8>> {
+        Ok(Vec::new())
+    }
+
+    /// Returns the bytes written for `message`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExportFormatError`] when the message cannot be encoded.
+    fn message(&mut self, message: &Message) -> ExportFormatResult<Vec<u8>>;
+
+    /// Returns the bytes written after the last message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExportFormatError`] when the closing bytes cannot be
+    /// encoded.
+    fn finish(&mut self) -> ExportFormatResult<Vec<u8>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Errors returned when a conversation cannot be encoded.
+#[derive(Debug, Error)]
+pub enum ExportFormatError {
+    /// A message could not be serialised.
+    #[error("export serialisation failed: {0}")]
+    Serialisation(#[from] serde_json::Error),
+}
@@ -0,0 +1,86 @@
+//! Port for human feedback on assistant messages.
+//!
+//! Defines the persistence interface for reviewer ratings and the
+//! per-backend summaries derived from them.
+
+use crate::context::RequestContext;
+use crate::message::domain::{FeedbackSummary, MessageFeedback, MessageId};
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for feedback persistence operations.
+pub type FeedbackResult<T> = Result<T, FeedbackError>;
+
+/// Port for storing and aggregating message feedback.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Each reviewer holds at most one record per message; storing feedback
+///   for the same message and author replaces the earlier record while
+///   keeping its identifier
+/// - All queries and mutations are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait MessageFeedbackRepository: Send + Sync {
+    /// Stores feedback, replacing the author's earlier feedback on the same
+    /// message.
+    ///
+    /// Returns the stored record, which carries the earlier identifier when
+    /// feedback was replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FeedbackError::Persistence`] if the underlying store fails.
+    async fn upsert(
+        &self,
+        ctx: &RequestContext,
+        feedback: &MessageFeedback,
+    ) -> FeedbackResult<MessageFeedback>;
+
+    /// Returns a page of feedback on a message, oldest submission first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FeedbackError::Persistence`] if the underlying store fails.
+    async fn list_for_message(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+        page: PageRequest,
+    ) -> FeedbackResult<Page<MessageFeedback>>;
+
+    /// Returns feedback totals per agent backend and instruction-set
+    /// version, ordered as by [`FeedbackSummary::aggregate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FeedbackError::Persistence`] if the underlying store fails.
+    async fn summarize(&self, ctx: &RequestContext) -> FeedbackResult<Vec<FeedbackSummary>>;
+}
+
+/// Errors that can occur when persisting feedback.
+#[derive(Debug, Clone, Error)]
+pub enum FeedbackError {
+    /// A stored record could not be decoded.
+    #[error("invalid persisted feedback: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl FeedbackError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+
+    /// Creates an invalid persisted data error from any error type.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+}
@@ -0,0 +1,101 @@
+//! Port for forking conversations and querying fork provenance.
+//!
+//! A fork copies its parent's messages through the fork point into a new
+//! conversation and records a [`ConversationFork`] naming the parent, the
+//! fork point, and the parent's context snapshot there. Copies share their
+//! attachments' blobs with the parent's messages, so redacting a parent
+//! message also removes the data of attachments its copies hold.
+
+use crate::context::RequestContext;
+use crate::message::domain::{ConversationFork, ConversationId, SequenceNumber};
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for conversation fork operations.
+pub type ConversationForkResult<T> = Result<T, ConversationForkError>;
+
+/// Persistence contract for conversation forks.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - The forked conversation, its copied messages, and its provenance are
+///   stored together, or not at all
+/// - The parent's latest context snapshot whose sequence range ends at or
+///   before the fork point is linked as
+///   [`ConversationFork::parent_snapshot_id`]
+/// - All queries and mutations are scoped to the tenant identified
+///   by [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait ConversationForkRepository: Send + Sync {
+    /// Creates the fork described by `fork` and returns its provenance as
+    /// stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationForkError::ParentNotFound`] when the parent
+    /// conversation does not exist,
+    /// [`ConversationForkError::ForkPointNotFound`] when the parent has no
+    /// message at the fork point, or [`ConversationForkError::Persistence`]
+    /// if the underlying store fails.
+    async fn fork(
+        &self,
+        ctx: &RequestContext,
+        fork: &ConversationFork,
+    ) -> ConversationForkResult<ConversationFork>;
+
+    /// Returns where a conversation was forked from, or `None` when it is
+    /// not a fork.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationForkError::Persistence`] if the lookup fails.
+    async fn find_fork(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> ConversationForkResult<Option<ConversationFork>>;
+
+    /// Returns a page of the forks taken from a conversation, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationForkError::Persistence`] if the query fails.
+    async fn find_forks(
+        &self,
+        ctx: &RequestContext,
+        parent_conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> ConversationForkResult<Page<ConversationFork>>;
+}
+
+/// Errors that can occur when forking a conversation.
+#[derive(Debug, Clone, Error)]
+pub enum ConversationForkError {
+    /// The conversation to fork does not exist.
+    #[error("conversation not found: {0}")]
+    ParentNotFound(ConversationId),
+
+    /// The parent has no message at the requested fork point.
+    #[error("conversation {conversation_id} has no message {forked_at}")]
+    ForkPointNotFound {
+        /// The parent conversation.
+        conversation_id: ConversationId,
+        /// The requested fork point.
+        forked_at: SequenceNumber,
+    },
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl ConversationForkError {
+    /// Wraps a persistence error.
+    #[must_use]
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
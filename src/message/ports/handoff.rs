@@ -7,6 +7,7 @@ use crate::context::RequestContext;
 use crate::message::domain::{
     AgentSession, AgentSessionId, ConversationId, HandoffId, HandoffMetadata, HandoffStatus, TurnId,
 };
+use crate::pagination::{Page, PageRequest};
 use async_trait::async_trait;
 use std::sync::Arc;
 use thiserror::Error;
@@ -64,7 +65,7 @@ impl<'a> InitiateHandoffParams<'a> {
 ///
 /// Implementations must ensure:
 /// - Handoff IDs are unique across the entire system
-/// - State transitions follow the valid lifecycle (Initiated → Accepted → Completed | Cancelled)
+/// - State transitions follow the valid lifecycle (Initiated → Accepted → Completed | Failed | Cancelled)
 /// - Concurrent access is handled safely
 /// - All queries and mutations are scoped to the tenant identified
 ///   by [`RequestContext::tenant_id`](crate::context::RequestContext)
@@ -74,7 +75,9 @@ pub trait AgentHandoffPort: Send + Sync {
     ///
     /// # Errors
     ///
-    /// Returns `HandoffError` if the handoff could not be initiated.
+    /// Returns [`HandoffError::ConversationArchived`] when the conversation is
+    /// archived and the implementation tracks conversation state, or another
+    /// `HandoffError` if the handoff could not be initiated.
     async fn initiate_handoff(
         &self,
         ctx: &RequestContext,
@@ -88,7 +91,9 @@ pub trait AgentHandoffPort: Send + Sync {
     ///
     /// # Errors
     ///
-    /// Returns `HandoffError` if the handoff cannot be completed or persisted.
+    /// Returns [`HandoffError::ConversationArchived`] when the conversation is
+    /// archived and the implementation tracks conversation state, or another
+    /// `HandoffError` if the handoff cannot be completed or persisted.
     async fn complete_handoff(
         &self,
         ctx: &RequestContext,
@@ -108,6 +113,22 @@ pub trait AgentHandoffPort: Send + Sync {
         reason: Option<&str>,
     ) -> HandoffResult<()>;
 
+    /// Records the target agent's rejection of a pending handoff.
+    ///
+    /// The handoff ends [`HandoffStatus::Failed`] with `reason` recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandoffError::InvalidStateTransition`] when the handoff is
+    /// already in a terminal state, or another `HandoffError` if the
+    /// handoff cannot be found or persisted.
+    async fn reject_handoff(
+        &self,
+        ctx: &RequestContext,
+        handoff_id: HandoffId,
+        reason: &str,
+    ) -> HandoffResult<HandoffMetadata>;
+
     /// Retrieves handoff metadata by ID.
     ///
     /// # Errors
@@ -119,7 +140,7 @@ pub trait AgentHandoffPort: Send + Sync {
         handoff_id: HandoffId,
     ) -> HandoffResult<Option<HandoffMetadata>>;
 
-    /// Lists all handoffs for a conversation.
+    /// Lists one page of a conversation's handoffs, in initiation order.
     ///
     /// # Errors
     ///
@@ -128,7 +149,8 @@ pub trait AgentHandoffPort: Send + Sync {
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> HandoffResult<Vec<HandoffMetadata>>;
+        page: PageRequest,
+    ) -> HandoffResult<Page<HandoffMetadata>>;
 }
 
 /// Errors that can occur during handoff operations.
@@ -155,6 +177,10 @@ pub enum HandoffError {
     #[error("conversation not found: {0}")]
     ConversationNotFound(ConversationId),
 
+    /// The conversation is archived and rejects handoffs.
+    #[error("conversation is archived: {0}")]
+    ConversationArchived(ConversationId),
+
     /// Prior turn not found.
     #[error("prior turn not found: {0}")]
     PriorTurnNotFound(TurnId),
@@ -170,6 +196,18 @@ pub enum HandoffError {
         target_conversation: ConversationId,
     },
 
+    /// A handoff was rejected without saying why.
+    #[error("handoff rejection requires a reason")]
+    MissingRejectionReason,
+
+    /// The target session's context package could not be prepared or read.
+    #[error("context transfer failed: {0}")]
+    ContextTransferFailed(String),
+
+    /// No port for reading handoff chains is attached to the service.
+    #[error("handoff chain queries are not configured")]
+    ChainNotConfigured,
+
     /// Context snapshot capture failed.
     #[error("context snapshot failed: {0}")]
     SnapshotFailed(String),
@@ -0,0 +1,39 @@
+//! Port for reading the chain of handoffs within a conversation.
+
+use crate::context::RequestContext;
+use crate::message::domain::{ConversationId, HandoffChain};
+use crate::message::ports::handoff::HandoffResult;
+use async_trait::async_trait;
+
+/// Port for reading a conversation's handoffs joined to their sessions and
+/// snapshots.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Links are ordered by handoff initiation time, ties broken by handoff
+///   id
+/// - Every handoff of the conversation is included, whatever its status
+/// - All queries are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait HandoffChainPort: Send + Sync {
+    /// Returns the handoff chain of a conversation.
+    ///
+    /// Conversations without handoffs yield an empty chain rather than an
+    /// error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandoffError::SessionNotFound`] if a handoff's source
+    /// session is missing, or [`HandoffError::Persistence`] if the
+    /// underlying store fails.
+    ///
+    /// [`HandoffError::SessionNotFound`]: crate::message::ports::handoff::HandoffError::SessionNotFound
+    /// [`HandoffError::Persistence`]: crate::message::ports::handoff::HandoffError::Persistence
+    async fn handoff_chain(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> HandoffResult<HandoffChain>;
+}
@@ -0,0 +1,69 @@
+//! Port for the context packages prepared for handoff target sessions.
+
+use crate::context::RequestContext;
+use crate::message::domain::{AgentSessionId, HandoffContextPackage};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for handoff context persistence operations.
+pub type HandoffContextResult<T> = Result<T, HandoffContextError>;
+
+/// Port for storing the context package of each handoff target session.
+///
+/// # Implementation Notes
+///
+/// All queries and mutations are scoped to the tenant identified by
+/// [`RequestContext::tenant_id`](crate::context::RequestContext).
+#[async_trait]
+pub trait HandoffContextRepository: Send + Sync {
+    /// Stores a package, replacing any earlier package for its target
+    /// session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandoffContextError::Persistence`] if the underlying store
+    /// fails.
+    async fn store(
+        &self,
+        ctx: &RequestContext,
+        package: &HandoffContextPackage,
+    ) -> HandoffContextResult<()>;
+
+    /// Returns the package prepared for `target_session_id`, or `None` when
+    /// the session took over no handoff.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandoffContextError`] if the underlying store fails or the
+    /// stored package cannot be decoded.
+    async fn find_for_session(
+        &self,
+        ctx: &RequestContext,
+        target_session_id: AgentSessionId,
+    ) -> HandoffContextResult<Option<HandoffContextPackage>>;
+}
+
+/// Errors that can occur when persisting handoff context packages.
+#[derive(Debug, Clone, Error)]
+pub enum HandoffContextError {
+    /// A stored package could not be decoded.
+    #[error("invalid persisted handoff context: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl HandoffContextError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+
+    /// Creates an invalid persisted data error from any error type.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+}
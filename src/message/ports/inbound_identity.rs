@@ -0,0 +1,82 @@
+//! Port for mapping external chat identities onto Corbusier.
+//!
+//! Inbound gateways receive messages that name an external author and
+//! channel. This port resolves the author to a tenant-scoped principal and
+//! remembers which conversation each external channel feeds.
+
+use crate::context::RequestContext;
+use crate::message::domain::{ConversationId, InboundOrigin, InboundPrincipal};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for inbound identity mapping operations.
+pub type InboundIdentityResult<T> = Result<T, InboundIdentityError>;
+
+/// Port for resolving external users and channels.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Each external channel is bound to at most one conversation per tenant;
+///   binding a channel that is already bound keeps the existing conversation
+/// - Channel bindings are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait InboundIdentityMapping: Send + Sync {
+    /// Returns the principal the author of `origin` acts as, or `None` when
+    /// the external user is not mapped.
+    ///
+    /// No request context is available yet: the principal determines the
+    /// tenant of every later call.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InboundIdentityError::Persistence`] if the underlying store
+    /// fails.
+    async fn principal_for(
+        &self,
+        origin: &InboundOrigin,
+    ) -> InboundIdentityResult<Option<InboundPrincipal>>;
+
+    /// Returns the conversation bound to the channel of `origin`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InboundIdentityError::Persistence`] if the underlying store
+    /// fails.
+    async fn conversation_for(
+        &self,
+        ctx: &RequestContext,
+        origin: &InboundOrigin,
+    ) -> InboundIdentityResult<Option<ConversationId>>;
+
+    /// Binds the channel of `origin` to a conversation unless it is already
+    /// bound, returning the conversation the channel is bound to afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InboundIdentityError::Persistence`] if the underlying store
+    /// fails.
+    async fn bind_conversation(
+        &self,
+        ctx: &RequestContext,
+        origin: &InboundOrigin,
+        conversation_id: ConversationId,
+    ) -> InboundIdentityResult<ConversationId>;
+}
+
+/// Errors that can occur when mapping inbound identities.
+#[derive(Debug, Clone, Error)]
+pub enum InboundIdentityError {
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl InboundIdentityError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
@@ -0,0 +1,53 @@
+//! Port for extension callbacks on conversation lifecycle points.
+//!
+//! Embedders implement [`ConversationLifecycleHook`] to integrate with
+//! conversation workflows without changing service code, and register their
+//! hooks with [`crate::message::services::ConversationLifecycleHooks`].
+
+use crate::context::RequestContext;
+use crate::message::domain::ConversationLifecycleEvent;
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type for lifecycle hook callbacks.
+pub type LifecycleHookResult = Result<(), LifecycleHookError>;
+
+/// Callback invoked when a conversation reaches a lifecycle point.
+///
+/// # Implementation Notes
+///
+/// Hooks run after the transition has been persisted and cannot veto it.
+/// A hook that fails, panics, or overruns its timeout is reported and
+/// skipped; the workflow that announced the event carries on regardless.
+#[async_trait]
+pub trait ConversationLifecycleHook: Send + Sync {
+    /// Handles a lifecycle event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LifecycleHookError`] when the hook could not do its work.
+    async fn on_event(
+        &self,
+        ctx: &RequestContext,
+        event: &ConversationLifecycleEvent,
+    ) -> LifecycleHookResult;
+}
+
+/// Failure reported by a lifecycle hook.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{0}")]
+pub struct LifecycleHookError(String);
+
+impl LifecycleHookError {
+    /// Creates an error with a human-readable reason.
+    #[must_use]
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self(reason.into())
+    }
+
+    /// Returns the failure reason.
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.0
+    }
+}
@@ -4,22 +4,79 @@
 //! infrastructure. Adapters implement these ports to connect the domain
 //! to databases, external services, and other infrastructure.
 
+pub mod activity;
 pub mod agent_session;
+pub mod blob_store;
+pub mod content_cipher;
+pub mod content_scanner;
 pub mod context_snapshot;
 pub mod conversation;
+pub mod conversation_list;
+pub mod email;
+pub mod export;
+pub mod feedback;
+pub mod fork;
 pub mod handoff;
+pub mod handoff_chain;
+pub mod handoff_context;
+pub mod inbound_identity;
+pub mod lifecycle_hook;
+pub mod processing;
 pub mod repository;
 pub mod slash_command;
+pub mod slash_command_authoriser;
+pub mod slash_command_execution;
+pub mod snapshot_policy;
+pub mod streaming;
+pub mod summary;
+pub mod token_counter;
+pub mod transcript;
+pub mod transfer;
+pub mod turn;
 pub mod validator;
 
+pub use activity::{ActivityError, ActivityResult, ConversationActivityPort};
 pub use agent_session::{AgentSessionRepository, SessionError, SessionResult};
+pub use blob_store::{BlobStore, BlobStoreError, BlobStoreResult};
+pub use content_cipher::{CipherError, CipherResult, ContentCipher};
+pub use content_scanner::ContentScanner;
 pub use context_snapshot::{ContextSnapshotPort, SnapshotError, SnapshotResult};
 pub use conversation::{
     ConversationRepository, ConversationRepositoryError, ConversationRepositoryResult,
 };
+pub use conversation_list::{ConversationListError, ConversationListPort, ConversationListResult};
+pub use email::{EmailNotifier, EmailTransportError, EmailTransportResult, Mailbox};
+pub use export::{ExportEncoder, ExportFormat, ExportFormatError, ExportFormatResult};
+pub use feedback::{FeedbackError, FeedbackResult, MessageFeedbackRepository};
+pub use fork::{ConversationForkError, ConversationForkRepository, ConversationForkResult};
 pub use handoff::{AgentHandoffPort, HandoffError, HandoffResult};
+pub use handoff_chain::HandoffChainPort;
+pub use handoff_context::{HandoffContextError, HandoffContextRepository, HandoffContextResult};
+pub use inbound_identity::{InboundIdentityError, InboundIdentityMapping, InboundIdentityResult};
+pub use lifecycle_hook::{ConversationLifecycleHook, LifecycleHookError, LifecycleHookResult};
+pub use processing::{MessageProcessingRepository, ProcessingError, ProcessingResult};
 pub use repository::MessageRepository;
 pub use slash_command::{
+    SlashCommandDefinitionError, SlashCommandDefinitionRepository, SlashCommandDefinitionResult,
     SlashCommandRegistry, SlashCommandRegistryError, SlashCommandRegistryResult,
 };
-pub use validator::{MessageValidator, ValidationConfig};
+pub use slash_command_authoriser::{SlashCommandAuthorisationResult, SlashCommandAuthoriser};
+pub use slash_command_execution::{
+    SlashCommandExecutionError, SlashCommandExecutionRepository, SlashCommandExecutionResult,
+};
+pub use snapshot_policy::{SnapshotPolicyError, SnapshotPolicyRepository, SnapshotPolicyResult};
+pub use streaming::{PartialMessageRepository, StreamingError, StreamingResult};
+pub use summary::{
+    ConversationSummariser, RollingSummaryError, RollingSummaryRepository, RollingSummaryResult,
+    SummariserError, SummariserResult,
+};
+pub use token_counter::TokenCounter;
+pub use transcript::{TranscriptConverter, TranscriptError, TranscriptResult};
+pub use transfer::{
+    ConversationTransferError, ConversationTransferRepository, ConversationTransferResult,
+};
+pub use turn::{TurnError, TurnRepository, TurnResult};
+pub use validator::{
+    AttachmentPolicy, MessageValidator, RoleContentPolicy, ValidationConfig, ValidationRule,
+    ValidationStage,
+};
@@ -0,0 +1,102 @@
+//! Port for message processing pipeline status.
+//!
+//! Defines the persistence interface the post-ingestion stages use to record
+//! their progress on each message, and the queries that find messages stuck
+//! at a stage.
+
+use crate::context::RequestContext;
+use crate::message::domain::{MessageId, StageStatus, StuckMessagesQuery};
+use crate::pagination::Page;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for processing status persistence operations.
+pub type ProcessingResult<T> = Result<T, ProcessingError>;
+
+/// Port for storing and querying message processing status.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Each message holds at most one status per stage; storing a status for
+///   the same message and stage replaces the earlier one
+/// - All queries and mutations are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait MessageProcessingRepository: Send + Sync {
+    /// Stores a stage status, replacing the earlier status of the same
+    /// message and stage.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingError::Persistence`] if the underlying store
+    /// fails.
+    async fn upsert(&self, ctx: &RequestContext, status: &StageStatus) -> ProcessingResult<()>;
+
+    /// Stores a stage status and discards the statuses of every later stage
+    /// of the same message, atomically.
+    ///
+    /// Used when a message is sent back through a stage, since the later
+    /// stages will run again afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingError::Persistence`] if the underlying store
+    /// fails.
+    async fn restart_from(
+        &self,
+        ctx: &RequestContext,
+        status: &StageStatus,
+    ) -> ProcessingResult<()>;
+
+    /// Returns the recorded stage statuses of a message, at most one per
+    /// [`ProcessingStage`](crate::message::domain::ProcessingStage).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingError::Persistence`] if the underlying store
+    /// fails.
+    async fn find_for_message(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> ProcessingResult<Vec<StageStatus>>;
+
+    /// Returns a page of statuses matching `query`, least recently updated
+    /// first, with ties broken by message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingError::Persistence`] if the underlying store
+    /// fails.
+    async fn find_stuck(
+        &self,
+        ctx: &RequestContext,
+        query: &StuckMessagesQuery,
+    ) -> ProcessingResult<Page<StageStatus>>;
+}
+
+/// Errors that can occur when persisting processing status.
+#[derive(Debug, Clone, Error)]
+pub enum ProcessingError {
+    /// A stored record could not be decoded.
+    #[error("invalid persisted processing status: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl ProcessingError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+
+    /// Creates an invalid persisted data error from any error type.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+}
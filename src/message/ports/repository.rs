@@ -5,9 +5,13 @@
 
 use crate::context::RequestContext;
 use crate::message::{
-    domain::{ConversationId, Message, MessageId, SequenceNumber},
+    domain::{
+        ContentHash, ConversationId, ConversationTokenUsage, Message, MessageId, MessageQuery,
+        MessageRedaction, SequenceNumber,
+    },
     error::RepositoryError,
 };
+use crate::pagination::{Page, PageRequest, collect_pages};
 use async_trait::async_trait;
 
 /// Result type for repository operations.
@@ -23,7 +27,9 @@ pub type RepositoryResult<T> = Result<T, RepositoryError>;
 /// Implementations must ensure:
 /// - Message IDs are unique across the entire system
 /// - Sequence numbers are unique within a conversation
-/// - Messages are immutable after storage (no update operations)
+/// - Messages are immutable after storage, except that
+///   [`MessageRepository::redact`] replaces their content and
+///   [`MessageRepository::set_pinned`] changes their pinned flag
 /// - Concurrent access is handled safely
 /// - All queries and mutations are scoped to the tenant identified
 ///   by [`RequestContext::tenant_id`](crate::context::RequestContext)
@@ -35,10 +41,96 @@ pub trait MessageRepository: Send + Sync {
     ///
     /// Returns `RepositoryError` if:
     /// - A message with the same ID already exists
+    /// - The conversation is archived, in implementations that track
+    ///   conversation state ([`RepositoryError::ConversationArchived`])
     /// - The database connection fails
     /// - Serialisation fails
     async fn store(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<()>;
 
+    /// Appends a message as the next message of its conversation.
+    ///
+    /// The repository ignores the message's own sequence number and
+    /// allocates the next one in the same atomic step as the write, so
+    /// concurrent writers never collide on a sequence number. Returns the
+    /// stored message with its allocated sequence number.
+    ///
+    /// The method takes a whole [`Message`] rather than its conversation,
+    /// role, content, and metadata, so callers still choose the message ID
+    /// and creation time, and the message passes the same
+    /// [`MessageBuilder`](crate::message::domain::MessageBuilder)
+    /// validation as one stored with [`MessageRepository::store`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError` if:
+    /// - A message with the same ID already exists
+    /// - The conversation does not exist, in implementations that can
+    ///   verify conversation existence ([`RepositoryError::ConversationNotFound`])
+    /// - The conversation is archived, in implementations that track
+    ///   conversation state ([`RepositoryError::ConversationArchived`])
+    /// - The database connection fails
+    /// - Serialisation fails
+    async fn append(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<Message>;
+
+    /// Stores a batch of messages atomically: either every message is
+    /// stored or none is.
+    ///
+    /// An empty batch succeeds without touching storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError` if:
+    /// - Any message ID or per-conversation sequence number is already
+    ///   stored or repeated within the batch
+    ///   ([`RepositoryError::BatchConflicts`], listing every conflict)
+    /// - Any targeted conversation is archived, in implementations that
+    ///   track conversation state ([`RepositoryError::ConversationArchived`])
+    /// - The database connection fails
+    /// - Serialisation fails
+    async fn store_batch(&self, ctx: &RequestContext, messages: &[Message])
+    -> RepositoryResult<()>;
+
+    /// Redacts a stored message, replacing its content with the
+    /// redaction's placeholder as [`Message::redacted`] does.
+    ///
+    /// The message keeps its sequence number, so conversation history stays
+    /// contiguous. Implementations record the redaction as a tombstone and
+    /// scrub copies of the original content they keep elsewhere, such as
+    /// audit rows. Archived conversations can still be redacted. Returns the
+    /// redacted message.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError` if:
+    /// - The message does not exist ([`RepositoryError::NotFound`])
+    /// - The message is already redacted
+    ///   ([`RepositoryError::AlreadyRedacted`])
+    /// - The database connection fails
+    /// - Serialisation fails
+    async fn redact(
+        &self,
+        ctx: &RequestContext,
+        redaction: &MessageRedaction,
+    ) -> RepositoryResult<Message>;
+
+    /// Pins a stored message into every context, or unpins it.
+    ///
+    /// Only the message's [`pinned`](crate::message::domain::MessageMetadata::pinned)
+    /// flag changes; redacted and archived messages can be pinned too.
+    /// Returns the updated message.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError` if:
+    /// - The message does not exist ([`RepositoryError::NotFound`])
+    /// - The database connection fails
+    async fn set_pinned(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+        pinned: bool,
+    ) -> RepositoryResult<Message>;
+
     /// Retrieves a message by its ID.
     ///
     /// Returns `None` if the message does not exist.
@@ -52,9 +144,10 @@ pub trait MessageRepository: Send + Sync {
         id: MessageId,
     ) -> RepositoryResult<Option<Message>>;
 
-    /// Retrieves all messages for a conversation, ordered by sequence number.
+    /// Retrieves a page of a conversation's messages, ordered by sequence
+    /// number.
     ///
-    /// Returns an empty vector if no messages exist for the conversation.
+    /// Returns an empty page if no messages exist for the conversation.
     ///
     /// # Errors
     ///
@@ -63,7 +156,42 @@ pub trait MessageRepository: Send + Sync {
         &self,
         ctx: &RequestContext,
         conversation_id: ConversationId,
-    ) -> RepositoryResult<Vec<Message>>;
+        page: PageRequest,
+    ) -> RepositoryResult<Page<Message>>;
+
+    /// Retrieves a page of a conversation's messages matching `query`,
+    /// ordered by sequence number.
+    ///
+    /// The page is taken from [`MessageQuery::page`]. Returns an empty page
+    /// if no messages match.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError` if the query fails.
+    async fn query(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        query: &MessageQuery,
+    ) -> RepositoryResult<Page<Message>>;
+
+    /// Retrieves every pinned message in a conversation, ordered by
+    /// sequence number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError` if the query fails.
+    async fn find_pinned(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<Vec<Message>> {
+        collect_pages(|page| async move {
+            let query = MessageQuery::new().with_pinned().with_page(page);
+            self.query(ctx, conversation_id, &query).await
+        })
+        .await
+    }
 
     /// Returns the next sequence number for a conversation.
     ///
@@ -81,10 +209,40 @@ pub trait MessageRepository: Send + Sync {
         conversation_id: ConversationId,
     ) -> RepositoryResult<SequenceNumber>;
 
+    /// Returns the aggregate token usage of a conversation's messages.
+    ///
+    /// Token counts are read from each message's metadata; messages without
+    /// one are reported as uncounted. A conversation without messages, or
+    /// one that does not exist, reports zero usage.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError` if the query fails.
+    async fn token_usage(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<ConversationTokenUsage>;
+
     /// Checks if a message with the given ID already exists.
     ///
     /// # Errors
     ///
     /// Returns `RepositoryError` if the query fails.
     async fn exists(&self, ctx: &RequestContext, id: MessageId) -> RepositoryResult<bool>;
+
+    /// Returns those of `hashes` that none of the tenant's messages refers
+    /// to as the blob of an attachment or image.
+    ///
+    /// Identical attachment data shares one blob, so callers check here
+    /// before deleting a blob whose message was redacted or removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError` if the query fails.
+    async fn unreferenced_blobs(
+        &self,
+        ctx: &RequestContext,
+        hashes: &[ContentHash],
+    ) -> RepositoryResult<Vec<ContentHash>>;
 }
@@ -1,11 +1,18 @@
-//! Slash-command registry port.
+//! Slash-command registry ports.
 //!
 //! The registry port provides command definitions to orchestration services.
+//! The definition repository port persists versioned definitions so they
+//! survive restarts and can be managed at runtime.
 
+use async_trait::async_trait;
+use std::num::NonZeroU32;
+use std::sync::Arc;
 use thiserror::Error;
 
+use crate::context::RequestContext;
 use crate::message::domain::{
     SlashCommandDefinition, SlashCommandRegistryUnavailableError, SlashCommandSchemaError,
+    VersionedSlashCommand,
 };
 
 /// Result type for slash-command registry operations.
@@ -13,7 +20,8 @@ pub type SlashCommandRegistryResult<T> = Result<T, SlashCommandRegistryError>;
 
 /// Port for loading slash-command definitions.
 pub trait SlashCommandRegistry: Send + Sync {
-    /// Finds a command definition by command name (without leading slash).
+    /// Finds a command definition by qualified name (`namespace:command`, or
+    /// the bare command outside a namespace, without leading slash).
     ///
     /// # Errors
     ///
@@ -42,3 +50,121 @@ pub enum SlashCommandRegistryError {
     #[error("slash-command registry unavailable: {0}")]
     Unavailable(SlashCommandRegistryUnavailableError),
 }
+
+/// Result type for slash-command definition repository operations.
+pub type SlashCommandDefinitionResult<T> = Result<T, SlashCommandDefinitionError>;
+
+/// Store of versioned slash-command definitions.
+///
+/// Versions are keyed by qualified command name (`namespace:command`),
+/// stored lower-cased as parsed from invocations.
+///
+/// # Implementation Notes
+///
+/// All queries and mutations are scoped to the tenant identified by
+/// [`RequestContext::tenant_id`](crate::context::RequestContext).
+#[async_trait]
+pub trait SlashCommandDefinitionRepository: Send + Sync {
+    /// Appends a version of a command definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandDefinitionError::VersionConflict`] when the
+    /// version does not directly follow the command's latest version, or
+    /// [`SlashCommandDefinitionError::Persistence`] if the underlying store
+    /// fails.
+    async fn append(
+        &self,
+        ctx: &RequestContext,
+        command: &VersionedSlashCommand,
+    ) -> SlashCommandDefinitionResult<()>;
+
+    /// Returns the latest version of a command, if it is registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandDefinitionError`] if the underlying store fails
+    /// or the stored definition cannot be decoded.
+    async fn find_latest(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+    ) -> SlashCommandDefinitionResult<Option<VersionedSlashCommand>>;
+
+    /// Returns one version of a command, if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandDefinitionError`] if the underlying store fails
+    /// or the stored definition cannot be decoded.
+    async fn find_version(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+        version: NonZeroU32,
+    ) -> SlashCommandDefinitionResult<Option<VersionedSlashCommand>>;
+
+    /// Returns every version of a command, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandDefinitionError`] if the underlying store fails
+    /// or a stored definition cannot be decoded.
+    async fn history(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+    ) -> SlashCommandDefinitionResult<Vec<VersionedSlashCommand>>;
+
+    /// Returns the latest version of every registered command, ordered by
+    /// command name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandDefinitionError`] if the underlying store fails
+    /// or a stored definition cannot be decoded.
+    async fn list_latest(
+        &self,
+        ctx: &RequestContext,
+    ) -> SlashCommandDefinitionResult<Vec<VersionedSlashCommand>>;
+
+    /// Removes every version of a command, returning how many were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandDefinitionError::Persistence`] if the
+    /// underlying store fails.
+    async fn remove(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+    ) -> SlashCommandDefinitionResult<usize>;
+}
+
+/// Errors for slash-command definition repository operations.
+#[derive(Debug, Clone, Error)]
+pub enum SlashCommandDefinitionError {
+    /// The appended version does not follow the command's latest version.
+    #[error("slash command '{command}' cannot take version {version}")]
+    VersionConflict {
+        /// Command name.
+        command: String,
+        /// Rejected version.
+        version: NonZeroU32,
+    },
+
+    /// A stored definition could not be decoded.
+    #[error("invalid persisted slash-command definition: {0}")]
+    InvalidPersistedData(String),
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl SlashCommandDefinitionError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
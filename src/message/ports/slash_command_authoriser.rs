@@ -0,0 +1,51 @@
+//! Port for deciding whether a caller may invoke a slash command.
+//!
+//! [`SlashCommandService`](crate::message::services::SlashCommandService)
+//! consults a [`SlashCommandAuthoriser`] for each permission a command
+//! requires, after resolving the command and before validating parameters
+//! or planning tool calls.
+
+use crate::context::RequestContext;
+use crate::message::domain::{CommandPermission, SlashCommandAuthorisationError};
+
+/// Result type for slash-command authorisation.
+pub type SlashCommandAuthorisationResult<T> = Result<T, SlashCommandAuthorisationError>;
+
+/// Decides which command permissions a caller holds.
+///
+/// Decisions come from role and scope grants already resolved for the
+/// request, so the port is synchronous.
+pub trait SlashCommandAuthoriser: Send + Sync {
+    /// Returns whether the caller identified by `ctx` holds `permission`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandAuthorisationError`] when grants cannot be
+    /// read.
+    fn holds(
+        &self,
+        ctx: &RequestContext,
+        permission: &CommandPermission,
+    ) -> SlashCommandAuthorisationResult<bool>;
+
+    /// Returns the permissions in `required` that the caller does not
+    /// hold, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandAuthorisationError`] when grants cannot be
+    /// read.
+    fn missing(
+        &self,
+        ctx: &RequestContext,
+        required: &[CommandPermission],
+    ) -> SlashCommandAuthorisationResult<Vec<CommandPermission>> {
+        let mut missing = Vec::new();
+        for permission in required {
+            if !self.holds(ctx, permission)? {
+                missing.push(permission.clone());
+            }
+        }
+        Ok(missing)
+    }
+}
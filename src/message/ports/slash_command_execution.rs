@@ -0,0 +1,98 @@
+//! Port for the slash-command execution audit trail.
+//!
+//! Defines the interface for recording who ran which command, with which
+//! arguments, and the tool calls it planned, and for reading those records
+//! back per conversation.
+
+use crate::context::RequestContext;
+use crate::message::domain::{
+    ConversationId, SlashCommandExecutionId, SlashCommandExecutionRecord,
+};
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for slash-command execution audit operations.
+pub type SlashCommandExecutionResult<T> = Result<T, SlashCommandExecutionError>;
+
+/// Port for persisting slash-command execution records.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Records are append-only; a record ID is stored at most once
+/// - Records are listed in execution order, ties broken by record id
+/// - All queries and mutations are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait SlashCommandExecutionRepository: Send + Sync {
+    /// Stores a new execution record.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandExecutionError::Duplicate`] if a record with
+    /// the same ID exists, or [`SlashCommandExecutionError::Persistence`] if
+    /// the underlying store fails.
+    async fn store(
+        &self,
+        ctx: &RequestContext,
+        record: &SlashCommandExecutionRecord,
+    ) -> SlashCommandExecutionResult<()>;
+
+    /// Retrieves a record by its ID, such as the `execution_id` of a
+    /// message's slash-command expansion.
+    ///
+    /// Returns `None` if the record does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandExecutionError`] if the underlying store fails
+    /// or the stored record cannot be decoded.
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: SlashCommandExecutionId,
+    ) -> SlashCommandExecutionResult<Option<SlashCommandExecutionRecord>>;
+
+    /// Lists a page of the commands run in a conversation, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandExecutionError`] if the underlying store fails
+    /// or a stored record cannot be decoded.
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> SlashCommandExecutionResult<Page<SlashCommandExecutionRecord>>;
+}
+
+/// Errors that can occur when persisting slash-command executions.
+#[derive(Debug, Clone, Error)]
+pub enum SlashCommandExecutionError {
+    /// Duplicate record ID.
+    #[error("duplicate slash-command execution: {0}")]
+    Duplicate(SlashCommandExecutionId),
+
+    /// A stored record could not be decoded.
+    #[error("invalid persisted slash-command execution: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl SlashCommandExecutionError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+
+    /// Creates an invalid persisted data error from any error type.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+}
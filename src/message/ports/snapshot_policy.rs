@@ -0,0 +1,79 @@
+//! Port for the periodic snapshot policies set on conversations.
+
+use crate::context::RequestContext;
+use crate::message::domain::{ConversationId, PeriodicSnapshotPolicy};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for snapshot policy operations.
+pub type SnapshotPolicyResult<T> = Result<T, SnapshotPolicyError>;
+
+/// Store of the periodic snapshot policies set on individual conversations.
+///
+/// # Implementation Notes
+///
+/// All queries and mutations are scoped to the tenant identified by
+/// [`RequestContext::tenant_id`](crate::context::RequestContext).
+#[async_trait]
+pub trait SnapshotPolicyRepository: Send + Sync {
+    /// Sets the conversation's policy, replacing any it had.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotPolicyError::ConversationNotFound`] when the tenant
+    /// has no such conversation, or [`SnapshotPolicyError::Persistence`] if
+    /// the underlying store fails.
+    async fn set_policy(
+        &self,
+        ctx: &RequestContext,
+        policy: &PeriodicSnapshotPolicy,
+    ) -> SnapshotPolicyResult<()>;
+
+    /// Removes the conversation's policy, returning whether it had one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotPolicyError::Persistence`] if the underlying store
+    /// fails.
+    async fn clear_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> SnapshotPolicyResult<bool>;
+
+    /// Returns the conversation's policy, if it has one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotPolicyError`] if the underlying store fails or the
+    /// stored policy cannot be decoded.
+    async fn find_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> SnapshotPolicyResult<Option<PeriodicSnapshotPolicy>>;
+}
+
+/// Errors that can occur when persisting snapshot policies.
+#[derive(Debug, Clone, Error)]
+pub enum SnapshotPolicyError {
+    /// The tenant has no such conversation.
+    #[error("conversation not found: {0}")]
+    ConversationNotFound(ConversationId),
+
+    /// A stored policy could not be decoded.
+    #[error("invalid persisted snapshot policy: {0}")]
+    InvalidPersistedData(String),
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl SnapshotPolicyError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
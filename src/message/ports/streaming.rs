@@ -0,0 +1,146 @@
+//! Port for staging streamed assistant messages.
+//!
+//! Defines the persistence interface that holds [`PartialMessage`]s while
+//! their content streams in, and that turns a finished stream into a stored
+//! message.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{Message, MessageId, PartialMessage},
+    error::RepositoryError,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for partial message persistence operations.
+pub type StreamingResult<T> = Result<T, StreamingError>;
+
+/// Port for storing partial messages and finalising them.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Each message identifier holds at most one staged stream
+/// - [`PartialMessageRepository::update`] only replaces a stream that still
+///   holds the expected number of chunks, so concurrent writers cannot lose
+///   each other's chunks
+/// - [`PartialMessageRepository::finalise`] appends the message and removes
+///   its staged stream together, or does neither
+/// - All queries and mutations are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait PartialMessageRepository: Send + Sync {
+    /// Stages a new stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::AlreadyStarted`] when a stream is already
+    /// staged for the message, or [`StreamingError::Persistence`] if the
+    /// underlying store fails.
+    async fn start(&self, ctx: &RequestContext, partial: &PartialMessage) -> StreamingResult<()>;
+
+    /// Replaces a staged stream that holds `expected_chunk_count` chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::NotFound`] when no stream is staged for the
+    /// message, [`StreamingError::Conflict`] when the staged stream no
+    /// longer holds `expected_chunk_count` chunks, or
+    /// [`StreamingError::Persistence`] if the underlying store fails.
+    async fn update(
+        &self,
+        ctx: &RequestContext,
+        partial: &PartialMessage,
+        expected_chunk_count: u32,
+    ) -> StreamingResult<()>;
+
+    /// Returns the stream staged for a message, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::Persistence`] if the underlying store
+    /// fails.
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> StreamingResult<Option<PartialMessage>>;
+
+    /// Returns up to `limit` streams that have received nothing since
+    /// `updated_before`, least recently updated first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::Persistence`] if the underlying store
+    /// fails.
+    async fn find_stalled(
+        &self,
+        ctx: &RequestContext,
+        updated_before: DateTime<Utc>,
+        limit: usize,
+    ) -> StreamingResult<Vec<PartialMessage>>;
+
+    /// Appends `message` as the next message of its conversation and
+    /// removes the stream staged under its identifier, atomically.
+    ///
+    /// Returns the stored message with its allocated sequence number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::NotFound`] when no stream is staged for the
+    /// message, [`StreamingError::Message`] when the message cannot be
+    /// appended, or [`StreamingError::Persistence`] if the underlying store
+    /// fails.
+    async fn finalise(&self, ctx: &RequestContext, message: &Message) -> StreamingResult<Message>;
+
+    /// Removes a staged stream without storing a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::NotFound`] when no stream is staged for the
+    /// message, or [`StreamingError::Persistence`] if the underlying store
+    /// fails.
+    async fn discard(&self, ctx: &RequestContext, message_id: MessageId) -> StreamingResult<()>;
+}
+
+/// Errors that can occur when persisting partial messages.
+#[derive(Debug, Error)]
+pub enum StreamingError {
+    /// No stream is staged for the message.
+    #[error("no stream staged for message {0}")]
+    NotFound(MessageId),
+
+    /// A stream is already staged for the message.
+    #[error("a stream is already staged for message {0}")]
+    AlreadyStarted(MessageId),
+
+    /// Another writer changed the stream first.
+    #[error("stream for message {0} was changed concurrently")]
+    Conflict(MessageId),
+
+    /// The finalised message could not be appended.
+    #[error(transparent)]
+    Message(#[from] RepositoryError),
+
+    /// A stored record could not be decoded.
+    #[error("invalid persisted partial message: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl StreamingError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+
+    /// Creates an invalid persisted data error from any error type.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+}
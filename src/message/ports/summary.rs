@@ -0,0 +1,121 @@
+//! Ports for rolling conversation summaries.
+//!
+//! [`ConversationSummariser`] wraps the model that writes summaries, and
+//! [`RollingSummaryRepository`] stores the latest summary of each
+//! conversation.
+
+use crate::context::RequestContext;
+use crate::message::domain::{ConversationId, Message, RollingSummary};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for summariser calls.
+pub type SummariserResult<T> = Result<T, SummariserError>;
+
+/// Result type for rolling summary persistence operations.
+pub type RollingSummaryResult<T> = Result<T, RollingSummaryError>;
+
+/// Model that summarises conversations.
+#[async_trait]
+pub trait ConversationSummariser: Send + Sync {
+    /// Summarises `messages`, which hold a whole conversation in sequence
+    /// order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SummariserError`] when the model fails.
+    async fn summarise(
+        &self,
+        ctx: &RequestContext,
+        messages: &[Message],
+    ) -> SummariserResult<String>;
+
+    /// Folds `messages`, which follow those already covered, into an
+    /// existing `summary`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SummariserError`] when the model fails.
+    async fn extend(
+        &self,
+        ctx: &RequestContext,
+        summary: &str,
+        messages: &[Message],
+    ) -> SummariserResult<String>;
+}
+
+/// Failure reported by a summariser.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("summariser failed: {0}")]
+pub struct SummariserError(String);
+
+impl SummariserError {
+    /// Creates an error with a human-readable reason.
+    #[must_use]
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self(reason.into())
+    }
+
+    /// Returns the failure reason.
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Port for storing the latest summary of each conversation.
+///
+/// # Implementation Notes
+///
+/// All queries and mutations are scoped to the tenant identified by
+/// [`RequestContext::tenant_id`](crate::context::RequestContext).
+#[async_trait]
+pub trait RollingSummaryRepository: Send + Sync {
+    /// Returns the conversation's summary, or `None` when it has none yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RollingSummaryError`] if the underlying store fails.
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RollingSummaryResult<Option<RollingSummary>>;
+
+    /// Stores a summary, replacing the conversation's earlier summary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RollingSummaryError::Persistence`] if the underlying store
+    /// fails.
+    async fn store(
+        &self,
+        ctx: &RequestContext,
+        summary: &RollingSummary,
+    ) -> RollingSummaryResult<()>;
+}
+
+/// Errors that can occur when persisting rolling summaries.
+#[derive(Debug, Clone, Error)]
+pub enum RollingSummaryError {
+    /// A stored summary could not be decoded.
+    #[error("invalid persisted summary: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl RollingSummaryError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+
+    /// Creates an invalid persisted data error from any error type.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+}
@@ -0,0 +1,77 @@
+//! Port for measuring text in model tokens.
+//!
+//! A [`TokenCounter`] counts the tokens one encoding splits text into. The
+//! provided [`TokenCounter::count_message`] applies it to the parts of a
+//! message a model reads: text, reasoning, tool call names and arguments,
+//! tool results, custom payloads, and the names, alt text, and titles of
+//! attachments, images, and citations. Binary payloads and redaction
+//! placeholders are not counted.
+
+use crate::message::domain::{ContentPart, Message, MessageTokenCount};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// Counts the model tokens text encodes to.
+///
+/// Counting is pure computation, so the port is synchronous.
+pub trait TokenCounter: Send + Sync {
+    /// Returns the name of the encoding counts are measured in.
+    fn encoding(&self) -> &str;
+
+    /// Returns the number of tokens `text` encodes to.
+    fn count(&self, text: &str) -> u64;
+
+    /// Returns the token count of a message's model-visible content.
+    fn count_message(&self, message: &Message) -> MessageTokenCount {
+        let tokens = message
+            .content()
+            .iter()
+            .flat_map(counted_text)
+            .map(|text| self.count(&text))
+            .fold(0_u64, u64::saturating_add);
+        MessageTokenCount::new(tokens, self.encoding())
+    }
+}
+
+/// Returns the pieces of `part` a model reads.
+fn counted_text(part: &ContentPart) -> Vec<Cow<'_, str>> {
+    match part {
+        ContentPart::Text(text) => vec![Cow::Borrowed(text.text.as_str())],
+        ContentPart::Reasoning(reasoning) => vec![Cow::Borrowed(reasoning.text.as_str())],
+        ContentPart::ToolCall(call) => {
+            vec![
+                Cow::Borrowed(call.name.as_str()),
+                json_text(&call.arguments),
+            ]
+        }
+        ContentPart::ToolResult(result) => vec![json_text(&result.content)],
+        ContentPart::Attachment(attachment) => attachment
+            .name
+            .as_deref()
+            .map(Cow::Borrowed)
+            .into_iter()
+            .collect(),
+        ContentPart::Image(image) => [image.name.as_deref(), image.alt_text.as_deref()]
+            .into_iter()
+            .flatten()
+            .map(Cow::Borrowed)
+            .collect(),
+        ContentPart::Citation(citation) => citation
+            .title
+            .as_deref()
+            .map(Cow::Borrowed)
+            .into_iter()
+            .collect(),
+        ContentPart::Custom(custom) => vec![json_text(&custom.data)],
+        ContentPart::Redacted(_) => Vec::new(),
+    }
+}
+
+/// Returns a JSON string's contents, or the compact encoding of any other
+/// value.
+fn json_text(value: &Value) -> Cow<'_, str> {
+    match value {
+        Value::String(text) => Cow::Borrowed(text.as_str()),
+        other => Cow::Owned(other.to_string()),
+    }
+}
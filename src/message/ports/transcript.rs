@@ -0,0 +1,65 @@
+//! Port for converting transcripts from foreign wire formats.
+//!
+//! A [`TranscriptConverter`] reads one [`TranscriptFormat`] and maps its
+//! roles, tool calls, tool results, and attachments onto canonical
+//! [`TranscriptMessage`]s. Converting is pure computation, so the port is
+//! synchronous.
+
+use crate::message::domain::{TranscriptFormat, TranscriptMessage};
+use thiserror::Error;
+
+/// Result type for transcript conversion.
+pub type TranscriptResult<T> = Result<T, TranscriptError>;
+
+/// Converts transcripts of one wire format into canonical messages.
+pub trait TranscriptConverter: Send + Sync {
+    /// Returns the wire format this converter reads.
+    fn format(&self) -> TranscriptFormat;
+
+    /// Converts a transcript payload into messages, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TranscriptError`] when the payload is not a transcript of
+    /// this format or uses a role or content block with no canonical
+    /// equivalent.
+    fn convert(&self, payload: &[u8]) -> TranscriptResult<Vec<TranscriptMessage>>;
+}
+
+/// Errors returned when a transcript cannot be converted.
+#[derive(Debug, Error)]
+pub enum TranscriptError {
+    /// The payload is not valid JSON of the expected shape.
+    #[error("malformed transcript: {0}")]
+    Malformed(#[from] serde_json::Error),
+    /// The transcript holds no messages.
+    #[error("transcript contains no messages")]
+    Empty,
+    /// A message has a role with no canonical equivalent.
+    #[error("transcript message {position} has unsupported role `{role}`")]
+    UnsupportedRole {
+        /// Position of the message in the foreign transcript.
+        position: usize,
+        /// The foreign role.
+        role: String,
+    },
+    /// A message holds a content block with no canonical equivalent.
+    #[error("transcript message {position} has unsupported content: {reason}")]
+    UnsupportedContent {
+        /// Position of the message in the foreign transcript.
+        position: usize,
+        /// What could not be converted.
+        reason: String,
+    },
+}
+
+impl TranscriptError {
+    /// Creates an unsupported content error for the message at `position`.
+    #[must_use]
+    pub fn unsupported_content(position: usize, reason: impl Into<String>) -> Self {
+        Self::UnsupportedContent {
+            position,
+            reason: reason.into(),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Port for moving conversations between tenants.
+//!
+//! A transfer moves the conversation together with everything recorded
+//! against it: messages, agent sessions, handoffs, context snapshots, fork
+//! provenance, summaries, feedback, processing status, redaction
+//! tombstones, staged message streams, retention policies, and usage
+//! records. Domain events are keyed by aggregate rather than tenant, so they
+//! follow the conversation without being rewritten. Sealed messages and
+//! staged streams move as they are: their associated data names the row,
+//! not the tenant, so they still open under the target tenant.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::domain::{ConversationId, ConversationTransfer};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Result type for conversation transfer operations.
+pub type ConversationTransferResult<T> = Result<T, ConversationTransferError>;
+
+/// Port for moving a conversation from one tenant to another.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - The conversation and the rows that reference it change tenant
+///   together, or not at all
+/// - [`ConversationTransfer::outgoing`] is recorded for the source tenant
+///   and [`ConversationTransfer::incoming`] for the target tenant as part
+///   of the same change
+/// - Conversations tied to source-tenant accounting, through a task link
+///   or a usage budget, are refused rather than split across tenants
+#[async_trait]
+pub trait ConversationTransferRepository: Send + Sync {
+    /// Moves the conversation described by `transfer` out of `ctx`'s tenant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationTransferError::NotFound`] when the source
+    /// tenant has no such conversation,
+    /// [`ConversationTransferError::LinkedToTask`] or
+    /// [`ConversationTransferError::Budgeted`] when it is tied to the
+    /// source tenant's accounting,
+    /// [`ConversationTransferError::TargetTenantSuspended`] when the target
+    /// tenant is suspended, or [`ConversationTransferError::Persistence`]
+    /// if the underlying store fails.
+    async fn transfer(
+        &self,
+        ctx: &RequestContext,
+        transfer: &ConversationTransfer,
+    ) -> ConversationTransferResult<()>;
+}
+
+/// Errors that can occur when moving a conversation.
+#[derive(Debug, Clone, Error)]
+pub enum ConversationTransferError {
+    /// The source tenant has no such conversation.
+    #[error("conversation not found: {0}")]
+    NotFound(ConversationId),
+
+    /// The conversation is linked to one of the source tenant's tasks.
+    #[error("conversation {conversation_id} is linked to task {task_id}")]
+    LinkedToTask {
+        /// The conversation.
+        conversation_id: ConversationId,
+        /// The linked task.
+        task_id: Uuid,
+    },
+
+    /// The conversation has a usage budget in the source tenant.
+    #[error("conversation {0} has a usage budget")]
+    Budgeted(ConversationId),
+
+    /// The target tenant is suspended and accepts no conversations.
+    #[error("target tenant {0} is suspended")]
+    TargetTenantSuspended(TenantId),
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl ConversationTransferError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
@@ -0,0 +1,126 @@
+//! Port for turn persistence.
+//!
+//! Defines the interface for storing the turns of a conversation and
+//! answering what happened within each of them.
+
+use crate::context::RequestContext;
+use crate::message::domain::{ConversationId, Message, Turn, TurnId, TurnStateError};
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for turn repository operations.
+pub type TurnResult<T> = Result<T, TurnError>;
+
+/// Port for turn persistence operations.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Turn IDs are unique
+/// - Turns are listed in start order, ties broken by turn id
+/// - Recording a response appends to the stored turn in one step, so
+///   concurrent responses are all kept and none lands on an ended turn
+/// - All queries and mutations are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait TurnRepository: Send + Sync {
+    /// Stores a new turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnError::Duplicate`] if a turn with the same ID exists,
+    /// or [`TurnError::Persistence`] if the underlying store fails.
+    async fn store(&self, ctx: &RequestContext, turn: &Turn) -> TurnResult<()>;
+
+    /// Stores `turn`'s status and end time.
+    ///
+    /// Responses and tool calls are left as stored; they are only added
+    /// through [`record_response`](Self::record_response), so one recorded
+    /// concurrently is not overwritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnError::NotFound`] if the turn does not exist, or
+    /// [`TurnError::Persistence`] if the underlying store fails.
+    async fn update(&self, ctx: &RequestContext, turn: &Turn) -> TurnResult<()>;
+
+    /// Records `message` as a response within a stored turn, along with the
+    /// tool calls it makes, as [`Turn::record_response`] does. Recording a
+    /// message twice has no further effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnError::NotFound`] if the turn does not exist,
+    /// [`TurnError::State`] if it has ended or belongs to another
+    /// conversation, or [`TurnError::Persistence`] if the underlying store
+    /// fails.
+    async fn record_response(
+        &self,
+        ctx: &RequestContext,
+        turn_id: TurnId,
+        message: &Message,
+    ) -> TurnResult<()>;
+
+    /// Retrieves a turn by its ID.
+    ///
+    /// Returns `None` if the turn does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnError`] if the underlying store fails or the stored
+    /// turn cannot be decoded.
+    async fn find_by_id(&self, ctx: &RequestContext, turn_id: TurnId) -> TurnResult<Option<Turn>>;
+
+    /// Lists a page of a conversation's turns, oldest first.
+    ///
+    /// Returns an empty page if the conversation has no turns.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnError`] if the underlying store fails or a stored turn
+    /// cannot be decoded.
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> TurnResult<Page<Turn>>;
+}
+
+/// Errors that can occur when persisting turns.
+#[derive(Debug, Clone, Error)]
+pub enum TurnError {
+    /// Turn not found.
+    #[error("turn not found: {0}")]
+    NotFound(TurnId),
+
+    /// Duplicate turn ID.
+    #[error("duplicate turn: {0}")]
+    Duplicate(TurnId),
+
+    /// The turn refused the change.
+    #[error(transparent)]
+    State(#[from] TurnStateError),
+
+    /// A stored turn could not be decoded.
+    #[error("invalid persisted turn: {0}")]
+    InvalidPersistedData(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// Database or persistence error.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl TurnError {
+    /// Creates a persistence error from any error type.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+
+    /// Creates an invalid persisted data error from any error type.
+    pub fn invalid_persisted_data(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidPersistedData(Arc::new(err))
+    }
+}
@@ -2,7 +2,15 @@
 //!
 //! Defines the abstract interface for validating messages at different layers.
 
-use crate::message::{domain::Message, error::ValidationError};
+mod policy;
+
+pub(crate) use policy::mime_essence;
+pub use policy::{AttachmentPolicy, RoleContentPolicy};
+
+use crate::message::{
+    domain::{ImagePart, Message},
+    error::ValidationError,
+};
 
 /// Result type for validation operations.
 pub type ValidationResult<T> = Result<T, ValidationError>;
@@ -54,6 +62,70 @@ pub trait MessageValidator: Send + Sync {
     fn validate_content(&self, message: &Message) -> ValidationResult<()>;
 }
 
+/// Layer of validation a [`ValidationRule`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationStage {
+    /// Checked by [`MessageValidator::validate_structure`].
+    Structure,
+    /// Checked by [`MessageValidator::validate_content`].
+    Content,
+}
+
+/// A single validation check that can be registered with a validator.
+///
+/// Downstream crates implement this trait to add their own checks without
+/// replacing the validator; see
+/// [`ValidationRuleRegistry`](crate::message::validation::ValidationRuleRegistry).
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::{
+///     domain::{Message, Role},
+///     error::ValidationError,
+///     ports::validator::{ValidationConfig, ValidationResult, ValidationRule, ValidationStage},
+/// };
+///
+/// /// Requires assistant messages to name the backend that wrote them.
+/// struct AssistantNamesBackend;
+///
+/// impl ValidationRule for AssistantNamesBackend {
+///     fn name(&self) -> &str {
+///         "assistant_names_backend"
+///     }
+///
+///     fn stage(&self) -> ValidationStage {
+///         ValidationStage::Structure
+///     }
+///
+///     fn check(&self, message: &Message, _config: &ValidationConfig) -> ValidationResult<()> {
+///         if message.role() == Role::Assistant && message.metadata().agent_backend.is_none() {
+///             return Err(ValidationError::InvalidMetadata(
+///                 "assistant messages must name their agent backend".to_owned(),
+///             ));
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait ValidationRule: Send + Sync {
+    /// Returns a short name identifying the rule.
+    fn name(&self) -> &str;
+
+    /// Returns the layer the rule is checked in; content by default.
+    fn stage(&self) -> ValidationStage {
+        ValidationStage::Content
+    }
+
+    /// Checks `message` against the rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` describing each way the message breaks the
+    /// rule.
+    fn check(&self, message: &Message, config: &ValidationConfig) -> ValidationResult<()>;
+}
+
 /// Configuration for validation rules.
 ///
 /// Allows customization of validation behaviour for different contexts.
@@ -79,6 +151,18 @@ pub struct ValidationConfig {
     pub max_text_length: usize,
     /// Whether to allow empty text parts.
     pub allow_empty_text: bool,
+    /// Largest attachment, in bytes of data, kept inside the message.
+    ///
+    /// Larger attachments are moved to a blob store when the message is
+    /// stored, so they do not count towards `max_message_size_bytes`. `None`
+    /// keeps every attachment inline.
+    pub max_inline_attachment_bytes: Option<usize>,
+    /// MIME types accepted for image parts.
+    pub supported_image_formats: &'static [&'static str],
+    /// Kinds of content part each role may send.
+    pub role_content_policy: RoleContentPolicy,
+    /// Type, encoding and size rules for attachments.
+    pub attachment_policy: AttachmentPolicy,
 }
 
 impl Default for ValidationConfig {
@@ -88,6 +172,10 @@ impl Default for ValidationConfig {
             max_content_parts: 100,
             max_text_length: 100_000,
             allow_empty_text: false,
+            max_inline_attachment_bytes: None,
+            supported_image_formats: ImagePart::SUPPORTED_FORMATS,
+            role_content_policy: RoleContentPolicy::DEFAULT,
+            attachment_policy: AttachmentPolicy::DEFAULT,
         }
     }
 }
@@ -114,6 +202,10 @@ impl ValidationConfig {
             max_content_parts: 20,
             max_text_length: 10_000,
             allow_empty_text: false,
+            max_inline_attachment_bytes: None,
+            supported_image_formats: ImagePart::SUPPORTED_FORMATS,
+            role_content_policy: RoleContentPolicy::DEFAULT,
+            attachment_policy: AttachmentPolicy::DEFAULT,
         }
     }
 }
@@ -0,0 +1,212 @@
+//! Content policies applied by [`ValidationConfig`](super::ValidationConfig).
+
+use crate::message::domain::{ContentPartKind, Role};
+
+/// Kinds of content part each message role may carry.
+///
+/// [`RoleContentPolicy::DEFAULT`] keeps tool calls and reasoning to
+/// assistant messages, tool results to tool messages, and attachments and
+/// images to user and assistant messages. Text, citations, custom parts and
+/// redaction placeholders are open to every role.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::{ContentPartKind, Role};
+/// use corbusier::message::ports::validator::RoleContentPolicy;
+///
+/// let policy = RoleContentPolicy::DEFAULT;
+/// assert!(policy.allows(Role::Assistant, ContentPartKind::ToolCall));
+/// assert!(!policy.allows(Role::User, ContentPartKind::ToolCall));
+///
+/// let policy = policy.with_role(
+///     Role::Tool,
+///     &[ContentPartKind::Text, ContentPartKind::ToolResult, ContentPartKind::Image],
+/// );
+/// assert!(policy.allows(Role::Tool, ContentPartKind::Image));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoleContentPolicy {
+    user: &'static [ContentPartKind],
+    assistant: &'static [ContentPartKind],
+    tool: &'static [ContentPartKind],
+    system: &'static [ContentPartKind],
+}
+
+impl RoleContentPolicy {
+    /// The policy applied unless a configuration chooses another.
+    pub const DEFAULT: Self = Self {
+        user: &[
+            ContentPartKind::Text,
+            ContentPartKind::Attachment,
+            ContentPartKind::Image,
+            ContentPartKind::Citation,
+            ContentPartKind::Custom,
+            ContentPartKind::Redacted,
+        ],
+        assistant: &[
+            ContentPartKind::Text,
+            ContentPartKind::Reasoning,
+            ContentPartKind::ToolCall,
+            ContentPartKind::Attachment,
+            ContentPartKind::Image,
+            ContentPartKind::Citation,
+            ContentPartKind::Custom,
+            ContentPartKind::Redacted,
+        ],
+        tool: &[
+            ContentPartKind::Text,
+            ContentPartKind::ToolResult,
+            ContentPartKind::Citation,
+            ContentPartKind::Custom,
+            ContentPartKind::Redacted,
+        ],
+        system: &[
+            ContentPartKind::Text,
+            ContentPartKind::Citation,
+            ContentPartKind::Custom,
+            ContentPartKind::Redacted,
+        ],
+    };
+
+    /// A policy letting every role carry every kind of part.
+    pub const PERMISSIVE: Self = Self {
+        user: &ContentPartKind::ALL,
+        assistant: &ContentPartKind::ALL,
+        tool: &ContentPartKind::ALL,
+        system: &ContentPartKind::ALL,
+    };
+
+    /// Replaces the kinds `role` may carry.
+    #[must_use]
+    pub const fn with_role(mut self, role: Role, kinds: &'static [ContentPartKind]) -> Self {
+        match role {
+            Role::User => self.user = kinds,
+            Role::Assistant => self.assistant = kinds,
+            Role::Tool => self.tool = kinds,
+            Role::System => self.system = kinds,
+        }
+        self
+    }
+
+    /// Returns the kinds of part `role` may carry.
+    #[must_use]
+    pub const fn allowed(&self, role: Role) -> &'static [ContentPartKind] {
+        match role {
+            Role::User => self.user,
+            Role::Assistant => self.assistant,
+            Role::Tool => self.tool,
+            Role::System => self.system,
+        }
+    }
+
+    /// Returns `true` when `role` may carry parts of `kind`.
+    #[must_use]
+    pub fn allows(&self, role: Role, kind: ContentPartKind) -> bool {
+        self.allowed(role).contains(&kind)
+    }
+}
+
+impl Default for RoleContentPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Type, encoding and size rules for attachment parts.
+///
+/// Attachment data is base64 unless the MIME type is textual (`text/*`,
+/// JSON or XML), when it may also be plain text. Sizes are counted in
+/// decoded bytes; attachments already moved to a blob store are measured by
+/// their recorded `size_bytes`.
+///
+/// MIME types are compared case-insensitively and without parameters. A
+/// listed type ending in `/*` matches every subtype, so `"image/*"` admits
+/// `image/png`.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::ports::validator::AttachmentPolicy;
+///
+/// let policy = AttachmentPolicy {
+///     allowed_mime_types: Some(&["image/*", "application/pdf"]),
+///     max_attachment_bytes: Some(5 * 1024 * 1024),
+///     ..AttachmentPolicy::DEFAULT
+/// };
+/// assert!(policy.permits_mime_type("image/PNG"));
+/// assert!(!policy.permits_mime_type("text/html; charset=utf-8"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttachmentPolicy {
+    /// MIME types accepted, or `None` to accept any type not denied.
+    pub allowed_mime_types: Option<&'static [&'static str]>,
+    /// MIME types rejected even when allowed.
+    pub denied_mime_types: &'static [&'static str],
+    /// Largest single attachment, in decoded bytes.
+    pub max_attachment_bytes: Option<usize>,
+    /// Largest total of a message's attachments, in decoded bytes.
+    pub max_total_attachment_bytes: Option<usize>,
+    /// Whether data of non-textual types must be valid base64.
+    pub verify_encoding: bool,
+    /// Whether decoded data must match the file signature of its MIME type.
+    pub sniff_content: bool,
+}
+
+impl AttachmentPolicy {
+    /// Executable types, rejected by [`AttachmentPolicy::DEFAULT`].
+    pub const EXECUTABLE_MIME_TYPES: &'static [&'static str] = &[
+        "application/x-msdownload",
+        "application/x-dosexec",
+        "application/x-executable",
+    ];
+
+    /// The policy applied unless a configuration chooses another: any type
+    /// but executables, no size caps, and encoding and signature checks on.
+    pub const DEFAULT: Self = Self {
+        allowed_mime_types: None,
+        denied_mime_types: Self::EXECUTABLE_MIME_TYPES,
+        max_attachment_bytes: None,
+        max_total_attachment_bytes: None,
+        verify_encoding: true,
+        sniff_content: true,
+    };
+
+    /// Returns `true` when attachments of `mime_type` are accepted.
+    #[must_use]
+    pub fn permits_mime_type(&self, mime_type: &str) -> bool {
+        let essence = mime_essence(mime_type);
+        let matches = |listed: &&str| mime_matches(listed, &essence);
+        self.allowed_mime_types
+            .is_none_or(|allowed| allowed.iter().any(matches))
+            && !self.denied_mime_types.iter().any(matches)
+    }
+}
+
+impl Default for AttachmentPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Returns `mime_type` lowercased and without parameters.
+pub(crate) fn mime_essence(mime_type: &str) -> String {
+    mime_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+fn mime_matches(listed: &str, essence: &str) -> bool {
+    let listed = listed.to_ascii_lowercase();
+    listed.strip_suffix("/*").map_or_else(
+        || listed == essence,
+        |top_level| {
+            essence
+                .strip_prefix(top_level)
+                .is_some_and(|rest| rest.starts_with('/'))
+        },
+    )
+}
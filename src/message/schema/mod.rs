@@ -0,0 +1,273 @@
+//! JSON Schemas for the canonical message format.
+//!
+//! Each version of the message format has a JSON Schema describing
+//! [`Message`], [`ContentPart`], and [`MessageMetadata`]. The versions follow
+//! the `MessageCreated` event: version 2 adds message metadata and version 3
+//! adds image parts. The schema documents are embedded as const strings, so
+//! they can be published to integrators as they are, and [`MessageSchema`]
+//! validates inbound payloads against them at runtime.
+//!
+//! [`Message`]: crate::message::domain::Message
+//! [`ContentPart`]: crate::message::domain::ContentPart
+//! [`MessageMetadata`]: crate::message::domain::MessageMetadata
+
+mod validator;
+
+use crate::message::error::{MessageSchemaError, SchemaViolation};
+use crate::message::versioning::MessageCreatedUpgrader;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use validator::Validator;
+
+/// The schema of version 1 of the message format.
+pub const MESSAGE_SCHEMA_V1: &str = include_str!("message.v1.schema.json");
+
+/// The schema of version 2 of the message format.
+pub const MESSAGE_SCHEMA_V2: &str = include_str!("message.v2.schema.json");
+
+/// The schema of version 3 of the message format.
+pub const MESSAGE_SCHEMA_V3: &str = include_str!("message.v3.schema.json");
+
+/// Every version of the message format with a schema, oldest first.
+pub const SCHEMA_VERSIONS: [u32; 3] = [1, 2, 3];
+
+/// The version of the message format written today.
+pub const CURRENT_SCHEMA_VERSION: u32 = MessageCreatedUpgrader::CURRENT_VERSION;
+
+/// Returns the schema document of `version` of the message format.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::schema::{CURRENT_SCHEMA_VERSION, schema_document};
+///
+/// let document = schema_document(CURRENT_SCHEMA_VERSION).expect("current schema");
+/// assert!(document.contains("\"ImagePart\""));
+/// assert!(schema_document(0).is_none());
+/// ```
+#[must_use]
+pub const fn schema_document(version: u32) -> Option<&'static str> {
+    match version {
+        1 => Some(MESSAGE_SCHEMA_V1),
+        2 => Some(MESSAGE_SCHEMA_V2),
+        3 => Some(MESSAGE_SCHEMA_V3),
+        _ => None,
+    }
+}
+
+/// A type of the message format that has a schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaType {
+    /// A whole message.
+    Message,
+    /// A single content part.
+    ContentPart,
+    /// Message metadata, defined from version 2.
+    MessageMetadata,
+}
+
+impl SchemaType {
+    /// Every type with a schema.
+    pub const ALL: [Self; 3] = [Self::Message, Self::ContentPart, Self::MessageMetadata];
+
+    /// Returns the name of the type's definition in a schema document.
+    #[must_use]
+    pub const fn definition(self) -> &'static str {
+        match self {
+            Self::Message => "Message",
+            Self::ContentPart => "ContentPart",
+            Self::MessageMetadata => "MessageMetadata",
+        }
+    }
+}
+
+impl fmt::Display for SchemaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.definition())
+    }
+}
+
+/// A loaded schema document, ready to validate payloads.
+///
+/// Loading parses the document and compiles its patterns, so load a schema
+/// once and keep it.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::schema::{MessageSchema, SchemaType};
+/// use serde_json::json;
+///
+/// let schema = MessageSchema::current()?;
+/// schema.validate(SchemaType::ContentPart, &json!({"type": "text", "text": "Hi"}))?;
+/// assert!(schema.validate(SchemaType::ContentPart, &json!({"type": "text"})).is_err());
+/// # Ok::<(), corbusier::message::error::MessageSchemaError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct MessageSchema {
+    version: u32,
+    document: Value,
+    patterns: HashMap<String, Regex>,
+}
+
+impl MessageSchema {
+    /// Loads the schema of `version` of the message format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessageSchemaError::UnknownVersion` when no schema exists
+    /// for `version`, and `InvalidSchema` when its document cannot be
+    /// loaded.
+    pub fn load(version: u32) -> Result<Self, MessageSchemaError> {
+        let text = schema_document(version).ok_or(MessageSchemaError::UnknownVersion(version))?;
+        let invalid = |reason: String| MessageSchemaError::InvalidSchema { version, reason };
+        let document: Value = serde_json::from_str(text).map_err(|err| invalid(err.to_string()))?;
+        let patterns = compile_patterns(&document).map_err(|err| invalid(err.to_string()))?;
+        Ok(Self {
+            version,
+            document,
+            patterns,
+        })
+    }
+
+    /// Loads the schema of the current version of the message format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessageSchemaError::InvalidSchema` when the document cannot
+    /// be loaded.
+    pub fn current() -> Result<Self, MessageSchemaError> {
+        Self::load(CURRENT_SCHEMA_VERSION)
+    }
+
+    /// Returns the version of the message format this schema describes.
+    #[must_use]
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns `true` if this version of the format defines `schema_type`.
+    #[must_use]
+    pub fn defines(&self, schema_type: SchemaType) -> bool {
+        self.definition(schema_type).is_some()
+    }
+
+    /// Returns a standalone JSON Schema for `schema_type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessageSchemaError::UndefinedType` when this version of the
+    /// format predates `schema_type`.
+    pub fn json_schema(&self, schema_type: SchemaType) -> Result<Value, MessageSchemaError> {
+        if !self.defines(schema_type) {
+            return Err(self.undefined(schema_type));
+        }
+        let mut schema = self.document.clone();
+        if let Some(root) = schema.as_object_mut() {
+            root.insert(
+                "title".to_owned(),
+                Value::from(format!(
+                    "Corbusier {schema_type}, schema version {}",
+                    self.version
+                )),
+            );
+            root.insert(
+                "$ref".to_owned(),
+                Value::from(format!("#/$defs/{}", schema_type.definition())),
+            );
+        }
+        Ok(schema)
+    }
+
+    /// Checks `payload` against the schema of `schema_type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessageSchemaError::Violations` listing every mismatch, or
+    /// `UndefinedType` when this version of the format predates
+    /// `schema_type`.
+    pub fn validate(
+        &self,
+        schema_type: SchemaType,
+        payload: &Value,
+    ) -> Result<(), MessageSchemaError> {
+        let schema = self
+            .definition(schema_type)
+            .ok_or_else(|| self.undefined(schema_type))?;
+        let violations = Validator::new(&self.document, &self.patterns).violations(schema, payload);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(MessageSchemaError::Violations(violations))
+        }
+    }
+
+    fn definition(&self, schema_type: SchemaType) -> Option<&Value> {
+        self.document.get("$defs")?.get(schema_type.definition())
+    }
+
+    fn undefined(&self, schema_type: SchemaType) -> MessageSchemaError {
+        MessageSchemaError::UndefinedType {
+            version: self.version,
+            schema_type: schema_type.to_string(),
+        }
+    }
+}
+
+/// A standalone schema fragment with its patterns compiled.
+///
+/// Fragments validate values outside message payloads, such as the JSON
+/// Schema constraints on slash-command parameters. References resolve
+/// against the fragment itself.
+#[derive(Debug, Clone)]
+pub(crate) struct SchemaFragment<'a> {
+    schema: &'a Value,
+    patterns: HashMap<String, Regex>,
+}
+
+impl<'a> SchemaFragment<'a> {
+    /// Compiles the patterns of `schema`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the regex error of the first pattern that does not compile.
+    pub(crate) fn compile(schema: &'a Value) -> Result<Self, regex::Error> {
+        Ok(Self {
+            schema,
+            patterns: compile_patterns(schema)?,
+        })
+    }
+
+    /// Returns every way `value` fails to match the fragment.
+    pub(crate) fn violations(&self, value: &Value) -> Vec<SchemaViolation> {
+        Validator::new(self.schema, &self.patterns).violations(self.schema, value)
+    }
+}
+
+/// Compiles every `pattern` keyword in `schema`, keyed by its source.
+fn compile_patterns(schema: &Value) -> Result<HashMap<String, Regex>, regex::Error> {
+    let mut sources = Vec::new();
+    collect_patterns(schema, &mut sources);
+    sources
+        .into_iter()
+        .map(|source| Regex::new(source).map(|regex| (source.to_owned(), regex)))
+        .collect()
+}
+
+/// Collects the source of every `pattern` keyword in `schema`.
+fn collect_patterns<'a>(schema: &'a Value, out: &mut Vec<&'a str>) {
+    match schema {
+        Value::Object(keywords) => {
+            out.extend(keywords.get("pattern").and_then(Value::as_str));
+            keywords
+                .values()
+                .for_each(|nested| collect_patterns(nested, out));
+        }
+        Value::Array(items) => items
+            .iter()
+            .for_each(|nested| collect_patterns(nested, out)),
+        _ => {}
+    }
+}
@@ -0,0 +1,285 @@
+//! Validation of JSON values against the embedded schema documents.
+//!
+//! Only the keywords the message schemas and slash-command parameter
+//! constraints use are understood: `$ref` to a local definition, `type`,
+//! `const`, `enum`, `minimum`, `maximum`, `minLength`, `maxLength`,
+//! `pattern`, `properties`, `required`, `additionalProperties: false`,
+//! `items`, `minItems`, and `oneOf`. Annotations such as `format` and
+//! `default` are ignored, as JSON Schema allows.
+
+use crate::message::error::SchemaViolation;
+use regex::Regex;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+type Keywords = Map<String, Value>;
+
+/// Checks values against definitions of one schema document.
+pub(super) struct Validator<'a> {
+    document: &'a Value,
+    patterns: &'a HashMap<String, Regex>,
+}
+
+impl<'a> Validator<'a> {
+    /// Creates a validator resolving references within `document`.
+    pub(super) const fn new(document: &'a Value, patterns: &'a HashMap<String, Regex>) -> Self {
+        Self { document, patterns }
+    }
+
+    /// Returns every way `value` fails to match `schema`.
+    pub(super) fn violations(&self, schema: &Value, value: &Value) -> Vec<SchemaViolation> {
+        self.check(schema, value, "")
+    }
+
+    fn check(&self, schema: &Value, value: &Value, path: &str) -> Vec<SchemaViolation> {
+        match schema {
+            Value::Bool(true) => Vec::new(),
+            Value::Object(keywords) => self.check_keywords(keywords, value, path),
+            _ => vec![SchemaViolation::new(path, "no value is allowed here")],
+        }
+    }
+
+    fn check_keywords(
+        &self,
+        keywords: &Keywords,
+        value: &Value,
+        path: &str,
+    ) -> Vec<SchemaViolation> {
+        let mut out = keywords
+            .get("$ref")
+            .and_then(Value::as_str)
+            .map(|reference| self.check_reference(reference, value, path))
+            .unwrap_or_default();
+        if let Some(wrong_type) = check_type(keywords, value, path) {
+            out.push(wrong_type);
+            return out;
+        }
+        out.extend(check_literal(keywords, value, path));
+        out.extend(check_bounds(keywords, value, path));
+        out.extend(self.check_pattern(keywords, value, path));
+        match value {
+            Value::Object(fields) => out.extend(self.check_object(keywords, fields, path)),
+            Value::Array(items) => out.extend(self.check_array(keywords, items, path)),
+            _ => {}
+        }
+        if let Some(options) = keywords.get("oneOf").and_then(Value::as_array) {
+            out.extend(self.check_one_of(options, value, path));
+        }
+        out
+    }
+
+    fn check_reference(&self, reference: &str, value: &Value, path: &str) -> Vec<SchemaViolation> {
+        let target = reference
+            .strip_prefix("#/$defs/")
+            .and_then(|name| self.document.get("$defs")?.get(name));
+        match target {
+            Some(schema) => self.check(schema, value, path),
+            None => vec![SchemaViolation::new(
+                path,
+                format!("schema refers to unknown definition {reference}"),
+            )],
+        }
+    }
+
+    fn check_pattern(
+        &self,
+        keywords: &Keywords,
+        value: &Value,
+        path: &str,
+    ) -> Option<SchemaViolation> {
+        let pattern = keywords.get("pattern").and_then(Value::as_str)?;
+        let text = value.as_str()?;
+        let matches = self
+            .patterns
+            .get(pattern)
+            .is_some_and(|regex| regex.is_match(text));
+        (!matches)
+            .then(|| SchemaViolation::new(path, format!("does not match the pattern {pattern}")))
+    }
+
+    fn check_object(
+        &self,
+        keywords: &Keywords,
+        fields: &Map<String, Value>,
+        path: &str,
+    ) -> Vec<SchemaViolation> {
+        let mut out: Vec<SchemaViolation> = keywords
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter(|name| !fields.contains_key(*name))
+            .map(|name| SchemaViolation::new(path, format!("missing required property `{name}`")))
+            .collect();
+        let properties = keywords.get("properties").and_then(Value::as_object);
+        let closed = keywords.get("additionalProperties") == Some(&Value::Bool(false));
+        for (name, field) in fields {
+            let field_path = format!("{path}/{}", escape_pointer(name));
+            match properties.and_then(|declared| declared.get(name)) {
+                Some(schema) => out.extend(self.check(schema, field, &field_path)),
+                None if closed => out.push(SchemaViolation::new(field_path, "unexpected property")),
+                None => {}
+            }
+        }
+        out
+    }
+
+    fn check_array(
+        &self,
+        keywords: &Keywords,
+        items: &[Value],
+        path: &str,
+    ) -> Vec<SchemaViolation> {
+        let mut out: Vec<SchemaViolation> = keywords
+            .get("minItems")
+            .and_then(Value::as_u64)
+            .and_then(|min| usize::try_from(min).ok())
+            .filter(|&min| items.len() < min)
+            .map(|min| {
+                SchemaViolation::new(
+                    path,
+                    format!("expected at least {min} items, found {}", items.len()),
+                )
+            })
+            .into_iter()
+            .collect();
+        if let Some(schema) = keywords.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                out.extend(self.check(schema, item, &format!("{path}/{index}")));
+            }
+        }
+        out
+    }
+
+    /// Requires exactly one of `options` to match. When none does, the
+    /// violations of the closest option are reported if one is closer than
+    /// the rest.
+    fn check_one_of(&self, options: &[Value], value: &Value, path: &str) -> Vec<SchemaViolation> {
+        let mut attempts: Vec<Vec<SchemaViolation>> = options
+            .iter()
+            .map(|option| self.check(option, value, path))
+            .collect();
+        let matched = attempts.iter().filter(|attempt| attempt.is_empty()).count();
+        if matched == 1 {
+            return Vec::new();
+        }
+        if matched > 1 {
+            return vec![SchemaViolation::new(
+                path,
+                format!("matches {matched} alternatives where exactly one is allowed"),
+            )];
+        }
+        attempts.sort_by_key(Vec::len);
+        match attempts.as_mut_slice() {
+            [closest, next, ..] if closest.len() < next.len() => std::mem::take(closest),
+            [only] => std::mem::take(only),
+            _ => vec![SchemaViolation::new(
+                path,
+                "matches none of the allowed alternatives",
+            )],
+        }
+    }
+}
+
+/// Checks the `type` keyword. No further keywords apply to a value of the
+/// wrong type.
+fn check_type(keywords: &Keywords, value: &Value, path: &str) -> Option<SchemaViolation> {
+    let expected = keywords.get("type").and_then(Value::as_str)?;
+    let found = type_name(value);
+    (!has_type(value, expected))
+        .then(|| SchemaViolation::new(path, format!("expected {expected}, found {found}")))
+}
+
+/// Checks the `const`, `enum`, and `minimum` keywords.
+fn check_literal(keywords: &Keywords, value: &Value, path: &str) -> Vec<SchemaViolation> {
+    let mut out = Vec::new();
+    if let Some(expected) = keywords.get("const").filter(|expected| *expected != value) {
+        out.push(SchemaViolation::new(path, format!("expected {expected}")));
+    }
+    if let Some(options) = keywords
+        .get("enum")
+        .and_then(Value::as_array)
+        .filter(|options| !options.contains(value))
+    {
+        let allowed: Vec<String> = options.iter().map(ToString::to_string).collect();
+        out.push(SchemaViolation::new(
+            path,
+            format!("expected one of {}", allowed.join(", ")),
+        ));
+    }
+    if let Some((minimum, actual)) = keywords
+        .get("minimum")
+        .and_then(Value::as_f64)
+        .zip(value.as_f64())
+        .filter(|(minimum, actual)| actual < minimum)
+    {
+        out.push(SchemaViolation::new(
+            path,
+            format!("expected at least {minimum}, found {actual}"),
+        ));
+    }
+    out
+}
+
+/// Checks the `maximum`, `minLength`, and `maxLength` keywords.
+fn check_bounds(keywords: &Keywords, value: &Value, path: &str) -> Vec<SchemaViolation> {
+    let mut out = Vec::new();
+    if let Some((maximum, actual)) = keywords
+        .get("maximum")
+        .and_then(Value::as_f64)
+        .zip(value.as_f64())
+        .filter(|(maximum, actual)| actual > maximum)
+    {
+        out.push(SchemaViolation::new(
+            path,
+            format!("expected at most {maximum}, found {actual}"),
+        ));
+    }
+    let Some(length) = value.as_str().map(|text| text.chars().count()) else {
+        return out;
+    };
+    let bound = |keyword: &str| {
+        keywords
+            .get(keyword)
+            .and_then(Value::as_u64)
+            .and_then(|bound| usize::try_from(bound).ok())
+    };
+    if let Some(min) = bound("minLength").filter(|&min| length < min) {
+        out.push(SchemaViolation::new(
+            path,
+            format!("expected at least {min} characters, found {length}"),
+        ));
+    }
+    if let Some(max) = bound("maxLength").filter(|&max| length > max) {
+        out.push(SchemaViolation::new(
+            path,
+            format!("expected at most {max} characters, found {length}"),
+        ));
+    }
+    out
+}
+
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        other => type_name(value) == other,
+    }
+}
+
+const fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Escapes a property name for use in a JSON pointer.
+fn escape_pointer(name: &str) -> String {
+    name.replace('~', "~0").replace('/', "~1")
+}
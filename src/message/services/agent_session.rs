@@ -0,0 +1,207 @@
+//! Application service for pausing and resuming agent sessions.
+//!
+//! [`AgentSessionService`] moves a session between the active and paused
+//! states. Pausing captures a [`SnapshotType::SessionPaused`] snapshot of
+//! the session's context window; resuming finds that snapshot again, so the
+//! agent knows which messages to reload, and records it on the session if
+//! the session lost track of it.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        AgentSession, AgentSessionId, AgentSessionState, ContextWindowSnapshot, MessageSummary,
+        SequenceNumber, SequenceRange, SnapshotParams, SnapshotType,
+    },
+    ports::{
+        agent_session::{AgentSessionRepository, SessionError},
+        context_snapshot::{ContextSnapshotPort, SnapshotError},
+    },
+};
+use crate::pagination::collect_pages;
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Service-level errors for agent session pauses and resumptions.
+#[derive(Debug, Error)]
+pub enum AgentSessionServiceError {
+    /// Session does not exist.
+    #[error("agent session not found: {0}")]
+    SessionNotFound(AgentSessionId),
+    /// The session is not in a state the operation applies to.
+    #[error("cannot move agent session {session_id} from {from} to {to}")]
+    InvalidTransition {
+        /// The session.
+        session_id: AgentSessionId,
+        /// The session's current state.
+        from: AgentSessionState,
+        /// The state the operation would have moved it to.
+        to: AgentSessionState,
+    },
+    /// Session repository failure.
+    #[error(transparent)]
+    Session(#[from] SessionError),
+    /// Snapshot store failure.
+    #[error(transparent)]
+    Snapshot(#[from] SnapshotError),
+}
+
+/// Result type for agent session service operations.
+pub type AgentSessionServiceResult<T> = Result<T, AgentSessionServiceError>;
+
+/// A session returned to active state, with the snapshot its pause captured.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResumedSession {
+    /// The session, now active.
+    pub session: AgentSession,
+    /// The snapshot captured when the session was last paused, or `None`
+    /// when the session was paused without this service.
+    pub pause_snapshot: Option<ContextWindowSnapshot>,
+}
+
+/// Pauses and resumes agent sessions.
+#[derive(Clone)]
+pub struct AgentSessionService<S, C, K>
+where
+    S: AgentSessionRepository,
+    C: ContextSnapshotPort,
+    K: Clock + Send + Sync,
+{
+    session_repo: Arc<S>,
+    snapshot_adapter: Arc<C>,
+    clock: Arc<K>,
+}
+
+impl<S, C, K> AgentSessionService<S, C, K>
+where
+    S: AgentSessionRepository,
+    C: ContextSnapshotPort,
+    K: Clock + Send + Sync,
+{
+    /// Creates a new agent session service.
+    pub const fn new(session_repo: Arc<S>, snapshot_adapter: Arc<C>, clock: Arc<K>) -> Self {
+        Self {
+            session_repo,
+            snapshot_adapter,
+            clock,
+        }
+    }
+
+    /// Pauses an active session.
+    ///
+    /// Captures a [`SnapshotType::SessionPaused`] snapshot of the messages
+    /// from the session's start to `current_sequence`, records it on the
+    /// session, and stores the session as paused.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AgentSessionServiceError::SessionNotFound`] when the
+    /// session does not exist,
+    /// [`AgentSessionServiceError::InvalidTransition`] when it is not
+    /// active, or repository errors.
+    pub async fn pause(
+        &self,
+        ctx: &RequestContext,
+        session_id: AgentSessionId,
+        current_sequence: SequenceNumber,
+    ) -> AgentSessionServiceResult<AgentSession> {
+        let mut session = self.find(ctx, session_id).await?;
+        if session.state != AgentSessionState::Active {
+            return Err(invalid_transition(&session, AgentSessionState::Paused));
+        }
+
+        let snapshot = ContextWindowSnapshot::new(
+            SnapshotParams {
+                conversation_id: session.conversation_id,
+                session_id,
+                sequence_range: SequenceRange::new(session.start_sequence, current_sequence),
+                message_summary: MessageSummary::default(),
+                snapshot_type: SnapshotType::SessionPaused,
+            },
+            self.clock.as_ref(),
+        );
+        self.snapshot_adapter.store_snapshot(ctx, &snapshot).await?;
+
+        session.pause();
+        session.add_snapshot(snapshot);
+        self.session_repo.update(ctx, &session).await?;
+        Ok(session)
+    }
+
+    /// Resumes a paused session.
+    ///
+    /// Looks up the snapshot captured by the session's latest pause and
+    /// records it on the session when missing, then stores the session as
+    /// active.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AgentSessionServiceError::SessionNotFound`] when the
+    /// session does not exist,
+    /// [`AgentSessionServiceError::InvalidTransition`] when it is not
+    /// paused, or repository errors.
+    pub async fn resume(
+        &self,
+        ctx: &RequestContext,
+        session_id: AgentSessionId,
+    ) -> AgentSessionServiceResult<ResumedSession> {
+        let mut session = self.find(ctx, session_id).await?;
+        if session.state != AgentSessionState::Paused {
+            return Err(invalid_transition(&session, AgentSessionState::Active));
+        }
+
+        let pause_snapshot = self.latest_pause_snapshot(ctx, session_id).await?;
+        if let Some(snapshot) = &pause_snapshot
+            && !session
+                .context_snapshots
+                .iter()
+                .any(|recorded| recorded.snapshot_id == snapshot.snapshot_id)
+        {
+            session.add_snapshot(snapshot.clone());
+        }
+        session.resume();
+        self.session_repo.update(ctx, &session).await?;
+        Ok(ResumedSession {
+            session,
+            pause_snapshot,
+        })
+    }
+
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        session_id: AgentSessionId,
+    ) -> AgentSessionServiceResult<AgentSession> {
+        self.session_repo
+            .find_by_id(ctx, session_id)
+            .await?
+            .ok_or(AgentSessionServiceError::SessionNotFound(session_id))
+    }
+
+    async fn latest_pause_snapshot(
+        &self,
+        ctx: &RequestContext,
+        session_id: AgentSessionId,
+    ) -> AgentSessionServiceResult<Option<ContextWindowSnapshot>> {
+        let snapshots = collect_pages(|page| {
+            self.snapshot_adapter
+                .find_snapshots_for_session(ctx, session_id, page)
+        })
+        .await?;
+        Ok(snapshots
+            .into_iter()
+            .filter(|snapshot| snapshot.snapshot_type == SnapshotType::SessionPaused)
+            .max_by_key(|snapshot| snapshot.captured_at))
+    }
+}
+
+const fn invalid_transition(
+    session: &AgentSession,
+    to: AgentSessionState,
+) -> AgentSessionServiceError {
+    AgentSessionServiceError::InvalidTransition {
+        session_id: session.session_id,
+        from: session.state,
+        to,
+    }
+}
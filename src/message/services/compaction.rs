@@ -0,0 +1,277 @@
+//! Application service that compacts long conversation histories.
+//!
+//! [`ConversationCompactionService`] counts the messages that follow a
+//! conversation's latest compaction. Once the [`CompactionPolicy`] threshold
+//! is reached it summarises all but the most recent of them, extending the
+//! previous compaction's summary through [`ConversationSummariser::extend`]
+//! or, for a first compaction, summarising from the start. The result is
+//! stored as a [`SnapshotType::Compaction`] snapshot against the
+//! conversation's active agent session, its sequence range marking every
+//! message the summary stands in for. Pinned messages are neither counted
+//! towards the threshold nor summarised.
+//!
+//! [`ConversationCompactionService::history`] serves the history a context
+//! assembler should send in place of the raw transcript: the latest summary,
+//! the pinned messages it covers, and the messages after it. Pins live on
+//! the conversation's messages rather than on an agent session, so they
+//! carry across handoffs.
+//!
+//! The service is a [`ConversationLifecycleHook`]: register it with
+//! [`super::ConversationLifecycleHooks`] for
+//! [`ConversationLifecyclePoint::MessageStored`] and conversations are
+//! compacted as they grow. With a maintenance gate attached, nothing is
+//! compacted while the cluster is in maintenance.
+
+use crate::context::RequestContext;
+use crate::maintenance::{domain::MaintenanceMode, services::MaintenanceGate};
+use crate::message::{
+    domain::{
+        AgentSession, CompactedHistory, CompactionPolicy, ContextWindowSnapshot, ConversationId,
+        ConversationLifecycleEvent, ConversationLifecyclePoint, Message, SequenceRange,
+        SnapshotParams, SnapshotType,
+    },
+    error::RepositoryError,
+    ports::{
+        AgentSessionRepository, ContextSnapshotPort, ConversationLifecycleHook,
+        ConversationSummariser, LifecycleHookError, LifecycleHookResult, MessageRepository,
+        SessionError, SnapshotError, SummariserError, TokenCounter,
+    },
+};
+use crate::pagination::collect_pages;
+use async_trait::async_trait;
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Service-level errors for conversation compaction.
+#[derive(Debug, Error)]
+pub enum CompactionServiceError {
+    /// Message repository failure.
+    #[error(transparent)]
+    MessageRepository(#[from] RepositoryError),
+    /// Snapshot store failure.
+    #[error(transparent)]
+    Snapshot(#[from] SnapshotError),
+    /// Agent session repository failure.
+    #[error(transparent)]
+    Session(#[from] SessionError),
+    /// The summariser failed.
+    #[error(transparent)]
+    Summariser(#[from] SummariserError),
+    /// The cluster is in read-only maintenance mode.
+    #[error(transparent)]
+    MaintenanceMode(#[from] MaintenanceMode),
+}
+
+/// Result type for compaction service operations.
+pub type CompactionServiceResult<T> = Result<T, CompactionServiceError>;
+
+/// Ports consulted by [`ConversationCompactionService`].
+#[derive(Clone)]
+pub struct ConversationCompactionPorts {
+    /// Conversation messages.
+    pub messages: Arc<dyn MessageRepository>,
+    /// Context snapshots, which hold compactions.
+    pub snapshots: Arc<dyn ContextSnapshotPort>,
+    /// Agent sessions, which own each compaction.
+    pub sessions: Arc<dyn AgentSessionRepository>,
+    /// Model that writes the summaries.
+    pub summariser: Arc<dyn ConversationSummariser>,
+    /// Clock used to timestamp compactions.
+    pub clock: Arc<dyn Clock + Send + Sync>,
+}
+
+/// Conversation compaction application service.
+#[derive(Clone)]
+pub struct ConversationCompactionService {
+    ports: ConversationCompactionPorts,
+    policy: CompactionPolicy,
+    token_counter: Option<Arc<dyn TokenCounter>>,
+    maintenance: Option<Arc<MaintenanceGate>>,
+}
+
+impl ConversationCompactionService {
+    /// Creates a service using the default [`CompactionPolicy`].
+    #[must_use]
+    pub fn new(ports: ConversationCompactionPorts) -> Self {
+        Self {
+            ports,
+            policy: CompactionPolicy::default(),
+            token_counter: None,
+            maintenance: None,
+        }
+    }
+
+    /// Sets when conversations are compacted and how many recent messages
+    /// stay verbatim.
+    #[must_use]
+    pub const fn with_policy(mut self, policy: CompactionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Records each compaction summary's token count as its snapshot's
+    /// token estimate.
+    #[must_use]
+    pub fn with_token_counter(mut self, token_counter: Arc<dyn TokenCounter>) -> Self {
+        self.token_counter = Some(token_counter);
+        self
+    }
+
+    /// Refuses to compact while `gate` reports maintenance.
+    #[must_use]
+    pub fn with_maintenance(mut self, gate: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(gate);
+        self
+    }
+
+    /// Returns the conversation's history with its compacted range replaced
+    /// by the latest compaction summary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompactionServiceError`] when the compaction or the
+    /// messages cannot be read.
+    pub async fn history(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> CompactionServiceResult<CompactedHistory> {
+        let compaction = self
+            .ports
+            .snapshots
+            .find_latest_compaction(ctx, conversation_id)
+            .await?;
+        let messages = self.all_messages(ctx, conversation_id).await?;
+        Ok(CompactedHistory::assemble(compaction, messages))
+    }
+
+    /// Compacts the conversation if the policy says it is due.
+    ///
+    /// Only unpinned messages count towards the policy and are summarised;
+    /// pinned messages inside the new range stay verbatim.
+    ///
+    /// Returns the stored compaction, or `None` when too few messages
+    /// follow the last compaction or the conversation has no active agent
+    /// session to record it against.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompactionServiceError`] when history cannot be read, the
+    /// summariser fails, the compaction cannot be stored, or the cluster is
+    /// in maintenance.
+    pub async fn compact(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> CompactionServiceResult<Option<ContextWindowSnapshot>> {
+        if let Some(gate) = self.maintenance.as_deref() {
+            gate.ensure_writable().await?;
+        }
+        let history = self.history(ctx, conversation_id).await?;
+        let unpinned: Vec<Message> = history
+            .messages()
+            .iter()
+            .filter(|message| !message.is_pinned())
+            .cloned()
+            .collect();
+        let due = self.policy.messages_to_compact(unpinned.len());
+        let Some(batch) = unpinned.get(..due).filter(|batch| !batch.is_empty()) else {
+            return Ok(None);
+        };
+        let Some(session) = self
+            .ports
+            .sessions
+            .find_active_for_conversation(ctx, conversation_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let text = self.summarise(ctx, history.summary_text(), batch).await?;
+        let Some(snapshot) = self.compaction_snapshot(history.compaction(), batch, &session) else {
+            return Ok(None);
+        };
+        let snapshot = self.with_token_estimate(snapshot.with_summary(text));
+        self.ports.snapshots.store_snapshot(ctx, &snapshot).await?;
+        Ok(Some(snapshot))
+    }
+
+    async fn all_messages(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> CompactionServiceResult<Vec<Message>> {
+        Ok(collect_pages(|page| {
+            self.ports
+                .messages
+                .find_by_conversation(ctx, conversation_id, page)
+        })
+        .await?)
+    }
+
+    /// Folds `batch` into the previous summary, or summarises it afresh.
+    async fn summarise(
+        &self,
+        ctx: &RequestContext,
+        previous: Option<&str>,
+        batch: &[Message],
+    ) -> CompactionServiceResult<String> {
+        let summariser = &self.ports.summariser;
+        if let Some(summary) = previous {
+            return Ok(summariser.extend(ctx, summary, batch).await?);
+        }
+        Ok(summariser.summarise(ctx, batch).await?)
+    }
+
+    /// Builds a compaction covering the previous compaction's range and
+    /// `batch`, without its summary.
+    fn compaction_snapshot(
+        &self,
+        previous: Option<&ContextWindowSnapshot>,
+        batch: &[Message],
+        session: &AgentSession,
+    ) -> Option<ContextWindowSnapshot> {
+        let first = batch.first()?.sequence_number();
+        let last = batch.last()?.sequence_number();
+        let start = previous.map_or(first, |snapshot| snapshot.sequence_range.start);
+        let mut message_summary = previous
+            .map(|snapshot| snapshot.message_summary)
+            .unwrap_or_default();
+        for message in batch {
+            message_summary.record(message.role());
+        }
+        let params = SnapshotParams {
+            conversation_id: session.conversation_id,
+            session_id: session.session_id,
+            sequence_range: SequenceRange::new(start, last),
+            message_summary,
+            snapshot_type: SnapshotType::Compaction,
+        };
+        Some(ContextWindowSnapshot::new(params, &*self.ports.clock))
+    }
+
+    fn with_token_estimate(&self, snapshot: ContextWindowSnapshot) -> ContextWindowSnapshot {
+        let Some(counter) = self.token_counter.as_deref() else {
+            return snapshot;
+        };
+        let tokens = counter.count(snapshot.summary.as_deref().unwrap_or_default());
+        snapshot.with_token_estimate(tokens)
+    }
+}
+
+#[async_trait]
+impl ConversationLifecycleHook for ConversationCompactionService {
+    async fn on_event(
+        &self,
+        ctx: &RequestContext,
+        event: &ConversationLifecycleEvent,
+    ) -> LifecycleHookResult {
+        if event.point() != ConversationLifecyclePoint::MessageStored {
+            return Ok(());
+        }
+        self.compact(ctx, event.conversation_id)
+            .await
+            .map(|_| ())
+            .map_err(|err| LifecycleHookError::new(err.to_string()))
+    }
+}
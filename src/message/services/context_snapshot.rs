@@ -0,0 +1,186 @@
+//! Application service for incremental context snapshots.
+//!
+//! [`ContextSnapshotService`] stores a full capture of a session's context
+//! window as an increment on an earlier snapshot, resolves an incremental
+//! snapshot back into the full view it stands for, and reports how much
+//! storage a session's increments save over full captures.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        AgentSessionId, ContextWindowSnapshot, ResolvedSnapshot, SnapshotChainError,
+        SnapshotStorage,
+    },
+    ports::context_snapshot::{ContextSnapshotPort, SnapshotError},
+};
+use crate::pagination::collect_pages;
+
+/// Service-level errors for incremental snapshots.
+#[derive(Debug, Error)]
+pub enum ContextSnapshotServiceError {
+    /// Snapshot does not exist.
+    #[error("snapshot not found: {0}")]
+    SnapshotNotFound(Uuid),
+    /// The snapshots do not form a resolvable chain.
+    #[error(transparent)]
+    Chain(#[from] SnapshotChainError),
+    /// Snapshot store failure.
+    #[error(transparent)]
+    Snapshot(#[from] SnapshotError),
+}
+
+/// Result type for context snapshot service operations.
+pub type ContextSnapshotServiceResult<T> = Result<T, ContextSnapshotServiceError>;
+
+/// Captures, resolves, and measures incremental context snapshots.
+#[derive(Clone)]
+pub struct ContextSnapshotService<C>
+where
+    C: ContextSnapshotPort,
+{
+    snapshot_adapter: Arc<C>,
+}
+
+impl<C> ContextSnapshotService<C>
+where
+    C: ContextSnapshotPort,
+{
+    /// Creates a new context snapshot service.
+    pub const fn new(snapshot_adapter: Arc<C>) -> Self {
+        Self { snapshot_adapter }
+    }
+
+    /// Stores the full capture `current` as an increment on
+    /// `parent_snapshot_id`.
+    ///
+    /// The stored snapshot keeps the identity, type, and capture time of
+    /// `current`, but records only the messages and tool calls it adds to
+    /// the parent's resolved view.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContextSnapshotServiceError::SnapshotNotFound`] when the
+    /// parent does not exist, [`ContextSnapshotServiceError::Chain`] when
+    /// the parent cannot be resolved or `current` does not extend it, or
+    /// store errors.
+    pub async fn capture_incremental(
+        &self,
+        ctx: &RequestContext,
+        parent_snapshot_id: Uuid,
+        current: ContextWindowSnapshot,
+    ) -> ContextSnapshotServiceResult<ContextWindowSnapshot> {
+        let parent = self.resolve(ctx, parent_snapshot_id).await?;
+        let increment = parent.increment(current)?;
+        self.snapshot_adapter
+            .store_snapshot(ctx, &increment)
+            .await?;
+        Ok(increment)
+    }
+
+    /// Reconstitutes the full context window a snapshot stands for.
+    ///
+    /// Full captures resolve to themselves; increments are folded onto
+    /// their ancestors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContextSnapshotServiceError::SnapshotNotFound`] when the
+    /// snapshot does not exist, [`ContextSnapshotServiceError::Chain`] when
+    /// an ancestor is missing or the chain is inconsistent, or store
+    /// errors.
+    pub async fn resolve(
+        &self,
+        ctx: &RequestContext,
+        snapshot_id: Uuid,
+    ) -> ContextSnapshotServiceResult<ResolvedSnapshot> {
+        let mut chain = vec![self.find(ctx, snapshot_id).await?];
+        while let Some((child_id, parent_id)) = chain
+            .last()
+            .and_then(|child| Some((child.snapshot_id, child.parent_snapshot_id?)))
+        {
+            if chain.iter().any(|known| known.snapshot_id == parent_id) {
+                return Err(SnapshotChainError::Cycle(parent_id).into());
+            }
+            let parent = self
+                .snapshot_adapter
+                .find_by_id(ctx, parent_id)
+                .await?
+                .ok_or(SnapshotChainError::MissingParent {
+                    snapshot_id: child_id,
+                    parent_snapshot_id: parent_id,
+                })?;
+            chain.push(parent);
+        }
+        let mut oldest_first = chain.into_iter().rev();
+        let root = oldest_first
+            .next()
+            .ok_or(ContextSnapshotServiceError::SnapshotNotFound(snapshot_id))?;
+        Ok(ResolvedSnapshot::resolve(root, oldest_first)?)
+    }
+
+    /// Measures the storage a session's snapshots use against full
+    /// captures of the same context windows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContextSnapshotServiceError::Chain`] when an ancestor of
+    /// one of the snapshots is missing, or store errors.
+    pub async fn session_storage(
+        &self,
+        ctx: &RequestContext,
+        session_id: AgentSessionId,
+    ) -> ContextSnapshotServiceResult<SnapshotStorage> {
+        let snapshots = collect_pages(|page| {
+            self.snapshot_adapter
+                .find_snapshots_for_session(ctx, session_id, page)
+        })
+        .await?;
+        let session_ids: Vec<Uuid> = snapshots.iter().map(|s| s.snapshot_id).collect();
+        let mut known: HashMap<Uuid, ContextWindowSnapshot> = snapshots
+            .into_iter()
+            .map(|snapshot| (snapshot.snapshot_id, snapshot))
+            .collect();
+        self.load_ancestors(ctx, &mut known).await?;
+        Ok(SnapshotStorage::measure(&session_ids, &known)?)
+    }
+
+    /// Adds to `known` the ancestors of its snapshots stored outside it,
+    /// such as those captured in an earlier session.
+    async fn load_ancestors(
+        &self,
+        ctx: &RequestContext,
+        known: &mut HashMap<Uuid, ContextWindowSnapshot>,
+    ) -> ContextSnapshotServiceResult<()> {
+        let mut pending: Vec<Uuid> = known
+            .values()
+            .filter_map(|snapshot| snapshot.parent_snapshot_id)
+            .collect();
+        while let Some(id) = pending.pop() {
+            if known.contains_key(&id) {
+                continue;
+            }
+            if let Some(snapshot) = self.snapshot_adapter.find_by_id(ctx, id).await? {
+                pending.extend(snapshot.parent_snapshot_id);
+                known.insert(id, snapshot);
+            }
+        }
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        snapshot_id: Uuid,
+    ) -> ContextSnapshotServiceResult<ContextWindowSnapshot> {
+        self.snapshot_adapter
+            .find_by_id(ctx, snapshot_id)
+            .await?
+            .ok_or(ContextSnapshotServiceError::SnapshotNotFound(snapshot_id))
+    }
+}
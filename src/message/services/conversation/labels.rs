@@ -0,0 +1,137 @@
+//! Conversation labels and label queries.
+//!
+//! Setting or removing a label persists the conversation together with a
+//! [`ConversationLabelEvent`] naming who made the change, so the label
+//! history forms part of the audit trail. Labels describe a conversation
+//! rather than add to it, so archived conversations can still be
+//! relabelled. Changes that leave the labels as they were are not persisted
+//! or recorded.
+
+use super::{ConversationService, ConversationServiceResult};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{Conversation, ConversationId, ConversationLabel, ConversationLabelEvent},
+    ports::{MessageRepository, MessageValidator, conversation::ConversationRepository},
+};
+use crate::pagination::{Page, PageRequest};
+use mockable::Clock;
+
+impl<ConvoRepo, MessageRepo, Validator, C> ConversationService<ConvoRepo, MessageRepo, Validator, C>
+where
+    ConvoRepo: ConversationRepository,
+    MessageRepo: MessageRepository,
+    Validator: MessageValidator,
+    C: Clock + Send + Sync,
+{
+    /// Sets a label on a conversation, replacing any value it held.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist, or
+    /// [`ConversationServiceError::ConversationRepository`] when the change
+    /// cannot be stored.
+    ///
+    /// [`ConversationServiceError::ConversationNotFound`]: super::ConversationServiceError::ConversationNotFound
+    /// [`ConversationServiceError::ConversationRepository`]: super::ConversationServiceError::ConversationRepository
+    pub async fn set_label(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        label: ConversationLabel,
+    ) -> ConversationServiceResult<Conversation> {
+        let mut conversation = self.require_conversation(ctx, conversation_id).await?;
+        let Some(change) = conversation.set_label(label, &*self.clock) else {
+            return Ok(conversation);
+        };
+        let event = ConversationLabelEvent::new(ctx, change, &*self.clock);
+        self.persist_label_change(ctx, conversation, &event).await
+    }
+
+    /// Removes a label from a conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist, or
+    /// [`ConversationServiceError::ConversationRepository`] when the change
+    /// cannot be stored.
+    ///
+    /// [`ConversationServiceError::ConversationNotFound`]: super::ConversationServiceError::ConversationNotFound
+    /// [`ConversationServiceError::ConversationRepository`]: super::ConversationServiceError::ConversationRepository
+    pub async fn remove_label(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        key: &str,
+    ) -> ConversationServiceResult<Conversation> {
+        let mut conversation = self.require_conversation(ctx, conversation_id).await?;
+        let Some(change) = conversation.remove_label(key, &*self.clock) else {
+            return Ok(conversation);
+        };
+        let event = ConversationLabelEvent::new(ctx, change, &*self.clock);
+        self.persist_label_change(ctx, conversation, &event).await
+    }
+
+    /// Returns the conversations carrying `label`, oldest first in natural
+    /// order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationRepository`] when the
+    /// query fails.
+    ///
+    /// [`ConversationServiceError::ConversationRepository`]: super::ConversationServiceError::ConversationRepository
+    pub async fn find_by_label(
+        &self,
+        ctx: &RequestContext,
+        label: &ConversationLabel,
+        page: PageRequest,
+    ) -> ConversationServiceResult<Page<Conversation>> {
+        Ok(self
+            .conversation_repository
+            .find_by_label(ctx, label, page)
+            .await?)
+    }
+
+    /// Returns a conversation's label history, oldest first in natural
+    /// order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist.
+    ///
+    /// [`ConversationServiceError::ConversationNotFound`]: super::ConversationServiceError::ConversationNotFound
+    pub async fn label_history(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> ConversationServiceResult<Page<ConversationLabelEvent>> {
+        self.require_conversation(ctx, conversation_id).await?;
+        Ok(self
+            .conversation_repository
+            .find_label_events(ctx, conversation_id, page)
+            .await?)
+    }
+
+    async fn persist_label_change(
+        &self,
+        ctx: &RequestContext,
+        conversation: Conversation,
+        event: &ConversationLabelEvent,
+    ) -> ConversationServiceResult<Conversation> {
+        self.conversation_repository
+            .update_labels(ctx, &conversation, event)
+            .await?;
+        tracing::info!(
+            conversation_id = %conversation.id(),
+            label = %event.change.key,
+            removed = event.change.is_removal(),
+            "conversation relabelled"
+        );
+        let version = conversation.version().saturating_add(1);
+        Ok(conversation.with_version(version))
+    }
+}
@@ -0,0 +1,217 @@
+//! Conversation state transitions and context updates.
+//!
+//! Pausing, resuming, and completing follow the state machine enforced by
+//! [`Conversation`]. Pausing and resuming are operator actions, recorded with
+//! the caller's reason when an operator action repository is attached.
+//! Context and task-link updates are refused for archived conversations,
+//! which are closed to writes.
+
+use super::{ConversationService, ConversationServiceError, ConversationServiceResult};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{Conversation, ConversationId, ConversationState},
+    ports::{MessageRepository, MessageValidator, conversation::ConversationRepository},
+};
+use crate::operator::domain::{
+    OperatorAction, OperatorActionDetails, OperatorActionKind, OperatorReason, OperatorSubject,
+    OperatorTransition,
+};
+use crate::task::domain::TaskId;
+use mockable::Clock;
+use serde_json::Value;
+use uuid::Uuid;
+
+impl<ConvoRepo, MessageRepo, Validator, C> ConversationService<ConvoRepo, MessageRepo, Validator, C>
+where
+    ConvoRepo: ConversationRepository,
+    MessageRepo: MessageRepository,
+    Validator: MessageValidator,
+    C: Clock + Send + Sync,
+{
+    /// Pauses an active conversation on an operator's behalf.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist,
+    /// [`ConversationLifecycleError::InvalidTransition`] unless it is active,
+    /// or [`ConversationServiceError::OperatorActions`] when the action
+    /// cannot be recorded.
+    ///
+    /// [`ConversationLifecycleError::InvalidTransition`]: crate::message::domain::ConversationLifecycleError::InvalidTransition
+    pub async fn pause_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        reason: &OperatorReason,
+    ) -> ConversationServiceResult<Conversation> {
+        let conversation = self
+            .update_conversation(ctx, conversation_id, |conversation, clock| {
+                Ok(conversation.pause(clock)?)
+            })
+            .await?;
+        self.record_operator_action(
+            ctx,
+            operator_details(
+                OperatorActionKind::ConversationPaused,
+                &conversation,
+                ConversationState::Active,
+                reason,
+            ),
+        )
+        .await?;
+        Ok(conversation)
+    }
+
+    /// Resumes a paused conversation on an operator's behalf.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist,
+    /// [`ConversationLifecycleError::InvalidTransition`] unless it is
+    /// paused, or [`ConversationServiceError::OperatorActions`] when the
+    /// action cannot be recorded.
+    ///
+    /// [`ConversationLifecycleError::InvalidTransition`]: crate::message::domain::ConversationLifecycleError::InvalidTransition
+    pub async fn resume_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        reason: &OperatorReason,
+    ) -> ConversationServiceResult<Conversation> {
+        let conversation = self
+            .update_conversation(ctx, conversation_id, |conversation, clock| {
+                Ok(conversation.resume(clock)?)
+            })
+            .await?;
+        self.record_operator_action(
+            ctx,
+            operator_details(
+                OperatorActionKind::ConversationResumed,
+                &conversation,
+                ConversationState::Paused,
+                reason,
+            ),
+        )
+        .await?;
+        Ok(conversation)
+    }
+
+    /// Marks an active or paused conversation as completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist, or
+    /// [`ConversationLifecycleError::InvalidTransition`] when it is already
+    /// completed or archived.
+    ///
+    /// [`ConversationLifecycleError::InvalidTransition`]: crate::message::domain::ConversationLifecycleError::InvalidTransition
+    pub async fn complete_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> ConversationServiceResult<Conversation> {
+        self.update_conversation(ctx, conversation_id, |conversation, clock| {
+            Ok(conversation.complete(clock)?)
+        })
+        .await
+    }
+
+    /// Replaces a conversation's context data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist, or
+    /// [`ConversationServiceError::ConversationArchived`] when it is
+    /// archived.
+    pub async fn replace_context(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        context: Value,
+    ) -> ConversationServiceResult<Conversation> {
+        self.update_conversation(ctx, conversation_id, |conversation, clock| {
+            ensure_writable(conversation)?;
+            conversation.replace_context(context, clock);
+            Ok(())
+        })
+        .await
+    }
+
+    /// Links a conversation to a task, or unlinks it with `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist, or
+    /// [`ConversationServiceError::ConversationArchived`] when it is
+    /// archived.
+    pub async fn link_task(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        task_id: Option<Uuid>,
+    ) -> ConversationServiceResult<Conversation> {
+        self.update_conversation(ctx, conversation_id, |conversation, clock| {
+            ensure_writable(conversation)?;
+            conversation.link_task(task_id, clock);
+            Ok(())
+        })
+        .await
+    }
+
+    async fn update_conversation<F>(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        change: F,
+    ) -> ConversationServiceResult<Conversation>
+    where
+        F: FnOnce(&mut Conversation, &C) -> ConversationServiceResult<()> + Send,
+    {
+        let mut conversation = self.require_conversation(ctx, conversation_id).await?;
+        change(&mut conversation, &self.clock)?;
+        self.persist_update(ctx, conversation).await
+    }
+
+    async fn record_operator_action(
+        &self,
+        ctx: &RequestContext,
+        details: OperatorActionDetails,
+    ) -> ConversationServiceResult<()> {
+        if let Some(operator_actions) = &self.operator_actions {
+            let action = OperatorAction::new(ctx, details, &*self.clock);
+            operator_actions.record(ctx, &action).await?;
+        }
+        Ok(())
+    }
+}
+
+fn operator_details(
+    kind: OperatorActionKind,
+    conversation: &Conversation,
+    from: ConversationState,
+    reason: &OperatorReason,
+) -> OperatorActionDetails {
+    OperatorActionDetails {
+        kind,
+        subject: OperatorSubject::Conversation {
+            conversation_id: conversation.id(),
+            task_id: conversation.task_id().map(TaskId::from_uuid),
+        },
+        reason: reason.clone(),
+        transition: Some(OperatorTransition::new(from, conversation.state())),
+    }
+}
+
+const fn ensure_writable(conversation: &Conversation) -> ConversationServiceResult<()> {
+    if conversation.is_archived() {
+        return Err(ConversationServiceError::ConversationArchived(
+            conversation.id(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,400 @@
+//! Application service for conversation lifecycle and message workflows.
+//!
+//! This module implements the service-layer orchestration for conversations in
+//! the hexagonal architecture. [`ConversationService`] creates and loads
+//! conversations, appends messages, coordinates validation and persistence
+//! through repository traits, and keeps transport concerns out of the domain.
+//!
+//! Appending a message validates it and hands it to
+//! [`MessageRepository::append`], which allocates the next sequence number
+//! atomically with the write, so concurrent writers never see
+//! duplicate-sequence conflicts. This is the boundary where repository
+//! failures, validation failures, and conversation existence checks are
+//! normalized for callers.
+//!
+//! The service also drives the rest of the conversation state machine
+//! (pausing, resuming, and completing) and updates a conversation's context
+//! data and task link; those workflows live in [`lifecycle`].
+//!
+//! The service also archives and unarchives conversations. Appends to archived
+//! conversations are refused up front; repositories that track conversation
+//! state reject them again at write time so a concurrent archive cannot be
+//! bypassed.
+//!
+//! When a [`TokenCounter`] is attached, appended messages have their token
+//! count recorded in their metadata before they are stored.
+//!
+//! When [`ConversationLifecycleHooks`] are attached, the service announces
+//! creation, stored messages, and archival to them after each change is
+//! persisted.
+//!
+//! Conversations can be labelled for slicing by project, customer, or
+//! experiment; label changes are recorded in the conversation's label
+//! history, and conversations can be found by label. Those workflows live
+//! in [`labels`].
+//!
+//! Pausing and resuming are operator actions: each takes an
+//! [`OperatorReason`], and when an [`OperatorActionRepository`] is attached
+//! the service records who acted and why once the change is persisted.
+//!
+//! Appended messages naming a turn are recorded on an attached [`TurnRepository`]
+//! ([`turns`]); an attached [`PeriodicSnapshotScheduler`] sees each ([`snapshots`]).
+//!
+//! [`OperatorReason`]: crate::operator::domain::OperatorReason
+
+mod labels;
+mod lifecycle;
+mod snapshots;
+mod turns;
+
+use super::{ConversationLifecycleHooks, PeriodicSnapshotScheduler};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        ContentPart, Conversation, ConversationAccess, ConversationId, ConversationLifecycleChange,
+        ConversationLifecycleError, ConversationLifecycleEvent, Message, MessageBuilderError,
+        MessageMetadata, Role, SequenceNumber, TurnId, TurnStateError,
+    },
+    error::{RepositoryError, ValidationError},
+    ports::{
+        MessageRepository, MessageValidator, TokenCounter, TurnError, TurnRepository,
+        conversation::{ConversationRepository, ConversationRepositoryError},
+    },
+};
+use crate::operator::ports::{OperatorActionError, OperatorActionRepository};
+use crate::pagination::{Page, PageRequest};
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Request payload for appending a message to a conversation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppendMessageRequest {
+    conversation_id: ConversationId,
+    role: Role,
+    content: Vec<ContentPart>,
+    metadata: MessageMetadata,
+}
+
+impl AppendMessageRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(conversation_id: ConversationId, role: Role, content: Vec<ContentPart>) -> Self {
+        Self {
+            conversation_id,
+            role,
+            content,
+            metadata: MessageMetadata::empty(),
+        }
+    }
+
+    /// Attaches metadata to the appended message.
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: MessageMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+}
+
+/// Service-level errors for conversation workflows.
+#[derive(Debug, Error)]
+pub enum ConversationServiceError {
+    /// Conversation does not exist.
+    #[error("conversation not found: {0}")]
+    ConversationNotFound(ConversationId),
+    /// Conversation is archived and rejects writes.
+    #[error("conversation is archived: {0}")]
+    ConversationArchived(ConversationId),
+    /// Archive or unarchive transition was refused.
+    #[error(transparent)]
+    Lifecycle(#[from] ConversationLifecycleError),
+    /// Conversation repository failure.
+    #[error(transparent)]
+    ConversationRepository(#[from] ConversationRepositoryError),
+    /// Message repository failure.
+    #[error(transparent)]
+    MessageRepository(#[from] RepositoryError),
+    /// Message validation failure.
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+    /// Operator action audit failure.
+    #[error(transparent)]
+    OperatorActions(#[from] OperatorActionError),
+    /// The turn a message names does not exist in its conversation.
+    #[error("turn not found: {0}")]
+    TurnNotFound(TurnId),
+    /// The turn a message names refused it.
+    #[error(transparent)]
+    TurnState(#[from] TurnStateError),
+    /// Turn repository failure.
+    #[error(transparent)]
+    Turns(#[from] TurnError),
+}
+
+/// Result type for conversation service operations.
+pub type ConversationServiceResult<T> = Result<T, ConversationServiceError>;
+
+/// Conversation application service.
+#[derive(Clone)]
+pub struct ConversationService<ConvoRepo, MessageRepo, Validator, C>
+where
+    ConvoRepo: ConversationRepository,
+    MessageRepo: MessageRepository,
+    Validator: MessageValidator,
+    C: Clock + Send + Sync,
+{
+    conversation_repository: Arc<ConvoRepo>,
+    message_repository: Arc<MessageRepo>,
+    validator: Arc<Validator>,
+    clock: Arc<C>,
+    lifecycle_hooks: Option<Arc<ConversationLifecycleHooks>>,
+    operator_actions: Option<Arc<dyn OperatorActionRepository>>,
+    token_counter: Option<Arc<dyn TokenCounter>>,
+    turns: Option<Arc<dyn TurnRepository>>,
+    snapshot_scheduler: Option<Arc<PeriodicSnapshotScheduler>>,
+}
+
+impl<ConvoRepo, MessageRepo, Validator, C> ConversationService<ConvoRepo, MessageRepo, Validator, C>
+where
+    ConvoRepo: ConversationRepository,
+    MessageRepo: MessageRepository,
+    Validator: MessageValidator,
+    C: Clock + Send + Sync,
+{
+    /// Creates a new conversation service.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "Constructor is kept non-const to avoid implying const-context construction for runtime Arc dependencies"
+    )]
+    pub fn new(
+        conversation_repository: Arc<ConvoRepo>,
+        message_repository: Arc<MessageRepo>,
+        validator: Arc<Validator>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            conversation_repository,
+            message_repository,
+            validator,
+            clock,
+            lifecycle_hooks: None,
+            operator_actions: None,
+            token_counter: None,
+            turns: None,
+            snapshot_scheduler: None,
+        }
+    }
+
+    /// Attaches hooks that are told about conversation creation, stored
+    /// messages, and archival.
+    #[must_use]
+    pub fn with_lifecycle_hooks(mut self, hooks: Arc<ConversationLifecycleHooks>) -> Self {
+        self.lifecycle_hooks = Some(hooks);
+        self
+    }
+
+    /// Attaches the repository that records operator pauses and resumes.
+    #[must_use]
+    pub fn with_operator_actions(
+        mut self,
+        operator_actions: Arc<dyn OperatorActionRepository>,
+    ) -> Self {
+        self.operator_actions = Some(operator_actions);
+        self
+    }
+
+    /// Attaches the counter that measures appended messages in tokens.
+    #[must_use]
+    pub fn with_token_counter(mut self, token_counter: Arc<dyn TokenCounter>) -> Self {
+        self.token_counter = Some(token_counter);
+        self
+    }
+
+    /// Creates a new empty conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns repository errors when persistence fails.
+    pub async fn create_conversation(
+        &self,
+        ctx: &RequestContext,
+    ) -> ConversationServiceResult<Conversation> {
+        let conversation = Conversation::new(&*self.clock);
+        self.conversation_repository
+            .store(ctx, &conversation)
+            .await?;
+        self.announce(ctx, conversation.id(), ConversationLifecycleChange::Created)
+            .await;
+        Ok(conversation)
+    }
+
+    /// Archives a conversation, closing it to further writes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist, or
+    /// [`ConversationLifecycleError::AlreadyArchived`] when it is already
+    /// archived.
+    pub async fn archive_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> ConversationServiceResult<Conversation> {
+        let mut conversation = self.require_conversation(ctx, conversation_id).await?;
+        conversation.archive(&*self.clock)?;
+        let archived = self.persist_update(ctx, conversation).await?;
+        self.announce(ctx, conversation_id, ConversationLifecycleChange::Archived)
+            .await;
+        Ok(archived)
+    }
+
+    /// Reopens an archived conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationLifecycleError::ElevatedAccessRequired`] unless
+    /// `access` is elevated, [`ConversationLifecycleError::NotArchived`] when
+    /// the conversation is not archived, or
+    /// [`ConversationServiceError::ConversationNotFound`] when it does not
+    /// exist.
+    pub async fn unarchive_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        access: ConversationAccess,
+    ) -> ConversationServiceResult<Conversation> {
+        let mut conversation = self.require_conversation(ctx, conversation_id).await?;
+        conversation.unarchive(access, &*self.clock)?;
+        self.persist_update(ctx, conversation).await
+    }
+
+    /// Returns a page of conversation history ordered by sequence number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist.
+    pub async fn history(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> ConversationServiceResult<Page<Message>> {
+        self.require_conversation(ctx, conversation_id).await?;
+        self.message_repository
+            .find_by_conversation(ctx, conversation_id, page)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Appends a message to an existing conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationServiceError::ConversationNotFound`] when the
+    /// conversation does not exist,
+    /// [`ConversationServiceError::ConversationArchived`] when it is archived,
+    /// [`ConversationServiceError::TurnNotFound`] or
+    /// [`ConversationServiceError::TurnState`] when the turn it names cannot
+    /// take the message, or validation/repository errors when message
+    /// construction fails.
+    pub async fn append_message(
+        &self,
+        ctx: &RequestContext,
+        request: AppendMessageRequest,
+    ) -> ConversationServiceResult<Message> {
+        let AppendMessageRequest {
+            conversation_id,
+            role,
+            content,
+            metadata,
+        } = request;
+        if self
+            .require_conversation(ctx, conversation_id)
+            .await?
+            .is_archived()
+        {
+            return Err(ConversationServiceError::ConversationArchived(
+                conversation_id,
+            ));
+        }
+        let turn = self
+            .open_turn(ctx, conversation_id, metadata.turn_id)
+            .await?;
+
+        // The repository replaces this placeholder with the sequence number
+        // it allocates on append.
+        let mut message = Message::builder(conversation_id, role, SequenceNumber::new(1))
+            .with_content_parts(content)
+            .with_metadata(metadata)
+            .build(&*self.clock)
+            .map_err(|error| Self::builder_error_to_validation(&error))?;
+        self.validator.validate(&message)?;
+        if let Some(counter) = &self.token_counter {
+            let count = counter.count_message(&message);
+            message = message.with_token_count(count);
+        }
+
+        let stored = self.message_repository.append(ctx, &message).await?;
+        self.record_turn_response(ctx, turn, &stored).await;
+        self.schedule_snapshot(ctx, &stored);
+        self.announce(
+            ctx,
+            conversation_id,
+            ConversationLifecycleChange::MessageStored {
+                message_id: stored.id(),
+                sequence_number: stored.sequence_number(),
+                role,
+            },
+        )
+        .await;
+        Ok(stored)
+    }
+
+    async fn announce(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        change: ConversationLifecycleChange,
+    ) {
+        if let Some(hooks) = &self.lifecycle_hooks {
+            let event = ConversationLifecycleEvent::new(conversation_id, change, self.clock.utc());
+            hooks.dispatch(ctx, &event).await;
+        }
+    }
+
+    async fn require_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> ConversationServiceResult<Conversation> {
+        self.conversation_repository
+            .find_by_id(ctx, conversation_id)
+            .await?
+            .ok_or(ConversationServiceError::ConversationNotFound(
+                conversation_id,
+            ))
+    }
+
+    /// Persists an update and returns the conversation at its new version,
+    /// ready for a further update.
+    async fn persist_update(
+        &self,
+        ctx: &RequestContext,
+        conversation: Conversation,
+    ) -> ConversationServiceResult<Conversation> {
+        self.conversation_repository
+            .update(ctx, &conversation)
+            .await?;
+        let version = conversation.version().saturating_add(1);
+        Ok(conversation.with_version(version))
+    }
+
+    const fn builder_error_to_validation(error: &MessageBuilderError) -> ValidationError {
+        match error {
+            MessageBuilderError::EmptyContent => ValidationError::EmptyContent,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Reporting stored messages to the periodic snapshot scheduler.
+//!
+//! The scheduler captures in the background and defers when it is busy, so
+//! appending a message never waits on the snapshot store and never fails
+//! because of it.
+
+use super::ConversationService;
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{Message, SnapshotMark},
+    ports::{MessageRepository, MessageValidator, conversation::ConversationRepository},
+    services::{PeriodicSnapshotScheduler, SnapshotSchedule},
+};
+use mockable::Clock;
+use std::sync::Arc;
+
+impl<ConvoRepo, MessageRepo, Validator, C> ConversationService<ConvoRepo, MessageRepo, Validator, C>
+where
+    ConvoRepo: ConversationRepository,
+    MessageRepo: MessageRepository,
+    Validator: MessageValidator,
+    C: Clock + Send + Sync,
+{
+    /// Attaches the scheduler that captures periodic snapshots of the
+    /// conversation's active session.
+    #[must_use]
+    pub fn with_snapshot_scheduler(mut self, scheduler: Arc<PeriodicSnapshotScheduler>) -> Self {
+        self.snapshot_scheduler = Some(scheduler);
+        self
+    }
+
+    /// Tells the attached scheduler, if any, that `stored` was appended.
+    pub(super) fn schedule_snapshot(&self, ctx: &RequestContext, stored: &Message) {
+        let Some(scheduler) = &self.snapshot_scheduler else {
+            return;
+        };
+        let current = SnapshotMark::new(stored.sequence_number(), stored.created_at());
+        if let SnapshotSchedule::Deferred =
+            scheduler.observe(ctx, stored.conversation_id(), current)
+        {
+            tracing::debug!(
+                conversation_id = %stored.conversation_id(),
+                sequence_number = %stored.sequence_number(),
+                "periodic snapshot check deferred"
+            );
+        }
+    }
+}
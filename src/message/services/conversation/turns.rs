@@ -0,0 +1,84 @@
+//! Linking appended messages to persisted turns.
+//!
+//! When a [`TurnRepository`] is attached, a message whose metadata names a
+//! turn is only appended if that turn exists in the same conversation and is
+//! still in progress. Once the message is stored it is recorded on the turn
+//! as a response, together with the tool calls it makes, in one atomic
+//! repository write. The message is stored by then, so a turn that ended
+//! in between, or a failed write, is logged rather than failing the append.
+
+use super::{ConversationService, ConversationServiceError, ConversationServiceResult};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationId, Message, TurnId, TurnStateError},
+    ports::{
+        MessageRepository, MessageValidator, TurnRepository, conversation::ConversationRepository,
+    },
+};
+use mockable::Clock;
+use std::sync::Arc;
+
+impl<ConvoRepo, MessageRepo, Validator, C> ConversationService<ConvoRepo, MessageRepo, Validator, C>
+where
+    ConvoRepo: ConversationRepository,
+    MessageRepo: MessageRepository,
+    Validator: MessageValidator,
+    C: Clock + Send + Sync,
+{
+    /// Attaches the repository of turns that appended messages are recorded
+    /// on.
+    #[must_use]
+    pub fn with_turns(mut self, turns: Arc<dyn TurnRepository>) -> Self {
+        self.turns = Some(turns);
+        self
+    }
+
+    /// Loads the turn an appended message names, checking it can take
+    /// another response.
+    ///
+    /// Returns `None` when no turn is named or no turn repository is
+    /// attached.
+    pub(super) async fn open_turn(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        turn_id: Option<TurnId>,
+    ) -> ConversationServiceResult<Option<TurnId>> {
+        let (Some(turns), Some(turn_id)) = (&self.turns, turn_id) else {
+            return Ok(None);
+        };
+        let turn = turns
+            .find_by_id(ctx, turn_id)
+            .await?
+            .filter(|turn| turn.conversation_id == conversation_id)
+            .ok_or(ConversationServiceError::TurnNotFound(turn_id))?;
+        if turn.status.is_terminal() {
+            return Err(TurnStateError::Ended {
+                turn_id,
+                status: turn.status,
+            }
+            .into());
+        }
+        Ok(Some(turn.turn_id))
+    }
+
+    /// Records a stored message as a response within the turn `turn_id`.
+    pub(super) async fn record_turn_response(
+        &self,
+        ctx: &RequestContext,
+        turn_id: Option<TurnId>,
+        message: &Message,
+    ) {
+        let (Some(turns), Some(turn_id)) = (&self.turns, turn_id) else {
+            return;
+        };
+        if let Err(err) = turns.record_response(ctx, turn_id, message).await {
+            tracing::warn!(
+                %turn_id,
+                message_id = %message.id(),
+                error = %err,
+                "stored message could not be recorded on its turn"
+            );
+        }
+    }
+}
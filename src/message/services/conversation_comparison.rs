@@ -0,0 +1,68 @@
+//! Application service for comparing branches of a forked conversation.
+//!
+//! [`ConversationComparisonService`] loads the full history of two
+//! conversations and aligns them with [`ConversationComparison::compare`],
+//! so alternative strategies tried from the same starting point can be
+//! evaluated side by side.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationComparison, ConversationId, Message},
+    error::RepositoryError,
+    ports::MessageRepository,
+};
+use crate::pagination::collect_pages;
+use std::sync::Arc;
+
+/// Conversation comparison application service.
+#[derive(Clone)]
+pub struct ConversationComparisonService<MessageRepo>
+where
+    MessageRepo: MessageRepository,
+{
+    message_repository: Arc<MessageRepo>,
+}
+
+impl<MessageRepo> ConversationComparisonService<MessageRepo>
+where
+    MessageRepo: MessageRepository,
+{
+    /// Creates a comparison service.
+    #[must_use]
+    pub const fn new(message_repository: Arc<MessageRepo>) -> Self {
+        Self { message_repository }
+    }
+
+    /// Compares two branches of a forked conversation from their divergence
+    /// point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RepositoryError`] when either conversation's messages
+    /// cannot be read.
+    pub async fn compare(
+        &self,
+        ctx: &RequestContext,
+        left: ConversationId,
+        right: ConversationId,
+    ) -> Result<ConversationComparison, RepositoryError> {
+        let left_messages = self.all_messages(ctx, left).await?;
+        let right_messages = self.all_messages(ctx, right).await?;
+        Ok(ConversationComparison::compare(
+            (left, &left_messages),
+            (right, &right_messages),
+        ))
+    }
+
+    async fn all_messages(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> Result<Vec<Message>, RepositoryError> {
+        collect_pages(|page| {
+            self.message_repository
+                .find_by_conversation(ctx, conversation_id, page)
+        })
+        .await
+    }
+}
@@ -2,10 +2,15 @@
 
 use super::{AppendMessageRequest, ConversationService, ConversationServiceError};
 use crate::message::{
-    adapters::memory::{InMemoryConversationRepository, InMemoryMessageRepository},
-    domain::{ContentPart, ConversationId, Role, TextPart},
+    adapters::{
+        memory::{InMemoryConversationRepository, InMemoryMessageRepository},
+        token_counting::{HEURISTIC_ENCODING, HeuristicTokenCounter},
+    },
+    domain::{ContentPart, ConversationId, MessageTokenCount, Role, TextPart},
+    ports::MessageRepository,
     validation::service::DefaultMessageValidator,
 };
+use crate::pagination::PageRequest;
 use crate::test_support::test_request_ctx;
 use mockable::DefaultClock;
 use rstest::{fixture, rstest};
@@ -50,7 +55,9 @@ async fn create_and_read_history(
             ),
         )
         .await?;
-    let history = service.history(&ctx, conversation.id()).await?;
+    let history = service
+        .history(&ctx, conversation.id(), PageRequest::default())
+        .await?;
 
     assert_eq!(history.len(), 1);
     assert_eq!(
@@ -113,3 +120,68 @@ async fn append_validates_content(service: TestService, ctx: crate::context::Req
             if !validation.to_string().is_empty()
     ));
 }
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn concurrent_appends_never_collide(
+    service: TestService,
+    ctx: crate::context::RequestContext,
+) -> Result<(), eyre::Report> {
+    let conversation = service.create_conversation(&ctx).await?;
+
+    let appends = (0..8).map(|turn| {
+        service.append_message(
+            &ctx,
+            AppendMessageRequest::new(
+                conversation.id(),
+                Role::User,
+                vec![ContentPart::Text(TextPart::new(format!("turn {turn}")))],
+            ),
+        )
+    });
+    let appended = futures::future::try_join_all(appends).await?;
+
+    let mut sequences: Vec<u64> = appended
+        .iter()
+        .map(|message| message.sequence_number().value())
+        .collect();
+    sequences.sort_unstable();
+    assert_eq!(sequences, (1..=8).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn appended_messages_record_their_token_count(
+    ctx: crate::context::RequestContext,
+) -> Result<(), eyre::Report> {
+    let messages = Arc::new(InMemoryMessageRepository::new());
+    let service = ConversationService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        messages.clone(),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_token_counter(Arc::new(HeuristicTokenCounter::new()));
+    let conversation = service.create_conversation(&ctx).await?;
+
+    let message = service
+        .append_message(
+            &ctx,
+            AppendMessageRequest::new(
+                conversation.id(),
+                Role::User,
+                vec![ContentPart::Text(TextPart::new("Hello, world!"))],
+            ),
+        )
+        .await?;
+
+    assert_eq!(
+        message.metadata().token_count,
+        Some(MessageTokenCount::new(6, HEURISTIC_ENCODING))
+    );
+    let usage = messages.token_usage(&ctx, conversation.id()).await?;
+    assert_eq!(usage.tokens, 6);
+    assert!(usage.is_complete());
+    Ok(())
+}
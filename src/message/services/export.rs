@@ -0,0 +1,249 @@
+//! Export of conversations for analysis and fine-tuning.
+//!
+//! [`ConversationExporter`] reads a conversation's messages a page at a
+//! time, keeps those its [`ExportFilter`] allows, and streams them through
+//! the encoder of the requested [`ExportFormat`] to an [`AsyncWrite`], so
+//! exports of long conversations never hold the whole conversation in
+//! memory.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationId, ExportFilter, ExportReport, Message},
+    error::RepositoryError,
+    ports::{
+        ConversationRepository, ConversationRepositoryError, ExportEncoder, ExportFormat,
+        ExportFormatError, MessageRepository,
+    },
+};
+use crate::pagination::{Limit, PageRequest};
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Request to export one conversation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportRequest {
+    conversation_id: ConversationId,
+    format: String,
+    filter: ExportFilter,
+}
+
+impl ExportRequest {
+    /// Requests every message of a conversation in the format named
+    /// `format`.
+    #[must_use]
+    pub fn new(conversation_id: ConversationId, format: impl Into<String>) -> Self {
+        Self {
+            conversation_id,
+            format: format.into(),
+            filter: ExportFilter::default(),
+        }
+    }
+
+    /// Exports only the messages `filter` allows.
+    #[must_use]
+    pub fn with_filter(mut self, filter: ExportFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+/// Service-level errors for conversation exports.
+#[derive(Debug, Error)]
+pub enum ExportServiceError {
+    /// No format with the requested name is registered.
+    #[error("unknown export format: {0}")]
+    UnknownFormat(String),
+    /// Conversation does not exist.
+    #[error("conversation not found: {0}")]
+    ConversationNotFound(ConversationId),
+    /// A message could not be encoded.
+    #[error(transparent)]
+    Format(#[from] ExportFormatError),
+    /// Conversation repository failure.
+    #[error(transparent)]
+    ConversationRepository(#[from] ConversationRepositoryError),
+    /// Message repository failure.
+    #[error(transparent)]
+    MessageRepository(#[from] RepositoryError),
+    /// The output could not be written.
+    #[error("export output failed: {0}")]
+    Write(#[from] io::Error),
+}
+
+/// Result type for conversation exports.
+pub type ExportServiceResult<T> = Result<T, ExportServiceError>;
+
+/// Streams conversations out in pluggable formats.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+/// use corbusier::message::adapters::export::{JsonlExportFormat, MarkdownExportFormat};
+/// use corbusier::message::adapters::memory::{
+///     InMemoryConversationRepository, InMemoryMessageRepository,
+/// };
+/// use corbusier::message::domain::{
+///     ContentPart, Conversation, ExportFilter, Message, Role, SequenceNumber, TextPart,
+/// };
+/// use corbusier::message::ports::{ConversationRepository, MessageRepository};
+/// use corbusier::message::services::{ConversationExporter, ExportRequest};
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let conversations = InMemoryConversationRepository::new();
+/// let messages = InMemoryMessageRepository::new();
+/// let exporter = ConversationExporter::new(
+///     Arc::new(conversations.clone()),
+///     Arc::new(messages.clone()),
+/// )
+/// .with_format(Arc::new(JsonlExportFormat::new()))
+/// .with_format(Arc::new(MarkdownExportFormat::new()));
+/// let ctx = RequestContext::new(
+///     TenantId::new(),
+///     CorrelationId::new(),
+///     UserId::new(),
+///     SessionId::new(),
+/// );
+/// let conversation = Conversation::new(&DefaultClock);
+/// conversations.store(&ctx, &conversation).await?;
+/// let message = Message::new(
+///     conversation.id(),
+///     Role::User,
+///     vec![ContentPart::Text(TextPart::new("Hello"))],
+///     SequenceNumber::new(1),
+///     &DefaultClock,
+/// )?;
+/// messages.store(&ctx, &message).await?;
+///
+/// let mut transcript = Vec::new();
+/// let request = ExportRequest::new(conversation.id(), MarkdownExportFormat::NAME)
+///     .with_filter(ExportFilter::new().with_roles([Role::User]));
+/// let report = exporter.export(&ctx, &request, &mut transcript).await?;
+/// assert_eq!(report.exported, 1);
+/// assert!(String::from_utf8(transcript)?.contains("Hello"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ConversationExporter {
+    conversations: Arc<dyn ConversationRepository>,
+    messages: Arc<dyn MessageRepository>,
+    formats: BTreeMap<String, Arc<dyn ExportFormat>>,
+}
+
+impl ConversationExporter {
+    /// Creates an exporter with no formats registered.
+    #[must_use]
+    pub fn new(
+        conversations: Arc<dyn ConversationRepository>,
+        messages: Arc<dyn MessageRepository>,
+    ) -> Self {
+        Self {
+            conversations,
+            messages,
+            formats: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `format` under its name, replacing any format registered
+    /// under the same name.
+    #[must_use]
+    pub fn with_format(mut self, format: Arc<dyn ExportFormat>) -> Self {
+        self.formats.insert(format.name().to_owned(), format);
+        self
+    }
+
+    /// Returns the names of the registered formats, in order.
+    pub fn formats(&self) -> impl Iterator<Item = &str> {
+        self.formats.keys().map(String::as_str)
+    }
+
+    /// Writes a conversation to `writer` in the requested format.
+    ///
+    /// Messages are written oldest first. The writer is flushed once the
+    /// export is complete; output already written stays written when the
+    /// export fails part-way.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExportServiceError::UnknownFormat`] when no format has the
+    /// requested name, [`ExportServiceError::ConversationNotFound`] when the
+    /// conversation does not exist, [`ExportServiceError::Format`] when a
+    /// message cannot be encoded, [`ExportServiceError::Write`] when the
+    /// writer fails, or repository errors.
+    pub async fn export<W>(
+        &self,
+        ctx: &RequestContext,
+        request: &ExportRequest,
+        writer: &mut W,
+    ) -> ExportServiceResult<ExportReport>
+    where
+        W: AsyncWrite + Unpin + Send + ?Sized,
+    {
+        let conversation_id = request.conversation_id;
+        let format = self
+            .formats
+            .get(&request.format)
+            .ok_or_else(|| ExportServiceError::UnknownFormat(request.format.clone()))?;
+        self.conversations
+            .find_by_id(ctx, conversation_id)
+            .await?
+            .ok_or(ExportServiceError::ConversationNotFound(conversation_id))?;
+
+        let mut encoder = format.encoder(conversation_id);
+        writer.write_all(&encoder.begin()?).await?;
+        let mut report = ExportReport {
+            conversation_id,
+            exported: 0,
+            skipped: 0,
+        };
+        let mut page_request = PageRequest::new(Limit::MAX);
+        loop {
+            let page = self
+                .messages
+                .find_by_conversation(ctx, conversation_id, page_request)
+                .await?;
+            let next = page.next_cursor();
+            let (kept, skipped): (Vec<Message>, Vec<Message>) = page
+                .into_items()
+                .into_iter()
+                .partition(|message| request.filter.allows(message));
+            write_messages(encoder.as_mut(), &kept, writer).await?;
+            report.exported += kept.len();
+            report.skipped += skipped.len();
+            let Some(cursor) = next else {
+                break;
+            };
+            page_request = page_request.with_cursor(cursor);
+        }
+        writer.write_all(&encoder.finish()?).await?;
+        writer.flush().await?;
+        tracing::info!(
+            %conversation_id,
+            format = %request.format,
+            exported = report.exported,
+            skipped = report.skipped,
+            "conversation exported"
+        );
+        Ok(report)
+    }
+}
+
+async fn write_messages<W>(
+    encoder: &mut dyn ExportEncoder,
+    messages: &[Message],
+    writer: &mut W,
+) -> ExportServiceResult<()>
+where
+    W: AsyncWrite + Unpin + Send + ?Sized,
+{
+    for message in messages {
+        writer.write_all(&encoder.message(message)?).await?;
+    }
+    Ok(())
+}
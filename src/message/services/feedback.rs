@@ -0,0 +1,186 @@
+//! Application service for human feedback on assistant messages.
+//!
+//! [`MessageFeedbackService`] checks that the rated message exists in the
+//! named conversation, builds the feedback record attributed to the caller,
+//! and stores it, replacing the caller's earlier rating of the same message.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        ConversationId, FeedbackDomainError, FeedbackSubmission, FeedbackSummary, Message,
+        MessageFeedback, MessageId,
+    },
+    error::RepositoryError,
+    ports::{
+        MessageRepository,
+        feedback::{FeedbackError, MessageFeedbackRepository},
+    },
+};
+use crate::pagination::collect_pages;
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Request payload for rating an assistant message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmitFeedbackRequest {
+    /// Conversation containing the message.
+    pub conversation_id: ConversationId,
+    /// The rated message.
+    pub message_id: MessageId,
+    /// Rating, comment, and category.
+    pub submission: FeedbackSubmission,
+}
+
+impl SubmitFeedbackRequest {
+    /// Creates a feedback request.
+    #[must_use]
+    pub const fn new(
+        conversation_id: ConversationId,
+        message_id: MessageId,
+        submission: FeedbackSubmission,
+    ) -> Self {
+        Self {
+            conversation_id,
+            message_id,
+            submission,
+        }
+    }
+}
+
+/// Service-level errors for feedback workflows.
+#[derive(Debug, Error)]
+pub enum FeedbackServiceError {
+    /// The message does not exist in the named conversation.
+    #[error("message {message_id} not found in conversation {conversation_id}")]
+    MessageNotFound {
+        /// Conversation named by the caller.
+        conversation_id: ConversationId,
+        /// Message named by the caller.
+        message_id: MessageId,
+    },
+    /// The feedback was rejected.
+    #[error(transparent)]
+    Domain(#[from] FeedbackDomainError),
+    /// Message repository failure.
+    #[error(transparent)]
+    MessageRepository(#[from] RepositoryError),
+    /// Feedback repository failure.
+    #[error(transparent)]
+    Feedback(#[from] FeedbackError),
+}
+
+/// Result type for feedback service operations.
+pub type FeedbackServiceResult<T> = Result<T, FeedbackServiceError>;
+
+/// Feedback application service.
+#[derive(Clone)]
+pub struct MessageFeedbackService<MessageRepo, FeedbackRepo, C>
+where
+    MessageRepo: MessageRepository,
+    FeedbackRepo: MessageFeedbackRepository,
+    C: Clock + Send + Sync,
+{
+    message_repository: Arc<MessageRepo>,
+    feedback_repository: Arc<FeedbackRepo>,
+    clock: Arc<C>,
+}
+
+impl<MessageRepo, FeedbackRepo, C> MessageFeedbackService<MessageRepo, FeedbackRepo, C>
+where
+    MessageRepo: MessageRepository,
+    FeedbackRepo: MessageFeedbackRepository,
+    C: Clock + Send + Sync,
+{
+    /// Creates a new feedback service.
+    #[must_use]
+    pub const fn new(
+        message_repository: Arc<MessageRepo>,
+        feedback_repository: Arc<FeedbackRepo>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            message_repository,
+            feedback_repository,
+            clock,
+        }
+    }
+
+    /// Records the caller's feedback on an assistant message.
+    ///
+    /// Submitting again for the same message replaces the caller's earlier
+    /// rating and keeps its identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FeedbackServiceError::MessageNotFound`] when the message is
+    /// not in the named conversation, or [`FeedbackServiceError::Domain`]
+    /// when the message is not an assistant message or the submission is
+    /// invalid.
+    pub async fn submit(
+        &self,
+        ctx: &RequestContext,
+        request: SubmitFeedbackRequest,
+    ) -> FeedbackServiceResult<MessageFeedback> {
+        let message = self
+            .find_message(ctx, request.conversation_id, request.message_id)
+            .await?;
+        let feedback = MessageFeedback::for_message(
+            &message,
+            ctx.user_id(),
+            request.submission,
+            &*self.clock,
+        )?;
+        Ok(self.feedback_repository.upsert(ctx, &feedback).await?)
+    }
+
+    /// Returns every reviewer's feedback on a message, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FeedbackServiceError::MessageNotFound`] when the message is
+    /// not in the named conversation.
+    pub async fn feedback_for_message(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        message_id: MessageId,
+    ) -> FeedbackServiceResult<Vec<MessageFeedback>> {
+        self.find_message(ctx, conversation_id, message_id).await?;
+        Ok(collect_pages(|page| {
+            self.feedback_repository
+                .list_for_message(ctx, message_id, page)
+        })
+        .await?)
+    }
+
+    /// Returns feedback totals per agent backend and instruction-set
+    /// version.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FeedbackServiceError::Feedback`] when the feedback store
+    /// fails.
+    pub async fn summaries(
+        &self,
+        ctx: &RequestContext,
+    ) -> FeedbackServiceResult<Vec<FeedbackSummary>> {
+        Ok(self.feedback_repository.summarize(ctx).await?)
+    }
+
+    async fn find_message(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        message_id: MessageId,
+    ) -> FeedbackServiceResult<Message> {
+        self.message_repository
+            .find_by_id(ctx, message_id)
+            .await?
+            .filter(|message| message.conversation_id() == conversation_id)
+            .ok_or(FeedbackServiceError::MessageNotFound {
+                conversation_id,
+                message_id,
+            })
+    }
+}
@@ -0,0 +1,161 @@
+//! Forking conversations to explore alternative strategies.
+//!
+//! [`ConversationForkService`] creates a [`ConversationFork`] for a request
+//! and hands it to the repository, which copies the parent's history through
+//! the fork point into a new conversation and records where it came from.
+//! The same service answers provenance queries in both directions: where a
+//! fork came from, and which forks a conversation has.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationFork, ConversationForkRequest, ConversationId},
+    ports::{ConversationForkRepository, ConversationForkResult},
+};
+use crate::pagination::{Page, PageRequest};
+use mockable::Clock;
+use std::sync::Arc;
+
+/// Forks conversations and reports their provenance.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+/// use corbusier::message::adapters::memory::{
+///     InMemoryContextSnapshotAdapter, InMemoryConversationForkAdapter,
+///     InMemoryConversationRepository, InMemoryMessageRepository,
+/// };
+/// use corbusier::message::domain::{
+///     ContentPart, Conversation, ConversationForkRequest, Message, Role, SequenceNumber,
+///     TextPart,
+/// };
+/// use corbusier::message::ports::{ConversationRepository, MessageRepository};
+/// use corbusier::message::services::ConversationForkService;
+/// use corbusier::pagination::PageRequest;
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let conversations = InMemoryConversationRepository::new();
+/// let messages = InMemoryMessageRepository::new();
+/// let service = ConversationForkService::new(
+///     Arc::new(InMemoryConversationForkAdapter::new(
+///         conversations.clone(),
+///         messages.clone(),
+///         InMemoryContextSnapshotAdapter::new(),
+///     )),
+///     Arc::new(DefaultClock),
+/// );
+/// let ctx = RequestContext::new(
+///     TenantId::new(),
+///     CorrelationId::new(),
+///     UserId::new(),
+///     SessionId::new(),
+/// );
+/// let parent = Conversation::new(&DefaultClock);
+/// conversations.store(&ctx, &parent).await?;
+/// for sequence in 1..=3 {
+///     let message = Message::new(
+///         parent.id(),
+///         Role::User,
+///         vec![ContentPart::Text(TextPart::new("Try this"))],
+///         SequenceNumber::new(sequence),
+///         &DefaultClock,
+///     )?;
+///     messages.store(&ctx, &message).await?;
+/// }
+///
+/// let fork = service
+///     .fork(
+///         &ctx,
+///         ConversationForkRequest {
+///             parent_conversation_id: parent.id(),
+///             forked_at: SequenceNumber::new(2),
+///         },
+///     )
+///     .await?;
+/// let copied = messages
+///     .find_by_conversation(&ctx, fork.conversation_id, PageRequest::default())
+///     .await?;
+/// assert_eq!(copied.items().len(), 2);
+/// let forks = service
+///     .forks(&ctx, parent.id(), PageRequest::default())
+///     .await?;
+/// assert_eq!(forks.items(), &[fork]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ConversationForkService {
+    repository: Arc<dyn ConversationForkRepository>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl ConversationForkService {
+    /// Creates a service that forks conversations through `repository`.
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn ConversationForkRepository>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Forks a conversation after the requested message.
+    ///
+    /// Returns the fork's provenance, whose `conversation_id` names the new
+    /// conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationForkError`](crate::message::ports::ConversationForkError)
+    /// when the parent or the fork point does not exist, or the fork cannot
+    /// be stored.
+    pub async fn fork(
+        &self,
+        ctx: &RequestContext,
+        request: ConversationForkRequest,
+    ) -> ConversationForkResult<ConversationFork> {
+        let fork = ConversationFork::new(request, &*self.clock);
+        let stored = self.repository.fork(ctx, &fork).await?;
+        tracing::info!(
+            conversation_id = %stored.conversation_id,
+            parent_conversation_id = %stored.parent_conversation_id,
+            forked_at = %stored.forked_at,
+            "conversation forked"
+        );
+        Ok(stored)
+    }
+
+    /// Returns where a conversation was forked from, or `None` when it is
+    /// not a fork.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationForkError`](crate::message::ports::ConversationForkError)
+    /// when the lookup fails.
+    pub async fn provenance(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> ConversationForkResult<Option<ConversationFork>> {
+        self.repository.find_fork(ctx, conversation_id).await
+    }
+
+    /// Returns a page of the forks taken from a conversation, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationForkError`](crate::message::ports::ConversationForkError)
+    /// when the query fails.
+    pub async fn forks(
+        &self,
+        ctx: &RequestContext,
+        parent_conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> ConversationForkResult<Page<ConversationFork>> {
+        self.repository
+            .find_forks(ctx, parent_conversation_id, page)
+            .await
+    }
+}
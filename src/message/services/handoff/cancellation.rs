@@ -0,0 +1,102 @@
+//! Operator cancellation of pending handoffs.
+
+use mockable::Clock;
+
+use super::workflows::HandoffService;
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{HandoffId, HandoffStatus},
+    ports::{
+        agent_session::AgentSessionRepository,
+        context_snapshot::ContextSnapshotPort,
+        handoff::{AgentHandoffPort, HandoffError, HandoffResult},
+    },
+};
+use crate::operator::domain::{
+    OperatorAction, OperatorActionDetails, OperatorActionKind, OperatorReason, OperatorSubject,
+    OperatorTransition,
+};
+
+impl<S, H, C, K> HandoffService<S, H, C, K>
+where
+    S: AgentSessionRepository,
+    H: AgentHandoffPort,
+    C: ContextSnapshotPort,
+    K: Clock + Send + Sync,
+{
+    /// Cancels a pending handoff on an operator's behalf.
+    ///
+    /// Reverts the source session to active state if it was marked as handed
+    /// off, and records the cancellation as an operator action when an
+    /// operator action repository is attached.
+    ///
+    /// # Parameters
+    ///
+    /// - `handoff_id`: The handoff to cancel
+    /// - `reason`: Why the operator cancelled it
+    ///
+    /// # Errors
+    ///
+    /// Returns `HandoffError` if:
+    /// - Handoff not found
+    /// - Handoff is already in a terminal state
+    /// - Source session update fails
+    /// - The operator action cannot be recorded
+    pub async fn cancel(
+        &self,
+        ctx: &RequestContext,
+        handoff_id: HandoffId,
+        reason: &OperatorReason,
+    ) -> HandoffResult<()> {
+        // Find the handoff
+        let handoff = self
+            .handoff_adapter
+            .find_handoff(ctx, handoff_id)
+            .await?
+            .ok_or(HandoffError::NotFound(handoff_id))?;
+
+        // Revert source session if needed
+        let source_session = self
+            .session_repo
+            .find_by_id(ctx, handoff.source_session_id)
+            .await
+            .map_err(|e| HandoffError::SessionUpdateFailed(e.to_string()))?;
+        let conversation_id = source_session
+            .as_ref()
+            .map(|session| session.conversation_id);
+        if let Some(mut session) = source_session
+            && session.revert_from_handoff(handoff_id)
+        {
+            self.session_repo
+                .update(ctx, &session)
+                .await
+                .map_err(|e| HandoffError::SessionUpdateFailed(e.to_string()))?;
+        }
+
+        // Cancel the handoff
+        self.handoff_adapter
+            .cancel_handoff(ctx, handoff_id, Some(reason.as_str()))
+            .await?;
+
+        if let Some(operator_actions) = &self.operator_actions {
+            let details = OperatorActionDetails {
+                kind: OperatorActionKind::HandoffCancelled,
+                subject: OperatorSubject::Handoff {
+                    handoff_id,
+                    conversation_id,
+                },
+                reason: reason.clone(),
+                transition: Some(OperatorTransition::new(
+                    handoff.status,
+                    HandoffStatus::Cancelled,
+                )),
+            };
+            let action = OperatorAction::new(ctx, details, self.clock.as_ref());
+            operator_actions
+                .record(ctx, &action)
+                .await
+                .map_err(HandoffError::persistence)?;
+        }
+        Ok(())
+    }
+}
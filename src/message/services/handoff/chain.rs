@@ -0,0 +1,54 @@
+//! Queries over the chain of handoffs within a conversation.
+
+use std::sync::Arc;
+
+use mockable::Clock;
+
+use super::workflows::HandoffService;
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationId, HandoffChain},
+    ports::{
+        agent_session::AgentSessionRepository,
+        context_snapshot::ContextSnapshotPort,
+        handoff::{AgentHandoffPort, HandoffError, HandoffResult},
+        handoff_chain::HandoffChainPort,
+    },
+};
+
+impl<S, H, C, K> HandoffService<S, H, C, K>
+where
+    S: AgentSessionRepository,
+    H: AgentHandoffPort,
+    C: ContextSnapshotPort,
+    K: Clock + Send + Sync,
+{
+    /// Attaches the port that reads handoff chains.
+    #[must_use]
+    pub fn with_handoff_chain(mut self, chains: Arc<dyn HandoffChainPort>) -> Self {
+        self.handoff_chains = Some(chains);
+        self
+    }
+
+    /// Returns a conversation's handoffs in initiation order, each joined
+    /// to its source and target sessions and the snapshots captured on
+    /// either side.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HandoffError` if:
+    /// - No handoff chain port is attached
+    /// - A handoff's source session is missing
+    /// - The chain cannot be read
+    pub async fn handoff_chain(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> HandoffResult<HandoffChain> {
+        self.handoff_chains
+            .as_ref()
+            .ok_or(HandoffError::ChainNotConfigured)?
+            .handoff_chain(ctx, conversation_id)
+            .await
+    }
+}
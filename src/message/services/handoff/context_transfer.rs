@@ -0,0 +1,219 @@
+//! Context transfer to the target session of a completed handoff.
+
+use std::sync::Arc;
+
+use mockable::Clock;
+
+use super::workflows::HandoffService;
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        AgentSessionId, ContentPartKind, ContextWindowSnapshot, ConversationId,
+        HandoffContextPackage, HandoffMetadata, HandoffStatus, Message, MessageQuery, SnapshotType,
+    },
+    ports::{
+        MessageRepository,
+        agent_session::AgentSessionRepository,
+        context_snapshot::ContextSnapshotPort,
+        handoff::{AgentHandoffPort, HandoffError, HandoffResult},
+        handoff_context::HandoffContextRepository,
+        summary::RollingSummaryRepository,
+    },
+};
+use crate::pagination::collect_pages;
+
+/// Ports the service reads and writes context packages through.
+#[derive(Clone)]
+pub(super) struct ContextTransfer {
+    messages: Arc<dyn MessageRepository>,
+    packages: Arc<dyn HandoffContextRepository>,
+}
+
+fn transfer_failed(err: impl std::fmt::Display) -> HandoffError {
+    HandoffError::ContextTransferFailed(err.to_string())
+}
+
+impl<S, H, C, K> HandoffService<S, H, C, K>
+where
+    S: AgentSessionRepository,
+    H: AgentHandoffPort,
+    C: ContextSnapshotPort,
+    K: Clock + Send + Sync,
+{
+    /// Prepares a context package for the target session of every handoff
+    /// completed from now on.
+    #[must_use]
+    pub fn with_context_transfer(
+        mut self,
+        messages: Arc<dyn MessageRepository>,
+        packages: Arc<dyn HandoffContextRepository>,
+    ) -> Self {
+        self.context_transfer = Some(ContextTransfer { messages, packages });
+        self
+    }
+
+    /// Takes package summaries from the conversation's rolling summary
+    /// rather than its latest compaction.
+    #[must_use]
+    pub fn with_rolling_summaries(mut self, summaries: Arc<dyn RollingSummaryRepository>) -> Self {
+        self.rolling_summaries = Some(summaries);
+        self
+    }
+
+    /// Materialises and stores the context package for the target session
+    /// of a completed handoff.
+    ///
+    /// The package starts from the snapshot captured when the handoff was
+    /// initiated. It carries the conversation's summary, its pinned
+    /// messages, and the tool calls up to the end of the snapshot's window
+    /// that no message answers. [`complete`](Self::complete) calls this
+    /// once context transfer is configured; call it directly to rebuild a
+    /// package.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HandoffError` if:
+    /// - The handoff has not completed
+    /// - The target session is not found
+    /// - Context transfer is not configured
+    /// - Any of the package's sources cannot be read, or the package
+    ///   cannot be stored
+    pub async fn prepare_target_context(
+        &self,
+        ctx: &RequestContext,
+        handoff: &HandoffMetadata,
+    ) -> HandoffResult<HandoffContextPackage> {
+        let (Some(target_session_id), HandoffStatus::Completed) =
+            (handoff.target_session_id, handoff.status)
+        else {
+            return Err(HandoffError::invalid_transition(
+                handoff.status,
+                HandoffStatus::Completed,
+            ));
+        };
+        let transfer = self.transfer()?;
+        let conversation_id = self
+            .session_repo
+            .find_by_id(ctx, target_session_id)
+            .await
+            .map_err(|_| HandoffError::SessionNotFound(target_session_id))?
+            .ok_or(HandoffError::SessionNotFound(target_session_id))?
+            .conversation_id;
+
+        let mut package = HandoffContextPackage::new(
+            handoff,
+            conversation_id,
+            target_session_id,
+            self.clock.as_ref(),
+        );
+        let snapshot = self.initiation_snapshot(ctx, handoff).await?;
+        if let Some(initiation) = &snapshot {
+            package = package.with_snapshot(initiation);
+        }
+        if let Some(summary) = self.summary(ctx, conversation_id).await? {
+            package = package.with_summary(summary);
+        }
+        let pinned = transfer
+            .messages
+            .find_pinned(ctx, conversation_id)
+            .await
+            .map_err(transfer_failed)?;
+        let history = tool_history(transfer, ctx, conversation_id, snapshot.as_ref()).await?;
+        package = package
+            .with_pinned_messages(pinned.iter().map(Message::id))
+            .with_open_tool_calls(&history);
+
+        transfer
+            .packages
+            .store(ctx, &package)
+            .await
+            .map_err(transfer_failed)?;
+        Ok(package)
+    }
+
+    /// Returns the context package prepared for `target_session_id`, or
+    /// `None` when the session took over no handoff.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HandoffError` if context transfer is not configured or the
+    /// package cannot be read.
+    pub async fn target_context(
+        &self,
+        ctx: &RequestContext,
+        target_session_id: AgentSessionId,
+    ) -> HandoffResult<Option<HandoffContextPackage>> {
+        self.transfer()?
+            .packages
+            .find_for_session(ctx, target_session_id)
+            .await
+            .map_err(transfer_failed)
+    }
+
+    fn transfer(&self) -> HandoffResult<&ContextTransfer> {
+        self.context_transfer
+            .as_ref()
+            .ok_or_else(|| transfer_failed("context transfer is not configured"))
+    }
+
+    /// Finds the snapshot captured for `handoff` when it was initiated.
+    async fn initiation_snapshot(
+        &self,
+        ctx: &RequestContext,
+        handoff: &HandoffMetadata,
+    ) -> HandoffResult<Option<ContextWindowSnapshot>> {
+        let snapshot_adapter = &self.snapshot_adapter;
+        let snapshots = collect_pages(|page| {
+            snapshot_adapter.find_snapshots_for_session(ctx, handoff.source_session_id, page)
+        })
+        .await
+        .map_err(transfer_failed)?;
+        Ok(snapshots.into_iter().rev().find(|snapshot| {
+            snapshot.snapshot_type == SnapshotType::HandoffInitiated
+                && snapshot.captured_at <= handoff.initiated_at
+        }))
+    }
+
+    async fn summary(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> HandoffResult<Option<String>> {
+        if let Some(summaries) = &self.rolling_summaries {
+            let rolling = summaries
+                .find(ctx, conversation_id)
+                .await
+                .map_err(transfer_failed)?;
+            return Ok(rolling.map(|summary| summary.text().to_owned()));
+        }
+        let compaction = self
+            .snapshot_adapter
+            .find_latest_compaction(ctx, conversation_id)
+            .await
+            .map_err(transfer_failed)?;
+        Ok(compaction.and_then(|snapshot| snapshot.summary))
+    }
+}
+
+/// Reads the messages holding tool calls or results, up to the end of the
+/// initiation snapshot's window when there is one.
+async fn tool_history(
+    transfer: &ContextTransfer,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+    snapshot: Option<&ContextWindowSnapshot>,
+) -> HandoffResult<Vec<Message>> {
+    let messages = &transfer.messages;
+    let mut history = collect_pages(|page| async move {
+        let query = MessageQuery::new()
+            .with_content_part(ContentPartKind::ToolCall)
+            .with_content_part(ContentPartKind::ToolResult)
+            .with_page(page);
+        messages.query(ctx, conversation_id, &query).await
+    })
+    .await
+    .map_err(transfer_failed)?;
+    let window_end = snapshot.map(|initiation| initiation.sequence_range.end);
+    history.retain(|message| window_end.is_none_or(|end| message.sequence_number() <= end));
+    Ok(history)
+}
@@ -4,12 +4,23 @@
 //! ensuring context is preserved and proper audit trails are maintained.
 //!
 //! This module is split into submodules:
+//! - [`cancellation`]: Operator cancellation of pending handoffs
+//! - [`chain`]: Ordered chains of a conversation's handoffs
+//! - [`context_transfer`]: Context packages for the target sessions of
+//!   completed handoffs
+//! - [`rejection`]: Target agent rejection of pending handoffs
 //! - [`params`]: Parameter types for initiating and completing handoffs
 //! - [`conversions`]: Type conversions between session state and handoff status
+//! - [`turns`]: Checks that handoffs name persisted turns
 //! - [`workflows`]: The [`HandoffService`] orchestration logic
 
+mod cancellation;
+mod chain;
+mod context_transfer;
 mod conversions;
 mod params;
+mod rejection;
+mod turns;
 mod workflows;
 
 /// Parameter types for initiating and completing handoffs.
@@ -0,0 +1,101 @@
+//! Target agent rejection of pending handoffs.
+
+use mockable::Clock;
+
+use super::workflows::HandoffService;
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        ConversationLifecycleChange, ConversationLifecycleEvent, HandoffId, HandoffMetadata,
+        HandoffStatus,
+    },
+    ports::{
+        agent_session::AgentSessionRepository,
+        context_snapshot::ContextSnapshotPort,
+        handoff::{AgentHandoffPort, HandoffError, HandoffResult},
+    },
+};
+
+impl<S, H, C, K> HandoffService<S, H, C, K>
+where
+    S: AgentSessionRepository,
+    H: AgentHandoffPort,
+    C: ContextSnapshotPort,
+    K: Clock + Send + Sync,
+{
+    /// Rejects a pending handoff on the target agent's behalf.
+    ///
+    /// The handoff ends [`HandoffStatus::Failed`] with the reason recorded,
+    /// the source session returns to active state so its agent can carry
+    /// on, and a `HandoffRejected` lifecycle event is dispatched when hooks
+    /// are attached.
+    ///
+    /// # Parameters
+    ///
+    /// - `handoff_id`: The handoff to reject
+    /// - `reason`: Why the target agent rejected it
+    ///
+    /// # Errors
+    ///
+    /// Returns `HandoffError` if:
+    /// - The reason is blank
+    /// - Handoff not found
+    /// - Handoff is already in a terminal state
+    /// - Source session lookup or update fails
+    pub async fn reject(
+        &self,
+        ctx: &RequestContext,
+        handoff_id: HandoffId,
+        reason: &str,
+    ) -> HandoffResult<HandoffMetadata> {
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(HandoffError::MissingRejectionReason);
+        }
+        let handoff = self
+            .handoff_adapter
+            .find_handoff(ctx, handoff_id)
+            .await?
+            .ok_or(HandoffError::NotFound(handoff_id))?;
+        if handoff.is_terminal() {
+            return Err(HandoffError::invalid_transition(
+                handoff.status,
+                HandoffStatus::Failed,
+            ));
+        }
+
+        let rejected = self
+            .handoff_adapter
+            .reject_handoff(ctx, handoff_id, reason)
+            .await?;
+
+        // Hand control back to the source session
+        let mut source_session = self
+            .session_repo
+            .find_by_id(ctx, rejected.source_session_id)
+            .await
+            .map_err(|e| HandoffError::SessionUpdateFailed(e.to_string()))?
+            .ok_or(HandoffError::SessionNotFound(rejected.source_session_id))?;
+        if source_session.revert_from_handoff(handoff_id) {
+            self.session_repo
+                .update(ctx, &source_session)
+                .await
+                .map_err(|e| HandoffError::SessionUpdateFailed(e.to_string()))?;
+        }
+
+        if let Some(hooks) = &self.lifecycle_hooks {
+            let change = ConversationLifecycleChange::HandoffRejected {
+                handoff_id,
+                target_agent: rejected.target_agent.clone(),
+                reason: reason.to_owned(),
+            };
+            let event = ConversationLifecycleEvent::new(
+                source_session.conversation_id,
+                change,
+                self.clock.utc(),
+            );
+            hooks.dispatch(ctx, &event).await;
+        }
+        Ok(rejected)
+    }
+}
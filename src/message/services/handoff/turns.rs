@@ -0,0 +1,53 @@
+//! Checking that handoffs name persisted turns.
+
+use std::sync::Arc;
+
+use mockable::Clock;
+
+use super::workflows::HandoffService;
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{AgentSession, TurnId},
+    ports::{
+        agent_session::AgentSessionRepository,
+        context_snapshot::ContextSnapshotPort,
+        handoff::{AgentHandoffPort, HandoffError, HandoffResult},
+        turn::TurnRepository,
+    },
+};
+
+impl<S, H, C, K> HandoffService<S, H, C, K>
+where
+    S: AgentSessionRepository,
+    H: AgentHandoffPort,
+    C: ContextSnapshotPort,
+    K: Clock + Send + Sync,
+{
+    /// Attaches the repository of turns, so initiated handoffs must name a
+    /// prior turn handled by their source session.
+    #[must_use]
+    pub fn with_turns(mut self, turns: Arc<dyn TurnRepository>) -> Self {
+        self.turns = Some(turns);
+        self
+    }
+
+    /// Checks that `prior_turn_id` names a turn handled by
+    /// `source_session`, when a turn repository is attached.
+    pub(super) async fn require_prior_turn(
+        &self,
+        ctx: &RequestContext,
+        source_session: &AgentSession,
+        prior_turn_id: TurnId,
+    ) -> HandoffResult<()> {
+        let Some(turns) = &self.turns else {
+            return Ok(());
+        };
+        turns
+            .find_by_id(ctx, prior_turn_id)
+            .await
+            .map_err(HandoffError::persistence)?
+            .filter(|turn| turn.session_id == source_session.session_id)
+            .map(|_| ())
+            .ok_or(HandoffError::PriorTurnNotFound(prior_turn_id))
+    }
+}
@@ -8,19 +8,27 @@ use std::sync::Arc;
 
 use mockable::Clock;
 
+use super::context_transfer::ContextTransfer;
 use super::params::{CompleteHandoffParams, ServiceInitiateParams};
 use crate::context::RequestContext;
+use crate::message::services::ConversationLifecycleHooks;
 use crate::message::{
     domain::{
-        AgentSession, ContextWindowSnapshot, ConversationId, HandoffId, HandoffMetadata,
-        HandoffSessionParams, MessageSummary, SequenceRange, SnapshotParams, SnapshotType,
+        AgentSession, ContextWindowSnapshot, ConversationId, ConversationLifecycleChange,
+        ConversationLifecycleEvent, HandoffMetadata, HandoffSessionParams, HandoffStatus,
+        MessageSummary, SequenceRange, SnapshotParams, SnapshotType,
     },
     ports::{
         agent_session::{AgentSessionRepository, SessionResult},
         context_snapshot::ContextSnapshotPort,
         handoff::{AgentHandoffPort, HandoffError, HandoffResult, InitiateHandoffParams},
+        handoff_chain::HandoffChainPort,
+        summary::RollingSummaryRepository,
+        turn::TurnRepository,
     },
 };
+use crate::operator::ports::OperatorActionRepository;
+use crate::pagination::collect_pages;
 
 /// Service for coordinating agent handoffs with context preservation.
 ///
@@ -72,10 +80,16 @@ where
     C: ContextSnapshotPort,
     K: Clock + Send + Sync,
 {
-    session_repo: Arc<S>,
-    handoff_adapter: Arc<H>,
-    snapshot_adapter: Arc<C>,
-    clock: Arc<K>,
+    pub(super) session_repo: Arc<S>,
+    pub(super) handoff_adapter: Arc<H>,
+    pub(super) snapshot_adapter: Arc<C>,
+    pub(super) clock: Arc<K>,
+    pub(super) lifecycle_hooks: Option<Arc<ConversationLifecycleHooks>>,
+    pub(super) operator_actions: Option<Arc<dyn OperatorActionRepository>>,
+    pub(super) context_transfer: Option<ContextTransfer>,
+    pub(super) rolling_summaries: Option<Arc<dyn RollingSummaryRepository>>,
+    pub(super) handoff_chains: Option<Arc<dyn HandoffChainPort>>,
+    pub(super) turns: Option<Arc<dyn TurnRepository>>,
 }
 
 impl<S, H, C, K> HandoffService<S, H, C, K>
@@ -97,9 +111,32 @@ where
             handoff_adapter,
             snapshot_adapter,
             clock,
+            lifecycle_hooks: None,
+            operator_actions: None,
+            context_transfer: None,
+            rolling_summaries: None,
+            handoff_chains: None,
+            turns: None,
         }
     }
 
+    /// Attaches hooks that are told when a handoff completes.
+    #[must_use]
+    pub fn with_lifecycle_hooks(mut self, hooks: Arc<ConversationLifecycleHooks>) -> Self {
+        self.lifecycle_hooks = Some(hooks);
+        self
+    }
+
+    /// Attaches the repository that records operator cancellations.
+    #[must_use]
+    pub fn with_operator_actions(
+        mut self,
+        operator_actions: Arc<dyn OperatorActionRepository>,
+    ) -> Self {
+        self.operator_actions = Some(operator_actions);
+        self
+    }
+
     /// Initiates a handoff from the current active session to a target agent.
     ///
     /// This method:
@@ -113,6 +150,8 @@ where
     /// Returns `HandoffError` if:
     /// - Source session not found
     /// - Source session is not active
+    /// - The prior turn is not a turn of the source session, when turns are
+    ///   attached
     /// - Handoff creation fails
     /// - Source session update fails
     pub async fn initiate(
@@ -131,9 +170,11 @@ where
         if !source_session.is_active() {
             return Err(HandoffError::InvalidStateTransition {
                 from: source_session.state.into(),
-                to: crate::message::domain::HandoffStatus::Initiated,
+                to: HandoffStatus::Initiated,
             });
         }
+        self.require_prior_turn(ctx, &source_session, params.prior_turn_id)
+            .await?;
 
         // Capture context snapshot before handoff
         let snapshot = self.build_snapshot(SnapshotParams {
@@ -187,6 +228,8 @@ where
     /// 1. Validates the handoff exists and is in correct state
     /// 2. Creates a context snapshot for the new session start
     /// 3. Completes the handoff record
+    /// 4. Prepares the target session's context package, when context
+    ///    transfer is configured
     ///
     /// # Errors
     ///
@@ -194,6 +237,7 @@ where
     /// - Handoff not found
     /// - Handoff is not in `Initiated` or `Accepted` state
     /// - Target session not found
+    /// - The context package cannot be prepared
     pub async fn complete(
         &self,
         ctx: &RequestContext,
@@ -252,56 +296,23 @@ where
             .handoff_adapter
             .complete_handoff(ctx, handoff_id, target_session_id)
             .await?;
-
-        Ok(completed)
-    }
-
-    /// Cancels a pending handoff.
-    ///
-    /// Reverts the source session to active state if it was marked as handed off.
-    ///
-    /// # Parameters
-    ///
-    /// - `handoff_id`: The handoff to cancel
-    /// - `reason`: Optional reason for cancellation
-    ///
-    /// # Errors
-    ///
-    /// Returns `HandoffError` if:
-    /// - Handoff not found
-    /// - Handoff is already in a terminal state
-    /// - Source session update fails
-    pub async fn cancel(
-        &self,
-        ctx: &RequestContext,
-        handoff_id: HandoffId,
-        reason: Option<&str>,
-    ) -> HandoffResult<()> {
-        // Find the handoff
-        let handoff = self
-            .handoff_adapter
-            .find_handoff(ctx, handoff_id)
-            .await?
-            .ok_or(HandoffError::NotFound(handoff_id))?;
-
-        // Revert source session if needed
-        if let Some(mut source_session) = self
-            .session_repo
-            .find_by_id(ctx, handoff.source_session_id)
-            .await
-            .map_err(|e| HandoffError::SessionUpdateFailed(e.to_string()))?
-            && source_session.revert_from_handoff(handoff_id)
-        {
-            self.session_repo
-                .update(ctx, &source_session)
-                .await
-                .map_err(|e| HandoffError::SessionUpdateFailed(e.to_string()))?;
+        if self.context_transfer.is_some() {
+            self.prepare_target_context(ctx, &completed).await?;
         }
 
-        // Cancel the handoff
-        self.handoff_adapter
-            .cancel_handoff(ctx, handoff_id, reason)
-            .await
+        if let Some(hooks) = &self.lifecycle_hooks {
+            let change = ConversationLifecycleChange::HandoffCompleted {
+                handoff_id,
+                target_agent: completed.target_agent.clone(),
+            };
+            let event = ConversationLifecycleEvent::new(
+                target_session.conversation_id,
+                change,
+                self.clock.utc(),
+            );
+            hooks.dispatch(ctx, &event).await;
+        }
+        Ok(completed)
     }
 
     /// Creates a new session for the target agent during handoff acceptance.
@@ -344,10 +355,11 @@ where
         ctx: &RequestContext,
         conversation_id: ConversationId,
     ) -> HandoffResult<Option<HandoffMetadata>> {
-        let handoffs = self
-            .handoff_adapter
-            .list_handoffs_for_conversation(ctx, conversation_id)
-            .await?;
+        let handoff_adapter = &self.handoff_adapter;
+        let handoffs = collect_pages(|page| {
+            handoff_adapter.list_handoffs_for_conversation(ctx, conversation_id, page)
+        })
+        .await?;
 
         // Find a non-terminal handoff
         Ok(handoffs.into_iter().find(|h| !h.is_terminal()))
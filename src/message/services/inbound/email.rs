@@ -0,0 +1,294 @@
+//! Email-driven conversations and tasks.
+//!
+//! Each email thread feeds one conversation: the opening email creates it
+//! and every reply in the thread is appended to it. An email whose subject
+//! names an issue, such as `[github:leynos/corbusier#42] Fix the flaky
+//! test`, also opens a task for that issue through an [`EmailTaskIntake`].
+//! Emails arrive from a provider webhook or by polling a [`Mailbox`], and
+//! [`EmailReplyHook`] sends each assistant reply back to the thread's
+//! sender through an [`EmailNotifier`].
+
+use super::{InboundMessageService, InboundReceipt, InboundServiceError, InboundServiceResult};
+use crate::context::{CorrelationId, RequestContext};
+use crate::message::{
+    domain::{
+        ContentPart, ConversationId, ConversationLifecycleChange, ConversationLifecycleEvent,
+        EmailEnvelope, InboundEmail, Message, OutboundEmail, Role,
+    },
+    ports::{
+        ConversationLifecycleHook, ConversationRepository, InboundIdentityMapping,
+        LifecycleHookError, LifecycleHookResult, MessageRepository, MessageValidator,
+        email::{EmailNotifier, Mailbox},
+    },
+};
+use crate::pagination::{PageRequest, SortOrder};
+use crate::task::{
+    domain::{IssueRef, Task},
+    ports::TaskRepository,
+    services::{CreateTaskFromIssueRequest, TaskLifecycleResult, TaskLifecycleService},
+};
+use async_trait::async_trait;
+use mockable::Clock;
+use std::sync::Arc;
+
+/// Opens tasks for emails that name an issue in their subject.
+#[async_trait]
+pub trait EmailTaskIntake: Send + Sync {
+    /// Returns the task for `issue_ref`, creating it from the email's
+    /// `title` and `description` when the issue has no task yet.
+    async fn open_task(
+        &self,
+        ctx: &RequestContext,
+        issue_ref: &IssueRef,
+        title: &str,
+        description: &str,
+    ) -> TaskLifecycleResult<Task>;
+}
+
+#[async_trait]
+impl<R, C> EmailTaskIntake for TaskLifecycleService<R, C>
+where
+    R: TaskRepository + 'static,
+    C: Clock + Send + Sync + 'static,
+{
+    async fn open_task(
+        &self,
+        ctx: &RequestContext,
+        issue_ref: &IssueRef,
+        title: &str,
+        description: &str,
+    ) -> TaskLifecycleResult<Task> {
+        if let Some(existing) = self.find_by_issue_ref(ctx, issue_ref).await? {
+            return Ok(existing);
+        }
+        let request = CreateTaskFromIssueRequest::new(
+            issue_ref.provider().as_str(),
+            issue_ref.repository().as_str(),
+            issue_ref.issue_number().value(),
+            title,
+        )
+        .with_description(description);
+        self.create_from_issue(ctx, request).await
+    }
+}
+
+/// Outcome of ingesting an email.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailReceipt {
+    /// Where the email was stored.
+    pub receipt: InboundReceipt,
+    /// The task opened for the issue named in the subject, if any.
+    pub task: Option<Task>,
+}
+
+/// Outcome of draining a mailbox.
+#[derive(Debug, Default)]
+pub struct MailboxPollReport {
+    /// Emails stored, in mailbox order.
+    pub ingested: Vec<EmailReceipt>,
+    /// `Message-ID`s of emails dropped because their sender is not mapped.
+    pub unmapped: Vec<String>,
+}
+
+impl<Mapping, ConvoRepo, MessageRepo, Validator, C>
+    InboundMessageService<Mapping, ConvoRepo, MessageRepo, Validator, C>
+where
+    Mapping: InboundIdentityMapping,
+    ConvoRepo: ConversationRepository,
+    MessageRepo: MessageRepository,
+    Validator: MessageValidator,
+    C: Clock + Send + Sync,
+{
+    /// Stores an email in the conversation fed by its thread.
+    ///
+    /// The sender address is mapped like any other external author, under
+    /// the `email` source. When a task intake is attached and the subject
+    /// names an issue, the task for that issue is opened as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Self::ingest`], or
+    /// [`InboundServiceError::Task`] when the task cannot be opened.
+    pub async fn ingest_email(
+        &self,
+        correlation_id: CorrelationId,
+        email: InboundEmail,
+    ) -> InboundServiceResult<EmailReceipt> {
+        let issue = self
+            .task_intake
+            .as_ref()
+            .and_then(|intake| issue_tag(&email.subject).map(|tag| (intake, tag)));
+        let description = email.text.clone();
+        let receipt = self
+            .ingest(correlation_id, email.into_inbound_message())
+            .await?;
+        let task = match issue {
+            Some((intake, (issue_ref, title))) => Some(
+                intake
+                    .open_task(&receipt.context, &issue_ref, &title, &description)
+                    .await?,
+            ),
+            None => None,
+        };
+        Ok(EmailReceipt { receipt, task })
+    }
+
+    /// Ingests every unseen email in `mailbox`, marking each seen once it
+    /// is stored.
+    ///
+    /// Emails from unmapped senders are marked seen and reported rather
+    /// than retried. Any other failure stops the poll and leaves the
+    /// failing email unseen, so the next poll retries it. During
+    /// maintenance the mailbox is not read at all.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InboundServiceError::Mailbox`] when the mailbox cannot be
+    /// read or updated, [`InboundServiceError::MaintenanceMode`] during
+    /// maintenance, or the errors of [`Self::ingest_email`].
+    pub async fn poll_mailbox(
+        &self,
+        mailbox: &dyn Mailbox,
+    ) -> InboundServiceResult<MailboxPollReport> {
+        self.ensure_writable().await?;
+        let mut report = MailboxPollReport::default();
+        for email in mailbox.fetch_unseen().await? {
+            let message_id = email.message_id.clone();
+            match self.ingest_email(CorrelationId::new(), email).await {
+                Ok(receipt) => report.ingested.push(receipt),
+                Err(InboundServiceError::UnmappedUser {
+                    external_user_id, ..
+                }) => {
+                    tracing::warn!(
+                        message_id = %message_id,
+                        sender = %external_user_id,
+                        "dropping email from unmapped sender"
+                    );
+                    report.unmapped.push(message_id.clone());
+                }
+                Err(other) => return Err(other),
+            }
+            mailbox.mark_seen(&message_id).await?;
+        }
+        Ok(report)
+    }
+}
+
+/// Splits an issue tag such as `[github:owner/repo#42]` off a subject,
+/// returning the issue and the rest of the subject as the task title.
+fn issue_tag(subject: &str) -> Option<(IssueRef, String)> {
+    let (before, after_open) = subject.split_once('[')?;
+    let (tag, after) = after_open.split_once(']')?;
+    let (provider, reference) = tag.split_once(':')?;
+    let (repository, number) = reference.rsplit_once('#')?;
+    let issue_ref = IssueRef::from_parts(provider, repository, number.trim().parse().ok()?).ok()?;
+    let rest = format!("{} {}", before.trim(), after.trim());
+    let title = match rest.trim() {
+        "" => subject.trim(),
+        trimmed => trimmed,
+    };
+    Some((issue_ref, title.to_owned()))
+}
+
+/// Lifecycle hook emailing assistant replies to the sender of the thread
+/// that feeds the conversation.
+///
+/// Register it for [`ConversationLifecyclePoint::MessageStored`]. Replies
+/// in conversations that no email fed are ignored.
+///
+/// [`ConversationLifecyclePoint::MessageStored`]: crate::message::domain::ConversationLifecyclePoint::MessageStored
+pub struct EmailReplyHook<MessageRepo>
+where
+    MessageRepo: MessageRepository,
+{
+    messages: Arc<MessageRepo>,
+    notifier: Arc<dyn EmailNotifier>,
+}
+
+impl<MessageRepo> EmailReplyHook<MessageRepo>
+where
+    MessageRepo: MessageRepository,
+{
+    /// Creates a hook reading messages from `messages` and sending replies
+    /// through `notifier`.
+    #[must_use]
+    pub const fn new(messages: Arc<MessageRepo>, notifier: Arc<dyn EmailNotifier>) -> Self {
+        Self { messages, notifier }
+    }
+
+    /// Returns the envelope of the latest email in the conversation.
+    async fn latest_envelope(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> Result<Option<EmailEnvelope>, LifecycleHookError> {
+        let mut request = PageRequest::default().with_order(SortOrder::Descending);
+        loop {
+            let page = self
+                .messages
+                .find_by_conversation(ctx, conversation_id, request)
+                .await
+                .map_err(|err| LifecycleHookError::new(err.to_string()))?;
+            if let Some(envelope) = page
+                .items()
+                .iter()
+                .find_map(|message| EmailEnvelope::from_metadata(message.metadata()))
+            {
+                return Ok(Some(envelope));
+            }
+            let Some(cursor) = page.next_cursor() else {
+                return Ok(None);
+            };
+            request = request.with_cursor(cursor);
+        }
+    }
+}
+
+fn reply_text(message: &Message) -> Option<String> {
+    let text: Vec<&str> = message
+        .content()
+        .iter()
+        .filter_map(|part| match part {
+            ContentPart::Text(text) => Some(text.text.as_str()),
+            _ => None,
+        })
+        .collect();
+    (!text.is_empty()).then(|| text.join("\n\n"))
+}
+
+#[async_trait]
+impl<MessageRepo> ConversationLifecycleHook for EmailReplyHook<MessageRepo>
+where
+    MessageRepo: MessageRepository + 'static,
+{
+    async fn on_event(
+        &self,
+        ctx: &RequestContext,
+        event: &ConversationLifecycleEvent,
+    ) -> LifecycleHookResult {
+        let ConversationLifecycleChange::MessageStored {
+            message_id,
+            role: Role::Assistant,
+            ..
+        } = event.change
+        else {
+            return Ok(());
+        };
+        let reply = self
+            .messages
+            .find_by_id(ctx, message_id)
+            .await
+            .map_err(|err| LifecycleHookError::new(err.to_string()))?
+            .and_then(|message| reply_text(&message));
+        let Some(body) = reply else {
+            return Ok(());
+        };
+        let Some(envelope) = self.latest_envelope(ctx, event.conversation_id).await? else {
+            return Ok(());
+        };
+        self.notifier
+            .send(&OutboundEmail::reply_to(&envelope, body))
+            .await
+            .map_err(|err| LifecycleHookError::new(err.to_string()))
+    }
+}
@@ -0,0 +1,201 @@
+//! Application service ingesting messages from external chat systems.
+//!
+//! [`InboundMessageService`] resolves the external author to a principal,
+//! builds the request context the rest of the system audits against, finds
+//! or creates the conversation bound to the external channel, and appends
+//! the message as a user message recording its [`InboundOrigin`]. Email
+//! threads are ingested the same way, each thread feeding one conversation;
+//! see the [`email`] module. With a maintenance gate attached, nothing is
+//! ingested while the cluster is in maintenance.
+//!
+//! [`InboundOrigin`]: crate::message::domain::InboundOrigin
+
+mod email;
+
+pub use email::{EmailReceipt, EmailReplyHook, EmailTaskIntake, MailboxPollReport};
+
+use super::{AppendMessageRequest, ConversationService, ConversationServiceError};
+use crate::context::{CorrelationId, RequestContext, SessionId};
+use crate::maintenance::{domain::MaintenanceMode, services::MaintenanceGate};
+use crate::message::{
+    domain::{ContentPart, ConversationId, InboundMessage, Message, Role, TextPart},
+    ports::{
+        ConversationRepository, MessageRepository, MessageValidator,
+        email::EmailTransportError,
+        inbound_identity::{InboundIdentityError, InboundIdentityMapping},
+    },
+};
+use crate::task::services::TaskLifecycleError;
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Outcome of ingesting an inbound message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InboundReceipt {
+    /// Request context the message was stored under.
+    pub context: RequestContext,
+    /// Conversation the message was appended to.
+    pub conversation_id: ConversationId,
+    /// Whether the conversation was created for this message.
+    pub created_conversation: bool,
+    /// The stored message.
+    pub message: Message,
+}
+
+/// Service-level errors for inbound ingestion.
+#[derive(Debug, Error)]
+pub enum InboundServiceError {
+    /// The external author is not mapped to a principal.
+    #[error("external user {external_user_id} from {external_system} is not mapped")]
+    UnmappedUser {
+        /// External system name.
+        external_system: String,
+        /// Author identifier in the external system.
+        external_user_id: String,
+    },
+    /// Identity mapping failure.
+    #[error(transparent)]
+    Identity(#[from] InboundIdentityError),
+    /// Conversation creation or append failure.
+    #[error(transparent)]
+    Conversation(#[from] ConversationServiceError),
+    /// Task creation for an email failed.
+    #[error(transparent)]
+    Task(#[from] TaskLifecycleError),
+    /// Reading or updating a polled mailbox failed.
+    #[error(transparent)]
+    Mailbox(#[from] EmailTransportError),
+    /// The cluster is in read-only maintenance mode.
+    #[error(transparent)]
+    MaintenanceMode(#[from] MaintenanceMode),
+}
+
+/// Result type for inbound ingestion.
+pub type InboundServiceResult<T> = Result<T, InboundServiceError>;
+
+/// Inbound message ingestion service.
+#[derive(Clone)]
+pub struct InboundMessageService<Mapping, ConvoRepo, MessageRepo, Validator, C>
+where
+    Mapping: InboundIdentityMapping,
+    ConvoRepo: ConversationRepository,
+    MessageRepo: MessageRepository,
+    Validator: MessageValidator,
+    C: Clock + Send + Sync,
+{
+    mapping: Arc<Mapping>,
+    conversations: Arc<ConversationService<ConvoRepo, MessageRepo, Validator, C>>,
+    task_intake: Option<Arc<dyn EmailTaskIntake>>,
+    maintenance: Option<Arc<MaintenanceGate>>,
+}
+
+impl<Mapping, ConvoRepo, MessageRepo, Validator, C>
+    InboundMessageService<Mapping, ConvoRepo, MessageRepo, Validator, C>
+where
+    Mapping: InboundIdentityMapping,
+    ConvoRepo: ConversationRepository,
+    MessageRepo: MessageRepository,
+    Validator: MessageValidator,
+    C: Clock + Send + Sync,
+{
+    /// Creates a new inbound message service.
+    #[must_use]
+    pub const fn new(
+        mapping: Arc<Mapping>,
+        conversations: Arc<ConversationService<ConvoRepo, MessageRepo, Validator, C>>,
+    ) -> Self {
+        Self {
+            mapping,
+            conversations,
+            task_intake: None,
+            maintenance: None,
+        }
+    }
+
+    /// Opens tasks for emails whose subject names an issue.
+    #[must_use]
+    pub fn with_task_intake(mut self, task_intake: Arc<dyn EmailTaskIntake>) -> Self {
+        self.task_intake = Some(task_intake);
+        self
+    }
+
+    /// Refuses to ingest anything while `gate` reports maintenance.
+    #[must_use]
+    pub fn with_maintenance(mut self, gate: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(gate);
+        self
+    }
+
+    /// Fails when a maintenance gate is attached and reports maintenance.
+    async fn ensure_writable(&self) -> InboundServiceResult<()> {
+        if let Some(gate) = self.maintenance.as_deref() {
+            gate.ensure_writable().await?;
+        }
+        Ok(())
+    }
+
+    /// Stores an inbound message in the conversation bound to its channel.
+    ///
+    /// The message is stored under a request context for the mapped
+    /// principal, carrying `correlation_id` and a fresh session identifier.
+    /// A conversation is created and bound the first time a channel writes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InboundServiceError::UnmappedUser`] when the author has no
+    /// principal, [`InboundServiceError::MaintenanceMode`] during
+    /// maintenance, or mapping and conversation errors from the ports.
+    pub async fn ingest(
+        &self,
+        correlation_id: CorrelationId,
+        inbound: InboundMessage,
+    ) -> InboundServiceResult<InboundReceipt> {
+        self.ensure_writable().await?;
+        let InboundMessage {
+            origin,
+            text,
+            attachments,
+            extensions,
+        } = inbound;
+        let principal = self.mapping.principal_for(&origin).await?.ok_or_else(|| {
+            InboundServiceError::UnmappedUser {
+                external_system: origin.source.clone(),
+                external_user_id: origin.external_user_id.clone(),
+            }
+        })?;
+        let ctx = RequestContext::new(
+            principal.tenant_id,
+            correlation_id,
+            principal.user_id,
+            SessionId::new(),
+        );
+        let (conversation_id, created_conversation) =
+            if let Some(existing) = self.mapping.conversation_for(&ctx, &origin).await? {
+                (existing, false)
+            } else {
+                let created = self.conversations.create_conversation(&ctx).await?;
+                let bound = self
+                    .mapping
+                    .bind_conversation(&ctx, &origin, created.id())
+                    .await?;
+                (bound, bound == created.id())
+            };
+        let text_part = (!text.trim().is_empty()).then(|| ContentPart::Text(TextPart::new(text)));
+        let content = text_part
+            .into_iter()
+            .chain(attachments.into_iter().map(ContentPart::Attachment))
+            .collect();
+        let mut metadata = origin.to_metadata();
+        metadata.extensions.extend(extensions);
+        let request =
+            AppendMessageRequest::new(conversation_id, Role::User, content).with_metadata(metadata);
+        let message = self.conversations.append_message(&ctx, request).await?;
+        Ok(InboundReceipt {
+            context: ctx,
+            conversation_id,
+            created_conversation,
+            message,
+        })
+    }
+}
@@ -0,0 +1,257 @@
+//! Ingestion of transcripts recorded in other providers' wire formats.
+//!
+//! [`TranscriptIngestionService`] converts a transcript with the converter
+//! registered for its [`TranscriptFormat`], builds and validates a message
+//! for each converted entry, and appends them to a conversation in one
+//! atomic batch: either the whole transcript is stored or none of it is.
+//! Each stored message records its format and position in the transcript
+//! under [`INBOUND_TRANSCRIPT_EXTENSION_KEY`].
+//!
+//! [`INBOUND_TRANSCRIPT_EXTENSION_KEY`]:
+//!     crate::message::domain::INBOUND_TRANSCRIPT_EXTENSION_KEY
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationId, Message, MessageBuilderError, TranscriptFormat, TranscriptMessage},
+    error::{RepositoryError, ValidationError},
+    ports::{
+        ConversationRepository, ConversationRepositoryError, MessageRepository, MessageValidator,
+        TranscriptConverter, TranscriptError,
+    },
+};
+use mockable::Clock;
+use std::collections::HashMap;
+use std::iter;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Request payload for ingesting a transcript into a conversation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IngestTranscriptRequest {
+    conversation_id: ConversationId,
+    format: TranscriptFormat,
+    payload: Vec<u8>,
+}
+
+impl IngestTranscriptRequest {
+    /// Creates a request to append the transcript in `payload`, recorded in
+    /// `format`, to a conversation.
+    #[must_use]
+    pub fn new(
+        conversation_id: ConversationId,
+        format: TranscriptFormat,
+        payload: impl Into<Vec<u8>>,
+    ) -> Self {
+        Self {
+            conversation_id,
+            format,
+            payload: payload.into(),
+        }
+    }
+}
+
+/// Service-level errors for transcript ingestion.
+#[derive(Debug, Error)]
+pub enum IngestionServiceError {
+    /// No converter is registered for the transcript's format.
+    #[error("no converter is registered for {0} transcripts")]
+    UnsupportedFormat(TranscriptFormat),
+    /// The transcript could not be converted.
+    #[error(transparent)]
+    Transcript(#[from] TranscriptError),
+    /// Conversation does not exist.
+    #[error("conversation not found: {0}")]
+    ConversationNotFound(ConversationId),
+    /// Conversation is archived and rejects writes.
+    #[error("conversation is archived: {0}")]
+    ConversationArchived(ConversationId),
+    /// A converted message failed validation; nothing was stored.
+    #[error("transcript message {position} is invalid: {source}")]
+    InvalidMessage {
+        /// Position of the message in the converted transcript.
+        position: usize,
+        /// The validation failure.
+        source: ValidationError,
+    },
+    /// Conversation repository failure.
+    #[error(transparent)]
+    ConversationRepository(#[from] ConversationRepositoryError),
+    /// Message repository failure.
+    #[error(transparent)]
+    MessageRepository(#[from] RepositoryError),
+}
+
+/// Result type for transcript ingestion.
+pub type IngestionServiceResult<T> = Result<T, IngestionServiceError>;
+
+/// Appends transcripts from other providers to conversations.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+/// use corbusier::message::adapters::memory::{
+///     InMemoryConversationRepository, InMemoryMessageRepository,
+/// };
+/// use corbusier::message::adapters::transcript::OpenAiChatConverter;
+/// use corbusier::message::domain::{Conversation, Role, TranscriptFormat};
+/// use corbusier::message::ports::ConversationRepository;
+/// use corbusier::message::services::{IngestTranscriptRequest, TranscriptIngestionService};
+/// use corbusier::message::validation::service::DefaultMessageValidator;
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let conversations = InMemoryConversationRepository::new();
+/// let service = TranscriptIngestionService::new(
+///     Arc::new(conversations.clone()),
+///     Arc::new(InMemoryMessageRepository::new()),
+///     Arc::new(DefaultMessageValidator::new()),
+///     Arc::new(DefaultClock),
+/// )
+/// .with_converter(Arc::new(OpenAiChatConverter::new()));
+/// let ctx = RequestContext::new(
+///     TenantId::new(),
+///     CorrelationId::new(),
+///     UserId::new(),
+///     SessionId::new(),
+/// );
+/// let conversation = Conversation::new(&DefaultClock);
+/// conversations.store(&ctx, &conversation).await?;
+///
+/// let payload = br#"{"messages": [
+///     {"role": "user", "content": "Hi"},
+///     {"role": "assistant", "content": "Hello!"}
+/// ]}"#;
+/// let stored = service
+///     .ingest(
+///         &ctx,
+///         IngestTranscriptRequest::new(conversation.id(), TranscriptFormat::OpenAiChat, payload),
+///     )
+///     .await?;
+/// assert_eq!(stored.len(), 2);
+/// assert_eq!(stored[1].role(), Role::Assistant);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TranscriptIngestionService {
+    conversations: Arc<dyn ConversationRepository>,
+    messages: Arc<dyn MessageRepository>,
+    validator: Arc<dyn MessageValidator>,
+    clock: Arc<dyn Clock + Send + Sync>,
+    converters: HashMap<TranscriptFormat, Arc<dyn TranscriptConverter>>,
+}
+
+impl TranscriptIngestionService {
+    /// Creates a service with no converters registered.
+    #[must_use]
+    pub fn new(
+        conversations: Arc<dyn ConversationRepository>,
+        messages: Arc<dyn MessageRepository>,
+        validator: Arc<dyn MessageValidator>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            conversations,
+            messages,
+            validator,
+            clock,
+            converters: HashMap::new(),
+        }
+    }
+
+    /// Registers `converter` for its format, replacing any converter
+    /// registered for the same format.
+    #[must_use]
+    pub fn with_converter(mut self, converter: Arc<dyn TranscriptConverter>) -> Self {
+        self.converters.insert(converter.format(), converter);
+        self
+    }
+
+    /// Returns `true` when a converter is registered for `format`.
+    #[must_use]
+    pub fn supports(&self, format: TranscriptFormat) -> bool {
+        self.converters.contains_key(&format)
+    }
+
+    /// Converts a transcript and appends its messages to the conversation,
+    /// returning the stored messages in order.
+    ///
+    /// The messages take the sequence numbers following the conversation's
+    /// last message. A message appended concurrently claims one of those
+    /// numbers, in which case the batch is refused with
+    /// `RepositoryError::BatchConflicts` and can be retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IngestionServiceError::UnsupportedFormat`] when no
+    /// converter reads the format, [`IngestionServiceError::Transcript`]
+    /// when the payload cannot be converted,
+    /// [`IngestionServiceError::ConversationNotFound`] or
+    /// [`IngestionServiceError::ConversationArchived`] when the conversation
+    /// cannot take messages, [`IngestionServiceError::InvalidMessage`] when a
+    /// converted message fails validation, or repository errors.
+    pub async fn ingest(
+        &self,
+        ctx: &RequestContext,
+        request: IngestTranscriptRequest,
+    ) -> IngestionServiceResult<Vec<Message>> {
+        let IngestTranscriptRequest {
+            conversation_id,
+            format,
+            payload,
+        } = request;
+        let converter = self
+            .converters
+            .get(&format)
+            .ok_or(IngestionServiceError::UnsupportedFormat(format))?;
+        let transcript = converter.convert(&payload)?;
+        self.require_writable(ctx, conversation_id).await?;
+
+        let first = self
+            .messages
+            .next_sequence_number(ctx, conversation_id)
+            .await?;
+        let sequence_numbers = iter::successors(Some(first), |sequence| Some(sequence.next()));
+        let messages = transcript
+            .into_iter()
+            .zip(sequence_numbers)
+            .enumerate()
+            .map(|(position, (entry, sequence_number))| {
+                let TranscriptMessage { role, content } = entry;
+                Message::builder(conversation_id, role, sequence_number)
+                    .with_content_parts(content)
+                    .with_metadata(format.to_metadata(position))
+                    .build(&*self.clock)
+                    .map_err(|MessageBuilderError::EmptyContent| ValidationError::EmptyContent)
+                    .and_then(|message| self.validator.validate(&message).map(|()| message))
+                    .map_err(|source| IngestionServiceError::InvalidMessage { position, source })
+            })
+            .collect::<IngestionServiceResult<Vec<_>>>()?;
+        self.messages.store_batch(ctx, &messages).await?;
+        tracing::info!(
+            %conversation_id,
+            %format,
+            messages = messages.len(),
+            "transcript ingested"
+        );
+        Ok(messages)
+    }
+
+    async fn require_writable(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> IngestionServiceResult<()> {
+        let conversation = self
+            .conversations
+            .find_by_id(ctx, conversation_id)
+            .await?
+            .ok_or(IngestionServiceError::ConversationNotFound(conversation_id))?;
+        if conversation.is_archived() {
+            return Err(IngestionServiceError::ConversationArchived(conversation_id));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+//! Registry that runs extension hooks at conversation lifecycle points.
+//!
+//! [`ConversationLifecycleHooks`] holds the hooks an embedder has registered
+//! and the lifecycle points each one subscribes to. Services that own a
+//! lifecycle transition hand the resulting event to
+//! [`ConversationLifecycleHooks::dispatch`] once the transition is persisted.
+//!
+//! Hooks run one at a time in registration order. Each runs on its own task
+//! under a timeout, so a hook that fails, panics, or hangs is reported and
+//! logged without affecting the other hooks or the workflow that announced
+//! the event.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationLifecycleEvent, ConversationLifecyclePoint},
+    ports::{ConversationLifecycleHook, LifecycleHookError},
+};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Time a hook may run before it is abandoned, unless configured otherwise.
+pub const DEFAULT_LIFECYCLE_HOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How a hook run ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleHookOutcome {
+    /// The hook finished successfully.
+    Completed,
+    /// The hook reported a failure.
+    Failed(LifecycleHookError),
+    /// The hook overran its timeout and was cancelled.
+    TimedOut,
+    /// The hook panicked.
+    Panicked,
+}
+
+/// The outcome of running one hook for one event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleHookReport {
+    /// Name the hook was registered under.
+    pub hook_name: String,
+    /// How the run ended.
+    pub outcome: LifecycleHookOutcome,
+}
+
+#[derive(Clone)]
+struct RegisteredHook {
+    name: String,
+    points: BTreeSet<ConversationLifecyclePoint>,
+    hook: Arc<dyn ConversationLifecycleHook>,
+}
+
+/// Hooks registered against conversation lifecycle points.
+#[derive(Clone)]
+pub struct ConversationLifecycleHooks {
+    hooks: Vec<RegisteredHook>,
+    timeout: Duration,
+}
+
+impl Default for ConversationLifecycleHooks {
+    fn default() -> Self {
+        Self {
+            hooks: Vec::new(),
+            timeout: DEFAULT_LIFECYCLE_HOOK_TIMEOUT,
+        }
+    }
+}
+
+impl ConversationLifecycleHooks {
+    /// Creates an empty registry using [`DEFAULT_LIFECYCLE_HOOK_TIMEOUT`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the time each hook may run before it is cancelled.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Registers a hook for the given lifecycle points.
+    ///
+    /// Registering with no points subscribes the hook to nothing.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        points: impl IntoIterator<Item = ConversationLifecyclePoint>,
+        hook: Arc<dyn ConversationLifecycleHook>,
+    ) {
+        self.hooks.push(RegisteredHook {
+            name: name.into(),
+            points: points.into_iter().collect(),
+            hook,
+        });
+    }
+
+    /// Runs every hook subscribed to the event's lifecycle point.
+    ///
+    /// Hook failures are logged and reported, never propagated.
+    pub async fn dispatch(
+        &self,
+        ctx: &RequestContext,
+        event: &ConversationLifecycleEvent,
+    ) -> Vec<LifecycleHookReport> {
+        let point = event.point();
+        let mut reports = Vec::new();
+        for registered in self
+            .hooks
+            .iter()
+            .filter(|registered| registered.points.contains(&point))
+        {
+            let outcome = self.run_isolated(registered, ctx, event).await;
+            log_incomplete(&registered.name, event, &outcome);
+            reports.push(LifecycleHookReport {
+                hook_name: registered.name.clone(),
+                outcome,
+            });
+        }
+        reports
+    }
+
+    async fn run_isolated(
+        &self,
+        registered: &RegisteredHook,
+        ctx: &RequestContext,
+        event: &ConversationLifecycleEvent,
+    ) -> LifecycleHookOutcome {
+        let hook = Arc::clone(&registered.hook);
+        let task_ctx = ctx.clone();
+        let task_event = event.clone();
+        let mut task = tokio::spawn(async move { hook.on_event(&task_ctx, &task_event).await });
+        match tokio::time::timeout(self.timeout, &mut task).await {
+            Ok(Ok(Ok(()))) => LifecycleHookOutcome::Completed,
+            Ok(Ok(Err(err))) => LifecycleHookOutcome::Failed(err),
+            Ok(Err(_)) => LifecycleHookOutcome::Panicked,
+            Err(_) => {
+                task.abort();
+                LifecycleHookOutcome::TimedOut
+            }
+        }
+    }
+}
+
+fn log_incomplete(
+    hook_name: &str,
+    event: &ConversationLifecycleEvent,
+    outcome: &LifecycleHookOutcome,
+) {
+    if *outcome != LifecycleHookOutcome::Completed {
+        tracing::warn!(
+            hook = hook_name,
+            point = %event.point(),
+            conversation_id = %event.conversation_id,
+            outcome = ?outcome,
+            "conversation lifecycle hook did not complete"
+        );
+    }
+}
@@ -0,0 +1,268 @@
+//! Load-shedding controller for background message processing.
+//!
+//! [`LoadSheddingController`] watches interactive turn latency and
+//! processing queue depths, derives the current [`LoadLevel`] from its
+//! [`LoadSheddingPolicy`], and tells background workers whether each job may
+//! run now or should be deferred. Level changes are logged, and
+//! [`LoadSheddingController::metrics`] exposes the level, the signals behind
+//! it, and per-workload admission counters for export.
+
+use crate::message::domain::{
+    BackgroundWorkload, LoadLevel, LoadSheddingMetrics, LoadSheddingPolicy, LoadSignals,
+    ProcessingStage, ShedMode, SheddingDecision, WorkloadSheddingMetrics,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use mockable::Clock;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// How long a turn latency observation counts towards the load level,
+/// unless configured otherwise.
+pub const DEFAULT_LATENCY_WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Default, Clone, Copy)]
+struct WorkloadCounters {
+    admitted: u64,
+    deferred: u64,
+    /// Jobs still to defer before the next sampled job runs.
+    skip_remaining: u32,
+}
+
+#[derive(Debug)]
+struct ControllerState {
+    level: LoadLevel,
+    level_changes: u64,
+    latencies: VecDeque<(DateTime<Utc>, Duration)>,
+    queue_depths: BTreeMap<String, usize>,
+    workloads: BTreeMap<BackgroundWorkload, WorkloadCounters>,
+}
+
+impl Default for ControllerState {
+    fn default() -> Self {
+        Self {
+            level: LoadLevel::Normal,
+            level_changes: 0,
+            latencies: VecDeque::new(),
+            queue_depths: BTreeMap::new(),
+            workloads: BTreeMap::new(),
+        }
+    }
+}
+
+impl ControllerState {
+    fn signals(&self) -> LoadSignals {
+        LoadSignals {
+            turn_latency_p95: p95(self.latencies.iter().map(|(_, latency)| *latency)),
+            max_queue_depth: self
+                .queue_depths
+                .values()
+                .copied()
+                .max()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Returns the 95th percentile of `samples`, or `None` when there are none.
+fn p95(samples: impl Iterator<Item = Duration>) -> Option<Duration> {
+    let mut sorted: Vec<Duration> = samples.collect();
+    sorted.sort_unstable();
+    let rank = sorted.len().saturating_mul(95).div_ceil(100);
+    sorted.get(rank.saturating_sub(1)).copied()
+}
+
+/// Decides which background jobs run as load rises and falls.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::{
+///     domain::{BackgroundWorkload, LoadLevel, LoadSheddingPolicy},
+///     services::LoadSheddingController,
+/// };
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// let controller =
+///     LoadSheddingController::new(LoadSheddingPolicy::default(), Arc::new(DefaultClock));
+/// controller.record_queue_depth("embedding", 5_000);
+///
+/// assert_eq!(controller.level(), LoadLevel::Overloaded);
+/// assert!(!controller.admit(BackgroundWorkload::Analytics).is_run());
+/// ```
+pub struct LoadSheddingController<C>
+where
+    C: Clock + Send + Sync,
+{
+    policy: LoadSheddingPolicy,
+    latency_window: Duration,
+    clock: Arc<C>,
+    state: Mutex<ControllerState>,
+}
+
+impl<C> LoadSheddingController<C>
+where
+    C: Clock + Send + Sync,
+{
+    /// Creates a controller applying `policy`, starting at
+    /// [`LoadLevel::Normal`] with a [`DEFAULT_LATENCY_WINDOW`].
+    #[must_use]
+    pub fn new(policy: LoadSheddingPolicy, clock: Arc<C>) -> Self {
+        Self {
+            policy,
+            latency_window: DEFAULT_LATENCY_WINDOW,
+            clock,
+            state: Mutex::new(ControllerState::default()),
+        }
+    }
+
+    /// Sets how long a turn latency observation counts towards the load
+    /// level.
+    #[must_use]
+    pub const fn with_latency_window(mut self, window: Duration) -> Self {
+        self.latency_window = window;
+        self
+    }
+
+    /// Records how long an interactive turn took.
+    pub fn record_turn_latency(&self, latency: Duration) {
+        let mut state = self.lock();
+        state.latencies.push_back((self.clock.utc(), latency));
+        self.evaluate(&mut state);
+    }
+
+    /// Records the current depth of the named processing queue.
+    pub fn record_queue_depth(&self, queue: &str, depth: usize) {
+        let mut state = self.lock();
+        state.queue_depths.insert(queue.to_owned(), depth);
+        self.evaluate(&mut state);
+    }
+
+    /// Returns the current load level.
+    #[must_use]
+    pub fn level(&self) -> LoadLevel {
+        let mut state = self.lock();
+        self.evaluate(&mut state)
+    }
+
+    /// Decides whether a job of `workload` may run now.
+    ///
+    /// Sampled workloads run the first job of each sample and defer the
+    /// rest, so a steady stream of jobs runs at the configured ratio.
+    #[must_use]
+    pub fn admit(&self, workload: BackgroundWorkload) -> SheddingDecision {
+        let mut state = self.lock();
+        let level = self.evaluate(&mut state);
+        let mode = self.policy.mode(workload, level);
+        let counters = state.workloads.entry(workload).or_default();
+        let decision = match mode {
+            ShedMode::Run => SheddingDecision::Run,
+            ShedMode::Pause => SheddingDecision::Defer,
+            ShedMode::Sample(one_in) => {
+                if let Some(remaining) = counters.skip_remaining.checked_sub(1) {
+                    counters.skip_remaining = remaining;
+                    SheddingDecision::Defer
+                } else {
+                    counters.skip_remaining = one_in.saturating_sub(1);
+                    SheddingDecision::Run
+                }
+            }
+        };
+        match decision {
+            SheddingDecision::Run => counters.admitted = counters.admitted.saturating_add(1),
+            SheddingDecision::Defer => {
+                counters.deferred = counters.deferred.saturating_add(1);
+                tracing::debug!(
+                    workload = %workload,
+                    level = %level,
+                    "deferring background job under load"
+                );
+            }
+        }
+        decision
+    }
+
+    /// Decides whether `stage` may pick up a message now.
+    ///
+    /// Critical stages, which belong to no [`BackgroundWorkload`], always
+    /// run.
+    #[must_use]
+    pub fn admit_stage(&self, stage: ProcessingStage) -> SheddingDecision {
+        BackgroundWorkload::for_stage(stage)
+            .map_or(SheddingDecision::Run, |workload| self.admit(workload))
+    }
+
+    /// Returns the current level, signals, and admission counters.
+    #[must_use]
+    pub fn metrics(&self) -> LoadSheddingMetrics {
+        let mut state = self.lock();
+        let level = self.evaluate(&mut state);
+        let workloads = BackgroundWorkload::ALL
+            .into_iter()
+            .map(|workload| {
+                let counters = state.workloads.get(&workload).copied().unwrap_or_default();
+                WorkloadSheddingMetrics {
+                    workload,
+                    mode: self.policy.mode(workload, level),
+                    admitted: counters.admitted,
+                    deferred: counters.deferred,
+                }
+            })
+            .collect();
+        LoadSheddingMetrics {
+            level,
+            signals: state.signals(),
+            queue_depths: state.queue_depths.clone(),
+            level_changes: state.level_changes,
+            workloads,
+        }
+    }
+
+    /// The state only holds counters and observations, so a panic while
+    /// the lock was held cannot leave it inconsistent.
+    fn lock(&self) -> MutexGuard<'_, ControllerState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Drops expired latency observations and updates the load level.
+    fn evaluate(&self, state: &mut ControllerState) -> LoadLevel {
+        let cutoff = TimeDelta::from_std(self.latency_window)
+            .ok()
+            .and_then(|window| self.clock.utc().checked_sub_signed(window));
+        if let Some(expired_before) = cutoff {
+            while state
+                .latencies
+                .front()
+                .is_some_and(|(observed_at, _)| *observed_at < expired_before)
+            {
+                state.latencies.pop_front();
+            }
+        }
+        let signals = state.signals();
+        let level = self.policy.level(signals);
+        if level != state.level {
+            let turn_latency_p95_ms = signals.turn_latency_p95.map(|latency| latency.as_millis());
+            if level > state.level {
+                tracing::warn!(
+                    from = %state.level,
+                    to = %level,
+                    turn_latency_p95_ms = ?turn_latency_p95_ms,
+                    max_queue_depth = signals.max_queue_depth,
+                    "background processing load level rose"
+                );
+            } else {
+                tracing::info!(
+                    from = %state.level,
+                    to = %level,
+                    turn_latency_p95_ms = ?turn_latency_p95_ms,
+                    max_queue_depth = signals.max_queue_depth,
+                    "background processing load level fell"
+                );
+            }
+            state.level = level;
+            state.level_changes = state.level_changes.saturating_add(1);
+        }
+        level
+    }
+}
@@ -3,15 +3,93 @@
 //! Services orchestrate domain operations and coordinate between ports,
 //! implementing business workflows that span multiple aggregates.
 
+mod agent_session;
+mod compaction;
+mod context_snapshot;
 mod conversation;
+mod conversation_comparison;
+mod export;
+mod feedback;
+mod fork;
 mod handoff;
+mod inbound;
+mod ingestion;
+mod lifecycle_hooks;
+mod load_shedding;
+mod periodic_snapshot;
+mod processing;
+mod rolling_summary;
 mod slash_command;
+mod slash_command_audit;
+mod slash_command_catalog;
+mod snapshot_pruning;
+mod streaming;
+mod tool_call_pairing;
+mod transfer;
+mod turn;
 
 #[cfg(test)]
 mod conversation_tests;
 #[cfg(test)]
 mod handoff_tests;
 
+pub use agent_session::{
+    AgentSessionService, AgentSessionServiceError, AgentSessionServiceResult, ResumedSession,
+};
+pub use compaction::{
+    CompactionServiceError, CompactionServiceResult, ConversationCompactionPorts,
+    ConversationCompactionService,
+};
+pub use context_snapshot::{
+    ContextSnapshotService, ContextSnapshotServiceError, ContextSnapshotServiceResult,
+};
 pub use conversation::{AppendMessageRequest, ConversationService, ConversationServiceError};
+pub use conversation_comparison::ConversationComparisonService;
+pub use export::{ConversationExporter, ExportRequest, ExportServiceError, ExportServiceResult};
+pub use feedback::{
+    FeedbackServiceError, FeedbackServiceResult, MessageFeedbackService, SubmitFeedbackRequest,
+};
+pub use fork::ConversationForkService;
 pub use handoff::{CompleteHandoffParams, HandoffService, ServiceInitiateParams};
+pub use inbound::{
+    EmailReceipt, EmailReplyHook, EmailTaskIntake, InboundMessageService, InboundReceipt,
+    InboundServiceError, InboundServiceResult, MailboxPollReport,
+};
+pub use ingestion::{
+    IngestTranscriptRequest, IngestionServiceError, IngestionServiceResult,
+    TranscriptIngestionService,
+};
+pub use lifecycle_hooks::{
+    ConversationLifecycleHooks, DEFAULT_LIFECYCLE_HOOK_TIMEOUT, LifecycleHookOutcome,
+    LifecycleHookReport,
+};
+pub use load_shedding::{DEFAULT_LATENCY_WINDOW, LoadSheddingController};
+pub use periodic_snapshot::{
+    DEFAULT_MAX_IN_FLIGHT, MAX_REMEMBERED_MARKS, PendingSnapshot, PeriodicSnapshotError,
+    PeriodicSnapshotResult, PeriodicSnapshotScheduler, SnapshotSchedule,
+};
+pub use processing::{
+    MessageProcessingService, ProcessingServiceError, ProcessingServiceResult, ReprocessRequest,
+    StageOutcome, StageReport,
+};
+pub use rolling_summary::{
+    RollingSummaryService, RollingSummaryServiceError, RollingSummaryServiceResult,
+};
 pub use slash_command::SlashCommandService;
+pub use slash_command_audit::SlashCommandAuditTrail;
+pub use slash_command_catalog::{
+    PackConflictPolicy, PackInstallReport, SlashCommandCatalog, SlashCommandCatalogError,
+    SlashCommandCatalogResult,
+};
+pub use snapshot_pruning::SnapshotPruningService;
+pub use streaming::{
+    StreamRecoveryReport, StreamingMessageBuilder, StreamingServiceError, StreamingServiceResult,
+};
+pub use tool_call_pairing::{
+    ToolCallPairingService, ToolCallPairingServiceError, ToolCallPairingServiceResult,
+};
+pub use transfer::{
+    ConversationTransferService, ConversationTransferServiceError,
+    ConversationTransferServiceResult,
+};
+pub use turn::{TurnService, TurnServiceError, TurnServiceResult};
@@ -0,0 +1,298 @@
+//! Scheduler for periodic snapshots of long-running agent sessions.
+//!
+//! [`PeriodicSnapshotScheduler`] is told about each message stored in a
+//! conversation. When the conversation's [`SnapshotInterval`] has elapsed
+//! since the active session's last capture, it stores a
+//! [`SnapshotType::Periodic`] snapshot of the session's context window
+//! through the [`ContextSnapshotPort`].
+//!
+//! Captures run on their own tasks, so message ingestion never waits on the
+//! snapshot store. At most one capture runs per conversation, and at most
+//! [`DEFAULT_MAX_IN_FLIGHT`] (or the configured limit) run at once; messages
+//! observed beyond those limits are deferred rather than queued, and the
+//! next message observed retries.
+//!
+//! The scheduler remembers each active session's last capture so it need
+//! not read the snapshot store on every message. The memory is a cache: a
+//! conversation's entry is dropped once it has no active session or a new
+//! session takes over, and an arbitrary entry is evicted when
+//! [`MAX_REMEMBERED_MARKS`] are held, falling back to the store's latest
+//! snapshot.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use mockable::Clock;
+use thiserror::Error;
+use tokio::task::JoinHandle;
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    domain::{
+        AgentSession, AgentSessionId, ContextWindowSnapshot, ConversationId, MessageSummary,
+        SequenceRange, SnapshotInterval, SnapshotMark, SnapshotParams, SnapshotType,
+    },
+    ports::{
+        agent_session::{AgentSessionRepository, SessionError},
+        context_snapshot::{ContextSnapshotPort, SnapshotError},
+        snapshot_policy::{SnapshotPolicyError, SnapshotPolicyRepository},
+    },
+};
+
+/// How many captures may run at once, unless configured otherwise.
+pub const DEFAULT_MAX_IN_FLIGHT: usize = 8;
+
+/// How many conversations' last captures the scheduler remembers.
+pub const MAX_REMEMBERED_MARKS: usize = 10_000;
+
+/// Errors raised by a periodic snapshot capture.
+#[derive(Debug, Error)]
+pub enum PeriodicSnapshotError {
+    /// Snapshot policy store failure.
+    #[error(transparent)]
+    Policy(#[from] SnapshotPolicyError),
+    /// Session repository failure.
+    #[error(transparent)]
+    Session(#[from] SessionError),
+    /// Snapshot store failure.
+    #[error(transparent)]
+    Snapshot(#[from] SnapshotError),
+    /// The capture task panicked or was cancelled.
+    #[error("periodic snapshot task did not finish: {0}")]
+    TaskFailed(String),
+}
+
+/// Result type for periodic snapshot captures.
+pub type PeriodicSnapshotResult<T> = Result<T, PeriodicSnapshotError>;
+
+/// What the scheduler did with an observed message.
+#[derive(Debug)]
+pub enum SnapshotSchedule {
+    /// A capture task was started; it captures only if a snapshot is due.
+    Started(PendingSnapshot),
+    /// A capture was already running for the conversation, or the
+    /// scheduler was at its in-flight limit.
+    Deferred,
+}
+
+/// A capture running in the background.
+#[derive(Debug)]
+pub struct PendingSnapshot {
+    task: JoinHandle<PeriodicSnapshotResult<Option<ContextWindowSnapshot>>>,
+}
+
+impl PendingSnapshot {
+    /// Waits for the capture, returning the stored snapshot, or `None` when
+    /// no snapshot was due.
+    ///
+    /// # Errors
+    ///
+    /// Returns the capture's store errors, or
+    /// [`PeriodicSnapshotError::TaskFailed`] when the task did not finish.
+    pub async fn finished(self) -> PeriodicSnapshotResult<Option<ContextWindowSnapshot>> {
+        self.task
+            .await
+            .map_err(|err| PeriodicSnapshotError::TaskFailed(err.to_string()))?
+    }
+}
+
+type ConversationKey = (TenantId, ConversationId);
+
+#[derive(Debug, Default)]
+struct SchedulerState {
+    running: HashSet<ConversationKey>,
+    /// Last capture of each conversation's active session.
+    marks: HashMap<ConversationKey, (AgentSessionId, SnapshotMark)>,
+}
+
+impl SchedulerState {
+    /// Remembers a capture, evicting another conversation's when full.
+    fn remember(&mut self, key: ConversationKey, session_id: AgentSessionId, mark: SnapshotMark) {
+        if self.marks.len() >= MAX_REMEMBERED_MARKS && !self.marks.contains_key(&key) {
+            let evicted = self.marks.keys().next().copied();
+            if let Some(evicted) = evicted {
+                self.marks.remove(&evicted);
+            }
+        }
+        self.marks.insert(key, (session_id, mark));
+    }
+}
+
+/// Releases a conversation's capture slot when its task ends, however it
+/// ends.
+struct RunningCapture {
+    state: Arc<Mutex<SchedulerState>>,
+    key: ConversationKey,
+}
+
+impl Drop for RunningCapture {
+    fn drop(&mut self) {
+        lock(&self.state).running.remove(&self.key);
+    }
+}
+
+/// Captures periodic snapshots of the active sessions of conversations.
+#[derive(Clone)]
+pub struct PeriodicSnapshotScheduler {
+    snapshots: Arc<dyn ContextSnapshotPort>,
+    sessions: Arc<dyn AgentSessionRepository>,
+    policies: Arc<dyn SnapshotPolicyRepository>,
+    clock: Arc<dyn Clock + Send + Sync>,
+    default_interval: SnapshotInterval,
+    max_in_flight: usize,
+    state: Arc<Mutex<SchedulerState>>,
+}
+
+impl PeriodicSnapshotScheduler {
+    /// Creates a scheduler that captures only conversations with a policy.
+    #[must_use]
+    pub fn new(
+        snapshots: Arc<dyn ContextSnapshotPort>,
+        sessions: Arc<dyn AgentSessionRepository>,
+        policies: Arc<dyn SnapshotPolicyRepository>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            snapshots,
+            sessions,
+            policies,
+            clock,
+            default_interval: SnapshotInterval::new(),
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+            state: Arc::default(),
+        }
+    }
+
+    /// Applies `interval` to conversations without a policy of their own.
+    #[must_use]
+    pub const fn with_default_interval(mut self, interval: SnapshotInterval) -> Self {
+        self.default_interval = interval;
+        self
+    }
+
+    /// Limits how many captures may run at once.
+    #[must_use]
+    pub const fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = max_in_flight;
+        self
+    }
+
+    /// Notes that the conversation's latest message is at `current`, and
+    /// starts a capture task unless one is already running for the
+    /// conversation or the in-flight limit is reached.
+    ///
+    /// Returns without waiting on any store.
+    #[must_use]
+    pub fn observe(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        current: SnapshotMark,
+    ) -> SnapshotSchedule {
+        let key = (ctx.tenant_id(), conversation_id);
+        {
+            let mut state = lock(&self.state);
+            if state.running.len() >= self.max_in_flight || !state.running.insert(key) {
+                return SnapshotSchedule::Deferred;
+            }
+        }
+        let running = RunningCapture {
+            state: Arc::clone(&self.state),
+            key,
+        };
+        let scheduler = self.clone();
+        let task_ctx = ctx.clone();
+        let task = tokio::spawn(async move {
+            let _running = running;
+            let outcome = scheduler.capture(&task_ctx, conversation_id, current).await;
+            if let Err(err) = &outcome {
+                tracing::warn!(
+                    conversation_id = %conversation_id,
+                    error = %err,
+                    "periodic snapshot capture failed"
+                );
+            }
+            outcome
+        });
+        SnapshotSchedule::Started(PendingSnapshot { task })
+    }
+
+    async fn capture(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        current: SnapshotMark,
+    ) -> PeriodicSnapshotResult<Option<ContextWindowSnapshot>> {
+        let key = (ctx.tenant_id(), conversation_id);
+        let interval = self
+            .policies
+            .find_policy(ctx, conversation_id)
+            .await?
+            .map_or(self.default_interval, |policy| policy.interval);
+        if !interval.is_enabled() {
+            lock(&self.state).marks.remove(&key);
+            return Ok(None);
+        }
+        let Some(session) = self
+            .sessions
+            .find_active_for_conversation(ctx, conversation_id)
+            .await?
+        else {
+            lock(&self.state).marks.remove(&key);
+            return Ok(None);
+        };
+        let last = self.last_mark(ctx, &session).await?;
+        if !interval.is_due(last, current) {
+            return Ok(None);
+        }
+
+        let snapshot = ContextWindowSnapshot::new(
+            SnapshotParams {
+                conversation_id,
+                session_id: session.session_id,
+                sequence_range: SequenceRange::new(session.start_sequence, current.sequence),
+                message_summary: MessageSummary::default(),
+                snapshot_type: SnapshotType::Periodic,
+            },
+            self.clock.as_ref(),
+        );
+        self.snapshots.store_snapshot(ctx, &snapshot).await?;
+        lock(&self.state).remember(key, session.session_id, current);
+        Ok(Some(snapshot))
+    }
+
+    /// Returns where the session was last captured: the remembered mark,
+    /// else the conversation's latest snapshot if it belongs to the
+    /// session, else the session's start. A mark remembered for an earlier
+    /// session is forgotten.
+    async fn last_mark(
+        &self,
+        ctx: &RequestContext,
+        session: &AgentSession,
+    ) -> PeriodicSnapshotResult<SnapshotMark> {
+        let key = (ctx.tenant_id(), session.conversation_id);
+        {
+            let mut state = lock(&self.state);
+            match state.marks.get(&key).copied() {
+                Some((session_id, mark)) if session_id == session.session_id => return Ok(mark),
+                Some(_) => {
+                    state.marks.remove(&key);
+                }
+                None => {}
+            }
+        }
+        let latest = self
+            .snapshots
+            .find_latest_snapshot(ctx, session.conversation_id)
+            .await?
+            .filter(|snapshot| snapshot.session_id == session.session_id);
+        Ok(latest.map_or_else(
+            || SnapshotMark::session_start(session),
+            |snapshot| SnapshotMark::new(snapshot.sequence_range.end, snapshot.captured_at),
+        ))
+    }
+}
+
+fn lock(state: &Mutex<SchedulerState>) -> MutexGuard<'_, SchedulerState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
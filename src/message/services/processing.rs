@@ -0,0 +1,362 @@
+//! Application service for the message processing pipeline status.
+//!
+//! [`MessageProcessingService`] records each post-ingestion stage picking a
+//! message up and finishing with it, reports a message's progress across the
+//! pipeline, finds messages stuck at a stage, and sends a message back through
+//! a stage so it can recover. With a [`LoadSheddingController`] attached, it
+//! also tells stages whether to pick a message up now or defer it under load.
+
+use super::LoadSheddingController;
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        ConversationId, LoadSheddingMetrics, Message, MessageId, MessageProcessingStatus,
+        ProcessingDomainError, ProcessingStage, SheddingDecision, StageState, StageStatus,
+        StuckMessagesQuery,
+    },
+    error::RepositoryError,
+    ports::{
+        MessageRepository,
+        processing::{MessageProcessingRepository, ProcessingError},
+    },
+};
+use crate::pagination::Page;
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// How a stage finished with a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", content = "reason", rename_all = "snake_case")]
+pub enum StageOutcome {
+    /// The stage processed the message.
+    Succeeded,
+    /// The stage gave up, for the given reason.
+    Failed(String),
+}
+
+/// A stage's report that it has finished with a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageReport {
+    /// The processed message.
+    pub message_id: MessageId,
+    /// The reporting stage.
+    pub stage: ProcessingStage,
+    /// How the stage finished.
+    pub outcome: StageOutcome,
+}
+
+impl StageReport {
+    /// Reports that `stage` processed the message.
+    #[must_use]
+    pub const fn succeeded(message_id: MessageId, stage: ProcessingStage) -> Self {
+        Self {
+            message_id,
+            stage,
+            outcome: StageOutcome::Succeeded,
+        }
+    }
+
+    /// Reports that `stage` gave up on the message.
+    #[must_use]
+    pub fn failed(
+        message_id: MessageId,
+        stage: ProcessingStage,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            message_id,
+            stage,
+            outcome: StageOutcome::Failed(reason.into()),
+        }
+    }
+}
+
+/// Request to send a message back through a stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReprocessRequest {
+    /// Conversation containing the message.
+    pub conversation_id: ConversationId,
+    /// The message to reprocess.
+    pub message_id: MessageId,
+    /// The stage to run again.
+    pub stage: ProcessingStage,
+}
+
+impl ReprocessRequest {
+    /// Creates a reprocess request.
+    #[must_use]
+    pub const fn new(
+        conversation_id: ConversationId,
+        message_id: MessageId,
+        stage: ProcessingStage,
+    ) -> Self {
+        Self {
+            conversation_id,
+            message_id,
+            stage,
+        }
+    }
+}
+
+/// Service-level errors for processing status workflows.
+#[derive(Debug, Error)]
+pub enum ProcessingServiceError {
+    /// The message does not exist in the named conversation.
+    #[error("message {message_id} not found in conversation {conversation_id}")]
+    MessageNotFound {
+        /// Conversation named by the caller.
+        conversation_id: ConversationId,
+        /// Message named by the caller.
+        message_id: MessageId,
+    },
+    /// The stage has not picked the message up.
+    #[error("stage {stage} has not started on message {message_id}")]
+    StageNotStarted {
+        /// The message.
+        message_id: MessageId,
+        /// The stage.
+        stage: ProcessingStage,
+    },
+    /// The stage transition was rejected.
+    #[error(transparent)]
+    Domain(#[from] ProcessingDomainError),
+    /// Message repository failure.
+    #[error(transparent)]
+    MessageRepository(#[from] RepositoryError),
+    /// Processing status repository failure.
+    #[error(transparent)]
+    Processing(#[from] ProcessingError),
+}
+
+/// Result type for processing service operations.
+pub type ProcessingServiceResult<T> = Result<T, ProcessingServiceError>;
+
+/// Processing status application service.
+#[derive(Clone)]
+pub struct MessageProcessingService<MessageRepo, ProcessingRepo, C>
+where
+    MessageRepo: MessageRepository,
+    ProcessingRepo: MessageProcessingRepository,
+    C: Clock + Send + Sync,
+{
+    message_repository: Arc<MessageRepo>,
+    processing_repository: Arc<ProcessingRepo>,
+    clock: Arc<C>,
+    load_shedding: Option<Arc<LoadSheddingController<C>>>,
+}
+
+impl<MessageRepo, ProcessingRepo, C> MessageProcessingService<MessageRepo, ProcessingRepo, C>
+where
+    MessageRepo: MessageRepository,
+    ProcessingRepo: MessageProcessingRepository,
+    C: Clock + Send + Sync,
+{
+    /// Creates a new processing status service.
+    #[must_use]
+    pub const fn new(
+        message_repository: Arc<MessageRepo>,
+        processing_repository: Arc<ProcessingRepo>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            message_repository,
+            processing_repository,
+            clock,
+            load_shedding: None,
+        }
+    }
+
+    /// Sheds non-critical stages under load using `controller`.
+    #[must_use]
+    pub fn with_load_shedding(mut self, controller: Arc<LoadSheddingController<C>>) -> Self {
+        self.load_shedding = Some(controller);
+        self
+    }
+
+    /// Decides whether `stage` may pick up a message now.
+    ///
+    /// Every stage runs when no load-shedding controller is attached. A
+    /// stage told to defer leaves the message unstarted for a later pass.
+    #[must_use]
+    pub fn admit_stage(&self, stage: ProcessingStage) -> SheddingDecision {
+        self.load_shedding
+            .as_ref()
+            .map_or(SheddingDecision::Run, |controller| {
+                controller.admit_stage(stage)
+            })
+    }
+
+    /// Returns the load-shedding metrics, when a controller is attached.
+    #[must_use]
+    pub fn load_shedding_metrics(&self) -> Option<LoadSheddingMetrics> {
+        self.load_shedding
+            .as_ref()
+            .map(|controller| controller.metrics())
+    }
+
+    /// Records `stage` picking up `message`.
+    ///
+    /// A stage that finished with the message before starts another
+    /// attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingDomainError::StageInProgress`] when an attempt is
+    /// already in progress.
+    pub async fn begin_stage(
+        &self,
+        ctx: &RequestContext,
+        message: &Message,
+        stage: ProcessingStage,
+    ) -> ProcessingServiceResult<StageStatus> {
+        let status = match self.find_stage(ctx, message.id(), stage).await? {
+            Some(status) if status.state == StageState::InProgress => {
+                return Err(ProcessingDomainError::StageInProgress(stage).into());
+            }
+            Some(mut status) => {
+                status.retry(&*self.clock);
+                status
+            }
+            None => StageStatus::started(message, stage, &*self.clock),
+        };
+        self.processing_repository.upsert(ctx, &status).await?;
+        Ok(status)
+    }
+
+    /// Records a stage finishing with a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingServiceError::StageNotStarted`] when the stage
+    /// never picked the message up, or
+    /// [`ProcessingDomainError::StageNotInProgress`] when it already
+    /// finished.
+    pub async fn finish_stage(
+        &self,
+        ctx: &RequestContext,
+        report: StageReport,
+    ) -> ProcessingServiceResult<StageStatus> {
+        let mut status = self
+            .require_stage(ctx, report.message_id, report.stage)
+            .await?;
+        match report.outcome {
+            StageOutcome::Succeeded => status.succeed(&*self.clock)?,
+            StageOutcome::Failed(reason) => status.fail(reason, &*self.clock)?,
+        }
+        self.processing_repository.upsert(ctx, &status).await?;
+        Ok(status)
+    }
+
+    /// Returns a message's progress across the pipeline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingServiceError::MessageNotFound`] when the message
+    /// is not in the named conversation.
+    pub async fn status(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        message_id: MessageId,
+    ) -> ProcessingServiceResult<MessageProcessingStatus> {
+        self.find_message(ctx, conversation_id, message_id).await?;
+        self.load_status(ctx, message_id).await
+    }
+
+    /// Returns a page of statuses of messages stuck at a stage, least
+    /// recently updated first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingServiceError::Processing`] when the status store
+    /// fails.
+    pub async fn stuck_messages(
+        &self,
+        ctx: &RequestContext,
+        query: StuckMessagesQuery,
+    ) -> ProcessingServiceResult<Page<StageStatus>> {
+        Ok(self.processing_repository.find_stuck(ctx, &query).await?)
+    }
+
+    /// Sends a message back through a stage.
+    ///
+    /// The stage starts another attempt and the statuses of later stages
+    /// are discarded, since they run again once the stage finishes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessingServiceError::MessageNotFound`] when the message
+    /// is not in the named conversation, or
+    /// [`ProcessingServiceError::StageNotStarted`] when the stage never
+    /// picked it up.
+    pub async fn reprocess(
+        &self,
+        ctx: &RequestContext,
+        request: ReprocessRequest,
+    ) -> ProcessingServiceResult<MessageProcessingStatus> {
+        self.find_message(ctx, request.conversation_id, request.message_id)
+            .await?;
+        let mut status = self
+            .require_stage(ctx, request.message_id, request.stage)
+            .await?;
+        status.retry(&*self.clock);
+        self.processing_repository
+            .restart_from(ctx, &status)
+            .await?;
+        self.load_status(ctx, request.message_id).await
+    }
+
+    async fn load_status(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> ProcessingServiceResult<MessageProcessingStatus> {
+        let stages = self
+            .processing_repository
+            .find_for_message(ctx, message_id)
+            .await?;
+        Ok(MessageProcessingStatus::from_stages(message_id, stages))
+    }
+
+    async fn find_stage(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+        stage: ProcessingStage,
+    ) -> ProcessingServiceResult<Option<StageStatus>> {
+        let stages = self
+            .processing_repository
+            .find_for_message(ctx, message_id)
+            .await?;
+        Ok(stages.into_iter().find(|status| status.stage == stage))
+    }
+
+    async fn require_stage(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+        stage: ProcessingStage,
+    ) -> ProcessingServiceResult<StageStatus> {
+        self.find_stage(ctx, message_id, stage)
+            .await?
+            .ok_or(ProcessingServiceError::StageNotStarted { message_id, stage })
+    }
+
+    async fn find_message(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        message_id: MessageId,
+    ) -> ProcessingServiceResult<Message> {
+        self.message_repository
+            .find_by_id(ctx, message_id)
+            .await?
+            .filter(|message| message.conversation_id() == conversation_id)
+            .ok_or(ProcessingServiceError::MessageNotFound {
+                conversation_id,
+                message_id,
+            })
+    }
+}
@@ -0,0 +1,249 @@
+//! Application service that keeps rolling conversation summaries current.
+//!
+//! [`RollingSummaryService`] folds newly stored messages into each
+//! conversation's summary through [`ConversationSummariser::extend`], so a
+//! busy conversation costs one small summariser call per write rather than
+//! a full re-read. Once a summary has been extended
+//! [`DEFAULT_SUMMARY_RECOMPUTE_INTERVAL`] times (or the configured interval),
+//! the next update rebuilds it from the full conversation to correct the
+//! drift incremental updates accumulate.
+//!
+//! The service is a [`ConversationLifecycleHook`]: register it with
+//! [`super::ConversationLifecycleHooks`] for
+//! [`ConversationLifecyclePoint::MessageStored`] and summaries are updated
+//! after every stored message.
+//!
+//! With a maintenance gate attached, summaries are neither refreshed nor
+//! recomputed while the cluster is in maintenance; the first update after
+//! it ends folds in the messages missed meanwhile.
+
+use crate::context::RequestContext;
+use crate::maintenance::{domain::MaintenanceMode, services::MaintenanceGate};
+use crate::message::{
+    domain::{
+        ConversationId, ConversationLifecycleEvent, ConversationLifecyclePoint,
+        DEFAULT_SUMMARY_RECOMPUTE_INTERVAL, Message, RollingSummary,
+    },
+    error::RepositoryError,
+    ports::{
+        ConversationLifecycleHook, ConversationSummariser, LifecycleHookError, LifecycleHookResult,
+        MessageRepository, RollingSummaryError, RollingSummaryRepository, SummariserError,
+    },
+};
+use crate::pagination::collect_pages;
+use async_trait::async_trait;
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Service-level errors for rolling summary maintenance.
+#[derive(Debug, Error)]
+pub enum RollingSummaryServiceError {
+    /// Message repository failure.
+    #[error(transparent)]
+    MessageRepository(#[from] RepositoryError),
+    /// Summary repository failure.
+    #[error(transparent)]
+    Summary(#[from] RollingSummaryError),
+    /// The summariser failed.
+    #[error(transparent)]
+    Summariser(#[from] SummariserError),
+    /// The cluster is in read-only maintenance mode.
+    #[error(transparent)]
+    MaintenanceMode(#[from] MaintenanceMode),
+}
+
+/// Result type for rolling summary service operations.
+pub type RollingSummaryServiceResult<T> = Result<T, RollingSummaryServiceError>;
+
+/// Rolling summary application service.
+#[derive(Clone)]
+pub struct RollingSummaryService<MessageRepo, SummaryRepo, C>
+where
+    MessageRepo: MessageRepository,
+    SummaryRepo: RollingSummaryRepository,
+    C: Clock + Send + Sync,
+{
+    message_repository: Arc<MessageRepo>,
+    summary_repository: Arc<SummaryRepo>,
+    summariser: Arc<dyn ConversationSummariser>,
+    clock: Arc<C>,
+    recompute_interval: u32,
+    maintenance: Option<Arc<MaintenanceGate>>,
+}
+
+impl<MessageRepo, SummaryRepo, C> RollingSummaryService<MessageRepo, SummaryRepo, C>
+where
+    MessageRepo: MessageRepository,
+    SummaryRepo: RollingSummaryRepository,
+    C: Clock + Send + Sync,
+{
+    /// Creates a service using [`DEFAULT_SUMMARY_RECOMPUTE_INTERVAL`].
+    #[must_use]
+    pub fn new(
+        message_repository: Arc<MessageRepo>,
+        summary_repository: Arc<SummaryRepo>,
+        summariser: Arc<dyn ConversationSummariser>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            message_repository,
+            summary_repository,
+            summariser,
+            clock,
+            recompute_interval: DEFAULT_SUMMARY_RECOMPUTE_INTERVAL,
+            maintenance: None,
+        }
+    }
+
+    /// Sets how many incremental updates a summary accepts before it is
+    /// rebuilt from the full conversation.
+    ///
+    /// An interval of zero recomputes on every update.
+    #[must_use]
+    pub const fn with_recompute_interval(mut self, recompute_interval: u32) -> Self {
+        self.recompute_interval = recompute_interval;
+        self
+    }
+
+    /// Refuses to update summaries while `gate` reports maintenance.
+    #[must_use]
+    pub fn with_maintenance(mut self, gate: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(gate);
+        self
+    }
+
+    /// Returns the conversation's current summary, if it has one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RollingSummaryServiceError::Summary`] when the summary
+    /// store fails.
+    pub async fn summary(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RollingSummaryServiceResult<Option<RollingSummary>> {
+        Ok(self.summary_repository.find(ctx, conversation_id).await?)
+    }
+
+    /// Brings the conversation's summary up to date with its messages.
+    ///
+    /// Messages the summary does not yet cover are folded into it, unless
+    /// the summary is missing or due for recompute, in which case it is
+    /// rebuilt from every message. Returns `None` for a conversation without
+    /// messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RollingSummaryServiceError`] when messages cannot be read,
+    /// the summariser fails, the summary cannot be stored, or the cluster is
+    /// in maintenance.
+    pub async fn refresh(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RollingSummaryServiceResult<Option<RollingSummary>> {
+        self.ensure_writable().await?;
+        let current = self
+            .summary_repository
+            .find(ctx, conversation_id)
+            .await?
+            .filter(|summary| !summary.is_due_for_recompute(self.recompute_interval));
+        let messages = self.all_messages(ctx, conversation_id).await?;
+        let Some(mut summary) = current else {
+            return self.rebuild(ctx, conversation_id, &messages).await;
+        };
+        let unsummarised: Vec<Message> = messages
+            .into_iter()
+            .filter(|message| message.sequence_number() > summary.covered_through())
+            .collect();
+        let Some(covered_through) = unsummarised.last().map(Message::sequence_number) else {
+            return Ok(Some(summary));
+        };
+        let text = self
+            .summariser
+            .extend(ctx, summary.text(), &unsummarised)
+            .await?;
+        summary.extend(text, covered_through, &*self.clock);
+        self.summary_repository.store(ctx, &summary).await?;
+        Ok(Some(summary))
+    }
+
+    /// Rebuilds the conversation's summary from every message.
+    ///
+    /// Returns `None` for a conversation without messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RollingSummaryServiceError`] when messages cannot be read,
+    /// the summariser fails, the summary cannot be stored, or the cluster is
+    /// in maintenance.
+    pub async fn recompute(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RollingSummaryServiceResult<Option<RollingSummary>> {
+        self.ensure_writable().await?;
+        let messages = self.all_messages(ctx, conversation_id).await?;
+        self.rebuild(ctx, conversation_id, &messages).await
+    }
+
+    async fn ensure_writable(&self) -> RollingSummaryServiceResult<()> {
+        if let Some(gate) = self.maintenance.as_deref() {
+            gate.ensure_writable().await?;
+        }
+        Ok(())
+    }
+
+    async fn all_messages(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RollingSummaryServiceResult<Vec<Message>> {
+        Ok(collect_pages(|page| {
+            self.message_repository
+                .find_by_conversation(ctx, conversation_id, page)
+        })
+        .await?)
+    }
+
+    async fn rebuild(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        messages: &[Message],
+    ) -> RollingSummaryServiceResult<Option<RollingSummary>> {
+        let Some(covered_through) = messages.last().map(Message::sequence_number) else {
+            return Ok(None);
+        };
+        let text = self.summariser.summarise(ctx, messages).await?;
+        let summary =
+            RollingSummary::recomputed(conversation_id, text, covered_through, &*self.clock);
+        self.summary_repository.store(ctx, &summary).await?;
+        Ok(Some(summary))
+    }
+}
+
+#[async_trait]
+impl<MessageRepo, SummaryRepo, C> ConversationLifecycleHook
+    for RollingSummaryService<MessageRepo, SummaryRepo, C>
+where
+    MessageRepo: MessageRepository + 'static,
+    SummaryRepo: RollingSummaryRepository + 'static,
+    C: Clock + Send + Sync + 'static,
+{
+    async fn on_event(
+        &self,
+        ctx: &RequestContext,
+        event: &ConversationLifecycleEvent,
+    ) -> LifecycleHookResult {
+        if event.point() != ConversationLifecyclePoint::MessageStored {
+            return Ok(());
+        }
+        self.refresh(ctx, event.conversation_id)
+            .await
+            .map(|_| ())
+            .map_err(|err| LifecycleHookError::new(err.to_string()))
+    }
+}
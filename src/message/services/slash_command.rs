@@ -6,11 +6,14 @@ use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use crate::context::RequestContext;
 use crate::message::domain::{
-    PlannedToolCall, SlashCommandError, SlashCommandExecution, SlashCommandExpansion,
-    SlashCommandInvocation, ToolCallAudit, ToolCallStatus,
+    ArgumentTemplate, HELP_COMMAND, PlannedToolCall, SlashCommandDefinition, SlashCommandError,
+    SlashCommandExecution, SlashCommandExpansion, SlashCommandInvocation, ToolCallAudit,
+    ToolCallStatus, ToolCallTemplate, render_help_index, resolve_command,
 };
 use crate::message::ports::slash_command::{SlashCommandRegistry, SlashCommandRegistryError};
+use crate::message::ports::slash_command_authoriser::SlashCommandAuthoriser;
 
 /// Service that executes slash commands using a registry.
 #[derive(Clone)]
@@ -20,6 +23,7 @@ where
 {
     registry: Arc<R>,
     environment: Arc<Environment<'static>>,
+    authoriser: Option<Arc<dyn SlashCommandAuthoriser>>,
 }
 
 impl<R> SlashCommandService<R>
@@ -32,34 +36,82 @@ where
         Self {
             registry,
             environment: Arc::new(create_template_environment()),
+            authoriser: None,
         }
     }
 
+    /// Checks command permissions with `authoriser`.
+    ///
+    /// Without an authoriser, commands that require permissions are always
+    /// denied.
+    #[must_use]
+    pub fn with_authoriser(mut self, authoriser: Arc<dyn SlashCommandAuthoriser>) -> Self {
+        self.authoriser = Some(authoriser);
+        self
+    }
+
     /// Executes a raw slash-command input and returns deterministic output.
     ///
+    /// Namespaced names and aliases resolve by the precedence documented on
+    /// [`resolve_command`]. `/help` renders the command index, and
+    /// `/help <command>` renders that command's usage, without planning tool
+    /// calls.
+    ///
     /// # Errors
     ///
-    /// Returns [`SlashCommandError`] when parsing, validation, template
-    /// rendering, or registry lookup fails.
+    /// Returns [`SlashCommandError`] when parsing, resolution, validation,
+    /// template rendering, or registry lookup fails, and
+    /// [`SlashCommandError::PermissionDenied`] for commands that require
+    /// permissions, since there is no caller to authorise.
     pub fn execute(&self, raw_input: &str) -> Result<SlashCommandExecution, SlashCommandError> {
+        self.run(None, raw_input)
+    }
+
+    /// Executes a raw slash-command input on behalf of the caller in `ctx`.
+    ///
+    /// Behaves like [`execute`](Self::execute), except that the caller's
+    /// permissions are checked with the configured authoriser once the
+    /// command resolves, before parameters are validated or tool calls are
+    /// planned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandError::PermissionDenied`] listing every
+    /// required permission the caller lacks,
+    /// [`SlashCommandError::AuthorisationUnavailable`] when the authoriser
+    /// fails, or the errors of [`execute`](Self::execute).
+    pub fn execute_for(
+        &self,
+        ctx: &RequestContext,
+        raw_input: &str,
+    ) -> Result<SlashCommandExecution, SlashCommandError> {
+        self.run(Some(ctx), raw_input)
+    }
+
+    fn run(
+        &self,
+        caller: Option<&RequestContext>,
+        raw_input: &str,
+    ) -> Result<SlashCommandExecution, SlashCommandError> {
         let invocation = SlashCommandInvocation::parse(raw_input)?;
-        let definition = self
-            .registry
-            .find_by_name(invocation.command())
-            .map_err(map_registry_error)?
-            .ok_or_else(|| SlashCommandError::UnknownCommand(invocation.command().to_owned()))?;
+        if invocation.command() == HELP_COMMAND {
+            return self.help(invocation);
+        }
+        let definition = self.resolve(&invocation)?;
+        let command = definition.qualified_name();
+        self.authorise(caller, &definition)?;
 
-        let validated_parameters = definition.validate_parameters(invocation.parameters())?;
+        let validated_parameters = definition.validate_invocation(&invocation)?;
         let expanded_content = render_template(
             self.environment.as_ref(),
-            invocation.command(),
+            &command,
             &definition.expansion_template,
-            &validated_parameters,
+            build_template_context(&command, &validated_parameters),
         )?;
 
         let planned_tool_calls = Self::plan_tool_calls(
             self.environment.as_ref(),
-            invocation.command(),
+            &command,
             &definition.tool_calls,
             &validated_parameters,
         )?;
@@ -71,11 +123,7 @@ where
             })
             .collect();
 
-        let expansion = build_expansion(
-            invocation.command(),
-            &validated_parameters,
-            expanded_content,
-        );
+        let expansion = build_expansion(&command, &validated_parameters, expanded_content);
         Ok(SlashCommandExecution::new(
             invocation,
             expansion,
@@ -84,22 +132,94 @@ where
         ))
     }
 
+    fn resolve(
+        &self,
+        invocation: &SlashCommandInvocation,
+    ) -> Result<SlashCommandDefinition, SlashCommandError> {
+        if let Some(exact) = self
+            .registry
+            .find_by_name(invocation.command())
+            .map_err(map_registry_error)?
+        {
+            return Ok(exact);
+        }
+        let definitions = self.registry.list().map_err(map_registry_error)?;
+        invocation.resolve(&definitions).cloned()
+    }
+
+    fn authorise(
+        &self,
+        caller: Option<&RequestContext>,
+        definition: &SlashCommandDefinition,
+    ) -> Result<(), SlashCommandError> {
+        if definition.permissions.is_empty() {
+            return Ok(());
+        }
+        let missing = match (caller, &self.authoriser) {
+            (Some(ctx), Some(authoriser)) => authoriser
+                .missing(ctx, &definition.permissions)
+                .map_err(|source| SlashCommandError::AuthorisationUnavailable { source })?,
+            _ => definition.permissions.clone(),
+        };
+        if missing.is_empty() {
+            return Ok(());
+        }
+        Err(SlashCommandError::PermissionDenied {
+            command: definition.qualified_name(),
+            missing,
+        })
+    }
+
+    fn help(
+        &self,
+        invocation: SlashCommandInvocation,
+    ) -> Result<SlashCommandExecution, SlashCommandError> {
+        if let Some(parameter) = invocation.parameters().keys().next() {
+            return Err(SlashCommandError::UnknownParameter {
+                command: HELP_COMMAND.to_owned(),
+                parameter: parameter.clone(),
+                span: invocation.parameter_span(parameter),
+            });
+        }
+        if let Some(extra) = invocation.arguments().get(1) {
+            return Err(SlashCommandError::UnexpectedArgument {
+                command: HELP_COMMAND.to_owned(),
+                argument: extra.clone(),
+                span: invocation.argument_span(1),
+            });
+        }
+        let definitions = self.registry.list().map_err(map_registry_error)?;
+        let expansion = match invocation.arguments().first() {
+            None => SlashCommandExpansion::new(
+                format!("/{HELP_COMMAND}"),
+                render_help_index(&definitions),
+            ),
+            Some(topic) => {
+                let definition = resolve_command(&definitions, topic)?;
+                SlashCommandExpansion::new(format!("/{HELP_COMMAND}"), definition.render_help())
+                    .with_parameter("command", Value::String(definition.qualified_name()))
+            }
+        };
+        Ok(SlashCommandExecution::new(
+            invocation,
+            expansion,
+            Vec::new(),
+            Vec::new(),
+        ))
+    }
+
     fn plan_tool_calls(
         environment: &Environment<'_>,
         command: &str,
-        templates: &[crate::message::domain::ToolCallTemplate],
+        templates: &[ToolCallTemplate],
         parameters: &BTreeMap<String, Value>,
     ) -> Result<Vec<PlannedToolCall>, SlashCommandError> {
         templates
             .iter()
             .enumerate()
             .map(|(index, template)| {
-                let rendered_arguments = render_template(
-                    environment,
-                    command,
-                    &template.arguments_template,
-                    parameters,
-                )?;
+                let rendered_arguments =
+                    render_arguments(environment, command, template, parameters)?;
                 let arguments: Value =
                     serde_json::from_str(&rendered_arguments).map_err(|error| {
                         SlashCommandError::InvalidToolArgumentsTemplate {
@@ -126,13 +246,37 @@ where
     }
 }
 
+/// Renders a tool-call argument template, binding each `${...}` result as
+/// a template variable so parameter values are never parsed as template
+/// source.
+fn render_arguments(
+    environment: &Environment<'_>,
+    command: &str,
+    template: &ToolCallTemplate,
+    parameters: &BTreeMap<String, Value>,
+) -> Result<String, SlashCommandError> {
+    let parsed = ArgumentTemplate::parse(&template.arguments_template).map_err(|error| {
+        SlashCommandError::TemplateRender {
+            command: command.to_owned(),
+            reason: error.to_string(),
+        }
+    })?;
+    let (source, bindings) = parsed.bind(parameters);
+    let mut context = build_template_context(command, parameters);
+    context.extend(
+        bindings
+            .into_iter()
+            .map(|(name, encoded)| (name, Value::String(encoded))),
+    );
+    render_template(environment, command, &source, context)
+}
+
 fn render_template(
     environment: &Environment<'_>,
     command: &str,
     template: &str,
-    parameters: &BTreeMap<String, Value>,
+    context: Map<String, Value>,
 ) -> Result<String, SlashCommandError> {
-    let context = build_template_context(command, parameters);
     environment
         .render_str(template, context)
         .map_err(|error| SlashCommandError::TemplateRender {
@@ -0,0 +1,99 @@
+//! Application service for the slash-command execution audit trail.
+//!
+//! [`SlashCommandAuditTrail`] records each execution attributed to the
+//! caller and returns the record whose expansion should be attached to the
+//! resulting messages, so they link back to the audit entry.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        ConversationId, SlashCommandExecution, SlashCommandExecutionId, SlashCommandExecutionRecord,
+    },
+    ports::slash_command_execution::{
+        SlashCommandExecutionRepository, SlashCommandExecutionResult,
+    },
+};
+use crate::pagination::{Page, PageRequest};
+use mockable::Clock;
+use std::sync::Arc;
+
+/// Slash-command audit trail service.
+#[derive(Clone)]
+pub struct SlashCommandAuditTrail<R, C>
+where
+    R: SlashCommandExecutionRepository,
+    C: Clock + Send + Sync,
+{
+    repository: Arc<R>,
+    clock: Arc<C>,
+}
+
+impl<R, C> SlashCommandAuditTrail<R, C>
+where
+    R: SlashCommandExecutionRepository,
+    C: Clock + Send + Sync,
+{
+    /// Creates a new audit trail service.
+    #[must_use]
+    pub const fn new(repository: Arc<R>, clock: Arc<C>) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Records `execution` as run by the caller in `conversation_id`.
+    ///
+    /// The returned record's [`expansion`](SlashCommandExecutionRecord::expansion)
+    /// carries the record identifier; store it in the metadata of the
+    /// messages the command produces.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandExecutionError`](crate::message::ports::SlashCommandExecutionError)
+    /// if the record cannot be stored.
+    pub async fn record(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        execution: SlashCommandExecution,
+    ) -> SlashCommandExecutionResult<SlashCommandExecutionRecord> {
+        let record = SlashCommandExecutionRecord::new(
+            conversation_id,
+            ctx.user_id(),
+            execution,
+            &*self.clock,
+        );
+        self.repository.store(ctx, &record).await?;
+        Ok(record)
+    }
+
+    /// Returns the record with `id`, such as the `execution_id` found in a
+    /// message's slash-command expansion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandExecutionError`](crate::message::ports::SlashCommandExecutionError)
+    /// if the lookup fails.
+    pub async fn find(
+        &self,
+        ctx: &RequestContext,
+        id: SlashCommandExecutionId,
+    ) -> SlashCommandExecutionResult<Option<SlashCommandExecutionRecord>> {
+        self.repository.find_by_id(ctx, id).await
+    }
+
+    /// Returns a page of the commands run in a conversation, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandExecutionError`](crate::message::ports::SlashCommandExecutionError)
+    /// if the lookup fails.
+    pub async fn for_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> SlashCommandExecutionResult<Page<SlashCommandExecutionRecord>> {
+        self.repository
+            .find_by_conversation(ctx, conversation_id, page)
+            .await
+    }
+}
@@ -0,0 +1,250 @@
+//! Runtime management of persisted slash-command definitions.
+//!
+//! [`SlashCommandCatalog`] validates definitions before storing them as new
+//! versions through the [`SlashCommandDefinitionRepository`] port, restores
+//! earlier versions, installs declarative command packs, and loads the latest
+//! definitions for a
+//! [`SlashCommandRegistry`](crate::message::ports::SlashCommandRegistry).
+
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+use mockable::Clock;
+use thiserror::Error;
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        SlashCommandDefinition, SlashCommandPack, SlashCommandSchemaError, VersionedSlashCommand,
+    },
+    ports::slash_command::{SlashCommandDefinitionError, SlashCommandDefinitionRepository},
+};
+
+/// Errors returned by [`SlashCommandCatalog`].
+#[derive(Debug, Clone, Error)]
+pub enum SlashCommandCatalogError {
+    /// The definition failed schema validation.
+    #[error(transparent)]
+    InvalidDefinition(#[from] SlashCommandSchemaError),
+
+    /// The command has no such version.
+    #[error("slash command '{command}' has no version {version}")]
+    UnknownVersion {
+        /// Command name.
+        command: String,
+        /// Requested version.
+        version: NonZeroU32,
+    },
+
+    /// Installing a pack would change commands that are already registered.
+    #[error("slash-command pack conflicts with registered commands: {}", commands.join(", "))]
+    PackConflict {
+        /// Conflicting command names, in name order.
+        commands: Vec<String>,
+    },
+
+    /// The definition repository failed.
+    #[error(transparent)]
+    Repository(#[from] SlashCommandDefinitionError),
+}
+
+/// Result type for [`SlashCommandCatalog`] operations.
+pub type SlashCommandCatalogResult<T> = Result<T, SlashCommandCatalogError>;
+
+/// How installing a pack treats commands that are already registered with a
+/// different definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackConflictPolicy {
+    /// Keep the registered definition and skip the pack's.
+    KeepExisting,
+    /// Register the pack's definition as the command's next version.
+    Replace,
+    /// Install nothing and fail with
+    /// [`SlashCommandCatalogError::PackConflict`].
+    Reject,
+}
+
+/// Outcome of installing a pack.
+///
+/// Each list is in command name order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackInstallReport {
+    /// Versions registered from the pack.
+    pub registered: Vec<VersionedSlashCommand>,
+    /// Commands whose registered definition already matched the pack.
+    pub unchanged: Vec<String>,
+    /// Conflicting commands left at their registered definition.
+    pub kept: Vec<String>,
+}
+
+/// Manages versioned slash-command definitions at runtime.
+#[derive(Clone)]
+pub struct SlashCommandCatalog<R, K>
+where
+    R: SlashCommandDefinitionRepository,
+    K: Clock + Send + Sync,
+{
+    repository: Arc<R>,
+    clock: Arc<K>,
+}
+
+impl<R, K> SlashCommandCatalog<R, K>
+where
+    R: SlashCommandDefinitionRepository,
+    K: Clock + Send + Sync,
+{
+    /// Creates a catalog over `repository`.
+    pub const fn new(repository: Arc<R>, clock: Arc<K>) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Validates `definition` and stores it as the command's next version.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandCatalogError::InvalidDefinition`] when the
+    /// definition fails schema validation, or
+    /// [`SlashCommandCatalogError::Repository`] when storage fails, including
+    /// a version conflict with a concurrent registration.
+    pub async fn register(
+        &self,
+        ctx: &RequestContext,
+        definition: SlashCommandDefinition,
+    ) -> SlashCommandCatalogResult<VersionedSlashCommand> {
+        let mut normalised = definition;
+        normalised.normalise_names();
+        normalised.validate_schema()?;
+        let latest = self
+            .repository
+            .find_latest(ctx, &normalised.qualified_name())
+            .await?;
+        let next = match latest {
+            None => VersionedSlashCommand::first(normalised, self.clock.as_ref()),
+            Some(current) => current
+                .succeed(normalised, self.clock.as_ref())
+                .ok_or_else(|| SlashCommandDefinitionError::VersionConflict {
+                    command: current.command(),
+                    version: current.version,
+                })?,
+        };
+        self.repository.append(ctx, &next).await?;
+        Ok(next)
+    }
+
+    /// Registers an earlier version's definition again as the command's next
+    /// version.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandCatalogError::UnknownVersion`] when the command
+    /// has no such version, or the errors of
+    /// [`register`](Self::register).
+    pub async fn restore(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+        version: NonZeroU32,
+    ) -> SlashCommandCatalogResult<VersionedSlashCommand> {
+        let earlier = self
+            .repository
+            .find_version(ctx, command, version)
+            .await?
+            .ok_or_else(|| SlashCommandCatalogError::UnknownVersion {
+                command: command.to_ascii_lowercase(),
+                version,
+            })?;
+        self.register(ctx, earlier.definition).await
+    }
+
+    /// Installs a pack's definitions, resolving conflicts with registered
+    /// commands by `policy`.
+    ///
+    /// Commands are installed in name order. A command whose latest
+    /// registered definition equals the pack's is left unchanged, so
+    /// installing the same pack twice registers nothing the second time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandCatalogError::PackConflict`] under
+    /// [`PackConflictPolicy::Reject`] when any command conflicts, or
+    /// [`SlashCommandCatalogError::Repository`] when storage fails. A
+    /// storage failure part way through leaves the earlier commands
+    /// installed.
+    pub async fn install_pack(
+        &self,
+        ctx: &RequestContext,
+        pack: SlashCommandPack,
+        policy: PackConflictPolicy,
+    ) -> SlashCommandCatalogResult<PackInstallReport> {
+        let mut report = PackInstallReport::default();
+        let mut pending = Vec::new();
+        let mut conflicts = Vec::new();
+        for definition in pack.into_definitions() {
+            match self
+                .repository
+                .find_latest(ctx, &definition.qualified_name())
+                .await?
+            {
+                Some(latest) if latest.definition == definition => {
+                    report.unchanged.push(definition.qualified_name());
+                }
+                Some(_) => conflicts.push(definition),
+                None => pending.push(definition),
+            }
+        }
+        match policy {
+            PackConflictPolicy::Reject if !conflicts.is_empty() => {
+                return Err(SlashCommandCatalogError::PackConflict {
+                    commands: conflicts
+                        .iter()
+                        .map(SlashCommandDefinition::qualified_name)
+                        .collect(),
+                });
+            }
+            PackConflictPolicy::KeepExisting => {
+                report.kept = conflicts
+                    .iter()
+                    .map(SlashCommandDefinition::qualified_name)
+                    .collect();
+            }
+            PackConflictPolicy::Replace | PackConflictPolicy::Reject => pending.extend(conflicts),
+        }
+        pending.sort_by_key(SlashCommandDefinition::qualified_name);
+        for definition in pending {
+            report
+                .registered
+                .push(self.register(ctx, definition).await?);
+        }
+        Ok(report)
+    }
+
+    /// Removes every version of a command, returning how many were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandCatalogError::Repository`] when storage fails.
+    pub async fn remove(
+        &self,
+        ctx: &RequestContext,
+        command: &str,
+    ) -> SlashCommandCatalogResult<usize> {
+        Ok(self.repository.remove(ctx, command).await?)
+    }
+
+    /// Returns the latest definition of every registered command, ready to
+    /// seed a registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SlashCommandCatalogError::Repository`] when storage fails.
+    pub async fn definitions(
+        &self,
+        ctx: &RequestContext,
+    ) -> SlashCommandCatalogResult<Vec<SlashCommandDefinition>> {
+        let latest = self.repository.list_latest(ctx).await?;
+        Ok(latest
+            .into_iter()
+            .map(|command| command.definition)
+            .collect())
+    }
+}
@@ -0,0 +1,105 @@
+//! Application service that prunes context snapshots.
+//!
+//! [`SnapshotPruningService`] applies [`SnapshotRetention`] rules through the
+//! [`ContextSnapshotPort`]: it trims each session to its latest snapshots and
+//! expires the tenant's periodic snapshots once they reach the configured
+//! age. Either job is safe to run repeatedly.
+
+use std::sync::Arc;
+
+use mockable::Clock;
+use uuid::Uuid;
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{AgentSessionId, SnapshotRetention, SnapshotType},
+    ports::context_snapshot::{ContextSnapshotPort, SnapshotError, SnapshotResult},
+};
+use crate::pagination::collect_pages;
+
+/// Prunes context snapshots by retention rules.
+#[derive(Clone)]
+pub struct SnapshotPruningService<C, K>
+where
+    C: ContextSnapshotPort,
+    K: Clock + Send + Sync,
+{
+    snapshot_adapter: Arc<C>,
+    rules: SnapshotRetention,
+    clock: Arc<K>,
+}
+
+impl<C, K> SnapshotPruningService<C, K>
+where
+    C: ContextSnapshotPort,
+    K: Clock + Send + Sync,
+{
+    /// Creates a service applying `rules`.
+    pub const fn new(snapshot_adapter: Arc<C>, rules: SnapshotRetention, clock: Arc<K>) -> Self {
+        Self {
+            snapshot_adapter,
+            rules,
+            clock,
+        }
+    }
+
+    /// Returns the rules the service applies.
+    #[must_use]
+    pub const fn rules(&self) -> SnapshotRetention {
+        self.rules
+    }
+
+    /// Deletes the session's snapshots beyond the per-session limit,
+    /// returning their identifiers, oldest first.
+    ///
+    /// Increments built on a deleted snapshot are deleted with it, and are
+    /// only ever built on snapshots that are themselves pruned.
+    ///
+    /// # Errors
+    ///
+    /// Returns store errors.
+    pub async fn prune_session(
+        &self,
+        ctx: &RequestContext,
+        session_id: AgentSessionId,
+    ) -> SnapshotResult<Vec<Uuid>> {
+        if self.rules.keep_latest.is_none() {
+            return Ok(Vec::new());
+        }
+        let snapshots = collect_pages(|page| {
+            self.snapshot_adapter
+                .find_snapshots_for_session(ctx, session_id, page)
+        })
+        .await?;
+        let prunable = self.rules.prunable(&snapshots);
+        for snapshot_id in &prunable {
+            match self
+                .snapshot_adapter
+                .delete_snapshot(ctx, *snapshot_id)
+                .await
+            {
+                // Already deleted along with a pruned parent.
+                Ok(()) | Err(SnapshotError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(prunable)
+    }
+
+    /// Deletes the tenant's periodic snapshots older than the configured
+    /// age, returning how many were deleted.
+    ///
+    /// Periodic snapshots that a remaining snapshot extends are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns store errors.
+    pub async fn expire_periodic(&self, ctx: &RequestContext) -> SnapshotResult<usize> {
+        let Some(cutoff) = self.rules.periodic_cutoff(self.clock.utc()) else {
+            return Ok(0);
+        };
+        self.snapshot_adapter
+            .prune_before(ctx, SnapshotType::Periodic, cutoff)
+            .await
+    }
+}
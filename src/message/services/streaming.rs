@@ -0,0 +1,206 @@
+//! Application service for ingesting streamed assistant messages.
+//!
+//! [`StreamingMessageBuilder`] stages each chunk of a streamed response as it
+//! arrives, so a crash loses nothing already received, and turns the staged
+//! text into a canonical message when the stream ends. A recovery pass
+//! finalises streams whose producer stopped sending, flagging them as
+//! interrupted, and discards those that never received any text.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        ChunkAppend, ConversationId, MAX_STALLED_STREAMS, Message, MessageId, MessageMetadata,
+        PartialMessage, StreamChunk, StreamingDomainError,
+    },
+    ports::streaming::{PartialMessageRepository, StreamingError},
+};
+use chrono::Duration;
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Service-level errors for streamed message ingestion.
+#[derive(Debug, Error)]
+pub enum StreamingServiceError {
+    /// The chunk or the finished stream was rejected.
+    #[error(transparent)]
+    Domain(#[from] StreamingDomainError),
+    /// Partial message repository failure.
+    #[error(transparent)]
+    Repository(#[from] StreamingError),
+}
+
+/// Result type for streaming service operations.
+pub type StreamingServiceResult<T> = Result<T, StreamingServiceError>;
+
+/// Outcome of a recovery pass over stalled streams.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StreamRecoveryReport {
+    /// Messages stored from interrupted streams, flagged as interrupted.
+    pub finalised: Vec<Message>,
+    /// Streams removed because they never received any text.
+    pub discarded: Vec<MessageId>,
+}
+
+/// Streamed assistant message application service.
+#[derive(Clone)]
+pub struct StreamingMessageBuilder<Repo, C>
+where
+    Repo: PartialMessageRepository,
+    C: Clock + Send + Sync,
+{
+    repository: Arc<Repo>,
+    clock: Arc<C>,
+}
+
+impl<Repo, C> StreamingMessageBuilder<Repo, C>
+where
+    Repo: PartialMessageRepository,
+    C: Clock + Send + Sync,
+{
+    /// Creates a new streaming message builder.
+    #[must_use]
+    pub const fn new(repository: Arc<Repo>, clock: Arc<C>) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Starts staging a new assistant message in `conversation_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingServiceError::Repository`] if the stream cannot be
+    /// staged.
+    pub async fn start(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        metadata: MessageMetadata,
+    ) -> StreamingServiceResult<PartialMessage> {
+        let partial = PartialMessage::start(conversation_id, metadata, &*self.clock);
+        self.repository.start(ctx, &partial).await?;
+        Ok(partial)
+    }
+
+    /// Adds a chunk to a staged stream and persists it.
+    ///
+    /// A chunk the stream already holds is ignored, so producers may resend
+    /// chunks after a timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::NotFound`] when no stream is staged for the
+    /// message, [`StreamingDomainError::ChunkOutOfOrder`] when earlier
+    /// chunks are missing, or [`StreamingError::Conflict`] when another
+    /// writer added a chunk first.
+    pub async fn push(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+        chunk: &StreamChunk,
+    ) -> StreamingServiceResult<PartialMessage> {
+        let mut partial = self.require(ctx, message_id).await?;
+        let expected_chunk_count = partial.chunk_count;
+        if partial.append(chunk, &*self.clock)? == ChunkAppend::Appended {
+            self.repository
+                .update(ctx, &partial, expected_chunk_count)
+                .await?;
+        }
+        Ok(partial)
+    }
+
+    /// Stores the staged text as the next message of its conversation and
+    /// removes the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::NotFound`] when no stream is staged for the
+    /// message, [`StreamingDomainError::EmptyStream`] when it received no
+    /// text, or [`StreamingError::Message`] when the message cannot be
+    /// appended.
+    pub async fn finish(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> StreamingServiceResult<Message> {
+        let partial = self.require(ctx, message_id).await?;
+        let message = partial.finalise(&*self.clock)?;
+        Ok(self.repository.finalise(ctx, &message).await?)
+    }
+
+    /// Drops a staged stream without storing a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::NotFound`] when no stream is staged for the
+    /// message.
+    pub async fn abandon(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> StreamingServiceResult<()> {
+        Ok(self.repository.discard(ctx, message_id).await?)
+    }
+
+    /// Settles streams that have received nothing for `idle_for`.
+    ///
+    /// Streams holding text are stored as messages flagged as interrupted;
+    /// streams without text are discarded. A stream finished or abandoned by
+    /// its producer while the pass runs is skipped. At most
+    /// [`MAX_STALLED_STREAMS`] streams are settled per pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingServiceError::Repository`] if stalled streams
+    /// cannot be listed or settled.
+    pub async fn recover_stalled(
+        &self,
+        ctx: &RequestContext,
+        idle_for: Duration,
+    ) -> StreamingServiceResult<StreamRecoveryReport> {
+        let updated_before = self.clock.utc() - idle_for;
+        let stalled = self
+            .repository
+            .find_stalled(ctx, updated_before, MAX_STALLED_STREAMS)
+            .await?;
+        let mut report = StreamRecoveryReport::default();
+        for partial in stalled {
+            self.recover(ctx, &partial, &mut report).await?;
+        }
+        Ok(report)
+    }
+
+    async fn recover(
+        &self,
+        ctx: &RequestContext,
+        partial: &PartialMessage,
+        report: &mut StreamRecoveryReport,
+    ) -> StreamingServiceResult<()> {
+        let settled = if partial.content.is_empty() {
+            self.repository
+                .discard(ctx, partial.message_id)
+                .await
+                .map(|()| report.discarded.push(partial.message_id))
+        } else {
+            let message = partial.finalise_interrupted(&*self.clock)?;
+            self.repository
+                .finalise(ctx, &message)
+                .await
+                .map(|stored| report.finalised.push(stored))
+        };
+        match settled {
+            Ok(()) | Err(StreamingError::NotFound(_)) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn require(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> StreamingServiceResult<PartialMessage> {
+        self.repository
+            .find(ctx, message_id)
+            .await?
+            .ok_or_else(|| StreamingError::NotFound(message_id).into())
+    }
+}
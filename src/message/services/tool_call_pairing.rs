@@ -0,0 +1,102 @@
+//! Application service for checking tool call pairing across a
+//! conversation.
+//!
+//! [`ToolCallPairingService`] loads a conversation's full history and checks
+//! it with [`ToolCallPairing::check`]. Run it before handing a conversation
+//! to another agent or exporting it, so an agent never receives a result
+//! without its call or a call still waiting on a result.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ConversationId, Message, ToolCallPairing},
+    error::RepositoryError,
+    ports::MessageRepository,
+};
+use crate::pagination::collect_pages;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Errors returned by [`ToolCallPairingService::require_consistent`].
+#[derive(Debug, Error)]
+pub enum ToolCallPairingServiceError {
+    /// Message repository failure.
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+    /// The conversation's tool calls and results do not pair up.
+    #[error(
+        "conversation {conversation_id} has {} tool call pairing issue(s)",
+        pairing.issues().len()
+    )]
+    Inconsistent {
+        /// The conversation checked.
+        conversation_id: ConversationId,
+        /// The pairing report listing each issue.
+        pairing: ToolCallPairing,
+    },
+}
+
+/// Result type for tool call pairing service operations.
+pub type ToolCallPairingServiceResult<T> = Result<T, ToolCallPairingServiceError>;
+
+/// Tool call pairing application service.
+#[derive(Clone)]
+pub struct ToolCallPairingService<MessageRepo>
+where
+    MessageRepo: MessageRepository,
+{
+    message_repository: Arc<MessageRepo>,
+}
+
+impl<MessageRepo> ToolCallPairingService<MessageRepo>
+where
+    MessageRepo: MessageRepository,
+{
+    /// Creates a pairing service.
+    #[must_use]
+    pub const fn new(message_repository: Arc<MessageRepo>) -> Self {
+        Self { message_repository }
+    }
+
+    /// Reports how the conversation's tool calls pair with their results.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RepositoryError`] when the conversation's messages cannot
+    /// be read.
+    pub async fn check(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> Result<ToolCallPairing, RepositoryError> {
+        let messages: Vec<Message> = collect_pages(|page| {
+            self.message_repository
+                .find_by_conversation(ctx, conversation_id, page)
+        })
+        .await?;
+        Ok(ToolCallPairing::check(&messages))
+    }
+
+    /// Checks the conversation and fails unless every call is answered
+    /// exactly once and every result answers an earlier call.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolCallPairingServiceError::Inconsistent`] with the report
+    /// when any issue is found, or [`ToolCallPairingServiceError::Repository`]
+    /// when the messages cannot be read.
+    pub async fn require_consistent(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> ToolCallPairingServiceResult<ToolCallPairing> {
+        let pairing = self.check(ctx, conversation_id).await?;
+        if pairing.is_consistent() {
+            Ok(pairing)
+        } else {
+            Err(ToolCallPairingServiceError::Inconsistent {
+                conversation_id,
+                pairing,
+            })
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Administrative transfer of conversations between tenants.
+//!
+//! [`ConversationTransferService`] checks the caller's access and the target
+//! tenant, then hands the accepted [`ConversationTransfer`] to the
+//! repository, which moves the conversation and records the operator action
+//! on each side atomically.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        ConversationAccess, ConversationTransfer, ConversationTransferRefused,
+        ConversationTransferRequest,
+    },
+    ports::{ConversationTransferError, ConversationTransferRepository},
+};
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Service-level errors for conversation transfers.
+#[derive(Debug, Error)]
+pub enum ConversationTransferServiceError {
+    /// The transfer was refused before any data moved.
+    #[error(transparent)]
+    Refused(#[from] ConversationTransferRefused),
+    /// The repository could not move the conversation.
+    #[error(transparent)]
+    Repository(#[from] ConversationTransferError),
+}
+
+/// Result type for conversation transfer operations.
+pub type ConversationTransferServiceResult<T> = Result<T, ConversationTransferServiceError>;
+
+/// Moves conversations between tenants on an operator's behalf.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+/// use corbusier::message::adapters::memory::{
+///     InMemoryConversationRepository, InMemoryConversationTransferAdapter,
+/// };
+/// use corbusier::message::domain::{
+///     Conversation, ConversationAccess, ConversationTransferRequest,
+/// };
+/// use corbusier::message::ports::ConversationRepository;
+/// use corbusier::message::services::ConversationTransferService;
+/// use corbusier::operator::adapters::InMemoryOperatorActionRepository;
+/// use corbusier::operator::domain::OperatorReason;
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let conversations = InMemoryConversationRepository::new();
+/// let service = ConversationTransferService::new(
+///     Arc::new(InMemoryConversationTransferAdapter::new(
+///         conversations.clone(),
+///         Arc::new(InMemoryOperatorActionRepository::new()),
+///     )),
+///     Arc::new(DefaultClock),
+/// );
+/// let ctx = RequestContext::new(
+///     TenantId::new(),
+///     CorrelationId::new(),
+///     UserId::new(),
+///     SessionId::new(),
+/// );
+/// let conversation = Conversation::new(&DefaultClock);
+/// conversations.store(&ctx, &conversation).await?;
+///
+/// let transfer = service
+///     .transfer(
+///         &ctx,
+///         ConversationTransferRequest {
+///             conversation_id: conversation.id(),
+///             target_tenant_id: TenantId::new(),
+///             reason: OperatorReason::new("customer account merge")?,
+///         },
+///         ConversationAccess::Elevated,
+///     )
+///     .await?;
+/// assert!(conversations.find_by_id(&ctx, transfer.conversation_id).await?.is_none());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ConversationTransferService {
+    repository: Arc<dyn ConversationTransferRepository>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl ConversationTransferService {
+    /// Creates a service that moves conversations through `repository`.
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn ConversationTransferRepository>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Moves a conversation out of `ctx`'s tenant into the requested one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversationTransferServiceError::Refused`] unless `access`
+    /// is elevated and the target is another tenant, or
+    /// [`ConversationTransferServiceError::Repository`] when the
+    /// conversation cannot be moved.
+    pub async fn transfer(
+        &self,
+        ctx: &RequestContext,
+        request: ConversationTransferRequest,
+        access: ConversationAccess,
+    ) -> ConversationTransferServiceResult<ConversationTransfer> {
+        let transfer = ConversationTransfer::new(ctx, request, access, &*self.clock)?;
+        self.repository.transfer(ctx, &transfer).await?;
+        tracing::info!(
+            conversation_id = %transfer.conversation_id,
+            source_tenant_id = %transfer.source_tenant_id,
+            target_tenant_id = %transfer.target_tenant_id,
+            "conversation transferred between tenants"
+        );
+        Ok(transfer)
+    }
+}
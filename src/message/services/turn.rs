@@ -0,0 +1,212 @@
+//! Application service for the turns of agent sessions.
+//!
+//! [`TurnService`] starts a turn from the message that prompted the agent,
+//! records the agent's responses on it, and ends it as completed or failed.
+//! Starting a turn also records it on its agent session, so the session's
+//! turn list and the persisted turns stay in step.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{AgentSessionId, ConversationId, Message, MessageId, Turn, TurnId, TurnStateError},
+    error::RepositoryError,
+    ports::{
+        MessageRepository,
+        agent_session::{AgentSessionRepository, SessionError},
+        turn::{TurnError, TurnRepository},
+    },
+};
+use crate::pagination::{Page, PageRequest};
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Service-level errors for turn workflows.
+#[derive(Debug, Error)]
+pub enum TurnServiceError {
+    /// Agent session does not exist.
+    #[error("agent session not found: {0}")]
+    SessionNotFound(AgentSessionId),
+    /// Message does not exist.
+    #[error("message not found: {0}")]
+    MessageNotFound(MessageId),
+    /// Turn does not exist.
+    #[error("turn not found: {0}")]
+    TurnNotFound(TurnId),
+    /// The turn refused the change.
+    #[error(transparent)]
+    State(#[from] TurnStateError),
+    /// Turn repository failure.
+    #[error(transparent)]
+    Turns(#[from] TurnError),
+    /// Session repository failure.
+    #[error(transparent)]
+    Session(#[from] SessionError),
+    /// Message repository failure.
+    #[error(transparent)]
+    Messages(#[from] RepositoryError),
+}
+
+/// Result type for turn service operations.
+pub type TurnServiceResult<T> = Result<T, TurnServiceError>;
+
+/// Starts, extends, and ends the turns of agent sessions.
+#[derive(Clone)]
+pub struct TurnService<S, M, T, K>
+where
+    S: AgentSessionRepository,
+    M: MessageRepository,
+    T: TurnRepository,
+    K: Clock + Send + Sync,
+{
+    session_repo: Arc<S>,
+    message_repo: Arc<M>,
+    turn_repo: Arc<T>,
+    clock: Arc<K>,
+}
+
+impl<S, M, T, K> TurnService<S, M, T, K>
+where
+    S: AgentSessionRepository,
+    M: MessageRepository,
+    T: TurnRepository,
+    K: Clock + Send + Sync,
+{
+    /// Creates a new turn service.
+    pub const fn new(
+        session_repo: Arc<S>,
+        message_repo: Arc<M>,
+        turn_repo: Arc<T>,
+        clock: Arc<K>,
+    ) -> Self {
+        Self {
+            session_repo,
+            message_repo,
+            turn_repo,
+            clock,
+        }
+    }
+
+    /// Starts a turn in a session, prompted by a stored message, and
+    /// records it on the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnServiceError::SessionNotFound`] or
+    /// [`TurnServiceError::MessageNotFound`] when either does not exist,
+    /// [`TurnServiceError::State`] when the message belongs to another
+    /// conversation, or repository errors.
+    pub async fn start(
+        &self,
+        ctx: &RequestContext,
+        session_id: AgentSessionId,
+        initiating_message_id: MessageId,
+    ) -> TurnServiceResult<Turn> {
+        let mut session = self
+            .session_repo
+            .find_by_id(ctx, session_id)
+            .await?
+            .ok_or(TurnServiceError::SessionNotFound(session_id))?;
+        let message = self.find_message(ctx, initiating_message_id).await?;
+        let turn = Turn::start(&session, &message, self.clock.as_ref())?;
+        self.turn_repo.store(ctx, &turn).await?;
+
+        session.record_turn(turn.turn_id);
+        self.session_repo.update(ctx, &session).await?;
+        Ok(turn)
+    }
+
+    /// Records a stored message as a response within a turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnServiceError::TurnNotFound`] or
+    /// [`TurnServiceError::MessageNotFound`] when either does not exist,
+    /// [`TurnServiceError::State`] when the turn has ended or the message
+    /// belongs to another conversation, or repository errors.
+    pub async fn record_response(
+        &self,
+        ctx: &RequestContext,
+        turn_id: TurnId,
+        message_id: MessageId,
+    ) -> TurnServiceResult<Turn> {
+        let message = self.find_message(ctx, message_id).await?;
+        self.turn_repo
+            .record_response(ctx, turn_id, &message)
+            .await
+            .map_err(|err| match err {
+                TurnError::NotFound(_) => TurnServiceError::TurnNotFound(turn_id),
+                TurnError::State(state) => TurnServiceError::State(state),
+                other => other.into(),
+            })?;
+        self.find(ctx, turn_id).await
+    }
+
+    /// Marks a turn completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnServiceError::TurnNotFound`] when the turn does not
+    /// exist, [`TurnServiceError::State`] when it has already ended, or
+    /// repository errors.
+    pub async fn complete(&self, ctx: &RequestContext, turn_id: TurnId) -> TurnServiceResult<Turn> {
+        let mut turn = self.find(ctx, turn_id).await?;
+        turn.complete(self.clock.as_ref())?;
+        self.turn_repo.update(ctx, &turn).await?;
+        Ok(turn)
+    }
+
+    /// Marks a turn failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnServiceError::TurnNotFound`] when the turn does not
+    /// exist, [`TurnServiceError::State`] when it has already ended, or
+    /// repository errors.
+    pub async fn fail(&self, ctx: &RequestContext, turn_id: TurnId) -> TurnServiceResult<Turn> {
+        let mut turn = self.find(ctx, turn_id).await?;
+        turn.fail(self.clock.as_ref())?;
+        self.turn_repo.update(ctx, &turn).await?;
+        Ok(turn)
+    }
+
+    /// Returns a turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnServiceError::TurnNotFound`] when the turn does not
+    /// exist, or repository errors.
+    pub async fn find(&self, ctx: &RequestContext, turn_id: TurnId) -> TurnServiceResult<Turn> {
+        self.turn_repo
+            .find_by_id(ctx, turn_id)
+            .await?
+            .ok_or(TurnServiceError::TurnNotFound(turn_id))
+    }
+
+    /// Returns a page of a conversation's turns, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns repository errors.
+    pub async fn turns(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> TurnServiceResult<Page<Turn>> {
+        Ok(self
+            .turn_repo
+            .find_by_conversation(ctx, conversation_id, page)
+            .await?)
+    }
+
+    async fn find_message(
+        &self,
+        ctx: &RequestContext,
+        message_id: MessageId,
+    ) -> TurnServiceResult<Message> {
+        self.message_repo
+            .find_by_id(ctx, message_id)
+            .await?
+            .ok_or(TurnServiceError::MessageNotFound(message_id))
+    }
+}
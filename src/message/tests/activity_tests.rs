@@ -0,0 +1,209 @@
+//! Unit tests for conversation activity statistics aggregation.
+
+use super::adapters_test_support::{clock, ctx};
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{
+        InMemoryConversationActivityAdapter, InMemoryHandoffAdapter, InMemoryMessageRepository,
+    },
+    domain::{
+        ActivityBucketWidth, ActivityQuery, AgentSession, AgentSessionId, ContentPart,
+        ConversationActivity, ConversationId, HandoffMetadata, HandoffParams, Message, MessageId,
+        MessageMetadata, Role, SequenceNumber, TextPart, ToolCallPart, ToolResultPart, TurnId,
+    },
+    ports::{
+        activity::{ActivityError, ConversationActivityPort},
+        handoff::{AgentHandoffPort, InitiateHandoffParams},
+        repository::MessageRepository,
+    },
+};
+use chrono::{DateTime, TimeZone, Utc};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+
+fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 4, 2, hour, minute, 0)
+        .single()
+        .expect("valid timestamp")
+}
+
+/// Builds a message with a fixed timestamp; sequence numbers are irrelevant to
+/// aggregation, so every message uses the first one.
+fn persisted(
+    conversation_id: ConversationId,
+    role: Role,
+    content: Vec<ContentPart>,
+    created_at: DateTime<Utc>,
+) -> Message {
+    Message::from_persisted(
+        MessageId::new(),
+        conversation_id,
+        role,
+        content,
+        MessageMetadata::empty(),
+        created_at,
+        SequenceNumber::new(1),
+    )
+    .expect("valid message")
+}
+
+fn text(value: &str) -> Vec<ContentPart> {
+    vec![ContentPart::Text(TextPart::new(value))]
+}
+
+fn handoff_at(initiated_at: DateTime<Utc>, clock: &DefaultClock) -> HandoffMetadata {
+    let params = HandoffParams::new(AgentSessionId::new(), TurnId::new(), "agent-a", "agent-b");
+    let mut handoff = HandoffMetadata::new(params, clock);
+    handoff.initiated_at = initiated_at;
+    handoff
+}
+
+fn day_query(conversation_id: ConversationId, width: ActivityBucketWidth) -> ActivityQuery {
+    ActivityQuery::new(conversation_id, at(0, 0), at(23, 59), width)
+}
+
+#[rstest]
+#[case::hour(ActivityBucketWidth::Hour, at(13, 0))]
+#[case::day(ActivityBucketWidth::Day, at(0, 0))]
+fn bucket_start_truncates_to_width(
+    #[case] width: ActivityBucketWidth,
+    #[case] expected: DateTime<Utc>,
+) {
+    assert_eq!(width.bucket_start(at(13, 47)), expected);
+}
+
+#[rstest]
+fn aggregate_counts_roles_tool_calls_and_errors_per_bucket() {
+    let conversation_id = ConversationId::new();
+    let messages = vec![
+        persisted(conversation_id, Role::User, text("hi"), at(9, 5)),
+        persisted(
+            conversation_id,
+            Role::Assistant,
+            vec![
+                ContentPart::ToolCall(ToolCallPart::new("c1", "read", json!({}))),
+                ContentPart::ToolCall(ToolCallPart::new("c2", "grep", json!({}))),
+            ],
+            at(9, 10),
+        ),
+        persisted(
+            conversation_id,
+            Role::Tool,
+            vec![
+                ContentPart::ToolResult(ToolResultPart::success("c1", json!("ok"))),
+                ContentPart::ToolResult(ToolResultPart::failure("c2", "boom")),
+            ],
+            at(9, 11),
+        ),
+        persisted(conversation_id, Role::System, text("note"), at(11, 0)),
+    ];
+
+    let activity = ConversationActivity::aggregate(
+        &day_query(conversation_id, ActivityBucketWidth::Hour),
+        &messages,
+        &[],
+    );
+
+    assert_eq!(activity.buckets.len(), 2);
+    let first = activity.buckets.first().expect("first bucket");
+    assert_eq!(first.bucket_start, at(9, 0));
+    assert_eq!(first.message_summary.user_count, 1);
+    assert_eq!(first.message_summary.assistant_count, 1);
+    assert_eq!(first.message_summary.tool_count, 1);
+    assert_eq!(first.tool_calls, 2);
+    assert_eq!(first.tool_errors, 1);
+    let last = activity.buckets.last().expect("last bucket");
+    assert_eq!(last.bucket_start, at(11, 0));
+    assert_eq!(last.message_summary.system_count, 1);
+    assert_eq!(activity.totals().message_summary.total(), 4);
+}
+
+#[rstest]
+fn aggregate_includes_handoff_only_buckets_and_ignores_out_of_window(clock: DefaultClock) {
+    let conversation_id = ConversationId::new();
+    let query = ActivityQuery::new(
+        conversation_id,
+        at(8, 0),
+        at(12, 0),
+        ActivityBucketWidth::Hour,
+    );
+    let messages = vec![
+        persisted(conversation_id, Role::User, text("early"), at(7, 59)),
+        persisted(conversation_id, Role::User, text("late"), at(12, 0)),
+    ];
+    let handoffs = vec![handoff_at(at(10, 30), &clock), handoff_at(at(6, 0), &clock)];
+
+    let activity = ConversationActivity::aggregate(&query, &messages, &handoffs);
+
+    assert_eq!(activity.buckets.len(), 1);
+    let bucket = activity.buckets.first().expect("handoff bucket");
+    assert_eq!(bucket.bucket_start, at(10, 0));
+    assert_eq!(bucket.handoffs, 1);
+    assert!(bucket.message_summary.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn in_memory_adapter_rejects_inverted_window(ctx: RequestContext, clock: DefaultClock) {
+    let adapter = InMemoryConversationActivityAdapter::new(
+        InMemoryMessageRepository::new(),
+        InMemoryHandoffAdapter::new(clock),
+    );
+    let query = ActivityQuery::new(
+        ConversationId::new(),
+        at(12, 0),
+        at(12, 0),
+        ActivityBucketWidth::Hour,
+    );
+
+    let result = adapter.conversation_activity(&ctx, &query).await;
+
+    assert!(matches!(result, Err(ActivityError::InvalidWindow { .. })));
+}
+
+#[rstest]
+#[tokio::test]
+async fn in_memory_adapter_reads_shared_message_and_handoff_state(
+    ctx: RequestContext,
+    clock: DefaultClock,
+) {
+    let messages = InMemoryMessageRepository::new();
+    let handoffs = InMemoryHandoffAdapter::new(DefaultClock);
+    let adapter = InMemoryConversationActivityAdapter::new(messages.clone(), handoffs.clone());
+    let conversation_id = ConversationId::new();
+
+    let message = Message::new(
+        conversation_id,
+        Role::User,
+        text("hello"),
+        SequenceNumber::new(1),
+        &clock,
+    )
+    .expect("valid message");
+    messages.store(&ctx, &message).await.expect("store message");
+    let source_session =
+        AgentSession::new(conversation_id, "agent-a", SequenceNumber::new(1), &clock);
+    let params =
+        InitiateHandoffParams::new(conversation_id, &source_session, "agent-b", TurnId::new());
+    handoffs
+        .initiate_handoff(&ctx, params)
+        .await
+        .expect("initiate handoff");
+
+    let now = message.created_at();
+    let query = ActivityQuery::new(
+        conversation_id,
+        now - chrono::TimeDelta::days(1),
+        now + chrono::TimeDelta::days(1),
+        ActivityBucketWidth::Day,
+    );
+    let activity = adapter
+        .conversation_activity(&ctx, &query)
+        .await
+        .expect("activity");
+
+    let totals = activity.totals();
+    assert_eq!(totals.message_summary.user_count, 1);
+    assert_eq!(totals.handoffs, 1);
+}
@@ -7,6 +7,7 @@ use crate::message::{
     domain::{ConversationId, MessageId},
     ports::repository::MessageRepository,
 };
+use crate::pagination::{Limit, PageRequest, SortOrder};
 use mockable::DefaultClock;
 use rstest::rstest;
 
@@ -59,7 +60,7 @@ async fn find_by_conversation_returns_messages_in_order(
     repo.store(&ctx, &message2).await.expect("store 2");
 
     let messages = repo
-        .find_by_conversation(&ctx, conversation_id)
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
         .await
         .expect("find_by_conversation");
 
@@ -91,11 +92,11 @@ async fn find_by_conversation_filters_by_conversation(
     repo.store(&ctx, &msg_b1).await.expect("store b1");
 
     let messages_a = repo
-        .find_by_conversation(&ctx, conversation_a)
+        .find_by_conversation(&ctx, conversation_a, PageRequest::default())
         .await
         .expect("find conversation a");
     let messages_b = repo
-        .find_by_conversation(&ctx, conversation_b)
+        .find_by_conversation(&ctx, conversation_b, PageRequest::default())
         .await
         .expect("find conversation b");
 
@@ -104,6 +105,39 @@ async fn find_by_conversation_filters_by_conversation(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn find_by_conversation_pages_newest_first(
+    repo: InMemoryMessageRepository,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let conversation_id = ConversationId::new();
+    for sequence in 1..=3 {
+        repo.store(&ctx, &make_message(conversation_id, sequence, &clock)?)
+            .await?;
+    }
+    let request = PageRequest::new(Limit::new(2)?).with_order(SortOrder::Descending);
+
+    let first = repo
+        .find_by_conversation(&ctx, conversation_id, request)
+        .await?;
+    let cursor = first.next_cursor().ok_or("a second page should follow")?;
+    let second = repo
+        .find_by_conversation(&ctx, conversation_id, request.with_cursor(cursor))
+        .await?;
+
+    let sequences = |page: &[crate::message::domain::Message]| {
+        page.iter()
+            .map(|message| message.sequence_number().value())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(sequences(&first), vec![3, 2]);
+    assert_eq!(sequences(&second), vec![1]);
+    assert!(second.is_last());
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn find_by_conversation_returns_empty_for_unknown_conversation(
@@ -111,7 +145,7 @@ async fn find_by_conversation_returns_empty_for_unknown_conversation(
     ctx: RequestContext,
 ) {
     let messages = repo
-        .find_by_conversation(&ctx, ConversationId::new())
+        .find_by_conversation(&ctx, ConversationId::new(), PageRequest::default())
         .await
         .expect("find_by_conversation");
 
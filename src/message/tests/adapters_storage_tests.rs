@@ -105,3 +105,26 @@ async fn store_allows_different_message_ids(
     assert_eq!(repo.len(), 2);
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn append_allocates_the_next_sequence_number(
+    repo: InMemoryMessageRepository,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) -> Result<(), crate::message::domain::MessageBuilderError> {
+    let conversation_id = ConversationId::new();
+    repo.store(&ctx, &make_message(conversation_id, 1, &clock)?)
+        .await
+        .expect("first store");
+    let message = make_message(conversation_id, 1, &clock)?;
+
+    let appended = repo.append(&ctx, &message).await.expect("append");
+    let duplicate = repo.append(&ctx, &message).await;
+
+    assert_eq!(appended.id(), message.id());
+    assert_eq!(appended.sequence_number().value(), 2);
+    assert!(matches!(duplicate, Err(RepositoryError::DuplicateMessage(id)) if id == message.id()));
+    assert_eq!(repo.len(), 2);
+    Ok(())
+}
@@ -0,0 +1,283 @@
+//! Unit tests for conversation archival and write protection.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{
+        InMemoryAgentSessionRepository, InMemoryConversationRepository, InMemoryHandoffAdapter,
+        InMemoryMessageRepository,
+    },
+    domain::{
+        AgentSession, ContentPart, Conversation, ConversationAccess, ConversationId,
+        ConversationLifecycleError, ConversationState, Message, Role, SequenceNumber, TextPart,
+        TurnId,
+    },
+    error::RepositoryError,
+    ports::{
+        AgentHandoffPort, AgentSessionRepository, ConversationRepository, MessageRepository,
+        agent_session::SessionError,
+        handoff::{HandoffError, InitiateHandoffParams},
+    },
+    services::{AppendMessageRequest, ConversationService, ConversationServiceError},
+    validation::service::DefaultMessageValidator,
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+type TestService = ConversationService<
+    InMemoryConversationRepository,
+    InMemoryMessageRepository,
+    DefaultMessageValidator,
+    DefaultClock,
+>;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+fn service(conversations: &InMemoryConversationRepository) -> TestService {
+    ConversationService::new(
+        Arc::new(conversations.clone()),
+        Arc::new(InMemoryMessageRepository::new().with_conversations(conversations.clone())),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+}
+
+fn text_request(conversation: &Conversation) -> AppendMessageRequest {
+    AppendMessageRequest::new(
+        conversation.id(),
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("hello"))],
+    )
+}
+
+async fn archived_conversation(
+    ctx: &RequestContext,
+    conversations: &InMemoryConversationRepository,
+) -> Conversation {
+    let mut conversation = Conversation::new(&DefaultClock);
+    conversations
+        .store(ctx, &conversation)
+        .await
+        .expect("store conversation");
+    conversation.archive(&DefaultClock).expect("archive");
+    conversations
+        .update(ctx, &conversation)
+        .await
+        .expect("update conversation");
+    conversation
+}
+
+#[rstest]
+fn archiving_twice_is_rejected() {
+    let mut conversation = Conversation::new(&DefaultClock);
+
+    conversation.archive(&DefaultClock).expect("first archive");
+
+    assert_eq!(conversation.state(), ConversationState::Archived);
+    assert_eq!(
+        conversation.archive(&DefaultClock),
+        Err(ConversationLifecycleError::AlreadyArchived(
+            conversation.id()
+        ))
+    );
+}
+
+#[rstest]
+#[case(ConversationAccess::Standard, true)]
+#[case(ConversationAccess::Elevated, false)]
+fn unarchiving_requires_elevated_access(#[case] access: ConversationAccess, #[case] denied: bool) {
+    let mut conversation = Conversation::new(&DefaultClock);
+    conversation.archive(&DefaultClock).expect("archive");
+
+    let result = conversation.unarchive(access, &DefaultClock);
+
+    if denied {
+        assert_eq!(
+            result,
+            Err(ConversationLifecycleError::ElevatedAccessRequired(
+                conversation.id()
+            ))
+        );
+        assert!(conversation.is_archived());
+    } else {
+        assert_eq!(result, Ok(()));
+        assert_eq!(conversation.state(), ConversationState::Active);
+    }
+}
+
+#[rstest]
+fn unarchiving_an_active_conversation_is_rejected() {
+    let mut conversation = Conversation::new(&DefaultClock);
+
+    assert_eq!(
+        conversation.unarchive(ConversationAccess::Elevated, &DefaultClock),
+        Err(ConversationLifecycleError::NotArchived(conversation.id()))
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn service_refuses_appends_until_unarchived(ctx: RequestContext) {
+    let conversations = InMemoryConversationRepository::new();
+    let service = service(&conversations);
+    let conversation = service.create_conversation(&ctx).await.expect("create");
+
+    let archived = service
+        .archive_conversation(&ctx, conversation.id())
+        .await
+        .expect("archive");
+    let refused = service
+        .append_message(&ctx, text_request(&conversation))
+        .await;
+    let denied = service
+        .unarchive_conversation(&ctx, conversation.id(), ConversationAccess::Standard)
+        .await;
+    service
+        .unarchive_conversation(&ctx, conversation.id(), ConversationAccess::Elevated)
+        .await
+        .expect("elevated unarchive");
+    let appended = service
+        .append_message(&ctx, text_request(&conversation))
+        .await;
+
+    assert!(archived.is_archived());
+    assert!(matches!(
+        refused,
+        Err(ConversationServiceError::ConversationArchived(id)) if id == conversation.id()
+    ));
+    assert!(matches!(
+        denied,
+        Err(ConversationServiceError::Lifecycle(
+            ConversationLifecycleError::ElevatedAccessRequired(_)
+        ))
+    ));
+    assert!(appended.is_ok());
+}
+
+#[rstest]
+#[tokio::test]
+async fn message_repository_rejects_archived_conversations(ctx: RequestContext) {
+    let conversations = InMemoryConversationRepository::new();
+    let conversation = archived_conversation(&ctx, &conversations).await;
+    let messages = InMemoryMessageRepository::new().with_conversations(conversations);
+    let message = Message::new(
+        conversation.id(),
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("late"))],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )
+    .expect("valid message");
+
+    let result = messages.store(&ctx, &message).await;
+
+    assert!(matches!(
+        result,
+        Err(RepositoryError::ConversationArchived(id)) if id == conversation.id()
+    ));
+    assert!(messages.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn message_batches_touching_archived_conversations_are_rejected(ctx: RequestContext) {
+    let conversations = InMemoryConversationRepository::new();
+    let conversation = archived_conversation(&ctx, &conversations).await;
+    let messages = InMemoryMessageRepository::new().with_conversations(conversations);
+    let batch: Vec<Message> = [ConversationId::new(), conversation.id()]
+        .into_iter()
+        .map(|conversation_id| {
+            Message::new(
+                conversation_id,
+                Role::User,
+                vec![ContentPart::Text(TextPart::new("late"))],
+                SequenceNumber::new(1),
+                &DefaultClock,
+            )
+            .expect("valid message")
+        })
+        .collect();
+
+    let result = messages.store_batch(&ctx, &batch).await;
+
+    assert!(matches!(
+        result,
+        Err(RepositoryError::ConversationArchived(id)) if id == conversation.id()
+    ));
+    assert!(messages.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn session_repository_rejects_archived_conversations(ctx: RequestContext) {
+    let conversations = InMemoryConversationRepository::new();
+    let conversation = archived_conversation(&ctx, &conversations).await;
+    let sessions = InMemoryAgentSessionRepository::new().with_conversations(conversations);
+    let session = AgentSession::new(
+        conversation.id(),
+        "codex_cli",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+
+    let result = sessions.store(&ctx, &session).await;
+
+    assert!(matches!(
+        result,
+        Err(SessionError::ConversationArchived(id)) if id == conversation.id()
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn handoffs_are_rejected_once_archived(ctx: RequestContext) {
+    let conversations = InMemoryConversationRepository::new();
+    let mut conversation = Conversation::new(&DefaultClock);
+    conversations
+        .store(&ctx, &conversation)
+        .await
+        .expect("store conversation");
+    let handoffs =
+        InMemoryHandoffAdapter::new(DefaultClock).with_conversations(conversations.clone());
+    let source = AgentSession::new(
+        conversation.id(),
+        "codex_cli",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+    let pending = handoffs
+        .initiate_handoff(
+            &ctx,
+            InitiateHandoffParams::new(conversation.id(), &source, "claude_code", TurnId::new()),
+        )
+        .await
+        .expect("initiate while active");
+
+    conversation.archive(&DefaultClock).expect("archive");
+    conversations
+        .update(&ctx, &conversation)
+        .await
+        .expect("update conversation");
+    let initiated = handoffs
+        .initiate_handoff(
+            &ctx,
+            InitiateHandoffParams::new(conversation.id(), &source, "claude_code", TurnId::new()),
+        )
+        .await;
+    let completed = handoffs
+        .complete_handoff(&ctx, pending.handoff_id, source.session_id)
+        .await;
+
+    assert!(matches!(
+        initiated,
+        Err(HandoffError::ConversationArchived(id)) if id == conversation.id()
+    ));
+    assert!(matches!(
+        completed,
+        Err(HandoffError::ConversationArchived(id)) if id == conversation.id()
+    ));
+}
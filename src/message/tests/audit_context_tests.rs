@@ -208,3 +208,30 @@ fn audit_context_from_request_context_maps_fields(#[case] causation_id: Option<C
 
     assert_eq!(AuditContext::from(&ctx), expected);
 }
+
+// ============================================================================
+// from_current() tests
+// ============================================================================
+
+#[tokio::test]
+async fn audit_context_from_current_is_empty_outside_a_scope() {
+    assert!(AuditContext::from_current().is_empty());
+}
+
+#[tokio::test]
+async fn audit_context_from_current_reads_the_scoped_request() {
+    let request = RequestContext::new(
+        TenantId::new(),
+        CorrelationId::new(),
+        UserId::new(),
+        SessionId::new(),
+    )
+    .with_causation_id(CausationId::new());
+
+    let audit = request
+        .clone()
+        .scope(async { AuditContext::from_current() })
+        .await;
+
+    assert_eq!(audit, AuditContext::from(&request));
+}
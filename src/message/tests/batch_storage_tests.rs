@@ -0,0 +1,88 @@
+//! Unit tests for atomic batch message storage.
+
+use super::adapters_test_support::{clock, ctx, make_message, repo};
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::InMemoryMessageRepository,
+    domain::{ConversationId, MessageBuilderError, SequenceNumber},
+    error::{BatchConflict, RepositoryError},
+    ports::repository::MessageRepository,
+};
+use crate::pagination::PageRequest;
+use mockable::DefaultClock;
+use rstest::rstest;
+
+#[rstest]
+#[tokio::test]
+async fn store_batch_stores_every_message_in_order(
+    repo: InMemoryMessageRepository,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) -> Result<(), MessageBuilderError> {
+    let conversation_id = ConversationId::new();
+    let batch = vec![
+        make_message(conversation_id, 1, &clock)?,
+        make_message(conversation_id, 2, &clock)?,
+        make_message(conversation_id, 3, &clock)?,
+    ];
+
+    repo.store_batch(&ctx, &batch).await.expect("batch stored");
+    repo.store_batch(&ctx, &[])
+        .await
+        .expect("empty batch stored");
+
+    let stored = repo
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
+        .await
+        .expect("history");
+    assert_eq!(stored.items(), batch.as_slice());
+    assert_eq!(
+        repo.next_sequence_number(&ctx, conversation_id)
+            .await
+            .expect("next sequence"),
+        SequenceNumber::new(4)
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn store_batch_reports_every_duplicate_and_stores_nothing(
+    repo: InMemoryMessageRepository,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) -> Result<(), MessageBuilderError> {
+    let conversation_id = ConversationId::new();
+    let stored = make_message(conversation_id, 1, &clock)?;
+    repo.store(&ctx, &stored).await.expect("first store");
+    let fresh = make_message(conversation_id, 2, &clock)?;
+    let repeated_sequence = make_message(conversation_id, 2, &clock)?;
+    let batch = vec![fresh, stored.clone(), repeated_sequence.clone()];
+
+    let result = repo.store_batch(&ctx, &batch).await;
+
+    let Err(RepositoryError::BatchConflicts(conflicts)) = result else {
+        panic!("expected batch conflicts, got {result:?}");
+    };
+    assert_eq!(
+        conflicts,
+        vec![
+            BatchConflict::DuplicateMessage {
+                index: 1,
+                message_id: stored.id(),
+            },
+            BatchConflict::DuplicateSequence {
+                index: 1,
+                conversation_id,
+                sequence: SequenceNumber::new(1),
+            },
+            BatchConflict::DuplicateSequence {
+                index: 2,
+                conversation_id,
+                sequence: SequenceNumber::new(2),
+            },
+        ]
+    );
+    assert_eq!(repo.len(), 1);
+    Ok(())
+}
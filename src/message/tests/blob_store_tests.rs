@@ -0,0 +1,269 @@
+//! Unit tests for attachment blob storage and externalisation.
+
+use super::adapters_test_support::ctx;
+use crate::message::{
+    adapters::{
+        blob_store::ObjectStoreBlobStore, externalising::ExternalisingMessageRepository,
+        memory::InMemoryMessageRepository,
+    },
+    domain::{
+        AttachmentPart, ContentHash, ContentHashError, ContentPart, ConversationId, ImagePart,
+        Message, MessageRedaction, RedactionReason, Role, SequenceNumber, TextPart,
+    },
+    error::ValidationError,
+    ports::{
+        MessageRepository,
+        blob_store::{BlobStore, BlobStoreError},
+        validator::{MessageValidator, ValidationConfig},
+    },
+    validation::service::DefaultMessageValidator,
+};
+use bytes::Bytes;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+fn large_data() -> String {
+    "iVBORw0KGgo".repeat(500)
+}
+
+fn message_with_attachments() -> Message {
+    Message::new(
+        ConversationId::new(),
+        Role::User,
+        vec![
+            ContentPart::Text(TextPart::new("Screenshot attached")),
+            ContentPart::Attachment(AttachmentPart::new("image/png", large_data())),
+            ContentPart::Attachment(AttachmentPart::new("text/plain", "SGk=")),
+        ],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+fn attachments(message: &Message) -> Vec<&AttachmentPart> {
+    message
+        .content()
+        .iter()
+        .filter_map(|part| match part {
+            ContentPart::Attachment(attachment) => Some(attachment),
+            _ => None,
+        })
+        .collect()
+}
+
+#[rstest]
+#[case::too_short("abc123")]
+#[case::uppercase("2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824")]
+#[case::not_hex("zcf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824")]
+fn malformed_content_hashes_are_rejected(#[case] value: &str) {
+    assert_eq!(
+        ContentHash::parse(value),
+        Err(ContentHashError(value.to_owned()))
+    );
+}
+
+#[rstest]
+fn only_attachments_over_the_limit_are_externalised() {
+    let message = message_with_attachments();
+
+    let (externalised, blobs) = message.clone().externalise_attachments(1024);
+
+    let [blob] = blobs.as_slice() else {
+        panic!("expected one blob, got {blobs:?}");
+    };
+    assert_eq!(blob.data, large_data());
+    assert_eq!(blob.hash, ContentHash::of(large_data().as_bytes()));
+    let parts = attachments(&externalised);
+    assert_eq!(
+        parts.first().and_then(|part| part.blob.as_ref()),
+        Some(&blob.hash)
+    );
+    assert_eq!(parts.first().map(|part| part.data.as_str()), Some(""));
+    assert_eq!(parts.get(1).map(|part| part.is_externalised()), Some(false));
+    let restored = externalised.with_attachment_data(
+        &[(blob.hash.clone(), blob.data.clone())]
+            .into_iter()
+            .collect(),
+    );
+    assert_eq!(restored, message);
+}
+
+#[rstest]
+fn large_images_are_externalised_and_restored() {
+    let image = ImagePart::new("image/png", large_data(), 1920, 1080);
+    let message = Message::new(
+        ConversationId::new(),
+        Role::User,
+        vec![ContentPart::Image(image)],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )
+    .expect("valid message");
+
+    let (externalised, blobs) = message.clone().externalise_attachments(1024);
+
+    let hash = ContentHash::of(large_data().as_bytes());
+    assert_eq!(
+        externalised.attachment_blobs().collect::<Vec<_>>(),
+        vec![&hash]
+    );
+    let restored = externalised.with_attachment_data(
+        &blobs
+            .into_iter()
+            .map(|blob| (blob.hash, blob.data))
+            .collect(),
+    );
+    assert_eq!(restored, message);
+}
+
+#[rstest]
+fn validation_measures_externalised_attachments_as_references() {
+    let message = message_with_attachments();
+    let config = ValidationConfig {
+        max_message_size_bytes: 2048,
+        ..Default::default()
+    };
+    let inline = DefaultMessageValidator::with_config(config.clone());
+    let externalising = DefaultMessageValidator::with_config(ValidationConfig {
+        max_inline_attachment_bytes: Some(1024),
+        ..config
+    });
+
+    assert!(matches!(
+        inline.validate(&message),
+        Err(ValidationError::MessageTooLarge { .. })
+    ));
+    assert!(externalising.validate(&message).is_ok());
+    let (externalised, _) = message.externalise_attachments(1024);
+    assert!(externalising.validate(&externalised).is_ok());
+}
+
+#[rstest]
+#[tokio::test]
+async fn blobs_are_checked_against_their_hash_and_scoped_to_tenants() {
+    let store = ObjectStoreBlobStore::in_memory();
+    let (owner, other) = (ctx(), ctx());
+    let hash = ContentHash::of(b"hello");
+
+    let wrong = store
+        .put(
+            &owner,
+            &ContentHash::of(b"world"),
+            Bytes::from_static(b"hello"),
+        )
+        .await;
+    store
+        .put(&owner, &hash, Bytes::from_static(b"hello"))
+        .await
+        .expect("put blob");
+
+    assert!(matches!(wrong, Err(BlobStoreError::HashMismatch { .. })));
+    assert_eq!(
+        store.get(&owner, &hash).await.expect("get blob"),
+        Some(Bytes::from_static(b"hello"))
+    );
+    assert_eq!(store.get(&other, &hash).await.expect("get blob"), None);
+    store.delete(&owner, &hash).await.expect("delete blob");
+    store.delete(&owner, &hash).await.expect("delete again");
+    assert_eq!(store.get(&owner, &hash).await.expect("get blob"), None);
+}
+
+#[rstest]
+#[tokio::test]
+async fn externalising_repository_stores_references_and_returns_whole_attachments() {
+    let ctx = ctx();
+    let inner = Arc::new(InMemoryMessageRepository::new());
+    let repository = ExternalisingMessageRepository::new(
+        Arc::clone(&inner),
+        Arc::new(ObjectStoreBlobStore::in_memory()),
+        1024,
+    );
+    let message = message_with_attachments();
+
+    repository
+        .store(&ctx, &message)
+        .await
+        .expect("store message");
+
+    let raw = inner
+        .find_by_id(&ctx, message.id())
+        .await
+        .expect("find raw")
+        .expect("raw message");
+    let hash = ContentHash::of(large_data().as_bytes());
+    assert_eq!(raw.attachment_blobs().collect::<Vec<_>>(), vec![&hash]);
+    let found = repository
+        .find_by_id(&ctx, message.id())
+        .await
+        .expect("find message");
+    assert_eq!(found, Some(message));
+}
+
+#[rstest]
+#[tokio::test]
+async fn redaction_deletes_externalised_blobs() {
+    let ctx = ctx();
+    let blobs = Arc::new(ObjectStoreBlobStore::in_memory());
+    let repository = ExternalisingMessageRepository::new(
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::clone(&blobs) as Arc<dyn BlobStore>,
+        1024,
+    );
+    let message = message_with_attachments();
+    repository
+        .store(&ctx, &message)
+        .await
+        .expect("store message");
+    let redaction = MessageRedaction::new(
+        message.id(),
+        RedactionReason::new("pasted credentials").expect("reason"),
+        ctx.user_id(),
+        &DefaultClock,
+    );
+
+    repository
+        .redact(&ctx, &redaction)
+        .await
+        .expect("redact message");
+
+    let hash = ContentHash::of(large_data().as_bytes());
+    assert_eq!(blobs.get(&ctx, &hash).await.expect("get blob"), None);
+}
+
+#[rstest]
+#[tokio::test]
+async fn redaction_keeps_blobs_other_messages_share() {
+    let ctx = ctx();
+    let blobs = Arc::new(ObjectStoreBlobStore::in_memory());
+    let repository = ExternalisingMessageRepository::new(
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::clone(&blobs) as Arc<dyn BlobStore>,
+        1024,
+    );
+    let (redacted, kept) = (message_with_attachments(), message_with_attachments());
+    for message in [&redacted, &kept] {
+        repository
+            .store(&ctx, message)
+            .await
+            .expect("store message");
+    }
+    let redaction = MessageRedaction::new(
+        redacted.id(),
+        RedactionReason::new("pasted credentials").expect("reason"),
+        ctx.user_id(),
+        &DefaultClock,
+    );
+
+    repository
+        .redact(&ctx, &redaction)
+        .await
+        .expect("redact message");
+
+    let found = repository
+        .find_by_id(&ctx, kept.id())
+        .await
+        .expect("find message");
+    assert_eq!(found, Some(kept));
+}
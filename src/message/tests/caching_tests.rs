@@ -0,0 +1,120 @@
+//! Unit tests for the read-through conversation history cache.
+
+use super::adapters_test_support::{clock, ctx, make_message, repo};
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::{
+        caching::{CachingMessageRepository, MessageCacheMetrics},
+        memory::InMemoryMessageRepository,
+    },
+    domain::{ConversationId, Message, MessageRedaction, RedactionReason},
+    ports::MessageRepository,
+};
+use crate::pagination::PageRequest;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+use std::time::Duration;
+
+type Cached = CachingMessageRepository<InMemoryMessageRepository>;
+
+fn cached(inner: &Arc<InMemoryMessageRepository>) -> Cached {
+    CachingMessageRepository::new(Arc::clone(inner), 100, Duration::from_secs(60))
+}
+
+async fn history_len(
+    repository: &Cached,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+) -> usize {
+    repository
+        .find_by_conversation(ctx, conversation_id, PageRequest::default())
+        .await
+        .expect("history")
+        .len()
+}
+
+async fn seeded(
+    repository: &Cached,
+    ctx: &RequestContext,
+    clock: &DefaultClock,
+) -> (ConversationId, Message) {
+    let conversation_id = ConversationId::new();
+    let message = make_message(conversation_id, 1, clock).expect("valid message");
+    repository.store(ctx, &message).await.expect("store");
+    (conversation_id, message)
+}
+
+#[rstest]
+#[tokio::test]
+async fn repeated_reads_are_served_from_the_cache(
+    ctx: RequestContext,
+    clock: DefaultClock,
+    repo: InMemoryMessageRepository,
+) {
+    let inner = Arc::new(repo);
+    let repository = cached(&inner);
+    let (conversation_id, _) = seeded(&repository, &ctx, &clock).await;
+
+    assert_eq!(history_len(&repository, &ctx, conversation_id).await, 1);
+    let bypassing = make_message(conversation_id, 2, &clock).expect("valid message");
+    inner.store(&ctx, &bypassing).await.expect("store");
+
+    assert_eq!(history_len(&repository, &ctx, conversation_id).await, 1);
+    assert_eq!(
+        repository.metrics(),
+        MessageCacheMetrics {
+            hits: 1,
+            misses: 1,
+            invalidations: 1,
+        }
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn writes_through_the_decorator_invalidate_the_conversation(
+    ctx: RequestContext,
+    clock: DefaultClock,
+    repo: InMemoryMessageRepository,
+) {
+    let repository = cached(&Arc::new(repo));
+    let (conversation_id, first) = seeded(&repository, &ctx, &clock).await;
+    assert_eq!(history_len(&repository, &ctx, conversation_id).await, 1);
+
+    let next = make_message(conversation_id, 2, &clock).expect("valid message");
+    repository.append(&ctx, &next).await.expect("append");
+    assert_eq!(history_len(&repository, &ctx, conversation_id).await, 2);
+
+    let reason = RedactionReason::new("pasted API key").expect("valid reason");
+    let redaction = MessageRedaction::new(first.id(), reason, ctx.user_id(), &clock);
+    repository.redact(&ctx, &redaction).await.expect("redact");
+    let history = repository
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
+        .await
+        .expect("history");
+    let [redacted, _] = history.items() else {
+        panic!("expected two messages, got {history:?}");
+    };
+    assert!(redacted.is_redacted());
+    assert_eq!(repository.metrics().misses, 3);
+}
+
+#[rstest]
+#[tokio::test]
+async fn tenants_do_not_share_cached_history(
+    ctx: RequestContext,
+    clock: DefaultClock,
+    repo: InMemoryMessageRepository,
+) {
+    let repository = cached(&Arc::new(repo));
+    let (conversation_id, _) = seeded(&repository, &ctx, &clock).await;
+    assert_eq!(history_len(&repository, &ctx, conversation_id).await, 1);
+
+    let other_tenant = super::adapters_test_support::ctx();
+    assert_eq!(
+        history_len(&repository, &other_tenant, conversation_id).await,
+        0
+    );
+    assert_eq!(repository.metrics().misses, 2);
+}
@@ -0,0 +1,158 @@
+//! Unit tests for canonical JSON serialisation.
+
+use crate::message::{
+    adapters::inbound::{SignatureScheme, WebhookSignatureVerifier},
+    canonical::{canonical_sha256, canonicalize, to_canonical_string},
+    domain::{ContentPart, ConversationId, Message, Role, SequenceNumber, TextPart, ToolCallPart},
+    versioning::VersionedEvent,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+fn message_with(conversation_id: ConversationId, content: ContentPart, sequence: u64) -> Message {
+    Message::new(
+        conversation_id,
+        Role::User,
+        vec![content],
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+#[rstest]
+fn keys_are_sorted_at_every_depth() {
+    let value = json!({"z": {"y": 1, "b": [{"d": true, "c": null}]}, "a": "x"});
+
+    assert_eq!(
+        canonicalize(&value).to_string(),
+        r#"{"a":"x","z":{"b":[{"c":null,"d":true}],"y":1}}"#
+    );
+}
+
+#[rstest]
+#[case(json!(1.0), "1")]
+#[case(json!(-0.0), "0")]
+#[case(json!(-42.0), "-42")]
+#[case(json!(1.5), "1.5")]
+#[case(json!(u64::MAX), "18446744073709551615")]
+#[case(json!(1e300), "1e300")]
+fn numbers_are_normalised(#[case] value: Value, #[case] expected: &str) {
+    assert_eq!(canonicalize(&value).to_string(), expected);
+}
+
+#[rstest]
+fn structs_serialise_with_sorted_fields() {
+    #[derive(serde::Serialize)]
+    struct Unsorted {
+        zeta: u8,
+        alpha: u8,
+    }
+
+    let text = to_canonical_string(&Unsorted { zeta: 2, alpha: 1 }).expect("serialisable");
+
+    assert_eq!(text, r#"{"alpha":1,"zeta":2}"#);
+}
+
+#[rstest]
+fn non_string_map_keys_are_rejected() {
+    let map = HashMap::from([((1, 2), "pair")]);
+
+    assert!(to_canonical_string(&map).is_err());
+}
+
+#[rstest]
+fn equivalent_tool_arguments_share_a_canonical_message() {
+    let conversation_id = ConversationId::new();
+    let first = message_with(
+        conversation_id,
+        ContentPart::ToolCall(ToolCallPart::new(
+            "call-1",
+            "read",
+            json!({"path": "a", "limit": 10.0}),
+        )),
+        1,
+    );
+    let second = message_with(
+        conversation_id,
+        ContentPart::ToolCall(ToolCallPart::new(
+            "call-1",
+            "read",
+            json!({"limit": 10, "path": "a"}),
+        )),
+        2,
+    );
+
+    assert_eq!(
+        first.dedup_hash().expect("hashable"),
+        second.dedup_hash().expect("hashable")
+    );
+    assert_ne!(
+        first.to_canonical_json().expect("serialisable"),
+        second.to_canonical_json().expect("serialisable")
+    );
+}
+
+#[rstest]
+fn dedup_hash_distinguishes_content_and_conversation() {
+    let conversation_id = ConversationId::new();
+    let hello = message_with(conversation_id, ContentPart::Text(TextPart::new("hi")), 1);
+    let other_text = message_with(conversation_id, ContentPart::Text(TextPart::new("yo")), 1);
+    let other_conversation = message_with(
+        ConversationId::new(),
+        ContentPart::Text(TextPart::new("hi")),
+        1,
+    );
+
+    let hash = hello.dedup_hash().expect("hashable");
+
+    assert_eq!(hash.len(), 64);
+    assert_ne!(hash, other_text.dedup_hash().expect("hashable"));
+    assert_ne!(hash, other_conversation.dedup_hash().expect("hashable"));
+}
+
+#[rstest]
+fn canonical_message_json_round_trips() {
+    let message = message_with(
+        ConversationId::new(),
+        ContentPart::Text(TextPart::new("hello")),
+        3,
+    );
+
+    let text = message.to_canonical_json().expect("serialisable");
+    let parsed: Message = serde_json::from_str(&text).expect("deserialisable");
+
+    assert_eq!(parsed, message);
+}
+
+#[rstest]
+fn versioned_events_hash_identically_whatever_the_data_order() {
+    let first = VersionedEvent::new_with_clock(
+        2,
+        "MessageCreated",
+        json!({"b": [1.0, 2.0], "a": {"y": 1, "x": 0}}),
+        &DefaultClock,
+    );
+    let mut second = first.clone();
+    *second.data_mut() = json!({"a": {"x": 0, "y": 1}, "b": [1, 2]});
+
+    assert_eq!(
+        canonical_sha256(&first).expect("hashable"),
+        canonical_sha256(&second).expect("hashable")
+    );
+}
+
+#[rstest]
+fn canonical_signatures_ignore_key_order() {
+    let verifier =
+        WebhookSignatureVerifier::new(SignatureScheme::HmacSha256, "secret").expect("secret");
+
+    let first = verifier
+        .sign_canonical("1700000000", &json!({"b": 1, "a": 2}))
+        .expect("signable");
+    let second = verifier.sign("1700000000", br#"{"a":2,"b":1}"#);
+
+    assert_eq!(first, second);
+}
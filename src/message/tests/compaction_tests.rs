@@ -0,0 +1,352 @@
+//! Unit tests for conversation compaction.
+
+use crate::context::RequestContext;
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::message::{
+    adapters::{
+        memory::{
+            InMemoryAgentSessionRepository, InMemoryContextSnapshotAdapter,
+            InMemoryConversationRepository, InMemoryMessageRepository,
+        },
+        token_counting::HeuristicTokenCounter,
+    },
+    domain::{
+        AgentSession, AgentSessionId, CompactedHistory, CompactionPolicy, ContentPart,
+        ContextWindowSnapshot, ConversationId, ConversationLifecyclePoint, Message, MessageSummary,
+        Role, SequenceNumber, SequenceRange, SnapshotParams, SnapshotType, TextPart,
+    },
+    ports::{
+        AgentSessionRepository, ConversationSummariser, MessageRepository, SummariserResult,
+        TokenCounter,
+    },
+    services::{
+        AppendMessageRequest, CompactionServiceError, ConversationCompactionPorts,
+        ConversationCompactionService, ConversationLifecycleHooks, ConversationService,
+    },
+    validation::service::DefaultMessageValidator,
+};
+use crate::operator::domain::OperatorReason;
+use crate::test_support::test_request_ctx;
+use async_trait::async_trait;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::{Arc, Mutex};
+
+type TestConversations = ConversationService<
+    InMemoryConversationRepository,
+    InMemoryMessageRepository,
+    DefaultMessageValidator,
+    DefaultClock,
+>;
+
+/// Summariser that describes which messages each call saw.
+#[derive(Default)]
+struct RecordingSummariser {
+    calls: Mutex<Vec<String>>,
+}
+
+impl RecordingSummariser {
+    fn calls(&self) -> Vec<String> {
+        self.calls.lock().expect("calls lock").clone()
+    }
+
+    fn record(&self, call: String) -> SummariserResult<String> {
+        self.calls.lock().expect("calls lock").push(call.clone());
+        Ok(call)
+    }
+}
+
+fn sequences(messages: &[Message]) -> String {
+    messages
+        .iter()
+        .map(|message| message.sequence_number().to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[async_trait]
+impl ConversationSummariser for RecordingSummariser {
+    async fn summarise(
+        &self,
+        _ctx: &RequestContext,
+        messages: &[Message],
+    ) -> SummariserResult<String> {
+        self.record(format!("summarise {}", sequences(messages)))
+    }
+
+    async fn extend(
+        &self,
+        _ctx: &RequestContext,
+        summary: &str,
+        messages: &[Message],
+    ) -> SummariserResult<String> {
+        self.record(format!("{summary}; extend {}", sequences(messages)))
+    }
+}
+
+struct Harness {
+    conversations: TestConversations,
+    messages: Arc<InMemoryMessageRepository>,
+    compaction: Arc<ConversationCompactionService>,
+    sessions: Arc<InMemoryAgentSessionRepository>,
+    summariser: Arc<RecordingSummariser>,
+}
+
+impl Harness {
+    async fn say(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        text: &str,
+    ) -> Message {
+        self.conversations
+            .append_message(
+                ctx,
+                AppendMessageRequest::new(
+                    conversation_id,
+                    Role::User,
+                    vec![ContentPart::Text(TextPart::new(text))],
+                ),
+            )
+            .await
+            .expect("append")
+    }
+
+    async fn start_session(&self, ctx: &RequestContext, conversation_id: ConversationId) {
+        let session = AgentSession::new(
+            conversation_id,
+            "claude",
+            SequenceNumber::new(1),
+            &DefaultClock,
+        );
+        self.sessions.store(ctx, &session).await.expect("session");
+    }
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let messages = Arc::new(InMemoryMessageRepository::new());
+    let sessions = Arc::new(InMemoryAgentSessionRepository::new());
+    let summariser = Arc::new(RecordingSummariser::default());
+    let compaction = Arc::new(
+        ConversationCompactionService::new(ConversationCompactionPorts {
+            messages: messages.clone(),
+            snapshots: Arc::new(InMemoryContextSnapshotAdapter::new()),
+            sessions: sessions.clone(),
+            summariser: summariser.clone(),
+            clock: Arc::new(DefaultClock),
+        })
+        .with_policy(CompactionPolicy::new(4, 2))
+        .with_token_counter(Arc::new(HeuristicTokenCounter::new())),
+    );
+    let mut hooks = ConversationLifecycleHooks::new();
+    hooks.register(
+        "compaction",
+        [ConversationLifecyclePoint::MessageStored],
+        compaction.clone(),
+    );
+    let conversations = ConversationService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        messages.clone(),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_lifecycle_hooks(Arc::new(hooks));
+    Harness {
+        conversations,
+        messages,
+        compaction,
+        sessions,
+        summariser,
+    }
+}
+
+fn snapshot(snapshot_type: SnapshotType, end: u64) -> ContextWindowSnapshot {
+    ContextWindowSnapshot::new(
+        SnapshotParams {
+            conversation_id: ConversationId::new(),
+            session_id: AgentSessionId::new(),
+            sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(end)),
+            message_summary: MessageSummary::default(),
+            snapshot_type,
+        },
+        &DefaultClock,
+    )
+}
+
+fn user_message(sequence: u64) -> Message {
+    Message::new(
+        ConversationId::new(),
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("hello"))],
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+#[rstest]
+#[case(4, 2, 4)]
+#[case(2, 2, 3)]
+#[case(0, 0, 1)]
+fn compaction_threshold_always_leaves_a_message_to_summarise(
+    #[case] threshold: usize,
+    #[case] retain_recent: usize,
+    #[case] expected: usize,
+) {
+    let policy = CompactionPolicy::new(threshold, retain_recent);
+
+    assert_eq!(policy.threshold(), expected);
+    assert_eq!(policy.messages_to_compact(expected - 1), 0);
+    assert_eq!(
+        policy.messages_to_compact(expected),
+        expected - retain_recent
+    );
+}
+
+#[rstest]
+#[case(snapshot(SnapshotType::Checkpoint, 2).with_summary("checkpoint"))]
+#[case(snapshot(SnapshotType::Compaction, 2))]
+fn history_ignores_snapshots_that_are_not_summarised_compactions(
+    #[case] snapshot: ContextWindowSnapshot,
+) {
+    let history = CompactedHistory::assemble(Some(snapshot), (1..=3).map(user_message));
+
+    assert!(history.compaction().is_none());
+    assert_eq!(history.messages().len(), 3);
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn growing_conversations_are_compacted_behind_recent_messages(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation = harness
+        .conversations
+        .create_conversation(&ctx)
+        .await
+        .expect("create");
+    harness.start_session(&ctx, conversation.id()).await;
+
+    for text in ["one", "two", "three", "four", "five", "six"] {
+        harness.say(&ctx, conversation.id(), text).await;
+    }
+
+    assert_eq!(
+        harness.summariser.calls(),
+        vec!["summarise 1,2", "summarise 1,2; extend 3,4"]
+    );
+    let history = harness
+        .compaction
+        .history(&ctx, conversation.id())
+        .await
+        .expect("history");
+    let compaction = history.compaction().expect("compacted");
+    assert_eq!(compaction.snapshot_type, SnapshotType::Compaction);
+    assert_eq!(
+        compaction.sequence_range,
+        SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(4))
+    );
+    assert_eq!(compaction.message_summary, MessageSummary::new(4, 0, 0, 0));
+    let summary = history.summary_text().expect("summary");
+    assert_eq!(
+        compaction.token_estimate,
+        Some(HeuristicTokenCounter::new().count(summary))
+    );
+    assert_eq!(sequences(history.messages()), "5,6");
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn pinned_messages_are_kept_verbatim_through_compaction(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation = harness
+        .conversations
+        .create_conversation(&ctx)
+        .await
+        .expect("create");
+    harness.start_session(&ctx, conversation.id()).await;
+    let instruction = harness
+        .say(&ctx, conversation.id(), "always answer in French")
+        .await;
+    harness
+        .messages
+        .set_pinned(&ctx, instruction.id(), true)
+        .await
+        .expect("pin");
+
+    for text in ["two", "three", "four", "five"] {
+        harness.say(&ctx, conversation.id(), text).await;
+    }
+
+    assert_eq!(harness.summariser.calls(), vec!["summarise 2,3"]);
+    let history = harness
+        .compaction
+        .history(&ctx, conversation.id())
+        .await
+        .expect("history");
+    assert_eq!(history.compacted_through(), Some(SequenceNumber::new(3)));
+    assert_eq!(sequences(history.pinned()), "1");
+    assert_eq!(sequences(history.messages()), "4,5");
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn conversations_without_an_active_session_are_not_compacted(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation = harness
+        .conversations
+        .create_conversation(&ctx)
+        .await
+        .expect("create");
+
+    for text in ["one", "two", "three", "four"] {
+        harness.say(&ctx, conversation.id(), text).await;
+    }
+
+    let compacted = harness
+        .compaction
+        .compact(&ctx, conversation.id())
+        .await
+        .expect("compact");
+    assert!(compacted.is_none());
+    assert!(harness.summariser.calls().is_empty());
+    let history = harness
+        .compaction
+        .history(&ctx, conversation.id())
+        .await
+        .expect("history");
+    assert!(history.summary_text().is_none());
+    assert_eq!(history.messages().len(), 4);
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn conversations_are_not_compacted_during_maintenance(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation = harness
+        .conversations
+        .create_conversation(&ctx)
+        .await
+        .expect("create");
+    for text in ["one", "two", "three", "four"] {
+        harness.say(&ctx, conversation.id(), text).await;
+    }
+    harness.start_session(&ctx, conversation.id()).await;
+    let gate = Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ));
+    let compaction = (*harness.compaction)
+        .clone()
+        .with_maintenance(Arc::clone(&gate));
+    let reason = OperatorReason::new("failover").expect("valid reason");
+    gate.enable(reason, None).await.expect("enable maintenance");
+
+    let refused = compaction.compact(&ctx, conversation.id()).await;
+
+    assert!(matches!(
+        refused,
+        Err(CompactionServiceError::MaintenanceMode(_))
+    ));
+    assert!(harness.summariser.calls().is_empty());
+}
@@ -1,4 +1,4 @@
-//! Unit tests for content part types and metadata.
+//! Unit tests for content part types.
 
 #![expect(
     clippy::too_many_arguments,
@@ -6,9 +6,11 @@
 )]
 
 use crate::message::domain::{
-    AgentResponseAudit, AgentResponseStatus, AttachmentPart, MessageMetadata, ReviewLinkage, Role,
-    TextPart, ToolCallAudit, ToolCallPart, ToolCallStatus, ToolResultPart, TurnId,
+    AgentContextPolicy, AttachmentPart, CitationPart, CitationSource, CitationSpan, ContentPart,
+    ContentPartKind, ConversationId, ImagePart, Message, ReasoningPart, ReasoningSharing, Role,
+    SequenceNumber, TextPart, ToolCallPart, ToolResultPart,
 };
+use mockable::DefaultClock;
 use rstest::rstest;
 use serde_json::json;
 
@@ -81,6 +83,110 @@ fn text_part_len() {
     assert_eq!(text.len(), 5);
 }
 
+// ============================================================================
+// ReasoningPart tests
+// ============================================================================
+
+#[rstest]
+fn reasoning_part_serialises_with_redaction_flag() {
+    let part = ContentPart::Reasoning(ReasoningPart::new("Check the cache first."));
+
+    let value = serde_json::to_value(&part).expect("serialize");
+
+    assert_eq!(part.kind(), ContentPartKind::Reasoning);
+    assert_eq!(
+        value,
+        json!({"type": "reasoning", "text": "Check the cache first.", "redacted": false})
+    );
+    let unflagged: ContentPart =
+        serde_json::from_value(json!({"type": "reasoning", "text": "Check the cache first."}))
+            .expect("deserialize");
+    assert_eq!(unflagged, part);
+}
+
+fn assistant_message(content: Vec<ContentPart>) -> Message {
+    Message::new(
+        ConversationId::new(),
+        Role::Assistant,
+        content,
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+#[rstest]
+#[case::strip(ReasoningSharing::Strip, vec![1])]
+#[case::keep(ReasoningSharing::Keep, vec![2, 1])]
+fn agent_context_policy_controls_reasoning(
+    #[case] reasoning: ReasoningSharing,
+    #[case] part_counts: Vec<usize>,
+) {
+    let answered = assistant_message(vec![
+        ContentPart::Reasoning(ReasoningPart::new("The user wants a summary.")),
+        ContentPart::Text(TextPart::new("Here is the summary.")),
+    ]);
+    let thinking_only = assistant_message(vec![ContentPart::Reasoning(ReasoningPart::new(
+        "Still reading the diff.",
+    ))]);
+
+    let shared = AgentContextPolicy::default()
+        .with_reasoning(reasoning)
+        .apply(vec![answered, thinking_only]);
+
+    let counts: Vec<_> = shared
+        .iter()
+        .map(|message| message.content().len())
+        .collect();
+    assert_eq!(counts, part_counts);
+}
+
+// ============================================================================
+// CitationPart tests
+// ============================================================================
+
+#[rstest]
+fn citation_part_serialises_with_tagged_source() {
+    let part = ContentPart::Citation(CitationPart::new(
+        CitationSource::uri("https://example.com/paper.pdf"),
+        vec![CitationSpan::new(0, 3, 11)],
+    ));
+
+    let value = serde_json::to_value(&part).expect("serialize");
+
+    assert_eq!(part.kind(), ContentPartKind::Citation);
+    assert_eq!(
+        value,
+        json!({
+            "type": "citation",
+            "source": {"kind": "uri", "uri": "https://example.com/paper.pdf"},
+            "spans": [{"part_index": 0, "start": 3, "end": 11}]
+        })
+    );
+    let parsed: ContentPart = serde_json::from_value(value).expect("deserialize");
+    assert_eq!(parsed, part);
+}
+
+#[rstest]
+fn stripping_reasoning_renumbers_citation_spans() {
+    let citation = CitationPart::new(
+        CitationSource::uri("https://example.com/paper.pdf"),
+        vec![CitationSpan::new(1, 0, 6)],
+    );
+    let message = assistant_message(vec![
+        ContentPart::Reasoning(ReasoningPart::new("The paper covers this.")),
+        ContentPart::Text(TextPart::new("Caches help.")),
+        ContentPart::Citation(citation),
+    ]);
+
+    let stripped = message.without_reasoning().expect("text remains");
+
+    let [ContentPart::Text(_), ContentPart::Citation(renumbered)] = stripped.content() else {
+        panic!("unexpected content: {:?}", stripped.content());
+    };
+    assert_eq!(renumbered.spans, vec![CitationSpan::new(0, 0, 6)]);
+}
+
 // ============================================================================
 // ToolCallPart tests
 // ============================================================================
@@ -161,207 +267,58 @@ fn attachment_is_valid(#[case] mime_type: &str, #[case] data: &str, #[case] expe
 }
 
 // ============================================================================
-// MessageMetadata tests
+// ImagePart tests
 // ============================================================================
 
 #[rstest]
-fn message_metadata_empty() {
-    let metadata = MessageMetadata::empty();
-    assert!(metadata.is_empty());
-}
-
-#[rstest]
-fn message_metadata_with_agent_backend() {
-    let metadata = MessageMetadata::with_agent_backend("claude_code_sdk");
-    assert_eq!(metadata.agent_backend, Some("claude_code_sdk".to_owned()));
-    assert!(!metadata.is_empty());
-}
-
-#[rstest]
-fn message_metadata_builder_chain() {
-    let turn_id = TurnId::new();
-    let tool_call = ToolCallAudit::new("call-1", "search", ToolCallStatus::Succeeded);
-    let response = AgentResponseAudit::new(AgentResponseStatus::Completed).with_response_id("r-1");
-    let metadata = MessageMetadata::with_agent_backend("claude")
-        .with_turn_id(turn_id)
-        .with_tool_call_audit(tool_call)
-        .with_agent_response_audit(response)
-        .with_extension("custom", json!({"key": "value"}))
-        .expect("non-reserved key should succeed");
-
-    assert_eq!(metadata.agent_backend, Some("claude".to_owned()));
-    assert_eq!(metadata.turn_id, Some(turn_id));
-    assert_eq!(metadata.tool_call_audits.len(), 1);
-    assert!(metadata.agent_response_audit.is_some());
-    assert!(metadata.extensions.contains_key("custom"));
-}
-
-// ============================================================================
-// Audit metadata tests
-// ============================================================================
-
-#[rstest]
-fn tool_call_audit_new_sets_fields() {
-    let audit = ToolCallAudit::new("call-123", "read_file", ToolCallStatus::Running);
-    assert_eq!(audit.call_id, "call-123");
-    assert_eq!(audit.tool_name, "read_file");
-    assert_eq!(audit.status, ToolCallStatus::Running);
-    assert!(audit.error.is_none());
-}
-
-#[rstest]
-fn tool_call_audit_with_error() {
-    let audit = ToolCallAudit::new("call-123", "read_file", ToolCallStatus::Failed)
-        .with_error("permission denied");
-    assert_eq!(audit.error, Some("permission denied".to_owned()));
-}
-
-#[rstest]
-fn agent_response_audit_builders() {
-    let audit = AgentResponseAudit::new(AgentResponseStatus::Completed)
-        .with_response_id("resp-1")
-        .with_model("claude-3-opus")
-        .with_error("none");
-    assert_eq!(audit.status, AgentResponseStatus::Completed);
-    assert_eq!(audit.response_id, Some("resp-1".to_owned()));
-    assert_eq!(audit.model, Some("claude-3-opus".to_owned()));
-    assert_eq!(audit.error, Some("none".to_owned()));
-}
-
-// ============================================================================
-// Review linkage extension tests
-// ============================================================================
-
-#[rstest]
-#[case::with_optional_fields(
-    ReviewLinkage::new("rc-42", "thread-root-7", "alice", "pending")
-        .with_file_path("src/lib.rs")
-        .with_commit_sha("abc123"),
-)]
-#[case::absent_optional_fields(ReviewLinkage::new("rc-99", "thread-root-1", "bob", "verified"))]
-fn review_linkage_round_trip_serialization(#[case] linkage: ReviewLinkage) {
-    let expected = linkage.clone();
-    let metadata = MessageMetadata::empty()
-        .with_review_linkage(&linkage)
-        .expect("serialize linkage");
-
-    let json = serde_json::to_string(&metadata).expect("serialize");
-    let deserialized: MessageMetadata = serde_json::from_str(&json).expect("deserialize");
-
-    let ext = deserialized
-        .extensions
-        .get("review.linkage.v1")
-        .expect("review.linkage.v1 key present");
-    let recovered: ReviewLinkage =
-        serde_json::from_value(ext.clone()).expect("deserialize linkage");
-    assert_eq!(recovered, expected);
-}
-
-#[rstest]
-fn legacy_flat_extensions_merged_on_deserialize() {
-    // Simulate the old flat layout where extension keys lived at the top level
-    // alongside known struct fields, rather than nested under "extensions".
-    let legacy_json = json!({
-        "agent_backend": "claude",
-        "review.linkage.v1": { "review_comment_id": "rc-42" },
-        "custom.workflow": "some-value"
-    });
-    let metadata: MessageMetadata =
-        serde_json::from_value(legacy_json).expect("deserialize legacy flat layout");
-
-    assert_eq!(metadata.agent_backend, Some("claude".to_owned()));
-    // Legacy top-level keys should be collected into extensions.
-    assert_eq!(
-        metadata
-            .extensions
-            .get("review.linkage.v1")
-            .and_then(|v| v.get("review_comment_id"))
-            .and_then(serde_json::Value::as_str),
-        Some("rc-42"),
+fn image_part_serialises_with_image_tag() {
+    let part = ContentPart::Image(
+        ImagePart::new("image/png", "iVBORw0KGgo=", 640, 480).with_alt_text("Build chart"),
     );
-    assert_eq!(
-        metadata
-            .extensions
-            .get("custom.workflow")
-            .and_then(serde_json::Value::as_str),
-        Some("some-value"),
-    );
-}
 
-#[rstest]
-fn legacy_flat_extensions_do_not_overwrite_namespaced() {
-    // When both a legacy top-level key and a namespaced key exist,
-    // the namespaced version (under "extensions") takes precedence.
-    let json_with_both = json!({
-        "extensions": { "review.linkage.v1": { "review_comment_id": "new" } },
-        "review.linkage.v1": { "review_comment_id": "old-flat" }
-    });
-    let metadata: MessageMetadata =
-        serde_json::from_value(json_with_both).expect("deserialize mixed layout");
+    let value = serde_json::to_value(&part).expect("serialise image");
 
     assert_eq!(
-        metadata
-            .extensions
-            .get("review.linkage.v1")
-            .and_then(|v| v.get("review_comment_id"))
-            .and_then(serde_json::Value::as_str),
-        Some("new"),
-        "namespaced key should win over legacy flat key"
+        value,
+        json!({
+            "type": "image",
+            "mime_type": "image/png",
+            "data": "iVBORw0KGgo=",
+            "width": 640,
+            "height": 480,
+            "alt_text": "Build chart"
+        })
     );
+    assert_eq!(part.kind(), ContentPartKind::Image);
+    let decoded: ContentPart = serde_json::from_value(value).expect("deserialise image");
+    assert_eq!(decoded, part);
 }
 
 #[rstest]
-fn review_linkage_does_not_collide_with_top_level_fields() {
-    let turn_id = TurnId::new();
-    let linkage = ReviewLinkage::new("rc-1", "thread-1", "reviewer", "pending")
-        .with_file_path("path.rs")
-        .with_commit_sha("deadbeef");
-    let metadata = MessageMetadata::with_agent_backend("claude")
-        .with_turn_id(turn_id)
-        .with_review_linkage(&linkage)
-        .expect("serialize linkage");
-
-    let json = serde_json::to_string(&metadata).expect("serialize");
-    let parsed: serde_json::Value = serde_json::from_str(&json).expect("parse as Value");
-
-    // Top-level fields remain intact.
-    assert_eq!(
-        parsed
-            .get("agent_backend")
-            .and_then(serde_json::Value::as_str),
-        Some("claude"),
-    );
-    assert!(
-        parsed
-            .get("turn_id")
-            .and_then(serde_json::Value::as_str)
-            .is_some()
-    );
+fn image_part_from_attachment_keeps_its_data() {
+    let attachment = AttachmentPart::new("image/jpeg", "/9j/4AAQ").with_name("photo.jpg");
 
-    // Review linkage lives under extensions, not at top level.
-    assert!(parsed.get("review_comment_id").is_none());
-    let nested = parsed
-        .get("extensions")
-        .and_then(|e| e.get("review.linkage.v1"))
-        .and_then(|l| l.get("review_comment_id"));
-    assert!(
-        nested.is_some(),
-        "review_comment_id nested under extensions"
-    );
-}
+    let image = ImagePart::from_attachment(attachment, 1024, 768);
 
-#[rstest]
-fn with_extension_rejects_reserved_review_linkage_key() {
-    let result =
-        MessageMetadata::empty().with_extension("review.linkage.v1", json!({"rogue": true}));
-    assert!(
-        result.is_err(),
-        "reserved review.linkage.* key should be rejected"
+    assert_eq!(
+        image,
+        ImagePart::new("image/jpeg", "/9j/4AAQ", 1024, 768).with_name("photo.jpg")
     );
 }
 
 #[rstest]
-fn with_extension_allows_non_reserved_key() {
-    let result = MessageMetadata::empty().with_extension("custom.workflow", json!("ok"));
-    assert!(result.is_ok(), "non-reserved key should be accepted");
+#[case("image/png", "data", 1, 1, true)]
+#[case("", "data", 1, 1, false)]
+#[case("image/png", "", 1, 1, false)]
+#[case("image/png", "data", 0, 1, false)]
+#[case("image/png", "data", 1, 0, false)]
+fn image_is_valid(
+    #[case] mime_type: &str,
+    #[case] data: &str,
+    #[case] width: u32,
+    #[case] height: u32,
+    #[case] expected: bool,
+) {
+    let image = ImagePart::new(mime_type, data, width, height);
+    assert_eq!(image.is_valid(), expected);
 }
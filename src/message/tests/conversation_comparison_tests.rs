@@ -0,0 +1,168 @@
+//! Unit tests for comparing branches of a forked conversation.
+
+use super::adapters_test_support::{ctx, repo};
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::InMemoryMessageRepository,
+    domain::{
+        ContentPart, ConversationComparison, ConversationId, Message, Role, SequenceNumber,
+        TextPart, ToolCallPart, ToolResultPart,
+    },
+    ports::repository::MessageRepository,
+    services::ConversationComparisonService,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+use std::sync::Arc;
+
+fn message(conversation_id: ConversationId, seq: u64, role: Role, part: ContentPart) -> Message {
+    Message::new(
+        conversation_id,
+        role,
+        vec![part],
+        SequenceNumber::new(seq),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+fn text(value: &str) -> ContentPart {
+    ContentPart::Text(TextPart::new(value))
+}
+
+fn call(call_id: &str, command: &str) -> ContentPart {
+    ContentPart::ToolCall(ToolCallPart::new(
+        call_id,
+        "run_command",
+        json!({ "command": command }),
+    ))
+}
+
+/// Builds a branch that shares the opening prompt, then runs `command`
+/// and replies with `reply`.
+fn branch(
+    conversation_id: ConversationId,
+    command: &str,
+    succeeded: bool,
+    reply: &str,
+) -> Vec<Message> {
+    let result = if succeeded {
+        ToolResultPart::success(format!("{command}-call"), json!("ok"))
+    } else {
+        ToolResultPart::failure(format!("{command}-call"), "exit status 1")
+    };
+    vec![
+        message(conversation_id, 1, Role::User, text("Make the tests pass")),
+        message(
+            conversation_id,
+            2,
+            Role::Assistant,
+            call(&format!("{command}-call"), command),
+        ),
+        message(
+            conversation_id,
+            3,
+            Role::Tool,
+            ContentPart::ToolResult(result),
+        ),
+        message(conversation_id, 4, Role::Assistant, text(reply)),
+    ]
+}
+
+#[test]
+fn comparison_reports_divergence_and_unique_tool_calls() {
+    let (left_id, right_id) = (ConversationId::new(), ConversationId::new());
+    let left = branch(left_id, "cargo test", false, "Tests still fail");
+    let mut right = branch(right_id, "cargo nextest run", true, "All green");
+    right.push(message(right_id, 5, Role::User, text("Thanks")));
+
+    let comparison = ConversationComparison::compare((left_id, &left), (right_id, &right));
+
+    assert_eq!(comparison.shared_messages(), 1);
+    assert!(!comparison.is_identical());
+    let offsets: Vec<_> = comparison
+        .differing_messages()
+        .iter()
+        .map(|difference| (difference.offset, difference.left.is_some()))
+        .collect();
+    assert_eq!(offsets, vec![(0, true), (1, true), (2, true), (3, false)]);
+    let commands: Vec<_> = comparison
+        .left()
+        .unique_tool_calls()
+        .iter()
+        .map(|call| call.arguments.clone())
+        .collect();
+    assert_eq!(commands, vec![json!({ "command": "cargo test" })]);
+    assert_eq!(comparison.left().messages().len(), 3);
+    assert_eq!(comparison.right().conversation_id(), right_id);
+}
+
+#[test]
+fn comparison_summarises_each_branch_outcome() {
+    let (left_id, right_id) = (ConversationId::new(), ConversationId::new());
+    let left = branch(left_id, "cargo test", false, "Tests still fail");
+    let right = branch(right_id, "cargo nextest run", true, "All green");
+
+    let comparison = ConversationComparison::compare((left_id, &left), (right_id, &right));
+
+    let left_outcome = comparison.left().outcome();
+    let right_outcome = comparison.right().outcome();
+    assert_eq!(
+        (
+            left_outcome.successful_tool_results(),
+            left_outcome.failed_tool_results()
+        ),
+        (0, 1)
+    );
+    assert_eq!(
+        (
+            right_outcome.successful_tool_results(),
+            right_outcome.failed_tool_results()
+        ),
+        (1, 0)
+    );
+    assert_eq!(left_outcome.final_response(), Some("Tests still fail"));
+    assert_eq!(right_outcome.final_response(), Some("All green"));
+}
+
+#[test]
+fn matching_tool_calls_are_not_reported_as_differences() {
+    let (left_id, right_id) = (ConversationId::new(), ConversationId::new());
+    let left = branch(left_id, "cargo test", true, "Done");
+    let right = branch(right_id, "cargo test", true, "Finished");
+
+    let comparison = ConversationComparison::compare((left_id, &left), (right_id, &right));
+
+    assert_eq!(comparison.shared_messages(), 3);
+    assert_eq!(comparison.differing_messages().len(), 1);
+    assert!(comparison.left().unique_tool_calls().is_empty());
+    assert!(comparison.right().unique_tool_calls().is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn service_compares_stored_branches(repo: InMemoryMessageRepository, ctx: RequestContext) {
+    let (left_id, right_id) = (ConversationId::new(), ConversationId::new());
+    let left = branch(left_id, "cargo test", true, "Done");
+    repo.store_batch(&ctx, &left).await.expect("left stored");
+    repo.store_batch(&ctx, &branch(right_id, "cargo test", true, "Done"))
+        .await
+        .expect("right stored");
+    let service = ConversationComparisonService::new(Arc::new(repo));
+
+    let identical = service
+        .compare(&ctx, left_id, right_id)
+        .await
+        .expect("branches compared");
+    let against_empty = service
+        .compare(&ctx, left_id, ConversationId::new())
+        .await
+        .expect("branches compared");
+
+    assert!(identical.is_identical());
+    assert_eq!(identical.shared_messages(), left.len());
+    assert_eq!(against_empty.shared_messages(), 0);
+    assert_eq!(against_empty.left().messages(), left.as_slice());
+    assert_eq!(against_empty.right().outcome().final_response(), None);
+}
@@ -0,0 +1,168 @@
+//! Unit tests for the conversation state machine, context, and task link.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemoryConversationRepository, InMemoryMessageRepository},
+    domain::{Conversation, ConversationLifecycleError, ConversationState},
+    ports::ConversationRepository,
+    services::{ConversationService, ConversationServiceError},
+    validation::service::DefaultMessageValidator,
+};
+use crate::operator::domain::OperatorReason;
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+type TestService = ConversationService<
+    InMemoryConversationRepository,
+    InMemoryMessageRepository,
+    DefaultMessageValidator,
+    DefaultClock,
+>;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+#[fixture]
+fn conversations() -> InMemoryConversationRepository {
+    InMemoryConversationRepository::new()
+}
+
+fn service(conversations: &InMemoryConversationRepository) -> TestService {
+    ConversationService::new(
+        Arc::new(conversations.clone()),
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+}
+
+fn in_state(state: ConversationState) -> Conversation {
+    let mut conversation = Conversation::new(&DefaultClock);
+    match state {
+        ConversationState::Active => {}
+        ConversationState::Paused => conversation.pause(&DefaultClock).expect("pause"),
+        ConversationState::Completed => conversation.complete(&DefaultClock).expect("complete"),
+        ConversationState::Archived => conversation.archive(&DefaultClock).expect("archive"),
+    }
+    conversation
+}
+
+#[rstest]
+#[case::pause_active(ConversationState::Active, ConversationState::Paused, true)]
+#[case::pause_paused(ConversationState::Paused, ConversationState::Paused, false)]
+#[case::resume_paused(ConversationState::Paused, ConversationState::Active, true)]
+#[case::resume_active(ConversationState::Active, ConversationState::Active, false)]
+#[case::complete_active(ConversationState::Active, ConversationState::Completed, true)]
+#[case::complete_paused(ConversationState::Paused, ConversationState::Completed, true)]
+#[case::complete_completed(ConversationState::Completed, ConversationState::Completed, false)]
+#[case::complete_archived(ConversationState::Archived, ConversationState::Completed, false)]
+#[case::pause_completed(ConversationState::Completed, ConversationState::Paused, false)]
+#[case::archive_completed(ConversationState::Completed, ConversationState::Archived, true)]
+fn transitions_follow_the_state_machine(
+    #[case] from: ConversationState,
+    #[case] to: ConversationState,
+    #[case] allowed: bool,
+) {
+    let mut conversation = in_state(from);
+    let outcome = match to {
+        ConversationState::Active => conversation.resume(&DefaultClock),
+        ConversationState::Paused => conversation.pause(&DefaultClock),
+        ConversationState::Completed => conversation.complete(&DefaultClock),
+        ConversationState::Archived => conversation.archive(&DefaultClock),
+    };
+
+    if allowed {
+        assert_eq!(outcome, Ok(()));
+        assert_eq!(conversation.state(), to);
+    } else {
+        assert!(outcome.is_err());
+        assert_eq!(conversation.state(), from);
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn service_persists_transitions(
+    ctx: RequestContext,
+    conversations: InMemoryConversationRepository,
+) {
+    let service = service(&conversations);
+    let conversation = service.create_conversation(&ctx).await.expect("create");
+    let reason = OperatorReason::new("operator check").expect("valid reason");
+
+    service
+        .pause_conversation(&ctx, conversation.id(), &reason)
+        .await
+        .expect("pause");
+    service
+        .resume_conversation(&ctx, conversation.id(), &reason)
+        .await
+        .expect("resume");
+    let completed = service
+        .complete_conversation(&ctx, conversation.id())
+        .await
+        .expect("complete");
+    let refused = service
+        .pause_conversation(&ctx, conversation.id(), &reason)
+        .await;
+
+    assert_eq!(completed.state(), ConversationState::Completed);
+    assert!(matches!(
+        refused,
+        Err(ConversationServiceError::Lifecycle(
+            ConversationLifecycleError::InvalidTransition {
+                from: ConversationState::Completed,
+                to: ConversationState::Paused,
+                ..
+            }
+        ))
+    ));
+    let stored = conversations
+        .find_by_id(&ctx, conversation.id())
+        .await
+        .expect("find")
+        .expect("stored");
+    assert_eq!(stored, completed);
+}
+
+#[rstest]
+#[tokio::test]
+async fn service_updates_context_and_task_link_until_archived(
+    ctx: RequestContext,
+    conversations: InMemoryConversationRepository,
+) {
+    let service = service(&conversations);
+    let conversation = service.create_conversation(&ctx).await.expect("create");
+    let task_id = Uuid::new_v4();
+
+    service
+        .replace_context(
+            &ctx,
+            conversation.id(),
+            json!({ "repository": "corbusier" }),
+        )
+        .await
+        .expect("replace context");
+    let linked = service
+        .link_task(&ctx, conversation.id(), Some(task_id))
+        .await
+        .expect("link task");
+    service
+        .archive_conversation(&ctx, conversation.id())
+        .await
+        .expect("archive");
+    let refused = service.link_task(&ctx, conversation.id(), None).await;
+
+    assert_eq!(linked.context(), &json!({ "repository": "corbusier" }));
+    assert_eq!(linked.task_id(), Some(task_id));
+    assert!(matches!(
+        refused,
+        Err(ConversationServiceError::ConversationArchived(id)) if id == conversation.id()
+    ));
+}
@@ -0,0 +1,249 @@
+//! Unit tests for filtered, keyset-paginated conversation listings.
+
+use super::adapters_test_support::ctx;
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    adapters::memory::InMemoryConversationSummaries,
+    domain::{
+        ContentPart, Conversation, ConversationId, ConversationListFilter, ConversationListQuery,
+        ConversationSortKey, ConversationState, Message, MessageId, MessageMetadata, Role,
+        SequenceNumber, TextPart,
+    },
+    ports::conversation_list::{ConversationListError, ConversationListPort},
+};
+use crate::pagination::{Cursor, Limit, SortOrder};
+use chrono::{DateTime, TimeZone, Utc};
+use rstest::{fixture, rstest};
+use uuid::Uuid;
+
+fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 4, 2, hour, minute, 0)
+        .single()
+        .expect("valid timestamp")
+}
+
+fn limit(value: u32) -> Limit {
+    Limit::new(value).expect("valid limit")
+}
+
+fn reply(conversation_id: ConversationId, backend: &str, created_at: DateTime<Utc>) -> Message {
+    Message::from_persisted(
+        MessageId::new(),
+        conversation_id,
+        Role::Assistant,
+        vec![ContentPart::Text(TextPart::new("done"))],
+        MessageMetadata::with_agent_backend(backend),
+        created_at,
+        SequenceNumber::new(1),
+    )
+    .expect("valid message")
+}
+
+/// Three conversations for one tenant:
+///
+/// - `tagged`: active, created 09:00, task-linked with label `bug`, last
+///   replied to by `codex_cli` at 12:00
+/// - `paused`: paused, created and last active at 10:00
+/// - `recent`: active, created 11:00, last replied to by `claude_code_sdk`
+///   at 11:30
+struct Listing {
+    summaries: InMemoryConversationSummaries,
+    ctx: RequestContext,
+    task_id: Uuid,
+    tagged: ConversationId,
+    paused: ConversationId,
+    recent: ConversationId,
+}
+
+#[fixture]
+fn listing(ctx: RequestContext) -> Listing {
+    let summaries = InMemoryConversationSummaries::new();
+    let task_id = Uuid::new_v4();
+    let conversations = [
+        (ConversationState::Active, at(9, 0)),
+        (ConversationState::Paused, at(10, 0)),
+        (ConversationState::Active, at(11, 0)),
+    ]
+    .map(|(state, created_at)| {
+        let conversation =
+            Conversation::from_persisted(ConversationId::new(), state, created_at, created_at);
+        summaries
+            .record_conversation(&ctx, &conversation)
+            .expect("record conversation");
+        conversation.id()
+    });
+    let [tagged, paused, recent] = conversations;
+    summaries
+        .record_message(&ctx, &reply(tagged, "codex_cli", at(12, 0)))
+        .expect("record message");
+    summaries
+        .record_message(&ctx, &reply(recent, "claude_code_sdk", at(11, 30)))
+        .expect("record message");
+    summaries
+        .record_task_labels(&ctx, task_id, vec!["bug".to_owned()])
+        .expect("record task labels");
+    summaries
+        .link_task(&ctx, tagged, task_id)
+        .expect("link task");
+
+    Listing {
+        summaries,
+        ctx,
+        task_id,
+        tagged,
+        paused,
+        recent,
+    }
+}
+
+async fn listed_ids(listing: &Listing, query: &ConversationListQuery) -> Vec<ConversationId> {
+    listing
+        .summaries
+        .list_conversations(&listing.ctx, query)
+        .await
+        .expect("list conversations")
+        .iter()
+        .map(|summary| summary.conversation_id)
+        .collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn default_listing_orders_by_most_recent_activity(listing: Listing) {
+    let page = listing
+        .summaries
+        .list_conversations(&listing.ctx, &ConversationListQuery::new(limit(10)))
+        .await
+        .expect("list conversations");
+
+    let ids = page
+        .iter()
+        .map(|summary| summary.conversation_id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![listing.tagged, listing.recent, listing.paused]);
+    assert!(page.is_last());
+    let tagged = page.first().expect("tagged summary");
+    assert_eq!(tagged.message_count, 1);
+    assert_eq!(tagged.agent_backend.as_deref(), Some("codex_cli"));
+    assert_eq!(tagged.last_activity_at, at(12, 0));
+}
+
+#[rstest]
+#[tokio::test]
+async fn filters_narrow_the_listing(listing: Listing) {
+    let cases = [
+        (
+            ConversationListFilter {
+                state: Some(ConversationState::Active),
+                ..ConversationListFilter::default()
+            },
+            vec![listing.tagged, listing.recent],
+        ),
+        (
+            ConversationListFilter {
+                agent_backend: Some("claude_code_sdk".to_owned()),
+                ..ConversationListFilter::default()
+            },
+            vec![listing.recent],
+        ),
+        (
+            ConversationListFilter {
+                task_id: Some(listing.task_id),
+                ..ConversationListFilter::default()
+            },
+            vec![listing.tagged],
+        ),
+        (
+            ConversationListFilter {
+                label: Some("bug".to_owned()),
+                ..ConversationListFilter::default()
+            },
+            vec![listing.tagged],
+        ),
+        (
+            ConversationListFilter {
+                last_activity_from: Some(at(10, 30)),
+                last_activity_until: Some(at(12, 0)),
+                ..ConversationListFilter::default()
+            },
+            vec![listing.recent],
+        ),
+    ];
+
+    for (filter, expected) in cases {
+        let query = ConversationListQuery::new(limit(10)).with_filter(filter.clone());
+        assert_eq!(listed_ids(&listing, &query).await, expected, "{filter:?}");
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn cursor_pages_through_listing_without_gaps(listing: Listing) {
+    let base = ConversationListQuery::new(limit(1))
+        .sorted_by(ConversationSortKey::CreatedAt, SortOrder::Ascending);
+    let mut query = base.clone();
+    let mut seen = Vec::new();
+
+    loop {
+        let page = listing
+            .summaries
+            .list_conversations(&listing.ctx, &query)
+            .await
+            .expect("list conversations");
+        seen.extend(page.iter().map(|summary| summary.conversation_id));
+        let Some(cursor) = page.next_cursor() else {
+            break;
+        };
+        query = base.clone().with_cursor(cursor);
+    }
+
+    assert_eq!(seen, vec![listing.tagged, listing.paused, listing.recent]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn listing_is_scoped_to_the_request_tenant(listing: Listing) {
+    let other_tenant = RequestContext::new(
+        TenantId::new(),
+        listing.ctx.correlation_id(),
+        listing.ctx.user_id(),
+        listing.ctx.session_id(),
+    );
+
+    let page = listing
+        .summaries
+        .list_conversations(&other_tenant, &ConversationListQuery::new(limit(10)))
+        .await
+        .expect("list conversations");
+
+    assert!(page.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn inverted_activity_range_is_rejected(listing: Listing) {
+    let query = ConversationListQuery::new(limit(10)).with_filter(ConversationListFilter {
+        last_activity_from: Some(at(12, 0)),
+        last_activity_until: Some(at(9, 0)),
+        ..ConversationListFilter::default()
+    });
+
+    let result = listing
+        .summaries
+        .list_conversations(&listing.ctx, &query)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ConversationListError::InvalidActivityRange { .. })
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn cursor_from_another_listing_yields_an_empty_page(listing: Listing) {
+    let query =
+        ConversationListQuery::new(limit(10)).with_cursor(Cursor::at_number(1, Uuid::nil()));
+
+    assert!(listed_ids(&listing, &query).await.is_empty());
+}
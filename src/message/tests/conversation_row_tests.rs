@@ -40,6 +40,7 @@ fn new_conversation_has_empty_context() {
     assert!(conv.context.is_object());
     let context_obj = conv.context.as_object().expect("context should be object");
     assert!(context_obj.is_empty());
+    assert_eq!(conv.labels, json!({}));
 }
 
 // ============================================================================
@@ -62,8 +63,10 @@ fn conversation_row_struct_holds_all_fields() {
         task_id,
         context: context.clone(),
         state: state.clone(),
+        labels: json!({"project": "apollo"}),
         created_at,
         updated_at,
+        version: 3,
     };
 
     assert_eq!(row.id, id);
@@ -71,8 +74,10 @@ fn conversation_row_struct_holds_all_fields() {
     assert_eq!(row.task_id, task_id);
     assert_eq!(row.context, context);
     assert_eq!(row.state, state);
+    assert_eq!(row.labels, json!({"project": "apollo"}));
     assert_eq!(row.created_at, created_at);
     assert_eq!(row.updated_at, updated_at);
+    assert_eq!(row.version, 3);
 }
 
 #[rstest]
@@ -83,8 +88,10 @@ fn conversation_row_clone_preserves_fields() {
         task_id: None,
         context: json!({}),
         state: "completed".to_owned(),
+        labels: json!({}),
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        version: 0,
     };
 
     let cloned = row.clone();
@@ -106,8 +113,10 @@ fn conversation_row_debug_format() {
         task_id: None,
         context: json!({}),
         state: "active".to_owned(),
+        labels: json!({}),
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        version: 0,
     };
 
     let debug_str = format!("{row:?}");
@@ -0,0 +1,248 @@
+//! Unit tests for moving conversations between tenants.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::{
+    adapters::memory::{InMemoryConversationRepository, InMemoryConversationTransferAdapter},
+    domain::{
+        Conversation, ConversationAccess, ConversationId, ConversationTransfer,
+        ConversationTransferRefused, ConversationTransferRequest,
+    },
+    ports::{ConversationRepository, ConversationTransferError},
+    services::{ConversationTransferService, ConversationTransferServiceError},
+};
+use crate::operator::{
+    adapters::InMemoryOperatorActionRepository,
+    domain::{OperatorActionKind, OperatorActionQuery, OperatorReason, OperatorTransition},
+    ports::OperatorActionRepository,
+};
+use crate::pagination::PageRequest;
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+fn request(
+    conversation_id: ConversationId,
+    target_tenant_id: TenantId,
+) -> ConversationTransferRequest {
+    ConversationTransferRequest {
+        conversation_id,
+        target_tenant_id,
+        reason: OperatorReason::new("customer account merge").expect("valid reason"),
+    }
+}
+
+fn in_tenant(ctx: &RequestContext, tenant_id: TenantId) -> RequestContext {
+    RequestContext::new(
+        tenant_id,
+        ctx.correlation_id(),
+        ctx.user_id(),
+        ctx.session_id(),
+    )
+}
+
+struct Harness {
+    conversations: InMemoryConversationRepository,
+    audit: Arc<InMemoryOperatorActionRepository>,
+    service: ConversationTransferService,
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let conversations = InMemoryConversationRepository::new();
+    let audit = Arc::new(InMemoryOperatorActionRepository::new());
+    let service = ConversationTransferService::new(
+        Arc::new(InMemoryConversationTransferAdapter::new(
+            conversations.clone(),
+            Arc::clone(&audit) as Arc<dyn OperatorActionRepository>,
+        )),
+        Arc::new(DefaultClock),
+    );
+    Harness {
+        conversations,
+        audit,
+        service,
+    }
+}
+
+#[rstest]
+fn transfers_require_elevated_access(ctx: RequestContext) {
+    let conversation_id = ConversationId::new();
+
+    let refused = ConversationTransfer::new(
+        &ctx,
+        request(conversation_id, TenantId::new()),
+        ConversationAccess::Standard,
+        &DefaultClock,
+    );
+
+    assert_eq!(
+        refused,
+        Err(ConversationTransferRefused::ElevatedAccessRequired(
+            conversation_id
+        ))
+    );
+}
+
+#[rstest]
+fn transfers_into_the_owning_tenant_are_refused(ctx: RequestContext) {
+    let conversation_id = ConversationId::new();
+
+    let refused = ConversationTransfer::new(
+        &ctx,
+        request(conversation_id, ctx.tenant_id()),
+        ConversationAccess::Elevated,
+        &DefaultClock,
+    );
+
+    assert_eq!(
+        refused,
+        Err(ConversationTransferRefused::SameTenant {
+            conversation_id,
+            tenant_id: ctx.tenant_id(),
+        })
+    );
+}
+
+#[rstest]
+fn accepted_transfers_describe_the_move_on_both_sides(ctx: RequestContext) {
+    let target = TenantId::new();
+
+    let transfer = ConversationTransfer::new(
+        &ctx,
+        request(ConversationId::new(), target),
+        ConversationAccess::Elevated,
+        &DefaultClock,
+    )
+    .expect("accepted transfer");
+
+    let expected = Some(OperatorTransition::new(ctx.tenant_id(), target));
+    assert_eq!(
+        transfer.outgoing.kind,
+        OperatorActionKind::ConversationTransferredOut
+    );
+    assert_eq!(
+        transfer.incoming.kind,
+        OperatorActionKind::ConversationTransferredIn
+    );
+    assert_eq!(transfer.outgoing.transition, expected);
+    assert_eq!(transfer.incoming.transition, expected);
+    assert_eq!(transfer.outgoing.occurred_at, transfer.incoming.occurred_at);
+    assert_ne!(transfer.outgoing.id, transfer.incoming.id);
+}
+
+#[rstest]
+#[tokio::test]
+async fn transfers_move_the_conversation_and_audit_both_tenants(
+    ctx: RequestContext,
+    harness: Harness,
+) {
+    let conversation = Conversation::new(&DefaultClock);
+    harness
+        .conversations
+        .store(&ctx, &conversation)
+        .await
+        .expect("store conversation");
+    let target_ctx = in_tenant(&ctx, TenantId::new());
+
+    harness
+        .service
+        .transfer(
+            &ctx,
+            request(conversation.id(), target_ctx.tenant_id()),
+            ConversationAccess::Elevated,
+        )
+        .await
+        .expect("transfer");
+
+    let source_copy = harness
+        .conversations
+        .find_by_id(&ctx, conversation.id())
+        .await
+        .expect("source lookup");
+    let target_copy = harness
+        .conversations
+        .find_by_id(&target_ctx, conversation.id())
+        .await
+        .expect("target lookup");
+    assert!(source_copy.is_none());
+    assert_eq!(target_copy.map(|found| found.id()), Some(conversation.id()));
+    for (tenant_ctx, kind) in [
+        (&ctx, OperatorActionKind::ConversationTransferredOut),
+        (&target_ctx, OperatorActionKind::ConversationTransferredIn),
+    ] {
+        let timeline = harness
+            .audit
+            .query(
+                tenant_ctx,
+                OperatorActionQuery::for_conversation(conversation.id()),
+                PageRequest::default(),
+            )
+            .await
+            .expect("timeline");
+        let kinds: Vec<_> = timeline.iter().map(|action| action.kind).collect();
+        assert_eq!(kinds, [kind]);
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn task_linked_conversations_stay_with_their_tenant(ctx: RequestContext, harness: Harness) {
+    let task_id = Uuid::new_v4();
+    let conversation = Conversation::new(&DefaultClock).with_task(task_id);
+    harness
+        .conversations
+        .store(&ctx, &conversation)
+        .await
+        .expect("store conversation");
+
+    let refused = harness
+        .service
+        .transfer(
+            &ctx,
+            request(conversation.id(), TenantId::new()),
+            ConversationAccess::Elevated,
+        )
+        .await;
+
+    assert!(matches!(
+        refused,
+        Err(ConversationTransferServiceError::Repository(
+            ConversationTransferError::LinkedToTask { task_id: linked, .. }
+        )) if linked == task_id
+    ));
+    let still_owned = harness
+        .conversations
+        .find_by_id(&ctx, conversation.id())
+        .await
+        .expect("source lookup");
+    assert!(still_owned.is_some());
+}
+
+#[rstest]
+#[tokio::test]
+async fn transferring_an_unknown_conversation_fails(ctx: RequestContext, harness: Harness) {
+    let conversation_id = ConversationId::new();
+
+    let refused = harness
+        .service
+        .transfer(
+            &ctx,
+            request(conversation_id, TenantId::new()),
+            ConversationAccess::Elevated,
+        )
+        .await;
+
+    assert!(matches!(
+        refused,
+        Err(ConversationTransferServiceError::Repository(
+            ConversationTransferError::NotFound(missing)
+        )) if missing == conversation_id
+    ));
+}
@@ -0,0 +1,191 @@
+//! Unit tests for custom content part types.
+
+use super::validation_fixtures::{default_validator, message_factory};
+use crate::message::{
+    adapters::memory::{InMemoryConversationRepository, InMemoryMessageRepository},
+    domain::{
+        ContentPart, ContentTypeRegistry, CustomContent, CustomContentError, CustomPart, Message,
+        MessageBuilderError, Role,
+    },
+    error::ValidationError,
+    ports::validator::MessageValidator,
+    services::{AppendMessageRequest, ConversationService},
+    validation::service::DefaultMessageValidator,
+};
+use crate::pagination::PageRequest;
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CadReference {
+    model: String,
+    revision: u32,
+}
+
+impl CustomContent for CadReference {
+    const KIND: &'static str = "cad_ref";
+
+    fn validate(&self) -> Result<(), String> {
+        if self.revision == 0 {
+            return Err("revision must be positive".to_owned());
+        }
+        Ok(())
+    }
+
+    fn render_prompt(&self) -> String {
+        format!("[CAD model {} rev {}]", self.model, self.revision)
+    }
+}
+
+fn cad_part(revision: u32) -> CustomPart {
+    CustomPart::encode(&CadReference {
+        model: "bracket".to_owned(),
+        revision,
+    })
+    .expect("CAD reference should encode")
+}
+
+#[fixture]
+fn registry() -> ContentTypeRegistry {
+    let mut registry = ContentTypeRegistry::new();
+    registry
+        .register::<CadReference>()
+        .expect("first registration should succeed");
+    registry
+}
+
+#[rstest]
+fn custom_part_serializes_with_kind_and_data() {
+    let part = ContentPart::Custom(cad_part(3));
+
+    let value = serde_json::to_value(&part).expect("part should serialize");
+
+    assert_eq!(
+        value,
+        json!({
+            "type": "custom",
+            "kind": "cad_ref",
+            "data": { "model": "bracket", "revision": 3 }
+        })
+    );
+    let restored: ContentPart = serde_json::from_value(value).expect("part should deserialize");
+    assert_eq!(restored, part);
+}
+
+#[rstest]
+fn decode_rejects_a_different_kind() {
+    let part = CustomPart::new("spreadsheet", json!({}));
+
+    let result = part.decode::<CadReference>();
+
+    assert_eq!(
+        result,
+        Err(CustomContentError::KindMismatch {
+            expected: "cad_ref".to_owned(),
+            actual: "spreadsheet".to_owned(),
+        })
+    );
+}
+
+#[rstest]
+fn registering_a_kind_twice_fails(mut registry: ContentTypeRegistry) {
+    let result = registry.register::<CadReference>();
+
+    assert_eq!(
+        result,
+        Err(CustomContentError::DuplicateKind("cad_ref".to_owned()))
+    );
+}
+
+#[rstest]
+fn registry_renders_registered_parts_for_prompts(registry: ContentTypeRegistry) {
+    let rendered = registry.render_prompt(&cad_part(7));
+
+    assert_eq!(rendered.as_deref(), Ok("[CAD model bracket rev 7]"));
+}
+
+#[rstest]
+fn validator_without_registry_rejects_custom_parts(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+) {
+    let message = message_factory(Role::User, vec![ContentPart::Custom(cad_part(1))])
+        .expect("test message should build");
+
+    let result = default_validator.validate(&message);
+
+    assert!(matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 0, .. })
+    ));
+}
+
+#[rstest]
+#[case::valid(cad_part(2), true)]
+#[case::fails_type_rule(cad_part(0), false)]
+#[case::malformed(CustomPart::new("cad_ref", json!({ "model": 5 })), false)]
+#[case::unknown_kind(CustomPart::new("spreadsheet", json!({})), false)]
+#[case::blank_kind(CustomPart::new("  ", json!({})), false)]
+fn validator_with_registry_checks_custom_parts(
+    registry: ContentTypeRegistry,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] part: CustomPart,
+    #[case] accepted: bool,
+) {
+    let validator = DefaultMessageValidator::new().with_content_types(Arc::new(registry));
+    let message = message_factory(Role::User, vec![ContentPart::Custom(part)])
+        .expect("test message should build");
+
+    let result = validator.validate(&message);
+
+    assert_eq!(result.is_ok(), accepted, "unexpected result: {result:?}");
+}
+
+#[rstest]
+#[tokio::test]
+async fn custom_parts_round_trip_through_storage(registry: ContentTypeRegistry) {
+    let ctx = test_request_ctx();
+    let service = ConversationService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(DefaultMessageValidator::new().with_content_types(Arc::new(registry))),
+        Arc::new(DefaultClock),
+    );
+    let conversation = service.create_conversation(&ctx).await.expect("create");
+
+    service
+        .append_message(
+            &ctx,
+            AppendMessageRequest::new(
+                conversation.id(),
+                Role::User,
+                vec![ContentPart::Custom(cad_part(4))],
+            ),
+        )
+        .await
+        .expect("append");
+    let history = service
+        .history(&ctx, conversation.id(), PageRequest::default())
+        .await
+        .expect("history");
+
+    let decoded: Vec<CadReference> = history
+        .iter()
+        .flat_map(Message::content)
+        .filter_map(|part| match part {
+            ContentPart::Custom(custom) => custom.decode().ok(),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        decoded,
+        vec![CadReference {
+            model: "bracket".to_owned(),
+            revision: 4,
+        }]
+    );
+}
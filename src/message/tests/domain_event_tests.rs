@@ -26,6 +26,7 @@ fn domain_event_row_struct_holds_all_fields() {
     let causation_id = Some(Uuid::new_v4());
     let user_id = Some(Uuid::new_v4());
     let session_id = Some(Uuid::new_v4());
+    let position = 42;
 
     let row = DomainEventRow {
         id,
@@ -39,6 +40,7 @@ fn domain_event_row_struct_holds_all_fields() {
         causation_id,
         user_id,
         session_id,
+        position,
     };
 
     assert_eq!(row.id, id);
@@ -52,6 +54,7 @@ fn domain_event_row_struct_holds_all_fields() {
     assert_eq!(row.causation_id, causation_id);
     assert_eq!(row.user_id, user_id);
     assert_eq!(row.session_id, session_id);
+    assert_eq!(row.position, position);
 }
 
 #[rstest]
@@ -68,6 +71,7 @@ fn domain_event_row_with_no_audit_fields() {
         causation_id: None,
         user_id: None,
         session_id: None,
+        position: 1,
     };
 
     assert!(row.correlation_id.is_none());
@@ -90,6 +94,7 @@ fn domain_event_row_clone_preserves_all_fields() {
         causation_id: None,
         user_id: Some(Uuid::new_v4()),
         session_id: None,
+        position: 1,
     };
 
     let cloned = row.clone();
@@ -105,6 +110,7 @@ fn domain_event_row_clone_preserves_all_fields() {
     assert_eq!(cloned.causation_id, row.causation_id);
     assert_eq!(cloned.user_id, row.user_id);
     assert_eq!(cloned.session_id, row.session_id);
+    assert_eq!(cloned.position, row.position);
 }
 
 #[rstest]
@@ -121,6 +127,7 @@ fn domain_event_row_debug_format() {
         causation_id: None,
         user_id: None,
         session_id: None,
+        position: 1,
     };
 
     let debug_str = format!("{row:?}");
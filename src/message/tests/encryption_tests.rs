@@ -0,0 +1,216 @@
+//! Tests for field-level encryption of message content.
+//!
+//! Covers the AES-GCM envelope cipher and the codec the `PostgreSQL`
+//! repositories use to seal and open message rows.
+
+use crate::message::{
+    adapters::{
+        encryption::AesGcmContentCipher,
+        models::{MessageRow, NewMessage},
+        postgres::sealing::MessageCodec,
+    },
+    domain::{
+        ContentPart, ConversationId, EncryptionKeyId, Message, Role, SequenceNumber, TextPart,
+    },
+    error::RepositoryError,
+    ports::content_cipher::{CipherError, ContentCipher},
+};
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn key_id(value: &str) -> EncryptionKeyId {
+    EncryptionKeyId::new(value).expect("valid key id")
+}
+
+#[fixture]
+fn cipher() -> AesGcmContentCipher {
+    AesGcmContentCipher::new(key_id("current"), [7; 32])
+}
+
+#[fixture]
+fn message() -> Message {
+    Message::new(
+        ConversationId::new(),
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("my home address"))],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+/// Reads a written row back as the database would return it.
+fn stored(row: NewMessage) -> MessageRow {
+    MessageRow {
+        id: row.id,
+        tenant_id: row.tenant_id,
+        conversation_id: row.conversation_id,
+        role: row.role,
+        content: row.content,
+        metadata: row.metadata,
+        created_at: row.created_at,
+        sequence_number: row.sequence_number,
+    }
+}
+
+#[rstest]
+#[case("content-2026-10")]
+#[case("a")]
+#[case("key_v2.1")]
+fn key_ids_accept_safe_names(#[case] value: &str) {
+    assert_eq!(key_id(value).as_str(), value);
+}
+
+#[rstest]
+#[case("")]
+#[case("has space")]
+#[case("slash/key")]
+#[case(&"k".repeat(65))]
+fn key_ids_reject_unsafe_names(#[case] value: &str) {
+    assert!(EncryptionKeyId::new(value).is_err());
+}
+
+#[rstest]
+fn sealing_tags_the_active_key_and_round_trips(cipher: AesGcmContentCipher) {
+    let sealed = cipher.seal(b"secret", b"aad").expect("seal");
+
+    assert_eq!(sealed.key_id, key_id("current"));
+    assert_ne!(sealed.ciphertext, b"secret");
+    assert_eq!(cipher.open(&sealed, b"aad").expect("open"), b"secret");
+}
+
+#[rstest]
+fn sealing_twice_uses_fresh_keys_and_nonces(cipher: AesGcmContentCipher) {
+    let first = cipher.seal(b"secret", b"aad").expect("seal");
+    let second = cipher.seal(b"secret", b"aad").expect("seal");
+
+    assert_ne!(first.wrapped_key, second.wrapped_key);
+    assert_ne!(first.ciphertext, second.ciphertext);
+}
+
+#[rstest]
+fn opening_with_other_associated_data_fails(cipher: AesGcmContentCipher) {
+    let sealed = cipher.seal(b"secret", b"message-1").expect("seal");
+
+    let result = cipher.open(&sealed, b"message-2");
+
+    assert!(matches!(result, Err(CipherError::Decryption(_))));
+}
+
+#[rstest]
+fn opening_a_tampered_value_fails(cipher: AesGcmContentCipher) {
+    let mut sealed = cipher.seal(b"secret", b"aad").expect("seal");
+    if let Some(byte) = sealed.ciphertext.first_mut() {
+        *byte ^= 1;
+    }
+
+    assert!(matches!(
+        cipher.open(&sealed, b"aad"),
+        Err(CipherError::Decryption(_))
+    ));
+}
+
+#[rstest]
+fn values_sealed_under_a_retired_key_still_open(cipher: AesGcmContentCipher) {
+    let sealed = cipher.seal(b"secret", b"aad").expect("seal");
+    let rotated =
+        AesGcmContentCipher::new(key_id("next"), [9; 32]).with_key(key_id("current"), [7; 32]);
+
+    assert_eq!(rotated.open(&sealed, b"aad").expect("open"), b"secret");
+    assert_eq!(
+        rotated.seal(b"secret", b"aad").expect("seal").key_id,
+        key_id("next")
+    );
+}
+
+#[rstest]
+fn opening_under_an_unknown_key_fails(cipher: AesGcmContentCipher) {
+    let sealed = cipher.seal(b"secret", b"aad").expect("seal");
+    let other = AesGcmContentCipher::new(key_id("next"), [9; 32]);
+
+    assert_eq!(
+        other.open(&sealed, b"aad"),
+        Err(CipherError::UnknownKey(key_id("current")))
+    );
+}
+
+#[rstest]
+fn the_codec_seals_content_and_metadata_and_opens_them(
+    cipher: AesGcmContentCipher,
+    message: Message,
+) {
+    let codec = MessageCodec::sealed(Arc::new(cipher));
+    let plain = NewMessage::try_from_domain(&message, Uuid::new_v4()).expect("row");
+
+    let row = codec.seal(plain).expect("seal");
+
+    assert!(row.content.get("$sealed").is_some());
+    assert!(row.metadata.get("$sealed").is_some());
+    assert!(!row.content.to_string().contains("home address"));
+    assert_eq!(codec.to_message(stored(row)).expect("open"), message);
+}
+
+#[rstest]
+fn the_pinned_flag_stays_beside_the_envelope(cipher: AesGcmContentCipher, message: Message) {
+    let codec = MessageCodec::sealed(Arc::new(cipher));
+    let pinned = message.with_pinned(true);
+
+    let row = codec
+        .seal(NewMessage::try_from_domain(&pinned, Uuid::new_v4()).expect("row"))
+        .expect("seal");
+
+    assert_eq!(row.metadata.get("pinned"), Some(&json!(true)));
+    assert!(
+        codec
+            .to_message(stored(row.clone()))
+            .expect("open")
+            .is_pinned()
+    );
+    let mut unpinned = stored(row);
+    if let Some(fields) = unpinned.metadata.as_object_mut() {
+        fields.remove("pinned");
+    }
+    assert!(!codec.to_message(unpinned).expect("open").is_pinned());
+}
+
+#[rstest]
+fn the_codec_reads_unsealed_rows_as_they_are(cipher: AesGcmContentCipher, message: Message) {
+    let codec = MessageCodec::sealed(Arc::new(cipher));
+    let plain = NewMessage::try_from_domain(&message, Uuid::new_v4()).expect("row");
+
+    assert_eq!(codec.to_message(stored(plain)).expect("read"), message);
+}
+
+#[rstest]
+fn sealed_rows_cannot_be_read_without_a_cipher(cipher: AesGcmContentCipher, message: Message) {
+    let row = MessageCodec::sealed(Arc::new(cipher))
+        .seal(NewMessage::try_from_domain(&message, Uuid::new_v4()).expect("row"))
+        .expect("seal");
+
+    let result = MessageCodec::plain().to_message(stored(row));
+
+    assert!(matches!(result, Err(RepositoryError::Serialization(_))));
+}
+
+#[rstest]
+fn sealed_values_do_not_open_in_another_row(cipher: AesGcmContentCipher, message: Message) {
+    let codec = MessageCodec::sealed(Arc::new(cipher));
+    let sealed = codec
+        .seal(NewMessage::try_from_domain(&message, Uuid::new_v4()).expect("row"))
+        .expect("seal");
+    let moved = MessageRow {
+        id: Uuid::new_v4(),
+        metadata: json!({}),
+        ..stored(sealed)
+    };
+
+    let result = codec.to_message(moved);
+
+    assert!(matches!(
+        result,
+        Err(RepositoryError::Cipher(CipherError::Decryption(_)))
+    ));
+}
@@ -0,0 +1,157 @@
+//! Unit tests for event wire formats.
+
+use crate::message::{
+    error::EventCodecError,
+    versioning::{EventCodecs, EventFormat, EventMetadata, VersionedEvent},
+};
+use rstest::rstest;
+use serde_json::json;
+
+fn scored(format: EventFormat) -> VersionedEvent {
+    VersionedEvent::with_metadata(
+        2,
+        "Scored",
+        json!({ "points": 4, "tags": ["a", "b"], "ratio": 0.5 }),
+        EventMetadata::now()
+            .with_correlation_id("req-1")
+            .with_format(format),
+    )
+}
+
+fn assert_same_event(decoded: &VersionedEvent, original: &VersionedEvent) {
+    assert_eq!(decoded.version(), original.version());
+    assert_eq!(decoded.event_type(), original.event_type());
+    assert_eq!(decoded.data(), original.data());
+    assert_eq!(
+        decoded.metadata().occurred_at,
+        original.metadata().occurred_at
+    );
+    assert_eq!(
+        decoded.metadata().correlation_id,
+        original.metadata().correlation_id
+    );
+}
+
+#[rstest]
+fn json_events_round_trip() {
+    let codecs = EventCodecs::new();
+    let event = scored(EventFormat::Json);
+
+    let decoded = codecs
+        .decode(&codecs.encode(&event).expect("encode"))
+        .expect("decode");
+
+    assert_same_event(&decoded, &event);
+    assert_eq!(decoded.metadata().format, EventFormat::Json);
+}
+
+#[rstest]
+fn json_events_are_written_without_a_format_tag() {
+    let bytes = EventCodecs::new()
+        .encode(&scored(EventFormat::Json))
+        .expect("encode");
+
+    let value: serde_json::Value = serde_json::from_slice(&bytes).expect("json");
+
+    assert!(value.pointer("/metadata/format").is_none(), "{value}");
+}
+
+#[rstest]
+#[case::json_object(b"{}".as_slice(), Some(EventFormat::Json))]
+#[case::json_after_whitespace(b"\n  {}".as_slice(), Some(EventFormat::Json))]
+#[case::cbor_map(&[0xa4, 0x00], Some(EventFormat::Cbor))]
+#[case::msgpack_fixmap(&[0x84, 0x00], Some(EventFormat::MessagePack))]
+#[case::msgpack_map16(&[0xde, 0x00, 0x04], Some(EventFormat::MessagePack))]
+#[case::json_array(b"[]".as_slice(), None)]
+#[case::empty(&[], None)]
+fn formats_are_detected_from_the_first_byte(
+    #[case] bytes: &[u8],
+    #[case] expected: Option<EventFormat>,
+) {
+    assert_eq!(EventFormat::detect(bytes), expected);
+}
+
+#[rstest]
+fn unrecognised_bytes_are_rejected() {
+    let result = EventCodecs::new().decode(b"not an event");
+
+    assert!(matches!(result, Err(EventCodecError::UnrecognisedFormat)));
+}
+
+#[rstest]
+fn malformed_json_is_a_decode_error() {
+    let result = EventCodecs::new().decode(b"{\"version\": 1}");
+
+    assert!(matches!(
+        result,
+        Err(EventCodecError::Decode {
+            format: EventFormat::Json,
+            ..
+        })
+    ));
+}
+
+#[rstest]
+#[case(EventFormat::Cbor)]
+#[case(EventFormat::MessagePack)]
+fn formats_without_a_codec_are_reported(#[case] format: EventFormat) {
+    let codecs = EventCodecs::json_only();
+
+    let result = codecs.encode(&scored(format));
+
+    assert!(!codecs.supports(format));
+    assert!(matches!(
+        result,
+        Err(EventCodecError::UnsupportedFormat(unsupported)) if unsupported == format
+    ));
+}
+
+#[cfg(feature = "cbor")]
+#[rstest]
+fn cbor_events_round_trip() {
+    let codecs = EventCodecs::new();
+    let event = scored(EventFormat::Cbor);
+
+    let bytes = codecs.encode(&event).expect("encode");
+    let decoded = codecs.decode(&bytes).expect("decode");
+
+    assert_eq!(EventFormat::detect(&bytes), Some(EventFormat::Cbor));
+    assert_same_event(&decoded, &event);
+    assert_eq!(decoded.metadata().format, EventFormat::Cbor);
+}
+
+#[cfg(feature = "msgpack")]
+#[rstest]
+fn message_pack_events_round_trip() {
+    let codecs = EventCodecs::new();
+    let event = scored(EventFormat::MessagePack);
+
+    let bytes = codecs.encode(&event).expect("encode");
+    let decoded = codecs.decode(&bytes).expect("decode");
+
+    assert_eq!(EventFormat::detect(&bytes), Some(EventFormat::MessagePack));
+    assert_same_event(&decoded, &event);
+    assert_eq!(decoded.metadata().format, EventFormat::MessagePack);
+}
+
+#[cfg(all(feature = "cbor", feature = "msgpack"))]
+#[rstest]
+fn a_mixed_store_reads_back_in_every_format() {
+    let codecs = EventCodecs::new();
+    let formats = [
+        EventFormat::Json,
+        EventFormat::Cbor,
+        EventFormat::MessagePack,
+    ];
+    let stored: Vec<Vec<u8>> = formats
+        .iter()
+        .map(|&format| codecs.encode(&scored(format)).expect("encode"))
+        .collect();
+
+    let read: Vec<EventFormat> = stored
+        .iter()
+        .map(|bytes| codecs.decode(bytes).expect("decode").metadata().format)
+        .collect();
+
+    assert_eq!(read, formats);
+}
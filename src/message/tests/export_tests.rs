@@ -0,0 +1,280 @@
+//! Unit tests for conversation exports.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::{
+        export::{JsonlExportFormat, MarkdownExportFormat, OpenAiFineTuneExportFormat},
+        memory::{InMemoryConversationRepository, InMemoryMessageRepository},
+    },
+    domain::{
+        ContentPart, Conversation, ConversationId, ExportFilter, Message, MessageRedaction,
+        ReasoningPart, RedactionReason, Role, SequenceNumber, TextPart, ToolCallPart,
+        ToolResultPart,
+    },
+    ports::{ConversationRepository, MessageRepository},
+    services::{ConversationExporter, ExportRequest, ExportServiceError},
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+struct Harness {
+    conversations: InMemoryConversationRepository,
+    messages: InMemoryMessageRepository,
+    exporter: ConversationExporter,
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let conversations = InMemoryConversationRepository::new();
+    let messages = InMemoryMessageRepository::new();
+    let exporter =
+        ConversationExporter::new(Arc::new(conversations.clone()), Arc::new(messages.clone()))
+            .with_format(Arc::new(JsonlExportFormat::new()))
+            .with_format(Arc::new(MarkdownExportFormat::new()))
+            .with_format(Arc::new(OpenAiFineTuneExportFormat::new()));
+    Harness {
+        conversations,
+        messages,
+        exporter,
+    }
+}
+
+fn message(
+    conversation_id: ConversationId,
+    role: Role,
+    sequence: u64,
+    parts: Vec<ContentPart>,
+) -> Message {
+    Message::new(
+        conversation_id,
+        role,
+        parts,
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+/// Stores a conversation of a user question, an assistant tool call with
+/// reasoning, its result, and the assistant's answer.
+async fn weather_conversation(ctx: &RequestContext, harness: &Harness) -> ConversationId {
+    let conversation = Conversation::new(&DefaultClock);
+    harness
+        .conversations
+        .store(ctx, &conversation)
+        .await
+        .expect("store conversation");
+    let id = conversation.id();
+    let history = [
+        message(
+            id,
+            Role::System,
+            1,
+            vec![ContentPart::Text(TextPart::new("Be terse."))],
+        ),
+        message(
+            id,
+            Role::User,
+            2,
+            vec![ContentPart::Text(TextPart::new("Weather?"))],
+        ),
+        message(
+            id,
+            Role::Assistant,
+            3,
+            vec![
+                ContentPart::Reasoning(ReasoningPart::new("Use the tool.")),
+                ContentPart::ToolCall(ToolCallPart::new(
+                    "call-1",
+                    "weather",
+                    json!({ "city": "Paris" }),
+                )),
+            ],
+        ),
+        message(
+            id,
+            Role::Tool,
+            4,
+            vec![ContentPart::ToolResult(ToolResultPart::success(
+                "call-1",
+                json!("18C"),
+            ))],
+        ),
+        message(
+            id,
+            Role::Assistant,
+            5,
+            vec![ContentPart::Text(TextPart::new("18C."))],
+        ),
+    ];
+    for stored in &history {
+        harness
+            .messages
+            .store(ctx, stored)
+            .await
+            .expect("store message");
+    }
+    id
+}
+
+async fn export(ctx: &RequestContext, harness: &Harness, request: &ExportRequest) -> String {
+    let mut output = Vec::new();
+    harness
+        .exporter
+        .export(ctx, request, &mut output)
+        .await
+        .expect("export succeeds");
+    String::from_utf8(output).expect("utf-8 export")
+}
+
+#[rstest]
+#[tokio::test]
+async fn jsonl_exports_read_back_as_messages(ctx: RequestContext, harness: Harness) {
+    let id = weather_conversation(&ctx, &harness).await;
+
+    let output = export(
+        &ctx,
+        &harness,
+        &ExportRequest::new(id, JsonlExportFormat::NAME),
+    )
+    .await;
+
+    let read: Vec<Message> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("message line"))
+        .collect();
+    let sequence_numbers: Vec<u64> = read
+        .iter()
+        .map(|message| message.sequence_number().value())
+        .collect();
+    assert_eq!(sequence_numbers, [1, 2, 3, 4, 5]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn markdown_exports_read_as_a_transcript(ctx: RequestContext, harness: Harness) {
+    let id = weather_conversation(&ctx, &harness).await;
+
+    let output = export(
+        &ctx,
+        &harness,
+        &ExportRequest::new(id, MarkdownExportFormat::NAME),
+    )
+    .await;
+
+    assert!(
+        output.starts_with(&format!("# Conversation {id}\n")),
+        "{output}"
+    );
+    assert!(output.contains("\n## User · #2 · "), "{output}");
+    assert!(output.contains("> Use the tool."), "{output}");
+    assert!(
+        output.contains("**Tool call** `weather` (`call-1`)"),
+        "{output}"
+    );
+    assert!(
+        output.contains("**Tool result** (`call-1`)\n\n```text\n18C\n```"),
+        "{output}"
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn fine_tune_exports_are_one_training_example(ctx: RequestContext, harness: Harness) {
+    let id = weather_conversation(&ctx, &harness).await;
+
+    let output = export(
+        &ctx,
+        &harness,
+        &ExportRequest::new(id, OpenAiFineTuneExportFormat::NAME),
+    )
+    .await;
+
+    assert_eq!(output.lines().count(), 1);
+    let example: Value = serde_json::from_str(&output).expect("json line");
+    assert_eq!(
+        example,
+        json!({ "messages": [
+            { "role": "system", "content": "Be terse." },
+            { "role": "user", "content": "Weather?" },
+            { "role": "assistant", "content": null, "tool_calls": [{
+                "id": "call-1", "type": "function",
+                "function": { "name": "weather", "arguments": "{\"city\":\"Paris\"}" }
+            }]},
+            { "role": "tool", "tool_call_id": "call-1", "content": "18C" },
+            { "role": "assistant", "content": "18C." }
+        ]})
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn filters_choose_roles_and_drop_redacted_messages(ctx: RequestContext, harness: Harness) {
+    let id = weather_conversation(&ctx, &harness).await;
+    let secret = message(
+        id,
+        Role::User,
+        6,
+        vec![ContentPart::Text(TextPart::new("sk-123"))],
+    );
+    let reason = RedactionReason::new("pasted API key").expect("valid reason");
+    let redaction = MessageRedaction::new(secret.id(), reason, ctx.user_id(), &DefaultClock);
+    harness
+        .messages
+        .store(&ctx, &secret.redacted(&redaction))
+        .await
+        .expect("store message");
+    let filter = ExportFilter::new()
+        .with_roles([Role::User])
+        .without_redacted();
+    let request = ExportRequest::new(id, JsonlExportFormat::NAME).with_filter(filter);
+
+    let report = harness
+        .exporter
+        .export(&ctx, &request, &mut Vec::new())
+        .await
+        .expect("export succeeds");
+
+    assert_eq!((report.exported, report.skipped), (1, 5));
+}
+
+#[rstest]
+#[tokio::test]
+async fn unknown_formats_and_conversations_are_refused(ctx: RequestContext, harness: Harness) {
+    let id = weather_conversation(&ctx, &harness).await;
+    let missing = ConversationId::new();
+
+    let unknown_format = harness
+        .exporter
+        .export(&ctx, &ExportRequest::new(id, "csv"), &mut Vec::new())
+        .await;
+    let unknown_conversation = harness
+        .exporter
+        .export(
+            &ctx,
+            &ExportRequest::new(missing, JsonlExportFormat::NAME),
+            &mut Vec::new(),
+        )
+        .await;
+
+    assert!(matches!(
+        unknown_format,
+        Err(ExportServiceError::UnknownFormat(name)) if name == "csv"
+    ));
+    assert!(matches!(
+        unknown_conversation,
+        Err(ExportServiceError::ConversationNotFound(found)) if found == missing
+    ));
+    assert_eq!(
+        harness.exporter.formats().collect::<Vec<_>>(),
+        ["jsonl", "markdown", "openai_fine_tune"]
+    );
+}
@@ -0,0 +1,231 @@
+//! Unit tests for human feedback on assistant messages.
+
+use super::adapters_test_support::ctx;
+use crate::context::{RequestContext, UserId};
+use crate::message::{
+    adapters::memory::{InMemoryMessageFeedbackRepository, InMemoryMessageRepository},
+    domain::{
+        AgentResponseAudit, AgentResponseStatus, ContentPart, ConversationId, FeedbackCategory,
+        FeedbackDomainError, FeedbackRating, FeedbackSentiment, FeedbackSubmission,
+        FeedbackSummary, MAX_FEEDBACK_COMMENT_CHARS, Message, MessageFeedback, MessageId,
+        MessageMetadata, Role, SequenceNumber, TextPart,
+    },
+    ports::MessageRepository,
+    services::{FeedbackServiceError, MessageFeedbackService, SubmitFeedbackRequest},
+};
+use chrono::Utc;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+type Service = MessageFeedbackService<
+    InMemoryMessageRepository,
+    InMemoryMessageFeedbackRepository,
+    DefaultClock,
+>;
+
+fn message(role: Role, metadata: MessageMetadata) -> Message {
+    Message::from_persisted(
+        MessageId::new(),
+        ConversationId::new(),
+        role,
+        vec![ContentPart::Text(TextPart::new("done"))],
+        metadata,
+        Utc::now(),
+        SequenceNumber::new(1),
+    )
+    .expect("valid message")
+}
+
+fn reply(backend: &str, version: &str) -> Message {
+    message(
+        Role::Assistant,
+        MessageMetadata::with_agent_backend(backend).with_agent_response_audit(
+            AgentResponseAudit::new(AgentResponseStatus::Completed)
+                .with_instruction_set_version(version),
+        ),
+    )
+}
+
+fn rate(target: &Message, rating: FeedbackRating) -> MessageFeedback {
+    MessageFeedback::for_message(
+        target,
+        UserId::new(),
+        FeedbackSubmission::new(rating),
+        &DefaultClock,
+    )
+    .expect("valid feedback")
+}
+
+async fn service_with(target: &Message, ctx: &RequestContext) -> Service {
+    let messages = InMemoryMessageRepository::new();
+    messages.store(ctx, target).await.expect("store message");
+    MessageFeedbackService::new(
+        Arc::new(messages),
+        Arc::new(InMemoryMessageFeedbackRepository::new()),
+        Arc::new(DefaultClock),
+    )
+}
+
+#[rstest]
+#[case(Role::User)]
+#[case(Role::Tool)]
+#[case(Role::System)]
+fn feedback_on_non_assistant_message_is_rejected(#[case] role: Role) {
+    let target = message(role, MessageMetadata::empty());
+    let result = MessageFeedback::for_message(
+        &target,
+        UserId::new(),
+        FeedbackSubmission::new(FeedbackRating::ThumbsUp),
+        &DefaultClock,
+    );
+    assert_eq!(
+        result,
+        Err(FeedbackDomainError::NotAssistantMessage(target.id()))
+    );
+}
+
+#[rstest]
+#[case(0)]
+#[case(6)]
+fn star_rating_outside_range_is_rejected(#[case] stars: u8) {
+    let result = MessageFeedback::for_message(
+        &reply("codex_cli", "v1"),
+        UserId::new(),
+        FeedbackSubmission::new(FeedbackRating::Stars(stars)),
+        &DefaultClock,
+    );
+    assert_eq!(result, Err(FeedbackDomainError::InvalidStarRating(stars)));
+}
+
+#[rstest]
+fn overlong_comment_is_rejected() {
+    let comment = "x".repeat(MAX_FEEDBACK_COMMENT_CHARS + 1);
+    let result = MessageFeedback::for_message(
+        &reply("codex_cli", "v1"),
+        UserId::new(),
+        FeedbackSubmission::new(FeedbackRating::ThumbsDown).with_comment(comment),
+        &DefaultClock,
+    );
+    assert_eq!(
+        result,
+        Err(FeedbackDomainError::CommentTooLong {
+            max: MAX_FEEDBACK_COMMENT_CHARS,
+            actual: MAX_FEEDBACK_COMMENT_CHARS + 1,
+        })
+    );
+}
+
+#[rstest]
+fn feedback_is_attributed_to_backend_and_instruction_set() {
+    let target = reply("codex_cli", "2026.04");
+    let feedback = MessageFeedback::for_message(
+        &target,
+        UserId::new(),
+        FeedbackSubmission::new(FeedbackRating::Stars(4))
+            .with_comment("   ")
+            .with_category(FeedbackCategory::Accuracy),
+        &DefaultClock,
+    )
+    .expect("valid feedback");
+
+    assert_eq!(feedback.message_id(), target.id());
+    assert_eq!(feedback.conversation_id(), target.conversation_id());
+    assert_eq!(feedback.agent_backend(), Some("codex_cli"));
+    assert_eq!(feedback.instruction_set_version(), Some("2026.04"));
+    assert_eq!(feedback.sentiment(), FeedbackSentiment::Positive);
+    assert_eq!(feedback.comment(), None, "blank comments are dropped");
+}
+
+#[rstest]
+fn summaries_group_by_backend_and_version() {
+    let codex_v1 = reply("codex_cli", "v1");
+    let codex_v2 = reply("codex_cli", "v2");
+    let feedback = [
+        rate(&codex_v1, FeedbackRating::ThumbsUp),
+        rate(&codex_v1, FeedbackRating::Stars(5)),
+        rate(&codex_v1, FeedbackRating::Stars(2)),
+        rate(&codex_v1, FeedbackRating::Stars(3)),
+        rate(&codex_v2, FeedbackRating::ThumbsDown),
+    ];
+
+    let summaries = FeedbackSummary::aggregate(&feedback);
+
+    let versions: Vec<_> = summaries
+        .iter()
+        .map(|summary| summary.instruction_set_version.as_deref())
+        .collect();
+    assert_eq!(versions, [Some("v1"), Some("v2")]);
+    let first = summaries.first().expect("v1 summary");
+    assert_eq!(
+        (first.total, first.positive, first.neutral, first.negative),
+        (4, 2, 1, 1)
+    );
+    assert_eq!(first.approval_rate_bps(), Some(6_666));
+    assert_eq!(first.average_stars_centi(), Some(333));
+    let second = summaries.get(1).expect("v2 summary");
+    assert_eq!(second.approval_rate_bps(), Some(0));
+    assert_eq!(second.average_stars_centi(), None);
+}
+
+#[rstest]
+#[tokio::test]
+async fn resubmitting_replaces_feedback_and_keeps_its_id() {
+    let request_ctx = ctx();
+    let target = reply("codex_cli", "v1");
+    let service = service_with(&target, &request_ctx).await;
+    let submit = |rating| {
+        SubmitFeedbackRequest::new(
+            target.conversation_id(),
+            target.id(),
+            FeedbackSubmission::new(rating),
+        )
+    };
+
+    let first = service
+        .submit(&request_ctx, submit(FeedbackRating::ThumbsDown))
+        .await
+        .expect("first submission");
+    let second = service
+        .submit(&request_ctx, submit(FeedbackRating::ThumbsUp))
+        .await
+        .expect("second submission");
+
+    assert_eq!(second.id(), first.id());
+    assert_eq!(second.author(), request_ctx.user_id());
+    let stored = service
+        .feedback_for_message(&request_ctx, target.conversation_id(), target.id())
+        .await
+        .expect("list feedback");
+    assert_eq!(stored.len(), 1);
+    assert_eq!(
+        stored.first().map(MessageFeedback::rating),
+        Some(FeedbackRating::ThumbsUp)
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn message_in_another_conversation_is_not_found() {
+    let request_ctx = ctx();
+    let target = reply("codex_cli", "v1");
+    let service = service_with(&target, &request_ctx).await;
+    let other_conversation = ConversationId::new();
+
+    let result = service
+        .submit(
+            &request_ctx,
+            SubmitFeedbackRequest::new(
+                other_conversation,
+                target.id(),
+                FeedbackSubmission::new(FeedbackRating::ThumbsUp),
+            ),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(FeedbackServiceError::MessageNotFound { conversation_id, message_id })
+            if conversation_id == other_conversation && message_id == target.id()
+    ));
+}
@@ -0,0 +1,288 @@
+//! Unit tests for conversation forks.
+
+use super::adapters_test_support::{clock, ctx, make_message};
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{
+        InMemoryContextSnapshotAdapter, InMemoryConversationForkAdapter,
+        InMemoryConversationRepository, InMemoryMessageRepository,
+    },
+    domain::{
+        AgentSessionId, ContentPart, ContextWindowSnapshot, Conversation, ConversationForkRequest,
+        ConversationId, Message, MessageSummary, Role, SequenceNumber, SequenceRange,
+        SnapshotParams, SnapshotType, TextPart,
+    },
+    ports::{
+        ContextSnapshotPort, ConversationForkError, ConversationRepository, MessageRepository,
+    },
+    services::{AppendMessageRequest, ConversationForkService, ConversationService},
+    validation::service::DefaultMessageValidator,
+};
+use crate::pagination::{Limit, PageRequest, collect_pages};
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use serde_json::json;
+use std::sync::Arc;
+
+struct Harness {
+    conversations: InMemoryConversationRepository,
+    messages: InMemoryMessageRepository,
+    snapshots: InMemoryContextSnapshotAdapter,
+    forks: ConversationForkService,
+}
+
+impl Harness {
+    async fn parent_with_messages(&self, ctx: &RequestContext, count: u64) -> Conversation {
+        let parent = Conversation::new(&DefaultClock).with_context(json!({ "goal": "fix CI" }));
+        self.conversations
+            .store(ctx, &parent)
+            .await
+            .expect("store parent");
+        for sequence in 1..=count {
+            let message = make_message(parent.id(), sequence, &DefaultClock).expect("message");
+            self.messages.store(ctx, &message).await.expect("store");
+        }
+        parent
+    }
+
+    async fn history(&self, ctx: &RequestContext, conversation_id: ConversationId) -> Vec<Message> {
+        collect_pages(|page| {
+            self.messages
+                .find_by_conversation(ctx, conversation_id, page)
+        })
+        .await
+        .expect("history")
+    }
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let conversations = InMemoryConversationRepository::new();
+    let messages = InMemoryMessageRepository::new();
+    let snapshots = InMemoryContextSnapshotAdapter::new();
+    let forks = ConversationForkService::new(
+        Arc::new(InMemoryConversationForkAdapter::new(
+            conversations.clone(),
+            messages.clone(),
+            snapshots.clone(),
+        )),
+        Arc::new(DefaultClock),
+    );
+    Harness {
+        conversations,
+        messages,
+        snapshots,
+        forks,
+    }
+}
+
+fn request(parent: &Conversation, forked_at: u64) -> ConversationForkRequest {
+    ConversationForkRequest {
+        parent_conversation_id: parent.id(),
+        forked_at: SequenceNumber::new(forked_at),
+    }
+}
+
+fn snapshot(conversation_id: ConversationId, end: u64) -> ContextWindowSnapshot {
+    ContextWindowSnapshot::new(
+        SnapshotParams {
+            conversation_id,
+            session_id: AgentSessionId::new(),
+            sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(end)),
+            message_summary: MessageSummary::default(),
+            snapshot_type: SnapshotType::Checkpoint,
+        },
+        &DefaultClock,
+    )
+}
+
+#[rstest]
+fn copied_messages_keep_everything_but_their_identity(clock: DefaultClock) {
+    let message = make_message(ConversationId::new(), 3, &clock)
+        .expect("message")
+        .with_pinned(true);
+    let fork_id = ConversationId::new();
+
+    let copy = message.copied_to(fork_id);
+
+    assert_ne!(copy.id(), message.id());
+    assert_eq!(copy.conversation_id(), fork_id);
+    assert_eq!(copy.sequence_number(), message.sequence_number());
+    assert_eq!(copy.created_at(), message.created_at());
+    assert_eq!(copy.content(), message.content());
+    assert!(copy.is_pinned());
+}
+
+#[rstest]
+#[tokio::test]
+async fn forks_copy_history_through_the_fork_point(harness: Harness, ctx: RequestContext) {
+    let parent = harness.parent_with_messages(&ctx, 4).await;
+    let pinned = harness
+        .history(&ctx, parent.id())
+        .await
+        .into_iter()
+        .next()
+        .expect("first message");
+    harness
+        .messages
+        .set_pinned(&ctx, pinned.id(), true)
+        .await
+        .expect("pin");
+
+    let fork = harness
+        .forks
+        .fork(&ctx, request(&parent, 2))
+        .await
+        .expect("fork");
+
+    let parent_history = harness.history(&ctx, parent.id()).await;
+    let fork_history = harness.history(&ctx, fork.conversation_id).await;
+    assert_eq!(fork_history.len(), 2);
+    for (copy, original) in fork_history.iter().zip(&parent_history) {
+        assert_ne!(copy.id(), original.id());
+        assert_eq!(copy.sequence_number(), original.sequence_number());
+        assert_eq!(copy.content(), original.content());
+        assert_eq!(copy.is_pinned(), original.is_pinned());
+    }
+    assert_eq!(parent_history.len(), 4);
+    let forked = harness
+        .conversations
+        .find_by_id(&ctx, fork.conversation_id)
+        .await
+        .expect("find")
+        .expect("fork conversation");
+    assert_eq!(forked.context(), parent.context());
+    assert_eq!(forked.created_at(), fork.created_at);
+}
+
+#[rstest]
+#[tokio::test]
+async fn forks_continue_after_the_fork_point(harness: Harness, ctx: RequestContext) {
+    let parent = harness.parent_with_messages(&ctx, 3).await;
+    let fork = harness
+        .forks
+        .fork(&ctx, request(&parent, 2))
+        .await
+        .expect("fork");
+    let conversations = ConversationService::new(
+        Arc::new(harness.conversations.clone()),
+        Arc::new(harness.messages.clone()),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    );
+
+    let appended = conversations
+        .append_message(
+            &ctx,
+            AppendMessageRequest::new(
+                fork.conversation_id,
+                Role::User,
+                vec![ContentPart::Text(TextPart::new("try another way"))],
+            ),
+        )
+        .await
+        .expect("append to fork");
+
+    assert_eq!(appended.sequence_number(), SequenceNumber::new(3));
+    assert_eq!(harness.history(&ctx, parent.id()).await.len(), 3);
+}
+
+#[rstest]
+#[tokio::test]
+async fn fork_provenance_links_the_parent_snapshot_at_the_fork_point(
+    harness: Harness,
+    ctx: RequestContext,
+) {
+    let parent = harness.parent_with_messages(&ctx, 4).await;
+    let before = snapshot(parent.id(), 2);
+    harness
+        .snapshots
+        .store_snapshot(&ctx, &before)
+        .await
+        .expect("store");
+    harness
+        .snapshots
+        .store_snapshot(&ctx, &snapshot(parent.id(), 4))
+        .await
+        .expect("store");
+
+    let fork = harness
+        .forks
+        .fork(&ctx, request(&parent, 3))
+        .await
+        .expect("fork");
+    let sibling = harness
+        .forks
+        .fork(&ctx, request(&parent, 1))
+        .await
+        .expect("fork");
+
+    assert_eq!(fork.parent_snapshot_id, Some(before.snapshot_id));
+    assert_eq!(sibling.parent_snapshot_id, None);
+    assert_eq!(
+        harness
+            .forks
+            .provenance(&ctx, fork.conversation_id)
+            .await
+            .expect("provenance"),
+        Some(fork)
+    );
+    assert_eq!(
+        harness
+            .forks
+            .provenance(&ctx, parent.id())
+            .await
+            .expect("provenance"),
+        None
+    );
+    let first = harness
+        .forks
+        .forks(
+            &ctx,
+            parent.id(),
+            PageRequest::new(Limit::new(1).expect("limit")),
+        )
+        .await
+        .expect("first page");
+    let cursor = first.next_cursor().expect("another page follows");
+    let second = harness
+        .forks
+        .forks(
+            &ctx,
+            parent.id(),
+            PageRequest::default().with_cursor(cursor),
+        )
+        .await
+        .expect("second page");
+    assert_eq!(first.items(), &[fork]);
+    assert_eq!(second.items(), &[sibling]);
+    assert!(second.is_last());
+}
+
+#[rstest]
+#[tokio::test]
+async fn forks_need_an_existing_parent_and_fork_point(harness: Harness, ctx: RequestContext) {
+    let parent = harness.parent_with_messages(&ctx, 2).await;
+    let missing = Conversation::new(&DefaultClock);
+
+    let no_parent = harness.forks.fork(&ctx, request(&missing, 1)).await;
+    let past_end = harness.forks.fork(&ctx, request(&parent, 3)).await;
+
+    assert!(matches!(
+        no_parent,
+        Err(ConversationForkError::ParentNotFound(id)) if id == missing.id()
+    ));
+    assert!(matches!(
+        past_end,
+        Err(ConversationForkError::ForkPointNotFound { conversation_id, forked_at })
+            if conversation_id == parent.id() && forked_at == SequenceNumber::new(3)
+    ));
+    assert!(
+        harness
+            .forks
+            .forks(&ctx, parent.id(), PageRequest::default())
+            .await
+            .expect("forks")
+            .is_empty()
+    );
+}
@@ -1,6 +1,8 @@
 //! Unit tests for domain identifier types.
 
-use crate::message::domain::{ConversationId, MessageId, SequenceNumber, TurnId};
+use crate::message::domain::{
+    ConversationId, MessageId, SequenceNumber, SequenceNumberError, TurnId,
+};
 use rstest::rstest;
 
 // ============================================================================
@@ -121,11 +123,45 @@ fn sequence_number_next_increments() {
 }
 
 #[rstest]
-fn sequence_number_from_u64() {
-    let seq: SequenceNumber = 100.into();
+fn sequence_number_try_from_u64() {
+    let seq = SequenceNumber::try_from(100).expect("non-zero sequence number");
     assert_eq!(seq.value(), 100);
 }
 
+#[rstest]
+fn sequence_number_rejects_zero() {
+    assert_eq!(SequenceNumber::try_new(0), Err(SequenceNumberError));
+    assert_eq!(SequenceNumber::try_from(0), Err(SequenceNumberError));
+}
+
+#[rstest]
+fn sequence_number_first_is_one() {
+    assert_eq!(SequenceNumber::FIRST.value(), 1);
+}
+
+#[rstest]
+#[case(3, 7, 4)]
+#[case(7, 3, 4)]
+#[case(5, 5, 0)]
+#[case(1, u64::MAX, u64::MAX - 1)]
+fn sequence_number_distance_is_symmetric(
+    #[case] from: u64,
+    #[case] to: u64,
+    #[case] expected: u64,
+) {
+    let distance = SequenceNumber::new(from).distance(SequenceNumber::new(to));
+    assert_eq!(distance, expected);
+}
+
+#[rstest]
+fn sequence_number_deserialization_rejects_zero() {
+    let result = serde_json::from_str::<SequenceNumber>("0");
+    assert!(result.is_err());
+    let seq: SequenceNumber = serde_json::from_str("12").expect("non-zero sequence number");
+    assert_eq!(seq, SequenceNumber::new(12));
+    assert_eq!(serde_json::to_string(&seq).expect("serialises"), "12");
+}
+
 #[rstest]
 fn sequence_number_ordering() {
     let seq1 = SequenceNumber::new(1);
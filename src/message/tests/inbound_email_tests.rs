@@ -0,0 +1,341 @@
+//! Unit tests for the inbound email gateway.
+
+use crate::context::{CorrelationId, TenantId, UserId};
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::message::{
+    adapters::{
+        inbound::{InboundPayloadError, parse_email_webhook},
+        memory::{
+            InMemoryConversationRepository, InMemoryEmailNotifier, InMemoryInboundIdentityMapping,
+            InMemoryMailbox, InMemoryMessageRepository,
+        },
+    },
+    domain::{
+        AttachmentPart, ContentPart, ConversationLifecycleChange, ConversationLifecycleEvent,
+        EMAIL_SOURCE, EmailEnvelope, InboundEmail, InboundPrincipal, OutboundEmail, Role, TextPart,
+    },
+    ports::ConversationLifecycleHook,
+    services::{
+        AppendMessageRequest, ConversationService, EmailReplyHook, InboundMessageService,
+        InboundServiceError,
+    },
+    validation::service::DefaultMessageValidator,
+};
+use crate::operator::domain::OperatorReason;
+use crate::task::{adapters::memory::InMemoryTaskRepository, services::TaskLifecycleService};
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+type Conversations = ConversationService<
+    InMemoryConversationRepository,
+    InMemoryMessageRepository,
+    DefaultMessageValidator,
+    DefaultClock,
+>;
+
+type Service = InboundMessageService<
+    InMemoryInboundIdentityMapping,
+    InMemoryConversationRepository,
+    InMemoryMessageRepository,
+    DefaultMessageValidator,
+    DefaultClock,
+>;
+
+struct Gateway {
+    service: Service,
+    conversations: Arc<Conversations>,
+    messages: Arc<InMemoryMessageRepository>,
+}
+
+#[fixture]
+fn gateway() -> Gateway {
+    let principal = InboundPrincipal::new(TenantId::new(), UserId::new());
+    let mapping = InMemoryInboundIdentityMapping::new().with_user(
+        EMAIL_SOURCE,
+        "jdoe@example.com",
+        principal,
+    );
+    let messages = Arc::new(InMemoryMessageRepository::new());
+    let conversations = Arc::new(ConversationService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        messages.clone(),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    ));
+    let service = InboundMessageService::new(Arc::new(mapping), conversations.clone())
+        .with_task_intake(Arc::new(TaskLifecycleService::new(
+            Arc::new(InMemoryTaskRepository::new()),
+            Arc::new(DefaultClock),
+        )));
+    Gateway {
+        service,
+        conversations,
+        messages,
+    }
+}
+
+fn opening_email() -> InboundEmail {
+    InboundEmail::new(
+        "<a1@example.com>",
+        "jdoe@example.com",
+        "Deploy the fix",
+        "Please deploy the fix to staging.",
+    )
+}
+
+fn reply_to_opening(message_id: &str, text: &str) -> InboundEmail {
+    InboundEmail::new(message_id, "JDoe@Example.com", "Re: Deploy the fix", text)
+        .with_in_reply_to("<a1@example.com>")
+        .with_references(["<a1@example.com>".to_owned()])
+}
+
+#[rstest]
+fn email_webhooks_are_parsed() {
+    let body = br#"{
+        "message_id": "<a2@example.com>",
+        "from": "Jane Doe <jdoe@example.com>",
+        "to": ["Agent <agent@corbusier.example>"],
+        "subject": "Re: Deploy",
+        "text": "Logs attached.",
+        "in_reply_to": "<a1@example.com>",
+        "references": ["<a0@example.com>", "<a1@example.com>"],
+        "attachments": [
+            {"filename": "log.txt", "content_type": "text/plain", "content": "SGVsbG8=", "size": 5}
+        ]
+    }"#;
+
+    let email = parse_email_webhook(body).expect("valid payload");
+
+    assert_eq!(email.from, "jdoe@example.com");
+    assert_eq!(email.to, vec!["agent@corbusier.example".to_owned()]);
+    assert_eq!(email.thread_id(), "<a0@example.com>");
+    assert_eq!(
+        email.attachments,
+        vec![
+            AttachmentPart::new("text/plain", "SGVsbG8=")
+                .with_name("log.txt")
+                .with_size(5)
+        ]
+    );
+}
+
+#[rstest]
+#[case::blank_email(br#"{"message_id":"<a@x>","from":"a@x","text":" "}"#.as_slice(), true)]
+#[case::missing_sender(br#"{"message_id":"<a@x>","text":"hi"}"#.as_slice(), false)]
+fn invalid_email_webhooks_are_rejected(#[case] body: &[u8], #[case] empty_text: bool) {
+    let result = parse_email_webhook(body);
+
+    if empty_text {
+        assert!(matches!(result, Err(InboundPayloadError::EmptyText)));
+    } else {
+        assert!(matches!(result, Err(InboundPayloadError::Malformed(_))));
+    }
+}
+
+#[rstest]
+#[case::plain("Deploy", "Re: Deploy")]
+#[case::already_a_reply("RE: Deploy", "RE: Deploy")]
+fn replies_thread_under_the_received_email(#[case] subject: &str, #[case] expected: &str) {
+    let received = InboundEmail::new("<a2@example.com>", "jdoe@example.com", subject, "Go?")
+        .with_references(["<a1@example.com>".to_owned()]);
+
+    let reply = OutboundEmail::reply_to(&received.envelope(), "Deployed.");
+
+    assert_eq!(reply.to, "jdoe@example.com");
+    assert_eq!(reply.subject, expected);
+    assert_eq!(reply.in_reply_to.as_deref(), Some("<a2@example.com>"));
+    assert_eq!(
+        reply.references,
+        vec!["<a1@example.com>".to_owned(), "<a2@example.com>".to_owned()]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn emails_in_a_thread_share_a_conversation(gateway: Gateway) {
+    let opening = gateway
+        .service
+        .ingest_email(CorrelationId::new(), opening_email())
+        .await
+        .expect("opening email");
+    let reply = gateway
+        .service
+        .ingest_email(
+            CorrelationId::new(),
+            reply_to_opening("<a2@example.com>", "").with_attachment(
+                AttachmentPart::new("text/plain", "SGVsbG8=").with_name("log.txt"),
+            ),
+        )
+        .await
+        .expect("reply");
+
+    assert!(opening.receipt.created_conversation);
+    assert!(!reply.receipt.created_conversation);
+    assert_eq!(
+        reply.receipt.conversation_id,
+        opening.receipt.conversation_id
+    );
+    assert!(matches!(
+        reply.receipt.message.content(),
+        [ContentPart::Attachment(attachment)] if attachment.name.as_deref() == Some("log.txt")
+    ));
+    let envelope =
+        EmailEnvelope::from_metadata(reply.receipt.message.metadata()).expect("email envelope");
+    assert_eq!(envelope.message_id, "<a2@example.com>");
+    assert_eq!(envelope.from, "JDoe@Example.com");
+}
+
+#[rstest]
+#[tokio::test]
+async fn issue_tags_in_the_subject_open_one_task(gateway: Gateway) {
+    let opening = InboundEmail::new(
+        "<a1@example.com>",
+        "jdoe@example.com",
+        "[github:leynos/corbusier#42] Fix the flaky test",
+        "It fails about once a day.",
+    );
+
+    let first = gateway
+        .service
+        .ingest_email(CorrelationId::new(), opening.clone())
+        .await
+        .expect("opening email");
+    let second = gateway
+        .service
+        .ingest_email(
+            CorrelationId::new(),
+            reply_to_opening("<a2@example.com>", "Any news?"),
+        )
+        .await
+        .expect("untagged reply");
+    let resent = gateway
+        .service
+        .ingest_email(
+            CorrelationId::new(),
+            InboundEmail {
+                message_id: "<a3@example.com>".to_owned(),
+                ..opening
+            },
+        )
+        .await
+        .expect("tagged email");
+
+    let task = first.task.expect("task opened");
+    assert_eq!(
+        task.origin().issue_ref().to_string(),
+        "github/leynos/corbusier/#42"
+    );
+    assert!(second.task.is_none());
+    assert_eq!(
+        resent.task.map(|resent_task| resent_task.id()),
+        Some(task.id())
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn polling_drains_the_mailbox(gateway: Gateway) {
+    let mailbox = InMemoryMailbox::new();
+    mailbox.deliver(opening_email());
+    mailbox.deliver(InboundEmail::new(
+        "<m1@example.com>",
+        "mallory@example.com",
+        "Hi",
+        "Let me in",
+    ));
+
+    let report = gateway
+        .service
+        .poll_mailbox(&mailbox)
+        .await
+        .expect("first poll");
+    let again = gateway
+        .service
+        .poll_mailbox(&mailbox)
+        .await
+        .expect("second poll");
+
+    assert_eq!(report.ingested.len(), 1);
+    assert_eq!(report.unmapped, vec!["<m1@example.com>".to_owned()]);
+    assert!(again.ingested.is_empty() && again.unmapped.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn polling_waits_out_maintenance(gateway: Gateway) {
+    let gate = Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ));
+    let service = gateway.service.clone().with_maintenance(Arc::clone(&gate));
+    let mailbox = InMemoryMailbox::new();
+    mailbox.deliver(opening_email());
+    let reason = OperatorReason::new("failover").expect("valid reason");
+    gate.enable(reason, None).await.expect("enable maintenance");
+
+    let refused = service.poll_mailbox(&mailbox).await;
+    gate.disable(None).await.expect("disable maintenance");
+    let report = service.poll_mailbox(&mailbox).await.expect("poll");
+
+    assert!(matches!(
+        refused,
+        Err(InboundServiceError::MaintenanceMode(_))
+    ));
+    assert_eq!(report.ingested.len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn assistant_replies_are_emailed_to_the_thread(gateway: Gateway) {
+    let notifier = Arc::new(InMemoryEmailNotifier::new());
+    let hook = EmailReplyHook::new(gateway.messages.clone(), notifier.clone());
+    gateway
+        .service
+        .ingest_email(CorrelationId::new(), opening_email())
+        .await
+        .expect("opening email");
+    let latest = gateway
+        .service
+        .ingest_email(
+            CorrelationId::new(),
+            reply_to_opening("<a2@example.com>", "Is it done?"),
+        )
+        .await
+        .expect("reply");
+    let ctx = latest.receipt.context;
+    let answer = gateway
+        .conversations
+        .append_message(
+            &ctx,
+            AppendMessageRequest::new(
+                latest.receipt.conversation_id,
+                Role::Assistant,
+                vec![ContentPart::Text(TextPart::new("Deployed to staging."))],
+            ),
+        )
+        .await
+        .expect("assistant reply");
+
+    let event = ConversationLifecycleEvent::new(
+        answer.conversation_id(),
+        ConversationLifecycleChange::MessageStored {
+            message_id: answer.id(),
+            sequence_number: answer.sequence_number(),
+            role: Role::Assistant,
+        },
+        DefaultClock.utc(),
+    );
+    hook.on_event(&ctx, &event).await.expect("hook ran");
+
+    assert_eq!(
+        notifier.sent(),
+        vec![OutboundEmail {
+            to: "JDoe@Example.com".to_owned(),
+            subject: "Re: Deploy the fix".to_owned(),
+            body: "Deployed to staging.".to_owned(),
+            in_reply_to: Some("<a2@example.com>".to_owned()),
+            references: vec!["<a1@example.com>".to_owned(), "<a2@example.com>".to_owned()],
+        }]
+    );
+}
@@ -0,0 +1,284 @@
+//! Unit tests for the inbound message gateway.
+
+use crate::context::{CorrelationId, TenantId, UserId};
+use crate::message::{
+    adapters::{
+        inbound::{
+            InboundPayloadError, SignatureConfigError, SignatureError, SignatureScheme,
+            SignedRequest, SlackDelivery, WebhookSignatureVerifier, parse_slack_delivery,
+            parse_webhook_message,
+        },
+        memory::{
+            InMemoryConversationRepository, InMemoryInboundIdentityMapping,
+            InMemoryMessageRepository,
+        },
+    },
+    domain::{INBOUND_ORIGIN_EXTENSION_KEY, InboundMessage, InboundOrigin, InboundPrincipal, Role},
+    services::{ConversationService, InboundMessageService, InboundServiceError},
+    validation::service::DefaultMessageValidator,
+};
+use mockable::{Clock, DefaultClock};
+use rstest::rstest;
+use std::sync::Arc;
+
+type Service = InboundMessageService<
+    InMemoryInboundIdentityMapping,
+    InMemoryConversationRepository,
+    InMemoryMessageRepository,
+    DefaultMessageValidator,
+    DefaultClock,
+>;
+
+fn verifier(scheme: SignatureScheme) -> WebhookSignatureVerifier {
+    WebhookSignatureVerifier::new(scheme, "s3cret").expect("non-empty secret")
+}
+
+fn now() -> String {
+    DefaultClock.utc().timestamp().to_string()
+}
+
+fn service(principal: InboundPrincipal) -> Service {
+    let mapping = InMemoryInboundIdentityMapping::new().with_user("ops", "jdoe", principal);
+    let conversations = ConversationService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    );
+    InboundMessageService::new(Arc::new(mapping), Arc::new(conversations))
+}
+
+fn message_from(user: &str, text: &str) -> InboundMessage {
+    InboundMessage::new(InboundOrigin::new("ops", "ops-room", user), text)
+}
+
+#[rstest]
+#[case(SignatureScheme::Slack, "v0=")]
+#[case(SignatureScheme::HmacSha256, "sha256=")]
+#[case(SignatureScheme::GitHub, "sha256=")]
+fn signed_requests_verify(#[case] scheme: SignatureScheme, #[case] prefix: &str) {
+    let verifier = verifier(scheme);
+    let timestamp = now();
+    let signature = verifier.sign(&timestamp, b"{}");
+    let request = SignedRequest {
+        signature: Some(&signature),
+        timestamp: Some(&timestamp),
+        body: b"{}",
+    };
+
+    assert!(signature.starts_with(prefix));
+    assert_eq!(verifier.verify(&request, &DefaultClock), Ok(()));
+}
+
+#[rstest]
+fn github_signatures_cover_the_body_alone() {
+    let verifier =
+        WebhookSignatureVerifier::new(SignatureScheme::GitHub, "It's a Secret to Everybody")
+            .expect("non-empty secret");
+    let request = SignedRequest {
+        signature: Some("sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17"),
+        timestamp: None,
+        body: b"Hello, World!",
+    };
+
+    assert_eq!(verifier.verify(&request, &DefaultClock), Ok(()));
+}
+
+#[rstest]
+#[case::tampered_body(b"{\"text\":\"other\"}".as_slice())]
+#[case::empty_body(b"".as_slice())]
+fn altered_requests_are_rejected(#[case] body: &[u8]) {
+    let verifier = verifier(SignatureScheme::HmacSha256);
+    let timestamp = now();
+    let signature = verifier.sign(&timestamp, b"{\"text\":\"hello\"}");
+    let request = SignedRequest {
+        signature: Some(&signature),
+        timestamp: Some(&timestamp),
+        body,
+    };
+
+    assert_eq!(
+        verifier.verify(&request, &DefaultClock),
+        Err(SignatureError::Mismatch)
+    );
+}
+
+#[rstest]
+fn signature_from_another_scheme_is_rejected() {
+    let timestamp = now();
+    let signature = verifier(SignatureScheme::Slack).sign(&timestamp, b"{}");
+    let request = SignedRequest {
+        signature: Some(&signature),
+        timestamp: Some(&timestamp),
+        body: b"{}",
+    };
+
+    assert_eq!(
+        verifier(SignatureScheme::HmacSha256).verify(&request, &DefaultClock),
+        Err(SignatureError::Mismatch)
+    );
+}
+
+#[rstest]
+fn stale_timestamps_are_rejected() {
+    let verifier = verifier(SignatureScheme::Slack);
+    let timestamp = (DefaultClock.utc().timestamp() - 3_600).to_string();
+    let signature = verifier.sign(&timestamp, b"{}");
+    let request = SignedRequest {
+        signature: Some(&signature),
+        timestamp: Some(&timestamp),
+        body: b"{}",
+    };
+
+    assert!(matches!(
+        verifier.verify(&request, &DefaultClock),
+        Err(SignatureError::StaleTimestamp { age_secs }) if age_secs >= 3_600
+    ));
+}
+
+#[rstest]
+#[case(None, Some("sha256=00"), SignatureError::MissingTimestamp)]
+#[case(Some("1"), None, SignatureError::MissingSignature)]
+#[case(Some("yesterday"), Some("sha256=00"), SignatureError::InvalidTimestamp)]
+fn incomplete_requests_are_rejected(
+    #[case] timestamp: Option<&str>,
+    #[case] signature: Option<&str>,
+    #[case] expected: SignatureError,
+) {
+    let request = SignedRequest {
+        signature,
+        timestamp,
+        body: b"{}",
+    };
+
+    assert_eq!(
+        verifier(SignatureScheme::HmacSha256).verify(&request, &DefaultClock),
+        Err(expected)
+    );
+}
+
+#[rstest]
+fn empty_secrets_are_rejected() {
+    assert!(matches!(
+        WebhookSignatureVerifier::new(SignatureScheme::Slack, ""),
+        Err(SignatureConfigError::EmptySecret)
+    ));
+}
+
+#[rstest]
+fn slack_message_events_become_inbound_messages() {
+    let body = br#"{"type":"event_callback","event_id":"Ev1","event":{
+        "type":"message","channel":"C1","user":"U1","text":"Deploy?"}}"#;
+
+    let delivery = parse_slack_delivery(body).expect("valid payload");
+
+    let expected = InboundOrigin::new("slack", "C1", "U1").with_external_message_id("Ev1");
+    assert_eq!(
+        delivery,
+        SlackDelivery::Message(InboundMessage::new(expected, "Deploy?"))
+    );
+}
+
+#[rstest]
+#[case::bot_post(r#"{"type":"message","bot_id":"B1","channel":"C1","text":"hi"}"#)]
+#[case::edit(r#"{"type":"message","subtype":"message_changed","channel":"C1","user":"U1"}"#)]
+#[case::reaction(r#"{"type":"reaction_added","user":"U1"}"#)]
+#[case::blank_text(r#"{"type":"message","channel":"C1","user":"U1","text":"  "}"#)]
+fn other_slack_events_are_ignored(#[case] event: &str) {
+    let body = format!(r#"{{"type":"event_callback","event":{event}}}"#);
+
+    assert_eq!(
+        parse_slack_delivery(body.as_bytes()).expect("valid payload"),
+        SlackDelivery::Ignored
+    );
+}
+
+#[rstest]
+fn webhook_payloads_name_their_source() {
+    let body = br#"{"channel_id":"ops-room","user_id":"jdoe","message_id":"42","text":"hi"}"#;
+
+    let message = parse_webhook_message("ops", body).expect("valid payload");
+
+    assert_eq!(
+        message.origin,
+        InboundOrigin::new("ops", "ops-room", "jdoe").with_external_message_id("42")
+    );
+    assert_eq!(message.text, "hi");
+}
+
+#[rstest]
+#[case::blank_text(br#"{"channel_id":"c","user_id":"u","text":" "}"#.as_slice(), true)]
+#[case::missing_user(br#"{"channel_id":"c","text":"hi"}"#.as_slice(), false)]
+fn invalid_webhook_payloads_are_rejected(#[case] body: &[u8], #[case] empty_text: bool) {
+    let result = parse_webhook_message("ops", body);
+
+    if empty_text {
+        assert!(matches!(result, Err(InboundPayloadError::EmptyText)));
+    } else {
+        assert!(matches!(result, Err(InboundPayloadError::Malformed(_))));
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn first_message_creates_and_binds_a_conversation() {
+    let principal = InboundPrincipal::new(TenantId::new(), UserId::new());
+    let service = service(principal);
+    let correlation_id = CorrelationId::new();
+
+    let first = service
+        .ingest(correlation_id, message_from("jdoe", "Deploy?"))
+        .await
+        .expect("first message");
+    let second = service
+        .ingest(CorrelationId::new(), message_from("jdoe", "Any news?"))
+        .await
+        .expect("second message");
+
+    assert!(first.created_conversation);
+    assert!(!second.created_conversation);
+    assert_eq!(second.conversation_id, first.conversation_id);
+    assert_eq!(first.context.tenant_id(), principal.tenant_id);
+    assert_eq!(first.context.user_id(), principal.user_id);
+    assert_eq!(first.context.correlation_id(), correlation_id);
+    assert_eq!(first.message.role(), Role::User);
+}
+
+#[rstest]
+#[tokio::test]
+async fn ingested_messages_record_their_origin() {
+    let service = service(InboundPrincipal::new(TenantId::new(), UserId::new()));
+    let inbound = message_from("jdoe", "Deploy?");
+    let origin = inbound.origin.clone();
+
+    let receipt = service
+        .ingest(CorrelationId::new(), inbound)
+        .await
+        .expect("ingested");
+
+    let recorded = receipt
+        .message
+        .metadata()
+        .extensions
+        .get(INBOUND_ORIGIN_EXTENSION_KEY)
+        .cloned()
+        .expect("origin extension");
+    let recorded_origin: InboundOrigin = serde_json::from_value(recorded).expect("origin");
+    assert_eq!(recorded_origin, origin);
+}
+
+#[rstest]
+#[tokio::test]
+async fn unmapped_authors_are_rejected() {
+    let service = service(InboundPrincipal::new(TenantId::new(), UserId::new()));
+
+    let result = service
+        .ingest(CorrelationId::new(), message_from("mallory", "hi"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(InboundServiceError::UnmappedUser { external_system, external_user_id })
+            if external_system == "ops" && external_user_id == "mallory"
+    ));
+}
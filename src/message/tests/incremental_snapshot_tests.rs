@@ -0,0 +1,213 @@
+//! Unit tests for incremental context snapshots.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::InMemoryContextSnapshotAdapter,
+    domain::{
+        AgentSessionId, ContextWindowSnapshot, ConversationId, MessageId, MessageSummary,
+        SequenceNumber, SequenceRange, SnapshotChainError, SnapshotParams, SnapshotType,
+        ToolCallReference,
+    },
+    ports::ContextSnapshotPort,
+    services::{ContextSnapshotService, ContextSnapshotServiceError},
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+struct Harness {
+    conversation_id: ConversationId,
+    session_id: AgentSessionId,
+    snapshots: Arc<InMemoryContextSnapshotAdapter>,
+    service: ContextSnapshotService<InMemoryContextSnapshotAdapter>,
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let snapshots = Arc::new(InMemoryContextSnapshotAdapter::new());
+    Harness {
+        conversation_id: ConversationId::new(),
+        session_id: AgentSessionId::new(),
+        service: ContextSnapshotService::new(Arc::clone(&snapshots)),
+        snapshots,
+    }
+}
+
+impl Harness {
+    /// A full capture of the messages 1 to `end`, all from the user, with
+    /// one tool call per message.
+    fn capture(&self, end: u64) -> ContextWindowSnapshot {
+        let count = u32::try_from(end).expect("small range");
+        ContextWindowSnapshot::new(
+            SnapshotParams {
+                conversation_id: self.conversation_id,
+                session_id: self.session_id,
+                sequence_range: SequenceRange::new(
+                    SequenceNumber::new(1),
+                    SequenceNumber::new(end),
+                ),
+                message_summary: MessageSummary::new(count, 0, 0, 0),
+                snapshot_type: SnapshotType::Checkpoint,
+            },
+            &DefaultClock,
+        )
+        .with_visible_tool_calls((1..=end).map(|sequence| {
+            ToolCallReference::new(
+                format!("call-{sequence}"),
+                "search",
+                MessageId::new(),
+                SequenceNumber::new(sequence),
+            )
+        }))
+        .with_token_estimate(end * 100)
+    }
+
+    async fn store_full(&self, ctx: &RequestContext, end: u64) -> ContextWindowSnapshot {
+        let snapshot = self.capture(end);
+        self.snapshots
+            .store_snapshot(ctx, &snapshot)
+            .await
+            .expect("store snapshot");
+        snapshot
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn increments_store_only_the_new_messages(ctx: RequestContext, harness: Harness) {
+    let root = harness.store_full(&ctx, 10).await;
+
+    let increment = harness
+        .service
+        .capture_incremental(&ctx, root.snapshot_id, harness.capture(13))
+        .await
+        .expect("capture increment");
+
+    assert_eq!(increment.parent_snapshot_id, Some(root.snapshot_id));
+    assert_eq!(
+        increment.sequence_range,
+        SequenceRange::new(SequenceNumber::new(11), SequenceNumber::new(13))
+    );
+    assert_eq!(increment.message_summary.total(), 3);
+    assert_eq!(increment.visible_tool_calls.len(), 3);
+    assert_eq!(increment.token_estimate, Some(300));
+}
+
+#[rstest]
+#[tokio::test]
+async fn resolving_reconstitutes_the_full_view(ctx: RequestContext, harness: Harness) {
+    let root = harness.store_full(&ctx, 10).await;
+    let middle = harness
+        .service
+        .capture_incremental(&ctx, root.snapshot_id, harness.capture(13))
+        .await
+        .expect("capture increment");
+    let latest = harness
+        .service
+        .capture_incremental(&ctx, middle.snapshot_id, harness.capture(20))
+        .await
+        .expect("capture increment");
+
+    let resolved = harness
+        .service
+        .resolve(&ctx, latest.snapshot_id)
+        .await
+        .expect("resolve");
+
+    let full = harness.capture(20);
+    assert_eq!(resolved.snapshot.snapshot_id, latest.snapshot_id);
+    assert_eq!(resolved.snapshot.sequence_range, full.sequence_range);
+    assert_eq!(resolved.snapshot.message_summary, full.message_summary);
+    assert_eq!(resolved.snapshot.visible_tool_calls.len(), 20);
+    assert_eq!(resolved.snapshot.token_estimate, Some(2000));
+    assert!(!resolved.snapshot.is_incremental());
+    assert_eq!(
+        resolved.chain,
+        [root.snapshot_id, middle.snapshot_id, latest.snapshot_id]
+    );
+    assert_eq!(resolved.storage.stored_messages, 20);
+    assert_eq!(resolved.storage.full_messages, 43);
+}
+
+#[rstest]
+#[tokio::test]
+async fn session_storage_reports_the_savings(ctx: RequestContext, harness: Harness) {
+    let root = harness.store_full(&ctx, 10).await;
+    let increment = harness
+        .service
+        .capture_incremental(&ctx, root.snapshot_id, harness.capture(15))
+        .await
+        .expect("capture increment");
+    harness
+        .service
+        .capture_incremental(&ctx, increment.snapshot_id, harness.capture(20))
+        .await
+        .expect("capture increment");
+
+    let storage = harness
+        .service
+        .session_storage(&ctx, harness.session_id)
+        .await
+        .expect("measure storage");
+
+    assert_eq!(storage.stored_messages, 20);
+    assert_eq!(storage.full_messages, 45);
+    assert_eq!(storage.saved_messages(), 25);
+}
+
+#[rstest]
+#[tokio::test]
+async fn broken_chains_and_regressions_are_refused(ctx: RequestContext, harness: Harness) {
+    let root = harness.store_full(&ctx, 10).await;
+    let missing_parent = uuid::Uuid::new_v4();
+    let orphan = harness.capture(12).with_parent(missing_parent);
+    harness
+        .snapshots
+        .store_snapshot(&ctx, &orphan)
+        .await
+        .expect("store orphan");
+
+    let regressed = harness
+        .service
+        .capture_incremental(&ctx, root.snapshot_id, harness.capture(8))
+        .await;
+    let unresolvable = harness.service.resolve(&ctx, orphan.snapshot_id).await;
+
+    assert!(matches!(
+        regressed,
+        Err(ContextSnapshotServiceError::Chain(
+            SnapshotChainError::Regressed { .. }
+        ))
+    ));
+    assert!(matches!(
+        unresolvable,
+        Err(ContextSnapshotServiceError::Chain(SnapshotChainError::MissingParent {
+            parent_snapshot_id,
+            ..
+        })) if parent_snapshot_id == missing_parent
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn removing_a_parent_removes_its_increments(ctx: RequestContext, harness: Harness) {
+    let root = harness.store_full(&ctx, 10).await;
+    harness
+        .service
+        .capture_incremental(&ctx, root.snapshot_id, harness.capture(12))
+        .await
+        .expect("capture increment");
+
+    harness
+        .snapshots
+        .remove(&[root.snapshot_id])
+        .expect("remove root");
+
+    assert!(harness.snapshots.is_empty());
+}
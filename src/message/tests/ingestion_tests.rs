@@ -0,0 +1,393 @@
+//! Unit tests for transcript conversion and ingestion.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::{
+        memory::{InMemoryConversationRepository, InMemoryMessageRepository},
+        transcript::{AnthropicMessagesConverter, OpenAiChatConverter, URI_LIST_MIME_TYPE},
+    },
+    domain::{
+        AttachmentPart, ContentPart, Conversation, ConversationId,
+        INBOUND_TRANSCRIPT_EXTENSION_KEY, ReasoningPart, Role, SequenceNumber, TextPart,
+        ToolCallPart, ToolResultPart, TranscriptFormat, TranscriptMessage,
+    },
+    error::ValidationError,
+    ports::{ConversationRepository, MessageRepository, TranscriptConverter, TranscriptError},
+    services::{IngestTranscriptRequest, IngestionServiceError, TranscriptIngestionService},
+    validation::service::DefaultMessageValidator,
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+fn text(role: Role, text: &str) -> TranscriptMessage {
+    TranscriptMessage::new(role, vec![ContentPart::Text(TextPart::new(text))])
+}
+
+fn convert(converter: &dyn TranscriptConverter, payload: &Value) -> Vec<TranscriptMessage> {
+    converter
+        .convert(payload.to_string().as_bytes())
+        .expect("transcript converts")
+}
+
+#[rstest]
+fn openai_roles_and_tool_calls_are_converted() {
+    let payload = json!({
+        "messages": [
+            { "role": "developer", "content": "Be terse." },
+            { "role": "user", "content": "Weather in Paris?" },
+            { "role": "assistant", "content": null, "tool_calls": [{
+                "id": "call_1", "type": "function",
+                "function": { "name": "weather", "arguments": "{\"city\":\"Paris\"}" }
+            }]},
+            { "role": "tool", "tool_call_id": "call_1", "content": "18C" }
+        ],
+        "choices": [
+            { "message": { "role": "assistant", "content": "18C in Paris." } },
+            { "message": { "role": "assistant", "content": "Mild." } }
+        ]
+    });
+
+    let transcript = convert(&OpenAiChatConverter::new(), &payload);
+
+    assert_eq!(
+        transcript,
+        [
+            text(Role::System, "Be terse."),
+            text(Role::User, "Weather in Paris?"),
+            TranscriptMessage::new(
+                Role::Assistant,
+                vec![ContentPart::ToolCall(ToolCallPart::new(
+                    "call_1",
+                    "weather",
+                    json!({ "city": "Paris" }),
+                ))],
+            ),
+            TranscriptMessage::new(
+                Role::Tool,
+                vec![ContentPart::ToolResult(ToolResultPart::success(
+                    "call_1",
+                    json!("18C"),
+                ))],
+            ),
+            text(Role::Assistant, "18C in Paris."),
+        ]
+    );
+}
+
+#[rstest]
+fn openai_content_parts_become_attachments() {
+    let payload = json!({ "messages": [{ "role": "user", "content": [
+        { "type": "text", "text": "Compare these." },
+        { "type": "image_url", "image_url": { "url": "data:image/png;base64,iVBORw0KGgo=" } },
+        { "type": "image_url", "image_url": { "url": "https://example.com/chart.png" } },
+        { "type": "input_audio", "input_audio": { "data": "UklGRg==", "format": "wav" } },
+        { "type": "file", "file": {
+            "filename": "notes.pdf", "file_data": "data:application/pdf;base64,JVBERi0="
+        }}
+    ]}]});
+
+    let transcript = convert(&OpenAiChatConverter::new(), &payload);
+
+    let content: Vec<ContentPart> = transcript
+        .into_iter()
+        .flat_map(|message| message.content)
+        .collect();
+    assert_eq!(
+        content,
+        [
+            ContentPart::Text(TextPart::new("Compare these.")),
+            ContentPart::Attachment(AttachmentPart::new("image/png", "iVBORw0KGgo=")),
+            ContentPart::Attachment(AttachmentPart::new(
+                URI_LIST_MIME_TYPE,
+                "https://example.com/chart.png",
+            )),
+            ContentPart::Attachment(AttachmentPart::new("audio/wav", "UklGRg==")),
+            ContentPart::Attachment(
+                AttachmentPart::new("application/pdf", "JVBERi0=").with_name("notes.pdf"),
+            ),
+        ]
+    );
+}
+
+#[rstest]
+fn anthropic_tool_results_move_into_tool_messages() {
+    let payload = json!({
+        "system": [{ "type": "text", "text": "Be terse." }],
+        "messages": [
+            { "role": "user", "content": "Weather in Paris?" },
+            { "role": "assistant", "content": [
+                { "type": "thinking", "thinking": "Use the tool.", "signature": "sig" },
+                { "type": "tool_use", "id": "toolu_1", "name": "weather",
+                  "input": { "city": "Paris" } }
+            ]},
+            { "role": "user", "content": [
+                { "type": "tool_result", "tool_use_id": "toolu_1", "content": "18C" },
+                { "type": "text", "text": "And tomorrow?" }
+            ]}
+        ]
+    });
+
+    let transcript = convert(&AnthropicMessagesConverter::new(), &payload);
+
+    assert_eq!(
+        transcript,
+        [
+            text(Role::System, "Be terse."),
+            text(Role::User, "Weather in Paris?"),
+            TranscriptMessage::new(
+                Role::Assistant,
+                vec![
+                    ContentPart::Reasoning(ReasoningPart::new("Use the tool.")),
+                    ContentPart::ToolCall(ToolCallPart::new(
+                        "toolu_1",
+                        "weather",
+                        json!({ "city": "Paris" }),
+                    )),
+                ],
+            ),
+            TranscriptMessage::new(
+                Role::Tool,
+                vec![ContentPart::ToolResult(ToolResultPart::success(
+                    "toolu_1",
+                    json!("18C"),
+                ))],
+            ),
+            text(Role::User, "And tomorrow?"),
+        ]
+    );
+}
+
+#[rstest]
+fn anthropic_documents_and_failed_results_are_kept() {
+    let payload = json!({ "messages": [{ "role": "user", "content": [
+        { "type": "document", "title": "notes.pdf",
+          "source": { "type": "base64", "media_type": "application/pdf", "data": "JVBERi0=" } },
+        { "type": "tool_result", "tool_use_id": "toolu_1", "is_error": true,
+          "content": [{ "type": "text", "text": "timeout" }] }
+    ]}]});
+
+    let transcript = convert(&AnthropicMessagesConverter::new(), &payload);
+
+    assert_eq!(
+        transcript,
+        [
+            TranscriptMessage::new(
+                Role::Tool,
+                vec![ContentPart::ToolResult(ToolResultPart {
+                    call_id: "toolu_1".to_owned(),
+                    content: json!([{ "type": "text", "text": "timeout" }]),
+                    success: false,
+                })],
+            ),
+            TranscriptMessage::new(
+                Role::User,
+                vec![ContentPart::Attachment(
+                    AttachmentPart::new("application/pdf", "JVBERi0=").with_name("notes.pdf"),
+                )],
+            ),
+        ]
+    );
+}
+
+#[rstest]
+#[case::openai_role(
+    TranscriptFormat::OpenAiChat,
+    json!({ "messages": [{ "role": "critic", "content": "Hm." }] })
+)]
+#[case::anthropic_role(
+    TranscriptFormat::AnthropicMessages,
+    json!({ "messages": [{ "role": "system", "content": "Hm." }] })
+)]
+fn unknown_roles_are_rejected(#[case] format: TranscriptFormat, #[case] payload: Value) {
+    let converter: Box<dyn TranscriptConverter> = match format {
+        TranscriptFormat::OpenAiChat => Box::new(OpenAiChatConverter::new()),
+        TranscriptFormat::AnthropicMessages => Box::new(AnthropicMessagesConverter::new()),
+    };
+
+    let result = converter.convert(payload.to_string().as_bytes());
+
+    assert!(matches!(
+        result,
+        Err(TranscriptError::UnsupportedRole { position: 0, .. })
+    ));
+}
+
+#[rstest]
+#[case::empty(json!({ "messages": [] }), "no messages")]
+#[case::unknown_block(
+    json!({ "messages": [{ "role": "user", "content": [{ "type": "hologram" }] }] }),
+    "unknown type"
+)]
+#[case::not_a_transcript(json!({ "prompt": "Hi" }), "malformed")]
+fn unconvertible_payloads_are_explained(#[case] payload: Value, #[case] expected: &str) {
+    let error = AnthropicMessagesConverter::new()
+        .convert(payload.to_string().as_bytes())
+        .expect_err("payload is refused");
+
+    assert!(error.to_string().contains(expected), "{error}");
+}
+
+struct Harness {
+    conversations: InMemoryConversationRepository,
+    messages: InMemoryMessageRepository,
+    service: TranscriptIngestionService,
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let conversations = InMemoryConversationRepository::new();
+    let messages = InMemoryMessageRepository::new().with_conversations(conversations.clone());
+    let service = TranscriptIngestionService::new(
+        Arc::new(conversations.clone()),
+        Arc::new(messages.clone()),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_converter(Arc::new(OpenAiChatConverter::new()))
+    .with_converter(Arc::new(AnthropicMessagesConverter::new()));
+    Harness {
+        conversations,
+        messages,
+        service,
+    }
+}
+
+async fn conversation(ctx: &RequestContext, harness: &Harness) -> ConversationId {
+    let conversation = Conversation::new(&DefaultClock);
+    harness
+        .conversations
+        .store(ctx, &conversation)
+        .await
+        .expect("store conversation");
+    conversation.id()
+}
+
+fn openai_request(conversation_id: ConversationId, messages: &Value) -> IngestTranscriptRequest {
+    let payload = json!({ "messages": messages }).to_string();
+    IngestTranscriptRequest::new(conversation_id, TranscriptFormat::OpenAiChat, payload)
+}
+
+#[rstest]
+#[tokio::test]
+async fn transcripts_are_appended_after_existing_messages(ctx: RequestContext, harness: Harness) {
+    let conversation_id = conversation(&ctx, &harness).await;
+    let history = json!([{ "role": "user", "content": "Hi" }]);
+    harness
+        .service
+        .ingest(&ctx, openai_request(conversation_id, &history))
+        .await
+        .expect("first transcript");
+
+    let stored = harness
+        .service
+        .ingest(
+            &ctx,
+            openai_request(
+                conversation_id,
+                &json!([
+                    { "role": "user", "content": "Still there?" },
+                    { "role": "assistant", "content": "Yes." }
+                ]),
+            ),
+        )
+        .await
+        .expect("second transcript");
+
+    let sequence_numbers: Vec<u64> = stored
+        .iter()
+        .map(|message| message.sequence_number().value())
+        .collect();
+    assert_eq!(sequence_numbers, [2, 3]);
+    assert_eq!(
+        stored.last().map(|message| {
+            message.metadata().extensions[INBOUND_TRANSCRIPT_EXTENSION_KEY].clone()
+        }),
+        Some(json!({ "format": "openai_chat", "position": 1 }))
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn an_invalid_message_stores_nothing(ctx: RequestContext, harness: Harness) {
+    let conversation_id = conversation(&ctx, &harness).await;
+    let messages = json!([
+        { "role": "user", "content": "Hi" },
+        { "role": "assistant", "content": null }
+    ]);
+
+    let result = harness
+        .service
+        .ingest(&ctx, openai_request(conversation_id, &messages))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(IngestionServiceError::InvalidMessage {
+            position: 1,
+            source: ValidationError::EmptyContent,
+        })
+    ));
+    let next = harness
+        .messages
+        .next_sequence_number(&ctx, conversation_id)
+        .await
+        .expect("next sequence number");
+    assert_eq!(next, SequenceNumber::FIRST);
+}
+
+#[rstest]
+#[tokio::test]
+async fn archived_conversations_refuse_transcripts(ctx: RequestContext, harness: Harness) {
+    let mut conversation = Conversation::new(&DefaultClock);
+    conversation.archive(&DefaultClock).expect("archive");
+    harness
+        .conversations
+        .store(&ctx, &conversation)
+        .await
+        .expect("store conversation");
+    let messages = json!([{ "role": "user", "content": "Hi" }]);
+
+    let result = harness
+        .service
+        .ingest(&ctx, openai_request(conversation.id(), &messages))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(IngestionServiceError::ConversationArchived(id)) if id == conversation.id()
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn formats_without_a_converter_are_refused(ctx: RequestContext) {
+    let service = TranscriptIngestionService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    );
+    let request = IngestTranscriptRequest::new(
+        ConversationId::new(),
+        TranscriptFormat::AnthropicMessages,
+        "{}",
+    );
+
+    let result = service.ingest(&ctx, request).await;
+
+    assert!(!service.supports(TranscriptFormat::AnthropicMessages));
+    assert!(matches!(
+        result,
+        Err(IngestionServiceError::UnsupportedFormat(
+            TranscriptFormat::AnthropicMessages
+        ))
+    ));
+}
@@ -0,0 +1,255 @@
+//! Unit tests for conversation labels and label queries.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemoryConversationRepository, InMemoryMessageRepository},
+    domain::{
+        Conversation, ConversationLabel, ConversationLabelChange, ConversationLabelError,
+        MAX_LABEL_KEY_CHARS, MAX_LABEL_VALUE_CHARS,
+    },
+    ports::ConversationRepository,
+    services::ConversationService,
+    validation::service::DefaultMessageValidator,
+};
+use crate::pagination::{Limit, PageRequest};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use serde_json::json;
+use std::sync::Arc;
+
+type TestService = ConversationService<
+    InMemoryConversationRepository,
+    InMemoryMessageRepository,
+    DefaultMessageValidator,
+    DefaultClock,
+>;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+#[fixture]
+fn service() -> TestService {
+    ConversationService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+}
+
+fn label(key: &str, value: &str) -> ConversationLabel {
+    ConversationLabel::new(key, value).expect("valid label")
+}
+
+#[rstest]
+#[case::lowercase("project")]
+#[case::digits_and_separators("team-2.eu_west")]
+#[case::leading_digit("2026-q3")]
+#[case::longest(&"k".repeat(MAX_LABEL_KEY_CHARS))]
+fn label_keys_accept_the_key_alphabet(#[case] key: &str) {
+    assert_eq!(label(key, "apollo").key(), key);
+}
+
+#[rstest]
+#[case::empty("")]
+#[case::uppercase("Project")]
+#[case::leading_separator("-project")]
+#[case::whitespace("project name")]
+#[case::too_long(&"k".repeat(MAX_LABEL_KEY_CHARS + 1))]
+fn label_keys_outside_the_alphabet_are_rejected(#[case] key: &str) {
+    assert_eq!(
+        ConversationLabel::new(key, "apollo"),
+        Err(ConversationLabelError::InvalidKey(key.to_owned()))
+    );
+}
+
+#[rstest]
+fn label_values_must_be_present_and_bounded() {
+    assert_eq!(
+        ConversationLabel::new("project", ""),
+        Err(ConversationLabelError::EmptyValue {
+            key: "project".to_owned()
+        })
+    );
+    assert_eq!(
+        ConversationLabel::new("project", "v".repeat(MAX_LABEL_VALUE_CHARS + 1)),
+        Err(ConversationLabelError::ValueTooLong {
+            key: "project".to_owned(),
+            max: MAX_LABEL_VALUE_CHARS,
+            actual: MAX_LABEL_VALUE_CHARS + 1,
+        })
+    );
+}
+
+#[rstest]
+fn setting_and_removing_labels_reports_each_change() {
+    let mut conversation = Conversation::new(&DefaultClock);
+    let id = conversation.id();
+
+    let added = conversation.set_label(label("project", "apollo"), &DefaultClock);
+    let unchanged = conversation.set_label(label("project", "apollo"), &DefaultClock);
+    let replaced = conversation.set_label(label("project", "gemini"), &DefaultClock);
+    let removed = conversation.remove_label("project", &DefaultClock);
+    let absent = conversation.remove_label("project", &DefaultClock);
+
+    let change = |previous: Option<&str>, value: Option<&str>| ConversationLabelChange {
+        conversation_id: id,
+        key: "project".to_owned(),
+        previous: previous.map(str::to_owned),
+        value: value.map(str::to_owned),
+    };
+    assert_eq!(added, Some(change(None, Some("apollo"))));
+    assert_eq!(unchanged, None);
+    assert_eq!(replaced, Some(change(Some("apollo"), Some("gemini"))));
+    assert_eq!(removed, Some(change(Some("gemini"), None)));
+    assert!(removed.is_some_and(|removal| removal.is_removal()));
+    assert_eq!(absent, None);
+    assert!(conversation.labels().is_empty());
+}
+
+#[rstest]
+fn conversations_serialised_without_labels_deserialise_unlabelled() {
+    let mut labelled = Conversation::new(&DefaultClock);
+    assert!(
+        labelled
+            .set_label(label("customer", "acme"), &DefaultClock)
+            .is_some()
+    );
+    let mut serialised = serde_json::to_value(&labelled).expect("serialise");
+    assert_eq!(serialised["labels"], json!({"customer": "acme"}));
+
+    serialised.as_object_mut().expect("object").remove("labels");
+    let restored: Conversation = serde_json::from_value(serialised).expect("deserialise");
+
+    assert!(restored.labels().is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn label_changes_are_recorded_in_the_label_history(
+    ctx: RequestContext,
+    service: TestService,
+) {
+    let conversation = service.create_conversation(&ctx).await.expect("create");
+
+    service
+        .set_label(&ctx, conversation.id(), label("project", "apollo"))
+        .await
+        .expect("set");
+    service
+        .set_label(&ctx, conversation.id(), label("project", "apollo"))
+        .await
+        .expect("set again");
+    let relabelled = service
+        .remove_label(&ctx, conversation.id(), "project")
+        .await
+        .expect("remove");
+
+    assert!(relabelled.labels().is_empty());
+    let history = service
+        .label_history(&ctx, conversation.id(), PageRequest::default())
+        .await
+        .expect("history");
+    let changes = history
+        .items()
+        .iter()
+        .map(|event| {
+            assert_eq!(event.actor, ctx.user_id());
+            assert_eq!(event.correlation_id, ctx.correlation_id());
+            (event.change.previous.clone(), event.change.value.clone())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changes,
+        vec![
+            (None, Some("apollo".to_owned())),
+            (Some("apollo".to_owned()), None),
+        ]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn archived_conversations_can_be_relabelled(ctx: RequestContext, service: TestService) {
+    let conversation = service.create_conversation(&ctx).await.expect("create");
+    service
+        .archive_conversation(&ctx, conversation.id())
+        .await
+        .expect("archive");
+
+    let labelled = service
+        .set_label(&ctx, conversation.id(), label("experiment", "retired"))
+        .await
+        .expect("set");
+
+    assert_eq!(labelled.label("experiment"), Some("retired"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn conversations_are_found_by_label_oldest_first(ctx: RequestContext, service: TestService) {
+    let mut apollo = Vec::new();
+    for index in 0..3 {
+        let conversation = service.create_conversation(&ctx).await.expect("create");
+        let project = if index == 1 { "gemini" } else { "apollo" };
+        let labelled = service
+            .set_label(&ctx, conversation.id(), label("project", project))
+            .await
+            .expect("set");
+        if project == "apollo" {
+            apollo.push(labelled.id());
+        }
+    }
+    service.create_conversation(&ctx).await.expect("unlabelled");
+
+    let page = PageRequest::new(Limit::new(1).expect("limit"));
+    let first = service
+        .find_by_label(&ctx, &label("project", "apollo"), page)
+        .await
+        .expect("first page");
+    let cursor = first.next_cursor().expect("second page");
+    let second = service
+        .find_by_label(&ctx, &label("project", "apollo"), page.with_cursor(cursor))
+        .await
+        .expect("second page");
+
+    let found = first
+        .items()
+        .iter()
+        .chain(second.items())
+        .map(Conversation::id)
+        .collect::<Vec<_>>();
+    assert_eq!(found, apollo);
+    assert!(second.is_last());
+}
+
+#[rstest]
+#[tokio::test]
+async fn label_queries_are_scoped_to_the_tenant(ctx: RequestContext) {
+    let conversations = InMemoryConversationRepository::new();
+    let mut conversation = Conversation::new(&DefaultClock);
+    assert!(
+        conversation
+            .set_label(label("customer", "acme"), &DefaultClock)
+            .is_some()
+    );
+    conversations
+        .store(&ctx, &conversation)
+        .await
+        .expect("store");
+
+    let other_tenant = test_request_ctx();
+    let found = conversations
+        .find_by_label(
+            &other_tenant,
+            &label("customer", "acme"),
+            PageRequest::default(),
+        )
+        .await
+        .expect("query");
+
+    assert!(found.items().is_empty());
+}
@@ -0,0 +1,221 @@
+//! Unit tests for conversation lifecycle hooks.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemoryConversationRepository, InMemoryMessageRepository},
+    domain::{
+        ContentPart, ConversationId, ConversationLifecycleChange, ConversationLifecycleEvent,
+        ConversationLifecyclePoint, Role, TextPart,
+    },
+    ports::{ConversationLifecycleHook, LifecycleHookError, LifecycleHookResult},
+    services::{
+        AppendMessageRequest, ConversationLifecycleHooks, ConversationService, LifecycleHookOutcome,
+    },
+    validation::service::DefaultMessageValidator,
+};
+use crate::test_support::test_request_ctx;
+use async_trait::async_trait;
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+type TestService = ConversationService<
+    InMemoryConversationRepository,
+    InMemoryMessageRepository,
+    DefaultMessageValidator,
+    DefaultClock,
+>;
+
+#[derive(Default)]
+struct RecordingHook {
+    events: Mutex<Vec<ConversationLifecycleEvent>>,
+}
+
+impl RecordingHook {
+    fn points(&self) -> Vec<ConversationLifecyclePoint> {
+        self.events
+            .lock()
+            .expect("events lock")
+            .iter()
+            .map(ConversationLifecycleEvent::point)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ConversationLifecycleHook for RecordingHook {
+    async fn on_event(
+        &self,
+        _ctx: &RequestContext,
+        event: &ConversationLifecycleEvent,
+    ) -> LifecycleHookResult {
+        self.events.lock().expect("events lock").push(event.clone());
+        Ok(())
+    }
+}
+
+enum MisbehavingHook {
+    Fail,
+    Panic,
+    Hang,
+}
+
+#[async_trait]
+impl ConversationLifecycleHook for MisbehavingHook {
+    async fn on_event(
+        &self,
+        _ctx: &RequestContext,
+        _event: &ConversationLifecycleEvent,
+    ) -> LifecycleHookResult {
+        match self {
+            Self::Fail => Err(LifecycleHookError::new("webhook unreachable")),
+            Self::Panic => panic!("hook bug"),
+            Self::Hang => {
+                tokio::time::sleep(Duration::from_secs(30)).await;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+fn service(hooks: ConversationLifecycleHooks) -> TestService {
+    ConversationService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_lifecycle_hooks(Arc::new(hooks))
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn conversation_service_announces_its_lifecycle_points(ctx: RequestContext) {
+    let recorder = Arc::new(RecordingHook::default());
+    let mut hooks = ConversationLifecycleHooks::new();
+    hooks.register(
+        "recorder",
+        ConversationLifecyclePoint::all(),
+        recorder.clone(),
+    );
+    let service = service(hooks);
+
+    let conversation = service.create_conversation(&ctx).await.expect("create");
+    let message = service
+        .append_message(
+            &ctx,
+            AppendMessageRequest::new(
+                conversation.id(),
+                Role::User,
+                vec![ContentPart::Text(TextPart::new("hello"))],
+            ),
+        )
+        .await
+        .expect("append");
+    service
+        .archive_conversation(&ctx, conversation.id())
+        .await
+        .expect("archive");
+
+    assert_eq!(
+        recorder.points(),
+        vec![
+            ConversationLifecyclePoint::Created,
+            ConversationLifecyclePoint::MessageStored,
+            ConversationLifecyclePoint::Archived,
+        ]
+    );
+    let events = recorder.events.lock().expect("events lock");
+    assert!(
+        events
+            .iter()
+            .all(|event| event.conversation_id == conversation.id())
+    );
+    assert!(events.iter().any(|event| event.change
+        == ConversationLifecycleChange::MessageStored {
+            message_id: message.id(),
+            sequence_number: message.sequence_number(),
+            role: Role::User,
+        }));
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn hooks_only_receive_the_points_they_subscribe_to(ctx: RequestContext) {
+    let recorder = Arc::new(RecordingHook::default());
+    let mut hooks = ConversationLifecycleHooks::new();
+    hooks.register(
+        "archive-only",
+        [ConversationLifecyclePoint::Archived],
+        recorder.clone(),
+    );
+    let service = service(hooks);
+
+    let conversation = service.create_conversation(&ctx).await.expect("create");
+    service
+        .archive_conversation(&ctx, conversation.id())
+        .await
+        .expect("archive");
+
+    assert_eq!(
+        recorder.points(),
+        vec![ConversationLifecyclePoint::Archived]
+    );
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn misbehaving_hooks_are_isolated_from_each_other_and_the_workflow(ctx: RequestContext) {
+    let recorder = Arc::new(RecordingHook::default());
+    let mut hooks = ConversationLifecycleHooks::new().with_timeout(Duration::from_millis(50));
+    for (name, hook) in [
+        ("failing", MisbehavingHook::Fail),
+        ("panicking", MisbehavingHook::Panic),
+        ("hanging", MisbehavingHook::Hang),
+    ] {
+        hooks.register(name, [ConversationLifecyclePoint::Created], Arc::new(hook));
+    }
+    hooks.register(
+        "recorder",
+        [ConversationLifecyclePoint::Created],
+        recorder.clone(),
+    );
+    let event = ConversationLifecycleEvent::new(
+        ConversationId::new(),
+        ConversationLifecycleChange::Created,
+        DefaultClock.utc(),
+    );
+
+    let reports = hooks.dispatch(&ctx, &event).await;
+    let created = service(hooks).create_conversation(&ctx).await;
+
+    assert_eq!(
+        reports
+            .into_iter()
+            .map(|report| (report.hook_name, report.outcome))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "failing".to_owned(),
+                LifecycleHookOutcome::Failed(LifecycleHookError::new("webhook unreachable"))
+            ),
+            ("panicking".to_owned(), LifecycleHookOutcome::Panicked),
+            ("hanging".to_owned(), LifecycleHookOutcome::TimedOut),
+            ("recorder".to_owned(), LifecycleHookOutcome::Completed),
+        ]
+    );
+    assert!(created.is_ok());
+    assert_eq!(
+        recorder.points(),
+        vec![
+            ConversationLifecyclePoint::Created,
+            ConversationLifecyclePoint::Created,
+        ]
+    );
+}
@@ -0,0 +1,175 @@
+//! Unit tests for background processing load shedding.
+
+use crate::message::{
+    adapters::memory::{InMemoryMessageProcessingRepository, InMemoryMessageRepository},
+    domain::{
+        BackgroundWorkload, LoadLevel, LoadSheddingPolicy, LoadSignals, ProcessingStage, ShedMode,
+        SheddingDecision,
+    },
+    services::{LoadSheddingController, MessageProcessingService},
+};
+use chrono::{DateTime, Local, TimeDelta, Utc};
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+/// Clock that only moves when told to.
+struct ManualClock(Mutex<DateTime<Utc>>);
+
+impl ManualClock {
+    fn new() -> Self {
+        Self(Mutex::new(DefaultClock.utc()))
+    }
+
+    fn advance(&self, by: TimeDelta) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn local(&self) -> DateTime<Local> {
+        self.utc().with_timezone(&Local)
+    }
+
+    fn utc(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[fixture]
+fn clock() -> Arc<ManualClock> {
+    Arc::new(ManualClock::new())
+}
+
+fn controller(clock: &Arc<ManualClock>) -> LoadSheddingController<ManualClock> {
+    LoadSheddingController::new(
+        LoadSheddingPolicy::default()
+            .with_turn_latency_slo(Duration::from_millis(500))
+            .with_queue_depths(10, 100),
+        Arc::clone(clock),
+    )
+}
+
+#[rstest]
+#[case::idle(None, 0, LoadLevel::Normal)]
+#[case::within_slo(Some(500), 10, LoadLevel::Normal)]
+#[case::slow_turns(Some(501), 0, LoadLevel::Elevated)]
+#[case::growing_queue(None, 11, LoadLevel::Elevated)]
+#[case::very_slow_turns(Some(1_001), 0, LoadLevel::Overloaded)]
+#[case::backed_up_queue(Some(100), 101, LoadLevel::Overloaded)]
+fn signals_map_to_load_levels(
+    #[case] latency_ms: Option<u64>,
+    #[case] max_queue_depth: usize,
+    #[case] expected: LoadLevel,
+) {
+    let policy = LoadSheddingPolicy::default()
+        .with_turn_latency_slo(Duration::from_millis(500))
+        .with_queue_depths(10, 100);
+    let signals = LoadSignals {
+        turn_latency_p95: latency_ms.map(Duration::from_millis),
+        max_queue_depth,
+    };
+
+    assert_eq!(policy.level(signals), expected);
+}
+
+#[rstest]
+fn default_policy_sheds_analytics_first() {
+    let policy = LoadSheddingPolicy::default();
+
+    assert_eq!(
+        policy.mode(BackgroundWorkload::Analytics, LoadLevel::Elevated),
+        ShedMode::Pause
+    );
+    assert_eq!(
+        policy.mode(BackgroundWorkload::Embedding, LoadLevel::Elevated),
+        ShedMode::Sample(4)
+    );
+    assert!(BackgroundWorkload::ALL.into_iter().all(|workload| {
+        policy.mode(workload, LoadLevel::Normal) == ShedMode::Run
+            && policy.mode(workload, LoadLevel::Overloaded) == ShedMode::Pause
+    }));
+}
+
+#[rstest]
+fn sampled_workloads_run_one_job_in_n(clock: Arc<ManualClock>) {
+    let shedder = controller(&clock);
+    shedder.record_queue_depth("embedding", 50);
+
+    let decisions: Vec<SheddingDecision> = (0..8)
+        .map(|_| shedder.admit(BackgroundWorkload::Embedding))
+        .collect();
+
+    let run = SheddingDecision::Run;
+    let defer = SheddingDecision::Defer;
+    assert_eq!(
+        decisions,
+        vec![run, defer, defer, defer, run, defer, defer, defer]
+    );
+}
+
+#[rstest]
+fn critical_stages_are_never_shed(clock: Arc<ManualClock>) {
+    let shedder = controller(&clock);
+    shedder.record_queue_depth("moderation", 5_000);
+
+    assert_eq!(
+        shedder.admit_stage(ProcessingStage::Moderation),
+        SheddingDecision::Run
+    );
+    assert_eq!(
+        shedder.admit_stage(ProcessingStage::Embedding),
+        SheddingDecision::Defer
+    );
+}
+
+#[rstest]
+fn slow_turns_stop_counting_once_they_leave_the_window(clock: Arc<ManualClock>) {
+    let shedder = controller(&clock).with_latency_window(Duration::from_secs(30));
+    shedder.record_turn_latency(Duration::from_secs(2));
+    assert_eq!(shedder.level(), LoadLevel::Overloaded);
+
+    clock.advance(TimeDelta::seconds(31));
+    shedder.record_turn_latency(Duration::from_millis(100));
+
+    assert_eq!(shedder.level(), LoadLevel::Normal);
+    assert_eq!(shedder.metrics().level_changes, 2);
+}
+
+#[rstest]
+fn metrics_report_decisions_per_workload(clock: Arc<ManualClock>) {
+    let shedder = controller(&clock);
+    assert!(shedder.admit(BackgroundWorkload::Analytics).is_run());
+    shedder.record_queue_depth("analytics", 20);
+    assert!(!shedder.admit(BackgroundWorkload::Analytics).is_run());
+
+    let metrics = shedder.metrics();
+
+    assert_eq!(metrics.level, LoadLevel::Elevated);
+    assert_eq!(metrics.signals.max_queue_depth, 20);
+    let analytics = metrics
+        .workloads
+        .iter()
+        .find(|workload| workload.workload == BackgroundWorkload::Analytics)
+        .expect("analytics counters");
+    assert_eq!(
+        (analytics.mode, analytics.admitted, analytics.deferred),
+        (ShedMode::Pause, 1, 1)
+    );
+}
+
+#[rstest]
+fn processing_service_admits_every_stage_without_a_controller() {
+    let service = MessageProcessingService::new(
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(InMemoryMessageProcessingRepository::new()),
+        Arc::new(DefaultClock),
+    );
+
+    assert_eq!(
+        service.admit_stage(ProcessingStage::Embedding),
+        SheddingDecision::Run
+    );
+    assert!(service.load_shedding_metrics().is_none());
+}
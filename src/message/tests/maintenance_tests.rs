@@ -0,0 +1,50 @@
+//! Unit tests for refusing message writes during maintenance.
+
+use super::adapters_test_support::{clock, ctx, make_message, repo};
+use crate::context::RequestContext;
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::message::{
+    adapters::{maintenance::MaintenanceGatedMessageRepository, memory::InMemoryMessageRepository},
+    domain::ConversationId,
+    error::RepositoryError,
+    ports::MessageRepository,
+};
+use crate::operator::domain::OperatorReason;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+fn gate() -> Arc<MaintenanceGate> {
+    Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ))
+}
+
+#[rstest]
+#[tokio::test]
+async fn writes_are_refused_during_maintenance_and_reads_are_not(
+    ctx: RequestContext,
+    clock: DefaultClock,
+    repo: InMemoryMessageRepository,
+) {
+    let inner = Arc::new(repo);
+    let gate = gate();
+    let gated = MaintenanceGatedMessageRepository::new(Arc::clone(&inner), Arc::clone(&gate));
+    let conversation_id = ConversationId::new();
+    let stored = make_message(conversation_id, 1, &clock).expect("valid message");
+    gated
+        .store(&ctx, &stored)
+        .await
+        .expect("store before maintenance");
+    let reason = OperatorReason::new("schema migration").expect("valid reason");
+    gate.enable(reason, None).await.expect("enable maintenance");
+
+    let refused = make_message(conversation_id, 2, &clock).expect("valid message");
+    let result = gated.store(&ctx, &refused).await;
+
+    assert!(matches!(result, Err(RepositoryError::MaintenanceMode(_))));
+    assert!(!inner.exists(&ctx, refused.id()).await.expect("exists"));
+    let found = gated.find_by_id(&ctx, stored.id()).await.expect("read");
+    assert_eq!(found, Some(stored));
+}
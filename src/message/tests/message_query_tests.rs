@@ -0,0 +1,189 @@
+//! Unit tests for filtered message queries.
+
+use super::adapters_test_support::{ctx, make_message, repo};
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::InMemoryMessageRepository,
+    domain::{
+        ContentPart, ContentPartKind, ConversationId, Message, MessageBuilderError, MessageId,
+        MessageMetadata, MessageQuery, Role, SequenceNumber, TextPart, ToolCallPart,
+        ToolResultPart,
+    },
+    ports::repository::MessageRepository,
+};
+use crate::pagination::{Limit, PageRequest, SortOrder};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+
+fn at(minutes: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0)
+        .single()
+        .expect("valid timestamp")
+        + Duration::minutes(minutes)
+}
+
+fn persisted(
+    conversation_id: ConversationId,
+    seq: u64,
+    (role, part): (Role, ContentPart),
+    metadata: MessageMetadata,
+) -> Result<Message, MessageBuilderError> {
+    let minutes = i64::try_from(seq).expect("small sequence number");
+    Message::from_persisted(
+        MessageId::new(),
+        conversation_id,
+        role,
+        vec![part],
+        metadata,
+        at(minutes),
+        SequenceNumber::new(seq),
+    )
+}
+
+/// Stores a user prompt, an assistant tool call, the tool's result, and an
+/// assistant reply, created one minute apart.
+async fn seed_conversation(
+    repo: &InMemoryMessageRepository,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let backend = || MessageMetadata::with_agent_backend("codex_cli");
+    let messages = [
+        persisted(
+            conversation_id,
+            1,
+            (
+                Role::User,
+                ContentPart::Text(TextPart::new("Run the tests")),
+            ),
+            MessageMetadata::empty(),
+        )?,
+        persisted(
+            conversation_id,
+            2,
+            (
+                Role::Assistant,
+                ContentPart::ToolCall(ToolCallPart::new("call-1", "run_tests", json!({}))),
+            ),
+            backend(),
+        )?,
+        persisted(
+            conversation_id,
+            3,
+            (
+                Role::Tool,
+                ContentPart::ToolResult(ToolResultPart::success("call-1", json!("ok"))),
+            ),
+            MessageMetadata::empty().with_extension("replay.source", json!("ci"))?,
+        )?,
+        persisted(
+            conversation_id,
+            4,
+            (
+                Role::Assistant,
+                ContentPart::Text(TextPart::new("All green")),
+            ),
+            backend(),
+        )?,
+    ];
+    for message in &messages {
+        repo.store(ctx, message).await?;
+    }
+    Ok(())
+}
+
+fn sequences(messages: &[Message]) -> Vec<u64> {
+    messages
+        .iter()
+        .map(|message| message.sequence_number().value())
+        .collect()
+}
+
+#[rstest]
+#[case::everything(MessageQuery::new(), vec![1, 2, 3, 4])]
+#[case::one_role(MessageQuery::new().with_role(Role::Assistant), vec![2, 4])]
+#[case::any_of_roles(MessageQuery::new().with_role(Role::User).with_role(Role::Tool), vec![1, 3])]
+#[case::tool_calls(MessageQuery::new().with_tool_calls(), vec![2])]
+#[case::tool_results(MessageQuery::new().with_content_part(ContentPartKind::ToolResult), vec![3])]
+#[case::known_metadata_field(MessageQuery::new().with_metadata_key("agent_backend"), vec![2, 4])]
+#[case::extension_key(MessageQuery::new().with_metadata_key("replay.source"), vec![3])]
+#[case::created_from(MessageQuery::new().with_created_from(at(2)), vec![2, 3, 4])]
+#[case::created_until(MessageQuery::new().with_created_until(at(3)), vec![1, 2])]
+#[case::combined(
+    MessageQuery::new().with_role(Role::Assistant).with_content_part(ContentPartKind::Text),
+    vec![4]
+)]
+#[tokio::test]
+async fn query_returns_matching_messages_in_sequence_order(
+    repo: InMemoryMessageRepository,
+    ctx: RequestContext,
+    #[case] query: MessageQuery,
+    #[case] expected: Vec<u64>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let conversation_id = ConversationId::new();
+    seed_conversation(&repo, &ctx, conversation_id).await?;
+    repo.store(
+        &ctx,
+        &make_message(ConversationId::new(), 1, &DefaultClock)?,
+    )
+    .await?;
+
+    let page = repo.query(&ctx, conversation_id, &query).await?;
+
+    assert_eq!(sequences(&page), expected);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn query_pages_through_matches(
+    repo: InMemoryMessageRepository,
+    ctx: RequestContext,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let conversation_id = ConversationId::new();
+    seed_conversation(&repo, &ctx, conversation_id).await?;
+    let request = PageRequest::new(Limit::new(1)?).with_order(SortOrder::Descending);
+    let query = MessageQuery::new()
+        .with_role(Role::Assistant)
+        .with_page(request);
+
+    let first = repo.query(&ctx, conversation_id, &query).await?;
+    let cursor = first.next_cursor().ok_or("a second page should follow")?;
+    let second = repo
+        .query(
+            &ctx,
+            conversation_id,
+            &query.clone().with_page(request.with_cursor(cursor)),
+        )
+        .await?;
+
+    assert_eq!(sequences(&first), vec![4]);
+    assert_eq!(sequences(&second), vec![2]);
+    assert!(second.is_last());
+    Ok(())
+}
+
+#[rstest]
+#[case(ContentPart::Text(TextPart::new("hi")), ContentPartKind::Text, "text")]
+#[case(
+    ContentPart::ToolCall(ToolCallPart::new("call-1", "search", json!({}))),
+    ContentPartKind::ToolCall,
+    "tool_call"
+)]
+#[case(
+    ContentPart::ToolResult(ToolResultPart::failure("call-1", "boom")),
+    ContentPartKind::ToolResult,
+    "tool_result"
+)]
+fn content_part_kind_matches_serialised_tag(
+    #[case] part: ContentPart,
+    #[case] kind: ContentPartKind,
+    #[case] tag: &str,
+) -> Result<(), serde_json::Error> {
+    assert_eq!(part.kind(), kind);
+    assert_eq!(kind.as_str(), tag);
+    assert_eq!(serde_json::to_value(&part)?["type"], tag);
+    Ok(())
+}
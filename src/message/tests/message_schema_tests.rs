@@ -0,0 +1,232 @@
+//! Unit tests for the JSON Schemas of the message format.
+
+use crate::message::{
+    domain::{
+        AttachmentPart, CitationPart, CitationSource, CitationSpan, ContentHash, ContentPart,
+        ConversationId, CustomPart, ImagePart, ImageThumbnail, Message, MessageMetadata,
+        MessageTokenCount, ReasoningPart, Role, SequenceNumber, TextPart, ToolCallPart,
+        ToolResultPart, TurnId,
+    },
+    error::MessageSchemaError,
+    schema::{CURRENT_SCHEMA_VERSION, MessageSchema, SCHEMA_VERSIONS, SchemaType, schema_document},
+    versioning::{EventUpgrader, MessageCreatedUpgrader},
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::{Value, json};
+
+fn every_part() -> Vec<ContentPart> {
+    vec![
+        ContentPart::Text(TextPart::new("See the chart.")),
+        ContentPart::Reasoning(ReasoningPart::new("The user wants a chart.")),
+        ContentPart::ToolCall(ToolCallPart::new(
+            "call-1",
+            "plot",
+            json!({ "kind": "bar" }),
+        )),
+        ContentPart::ToolResult(ToolResultPart {
+            call_id: "call-1".to_owned(),
+            content: json!("done"),
+            success: true,
+        }),
+        ContentPart::Attachment(AttachmentPart::new("text/plain", "notes").with_name("notes.txt")),
+        ContentPart::Image(
+            ImagePart::new("image/png", "iVBORw0KGgo=", 800, 600)
+                .with_alt_text("A bar chart")
+                .with_thumbnail(ImageThumbnail::new(ContentHash::of(b"thumb"), 80, 60)),
+        ),
+        ContentPart::Citation(
+            CitationPart::new(
+                CitationSource::uri("https://example.com/data"),
+                vec![CitationSpan::new(0, 0, 7)],
+            )
+            .with_title("Data"),
+        ),
+        ContentPart::Custom(CustomPart::new("spreadsheet", json!({ "rows": 3 }))),
+    ]
+}
+
+fn message_json() -> Value {
+    let metadata = MessageMetadata::with_agent_backend("claude_code_sdk")
+        .with_turn_id(TurnId::new())
+        .with_token_count(MessageTokenCount::new(42, "cl100k_base"));
+    let message = Message::builder(
+        ConversationId::new(),
+        Role::Assistant,
+        SequenceNumber::new(3),
+    )
+    .with_content_parts(every_part())
+    .with_metadata(metadata)
+    .build(&DefaultClock)
+    .expect("valid message");
+    serde_json::to_value(message).expect("serialise message")
+}
+
+fn violations(result: Result<(), MessageSchemaError>) -> Vec<(String, String)> {
+    let Err(MessageSchemaError::Violations(found)) = result else {
+        panic!("expected violations, got {result:?}");
+    };
+    found
+        .into_iter()
+        .map(|violation| (violation.path, violation.message))
+        .collect()
+}
+
+#[rstest]
+fn every_version_loads() {
+    for version in SCHEMA_VERSIONS {
+        let schema = MessageSchema::load(version).expect("schema loads");
+
+        assert_eq!(schema.version(), version);
+        assert!(schema.defines(SchemaType::Message));
+        assert!(schema.defines(SchemaType::ContentPart));
+    }
+}
+
+#[rstest]
+fn versions_follow_the_message_created_event() {
+    let upgrader = MessageCreatedUpgrader::new();
+
+    assert_eq!(CURRENT_SCHEMA_VERSION, upgrader.current_version());
+    assert!(
+        SCHEMA_VERSIONS
+            .iter()
+            .all(|&version| upgrader.supports_version(version))
+    );
+    assert!(schema_document(CURRENT_SCHEMA_VERSION + 1).is_none());
+    assert!(matches!(
+        MessageSchema::load(0),
+        Err(MessageSchemaError::UnknownVersion(0))
+    ));
+}
+
+#[rstest]
+fn serialised_messages_match_the_current_schema() {
+    let schema = MessageSchema::current().expect("schema");
+
+    schema
+        .validate(SchemaType::Message, &message_json())
+        .expect("message matches");
+}
+
+#[rstest]
+fn every_content_part_matches_the_current_schema() {
+    let schema = MessageSchema::current().expect("schema");
+
+    for part in every_part() {
+        let value = serde_json::to_value(&part).expect("serialise part");
+        schema
+            .validate(SchemaType::ContentPart, &value)
+            .expect("part matches");
+    }
+}
+
+#[rstest]
+fn legacy_metadata_keys_are_accepted() {
+    let schema = MessageSchema::current().expect("schema");
+
+    schema
+        .validate(
+            SchemaType::MessageMetadata,
+            &json!({ "agent_backend": "x", "acme.trace_id": "abc" }),
+        )
+        .expect("metadata matches");
+}
+
+#[rstest]
+fn violations_point_at_the_offending_values() {
+    let schema = MessageSchema::current().expect("schema");
+    let mut message = message_json();
+    message["role"] = json!("robot");
+    message["content"][0] = json!({ "type": "text", "txt": "typo" });
+    message["sequence_number"] = json!(0);
+
+    let found = violations(schema.validate(SchemaType::Message, &message));
+
+    assert!(found.contains(&(
+        "/role".to_owned(),
+        r#"expected one of "user", "assistant", "tool", "system""#.to_owned()
+    )));
+    assert!(found.contains(&(
+        "/content/0".to_owned(),
+        "missing required property `text`".to_owned()
+    )));
+    assert!(found.contains(&(
+        "/content/0/txt".to_owned(),
+        "unexpected property".to_owned()
+    )));
+    assert!(found.contains(&(
+        "/sequence_number".to_owned(),
+        "expected at least 1, found 0".to_owned()
+    )));
+}
+
+#[rstest]
+#[case::unknown_type(json!({ "type": "video" }), "", "matches none of the allowed alternatives")]
+#[case::not_an_object(json!("text"), "", "matches none of the allowed alternatives")]
+#[case::bad_blob(
+    json!({ "type": "attachment", "mime_type": "text/plain", "data": "", "blob": "XYZ" }),
+    "/blob",
+    "does not match the pattern ^[0-9a-f]{64}$"
+)]
+#[case::wrong_type(json!({ "type": "text", "text": 7 }), "/text", "expected string, found number")]
+fn invalid_parts_are_explained(#[case] part: Value, #[case] path: &str, #[case] message: &str) {
+    let schema = MessageSchema::current().expect("schema");
+
+    let found = violations(schema.validate(SchemaType::ContentPart, &part));
+
+    assert_eq!(found, [(path.to_owned(), message.to_owned())]);
+}
+
+#[rstest]
+#[case(2, false)]
+#[case(3, true)]
+fn image_parts_arrive_in_version_three(#[case] version: u32, #[case] accepted: bool) {
+    let schema = MessageSchema::load(version).expect("schema");
+    let image = json!({
+        "type": "image", "mime_type": "image/png", "data": "", "width": 1, "height": 1
+    });
+
+    assert_eq!(
+        schema.validate(SchemaType::ContentPart, &image).is_ok(),
+        accepted
+    );
+}
+
+#[rstest]
+fn version_one_predates_metadata() {
+    let schema = MessageSchema::load(1).expect("schema");
+    let mut message = message_json();
+    if let Some(fields) = message.as_object_mut() {
+        fields.remove("metadata");
+    }
+    message["content"] = json!([{ "type": "text", "text": "Hi" }]);
+
+    schema
+        .validate(SchemaType::Message, &message)
+        .expect("message without metadata matches");
+    assert!(!schema.defines(SchemaType::MessageMetadata));
+    assert!(matches!(
+        schema.json_schema(SchemaType::MessageMetadata),
+        Err(MessageSchemaError::UndefinedType { version: 1, .. })
+    ));
+}
+
+#[rstest]
+#[case(SchemaType::Message)]
+#[case(SchemaType::ContentPart)]
+#[case(SchemaType::MessageMetadata)]
+fn exported_schemas_are_rooted_at_their_type(#[case] schema_type: SchemaType) {
+    let schema = MessageSchema::current().expect("schema");
+
+    let exported = schema.json_schema(schema_type).expect("json schema");
+
+    assert_eq!(
+        exported.get("$ref"),
+        Some(&json!(format!("#/$defs/{}", schema_type.definition())))
+    );
+    assert_eq!(
+        exported.get("$schema"),
+        Some(&json!("https://json-schema.org/draft/2020-12/schema"))
+    );
+}
@@ -3,24 +3,78 @@
 //! Tests are organised by domain concept, covering happy paths, error cases,
 //! and edge cases for all public APIs.
 
+mod activity_tests;
 mod adapters_query_tests;
 mod adapters_storage_tests;
 mod adapters_test_support;
+mod archival_tests;
 mod audit_context_tests;
+mod batch_storage_tests;
+mod blob_store_tests;
+mod caching_tests;
+mod canonical_tests;
+mod compaction_tests;
+mod content_screening_tests;
 mod content_tests;
+mod conversation_comparison_tests;
+mod conversation_lifecycle_tests;
+mod conversation_list_tests;
 mod conversation_row_tests;
+mod conversation_transfer_tests;
+mod custom_content_tests;
 mod domain_event_tests;
+mod encryption_tests;
 mod error_tests;
+mod event_codec_tests;
+mod export_tests;
+mod feedback_tests;
+mod fork_tests;
 mod id_tests;
+mod inbound_email_tests;
+mod inbound_tests;
+mod incremental_snapshot_tests;
+mod ingestion_tests;
+mod label_tests;
+mod lifecycle_hook_tests;
+mod load_shedding_tests;
+mod maintenance_tests;
+mod message_query_tests;
+mod message_schema_tests;
 mod message_tests;
+mod metadata_tests;
 mod models_tests;
+mod optimistic_concurrency_tests;
+mod periodic_snapshot_tests;
+mod pinning_tests;
+mod processing_tests;
+mod redaction_tests;
 mod role_tests;
+mod rolling_summary_tests;
 mod row_to_message_tests;
+mod session_pause_tests;
+mod slash_command_audit_tests;
+mod slash_command_authorisation_tests;
+mod slash_command_catalog_tests;
+mod slash_command_expression_tests;
+mod slash_command_pack_tests;
+mod slash_command_parameter_tests;
+mod slash_command_resolution_tests;
 mod slash_command_tests;
+mod snapshot_retention_tests;
+mod streaming_tests;
+mod token_count_tests;
+mod tool_call_pairing_tests;
+mod turn_tests;
+mod upgrade_chain_tests;
+mod validation_attachment_tests;
 mod validation_config_tests;
 mod validation_content_tests;
 pub(crate) mod validation_fixtures;
 mod validation_limits_tests;
 mod validation_metadata_tests;
+mod validation_registry_tests;
+mod validation_rich_content_tests;
+mod validation_role_policy_tests;
 mod validation_structure_tests;
+mod version_negotiation_tests;
 mod versioning_tests;
@@ -0,0 +1,146 @@
+//! Unit tests for optimistic concurrency on conversations and sessions.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemoryAgentSessionRepository, InMemoryConversationRepository},
+    domain::{AgentSession, Conversation, ConversationId, SequenceNumber},
+    ports::{
+        AgentSessionRepository, ConversationRepository, agent_session::SessionError,
+        conversation::ConversationRepositoryError,
+    },
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+#[rstest]
+#[tokio::test]
+async fn conversation_updates_bump_the_stored_version(ctx: RequestContext) {
+    let conversations = InMemoryConversationRepository::new();
+    let mut conversation = Conversation::new(&DefaultClock);
+    conversations
+        .store(&ctx, &conversation)
+        .await
+        .expect("store");
+
+    conversation.pause(&DefaultClock).expect("pause");
+    conversations
+        .update(&ctx, &conversation)
+        .await
+        .expect("update");
+    let stored = conversations
+        .find_by_id(&ctx, conversation.id())
+        .await
+        .expect("find")
+        .expect("stored");
+
+    assert_eq!(conversation.version(), 0);
+    assert_eq!(stored.version(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn stale_conversation_updates_are_rejected(ctx: RequestContext) {
+    let conversations = InMemoryConversationRepository::new();
+    let conversation = Conversation::new(&DefaultClock);
+    conversations
+        .store(&ctx, &conversation)
+        .await
+        .expect("store");
+    let mut first = conversation.clone();
+    let mut second = conversation.clone();
+
+    first.pause(&DefaultClock).expect("pause");
+    conversations
+        .update(&ctx, &first)
+        .await
+        .expect("first writer wins");
+    second.complete(&DefaultClock).expect("complete");
+    let result = conversations.update(&ctx, &second).await;
+
+    assert!(matches!(
+        result,
+        Err(ConversationRepositoryError::ConcurrentModification {
+            conversation_id,
+            expected: 0,
+            actual: 1,
+        }) if conversation_id == conversation.id()
+    ));
+    let stored = conversations
+        .find_by_id(&ctx, conversation.id())
+        .await
+        .expect("find")
+        .expect("stored");
+    assert_eq!(stored.state(), first.state());
+}
+
+#[rstest]
+#[tokio::test]
+async fn reloaded_conversations_can_be_updated_again(ctx: RequestContext) {
+    let conversations = InMemoryConversationRepository::new();
+    let mut conversation = Conversation::new(&DefaultClock);
+    conversations
+        .store(&ctx, &conversation)
+        .await
+        .expect("store");
+    conversation.pause(&DefaultClock).expect("pause");
+    conversations
+        .update(&ctx, &conversation)
+        .await
+        .expect("update");
+
+    let mut reloaded = conversations
+        .find_by_id(&ctx, conversation.id())
+        .await
+        .expect("find")
+        .expect("stored");
+    reloaded.resume(&DefaultClock).expect("resume");
+    conversations
+        .update(&ctx, &reloaded)
+        .await
+        .expect("update after reload");
+}
+
+#[rstest]
+#[tokio::test]
+async fn stale_session_updates_are_rejected(ctx: RequestContext) {
+    let sessions = InMemoryAgentSessionRepository::new();
+    let session = AgentSession::new(
+        ConversationId::new(),
+        "codex_cli",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+    sessions.store(&ctx, &session).await.expect("store");
+    let mut first = session.clone();
+    let mut second = session.clone();
+
+    assert!(first.pause());
+    sessions
+        .update(&ctx, &first)
+        .await
+        .expect("first writer wins");
+    second.complete(SequenceNumber::new(5), &DefaultClock);
+    let result = sessions.update(&ctx, &second).await;
+
+    assert!(matches!(
+        result,
+        Err(SessionError::ConcurrentModification {
+            session_id,
+            expected: 0,
+            actual: 1,
+        }) if session_id == session.session_id
+    ));
+    let stored = sessions
+        .find_by_id(&ctx, session.session_id)
+        .await
+        .expect("find")
+        .expect("stored");
+    assert_eq!(stored.state, first.state);
+    assert_eq!(stored.version, 1);
+}
@@ -0,0 +1,161 @@
+//! Unit tests for the periodic snapshot scheduler.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{
+        InMemoryAgentSessionRepository, InMemoryContextSnapshotAdapter,
+        InMemorySnapshotPolicyRepository,
+    },
+    domain::{
+        AgentSession, ContextWindowSnapshot, ConversationId, PeriodicSnapshotPolicy,
+        SequenceNumber, SnapshotInterval, SnapshotMark, SnapshotType,
+    },
+    ports::{AgentSessionRepository, SnapshotPolicyRepository},
+    services::{PeriodicSnapshotScheduler, SnapshotSchedule},
+};
+use crate::test_support::test_request_ctx;
+use chrono::Duration;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+struct Harness {
+    sessions: Arc<InMemoryAgentSessionRepository>,
+    policies: Arc<InMemorySnapshotPolicyRepository>,
+    scheduler: PeriodicSnapshotScheduler,
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let sessions = Arc::new(InMemoryAgentSessionRepository::new());
+    let policies = Arc::new(InMemorySnapshotPolicyRepository::new());
+    let scheduler = PeriodicSnapshotScheduler::new(
+        Arc::new(InMemoryContextSnapshotAdapter::new()),
+        Arc::clone(&sessions) as Arc<dyn AgentSessionRepository>,
+        Arc::clone(&policies) as Arc<dyn SnapshotPolicyRepository>,
+        Arc::new(DefaultClock),
+    );
+    Harness {
+        sessions,
+        policies,
+        scheduler,
+    }
+}
+
+fn every(value: u32) -> NonZeroU32 {
+    NonZeroU32::new(value).expect("non-zero interval")
+}
+
+async fn active_session(ctx: &RequestContext, harness: &Harness) -> AgentSession {
+    let session = AgentSession::new(
+        ConversationId::new(),
+        "claude",
+        SequenceNumber::new(3),
+        &DefaultClock,
+    );
+    harness
+        .sessions
+        .store(ctx, &session)
+        .await
+        .expect("store session");
+    session
+}
+
+async fn observe(
+    harness: &Harness,
+    ctx: &RequestContext,
+    (session, current): (&AgentSession, SnapshotMark),
+) -> Option<ContextWindowSnapshot> {
+    let SnapshotSchedule::Started(pending) =
+        harness
+            .scheduler
+            .observe(ctx, session.conversation_id, current)
+    else {
+        panic!("expected the capture to start");
+    };
+    pending.finished().await.expect("capture")
+}
+
+fn at(session: &AgentSession, sequence: u64) -> SnapshotMark {
+    SnapshotMark::new(SequenceNumber::new(sequence), session.started_at)
+}
+
+#[rstest]
+#[tokio::test]
+async fn conversations_without_a_policy_are_not_captured(ctx: RequestContext, harness: Harness) {
+    let session = active_session(&ctx, &harness).await;
+
+    let captured = observe(&harness, &ctx, (&session, at(&session, 500))).await;
+
+    assert_eq!(captured, None);
+}
+
+#[rstest]
+#[tokio::test]
+async fn policies_capture_every_n_messages(ctx: RequestContext, harness: Harness) {
+    let session = active_session(&ctx, &harness).await;
+    let policy = PeriodicSnapshotPolicy::new(
+        session.conversation_id,
+        SnapshotInterval::new().every_messages(every(5)),
+    );
+    harness
+        .policies
+        .set_policy(&ctx, &policy)
+        .await
+        .expect("set policy");
+
+    let early = observe(&harness, &ctx, (&session, at(&session, 7))).await;
+    let due = observe(&harness, &ctx, (&session, at(&session, 8)))
+        .await
+        .expect("snapshot due");
+    let after = observe(&harness, &ctx, (&session, at(&session, 12))).await;
+
+    assert_eq!(early, None);
+    assert_eq!(due.snapshot_type, SnapshotType::Periodic);
+    assert_eq!(due.session_id, session.session_id);
+    assert_eq!(due.sequence_range.start, SequenceNumber::new(3));
+    assert_eq!(due.sequence_range.end, SequenceNumber::new(8));
+    assert_eq!(after, None);
+}
+
+#[rstest]
+#[tokio::test]
+async fn the_default_interval_captures_after_m_minutes(ctx: RequestContext, harness: Harness) {
+    let harness = Harness {
+        scheduler: harness
+            .scheduler
+            .clone()
+            .with_default_interval(SnapshotInterval::new().every_minutes(every(10))),
+        ..harness
+    };
+    let session = active_session(&ctx, &harness).await;
+    let later = |minutes| {
+        SnapshotMark::new(
+            SequenceNumber::new(4),
+            session.started_at + Duration::minutes(minutes),
+        )
+    };
+
+    let early = observe(&harness, &ctx, (&session, later(9))).await;
+    let due = observe(&harness, &ctx, (&session, later(10))).await;
+
+    assert_eq!(early, None);
+    assert!(due.is_some_and(|snapshot| snapshot.snapshot_type == SnapshotType::Periodic));
+}
+
+#[rstest]
+#[tokio::test]
+async fn captures_beyond_the_in_flight_limit_are_deferred(ctx: RequestContext, harness: Harness) {
+    let session = active_session(&ctx, &harness).await;
+    let scheduler = harness.scheduler.with_max_in_flight(0);
+
+    let schedule = scheduler.observe(&ctx, session.conversation_id, at(&session, 9));
+
+    assert!(matches!(schedule, SnapshotSchedule::Deferred));
+}
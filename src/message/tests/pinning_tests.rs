@@ -0,0 +1,88 @@
+//! Unit tests for pinned messages.
+
+use super::adapters_test_support::{clock, ctx, make_message, repo};
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::InMemoryMessageRepository,
+    domain::{ConversationId, MessageId, MessageMetadata},
+    error::RepositoryError,
+    ports::repository::MessageRepository,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+
+#[rstest]
+fn pinned_metadata_is_serialised_only_when_set() {
+    let pinned = MessageMetadata::default().with_pinned(true);
+
+    assert!(pinned.has_key("pinned"));
+    assert!(!pinned.is_empty());
+    assert_eq!(
+        serde_json::to_value(&pinned).expect("serialise"),
+        json!({ "pinned": true })
+    );
+    assert_eq!(
+        serde_json::to_value(MessageMetadata::default().with_pinned(false)).expect("serialise"),
+        json!({})
+    );
+    let restored: MessageMetadata =
+        serde_json::from_value(json!({ "pinned": true })).expect("deserialise");
+    assert!(restored.pinned);
+}
+
+#[rstest]
+#[tokio::test]
+async fn pinned_messages_are_found_until_unpinned(
+    repo: InMemoryMessageRepository,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) {
+    let conversation_id = ConversationId::new();
+    let messages: Vec<_> = (1..=3)
+        .map(|seq| make_message(conversation_id, seq, &clock).expect("valid message"))
+        .collect();
+    for message in &messages {
+        repo.store(&ctx, message).await.expect("store");
+    }
+    let target = messages.get(1).expect("second message");
+
+    let pinned = repo.set_pinned(&ctx, target.id(), true).await.expect("pin");
+
+    assert!(pinned.is_pinned());
+    assert_eq!(pinned.content(), target.content());
+    let found = repo
+        .find_pinned(&ctx, conversation_id)
+        .await
+        .expect("find pinned");
+    assert_eq!(found, vec![pinned]);
+    assert!(
+        repo.find_pinned(&ctx, ConversationId::new())
+            .await
+            .expect("other conversation")
+            .is_empty()
+    );
+
+    let unpinned = repo
+        .set_pinned(&ctx, target.id(), false)
+        .await
+        .expect("unpin");
+
+    assert!(!unpinned.is_pinned());
+    assert!(
+        repo.find_pinned(&ctx, conversation_id)
+            .await
+            .expect("find pinned")
+            .is_empty()
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn pinning_a_missing_message_fails(repo: InMemoryMessageRepository, ctx: RequestContext) {
+    let missing = MessageId::new();
+
+    let result = repo.set_pinned(&ctx, missing, true).await;
+
+    assert!(matches!(result, Err(RepositoryError::NotFound(id)) if id == missing));
+}
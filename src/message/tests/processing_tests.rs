@@ -0,0 +1,251 @@
+//! Unit tests for message processing pipeline status.
+
+use super::adapters_test_support::ctx;
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemoryMessageProcessingRepository, InMemoryMessageRepository},
+    domain::{
+        ContentPart, ConversationId, Message, MessageProcessingStatus, ProcessingDomainError,
+        ProcessingStage, Role, SequenceNumber, StageState, StageStatus, StuckMessagesQuery,
+        TextPart,
+    },
+    ports::MessageRepository,
+    services::{MessageProcessingService, ProcessingServiceError, ReprocessRequest, StageReport},
+};
+use chrono::TimeDelta;
+use mockable::{Clock, DefaultClock};
+use rstest::rstest;
+use std::sync::Arc;
+
+type TestService = MessageProcessingService<
+    InMemoryMessageRepository,
+    InMemoryMessageProcessingRepository,
+    DefaultClock,
+>;
+
+fn message() -> Message {
+    Message::new(
+        ConversationId::new(),
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("Deploy?"))],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+async fn service_with(ctx: &RequestContext, message: &Message) -> TestService {
+    let messages = InMemoryMessageRepository::new();
+    messages.store(ctx, message).await.expect("store message");
+    MessageProcessingService::new(
+        Arc::new(messages),
+        Arc::new(InMemoryMessageProcessingRepository::new()),
+        Arc::new(DefaultClock),
+    )
+}
+
+async fn run_stage(
+    service: &TestService,
+    ctx: &RequestContext,
+    message: &Message,
+    report: StageReport,
+) -> StageStatus {
+    service
+        .begin_stage(ctx, message, report.stage)
+        .await
+        .expect("begin stage");
+    service
+        .finish_stage(ctx, report)
+        .await
+        .expect("finish stage")
+}
+
+#[rstest]
+fn finished_stages_cannot_finish_again() {
+    let mut status = StageStatus::started(&message(), ProcessingStage::Embedding, &DefaultClock);
+
+    status.fail("model timeout", &DefaultClock).expect("fail");
+
+    assert_eq!(status.state, StageState::Failed);
+    assert_eq!(status.last_error.as_deref(), Some("model timeout"));
+    assert_eq!(
+        status.succeed(&DefaultClock),
+        Err(ProcessingDomainError::StageNotInProgress(
+            ProcessingStage::Embedding
+        ))
+    );
+}
+
+#[rstest]
+fn retrying_clears_the_failure_and_counts_the_attempt() {
+    let mut status = StageStatus::started(&message(), ProcessingStage::Moderation, &DefaultClock);
+    status
+        .fail("classifier unavailable", &DefaultClock)
+        .expect("fail");
+
+    status.retry(&DefaultClock);
+
+    assert_eq!(status.state, StageState::InProgress);
+    assert_eq!(status.attempts, 2);
+    assert_eq!(status.last_error, None);
+}
+
+#[rstest]
+fn current_stage_is_the_earliest_unfinished_stage() {
+    let message = message();
+    let mut validation = StageStatus::started(&message, ProcessingStage::Validation, &DefaultClock);
+    validation.succeed(&DefaultClock).expect("succeed");
+    let moderation = StageStatus::started(&message, ProcessingStage::Moderation, &DefaultClock);
+
+    let status = MessageProcessingStatus::from_stages(message.id(), vec![moderation, validation]);
+
+    assert_eq!(status.current_stage(), Some(ProcessingStage::Moderation));
+    assert_eq!(
+        status.stages.first().map(|stage| stage.stage),
+        Some(ProcessingStage::Validation)
+    );
+    assert!(!status.is_complete());
+}
+
+#[rstest]
+#[case(ProcessingStage::Validation, "validation")]
+#[case(ProcessingStage::Projection, "projection")]
+fn stages_round_trip_through_storage_names(#[case] stage: ProcessingStage, #[case] name: &str) {
+    assert_eq!(stage.as_str(), name);
+    assert_eq!(ProcessingStage::try_from(name), Ok(stage));
+}
+
+#[rstest]
+#[tokio::test]
+async fn stages_report_progress_through_the_pipeline(ctx: RequestContext) {
+    let message = message();
+    let service = service_with(&ctx, &message).await;
+
+    for stage in ProcessingStage::ALL {
+        run_stage(
+            &service,
+            &ctx,
+            &message,
+            StageReport::succeeded(message.id(), stage),
+        )
+        .await;
+    }
+    let status = service
+        .status(&ctx, message.conversation_id(), message.id())
+        .await
+        .expect("status");
+
+    assert!(status.is_complete());
+    assert_eq!(status.stages.len(), ProcessingStage::ALL.len());
+}
+
+#[rstest]
+#[tokio::test]
+async fn stages_cannot_begin_twice_or_finish_unstarted(ctx: RequestContext) {
+    let message = message();
+    let service = service_with(&ctx, &message).await;
+
+    service
+        .begin_stage(&ctx, &message, ProcessingStage::Validation)
+        .await
+        .expect("begin");
+    let again = service
+        .begin_stage(&ctx, &message, ProcessingStage::Validation)
+        .await;
+    let unstarted = service
+        .finish_stage(
+            &ctx,
+            StageReport::succeeded(message.id(), ProcessingStage::Embedding),
+        )
+        .await;
+
+    assert!(matches!(
+        again,
+        Err(ProcessingServiceError::Domain(
+            ProcessingDomainError::StageInProgress(ProcessingStage::Validation)
+        ))
+    ));
+    assert!(matches!(
+        unstarted,
+        Err(ProcessingServiceError::StageNotStarted {
+            stage: ProcessingStage::Embedding,
+            ..
+        })
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn failed_stages_are_stuck_until_reprocessed(ctx: RequestContext) {
+    let message = message();
+    let service = service_with(&ctx, &message).await;
+    for stage in [ProcessingStage::Validation, ProcessingStage::Moderation] {
+        run_stage(
+            &service,
+            &ctx,
+            &message,
+            StageReport::succeeded(message.id(), stage),
+        )
+        .await;
+    }
+    run_stage(
+        &service,
+        &ctx,
+        &message,
+        StageReport::failed(message.id(), ProcessingStage::Embedding, "model timeout"),
+    )
+    .await;
+    let query = StuckMessagesQuery::new(
+        ProcessingStage::Embedding,
+        DefaultClock.utc() + TimeDelta::seconds(1),
+    );
+
+    let stuck = service.stuck_messages(&ctx, query).await.expect("stuck");
+    let reprocessed = service
+        .reprocess(
+            &ctx,
+            ReprocessRequest::new(
+                message.conversation_id(),
+                message.id(),
+                ProcessingStage::Moderation,
+            ),
+        )
+        .await
+        .expect("reprocess");
+
+    assert_eq!(stuck.len(), 1);
+    assert!(stuck.is_last());
+    assert_eq!(
+        stuck
+            .first()
+            .and_then(|status| status.last_error.as_deref()),
+        Some("model timeout")
+    );
+    assert_eq!(
+        reprocessed.current_stage(),
+        Some(ProcessingStage::Moderation)
+    );
+    assert_eq!(reprocessed.stage(ProcessingStage::Embedding), None);
+    assert_eq!(
+        reprocessed
+            .stage(ProcessingStage::Moderation)
+            .map(|status| (status.state, status.attempts)),
+        Some((StageState::InProgress, 2))
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn status_requires_the_message_in_the_conversation(ctx: RequestContext) {
+    let message = message();
+    let service = service_with(&ctx, &message).await;
+
+    let result = service
+        .status(&ctx, ConversationId::new(), message.id())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ProcessingServiceError::MessageNotFound { message_id, .. }) if message_id == message.id()
+    ));
+}
@@ -0,0 +1,166 @@
+//! Unit tests for message redaction.
+
+use super::adapters_test_support::{clock, ctx, make_message, repo};
+use super::validation_fixtures::default_validator;
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::InMemoryMessageRepository,
+    domain::{
+        ContentPart, ConversationId, MAX_REDACTION_REASON_CHARS, Message, MessageId,
+        MessageMetadata, MessageRedaction, RedactedPart, RedactionDomainError, RedactionReason,
+        Role, SequenceNumber, SlashCommandExpansion, TextPart,
+    },
+    error::{RepositoryError, ValidationError},
+    ports::{repository::MessageRepository, validator::MessageValidator},
+    validation::service::DefaultMessageValidator,
+};
+use crate::pagination::PageRequest;
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+
+fn redaction_of(message_id: MessageId, ctx: &RequestContext) -> MessageRedaction {
+    let reason = RedactionReason::new("pasted API key").expect("valid reason");
+    MessageRedaction::new(message_id, reason, ctx.user_id(), &DefaultClock)
+}
+
+#[rstest]
+#[case::blank(" \t", RedactionDomainError::EmptyReason)]
+#[case::too_long(
+    &"x".repeat(MAX_REDACTION_REASON_CHARS + 1),
+    RedactionDomainError::ReasonTooLong {
+        max: MAX_REDACTION_REASON_CHARS,
+        actual: MAX_REDACTION_REASON_CHARS + 1,
+    }
+)]
+fn redaction_reasons_are_validated(#[case] reason: &str, #[case] expected: RedactionDomainError) {
+    assert_eq!(RedactionReason::new(reason), Err(expected));
+}
+
+#[rstest]
+fn redacted_messages_keep_identity_and_scrub_command_input(ctx: RequestContext) {
+    let expansion = SlashCommandExpansion::new("/deploy", "deploy with token s3cret")
+        .with_parameter("token", json!("s3cret"));
+    let message = Message::builder(ConversationId::new(), Role::User, SequenceNumber::new(4))
+        .with_content(ContentPart::Text(TextPart::new("my token is s3cret")))
+        .with_metadata(MessageMetadata {
+            slash_command_expansion: Some(expansion),
+            ..MessageMetadata::default()
+        })
+        .build(&DefaultClock)
+        .expect("valid message");
+    let redaction = redaction_of(message.id(), &ctx);
+
+    let redacted = message.clone().redacted(&redaction);
+
+    assert!(redacted.is_redacted());
+    assert!(!message.is_redacted());
+    assert_eq!(redacted.id(), message.id());
+    assert_eq!(redacted.sequence_number(), message.sequence_number());
+    assert_eq!(redacted.created_at(), message.created_at());
+    assert_eq!(
+        redacted.content(),
+        [ContentPart::Redacted(RedactedPart {
+            reason: "pasted API key".to_owned(),
+            redacted_at: redaction.redacted_at(),
+        })]
+    );
+    let scrubbed = redacted
+        .metadata()
+        .slash_command_expansion
+        .as_ref()
+        .expect("expansion kept");
+    assert_eq!(scrubbed.command, "/deploy");
+    assert!(scrubbed.parameters.is_empty());
+    assert!(scrubbed.expanded_content.is_empty());
+}
+
+#[rstest]
+fn new_messages_cannot_carry_redacted_placeholders(
+    default_validator: DefaultMessageValidator,
+    ctx: RequestContext,
+) {
+    let conversation_id = ConversationId::new();
+    let redaction = redaction_of(MessageId::new(), &ctx);
+    let message = Message::new(
+        conversation_id,
+        Role::User,
+        vec![redaction.placeholder()],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )
+    .expect("valid message");
+
+    let result = default_validator.validate(&message);
+
+    assert!(matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 0, .. })
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn redact_replaces_stored_content_in_place(
+    repo: InMemoryMessageRepository,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) {
+    let conversation_id = ConversationId::new();
+    for seq in 1..=3 {
+        let message = make_message(conversation_id, seq, &clock).expect("valid message");
+        repo.store(&ctx, &message).await.expect("store");
+    }
+    let page = repo
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
+        .await
+        .expect("history");
+    let target = page.items().get(1).expect("second message").clone();
+
+    let redacted = repo
+        .redact(&ctx, &redaction_of(target.id(), &ctx))
+        .await
+        .expect("redact");
+
+    let history = repo
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
+        .await
+        .expect("history");
+    let sequences: Vec<u64> = history
+        .items()
+        .iter()
+        .map(|message| message.sequence_number().value())
+        .collect();
+    assert_eq!(sequences, [1, 2, 3]);
+    assert_eq!(
+        repo.find_by_id(&ctx, target.id()).await.expect("find"),
+        Some(redacted)
+    );
+    assert!(
+        history
+            .items()
+            .iter()
+            .all(|message| message.id() == target.id() || !message.is_redacted())
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn redact_rejects_missing_and_already_redacted_messages(
+    repo: InMemoryMessageRepository,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) {
+    let message = make_message(ConversationId::new(), 1, &clock).expect("valid message");
+    repo.store(&ctx, &message).await.expect("store");
+    let missing = MessageId::new();
+
+    let not_found = repo.redact(&ctx, &redaction_of(missing, &ctx)).await;
+    repo.redact(&ctx, &redaction_of(message.id(), &ctx))
+        .await
+        .expect("first redaction");
+    let repeated = repo.redact(&ctx, &redaction_of(message.id(), &ctx)).await;
+
+    assert!(matches!(not_found, Err(RepositoryError::NotFound(id)) if id == missing));
+    assert!(matches!(repeated, Err(RepositoryError::AlreadyRedacted(id)) if id == message.id()));
+}
@@ -0,0 +1,251 @@
+//! Unit tests for rolling conversation summaries.
+
+use crate::context::RequestContext;
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::message::{
+    adapters::memory::{
+        InMemoryConversationRepository, InMemoryMessageRepository, InMemoryRollingSummaryRepository,
+    },
+    domain::{ContentPart, ConversationId, ConversationLifecyclePoint, Message, Role, TextPart},
+    ports::{ConversationSummariser, SummariserError, SummariserResult},
+    services::{
+        AppendMessageRequest, ConversationLifecycleHooks, ConversationService,
+        RollingSummaryService, RollingSummaryServiceError,
+    },
+    validation::service::DefaultMessageValidator,
+};
+use crate::operator::domain::OperatorReason;
+use crate::test_support::test_request_ctx;
+use async_trait::async_trait;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+type TestSummaries = RollingSummaryService<
+    InMemoryMessageRepository,
+    InMemoryRollingSummaryRepository,
+    DefaultClock,
+>;
+
+type TestConversations = ConversationService<
+    InMemoryConversationRepository,
+    InMemoryMessageRepository,
+    DefaultMessageValidator,
+    DefaultClock,
+>;
+
+/// Summariser that describes which messages each call saw.
+#[derive(Default)]
+struct RecordingSummariser {
+    calls: Mutex<Vec<String>>,
+    offline: AtomicBool,
+}
+
+impl RecordingSummariser {
+    fn calls(&self) -> Vec<String> {
+        self.calls.lock().expect("calls lock").clone()
+    }
+
+    fn record(&self, call: String) -> SummariserResult<String> {
+        if self.offline.load(Ordering::SeqCst) {
+            return Err(SummariserError::new("summariser offline"));
+        }
+        self.calls.lock().expect("calls lock").push(call.clone());
+        Ok(call)
+    }
+}
+
+fn sequences(messages: &[Message]) -> String {
+    messages
+        .iter()
+        .map(|message| message.sequence_number().to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[async_trait]
+impl ConversationSummariser for RecordingSummariser {
+    async fn summarise(
+        &self,
+        _ctx: &RequestContext,
+        messages: &[Message],
+    ) -> SummariserResult<String> {
+        self.record(format!("summarise {}", sequences(messages)))
+    }
+
+    async fn extend(
+        &self,
+        _ctx: &RequestContext,
+        _summary: &str,
+        messages: &[Message],
+    ) -> SummariserResult<String> {
+        self.record(format!("extend {}", sequences(messages)))
+    }
+}
+
+struct Harness {
+    conversations: TestConversations,
+    summaries: Arc<TestSummaries>,
+    summariser: Arc<RecordingSummariser>,
+}
+
+impl Harness {
+    async fn say(&self, ctx: &RequestContext, conversation_id: ConversationId, text: &str) {
+        self.conversations
+            .append_message(
+                ctx,
+                AppendMessageRequest::new(
+                    conversation_id,
+                    Role::User,
+                    vec![ContentPart::Text(TextPart::new(text))],
+                ),
+            )
+            .await
+            .expect("append");
+    }
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let messages = Arc::new(InMemoryMessageRepository::new());
+    let summariser = Arc::new(RecordingSummariser::default());
+    let summaries = Arc::new(
+        RollingSummaryService::new(
+            messages.clone(),
+            Arc::new(InMemoryRollingSummaryRepository::new()),
+            summariser.clone(),
+            Arc::new(DefaultClock),
+        )
+        .with_recompute_interval(2),
+    );
+    let mut hooks = ConversationLifecycleHooks::new();
+    hooks.register(
+        "rolling-summary",
+        [ConversationLifecyclePoint::MessageStored],
+        summaries.clone(),
+    );
+    let conversations = ConversationService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        messages,
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_lifecycle_hooks(Arc::new(hooks));
+    Harness {
+        conversations,
+        summaries,
+        summariser,
+    }
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn stored_messages_extend_the_summary_until_it_is_recomputed(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation = harness
+        .conversations
+        .create_conversation(&ctx)
+        .await
+        .expect("create");
+
+    for text in ["one", "two", "three", "four"] {
+        harness.say(&ctx, conversation.id(), text).await;
+    }
+
+    assert_eq!(
+        harness.summariser.calls(),
+        vec!["summarise 1", "extend 2", "extend 3", "summarise 1,2,3,4"]
+    );
+    let summary = harness
+        .summaries
+        .summary(&ctx, conversation.id())
+        .await
+        .expect("summary read")
+        .expect("summary stored");
+    assert_eq!(summary.text(), "summarise 1,2,3,4");
+    assert_eq!(summary.covered_through().value(), 4);
+    assert_eq!(summary.incremental_updates(), 0);
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn summaries_catch_up_on_messages_missed_while_the_summariser_failed(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation = harness
+        .conversations
+        .create_conversation(&ctx)
+        .await
+        .expect("create");
+    harness.say(&ctx, conversation.id(), "one").await;
+
+    harness.summariser.offline.store(true, Ordering::SeqCst);
+    harness.say(&ctx, conversation.id(), "two").await;
+    harness.summariser.offline.store(false, Ordering::SeqCst);
+    harness.say(&ctx, conversation.id(), "three").await;
+
+    assert_eq!(
+        harness.summariser.calls(),
+        vec!["summarise 1", "extend 2,3"]
+    );
+    let summary = harness
+        .summaries
+        .summary(&ctx, conversation.id())
+        .await
+        .expect("summary read")
+        .expect("summary stored");
+    assert_eq!(summary.covered_through().value(), 3);
+    assert_eq!(summary.incremental_updates(), 1);
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn conversations_without_messages_have_no_summary(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation = harness
+        .conversations
+        .create_conversation(&ctx)
+        .await
+        .expect("create");
+
+    let refreshed = harness
+        .summaries
+        .refresh(&ctx, conversation.id())
+        .await
+        .expect("refresh");
+
+    assert!(refreshed.is_none());
+    assert!(harness.summariser.calls().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn summaries_are_not_updated_during_maintenance() {
+    let ctx = test_request_ctx();
+    let summariser = Arc::new(RecordingSummariser::default());
+    let gate = Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ));
+    let summaries = RollingSummaryService::new(
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(InMemoryRollingSummaryRepository::new()),
+        summariser.clone(),
+        Arc::new(DefaultClock),
+    )
+    .with_maintenance(Arc::clone(&gate));
+    let reason = OperatorReason::new("failover").expect("valid reason");
+    gate.enable(reason, None).await.expect("enable maintenance");
+
+    let refreshed = summaries.refresh(&ctx, ConversationId::new()).await;
+    let recomputed = summaries.recompute(&ctx, ConversationId::new()).await;
+
+    assert!(matches!(
+        refreshed,
+        Err(RollingSummaryServiceError::MaintenanceMode(_))
+    ));
+    assert!(matches!(
+        recomputed,
+        Err(RollingSummaryServiceError::MaintenanceMode(_))
+    ));
+    assert!(summariser.calls().is_empty());
+}
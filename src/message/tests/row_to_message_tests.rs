@@ -6,7 +6,7 @@
 use crate::message::{
     adapters::models::MessageRow,
     adapters::postgres::row_to_message,
-    domain::{AgentResponseStatus, Role, ToolCallStatus},
+    domain::{AgentResponseStatus, Role, SequenceNumberError, ToolCallStatus},
     error::RepositoryError,
 };
 use chrono::Utc;
@@ -150,6 +150,21 @@ fn row_to_message_fails_for_negative_sequence_number(message_row: MessageRow) {
     }
 }
 
+#[rstest]
+fn row_to_message_rejects_zero_sequence_number(message_row: MessageRow) {
+    let row = MessageRow {
+        sequence_number: 0,
+        ..message_row
+    };
+
+    let result = row_to_message(row);
+
+    assert!(matches!(
+        result,
+        Err(RepositoryError::InvalidSequenceNumber(SequenceNumberError))
+    ));
+}
+
 #[rstest]
 fn row_to_message_handles_max_valid_sequence_number(message_row: MessageRow) {
     let row = MessageRow {
@@ -0,0 +1,197 @@
+//! Unit tests for pausing and resuming agent sessions.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemoryAgentSessionRepository, InMemoryContextSnapshotAdapter},
+    domain::{AgentSession, AgentSessionState, ConversationId, SequenceNumber, SnapshotType},
+    ports::{AgentSessionRepository, ContextSnapshotPort},
+    services::{AgentSessionService, AgentSessionServiceError},
+};
+use crate::pagination::PageRequest;
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+struct Harness {
+    sessions: Arc<InMemoryAgentSessionRepository>,
+    snapshots: Arc<InMemoryContextSnapshotAdapter>,
+    service: AgentSessionService<
+        InMemoryAgentSessionRepository,
+        InMemoryContextSnapshotAdapter,
+        DefaultClock,
+    >,
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let sessions = Arc::new(InMemoryAgentSessionRepository::new());
+    let snapshots = Arc::new(InMemoryContextSnapshotAdapter::new());
+    let service = AgentSessionService::new(
+        Arc::clone(&sessions),
+        Arc::clone(&snapshots),
+        Arc::new(DefaultClock),
+    );
+    Harness {
+        sessions,
+        snapshots,
+        service,
+    }
+}
+
+async fn active_session(ctx: &RequestContext, harness: &Harness) -> AgentSession {
+    let session = AgentSession::new(
+        ConversationId::new(),
+        "claude",
+        SequenceNumber::new(3),
+        &DefaultClock,
+    );
+    harness
+        .sessions
+        .store(ctx, &session)
+        .await
+        .expect("store session");
+    session
+}
+
+#[rstest]
+#[tokio::test]
+async fn pausing_captures_the_context_window(ctx: RequestContext, harness: Harness) {
+    let session = active_session(&ctx, &harness).await;
+
+    let paused = harness
+        .service
+        .pause(&ctx, session.session_id, SequenceNumber::new(9))
+        .await
+        .expect("pause");
+
+    assert_eq!(paused.state, AgentSessionState::Paused);
+    let [recorded] = paused.context_snapshots.as_slice() else {
+        panic!("expected one snapshot, got {:?}", paused.context_snapshots);
+    };
+    assert_eq!(recorded.snapshot_type, SnapshotType::SessionPaused);
+    assert_eq!(recorded.sequence_range.start, SequenceNumber::new(3));
+    assert_eq!(recorded.sequence_range.end, SequenceNumber::new(9));
+    let stored = harness
+        .snapshots
+        .find_snapshots_for_session(&ctx, session.session_id, PageRequest::default())
+        .await
+        .expect("list snapshots");
+    assert_eq!(stored.into_items(), [recorded.clone()]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn resuming_returns_the_pause_snapshot(ctx: RequestContext, harness: Harness) {
+    let session = active_session(&ctx, &harness).await;
+    let paused = harness
+        .service
+        .pause(&ctx, session.session_id, SequenceNumber::new(9))
+        .await
+        .expect("pause");
+
+    let resumed = harness
+        .service
+        .resume(&ctx, session.session_id)
+        .await
+        .expect("resume");
+
+    assert_eq!(resumed.session.state, AgentSessionState::Active);
+    assert_eq!(resumed.session.context_snapshots, paused.context_snapshots);
+    assert_eq!(
+        resumed.pause_snapshot.as_ref(),
+        paused.context_snapshots.last()
+    );
+    let stored = harness
+        .sessions
+        .find_by_id(&ctx, session.session_id)
+        .await
+        .expect("find session")
+        .expect("session exists");
+    assert_eq!(stored.state, AgentSessionState::Active);
+}
+
+#[rstest]
+#[tokio::test]
+async fn resuming_restores_a_lost_pause_snapshot(ctx: RequestContext, harness: Harness) {
+    let session = active_session(&ctx, &harness).await;
+    harness
+        .service
+        .pause(&ctx, session.session_id, SequenceNumber::new(9))
+        .await
+        .expect("pause");
+    let mut forgetful = harness
+        .sessions
+        .find_by_id(&ctx, session.session_id)
+        .await
+        .expect("find session")
+        .expect("session exists");
+    forgetful.context_snapshots.clear();
+    harness
+        .sessions
+        .update(&ctx, &forgetful)
+        .await
+        .expect("update session");
+
+    let resumed = harness
+        .service
+        .resume(&ctx, session.session_id)
+        .await
+        .expect("resume");
+
+    let pause_snapshot = resumed.pause_snapshot.expect("pause snapshot found");
+    assert_eq!(resumed.session.context_snapshots, [pause_snapshot]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn invalid_transitions_are_refused(ctx: RequestContext, harness: Harness) {
+    let session = active_session(&ctx, &harness).await;
+    let missing = AgentSession::new(
+        ConversationId::new(),
+        "claude",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+
+    let resume_active = harness.service.resume(&ctx, session.session_id).await;
+    harness
+        .service
+        .pause(&ctx, session.session_id, SequenceNumber::new(4))
+        .await
+        .expect("pause");
+    let pause_paused = harness
+        .service
+        .pause(&ctx, session.session_id, SequenceNumber::new(5))
+        .await;
+    let pause_missing = harness
+        .service
+        .pause(&ctx, missing.session_id, SequenceNumber::new(1))
+        .await;
+
+    assert!(matches!(
+        resume_active,
+        Err(AgentSessionServiceError::InvalidTransition {
+            from: AgentSessionState::Active,
+            to: AgentSessionState::Active,
+            ..
+        })
+    ));
+    assert!(matches!(
+        pause_paused,
+        Err(AgentSessionServiceError::InvalidTransition {
+            from: AgentSessionState::Paused,
+            to: AgentSessionState::Paused,
+            ..
+        })
+    ));
+    assert!(matches!(
+        pause_missing,
+        Err(AgentSessionServiceError::SessionNotFound(id)) if id == missing.session_id
+    ));
+}
@@ -0,0 +1,154 @@
+//! Unit tests for the slash-command execution audit trail.
+
+use crate::context::{RequestContext, UserId};
+use crate::message::{
+    adapters::memory::{InMemorySlashCommandExecutionRepository, InMemorySlashCommandRegistry},
+    domain::{
+        CommandParameterSpec, CommandParameterType, ConversationId, MessageMetadata,
+        PersistedSlashCommandExecution, SlashCommandDefinition, SlashCommandExecution,
+        SlashCommandExecutionId, SlashCommandExecutionRecord,
+    },
+    ports::slash_command_execution::{SlashCommandExecutionError, SlashCommandExecutionRepository},
+    services::{SlashCommandAuditTrail, SlashCommandService},
+};
+use crate::pagination::{Limit, PageRequest};
+use crate::test_support::test_request_ctx;
+use chrono::{TimeDelta, Utc};
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+type AuditTrail = SlashCommandAuditTrail<InMemorySlashCommandExecutionRepository, DefaultClock>;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+fn execute(raw: &str) -> SlashCommandExecution {
+    let registry = InMemorySlashCommandRegistry::with_commands(vec![
+        SlashCommandDefinition::new("review", "Review", "Review {{ target }}.").with_parameter(
+            CommandParameterSpec::new("target", CommandParameterType::String, true),
+        ),
+    ])
+    .expect("registry");
+    SlashCommandService::new(Arc::new(registry))
+        .execute(raw)
+        .expect("executes")
+}
+
+fn trail() -> (AuditTrail, Arc<InMemorySlashCommandExecutionRepository>) {
+    let repository = Arc::new(InMemorySlashCommandExecutionRepository::new());
+    let trail = SlashCommandAuditTrail::new(Arc::clone(&repository), Arc::new(DefaultClock));
+    (trail, repository)
+}
+
+#[rstest]
+#[tokio::test]
+async fn recording_links_the_expansion_to_the_record(ctx: RequestContext) {
+    let (audit, _) = trail();
+    let conversation_id = ConversationId::new();
+
+    let record = audit
+        .record(&ctx, conversation_id, execute("/review target=main"))
+        .await
+        .expect("recorded");
+
+    assert_eq!(record.invoked_by(), ctx.user_id());
+    assert_eq!(record.expansion().execution_id, Some(record.id()));
+    let metadata =
+        MessageMetadata::default().with_slash_command_expansion(record.expansion().clone());
+    let linked = metadata
+        .slash_command_expansion
+        .and_then(|expansion| expansion.execution_id)
+        .expect("linked");
+    let found = audit.find(&ctx, linked).await.expect("lookup");
+    assert_eq!(found, Some(record));
+}
+
+#[rstest]
+#[tokio::test]
+async fn conversation_history_is_ordered_and_scoped(ctx: RequestContext) {
+    let (audit, repository) = trail();
+    let conversation_id = ConversationId::new();
+    let started = Utc::now();
+    let records: Vec<SlashCommandExecutionRecord> = ["/review target=b", "/review target=a"]
+        .into_iter()
+        .zip([TimeDelta::seconds(2), TimeDelta::seconds(1)])
+        .map(|(raw, offset)| {
+            SlashCommandExecutionRecord::from_persisted(PersistedSlashCommandExecution {
+                id: SlashCommandExecutionId::new(),
+                conversation_id,
+                invoked_by: UserId::new(),
+                executed_at: started + offset,
+                execution: execute(raw),
+            })
+        })
+        .collect();
+    for record in &records {
+        repository.store(&ctx, record).await.expect("stored");
+    }
+    audit
+        .record(&ctx, ConversationId::new(), execute("/review target=c"))
+        .await
+        .expect("other conversation");
+
+    let first = audit
+        .for_conversation(
+            &ctx,
+            conversation_id,
+            PageRequest::new(Limit::new(1).expect("limit")),
+        )
+        .await
+        .expect("first page");
+    let cursor = first.next_cursor().expect("more records");
+    let second = audit
+        .for_conversation(
+            &ctx,
+            conversation_id,
+            PageRequest::new(Limit::new(1).expect("limit")).with_cursor(cursor),
+        )
+        .await
+        .expect("second page");
+    let other_tenant = audit
+        .for_conversation(&test_request_ctx(), conversation_id, PageRequest::default())
+        .await
+        .expect("other tenant");
+
+    assert_eq!(first.items(), records.get(1..2).expect("older record"));
+    assert_eq!(second.items(), records.get(..1).expect("newer record"));
+    assert!(second.is_last());
+    assert!(other_tenant.items().is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn records_are_stored_once(ctx: RequestContext) {
+    let (audit, repository) = trail();
+    let record = audit
+        .record(&ctx, ConversationId::new(), execute("/review target=main"))
+        .await
+        .expect("recorded");
+
+    let result = repository.store(&ctx, &record).await;
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandExecutionError::Duplicate(id)) if id == record.id()
+    ));
+}
+
+#[rstest]
+fn records_round_trip_through_json() {
+    let record = SlashCommandExecutionRecord::new(
+        ConversationId::new(),
+        UserId::new(),
+        execute("/review target=main"),
+        &DefaultClock,
+    );
+
+    let json = serde_json::to_value(&record).expect("serialize");
+    let decoded: SlashCommandExecutionRecord = serde_json::from_value(json).expect("deserialize");
+
+    assert_eq!(decoded, record);
+}
@@ -0,0 +1,193 @@
+//! Unit tests for slash-command permissions and authorisation.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemorySlashCommandAuthoriser, InMemorySlashCommandRegistry},
+    domain::{
+        CommandParameterSpec, CommandParameterType, CommandPermission,
+        SlashCommandAuthorisationError, SlashCommandDefinition, SlashCommandError,
+        SlashCommandPack, SlashCommandPackFormat, SlashCommandSchemaError, ToolCallTemplate,
+    },
+    ports::{
+        slash_command::SlashCommandRegistryError,
+        slash_command_authoriser::{SlashCommandAuthorisationResult, SlashCommandAuthoriser},
+    },
+    services::SlashCommandService,
+};
+use crate::test_support::test_request_ctx;
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+type Service = SlashCommandService<InMemorySlashCommandRegistry>;
+
+fn deploy() -> SlashCommandDefinition {
+    SlashCommandDefinition::new("deploy", "Deploy", "Deploy {{ env }}.")
+        .with_parameter(CommandParameterSpec::new(
+            "env",
+            CommandParameterType::String,
+            true,
+        ))
+        .with_permission(CommandPermission::role("maintainer"))
+        .with_permission(CommandPermission::scope("deploy:write"))
+        .with_tool_call(ToolCallTemplate::new(
+            "deployer",
+            "{\"env\":{{ env | json_string }}}",
+        ))
+}
+
+struct Harness {
+    authoriser: Arc<InMemorySlashCommandAuthoriser>,
+    service: Service,
+}
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let registry = InMemorySlashCommandRegistry::with_commands([
+        deploy(),
+        SlashCommandDefinition::new("status", "Status", "Status."),
+    ])
+    .expect("registry");
+    let authoriser = Arc::new(InMemorySlashCommandAuthoriser::new());
+    let service = SlashCommandService::new(Arc::new(registry))
+        .with_authoriser(Arc::clone(&authoriser) as Arc<dyn SlashCommandAuthoriser>);
+    Harness {
+        authoriser,
+        service,
+    }
+}
+
+fn denied(missing: Vec<CommandPermission>) -> SlashCommandError {
+    SlashCommandError::PermissionDenied {
+        command: "deploy".to_owned(),
+        missing,
+    }
+}
+
+#[rstest]
+fn unrestricted_commands_need_no_caller(harness: Harness) {
+    let execution = harness.service.execute("/status").expect("status runs");
+
+    assert_eq!(execution.expansion().expanded_content, "Status.");
+}
+
+#[rstest]
+fn restricted_commands_deny_anonymous_callers(harness: Harness) {
+    let error = harness
+        .service
+        .execute("/deploy env=prod")
+        .expect_err("no caller");
+
+    assert_eq!(error, denied(deploy().permissions));
+    assert_eq!(
+        error.to_string(),
+        "permission denied for command '/deploy': missing role:maintainer, scope:deploy:write"
+    );
+}
+
+#[rstest]
+fn denial_lists_only_missing_permissions(ctx: RequestContext, harness: Harness) {
+    harness
+        .authoriser
+        .grant(&ctx, CommandPermission::role("maintainer"))
+        .expect("grant");
+
+    let error = harness
+        .service
+        .execute_for(&ctx, "/deploy")
+        .expect_err("scope missing");
+
+    assert_eq!(
+        error,
+        denied(vec![CommandPermission::scope("deploy:write")])
+    );
+}
+
+#[rstest]
+fn callers_holding_every_permission_plan_tool_calls(ctx: RequestContext, harness: Harness) {
+    for permission in deploy().permissions {
+        harness.authoriser.grant(&ctx, permission).expect("grant");
+    }
+
+    let execution = harness
+        .service
+        .execute_for(&ctx, "/deploy env=prod")
+        .expect("authorised");
+    let other_user = harness
+        .service
+        .execute_for(&test_request_ctx(), "/deploy env=prod")
+        .expect_err("grants are per user");
+
+    assert_eq!(execution.planned_tool_calls().len(), 1);
+    assert_eq!(other_user, denied(deploy().permissions));
+}
+
+struct FailingAuthoriser;
+
+impl SlashCommandAuthoriser for FailingAuthoriser {
+    fn holds(
+        &self,
+        _ctx: &RequestContext,
+        _permission: &CommandPermission,
+    ) -> SlashCommandAuthorisationResult<bool> {
+        Err(SlashCommandAuthorisationError::new("directory offline"))
+    }
+}
+
+#[rstest]
+fn authoriser_failures_are_reported(ctx: RequestContext) {
+    let registry = InMemorySlashCommandRegistry::with_commands([deploy()]).expect("registry");
+    let service = SlashCommandService::new(Arc::new(registry))
+        .with_authoriser(Arc::new(FailingAuthoriser) as Arc<dyn SlashCommandAuthoriser>);
+
+    let error = service
+        .execute_for(&ctx, "/deploy env=prod")
+        .expect_err("authoriser fails");
+
+    assert_eq!(
+        error,
+        SlashCommandError::AuthorisationUnavailable {
+            source: SlashCommandAuthorisationError::new("directory offline"),
+        }
+    );
+}
+
+#[rstest]
+#[case::empty(CommandPermission::role(""))]
+#[case::whitespace(CommandPermission::scope("deploy write"))]
+fn malformed_permissions_are_rejected(#[case] permission: CommandPermission) {
+    let definition =
+        SlashCommandDefinition::new("deploy", "Deploy", "Deploy.").with_permission(permission);
+
+    let result = InMemorySlashCommandRegistry::with_commands([definition]);
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandRegistryError::InvalidDefinition(
+            SlashCommandSchemaError::InvalidCommandDefinition { .. }
+        ))
+    ));
+}
+
+#[rstest]
+fn packs_and_help_declare_permissions(harness: Harness) {
+    let source = concat!(
+        "[[commands]]\nname = \"deploy\"\ndescription = \"Deploy\"\ntemplate = \"Deploy.\"\n",
+        "roles = [\"maintainer\"]\nscopes = [\"deploy:write\"]\n",
+    );
+
+    let pack = SlashCommandPack::parse(SlashCommandPackFormat::Toml, source).expect("valid pack");
+    let help = harness.service.execute("/help deploy").expect("help");
+
+    let definition = pack.definitions().first().expect("deploy command");
+    assert_eq!(definition.permissions, deploy().permissions);
+    assert!(
+        help.expansion()
+            .expanded_content
+            .contains("Requires: role:maintainer, scope:deploy:write")
+    );
+}
@@ -0,0 +1,181 @@
+//! Unit tests for versioned slash-command definitions.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemorySlashCommandDefinitionRepository, InMemorySlashCommandRegistry},
+    domain::{
+        CommandParameterSpec, CommandParameterType, SlashCommandDefinition, SlashCommandSchemaError,
+    },
+    ports::{SlashCommandDefinitionError, SlashCommandDefinitionRepository, SlashCommandRegistry},
+    services::{SlashCommandCatalog, SlashCommandCatalogError},
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+type Catalog = SlashCommandCatalog<InMemorySlashCommandDefinitionRepository, DefaultClock>;
+
+struct Harness {
+    repository: Arc<InMemorySlashCommandDefinitionRepository>,
+    catalog: Catalog,
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let repository = Arc::new(InMemorySlashCommandDefinitionRepository::new());
+    let catalog = SlashCommandCatalog::new(Arc::clone(&repository), Arc::new(DefaultClock));
+    Harness {
+        repository,
+        catalog,
+    }
+}
+
+fn deploy(description: &str) -> SlashCommandDefinition {
+    SlashCommandDefinition::new("Deploy", description, "Deploy {{ target }}.").with_parameter(
+        CommandParameterSpec::new("target", CommandParameterType::String, true),
+    )
+}
+
+fn version(value: u32) -> NonZeroU32 {
+    NonZeroU32::new(value).expect("non-zero version")
+}
+
+#[rstest]
+#[tokio::test]
+async fn registering_again_adds_a_version(ctx: RequestContext, harness: Harness) {
+    let first = harness
+        .catalog
+        .register(&ctx, deploy("first"))
+        .await
+        .expect("register first");
+    let second = harness
+        .catalog
+        .register(&ctx, deploy("second"))
+        .await
+        .expect("register second");
+
+    let history = harness
+        .repository
+        .history(&ctx, "deploy")
+        .await
+        .expect("history");
+    assert_eq!(first.version, version(1));
+    assert_eq!(second.version, version(2));
+    assert_eq!(second.command(), "deploy");
+    assert_eq!(history, [first, second.clone()]);
+    let latest = harness
+        .repository
+        .find_latest(&ctx, "DEPLOY")
+        .await
+        .expect("find latest");
+    assert_eq!(latest, Some(second));
+}
+
+#[rstest]
+#[tokio::test]
+async fn restoring_re_registers_an_earlier_definition(ctx: RequestContext, harness: Harness) {
+    for description in ["first", "second"] {
+        harness
+            .catalog
+            .register(&ctx, deploy(description))
+            .await
+            .expect("register");
+    }
+
+    let restored = harness
+        .catalog
+        .restore(&ctx, "deploy", version(1))
+        .await
+        .expect("restore");
+    let missing = harness.catalog.restore(&ctx, "deploy", version(9)).await;
+
+    assert_eq!(restored.version, version(3));
+    assert_eq!(restored.definition.description, "first");
+    assert!(matches!(
+        missing,
+        Err(SlashCommandCatalogError::UnknownVersion { version: v, .. }) if v == version(9)
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn invalid_definitions_are_not_stored(ctx: RequestContext, harness: Harness) {
+    let invalid = deploy("broken").with_parameter(CommandParameterSpec::new(
+        "env",
+        CommandParameterType::Select,
+        true,
+    ));
+
+    let result = harness.catalog.register(&ctx, invalid).await;
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandCatalogError::InvalidDefinition(
+            SlashCommandSchemaError::InvalidParameterDefinition { .. }
+        ))
+    ));
+    let stored = harness.repository.list_latest(&ctx).await.expect("list");
+    assert!(stored.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn appending_out_of_order_is_a_version_conflict(ctx: RequestContext, harness: Harness) {
+    let first = harness
+        .catalog
+        .register(&ctx, deploy("first"))
+        .await
+        .expect("register");
+
+    let result = harness.repository.append(&ctx, &first).await;
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandDefinitionError::VersionConflict { version: v, .. }) if v == version(1)
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn latest_definitions_seed_a_registry(ctx: RequestContext, harness: Harness) {
+    harness
+        .catalog
+        .register(&ctx, deploy("first"))
+        .await
+        .expect("register");
+    harness
+        .catalog
+        .register(&ctx, deploy("second"))
+        .await
+        .expect("register");
+
+    let definitions = harness.catalog.definitions(&ctx).await.expect("load");
+    let registry = InMemorySlashCommandRegistry::with_commands(definitions).expect("registry");
+    let removed = harness
+        .catalog
+        .remove(&ctx, "deploy")
+        .await
+        .expect("remove");
+
+    let found = registry.find_by_name("deploy").expect("find");
+    assert_eq!(
+        found.map(|definition| definition.description),
+        Some("second".to_owned())
+    );
+    assert_eq!(removed, 2);
+    assert!(
+        harness
+            .catalog
+            .definitions(&ctx)
+            .await
+            .expect("load")
+            .is_empty()
+    );
+}
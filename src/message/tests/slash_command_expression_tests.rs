@@ -0,0 +1,118 @@
+//! Unit tests for `${...}` expressions in tool-call argument templates.
+
+use crate::message::{
+    adapters::memory::InMemorySlashCommandRegistry,
+    domain::{
+        CommandParameterSpec, CommandParameterType, SlashCommandDefinition,
+        SlashCommandSchemaError, ToolCallTemplate,
+    },
+    ports::slash_command::SlashCommandRegistryError,
+    services::SlashCommandService,
+};
+use rstest::rstest;
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+fn push_command(arguments_template: &str) -> SlashCommandDefinition {
+    SlashCommandDefinition::new("push", "Push a branch", "Push.")
+        .with_parameter(CommandParameterSpec::new(
+            "branch",
+            CommandParameterType::String,
+            false,
+        ))
+        .with_parameter(CommandParameterSpec::new(
+            "force",
+            CommandParameterType::Boolean,
+            false,
+        ))
+        .with_parameter(CommandParameterSpec::new(
+            "depth",
+            CommandParameterType::Integer,
+            false,
+        ))
+        .with_tool_call(ToolCallTemplate::new("git", arguments_template))
+}
+
+fn plan(arguments_template: &str, input: &str) -> Value {
+    let registry = InMemorySlashCommandRegistry::with_commands([push_command(arguments_template)])
+        .expect("valid command");
+    let execution = SlashCommandService::new(Arc::new(registry))
+        .execute(input)
+        .expect("executes");
+    execution
+        .planned_tool_calls()
+        .first()
+        .map(|call| call.arguments().clone())
+        .expect("one tool call")
+}
+
+#[rstest]
+#[case::default_applies("/push", json!({"ref": "refs/heads/main", "flags": ""}))]
+#[case::value_wins("/push branch=dev force=true", json!({"ref": "refs/heads/dev", "flags": "--force"}))]
+#[case::false_is_unset("/push force=false", json!({"ref": "refs/heads/main", "flags": ""}))]
+fn expressions_interpolate_defaults_and_conditionals(#[case] input: &str, #[case] expected: Value) {
+    let arguments = plan(
+        r#"{"ref": "refs/heads/${branch:-main}", "flags": "${force:+--force}"}"#,
+        input,
+    );
+
+    assert_eq!(arguments, expected);
+}
+
+#[rstest]
+#[case::set("/push depth=3", json!({"depth": 3, "literal": "${depth}"}))]
+#[case::unset("/push", json!({"depth": null, "literal": "${depth}"}))]
+fn bare_expressions_insert_json_values(#[case] input: &str, #[case] expected: Value) {
+    let arguments = plan(r#"{"depth": ${depth}, "literal": "$${depth}"}"#, input);
+
+    assert_eq!(arguments, expected);
+}
+
+#[rstest]
+#[case::json_structure(
+    r#"/push branch='dev", "admin": true, "x": "'"#,
+    r#"dev", "admin": true, "x": ""#
+)]
+#[case::template_syntax("/push branch={{command}}", "{{command}}")]
+fn parameter_values_cannot_inject_structure(#[case] input: &str, #[case] branch: &str) {
+    let arguments = plan(r#"{"branch": "${branch}", "bare": ${branch}}"#, input);
+
+    assert_eq!(arguments, json!({"branch": branch, "bare": branch}));
+}
+
+#[rstest]
+#[case::unterminated(r#"{"ref": "${branch"#, "unterminated '${' expression")]
+#[case::undeclared(
+    r#"{"ref": "${remote}"}"#,
+    "expression reads undeclared parameter 'remote'"
+)]
+#[case::unquoted_default(
+    r#"{"ref": ${branch:-main}}"#,
+    "defaults and conditionals must be inside a JSON string"
+)]
+#[case::bad_operator(
+    r#"{"ref": "${branch:=main}"}"#,
+    "expected ':-' or ':+' after the parameter name"
+)]
+#[case::quote_in_text(
+    r#"{"ref": "${branch:-ma\"in}"}"#,
+    "expression text cannot contain quotes, backslashes, '$', or '{'"
+)]
+#[case::inside_tag(
+    r#"{"ref": "{{ '${branch}' }}"}"#,
+    "expressions cannot appear inside template tags"
+)]
+fn malformed_expressions_are_rejected_on_registration(
+    #[case] arguments_template: &str,
+    #[case] reason: &str,
+) {
+    let result = InMemorySlashCommandRegistry::with_commands([push_command(arguments_template)]);
+
+    let Err(SlashCommandRegistryError::InvalidDefinition(
+        SlashCommandSchemaError::InvalidToolCallTemplate { source, .. },
+    )) = result
+    else {
+        panic!("expected an invalid tool-call template");
+    };
+    assert_eq!(source.reason, reason);
+}
@@ -0,0 +1,218 @@
+//! Unit tests for declarative slash-command packs.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::InMemorySlashCommandDefinitionRepository,
+    domain::{
+        CommandParameterType, SlashCommandDefinition, SlashCommandPack, SlashCommandPackFormat,
+        SlashCommandSchemaError,
+    },
+    services::{PackConflictPolicy, SlashCommandCatalog, SlashCommandCatalogError},
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+const TOML_PACK: &str = r#"
+[[commands]]
+name = "Status"
+description = "Show status"
+template = "Status."
+
+[[commands]]
+name = "deploy"
+description = "Deploy a service"
+help = "Deploys the current branch."
+template = "Deploy to {{ env }}."
+
+[[commands.parameters]]
+name = "env"
+type = "select"
+required = true
+options = ["staging", "production"]
+
+[[commands.tool_calls]]
+tool = "deployer"
+arguments = '{"env":{{ env | json_string }}}'
+"#;
+
+const YAML_PACK: &str = r#"
+commands:
+  - name: Status
+    description: Show status
+    template: Status.
+  - name: deploy
+    description: Deploy a service
+    help: Deploys the current branch.
+    template: "Deploy to {{ env }}."
+    parameters:
+      - name: env
+        type: select
+        required: true
+        options: [staging, production]
+    tool_calls:
+      - tool: deployer
+        arguments: '{"env":{{ env | json_string }}}'
+"#;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+type Catalog = SlashCommandCatalog<InMemorySlashCommandDefinitionRepository, DefaultClock>;
+
+#[fixture]
+fn catalog() -> Catalog {
+    SlashCommandCatalog::new(
+        Arc::new(InMemorySlashCommandDefinitionRepository::new()),
+        Arc::new(DefaultClock),
+    )
+}
+
+fn toml_pack(source: &str) -> SlashCommandPack {
+    SlashCommandPack::parse(SlashCommandPackFormat::Toml, source).expect("valid pack")
+}
+
+#[rstest]
+#[case::toml(SlashCommandPackFormat::Toml, TOML_PACK)]
+#[case::yaml(SlashCommandPackFormat::Yaml, YAML_PACK)]
+fn packs_parse_into_sorted_definitions(
+    #[case] format: SlashCommandPackFormat,
+    #[case] source: &str,
+) {
+    let pack = SlashCommandPack::parse(format, source).expect("valid pack");
+
+    let names: Vec<_> = pack
+        .definitions()
+        .iter()
+        .map(|definition| definition.command.as_str())
+        .collect();
+    assert_eq!(names, ["deploy", "status"]);
+    let deploy = pack.definitions().first().expect("deploy command");
+    assert_eq!(deploy.help.as_deref(), Some("Deploys the current branch."));
+    let env = deploy.parameters.first().expect("env parameter");
+    assert_eq!(env.parameter_type, CommandParameterType::Select);
+    assert_eq!(env.options, ["staging", "production"]);
+    assert_eq!(
+        deploy
+            .tool_calls
+            .first()
+            .map(|call| call.tool_name.as_str()),
+        Some("deployer")
+    );
+}
+
+#[rstest]
+#[case::yml("YML", Some(SlashCommandPackFormat::Yaml))]
+#[case::toml("toml", Some(SlashCommandPackFormat::Toml))]
+#[case::unknown("json", None)]
+fn formats_follow_file_extensions(
+    #[case] extension: &str,
+    #[case] expected: Option<SlashCommandPackFormat>,
+) {
+    assert_eq!(SlashCommandPackFormat::from_extension(extension), expected);
+}
+
+#[rstest]
+#[case::unknown_key(
+    "[[commands]]\nname = \"a\"\ndescription = \"A\"\ntemplate = \"A\"\nnickname = \"b\"\n"
+)]
+#[case::missing_template("[[commands]]\nname = \"a\"\ndescription = \"A\"\n")]
+fn malformed_packs_are_rejected(#[case] source: &str) {
+    let result = SlashCommandPack::parse(SlashCommandPackFormat::Toml, source);
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandSchemaError::InvalidPack { .. })
+    ));
+}
+
+#[rstest]
+#[case::duplicate(concat!(
+    "[[commands]]\nname = \"a\"\ndescription = \"A\"\ntemplate = \"A\"\n",
+    "[[commands]]\nname = \"A\"\ndescription = \"A\"\ntemplate = \"A\"\n",
+))]
+#[case::bad_name("[[commands]]\nname = \"a b\"\ndescription = \"A\"\ntemplate = \"A\"\n")]
+fn invalid_commands_are_rejected(#[case] source: &str) {
+    let result = SlashCommandPack::parse(SlashCommandPackFormat::Toml, source);
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandSchemaError::InvalidCommandDefinition { .. })
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn reinstalling_a_pack_registers_nothing(ctx: RequestContext, catalog: Catalog) {
+    let first = catalog
+        .install_pack(&ctx, toml_pack(TOML_PACK), PackConflictPolicy::Reject)
+        .await
+        .expect("install");
+    let second = catalog
+        .install_pack(&ctx, toml_pack(TOML_PACK), PackConflictPolicy::Reject)
+        .await
+        .expect("reinstall");
+
+    assert_eq!(first.registered.len(), 2);
+    assert!(second.registered.is_empty());
+    assert_eq!(second.unchanged, ["deploy", "status"]);
+}
+
+#[rstest]
+#[case::keep((PackConflictPolicy::KeepExisting, "Existing", 0))]
+#[case::replace((PackConflictPolicy::Replace, "Show status", 1))]
+#[tokio::test]
+async fn conflicts_follow_the_policy(
+    ctx: RequestContext,
+    catalog: Catalog,
+    #[case] (policy, description, replaced): (PackConflictPolicy, &str, usize),
+) {
+    catalog
+        .register(
+            &ctx,
+            SlashCommandDefinition::new("status", "Existing", "Status."),
+        )
+        .await
+        .expect("register");
+
+    let report = catalog
+        .install_pack(&ctx, toml_pack(TOML_PACK), policy)
+        .await
+        .expect("install");
+
+    let kept = usize::from(replaced == 0);
+    assert_eq!(report.registered.len(), 1 + replaced);
+    assert_eq!(report.kept.len(), kept);
+    let definitions = catalog.definitions(&ctx).await.expect("load");
+    let status = definitions
+        .iter()
+        .find(|definition| definition.command == "status")
+        .expect("status command");
+    assert_eq!(status.description, description);
+}
+
+#[rstest]
+#[tokio::test]
+async fn rejected_conflicts_install_nothing(ctx: RequestContext, catalog: Catalog) {
+    catalog
+        .register(
+            &ctx,
+            SlashCommandDefinition::new("status", "Existing", "Status."),
+        )
+        .await
+        .expect("register");
+
+    let result = catalog
+        .install_pack(&ctx, toml_pack(TOML_PACK), PackConflictPolicy::Reject)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandCatalogError::PackConflict { ref commands }) if commands == &["status"]
+    ));
+    let definitions = catalog.definitions(&ctx).await.expect("load");
+    assert_eq!(definitions.len(), 1);
+}
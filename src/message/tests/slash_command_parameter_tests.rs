@@ -0,0 +1,142 @@
+//! Unit tests for slash-command parameter coercion and constraints.
+
+use crate::message::domain::{
+    CommandParameterSpec, CommandParameterType, SlashCommandDefinition, SlashCommandError,
+    SlashCommandInvocation, SlashCommandPack, SlashCommandPackFormat, SlashCommandSchemaError,
+    TokenSpan,
+};
+use rstest::rstest;
+use serde_json::{Value, json};
+
+fn scale_command() -> SlashCommandDefinition {
+    SlashCommandDefinition::new("scale", "Scale a service", "Scale to {{ replicas }}.")
+        .with_parameter(
+            CommandParameterSpec::new("replicas", CommandParameterType::Integer, false)
+                .with_default("2")
+                .with_schema(json!({"minimum": 1, "maximum": 10})),
+        )
+        .with_parameter(
+            CommandParameterSpec::new("service", CommandParameterType::String, true)
+                .with_schema(json!({"pattern": "^[a-z-]+$", "maxLength": 12})),
+        )
+}
+
+fn execute(input: &str) -> Result<Value, SlashCommandError> {
+    let invocation = SlashCommandInvocation::parse(input)?;
+    let parameters = scale_command().validate_invocation(&invocation)?;
+    Ok(Value::Object(parameters.into_iter().collect()))
+}
+
+#[rstest]
+fn omitted_parameters_take_their_coerced_default() {
+    let parameters = execute("/scale service=api").expect("execute");
+
+    assert_eq!(parameters, json!({"replicas": 2, "service": "api"}));
+}
+
+#[rstest]
+#[case::fraction("4.5", "expected a whole number")]
+#[case::word("many", "expected a whole number")]
+fn integers_reject_other_values(#[case] raw: &str, #[case] reason: &str) {
+    let error = execute(&format!("/scale service=api replicas={raw}")).expect_err("rejected");
+
+    assert_eq!(
+        error,
+        SlashCommandError::InvalidParameterValue {
+            command: "scale".to_owned(),
+            parameter: "replicas".to_owned(),
+            reason: reason.to_owned(),
+            span: Some(TokenSpan::new(19, 28 + raw.len())),
+        }
+    );
+}
+
+#[rstest]
+#[case::too_many("/scale service=api replicas=20", "replicas", TokenSpan::new(19, 30))]
+#[case::pattern("/scale  service=API", "service", TokenSpan::new(8, 19))]
+#[case::too_long("/scale service=checkout-service", "service", TokenSpan::new(7, 31))]
+fn constraint_violations_point_at_the_token(
+    #[case] input: &str,
+    #[case] parameter: &str,
+    #[case] span: TokenSpan,
+) {
+    let error = execute(input).expect_err("constraint violated");
+
+    let SlashCommandError::ParameterConstraintViolation {
+        parameter: violated,
+        span: found,
+        ..
+    } = &error
+    else {
+        panic!("expected a constraint violation, got {error:?}");
+    };
+    assert_eq!(violated, parameter);
+    assert_eq!(*found, Some(span));
+    assert_eq!(
+        input
+            .get(span.start..span.end)
+            .map(|token| token.starts_with(parameter)),
+        Some(true)
+    );
+}
+
+#[rstest]
+fn unknown_parameters_point_at_the_token() {
+    let error = execute("/scale service=api region=eu").expect_err("unknown parameter");
+
+    assert!(matches!(
+        error,
+        SlashCommandError::UnknownParameter { parameter, span, .. }
+            if parameter == "region" && span == Some(TokenSpan::new(19, 28))
+    ));
+}
+
+#[rstest]
+#[case::required_default(
+    CommandParameterSpec::new("count", CommandParameterType::Integer, true).with_default("1")
+)]
+#[case::bad_default(
+    CommandParameterSpec::new("count", CommandParameterType::Integer, false).with_default("one")
+)]
+#[case::default_breaks_constraints(
+    CommandParameterSpec::new("count", CommandParameterType::Integer, false)
+        .with_default("0")
+        .with_schema(json!({"minimum": 1}))
+)]
+#[case::bad_pattern(
+    CommandParameterSpec::new("name", CommandParameterType::String, false)
+        .with_schema(json!({"pattern": "("}))
+)]
+#[case::non_object_schema(
+    CommandParameterSpec::new("name", CommandParameterType::String, false).with_schema(json!(3))
+)]
+fn invalid_parameter_definitions_are_rejected(#[case] parameter: CommandParameterSpec) {
+    let definition =
+        SlashCommandDefinition::new("count", "Count", "Count.").with_parameter(parameter);
+
+    let result = definition.validate_schema();
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandSchemaError::InvalidParameterDefinition { .. })
+    ));
+}
+
+#[rstest]
+fn packs_declare_defaults_and_constraints() {
+    let source = concat!(
+        "[[commands]]\nname = \"scale\"\ndescription = \"Scale\"\ntemplate = \"Scale.\"\n",
+        "[[commands.parameters]]\nname = \"replicas\"\ntype = \"integer\"\ndefault = 2\n",
+        "schema = { minimum = 1, maximum = 10 }\n",
+    );
+
+    let pack = SlashCommandPack::parse(SlashCommandPackFormat::Toml, source).expect("valid pack");
+
+    let replicas = pack
+        .definitions()
+        .first()
+        .and_then(|definition| definition.parameters.first())
+        .expect("replicas parameter");
+    assert_eq!(replicas.default.as_deref(), Some("2"));
+    assert_eq!(replicas.schema, Some(json!({"minimum": 1, "maximum": 10})));
+}
@@ -0,0 +1,185 @@
+//! Unit tests for slash-command namespaces, aliases, and generated help.
+
+use crate::message::{
+    adapters::memory::InMemorySlashCommandRegistry,
+    domain::{
+        CommandParameterSpec, CommandParameterType, SlashCommandDefinition, SlashCommandError,
+        SlashCommandInvocation, SlashCommandPack, SlashCommandPackFormat, SlashCommandSchemaError,
+        TokenSpan, resolve_command,
+    },
+    ports::slash_command::SlashCommandRegistryError,
+    services::SlashCommandService,
+};
+use rstest::{fixture, rstest};
+use std::sync::Arc;
+
+fn definitions() -> Vec<SlashCommandDefinition> {
+    vec![
+        SlashCommandDefinition::new("issue", "Local issues", "Issue.").with_alias("i"),
+        SlashCommandDefinition::new("issue", "GitHub issues", "Issue {{ title }}.")
+            .with_namespace("gh")
+            .with_alias("iss")
+            .with_help("Opens an issue in the current repository.")
+            .with_parameter(CommandParameterSpec::new(
+                "title",
+                CommandParameterType::String,
+                true,
+            )),
+        SlashCommandDefinition::new("issue", "Jira issues", "Issue.")
+            .with_namespace("jira")
+            .with_alias("iss"),
+        SlashCommandDefinition::new("pr", "GitHub pull requests", "PR.").with_namespace("gh"),
+        SlashCommandDefinition::new("deploy", "Deploy", "Deploy.").with_alias("pr"),
+    ]
+}
+
+#[fixture]
+fn service() -> SlashCommandService<InMemorySlashCommandRegistry> {
+    let registry = InMemorySlashCommandRegistry::with_commands(definitions()).expect("registry");
+    SlashCommandService::new(Arc::new(registry))
+}
+
+#[rstest]
+#[case::global_name("issue", "issue")]
+#[case::global_alias("i", "issue")]
+#[case::qualified_name("GH:issue", "gh:issue")]
+#[case::qualified_alias("/gh:iss", "gh:issue")]
+#[case::global_alias_before_namespaced_name("pr", "deploy")]
+#[case::namespaced_name("gh:pr", "gh:pr")]
+fn names_resolve_by_precedence(#[case] name: &str, #[case] expected: &str) {
+    let definitions = definitions();
+
+    let resolved = resolve_command(&definitions, name).expect("resolves");
+
+    assert_eq!(resolved.qualified_name(), expected);
+}
+
+#[rstest]
+fn ambiguous_names_list_their_candidates() {
+    let result = resolve_command(&definitions(), "iss");
+
+    assert_eq!(
+        result,
+        Err(SlashCommandError::AmbiguousCommand {
+            command: "iss".to_owned(),
+            candidates: vec!["gh:issue".to_owned(), "jira:issue".to_owned()],
+        })
+    );
+}
+
+#[rstest]
+#[case::missing("/gh:")]
+#[case::empty_namespace("/:issue")]
+#[case::nested("/gh:issue:new")]
+fn malformed_qualified_names_are_rejected(#[case] input: &str) {
+    let result = SlashCommandInvocation::parse(input);
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandError::InvalidCommandName(_))
+    ));
+}
+
+#[rstest]
+#[case::alias_taken(SlashCommandDefinition::new("bug", "Bug", "Bug.").with_namespace("gh").with_alias("pr"))]
+#[case::reserved_help(SlashCommandDefinition::new("assist", "Assist", "Assist.").with_alias("help"))]
+fn conflicting_names_are_rejected(#[case] extra: SlashCommandDefinition) {
+    let mut all = definitions();
+    all.push(extra);
+
+    let result = InMemorySlashCommandRegistry::with_commands(all);
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandRegistryError::InvalidDefinition(
+            SlashCommandSchemaError::InvalidCommandDefinition { .. }
+        ))
+    ));
+}
+
+#[rstest]
+fn aliases_expand_under_the_qualified_name(
+    service: SlashCommandService<InMemorySlashCommandRegistry>,
+) {
+    let execution = service
+        .execute("/gh:iss title=Crash")
+        .expect("alias executes");
+
+    assert_eq!(execution.expansion().command, "/gh:issue");
+    assert_eq!(execution.expansion().expanded_content, "Issue Crash.");
+}
+
+#[rstest]
+fn positional_arguments_are_rejected_by_commands(
+    service: SlashCommandService<InMemorySlashCommandRegistry>,
+) {
+    let error = service
+        .execute("/gh:issue create title=Crash")
+        .expect_err("positional argument");
+
+    assert_eq!(
+        error,
+        SlashCommandError::UnexpectedArgument {
+            command: "gh:issue".to_owned(),
+            argument: "create".to_owned(),
+            span: Some(TokenSpan::new(10, 16)),
+        }
+    );
+}
+
+#[rstest]
+fn help_lists_every_command(service: SlashCommandService<InMemorySlashCommandRegistry>) {
+    let execution = service.execute("/help").expect("help");
+
+    let content = &execution.expansion().expanded_content;
+    assert!(content.starts_with("Available commands:\n  /deploy - Deploy (aliases: /pr)\n"));
+    assert!(content.contains("  /gh:issue - GitHub issues (aliases: /gh:iss)\n"));
+    assert!(execution.planned_tool_calls().is_empty());
+}
+
+#[rstest]
+fn help_renders_command_usage(service: SlashCommandService<InMemorySlashCommandRegistry>) {
+    let execution = service.execute("/help gh:iss").expect("help");
+
+    assert_eq!(
+        execution.expansion().expanded_content,
+        concat!(
+            "/gh:issue - GitHub issues\n",
+            "Usage: /gh:issue title=<string>\n",
+            "Aliases: /gh:iss\n",
+            "\n",
+            "Opens an issue in the current repository.",
+        )
+    );
+}
+
+#[rstest]
+#[case::unknown("/help missing", SlashCommandError::UnknownCommand("missing".to_owned()))]
+#[case::extra("/help deploy pr", SlashCommandError::UnexpectedArgument {
+    command: "help".to_owned(),
+    argument: "pr".to_owned(),
+    span: Some(TokenSpan::new(13, 15)),
+})]
+fn help_rejects_bad_topics(
+    service: SlashCommandService<InMemorySlashCommandRegistry>,
+    #[case] input: &str,
+    #[case] expected: SlashCommandError,
+) {
+    let error = service.execute(input).expect_err("help fails");
+
+    assert_eq!(error, expected);
+}
+
+#[rstest]
+fn packs_declare_namespaces_and_aliases() {
+    let source = concat!(
+        "[[commands]]\nname = \"Issue\"\nnamespace = \"GH\"\naliases = [\"Iss\"]\n",
+        "description = \"Issues\"\ntemplate = \"Issue.\"\n",
+    );
+
+    let pack = SlashCommandPack::parse(SlashCommandPackFormat::Toml, source).expect("valid pack");
+
+    let issue = pack.definitions().first().expect("issue command");
+    assert_eq!(issue.qualified_name(), "gh:issue");
+    assert_eq!(issue.aliases, ["iss"]);
+}
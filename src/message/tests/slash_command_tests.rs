@@ -9,7 +9,7 @@ use crate::message::{
     adapters::memory::InMemorySlashCommandRegistry,
     domain::{
         CommandParameterSpec, CommandParameterType, SlashCommandDefinition, SlashCommandError,
-        SlashCommandInvocation, ToolCallTemplate,
+        SlashCommandInvocation, TokenSpan, ToolCallTemplate,
     },
     ports::slash_command::SlashCommandRegistry,
     services::SlashCommandService,
@@ -126,6 +126,7 @@ fn parser_reports_unquoted_backslash_token_with_hint() {
         error,
         SlashCommandError::InvalidParameterToken {
             token: r"issue=C:\".to_owned(),
+            span: TokenSpan::new(19, 28),
         }
     );
     assert!(
@@ -182,6 +183,7 @@ fn service_rejects_invalid_boolean_parameter(
             command: "review".to_owned(),
             parameter: "include_summary".to_owned(),
             reason: "expected true or false (case-insensitive)".to_owned(),
+            span: Some(TokenSpan::new(20, 41)),
         }
     );
 }
@@ -256,6 +258,7 @@ fn service_rejects_non_finite_number_parameter_values(#[case] value: &str) {
             command: "set-count".to_owned(),
             parameter: "count".to_owned(),
             reason: "expected a number".to_owned(),
+            span: Some(TokenSpan::new(11, 17 + value.len())),
         }
     );
 }
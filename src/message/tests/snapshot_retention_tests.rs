@@ -0,0 +1,177 @@
+//! Unit tests for snapshot retention rules and pruning.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::InMemoryContextSnapshotAdapter,
+    domain::{
+        AgentSessionId, ContextWindowSnapshot, ConversationId, MessageSummary, SequenceNumber,
+        SequenceRange, SnapshotParams, SnapshotRetention, SnapshotType,
+    },
+    ports::{ContextSnapshotPort, SnapshotError},
+    services::SnapshotPruningService,
+};
+use crate::test_support::test_request_ctx;
+use chrono::{Duration, Utc};
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use std::num::{NonZeroU32, NonZeroUsize};
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+/// A snapshot of `session_id` captured `days_ago` days ago.
+fn captured(
+    session_id: AgentSessionId,
+    snapshot_type: SnapshotType,
+    days_ago: i64,
+) -> ContextWindowSnapshot {
+    let params = SnapshotParams {
+        conversation_id: ConversationId::new(),
+        session_id,
+        sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(5)),
+        message_summary: MessageSummary::default(),
+        snapshot_type,
+    };
+    ContextWindowSnapshot {
+        captured_at: Utc::now() - Duration::days(days_ago),
+        ..ContextWindowSnapshot::new(params, &DefaultClock)
+    }
+}
+
+fn keep_latest(count: usize) -> SnapshotRetention {
+    SnapshotRetention::new()
+        .keep_latest_per_session(NonZeroUsize::new(count).expect("non-zero count"))
+}
+
+async fn store_all(
+    snapshots: &InMemoryContextSnapshotAdapter,
+    ctx: &RequestContext,
+    all: &[&ContextWindowSnapshot],
+) {
+    for snapshot in all {
+        snapshots
+            .store_snapshot(ctx, snapshot)
+            .await
+            .expect("store snapshot");
+    }
+}
+
+#[rstest]
+fn default_rules_prune_nothing() {
+    let session_id = AgentSessionId::new();
+    let snapshots: Vec<_> = (0..5)
+        .map(|days| captured(session_id, SnapshotType::Checkpoint, days))
+        .collect();
+
+    assert!(SnapshotRetention::default().prunable(&snapshots).is_empty());
+}
+
+#[rstest]
+fn the_per_session_limit_prunes_the_oldest_snapshots() {
+    let session_id = AgentSessionId::new();
+    let oldest = captured(session_id, SnapshotType::Checkpoint, 4);
+    let older = captured(session_id, SnapshotType::Checkpoint, 3);
+    let kept = [
+        captured(session_id, SnapshotType::Checkpoint, 2),
+        captured(session_id, SnapshotType::Checkpoint, 1),
+    ];
+    let all = [
+        kept[1].clone(),
+        oldest.clone(),
+        kept[0].clone(),
+        older.clone(),
+    ];
+
+    let prunable = keep_latest(2).prunable(&all);
+
+    assert_eq!(prunable, [oldest.snapshot_id, older.snapshot_id]);
+}
+
+#[rstest]
+#[case::handoffs_kept(keep_latest(1), 1)]
+#[case::handoffs_pruned(keep_latest(1).prune_handoffs(), 2)]
+fn handoff_snapshots_are_kept_unless_configured(
+    #[case] rules: SnapshotRetention,
+    #[case] expected: usize,
+) {
+    let session_id = AgentSessionId::new();
+    let all = [
+        captured(session_id, SnapshotType::HandoffInitiated, 5),
+        captured(session_id, SnapshotType::HandoffInitiated, 4),
+        captured(session_id, SnapshotType::Checkpoint, 3),
+        captured(session_id, SnapshotType::Checkpoint, 1),
+    ];
+
+    let prunable = rules.prunable(&all);
+
+    assert_eq!(prunable.len(), expected);
+}
+
+#[rstest]
+fn kept_increments_keep_their_parents_and_latest_types() {
+    let session_id = AgentSessionId::new();
+    let compaction = captured(session_id, SnapshotType::Compaction, 9);
+    let root = captured(session_id, SnapshotType::Checkpoint, 8);
+    let stale = captured(session_id, SnapshotType::Checkpoint, 7);
+    let fresh = captured(session_id, SnapshotType::Checkpoint, 2);
+    let increment = captured(session_id, SnapshotType::Periodic, 1).with_parent(root.snapshot_id);
+    let all = [compaction, root, stale.clone(), fresh, increment];
+
+    let prunable = keep_latest(1).prunable(&all);
+
+    assert_eq!(prunable, [stale.snapshot_id]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn pruning_a_session_deletes_its_prunable_snapshots(ctx: RequestContext) {
+    let adapter = Arc::new(InMemoryContextSnapshotAdapter::new());
+    let service =
+        SnapshotPruningService::new(Arc::clone(&adapter), keep_latest(1), Arc::new(DefaultClock));
+    let session_id = AgentSessionId::new();
+    let old = captured(session_id, SnapshotType::Checkpoint, 3);
+    let old_increment =
+        captured(session_id, SnapshotType::Checkpoint, 2).with_parent(old.snapshot_id);
+    let latest = captured(session_id, SnapshotType::Checkpoint, 1);
+    store_all(&adapter, &ctx, &[&old, &old_increment, &latest]).await;
+
+    let deleted = service
+        .prune_session(&ctx, session_id)
+        .await
+        .expect("prune session");
+
+    assert_eq!(deleted, [old.snapshot_id, old_increment.snapshot_id]);
+    assert_eq!(adapter.len(), 1);
+    let missing = adapter.delete_snapshot(&ctx, old.snapshot_id).await;
+    assert!(matches!(missing, Err(SnapshotError::NotFound(id)) if id == old.snapshot_id));
+}
+
+#[rstest]
+#[tokio::test]
+async fn periodic_snapshots_expire_unless_extended(ctx: RequestContext) {
+    let adapter = Arc::new(InMemoryContextSnapshotAdapter::new());
+    let rules = SnapshotRetention::new()
+        .expire_periodic_after_days(NonZeroU32::new(30).expect("non-zero days"));
+    let service = SnapshotPruningService::new(Arc::clone(&adapter), rules, Arc::new(DefaultClock));
+    let session_id = AgentSessionId::new();
+    let expired = captured(session_id, SnapshotType::Periodic, 40);
+    let extended = captured(session_id, SnapshotType::Periodic, 35);
+    let handoff =
+        captured(session_id, SnapshotType::HandoffInitiated, 31).with_parent(extended.snapshot_id);
+    let recent = captured(session_id, SnapshotType::Periodic, 2);
+    store_all(&adapter, &ctx, &[&expired, &extended, &handoff, &recent]).await;
+
+    let deleted = service.expire_periodic(&ctx).await.expect("expire");
+
+    assert_eq!(deleted, 1);
+    for kept in [&extended, &handoff, &recent] {
+        let found = adapter
+            .find_by_id(&ctx, kept.snapshot_id)
+            .await
+            .expect("find snapshot");
+        assert!(found.is_some());
+    }
+}
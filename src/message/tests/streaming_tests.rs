@@ -0,0 +1,232 @@
+//! Unit tests for streamed assistant message ingestion.
+
+use super::adapters_test_support::ctx;
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemoryMessageRepository, InMemoryPartialMessageRepository},
+    domain::{
+        ChunkAppend, ConversationId, MessageMetadata, PartialMessage, Role,
+        STREAM_INTERRUPTED_EXTENSION_KEY, SequenceNumber, StreamChunk, StreamingDomainError,
+    },
+    ports::{
+        MessageRepository,
+        streaming::{PartialMessageRepository, StreamingError},
+    },
+    services::{StreamingMessageBuilder, StreamingServiceError},
+};
+use chrono::{DateTime, Local, TimeDelta, Utc};
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Clock that only moves when told to.
+struct ManualClock(Mutex<DateTime<Utc>>);
+
+impl ManualClock {
+    fn advance(&self, by: TimeDelta) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn local(&self) -> DateTime<Local> {
+        self.utc().with_timezone(&Local)
+    }
+
+    fn utc(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+struct Harness {
+    builder: StreamingMessageBuilder<InMemoryPartialMessageRepository, ManualClock>,
+    streams: Arc<InMemoryPartialMessageRepository>,
+    messages: InMemoryMessageRepository,
+    clock: Arc<ManualClock>,
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let messages = InMemoryMessageRepository::new();
+    let streams = Arc::new(InMemoryPartialMessageRepository::new(messages.clone()));
+    let clock = Arc::new(ManualClock(Mutex::new(DefaultClock.utc())));
+    Harness {
+        builder: StreamingMessageBuilder::new(Arc::clone(&streams), Arc::clone(&clock)),
+        streams,
+        messages,
+        clock,
+    }
+}
+
+fn partial() -> PartialMessage {
+    PartialMessage::start(
+        ConversationId::new(),
+        MessageMetadata::with_agent_backend("claude_code_sdk"),
+        &DefaultClock,
+    )
+}
+
+async fn stream(
+    harness: &Harness,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+    chunks: &[&str],
+) -> PartialMessage {
+    let mut partial = harness
+        .builder
+        .start(ctx, conversation_id, MessageMetadata::empty())
+        .await
+        .expect("start stream");
+    for (index, text) in (0_u32..).zip(chunks) {
+        partial = harness
+            .builder
+            .push(ctx, partial.message_id, &StreamChunk::new(index, *text))
+            .await
+            .expect("push chunk");
+    }
+    partial
+}
+
+#[rstest]
+fn chunks_must_arrive_in_order() {
+    let mut partial = partial();
+
+    let result = partial.append(&StreamChunk::new(1, "now."), &DefaultClock);
+
+    assert_eq!(
+        result,
+        Err(StreamingDomainError::ChunkOutOfOrder {
+            message_id: partial.message_id,
+            expected: 0,
+            received: 1,
+        })
+    );
+    assert!(partial.content.is_empty());
+}
+
+#[rstest]
+fn resent_chunks_are_ignored() {
+    let mut partial = partial();
+    partial
+        .append(&StreamChunk::new(0, "Deploying "), &DefaultClock)
+        .expect("first chunk");
+
+    let resent = partial
+        .append(&StreamChunk::new(0, "Deploying "), &DefaultClock)
+        .expect("resent chunk");
+
+    assert_eq!(resent, ChunkAppend::Duplicate);
+    assert_eq!(partial.content, "Deploying ");
+    assert_eq!(partial.chunk_count, 1);
+}
+
+#[rstest]
+fn streams_without_text_cannot_be_finalised() {
+    let partial = partial();
+
+    assert_eq!(
+        partial.finalise(&DefaultClock),
+        Err(StreamingDomainError::EmptyStream(partial.message_id))
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn finishing_a_stream_appends_the_assistant_message(harness: Harness) {
+    let ctx = ctx();
+    let conversation_id = ConversationId::new();
+    let partial = stream(&harness, &ctx, conversation_id, &["Deploying ", "now."]).await;
+
+    let message = harness
+        .builder
+        .finish(&ctx, partial.message_id)
+        .await
+        .expect("finish stream");
+
+    assert_eq!(message.id(), partial.message_id);
+    assert_eq!(message.role(), Role::Assistant);
+    assert_eq!(message.sequence_number(), SequenceNumber::new(1));
+    assert_eq!(message.content().len(), 1);
+    let stored = harness
+        .messages
+        .find_by_id(&ctx, message.id())
+        .await
+        .expect("find message");
+    assert_eq!(stored, Some(message));
+    let staged = harness
+        .streams
+        .find(&ctx, partial.message_id)
+        .await
+        .expect("find stream");
+    assert_eq!(staged, None);
+}
+
+#[rstest]
+#[tokio::test]
+async fn stale_writers_cannot_overwrite_newer_chunks(harness: Harness) {
+    let ctx = ctx();
+    let partial = stream(&harness, &ctx, ConversationId::new(), &["Deploying "]).await;
+    let mut stale = partial.clone();
+    stale.chunk_count = 0;
+    stale.content.clear();
+    stale
+        .append(&StreamChunk::new(0, "Rolling back"), &DefaultClock)
+        .expect("stale chunk");
+
+    let result = harness.streams.update(&ctx, &stale, 0).await;
+
+    assert!(matches!(result, Err(StreamingError::Conflict(id)) if id == partial.message_id));
+}
+
+#[rstest]
+#[tokio::test]
+async fn pushing_to_an_unknown_stream_fails(harness: Harness) {
+    let ctx = ctx();
+    let missing = partial().message_id;
+
+    let result = harness
+        .builder
+        .push(&ctx, missing, &StreamChunk::new(0, "hello"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(StreamingServiceError::Repository(StreamingError::NotFound(id))) if id == missing
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn recovery_settles_only_stalled_streams(harness: Harness) {
+    let ctx = ctx();
+    let conversation_id = ConversationId::new();
+    let interrupted = stream(&harness, &ctx, conversation_id, &["Deploying "]).await;
+    let empty = stream(&harness, &ctx, conversation_id, &[]).await;
+    harness.clock.advance(TimeDelta::minutes(10));
+    let live = stream(&harness, &ctx, conversation_id, &["Still "]).await;
+
+    let report = harness
+        .builder
+        .recover_stalled(&ctx, TimeDelta::minutes(5))
+        .await
+        .expect("recover streams");
+
+    assert_eq!(report.discarded, vec![empty.message_id]);
+    let [recovered] = report.finalised.as_slice() else {
+        panic!("expected one recovered message, got {:?}", report.finalised);
+    };
+    assert_eq!(recovered.id(), interrupted.message_id);
+    assert_eq!(
+        recovered
+            .metadata()
+            .extensions
+            .get(STREAM_INTERRUPTED_EXTENSION_KEY),
+        Some(&serde_json::Value::Bool(true))
+    );
+    let remaining = harness
+        .streams
+        .find(&ctx, live.message_id)
+        .await
+        .expect("find live stream");
+    assert_eq!(remaining, Some(live));
+}
@@ -0,0 +1,191 @@
+//! Unit tests for token counting and per-conversation token usage.
+
+use crate::message::{
+    adapters::{
+        memory::InMemoryMessageRepository,
+        token_counting::{BpeTokenCounter, HeuristicTokenCounter, TokenRanksError},
+    },
+    domain::{
+        ContentPart, ConversationId, ConversationTokenUsage, ImagePart, Message, MessageMetadata,
+        MessageTokenCount, Role, SequenceNumber, TextPart, ToolCallPart, ToolResultPart,
+    },
+    ports::{MessageRepository, TokenCounter},
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Counter that measures text in characters, so tests can see exactly
+/// which text a message contributes.
+struct CharCounter;
+
+impl TokenCounter for CharCounter {
+    fn encoding(&self) -> &str {
+        "chars"
+    }
+
+    fn count(&self, text: &str) -> u64 {
+        u64::try_from(text.chars().count()).unwrap_or(u64::MAX)
+    }
+}
+
+fn message_with(
+    conversation_id: ConversationId,
+    sequence: u64,
+    content: Vec<ContentPart>,
+) -> Result<Message, eyre::Report> {
+    Ok(Message::new(
+        conversation_id,
+        Role::Assistant,
+        content,
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )?)
+}
+
+/// Ranks "a", "b", "c", "ab", and "abc", in that order.
+fn tiny_ranks() -> HashMap<Vec<u8>, u32> {
+    [&b"a"[..], b"b", b"c", b"ab", b"abc"]
+        .into_iter()
+        .zip(0..)
+        .map(|(token, rank)| (token.to_vec(), rank))
+        .collect()
+}
+
+// ============================================================================
+// Counter adapters
+// ============================================================================
+
+#[rstest]
+#[case::whole_piece("abc", 1)]
+#[case::merged_prefix("abca", 2)]
+#[case::unmerged("cba", 3)]
+#[case::two_pieces("abc abc", 3)]
+#[case::empty("", 0)]
+fn bpe_counter_merges_by_rank(#[case] text: &str, #[case] expected: u64) {
+    let counter = BpeTokenCounter::new("tiny", tiny_ranks());
+
+    assert_eq!(counter.count(text), expected);
+}
+
+#[rstest]
+fn bpe_counter_loads_tiktoken_ranks() -> Result<(), eyre::Report> {
+    let counter = BpeTokenCounter::from_tiktoken("tiny", "YQ== 0\n\nYg== 1\nYWI= 2\n")?;
+
+    eyre::ensure!(counter.encoding() == "tiny");
+    eyre::ensure!(counter.count("ab") == 1);
+    eyre::ensure!(counter.count("aab") == 2);
+    Ok(())
+}
+
+#[rstest]
+#[case::missing_rank("YQ==\n", TokenRanksError::MalformedLine { line: 1 })]
+#[case::bad_base64("YQ== 0\n!!! 1\n", TokenRanksError::MalformedLine { line: 2 })]
+#[case::duplicate("YQ== 0\nYQ== 1\n", TokenRanksError::DuplicateToken { line: 2 })]
+#[case::empty("\n", TokenRanksError::Empty)]
+fn bpe_counter_rejects_bad_ranks(#[case] ranks: &str, #[case] expected: TokenRanksError) {
+    assert_eq!(
+        BpeTokenCounter::from_tiktoken("tiny", ranks).err(),
+        Some(expected)
+    );
+}
+
+#[rstest]
+#[case::short_words("Hello, world!", 6)]
+#[case::long_word("internationalisation", 5)]
+#[case::digits_split_in_threes("1234567", 3)]
+#[case::whitespace_only("   ", 1)]
+fn heuristic_counter_estimates_per_piece(#[case] text: &str, #[case] expected: u64) {
+    assert_eq!(HeuristicTokenCounter::new().count(text), expected);
+}
+
+// ============================================================================
+// Message counting
+// ============================================================================
+
+#[rstest]
+fn count_message_covers_model_visible_text() -> Result<(), eyre::Report> {
+    let message = message_with(
+        ConversationId::new(),
+        1,
+        vec![
+            ContentPart::Text(TextPart::new("hi")),
+            ContentPart::ToolCall(ToolCallPart::new("call-1", "ls", json!({"p": 1}))),
+            ContentPart::ToolResult(ToolResultPart::success("call-1", json!("ok"))),
+            ContentPart::Image(ImagePart::new("image/png", "AAAA", 1, 1).with_alt_text("cat")),
+        ],
+    )?;
+
+    let count = CharCounter.count_message(&message);
+
+    // "hi" + "ls" + `{"p":1}` + "ok" + "cat"; image data is not counted.
+    eyre::ensure!(count == MessageTokenCount::new(16, "chars"), "{count:?}");
+    Ok(())
+}
+
+#[rstest]
+fn token_count_round_trips_through_metadata() -> Result<(), eyre::Report> {
+    let metadata =
+        MessageMetadata::empty().with_token_count(MessageTokenCount::new(12, "cl100k_base"));
+
+    let value = serde_json::to_value(&metadata)?;
+    let restored: MessageMetadata = serde_json::from_value(value.clone())?;
+
+    eyre::ensure!(value == json!({"token_count": {"tokens": 12, "encoding": "cl100k_base"}}));
+    eyre::ensure!(restored == metadata);
+    eyre::ensure!(restored.has_key("token_count"));
+    eyre::ensure!(!MessageMetadata::empty().has_key("token_count"));
+    Ok(())
+}
+
+// ============================================================================
+// Conversation usage
+// ============================================================================
+
+#[rstest]
+#[tokio::test]
+async fn repository_sums_token_counts_per_conversation() -> Result<(), eyre::Report> {
+    let ctx = test_request_ctx();
+    let repository = InMemoryMessageRepository::new();
+    let conversation_id = ConversationId::new();
+    let text = |value: &str| vec![ContentPart::Text(TextPart::new(value))];
+    for (sequence, tokens) in [(1, 5), (2, 7)] {
+        let message = message_with(conversation_id, sequence, text("hello"))?
+            .with_token_count(MessageTokenCount::new(tokens, "chars"));
+        repository.store(&ctx, &message).await?;
+    }
+    let uncounted = message_with(conversation_id, 3, text("stored before counting"))?;
+    repository.store(&ctx, &uncounted).await?;
+    let other = message_with(ConversationId::new(), 1, text("elsewhere"))?
+        .with_token_count(MessageTokenCount::new(100, "chars"));
+    repository.store(&ctx, &other).await?;
+
+    let usage = repository.token_usage(&ctx, conversation_id).await?;
+
+    eyre::ensure!(
+        usage
+            == ConversationTokenUsage {
+                message_count: 3,
+                counted_messages: 2,
+                tokens: 12,
+            },
+        "{usage:?}"
+    );
+    eyre::ensure!(usage.uncounted_messages() == 1);
+    eyre::ensure!(!usage.is_complete());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn conversation_without_messages_has_zero_usage() -> Result<(), eyre::Report> {
+    let usage = InMemoryMessageRepository::new()
+        .token_usage(&test_request_ctx(), ConversationId::new())
+        .await?;
+
+    eyre::ensure!(usage == ConversationTokenUsage::default());
+    eyre::ensure!(usage.is_complete());
+    Ok(())
+}
@@ -0,0 +1,172 @@
+//! Unit tests for checking tool call and result pairing.
+
+use super::adapters_test_support::{ctx, repo};
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::InMemoryMessageRepository,
+    domain::{
+        ContentPart, ConversationId, Message, Role, SequenceNumber, TextPart, ToolCallPairing,
+        ToolCallPairingIssue, ToolCallPart, ToolResultPart,
+    },
+    ports::repository::MessageRepository,
+    services::{ToolCallPairingService, ToolCallPairingServiceError},
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+use std::sync::Arc;
+
+fn message(conversation_id: ConversationId, seq: u64, role: Role, part: ContentPart) -> Message {
+    Message::new(
+        conversation_id,
+        role,
+        vec![part],
+        SequenceNumber::new(seq),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+fn call(call_id: &str) -> ContentPart {
+    ContentPart::ToolCall(ToolCallPart::new(call_id, "run_command", json!({})))
+}
+
+fn result(call_id: &str) -> ContentPart {
+    ContentPart::ToolResult(ToolResultPart::success(call_id, json!("ok")))
+}
+
+/// Builds a history from `(role, part)` pairs numbered from one.
+fn history(conversation_id: ConversationId, parts: Vec<(Role, ContentPart)>) -> Vec<Message> {
+    parts
+        .into_iter()
+        .zip(1..)
+        .map(|((role, part), seq)| message(conversation_id, seq, role, part))
+        .collect()
+}
+
+#[test]
+fn answered_calls_are_consistent() {
+    let messages = history(
+        ConversationId::new(),
+        vec![
+            (
+                Role::User,
+                ContentPart::Text(TextPart::new("Run the tests")),
+            ),
+            (Role::Assistant, call("c1")),
+            (Role::Assistant, call("c2")),
+            (Role::Tool, result("c2")),
+            (Role::Tool, result("c1")),
+        ],
+    );
+
+    let pairing = ToolCallPairing::check(&messages);
+
+    assert!(pairing.is_consistent());
+    assert_eq!(pairing.paired_calls(), 2);
+}
+
+#[test]
+fn each_break_in_pairing_is_reported_in_order() {
+    let messages = history(
+        ConversationId::new(),
+        vec![
+            (Role::Tool, result("early")),
+            (Role::Assistant, call("c1")),
+            (Role::Assistant, call("c1")),
+            (Role::Tool, result("c1")),
+            (Role::Tool, result("c1")),
+            (Role::Assistant, call("c2")),
+        ],
+    );
+    let ids: Vec<_> = messages.iter().map(Message::id).collect();
+    let [early, _, repeated_call, _, repeated_result, unanswered] = ids.as_slice() else {
+        panic!("expected six messages");
+    };
+
+    let pairing = ToolCallPairing::check(&messages);
+
+    assert_eq!(pairing.paired_calls(), 1);
+    assert_eq!(
+        pairing.issues(),
+        &[
+            ToolCallPairingIssue::UnknownCall {
+                call_id: "early".to_owned(),
+                result_message: *early,
+            },
+            ToolCallPairingIssue::DuplicateCall {
+                call_id: "c1".to_owned(),
+                call_message: *repeated_call,
+            },
+            ToolCallPairingIssue::DuplicateResult {
+                call_id: "c1".to_owned(),
+                result_message: *repeated_result,
+            },
+            ToolCallPairingIssue::OrphanedCall {
+                call_id: "c2".to_owned(),
+                tool_name: "run_command".to_owned(),
+                call_message: *unanswered,
+            },
+        ]
+    );
+    assert_eq!(pairing.orphaned_calls().count(), 1);
+}
+
+#[test]
+fn a_result_before_its_call_is_unknown() {
+    let messages = history(
+        ConversationId::new(),
+        vec![(Role::Tool, result("c1")), (Role::Assistant, call("c1"))],
+    );
+
+    let pairing = ToolCallPairing::check(&messages);
+
+    assert_eq!(pairing.paired_calls(), 0);
+    assert!(matches!(
+        pairing.issues(),
+        [
+            ToolCallPairingIssue::UnknownCall { .. },
+            ToolCallPairingIssue::OrphanedCall { .. },
+        ]
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn service_requires_consistent_stored_history(
+    repo: InMemoryMessageRepository,
+    ctx: RequestContext,
+) {
+    let (paired_id, orphaned_id) = (ConversationId::new(), ConversationId::new());
+    let paired = history(
+        paired_id,
+        vec![(Role::Assistant, call("c1")), (Role::Tool, result("c1"))],
+    );
+    repo.store_batch(&ctx, &paired)
+        .await
+        .expect("paired stored");
+    repo.store_batch(
+        &ctx,
+        &history(orphaned_id, vec![(Role::Assistant, call("c1"))]),
+    )
+    .await
+    .expect("orphaned stored");
+    let service = ToolCallPairingService::new(Arc::new(repo));
+
+    let consistent = service
+        .require_consistent(&ctx, paired_id)
+        .await
+        .expect("pairing is consistent");
+    let inconsistent = service.require_consistent(&ctx, orphaned_id).await;
+
+    assert_eq!(consistent.paired_calls(), 1);
+    let Err(ToolCallPairingServiceError::Inconsistent {
+        conversation_id,
+        pairing,
+    }) = inconsistent
+    else {
+        panic!("expected an inconsistent pairing, got {inconsistent:?}");
+    };
+    assert_eq!(conversation_id, orphaned_id);
+    assert_eq!(pairing.orphaned_calls().count(), 1);
+}
@@ -0,0 +1,305 @@
+//! Unit tests for persisted turns and the messages and handoffs that name
+//! them.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{
+        InMemoryAgentSessionRepository, InMemoryContextSnapshotAdapter,
+        InMemoryConversationRepository, InMemoryHandoffAdapter, InMemoryMessageRepository,
+        InMemoryTurnRepository,
+    },
+    domain::{
+        AgentSession, ContentPart, Conversation, MessageMetadata, Role, SequenceNumber, TextPart,
+        ToolCallPart, Turn, TurnId, TurnStateError, TurnStatus,
+    },
+    ports::{
+        AgentSessionRepository, ConversationRepository, TurnError, TurnRepository,
+        handoff::HandoffError,
+    },
+    services::{
+        AppendMessageRequest, ConversationService, ConversationServiceError, HandoffService,
+        ServiceInitiateParams, TurnService,
+    },
+    validation::service::DefaultMessageValidator,
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use serde_json::json;
+use std::sync::Arc;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+struct Harness {
+    sessions: Arc<InMemoryAgentSessionRepository>,
+    turns: Arc<InMemoryTurnRepository>,
+    conversations: ConversationService<
+        InMemoryConversationRepository,
+        InMemoryMessageRepository,
+        DefaultMessageValidator,
+        DefaultClock,
+    >,
+    turn_service: TurnService<
+        InMemoryAgentSessionRepository,
+        InMemoryMessageRepository,
+        InMemoryTurnRepository,
+        DefaultClock,
+    >,
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let conversation_repo = InMemoryConversationRepository::new();
+    let messages =
+        Arc::new(InMemoryMessageRepository::new().with_conversations(conversation_repo.clone()));
+    let sessions = Arc::new(InMemoryAgentSessionRepository::new());
+    let turns = Arc::new(InMemoryTurnRepository::new());
+    let conversations = ConversationService::new(
+        Arc::new(conversation_repo),
+        Arc::clone(&messages),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_turns(Arc::clone(&turns) as Arc<dyn TurnRepository>);
+    let turn_service = TurnService::new(
+        Arc::clone(&sessions),
+        messages,
+        Arc::clone(&turns),
+        Arc::new(DefaultClock),
+    );
+    Harness {
+        sessions,
+        turns,
+        conversations,
+        turn_service,
+    }
+}
+
+/// A conversation with an agent session, and a turn that session started
+/// for a user prompt.
+struct Started {
+    conversation: Conversation,
+    session: AgentSession,
+    turn: Turn,
+}
+
+async fn started_turn(ctx: &RequestContext, harness: &Harness) -> Started {
+    let conversation = harness
+        .conversations
+        .create_conversation(ctx)
+        .await
+        .expect("create conversation");
+    let session = AgentSession::new(
+        conversation.id(),
+        "claude",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+    harness
+        .sessions
+        .store(ctx, &session)
+        .await
+        .expect("store session");
+    let prompt = harness
+        .conversations
+        .append_message(
+            ctx,
+            AppendMessageRequest::new(
+                conversation.id(),
+                Role::User,
+                vec![ContentPart::Text(TextPart::new("Fix it"))],
+            ),
+        )
+        .await
+        .expect("append prompt");
+    let turn = harness
+        .turn_service
+        .start(ctx, session.session_id, prompt.id())
+        .await
+        .expect("start turn");
+    Started {
+        conversation,
+        session,
+        turn,
+    }
+}
+
+fn reply(conversation: &Conversation, turn_id: TurnId, part: ContentPart) -> AppendMessageRequest {
+    AppendMessageRequest::new(conversation.id(), Role::Assistant, vec![part])
+        .with_metadata(MessageMetadata::empty().with_turn_id(turn_id))
+}
+
+#[rstest]
+#[tokio::test]
+async fn starting_a_turn_records_it_on_the_session(ctx: RequestContext, harness: Harness) {
+    let Started { session, turn, .. } = started_turn(&ctx, &harness).await;
+
+    let stored_session = harness
+        .sessions
+        .find_by_id(&ctx, session.session_id)
+        .await
+        .expect("find session")
+        .expect("session exists");
+    assert_eq!(stored_session.turn_ids, [turn.turn_id]);
+    assert_eq!(turn.status, TurnStatus::InProgress);
+    assert_eq!(
+        harness
+            .turns
+            .find_by_id(&ctx, turn.turn_id)
+            .await
+            .expect("find turn"),
+        Some(turn)
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn appended_messages_are_recorded_on_their_turn(ctx: RequestContext, harness: Harness) {
+    let Started {
+        conversation, turn, ..
+    } = started_turn(&ctx, &harness).await;
+    let call = ToolCallPart::new("call-1", "run_tests", json!({}));
+
+    let stored = harness
+        .conversations
+        .append_message(
+            &ctx,
+            reply(&conversation, turn.turn_id, ContentPart::ToolCall(call)),
+        )
+        .await
+        .expect("append reply");
+    let completed = harness
+        .turn_service
+        .complete(&ctx, turn.turn_id)
+        .await
+        .expect("complete turn");
+
+    assert_eq!(completed.response_message_ids, [stored.id()]);
+    let [call] = completed.tool_calls.as_slice() else {
+        panic!("expected one tool call, got {:?}", completed.tool_calls);
+    };
+    assert_eq!(call.call_id, "call-1");
+    assert!(completed.duration().is_some());
+}
+
+#[rstest]
+#[tokio::test]
+async fn ending_a_stale_turn_keeps_responses_recorded_since(ctx: RequestContext, harness: Harness) {
+    let Started {
+        conversation,
+        turn: mut stale,
+        ..
+    } = started_turn(&ctx, &harness).await;
+    let text = ContentPart::Text(TextPart::new("done"));
+
+    let stored = harness
+        .conversations
+        .append_message(&ctx, reply(&conversation, stale.turn_id, text))
+        .await
+        .expect("append reply");
+    stale.complete(&DefaultClock).expect("complete turn");
+    harness
+        .turns
+        .update(&ctx, &stale)
+        .await
+        .expect("update turn");
+    let late = harness
+        .turns
+        .record_response(&ctx, stale.turn_id, &stored)
+        .await;
+
+    let turn = harness
+        .turn_service
+        .find(&ctx, stale.turn_id)
+        .await
+        .expect("find turn");
+    assert_eq!(turn.status, TurnStatus::Completed);
+    assert_eq!(turn.response_message_ids, [stored.id()]);
+    assert!(matches!(
+        late,
+        Err(TurnError::State(TurnStateError::Ended { .. }))
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn ended_and_unknown_turns_refuse_messages(ctx: RequestContext, harness: Harness) {
+    let Started {
+        conversation, turn, ..
+    } = started_turn(&ctx, &harness).await;
+    harness
+        .turn_service
+        .fail(&ctx, turn.turn_id)
+        .await
+        .expect("fail turn");
+    let unknown = TurnId::new();
+    let text = || ContentPart::Text(TextPart::new("late"));
+
+    let to_ended = harness
+        .conversations
+        .append_message(&ctx, reply(&conversation, turn.turn_id, text()))
+        .await;
+    let to_unknown = harness
+        .conversations
+        .append_message(&ctx, reply(&conversation, unknown, text()))
+        .await;
+
+    assert!(matches!(
+        to_ended,
+        Err(ConversationServiceError::TurnState(TurnStateError::Ended {
+            status: TurnStatus::Failed,
+            ..
+        }))
+    ));
+    assert!(matches!(
+        to_unknown,
+        Err(ConversationServiceError::TurnNotFound(id)) if id == unknown
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn handoffs_must_name_a_turn_of_their_source_session(ctx: RequestContext, harness: Harness) {
+    let Started { session, turn, .. } = started_turn(&ctx, &harness).await;
+    let handoffs = HandoffService::new(
+        Arc::clone(&harness.sessions),
+        Arc::new(InMemoryHandoffAdapter::new(DefaultClock)),
+        Arc::new(InMemoryContextSnapshotAdapter::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_turns(Arc::clone(&harness.turns) as Arc<dyn TurnRepository>);
+    let unknown = TurnId::new();
+
+    let refused = handoffs
+        .initiate(
+            &ctx,
+            ServiceInitiateParams::new(
+                session.session_id,
+                "codex",
+                unknown,
+                SequenceNumber::new(2),
+            ),
+        )
+        .await;
+    let initiated = handoffs
+        .initiate(
+            &ctx,
+            ServiceInitiateParams::new(
+                session.session_id,
+                "codex",
+                turn.turn_id,
+                SequenceNumber::new(2),
+            ),
+        )
+        .await
+        .expect("initiate handoff");
+
+    assert!(matches!(
+        refused,
+        Err(HandoffError::PriorTurnNotFound(id)) if id == unknown
+    ));
+    assert_eq!(initiated.prior_turn_id, turn.turn_id);
+}
@@ -0,0 +1,145 @@
+//! Unit tests for upgrade chains and bulk upgrades.
+
+use crate::message::{
+    error::SchemaUpgradeError,
+    versioning::{
+        EventUpgrader, UpgradeChain, UpgradeStep, UpgraderRegistry, VersionedEvent,
+        upgrader::UpgradeResult,
+    },
+};
+use rstest::rstest;
+use serde_json::json;
+
+/// Records the step it took in the event's `steps` array.
+struct Step {
+    from: u32,
+    to: u32,
+}
+
+impl UpgradeStep for Step {
+    fn from_version(&self) -> u32 {
+        self.from
+    }
+
+    fn to_version(&self) -> u32 {
+        self.to
+    }
+
+    fn upgrade(&self, mut event: VersionedEvent) -> UpgradeResult<VersionedEvent> {
+        let taken = event
+            .data_mut()
+            .get_mut("steps")
+            .and_then(serde_json::Value::as_array_mut)
+            .ok_or_else(|| SchemaUpgradeError::malformed("expected a steps array"))?;
+        taken.push(json!(format!("{}->{}", self.from, self.to)));
+        Ok(event)
+    }
+}
+
+fn steps(pairs: &[(u32, u32)]) -> Vec<Box<dyn UpgradeStep>> {
+    pairs
+        .iter()
+        .map(|&(from, to)| Box::new(Step { from, to }) as Box<dyn UpgradeStep>)
+        .collect()
+}
+
+fn scored(version: u32) -> VersionedEvent {
+    VersionedEvent::new(version, "Scored", json!({ "steps": [] }))
+}
+
+#[rstest]
+#[case(1, json!(["1->2", "2->3", "3->5"]))]
+#[case(3, json!(["3->5"]))]
+#[case(5, json!([]))]
+fn chains_apply_every_step_from_the_stored_version(
+    #[case] stored: u32,
+    #[case] expected: serde_json::Value,
+) {
+    let chain = UpgradeChain::new("Scored", steps(&[(3, 5), (1, 2), (2, 3)])).expect("chain");
+
+    let upgraded = chain.upgrade(scored(stored)).expect("upgrade");
+
+    assert_eq!(upgraded.version(), 5);
+    assert_eq!(upgraded.data().get("steps"), Some(&expected));
+    assert_eq!(chain.current_version(), 5);
+}
+
+#[rstest]
+fn chains_reject_versions_they_cannot_reach() {
+    let chain = UpgradeChain::new("Scored", steps(&[(1, 2)])).expect("chain");
+
+    assert!(chain.supports_version(2));
+    assert!(!chain.supports_version(7));
+    assert!(matches!(
+        chain.upgrade(scored(7)),
+        Err(SchemaUpgradeError::UnsupportedVersion(7))
+    ));
+}
+
+#[rstest]
+#[case::empty(&[], "no steps")]
+#[case::conflicting(&[(1, 2), (1, 3)], "two steps from version 1")]
+#[case::cycle(&[(1, 2), (2, 1)], "returns to version 1")]
+#[case::standstill(&[(2, 2)], "returns to version 2")]
+#[case::gap(&[(1, 2), (3, 4)], "no step from version 2")]
+fn malformed_chains_are_rejected(#[case] pairs: &[(u32, u32)], #[case] message: &str) {
+    let Err(err) = UpgradeChain::new("Scored", steps(pairs)) else {
+        panic!("expected {message}");
+    };
+
+    assert!(err.to_string().contains(message), "{err}");
+}
+
+#[rstest]
+fn registering_a_broken_chain_leaves_the_registry_unchanged() {
+    let mut registry = UpgraderRegistry::empty();
+
+    let result = registry.register_steps("Scored", steps(&[(1, 2), (2, 1)]));
+
+    assert!(matches!(
+        result,
+        Err(SchemaUpgradeError::UpgradeCycle { .. })
+    ));
+    assert!(!registry.has_upgrader("Scored"));
+}
+
+#[rstest]
+fn upgrade_all_upgrades_known_types_and_passes_others_through() {
+    let mut registry = UpgraderRegistry::new();
+    registry
+        .register_steps("Scored", steps(&[(1, 2)]))
+        .expect("register");
+    let events = vec![
+        scored(1),
+        VersionedEvent::new(4, "Unregistered", json!({})),
+        VersionedEvent::new(1, "MessageCreated", json!({ "id": "1", "content": [] })),
+    ];
+
+    let upgraded = registry.upgrade_all(events).expect("upgrade");
+
+    let versions: Vec<(&str, u32)> = upgraded
+        .iter()
+        .map(|event| (event.event_type(), event.version()))
+        .collect();
+    assert_eq!(
+        versions,
+        [("Scored", 2), ("Unregistered", 4), ("MessageCreated", 3)]
+    );
+}
+
+#[rstest]
+#[case("Scored", 1, true)]
+#[case("Scored", 2, false)]
+#[case("Unregistered", 1, false)]
+fn needs_upgrade_compares_with_the_current_version(
+    #[case] event_type: &str,
+    #[case] version: u32,
+    #[case] expected: bool,
+) {
+    let mut registry = UpgraderRegistry::empty();
+    registry
+        .register_steps("Scored", steps(&[(1, 2)]))
+        .expect("register");
+
+    assert_eq!(registry.needs_upgrade(event_type, version), expected);
+}
@@ -0,0 +1,176 @@
+//! Unit tests for attachment type, encoding and size validation.
+
+use super::validation_fixtures::{default_validator, message_factory};
+use crate::message::{
+    domain::{AttachmentPart, ContentPart, Message, MessageBuilderError, Role},
+    error::ValidationError,
+    ports::validator::{AttachmentPolicy, MessageValidator, ValidationConfig},
+    validation::service::DefaultMessageValidator,
+};
+use rstest::rstest;
+
+/// "Hello World", 11 bytes once decoded.
+const HELLO_WORLD: &str = "SGVsbG8gV29ybGQ=";
+/// A PNG signature.
+const PNG: &str = "iVBORw0KGgo=";
+/// The start of a Windows executable.
+const EXECUTABLE: &str = "TVqQAAMAAAAEAAAA";
+
+fn validator_with(policy: AttachmentPolicy) -> DefaultMessageValidator {
+    DefaultMessageValidator::with_config(ValidationConfig {
+        attachment_policy: policy,
+        ..ValidationConfig::default()
+    })
+}
+
+fn attachments(
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    parts: Vec<AttachmentPart>,
+) -> Message {
+    message_factory(
+        Role::User,
+        parts.into_iter().map(ContentPart::Attachment).collect(),
+    )
+    .expect("message should build")
+}
+
+fn rejected_at_first_part(result: Result<(), ValidationError>) -> bool {
+    matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 0, .. })
+    )
+}
+
+#[rstest]
+#[case::base64_text("text/plain", HELLO_WORLD)]
+#[case::plain_text("text/plain", "Hello World")]
+#[case::plain_json("application/json", r#"{"ok": true}"#)]
+#[case::labelled_png("image/png", PNG)]
+#[case::unsniffed_binary("application/octet-stream", HELLO_WORLD)]
+fn well_formed_attachments_pass(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] mime_type: &str,
+    #[case] data: &str,
+) {
+    let message = attachments(message_factory, vec![AttachmentPart::new(mime_type, data)]);
+    assert!(default_validator.validate(&message).is_ok());
+}
+
+#[rstest]
+#[case::binary_not_base64("image/png", "not base64!")]
+#[case::png_labelled_jpeg("image/jpeg", PNG)]
+#[case::text_labelled_png("image/png", HELLO_WORLD)]
+#[case::hidden_executable("application/octet-stream", EXECUTABLE)]
+#[case::denied_executable("application/x-msdownload", EXECUTABLE)]
+fn malformed_or_mislabelled_attachments_fail(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] mime_type: &str,
+    #[case] data: &str,
+) {
+    let message = attachments(message_factory, vec![AttachmentPart::new(mime_type, data)]);
+    assert!(rejected_at_first_part(default_validator.validate(&message)));
+}
+
+#[rstest]
+fn checks_can_be_switched_off(
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+) {
+    let validator = validator_with(AttachmentPolicy {
+        verify_encoding: false,
+        sniff_content: false,
+        ..AttachmentPolicy::DEFAULT
+    });
+    let message = attachments(
+        message_factory,
+        vec![
+            AttachmentPart::new("image/png", "not base64!"),
+            AttachmentPart::new("image/jpeg", PNG),
+        ],
+    );
+    assert!(validator.validate(&message).is_ok());
+}
+
+#[rstest]
+#[case::exact("application/pdf", true)]
+#[case::wildcard("image/webp", true)]
+#[case::case_and_parameters("IMAGE/PNG; name=chart.png", true)]
+#[case::unlisted("text/html", false)]
+#[case::wildcard_is_not_a_prefix("imagery/png", false)]
+fn allowlist_matches_types_and_wildcards(#[case] mime_type: &str, #[case] permitted: bool) {
+    let policy = AttachmentPolicy {
+        allowed_mime_types: Some(&["image/*", "application/pdf"]),
+        ..AttachmentPolicy::DEFAULT
+    };
+    assert_eq!(policy.permits_mime_type(mime_type), permitted);
+}
+
+#[rstest]
+fn denylist_overrides_allowlist() {
+    let policy = AttachmentPolicy {
+        allowed_mime_types: Some(&["application/*"]),
+        ..AttachmentPolicy::DEFAULT
+    };
+    assert!(policy.permits_mime_type("application/pdf"));
+    assert!(!policy.permits_mime_type("application/x-msdownload"));
+}
+
+#[rstest]
+#[case::at_limit(11, true)]
+#[case::over_limit(10, false)]
+fn attachment_size_is_counted_in_decoded_bytes(
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] limit: usize,
+    #[case] passes: bool,
+) {
+    let validator = validator_with(AttachmentPolicy {
+        max_attachment_bytes: Some(limit),
+        ..AttachmentPolicy::DEFAULT
+    });
+    let message = attachments(
+        message_factory,
+        vec![AttachmentPart::new("text/plain", HELLO_WORLD)],
+    );
+    let result = validator.validate(&message);
+    assert_eq!(result.is_ok(), passes, "{result:?}");
+}
+
+#[rstest]
+fn externalised_attachments_are_measured_by_recorded_size(
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+) {
+    let validator = validator_with(AttachmentPolicy {
+        max_attachment_bytes: Some(1024),
+        ..AttachmentPolicy::DEFAULT
+    });
+    let mut stored = AttachmentPart::new("image/png", PNG).with_size(4096);
+    let _blob = stored.externalise();
+    let message = attachments(message_factory, vec![stored]);
+    assert!(rejected_at_first_part(validator.validate(&message)));
+}
+
+#[rstest]
+fn total_attachment_size_is_capped(
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+) {
+    let validator = validator_with(AttachmentPolicy {
+        max_attachment_bytes: Some(11),
+        max_total_attachment_bytes: Some(16),
+        ..AttachmentPolicy::DEFAULT
+    });
+    let message = attachments(
+        message_factory,
+        vec![
+            AttachmentPart::new("text/plain", HELLO_WORLD),
+            AttachmentPart::new("text/plain", HELLO_WORLD),
+        ],
+    );
+    assert!(matches!(
+        validator.validate(&message),
+        Err(ValidationError::AttachmentsTooLarge {
+            actual_bytes: 22,
+            limit_bytes: 16,
+        })
+    ));
+}
@@ -3,14 +3,16 @@
 use super::validation_fixtures::{clock, default_validator};
 use crate::message::{
     domain::{
-        AgentResponseAudit, AgentResponseStatus, ContentPart, ConversationId, Message,
-        MessageMetadata, Role, SequenceNumber, TextPart, ToolCallAudit, ToolCallStatus,
+        AgentResponseAudit, AgentResponseStatus, ContentPart, ConversationId,
+        INBOUND_ORIGIN_EXTENSION_KEY, Message, MessageMetadata, Role,
+        STREAM_INTERRUPTED_EXTENSION_KEY, SequenceNumber, TextPart, ToolCallAudit, ToolCallStatus,
     },
     error::ValidationError,
     ports::validator::MessageValidator,
 };
 use mockable::DefaultClock;
 use rstest::rstest;
+use serde_json::json;
 
 fn build_message_with_metadata(clock: &DefaultClock, metadata: MessageMetadata) -> Message {
     Message::builder(
@@ -119,3 +121,57 @@ fn validate_metadata_rejects_empty_response_id(
 
     assert_invalid_metadata(result, "response_id");
 }
+
+#[rstest]
+fn validate_metadata_accepts_vendor_and_corbusier_extension_keys(
+    clock: DefaultClock,
+    default_validator: crate::message::validation::service::DefaultMessageValidator,
+) {
+    let mut metadata = MessageMetadata::empty()
+        .with_extension("acme.trace_id", json!("t-1"))
+        .expect("vendor-prefixed key");
+    metadata
+        .extensions
+        .insert(INBOUND_ORIGIN_EXTENSION_KEY.to_owned(), json!({}));
+    metadata
+        .extensions
+        .insert(STREAM_INTERRUPTED_EXTENSION_KEY.to_owned(), json!(true));
+
+    let message = build_message_with_metadata(&clock, metadata);
+
+    assert!(default_validator.validate_structure(&message).is_ok());
+}
+
+#[rstest]
+#[case("trace_id")]
+#[case("custom")]
+fn validate_metadata_accepts_legacy_extension_keys_without_a_vendor_prefix(
+    clock: DefaultClock,
+    default_validator: crate::message::validation::service::DefaultMessageValidator,
+    #[case] key: &str,
+) {
+    let mut metadata = MessageMetadata::empty();
+    metadata.extensions.insert(key.to_owned(), json!(true));
+
+    let message = build_message_with_metadata(&clock, metadata);
+
+    assert!(default_validator.validate_structure(&message).is_ok());
+}
+
+#[rstest]
+#[case("review.notes", "reserved")]
+#[case("corbusier.anything", "reserved")]
+fn validate_metadata_rejects_unknown_keys_in_reserved_namespaces(
+    clock: DefaultClock,
+    default_validator: crate::message::validation::service::DefaultMessageValidator,
+    #[case] key: &str,
+    #[case] expected_fragment: &str,
+) {
+    let mut metadata = MessageMetadata::empty();
+    metadata.extensions.insert(key.to_owned(), json!(true));
+
+    let message = build_message_with_metadata(&clock, metadata);
+    let result = default_validator.validate_structure(&message);
+
+    assert_invalid_metadata(result, expected_fragment);
+}
@@ -0,0 +1,151 @@
+//! Unit tests for custom validation rules and their registry.
+
+use super::validation_fixtures::{clock, default_validator};
+use crate::message::{
+    domain::{ContentPart, ConversationId, Message, Role, SequenceNumber, TextPart},
+    error::ValidationError,
+    ports::validator::{
+        MessageValidator, ValidationConfig, ValidationResult, ValidationRule, ValidationStage,
+    },
+    validation::{DefaultMessageValidator, ValidationRuleRegistry},
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+/// Rule that rejects every message with an error naming itself.
+struct Rejects {
+    name: &'static str,
+    stage: ValidationStage,
+}
+
+impl Rejects {
+    const fn content(name: &'static str) -> Self {
+        Self {
+            name,
+            stage: ValidationStage::Content,
+        }
+    }
+
+    const fn structure(name: &'static str) -> Self {
+        Self {
+            name,
+            stage: ValidationStage::Structure,
+        }
+    }
+}
+
+impl ValidationRule for Rejects {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn stage(&self) -> ValidationStage {
+        self.stage
+    }
+
+    fn check(&self, _message: &Message, _config: &ValidationConfig) -> ValidationResult<()> {
+        Err(ValidationError::InvalidMetadata(self.name.to_owned()))
+    }
+}
+
+/// Rule that accepts every message.
+struct Accepts;
+
+impl ValidationRule for Accepts {
+    fn name(&self) -> &str {
+        "accepts"
+    }
+
+    fn check(&self, _message: &Message, _config: &ValidationConfig) -> ValidationResult<()> {
+        Ok(())
+    }
+}
+
+fn message(clock: &DefaultClock) -> Message {
+    Message::new(
+        ConversationId::new(),
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("Hello"))],
+        SequenceNumber::new(1),
+        clock,
+    )
+    .expect("valid message")
+}
+
+fn rejected_by(result: ValidationResult<()>) -> Vec<String> {
+    let errors = match result {
+        Ok(()) => Vec::new(),
+        Err(ValidationError::Multiple(errors)) => errors,
+        Err(other) => vec![other],
+    };
+    errors
+        .into_iter()
+        .filter_map(|error| match error {
+            ValidationError::InvalidMetadata(name) => Some(name),
+            _ => None,
+        })
+        .collect()
+}
+
+#[rstest]
+fn custom_rules_run_in_registration_order_and_are_aggregated(clock: DefaultClock) {
+    let rules = ValidationRuleRegistry::new()
+        .with_rule(Rejects::content("first"))
+        .with_rule(Accepts)
+        .with_rule(Rejects::content("second"));
+    let validator = DefaultMessageValidator::new().with_rules(rules);
+
+    let result = validator.validate(&message(&clock));
+
+    assert!(result.as_ref().is_err_and(ValidationError::is_multiple));
+    assert_eq!(rejected_by(result), ["first", "second"]);
+}
+
+#[rstest]
+fn rules_added_later_run_after_earlier_ones(clock: DefaultClock) {
+    let validator = DefaultMessageValidator::new()
+        .with_rule(Rejects::content("first"))
+        .with_rules(ValidationRuleRegistry::new().with_rule(Rejects::content("second")))
+        .with_rule(Rejects::content("third"));
+
+    assert_eq!(
+        validator.custom_rules().names().collect::<Vec<_>>(),
+        ["first", "second", "third"]
+    );
+    assert_eq!(
+        rejected_by(validator.validate_content(&message(&clock))),
+        ["first", "second", "third"]
+    );
+}
+
+#[rstest]
+fn custom_rules_run_in_the_stage_they_name(clock: DefaultClock) {
+    let validator = DefaultMessageValidator::new()
+        .with_rule(Rejects::structure("structure"))
+        .with_rule(Rejects::content("content"));
+    let message = message(&clock);
+
+    assert_eq!(
+        rejected_by(validator.validate_structure(&message)),
+        ["structure"]
+    );
+    assert_eq!(
+        rejected_by(validator.validate_content(&message)),
+        ["content"]
+    );
+    assert_eq!(
+        rejected_by(validator.validate(&message)),
+        ["structure", "content"]
+    );
+}
+
+#[rstest]
+fn passing_custom_rules_leave_valid_messages_valid(
+    clock: DefaultClock,
+    default_validator: DefaultMessageValidator,
+) {
+    let validator = default_validator.with_rule(Accepts);
+
+    assert!(validator.validate(&message(&clock)).is_ok());
+    assert_eq!(validator.custom_rules().len(), 1);
+}
@@ -0,0 +1,241 @@
+//! Unit tests for validation service - image, reasoning, and citation
+//! validation tests.
+
+use super::validation_fixtures::{default_validator, message_factory};
+use crate::message::{
+    domain::{
+        CitationPart, CitationSource, CitationSpan, ContentHash, ContentPart, ConversationId,
+        ImagePart, ImageThumbnail, Message, MessageBuilderError, MessageId, ReasoningPart, Role,
+        SequenceNumber, TextPart,
+    },
+    error::ValidationError,
+    ports::validator::{MessageValidator, ValidationConfig},
+    validation::service::DefaultMessageValidator,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+// ============================================================================
+// Image validation tests
+// ============================================================================
+
+fn chart() -> ImagePart {
+    ImagePart::new("image/png", "iVBORw0KGgo=", 640, 480)
+}
+
+#[rstest]
+fn valid_image_passes(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+) {
+    let image = chart()
+        .with_alt_text("Weekly build durations")
+        .with_thumbnail(ImageThumbnail::new(ContentHash::of(b"thumb"), 160, 120));
+    let message = message_factory(Role::User, vec![ContentPart::Image(image)])
+        .expect("test message should build");
+    assert!(default_validator.validate(&message).is_ok());
+}
+
+#[rstest]
+#[case::unsupported_format(ImagePart { mime_type: "image/tiff".to_owned(), ..chart() })]
+#[case::no_data(ImagePart { data: String::new(), ..chart() })]
+#[case::zero_width(ImagePart { width: 0, ..chart() })]
+#[case::blank_alt_text(chart().with_alt_text("  "))]
+#[case::oversized_thumbnail(
+    chart().with_thumbnail(ImageThumbnail::new(ContentHash::of(b"thumb"), 1280, 960))
+)]
+fn invalid_image_fails(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] image: ImagePart,
+) {
+    let message = message_factory(Role::User, vec![ContentPart::Image(image)])
+        .expect("test message should build");
+    let result = default_validator.validate(&message);
+    assert!(matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 0, .. })
+    ));
+}
+
+#[rstest]
+fn image_formats_are_configurable(
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+) {
+    let validator = DefaultMessageValidator::with_config(ValidationConfig {
+        supported_image_formats: &["image/tiff"],
+        ..ValidationConfig::default()
+    });
+    let tiff = ImagePart {
+        mime_type: "image/tiff".to_owned(),
+        ..chart()
+    };
+    let message = message_factory(
+        Role::User,
+        vec![ContentPart::Image(tiff), ContentPart::Image(chart())],
+    )
+    .expect("test message should build");
+    let result = validator.validate(&message);
+    assert!(matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 1, .. })
+    ));
+}
+
+// ============================================================================
+// Reasoning validation tests
+// ============================================================================
+
+#[rstest]
+#[case::readable(ReasoningPart::new("The build broke after the lockfile changed."))]
+#[case::redacted(ReasoningPart::new("EqgBCkYIBhgCIkA=").into_redacted())]
+fn assistant_reasoning_passes(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] reasoning: ReasoningPart,
+) {
+    let message = message_factory(
+        Role::Assistant,
+        vec![
+            ContentPart::Reasoning(reasoning),
+            ContentPart::Text(TextPart::new("Reverting the lockfile fixes it.")),
+        ],
+    )
+    .expect("test message should build");
+    assert!(default_validator.validate(&message).is_ok());
+}
+
+#[rstest]
+#[case::user(Role::User)]
+#[case::tool(Role::Tool)]
+#[case::system(Role::System)]
+fn reasoning_outside_assistant_messages_fails(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] role: Role,
+) {
+    let message = message_factory(
+        role,
+        vec![
+            ContentPart::Text(TextPart::new("Here is the log.")),
+            ContentPart::Reasoning(ReasoningPart::new("I should check the log.")),
+        ],
+    )
+    .expect("test message should build");
+    let result = default_validator.validate(&message);
+    assert!(matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 1, .. })
+    ));
+}
+
+#[rstest]
+fn empty_reasoning_fails(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+) {
+    let message = message_factory(
+        Role::Assistant,
+        vec![ContentPart::Reasoning(ReasoningPart::new("  "))],
+    )
+    .expect("test message should build");
+    let result = default_validator.validate(&message);
+    assert!(matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 0, .. })
+    ));
+}
+
+// ============================================================================
+// Citation validation tests
+// ============================================================================
+
+fn cited_answer(citation: CitationPart) -> Vec<ContentPart> {
+    vec![
+        ContentPart::Text(TextPart::new("Café opens at 9 ☕")),
+        ContentPart::Citation(citation),
+    ]
+}
+
+#[rstest]
+#[case::uri(
+    CitationSource::uri("https://cafe.example/hours"),
+    CitationSpan::new(0, 0, 4)
+)]
+#[case::message(CitationSource::message(MessageId::new()), CitationSpan::new(0, 5, 17))]
+fn citations_of_text_in_the_message_pass(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] source: CitationSource,
+    #[case] span: CitationSpan,
+) {
+    let message = message_factory(
+        Role::Assistant,
+        cited_answer(CitationPart::new(source, vec![span])),
+    )
+    .expect("test message should build");
+    assert!(default_validator.validate(&message).is_ok());
+}
+
+#[rstest]
+#[case::missing_part(vec![CitationSpan::new(2, 0, 4)])]
+#[case::non_text_part(vec![CitationSpan::new(1, 0, 4)])]
+#[case::past_end(vec![CitationSpan::new(0, 5, 18)])]
+#[case::empty_span(vec![CitationSpan::new(0, 4, 4)])]
+#[case::no_spans(Vec::new())]
+fn citations_with_dangling_or_empty_spans_fail(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] spans: Vec<CitationSpan>,
+) {
+    let citation = CitationPart::new(CitationSource::uri("https://cafe.example/hours"), spans);
+    let message = message_factory(Role::Assistant, cited_answer(citation))
+        .expect("test message should build");
+    let result = default_validator.validate(&message);
+    assert!(matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 1, .. })
+    ));
+}
+
+#[rstest]
+#[case::no_scheme(CitationSource::uri("cafe.example/hours"))]
+#[case::whitespace(CitationSource::uri("https://cafe.example/opening hours"))]
+#[case::nil_message(CitationSource::message(MessageId::from_uuid(uuid::Uuid::nil())))]
+fn citations_with_invalid_sources_fail(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] source: CitationSource,
+) {
+    let citation = CitationPart::new(source, vec![CitationSpan::new(0, 0, 4)]);
+    let message = message_factory(Role::Assistant, cited_answer(citation))
+        .expect("test message should build");
+    let result = default_validator.validate(&message);
+    assert!(matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 1, .. })
+    ));
+}
+
+#[rstest]
+fn citing_the_citing_message_fails(default_validator: DefaultMessageValidator) {
+    let id = MessageId::new();
+    let citation = CitationPart::new(
+        CitationSource::message(id),
+        vec![CitationSpan::new(0, 0, 4)],
+    );
+    let message = Message::builder(
+        ConversationId::new(),
+        Role::Assistant,
+        SequenceNumber::new(1),
+    )
+    .with_id(id)
+    .with_content_parts(cited_answer(citation))
+    .build(&DefaultClock)
+    .expect("test message should build");
+    let result = default_validator.validate(&message);
+    assert!(matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 1, .. })
+    ));
+}
@@ -0,0 +1,181 @@
+//! Unit tests for the per-role content-part policy.
+
+use super::validation_fixtures::{default_validator, message_factory};
+use crate::message::{
+    domain::{
+        AttachmentPart, CitationPart, CitationSource, ContentPart, ContentPartKind, CustomPart,
+        ImagePart, Message, MessageBuilderError, ReasoningPart, RedactedPart, Role, TextPart,
+        ToolCallPart, ToolResultPart,
+    },
+    error::ValidationError,
+    ports::validator::{MessageValidator, RoleContentPolicy, ValidationConfig},
+    validation::{rules::validate_role_content, service::DefaultMessageValidator},
+};
+use chrono::Utc;
+use rstest::rstest;
+use serde_json::json;
+
+fn part_of(kind: ContentPartKind) -> ContentPart {
+    match kind {
+        ContentPartKind::Text => ContentPart::Text(TextPart::new("Hello")),
+        ContentPartKind::Reasoning => ContentPart::Reasoning(ReasoningPart::new("Thinking")),
+        ContentPartKind::ToolCall => {
+            ContentPart::ToolCall(ToolCallPart::new("call-1", "read_file", json!({})))
+        }
+        ContentPartKind::ToolResult => {
+            ContentPart::ToolResult(ToolResultPart::success("call-1", json!("ok")))
+        }
+        ContentPartKind::Attachment => {
+            ContentPart::Attachment(AttachmentPart::new("text/plain", "SGVsbG8="))
+        }
+        ContentPartKind::Image => {
+            ContentPart::Image(ImagePart::new("image/png", "iVBORw0KGgo=", 640, 480))
+        }
+        ContentPartKind::Citation => ContentPart::Citation(CitationPart::new(
+            CitationSource::uri("https://example.com/doc"),
+            Vec::new(),
+        )),
+        ContentPartKind::Custom => {
+            ContentPart::Custom(CustomPart::new("acme.widget", json!({"id": 1})))
+        }
+        ContentPartKind::Redacted => ContentPart::Redacted(RedactedPart {
+            reason: "personal data".to_owned(),
+            redacted_at: Utc::now(),
+        }),
+    }
+}
+
+/// The default policy, written out in full.
+fn default_allows(role: Role, kind: ContentPartKind) -> bool {
+    match kind {
+        ContentPartKind::Text
+        | ContentPartKind::Citation
+        | ContentPartKind::Custom
+        | ContentPartKind::Redacted => true,
+        ContentPartKind::Reasoning | ContentPartKind::ToolCall => role == Role::Assistant,
+        ContentPartKind::ToolResult => role == Role::Tool,
+        ContentPartKind::Attachment | ContentPartKind::Image => {
+            matches!(role, Role::User | Role::Assistant)
+        }
+    }
+}
+
+#[rstest]
+#[case::user(Role::User)]
+#[case::assistant(Role::Assistant)]
+#[case::tool(Role::Tool)]
+#[case::system(Role::System)]
+fn default_policy_covers_every_part_kind(
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] role: Role,
+) {
+    let config = ValidationConfig::default();
+    for kind in ContentPartKind::ALL {
+        let message = message_factory(role, vec![part_of(kind)]).expect("message should build");
+        let result = validate_role_content(&message, &config);
+        assert_eq!(
+            result.is_ok(),
+            default_allows(role, kind),
+            "{kind:?} in a {role} message"
+        );
+    }
+}
+
+#[rstest]
+#[case::tool_call_from_user(Role::User, ContentPartKind::ToolCall)]
+#[case::tool_result_from_assistant(Role::Assistant, ContentPartKind::ToolResult)]
+#[case::attachment_from_tool(Role::Tool, ContentPartKind::Attachment)]
+#[case::image_from_system(Role::System, ContentPartKind::Image)]
+fn validator_rejects_parts_the_role_may_not_carry(
+    default_validator: DefaultMessageValidator,
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+    #[case] role: Role,
+    #[case] kind: ContentPartKind,
+) {
+    let message = message_factory(
+        role,
+        vec![ContentPart::Text(TextPart::new("Context")), part_of(kind)],
+    )
+    .expect("message should build");
+    let result = default_validator.validate(&message);
+    assert!(matches!(
+        result,
+        Err(ValidationError::InvalidContentPart { index: 1, .. })
+    ));
+}
+
+#[rstest]
+fn disallowed_parts_are_each_reported(
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+) {
+    let message = message_factory(
+        Role::User,
+        vec![
+            part_of(ContentPartKind::ToolCall),
+            part_of(ContentPartKind::Text),
+            part_of(ContentPartKind::ToolResult),
+        ],
+    )
+    .expect("message should build");
+    let result = validate_role_content(&message, &ValidationConfig::default());
+    let Err(ValidationError::Multiple(errors)) = result else {
+        panic!("expected one error per disallowed part, got {result:?}");
+    };
+    assert!(matches!(
+        errors.as_slice(),
+        [
+            ValidationError::InvalidContentPart { index: 0, .. },
+            ValidationError::InvalidContentPart { index: 2, .. },
+        ]
+    ));
+}
+
+#[rstest]
+fn permissive_policy_accepts_every_combination(
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+) {
+    let config = ValidationConfig {
+        role_content_policy: RoleContentPolicy::PERMISSIVE,
+        ..ValidationConfig::default()
+    };
+    for role in Role::ALL {
+        let content = ContentPartKind::ALL.into_iter().map(part_of).collect();
+        let message = message_factory(role, content).expect("message should build");
+        assert!(
+            validate_role_content(&message, &config).is_ok(),
+            "{role} message"
+        );
+    }
+}
+
+#[rstest]
+fn with_role_replaces_only_that_role(
+    message_factory: impl Fn(Role, Vec<ContentPart>) -> Result<Message, MessageBuilderError>,
+) {
+    let policy = RoleContentPolicy::DEFAULT.with_role(
+        Role::Tool,
+        &[ContentPartKind::ToolResult, ContentPartKind::Image],
+    );
+    let validator = DefaultMessageValidator::with_config(ValidationConfig {
+        role_content_policy: policy,
+        ..ValidationConfig::default()
+    });
+
+    let screenshot = message_factory(
+        Role::Tool,
+        vec![
+            part_of(ContentPartKind::ToolResult),
+            part_of(ContentPartKind::Image),
+        ],
+    )
+    .expect("message should build");
+    assert!(validator.validate(&screenshot).is_ok());
+
+    let narrated = message_factory(Role::Tool, vec![part_of(ContentPartKind::Text)])
+        .expect("message should build");
+    assert!(validator.validate(&narrated).is_err());
+    assert_eq!(
+        policy.allowed(Role::User),
+        RoleContentPolicy::DEFAULT.allowed(Role::User)
+    );
+}
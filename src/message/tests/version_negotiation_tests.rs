@@ -0,0 +1,205 @@
+//! Unit tests for event downgrades and version negotiation.
+
+use crate::message::{
+    error::SchemaUpgradeError,
+    versioning::{
+        EventUpgrader, MessageCreatedUpgrader, SupportedVersions, UpgradeChain, UpgradeStep,
+        UpgraderRegistry, VersionedEvent, upgrader::UpgradeResult,
+    },
+};
+use rstest::rstest;
+use serde_json::json;
+
+/// Moves a `score` field to `points` and back.
+struct RenameScore;
+
+impl UpgradeStep for RenameScore {
+    fn from_version(&self) -> u32 {
+        1
+    }
+
+    fn to_version(&self) -> u32 {
+        2
+    }
+
+    fn upgrade(&self, event: VersionedEvent) -> UpgradeResult<VersionedEvent> {
+        rename(event, "score", "points")
+    }
+
+    fn downgrade(&self, event: VersionedEvent) -> UpgradeResult<VersionedEvent> {
+        rename(event, "points", "score")
+    }
+}
+
+/// Adds a field that cannot be removed without losing data.
+struct AddRank;
+
+impl UpgradeStep for AddRank {
+    fn from_version(&self) -> u32 {
+        2
+    }
+
+    fn to_version(&self) -> u32 {
+        3
+    }
+
+    fn upgrade(&self, mut event: VersionedEvent) -> UpgradeResult<VersionedEvent> {
+        let data = event
+            .data_mut()
+            .as_object_mut()
+            .ok_or_else(|| SchemaUpgradeError::malformed("expected an object"))?;
+        data.insert("rank".to_owned(), json!(0));
+        Ok(event)
+    }
+}
+
+fn rename(mut event: VersionedEvent, from: &str, to: &str) -> UpgradeResult<VersionedEvent> {
+    let data = event
+        .data_mut()
+        .as_object_mut()
+        .ok_or_else(|| SchemaUpgradeError::malformed("expected an object"))?;
+    let value = data
+        .remove(from)
+        .ok_or_else(|| SchemaUpgradeError::malformed(format!("missing {from}")))?;
+    data.insert(to.to_owned(), value);
+    Ok(event)
+}
+
+fn registry(steps: Vec<Box<dyn UpgradeStep>>) -> UpgraderRegistry {
+    let mut registry = UpgraderRegistry::empty();
+    registry.register_steps("Scored", steps).expect("register");
+    registry
+}
+
+#[rstest]
+fn chains_reverse_steps_that_support_it() {
+    let chain = UpgradeChain::new("Scored", vec![Box::new(RenameScore)]).expect("chain");
+
+    let downgraded = chain
+        .downgrade(VersionedEvent::new(2, "Scored", json!({ "points": 4 })), 1)
+        .expect("downgrade");
+
+    assert_eq!(downgraded.version(), 1);
+    assert_eq!(downgraded.data(), &json!({ "score": 4 }));
+}
+
+#[rstest]
+fn chains_refuse_to_reverse_steps_without_a_downgrade() {
+    let chain =
+        UpgradeChain::new("Scored", vec![Box::new(RenameScore), Box::new(AddRank)]).expect("chain");
+
+    let result = chain.downgrade(VersionedEvent::new(3, "Scored", json!({})), 1);
+
+    assert!(matches!(
+        result,
+        Err(SchemaUpgradeError::UnsupportedDowngrade { from: 3, to: 2 })
+    ));
+}
+
+#[rstest]
+#[case(3, true)]
+#[case(2, false)]
+fn upgraders_only_downgrade_to_their_own_version_by_default(
+    #[case] target: u32,
+    #[case] accepted: bool,
+) {
+    let upgrader = MessageCreatedUpgrader::new();
+    let event = VersionedEvent::new(3, "MessageCreated", json!({}));
+
+    assert_eq!(upgrader.downgrade(event, target).is_ok(), accepted);
+}
+
+#[rstest]
+fn negotiation_downgrades_events_newer_than_supported() {
+    let registry = registry(vec![Box::new(RenameScore)]);
+    let supported = SupportedVersions::new().with("Scored", 1);
+    let event = VersionedEvent::new(2, "Scored", json!({ "points": 4 }));
+
+    let negotiated = registry.negotiate(event, &supported).expect("negotiate");
+
+    assert_eq!(negotiated.version(), 1);
+}
+
+#[rstest]
+#[case::not_declared(SupportedVersions::new())]
+#[case::within_range(SupportedVersions::new().with("Scored", 2))]
+fn negotiation_leaves_supported_events_alone(#[case] supported: SupportedVersions) {
+    let registry = UpgraderRegistry::empty();
+    let event = VersionedEvent::new(2, "Scored", json!({ "points": 4 }));
+
+    let negotiated = registry.negotiate(event, &supported).expect("negotiate");
+
+    assert_eq!(negotiated.version(), 2);
+}
+
+#[rstest]
+#[case::irreversible(registry(vec![Box::new(RenameScore), Box::new(AddRank)]))]
+#[case::unregistered(UpgraderRegistry::empty())]
+fn negotiation_reports_events_it_cannot_downgrade(#[case] registry: UpgraderRegistry) {
+    let supported = SupportedVersions::new().with("Scored", 1);
+    let event = VersionedEvent::new(3, "Scored", json!({}));
+
+    let result = registry.negotiate(event, &supported);
+
+    assert!(matches!(
+        result,
+        Err(SchemaUpgradeError::IncompatibleVersion {
+            version: 3,
+            max_supported: 1,
+            ..
+        })
+    ));
+}
+
+#[rstest]
+fn supported_versions_follow_the_registry() {
+    let registry = registry(vec![Box::new(RenameScore), Box::new(AddRank)]);
+
+    let supported = SupportedVersions::from_registry(&registry);
+
+    assert_eq!(supported.max_supported("Scored"), Some(3));
+    assert_eq!(supported.max_supported("MessageCreated"), None);
+}
+
+#[rstest]
+fn deserialising_a_newer_event_downgrades_it() {
+    let registry = registry(vec![Box::new(RenameScore)]);
+    let supported = SupportedVersions::from_registry(&registry).with("Scored", 1);
+    let stored = VersionedEvent::new(2, "Scored", json!({ "points": 4 }));
+    let json = serde_json::to_string(&stored).expect("serialise");
+
+    let event =
+        VersionedEvent::from_json_negotiated(&json, &supported, &registry).expect("deserialise");
+
+    assert_eq!(event.version(), 1);
+    assert_eq!(event.data(), &json!({ "score": 4 }));
+}
+
+#[rstest]
+fn deserialising_an_unfamiliar_newer_shape_is_a_typed_incompatibility() {
+    let supported = SupportedVersions::new().with("Scored", 1);
+    let json = r#"{"version": 4, "event_type": "Scored", "payload": {}}"#;
+
+    let result = VersionedEvent::from_json_negotiated(json, &supported, &UpgraderRegistry::empty());
+
+    assert!(matches!(
+        result,
+        Err(SchemaUpgradeError::IncompatibleVersion {
+            version: 4,
+            max_supported: 1,
+            ..
+        })
+    ));
+}
+
+#[rstest]
+#[case::not_json("not json")]
+#[case::no_version(r#"{"event_type": "Scored"}"#)]
+#[case::supported_but_incomplete(r#"{"version": 1, "event_type": "Scored"}"#)]
+fn deserialising_malformed_events_reports_malformed_data(#[case] json: &str) {
+    let supported = SupportedVersions::new().with("Scored", 1);
+
+    let result = VersionedEvent::from_json_negotiated(json, &supported, &UpgraderRegistry::empty());
+
+    assert!(matches!(result, Err(SchemaUpgradeError::MalformedData(_))));
+}
@@ -1,9 +1,11 @@
 //! Unit tests for schema versioning.
 
 use crate::message::{
+    domain::{AttachmentPart, ContentPart, ImagePart, MessageMetadata},
     error::SchemaUpgradeError,
     versioning::{EventUpgrader, MessageCreatedUpgrader, UpgraderRegistry, VersionedEvent},
 };
+use base64::{Engine as _, engine::general_purpose::STANDARD};
 use chrono::Utc;
 use rstest::rstest;
 use serde_json::json;
@@ -58,16 +60,17 @@ fn versioned_event_data_mut() {
 #[case(0, false)]
 #[case(1, true)]
 #[case(2, true)]
-#[case(3, false)]
+#[case(3, true)]
+#[case(4, false)]
 fn upgrader_version_support(#[case] version: u32, #[case] expected: bool) {
     let upgrader = MessageCreatedUpgrader::new();
     assert_eq!(upgrader.supports_version(version), expected);
-    // Current version is always 2
-    assert_eq!(upgrader.current_version(), 2);
+    // Current version is always 3
+    assert_eq!(upgrader.current_version(), 3);
 }
 
 #[rstest]
-fn upgrade_v1_to_v2_adds_metadata() {
+fn upgrade_v1_adds_metadata() {
     let upgrader = MessageCreatedUpgrader::new();
     let event = VersionedEvent::new(
         1,
@@ -80,7 +83,7 @@ fn upgrade_v1_to_v2_adds_metadata() {
 
     let upgraded = upgrader.upgrade(event).expect("should upgrade");
 
-    assert_eq!(upgraded.version(), 2);
+    assert_eq!(upgraded.version(), 3);
     assert!(upgraded.data().get("metadata").is_some());
     // Original fields preserved
     assert_eq!(upgraded.data().get("id"), Some(&json!("msg-123")));
@@ -101,7 +104,7 @@ fn upgrade_v1_preserves_existing_metadata() {
 
     let upgraded = upgrader.upgrade(event).expect("should upgrade");
 
-    assert_eq!(upgraded.version(), 2);
+    assert_eq!(upgraded.version(), 3);
     // Existing metadata preserved
     assert_eq!(
         upgraded.data().get("metadata"),
@@ -110,10 +113,10 @@ fn upgrade_v1_preserves_existing_metadata() {
 }
 
 #[rstest]
-fn upgrade_v2_unchanged() {
+fn upgrade_v3_unchanged() {
     let upgrader = MessageCreatedUpgrader::new();
     let event = VersionedEvent::new(
-        2,
+        3,
         "MessageCreated",
         json!({
             "id": "msg-123",
@@ -121,15 +124,154 @@ fn upgrade_v2_unchanged() {
         }),
     );
 
-    let upgraded = upgrader.upgrade(event).expect("should not modify v2");
+    let upgraded = upgrader.upgrade(event).expect("should not modify v3");
 
-    assert_eq!(upgraded.version(), 2);
+    assert_eq!(upgraded.version(), 3);
     assert_eq!(
         upgraded.data().get("metadata"),
         Some(&json!({"key": "value"}))
     );
 }
 
+#[rstest]
+#[case(1)]
+#[case(2)]
+#[case(3)]
+fn upgrade_preserves_metadata_extensions(#[case] version: u32) {
+    let event = VersionedEvent::new(
+        version,
+        "MessageCreated",
+        json!({
+            "id": "msg-123",
+            "content": [],
+            "metadata": {
+                "agent_backend": "codex_cli",
+                "extensions": {"acme.trace_id": "t-1"},
+                "acme.legacy_flag": true
+            }
+        }),
+    );
+
+    let upgraded = MessageCreatedUpgrader::new()
+        .upgrade(event)
+        .expect("should upgrade");
+    let metadata: MessageMetadata = serde_json::from_value(
+        upgraded
+            .data()
+            .get("metadata")
+            .cloned()
+            .expect("metadata field"),
+    )
+    .expect("valid metadata");
+
+    assert_eq!(metadata.agent_backend.as_deref(), Some("codex_cli"));
+    assert_eq!(
+        metadata.extensions.get("acme.trace_id"),
+        Some(&json!("t-1"))
+    );
+    assert_eq!(
+        metadata.extensions.get("acme.legacy_flag"),
+        Some(&json!(true))
+    );
+    let reserialized = serde_json::to_value(&metadata).expect("serializable metadata");
+    assert_eq!(
+        reserialized.get("extensions"),
+        Some(&json!({"acme.trace_id": "t-1", "acme.legacy_flag": true}))
+    );
+}
+
+/// Encodes a v2 `MessageCreated` event the way v2 producers wrote it: the
+/// message's content parts serialized as they were stored.
+fn v2_message_created(content: &[ContentPart]) -> VersionedEvent {
+    VersionedEvent::new(
+        2,
+        "MessageCreated",
+        json!({
+            "id": "msg-123",
+            "content": serde_json::to_value(content).expect("serializable content"),
+            "metadata": {}
+        }),
+    )
+}
+
+fn upgraded_content(event: VersionedEvent) -> Vec<ContentPart> {
+    let upgraded = MessageCreatedUpgrader::new()
+        .upgrade(event)
+        .expect("should upgrade");
+    assert_eq!(upgraded.version(), 3);
+    serde_json::from_value(
+        upgraded
+            .data()
+            .get("content")
+            .cloned()
+            .expect("content field"),
+    )
+    .expect("valid content parts")
+}
+
+/// The leading bytes of images, up to and including their dimensions.
+const PNG_3X2: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x03\0\0\0\x02\x08\x06\0\0\0";
+const GIF_5X4: &[u8] = b"GIF89a\x05\0\x04\0\x80\0\0";
+const JPEG_9X7: &[u8] = b"\xff\xd8\xff\xe0\0\x10JFIF\0\x01\x01\0\0\x01\0\x01\0\0\
+\xff\xc0\0\x11\x08\0\x07\0\x09\x03\x01\x22\0\x02\x11\x01\x03\x11\x01";
+const WEBP_12X11: &[u8] = b"RIFF\x24\0\0\0WEBPVP8X\x0a\0\0\0\0\0\0\0\x0b\0\0\x0a\0\0";
+
+#[rstest]
+#[case("image/png", PNG_3X2, (3, 2))]
+#[case("image/gif", GIF_5X4, (5, 4))]
+#[case("image/jpeg", JPEG_9X7, (9, 7))]
+#[case("image/webp", WEBP_12X11, (12, 11))]
+fn upgrade_v2_turns_image_attachments_into_images(
+    #[case] mime_type: &str,
+    #[case] image: &[u8],
+    #[case] dimensions: (u32, u32),
+) {
+    let (width, height) = dimensions;
+    let data = STANDARD.encode(image);
+    let attachment = AttachmentPart::new(mime_type, data.clone())
+        .with_name("chart")
+        .with_size(u64::try_from(image.len()).expect("small image"));
+
+    let content = upgraded_content(v2_message_created(&[ContentPart::Attachment(attachment)]));
+
+    assert_eq!(
+        content,
+        vec![ContentPart::Image(
+            ImagePart::new(mime_type, data, width, height).with_name("chart")
+        )]
+    );
+}
+
+#[rstest]
+fn upgrade_v2_decides_on_the_mime_type_alone() {
+    let pdf = AttachmentPart::new("application/pdf", "JVBERi0=");
+    let mut stored = AttachmentPart::new("image/png", STANDARD.encode(PNG_3X2));
+    let blob = stored.externalise();
+    let unreadable = AttachmentPart::new("image/png", "bm90IGFuIGltYWdl");
+
+    let content = upgraded_content(v2_message_created(&[
+        ContentPart::Attachment(pdf.clone()),
+        ContentPart::Attachment(stored),
+        ContentPart::Attachment(unreadable),
+    ]));
+
+    let mut externalised = ImagePart::new("image/png", "", 0, 0);
+    externalised.blob = Some(blob.hash);
+    assert_eq!(
+        content,
+        vec![
+            ContentPart::Attachment(pdf),
+            ContentPart::Image(externalised),
+            ContentPart::Image(ImagePart::new("image/png", "bm90IGFuIGltYWdl", 0, 0)),
+        ]
+    );
+    assert!(
+        !content
+            .iter()
+            .any(|part| matches!(part, ContentPart::Image(image) if image.is_valid()))
+    );
+}
+
 #[rstest]
 fn upgrade_unsupported_version_fails() {
     let upgrader = MessageCreatedUpgrader::new();
@@ -184,7 +326,7 @@ fn registry_upgrade_dispatches_correctly() {
 
     let upgraded = registry.upgrade(event).expect("should upgrade");
 
-    assert_eq!(upgraded.version(), 2);
+    assert_eq!(upgraded.version(), 3);
 }
 
 #[rstest]
@@ -203,7 +345,7 @@ fn registry_upgrade_unknown_type_fails() {
 #[rstest]
 fn registry_current_version() {
     let registry = UpgraderRegistry::new();
-    assert_eq!(registry.current_version("MessageCreated"), Some(2));
+    assert_eq!(registry.current_version("MessageCreated"), Some(3));
     assert_eq!(registry.current_version("Unknown"), None);
 }
 
@@ -1,10 +1,14 @@
 //! Message validation implementation.
 //!
 //! This module provides the default implementation of message validation,
-//! including individual validation rules and the composite validator service.
+//! including individual validation rules, the composite validator service,
+//! the registry through which other crates add rules of their own, and the
+//! screening of message text for sensitive data.
 
 pub mod handoff;
+pub mod registry;
 pub mod rules;
+pub mod screening;
 pub mod service;
 
 #[cfg(test)]
@@ -15,4 +19,6 @@ pub use handoff::{
     validate_handoff_can_complete, validate_handoff_initiation,
     validate_session_can_initiate_handoff, validate_snapshot_for_handoff, validate_target_agent,
 };
+pub use registry::ValidationRuleRegistry;
+pub use screening::ContentScreening;
 pub use service::DefaultMessageValidator;
@@ -0,0 +1,139 @@
+//! Registry of validation rules contributed from outside the validator.
+//!
+//! [`ValidationRuleRegistry`] holds [`ValidationRule`]s in registration
+//! order. [`DefaultMessageValidator`](super::DefaultMessageValidator) runs
+//! them after its built-in rules, in the layer each rule names, and reports
+//! their failures alongside its own.
+
+use crate::message::{
+    domain::Message,
+    error::ValidationError,
+    ports::validator::{ValidationConfig, ValidationResult, ValidationRule, ValidationStage},
+};
+use std::fmt;
+use std::sync::Arc;
+
+/// Ordered collection of custom validation rules.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::{
+///     domain::{ContentPart, ConversationId, Message, Role, SequenceNumber, TextPart},
+///     error::ValidationError,
+///     ports::validator::{MessageValidator, ValidationConfig, ValidationResult, ValidationRule},
+///     validation::{DefaultMessageValidator, ValidationRuleRegistry},
+/// };
+/// use mockable::DefaultClock;
+///
+/// struct AtMostTwoParts;
+///
+/// impl ValidationRule for AtMostTwoParts {
+///     fn name(&self) -> &str {
+///         "at_most_two_parts"
+///     }
+///
+///     fn check(&self, message: &Message, _config: &ValidationConfig) -> ValidationResult<()> {
+///         if message.content().len() > 2 {
+///             return Err(ValidationError::TooManyContentParts {
+///                 max: 2,
+///                 actual: message.content().len(),
+///             });
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let rules = ValidationRuleRegistry::new().with_rule(AtMostTwoParts);
+/// let validator = DefaultMessageValidator::new().with_rules(rules);
+/// let message = Message::new(
+///     ConversationId::new(),
+///     Role::User,
+///     vec![ContentPart::Text(TextPart::new("Hello"))],
+///     SequenceNumber::new(1),
+///     &DefaultClock,
+/// )
+/// .expect("valid message");
+/// assert!(validator.validate(&message).is_ok());
+/// ```
+#[derive(Clone, Default)]
+pub struct ValidationRuleRegistry {
+    rules: Vec<Arc<dyn ValidationRule>>,
+}
+
+impl ValidationRuleRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Appends a rule, to be run after those already registered.
+    #[must_use]
+    pub fn with_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.register(Arc::new(rule));
+        self
+    }
+
+    /// Appends a shared rule, to be run after those already registered.
+    pub fn register(&mut self, rule: Arc<dyn ValidationRule>) {
+        self.rules.push(rule);
+    }
+
+    /// Appends every rule in `other`, keeping their order.
+    pub fn append(&mut self, other: Self) {
+        self.rules.extend(other.rules);
+    }
+
+    /// Returns the names of the registered rules, in the order they run.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().map(|rule| rule.name())
+    }
+
+    /// Returns the number of registered rules.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns `true` when no rules are registered.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Runs every rule for `stage` in registration order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the failures of every rule that rejected the message,
+    /// combined with [`ValidationError::multiple`].
+    pub fn check(
+        &self,
+        stage: ValidationStage,
+        message: &Message,
+        config: &ValidationConfig,
+    ) -> ValidationResult<()> {
+        let errors: Vec<ValidationError> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.stage() == stage)
+            .filter_map(|rule| rule.check(message, config).err())
+            .flat_map(|error| match error {
+                ValidationError::Multiple(inner) => inner,
+                other => vec![other],
+            })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError::multiple(errors))
+        }
+    }
+}
+
+impl fmt::Debug for ValidationRuleRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
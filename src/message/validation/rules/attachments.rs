@@ -0,0 +1,158 @@
+//! Rules for attachment types, encoding and size.
+
+use crate::message::{
+    domain::{AttachmentPart, ContentPart, Message},
+    error::ValidationError,
+    ports::validator::{AttachmentPolicy, ValidationConfig, mime_essence},
+};
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+
+/// Leading bytes of the formats whose labels are checked.
+const SIGNATURES: &[(&str, &[u8])] = &[
+    ("image/png", b"\x89PNG\r\n\x1a\n"),
+    ("image/jpeg", b"\xFF\xD8\xFF"),
+    ("image/gif", b"GIF8"),
+    ("application/pdf", b"%PDF-"),
+    ("application/gzip", b"\x1F\x8B"),
+    ("application/x-msdownload", b"MZ"),
+    ("application/x-executable", b"\x7FELF"),
+];
+
+const WEBP: &str = "image/webp";
+
+pub(super) fn validate_attachment_part(
+    attachment: &AttachmentPart,
+    index: usize,
+    policy: &AttachmentPolicy,
+) -> Result<(), ValidationError> {
+    let invalid = |reason: String| ValidationError::invalid_content_part(index, reason);
+
+    if attachment.mime_type.is_empty() {
+        return Err(invalid("attachment must have a MIME type".to_owned()));
+    }
+
+    if attachment.data.is_empty() && !attachment.is_externalised() {
+        return Err(invalid("attachment data cannot be empty".to_owned()));
+    }
+
+    if !policy.permits_mime_type(&attachment.mime_type) {
+        return Err(invalid(format!(
+            "attachment type '{}' is not permitted",
+            attachment.mime_type
+        )));
+    }
+
+    let size = if attachment.is_externalised() {
+        recorded_size(attachment)
+    } else {
+        Some(inline_size(attachment, policy).map_err(invalid)?)
+    };
+
+    match (size, policy.max_attachment_bytes) {
+        (Some(size), Some(limit)) if size > limit => Err(invalid(format!(
+            "attachment of {size} bytes exceeds the limit of {limit} bytes"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Validates the combined decoded size of a message's attachments.
+///
+/// # Errors
+///
+/// Returns `ValidationError::AttachmentsTooLarge` when the attachments
+/// together exceed the configured limit.
+pub fn validate_attachment_total_size(
+    message: &Message,
+    config: &ValidationConfig,
+) -> Result<(), ValidationError> {
+    let Some(limit) = config.attachment_policy.max_total_attachment_bytes else {
+        return Ok(());
+    };
+    let total: usize = message
+        .content()
+        .iter()
+        .filter_map(|part| match part {
+            ContentPart::Attachment(attachment) => Some(attachment),
+            _ => None,
+        })
+        .filter_map(|attachment| {
+            if attachment.is_externalised() {
+                recorded_size(attachment)
+            } else {
+                Some(
+                    STANDARD
+                        .decode(&attachment.data)
+                        .map_or(attachment.data.len(), |bytes| bytes.len()),
+                )
+            }
+        })
+        .sum();
+
+    if total > limit {
+        return Err(ValidationError::AttachmentsTooLarge {
+            actual_bytes: total,
+            limit_bytes: limit,
+        });
+    }
+    Ok(())
+}
+
+/// Checks the encoding and signature of inline data, returning its size.
+fn inline_size(attachment: &AttachmentPart, policy: &AttachmentPolicy) -> Result<usize, String> {
+    let Ok(bytes) = STANDARD.decode(&attachment.data) else {
+        if policy.verify_encoding && !is_textual(&attachment.mime_type) {
+            return Err("attachment data is not valid base64".to_owned());
+        }
+        return Ok(attachment.data.len());
+    };
+
+    if policy.sniff_content
+        && let Some(reason) = mislabelling(&attachment.mime_type, &bytes)
+    {
+        return Err(reason);
+    }
+    Ok(bytes.len())
+}
+
+fn recorded_size(attachment: &AttachmentPart) -> Option<usize> {
+    attachment
+        .size_bytes
+        .map(|size| usize::try_from(size).unwrap_or(usize::MAX))
+}
+
+fn is_textual(mime_type: &str) -> bool {
+    let essence = mime_essence(mime_type);
+    essence.starts_with("text/")
+        || matches!(essence.as_str(), "application/json" | "application/xml")
+        || essence.ends_with("+json")
+        || essence.ends_with("+xml")
+}
+
+/// Explains how `bytes` contradict `mime_type`, if they do.
+///
+/// Data labelled with a type in [`SIGNATURES`], or WebP, must start with its
+/// signature, and executable data must be labelled as such whatever its
+/// label claims.
+fn mislabelling(mime_type: &str, bytes: &[u8]) -> Option<String> {
+    let essence = mime_essence(mime_type);
+    let detected = sniff(bytes);
+    let expects_signature = essence == WEBP || SIGNATURES.iter().any(|(mime, _)| *mime == essence);
+    if expects_signature && detected != Some(essence.as_str()) {
+        return Some(format!("attachment data does not look like {essence}"));
+    }
+    detected
+        .filter(|found| AttachmentPolicy::EXECUTABLE_MIME_TYPES.contains(found))
+        .filter(|found| *found != essence)
+        .map(|found| format!("attachment labelled {essence} holds {found} data"))
+}
+
+fn sniff(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP".as_slice()) {
+        return Some(WEBP);
+    }
+    SIGNATURES
+        .iter()
+        .find(|(_, signature)| bytes.starts_with(signature))
+        .map(|(mime, _)| *mime)
+}
@@ -0,0 +1,136 @@
+//! Rules for the audit records and extension keys in message metadata.
+
+use crate::message::{
+    domain::{
+        AgentResponseAudit, ExtensionKeyError, Message, RESERVED_EXTENSION_NAMESPACES,
+        ToolCallAudit, is_corbusier_extension_key,
+    },
+    error::ValidationError,
+};
+
+/// Validates message metadata audit records and extension keys.
+///
+/// Extension keys in the reserved namespaces must be keys Corbusier writes
+/// itself. Keys without a vendor prefix are accepted, since legacy rows
+/// stored them at the top level of the metadata; the prefix is enforced
+/// when keys are written through `MessageMetadata::with_extension`.
+///
+/// # Errors
+///
+/// Returns `ValidationError::InvalidMetadata` if audit metadata is malformed
+/// or an unknown extension key lies in a reserved namespace.
+///
+/// # Examples
+///
+/// ```rust
+/// use corbusier::message::domain::{
+///     ContentPart, ConversationId, Message, MessageMetadata, Role, SequenceNumber, TextPart,
+///     ToolCallAudit, ToolCallStatus,
+/// };
+/// use corbusier::message::validation::rules::validate_metadata;
+/// use mockable::DefaultClock;
+///
+/// let clock = DefaultClock;
+/// let metadata = MessageMetadata::empty().with_tool_call_audit(
+///     ToolCallAudit::new("call-123", "search", ToolCallStatus::Succeeded),
+/// );
+/// let message = Message::builder(ConversationId::new(), Role::Assistant, SequenceNumber::new(1))
+///     .with_content(ContentPart::Text(TextPart::new("Hello")))
+///     .with_metadata(metadata)
+///     .build(&clock)
+///     .expect("valid message");
+///
+/// assert!(validate_metadata(&message).is_ok());
+/// ```
+pub fn validate_metadata(message: &Message) -> Result<(), ValidationError> {
+    let metadata = message.metadata();
+    let mut errors = Vec::new();
+
+    for (index, audit) in metadata.tool_call_audits.iter().enumerate() {
+        collect_metadata_error(&mut errors, validate_tool_call_audit(audit, index));
+    }
+
+    if let Some(audit) = metadata.agent_response_audit.as_ref() {
+        collect_metadata_error(&mut errors, validate_agent_response_audit(audit));
+    }
+
+    let mut keys: Vec<&String> = metadata.extensions.keys().collect();
+    keys.sort();
+    for key in keys {
+        collect_metadata_error(&mut errors, validate_extension_key(key));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::multiple(errors))
+    }
+}
+
+fn validate_tool_call_audit(audit: &ToolCallAudit, index: usize) -> Option<ValidationError> {
+    if audit.call_id.trim().is_empty() {
+        return Some(ValidationError::InvalidMetadata(format!(
+            "tool call audit at index {index} must include a call_id"
+        )));
+    }
+
+    if audit.tool_name.trim().is_empty() {
+        return Some(ValidationError::InvalidMetadata(format!(
+            "tool call audit at index {index} must include a tool_name"
+        )));
+    }
+
+    if let Some(error) = audit.error.as_ref()
+        && error.trim().is_empty()
+    {
+        return Some(ValidationError::InvalidMetadata(format!(
+            "tool call audit at index {index} has an empty error message"
+        )));
+    }
+
+    None
+}
+
+fn validate_agent_response_audit(audit: &AgentResponseAudit) -> Option<ValidationError> {
+    if let Some(response_id) = audit.response_id.as_ref()
+        && response_id.trim().is_empty()
+    {
+        return Some(ValidationError::InvalidMetadata(
+            "agent response audit must include a non-empty response_id".to_owned(),
+        ));
+    }
+
+    if let Some(model) = audit.model.as_ref()
+        && model.trim().is_empty()
+    {
+        return Some(ValidationError::InvalidMetadata(
+            "agent response audit must include a non-empty model".to_owned(),
+        ));
+    }
+
+    if let Some(error) = audit.error.as_ref()
+        && error.trim().is_empty()
+    {
+        return Some(ValidationError::InvalidMetadata(
+            "agent response audit must include a non-empty error".to_owned(),
+        ));
+    }
+
+    None
+}
+
+fn validate_extension_key(key: &str) -> Option<ValidationError> {
+    let (vendor, _) = key.split_once('.')?;
+    if !RESERVED_EXTENSION_NAMESPACES.contains(&vendor) || is_corbusier_extension_key(key) {
+        return None;
+    }
+    Some(ValidationError::InvalidMetadata(
+        ExtensionKeyError::Reserved(key.to_owned()).to_string(),
+    ))
+}
+
+fn collect_metadata_error(errors: &mut Vec<ValidationError>, maybe_error: Option<ValidationError>) {
+    if let Some(error) = maybe_error {
+        errors.push(error);
+    }
+}
@@ -0,0 +1,237 @@
+//! Individual validation rule implementations.
+//!
+//! Each rule is implemented as a pure function that validates a specific
+//! aspect of a message. Rules return `Ok(())` on success or a specific
+//! `ValidationError` on failure.
+
+mod attachments;
+mod metadata;
+mod parts;
+#[cfg(test)]
+mod tests;
+
+pub use attachments::validate_attachment_total_size;
+pub use metadata::validate_metadata;
+
+use crate::message::{
+    domain::{CitationSource, ContentPart, ContentTypeRegistry, CustomContentError, Message},
+    error::ValidationError,
+    ports::validator::ValidationConfig,
+};
+use parts::{dangling_span_reason, validate_content_part};
+
+/// Validates that the message has a non-nil ID.
+///
+/// # Errors
+///
+/// Returns `ValidationError::MissingMessageId` if the ID is nil.
+pub fn validate_message_id(message: &Message) -> Result<(), ValidationError> {
+    if message.id().as_ref().is_nil() {
+        return Err(ValidationError::MissingMessageId);
+    }
+    Ok(())
+}
+
+/// Validates that the message has at least one content part.
+///
+/// # Errors
+///
+/// Returns `ValidationError::EmptyContent` if the content array is empty.
+pub fn validate_content_not_empty(message: &Message) -> Result<(), ValidationError> {
+    if message.content().is_empty() {
+        return Err(ValidationError::EmptyContent);
+    }
+    Ok(())
+}
+
+/// Validates that the message does not exceed size limits.
+///
+/// Attachments above [`ValidationConfig::max_inline_attachment_bytes`] are
+/// measured as the blob references they will be stored as.
+///
+/// # Errors
+///
+/// Returns `ValidationError::MessageTooLarge` if the serialized message
+/// exceeds the configured limit.
+pub fn validate_message_size(
+    message: &Message,
+    config: &ValidationConfig,
+) -> Result<(), ValidationError> {
+    let serialized = config
+        .max_inline_attachment_bytes
+        .map_or_else(
+            || serde_json::to_vec(message),
+            |limit| serde_json::to_vec(&message.clone().externalise_attachments(limit).0),
+        )
+        .map_err(|e| {
+            ValidationError::InvalidMetadata(format!("failed to serialize message: {e}"))
+        })?;
+
+    if serialized.len() > config.max_message_size_bytes {
+        return Err(ValidationError::MessageTooLarge {
+            actual_bytes: serialized.len(),
+            limit_bytes: config.max_message_size_bytes,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that the message does not have too many content parts.
+///
+/// # Errors
+///
+/// Returns `ValidationError::TooManyContentParts` if the number of parts exceeds
+/// the configured limit.
+pub fn validate_content_parts_count(
+    message: &Message,
+    config: &ValidationConfig,
+) -> Result<(), ValidationError> {
+    let count = message.content().len();
+    if count > config.max_content_parts {
+        return Err(ValidationError::TooManyContentParts {
+            max: config.max_content_parts,
+            actual: count,
+        });
+    }
+    Ok(())
+}
+
+/// Validates all individual content parts.
+///
+/// # Errors
+///
+/// Returns `ValidationError::Multiple` if any content parts are invalid.
+pub fn validate_content_parts(
+    message: &Message,
+    config: &ValidationConfig,
+) -> Result<(), ValidationError> {
+    let mut errors = Vec::new();
+
+    for (index, part) in message.content().iter().enumerate() {
+        if let Err(e) = validate_content_part(part, index, config) {
+            errors.push(e);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::multiple(errors))
+    }
+}
+
+/// Validates custom content parts against their registered types.
+///
+/// Without a registry no custom kinds are known, so every custom part is
+/// rejected.
+///
+/// # Errors
+///
+/// Returns `ValidationError::Multiple` if any custom part has an unregistered
+/// kind or fails its type's validation.
+pub fn validate_custom_parts(
+    message: &Message,
+    content_types: Option<&ContentTypeRegistry>,
+) -> Result<(), ValidationError> {
+    let errors: Vec<ValidationError> = message
+        .content()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, part)| match part {
+            ContentPart::Custom(custom) if custom.is_valid() => Some((index, custom)),
+            _ => None,
+        })
+        .filter_map(|(index, custom)| {
+            let result = content_types.map_or_else(
+                || Err(CustomContentError::UnknownKind(custom.kind.clone())),
+                |registry| registry.validate(custom),
+            );
+            result
+                .err()
+                .map(|err| ValidationError::invalid_content_part(index, err.to_string()))
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::multiple(errors))
+    }
+}
+
+/// Validates that every content part is of a kind the message's role may
+/// carry under the configured [`RoleContentPolicy`].
+///
+/// [`RoleContentPolicy`]: crate::message::ports::validator::RoleContentPolicy
+///
+/// # Errors
+///
+/// Returns `ValidationError::Multiple` with one error per disallowed part.
+pub fn validate_role_content(
+    message: &Message,
+    config: &ValidationConfig,
+) -> Result<(), ValidationError> {
+    let role = message.role();
+    let errors: Vec<ValidationError> = message
+        .content()
+        .iter()
+        .enumerate()
+        .filter(|(_, part)| !config.role_content_policy.allows(role, part.kind()))
+        .map(|(index, part)| {
+            ValidationError::invalid_content_part(
+                index,
+                format!(
+                    "{} parts are not allowed in {role} messages",
+                    part.kind().as_str()
+                ),
+            )
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::multiple(errors))
+    }
+}
+
+/// Validates that citations refer to text in the same message.
+///
+/// Each span must name a text part of the message and end within its
+/// characters, and a citation must not cite the message that holds it.
+///
+/// # Errors
+///
+/// Returns `ValidationError::Multiple` with one error per dangling
+/// reference.
+pub fn validate_citation_references(message: &Message) -> Result<(), ValidationError> {
+    let errors: Vec<ValidationError> = message
+        .content()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, part)| match part {
+            ContentPart::Citation(citation) => Some((index, citation)),
+            _ => None,
+        })
+        .flat_map(|(index, citation)| {
+            let self_citation = (citation.source == CitationSource::message(message.id()))
+                .then(|| "citation cannot cite its own message".to_owned());
+            let dangling_spans = citation
+                .spans
+                .iter()
+                .filter_map(|span| dangling_span_reason(message, span));
+            self_citation
+                .into_iter()
+                .chain(dangling_spans)
+                .map(move |reason| ValidationError::invalid_content_part(index, reason))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::multiple(errors))
+    }
+}
@@ -0,0 +1,229 @@
+//! Structural rules for individual content parts.
+
+use super::attachments::validate_attachment_part;
+use crate::message::{
+    domain::{
+        CitationPart, CitationSpan, ContentPart, CustomPart, ImagePart, ImageThumbnail, Message,
+        ReasoningPart, TextPart, ToolCallPart, ToolResultPart,
+    },
+    error::ValidationError,
+    ports::validator::ValidationConfig,
+};
+
+pub(super) fn validate_content_part(
+    part: &ContentPart,
+    index: usize,
+    config: &ValidationConfig,
+) -> Result<(), ValidationError> {
+    match part {
+        ContentPart::Text(text) => validate_text_part(text, index, config),
+        ContentPart::Reasoning(reasoning) => validate_reasoning_part(reasoning, index),
+        ContentPart::ToolCall(tool_call) => validate_tool_call_part(tool_call, index),
+        ContentPart::ToolResult(tool_result) => validate_tool_result_part(tool_result, index),
+        ContentPart::Attachment(attachment) => {
+            validate_attachment_part(attachment, index, &config.attachment_policy)
+        }
+        ContentPart::Image(image) => validate_image_part(image, index, config),
+        ContentPart::Citation(citation) => validate_citation_part(citation, index),
+        ContentPart::Custom(custom) => validate_custom_part_structure(custom, index),
+        ContentPart::Redacted(_) => Err(ValidationError::invalid_content_part(
+            index,
+            "redacted placeholders are written by redaction, not supplied",
+        )),
+    }
+}
+
+fn validate_text_part(
+    text: &TextPart,
+    index: usize,
+    config: &ValidationConfig,
+) -> Result<(), ValidationError> {
+    if !config.allow_empty_text && text.is_empty() {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "text content cannot be empty",
+        ));
+    }
+
+    let char_count = text.text.chars().count();
+    if char_count > config.max_text_length {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            format!(
+                "text content exceeds maximum length of {} characters",
+                config.max_text_length
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_reasoning_part(reasoning: &ReasoningPart, index: usize) -> Result<(), ValidationError> {
+    if reasoning.is_empty() {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "reasoning content cannot be empty",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_citation_part(citation: &CitationPart, index: usize) -> Result<(), ValidationError> {
+    if !citation.source.is_valid() {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "citation source must be a URI with a scheme or a non-nil message ID",
+        ));
+    }
+
+    if citation.spans.is_empty() {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "citation must cite at least one span",
+        ));
+    }
+
+    if let Some(span) = citation.spans.iter().find(|span| span.start >= span.end) {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            format!(
+                "citation span {}..{} of part {} is empty",
+                span.start, span.end, span.part_index
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Explains why `span` does not point at text in `message`, if it does not.
+pub(super) fn dangling_span_reason(message: &Message, span: &CitationSpan) -> Option<String> {
+    let Some(ContentPart::Text(text)) = message.content().get(span.part_index) else {
+        return Some(format!(
+            "citation refers to part {}, which is not a text part",
+            span.part_index
+        ));
+    };
+    let length = text.text.chars().count();
+    (span.end > length).then(|| {
+        format!(
+            "citation span {}..{} runs past the {length} characters of part {}",
+            span.start, span.end, span.part_index
+        )
+    })
+}
+
+fn validate_tool_call_part(tool_call: &ToolCallPart, index: usize) -> Result<(), ValidationError> {
+    if tool_call.call_id.is_empty() {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "tool call must have a call_id",
+        ));
+    }
+
+    if tool_call.name.is_empty() {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "tool call must have a name",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_tool_result_part(
+    tool_result: &ToolResultPart,
+    index: usize,
+) -> Result<(), ValidationError> {
+    if tool_result.call_id.is_empty() {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "tool result must have a call_id",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_image_part(
+    image: &ImagePart,
+    index: usize,
+    config: &ValidationConfig,
+) -> Result<(), ValidationError> {
+    if !config
+        .supported_image_formats
+        .contains(&image.mime_type.as_str())
+    {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            format!("unsupported image format '{}'", image.mime_type),
+        ));
+    }
+
+    if image.data.is_empty() && !image.is_externalised() {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "image data cannot be empty",
+        ));
+    }
+
+    if image.width == 0 || image.height == 0 {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "image dimensions must be non-zero",
+        ));
+    }
+
+    if image
+        .alt_text
+        .as_ref()
+        .is_some_and(|alt_text| alt_text.trim().is_empty())
+    {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "image alt text cannot be blank",
+        ));
+    }
+
+    image.thumbnail.as_ref().map_or(Ok(()), |thumbnail| {
+        validate_image_thumbnail(thumbnail, image, index)
+    })
+}
+
+fn validate_image_thumbnail(
+    thumbnail: &ImageThumbnail,
+    image: &ImagePart,
+    index: usize,
+) -> Result<(), ValidationError> {
+    if thumbnail.width == 0 || thumbnail.height == 0 {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "thumbnail dimensions must be non-zero",
+        ));
+    }
+
+    if thumbnail.width > image.width || thumbnail.height > image.height {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "thumbnail cannot be larger than the image",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_custom_part_structure(
+    custom: &CustomPart,
+    index: usize,
+) -> Result<(), ValidationError> {
+    if !custom.is_valid() {
+        return Err(ValidationError::invalid_content_part(
+            index,
+            "custom content must have a kind",
+        ));
+    }
+
+    Ok(())
+}
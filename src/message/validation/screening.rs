@@ -0,0 +1,165 @@
+//! Screening of message text for sensitive data.
+//!
+//! [`ContentScreening`] runs a [`ContentScanner`] over a message and applies
+//! the [`SensitiveDataAction`] configured for each kind of data it finds.
+//! Call [`ContentScreening::screen`] before storing a message to reject,
+//! mask, or annotate it. Registered with
+//! [`DefaultMessageValidator::with_rule`](super::DefaultMessageValidator::with_rule),
+//! it also makes the validator reject the kinds whose action is
+//! [`SensitiveDataAction::Reject`].
+
+use crate::message::{
+    domain::{Message, SensitiveDataAction, SensitiveDataKind, SensitiveSpan},
+    error::ValidationError,
+    ports::{
+        ContentScanner,
+        validator::{ValidationConfig, ValidationResult, ValidationRule},
+    },
+};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Applies per-kind actions to the sensitive data a scanner finds.
+///
+/// Every kind is rejected unless [`ContentScreening::with_action`] says
+/// otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::{
+///     adapters::content_scanning::RegexContentScanner,
+///     domain::{
+///         ContentPart, ConversationId, Message, Role, SensitiveDataAction, SensitiveDataKind,
+///         SequenceNumber, TextPart,
+///     },
+///     validation::screening::ContentScreening,
+/// };
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// let scanner = RegexContentScanner::standard().expect("built-in patterns compile");
+/// let screening = ContentScreening::new(Arc::new(scanner))
+///     .with_action(SensitiveDataKind::EmailAddress, SensitiveDataAction::Redact);
+/// let message = Message::new(
+///     ConversationId::new(),
+///     Role::User,
+///     vec![ContentPart::Text(TextPart::new("Write to ada@example.com"))],
+///     SequenceNumber::new(1),
+///     &DefaultClock,
+/// )
+/// .expect("valid message");
+///
+/// let screened = screening.screen(message).expect("emails are redacted, not rejected");
+/// assert_eq!(
+///     screened.content(),
+///     &[ContentPart::Text(TextPart::new("Write to [email address]"))]
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ContentScreening {
+    scanner: Arc<dyn ContentScanner>,
+    actions: BTreeMap<SensitiveDataKind, SensitiveDataAction>,
+}
+
+impl ContentScreening {
+    /// Creates a screening that rejects every kind of data `scanner` finds.
+    #[must_use]
+    pub fn new(scanner: Arc<dyn ContentScanner>) -> Self {
+        Self {
+            scanner,
+            actions: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the action taken on data of `kind`.
+    #[must_use]
+    pub fn with_action(mut self, kind: SensitiveDataKind, action: SensitiveDataAction) -> Self {
+        self.actions.insert(kind, action);
+        self
+    }
+
+    /// Returns the action taken on data of `kind`.
+    #[must_use]
+    pub fn action_for(&self, kind: SensitiveDataKind) -> SensitiveDataAction {
+        self.actions.get(&kind).copied().unwrap_or_default()
+    }
+
+    /// Returns the spans of rejected kinds, reasons not to store `message`.
+    #[must_use]
+    pub fn rejected_spans(&self, message: &Message) -> Vec<SensitiveSpan> {
+        self.spans_to(SensitiveDataAction::Reject, message)
+    }
+
+    /// Applies the configured actions to `message`.
+    ///
+    /// Redacted data is replaced by the placeholder of its kind. Annotated
+    /// data is recorded under
+    /// [`SENSITIVE_DATA_EXTENSION_KEY`](crate::message::domain::SENSITIVE_DATA_EXTENSION_KEY)
+    /// with its position in the returned, already masked, text.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::SensitiveData`] listing every span of a
+    /// rejected kind, or [`ValidationError::InvalidMetadata`] if the
+    /// annotations cannot be recorded.
+    pub fn screen(&self, message: Message) -> ValidationResult<Message> {
+        let rejected = self.rejected_spans(&message);
+        if !rejected.is_empty() {
+            return Err(ValidationError::SensitiveData(rejected));
+        }
+
+        let redacted = self.spans_to(SensitiveDataAction::Redact, &message);
+        let masked = if redacted.is_empty() {
+            message
+        } else {
+            message.with_sensitive_data_masked(&redacted)
+        };
+
+        // Scanning the masked text gives annotations the positions they
+        // hold in the message as stored.
+        let annotated = self.spans_to(SensitiveDataAction::Annotate, &masked);
+        if annotated.is_empty() {
+            return Ok(masked);
+        }
+        masked
+            .with_sensitive_data_recorded(&annotated)
+            .map_err(|err| {
+                ValidationError::InvalidMetadata(format!(
+                    "failed to record sensitive data spans: {err}"
+                ))
+            })
+    }
+
+    fn spans_to(&self, action: SensitiveDataAction, message: &Message) -> Vec<SensitiveSpan> {
+        self.scanner
+            .scan_message(message)
+            .into_iter()
+            .filter(|span| self.action_for(span.kind) == action)
+            .collect()
+    }
+}
+
+impl fmt::Debug for ContentScreening {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContentScreening")
+            .field("actions", &self.actions)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ValidationRule for ContentScreening {
+    fn name(&self) -> &str {
+        "content_screening"
+    }
+
+    fn check(&self, message: &Message, _config: &ValidationConfig) -> ValidationResult<()> {
+        let rejected = self.rejected_spans(message);
+        if rejected.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError::SensitiveData(rejected))
+        }
+    }
+}
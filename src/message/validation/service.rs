@@ -4,17 +4,26 @@
 //! combining individual validation rules into a comprehensive validator.
 
 use crate::message::{
-    domain::Message,
+    domain::{ContentTypeRegistry, Message},
     error::ValidationError,
-    ports::validator::{MessageValidator, ValidationConfig, ValidationResult},
-    validation::rules,
+    ports::validator::{
+        MessageValidator, ValidationConfig, ValidationResult, ValidationRule, ValidationStage,
+    },
+    validation::{ValidationRuleRegistry, rules},
 };
+use std::sync::Arc;
 
 /// Default implementation of the message validator.
 ///
 /// Applies all validation rules in order, collecting errors to provide
 /// comprehensive feedback rather than failing on the first error.
 ///
+/// Custom content parts are checked against the registry attached with
+/// [`DefaultMessageValidator::with_content_types`]; without one they are
+/// rejected. Rules added with [`DefaultMessageValidator::with_rule`] or
+/// [`DefaultMessageValidator::with_rules`] run after the built-in rules of
+/// their stage, and their failures are reported with the rest.
+///
 /// # Examples
 ///
 /// ```
@@ -40,21 +49,53 @@ use crate::message::{
 #[derive(Debug, Clone)]
 pub struct DefaultMessageValidator {
     config: ValidationConfig,
+    content_types: Option<Arc<ContentTypeRegistry>>,
+    custom_rules: ValidationRuleRegistry,
 }
 
 impl DefaultMessageValidator {
     /// Creates a new validator with default configuration.
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            config: ValidationConfig::default(),
-        }
+        Self::with_config(ValidationConfig::default())
     }
 
     /// Creates a new validator with custom configuration.
     #[must_use]
     pub const fn with_config(config: ValidationConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            content_types: None,
+            custom_rules: ValidationRuleRegistry::new(),
+        }
+    }
+
+    /// Accepts custom content parts of the kinds in `content_types`.
+    #[must_use]
+    pub fn with_content_types(mut self, content_types: Arc<ContentTypeRegistry>) -> Self {
+        self.content_types = Some(content_types);
+        self
+    }
+
+    /// Adds a custom rule, run after the rules already registered.
+    #[must_use]
+    pub fn with_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.custom_rules.register(Arc::new(rule));
+        self
+    }
+
+    /// Adds every rule in `rules`, run in order after the rules already
+    /// registered.
+    #[must_use]
+    pub fn with_rules(mut self, rules: ValidationRuleRegistry) -> Self {
+        self.custom_rules.append(rules);
+        self
+    }
+
+    /// Returns the custom rules, in the order they run.
+    #[must_use]
+    pub const fn custom_rules(&self) -> &ValidationRuleRegistry {
+        &self.custom_rules
     }
 
     /// Returns the current validation configuration.
@@ -112,6 +153,13 @@ impl MessageValidator for DefaultMessageValidator {
             collect_errors(&mut errors, e);
         }
 
+        if let Err(e) = self
+            .custom_rules
+            .check(ValidationStage::Structure, message, &self.config)
+        {
+            collect_errors(&mut errors, e);
+        }
+
         if errors.is_empty() {
             Ok(())
         } else {
@@ -120,7 +168,40 @@ impl MessageValidator for DefaultMessageValidator {
     }
 
     fn validate_content(&self, message: &Message) -> ValidationResult<()> {
-        rules::validate_content_parts(message, &self.config)
+        let mut errors = Vec::new();
+
+        if let Err(e) = rules::validate_content_parts(message, &self.config) {
+            collect_errors(&mut errors, e);
+        }
+
+        if let Err(e) = rules::validate_attachment_total_size(message, &self.config) {
+            errors.push(e);
+        }
+
+        if let Err(e) = rules::validate_custom_parts(message, self.content_types.as_deref()) {
+            collect_errors(&mut errors, e);
+        }
+
+        if let Err(e) = rules::validate_role_content(message, &self.config) {
+            collect_errors(&mut errors, e);
+        }
+
+        if let Err(e) = rules::validate_citation_references(message) {
+            collect_errors(&mut errors, e);
+        }
+
+        if let Err(e) = self
+            .custom_rules
+            .check(ValidationStage::Content, message, &self.config)
+        {
+            collect_errors(&mut errors, e);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError::multiple(errors))
+        }
     }
 }
 
@@ -0,0 +1,221 @@
+//! Upgrade chains composed from single-version migration steps.
+//!
+//! Each [`UpgradeStep`] migrates one event type between two versions. An
+//! [`UpgradeChain`] links the steps of one event type, checking when it is
+//! built that every version leads to the same current version, and applies
+//! as many steps as an event needs.
+
+use super::VersionedEvent;
+use super::upgrader::{EventUpgrader, UpgradeResult};
+use crate::message::error::SchemaUpgradeError;
+use std::collections::{HashMap, HashSet};
+
+/// One migration of an event type from one schema version to another.
+///
+/// Steps only transform the payload; the chain sets the event's version to
+/// [`Self::to_version`] once the step succeeds.
+pub trait UpgradeStep: Send + Sync {
+    /// Returns the version this step migrates from.
+    fn from_version(&self) -> u32;
+
+    /// Returns the version this step produces.
+    fn to_version(&self) -> u32;
+
+    /// Migrates `event` from [`Self::from_version`] to [`Self::to_version`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaUpgradeError` when the event data cannot be migrated.
+    fn upgrade(&self, event: VersionedEvent) -> UpgradeResult<VersionedEvent>;
+
+    /// Migrates `event` back from [`Self::to_version`] to
+    /// [`Self::from_version`].
+    ///
+    /// Steps that cannot be reversed keep the default, which refuses.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaUpgradeError::UnsupportedDowngrade` by default, or
+    /// another `SchemaUpgradeError` when the event data cannot be migrated.
+    fn downgrade(&self, event: VersionedEvent) -> UpgradeResult<VersionedEvent> {
+        Err(SchemaUpgradeError::UnsupportedDowngrade {
+            from: event.version(),
+            to: self.from_version(),
+        })
+    }
+}
+
+/// The linked upgrade steps of one event type.
+///
+/// Building a chain rejects two steps from the same version, steps that
+/// lead back to a version already passed, and steps that end at different
+/// versions, which would leave a gap.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::versioning::{
+///     EventUpgrader, UpgradeChain, UpgradeStep, VersionedEvent, upgrader::UpgradeResult,
+/// };
+/// use serde_json::json;
+///
+/// struct Rename(u32);
+///
+/// impl UpgradeStep for Rename {
+///     fn from_version(&self) -> u32 { self.0 }
+///     fn to_version(&self) -> u32 { self.0 + 1 }
+///     fn upgrade(&self, event: VersionedEvent) -> UpgradeResult<VersionedEvent> { Ok(event) }
+/// }
+///
+/// let steps: Vec<Box<dyn UpgradeStep>> = vec![Box::new(Rename(2)), Box::new(Rename(1))];
+/// let chain = UpgradeChain::new("Scored", steps)?;
+/// let upgraded = chain.upgrade(VersionedEvent::new(1, "Scored", json!({})))?;
+/// assert_eq!(upgraded.version(), 3);
+/// # Ok::<(), corbusier::message::error::SchemaUpgradeError>(())
+/// ```
+pub struct UpgradeChain {
+    event_type: String,
+    steps: HashMap<u32, Box<dyn UpgradeStep>>,
+    current_version: u32,
+}
+
+impl UpgradeChain {
+    /// Links `steps` into the chain for `event_type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaUpgradeError::EmptyUpgradeChain` when `steps` is
+    /// empty, `ConflictingUpgradeSteps` when two steps start at the same
+    /// version, `UpgradeCycle` when following the steps revisits a
+    /// version, and `UpgradeGap` when the steps end at different versions.
+    pub fn new(
+        event_type: impl Into<String>,
+        steps: Vec<Box<dyn UpgradeStep>>,
+    ) -> UpgradeResult<Self> {
+        let name = event_type.into();
+        let mut by_version = HashMap::new();
+        for step in steps {
+            let from = step.from_version();
+            if by_version.insert(from, step).is_some() {
+                return Err(SchemaUpgradeError::ConflictingUpgradeSteps {
+                    event_type: name,
+                    version: from,
+                });
+            }
+        }
+        let current_version = final_version(&name, &by_version)?;
+        Ok(Self {
+            event_type: name,
+            steps: by_version,
+            current_version,
+        })
+    }
+
+    /// Returns the event type the chain upgrades.
+    #[must_use]
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    /// Returns the step producing `version` that starts closest to it
+    /// without going below `floor`.
+    fn step_into(&self, version: u32, floor: u32) -> Option<&dyn UpgradeStep> {
+        self.steps
+            .values()
+            .filter(|step| step.to_version() == version && step.from_version() >= floor)
+            .max_by_key(|step| step.from_version())
+            .map(Box::as_ref)
+    }
+}
+
+impl EventUpgrader for UpgradeChain {
+    fn upgrade(&self, event: VersionedEvent) -> UpgradeResult<VersionedEvent> {
+        let mut upgraded = event;
+        while let Some(step) = self.steps.get(&upgraded.version()) {
+            let to = step.to_version();
+            upgraded = step.upgrade(upgraded)?;
+            upgraded.set_version(to);
+        }
+        if upgraded.version() == self.current_version {
+            Ok(upgraded)
+        } else {
+            Err(SchemaUpgradeError::UnsupportedVersion(upgraded.version()))
+        }
+    }
+
+    fn current_version(&self) -> u32 {
+        self.current_version
+    }
+
+    fn supports_version(&self, version: u32) -> bool {
+        version == self.current_version || self.steps.contains_key(&version)
+    }
+
+    /// Reverses steps one at a time, preferring the step that goes back
+    /// least far, until the event reaches `target`.
+    fn downgrade(&self, event: VersionedEvent, target: u32) -> UpgradeResult<VersionedEvent> {
+        let unsupported = SchemaUpgradeError::UnsupportedDowngrade {
+            from: event.version(),
+            to: target,
+        };
+        let mut downgraded = event;
+        while downgraded.version() > target {
+            let Some(step) = self.step_into(downgraded.version(), target) else {
+                return Err(unsupported);
+            };
+            let from = step.from_version();
+            downgraded = step.downgrade(downgraded)?;
+            downgraded.set_version(from);
+        }
+        if downgraded.version() == target {
+            Ok(downgraded)
+        } else {
+            Err(unsupported)
+        }
+    }
+}
+
+/// Follows the steps from every starting version and returns the version
+/// they all end at.
+fn final_version(
+    event_type: &str,
+    steps: &HashMap<u32, Box<dyn UpgradeStep>>,
+) -> UpgradeResult<u32> {
+    let mut starts: Vec<u32> = steps.keys().copied().collect();
+    starts.sort_unstable();
+    let mut ends = Vec::with_capacity(starts.len());
+    for start in starts {
+        ends.push(walk(event_type, steps, start)?);
+    }
+    let (Some(&lowest), Some(&highest)) = (ends.iter().min(), ends.iter().max()) else {
+        return Err(SchemaUpgradeError::EmptyUpgradeChain(event_type.to_owned()));
+    };
+    if lowest == highest {
+        Ok(highest)
+    } else {
+        Err(SchemaUpgradeError::UpgradeGap {
+            event_type: event_type.to_owned(),
+            version: lowest,
+        })
+    }
+}
+
+/// Follows the steps from `start` and returns the version they end at.
+fn walk(
+    event_type: &str,
+    steps: &HashMap<u32, Box<dyn UpgradeStep>>,
+    start: u32,
+) -> UpgradeResult<u32> {
+    let mut seen = HashSet::from([start]);
+    let mut version = start;
+    while let Some(step) = steps.get(&version) {
+        version = step.to_version();
+        if !seen.insert(version) {
+            return Err(SchemaUpgradeError::UpgradeCycle {
+                event_type: event_type.to_owned(),
+                version,
+            });
+        }
+    }
+    Ok(version)
+}
@@ -0,0 +1,278 @@
+//! Wire formats for versioned events.
+//!
+//! JSON is always available. CBOR and MessagePack encode the same envelope
+//! more compactly and are enabled with the `cbor` and `msgpack` features.
+//! Every format writes the envelope as a map, so the first byte of an
+//! encoded event identifies its format and a store holding a mix of formats
+//! can be read back without knowing which format each event used.
+
+use super::VersionedEvent;
+use crate::message::error::EventCodecError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Result type for codec operations.
+pub type CodecResult<T> = Result<T, EventCodecError>;
+
+/// The wire format an event is encoded in.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum EventFormat {
+    /// JSON text.
+    #[default]
+    Json,
+    /// CBOR, as defined in RFC 8949.
+    Cbor,
+    /// MessagePack.
+    MessagePack,
+}
+
+impl EventFormat {
+    /// Identifies the format of an encoded event from its first byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use corbusier::message::versioning::EventFormat;
+    ///
+    /// assert_eq!(EventFormat::detect(b" {\"version\": 1}"), Some(EventFormat::Json));
+    /// assert_eq!(EventFormat::detect(&[0xa4]), Some(EventFormat::Cbor));
+    /// assert_eq!(EventFormat::detect(&[0x84]), Some(EventFormat::MessagePack));
+    /// assert_eq!(EventFormat::detect(b""), None);
+    /// ```
+    #[must_use]
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        let first = bytes
+            .iter()
+            .copied()
+            .find(|byte| !byte.is_ascii_whitespace())?;
+        match first {
+            b'{' => Some(Self::Json),
+            0xa0..=0xbf => Some(Self::Cbor),
+            0x80..=0x8f | 0xde | 0xdf => Some(Self::MessagePack),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` for JSON, the format recorded by default.
+    #[expect(
+        clippy::trivially_copy_pass_by_ref,
+        reason = "serde's skip_serializing_if passes the field by reference"
+    )]
+    pub(crate) const fn is_json(&self) -> bool {
+        matches!(self, Self::Json)
+    }
+}
+
+impl fmt::Display for EventFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Json => "JSON",
+            Self::Cbor => "CBOR",
+            Self::MessagePack => "MessagePack",
+        })
+    }
+}
+
+/// Encodes and decodes versioned events in one wire format.
+pub trait EventCodec: Send + Sync {
+    /// Returns the format this codec handles.
+    fn format(&self) -> EventFormat;
+
+    /// Encodes `event`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventCodecError::Encode` when the event cannot be encoded.
+    fn encode(&self, event: &VersionedEvent) -> CodecResult<Vec<u8>>;
+
+    /// Decodes an event from `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventCodecError::Decode` when the bytes are not an event in
+    /// this format.
+    fn decode(&self, bytes: &[u8]) -> CodecResult<VersionedEvent>;
+}
+
+/// Codec for JSON, the format events have always been stored in.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl EventCodec for JsonCodec {
+    fn format(&self) -> EventFormat {
+        EventFormat::Json
+    }
+
+    fn encode(&self, event: &VersionedEvent) -> CodecResult<Vec<u8>> {
+        serde_json::to_vec(event).map_err(|err| EventCodecError::encode(EventFormat::Json, err))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> CodecResult<VersionedEvent> {
+        serde_json::from_slice(bytes).map_err(|err| EventCodecError::decode(EventFormat::Json, err))
+    }
+}
+
+/// Codec for CBOR.
+#[cfg(feature = "cbor")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CborCodec;
+
+#[cfg(feature = "cbor")]
+impl EventCodec for CborCodec {
+    fn format(&self) -> EventFormat {
+        EventFormat::Cbor
+    }
+
+    fn encode(&self, event: &VersionedEvent) -> CodecResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(event, &mut bytes)
+            .map_err(|err| EventCodecError::encode(EventFormat::Cbor, err))?;
+        Ok(bytes)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> CodecResult<VersionedEvent> {
+        ciborium::from_reader(bytes).map_err(|err| EventCodecError::decode(EventFormat::Cbor, err))
+    }
+}
+
+/// Codec for MessagePack.
+///
+/// Events are written with named fields so that, as in the other formats,
+/// the envelope is a map.
+#[cfg(feature = "msgpack")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MessagePackCodec;
+
+#[cfg(feature = "msgpack")]
+impl EventCodec for MessagePackCodec {
+    fn format(&self) -> EventFormat {
+        EventFormat::MessagePack
+    }
+
+    fn encode(&self, event: &VersionedEvent) -> CodecResult<Vec<u8>> {
+        rmp_serde::to_vec_named(event)
+            .map_err(|err| EventCodecError::encode(EventFormat::MessagePack, err))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> CodecResult<VersionedEvent> {
+        rmp_serde::from_slice(bytes)
+            .map_err(|err| EventCodecError::decode(EventFormat::MessagePack, err))
+    }
+}
+
+/// Codecs keyed by format.
+///
+/// Events are encoded in the format their metadata names, and decoded in
+/// whichever format their bytes are detected as.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::versioning::{EventCodecs, EventFormat, VersionedEvent};
+/// use serde_json::json;
+///
+/// let codecs = EventCodecs::new();
+/// let event = VersionedEvent::new(1, "Scored", json!({ "points": 4 }));
+/// let bytes = codecs.encode(&event)?;
+/// let decoded = codecs.decode(&bytes)?;
+/// assert_eq!(decoded.metadata().format, EventFormat::Json);
+/// assert_eq!(decoded.data(), event.data());
+/// # Ok::<(), corbusier::message::error::EventCodecError>(())
+/// ```
+pub struct EventCodecs {
+    codecs: HashMap<EventFormat, Box<dyn EventCodec>>,
+}
+
+impl EventCodecs {
+    /// Creates a set holding the codec of every format enabled in this
+    /// build.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_codecs([
+            Box::new(JsonCodec) as Box<dyn EventCodec>,
+            #[cfg(feature = "cbor")]
+            Box::new(CborCodec),
+            #[cfg(feature = "msgpack")]
+            Box::new(MessagePackCodec),
+        ])
+    }
+
+    /// Creates a set holding only the JSON codec.
+    #[must_use]
+    pub fn json_only() -> Self {
+        Self::from_codecs([Box::new(JsonCodec) as Box<dyn EventCodec>])
+    }
+
+    fn from_codecs(codecs: impl IntoIterator<Item = Box<dyn EventCodec>>) -> Self {
+        Self {
+            codecs: codecs
+                .into_iter()
+                .map(|codec| (codec.format(), codec))
+                .collect(),
+        }
+    }
+
+    /// Registers `codec` for its format, replacing any codec already
+    /// registered for it.
+    pub fn register(&mut self, codec: Box<dyn EventCodec>) {
+        self.codecs.insert(codec.format(), codec);
+    }
+
+    /// Returns `true` if a codec is registered for `format`.
+    #[must_use]
+    pub fn supports(&self, format: EventFormat) -> bool {
+        self.codecs.contains_key(&format)
+    }
+
+    /// Encodes `event` in the format its metadata names.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventCodecError::UnsupportedFormat` when no codec is
+    /// registered for the format, or the codec's error.
+    pub fn encode(&self, event: &VersionedEvent) -> CodecResult<Vec<u8>> {
+        self.codec(event.metadata().format)?.encode(event)
+    }
+
+    /// Decodes an event in whichever format `bytes` are in, recording that
+    /// format in the event's metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventCodecError::UnrecognisedFormat` when the format cannot
+    /// be detected, `UnsupportedFormat` when no codec is registered for
+    /// it, or the codec's error.
+    pub fn decode(&self, bytes: &[u8]) -> CodecResult<VersionedEvent> {
+        let format = EventFormat::detect(bytes).ok_or(EventCodecError::UnrecognisedFormat)?;
+        let mut event = self.codec(format)?.decode(bytes)?;
+        event.metadata_mut().format = format;
+        Ok(event)
+    }
+
+    fn codec(&self, format: EventFormat) -> CodecResult<&dyn EventCodec> {
+        self.codecs
+            .get(&format)
+            .map(Box::as_ref)
+            .ok_or(EventCodecError::UnsupportedFormat(format))
+    }
+}
+
+impl Default for EventCodecs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for EventCodecs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut formats: Vec<EventFormat> = self.codecs.keys().copied().collect();
+        formats.sort_unstable();
+        f.debug_struct("EventCodecs")
+            .field("formats", &formats)
+            .finish()
+    }
+}
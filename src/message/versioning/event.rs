@@ -3,6 +3,8 @@
 //! Events are stored with explicit version numbers, allowing the system
 //! to upgrade older events to the current schema on read.
 
+use super::EventFormat;
+use crate::message::canonical;
 use chrono::{DateTime, Utc};
 use mockable::Clock;
 use serde::{Deserialize, Serialize};
@@ -110,12 +112,27 @@ impl VersionedEvent {
         &mut self.data
     }
 
+    /// Consumes the event and returns its data.
+    #[must_use]
+    pub fn into_data(self) -> Value {
+        self.data
+    }
+
     /// Returns the event metadata.
     #[must_use]
     pub const fn metadata(&self) -> &EventMetadata {
         &self.metadata
     }
 
+    /// Returns a mutable reference to the event metadata.
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "&mut self methods cannot be const in stable Rust"
+    )]
+    pub fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
+
     /// Sets the schema version.
     ///
     /// This is used by upgraders to bump the version after transformation.
@@ -126,6 +143,30 @@ impl VersionedEvent {
     pub fn set_version(&mut self, version: u32) {
         self.version = version;
     }
+
+    /// Serialises the event to canonical JSON, with sorted keys and
+    /// normalised numbers at every depth of its data.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serialisation error when the event cannot be represented
+    /// as JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use corbusier::message::versioning::VersionedEvent;
+    /// use serde_json::json;
+    ///
+    /// let first = VersionedEvent::new(1, "Scored", json!({"b": 2.0, "a": 1}));
+    /// let mut second = first.clone();
+    /// *second.data_mut() = json!({"a": 1, "b": 2});
+    /// assert_eq!(first.to_canonical_json()?, second.to_canonical_json()?);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn to_canonical_json(&self) -> Result<String, serde_json::Error> {
+        canonical::to_canonical_string(self)
+    }
 }
 
 /// Metadata associated with an event.
@@ -141,6 +182,10 @@ pub struct EventMetadata {
     /// Correlation ID for distributed tracing.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub correlation_id: Option<String>,
+    /// The wire format the event is stored in. Decoding sets it to the
+    /// format detected, and encoding writes the event in it.
+    #[serde(default, skip_serializing_if = "EventFormat::is_json")]
+    pub format: EventFormat,
 }
 
 impl EventMetadata {
@@ -151,6 +196,7 @@ impl EventMetadata {
             occurred_at: Utc::now(),
             source: None,
             correlation_id: None,
+            format: EventFormat::Json,
         }
     }
 
@@ -161,6 +207,7 @@ impl EventMetadata {
             occurred_at: clock.utc(),
             source: None,
             correlation_id: None,
+            format: EventFormat::Json,
         }
     }
 
@@ -177,6 +224,13 @@ impl EventMetadata {
         self.correlation_id = Some(correlation_id.into());
         self
     }
+
+    /// Sets the wire format.
+    #[must_use]
+    pub const fn with_format(mut self, format: EventFormat) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 impl Default for EventMetadata {
@@ -0,0 +1,106 @@
+//! Reads image dimensions from the header of encoded image data.
+//!
+//! Attachments stored before image parts existed never recorded their
+//! dimensions, so upgrading them reads the width and height from the data.
+
+/// Returns the `(width, height)` recorded in the header of `data`, an image
+/// encoded as `mime_type`, when both are non-zero.
+pub(super) fn dimensions(mime_type: &str, data: &[u8]) -> Option<(u32, u32)> {
+    let (width, height) = match mime_type {
+        "image/png" => png(data),
+        "image/gif" => gif(data),
+        "image/jpeg" => jpeg(data),
+        "image/webp" => webp(data),
+        _ => None,
+    }?;
+    (width > 0 && height > 0).then_some((width, height))
+}
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+fn png(data: &[u8]) -> Option<(u32, u32)> {
+    if !data.starts_with(PNG_SIGNATURE) || data.get(12..16)? != b"IHDR" {
+        return None;
+    }
+    Some((read_be(data, 16, 4)?, read_be(data, 20, 4)?))
+}
+
+fn gif(data: &[u8]) -> Option<(u32, u32)> {
+    if !data.starts_with(b"GIF87a") && !data.starts_with(b"GIF89a") {
+        return None;
+    }
+    Some((read_le(data, 6, 2)?, read_le(data, 8, 2)?))
+}
+
+/// Walks the JPEG segments up to the first start-of-frame marker, which
+/// records the height before the width.
+fn jpeg(data: &[u8]) -> Option<(u32, u32)> {
+    if !data.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+    let mut at = 2;
+    loop {
+        let (marker, after) = next_marker(data, at)?;
+        if is_start_of_frame(marker) {
+            return Some((read_be(data, after + 5, 2)?, read_be(data, after + 3, 2)?));
+        }
+        at = match marker {
+            0x01 | 0xD0..=0xD7 => after,
+            0xD9 | 0xDA => return None,
+            _ => after + usize::try_from(read_be(data, after, 2)?).ok()?,
+        };
+    }
+}
+
+/// Returns the JPEG marker starting at `at`, skipping fill bytes, and the
+/// offset just past it.
+fn next_marker(data: &[u8], at: usize) -> Option<(u8, usize)> {
+    if *data.get(at)? != 0xFF {
+        return None;
+    }
+    let marker_at = at + data.get(at..)?.iter().position(|byte| *byte != 0xFF)?;
+    Some((*data.get(marker_at)?, marker_at + 1))
+}
+
+const fn is_start_of_frame(marker: u8) -> bool {
+    matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC)
+}
+
+fn webp(data: &[u8]) -> Option<(u32, u32)> {
+    if !data.starts_with(b"RIFF") || data.get(8..12)? != b"WEBP" {
+        return None;
+    }
+    match data.get(12..16)? {
+        b"VP8 " => Some((
+            read_le(data, 26, 2)? & 0x3FFF,
+            read_le(data, 28, 2)? & 0x3FFF,
+        )),
+        b"VP8L" => {
+            let bits = read_le(data, 21, 4)?;
+            Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
+        }
+        b"VP8X" => Some((read_le(data, 24, 3)? + 1, read_le(data, 27, 3)? + 1)),
+        _ => None,
+    }
+}
+
+/// Reads `len` bytes at `at` as a big-endian unsigned integer.
+fn read_be(data: &[u8], at: usize, len: usize) -> Option<u32> {
+    let bytes = data.get(at..at + len)?;
+    Some(
+        bytes
+            .iter()
+            .fold(0, |value, byte| (value << 8) | u32::from(*byte)),
+    )
+}
+
+/// Reads `len` bytes at `at` as a little-endian unsigned integer.
+fn read_le(data: &[u8], at: usize, len: usize) -> Option<u32> {
+    let bytes = data.get(at..at + len)?;
+    Some(
+        bytes
+            .iter()
+            .rev()
+            .fold(0, |value, byte| (value << 8) | u32::from(*byte)),
+    )
+}
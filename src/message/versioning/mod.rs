@@ -2,10 +2,26 @@
 //!
 //! This module provides infrastructure for versioned events and schema
 //! migrations, allowing the system to evolve message formats while
-//! maintaining backwards compatibility with stored data.
+//! maintaining backwards compatibility with stored data. Upgraders can be
+//! composed from single-version steps into chains, and a registry upgrades
+//! whole batches of stored events at once. Readers that predate an event's
+//! version negotiate it down to the newest version they support. Events can
+//! be written as JSON, CBOR or MessagePack and read back in any of them.
 
+pub mod chain;
+pub mod codec;
 pub mod event;
+mod image_header;
+pub mod negotiation;
+mod registry;
 pub mod upgrader;
 
+pub use chain::{UpgradeChain, UpgradeStep};
+#[cfg(feature = "cbor")]
+pub use codec::CborCodec;
+#[cfg(feature = "msgpack")]
+pub use codec::MessagePackCodec;
+pub use codec::{EventCodec, EventCodecs, EventFormat, JsonCodec};
 pub use event::{EventMetadata, VersionedEvent};
+pub use negotiation::SupportedVersions;
 pub use upgrader::{EventUpgrader, MessageCreatedUpgrader, UpgraderRegistry};
@@ -0,0 +1,149 @@
+//! Reading events written at a newer schema version than the reader knows.
+//!
+//! After a deployment is rolled back, the older code meets events that
+//! newer code wrote. A reader declares the newest version it understands
+//! per event type in [`SupportedVersions`]. Newer events are downgraded when
+//! their upgrader can reverse its steps, and are otherwise rejected with
+//! [`SchemaUpgradeError::IncompatibleVersion`] rather than a parse failure.
+
+use super::{UpgraderRegistry, VersionedEvent, upgrader::UpgradeResult};
+use crate::message::error::SchemaUpgradeError;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The newest schema version a reader understands, per event type.
+///
+/// Event types not declared are read at any version.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::versioning::negotiation::SupportedVersions;
+///
+/// let supported = SupportedVersions::new().with("MessageCreated", 2);
+/// assert_eq!(supported.max_supported("MessageCreated"), Some(2));
+/// assert!(supported.check("MessageCreated", 3).is_err());
+/// assert!(supported.check("TaskStateChanged", 9).is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SupportedVersions {
+    max_versions: HashMap<String, u32>,
+}
+
+impl SupportedVersions {
+    /// Creates a declaration that restricts no event type.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares the current version of every type `registry` upgrades as
+    /// the newest the reader understands.
+    #[must_use]
+    pub fn from_registry(registry: &UpgraderRegistry) -> Self {
+        registry
+            .current_versions()
+            .fold(Self::new(), |supported, (event_type, version)| {
+                supported.with(event_type, version)
+            })
+    }
+
+    /// Declares that the reader understands `event_type` up to `version`.
+    #[must_use]
+    pub fn with(mut self, event_type: impl Into<String>, version: u32) -> Self {
+        self.max_versions.insert(event_type.into(), version);
+        self
+    }
+
+    /// Returns the newest version of `event_type` the reader understands,
+    /// if declared.
+    #[must_use]
+    pub fn max_supported(&self, event_type: &str) -> Option<u32> {
+        self.max_versions.get(event_type).copied()
+    }
+
+    /// Checks that the reader understands `event_type` at `version`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaUpgradeError::IncompatibleVersion` when `version` is
+    /// newer than the declared maximum.
+    pub fn check(&self, event_type: &str, version: u32) -> UpgradeResult<()> {
+        match self.max_supported(event_type) {
+            Some(max_supported) if version > max_supported => {
+                Err(SchemaUpgradeError::IncompatibleVersion {
+                    event_type: event_type.to_owned(),
+                    version,
+                    max_supported,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The fields every serialised event carries, whatever its version.
+#[derive(Deserialize)]
+struct EventHeader {
+    version: u32,
+    event_type: String,
+}
+
+impl VersionedEvent {
+    /// Deserialises an event from JSON for a reader understanding
+    /// `supported`, downgrading it through `registry` when it is newer.
+    ///
+    /// The version is read before the rest of the event, so an event the
+    /// reader cannot handle is reported as incompatible rather than as
+    /// whatever parse error its unfamiliar shape would cause.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaUpgradeError::IncompatibleVersion` when the event is
+    /// newer than supported and cannot be parsed or downgraded, and
+    /// `SchemaUpgradeError::MalformedData` when the JSON is not an event.
+    pub fn from_json_negotiated(
+        json: &str,
+        supported: &SupportedVersions,
+        registry: &UpgraderRegistry,
+    ) -> UpgradeResult<Self> {
+        let header: EventHeader = serde_json::from_str(json)
+            .map_err(|err| SchemaUpgradeError::malformed(err.to_string()))?;
+        let compatibility = supported.check(&header.event_type, header.version);
+        match serde_json::from_str::<Self>(json) {
+            Ok(event) if compatibility.is_ok() => Ok(event),
+            Ok(event) => registry.negotiate(event, supported),
+            Err(err) => compatibility.and(Err(SchemaUpgradeError::malformed(err.to_string()))),
+        }
+    }
+}
+
+impl UpgraderRegistry {
+    /// Brings `event` within what `supported` allows, downgrading it when
+    /// it is newer than the reader understands.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaUpgradeError::IncompatibleVersion` when the event is
+    /// too new and its upgrader cannot downgrade it, or the upgrader's
+    /// error when a downgrade step fails.
+    pub fn negotiate(
+        &self,
+        event: VersionedEvent,
+        supported: &SupportedVersions,
+    ) -> UpgradeResult<VersionedEvent> {
+        let Err(incompatible) = supported.check(event.event_type(), event.version()) else {
+            return Ok(event);
+        };
+        let Some(target) = supported.max_supported(event.event_type()) else {
+            return Err(incompatible);
+        };
+        match self.downgrade(event, target) {
+            Err(
+                SchemaUpgradeError::UnsupportedDowngrade { .. }
+                | SchemaUpgradeError::UnknownEventType(_),
+            ) => Err(incompatible),
+            other => other,
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Registry dispatching event upgrades by event type.
+
+use super::upgrader::{EventUpgrader, MessageCreatedUpgrader, UpgradeResult};
+use super::{UpgradeChain, UpgradeStep, VersionedEvent};
+use crate::message::error::SchemaUpgradeError;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Registry of event upgraders keyed by event type.
+///
+/// The registry dispatches upgrade requests to the appropriate upgrader
+/// based on the event type.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::versioning::{UpgraderRegistry, VersionedEvent};
+/// use serde_json::json;
+///
+/// let registry = UpgraderRegistry::new();
+/// let event = VersionedEvent::new(1, "MessageCreated", json!({"id": "123"}));
+/// let upgraded = registry.upgrade(event).expect("should upgrade");
+/// assert_eq!(upgraded.version(), 3);
+/// ```
+#[derive(Default)]
+pub struct UpgraderRegistry {
+    upgraders: HashMap<String, Box<dyn EventUpgrader>>,
+}
+
+impl UpgraderRegistry {
+    /// Creates a new registry with default upgraders.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut registry = Self::default();
+        registry.register("MessageCreated", Box::new(MessageCreatedUpgrader::new()));
+        registry
+    }
+
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Registers an upgrader for an event type.
+    ///
+    /// If an upgrader is already registered for the event type, it is replaced.
+    pub fn register(&mut self, event_type: &str, upgrader: Box<dyn EventUpgrader>) {
+        self.upgraders.insert(event_type.to_owned(), upgrader);
+    }
+
+    /// Links `steps` into an [`UpgradeChain`] and registers it for
+    /// `event_type`, replacing any upgrader already registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`UpgradeChain::new`] when the steps do not
+    /// form a single chain; the registry is left unchanged.
+    pub fn register_steps(
+        &mut self,
+        event_type: &str,
+        steps: Vec<Box<dyn UpgradeStep>>,
+    ) -> UpgradeResult<()> {
+        let chain = UpgradeChain::new(event_type, steps)?;
+        self.register(event_type, Box::new(chain));
+        Ok(())
+    }
+
+    /// Upgrades an event using the appropriate upgrader.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `SchemaUpgradeError::UnknownEventType` if no upgrader is
+    ///   registered for the event type.
+    /// - May return `SchemaUpgradeError::UnsupportedVersion` if the upgrader
+    ///   for the event type does not support the event's version.
+    pub fn upgrade(&self, event: VersionedEvent) -> UpgradeResult<VersionedEvent> {
+        match self.upgraders.get(event.event_type()) {
+            Some(upgrader) => upgrader.upgrade(event),
+            None => Err(SchemaUpgradeError::UnknownEventType(
+                event.event_type().to_owned(),
+            )),
+        }
+    }
+
+    /// Downgrades an event to `target` using the appropriate upgrader.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaUpgradeError::UnknownEventType` if no upgrader is
+    /// registered for the event type, or the upgrader's downgrade error.
+    pub fn downgrade(&self, event: VersionedEvent, target: u32) -> UpgradeResult<VersionedEvent> {
+        match self.upgraders.get(event.event_type()) {
+            Some(upgrader) => upgrader.downgrade(event, target),
+            None => Err(SchemaUpgradeError::UnknownEventType(
+                event.event_type().to_owned(),
+            )),
+        }
+    }
+
+    /// Brings every event up to the current version of its type.
+    ///
+    /// Unlike [`Self::upgrade`], events of a type with no upgrader are
+    /// returned unchanged, so a mixed stream of stored events can be passed
+    /// in whole. Events keep their order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error an upgrader reports.
+    pub fn upgrade_all(
+        &self,
+        events: impl IntoIterator<Item = VersionedEvent>,
+    ) -> UpgradeResult<Vec<VersionedEvent>> {
+        events
+            .into_iter()
+            .map(|event| match self.upgraders.get(event.event_type()) {
+                Some(upgrader) => upgrader.upgrade(event),
+                None => Ok(event),
+            })
+            .collect()
+    }
+
+    /// Returns `true` if an event of `event_type` stored at `version` is
+    /// behind the current version of its type.
+    #[must_use]
+    pub fn needs_upgrade(&self, event_type: &str, version: u32) -> bool {
+        self.current_version(event_type)
+            .is_some_and(|current| version != current)
+    }
+
+    /// Returns `true` if an upgrader is registered for the event type.
+    #[must_use]
+    pub fn has_upgrader(&self, event_type: &str) -> bool {
+        self.upgraders.contains_key(event_type)
+    }
+
+    /// Returns the current version for an event type, if known.
+    #[must_use]
+    pub fn current_version(&self, event_type: &str) -> Option<u32> {
+        self.upgraders.get(event_type).map(|u| u.current_version())
+    }
+
+    /// Returns every registered event type with its current version.
+    pub fn current_versions(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.upgraders
+            .iter()
+            .map(|(event_type, upgrader)| (event_type.as_str(), upgrader.current_version()))
+    }
+}
+
+impl fmt::Debug for UpgraderRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut event_types: Vec<&str> = self.upgraders.keys().map(String::as_str).collect();
+        event_types.sort_unstable();
+        f.debug_struct("UpgraderRegistry")
+            .field("event_types", &event_types)
+            .finish()
+    }
+}
@@ -3,9 +3,12 @@
 //! Upgraders transform events from older schema versions to the current
 //! version, enabling backwards-compatible evolution of the event format.
 
-use super::VersionedEvent;
-use crate::message::error::SchemaUpgradeError;
-use std::collections::HashMap;
+use super::{VersionedEvent, image_header};
+use crate::message::{domain::ImagePart, error::SchemaUpgradeError};
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use serde_json::{Map, Value};
+
+pub use super::registry::UpgraderRegistry;
 
 /// Result type for upgrade operations.
 pub type UpgradeResult<T> = Result<T, SchemaUpgradeError>;
@@ -41,12 +44,35 @@ pub trait EventUpgrader: Send + Sync {
 
     /// Returns `true` if this upgrader can handle the given version.
     fn supports_version(&self, version: u32) -> bool;
+
+    /// Downgrades an event to the older version `target`, so a consumer
+    /// that predates the event's version can still read it.
+    ///
+    /// Downgrading is optional; the default only accepts an event already
+    /// at `target`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaUpgradeError::UnsupportedDowngrade` when the event
+    /// cannot be brought back to `target`.
+    fn downgrade(&self, event: VersionedEvent, target: u32) -> UpgradeResult<VersionedEvent> {
+        if event.version() == target {
+            Ok(event)
+        } else {
+            Err(SchemaUpgradeError::UnsupportedDowngrade {
+                from: event.version(),
+                to: target,
+            })
+        }
+    }
 }
 
 /// Upgrader for `MessageCreated` events.
 ///
-/// Handles migration of message creation events from v1 to v2:
+/// Handles migration of message creation events to v3:
 /// - v1 → v2: Adds the `metadata` field if missing
+/// - v2 → v3: Turns attachments of a supported image format into `image`
+///   parts, reading their `width` and `height` from the image data
 ///
 /// # Schema Changes
 ///
@@ -59,15 +85,24 @@ pub trait EventUpgrader: Send + Sync {
 /// ```json
 /// { "id": "...", "content": [...], "metadata": {} }
 /// ```
+///
+/// **v3 format:**
+/// ```json
+/// { "id": "...", "content": [{ "type": "image", "width": 800, "height": 600, ... }], "metadata": {} }
+/// ```
+///
+/// Whether an attachment becomes an image depends only on its MIME type.
+/// When its data is held in a blob store, or its header cannot be read, the
+/// image gets zero dimensions, which [`ImagePart::is_valid`] reports.
 #[derive(Debug, Default)]
 pub struct MessageCreatedUpgrader;
 
 impl MessageCreatedUpgrader {
     /// The current schema version.
-    pub const CURRENT_VERSION: u32 = 2;
+    pub const CURRENT_VERSION: u32 = 3;
 
     /// Supported schema versions.
-    const SUPPORTED_VERSIONS: &'static [u32] = &[1, 2];
+    const SUPPORTED_VERSIONS: &'static [u32] = &[1, 2, 3];
 
     /// Creates a new upgrader.
     #[must_use]
@@ -88,13 +123,64 @@ impl MessageCreatedUpgrader {
         event.set_version(2);
         Ok(event)
     }
+
+    /// Upgrades a v2 event to v3 by turning image attachments into image
+    /// parts.
+    fn upgrade_v2_to_v3(mut event: VersionedEvent) -> UpgradeResult<VersionedEvent> {
+        let obj = event
+            .data_mut()
+            .as_object_mut()
+            .ok_or_else(|| SchemaUpgradeError::malformed("expected event data to be an object"))?;
+
+        if let Some(content) = obj.get_mut("content") {
+            content
+                .as_array_mut()
+                .ok_or_else(|| SchemaUpgradeError::malformed("expected content to be an array"))?
+                .iter_mut()
+                .for_each(upgrade_image_attachment);
+        }
+        event.set_version(3);
+        Ok(event)
+    }
+}
+
+/// Retags an attachment part with a supported image MIME type as an image,
+/// reading its dimensions from its inline data.
+///
+/// Attachments never recorded their dimensions, and an image has no byte
+/// size, so `size_bytes` is dropped.
+fn upgrade_image_attachment(part: &mut Value) {
+    let Some(fields) = part.as_object_mut() else {
+        return;
+    };
+    let is_image_attachment = fields.get("type").and_then(Value::as_str) == Some("attachment")
+        && fields
+            .get("mime_type")
+            .and_then(Value::as_str)
+            .is_some_and(ImagePart::is_supported_format);
+    if !is_image_attachment {
+        return;
+    }
+    let (width, height) = inline_dimensions(fields).unwrap_or((0, 0));
+    fields.insert("type".to_owned(), Value::from("image"));
+    fields.insert("width".to_owned(), Value::from(width));
+    fields.insert("height".to_owned(), Value::from(height));
+    fields.remove("size_bytes");
+}
+
+/// Reads an attachment's dimensions from the header of its base64 data.
+fn inline_dimensions(fields: &Map<String, Value>) -> Option<(u32, u32)> {
+    let mime_type = fields.get("mime_type")?.as_str()?;
+    let data = STANDARD.decode(fields.get("data")?.as_str()?).ok()?;
+    image_header::dimensions(mime_type, &data)
 }
 
 impl EventUpgrader for MessageCreatedUpgrader {
     fn upgrade(&self, event: VersionedEvent) -> UpgradeResult<VersionedEvent> {
         match event.version() {
-            1 => Self::upgrade_v1_to_v2(event),
-            2 => Ok(event), // Current version, no upgrade needed
+            1 => Self::upgrade_v1_to_v2(event).and_then(Self::upgrade_v2_to_v3),
+            2 => Self::upgrade_v2_to_v3(event),
+            3 => Ok(event), // Current version, no upgrade needed
             v => Err(SchemaUpgradeError::UnsupportedVersion(v)),
         }
     }
@@ -108,79 +194,6 @@ impl EventUpgrader for MessageCreatedUpgrader {
     }
 }
 
-/// Registry of event upgraders keyed by event type.
-///
-/// The registry dispatches upgrade requests to the appropriate upgrader
-/// based on the event type.
-///
-/// # Examples
-///
-/// ```
-/// use corbusier::message::versioning::{UpgraderRegistry, VersionedEvent};
-/// use serde_json::json;
-///
-/// let registry = UpgraderRegistry::new();
-/// let event = VersionedEvent::new(1, "MessageCreated", json!({"id": "123"}));
-/// let upgraded = registry.upgrade(event).expect("should upgrade");
-/// assert_eq!(upgraded.version(), 2);
-/// ```
-#[derive(Default)]
-pub struct UpgraderRegistry {
-    upgraders: HashMap<String, Box<dyn EventUpgrader>>,
-}
-
-impl UpgraderRegistry {
-    /// Creates a new registry with default upgraders.
-    #[must_use]
-    pub fn new() -> Self {
-        let mut registry = Self::default();
-        registry.register("MessageCreated", Box::new(MessageCreatedUpgrader::new()));
-        registry
-    }
-
-    /// Creates an empty registry.
-    #[must_use]
-    pub fn empty() -> Self {
-        Self::default()
-    }
-
-    /// Registers an upgrader for an event type.
-    ///
-    /// If an upgrader is already registered for the event type, it is replaced.
-    pub fn register(&mut self, event_type: &str, upgrader: Box<dyn EventUpgrader>) {
-        self.upgraders.insert(event_type.to_owned(), upgrader);
-    }
-
-    /// Upgrades an event using the appropriate upgrader.
-    ///
-    /// # Errors
-    ///
-    /// - Returns `SchemaUpgradeError::UnknownEventType` if no upgrader is
-    ///   registered for the event type.
-    /// - May return `SchemaUpgradeError::UnsupportedVersion` if the upgrader
-    ///   for the event type does not support the event's version.
-    pub fn upgrade(&self, event: VersionedEvent) -> UpgradeResult<VersionedEvent> {
-        match self.upgraders.get(event.event_type()) {
-            Some(upgrader) => upgrader.upgrade(event),
-            None => Err(SchemaUpgradeError::UnknownEventType(
-                event.event_type().to_owned(),
-            )),
-        }
-    }
-
-    /// Returns `true` if an upgrader is registered for the event type.
-    #[must_use]
-    pub fn has_upgrader(&self, event_type: &str) -> bool {
-        self.upgraders.contains_key(event_type)
-    }
-
-    /// Returns the current version for an event type, if known.
-    #[must_use]
-    pub fn current_version(&self, event_type: &str) -> Option<u32> {
-        self.upgraders.get(event_type).map(|u| u.current_version())
-    }
-}
-
 #[cfg(test)]
 mod tests {
     //! Tests for message version upgrader behaviour.
@@ -189,28 +202,28 @@ mod tests {
     use serde_json::json;
 
     #[test]
-    fn message_created_upgrader_v1_to_v2() {
+    fn message_created_upgrader_v1_to_current() {
         let sut = MessageCreatedUpgrader::new();
         let event = VersionedEvent::new(1, "MessageCreated", json!({"id": "123", "content": []}));
 
         let result = sut.upgrade(event).expect("should upgrade");
 
-        assert_eq!(result.version(), 2);
+        assert_eq!(result.version(), MessageCreatedUpgrader::CURRENT_VERSION);
         assert!(result.data().get("metadata").is_some());
     }
 
     #[test]
-    fn message_created_upgrader_v2_unchanged() {
+    fn message_created_upgrader_v3_unchanged() {
         let sut = MessageCreatedUpgrader::new();
         let event = VersionedEvent::new(
-            2,
+            3,
             "MessageCreated",
             json!({"id": "123", "content": [], "metadata": {"key": "value"}}),
         );
 
         let result = sut.upgrade(event).expect("should upgrade");
 
-        assert_eq!(result.version(), 2);
+        assert_eq!(result.version(), 3);
         assert_eq!(
             result.data().get("metadata"),
             Some(&json!({"key": "value"}))
@@ -237,7 +250,7 @@ mod tests {
 
         let upgraded = registry.upgrade(event).expect("should upgrade");
 
-        assert_eq!(upgraded.version(), 2);
+        assert_eq!(upgraded.version(), 3);
     }
 
     #[test]
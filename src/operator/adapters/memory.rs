@@ -0,0 +1,63 @@
+//! In-memory repository for operator actions.
+
+use crate::context::{RequestContext, TenantId};
+use crate::operator::domain::{OperatorAction, OperatorActionQuery};
+use crate::operator::ports::{OperatorActionRepository, OperatorActionResult};
+use crate::pagination::{Cursor, Page, PageRequest};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Thread-safe in-memory operator action repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryOperatorActionRepository {
+    actions: Arc<RwLock<HashMap<TenantId, Vec<OperatorAction>>>>,
+}
+
+impl InMemoryOperatorActionRepository {
+    /// Creates an empty in-memory operator action repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OperatorActionRepository for InMemoryOperatorActionRepository {
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        action: &OperatorAction,
+    ) -> OperatorActionResult<()> {
+        let mut actions = self.actions.write().await;
+        actions
+            .entry(ctx.tenant_id())
+            .or_default()
+            .push(action.clone());
+        Ok(())
+    }
+
+    async fn query(
+        &self,
+        ctx: &RequestContext,
+        query: OperatorActionQuery,
+        page: PageRequest,
+    ) -> OperatorActionResult<Page<OperatorAction>> {
+        let actions = self.actions.read().await;
+        let tenant_actions = actions.get(&ctx.tenant_id()).map_or(&[][..], Vec::as_slice);
+        let mut matching = tenant_actions
+            .iter()
+            .filter(|action| query.matches(action))
+            .cloned()
+            .collect::<Vec<_>>();
+        matching.sort_by(|left, right| {
+            left.occurred_at
+                .cmp(&right.occurred_at)
+                .then_with(|| left.id.cmp(&right.id))
+        });
+        Ok(Page::from_ordered(matching, page, |action| {
+            Cursor::at_timestamp(action.occurred_at, action.id.into_inner())
+        }))
+    }
+}
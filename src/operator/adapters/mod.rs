@@ -0,0 +1,7 @@
+//! Adapter implementations for operator action ports.
+
+pub mod memory;
+pub mod postgres;
+
+pub use memory::InMemoryOperatorActionRepository;
+pub use postgres::PostgresOperatorActionRepository;
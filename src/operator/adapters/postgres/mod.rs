@@ -0,0 +1,8 @@
+//! `PostgreSQL` adapters for operator action persistence.
+
+mod models;
+mod repository;
+mod schema;
+
+pub use repository::PostgresOperatorActionRepository;
+pub(crate) use repository::insert_action;
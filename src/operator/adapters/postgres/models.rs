@@ -0,0 +1,36 @@
+//! Diesel models for operator action persistence.
+
+use super::schema::operator_actions;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+/// Row representation for an operator action.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = operator_actions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct OperatorActionRow {
+    /// Action identifier.
+    pub id: uuid::Uuid,
+    /// Tenant identifier.
+    pub tenant_id: uuid::Uuid,
+    /// Operation kind string.
+    pub kind: String,
+    /// Conversation whose timeline includes the action, if any.
+    pub conversation_id: Option<uuid::Uuid>,
+    /// Task whose timeline includes the action, if any.
+    pub task_id: Option<uuid::Uuid>,
+    /// Handoff acted on, if any.
+    pub handoff_id: Option<uuid::Uuid>,
+    /// Operator's justification.
+    pub reason: String,
+    /// Acting user.
+    pub actor_id: uuid::Uuid,
+    /// Correlation identifier of the acting request.
+    pub correlation_id: uuid::Uuid,
+    /// Subject state before the action, if any.
+    pub from_state: Option<String>,
+    /// Subject state after the action, if any.
+    pub to_state: Option<String>,
+    /// When the action was taken.
+    pub occurred_at: DateTime<Utc>,
+}
@@ -0,0 +1,216 @@
+//! `PostgreSQL` repository implementation for operator actions.
+
+use super::models::OperatorActionRow;
+use super::schema::operator_actions;
+use crate::context::{CorrelationId, RequestContext, UserId};
+use crate::message::domain::{ConversationId, HandoffId};
+use crate::operator::domain::{
+    OperatorAction, OperatorActionId, OperatorActionKind, OperatorActionQuery, OperatorReason,
+    OperatorSubject, OperatorTransition,
+};
+use crate::operator::ports::{OperatorActionError, OperatorActionRepository, OperatorActionResult};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{
+    FromTxError, PgPool, TxError, ensure_tenant_exists, get_conn_with, keyset_page,
+    run_blocking_with, with_tenant_read_tx, with_tenant_tx,
+};
+use crate::task::domain::TaskId;
+use async_trait::async_trait;
+use diesel::pg::{Pg, PgConnection};
+use diesel::prelude::*;
+
+impl FromTxError<Self> for OperatorActionError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(error) => error,
+            TxError::Diesel(error) => Self::persistence_failed(error),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed operator action repository.
+#[derive(Debug, Clone)]
+pub struct PostgresOperatorActionRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresOperatorActionRepository,
+    "operator_action_repository"
+);
+
+impl PostgresOperatorActionRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OperatorActionRepository for PostgresOperatorActionRepository {
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        action: &OperatorAction,
+    ) -> OperatorActionResult<()> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = to_row(action, tenant_uuid);
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, OperatorActionError::persistence_failed)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid)
+                        .map_err(OperatorActionError::persistence_failed)?;
+                    insert_row(tx, &row).map_err(OperatorActionError::persistence_failed)
+                })
+            },
+            OperatorActionError::persistence_failed,
+        )
+        .await
+    }
+
+    async fn query(
+        &self,
+        ctx: &RequestContext,
+        query: OperatorActionQuery,
+        page: PageRequest,
+    ) -> OperatorActionResult<Page<OperatorAction>> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let rows = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, OperatorActionError::persistence_failed)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    let filtered = filtered_query(tenant_uuid, query);
+                    let paged = keyset_page!(
+                        filtered,
+                        page,
+                        after_timestamp,
+                        (operator_actions::occurred_at, operator_actions::id)
+                    );
+                    paged
+                        .select(OperatorActionRow::as_select())
+                        .load::<OperatorActionRow>(tx)
+                        .map_err(OperatorActionError::persistence_failed)
+                })
+            },
+            OperatorActionError::persistence_failed,
+        )
+        .await?;
+        Page::from_overfetched(rows, page, |row| {
+            Cursor::at_timestamp(row.occurred_at, row.id)
+        })
+        .try_map(row_to_action)
+    }
+}
+
+fn filtered_query(
+    tenant_uuid: uuid::Uuid,
+    query: OperatorActionQuery,
+) -> operator_actions::BoxedQuery<'static, Pg> {
+    let mut boxed = operator_actions::table
+        .filter(operator_actions::tenant_id.eq(tenant_uuid))
+        .into_boxed::<Pg>();
+    if let Some(conversation_id) = query.conversation_id() {
+        boxed = boxed.filter(operator_actions::conversation_id.eq(conversation_id.into_inner()));
+    }
+    if let Some(task_id) = query.task_id() {
+        boxed = boxed.filter(operator_actions::task_id.eq(task_id.into_inner()));
+    }
+    if let Some(actor) = query.actor() {
+        boxed = boxed.filter(operator_actions::actor_id.eq(actor.into_inner()));
+    }
+    if let Some(kind) = query.kind() {
+        boxed = boxed.filter(operator_actions::kind.eq(kind.as_str()));
+    }
+    boxed
+}
+
+/// Records `action` for `tenant_id` inside the caller's transaction.
+///
+/// Adapters that audit a change in the same transaction as the change
+/// itself use this rather than [`PostgresOperatorActionRepository`].
+pub(crate) fn insert_action(
+    conn: &mut PgConnection,
+    action: &OperatorAction,
+    tenant_id: uuid::Uuid,
+) -> QueryResult<()> {
+    insert_row(conn, &to_row(action, tenant_id))
+}
+
+fn insert_row(conn: &mut PgConnection, row: &OperatorActionRow) -> QueryResult<()> {
+    diesel::insert_into(operator_actions::table)
+        .values(row)
+        .execute(conn)
+        .map(|_| ())
+}
+
+fn to_row(action: &OperatorAction, tenant_id: uuid::Uuid) -> OperatorActionRow {
+    let (from_state, to_state) = action
+        .transition
+        .clone()
+        .map_or((None, None), |transition| {
+            (Some(transition.from), Some(transition.to))
+        });
+    OperatorActionRow {
+        id: action.id.into_inner(),
+        tenant_id,
+        kind: action.kind.as_str().to_owned(),
+        conversation_id: action
+            .subject
+            .conversation_id()
+            .map(ConversationId::into_inner),
+        task_id: action.subject.task_id().map(TaskId::into_inner),
+        handoff_id: action.subject.handoff_id().map(HandoffId::into_inner),
+        reason: action.reason.as_str().to_owned(),
+        actor_id: action.actor.into_inner(),
+        correlation_id: action.correlation_id.into_inner(),
+        from_state,
+        to_state,
+        occurred_at: action.occurred_at,
+    }
+}
+
+fn row_to_subject(row: &OperatorActionRow) -> OperatorActionResult<OperatorSubject> {
+    let conversation_id = row.conversation_id.map(ConversationId::from_uuid);
+    let task_id = row.task_id.map(TaskId::from_uuid);
+    match (row.handoff_id, conversation_id, task_id) {
+        (Some(handoff_uuid), _, _) => Ok(OperatorSubject::Handoff {
+            handoff_id: HandoffId::from_uuid(handoff_uuid),
+            conversation_id,
+        }),
+        (None, Some(conversation), _) => Ok(OperatorSubject::Conversation {
+            conversation_id: conversation,
+            task_id,
+        }),
+        (None, None, Some(task)) => Ok(OperatorSubject::Task { task_id: task }),
+        (None, None, None) => Err(OperatorActionError::invalid_persisted_data(format!(
+            "operator action {} has no subject",
+            row.id
+        ))),
+    }
+}
+
+fn row_to_action(row: OperatorActionRow) -> OperatorActionResult<OperatorAction> {
+    let subject = row_to_subject(&row)?;
+    let kind = OperatorActionKind::try_from(row.kind.as_str())
+        .map_err(|err| OperatorActionError::invalid_persisted_data(err.to_string()))?;
+    let reason = OperatorReason::new(row.reason)
+        .map_err(|err| OperatorActionError::invalid_persisted_data(err.to_string()))?;
+    let transition = match (row.from_state, row.to_state) {
+        (Some(from), Some(to)) => Some(OperatorTransition { from, to }),
+        _ => None,
+    };
+    Ok(OperatorAction {
+        id: OperatorActionId::from_uuid(row.id),
+        kind,
+        subject,
+        reason,
+        actor: UserId::from_uuid(row.actor_id),
+        correlation_id: CorrelationId::from_uuid(row.correlation_id),
+        transition,
+        occurred_at: row.occurred_at,
+    })
+}
@@ -0,0 +1,34 @@
+//! Diesel schema for operator action persistence.
+
+diesel::table! {
+    /// Audited operator actions.
+    operator_actions (id) {
+        /// Action identifier.
+        id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Operation performed.
+        #[max_length = 50]
+        kind -> Varchar,
+        /// Conversation whose timeline includes the action.
+        conversation_id -> Nullable<Uuid>,
+        /// Task whose timeline includes the action.
+        task_id -> Nullable<Uuid>,
+        /// Handoff acted on.
+        handoff_id -> Nullable<Uuid>,
+        /// Operator's justification.
+        reason -> Text,
+        /// Acting user.
+        actor_id -> Uuid,
+        /// Correlation identifier of the acting request.
+        correlation_id -> Uuid,
+        /// Subject state before the action.
+        #[max_length = 50]
+        from_state -> Nullable<Varchar>,
+        /// Subject state after the action.
+        #[max_length = 50]
+        to_state -> Nullable<Varchar>,
+        /// When the action was taken.
+        occurred_at -> Timestamptz,
+    }
+}
@@ -0,0 +1,291 @@
+//! Operator action records.
+
+use super::OperatorReason;
+use crate::context::{CorrelationId, RequestContext, UserId};
+use crate::message::domain::{ConversationId, HandoffId};
+use crate::task::domain::TaskId;
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Unique identifier for a recorded operator action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct OperatorActionId(Uuid);
+
+impl OperatorActionId {
+    /// Creates a new random operator action identifier.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Creates an identifier from an existing UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Returns the wrapped UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for OperatorActionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for OperatorActionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The privileged operation an operator performed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperatorActionKind {
+    /// An active conversation was paused.
+    ConversationPaused,
+    /// A paused conversation was resumed.
+    ConversationResumed,
+    /// A pending handoff was cancelled.
+    HandoffCancelled,
+    /// A task was moved to a state its lifecycle would not normally allow.
+    TaskForceTransitioned,
+    /// A conversation was moved out of the tenant, recorded in the source
+    /// tenant.
+    ConversationTransferredOut,
+    /// A conversation was moved into the tenant, recorded in the target
+    /// tenant.
+    ConversationTransferredIn,
+}
+
+impl OperatorActionKind {
+    /// Returns the stable string representation for persistence.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ConversationPaused => "conversation_paused",
+            Self::ConversationResumed => "conversation_resumed",
+            Self::HandoffCancelled => "handoff_cancelled",
+            Self::TaskForceTransitioned => "task_force_transitioned",
+            Self::ConversationTransferredOut => "conversation_transferred_out",
+            Self::ConversationTransferredIn => "conversation_transferred_in",
+        }
+    }
+}
+
+impl fmt::Display for OperatorActionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when an operator action kind string is not recognised.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown operator action kind: {0}")]
+pub struct ParseOperatorActionKindError(pub String);
+
+impl TryFrom<&str> for OperatorActionKind {
+    type Error = ParseOperatorActionKindError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "conversation_paused" => Ok(Self::ConversationPaused),
+            "conversation_resumed" => Ok(Self::ConversationResumed),
+            "handoff_cancelled" => Ok(Self::HandoffCancelled),
+            "task_force_transitioned" => Ok(Self::TaskForceTransitioned),
+            "conversation_transferred_out" => Ok(Self::ConversationTransferredOut),
+            "conversation_transferred_in" => Ok(Self::ConversationTransferredIn),
+            other => Err(ParseOperatorActionKindError(other.to_owned())),
+        }
+    }
+}
+
+/// The aggregate an operator action was applied to.
+///
+/// Subjects carry the related conversation or task where one is known, so an
+/// action appears in the timelines of both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OperatorSubject {
+    /// A conversation, and the task it serves when linked.
+    Conversation {
+        /// The conversation acted on.
+        conversation_id: ConversationId,
+        /// The task the conversation is linked to, if any.
+        task_id: Option<TaskId>,
+    },
+    /// A handoff, and the conversation it belongs to when known.
+    Handoff {
+        /// The handoff acted on.
+        handoff_id: HandoffId,
+        /// The conversation of the handoff's source session, if found.
+        conversation_id: Option<ConversationId>,
+    },
+    /// A task.
+    Task {
+        /// The task acted on.
+        task_id: TaskId,
+    },
+}
+
+impl OperatorSubject {
+    /// Returns the conversation whose timeline includes the action.
+    #[must_use]
+    pub const fn conversation_id(&self) -> Option<ConversationId> {
+        match *self {
+            Self::Conversation {
+                conversation_id, ..
+            } => Some(conversation_id),
+            Self::Handoff {
+                conversation_id, ..
+            } => conversation_id,
+            Self::Task { .. } => None,
+        }
+    }
+
+    /// Returns the task whose timeline includes the action.
+    #[must_use]
+    pub const fn task_id(&self) -> Option<TaskId> {
+        match *self {
+            Self::Conversation { task_id, .. } => task_id,
+            Self::Task { task_id } => Some(task_id),
+            Self::Handoff { .. } => None,
+        }
+    }
+
+    /// Returns the handoff acted on, if the subject is a handoff.
+    #[must_use]
+    pub const fn handoff_id(&self) -> Option<HandoffId> {
+        match *self {
+            Self::Handoff { handoff_id, .. } => Some(handoff_id),
+            Self::Conversation { .. } | Self::Task { .. } => None,
+        }
+    }
+}
+
+/// The state change an operator action caused, in the subject's own state
+/// vocabulary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperatorTransition {
+    /// State before the action.
+    pub from: String,
+    /// State after the action.
+    pub to: String,
+}
+
+impl OperatorTransition {
+    /// Creates a transition between two displayable states.
+    #[must_use]
+    pub fn new(from: impl fmt::Display, to: impl fmt::Display) -> Self {
+        Self {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+}
+
+/// What an operator did, supplied by the service performing the action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorActionDetails {
+    /// The operation performed.
+    pub kind: OperatorActionKind,
+    /// The aggregate acted on.
+    pub subject: OperatorSubject,
+    /// Why the operator acted.
+    pub reason: OperatorReason,
+    /// The resulting state change, when the subject has a state.
+    pub transition: Option<OperatorTransition>,
+}
+
+/// An audited operator action.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+/// use corbusier::message::domain::ConversationId;
+/// use corbusier::operator::domain::{
+///     OperatorAction, OperatorActionDetails, OperatorActionKind, OperatorReason,
+///     OperatorSubject,
+/// };
+/// use mockable::DefaultClock;
+///
+/// let ctx = RequestContext::new(
+///     TenantId::new(),
+///     CorrelationId::new(),
+///     UserId::new(),
+///     SessionId::new(),
+/// );
+/// let conversation_id = ConversationId::new();
+/// let action = OperatorAction::new(
+///     &ctx,
+///     OperatorActionDetails {
+///         kind: OperatorActionKind::ConversationPaused,
+///         subject: OperatorSubject::Conversation {
+///             conversation_id,
+///             task_id: None,
+///         },
+///         reason: OperatorReason::new("runaway tool loop").expect("valid reason"),
+///         transition: None,
+///     },
+///     &DefaultClock,
+/// );
+/// assert_eq!(action.actor, ctx.user_id());
+/// assert_eq!(action.subject.conversation_id(), Some(conversation_id));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperatorAction {
+    /// Action identifier.
+    pub id: OperatorActionId,
+    /// The operation performed.
+    pub kind: OperatorActionKind,
+    /// The aggregate acted on.
+    pub subject: OperatorSubject,
+    /// Why the operator acted.
+    pub reason: OperatorReason,
+    /// The user who acted.
+    pub actor: UserId,
+    /// Correlation identifier of the request that performed the action.
+    pub correlation_id: CorrelationId,
+    /// The resulting state change, when the subject has a state.
+    pub transition: Option<OperatorTransition>,
+    /// When the action was taken.
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl OperatorAction {
+    /// Records `details` as performed now by the user in `ctx`.
+    #[must_use]
+    pub fn new(
+        ctx: &RequestContext,
+        details: OperatorActionDetails,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        let OperatorActionDetails {
+            kind,
+            subject,
+            reason,
+            transition,
+        } = details;
+        Self {
+            id: OperatorActionId::new(),
+            kind,
+            subject,
+            reason,
+            actor: ctx.user_id(),
+            correlation_id: ctx.correlation_id(),
+            transition,
+            occurred_at: clock.utc(),
+        }
+    }
+}
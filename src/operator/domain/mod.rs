@@ -0,0 +1,12 @@
+//! Domain types for operator action auditing.
+
+pub mod action;
+pub mod query;
+pub mod reason;
+
+pub use action::{
+    OperatorAction, OperatorActionDetails, OperatorActionId, OperatorActionKind, OperatorSubject,
+    OperatorTransition, ParseOperatorActionKindError,
+};
+pub use query::OperatorActionQuery;
+pub use reason::{MAX_OPERATOR_REASON_CHARS, OperatorReason, OperatorReasonError};
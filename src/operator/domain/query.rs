@@ -0,0 +1,104 @@
+//! Filters for listing recorded operator actions.
+
+use super::{OperatorAction, OperatorActionKind};
+use crate::context::UserId;
+use crate::message::domain::ConversationId;
+use crate::task::domain::TaskId;
+
+/// Filter over a tenant's operator actions.
+///
+/// Every set criterion must match. The default query matches every action.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::UserId;
+/// use corbusier::operator::domain::{OperatorActionKind, OperatorActionQuery};
+/// use corbusier::task::domain::TaskId;
+///
+/// let task_id = TaskId::new();
+/// let query = OperatorActionQuery::for_task(task_id)
+///     .with_kind(OperatorActionKind::TaskForceTransitioned)
+///     .with_actor(UserId::new());
+/// assert_eq!(query.task_id(), Some(task_id));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperatorActionQuery {
+    conversation_id: Option<ConversationId>,
+    task_id: Option<TaskId>,
+    actor: Option<UserId>,
+    kind: Option<OperatorActionKind>,
+}
+
+impl OperatorActionQuery {
+    /// Matches actions in a conversation's timeline.
+    #[must_use]
+    pub fn for_conversation(conversation_id: ConversationId) -> Self {
+        Self {
+            conversation_id: Some(conversation_id),
+            ..Self::default()
+        }
+    }
+
+    /// Matches actions in a task's timeline.
+    #[must_use]
+    pub fn for_task(task_id: TaskId) -> Self {
+        Self {
+            task_id: Some(task_id),
+            ..Self::default()
+        }
+    }
+
+    /// Restricts the query to actions taken by `actor`.
+    #[must_use]
+    pub const fn with_actor(mut self, actor: UserId) -> Self {
+        self.actor = Some(actor);
+        self
+    }
+
+    /// Restricts the query to actions of one kind.
+    #[must_use]
+    pub const fn with_kind(mut self, kind: OperatorActionKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Returns the conversation filter, if set.
+    #[must_use]
+    pub const fn conversation_id(&self) -> Option<ConversationId> {
+        self.conversation_id
+    }
+
+    /// Returns the task filter, if set.
+    #[must_use]
+    pub const fn task_id(&self) -> Option<TaskId> {
+        self.task_id
+    }
+
+    /// Returns the actor filter, if set.
+    #[must_use]
+    pub const fn actor(&self) -> Option<UserId> {
+        self.actor
+    }
+
+    /// Returns the kind filter, if set.
+    #[must_use]
+    pub const fn kind(&self) -> Option<OperatorActionKind> {
+        self.kind
+    }
+
+    /// Returns whether `action` satisfies every criterion.
+    #[must_use]
+    pub fn matches(&self, action: &OperatorAction) -> bool {
+        let conversation_matches = self
+            .conversation_id
+            .is_none_or(|id| action.subject.conversation_id() == Some(id));
+        let task_matches = self
+            .task_id
+            .is_none_or(|id| action.subject.task_id() == Some(id));
+        conversation_matches
+            && task_matches
+            && self.actor.is_none_or(|actor| action.actor == actor)
+            && self.kind.is_none_or(|kind| action.kind == kind)
+    }
+}
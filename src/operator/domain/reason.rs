@@ -0,0 +1,90 @@
+//! Free-text justification required for operator actions.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// Maximum length of an operator reason, in characters.
+pub const MAX_OPERATOR_REASON_CHARS: usize = 1_000;
+
+/// Errors returned while constructing an operator reason.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum OperatorReasonError {
+    /// An operator action must say why it was taken.
+    #[error("operator reason must not be empty")]
+    Empty,
+    /// The reason exceeds [`MAX_OPERATOR_REASON_CHARS`].
+    #[error("operator reason has {actual} characters, the limit is {max}")]
+    TooLong {
+        /// Maximum permitted characters.
+        max: usize,
+        /// Characters supplied.
+        actual: usize,
+    },
+}
+
+/// Why an operator acted, trimmed and non-empty.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::operator::domain::OperatorReason;
+///
+/// let reason = OperatorReason::new(" runaway tool loop ").expect("valid reason");
+/// assert_eq!(reason.as_str(), "runaway tool loop");
+/// assert!(OperatorReason::new("").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct OperatorReason(String);
+
+impl OperatorReason {
+    /// Creates a reason from free text.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OperatorReasonError::Empty`] when the trimmed text is empty,
+    /// or [`OperatorReasonError::TooLong`] when it exceeds
+    /// [`MAX_OPERATOR_REASON_CHARS`].
+    pub fn new(reason: impl Into<String>) -> Result<Self, OperatorReasonError> {
+        let raw = reason.into();
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            return Err(OperatorReasonError::Empty);
+        }
+        let actual = trimmed.chars().count();
+        if actual > MAX_OPERATOR_REASON_CHARS {
+            return Err(OperatorReasonError::TooLong {
+                max: MAX_OPERATOR_REASON_CHARS,
+                actual,
+            });
+        }
+        Ok(Self(trimmed.to_owned()))
+    }
+
+    /// Returns the reason text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for OperatorReason {
+    type Error = OperatorReasonError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<OperatorReason> for String {
+    fn from(value: OperatorReason) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for OperatorReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,21 @@
+//! Audit records for privileged operator actions.
+//!
+//! Pausing or resuming a conversation, cancelling a handoff, and forcing a
+//! task past its lifecycle rules each require a written reason. The services
+//! that perform them record an [`domain::OperatorAction`] naming the acting
+//! user, the subject, the reason, and the state change, through an
+//! [`ports::OperatorActionRepository`] attached with their
+//! `with_operator_actions` builders. The recorded actions form part of the
+//! conversation and task timelines served by the HTTP API. The module follows
+//! hexagonal architecture:
+//!
+//! - Domain types in [`domain`]
+//! - Port contracts in [`ports`]
+//! - Adapter implementations in [`adapters`]
+
+pub mod adapters;
+pub mod domain;
+pub mod ports;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,5 @@
+//! Port contracts for operator action auditing.
+
+pub mod repository;
+
+pub use repository::{OperatorActionError, OperatorActionRepository, OperatorActionResult};
@@ -0,0 +1,58 @@
+//! Port contract for persisting and querying operator actions.
+
+use crate::context::RequestContext;
+use crate::operator::domain::{OperatorAction, OperatorActionQuery};
+use crate::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type for operator action repository operations.
+pub type OperatorActionResult<T> = Result<T, OperatorActionError>;
+
+/// Append-only store of a tenant's operator actions.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait OperatorActionRepository: Send + Sync {
+    /// Records an action taken under `ctx`.
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        action: &OperatorAction,
+    ) -> OperatorActionResult<()>;
+
+    /// Returns a page of the actions matching `query`, oldest first.
+    async fn query(
+        &self,
+        ctx: &RequestContext,
+        query: OperatorActionQuery,
+        page: PageRequest,
+    ) -> OperatorActionResult<Page<OperatorAction>>;
+}
+
+/// Errors returned by operator action repository implementations.
+#[derive(Debug, Clone, Error)]
+pub enum OperatorActionError {
+    /// Persistence-layer failure.
+    #[error("operator action persistence operation failed: {reason}")]
+    PersistenceFailed {
+        /// Human-readable reason from the failing dependency.
+        reason: String,
+    },
+    /// Persisted data failed validation.
+    #[error("invalid persisted operator action data: {0}")]
+    InvalidPersistedData(String),
+}
+
+impl OperatorActionError {
+    /// Creates a persistence failure from an infrastructure error.
+    pub fn persistence_failed(err: impl std::error::Error) -> Self {
+        Self::PersistenceFailed {
+            reason: err.to_string(),
+        }
+    }
+
+    /// Creates an invalid persisted data error.
+    pub fn invalid_persisted_data(err: impl Into<String>) -> Self {
+        Self::InvalidPersistedData(err.into())
+    }
+}
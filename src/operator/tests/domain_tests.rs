@@ -0,0 +1,110 @@
+//! Unit tests for operator action domain types.
+
+use crate::context::{RequestContext, UserId};
+use crate::message::domain::{ConversationId, HandoffId};
+use crate::operator::domain::{
+    MAX_OPERATOR_REASON_CHARS, OperatorAction, OperatorActionDetails, OperatorActionKind,
+    OperatorActionQuery, OperatorReason, OperatorReasonError, OperatorSubject,
+};
+use crate::task::domain::TaskId;
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+fn action_on(
+    ctx: &RequestContext,
+    kind: OperatorActionKind,
+    subject: OperatorSubject,
+) -> OperatorAction {
+    let details = OperatorActionDetails {
+        kind,
+        subject,
+        reason: OperatorReason::new("operator check").expect("valid reason"),
+        transition: None,
+    };
+    OperatorAction::new(ctx, details, &DefaultClock)
+}
+
+#[rstest]
+#[case::blank(" \n", OperatorReasonError::Empty)]
+#[case::too_long(
+    &"x".repeat(MAX_OPERATOR_REASON_CHARS + 1),
+    OperatorReasonError::TooLong {
+        max: MAX_OPERATOR_REASON_CHARS,
+        actual: MAX_OPERATOR_REASON_CHARS + 1,
+    }
+)]
+fn operator_reasons_are_validated(#[case] reason: &str, #[case] expected: OperatorReasonError) {
+    assert_eq!(OperatorReason::new(reason), Err(expected));
+}
+
+#[rstest]
+#[case(OperatorActionKind::ConversationPaused)]
+#[case(OperatorActionKind::ConversationResumed)]
+#[case(OperatorActionKind::HandoffCancelled)]
+#[case(OperatorActionKind::TaskForceTransitioned)]
+#[case(OperatorActionKind::ConversationTransferredOut)]
+#[case(OperatorActionKind::ConversationTransferredIn)]
+fn action_kinds_round_trip_through_strings(#[case] kind: OperatorActionKind) {
+    assert_eq!(OperatorActionKind::try_from(kind.as_str()), Ok(kind));
+}
+
+#[rstest]
+fn actions_record_the_acting_request(ctx: RequestContext) {
+    let action = action_on(
+        &ctx,
+        OperatorActionKind::TaskForceTransitioned,
+        OperatorSubject::Task {
+            task_id: TaskId::new(),
+        },
+    );
+
+    assert_eq!(action.actor, ctx.user_id());
+    assert_eq!(action.correlation_id, ctx.correlation_id());
+}
+
+#[rstest]
+fn conversation_actions_join_the_linked_task_timeline(ctx: RequestContext) {
+    let conversation_id = ConversationId::new();
+    let task_id = TaskId::new();
+    let action = action_on(
+        &ctx,
+        OperatorActionKind::ConversationPaused,
+        OperatorSubject::Conversation {
+            conversation_id,
+            task_id: Some(task_id),
+        },
+    );
+
+    assert!(OperatorActionQuery::for_conversation(conversation_id).matches(&action));
+    assert!(OperatorActionQuery::for_task(task_id).matches(&action));
+    assert!(!OperatorActionQuery::for_task(TaskId::new()).matches(&action));
+}
+
+#[rstest]
+fn queries_require_every_criterion(ctx: RequestContext) {
+    let conversation_id = ConversationId::new();
+    let action = action_on(
+        &ctx,
+        OperatorActionKind::HandoffCancelled,
+        OperatorSubject::Handoff {
+            handoff_id: HandoffId::new(),
+            conversation_id: Some(conversation_id),
+        },
+    );
+    let query = OperatorActionQuery::for_conversation(conversation_id);
+
+    assert!(OperatorActionQuery::default().matches(&action));
+    assert!(query.with_actor(ctx.user_id()).matches(&action));
+    assert!(!query.with_actor(UserId::new()).matches(&action));
+    assert!(
+        !query
+            .with_kind(OperatorActionKind::ConversationPaused)
+            .matches(&action)
+    );
+}
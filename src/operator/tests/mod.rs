@@ -0,0 +1,4 @@
+//! Unit tests for operator action auditing.
+
+mod domain_tests;
+mod service_tests;
@@ -0,0 +1,165 @@
+//! Tests for the operator actions recorded by conversation and task services.
+
+use std::sync::Arc;
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemoryConversationRepository, InMemoryMessageRepository},
+    services::ConversationService,
+    validation::service::DefaultMessageValidator,
+};
+use crate::operator::{
+    adapters::InMemoryOperatorActionRepository,
+    domain::{
+        OperatorActionKind, OperatorActionQuery, OperatorReason, OperatorSubject,
+        OperatorTransition,
+    },
+    ports::OperatorActionRepository,
+};
+use crate::pagination::PageRequest;
+use crate::task::{
+    adapters::memory::InMemoryTaskRepository,
+    domain::{TaskDomainError, TaskState},
+    services::{
+        CreateTaskFromIssueRequest, TaskLifecycleError, TaskLifecycleService, TransitionTaskRequest,
+    },
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+#[fixture]
+fn audit() -> Arc<InMemoryOperatorActionRepository> {
+    Arc::new(InMemoryOperatorActionRepository::new())
+}
+
+fn reason() -> OperatorReason {
+    OperatorReason::new("runaway tool loop").expect("valid reason")
+}
+
+#[rstest]
+#[tokio::test]
+async fn conversation_pauses_and_resumes_are_recorded(
+    ctx: RequestContext,
+    audit: Arc<InMemoryOperatorActionRepository>,
+) {
+    let service = ConversationService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_operator_actions(Arc::clone(&audit) as Arc<dyn OperatorActionRepository>);
+    let conversation = service.create_conversation(&ctx).await.expect("create");
+
+    service
+        .pause_conversation(&ctx, conversation.id(), &reason())
+        .await
+        .expect("pause");
+    service
+        .resume_conversation(&ctx, conversation.id(), &reason())
+        .await
+        .expect("resume");
+    let refused = service
+        .resume_conversation(&ctx, conversation.id(), &reason())
+        .await;
+
+    let timeline = audit
+        .query(
+            &ctx,
+            OperatorActionQuery::for_conversation(conversation.id()),
+            PageRequest::default(),
+        )
+        .await
+        .expect("timeline");
+    let recorded: Vec<_> = timeline
+        .iter()
+        .map(|action| (action.kind, action.transition.clone()))
+        .collect();
+    assert!(refused.is_err());
+    assert_eq!(
+        recorded,
+        [
+            (
+                OperatorActionKind::ConversationPaused,
+                Some(OperatorTransition::new("active", "paused")),
+            ),
+            (
+                OperatorActionKind::ConversationResumed,
+                Some(OperatorTransition::new("paused", "active")),
+            ),
+        ]
+    );
+    assert!(timeline.iter().all(|action| action.actor == ctx.user_id()));
+}
+
+#[rstest]
+#[tokio::test]
+async fn forced_task_transitions_bypass_lifecycle_rules_and_are_recorded(
+    ctx: RequestContext,
+    audit: Arc<InMemoryOperatorActionRepository>,
+) {
+    let service = TaskLifecycleService::new(
+        Arc::new(InMemoryTaskRepository::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_operator_actions(Arc::clone(&audit) as Arc<dyn OperatorActionRepository>);
+    let task = service
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "owner/repo", 7, "Fix flaky test"),
+        )
+        .await
+        .expect("create");
+    service
+        .transition_task(&ctx, TransitionTaskRequest::new(task.id(), "abandoned"))
+        .await
+        .expect("abandon");
+
+    let reopened = service
+        .force_transition_task(
+            &ctx,
+            TransitionTaskRequest::new(task.id(), "in_progress"),
+            &reason(),
+        )
+        .await
+        .expect("forced transition");
+    let repeated = service
+        .force_transition_task(
+            &ctx,
+            TransitionTaskRequest::new(task.id(), "in_progress"),
+            &reason(),
+        )
+        .await;
+
+    assert_eq!(reopened.state(), TaskState::InProgress);
+    assert!(matches!(
+        repeated,
+        Err(TaskLifecycleError::Domain(
+            TaskDomainError::InvalidStateTransition { .. }
+        ))
+    ));
+    let timeline = audit
+        .query(
+            &ctx,
+            OperatorActionQuery::for_task(task.id()),
+            PageRequest::default(),
+        )
+        .await
+        .expect("timeline");
+    let [action] = timeline.items() else {
+        panic!("expected one recorded action, got {}", timeline.len());
+    };
+    assert_eq!(action.kind, OperatorActionKind::TaskForceTransitioned);
+    assert_eq!(action.subject, OperatorSubject::Task { task_id: task.id() });
+    assert_eq!(
+        action.transition,
+        Some(OperatorTransition::new("abandoned", "in_progress"))
+    );
+    assert_eq!(action.reason, reason());
+}
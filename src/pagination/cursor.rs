@@ -0,0 +1,156 @@
+//! Keyset cursors and the seek positions adapters derive from them.
+
+use super::PaginationError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Sort value recorded in a [`Cursor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CursorKey {
+    /// A numeric sort value, such as a message sequence number.
+    Number(i64),
+    /// A timestamp sort value, such as a creation time.
+    Timestamp(DateTime<Utc>),
+}
+
+/// Opaque keyset position after the last item of a page.
+///
+/// A cursor pairs the last item's sort value with its identifier, which
+/// breaks ties between items sharing a sort value.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::pagination::Cursor;
+/// use uuid::Uuid;
+///
+/// let cursor = Cursor::at_number(100, Uuid::nil());
+/// let token = cursor.to_string();
+/// assert_eq!(token.parse::<Cursor>(), Ok(cursor));
+/// assert!("not-a-cursor".parse::<Cursor>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Cursor {
+    key: CursorKey,
+    id: Uuid,
+}
+
+impl Cursor {
+    const NUMBER_PREFIX: &'static str = "n";
+    const TIMESTAMP_PREFIX: &'static str = "t";
+
+    /// Creates the position of an item ordered by a number.
+    #[must_use]
+    pub const fn at_number(value: i64, id: Uuid) -> Self {
+        Self {
+            key: CursorKey::Number(value),
+            id,
+        }
+    }
+
+    /// Creates the position of an item ordered by a timestamp.
+    #[must_use]
+    pub const fn at_timestamp(value: DateTime<Utc>, id: Uuid) -> Self {
+        Self {
+            key: CursorKey::Timestamp(value),
+            id,
+        }
+    }
+
+    /// Returns the recorded sort value.
+    #[must_use]
+    pub const fn key(self) -> CursorKey {
+        self.key
+    }
+
+    /// Returns the identifier of the recorded item.
+    #[must_use]
+    pub const fn id(self) -> Uuid {
+        self.id
+    }
+
+    /// Orders this position against `other`, or returns `None` when the two
+    /// record different kinds of sort value.
+    #[must_use]
+    pub fn compare(self, other: Self) -> Option<Ordering> {
+        let by_key = match (self.key, other.key) {
+            (CursorKey::Number(left), CursorKey::Number(right)) => left.cmp(&right),
+            (CursorKey::Timestamp(left), CursorKey::Timestamp(right)) => left.cmp(&right),
+            _ => return None,
+        };
+        Some(by_key.then_with(|| self.id.cmp(&other.id)))
+    }
+
+    fn parse_key(token: &str) -> Option<CursorKey> {
+        if let Some(number) = token.strip_prefix(Self::NUMBER_PREFIX) {
+            return number.parse().ok().map(CursorKey::Number);
+        }
+        let timestamp = token.strip_prefix(Self::TIMESTAMP_PREFIX)?;
+        let (seconds, nanos) = timestamp.split_once('.')?;
+        DateTime::from_timestamp(seconds.parse().ok()?, nanos.parse().ok()?)
+            .map(CursorKey::Timestamp)
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.key {
+            CursorKey::Number(value) => write!(f, "{}{value}", Self::NUMBER_PREFIX)?,
+            CursorKey::Timestamp(value) => write!(
+                f,
+                "{}{}.{}",
+                Self::TIMESTAMP_PREFIX,
+                value.timestamp(),
+                value.timestamp_subsec_nanos()
+            )?,
+        }
+        write!(f, "_{}", self.id.simple())
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = PaginationError;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        token
+            .split_once('_')
+            .and_then(|(key, id)| {
+                Some(Self {
+                    key: Self::parse_key(key)?,
+                    id: Uuid::try_parse(id).ok()?,
+                })
+            })
+            .ok_or_else(|| PaginationError::InvalidCursor(token.to_owned()))
+    }
+}
+
+impl TryFrom<String> for Cursor {
+    type Error = PaginationError;
+
+    fn try_from(token: String) -> Result<Self, Self::Error> {
+        token.parse()
+    }
+}
+
+impl From<Cursor> for String {
+    fn from(cursor: Cursor) -> Self {
+        cursor.to_string()
+    }
+}
+
+/// Where a listing resumes, as seen by the adapter serving it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seek<K> {
+    /// No cursor was given, so the listing starts at its first item.
+    Start,
+    /// The listing resumes strictly after this sort value and identifier.
+    After(K, Uuid),
+    /// The cursor was issued by a listing with a different kind of sort
+    /// value, so no item follows it.
+    Foreign,
+}
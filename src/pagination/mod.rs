@@ -0,0 +1,225 @@
+//! Shared pagination and sorting primitives for list and query ports.
+//!
+//! Every port method that lists a collection takes a [`PageRequest`] and
+//! returns a [`Page`], so no query can return an unbounded result. A request
+//! names a [`Limit`], a [`SortOrder`] relative to the method's natural
+//! ordering, and optionally the [`Cursor`] returned with the previous page.
+//!
+//! Pages are keyset-paginated: a [`Cursor`] records the sort value and
+//! identifier of the last item returned, and the next page starts strictly
+//! after that position. Inserts and deletes between requests therefore never
+//! repeat or skip items, and storage seeks straight to the position instead
+//! of counting past an offset.
+//!
+//! Cursors are opaque to callers: pass back the cursor from
+//! [`Page::next_cursor`] unchanged to continue a listing. Callers that
+//! genuinely need a whole collection page through it with [`collect_pages`].
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+mod cursor;
+mod page;
+
+pub use cursor::{Cursor, CursorKey, Seek};
+pub use page::{Page, collect_pages};
+
+/// Items per page when the caller does not choose a limit.
+pub const DEFAULT_PAGE_LIMIT: u32 = 50;
+
+/// Largest number of items a single page may hold.
+pub const MAX_PAGE_LIMIT: u32 = 500;
+
+/// Errors raised when building pagination requests.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PaginationError {
+    /// The limit is zero or above [`MAX_PAGE_LIMIT`].
+    #[error("page limit must be between 1 and {MAX_PAGE_LIMIT}, got {0}")]
+    InvalidLimit(u32),
+    /// The cursor token was not issued by a previous page.
+    #[error("invalid page cursor: {0}")]
+    InvalidCursor(String),
+}
+
+/// Maximum number of items in a page, between 1 and [`MAX_PAGE_LIMIT`].
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::pagination::{Limit, MAX_PAGE_LIMIT};
+///
+/// assert_eq!(Limit::new(20).map(Limit::get), Ok(20));
+/// assert!(Limit::new(0).is_err());
+/// assert!(Limit::new(MAX_PAGE_LIMIT + 1).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct Limit(u32);
+
+impl Limit {
+    /// The largest permitted limit.
+    pub const MAX: Self = Self(MAX_PAGE_LIMIT);
+
+    /// Creates a limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PaginationError::InvalidLimit`] when `value` is zero or
+    /// above [`MAX_PAGE_LIMIT`].
+    pub const fn new(value: u32) -> Result<Self, PaginationError> {
+        if value == 0 || value > MAX_PAGE_LIMIT {
+            return Err(PaginationError::InvalidLimit(value));
+        }
+        Ok(Self(value))
+    }
+
+    /// Returns the limit as a number of items.
+    #[must_use]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl Default for Limit {
+    fn default() -> Self {
+        Self(DEFAULT_PAGE_LIMIT)
+    }
+}
+
+impl TryFrom<u32> for Limit {
+    type Error = PaginationError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Limit> for u32 {
+    fn from(limit: Limit) -> Self {
+        limit.0
+    }
+}
+
+/// Direction a listing is returned in, relative to the port method's
+/// documented natural order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    /// The method's natural order, such as oldest first.
+    #[default]
+    Ascending,
+    /// The reverse of the natural order.
+    Descending,
+}
+
+/// A request for one page of a listing.
+///
+/// The default request asks for the first [`DEFAULT_PAGE_LIMIT`] items in
+/// natural order. Fields missing from a deserialized request take their
+/// defaults, so a query string such as `?limit=20&order=descending` is a
+/// valid request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PageRequest {
+    limit: Limit,
+    cursor: Option<Cursor>,
+    order: SortOrder,
+}
+
+impl PageRequest {
+    /// Requests the first page of at most `limit` items in natural order.
+    #[must_use]
+    pub const fn new(limit: Limit) -> Self {
+        Self {
+            limit,
+            cursor: None,
+            order: SortOrder::Ascending,
+        }
+    }
+
+    /// Continues the listing from `cursor`.
+    #[must_use]
+    pub const fn with_cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Returns the listing in `order`.
+    #[must_use]
+    pub const fn with_order(mut self, order: SortOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Returns the maximum number of items in the page.
+    #[must_use]
+    pub const fn limit(&self) -> Limit {
+        self.limit
+    }
+
+    /// Returns the cursor the page continues from, if any.
+    #[must_use]
+    pub const fn cursor(&self) -> Option<Cursor> {
+        self.cursor
+    }
+
+    /// Returns the requested order.
+    #[must_use]
+    pub const fn order(&self) -> SortOrder {
+        self.order
+    }
+
+    /// Returns where a listing ordered by timestamp resumes.
+    #[must_use]
+    pub const fn after_timestamp(&self) -> Seek<DateTime<Utc>> {
+        match self.cursor {
+            None => Seek::Start,
+            Some(cursor) => match cursor.key() {
+                CursorKey::Timestamp(value) => Seek::After(value, cursor.id()),
+                CursorKey::Number(_) => Seek::Foreign,
+            },
+        }
+    }
+
+    /// Returns where a listing ordered by a number, such as a sequence
+    /// number, resumes.
+    #[must_use]
+    pub const fn after_number(&self) -> Seek<i64> {
+        match self.cursor {
+            None => Seek::Start,
+            Some(cursor) => match cursor.key() {
+                CursorKey::Number(value) => Seek::After(value, cursor.id()),
+                CursorKey::Timestamp(_) => Seek::Foreign,
+            },
+        }
+    }
+
+    /// Returns `true` when an item at `position` lies beyond the request's
+    /// cursor in the requested order.
+    ///
+    /// Every item lies beyond a missing cursor; none lies beyond a cursor
+    /// issued by a listing with a different kind of sort value.
+    #[must_use]
+    pub fn is_past_cursor(&self, position: Cursor) -> bool {
+        let Some(cursor) = self.cursor else {
+            return true;
+        };
+        match (position.compare(cursor), self.order) {
+            (Some(ordering), SortOrder::Ascending) => ordering.is_gt(),
+            (Some(ordering), SortOrder::Descending) => ordering.is_lt(),
+            (None, _) => false,
+        }
+    }
+
+    /// Returns the SQL `LIMIT` for fetching the page: one more row than the
+    /// page holds, so [`Page::from_overfetched`] can tell whether another
+    /// page follows.
+    #[must_use]
+    pub fn sql_fetch_limit(&self) -> i64 {
+        i64::from(self.limit.get()) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests;
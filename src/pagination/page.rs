@@ -0,0 +1,192 @@
+//! Pages of listing results and whole-listing collection.
+
+use super::{Cursor, Limit, PageRequest, SortOrder};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::ops::Deref;
+
+/// One page of a listing.
+///
+/// A page dereferences to a slice of its items, so it can be iterated and
+/// inspected like the `Vec` that list methods used to return.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::pagination::{Cursor, Limit, Page, PageRequest, SortOrder};
+/// use uuid::Uuid;
+///
+/// let position = |item: &i64| Cursor::at_number(*item, Uuid::nil());
+/// let request = PageRequest::new(Limit::new(2)?).with_order(SortOrder::Descending);
+/// let first = Page::from_ordered(vec![1, 2, 3], request, position);
+/// assert_eq!(first.items(), &[3, 2]);
+///
+/// let cursor = first.next_cursor().expect("more items follow");
+/// let second = Page::from_ordered(vec![1, 2, 3], request.with_cursor(cursor), position);
+/// assert_eq!(second.items(), &[1]);
+/// assert!(second.is_last());
+/// # Ok::<(), corbusier::pagination::PaginationError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Page<T> {
+    items: Vec<T>,
+    next_cursor: Option<Cursor>,
+}
+
+impl<T> Page<T> {
+    /// Creates a page from its items and the cursor of the following page.
+    #[must_use]
+    pub const fn new(items: Vec<T>, next_cursor: Option<Cursor>) -> Self {
+        Self { items, next_cursor }
+    }
+
+    /// Creates the final page of a listing.
+    #[must_use]
+    pub const fn last(items: Vec<T>) -> Self {
+        Self::new(items, None)
+    }
+
+    /// Builds a page from items fetched in the requested order, starting
+    /// after the request's cursor, with up to
+    /// [`PageRequest::sql_fetch_limit`] items.
+    ///
+    /// `position` gives an item's keyset position; the next cursor is the
+    /// position of the page's last item.
+    #[must_use]
+    pub fn from_overfetched(
+        mut items: Vec<T>,
+        request: PageRequest,
+        position: impl Fn(&T) -> Cursor,
+    ) -> Self {
+        let limit = usize::try_from(request.limit.get()).unwrap_or(usize::MAX);
+        if items.len() <= limit {
+            return Self::last(items);
+        }
+        items.truncate(limit);
+        let next = items.last().map(position);
+        Self::new(items, next)
+    }
+
+    /// Builds a page from a whole listing held in natural order, applying
+    /// the request's order, cursor, and limit.
+    ///
+    /// `position` gives an item's keyset position and must increase along
+    /// the natural order.
+    #[must_use]
+    pub fn from_ordered(
+        mut items: Vec<T>,
+        request: PageRequest,
+        position: impl Fn(&T) -> Cursor,
+    ) -> Self {
+        if request.order == SortOrder::Descending {
+            items.reverse();
+        }
+        let window = usize::try_from(request.sql_fetch_limit()).unwrap_or(usize::MAX);
+        let fetched = items
+            .into_iter()
+            .filter(|item| request.is_past_cursor(position(item)))
+            .take(window)
+            .collect();
+        Self::from_overfetched(fetched, request, position)
+    }
+
+    /// Returns the page's items.
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Consumes the page, returning its items.
+    #[must_use]
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Returns the cursor of the following page, or `None` on the last page.
+    #[must_use]
+    pub const fn next_cursor(&self) -> Option<Cursor> {
+        self.next_cursor
+    }
+
+    /// Returns `true` when no page follows this one.
+    #[must_use]
+    pub const fn is_last(&self) -> bool {
+        self.next_cursor.is_none()
+    }
+
+    /// Converts the page's items, keeping its cursor.
+    #[must_use]
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page::new(self.items.into_iter().map(f).collect(), self.next_cursor)
+    }
+
+    /// Converts the page's items with a fallible conversion, keeping its
+    /// cursor.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first conversion error.
+    pub fn try_map<U, E>(self, f: impl FnMut(T) -> Result<U, E>) -> Result<Page<U>, E> {
+        let items = self.items.into_iter().map(f).collect::<Result<_, _>>()?;
+        Ok(Page::new(items, self.next_cursor))
+    }
+}
+
+impl<T> Default for Page<T> {
+    fn default() -> Self {
+        Self::last(Vec::new())
+    }
+}
+
+impl<T> Deref for Page<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'page, T> IntoIterator for &'page Page<T> {
+    type Item = &'page T;
+    type IntoIter = std::slice::Iter<'page, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+/// Pages through a whole listing, [`Limit::MAX`] items at a time.
+///
+/// Reserve this for workflows that need every item, such as rebuilding a
+/// summary or routing across all active backends; user-facing listings
+/// should hand pages to their callers instead.
+///
+/// # Errors
+///
+/// Returns the first error raised while fetching a page.
+pub async fn collect_pages<T, E, F, Fut>(mut fetch: F) -> Result<Vec<T>, E>
+where
+    F: FnMut(PageRequest) -> Fut,
+    Fut: Future<Output = Result<Page<T>, E>>,
+{
+    let mut request = PageRequest::new(Limit::MAX);
+    let mut items = Vec::new();
+    loop {
+        let page = fetch(request).await?;
+        let next_cursor = page.next_cursor();
+        items.extend(page.into_items());
+        let Some(cursor) = next_cursor else {
+            return Ok(items);
+        };
+        request = request.with_cursor(cursor);
+    }
+}
@@ -0,0 +1,134 @@
+//! Unit tests for the shared pagination primitives.
+
+use super::{
+    Cursor, DEFAULT_PAGE_LIMIT, Limit, Page, PageRequest, PaginationError, Seek, SortOrder,
+    collect_pages,
+};
+use chrono::{DateTime, Utc};
+use rstest::rstest;
+use uuid::Uuid;
+
+fn request(limit: u32) -> PageRequest {
+    PageRequest::new(Limit::new(limit).expect("valid limit"))
+}
+
+fn at(item: u32) -> Cursor {
+    Cursor::at_number(i64::from(item), Uuid::nil())
+}
+
+fn position(item: &u32) -> Cursor {
+    at(*item)
+}
+
+#[rstest]
+fn default_request_asks_for_the_first_page_in_natural_order() {
+    let request = PageRequest::default();
+
+    assert_eq!(request.limit().get(), DEFAULT_PAGE_LIMIT);
+    assert_eq!(request.cursor(), None);
+    assert_eq!(request.order(), SortOrder::Ascending);
+    assert_eq!(request.after_number(), Seek::Start);
+    assert_eq!(request.sql_fetch_limit(), 51);
+}
+
+#[rstest]
+#[case::first_page(request(2), &[1, 2], Some(2))]
+#[case::middle_page(request(2).with_cursor(at(2)), &[3, 4], Some(4))]
+#[case::last_page(request(2).with_cursor(at(4)), &[5], None)]
+#[case::past_the_end(request(2).with_cursor(at(9)), &[], None)]
+#[case::exact_fit(request(5), &[1, 2, 3, 4, 5], None)]
+#[case::descending(request(2).with_order(SortOrder::Descending), &[5, 4], Some(4))]
+#[case::descending_resumed(
+    request(2).with_order(SortOrder::Descending).with_cursor(at(4)),
+    &[3, 2],
+    Some(2)
+)]
+fn ordered_listings_are_windowed_by_the_request(
+    #[case] request: PageRequest,
+    #[case] expected: &[u32],
+    #[case] next_item: Option<u32>,
+) {
+    let page = Page::from_ordered(vec![1, 2, 3, 4, 5], request, position);
+
+    assert_eq!(page.items(), expected);
+    assert_eq!(page.next_cursor(), next_item.map(at));
+}
+
+#[rstest]
+fn listings_resume_after_the_cursor_when_items_are_removed() {
+    let first = Page::from_ordered(vec![1, 2, 3, 4, 5], request(2), position);
+    let cursor = first.next_cursor().expect("more items follow");
+
+    let second = Page::from_ordered(vec![3, 4, 5], request(2).with_cursor(cursor), position);
+
+    assert_eq!(second.items(), &[3, 4]);
+}
+
+#[rstest]
+fn ties_on_the_sort_value_are_broken_by_identifier() {
+    let at_noon = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).expect("valid timestamp");
+    let ids = [Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(3)];
+    let stamped = |id: &Uuid| Cursor::at_timestamp(at_noon, *id);
+
+    let first = Page::from_ordered(ids.to_vec(), request(2), stamped);
+    let cursor = first.next_cursor().expect("more items follow");
+    let second = Page::from_ordered(ids.to_vec(), request(2).with_cursor(cursor), stamped);
+
+    assert_eq!(second.items(), &[Uuid::from_u128(3)]);
+    assert_eq!(
+        request(2).with_cursor(cursor).after_timestamp(),
+        Seek::After(at_noon, Uuid::from_u128(2))
+    );
+}
+
+#[rstest]
+fn cursors_from_another_kind_of_listing_match_nothing() {
+    let stamped = Cursor::at_timestamp(Utc::now(), Uuid::nil());
+    let page = Page::from_ordered(vec![1, 2, 3], request(2).with_cursor(stamped), position);
+
+    assert!(page.is_empty());
+    assert_eq!(
+        request(2).with_cursor(stamped).after_number(),
+        Seek::Foreign
+    );
+}
+
+#[rstest]
+#[case::number(Cursor::at_number(-42, Uuid::from_u128(7)))]
+#[case::timestamp(Cursor::at_timestamp(
+    DateTime::<Utc>::from_timestamp(1_700_000_000, 123_456_789).expect("valid timestamp"),
+    Uuid::from_u128(7)
+))]
+fn cursor_tokens_round_trip(#[case] cursor: Cursor) {
+    assert_eq!(cursor.to_string().parse::<Cursor>(), Ok(cursor));
+}
+
+#[rstest]
+#[case::bare_number("42")]
+#[case::offset_token("o42")]
+#[case::missing_identifier("n42")]
+#[case::malformed_timestamp("tnoon_00000000000000000000000000000000")]
+fn cursors_reject_foreign_tokens(#[case] token: &str) {
+    assert_eq!(
+        token.parse::<Cursor>(),
+        Err(PaginationError::InvalidCursor(token.to_owned()))
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn collect_pages_follows_cursors_to_the_end() {
+    let all: Vec<u32> = (1..=1_200).collect();
+    let mut fetches = 0;
+
+    let collected = collect_pages(|request| {
+        fetches += 1;
+        let page = Page::from_ordered(all.clone(), request, position);
+        async move { Ok::<_, PaginationError>(page) }
+    })
+    .await
+    .expect("pages collected");
+
+    assert_eq!(collected, all);
+    assert_eq!(fetches, 3);
+}
@@ -2,11 +2,23 @@
 //!
 //! This facade keeps bounded contexts from depending directly on another
 //! adapter module's internal layout while still reusing the canonical tenant
-//! transaction and blocking-execution helpers.
+//! transaction and blocking-execution helpers, and gives every adapter the
+//! same `SELECT 1` health check.
 
-use diesel::PgConnection;
+use crate::context::RequestContext;
+use crate::health::{ComponentHealth, PoolStatistics, report::DEFAULT_CHECK_TIMEOUT};
 use diesel::r2d2::{ConnectionManager, Pool, PoolError, PooledConnection};
+use diesel::{PgConnection, RunQueryDsl};
 
+pub(crate) use crate::message::adapters::postgres::blob_references::{
+    delete_blobs, load_blobs, message_blobs_query, unreferenced_blobs_query,
+};
+pub(crate) use crate::message::adapters::postgres::keyset::keyset_page;
+pub(crate) use crate::message::adapters::postgres::redaction::redact_message;
+pub(crate) use crate::message::adapters::postgres::sealing::{MessageCodec, RowScope};
+pub(crate) use crate::message::adapters::postgres::sql_helpers::{
+    scrub_audit_values, set_audit_context,
+};
 pub(crate) use crate::message::adapters::postgres::tenant_tx::{
     FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
 };
@@ -18,6 +30,9 @@ pub type PgPool = Pool<ConnectionManager<PgConnection>>;
 pub(crate) type PooledConn = PooledConnection<ConnectionManager<PgConnection>>;
 
 /// Runs a blocking task and maps join errors into the caller's error type.
+///
+/// The task keeps the caller's tracing span and ambient request context, so
+/// transactions it opens are attributed to the calling request.
 pub(crate) async fn run_blocking_with<F, T, E, M>(f: F, map_err: M) -> Result<T, E>
 where
     F: FnOnce() -> Result<T, E> + Send + 'static,
@@ -25,7 +40,11 @@ where
     E: Send + 'static,
     M: FnOnce(tokio::task::JoinError) -> E,
 {
-    tokio::task::spawn_blocking(f).await.map_err(map_err)?
+    let ctx = RequestContext::current();
+    let span = tracing::Span::current();
+    tokio::task::spawn_blocking(move || span.in_scope(|| RequestContext::scope_blocking(ctx, f)))
+        .await
+        .map_err(map_err)?
 }
 
 /// Obtains a connection from the pool with a caller-provided error mapper.
@@ -35,3 +54,57 @@ where
 {
     pool.get().map_err(map_err)
 }
+
+/// Checks `pool` with `SELECT 1` and reports its connection counts under
+/// `component`.
+///
+/// The counts are taken before the check borrows a connection, and waiting
+/// for a connection gives up after the readiness probe's default timeout.
+pub(crate) async fn check_pool_health(component: &str, pool: &PgPool) -> ComponentHealth {
+    let state = pool.state();
+    let statistics = PoolStatistics {
+        connections: state.connections,
+        idle_connections: state.idle_connections,
+        max_size: pool.max_size(),
+    };
+    let probe_pool = pool.clone();
+    let outcome = run_blocking_with(
+        move || {
+            let mut conn = probe_pool
+                .get_timeout(DEFAULT_CHECK_TIMEOUT)
+                .map_err(|err| err.to_string())?;
+            diesel::sql_query("SELECT 1")
+                .execute(&mut conn)
+                .map(drop)
+                .map_err(|err| err.to_string())
+        },
+        |err| err.to_string(),
+    )
+    .await;
+    match outcome {
+        Ok(()) => ComponentHealth::healthy(component),
+        Err(reason) => ComponentHealth::unhealthy(component, reason),
+    }
+    .with_pool(statistics)
+}
+
+/// Implements [`ComponentHealthCheck`] for a Postgres adapter whose `pool`
+/// field holds a [`PgPool`], reporting under `$component`.
+///
+/// [`ComponentHealthCheck`]: crate::health::ComponentHealthCheck
+macro_rules! pool_health_check {
+    ($adapter:ty, $component:literal) => {
+        #[async_trait::async_trait]
+        impl $crate::health::ComponentHealthCheck for $adapter {
+            fn component(&self) -> &str {
+                $component
+            }
+
+            async fn check_health(&self) -> $crate::health::ComponentHealth {
+                $crate::postgres_support::check_pool_health($component, &self.pool).await
+            }
+        }
+    };
+}
+
+pub(crate) use pool_health_check;
@@ -0,0 +1,79 @@
+//! Per-aggregate activity read model.
+
+use crate::projection::{
+    domain::{AggregateActivity, ProjectionName, RecordedEvent},
+    ports::{Projection, ProjectionError, ProjectionResult},
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+type ActivityKey = (String, Uuid);
+
+fn lock_error(err: impl std::fmt::Display) -> ProjectionError {
+    ProjectionError::failed(std::io::Error::other(err.to_string()))
+}
+
+/// Projection counting the events recorded against each aggregate, such as
+/// the turns of an agent session or the messages of a conversation.
+///
+/// The read model is held in memory, so it is empty after a restart even
+/// when its checkpoint is persisted; call
+/// [`crate::projection::services::ProjectionRunner::rebuild`] for it on
+/// start-up. Clones share the same read model.
+#[derive(Debug, Clone)]
+pub struct AggregateActivityProjection {
+    name: ProjectionName,
+    activity: Arc<RwLock<HashMap<ActivityKey, AggregateActivity>>>,
+}
+
+impl AggregateActivityProjection {
+    /// Creates an empty projection whose checkpoint is stored under `name`.
+    #[must_use]
+    pub fn new(name: ProjectionName) -> Self {
+        Self {
+            name,
+            activity: Arc::default(),
+        }
+    }
+
+    /// Returns the activity recorded against an aggregate, or `None` when
+    /// no event has been applied for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError::Failed`] when the read model's lock is
+    /// poisoned.
+    pub fn get(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: Uuid,
+    ) -> ProjectionResult<Option<AggregateActivity>> {
+        let activity = self.activity.read().map_err(lock_error)?;
+        Ok(activity
+            .get(&(aggregate_type.to_owned(), aggregate_id))
+            .cloned())
+    }
+}
+
+#[async_trait]
+impl Projection for AggregateActivityProjection {
+    fn name(&self) -> &ProjectionName {
+        &self.name
+    }
+
+    async fn apply(&self, event: &RecordedEvent) -> ProjectionResult<()> {
+        let mut activity = self.activity.write().map_err(lock_error)?;
+        activity
+            .entry((event.aggregate_type.clone(), event.aggregate_id))
+            .and_modify(|summary| summary.record(event))
+            .or_insert_with(|| AggregateActivity::first(event));
+        Ok(())
+    }
+
+    async fn reset(&self) -> ProjectionResult<()> {
+        self.activity.write().map_err(lock_error)?.clear();
+        Ok(())
+    }
+}
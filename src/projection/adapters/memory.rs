@@ -0,0 +1,101 @@
+//! In-memory event stream and checkpoint store.
+
+use crate::projection::{
+    domain::{EventPosition, ProjectionCheckpoint, ProjectionName, RecordedEvent},
+    ports::{
+        DomainEventSource, ProjectionCheckpointStore, ProjectionStoreError, ProjectionStoreResult,
+    },
+};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+fn lock_error(err: impl std::fmt::Display) -> ProjectionStoreError {
+    ProjectionStoreError::persistence_failed(std::io::Error::other(err.to_string()))
+}
+
+/// Thread-safe in-memory domain event stream.
+///
+/// Clones share the same stream, standing in for the `domain_events` table.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDomainEventLog {
+    events: Arc<RwLock<Vec<RecordedEvent>>>,
+}
+
+impl InMemoryDomainEventLog {
+    /// Creates an empty stream.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `event`, assigning it the next position, and returns the
+    /// stored event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionStoreError::PersistenceFailed`] when the stream's
+    /// lock is poisoned.
+    pub fn append(&self, mut event: RecordedEvent) -> ProjectionStoreResult<RecordedEvent> {
+        let mut events = self.events.write().map_err(lock_error)?;
+        event.position = events
+            .last()
+            .map_or(EventPosition::START, |last| last.position)
+            .next();
+        events.push(event.clone());
+        Ok(event)
+    }
+}
+
+#[async_trait]
+impl DomainEventSource for InMemoryDomainEventLog {
+    async fn read_after(
+        &self,
+        after: EventPosition,
+        limit: usize,
+    ) -> ProjectionStoreResult<Vec<RecordedEvent>> {
+        let events = self.events.read().map_err(lock_error)?;
+        Ok(events
+            .iter()
+            .filter(|event| event.position > after)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
+
+/// Thread-safe in-memory projection checkpoint store.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryProjectionCheckpointStore {
+    checkpoints: Arc<RwLock<BTreeMap<ProjectionName, ProjectionCheckpoint>>>,
+}
+
+impl InMemoryProjectionCheckpointStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ProjectionCheckpointStore for InMemoryProjectionCheckpointStore {
+    async fn load(
+        &self,
+        projection: &ProjectionName,
+    ) -> ProjectionStoreResult<Option<ProjectionCheckpoint>> {
+        let checkpoints = self.checkpoints.read().map_err(lock_error)?;
+        Ok(checkpoints.get(projection).cloned())
+    }
+
+    async fn save(&self, checkpoint: &ProjectionCheckpoint) -> ProjectionStoreResult<()> {
+        let mut checkpoints = self.checkpoints.write().map_err(lock_error)?;
+        checkpoints.insert(checkpoint.projection.clone(), checkpoint.clone());
+        Ok(())
+    }
+
+    async fn list(&self) -> ProjectionStoreResult<Vec<ProjectionCheckpoint>> {
+        let checkpoints = self.checkpoints.read().map_err(lock_error)?;
+        Ok(checkpoints.values().cloned().collect())
+    }
+}
@@ -0,0 +1,9 @@
+//! Adapter implementations for the projection ports.
+
+pub mod activity;
+pub mod memory;
+pub mod postgres;
+
+pub use activity::AggregateActivityProjection;
+pub use memory::{InMemoryDomainEventLog, InMemoryProjectionCheckpointStore};
+pub use postgres::{PostgresDomainEventSource, PostgresProjectionCheckpointStore};
@@ -0,0 +1,190 @@
+//! `PostgreSQL` reader for the `domain_events` stream.
+
+use crate::context::{CorrelationId, SessionId, UserId};
+use crate::message::adapters::{models::DomainEventRow, schema::domain_events};
+use crate::message::versioning::{UpgraderRegistry, VersionedEvent};
+use crate::postgres_support::{PgPool, get_conn_with, run_blocking_with};
+use crate::projection::{
+    domain::{EventPosition, RecordedEvent},
+    ports::{DomainEventSource, ProjectionStoreError, ProjectionStoreResult},
+};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::sql_types::{Array, Integer, Jsonb, Uuid as SqlUuid};
+use std::sync::Arc;
+
+/// `PostgreSQL`-backed domain event stream.
+///
+/// Positions come from a sequence drawn while holding a transaction-scoped
+/// advisory lock, so they become visible in order: once an event can be
+/// read, no event at a lower position can still commit.
+///
+/// With an [`UpgraderRegistry`] attached, events stored at an older schema
+/// version are upgraded as they are read, and the upgraded rows are written
+/// back in one statement per read so each row is migrated only once.
+#[derive(Debug, Clone)]
+pub struct PostgresDomainEventSource {
+    pool: PgPool,
+    upgrades: Option<Arc<UpgraderRegistry>>,
+}
+
+crate::postgres_support::pool_health_check!(PostgresDomainEventSource, "domain_event_source");
+
+impl PostgresDomainEventSource {
+    /// Creates a new event source from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            upgrades: None,
+        }
+    }
+
+    /// Upgrades events to their current schema version on read, writing
+    /// the upgraded rows back.
+    #[must_use]
+    pub fn with_upgrades(mut self, registry: Arc<UpgraderRegistry>) -> Self {
+        self.upgrades = Some(registry);
+        self
+    }
+}
+
+#[async_trait]
+impl DomainEventSource for PostgresDomainEventSource {
+    async fn read_after(
+        &self,
+        after: EventPosition,
+        limit: usize,
+    ) -> ProjectionStoreResult<Vec<RecordedEvent>> {
+        let pool = self.pool.clone();
+        let upgrades = self.upgrades.clone();
+        let after = i64::try_from(after.value()).unwrap_or(i64::MAX);
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let rows = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ProjectionStoreError::persistence_failed)?;
+                let stored = domain_events::table
+                    .filter(domain_events::position.gt(after))
+                    .order(domain_events::position.asc())
+                    .limit(limit)
+                    .select(DomainEventRow::as_select())
+                    .load::<DomainEventRow>(&mut conn)
+                    .map_err(ProjectionStoreError::persistence_failed)?;
+                match upgrades {
+                    Some(registry) => upgrade_rows(&mut conn, &registry, stored),
+                    None => Ok(stored),
+                }
+            },
+            ProjectionStoreError::persistence_failed,
+        )
+        .await?;
+        rows.into_iter().map(row_to_event).collect()
+    }
+}
+
+/// Writes back the upgraded payload and version of each row that changed.
+///
+/// Only rows still at the version they were read at are updated, so a
+/// concurrent writer's newer payload is never overwritten.
+const WRITE_BACK_SQL: &str = concat!(
+    "UPDATE domain_events AS stored ",
+    "SET event_data = upgraded.event_data, event_version = upgraded.event_version ",
+    "FROM unnest($1, $2, $3, $4) AS upgraded(id, event_data, event_version, read_version) ",
+    "WHERE stored.id = upgraded.id AND stored.event_version = upgraded.read_version",
+);
+
+/// Upgrades the rows of `rows` that are behind their type's current
+/// version and writes them back.
+fn upgrade_rows(
+    conn: &mut PgConnection,
+    registry: &UpgraderRegistry,
+    mut rows: Vec<DomainEventRow>,
+) -> ProjectionStoreResult<Vec<DomainEventRow>> {
+    let stale: Vec<&mut DomainEventRow> = rows
+        .iter_mut()
+        .filter(|row| {
+            u32::try_from(row.event_version)
+                .is_ok_and(|version| registry.needs_upgrade(&row.event_type, version))
+        })
+        .collect();
+    if stale.is_empty() {
+        return Ok(rows);
+    }
+    let upgraded = registry
+        .upgrade_all(stale.iter().map(|row| {
+            VersionedEvent::new(
+                row.event_version.unsigned_abs(),
+                row.event_type.clone(),
+                row.event_data.clone(),
+            )
+        }))
+        .map_err(|err| ProjectionStoreError::invalid_persisted_data(err.to_string()))?;
+    let mut write_back = WriteBack::default();
+    for (row, event) in stale.into_iter().zip(upgraded) {
+        write_back.record(row, event)?;
+    }
+    write_back.execute(conn)?;
+    Ok(rows)
+}
+
+/// Upgraded rows gathered for a single write-back statement.
+#[derive(Default)]
+struct WriteBack {
+    ids: Vec<uuid::Uuid>,
+    data: Vec<serde_json::Value>,
+    versions: Vec<i32>,
+    read_versions: Vec<i32>,
+}
+
+impl WriteBack {
+    /// Applies `event` to `row` and queues the row for writing back.
+    fn record(
+        &mut self,
+        row: &mut DomainEventRow,
+        event: VersionedEvent,
+    ) -> ProjectionStoreResult<()> {
+        let version = i32::try_from(event.version())
+            .map_err(|err| ProjectionStoreError::invalid_persisted_data(err.to_string()))?;
+        self.ids.push(row.id);
+        self.read_versions.push(row.event_version);
+        self.versions.push(version);
+        row.event_version = version;
+        row.event_data = event.into_data();
+        self.data.push(row.event_data.clone());
+        Ok(())
+    }
+
+    fn execute(self, conn: &mut PgConnection) -> ProjectionStoreResult<()> {
+        diesel::sql_query(WRITE_BACK_SQL)
+            .bind::<Array<SqlUuid>, _>(self.ids)
+            .bind::<Array<Jsonb>, _>(self.data)
+            .bind::<Array<Integer>, _>(self.versions)
+            .bind::<Array<Integer>, _>(self.read_versions)
+            .execute(conn)
+            .map_err(ProjectionStoreError::persistence_failed)?;
+        Ok(())
+    }
+}
+
+/// Converts a stored domain event row into a [`RecordedEvent`].
+pub(crate) fn row_to_event(row: DomainEventRow) -> ProjectionStoreResult<RecordedEvent> {
+    let position = u64::try_from(row.position)
+        .map_err(|err| ProjectionStoreError::invalid_persisted_data(err.to_string()))?;
+    let event_version = u32::try_from(row.event_version)
+        .map_err(|err| ProjectionStoreError::invalid_persisted_data(err.to_string()))?;
+    Ok(RecordedEvent {
+        position: EventPosition::new(position),
+        id: row.id,
+        aggregate_id: row.aggregate_id,
+        aggregate_type: row.aggregate_type,
+        event_type: row.event_type,
+        data: row.event_data,
+        event_version,
+        occurred_at: row.occurred_at,
+        correlation_id: row.correlation_id.map(CorrelationId::from_uuid),
+        causation_id: row.causation_id,
+        user_id: row.user_id.map(UserId::from_uuid),
+        session_id: row.session_id.map(SessionId::from_uuid),
+    })
+}
@@ -0,0 +1,11 @@
+//! `PostgreSQL` adapters for the domain event stream and projection
+//! checkpoints.
+
+mod events;
+mod models;
+mod repository;
+mod schema;
+
+pub use events::PostgresDomainEventSource;
+pub(crate) use events::row_to_event;
+pub use repository::PostgresProjectionCheckpointStore;
@@ -0,0 +1,20 @@
+//! Diesel models for projection checkpoint persistence.
+
+use super::schema::projection_checkpoints;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+/// Row representation of a projection checkpoint.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = projection_checkpoints)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ProjectionCheckpointRow {
+    /// Name the projection's checkpoint is stored under.
+    pub projection_name: String,
+    /// Position of the last applied event.
+    pub position: i64,
+    /// Events applied since the projection was last rebuilt.
+    pub events_applied: i64,
+    /// When the checkpoint last moved.
+    pub updated_at: DateTime<Utc>,
+}
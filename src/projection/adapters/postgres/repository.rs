@@ -0,0 +1,120 @@
+//! `PostgreSQL` store for projection checkpoints.
+
+use super::models::ProjectionCheckpointRow;
+use super::schema::projection_checkpoints;
+use crate::postgres_support::{PgPool, get_conn_with, run_blocking_with};
+use crate::projection::{
+    domain::{EventPosition, ProjectionCheckpoint, ProjectionName},
+    ports::{ProjectionCheckpointStore, ProjectionStoreError, ProjectionStoreResult},
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+
+/// `PostgreSQL`-backed projection checkpoint store.
+///
+/// Checkpoints follow the event stream, which spans every tenant, so they
+/// are not tenant-scoped.
+#[derive(Debug, Clone)]
+pub struct PostgresProjectionCheckpointStore {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresProjectionCheckpointStore,
+    "projection_checkpoint_store"
+);
+
+impl PostgresProjectionCheckpointStore {
+    /// Creates a new store from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProjectionCheckpointStore for PostgresProjectionCheckpointStore {
+    async fn load(
+        &self,
+        projection: &ProjectionName,
+    ) -> ProjectionStoreResult<Option<ProjectionCheckpoint>> {
+        let pool = self.pool.clone();
+        let name = projection.as_str().to_owned();
+        let row = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ProjectionStoreError::persistence_failed)?;
+                projection_checkpoints::table
+                    .find(name)
+                    .select(ProjectionCheckpointRow::as_select())
+                    .first::<ProjectionCheckpointRow>(&mut conn)
+                    .optional()
+                    .map_err(ProjectionStoreError::persistence_failed)
+            },
+            ProjectionStoreError::persistence_failed,
+        )
+        .await?;
+        row.map(row_to_checkpoint).transpose()
+    }
+
+    async fn save(&self, checkpoint: &ProjectionCheckpoint) -> ProjectionStoreResult<()> {
+        let pool = self.pool.clone();
+        let row = to_row(checkpoint)?;
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ProjectionStoreError::persistence_failed)?;
+                diesel::insert_into(projection_checkpoints::table)
+                    .values(&row)
+                    .on_conflict(projection_checkpoints::projection_name)
+                    .do_update()
+                    .set(&row)
+                    .execute(&mut conn)
+                    .map(|_| ())
+                    .map_err(ProjectionStoreError::persistence_failed)
+            },
+            ProjectionStoreError::persistence_failed,
+        )
+        .await
+    }
+
+    async fn list(&self) -> ProjectionStoreResult<Vec<ProjectionCheckpoint>> {
+        let pool = self.pool.clone();
+        let rows = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, ProjectionStoreError::persistence_failed)?;
+                projection_checkpoints::table
+                    .order(projection_checkpoints::projection_name.asc())
+                    .select(ProjectionCheckpointRow::as_select())
+                    .load::<ProjectionCheckpointRow>(&mut conn)
+                    .map_err(ProjectionStoreError::persistence_failed)
+            },
+            ProjectionStoreError::persistence_failed,
+        )
+        .await?;
+        rows.into_iter().map(row_to_checkpoint).collect()
+    }
+}
+
+fn to_row(checkpoint: &ProjectionCheckpoint) -> ProjectionStoreResult<ProjectionCheckpointRow> {
+    let to_i64 =
+        |value: u64| i64::try_from(value).map_err(ProjectionStoreError::persistence_failed);
+    Ok(ProjectionCheckpointRow {
+        projection_name: checkpoint.projection.as_str().to_owned(),
+        position: to_i64(checkpoint.position.value())?,
+        events_applied: to_i64(checkpoint.events_applied)?,
+        updated_at: checkpoint.updated_at,
+    })
+}
+
+fn row_to_checkpoint(row: ProjectionCheckpointRow) -> ProjectionStoreResult<ProjectionCheckpoint> {
+    let invalid =
+        |err: &dyn std::fmt::Display| ProjectionStoreError::invalid_persisted_data(err.to_string());
+    let projection = ProjectionName::new(row.projection_name).map_err(|err| invalid(&err))?;
+    let position = u64::try_from(row.position).map_err(|err| invalid(&err))?;
+    let events_applied = u64::try_from(row.events_applied).map_err(|err| invalid(&err))?;
+    Ok(ProjectionCheckpoint {
+        projection,
+        position: EventPosition::new(position),
+        events_applied,
+        updated_at: row.updated_at,
+    })
+}
@@ -0,0 +1,16 @@
+//! Diesel schema for projection checkpoint persistence.
+
+diesel::table! {
+    /// How far each projection has consumed the domain event stream.
+    projection_checkpoints (projection_name) {
+        /// Name the projection's checkpoint is stored under.
+        #[max_length = 100]
+        projection_name -> Varchar,
+        /// Position of the last applied event.
+        position -> Int8,
+        /// Events applied since the projection was last rebuilt.
+        events_applied -> Int8,
+        /// When the checkpoint last moved.
+        updated_at -> Timestamptz,
+    }
+}
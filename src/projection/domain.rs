@@ -0,0 +1,285 @@
+//! Domain types for event-sourced projections.
+
+use crate::context::{CorrelationId, SessionId, UserId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Maximum length of a projection name, matching the checkpoint column.
+pub const MAX_PROJECTION_NAME_LENGTH: usize = 100;
+
+/// Position of an event in the domain event stream.
+///
+/// Positions are assigned in commit order from `1`; [`Self::START`]
+/// precedes every event and is where a new projection begins reading.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct EventPosition(u64);
+
+impl EventPosition {
+    /// The position before the first event.
+    pub const START: Self = Self(0);
+
+    /// Creates a position from its numeric value.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the numeric value.
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the position immediately after this one.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        Self(self.0.saturating_add(1))
+    }
+}
+
+impl fmt::Display for EventPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Errors returned when a projection name is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ProjectionNameError {
+    /// The name is blank.
+    #[error("projection name must not be empty")]
+    Empty,
+    /// The name exceeds [`MAX_PROJECTION_NAME_LENGTH`] characters.
+    #[error("projection name must be at most {max} characters, got {actual}")]
+    TooLong {
+        /// Maximum permitted length.
+        max: usize,
+        /// Length of the rejected name.
+        actual: usize,
+    },
+}
+
+/// Stable name a projection's checkpoint is stored under, such as
+/// `aggregate_activity`.
+///
+/// Renaming a projection starts it again from the beginning of the stream.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ProjectionName(String);
+
+impl ProjectionName {
+    /// Creates a projection name, trimming surrounding whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionNameError::Empty`] when the name is blank, or
+    /// [`ProjectionNameError::TooLong`] when it exceeds
+    /// [`MAX_PROJECTION_NAME_LENGTH`] characters.
+    pub fn new(value: impl Into<String>) -> Result<Self, ProjectionNameError> {
+        let raw = value.into();
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            return Err(ProjectionNameError::Empty);
+        }
+        let actual = trimmed.chars().count();
+        if actual > MAX_PROJECTION_NAME_LENGTH {
+            return Err(ProjectionNameError::TooLong {
+                max: MAX_PROJECTION_NAME_LENGTH,
+                actual,
+            });
+        }
+        Ok(Self(trimmed.to_owned()))
+    }
+
+    /// Returns the name as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for ProjectionName {
+    type Error = ProjectionNameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<ProjectionName> for String {
+    fn from(value: ProjectionName) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for ProjectionName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A domain event as stored in the event stream.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::projection::domain::{EventPosition, RecordedEvent};
+/// use mockable::{Clock, DefaultClock};
+/// use serde_json::json;
+/// use uuid::Uuid;
+///
+/// let event = RecordedEvent::new("Conversation", Uuid::new_v4(), "MessageAppended", DefaultClock.utc())
+///     .with_data(json!({ "sequence_number": 1 }));
+///
+/// assert_eq!(event.position, EventPosition::START);
+/// assert_eq!(event.event_version, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// Where the event sits in the stream, or [`EventPosition::START`]
+    /// before it is appended.
+    pub position: EventPosition,
+    /// Unique event identifier.
+    pub id: Uuid,
+    /// The aggregate the event applies to.
+    pub aggregate_id: Uuid,
+    /// Type of aggregate, such as `Conversation`.
+    pub aggregate_type: String,
+    /// Type of event, such as `MessageAppended`.
+    pub event_type: String,
+    /// Event payload.
+    pub data: Value,
+    /// Schema version of the payload.
+    pub event_version: u32,
+    /// When the event occurred.
+    pub occurred_at: DateTime<Utc>,
+    /// Correlation identifier of the request that caused the event.
+    pub correlation_id: Option<CorrelationId>,
+    /// Identifier of the event that caused this one.
+    pub causation_id: Option<Uuid>,
+    /// User who caused the event.
+    pub user_id: Option<UserId>,
+    /// Session the event was caused in.
+    pub session_id: Option<SessionId>,
+}
+
+impl RecordedEvent {
+    /// Creates an unappended version-1 event with a null payload.
+    #[must_use]
+    pub fn new(
+        aggregate_type: impl Into<String>,
+        aggregate_id: Uuid,
+        event_type: impl Into<String>,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            position: EventPosition::START,
+            id: Uuid::new_v4(),
+            aggregate_id,
+            aggregate_type: aggregate_type.into(),
+            event_type: event_type.into(),
+            data: Value::Null,
+            event_version: 1,
+            occurred_at,
+            correlation_id: None,
+            causation_id: None,
+            user_id: None,
+            session_id: None,
+        }
+    }
+
+    /// Sets the payload.
+    #[must_use]
+    pub fn with_data(mut self, data: Value) -> Self {
+        self.data = data;
+        self
+    }
+}
+
+/// How far a projection has consumed the event stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectionCheckpoint {
+    /// The projection the checkpoint belongs to.
+    pub projection: ProjectionName,
+    /// Position of the last applied event.
+    pub position: EventPosition,
+    /// Events applied since the projection was last rebuilt.
+    pub events_applied: u64,
+    /// When the checkpoint last moved.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ProjectionCheckpoint {
+    /// Creates a checkpoint for a projection that has applied nothing.
+    #[must_use]
+    pub const fn new(projection: ProjectionName, updated_at: DateTime<Utc>) -> Self {
+        Self {
+            projection,
+            position: EventPosition::START,
+            events_applied: 0,
+            updated_at,
+        }
+    }
+
+    /// Records that the event at `position` was applied.
+    pub const fn advance(&mut self, position: EventPosition) {
+        self.position = position;
+        self.events_applied = self.events_applied.saturating_add(1);
+    }
+}
+
+/// What one run of a projection did.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectionRunReport {
+    /// Events applied during the run.
+    pub applied: u64,
+    /// The projection's checkpoint after the run.
+    pub checkpoint: ProjectionCheckpoint,
+}
+
+/// How much has happened to one aggregate, as built by
+/// [`crate::projection::adapters::AggregateActivityProjection`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregateActivity {
+    /// Type of aggregate.
+    pub aggregate_type: String,
+    /// The aggregate.
+    pub aggregate_id: Uuid,
+    /// Events recorded against the aggregate.
+    pub event_count: u64,
+    /// Type of the most recent event.
+    pub last_event_type: String,
+    /// When the first event occurred.
+    pub first_occurred_at: DateTime<Utc>,
+    /// When the most recent event occurred.
+    pub last_occurred_at: DateTime<Utc>,
+}
+
+impl AggregateActivity {
+    /// Starts the activity of an aggregate from its first event.
+    #[must_use]
+    pub fn first(event: &RecordedEvent) -> Self {
+        Self {
+            aggregate_type: event.aggregate_type.clone(),
+            aggregate_id: event.aggregate_id,
+            event_count: 1,
+            last_event_type: event.event_type.clone(),
+            first_occurred_at: event.occurred_at,
+            last_occurred_at: event.occurred_at,
+        }
+    }
+
+    /// Adds a later event of the same aggregate.
+    pub fn record(&mut self, event: &RecordedEvent) {
+        self.event_count = self.event_count.saturating_add(1);
+        self.last_event_type.clone_from(&event.event_type);
+        self.last_occurred_at = event.occurred_at;
+    }
+}
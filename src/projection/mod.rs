@@ -0,0 +1,23 @@
+//! Event-sourced projections that build read models from domain events.
+//!
+//! Every row appended to the `domain_events` table receives a global
+//! [`domain::EventPosition`]. A [`ports::Projection`] folds events into a
+//! read model, such as per-aggregate activity or conversation summaries, and
+//! [`services::ProjectionRunner`] replays the events after each
+//! projection's checkpoint in position order. Checkpoints are persisted
+//! through a [`ports::ProjectionCheckpointStore`], so a restarted runner
+//! resumes where it stopped and a projection can be rebuilt from the start
+//! at any time. The module follows hexagonal architecture:
+//!
+//! - Domain types in [`domain`]
+//! - Port contracts in [`ports`]
+//! - Adapter implementations in [`adapters`]
+//! - The replaying runner in [`services`]
+
+pub mod adapters;
+pub mod domain;
+pub mod ports;
+pub mod services;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,118 @@
+//! Port contracts for reading the event stream, storing checkpoints, and
+//! building read models.
+
+use crate::projection::domain::{
+    EventPosition, ProjectionCheckpoint, ProjectionName, RecordedEvent,
+};
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type for event source and checkpoint store operations.
+pub type ProjectionStoreResult<T> = Result<T, ProjectionStoreError>;
+
+/// Result type for projection operations.
+pub type ProjectionResult<T> = Result<T, ProjectionError>;
+
+/// Ordered, append-only stream of domain events.
+///
+/// The stream spans every tenant, so the operations take no request
+/// context. Implementations must expose events in position order and must
+/// not expose an event while one at a lower position can still appear.
+#[async_trait]
+pub trait DomainEventSource: Send + Sync {
+    /// Returns up to `limit` events positioned after `after`, oldest first.
+    async fn read_after(
+        &self,
+        after: EventPosition,
+        limit: usize,
+    ) -> ProjectionStoreResult<Vec<RecordedEvent>>;
+}
+
+/// Per-projection progress through the event stream.
+#[async_trait]
+pub trait ProjectionCheckpointStore: Send + Sync {
+    /// Returns the checkpoint for `projection`, or `None` before its first
+    /// run.
+    async fn load(
+        &self,
+        projection: &ProjectionName,
+    ) -> ProjectionStoreResult<Option<ProjectionCheckpoint>>;
+
+    /// Stores a checkpoint, replacing any earlier one for the same
+    /// projection.
+    async fn save(&self, checkpoint: &ProjectionCheckpoint) -> ProjectionStoreResult<()>;
+
+    /// Returns every stored checkpoint, ordered by projection name.
+    async fn list(&self) -> ProjectionStoreResult<Vec<ProjectionCheckpoint>>;
+}
+
+/// A read model built by folding domain events in stream order.
+///
+/// The runner applies each event at most once between rebuilds, and only
+/// after every earlier event has been applied. A projection whose read
+/// model is persisted separately from its checkpoint may see the last
+/// event of an interrupted batch again after a restart, so `apply` should
+/// tolerate repeats of the most recent event.
+#[async_trait]
+pub trait Projection: Send + Sync {
+    /// Returns the name the projection's checkpoint is stored under.
+    fn name(&self) -> &ProjectionName;
+
+    /// Folds `event` into the read model.
+    ///
+    /// Events the projection does not care about should be ignored rather
+    /// than rejected.
+    async fn apply(&self, event: &RecordedEvent) -> ProjectionResult<()>;
+
+    /// Discards the read model before it is rebuilt from the start of the
+    /// stream.
+    async fn reset(&self) -> ProjectionResult<()>;
+}
+
+/// Errors returned by event source and checkpoint store implementations.
+#[derive(Debug, Clone, Error)]
+pub enum ProjectionStoreError {
+    /// Persistence-layer failure.
+    #[error("projection persistence operation failed: {reason}")]
+    PersistenceFailed {
+        /// Human-readable reason from the failing dependency.
+        reason: String,
+    },
+    /// Persisted data failed validation.
+    #[error("invalid persisted projection data: {0}")]
+    InvalidPersistedData(String),
+}
+
+impl ProjectionStoreError {
+    /// Creates a persistence failure from an infrastructure error.
+    pub fn persistence_failed(err: impl std::error::Error) -> Self {
+        Self::PersistenceFailed {
+            reason: err.to_string(),
+        }
+    }
+
+    /// Creates an invalid persisted data error.
+    pub fn invalid_persisted_data(err: impl Into<String>) -> Self {
+        Self::InvalidPersistedData(err.into())
+    }
+}
+
+/// Errors returned by projections while building their read model.
+#[derive(Debug, Clone, Error)]
+pub enum ProjectionError {
+    /// The read model could not be updated.
+    #[error("projection failed: {reason}")]
+    Failed {
+        /// Human-readable reason from the failing dependency.
+        reason: String,
+    },
+}
+
+impl ProjectionError {
+    /// Creates a failure from any error.
+    pub fn failed(err: impl std::error::Error) -> Self {
+        Self::Failed {
+            reason: err.to_string(),
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! Replays the domain event stream into registered projections.
+
+use crate::maintenance::{domain::MaintenanceMode, services::MaintenanceGate};
+use crate::projection::{
+    domain::{
+        EventPosition, ProjectionCheckpoint, ProjectionName, ProjectionRunReport, RecordedEvent,
+    },
+    ports::{
+        DomainEventSource, Projection, ProjectionCheckpointStore, ProjectionError,
+        ProjectionStoreError,
+    },
+};
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Default number of events read from the stream per batch.
+pub const DEFAULT_PROJECTION_BATCH_SIZE: usize = 500;
+
+/// Errors returned while running projections.
+#[derive(Debug, Error)]
+pub enum ProjectionRunError {
+    /// Event source or checkpoint store failure.
+    #[error(transparent)]
+    Store(#[from] ProjectionStoreError),
+    /// A projection could not apply an event.
+    #[error("projection {projection} failed at event {position}: {source}")]
+    Projection {
+        /// The failing projection.
+        projection: ProjectionName,
+        /// Position of the event it could not apply.
+        position: EventPosition,
+        /// The projection's error.
+        #[source]
+        source: ProjectionError,
+    },
+    /// No projection with the given name is registered.
+    #[error("projection not registered: {0}")]
+    UnknownProjection(ProjectionName),
+    /// The cluster is in read-only maintenance mode.
+    #[error(transparent)]
+    MaintenanceMode(#[from] MaintenanceMode),
+}
+
+/// Result type for projection runs.
+pub type ProjectionRunResult<T> = Result<T, ProjectionRunError>;
+
+/// Replays domain events into projections from their saved checkpoints.
+///
+/// Each projection reads the stream independently, in batches, and its
+/// checkpoint is saved after every batch, even one that stops at a failing
+/// event, so the next run resumes with the event that failed. Run at most
+/// one runner per projection at a time; concurrent runners would apply the
+/// same events twice. With a maintenance gate attached, nothing is replayed
+/// while the cluster is in maintenance.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::projection::adapters::{
+///     AggregateActivityProjection, InMemoryDomainEventLog, InMemoryProjectionCheckpointStore,
+/// };
+/// use corbusier::projection::domain::{ProjectionName, RecordedEvent};
+/// use corbusier::projection::services::ProjectionRunner;
+/// use mockable::{Clock, DefaultClock};
+/// use std::sync::Arc;
+/// use uuid::Uuid;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let events = InMemoryDomainEventLog::new();
+/// let conversation_id = Uuid::new_v4();
+/// events.append(RecordedEvent::new("Conversation", conversation_id, "MessageAppended", DefaultClock.utc()))?;
+/// let activity = Arc::new(AggregateActivityProjection::new(ProjectionName::new(
+///     "aggregate_activity",
+/// )?));
+/// let runner = ProjectionRunner::new(
+///     Arc::new(events),
+///     Arc::new(InMemoryProjectionCheckpointStore::new()),
+///     Arc::new(DefaultClock),
+/// )
+/// .with_projection(activity.clone());
+///
+/// runner.catch_up().await?;
+///
+/// let summary = activity.get("Conversation", conversation_id)?;
+/// assert_eq!(summary.map(|summary| summary.event_count), Some(1));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ProjectionRunner<C: Clock + Send + Sync> {
+    events: Arc<dyn DomainEventSource>,
+    checkpoints: Arc<dyn ProjectionCheckpointStore>,
+    projections: Vec<Arc<dyn Projection>>,
+    batch_size: usize,
+    clock: Arc<C>,
+    maintenance: Option<Arc<MaintenanceGate>>,
+}
+
+impl<C: Clock + Send + Sync> ProjectionRunner<C> {
+    /// Creates a runner with no projections that reads
+    /// [`DEFAULT_PROJECTION_BATCH_SIZE`] events per batch.
+    #[must_use]
+    pub fn new(
+        events: Arc<dyn DomainEventSource>,
+        checkpoints: Arc<dyn ProjectionCheckpointStore>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            events,
+            checkpoints,
+            projections: Vec::new(),
+            batch_size: DEFAULT_PROJECTION_BATCH_SIZE,
+            clock,
+            maintenance: None,
+        }
+    }
+
+    /// Registers a projection. Names must be unique within a runner.
+    #[must_use]
+    pub fn with_projection(mut self, projection: Arc<dyn Projection>) -> Self {
+        self.projections.push(projection);
+        self
+    }
+
+    /// Sets how many events are read per batch, at least one.
+    #[must_use]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Refuses to replay events while `gate` reports maintenance.
+    #[must_use]
+    pub fn with_maintenance(mut self, gate: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(gate);
+        self
+    }
+
+    /// Applies every event after each projection's checkpoint, in
+    /// registration order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionRunError::Projection`] when a projection rejects
+    /// an event, [`ProjectionRunError::Store`] when the stream or the
+    /// checkpoints cannot be read or saved, or
+    /// [`ProjectionRunError::MaintenanceMode`] during maintenance.
+    /// Projections registered after the failing one are not run.
+    pub async fn catch_up(&self) -> ProjectionRunResult<Vec<ProjectionRunReport>> {
+        self.ensure_writable().await?;
+        let mut reports = Vec::with_capacity(self.projections.len());
+        for projection in &self.projections {
+            let checkpoint = self
+                .checkpoints
+                .load(projection.name())
+                .await?
+                .unwrap_or_else(|| {
+                    ProjectionCheckpoint::new(projection.name().clone(), self.clock.utc())
+                });
+            reports.push(self.replay(projection.as_ref(), checkpoint).await?);
+        }
+        Ok(reports)
+    }
+
+    /// Discards a projection's read model and replays the whole stream into
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionRunError::UnknownProjection`] when no projection
+    /// named `name` is registered, or the errors of [`Self::catch_up`].
+    pub async fn rebuild(&self, name: &ProjectionName) -> ProjectionRunResult<ProjectionRunReport> {
+        self.ensure_writable().await?;
+        let projection = self
+            .projections
+            .iter()
+            .find(|projection| projection.name() == name)
+            .ok_or_else(|| ProjectionRunError::UnknownProjection(name.clone()))?;
+        projection
+            .reset()
+            .await
+            .map_err(|source| ProjectionRunError::Projection {
+                projection: name.clone(),
+                position: EventPosition::START,
+                source,
+            })?;
+        let checkpoint = ProjectionCheckpoint::new(name.clone(), self.clock.utc());
+        self.checkpoints.save(&checkpoint).await?;
+        self.replay(projection.as_ref(), checkpoint).await
+    }
+
+    /// Returns the stored checkpoint of every projection that has run.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionRunError::Store`] when the checkpoints cannot be
+    /// read.
+    pub async fn checkpoints(&self) -> ProjectionRunResult<Vec<ProjectionCheckpoint>> {
+        Ok(self.checkpoints.list().await?)
+    }
+
+    async fn ensure_writable(&self) -> ProjectionRunResult<()> {
+        if let Some(gate) = self.maintenance.as_deref() {
+            gate.ensure_writable().await?;
+        }
+        Ok(())
+    }
+
+    async fn replay(
+        &self,
+        projection: &dyn Projection,
+        mut checkpoint: ProjectionCheckpoint,
+    ) -> ProjectionRunResult<ProjectionRunReport> {
+        let mut applied: u64 = 0;
+        loop {
+            let events = self
+                .events
+                .read_after(checkpoint.position, self.batch_size)
+                .await?;
+            let before = checkpoint.events_applied;
+            let outcome = apply_events(projection, &events, &mut checkpoint).await;
+            let progressed = checkpoint.events_applied.saturating_sub(before);
+            if progressed > 0 {
+                applied = applied.saturating_add(progressed);
+                checkpoint.updated_at = self.clock.utc();
+                self.checkpoints.save(&checkpoint).await?;
+            }
+            outcome?;
+            if events.len() < self.batch_size {
+                break;
+            }
+        }
+        if applied > 0 {
+            tracing::debug!(
+                projection = %checkpoint.projection,
+                applied,
+                position = %checkpoint.position,
+                "projection caught up"
+            );
+        }
+        Ok(ProjectionRunReport {
+            applied,
+            checkpoint,
+        })
+    }
+}
+
+async fn apply_events(
+    projection: &dyn Projection,
+    events: &[RecordedEvent],
+    checkpoint: &mut ProjectionCheckpoint,
+) -> ProjectionRunResult<()> {
+    for event in events {
+        projection
+            .apply(event)
+            .await
+            .map_err(|source| ProjectionRunError::Projection {
+                projection: projection.name().clone(),
+                position: event.position,
+                source,
+            })?;
+        checkpoint.advance(event.position);
+    }
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Unit tests for projection domain types.
+
+use crate::projection::domain::{
+    AggregateActivity, EventPosition, MAX_PROJECTION_NAME_LENGTH, ProjectionCheckpoint,
+    ProjectionName, ProjectionNameError, RecordedEvent,
+};
+use chrono::{Duration, Utc};
+use rstest::rstest;
+use uuid::Uuid;
+
+#[rstest]
+fn projection_names_are_trimmed() {
+    let name = ProjectionName::new("  aggregate_activity ").expect("valid name");
+
+    assert_eq!(name.as_str(), "aggregate_activity");
+}
+
+#[rstest]
+#[case("", ProjectionNameError::Empty)]
+#[case("   ", ProjectionNameError::Empty)]
+#[case(
+    &"p".repeat(MAX_PROJECTION_NAME_LENGTH + 1),
+    ProjectionNameError::TooLong { max: MAX_PROJECTION_NAME_LENGTH, actual: MAX_PROJECTION_NAME_LENGTH + 1 },
+)]
+fn invalid_projection_names_are_rejected(#[case] raw: &str, #[case] expected: ProjectionNameError) {
+    assert_eq!(ProjectionName::new(raw), Err(expected));
+}
+
+#[rstest]
+fn projection_names_deserialise_through_validation() {
+    let parsed: Result<ProjectionName, _> = serde_json::from_str("\" \"");
+
+    assert!(parsed.is_err());
+}
+
+#[rstest]
+fn checkpoints_advance_position_and_count() {
+    let name = ProjectionName::new("aggregate_activity").expect("valid name");
+    let mut checkpoint = ProjectionCheckpoint::new(name, Utc::now());
+
+    checkpoint.advance(EventPosition::new(3));
+    checkpoint.advance(EventPosition::new(4));
+
+    assert_eq!(checkpoint.position, EventPosition::new(4));
+    assert_eq!(checkpoint.events_applied, 2);
+}
+
+#[rstest]
+fn aggregate_activity_tracks_count_and_latest_event() {
+    let aggregate_id = Uuid::new_v4();
+    let started = Utc::now();
+    let first = RecordedEvent::new("AgentSession", aggregate_id, "SessionStarted", started);
+    let later = RecordedEvent::new(
+        "AgentSession",
+        aggregate_id,
+        "TurnCompleted",
+        started + Duration::seconds(5),
+    );
+
+    let mut activity = AggregateActivity::first(&first);
+    activity.record(&later);
+
+    assert_eq!(activity.event_count, 2);
+    assert_eq!(activity.last_event_type, "TurnCompleted");
+    assert_eq!(activity.first_occurred_at, started);
+    assert_eq!(activity.last_occurred_at, later.occurred_at);
+}
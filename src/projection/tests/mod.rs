@@ -0,0 +1,4 @@
+//! Unit tests for event-sourced projections.
+
+mod domain_tests;
+mod service_tests;
@@ -0,0 +1,236 @@
+//! Unit tests for the projection runner.
+
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::operator::domain::OperatorReason;
+use crate::projection::{
+    adapters::{
+        AggregateActivityProjection, InMemoryDomainEventLog, InMemoryProjectionCheckpointStore,
+    },
+    domain::{EventPosition, ProjectionName, RecordedEvent},
+    ports::{Projection, ProjectionCheckpointStore, ProjectionError, ProjectionResult},
+    services::{ProjectionRunError, ProjectionRunner},
+};
+use async_trait::async_trait;
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+use std::sync::{Arc, Mutex, PoisonError};
+use uuid::Uuid;
+
+/// Projection that records the positions it applied and can fail on one.
+struct RecordingProjection {
+    name: ProjectionName,
+    applied: Mutex<Vec<EventPosition>>,
+    fail_at: Mutex<Option<EventPosition>>,
+}
+
+impl RecordingProjection {
+    fn new(name: &str) -> Self {
+        Self {
+            name: ProjectionName::new(name).expect("valid name"),
+            applied: Mutex::default(),
+            fail_at: Mutex::default(),
+        }
+    }
+
+    fn applied(&self) -> Vec<u64> {
+        self.applied
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|position| position.value())
+            .collect()
+    }
+
+    fn fail_at(&self, position: Option<u64>) {
+        *self.fail_at.lock().unwrap_or_else(PoisonError::into_inner) =
+            position.map(EventPosition::new);
+    }
+}
+
+#[async_trait]
+impl Projection for RecordingProjection {
+    fn name(&self) -> &ProjectionName {
+        &self.name
+    }
+
+    async fn apply(&self, event: &RecordedEvent) -> ProjectionResult<()> {
+        if *self.fail_at.lock().unwrap_or_else(PoisonError::into_inner) == Some(event.position) {
+            return Err(ProjectionError::Failed {
+                reason: "read model unavailable".to_owned(),
+            });
+        }
+        self.applied
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(event.position);
+        Ok(())
+    }
+
+    async fn reset(&self) -> ProjectionResult<()> {
+        self.applied
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+        Ok(())
+    }
+}
+
+struct Harness {
+    events: InMemoryDomainEventLog,
+    checkpoints: InMemoryProjectionCheckpointStore,
+}
+
+impl Harness {
+    fn append(&self, count: usize) {
+        for _ in 0..count {
+            self.events
+                .append(RecordedEvent::new(
+                    "Conversation",
+                    Uuid::new_v4(),
+                    "MessageAppended",
+                    DefaultClock.utc(),
+                ))
+                .expect("append event");
+        }
+    }
+
+    fn runner(&self, projection: Arc<dyn Projection>) -> ProjectionRunner<DefaultClock> {
+        ProjectionRunner::new(
+            Arc::new(self.events.clone()),
+            Arc::new(self.checkpoints.clone()),
+            Arc::new(DefaultClock),
+        )
+        .with_batch_size(2)
+        .with_projection(projection)
+    }
+}
+
+#[fixture]
+fn harness() -> Harness {
+    Harness {
+        events: InMemoryDomainEventLog::new(),
+        checkpoints: InMemoryProjectionCheckpointStore::new(),
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn catch_up_replays_events_in_order_and_resumes_from_the_checkpoint(harness: Harness) {
+    let projection = Arc::new(RecordingProjection::new("recording"));
+    let runner = harness.runner(projection.clone());
+    harness.append(5);
+
+    let first = runner.catch_up().await.expect("first run");
+    harness.append(2);
+    let second = runner.catch_up().await.expect("second run");
+
+    assert_eq!(first.iter().map(|report| report.applied).sum::<u64>(), 5);
+    assert_eq!(second.iter().map(|report| report.applied).sum::<u64>(), 2);
+    assert_eq!(projection.applied(), [1, 2, 3, 4, 5, 6, 7]);
+    let stored = harness
+        .checkpoints
+        .load(projection.name())
+        .await
+        .expect("load checkpoint")
+        .expect("checkpoint saved");
+    assert_eq!(stored.position, EventPosition::new(7));
+    assert_eq!(stored.events_applied, 7);
+}
+
+#[rstest]
+#[tokio::test]
+async fn failed_events_are_retried_on_the_next_run(harness: Harness) {
+    let projection = Arc::new(RecordingProjection::new("recording"));
+    let runner = harness.runner(projection.clone());
+    harness.append(4);
+    projection.fail_at(Some(3));
+
+    let failed = runner.catch_up().await;
+    projection.fail_at(None);
+    let resumed = runner.catch_up().await.expect("resumed run");
+
+    assert!(matches!(
+        failed,
+        Err(ProjectionRunError::Projection { position, .. }) if position == EventPosition::new(3)
+    ));
+    assert_eq!(resumed.iter().map(|report| report.applied).sum::<u64>(), 2);
+    assert_eq!(projection.applied(), [1, 2, 3, 4]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn rebuild_resets_the_read_model_and_replays_the_stream(harness: Harness) {
+    let name = ProjectionName::new("aggregate_activity").expect("valid name");
+    let activity = Arc::new(AggregateActivityProjection::new(name.clone()));
+    let runner = harness.runner(activity.clone());
+    let conversation_id = Uuid::new_v4();
+    for event_type in ["ConversationCreated", "MessageAppended", "MessageAppended"] {
+        harness
+            .events
+            .append(RecordedEvent::new(
+                "Conversation",
+                conversation_id,
+                event_type,
+                DefaultClock.utc(),
+            ))
+            .expect("append event");
+    }
+    runner.catch_up().await.expect("catch up");
+
+    let report = runner.rebuild(&name).await.expect("rebuild");
+
+    let summary = activity
+        .get("Conversation", conversation_id)
+        .expect("read model")
+        .expect("conversation activity");
+    assert_eq!(report.applied, 3);
+    assert_eq!(report.checkpoint.events_applied, 3);
+    assert_eq!(summary.event_count, 3);
+    assert_eq!(summary.last_event_type, "MessageAppended");
+}
+
+#[rstest]
+#[tokio::test]
+async fn rebuilding_an_unregistered_projection_fails(harness: Harness) {
+    let runner = harness.runner(Arc::new(RecordingProjection::new("recording")));
+    let missing = ProjectionName::new("missing").expect("valid name");
+
+    let result = runner.rebuild(&missing).await;
+
+    assert!(matches!(
+        result,
+        Err(ProjectionRunError::UnknownProjection(name)) if name == missing
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn projections_wait_out_maintenance(harness: Harness) {
+    let projection = Arc::new(RecordingProjection::new("recording"));
+    let gate = Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ));
+    let runner = harness
+        .runner(projection.clone())
+        .with_maintenance(Arc::clone(&gate));
+    harness.append(3);
+    let reason = OperatorReason::new("failover").expect("valid reason");
+    gate.enable(reason, None).await.expect("enable maintenance");
+
+    let paused = runner.catch_up().await;
+    let rebuild = runner.rebuild(projection.name()).await;
+    gate.disable(None).await.expect("disable maintenance");
+    let resumed = runner.catch_up().await.expect("resumed run");
+
+    assert!(matches!(
+        paused,
+        Err(ProjectionRunError::MaintenanceMode(_))
+    ));
+    assert!(matches!(
+        rebuild,
+        Err(ProjectionRunError::MaintenanceMode(_))
+    ));
+    assert_eq!(resumed.iter().map(|report| report.applied).sum::<u64>(), 3);
+    assert_eq!(projection.applied(), [1, 2, 3]);
+}
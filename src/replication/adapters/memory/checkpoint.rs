@@ -0,0 +1,50 @@
+//! In-memory replication checkpoint store.
+
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use crate::replication::{
+    domain::{RegionId, ReplicationCheckpoint},
+    ports::{ReplicationCheckpointStore, ReplicationStoreError, ReplicationStoreResult},
+};
+
+/// Thread-safe in-memory checkpoint store.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryReplicationCheckpointStore {
+    checkpoints: Arc<RwLock<BTreeMap<RegionId, ReplicationCheckpoint>>>,
+}
+
+impl InMemoryReplicationCheckpointStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn lock_error(err: impl std::fmt::Display) -> ReplicationStoreError {
+    ReplicationStoreError::persistence_failed(std::io::Error::other(err.to_string()))
+}
+
+#[async_trait]
+impl ReplicationCheckpointStore for InMemoryReplicationCheckpointStore {
+    async fn load(
+        &self,
+        origin: &RegionId,
+    ) -> ReplicationStoreResult<Option<ReplicationCheckpoint>> {
+        let checkpoints = self.checkpoints.read().map_err(lock_error)?;
+        Ok(checkpoints.get(origin).cloned())
+    }
+
+    async fn save(&self, checkpoint: &ReplicationCheckpoint) -> ReplicationStoreResult<()> {
+        let mut checkpoints = self.checkpoints.write().map_err(lock_error)?;
+        checkpoints.insert(checkpoint.origin.clone(), checkpoint.clone());
+        Ok(())
+    }
+
+    async fn list(&self) -> ReplicationStoreResult<Vec<ReplicationCheckpoint>> {
+        let checkpoints = self.checkpoints.read().map_err(lock_error)?;
+        Ok(checkpoints.values().cloned().collect())
+    }
+}
@@ -0,0 +1,81 @@
+//! In-memory replication log.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, RwLock};
+
+use crate::context::RequestContext;
+use crate::replication::{
+    domain::{ReplicatedChange, ReplicationEvent, ReplicationHead, ReplicationPosition},
+    ports::{ReplicationLog, ReplicationStoreError, ReplicationStoreResult},
+};
+
+/// Thread-safe in-memory replication log.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryReplicationLog {
+    events: Arc<RwLock<Vec<ReplicationEvent>>>,
+}
+
+impl InMemoryReplicationLog {
+    /// Creates an empty log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn lock_error(err: impl std::fmt::Display) -> ReplicationStoreError {
+    ReplicationStoreError::persistence_failed(std::io::Error::other(err.to_string()))
+}
+
+#[async_trait]
+impl ReplicationLog for InMemoryReplicationLog {
+    async fn append(
+        &self,
+        ctx: &RequestContext,
+        change: ReplicatedChange,
+        occurred_at: DateTime<Utc>,
+    ) -> ReplicationStoreResult<ReplicationEvent> {
+        let mut events = self.events.write().map_err(lock_error)?;
+        let position = events
+            .last()
+            .map_or(ReplicationPosition::START, |event| event.position)
+            .next();
+        let event = ReplicationEvent {
+            position,
+            tenant_id: ctx.tenant_id(),
+            correlation_id: ctx.correlation_id(),
+            user_id: ctx.user_id(),
+            session_id: ctx.session_id(),
+            occurred_at,
+            change,
+        };
+        events.push(event.clone());
+        Ok(event)
+    }
+
+    async fn read_after(
+        &self,
+        after: ReplicationPosition,
+        limit: usize,
+    ) -> ReplicationStoreResult<Vec<ReplicationEvent>> {
+        let events = self.events.read().map_err(lock_error)?;
+        Ok(events
+            .iter()
+            .skip_while(|event| event.position <= after)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn head(&self) -> ReplicationStoreResult<ReplicationHead> {
+        let events = self.events.read().map_err(lock_error)?;
+        Ok(events
+            .last()
+            .map(|event| ReplicationHead {
+                position: event.position,
+                occurred_at: Some(event.occurred_at),
+            })
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,7 @@
+//! In-memory adapters for replication ports.
+
+mod checkpoint;
+mod log;
+
+pub use checkpoint::InMemoryReplicationCheckpointStore;
+pub use log::InMemoryReplicationLog;
@@ -0,0 +1,8 @@
+//! Adapter implementations for replication ports.
+
+pub mod memory;
+pub mod recording;
+
+pub use recording::{
+    ReplicatingConversationRepository, ReplicatingMessageRepository, ReplicatingTaskRepository,
+};
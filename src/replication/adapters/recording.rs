@@ -0,0 +1,378 @@
+//! Repository decorators that export committed writes for replication.
+//!
+//! Each decorator forwards every call to the wrapped repository and, once a
+//! write has succeeded, appends the written aggregate to the region's
+//! [`ReplicationLog`]. Replication is asynchronous: a failure to record is
+//! logged rather than returned, because the local write has already
+//! committed and must not be reported as failed.
+//!
+//! Wrap only the repositories that serve local traffic. The applier on a
+//! replica must write through undecorated repositories, or applied changes
+//! would be exported again and echo between regions.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{
+        ContentHash, Conversation, ConversationId, ConversationLabel, ConversationLabelEvent,
+        ConversationTokenUsage, Message, MessageId, MessageQuery, MessageRedaction, SequenceNumber,
+    },
+    ports::{
+        conversation::{ConversationRepository, ConversationRepositoryResult},
+        repository::{MessageRepository, RepositoryResult},
+    },
+};
+use crate::pagination::{Page, PageRequest};
+use crate::replication::{domain::ReplicatedChange, ports::ReplicationLog};
+use crate::task::{
+    domain::{BranchRef, IssueRef, PullRequestRef, Task, TaskId},
+    ports::{TaskRepository, TaskRepositoryResult},
+};
+use async_trait::async_trait;
+use mockable::Clock;
+use std::sync::Arc;
+
+/// Appends committed writes to the replication log.
+#[derive(Clone)]
+struct Recorder {
+    log: Arc<dyn ReplicationLog>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl Recorder {
+    async fn record(&self, ctx: &RequestContext, change: ReplicatedChange) {
+        let aggregate = change.aggregate();
+        if let Err(err) = self.log.append(ctx, change, self.clock.utc()).await {
+            tracing::error!(
+                tenant_id = %ctx.tenant_id(),
+                %aggregate,
+                error = %err,
+                "committed write was not recorded for replication"
+            );
+        }
+    }
+
+    async fn record_conversation(&self, ctx: &RequestContext, conversation: &Conversation) {
+        let change = ReplicatedChange::ConversationUpserted {
+            conversation: Box::new(conversation.clone()),
+        };
+        self.record(ctx, change).await;
+    }
+
+    async fn record_message(&self, ctx: &RequestContext, message: &Message) {
+        let change = ReplicatedChange::MessageStored {
+            message: Box::new(message.clone()),
+        };
+        self.record(ctx, change).await;
+    }
+
+    async fn record_redaction(&self, ctx: &RequestContext, redaction: &MessageRedaction) {
+        let change = ReplicatedChange::MessageRedacted {
+            redaction: redaction.clone(),
+        };
+        self.record(ctx, change).await;
+    }
+
+    async fn record_pin(&self, ctx: &RequestContext, message_id: MessageId, pinned: bool) {
+        let change = ReplicatedChange::MessagePinned { message_id, pinned };
+        self.record(ctx, change).await;
+    }
+
+    async fn record_task(&self, ctx: &RequestContext, task: &Task) {
+        let change = ReplicatedChange::TaskUpserted {
+            task: Box::new(task.clone()),
+        };
+        self.record(ctx, change).await;
+    }
+}
+
+/// [`ConversationRepository`] decorator that exports stored and updated
+/// conversations.
+pub struct ReplicatingConversationRepository<R: ?Sized> {
+    inner: Arc<R>,
+    recorder: Recorder,
+}
+
+impl<R: ConversationRepository + ?Sized> ReplicatingConversationRepository<R> {
+    /// Wraps `inner` so its writes are appended to `log`.
+    #[must_use]
+    pub fn new(
+        inner: Arc<R>,
+        log: Arc<dyn ReplicationLog>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            inner,
+            recorder: Recorder { log, clock },
+        }
+    }
+}
+
+#[async_trait]
+impl<R: ConversationRepository + ?Sized> ConversationRepository
+    for ReplicatingConversationRepository<R>
+{
+    async fn store(
+        &self,
+        ctx: &RequestContext,
+        conversation: &Conversation,
+    ) -> ConversationRepositoryResult<()> {
+        self.inner.store(ctx, conversation).await?;
+        self.recorder.record_conversation(ctx, conversation).await;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        ctx: &RequestContext,
+        conversation: &Conversation,
+    ) -> ConversationRepositoryResult<()> {
+        self.inner.update(ctx, conversation).await?;
+        self.recorder.record_conversation(ctx, conversation).await;
+        Ok(())
+    }
+
+    async fn update_labels(
+        &self,
+        ctx: &RequestContext,
+        conversation: &Conversation,
+        event: &ConversationLabelEvent,
+    ) -> ConversationRepositoryResult<()> {
+        self.inner.update_labels(ctx, conversation, event).await?;
+        self.recorder.record_conversation(ctx, conversation).await;
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: ConversationId,
+    ) -> ConversationRepositoryResult<Option<Conversation>> {
+        self.inner.find_by_id(ctx, id).await
+    }
+
+    async fn find_by_label(
+        &self,
+        ctx: &RequestContext,
+        label: &ConversationLabel,
+        page: PageRequest,
+    ) -> ConversationRepositoryResult<Page<Conversation>> {
+        self.inner.find_by_label(ctx, label, page).await
+    }
+
+    async fn find_label_events(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> ConversationRepositoryResult<Page<ConversationLabelEvent>> {
+        self.inner
+            .find_label_events(ctx, conversation_id, page)
+            .await
+    }
+}
+
+/// [`MessageRepository`] decorator that exports stored and redacted
+/// messages.
+pub struct ReplicatingMessageRepository<R: ?Sized> {
+    inner: Arc<R>,
+    recorder: Recorder,
+}
+
+impl<R: MessageRepository + ?Sized> ReplicatingMessageRepository<R> {
+    /// Wraps `inner` so its writes are appended to `log`.
+    #[must_use]
+    pub fn new(
+        inner: Arc<R>,
+        log: Arc<dyn ReplicationLog>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            inner,
+            recorder: Recorder { log, clock },
+        }
+    }
+}
+
+#[async_trait]
+impl<R: MessageRepository + ?Sized> MessageRepository for ReplicatingMessageRepository<R> {
+    async fn store(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<()> {
+        self.inner.store(ctx, message).await?;
+        self.recorder.record_message(ctx, message).await;
+        Ok(())
+    }
+
+    async fn append(&self, ctx: &RequestContext, message: &Message) -> RepositoryResult<Message> {
+        let stored = self.inner.append(ctx, message).await?;
+        self.recorder.record_message(ctx, &stored).await;
+        Ok(stored)
+    }
+
+    async fn store_batch(
+        &self,
+        ctx: &RequestContext,
+        messages: &[Message],
+    ) -> RepositoryResult<()> {
+        self.inner.store_batch(ctx, messages).await?;
+        for message in messages {
+            self.recorder.record_message(ctx, message).await;
+        }
+        Ok(())
+    }
+
+    async fn redact(
+        &self,
+        ctx: &RequestContext,
+        redaction: &MessageRedaction,
+    ) -> RepositoryResult<Message> {
+        let redacted = self.inner.redact(ctx, redaction).await?;
+        self.recorder.record_redaction(ctx, redaction).await;
+        Ok(redacted)
+    }
+
+    async fn set_pinned(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+        pinned: bool,
+    ) -> RepositoryResult<Message> {
+        let updated = self.inner.set_pinned(ctx, id, pinned).await?;
+        self.recorder.record_pin(ctx, id, pinned).await;
+        Ok(updated)
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: MessageId,
+    ) -> RepositoryResult<Option<Message>> {
+        self.inner.find_by_id(ctx, id).await
+    }
+
+    async fn find_by_conversation(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> RepositoryResult<Page<Message>> {
+        self.inner
+            .find_by_conversation(ctx, conversation_id, page)
+            .await
+    }
+
+    async fn query(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        query: &MessageQuery,
+    ) -> RepositoryResult<Page<Message>> {
+        self.inner.query(ctx, conversation_id, query).await
+    }
+
+    async fn next_sequence_number(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<SequenceNumber> {
+        self.inner.next_sequence_number(ctx, conversation_id).await
+    }
+
+    async fn token_usage(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RepositoryResult<ConversationTokenUsage> {
+        self.inner.token_usage(ctx, conversation_id).await
+    }
+
+    async fn exists(&self, ctx: &RequestContext, id: MessageId) -> RepositoryResult<bool> {
+        self.inner.exists(ctx, id).await
+    }
+
+    async fn unreferenced_blobs(
+        &self,
+        ctx: &RequestContext,
+        hashes: &[ContentHash],
+    ) -> RepositoryResult<Vec<ContentHash>> {
+        self.inner.unreferenced_blobs(ctx, hashes).await
+    }
+}
+
+/// [`TaskRepository`] decorator that exports stored and updated tasks.
+pub struct ReplicatingTaskRepository<R: ?Sized> {
+    inner: Arc<R>,
+    recorder: Recorder,
+}
+
+impl<R: TaskRepository + ?Sized> ReplicatingTaskRepository<R> {
+    /// Wraps `inner` so its writes are appended to `log`.
+    #[must_use]
+    pub fn new(
+        inner: Arc<R>,
+        log: Arc<dyn ReplicationLog>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            inner,
+            recorder: Recorder { log, clock },
+        }
+    }
+}
+
+#[async_trait]
+impl<R: TaskRepository + ?Sized> TaskRepository for ReplicatingTaskRepository<R> {
+    async fn store(&self, ctx: &RequestContext, task: &Task) -> TaskRepositoryResult<()> {
+        self.inner.store(ctx, task).await?;
+        self.recorder.record_task(ctx, task).await;
+        Ok(())
+    }
+
+    async fn update(&self, ctx: &RequestContext, task: &Task) -> TaskRepositoryResult<()> {
+        self.inner.update(ctx, task).await?;
+        self.recorder.record_task(ctx, task).await;
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        ctx: &RequestContext,
+        id: TaskId,
+    ) -> TaskRepositoryResult<Option<Task>> {
+        self.inner.find_by_id(ctx, id).await
+    }
+
+    async fn find_by_issue_ref(
+        &self,
+        ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> TaskRepositoryResult<Option<Task>> {
+        self.inner.find_by_issue_ref(ctx, issue_ref).await
+    }
+
+    async fn find_by_branch_ref(
+        &self,
+        ctx: &RequestContext,
+        branch_ref: &BranchRef,
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>> {
+        self.inner.find_by_branch_ref(ctx, branch_ref, page).await
+    }
+
+    async fn find_by_pull_request_ref(
+        &self,
+        ctx: &RequestContext,
+        pull_request_ref: &PullRequestRef,
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>> {
+        self.inner
+            .find_by_pull_request_ref(ctx, pull_request_ref, page)
+            .await
+    }
+
+    async fn find_reconcilable(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>> {
+        self.inner.find_reconcilable(ctx, page).await
+    }
+}
@@ -0,0 +1,141 @@
+//! Conflict policy for aggregates written in more than one region.
+
+use super::{RegionId, ReplicatedAggregate, ReplicationPosition};
+use crate::context::TenantId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+/// How to settle a replicated change that clashes with a local write.
+///
+/// A conversation or task conflicts when the local copy differs from the
+/// incoming snapshot and is at least as recent, which means it was written
+/// locally after the origin's write. A message conflicts when its sequence
+/// number is already taken locally by a different message.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// Keep whichever write is later, breaking ties by region identifier so
+    /// that both regions pick the same winner.
+    #[default]
+    LastWriterWins,
+    /// Always take the origin's write, as a disaster-recovery standby
+    /// following a primary would.
+    PreferOrigin,
+    /// Always keep the local write.
+    PreferLocal,
+    /// Stop applying the stream so an operator can decide.
+    Reject,
+}
+
+impl ConflictPolicy {
+    /// Settles a conflicting conversation or task snapshot.
+    #[must_use]
+    pub fn resolve_upsert(
+        self,
+        local: WriteVersion<'_>,
+        incoming: WriteVersion<'_>,
+    ) -> ConflictResolution {
+        match self {
+            Self::LastWriterWins if incoming.is_later_than(local) => {
+                ConflictResolution::ApplyIncoming
+            }
+            Self::LastWriterWins | Self::PreferLocal => ConflictResolution::KeepLocal,
+            Self::PreferOrigin => ConflictResolution::ApplyIncoming,
+            Self::Reject => ConflictResolution::Rejected,
+        }
+    }
+
+    /// Settles an incoming message whose sequence number is taken locally.
+    ///
+    /// Messages are never overwritten, so every policy except
+    /// [`Self::Reject`] keeps both messages by appending the incoming one at
+    /// the conversation's next free sequence number.
+    #[must_use]
+    pub const fn resolve_message(self) -> ConflictResolution {
+        match self {
+            Self::Reject => ConflictResolution::Rejected,
+            Self::LastWriterWins | Self::PreferOrigin | Self::PreferLocal => {
+                ConflictResolution::AppendIncoming
+            }
+        }
+    }
+}
+
+/// One region's version of a conflicting aggregate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteVersion<'a> {
+    /// The region that made the write.
+    pub region: &'a RegionId,
+    /// When the aggregate was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WriteVersion<'_> {
+    /// Returns whether this write wins a last-writer-wins comparison.
+    #[must_use]
+    pub fn is_later_than(self, other: WriteVersion<'_>) -> bool {
+        (self.updated_at, self.region) > (other.updated_at, other.region)
+    }
+}
+
+/// How a conflict was settled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictResolution {
+    /// The incoming snapshot replaced the local copy.
+    ApplyIncoming,
+    /// The local copy was kept and the incoming snapshot discarded.
+    KeepLocal,
+    /// The incoming message was appended after the local messages.
+    AppendIncoming,
+    /// The stream was halted at the conflicting event.
+    Rejected,
+}
+
+impl ConflictResolution {
+    /// Returns the stable string representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ApplyIncoming => "apply_incoming",
+            Self::KeepLocal => "keep_local",
+            Self::AppendIncoming => "append_incoming",
+            Self::Rejected => "rejected",
+        }
+    }
+}
+
+impl fmt::Display for ConflictResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A conflict met while applying a replication stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplicationConflict {
+    /// The region the conflicting change came from.
+    pub origin: RegionId,
+    /// Position of the conflicting event in the origin's log.
+    pub position: ReplicationPosition,
+    /// The tenant that owns the aggregate.
+    pub tenant_id: TenantId,
+    /// The kind of aggregate in conflict.
+    pub aggregate: ReplicatedAggregate,
+    /// The aggregate's identifier.
+    pub aggregate_id: Uuid,
+    /// How the conflict was settled.
+    pub resolution: ConflictResolution,
+}
+
+impl fmt::Display for ReplicationConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} from {} at position {} ({})",
+            self.aggregate, self.aggregate_id, self.origin, self.position, self.resolution
+        )
+    }
+}
@@ -0,0 +1,221 @@
+//! Replication log entries and the batches exported to other regions.
+
+use super::RegionId;
+use crate::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+use crate::message::canonical;
+use crate::message::domain::{Conversation, Message, MessageId, MessageRedaction};
+use crate::task::domain::Task;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Position of an event in a region's replication log.
+///
+/// Positions are assigned contiguously from `1`; [`Self::START`] precedes
+/// every event and is where a new replica begins reading.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct ReplicationPosition(u64);
+
+impl ReplicationPosition {
+    /// The position before the first event.
+    pub const START: Self = Self(0);
+
+    /// Creates a position from its numeric value.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the numeric value.
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the position immediately after this one.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        Self(self.0.saturating_add(1))
+    }
+
+    /// Returns how many events lie after this position up to and including
+    /// `later`, or `0` when `later` is not ahead.
+    #[must_use]
+    pub const fn events_until(self, later: Self) -> u64 {
+        later.0.saturating_sub(self.0)
+    }
+}
+
+impl fmt::Display for ReplicationPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The kind of aggregate a replicated change carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplicatedAggregate {
+    /// A conversation.
+    Conversation,
+    /// A message.
+    Message,
+    /// A task.
+    Task,
+}
+
+impl ReplicatedAggregate {
+    /// Returns the stable string representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Conversation => "conversation",
+            Self::Message => "message",
+            Self::Task => "task",
+        }
+    }
+}
+
+impl fmt::Display for ReplicatedAggregate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A committed write to replicate, carrying the full aggregate state.
+///
+/// Conversations and tasks are replicated as snapshots of their latest
+/// state, so applying the newest snapshot is enough to converge. Messages
+/// are immutable once stored and are replicated once; a later redaction or
+/// pin is replicated as its own change so replicas update their copy too.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReplicatedChange {
+    /// A conversation was created or updated.
+    ConversationUpserted {
+        /// The conversation's state after the write.
+        conversation: Box<Conversation>,
+    },
+    /// A message was stored.
+    MessageStored {
+        /// The stored message, with its allocated sequence number.
+        message: Box<Message>,
+    },
+    /// A stored message was redacted.
+    MessageRedacted {
+        /// The redaction to repeat on the replica.
+        redaction: MessageRedaction,
+    },
+    /// A stored message was pinned or unpinned.
+    MessagePinned {
+        /// The message whose pinned flag changed.
+        message_id: MessageId,
+        /// Whether the message is now pinned.
+        pinned: bool,
+    },
+    /// A task was created or updated.
+    TaskUpserted {
+        /// The task's state after the write.
+        task: Box<Task>,
+    },
+}
+
+impl ReplicatedChange {
+    /// Returns the kind of aggregate the change carries.
+    #[must_use]
+    pub const fn aggregate(&self) -> ReplicatedAggregate {
+        match self {
+            Self::ConversationUpserted { .. } => ReplicatedAggregate::Conversation,
+            Self::MessageStored { .. }
+            | Self::MessageRedacted { .. }
+            | Self::MessagePinned { .. } => ReplicatedAggregate::Message,
+            Self::TaskUpserted { .. } => ReplicatedAggregate::Task,
+        }
+    }
+}
+
+/// A change recorded in a region's replication log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplicationEvent {
+    /// Where the event sits in the origin's log.
+    pub position: ReplicationPosition,
+    /// The tenant that owns the changed aggregate.
+    pub tenant_id: TenantId,
+    /// Correlation identifier of the request that made the change.
+    pub correlation_id: CorrelationId,
+    /// The user who made the change.
+    pub user_id: UserId,
+    /// The session the change was made in.
+    pub session_id: SessionId,
+    /// When the change was recorded.
+    pub occurred_at: DateTime<Utc>,
+    /// What changed.
+    pub change: ReplicatedChange,
+}
+
+impl ReplicationEvent {
+    /// Returns a request context equivalent to the one the change was made
+    /// under, for replaying it against local repositories.
+    #[must_use]
+    pub const fn request_context(&self) -> RequestContext {
+        RequestContext::new(
+            self.tenant_id,
+            self.correlation_id,
+            self.user_id,
+            self.session_id,
+        )
+    }
+}
+
+/// The latest entry in a replication log.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplicationHead {
+    /// Position of the latest event, or [`ReplicationPosition::START`] when
+    /// the log is empty.
+    pub position: ReplicationPosition,
+    /// When the latest event was recorded.
+    pub occurred_at: Option<DateTime<Utc>>,
+}
+
+/// A contiguous run of events exported from one region.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplicationBatch {
+    /// The region whose log the events come from.
+    pub origin: RegionId,
+    /// The position the export started after.
+    pub after: ReplicationPosition,
+    /// Events in log order.
+    pub events: Vec<ReplicationEvent>,
+    /// The origin's log head when the batch was exported.
+    pub head: ReplicationHead,
+}
+
+impl ReplicationBatch {
+    /// Returns the position to export after when fetching the next batch.
+    #[must_use]
+    pub fn next_after(&self) -> ReplicationPosition {
+        self.events
+            .last()
+            .map_or(self.after, |event| event.position)
+    }
+
+    /// Serialises the batch to canonical JSON for export, so replicas can
+    /// hash or verify the bytes they receive.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serialisation error when an event's payload cannot be
+    /// represented as JSON.
+    pub fn to_canonical_json(&self) -> Result<String, serde_json::Error> {
+        canonical::to_canonical_string(self)
+    }
+
+    /// Returns whether the batch reaches the origin's log head.
+    #[must_use]
+    pub fn is_caught_up(&self) -> bool {
+        self.next_after() >= self.head.position
+    }
+}
@@ -0,0 +1,186 @@
+//! Replica progress and lag assessment.
+
+use super::{RegionId, ReplicationConflict, ReplicationHead, ReplicationPosition};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+/// Default number of unapplied events above which a replica is lagging.
+pub const DEFAULT_MAX_PENDING_EVENTS: u64 = 1_000;
+
+/// Default age gap above which a replica is lagging.
+pub const DEFAULT_MAX_BEHIND: Duration = Duration::from_secs(60);
+
+/// Default time without a sync after which a replica is stalled.
+pub const DEFAULT_MAX_SILENCE: Duration = Duration::from_secs(300);
+
+/// How far a replica has applied one origin's stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplicationCheckpoint {
+    /// The region whose stream is being applied.
+    pub origin: RegionId,
+    /// Position of the last applied event.
+    pub applied: ReplicationPosition,
+    /// When the last applied event was recorded at the origin.
+    pub applied_occurred_at: Option<DateTime<Utc>>,
+    /// The origin's log head as of the last sync.
+    pub head: ReplicationHead,
+    /// When a batch from the origin was last applied.
+    pub synced_at: DateTime<Utc>,
+    /// Conflicts met on this stream so far.
+    pub conflicts: u64,
+}
+
+impl ReplicationCheckpoint {
+    /// Creates a checkpoint for a stream nothing has been applied from.
+    #[must_use]
+    pub fn new(origin: RegionId, synced_at: DateTime<Utc>) -> Self {
+        Self {
+            origin,
+            applied: ReplicationPosition::START,
+            applied_occurred_at: None,
+            head: ReplicationHead::default(),
+            synced_at,
+            conflicts: 0,
+        }
+    }
+
+    /// Returns the number of events known to be waiting at the origin.
+    #[must_use]
+    pub const fn pending_events(&self) -> u64 {
+        self.applied.events_until(self.head.position)
+    }
+}
+
+/// What one call to apply a batch did.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplicationApplyReport {
+    /// Events applied to local repositories.
+    pub applied: usize,
+    /// Events skipped because they had already been applied.
+    pub skipped: usize,
+    /// Conflicts met and how each was settled.
+    pub conflicts: Vec<ReplicationConflict>,
+    /// The stream's checkpoint after the batch.
+    pub checkpoint: ReplicationCheckpoint,
+}
+
+/// How healthy a replica's view of an origin is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplicationHealth {
+    /// The replica is within its lag thresholds.
+    Healthy,
+    /// The replica is syncing but too far behind.
+    Lagging,
+    /// No batch has been applied for too long.
+    Stalled,
+}
+
+impl ReplicationHealth {
+    /// Returns the stable string representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Lagging => "lagging",
+            Self::Stalled => "stalled",
+        }
+    }
+}
+
+impl fmt::Display for ReplicationHealth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A replica's lag behind one origin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplicationLag {
+    /// The origin region.
+    pub origin: RegionId,
+    /// Events recorded at the origin but not yet applied.
+    pub pending_events: u64,
+    /// How much older the latest applied change is than the origin's latest
+    /// change, or `None` while events are pending but none has been applied.
+    pub behind: Option<Duration>,
+    /// Time since a batch from the origin was last applied.
+    pub since_sync: Duration,
+    /// Conflicts met on this stream so far.
+    pub conflicts: u64,
+    /// The assessment against the lag thresholds.
+    pub health: ReplicationHealth,
+}
+
+/// Thresholds for judging replication lag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplicationLagPolicy {
+    max_pending_events: u64,
+    max_behind: Duration,
+    max_silence: Duration,
+}
+
+impl Default for ReplicationLagPolicy {
+    fn default() -> Self {
+        Self {
+            max_pending_events: DEFAULT_MAX_PENDING_EVENTS,
+            max_behind: DEFAULT_MAX_BEHIND,
+            max_silence: DEFAULT_MAX_SILENCE,
+        }
+    }
+}
+
+impl ReplicationLagPolicy {
+    /// Sets how many unapplied events a healthy replica may have.
+    #[must_use]
+    pub const fn with_max_pending_events(mut self, max_pending_events: u64) -> Self {
+        self.max_pending_events = max_pending_events;
+        self
+    }
+
+    /// Sets how far behind the origin's latest change a healthy replica may
+    /// be.
+    #[must_use]
+    pub const fn with_max_behind(mut self, max_behind: Duration) -> Self {
+        self.max_behind = max_behind;
+        self
+    }
+
+    /// Sets how long a replica may go without syncing before it is stalled.
+    #[must_use]
+    pub const fn with_max_silence(mut self, max_silence: Duration) -> Self {
+        self.max_silence = max_silence;
+        self
+    }
+
+    /// Measures a replica's lag from its checkpoint at `now`.
+    #[must_use]
+    pub fn assess(&self, checkpoint: &ReplicationCheckpoint, now: DateTime<Utc>) -> ReplicationLag {
+        let pending_events = checkpoint.pending_events();
+        let behind = match (checkpoint.applied_occurred_at, checkpoint.head.occurred_at) {
+            _ if pending_events == 0 => Some(Duration::ZERO),
+            (Some(applied), Some(head)) => Some((head - applied).to_std().unwrap_or_default()),
+            _ => None,
+        };
+        let since_sync = (now - checkpoint.synced_at).to_std().unwrap_or_default();
+        let health = if since_sync > self.max_silence {
+            ReplicationHealth::Stalled
+        } else if pending_events > self.max_pending_events
+            || behind.is_some_and(|gap| gap > self.max_behind)
+        {
+            ReplicationHealth::Lagging
+        } else {
+            ReplicationHealth::Healthy
+        };
+        ReplicationLag {
+            origin: checkpoint.origin.clone(),
+            pending_events,
+            behind,
+            since_sync,
+            conflicts: checkpoint.conflicts,
+            health,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Domain types for cross-region replication.
+
+pub mod conflict;
+pub mod event;
+pub mod lag;
+pub mod region;
+
+pub use conflict::{ConflictPolicy, ConflictResolution, ReplicationConflict, WriteVersion};
+pub use event::{
+    ReplicatedAggregate, ReplicatedChange, ReplicationBatch, ReplicationEvent, ReplicationHead,
+    ReplicationPosition,
+};
+pub use lag::{
+    DEFAULT_MAX_BEHIND, DEFAULT_MAX_PENDING_EVENTS, DEFAULT_MAX_SILENCE, ReplicationApplyReport,
+    ReplicationCheckpoint, ReplicationHealth, ReplicationLag, ReplicationLagPolicy,
+};
+pub use region::{EmptyRegionIdError, RegionId};
@@ -0,0 +1,61 @@
+//! Identifiers for deployments taking part in replication.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// Error returned when a region identifier is blank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("region identifier must not be empty")]
+pub struct EmptyRegionIdError;
+
+/// Identifies a Corbusier deployment, such as `eu-west` or `dr-standby`.
+///
+/// Region identifiers order lexically; replication uses that order to break
+/// ties between writes made at the same instant in two regions, so every
+/// region settles on the same winner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RegionId(String);
+
+impl RegionId {
+    /// Creates a region identifier, trimming surrounding whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyRegionIdError`] when the identifier is blank.
+    pub fn new(value: impl Into<String>) -> Result<Self, EmptyRegionIdError> {
+        let raw = value.into();
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            return Err(EmptyRegionIdError);
+        }
+        Ok(Self(trimmed.to_owned()))
+    }
+
+    /// Returns the identifier as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for RegionId {
+    type Error = EmptyRegionIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<RegionId> for String {
+    fn from(value: RegionId) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for RegionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,24 @@
+//! Asynchronous replication of conversations and tasks between deployments.
+//!
+//! A region records its committed writes in a replication log through the
+//! repository decorators in [`adapters`]. Another deployment, such as a
+//! disaster-recovery standby or a geographically closer replica, pulls
+//! batches from the log with [`services::ReplicationExporter`], applies them
+//! with [`services::ReplicationApplier`] under a
+//! [`domain::ConflictPolicy`], and watches its progress with
+//! [`services::ReplicationLagMonitor`]. Batches are plain serialisable
+//! values, so any transport can carry them. The module follows hexagonal
+//! architecture:
+//!
+//! - Domain types in [`domain`]
+//! - Port contracts in [`ports`]
+//! - Adapter implementations in [`adapters`]
+//! - Orchestration services in [`services`]
+
+pub mod adapters;
+pub mod domain;
+pub mod ports;
+pub mod services;
+
+#[cfg(test)]
+mod tests;
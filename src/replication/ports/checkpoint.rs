@@ -0,0 +1,21 @@
+//! Port contract for remembering how far each origin's stream was applied.
+
+use super::ReplicationStoreResult;
+use crate::replication::domain::{RegionId, ReplicationCheckpoint};
+use async_trait::async_trait;
+
+/// Per-origin progress of the streams a replica applies.
+#[async_trait]
+pub trait ReplicationCheckpointStore: Send + Sync {
+    /// Returns the checkpoint for `origin`, or `None` before its first batch.
+    async fn load(
+        &self,
+        origin: &RegionId,
+    ) -> ReplicationStoreResult<Option<ReplicationCheckpoint>>;
+
+    /// Stores a checkpoint, replacing any earlier one for the same origin.
+    async fn save(&self, checkpoint: &ReplicationCheckpoint) -> ReplicationStoreResult<()>;
+
+    /// Returns every stored checkpoint, ordered by origin.
+    async fn list(&self) -> ReplicationStoreResult<Vec<ReplicationCheckpoint>>;
+}
@@ -0,0 +1,58 @@
+//! Port contract for a region's replication log.
+
+use crate::context::RequestContext;
+use crate::replication::domain::{
+    ReplicatedChange, ReplicationEvent, ReplicationHead, ReplicationPosition,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+/// Result type for replication log and checkpoint operations.
+pub type ReplicationStoreResult<T> = Result<T, ReplicationStoreError>;
+
+/// Ordered, append-only record of the writes a region exports.
+///
+/// The log spans every tenant: positions are global to the region so a
+/// replica can follow one stream. Implementations must assign positions
+/// contiguously and never reorder or drop appended events.
+#[async_trait]
+pub trait ReplicationLog: Send + Sync {
+    /// Appends a change made under `ctx`, assigning it the next position.
+    async fn append(
+        &self,
+        ctx: &RequestContext,
+        change: ReplicatedChange,
+        occurred_at: DateTime<Utc>,
+    ) -> ReplicationStoreResult<ReplicationEvent>;
+
+    /// Returns up to `limit` events positioned after `after`, oldest first.
+    async fn read_after(
+        &self,
+        after: ReplicationPosition,
+        limit: usize,
+    ) -> ReplicationStoreResult<Vec<ReplicationEvent>>;
+
+    /// Returns the latest event's position and timestamp.
+    async fn head(&self) -> ReplicationStoreResult<ReplicationHead>;
+}
+
+/// Errors returned by replication log and checkpoint implementations.
+#[derive(Debug, Clone, Error)]
+pub enum ReplicationStoreError {
+    /// Persistence-layer failure.
+    #[error("replication persistence operation failed: {reason}")]
+    PersistenceFailed {
+        /// Human-readable reason from the failing dependency.
+        reason: String,
+    },
+}
+
+impl ReplicationStoreError {
+    /// Creates a persistence failure from an infrastructure error.
+    pub fn persistence_failed(err: impl std::error::Error) -> Self {
+        Self::PersistenceFailed {
+            reason: err.to_string(),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! Port contracts for cross-region replication.
+
+pub mod checkpoint;
+pub mod log;
+
+pub use checkpoint::ReplicationCheckpointStore;
+pub use log::{ReplicationLog, ReplicationStoreError, ReplicationStoreResult};
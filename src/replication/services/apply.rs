@@ -0,0 +1,385 @@
+//! Apply side of a replication stream.
+
+use crate::context::RequestContext;
+use crate::maintenance::{domain::MaintenanceMode, services::MaintenanceGate};
+use crate::message::{
+    domain::{Conversation, Message, MessageId, MessageRedaction},
+    error::RepositoryError,
+    ports::{ConversationRepository, ConversationRepositoryError, MessageRepository},
+};
+use crate::replication::{
+    domain::{
+        ConflictPolicy, ConflictResolution, RegionId, ReplicatedChange, ReplicationApplyReport,
+        ReplicationBatch, ReplicationCheckpoint, ReplicationConflict, ReplicationEvent,
+        ReplicationPosition, WriteVersion,
+    },
+    ports::{ReplicationCheckpointStore, ReplicationStoreError},
+};
+use crate::task::{
+    domain::Task,
+    ports::{TaskRepository, TaskRepositoryError},
+};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors returned while applying a replication batch.
+#[derive(Debug, Error)]
+pub enum ReplicationError {
+    /// The batch was exported by the region applying it.
+    #[error("replication batch originates from this region: {0}")]
+    OwnOrigin(RegionId),
+    /// The batch does not continue from the last applied position.
+    #[error("replication stream from {origin} expected position {expected} but found {found}")]
+    Gap {
+        /// The origin region.
+        origin: RegionId,
+        /// The position that should have come next.
+        expected: ReplicationPosition,
+        /// The position that came instead.
+        found: ReplicationPosition,
+    },
+    /// The conflict policy halted the stream.
+    #[error("replication conflict rejected: {0}")]
+    ConflictRejected(ReplicationConflict),
+    /// Checkpoint store failure.
+    #[error(transparent)]
+    Store(#[from] ReplicationStoreError),
+    /// Conversation repository failure.
+    #[error(transparent)]
+    ConversationRepository(#[from] ConversationRepositoryError),
+    /// Message repository failure.
+    #[error(transparent)]
+    MessageRepository(#[from] RepositoryError),
+    /// Task repository failure.
+    #[error(transparent)]
+    TaskRepository(#[from] TaskRepositoryError),
+    /// The cluster is in read-only maintenance mode.
+    #[error(transparent)]
+    MaintenanceMode(#[from] MaintenanceMode),
+}
+
+/// Result type for replication apply operations.
+pub type ReplicationResult<T> = Result<T, ReplicationError>;
+
+/// Local repositories and progress store a replica applies streams into.
+///
+/// The repositories must not be wrapped in the replicating decorators, or
+/// applied changes would be exported again.
+#[derive(Clone)]
+pub struct ReplicationApplierDeps {
+    /// Conversation repository.
+    pub conversations: Arc<dyn ConversationRepository>,
+    /// Message repository.
+    pub messages: Arc<dyn MessageRepository>,
+    /// Task repository.
+    pub tasks: Arc<dyn TaskRepository>,
+    /// Per-origin progress store.
+    pub checkpoints: Arc<dyn ReplicationCheckpointStore>,
+}
+
+/// Event being applied, with the context it is replayed under.
+struct Incoming<'a> {
+    origin: &'a RegionId,
+    event: &'a ReplicationEvent,
+    ctx: RequestContext,
+}
+
+impl Incoming<'_> {
+    fn conflict(&self, aggregate_id: Uuid, resolution: ConflictResolution) -> ReplicationConflict {
+        ReplicationConflict {
+            origin: self.origin.clone(),
+            position: self.event.position,
+            tenant_id: self.event.tenant_id,
+            aggregate: self.event.change.aggregate(),
+            aggregate_id,
+            resolution,
+        }
+    }
+}
+
+/// Applies batches exported by other regions to local repositories.
+///
+/// Events are applied in log order and at most once: events at or before
+/// the origin's checkpoint are skipped, and a batch that does not continue
+/// from the checkpoint is refused. Progress is saved after every batch, even
+/// one that stops early, so a retried batch resumes where it stopped. With a
+/// maintenance gate attached, batches are refused whole while the cluster is
+/// in maintenance.
+#[derive(Clone)]
+pub struct ReplicationApplier<C: Clock + Send + Sync> {
+    region: RegionId,
+    deps: ReplicationApplierDeps,
+    policy: ConflictPolicy,
+    clock: Arc<C>,
+    maintenance: Option<Arc<MaintenanceGate>>,
+}
+
+impl<C: Clock + Send + Sync> ReplicationApplier<C> {
+    /// Creates an applier for the region `region`, settling conflicts
+    /// last-writer-wins.
+    #[must_use]
+    pub fn new(region: RegionId, deps: ReplicationApplierDeps, clock: Arc<C>) -> Self {
+        Self {
+            region,
+            deps,
+            policy: ConflictPolicy::default(),
+            clock,
+            maintenance: None,
+        }
+    }
+
+    /// Sets the conflict policy.
+    #[must_use]
+    pub const fn with_conflict_policy(mut self, policy: ConflictPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Refuses batches while `gate` reports maintenance.
+    #[must_use]
+    pub fn with_maintenance(mut self, gate: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(gate);
+        self
+    }
+
+    /// Applies a batch and advances the origin's checkpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReplicationError::OwnOrigin`] for a batch this region
+    /// exported, [`ReplicationError::Gap`] when events are missing,
+    /// [`ReplicationError::ConflictRejected`] when the policy is
+    /// [`ConflictPolicy::Reject`] and a conflict is met,
+    /// [`ReplicationError::MaintenanceMode`] during maintenance, or a
+    /// repository error. Events before the failing one stay applied.
+    pub async fn apply(
+        &self,
+        batch: &ReplicationBatch,
+    ) -> ReplicationResult<ReplicationApplyReport> {
+        if let Some(gate) = self.maintenance.as_deref() {
+            gate.ensure_writable().await?;
+        }
+        if batch.origin == self.region {
+            return Err(ReplicationError::OwnOrigin(batch.origin.clone()));
+        }
+        let now = self.clock.utc();
+        let mut checkpoint = self
+            .deps
+            .checkpoints
+            .load(&batch.origin)
+            .await?
+            .unwrap_or_else(|| ReplicationCheckpoint::new(batch.origin.clone(), now));
+        let mut report = ReplicationApplyReport {
+            applied: 0,
+            skipped: 0,
+            conflicts: Vec::new(),
+            checkpoint: checkpoint.clone(),
+        };
+        let outcome = self.apply_events(batch, &mut checkpoint, &mut report).await;
+
+        if batch.head.position >= checkpoint.head.position {
+            checkpoint.head = batch.head;
+        }
+        checkpoint.synced_at = now;
+        self.deps.checkpoints.save(&checkpoint).await?;
+        outcome?;
+        report.checkpoint = checkpoint;
+        Ok(report)
+    }
+
+    async fn apply_events(
+        &self,
+        batch: &ReplicationBatch,
+        checkpoint: &mut ReplicationCheckpoint,
+        report: &mut ReplicationApplyReport,
+    ) -> ReplicationResult<()> {
+        for event in &batch.events {
+            if event.position <= checkpoint.applied {
+                report.skipped = report.skipped.saturating_add(1);
+                continue;
+            }
+            let expected = checkpoint.applied.next();
+            if event.position != expected {
+                return Err(ReplicationError::Gap {
+                    origin: batch.origin.clone(),
+                    expected,
+                    found: event.position,
+                });
+            }
+            let incoming = Incoming {
+                origin: &batch.origin,
+                event,
+                ctx: event.request_context(),
+            };
+            if let Some(conflict) = self.apply_event(&incoming).await? {
+                checkpoint.conflicts = checkpoint.conflicts.saturating_add(1);
+                report.conflicts.push(conflict);
+            }
+            checkpoint.applied = event.position;
+            checkpoint.applied_occurred_at = Some(event.occurred_at);
+            report.applied = report.applied.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    async fn apply_event(
+        &self,
+        incoming: &Incoming<'_>,
+    ) -> ReplicationResult<Option<ReplicationConflict>> {
+        match &incoming.event.change {
+            ReplicatedChange::ConversationUpserted { conversation } => {
+                self.apply_conversation(incoming, conversation).await
+            }
+            ReplicatedChange::MessageStored { message } => {
+                self.apply_message(incoming, message).await
+            }
+            ReplicatedChange::MessageRedacted { redaction } => {
+                self.apply_redaction(incoming, redaction).await
+            }
+            ReplicatedChange::MessagePinned { message_id, pinned } => {
+                self.apply_pin(incoming, *message_id, *pinned).await
+            }
+            ReplicatedChange::TaskUpserted { task } => self.apply_task(incoming, task).await,
+        }
+    }
+
+    async fn apply_conversation(
+        &self,
+        incoming: &Incoming<'_>,
+        conversation: &Conversation,
+    ) -> ReplicationResult<Option<ReplicationConflict>> {
+        let repository = &self.deps.conversations;
+        let Some(local) = repository
+            .find_by_id(&incoming.ctx, conversation.id())
+            .await?
+        else {
+            repository.store(&incoming.ctx, conversation).await?;
+            return Ok(None);
+        };
+        // Versions count local updates, so they differ between regions; the
+        // incoming copy is compared and written at the local version.
+        let rebased = conversation.clone().with_version(local.version());
+        if local == rebased {
+            return Ok(None);
+        }
+        let (resolution, conflict) = self.settle_upsert(
+            incoming,
+            conversation.id().into_inner(),
+            (local.updated_at(), conversation.updated_at()),
+        )?;
+        if resolution == ConflictResolution::ApplyIncoming {
+            repository.update(&incoming.ctx, &rebased).await?;
+        }
+        Ok(conflict)
+    }
+
+    async fn apply_task(
+        &self,
+        incoming: &Incoming<'_>,
+        task: &Task,
+    ) -> ReplicationResult<Option<ReplicationConflict>> {
+        let repository = &self.deps.tasks;
+        let Some(local) = repository.find_by_id(&incoming.ctx, task.id()).await? else {
+            repository.store(&incoming.ctx, task).await?;
+            return Ok(None);
+        };
+        if local == *task {
+            return Ok(None);
+        }
+        let (resolution, conflict) = self.settle_upsert(
+            incoming,
+            task.id().into_inner(),
+            (local.updated_at(), task.updated_at()),
+        )?;
+        if resolution == ConflictResolution::ApplyIncoming {
+            repository.update(&incoming.ctx, task).await?;
+        }
+        Ok(conflict)
+    }
+
+    async fn apply_message(
+        &self,
+        incoming: &Incoming<'_>,
+        message: &Message,
+    ) -> ReplicationResult<Option<ReplicationConflict>> {
+        let repository = &self.deps.messages;
+        if repository.exists(&incoming.ctx, message.id()).await? {
+            return Ok(None);
+        }
+        match repository.store(&incoming.ctx, message).await {
+            Ok(()) => Ok(None),
+            Err(RepositoryError::DuplicateSequence { .. }) => {
+                let resolution = self.policy.resolve_message();
+                let conflict = incoming.conflict(message.id().into_inner(), resolution);
+                tracing::warn!(%conflict, "replication conflict");
+                if resolution == ConflictResolution::Rejected {
+                    return Err(ReplicationError::ConflictRejected(conflict));
+                }
+                repository.append(&incoming.ctx, message).await?;
+                Ok(Some(conflict))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Repeats a redaction locally. A message already redacted here needs
+    /// nothing more, whichever redaction came first.
+    async fn apply_redaction(
+        &self,
+        incoming: &Incoming<'_>,
+        redaction: &MessageRedaction,
+    ) -> ReplicationResult<Option<ReplicationConflict>> {
+        match self.deps.messages.redact(&incoming.ctx, redaction).await {
+            Ok(_) | Err(RepositoryError::AlreadyRedacted(_)) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn apply_pin(
+        &self,
+        incoming: &Incoming<'_>,
+        message_id: MessageId,
+        pinned: bool,
+    ) -> ReplicationResult<Option<ReplicationConflict>> {
+        self.deps
+            .messages
+            .set_pinned(&incoming.ctx, message_id, pinned)
+            .await?;
+        Ok(None)
+    }
+
+    /// Decides between a differing local copy and an incoming snapshot,
+    /// given their `(local, incoming)` update timestamps.
+    ///
+    /// An incoming snapshot newer than the local copy is ordinary
+    /// replication; anything else means the aggregate was also written here.
+    fn settle_upsert(
+        &self,
+        incoming: &Incoming<'_>,
+        aggregate_id: Uuid,
+        (local_updated_at, incoming_updated_at): (DateTime<Utc>, DateTime<Utc>),
+    ) -> ReplicationResult<(ConflictResolution, Option<ReplicationConflict>)> {
+        if incoming_updated_at > local_updated_at {
+            return Ok((ConflictResolution::ApplyIncoming, None));
+        }
+        let resolution = self.policy.resolve_upsert(
+            WriteVersion {
+                region: &self.region,
+                updated_at: local_updated_at,
+            },
+            WriteVersion {
+                region: incoming.origin,
+                updated_at: incoming_updated_at,
+            },
+        );
+        let conflict = incoming.conflict(aggregate_id, resolution);
+        tracing::warn!(%conflict, "replication conflict");
+        if resolution == ConflictResolution::Rejected {
+            return Err(ReplicationError::ConflictRejected(conflict));
+        }
+        Ok((resolution, Some(conflict)))
+    }
+}
@@ -0,0 +1,51 @@
+//! Export side of a replication stream.
+
+use crate::replication::{
+    domain::{RegionId, ReplicationBatch, ReplicationPosition},
+    ports::{ReplicationLog, ReplicationStoreResult},
+};
+use std::sync::Arc;
+
+/// Serves batches of this region's replication log to replicas.
+///
+/// Replicas pull with the position they last applied; batches carry the
+/// log head so the replica can tell how far behind it is.
+#[derive(Clone)]
+pub struct ReplicationExporter {
+    origin: RegionId,
+    log: Arc<dyn ReplicationLog>,
+}
+
+impl ReplicationExporter {
+    /// Creates an exporter for the log of region `origin`.
+    #[must_use]
+    pub fn new(origin: RegionId, log: Arc<dyn ReplicationLog>) -> Self {
+        Self { origin, log }
+    }
+
+    /// Returns the region this exporter serves.
+    #[must_use]
+    pub const fn origin(&self) -> &RegionId {
+        &self.origin
+    }
+
+    /// Returns up to `limit` events positioned after `after`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the log cannot be read.
+    pub async fn export(
+        &self,
+        after: ReplicationPosition,
+        limit: usize,
+    ) -> ReplicationStoreResult<ReplicationBatch> {
+        let events = self.log.read_after(after, limit).await?;
+        let head = self.log.head().await?;
+        Ok(ReplicationBatch {
+            origin: self.origin.clone(),
+            after,
+            events,
+            head,
+        })
+    }
+}
@@ -0,0 +1,80 @@
+//! Lag monitoring for the streams a replica applies.
+
+use crate::replication::{
+    domain::{RegionId, ReplicationHealth, ReplicationLag, ReplicationLagPolicy},
+    ports::{ReplicationCheckpointStore, ReplicationStoreResult},
+};
+use mockable::Clock;
+use std::sync::Arc;
+
+/// Reports how far behind each origin a replica is.
+///
+/// Lag is measured from the checkpoints the applier saves, so it reflects
+/// the origin's log head as of the last applied batch. A replica that stops
+/// pulling is caught by the silence threshold rather than the event count.
+#[derive(Clone)]
+pub struct ReplicationLagMonitor<C: Clock + Send + Sync> {
+    checkpoints: Arc<dyn ReplicationCheckpointStore>,
+    policy: ReplicationLagPolicy,
+    clock: Arc<C>,
+}
+
+impl<C: Clock + Send + Sync> ReplicationLagMonitor<C> {
+    /// Creates a monitor with the default lag thresholds.
+    #[must_use]
+    pub fn new(checkpoints: Arc<dyn ReplicationCheckpointStore>, clock: Arc<C>) -> Self {
+        Self {
+            checkpoints,
+            policy: ReplicationLagPolicy::default(),
+            clock,
+        }
+    }
+
+    /// Sets the lag thresholds.
+    #[must_use]
+    pub const fn with_policy(mut self, policy: ReplicationLagPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the lag behind `origin`, or `None` before its first batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the checkpoint store cannot be read.
+    pub async fn lag(&self, origin: &RegionId) -> ReplicationStoreResult<Option<ReplicationLag>> {
+        let checkpoint = self.checkpoints.load(origin).await?;
+        let now = self.clock.utc();
+        Ok(checkpoint.map(|stored| self.policy.assess(&stored, now)))
+    }
+
+    /// Returns the lag behind every known origin, logging a warning for
+    /// each stream that is lagging or stalled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the checkpoint store cannot be read.
+    pub async fn report(&self) -> ReplicationStoreResult<Vec<ReplicationLag>> {
+        let now = self.clock.utc();
+        let lags: Vec<ReplicationLag> = self
+            .checkpoints
+            .list()
+            .await?
+            .iter()
+            .map(|checkpoint| self.policy.assess(checkpoint, now))
+            .collect();
+        for lag in lags
+            .iter()
+            .filter(|lag| lag.health != ReplicationHealth::Healthy)
+        {
+            tracing::warn!(
+                origin = %lag.origin,
+                health = %lag.health,
+                pending_events = lag.pending_events,
+                since_sync_secs = lag.since_sync.as_secs(),
+                "replication is behind"
+            );
+        }
+        Ok(lags)
+    }
+}
@@ -0,0 +1,9 @@
+//! Services that export, apply, and monitor replication streams.
+
+pub mod apply;
+pub mod export;
+pub mod lag;
+
+pub use apply::{ReplicationApplier, ReplicationApplierDeps, ReplicationError, ReplicationResult};
+pub use export::ReplicationExporter;
+pub use lag::ReplicationLagMonitor;
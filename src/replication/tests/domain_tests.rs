@@ -0,0 +1,173 @@
+//! Unit tests for replication domain types.
+
+use crate::message::domain::Conversation;
+use crate::replication::domain::{
+    ConflictPolicy, ConflictResolution, EmptyRegionIdError, RegionId, ReplicatedChange,
+    ReplicationBatch, ReplicationCheckpoint, ReplicationEvent, ReplicationHead, ReplicationHealth,
+    ReplicationLagPolicy, ReplicationPosition, WriteVersion,
+};
+use crate::test_support::test_request_ctx;
+use chrono::{DateTime, TimeDelta, Utc};
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+use std::time::Duration;
+
+fn region(name: &str) -> RegionId {
+    RegionId::new(name).expect("valid region")
+}
+
+#[fixture]
+fn now() -> DateTime<Utc> {
+    DefaultClock.utc()
+}
+
+#[rstest]
+fn region_ids_are_trimmed_and_must_not_be_blank() {
+    assert_eq!(region("  eu-west ").as_str(), "eu-west");
+    assert_eq!(RegionId::new("   "), Err(EmptyRegionIdError));
+}
+
+#[rstest]
+#[case::incoming_later(
+    ConflictPolicy::LastWriterWins,
+    1,
+    "eu",
+    ConflictResolution::ApplyIncoming
+)]
+#[case::local_later(ConflictPolicy::LastWriterWins, -1, "eu", ConflictResolution::KeepLocal)]
+#[case::tie_to_greater_region(
+    ConflictPolicy::LastWriterWins,
+    0,
+    "us",
+    ConflictResolution::ApplyIncoming
+)]
+#[case::tie_to_local_region(ConflictPolicy::LastWriterWins, 0, "ap", ConflictResolution::KeepLocal)]
+#[case::prefer_origin(ConflictPolicy::PreferOrigin, -1, "eu", ConflictResolution::ApplyIncoming)]
+#[case::prefer_local(ConflictPolicy::PreferLocal, 1, "eu", ConflictResolution::KeepLocal)]
+#[case::reject(ConflictPolicy::Reject, 1, "eu", ConflictResolution::Rejected)]
+fn upsert_conflicts_follow_the_policy(
+    now: DateTime<Utc>,
+    #[case] policy: ConflictPolicy,
+    #[case] incoming_offset_secs: i64,
+    #[case] origin: &str,
+    #[case] expected: ConflictResolution,
+) {
+    let local_region = region("mid");
+    let origin_region = region(origin);
+
+    let resolution = policy.resolve_upsert(
+        WriteVersion {
+            region: &local_region,
+            updated_at: now,
+        },
+        WriteVersion {
+            region: &origin_region,
+            updated_at: now + TimeDelta::seconds(incoming_offset_secs),
+        },
+    );
+
+    assert_eq!(resolution, expected);
+}
+
+#[rstest]
+#[case(ConflictPolicy::LastWriterWins, ConflictResolution::AppendIncoming)]
+#[case(ConflictPolicy::PreferOrigin, ConflictResolution::AppendIncoming)]
+#[case(ConflictPolicy::PreferLocal, ConflictResolution::AppendIncoming)]
+#[case(ConflictPolicy::Reject, ConflictResolution::Rejected)]
+fn message_conflicts_keep_both_messages_unless_rejected(
+    #[case] policy: ConflictPolicy,
+    #[case] expected: ConflictResolution,
+) {
+    assert_eq!(policy.resolve_message(), expected);
+}
+
+#[rstest]
+fn batches_report_where_to_resume_and_whether_they_reach_the_head(now: DateTime<Utc>) {
+    let ctx = test_request_ctx();
+    let event = ReplicationEvent {
+        position: ReplicationPosition::new(3),
+        tenant_id: ctx.tenant_id(),
+        correlation_id: ctx.correlation_id(),
+        user_id: ctx.user_id(),
+        session_id: ctx.session_id(),
+        occurred_at: now,
+        change: ReplicatedChange::ConversationUpserted {
+            conversation: Box::new(Conversation::new(&DefaultClock)),
+        },
+    };
+    let mut batch = ReplicationBatch {
+        origin: region("eu"),
+        after: ReplicationPosition::new(2),
+        events: vec![],
+        head: ReplicationHead {
+            position: ReplicationPosition::new(4),
+            occurred_at: Some(now),
+        },
+    };
+    assert_eq!(batch.next_after(), ReplicationPosition::new(2));
+    assert!(!batch.is_caught_up());
+
+    batch.events.push(event);
+    batch.head.position = ReplicationPosition::new(3);
+
+    assert_eq!(batch.next_after(), ReplicationPosition::new(3));
+    assert!(batch.is_caught_up());
+}
+
+#[rstest]
+fn changes_serialise_with_a_kind_tag() {
+    let change = ReplicatedChange::ConversationUpserted {
+        conversation: Box::new(Conversation::new(&DefaultClock)),
+    };
+
+    let json = serde_json::to_value(&change).expect("serialise change");
+    let decoded: ReplicatedChange = serde_json::from_value(json.clone()).expect("decode change");
+
+    assert_eq!(
+        json.get("kind").and_then(serde_json::Value::as_str),
+        Some("conversation_upserted")
+    );
+    assert_eq!(decoded, change);
+}
+
+fn checkpoint(now: DateTime<Utc>, applied: u64, head: u64) -> ReplicationCheckpoint {
+    ReplicationCheckpoint {
+        applied: ReplicationPosition::new(applied),
+        applied_occurred_at: Some(now - TimeDelta::seconds(90)),
+        head: ReplicationHead {
+            position: ReplicationPosition::new(head),
+            occurred_at: Some(now),
+        },
+        ..ReplicationCheckpoint::new(region("eu"), now)
+    }
+}
+
+#[rstest]
+fn caught_up_replicas_are_healthy(now: DateTime<Utc>) {
+    let lag = ReplicationLagPolicy::default().assess(&checkpoint(now, 7, 7), now);
+
+    assert_eq!(lag.pending_events, 0);
+    assert_eq!(lag.behind, Some(Duration::ZERO));
+    assert_eq!(lag.health, ReplicationHealth::Healthy);
+}
+
+#[rstest]
+fn replicas_far_behind_the_head_are_lagging(now: DateTime<Utc>) {
+    let policy = ReplicationLagPolicy::default().with_max_behind(Duration::from_secs(30));
+
+    let lag = policy.assess(&checkpoint(now, 5, 7), now);
+
+    assert_eq!(lag.pending_events, 2);
+    assert_eq!(lag.behind, Some(Duration::from_secs(90)));
+    assert_eq!(lag.health, ReplicationHealth::Lagging);
+}
+
+#[rstest]
+fn replicas_that_stop_syncing_are_stalled(now: DateTime<Utc>) {
+    let policy = ReplicationLagPolicy::default().with_max_silence(Duration::from_secs(60));
+
+    let lag = policy.assess(&checkpoint(now, 7, 7), now + TimeDelta::minutes(5));
+
+    assert_eq!(lag.since_sync, Duration::from_secs(300));
+    assert_eq!(lag.health, ReplicationHealth::Stalled);
+}
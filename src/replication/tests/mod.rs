@@ -0,0 +1,4 @@
+//! Unit tests for replication domain and service logic.
+
+mod domain_tests;
+mod service_tests;
@@ -0,0 +1,163 @@
+//! Regions, fixtures and builders shared by the replication service tests.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{InMemoryConversationRepository, InMemoryMessageRepository},
+    domain::{ContentPart, Conversation, Message, Role, SequenceNumber, TextPart},
+    ports::ConversationRepository,
+};
+use crate::replication::{
+    adapters::{
+        ReplicatingConversationRepository, ReplicatingMessageRepository, ReplicatingTaskRepository,
+        memory::{InMemoryReplicationCheckpointStore, InMemoryReplicationLog},
+    },
+    domain::{ConflictPolicy, RegionId},
+    services::{
+        ReplicationApplier, ReplicationApplierDeps, ReplicationExporter, ReplicationLagMonitor,
+    },
+};
+use crate::task::{
+    adapters::memory::InMemoryTaskRepository,
+    domain::{ExternalIssue, ExternalIssueMetadata, IssueRef, Task},
+};
+use crate::test_support::test_request_ctx;
+use chrono::{DateTime, Local, TimeDelta, Utc};
+use mockable::{Clock, DefaultClock};
+use rstest::fixture;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Clock that only moves when told to.
+pub(super) struct ManualClock(Mutex<DateTime<Utc>>);
+
+impl ManualClock {
+    pub(super) fn new() -> Self {
+        Self(Mutex::new(DefaultClock.utc()))
+    }
+
+    pub(super) fn advance(&self, by: TimeDelta) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn local(&self) -> DateTime<Local> {
+        self.utc().with_timezone(&Local)
+    }
+
+    fn utc(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+pub(super) fn region(name: &str) -> RegionId {
+    RegionId::new(name).expect("valid region")
+}
+
+/// A region serving local traffic through replicating repositories.
+pub(super) struct Primary {
+    pub(super) conversations: ReplicatingConversationRepository<InMemoryConversationRepository>,
+    pub(super) messages: ReplicatingMessageRepository<InMemoryMessageRepository>,
+    pub(super) tasks: ReplicatingTaskRepository<InMemoryTaskRepository>,
+    pub(super) exporter: ReplicationExporter,
+}
+
+impl Primary {
+    pub(super) fn new(clock: &Arc<ManualClock>) -> Self {
+        let log = Arc::new(InMemoryReplicationLog::new());
+        Self {
+            conversations: ReplicatingConversationRepository::new(
+                Arc::new(InMemoryConversationRepository::new()),
+                log.clone(),
+                clock.clone(),
+            ),
+            messages: ReplicatingMessageRepository::new(
+                Arc::new(InMemoryMessageRepository::new()),
+                log.clone(),
+                clock.clone(),
+            ),
+            tasks: ReplicatingTaskRepository::new(
+                Arc::new(InMemoryTaskRepository::new()),
+                log.clone(),
+                clock.clone(),
+            ),
+            exporter: ReplicationExporter::new(region("primary"), log),
+        }
+    }
+}
+
+/// A standby region applying the primary's stream.
+pub(super) struct Replica {
+    pub(super) conversations: InMemoryConversationRepository,
+    pub(super) messages: InMemoryMessageRepository,
+    pub(super) tasks: InMemoryTaskRepository,
+    pub(super) checkpoints: InMemoryReplicationCheckpointStore,
+    pub(super) clock: Arc<ManualClock>,
+}
+
+impl Replica {
+    pub(super) fn new(clock: &Arc<ManualClock>) -> Self {
+        Self {
+            conversations: InMemoryConversationRepository::new(),
+            messages: InMemoryMessageRepository::new(),
+            tasks: InMemoryTaskRepository::new(),
+            checkpoints: InMemoryReplicationCheckpointStore::new(),
+            clock: clock.clone(),
+        }
+    }
+
+    pub(super) fn applier(&self, policy: ConflictPolicy) -> ReplicationApplier<ManualClock> {
+        let deps = ReplicationApplierDeps {
+            conversations: Arc::new(self.conversations.clone()),
+            messages: Arc::new(self.messages.clone()),
+            tasks: Arc::new(self.tasks.clone()),
+            checkpoints: Arc::new(self.checkpoints.clone()),
+        };
+        ReplicationApplier::new(region("standby"), deps, self.clock.clone())
+            .with_conflict_policy(policy)
+    }
+
+    pub(super) fn monitor(&self) -> ReplicationLagMonitor<ManualClock> {
+        ReplicationLagMonitor::new(Arc::new(self.checkpoints.clone()), self.clock.clone())
+    }
+}
+
+#[fixture]
+pub(super) fn clock() -> Arc<ManualClock> {
+    Arc::new(ManualClock::new())
+}
+
+#[fixture]
+pub(super) fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+pub(super) fn text_message(conversation: &Conversation, sequence: u64, text: &str) -> Message {
+    Message::new(
+        conversation.id(),
+        Role::User,
+        vec![ContentPart::Text(TextPart::new(text))],
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+pub(super) fn issue_task(clock: &ManualClock) -> Task {
+    let issue_ref = IssueRef::from_parts("github", "owner/repo", 7).expect("valid issue ref");
+    let metadata = ExternalIssueMetadata::new("Replicate me").expect("valid metadata");
+    Task::new_from_issue(&ExternalIssue::new(issue_ref, metadata), clock)
+}
+
+pub(super) async fn store_conversation(
+    primary: &Primary,
+    ctx: &RequestContext,
+    clock: &ManualClock,
+) -> Conversation {
+    let conversation = Conversation::new(clock);
+    primary
+        .conversations
+        .store(ctx, &conversation)
+        .await
+        .expect("store conversation");
+    conversation
+}
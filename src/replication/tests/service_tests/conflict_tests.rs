@@ -0,0 +1,165 @@
+//! Tests for settling writes that diverged between regions.
+
+use super::common::{
+    ManualClock, Primary, Replica, clock, ctx, region, store_conversation, text_message,
+};
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{Conversation, ConversationState},
+    ports::{ConversationRepository, MessageRepository},
+};
+use crate::pagination::PageRequest;
+use crate::replication::{
+    domain::{ConflictPolicy, ConflictResolution, ReplicatedAggregate, ReplicationPosition},
+    services::ReplicationError,
+};
+use chrono::TimeDelta;
+use rstest::rstest;
+use std::sync::Arc;
+
+#[rstest]
+#[case::last_writer_wins(ConflictPolicy::LastWriterWins, ConversationState::Completed)]
+#[case::prefer_origin(ConflictPolicy::PreferOrigin, ConversationState::Paused)]
+#[case::prefer_local(ConflictPolicy::PreferLocal, ConversationState::Completed)]
+#[tokio::test]
+async fn diverged_conversations_are_settled_by_the_policy(
+    clock: Arc<ManualClock>,
+    ctx: RequestContext,
+    #[case] policy: ConflictPolicy,
+    #[case] expected: ConversationState,
+) {
+    let primary = Primary::new(&clock);
+    let replica = Replica::new(&clock);
+    let applier = replica.applier(policy);
+    let mut conversation = store_conversation(&primary, &ctx, &clock).await;
+    let first = primary
+        .exporter
+        .export(ReplicationPosition::START, 100)
+        .await
+        .expect("export");
+    applier.apply(&first).await.expect("apply creation");
+    clock.advance(TimeDelta::seconds(1));
+    let mut local = conversation.clone();
+    conversation
+        .pause(clock.as_ref())
+        .expect("pause at primary");
+    primary
+        .conversations
+        .update(&ctx, &conversation)
+        .await
+        .expect("update");
+    clock.advance(TimeDelta::seconds(1));
+    local.complete(clock.as_ref()).expect("complete at standby");
+    replica
+        .conversations
+        .update(&ctx, &local)
+        .await
+        .expect("update");
+
+    let second = primary
+        .exporter
+        .export(first.next_after(), 100)
+        .await
+        .expect("export");
+    let report = applier.apply(&second).await.expect("apply update");
+
+    let conflict = report.conflicts.first().expect("conflict recorded");
+    assert_eq!(conflict.aggregate, ReplicatedAggregate::Conversation);
+    assert_eq!(report.checkpoint.conflicts, 1);
+    let stored = replica
+        .conversations
+        .find_by_id(&ctx, conversation.id())
+        .await
+        .expect("find")
+        .expect("replicated conversation");
+    assert_eq!(stored.state(), expected);
+}
+
+#[rstest]
+#[tokio::test]
+async fn rejected_conflicts_halt_the_stream(clock: Arc<ManualClock>, ctx: RequestContext) {
+    let primary = Primary::new(&clock);
+    let replica = Replica::new(&clock);
+    let conversation = store_conversation(&primary, &ctx, &clock).await;
+    let mut local = conversation.clone();
+    local.complete(clock.as_ref()).expect("complete at standby");
+    replica
+        .conversations
+        .store(&ctx, &local)
+        .await
+        .expect("store");
+    let applier = replica.applier(ConflictPolicy::Reject);
+
+    let batch = primary
+        .exporter
+        .export(ReplicationPosition::START, 100)
+        .await
+        .expect("export");
+    let result = applier.apply(&batch).await;
+
+    assert!(matches!(
+        result,
+        Err(ReplicationError::ConflictRejected(conflict))
+            if conflict.resolution == ConflictResolution::Rejected
+    ));
+    let lag = replica
+        .monitor()
+        .lag(&region("primary"))
+        .await
+        .expect("lag");
+    assert_eq!(lag.map(|found| found.pending_events), Some(1));
+}
+
+#[rstest]
+#[tokio::test]
+async fn clashing_messages_are_appended_after_local_ones(
+    clock: Arc<ManualClock>,
+    ctx: RequestContext,
+) {
+    let primary = Primary::new(&clock);
+    let replica = Replica::new(&clock);
+    let conversation = store_conversation(&primary, &ctx, &clock).await;
+    replica
+        .conversations
+        .store(&ctx, &conversation)
+        .await
+        .expect("store");
+    replica
+        .messages
+        .store(&ctx, &text_message(&conversation, 1, "from standby"))
+        .await
+        .expect("store local message");
+    let incoming = text_message(&conversation, 1, "from primary");
+    primary
+        .messages
+        .store(&ctx, &incoming)
+        .await
+        .expect("store");
+
+    let batch = primary
+        .exporter
+        .export(ReplicationPosition::START, 100)
+        .await
+        .expect("export");
+    let report = replica
+        .applier(ConflictPolicy::default())
+        .apply(&batch)
+        .await
+        .expect("apply");
+
+    assert_eq!(
+        report.conflicts.first().map(|conflict| conflict.resolution),
+        Some(ConflictResolution::AppendIncoming)
+    );
+    let page = replica
+        .messages
+        .find_by_conversation(&ctx, conversation.id(), PageRequest::default())
+        .await
+        .expect("list messages");
+    let sequences: Vec<u64> = page
+        .items()
+        .iter()
+        .map(|message| message.sequence_number().value())
+        .collect();
+    assert_eq!(sequences, vec![1, 2]);
+}
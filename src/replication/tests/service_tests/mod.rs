@@ -0,0 +1,5 @@
+//! Unit tests for exporting, applying, and monitoring replication streams.
+
+mod common;
+mod conflict_tests;
+mod stream_tests;
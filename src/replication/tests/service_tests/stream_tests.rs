@@ -0,0 +1,230 @@
+//! Tests for exporting, applying, and monitoring replication streams.
+
+use super::common::{
+    ManualClock, Primary, Replica, clock, ctx, issue_task, region, store_conversation, text_message,
+};
+use crate::context::RequestContext;
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::message::ports::{ConversationRepository, MessageRepository};
+use crate::operator::domain::OperatorReason;
+use crate::replication::{
+    domain::{ConflictPolicy, ReplicationHealth, ReplicationLagPolicy, ReplicationPosition},
+    services::{ReplicationApplier, ReplicationApplierDeps, ReplicationError},
+};
+use crate::task::{domain::TaskState, ports::TaskRepository};
+use chrono::TimeDelta;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[rstest]
+#[tokio::test]
+async fn replicas_converge_on_the_primarys_writes(clock: Arc<ManualClock>, ctx: RequestContext) {
+    let primary = Primary::new(&clock);
+    let replica = Replica::new(&clock);
+    let mut conversation = store_conversation(&primary, &ctx, &clock).await;
+    let message = text_message(&conversation, 1, "hello");
+    primary.messages.store(&ctx, &message).await.expect("store");
+    let mut task = issue_task(&clock);
+    primary.tasks.store(&ctx, &task).await.expect("store task");
+    clock.advance(TimeDelta::seconds(1));
+    conversation.pause(clock.as_ref()).expect("pause");
+    primary
+        .conversations
+        .update(&ctx, &conversation)
+        .await
+        .expect("update");
+    task.transition_to(TaskState::InProgress, clock.as_ref())
+        .expect("start");
+    primary
+        .tasks
+        .update(&ctx, &task)
+        .await
+        .expect("update task");
+
+    let batch = primary
+        .exporter
+        .export(ReplicationPosition::START, 100)
+        .await
+        .expect("export");
+    let report = replica
+        .applier(ConflictPolicy::default())
+        .apply(&batch)
+        .await
+        .expect("apply");
+
+    assert!(batch.is_caught_up());
+    assert_eq!(report.applied, 5);
+    assert!(report.conflicts.is_empty());
+    assert_eq!(report.checkpoint.applied, ReplicationPosition::new(5));
+    assert_eq!(
+        replica
+            .conversations
+            .find_by_id(&ctx, conversation.id())
+            .await
+            .expect("find"),
+        primary
+            .conversations
+            .find_by_id(&ctx, conversation.id())
+            .await
+            .expect("find at primary")
+    );
+    assert_eq!(
+        replica
+            .messages
+            .find_by_id(&ctx, message.id())
+            .await
+            .expect("find"),
+        Some(message)
+    );
+    assert_eq!(
+        replica
+            .tasks
+            .find_by_id(&ctx, task.id())
+            .await
+            .expect("find"),
+        Some(task)
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn reapplied_events_are_skipped(clock: Arc<ManualClock>, ctx: RequestContext) {
+    let primary = Primary::new(&clock);
+    let replica = Replica::new(&clock);
+    store_conversation(&primary, &ctx, &clock).await;
+    let applier = replica.applier(ConflictPolicy::default());
+    let batch = primary
+        .exporter
+        .export(ReplicationPosition::START, 100)
+        .await
+        .expect("export");
+    applier.apply(&batch).await.expect("first apply");
+
+    let report = applier.apply(&batch).await.expect("second apply");
+
+    assert_eq!((report.applied, report.skipped), (0, 1));
+}
+
+#[rstest]
+#[tokio::test]
+async fn batches_that_skip_events_are_refused(clock: Arc<ManualClock>, ctx: RequestContext) {
+    let primary = Primary::new(&clock);
+    let replica = Replica::new(&clock);
+    store_conversation(&primary, &ctx, &clock).await;
+    store_conversation(&primary, &ctx, &clock).await;
+
+    let batch = primary
+        .exporter
+        .export(ReplicationPosition::new(1), 100)
+        .await
+        .expect("export");
+    let result = replica
+        .applier(ConflictPolicy::default())
+        .apply(&batch)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ReplicationError::Gap { expected, found, .. })
+            if expected == ReplicationPosition::new(1) && found == ReplicationPosition::new(2)
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn batches_wait_out_maintenance(clock: Arc<ManualClock>, ctx: RequestContext) {
+    let primary = Primary::new(&clock);
+    let replica = Replica::new(&clock);
+    store_conversation(&primary, &ctx, &clock).await;
+    let gate = Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ));
+    let applier = replica
+        .applier(ConflictPolicy::default())
+        .with_maintenance(Arc::clone(&gate));
+    let batch = primary
+        .exporter
+        .export(ReplicationPosition::START, 100)
+        .await
+        .expect("export");
+    let reason = OperatorReason::new("failover").expect("valid reason");
+    gate.enable(reason, None).await.expect("enable maintenance");
+
+    let refused = applier.apply(&batch).await;
+    gate.disable(None).await.expect("disable maintenance");
+    let report = applier.apply(&batch).await.expect("apply");
+
+    assert!(matches!(refused, Err(ReplicationError::MaintenanceMode(_))));
+    assert_eq!((report.applied, report.skipped), (1, 0));
+}
+
+#[rstest]
+#[tokio::test]
+async fn a_region_refuses_its_own_stream(clock: Arc<ManualClock>) {
+    let primary = Primary::new(&clock);
+    let replica = Replica::new(&clock);
+    let deps = ReplicationApplierDeps {
+        conversations: Arc::new(replica.conversations.clone()),
+        messages: Arc::new(replica.messages.clone()),
+        tasks: Arc::new(replica.tasks.clone()),
+        checkpoints: Arc::new(replica.checkpoints.clone()),
+    };
+    let applier = ReplicationApplier::new(region("primary"), deps, clock.clone());
+    let batch = primary
+        .exporter
+        .export(ReplicationPosition::START, 100)
+        .await
+        .expect("export");
+
+    let result = applier.apply(&batch).await;
+
+    assert!(matches!(result, Err(ReplicationError::OwnOrigin(_))));
+}
+
+#[rstest]
+#[tokio::test]
+async fn the_monitor_reports_pending_and_stalled_streams(
+    clock: Arc<ManualClock>,
+    ctx: RequestContext,
+) {
+    let primary = Primary::new(&clock);
+    let replica = Replica::new(&clock);
+    for _ in 0..3 {
+        store_conversation(&primary, &ctx, &clock).await;
+    }
+    let batch = primary
+        .exporter
+        .export(ReplicationPosition::START, 1)
+        .await
+        .expect("export");
+    replica
+        .applier(ConflictPolicy::default())
+        .apply(&batch)
+        .await
+        .expect("apply");
+    let monitor = replica.monitor().with_policy(
+        ReplicationLagPolicy::default()
+            .with_max_pending_events(1)
+            .with_max_silence(Duration::from_secs(60)),
+    );
+
+    let lagging = monitor.report().await.expect("report");
+    clock.advance(TimeDelta::minutes(2));
+    let stalled = monitor.report().await.expect("report");
+
+    assert!(!batch.is_caught_up());
+    assert_eq!(
+        lagging
+            .iter()
+            .map(|lag| (lag.pending_events, lag.health))
+            .collect::<Vec<_>>(),
+        vec![(2, ReplicationHealth::Lagging)]
+    );
+    assert_eq!(
+        stalled.first().map(|lag| lag.health),
+        Some(ReplicationHealth::Stalled)
+    );
+}
@@ -0,0 +1,235 @@
+//! In-memory retention repository.
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::adapters::memory::{
+    InMemoryContextSnapshotAdapter, InMemoryConversationRepository, InMemoryMessageRepository,
+};
+use crate::message::domain::{ConversationId, Message, MessageId};
+use crate::message::ports::repository::MessageRepository;
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::retention::domain::{
+    ConversationRetention, PurgeBatch, PurgeCounts, PurgeMode, PurgeScope, PurgeSelection,
+};
+use crate::retention::ports::{
+    PurgeStore, RetentionError, RetentionPolicyRepository, RetentionResult,
+};
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+type TenantPolicies = HashMap<TenantId, HashMap<ConversationId, ConversationRetention>>;
+
+/// Thread-safe in-memory retention repository.
+///
+/// Policies are kept here; purges act on the attached message and snapshot
+/// adapters, confined to the tenant's conversations in the attached
+/// conversation repository. The in-memory adapters keep no domain events,
+/// so purges never count any.
+#[derive(Debug, Clone)]
+pub struct InMemoryRetentionRepository {
+    policies: Arc<RwLock<TenantPolicies>>,
+    conversations: InMemoryConversationRepository,
+    messages: InMemoryMessageRepository,
+    snapshots: InMemoryContextSnapshotAdapter,
+}
+
+impl InMemoryRetentionRepository {
+    /// Creates a repository with no policies over the given adapters.
+    #[must_use]
+    pub fn new(
+        conversations: InMemoryConversationRepository,
+        messages: InMemoryMessageRepository,
+        snapshots: InMemoryContextSnapshotAdapter,
+    ) -> Self {
+        Self {
+            policies: Arc::default(),
+            conversations,
+            messages,
+            snapshots,
+        }
+    }
+
+    fn tenant_conversations(
+        &self,
+        tenant_id: TenantId,
+    ) -> RetentionResult<HashSet<ConversationId>> {
+        self.conversations
+            .conversation_ids(tenant_id)
+            .map_err(RetentionError::persistence_failed)
+    }
+
+    async fn scoped(
+        &self,
+        ctx: &RequestContext,
+        scope: PurgeScope,
+    ) -> RetentionResult<HashSet<ConversationId>> {
+        let mut conversations = self.tenant_conversations(ctx.tenant_id())?;
+        match scope {
+            PurgeScope::Conversation(conversation_id) => {
+                conversations.retain(|id| *id == conversation_id);
+            }
+            PurgeScope::Default => {
+                if let Some(own) = self.policies.read().await.get(&ctx.tenant_id()) {
+                    conversations.retain(|id| !own.contains_key(id));
+                }
+            }
+        }
+        Ok(conversations)
+    }
+
+    async fn expired_messages(
+        &self,
+        ctx: &RequestContext,
+        selection: &PurgeSelection,
+    ) -> RetentionResult<Vec<MessageId>> {
+        let conversations = self.scoped(ctx, selection.scope).await?;
+        let messages = self
+            .messages
+            .created_before(&conversations, selection.cutoff)
+            .map_err(RetentionError::persistence_failed)?;
+        Ok(messages
+            .iter()
+            .filter(|message| selection.mode == PurgeMode::Delete || !message.is_redacted())
+            .map(Message::id)
+            .collect())
+    }
+
+    async fn expired_snapshots(
+        &self,
+        ctx: &RequestContext,
+        selection: &PurgeSelection,
+    ) -> RetentionResult<Vec<Uuid>> {
+        let conversations = self.scoped(ctx, selection.scope).await?;
+        self.snapshots
+            .captured_before(&conversations, selection.cutoff)
+            .map_err(RetentionError::persistence_failed)
+    }
+}
+
+#[async_trait]
+impl RetentionPolicyRepository for InMemoryRetentionRepository {
+    async fn set_policy(
+        &self,
+        ctx: &RequestContext,
+        retention: &ConversationRetention,
+    ) -> RetentionResult<()> {
+        let conversation_id = retention.conversation_id;
+        if !self
+            .tenant_conversations(ctx.tenant_id())?
+            .contains(&conversation_id)
+        {
+            return Err(RetentionError::ConversationNotFound(conversation_id));
+        }
+        self.policies
+            .write()
+            .await
+            .entry(ctx.tenant_id())
+            .or_default()
+            .insert(conversation_id, retention.clone());
+        Ok(())
+    }
+
+    async fn clear_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RetentionResult<bool> {
+        Ok(self
+            .policies
+            .write()
+            .await
+            .get_mut(&ctx.tenant_id())
+            .and_then(|own| own.remove(&conversation_id))
+            .is_some())
+    }
+
+    async fn find_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RetentionResult<Option<ConversationRetention>> {
+        Ok(self
+            .policies
+            .read()
+            .await
+            .get(&ctx.tenant_id())
+            .and_then(|own| own.get(&conversation_id))
+            .cloned())
+    }
+
+    async fn list_policies(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> RetentionResult<Page<ConversationRetention>> {
+        let mut policies = self
+            .policies
+            .read()
+            .await
+            .get(&ctx.tenant_id())
+            .map(|own| own.values().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        policies
+            .sort_by_key(|retention| (retention.set_at, retention.conversation_id.into_inner()));
+        Ok(Page::from_ordered(policies, page, |retention| {
+            Cursor::at_timestamp(retention.set_at, retention.conversation_id.into_inner())
+        }))
+    }
+}
+
+#[async_trait]
+impl PurgeStore for InMemoryRetentionRepository {
+    async fn count_expired(
+        &self,
+        ctx: &RequestContext,
+        selection: &PurgeSelection,
+    ) -> RetentionResult<PurgeCounts> {
+        let messages = self.expired_messages(ctx, selection).await?;
+        let snapshots = self.expired_snapshots(ctx, selection).await?;
+        Ok(PurgeCounts {
+            messages: count(messages.len()),
+            snapshots: count(snapshots.len()),
+            events: 0,
+        })
+    }
+
+    async fn purge_batch(
+        &self,
+        ctx: &RequestContext,
+        batch: &PurgeBatch,
+    ) -> RetentionResult<PurgeCounts> {
+        let mut messages = self.expired_messages(ctx, &batch.selection).await?;
+        messages.truncate(batch.limit);
+        let mut snapshots = self.expired_snapshots(ctx, &batch.selection).await?;
+        snapshots.truncate(batch.limit);
+
+        match batch.selection.mode {
+            PurgeMode::Delete => self
+                .messages
+                .remove(&messages)
+                .map_err(RetentionError::persistence_failed)?,
+            PurgeMode::Anonymise => {
+                for message_id in &messages {
+                    self.messages
+                        .redact(ctx, &batch.redaction(*message_id))
+                        .await
+                        .map_err(RetentionError::persistence_failed)?;
+                }
+            }
+        }
+        self.snapshots
+            .remove(&snapshots)
+            .map_err(RetentionError::persistence_failed)?;
+        Ok(PurgeCounts {
+            messages: count(messages.len()),
+            snapshots: count(snapshots.len()),
+            events: 0,
+        })
+    }
+}
+
+fn count(len: usize) -> u64 {
+    u64::try_from(len).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,7 @@
+//! Adapter implementations for the retention ports.
+
+pub mod memory;
+pub mod postgres;
+
+pub use memory::InMemoryRetentionRepository;
+pub use postgres::PostgresRetentionRepository;
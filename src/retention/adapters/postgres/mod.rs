@@ -0,0 +1,7 @@
+//! `PostgreSQL` adapters for retention policies and purges.
+
+mod models;
+mod purge;
+mod repository;
+
+pub use repository::PostgresRetentionRepository;
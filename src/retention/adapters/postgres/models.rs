@@ -0,0 +1,25 @@
+//! Diesel models for retention policy persistence.
+
+use crate::message::adapters::schema::conversation_retention_policies;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Row representation of a conversation's retention policy.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = conversation_retention_policies)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ConversationRetentionPolicyRow {
+    /// The conversation the policy applies to.
+    pub conversation_id: Uuid,
+    /// Owning tenant identifier.
+    pub tenant_id: Uuid,
+    /// Days the conversation's data is kept.
+    pub retention_days: i32,
+    /// What happens to expired data.
+    pub purge_mode: String,
+    /// User who set the policy.
+    pub set_by: Uuid,
+    /// When the policy was set.
+    pub set_at: DateTime<Utc>,
+}
@@ -0,0 +1,300 @@
+//! Purge statements for the `PostgreSQL` retention repository.
+//!
+//! Expired rows are selected with SQL built from the purge scope: a
+//! conversation scope matches one conversation, while the default scope
+//! excludes every conversation with a policy of its own. Every selection
+//! binds the tenant as `$1`, the cutoff as `$2`, the scoped conversation,
+//! if any, as `$3`, and the batch limit as `$4`.
+//!
+//! Deleting a message or event leaves its content in the rows the audit
+//! trigger wrote, so the purge clears their values too. Rolling summaries
+//! always cover a conversation from its first message, so deleting the
+//! oldest messages invalidates every summary of the conversation; they are
+//! dropped and rebuilt from what remains. Anonymised messages are redacted
+//! through the usual tombstones, whose trigger does both of these.
+//!
+//! Either way the batch's messages stop referring to their attachment
+//! blobs. Each batch returns the blobs no message of the tenant refers to
+//! any more, for the repository to delete once the batch commits.
+
+use crate::message::adapters::schema::{
+    context_snapshots, conversation_rolling_summaries, domain_events, messages,
+};
+use crate::message::domain::{ContentHash, MessageId};
+use crate::postgres_support::{
+    RowScope, load_blobs, message_blobs_query, redact_message, scrub_audit_values,
+    unreferenced_blobs_query,
+};
+use crate::retention::domain::{PurgeBatch, PurgeCounts, PurgeMode, PurgeScope, PurgeSelection};
+use crate::retention::ports::{RetentionError, RetentionResult};
+use diesel::pg::{Pg, PgConnection};
+use diesel::prelude::*;
+use diesel::query_builder::{BoxedSqlQuery, SqlQuery};
+use diesel::sql_types::{Array, BigInt, Nullable, Timestamptz, Uuid as SqlUuid};
+use serde_json::json;
+use uuid::Uuid;
+
+/// Events an anonymising purge has not yet stripped.
+const UNANONYMISED_EVENT: &str =
+    " AND NOT (e.event_data = '{}'::jsonb AND e.user_id IS NULL AND e.session_id IS NULL)";
+
+#[derive(QueryableByName)]
+struct IdRow {
+    #[diesel(sql_type = SqlUuid)]
+    id: Uuid,
+}
+
+#[derive(QueryableByName)]
+struct CountsRow {
+    #[diesel(sql_type = BigInt)]
+    messages: i64,
+    #[diesel(sql_type = BigInt)]
+    snapshots: i64,
+    #[diesel(sql_type = BigInt)]
+    events: i64,
+}
+
+/// Counts what a purge of `selection` would change.
+pub(super) fn count_expired(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    selection: &PurgeSelection,
+) -> RetentionResult<PurgeCounts> {
+    let sql = format!(
+        "SELECT \
+            (SELECT COUNT(*) FROM ({messages}) expired) AS messages, \
+            (SELECT COUNT(*) FROM ({snapshots}) expired) AS snapshots, \
+            (SELECT COUNT(*) FROM domain_events e \
+                WHERE e.aggregate_id IN (SELECT id FROM ({messages}) expired){pending}) \
+            + (SELECT COUNT(*) FROM ({events}) expired) AS events",
+        messages = expired_messages_sql(selection),
+        snapshots = expired_snapshots_sql(selection),
+        events = expired_conversation_events_sql(selection),
+        pending = pending_events_filter(selection.mode),
+    );
+    let row = selection_query(sql, tenant_id, selection, 0)
+        .get_result::<CountsRow>(conn)
+        .map_err(RetentionError::persistence_failed)?;
+    Ok(PurgeCounts {
+        messages: to_count(row.messages),
+        snapshots: to_count(row.snapshots),
+        events: to_count(row.events),
+    })
+}
+
+/// Purges one batch inside the caller's transaction, returning what it
+/// purged and the blobs left unreferenced.
+pub(super) fn purge_batch(
+    conn: &mut PgConnection,
+    scope: RowScope<'_>,
+    correlation_id: Uuid,
+    batch: &PurgeBatch,
+) -> RetentionResult<(PurgeCounts, Vec<ContentHash>)> {
+    let tenant_id = scope.tenant_id;
+    let selection = &batch.selection;
+    let limit = i64::try_from(batch.limit).unwrap_or(i64::MAX);
+    let message_ids = select_ids(
+        conn,
+        selection_query(
+            format!(
+                "{} ORDER BY m.created_at, m.id LIMIT $4",
+                expired_messages_sql(selection)
+            ),
+            tenant_id,
+            selection,
+            limit,
+        ),
+    )?;
+    let blobs = load_blobs(conn, message_blobs_query(tenant_id, &message_ids))
+        .map_err(RetentionError::persistence_failed)?;
+    let events = purge_events(conn, tenant_id, batch, &message_ids)?;
+    let snapshot_ids = select_ids(
+        conn,
+        selection_query(
+            format!(
+                "{} ORDER BY s.captured_at, s.id LIMIT $4",
+                expired_snapshots_sql(selection)
+            ),
+            tenant_id,
+            selection,
+            limit,
+        ),
+    )?;
+    let snapshots = diesel::delete(
+        context_snapshots::table.filter(context_snapshots::id.eq_any(&snapshot_ids)),
+    )
+    .execute(conn)
+    .map_err(RetentionError::persistence_failed)?;
+    let messages = match selection.mode {
+        PurgeMode::Delete => delete_messages(conn, tenant_id, &message_ids)?,
+        PurgeMode::Anonymise => {
+            for id in &message_ids {
+                let redaction = batch.redaction(MessageId::from_uuid(*id));
+                redact_message(conn, scope, correlation_id, &redaction)
+                    .map_err(RetentionError::persistence_failed)?;
+            }
+            message_ids.len()
+        }
+    };
+    let unreferenced = load_blobs(conn, unreferenced_blobs_query(tenant_id, &blobs))
+        .map_err(RetentionError::persistence_failed)?;
+    let counts = PurgeCounts {
+        messages: to_count_usize(messages),
+        snapshots: to_count_usize(snapshots),
+        events: to_count_usize(events),
+    };
+    Ok((counts, unreferenced))
+}
+
+/// Deletes or strips the events of the batch's messages, and a batch of
+/// expired events recorded against the scoped conversations themselves.
+fn purge_events(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    batch: &PurgeBatch,
+    message_ids: &[Uuid],
+) -> RetentionResult<usize> {
+    let selection = &batch.selection;
+    let limit = i64::try_from(batch.limit).unwrap_or(i64::MAX);
+    let sql = format!(
+        "SELECT e.id FROM domain_events e WHERE e.aggregate_id = ANY($5){pending} \
+         UNION ({events} ORDER BY e.occurred_at, e.id LIMIT $4)",
+        pending = pending_events_filter(selection.mode),
+        events = expired_conversation_events_sql(selection),
+    );
+    let query = selection_query(sql, tenant_id, selection, limit)
+        .bind::<Array<SqlUuid>, _>(message_ids.to_vec());
+    let event_ids = select_ids(conn, query)?;
+    let events = domain_events::table.filter(domain_events::id.eq_any(&event_ids));
+    let purged = match selection.mode {
+        PurgeMode::Delete => diesel::delete(events).execute(conn),
+        PurgeMode::Anonymise => diesel::update(events)
+            .set((
+                domain_events::event_data.eq(json!({})),
+                domain_events::user_id.eq(None::<Uuid>),
+                domain_events::session_id.eq(None::<Uuid>),
+            ))
+            .execute(conn),
+    }
+    .map_err(RetentionError::persistence_failed)?;
+    scrub_audit_values(conn, "domain_events", &event_ids)
+        .map_err(RetentionError::persistence_failed)?;
+    Ok(purged)
+}
+
+/// Deletes messages, then clears their audit rows and the rolling
+/// summaries that covered them.
+fn delete_messages(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    message_ids: &[Uuid],
+) -> RetentionResult<usize> {
+    let mut conversation_ids = diesel::delete(
+        messages::table
+            .filter(messages::tenant_id.eq(tenant_id))
+            .filter(messages::id.eq_any(message_ids)),
+    )
+    .returning(messages::conversation_id)
+    .get_results::<Uuid>(conn)
+    .map_err(RetentionError::persistence_failed)?;
+    let deleted = conversation_ids.len();
+    conversation_ids.sort_unstable();
+    conversation_ids.dedup();
+    diesel::delete(
+        conversation_rolling_summaries::table
+            .filter(conversation_rolling_summaries::tenant_id.eq(tenant_id))
+            .filter(conversation_rolling_summaries::conversation_id.eq_any(&conversation_ids)),
+    )
+    .execute(conn)
+    .map_err(RetentionError::persistence_failed)?;
+    scrub_audit_values(conn, "messages", message_ids)
+        .map_err(RetentionError::persistence_failed)?;
+    Ok(deleted)
+}
+
+fn select_ids(
+    conn: &mut PgConnection,
+    query: BoxedSqlQuery<'static, Pg, SqlQuery>,
+) -> RetentionResult<Vec<Uuid>> {
+    query
+        .load::<IdRow>(conn)
+        .map(|rows| rows.into_iter().map(|row| row.id).collect())
+        .map_err(RetentionError::persistence_failed)
+}
+
+/// Binds the selection parameters shared by every purge statement.
+fn selection_query(
+    sql: String,
+    tenant_id: Uuid,
+    selection: &PurgeSelection,
+    limit: i64,
+) -> BoxedSqlQuery<'static, Pg, SqlQuery> {
+    let conversation_id = match selection.scope {
+        PurgeScope::Conversation(conversation_id) => Some(conversation_id.into_inner()),
+        PurgeScope::Default => None,
+    };
+    diesel::sql_query(sql)
+        .into_boxed()
+        .bind::<SqlUuid, _>(tenant_id)
+        .bind::<Timestamptz, _>(selection.cutoff)
+        .bind::<Nullable<SqlUuid>, _>(conversation_id)
+        .bind::<BigInt, _>(limit)
+}
+
+/// Expired messages; anonymisation skips messages already redacted.
+fn expired_messages_sql(selection: &PurgeSelection) -> String {
+    let unredacted = match selection.mode {
+        PurgeMode::Delete => "",
+        PurgeMode::Anonymise => {
+            " AND NOT EXISTS (SELECT 1 FROM message_redactions r WHERE r.message_id = m.id)"
+        }
+    };
+    format!(
+        "SELECT m.id FROM messages m \
+         WHERE m.tenant_id = $1 AND m.created_at < $2 AND {scope}{unredacted}",
+        scope = scope_condition(selection.scope, "m.conversation_id"),
+    )
+}
+
+fn expired_snapshots_sql(selection: &PurgeSelection) -> String {
+    format!(
+        "SELECT s.id FROM context_snapshots s \
+         WHERE s.tenant_id = $1 AND s.captured_at < $2 AND {scope}",
+        scope = scope_condition(selection.scope, "s.conversation_id"),
+    )
+}
+
+/// Expired events recorded against the scoped conversations.
+fn expired_conversation_events_sql(selection: &PurgeSelection) -> String {
+    format!(
+        "SELECT e.id FROM domain_events e JOIN conversations c ON c.id = e.aggregate_id \
+         WHERE c.tenant_id = $1 AND e.occurred_at < $2 AND {scope}{pending}",
+        scope = scope_condition(selection.scope, "c.id"),
+        pending = pending_events_filter(selection.mode),
+    )
+}
+
+const fn pending_events_filter(mode: PurgeMode) -> &'static str {
+    match mode {
+        PurgeMode::Delete => "",
+        PurgeMode::Anonymise => UNANONYMISED_EVENT,
+    }
+}
+
+fn scope_condition(scope: PurgeScope, conversation_column: &str) -> String {
+    match scope {
+        PurgeScope::Conversation(_) => format!("{conversation_column} = $3"),
+        PurgeScope::Default => format!(
+            "NOT EXISTS (SELECT 1 FROM conversation_retention_policies p \
+             WHERE p.tenant_id = $1 AND p.conversation_id = {conversation_column})"
+        ),
+    }
+}
+
+fn to_count(value: i64) -> u64 {
+    u64::try_from(value).unwrap_or(0)
+}
+
+fn to_count_usize(value: usize) -> u64 {
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
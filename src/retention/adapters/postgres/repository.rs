@@ -0,0 +1,306 @@
+//! `PostgreSQL` repository implementation for retention policies and purges.
+
+use super::models::ConversationRetentionPolicyRow;
+use super::purge;
+use crate::context::{RequestContext, TenantId, UserId};
+use crate::message::adapters::audit_context::AuditContext;
+use crate::message::adapters::schema::{conversation_retention_policies, conversations};
+use crate::message::domain::ConversationId;
+use crate::message::ports::{BlobStore, ContentCipher};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{
+    FromTxError, MessageCodec, PgPool, RowScope, TxError, delete_blobs, ensure_tenant_exists,
+    get_conn_with, keyset_page, run_blocking_with, set_audit_context, with_tenant_read_tx,
+    with_tenant_tx,
+};
+use crate::retention::domain::{
+    ConversationRetention, PurgeBatch, PurgeCounts, PurgeMode, PurgeSelection, RetentionPolicy,
+};
+use crate::retention::ports::{
+    PurgeStore, RetentionError, RetentionPolicyRepository, RetentionResult,
+};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+impl FromTxError<Self> for RetentionError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(error) => error,
+            TxError::Diesel(error) => Self::persistence_failed(error),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed retention policy repository and purge store.
+#[derive(Clone)]
+pub struct PostgresRetentionRepository {
+    pool: PgPool,
+    codec: MessageCodec,
+    blobs: Option<Arc<dyn BlobStore>>,
+}
+
+crate::postgres_support::pool_health_check!(PostgresRetentionRepository, "retention_repository");
+
+impl PostgresRetentionRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            codec: MessageCodec::plain(),
+            blobs: None,
+        }
+    }
+
+    /// Deletes from `blobs` the attachment blobs purged messages leave
+    /// unreferenced. Pass the store the message repository externalises
+    /// attachments to.
+    #[must_use]
+    pub fn with_blob_store(mut self, blobs: Arc<dyn BlobStore>) -> Self {
+        self.blobs = Some(blobs);
+        self
+    }
+
+    /// Reads and rewrites encrypted messages with `cipher` when anonymising
+    /// purges redact them. Pass the cipher the message repository was given.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Arc<dyn ContentCipher>) -> Self {
+        self.codec = MessageCodec::sealed(cipher);
+        self
+    }
+
+    async fn read<F, T>(&self, tenant_id: TenantId, query_fn: F) -> RetentionResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> RetentionResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, RetentionError::persistence_failed)?;
+                with_tenant_read_tx(&mut conn, tenant_id.into_inner(), query_fn)
+            },
+            RetentionError::persistence_failed,
+        )
+        .await
+    }
+
+    async fn write<F, T>(&self, ctx: &RequestContext, write_fn: F) -> RetentionResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> RetentionResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let audit = AuditContext::from(ctx);
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, RetentionError::persistence_failed)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid)
+                        .map_err(RetentionError::persistence_failed)?;
+                    set_audit_context(tx, &audit).map_err(RetentionError::persistence_failed)?;
+                    write_fn(tx)
+                })
+            },
+            RetentionError::persistence_failed,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl RetentionPolicyRepository for PostgresRetentionRepository {
+    async fn set_policy(
+        &self,
+        ctx: &RequestContext,
+        retention: &ConversationRetention,
+    ) -> RetentionResult<()> {
+        let conversation_id = retention.conversation_id;
+        let row = to_row(retention, ctx.tenant_id().into_inner());
+        self.write(ctx, move |tx| {
+            let exists = diesel::select(diesel::dsl::exists(
+                conversations::table
+                    .filter(conversations::id.eq(row.conversation_id))
+                    .filter(conversations::tenant_id.eq(row.tenant_id)),
+            ))
+            .get_result::<bool>(tx)
+            .map_err(RetentionError::persistence_failed)?;
+            if !exists {
+                return Err(RetentionError::ConversationNotFound(conversation_id));
+            }
+            diesel::insert_into(conversation_retention_policies::table)
+                .values(&row)
+                .on_conflict(conversation_retention_policies::conversation_id)
+                .do_update()
+                .set(&row)
+                .execute(tx)
+                .map(|_| ())
+                .map_err(RetentionError::persistence_failed)
+        })
+        .await
+    }
+
+    async fn clear_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RetentionResult<bool> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        self.write(ctx, move |tx| {
+            diesel::delete(
+                conversation_retention_policies::table
+                    .filter(conversation_retention_policies::tenant_id.eq(tenant_uuid))
+                    .filter(
+                        conversation_retention_policies::conversation_id
+                            .eq(conversation_id.into_inner()),
+                    ),
+            )
+            .execute(tx)
+            .map(|deleted| deleted > 0)
+            .map_err(RetentionError::persistence_failed)
+        })
+        .await
+    }
+
+    async fn find_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RetentionResult<Option<ConversationRetention>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = self
+            .read(ctx.tenant_id(), move |conn| {
+                conversation_retention_policies::table
+                    .filter(conversation_retention_policies::tenant_id.eq(tenant_uuid))
+                    .filter(
+                        conversation_retention_policies::conversation_id
+                            .eq(conversation_id.into_inner()),
+                    )
+                    .select(ConversationRetentionPolicyRow::as_select())
+                    .first::<ConversationRetentionPolicyRow>(conn)
+                    .optional()
+                    .map_err(RetentionError::persistence_failed)
+            })
+            .await?;
+        row.map(row_to_retention).transpose()
+    }
+
+    async fn list_policies(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> RetentionResult<Page<ConversationRetention>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let rows = self
+            .read(ctx.tenant_id(), move |conn| {
+                let filtered = conversation_retention_policies::table
+                    .filter(conversation_retention_policies::tenant_id.eq(tenant_uuid))
+                    .into_boxed();
+                let paged = keyset_page!(
+                    filtered,
+                    page,
+                    after_timestamp,
+                    (
+                        conversation_retention_policies::set_at,
+                        conversation_retention_policies::conversation_id
+                    )
+                );
+                paged
+                    .select(ConversationRetentionPolicyRow::as_select())
+                    .load::<ConversationRetentionPolicyRow>(conn)
+                    .map_err(RetentionError::persistence_failed)
+            })
+            .await?;
+        Page::from_overfetched(rows, page, |row| {
+            Cursor::at_timestamp(row.set_at, row.conversation_id)
+        })
+        .try_map(row_to_retention)
+    }
+}
+
+#[async_trait]
+impl PurgeStore for PostgresRetentionRepository {
+    async fn count_expired(
+        &self,
+        ctx: &RequestContext,
+        selection: &PurgeSelection,
+    ) -> RetentionResult<PurgeCounts> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let selection = *selection;
+        self.read(ctx.tenant_id(), move |conn| {
+            purge::count_expired(conn, tenant_uuid, &selection)
+        })
+        .await
+    }
+
+    async fn purge_batch(
+        &self,
+        ctx: &RequestContext,
+        batch: &PurgeBatch,
+    ) -> RetentionResult<PurgeCounts> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let correlation_id = ctx.correlation_id().into_inner();
+        let batch = batch.clone();
+        let codec = self.codec.clone();
+        let (counts, unreferenced) = self
+            .write(ctx, move |tx| {
+                let scope = RowScope {
+                    tenant_id: tenant_uuid,
+                    codec: &codec,
+                };
+                purge::purge_batch(tx, scope, correlation_id, &batch)
+            })
+            .await?;
+        if let Some(blobs) = &self.blobs {
+            delete_blobs(blobs.as_ref(), ctx, &unreferenced)
+                .await
+                .map_err(RetentionError::persistence_failed)?;
+        }
+        Ok(counts)
+    }
+}
+
+impl std::fmt::Debug for PostgresRetentionRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PostgresRetentionRepository")
+            .field("codec", &self.codec)
+            .field("blobs", &self.blobs.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+fn to_row(
+    retention: &ConversationRetention,
+    tenant_id: uuid::Uuid,
+) -> ConversationRetentionPolicyRow {
+    ConversationRetentionPolicyRow {
+        conversation_id: retention.conversation_id.into_inner(),
+        tenant_id,
+        retention_days: i32::try_from(retention.policy.retention_days()).unwrap_or(i32::MAX),
+        purge_mode: retention.policy.mode().as_str().to_owned(),
+        set_by: retention.set_by.into_inner(),
+        set_at: retention.set_at,
+    }
+}
+
+fn row_to_retention(row: ConversationRetentionPolicyRow) -> RetentionResult<ConversationRetention> {
+    let mode = PurgeMode::try_from(row.purge_mode.as_str())
+        .map_err(|err| RetentionError::invalid_persisted_data(err.to_string()))?;
+    let days = u32::try_from(row.retention_days).map_err(|_| {
+        RetentionError::invalid_persisted_data(format!(
+            "retention policy for conversation {} has {} retention days",
+            row.conversation_id, row.retention_days
+        ))
+    })?;
+    let policy = RetentionPolicy::new(days, mode)
+        .map_err(|err| RetentionError::invalid_persisted_data(err.to_string()))?;
+    Ok(ConversationRetention {
+        conversation_id: ConversationId::from_uuid(row.conversation_id),
+        policy,
+        set_by: UserId::from_uuid(row.set_by),
+        set_at: row.set_at,
+    })
+}
@@ -0,0 +1,364 @@
+//! Domain types for retention policies and purges.
+
+use crate::context::{RequestContext, UserId};
+use crate::message::domain::{ConversationId, MessageId, MessageRedaction, RedactionReason};
+use chrono::{DateTime, TimeDelta, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::AddAssign;
+use thiserror::Error;
+
+/// Longest retention period a policy may set, in days.
+pub const MAX_RETENTION_DAYS: u32 = 36_500;
+
+/// Default number of rows of each kind purged per batch.
+pub const DEFAULT_PURGE_BATCH_SIZE: usize = 500;
+
+/// Errors raised while constructing retention domain values.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RetentionDomainError {
+    /// The retention period is zero or exceeds [`MAX_RETENTION_DAYS`].
+    #[error("retention period of {0} days is outside 1 to {MAX_RETENTION_DAYS} days")]
+    InvalidPeriod(u32),
+    /// The text does not name a purge mode.
+    #[error("unknown purge mode: {0}")]
+    UnknownMode(String),
+}
+
+/// What a purge does to expired data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PurgeMode {
+    /// Deletes expired messages, snapshots, and events.
+    Delete,
+    /// Redacts expired messages in place and strips the payload and actor
+    /// from expired events, keeping conversation history contiguous.
+    /// Snapshots carry nothing worth keeping once their messages are
+    /// anonymised, so they are deleted.
+    Anonymise,
+}
+
+impl PurgeMode {
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Delete => "delete",
+            Self::Anonymise => "anonymise",
+        }
+    }
+}
+
+impl fmt::Display for PurgeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<&str> for PurgeMode {
+    type Error = RetentionDomainError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "delete" => Ok(Self::Delete),
+            "anonymise" => Ok(Self::Anonymise),
+            _ => Err(RetentionDomainError::UnknownMode(value.to_owned())),
+        }
+    }
+}
+
+/// How long conversation data is kept and what happens to it afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeDelta, Utc};
+/// use corbusier::retention::domain::{PurgeMode, RetentionPolicy};
+///
+/// let policy = RetentionPolicy::new(30, PurgeMode::Delete).expect("valid policy");
+/// let now = Utc::now();
+/// assert_eq!(policy.cutoff(now), now - TimeDelta::days(30));
+/// assert!(RetentionPolicy::new(0, PurgeMode::Delete).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    retention_days: u32,
+    mode: PurgeMode,
+}
+
+impl RetentionPolicy {
+    /// Creates a policy keeping data for `retention_days` days.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RetentionDomainError::InvalidPeriod`] when `retention_days`
+    /// is zero or exceeds [`MAX_RETENTION_DAYS`].
+    pub const fn new(retention_days: u32, mode: PurgeMode) -> Result<Self, RetentionDomainError> {
+        if retention_days == 0 || retention_days > MAX_RETENTION_DAYS {
+            return Err(RetentionDomainError::InvalidPeriod(retention_days));
+        }
+        Ok(Self {
+            retention_days,
+            mode,
+        })
+    }
+
+    /// Returns how many days data is kept.
+    #[must_use]
+    pub const fn retention_days(self) -> u32 {
+        self.retention_days
+    }
+
+    /// Returns what happens to data once it expires.
+    #[must_use]
+    pub const fn mode(self) -> PurgeMode {
+        self.mode
+    }
+
+    /// Returns the retention period.
+    #[must_use]
+    pub fn retention_period(self) -> TimeDelta {
+        TimeDelta::days(i64::from(self.retention_days))
+    }
+
+    /// Returns the instant before which data recorded is expired at `now`.
+    #[must_use]
+    pub fn cutoff(self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - self.retention_period()
+    }
+}
+
+/// A retention policy set on one conversation, overriding the default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversationRetention {
+    /// The conversation the policy applies to.
+    pub conversation_id: ConversationId,
+    /// The policy.
+    pub policy: RetentionPolicy,
+    /// The user who set the policy.
+    pub set_by: UserId,
+    /// When the policy was set.
+    pub set_at: DateTime<Utc>,
+}
+
+impl ConversationRetention {
+    /// Records `policy` as set on `conversation_id` now by the user in
+    /// `ctx`.
+    #[must_use]
+    pub fn new(
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        policy: RetentionPolicy,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        Self {
+            conversation_id,
+            policy,
+            set_by: ctx.user_id(),
+            set_at: clock.utc(),
+        }
+    }
+}
+
+/// Which conversations a purge covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PurgeScope {
+    /// One conversation with its own policy.
+    Conversation(ConversationId),
+    /// Every conversation without a policy of its own.
+    Default,
+}
+
+impl fmt::Display for PurgeScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Conversation(conversation_id) => write!(f, "conversation {conversation_id}"),
+            Self::Default => f.write_str("default policy"),
+        }
+    }
+}
+
+/// The expired data one policy selects: everything in `scope` recorded
+/// before `cutoff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PurgeSelection {
+    /// Conversations covered.
+    pub scope: PurgeScope,
+    /// Data recorded before this instant is expired.
+    pub cutoff: DateTime<Utc>,
+    /// What happens to the expired data.
+    pub mode: PurgeMode,
+}
+
+impl PurgeSelection {
+    /// Selects the data in `scope` that `policy` expires at `now`.
+    #[must_use]
+    pub fn new(scope: PurgeScope, policy: RetentionPolicy, now: DateTime<Utc>) -> Self {
+        Self {
+            scope,
+            cutoff: policy.cutoff(now),
+            mode: policy.mode(),
+        }
+    }
+}
+
+/// One bounded step of a purge.
+///
+/// A batch purges at most `limit` messages, `limit` snapshots, and `limit`
+/// conversation events, together with the events recorded against the
+/// purged messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PurgeBatch {
+    /// The expired data to purge.
+    pub selection: PurgeSelection,
+    /// Most rows of each kind to purge.
+    pub limit: usize,
+    /// Why anonymised messages were redacted.
+    pub reason: RedactionReason,
+    /// The user running the purge.
+    pub purged_by: UserId,
+    /// When the purge started.
+    pub purged_at: DateTime<Utc>,
+}
+
+impl PurgeBatch {
+    /// Returns the redaction that anonymises `message_id` in this batch.
+    #[must_use]
+    pub fn redaction(&self, message_id: MessageId) -> MessageRedaction {
+        MessageRedaction::made_at(
+            message_id,
+            self.reason.clone(),
+            self.purged_by,
+            self.purged_at,
+        )
+    }
+}
+
+/// Numbers of rows purged, or that a dry run would purge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PurgeCounts {
+    /// Messages deleted or redacted.
+    pub messages: u64,
+    /// Context snapshots deleted.
+    pub snapshots: u64,
+    /// Domain events deleted or anonymised.
+    pub events: u64,
+}
+
+impl PurgeCounts {
+    /// Returns the total number of rows.
+    #[must_use]
+    pub const fn total(self) -> u64 {
+        self.messages
+            .saturating_add(self.snapshots)
+            .saturating_add(self.events)
+    }
+
+    /// Returns `true` when no rows were counted.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.total() == 0
+    }
+}
+
+impl AddAssign for PurgeCounts {
+    fn add_assign(&mut self, rhs: Self) {
+        self.messages = self.messages.saturating_add(rhs.messages);
+        self.snapshots = self.snapshots.saturating_add(rhs.snapshots);
+        self.events = self.events.saturating_add(rhs.events);
+    }
+}
+
+/// How a purge runs.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::RedactionReason;
+/// use corbusier::retention::domain::{DEFAULT_PURGE_BATCH_SIZE, PurgeOptions};
+///
+/// let reason = RedactionReason::new("retention policy DP-7").expect("valid reason");
+/// let options = PurgeOptions::new(reason).dry_run();
+/// assert!(options.is_dry_run());
+/// assert_eq!(options.batch_size(), DEFAULT_PURGE_BATCH_SIZE);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PurgeOptions {
+    reason: RedactionReason,
+    batch_size: usize,
+    dry_run: bool,
+}
+
+impl PurgeOptions {
+    /// Creates options for a purge made for `reason`, recorded on every
+    /// message it anonymises.
+    #[must_use]
+    pub const fn new(reason: RedactionReason) -> Self {
+        Self {
+            reason,
+            batch_size: DEFAULT_PURGE_BATCH_SIZE,
+            dry_run: false,
+        }
+    }
+
+    /// Sets the most rows of each kind purged per batch, at least one.
+    #[must_use]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Counts the expired data without changing it.
+    #[must_use]
+    pub const fn dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+
+    /// Returns why the purge is made.
+    #[must_use]
+    pub const fn reason(&self) -> &RedactionReason {
+        &self.reason
+    }
+
+    /// Returns the most rows of each kind purged per batch.
+    #[must_use]
+    pub const fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Returns `true` when the purge only counts the expired data.
+    #[must_use]
+    pub const fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+/// Progress of a running purge, reported after every batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PurgeProgress {
+    /// The expired data being purged.
+    pub selection: PurgeSelection,
+    /// Batches completed so far across the whole purge.
+    pub batches: u32,
+    /// Rows purged by the latest batch.
+    pub batch: PurgeCounts,
+    /// Rows purged so far across the whole purge.
+    pub total: PurgeCounts,
+    /// Whether the counts describe a dry run.
+    pub dry_run: bool,
+}
+
+/// Outcome of a purge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PurgeReport {
+    /// Whether nothing was changed.
+    pub dry_run: bool,
+    /// Policies applied: conversation policies plus the default, if any.
+    pub policies: u32,
+    /// Batches run; a dry run counts each policy as one batch.
+    pub batches: u32,
+    /// Rows purged, or that would have been purged.
+    pub purged: PurgeCounts,
+}
@@ -0,0 +1,24 @@
+//! Retention policies and purging of expired conversation data.
+//!
+//! Compliance rules require conversation data to be removed once it is
+//! older than a retention period. A [`domain::RetentionPolicy`] states the
+//! period and whether expired data is deleted or anonymised; the purge
+//! service applies a default policy to every conversation, except those
+//! given a policy of their own through a
+//! [`ports::RetentionPolicyRepository`]. Purges run in bounded batches
+//! through a [`ports::PurgeStore`], report progress after every batch, and
+//! can be rehearsed as a dry run that only counts. The module follows
+//! hexagonal architecture:
+//!
+//! - Domain types in [`domain`]
+//! - Port contracts in [`ports`]
+//! - Adapter implementations in [`adapters`]
+//! - The purge service in [`services`]
+
+pub mod adapters;
+pub mod domain;
+pub mod ports;
+pub mod services;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,125 @@
+//! Port contracts for retention policies and purges.
+
+use crate::context::RequestContext;
+use crate::maintenance::domain::MaintenanceMode;
+use crate::message::domain::ConversationId;
+use crate::pagination::{Page, PageRequest};
+use crate::retention::domain::{
+    ConversationRetention, PurgeBatch, PurgeCounts, PurgeProgress, PurgeSelection,
+};
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type for retention operations.
+pub type RetentionResult<T> = Result<T, RetentionError>;
+
+/// Store of the retention policies set on individual conversations.
+#[async_trait]
+pub trait RetentionPolicyRepository: Send + Sync {
+    /// Sets the conversation's policy, replacing any it had.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RetentionError::ConversationNotFound`] when the tenant has
+    /// no such conversation.
+    async fn set_policy(
+        &self,
+        ctx: &RequestContext,
+        retention: &ConversationRetention,
+    ) -> RetentionResult<()>;
+
+    /// Removes the conversation's policy, returning whether it had one.
+    async fn clear_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RetentionResult<bool>;
+
+    /// Returns the conversation's policy, if it has one.
+    async fn find_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RetentionResult<Option<ConversationRetention>>;
+
+    /// Returns the tenant's conversation policies, oldest first in natural
+    /// order.
+    async fn list_policies(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> RetentionResult<Page<ConversationRetention>>;
+}
+
+/// Store that purges expired conversation data.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Only the tenant's conversations in the selection's scope are touched,
+///   and a [`PurgeScope::Default`] selection skips every conversation with
+///   a policy of its own
+/// - Each batch is applied atomically, so an interrupted purge can be
+///   rerun and continues where it stopped
+/// - Anonymised rows are not selected again, so repeated batches make
+///   progress
+///
+/// [`PurgeScope::Default`]: crate::retention::domain::PurgeScope::Default
+#[async_trait]
+pub trait PurgeStore: Send + Sync {
+    /// Counts the data a purge of `selection` would change, without
+    /// changing it.
+    async fn count_expired(
+        &self,
+        ctx: &RequestContext,
+        selection: &PurgeSelection,
+    ) -> RetentionResult<PurgeCounts>;
+
+    /// Purges one batch of expired data, oldest first, and returns what it
+    /// purged. An empty count means nothing expired remains.
+    async fn purge_batch(
+        &self,
+        ctx: &RequestContext,
+        batch: &PurgeBatch,
+    ) -> RetentionResult<PurgeCounts>;
+}
+
+/// Receives progress reports while a purge runs.
+pub trait PurgeProgressReporter: Send + Sync {
+    /// Called after every batch.
+    fn report(&self, progress: &PurgeProgress);
+}
+
+/// Errors returned by retention repository implementations.
+#[derive(Debug, Clone, Error)]
+pub enum RetentionError {
+    /// The tenant has no such conversation.
+    #[error("conversation not found: {0}")]
+    ConversationNotFound(ConversationId),
+    /// Persistence-layer failure.
+    #[error("retention persistence operation failed: {reason}")]
+    PersistenceFailed {
+        /// Human-readable reason from the failing dependency.
+        reason: String,
+    },
+    /// Persisted data failed validation.
+    #[error("invalid persisted retention data: {0}")]
+    InvalidPersistedData(String),
+    /// The cluster is in read-only maintenance mode.
+    #[error(transparent)]
+    MaintenanceMode(#[from] MaintenanceMode),
+}
+
+impl RetentionError {
+    /// Creates a persistence failure from an infrastructure error.
+    pub fn persistence_failed(err: impl std::error::Error) -> Self {
+        Self::PersistenceFailed {
+            reason: err.to_string(),
+        }
+    }
+
+    /// Creates an invalid persisted data error.
+    pub fn invalid_persisted_data(err: impl Into<String>) -> Self {
+        Self::InvalidPersistedData(err.into())
+    }
+}
@@ -0,0 +1,291 @@
+//! The purge service applying retention policies.
+//!
+//! [`PurgeService`] applies each conversation's own policy to that
+//! conversation, then the default policy, when one is configured, to every
+//! other conversation in the tenant. Without a default policy,
+//! conversations without their own are kept indefinitely. Each policy is
+//! applied in batches until nothing it expires remains; a dry run instead
+//! counts what each policy would purge in one step.
+//!
+//! With a maintenance gate attached, policies cannot be changed and nothing
+//! is purged while the cluster is in maintenance. Dry runs only read, so
+//! they still run; a purge under way stops before its next batch.
+
+use super::domain::{
+    ConversationRetention, PurgeBatch, PurgeCounts, PurgeOptions, PurgeProgress, PurgeReport,
+    PurgeScope, PurgeSelection, RetentionPolicy,
+};
+use super::ports::{PurgeProgressReporter, PurgeStore, RetentionPolicyRepository, RetentionResult};
+use crate::context::RequestContext;
+use crate::maintenance::services::MaintenanceGate;
+use crate::message::domain::ConversationId;
+use crate::pagination::collect_pages;
+use mockable::Clock;
+use std::sync::Arc;
+
+/// Sets retention policies and purges the data they expire.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::context::RequestContext;
+/// use corbusier::message::adapters::memory::{
+///     InMemoryContextSnapshotAdapter, InMemoryConversationRepository, InMemoryMessageRepository,
+/// };
+/// use corbusier::message::domain::RedactionReason;
+/// use corbusier::retention::{
+///     adapters::InMemoryRetentionRepository,
+///     domain::{PurgeMode, PurgeOptions, RetentionPolicy},
+///     services::PurgeService,
+/// };
+/// use mockable::DefaultClock;
+/// use std::sync::Arc;
+///
+/// # async fn example(ctx: &RequestContext) -> Result<(), Box<dyn std::error::Error>> {
+/// let repository = Arc::new(InMemoryRetentionRepository::new(
+///     InMemoryConversationRepository::new(),
+///     InMemoryMessageRepository::new(),
+///     InMemoryContextSnapshotAdapter::new(),
+/// ));
+/// let service = PurgeService::new(repository.clone(), repository, Arc::new(DefaultClock))
+///     .with_default_policy(RetentionPolicy::new(90, PurgeMode::Delete)?);
+/// let options = PurgeOptions::new(RedactionReason::new("90-day retention")?).dry_run();
+/// let report = service.purge(ctx, &options).await?;
+/// assert!(report.purged.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub struct PurgeService {
+    policies: Arc<dyn RetentionPolicyRepository>,
+    store: Arc<dyn PurgeStore>,
+    clock: Arc<dyn Clock + Send + Sync>,
+    default_policy: Option<RetentionPolicy>,
+    reporter: Option<Arc<dyn PurgeProgressReporter>>,
+    maintenance: Option<Arc<MaintenanceGate>>,
+}
+
+impl PurgeService {
+    /// Creates a service with no default policy and no progress reporter.
+    #[must_use]
+    pub fn new(
+        policies: Arc<dyn RetentionPolicyRepository>,
+        store: Arc<dyn PurgeStore>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            policies,
+            store,
+            clock,
+            default_policy: None,
+            reporter: None,
+            maintenance: None,
+        }
+    }
+
+    /// Applies `policy` to every conversation without a policy of its own.
+    #[must_use]
+    pub const fn with_default_policy(mut self, policy: RetentionPolicy) -> Self {
+        self.default_policy = Some(policy);
+        self
+    }
+
+    /// Reports progress to `reporter` after every batch.
+    #[must_use]
+    pub fn with_progress_reporter(mut self, reporter: Arc<dyn PurgeProgressReporter>) -> Self {
+        self.reporter = Some(reporter);
+        self
+    }
+
+    /// Refuses to change policies or purge while `gate` reports
+    /// maintenance.
+    #[must_use]
+    pub fn with_maintenance(mut self, gate: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(gate);
+        self
+    }
+
+    /// Returns the default policy, if one is configured.
+    #[must_use]
+    pub const fn default_policy(&self) -> Option<RetentionPolicy> {
+        self.default_policy
+    }
+
+    /// Sets a conversation's own policy, replacing any it had.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RetentionError::ConversationNotFound`] when the tenant has
+    /// no such conversation, [`RetentionError::MaintenanceMode`] during
+    /// maintenance, or the repository error when the policy cannot be
+    /// stored.
+    ///
+    /// [`RetentionError::ConversationNotFound`]: super::ports::RetentionError::ConversationNotFound
+    /// [`RetentionError::MaintenanceMode`]: super::ports::RetentionError::MaintenanceMode
+    pub async fn set_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        policy: RetentionPolicy,
+    ) -> RetentionResult<ConversationRetention> {
+        self.ensure_writable().await?;
+        let retention = ConversationRetention::new(ctx, conversation_id, policy, &*self.clock);
+        self.policies.set_policy(ctx, &retention).await?;
+        tracing::info!(
+            conversation_id = %conversation_id,
+            retention_days = policy.retention_days(),
+            mode = %policy.mode(),
+            "conversation retention policy set"
+        );
+        Ok(retention)
+    }
+
+    /// Removes a conversation's own policy, so the default applies again.
+    /// Returns whether it had one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the policy cannot be removed, or
+    /// the maintenance error during maintenance.
+    pub async fn clear_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RetentionResult<bool> {
+        self.ensure_writable().await?;
+        let cleared = self.policies.clear_policy(ctx, conversation_id).await?;
+        if cleared {
+            tracing::info!(conversation_id = %conversation_id, "conversation retention policy cleared");
+        }
+        Ok(cleared)
+    }
+
+    /// Returns the policy that applies to a conversation: its own, or the
+    /// default when it has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns the repository error when the policy cannot be loaded.
+    pub async fn effective_policy(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> RetentionResult<Option<RetentionPolicy>> {
+        let own = self.policies.find_policy(ctx, conversation_id).await?;
+        Ok(own
+            .map(|retention| retention.policy)
+            .or(self.default_policy))
+    }
+
+    /// Purges the tenant's expired data, or counts it on a dry run.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first repository error, or the maintenance error when a
+    /// purge that is not a dry run meets maintenance. Batches completed
+    /// before it stay purged, so the purge can simply be rerun.
+    pub async fn purge(
+        &self,
+        ctx: &RequestContext,
+        options: &PurgeOptions,
+    ) -> RetentionResult<PurgeReport> {
+        if !options.is_dry_run() {
+            self.ensure_writable().await?;
+        }
+        let now = self.clock.utc();
+        let mut selections = collect_pages(|page| self.policies.list_policies(ctx, page))
+            .await?
+            .into_iter()
+            .map(|retention| {
+                PurgeSelection::new(
+                    PurgeScope::Conversation(retention.conversation_id),
+                    retention.policy,
+                    now,
+                )
+            })
+            .collect::<Vec<_>>();
+        if let Some(policy) = self.default_policy {
+            selections.push(PurgeSelection::new(PurgeScope::Default, policy, now));
+        }
+
+        let mut report = PurgeReport {
+            dry_run: options.is_dry_run(),
+            ..PurgeReport::default()
+        };
+        for selection in selections {
+            report.policies = report.policies.saturating_add(1);
+            if options.is_dry_run() {
+                let counts = self.store.count_expired(ctx, &selection).await?;
+                self.record_batch(&mut report, selection, counts);
+            } else {
+                let batch = PurgeBatch {
+                    selection,
+                    limit: options.batch_size(),
+                    reason: options.reason().clone(),
+                    purged_by: ctx.user_id(),
+                    purged_at: now,
+                };
+                self.purge_selection(ctx, &batch, &mut report).await?;
+            }
+        }
+        tracing::info!(
+            dry_run = report.dry_run,
+            policies = report.policies,
+            batches = report.batches,
+            messages = report.purged.messages,
+            snapshots = report.purged.snapshots,
+            events = report.purged.events,
+            "retention purge finished"
+        );
+        Ok(report)
+    }
+
+    async fn purge_selection(
+        &self,
+        ctx: &RequestContext,
+        batch: &PurgeBatch,
+        report: &mut PurgeReport,
+    ) -> RetentionResult<()> {
+        loop {
+            self.ensure_writable().await?;
+            let counts = self.store.purge_batch(ctx, batch).await?;
+            if counts.is_empty() {
+                return Ok(());
+            }
+            self.record_batch(report, batch.selection, counts);
+        }
+    }
+
+    async fn ensure_writable(&self) -> RetentionResult<()> {
+        if let Some(gate) = self.maintenance.as_deref() {
+            gate.ensure_writable().await?;
+        }
+        Ok(())
+    }
+
+    fn record_batch(
+        &self,
+        report: &mut PurgeReport,
+        selection: PurgeSelection,
+        counts: PurgeCounts,
+    ) {
+        report.batches = report.batches.saturating_add(1);
+        report.purged += counts;
+        tracing::debug!(
+            scope = %selection.scope,
+            dry_run = report.dry_run,
+            messages = counts.messages,
+            snapshots = counts.snapshots,
+            events = counts.events,
+            "retention purge batch"
+        );
+        if let Some(reporter) = &self.reporter {
+            reporter.report(&PurgeProgress {
+                selection,
+                batches: report.batches,
+                batch: counts,
+                total: report.purged,
+                dry_run: report.dry_run,
+            });
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Unit tests for retention domain values.
+
+use crate::message::domain::{ConversationId, RedactionReason};
+use crate::retention::domain::{
+    DEFAULT_PURGE_BATCH_SIZE, MAX_RETENTION_DAYS, PurgeCounts, PurgeMode, PurgeOptions, PurgeScope,
+    PurgeSelection, RetentionDomainError, RetentionPolicy,
+};
+use chrono::{TimeDelta, Utc};
+use rstest::rstest;
+
+fn reason() -> RedactionReason {
+    RedactionReason::new("retention expired").expect("valid reason")
+}
+
+#[rstest]
+#[case(1)]
+#[case(90)]
+#[case(MAX_RETENTION_DAYS)]
+fn policies_accept_periods_within_bounds(#[case] days: u32) {
+    let policy = RetentionPolicy::new(days, PurgeMode::Anonymise).expect("valid policy");
+
+    assert_eq!(policy.retention_days(), days);
+    assert_eq!(policy.mode(), PurgeMode::Anonymise);
+    assert_eq!(policy.retention_period(), TimeDelta::days(i64::from(days)));
+}
+
+#[rstest]
+#[case(0)]
+#[case(MAX_RETENTION_DAYS + 1)]
+fn policies_reject_periods_out_of_bounds(#[case] days: u32) {
+    assert_eq!(
+        RetentionPolicy::new(days, PurgeMode::Delete),
+        Err(RetentionDomainError::InvalidPeriod(days))
+    );
+}
+
+#[rstest]
+#[case("delete", PurgeMode::Delete)]
+#[case(" Anonymise ", PurgeMode::Anonymise)]
+fn purge_modes_parse_case_insensitively(#[case] text: &str, #[case] expected: PurgeMode) {
+    assert_eq!(PurgeMode::try_from(text), Ok(expected));
+    assert_eq!(PurgeMode::try_from(expected.as_str()), Ok(expected));
+}
+
+#[rstest]
+fn unknown_purge_modes_are_rejected() {
+    assert_eq!(
+        PurgeMode::try_from("archive"),
+        Err(RetentionDomainError::UnknownMode("archive".to_owned()))
+    );
+}
+
+#[rstest]
+fn selections_expire_data_older_than_the_period() {
+    let now = Utc::now();
+    let policy = RetentionPolicy::new(7, PurgeMode::Delete).expect("valid policy");
+    let scope = PurgeScope::Conversation(ConversationId::new());
+
+    let selection = PurgeSelection::new(scope, policy, now);
+
+    assert_eq!(selection.scope, scope);
+    assert_eq!(selection.cutoff, now - TimeDelta::days(7));
+    assert_eq!(selection.mode, PurgeMode::Delete);
+}
+
+#[rstest]
+fn purge_counts_accumulate() {
+    let mut total = PurgeCounts::default();
+    assert!(total.is_empty());
+
+    total += PurgeCounts {
+        messages: 2,
+        snapshots: 1,
+        events: 3,
+    };
+    total += PurgeCounts {
+        messages: 1,
+        snapshots: 0,
+        events: u64::MAX,
+    };
+
+    assert_eq!(total.messages, 3);
+    assert_eq!(total.snapshots, 1);
+    assert_eq!(total.events, u64::MAX);
+    assert!(!total.is_empty());
+}
+
+#[rstest]
+fn purge_options_default_to_a_full_purge() {
+    let options = PurgeOptions::new(reason());
+
+    assert_eq!(options.batch_size(), DEFAULT_PURGE_BATCH_SIZE);
+    assert!(!options.is_dry_run());
+    assert_eq!(options.reason(), &reason());
+}
+
+#[rstest]
+fn purge_batches_hold_at_least_one_row() {
+    let options = PurgeOptions::new(reason()).with_batch_size(0).dry_run();
+
+    assert_eq!(options.batch_size(), 1);
+    assert!(options.is_dry_run());
+}
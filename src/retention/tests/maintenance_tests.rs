@@ -0,0 +1,53 @@
+//! Unit tests for purges during read-only maintenance.
+
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::message::{
+    adapters::memory::{
+        InMemoryContextSnapshotAdapter, InMemoryConversationRepository, InMemoryMessageRepository,
+    },
+    domain::{ConversationId, RedactionReason},
+};
+use crate::operator::domain::OperatorReason;
+use crate::retention::{
+    adapters::InMemoryRetentionRepository,
+    domain::{PurgeMode, PurgeOptions, RetentionPolicy},
+    ports::RetentionError,
+    services::PurgeService,
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn purges_and_policy_changes_wait_out_maintenance() {
+    let ctx = test_request_ctx();
+    let repository = Arc::new(InMemoryRetentionRepository::new(
+        InMemoryConversationRepository::new(),
+        InMemoryMessageRepository::new(),
+        InMemoryContextSnapshotAdapter::new(),
+    ));
+    let gate = Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ));
+    let policy = RetentionPolicy::new(30, PurgeMode::Delete).expect("valid policy");
+    let service = PurgeService::new(repository.clone(), repository, Arc::new(DefaultClock))
+        .with_default_policy(policy)
+        .with_maintenance(Arc::clone(&gate));
+    let options =
+        PurgeOptions::new(RedactionReason::new("retention expired").expect("valid reason"));
+    let reason = OperatorReason::new("failover").expect("valid reason");
+    gate.enable(reason, None).await.expect("enable maintenance");
+
+    let purge = service.purge(&ctx, &options).await;
+    let dry_run = service.purge(&ctx, &options.clone().dry_run()).await;
+    let set = service
+        .set_policy(&ctx, ConversationId::new(), policy)
+        .await;
+    let cleared = service.clear_policy(&ctx, ConversationId::new()).await;
+
+    assert!(matches!(purge, Err(RetentionError::MaintenanceMode(_))));
+    assert!(dry_run.is_ok_and(|report| report.dry_run));
+    assert!(matches!(set, Err(RetentionError::MaintenanceMode(_))));
+    assert!(matches!(cleared, Err(RetentionError::MaintenanceMode(_))));
+}
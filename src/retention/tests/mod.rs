@@ -0,0 +1,5 @@
+//! Unit tests for retention policies and purges.
+
+mod domain_tests;
+mod maintenance_tests;
+mod service_tests;
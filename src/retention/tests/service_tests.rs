@@ -0,0 +1,396 @@
+//! Unit tests for the purge service.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::{
+        InMemoryContextSnapshotAdapter, InMemoryConversationRepository, InMemoryMessageRepository,
+    },
+    domain::{
+        AgentSessionId, ContentPart, ContextWindowSnapshot, Conversation, ConversationId, Message,
+        MessageId, MessageSummary, RedactionReason, Role, SequenceNumber, SequenceRange,
+        SnapshotParams, SnapshotType, TextPart,
+    },
+    ports::{ContextSnapshotPort, ConversationRepository, MessageRepository},
+};
+use crate::retention::{
+    adapters::InMemoryRetentionRepository,
+    domain::{PurgeCounts, PurgeMode, PurgeOptions, PurgeProgress, PurgeScope, RetentionPolicy},
+    ports::{PurgeProgressReporter, RetentionError},
+    services::PurgeService,
+};
+use crate::test_support::test_request_ctx;
+use chrono::{DateTime, Local, TimeDelta, Utc};
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+use std::sync::{Arc, Mutex, PoisonError};
+use uuid::Uuid;
+
+/// Clock that only moves when told to.
+struct ManualClock(Mutex<DateTime<Utc>>);
+
+impl ManualClock {
+    fn advance(&self, by: TimeDelta) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn local(&self) -> DateTime<Local> {
+        self.utc().with_timezone(&Local)
+    }
+
+    fn utc(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Reporter that keeps every progress update.
+#[derive(Default)]
+struct RecordingReporter(Mutex<Vec<PurgeProgress>>);
+
+impl RecordingReporter {
+    fn reports(&self) -> Vec<PurgeProgress> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl PurgeProgressReporter for RecordingReporter {
+    fn report(&self, progress: &PurgeProgress) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(*progress);
+    }
+}
+
+struct Harness {
+    clock: Arc<ManualClock>,
+    conversations: InMemoryConversationRepository,
+    messages: InMemoryMessageRepository,
+    snapshots: InMemoryContextSnapshotAdapter,
+    repository: Arc<InMemoryRetentionRepository>,
+}
+
+impl Harness {
+    fn service(&self, default_policy: Option<RetentionPolicy>) -> PurgeService {
+        let service = PurgeService::new(
+            self.repository.clone(),
+            self.repository.clone(),
+            self.clock.clone(),
+        );
+        match default_policy {
+            Some(policy) => service.with_default_policy(policy),
+            None => service,
+        }
+    }
+
+    async fn conversation(&self, ctx: &RequestContext) -> ConversationId {
+        let conversation = Conversation::new(&*self.clock);
+        self.conversations
+            .store(ctx, &conversation)
+            .await
+            .expect("store conversation");
+        conversation.id()
+    }
+
+    async fn message(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        sequence: u64,
+    ) -> MessageId {
+        let message = Message::new(
+            conversation_id,
+            Role::User,
+            vec![ContentPart::Text(TextPart::new("personal details"))],
+            SequenceNumber::new(sequence),
+            &*self.clock,
+        )
+        .expect("valid message");
+        self.messages
+            .store(ctx, &message)
+            .await
+            .expect("store message");
+        message.id()
+    }
+
+    async fn snapshot(&self, ctx: &RequestContext, conversation_id: ConversationId) -> Uuid {
+        let snapshot = ContextWindowSnapshot::new(
+            SnapshotParams {
+                conversation_id,
+                session_id: AgentSessionId::new(),
+                sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(1)),
+                message_summary: MessageSummary::default(),
+                snapshot_type: SnapshotType::SessionStart,
+            },
+            &*self.clock,
+        );
+        self.snapshots
+            .store_snapshot(ctx, &snapshot)
+            .await
+            .expect("store snapshot");
+        snapshot.snapshot_id
+    }
+
+    async fn message_exists(&self, ctx: &RequestContext, id: MessageId) -> bool {
+        self.find(ctx, id).await.is_some()
+    }
+
+    async fn find(&self, ctx: &RequestContext, id: MessageId) -> Option<Message> {
+        self.messages
+            .find_by_id(ctx, id)
+            .await
+            .expect("find message")
+    }
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let conversations = InMemoryConversationRepository::new();
+    let messages = InMemoryMessageRepository::new();
+    let snapshots = InMemoryContextSnapshotAdapter::new();
+    let repository = Arc::new(InMemoryRetentionRepository::new(
+        conversations.clone(),
+        messages.clone(),
+        snapshots.clone(),
+    ));
+    Harness {
+        clock: Arc::new(ManualClock(Mutex::new(DefaultClock.utc()))),
+        conversations,
+        messages,
+        snapshots,
+        repository,
+    }
+}
+
+fn policy(days: u32, mode: PurgeMode) -> RetentionPolicy {
+    RetentionPolicy::new(days, mode).expect("valid policy")
+}
+
+fn options() -> PurgeOptions {
+    PurgeOptions::new(RedactionReason::new("retention expired").expect("valid reason"))
+}
+
+#[rstest]
+#[tokio::test]
+async fn dry_runs_count_expired_data_without_purging(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation_id = harness.conversation(&ctx).await;
+    let old = harness.message(&ctx, conversation_id, 1).await;
+    harness.snapshot(&ctx, conversation_id).await;
+    harness.clock.advance(TimeDelta::days(40));
+    harness.message(&ctx, conversation_id, 2).await;
+    let service = harness.service(Some(policy(30, PurgeMode::Delete)));
+
+    let report = service
+        .purge(&ctx, &options().dry_run())
+        .await
+        .expect("dry run");
+
+    assert!(report.dry_run);
+    assert_eq!(report.policies, 1);
+    assert_eq!(
+        report.purged,
+        PurgeCounts {
+            messages: 1,
+            snapshots: 1,
+            events: 0,
+        }
+    );
+    assert!(harness.message_exists(&ctx, old).await);
+}
+
+#[rstest]
+#[tokio::test]
+async fn deleting_purges_in_batches_and_reports_progress(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation_id = harness.conversation(&ctx).await;
+    let mut old = Vec::new();
+    for sequence in 1..=3 {
+        old.push(harness.message(&ctx, conversation_id, sequence).await);
+    }
+    harness.clock.advance(TimeDelta::days(40));
+    let recent = harness.message(&ctx, conversation_id, 4).await;
+    let reporter = Arc::new(RecordingReporter::default());
+    let service = harness
+        .service(Some(policy(30, PurgeMode::Delete)))
+        .with_progress_reporter(reporter.clone());
+
+    let report = service
+        .purge(&ctx, &options().with_batch_size(2))
+        .await
+        .expect("purge");
+
+    assert!(!report.dry_run);
+    assert_eq!(report.batches, 2);
+    assert_eq!(report.purged.messages, 3);
+    let totals: Vec<_> = reporter
+        .reports()
+        .iter()
+        .map(|progress| (progress.batch.messages, progress.total.messages))
+        .collect();
+    assert_eq!(totals, vec![(2, 2), (1, 3)]);
+    for id in old {
+        assert!(!harness.message_exists(&ctx, id).await);
+    }
+    assert!(harness.message_exists(&ctx, recent).await);
+}
+
+#[rstest]
+#[tokio::test]
+async fn anonymising_redacts_expired_messages_once(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation_id = harness.conversation(&ctx).await;
+    let old = harness.message(&ctx, conversation_id, 1).await;
+    let snapshot_id = harness.snapshot(&ctx, conversation_id).await;
+    harness.clock.advance(TimeDelta::days(40));
+    let service = harness.service(Some(policy(30, PurgeMode::Anonymise)));
+
+    let report = service.purge(&ctx, &options()).await.expect("purge");
+
+    assert_eq!(report.purged.messages, 1);
+    assert_eq!(report.purged.snapshots, 1);
+    let redacted = harness.find(&ctx, old).await.expect("message kept");
+    assert!(redacted.is_redacted());
+    assert!(
+        harness
+            .snapshots
+            .find_by_id(&ctx, snapshot_id)
+            .await
+            .expect("find snapshot")
+            .is_none()
+    );
+    let rerun = service.purge(&ctx, &options()).await.expect("purge again");
+    assert!(rerun.purged.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn conversation_policies_override_the_default(harness: Harness) {
+    let ctx = test_request_ctx();
+    let kept = harness.conversation(&ctx).await;
+    let purged = harness.conversation(&ctx).await;
+    let kept_message = harness.message(&ctx, kept, 1).await;
+    let purged_message = harness.message(&ctx, purged, 1).await;
+    let service = harness.service(Some(policy(30, PurgeMode::Delete)));
+    service
+        .set_policy(&ctx, kept, policy(60, PurgeMode::Delete))
+        .await
+        .expect("set policy");
+    harness.clock.advance(TimeDelta::days(40));
+
+    let report = service.purge(&ctx, &options()).await.expect("purge");
+
+    assert_eq!(report.policies, 2);
+    assert_eq!(report.purged.messages, 1);
+    assert!(harness.message_exists(&ctx, kept_message).await);
+    assert!(!harness.message_exists(&ctx, purged_message).await);
+}
+
+#[rstest]
+#[tokio::test]
+async fn without_a_default_only_conversation_policies_apply(harness: Harness) {
+    let ctx = test_request_ctx();
+    let governed = harness.conversation(&ctx).await;
+    let ungoverned = harness.conversation(&ctx).await;
+    let governed_message = harness.message(&ctx, governed, 1).await;
+    let ungoverned_message = harness.message(&ctx, ungoverned, 1).await;
+    let service = harness.service(None);
+    service
+        .set_policy(&ctx, governed, policy(7, PurgeMode::Delete))
+        .await
+        .expect("set policy");
+    harness.clock.advance(TimeDelta::days(10));
+
+    let report = service.purge(&ctx, &options()).await.expect("purge");
+
+    assert_eq!(report.policies, 1);
+    assert!(!harness.message_exists(&ctx, governed_message).await);
+    assert!(harness.message_exists(&ctx, ungoverned_message).await);
+}
+
+#[rstest]
+#[tokio::test]
+async fn effective_policies_fall_back_to_the_default(harness: Harness) {
+    let ctx = test_request_ctx();
+    let conversation_id = harness.conversation(&ctx).await;
+    let default_policy = policy(30, PurgeMode::Delete);
+    let own = policy(365, PurgeMode::Anonymise);
+    let service = harness.service(Some(default_policy));
+
+    let retention = service
+        .set_policy(&ctx, conversation_id, own)
+        .await
+        .expect("set policy");
+
+    assert_eq!(retention.set_by, ctx.user_id());
+    assert_eq!(
+        service.effective_policy(&ctx, conversation_id).await.ok(),
+        Some(Some(own))
+    );
+    assert!(
+        service
+            .clear_policy(&ctx, conversation_id)
+            .await
+            .expect("clear")
+    );
+    assert!(
+        !service
+            .clear_policy(&ctx, conversation_id)
+            .await
+            .expect("clear again")
+    );
+    assert_eq!(
+        service.effective_policy(&ctx, conversation_id).await.ok(),
+        Some(Some(default_policy))
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn policies_require_a_conversation_in_the_tenant(harness: Harness) {
+    let ctx = test_request_ctx();
+    let other_tenant = test_request_ctx();
+    let conversation_id = harness.conversation(&other_tenant).await;
+    let service = harness.service(None);
+
+    let result = service
+        .set_policy(&ctx, conversation_id, policy(30, PurgeMode::Delete))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RetentionError::ConversationNotFound(id)) if id == conversation_id
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn purges_leave_other_tenants_untouched(harness: Harness) {
+    let ctx = test_request_ctx();
+    let other_tenant = test_request_ctx();
+    let conversation_id = harness.conversation(&other_tenant).await;
+    let message_id = harness.message(&other_tenant, conversation_id, 1).await;
+    harness.clock.advance(TimeDelta::days(40));
+    let service = harness.service(Some(policy(30, PurgeMode::Delete)));
+
+    let report = service.purge(&ctx, &options()).await.expect("purge");
+
+    assert!(report.purged.is_empty());
+    assert_eq!(report.batches, 0);
+    assert!(harness.message_exists(&other_tenant, message_id).await);
+}
+
+#[rstest]
+fn progress_names_the_scope() {
+    let conversation_id = ConversationId::new();
+
+    assert_eq!(
+        PurgeScope::Conversation(conversation_id).to_string(),
+        format!("conversation {conversation_id}")
+    );
+    assert_eq!(PurgeScope::Default.to_string(), "default policy");
+}
@@ -0,0 +1,268 @@
+//! Expected and observed schema shapes, and the comparison between them.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use thiserror::Error;
+
+/// A migration directory the binary was built against.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::schema_check::ExpectedMigration;
+///
+/// let migration = ExpectedMigration::new("2026-05-04-000000_add_agent_turn_outcomes");
+/// assert_eq!(migration.version(), "20260504000000");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpectedMigration {
+    name: &'static str,
+}
+
+impl ExpectedMigration {
+    /// Creates an expectation from a migration directory name.
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+
+    /// Returns the migration directory name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the version Diesel records for this migration: the
+    /// directory's timestamp prefix without separators.
+    #[must_use]
+    pub fn version(self) -> String {
+        let prefix = self
+            .name
+            .split_once('_')
+            .map_or(self.name, |(prefix, _)| prefix);
+        prefix.replace('-', "")
+    }
+}
+
+/// The columns the binary reads and writes on one table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableFingerprint {
+    table: &'static str,
+    columns: &'static [&'static str],
+}
+
+impl TableFingerprint {
+    /// Creates a fingerprint of `table` with exactly `columns`.
+    #[must_use]
+    pub const fn new(table: &'static str, columns: &'static [&'static str]) -> Self {
+        Self { table, columns }
+    }
+
+    /// Returns the table name.
+    #[must_use]
+    pub const fn table(self) -> &'static str {
+        self.table
+    }
+
+    /// Returns the expected column names.
+    #[must_use]
+    pub const fn columns(self) -> &'static [&'static str] {
+        self.columns
+    }
+
+    fn mismatches(self, observed: Option<&BTreeSet<String>>) -> Vec<SchemaMismatch> {
+        let Some(observed) = observed else {
+            return vec![SchemaMismatch::MissingTable {
+                table: self.table.to_owned(),
+            }];
+        };
+        let missing = self
+            .columns
+            .iter()
+            .filter(|column| !observed.contains(**column))
+            .map(|column| SchemaMismatch::MissingColumn {
+                table: self.table.to_owned(),
+                column: (*column).to_owned(),
+            });
+        let unexpected = observed
+            .iter()
+            .filter(|column| !self.columns.contains(&column.as_str()))
+            .map(|column| SchemaMismatch::UnexpectedColumn {
+                table: self.table.to_owned(),
+                column: column.clone(),
+            });
+        missing.chain(unexpected).collect()
+    }
+}
+
+/// The schema a binary was built against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaExpectations {
+    migrations: &'static [ExpectedMigration],
+    tables: &'static [TableFingerprint],
+}
+
+impl SchemaExpectations {
+    /// Creates expectations from the migrations and key table fingerprints.
+    #[must_use]
+    pub const fn new(
+        migrations: &'static [ExpectedMigration],
+        tables: &'static [TableFingerprint],
+    ) -> Self {
+        Self { migrations, tables }
+    }
+
+    /// Returns the expected migrations, oldest first.
+    #[must_use]
+    pub const fn migrations(&self) -> &'static [ExpectedMigration] {
+        self.migrations
+    }
+
+    /// Returns the fingerprinted tables.
+    #[must_use]
+    pub const fn tables(&self) -> &'static [TableFingerprint] {
+        self.tables
+    }
+
+    /// Compares an observed schema against these expectations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaIncompatibleError`] listing every mismatch found.
+    pub fn compare(&self, observed: &ObservedSchema) -> Result<(), SchemaIncompatibleError> {
+        let mut mismatches = self.migration_mismatches(observed.applied_migrations.as_ref());
+        for table in self.tables {
+            mismatches.extend(table.mismatches(observed.columns.get(table.table)));
+        }
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaIncompatibleError { mismatches })
+        }
+    }
+
+    fn migration_mismatches(&self, applied: Option<&BTreeSet<String>>) -> Vec<SchemaMismatch> {
+        let Some(applied) = applied else {
+            return vec![SchemaMismatch::MigrationHistoryMissing];
+        };
+        let expected: BTreeSet<String> = self
+            .migrations
+            .iter()
+            .map(|migration| migration.version())
+            .collect();
+        let missing = self
+            .migrations
+            .iter()
+            .filter(|migration| !applied.contains(&migration.version()))
+            .map(|migration| SchemaMismatch::MissingMigration {
+                name: migration.name().to_owned(),
+            });
+        let unknown =
+            applied
+                .difference(&expected)
+                .map(|version| SchemaMismatch::UnknownMigration {
+                    version: version.clone(),
+                });
+        missing.chain(unknown).collect()
+    }
+}
+
+/// The schema observed in a database.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObservedSchema {
+    /// Versions recorded in Diesel's migration history, or `None` when the
+    /// database has no migration history table.
+    pub applied_migrations: Option<BTreeSet<String>>,
+    /// Column names of each fingerprinted table that exists.
+    pub columns: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// One difference between the expected and observed schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaMismatch {
+    /// The database has no Diesel migration history.
+    MigrationHistoryMissing,
+    /// A migration the binary needs has not been applied.
+    MissingMigration {
+        /// Migration directory name.
+        name: String,
+    },
+    /// The database has a migration the binary does not know about.
+    UnknownMigration {
+        /// Recorded migration version.
+        version: String,
+    },
+    /// A fingerprinted table does not exist.
+    MissingTable {
+        /// Table name.
+        table: String,
+    },
+    /// A fingerprinted table lacks a column the binary uses.
+    MissingColumn {
+        /// Table name.
+        table: String,
+        /// Column name.
+        column: String,
+    },
+    /// A fingerprinted table has a column the binary does not know about.
+    UnexpectedColumn {
+        /// Table name.
+        table: String,
+        /// Column name.
+        column: String,
+    },
+}
+
+impl fmt::Display for SchemaMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MigrationHistoryMissing => f.write_str(
+                "no migration history table (__diesel_schema_migrations); \
+                 run `diesel migration run`",
+            ),
+            Self::MissingMigration { name } => {
+                write!(
+                    f,
+                    "migration {name} is not applied; run `diesel migration run`"
+                )
+            }
+            Self::UnknownMigration { version } => write!(
+                f,
+                "migration {version} is applied but unknown to this binary; \
+                 deploy a binary built with that migration"
+            ),
+            Self::MissingTable { table } => write!(f, "table {table} does not exist"),
+            Self::MissingColumn { table, column } => {
+                write!(f, "column {table}.{column} is missing")
+            }
+            Self::UnexpectedColumn { table, column } => write!(
+                f,
+                "column {table}.{column} is not known to this binary; \
+                 check for a migration applied out of band"
+            ),
+        }
+    }
+}
+
+/// Error returned when the database schema does not match the binary.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("database schema is incompatible with this binary: {}", join(.mismatches))]
+pub struct SchemaIncompatibleError {
+    mismatches: Vec<SchemaMismatch>,
+}
+
+impl SchemaIncompatibleError {
+    /// Returns every mismatch found.
+    #[must_use]
+    pub fn mismatches(&self) -> &[SchemaMismatch] {
+        &self.mismatches
+    }
+}
+
+fn join(mismatches: &[SchemaMismatch]) -> String {
+    mismatches
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -0,0 +1,129 @@
+//! The schema this binary was built against.
+
+use super::domain::{ExpectedMigration, SchemaExpectations, TableFingerprint};
+use crate::message::adapters::schema::{conversations, messages};
+use crate::task::adapters::postgres::schema::tasks;
+
+/// Builds a [`TableFingerprint`] from a Diesel `table!` module, so removing a
+/// column from the schema without updating the fingerprint fails to compile.
+macro_rules! fingerprint {
+    ($table:ident { $($column:ident),+ $(,)? }) => {
+        TableFingerprint::new(
+            stringify!($table),
+            &[$(<$table::$column as diesel::Column>::NAME),+],
+        )
+    };
+}
+
+/// Every migration under `migrations/`, oldest first.
+///
+/// Add new migrations here when adding their directory.
+const MIGRATIONS: &[ExpectedMigration] = &[
+    ExpectedMigration::new(".gitkeep"),
+    ExpectedMigration::new("2026-01-15-000000_create_base_tables"),
+    ExpectedMigration::new("2026-01-15-000001_add_message_uniqueness_constraints"),
+    ExpectedMigration::new("2026-01-16-000000_add_audit_trigger"),
+    ExpectedMigration::new("2026-02-03-000000_add_agent_sessions_and_handoffs"),
+    ExpectedMigration::new("2026-02-09-000000_add_tasks_table"),
+    ExpectedMigration::new("2026-02-11-000000_add_branch_pr_lookup_indexes"),
+    ExpectedMigration::new("2026-02-25-000000_add_backend_registrations_table"),
+    ExpectedMigration::new("2026-02-28-000000_add_mcp_servers_table"),
+    ExpectedMigration::new("2026-03-03-000000_add_agent_turn_sessions_table"),
+    ExpectedMigration::new("2026-03-03-000000_add_hook_executions_table"),
+    ExpectedMigration::new("2026-03-04-000000_add_tool_catalog_tables"),
+    ExpectedMigration::new("2026-03-06-000000_add_unique_active_session_per_conversation"),
+    ExpectedMigration::new("2026-03-10-000000_add_tenant_id_to_tool_registry"),
+    ExpectedMigration::new("2026-03-11-000000_tenant_scope_mcp_servers"),
+    ExpectedMigration::new("2026-03-13-000000_add_tenant_id_to_hook_executions_table"),
+    ExpectedMigration::new("2026-03-13-000000_tenant_scope_agent_backend"),
+    ExpectedMigration::new("2026-03-14-000000_add_hook_executions_unique_constraint"),
+    ExpectedMigration::new("2026-03-20-000000_add_reserved_agent_turn_session_status"),
+    ExpectedMigration::new("2026-03-21-000000_add_tenant_schema_and_constraints"),
+    ExpectedMigration::new("2026-03-22-000000_add_hook_policy_audit_events"),
+    ExpectedMigration::new("2026-04-01-000000_add_tenant_id_to_conversations_and_messages"),
+    ExpectedMigration::new("2026-04-01-000001_enforce_tenant_scope_for_conversations_and_messages"),
+    ExpectedMigration::new("2026-04-10-000000_add_activity_statistics_indexes"),
+    ExpectedMigration::new("2026-04-12-000000_add_task_cost_attribution"),
+    ExpectedMigration::new("2026-04-14-000000_add_conversation_summaries"),
+    ExpectedMigration::new("2026-04-16-000000_add_backend_experiments"),
+    ExpectedMigration::new("2026-04-18-000000_add_message_feedback"),
+    ExpectedMigration::new("2026-04-20-000000_add_message_processing_stages"),
+    ExpectedMigration::new("2026-04-22-000000_add_conversation_budgets"),
+    ExpectedMigration::new("2026-04-24-000000_index_delegated_budgets"),
+    ExpectedMigration::new("2026-04-26-000000_add_agent_memory_facts"),
+    ExpectedMigration::new("2026-04-28-000000_add_conversation_rolling_summaries"),
+    ExpectedMigration::new("2026-04-30-000000_add_message_redactions"),
+    ExpectedMigration::new("2026-05-02-000000_add_operator_actions"),
+    ExpectedMigration::new("2026-05-04-000000_add_agent_turn_outcomes"),
+    ExpectedMigration::new("2026-05-06-000000_add_aggregate_versions"),
+    ExpectedMigration::new("2026-05-08-000000_add_maintenance_mode"),
+    ExpectedMigration::new("2026-05-10-000000_add_conversation_transfers"),
+    ExpectedMigration::new("2026-05-12-000000_add_projection_checkpoints"),
+    ExpectedMigration::new("2026-05-14-000000_add_context_assembly_reports"),
+    ExpectedMigration::new("2026-05-16-000000_add_partial_messages"),
+    ExpectedMigration::new("2026-05-18-000000_add_turn_callbacks"),
+    ExpectedMigration::new("2026-05-20-000000_add_compaction_snapshots"),
+    ExpectedMigration::new("2026-05-22-000000_add_conversation_forks"),
+    ExpectedMigration::new("2026-05-24-000000_add_conversation_labels"),
+    ExpectedMigration::new("2026-05-26-000000_add_retention_policies"),
+    ExpectedMigration::new("2026-05-28-000000_add_erasure_certificates"),
+    ExpectedMigration::new("2026-05-30-000000_add_encryption_key_rotations"),
+    ExpectedMigration::new("2026-06-01-000000_seal_partial_message_content"),
+    ExpectedMigration::new("2026-06-02-000000_index_attachment_blob_references"),
+    ExpectedMigration::new("2026-06-04-000000_widen_erasure_certificates"),
+    ExpectedMigration::new("2026-06-06-000000_move_experiment_observations"),
+    ExpectedMigration::new("2026-06-08-000000_add_secret_injection_audits"),
+    ExpectedMigration::new("2026-06-10-000000_add_handoff_context_packages"),
+    ExpectedMigration::new("2026-06-12-000000_add_session_paused_snapshots"),
+    ExpectedMigration::new("2026-06-14-000000_add_turns"),
+    ExpectedMigration::new("2026-06-16-000000_add_incremental_snapshots"),
+    ExpectedMigration::new("2026-06-18-000000_add_periodic_snapshots"),
+    ExpectedMigration::new("2026-06-20-000000_add_slash_command_definitions"),
+    ExpectedMigration::new("2026-06-22-000000_add_slash_command_executions"),
+    ExpectedMigration::new("2026-06-24-000000_add_webhook_deliveries"),
+    ExpectedMigration::new("2026-06-26-000000_add_issue_status_notifications"),
+    ExpectedMigration::new("2026-06-28-000000_add_task_scheduling"),
+    ExpectedMigration::new("2026-06-30-000000_add_handoff_package_conversations"),
+];
+
+/// Tables every request path touches.
+const TABLES: &[TableFingerprint] = &[
+    fingerprint!(conversations {
+        id,
+        tenant_id,
+        task_id,
+        context,
+        state,
+        labels,
+        created_at,
+        updated_at,
+        version,
+    }),
+    fingerprint!(messages {
+        id,
+        tenant_id,
+        conversation_id,
+        role,
+        content,
+        metadata,
+        created_at,
+        sequence_number,
+    }),
+    fingerprint!(tasks {
+        id,
+        tenant_id,
+        origin,
+        branch_ref,
+        pull_request_ref,
+        state,
+        workspace_id,
+        created_at,
+        updated_at,
+        priority,
+        due_at,
+        required_capabilities,
+    }),
+];
+
+/// The schema this binary expects to find.
+pub const EXPECTED_SCHEMA: SchemaExpectations = SchemaExpectations::new(MIGRATIONS, TABLES);
@@ -0,0 +1,22 @@
+//! Startup compatibility check between the binary and its database schema.
+//!
+//! The binary records the migrations it was built against and fingerprints
+//! the columns of its key tables in [`EXPECTED_SCHEMA`].
+//! [`verify_schema_compatibility`] compares these with Diesel's migration
+//! history and `information_schema`, and reports every missing migration,
+//! unknown migration, and missing or unexpected column in one
+//! [`SchemaIncompatibleError`].
+
+mod domain;
+mod expected;
+mod postgres;
+
+pub use domain::{
+    ExpectedMigration, ObservedSchema, SchemaExpectations, SchemaIncompatibleError, SchemaMismatch,
+    TableFingerprint,
+};
+pub use expected::EXPECTED_SCHEMA;
+pub use postgres::{SchemaCheckError, check_schema, observe_schema, verify_schema_compatibility};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,134 @@
+//! `PostgreSQL` schema inspection for the startup compatibility check.
+
+use super::domain::{ObservedSchema, SchemaExpectations, SchemaIncompatibleError};
+use super::expected::EXPECTED_SCHEMA;
+use crate::postgres_support::PgPool;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::sql_types::{Array, Bool, Text};
+use std::collections::{BTreeMap, BTreeSet};
+use thiserror::Error;
+
+/// Errors returned by the schema compatibility check.
+#[derive(Debug, Clone, Error)]
+pub enum SchemaCheckError {
+    /// The schema could not be inspected.
+    #[error("failed to inspect the database schema: {reason}")]
+    Database {
+        /// Human-readable reason from the failing dependency.
+        reason: String,
+    },
+    /// The schema does not match the binary.
+    #[error(transparent)]
+    Incompatible(#[from] SchemaIncompatibleError),
+}
+
+impl SchemaCheckError {
+    /// Creates an inspection failure from an infrastructure error.
+    pub fn database(err: impl std::error::Error) -> Self {
+        Self::Database {
+            reason: err.to_string(),
+        }
+    }
+}
+
+#[derive(QueryableByName)]
+struct PresenceRow {
+    #[diesel(sql_type = Bool)]
+    present: bool,
+}
+
+#[derive(QueryableByName)]
+struct VersionRow {
+    #[diesel(sql_type = Text)]
+    version: String,
+}
+
+#[derive(QueryableByName)]
+struct ColumnRow {
+    #[diesel(sql_type = Text)]
+    table_name: String,
+    #[diesel(sql_type = Text)]
+    column_name: String,
+}
+
+/// Reads the applied migrations and the columns of `tables` in the
+/// connection's current schema.
+///
+/// # Errors
+///
+/// Returns the Diesel error if any inspection query fails.
+pub fn observe_schema(
+    connection: &mut PgConnection,
+    tables: &[&str],
+) -> QueryResult<ObservedSchema> {
+    let history = diesel::sql_query(
+        "SELECT to_regclass('__diesel_schema_migrations') IS NOT NULL AS present",
+    )
+    .get_result::<PresenceRow>(connection)?;
+    let applied_migrations = if history.present {
+        let versions =
+            diesel::sql_query("SELECT version::text AS version FROM __diesel_schema_migrations")
+                .load::<VersionRow>(connection)?;
+        Some(versions.into_iter().map(|row| row.version).collect())
+    } else {
+        None
+    };
+
+    let table_names: Vec<String> = tables.iter().map(|table| (*table).to_owned()).collect();
+    let column_rows = diesel::sql_query(concat!(
+        "SELECT table_name::text AS table_name, column_name::text AS column_name ",
+        "FROM information_schema.columns ",
+        "WHERE table_schema = current_schema() AND table_name = ANY($1)",
+    ))
+    .bind::<Array<Text>, _>(table_names)
+    .load::<ColumnRow>(connection)?;
+    let mut columns: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for row in column_rows {
+        columns
+            .entry(row.table_name)
+            .or_default()
+            .insert(row.column_name);
+    }
+
+    Ok(ObservedSchema {
+        applied_migrations,
+        columns,
+    })
+}
+
+/// Checks the connected database against `expectations`.
+///
+/// # Errors
+///
+/// Returns [`SchemaCheckError::Database`] if the schema cannot be inspected,
+/// or [`SchemaCheckError::Incompatible`] listing every mismatch.
+pub fn check_schema(
+    connection: &mut PgConnection,
+    expectations: &SchemaExpectations,
+) -> Result<(), SchemaCheckError> {
+    let tables: Vec<&str> = expectations
+        .tables()
+        .iter()
+        .map(|table| table.table())
+        .collect();
+    let observed = observe_schema(connection, &tables).map_err(SchemaCheckError::database)?;
+    Ok(expectations.compare(&observed)?)
+}
+
+/// Checks the database behind `pool` against the schema this binary was
+/// built against.
+///
+/// Run this once at startup, before serving traffic, so a missing migration
+/// or an out-of-band column fails fast instead of surfacing as a Diesel
+/// error mid-request.
+///
+/// # Errors
+///
+/// Returns [`SchemaCheckError::Database`] if no connection is available or
+/// the schema cannot be inspected, or [`SchemaCheckError::Incompatible`]
+/// listing every mismatch.
+pub fn verify_schema_compatibility(pool: &PgPool) -> Result<(), SchemaCheckError> {
+    let mut connection = pool.get().map_err(SchemaCheckError::database)?;
+    check_schema(&mut connection, &EXPECTED_SCHEMA)
+}
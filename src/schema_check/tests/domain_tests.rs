@@ -0,0 +1,125 @@
+//! Unit tests for comparing observed schemas against expectations.
+
+use crate::schema_check::{
+    EXPECTED_SCHEMA, ExpectedMigration, ObservedSchema, SchemaExpectations, SchemaMismatch,
+    TableFingerprint,
+};
+use rstest::{fixture, rstest};
+use std::collections::{BTreeMap, BTreeSet};
+
+const MIGRATIONS: &[ExpectedMigration] = &[
+    ExpectedMigration::new("2026-01-15-000000_create_base_tables"),
+    ExpectedMigration::new("2026-01-16-000000_add_audit_trigger"),
+];
+const TABLES: &[TableFingerprint] = &[TableFingerprint::new("messages", &["id", "role"])];
+const EXPECTATIONS: SchemaExpectations = SchemaExpectations::new(MIGRATIONS, TABLES);
+
+fn set(values: &[&str]) -> BTreeSet<String> {
+    values.iter().map(|value| (*value).to_owned()).collect()
+}
+
+#[fixture]
+fn matching() -> ObservedSchema {
+    ObservedSchema {
+        applied_migrations: Some(set(&["20260115000000", "20260116000000"])),
+        columns: BTreeMap::from([("messages".to_owned(), set(&["id", "role"]))]),
+    }
+}
+
+#[rstest]
+fn matching_schema_is_compatible(matching: ObservedSchema) {
+    assert_eq!(EXPECTATIONS.compare(&matching), Ok(()));
+}
+
+#[rstest]
+fn missing_history_is_reported(mut matching: ObservedSchema) {
+    matching.applied_migrations = None;
+
+    let error = EXPECTATIONS
+        .compare(&matching)
+        .expect_err("history is missing");
+
+    assert_eq!(
+        error.mismatches(),
+        [SchemaMismatch::MigrationHistoryMissing]
+    );
+}
+
+#[rstest]
+fn migration_differences_are_reported_in_both_directions(mut matching: ObservedSchema) {
+    matching.applied_migrations = Some(set(&["20260115000000", "20260301000000"]));
+
+    let error = EXPECTATIONS
+        .compare(&matching)
+        .expect_err("migrations differ");
+
+    assert_eq!(
+        error.mismatches(),
+        [
+            SchemaMismatch::MissingMigration {
+                name: "2026-01-16-000000_add_audit_trigger".to_owned(),
+            },
+            SchemaMismatch::UnknownMigration {
+                version: "20260301000000".to_owned(),
+            },
+        ]
+    );
+    assert!(error.to_string().contains("run `diesel migration run`"));
+}
+
+#[rstest]
+fn column_differences_are_reported(mut matching: ObservedSchema) {
+    matching
+        .columns
+        .insert("messages".to_owned(), set(&["id", "legacy_body"]));
+
+    let error = EXPECTATIONS.compare(&matching).expect_err("columns differ");
+
+    assert_eq!(
+        error.mismatches(),
+        [
+            SchemaMismatch::MissingColumn {
+                table: "messages".to_owned(),
+                column: "role".to_owned(),
+            },
+            SchemaMismatch::UnexpectedColumn {
+                table: "messages".to_owned(),
+                column: "legacy_body".to_owned(),
+            },
+        ]
+    );
+}
+
+#[rstest]
+fn missing_tables_are_reported(mut matching: ObservedSchema) {
+    matching.columns.clear();
+
+    let error = EXPECTATIONS
+        .compare(&matching)
+        .expect_err("table is missing");
+
+    assert_eq!(
+        error.mismatches(),
+        [SchemaMismatch::MissingTable {
+            table: "messages".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn expected_migrations_match_the_migrations_directory() -> std::io::Result<()> {
+    let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/migrations");
+    let mut on_disk = std::fs::read_dir(directory)?
+        .map(|entry| entry.map(|found| found.file_name().to_string_lossy().into_owned()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    on_disk.sort();
+
+    let expected: Vec<&str> = EXPECTED_SCHEMA
+        .migrations()
+        .iter()
+        .map(|migration| migration.name())
+        .collect();
+
+    assert_eq!(expected, on_disk);
+    Ok(())
+}
@@ -0,0 +1,3 @@
+//! Unit tests for the schema compatibility check.
+
+mod domain_tests;
@@ -0,0 +1,310 @@
+//! GitHub implementation of the `IssueProviderClient` port.
+//!
+//! [`GitHubIssueClient`] reads issues, labels, and comments through the
+//! GitHub REST API, authenticating with a bearer token. Exhausted rate
+//! limits surface as [`IssueProviderError::RateLimited`] with the reset time
+//! GitHub reports; a client configured with a maximum wait sleeps until a
+//! reset that falls within it and retries once. The client also implements
+//! [`IssueNotifier`](crate::task::ports::IssueNotifier), showing task state
+//! on issues with comments and labels.
+//!
+//! [`parse_github_webhook`] reads `issues` and `pull_request` webhook
+//! deliveries into [`IssueWebhookEvent`](crate::task::domain::IssueWebhookEvent)s;
+//! verify their `X-Hub-Signature-256` header first with a
+//! [`SignatureScheme::GitHub`](crate::message::adapters::inbound::SignatureScheme)
+//! verifier.
+
+mod notifier;
+mod payload;
+mod webhook;
+
+pub use webhook::{
+    GITHUB_DELIVERY_HEADER, GITHUB_EVENT_HEADER, GITHUB_SIGNATURE_HEADER, GitHubWebhookError,
+    parse_github_webhook,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use reqwest::header::{ACCEPT, HeaderMap};
+use reqwest::{Client, Method, RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::time::Duration;
+use thiserror::Error;
+
+use crate::context::RequestContext;
+use crate::task::domain::{ExternalIssue, IssueComment, IssueProvider, IssueRef};
+use crate::task::ports::{IssueProviderClient, IssueProviderError, IssueProviderResult};
+use payload::{CommentPayload, IssuePayload, LabelPayload};
+
+/// Public GitHub REST API endpoint.
+pub const DEFAULT_GITHUB_API_URL: &str = "https://api.github.com";
+
+const GITHUB_MEDIA_TYPE: &str = "application/vnd.github+json";
+const API_VERSION_HEADER: &str = "x-github-api-version";
+const API_VERSION: &str = "2022-11-28";
+const PAGE_SIZE: usize = 100;
+
+/// Connection settings for the GitHub REST API.
+#[derive(Clone)]
+pub struct GitHubConfig {
+    token: String,
+    api_url: String,
+    user_agent: String,
+    max_rate_limit_wait: Duration,
+}
+
+impl GitHubConfig {
+    /// Creates settings authenticating with `token`, a personal access or
+    /// installation token.
+    #[must_use]
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+            api_url: DEFAULT_GITHUB_API_URL.to_owned(),
+            user_agent: "corbusier".to_owned(),
+            max_rate_limit_wait: Duration::ZERO,
+        }
+    }
+
+    /// Talks to `api_url` instead of the public API, as for GitHub
+    /// Enterprise Server.
+    #[must_use]
+    pub fn with_api_url(mut self, api_url: impl Into<String>) -> Self {
+        self.api_url = api_url.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Identifies requests with `user_agent`, which GitHub requires.
+    #[must_use]
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Waits up to `wait` for an exhausted rate limit to reset, then retries
+    /// once. Without a wait, rate-limited requests fail immediately.
+    #[must_use]
+    pub const fn with_max_rate_limit_wait(mut self, wait: Duration) -> Self {
+        self.max_rate_limit_wait = wait;
+        self
+    }
+}
+
+impl fmt::Debug for GitHubConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GitHubConfig")
+            .field("api_url", &self.api_url)
+            .field("user_agent", &self.user_agent)
+            .field("max_rate_limit_wait", &self.max_rate_limit_wait)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Unexpected HTTP status from the GitHub API.
+#[derive(Debug, Error)]
+#[error("GitHub API responded with {0}")]
+struct UnexpectedStatus(StatusCode);
+
+/// Issue provider client for GitHub issues.
+#[derive(Clone)]
+pub struct GitHubIssueClient {
+    http: Client,
+    config: GitHubConfig,
+}
+
+impl GitHubIssueClient {
+    /// Creates a client for the API described by `config`.
+    ///
+    /// No request is made until the first lookup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssueProviderError::Provider`] when the HTTP client cannot
+    /// be set up, for example when no TLS backend is available.
+    pub fn new(config: GitHubConfig) -> IssueProviderResult<Self> {
+        let http = Client::builder()
+            .user_agent(config.user_agent.clone())
+            .build()
+            .map_err(IssueProviderError::provider)?;
+        Ok(Self { http, config })
+    }
+
+    /// Fetches every page of a list endpoint.
+    async fn get_all<T: DeserializeOwned>(
+        &self,
+        issue_ref: &IssueRef,
+        path: &str,
+    ) -> IssueProviderResult<Vec<T>> {
+        let mut items = Vec::new();
+        for page in 1_usize.. {
+            let url = format!("{path}?per_page={PAGE_SIZE}&page={page}");
+            let batch: Vec<T> = self.get(issue_ref, &url).await?;
+            let is_last = batch.len() < PAGE_SIZE;
+            items.extend(batch);
+            if is_last {
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    /// Sends a GET request, retrying once after a rate-limit reset that
+    /// falls within the configured wait.
+    async fn get<T: DeserializeOwned>(
+        &self,
+        issue_ref: &IssueRef,
+        path: &str,
+    ) -> IssueProviderResult<T> {
+        let first = self.send(issue_ref, path).await;
+        let wait = match &first {
+            Err(IssueProviderError::RateLimited {
+                reset_at: Some(reset_at),
+            }) => self.wait_until(*reset_at),
+            _ => None,
+        };
+        match wait {
+            Some(duration) => {
+                tokio::time::sleep(duration).await;
+                self.send(issue_ref, path).await
+            }
+            None => first,
+        }
+    }
+
+    async fn send<T: DeserializeOwned>(
+        &self,
+        issue_ref: &IssueRef,
+        path: &str,
+    ) -> IssueProviderResult<T> {
+        let response = self
+            .request(Method::GET, path)
+            .send()
+            .await
+            .map_err(IssueProviderError::provider)?;
+        if let Some(error) = status_error(issue_ref, response.status(), response.headers()) {
+            return Err(error);
+        }
+        response
+            .json::<T>()
+            .await
+            .map_err(IssueProviderError::invalid_response)
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http
+            .request(method, format!("{}{path}", self.config.api_url))
+            .bearer_auth(&self.config.token)
+            .header(ACCEPT, GITHUB_MEDIA_TYPE)
+            .header(API_VERSION_HEADER, API_VERSION)
+    }
+
+    fn wait_until(&self, reset_at: DateTime<Utc>) -> Option<Duration> {
+        let remaining = (reset_at - Utc::now()).to_std().unwrap_or_default();
+        (!self.config.max_rate_limit_wait.is_zero() && remaining <= self.config.max_rate_limit_wait)
+            .then_some(remaining)
+    }
+}
+
+impl fmt::Debug for GitHubIssueClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GitHubIssueClient")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the issue's API path, rejecting other providers' issues.
+fn issue_path(issue_ref: &IssueRef) -> IssueProviderResult<String> {
+    if issue_ref.provider() != IssueProvider::GitHub {
+        return Err(IssueProviderError::UnsupportedProvider(
+            issue_ref.provider(),
+        ));
+    }
+    Ok(format!(
+        "/repos/{}/issues/{}",
+        issue_ref.repository(),
+        issue_ref.issue_number()
+    ))
+}
+
+/// Maps a non-success status to the port error it stands for.
+fn status_error(
+    issue_ref: &IssueRef,
+    status: StatusCode,
+    headers: &HeaderMap,
+) -> Option<IssueProviderError> {
+    if status.is_success() {
+        return None;
+    }
+    if let Some(limited) = rate_limit_error(status, headers) {
+        return Some(limited);
+    }
+    Some(match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => IssueProviderError::AccessDenied,
+        StatusCode::NOT_FOUND | StatusCode::GONE => IssueProviderError::NotFound(issue_ref.clone()),
+        other => IssueProviderError::provider(UnexpectedStatus(other)),
+    })
+}
+
+/// Recognises GitHub's primary and secondary rate-limit responses.
+///
+/// Secondary limits send `retry-after` in seconds; an exhausted primary
+/// limit sends `x-ratelimit-remaining: 0` with the reset as a Unix time.
+fn rate_limit_error(status: StatusCode, headers: &HeaderMap) -> Option<IssueProviderError> {
+    if !matches!(
+        status,
+        StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS
+    ) {
+        return None;
+    }
+    let number = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<i64>().ok())
+    };
+    let reset_at = if let Some(seconds) = number("retry-after") {
+        TimeDelta::try_seconds(seconds).and_then(|delay| Utc::now().checked_add_signed(delay))
+    } else if number("x-ratelimit-remaining") == Some(0) {
+        number("x-ratelimit-reset").and_then(|epoch| DateTime::from_timestamp(epoch, 0))
+    } else if status == StatusCode::TOO_MANY_REQUESTS {
+        None
+    } else {
+        return None;
+    };
+    Some(IssueProviderError::RateLimited { reset_at })
+}
+
+#[async_trait]
+impl IssueProviderClient for GitHubIssueClient {
+    async fn fetch_issue(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<ExternalIssue> {
+        let path = issue_path(issue_ref)?;
+        let payload: IssuePayload = self.get(issue_ref, &path).await?;
+        payload.into_external(issue_ref)
+    }
+
+    async fn list_labels(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<Vec<String>> {
+        let path = format!("{}/labels", issue_path(issue_ref)?);
+        let labels: Vec<LabelPayload> = self.get_all(issue_ref, &path).await?;
+        Ok(labels.into_iter().map(LabelPayload::into_name).collect())
+    }
+
+    async fn list_comments(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<Vec<IssueComment>> {
+        let path = format!("{}/comments", issue_path(issue_ref)?);
+        let comments: Vec<CommentPayload> = self.get_all(issue_ref, &path).await?;
+        Ok(comments.into_iter().map(IssueComment::from).collect())
+    }
+}
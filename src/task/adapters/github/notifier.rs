@@ -0,0 +1,62 @@
+//! GitHub implementation of the `IssueNotifier` port.
+//!
+//! A status update is shown as a marked comment and a `corbusier:` label
+//! replacing any earlier status label. The label is written last and an
+//! issue already carrying it is left alone, so an update delivered again
+//! posts no second comment.
+
+use async_trait::async_trait;
+use reqwest::{Method, RequestBuilder};
+use serde_json::json;
+
+use super::{GitHubIssueClient, issue_path, status_error};
+use crate::context::RequestContext;
+use crate::task::domain::{IssueRef, IssueStatusUpdate, is_status_label};
+use crate::task::ports::{
+    IssueNotifier, IssueProviderClient, IssueProviderError, IssueProviderResult,
+};
+
+/// Sends a write request, discarding the response body.
+async fn write(issue_ref: &IssueRef, request: RequestBuilder) -> IssueProviderResult<()> {
+    let response = request.send().await.map_err(IssueProviderError::provider)?;
+    status_error(issue_ref, response.status(), response.headers()).map_or(Ok(()), Err)
+}
+
+#[async_trait]
+impl IssueNotifier for GitHubIssueClient {
+    async fn notify(
+        &self,
+        ctx: &RequestContext,
+        update: &IssueStatusUpdate,
+    ) -> IssueProviderResult<()> {
+        let issue_ref = update.issue_ref();
+        let path = issue_path(issue_ref)?;
+        let label = update.status_label();
+        let labels = self.list_labels(ctx, issue_ref).await?;
+        if labels.contains(&label) {
+            return Ok(());
+        }
+
+        let comment = json!({ "body": update.comment_body() });
+        write(
+            issue_ref,
+            self.request(Method::POST, &format!("{path}/comments"))
+                .json(&comment),
+        )
+        .await?;
+        for stale in labels.iter().filter(|name| is_status_label(name)) {
+            let removal = self.request(Method::DELETE, &format!("{path}/labels/{stale}"));
+            match write(issue_ref, removal).await {
+                Ok(()) | Err(IssueProviderError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        let added = json!({ "labels": [label] });
+        write(
+            issue_ref,
+            self.request(Method::POST, &format!("{path}/labels"))
+                .json(&added),
+        )
+        .await
+    }
+}
@@ -0,0 +1,105 @@
+//! GitHub REST API and webhook payloads and their mapping into task domain
+//! values.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::task::domain::{
+    ExternalIssue, ExternalIssueMetadata, IssueComment, IssueRef, TaskDomainError,
+};
+use crate::task::ports::{IssueProviderError, IssueProviderResult};
+
+/// Issue returned by `GET /repos/{owner}/{repo}/issues/{number}` and
+/// embedded in `issues` webhook events.
+#[derive(Debug, Deserialize)]
+pub(super) struct IssuePayload {
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    labels: Vec<LabelPayload>,
+    #[serde(default)]
+    assignees: Vec<UserPayload>,
+    #[serde(default)]
+    milestone: Option<MilestonePayload>,
+    /// Present when the issue number belongs to a pull request.
+    #[serde(default)]
+    pull_request: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct LabelPayload {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct UserPayload {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MilestonePayload {
+    title: String,
+}
+
+/// Comment returned by `GET /repos/{owner}/{repo}/issues/{number}/comments`.
+#[derive(Debug, Deserialize)]
+pub(super) struct CommentPayload {
+    /// `null` for comments by deleted accounts.
+    #[serde(default)]
+    user: Option<UserPayload>,
+    #[serde(default)]
+    body: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl IssuePayload {
+    /// Maps the payload into an external issue.
+    ///
+    /// Pull requests share the issue number space, so a pull request is
+    /// reported as [`IssueProviderError::NotFound`].
+    pub(super) fn into_external(self, issue_ref: &IssueRef) -> IssueProviderResult<ExternalIssue> {
+        if self.pull_request.is_some() {
+            return Err(IssueProviderError::NotFound(issue_ref.clone()));
+        }
+        let metadata = self
+            .into_metadata()
+            .map_err(IssueProviderError::invalid_response)?;
+        Ok(ExternalIssue::new(issue_ref.clone(), metadata))
+    }
+
+    /// Maps the payload into issue metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskDomainError::EmptyIssueTitle`] when the title is blank.
+    pub(super) fn into_metadata(self) -> Result<ExternalIssueMetadata, TaskDomainError> {
+        let mut metadata = ExternalIssueMetadata::new(self.title)?
+            .with_labels(self.labels.into_iter().map(LabelPayload::into_name))
+            .with_assignees(self.assignees.into_iter().map(|user| user.login));
+        if let Some(body) = self.body {
+            metadata = metadata.with_description(body);
+        }
+        if let Some(milestone) = self.milestone {
+            metadata = metadata.with_milestone(milestone.title);
+        }
+        Ok(metadata)
+    }
+}
+
+impl LabelPayload {
+    pub(super) fn into_name(self) -> String {
+        self.name
+    }
+}
+
+impl From<CommentPayload> for IssueComment {
+    fn from(payload: CommentPayload) -> Self {
+        Self {
+            author: payload.user.map(|user| user.login),
+            body: payload.body.unwrap_or_default(),
+            created_at: payload.created_at,
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! GitHub webhook deliveries for issue and pull request events.
+//!
+//! [`parse_github_webhook`] reads the body of an `issues`, `issue_comment`,
+//! or `pull_request` delivery into an [`IssueWebhookEvent`]. Opened,
+//! closed, labelled, and unlabelled issues, new comments, and opened,
+//! reopened, and closed pull requests are recognised; other events and
+//! actions are acknowledged without an event.
+//!
+//! An opened pull request lists the issues its description closes with
+//! GitHub's closing keywords, such as `Fixes #12` or
+//! `Closes octo/widgets#12`.
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::payload::{CommentPayload, IssuePayload, LabelPayload};
+use crate::task::domain::{
+    BranchRef, ExternalIssue, IssueComment, IssueProvider, IssueRef, IssueWebhookEvent,
+    PullRequestRef, TaskDomainError,
+};
+
+/// Header naming the webhook event.
+pub const GITHUB_EVENT_HEADER: &str = "X-GitHub-Event";
+/// Header carrying the delivery identifier.
+pub const GITHUB_DELIVERY_HEADER: &str = "X-GitHub-Delivery";
+/// Header carrying the HMAC-SHA256 signature of the body.
+pub const GITHUB_SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+
+/// Keywords that close an issue when followed by its reference.
+const CLOSING_KEYWORDS: [&str; 9] = [
+    "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+];
+
+/// Errors returned when a GitHub webhook body cannot be read.
+#[derive(Debug, Error)]
+pub enum GitHubWebhookError {
+    /// The body is not valid JSON of the expected shape.
+    #[error("malformed GitHub webhook payload: {0}")]
+    Malformed(#[from] serde_json::Error),
+    /// The payload names an invalid repository, issue, or branch.
+    #[error(transparent)]
+    InvalidReference(#[from] TaskDomainError),
+}
+
+#[derive(Debug, Deserialize)]
+struct RepositoryPayload {
+    full_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssuesEventPayload {
+    action: String,
+    issue: EventIssuePayload,
+    /// The added or removed label of a `labeled` or `unlabeled` action.
+    #[serde(default)]
+    label: Option<LabelPayload>,
+    repository: RepositoryPayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueCommentEventPayload {
+    action: String,
+    issue: EventIssuePayload,
+    comment: CommentPayload,
+    repository: RepositoryPayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventIssuePayload {
+    number: u64,
+    /// `completed`, `not_planned`, or `duplicate` once closed.
+    #[serde(default)]
+    state_reason: Option<String>,
+    #[serde(flatten)]
+    details: IssuePayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct PullRequestEventPayload {
+    action: String,
+    number: u64,
+    pull_request: EventPullRequestPayload,
+    repository: RepositoryPayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventPullRequestPayload {
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    merged: bool,
+    head: HeadPayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct HeadPayload {
+    #[serde(rename = "ref")]
+    branch: String,
+    /// The fork the branch lives in; `null` once the fork is deleted.
+    #[serde(default)]
+    repo: Option<RepositoryPayload>,
+}
+
+/// Parses a GitHub webhook body for the event named by the
+/// [`GITHUB_EVENT_HEADER`] header.
+///
+/// Returns `Ok(None)` for events and actions that do not affect tasks,
+/// including the `ping` sent when a webhook is created.
+///
+/// # Errors
+///
+/// Returns [`GitHubWebhookError::Malformed`] when the body does not match
+/// the event's payload, or [`GitHubWebhookError::InvalidReference`] when
+/// it names an invalid repository, issue, pull request, or branch.
+pub fn parse_github_webhook(
+    event: &str,
+    body: &[u8],
+) -> Result<Option<IssueWebhookEvent>, GitHubWebhookError> {
+    match event.trim() {
+        "issues" => issues_event(serde_json::from_slice(body)?),
+        "issue_comment" => issue_comment_event(serde_json::from_slice(body)?),
+        "pull_request" => pull_request_event(serde_json::from_slice(body)?),
+        _ => Ok(None),
+    }
+}
+
+fn issues_event(
+    payload: IssuesEventPayload,
+) -> Result<Option<IssueWebhookEvent>, GitHubWebhookError> {
+    let IssuesEventPayload {
+        action,
+        issue,
+        label,
+        repository,
+    } = payload;
+    let issue_ref = IssueRef::from_parts(
+        IssueProvider::GitHub.as_str(),
+        &repository.full_name,
+        issue.number,
+    )?;
+    let event = match action.as_str() {
+        "opened" => IssueWebhookEvent::IssueOpened(ExternalIssue::new(
+            issue_ref,
+            issue.details.into_metadata()?,
+        )),
+        "closed" => IssueWebhookEvent::IssueClosed {
+            issue_ref,
+            completed: !matches!(
+                issue.state_reason.as_deref(),
+                Some("not_planned" | "duplicate")
+            ),
+        },
+        "labeled" | "unlabeled" => match label {
+            Some(label) => IssueWebhookEvent::IssueLabelChanged {
+                issue_ref,
+                label: label.into_name(),
+            },
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+fn issue_comment_event(
+    payload: IssueCommentEventPayload,
+) -> Result<Option<IssueWebhookEvent>, GitHubWebhookError> {
+    if payload.action != "created" {
+        return Ok(None);
+    }
+    let issue_ref = IssueRef::from_parts(
+        IssueProvider::GitHub.as_str(),
+        &payload.repository.full_name,
+        payload.issue.number,
+    )?;
+    Ok(Some(IssueWebhookEvent::IssueCommented {
+        issue_ref,
+        body: IssueComment::from(payload.comment).body,
+    }))
+}
+
+fn pull_request_event(
+    payload: PullRequestEventPayload,
+) -> Result<Option<IssueWebhookEvent>, GitHubWebhookError> {
+    let PullRequestEventPayload {
+        action,
+        number,
+        pull_request: details,
+        repository,
+    } = payload;
+    let provider = IssueProvider::GitHub.as_str();
+    let pull_request = PullRequestRef::from_parts(provider, &repository.full_name, number)?;
+    let event = match action.as_str() {
+        "opened" | "reopened" => {
+            let head_repository = details.head.repo.as_ref().unwrap_or(&repository);
+            IssueWebhookEvent::PullRequestOpened {
+                pull_request,
+                branch: BranchRef::from_parts(
+                    provider,
+                    &head_repository.full_name,
+                    &details.head.branch,
+                )?,
+                closes: closing_references(
+                    &repository.full_name,
+                    details.body.as_deref().unwrap_or_default(),
+                ),
+            }
+        }
+        "closed" => IssueWebhookEvent::PullRequestClosed {
+            pull_request,
+            merged: details.merged,
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+/// Returns the issues `body` closes, in order of first mention.
+///
+/// References that do not name a valid issue are skipped.
+fn closing_references(repository: &str, body: &str) -> Vec<IssueRef> {
+    let words: Vec<&str> = body.split_whitespace().collect();
+    let mut references: Vec<IssueRef> = Vec::new();
+    for pair in words.windows(2) {
+        let [keyword, reference] = pair else {
+            continue;
+        };
+        let closes = CLOSING_KEYWORDS.iter().any(|candidate| {
+            keyword
+                .trim_end_matches(':')
+                .eq_ignore_ascii_case(candidate)
+        });
+        if !closes {
+            continue;
+        }
+        let Some(issue_ref) = parse_issue_reference(repository, reference) else {
+            continue;
+        };
+        if !references.contains(&issue_ref) {
+            references.push(issue_ref);
+        }
+    }
+    references
+}
+
+/// Parses `#12` or `owner/repo#12`, ignoring surrounding punctuation.
+fn parse_issue_reference(repository: &str, text: &str) -> Option<IssueRef> {
+    let trimmed = text
+        .trim_start_matches(['(', '['])
+        .trim_end_matches(|character: char| !character.is_ascii_digit());
+    let (target, number) = trimmed.split_once('#')?;
+    let issue_number = number.parse().ok()?;
+    let target_repository = if target.is_empty() {
+        repository
+    } else {
+        target
+    };
+    IssueRef::from_parts(
+        IssueProvider::GitHub.as_str(),
+        target_repository,
+        issue_number,
+    )
+    .ok()
+}
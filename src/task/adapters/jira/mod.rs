@@ -0,0 +1,288 @@
+//! Jira Cloud implementation of the `IssueProviderClient` port.
+//!
+//! [`JiraIssueClient`] reads issues, labels, and comments through the Jira
+//! Cloud REST API (version 2, whose descriptions and comments are plain
+//! text), authenticating with an account email and API token. Beyond the
+//! read-only port it can move an issue to a status through its workflow and
+//! post comments.
+//!
+//! Jira issues are referenced as described in
+//! [`JiraIssueKey`](crate::task::domain::JiraIssueKey); a client only accepts
+//! references to issues on its own site.
+
+mod payload;
+
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use reqwest::header::{ACCEPT, RETRY_AFTER};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use thiserror::Error;
+
+use crate::context::RequestContext;
+use crate::task::domain::{ExternalIssue, IssueComment, IssueRef, JiraIssueKey};
+use crate::task::ports::{IssueProviderClient, IssueProviderError, IssueProviderResult};
+use payload::{
+    CommentPagePayload, CommentPayload, CommentRequest, ISSUE_FIELDS, IssuePayload, LabelsPayload,
+    TransitionsPayload,
+};
+
+const JSON_MEDIA_TYPE: &str = "application/json";
+const PAGE_SIZE: usize = 100;
+
+/// Connection settings for a Jira Cloud site.
+#[derive(Clone)]
+pub struct JiraConfig {
+    site: String,
+    base_url: String,
+    email: String,
+    api_token: String,
+}
+
+impl JiraConfig {
+    /// Creates settings for the site at `https://{site}.atlassian.net`,
+    /// authenticating as the account `email` with an API token.
+    #[must_use]
+    pub fn new(
+        site: impl Into<String>,
+        email: impl Into<String>,
+        api_token: impl Into<String>,
+    ) -> Self {
+        let site_name = site.into().trim().to_owned();
+        Self {
+            base_url: format!("https://{site_name}.atlassian.net"),
+            site: site_name,
+            email: email.into(),
+            api_token: api_token.into(),
+        }
+    }
+
+    /// Talks to `base_url` instead of the site's `atlassian.net` address,
+    /// as behind a proxy.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_owned();
+        self
+    }
+}
+
+impl fmt::Debug for JiraConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JiraConfig")
+            .field("site", &self.site)
+            .field("base_url", &self.base_url)
+            .field("email", &self.email)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Unexpected HTTP status from the Jira API.
+#[derive(Debug, Error)]
+#[error("Jira API responded with {0}")]
+struct UnexpectedStatus(StatusCode);
+
+/// Issue provider client for Jira Cloud issues.
+#[derive(Clone)]
+pub struct JiraIssueClient {
+    http: Client,
+    config: JiraConfig,
+}
+
+impl JiraIssueClient {
+    /// Creates a client for the site described by `config`.
+    ///
+    /// No request is made until the first lookup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssueProviderError::Provider`] when the HTTP client cannot
+    /// be set up, for example when no TLS backend is available.
+    pub fn new(config: JiraConfig) -> IssueProviderResult<Self> {
+        let http = Client::builder()
+            .build()
+            .map_err(IssueProviderError::provider)?;
+        Ok(Self { http, config })
+    }
+
+    /// Moves an issue to `status` through the first workflow transition
+    /// that reaches it, matching target status names and then transition
+    /// names without regard to case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssueProviderError::TransitionUnavailable`] when no
+    /// transition reaches `status`, or another [`IssueProviderError`] when
+    /// the issue does not exist or the provider cannot be queried.
+    pub async fn transition_issue(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+        status: &str,
+    ) -> IssueProviderResult<()> {
+        let path = format!("{}/transitions", self.issue_path(issue_ref)?);
+        let offered: TransitionsPayload = self.get(issue_ref, &path).await?;
+        let request = offered.request_for(status).ok_or_else(|| {
+            IssueProviderError::TransitionUnavailable {
+                issue_ref: issue_ref.clone(),
+                status: status.to_owned(),
+            }
+        })?;
+        self.execute(
+            issue_ref,
+            self.request(reqwest::Method::POST, &path).json(&request),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Posts a comment on an issue and returns it as stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssueProviderError`] when the issue does not exist or the
+    /// provider rejects the comment.
+    pub async fn add_comment(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+        body: &str,
+    ) -> IssueProviderResult<IssueComment> {
+        let path = format!("{}/comment", self.issue_path(issue_ref)?);
+        let request = self
+            .request(reqwest::Method::POST, &path)
+            .json(&CommentRequest { body });
+        let comment: CommentPayload = decode(self.execute(issue_ref, request).await?).await?;
+        Ok(comment.into())
+    }
+
+    /// Returns the issue's API path, rejecting issues of other providers
+    /// and other sites.
+    fn issue_path(&self, issue_ref: &IssueRef) -> IssueProviderResult<String> {
+        let key = issue_key(issue_ref)?;
+        if issue_ref.jira_site() != Some(self.config.site.as_str()) {
+            return Err(IssueProviderError::NotFound(issue_ref.clone()));
+        }
+        Ok(format!("/rest/api/2/issue/{key}"))
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> RequestBuilder {
+        self.http
+            .request(method, format!("{}{path}", self.config.base_url))
+            .basic_auth(&self.config.email, Some(&self.config.api_token))
+            .header(ACCEPT, JSON_MEDIA_TYPE)
+    }
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        issue_ref: &IssueRef,
+        path: &str,
+    ) -> IssueProviderResult<T> {
+        let request = self.request(reqwest::Method::GET, path);
+        decode(self.execute(issue_ref, request).await?).await
+    }
+
+    /// Sends a request and maps a non-success status to the port error it
+    /// stands for.
+    async fn execute(
+        &self,
+        issue_ref: &IssueRef,
+        request: RequestBuilder,
+    ) -> IssueProviderResult<Response> {
+        let response = request.send().await.map_err(IssueProviderError::provider)?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        Err(match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => IssueProviderError::AccessDenied,
+            StatusCode::NOT_FOUND => IssueProviderError::NotFound(issue_ref.clone()),
+            StatusCode::TOO_MANY_REQUESTS => IssueProviderError::RateLimited {
+                reset_at: retry_at(&response),
+            },
+            other => IssueProviderError::provider(UnexpectedStatus(other)),
+        })
+    }
+}
+
+impl fmt::Debug for JiraIssueClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JiraIssueClient")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the Jira key of an issue reference, rejecting other providers.
+fn issue_key(issue_ref: &IssueRef) -> IssueProviderResult<JiraIssueKey> {
+    issue_ref
+        .jira_key()
+        .ok_or(IssueProviderError::UnsupportedProvider(
+            issue_ref.provider(),
+        ))
+}
+
+async fn decode<T: DeserializeOwned>(response: Response) -> IssueProviderResult<T> {
+    response
+        .json::<T>()
+        .await
+        .map_err(IssueProviderError::invalid_response)
+}
+
+/// Reads the `Retry-After` seconds Jira sends with a 429 response.
+fn retry_at(response: &Response) -> Option<chrono::DateTime<Utc>> {
+    let seconds = response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<i64>()
+        .ok()?;
+    Utc::now().checked_add_signed(TimeDelta::try_seconds(seconds)?)
+}
+
+#[async_trait]
+impl IssueProviderClient for JiraIssueClient {
+    async fn fetch_issue(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<ExternalIssue> {
+        let path = format!("{}?fields={ISSUE_FIELDS}", self.issue_path(issue_ref)?);
+        let payload: IssuePayload = self.get(issue_ref, &path).await?;
+        payload.into_external(issue_ref)
+    }
+
+    async fn list_labels(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<Vec<String>> {
+        let path = format!("{}?fields=labels", self.issue_path(issue_ref)?);
+        let payload: LabelsPayload = self.get(issue_ref, &path).await?;
+        Ok(payload.into_labels())
+    }
+
+    async fn list_comments(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<Vec<IssueComment>> {
+        let base = format!("{}/comment", self.issue_path(issue_ref)?);
+        let mut comments = Vec::new();
+        loop {
+            let path = format!(
+                "{base}?orderBy=created&startAt={}&maxResults={PAGE_SIZE}",
+                comments.len()
+            );
+            let page: CommentPagePayload = self.get(issue_ref, &path).await?;
+            let is_last =
+                page.comments.is_empty() || page.start_at + page.comments.len() >= page.total;
+            comments.extend(page.comments.into_iter().map(IssueComment::from));
+            if is_last {
+                return Ok(comments);
+            }
+        }
+    }
+}
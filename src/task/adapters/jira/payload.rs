@@ -0,0 +1,179 @@
+//! Jira Cloud REST API payloads and their mapping into task domain values.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::task::domain::{ExternalIssue, ExternalIssueMetadata, IssueComment, IssueRef};
+use crate::task::ports::{IssueProviderError, IssueProviderResult};
+
+/// Fields requested when fetching an issue.
+pub(super) const ISSUE_FIELDS: &str = "summary,description,labels,assignee,fixVersions";
+
+/// Issue returned by `GET /rest/api/2/issue/{key}`.
+#[derive(Debug, Deserialize)]
+pub(super) struct IssuePayload {
+    fields: IssueFields,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IssueFields {
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    assignee: Option<UserPayload>,
+    #[serde(default)]
+    fix_versions: Vec<VersionPayload>,
+}
+
+/// Issue labels, as returned by `GET /rest/api/2/issue/{key}?fields=labels`.
+#[derive(Debug, Deserialize)]
+pub(super) struct LabelsPayload {
+    fields: LabelFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct LabelFields {
+    #[serde(default)]
+    labels: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserPayload {
+    display_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct VersionPayload {
+    name: String,
+}
+
+/// A page of `GET /rest/api/2/issue/{key}/comment`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CommentPagePayload {
+    pub(super) start_at: usize,
+    pub(super) total: usize,
+    pub(super) comments: Vec<CommentPayload>,
+}
+
+/// A comment, as listed or as returned when posted.
+#[derive(Debug, Deserialize)]
+pub(super) struct CommentPayload {
+    #[serde(default)]
+    author: Option<UserPayload>,
+    #[serde(default)]
+    body: String,
+    #[serde(deserialize_with = "jira_timestamp")]
+    created: DateTime<Utc>,
+}
+
+/// Transitions offered by `GET /rest/api/2/issue/{key}/transitions`.
+#[derive(Debug, Deserialize)]
+pub(super) struct TransitionsPayload {
+    transitions: Vec<TransitionPayload>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TransitionPayload {
+    id: String,
+    name: String,
+    to: StatusPayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatusPayload {
+    name: String,
+}
+
+/// Body of `POST /rest/api/2/issue/{key}/transitions`.
+#[derive(Debug, Serialize)]
+pub(super) struct TransitionRequest<'a> {
+    transition: TransitionId<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct TransitionId<'a> {
+    id: &'a str,
+}
+
+/// Body of `POST /rest/api/2/issue/{key}/comment`.
+#[derive(Debug, Serialize)]
+pub(super) struct CommentRequest<'a> {
+    pub(super) body: &'a str,
+}
+
+impl IssuePayload {
+    /// Maps the payload into an external issue. The first fix version
+    /// stands in for the milestone.
+    pub(super) fn into_external(self, issue_ref: &IssueRef) -> IssueProviderResult<ExternalIssue> {
+        let IssueFields {
+            summary,
+            description,
+            labels,
+            assignee,
+            fix_versions,
+        } = self.fields;
+        let mut metadata = ExternalIssueMetadata::new(summary)
+            .map_err(IssueProviderError::invalid_response)?
+            .with_labels(labels)
+            .with_assignees(assignee.map(|user| user.display_name));
+        if let Some(text) = description {
+            metadata = metadata.with_description(text);
+        }
+        if let Some(version) = fix_versions.into_iter().next() {
+            metadata = metadata.with_milestone(version.name);
+        }
+        Ok(ExternalIssue::new(issue_ref.clone(), metadata))
+    }
+}
+
+impl LabelsPayload {
+    pub(super) fn into_labels(self) -> Vec<String> {
+        self.fields.labels
+    }
+}
+
+impl TransitionsPayload {
+    /// Returns the request moving the issue to `status`, matched against
+    /// each transition's target status and then its name, ignoring case.
+    pub(super) fn request_for(&self, status: &str) -> Option<TransitionRequest<'_>> {
+        let target = status.trim();
+        self.transitions
+            .iter()
+            .find(|transition| transition.to.name.eq_ignore_ascii_case(target))
+            .or_else(|| {
+                self.transitions
+                    .iter()
+                    .find(|transition| transition.name.eq_ignore_ascii_case(target))
+            })
+            .map(|transition| TransitionRequest {
+                transition: TransitionId { id: &transition.id },
+            })
+    }
+}
+
+impl From<CommentPayload> for IssueComment {
+    fn from(payload: CommentPayload) -> Self {
+        Self {
+            author: payload.author.map(|user| user.display_name),
+            body: payload.body,
+            created_at: payload.created,
+        }
+    }
+}
+
+/// Reads Jira's timestamps, such as `2026-01-17T12:34:00.000+0000`, whose
+/// offsets lack the colon RFC 3339 requires.
+fn jira_timestamp<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    DateTime::parse_from_str(&text, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .or_else(|_| DateTime::parse_from_rfc3339(&text))
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,77 @@
+//! In-memory repository for conversation usage budgets.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::domain::ConversationId;
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::task::{
+    domain::ConversationBudget,
+    ports::{UsageBudgetError, UsageBudgetRepository, UsageBudgetResult},
+};
+
+/// Thread-safe in-memory budget repository.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryUsageBudgetRepository {
+    state: Arc<RwLock<HashMap<(TenantId, ConversationId), ConversationBudget>>>,
+}
+
+impl InMemoryUsageBudgetRepository {
+    /// Creates an empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn lock_error(err: impl std::fmt::Display) -> UsageBudgetError {
+    UsageBudgetError::persistence(std::io::Error::other(err.to_string()))
+}
+
+#[async_trait]
+impl UsageBudgetRepository for InMemoryUsageBudgetRepository {
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> UsageBudgetResult<Option<ConversationBudget>> {
+        let state = self.state.read().map_err(lock_error)?;
+        Ok(state.get(&(ctx.tenant_id(), conversation_id)).copied())
+    }
+
+    async fn find_delegated(
+        &self,
+        ctx: &RequestContext,
+        parent_conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> UsageBudgetResult<Page<ConversationBudget>> {
+        let state = self.state.read().map_err(lock_error)?;
+        let tenant_id = ctx.tenant_id();
+        let mut children: Vec<ConversationBudget> = state
+            .iter()
+            .filter(|((tenant, _), budget)| {
+                *tenant == tenant_id
+                    && budget.parent_conversation_id == Some(parent_conversation_id)
+            })
+            .map(|(_, budget)| *budget)
+            .collect();
+        children.sort_by_key(|budget| (budget.created_at, budget.conversation_id.into_inner()));
+        Ok(Page::from_ordered(children, page, |budget| {
+            Cursor::at_timestamp(budget.created_at, budget.conversation_id.into_inner())
+        }))
+    }
+
+    async fn save(
+        &self,
+        ctx: &RequestContext,
+        budgets: &[ConversationBudget],
+    ) -> UsageBudgetResult<()> {
+        let mut state = self.state.write().map_err(lock_error)?;
+        for budget in budgets {
+            state.insert((ctx.tenant_id(), budget.conversation_id), *budget);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+//! In-memory usage ledger for task cost attribution tests.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::context::{RequestContext, TenantId};
+use crate::message::domain::ConversationId;
+use crate::task::{
+    domain::{
+        BackendUsage, ConversationCost, CostMicros, TaskConversationLink, TaskCostReport, TaskId,
+        TokenUsage, UsageRecord, UsageRecordId,
+    },
+    ports::{TaskCostError, TaskCostLedger, TaskCostResult},
+};
+
+/// Thread-safe in-memory task cost ledger.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTaskCostLedger {
+    state: Arc<RwLock<HashMap<TenantId, TenantLedgerState>>>,
+}
+
+#[derive(Debug, Default)]
+struct TenantLedgerState {
+    records: HashMap<UsageRecordId, UsageRecord>,
+    /// Links in insertion order, so reports list conversations as linked.
+    links: Vec<TaskConversationLink>,
+}
+
+impl InMemoryTaskCostLedger {
+    /// Creates an empty in-memory ledger.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn read_state(
+        &self,
+    ) -> TaskCostResult<RwLockReadGuard<'_, HashMap<TenantId, TenantLedgerState>>> {
+        self.state
+            .read()
+            .map_err(|err| TaskCostError::persistence(std::io::Error::other(err.to_string())))
+    }
+
+    fn write_state(
+        &self,
+    ) -> TaskCostResult<RwLockWriteGuard<'_, HashMap<TenantId, TenantLedgerState>>> {
+        self.state
+            .write()
+            .map_err(|err| TaskCostError::persistence(std::io::Error::other(err.to_string())))
+    }
+}
+
+impl TenantLedgerState {
+    fn link_for(&self, conversation_id: ConversationId) -> Option<&TaskConversationLink> {
+        self.links
+            .iter()
+            .find(|link| link.conversation_id == conversation_id)
+    }
+
+    fn conversation_cost(&self, link: TaskConversationLink) -> ConversationCost {
+        self.records
+            .values()
+            .filter(|record| record.conversation_id == link.conversation_id)
+            .fold(
+                ConversationCost {
+                    link,
+                    turn_count: 0,
+                    usage: TokenUsage::default(),
+                    cost: CostMicros::default(),
+                },
+                |mut line, record| {
+                    line.turn_count = line.turn_count.saturating_add(1);
+                    line.usage = line.usage.combine(record.usage);
+                    line.cost = line.cost.saturating_add(record.cost);
+                    line
+                },
+            )
+    }
+}
+
+#[async_trait]
+impl TaskCostLedger for InMemoryTaskCostLedger {
+    async fn record_usage(&self, ctx: &RequestContext, record: &UsageRecord) -> TaskCostResult<()> {
+        let mut state = self.write_state()?;
+        let tenant = state.entry(ctx.tenant_id()).or_default();
+        if tenant.records.contains_key(&record.id) {
+            return Err(TaskCostError::DuplicateUsageRecord(record.id));
+        }
+        tenant.records.insert(record.id, record.clone());
+        Ok(())
+    }
+
+    async fn link_conversation(
+        &self,
+        ctx: &RequestContext,
+        link: &TaskConversationLink,
+    ) -> TaskCostResult<()> {
+        let mut state = self.write_state()?;
+        let tenant = state.entry(ctx.tenant_id()).or_default();
+        match tenant.link_for(link.conversation_id) {
+            Some(existing) if existing.task_id == link.task_id => Ok(()),
+            Some(existing) => Err(TaskCostError::ConversationAlreadyLinked {
+                conversation_id: link.conversation_id,
+                task_id: existing.task_id,
+            }),
+            None => {
+                tenant.links.push(*link);
+                Ok(())
+            }
+        }
+    }
+
+    async fn find_link(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> TaskCostResult<Option<TaskConversationLink>> {
+        let state = self.read_state()?;
+        Ok(state
+            .get(&ctx.tenant_id())
+            .and_then(|tenant| tenant.link_for(conversation_id).copied()))
+    }
+
+    async fn task_cost_report(
+        &self,
+        ctx: &RequestContext,
+        task_id: TaskId,
+    ) -> TaskCostResult<TaskCostReport> {
+        let state = self.read_state()?;
+        let conversations = state
+            .get(&ctx.tenant_id())
+            .map(|tenant| {
+                tenant
+                    .links
+                    .iter()
+                    .filter(|link| link.task_id == task_id)
+                    .map(|link| tenant.conversation_cost(*link))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(TaskCostReport::new(task_id, conversations))
+    }
+
+    async fn backend_usage(
+        &self,
+        ctx: &RequestContext,
+        agent_backend: &str,
+        conversation_ids: &[ConversationId],
+    ) -> TaskCostResult<BackendUsage> {
+        let state = self.read_state()?;
+        Ok(state
+            .get(&ctx.tenant_id())
+            .map(|tenant| {
+                tenant
+                    .records
+                    .values()
+                    .filter(|record| {
+                        record.agent_backend == agent_backend
+                            && conversation_ids.contains(&record.conversation_id)
+                    })
+                    .fold(BackendUsage::default(), BackendUsage::with_record)
+            })
+            .unwrap_or_default())
+    }
+    async fn conversation_usage(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> TaskCostResult<BackendUsage> {
+        let state = self.read_state()?;
+        Ok(state
+            .get(&ctx.tenant_id())
+            .map(|tenant| {
+                tenant
+                    .records
+                    .values()
+                    .filter(|record| record.conversation_id == conversation_id)
+                    .fold(BackendUsage::default(), BackendUsage::with_record)
+            })
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,147 @@
+//! In-memory issue provider adapter for task tests.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::context::RequestContext;
+use crate::task::{
+    domain::{ExternalIssue, IssueComment, IssueRef, IssueStatusUpdate, is_status_label},
+    ports::{IssueNotifier, IssueProviderClient, IssueProviderError, IssueProviderResult},
+};
+
+/// Thread-safe in-memory stand-in for an external issue tracker.
+///
+/// Serves issues and comments recorded by the test; every other issue is
+/// reported as not found. Status updates are applied to recorded issues as
+/// a status label and a comment.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryIssueProvider {
+    issues: Arc<RwLock<HashMap<IssueRef, StoredIssue>>>,
+}
+
+#[derive(Debug, Clone)]
+struct StoredIssue {
+    issue: ExternalIssue,
+    comments: Vec<IssueComment>,
+}
+
+impl InMemoryIssueProvider {
+    /// Creates a provider with no issues.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `issue`, replacing any earlier version and its comments.
+    pub fn insert_issue(&self, issue: ExternalIssue) {
+        let mut issues = self
+            .issues
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        issues.insert(
+            issue.issue_ref().clone(),
+            StoredIssue {
+                issue,
+                comments: Vec::new(),
+            },
+        );
+    }
+
+    /// Appends a comment to a recorded issue.
+    ///
+    /// Comments on unrecorded issues are ignored.
+    pub fn add_comment(&self, issue_ref: &IssueRef, comment: IssueComment) {
+        let mut issues = self
+            .issues
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(stored) = issues.get_mut(issue_ref) {
+            stored.comments.push(comment);
+        }
+    }
+
+    fn read<T>(
+        &self,
+        issue_ref: &IssueRef,
+        query: impl FnOnce(&StoredIssue) -> T,
+    ) -> IssueProviderResult<T> {
+        let issues = self
+            .issues
+            .read()
+            .map_err(|err| IssueProviderError::provider(std::io::Error::other(err.to_string())))?;
+        issues
+            .get(issue_ref)
+            .map(query)
+            .ok_or_else(|| IssueProviderError::NotFound(issue_ref.clone()))
+    }
+}
+
+#[async_trait]
+impl IssueProviderClient for InMemoryIssueProvider {
+    async fn fetch_issue(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<ExternalIssue> {
+        self.read(issue_ref, |stored| stored.issue.clone())
+    }
+
+    async fn list_labels(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<Vec<String>> {
+        self.read(issue_ref, |stored| {
+            stored.issue.metadata().labels().to_vec()
+        })
+    }
+
+    async fn list_comments(
+        &self,
+        _ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<Vec<IssueComment>> {
+        self.read(issue_ref, |stored| stored.comments.clone())
+    }
+}
+
+#[async_trait]
+impl IssueNotifier for InMemoryIssueProvider {
+    async fn notify(
+        &self,
+        _ctx: &RequestContext,
+        update: &IssueStatusUpdate,
+    ) -> IssueProviderResult<()> {
+        let mut issues = self
+            .issues
+            .write()
+            .map_err(|err| IssueProviderError::provider(std::io::Error::other(err.to_string())))?;
+        let stored = issues
+            .get_mut(update.issue_ref())
+            .ok_or_else(|| IssueProviderError::NotFound(update.issue_ref().clone()))?;
+        let label = update.status_label();
+        let metadata = stored.issue.metadata();
+        if metadata.labels().contains(&label) {
+            return Ok(());
+        }
+        let labels: Vec<String> = metadata
+            .labels()
+            .iter()
+            .filter(|name| !is_status_label(name))
+            .cloned()
+            .chain([label])
+            .collect();
+        stored.issue = ExternalIssue::new(
+            update.issue_ref().clone(),
+            metadata.clone().with_labels(labels),
+        );
+        stored.comments.push(IssueComment {
+            author: None,
+            body: update.comment_body(),
+            created_at: Utc::now(),
+        });
+        Ok(())
+    }
+}
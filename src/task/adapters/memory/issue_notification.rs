@@ -0,0 +1,98 @@
+//! In-memory queue of issue status updates awaiting delivery.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::context::{RequestContext, TenantId};
+use crate::task::{
+    domain::{IssueStatusUpdate, PendingIssueNotification, TaskId},
+    ports::{IssueNotificationQueue, IssueNotificationQueueError, IssueNotificationQueueResult},
+};
+
+type QueueKey = (TenantId, TaskId);
+
+/// Thread-safe in-memory issue notification queue.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryIssueNotificationQueue {
+    state: Arc<RwLock<HashMap<QueueKey, PendingIssueNotification>>>,
+}
+
+impl InMemoryIssueNotificationQueue {
+    /// Creates an empty queue.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn lock_error(err: impl std::fmt::Display) -> IssueNotificationQueueError {
+    IssueNotificationQueueError::persistence(std::io::Error::other(err.to_string()))
+}
+
+fn key(ctx: &RequestContext, update: &IssueStatusUpdate) -> QueueKey {
+    (ctx.tenant_id(), update.task_id())
+}
+
+#[async_trait]
+impl IssueNotificationQueue for InMemoryIssueNotificationQueue {
+    async fn enqueue(
+        &self,
+        ctx: &RequestContext,
+        pending: &PendingIssueNotification,
+    ) -> IssueNotificationQueueResult<()> {
+        let mut state = self.state.write().map_err(lock_error)?;
+        state.insert(key(ctx, pending.update()), pending.clone());
+        Ok(())
+    }
+
+    async fn due(
+        &self,
+        ctx: &RequestContext,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> IssueNotificationQueueResult<Vec<PendingIssueNotification>> {
+        let state = self.state.read().map_err(lock_error)?;
+        let mut due: Vec<PendingIssueNotification> = state
+            .iter()
+            .filter(|((tenant_id, _), pending)| {
+                *tenant_id == ctx.tenant_id() && pending.next_attempt_at() <= now
+            })
+            .map(|(_, pending)| pending.clone())
+            .collect();
+        due.sort_by_key(PendingIssueNotification::next_attempt_at);
+        due.truncate(limit);
+        Ok(due)
+    }
+
+    async fn reschedule(
+        &self,
+        ctx: &RequestContext,
+        pending: &PendingIssueNotification,
+    ) -> IssueNotificationQueueResult<()> {
+        let mut state = self.state.write().map_err(lock_error)?;
+        if let Some(queued) = state.get_mut(&key(ctx, pending.update()))
+            && queued.update().state() == pending.update().state()
+        {
+            queued.clone_from(pending);
+        }
+        Ok(())
+    }
+
+    async fn remove(
+        &self,
+        ctx: &RequestContext,
+        update: &IssueStatusUpdate,
+    ) -> IssueNotificationQueueResult<()> {
+        let mut state = self.state.write().map_err(lock_error)?;
+        let queue_key = key(ctx, update);
+        if state
+            .get(&queue_key)
+            .is_some_and(|queued| queued.update().state() == update.state())
+        {
+            state.remove(&queue_key);
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,17 @@
 //! In-memory task persistence adapters.
 
+mod budget;
+mod cost;
+mod issue;
+mod issue_notification;
 mod task;
+mod vcs;
+mod webhook_delivery;
 
+pub use budget::InMemoryUsageBudgetRepository;
+pub use cost::InMemoryTaskCostLedger;
+pub use issue::InMemoryIssueProvider;
+pub use issue_notification::InMemoryIssueNotificationQueue;
 pub use task::InMemoryTaskRepository;
+pub use vcs::InMemoryVcsStatus;
+pub use webhook_delivery::InMemoryWebhookDeliveryLog;
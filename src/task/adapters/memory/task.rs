@@ -1,13 +1,19 @@
 //! In-memory repository for task lifecycle tests.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
 use crate::task::{
-    domain::{BranchRef, IssueRef, PullRequestRef, Task, TaskId},
-    ports::{TaskRepository, TaskRepositoryError, TaskRepositoryResult},
+    domain::{BranchRef, IssueRef, PullRequestRef, Task, TaskId, TaskPriority, TaskState},
+    ports::{
+        TaskQueue, TaskQueueError, TaskQueueResult, TaskRepository, TaskRepositoryError,
+        TaskRepositoryResult,
+    },
 };
 
 /// Thread-safe in-memory task repository.
@@ -89,14 +95,42 @@ fn find_by_index(
     index: &HashMap<String, Vec<TaskId>>,
     key: &str,
 ) -> Vec<Task> {
-    index
+    let mut tasks: Vec<Task> = index
         .get(key)
         .map(|ids| {
             ids.iter()
                 .filter_map(|id| state.tasks.get(id).cloned())
                 .collect()
         })
-        .unwrap_or_default()
+        .unwrap_or_default();
+    tasks.sort_by_key(|task| (task.created_at(), task.id().into_inner()));
+    tasks
+}
+
+/// Sort key placing the task to claim first lowest: highest priority, then
+/// earliest due date with undated tasks last, then oldest.
+type ClaimOrder = (
+    Reverse<TaskPriority>,
+    bool,
+    Option<DateTime<Utc>>,
+    DateTime<Utc>,
+    uuid::Uuid,
+);
+
+fn claim_order(task: &Task) -> ClaimOrder {
+    let schedule = task.schedule();
+    (
+        Reverse(schedule.priority()),
+        schedule.due_at().is_none(),
+        schedule.due_at(),
+        task.created_at(),
+        task.id().into_inner(),
+    )
+}
+
+/// Keyset position of a task in creation order.
+fn task_position(task: &Task) -> Cursor {
+    Cursor::at_timestamp(task.created_at(), task.id().into_inner())
 }
 
 #[async_trait]
@@ -194,25 +228,79 @@ impl TaskRepository for InMemoryTaskRepository {
         &self,
         ctx: &RequestContext,
         branch_ref: &BranchRef,
-    ) -> TaskRepositoryResult<Vec<Task>> {
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>> {
         let tenants = self.read_state()?;
         let key = branch_ref.to_string();
-        Ok(tenants
+        let tasks = tenants
             .get(&ctx.tenant_id())
             .map(|state| find_by_index(state, &state.branch_index, &key))
-            .unwrap_or_default())
+            .unwrap_or_default();
+        Ok(Page::from_ordered(tasks, page, task_position))
     }
 
     async fn find_by_pull_request_ref(
         &self,
         ctx: &RequestContext,
         pr_ref: &PullRequestRef,
-    ) -> TaskRepositoryResult<Vec<Task>> {
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>> {
         let tenants = self.read_state()?;
         let key = pr_ref.to_string();
-        Ok(tenants
+        let tasks = tenants
             .get(&ctx.tenant_id())
             .map(|state| find_by_index(state, &state.pull_request_index, &key))
-            .unwrap_or_default())
+            .unwrap_or_default();
+        Ok(Page::from_ordered(tasks, page, task_position))
+    }
+
+    async fn find_reconcilable(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>> {
+        let tenants = self.read_state()?;
+        let mut tasks: Vec<Task> = tenants
+            .get(&ctx.tenant_id())
+            .map(|state| {
+                state
+                    .tasks
+                    .values()
+                    .filter(|task| !task.state().is_terminal())
+                    .filter(|task| task.branch_ref().is_some() || task.pull_request_ref().is_some())
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        tasks.sort_by_key(|task| (task.created_at(), task.id().into_inner()));
+        Ok(Page::from_ordered(tasks, page, task_position))
+    }
+}
+
+#[async_trait]
+impl TaskQueue for InMemoryTaskRepository {
+    async fn claim_next(
+        &self,
+        ctx: &RequestContext,
+        worker_capabilities: &[String],
+        claimed_at: DateTime<Utc>,
+    ) -> TaskQueueResult<Option<Task>> {
+        let mut tenants = self.write_state().map_err(TaskQueueError::persistence)?;
+        let Some(state) = tenants.get_mut(&ctx.tenant_id()) else {
+            return Ok(None);
+        };
+        let next = state
+            .tasks
+            .values()
+            .filter(|task| task.state() == TaskState::Draft)
+            .filter(|task| task.schedule().is_satisfied_by(worker_capabilities))
+            .min_by_key(|task| claim_order(task))
+            .map(Task::id);
+        let Some(task) = next.and_then(|task_id| state.tasks.get_mut(&task_id)) else {
+            return Ok(None);
+        };
+        task.claim(claimed_at)
+            .map_err(TaskQueueError::persistence)?;
+        Ok(Some(task.clone()))
     }
 }
@@ -0,0 +1,110 @@
+//! In-memory VCS status adapter for reconciliation tests.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use crate::context::RequestContext;
+use crate::task::{
+    domain::{BranchRef, BranchStatus, PullRequestRef, PullRequestStatus},
+    ports::{VcsStatusError, VcsStatusPort, VcsStatusResult},
+};
+
+/// Thread-safe in-memory stand-in for a VCS provider.
+///
+/// Branches are present and pull requests are open unless a test records
+/// otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryVcsStatus {
+    state: Arc<RwLock<VcsState>>,
+}
+
+#[derive(Debug, Default)]
+struct VcsState {
+    deleted_branches: HashSet<String>,
+    force_pushes: HashMap<String, DateTime<Utc>>,
+    pull_requests: HashMap<String, PullRequestStatus>,
+}
+
+impl InMemoryVcsStatus {
+    /// Creates a provider where every branch exists and every pull request
+    /// is open.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks a branch as deleted.
+    pub fn delete_branch(&self, branch_ref: &BranchRef) {
+        self.with_state(|state| {
+            state.deleted_branches.insert(branch_ref.to_string());
+        });
+    }
+
+    /// Records a force-push of a branch at `at`.
+    pub fn force_push_branch(&self, branch_ref: &BranchRef, at: DateTime<Utc>) {
+        self.with_state(|state| {
+            state.force_pushes.insert(branch_ref.to_string(), at);
+        });
+    }
+
+    /// Sets the status of a pull request.
+    pub fn set_pull_request_status(&self, pr_ref: &PullRequestRef, status: PullRequestStatus) {
+        self.with_state(|state| {
+            state.pull_requests.insert(pr_ref.to_string(), status);
+        });
+    }
+
+    fn with_state(&self, update: impl FnOnce(&mut VcsState)) {
+        let mut state = self
+            .state
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        update(&mut state);
+    }
+
+    fn read<T>(&self, query: impl FnOnce(&VcsState) -> T) -> VcsStatusResult<T> {
+        let state = self
+            .state
+            .read()
+            .map_err(|err| VcsStatusError::provider(std::io::Error::other(err.to_string())))?;
+        Ok(query(&state))
+    }
+}
+
+#[async_trait]
+impl VcsStatusPort for InMemoryVcsStatus {
+    async fn branch_status(
+        &self,
+        _ctx: &RequestContext,
+        branch_ref: &BranchRef,
+        since: DateTime<Utc>,
+    ) -> VcsStatusResult<BranchStatus> {
+        let key = branch_ref.to_string();
+        self.read(|state| {
+            if state.deleted_branches.contains(&key) {
+                BranchStatus::Deleted
+            } else if state.force_pushes.get(&key).is_some_and(|at| *at > since) {
+                BranchStatus::ForcePushed
+            } else {
+                BranchStatus::Present
+            }
+        })
+    }
+
+    async fn pull_request_status(
+        &self,
+        _ctx: &RequestContext,
+        pr_ref: &PullRequestRef,
+    ) -> VcsStatusResult<PullRequestStatus> {
+        let key = pr_ref.to_string();
+        self.read(|state| {
+            state
+                .pull_requests
+                .get(&key)
+                .copied()
+                .unwrap_or(PullRequestStatus::Open)
+        })
+    }
+}
@@ -0,0 +1,53 @@
+//! In-memory log of processed webhook deliveries.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+use crate::context::{RequestContext, TenantId};
+use crate::task::{
+    domain::WebhookDelivery,
+    ports::{WebhookDeliveryError, WebhookDeliveryLog, WebhookDeliveryResult},
+};
+
+/// Thread-safe in-memory webhook delivery log.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryWebhookDeliveryLog {
+    state: Arc<RwLock<HashSet<(TenantId, WebhookDelivery)>>>,
+}
+
+impl InMemoryWebhookDeliveryLog {
+    /// Creates an empty log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn lock_error(err: impl std::fmt::Display) -> WebhookDeliveryError {
+    WebhookDeliveryError::persistence(std::io::Error::other(err.to_string()))
+}
+
+#[async_trait]
+impl WebhookDeliveryLog for InMemoryWebhookDeliveryLog {
+    async fn claim(
+        &self,
+        ctx: &RequestContext,
+        delivery: &WebhookDelivery,
+        _received_at: DateTime<Utc>,
+    ) -> WebhookDeliveryResult<bool> {
+        let mut state = self.state.write().map_err(lock_error)?;
+        Ok(state.insert((ctx.tenant_id(), delivery.clone())))
+    }
+
+    async fn release(
+        &self,
+        ctx: &RequestContext,
+        delivery: &WebhookDelivery,
+    ) -> WebhookDeliveryResult<()> {
+        let mut state = self.state.write().map_err(lock_error)?;
+        state.remove(&(ctx.tenant_id(), delivery.clone()));
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 //! Adapter implementations for task lifecycle ports.
 
+pub mod github;
+pub mod jira;
 pub mod memory;
 pub mod postgres;
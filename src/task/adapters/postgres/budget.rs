@@ -0,0 +1,216 @@
+//! `PostgreSQL` repository for conversation usage budgets.
+//!
+//! Budgets are upserted on the `(tenant_id, conversation_id)` primary key.
+//! [`UsageBudgetRepository::save`] writes a parent and its child in one
+//! transaction, so a reservation is never visible without the delegated
+//! budget it was made for.
+
+use super::{models::ConversationBudgetRow, schema::conversation_budgets};
+use crate::context::{RequestContext, TenantId};
+use crate::message::adapters::postgres::blocking_helpers::{
+    PgPool, get_conn_with, run_blocking_with,
+};
+use crate::message::domain::ConversationId;
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{
+    FromTxError, TxError, keyset_page, with_tenant_read_tx, with_tenant_tx,
+};
+use crate::task::{
+    domain::{ConversationBudget, CostMicros, UsageAllowance},
+    ports::{UsageBudgetError, UsageBudgetRepository, UsageBudgetResult},
+};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+
+impl FromTxError<Self> for UsageBudgetError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed budget repository.
+#[derive(Debug, Clone)]
+pub struct PostgresUsageBudgetRepository {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresUsageBudgetRepository,
+    "usage_budget_repository"
+);
+
+impl PostgresUsageBudgetRepository {
+    /// Creates a new repository from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UsageBudgetRepository for PostgresUsageBudgetRepository {
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> UsageBudgetResult<Option<ConversationBudget>> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, UsageBudgetError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    conversation_budgets::table
+                        .filter(conversation_budgets::tenant_id.eq(tenant_uuid))
+                        .filter(
+                            conversation_budgets::conversation_id.eq(conversation_id.into_inner()),
+                        )
+                        .select(ConversationBudgetRow::as_select())
+                        .first::<ConversationBudgetRow>(tx)
+                        .optional()
+                        .map_err(UsageBudgetError::persistence)
+                })
+            },
+            UsageBudgetError::persistence,
+        )
+        .await?;
+        row.as_ref().map(row_to_budget).transpose()
+    }
+
+    async fn find_delegated(
+        &self,
+        ctx: &RequestContext,
+        parent_conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> UsageBudgetResult<Page<ConversationBudget>> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, UsageBudgetError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    let query = conversation_budgets::table
+                        .filter(conversation_budgets::tenant_id.eq(tenant_uuid))
+                        .filter(
+                            conversation_budgets::parent_conversation_id
+                                .eq(parent_conversation_id.into_inner()),
+                        )
+                        .select(ConversationBudgetRow::as_select())
+                        .into_boxed();
+                    let rows = keyset_page!(
+                        query,
+                        page,
+                        after_timestamp,
+                        (
+                            conversation_budgets::created_at,
+                            conversation_budgets::conversation_id
+                        )
+                    )
+                    .load::<ConversationBudgetRow>(tx)
+                    .map_err(UsageBudgetError::persistence)?;
+                    Page::from_overfetched(rows, page, |row| {
+                        Cursor::at_timestamp(row.created_at, row.conversation_id)
+                    })
+                    .try_map(|row| row_to_budget(&row))
+                })
+            },
+            UsageBudgetError::persistence,
+        )
+        .await
+    }
+
+    async fn save(
+        &self,
+        ctx: &RequestContext,
+        budgets: &[ConversationBudget],
+    ) -> UsageBudgetResult<()> {
+        let pool = self.pool.clone();
+        let tenant_id = ctx.tenant_id();
+        let rows = budgets
+            .iter()
+            .map(|budget| to_row(budget, tenant_id))
+            .collect::<UsageBudgetResult<Vec<_>>>()?;
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, UsageBudgetError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_id.into_inner(), |tx| {
+                    for row in &rows {
+                        upsert_row(tx, row).map_err(UsageBudgetError::persistence)?;
+                    }
+                    Ok(())
+                })
+            },
+            UsageBudgetError::persistence,
+        )
+        .await
+    }
+}
+
+fn upsert_row(conn: &mut PgConnection, row: &ConversationBudgetRow) -> QueryResult<()> {
+    diesel::insert_into(conversation_budgets::table)
+        .values(row)
+        .on_conflict((
+            conversation_budgets::tenant_id,
+            conversation_budgets::conversation_id,
+        ))
+        .do_update()
+        .set((
+            conversation_budgets::reserved_tokens
+                .eq(excluded(conversation_budgets::reserved_tokens)),
+            conversation_budgets::reserved_cost_micros
+                .eq(excluded(conversation_budgets::reserved_cost_micros)),
+            conversation_budgets::absorbed_tokens
+                .eq(excluded(conversation_budgets::absorbed_tokens)),
+            conversation_budgets::absorbed_cost_micros
+                .eq(excluded(conversation_budgets::absorbed_cost_micros)),
+            conversation_budgets::reconciled_at.eq(excluded(conversation_budgets::reconciled_at)),
+        ))
+        .execute(conn)
+        .map(|_| ())
+}
+
+fn to_row(
+    budget: &ConversationBudget,
+    tenant_id: TenantId,
+) -> UsageBudgetResult<ConversationBudgetRow> {
+    let to_i64 = |value: u64| i64::try_from(value).map_err(UsageBudgetError::persistence);
+    Ok(ConversationBudgetRow {
+        tenant_id: tenant_id.into_inner(),
+        conversation_id: budget.conversation_id.into_inner(),
+        parent_conversation_id: budget
+            .parent_conversation_id
+            .map(ConversationId::into_inner),
+        limit_tokens: to_i64(budget.limit.tokens)?,
+        limit_cost_micros: to_i64(budget.limit.cost.value())?,
+        reserved_tokens: to_i64(budget.reserved.tokens)?,
+        reserved_cost_micros: to_i64(budget.reserved.cost.value())?,
+        absorbed_tokens: to_i64(budget.absorbed.tokens)?,
+        absorbed_cost_micros: to_i64(budget.absorbed.cost.value())?,
+        created_at: budget.created_at,
+        reconciled_at: budget.reconciled_at,
+    })
+}
+
+fn row_to_budget(row: &ConversationBudgetRow) -> UsageBudgetResult<ConversationBudget> {
+    let allowance = |tokens: i64, cost: i64| -> UsageBudgetResult<UsageAllowance> {
+        let to_u64 = |value: i64| u64::try_from(value).map_err(UsageBudgetError::persistence);
+        Ok(UsageAllowance::new(
+            to_u64(tokens)?,
+            CostMicros::new(to_u64(cost)?),
+        ))
+    };
+    Ok(ConversationBudget {
+        conversation_id: ConversationId::from_uuid(row.conversation_id),
+        parent_conversation_id: row.parent_conversation_id.map(ConversationId::from_uuid),
+        limit: allowance(row.limit_tokens, row.limit_cost_micros)?,
+        reserved: allowance(row.reserved_tokens, row.reserved_cost_micros)?,
+        absorbed: allowance(row.absorbed_tokens, row.absorbed_cost_micros)?,
+        created_at: row.created_at,
+        reconciled_at: row.reconciled_at,
+    })
+}
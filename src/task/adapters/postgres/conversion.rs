@@ -0,0 +1,102 @@
+//! Row conversion helpers for the `PostgreSQL` task repository.
+
+use super::models::{NewTaskRow, TaskRow};
+use crate::context::TenantId;
+use crate::task::{
+    domain::{
+        BranchRef, PersistedTaskData, PullRequestRef, Task, TaskId, TaskOrigin, TaskPriority,
+        TaskSchedule, TaskState,
+    },
+    ports::{TaskRepositoryError, TaskRepositoryResult},
+};
+use thiserror::Error;
+
+/// A stored priority rank outside the known priorities.
+#[derive(Debug, Error)]
+#[error("unknown task priority rank: {0}")]
+struct UnknownPriorityRank(i16);
+
+pub(super) fn to_new_row(task: &Task, tenant_id: TenantId) -> TaskRepositoryResult<NewTaskRow> {
+    let origin = serde_json::to_value(task.origin()).map_err(TaskRepositoryError::persistence)?;
+    let schedule = task.schedule();
+
+    Ok(NewTaskRow {
+        id: task.id().into_inner(),
+        tenant_id: tenant_id.into_inner(),
+        origin,
+        branch_ref: task.branch_ref().map(ToString::to_string),
+        pull_request_ref: task.pull_request_ref().map(ToString::to_string),
+        state: task.state().as_str().to_owned(),
+        workspace_id: None,
+        created_at: task.created_at(),
+        updated_at: task.updated_at(),
+        priority: schedule.priority().rank(),
+        due_at: schedule.due_at(),
+        required_capabilities: schedule.required_capabilities().to_vec(),
+    })
+}
+
+pub(super) fn row_to_task(row: TaskRow) -> TaskRepositoryResult<Task> {
+    let TaskRow {
+        id,
+        tenant_id: _tenant_id,
+        origin: persisted_origin,
+        branch_ref,
+        pull_request_ref,
+        state: persisted_state,
+        workspace_id,
+        created_at,
+        updated_at,
+        priority,
+        due_at,
+        required_capabilities,
+    } = row;
+
+    // workspace_id is still deferred to roadmap item 1.2.3.
+    debug_assert!(
+        workspace_id.is_none(),
+        "workspace column should remain unset until roadmap item 1.2.3"
+    );
+
+    let origin = serde_json::from_value::<TaskOrigin>(persisted_origin)
+        .map_err(TaskRepositoryError::persistence)?;
+    let state =
+        TaskState::try_from(persisted_state.as_str()).map_err(TaskRepositoryError::persistence)?;
+
+    let parsed_branch = branch_ref
+        .map(|s| BranchRef::parse_canonical(&s))
+        .transpose()
+        .map_err(TaskRepositoryError::persistence)?;
+    let parsed_pr = pull_request_ref
+        .map(|s| PullRequestRef::parse_canonical(&s))
+        .transpose()
+        .map_err(TaskRepositoryError::persistence)?;
+
+    let schedule = to_schedule(priority, due_at, required_capabilities)?;
+
+    let data = PersistedTaskData {
+        id: TaskId::from_uuid(id),
+        origin,
+        branch_ref: parsed_branch,
+        pull_request_ref: parsed_pr,
+        state,
+        schedule,
+        created_at,
+        updated_at,
+    };
+    Ok(Task::from_persisted(data))
+}
+
+fn to_schedule(
+    rank: i16,
+    due_at: Option<chrono::DateTime<chrono::Utc>>,
+    required_capabilities: Vec<String>,
+) -> TaskRepositoryResult<TaskSchedule> {
+    let priority = TaskPriority::from_rank(rank)
+        .ok_or_else(|| TaskRepositoryError::persistence(UnknownPriorityRank(rank)))?;
+    let schedule = TaskSchedule::new(priority).with_required_capabilities(required_capabilities);
+    Ok(match due_at {
+        Some(due) => schedule.with_due_at(due),
+        None => schedule,
+    })
+}
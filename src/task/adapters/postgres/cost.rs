@@ -0,0 +1,395 @@
+//! `PostgreSQL` usage ledger for task cost attribution.
+//!
+//! Usage records and conversation links live in separate tables; cost reports
+//! join them with a single grouped query so a task's total is computed in the
+//! database rather than by loading every record.
+
+use super::{
+    models::{NewUsageRecordRow, TaskConversationLinkRow},
+    schema::{task_conversation_links, usage_records},
+};
+use crate::context::{RequestContext, TenantId};
+use crate::message::adapters::postgres::blocking_helpers::{
+    PgPool, get_conn_with, run_blocking_with,
+};
+use crate::message::domain::ConversationId;
+use crate::postgres_support::{FromTxError, TxError, with_tenant_read_tx, with_tenant_tx};
+use crate::task::{
+    domain::{
+        BackendUsage, ConversationCost, ConversationLinkKind, CostMicros, TaskConversationLink,
+        TaskCostReport, TaskId, TokenUsage, UsageRecord,
+    },
+    ports::{TaskCostError, TaskCostLedger, TaskCostResult},
+};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use diesel::sql_types::{Array, BigInt, Nullable, Uuid as SqlUuid, Varchar};
+
+impl FromTxError<Self> for TaskCostError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// Rolls up usage per linked conversation for one task.
+///
+/// Bind parameters: `$1` tenant, `$2` task.
+const TASK_COST_SQL: &str = concat!(
+    "SELECT l.conversation_id, l.task_id, l.link_kind, l.parent_conversation_id, ",
+    "COUNT(u.id) AS turn_count, ",
+    "COALESCE(SUM(u.input_tokens), 0)::BIGINT AS input_tokens, ",
+    "COALESCE(SUM(u.output_tokens), 0)::BIGINT AS output_tokens, ",
+    "COALESCE(SUM(u.cost_micros), 0)::BIGINT AS cost_micros ",
+    "FROM task_conversation_links l ",
+    "LEFT JOIN usage_records u ",
+    "ON u.tenant_id = l.tenant_id AND u.conversation_id = l.conversation_id ",
+    "WHERE l.tenant_id = $1 AND l.task_id = $2 ",
+    "GROUP BY l.conversation_id, l.task_id, l.link_kind, l.parent_conversation_id, ",
+    "l.linked_at ",
+    "ORDER BY l.linked_at",
+);
+
+/// Rolls up usage recorded on one backend by a set of conversations.
+///
+/// Bind parameters: `$1` tenant, `$2` backend name, `$3` conversation IDs.
+const BACKEND_USAGE_SQL: &str = concat!(
+    "SELECT COUNT(id) AS turn_count, ",
+    "COALESCE(SUM(input_tokens), 0)::BIGINT AS input_tokens, ",
+    "COALESCE(SUM(output_tokens), 0)::BIGINT AS output_tokens, ",
+    "COALESCE(SUM(cost_micros), 0)::BIGINT AS cost_micros ",
+    "FROM usage_records ",
+    "WHERE tenant_id = $1 AND agent_backend = $2 AND conversation_id = ANY($3)",
+);
+
+/// Rolls up usage recorded by one conversation.
+///
+/// Bind parameters: `$1` tenant, `$2` conversation.
+const CONVERSATION_USAGE_SQL: &str = concat!(
+    "SELECT COUNT(id) AS turn_count, ",
+    "COALESCE(SUM(input_tokens), 0)::BIGINT AS input_tokens, ",
+    "COALESCE(SUM(output_tokens), 0)::BIGINT AS output_tokens, ",
+    "COALESCE(SUM(cost_micros), 0)::BIGINT AS cost_micros ",
+    "FROM usage_records WHERE tenant_id = $1 AND conversation_id = $2",
+);
+
+/// Query result row for a usage roll-up.
+#[derive(Debug, QueryableByName)]
+struct BackendUsageRow {
+    #[diesel(sql_type = BigInt)]
+    turn_count: i64,
+    #[diesel(sql_type = BigInt)]
+    input_tokens: i64,
+    #[diesel(sql_type = BigInt)]
+    output_tokens: i64,
+    #[diesel(sql_type = BigInt)]
+    cost_micros: i64,
+}
+
+/// Query result row for one conversation in a task cost report.
+#[derive(Debug, QueryableByName)]
+struct ConversationCostRow {
+    #[diesel(sql_type = SqlUuid)]
+    conversation_id: uuid::Uuid,
+    #[diesel(sql_type = SqlUuid)]
+    task_id: uuid::Uuid,
+    #[diesel(sql_type = Varchar)]
+    link_kind: String,
+    #[diesel(sql_type = Nullable<SqlUuid>)]
+    parent_conversation_id: Option<uuid::Uuid>,
+    #[diesel(sql_type = BigInt)]
+    turn_count: i64,
+    #[diesel(sql_type = BigInt)]
+    input_tokens: i64,
+    #[diesel(sql_type = BigInt)]
+    output_tokens: i64,
+    #[diesel(sql_type = BigInt)]
+    cost_micros: i64,
+}
+
+/// `PostgreSQL`-backed task cost ledger.
+#[derive(Debug, Clone)]
+pub struct PostgresTaskCostLedger {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(PostgresTaskCostLedger, "task_cost_ledger");
+
+impl PostgresTaskCostLedger {
+    /// Creates a new ledger from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn run<F, T, Q>(&self, tenant_id: TenantId, query_fn: F, wrap: Q) -> TaskCostResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> TaskCostResult<T> + Send + 'static,
+        T: Send + 'static,
+        Q: FnOnce(&mut PgConnection, uuid::Uuid, F) -> TaskCostResult<T> + Send + 'static,
+    {
+        let pool = self.pool.clone();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, TaskCostError::persistence)?;
+                wrap(&mut conn, tenant_id.into_inner(), query_fn)
+            },
+            TaskCostError::persistence,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl TaskCostLedger for PostgresTaskCostLedger {
+    async fn record_usage(&self, ctx: &RequestContext, record: &UsageRecord) -> TaskCostResult<()> {
+        let tenant_id = ctx.tenant_id();
+        let record_id = record.id;
+        let row = to_new_usage_row(record, tenant_id)?;
+
+        self.run(
+            tenant_id,
+            move |conn| {
+                diesel::insert_into(usage_records::table)
+                    .values(&row)
+                    .execute(conn)
+                    .map_err(|err| match err {
+                        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                            TaskCostError::DuplicateUsageRecord(record_id)
+                        }
+                        other => TaskCostError::persistence(other),
+                    })?;
+                Ok(())
+            },
+            with_tenant_tx,
+        )
+        .await
+    }
+
+    async fn link_conversation(
+        &self,
+        ctx: &RequestContext,
+        link: &TaskConversationLink,
+    ) -> TaskCostResult<()> {
+        let tenant_id = ctx.tenant_id();
+        let link = *link;
+
+        self.run(
+            tenant_id,
+            move |conn| {
+                let inserted = diesel::insert_into(task_conversation_links::table)
+                    .values(to_link_row(&link, tenant_id))
+                    .on_conflict((
+                        task_conversation_links::tenant_id,
+                        task_conversation_links::conversation_id,
+                    ))
+                    .do_nothing()
+                    .execute(conn)
+                    .map_err(TaskCostError::persistence)?;
+                if inserted > 0 {
+                    return Ok(());
+                }
+                match load_link(conn, tenant_id, link.conversation_id)? {
+                    Some(existing) if existing.task_id != link.task_id => {
+                        Err(TaskCostError::ConversationAlreadyLinked {
+                            conversation_id: link.conversation_id,
+                            task_id: existing.task_id,
+                        })
+                    }
+                    _ => Ok(()),
+                }
+            },
+            with_tenant_tx,
+        )
+        .await
+    }
+
+    async fn find_link(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> TaskCostResult<Option<TaskConversationLink>> {
+        let tenant_id = ctx.tenant_id();
+        self.run(
+            tenant_id,
+            move |conn| load_link(conn, tenant_id, conversation_id),
+            with_tenant_read_tx,
+        )
+        .await
+    }
+
+    async fn task_cost_report(
+        &self,
+        ctx: &RequestContext,
+        task_id: TaskId,
+    ) -> TaskCostResult<TaskCostReport> {
+        let tenant_id = ctx.tenant_id();
+        let rows = self
+            .run(
+                tenant_id,
+                move |conn| {
+                    diesel::sql_query(TASK_COST_SQL)
+                        .bind::<SqlUuid, _>(tenant_id.into_inner())
+                        .bind::<SqlUuid, _>(task_id.into_inner())
+                        .load::<ConversationCostRow>(conn)
+                        .map_err(TaskCostError::persistence)
+                },
+                with_tenant_read_tx,
+            )
+            .await?;
+
+        let conversations = rows
+            .iter()
+            .map(row_to_conversation_cost)
+            .collect::<TaskCostResult<Vec<_>>>()?;
+        Ok(TaskCostReport::new(task_id, conversations))
+    }
+
+    async fn backend_usage(
+        &self,
+        ctx: &RequestContext,
+        agent_backend: &str,
+        conversation_ids: &[ConversationId],
+    ) -> TaskCostResult<BackendUsage> {
+        let tenant_id = ctx.tenant_id();
+        let backend = agent_backend.to_owned();
+        let ids = conversation_ids
+            .iter()
+            .map(|id| id.into_inner())
+            .collect::<Vec<_>>();
+        let row = self
+            .run(
+                tenant_id,
+                move |conn| {
+                    diesel::sql_query(BACKEND_USAGE_SQL)
+                        .bind::<SqlUuid, _>(tenant_id.into_inner())
+                        .bind::<Varchar, _>(backend)
+                        .bind::<Array<SqlUuid>, _>(ids)
+                        .get_result::<BackendUsageRow>(conn)
+                        .map_err(TaskCostError::persistence)
+                },
+                with_tenant_read_tx,
+            )
+            .await?;
+        row_to_backend_usage(&row)
+    }
+
+    async fn conversation_usage(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> TaskCostResult<BackendUsage> {
+        let tenant_id = ctx.tenant_id();
+        let row = self
+            .run(
+                tenant_id,
+                move |conn| {
+                    diesel::sql_query(CONVERSATION_USAGE_SQL)
+                        .bind::<SqlUuid, _>(tenant_id.into_inner())
+                        .bind::<SqlUuid, _>(conversation_id.into_inner())
+                        .get_result::<BackendUsageRow>(conn)
+                        .map_err(TaskCostError::persistence)
+                },
+                with_tenant_read_tx,
+            )
+            .await?;
+        row_to_backend_usage(&row)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Conversion helpers
+// ---------------------------------------------------------------------------
+
+fn load_link(
+    conn: &mut PgConnection,
+    tenant_id: TenantId,
+    conversation_id: ConversationId,
+) -> TaskCostResult<Option<TaskConversationLink>> {
+    task_conversation_links::table
+        .filter(task_conversation_links::tenant_id.eq(tenant_id.into_inner()))
+        .filter(task_conversation_links::conversation_id.eq(conversation_id.into_inner()))
+        .select(TaskConversationLinkRow::as_select())
+        .first::<TaskConversationLinkRow>(conn)
+        .optional()
+        .map_err(TaskCostError::persistence)?
+        .map(|row| {
+            to_link(
+                row.task_id,
+                row.conversation_id,
+                &row.link_kind,
+                row.parent_conversation_id,
+            )
+        })
+        .transpose()
+}
+
+fn to_link(
+    task_id: uuid::Uuid,
+    conversation_id: uuid::Uuid,
+    link_kind: &str,
+    parent_conversation_id: Option<uuid::Uuid>,
+) -> TaskCostResult<TaskConversationLink> {
+    Ok(TaskConversationLink {
+        task_id: TaskId::from_uuid(task_id),
+        conversation_id: ConversationId::from_uuid(conversation_id),
+        kind: ConversationLinkKind::try_from(link_kind).map_err(TaskCostError::persistence)?,
+        parent_conversation_id: parent_conversation_id.map(ConversationId::from_uuid),
+    })
+}
+
+fn to_link_row(link: &TaskConversationLink, tenant_id: TenantId) -> TaskConversationLinkRow {
+    TaskConversationLinkRow {
+        tenant_id: tenant_id.into_inner(),
+        conversation_id: link.conversation_id.into_inner(),
+        task_id: link.task_id.into_inner(),
+        link_kind: link.kind.as_str().to_owned(),
+        parent_conversation_id: link.parent_conversation_id.map(ConversationId::into_inner),
+    }
+}
+
+fn to_new_usage_row(
+    record: &UsageRecord,
+    tenant_id: TenantId,
+) -> TaskCostResult<NewUsageRecordRow> {
+    let to_i64 = |value: u64| i64::try_from(value).map_err(TaskCostError::persistence);
+    Ok(NewUsageRecordRow {
+        id: record.id.into_inner(),
+        tenant_id: tenant_id.into_inner(),
+        conversation_id: record.conversation_id.into_inner(),
+        turn_id: record.turn_id.into_inner(),
+        agent_backend: record.agent_backend.clone(),
+        input_tokens: to_i64(record.usage.input_tokens)?,
+        output_tokens: to_i64(record.usage.output_tokens)?,
+        cost_micros: to_i64(record.cost.value())?,
+        recorded_at: record.recorded_at,
+    })
+}
+
+fn row_to_backend_usage(row: &BackendUsageRow) -> TaskCostResult<BackendUsage> {
+    let to_u64 = |value: i64| u64::try_from(value).map_err(TaskCostError::persistence);
+    Ok(BackendUsage {
+        turn_count: to_u64(row.turn_count)?,
+        usage: TokenUsage::new(to_u64(row.input_tokens)?, to_u64(row.output_tokens)?),
+        cost: CostMicros::new(to_u64(row.cost_micros)?),
+    })
+}
+
+fn row_to_conversation_cost(row: &ConversationCostRow) -> TaskCostResult<ConversationCost> {
+    let to_u64 = |value: i64| u64::try_from(value).map_err(TaskCostError::persistence);
+    Ok(ConversationCost {
+        link: to_link(
+            row.task_id,
+            row.conversation_id,
+            &row.link_kind,
+            row.parent_conversation_id,
+        )?,
+        turn_count: to_u64(row.turn_count)?,
+        usage: TokenUsage::new(to_u64(row.input_tokens)?, to_u64(row.output_tokens)?),
+        cost: CostMicros::new(to_u64(row.cost_micros)?),
+    })
+}
@@ -0,0 +1,230 @@
+//! `PostgreSQL` queue of issue status updates awaiting delivery.
+//!
+//! Updates are keyed on `(tenant_id, task_id)`; enqueueing upserts, so a
+//! newer update replaces the one it supersedes. Rescheduling and removal
+//! also match the reported state, leaving a newer update queued when an
+//! older delivery finishes.
+
+use super::{models::IssueStatusNotificationRow, schema::issue_status_notifications};
+use crate::context::{RequestContext, TenantId};
+use crate::message::adapters::postgres::blocking_helpers::{
+    PgPool, get_conn_with, run_blocking_with,
+};
+use crate::postgres_support::{FromTxError, TxError, with_tenant_read_tx, with_tenant_tx};
+use crate::task::{
+    domain::{IssueRef, IssueStatusUpdate, PendingIssueNotification, TaskId, TaskState},
+    ports::{IssueNotificationQueue, IssueNotificationQueueError, IssueNotificationQueueResult},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+
+impl FromTxError<Self> for IssueNotificationQueueError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed issue notification queue.
+#[derive(Debug, Clone)]
+pub struct PostgresIssueNotificationQueue {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(
+    PostgresIssueNotificationQueue,
+    "issue_notification_queue"
+);
+
+impl PostgresIssueNotificationQueue {
+    /// Creates a new queue from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IssueNotificationQueue for PostgresIssueNotificationQueue {
+    async fn enqueue(
+        &self,
+        ctx: &RequestContext,
+        pending: &PendingIssueNotification,
+    ) -> IssueNotificationQueueResult<()> {
+        let pool = self.pool.clone();
+        let tenant_id = ctx.tenant_id();
+        let row = to_row(pending, tenant_id)?;
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, IssueNotificationQueueError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_id.into_inner(), |tx| {
+                    diesel::insert_into(issue_status_notifications::table)
+                        .values(&row)
+                        .on_conflict((
+                            issue_status_notifications::tenant_id,
+                            issue_status_notifications::task_id,
+                        ))
+                        .do_update()
+                        .set((
+                            issue_status_notifications::provider
+                                .eq(excluded(issue_status_notifications::provider)),
+                            issue_status_notifications::repository
+                                .eq(excluded(issue_status_notifications::repository)),
+                            issue_status_notifications::issue_number
+                                .eq(excluded(issue_status_notifications::issue_number)),
+                            issue_status_notifications::state
+                                .eq(excluded(issue_status_notifications::state)),
+                            issue_status_notifications::attempts
+                                .eq(excluded(issue_status_notifications::attempts)),
+                            issue_status_notifications::next_attempt_at
+                                .eq(excluded(issue_status_notifications::next_attempt_at)),
+                            issue_status_notifications::last_error
+                                .eq(excluded(issue_status_notifications::last_error)),
+                        ))
+                        .execute(tx)
+                        .map(|_| ())
+                        .map_err(IssueNotificationQueueError::persistence)
+                })
+            },
+            IssueNotificationQueueError::persistence,
+        )
+        .await
+    }
+
+    async fn due(
+        &self,
+        ctx: &RequestContext,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> IssueNotificationQueueResult<Vec<PendingIssueNotification>> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row_limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let rows = run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, IssueNotificationQueueError::persistence)?;
+                with_tenant_read_tx(&mut conn, tenant_uuid, |tx| {
+                    issue_status_notifications::table
+                        .filter(issue_status_notifications::tenant_id.eq(tenant_uuid))
+                        .filter(issue_status_notifications::next_attempt_at.le(now))
+                        .order((
+                            issue_status_notifications::next_attempt_at.asc(),
+                            issue_status_notifications::task_id.asc(),
+                        ))
+                        .limit(row_limit)
+                        .select(IssueStatusNotificationRow::as_select())
+                        .load::<IssueStatusNotificationRow>(tx)
+                        .map_err(IssueNotificationQueueError::persistence)
+                })
+            },
+            IssueNotificationQueueError::persistence,
+        )
+        .await?;
+        rows.into_iter().map(row_to_pending).collect()
+    }
+
+    async fn reschedule(
+        &self,
+        ctx: &RequestContext,
+        pending: &PendingIssueNotification,
+    ) -> IssueNotificationQueueResult<()> {
+        let pool = self.pool.clone();
+        let tenant_id = ctx.tenant_id();
+        let row = to_row(pending, tenant_id)?;
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, IssueNotificationQueueError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_id.into_inner(), |tx| {
+                    diesel::update(
+                        issue_status_notifications::table
+                            .filter(issue_status_notifications::tenant_id.eq(row.tenant_id))
+                            .filter(issue_status_notifications::task_id.eq(row.task_id))
+                            .filter(issue_status_notifications::state.eq(&row.state)),
+                    )
+                    .set((
+                        issue_status_notifications::attempts.eq(row.attempts),
+                        issue_status_notifications::next_attempt_at.eq(row.next_attempt_at),
+                        issue_status_notifications::last_error.eq(&row.last_error),
+                    ))
+                    .execute(tx)
+                    .map(|_| ())
+                    .map_err(IssueNotificationQueueError::persistence)
+                })
+            },
+            IssueNotificationQueueError::persistence,
+        )
+        .await
+    }
+
+    async fn remove(
+        &self,
+        ctx: &RequestContext,
+        update: &IssueStatusUpdate,
+    ) -> IssueNotificationQueueResult<()> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let task_uuid = update.task_id().into_inner();
+        let state = update.state().as_str();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, IssueNotificationQueueError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    diesel::delete(
+                        issue_status_notifications::table
+                            .filter(issue_status_notifications::tenant_id.eq(tenant_uuid))
+                            .filter(issue_status_notifications::task_id.eq(task_uuid))
+                            .filter(issue_status_notifications::state.eq(state)),
+                    )
+                    .execute(tx)
+                    .map(|_| ())
+                    .map_err(IssueNotificationQueueError::persistence)
+                })
+            },
+            IssueNotificationQueueError::persistence,
+        )
+        .await
+    }
+}
+
+fn to_row(
+    pending: &PendingIssueNotification,
+    tenant_id: TenantId,
+) -> IssueNotificationQueueResult<IssueStatusNotificationRow> {
+    let update = pending.update();
+    let issue_ref = update.issue_ref();
+    Ok(IssueStatusNotificationRow {
+        tenant_id: tenant_id.into_inner(),
+        task_id: update.task_id().into_inner(),
+        provider: issue_ref.provider().as_str().to_owned(),
+        repository: issue_ref.repository().as_str().to_owned(),
+        issue_number: i64::try_from(issue_ref.issue_number().value())
+            .map_err(IssueNotificationQueueError::persistence)?,
+        state: update.state().as_str().to_owned(),
+        attempts: i32::try_from(pending.attempts()).unwrap_or(i32::MAX),
+        next_attempt_at: pending.next_attempt_at(),
+        last_error: pending.last_error().map(str::to_owned),
+    })
+}
+
+fn row_to_pending(
+    row: IssueStatusNotificationRow,
+) -> IssueNotificationQueueResult<PendingIssueNotification> {
+    let issue_number =
+        u64::try_from(row.issue_number).map_err(IssueNotificationQueueError::persistence)?;
+    let issue_ref = IssueRef::from_parts(&row.provider, &row.repository, issue_number)
+        .map_err(IssueNotificationQueueError::persistence)?;
+    let state = TaskState::try_from(row.state.as_str())
+        .map_err(IssueNotificationQueueError::persistence)?;
+    let attempts = u32::try_from(row.attempts).map_err(IssueNotificationQueueError::persistence)?;
+    let update = IssueStatusUpdate::new(TaskId::from_uuid(row.task_id), issue_ref, state);
+    Ok(PendingIssueNotification::from_persisted(
+        update,
+        attempts,
+        row.next_attempt_at,
+        row.last_error,
+    ))
+}
@@ -1,7 +1,17 @@
 //! `PostgreSQL` adapters for task lifecycle persistence.
 
+mod budget;
+mod conversion;
+mod cost;
+mod issue_notification;
 mod models;
+mod queue;
 mod repository;
-mod schema;
+pub(crate) mod schema;
+mod webhook_delivery;
 
+pub use budget::PostgresUsageBudgetRepository;
+pub use cost::PostgresTaskCostLedger;
+pub use issue_notification::PostgresIssueNotificationQueue;
 pub use repository::{PostgresTaskRepository, TaskPgPool};
+pub use webhook_delivery::PostgresWebhookDeliveryLog;
@@ -1,6 +1,9 @@
 //! Diesel row models for task persistence.
 
-use super::schema::tasks;
+use super::schema::{
+    conversation_budgets, issue_status_notifications, task_conversation_links, tasks,
+    usage_records, webhook_deliveries,
+};
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use serde_json::Value;
@@ -37,6 +40,15 @@ pub struct TaskRow {
     /// Last update timestamp.
     #[diesel(sql_type = diesel::sql_types::Timestamptz)]
     pub updated_at: DateTime<Utc>,
+    /// Claim priority rank.
+    #[diesel(sql_type = diesel::sql_types::Int2)]
+    pub priority: i16,
+    /// Optional due date.
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Timestamptz>)]
+    pub due_at: Option<DateTime<Utc>>,
+    /// Capabilities a worker needs to claim the task.
+    #[diesel(sql_type = diesel::sql_types::Array<diesel::sql_types::Text>)]
+    pub required_capabilities: Vec<String>,
 }
 
 /// Insert model for task records.
@@ -61,4 +73,120 @@ pub struct NewTaskRow {
     pub created_at: DateTime<Utc>,
     /// Last update timestamp.
     pub updated_at: DateTime<Utc>,
+    /// Claim priority rank.
+    pub priority: i16,
+    /// Optional due date.
+    pub due_at: Option<DateTime<Utc>>,
+    /// Capabilities a worker needs to claim the task.
+    pub required_capabilities: Vec<String>,
+}
+
+/// Insert model for usage records.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = usage_records)]
+pub struct NewUsageRecordRow {
+    /// Usage record identifier.
+    pub id: uuid::Uuid,
+    /// Owning tenant identifier.
+    pub tenant_id: uuid::Uuid,
+    /// Conversation the turn belongs to.
+    pub conversation_id: uuid::Uuid,
+    /// Turn that consumed the tokens.
+    pub turn_id: uuid::Uuid,
+    /// Agent backend that executed the turn.
+    pub agent_backend: String,
+    /// Prompt tokens.
+    pub input_tokens: i64,
+    /// Completion tokens.
+    pub output_tokens: i64,
+    /// Cost in micro-dollars.
+    pub cost_micros: i64,
+    /// When the usage was recorded.
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Query and insert model for conversation-to-task links.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = task_conversation_links)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TaskConversationLinkRow {
+    /// Owning tenant identifier.
+    pub tenant_id: uuid::Uuid,
+    /// Attributed conversation identifier.
+    pub conversation_id: uuid::Uuid,
+    /// Task the conversation serves.
+    pub task_id: uuid::Uuid,
+    /// Link kind.
+    pub link_kind: String,
+    /// Conversation this one was spawned from.
+    pub parent_conversation_id: Option<uuid::Uuid>,
+}
+
+/// Query and insert model for conversation budgets.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = conversation_budgets)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ConversationBudgetRow {
+    /// Owning tenant identifier.
+    pub tenant_id: uuid::Uuid,
+    /// Budgeted conversation identifier.
+    pub conversation_id: uuid::Uuid,
+    /// Conversation the budget was delegated from.
+    pub parent_conversation_id: Option<uuid::Uuid>,
+    /// Token limit.
+    pub limit_tokens: i64,
+    /// Cost limit in micro-dollars.
+    pub limit_cost_micros: i64,
+    /// Tokens reserved for unreconciled children.
+    pub reserved_tokens: i64,
+    /// Cost reserved for unreconciled children.
+    pub reserved_cost_micros: i64,
+    /// Tokens charged back from reconciled children.
+    pub absorbed_tokens: i64,
+    /// Cost charged back from reconciled children.
+    pub absorbed_cost_micros: i64,
+    /// When the budget was allocated.
+    pub created_at: DateTime<Utc>,
+    /// When the budget was reconciled into its parent.
+    pub reconciled_at: Option<DateTime<Utc>>,
+}
+
+/// Insert model for claimed webhook deliveries.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = webhook_deliveries)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookDeliveryRow {
+    /// Owning tenant identifier.
+    pub tenant_id: uuid::Uuid,
+    /// Issue provider that sent the delivery.
+    pub provider: String,
+    /// Provider-assigned delivery identifier.
+    pub delivery_id: String,
+    /// When the delivery was claimed.
+    pub received_at: DateTime<Utc>,
+}
+
+/// Query and insert model for queued issue status updates.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = issue_status_notifications)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IssueStatusNotificationRow {
+    /// Owning tenant identifier.
+    pub tenant_id: uuid::Uuid,
+    /// Task whose state is reported.
+    pub task_id: uuid::Uuid,
+    /// Issue provider of the originating issue.
+    pub provider: String,
+    /// Repository, or Jira site and project, of the issue.
+    pub repository: String,
+    /// Issue number.
+    pub issue_number: i64,
+    /// Reported task state.
+    pub state: String,
+    /// Failed delivery attempts.
+    pub attempts: i32,
+    /// When delivery is next due.
+    pub next_attempt_at: DateTime<Utc>,
+    /// Error of the last failed attempt.
+    pub last_error: Option<String>,
 }
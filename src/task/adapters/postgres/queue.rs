@@ -0,0 +1,99 @@
+//! `PostgreSQL` implementation of the `TaskQueue` port.
+//!
+//! The claim selects the first claimable draft with `FOR UPDATE SKIP
+//! LOCKED` and starts it in the same transaction, so concurrent workers
+//! pass over rows another claim has locked instead of waiting on them or
+//! claiming them twice.
+
+use super::{
+    conversion::row_to_task, models::TaskRow, repository::PostgresTaskRepository, schema::tasks,
+};
+use crate::context::RequestContext;
+use crate::message::adapters::postgres::blocking_helpers::{get_conn_with, run_blocking_with};
+use crate::postgres_support::{FromTxError, TxError, with_tenant_tx};
+use crate::task::{
+    domain::{Task, TaskState},
+    ports::{TaskQueue, TaskQueueError, TaskQueueResult},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+
+impl FromTxError<Self> for TaskQueueError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+#[async_trait]
+impl TaskQueue for PostgresTaskRepository {
+    async fn claim_next(
+        &self,
+        ctx: &RequestContext,
+        worker_capabilities: &[String],
+        claimed_at: DateTime<Utc>,
+    ) -> TaskQueueResult<Option<Task>> {
+        let pool = self.pool().clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let capabilities = worker_capabilities.to_vec();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, TaskQueueError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    claim_in_tx(tx, tenant_uuid, &capabilities, claimed_at)
+                })
+            },
+            TaskQueueError::persistence,
+        )
+        .await
+    }
+}
+
+/// Locks the next claimable draft, skipping rows other claims hold, and
+/// starts it.
+fn claim_in_tx(
+    tx: &mut PgConnection,
+    tenant_uuid: uuid::Uuid,
+    capabilities: &[String],
+    claimed_at: DateTime<Utc>,
+) -> TaskQueueResult<Option<Task>> {
+    let row = tasks::table
+        .filter(tasks::tenant_id.eq(tenant_uuid))
+        .filter(tasks::state.eq(TaskState::Draft.as_str()))
+        .filter(tasks::required_capabilities.is_contained_by(capabilities))
+        .order((
+            tasks::priority.desc(),
+            tasks::due_at.asc().nulls_last(),
+            tasks::created_at.asc(),
+            tasks::id.asc(),
+        ))
+        .select(TaskRow::as_select())
+        .for_update()
+        .skip_locked()
+        .first::<TaskRow>(tx)
+        .optional()
+        .map_err(TaskQueueError::persistence)?;
+    let Some(found) = row else {
+        return Ok(None);
+    };
+
+    let mut task = row_to_task(found).map_err(TaskQueueError::persistence)?;
+    task.claim(claimed_at)
+        .map_err(TaskQueueError::persistence)?;
+    diesel::update(
+        tasks::table
+            .filter(tasks::id.eq(task.id().into_inner()))
+            .filter(tasks::tenant_id.eq(tenant_uuid)),
+    )
+    .set((
+        tasks::state.eq(task.state().as_str()),
+        tasks::updated_at.eq(task.updated_at()),
+    ))
+    .execute(tx)
+    .map_err(TaskQueueError::persistence)?;
+    Ok(Some(task))
+}
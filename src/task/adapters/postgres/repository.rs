@@ -7,20 +7,20 @@
 //! policies on the `tasks` table, which land in milestone 1.5.3.
 
 use super::{
-    models::{NewTaskRow, TaskRow},
+    conversion::{row_to_task, to_new_row},
+    models::TaskRow,
     schema::tasks,
 };
 use crate::context::{RequestContext, TenantId};
 use crate::message::adapters::postgres::blocking_helpers::{
     PgPool, get_conn_with, run_blocking_with,
 };
+use crate::pagination::{Cursor, Page, PageRequest};
 use crate::postgres_support::{
-    FromTxError, TxError, ensure_tenant_exists, with_tenant_read_tx, with_tenant_tx,
+    FromTxError, TxError, ensure_tenant_exists, keyset_page, with_tenant_read_tx, with_tenant_tx,
 };
 use crate::task::{
-    domain::{
-        BranchRef, IssueRef, PersistedTaskData, PullRequestRef, Task, TaskId, TaskOrigin, TaskState,
-    },
+    domain::{BranchRef, IssueRef, PullRequestRef, Task, TaskId, TaskState},
     ports::{TaskRepository, TaskRepositoryError, TaskRepositoryResult},
 };
 use async_trait::async_trait;
@@ -78,12 +78,19 @@ pub struct PostgresTaskRepository {
     pool: TaskPgPool,
 }
 
+crate::postgres_support::pool_health_check!(PostgresTaskRepository, "task_repository");
+
 impl PostgresTaskRepository {
     /// Creates a new repository from a `PostgreSQL` connection pool.
     #[must_use]
     #[rustfmt::skip]
     pub const fn new(pool: TaskPgPool) -> Self { Self { pool } }
 
+    /// Returns the connection pool, for the queue adapter.
+    pub(super) const fn pool(&self) -> &TaskPgPool {
+        &self.pool
+    }
+
     /// Executes a write query inside a transaction with tenant context.
     async fn execute_query<F, T>(&self, tenant_id: TenantId, query_fn: F) -> TaskRepositoryResult<T>
     where
@@ -138,25 +145,42 @@ impl PostgresTaskRepository {
 }
 
 /// Generates a `find_by_*_ref` body that filters `tasks` by a nullable
-/// `VARCHAR` column and maps the resulting rows to domain `Task` values.
+/// `VARCHAR` column and maps one page of the resulting rows, oldest first,
+/// to domain `Task` values.
 macro_rules! find_tasks_by_ref_column {
-    ($self:expr, $tenant_id:expr, $ref_str:expr, $column:expr) => {{
+    ($self:expr, $tenant_id:expr, ($ref_str:expr, $page:expr), $column:expr) => {{
         let tenant_for_query = $tenant_id;
         let value = $ref_str;
+        let page = $page;
         $self
             .execute_read_query(tenant_for_query, move |conn| {
-                let rows = tasks::table
+                let query = tasks::table
                     .filter(tasks::tenant_id.eq(tenant_for_query.into_inner()))
-                    .filter($column.eq(&value))
-                    .select(TaskRow::as_select())
-                    .load::<TaskRow>(conn)
-                    .map_err(TaskRepositoryError::persistence)?;
-                rows.into_iter().map(row_to_task).collect()
+                    .filter($column.eq(value))
+                    .into_boxed();
+                load_task_page(conn, query, page)
             })
             .await
     }};
 }
 
+/// Loads one page of a boxed task query ordered by creation time.
+fn load_task_page(
+    conn: &mut PgConnection,
+    query: tasks::BoxedQuery<'static, diesel::pg::Pg>,
+    page: PageRequest,
+) -> TaskRepositoryResult<Page<Task>> {
+    let paged = keyset_page!(query, page, after_timestamp, (tasks::created_at, tasks::id));
+    let rows = paged
+        .select(TaskRow::as_select())
+        .load::<TaskRow>(conn)
+        .map_err(TaskRepositoryError::persistence)?;
+    Page::from_overfetched(rows, page, |row| {
+        Cursor::at_timestamp(row.created_at, row.id)
+    })
+    .try_map(row_to_task)
+}
+
 #[async_trait]
 impl TaskRepository for PostgresTaskRepository {
     async fn store(&self, ctx: &RequestContext, task: &Task) -> TaskRepositoryResult<()> {
@@ -200,6 +224,7 @@ impl TaskRepository for PostgresTaskRepository {
         let pr_val = task.pull_request_ref().map(ToString::to_string);
         let state_val = task.state().as_str().to_owned();
         let updated_val = task.updated_at();
+        let schedule = task.schedule().clone();
 
         self.execute_query(tenant_id, move |conn| {
             let updated_count = diesel::update(
@@ -212,6 +237,9 @@ impl TaskRepository for PostgresTaskRepository {
                 tasks::pull_request_ref.eq(&pr_val),
                 tasks::state.eq(&state_val),
                 tasks::updated_at.eq(updated_val),
+                tasks::priority.eq(schedule.priority().rank()),
+                tasks::due_at.eq(schedule.due_at()),
+                tasks::required_capabilities.eq(schedule.required_capabilities()),
             ))
             .execute(conn)
             .map_err(TaskRepositoryError::persistence)?;
@@ -263,86 +291,46 @@ impl TaskRepository for PostgresTaskRepository {
         &self,
         ctx: &RequestContext,
         branch_ref: &BranchRef,
-    ) -> TaskRepositoryResult<Vec<Task>> {
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>> {
         let tenant_id = ctx.tenant_id();
         let ref_str = branch_ref.to_string();
-        find_tasks_by_ref_column!(self, tenant_id, ref_str, tasks::branch_ref)
+        find_tasks_by_ref_column!(self, tenant_id, (ref_str, page), tasks::branch_ref)
     }
 
     async fn find_by_pull_request_ref(
         &self,
         ctx: &RequestContext,
         pr_ref: &PullRequestRef,
-    ) -> TaskRepositoryResult<Vec<Task>> {
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>> {
         let tenant_id = ctx.tenant_id();
         let ref_str = pr_ref.to_string();
-        find_tasks_by_ref_column!(self, tenant_id, ref_str, tasks::pull_request_ref)
+        find_tasks_by_ref_column!(self, tenant_id, (ref_str, page), tasks::pull_request_ref)
     }
-}
-
-// ---------------------------------------------------------------------------
-// Conversion helpers
-// ---------------------------------------------------------------------------
-
-fn to_new_row(task: &Task, tenant_id: TenantId) -> TaskRepositoryResult<NewTaskRow> {
-    let origin = serde_json::to_value(task.origin()).map_err(TaskRepositoryError::persistence)?;
-
-    Ok(NewTaskRow {
-        id: task.id().into_inner(),
-        tenant_id: tenant_id.into_inner(),
-        origin,
-        branch_ref: task.branch_ref().map(ToString::to_string),
-        pull_request_ref: task.pull_request_ref().map(ToString::to_string),
-        state: task.state().as_str().to_owned(),
-        workspace_id: None,
-        created_at: task.created_at(),
-        updated_at: task.updated_at(),
-    })
-}
-
-fn row_to_task(row: TaskRow) -> TaskRepositoryResult<Task> {
-    let TaskRow {
-        id,
-        tenant_id: _tenant_id,
-        origin: persisted_origin,
-        branch_ref,
-        pull_request_ref,
-        state: persisted_state,
-        workspace_id,
-        created_at,
-        updated_at,
-    } = row;
-
-    // workspace_id is still deferred to roadmap item 1.2.3.
-    debug_assert!(
-        workspace_id.is_none(),
-        "workspace column should remain unset until roadmap item 1.2.3"
-    );
-
-    let origin = serde_json::from_value::<TaskOrigin>(persisted_origin)
-        .map_err(TaskRepositoryError::persistence)?;
-    let state =
-        TaskState::try_from(persisted_state.as_str()).map_err(TaskRepositoryError::persistence)?;
 
-    let parsed_branch = branch_ref
-        .map(|s| BranchRef::parse_canonical(&s))
-        .transpose()
-        .map_err(TaskRepositoryError::persistence)?;
-    let parsed_pr = pull_request_ref
-        .map(|s| PullRequestRef::parse_canonical(&s))
-        .transpose()
-        .map_err(TaskRepositoryError::persistence)?;
+    async fn find_reconcilable(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>> {
+        let tenant_id = ctx.tenant_id();
+        let terminal_states = [TaskState::Done.as_str(), TaskState::Abandoned.as_str()];
 
-    let data = PersistedTaskData {
-        id: TaskId::from_uuid(id),
-        origin,
-        branch_ref: parsed_branch,
-        pull_request_ref: parsed_pr,
-        state,
-        created_at,
-        updated_at,
-    };
-    Ok(Task::from_persisted(data))
+        self.execute_read_query(tenant_id, move |conn| {
+            let query = tasks::table
+                .filter(tasks::tenant_id.eq(tenant_id.into_inner()))
+                .filter(diesel::dsl::not(tasks::state.eq_any(terminal_states)))
+                .filter(
+                    tasks::branch_ref
+                        .is_not_null()
+                        .or(tasks::pull_request_ref.is_not_null()),
+                )
+                .into_boxed();
+            load_task_page(conn, query, page)
+        })
+        .await
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -363,7 +351,7 @@ fn find_task_by_issue_ref(
         .map_err(TaskRepositoryError::persistence)?;
     let query = diesel::sql_query(concat!(
         "SELECT id, tenant_id, origin, branch_ref, pull_request_ref, state, workspace_id, ",
-        "created_at, updated_at FROM tasks ",
+        "created_at, updated_at, priority, due_at, required_capabilities FROM tasks ",
         "WHERE origin->>'type' = 'issue' ",
         "AND tenant_id = $1 ",
         "AND origin->'issue_ref'->>'provider' = $2 ",
@@ -24,5 +24,126 @@ diesel::table! {
         created_at -> Timestamptz,
         /// Last update timestamp.
         updated_at -> Timestamptz,
+        /// Claim priority rank; higher ranks are claimed first.
+        priority -> Int2,
+        /// Optional date the task is due by.
+        due_at -> Nullable<Timestamptz>,
+        /// Capabilities a worker needs to claim the task.
+        required_capabilities -> Array<Text>,
+    }
+}
+
+diesel::table! {
+    /// Attribution of conversations to the tasks they serve.
+    task_conversation_links (tenant_id, conversation_id) {
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Attributed conversation identifier.
+        conversation_id -> Uuid,
+        /// Task the conversation serves.
+        task_id -> Uuid,
+        /// Link kind: primary, handoff, or delegation.
+        #[max_length = 20]
+        link_kind -> Varchar,
+        /// Conversation this one was spawned from.
+        parent_conversation_id -> Nullable<Uuid>,
+        /// When the link was created.
+        linked_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// Token usage and cost recorded per agent turn.
+    usage_records (id) {
+        /// Usage record identifier.
+        id -> Uuid,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Conversation the turn belongs to.
+        conversation_id -> Uuid,
+        /// Turn that consumed the tokens.
+        turn_id -> Uuid,
+        /// Agent backend that executed the turn.
+        #[max_length = 100]
+        agent_backend -> Varchar,
+        /// Prompt tokens.
+        input_tokens -> Int8,
+        /// Completion tokens.
+        output_tokens -> Int8,
+        /// Cost in micro-dollars.
+        cost_micros -> Int8,
+        /// When the usage was recorded.
+        recorded_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// Token and cost budgets for conversations.
+    conversation_budgets (tenant_id, conversation_id) {
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Budgeted conversation identifier.
+        conversation_id -> Uuid,
+        /// Conversation the budget was delegated from.
+        parent_conversation_id -> Nullable<Uuid>,
+        /// Token limit.
+        limit_tokens -> Int8,
+        /// Cost limit in micro-dollars.
+        limit_cost_micros -> Int8,
+        /// Tokens reserved for unreconciled children.
+        reserved_tokens -> Int8,
+        /// Cost reserved for unreconciled children.
+        reserved_cost_micros -> Int8,
+        /// Tokens charged back from reconciled children.
+        absorbed_tokens -> Int8,
+        /// Cost charged back from reconciled children.
+        absorbed_cost_micros -> Int8,
+        /// When the budget was allocated.
+        created_at -> Timestamptz,
+        /// When the budget was reconciled into its parent.
+        reconciled_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    /// Webhook deliveries claimed for processing.
+    webhook_deliveries (tenant_id, provider, delivery_id) {
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Issue provider that sent the delivery.
+        #[max_length = 20]
+        provider -> Varchar,
+        /// Provider-assigned delivery identifier.
+        #[max_length = 255]
+        delivery_id -> Varchar,
+        /// When the delivery was claimed.
+        received_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// Task status updates awaiting delivery to their originating issues.
+    issue_status_notifications (tenant_id, task_id) {
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Task whose state is reported.
+        task_id -> Uuid,
+        /// Issue provider of the originating issue.
+        #[max_length = 20]
+        provider -> Varchar,
+        /// Repository, or Jira site and project, of the issue.
+        #[max_length = 255]
+        repository -> Varchar,
+        /// Issue number.
+        issue_number -> Int8,
+        /// Reported task state.
+        #[max_length = 50]
+        state -> Varchar,
+        /// Failed delivery attempts.
+        attempts -> Int4,
+        /// When delivery is next due.
+        next_attempt_at -> Timestamptz,
+        /// Error of the last failed attempt.
+        last_error -> Nullable<Text>,
     }
 }
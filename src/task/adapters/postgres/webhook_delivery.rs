@@ -0,0 +1,109 @@
+//! `PostgreSQL` log of claimed webhook deliveries.
+//!
+//! A claim inserts a row keyed on `(tenant_id, provider, delivery_id)`; a
+//! conflicting insert affects no rows, so exactly one concurrent claim of a
+//! delivery succeeds.
+
+use super::{models::WebhookDeliveryRow, schema::webhook_deliveries};
+use crate::context::RequestContext;
+use crate::message::adapters::postgres::blocking_helpers::{
+    PgPool, get_conn_with, run_blocking_with,
+};
+use crate::postgres_support::{FromTxError, TxError, ensure_tenant_exists, with_tenant_tx};
+use crate::task::{
+    domain::WebhookDelivery,
+    ports::{WebhookDeliveryError, WebhookDeliveryLog, WebhookDeliveryResult},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+impl FromTxError<Self> for WebhookDeliveryError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::persistence(e),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed webhook delivery log.
+#[derive(Debug, Clone)]
+pub struct PostgresWebhookDeliveryLog {
+    pool: PgPool,
+}
+
+crate::postgres_support::pool_health_check!(PostgresWebhookDeliveryLog, "webhook_delivery_log");
+
+impl PostgresWebhookDeliveryLog {
+    /// Creates a new log from a `PostgreSQL` connection pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryLog for PostgresWebhookDeliveryLog {
+    async fn claim(
+        &self,
+        ctx: &RequestContext,
+        delivery: &WebhookDelivery,
+        received_at: DateTime<Utc>,
+    ) -> WebhookDeliveryResult<bool> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = WebhookDeliveryRow {
+            tenant_id: tenant_uuid,
+            provider: delivery.provider().as_str().to_owned(),
+            delivery_id: delivery.delivery_id().to_owned(),
+            received_at,
+        };
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, WebhookDeliveryError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    ensure_tenant_exists(tx, tenant_uuid)
+                        .map_err(WebhookDeliveryError::persistence)?;
+                    let inserted = diesel::insert_into(webhook_deliveries::table)
+                        .values(&row)
+                        .on_conflict_do_nothing()
+                        .execute(tx)
+                        .map_err(WebhookDeliveryError::persistence)?;
+                    Ok(inserted == 1)
+                })
+            },
+            WebhookDeliveryError::persistence,
+        )
+        .await
+    }
+
+    async fn release(
+        &self,
+        ctx: &RequestContext,
+        delivery: &WebhookDelivery,
+    ) -> WebhookDeliveryResult<()> {
+        let pool = self.pool.clone();
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let provider = delivery.provider().as_str();
+        let delivery_id = delivery.delivery_id().to_owned();
+        run_blocking_with(
+            move || {
+                let mut conn = get_conn_with(&pool, WebhookDeliveryError::persistence)?;
+                with_tenant_tx(&mut conn, tenant_uuid, |tx| {
+                    diesel::delete(
+                        webhook_deliveries::table
+                            .filter(webhook_deliveries::tenant_id.eq(tenant_uuid))
+                            .filter(webhook_deliveries::provider.eq(provider))
+                            .filter(webhook_deliveries::delivery_id.eq(delivery_id)),
+                    )
+                    .execute(tx)
+                    .map(|_| ())
+                    .map_err(WebhookDeliveryError::persistence)
+                })
+            },
+            WebhookDeliveryError::persistence,
+        )
+        .await
+    }
+}
@@ -0,0 +1,299 @@
+//! Token and cost budgets for conversations and their delegated children.
+//!
+//! A [`ConversationBudget`] caps the tokens and cost a conversation may
+//! consume. When a conversation delegates a sub-task to a child
+//! conversation, the child receives a slice of the parent's remaining budget,
+//! which the parent holds in reserve until the child completes. Reconciling
+//! the child releases the reservation and charges the parent with what the
+//! child actually used.
+//!
+//! Consumption is not stored on the budget. It is derived from the usage
+//! ledger whenever a [`BudgetStatus`] is computed, so recording a turn never
+//! has to touch a budget.
+
+use super::{BackendUsage, CostMicros, TaskDomainError};
+use crate::message::domain::ConversationId;
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+
+/// An amount of tokens and cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct UsageAllowance {
+    /// Input plus output tokens.
+    pub tokens: u64,
+    /// Cost in micro-dollars.
+    pub cost: CostMicros,
+}
+
+impl UsageAllowance {
+    /// Creates an allowance.
+    #[must_use]
+    pub const fn new(tokens: u64, cost: CostMicros) -> Self {
+        Self { tokens, cost }
+    }
+
+    /// Returns the allowance consumed by rolled-up usage.
+    #[must_use]
+    pub const fn consumed_by(usage: &BackendUsage) -> Self {
+        Self {
+            tokens: usage.usage.total(),
+            cost: usage.cost,
+        }
+    }
+
+    /// Returns the saturating sum of two allowances.
+    #[must_use]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self {
+            tokens: self.tokens.saturating_add(other.tokens),
+            cost: self.cost.saturating_add(other.cost),
+        }
+    }
+
+    /// Returns the difference of two allowances, floored at zero.
+    #[must_use]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            tokens: self.tokens.saturating_sub(other.tokens),
+            cost: self.cost.saturating_sub(other.cost),
+        }
+    }
+
+    /// Returns whether this allowance is at least `other` in both tokens and
+    /// cost.
+    #[must_use]
+    pub const fn covers(&self, other: &Self) -> bool {
+        self.tokens >= other.tokens && self.cost.value() >= other.cost.value()
+    }
+
+    /// Returns whether either the tokens or the cost have run out.
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        self.tokens == 0 || self.cost.value() == 0
+    }
+}
+
+/// How much of a parent's remaining budget a delegated child receives.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::task::domain::{BudgetShare, CostMicros, UsageAllowance};
+///
+/// let remaining = UsageAllowance::new(80_000, CostMicros::new(2_000_000));
+/// let quarter = BudgetShare::percent(25).expect("valid share");
+/// assert_eq!(
+///     quarter.slice_of(remaining),
+///     UsageAllowance::new(20_000, CostMicros::new(500_000))
+/// );
+/// assert!(BudgetShare::percent(0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetShare(ShareKind);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShareKind {
+    Percent(u8),
+    Fixed(UsageAllowance),
+}
+
+impl BudgetShare {
+    /// A fraction of the parent's remaining budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskDomainError::InvalidBudgetShare`] unless `percent` lies
+    /// in `1..=100`.
+    pub const fn percent(percent: u8) -> Result<Self, TaskDomainError> {
+        if percent == 0 || percent > 100 {
+            return Err(TaskDomainError::InvalidBudgetShare(percent));
+        }
+        Ok(Self(ShareKind::Percent(percent)))
+    }
+
+    /// A fixed slice, which the parent's remaining budget must cover.
+    #[must_use]
+    pub const fn fixed(slice: UsageAllowance) -> Self {
+        Self(ShareKind::Fixed(slice))
+    }
+
+    /// Returns the slice this share takes from `remaining`.
+    #[must_use]
+    pub fn slice_of(self, remaining: UsageAllowance) -> UsageAllowance {
+        match self.0 {
+            ShareKind::Percent(percent) => UsageAllowance::new(
+                percent_of(remaining.tokens, percent),
+                CostMicros::new(percent_of(remaining.cost.value(), percent)),
+            ),
+            ShareKind::Fixed(slice) => slice,
+        }
+    }
+}
+
+fn percent_of(value: u64, percent: u8) -> u64 {
+    let scaled = u128::from(value)
+        .saturating_mul(u128::from(percent))
+        .div_euclid(100);
+    u64::try_from(scaled).unwrap_or(value)
+}
+
+/// A child conversation and the share of budget it should receive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetDelegation {
+    /// The delegated child conversation.
+    pub conversation_id: ConversationId,
+    /// The share of the parent's remaining budget it receives.
+    pub share: BudgetShare,
+}
+
+/// Token and cost budget for one conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationBudget {
+    /// The budgeted conversation.
+    pub conversation_id: ConversationId,
+    /// The conversation this budget was delegated from, if any.
+    pub parent_conversation_id: Option<ConversationId>,
+    /// The most the conversation may consume, including delegated work.
+    pub limit: UsageAllowance,
+    /// Slices held for delegated children that have not been reconciled.
+    pub reserved: UsageAllowance,
+    /// Usage charged back from reconciled children.
+    pub absorbed: UsageAllowance,
+    /// When the budget was allocated.
+    pub created_at: DateTime<Utc>,
+    /// When a delegated budget was reconciled into its parent.
+    pub reconciled_at: Option<DateTime<Utc>>,
+}
+
+impl ConversationBudget {
+    /// Allocates a top-level budget.
+    #[must_use]
+    pub fn new(
+        conversation_id: ConversationId,
+        limit: UsageAllowance,
+        clock: &(impl Clock + ?Sized),
+    ) -> Self {
+        Self {
+            conversation_id,
+            parent_conversation_id: None,
+            limit,
+            reserved: UsageAllowance::default(),
+            absorbed: UsageAllowance::default(),
+            created_at: clock.utc(),
+            reconciled_at: None,
+        }
+    }
+
+    /// Returns whether this delegated budget has been reconciled.
+    #[must_use]
+    pub const fn is_reconciled(&self) -> bool {
+        self.reconciled_at.is_some()
+    }
+
+    /// Computes the budget's position given the usage recorded by the
+    /// conversation itself.
+    #[must_use]
+    pub const fn status(&self, own_usage: &BackendUsage) -> BudgetStatus {
+        let consumed = UsageAllowance::consumed_by(own_usage).saturating_add(self.absorbed);
+        BudgetStatus {
+            conversation_id: self.conversation_id,
+            limit: self.limit,
+            consumed,
+            reserved: self.reserved,
+            remaining: self
+                .limit
+                .saturating_sub(consumed)
+                .saturating_sub(self.reserved),
+        }
+    }
+
+    /// Splits a slice of the remaining budget off to a delegated child and
+    /// holds it in reserve until the child is reconciled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskDomainError::InsufficientBudget`] when the slice is
+    /// empty or exceeds what remains.
+    pub fn delegate(
+        &mut self,
+        own_usage: &BackendUsage,
+        delegation: BudgetDelegation,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<Self, TaskDomainError> {
+        let remaining = self.status(own_usage).remaining;
+        let slice = delegation.share.slice_of(remaining);
+        if slice.is_exhausted() || !remaining.covers(&slice) {
+            return Err(TaskDomainError::InsufficientBudget(self.conversation_id));
+        }
+        self.reserved = self.reserved.saturating_add(slice);
+        Ok(Self {
+            parent_conversation_id: Some(self.conversation_id),
+            ..Self::new(delegation.conversation_id, slice, clock)
+        })
+    }
+
+    /// Releases a completed child's reservation and charges this budget with
+    /// what the child consumed.
+    ///
+    /// Reconciling an already reconciled child changes nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskDomainError::BudgetNotDelegatedFrom`] when `child` was
+    /// not delegated from this budget, or
+    /// [`TaskDomainError::OpenBudgetDelegations`] when the child still holds
+    /// reservations for children of its own.
+    pub fn reconcile(
+        &mut self,
+        child: &mut Self,
+        child_usage: &BackendUsage,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<(), TaskDomainError> {
+        if child.parent_conversation_id != Some(self.conversation_id) {
+            return Err(TaskDomainError::BudgetNotDelegatedFrom {
+                child: child.conversation_id,
+                parent: self.conversation_id,
+            });
+        }
+        if child.is_reconciled() {
+            return Ok(());
+        }
+        if child.reserved != UsageAllowance::default() {
+            return Err(TaskDomainError::OpenBudgetDelegations(
+                child.conversation_id,
+            ));
+        }
+        self.reserved = self.reserved.saturating_sub(child.limit);
+        self.absorbed = self
+            .absorbed
+            .saturating_add(child.status(child_usage).consumed);
+        child.reconciled_at = Some(clock.utc());
+        Ok(())
+    }
+}
+
+/// A budget's limit, consumption, and what is left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BudgetStatus {
+    /// The budgeted conversation.
+    pub conversation_id: ConversationId,
+    /// The budget's limit.
+    pub limit: UsageAllowance,
+    /// Usage recorded by the conversation plus usage charged back from
+    /// reconciled children. May exceed the limit, since a turn's usage is
+    /// only known once it completes.
+    pub consumed: UsageAllowance,
+    /// Slices held for delegated children that have not been reconciled.
+    pub reserved: UsageAllowance,
+    /// What the conversation may still spend itself or delegate.
+    pub remaining: UsageAllowance,
+}
+
+impl BudgetStatus {
+    /// Returns whether no further turns fit in the budget.
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        self.remaining.is_exhausted()
+    }
+}
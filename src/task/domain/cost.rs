@@ -0,0 +1,377 @@
+//! Turn-level usage records and their attribution to tasks.
+//!
+//! Each agent turn may emit a [`UsageRecord`] describing the tokens it
+//! consumed and what they cost. Conversations are linked to the task they
+//! serve through [`TaskConversationLink`], including handoff and delegation
+//! sub-conversations, so that a [`TaskCostReport`] can roll up the total cost
+//! of an issue or pull request.
+
+use super::TaskId;
+use crate::message::domain::{ConversationId, TurnId};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+/// Unique identifier for a usage record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UsageRecordId(Uuid);
+
+impl UsageRecordId {
+    /// Creates a new random usage record identifier.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Creates a usage record identifier from an existing UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Returns the wrapped UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for UsageRecordId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for UsageRecordId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Token counts consumed by one or more turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TokenUsage {
+    /// Prompt tokens sent to the model.
+    pub input_tokens: u64,
+    /// Completion tokens produced by the model.
+    pub output_tokens: u64,
+}
+
+impl TokenUsage {
+    /// Creates a token usage value.
+    #[must_use]
+    pub const fn new(input_tokens: u64, output_tokens: u64) -> Self {
+        Self {
+            input_tokens,
+            output_tokens,
+        }
+    }
+
+    /// Returns input plus output tokens.
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.input_tokens.saturating_add(self.output_tokens)
+    }
+
+    /// Returns the saturating sum of two usage values.
+    #[must_use]
+    pub const fn combine(self, other: Self) -> Self {
+        Self {
+            input_tokens: self.input_tokens.saturating_add(other.input_tokens),
+            output_tokens: self.output_tokens.saturating_add(other.output_tokens),
+        }
+    }
+}
+
+/// Monetary cost in millionths of a US dollar.
+///
+/// Integer micro-dollars avoid floating-point drift when many small per-turn
+/// costs are summed.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::task::domain::CostMicros;
+///
+/// let cost = CostMicros::new(4_320_000);
+/// assert_eq!(cost.to_string(), "$4.32");
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct CostMicros(u64);
+
+impl CostMicros {
+    /// Creates a cost from a micro-dollar amount.
+    #[must_use]
+    pub const fn new(micros: u64) -> Self {
+        Self(micros)
+    }
+
+    /// Returns the micro-dollar amount.
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the saturating sum of two costs.
+    #[must_use]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Returns the difference of two costs, floored at zero.
+    #[must_use]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+}
+
+impl fmt::Display for CostMicros {
+    /// Formats the cost as dollars rounded to the nearest cent.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cents = self.0.saturating_add(5_000).div_euclid(10_000);
+        write!(f, "${}.{:02}", cents.div_euclid(100), cents.rem_euclid(100))
+    }
+}
+
+/// Parameters for creating a usage record.
+#[derive(Debug, Clone)]
+pub struct UsageRecordParams {
+    /// The conversation the turn belongs to.
+    pub conversation_id: ConversationId,
+    /// The turn that consumed the tokens.
+    pub turn_id: TurnId,
+    /// The agent backend that executed the turn.
+    pub agent_backend: String,
+    /// Tokens consumed by the turn.
+    pub usage: TokenUsage,
+    /// Cost of the turn.
+    pub cost: CostMicros,
+}
+
+/// Token usage and cost recorded for a single agent turn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageRecord {
+    /// Unique identifier for this record.
+    pub id: UsageRecordId,
+    /// The conversation the turn belongs to.
+    pub conversation_id: ConversationId,
+    /// The turn that consumed the tokens.
+    pub turn_id: TurnId,
+    /// The agent backend that executed the turn.
+    pub agent_backend: String,
+    /// Tokens consumed by the turn.
+    pub usage: TokenUsage,
+    /// Cost of the turn.
+    pub cost: CostMicros,
+    /// When the usage was recorded.
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl UsageRecord {
+    /// Creates a new usage record timestamped by `clock`.
+    #[must_use]
+    pub fn new(params: UsageRecordParams, clock: &impl Clock) -> Self {
+        Self {
+            id: UsageRecordId::new(),
+            conversation_id: params.conversation_id,
+            turn_id: params.turn_id,
+            agent_backend: params.agent_backend,
+            usage: params.usage,
+            cost: params.cost,
+            recorded_at: clock.utc(),
+        }
+    }
+}
+
+/// How a conversation relates to the task it serves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationLinkKind {
+    /// The conversation created to work on the task.
+    Primary,
+    /// A conversation continuing work after a handoff to another agent.
+    Handoff,
+    /// A sub-conversation delegated from another linked conversation.
+    Delegation,
+}
+
+impl ConversationLinkKind {
+    /// Returns the link kind as a string slice.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Handoff => "handoff",
+            Self::Delegation => "delegation",
+        }
+    }
+}
+
+impl fmt::Display for ConversationLinkKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an invalid conversation link kind.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unknown conversation link kind: {0}")]
+pub struct ParseConversationLinkKindError(pub String);
+
+impl TryFrom<&str> for ConversationLinkKind {
+    type Error = ParseConversationLinkKindError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "primary" => Ok(Self::Primary),
+            "handoff" => Ok(Self::Handoff),
+            "delegation" => Ok(Self::Delegation),
+            other => Err(ParseConversationLinkKindError(other.to_owned())),
+        }
+    }
+}
+
+/// Attribution of a conversation to the task it serves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskConversationLink {
+    /// The task the conversation serves.
+    pub task_id: TaskId,
+    /// The attributed conversation.
+    pub conversation_id: ConversationId,
+    /// How the conversation relates to the task.
+    pub kind: ConversationLinkKind,
+    /// The conversation this one was spawned from, for handoff and
+    /// delegation links.
+    pub parent_conversation_id: Option<ConversationId>,
+}
+
+impl TaskConversationLink {
+    /// Links the primary conversation for a task.
+    #[must_use]
+    pub const fn primary(task_id: TaskId, conversation_id: ConversationId) -> Self {
+        Self {
+            task_id,
+            conversation_id,
+            kind: ConversationLinkKind::Primary,
+            parent_conversation_id: None,
+        }
+    }
+
+    /// Links a sub-conversation spawned from `parent`, inheriting its task.
+    #[must_use]
+    pub const fn child_of(
+        parent: &Self,
+        conversation_id: ConversationId,
+        kind: ConversationLinkKind,
+    ) -> Self {
+        Self {
+            task_id: parent.task_id,
+            conversation_id,
+            kind,
+            parent_conversation_id: Some(parent.conversation_id),
+        }
+    }
+}
+
+/// Usage rolled up for one conversation attributed to a task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationCost {
+    /// The attributed conversation and how it relates to the task.
+    pub link: TaskConversationLink,
+    /// Number of usage records (turns) in the conversation.
+    pub turn_count: u64,
+    /// Tokens consumed across those turns.
+    pub usage: TokenUsage,
+    /// Cost across those turns.
+    pub cost: CostMicros,
+}
+
+/// Usage rolled up for one agent backend across a set of conversations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackendUsage {
+    /// Number of usage records (turns) executed on the backend.
+    pub turn_count: u64,
+    /// Tokens consumed across those turns.
+    pub usage: TokenUsage,
+    /// Cost across those turns.
+    pub cost: CostMicros,
+}
+
+impl BackendUsage {
+    /// Returns the usage with `record` added.
+    #[must_use]
+    pub const fn with_record(self, record: &UsageRecord) -> Self {
+        Self {
+            turn_count: self.turn_count.saturating_add(1),
+            usage: self.usage.combine(record.usage),
+            cost: self.cost.saturating_add(record.cost),
+        }
+    }
+}
+
+/// Cost report for a task, including every linked conversation.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::message::domain::ConversationId;
+/// use corbusier::task::domain::{
+///     ConversationCost, CostMicros, TaskConversationLink, TaskCostReport, TaskId, TokenUsage,
+/// };
+///
+/// let task_id = TaskId::new();
+/// let report = TaskCostReport::new(
+///     task_id,
+///     vec![ConversationCost {
+///         link: TaskConversationLink::primary(task_id, ConversationId::new()),
+///         turn_count: 3,
+///         usage: TokenUsage::new(200_000, 10_000),
+///         cost: CostMicros::new(4_320_000),
+///     }],
+/// );
+/// assert_eq!(report.total_cost.to_string(), "$4.32");
+/// assert_eq!(report.total_usage.total(), 210_000);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskCostReport {
+    /// The reported task.
+    pub task_id: TaskId,
+    /// Per-conversation breakdown in link order.
+    pub conversations: Vec<ConversationCost>,
+    /// Number of turns across all conversations.
+    pub total_turns: u64,
+    /// Tokens consumed across all conversations.
+    pub total_usage: TokenUsage,
+    /// Cost across all conversations.
+    pub total_cost: CostMicros,
+}
+
+impl TaskCostReport {
+    /// Builds a report, computing totals from the conversation breakdown.
+    #[must_use]
+    pub fn new(task_id: TaskId, conversations: Vec<ConversationCost>) -> Self {
+        let (total_turns, total_usage, total_cost) = conversations.iter().fold(
+            (0_u64, TokenUsage::default(), CostMicros::default()),
+            |(turns, usage, cost), line| {
+                (
+                    turns.saturating_add(line.turn_count),
+                    usage.combine(line.usage),
+                    cost.saturating_add(line.cost),
+                )
+            },
+        );
+        Self {
+            task_id,
+            conversations,
+            total_turns,
+            total_usage,
+            total_cost,
+        }
+    }
+}
@@ -57,12 +57,51 @@ pub enum TaskDomainError {
     #[error("invalid pull request reference format: {0}")]
     InvalidPullRequestRefFormat(String),
 
+    /// A delegated budget share is not between 1 and 100 percent.
+    #[error("budget share must be between 1 and 100 percent, got {0}")]
+    InvalidBudgetShare(u8),
+
+    /// A conversation's remaining budget cannot cover a delegated slice.
+    #[error("conversation {0} has insufficient budget remaining to delegate")]
+    InsufficientBudget(crate::message::domain::ConversationId),
+
+    /// A delegated budget still has open delegations of its own.
+    #[error("conversation {0} still has delegated budgets to reconcile")]
+    OpenBudgetDelegations(crate::message::domain::ConversationId),
+
+    /// A delegated budget was not delegated from the given parent.
+    #[error("conversation {child} was not delegated from conversation {parent}")]
+    BudgetNotDelegatedFrom {
+        /// The delegated conversation.
+        child: crate::message::domain::ConversationId,
+        /// The conversation it was expected to be delegated from.
+        parent: crate::message::domain::ConversationId,
+    },
+
     /// A canonical reference exceeds the `VARCHAR(255)` column limit.
     #[error("canonical reference exceeds 255-character storage limit: {0}")]
     CanonicalRefTooLong(String),
+
+    /// A webhook delivery identifier is blank or too long to store.
+    #[error("invalid webhook delivery identifier '{0}'")]
+    InvalidWebhookDeliveryId(String),
+
+    /// A Jira issue key is not a project key and number such as `PROJ-123`.
+    #[error("invalid Jira issue key '{0}', expected PROJ-123")]
+    InvalidJiraIssueKey(String),
+
+    /// A Jira project key does not start with a letter followed by letters,
+    /// digits, or underscores.
+    #[error("invalid Jira project key '{0}'")]
+    InvalidJiraProjectKey(String),
 }
 
 /// Error returned while parsing task states from persistence.
 #[derive(Debug, Clone, Error, PartialEq, Eq)]
 #[error("unknown task state: {0}")]
 pub struct ParseTaskStateError(pub String);
+
+/// Error returned while parsing task priorities.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("unknown task priority: {0}")]
+pub struct ParseTaskPriorityError(pub String);
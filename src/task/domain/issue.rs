@@ -1,6 +1,7 @@
 //! Issue-origin value objects for task creation.
 
 use super::{IssueNumber, RepositoryFullName, TaskDomainError};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -13,6 +14,11 @@ pub enum IssueProvider {
     /// GitLab issues.
     #[serde(rename = "gitlab")]
     GitLab,
+    /// Jira Cloud issues, keyed as described in [`JiraIssueKey`].
+    ///
+    /// [`JiraIssueKey`]: super::JiraIssueKey
+    #[serde(rename = "jira")]
+    Jira,
 }
 
 impl IssueProvider {
@@ -22,6 +28,7 @@ impl IssueProvider {
         match self {
             Self::GitHub => "github",
             Self::GitLab => "gitlab",
+            Self::Jira => "jira",
         }
     }
 }
@@ -34,6 +41,7 @@ impl TryFrom<&str> for IssueProvider {
         match normalized.as_str() {
             "github" => Ok(Self::GitHub),
             "gitlab" => Ok(Self::GitLab),
+            "jira" => Ok(Self::Jira),
             _ => Err(TaskDomainError::InvalidIssueProvider(value.to_owned())),
         }
     }
@@ -252,6 +260,17 @@ impl IssueSnapshot {
     }
 }
 
+/// Comment on an external issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueComment {
+    /// Login of the comment author, or `None` when the account was deleted.
+    pub author: Option<String>,
+    /// Comment text.
+    pub body: String,
+    /// When the comment was posted.
+    pub created_at: DateTime<Utc>,
+}
+
 /// External issue payload used to create a task.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExternalIssue {
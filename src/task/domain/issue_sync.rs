@@ -0,0 +1,169 @@
+//! Task state changes posted back to the issues tasks came from.
+//!
+//! Writes made on a task's behalf carry markers: status labels are prefixed
+//! with [`STATUS_LABEL_PREFIX`] and comments embed a hidden marker line, so
+//! the service recognises its own writes when they come back through
+//! provider webhooks or later reads instead of treating them as user input.
+
+use super::{IssueRef, Task, TaskId, TaskState};
+use chrono::{DateTime, Utc};
+
+/// Prefix of the labels reporting task state on an issue.
+pub const STATUS_LABEL_PREFIX: &str = "corbusier:";
+
+/// Opening of the hidden line marking status comments.
+const COMMENT_MARKER: &str = "<!-- corbusier:status-sync";
+
+/// A task state to report on the task's originating issue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueStatusUpdate {
+    task_id: TaskId,
+    issue_ref: IssueRef,
+    state: TaskState,
+}
+
+impl IssueStatusUpdate {
+    /// Creates an update reporting `state` for a task on `issue_ref`.
+    #[must_use]
+    pub const fn new(task_id: TaskId, issue_ref: IssueRef, state: TaskState) -> Self {
+        Self {
+            task_id,
+            issue_ref,
+            state,
+        }
+    }
+
+    /// Returns the update reporting `task`'s current state, when that state
+    /// is reported: `in_progress`, `in_review`, or `done`.
+    #[must_use]
+    pub fn for_task(task: &Task) -> Option<Self> {
+        matches!(
+            task.state(),
+            TaskState::InProgress | TaskState::InReview | TaskState::Done
+        )
+        .then(|| Self::new(task.id(), task.origin().issue_ref().clone(), task.state()))
+    }
+
+    /// Returns the task whose state is reported.
+    #[must_use]
+    pub const fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+
+    /// Returns the issue the state is reported on.
+    #[must_use]
+    pub const fn issue_ref(&self) -> &IssueRef {
+        &self.issue_ref
+    }
+
+    /// Returns the reported state.
+    #[must_use]
+    pub const fn state(&self) -> TaskState {
+        self.state
+    }
+
+    /// Returns the label reporting the state, such as
+    /// `corbusier:in-progress`.
+    #[must_use]
+    pub fn status_label(&self) -> String {
+        format!(
+            "{STATUS_LABEL_PREFIX}{}",
+            self.state.as_str().replace('_', "-")
+        )
+    }
+
+    /// Returns the comment announcing the state, led by its marker line.
+    #[must_use]
+    pub fn comment_body(&self) -> String {
+        format!(
+            "{COMMENT_MARKER} task={} state={} -->\nThe task for this issue is now **{}**.",
+            self.task_id,
+            self.state.as_str(),
+            self.state.as_str().replace('_', " ")
+        )
+    }
+}
+
+/// Returns whether `label` is a status label written for a task.
+#[must_use]
+pub fn is_status_label(label: &str) -> bool {
+    label.starts_with(STATUS_LABEL_PREFIX)
+}
+
+/// Returns whether `body` is a status comment written for a task.
+#[must_use]
+pub fn is_status_comment(body: &str) -> bool {
+    body.trim_start().starts_with(COMMENT_MARKER)
+}
+
+/// A status update awaiting delivery to its issue provider.
+///
+/// Updates are queued before they are sent and stay queued while delivery
+/// fails transiently; a task has at most one queued update, its latest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingIssueNotification {
+    update: IssueStatusUpdate,
+    attempts: u32,
+    next_attempt_at: DateTime<Utc>,
+    last_error: Option<String>,
+}
+
+impl PendingIssueNotification {
+    /// Queues `update` for delivery from `now`.
+    #[must_use]
+    pub const fn new(update: IssueStatusUpdate, now: DateTime<Utc>) -> Self {
+        Self {
+            update,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+        }
+    }
+
+    /// Rebuilds a queued update from persisted parts.
+    #[must_use]
+    pub const fn from_persisted(
+        update: IssueStatusUpdate,
+        attempts: u32,
+        next_attempt_at: DateTime<Utc>,
+        last_error: Option<String>,
+    ) -> Self {
+        Self {
+            update,
+            attempts,
+            next_attempt_at,
+            last_error,
+        }
+    }
+
+    /// Records a failed delivery attempt and when to try again.
+    pub fn record_failure(&mut self, error: impl Into<String>, retry_at: DateTime<Utc>) {
+        self.attempts = self.attempts.saturating_add(1);
+        self.next_attempt_at = retry_at;
+        self.last_error = Some(error.into());
+    }
+
+    /// Returns the queued update.
+    #[must_use]
+    pub const fn update(&self) -> &IssueStatusUpdate {
+        &self.update
+    }
+
+    /// Returns how many delivery attempts have failed.
+    #[must_use]
+    pub const fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Returns when delivery is next due.
+    #[must_use]
+    pub const fn next_attempt_at(&self) -> DateTime<Utc> {
+        self.next_attempt_at
+    }
+
+    /// Returns the error of the last failed attempt.
+    #[must_use]
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+}
@@ -0,0 +1,129 @@
+//! Jira issue keys and their mapping into issue references.
+//!
+//! Jira identifies issues by keys such as `PROJ-123`: a project key and a
+//! number within the project. An [`IssueRef`] for a Jira issue stores the
+//! Jira Cloud site and the project key as its repository (`acme/PROJ` for
+//! `PROJ-123` on `acme.atlassian.net`) and the number as its issue number,
+//! so Jira-origin tasks share the storage and uniqueness rules of forge
+//! issues.
+
+use super::{IssueNumber, IssueProvider, IssueRef, RepositoryFullName, TaskDomainError};
+use std::fmt;
+use std::str::FromStr;
+
+/// A Jira issue key such as `PROJ-123`.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::task::domain::{IssueRef, JiraIssueKey};
+///
+/// let key: JiraIssueKey = "proj-123".parse()?;
+/// let issue_ref = IssueRef::from_jira_key("acme", &key)?;
+///
+/// assert_eq!(issue_ref.repository().as_str(), "acme/PROJ");
+/// assert_eq!(issue_ref.jira_key(), Some(key));
+/// # Ok::<(), corbusier::task::domain::TaskDomainError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JiraIssueKey {
+    project: String,
+    number: IssueNumber,
+}
+
+impl JiraIssueKey {
+    /// Creates a key from a project key and an issue number.
+    ///
+    /// Project keys are upper-cased; they must start with a letter and
+    /// contain only letters, digits, and underscores.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskDomainError::InvalidJiraProjectKey`] for an invalid
+    /// project key, or [`TaskDomainError::InvalidIssueNumber`] for a zero
+    /// number.
+    pub fn new(project: &str, number: u64) -> Result<Self, TaskDomainError> {
+        let normalized = project.trim().to_ascii_uppercase();
+        let mut characters = normalized.chars();
+        let is_valid = characters
+            .next()
+            .is_some_and(|first| first.is_ascii_uppercase())
+            && characters
+                .all(|rest| rest.is_ascii_uppercase() || rest.is_ascii_digit() || rest == '_');
+        if !is_valid {
+            return Err(TaskDomainError::InvalidJiraProjectKey(project.to_owned()));
+        }
+        Ok(Self {
+            project: normalized,
+            number: IssueNumber::new(number)?,
+        })
+    }
+
+    /// Returns the upper-case project key.
+    #[must_use]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+
+    /// Returns the issue's number within its project.
+    #[must_use]
+    pub const fn number(&self) -> IssueNumber {
+        self.number
+    }
+}
+
+impl FromStr for JiraIssueKey {
+    type Err = TaskDomainError;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        let invalid = || TaskDomainError::InvalidJiraIssueKey(key.to_owned());
+        let (project, number) = key.trim().rsplit_once('-').ok_or_else(invalid)?;
+        let parsed = number.parse().map_err(|_| invalid())?;
+        Self::new(project, parsed)
+    }
+}
+
+impl fmt::Display for JiraIssueKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.project, self.number)
+    }
+}
+
+impl IssueRef {
+    /// Creates a reference to a Jira issue on the Jira Cloud `site`, the
+    /// subdomain of its `atlassian.net` address.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskDomainError::InvalidRepository`] when `site` is empty
+    /// or contains a slash or whitespace.
+    pub fn from_jira_key(site: &str, key: &JiraIssueKey) -> Result<Self, TaskDomainError> {
+        let repository = RepositoryFullName::new(format!("{}/{}", site.trim(), key.project))?;
+        Ok(Self::new(IssueProvider::Jira, repository, key.number))
+    }
+
+    /// Returns the Jira issue key, when this references a Jira issue.
+    #[must_use]
+    pub fn jira_key(&self) -> Option<JiraIssueKey> {
+        if self.provider() != IssueProvider::Jira {
+            return None;
+        }
+        let (_, project) = self.repository().as_str().split_once('/')?;
+        Some(JiraIssueKey {
+            project: project.to_owned(),
+            number: self.issue_number(),
+        })
+    }
+
+    /// Returns the Jira Cloud site, when this references a Jira issue.
+    #[must_use]
+    pub fn jira_site(&self) -> Option<&str> {
+        if self.provider() != IssueProvider::Jira {
+            return None;
+        }
+        self.repository()
+            .as_str()
+            .split_once('/')
+            .map(|(site, _)| site)
+    }
+}
@@ -1,22 +1,48 @@
 //! Domain model for task lifecycle management.
 //!
 //! The task domain models issue-origin task creation, branch and pull request
-//! association, and lookup while keeping all infrastructure concerns outside
-//! of the domain boundary.
+//! association, usage attribution and budgets, webhook events, issue status
+//! sync, prioritisation, and lookup while keeping all infrastructure concerns
+//! outside of the domain boundary.
 
 mod branch;
+mod budget;
+mod cost;
 mod error;
 mod ids;
 mod issue;
+mod issue_sync;
+mod jira;
 mod pull_request;
+mod reconciliation;
+mod schedule;
 mod task;
+mod webhook;
 
 pub use branch::{BranchName, BranchRef};
-pub use error::{ParseTaskStateError, TaskDomainError};
+pub use budget::{BudgetDelegation, BudgetShare, BudgetStatus, ConversationBudget, UsageAllowance};
+pub use cost::{
+    BackendUsage, ConversationCost, ConversationLinkKind, CostMicros,
+    ParseConversationLinkKindError, TaskConversationLink, TaskCostReport, TokenUsage, UsageRecord,
+    UsageRecordId, UsageRecordParams,
+};
+pub use error::{ParseTaskPriorityError, ParseTaskStateError, TaskDomainError};
 pub use ids::{IssueNumber, RepositoryFullName, TaskId};
-pub use issue::{ExternalIssue, ExternalIssueMetadata, IssueProvider, IssueRef, IssueSnapshot};
+pub use issue::{
+    ExternalIssue, ExternalIssueMetadata, IssueComment, IssueProvider, IssueRef, IssueSnapshot,
+};
+pub use issue_sync::{
+    IssueStatusUpdate, PendingIssueNotification, STATUS_LABEL_PREFIX, is_status_comment,
+    is_status_label,
+};
+pub use jira::JiraIssueKey;
 pub use pull_request::{PullRequestNumber, PullRequestRef};
+pub use reconciliation::{
+    BranchStatus, PullRequestStatus, ReconciliationFinding, TaskReconciliation, VcsObservation,
+};
+pub use schedule::{TaskPriority, TaskSchedule, normalize_capabilities};
 pub use task::{PersistedTaskData, Task, TaskOrigin, TaskState};
+pub use webhook::{IssueWebhookEvent, WebhookDelivery};
 
 /// Type alias exposing [`IssueProvider`] under a VCS-agnostic name for use
 /// in branch and pull request contexts.
@@ -0,0 +1,192 @@
+//! Reconciliation of task branch and pull request associations against the
+//! VCS provider.
+//!
+//! Associations recorded on a task can drift from reality: branches are
+//! deleted, pull requests are merged or closed outside Corbusier, and history
+//! is force-pushed. A reconciliation run observes the provider state for each
+//! association, records a [`ReconciliationFinding`] for every discrepancy, and
+//! derives the task state the observation implies.
+
+use super::{BranchRef, PullRequestRef, Task, TaskId, TaskState};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Provider-observed state of a task's associated branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BranchStatus {
+    /// The branch exists and its history has not been rewritten.
+    Present,
+    /// The branch exists but its history was force-pushed.
+    ForcePushed,
+    /// The branch no longer exists.
+    Deleted,
+}
+
+/// Provider-observed state of a task's associated pull request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PullRequestStatus {
+    /// The pull request is open.
+    Open,
+    /// The pull request was merged.
+    Merged,
+    /// The pull request was closed without being merged.
+    ClosedUnmerged,
+}
+
+/// A discrepancy between a task's associations and the VCS provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReconciliationFinding {
+    /// The associated branch was deleted.
+    BranchDeleted {
+        /// The deleted branch.
+        branch_ref: BranchRef,
+    },
+    /// The associated branch history was force-pushed.
+    BranchForcePushed {
+        /// The rewritten branch.
+        branch_ref: BranchRef,
+    },
+    /// The associated pull request was merged.
+    PullRequestMerged {
+        /// The merged pull request.
+        pull_request_ref: PullRequestRef,
+    },
+    /// The associated pull request was closed without being merged.
+    PullRequestClosedUnmerged {
+        /// The closed pull request.
+        pull_request_ref: PullRequestRef,
+    },
+}
+
+impl fmt::Display for ReconciliationFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BranchDeleted { branch_ref } => write!(f, "branch {branch_ref} was deleted"),
+            Self::BranchForcePushed { branch_ref } => {
+                write!(f, "branch {branch_ref} was force-pushed")
+            }
+            Self::PullRequestMerged { pull_request_ref } => {
+                write!(f, "pull request {pull_request_ref} was merged")
+            }
+            Self::PullRequestClosedUnmerged { pull_request_ref } => {
+                write!(
+                    f,
+                    "pull request {pull_request_ref} was closed without merging"
+                )
+            }
+        }
+    }
+}
+
+/// Provider state observed for one task's associations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VcsObservation {
+    /// Branch status, when the task has an associated branch.
+    pub branch: Option<BranchStatus>,
+    /// Pull request status, when the task has an associated pull request.
+    pub pull_request: Option<PullRequestStatus>,
+}
+
+impl VcsObservation {
+    /// Returns the task state implied by this observation, if any.
+    ///
+    /// A merged pull request completes the task and a pull request closed
+    /// without merging abandons it. When no pull request is associated, a
+    /// deleted branch also abandons the task. Force-pushed history is
+    /// reported but never changes state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use corbusier::task::domain::{
+    ///     BranchStatus, PullRequestStatus, TaskState, VcsObservation,
+    /// };
+    ///
+    /// let merged = VcsObservation {
+    ///     branch: Some(BranchStatus::Deleted),
+    ///     pull_request: Some(PullRequestStatus::Merged),
+    /// };
+    /// assert_eq!(merged.implied_state(), Some(TaskState::Done));
+    ///
+    /// let rewritten = VcsObservation {
+    ///     branch: Some(BranchStatus::ForcePushed),
+    ///     pull_request: None,
+    /// };
+    /// assert_eq!(rewritten.implied_state(), None);
+    /// ```
+    #[must_use]
+    pub const fn implied_state(&self) -> Option<TaskState> {
+        match (self.pull_request, self.branch) {
+            (Some(PullRequestStatus::Merged), _) => Some(TaskState::Done),
+            (Some(PullRequestStatus::ClosedUnmerged), _) | (None, Some(BranchStatus::Deleted)) => {
+                Some(TaskState::Abandoned)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of reconciling a single task that had at least one finding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskReconciliation {
+    /// The reconciled task.
+    pub task_id: TaskId,
+    /// Discrepancies observed for the task's associations.
+    pub findings: Vec<ReconciliationFinding>,
+    /// Task state before reconciliation.
+    pub previous_state: TaskState,
+    /// State the task was moved to, when reconciliation changed it.
+    pub new_state: Option<TaskState>,
+}
+
+impl TaskReconciliation {
+    /// Records the findings for `observation` against the task's references.
+    #[must_use]
+    pub fn observe(task: &Task, observation: VcsObservation) -> Self {
+        let branch_finding =
+            task.branch_ref()
+                .zip(observation.branch)
+                .and_then(|(branch, status)| match status {
+                    BranchStatus::Present => None,
+                    BranchStatus::ForcePushed => Some(ReconciliationFinding::BranchForcePushed {
+                        branch_ref: branch.clone(),
+                    }),
+                    BranchStatus::Deleted => Some(ReconciliationFinding::BranchDeleted {
+                        branch_ref: branch.clone(),
+                    }),
+                });
+        let pull_request_finding = task
+            .pull_request_ref()
+            .zip(observation.pull_request)
+            .and_then(|(pr, status)| match status {
+                PullRequestStatus::Open => None,
+                PullRequestStatus::Merged => Some(ReconciliationFinding::PullRequestMerged {
+                    pull_request_ref: pr.clone(),
+                }),
+                PullRequestStatus::ClosedUnmerged => {
+                    Some(ReconciliationFinding::PullRequestClosedUnmerged {
+                        pull_request_ref: pr.clone(),
+                    })
+                }
+            });
+
+        Self {
+            task_id: task.id(),
+            findings: branch_finding
+                .into_iter()
+                .chain(pull_request_finding)
+                .collect(),
+            previous_state: task.state(),
+            new_state: None,
+        }
+    }
+
+    /// Returns whether any discrepancy was observed.
+    #[must_use]
+    pub const fn has_findings(&self) -> bool {
+        !self.findings.is_empty()
+    }
+}
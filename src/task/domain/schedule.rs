@@ -0,0 +1,174 @@
+//! Task priority, due dates, and the capabilities a worker needs to claim a
+//! task.
+
+use super::ParseTaskPriorityError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How urgently a task should be picked up.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskPriority {
+    /// Picked up once nothing more pressing is waiting.
+    Low,
+    /// The priority of tasks nobody has ranked.
+    #[default]
+    Normal,
+    /// Picked up ahead of normal work.
+    High,
+    /// Picked up before anything else.
+    Urgent,
+}
+
+impl TaskPriority {
+    /// Returns the canonical storage representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Normal => "normal",
+            Self::High => "high",
+            Self::Urgent => "urgent",
+        }
+    }
+
+    /// Returns the stored rank; higher ranks are claimed first.
+    #[must_use]
+    pub const fn rank(self) -> i16 {
+        match self {
+            Self::Low => 0,
+            Self::Normal => 1,
+            Self::High => 2,
+            Self::Urgent => 3,
+        }
+    }
+
+    /// Returns the priority stored as `rank`, if any.
+    #[must_use]
+    pub const fn from_rank(rank: i16) -> Option<Self> {
+        match rank {
+            0 => Some(Self::Low),
+            1 => Some(Self::Normal),
+            2 => Some(Self::High),
+            3 => Some(Self::Urgent),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TaskPriority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<&str> for TaskPriority {
+    type Error = ParseTaskPriorityError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let normalized = value.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "low" => Ok(Self::Low),
+            "normal" => Ok(Self::Normal),
+            "high" => Ok(Self::High),
+            "urgent" => Ok(Self::Urgent),
+            _ => Err(ParseTaskPriorityError(value.to_owned())),
+        }
+    }
+}
+
+/// When and by whom a task should be picked up.
+///
+/// Queued tasks are claimed by descending priority, then earliest due date,
+/// with undated tasks after dated ones, then creation order. A worker may
+/// only claim a task whose required capabilities it has all of.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskSchedule {
+    priority: TaskPriority,
+    due_at: Option<DateTime<Utc>>,
+    required_capabilities: Vec<String>,
+}
+
+impl TaskSchedule {
+    /// Creates an undated schedule with `priority` and no required
+    /// capabilities.
+    #[must_use]
+    pub const fn new(priority: TaskPriority) -> Self {
+        Self {
+            priority,
+            due_at: None,
+            required_capabilities: Vec::new(),
+        }
+    }
+
+    /// Sets the date the task is due by.
+    #[must_use]
+    pub const fn with_due_at(mut self, due_at: DateTime<Utc>) -> Self {
+        self.due_at = Some(due_at);
+        self
+    }
+
+    /// Sets the capabilities a worker needs to claim the task.
+    ///
+    /// Names are trimmed and lower-cased; blanks and duplicates are dropped.
+    #[must_use]
+    pub fn with_required_capabilities<I, S>(mut self, capabilities: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.required_capabilities = normalize_capabilities(capabilities);
+        self
+    }
+
+    /// Returns the task priority.
+    #[must_use]
+    pub const fn priority(&self) -> TaskPriority {
+        self.priority
+    }
+
+    /// Returns the date the task is due by, if any.
+    #[must_use]
+    pub const fn due_at(&self) -> Option<DateTime<Utc>> {
+        self.due_at
+    }
+
+    /// Returns the capabilities a worker needs, sorted.
+    #[must_use]
+    pub fn required_capabilities(&self) -> &[String] {
+        &self.required_capabilities
+    }
+
+    /// Returns whether a worker offering `worker_capabilities` may claim the
+    /// task.
+    ///
+    /// `worker_capabilities` must already be normalized with
+    /// [`normalize_capabilities`].
+    #[must_use]
+    pub fn is_satisfied_by(&self, worker_capabilities: &[String]) -> bool {
+        self.required_capabilities
+            .iter()
+            .all(|required| worker_capabilities.contains(required))
+    }
+}
+
+/// Trims, lower-cases, sorts, and deduplicates capability names, dropping
+/// blanks.
+#[must_use]
+pub fn normalize_capabilities<I, S>(capabilities: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut names: Vec<String> = capabilities
+        .into_iter()
+        .map(|name| name.as_ref().trim().to_ascii_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
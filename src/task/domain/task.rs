@@ -2,7 +2,7 @@
 
 use super::{
     BranchRef, ExternalIssue, IssueRef, IssueSnapshot, ParseTaskStateError, PullRequestRef,
-    TaskDomainError, TaskId,
+    TaskDomainError, TaskId, TaskSchedule,
 };
 use chrono::{DateTime, Utc};
 use mockable::Clock;
@@ -117,6 +117,8 @@ pub struct Task {
     branch_ref: Option<BranchRef>,
     pull_request_ref: Option<PullRequestRef>,
     state: TaskState,
+    #[serde(default)]
+    schedule: TaskSchedule,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -134,6 +136,8 @@ pub struct PersistedTaskData {
     pub pull_request_ref: Option<PullRequestRef>,
     /// Persisted lifecycle state.
     pub state: TaskState,
+    /// Persisted priority, due date, and required capabilities.
+    pub schedule: TaskSchedule,
     /// Persisted creation timestamp.
     pub created_at: DateTime<Utc>,
     /// Persisted latest lifecycle timestamp.
@@ -156,6 +160,7 @@ impl Task {
             branch_ref: None,
             pull_request_ref: None,
             state: TaskState::Draft,
+            schedule: TaskSchedule::default(),
             created_at: timestamp,
             updated_at: timestamp,
         }
@@ -170,6 +175,7 @@ impl Task {
             branch_ref: data.branch_ref,
             pull_request_ref: data.pull_request_ref,
             state: data.state,
+            schedule: data.schedule,
             created_at: data.created_at,
             updated_at: data.updated_at,
         }
@@ -205,6 +211,12 @@ impl Task {
         self.state
     }
 
+    /// Returns the task's priority, due date, and required capabilities.
+    #[must_use]
+    pub const fn schedule(&self) -> &TaskSchedule {
+        &self.schedule
+    }
+
     /// Returns the creation timestamp.
     #[must_use]
     pub const fn created_at(&self) -> DateTime<Utc> {
@@ -268,6 +280,32 @@ impl Task {
         Ok(())
     }
 
+    /// Replaces the task's priority, due date, and required capabilities.
+    pub fn reschedule(&mut self, schedule: TaskSchedule, clock: &impl Clock) {
+        self.schedule = schedule;
+        self.touch(clock);
+    }
+
+    /// Starts work on a queued task for the worker that claimed it at
+    /// `claimed_at`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskDomainError::InvalidStateTransition`] unless the task is
+    /// a [`TaskState::Draft`] waiting in the queue.
+    pub fn claim(&mut self, claimed_at: DateTime<Utc>) -> Result<(), TaskDomainError> {
+        if self.state != TaskState::Draft {
+            return Err(TaskDomainError::InvalidStateTransition {
+                task_id: self.id,
+                from: self.state,
+                to: TaskState::InProgress,
+            });
+        }
+        self.state = TaskState::InProgress;
+        self.updated_at = claimed_at;
+        Ok(())
+    }
+
     /// Transitions the task state when the transition is permitted.
     ///
     /// # Errors
@@ -291,6 +329,32 @@ impl Task {
         Ok(())
     }
 
+    /// Moves the task to `target` regardless of the lifecycle rules.
+    ///
+    /// Reserved for audited operator overrides, such as reopening a task
+    /// that was closed in error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskDomainError::InvalidStateTransition`] when the task is
+    /// already in `target`.
+    pub fn force_transition_to(
+        &mut self,
+        target: TaskState,
+        clock: &impl Clock,
+    ) -> Result<(), TaskDomainError> {
+        if self.state == target {
+            return Err(TaskDomainError::InvalidStateTransition {
+                task_id: self.id,
+                from: self.state,
+                to: target,
+            });
+        }
+        self.state = target;
+        self.touch(clock);
+        Ok(())
+    }
+
     /// Updates the `updated_at` timestamp to the current clock time.
     fn touch(&mut self, clock: &impl Clock) {
         self.updated_at = clock.utc();
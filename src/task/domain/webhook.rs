@@ -0,0 +1,96 @@
+//! Issue and pull request changes reported by provider webhooks.
+
+use super::{BranchRef, ExternalIssue, IssueProvider, IssueRef, PullRequestRef, TaskDomainError};
+
+/// Maximum stored length of a webhook delivery identifier.
+const MAX_DELIVERY_ID_LENGTH: usize = 255;
+
+/// Identifies one webhook delivery from an issue provider.
+///
+/// Providers redeliver on timeouts and on request, so deliveries are
+/// recorded to process each one once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WebhookDelivery {
+    provider: IssueProvider,
+    delivery_id: String,
+}
+
+impl WebhookDelivery {
+    /// Creates a delivery from the identifier the provider assigned, such as
+    /// GitHub's `X-GitHub-Delivery` header.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskDomainError::InvalidWebhookDeliveryId`] when the
+    /// identifier is blank or longer than 255 characters.
+    pub fn new(
+        provider: IssueProvider,
+        delivery_id: impl Into<String>,
+    ) -> Result<Self, TaskDomainError> {
+        let raw = delivery_id.into();
+        let trimmed = raw.trim();
+        if trimmed.is_empty() || trimmed.chars().count() > MAX_DELIVERY_ID_LENGTH {
+            return Err(TaskDomainError::InvalidWebhookDeliveryId(raw));
+        }
+        Ok(Self {
+            provider,
+            delivery_id: trimmed.to_owned(),
+        })
+    }
+
+    /// Returns the provider that sent the delivery.
+    #[must_use]
+    pub const fn provider(&self) -> IssueProvider {
+        self.provider
+    }
+
+    /// Returns the provider's delivery identifier.
+    #[must_use]
+    pub fn delivery_id(&self) -> &str {
+        &self.delivery_id
+    }
+}
+
+/// A change to an issue or pull request that affects tasks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueWebhookEvent {
+    /// An issue was opened.
+    IssueOpened(ExternalIssue),
+    /// An issue was closed.
+    IssueClosed {
+        /// The closed issue.
+        issue_ref: IssueRef,
+        /// `false` when the issue was closed as not planned.
+        completed: bool,
+    },
+    /// A comment was posted on an issue or pull request.
+    IssueCommented {
+        /// The commented issue or pull request.
+        issue_ref: IssueRef,
+        /// The comment's body.
+        body: String,
+    },
+    /// A label was added to or removed from an issue.
+    IssueLabelChanged {
+        /// The relabelled issue.
+        issue_ref: IssueRef,
+        /// The added or removed label.
+        label: String,
+    },
+    /// A pull request was opened or reopened.
+    PullRequestOpened {
+        /// The opened pull request.
+        pull_request: PullRequestRef,
+        /// The branch the pull request merges from.
+        branch: BranchRef,
+        /// Issues the pull request description says it closes.
+        closes: Vec<IssueRef>,
+    },
+    /// A pull request was closed, merged or not.
+    PullRequestClosed {
+        /// The closed pull request.
+        pull_request: PullRequestRef,
+        /// Whether the pull request was merged.
+        merged: bool,
+    },
+}
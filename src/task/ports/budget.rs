@@ -0,0 +1,95 @@
+//! Repository port for conversation usage budgets.
+
+use crate::context::RequestContext;
+use crate::message::domain::ConversationId;
+use crate::pagination::{Page, PageRequest};
+use crate::task::domain::{ConversationBudget, TaskDomainError};
+use crate::task::ports::TaskCostError;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for usage budget operations.
+pub type UsageBudgetResult<T> = Result<T, UsageBudgetError>;
+
+/// Persistence contract for conversation budgets.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - A conversation has at most one budget
+/// - [`UsageBudgetRepository::save`] writes every budget or none
+/// - All queries and mutations are scoped to the tenant identified
+///   by [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait UsageBudgetRepository: Send + Sync {
+    /// Returns the budget for a conversation, if any.
+    async fn find(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> UsageBudgetResult<Option<ConversationBudget>>;
+
+    /// Returns a page of the budgets delegated from a conversation, oldest
+    /// first.
+    async fn find_delegated(
+        &self,
+        ctx: &RequestContext,
+        parent_conversation_id: ConversationId,
+        page: PageRequest,
+    ) -> UsageBudgetResult<Page<ConversationBudget>>;
+
+    /// Inserts or replaces budgets in one atomic write.
+    ///
+    /// Delegation and reconciliation change a parent and its child together,
+    /// so both are saved in a single call.
+    async fn save(
+        &self,
+        ctx: &RequestContext,
+        budgets: &[ConversationBudget],
+    ) -> UsageBudgetResult<()>;
+}
+
+/// Errors returned by usage budget operations.
+#[derive(Debug, Clone, Error)]
+pub enum UsageBudgetError {
+    /// The conversation has no budget.
+    #[error("conversation {0} has no budget")]
+    NotFound(ConversationId),
+
+    /// The conversation already has a budget.
+    #[error("conversation {0} already has a budget")]
+    AlreadyAllocated(ConversationId),
+
+    /// The conversation has used up its budget.
+    #[error("conversation {0} has exhausted its budget")]
+    Exhausted(ConversationId),
+
+    /// The conversation's delegated budget has been reconciled into its
+    /// parent, so the conversation may not spend any more.
+    #[error("budget for conversation {0} is closed")]
+    Closed(ConversationId),
+
+    /// The conversation's budget was not delegated from another.
+    #[error("budget for conversation {0} was not delegated")]
+    NotDelegated(ConversationId),
+
+    /// A budget rule was violated.
+    #[error(transparent)]
+    Domain(#[from] TaskDomainError),
+
+    /// The usage ledger failed.
+    #[error(transparent)]
+    Ledger(#[from] TaskCostError),
+
+    /// Persistence-layer failure.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl UsageBudgetError {
+    /// Wraps a persistence error.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
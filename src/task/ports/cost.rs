@@ -0,0 +1,114 @@
+//! Ledger port for turn usage records and their attribution to tasks.
+
+use crate::context::RequestContext;
+use crate::message::domain::ConversationId;
+use crate::task::domain::{
+    BackendUsage, TaskConversationLink, TaskCostReport, TaskId, UsageRecord, UsageRecordId,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for task cost ledger operations.
+pub type TaskCostResult<T> = Result<T, TaskCostError>;
+
+/// Usage ledger contract.
+///
+/// Usage records are keyed by conversation, not task, so a conversation can
+/// be linked to its task before or after the turns it contains are recorded.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - A conversation is linked to at most one task
+/// - Re-linking a conversation to the same task is a no-op
+/// - All queries and mutations are scoped to the tenant identified
+///   by [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait TaskCostLedger: Send + Sync {
+    /// Appends a usage record for a turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskCostError::DuplicateUsageRecord`] when the record ID
+    /// already exists.
+    async fn record_usage(&self, ctx: &RequestContext, record: &UsageRecord) -> TaskCostResult<()>;
+
+    /// Attributes a conversation to a task.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskCostError::ConversationAlreadyLinked`] when the
+    /// conversation is already attributed to a different task.
+    async fn link_conversation(
+        &self,
+        ctx: &RequestContext,
+        link: &TaskConversationLink,
+    ) -> TaskCostResult<()>;
+
+    /// Returns the task link for a conversation, if any.
+    async fn find_link(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> TaskCostResult<Option<TaskConversationLink>>;
+
+    /// Rolls up usage across every conversation linked to a task.
+    ///
+    /// Tasks without linked conversations yield an empty report.
+    async fn task_cost_report(
+        &self,
+        ctx: &RequestContext,
+        task_id: TaskId,
+    ) -> TaskCostResult<TaskCostReport>;
+
+    /// Rolls up usage recorded on `agent_backend` by the given
+    /// conversations.
+    ///
+    /// An empty conversation list yields empty usage.
+    async fn backend_usage(
+        &self,
+        ctx: &RequestContext,
+        agent_backend: &str,
+        conversation_ids: &[ConversationId],
+    ) -> TaskCostResult<BackendUsage>;
+
+    /// Rolls up usage recorded by one conversation across every backend.
+    async fn conversation_usage(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> TaskCostResult<BackendUsage>;
+}
+
+/// Errors returned by task cost ledger implementations.
+#[derive(Debug, Clone, Error)]
+pub enum TaskCostError {
+    /// A usage record with the same identifier already exists.
+    #[error("duplicate usage record: {0}")]
+    DuplicateUsageRecord(UsageRecordId),
+
+    /// The conversation is already attributed to another task.
+    #[error("conversation {conversation_id} is already linked to task {task_id}")]
+    ConversationAlreadyLinked {
+        /// The conversation being linked.
+        conversation_id: ConversationId,
+        /// The task the conversation is already linked to.
+        task_id: TaskId,
+    },
+
+    /// The conversation is not linked to any task.
+    #[error("conversation {0} is not linked to a task")]
+    ConversationNotLinked(ConversationId),
+
+    /// Persistence-layer failure.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl TaskCostError {
+    /// Wraps a persistence error.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
@@ -0,0 +1,98 @@
+//! Ports for posting task state back to originating issues.
+
+use crate::context::RequestContext;
+use crate::task::domain::{IssueStatusUpdate, PendingIssueNotification};
+use crate::task::ports::IssueProviderResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Writes task state onto the issues tasks came from.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Writes carry the markers of
+///   [`IssueStatusUpdate::status_label`] and
+///   [`IssueStatusUpdate::comment_body`], so they can be told apart from
+///   user input
+/// - Repeating an update already shown on the issue changes nothing, as
+///   updates are delivered at least once
+/// - References to issues of another provider are rejected with
+///   [`IssueProviderError::UnsupportedProvider`](super::IssueProviderError)
+#[async_trait]
+pub trait IssueNotifier: Send + Sync {
+    /// Shows `update` on its issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IssueProviderError`](super::IssueProviderError); those
+    /// that are [transient](super::IssueProviderError::is_transient) are
+    /// retried.
+    async fn notify(
+        &self,
+        ctx: &RequestContext,
+        update: &IssueStatusUpdate,
+    ) -> IssueProviderResult<()>;
+}
+
+/// Result type for issue notification queue operations.
+pub type IssueNotificationQueueResult<T> = Result<T, IssueNotificationQueueError>;
+
+/// Outbox of status updates awaiting delivery.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - A task has at most one queued update; enqueueing replaces it
+/// - Rescheduling and removal only touch a task's queued update while it
+///   still reports the same state, so a newer update is never lost
+/// - All queries and mutations are scoped to the tenant identified
+///   by [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait IssueNotificationQueue: Send + Sync {
+    /// Queues a notification, replacing any queued for the same task.
+    async fn enqueue(
+        &self,
+        ctx: &RequestContext,
+        pending: &PendingIssueNotification,
+    ) -> IssueNotificationQueueResult<()>;
+
+    /// Lists up to `limit` notifications due at `now`, earliest first.
+    async fn due(
+        &self,
+        ctx: &RequestContext,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> IssueNotificationQueueResult<Vec<PendingIssueNotification>>;
+
+    /// Stores a notification's failed attempts and next attempt time.
+    async fn reschedule(
+        &self,
+        ctx: &RequestContext,
+        pending: &PendingIssueNotification,
+    ) -> IssueNotificationQueueResult<()>;
+
+    /// Removes a delivered or abandoned update.
+    async fn remove(
+        &self,
+        ctx: &RequestContext,
+        update: &IssueStatusUpdate,
+    ) -> IssueNotificationQueueResult<()>;
+}
+
+/// Errors returned by issue notification queue operations.
+#[derive(Debug, Clone, Error)]
+pub enum IssueNotificationQueueError {
+    /// Persistence-layer failure.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl IssueNotificationQueueError {
+    /// Wraps a persistence error.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
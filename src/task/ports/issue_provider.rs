@@ -0,0 +1,136 @@
+//! Issue provider port for reading issues from external trackers.
+
+use crate::context::RequestContext;
+use crate::task::domain::{ExternalIssue, IssueComment, IssueProvider, IssueRef, IssueSnapshot};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for issue provider lookups.
+pub type IssueProviderResult<T> = Result<T, IssueProviderError>;
+
+/// Read-only client for an external issue tracker.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Lookups never mutate provider state
+/// - References to issues of another provider are rejected with
+///   [`IssueProviderError::UnsupportedProvider`] before any request is made
+/// - Provider credentials are resolved for the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait IssueProviderClient: Send + Sync {
+    /// Fetches an issue's current title, description, labels, assignees,
+    /// and milestone.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssueProviderError::NotFound`] when the issue does not
+    /// exist or is a pull request, or another [`IssueProviderError`] when
+    /// the provider cannot be queried.
+    async fn fetch_issue(
+        &self,
+        ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<ExternalIssue>;
+
+    /// Lists the names of the labels on an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssueProviderError`] when the issue does not exist or the
+    /// provider cannot be queried.
+    async fn list_labels(
+        &self,
+        ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<Vec<String>>;
+
+    /// Lists the comments on an issue, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssueProviderError`] when the issue does not exist or the
+    /// provider cannot be queried.
+    async fn list_comments(
+        &self,
+        ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<Vec<IssueComment>>;
+
+    /// Fetches an issue and maps it into the snapshot persisted with a
+    /// task's origin.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`fetch_issue`](Self::fetch_issue).
+    async fn fetch_snapshot(
+        &self,
+        ctx: &RequestContext,
+        issue_ref: &IssueRef,
+    ) -> IssueProviderResult<IssueSnapshot> {
+        let issue = self.fetch_issue(ctx, issue_ref).await?;
+        Ok(IssueSnapshot::from_external(issue.metadata().clone()))
+    }
+}
+
+/// Errors returned by issue provider clients.
+#[derive(Debug, Clone, Error)]
+pub enum IssueProviderError {
+    /// The issue does not exist, is not visible, or is not an issue.
+    #[error("issue not found: {0}")]
+    NotFound(IssueRef),
+
+    /// The client does not talk to the referenced issue's provider.
+    #[error("issue provider '{0}' is not supported by this client")]
+    UnsupportedProvider(IssueProvider),
+
+    /// The provider rejected the credentials or denied access.
+    #[error("issue provider denied access")]
+    AccessDenied,
+
+    /// The issue's workflow offers no transition to the requested status.
+    #[error("issue {issue_ref} cannot move to status '{status}'")]
+    TransitionUnavailable {
+        /// The issue that was to move.
+        issue_ref: IssueRef,
+        /// The requested status.
+        status: String,
+    },
+
+    /// The provider's rate limit is exhausted.
+    #[error("issue provider rate limit exceeded")]
+    RateLimited {
+        /// When the provider will accept requests again, if it said.
+        reset_at: Option<DateTime<Utc>>,
+    },
+
+    /// The provider's response could not be decoded.
+    #[error("invalid issue provider response: {0}")]
+    InvalidResponse(Arc<dyn std::error::Error + Send + Sync>),
+
+    /// The provider request failed.
+    #[error("issue provider error: {0}")]
+    Provider(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl IssueProviderError {
+    /// Wraps a provider error.
+    pub fn provider(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Provider(Arc::new(err))
+    }
+
+    /// Wraps a response decoding error.
+    pub fn invalid_response(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::InvalidResponse(Arc::new(err))
+    }
+
+    /// Returns whether retrying later may succeed: the provider was rate
+    /// limited or the request failed, rather than being refused.
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Self::RateLimited { .. } | Self::Provider(_))
+    }
+}
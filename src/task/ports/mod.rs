@@ -2,6 +2,23 @@
 //!
 //! Ports define infrastructure-agnostic interfaces used by task services.
 
+pub mod budget;
+pub mod cost;
+pub mod issue_notifier;
+pub mod issue_provider;
+pub mod queue;
 pub mod repository;
+pub mod vcs;
+pub mod webhook_delivery;
 
+pub use budget::{UsageBudgetError, UsageBudgetRepository, UsageBudgetResult};
+pub use cost::{TaskCostError, TaskCostLedger, TaskCostResult};
+pub use issue_notifier::{
+    IssueNotificationQueue, IssueNotificationQueueError, IssueNotificationQueueResult,
+    IssueNotifier,
+};
+pub use issue_provider::{IssueProviderClient, IssueProviderError, IssueProviderResult};
+pub use queue::{TaskQueue, TaskQueueError, TaskQueueResult};
 pub use repository::{TaskRepository, TaskRepositoryError, TaskRepositoryResult};
+pub use vcs::{VcsStatusError, VcsStatusPort, VcsStatusResult};
+pub use webhook_delivery::{WebhookDeliveryError, WebhookDeliveryLog, WebhookDeliveryResult};
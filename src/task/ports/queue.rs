@@ -0,0 +1,57 @@
+//! Queue port from which workers claim the next task to work on.
+
+use crate::context::RequestContext;
+use crate::task::domain::Task;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for task queue operations.
+pub type TaskQueueResult<T> = Result<T, TaskQueueError>;
+
+/// Claims queued tasks for workers.
+///
+/// A task is queued while it is a [`TaskState::Draft`](crate::task::domain::TaskState).
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Tasks are claimed in [`TaskSchedule`](crate::task::domain::TaskSchedule)
+///   order: highest priority first, then earliest due date with undated
+///   tasks last, then oldest first
+/// - Only tasks whose required capabilities the worker has are claimed
+/// - A claim moves the task to `in_progress` atomically, so concurrent
+///   claims never return the same task and never wait on one another
+/// - All claims are scoped to the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait TaskQueue: Send + Sync {
+    /// Claims the next queued task a worker offering `worker_capabilities`
+    /// can take, starting it at `claimed_at`.
+    ///
+    /// `worker_capabilities` must already be normalized with
+    /// [`normalize_capabilities`](crate::task::domain::normalize_capabilities).
+    /// Returns `None` when no such task is queued.
+    async fn claim_next(
+        &self,
+        ctx: &RequestContext,
+        worker_capabilities: &[String],
+        claimed_at: DateTime<Utc>,
+    ) -> TaskQueueResult<Option<Task>>;
+}
+
+/// Errors returned by task queue operations.
+#[derive(Debug, Clone, Error)]
+pub enum TaskQueueError {
+    /// Persistence-layer failure.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl TaskQueueError {
+    /// Wraps a persistence error.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
@@ -1,6 +1,7 @@
 //! Repository port for task persistence, lookup, and association management.
 
 use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
 use crate::task::domain::{BranchRef, IssueRef, PullRequestRef, Task, TaskId};
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -56,23 +57,38 @@ pub trait TaskRepository: Send + Sync {
         issue_ref: &IssueRef,
     ) -> TaskRepositoryResult<Option<Task>>;
 
-    /// Returns all tasks linked to the given branch reference.
+    /// Returns a page of the tasks linked to the given branch reference,
+    /// oldest first.
     ///
     /// Multiple tasks may share a branch (many-to-many relationship).
     async fn find_by_branch_ref(
         &self,
         ctx: &RequestContext,
         branch_ref: &BranchRef,
-    ) -> TaskRepositoryResult<Vec<Task>>;
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>>;
 
-    /// Returns all tasks linked to the given pull request reference.
+    /// Returns a page of the tasks linked to the given pull request
+    /// reference, oldest first.
     ///
     /// Multiple tasks may share a pull request (many-to-many relationship).
     async fn find_by_pull_request_ref(
         &self,
         ctx: &RequestContext,
         pr_ref: &PullRequestRef,
-    ) -> TaskRepositoryResult<Vec<Task>>;
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>>;
+
+    /// Returns a page of the non-terminal tasks with an associated branch or
+    /// pull request, oldest first.
+    ///
+    /// These are the tasks whose associations the reconciliation job checks
+    /// against the VCS provider.
+    async fn find_reconcilable(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> TaskRepositoryResult<Page<Task>>;
 }
 
 /// Errors returned by task repository implementations.
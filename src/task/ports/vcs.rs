@@ -0,0 +1,66 @@
+//! VCS provider port for observing branch and pull request state.
+
+use crate::context::RequestContext;
+use crate::task::domain::{BranchRef, BranchStatus, PullRequestRef, PullRequestStatus};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for VCS status lookups.
+pub type VcsStatusResult<T> = Result<T, VcsStatusError>;
+
+/// Read-only view of branch and pull request state at the VCS provider.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Lookups never mutate provider state
+/// - Provider credentials are resolved for the tenant identified by
+///   [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait VcsStatusPort: Send + Sync {
+    /// Returns the current status of a branch.
+    ///
+    /// [`BranchStatus::ForcePushed`] is reported only when history was
+    /// rewritten after `since`, so repeated runs do not report the same
+    /// rewrite twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VcsStatusError::Provider`] when the provider cannot be
+    /// queried.
+    async fn branch_status(
+        &self,
+        ctx: &RequestContext,
+        branch_ref: &BranchRef,
+        since: DateTime<Utc>,
+    ) -> VcsStatusResult<BranchStatus>;
+
+    /// Returns the current status of a pull request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VcsStatusError::Provider`] when the provider cannot be
+    /// queried.
+    async fn pull_request_status(
+        &self,
+        ctx: &RequestContext,
+        pr_ref: &PullRequestRef,
+    ) -> VcsStatusResult<PullRequestStatus>;
+}
+
+/// Errors returned by VCS status implementations.
+#[derive(Debug, Clone, Error)]
+pub enum VcsStatusError {
+    /// The provider request failed.
+    #[error("VCS provider error: {0}")]
+    Provider(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl VcsStatusError {
+    /// Wraps a provider error.
+    pub fn provider(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Provider(Arc::new(err))
+    }
+}
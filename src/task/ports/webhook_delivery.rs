@@ -0,0 +1,56 @@
+//! Port for recording which webhook deliveries have been processed.
+
+use crate::context::RequestContext;
+use crate::task::domain::WebhookDelivery;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Result type for webhook delivery log operations.
+pub type WebhookDeliveryResult<T> = Result<T, WebhookDeliveryError>;
+
+/// Record of the webhook deliveries a tenant has processed.
+///
+/// # Implementation Notes
+///
+/// Implementations must ensure:
+/// - Concurrent claims of the same delivery succeed for exactly one caller
+/// - All queries and mutations are scoped to the tenant identified
+///   by [`RequestContext::tenant_id`](crate::context::RequestContext)
+#[async_trait]
+pub trait WebhookDeliveryLog: Send + Sync {
+    /// Claims `delivery` for processing.
+    ///
+    /// Returns `false` when the delivery has already been claimed, so a
+    /// redelivered event is not applied twice.
+    async fn claim(
+        &self,
+        ctx: &RequestContext,
+        delivery: &WebhookDelivery,
+        received_at: DateTime<Utc>,
+    ) -> WebhookDeliveryResult<bool>;
+
+    /// Releases a claim whose processing failed, so a redelivery is
+    /// processed again.
+    async fn release(
+        &self,
+        ctx: &RequestContext,
+        delivery: &WebhookDelivery,
+    ) -> WebhookDeliveryResult<()>;
+}
+
+/// Errors returned by webhook delivery log operations.
+#[derive(Debug, Clone, Error)]
+pub enum WebhookDeliveryError {
+    /// Persistence-layer failure.
+    #[error("persistence error: {0}")]
+    Persistence(Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl WebhookDeliveryError {
+    /// Wraps a persistence error.
+    pub fn persistence(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Persistence(Arc::new(err))
+    }
+}
@@ -0,0 +1,238 @@
+//! Service layer for conversation budgets and their delegation.
+//!
+//! Provides [`UsageBudgetService`], which allocates top-level budgets, splits
+//! slices off to delegated child conversations, gates new turns on the
+//! remaining budget, and reconciles children into their parents when they
+//! complete. Consumption is read from the usage ledger, so budgets follow
+//! whatever [`crate::task::services::TaskCostService`] has recorded.
+
+use crate::context::RequestContext;
+use crate::message::domain::ConversationId;
+use crate::task::{
+    domain::{BudgetDelegation, BudgetShare, BudgetStatus, ConversationBudget, UsageAllowance},
+    ports::{TaskCostLedger, UsageBudgetError, UsageBudgetRepository, UsageBudgetResult},
+};
+use mockable::Clock;
+use std::sync::Arc;
+
+/// Request payload for delegating budget to a child conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DelegateBudgetRequest {
+    parent_conversation_id: ConversationId,
+    delegation: BudgetDelegation,
+}
+
+impl DelegateBudgetRequest {
+    /// Creates a request giving `conversation_id` a share of its parent's
+    /// remaining budget.
+    #[must_use]
+    pub const fn new(
+        parent_conversation_id: ConversationId,
+        conversation_id: ConversationId,
+        share: BudgetShare,
+    ) -> Self {
+        Self {
+            parent_conversation_id,
+            delegation: BudgetDelegation {
+                conversation_id,
+                share,
+            },
+        }
+    }
+}
+
+/// Conversation budget service.
+#[derive(Clone)]
+pub struct UsageBudgetService<L, B, C>
+where
+    L: TaskCostLedger,
+    B: UsageBudgetRepository,
+    C: Clock + Send + Sync,
+{
+    ledger: Arc<L>,
+    budgets: Arc<B>,
+    clock: Arc<C>,
+}
+
+impl<L, B, C> UsageBudgetService<L, B, C>
+where
+    L: TaskCostLedger,
+    B: UsageBudgetRepository,
+    C: Clock + Send + Sync,
+{
+    /// Creates a new budget service.
+    #[must_use]
+    pub const fn new(ledger: Arc<L>, budgets: Arc<B>, clock: Arc<C>) -> Self {
+        Self {
+            ledger,
+            budgets,
+            clock,
+        }
+    }
+
+    /// Allocates a top-level budget for a conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UsageBudgetError::AlreadyAllocated`] when the conversation
+    /// already has a budget.
+    pub async fn allocate(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+        limit: UsageAllowance,
+    ) -> UsageBudgetResult<ConversationBudget> {
+        if self.budgets.find(ctx, conversation_id).await?.is_some() {
+            return Err(UsageBudgetError::AlreadyAllocated(conversation_id));
+        }
+        let budget = ConversationBudget::new(conversation_id, limit, &*self.clock);
+        self.budgets.save(ctx, &[budget]).await?;
+        Ok(budget)
+    }
+
+    /// Gives a delegated child conversation a slice of its parent's
+    /// remaining budget, reserving the slice on the parent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UsageBudgetError::NotFound`] when the parent has no budget,
+    /// [`UsageBudgetError::AlreadyAllocated`] when the child already has one,
+    /// [`UsageBudgetError::Closed`] when the parent's own budget has been
+    /// reconciled, or [`UsageBudgetError::Domain`] when the parent cannot
+    /// cover the slice.
+    pub async fn delegate(
+        &self,
+        ctx: &RequestContext,
+        request: DelegateBudgetRequest,
+    ) -> UsageBudgetResult<ConversationBudget> {
+        let DelegateBudgetRequest {
+            parent_conversation_id,
+            delegation,
+        } = request;
+        let mut parent = self.require_open(ctx, parent_conversation_id).await?;
+        if self
+            .budgets
+            .find(ctx, delegation.conversation_id)
+            .await?
+            .is_some()
+        {
+            return Err(UsageBudgetError::AlreadyAllocated(
+                delegation.conversation_id,
+            ));
+        }
+        let usage = self
+            .ledger
+            .conversation_usage(ctx, parent_conversation_id)
+            .await?;
+        let child = parent.delegate(&usage, delegation, &*self.clock)?;
+        self.budgets.save(ctx, &[parent, child]).await?;
+        Ok(child)
+    }
+
+    /// Returns a conversation's budget position.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UsageBudgetError::NotFound`] when the conversation has no
+    /// budget.
+    pub async fn status(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> UsageBudgetResult<BudgetStatus> {
+        let budget = self.require(ctx, conversation_id).await?;
+        self.status_of(ctx, &budget).await
+    }
+
+    /// Checks that a conversation may start another turn.
+    ///
+    /// Conversations without a budget are unrestricted and yield `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UsageBudgetError::Closed`] when the conversation's delegated
+    /// budget has been reconciled, or [`UsageBudgetError::Exhausted`] when
+    /// nothing remains.
+    pub async fn ensure_within_budget(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> UsageBudgetResult<Option<BudgetStatus>> {
+        let Some(budget) = self.budgets.find(ctx, conversation_id).await? else {
+            return Ok(None);
+        };
+        if budget.is_reconciled() {
+            return Err(UsageBudgetError::Closed(conversation_id));
+        }
+        let status = self.status_of(ctx, &budget).await?;
+        if status.is_exhausted() {
+            return Err(UsageBudgetError::Exhausted(conversation_id));
+        }
+        Ok(Some(status))
+    }
+
+    /// Reconciles a completed child into its parent, releasing the child's
+    /// reservation and charging the parent with what the child consumed.
+    ///
+    /// Returns the parent's updated position. Reconciling a child twice
+    /// changes nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UsageBudgetError::NotFound`] when either budget is missing,
+    /// [`UsageBudgetError::NotDelegated`] when the child's budget is
+    /// top-level, or [`UsageBudgetError::Domain`] when the child still has
+    /// delegated budgets of its own to reconcile.
+    pub async fn reconcile(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> UsageBudgetResult<BudgetStatus> {
+        let mut child = self.require(ctx, conversation_id).await?;
+        let parent_id = child
+            .parent_conversation_id
+            .ok_or(UsageBudgetError::NotDelegated(conversation_id))?;
+        let mut parent = self.require(ctx, parent_id).await?;
+        if !child.is_reconciled() {
+            let child_usage = self.ledger.conversation_usage(ctx, conversation_id).await?;
+            parent.reconcile(&mut child, &child_usage, &*self.clock)?;
+            self.budgets.save(ctx, &[parent, child]).await?;
+        }
+        self.status_of(ctx, &parent).await
+    }
+
+    async fn status_of(
+        &self,
+        ctx: &RequestContext,
+        budget: &ConversationBudget,
+    ) -> UsageBudgetResult<BudgetStatus> {
+        let usage = self
+            .ledger
+            .conversation_usage(ctx, budget.conversation_id)
+            .await?;
+        Ok(budget.status(&usage))
+    }
+
+    async fn require(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> UsageBudgetResult<ConversationBudget> {
+        self.budgets
+            .find(ctx, conversation_id)
+            .await?
+            .ok_or(UsageBudgetError::NotFound(conversation_id))
+    }
+
+    async fn require_open(
+        &self,
+        ctx: &RequestContext,
+        conversation_id: ConversationId,
+    ) -> UsageBudgetResult<ConversationBudget> {
+        let budget = self.require(ctx, conversation_id).await?;
+        if budget.is_reconciled() {
+            return Err(UsageBudgetError::Closed(conversation_id));
+        }
+        Ok(budget)
+    }
+}
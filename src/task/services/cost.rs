@@ -0,0 +1,137 @@
+//! Service layer for turn-level cost attribution to tasks.
+//!
+//! Provides [`TaskCostService`], which records per-turn usage, attributes
+//! conversations (including handoff and delegation sub-conversations) to the
+//! task they serve, and produces per-task cost reports.
+
+use crate::context::RequestContext;
+use crate::message::domain::ConversationId;
+use crate::task::{
+    domain::{
+        ConversationLinkKind, TaskConversationLink, TaskCostReport, TaskId, UsageRecord,
+        UsageRecordParams,
+    },
+    ports::{TaskCostError, TaskCostLedger, TaskCostResult},
+};
+use mockable::Clock;
+use std::sync::Arc;
+
+/// Request payload for attributing a sub-conversation to its parent's task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkSubConversationRequest {
+    parent_conversation_id: ConversationId,
+    conversation_id: ConversationId,
+    kind: ConversationLinkKind,
+}
+
+impl LinkSubConversationRequest {
+    /// Creates a request linking `conversation_id` beneath its parent.
+    #[must_use]
+    pub const fn new(
+        parent_conversation_id: ConversationId,
+        conversation_id: ConversationId,
+        kind: ConversationLinkKind,
+    ) -> Self {
+        Self {
+            parent_conversation_id,
+            conversation_id,
+            kind,
+        }
+    }
+}
+
+/// Task cost attribution service.
+#[derive(Clone)]
+pub struct TaskCostService<L, C>
+where
+    L: TaskCostLedger,
+    C: Clock + Send + Sync,
+{
+    ledger: Arc<L>,
+    clock: Arc<C>,
+}
+
+impl<L, C> TaskCostService<L, C>
+where
+    L: TaskCostLedger,
+    C: Clock + Send + Sync,
+{
+    /// Creates a new task cost service.
+    #[must_use]
+    pub const fn new(ledger: Arc<L>, clock: Arc<C>) -> Self {
+        Self { ledger, clock }
+    }
+
+    /// Records token usage and cost for a completed turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskCostError`] when the ledger rejects the record.
+    pub async fn record_turn_usage(
+        &self,
+        ctx: &RequestContext,
+        params: UsageRecordParams,
+    ) -> TaskCostResult<UsageRecord> {
+        let record = UsageRecord::new(params, &*self.clock);
+        self.ledger.record_usage(ctx, &record).await?;
+        Ok(record)
+    }
+
+    /// Attributes the conversation created to work on a task.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskCostError::ConversationAlreadyLinked`] when the
+    /// conversation already serves another task.
+    pub async fn link_primary_conversation(
+        &self,
+        ctx: &RequestContext,
+        task_id: TaskId,
+        conversation_id: ConversationId,
+    ) -> TaskCostResult<TaskConversationLink> {
+        let link = TaskConversationLink::primary(task_id, conversation_id);
+        self.ledger.link_conversation(ctx, &link).await?;
+        Ok(link)
+    }
+
+    /// Attributes a handoff or delegation sub-conversation to the task served
+    /// by its parent, so its usage rolls up into the parent task's total.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskCostError::ConversationNotLinked`] when the parent is not
+    /// attributed to a task, or [`TaskCostError::ConversationAlreadyLinked`]
+    /// when the child already serves another task.
+    pub async fn link_sub_conversation(
+        &self,
+        ctx: &RequestContext,
+        request: LinkSubConversationRequest,
+    ) -> TaskCostResult<TaskConversationLink> {
+        let LinkSubConversationRequest {
+            parent_conversation_id,
+            conversation_id,
+            kind,
+        } = request;
+        let parent = self
+            .ledger
+            .find_link(ctx, parent_conversation_id)
+            .await?
+            .ok_or(TaskCostError::ConversationNotLinked(parent_conversation_id))?;
+        let link = TaskConversationLink::child_of(&parent, conversation_id, kind);
+        self.ledger.link_conversation(ctx, &link).await?;
+        Ok(link)
+    }
+
+    /// Returns the cost report for a task.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskCostError`] when the ledger query fails.
+    pub async fn task_cost_report(
+        &self,
+        ctx: &RequestContext,
+        task_id: TaskId,
+    ) -> TaskCostResult<TaskCostReport> {
+        self.ledger.task_cost_report(ctx, task_id).await
+    }
+}
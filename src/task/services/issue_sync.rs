@@ -0,0 +1,199 @@
+//! Service posting task state back to originating issues.
+//!
+//! Provides [`IssueStatusSync`], which [`TaskLifecycleService`] invokes when
+//! a task moves to `in_progress`, `in_review`, or `done`. Each update is
+//! queued before it is sent and removed once delivered, so an update whose
+//! delivery fails transiently, or is interrupted, is retried by
+//! [`IssueStatusSync::retry_due`] with exponential backoff. Updates the
+//! provider refuses, and those still failing after the configured attempts,
+//! are dropped with a warning.
+//!
+//! Each update is delivered by the notifier registered for its issue's
+//! provider. Updates for issues of a provider without a notifier, such as
+//! Jira, are skipped without being queued.
+//!
+//! [`TaskLifecycleService`]: super::TaskLifecycleService
+
+use crate::context::RequestContext;
+use crate::task::{
+    domain::{IssueProvider, IssueStatusUpdate, PendingIssueNotification},
+    ports::{
+        IssueNotificationQueue, IssueNotificationQueueError, IssueNotifier, IssueProviderError,
+    },
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Default number of delivery attempts before an update is dropped.
+const DEFAULT_MAX_ATTEMPTS: u32 = 8;
+/// Largest backoff exponent, bounding the delay between attempts.
+const MAX_BACKOFF_EXPONENT: u32 = 10;
+
+/// Errors returned while posting task state to issues.
+#[derive(Debug, Error)]
+pub enum IssueSyncError {
+    /// The notification queue could not be read or updated.
+    #[error(transparent)]
+    Queue(#[from] IssueNotificationQueueError),
+}
+
+/// Result type for issue status sync.
+pub type IssueSyncResult<T> = Result<T, IssueSyncError>;
+
+/// What a retry pass did with the due notifications.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IssueSyncReport {
+    /// Notifications delivered.
+    pub delivered: usize,
+    /// Notifications that failed transiently and were rescheduled.
+    pub rescheduled: usize,
+    /// Notifications refused or out of attempts, and dropped.
+    pub abandoned: usize,
+}
+
+/// Result of one delivery attempt.
+enum Attempt {
+    Delivered,
+    Rescheduled,
+    Abandoned,
+}
+
+/// Posts task state to issues through an [`IssueNotifier`] per provider,
+/// retrying transient failures from an [`IssueNotificationQueue`].
+#[derive(Clone)]
+pub struct IssueStatusSync {
+    notifiers: HashMap<IssueProvider, Arc<dyn IssueNotifier>>,
+    queue: Arc<dyn IssueNotificationQueue>,
+    max_attempts: u32,
+    base_delay: TimeDelta,
+}
+
+impl IssueStatusSync {
+    /// Creates a sync delivering updates for `provider`'s issues through
+    /// `notifier` and queueing them in `queue`, with eight attempts spaced
+    /// from 30 seconds apart.
+    #[must_use]
+    pub fn new(
+        provider: IssueProvider,
+        notifier: Arc<dyn IssueNotifier>,
+        queue: Arc<dyn IssueNotificationQueue>,
+    ) -> Self {
+        Self {
+            notifiers: HashMap::from([(provider, notifier)]),
+            queue,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: TimeDelta::seconds(30),
+        }
+    }
+
+    /// Delivers updates for `provider`'s issues through `notifier`,
+    /// replacing any notifier registered for it.
+    #[must_use]
+    pub fn with_notifier(
+        mut self,
+        provider: IssueProvider,
+        notifier: Arc<dyn IssueNotifier>,
+    ) -> Self {
+        self.notifiers.insert(provider, notifier);
+        self
+    }
+
+    /// Drops an update after `max_attempts` failed deliveries, waiting
+    /// `base_delay` after the first and doubling the wait after each.
+    #[must_use]
+    pub const fn with_retry_policy(mut self, max_attempts: u32, base_delay: TimeDelta) -> Self {
+        self.max_attempts = max_attempts;
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Queues `update`, replacing any older update of its task, and tries
+    /// to deliver it. Updates for issues of a provider without a notifier
+    /// are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssueSyncError::Queue`] when the update cannot be queued or
+    /// its delivery recorded. Delivery failures are not errors.
+    pub async fn notify(
+        &self,
+        ctx: &RequestContext,
+        update: IssueStatusUpdate,
+        now: DateTime<Utc>,
+    ) -> IssueSyncResult<()> {
+        if !self.notifiers.contains_key(&update.issue_ref().provider()) {
+            return Ok(());
+        }
+        let pending = PendingIssueNotification::new(update, now);
+        self.queue.enqueue(ctx, &pending).await?;
+        self.attempt(ctx, pending, now).await?;
+        Ok(())
+    }
+
+    /// Retries up to `limit` queued updates due at `now`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssueSyncError::Queue`] when the queue cannot be read or
+    /// updated.
+    pub async fn retry_due(
+        &self,
+        ctx: &RequestContext,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> IssueSyncResult<IssueSyncReport> {
+        let mut report = IssueSyncReport::default();
+        for pending in self.queue.due(ctx, now, limit).await? {
+            match self.attempt(ctx, pending, now).await? {
+                Attempt::Delivered => report.delivered += 1,
+                Attempt::Rescheduled => report.rescheduled += 1,
+                Attempt::Abandoned => report.abandoned += 1,
+            }
+        }
+        Ok(report)
+    }
+
+    async fn attempt(
+        &self,
+        ctx: &RequestContext,
+        mut pending: PendingIssueNotification,
+        now: DateTime<Utc>,
+    ) -> IssueSyncResult<Attempt> {
+        let provider = pending.update().issue_ref().provider();
+        let result = match self.notifiers.get(&provider) {
+            Some(notifier) => notifier.notify(ctx, pending.update()).await,
+            None => Err(IssueProviderError::UnsupportedProvider(provider)),
+        };
+        let Err(err) = result else {
+            self.queue.remove(ctx, pending.update()).await?;
+            return Ok(Attempt::Delivered);
+        };
+        let retry_at = now
+            .checked_add_signed(self.backoff(pending.attempts().saturating_add(1)))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        pending.record_failure(err.to_string(), retry_at);
+        if err.is_transient() && pending.attempts() < self.max_attempts {
+            self.queue.reschedule(ctx, &pending).await?;
+            return Ok(Attempt::Rescheduled);
+        }
+        tracing::warn!(
+            task_id = %pending.update().task_id(),
+            issue = %pending.update().issue_ref(),
+            attempts = pending.attempts(),
+            error = %err,
+            "dropping issue status update"
+        );
+        self.queue.remove(ctx, pending.update()).await?;
+        Ok(Attempt::Abandoned)
+    }
+
+    /// Returns the wait after the `attempts`th failure.
+    fn backoff(&self, attempts: u32) -> TimeDelta {
+        let exponent = attempts.saturating_sub(1).min(MAX_BACKOFF_EXPONENT);
+        self.base_delay
+            .checked_mul(2_i32.pow(exponent))
+            .unwrap_or(TimeDelta::MAX)
+    }
+}
@@ -1,155 +1,47 @@
 //! Service layer for task lifecycle orchestration.
 //!
 //! Provides [`TaskLifecycleService`] which coordinates issue-to-task creation,
-//! branch and pull request association, and lookup operations.
+//! branch and pull request association, and lookup operations. Operators may
+//! force a task past its lifecycle rules with
+//! [`TaskLifecycleService::force_transition_task`], which requires a reason and
+//! is recorded as an operator action when a repository is attached. With an
+//! [`EventPublisher`] attached, every state change is published as a
+//! `TaskStateChanged` event once it is persisted; with an
+//! [`IssueStatusSync`] attached, moves to `in_progress`, `in_review`, and
+//! `done` are also posted to the task's originating issue. With a
+//! [`TaskQueue`] attached, workers claim drafts in priority order through
+//! [`TaskLifecycleService::claim_next`].
+
+mod requests;
+mod scheduling;
+mod transitions;
+
+pub use requests::{
+    AssociateBranchRequest, AssociatePullRequestRequest, CreateTaskFromIssueRequest,
+};
+pub use scheduling::ScheduleTaskRequest;
+pub use transitions::TransitionTaskRequest;
 
+use super::IssueStatusSync;
 use crate::context::RequestContext;
+use crate::events::{
+    domain::{DomainEvent, DomainEventPayload},
+    ports::EventPublisher,
+};
+use crate::operator::ports::{OperatorActionError, OperatorActionRepository};
+use crate::pagination::{Page, PageRequest};
 use crate::task::{
     domain::{
-        BranchRef, ExternalIssue, ExternalIssueMetadata, IssueRef, ParseTaskStateError,
-        PullRequestRef, Task, TaskDomainError, TaskId, TaskState,
+        BranchRef, ExternalIssue, ExternalIssueMetadata, IssueRef, IssueStatusUpdate,
+        ParseTaskPriorityError, ParseTaskStateError, PullRequestRef, Task, TaskDomainError, TaskId,
+        TaskState,
     },
-    ports::{TaskRepository, TaskRepositoryError},
+    ports::{TaskQueue, TaskQueueError, TaskRepository, TaskRepositoryError},
 };
 use mockable::Clock;
 use std::sync::Arc;
 use thiserror::Error;
 
-/// Request payload for creating a task from external issue data.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CreateTaskFromIssueRequest {
-    provider: String,
-    repository: String,
-    issue_number: u64,
-    title: String,
-    description: Option<String>,
-    labels: Vec<String>,
-    assignees: Vec<String>,
-    milestone: Option<String>,
-}
-
-impl CreateTaskFromIssueRequest {
-    /// Creates a request with required issue fields.
-    #[must_use]
-    pub fn new(
-        provider: impl Into<String>,
-        repository: impl Into<String>,
-        issue_number: u64,
-        title: impl Into<String>,
-    ) -> Self {
-        Self {
-            provider: provider.into(),
-            repository: repository.into(),
-            issue_number,
-            title: title.into(),
-            description: None,
-            labels: Vec::new(),
-            assignees: Vec::new(),
-            milestone: None,
-        }
-    }
-
-    /// Sets issue description.
-    #[must_use]
-    pub fn with_description(mut self, description: impl Into<String>) -> Self {
-        self.description = Some(description.into());
-        self
-    }
-
-    /// Sets issue labels.
-    #[must_use]
-    pub fn with_labels(mut self, labels: impl IntoIterator<Item = String>) -> Self {
-        self.labels = labels.into_iter().collect();
-        self
-    }
-
-    /// Sets issue assignees.
-    #[must_use]
-    pub fn with_assignees(mut self, assignees: impl IntoIterator<Item = String>) -> Self {
-        self.assignees = assignees.into_iter().collect();
-        self
-    }
-
-    /// Sets issue milestone.
-    #[must_use]
-    pub fn with_milestone(mut self, milestone: impl Into<String>) -> Self {
-        self.milestone = Some(milestone.into());
-        self
-    }
-}
-
-/// Request payload for associating a branch with an existing task.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AssociateBranchRequest {
-    task_id: TaskId,
-    provider: String,
-    repository: String,
-    branch_name: String,
-}
-
-impl AssociateBranchRequest {
-    /// Creates a branch association request.
-    #[must_use]
-    pub fn new(
-        task_id: TaskId,
-        provider: impl Into<String>,
-        repository: impl Into<String>,
-        branch_name: impl Into<String>,
-    ) -> Self {
-        Self {
-            task_id,
-            provider: provider.into(),
-            repository: repository.into(),
-            branch_name: branch_name.into(),
-        }
-    }
-}
-
-/// Request payload for associating a pull request with an existing task.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AssociatePullRequestRequest {
-    task_id: TaskId,
-    provider: String,
-    repository: String,
-    pull_request_number: u64,
-}
-
-impl AssociatePullRequestRequest {
-    /// Creates a pull request association request.
-    #[must_use]
-    pub fn new(
-        task_id: TaskId,
-        provider: impl Into<String>,
-        repository: impl Into<String>,
-        pull_request_number: u64,
-    ) -> Self {
-        Self {
-            task_id,
-            provider: provider.into(),
-            repository: repository.into(),
-            pull_request_number,
-        }
-    }
-}
-
-/// Request payload for transitioning an existing task to a new state.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct TransitionTaskRequest {
-    task_id: TaskId,
-    target_state: String,
-}
-
-impl TransitionTaskRequest {
-    /// Creates a task state transition request.
-    #[must_use]
-    pub fn new(task_id: TaskId, target_state: impl Into<String>) -> Self {
-        Self {
-            task_id,
-            target_state: target_state.into(),
-        }
-    }
-}
-
 /// Service-level errors for task lifecycle operations.
 #[derive(Debug, Error)]
 pub enum TaskLifecycleError {
@@ -159,9 +51,21 @@ pub enum TaskLifecycleError {
     /// Requested state string could not be parsed.
     #[error(transparent)]
     InvalidState(#[from] ParseTaskStateError),
+    /// Requested priority string could not be parsed.
+    #[error(transparent)]
+    InvalidPriority(#[from] ParseTaskPriorityError),
     /// Repository operation failed.
     #[error(transparent)]
     Repository(#[from] TaskRepositoryError),
+    /// Operator action audit failed.
+    #[error(transparent)]
+    OperatorActions(#[from] OperatorActionError),
+    /// Task queue operation failed.
+    #[error(transparent)]
+    Queue(#[from] TaskQueueError),
+    /// A claim was requested but no task queue is attached.
+    #[error("no task queue is attached")]
+    QueueNotConfigured,
 }
 
 /// Result type for task lifecycle service operations.
@@ -176,6 +80,10 @@ where
 {
     repository: Arc<R>,
     clock: Arc<C>,
+    operator_actions: Option<Arc<dyn OperatorActionRepository>>,
+    event_publisher: Option<Arc<dyn EventPublisher>>,
+    issue_sync: Option<IssueStatusSync>,
+    task_queue: Option<Arc<dyn TaskQueue>>,
 }
 
 impl<R, C> TaskLifecycleService<R, C>
@@ -202,7 +110,92 @@ where
     /// Creates a new task lifecycle service.
     #[must_use]
     pub const fn new(repository: Arc<R>, clock: Arc<C>) -> Self {
-        Self { repository, clock }
+        Self {
+            repository,
+            clock,
+            operator_actions: None,
+            event_publisher: None,
+            issue_sync: None,
+            task_queue: None,
+        }
+    }
+
+    /// Attaches the repository that records forced transitions.
+    #[must_use]
+    pub fn with_operator_actions(
+        mut self,
+        operator_actions: Arc<dyn OperatorActionRepository>,
+    ) -> Self {
+        self.operator_actions = Some(operator_actions);
+        self
+    }
+
+    /// Attaches the publisher that announces task state changes.
+    #[must_use]
+    pub fn with_event_publisher(mut self, event_publisher: Arc<dyn EventPublisher>) -> Self {
+        self.event_publisher = Some(event_publisher);
+        self
+    }
+
+    /// Attaches the sync that posts task state to originating issues.
+    #[must_use]
+    pub fn with_issue_sync(mut self, issue_sync: IssueStatusSync) -> Self {
+        self.issue_sync = Some(issue_sync);
+        self
+    }
+
+    /// Attaches the queue from which workers claim tasks.
+    #[must_use]
+    pub fn with_task_queue(mut self, task_queue: Arc<dyn TaskQueue>) -> Self {
+        self.task_queue = Some(task_queue);
+        self
+    }
+
+    /// Publishes the change, and posts it to the task's issue, when `task`
+    /// left `from`.
+    ///
+    /// The change is already persisted, so a failed publication is logged
+    /// rather than returned.
+    async fn publish_state_change(&self, ctx: &RequestContext, task: &Task, from: TaskState) {
+        if task.state() == from {
+            return;
+        }
+        self.publish_event(ctx, task, from).await;
+        self.sync_issue_status(ctx, task).await;
+    }
+
+    async fn publish_event(&self, ctx: &RequestContext, task: &Task, from: TaskState) {
+        let Some(event_publisher) = &self.event_publisher else {
+            return;
+        };
+        let payload = DomainEventPayload::TaskStateChanged {
+            task_id: task.id(),
+            from,
+            to: task.state(),
+        };
+        let event = DomainEvent::new(ctx, payload, task.updated_at());
+        if let Err(err) = event_publisher.publish(&event).await {
+            tracing::warn!(
+                task_id = %task.id(),
+                error = %err,
+                "failed to publish task state change"
+            );
+        }
+    }
+
+    async fn sync_issue_status(&self, ctx: &RequestContext, task: &Task) {
+        let (Some(issue_sync), Some(update)) =
+            (&self.issue_sync, IssueStatusUpdate::for_task(task))
+        else {
+            return;
+        };
+        if let Err(err) = issue_sync.notify(ctx, update, self.clock.utc()).await {
+            tracing::warn!(
+                task_id = %task.id(),
+                error = %err,
+                "failed to queue issue status update"
+            );
+        }
     }
 
     async fn find_task_by_id_or_error(
@@ -244,8 +237,24 @@ where
             .with_assignees(assignees);
         let metadata = Self::apply_optional_metadata(base_metadata, description, milestone);
 
-        let external_issue = ExternalIssue::new(issue_ref, metadata);
-        let task = Task::new_from_issue(&external_issue, &*self.clock);
+        self.create_from_external_issue(ctx, &ExternalIssue::new(issue_ref, metadata))
+            .await
+    }
+
+    /// Creates a new task from an issue already read from its provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskLifecycleError::Repository`] when the repository
+    /// rejects persistence, including
+    /// [`TaskRepositoryError::DuplicateIssueOrigin`] when a task already
+    /// exists for the issue.
+    pub async fn create_from_external_issue(
+        &self,
+        ctx: &RequestContext,
+        issue: &ExternalIssue,
+    ) -> TaskLifecycleResult<Task> {
+        let task = Task::new_from_issue(issue, &*self.clock);
         self.repository.store(ctx, &task).await?;
         Ok(task)
     }
@@ -329,37 +338,15 @@ where
 
         let pr_ref = PullRequestRef::from_parts(&provider, &repository, pull_request_number)?;
         let mut task = self.find_task_by_id_or_error(ctx, task_id).await?;
+        let from = task.state();
         task.associate_pull_request(pr_ref, &*self.clock)?;
         self.repository.update(ctx, &task).await?;
+        self.publish_state_change(ctx, &task, from).await;
         Ok(task)
     }
 
-    /// Transitions an existing task to a target state.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`TaskLifecycleError::InvalidState`] when `target_state`
-    /// cannot be parsed, [`TaskLifecycleError::Domain`] when transition
-    /// validation fails, or [`TaskLifecycleError::Repository`] when lookup
-    /// or persistence fails.
-    pub async fn transition_task(
-        &self,
-        ctx: &RequestContext,
-        request: TransitionTaskRequest,
-    ) -> TaskLifecycleResult<Task> {
-        let TransitionTaskRequest {
-            task_id,
-            target_state,
-        } = request;
-
-        let parsed_target_state = TaskState::try_from(target_state.as_str())?;
-        let mut task = self.find_task_by_id_or_error(ctx, task_id).await?;
-        task.transition_to(parsed_target_state, &*self.clock)?;
-        self.repository.update(ctx, &task).await?;
-        Ok(task)
-    }
-
-    /// Retrieves all tasks linked to a branch reference.
+    /// Retrieves a page of the tasks linked to a branch reference, oldest
+    /// first.
     ///
     /// Multiple tasks may share a branch (many-to-many relationship).
     ///
@@ -371,11 +358,16 @@ where
         &self,
         ctx: &RequestContext,
         branch_ref: &BranchRef,
-    ) -> TaskLifecycleResult<Vec<Task>> {
-        Ok(self.repository.find_by_branch_ref(ctx, branch_ref).await?)
+        page: PageRequest,
+    ) -> TaskLifecycleResult<Page<Task>> {
+        Ok(self
+            .repository
+            .find_by_branch_ref(ctx, branch_ref, page)
+            .await?)
     }
 
-    /// Retrieves all tasks linked to a pull request reference.
+    /// Retrieves a page of the tasks linked to a pull request reference,
+    /// oldest first.
     ///
     /// Multiple tasks may share a pull request (many-to-many relationship).
     ///
@@ -387,10 +379,11 @@ where
         &self,
         ctx: &RequestContext,
         pr_ref: &PullRequestRef,
-    ) -> TaskLifecycleResult<Vec<Task>> {
+        page: PageRequest,
+    ) -> TaskLifecycleResult<Page<Task>> {
         Ok(self
             .repository
-            .find_by_pull_request_ref(ctx, pr_ref)
+            .find_by_pull_request_ref(ctx, pr_ref, page)
             .await?)
     }
 }
@@ -0,0 +1,121 @@
+//! Request payloads for creating tasks and associating them with branches
+//! and pull requests.
+
+use crate::task::domain::TaskId;
+
+/// Request payload for creating a task from external issue data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateTaskFromIssueRequest {
+    pub(super) provider: String,
+    pub(super) repository: String,
+    pub(super) issue_number: u64,
+    pub(super) title: String,
+    pub(super) description: Option<String>,
+    pub(super) labels: Vec<String>,
+    pub(super) assignees: Vec<String>,
+    pub(super) milestone: Option<String>,
+}
+
+impl CreateTaskFromIssueRequest {
+    /// Creates a request with required issue fields.
+    #[must_use]
+    pub fn new(
+        provider: impl Into<String>,
+        repository: impl Into<String>,
+        issue_number: u64,
+        title: impl Into<String>,
+    ) -> Self {
+        Self {
+            provider: provider.into(),
+            repository: repository.into(),
+            issue_number,
+            title: title.into(),
+            description: None,
+            labels: Vec::new(),
+            assignees: Vec::new(),
+            milestone: None,
+        }
+    }
+
+    /// Sets issue description.
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets issue labels.
+    #[must_use]
+    pub fn with_labels(mut self, labels: impl IntoIterator<Item = String>) -> Self {
+        self.labels = labels.into_iter().collect();
+        self
+    }
+
+    /// Sets issue assignees.
+    #[must_use]
+    pub fn with_assignees(mut self, assignees: impl IntoIterator<Item = String>) -> Self {
+        self.assignees = assignees.into_iter().collect();
+        self
+    }
+
+    /// Sets issue milestone.
+    #[must_use]
+    pub fn with_milestone(mut self, milestone: impl Into<String>) -> Self {
+        self.milestone = Some(milestone.into());
+        self
+    }
+}
+
+/// Request payload for associating a branch with an existing task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssociateBranchRequest {
+    pub(super) task_id: TaskId,
+    pub(super) provider: String,
+    pub(super) repository: String,
+    pub(super) branch_name: String,
+}
+
+impl AssociateBranchRequest {
+    /// Creates a branch association request.
+    #[must_use]
+    pub fn new(
+        task_id: TaskId,
+        provider: impl Into<String>,
+        repository: impl Into<String>,
+        branch_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            task_id,
+            provider: provider.into(),
+            repository: repository.into(),
+            branch_name: branch_name.into(),
+        }
+    }
+}
+
+/// Request payload for associating a pull request with an existing task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssociatePullRequestRequest {
+    pub(super) task_id: TaskId,
+    pub(super) provider: String,
+    pub(super) repository: String,
+    pub(super) pull_request_number: u64,
+}
+
+impl AssociatePullRequestRequest {
+    /// Creates a pull request association request.
+    #[must_use]
+    pub fn new(
+        task_id: TaskId,
+        provider: impl Into<String>,
+        repository: impl Into<String>,
+        pull_request_number: u64,
+    ) -> Self {
+        Self {
+            task_id,
+            provider: provider.into(),
+            repository: repository.into(),
+            pull_request_number,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Task prioritisation and claiming queued tasks for workers.
+
+use super::{TaskLifecycleError, TaskLifecycleResult, TaskLifecycleService};
+use crate::context::RequestContext;
+use crate::task::{
+    domain::{Task, TaskId, TaskPriority, TaskSchedule, TaskState, normalize_capabilities},
+    ports::TaskRepository,
+};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+
+/// Request payload for setting a task's priority, due date, and required
+/// worker capabilities.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleTaskRequest {
+    task_id: TaskId,
+    priority: String,
+    due_at: Option<DateTime<Utc>>,
+    required_capabilities: Vec<String>,
+}
+
+impl ScheduleTaskRequest {
+    /// Creates an undated request requiring no capabilities.
+    #[must_use]
+    pub fn new(task_id: TaskId, priority: impl Into<String>) -> Self {
+        Self {
+            task_id,
+            priority: priority.into(),
+            due_at: None,
+            required_capabilities: Vec::new(),
+        }
+    }
+
+    /// Sets the date the task is due by.
+    #[must_use]
+    pub const fn with_due_at(mut self, due_at: DateTime<Utc>) -> Self {
+        self.due_at = Some(due_at);
+        self
+    }
+
+    /// Sets the capabilities a worker needs to claim the task.
+    #[must_use]
+    pub fn with_required_capabilities(mut self, capabilities: Vec<String>) -> Self {
+        self.required_capabilities = capabilities;
+        self
+    }
+}
+
+impl<R, C> TaskLifecycleService<R, C>
+where
+    R: TaskRepository,
+    C: Clock + Send + Sync,
+{
+    /// Replaces a task's priority, due date, and required capabilities.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskLifecycleError::InvalidPriority`] when `priority` cannot
+    /// be parsed, or [`TaskLifecycleError::Repository`] when lookup or
+    /// persistence fails.
+    pub async fn schedule_task(
+        &self,
+        ctx: &RequestContext,
+        request: ScheduleTaskRequest,
+    ) -> TaskLifecycleResult<Task> {
+        let ScheduleTaskRequest {
+            task_id,
+            priority,
+            due_at,
+            required_capabilities,
+        } = request;
+
+        let parsed_priority = TaskPriority::try_from(priority.as_str())?;
+        let schedule =
+            TaskSchedule::new(parsed_priority).with_required_capabilities(required_capabilities);
+        let dated_schedule = match due_at {
+            Some(due) => schedule.with_due_at(due),
+            None => schedule,
+        };
+        let mut task = self.find_task_by_id_or_error(ctx, task_id).await?;
+        task.reschedule(dated_schedule, &*self.clock);
+        self.repository.update(ctx, &task).await?;
+        Ok(task)
+    }
+
+    /// Claims the most pressing queued task a worker offering
+    /// `worker_capabilities` can take, moving it to `in_progress`.
+    ///
+    /// Queued tasks are drafts; they are claimed by descending priority,
+    /// then earliest due date, then creation order. Returns `None` when no
+    /// queued task fits the worker.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskLifecycleError::QueueNotConfigured`] when no queue is
+    /// attached, or [`TaskLifecycleError::Queue`] when the claim fails.
+    pub async fn claim_next(
+        &self,
+        ctx: &RequestContext,
+        worker_capabilities: &[String],
+    ) -> TaskLifecycleResult<Option<Task>> {
+        let task_queue = self
+            .task_queue
+            .as_ref()
+            .ok_or(TaskLifecycleError::QueueNotConfigured)?;
+        let capabilities = normalize_capabilities(worker_capabilities);
+        let claimed = task_queue
+            .claim_next(ctx, &capabilities, self.clock.utc())
+            .await?;
+        if let Some(task) = &claimed {
+            self.publish_state_change(ctx, task, TaskState::Draft).await;
+        }
+        Ok(claimed)
+    }
+}
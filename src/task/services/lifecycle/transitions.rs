@@ -0,0 +1,105 @@
+//! State transitions of the task lifecycle, ordinary and forced.
+
+use super::{TaskLifecycleResult, TaskLifecycleService};
+use crate::context::RequestContext;
+use crate::operator::domain::{
+    OperatorAction, OperatorActionDetails, OperatorActionKind, OperatorReason, OperatorSubject,
+    OperatorTransition,
+};
+use crate::task::{
+    domain::{Task, TaskId, TaskState},
+    ports::TaskRepository,
+};
+use mockable::Clock;
+
+/// Request payload for transitioning an existing task to a new state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionTaskRequest {
+    task_id: TaskId,
+    target_state: String,
+}
+
+impl TransitionTaskRequest {
+    /// Creates a task state transition request.
+    #[must_use]
+    pub fn new(task_id: TaskId, target_state: impl Into<String>) -> Self {
+        Self {
+            task_id,
+            target_state: target_state.into(),
+        }
+    }
+}
+
+impl<R, C> TaskLifecycleService<R, C>
+where
+    R: TaskRepository,
+    C: Clock + Send + Sync,
+{
+    /// Transitions an existing task to a target state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskLifecycleError::InvalidState`] when `target_state`
+    /// cannot be parsed, [`TaskLifecycleError::Domain`] when transition
+    /// validation fails, or [`TaskLifecycleError::Repository`] when lookup
+    /// or persistence fails.
+    pub async fn transition_task(
+        &self,
+        ctx: &RequestContext,
+        request: TransitionTaskRequest,
+    ) -> TaskLifecycleResult<Task> {
+        let TransitionTaskRequest {
+            task_id,
+            target_state,
+        } = request;
+
+        let parsed_target_state = TaskState::try_from(target_state.as_str())?;
+        let mut task = self.find_task_by_id_or_error(ctx, task_id).await?;
+        let from = task.state();
+        task.transition_to(parsed_target_state, &*self.clock)?;
+        self.repository.update(ctx, &task).await?;
+        self.publish_state_change(ctx, &task, from).await;
+        Ok(task)
+    }
+
+    /// Moves an existing task to a target state on an operator's behalf,
+    /// bypassing the lifecycle's transition rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskLifecycleError::InvalidState`] when `target_state`
+    /// cannot be parsed, [`TaskLifecycleError::Domain`] when the task is
+    /// already in that state, [`TaskLifecycleError::Repository`] when lookup
+    /// or persistence fails, or [`TaskLifecycleError::OperatorActions`] when
+    /// the action cannot be recorded.
+    pub async fn force_transition_task(
+        &self,
+        ctx: &RequestContext,
+        request: TransitionTaskRequest,
+        reason: &OperatorReason,
+    ) -> TaskLifecycleResult<Task> {
+        let TransitionTaskRequest {
+            task_id,
+            target_state,
+        } = request;
+
+        let parsed_target_state = TaskState::try_from(target_state.as_str())?;
+        let mut task = self.find_task_by_id_or_error(ctx, task_id).await?;
+        let from = task.state();
+        task.force_transition_to(parsed_target_state, &*self.clock)?;
+        self.repository.update(ctx, &task).await?;
+
+        if let Some(operator_actions) = &self.operator_actions {
+            let details = OperatorActionDetails {
+                kind: OperatorActionKind::TaskForceTransitioned,
+                subject: OperatorSubject::Task { task_id },
+                reason: reason.clone(),
+                transition: Some(OperatorTransition::new(from, task.state())),
+            };
+            let action = OperatorAction::new(ctx, details, &*self.clock);
+            operator_actions.record(ctx, &action).await?;
+        }
+        self.publish_state_change(ctx, &task, from).await;
+        Ok(task)
+    }
+}
@@ -1,8 +1,22 @@
 //! Application services for task lifecycle orchestration.
 
+mod budget;
+mod cost;
+mod issue_sync;
 mod lifecycle;
+mod reconciliation;
+mod webhook;
 
+pub use budget::{DelegateBudgetRequest, UsageBudgetService};
+pub use cost::{LinkSubConversationRequest, TaskCostService};
+pub use issue_sync::{IssueStatusSync, IssueSyncError, IssueSyncReport, IssueSyncResult};
 pub use lifecycle::{
     AssociateBranchRequest, AssociatePullRequestRequest, CreateTaskFromIssueRequest,
-    TaskLifecycleError, TaskLifecycleService, TransitionTaskRequest,
+    ScheduleTaskRequest, TaskLifecycleError, TaskLifecycleService, TransitionTaskRequest,
+};
+pub use reconciliation::{
+    ReconciliationFailure, ReconciliationReport, TaskReconciliationJob, TaskReconciliationService,
+};
+pub use webhook::{
+    IssueWebhookError, IssueWebhookOutcome, IssueWebhookResult, IssueWebhookService,
 };
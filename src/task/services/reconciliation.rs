@@ -0,0 +1,300 @@
+//! Service layer for reconciling task branch and pull request associations.
+//!
+//! Provides [`TaskReconciliationService`], which checks each open task's
+//! associations against the VCS provider and updates task state when the
+//! provider shows the work has been merged or abandoned, and
+//! [`TaskReconciliationJob`], which runs the service on a fixed schedule and
+//! pauses while the cluster is in maintenance.
+
+use super::TaskLifecycleError;
+use crate::context::RequestContext;
+use crate::maintenance::services::MaintenanceGate;
+use crate::pagination::collect_pages;
+use crate::task::{
+    domain::{Task, TaskId, TaskReconciliation, VcsObservation},
+    ports::{TaskRepository, VcsStatusError, VcsStatusPort, VcsStatusResult},
+};
+use chrono::{DateTime, Utc};
+use futures::future;
+use mockable::Clock;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+/// Shortest interval accepted by [`TaskReconciliationJob`].
+const MIN_RECONCILIATION_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A task whose associations could not be checked during a run.
+#[derive(Debug, Clone)]
+pub struct ReconciliationFailure {
+    /// The task that was skipped.
+    pub task_id: TaskId,
+    /// The provider error encountered.
+    pub error: VcsStatusError,
+}
+
+/// Summary of a single reconciliation run.
+#[derive(Debug, Clone)]
+pub struct ReconciliationReport {
+    /// When the run started.
+    pub checked_at: DateTime<Utc>,
+    /// Number of tasks whose associations were checked.
+    pub tasks_checked: usize,
+    /// Tasks with at least one finding, including any state change applied.
+    pub reconciliations: Vec<TaskReconciliation>,
+    /// Tasks skipped because the provider could not be queried.
+    pub failures: Vec<ReconciliationFailure>,
+}
+
+impl ReconciliationReport {
+    /// Keeps the outcome when it carries at least one finding.
+    fn record(&mut self, outcome: TaskReconciliation) {
+        if outcome.has_findings() {
+            self.reconciliations.push(outcome);
+        }
+    }
+}
+
+/// Task branch and pull request reconciliation service.
+#[derive(Clone)]
+pub struct TaskReconciliationService<R, V, C>
+where
+    R: TaskRepository,
+    V: VcsStatusPort,
+    C: Clock + Send + Sync,
+{
+    repository: Arc<R>,
+    vcs: Arc<V>,
+    clock: Arc<C>,
+}
+
+impl<R, V, C> TaskReconciliationService<R, V, C>
+where
+    R: TaskRepository,
+    V: VcsStatusPort,
+    C: Clock + Send + Sync,
+{
+    /// Creates a new reconciliation service.
+    #[must_use]
+    pub const fn new(repository: Arc<R>, vcs: Arc<V>, clock: Arc<C>) -> Self {
+        Self {
+            repository,
+            vcs,
+            clock,
+        }
+    }
+
+    /// Checks every open task with a branch or pull request against the VCS
+    /// provider.
+    ///
+    /// Force-pushes are reported only when they happened after both `since`
+    /// and the task's last update. Provider failures for one task are
+    /// recorded in the report and do not stop the run.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskLifecycleError::Repository`] when tasks cannot be
+    /// loaded or a state change cannot be persisted.
+    pub async fn reconcile(
+        &self,
+        ctx: &RequestContext,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<ReconciliationReport, TaskLifecycleError> {
+        let checked_at = self.clock.utc();
+        let tasks = collect_pages(|page| self.repository.find_reconcilable(ctx, page)).await?;
+        let mut report = ReconciliationReport {
+            checked_at,
+            tasks_checked: tasks.len(),
+            reconciliations: Vec::new(),
+            failures: Vec::new(),
+        };
+
+        for task in tasks {
+            let task_id = task.id();
+            let task_since =
+                since.map_or_else(|| task.updated_at(), |value| value.max(task.updated_at()));
+            match self.observe(ctx, &task, task_since).await {
+                Ok(observation) => report.record(self.apply(ctx, task, observation).await?),
+                Err(error) => report
+                    .failures
+                    .push(ReconciliationFailure { task_id, error }),
+            }
+        }
+        Ok(report)
+    }
+
+    async fn observe(
+        &self,
+        ctx: &RequestContext,
+        task: &Task,
+        since: DateTime<Utc>,
+    ) -> VcsStatusResult<VcsObservation> {
+        let branch = match task.branch_ref() {
+            Some(branch_ref) => Some(self.vcs.branch_status(ctx, branch_ref, since).await?),
+            None => None,
+        };
+        let pull_request = match task.pull_request_ref() {
+            Some(pr_ref) => Some(self.vcs.pull_request_status(ctx, pr_ref).await?),
+            None => None,
+        };
+        Ok(VcsObservation {
+            branch,
+            pull_request,
+        })
+    }
+
+    async fn apply(
+        &self,
+        ctx: &RequestContext,
+        mut task: Task,
+        observation: VcsObservation,
+    ) -> Result<TaskReconciliation, TaskLifecycleError> {
+        let mut outcome = TaskReconciliation::observe(&task, observation);
+        let Some(target) = observation
+            .implied_state()
+            .filter(|target| task.state().can_transition_to(*target))
+        else {
+            return Ok(outcome);
+        };
+
+        task.transition_to(target, &*self.clock)?;
+        self.repository.update(ctx, &task).await?;
+        outcome.new_state = Some(target);
+        Ok(outcome)
+    }
+}
+
+/// Runs [`TaskReconciliationService`] on a fixed interval for one tenant.
+///
+/// Each run passes the start time of the previous successful run as the
+/// force-push cut-off, so a rewrite is reported once.
+///
+/// With a maintenance gate attached, scheduled runs are skipped while
+/// maintenance is enabled. A run already in progress finishes first.
+pub struct TaskReconciliationJob<R, V, C>
+where
+    R: TaskRepository,
+    V: VcsStatusPort,
+    C: Clock + Send + Sync,
+{
+    service: TaskReconciliationService<R, V, C>,
+    interval: Duration,
+    last_checked_at: Mutex<Option<DateTime<Utc>>>,
+    maintenance: Option<Arc<MaintenanceGate>>,
+}
+
+impl<R, V, C> TaskReconciliationJob<R, V, C>
+where
+    R: TaskRepository,
+    V: VcsStatusPort,
+    C: Clock + Send + Sync,
+{
+    /// Creates a job running every `interval`.
+    ///
+    /// Intervals shorter than one second are raised to one second.
+    #[must_use]
+    pub fn new(service: TaskReconciliationService<R, V, C>, interval: Duration) -> Self {
+        Self {
+            service,
+            interval: interval.max(MIN_RECONCILIATION_INTERVAL),
+            last_checked_at: Mutex::new(None),
+            maintenance: None,
+        }
+    }
+
+    /// Pauses scheduled runs while `gate` reports maintenance.
+    #[must_use]
+    pub fn with_maintenance(mut self, gate: Arc<MaintenanceGate>) -> Self {
+        self.maintenance = Some(gate);
+        self
+    }
+
+    /// Returns the interval between runs.
+    #[must_use]
+    pub const fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Performs a single reconciliation run.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskLifecycleError`] when the run fails; the force-push
+    /// cut-off is left unchanged so the next run covers the same window.
+    pub async fn run_once(
+        &self,
+        ctx: &RequestContext,
+    ) -> Result<ReconciliationReport, TaskLifecycleError> {
+        let since = *self
+            .last_checked_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let report = self.service.reconcile(ctx, since).await?;
+        *self
+            .last_checked_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(report.checked_at);
+        Ok(report)
+    }
+
+    /// Runs reconciliation every interval until `shutdown` completes.
+    ///
+    /// Findings, failed runs, and runs skipped for maintenance are emitted
+    /// as tracing events.
+    pub async fn run_until(&self, ctx: &RequestContext, shutdown: impl Future<Output = ()>) {
+        future::select(pin!(self.run_forever(ctx)), pin!(shutdown)).await;
+    }
+
+    async fn run_forever(&self, ctx: &RequestContext) {
+        let mut ticker = tokio::time::interval(self.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if self.paused_for_maintenance().await {
+                continue;
+            }
+            trace_run(&self.run_once(ctx).await);
+        }
+    }
+
+    async fn paused_for_maintenance(&self) -> bool {
+        let Some(gate) = self.maintenance.as_deref() else {
+            return false;
+        };
+        let paused = gate.is_active().await;
+        if paused {
+            tracing::info!("task reconciliation run skipped during maintenance");
+        }
+        paused
+    }
+}
+
+fn trace_run(result: &Result<ReconciliationReport, TaskLifecycleError>) {
+    match result {
+        Ok(report) => {
+            report.reconciliations.iter().for_each(trace_reconciliation);
+            report.failures.iter().for_each(trace_failure);
+        }
+        Err(err) => tracing::warn!(error = %err, "task reconciliation run failed"),
+    }
+}
+
+fn trace_reconciliation(reconciliation: &TaskReconciliation) {
+    for finding in &reconciliation.findings {
+        tracing::info!(
+            task_id = %reconciliation.task_id,
+            new_state = ?reconciliation.new_state,
+            "{finding}"
+        );
+    }
+}
+
+fn trace_failure(failure: &ReconciliationFailure) {
+    tracing::warn!(
+        task_id = %failure.task_id,
+        error = %failure.error,
+        "task reconciliation skipped"
+    );
+}
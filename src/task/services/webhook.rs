@@ -0,0 +1,293 @@
+//! Service layer applying issue provider webhook events to tasks.
+//!
+//! Provides [`IssueWebhookService`], which creates tasks for opened issues,
+//! links branches and pull requests to the tasks of the issues they close,
+//! and moves tasks through their lifecycle as issues and pull requests
+//! close. Each delivery is claimed before its event is applied, so a
+//! redelivered event is acknowledged without changing tasks twice; a
+//! delivery whose event fails is released so the provider's retry is
+//! applied. Comments and labels Corbusier wrote to report task state are
+//! ignored without being claimed, so status sync cannot feed back into the
+//! lifecycle.
+
+use super::{
+    AssociateBranchRequest, AssociatePullRequestRequest, TaskLifecycleError, TaskLifecycleService,
+    TransitionTaskRequest,
+};
+use crate::context::RequestContext;
+use crate::pagination::collect_pages;
+use crate::task::{
+    domain::{
+        BranchRef, ExternalIssue, IssueRef, IssueWebhookEvent, PullRequestRef, Task, TaskState,
+        WebhookDelivery, is_status_comment, is_status_label,
+    },
+    ports::{TaskRepository, TaskRepositoryError, WebhookDeliveryError, WebhookDeliveryLog},
+};
+use mockable::Clock;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Errors returned while handling a webhook delivery.
+#[derive(Debug, Error)]
+pub enum IssueWebhookError {
+    /// Applying the event to a task failed.
+    #[error(transparent)]
+    Lifecycle(#[from] TaskLifecycleError),
+    /// The delivery could not be claimed.
+    #[error(transparent)]
+    Deliveries(#[from] WebhookDeliveryError),
+}
+
+/// Result type for webhook handling.
+pub type IssueWebhookResult<T> = Result<T, IssueWebhookError>;
+
+/// What handling a webhook delivery did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueWebhookOutcome {
+    /// The delivery had already been handled; nothing changed.
+    Duplicate,
+    /// The event reports Corbusier's own status comment or label; nothing
+    /// changed and the delivery was not recorded.
+    Ignored,
+    /// The event was applied to the listed tasks, which may be none when no
+    /// task tracks the issue or pull request.
+    Applied(Vec<Task>),
+}
+
+/// Webhook-driven task lifecycle service.
+#[derive(Clone)]
+pub struct IssueWebhookService<R, C>
+where
+    R: TaskRepository,
+    C: Clock + Send + Sync,
+{
+    lifecycle: TaskLifecycleService<R, C>,
+    deliveries: Arc<dyn WebhookDeliveryLog>,
+    clock: Arc<C>,
+}
+
+impl<R, C> IssueWebhookService<R, C>
+where
+    R: TaskRepository,
+    C: Clock + Send + Sync,
+{
+    /// Creates a service applying events through `lifecycle` and recording
+    /// deliveries in `deliveries`.
+    #[must_use]
+    pub const fn new(
+        lifecycle: TaskLifecycleService<R, C>,
+        deliveries: Arc<dyn WebhookDeliveryLog>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            lifecycle,
+            deliveries,
+            clock,
+        }
+    }
+
+    /// Applies `event`, received in `delivery`, to the tasks it concerns.
+    ///
+    /// - An opened issue creates a draft task, unless one already exists.
+    /// - A closed issue moves its task to `done`, or to `abandoned` when it
+    ///   was closed as not planned.
+    /// - An opened pull request links its branch and itself to the tasks of
+    ///   the issues it closes, moving them to `in_review`.
+    /// - A closed pull request moves its tasks to `done` when merged and
+    ///   back to `in_progress` otherwise.
+    /// - Comments and label changes leave tasks unchanged. Those marked by
+    ///   [`is_status_comment`] or [`is_status_label`] are echoes of status
+    ///   sync and are answered with [`IssueWebhookOutcome::Ignored`].
+    ///
+    /// Transitions the lifecycle does not allow, such as completing a draft
+    /// task, are skipped rather than failing the delivery.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssueWebhookError::Deliveries`] when the delivery cannot be
+    /// claimed, or [`IssueWebhookError::Lifecycle`] when a task cannot be
+    /// read or updated.
+    pub async fn handle(
+        &self,
+        ctx: &RequestContext,
+        delivery: &WebhookDelivery,
+        event: IssueWebhookEvent,
+    ) -> IssueWebhookResult<IssueWebhookOutcome> {
+        if is_status_echo(&event) {
+            return Ok(IssueWebhookOutcome::Ignored);
+        }
+        if !self
+            .deliveries
+            .claim(ctx, delivery, self.clock.utc())
+            .await?
+        {
+            return Ok(IssueWebhookOutcome::Duplicate);
+        }
+        match self.apply(ctx, event).await {
+            Ok(tasks) => Ok(IssueWebhookOutcome::Applied(tasks)),
+            Err(err) => {
+                if let Err(release_err) = self.deliveries.release(ctx, delivery).await {
+                    tracing::warn!(
+                        delivery_id = delivery.delivery_id(),
+                        error = %release_err,
+                        "failed to release webhook delivery"
+                    );
+                }
+                Err(err.into())
+            }
+        }
+    }
+
+    async fn apply(
+        &self,
+        ctx: &RequestContext,
+        event: IssueWebhookEvent,
+    ) -> Result<Vec<Task>, TaskLifecycleError> {
+        match event {
+            IssueWebhookEvent::IssueOpened(issue) => self.open_issue(ctx, &issue).await,
+            IssueWebhookEvent::IssueClosed {
+                issue_ref,
+                completed,
+            } => {
+                let target = if completed {
+                    TaskState::Done
+                } else {
+                    TaskState::Abandoned
+                };
+                match self.lifecycle.find_by_issue_ref(ctx, &issue_ref).await? {
+                    Some(task) => self.move_tasks(ctx, vec![task], target).await,
+                    None => Ok(Vec::new()),
+                }
+            }
+            IssueWebhookEvent::PullRequestOpened {
+                pull_request,
+                branch,
+                closes,
+            } => {
+                self.link_pull_request(ctx, &pull_request, &branch, &closes)
+                    .await
+            }
+            IssueWebhookEvent::PullRequestClosed {
+                pull_request,
+                merged,
+            } => {
+                let target = if merged {
+                    TaskState::Done
+                } else {
+                    TaskState::InProgress
+                };
+                let tasks = collect_pages(|page| {
+                    self.lifecycle
+                        .find_by_pull_request_ref(ctx, &pull_request, page)
+                })
+                .await?;
+                self.move_tasks(ctx, tasks, target).await
+            }
+            IssueWebhookEvent::IssueCommented { .. }
+            | IssueWebhookEvent::IssueLabelChanged { .. } => Ok(Vec::new()),
+        }
+    }
+
+    async fn open_issue(
+        &self,
+        ctx: &RequestContext,
+        issue: &ExternalIssue,
+    ) -> Result<Vec<Task>, TaskLifecycleError> {
+        if self
+            .lifecycle
+            .find_by_issue_ref(ctx, issue.issue_ref())
+            .await?
+            .is_some()
+        {
+            return Ok(Vec::new());
+        }
+        match self.lifecycle.create_from_external_issue(ctx, issue).await {
+            Ok(task) => Ok(vec![task]),
+            Err(TaskLifecycleError::Repository(TaskRepositoryError::DuplicateIssueOrigin(_))) => {
+                Ok(Vec::new())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Moves each task to `target` where the lifecycle allows it.
+    async fn move_tasks(
+        &self,
+        ctx: &RequestContext,
+        tasks: Vec<Task>,
+        target: TaskState,
+    ) -> Result<Vec<Task>, TaskLifecycleError> {
+        let mut moved = Vec::new();
+        for task in tasks {
+            if !task.state().can_transition_to(target) {
+                continue;
+            }
+            let request = TransitionTaskRequest::new(task.id(), target.as_str());
+            moved.push(self.lifecycle.transition_task(ctx, request).await?);
+        }
+        Ok(moved)
+    }
+
+    async fn link_pull_request(
+        &self,
+        ctx: &RequestContext,
+        pull_request: &PullRequestRef,
+        branch: &BranchRef,
+        closes: &[IssueRef],
+    ) -> Result<Vec<Task>, TaskLifecycleError> {
+        let mut linked = Vec::new();
+        for issue_ref in closes {
+            let Some(task) = self.lifecycle.find_by_issue_ref(ctx, issue_ref).await? else {
+                continue;
+            };
+            let with_branch = if task.branch_ref().is_none() {
+                let request = AssociateBranchRequest::new(
+                    task.id(),
+                    branch.provider().as_str(),
+                    branch.repository().as_str(),
+                    branch.branch_name().as_str(),
+                );
+                self.lifecycle.associate_branch(ctx, request).await?
+            } else {
+                task
+            };
+            linked.push(
+                self.attach_pull_request(ctx, with_branch, pull_request)
+                    .await?,
+            );
+        }
+        Ok(linked)
+    }
+
+    /// Links `pull_request` to `task` unless it already has one or cannot
+    /// move into review.
+    async fn attach_pull_request(
+        &self,
+        ctx: &RequestContext,
+        task: Task,
+        pull_request: &PullRequestRef,
+    ) -> Result<Task, TaskLifecycleError> {
+        let reviewable = task.state() == TaskState::InReview
+            || task.state().can_transition_to(TaskState::InReview);
+        if task.pull_request_ref().is_some() || !reviewable {
+            return Ok(task);
+        }
+        let request = AssociatePullRequestRequest::new(
+            task.id(),
+            pull_request.provider().as_str(),
+            pull_request.repository().as_str(),
+            pull_request.pull_request_number().value(),
+        );
+        self.lifecycle.associate_pull_request(ctx, request).await
+    }
+}
+
+/// Returns whether `event` reports a comment or label written by status
+/// sync rather than by a user.
+fn is_status_echo(event: &IssueWebhookEvent) -> bool {
+    match event {
+        IssueWebhookEvent::IssueCommented { body, .. } => is_status_comment(body),
+        IssueWebhookEvent::IssueLabelChanged { label, .. } => is_status_label(label),
+        _ => false,
+    }
+}
@@ -3,6 +3,7 @@
 use std::sync::Arc;
 
 use crate::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+use crate::pagination::{Page, PageRequest};
 use crate::task::{
     adapters::memory::InMemoryTaskRepository,
     domain::{BranchRef, PullRequestRef, Task, TaskDomainError, TaskId, TaskState},
@@ -121,7 +122,7 @@ async fn associate_branch_persists_and_is_retrievable(service: TestService, ctx:
     let branch_ref = BranchRef::from_parts("github", "owner/repo", "feature/branch-test")
         .expect("valid branch ref");
     let found = service
-        .find_by_branch_ref(&ctx, &branch_ref)
+        .find_by_branch_ref(&ctx, &branch_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_single_task_found(&found, task.id());
@@ -227,7 +228,7 @@ async fn associate_pull_request_persists_and_transitions_to_in_review(
 
     let pr_ref = PullRequestRef::from_parts("github", "owner/repo", 42).expect("valid PR ref");
     let found = service
-        .find_by_pull_request_ref(&ctx, &pr_ref)
+        .find_by_pull_request_ref(&ctx, &pr_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_single_task_found(&found, task.id());
@@ -270,7 +271,7 @@ async fn assert_multiple_tasks_share_reference<F, Fut1, Fut2>(
 ) where
     F: FnOnce() -> Fut2,
     Fut1: std::future::Future<Output = Result<Task, TaskLifecycleError>>,
-    Fut2: std::future::Future<Output = Result<Vec<Task>, TaskLifecycleError>>,
+    Fut2: std::future::Future<Output = Result<Page<Task>, TaskLifecycleError>>,
 {
     let [ref first, ref second] = tasks;
 
@@ -317,7 +318,7 @@ async fn multiple_tasks_sharing_branch_all_returned(service: TestService, ctx: R
                 AssociateBranchRequest::new(task_id, "github", "owner/repo", "shared/branch"),
             )
         },
-        || service.find_by_branch_ref(&ctx, &branch_ref),
+        || service.find_by_branch_ref(&ctx, &branch_ref, PageRequest::default()),
     )
     .await;
 }
@@ -346,7 +347,7 @@ async fn multiple_tasks_sharing_pull_request_all_returned(
                 AssociatePullRequestRequest::new(task_id, "github", "owner/repo", 99),
             )
         },
-        || service.find_by_pull_request_ref(&ctx, &pr_ref),
+        || service.find_by_pull_request_ref(&ctx, &pr_ref, PageRequest::default()),
     )
     .await;
 }
@@ -0,0 +1,262 @@
+//! Tests for conversation budgets, delegation splits, and reconciliation.
+
+use std::sync::Arc;
+
+use crate::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+use crate::message::domain::{ConversationId, TurnId};
+use crate::task::{
+    adapters::memory::{InMemoryTaskCostLedger, InMemoryUsageBudgetRepository},
+    domain::{
+        BudgetShare, CostMicros, TaskDomainError, TokenUsage, UsageAllowance, UsageRecordParams,
+    },
+    ports::UsageBudgetError,
+    services::{DelegateBudgetRequest, TaskCostService, UsageBudgetService},
+};
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+
+type TestCosts = TaskCostService<InMemoryTaskCostLedger, DefaultClock>;
+type TestBudgets =
+    UsageBudgetService<InMemoryTaskCostLedger, InMemoryUsageBudgetRepository, DefaultClock>;
+
+struct Harness {
+    costs: TestCosts,
+    budgets: TestBudgets,
+}
+
+#[fixture]
+fn ctx() -> RequestContext {
+    RequestContext::new(
+        TenantId::new(),
+        CorrelationId::new(),
+        UserId::new(),
+        SessionId::new(),
+    )
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let ledger = Arc::new(InMemoryTaskCostLedger::new());
+    let clock = Arc::new(DefaultClock);
+    Harness {
+        costs: TaskCostService::new(ledger.clone(), clock.clone()),
+        budgets: UsageBudgetService::new(
+            ledger,
+            Arc::new(InMemoryUsageBudgetRepository::new()),
+            clock,
+        ),
+    }
+}
+
+const fn allowance(tokens: u64, micros: u64) -> UsageAllowance {
+    UsageAllowance::new(tokens, CostMicros::new(micros))
+}
+
+async fn spend(
+    harness: &Harness,
+    ctx: &RequestContext,
+    (conversation_id, tokens, micros): (ConversationId, u64, u64),
+) {
+    harness
+        .costs
+        .record_turn_usage(
+            ctx,
+            UsageRecordParams {
+                conversation_id,
+                turn_id: TurnId::new(),
+                agent_backend: "claude_code_sdk".to_owned(),
+                usage: TokenUsage::new(tokens, 0),
+                cost: CostMicros::new(micros),
+            },
+        )
+        .await
+        .expect("record usage");
+}
+
+#[rstest]
+#[case::lower_bound(0)]
+#[case::upper_bound(101)]
+fn budget_shares_must_be_a_percentage(#[case] percent: u8) {
+    assert_eq!(
+        BudgetShare::percent(percent),
+        Err(TaskDomainError::InvalidBudgetShare(percent))
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn child_receives_a_fraction_of_the_parents_remaining_budget(
+    harness: Harness,
+    ctx: RequestContext,
+) {
+    let parent = ConversationId::new();
+    let child = ConversationId::new();
+    harness
+        .budgets
+        .allocate(&ctx, parent, allowance(100_000, 1_000_000))
+        .await
+        .expect("allocate");
+    spend(&harness, &ctx, (parent, 20_000, 200_000)).await;
+
+    let delegated = harness
+        .budgets
+        .delegate(
+            &ctx,
+            DelegateBudgetRequest::new(parent, child, BudgetShare::percent(50).expect("share")),
+        )
+        .await
+        .expect("delegate");
+    let parent_status = harness.budgets.status(&ctx, parent).await.expect("status");
+
+    assert_eq!(delegated.parent_conversation_id, Some(parent));
+    assert_eq!(delegated.limit, allowance(40_000, 400_000));
+    assert_eq!(parent_status.reserved, allowance(40_000, 400_000));
+    assert_eq!(parent_status.remaining, allowance(40_000, 400_000));
+}
+
+#[rstest]
+#[tokio::test]
+async fn fixed_slices_must_fit_in_the_parents_remaining_budget(
+    harness: Harness,
+    ctx: RequestContext,
+) {
+    let parent = ConversationId::new();
+    harness
+        .budgets
+        .allocate(&ctx, parent, allowance(10_000, 100_000))
+        .await
+        .expect("allocate");
+
+    let result = harness
+        .budgets
+        .delegate(
+            &ctx,
+            DelegateBudgetRequest::new(
+                parent,
+                ConversationId::new(),
+                BudgetShare::fixed(allowance(20_000, 50_000)),
+            ),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(UsageBudgetError::Domain(TaskDomainError::InsufficientBudget(id))) if id == parent
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn exhausted_budgets_stop_further_turns(harness: Harness, ctx: RequestContext) {
+    let parent = ConversationId::new();
+    let child = ConversationId::new();
+    let unbudgeted = ConversationId::new();
+    harness
+        .budgets
+        .allocate(&ctx, parent, allowance(10_000, 100_000))
+        .await
+        .expect("allocate");
+    harness
+        .budgets
+        .delegate(
+            &ctx,
+            DelegateBudgetRequest::new(parent, child, BudgetShare::fixed(allowance(4_000, 40_000))),
+        )
+        .await
+        .expect("delegate");
+
+    let before = harness.budgets.ensure_within_budget(&ctx, child).await;
+    spend(&harness, &ctx, (child, 4_500, 30_000)).await;
+    let after = harness.budgets.ensure_within_budget(&ctx, child).await;
+    let free = harness.budgets.ensure_within_budget(&ctx, unbudgeted).await;
+
+    assert!(matches!(before, Ok(Some(status)) if status.remaining == allowance(4_000, 40_000)));
+    assert!(matches!(after, Err(UsageBudgetError::Exhausted(id)) if id == child));
+    assert!(matches!(free, Ok(None)));
+}
+
+#[rstest]
+#[tokio::test]
+async fn reconciliation_charges_the_parent_with_what_the_child_used(
+    harness: Harness,
+    ctx: RequestContext,
+) {
+    let parent = ConversationId::new();
+    let child = ConversationId::new();
+    harness
+        .budgets
+        .allocate(&ctx, parent, allowance(100_000, 1_000_000))
+        .await
+        .expect("allocate");
+    harness
+        .budgets
+        .delegate(
+            &ctx,
+            DelegateBudgetRequest::new(
+                parent,
+                child,
+                BudgetShare::fixed(allowance(30_000, 300_000)),
+            ),
+        )
+        .await
+        .expect("delegate");
+    spend(&harness, &ctx, (child, 12_000, 90_000)).await;
+
+    let reconciled = harness
+        .budgets
+        .reconcile(&ctx, child)
+        .await
+        .expect("reconcile");
+    let repeated = harness
+        .budgets
+        .reconcile(&ctx, child)
+        .await
+        .expect("reconcile again");
+    let closed = harness.budgets.ensure_within_budget(&ctx, child).await;
+
+    assert_eq!(reconciled.reserved, UsageAllowance::default());
+    assert_eq!(reconciled.consumed, allowance(12_000, 90_000));
+    assert_eq!(reconciled.remaining, allowance(88_000, 910_000));
+    assert_eq!(repeated, reconciled);
+    assert!(matches!(closed, Err(UsageBudgetError::Closed(id)) if id == child));
+}
+
+#[rstest]
+#[tokio::test]
+async fn children_with_open_delegations_cannot_be_reconciled(
+    harness: Harness,
+    ctx: RequestContext,
+) {
+    let parent = ConversationId::new();
+    let child = ConversationId::new();
+    let grandchild = ConversationId::new();
+    let half = BudgetShare::percent(50).expect("share");
+    harness
+        .budgets
+        .allocate(&ctx, parent, allowance(100_000, 1_000_000))
+        .await
+        .expect("allocate");
+    for (from, to) in [(parent, child), (child, grandchild)] {
+        harness
+            .budgets
+            .delegate(&ctx, DelegateBudgetRequest::new(from, to, half))
+            .await
+            .expect("delegate");
+    }
+
+    let early = harness.budgets.reconcile(&ctx, child).await;
+    harness
+        .budgets
+        .reconcile(&ctx, grandchild)
+        .await
+        .expect("reconcile grandchild");
+    let late = harness.budgets.reconcile(&ctx, child).await;
+    let top_level = harness.budgets.reconcile(&ctx, parent).await;
+
+    assert!(matches!(
+        early,
+        Err(UsageBudgetError::Domain(TaskDomainError::OpenBudgetDelegations(id))) if id == child
+    ));
+    assert!(matches!(late, Ok(status) if status.remaining == allowance(100_000, 1_000_000)));
+    assert!(matches!(top_level, Err(UsageBudgetError::NotDelegated(id)) if id == parent));
+}
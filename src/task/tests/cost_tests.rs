@@ -0,0 +1,207 @@
+//! Tests for turn-level usage recording and task cost attribution.
+
+use std::sync::Arc;
+
+use crate::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+use crate::message::domain::{ConversationId, TurnId};
+use crate::task::{
+    adapters::memory::InMemoryTaskCostLedger,
+    domain::{ConversationLinkKind, CostMicros, TaskId, TokenUsage, UsageRecordParams},
+    ports::TaskCostError,
+    services::{LinkSubConversationRequest, TaskCostService},
+};
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+
+type TestService = TaskCostService<InMemoryTaskCostLedger, DefaultClock>;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    RequestContext::new(
+        TenantId::new(),
+        CorrelationId::new(),
+        UserId::new(),
+        SessionId::new(),
+    )
+}
+
+#[fixture]
+fn service() -> TestService {
+    TaskCostService::new(
+        Arc::new(InMemoryTaskCostLedger::new()),
+        Arc::new(DefaultClock),
+    )
+}
+
+fn usage(conversation_id: ConversationId, tokens: u64, micros: u64) -> UsageRecordParams {
+    UsageRecordParams {
+        conversation_id,
+        turn_id: TurnId::new(),
+        agent_backend: "claude_code_sdk".to_owned(),
+        usage: TokenUsage::new(tokens, 0),
+        cost: CostMicros::new(micros),
+    }
+}
+
+#[rstest]
+#[case::rounds_down(4_324_999, "$4.32")]
+#[case::rounds_up(4_325_000, "$4.33")]
+#[case::zero(0, "$0.00")]
+fn cost_displays_as_dollars_and_cents(#[case] micros: u64, #[case] expected: &str) {
+    assert_eq!(CostMicros::new(micros).to_string(), expected);
+}
+
+#[rstest]
+#[tokio::test]
+async fn report_rolls_up_primary_handoff_and_delegation_conversations(
+    service: TestService,
+    ctx: RequestContext,
+) {
+    let task_id = TaskId::new();
+    let primary = ConversationId::new();
+    let handoff = ConversationId::new();
+    let delegated = ConversationId::new();
+
+    service
+        .link_primary_conversation(&ctx, task_id, primary)
+        .await
+        .expect("link primary");
+    service
+        .link_sub_conversation(
+            &ctx,
+            LinkSubConversationRequest::new(primary, handoff, ConversationLinkKind::Handoff),
+        )
+        .await
+        .expect("link handoff");
+    let nested = service
+        .link_sub_conversation(
+            &ctx,
+            LinkSubConversationRequest::new(handoff, delegated, ConversationLinkKind::Delegation),
+        )
+        .await
+        .expect("link delegation");
+    assert_eq!(nested.task_id, task_id);
+    assert_eq!(nested.parent_conversation_id, Some(handoff));
+
+    for params in [
+        usage(primary, 100_000, 2_000_000),
+        usage(primary, 50_000, 1_000_000),
+        usage(handoff, 40_000, 800_000),
+        usage(delegated, 20_000, 520_000),
+    ] {
+        service
+            .record_turn_usage(&ctx, params)
+            .await
+            .expect("record usage");
+    }
+
+    let report = service
+        .task_cost_report(&ctx, task_id)
+        .await
+        .expect("report");
+
+    assert_eq!(report.conversations.len(), 3);
+    assert_eq!(report.total_turns, 4);
+    assert_eq!(report.total_usage.total(), 210_000);
+    assert_eq!(report.total_cost.to_string(), "$4.32");
+    let primary_line = report.conversations.first().expect("primary line");
+    assert_eq!(primary_line.link.kind, ConversationLinkKind::Primary);
+    assert_eq!(primary_line.turn_count, 2);
+}
+
+#[rstest]
+#[tokio::test]
+async fn usage_recorded_before_linking_is_attributed(service: TestService, ctx: RequestContext) {
+    let task_id = TaskId::new();
+    let conversation_id = ConversationId::new();
+    service
+        .record_turn_usage(&ctx, usage(conversation_id, 10, 1_000))
+        .await
+        .expect("record usage");
+
+    service
+        .link_primary_conversation(&ctx, task_id, conversation_id)
+        .await
+        .expect("link primary");
+    let report = service
+        .task_cost_report(&ctx, task_id)
+        .await
+        .expect("report");
+
+    assert_eq!(report.total_cost, CostMicros::new(1_000));
+}
+
+#[rstest]
+#[tokio::test]
+async fn linking_to_a_second_task_is_rejected(service: TestService, ctx: RequestContext) {
+    let first_task = TaskId::new();
+    let conversation_id = ConversationId::new();
+    service
+        .link_primary_conversation(&ctx, first_task, conversation_id)
+        .await
+        .expect("link primary");
+    service
+        .link_primary_conversation(&ctx, first_task, conversation_id)
+        .await
+        .expect("re-linking to the same task is a no-op");
+
+    let result = service
+        .link_primary_conversation(&ctx, TaskId::new(), conversation_id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(TaskCostError::ConversationAlreadyLinked { task_id, .. }) if task_id == first_task
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn sub_conversation_requires_linked_parent(service: TestService, ctx: RequestContext) {
+    let parent = ConversationId::new();
+
+    let result = service
+        .link_sub_conversation(
+            &ctx,
+            LinkSubConversationRequest::new(
+                parent,
+                ConversationId::new(),
+                ConversationLinkKind::Delegation,
+            ),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(TaskCostError::ConversationNotLinked(id)) if id == parent
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn report_is_tenant_scoped(service: TestService, ctx: RequestContext) {
+    let task_id = TaskId::new();
+    let conversation_id = ConversationId::new();
+    service
+        .link_primary_conversation(&ctx, task_id, conversation_id)
+        .await
+        .expect("link primary");
+    service
+        .record_turn_usage(&ctx, usage(conversation_id, 10, 1_000))
+        .await
+        .expect("record usage");
+
+    let other_tenant = RequestContext::new(
+        TenantId::new(),
+        CorrelationId::new(),
+        UserId::new(),
+        SessionId::new(),
+    );
+    let report = service
+        .task_cost_report(&other_tenant, task_id)
+        .await
+        .expect("report");
+
+    assert!(report.conversations.is_empty());
+    assert_eq!(report.total_cost, CostMicros::default());
+}
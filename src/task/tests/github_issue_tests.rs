@@ -0,0 +1,248 @@
+//! Tests for the GitHub issue provider client against a canned HTTP server.
+
+use std::time::Duration;
+
+use super::http_stub::serve;
+use crate::context::RequestContext;
+use crate::task::{
+    adapters::github::{GitHubConfig, GitHubIssueClient},
+    domain::{IssueRef, IssueSnapshot, IssueStatusUpdate, TaskId, TaskState},
+    ports::{IssueNotifier, IssueProviderClient, IssueProviderError},
+};
+use crate::test_support::test_request_ctx;
+use chrono::DateTime;
+use rstest::{fixture, rstest};
+use serde_json::{Value, json};
+
+fn client(url: &str) -> GitHubIssueClient {
+    GitHubIssueClient::new(GitHubConfig::new("t0ken").with_api_url(url)).expect("client")
+}
+
+fn widgets_issue() -> IssueRef {
+    IssueRef::from_parts("github", "octo/widgets", 7).expect("issue ref")
+}
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+#[rstest]
+#[tokio::test]
+async fn issues_map_into_snapshots(ctx: RequestContext) {
+    let (url, server) = serve(vec![(
+        200,
+        Vec::new(),
+        json!({
+            "number": 7,
+            "title": " Crash on save ",
+            "body": "Steps to reproduce.",
+            "labels": [{"name": "bug"}, {"name": "p1"}],
+            "assignees": [{"login": "hubot"}],
+            "milestone": {"title": "v1.2"},
+        }),
+    )]);
+
+    let snapshot = client(&url)
+        .fetch_snapshot(&ctx, &widgets_issue())
+        .await
+        .expect("snapshot");
+
+    assert_eq!(
+        snapshot,
+        IssueSnapshot {
+            title: "Crash on save".to_owned(),
+            description: Some("Steps to reproduce.".to_owned()),
+            labels: vec!["bug".to_owned(), "p1".to_owned()],
+            assignees: vec!["hubot".to_owned()],
+            milestone: Some("v1.2".to_owned()),
+        }
+    );
+    let requests = server.join().expect("server");
+    let head = &requests.first().expect("one request").head;
+    assert!(head.starts_with("get /repos/octo/widgets/issues/7 http/1.1"));
+    assert!(head.contains("authorization: bearer t0ken"));
+    assert!(head.contains("accept: application/vnd.github+json"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn comments_are_read_across_pages(ctx: RequestContext) {
+    let full_page: Vec<Value> = (0..100)
+        .map(|index| {
+            json!({
+                "user": {"login": "hubot"},
+                "body": format!("comment {index}"),
+                "created_at": "2026-01-01T00:00:00Z",
+            })
+        })
+        .collect();
+    let (url, server) = serve(vec![
+        (200, Vec::new(), Value::Array(full_page)),
+        (
+            200,
+            Vec::new(),
+            json!([{"user": null, "body": "last", "created_at": "2026-01-02T00:00:00Z"}]),
+        ),
+    ]);
+
+    let comments = client(&url)
+        .list_comments(&ctx, &widgets_issue())
+        .await
+        .expect("comments");
+
+    assert_eq!(comments.len(), 101);
+    let last = comments.last().expect("last comment");
+    assert_eq!((last.author.as_deref(), last.body.as_str()), (None, "last"));
+    let requests = server.join().expect("server");
+    assert!(
+        requests
+            .get(1)
+            .is_some_and(|request| request.head.contains("/comments?per_page=100&page=2 "))
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn exhausted_rate_limits_report_the_reset(ctx: RequestContext) {
+    let (url, server) = serve(vec![(
+        403,
+        vec![
+            ("x-ratelimit-remaining", "0".to_owned()),
+            ("x-ratelimit-reset", "1767225600".to_owned()),
+        ],
+        json!({"message": "API rate limit exceeded"}),
+    )]);
+
+    let result = client(&url).list_labels(&ctx, &widgets_issue()).await;
+
+    assert!(matches!(
+        result,
+        Err(IssueProviderError::RateLimited { reset_at })
+            if reset_at == DateTime::from_timestamp(1_767_225_600, 0)
+    ));
+    server.join().expect("server");
+}
+
+#[rstest]
+#[tokio::test]
+async fn short_rate_limits_are_waited_out(ctx: RequestContext) {
+    let (url, server) = serve(vec![
+        (
+            429,
+            vec![("retry-after", "0".to_owned())],
+            json!({"message": "secondary rate limit"}),
+        ),
+        (200, Vec::new(), json!([{"name": "bug"}])),
+    ]);
+    let patient = GitHubIssueClient::new(
+        GitHubConfig::new("t0ken")
+            .with_api_url(&url)
+            .with_max_rate_limit_wait(Duration::from_secs(1)),
+    )
+    .expect("client");
+
+    let labels = patient
+        .list_labels(&ctx, &widgets_issue())
+        .await
+        .expect("labels");
+
+    assert_eq!(labels, ["bug"]);
+    assert_eq!(server.join().expect("server").len(), 2);
+}
+
+#[rstest]
+#[case::missing(404, json!({"message": "Not Found"}))]
+#[case::pull_request(200, json!({"title": "Fix", "pull_request": {"url": "https://example.test"}}))]
+#[tokio::test]
+async fn missing_issues_and_pull_requests_are_not_found(
+    ctx: RequestContext,
+    #[case] status: u16,
+    #[case] body: Value,
+) {
+    let (url, server) = serve(vec![(status, Vec::new(), body)]);
+
+    let result = client(&url).fetch_issue(&ctx, &widgets_issue()).await;
+
+    assert!(matches!(result, Err(IssueProviderError::NotFound(_))));
+    server.join().expect("server");
+}
+
+#[rstest]
+#[tokio::test]
+async fn other_providers_are_rejected_without_a_request(ctx: RequestContext) {
+    let gitlab = IssueRef::from_parts("gitlab", "octo/widgets", 7).expect("issue ref");
+
+    let result = client("http://127.0.0.1:9")
+        .fetch_issue(&ctx, &gitlab)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(IssueProviderError::UnsupportedProvider(_))
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn status_updates_comment_and_replace_the_status_label(ctx: RequestContext) {
+    let (url, server) = serve(vec![
+        (
+            200,
+            Vec::new(),
+            json!([{"name": "bug"}, {"name": "corbusier:in-progress"}]),
+        ),
+        (201, Vec::new(), json!({"id": 1})),
+        (200, Vec::new(), json!([{"name": "bug"}])),
+        (
+            200,
+            Vec::new(),
+            json!([{"name": "bug"}, {"name": "corbusier:done"}]),
+        ),
+    ]);
+    let update = IssueStatusUpdate::new(TaskId::new(), widgets_issue(), TaskState::Done);
+
+    client(&url).notify(&ctx, &update).await.expect("notify");
+
+    let requests = server.join().expect("server");
+    let heads: Vec<&str> = requests
+        .iter()
+        .filter_map(|request| request.head.lines().next())
+        .collect();
+    assert_eq!(
+        heads,
+        [
+            "get /repos/octo/widgets/issues/7/labels?per_page=100&page=1 http/1.1",
+            "post /repos/octo/widgets/issues/7/comments http/1.1",
+            "delete /repos/octo/widgets/issues/7/labels/corbusier:in-progress http/1.1",
+            "post /repos/octo/widgets/issues/7/labels http/1.1",
+        ]
+    );
+    let bodies: Vec<Value> = requests
+        .iter()
+        .filter(|request| !request.body.is_empty())
+        .map(|request| serde_json::from_str(&request.body).expect("json body"))
+        .collect();
+    assert_eq!(
+        bodies,
+        [
+            json!({"body": update.comment_body()}),
+            json!({"labels": ["corbusier:done"]}),
+        ]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn status_updates_already_shown_are_not_repeated(ctx: RequestContext) {
+    let (url, server) = serve(vec![(
+        200,
+        Vec::new(),
+        json!([{"name": "corbusier:in-review"}]),
+    )]);
+    let update = IssueStatusUpdate::new(TaskId::new(), widgets_issue(), TaskState::InReview);
+
+    client(&url).notify(&ctx, &update).await.expect("notify");
+
+    assert_eq!(server.join().expect("server").len(), 1);
+}
@@ -0,0 +1,72 @@
+//! Canned HTTP server for issue provider client tests.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread::{self, JoinHandle};
+
+use serde_json::Value;
+
+/// One canned response: status, extra headers, and JSON body.
+pub(super) type Reply = (u16, Vec<(&'static str, String)>, Value);
+
+/// A request received by the stub server.
+pub(super) struct Received {
+    /// Request line and headers, lower-cased.
+    pub(super) head: String,
+    /// Request body, as sent.
+    pub(super) body: String,
+}
+
+/// Serves `replies` in order, one per connection, and returns the requests
+/// it received.
+pub(super) fn serve(replies: Vec<Reply>) -> (String, JoinHandle<Vec<Received>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind stub server");
+    let url = format!("http://{}", listener.local_addr().expect("stub address"));
+    let handle = thread::spawn(move || {
+        replies
+            .into_iter()
+            .map(|(status, headers, body)| {
+                let (mut stream, _) = listener.accept().expect("accept request");
+                let received = read_request(&mut stream);
+                let payload = body.to_string();
+                let extra: String = headers
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}\r\n"))
+                    .collect();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n{extra}\r\n{payload}",
+                    payload.len()
+                )
+                .expect("write response");
+                received
+            })
+            .collect()
+    });
+    (url, handle)
+}
+
+/// Reads a request line, its headers, and a body of the declared length.
+fn read_request(stream: &mut TcpStream) -> Received {
+    let mut head = String::new();
+    let mut reader = BufReader::new(stream);
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).expect("read request");
+        if line.trim_end().is_empty() {
+            break;
+        }
+        head.push_str(&line.to_ascii_lowercase());
+    }
+    let length = head
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length:"))
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).expect("read request body");
+    Received {
+        head,
+        body: String::from_utf8(body).expect("utf-8 request body"),
+    }
+}
@@ -0,0 +1,262 @@
+//! Tests for posting task state back to originating issues.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::context::RequestContext;
+use crate::task::{
+    adapters::memory::{
+        InMemoryIssueNotificationQueue, InMemoryIssueProvider, InMemoryTaskRepository,
+    },
+    domain::{
+        ExternalIssue, ExternalIssueMetadata, IssueProvider, IssueRef, IssueStatusUpdate, TaskId,
+        TaskState, is_status_comment, is_status_label,
+    },
+    ports::{
+        IssueNotificationQueue, IssueNotifier, IssueProviderClient, IssueProviderError,
+        IssueProviderResult,
+    },
+    services::{IssueStatusSync, IssueSyncReport, TaskLifecycleService, TransitionTaskRequest},
+};
+use crate::test_support::test_request_ctx;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+
+/// Notifier failing with scripted errors before succeeding.
+struct ScriptedNotifier {
+    failures: Mutex<VecDeque<IssueProviderError>>,
+    delivered: Mutex<Vec<IssueStatusUpdate>>,
+}
+
+impl ScriptedNotifier {
+    fn failing(failures: impl IntoIterator<Item = IssueProviderError>) -> Arc<Self> {
+        Arc::new(Self {
+            failures: Mutex::new(failures.into_iter().collect()),
+            delivered: Mutex::default(),
+        })
+    }
+
+    fn delivered(&self) -> Vec<IssueStatusUpdate> {
+        self.delivered
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+#[async_trait]
+impl IssueNotifier for ScriptedNotifier {
+    async fn notify(
+        &self,
+        _ctx: &RequestContext,
+        update: &IssueStatusUpdate,
+    ) -> IssueProviderResult<()> {
+        let failure = self
+            .failures
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop_front();
+        if let Some(err) = failure {
+            return Err(err);
+        }
+        self.delivered
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(update.clone());
+        Ok(())
+    }
+}
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+fn issue_ref() -> IssueRef {
+    IssueRef::from_parts("github", "octo/widgets", 7).expect("issue ref")
+}
+
+fn update(state: TaskState) -> IssueStatusUpdate {
+    IssueStatusUpdate::new(TaskId::new(), issue_ref(), state)
+}
+
+fn unavailable() -> IssueProviderError {
+    IssueProviderError::provider(std::io::Error::other("connection reset"))
+}
+
+fn far_future(now: DateTime<Utc>) -> DateTime<Utc> {
+    now + TimeDelta::days(1)
+}
+
+#[rstest]
+#[tokio::test]
+async fn transitions_are_shown_on_the_originating_issue(ctx: RequestContext) {
+    let provider = Arc::new(InMemoryIssueProvider::new());
+    let metadata = ExternalIssueMetadata::new("Crash on save")
+        .expect("metadata")
+        .with_labels(["bug".to_owned()]);
+    let issue = ExternalIssue::new(issue_ref(), metadata);
+    provider.insert_issue(issue.clone());
+    let queue = Arc::new(InMemoryIssueNotificationQueue::new());
+    let lifecycle = TaskLifecycleService::new(
+        Arc::new(InMemoryTaskRepository::new()),
+        Arc::new(DefaultClock),
+    )
+    .with_issue_sync(IssueStatusSync::new(
+        IssueProvider::GitHub,
+        provider.clone(),
+        queue.clone(),
+    ));
+
+    let task = lifecycle
+        .create_from_external_issue(&ctx, &issue)
+        .await
+        .expect("task");
+    for state in ["in_progress", "paused", "in_progress", "in_review"] {
+        lifecycle
+            .transition_task(&ctx, TransitionTaskRequest::new(task.id(), state))
+            .await
+            .expect("transition");
+    }
+
+    let labels = provider
+        .list_labels(&ctx, &issue_ref())
+        .await
+        .expect("labels");
+    let comments = provider
+        .list_comments(&ctx, &issue_ref())
+        .await
+        .expect("comments");
+    assert_eq!(labels, ["bug", "corbusier:in-review"]);
+    assert_eq!(comments.len(), 2);
+    assert!(
+        comments
+            .iter()
+            .all(|comment| is_status_comment(&comment.body))
+    );
+    let pending = queue
+        .due(&ctx, far_future(DefaultClock.utc()), 10)
+        .await
+        .expect("due");
+    assert!(pending.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn transient_failures_are_retried_with_backoff(ctx: RequestContext) {
+    let notifier = ScriptedNotifier::failing([unavailable()]);
+    let sync = IssueStatusSync::new(
+        IssueProvider::GitHub,
+        notifier.clone(),
+        Arc::new(InMemoryIssueNotificationQueue::new()),
+    );
+    let now = DefaultClock.utc();
+
+    sync.notify(&ctx, update(TaskState::Done), now)
+        .await
+        .expect("queued");
+    let early = sync.retry_due(&ctx, now, 10).await.expect("retry");
+    let later = sync
+        .retry_due(&ctx, now + TimeDelta::seconds(30), 10)
+        .await
+        .expect("retry");
+
+    assert_eq!(early, IssueSyncReport::default());
+    assert_eq!(later.delivered, 1);
+    assert_eq!(
+        notifier
+            .delivered()
+            .iter()
+            .map(IssueStatusUpdate::state)
+            .collect::<Vec<_>>(),
+        [TaskState::Done]
+    );
+}
+
+#[rstest]
+#[case::refused(vec![IssueProviderError::NotFound(issue_ref())], 1)]
+#[case::out_of_attempts(vec![unavailable(), unavailable()], 2)]
+#[tokio::test]
+async fn failing_updates_are_dropped(
+    ctx: RequestContext,
+    #[case] failures: Vec<IssueProviderError>,
+    #[case] max_attempts: u32,
+) {
+    let queue = Arc::new(InMemoryIssueNotificationQueue::new());
+    let sync = IssueStatusSync::new(
+        IssueProvider::GitHub,
+        ScriptedNotifier::failing(failures),
+        queue.clone(),
+    )
+    .with_retry_policy(max_attempts, TimeDelta::seconds(1));
+    let now = DefaultClock.utc();
+
+    sync.notify(&ctx, update(TaskState::InReview), now)
+        .await
+        .expect("queued");
+    sync.retry_due(&ctx, far_future(now), 10)
+        .await
+        .expect("retry");
+
+    let pending = queue.due(&ctx, far_future(now), 10).await.expect("due");
+    assert!(pending.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn newer_updates_replace_queued_ones(ctx: RequestContext) {
+    let notifier = ScriptedNotifier::failing([unavailable(), unavailable()]);
+    let queue = Arc::new(InMemoryIssueNotificationQueue::new());
+    let sync = IssueStatusSync::new(IssueProvider::GitHub, notifier.clone(), queue.clone());
+    let now = DefaultClock.utc();
+    let started = update(TaskState::InProgress);
+    let reviewing = IssueStatusUpdate::new(started.task_id(), issue_ref(), TaskState::InReview);
+
+    sync.notify(&ctx, started, now).await.expect("queued");
+    sync.notify(&ctx, reviewing.clone(), now)
+        .await
+        .expect("queued");
+    let report = sync
+        .retry_due(&ctx, far_future(now), 10)
+        .await
+        .expect("retry");
+
+    assert_eq!(report.delivered, 1);
+    assert_eq!(notifier.delivered(), [reviewing]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn updates_without_a_provider_notifier_are_skipped(ctx: RequestContext) {
+    let notifier = ScriptedNotifier::failing([]);
+    let queue = Arc::new(InMemoryIssueNotificationQueue::new());
+    let sync = IssueStatusSync::new(IssueProvider::GitHub, notifier.clone(), queue.clone());
+    let jira_issue =
+        IssueRef::from_jira_key("acme", &"PROJ-12".parse().expect("key")).expect("issue ref");
+    let now = DefaultClock.utc();
+
+    sync.notify(
+        &ctx,
+        IssueStatusUpdate::new(TaskId::new(), jira_issue, TaskState::Done),
+        now,
+    )
+    .await
+    .expect("skipped");
+
+    assert!(notifier.delivered().is_empty());
+    let pending = queue.due(&ctx, far_future(now), 10).await.expect("due");
+    assert!(pending.is_empty());
+}
+
+#[rstest]
+fn status_writes_carry_markers() {
+    let reviewing = update(TaskState::InReview);
+
+    assert_eq!(reviewing.status_label(), "corbusier:in-review");
+    assert!(is_status_label(&reviewing.status_label()));
+    assert!(is_status_comment(&reviewing.comment_body()));
+    assert!(!is_status_label("bug"));
+    assert!(!is_status_comment("Task moved to review."));
+}
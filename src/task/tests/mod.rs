@@ -2,6 +2,15 @@
 
 mod branch_pr_service_tests;
 mod branch_pr_tests;
+mod budget_tests;
+mod cost_tests;
 mod domain_tests;
+mod github_issue_tests;
+mod http_stub;
+mod issue_sync_tests;
+mod jira_issue_tests;
+mod reconciliation_tests;
+mod scheduling_tests;
 mod service_tests;
 mod state_transition_tests;
+mod webhook_tests;
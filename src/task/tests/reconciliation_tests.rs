@@ -0,0 +1,285 @@
+//! Tests for reconciling task branch and pull request associations.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+use crate::maintenance::{adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate};
+use crate::operator::domain::OperatorReason;
+use crate::task::{
+    adapters::memory::{InMemoryTaskRepository, InMemoryVcsStatus},
+    domain::{
+        BranchRef, BranchStatus, PullRequestRef, PullRequestStatus, ReconciliationFinding, Task,
+        TaskState,
+    },
+    ports::{TaskRepository, VcsStatusError, VcsStatusPort, VcsStatusResult},
+    services::{
+        AssociateBranchRequest, AssociatePullRequestRequest, CreateTaskFromIssueRequest,
+        TaskLifecycleService, TaskReconciliationJob, TaskReconciliationService,
+    },
+};
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+
+struct Harness {
+    repository: Arc<InMemoryTaskRepository>,
+    vcs: Arc<InMemoryVcsStatus>,
+    lifecycle: TaskLifecycleService<InMemoryTaskRepository, DefaultClock>,
+    reconciliation:
+        TaskReconciliationService<InMemoryTaskRepository, InMemoryVcsStatus, DefaultClock>,
+}
+
+#[fixture]
+fn ctx() -> RequestContext {
+    RequestContext::new(
+        TenantId::new(),
+        CorrelationId::new(),
+        UserId::new(),
+        SessionId::new(),
+    )
+}
+
+#[fixture]
+fn harness() -> Harness {
+    let repository = Arc::new(InMemoryTaskRepository::new());
+    let vcs = Arc::new(InMemoryVcsStatus::new());
+    let clock = Arc::new(DefaultClock);
+    Harness {
+        lifecycle: TaskLifecycleService::new(repository.clone(), clock.clone()),
+        reconciliation: TaskReconciliationService::new(repository.clone(), vcs.clone(), clock),
+        repository,
+        vcs,
+    }
+}
+
+async fn task_with_branch(harness: &Harness, ctx: &RequestContext, issue_number: u64) -> Task {
+    let task = harness
+        .lifecycle
+        .create_from_issue(
+            ctx,
+            CreateTaskFromIssueRequest::new("github", "owner/repo", issue_number, "Reconcile"),
+        )
+        .await
+        .expect("create task");
+    harness
+        .lifecycle
+        .associate_branch(
+            ctx,
+            AssociateBranchRequest::new(
+                task.id(),
+                "github",
+                "owner/repo",
+                format!("feature/{issue_number}"),
+            ),
+        )
+        .await
+        .expect("associate branch")
+}
+
+async fn task_with_pull_request(
+    harness: &Harness,
+    ctx: &RequestContext,
+    issue_number: u64,
+) -> Task {
+    let task = task_with_branch(harness, ctx, issue_number).await;
+    harness
+        .lifecycle
+        .associate_pull_request(
+            ctx,
+            AssociatePullRequestRequest::new(task.id(), "github", "owner/repo", issue_number),
+        )
+        .await
+        .expect("associate pull request")
+}
+
+fn pr_ref(task: &Task) -> &PullRequestRef {
+    task.pull_request_ref().expect("pull request associated")
+}
+
+fn branch_ref(task: &Task) -> &BranchRef {
+    task.branch_ref().expect("branch associated")
+}
+
+async fn stored_state(harness: &Harness, ctx: &RequestContext, task: &Task) -> TaskState {
+    harness
+        .repository
+        .find_by_id(ctx, task.id())
+        .await
+        .expect("lookup")
+        .expect("task exists")
+        .state()
+}
+
+#[rstest]
+#[case::merged(PullRequestStatus::Merged, TaskState::Done)]
+#[case::closed_unmerged(PullRequestStatus::ClosedUnmerged, TaskState::Abandoned)]
+#[tokio::test]
+async fn closed_pull_requests_update_task_state(
+    harness: Harness,
+    ctx: RequestContext,
+    #[case] status: PullRequestStatus,
+    #[case] expected: TaskState,
+) {
+    let task = task_with_pull_request(&harness, &ctx, 1).await;
+    harness.vcs.set_pull_request_status(pr_ref(&task), status);
+
+    let report = harness
+        .reconciliation
+        .reconcile(&ctx, None)
+        .await
+        .expect("reconcile");
+
+    assert_eq!(report.tasks_checked, 1);
+    let outcome = report.reconciliations.first().expect("one reconciliation");
+    assert_eq!(outcome.previous_state, TaskState::InReview);
+    assert_eq!(outcome.new_state, Some(expected));
+    assert_eq!(stored_state(&harness, &ctx, &task).await, expected);
+}
+
+#[rstest]
+#[tokio::test]
+async fn deleted_branch_without_pull_request_abandons_task(harness: Harness, ctx: RequestContext) {
+    let task = task_with_branch(&harness, &ctx, 2).await;
+    harness.vcs.delete_branch(branch_ref(&task));
+
+    let report = harness
+        .reconciliation
+        .reconcile(&ctx, None)
+        .await
+        .expect("reconcile");
+
+    let outcome = report.reconciliations.first().expect("one reconciliation");
+    assert_eq!(
+        outcome.findings,
+        vec![ReconciliationFinding::BranchDeleted {
+            branch_ref: branch_ref(&task).clone()
+        }]
+    );
+    assert_eq!(
+        stored_state(&harness, &ctx, &task).await,
+        TaskState::Abandoned
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn force_push_is_reported_once_without_state_change(harness: Harness, ctx: RequestContext) {
+    let task = task_with_branch(&harness, &ctx, 3).await;
+    let pushed_at = task.updated_at() + TimeDelta::milliseconds(1);
+    harness.vcs.force_push_branch(branch_ref(&task), pushed_at);
+
+    let first = harness
+        .reconciliation
+        .reconcile(&ctx, None)
+        .await
+        .expect("first run");
+    let second = harness
+        .reconciliation
+        .reconcile(&ctx, Some(pushed_at))
+        .await
+        .expect("second run");
+
+    let outcome = first.reconciliations.first().expect("force-push finding");
+    assert!(matches!(
+        outcome.findings.as_slice(),
+        [ReconciliationFinding::BranchForcePushed { .. }]
+    ));
+    assert_eq!(outcome.new_state, None);
+    assert!(second.reconciliations.is_empty());
+    assert_eq!(stored_state(&harness, &ctx, &task).await, TaskState::Draft);
+}
+
+struct UnavailableProvider;
+
+#[async_trait]
+impl VcsStatusPort for UnavailableProvider {
+    async fn branch_status(
+        &self,
+        _ctx: &RequestContext,
+        _branch_ref: &BranchRef,
+        _since: DateTime<Utc>,
+    ) -> VcsStatusResult<BranchStatus> {
+        Err(VcsStatusError::provider(std::io::Error::other(
+            "rate limited",
+        )))
+    }
+
+    async fn pull_request_status(
+        &self,
+        _ctx: &RequestContext,
+        _pr_ref: &PullRequestRef,
+    ) -> VcsStatusResult<PullRequestStatus> {
+        Err(VcsStatusError::provider(std::io::Error::other(
+            "rate limited",
+        )))
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn provider_failures_are_reported_per_task(harness: Harness, ctx: RequestContext) {
+    let task = task_with_branch(&harness, &ctx, 4).await;
+    let service = TaskReconciliationService::new(
+        harness.repository.clone(),
+        Arc::new(UnavailableProvider),
+        Arc::new(DefaultClock),
+    );
+
+    let report = service.reconcile(&ctx, None).await.expect("reconcile");
+
+    assert_eq!(report.tasks_checked, 1);
+    assert!(report.reconciliations.is_empty());
+    let failure = report.failures.first().expect("one failure");
+    assert_eq!(failure.task_id, task.id());
+}
+
+#[rstest]
+#[tokio::test]
+async fn job_skips_tasks_made_terminal_by_earlier_runs(harness: Harness, ctx: RequestContext) {
+    let task = task_with_pull_request(&harness, &ctx, 5).await;
+    harness
+        .vcs
+        .set_pull_request_status(pr_ref(&task), PullRequestStatus::Merged);
+    let job = TaskReconciliationJob::new(harness.reconciliation, Duration::ZERO);
+
+    let first = job.run_once(&ctx).await.expect("first run");
+    let second = job.run_once(&ctx).await.expect("second run");
+
+    assert_eq!(job.interval(), Duration::from_secs(1));
+    assert_eq!(first.tasks_checked, 1);
+    assert_eq!(second.tasks_checked, 0);
+}
+
+#[rstest]
+#[case::maintenance(true, TaskState::InReview)]
+#[case::normal_operation(false, TaskState::Done)]
+#[tokio::test]
+async fn scheduled_runs_pause_during_maintenance(
+    harness: Harness,
+    ctx: RequestContext,
+    #[case] maintenance: bool,
+    #[case] expected: TaskState,
+) {
+    let task = task_with_pull_request(&harness, &ctx, 6).await;
+    harness
+        .vcs
+        .set_pull_request_status(pr_ref(&task), PullRequestStatus::Merged);
+    let gate = Arc::new(MaintenanceGate::new(
+        Arc::new(InMemoryMaintenanceStateRepository::new()),
+        Arc::new(DefaultClock),
+    ));
+    if maintenance {
+        let reason = OperatorReason::new("schema migration").expect("valid reason");
+        gate.enable(reason, None).await.expect("enable maintenance");
+    }
+    let job = TaskReconciliationJob::new(harness.reconciliation.clone(), Duration::ZERO)
+        .with_maintenance(gate);
+
+    // The first tick fires immediately, well within the shutdown delay.
+    job.run_until(&ctx, tokio::time::sleep(Duration::from_millis(200)))
+        .await;
+
+    assert_eq!(stored_state(&harness, &ctx, &task).await, expected);
+}
@@ -0,0 +1,183 @@
+//! Tests for task prioritisation and claiming queued tasks.
+
+use std::sync::Arc;
+
+use crate::context::RequestContext;
+use crate::task::{
+    adapters::memory::InMemoryTaskRepository,
+    domain::{Task, TaskId, TaskPriority, TaskSchedule, TaskState},
+    services::{
+        CreateTaskFromIssueRequest, ScheduleTaskRequest, TaskLifecycleError, TaskLifecycleService,
+        TransitionTaskRequest,
+    },
+};
+use crate::test_support::test_request_ctx;
+use chrono::TimeDelta;
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+
+type Lifecycle = TaskLifecycleService<InMemoryTaskRepository, DefaultClock>;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+#[fixture]
+fn lifecycle() -> Lifecycle {
+    let repository = Arc::new(InMemoryTaskRepository::new());
+    TaskLifecycleService::new(repository.clone(), Arc::new(DefaultClock))
+        .with_task_queue(repository)
+}
+
+async fn queued(
+    tasks: &Lifecycle,
+    ctx: &RequestContext,
+    issue_number: u64,
+    request: impl FnOnce(TaskId) -> ScheduleTaskRequest,
+) -> Task {
+    let task = tasks
+        .create_from_issue(
+            ctx,
+            CreateTaskFromIssueRequest::new("github", "octo/widgets", issue_number, "Queued"),
+        )
+        .await
+        .expect("task");
+    tasks
+        .schedule_task(ctx, request(task.id()))
+        .await
+        .expect("schedule")
+}
+
+async fn drain(tasks: &Lifecycle, ctx: &RequestContext, capabilities: &[String]) -> Vec<TaskId> {
+    let mut claimed = Vec::new();
+    while let Some(task) = tasks.claim_next(ctx, capabilities).await.expect("claim") {
+        assert_eq!(task.state(), TaskState::InProgress);
+        claimed.push(task.id());
+    }
+    claimed
+}
+
+#[rstest]
+#[tokio::test]
+async fn tasks_are_claimed_by_priority_then_due_date(ctx: RequestContext, lifecycle: Lifecycle) {
+    let due = DefaultClock.utc() + TimeDelta::days(1);
+    let low = queued(&lifecycle, &ctx, 1, |id| {
+        ScheduleTaskRequest::new(id, "low")
+    })
+    .await;
+    let undated = queued(&lifecycle, &ctx, 2, |id| {
+        ScheduleTaskRequest::new(id, "high")
+    })
+    .await;
+    let later = queued(&lifecycle, &ctx, 3, |id| {
+        ScheduleTaskRequest::new(id, "high").with_due_at(due + TimeDelta::hours(1))
+    })
+    .await;
+    let sooner = queued(&lifecycle, &ctx, 4, |id| {
+        ScheduleTaskRequest::new(id, "high").with_due_at(due)
+    })
+    .await;
+    let urgent = queued(&lifecycle, &ctx, 5, |id| {
+        ScheduleTaskRequest::new(id, "urgent")
+    })
+    .await;
+
+    assert_eq!(
+        drain(&lifecycle, &ctx, &[]).await,
+        [urgent.id(), sooner.id(), later.id(), undated.id(), low.id()]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn workers_only_claim_tasks_they_are_capable_of(ctx: RequestContext, lifecycle: Lifecycle) {
+    let needs_gpu = queued(&lifecycle, &ctx, 1, |id| {
+        ScheduleTaskRequest::new(id, "urgent")
+            .with_required_capabilities(vec!["gpu".to_owned(), "rust".to_owned()])
+    })
+    .await;
+    let routine = queued(&lifecycle, &ctx, 2, |id| {
+        ScheduleTaskRequest::new(id, "low")
+    })
+    .await;
+
+    assert_eq!(
+        drain(&lifecycle, &ctx, &["rust".to_owned()]).await,
+        [routine.id()]
+    );
+    assert_eq!(
+        drain(&lifecycle, &ctx, &[" Rust ".to_owned(), "GPU".to_owned()]).await,
+        [needs_gpu.id()]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn started_tasks_are_not_queued(ctx: RequestContext, lifecycle: Lifecycle) {
+    let started = queued(&lifecycle, &ctx, 1, |id| {
+        ScheduleTaskRequest::new(id, "urgent")
+    })
+    .await;
+    lifecycle
+        .transition_task(
+            &ctx,
+            TransitionTaskRequest::new(started.id(), "in_progress"),
+        )
+        .await
+        .expect("transition");
+
+    assert!(drain(&lifecycle, &ctx, &[]).await.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn claiming_needs_a_queue(ctx: RequestContext) {
+    let tasks = TaskLifecycleService::new(
+        Arc::new(InMemoryTaskRepository::new()),
+        Arc::new(DefaultClock),
+    );
+
+    let result = tasks.claim_next(&ctx, &[]).await;
+
+    assert!(matches!(
+        result,
+        Err(TaskLifecycleError::QueueNotConfigured)
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn unknown_priorities_are_rejected(ctx: RequestContext, lifecycle: Lifecycle) {
+    let task = queued(&lifecycle, &ctx, 1, |id| {
+        ScheduleTaskRequest::new(id, "normal")
+    })
+    .await;
+
+    let result = lifecycle
+        .schedule_task(&ctx, ScheduleTaskRequest::new(task.id(), "whenever"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(TaskLifecycleError::InvalidPriority(_))
+    ));
+}
+
+#[rstest]
+#[case::low(TaskPriority::Low)]
+#[case::normal(TaskPriority::Normal)]
+#[case::high(TaskPriority::High)]
+#[case::urgent(TaskPriority::Urgent)]
+fn priorities_round_trip(#[case] priority: TaskPriority) {
+    assert_eq!(TaskPriority::try_from(priority.as_str()), Ok(priority));
+    assert_eq!(TaskPriority::from_rank(priority.rank()), Some(priority));
+}
+
+#[rstest]
+fn required_capabilities_are_normalized() {
+    let schedule = TaskSchedule::default().with_required_capabilities([" Rust", "gpu", "rust", ""]);
+
+    assert_eq!(schedule.priority(), TaskPriority::Normal);
+    assert_eq!(schedule.required_capabilities(), ["gpu", "rust"]);
+}
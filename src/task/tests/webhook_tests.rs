@@ -0,0 +1,328 @@
+//! Tests for GitHub webhook parsing and webhook-driven task lifecycle
+//! changes.
+
+use std::sync::Arc;
+
+use crate::context::RequestContext;
+use crate::task::{
+    adapters::{
+        github::parse_github_webhook,
+        memory::{InMemoryTaskRepository, InMemoryWebhookDeliveryLog},
+    },
+    domain::{
+        BranchRef, IssueProvider, IssueRef, IssueStatusUpdate, IssueWebhookEvent, PullRequestRef,
+        Task, TaskId, TaskState, WebhookDelivery,
+    },
+    services::{IssueWebhookOutcome, IssueWebhookService, TaskLifecycleService},
+};
+use crate::test_support::test_request_ctx;
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+use serde_json::{Value, json};
+
+type TestService = IssueWebhookService<InMemoryTaskRepository, DefaultClock>;
+
+#[fixture]
+fn ctx() -> RequestContext {
+    test_request_ctx()
+}
+
+#[fixture]
+fn service() -> TestService {
+    let lifecycle = TaskLifecycleService::new(
+        Arc::new(InMemoryTaskRepository::new()),
+        Arc::new(DefaultClock),
+    );
+    IssueWebhookService::new(
+        lifecycle,
+        Arc::new(InMemoryWebhookDeliveryLog::new()),
+        Arc::new(DefaultClock),
+    )
+}
+
+fn issues_body(action: &str, number: u64, state_reason: Option<&str>) -> Vec<u8> {
+    json!({
+        "action": action,
+        "issue": {
+            "number": number,
+            "title": "Crash on save",
+            "body": "Steps to reproduce.",
+            "labels": [{"name": "bug"}],
+            "assignees": [],
+            "state_reason": state_reason,
+        },
+        "repository": {"full_name": "octo/widgets"},
+    })
+    .to_string()
+    .into_bytes()
+}
+
+fn comment_body(body: &str) -> Vec<u8> {
+    json!({
+        "action": "created",
+        "issue": {"number": 7, "title": "Crash on save"},
+        "comment": {
+            "user": {"login": "octocat"},
+            "body": body,
+            "created_at": "2026-01-02T03:04:05Z",
+        },
+        "repository": {"full_name": "octo/widgets"},
+    })
+    .to_string()
+    .into_bytes()
+}
+
+fn pull_request_body(action: &str, merged: bool, head_repo: Value, body: &str) -> Vec<u8> {
+    json!({
+        "action": action,
+        "number": 40,
+        "pull_request": {
+            "body": body,
+            "merged": merged,
+            "head": {"ref": "fix-save", "repo": head_repo},
+        },
+        "repository": {"full_name": "octo/widgets"},
+    })
+    .to_string()
+    .into_bytes()
+}
+
+fn parse(event: &str, body: &[u8]) -> IssueWebhookEvent {
+    parse_github_webhook(event, body)
+        .expect("valid payload")
+        .expect("task event")
+}
+
+fn delivery(id: &str) -> WebhookDelivery {
+    WebhookDelivery::new(IssueProvider::GitHub, id).expect("delivery id")
+}
+
+fn issue(number: u64) -> IssueRef {
+    IssueRef::from_parts("github", "octo/widgets", number).expect("issue ref")
+}
+
+async fn applied(
+    service: &TestService,
+    ctx: &RequestContext,
+    id: &str,
+    event: IssueWebhookEvent,
+) -> Vec<Task> {
+    match service.handle(ctx, &delivery(id), event).await {
+        Ok(IssueWebhookOutcome::Applied(tasks)) => tasks,
+        other => panic!("expected an applied delivery, got {other:?}"),
+    }
+}
+
+#[rstest]
+fn opened_issues_carry_their_metadata() {
+    let IssueWebhookEvent::IssueOpened(opened) = parse("issues", &issues_body("opened", 7, None))
+    else {
+        panic!("expected an opened issue");
+    };
+
+    assert_eq!(opened.issue_ref(), &issue(7));
+    assert_eq!(opened.metadata().title(), "Crash on save");
+    assert_eq!(opened.metadata().labels(), ["bug"]);
+}
+
+#[rstest]
+#[case::completed(Some("completed"), true)]
+#[case::legacy(None, true)]
+#[case::not_planned(Some("not_planned"), false)]
+fn closed_issues_report_whether_they_were_completed(
+    #[case] state_reason: Option<&str>,
+    #[case] completed: bool,
+) {
+    assert_eq!(
+        parse("issues", &issues_body("closed", 7, state_reason)),
+        IssueWebhookEvent::IssueClosed {
+            issue_ref: issue(7),
+            completed,
+        }
+    );
+}
+
+#[rstest]
+fn opened_pull_requests_list_the_issues_they_close() {
+    let body = pull_request_body(
+        "opened",
+        false,
+        json!({"full_name": "fork/widgets"}),
+        "Fixes #7, and closes: octo/gadgets#3.\nSee #9. Resolves (#7)",
+    );
+
+    assert_eq!(
+        parse("pull_request", &body),
+        IssueWebhookEvent::PullRequestOpened {
+            pull_request: PullRequestRef::from_parts("github", "octo/widgets", 40)
+                .expect("pull request ref"),
+            branch: BranchRef::from_parts("github", "fork/widgets", "fix-save")
+                .expect("branch ref"),
+            closes: vec![
+                issue(7),
+                IssueRef::from_parts("github", "octo/gadgets", 3).expect("issue ref"),
+            ],
+        }
+    );
+}
+
+#[rstest]
+#[case::ping("ping", json!({"zen": "Keep it logically awesome."}).to_string().into_bytes())]
+#[case::edited("issues", issues_body("edited", 7, None))]
+#[case::synchronized(
+    "pull_request",
+    pull_request_body("synchronize", false, Value::Null, "")
+)]
+fn other_events_are_acknowledged_without_an_event(#[case] event: &str, #[case] body: Vec<u8>) {
+    assert!(matches!(parse_github_webhook(event, &body), Ok(None)));
+}
+
+#[rstest]
+fn comments_and_label_changes_are_recognised() {
+    let mut labeled: Value =
+        serde_json::from_slice(&issues_body("labeled", 7, None)).expect("json");
+    labeled["label"] = json!({"name": "corbusier:in-review"});
+
+    assert_eq!(
+        parse("issues", labeled.to_string().as_bytes()),
+        IssueWebhookEvent::IssueLabelChanged {
+            issue_ref: issue(7),
+            label: "corbusier:in-review".to_owned(),
+        }
+    );
+    assert_eq!(
+        parse("issue_comment", &comment_body("Still failing.")),
+        IssueWebhookEvent::IssueCommented {
+            issue_ref: issue(7),
+            body: "Still failing.".to_owned(),
+        }
+    );
+}
+
+#[rstest]
+fn malformed_payloads_are_rejected() {
+    assert!(parse_github_webhook("issues", b"{\"action\": \"opened\"}").is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn opened_issues_create_tasks_once(service: TestService, ctx: RequestContext) {
+    let opened = parse("issues", &issues_body("opened", 7, None));
+
+    let created = applied(&service, &ctx, "delivery-1", opened.clone()).await;
+    let redelivered = service
+        .handle(&ctx, &delivery("delivery-1"), opened.clone())
+        .await
+        .expect("redelivery");
+    let reopened = applied(&service, &ctx, "delivery-2", opened).await;
+
+    assert_eq!(created.len(), 1);
+    assert_eq!(created.first().map(Task::state), Some(TaskState::Draft));
+    assert_eq!(redelivered, IssueWebhookOutcome::Duplicate);
+    assert!(reopened.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn pull_requests_move_their_tasks_through_review(service: TestService, ctx: RequestContext) {
+    applied(
+        &service,
+        &ctx,
+        "d-1",
+        parse("issues", &issues_body("opened", 7, None)),
+    )
+    .await;
+
+    let opened = pull_request_body("opened", false, Value::Null, "Fixes #7");
+    let linked = applied(&service, &ctx, "d-2", parse("pull_request", &opened)).await;
+    let merged = pull_request_body("closed", true, Value::Null, "Fixes #7");
+    let done = applied(&service, &ctx, "d-3", parse("pull_request", &merged)).await;
+
+    let task = linked.first().expect("linked task");
+    assert_eq!(task.state(), TaskState::InReview);
+    assert_eq!(
+        task.branch_ref().map(BranchRef::to_canonical),
+        Some("github:octo/widgets:fix-save".to_owned())
+    );
+    assert!(task.pull_request_ref().is_some());
+    assert_eq!(done.first().map(Task::state), Some(TaskState::Done));
+}
+
+#[rstest]
+#[tokio::test]
+async fn issues_closed_as_not_planned_abandon_their_tasks(
+    service: TestService,
+    ctx: RequestContext,
+) {
+    applied(
+        &service,
+        &ctx,
+        "d-1",
+        parse("issues", &issues_body("opened", 7, None)),
+    )
+    .await;
+
+    let closed = parse("issues", &issues_body("closed", 7, Some("not_planned")));
+    let abandoned = applied(&service, &ctx, "d-2", closed).await;
+
+    assert_eq!(
+        abandoned.first().map(Task::state),
+        Some(TaskState::Abandoned)
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn disallowed_transitions_are_skipped(service: TestService, ctx: RequestContext) {
+    applied(
+        &service,
+        &ctx,
+        "d-1",
+        parse("issues", &issues_body("opened", 7, None)),
+    )
+    .await;
+
+    let closed = parse("issues", &issues_body("closed", 7, Some("completed")));
+
+    assert!(applied(&service, &ctx, "d-2", closed).await.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn status_sync_echoes_are_ignored_without_claiming_the_delivery(
+    service: TestService,
+    ctx: RequestContext,
+) {
+    let update = IssueStatusUpdate::new(TaskId::new(), issue(7), TaskState::InReview);
+    let echoes = [
+        IssueWebhookEvent::IssueCommented {
+            issue_ref: issue(7),
+            body: update.comment_body(),
+        },
+        IssueWebhookEvent::IssueLabelChanged {
+            issue_ref: issue(7),
+            label: update.status_label(),
+        },
+    ];
+
+    for echo in echoes {
+        let outcome = service
+            .handle(&ctx, &delivery("d-1"), echo)
+            .await
+            .expect("echo");
+        assert_eq!(outcome, IssueWebhookOutcome::Ignored);
+    }
+    let user_comment = parse("issue_comment", &comment_body("Still failing."));
+    assert!(
+        applied(&service, &ctx, "d-1", user_comment)
+            .await
+            .is_empty()
+    );
+}
+
+#[rstest]
+#[case::blank("  ".to_owned())]
+#[case::too_long("x".repeat(256))]
+fn delivery_ids_must_be_storable(#[case] id: String) {
+    assert!(WebhookDelivery::new(IssueProvider::GitHub, id).is_err());
+}
@@ -0,0 +1,310 @@
+//! Catalog decorator that buffers tool call audit records and writes them
+//! in batches.
+//!
+//! Audit records are written once per tool call, so in busy deployments they
+//! dominate catalog writes. [`BufferedAuditCatalog`] queues them in memory
+//! and writes each tenant's records with one
+//! [`ToolCatalogRepository::record_audits`] call, either when a batch fills
+//! or on the background flush interval. A crash loses at most the records
+//! queued since the last flush; deployments that cannot accept that use
+//! [`AuditDurability::Strict`](crate::tool_registry::domain::AuditDurability)
+//! to write every record before the call returns.
+//!
+//! Reads write no audit records, so tool call audits are the only records
+//! this decorator defers.
+
+use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
+use crate::tool_registry::{
+    domain::{AuditBufferPolicy, CatalogEntry, McpServerId, ToolCallAuditRecord},
+    ports::{ToolCatalogRepository, ToolCatalogResult},
+};
+use async_trait::async_trait;
+use futures::future;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tokio::time::MissedTickBehavior;
+
+/// Counters describing the work done by a [`BufferedAuditCatalog`].
+///
+/// Comparing `batches_written` with `records_written` shows how many
+/// database writes batching saved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AuditBufferMetrics {
+    /// Records accepted by [`ToolCatalogRepository::record_audit`].
+    pub records_received: usize,
+    /// Records written to the wrapped catalog.
+    pub records_written: usize,
+    /// Write calls made to the wrapped catalog.
+    pub batches_written: usize,
+    /// Records discarded because the buffer was full.
+    pub records_dropped: usize,
+}
+
+/// A buffered record and the request it was produced for.
+struct PendingAudit {
+    ctx: RequestContext,
+    record: ToolCallAuditRecord,
+}
+
+/// [`ToolCatalogRepository`] decorator that defers audit record writes.
+///
+/// All other operations are forwarded to the wrapped catalog unchanged.
+/// Call [`Self::run_until`] to flush on the policy's interval, or
+/// [`Self::flush`] directly; records still buffered when the decorator is
+/// dropped are lost.
+pub struct BufferedAuditCatalog<R: ?Sized> {
+    inner: Arc<R>,
+    policy: AuditBufferPolicy,
+    pending: Mutex<VecDeque<PendingAudit>>,
+    metrics: Mutex<AuditBufferMetrics>,
+}
+
+impl<R: ToolCatalogRepository + ?Sized> BufferedAuditCatalog<R> {
+    /// Wraps `inner` so audit records are written according to `policy`.
+    ///
+    /// A batch size or buffer limit of zero is raised to one.
+    #[must_use]
+    pub fn new(inner: Arc<R>, policy: AuditBufferPolicy) -> Self {
+        let policy = AuditBufferPolicy {
+            max_batch_size: policy.max_batch_size.max(1),
+            max_pending: policy.max_pending.max(1),
+            ..policy
+        };
+        Self {
+            inner,
+            policy,
+            pending: Mutex::new(VecDeque::new()),
+            metrics: Mutex::new(AuditBufferMetrics::default()),
+        }
+    }
+
+    /// Returns the policy in effect.
+    #[must_use]
+    pub const fn policy(&self) -> &AuditBufferPolicy {
+        &self.policy
+    }
+
+    /// Returns the number of records waiting to be written.
+    #[must_use]
+    pub fn pending_len(&self) -> usize {
+        self.lock_pending().len()
+    }
+
+    /// Returns the counters accumulated since the decorator was created.
+    #[must_use]
+    pub fn metrics(&self) -> AuditBufferMetrics {
+        *self.lock_metrics()
+    }
+
+    /// Writes every buffered record, grouped into per-tenant batches, and
+    /// returns the number written.
+    ///
+    /// # Errors
+    ///
+    /// Returns the wrapped catalog's error when a batch fails. The failed
+    /// batch and any not yet attempted are kept for the next flush, subject
+    /// to the policy's buffer limit.
+    pub async fn flush(&self) -> ToolCatalogResult<usize> {
+        let mut remaining = std::mem::take(&mut *self.lock_pending());
+        let mut written = 0;
+        while let Some((ctx, records)) = next_batch(&mut remaining, self.policy.max_batch_size) {
+            if let Err(err) = self.inner.record_audits(&ctx, &records).await {
+                self.requeue(&ctx, records, remaining);
+                return Err(err);
+            }
+            written += records.len();
+            self.note_batch_written(records.len());
+        }
+        Ok(written)
+    }
+
+    /// Flushes every flush interval until `shutdown` completes, then
+    /// flushes once more.
+    ///
+    /// Failed flushes are emitted as tracing events and retried on the
+    /// next interval.
+    pub async fn run_until(&self, shutdown: impl Future<Output = ()>) {
+        future::select(pin!(self.run_forever()), pin!(shutdown)).await;
+        trace_flush(&self.flush().await);
+    }
+
+    async fn run_forever(&self) {
+        let mut ticker = tokio::time::interval(self.policy.flush_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            trace_flush(&self.flush().await);
+        }
+    }
+
+    /// Queues `records`, returning whether a full batch is waiting.
+    fn enqueue(&self, ctx: &RequestContext, records: &[ToolCallAuditRecord]) -> bool {
+        let mut pending = self.lock_pending();
+        pending.extend(records.iter().map(|record| PendingAudit {
+            ctx: ctx.clone(),
+            record: record.clone(),
+        }));
+        let dropped = self.trim(&mut pending);
+        let mut metrics = self.lock_metrics();
+        metrics.records_received += records.len();
+        metrics.records_dropped += dropped;
+        pending.len() >= self.policy.max_batch_size
+    }
+
+    /// Returns a failed batch and the unattempted records to the front of
+    /// the buffer, ahead of records queued during the flush.
+    fn requeue(
+        &self,
+        ctx: &RequestContext,
+        failed: Vec<ToolCallAuditRecord>,
+        mut remaining: VecDeque<PendingAudit>,
+    ) {
+        let mut pending = self.lock_pending();
+        for record in failed.into_iter().rev() {
+            remaining.push_front(PendingAudit {
+                ctx: ctx.clone(),
+                record,
+            });
+        }
+        remaining.append(&mut pending);
+        *pending = remaining;
+        let dropped = self.trim(&mut pending);
+        self.lock_metrics().records_dropped += dropped;
+    }
+
+    /// Drops the oldest records beyond the buffer limit, returning how many
+    /// were dropped.
+    fn trim(&self, pending: &mut VecDeque<PendingAudit>) -> usize {
+        let excess = pending.len().saturating_sub(self.policy.max_pending);
+        for dropped in pending.drain(..excess) {
+            tracing::warn!(
+                tenant_id = %dropped.ctx.tenant_id(),
+                call_id = %dropped.record.call_id(),
+                tool_name = dropped.record.tool_name(),
+                "audit buffer is full; dropping oldest audit record"
+            );
+        }
+        excess
+    }
+
+    fn note_batch_written(&self, count: usize) {
+        let mut metrics = self.lock_metrics();
+        metrics.records_written += count;
+        metrics.batches_written += 1;
+    }
+
+    fn lock_pending(&self) -> MutexGuard<'_, VecDeque<PendingAudit>> {
+        self.pending.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn lock_metrics(&self) -> MutexGuard<'_, AuditBufferMetrics> {
+        self.metrics.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Removes the oldest record and up to `max - 1` later records for the same
+/// tenant from `pending`, keeping their order.
+fn next_batch(
+    pending: &mut VecDeque<PendingAudit>,
+    max: usize,
+) -> Option<(RequestContext, Vec<ToolCallAuditRecord>)> {
+    let first = pending.pop_front()?;
+    let tenant_id = first.ctx.tenant_id();
+    let mut records = vec![first.record];
+    let mut rest = VecDeque::with_capacity(pending.len());
+    for entry in pending.drain(..) {
+        if records.len() < max && entry.ctx.tenant_id() == tenant_id {
+            records.push(entry.record);
+        } else {
+            rest.push_back(entry);
+        }
+    }
+    *pending = rest;
+    Some((first.ctx, records))
+}
+
+fn trace_flush(result: &ToolCatalogResult<usize>) {
+    match result {
+        Ok(0) => {}
+        Ok(written) => tracing::debug!(written, "flushed buffered audit records"),
+        Err(err) => tracing::warn!(error = %err, "audit buffer flush failed"),
+    }
+}
+
+#[async_trait]
+impl<R: ToolCatalogRepository + ?Sized> ToolCatalogRepository for BufferedAuditCatalog<R> {
+    async fn sync_server_tools(
+        &self,
+        ctx: &RequestContext,
+        server_id: McpServerId,
+        entries: &[CatalogEntry],
+    ) -> ToolCatalogResult<()> {
+        self.inner.sync_server_tools(ctx, server_id, entries).await
+    }
+
+    async fn mark_server_tools_unavailable(
+        &self,
+        ctx: &RequestContext,
+        server_id: McpServerId,
+    ) -> ToolCatalogResult<()> {
+        self.inner
+            .mark_server_tools_unavailable(ctx, server_id)
+            .await
+    }
+
+    async fn mark_server_tools_available(
+        &self,
+        ctx: &RequestContext,
+        server_id: McpServerId,
+    ) -> ToolCatalogResult<()> {
+        self.inner.mark_server_tools_available(ctx, server_id).await
+    }
+
+    async fn find_by_tool_name(
+        &self,
+        ctx: &RequestContext,
+        tool_name: &str,
+        page: PageRequest,
+    ) -> ToolCatalogResult<Page<CatalogEntry>> {
+        self.inner.find_by_tool_name(ctx, tool_name, page).await
+    }
+
+    async fn list_all(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> ToolCatalogResult<Page<CatalogEntry>> {
+        self.inner.list_all(ctx, page).await
+    }
+
+    async fn record_audit(
+        &self,
+        ctx: &RequestContext,
+        record: &ToolCallAuditRecord,
+    ) -> ToolCatalogResult<()> {
+        self.record_audits(ctx, std::slice::from_ref(record)).await
+    }
+
+    async fn record_audits(
+        &self,
+        ctx: &RequestContext,
+        records: &[ToolCallAuditRecord],
+    ) -> ToolCatalogResult<()> {
+        if self.policy.is_strict() {
+            self.lock_metrics().records_received += records.len();
+            self.inner.record_audits(ctx, records).await?;
+            self.note_batch_written(records.len());
+            return Ok(());
+        }
+        if self.enqueue(ctx, records) {
+            trace_flush(&self.flush().await);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
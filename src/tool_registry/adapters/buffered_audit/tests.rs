@@ -0,0 +1,146 @@
+//! Unit tests for [`BufferedAuditCatalog`].
+
+use super::*;
+use crate::test_support::test_request_ctx;
+use crate::tool_registry::adapters::memory::InMemoryToolCatalog;
+use crate::tool_registry::domain::{AuditDurability, ToolCallRequest};
+use mockable::{Clock, DefaultClock};
+use rstest::{fixture, rstest};
+use serde_json::json;
+use std::time::Duration;
+
+fn audit_record(tool_name: &str) -> ToolCallAuditRecord {
+    let request = ToolCallRequest::new(tool_name, json!({}), &DefaultClock);
+    ToolCallAuditRecord::for_rejection(&request, McpServerId::new(), &"denied", DefaultClock.utc())
+}
+
+fn policy(max_batch_size: usize, max_pending: usize) -> AuditBufferPolicy {
+    AuditBufferPolicy {
+        max_batch_size,
+        max_pending,
+        flush_interval: Duration::from_millis(10),
+        ..AuditBufferPolicy::default()
+    }
+}
+
+#[fixture]
+fn inner() -> Arc<InMemoryToolCatalog> {
+    Arc::new(InMemoryToolCatalog::new())
+}
+
+fn stored(inner: &InMemoryToolCatalog, ctx: &RequestContext) -> Vec<String> {
+    inner
+        .audit_records(ctx.tenant_id())
+        .expect("read audit records")
+        .iter()
+        .map(|record| record.tool_name().to_owned())
+        .collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn records_are_buffered_until_a_batch_fills(inner: Arc<InMemoryToolCatalog>) {
+    let ctx = test_request_ctx();
+    let catalog = BufferedAuditCatalog::new(Arc::clone(&inner), policy(3, 100));
+
+    for name in ["a", "b"] {
+        catalog
+            .record_audit(&ctx, &audit_record(name))
+            .await
+            .expect("record audit");
+    }
+    assert!(stored(&inner, &ctx).is_empty());
+    assert_eq!(catalog.pending_len(), 2);
+
+    catalog
+        .record_audit(&ctx, &audit_record("c"))
+        .await
+        .expect("record audit");
+
+    assert_eq!(stored(&inner, &ctx), vec!["a", "b", "c"]);
+    assert_eq!(catalog.pending_len(), 0);
+    assert_eq!(
+        catalog.metrics(),
+        AuditBufferMetrics {
+            records_received: 3,
+            records_written: 3,
+            batches_written: 1,
+            records_dropped: 0,
+        }
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn flush_writes_one_batch_per_tenant(inner: Arc<InMemoryToolCatalog>) {
+    let (first, second) = (test_request_ctx(), test_request_ctx());
+    let catalog = BufferedAuditCatalog::new(Arc::clone(&inner), policy(100, 1000));
+    for (ctx, name) in [(&first, "a"), (&second, "x"), (&first, "b"), (&second, "y")] {
+        catalog
+            .record_audit(ctx, &audit_record(name))
+            .await
+            .expect("record audit");
+    }
+
+    let written = catalog.flush().await.expect("flush");
+
+    assert_eq!(written, 4);
+    assert_eq!(stored(&inner, &first), vec!["a", "b"]);
+    assert_eq!(stored(&inner, &second), vec!["x", "y"]);
+    assert_eq!(catalog.metrics().batches_written, 2);
+}
+
+#[rstest]
+#[tokio::test]
+async fn oldest_records_are_dropped_when_the_buffer_is_full(inner: Arc<InMemoryToolCatalog>) {
+    let ctx = test_request_ctx();
+    let catalog = BufferedAuditCatalog::new(Arc::clone(&inner), policy(100, 2));
+    for name in ["a", "b", "c"] {
+        catalog
+            .record_audit(&ctx, &audit_record(name))
+            .await
+            .expect("record audit");
+    }
+
+    catalog.flush().await.expect("flush");
+
+    assert_eq!(stored(&inner, &ctx), vec!["b", "c"]);
+    assert_eq!(catalog.metrics().records_dropped, 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn strict_durability_writes_each_record_immediately(inner: Arc<InMemoryToolCatalog>) {
+    let ctx = test_request_ctx();
+    let catalog = BufferedAuditCatalog::new(Arc::clone(&inner), AuditBufferPolicy::strict());
+
+    catalog
+        .record_audit(&ctx, &audit_record("a"))
+        .await
+        .expect("record audit");
+
+    assert_eq!(catalog.policy().durability, AuditDurability::Strict);
+    assert_eq!(stored(&inner, &ctx), vec!["a"]);
+    assert_eq!(catalog.pending_len(), 0);
+}
+
+#[rstest]
+#[tokio::test]
+async fn run_until_flushes_remaining_records_at_shutdown(inner: Arc<InMemoryToolCatalog>) {
+    let ctx = test_request_ctx();
+    let catalog = BufferedAuditCatalog::new(
+        Arc::clone(&inner),
+        AuditBufferPolicy {
+            flush_interval: Duration::from_secs(3600),
+            ..AuditBufferPolicy::default()
+        },
+    );
+    catalog
+        .record_audit(&ctx, &audit_record("a"))
+        .await
+        .expect("record audit");
+
+    catalog.run_until(tokio::task::yield_now()).await;
+
+    assert_eq!(stored(&inner, &ctx), vec!["a"]);
+}
@@ -1,6 +1,7 @@
 //! In-memory repository for tool catalog entries and audit records.
 
 use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
 use crate::tool_registry::{
     domain::{CatalogEntry, CatalogEntryId, McpServerId, ToolCallAuditRecord},
     ports::{ToolCatalogError, ToolCatalogRepository, ToolCatalogResult},
@@ -175,9 +176,10 @@ impl ToolCatalogRepository for InMemoryToolCatalog {
         &self,
         ctx: &RequestContext,
         tool_name: &str,
-    ) -> ToolCatalogResult<Vec<CatalogEntry>> {
+        page: PageRequest,
+    ) -> ToolCatalogResult<Page<CatalogEntry>> {
         let tenants = self.read_state()?;
-        let entries = tenants
+        let mut entries: Vec<CatalogEntry> = tenants
             .get(&ctx.tenant_id())
             .map(|s| {
                 s.entries
@@ -187,16 +189,26 @@ impl ToolCatalogRepository for InMemoryToolCatalog {
                     .collect()
             })
             .unwrap_or_default();
-        Ok(entries)
+        entries.sort_by_key(|entry| (entry.discovered_at(), entry.id().into_inner()));
+        Ok(Page::from_ordered(entries, page, |entry| {
+            Cursor::at_timestamp(entry.discovered_at(), entry.id().into_inner())
+        }))
     }
 
-    async fn list_all(&self, ctx: &RequestContext) -> ToolCatalogResult<Vec<CatalogEntry>> {
+    async fn list_all(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> ToolCatalogResult<Page<CatalogEntry>> {
         let tenants = self.read_state()?;
-        let entries = tenants
+        let mut entries: Vec<CatalogEntry> = tenants
             .get(&ctx.tenant_id())
             .map(|s| s.entries.values().cloned().collect())
             .unwrap_or_default();
-        Ok(entries)
+        entries.sort_by_key(|entry| (entry.discovered_at(), entry.id().into_inner()));
+        Ok(Page::from_ordered(entries, page, |entry| {
+            Cursor::at_timestamp(entry.discovered_at(), entry.id().into_inner())
+        }))
     }
 
     async fn record_audit(
@@ -209,6 +221,17 @@ impl ToolCatalogRepository for InMemoryToolCatalog {
         state.audit_records.push(record.clone());
         Ok(())
     }
+
+    async fn record_audits(
+        &self,
+        ctx: &RequestContext,
+        records: &[ToolCallAuditRecord],
+    ) -> ToolCatalogResult<()> {
+        let mut tenants = self.write_state()?;
+        let state = tenants.entry(ctx.tenant_id()).or_default();
+        state.audit_records.extend_from_slice(records);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
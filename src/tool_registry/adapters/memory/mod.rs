@@ -1,7 +1,10 @@
-//! In-memory adapters for MCP server registry and tool catalog persistence.
+//! In-memory adapters for MCP server registry, tool catalog persistence, and
+//! tool secrets.
 
 mod catalog;
 mod repository;
+mod secrets;
 
 pub use catalog::InMemoryToolCatalog;
 pub use repository::InMemoryMcpServerRegistry;
+pub use secrets::{InMemorySecretInjectionAudit, InMemoryToolSecretStore};
@@ -1,6 +1,7 @@
 //! In-memory repository for MCP server registrations.
 
 use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
 use crate::tool_registry::{
     domain::{McpServerId, McpServerName, McpServerRegistration},
     ports::{McpServerRegistryError, McpServerRegistryRepository, McpServerRegistryResult},
@@ -126,13 +127,18 @@ impl McpServerRegistryRepository for InMemoryMcpServerRegistry {
     async fn list_all(
         &self,
         ctx: &RequestContext,
-    ) -> McpServerRegistryResult<Vec<McpServerRegistration>> {
+        page: PageRequest,
+    ) -> McpServerRegistryResult<Page<McpServerRegistration>> {
         let tenants = self.state.read().map_err(|err| {
             McpServerRegistryError::persistence(std::io::Error::other(err.to_string()))
         })?;
-        Ok(tenants
+        let mut servers: Vec<McpServerRegistration> = tenants
             .get(&ctx.tenant_id())
             .map(|state| state.servers.values().cloned().collect())
-            .unwrap_or_default())
+            .unwrap_or_default();
+        servers.sort_by_key(|server| (server.created_at(), server.id().into_inner()));
+        Ok(Page::from_ordered(servers, page, |server| {
+            Cursor::at_timestamp(server.created_at(), server.id().into_inner())
+        }))
     }
 }
@@ -0,0 +1,104 @@
+//! In-memory secret store and secret injection audit log, scoped by tenant.
+
+use crate::context::{RequestContext, TenantId};
+use crate::tool_registry::{
+    domain::{SecretInjectionEvent, SecretReference, SecretValue},
+    ports::{SecretInjectionAudit, ToolSecretError, ToolSecretResult, ToolSecretStore},
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Thread-safe in-memory secret store.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryToolSecretStore {
+    secrets: Arc<RwLock<HashMap<(TenantId, SecretReference), SecretValue>>>,
+}
+
+impl InMemoryToolSecretStore {
+    /// Creates an empty secret store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores or replaces the tenant's secret held under `reference`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolSecretError::Backend`] when lock acquisition fails.
+    pub fn put(
+        &self,
+        ctx: &RequestContext,
+        reference: SecretReference,
+        value: SecretValue,
+    ) -> ToolSecretResult<()> {
+        self.secrets
+            .write()
+            .map_err(|err| ToolSecretError::backend("write_lock", err))?
+            .insert((ctx.tenant_id(), reference), value);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ToolSecretStore for InMemoryToolSecretStore {
+    async fn resolve(
+        &self,
+        ctx: &RequestContext,
+        reference: &SecretReference,
+    ) -> ToolSecretResult<SecretValue> {
+        self.secrets
+            .read()
+            .map_err(|err| ToolSecretError::backend("read_lock", err))?
+            .get(&(ctx.tenant_id(), reference.clone()))
+            .cloned()
+            .ok_or_else(|| ToolSecretError::NotFound(reference.clone()))
+    }
+}
+
+/// Thread-safe in-memory log of secret injection events.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySecretInjectionAudit {
+    events: Arc<RwLock<HashMap<TenantId, Vec<SecretInjectionEvent>>>>,
+}
+
+impl InMemorySecretInjectionAudit {
+    /// Creates an empty audit log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the tenant's recorded events, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolSecretError::Backend`] when lock acquisition fails.
+    pub fn events(&self, ctx: &RequestContext) -> ToolSecretResult<Vec<SecretInjectionEvent>> {
+        Ok(self
+            .events
+            .read()
+            .map_err(|err| ToolSecretError::backend("read_lock", err))?
+            .get(&ctx.tenant_id())
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+#[async_trait]
+impl SecretInjectionAudit for InMemorySecretInjectionAudit {
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        event: &SecretInjectionEvent,
+    ) -> ToolSecretResult<()> {
+        self.events
+            .write()
+            .map_err(|err| ToolSecretError::backend("write_lock", err))?
+            .entry(ctx.tenant_id())
+            .or_default()
+            .push(event.clone());
+        Ok(())
+    }
+}
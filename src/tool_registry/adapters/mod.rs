@@ -1,16 +1,21 @@
-//! Adapter implementations for MCP server lifecycle, registry, and tool
-//! catalog ports.
+//! Adapter implementations for MCP server lifecycle, registry, tool
+//! catalog, and tool secret ports, plus a catalog decorator that batches
+//! audit record writes.
 
+mod buffered_audit;
 mod log_store;
 mod policy;
 mod policy_metadata;
 mod runtime;
+mod secret_injection;
 
 pub mod memory;
 pub mod postgres;
 
+pub use buffered_audit::{AuditBufferMetrics, BufferedAuditCatalog};
 pub use log_store::ObjectStoreLogAdapter;
 pub use policy::{
     AllowAllPolicy, DenyAllPolicy, FailingPolicy, HookBackedToolExecutionGovernance, StubGovernance,
 };
 pub use runtime::InMemoryMcpServerHost;
+pub use secret_injection::SecretInjectingHost;
@@ -1,6 +1,9 @@
-//! Diesel row models for tool catalog, audit log, and log metadata tables.
+//! Diesel row models for tool catalog, audit log, log metadata, and secret
+//! injection audit tables.
 
-use super::catalog_schema::{mcp_tool_catalog, tool_call_audit_log, tool_log_metadata};
+use super::catalog_schema::{
+    mcp_tool_catalog, secret_injection_audits, tool_call_audit_log, tool_log_metadata,
+};
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use serde_json::Value;
@@ -132,3 +135,18 @@ pub struct NewLogMetadataRow {
     /// Expiry timestamp.
     pub expires_at: DateTime<Utc>,
 }
+
+/// Query and insert row for secret injection audit records.
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = secret_injection_audits)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct SecretInjectionAuditRow {
+    /// Owning tenant identifier.
+    pub tenant_id: uuid::Uuid,
+    /// Server whose process received the secrets.
+    pub server_id: uuid::Uuid,
+    /// Serialized injection event, without secret values.
+    pub event: Value,
+    /// Injection timestamp.
+    pub injected_at: DateTime<Utc>,
+}
@@ -14,7 +14,8 @@ use super::{
     repository::McpServerPgPool,
 };
 use crate::context::{RequestContext, TenantId};
-use crate::postgres_support::{FromTxError, TxError};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{FromTxError, TxError, keyset_page};
 use crate::tool_registry::{
     domain::{CatalogEntry, McpServerId, ToolCallAuditRecord},
     ports::{ToolCatalogError, ToolCatalogRepository, ToolCatalogResult},
@@ -52,6 +53,8 @@ pub struct PostgresToolCatalog {
     pool: McpServerPgPool,
 }
 
+crate::postgres_support::pool_health_check!(PostgresToolCatalog, "tool_catalog");
+
 impl PostgresToolCatalog {
     /// Creates a new catalog repository from a `PostgreSQL` pool.
     #[must_use]
@@ -240,11 +243,12 @@ impl PostgresToolCatalog {
         .await
     }
 
-    fn load_entries_for_tenant(
+    fn load_entry_page(
         connection: &mut PgConnection,
         tenant_id: TenantId,
         tool_name: Option<&str>,
-    ) -> ToolCatalogResult<Vec<CatalogEntry>> {
+        page: PageRequest,
+    ) -> ToolCatalogResult<Page<CatalogEntry>> {
         let mut query = mcp_tool_catalog::table
             .filter(mcp_tool_catalog::tenant_id.eq(tenant_id.into_inner()))
             .into_boxed::<Pg>();
@@ -252,13 +256,21 @@ impl PostgresToolCatalog {
         if let Some(name) = tool_name {
             query = query.filter(mcp_tool_catalog::tool_name.eq(name));
         }
-
-        let rows = query
+        let paged = keyset_page!(
+            query,
+            page,
+            after_timestamp,
+            (mcp_tool_catalog::discovered_at, mcp_tool_catalog::id)
+        );
+        let rows = paged
             .select(CatalogEntryRow::as_select())
             .load::<CatalogEntryRow>(connection)
             .map_err(|e| ToolCatalogError::persistence("select", e))?;
 
-        rows.into_iter().map(row_to_entry).collect()
+        Page::from_overfetched(rows, page, |row| {
+            Cursor::at_timestamp(row.discovered_at, row.id)
+        })
+        .try_map(row_to_entry)
     }
 }
 
@@ -298,19 +310,24 @@ impl ToolCatalogRepository for PostgresToolCatalog {
         &self,
         ctx: &RequestContext,
         tool_name: &str,
-    ) -> ToolCatalogResult<Vec<CatalogEntry>> {
+        page: PageRequest,
+    ) -> ToolCatalogResult<Page<CatalogEntry>> {
         let tenant_id = ctx.tenant_id();
         let name = tool_name.to_owned();
         execute_read_query(&self.pool, tenant_id, move |connection| {
-            Self::load_entries_for_tenant(connection, tenant_id, Some(name.as_str()))
+            Self::load_entry_page(connection, tenant_id, Some(name.as_str()), page)
         })
         .await
     }
 
-    async fn list_all(&self, ctx: &RequestContext) -> ToolCatalogResult<Vec<CatalogEntry>> {
+    async fn list_all(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> ToolCatalogResult<Page<CatalogEntry>> {
         let tenant_id = ctx.tenant_id();
         execute_read_query(&self.pool, tenant_id, move |connection| {
-            Self::load_entries_for_tenant(connection, tenant_id, None)
+            Self::load_entry_page(connection, tenant_id, None, page)
         })
         .await
     }
@@ -332,4 +349,28 @@ impl ToolCatalogRepository for PostgresToolCatalog {
         })
         .await
     }
+
+    async fn record_audits(
+        &self,
+        ctx: &RequestContext,
+        records: &[ToolCallAuditRecord],
+    ) -> ToolCatalogResult<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+        let tenant_id = ctx.tenant_id();
+        let tid = tenant_id.into_inner();
+        let rows: Vec<_> = records
+            .iter()
+            .map(|record| audit_to_new_row(record, tid))
+            .collect();
+        execute_query_with_bootstrap(&self.pool, tenant_id, move |connection| {
+            diesel::insert_into(tool_call_audit_log::table)
+                .values(&rows)
+                .execute(connection)
+                .map_err(|e| ToolCatalogError::persistence("insert_batch", e))?;
+            Ok(())
+        })
+        .await
+    }
 }
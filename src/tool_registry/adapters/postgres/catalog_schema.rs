@@ -1,4 +1,5 @@
-//! Diesel schema for tool catalog, audit log, and log metadata tables.
+//! Diesel schema for tool catalog, audit log, log metadata, and secret
+//! injection audit tables.
 
 diesel::table! {
     /// Tool catalog records discovered from MCP servers.
@@ -90,3 +91,19 @@ diesel::table! {
         expires_at -> Timestamptz,
     }
 }
+
+diesel::table! {
+    /// Audit records of secrets injected into MCP server processes.
+    secret_injection_audits (id) {
+        /// Surrogate key preserving insertion order.
+        id -> BigInt,
+        /// Owning tenant identifier.
+        tenant_id -> Uuid,
+        /// Server whose process received the secrets.
+        server_id -> Uuid,
+        /// Serialized injection event, without secret values.
+        event -> Jsonb,
+        /// Injection timestamp.
+        injected_at -> Timestamptz,
+    }
+}
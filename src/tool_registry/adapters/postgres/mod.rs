@@ -1,4 +1,5 @@
-//! `PostgreSQL` adapters for MCP server registry and tool catalogue persistence.
+//! `PostgreSQL` adapters for MCP server registry, tool catalogue, and secret
+//! injection audit persistence.
 
 mod catalog_models;
 mod catalog_repository;
@@ -6,6 +7,8 @@ mod catalog_schema;
 mod models;
 mod repository;
 mod schema;
+mod secret_audit_repository;
 
 pub use catalog_repository::PostgresToolCatalog;
 pub use repository::{McpServerPgPool, PostgresMcpServerRegistry};
+pub use secret_audit_repository::PostgresSecretInjectionAudit;
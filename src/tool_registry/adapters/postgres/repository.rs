@@ -5,6 +5,8 @@ use super::{
     schema::mcp_servers,
 };
 use crate::context::{RequestContext, TenantId};
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::keyset_page;
 use crate::tool_registry::{
     domain::{
         McpServerHealthSnapshot, McpServerHealthStatus, McpServerId, McpServerLifecycleState,
@@ -27,6 +29,8 @@ pub struct PostgresMcpServerRegistry {
     pool: McpServerPgPool,
 }
 
+crate::postgres_support::pool_health_check!(PostgresMcpServerRegistry, "mcp_server_registry");
+
 impl PostgresMcpServerRegistry {
     /// Creates a new repository from a `PostgreSQL` pool.
     #[must_use]
@@ -169,15 +173,27 @@ impl McpServerRegistryRepository for PostgresMcpServerRegistry {
     async fn list_all(
         &self,
         ctx: &RequestContext,
-    ) -> McpServerRegistryResult<Vec<McpServerRegistration>> {
+        page: PageRequest,
+    ) -> McpServerRegistryResult<Page<McpServerRegistration>> {
         let tid = ctx.tenant_id().into_inner();
         self.run_blocking(move |connection| {
-            let rows = mcp_servers::table
+            let query = mcp_servers::table
                 .filter(mcp_servers::tenant_id.eq(tid))
                 .select(McpServerRow::as_select())
+                .into_boxed();
+            let paged = keyset_page!(
+                query,
+                page,
+                after_timestamp,
+                (mcp_servers::created_at, mcp_servers::id)
+            );
+            let rows = paged
                 .load::<McpServerRow>(connection)
                 .map_err(McpServerRegistryError::persistence)?;
-            rows.into_iter().map(row_to_server).collect()
+            Page::from_overfetched(rows, page, |row| {
+                Cursor::at_timestamp(row.created_at, row.id)
+            })
+            .try_map(row_to_server)
         })
         .await
     }
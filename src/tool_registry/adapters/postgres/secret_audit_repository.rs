@@ -0,0 +1,140 @@
+//! `PostgreSQL` audit log for secrets injected into MCP server processes.
+
+use super::{
+    catalog_models::SecretInjectionAuditRow, catalog_schema::secret_injection_audits,
+    repository::McpServerPgPool,
+};
+use crate::context::RequestContext;
+use crate::pagination::{Cursor, Page, PageRequest};
+use crate::postgres_support::{
+    FromTxError, TxError, ensure_tenant_exists, keyset_page, with_tenant_read_tx, with_tenant_tx,
+};
+use crate::tool_registry::{
+    domain::{McpServerId, SecretInjectionEvent},
+    ports::{SecretInjectionAudit, ToolSecretError, ToolSecretResult},
+};
+use async_trait::async_trait;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+
+impl FromTxError<Self> for ToolSecretError {
+    fn from_tx_error(err: TxError<Self>) -> Self {
+        match err {
+            TxError::Domain(e) => e,
+            TxError::Diesel(e) => Self::backend("transaction", e),
+        }
+    }
+}
+
+/// `PostgreSQL`-backed log of secret injection events.
+///
+/// Events are stored as recorded: variable names and secret references,
+/// never the resolved values.
+#[derive(Debug, Clone)]
+pub struct PostgresSecretInjectionAudit {
+    pool: McpServerPgPool,
+}
+
+crate::postgres_support::pool_health_check!(PostgresSecretInjectionAudit, "secret_injection_audit");
+
+impl PostgresSecretInjectionAudit {
+    /// Creates a new audit log from a `PostgreSQL` pool.
+    #[must_use]
+    pub const fn new(pool: McpServerPgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a page of the events recorded for a server, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolSecretError::Backend`] when the events cannot be read
+    /// or a stored event cannot be decoded.
+    pub async fn events_for_server(
+        &self,
+        ctx: &RequestContext,
+        server_id: McpServerId,
+        page: PageRequest,
+    ) -> ToolSecretResult<Page<SecretInjectionEvent>> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let server_uuid = server_id.into_inner();
+        self.run_blocking(move |connection| {
+            with_tenant_read_tx(connection, tenant_uuid, |tx| {
+                let query = secret_injection_audits::table
+                    .filter(secret_injection_audits::tenant_id.eq(tenant_uuid))
+                    .filter(secret_injection_audits::server_id.eq(server_uuid))
+                    .select((
+                        secret_injection_audits::id,
+                        SecretInjectionAuditRow::as_select(),
+                    ))
+                    .into_boxed();
+                // Every row shares the server id, so the surrogate key alone
+                // orders the listing.
+                let rows = keyset_page!(
+                    query,
+                    page,
+                    after_number,
+                    (
+                        secret_injection_audits::id,
+                        secret_injection_audits::server_id
+                    )
+                )
+                .load::<(i64, SecretInjectionAuditRow)>(tx)
+                .map_err(|err| ToolSecretError::backend("events_for_server", err))?;
+                Page::from_overfetched(rows, page, |(id, _)| Cursor::at_number(*id, server_uuid))
+                    .try_map(|(_, row)| {
+                        serde_json::from_value(row.event)
+                            .map_err(|err| ToolSecretError::backend("decode_event", err))
+                    })
+            })
+        })
+        .await
+    }
+
+    async fn run_blocking<F, T>(&self, f: F) -> ToolSecretResult<T>
+    where
+        F: FnOnce(&mut PgConnection) -> ToolSecretResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = pool
+                .get()
+                .map_err(|err| ToolSecretError::backend("connect", err))?;
+            f(&mut connection)
+        })
+        .await
+        .map_err(|err| ToolSecretError::backend("spawn_blocking", err))?
+    }
+}
+
+#[async_trait]
+impl SecretInjectionAudit for PostgresSecretInjectionAudit {
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        event: &SecretInjectionEvent,
+    ) -> ToolSecretResult<()> {
+        let tenant_uuid = ctx.tenant_id().into_inner();
+        let row = SecretInjectionAuditRow {
+            tenant_id: tenant_uuid,
+            server_id: event.server_id.into_inner(),
+            event: serde_json::to_value(event)
+                .map_err(|err| ToolSecretError::backend("encode_event", err))?,
+            injected_at: event.injected_at,
+        };
+
+        self.run_blocking(move |connection| {
+            with_tenant_tx(connection, tenant_uuid, |tx| {
+                ensure_tenant_exists(tx, tenant_uuid)
+                    .map_err(|err| ToolSecretError::backend("ensure_tenant", err))?;
+                diesel::insert_into(secret_injection_audits::table)
+                    .values(&row)
+                    .execute(tx)
+                    .map_err(|err| ToolSecretError::backend("record", err))
+            })
+            .map(|_| ())
+        })
+        .await
+    }
+}
@@ -0,0 +1,135 @@
+//! Runtime host decorator that injects per-tool secrets at spawn time.
+
+use crate::context::RequestContext;
+use crate::tool_registry::{
+    domain::{
+        McpServerHealthSnapshot, McpServerRegistration, McpToolDefinition, McpTransport,
+        SecretInjectionEvent, StdioTransportConfig, ToolCallRequest,
+    },
+    ports::{
+        McpServerHost, McpServerHostError, McpServerHostResult, SecretInjectionAudit,
+        StartHostResult, ToolCallHostResult, ToolSecretStore,
+    },
+};
+use async_trait::async_trait;
+use mockable::Clock;
+use std::sync::Arc;
+
+/// [`McpServerHost`] decorator that resolves secret environment variables
+/// when a STDIO server is started.
+///
+/// The wrapped host receives a transient copy of the registration whose
+/// environment carries the resolved values; the persisted registration
+/// keeps only [`crate::tool_registry::domain::SecretReference`]s. Every
+/// injection is recorded through [`SecretInjectionAudit`] before the process
+/// is spawned, and a failure to resolve or audit a secret refuses the start
+/// rather than spawning the process without it.
+#[derive(Clone)]
+pub struct SecretInjectingHost<H> {
+    inner: Arc<H>,
+    secrets: Arc<dyn ToolSecretStore>,
+    audit: Arc<dyn SecretInjectionAudit>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl<H: McpServerHost> SecretInjectingHost<H> {
+    /// Wraps `inner` so STDIO servers receive their secrets at spawn time.
+    #[must_use]
+    pub fn new(
+        inner: Arc<H>,
+        secrets: Arc<dyn ToolSecretStore>,
+        audit: Arc<dyn SecretInjectionAudit>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            inner,
+            secrets,
+            audit,
+            clock,
+        }
+    }
+
+    async fn spawn_registration(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+        config: &StdioTransportConfig,
+    ) -> McpServerHostResult<McpServerRegistration> {
+        let spawn_failed = |reason: String| McpServerHostError::ProcessSpawnFailed {
+            server_id: server.id(),
+            reason,
+        };
+        let mut resolved = Vec::with_capacity(config.secret_env().len());
+        for (variable, reference) in config.secret_env() {
+            let value = self.secrets.resolve(ctx, reference).await.map_err(|err| {
+                spawn_failed(format!("cannot resolve secret for {variable}: {err}"))
+            })?;
+            resolved.push((variable.clone(), value));
+        }
+        let event = SecretInjectionEvent {
+            server_id: server.id(),
+            server_name: server.name().clone(),
+            variables: config
+                .secret_env()
+                .iter()
+                .map(|(variable, reference)| (variable.clone(), reference.clone()))
+                .collect(),
+            injected_at: self.clock.utc(),
+        };
+        self.audit
+            .record(ctx, &event)
+            .await
+            .map_err(|err| spawn_failed(format!("cannot audit secret injection: {err}")))?;
+        Ok(server.with_transport(McpTransport::Stdio(config.with_resolved_secrets(resolved))))
+    }
+}
+
+#[async_trait]
+impl<H: McpServerHost> McpServerHost for SecretInjectingHost<H> {
+    async fn start(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+    ) -> McpServerHostResult<StartHostResult> {
+        match server.transport() {
+            McpTransport::Stdio(config) if !config.secret_env().is_empty() => {
+                let spawn = self.spawn_registration(ctx, server, config).await?;
+                self.inner.start(ctx, &spawn).await
+            }
+            _ => self.inner.start(ctx, server).await,
+        }
+    }
+
+    async fn stop(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+    ) -> McpServerHostResult<()> {
+        self.inner.stop(ctx, server).await
+    }
+
+    async fn health(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+    ) -> McpServerHostResult<McpServerHealthSnapshot> {
+        self.inner.health(ctx, server).await
+    }
+
+    async fn list_tools(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+    ) -> McpServerHostResult<Vec<McpToolDefinition>> {
+        self.inner.list_tools(ctx, server).await
+    }
+
+    async fn call_tool(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+        request: &ToolCallRequest,
+    ) -> McpServerHostResult<ToolCallHostResult> {
+        self.inner.call_tool(ctx, server, request).await
+    }
+}
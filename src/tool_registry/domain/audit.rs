@@ -2,6 +2,8 @@
 //!
 //! A [`ToolCallAuditRecord`] captures the full context of a completed
 //! tool call invocation for observability and compliance purposes.
+//! [`AuditBufferPolicy`] decides whether records are written as they arrive
+//! or buffered and written in batches.
 
 use super::McpServerId;
 use super::routing::{ToolCallId, ToolCallOutcome, ToolCallResult};
@@ -144,3 +146,65 @@ impl ToolCallAuditRecord {
         self.stderr_log_path.as_deref()
     }
 }
+
+/// Default number of audit records written in one batch.
+const DEFAULT_AUDIT_BATCH_SIZE: usize = 100;
+/// Default number of audit records held in memory before the oldest are
+/// dropped.
+const DEFAULT_AUDIT_MAX_PENDING: usize = 10_000;
+/// Default interval between background flushes.
+const DEFAULT_AUDIT_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Whether audit records may be held in memory before they are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuditDurability {
+    /// Records are buffered and written in batches. A crash loses at most
+    /// the records buffered since the last flush.
+    #[default]
+    Buffered,
+    /// Every record is written before the call that produced it returns.
+    Strict,
+}
+
+/// Configurable policy for batching audit record writes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditBufferPolicy {
+    /// Whether records may be buffered at all.
+    pub durability: AuditDurability,
+    /// Number of buffered records that triggers an immediate flush, and the
+    /// largest batch written at once.
+    pub max_batch_size: usize,
+    /// Number of records retained while writes fail; beyond this the oldest
+    /// records are dropped.
+    pub max_pending: usize,
+    /// Interval between background flushes.
+    pub flush_interval: Duration,
+}
+
+impl Default for AuditBufferPolicy {
+    fn default() -> Self {
+        Self {
+            durability: AuditDurability::Buffered,
+            max_batch_size: DEFAULT_AUDIT_BATCH_SIZE,
+            max_pending: DEFAULT_AUDIT_MAX_PENDING,
+            flush_interval: DEFAULT_AUDIT_FLUSH_INTERVAL,
+        }
+    }
+}
+
+impl AuditBufferPolicy {
+    /// Returns a policy that writes every record as it arrives.
+    #[must_use]
+    pub fn strict() -> Self {
+        Self {
+            durability: AuditDurability::Strict,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether records are written as they arrive.
+    #[must_use]
+    pub const fn is_strict(&self) -> bool {
+        matches!(self.durability, AuditDurability::Strict)
+    }
+}
@@ -29,6 +29,18 @@ pub enum ToolRegistryDomainError {
     #[error("STDIO working directory must not be empty when provided")]
     EmptyWorkingDirectory,
 
+    /// A secret reference is empty after trimming.
+    #[error("secret reference must not be empty")]
+    EmptySecretReference,
+
+    /// A secret environment variable name is empty after trimming.
+    #[error("secret environment variable name must not be empty")]
+    EmptySecretVariable,
+
+    /// A secret environment variable is also set as a plain variable.
+    #[error("environment variable '{0}' is set both as a plain value and as a secret")]
+    SecretVariableConflict(String),
+
     /// The HTTP+SSE base URL is empty.
     #[error("HTTP+SSE base URL must not be empty")]
     EmptyHttpSseBaseUrl,
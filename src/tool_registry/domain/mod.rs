@@ -2,8 +2,11 @@
 //!
 //! The tool registry domain models MCP server identity, transport
 //! configuration, lifecycle and health states, discovered tool metadata,
-//! tool catalog entries, call routing, audit trails, policy decisions,
-//! parameter validation, and stderr log capture with retention policies.
+//! tool catalog entries, call routing, audit trails and their buffering,
+//! policy decisions, the impact of policy changes on recent calls,
+//! parameter validation, secret
+//! references for process environments, and
+//! stderr log capture with retention policies.
 //! Infrastructure concerns remain outside this boundary.
 
 mod audit;
@@ -13,14 +16,16 @@ mod health;
 mod ids;
 mod log_capture;
 mod policy;
+mod policy_impact;
 mod redact;
 pub mod routing;
+mod secrets;
 mod server;
 mod tool;
 mod transport;
 pub mod validation;
 
-pub use audit::ToolCallAuditRecord;
+pub use audit::{AuditBufferPolicy, AuditDurability, ToolCallAuditRecord};
 pub use catalog::{CatalogEntry, CatalogEntryId, PersistedCatalogEntryData};
 pub use error::{
     ParseMcpServerHealthStatusError, ParseMcpServerLifecycleStateError, ToolRegistryDomainError,
@@ -32,11 +37,16 @@ pub use log_capture::{
     PersistedLogEntryData,
 };
 pub use policy::ToolGovernanceDecision;
+pub use policy_impact::{
+    BlockedToolCall, ConversationPolicyImpact, PolicyImpactReport, ReplayedToolCall,
+    SkippedToolCall,
+};
 pub use redact::{redact_error_message, redact_outcome_content, redact_parameters};
 pub use routing::{
     ToolCallId, ToolCallOutcome, ToolCallRequest, ToolCallResult, ToolCallTiming,
     ToolExecutionScope,
 };
+pub use secrets::{SecretInjectionEvent, SecretReference, SecretValue};
 pub use server::{McpServerLifecycleState, McpServerRegistration, PersistedMcpServerData};
 pub use tool::McpToolDefinition;
 pub use transport::{HttpSseTransportConfig, McpTransport, StdioTransportConfig};
@@ -0,0 +1,148 @@
+//! Impact of a tool policy change on recently executed tool calls.
+//!
+//! Before a new governance policy is rolled out, the calls that the current
+//! policy let through are replayed against it. A [`PolicyImpactReport`]
+//! lists, conversation by conversation, which of those calls the new policy
+//! would block, and flags conversations where the blocked share crosses an
+//! alert threshold.
+
+use crate::message::domain::{ConversationId, MessageId};
+use serde::{Deserialize, Serialize};
+
+/// A previously executed tool call that was replayed against a new policy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayedToolCall {
+    /// The conversation the call was made in.
+    pub conversation_id: ConversationId,
+    /// The message holding the call.
+    pub message_id: MessageId,
+    /// The call identifier recorded in the message.
+    pub call_id: String,
+    /// The tool that was called.
+    pub tool_name: String,
+}
+
+/// A replayed call that the new policy would block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockedToolCall {
+    /// The replayed call.
+    pub call: ReplayedToolCall,
+    /// The new policy's denial reason.
+    pub reason: String,
+}
+
+/// A call that could not be replayed, so its fate under the new policy is
+/// unknown.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkippedToolCall {
+    /// The call that was not replayed.
+    pub call: ReplayedToolCall,
+    /// Why it could not be replayed.
+    pub reason: String,
+}
+
+/// How a new policy would treat one conversation's recent tool calls.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationPolicyImpact {
+    /// The conversation evaluated.
+    pub conversation_id: ConversationId,
+    /// Number of calls replayed against the new policy.
+    pub replayed: usize,
+    /// Replayed calls the new policy would block.
+    pub blocked: Vec<BlockedToolCall>,
+    /// Calls that could not be replayed.
+    pub skipped: Vec<SkippedToolCall>,
+}
+
+impl ConversationPolicyImpact {
+    /// Creates an impact with nothing replayed yet.
+    #[must_use]
+    pub const fn new(conversation_id: ConversationId) -> Self {
+        Self {
+            conversation_id,
+            replayed: 0,
+            blocked: Vec::new(),
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Returns the percentage of replayed calls that would be blocked,
+    /// rounded up so that any blocked call registers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use corbusier::message::domain::{ConversationId, MessageId};
+    /// use corbusier::tool_registry::domain::{
+    ///     BlockedToolCall, ConversationPolicyImpact, ReplayedToolCall,
+    /// };
+    ///
+    /// let conversation_id = ConversationId::new();
+    /// let mut impact = ConversationPolicyImpact::new(conversation_id);
+    /// impact.replayed = 3;
+    /// impact.blocked.push(BlockedToolCall {
+    ///     call: ReplayedToolCall {
+    ///         conversation_id,
+    ///         message_id: MessageId::new(),
+    ///         call_id: "call-1".to_owned(),
+    ///         tool_name: "delete_branch".to_owned(),
+    ///     },
+    ///     reason: "destructive git operations need approval".to_owned(),
+    /// });
+    /// assert_eq!(impact.blocked_percent(), 34);
+    /// ```
+    #[must_use]
+    pub fn blocked_percent(&self) -> u8 {
+        if self.replayed == 0 {
+            return 0;
+        }
+        let percent = self
+            .blocked
+            .len()
+            .saturating_mul(100)
+            .div_ceil(self.replayed);
+        u8::try_from(percent.min(100)).unwrap_or(100)
+    }
+}
+
+/// What a new policy would block among recently executed tool calls.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyImpactReport {
+    /// Per-conversation impact, in the order the conversations were given.
+    pub conversations: Vec<ConversationPolicyImpact>,
+    /// Blocked percentage at which a conversation raises an alert.
+    pub alert_threshold_percent: u8,
+}
+
+impl PolicyImpactReport {
+    /// Returns the total number of calls replayed.
+    #[must_use]
+    pub fn replayed(&self) -> usize {
+        self.conversations
+            .iter()
+            .map(|impact| impact.replayed)
+            .sum()
+    }
+
+    /// Returns every call the new policy would block.
+    pub fn blocked(&self) -> impl Iterator<Item = &BlockedToolCall> {
+        self.conversations
+            .iter()
+            .flat_map(|impact| impact.blocked.iter())
+    }
+
+    /// Returns the conversations whose blocked share reaches the alert
+    /// threshold.
+    pub fn alerts(&self) -> impl Iterator<Item = &ConversationPolicyImpact> {
+        self.conversations.iter().filter(|impact| {
+            !impact.blocked.is_empty() && impact.blocked_percent() >= self.alert_threshold_percent
+        })
+    }
+
+    /// Returns whether the new policy would block none of the replayed
+    /// calls.
+    #[must_use]
+    pub fn is_clear(&self) -> bool {
+        self.blocked().next().is_none()
+    }
+}
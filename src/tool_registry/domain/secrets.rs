@@ -0,0 +1,112 @@
+//! Secret references for MCP server process environments.
+//!
+//! A STDIO registration names the secrets its process needs by reference
+//! rather than by value. The references are persisted with the
+//! registration; the values are resolved from a secret store only when the
+//! process is spawned and never written back. Each injection is recorded as
+//! a [`SecretInjectionEvent`] that names the variables and references but
+//! carries no secret material.
+
+use super::{McpServerId, McpServerName, ToolRegistryDomainError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Reference to a secret held in an external secret store.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::tool_registry::domain::SecretReference;
+///
+/// let reference = SecretReference::new(" github/token ").expect("valid reference");
+/// assert_eq!(reference.as_str(), "github/token");
+/// assert!(SecretReference::new("  ").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SecretReference(String);
+
+impl SecretReference {
+    /// Creates a secret reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolRegistryDomainError::EmptySecretReference`] when the
+    /// reference is empty after trimming.
+    pub fn new(value: impl Into<String>) -> Result<Self, ToolRegistryDomainError> {
+        let normalized = value.into().trim().to_owned();
+        if normalized.is_empty() {
+            return Err(ToolRegistryDomainError::EmptySecretReference);
+        }
+        Ok(Self(normalized))
+    }
+
+    /// Returns the reference as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for SecretReference {
+    type Error = ToolRegistryDomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<SecretReference> for String {
+    fn from(value: SecretReference) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for SecretReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Resolved secret material.
+///
+/// The value is deliberately not serializable and its `Debug` output is
+/// redacted, so it cannot leak through persistence or logs by accident.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretValue(String);
+
+impl SecretValue {
+    /// Wraps resolved secret material.
+    #[must_use]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Exposes the secret material for injection into a process
+    /// environment.
+    #[must_use]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SecretValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretValue([REDACTED])")
+    }
+}
+
+/// Audit record of secrets injected into an MCP server environment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecretInjectionEvent {
+    /// Server whose process received the secrets.
+    pub server_id: McpServerId,
+    /// Name of the server.
+    pub server_name: McpServerName,
+    /// Injected environment variables and the references they were
+    /// resolved from, ordered by variable name.
+    pub variables: Vec<(String, SecretReference)>,
+    /// When the secrets were injected.
+    pub injected_at: DateTime<Utc>,
+}
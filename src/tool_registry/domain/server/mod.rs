@@ -148,6 +148,18 @@ impl McpServerRegistration {
         &self.transport
     }
 
+    /// Returns a copy of the registration with a different transport.
+    ///
+    /// Used to hand a runtime host spawn-time settings that must not be
+    /// persisted with the registration.
+    #[must_use]
+    pub(crate) fn with_transport(&self, transport: McpTransport) -> Self {
+        Self {
+            transport,
+            ..self.clone()
+        }
+    }
+
     /// Returns the lifecycle state.
     #[must_use]
     pub const fn lifecycle_state(&self) -> McpServerLifecycleState {
@@ -1,16 +1,22 @@
 //! MCP server transport configuration value objects.
 
-use super::ToolRegistryDomainError;
+use super::{SecretReference, SecretValue, ToolRegistryDomainError};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 /// Transport settings for an MCP server hosted over STDIO.
+///
+/// Secret environment variables are stored as [`SecretReference`]s and
+/// resolved only when the process is spawned, so registrations never hold
+/// secret material.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StdioTransportConfig {
     command: String,
     args: Vec<String>,
     env: BTreeMap<String, String>,
     working_directory: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    secret_env: BTreeMap<String, SecretReference>,
 }
 
 impl StdioTransportConfig {
@@ -31,6 +37,7 @@ impl StdioTransportConfig {
             args: Vec::new(),
             env: BTreeMap::new(),
             working_directory: None,
+            secret_env: BTreeMap::new(),
         })
     }
 
@@ -67,6 +74,51 @@ impl StdioTransportConfig {
         Ok(self)
     }
 
+    /// Adds an environment variable whose value is resolved from the secret
+    /// store when the process is spawned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolRegistryDomainError::EmptySecretVariable`] when the
+    /// variable name is empty after trimming, or
+    /// [`ToolRegistryDomainError::SecretVariableConflict`] when the variable
+    /// is already set as a plain environment variable.
+    pub fn with_secret_env(
+        mut self,
+        variable: impl Into<String>,
+        reference: SecretReference,
+    ) -> Result<Self, ToolRegistryDomainError> {
+        let normalized = variable.into().trim().to_owned();
+        if normalized.is_empty() {
+            return Err(ToolRegistryDomainError::EmptySecretVariable);
+        }
+        if self.env.contains_key(&normalized) {
+            return Err(ToolRegistryDomainError::SecretVariableConflict(normalized));
+        }
+
+        self.secret_env.insert(normalized, reference);
+        Ok(self)
+    }
+
+    /// Returns a spawn-time copy with resolved secrets merged into the plain
+    /// environment and no secret references left.
+    ///
+    /// The copy holds secret material and must never be persisted.
+    #[must_use]
+    pub(crate) fn with_resolved_secrets(
+        &self,
+        resolved: impl IntoIterator<Item = (String, SecretValue)>,
+    ) -> Self {
+        let mut spawn_config = self.clone();
+        spawn_config.secret_env.clear();
+        spawn_config.env.extend(
+            resolved
+                .into_iter()
+                .map(|(variable, value)| (variable, value.expose().to_owned())),
+        );
+        spawn_config
+    }
+
     /// Returns the executable command.
     #[must_use]
     pub fn command(&self) -> &str {
@@ -85,6 +137,12 @@ impl StdioTransportConfig {
         &self.env
     }
 
+    /// Returns secret environment variables and their references.
+    #[must_use]
+    pub const fn secret_env(&self) -> &BTreeMap<String, SecretReference> {
+        &self.secret_env
+    }
+
     /// Returns the optional working directory.
     #[must_use]
     pub fn working_directory(&self) -> Option<&str> {
@@ -9,8 +9,11 @@
 //! - Port contracts in [`ports`]
 //! - Adapter implementations in [`adapters`]
 //! - Orchestration services in [`services`]
+//! - Typed tool bindings in `typed` (behind the `typed-tools` feature)
 
 pub mod adapters;
 pub mod domain;
 pub mod ports;
 pub mod services;
+#[cfg(feature = "typed-tools")]
+pub mod typed;
@@ -1,6 +1,7 @@
 //! Port contract for tool catalog persistence and audit trail.
 
 use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
 use crate::tool_registry::domain::{
     CatalogEntry, CatalogEntryId, McpServerId, ToolCallAuditRecord,
 };
@@ -53,7 +54,8 @@ pub trait ToolCatalogRepository: Send + Sync {
         server_id: McpServerId,
     ) -> ToolCatalogResult<()>;
 
-    /// Returns all catalog entries matching a tool name.
+    /// Returns a page of the catalog entries matching a tool name, in
+    /// discovery order.
     ///
     /// Multiple entries are returned when more than one server advertises
     /// a tool with the same name; callers must handle the ambiguity.
@@ -65,14 +67,19 @@ pub trait ToolCatalogRepository: Send + Sync {
         &self,
         ctx: &RequestContext,
         tool_name: &str,
-    ) -> ToolCatalogResult<Vec<CatalogEntry>>;
+        page: PageRequest,
+    ) -> ToolCatalogResult<Page<CatalogEntry>>;
 
-    /// Returns the complete tool catalog.
+    /// Returns a page of the tool catalog, in discovery order.
     ///
     /// # Errors
     ///
     /// Returns [`ToolCatalogError`] on persistence failures.
-    async fn list_all(&self, ctx: &RequestContext) -> ToolCatalogResult<Vec<CatalogEntry>>;
+    async fn list_all(
+        &self,
+        ctx: &RequestContext,
+        page: PageRequest,
+    ) -> ToolCatalogResult<Page<CatalogEntry>>;
 
     /// Persists a tool call audit trail record.
     ///
@@ -84,6 +91,19 @@ pub trait ToolCatalogRepository: Send + Sync {
         ctx: &RequestContext,
         record: &ToolCallAuditRecord,
     ) -> ToolCatalogResult<()>;
+
+    /// Persists a batch of tool call audit trail records in one write.
+    ///
+    /// Either every record is stored or none is.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolCatalogError`] on persistence failures.
+    async fn record_audits(
+        &self,
+        ctx: &RequestContext,
+        records: &[ToolCallAuditRecord],
+    ) -> ToolCatalogResult<()>;
 }
 
 /// Errors returned by tool catalog persistence operations.
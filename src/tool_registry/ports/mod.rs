@@ -5,6 +5,7 @@ mod governance;
 mod host;
 mod log_store;
 mod repository;
+mod secrets;
 
 pub use catalog::{ToolCatalogError, ToolCatalogRepository, ToolCatalogResult};
 pub use governance::{
@@ -20,3 +21,4 @@ pub use log_store::{
 pub use repository::{
     McpServerRegistryError, McpServerRegistryRepository, McpServerRegistryResult,
 };
+pub use secrets::{SecretInjectionAudit, ToolSecretError, ToolSecretResult, ToolSecretStore};
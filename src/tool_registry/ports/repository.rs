@@ -1,6 +1,7 @@
 //! Repository port for MCP server registry persistence and discovery.
 
 use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest};
 use crate::tool_registry::domain::{McpServerId, McpServerName, McpServerRegistration};
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -51,11 +52,13 @@ pub trait McpServerRegistryRepository: Send + Sync {
         server_name: &McpServerName,
     ) -> McpServerRegistryResult<Option<McpServerRegistration>>;
 
-    /// Returns all registrations regardless of lifecycle state.
+    /// Returns a page of registrations regardless of lifecycle state,
+    /// in registration order.
     async fn list_all(
         &self,
         ctx: &RequestContext,
-    ) -> McpServerRegistryResult<Vec<McpServerRegistration>>;
+        page: PageRequest,
+    ) -> McpServerRegistryResult<Page<McpServerRegistration>>;
 }
 
 /// Errors returned by MCP server registry repository implementations.
@@ -0,0 +1,69 @@
+//! Port contracts for resolving tool secrets and auditing their injection.
+
+use crate::context::RequestContext;
+use crate::tool_registry::domain::{SecretInjectionEvent, SecretReference, SecretValue};
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type for tool secret operations.
+pub type ToolSecretResult<T> = Result<T, ToolSecretError>;
+
+/// Secret store consulted when an MCP server process is spawned.
+#[async_trait]
+pub trait ToolSecretStore: Send + Sync {
+    /// Resolves a secret reference to its current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolSecretError::NotFound`] when the store holds no secret
+    /// under the reference, or [`ToolSecretError::Backend`] when the store
+    /// cannot be reached.
+    async fn resolve(
+        &self,
+        ctx: &RequestContext,
+        reference: &SecretReference,
+    ) -> ToolSecretResult<SecretValue>;
+}
+
+/// Sink for audit events raised when secrets enter a tool environment.
+#[async_trait]
+pub trait SecretInjectionAudit: Send + Sync {
+    /// Records that secrets were injected into a server environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolSecretError::Backend`] when the event cannot be
+    /// recorded.
+    async fn record(
+        &self,
+        ctx: &RequestContext,
+        event: &SecretInjectionEvent,
+    ) -> ToolSecretResult<()>;
+}
+
+/// Errors returned by secret store and injection audit adapters.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ToolSecretError {
+    /// The store holds no secret under the reference.
+    #[error("secret not found: {0}")]
+    NotFound(SecretReference),
+
+    /// The backing store failed.
+    #[error("secret backend error during '{operation}': {reason}")]
+    Backend {
+        /// Operation that failed.
+        operation: String,
+        /// Reason for the failure.
+        reason: String,
+    },
+}
+
+impl ToolSecretError {
+    /// Wraps a failure from the backing store.
+    pub fn backend(operation: impl Into<String>, err: impl std::fmt::Display) -> Self {
+        Self::Backend {
+            operation: operation.into(),
+            reason: err.to_string(),
+        }
+    }
+}
@@ -10,6 +10,7 @@ use crate::hook_engine::domain::{
 use crate::hook_engine::ports::HookPolicyAuditRepository;
 use crate::hook_engine::services::{HookEngineService, HookEngineServiceDeps};
 use crate::message::domain::ConversationId;
+use crate::pagination::PageRequest;
 use crate::task::domain::TaskId;
 use crate::test_support::test_request_ctx;
 use crate::tool_registry::adapters::{
@@ -245,7 +246,7 @@ async fn denied_pre_tool_use_blocks_host_and_persists_policy_audit(
     );
 
     let events = policy_audit
-        .find_by_task(&ctx, task_id)
+        .find_by_task(&ctx, task_id, PageRequest::default())
         .await
         .expect("query by task should succeed");
     assert_eq!(events.len(), 1);
@@ -315,7 +316,7 @@ async fn invalid_post_tool_payload_does_not_fail_successful_tool_call(
 
     assert!(result.outcome().is_success());
     let events = policy_audit
-        .find_by_conversation(&ctx, conversation_id)
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
         .await
         .expect("query by conversation should succeed");
     assert!(
@@ -339,7 +340,7 @@ async fn post_tool_use_observation_records_audit_event(
     assert!(result.outcome().is_success());
 
     let by_conversation = policy_audit
-        .find_by_conversation(&ctx, conversation_id)
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
         .await
         .expect("query by conversation should succeed");
     assert_eq!(by_conversation.len(), 1);
@@ -352,7 +353,11 @@ async fn post_tool_use_observation_records_audit_event(
     );
 
     let by_trigger = policy_audit
-        .find_by_trigger_context(&ctx, conversation_event.trigger_context_id())
+        .find_by_trigger_context(
+            &ctx,
+            conversation_event.trigger_context_id(),
+            PageRequest::default(),
+        )
         .await
         .expect("query by trigger should succeed");
     assert_eq!(by_trigger.len(), 1);
@@ -5,6 +5,7 @@
 //! audit recording.
 
 use crate::context::RequestContext;
+use crate::pagination::{Page, PageRequest, collect_pages};
 use crate::tool_registry::{
     domain::{
         CatalogEntry, LogRetentionPolicy, McpServerId, ToolCallRequest, ToolCallResult,
@@ -143,7 +144,7 @@ where
         self.set_tools_availability(ctx, server_id, true).await
     }
 
-    /// Returns the complete tool catalog.
+    /// Returns a page of the tool catalog, in discovery order.
     ///
     /// # Errors
     ///
@@ -151,8 +152,9 @@ where
     pub async fn list_catalog(
         &self,
         ctx: &RequestContext,
-    ) -> ToolDiscoveryRoutingServiceResult<Vec<CatalogEntry>> {
-        Ok(self.catalog.list_all(ctx).await?)
+        page: PageRequest,
+    ) -> ToolDiscoveryRoutingServiceResult<Page<CatalogEntry>> {
+        Ok(self.catalog.list_all(ctx, page).await?)
     }
 
     /// Routes a tool call through validation, governance, execution, stderr
@@ -188,6 +190,30 @@ where
         self.execute_and_audit(ctx, request, &entry).await
     }
 
+    /// Routes a call to a tool using typed arguments.
+    ///
+    /// The arguments are serialized into a [`ToolCallRequest`] stamped with
+    /// this service's clock and then routed exactly as
+    /// [`Self::call_tool`] routes it, including schema validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolDiscoveryRoutingServiceError::Domain`] when the
+    /// arguments cannot be serialized, and otherwise the errors of
+    /// [`Self::call_tool`].
+    #[cfg(feature = "typed-tools")]
+    pub async fn call_typed_tool<T>(
+        &self,
+        ctx: &RequestContext,
+        args: &T,
+    ) -> ToolDiscoveryRoutingServiceResult<ToolCallResult>
+    where
+        T: crate::tool_registry::typed::TypedTool + Sync,
+    {
+        let request = args.to_call_request(&*self.clock)?;
+        self.call_tool(ctx, &request).await
+    }
+
     /// Resolves a tool from the catalog, checks availability, validates
     /// parameters, and enforces policy. On failure returns the catalog
     /// entry (if resolved) alongside the error for audit purposes.
@@ -196,10 +222,11 @@ where
         ctx: &RequestContext,
         request: &ToolCallRequest,
     ) -> Result<CatalogEntry, (Option<CatalogEntry>, ToolDiscoveryRoutingServiceError)> {
-        let entries = match self
-            .catalog
-            .find_by_tool_name(ctx, request.tool_name())
-            .await
+        let entries = match collect_pages(|page| {
+            self.catalog
+                .find_by_tool_name(ctx, request.tool_name(), page)
+        })
+        .await
         {
             Ok(e) => e,
             Err(err) => return Err((None, err.into())),
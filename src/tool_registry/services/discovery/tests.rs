@@ -4,6 +4,7 @@
 mod test_helpers;
 
 use super::ToolDiscoveryRoutingServiceError;
+use crate::pagination::PageRequest;
 use crate::tool_registry::{
     adapters::{InMemoryMcpServerHost, StubGovernance, memory::InMemoryMcpServerRegistry},
     domain::{McpServerName, ToolCallRequest, ToolRegistryDomainError},
@@ -53,7 +54,7 @@ async fn discover_tools_persists_catalog(bundle: TestBundle) -> Result<()> {
     let ctx = test_request_ctx();
     let server_id = register_start_discover(&host, &lifecycle, &discovery, &ctx).await?;
 
-    let entries = discovery.list_catalog(&ctx).await?;
+    let entries = discovery.list_catalog(&ctx, PageRequest::default()).await?;
     assert_eq!(entries.len(), 1);
     let first = entries.first().expect("expected single catalog entry");
     assert_eq!(first.tool().name(), "read_file");
@@ -98,7 +99,7 @@ async fn mark_unavailable_updates_catalog(bundle: TestBundle) -> Result<()> {
     let server_id = register_start_discover(&host, &lifecycle, &discovery, &ctx).await?;
     discovery.mark_tools_unavailable(&ctx, server_id).await?;
 
-    let entries = discovery.list_catalog(&ctx).await?;
+    let entries = discovery.list_catalog(&ctx, PageRequest::default()).await?;
     assert_eq!(entries.len(), 1);
     assert!(!entries.first().expect("catalog entry").available());
     Ok(())
@@ -4,6 +4,7 @@ mod transitions;
 
 use crate::{
     context::RequestContext,
+    pagination::{Page, PageRequest},
     tool_registry::{
         domain::{
             McpServerHealthSnapshot, McpServerId, McpServerName, McpServerRegistration,
@@ -311,7 +312,7 @@ where
             .updated_server)
     }
 
-    /// Lists all registered MCP servers.
+    /// Lists a page of registered MCP servers, in registration order.
     ///
     /// # Errors
     ///
@@ -319,8 +320,9 @@ where
     pub async fn list_all(
         &self,
         ctx: &RequestContext,
-    ) -> McpServerLifecycleServiceResult<Vec<McpServerRegistration>> {
-        Ok(self.repository.list_all(ctx).await?)
+        page: PageRequest,
+    ) -> McpServerLifecycleServiceResult<Page<McpServerRegistration>> {
+        Ok(self.repository.list_all(ctx, page).await?)
     }
 
     /// Finds a registered server by name.
@@ -1,6 +1,7 @@
 //! Unit tests for MCP server lifecycle orchestration.
 
 use super::{McpServerLifecycleService, McpServerLifecycleServiceError, RegisterMcpServerRequest};
+use crate::pagination::PageRequest;
 use crate::{
     context::RequestContext,
     test_support::{HealthProbeFailureHost, other_tenant_ctx, test_request_ctx},
@@ -235,8 +236,20 @@ async fn duplicate_name_is_scoped_per_tenant(
         .await?;
 
     assert_ne!(first.id(), second.id());
-    assert_eq!(service.list_all(&ctx_a).await?.len(), 1);
-    assert_eq!(service.list_all(&ctx_b).await?.len(), 1);
+    assert_eq!(
+        service
+            .list_all(&ctx_a, PageRequest::default())
+            .await?
+            .len(),
+        1
+    );
+    assert_eq!(
+        service
+            .list_all(&ctx_b, PageRequest::default())
+            .await?
+            .len(),
+        1
+    );
     let found_a = service
         .find_by_name(&ctx_a, "workspace_tools")
         .await?
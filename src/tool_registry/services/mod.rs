@@ -1,8 +1,12 @@
-//! Application services for MCP server lifecycle, tool discovery, and
-//! call routing operations.
+//! Application services for MCP server lifecycle, tool discovery, call
+//! routing, and policy change impact evaluation.
 
 mod discovery;
 mod lifecycle;
+mod policy_impact;
+
+#[cfg(test)]
+mod policy_impact_tests;
 
 pub use discovery::{
     ServicePorts, ToolDiscoveryRoutingService, ToolDiscoveryRoutingServiceError,
@@ -12,3 +16,7 @@ pub use lifecycle::{
     LifecycleStartResult, McpServerLifecycleService, McpServerLifecycleServiceError,
     RegisterMcpServerRequest,
 };
+pub use policy_impact::{
+    DEFAULT_POLICY_IMPACT_ALERT_PERCENT, PolicyImpactRequest, PolicyImpactService,
+    PolicyImpactServiceError, PolicyImpactServiceResult,
+};
@@ -0,0 +1,235 @@
+//! Replays recent tool calls against a candidate governance policy.
+//!
+//! The replay is a dry run: each call the current policy let through is
+//! rebuilt from the conversation history and passed to the candidate's
+//! [`ToolExecutionGovernance::enforce_before_call`], but nothing is sent to
+//! an MCP server.
+
+use crate::context::RequestContext;
+use crate::message::{
+    domain::{ContentPart, ConversationId, Message, ToolCallPart},
+    error::RepositoryError,
+    ports::MessageRepository,
+};
+use crate::pagination::collect_pages;
+use crate::tool_registry::{
+    domain::{
+        BlockedToolCall, CatalogEntry, ConversationPolicyImpact, PolicyImpactReport,
+        ReplayedToolCall, SkippedToolCall, ToolCallRequest, ToolGovernanceDecision,
+    },
+    ports::{ToolCatalogError, ToolCatalogRepository, ToolExecutionGovernance},
+};
+use chrono::{DateTime, Utc};
+use mockable::Clock;
+use std::collections::HashSet;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Blocked percentage at which a conversation raises an alert, unless the
+/// request sets its own.
+pub const DEFAULT_POLICY_IMPACT_ALERT_PERCENT: u8 = 10;
+
+/// Result type for policy impact evaluations.
+pub type PolicyImpactServiceResult<T> = Result<T, PolicyImpactServiceError>;
+
+/// Errors returned by [`PolicyImpactService`].
+#[derive(Debug, Error)]
+pub enum PolicyImpactServiceError {
+    /// The message repository failed.
+    #[error(transparent)]
+    Messages(#[from] RepositoryError),
+
+    /// The tool catalog failed.
+    #[error(transparent)]
+    Catalog(#[from] ToolCatalogError),
+}
+
+/// The recent activity to replay against a candidate policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyImpactRequest {
+    /// Conversations whose tool calls are replayed.
+    pub conversations: Vec<ConversationId>,
+    /// Only calls in messages created at or after this instant are replayed.
+    pub since: DateTime<Utc>,
+    /// Blocked percentage at which a conversation raises an alert.
+    pub alert_threshold_percent: u8,
+}
+
+impl PolicyImpactRequest {
+    /// Creates a request using [`DEFAULT_POLICY_IMPACT_ALERT_PERCENT`].
+    #[must_use]
+    pub const fn new(conversations: Vec<ConversationId>, since: DateTime<Utc>) -> Self {
+        Self {
+            conversations,
+            since,
+            alert_threshold_percent: DEFAULT_POLICY_IMPACT_ALERT_PERCENT,
+        }
+    }
+
+    /// Sets the alert threshold, capped at 100 percent.
+    #[must_use]
+    pub fn with_alert_threshold(mut self, percent: u8) -> Self {
+        self.alert_threshold_percent = percent.min(100);
+        self
+    }
+}
+
+/// Service that reports which previously allowed tool calls a new policy
+/// would block.
+///
+/// A call counts as previously allowed when the conversation holds a result
+/// for it, because calls the current policy denied never reach a tool.
+/// Calls whose tool has since left the catalog, whose name is ambiguous, or
+/// on which the candidate policy fails to decide are reported as skipped.
+#[derive(Clone)]
+pub struct PolicyImpactService {
+    messages: Arc<dyn MessageRepository>,
+    catalog: Arc<dyn ToolCatalogRepository>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl PolicyImpactService {
+    /// Creates a policy impact service.
+    #[must_use]
+    pub fn new(
+        messages: Arc<dyn MessageRepository>,
+        catalog: Arc<dyn ToolCatalogRepository>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            messages,
+            catalog,
+            clock,
+        }
+    }
+
+    /// Replays the requested conversations' recent tool calls against
+    /// `candidate`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolicyImpactServiceError`] when the message repository or
+    /// the tool catalog fails.
+    pub async fn evaluate(
+        &self,
+        ctx: &RequestContext,
+        candidate: &dyn ToolExecutionGovernance,
+        request: &PolicyImpactRequest,
+    ) -> PolicyImpactServiceResult<PolicyImpactReport> {
+        let mut conversations = Vec::with_capacity(request.conversations.len());
+        for conversation_id in &request.conversations {
+            let history = collect_pages(|page| {
+                self.messages
+                    .find_by_conversation(ctx, *conversation_id, page)
+            })
+            .await?;
+            let mut impact = ConversationPolicyImpact::new(*conversation_id);
+            for (message, call) in executed_calls_since(&history, request.since) {
+                let outcome = self
+                    .replay(ctx, candidate, (*conversation_id, call))
+                    .await?;
+                let replayed = ReplayedToolCall {
+                    conversation_id: *conversation_id,
+                    message_id: message.id(),
+                    call_id: call.call_id.clone(),
+                    tool_name: call.name.clone(),
+                };
+                record(&mut impact, replayed, outcome);
+            }
+            conversations.push(impact);
+        }
+        Ok(PolicyImpactReport {
+            conversations,
+            alert_threshold_percent: request.alert_threshold_percent,
+        })
+    }
+
+    async fn replay(
+        &self,
+        ctx: &RequestContext,
+        candidate: &dyn ToolExecutionGovernance,
+        (conversation_id, call): (ConversationId, &ToolCallPart),
+    ) -> PolicyImpactServiceResult<ReplayOutcome> {
+        let entry = match self.resolve(ctx, &call.name).await? {
+            Ok(entry) => entry,
+            Err(reason) => return Ok(ReplayOutcome::Skipped(reason)),
+        };
+        let request = ToolCallRequest::new(&call.name, call.arguments.clone(), &*self.clock)
+            .with_conversation_id(conversation_id);
+        Ok(
+            match candidate.enforce_before_call(ctx, &request, &entry).await {
+                Ok(ToolGovernanceDecision::Allow) => ReplayOutcome::Allowed,
+                Ok(ToolGovernanceDecision::Deny { reason }) => ReplayOutcome::Blocked(reason),
+                Err(err) => ReplayOutcome::Skipped(err.to_string()),
+            },
+        )
+    }
+
+    /// Finds the single catalog entry a call would route to, or explains why
+    /// there is none.
+    async fn resolve(
+        &self,
+        ctx: &RequestContext,
+        tool_name: &str,
+    ) -> PolicyImpactServiceResult<Result<CatalogEntry, String>> {
+        let mut entries: Vec<CatalogEntry> =
+            collect_pages(|page| self.catalog.find_by_tool_name(ctx, tool_name, page))
+                .await?
+                .into_iter()
+                .filter(CatalogEntry::available)
+                .collect();
+        Ok(match (entries.pop(), entries.is_empty()) {
+            (Some(entry), true) => Ok(entry),
+            (Some(_), false) => Err(format!(
+                "tool '{tool_name}' is advertised by more than one server"
+            )),
+            (None, _) => Err(format!("tool '{tool_name}' is no longer available")),
+        })
+    }
+}
+
+enum ReplayOutcome {
+    Allowed,
+    Blocked(String),
+    Skipped(String),
+}
+
+fn record(impact: &mut ConversationPolicyImpact, call: ReplayedToolCall, outcome: ReplayOutcome) {
+    match outcome {
+        ReplayOutcome::Allowed => impact.replayed += 1,
+        ReplayOutcome::Blocked(reason) => {
+            impact.replayed += 1;
+            impact.blocked.push(BlockedToolCall { call, reason });
+        }
+        ReplayOutcome::Skipped(reason) => impact.skipped.push(SkippedToolCall { call, reason }),
+    }
+}
+
+/// Returns the tool calls made at or after `since` that have a recorded
+/// result, in conversation order.
+fn executed_calls_since(
+    history: &[Message],
+    since: DateTime<Utc>,
+) -> Vec<(&Message, &ToolCallPart)> {
+    let executed_set: HashSet<&str> = history
+        .iter()
+        .flat_map(Message::content)
+        .filter_map(|part| match part {
+            ContentPart::ToolResult(result) => Some(result.call_id.as_str()),
+            _ => None,
+        })
+        .collect();
+    let executed = &executed_set;
+    history
+        .iter()
+        .filter(|message| message.created_at() >= since)
+        .flat_map(|message| {
+            message.content().iter().filter_map(move |part| match part {
+                ContentPart::ToolCall(call) if executed.contains(call.call_id.as_str()) => {
+                    Some((message, call))
+                }
+                _ => None,
+            })
+        })
+        .collect()
+}
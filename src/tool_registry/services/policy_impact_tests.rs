@@ -0,0 +1,246 @@
+//! Unit tests for replaying recent tool calls against a candidate policy.
+
+use crate::context::RequestContext;
+use crate::message::{
+    adapters::memory::InMemoryMessageRepository,
+    domain::{
+        ContentPart, ConversationId, Message, MessageId, MessageMetadata, Role, SequenceNumber,
+        ToolCallPart, ToolResultPart,
+    },
+    ports::MessageRepository,
+};
+use crate::test_support::test_request_ctx;
+use crate::tool_registry::{
+    adapters::{StubGovernance, memory::InMemoryToolCatalog},
+    domain::{
+        CatalogEntry, McpServerId, McpServerName, McpToolDefinition, ToolCallRequest,
+        ToolGovernanceDecision,
+    },
+    ports::{ToolCatalogRepository, ToolExecutionGovernance, ToolGovernanceResult},
+    services::{PolicyImpactRequest, PolicyImpactService},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use mockable::{Clock, DefaultClock};
+use rstest::rstest;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Candidate policy that denies one tool by name.
+struct DenyTool(&'static str);
+
+#[async_trait]
+impl ToolExecutionGovernance for DenyTool {
+    async fn enforce_before_call(
+        &self,
+        _ctx: &RequestContext,
+        request: &ToolCallRequest,
+        _entry: &CatalogEntry,
+    ) -> ToolGovernanceResult<ToolGovernanceDecision> {
+        if request.tool_name() == self.0 {
+            return Ok(ToolGovernanceDecision::Deny {
+                reason: format!("{} needs approval", self.0),
+            });
+        }
+        Ok(ToolGovernanceDecision::Allow)
+    }
+}
+
+struct Harness {
+    ctx: RequestContext,
+    service: PolicyImpactService,
+    messages: Arc<InMemoryMessageRepository>,
+    now: DateTime<Utc>,
+}
+
+impl Harness {
+    async fn store(
+        &self,
+        conversation_id: ConversationId,
+        (sequence, age, content): (u64, Duration, Vec<ContentPart>),
+    ) -> MessageId {
+        let message = Message::from_persisted(
+            MessageId::new(),
+            conversation_id,
+            Role::Assistant,
+            content,
+            MessageMetadata::default(),
+            self.now - age,
+            SequenceNumber::new(sequence),
+        )
+        .expect("valid message");
+        self.messages
+            .store(&self.ctx, &message)
+            .await
+            .expect("message stored");
+        message.id()
+    }
+}
+
+async fn harness() -> Harness {
+    let ctx = test_request_ctx();
+    let catalog = Arc::new(InMemoryToolCatalog::new());
+    let server_id = McpServerId::new();
+    let server_name = McpServerName::new("workspace_tools").expect("valid server name");
+    let entries: Vec<CatalogEntry> = ["read_file", "delete_branch"]
+        .into_iter()
+        .map(|name| {
+            let tool = McpToolDefinition::new(name, "Workspace tool", json!({"type": "object"}))
+                .expect("valid tool");
+            CatalogEntry::new(server_id, server_name.clone(), tool, &DefaultClock)
+        })
+        .collect();
+    catalog
+        .sync_server_tools(&ctx, server_id, &entries)
+        .await
+        .expect("catalog synced");
+    let messages = Arc::new(InMemoryMessageRepository::new());
+    let service = PolicyImpactService::new(messages.clone(), catalog, Arc::new(DefaultClock));
+    Harness {
+        ctx,
+        service,
+        messages,
+        now: DefaultClock.utc(),
+    }
+}
+
+fn call(call_id: &str, tool_name: &str) -> ContentPart {
+    ContentPart::ToolCall(ToolCallPart::new(call_id, tool_name, json!({})))
+}
+
+fn result(call_id: &str) -> ContentPart {
+    ContentPart::ToolResult(ToolResultPart::success(call_id, json!("ok")))
+}
+
+async fn seed_conversation(harness: &Harness) -> (ConversationId, MessageId) {
+    let conversation_id = ConversationId::new();
+    let old = Duration::hours(2);
+    let recent = Duration::minutes(5);
+    harness
+        .store(
+            conversation_id,
+            (1, old, vec![call("c0", "delete_branch"), result("c0")]),
+        )
+        .await;
+    let recent_calls = harness
+        .store(
+            conversation_id,
+            (
+                2,
+                recent,
+                vec![
+                    call("c1", "read_file"),
+                    call("c2", "delete_branch"),
+                    call("c3", "delete_branch"),
+                    call("c4", "retired_tool"),
+                ],
+            ),
+        )
+        .await;
+    harness
+        .store(
+            conversation_id,
+            (3, recent, vec![result("c1"), result("c2"), result("c4")]),
+        )
+        .await;
+    (conversation_id, recent_calls)
+}
+
+#[rstest]
+#[tokio::test]
+async fn reports_previously_allowed_calls_the_new_policy_blocks() {
+    let harness = harness().await;
+    let (busy, recent_calls) = seed_conversation(&harness).await;
+    let quiet = ConversationId::new();
+    harness
+        .store(
+            quiet,
+            (
+                1,
+                Duration::minutes(1),
+                vec![call("c5", "read_file"), result("c5")],
+            ),
+        )
+        .await;
+    let request = PolicyImpactRequest::new(vec![busy, quiet], harness.now - Duration::hours(1))
+        .with_alert_threshold(50);
+
+    let report = harness
+        .service
+        .evaluate(&harness.ctx, &DenyTool("delete_branch"), &request)
+        .await
+        .expect("evaluation succeeds");
+
+    let blocked: Vec<_> = report
+        .blocked()
+        .map(|blocked| {
+            (
+                blocked.call.conversation_id,
+                blocked.call.message_id,
+                blocked.call.call_id.as_str(),
+                blocked.reason.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        blocked,
+        vec![(busy, recent_calls, "c2", "delete_branch needs approval")]
+    );
+    let per_conversation: Vec<_> = report
+        .conversations
+        .iter()
+        .map(|impact| {
+            (
+                impact.replayed,
+                impact.skipped.len(),
+                impact.blocked_percent(),
+            )
+        })
+        .collect();
+    assert_eq!(per_conversation, vec![(2, 1, 50), (1, 0, 0)]);
+    assert!(
+        report
+            .conversations
+            .first()
+            .and_then(|impact| impact.skipped.first())
+            .is_some_and(|skipped| skipped.call.call_id == "c4")
+    );
+    assert_eq!(
+        report
+            .alerts()
+            .map(|impact| impact.conversation_id)
+            .collect::<Vec<_>>(),
+        vec![busy]
+    );
+    assert!(!report.is_clear());
+}
+
+#[rstest]
+#[tokio::test]
+async fn policy_failures_skip_calls_instead_of_blocking_them() {
+    let harness = harness().await;
+    let (conversation_id, _) = seed_conversation(&harness).await;
+    let request = PolicyImpactRequest::new(vec![conversation_id], harness.now - Duration::hours(3));
+
+    let report = harness
+        .service
+        .evaluate(
+            &harness.ctx,
+            &StubGovernance::failing("policy engine offline"),
+            &request,
+        )
+        .await
+        .expect("evaluation succeeds");
+
+    assert_eq!(report.replayed(), 0);
+    assert!(report.is_clear());
+    assert_eq!(report.alerts().count(), 0);
+    assert_eq!(
+        report
+            .conversations
+            .iter()
+            .map(|impact| impact.skipped.len())
+            .sum::<usize>(),
+        4
+    );
+}
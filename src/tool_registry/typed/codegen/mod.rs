@@ -0,0 +1,191 @@
+//! Rust source generation from MCP tool input schemas.
+//!
+//! Each tool becomes a `<ToolName>Args` struct whose fields mirror the
+//! schema's `properties`. JSON Schema types map onto Rust types as follows:
+//!
+//! | Schema                         | Rust                    |
+//! |--------------------------------|-------------------------|
+//! | `string`                       | `String`                |
+//! | `integer`                      | `i64`                   |
+//! | `number`                       | `f64`                   |
+//! | `boolean`                      | `bool`                  |
+//! | `array`                        | `Vec<T>` of its `items` |
+//! | `object` with `properties`     | a nested struct         |
+//! | anything else                  | `serde_json::Value`     |
+//!
+//! Properties missing from `required`, or whose type admits `null`, become
+//! `Option<T>` and are omitted from the payload when unset.
+
+mod schema;
+
+use crate::tool_registry::domain::McpToolDefinition;
+use schema::{FieldDef, SchemaWalker, StructDef};
+use std::collections::BTreeSet;
+use thiserror::Error;
+
+/// Errors raised while generating tool bindings.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ToolBindingError {
+    /// A tool's input schema cannot be expressed as a Rust struct.
+    #[error("unsupported input schema for tool '{tool_name}': {reason}")]
+    UnsupportedSchema {
+        /// The tool whose schema was rejected.
+        tool_name: String,
+        /// Why the schema was rejected.
+        reason: String,
+    },
+    /// Two properties map to the same Rust field name.
+    #[error("tool '{tool_name}' has properties that collide as field '{field}'")]
+    DuplicateField {
+        /// The tool whose properties collide.
+        tool_name: String,
+        /// The shared Rust field name.
+        field: String,
+    },
+    /// Two tools or nested objects map to the same Rust type name.
+    #[error("tool '{tool_name}' generates type '{type_name}', which already exists")]
+    DuplicateType {
+        /// The tool that produced the second definition.
+        tool_name: String,
+        /// The shared Rust type name.
+        type_name: String,
+    },
+}
+
+/// Generates typed argument structs for MCP tools.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::tool_registry::{domain::McpToolDefinition, typed::ToolBindingGenerator};
+/// use serde_json::json;
+///
+/// let tool = McpToolDefinition::new(
+///     "read_file",
+///     "Read a file from the workspace",
+///     json!({
+///         "type": "object",
+///         "properties": {"path": {"type": "string"}},
+///         "required": ["path"]
+///     }),
+/// )
+/// .expect("valid tool");
+///
+/// let source = ToolBindingGenerator::new()
+///     .generate(&[tool])
+///     .expect("supported schema");
+/// assert!(source.contains("pub struct ReadFileArgs"));
+/// assert!(source.contains("pub path: String,"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolBindingGenerator {
+    crate_path: String,
+}
+
+impl Default for ToolBindingGenerator {
+    fn default() -> Self {
+        Self {
+            crate_path: "::corbusier".to_owned(),
+        }
+    }
+}
+
+impl ToolBindingGenerator {
+    /// Creates a generator whose output refers to this crate as
+    /// `::corbusier`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the path used to refer to this crate from the generated code,
+    /// for example `crate` when the bindings live inside it.
+    #[must_use]
+    pub fn with_crate_path(mut self, crate_path: impl Into<String>) -> Self {
+        self.crate_path = crate_path.into();
+        self
+    }
+
+    /// Generates Rust source declaring one argument struct per tool.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolBindingError`] when a schema is not an object schema or
+    /// when generated names collide.
+    pub fn generate(&self, tools: &[McpToolDefinition]) -> Result<String, ToolBindingError> {
+        let mut type_names = BTreeSet::new();
+        let mut lines = vec![
+            "// @generated by corbusier's typed tool binding generator. Do not edit.".to_owned(),
+        ];
+        for tool in tools {
+            let structs = SchemaWalker::walk(tool)?;
+            if let Some(duplicate) = structs
+                .iter()
+                .find(|definition| !type_names.insert(definition.name.clone()))
+            {
+                return Err(ToolBindingError::DuplicateType {
+                    tool_name: tool.name().to_owned(),
+                    type_name: duplicate.name.clone(),
+                });
+            }
+            for definition in &structs {
+                lines.push(String::new());
+                self.render(&mut lines, definition);
+            }
+        }
+        lines.push(String::new());
+        Ok(lines.join("\n"))
+    }
+
+    fn render(&self, lines: &mut Vec<String>, definition: &StructDef) {
+        push_doc(lines, "", &definition.doc);
+        let derives = if definition.has_float {
+            "Debug, Clone, PartialEq"
+        } else {
+            "Debug, Clone, PartialEq, Eq"
+        };
+        lines.push(format!(
+            "#[derive({derives}, serde::Serialize, serde::Deserialize)]"
+        ));
+        lines.push(format!("pub struct {} {{", definition.name));
+        for field in &definition.fields {
+            render_field(lines, field);
+        }
+        lines.push("}".to_owned());
+        if let Some(tool_name) = &definition.tool_name {
+            lines.extend([
+                String::new(),
+                format!(
+                    "impl {}::tool_registry::typed::TypedTool for {} {{",
+                    self.crate_path, definition.name
+                ),
+                format!("    const TOOL_NAME: &'static str = {tool_name:?};"),
+                "}".to_owned(),
+            ]);
+        }
+    }
+}
+
+fn render_field(lines: &mut Vec<String>, field: &FieldDef) {
+    push_doc(lines, "    ", &field.doc);
+    if field.ident.trim_start_matches("r#") != field.wire_name {
+        lines.push(format!("    #[serde(rename = {:?})]", field.wire_name));
+    }
+    if field.optional {
+        lines.push("    #[serde(default, skip_serializing_if = \"Option::is_none\")]".to_owned());
+        lines.push(format!("    pub {}: Option<{}>,", field.ident, field.ty));
+    } else {
+        lines.push(format!("    pub {}: {},", field.ident, field.ty));
+    }
+}
+
+fn push_doc(lines: &mut Vec<String>, indent: &str, doc: &str) {
+    lines.extend(doc.lines().map(|line| {
+        let text = line.trim_end();
+        if text.is_empty() {
+            format!("{indent}///")
+        } else {
+            format!("{indent}/// {text}")
+        }
+    }));
+}
@@ -0,0 +1,269 @@
+//! Walking tool input schemas into the struct definitions to emit.
+
+use super::ToolBindingError;
+use crate::tool_registry::domain::McpToolDefinition;
+use serde_json::{Map, Value};
+
+/// A struct to emit.
+pub(super) struct StructDef {
+    pub(super) name: String,
+    pub(super) doc: String,
+    pub(super) tool_name: Option<String>,
+    pub(super) fields: Vec<FieldDef>,
+    pub(super) has_float: bool,
+}
+
+/// A field of an emitted struct.
+pub(super) struct FieldDef {
+    pub(super) ident: String,
+    pub(super) wire_name: String,
+    pub(super) doc: String,
+    pub(super) ty: String,
+    pub(super) optional: bool,
+}
+
+struct RustType {
+    ty: String,
+    nullable: bool,
+    has_float: bool,
+}
+
+/// Walks one tool's input schema, collecting the structs it needs.
+pub(super) struct SchemaWalker<'a> {
+    tool: &'a McpToolDefinition,
+    structs: Vec<StructDef>,
+}
+
+impl<'a> SchemaWalker<'a> {
+    pub(super) fn walk(tool: &'a McpToolDefinition) -> Result<Vec<StructDef>, ToolBindingError> {
+        let mut walker = Self {
+            tool,
+            structs: Vec::new(),
+        };
+        let schema = tool.input_schema();
+        if !matches!(schema_type(schema), (None | Some("object"), false)) {
+            return Err(walker.unsupported("the input schema must be an object schema"));
+        }
+        let name = format!("{}Args", pascal_case(tool.name()));
+        let root = walker.object(&name, tool.description(), schema)?;
+        walker.structs.insert(
+            0,
+            StructDef {
+                tool_name: Some(tool.name().to_owned()),
+                ..root
+            },
+        );
+        Ok(walker.structs)
+    }
+
+    fn object(
+        &mut self,
+        name: &str,
+        doc: &str,
+        schema: &Value,
+    ) -> Result<StructDef, ToolBindingError> {
+        let empty = Map::new();
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+        let required: BTreeSet<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let mut definition = StructDef {
+            name: name.to_owned(),
+            doc: doc.to_owned(),
+            tool_name: None,
+            fields: Vec::with_capacity(properties.len()),
+            has_float: false,
+        };
+        for (wire_name, property) in properties {
+            let field = self.field(name, wire_name, property)?;
+            if definition
+                .fields
+                .iter()
+                .any(|existing| existing.ident == field.0.ident)
+            {
+                return Err(ToolBindingError::DuplicateField {
+                    tool_name: self.tool.name().to_owned(),
+                    field: field.0.ident,
+                });
+            }
+            let (mut field_def, has_float) = field;
+            field_def.optional |= !required.contains(wire_name.as_str());
+            definition.has_float |= has_float;
+            definition.fields.push(field_def);
+        }
+        Ok(definition)
+    }
+
+    fn field(
+        &mut self,
+        parent: &str,
+        wire_name: &str,
+        property: &Value,
+    ) -> Result<(FieldDef, bool), ToolBindingError> {
+        let ident = field_ident(wire_name).ok_or_else(|| {
+            self.unsupported(&format!("property '{wire_name}' has no usable name"))
+        })?;
+        let nested_name = format!("{parent}{}", pascal_case(wire_name));
+        let rust_type = self.property_type(&nested_name, property)?;
+        let doc =
+            describe(property).map_or_else(|| format!("`{wire_name}` parameter."), str::to_owned);
+        Ok((
+            FieldDef {
+                ident,
+                wire_name: wire_name.to_owned(),
+                doc,
+                ty: rust_type.ty,
+                optional: rust_type.nullable,
+            },
+            rust_type.has_float,
+        ))
+    }
+
+    fn property_type(
+        &mut self,
+        name: &str,
+        property: &Value,
+    ) -> Result<RustType, ToolBindingError> {
+        let (kind, nullable) = schema_type(property);
+        let (ty, has_float) = match kind {
+            Some("string") => ("String".to_owned(), false),
+            Some("integer") => ("i64".to_owned(), false),
+            Some("number") => ("f64".to_owned(), true),
+            Some("boolean") => ("bool".to_owned(), false),
+            Some("array") => self.array_type(name, property)?,
+            Some("object") if property.get("properties").is_some() => {
+                let index = self.structs.len();
+                let doc = describe(property).unwrap_or("Nested tool parameter object.");
+                let nested = self.object(name, doc, property)?;
+                let has_float = nested.has_float;
+                self.structs.insert(index, nested);
+                (name.to_owned(), has_float)
+            }
+            _ => ("serde_json::Value".to_owned(), false),
+        };
+        Ok(RustType {
+            ty,
+            nullable,
+            has_float,
+        })
+    }
+
+    fn array_type(
+        &mut self,
+        name: &str,
+        property: &Value,
+    ) -> Result<(String, bool), ToolBindingError> {
+        let Some(items) = property.get("items") else {
+            return Ok(("Vec<serde_json::Value>".to_owned(), false));
+        };
+        let item = self.property_type(&format!("{name}Item"), items)?;
+        let ty = if item.nullable {
+            format!("Vec<Option<{}>>", item.ty)
+        } else {
+            format!("Vec<{}>", item.ty)
+        };
+        Ok((ty, item.has_float))
+    }
+
+    fn unsupported(&self, reason: &str) -> ToolBindingError {
+        ToolBindingError::UnsupportedSchema {
+            tool_name: self.tool.name().to_owned(),
+            reason: reason.to_owned(),
+        }
+    }
+}
+
+/// Returns a schema's single non-null type and whether it admits `null`.
+///
+/// Schemas listing several non-null types yield `None`, as does a schema
+/// without a `type`.
+fn schema_type(schema: &Value) -> (Option<&str>, bool) {
+    match schema.get("type") {
+        Some(Value::String(kind)) => (Some(kind.as_str()), false),
+        Some(Value::Array(kinds)) => {
+            let names: Vec<&str> = kinds.iter().filter_map(Value::as_str).collect();
+            let nullable = names.contains(&"null");
+            let mut concrete = names.into_iter().filter(|kind| *kind != "null");
+            match (concrete.next(), concrete.next()) {
+                (Some(kind), None) => (Some(kind), nullable),
+                _ => (None, nullable),
+            }
+        }
+        _ => (None, false),
+    }
+}
+
+fn describe(schema: &Value) -> Option<&str> {
+    schema
+        .get("description")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|description| !description.is_empty())
+}
+
+fn words(name: &str) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+    for ch in name.chars() {
+        if !ch.is_ascii_alphanumeric() {
+            result.extend((!current.is_empty()).then(|| std::mem::take(&mut current)));
+            previous_lower = false;
+            continue;
+        }
+        if ch.is_ascii_uppercase() && previous_lower {
+            result.push(std::mem::take(&mut current));
+        }
+        previous_lower = ch.is_ascii_lowercase() || ch.is_ascii_digit();
+        current.push(ch.to_ascii_lowercase());
+    }
+    result.extend((!current.is_empty()).then_some(current));
+    result
+}
+
+fn pascal_case(name: &str) -> String {
+    let joined: String = words(name)
+        .iter()
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect();
+    if joined.starts_with(|ch: char| ch.is_ascii_digit()) {
+        format!("Tool{joined}")
+    } else {
+        joined
+    }
+}
+
+fn field_ident(name: &str) -> Option<String> {
+    let joined = words(name).join("_");
+    if joined.is_empty() {
+        return None;
+    }
+    if joined.starts_with(|ch: char| ch.is_ascii_digit()) {
+        return Some(format!("field_{joined}"));
+    }
+    Some(match joined.as_str() {
+        "self" | "super" | "crate" => format!("{joined}_"),
+        keyword if RESERVED.contains(&keyword) => format!("r#{joined}"),
+        _ => joined,
+    })
+}
+
+const RESERVED: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
@@ -0,0 +1,57 @@
+// @generated by corbusier's typed tool binding generator. Do not edit.
+
+/// Open an issue in the tracker.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateIssueArgs {
+    /// Who should pick the issue up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<CreateIssueArgsAssignee>,
+    /// `body` parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// `labels` parameter.
+    pub labels: Vec<String>,
+    /// `priority` parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// Issue title.
+    pub title: String,
+}
+
+impl crate::tool_registry::typed::TypedTool for CreateIssueArgs {
+    const TOOL_NAME: &'static str = "create_issue";
+}
+
+/// Who should pick the issue up.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateIssueArgsAssignee {
+    /// `login` parameter.
+    pub login: String,
+    /// `team` parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+}
+
+/// Search the workspace.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SearchCodeArgs {
+    /// `filters` parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filters: Option<serde_json::Value>,
+    /// `maxResults` parameter.
+    #[serde(rename = "maxResults")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// `min_score` parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_score: Option<f64>,
+    /// `query` parameter.
+    pub query: String,
+    /// `type` parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+impl crate::tool_registry::typed::TypedTool for SearchCodeArgs {
+    const TOOL_NAME: &'static str = "search-code";
+}
@@ -0,0 +1,75 @@
+//! Strongly-typed bindings for well-known MCP tools.
+//!
+//! Available with the `typed-tools` feature. [`ToolBindingGenerator`] turns
+//! the JSON Schemas of registered [`McpToolDefinition`]s into Rust argument
+//! structs, each implementing [`TypedTool`]. Orchestration code then builds
+//! tool calls from those structs, so a misspelt parameter or a value of the
+//! wrong type fails to compile rather than failing schema validation at
+//! call time.
+//!
+//! The generator emits plain Rust source, so it can run from a build script
+//! (writing into `OUT_DIR` for `include!`) or ahead of time, with the output
+//! checked in alongside the code that uses it.
+//!
+//! [`McpToolDefinition`]: crate::tool_registry::domain::McpToolDefinition
+
+mod codegen;
+
+pub use codegen::{ToolBindingError, ToolBindingGenerator};
+
+use crate::tool_registry::domain::{ToolCallRequest, ToolRegistryDomainError};
+use mockable::Clock;
+use serde::Serialize;
+
+/// Typed arguments for a named MCP tool.
+///
+/// Implementations are normally generated by [`ToolBindingGenerator`], but
+/// hand-written argument types work equally well.
+///
+/// # Examples
+///
+/// ```
+/// use corbusier::tool_registry::typed::TypedTool;
+/// use mockable::DefaultClock;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct ReadFileArgs {
+///     path: String,
+/// }
+///
+/// impl TypedTool for ReadFileArgs {
+///     const TOOL_NAME: &'static str = "read_file";
+/// }
+///
+/// let args = ReadFileArgs { path: "README.md".to_owned() };
+/// let request = args.to_call_request(&DefaultClock).expect("serializable");
+/// assert_eq!(request.tool_name(), "read_file");
+/// assert_eq!(request.parameters()["path"], "README.md");
+/// ```
+pub trait TypedTool: Serialize {
+    /// Name under which the tool is registered in the catalogue.
+    const TOOL_NAME: &'static str;
+
+    /// Builds a tool call request carrying these arguments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolRegistryDomainError::SchemaValidationFailed`] when the
+    /// arguments cannot be serialized to JSON.
+    fn to_call_request(
+        &self,
+        clock: &(impl Clock + ?Sized),
+    ) -> Result<ToolCallRequest, ToolRegistryDomainError> {
+        let parameters = serde_json::to_value(self).map_err(|err| {
+            ToolRegistryDomainError::SchemaValidationFailed {
+                tool_name: Self::TOOL_NAME.to_owned(),
+                reason: err.to_string(),
+            }
+        })?;
+        Ok(ToolCallRequest::new(Self::TOOL_NAME, parameters, clock))
+    }
+}
+
+#[cfg(test)]
+mod tests;
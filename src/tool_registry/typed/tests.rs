@@ -0,0 +1,164 @@
+//! Tests for typed tool bindings and their generator.
+
+use super::{ToolBindingError, ToolBindingGenerator, TypedTool};
+use crate::tool_registry::domain::{McpToolDefinition, validation::validate_parameters};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::{Value, json};
+
+mod generated {
+    include!("fixtures/workspace_tools.rs");
+}
+
+use generated::{CreateIssueArgs, CreateIssueArgsAssignee, SearchCodeArgs};
+
+fn tool(name: &str, input_schema: Value) -> McpToolDefinition {
+    McpToolDefinition::new(name, "Test tool", input_schema).expect("valid tool definition")
+}
+
+fn workspace_tools() -> Vec<McpToolDefinition> {
+    let create_issue = McpToolDefinition::new(
+        "create_issue",
+        "Open an issue in the tracker.",
+        json!({
+            "type": "object",
+            "properties": {
+                "title": {"type": "string", "description": "Issue title."},
+                "body": {"type": "string"},
+                "labels": {"type": "array", "items": {"type": "string"}},
+                "priority": {"type": "integer"},
+                "assignee": {
+                    "type": "object",
+                    "description": "Who should pick the issue up.",
+                    "properties": {
+                        "login": {"type": "string"},
+                        "team": {"type": ["string", "null"]}
+                    },
+                    "required": ["login", "team"]
+                }
+            },
+            "required": ["title", "labels"]
+        }),
+    )
+    .expect("valid tool definition");
+    let search_code = McpToolDefinition::new(
+        "search-code",
+        "Search the workspace.",
+        json!({
+            "type": "object",
+            "properties": {
+                "query": {"type": "string"},
+                "maxResults": {"type": "integer"},
+                "type": {"type": "string"},
+                "min_score": {"type": "number"},
+                "filters": {"type": "object"}
+            },
+            "required": ["query"]
+        }),
+    )
+    .expect("valid tool definition");
+    vec![create_issue, search_code]
+}
+
+#[rstest]
+fn generator_output_matches_checked_in_bindings() {
+    let source = ToolBindingGenerator::new()
+        .with_crate_path("crate")
+        .generate(&workspace_tools())
+        .expect("supported schemas");
+
+    assert_eq!(source, include_str!("fixtures/workspace_tools.rs"));
+}
+
+#[rstest]
+fn typed_arguments_build_schema_valid_requests() {
+    let args = CreateIssueArgs {
+        title: "Flaky test".to_owned(),
+        body: None,
+        labels: vec!["ci".to_owned()],
+        priority: Some(2),
+        assignee: Some(CreateIssueArgsAssignee {
+            login: "octocat".to_owned(),
+            team: None,
+        }),
+    };
+
+    let request = args.to_call_request(&DefaultClock).expect("serializable");
+
+    assert_eq!(request.tool_name(), "create_issue");
+    assert_eq!(
+        request.parameters(),
+        &json!({
+            "title": "Flaky test",
+            "labels": ["ci"],
+            "priority": 2,
+            "assignee": {"login": "octocat"}
+        })
+    );
+    let definitions = workspace_tools();
+    let schema = definitions.first().expect("create_issue").input_schema();
+    assert!(validate_parameters(schema, request.parameters()).is_ok());
+}
+
+#[rstest]
+fn renamed_and_keyword_properties_keep_their_wire_names() {
+    let args = SearchCodeArgs {
+        query: "fn main".to_owned(),
+        max_results: Some(5),
+        r#type: Some("rust".to_owned()),
+        min_score: None,
+        filters: None,
+    };
+
+    let request = args.to_call_request(&DefaultClock).expect("serializable");
+
+    assert_eq!(SearchCodeArgs::TOOL_NAME, "search-code");
+    assert_eq!(
+        request.parameters(),
+        &json!({"query": "fn main", "maxResults": 5, "type": "rust"})
+    );
+}
+
+#[rstest]
+#[case::array_root((
+    json!({"type": "array"}),
+    ToolBindingError::UnsupportedSchema {
+        tool_name: "broken".to_owned(),
+        reason: "the input schema must be an object schema".to_owned(),
+    },
+))]
+#[case::colliding_fields((
+    json!({"type": "object", "properties": {"repo-name": {}, "repo_name": {}}}),
+    ToolBindingError::DuplicateField {
+        tool_name: "broken".to_owned(),
+        field: "repo_name".to_owned(),
+    },
+))]
+#[case::unnamed_field((
+    json!({"type": "object", "properties": {"--": {}}}),
+    ToolBindingError::UnsupportedSchema {
+        tool_name: "broken".to_owned(),
+        reason: "property '--' has no usable name".to_owned(),
+    },
+))]
+fn unsupported_schemas_are_rejected(#[case] (schema, expected): (Value, ToolBindingError)) {
+    let result = ToolBindingGenerator::new().generate(&[tool("broken", schema)]);
+
+    assert_eq!(result, Err(expected));
+}
+
+#[rstest]
+fn tools_mapping_to_the_same_type_are_rejected() {
+    let schema = json!({"type": "object"});
+    let tools = [tool("read_file", schema.clone()), tool("read-file", schema)];
+
+    let result = ToolBindingGenerator::new().generate(&tools);
+
+    assert_eq!(
+        result,
+        Err(ToolBindingError::DuplicateType {
+            tool_name: "read-file".to_owned(),
+            type_name: "ReadFileArgs".to_owned(),
+        })
+    );
+}
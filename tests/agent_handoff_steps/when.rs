@@ -3,6 +3,7 @@
 use super::world::{HandoffWorld, run_async};
 use corbusier::message::domain::{HandoffSessionParams, SequenceNumber, TurnId};
 use corbusier::message::services::{CompleteHandoffParams, ServiceInitiateParams};
+use corbusier::operator::domain::OperatorReason;
 use eyre::{WrapErr, eyre};
 use rstest_bdd_macros::when;
 
@@ -83,12 +84,9 @@ fn cancel_handoff(world: &mut HandoffWorld) -> Result<(), eyre::Report> {
         .as_ref()
         .ok_or_else(|| eyre!("no current handoff"))?;
 
-    run_async(
-        world
-            .service
-            .cancel(&world.ctx, handoff.handoff_id, Some("target unavailable")),
-    )
-    .wrap_err("cancel handoff")?;
+    let reason = OperatorReason::new("target unavailable")?;
+    run_async(world.service.cancel(&world.ctx, handoff.handoff_id, &reason))
+        .wrap_err("cancel handoff")?;
 
     Ok(())
 }
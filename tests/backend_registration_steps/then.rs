@@ -4,13 +4,14 @@ use super::world::{BackendWorld, run_async};
 use corbusier::agent_backend::{
     ports::BackendRegistryError, services::BackendRegistryServiceError,
 };
+use corbusier::pagination::PageRequest;
 use rstest_bdd_macros::then;
 
 #[then("listing all backends returns {count:usize} entries")]
 fn list_all_returns_count(world: &mut BackendWorld, count: usize) -> Result<(), eyre::Report> {
-    let all = run_async(world.service.list_all(&world.ctx))
+    let all = run_async(world.service.list_all(&world.ctx, PageRequest::default()))
         .map_err(|err| eyre::eyre!("list_all failed: {err}"))?;
-    world.last_list_all_result = Some(all.clone());
+    world.last_list_all_result = Some(all.clone().into_items());
     if all.len() != count {
         return Err(eyre::eyre!(
             "expected {count} backends, found {}",
@@ -49,9 +50,13 @@ fn registration_fails_with_duplicate_name(world: &BackendWorld) -> Result<(), ey
 
 #[then(r#"listing active backends does not include "{name}""#)]
 fn active_listing_excludes(world: &mut BackendWorld, name: String) -> Result<(), eyre::Report> {
-    let active = run_async(world.service.list_active(&world.ctx))
-        .map_err(|err| eyre::eyre!("list_active failed: {err}"))?;
-    world.last_list_active_result = Some(active.clone());
+    let active = run_async(
+        world
+            .service
+            .list_active(&world.ctx, PageRequest::default()),
+    )
+    .map_err(|err| eyre::eyre!("list_active failed: {err}"))?;
+    world.last_list_active_result = Some(active.clone().into_items());
     if active.iter().any(|b| b.name().as_str() == name) {
         return Err(eyre::eyre!("expected active listing to exclude '{name}'"));
     }
@@ -60,7 +65,7 @@ fn active_listing_excludes(world: &mut BackendWorld, name: String) -> Result<(),
 
 #[then(r#"listing all backends still includes "{name}""#)]
 fn all_listing_includes(world: &mut BackendWorld, name: String) -> Result<(), eyre::Report> {
-    let all = run_async(world.service.list_all(&world.ctx))
+    let all = run_async(world.service.list_all(&world.ctx, PageRequest::default()))
         .map_err(|err| eyre::eyre!("list_all failed: {err}"))?;
     if !all.iter().any(|b| b.name().as_str() == name) {
         return Err(eyre::eyre!("expected all-listing to include '{name}'"));
@@ -12,6 +12,7 @@ use corbusier::message::{
     ports::validator::MessageValidator,
     validation::service::DefaultMessageValidator,
 };
+use corbusier::pagination::PageRequest;
 use eyre::{WrapErr, eyre};
 use mockable::DefaultClock;
 use rstest::fixture;
@@ -99,11 +100,11 @@ fn persist_tool_call_and_agent_response(world: &mut HistoryWorld) -> Result<(),
 
 #[then("the conversation history includes audit metadata")]
 fn history_includes_audit_metadata(world: &HistoryWorld) -> Result<(), eyre::Report> {
-    let history = run_async(
-        world
-            .repo
-            .find_by_conversation(&world.ctx, world.conversation_id),
-    )
+    let history = run_async(world.repo.find_by_conversation(
+        &world.ctx,
+        world.conversation_id,
+        PageRequest::default(),
+    ))
     .wrap_err("history fetch should succeed")?;
 
     let message = history
@@ -4,6 +4,7 @@ use super::async_utils::run_async;
 use super::world::HookWorld;
 use corbusier::hook_engine::domain::HookExecutionStatus;
 use corbusier::hook_engine::ports::{HookExecutionLogRepository, HookPolicyAuditRepository};
+use corbusier::pagination::PageRequest;
 use eyre::{WrapErr, ensure};
 use rstest_bdd_macros::then;
 
@@ -16,11 +17,11 @@ fn ensure_audit_events_for_failed(
         return Ok(());
     }
 
-    let audit_events = run_async(
-        world
-            .policy_audit
-            .find_by_trigger_context(&world.request_ctx, context.id()),
-    )?
+    let audit_events = run_async(world.policy_audit.find_by_trigger_context(
+        &world.request_ctx,
+        context.id(),
+        PageRequest::default(),
+    ))?
     .wrap_err("policy audit lookup failed")?;
     ensure!(
         !audit_events.is_empty(),
@@ -3,6 +3,7 @@
 use super::async_utils::run_async;
 use super::world::HookPolicyWorld;
 use corbusier::hook_engine::ports::HookPolicyAuditRepository;
+use corbusier::pagination::PageRequest;
 use corbusier::tool_registry::domain::ToolRegistryDomainError;
 use corbusier::tool_registry::services::ToolDiscoveryRoutingServiceError;
 use eyre::{WrapErr, eyre};
@@ -18,13 +19,13 @@ fn policy_audit_is_retrievable_by_conversation(
     let conversation_id = world
         .last_conversation_id
         .ok_or_else(|| eyre!("conversation id should be recorded"))?;
-    let events = run_async(
-        world
-            .policy_audit
-            .find_by_conversation(&world.request_ctx, conversation_id),
-    )?
+    let events = run_async(world.policy_audit.find_by_conversation(
+        &world.request_ctx,
+        conversation_id,
+        PageRequest::default(),
+    ))?
     .wrap_err("query policy audit by conversation")?;
-    world.last_events.clone_from(&events);
+    world.last_events = events.to_vec();
     if !result.outcome().is_success() {
         return Err(eyre!(
             "expected successful tool call outcome, got {:?}",
@@ -42,9 +43,13 @@ fn policy_audit_is_retrievable_by_task(world: &mut HookPolicyWorld) -> Result<()
     let task_id = world
         .last_task_id
         .ok_or_else(|| eyre!("task id should be recorded"))?;
-    let events = run_async(world.policy_audit.find_by_task(&world.request_ctx, task_id))?
-        .wrap_err("query policy audit by task")?;
-    world.last_events.clone_from(&events);
+    let events = run_async(world.policy_audit.find_by_task(
+        &world.request_ctx,
+        task_id,
+        PageRequest::default(),
+    ))?
+    .wrap_err("query policy audit by task")?;
+    world.last_events = events.to_vec();
     let Some(err) = &world.last_error else {
         return Err(eyre!("expected policy denial error"));
     };
@@ -70,11 +75,11 @@ fn policy_audit_is_retrievable_by_hook_event(
     let conversation_id = world
         .last_conversation_id
         .ok_or_else(|| eyre!("conversation id should be recorded"))?;
-    let events = run_async(
-        world
-            .policy_audit
-            .find_by_conversation(&world.request_ctx, conversation_id),
-    )?
+    let events = run_async(world.policy_audit.find_by_conversation(
+        &world.request_ctx,
+        conversation_id,
+        PageRequest::default(),
+    ))?
     .wrap_err("query policy audit by conversation")?;
     if events.len() != 1 {
         return Err(eyre!("expected 1 policy audit event, got {}", events.len()));
@@ -83,13 +88,13 @@ fn policy_audit_is_retrievable_by_hook_event(
         .first()
         .ok_or_else(|| eyre!("expected policy audit event"))?
         .trigger_context_id();
-    let by_event = run_async(
-        world
-            .policy_audit
-            .find_by_trigger_context(&world.request_ctx, trigger_context_id),
-    )?
+    let by_event = run_async(world.policy_audit.find_by_trigger_context(
+        &world.request_ctx,
+        trigger_context_id,
+        PageRequest::default(),
+    ))?
     .wrap_err("query policy audit by hook event")?;
-    world.last_events.clone_from(&by_event);
+    world.last_events = by_event.to_vec();
     if by_event.len() != 1 {
         return Err(eyre!(
             "expected 1 policy audit event by trigger, got {}",
@@ -118,9 +123,13 @@ fn no_policy_audit_is_persisted_for_task(world: &mut HookPolicyWorld) -> Result<
     let task_id = world
         .last_task_id
         .ok_or_else(|| eyre!("task id should be recorded"))?;
-    let events = run_async(world.policy_audit.find_by_task(&world.request_ctx, task_id))?
-        .wrap_err("query policy audit by task")?;
-    world.last_events.clone_from(&events);
+    let events = run_async(world.policy_audit.find_by_task(
+        &world.request_ctx,
+        task_id,
+        PageRequest::default(),
+    ))?
+    .wrap_err("query policy audit by task")?;
+    world.last_events = events.to_vec();
     if !events.is_empty() {
         return Err(eyre!(
             "expected no policy audit events for task, got {}",
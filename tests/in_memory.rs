@@ -9,6 +9,7 @@
 //! - `backend_registry_tests`: Agent backend registration and discovery
 //! - `mcp_server_lifecycle_tests`: MCP server registration and lifecycle
 //! - `tool_discovery_routing_tests`: Tool discovery, catalog, and call routing
+//! - `tool_secret_injection_tests`: Per-tool secret injection at spawn time
 //! - `hook_engine_tests`: Hook execution and persistence
 //! - `agent_turn_orchestration_tests`: Turn execution and session continuity
 //! - `http_api_surface_tests`: HTTP API surface tests
@@ -32,4 +33,5 @@ mod in_memory {
     mod slash_command_tests;
     mod task_lifecycle_tests;
     mod tool_discovery_routing_tests;
+    mod tool_secret_injection_tests;
 }
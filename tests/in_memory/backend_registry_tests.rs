@@ -10,6 +10,7 @@ use corbusier::agent_backend::{
     services::{BackendRegistryService, BackendRegistryServiceError, RegisterBackendRequest},
 };
 use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
 use mockable::DefaultClock;
 use rstest::{fixture, rstest};
 
@@ -46,7 +47,7 @@ async fn register_two_backends_and_list(service: TestService, ctx: RequestContex
         .expect("second registration should succeed");
 
     let all = service
-        .list_all(&ctx)
+        .list_all(&ctx, PageRequest::default())
         .await
         .expect("listing should succeed");
     assert_eq!(all.len(), 2);
@@ -74,7 +75,7 @@ async fn register_deactivate_list_active_returns_one(service: TestService, ctx:
         .expect("deactivation should succeed");
 
     let active = service
-        .list_active(&ctx)
+        .list_active(&ctx, PageRequest::default())
         .await
         .expect("listing should succeed");
     assert_eq!(active.len(), 1);
@@ -144,13 +145,13 @@ async fn register_under_one_tenant_is_invisible_to_another(
         .expect("registration under tenant A should succeed");
 
     let all_b = service
-        .list_all(&ctx_b)
+        .list_all(&ctx_b, PageRequest::default())
         .await
         .expect("listing under tenant B should succeed");
     assert!(all_b.is_empty(), "tenant B must not see tenant A backends");
 
     let active_b = service
-        .list_active(&ctx_b)
+        .list_active(&ctx_b, PageRequest::default())
         .await
         .expect("listing active under tenant B should succeed");
     assert!(
@@ -185,13 +186,13 @@ async fn duplicate_name_is_scoped_per_tenant(service: TestService, ctx: RequestC
         .expect("same name under tenant B should succeed");
 
     let all_a = service
-        .list_all(&ctx)
+        .list_all(&ctx, PageRequest::default())
         .await
         .expect("listing under tenant A should succeed");
     assert_eq!(all_a.len(), 1, "tenant A should see exactly one backend");
 
     let all_b = service
-        .list_all(&ctx_b)
+        .list_all(&ctx_b, PageRequest::default())
         .await
         .expect("listing under tenant B should succeed");
     assert_eq!(all_b.len(), 1, "tenant B should see exactly one backend");
@@ -220,7 +221,7 @@ async fn deactivate_under_one_tenant_does_not_affect_another(
         .expect("deactivation under tenant A should succeed");
 
     let active_a = service
-        .list_active(&ctx)
+        .list_active(&ctx, PageRequest::default())
         .await
         .expect("listing active under tenant A should succeed");
     assert!(
@@ -229,7 +230,7 @@ async fn deactivate_under_one_tenant_does_not_affect_another(
     );
 
     let active_b = service
-        .list_active(&ctx_b)
+        .list_active(&ctx_b, PageRequest::default())
         .await
         .expect("listing active under tenant B should succeed");
     assert_eq!(active_b.len(), 1, "tenant B backend should remain active");
@@ -11,6 +11,7 @@ use corbusier::message::{
     domain::{ContentPart, Message, Role, SequenceNumber, TextPart},
     ports::repository::MessageRepository,
 };
+use corbusier::pagination::PageRequest;
 use rstest::rstest;
 use std::io;
 use tokio::runtime::Runtime;
@@ -24,11 +25,11 @@ fn stores_messages_in_order(
     let rt = runtime?;
     store_conversation_messages(&rt, &scenario)?;
 
-    let messages = rt.block_on(
-        scenario
-            .repo
-            .find_by_conversation(&scenario.ctx, scenario.conversation_id),
-    )?;
+    let messages = rt.block_on(scenario.repo.find_by_conversation(
+        &scenario.ctx,
+        scenario.conversation_id,
+        PageRequest::default(),
+    ))?;
 
     verify_message_ordering(&messages);
     Ok(())
@@ -43,11 +44,11 @@ fn preserves_roles(
     let rt = runtime?;
     store_conversation_messages(&rt, &scenario)?;
 
-    let messages = rt.block_on(
-        scenario
-            .repo
-            .find_by_conversation(&scenario.ctx, scenario.conversation_id),
-    )?;
+    let messages = rt.block_on(scenario.repo.find_by_conversation(
+        &scenario.ctx,
+        scenario.conversation_id,
+        PageRequest::default(),
+    ))?;
 
     verify_role_preservation(&messages);
     Ok(())
@@ -116,8 +117,13 @@ fn concurrent_access_pattern_with_cloned_repository(
     )?;
     rt.block_on(repo_clone.store(&ctx, &msg2))?;
 
-    let from_original = rt.block_on(repo.find_by_conversation(&ctx, conversation_id))?;
-    let from_clone = rt.block_on(repo_clone.find_by_conversation(&ctx, conversation_id))?;
+    let from_original =
+        rt.block_on(repo.find_by_conversation(&ctx, conversation_id, PageRequest::default()))?;
+    let from_clone = rt.block_on(repo_clone.find_by_conversation(
+        &ctx,
+        conversation_id,
+        PageRequest::default(),
+    ))?;
 
     assert_eq!(from_original.len(), 2);
     assert_eq!(from_clone.len(), 2);
@@ -7,6 +7,11 @@ use corbusier::message::domain::{
 };
 use corbusier::message::ports::{agent_session::AgentSessionRepository, handoff::AgentHandoffPort};
 use corbusier::message::services::ServiceInitiateParams;
+use corbusier::operator::{
+    domain::{OperatorActionKind, OperatorActionQuery, OperatorReason, OperatorSubject},
+    ports::OperatorActionRepository,
+};
+use corbusier::pagination::PageRequest;
 use mockable::DefaultClock;
 use rstest::rstest;
 use tokio::runtime::Runtime;
@@ -48,9 +53,10 @@ fn cancel_handoff_reverts_source_session(
             .expect("initiate");
 
         let reason = "target agent unavailable";
+        let operator_reason = OperatorReason::new(reason).expect("valid reason");
         harness
             .service
-            .cancel(&ctx, handoff.handoff_id, Some(reason))
+            .cancel(&ctx, handoff.handoff_id, &operator_reason)
             .await
             .expect("cancel");
 
@@ -72,5 +78,26 @@ fn cancel_handoff_reverts_source_session(
             .expect("handoff exists");
 
         assert_eq!(stored.reason, Some(reason.to_owned()));
+
+        let audited = harness
+            .operator_actions
+            .query(
+                &ctx,
+                OperatorActionQuery::for_conversation(conversation_id),
+                PageRequest::default(),
+            )
+            .await
+            .expect("operator actions");
+        let action = audited.items().first().expect("cancellation recorded");
+        assert_eq!(action.kind, OperatorActionKind::HandoffCancelled);
+        assert_eq!(action.actor, ctx.user_id());
+        assert_eq!(action.reason, operator_reason);
+        assert_eq!(
+            action.subject,
+            OperatorSubject::Handoff {
+                handoff_id: handoff.handoff_id,
+                conversation_id: Some(conversation_id),
+            }
+        );
     });
 }
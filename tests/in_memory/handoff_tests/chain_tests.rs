@@ -2,13 +2,19 @@
 
 use super::harness::{HandoffTestHarness, TestResult, clock, ctx, harness, runtime};
 use corbusier::context::RequestContext;
+use corbusier::message::adapters::memory::InMemoryHandoffChainAdapter;
 use corbusier::message::domain::{
     AgentSession, ConversationId, HandoffSessionParams, HandoffStatus, SequenceNumber, TurnId,
 };
-use corbusier::message::ports::{agent_session::AgentSessionRepository, handoff::AgentHandoffPort};
+use corbusier::message::ports::{
+    agent_session::AgentSessionRepository,
+    handoff::{AgentHandoffPort, HandoffError},
+};
 use corbusier::message::services::{CompleteHandoffParams, ServiceInitiateParams};
+use corbusier::pagination::{Limit, PageRequest};
 use mockable::DefaultClock;
 use rstest::rstest;
+use std::sync::Arc;
 use tokio::runtime::Runtime;
 
 /// Parameters for completing a handoff to a target agent in tests.
@@ -113,7 +119,7 @@ fn handoff_chain_tracks_all_sessions(
 
         let sessions = harness
             .session_repo
-            .find_by_conversation(&ctx, conversation_id)
+            .find_by_conversation(&ctx, conversation_id, PageRequest::default())
             .await
             .expect("list");
 
@@ -127,7 +133,7 @@ fn handoff_chain_tracks_all_sessions(
 
         let handoffs = harness
             .handoff_adapter
-            .list_handoffs_for_conversation(&ctx, conversation_id)
+            .list_handoffs_for_conversation(&ctx, conversation_id, PageRequest::default())
             .await
             .expect("list handoffs");
 
@@ -139,6 +145,26 @@ fn handoff_chain_tracks_all_sessions(
             return Err(err);
         }
 
+        let first = PageRequest::new(Limit::new(1).expect("valid limit"));
+        let first_page = harness
+            .handoff_adapter
+            .list_handoffs_for_conversation(&ctx, conversation_id, first)
+            .await
+            .expect("list first handoff page");
+        let cursor = first_page.next_cursor().expect("second page follows");
+        let second_page = harness
+            .handoff_adapter
+            .list_handoffs_for_conversation(&ctx, conversation_id, first.with_cursor(cursor))
+            .await
+            .expect("list second handoff page");
+        let paged: Vec<_> = first_page.iter().chain(&second_page).collect();
+        if paged != handoffs.iter().collect::<Vec<_>>() || !second_page.is_last() {
+            let err = Box::new(std::io::Error::other(
+                "expected one handoff per page in initiation order",
+            )) as Box<dyn std::error::Error + Send + Sync>;
+            return Err(err);
+        }
+
         if !handoffs
             .iter()
             .all(|h| h.status == HandoffStatus::Completed)
@@ -153,3 +179,101 @@ fn handoff_chain_tracks_all_sessions(
     })?;
     Ok(())
 }
+
+/// Attaches an in-memory chain adapter sharing the harness's stores.
+fn with_chain_adapter(mut harness: HandoffTestHarness) -> HandoffTestHarness {
+    let chains = InMemoryHandoffChainAdapter::new(
+        (*harness.session_repo).clone(),
+        (*harness.handoff_adapter).clone(),
+        (*harness.snapshot_adapter).clone(),
+    );
+    harness.service = harness.service.with_handoff_chain(Arc::new(chains));
+    harness
+}
+
+#[rstest]
+fn handoff_chain_links_sessions_and_snapshots(
+    runtime: TestResult<Runtime>,
+    harness: HandoffTestHarness,
+    ctx: RequestContext,
+    clock: DefaultClock,
+) {
+    let harness = with_chain_adapter(harness);
+    let runtime_handle = runtime.expect("runtime");
+    runtime_handle.block_on(async {
+        let conversation_id = ConversationId::new();
+        let agent1 = AgentSession::new(conversation_id, "agent-1", SequenceNumber::new(1), &clock);
+        harness
+            .session_repo
+            .store(&ctx, &agent1)
+            .await
+            .expect("store 1");
+        let (handoff1, agent2) = complete_handoff_to_agent(
+            &harness,
+            &ctx,
+            &agent1,
+            HandoffParams::new("agent-2", SequenceNumber::new(6), "escalate to specialist"),
+        )
+        .await
+        .expect("handoff 1");
+        let initiate_params = ServiceInitiateParams::new(
+            agent2.session_id,
+            "agent-3",
+            TurnId::new(),
+            SequenceNumber::new(11),
+        );
+        let pending = harness
+            .service
+            .initiate(&ctx, initiate_params)
+            .await
+            .expect("initiate handoff 2");
+
+        let chain = harness
+            .service
+            .handoff_chain(&ctx, conversation_id)
+            .await
+            .expect("handoff chain");
+
+        assert_eq!(chain.conversation_id, conversation_id);
+        assert_eq!(chain.agents(), ["agent-1", "agent-2", "agent-3"]);
+        assert!(chain.is_linked());
+        let [first, second] = chain.links.as_slice() else {
+            panic!("expected two links, got {}", chain.links.len());
+        };
+        assert_eq!(first.handoff.handoff_id, handoff1.handoff_id);
+        assert_eq!(first.source_session.session_id, agent1.session_id);
+        assert_eq!(
+            first.target_session.as_ref().map(|s| s.session_id),
+            Some(agent2.session_id)
+        );
+        assert!(first.initiation_snapshot_id.is_some());
+        assert!(first.target_start_snapshot_id.is_some());
+        assert_eq!(second.handoff.handoff_id, pending.handoff_id);
+        assert_eq!(second.source_session.session_id, agent2.session_id);
+        assert!(second.initiation_snapshot_id.is_some());
+        assert!(second.target_session.is_none());
+        assert!(second.target_start_snapshot_id.is_none());
+    });
+}
+
+#[rstest]
+fn handoff_chain_is_empty_without_handoffs(
+    runtime: TestResult<Runtime>,
+    harness: HandoffTestHarness,
+    ctx: RequestContext,
+) {
+    let runtime_handle = runtime.expect("runtime");
+    let unconfigured =
+        runtime_handle.block_on(harness.service.handoff_chain(&ctx, ConversationId::new()));
+    assert!(matches!(
+        unconfigured,
+        Err(HandoffError::ChainNotConfigured)
+    ));
+
+    let harness = with_chain_adapter(harness);
+    let chain = runtime_handle
+        .block_on(harness.service.handoff_chain(&ctx, ConversationId::new()))
+        .expect("handoff chain");
+    assert!(chain.links.is_empty());
+    assert!(chain.agents().is_empty());
+}
@@ -0,0 +1,213 @@
+//! Handoff context transfer tests for in-memory adapters.
+
+use super::harness::{HandoffTestHarness, TestResult, clock, ctx, harness, runtime};
+use corbusier::context::RequestContext;
+use corbusier::message::adapters::memory::{
+    InMemoryHandoffContextRepository, InMemoryMessageRepository, InMemoryRollingSummaryRepository,
+};
+use corbusier::message::domain::{
+    AgentSession, ContentPart, ConversationId, HandoffSessionParams, Message, Role, RollingSummary,
+    SequenceNumber, SequenceRange, TextPart, ToolCallPart, ToolCallReference, ToolResultPart,
+    TurnId,
+};
+use corbusier::message::ports::{
+    MessageRepository, RollingSummaryRepository, agent_session::AgentSessionRepository,
+};
+use corbusier::message::services::{CompleteHandoffParams, ServiceInitiateParams};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+fn message(conversation_id: ConversationId, role: Role, part: ContentPart, seq: u64) -> Message {
+    Message::new(
+        conversation_id,
+        role,
+        vec![part],
+        SequenceNumber::new(seq),
+        &DefaultClock,
+    )
+    .expect("valid message")
+}
+
+#[rstest]
+fn completing_a_handoff_prepares_the_target_context(
+    runtime: TestResult<Runtime>,
+    mut harness: HandoffTestHarness,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) {
+    let runtime_handle = runtime.expect("runtime");
+    runtime_handle.block_on(async {
+        let messages = InMemoryMessageRepository::new();
+        let summaries = InMemoryRollingSummaryRepository::new();
+        harness.service = harness
+            .service
+            .with_context_transfer(
+                Arc::new(messages.clone()),
+                Arc::new(InMemoryHandoffContextRepository::new()),
+            )
+            .with_rolling_summaries(Arc::new(summaries.clone()));
+        let conversation_id = ConversationId::new();
+        let brief = message(
+            conversation_id,
+            Role::User,
+            ContentPart::Text(TextPart::new("Fix the flaky test.")),
+            1,
+        );
+        let open_call = message(
+            conversation_id,
+            Role::Assistant,
+            ContentPart::ToolCall(ToolCallPart::new("c2", "run_tests", json!({}))),
+            4,
+        );
+        let history = [
+            brief.clone(),
+            message(
+                conversation_id,
+                Role::Assistant,
+                ContentPart::ToolCall(ToolCallPart::new("c1", "read_file", json!({}))),
+                2,
+            ),
+            message(
+                conversation_id,
+                Role::Tool,
+                ContentPart::ToolResult(ToolResultPart::success("c1", json!("ok"))),
+                3,
+            ),
+            open_call.clone(),
+        ];
+        for stored in &history {
+            messages.store(&ctx, stored).await.expect("store message");
+        }
+        messages
+            .set_pinned(&ctx, brief.id(), true)
+            .await
+            .expect("pin");
+        summaries
+            .store(
+                &ctx,
+                &RollingSummary::recomputed(
+                    conversation_id,
+                    "The user wants the flaky test fixed.",
+                    SequenceNumber::new(4),
+                    &clock,
+                ),
+            )
+            .await
+            .expect("store summary");
+
+        let source_session = AgentSession::new(
+            conversation_id,
+            "source-agent",
+            SequenceNumber::new(1),
+            &clock,
+        );
+        harness
+            .session_repo
+            .store(&ctx, &source_session)
+            .await
+            .expect("store");
+        let handoff = harness
+            .service
+            .initiate(
+                &ctx,
+                ServiceInitiateParams::new(
+                    source_session.session_id,
+                    "target-agent",
+                    TurnId::new(),
+                    SequenceNumber::new(4),
+                ),
+            )
+            .await
+            .expect("initiate");
+        let target_session = harness
+            .service
+            .create_target_session(
+                &ctx,
+                HandoffSessionParams::new(
+                    conversation_id,
+                    "target-agent",
+                    SequenceNumber::new(5),
+                    handoff.handoff_id,
+                ),
+            )
+            .await
+            .expect("create target");
+        harness
+            .service
+            .complete(
+                &ctx,
+                CompleteHandoffParams::new(
+                    handoff.handoff_id,
+                    target_session.session_id,
+                    SequenceNumber::new(5),
+                ),
+            )
+            .await
+            .expect("complete");
+
+        let package = harness
+            .service
+            .target_context(&ctx, target_session.session_id)
+            .await
+            .expect("read package")
+            .expect("package prepared");
+
+        assert_eq!(package.handoff_id, handoff.handoff_id);
+        assert_eq!(package.conversation_id, conversation_id);
+        assert_eq!(package.source_session_id, source_session.session_id);
+        assert!(package.snapshot_id.is_some());
+        assert_eq!(
+            package.sequence_range,
+            Some(SequenceRange::new(
+                SequenceNumber::new(1),
+                SequenceNumber::new(4)
+            ))
+        );
+        assert_eq!(
+            package.summary.as_deref(),
+            Some("The user wants the flaky test fixed.")
+        );
+        assert_eq!(package.pinned_messages, [brief.id()]);
+        assert_eq!(
+            package.open_tool_calls,
+            [ToolCallReference::new(
+                "c2",
+                "run_tests",
+                open_call.id(),
+                SequenceNumber::new(4)
+            )]
+        );
+    });
+}
+
+#[rstest]
+fn target_context_is_absent_without_a_handoff(
+    runtime: TestResult<Runtime>,
+    harness: HandoffTestHarness,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) {
+    let runtime_handle = runtime.expect("runtime");
+    runtime_handle.block_on(async {
+        let service = harness.service.with_context_transfer(
+            Arc::new(InMemoryMessageRepository::new()),
+            Arc::new(InMemoryHandoffContextRepository::new()),
+        );
+        let session = AgentSession::new(
+            ConversationId::new(),
+            "solo-agent",
+            SequenceNumber::new(1),
+            &clock,
+        );
+
+        let package = service
+            .target_context(&ctx, session.session_id)
+            .await
+            .expect("read package");
+
+        assert_eq!(package, None);
+    });
+}
@@ -7,6 +7,7 @@ use corbusier::message::{
     },
     services::HandoffService,
 };
+use corbusier::operator::adapters::InMemoryOperatorActionRepository;
 use mockable::DefaultClock;
 use rstest::fixture;
 use std::sync::Arc;
@@ -46,6 +47,7 @@ pub struct HandoffTestHarness {
     pub session_repo: Arc<InMemoryAgentSessionRepository>,
     pub handoff_adapter: Arc<InMemoryHandoffAdapter<DefaultClock>>,
     pub snapshot_adapter: Arc<InMemoryContextSnapshotAdapter>,
+    pub operator_actions: Arc<InMemoryOperatorActionRepository>,
     pub service: HandoffService<
         InMemoryAgentSessionRepository,
         InMemoryHandoffAdapter<DefaultClock>,
@@ -68,18 +70,21 @@ impl HandoffTestHarness {
         let session_repo = Arc::new(InMemoryAgentSessionRepository::new());
         let handoff_adapter = Arc::new(InMemoryHandoffAdapter::new(DefaultClock));
         let snapshot_adapter = Arc::new(InMemoryContextSnapshotAdapter::new());
+        let operator_actions = Arc::new(InMemoryOperatorActionRepository::new());
 
         let service = HandoffService::new(
             Arc::clone(&session_repo),
             Arc::clone(&handoff_adapter),
             Arc::clone(&snapshot_adapter),
             clock,
-        );
+        )
+        .with_operator_actions(Arc::clone(&operator_actions));
 
         Self {
             session_repo,
             handoff_adapter,
             snapshot_adapter,
+            operator_actions,
             service,
         }
     }
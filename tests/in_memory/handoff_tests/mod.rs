@@ -3,8 +3,10 @@
 mod cancellation_tests;
 mod chain_tests;
 mod completion_tests;
+mod context_transfer_tests;
 mod harness;
 mod initiation_tests;
 mod pending_tests;
+mod rejection_tests;
 mod session_tests;
 mod snapshot_tests;
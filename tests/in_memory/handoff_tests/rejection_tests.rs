@@ -0,0 +1,170 @@
+//! Handoff rejection tests for in-memory adapters.
+
+use super::harness::{HandoffTestHarness, TestResult, clock, ctx, harness, runtime};
+use async_trait::async_trait;
+use corbusier::context::RequestContext;
+use corbusier::message::domain::{
+    AgentSession, AgentSessionState, ConversationId, ConversationLifecycleChange,
+    ConversationLifecycleEvent, ConversationLifecyclePoint, HandoffMetadata, HandoffStatus,
+    SequenceNumber, TurnId,
+};
+use corbusier::message::ports::{
+    ConversationLifecycleHook, LifecycleHookResult,
+    agent_session::AgentSessionRepository,
+    handoff::{AgentHandoffPort, HandoffError},
+};
+use corbusier::message::services::{ConversationLifecycleHooks, ServiceInitiateParams};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::{Arc, Mutex};
+use tokio::runtime::Runtime;
+
+#[derive(Default)]
+struct RecordingHook {
+    events: Mutex<Vec<ConversationLifecycleEvent>>,
+}
+
+#[async_trait]
+impl ConversationLifecycleHook for RecordingHook {
+    async fn on_event(
+        &self,
+        _ctx: &RequestContext,
+        event: &ConversationLifecycleEvent,
+    ) -> LifecycleHookResult {
+        self.events.lock().expect("events lock").push(event.clone());
+        Ok(())
+    }
+}
+
+async fn initiate(
+    harness: &HandoffTestHarness,
+    ctx: &RequestContext,
+    source_session: &AgentSession,
+) -> HandoffMetadata {
+    harness
+        .session_repo
+        .store(ctx, source_session)
+        .await
+        .expect("store");
+    let params = ServiceInitiateParams::new(
+        source_session.session_id,
+        "target-agent",
+        TurnId::new(),
+        SequenceNumber::new(5),
+    );
+    harness
+        .service
+        .initiate(ctx, params)
+        .await
+        .expect("initiate")
+}
+
+#[rstest]
+fn reject_handoff_reverts_source_session_and_announces_rejection(
+    runtime: TestResult<Runtime>,
+    mut harness: HandoffTestHarness,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) {
+    let runtime_handle = runtime.expect("runtime");
+    runtime_handle.block_on(async {
+        let recorder = Arc::new(RecordingHook::default());
+        let mut hooks = ConversationLifecycleHooks::new();
+        hooks.register(
+            "recorder",
+            [ConversationLifecyclePoint::HandoffRejected],
+            recorder.clone(),
+        );
+        harness.service = harness.service.with_lifecycle_hooks(Arc::new(hooks));
+        let conversation_id = ConversationId::new();
+        let source_session = AgentSession::new(
+            conversation_id,
+            "source-agent",
+            SequenceNumber::new(1),
+            &clock,
+        );
+        let handoff = initiate(&harness, &ctx, &source_session).await;
+
+        let rejected = harness
+            .service
+            .reject(&ctx, handoff.handoff_id, "  outside my tool permissions ")
+            .await
+            .expect("reject");
+
+        assert_eq!(rejected.status, HandoffStatus::Failed);
+        let stored = harness
+            .handoff_adapter
+            .find_handoff(&ctx, handoff.handoff_id)
+            .await
+            .expect("find handoff")
+            .expect("handoff exists");
+        assert_eq!(stored, rejected);
+        assert_eq!(
+            stored.reason.as_deref(),
+            Some("outside my tool permissions")
+        );
+
+        let reverted = harness
+            .session_repo
+            .find_by_id(&ctx, source_session.session_id)
+            .await
+            .expect("find")
+            .expect("exists");
+        assert_eq!(reverted.state, AgentSessionState::Active);
+        assert_eq!(reverted.terminated_by_handoff, None);
+
+        let events = recorder.events.lock().expect("events lock").clone();
+        let [event] = events.as_slice() else {
+            panic!("expected one lifecycle event, got {events:?}");
+        };
+        assert_eq!(event.conversation_id, conversation_id);
+        assert_eq!(
+            event.change,
+            ConversationLifecycleChange::HandoffRejected {
+                handoff_id: handoff.handoff_id,
+                target_agent: "target-agent".to_owned(),
+                reason: "outside my tool permissions".to_owned(),
+            }
+        );
+    });
+}
+
+#[rstest]
+fn reject_handoff_refuses_terminal_handoffs_and_blank_reasons(
+    runtime: TestResult<Runtime>,
+    harness: HandoffTestHarness,
+    clock: DefaultClock,
+    ctx: RequestContext,
+) {
+    let runtime_handle = runtime.expect("runtime");
+    runtime_handle.block_on(async {
+        let source_session = AgentSession::new(
+            ConversationId::new(),
+            "source-agent",
+            SequenceNumber::new(1),
+            &clock,
+        );
+        let handoff = initiate(&harness, &ctx, &source_session).await;
+
+        let blank = harness.service.reject(&ctx, handoff.handoff_id, " ").await;
+        assert!(matches!(blank, Err(HandoffError::MissingRejectionReason)));
+
+        harness
+            .service
+            .reject(&ctx, handoff.handoff_id, "busy")
+            .await
+            .expect("first rejection");
+        let again = harness
+            .service
+            .reject(&ctx, handoff.handoff_id, "still busy")
+            .await;
+
+        assert!(matches!(
+            again,
+            Err(HandoffError::InvalidStateTransition {
+                from: HandoffStatus::Failed,
+                to: HandoffStatus::Failed,
+            })
+        ));
+    });
+}
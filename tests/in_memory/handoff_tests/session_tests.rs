@@ -6,6 +6,7 @@ use corbusier::message::domain::{
     AgentSession, AgentSessionState, ConversationId, HandoffSessionParams, SequenceNumber,
 };
 use corbusier::message::ports::agent_session::AgentSessionRepository;
+use corbusier::pagination::PageRequest;
 use mockable::DefaultClock;
 use rstest::rstest;
 use tokio::runtime::Runtime;
@@ -119,7 +120,7 @@ fn session_repository_lists_by_conversation(
 
         let sessions = harness
             .session_repo
-            .find_by_conversation(&ctx, conversation_id)
+            .find_by_conversation(&ctx, conversation_id, PageRequest::default())
             .await
             .expect("should list");
 
@@ -7,6 +7,7 @@ use corbusier::message::ports::{
     agent_session::AgentSessionRepository, context_snapshot::ContextSnapshotPort,
 };
 use corbusier::message::services::ServiceInitiateParams;
+use corbusier::pagination::PageRequest;
 use mockable::DefaultClock;
 use rstest::rstest;
 use tokio::runtime::Runtime;
@@ -49,7 +50,7 @@ fn handoff_captures_context_snapshot(
 
         let snapshots = harness
             .snapshot_adapter
-            .find_snapshots_for_session(&ctx, source_session.session_id)
+            .find_snapshots_for_session(&ctx, source_session.session_id, PageRequest::default())
             .await
             .expect("find snapshots");
 
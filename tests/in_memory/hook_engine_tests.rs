@@ -12,6 +12,7 @@ use corbusier::hook_engine::ports::{
     HookEngine, HookExecutionLogRepository, HookPolicyAuditRepository,
 };
 use corbusier::hook_engine::services::{HookEngineService, HookEngineServiceDeps};
+use corbusier::pagination::PageRequest;
 use corbusier::test_support::other_tenant_ctx;
 use corbusier::test_support::test_request_ctx;
 use corbusier::{message::domain::ConversationId, task::domain::TaskId};
@@ -78,15 +79,23 @@ struct QueryExpectation<'a> {
 async fn assert_policy_audit_queries(expectation: QueryExpectation<'_>) -> Result<()> {
     let by_task = expectation
         .policy_audit
-        .find_by_task(expectation.ctx, expectation.task_id)
+        .find_by_task(expectation.ctx, expectation.task_id, PageRequest::default())
         .await?;
     let by_conversation = expectation
         .policy_audit
-        .find_by_conversation(expectation.ctx, expectation.conversation_id)
+        .find_by_conversation(
+            expectation.ctx,
+            expectation.conversation_id,
+            PageRequest::default(),
+        )
         .await?;
     let by_trigger = expectation
         .policy_audit
-        .find_by_trigger_context(expectation.ctx, expectation.trigger_context_id)
+        .find_by_trigger_context(
+            expectation.ctx,
+            expectation.trigger_context_id,
+            PageRequest::default(),
+        )
         .await?;
     let expected_ids: Vec<_> = by_trigger
         .iter()
@@ -0,0 +1,98 @@
+//! Conversation archival route tests for the in-memory HTTP API surface.
+
+use super::super::helpers::runtime;
+use super::support::{admin_token, assert_v1_metadata, build_bundle, with_bearer};
+use crate::http_api_test_helpers::{required_field, required_str_field};
+use actix_web::{App, test::TestRequest, web};
+use corbusier::http_api::api_routes;
+use rstest::rstest;
+use serde_json::{Value, json};
+use std::io;
+use tokio::runtime::Runtime;
+
+async fn send_json<F, Fut, B>(
+    send: &F,
+    request: TestRequest,
+    expected_status: u16,
+) -> Result<Value, eyre::Report>
+where
+    F: Fn(TestRequest) -> Fut,
+    Fut: std::future::Future<Output = actix_web::dev::ServiceResponse<B>>,
+    B: actix_web::body::MessageBody,
+{
+    let response = send(request).await;
+    eyre::ensure!(
+        response.status().as_u16() == expected_status,
+        "expected response status {expected_status}, got {}",
+        response.status().as_u16()
+    );
+    Ok(actix_web::test::read_body_json(response).await)
+}
+
+fn ensure_reason(body: &Value, reason: &str) -> Result<(), eyre::Report> {
+    eyre::ensure!(
+        required_str_field(required_field(body, "details"), "reason") == reason,
+        "expected {reason} reason"
+    );
+    Ok(())
+}
+
+fn post(uri: &str, token: &str) -> TestRequest {
+    with_bearer(TestRequest::post().uri(uri), token)
+}
+
+#[rstest]
+fn archived_conversations_reject_writes_until_an_admin_unarchives(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let admin = admin_token(&bundle.auth)?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let created = send_json(&call, post("/api/v1/conversations", &token), 201).await?;
+        let conversation_id = required_str_field(
+            required_field(required_field(&created, "data"), "conversation"),
+            "id",
+        )
+        .to_owned();
+        let base = format!("/api/v1/conversations/{conversation_id}");
+        let message = json!({ "role": "user", "content": [{ "type": "text", "text": "hi" }] });
+
+        let archived = send_json(&call, post(&format!("{base}/archive"), &token), 200).await?;
+        assert_v1_metadata(&archived);
+        eyre::ensure!(
+            required_str_field(
+                required_field(required_field(&archived, "data"), "conversation"),
+                "state"
+            ) == "archived",
+            "expected the conversation to be archived"
+        );
+
+        let append = || post(&format!("{base}/messages"), &token).set_json(&message);
+        ensure_reason(
+            &send_json(&call, append(), 409).await?,
+            "conversation_archived",
+        )?;
+        ensure_reason(
+            &send_json(&call, post(&format!("{base}/archive"), &token), 409).await?,
+            "conversation_already_archived",
+        )?;
+        ensure_reason(
+            &send_json(&call, post(&format!("{base}/unarchive"), &token), 403).await?,
+            "elevated_access_required",
+        )?;
+
+        send_json(&call, post(&format!("{base}/unarchive"), &admin), 200).await?;
+        send_json(&call, append(), 201).await?;
+        Ok(())
+    })
+}
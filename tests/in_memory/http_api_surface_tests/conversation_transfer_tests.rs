@@ -0,0 +1,171 @@
+//! Conversation transfer route tests for the in-memory HTTP API surface.
+
+use super::super::helpers::runtime;
+use super::support::{admin_token, build_bundle, with_bearer};
+use crate::http_api_test_helpers::{required_field, required_str_field};
+use actix_web::{App, test::TestRequest, web};
+use corbusier::context::TenantId;
+use corbusier::http_api::api_routes;
+use corbusier::message::{
+    adapters::memory::{InMemoryConversationRepository, InMemoryConversationTransferAdapter},
+    domain::Conversation,
+    ports::ConversationRepository,
+    services::ConversationTransferService,
+};
+use corbusier::operator::adapters::InMemoryOperatorActionRepository;
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::{Value, json};
+use std::io;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+async fn send_json<F, Fut, B>(
+    send: &F,
+    request: TestRequest,
+    expected_status: u16,
+) -> Result<Value, eyre::Report>
+where
+    F: Fn(TestRequest) -> Fut,
+    Fut: std::future::Future<Output = actix_web::dev::ServiceResponse<B>>,
+    B: actix_web::body::MessageBody,
+{
+    let response = send(request).await;
+    eyre::ensure!(
+        response.status().as_u16() == expected_status,
+        "expected response status {expected_status}, got {}",
+        response.status().as_u16()
+    );
+    Ok(actix_web::test::read_body_json(response).await)
+}
+
+fn transfer(token: &str, conversation: &Conversation, body: &Value) -> TestRequest {
+    with_bearer(
+        TestRequest::post().uri(&format!(
+            "/api/v1/admin/conversations/{}/transfer",
+            conversation.id()
+        )),
+        token,
+    )
+    .set_json(body)
+}
+
+fn ensure_reason(body: &Value, reason: &str) -> Result<(), eyre::Report> {
+    eyre::ensure!(
+        required_str_field(required_field(body, "details"), "reason") == reason,
+        "expected {reason} reason"
+    );
+    Ok(())
+}
+
+#[rstest]
+fn admins_transfer_conversations_to_another_tenant(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let admin = admin_token(&bundle.auth)?;
+        let ctx = bundle.auth.request_context();
+        let conversations = InMemoryConversationRepository::new();
+        let conversation = Conversation::new(&DefaultClock);
+        conversations.store(&ctx, &conversation).await?;
+        let service = Arc::new(ConversationTransferService::new(
+            Arc::new(InMemoryConversationTransferAdapter::new(
+                conversations.clone(),
+                Arc::new(InMemoryOperatorActionRepository::new()),
+            )),
+            Arc::new(DefaultClock),
+        ));
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(
+                    bundle.state.with_conversation_transfers(service),
+                ))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+        let target = TenantId::new();
+        let body = json!({ "target_tenant_id": target, "reason": "customer account merge" });
+
+        ensure_reason(
+            &send_json(&call, transfer(&token, &conversation, &body), 403).await?,
+            "admin_role_required",
+        )?;
+        ensure_reason(
+            &send_json(
+                &call,
+                transfer(
+                    &admin,
+                    &conversation,
+                    &json!({ "target_tenant_id": target, "reason": " " }),
+                ),
+                400,
+            )
+            .await?,
+            "invalid_transfer_reason",
+        )?;
+        ensure_reason(
+            &send_json(
+                &call,
+                transfer(
+                    &admin,
+                    &conversation,
+                    &json!({ "target_tenant_id": ctx.tenant_id(), "reason": "merge" }),
+                ),
+                400,
+            )
+            .await?,
+            "same_tenant_transfer",
+        )?;
+
+        let moved = send_json(&call, transfer(&admin, &conversation, &body), 200).await?;
+        eyre::ensure!(
+            moved.pointer("/data/transfer/target_tenant_id") == Some(&json!(target)),
+            "expected the target tenant in the response"
+        );
+        ensure_reason(
+            &send_json(&call, transfer(&admin, &conversation, &body), 404).await?,
+            "conversation_not_found",
+        )?;
+        eyre::ensure!(
+            conversations
+                .find_by_id(&ctx, conversation.id())
+                .await?
+                .is_none(),
+            "expected the conversation to leave the source tenant"
+        );
+        Ok(())
+    })
+}
+
+#[rstest]
+fn transfers_are_unavailable_without_a_service(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let admin = admin_token(&bundle.auth)?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+        let body = json!({ "target_tenant_id": TenantId::new(), "reason": "merge" });
+
+        ensure_reason(
+            &send_json(
+                &call,
+                transfer(&admin, &Conversation::new(&DefaultClock), &body),
+                503,
+            )
+            .await?,
+            "conversation_transfers_unavailable",
+        )
+    })
+}
@@ -0,0 +1,90 @@
+//! Doctor route tests for the in-memory HTTP API surface.
+
+use super::super::helpers::runtime;
+use super::support::{admin_token, build_bundle, with_bearer};
+use crate::http_api_test_helpers::{required_field, required_str_field};
+use actix_web::{App, test::TestRequest, web};
+use corbusier::doctor::services::DoctorService;
+use corbusier::http_api::api_routes;
+use corbusier::message::adapters::memory::{
+    InMemoryConversationRepository, InMemoryMessageRepository,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::Value;
+use std::io;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+fn run_doctor(token: &str) -> TestRequest {
+    with_bearer(TestRequest::get().uri("/api/v1/admin/doctor"), token)
+}
+
+fn ensure_reason(body: &Value, reason: &str) -> Result<(), eyre::Report> {
+    eyre::ensure!(
+        required_str_field(required_field(body, "details"), "reason") == reason,
+        "expected {reason} reason"
+    );
+    Ok(())
+}
+
+#[rstest]
+fn admin_receives_readiness_report(runtime: io::Result<Runtime>) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let admin = admin_token(&bundle.auth)?;
+        let doctor = Arc::new(
+            DoctorService::new(Arc::new(DefaultClock)).with_message_store(
+                Arc::new(InMemoryConversationRepository::new()),
+                Arc::new(InMemoryMessageRepository::new()),
+            ),
+        );
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state.with_doctor(doctor)))
+                .configure(api_routes),
+        )
+        .await;
+
+        let refused = actix_web::test::call_service(&app, run_doctor(&token).to_request()).await;
+        eyre::ensure!(refused.status().as_u16() == 403);
+        let refused_body: Value = actix_web::test::read_body_json(refused).await;
+        ensure_reason(&refused_body, "admin_role_required")?;
+
+        let response = actix_web::test::call_service(&app, run_doctor(&admin).to_request()).await;
+        eyre::ensure!(response.status().as_u16() == 200);
+        let body: Value = actix_web::test::read_body_json(response).await;
+        let report = required_field(required_field(&body, "data"), "report");
+        eyre::ensure!(report.get("ready").and_then(Value::as_bool) == Some(true));
+        let probe = required_field(report, "probes")
+            .get(0)
+            .ok_or_else(|| eyre::eyre!("report should list the message store probe"))?;
+        eyre::ensure!(required_str_field(probe, "component") == "message_store");
+        eyre::ensure!(required_str_field(probe, "status") == "passed");
+        Ok(())
+    })
+}
+
+#[rstest]
+fn doctor_is_unavailable_when_not_configured(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let admin = admin_token(&bundle.auth)?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+
+        let response = actix_web::test::call_service(&app, run_doctor(&admin).to_request()).await;
+        eyre::ensure!(response.status().as_u16() == 503);
+        let body: Value = actix_web::test::read_body_json(response).await;
+        ensure_reason(&body, "doctor_unavailable")
+    })
+}
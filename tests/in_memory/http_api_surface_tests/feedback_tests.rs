@@ -0,0 +1,237 @@
+//! Message feedback route tests for the in-memory HTTP API surface.
+
+use super::super::helpers::runtime;
+use super::support::{TestBundle, assert_v1_metadata, build_bundle, with_bearer};
+use crate::http_api_test_helpers::{required_field, required_str_field};
+use actix_web::{App, test::TestRequest, web};
+use corbusier::http_api::api_routes;
+use corbusier::message::{
+    adapters::memory::InMemoryMessageFeedbackRepository, services::MessageFeedbackService,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::{Value, json};
+use std::io;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+fn with_feedback(bundle: TestBundle) -> TestBundle {
+    let service = MessageFeedbackService::new(
+        Arc::new(bundle.messages.clone()),
+        Arc::new(InMemoryMessageFeedbackRepository::new()),
+        Arc::new(DefaultClock),
+    );
+    TestBundle {
+        state: bundle.state.with_feedback(Arc::new(service)),
+        ..bundle
+    }
+}
+
+async fn send_json<F, Fut, B>(
+    send: &F,
+    request: TestRequest,
+    expected_status: u16,
+) -> Result<Value, eyre::Report>
+where
+    F: Fn(TestRequest) -> Fut,
+    Fut: std::future::Future<Output = actix_web::dev::ServiceResponse<B>>,
+    B: actix_web::body::MessageBody,
+{
+    let response = send(request).await;
+    eyre::ensure!(
+        response.status().as_u16() == expected_status,
+        "expected response status {expected_status}, got {}",
+        response.status().as_u16()
+    );
+    Ok(actix_web::test::read_body_json(response).await)
+}
+
+/// Creates a conversation holding one message with the given role and
+/// returns the conversation and message identifiers.
+async fn seed_message<F, Fut, B>(
+    send: &F,
+    token: &str,
+    role: &str,
+) -> Result<(String, String), eyre::Report>
+where
+    F: Fn(TestRequest) -> Fut,
+    Fut: std::future::Future<Output = actix_web::dev::ServiceResponse<B>>,
+    B: actix_web::body::MessageBody,
+{
+    let created = send_json(
+        send,
+        with_bearer(TestRequest::post().uri("/api/v1/conversations"), token),
+        201,
+    )
+    .await?;
+    let conversation_id = required_str_field(
+        required_field(required_field(&created, "data"), "conversation"),
+        "id",
+    )
+    .to_owned();
+    let appended = send_json(
+        send,
+        with_bearer(
+            TestRequest::post()
+                .uri(&format!("/api/v1/conversations/{conversation_id}/messages"))
+                .set_json(json!({
+                    "role": role,
+                    "content": [{ "type": "text", "text": "Here is the fix." }]
+                })),
+            token,
+        ),
+        201,
+    )
+    .await?;
+    let message_id = required_str_field(
+        required_field(required_field(&appended, "data"), "message"),
+        "id",
+    )
+    .to_owned();
+    Ok((conversation_id, message_id))
+}
+
+fn feedback_uri(conversation_id: &str, message_id: &str) -> String {
+    format!("/api/v1/conversations/{conversation_id}/messages/{message_id}/feedback")
+}
+
+#[rstest]
+fn feedback_is_recorded_listed_and_summarised(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = with_feedback(build_bundle().await?);
+        let token = bundle.auth.token()?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let (conversation_id, message_id) = seed_message(&call, &token, "assistant").await?;
+        let uri = feedback_uri(&conversation_id, &message_id);
+        let submitted = send_json(
+            &call,
+            with_bearer(
+                TestRequest::post().uri(&uri).set_json(json!({
+                    "rating": { "stars": 4 },
+                    "comment": "Correct but verbose",
+                    "category": "style"
+                })),
+                &token,
+            ),
+            201,
+        )
+        .await?;
+        assert_v1_metadata(&submitted);
+        let feedback = required_field(required_field(&submitted, "data"), "feedback");
+        eyre::ensure!(
+            required_str_field(feedback, "message_id") == message_id,
+            "expected feedback on the rated message"
+        );
+        eyre::ensure!(
+            required_str_field(feedback, "category") == "style",
+            "expected the submitted category"
+        );
+
+        let listed = send_json(
+            &call,
+            with_bearer(TestRequest::get().uri(&uri), &token),
+            200,
+        )
+        .await?;
+        let entries = required_field(required_field(&listed, "data"), "feedback");
+        eyre::ensure!(
+            entries.as_array().map(Vec::len) == Some(1),
+            "expected exactly one feedback entry"
+        );
+
+        let summaries = send_json(
+            &call,
+            with_bearer(TestRequest::get().uri("/api/v1/feedback/summaries"), &token),
+            200,
+        )
+        .await?;
+        let summary = required_field(required_field(&summaries, "data"), "summaries")
+            .get(0)
+            .ok_or_else(|| eyre::eyre!("expected one summary"))?;
+        eyre::ensure!(
+            required_field(summary, "positive") == 1,
+            "expected one positive rating"
+        );
+        eyre::ensure!(
+            required_field(summary, "average_stars_centi") == 400,
+            "expected a four-star average"
+        );
+        Ok(())
+    })
+}
+
+#[rstest]
+fn feedback_on_user_message_is_rejected(runtime: io::Result<Runtime>) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = with_feedback(build_bundle().await?);
+        let token = bundle.auth.token()?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let (conversation_id, message_id) = seed_message(&call, &token, "user").await?;
+        let body = send_json(
+            &call,
+            with_bearer(
+                TestRequest::post()
+                    .uri(&feedback_uri(&conversation_id, &message_id))
+                    .set_json(json!({ "rating": "thumbs_up" })),
+                &token,
+            ),
+            400,
+        )
+        .await?;
+        eyre::ensure!(
+            required_str_field(required_field(&body, "details"), "reason")
+                == "not_assistant_message",
+            "expected not_assistant_message reason"
+        );
+        Ok(())
+    })
+}
+
+#[rstest]
+fn feedback_routes_are_unavailable_without_a_service(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let body = send_json(
+            &call,
+            with_bearer(TestRequest::get().uri("/api/v1/feedback/summaries"), &token),
+            503,
+        )
+        .await?;
+        eyre::ensure!(
+            required_str_field(required_field(&body, "details"), "reason")
+                == "feedback_unavailable",
+            "expected feedback_unavailable reason"
+        );
+        Ok(())
+    })
+}
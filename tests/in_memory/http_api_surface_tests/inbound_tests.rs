@@ -0,0 +1,358 @@
+//! Inbound gateway route tests for the in-memory HTTP API surface.
+
+use super::super::helpers::runtime;
+use super::support::{TestBundle, assert_v1_metadata, build_bundle};
+use crate::http_api_test_helpers::{required_field, required_str_field};
+use actix_web::{App, test::TestRequest, web};
+use corbusier::context::{TenantId, UserId};
+use corbusier::http_api::{InboundGateway, api_routes};
+use corbusier::message::{
+    adapters::{
+        inbound::{SignatureScheme, WebhookSignatureVerifier},
+        memory::{
+            InMemoryConversationRepository, InMemoryInboundIdentityMapping,
+            InMemoryMessageRepository,
+        },
+    },
+    domain::InboundPrincipal,
+    services::{ConversationService, InboundMessageService},
+    validation::service::DefaultMessageValidator,
+};
+use mockable::{Clock, DefaultClock};
+use rstest::rstest;
+use serde_json::{Value, json};
+use std::io;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+const WEBHOOK_SECRET: &str = "ops-webhook-secret";
+const SLACK_SECRET: &str = "slack-signing-secret";
+const EMAIL_SECRET: &str = "mail-provider-secret";
+
+fn verifier(
+    scheme: SignatureScheme,
+    secret: &str,
+) -> Result<WebhookSignatureVerifier, eyre::Report> {
+    Ok(WebhookSignatureVerifier::new(scheme, secret)?)
+}
+
+fn with_inbound(bundle: TestBundle) -> Result<TestBundle, eyre::Report> {
+    let principal = InboundPrincipal::new(TenantId::new(), UserId::new());
+    let mapping = InMemoryInboundIdentityMapping::new()
+        .with_user("ops", "jdoe", principal)
+        .with_user("slack", "U1", principal)
+        .with_user("email", "jdoe@example.com", principal);
+    let conversations = ConversationService::new(
+        Arc::new(InMemoryConversationRepository::new()),
+        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    );
+    let service = InboundMessageService::new(Arc::new(mapping), Arc::new(conversations));
+    let gateway = InboundGateway::new(Arc::new(service))
+        .with_webhook(
+            "ops",
+            verifier(SignatureScheme::HmacSha256, WEBHOOK_SECRET)?,
+        )
+        .with_slack(verifier(SignatureScheme::Slack, SLACK_SECRET)?)
+        .with_email(verifier(SignatureScheme::HmacSha256, EMAIL_SECRET)?);
+    Ok(TestBundle {
+        state: bundle.state.with_inbound(gateway),
+        ..bundle
+    })
+}
+
+/// Builds a request signed as `scheme` would sign it with `secret`.
+fn signed(
+    uri: &str,
+    scheme: SignatureScheme,
+    secret: &str,
+    body: &Value,
+) -> Result<TestRequest, eyre::Report> {
+    let (signature_header, timestamp_header) = match scheme {
+        SignatureScheme::Slack => ("X-Slack-Signature", "X-Slack-Request-Timestamp"),
+        SignatureScheme::HmacSha256 => ("X-Corbusier-Signature", "X-Corbusier-Timestamp"),
+    };
+    let payload = serde_json::to_vec(body)?;
+    let timestamp = DefaultClock.utc().timestamp().to_string();
+    let signature = verifier(scheme, secret)?.sign(&timestamp, &payload);
+    Ok(TestRequest::post()
+        .uri(uri)
+        .insert_header(("Content-Type", "application/json"))
+        .insert_header((signature_header, signature))
+        .insert_header((timestamp_header, timestamp))
+        .set_payload(payload))
+}
+
+async fn send_json<F, Fut, B>(
+    send: &F,
+    request: TestRequest,
+    expected_status: u16,
+) -> Result<Value, eyre::Report>
+where
+    F: Fn(TestRequest) -> Fut,
+    Fut: std::future::Future<Output = actix_web::dev::ServiceResponse<B>>,
+    B: actix_web::body::MessageBody,
+{
+    let response = send(request).await;
+    eyre::ensure!(
+        response.status().as_u16() == expected_status,
+        "expected response status {expected_status}, got {}",
+        response.status().as_u16()
+    );
+    Ok(actix_web::test::read_body_json(response).await)
+}
+
+fn ensure_reason(body: &Value, reason: &str) -> Result<(), eyre::Report> {
+    eyre::ensure!(
+        required_str_field(required_field(body, "details"), "reason") == reason,
+        "expected {reason} reason"
+    );
+    Ok(())
+}
+
+fn webhook_payload(text: &str) -> Value {
+    json!({ "channel_id": "ops-room", "user_id": "jdoe", "text": text })
+}
+
+#[rstest]
+fn signed_webhook_messages_share_a_channel_conversation(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = with_inbound(build_bundle().await?)?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+        let uri = "/api/v1/inbound/webhooks/ops";
+
+        let first = send_json(
+            &call,
+            signed(
+                uri,
+                SignatureScheme::HmacSha256,
+                WEBHOOK_SECRET,
+                &webhook_payload("Deploy?"),
+            )?,
+            201,
+        )
+        .await?;
+        assert_v1_metadata(&first);
+        let first_data = required_field(&first, "data");
+        eyre::ensure!(
+            required_field(first_data, "created_conversation") == true,
+            "expected the first message to create a conversation"
+        );
+
+        let second = send_json(
+            &call,
+            signed(
+                uri,
+                SignatureScheme::HmacSha256,
+                WEBHOOK_SECRET,
+                &webhook_payload("Any news?"),
+            )?,
+            201,
+        )
+        .await?;
+        let second_data = required_field(&second, "data");
+        eyre::ensure!(
+            required_field(second_data, "created_conversation") == false,
+            "expected the second message to reuse the conversation"
+        );
+        eyre::ensure!(
+            required_str_field(first_data, "conversation_id")
+                == required_str_field(second_data, "conversation_id"),
+            "expected both messages in the channel conversation"
+        );
+        Ok(())
+    })
+}
+
+#[rstest]
+#[case::wrong_secret(
+    "not-the-secret",
+    "/api/v1/inbound/webhooks/ops",
+    (401, "invalid_signature")
+)]
+#[case::unknown_source(
+    WEBHOOK_SECRET,
+    "/api/v1/inbound/webhooks/crm",
+    (404, "inbound_source_not_found")
+)]
+fn rejected_webhook_deliveries(
+    runtime: io::Result<Runtime>,
+    #[case] secret: &str,
+    #[case] uri: &str,
+    #[case] expected: (u16, &str),
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = with_inbound(build_bundle().await?)?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let request = signed(
+            uri,
+            SignatureScheme::HmacSha256,
+            secret,
+            &webhook_payload("Deploy?"),
+        )?;
+        let (status, reason) = expected;
+        let body = send_json(&call, request, status).await?;
+        ensure_reason(&body, reason)
+    })
+}
+
+#[rstest]
+fn slack_url_verification_is_echoed(runtime: io::Result<Runtime>) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = with_inbound(build_bundle().await?)?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let body = send_json(
+            &call,
+            signed(
+                "/api/v1/inbound/slack/events",
+                SignatureScheme::Slack,
+                SLACK_SECRET,
+                &json!({ "type": "url_verification", "challenge": "3eZbrw1a" }),
+            )?,
+            200,
+        )
+        .await?;
+        eyre::ensure!(
+            body == json!({ "challenge": "3eZbrw1a" }),
+            "expected the challenge to be echoed"
+        );
+
+        let message = send_json(
+            &call,
+            signed(
+                "/api/v1/inbound/slack/events",
+                SignatureScheme::Slack,
+                SLACK_SECRET,
+                &json!({
+                    "type": "event_callback",
+                    "event_id": "Ev1",
+                    "event": { "type": "message", "channel": "C1", "user": "U1", "text": "hi" }
+                }),
+            )?,
+            201,
+        )
+        .await?;
+        eyre::ensure!(
+            required_field(required_field(&message, "data"), "ingested") == true,
+            "expected the Slack message to be ingested"
+        );
+        Ok(())
+    })
+}
+
+#[rstest]
+fn email_replies_join_the_thread_conversation(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = with_inbound(build_bundle().await?)?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+        let uri = "/api/v1/inbound/email";
+
+        let opening = send_json(
+            &call,
+            signed(
+                uri,
+                SignatureScheme::HmacSha256,
+                EMAIL_SECRET,
+                &json!({
+                    "message_id": "<a1@example.com>",
+                    "from": "Jane Doe <jdoe@example.com>",
+                    "subject": "Deploy",
+                    "text": "Deploy?"
+                }),
+            )?,
+            201,
+        )
+        .await?;
+        let reply = send_json(
+            &call,
+            signed(
+                uri,
+                SignatureScheme::HmacSha256,
+                EMAIL_SECRET,
+                &json!({
+                    "message_id": "<a2@example.com>",
+                    "from": "jdoe@example.com",
+                    "subject": "Re: Deploy",
+                    "text": "Any news?",
+                    "in_reply_to": "<a1@example.com>",
+                    "references": ["<a1@example.com>"]
+                }),
+            )?,
+            201,
+        )
+        .await?;
+        let opening_data = required_field(&opening, "data");
+        let reply_data = required_field(&reply, "data");
+        eyre::ensure!(
+            required_field(reply_data, "created_conversation") == false,
+            "expected the reply to reuse the thread conversation"
+        );
+        eyre::ensure!(
+            required_str_field(opening_data, "conversation_id")
+                == required_str_field(reply_data, "conversation_id"),
+            "expected both emails in the thread conversation"
+        );
+        Ok(())
+    })
+}
+
+#[rstest]
+fn inbound_routes_are_unavailable_without_a_gateway(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let request = signed(
+            "/api/v1/inbound/webhooks/ops",
+            SignatureScheme::HmacSha256,
+            WEBHOOK_SECRET,
+            &webhook_payload("Deploy?"),
+        )?;
+        let body = send_json(&call, request, 503).await?;
+        ensure_reason(&body, "inbound_unavailable")
+    })
+}
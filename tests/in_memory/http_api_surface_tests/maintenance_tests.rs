@@ -0,0 +1,151 @@
+//! Maintenance mode route tests for the in-memory HTTP API surface.
+
+use super::super::helpers::runtime;
+use super::support::{admin_token, build_bundle, with_bearer};
+use crate::http_api_test_helpers::{required_field, required_str_field};
+use actix_web::{App, test::TestRequest, web};
+use corbusier::http_api::api_routes;
+use corbusier::maintenance::{
+    adapters::InMemoryMaintenanceStateRepository, services::MaintenanceGate,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::{Value, json};
+use std::io;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+async fn send_json<F, Fut, B>(
+    send: &F,
+    request: TestRequest,
+    expected_status: u16,
+) -> Result<Value, eyre::Report>
+where
+    F: Fn(TestRequest) -> Fut,
+    Fut: std::future::Future<Output = actix_web::dev::ServiceResponse<B>>,
+    B: actix_web::body::MessageBody,
+{
+    let response = send(request).await;
+    eyre::ensure!(
+        response.status().as_u16() == expected_status,
+        "expected response status {expected_status}, got {}",
+        response.status().as_u16()
+    );
+    Ok(actix_web::test::read_body_json(response).await)
+}
+
+fn toggle(token: &str, body: &Value) -> TestRequest {
+    with_bearer(TestRequest::put().uri("/api/v1/admin/maintenance"), token).set_json(body)
+}
+
+fn create_conversation(token: &str) -> TestRequest {
+    with_bearer(TestRequest::post().uri("/api/v1/conversations"), token)
+}
+
+fn ensure_reason(body: &Value, reason: &str) -> Result<(), eyre::Report> {
+    eyre::ensure!(
+        required_str_field(required_field(body, "details"), "reason") == reason,
+        "expected {reason} reason"
+    );
+    Ok(())
+}
+
+fn maintenance_enabled(body: &Value) -> Option<bool> {
+    required_field(required_field(body, "data"), "maintenance")
+        .get("enabled")
+        .and_then(Value::as_bool)
+}
+
+#[rstest]
+fn writes_are_refused_while_reads_continue_during_maintenance(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let admin = admin_token(&bundle.auth)?;
+        let gate = Arc::new(MaintenanceGate::new(
+            Arc::new(InMemoryMaintenanceStateRepository::new()),
+            Arc::new(DefaultClock),
+        ));
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state.with_maintenance(gate)))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+        let enable = json!({ "enabled": true, "reason": "schema migration" });
+
+        ensure_reason(
+            &send_json(&call, toggle(&token, &enable), 403).await?,
+            "admin_role_required",
+        )?;
+        ensure_reason(
+            &send_json(&call, toggle(&admin, &json!({ "enabled": true })), 400).await?,
+            "invalid_maintenance_reason",
+        )?;
+        let enabled = send_json(&call, toggle(&admin, &enable), 200).await?;
+        eyre::ensure!(
+            maintenance_enabled(&enabled) == Some(true),
+            "expected maintenance"
+        );
+
+        let refused = send_json(&call, create_conversation(&token), 503).await?;
+        ensure_reason(&refused, "maintenance_mode")?;
+        eyre::ensure!(
+            required_str_field(required_field(&refused, "details"), "maintenance_reason")
+                == "schema migration",
+            "expected the operator's reason"
+        );
+        send_json(
+            &call,
+            with_bearer(TestRequest::get().uri("/api/v1/tools"), &token),
+            200,
+        )
+        .await?;
+        let status = send_json(
+            &call,
+            with_bearer(TestRequest::get().uri("/api/v1/admin/maintenance"), &token),
+            200,
+        )
+        .await?;
+        eyre::ensure!(
+            maintenance_enabled(&status) == Some(true),
+            "expected maintenance"
+        );
+
+        send_json(&call, toggle(&admin, &json!({ "enabled": false })), 200).await?;
+        send_json(&call, create_conversation(&token), 201).await?;
+        Ok(())
+    })
+}
+
+#[rstest]
+fn maintenance_routes_are_unavailable_without_a_gate(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let unavailable = send_json(
+            &call,
+            with_bearer(TestRequest::get().uri("/api/v1/admin/maintenance"), &token),
+            503,
+        )
+        .await?;
+        ensure_reason(&unavailable, "maintenance_unavailable")?;
+        send_json(&call, create_conversation(&token), 201).await?;
+        Ok(())
+    })
+}
@@ -1,7 +1,15 @@
 //! In-memory integration tests for the HTTP API surface.
 
+mod archival_tests;
 mod auth_tests;
 mod conversation_tests;
+mod conversation_transfer_tests;
+mod doctor_tests;
+mod feedback_tests;
+mod inbound_tests;
+mod maintenance_tests;
+mod operator_action_tests;
+mod processing_tests;
 mod support;
 mod task_contract_tests;
 mod task_tests;
@@ -0,0 +1,128 @@
+//! Operator action timeline route tests for the in-memory HTTP API surface.
+
+use super::super::helpers::runtime;
+use super::support::{build_bundle, with_bearer};
+use crate::http_api_test_helpers::{required_field, required_str_field};
+use actix_web::{App, test::TestRequest, web};
+use corbusier::http_api::api_routes;
+use corbusier::message::domain::ConversationId;
+use corbusier::operator::{
+    adapters::InMemoryOperatorActionRepository,
+    domain::{
+        OperatorAction, OperatorActionDetails, OperatorActionKind, OperatorReason, OperatorSubject,
+        OperatorTransition,
+    },
+    ports::OperatorActionRepository,
+};
+use corbusier::task::domain::TaskId;
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::Value;
+use std::io;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+#[rstest]
+fn timelines_list_operator_actions_for_their_subject(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let ctx = bundle.auth.request_context();
+        let repository = Arc::new(InMemoryOperatorActionRepository::new());
+        let conversation_id = ConversationId::new();
+        let task_id = TaskId::new();
+        let action = OperatorAction::new(
+            &ctx,
+            OperatorActionDetails {
+                kind: OperatorActionKind::ConversationPaused,
+                subject: OperatorSubject::Conversation {
+                    conversation_id,
+                    task_id: Some(task_id),
+                },
+                reason: OperatorReason::new("runaway tool loop")?,
+                transition: Some(OperatorTransition::new("active", "paused")),
+            },
+            &DefaultClock,
+        );
+        repository.record(&ctx, &action).await?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(
+                    bundle.state.with_operator_actions(repository),
+                ))
+                .configure(api_routes),
+        )
+        .await;
+
+        for uri in [
+            format!("/api/v1/conversations/{conversation_id}/operator-actions"),
+            format!("/api/v1/tasks/{task_id}/operator-actions"),
+        ] {
+            let response = actix_web::test::call_service(
+                &app,
+                with_bearer(TestRequest::get().uri(&uri), &token).to_request(),
+            )
+            .await;
+            eyre::ensure!(response.status().as_u16() == 200, "expected 200 from {uri}");
+            let body: Value = actix_web::test::read_body_json(response).await;
+            let actions = required_field(required_field(&body, "data"), "operator_actions")
+                .as_array()
+                .ok_or_else(|| eyre::eyre!("operator_actions should be an array"))?;
+            let [listed] = actions.as_slice() else {
+                eyre::bail!("expected one action from {uri}, got {}", actions.len());
+            };
+            eyre::ensure!(
+                required_str_field(listed, "kind") == "conversation_paused",
+                "expected conversation_paused kind"
+            );
+            eyre::ensure!(
+                required_str_field(listed, "reason") == "runaway tool loop",
+                "expected the operator's reason"
+            );
+            eyre::ensure!(
+                required_str_field(listed, "actor") == ctx.user_id().to_string(),
+                "expected the acting user"
+            );
+        }
+        Ok(())
+    })
+}
+
+#[rstest]
+fn timelines_are_unavailable_without_a_repository(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+
+        let response = actix_web::test::call_service(
+            &app,
+            with_bearer(
+                TestRequest::get()
+                    .uri(&format!("/api/v1/tasks/{}/operator-actions", TaskId::new())),
+                &token,
+            )
+            .to_request(),
+        )
+        .await;
+        eyre::ensure!(response.status().as_u16() == 503, "expected 503");
+        let body: Value = actix_web::test::read_body_json(response).await;
+        eyre::ensure!(
+            required_str_field(required_field(&body, "details"), "reason")
+                == "operator_actions_unavailable",
+            "expected operator_actions_unavailable reason"
+        );
+        Ok(())
+    })
+}
@@ -0,0 +1,330 @@
+//! Message processing status route tests for the in-memory HTTP API surface.
+
+use super::super::helpers::runtime;
+use super::support::{assert_v1_metadata, build_bundle, with_bearer};
+use crate::http_api_test_helpers::{required_field, required_str_field};
+use actix_web::{App, test::TestRequest, web};
+use corbusier::http_api::api_routes;
+use corbusier::message::{
+    adapters::memory::{InMemoryMessageProcessingRepository, InMemoryMessageRepository},
+    domain::{LoadSheddingPolicy, MessageId, ProcessingStage, SheddingDecision},
+    ports::MessageRepository,
+    services::{LoadSheddingController, MessageProcessingService, StageReport},
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::{Value, json};
+use std::io;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+use uuid::Uuid;
+
+type Service = MessageProcessingService<
+    InMemoryMessageRepository,
+    InMemoryMessageProcessingRepository,
+    DefaultClock,
+>;
+
+async fn send_json<F, Fut, B>(
+    send: &F,
+    request: TestRequest,
+    expected_status: u16,
+) -> Result<Value, eyre::Report>
+where
+    F: Fn(TestRequest) -> Fut,
+    Fut: std::future::Future<Output = actix_web::dev::ServiceResponse<B>>,
+    B: actix_web::body::MessageBody,
+{
+    let response = send(request).await;
+    eyre::ensure!(
+        response.status().as_u16() == expected_status,
+        "expected response status {expected_status}, got {}",
+        response.status().as_u16()
+    );
+    Ok(actix_web::test::read_body_json(response).await)
+}
+
+/// Creates a conversation holding one user message and returns the
+/// conversation and message identifiers.
+async fn seed_message<F, Fut, B>(send: &F, token: &str) -> Result<(String, String), eyre::Report>
+where
+    F: Fn(TestRequest) -> Fut,
+    Fut: std::future::Future<Output = actix_web::dev::ServiceResponse<B>>,
+    B: actix_web::body::MessageBody,
+{
+    let created = send_json(
+        send,
+        with_bearer(TestRequest::post().uri("/api/v1/conversations"), token),
+        201,
+    )
+    .await?;
+    let conversation_id = required_str_field(
+        required_field(required_field(&created, "data"), "conversation"),
+        "id",
+    )
+    .to_owned();
+    let appended = send_json(
+        send,
+        with_bearer(
+            TestRequest::post()
+                .uri(&format!("/api/v1/conversations/{conversation_id}/messages"))
+                .set_json(json!({
+                    "role": "user",
+                    "content": [{ "type": "text", "text": "Deploy?" }]
+                })),
+            token,
+        ),
+        201,
+    )
+    .await?;
+    let message_id = required_str_field(
+        required_field(required_field(&appended, "data"), "message"),
+        "id",
+    )
+    .to_owned();
+    Ok((conversation_id, message_id))
+}
+
+fn current_stage(body: &Value) -> &Value {
+    required_field(
+        required_field(required_field(body, "data"), "processing"),
+        "current_stage",
+    )
+}
+
+#[rstest]
+fn failed_stages_are_listed_as_stuck_and_reprocessed(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let ctx = bundle.auth.request_context();
+        let service: Arc<Service> = Arc::new(MessageProcessingService::new(
+            Arc::new(bundle.messages.clone()),
+            Arc::new(InMemoryMessageProcessingRepository::new()),
+            Arc::new(DefaultClock),
+        ));
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(
+                    bundle.state.with_processing(service.clone()),
+                ))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let (conversation_id, message_id) = seed_message(&call, &token).await?;
+        let message = bundle
+            .messages
+            .find_by_id(&ctx, MessageId::from_uuid(Uuid::parse_str(&message_id)?))
+            .await?
+            .ok_or_else(|| eyre::eyre!("expected the seeded message"))?;
+        service
+            .begin_stage(&ctx, &message, ProcessingStage::Validation)
+            .await?;
+        service
+            .finish_stage(
+                &ctx,
+                StageReport::failed(message.id(), ProcessingStage::Validation, "schema drift"),
+            )
+            .await?;
+
+        let uri =
+            format!("/api/v1/conversations/{conversation_id}/messages/{message_id}/processing");
+        let status = send_json(
+            &call,
+            with_bearer(TestRequest::get().uri(&uri), &token),
+            200,
+        )
+        .await?;
+        assert_v1_metadata(&status);
+        eyre::ensure!(
+            current_stage(&status) == "validation",
+            "expected the message to be held at validation"
+        );
+
+        let stuck = send_json(
+            &call,
+            with_bearer(
+                TestRequest::get().uri("/api/v1/processing/stuck?stage=validation&idle_secs=0"),
+                &token,
+            ),
+            200,
+        )
+        .await?;
+        let entry = required_field(required_field(&stuck, "data"), "stuck")
+            .get(0)
+            .ok_or_else(|| eyre::eyre!("expected one stuck message"))?;
+        eyre::ensure!(
+            required_str_field(entry, "last_error") == "schema drift",
+            "expected the failure reason"
+        );
+
+        let reprocessed = send_json(
+            &call,
+            with_bearer(
+                TestRequest::post()
+                    .uri(&format!("{uri}/reprocess"))
+                    .set_json(json!({ "stage": "validation" })),
+                &token,
+            ),
+            200,
+        )
+        .await?;
+        let validation = required_field(
+            required_field(required_field(&reprocessed, "data"), "processing"),
+            "stages",
+        )
+        .get(0)
+        .ok_or_else(|| eyre::eyre!("expected the validation stage"))?;
+        eyre::ensure!(
+            required_str_field(validation, "state") == "in_progress",
+            "expected validation to run again"
+        );
+        eyre::ensure!(
+            required_field(validation, "attempts") == 2,
+            "expected a second attempt"
+        );
+        Ok(())
+    })
+}
+
+#[rstest]
+fn processing_routes_are_unavailable_without_a_service(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let body = send_json(
+            &call,
+            with_bearer(
+                TestRequest::get().uri("/api/v1/processing/stuck?stage=embedding"),
+                &token,
+            ),
+            503,
+        )
+        .await?;
+        eyre::ensure!(
+            required_str_field(required_field(&body, "details"), "reason")
+                == "processing_unavailable",
+            "expected processing_unavailable reason"
+        );
+        Ok(())
+    })
+}
+
+#[rstest]
+fn load_report_shows_how_background_work_is_shed(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let clock = Arc::new(DefaultClock);
+        let controller = Arc::new(LoadSheddingController::new(
+            LoadSheddingPolicy::default(),
+            clock.clone(),
+        ));
+        let service: Arc<Service> = Arc::new(
+            MessageProcessingService::new(
+                Arc::new(bundle.messages.clone()),
+                Arc::new(InMemoryMessageProcessingRepository::new()),
+                clock,
+            )
+            .with_load_shedding(controller.clone()),
+        );
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(
+                    bundle.state.with_processing(service.clone()),
+                ))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        controller.record_queue_depth("embedding", 250);
+        let first = service.admit_stage(ProcessingStage::Embedding);
+        let second = service.admit_stage(ProcessingStage::Embedding);
+        eyre::ensure!(
+            (first, second) == (SheddingDecision::Run, SheddingDecision::Defer),
+            "expected embedding to run one sampled job and defer the next"
+        );
+
+        let body = send_json(
+            &call,
+            with_bearer(TestRequest::get().uri("/api/v1/processing/load"), &token),
+            200,
+        )
+        .await?;
+        assert_v1_metadata(&body);
+        let load = required_field(required_field(&body, "data"), "load");
+        eyre::ensure!(
+            required_str_field(load, "level") == "elevated",
+            "expected an elevated load level"
+        );
+        let analytics = required_field(load, "workloads")
+            .as_array()
+            .and_then(|workloads| {
+                workloads
+                    .iter()
+                    .find(|workload| workload.get("workload") == Some(&json!("analytics")))
+            })
+            .ok_or_else(|| eyre::eyre!("expected analytics counters"))?;
+        eyre::ensure!(
+            required_str_field(analytics, "mode") == "pause",
+            "expected analytics to be paused"
+        );
+        Ok(())
+    })
+}
+
+#[rstest]
+fn load_report_is_unavailable_without_load_shedding(
+    runtime: io::Result<Runtime>,
+) -> Result<(), eyre::Report> {
+    let rt = runtime?;
+    rt.block_on(async {
+        let bundle = build_bundle().await?;
+        let token = bundle.auth.token()?;
+        let service: Arc<Service> = Arc::new(MessageProcessingService::new(
+            Arc::new(bundle.messages.clone()),
+            Arc::new(InMemoryMessageProcessingRepository::new()),
+            Arc::new(DefaultClock),
+        ));
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::new(bundle.state.with_processing(service)))
+                .configure(api_routes),
+        )
+        .await;
+        let call = |request: TestRequest| actix_web::test::call_service(&app, request.to_request());
+
+        let body = send_json(
+            &call,
+            with_bearer(TestRequest::get().uri("/api/v1/processing/load"), &token),
+            503,
+        )
+        .await?;
+        eyre::ensure!(
+            required_str_field(required_field(&body, "details"), "reason")
+                == "load_shedding_unavailable",
+            "expected load_shedding_unavailable reason"
+        );
+        Ok(())
+    })
+}
@@ -3,7 +3,7 @@
 use crate::http_api_test_helpers::{HttpApiAuth, assert_shared_error, bootstrap_file_tools_server};
 use actix_web::test as actix_test;
 use corbusier::{
-    http_api::{ApiConfig, ApiState, BearerTokenAuthenticator},
+    http_api::{ADMIN_ROLE, ApiConfig, ApiState, BearerTokenAuthenticator, JwtClaims},
     message::{
         adapters::memory::{InMemoryConversationRepository, InMemoryMessageRepository},
         services::ConversationService,
@@ -30,6 +30,7 @@ pub use crate::http_api_test_helpers::{assert_v1_metadata, with_bearer};
 pub struct TestBundle {
     pub state: ApiState,
     pub auth: HttpApiAuth,
+    pub messages: InMemoryMessageRepository,
 }
 
 async fn build_tool_service(
@@ -108,10 +109,11 @@ pub async fn build_bundle() -> Result<TestBundle, eyre::Report> {
     let auth = HttpApiAuth::new(TEST_JWT_SECRET);
     let ctx = auth.request_context();
     let clock = Arc::new(DefaultClock);
+    let messages = InMemoryMessageRepository::new();
 
     let conversation_service = Arc::new(ConversationService::new(
         Arc::new(InMemoryConversationRepository::new()),
-        Arc::new(InMemoryMessageRepository::new()),
+        Arc::new(messages.clone()),
         Arc::new(DefaultMessageValidator::new()),
         clock.clone(),
     ));
@@ -133,9 +135,23 @@ pub async fn build_bundle() -> Result<TestBundle, eyre::Report> {
             },
         ),
         auth,
+        messages,
     })
 }
 
+/// Returns a token for the fixture principal carrying the admin role.
+pub fn admin_token(auth: &HttpApiAuth) -> Result<String, eyre::Report> {
+    let ctx = auth.request_context();
+    let claims = JwtClaims::new(
+        ctx.user_id().into_inner(),
+        ctx.tenant_id().into_inner(),
+        ctx.session_id().into_inner(),
+        chrono::Utc::now().timestamp().saturating_add(3_600),
+    )
+    .with_role(ADMIN_ROLE);
+    Ok(auth.encode_claims(&claims)?)
+}
+
 pub async fn assert_rejects_response<B>(response: actix_web::dev::ServiceResponse<B>) -> Value
 where
     B: actix_web::body::MessageBody,
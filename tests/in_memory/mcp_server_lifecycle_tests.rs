@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use super::helpers::request_ctx;
 use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
 use corbusier::tool_registry::{
     adapters::{InMemoryMcpServerHost, memory::InMemoryMcpServerRegistry},
     domain::{
@@ -131,7 +132,7 @@ async fn list_servers_returns_registered_entries(
 
     let servers = context
         .service
-        .list_all(&request_ctx)
+        .list_all(&request_ctx, PageRequest::default())
         .await
         .expect("listing should succeed");
 
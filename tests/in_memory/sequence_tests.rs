@@ -9,6 +9,7 @@ use corbusier::message::{
     domain::{ContentPart, ConversationId, Message, Role, TextPart},
     ports::repository::MessageRepository,
 };
+use corbusier::pagination::PageRequest;
 use mockable::DefaultClock;
 use rstest::rstest;
 use std::io;
@@ -62,10 +63,10 @@ fn generation_across_conversations(
     rt.block_on(repo.store(&ctx, &msg2)).expect("store failed");
 
     let conv1_messages = rt
-        .block_on(repo.find_by_conversation(&ctx, conv1))
+        .block_on(repo.find_by_conversation(&ctx, conv1, PageRequest::default()))
         .expect("find_by_conversation failed");
     let conv2_messages = rt
-        .block_on(repo.find_by_conversation(&ctx, conv2))
+        .block_on(repo.find_by_conversation(&ctx, conv2, PageRequest::default()))
         .expect("find_by_conversation failed");
 
     assert_eq!(conv1_messages.len(), 1);
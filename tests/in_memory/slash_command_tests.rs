@@ -9,6 +9,7 @@ use corbusier::message::{
     ports::repository::MessageRepository,
     services::SlashCommandService,
 };
+use corbusier::pagination::PageRequest;
 use rstest::rstest;
 use std::sync::Arc;
 use tokio::runtime::Runtime;
@@ -101,7 +102,7 @@ fn slash_command_execution_metadata_round_trip_in_memory(
         .expect("storing message should succeed");
 
     let persisted = rt
-        .block_on(repo.find_by_conversation(&ctx, conversation_id))
+        .block_on(repo.find_by_conversation(&ctx, conversation_id, PageRequest::default()))
         .expect("message lookup should succeed")
         .first()
         .cloned()
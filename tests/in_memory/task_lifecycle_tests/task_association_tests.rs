@@ -3,6 +3,7 @@
 use super::{TestService, assert_single_task_found, service};
 use crate::in_memory::helpers::ctx;
 use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
 use corbusier::task::{
     domain::{BranchRef, PullRequestRef, TaskState},
     services::{AssociateBranchRequest, AssociatePullRequestRequest, CreateTaskFromIssueRequest},
@@ -44,7 +45,7 @@ async fn associate_branch_and_retrieve_by_ref(
     let branch_ref = BranchRef::from_parts("github", "corbusier/core", "feature/branch-integ")
         .expect("valid branch ref");
     let found = service
-        .find_by_branch_ref(&ctx, &branch_ref)
+        .find_by_branch_ref(&ctx, &branch_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_single_task_found(&found, task.id())?;
@@ -76,7 +77,7 @@ async fn associate_pr_and_verify_state_transition(
 
     let pr_ref = PullRequestRef::from_parts("github", "corbusier/core", 55).expect("valid PR ref");
     let found = service
-        .find_by_pull_request_ref(&ctx, &pr_ref)
+        .find_by_pull_request_ref(&ctx, &pr_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_single_task_found(&found, task.id())?;
@@ -129,7 +130,7 @@ async fn multiple_tasks_sharing_branch_all_returned(service: TestService, ctx: R
     let branch_ref = BranchRef::from_parts("github", "corbusier/core", "shared/integration-branch")
         .expect("valid branch ref");
     let found = service
-        .find_by_branch_ref(&ctx, &branch_ref)
+        .find_by_branch_ref(&ctx, &branch_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_eq!(found.len(), 2);
@@ -179,7 +180,7 @@ async fn multiple_tasks_sharing_pull_request_all_returned(
 
     let pr_ref = PullRequestRef::from_parts("github", "corbusier/core", 77).expect("valid PR ref");
     let found = service
-        .find_by_pull_request_ref(&ctx, &pr_ref)
+        .find_by_pull_request_ref(&ctx, &pr_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_eq!(found.len(), 2);
@@ -199,7 +200,7 @@ async fn find_by_pull_request_ref_returns_empty_when_none_match(
 ) {
     let pr_ref = PullRequestRef::from_parts("github", "corbusier/core", 999).expect("valid PR ref");
     let found = service
-        .find_by_pull_request_ref(&ctx, &pr_ref)
+        .find_by_pull_request_ref(&ctx, &pr_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert!(
@@ -3,6 +3,7 @@
 use super::{TestService, assert_single_task_found, service};
 use crate::in_memory::helpers::ctx;
 use corbusier::context::{RequestContext, TenantId};
+use corbusier::pagination::PageRequest;
 use corbusier::task::{
     domain::{BranchRef, IssueRef, PullRequestRef, Task},
     ports::TaskRepositoryError,
@@ -126,11 +127,15 @@ async fn branch_lookup_is_scoped_to_tenant(
 
     let branch_ref = BranchRef::from_parts(PROVIDER, REPO, BRANCH)?;
 
-    let found_own = service.find_by_branch_ref(&ctx, &branch_ref).await?;
+    let found_own = service
+        .find_by_branch_ref(&ctx, &branch_ref, PageRequest::default())
+        .await?;
     assert_single_task_found(&found_own, task.id())?;
 
     let ctx_b = ctx_other_tenant(&ctx);
-    let found_other = service.find_by_branch_ref(&ctx_b, &branch_ref).await?;
+    let found_other = service
+        .find_by_branch_ref(&ctx_b, &branch_ref, PageRequest::default())
+        .await?;
     assert!(
         found_other.is_empty(),
         "other tenant must not see branch associations"
@@ -163,11 +168,15 @@ async fn pr_lookup_is_scoped_to_tenant(
 
     let pr_ref = PullRequestRef::from_parts(PROVIDER, REPO, ISSUE_NO)?;
 
-    let found_own = service.find_by_pull_request_ref(&ctx, &pr_ref).await?;
+    let found_own = service
+        .find_by_pull_request_ref(&ctx, &pr_ref, PageRequest::default())
+        .await?;
     assert_single_task_found(&found_own, task.id())?;
 
     let ctx_b = ctx_other_tenant(&ctx);
-    let found_other = service.find_by_pull_request_ref(&ctx_b, &pr_ref).await?;
+    let found_other = service
+        .find_by_pull_request_ref(&ctx_b, &pr_ref, PageRequest::default())
+        .await?;
     assert!(
         found_other.is_empty(),
         "other tenant must not see PR associations"
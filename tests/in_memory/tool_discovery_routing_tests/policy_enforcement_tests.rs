@@ -10,6 +10,7 @@ use corbusier::hook_engine::domain::{
 };
 use corbusier::hook_engine::ports::HookPolicyAuditRepository;
 use corbusier::hook_engine::services::{HookEngineService, HookEngineServiceDeps};
+use corbusier::pagination::PageRequest;
 use corbusier::task::domain::TaskId;
 use corbusier::tool_registry::adapters::HookBackedToolExecutionGovernance;
 use corbusier::tool_registry::domain::{
@@ -165,7 +166,7 @@ async fn denied_call_leaves_queryable_policy_audit(
     ));
     assert_eq!(
         policy_audit
-            .find_by_task(&request_ctx, task_id)
+            .find_by_task(&request_ctx, task_id, PageRequest::default())
             .await
             .expect("query by task succeeds")
             .len(),
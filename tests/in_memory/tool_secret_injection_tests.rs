@@ -0,0 +1,287 @@
+//! In-memory integration tests for per-tool secret injection at spawn time.
+
+use std::sync::{Arc, Mutex};
+
+use super::helpers::{other_ctx, request_ctx};
+use async_trait::async_trait;
+use corbusier::context::RequestContext;
+use corbusier::tool_registry::{
+    adapters::{
+        InMemoryMcpServerHost, SecretInjectingHost,
+        memory::{
+            InMemoryMcpServerRegistry, InMemorySecretInjectionAudit, InMemoryToolSecretStore,
+        },
+    },
+    domain::{
+        McpServerHealthSnapshot, McpServerLifecycleState, McpServerRegistration, McpToolDefinition,
+        McpTransport, SecretReference, SecretValue, StdioTransportConfig, ToolCallRequest,
+        ToolRegistryDomainError,
+    },
+    ports::{
+        McpServerHost, McpServerHostError, McpServerHostResult, StartHostResult, ToolCallHostResult,
+    },
+    services::{
+        McpServerLifecycleService, McpServerLifecycleServiceError, RegisterMcpServerRequest,
+    },
+};
+use mockable::DefaultClock;
+use rstest::{fixture, rstest};
+
+const TOKEN: &str = "ghp_example_token";
+
+/// Host that remembers the registrations it was asked to spawn.
+#[derive(Clone, Default)]
+struct SpawnRecordingHost {
+    inner: InMemoryMcpServerHost,
+    spawned: Arc<Mutex<Vec<McpServerRegistration>>>,
+}
+
+impl SpawnRecordingHost {
+    fn spawned(&self) -> Vec<McpServerRegistration> {
+        self.spawned
+            .lock()
+            .map(|spawned| spawned.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl McpServerHost for SpawnRecordingHost {
+    async fn start(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+    ) -> McpServerHostResult<StartHostResult> {
+        if let Ok(mut spawned) = self.spawned.lock() {
+            spawned.push(server.clone());
+        }
+        self.inner.start(ctx, server).await
+    }
+
+    async fn stop(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+    ) -> McpServerHostResult<()> {
+        self.inner.stop(ctx, server).await
+    }
+
+    async fn health(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+    ) -> McpServerHostResult<McpServerHealthSnapshot> {
+        self.inner.health(ctx, server).await
+    }
+
+    async fn list_tools(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+    ) -> McpServerHostResult<Vec<McpToolDefinition>> {
+        self.inner.list_tools(ctx, server).await
+    }
+
+    async fn call_tool(
+        &self,
+        ctx: &RequestContext,
+        server: &McpServerRegistration,
+        request: &ToolCallRequest,
+    ) -> McpServerHostResult<ToolCallHostResult> {
+        self.inner.call_tool(ctx, server, request).await
+    }
+}
+
+type TestService = McpServerLifecycleService<
+    InMemoryMcpServerRegistry,
+    SecretInjectingHost<SpawnRecordingHost>,
+    DefaultClock,
+>;
+
+struct TestContext {
+    host: Arc<SpawnRecordingHost>,
+    secrets: Arc<InMemoryToolSecretStore>,
+    audit: Arc<InMemorySecretInjectionAudit>,
+    service: TestService,
+}
+
+#[fixture]
+fn context() -> TestContext {
+    let host = Arc::new(SpawnRecordingHost::default());
+    let secrets = Arc::new(InMemoryToolSecretStore::new());
+    let audit = Arc::new(InMemorySecretInjectionAudit::new());
+    let service = McpServerLifecycleService::new(
+        Arc::new(InMemoryMcpServerRegistry::new()),
+        Arc::new(SecretInjectingHost::new(
+            host.clone(),
+            secrets.clone(),
+            audit.clone(),
+            Arc::new(DefaultClock),
+        )),
+        Arc::new(DefaultClock),
+    );
+    TestContext {
+        host,
+        secrets,
+        audit,
+        service,
+    }
+}
+
+fn reference() -> Result<SecretReference, ToolRegistryDomainError> {
+    SecretReference::new("github/token")
+}
+
+fn github_request() -> Result<RegisterMcpServerRequest, ToolRegistryDomainError> {
+    let config = StdioTransportConfig::new("mcp-github")?
+        .with_env([("LOG_LEVEL".to_owned(), "info".to_owned())])
+        .with_secret_env("GITHUB_TOKEN", reference()?)?;
+    Ok(RegisterMcpServerRequest::new(
+        "github_tools",
+        McpTransport::Stdio(config),
+    ))
+}
+
+fn stdio_config(server: &McpServerRegistration) -> &StdioTransportConfig {
+    match server.transport() {
+        McpTransport::Stdio(config) => config,
+        McpTransport::HttpSse(_) => panic!("expected a STDIO transport"),
+    }
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn start_injects_resolved_secrets_without_persisting_them(
+    context: TestContext,
+    request_ctx: RequestContext,
+) {
+    let reference = reference().expect("valid reference");
+    context
+        .secrets
+        .put(&request_ctx, reference.clone(), SecretValue::new(TOKEN))
+        .expect("store secret");
+    let registered = context
+        .service
+        .register(&request_ctx, github_request().expect("valid test request"))
+        .await
+        .expect("registration should succeed");
+
+    let started = context
+        .service
+        .start(&request_ctx, registered.id())
+        .await
+        .expect("start should succeed");
+
+    let spawned = context.host.spawned();
+    let spawn_env = spawned.first().map(|server| stdio_config(server).env());
+    assert_eq!(
+        spawn_env
+            .and_then(|env| env.get("GITHUB_TOKEN"))
+            .map(String::as_str),
+        Some(TOKEN)
+    );
+    assert_eq!(
+        spawn_env
+            .and_then(|env| env.get("LOG_LEVEL"))
+            .map(String::as_str),
+        Some("info")
+    );
+    let persisted = stdio_config(&started.server);
+    assert!(!persisted.env().contains_key("GITHUB_TOKEN"));
+    assert_eq!(persisted.secret_env().get("GITHUB_TOKEN"), Some(&reference));
+    let serialized = serde_json::to_string(&started.server).expect("serialize registration");
+    assert!(!serialized.contains(TOKEN));
+
+    let events = context.audit.events(&request_ctx).expect("audit events");
+    assert_eq!(events.len(), 1);
+    let event = events.first().expect("one event");
+    assert_eq!(event.server_id, registered.id());
+    assert_eq!(
+        event.variables,
+        vec![("GITHUB_TOKEN".to_owned(), reference)]
+    );
+    let audited = serde_json::to_string(event).expect("serialize event");
+    assert!(!audited.contains(TOKEN));
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn start_refuses_to_spawn_when_a_secret_cannot_be_resolved(
+    context: TestContext,
+    request_ctx: RequestContext,
+) {
+    let reference = reference().expect("valid reference");
+    context
+        .secrets
+        .put(&other_ctx(), reference.clone(), SecretValue::new(TOKEN))
+        .expect("store secret for another tenant");
+    let registered = context
+        .service
+        .register(&request_ctx, github_request().expect("valid test request"))
+        .await
+        .expect("registration should succeed");
+
+    let result = context.service.start(&request_ctx, registered.id()).await;
+
+    assert!(matches!(
+        result,
+        Err(McpServerLifecycleServiceError::Host(
+            McpServerHostError::ProcessSpawnFailed { ref reason, .. }
+        )) if reason.contains("github/token")
+    ));
+    assert!(context.host.spawned().is_empty());
+    assert!(
+        context
+            .audit
+            .events(&request_ctx)
+            .expect("audit events")
+            .is_empty()
+    );
+    let server = context
+        .service
+        .find_by_name(&request_ctx, "github_tools")
+        .await
+        .expect("lookup should succeed")
+        .expect("server should exist");
+    assert_eq!(
+        server.lifecycle_state(),
+        McpServerLifecycleState::Registered
+    );
+}
+
+#[rstest]
+#[case(("", Err(ToolRegistryDomainError::EmptySecretVariable)))]
+#[case((
+    "LOG_LEVEL",
+    Err(ToolRegistryDomainError::SecretVariableConflict("LOG_LEVEL".to_owned()))
+))]
+#[case((" GITHUB_TOKEN ", Ok("GITHUB_TOKEN")))]
+fn secret_variables_are_validated(
+    #[case] (variable, expected): (&str, Result<&str, ToolRegistryDomainError>),
+) {
+    let result = StdioTransportConfig::new("mcp-github")
+        .expect("valid command")
+        .with_env([("LOG_LEVEL".to_owned(), "info".to_owned())])
+        .with_secret_env(variable, reference().expect("valid reference"));
+
+    assert_eq!(
+        result.map(|config| config.secret_env().keys().cloned().collect::<Vec<_>>()),
+        expected.map(|name| vec![name.to_owned()])
+    );
+}
+
+#[rstest]
+fn transports_persisted_before_secret_references_still_load() {
+    let persisted = serde_json::json!({
+        "command": "mcp-github",
+        "args": [],
+        "env": {"LOG_LEVEL": "info"},
+        "working_directory": null
+    });
+
+    let config: StdioTransportConfig =
+        serde_json::from_value(persisted).expect("legacy transport should load");
+
+    assert!(config.secret_env().is_empty());
+    assert!(SecretReference::new(" ").is_err());
+}
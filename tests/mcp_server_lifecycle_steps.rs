@@ -3,6 +3,7 @@
 use std::sync::Arc;
 
 use corbusier::context::{CorrelationId, RequestContext, SessionId, TenantId, UserId};
+use corbusier::pagination::PageRequest;
 use corbusier::tool_registry::{
     adapters::{InMemoryMcpServerHost, memory::InMemoryMcpServerRegistry},
     domain::{McpServerName, McpServerRegistration, McpToolDefinition, McpTransport},
@@ -180,9 +181,13 @@ fn list_servers_returns_count_impl(
     world: &mut McpLifecycleWorld,
     count: usize,
 ) -> Result<(), eyre::Report> {
-    let servers = run_async(world.service()?.list_all(&world.request_ctx))
-        .wrap_err("listing should succeed")?;
-    world.last_servers = Some(servers.clone());
+    let servers = run_async(
+        world
+            .service()?
+            .list_all(&world.request_ctx, PageRequest::default()),
+    )
+    .wrap_err("listing should succeed")?;
+    world.last_servers = Some(servers.to_vec());
     if servers.len() != count {
         return Err(eyre!("expected {count} servers, got {}", servers.len()));
     }
@@ -2,24 +2,69 @@
 //!
 //! Tests are organized into modules by functionality:
 //! - `cluster`: Embedded `PostgreSQL` cluster lifecycle helpers
+//! - `agent_memory_postgres_tests`: Long-term agent memory fact persistence
 //! - `agent_session_tests`: Agent session persistence and active-session uniqueness
+//! - `attachment_blob_postgres_tests`: Attachment data kept in a blob store
+//! - `async_repository_tests`: The `diesel-async` message repository (feature `async-postgres`)
+//! - `audit_log_postgres_tests`: Audit trail queries by correlation, aggregate, and tenant
 //! - `audit_tests`: Audit context capture and verification
+//! - `batch_insert_tests`: Atomic batch message insertion and duplicate diagnostics
 //! - `agent_turn_orchestration_tests`: Turn execution and session continuity
+//! - `backend_deprecation_postgres_tests`: Pinned-session and backend usage queries
 //! - `backend_registry_tests`: Agent backend registration and discovery
+//! - `context_assembly_postgres_tests`: Context assembly reports per conversation
+//! - `conversation_archival_postgres_tests`: Write protection for archived conversations
+//! - `conversation_fork_postgres_tests`: Forked conversation copies and provenance queries
+//! - `conversation_label_postgres_tests`: Label storage, containment queries, and label history
+//! - `conversation_lifecycle_postgres_tests`: Conversation state and context round-trips
+//! - `conversation_list_postgres_tests`: Conversation listings over the summary projection
+//! - `conversation_transfer_postgres_tests`: Moving conversations and their rows between tenants
 //! - `crud_tests`: Basic CRUD operations
+//! - `encryption_postgres_tests`: Transparent encryption of message content
+//! - `erasure_postgres_tests`: Subject erasure and erasure certificates
+//! - `experiment_postgres_tests`: Backend experiment persistence and per-arm reports
+//! - `health_check_postgres_tests`: Adapter health checks and readiness probes
+//! - `issue_notification_postgres_tests`: Queued issue status updates and their retries
+//! - `key_rotation_postgres_tests`: Resumable resealing of message content under a new key
 //! - `mcp_server_lifecycle_tests`: MCP server lifecycle persistence
+//! - `maintenance_postgres_tests`: Cluster-wide maintenance flag persistence
+//! - `message_feedback_postgres_tests`: Message feedback upserts and summaries
+//! - `message_processing_postgres_tests`: Processing stage status, stuck queries, and reprocessing
+//! - `message_query_postgres_tests`: Message filtering by role, content type, time, and metadata
+//! - `operator_action_postgres_tests`: Operator action records and timeline queries
+//! - `optimistic_concurrency_postgres_tests`: Version checks on conversation and session updates
+//! - `pinned_message_postgres_tests`: Pinning, unpinning, and pinned message queries
+//! - `projection_postgres_tests`: Domain event stream positions and projection checkpoints
+//! - `read_replica_postgres_tests`: Read routing to a replica pool and primary fallback
+//! - `redaction_postgres_tests`: Message redaction tombstones and audit scrubbing
+//! - `retention_postgres_tests`: Retention policies, dry runs, and batched purges
+//! - `rolling_summary_postgres_tests`: Rolling conversation summary upserts
+//! - `schema_check_postgres_tests`: Startup schema compatibility against migration history
 //! - `sequence_tests`: Sequence number management
+//! - `secret_injection_audit_postgres_tests`: Secret injection audit events per server
 //! - `serialization_tests`: Role parsing, JSONB round-trips, metadata handling
+//! - `slash_command_definition_postgres_tests`: Versioned slash command definitions
+//! - `slash_command_execution_postgres_tests`: Slash command execution audit trail
 //! - `slash_command_tests`: Slash command metadata round-trips
+//! - `snapshot_policy_postgres_tests`: Periodic snapshot policy upserts and removal
+//! - `snapshot_pruning_postgres_tests`: Snapshot deletion and pruning by type and age
 //! - `sql_helpers_tests`: SQL helper function unit tests
+//! - `streaming_postgres_tests`: Streamed assistant message staging
 //! - `task_branch_pr_postgres_tests`: Branch and PR association tests
+//! - `task_cost_postgres_tests`: Turn usage records and task cost roll-ups
 //! - `task_lifecycle_tests`: Issue-to-task creation and lookup
+//! - `task_queue_postgres_tests`: Priority-ordered, skip-locked task claims
+//! - `task_reconciliation_postgres_tests`: Stale branch and PR reconciliation
 //! - `task_tenant_isolation_tests`: Tenant context propagation for task operations
 //! - `tenant_schema_constraints_tests`: Composite FK enforcement for tenant-aware core tables
 //! - `tool_discovery_tenant_isolation_tests`: Composite FK and index-plan checks
 //! - `uniqueness_tests`: Uniqueness constraint enforcement
+//! - `usage_budget_postgres_tests`: Delegated budget splits and reconciliation
+//! - `webhook_delivery_postgres_tests`: Webhook delivery claims and releases
 //! - `tool_discovery_routing_tests`: Tool discovery, catalog, and audit trail
 //! - `tool_policy_enforcement_tests`: Hook-backed policy enforcement for tool calls
+//! - `turn_callback_postgres_tests`: One-shot turn completion callbacks
+//! - `turn_outcome_postgres_tests`: Turn outcome records per conversation
 //! - `hook_engine_tests`: Hook execution log persistence
 //! - `http_api_surface_tests`: HTTP API surface integration tests
 //! - `http_api_task_contract_tests`: Golden HTTP task contract fixture coverage
@@ -36,25 +81,73 @@ mod postgres {
     pub(crate) mod http_api_surface_common;
     pub(crate) mod migrations;
 
+    mod agent_memory_postgres_tests;
     mod agent_session_tests;
     mod agent_turn_orchestration_tests;
+    #[cfg(feature = "async-postgres")]
+    mod async_repository_tests;
+    mod attachment_blob_postgres_tests;
+    mod audit_log_postgres_tests;
     mod audit_tests;
+    mod backend_deprecation_postgres_tests;
     mod backend_registry_tests;
+    mod batch_insert_tests;
+    mod compaction_postgres_tests;
+    mod context_assembly_postgres_tests;
+    mod conversation_archival_postgres_tests;
+    mod conversation_fork_postgres_tests;
+    mod conversation_label_postgres_tests;
+    mod conversation_lifecycle_postgres_tests;
+    mod conversation_list_postgres_tests;
+    mod conversation_transfer_postgres_tests;
     mod crud_tests;
+    mod encryption_postgres_tests;
+    mod erasure_postgres_tests;
+    mod experiment_postgres_tests;
+    mod health_check_postgres_tests;
     mod hook_engine_tests;
     mod http_api_surface_tests;
     mod http_api_task_contract_tests;
+    mod issue_notification_postgres_tests;
+    mod key_rotation_postgres_tests;
+    mod maintenance_postgres_tests;
     mod mcp_server_lifecycle_tests;
+    mod message_feedback_postgres_tests;
+    mod message_processing_postgres_tests;
+    mod message_query_postgres_tests;
+    mod operator_action_postgres_tests;
+    mod optimistic_concurrency_postgres_tests;
+    mod pinned_message_postgres_tests;
+    mod projection_postgres_tests;
+    mod read_replica_postgres_tests;
+    mod redaction_postgres_tests;
+    mod retention_postgres_tests;
+    mod rolling_summary_postgres_tests;
+    mod schema_check_postgres_tests;
+    mod secret_injection_audit_postgres_tests;
     mod sequence_tests;
     mod serialization_tests;
+    mod slash_command_definition_postgres_tests;
+    mod slash_command_execution_postgres_tests;
     mod slash_command_tests;
+    mod snapshot_policy_postgres_tests;
+    mod snapshot_pruning_postgres_tests;
     mod sql_helpers_tests;
+    mod streaming_postgres_tests;
     mod task_branch_pr_postgres_tests;
+    mod task_cost_postgres_tests;
     mod task_lifecycle_tests;
+    mod task_queue_postgres_tests;
+    mod task_reconciliation_postgres_tests;
     mod task_tenant_isolation_tests;
     mod tenant_schema_constraints_tests;
+    mod token_usage_postgres_tests;
     mod tool_discovery_routing_tests;
     mod tool_discovery_tenant_isolation_tests;
     mod tool_policy_enforcement_tests;
+    mod turn_callback_postgres_tests;
+    mod turn_outcome_postgres_tests;
     mod uniqueness_tests;
+    mod usage_budget_postgres_tests;
+    mod webhook_delivery_postgres_tests;
 }
@@ -0,0 +1,79 @@
+//! `PostgreSQL` integration tests for long-term agent memory persistence.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use chrono::Duration;
+use corbusier::agent_backend::{
+    adapters::postgres::PostgresAgentMemoryRepository,
+    domain::{BackendId, MemoryFact, MemoryFactDraft},
+    ports::{AgentMemoryRepository, AgentMemoryRepositoryError},
+};
+use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
+use mockable::DefaultClock;
+use rstest::rstest;
+use uuid::Uuid;
+
+fn fact(backend_id: BackendId, content: &str) -> Result<MemoryFact, BoxError> {
+    let draft = MemoryFactDraft::new(content, 80)?.with_expiry(Duration::days(7));
+    Ok(
+        MemoryFact::new(backend_id, draft, vec![0.25, -0.5, 1.0], &DefaultClock)?
+            .with_source_conversation(Uuid::new_v4()),
+    )
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_memory_facts_round_trip_per_backend(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repository = PostgresAgentMemoryRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let backend_id = BackendId::new();
+    let mut remembered = fact(backend_id, "The team writes Rust with tabs")?;
+    let other_backend = fact(BackendId::new(), "The database is Postgres")?;
+    repository.upsert(&ctx, &remembered).await?;
+    repository.upsert(&ctx, &other_backend).await?;
+
+    remembered.set_confidence(95, &DefaultClock)?;
+    remembered.set_expiry(None);
+    repository.upsert(&ctx, &remembered).await?;
+
+    assert_eq!(
+        repository.find_by_id(&ctx, remembered.id()).await?,
+        Some(remembered.clone())
+    );
+    assert_eq!(
+        repository
+            .list_for_backend(&ctx, backend_id, PageRequest::default())
+            .await?
+            .into_items(),
+        vec![remembered]
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_deleting_a_missing_memory_fact_is_not_found(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repository = PostgresAgentMemoryRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let remembered = fact(BackendId::new(), "The team writes Rust with tabs")?;
+    repository.upsert(&ctx, &remembered).await?;
+
+    repository.delete(&ctx, remembered.id()).await?;
+    let result = repository.delete(&ctx, remembered.id()).await;
+
+    assert!(matches!(
+        result,
+        Err(AgentMemoryRepositoryError::NotFound(id)) if id == remembered.id()
+    ));
+    assert_eq!(repository.find_by_id(&ctx, remembered.id()).await?, None);
+    Ok(())
+}
@@ -0,0 +1,217 @@
+//! Tests for the `diesel-async` `PostgreSQL` message repository.
+
+use crate::postgres::cluster::TemporaryDatabase;
+use crate::postgres::helpers::{
+    BoxError, PostgresCluster, TEMPLATE_DB, clock, create_test_message, ensure_template,
+    fetch_audit_log_for_message, insert_conversation, other_tenant_ctx, postgres_cluster,
+    test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::{encryption::AesGcmContentCipher, postgres::AsyncPostgresMessageRepository},
+    domain::{
+        ContentPart, ContentPartKind, ConversationId, EncryptionKeyId, Message, MessageQuery, Role,
+        SequenceNumber, ToolResultPart,
+    },
+    error::RepositoryError,
+    ports::repository::MessageRepository,
+};
+use corbusier::pagination::PageRequest;
+use diesel_async::AsyncPgConnection;
+use diesel_async::pooled_connection::{AsyncDieselConnectionManager, deadpool::Pool};
+use futures::future::try_join_all;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+use uuid::Uuid;
+
+async fn setup_async_repository(
+    cluster: PostgresCluster,
+) -> Result<(TemporaryDatabase, AsyncPostgresMessageRepository), BoxError> {
+    ensure_template(cluster).await?;
+    let temp_db = cluster
+        .temporary_database_from_template(&format!("test_{}", Uuid::new_v4()), TEMPLATE_DB)
+        .await?;
+    let manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new(temp_db.url());
+    let pool = Pool::builder(manager).max_size(4).build()?;
+    Ok((temp_db, AsyncPostgresMessageRepository::new(pool)))
+}
+
+#[rstest]
+#[tokio::test]
+async fn stored_messages_are_found_within_their_tenant(
+    clock: DefaultClock,
+    test_request_context: RequestContext,
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+) -> Result<(), BoxError> {
+    let cluster = postgres_cluster?;
+    let (temp_db, repo) = setup_async_repository(cluster).await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(cluster, temp_db.name(), conversation_id, &ctx).await?;
+    let message = create_test_message(&clock, conversation_id, 1)?;
+
+    repo.store(&ctx, &message).await?;
+
+    assert_eq!(
+        repo.find_by_id(&ctx, message.id()).await?,
+        Some(message.clone())
+    );
+    assert!(repo.exists(&ctx, message.id()).await?);
+    let page = repo
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
+        .await?;
+    assert_eq!(page.items(), [message.clone()].as_slice());
+    assert_eq!(
+        repo.next_sequence_number(&ctx, conversation_id)
+            .await?
+            .value(),
+        2
+    );
+    let outsider = other_tenant_ctx(&ctx);
+    assert_eq!(repo.find_by_id(&outsider, message.id()).await?, None);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn duplicate_sequences_are_reported(
+    clock: DefaultClock,
+    test_request_context: RequestContext,
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+) -> Result<(), BoxError> {
+    let cluster = postgres_cluster?;
+    let (temp_db, repo) = setup_async_repository(cluster).await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(cluster, temp_db.name(), conversation_id, &ctx).await?;
+    repo.store(&ctx, &create_test_message(&clock, conversation_id, 1)?)
+        .await?;
+
+    let result = repo
+        .store(&ctx, &create_test_message(&clock, conversation_id, 1)?)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RepositoryError::DuplicateSequence { conversation_id: id, sequence })
+            if id == conversation_id && sequence.value() == 1
+    ));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn concurrent_appends_allocate_distinct_sequences(
+    clock: DefaultClock,
+    test_request_context: RequestContext,
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+) -> Result<(), BoxError> {
+    let cluster = postgres_cluster?;
+    let (temp_db, repo) = setup_async_repository(cluster).await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(cluster, temp_db.name(), conversation_id, &ctx).await?;
+    let messages = (0..8)
+        .map(|_| create_test_message(&clock, conversation_id, 1))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let appended = try_join_all(messages.iter().map(|message| repo.append(&ctx, message))).await?;
+
+    let mut sequences: Vec<u64> = appended
+        .iter()
+        .map(|message| message.sequence_number().value())
+        .collect();
+    sequences.sort_unstable();
+    assert_eq!(sequences, (1..=8).collect::<Vec<u64>>());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn queries_filter_within_the_conversation(
+    clock: DefaultClock,
+    test_request_context: RequestContext,
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+) -> Result<(), BoxError> {
+    let cluster = postgres_cluster?;
+    let (temp_db, repo) = setup_async_repository(cluster).await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(cluster, temp_db.name(), conversation_id, &ctx).await?;
+    let prompt = create_test_message(&clock, conversation_id, 1)?;
+    let tool_result = Message::builder(conversation_id, Role::Tool, SequenceNumber::new(2))
+        .with_content(ContentPart::ToolResult(ToolResultPart::success(
+            "call-1",
+            serde_json::json!("ok"),
+        )))
+        .build(&clock)?;
+    repo.store_batch(&ctx, &[prompt, tool_result.clone()])
+        .await?;
+
+    let query = MessageQuery::new()
+        .with_role(Role::Tool)
+        .with_content_part(ContentPartKind::ToolResult);
+    let page = repo.query(&ctx, conversation_id, &query).await?;
+
+    assert_eq!(page.items(), [tool_result].as_slice());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn encrypted_messages_are_sealed_and_read_back(
+    clock: DefaultClock,
+    test_request_context: RequestContext,
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+) -> Result<(), BoxError> {
+    let cluster = postgres_cluster?;
+    let (temp_db, plain) = setup_async_repository(cluster).await?;
+    let cipher = AesGcmContentCipher::new(EncryptionKeyId::new("content-2026-10")?, [7; 32]);
+    let repo = plain.clone().with_cipher(Arc::new(cipher));
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(cluster, temp_db.name(), conversation_id, &ctx).await?;
+    let message = create_test_message(&clock, conversation_id, 1)?;
+
+    let appended = repo.append(&ctx, &message).await?;
+    repo.set_pinned(&ctx, appended.id(), true).await?;
+
+    let found = repo
+        .find_by_id(&ctx, appended.id())
+        .await?
+        .ok_or("appended message")?;
+    assert_eq!(found.content(), message.content());
+    assert!(found.metadata().pinned);
+    assert!(plain.find_by_id(&ctx, appended.id()).await.is_err());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn pins_carry_the_audit_context(
+    clock: DefaultClock,
+    test_request_context: RequestContext,
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+) -> Result<(), BoxError> {
+    let cluster = postgres_cluster?;
+    let (temp_db, repo) = setup_async_repository(cluster).await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(cluster, temp_db.name(), conversation_id, &ctx).await?;
+    let message = create_test_message(&clock, conversation_id, 1)?;
+    repo.store_with_audit(&ctx, &message).await?;
+
+    repo.set_pinned(&ctx, message.id(), true).await?;
+
+    let audit_log = fetch_audit_log_for_message(cluster, temp_db.name(), message.id().into_inner())
+        .await?
+        .ok_or("audit log entry")?;
+    assert_eq!(audit_log.operation, "UPDATE");
+    assert_eq!(audit_log.user_id, Some(ctx.user_id().into_inner()));
+    assert_eq!(
+        audit_log.correlation_id,
+        Some(ctx.correlation_id().into_inner())
+    );
+    Ok(())
+}
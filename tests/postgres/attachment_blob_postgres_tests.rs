@@ -0,0 +1,111 @@
+//! `PostgreSQL` integration tests for attachment blob externalisation.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::{
+        blob_store::ObjectStoreBlobStore, encryption::AesGcmContentCipher,
+        externalising::ExternalisingMessageRepository,
+    },
+    domain::{
+        AttachmentPart, ContentHash, ContentPart, ConversationId, EncryptionKeyId, Message,
+        MessageRedaction, RedactionReason, Role, SequenceNumber,
+    },
+    ports::{blob_store::BlobStore, repository::MessageRepository},
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_rows_keep_references_to_externalised_attachments(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let inner = Arc::new(prep.repo.clone());
+    let repository = ExternalisingMessageRepository::new(
+        Arc::clone(&inner),
+        Arc::new(ObjectStoreBlobStore::in_memory()),
+        1024,
+    );
+    let data = "iVBORw0KGgo".repeat(500);
+    let message = Message::new(
+        conversation_id,
+        Role::User,
+        vec![ContentPart::Attachment(
+            AttachmentPart::new("image/png", data.as_str()).with_name("screenshot.png"),
+        )],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )?;
+
+    let appended = repository.append(&ctx, &message).await?;
+
+    let raw = inner
+        .find_by_id(&ctx, message.id())
+        .await?
+        .ok_or("message was not stored")?;
+    assert_eq!(
+        raw.attachment_blobs().collect::<Vec<_>>(),
+        vec![&ContentHash::of(data.as_bytes())]
+    );
+    let found = repository
+        .find_by_id(&ctx, message.id())
+        .await?
+        .ok_or("message was not stored")?;
+    assert_eq!(found.content(), message.content());
+    assert_eq!(appended.sequence_number(), SequenceNumber::new(1));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_redaction_deletes_blobs_only_once_unreferenced(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let cipher = AesGcmContentCipher::new(EncryptionKeyId::new("content-2026-10")?, [7; 32]);
+    let blobs = Arc::new(ObjectStoreBlobStore::in_memory());
+    let repository = ExternalisingMessageRepository::new(
+        Arc::new(prep.repo.clone().with_cipher(Arc::new(cipher))),
+        Arc::clone(&blobs) as Arc<dyn BlobStore>,
+        1024,
+    );
+    let data = "iVBORw0KGgo".repeat(500);
+    let hash = ContentHash::of(data.as_bytes());
+    let mut messages = Vec::new();
+    for _ in 0..2 {
+        let message = Message::new(
+            conversation_id,
+            Role::User,
+            vec![ContentPart::Attachment(AttachmentPart::new(
+                "image/png",
+                data.as_str(),
+            ))],
+            SequenceNumber::new(1),
+            &DefaultClock,
+        )?;
+        messages.push(repository.append(&ctx, &message).await?);
+    }
+    let reason = RedactionReason::new("pasted credentials")?;
+
+    for (redacted, shared) in messages.iter().zip([true, false]) {
+        let redaction =
+            MessageRedaction::new(redacted.id(), reason.clone(), ctx.user_id(), &DefaultClock);
+        repository.redact(&ctx, &redaction).await?;
+        assert_eq!(blobs.get(&ctx, &hash).await?.is_some(), shared);
+    }
+    Ok(())
+}
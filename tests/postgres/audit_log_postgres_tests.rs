@@ -0,0 +1,149 @@
+//! `PostgreSQL` integration tests for reading the audit trail.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, clock, create_test_message, insert_conversation, prepared_repo,
+    test_request_context,
+};
+use chrono::Utc;
+use corbusier::audit::{
+    adapters::PostgresAuditLogRepository,
+    domain::{AuditOperation, AuditQuery},
+    ports::AuditLogRepository,
+};
+use corbusier::context::{RequestContext, TenantId};
+use corbusier::message::adapters::{models::NewDomainEvent, schema::domain_events};
+use corbusier::message::domain::ConversationId;
+use corbusier::message::ports::repository::MessageRepository;
+use corbusier::pagination::PageRequest;
+use diesel::prelude::*;
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn audited_writes_are_read_back_by_correlation(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let message = create_test_message(&clock, conversation_id, 1)?;
+    prep.repo.store_with_audit(&ctx, &message).await?;
+    let audit = PostgresAuditLogRepository::new(build_pool(prep.temp_db.url(), 1)?);
+
+    let changes = audit
+        .changes(
+            &ctx,
+            AuditQuery::for_correlation(ctx.correlation_id()),
+            PageRequest::default(),
+        )
+        .await?;
+
+    assert!(changes.items().iter().any(|entry| {
+        entry.table_name == "messages"
+            && entry.operation == AuditOperation::Insert
+            && entry.row_id == Some(message.id().into_inner())
+            && entry.user_id == Some(ctx.user_id())
+    }));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn events_are_read_back_by_aggregate_within_the_tenant(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let message = create_test_message(&clock, conversation_id, 1)?;
+    prep.repo.store(&ctx, &message).await?;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let aggregate_id = message.id().into_inner();
+    {
+        let mut conn = pool.get()?;
+        diesel::insert_into(domain_events::table)
+            .values(&NewDomainEvent {
+                id: uuid::Uuid::new_v4(),
+                aggregate_id,
+                aggregate_type: "Message".to_owned(),
+                event_type: "MessageAppended".to_owned(),
+                event_data: json!({}),
+                event_version: 1,
+                occurred_at: Utc::now(),
+                correlation_id: Some(ctx.correlation_id().into_inner()),
+                causation_id: None,
+                user_id: Some(ctx.user_id().into_inner()),
+                session_id: None,
+            })
+            .execute(&mut conn)?;
+    }
+    let audit = PostgresAuditLogRepository::new(pool);
+    let other_tenant = RequestContext::new(
+        TenantId::new(),
+        ctx.correlation_id(),
+        ctx.user_id(),
+        ctx.session_id(),
+    );
+
+    let own = audit
+        .events(
+            &ctx,
+            AuditQuery::for_aggregate(aggregate_id),
+            PageRequest::default(),
+        )
+        .await?;
+    let foreign = audit
+        .events(
+            &other_tenant,
+            AuditQuery::for_aggregate(aggregate_id),
+            PageRequest::default(),
+        )
+        .await?;
+
+    let [event] = own.items() else {
+        panic!("expected one event, got {}", own.items().len());
+    };
+    assert_eq!(event.event_type, "MessageAppended");
+    assert!(foreign.items().is_empty());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn writes_inside_a_request_scope_are_attributed(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let message = create_test_message(&clock, conversation_id, 1)?;
+    ctx.clone().scope(prep.repo.store(&ctx, &message)).await?;
+    let audit = PostgresAuditLogRepository::new(build_pool(prep.temp_db.url(), 1)?);
+
+    let changes = audit
+        .changes(
+            &ctx,
+            AuditQuery::for_aggregate(message.id().into_inner()),
+            PageRequest::default(),
+        )
+        .await?;
+
+    let [entry] = changes.items() else {
+        panic!("expected one change, got {}", changes.items().len());
+    };
+    assert_eq!(entry.correlation_id, Some(ctx.correlation_id()));
+    assert_eq!(entry.user_id, Some(ctx.user_id()));
+    Ok(())
+}
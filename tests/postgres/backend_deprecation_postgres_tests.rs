@@ -0,0 +1,96 @@
+//! `PostgreSQL` integration tests for the queries behind backend deprecation
+//! impact reports.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::PostgresAgentSessionRepository,
+    domain::{AgentSession, ConversationId, SequenceNumber, TurnId},
+    ports::agent_session::AgentSessionRepository,
+};
+use corbusier::pagination::PageRequest;
+use corbusier::task::{
+    adapters::postgres::PostgresTaskCostLedger,
+    domain::{CostMicros, TokenUsage, UsageRecord, UsageRecordParams},
+    ports::TaskCostLedger,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+fn usage(conversation_id: ConversationId, agent_backend: &str, micros: u64) -> UsageRecord {
+    UsageRecord::new(
+        UsageRecordParams {
+            conversation_id,
+            turn_id: TurnId::new(),
+            agent_backend: agent_backend.to_owned(),
+            usage: TokenUsage::new(1_000, 100),
+            cost: CostMicros::new(micros),
+        },
+        &DefaultClock,
+    )
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_finds_pinned_sessions_and_their_backend_usage(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let sessions = PostgresAgentSessionRepository::new(pool.clone());
+    let ledger = PostgresTaskCostLedger::new(pool);
+    let pinned = ConversationId::new();
+    let moved = ConversationId::new();
+    for conversation_id in [pinned, moved] {
+        insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    }
+
+    sessions
+        .store(
+            &ctx,
+            &AgentSession::new(
+                pinned,
+                "legacy_model",
+                SequenceNumber::new(1),
+                &DefaultClock,
+            ),
+        )
+        .await?;
+    let mut ended = AgentSession::new(moved, "legacy_model", SequenceNumber::new(1), &DefaultClock);
+    ended.complete(SequenceNumber::new(4), &DefaultClock);
+    sessions.store(&ctx, &ended).await?;
+    for record in [
+        usage(pinned, "legacy_model", 250_000),
+        usage(pinned, "successor_model", 900_000),
+        usage(moved, "legacy_model", 900_000),
+    ] {
+        ledger.record_usage(&ctx, &record).await?;
+    }
+
+    let active = sessions
+        .find_active_by_backend(&ctx, "legacy_model", PageRequest::default())
+        .await?;
+    assert_eq!(
+        active
+            .iter()
+            .map(|session| session.conversation_id)
+            .collect::<Vec<_>>(),
+        vec![pinned]
+    );
+
+    let rolled_up = ledger
+        .backend_usage(&ctx, "legacy_model", &[pinned])
+        .await?;
+    assert_eq!(rolled_up.turn_count, 1);
+    assert_eq!(rolled_up.usage, TokenUsage::new(1_000, 100));
+    assert_eq!(rolled_up.cost, CostMicros::new(250_000));
+
+    let nothing = ledger.backend_usage(&ctx, "legacy_model", &[]).await?;
+    assert_eq!(nothing.turn_count, 0);
+    Ok(())
+}
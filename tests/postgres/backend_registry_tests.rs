@@ -7,6 +7,7 @@ use corbusier::agent_backend::{
     services::{BackendRegistryService, BackendRegistryServiceError, RegisterBackendRequest},
 };
 use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
 use diesel::PgConnection;
 use diesel::r2d2::ConnectionManager;
 use mockable::DefaultClock;
@@ -169,7 +170,7 @@ async fn postgres_list_active_excludes_inactive(
 
     let active = bctx
         .service
-        .list_active(&req_ctx)
+        .list_active(&req_ctx, PageRequest::default())
         .await
         .expect("listing should succeed");
     assert_eq!(active.len(), 1);
@@ -204,7 +205,7 @@ async fn postgres_list_all_includes_inactive(
 
     let all = bctx
         .service
-        .list_all(&req_ctx)
+        .list_all(&req_ctx, PageRequest::default())
         .await
         .expect("listing should succeed");
     assert_eq!(all.len(), 2);
@@ -244,12 +245,12 @@ async fn postgres_scopes_backends_per_tenant(
 
     let list_a = bctx
         .service
-        .list_all(&tenant_a_ctx)
+        .list_all(&tenant_a_ctx, PageRequest::default())
         .await
         .expect("tenant A list should succeed");
     let list_b = bctx
         .service
-        .list_all(&tenant_b_ctx)
+        .list_all(&tenant_b_ctx, PageRequest::default())
         .await
         .expect("tenant B list should succeed");
 
@@ -307,7 +308,19 @@ async fn postgres_duplicate_name_is_scoped_per_tenant(
 
     assert_eq!(found_a.id(), backend_a.id());
     assert_eq!(found_b.id(), backend_b.id());
-    assert_eq!(bctx.service.list_all(&tenant_a).await?.len(), 1);
-    assert_eq!(bctx.service.list_all(&tenant_b).await?.len(), 1);
+    assert_eq!(
+        bctx.service
+            .list_all(&tenant_a, PageRequest::default())
+            .await?
+            .len(),
+        1
+    );
+    assert_eq!(
+        bctx.service
+            .list_all(&tenant_b, PageRequest::default())
+            .await?
+            .len(),
+        1
+    );
     Ok(())
 }
@@ -0,0 +1,95 @@
+//! Batch message insertion tests for `PostgreSQL` message repository.
+
+use crate::postgres::helpers::{
+    BoxError, PostgresCluster, clock, create_test_message, ensure_template, insert_conversation,
+    postgres_cluster, setup_repository, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    domain::{ConversationId, Message, SequenceNumber},
+    error::{BatchConflict, RepositoryError},
+    ports::repository::MessageRepository,
+};
+use corbusier::pagination::PageRequest;
+use mockable::DefaultClock;
+use rstest::rstest;
+
+#[rstest]
+#[tokio::test]
+async fn store_batch_inserts_messages_across_conversations(
+    clock: DefaultClock,
+    test_request_context: RequestContext,
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+) -> Result<(), BoxError> {
+    let cluster = postgres_cluster?;
+    ensure_template(cluster).await?;
+    let (temp_db, repo) = setup_repository(cluster).await?;
+    let ctx = test_request_context;
+
+    let conv1 = ConversationId::new();
+    let conv2 = ConversationId::new();
+    insert_conversation(cluster, temp_db.name(), conv1, &ctx).await?;
+    insert_conversation(cluster, temp_db.name(), conv2, &ctx).await?;
+    let batch = vec![
+        create_test_message(&clock, conv1, 1)?,
+        create_test_message(&clock, conv1, 2)?,
+        create_test_message(&clock, conv2, 1)?,
+    ];
+
+    repo.store_batch(&ctx, &batch).await?;
+
+    let history = repo
+        .find_by_conversation(&ctx, conv1, PageRequest::default())
+        .await?;
+    let ids: Vec<_> = history.iter().map(Message::id).collect();
+    assert_eq!(
+        ids,
+        batch.iter().take(2).map(Message::id).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        repo.next_sequence_number(&ctx, conv2).await?,
+        SequenceNumber::new(2)
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn store_batch_reports_duplicates_and_stores_nothing(
+    clock: DefaultClock,
+    test_request_context: RequestContext,
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+) -> Result<(), BoxError> {
+    let cluster = postgres_cluster?;
+    ensure_template(cluster).await?;
+    let (temp_db, repo) = setup_repository(cluster).await?;
+    let ctx = test_request_context;
+
+    let conv_id = ConversationId::new();
+    insert_conversation(cluster, temp_db.name(), conv_id, &ctx).await?;
+    let stored = create_test_message(&clock, conv_id, 1)?;
+    repo.store(&ctx, &stored).await?;
+    let fresh = create_test_message(&clock, conv_id, 2)?;
+    let clashing = create_test_message(&clock, conv_id, 1)?;
+
+    let result = repo
+        .store_batch(&ctx, &[fresh.clone(), clashing.clone()])
+        .await;
+
+    assert!(
+        matches!(
+            &result,
+            Err(RepositoryError::BatchConflicts(conflicts)) if conflicts.as_slice() == [
+                BatchConflict::DuplicateSequence {
+                    index: 1,
+                    conversation_id: conv_id,
+                    sequence: SequenceNumber::new(1),
+                },
+            ]
+        ),
+        "Expected BatchConflicts error, got: {result:?}"
+    );
+    assert!(!repo.exists(&ctx, fresh.id()).await?);
+    assert!(!repo.exists(&ctx, clashing.id()).await?);
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! `PostgreSQL` integration tests for compaction snapshots.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, clock, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::{PostgresAgentSessionRepository, PostgresContextSnapshotAdapter},
+    domain::{
+        AgentSession, ContextWindowSnapshot, ConversationId, MessageSummary, SequenceNumber,
+        SequenceRange, SnapshotParams, SnapshotType,
+    },
+    ports::{agent_session::AgentSessionRepository, context_snapshot::ContextSnapshotPort},
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+fn snapshot(
+    clock: &DefaultClock,
+    session: &AgentSession,
+    snapshot_type: SnapshotType,
+    end: u64,
+) -> ContextWindowSnapshot {
+    ContextWindowSnapshot::new(
+        SnapshotParams {
+            conversation_id: session.conversation_id,
+            session_id: session.session_id,
+            sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(end)),
+            message_summary: MessageSummary::new(end.try_into().unwrap_or(u32::MAX), 0, 0, 0),
+            snapshot_type,
+        },
+        clock,
+    )
+}
+
+#[rstest]
+#[tokio::test]
+async fn postgres_latest_compaction_round_trips_its_summary(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let session = AgentSession::new(conversation_id, "claude", SequenceNumber::new(1), &clock);
+    PostgresAgentSessionRepository::new(pool.clone())
+        .store(&ctx, &session)
+        .await?;
+    let snapshots = PostgresContextSnapshotAdapter::new(pool);
+
+    let first = snapshot(&clock, &session, SnapshotType::Compaction, 4).with_summary("early");
+    snapshots.store_snapshot(&ctx, &first).await?;
+    let latest = snapshot(&clock, &session, SnapshotType::Compaction, 8)
+        .with_summary("early; later")
+        .with_token_estimate(3);
+    snapshots.store_snapshot(&ctx, &latest).await?;
+    let checkpoint = snapshot(&clock, &session, SnapshotType::Checkpoint, 10);
+    snapshots.store_snapshot(&ctx, &checkpoint).await?;
+
+    let found = snapshots
+        .find_latest_compaction(&ctx, conversation_id)
+        .await?
+        .ok_or("latest compaction")?;
+
+    assert_eq!(found.snapshot_id, latest.snapshot_id);
+    assert_eq!(found.sequence_range, latest.sequence_range);
+    assert_eq!(found.summary.as_deref(), Some("early; later"));
+    assert_eq!(found.token_estimate, Some(3));
+    assert!(found.is_compaction());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn postgres_rejects_compactions_without_a_summary(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let session = AgentSession::new(conversation_id, "claude", SequenceNumber::new(1), &clock);
+    PostgresAgentSessionRepository::new(pool.clone())
+        .store(&ctx, &session)
+        .await?;
+    let snapshots = PostgresContextSnapshotAdapter::new(pool);
+
+    let unsummarised = snapshot(&clock, &session, SnapshotType::Compaction, 4);
+    let result = snapshots.store_snapshot(&ctx, &unsummarised).await;
+
+    assert!(result.is_err(), "compaction without a summary was stored");
+    assert!(
+        snapshots
+            .find_latest_compaction(&ctx, conversation_id)
+            .await?
+            .is_none()
+    );
+    Ok(())
+}
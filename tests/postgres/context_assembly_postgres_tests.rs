@@ -0,0 +1,80 @@
+//! `PostgreSQL` integration tests for context assembly report persistence.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use chrono::{Duration, Utc};
+use corbusier::agent_backend::{
+    adapters::postgres::PostgresContextAssemblyReportRepository,
+    domain::{
+        BackendId, ContextAssemblyReport, MemoryFactId, MemoryRecallDecision, MemoryRecallVerdict,
+    },
+    ports::ContextAssemblyReportRepository,
+};
+use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
+use rstest::rstest;
+use uuid::Uuid;
+
+fn decision(content: &str, verdict: MemoryRecallVerdict) -> MemoryRecallDecision {
+    MemoryRecallDecision {
+        fact_id: MemoryFactId::new(),
+        content: content.to_owned(),
+        relevance_percent: Some(71),
+        confidence_percent: 45,
+        verdict,
+    }
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_context_assembly_reports_round_trip_per_conversation(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repository =
+        PostgresContextAssemblyReportRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let conversation_id = Uuid::new_v4();
+    let assembled_at = Utc::now();
+    let first = ContextAssemblyReport {
+        backend_id: BackendId::new(),
+        conversation_id,
+        recall_limit: 5,
+        memories: Vec::new(),
+        assembled_at,
+    };
+    let second = ContextAssemblyReport {
+        memories: vec![
+            decision(
+                "The team writes Rust with tabs",
+                MemoryRecallVerdict::Included,
+            ),
+            MemoryRecallDecision {
+                relevance_percent: None,
+                ..decision("Rust nightly is pinned", MemoryRecallVerdict::Expired)
+            },
+        ],
+        assembled_at: assembled_at + Duration::seconds(1),
+        ..first.clone()
+    };
+    let elsewhere = ContextAssemblyReport {
+        conversation_id: Uuid::new_v4(),
+        ..first.clone()
+    };
+    for report in [&second, &first, &elsewhere] {
+        repository.record(&ctx, report).await?;
+    }
+
+    let stored = repository
+        .list_for_conversation(&ctx, conversation_id, PageRequest::default())
+        .await?;
+
+    assert_eq!(stored.len(), 2);
+    assert_eq!(stored.first().map(|report| report.recall_limit), Some(5));
+    assert_eq!(
+        stored.get(1).map(|report| report.memories.clone()),
+        Some(second.memories)
+    );
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! `PostgreSQL` integration tests for archived conversation write protection.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::{
+        PostgresAgentSessionRepository, PostgresConversationRepository, PostgresMessageRepository,
+    },
+    domain::{
+        AgentSession, ContentPart, Conversation, ConversationAccess, Message, Role, SequenceNumber,
+        TextPart,
+    },
+    error::RepositoryError,
+    ports::{
+        AgentSessionRepository, ConversationRepository, MessageRepository,
+        agent_session::SessionError,
+    },
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+fn message(conversation: &Conversation, sequence: u64) -> Result<Message, BoxError> {
+    Ok(Message::new(
+        conversation.id(),
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("hello"))],
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )?)
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_rejects_writes_to_archived_conversations(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let conversations = PostgresConversationRepository::new(pool.clone());
+    let messages = PostgresMessageRepository::new(pool.clone());
+    let sessions = PostgresAgentSessionRepository::new(pool);
+    let mut conversation = Conversation::new(&DefaultClock);
+    conversations.store(&ctx, &conversation).await?;
+    conversation.archive(&DefaultClock)?;
+    conversations.update(&ctx, &conversation).await?;
+
+    let refused = messages.store(&ctx, &message(&conversation, 1)?).await;
+    let session = AgentSession::new(
+        conversation.id(),
+        "codex_cli",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+    let refused_session = sessions.store(&ctx, &session).await;
+    let mut persisted = conversations
+        .find_by_id(&ctx, conversation.id())
+        .await?
+        .ok_or("archived conversation should still exist")?;
+
+    assert!(matches!(
+        refused,
+        Err(RepositoryError::ConversationArchived(id)) if id == conversation.id()
+    ));
+    assert!(matches!(
+        refused_session,
+        Err(SessionError::ConversationArchived(id)) if id == conversation.id()
+    ));
+    assert!(persisted.is_archived());
+
+    persisted.unarchive(ConversationAccess::Elevated, &DefaultClock)?;
+    conversations.update(&ctx, &persisted).await?;
+    messages.store(&ctx, &message(&conversation, 1)?).await?;
+    Ok(())
+}
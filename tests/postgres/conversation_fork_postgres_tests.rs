@@ -0,0 +1,183 @@
+//! `PostgreSQL` integration tests for conversation forks.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use corbusier::context::{RequestContext, TenantId};
+use corbusier::message::{
+    adapters::postgres::{
+        PostgresAgentSessionRepository, PostgresContextSnapshotAdapter,
+        PostgresConversationForkRepository, PostgresConversationRepository,
+        PostgresMessageRepository,
+    },
+    domain::{
+        AgentSession, ContentPart, ContextWindowSnapshot, Conversation, ConversationForkRequest,
+        Message, MessageSummary, Role, SequenceNumber, SequenceRange, SnapshotParams, SnapshotType,
+        TextPart,
+    },
+    ports::{
+        AgentSessionRepository, ContextSnapshotPort, ConversationForkError, ConversationRepository,
+        MessageRepository,
+    },
+    services::ConversationForkService,
+};
+use corbusier::pagination::PageRequest;
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+use std::sync::Arc;
+
+fn message(conversation: &Conversation, sequence: u64) -> Result<Message, BoxError> {
+    Ok(Message::new(
+        conversation.id(),
+        Role::User,
+        vec![ContentPart::Text(TextPart::new(format!("step {sequence}")))],
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )?)
+}
+
+fn request(conversation: &Conversation, forked_at: u64) -> ConversationForkRequest {
+    ConversationForkRequest {
+        parent_conversation_id: conversation.id(),
+        forked_at: SequenceNumber::new(forked_at),
+    }
+}
+
+fn checkpoint(session: &AgentSession, end: u64) -> ContextWindowSnapshot {
+    ContextWindowSnapshot::new(
+        SnapshotParams {
+            conversation_id: session.conversation_id,
+            session_id: session.session_id,
+            sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(end)),
+            message_summary: MessageSummary::new(end.try_into().unwrap_or(u32::MAX), 0, 0, 0),
+            snapshot_type: SnapshotType::Checkpoint,
+        },
+        &DefaultClock,
+    )
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_forks_copy_history_and_record_provenance(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let conversations = PostgresConversationRepository::new(pool.clone());
+    let messages = PostgresMessageRepository::new(pool.clone());
+    let snapshots = PostgresContextSnapshotAdapter::new(pool.clone());
+    let service = ConversationForkService::new(
+        Arc::new(PostgresConversationForkRepository::new(pool.clone())),
+        Arc::new(DefaultClock),
+    );
+    let parent = Conversation::new(&DefaultClock).with_context(json!({ "goal": "fix CI" }));
+    conversations.store(&ctx, &parent).await?;
+    for sequence in 1..=4 {
+        messages.store(&ctx, &message(&parent, sequence)?).await?;
+    }
+    let session = AgentSession::new(parent.id(), "claude", SequenceNumber::new(1), &DefaultClock);
+    PostgresAgentSessionRepository::new(pool)
+        .store(&ctx, &session)
+        .await?;
+    let linked = checkpoint(&session, 2);
+    snapshots.store_snapshot(&ctx, &linked).await?;
+    snapshots
+        .store_snapshot(&ctx, &checkpoint(&session, 4))
+        .await?;
+
+    let fork = service.fork(&ctx, request(&parent, 3)).await?;
+
+    assert_eq!(fork.parent_snapshot_id, Some(linked.snapshot_id));
+    let copied = messages
+        .find_by_conversation(&ctx, fork.conversation_id, PageRequest::default())
+        .await?;
+    let sequences: Vec<u64> = copied
+        .items()
+        .iter()
+        .map(|copy| copy.sequence_number().value())
+        .collect();
+    assert_eq!(sequences, [1, 2, 3]);
+    let originals = messages
+        .find_by_conversation(&ctx, parent.id(), PageRequest::default())
+        .await?;
+    for (copy, original) in copied.items().iter().zip(originals.items()) {
+        assert_ne!(copy.id(), original.id());
+        assert_eq!(copy.content(), original.content());
+    }
+    assert_eq!(
+        messages
+            .next_sequence_number(&ctx, fork.conversation_id)
+            .await?,
+        SequenceNumber::new(4)
+    );
+    let forked = conversations
+        .find_by_id(&ctx, fork.conversation_id)
+        .await?
+        .ok_or("fork conversation")?;
+    assert_eq!(forked.context(), parent.context());
+    assert_eq!(
+        service
+            .provenance(&ctx, fork.conversation_id)
+            .await?
+            .map(|stored| (stored.parent_conversation_id, stored.forked_at)),
+        Some((parent.id(), SequenceNumber::new(3)))
+    );
+    assert_eq!(
+        service
+            .forks(&ctx, parent.id(), PageRequest::default())
+            .await?
+            .iter()
+            .map(|stored| stored.conversation_id)
+            .collect::<Vec<_>>(),
+        vec![fork.conversation_id]
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_forks_are_refused_without_parent_or_fork_point(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let conversations = PostgresConversationRepository::new(pool.clone());
+    let service = ConversationForkService::new(
+        Arc::new(PostgresConversationForkRepository::new(pool.clone())),
+        Arc::new(DefaultClock),
+    );
+    let parent = Conversation::new(&DefaultClock);
+    conversations.store(&ctx, &parent).await?;
+    PostgresMessageRepository::new(pool)
+        .store(&ctx, &message(&parent, 1)?)
+        .await?;
+    let other_tenant = RequestContext::new(
+        TenantId::new(),
+        ctx.correlation_id(),
+        ctx.user_id(),
+        ctx.session_id(),
+    );
+
+    let past_end = service.fork(&ctx, request(&parent, 2)).await;
+    let foreign = service.fork(&other_tenant, request(&parent, 1)).await;
+
+    assert!(matches!(
+        past_end,
+        Err(ConversationForkError::ForkPointNotFound { .. })
+    ));
+    assert!(matches!(
+        foreign,
+        Err(ConversationForkError::ParentNotFound(id)) if id == parent.id()
+    ));
+    assert!(
+        service
+            .forks(&ctx, parent.id(), PageRequest::default())
+            .await?
+            .is_empty()
+    );
+    Ok(())
+}
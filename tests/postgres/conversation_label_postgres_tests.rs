@@ -0,0 +1,211 @@
+//! `PostgreSQL` integration tests for conversation labels.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use corbusier::context::{RequestContext, TenantId};
+use corbusier::message::{
+    adapters::{
+        postgres::{PgPool, PostgresConversationRepository, PostgresMessageRepository},
+        schema::conversations,
+    },
+    domain::{Conversation, ConversationId, ConversationLabel, ConversationLabelEvent},
+    ports::{ConversationRepository, ConversationRepositoryError},
+    services::{ConversationService, ConversationServiceError},
+    validation::service::DefaultMessageValidator,
+};
+use corbusier::pagination::{Limit, PageRequest};
+use diesel::prelude::*;
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+type PostgresConversationService = ConversationService<
+    PostgresConversationRepository,
+    PostgresMessageRepository,
+    DefaultMessageValidator,
+    DefaultClock,
+>;
+
+fn label(key: &str, value: &str) -> Result<ConversationLabel, BoxError> {
+    Ok(ConversationLabel::new(key, value)?)
+}
+
+fn service(pool: &PgPool) -> PostgresConversationService {
+    ConversationService::new(
+        Arc::new(PostgresConversationRepository::new(pool.clone())),
+        Arc::new(PostgresMessageRepository::new(pool.clone())),
+        Arc::new(DefaultMessageValidator::new()),
+        Arc::new(DefaultClock),
+    )
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_labels_round_trip_and_are_queried_by_containment(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let service = service(&pool);
+    let apollo = service.create_conversation(&ctx).await?;
+    let gemini = service.create_conversation(&ctx).await?;
+    service
+        .set_label(&ctx, apollo.id(), label("project", "apollo")?)
+        .await?;
+    service
+        .set_label(&ctx, apollo.id(), label("customer", "acme")?)
+        .await?;
+    service
+        .set_label(&ctx, gemini.id(), label("project", "gemini")?)
+        .await?;
+
+    let stored = PostgresConversationRepository::new(pool.clone())
+        .find_by_id(&ctx, apollo.id())
+        .await?
+        .ok_or("conversation")?;
+    assert_eq!(stored.label("project"), Some("apollo"));
+    assert_eq!(stored.label("customer"), Some("acme"));
+    let column = {
+        let mut conn = pool.get()?;
+        conversations::table
+            .filter(conversations::id.eq(apollo.id().into_inner()))
+            .select(conversations::labels)
+            .first::<Value>(&mut conn)?
+    };
+    assert_eq!(column, json!({"customer": "acme", "project": "apollo"}));
+
+    let found = service
+        .find_by_label(&ctx, &label("project", "apollo")?, PageRequest::default())
+        .await?;
+    assert_eq!(
+        found
+            .items()
+            .iter()
+            .map(Conversation::id)
+            .collect::<Vec<_>>(),
+        vec![apollo.id()]
+    );
+    let single = service
+        .find_by_label(
+            &ctx,
+            &label("project", "gemini")?,
+            PageRequest::new(Limit::new(1)?),
+        )
+        .await?;
+    assert_eq!(single.items().len(), 1);
+    assert!(single.is_last());
+    let other_tenant = RequestContext::new(
+        TenantId::new(),
+        ctx.correlation_id(),
+        ctx.user_id(),
+        ctx.session_id(),
+    );
+    let foreign = service
+        .find_by_label(
+            &other_tenant,
+            &label("project", "apollo")?,
+            PageRequest::default(),
+        )
+        .await?;
+    assert!(foreign.items().is_empty());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_label_changes_are_audited_with_the_update(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let service = service(&build_pool(prep.temp_db.url(), 1)?);
+    let conversation = service.create_conversation(&ctx).await?;
+
+    service
+        .set_label(&ctx, conversation.id(), label("experiment", "a")?)
+        .await?;
+    service
+        .set_label(&ctx, conversation.id(), label("experiment", "b")?)
+        .await?;
+    service
+        .remove_label(&ctx, conversation.id(), "experiment")
+        .await?;
+
+    let history = service
+        .label_history(&ctx, conversation.id(), PageRequest::default())
+        .await?;
+    let changes = history
+        .items()
+        .iter()
+        .map(|event| {
+            (
+                event.change.key.as_str(),
+                event.change.previous.as_deref(),
+                event.change.value.as_deref(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changes,
+        vec![
+            ("experiment", None, Some("a")),
+            ("experiment", Some("a"), Some("b")),
+            ("experiment", Some("b"), None),
+        ]
+    );
+    assert!(
+        history
+            .items()
+            .iter()
+            .all(|event| event.actor == ctx.user_id())
+    );
+    let missing = service
+        .label_history(&ctx, ConversationId::new(), PageRequest::default())
+        .await;
+    assert!(matches!(
+        missing,
+        Err(ConversationServiceError::ConversationNotFound(_))
+    ));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_stale_label_changes_are_not_audited(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let service = service(&pool);
+    let repository = PostgresConversationRepository::new(pool);
+    let conversation = service.create_conversation(&ctx).await?;
+    let mut stale = repository
+        .find_by_id(&ctx, conversation.id())
+        .await?
+        .ok_or("conversation")?;
+    service
+        .set_label(&ctx, conversation.id(), label("project", "apollo")?)
+        .await?;
+
+    let change = stale
+        .set_label(label("project", "gemini")?, &DefaultClock)
+        .ok_or("label change")?;
+    let event = ConversationLabelEvent::new(&ctx, change, &DefaultClock);
+    let outcome = repository.update_labels(&ctx, &stale, &event).await;
+
+    assert!(matches!(
+        outcome,
+        Err(ConversationRepositoryError::ConcurrentModification { .. })
+    ));
+    let history = repository
+        .find_label_events(&ctx, conversation.id(), PageRequest::default())
+        .await?;
+    assert_eq!(history.items().len(), 1);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! `PostgreSQL` integration tests for conversation state and context.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::PostgresConversationRepository,
+    domain::{Conversation, ConversationState},
+    ports::ConversationRepository,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_round_trips_conversation_state_and_context(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversations = PostgresConversationRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let mut conversation =
+        Conversation::new(&DefaultClock).with_context(json!({ "goal": "fix the build" }));
+    conversations.store(&ctx, &conversation).await?;
+
+    conversation.pause(&DefaultClock)?;
+    conversation.complete(&DefaultClock)?;
+    conversation.replace_context(
+        json!({ "goal": "fix the build", "done": true }),
+        &DefaultClock,
+    );
+    conversations.update(&ctx, &conversation).await?;
+
+    let persisted = conversations
+        .find_by_id(&ctx, conversation.id())
+        .await?
+        .ok_or("conversation should exist")?;
+    assert_eq!(persisted.state(), ConversationState::Completed);
+    assert_eq!(
+        persisted.context(),
+        &json!({ "goal": "fix the build", "done": true })
+    );
+    assert_eq!(persisted.task_id(), None);
+    Ok(())
+}
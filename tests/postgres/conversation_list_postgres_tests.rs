@@ -0,0 +1,107 @@
+//! `PostgreSQL` integration tests for conversation listings over the summary
+//! projection.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use chrono::Utc;
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::PostgresConversationListAdapter,
+    domain::{
+        ContentPart, ConversationId, ConversationListFilter, ConversationListQuery, Message,
+        MessageId, MessageMetadata, Role, SequenceNumber, TextPart,
+    },
+    ports::{ConversationListPort, MessageRepository},
+};
+use corbusier::pagination::Limit;
+use corbusier::task::{
+    adapters::postgres::{PostgresTaskCostLedger, PostgresTaskRepository},
+    services::{CreateTaskFromIssueRequest, TaskCostService, TaskLifecycleService},
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_listing_filters_and_pages_projected_summaries(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let clock = Arc::new(DefaultClock);
+    let tasks = TaskLifecycleService::new(
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+        clock.clone(),
+    );
+    let costs = TaskCostService::new(Arc::new(PostgresTaskCostLedger::new(pool.clone())), clock);
+    let listing = PostgresConversationListAdapter::new(pool);
+
+    let task = tasks
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "corbusier/core", 1735, "Listing")
+                .with_labels(["bug".to_owned()]),
+        )
+        .await?;
+    let linked = ConversationId::new();
+    let unlinked = ConversationId::new();
+    for conversation_id in [unlinked, linked] {
+        insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    }
+    costs
+        .link_primary_conversation(&ctx, task.id(), linked)
+        .await?;
+    let reply = Message::from_persisted(
+        MessageId::new(),
+        linked,
+        Role::Assistant,
+        vec![ContentPart::Text(TextPart::new("Listed"))],
+        MessageMetadata::with_agent_backend("codex_cli"),
+        Utc::now(),
+        SequenceNumber::new(1),
+    )?;
+    prep.repo.store(&ctx, &reply).await?;
+
+    let limit = |value| Limit::new(value).expect("valid limit");
+    let labelled = listing
+        .list_conversations(
+            &ctx,
+            &ConversationListQuery::new(limit(10)).with_filter(ConversationListFilter {
+                label: Some("bug".to_owned()),
+                ..ConversationListFilter::default()
+            }),
+        )
+        .await?;
+    let summary = labelled.first().expect("labelled conversation");
+    assert_eq!(labelled.len(), 1);
+    assert_eq!(summary.conversation_id, linked);
+    assert_eq!(summary.task_id, Some(task.id().into_inner()));
+    assert_eq!(summary.agent_backend.as_deref(), Some("codex_cli"));
+    assert_eq!(summary.message_count, 1);
+
+    let first_page = listing
+        .list_conversations(&ctx, &ConversationListQuery::new(limit(1)))
+        .await?;
+    let cursor = first_page.next_cursor().expect("second page follows");
+    let second_page = listing
+        .list_conversations(
+            &ctx,
+            &ConversationListQuery::new(limit(1)).with_cursor(cursor),
+        )
+        .await?;
+    assert_eq!(
+        first_page
+            .iter()
+            .chain(&second_page)
+            .map(|listed| listed.conversation_id)
+            .collect::<Vec<_>>(),
+        vec![linked, unlinked]
+    );
+    assert!(second_page.is_last());
+    Ok(())
+}
@@ -0,0 +1,278 @@
+//! `PostgreSQL` integration tests for moving conversations between tenants.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use corbusier::context::{RequestContext, TenantId};
+use corbusier::message::{
+    adapters::{
+        blob_store::ObjectStoreBlobStore,
+        externalising::ExternalisingMessageRepository,
+        postgres::{
+            PostgresAgentSessionRepository, PostgresConversationRepository,
+            PostgresConversationTransferRepository, PostgresMessageRepository,
+        },
+    },
+    domain::{
+        AgentSession, AttachmentPart, ContentHash, ContentPart, Conversation, ConversationAccess,
+        ConversationTransferRequest, Message, Role, SequenceNumber, TextPart,
+    },
+    ports::{
+        AgentSessionRepository, BlobStore, ConversationRepository, ConversationTransferError,
+        MessageRepository,
+    },
+    services::{ConversationTransferService, ConversationTransferServiceError},
+};
+use corbusier::operator::{
+    adapters::PostgresOperatorActionRepository,
+    domain::{OperatorActionKind, OperatorActionQuery, OperatorReason},
+    ports::OperatorActionRepository,
+};
+use corbusier::pagination::PageRequest;
+use diesel::prelude::*;
+use diesel::sql_types::Text;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+/// Tables with a `conversation_id` that deliberately stay behind, and why.
+const NOT_TRANSFERRED: &[&str] = &[
+    // Conversations linked to tasks or budgets cannot be transferred.
+    "conversation_budgets",
+    "task_conversation_links",
+    // Each tenant keeps its own record of the transfer.
+    "operator_actions",
+    // Tied to the source tenant's backend registrations and hook runs.
+    "agent_turn_sessions",
+    "hook_policy_audit_events",
+];
+
+#[derive(QueryableByName)]
+struct TableName {
+    #[diesel(sql_type = Text)]
+    table_name: String,
+}
+
+fn in_tenant(ctx: &RequestContext, tenant_id: TenantId) -> RequestContext {
+    RequestContext::new(
+        tenant_id,
+        ctx.correlation_id(),
+        ctx.user_id(),
+        ctx.session_id(),
+    )
+}
+
+fn request(
+    conversation: &Conversation,
+    target: &RequestContext,
+) -> Result<ConversationTransferRequest, BoxError> {
+    Ok(ConversationTransferRequest {
+        conversation_id: conversation.id(),
+        target_tenant_id: target.tenant_id(),
+        reason: OperatorReason::new("customer account merge")?,
+    })
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_transfers_move_conversation_rows_and_audit_both_tenants(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let target = in_tenant(&ctx, TenantId::new());
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let conversations = PostgresConversationRepository::new(pool.clone());
+    let messages = PostgresMessageRepository::new(pool.clone());
+    let sessions = PostgresAgentSessionRepository::new(pool.clone());
+    let audit = PostgresOperatorActionRepository::new(pool.clone());
+    let service = ConversationTransferService::new(
+        Arc::new(PostgresConversationTransferRepository::new(pool)),
+        Arc::new(DefaultClock),
+    );
+    let conversation = Conversation::new(&DefaultClock);
+    conversations.store(&ctx, &conversation).await?;
+    let message = Message::new(
+        conversation.id(),
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("hello"))],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )?;
+    messages.store(&ctx, &message).await?;
+    let session = AgentSession::new(
+        conversation.id(),
+        "codex_cli",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+    sessions.store(&ctx, &session).await?;
+
+    service
+        .transfer(
+            &ctx,
+            request(&conversation, &target)?,
+            ConversationAccess::Elevated,
+        )
+        .await?;
+
+    assert!(
+        conversations
+            .find_by_id(&ctx, conversation.id())
+            .await?
+            .is_none()
+    );
+    assert!(messages.find_by_id(&ctx, message.id()).await?.is_none());
+    assert!(
+        conversations
+            .find_by_id(&target, conversation.id())
+            .await?
+            .is_some()
+    );
+    assert_eq!(
+        messages
+            .find_by_id(&target, message.id())
+            .await?
+            .map(|moved| moved.id()),
+        Some(message.id())
+    );
+    assert_eq!(
+        sessions
+            .find_by_id(&target, session.session_id)
+            .await?
+            .map(|moved| moved.session_id),
+        Some(session.session_id)
+    );
+    for (tenant_ctx, kind) in [
+        (&ctx, OperatorActionKind::ConversationTransferredOut),
+        (&target, OperatorActionKind::ConversationTransferredIn),
+    ] {
+        let timeline = audit
+            .query(
+                tenant_ctx,
+                OperatorActionQuery::for_conversation(conversation.id()),
+                PageRequest::default(),
+            )
+            .await?;
+        let kinds: Vec<_> = timeline.iter().map(|action| action.kind).collect();
+        assert_eq!(kinds, [kind]);
+    }
+
+    let repeated = service
+        .transfer(
+            &ctx,
+            request(&conversation, &target)?,
+            ConversationAccess::Elevated,
+        )
+        .await;
+    assert!(matches!(
+        repeated,
+        Err(ConversationTransferServiceError::Repository(
+            ConversationTransferError::NotFound(id)
+        )) if id == conversation.id()
+    ));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_transfers_move_attachment_blobs_to_the_target_tenant(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let target = in_tenant(&ctx, TenantId::new());
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let blobs = Arc::new(ObjectStoreBlobStore::in_memory());
+    let conversations = PostgresConversationRepository::new(pool.clone());
+    let messages = ExternalisingMessageRepository::new(
+        Arc::new(PostgresMessageRepository::new(pool.clone())),
+        Arc::clone(&blobs) as Arc<dyn BlobStore>,
+        1024,
+    );
+    let service = ConversationTransferService::new(
+        Arc::new(
+            PostgresConversationTransferRepository::new(pool)
+                .with_blob_store(Arc::clone(&blobs) as Arc<dyn BlobStore>),
+        ),
+        Arc::new(DefaultClock),
+    );
+    let data = "iVBORw0KGgo".repeat(500);
+    let hash = ContentHash::of(data.as_bytes());
+    let (moved, kept) = (
+        Conversation::new(&DefaultClock),
+        Conversation::new(&DefaultClock),
+    );
+    let mut stored = Vec::new();
+    for conversation in [&moved, &kept] {
+        conversations.store(&ctx, conversation).await?;
+        let message = Message::new(
+            conversation.id(),
+            Role::User,
+            vec![ContentPart::Attachment(AttachmentPart::new(
+                "image/png",
+                data.as_str(),
+            ))],
+            SequenceNumber::new(1),
+            &DefaultClock,
+        )?;
+        messages.store(&ctx, &message).await?;
+        stored.push(message);
+    }
+
+    service
+        .transfer(
+            &ctx,
+            request(&moved, &target)?,
+            ConversationAccess::Elevated,
+        )
+        .await?;
+
+    let [moved_message, _] = stored.as_slice() else {
+        return Err("expected two messages".into());
+    };
+    let found = messages
+        .find_by_id(&target, moved_message.id())
+        .await?
+        .ok_or("moved message")?;
+    assert_eq!(found.content(), moved_message.content());
+    assert!(blobs.get(&ctx, &hash).await?.is_some());
+
+    service
+        .transfer(&ctx, request(&kept, &target)?, ConversationAccess::Elevated)
+        .await?;
+
+    assert!(blobs.get(&ctx, &hash).await?.is_none());
+    assert!(blobs.get(&target, &hash).await?.is_some());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_transfers_move_every_table_keyed_by_conversation(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let mut conn = pool.get()?;
+    let keyed = diesel::sql_query(concat!(
+        "SELECT c.table_name::TEXT AS table_name FROM information_schema.columns c ",
+        "JOIN information_schema.tables t USING (table_schema, table_name) ",
+        "WHERE c.table_schema = current_schema() AND c.column_name = 'conversation_id' ",
+        "AND t.table_type = 'BASE TABLE'",
+    ))
+    .load::<TableName>(&mut conn)?
+    .into_iter()
+    .map(|row| row.table_name)
+    .collect::<BTreeSet<_>>();
+
+    let accounted = PostgresConversationTransferRepository::CONVERSATION_TABLES
+        .iter()
+        .chain(NOT_TRANSFERRED)
+        .map(|table| (*table).to_owned())
+        .collect::<BTreeSet<_>>();
+    assert_eq!(keyed, accounted);
+    Ok(())
+}
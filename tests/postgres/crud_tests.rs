@@ -10,6 +10,7 @@ use corbusier::message::{
     domain::{ConversationId, MessageId, Role},
     ports::repository::MessageRepository,
 };
+use corbusier::pagination::PageRequest;
 use mockable::DefaultClock;
 use rstest::rstest;
 
@@ -79,7 +80,10 @@ async fn find_by_conversation_returns_ordered_messages(
     ctx.repo.store(&req_ctx, &msg1).await?;
     ctx.repo.store(&req_ctx, &msg2).await?;
 
-    let messages = ctx.repo.find_by_conversation(&req_ctx, conv_id).await?;
+    let messages = ctx
+        .repo
+        .find_by_conversation(&req_ctx, conv_id, PageRequest::default())
+        .await?;
 
     assert_eq!(messages.len(), 3);
     let sequence_numbers: Vec<_> = messages
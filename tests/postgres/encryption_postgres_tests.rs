@@ -0,0 +1,300 @@
+//! `PostgreSQL` integration tests for encrypted message content.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::{
+        encryption::AesGcmContentCipher,
+        postgres::{PostgresConversationForkRepository, PostgresPartialMessageRepository},
+    },
+    domain::{
+        ContentPart, ContentPartKind, ConversationForkRequest, ConversationId, EncryptionKeyId,
+        Message, MessageMetadata, MessageQuery, MessageRedaction, MessageTokenCount,
+        RedactionReason, Role, SequenceNumber, StreamChunk, TextPart, ToolResultPart,
+    },
+    ports::{repository::MessageRepository, streaming::PartialMessageRepository},
+    services::{ConversationForkService, StreamingMessageBuilder},
+};
+use corbusier::pagination::PageRequest;
+use diesel::prelude::*;
+use diesel::sql_types::{Int8, Text, Uuid as SqlUuid};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+const SECRET: &str = "my home address";
+
+#[derive(QueryableByName)]
+struct Count {
+    #[diesel(sql_type = Int8)]
+    count: i64,
+}
+
+fn cipher() -> Result<Arc<AesGcmContentCipher>, BoxError> {
+    Ok(Arc::new(AesGcmContentCipher::new(
+        EncryptionKeyId::new("content-2026-10")?,
+        [7; 32],
+    )))
+}
+
+fn message(conversation_id: ConversationId, sequence: u64) -> Result<Message, BoxError> {
+    Ok(Message::new(
+        conversation_id,
+        Role::User,
+        vec![ContentPart::Text(TextPart::new(SECRET))],
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )?)
+}
+
+/// Counts the rows `sql` finds with the secret in plaintext.
+fn plaintext_rows(prep: &PreparedRepo, sql: &'static str) -> Result<i64, BoxError> {
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let mut conn = pool.get()?;
+    Ok(diesel::sql_query(sql)
+        .bind::<Text, _>(format!("%{SECRET}%"))
+        .get_result::<Count>(&mut conn)?
+        .count)
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_encrypts_content_and_reads_it_back(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let repo = prep.repo.clone().with_cipher(cipher()?);
+    let stored = message(conversation_id, 1)?;
+
+    repo.store_with_audit(&ctx, &stored).await?;
+
+    let found = repo
+        .find_by_id(&ctx, stored.id())
+        .await?
+        .ok_or("stored message")?;
+    assert_eq!(found.content(), stored.content());
+    assert_eq!(
+        plaintext_rows(
+            &prep,
+            "SELECT count(*) AS count FROM messages WHERE content::text LIKE $1",
+        )?,
+        0
+    );
+    assert_eq!(
+        plaintext_rows(
+            &prep,
+            "SELECT count(*) AS count FROM audit_logs WHERE new_values::text LIKE $1",
+        )?,
+        0
+    );
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let key_ids = diesel::sql_query(concat!(
+        "SELECT count(*) AS count FROM messages ",
+        "WHERE id = $1 AND content->'$sealed'->>'key_id' = 'content-2026-10'",
+    ))
+    .bind::<SqlUuid, _>(stored.id().into_inner())
+    .get_result::<Count>(&mut pool.get()?)?;
+    assert_eq!(key_ids.count, 1);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_pins_and_redacts_encrypted_messages(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let repo = prep.repo.clone().with_cipher(cipher()?);
+    let pinned = message(conversation_id, 1)?;
+    let redacted = message(conversation_id, 2)?;
+    repo.store(&ctx, &pinned).await?;
+    repo.store(&ctx, &redacted).await?;
+
+    repo.set_pinned(&ctx, pinned.id(), true).await?;
+    let redaction = MessageRedaction::new(
+        redacted.id(),
+        RedactionReason::new("personal data")?,
+        ctx.user_id(),
+        &DefaultClock,
+    );
+    repo.redact(&ctx, &redaction).await?;
+
+    let found = repo.find_pinned(&ctx, conversation_id).await?;
+    assert_eq!(
+        found.iter().map(Message::id).collect::<Vec<_>>(),
+        vec![pinned.id()]
+    );
+    assert_eq!(found.first().map(Message::content), Some(pinned.content()));
+    let scrubbed = repo
+        .find_by_id(&ctx, redacted.id())
+        .await?
+        .ok_or("redacted message")?;
+    assert!(scrubbed.is_redacted());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_encrypted_rows_need_the_cipher_and_plain_rows_do_not(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let plain = &prep.repo;
+    let encrypted = prep.repo.clone().with_cipher(cipher()?);
+    let before = message(conversation_id, 1)?;
+    let after = message(conversation_id, 2)?;
+    plain.store(&ctx, &before).await?;
+    encrypted.store(&ctx, &after).await?;
+
+    assert!(plain.find_by_id(&ctx, after.id()).await.is_err());
+    let earlier = encrypted
+        .find_by_id(&ctx, before.id())
+        .await?
+        .ok_or("plain message")?;
+    assert_eq!(earlier.content(), before.content());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_forks_reseal_encrypted_messages_under_their_copy_ids(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let repo = prep.repo.clone().with_cipher(cipher()?);
+    let original = message(conversation_id, 1)?;
+    repo.store(&ctx, &original).await?;
+    let service = ConversationForkService::new(
+        Arc::new(
+            PostgresConversationForkRepository::new(build_pool(prep.temp_db.url(), 1)?)
+                .with_cipher(cipher()?),
+        ),
+        Arc::new(DefaultClock),
+    );
+
+    let fork = service
+        .fork(
+            &ctx,
+            ConversationForkRequest {
+                parent_conversation_id: conversation_id,
+                forked_at: SequenceNumber::new(1),
+            },
+        )
+        .await?;
+
+    let copied = repo
+        .find_by_conversation(&ctx, fork.conversation_id, PageRequest::default())
+        .await?;
+    let copy = copied.items().first().ok_or("copied message")?;
+    assert_ne!(copy.id(), original.id());
+    assert_eq!(copy.content(), original.content());
+    assert_eq!(
+        plaintext_rows(
+            &prep,
+            "SELECT count(*) AS count FROM messages WHERE content::text LIKE $1",
+        )?,
+        0
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_sql_filters_and_aggregates_see_encrypted_messages(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let repo = prep.repo.clone().with_cipher(cipher()?);
+    let tool_result = Message::builder(conversation_id, Role::Tool, SequenceNumber::new(1))
+        .with_content(ContentPart::ToolResult(ToolResultPart::success(
+            "call-1",
+            serde_json::json!(SECRET),
+        )))
+        .with_metadata(
+            MessageMetadata::with_agent_backend("claude_code_sdk")
+                .with_token_count(MessageTokenCount::new(12, "cl100k_base")),
+        )
+        .build(&DefaultClock)?;
+    repo.store(&ctx, &tool_result).await?;
+    repo.store(&ctx, &message(conversation_id, 2)?).await?;
+
+    let query = MessageQuery::new()
+        .with_content_part(ContentPartKind::ToolResult)
+        .with_metadata_key("agent_backend");
+    let found = repo.query(&ctx, conversation_id, &query).await?;
+    let usage = repo.token_usage(&ctx, conversation_id).await?;
+
+    assert_eq!(
+        found.items().iter().map(Message::id).collect::<Vec<_>>(),
+        vec![tool_result.id()]
+    );
+    assert_eq!((usage.counted_messages, usage.tokens), (1, 12));
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let backends = diesel::sql_query(concat!(
+        "SELECT count(*) AS count FROM conversation_summaries ",
+        "WHERE conversation_id = $1 AND agent_backend = 'claude_code_sdk'",
+    ))
+    .bind::<SqlUuid, _>(conversation_id.into_inner())
+    .get_result::<Count>(&mut pool.get()?)?;
+    assert_eq!(backends.count, 1);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_encrypts_staged_streams(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let streams = Arc::new(PostgresPartialMessageRepository::new(pool).with_cipher(cipher()?));
+    let builder = StreamingMessageBuilder::new(Arc::clone(&streams), Arc::new(DefaultClock));
+
+    let started = builder
+        .start(&ctx, conversation_id, MessageMetadata::empty())
+        .await?;
+    let partial = builder
+        .push(&ctx, started.message_id, &StreamChunk::new(0, SECRET))
+        .await?;
+
+    assert_eq!(
+        plaintext_rows(
+            &prep,
+            "SELECT count(*) AS count FROM partial_messages WHERE content::text LIKE $1",
+        )?,
+        0
+    );
+    let staged = streams
+        .find(&ctx, started.message_id)
+        .await?
+        .ok_or("staged stream")?;
+    assert_eq!(staged.content, partial.content);
+    Ok(())
+}
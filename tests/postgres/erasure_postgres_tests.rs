@@ -0,0 +1,391 @@
+//! `PostgreSQL` integration tests for subject erasure.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use corbusier::context::{CorrelationId, RequestContext, SessionId, UserId};
+use corbusier::erasure::{adapters::PostgresErasureRepository, services::ErasureService};
+use corbusier::message::{
+    adapters::{
+        blob_store::ObjectStoreBlobStore,
+        postgres::{
+            PgPool, PostgresAgentSessionRepository, PostgresContextSnapshotAdapter,
+            PostgresConversationRepository,
+        },
+    },
+    domain::{
+        AgentSession, AttachmentBlob, AttachmentPart, ContentHash, ContentPart,
+        ContextWindowSnapshot, Conversation, ConversationId, Message, MessageId, MessageSummary,
+        RedactionReason, Role, SequenceNumber, SequenceRange, SnapshotParams, SnapshotType,
+        TextPart,
+    },
+    ports::{
+        BlobStore, agent_session::AgentSessionRepository, context_snapshot::ContextSnapshotPort,
+        conversation::ConversationRepository, repository::MessageRepository,
+    },
+};
+use corbusier::pagination::PageRequest;
+use diesel::prelude::*;
+use diesel::sql_types::{Int8, Text, Uuid as SqlUuid};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const SECRET: &str = "my home address";
+
+#[derive(QueryableByName)]
+struct Count {
+    #[diesel(sql_type = Int8)]
+    count: i64,
+}
+
+/// Another user of the same tenant.
+fn colleague(ctx: &RequestContext) -> RequestContext {
+    RequestContext::new(
+        ctx.tenant_id(),
+        CorrelationId::new(),
+        UserId::new(),
+        SessionId::new(),
+    )
+}
+
+fn reason() -> Result<RedactionReason, BoxError> {
+    Ok(RedactionReason::new("erasure request")?)
+}
+
+async fn start_conversation(
+    pool: &PgPool,
+    ctx: &RequestContext,
+) -> Result<ConversationId, BoxError> {
+    let conversation = Conversation::new(&DefaultClock);
+    PostgresConversationRepository::new(pool.clone())
+        .store(ctx, &conversation)
+        .await?;
+    Ok(conversation.id())
+}
+
+async fn write_message(
+    prep: &PreparedRepo,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+) -> Result<MessageId, BoxError> {
+    let sequence = prep.repo.next_sequence_number(ctx, conversation_id).await?;
+    let message = Message::new(
+        conversation_id,
+        Role::User,
+        vec![ContentPart::Text(TextPart::new(SECRET))],
+        sequence,
+        &DefaultClock,
+    )?;
+    prep.repo.store_with_audit(ctx, &message).await?;
+    Ok(message.id())
+}
+
+/// Writes a message whose attachments are kept in `blobs`.
+async fn write_attachments(
+    prep: &PreparedRepo,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+    (blobs, data): (&dyn BlobStore, &[&str]),
+) -> Result<MessageId, BoxError> {
+    let sequence = prep.repo.next_sequence_number(ctx, conversation_id).await?;
+    let parts = data
+        .iter()
+        .map(|datum| ContentPart::Attachment(AttachmentPart::new("image/png", *datum)))
+        .collect();
+    let message = Message::new(conversation_id, Role::User, parts, sequence, &DefaultClock)?;
+    let (externalised, moved) = message.externalise_attachments(16);
+    for AttachmentBlob { hash, data } in moved {
+        blobs.put(ctx, &hash, data.into()).await?;
+    }
+    prep.repo.store_with_audit(ctx, &externalised).await?;
+    Ok(externalised.id())
+}
+
+/// Starts an agent session with a snapshot in the conversation.
+async fn start_session(
+    pool: &PgPool,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+) -> Result<Uuid, BoxError> {
+    let session = AgentSession::new(
+        conversation_id,
+        "claude",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+    PostgresAgentSessionRepository::new(pool.clone())
+        .store(ctx, &session)
+        .await?;
+    let snapshot = ContextWindowSnapshot::new(
+        SnapshotParams {
+            conversation_id,
+            session_id: session.session_id,
+            sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(1)),
+            message_summary: MessageSummary::default(),
+            snapshot_type: SnapshotType::SessionStart,
+        },
+        &DefaultClock,
+    );
+    PostgresContextSnapshotAdapter::new(pool.clone())
+        .store_snapshot(ctx, &snapshot)
+        .await?;
+    Ok(snapshot.snapshot_id)
+}
+
+fn record_event(
+    pool: &PgPool,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+) -> Result<Uuid, BoxError> {
+    let mut conn = pool.get()?;
+    let id = Uuid::new_v4();
+    diesel::sql_query(concat!(
+        "INSERT INTO domain_events (id, aggregate_id, aggregate_type, event_type, event_data, ",
+        "event_version, occurred_at, user_id) ",
+        "VALUES ($1, $2, 'Conversation', 'ConversationStarted', ",
+        "jsonb_build_object('text', $3::text), 1, NOW(), $4)",
+    ))
+    .bind::<SqlUuid, _>(id)
+    .bind::<SqlUuid, _>(conversation_id.into_inner())
+    .bind::<Text, _>(SECRET)
+    .bind::<SqlUuid, _>(ctx.user_id().into_inner())
+    .execute(&mut conn)?;
+    Ok(id)
+}
+
+/// Records feedback by `ctx` on `message_id`, and a rolling summary, memory
+/// fact, operator action by `ctx`, and staged stream in the conversation.
+fn record_derived(
+    pool: &PgPool,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+    message_id: MessageId,
+) -> Result<(), BoxError> {
+    let mut conn = pool.get()?;
+    diesel::sql_query(concat!(
+        "WITH feedback AS (INSERT INTO message_feedback ",
+        "(id, tenant_id, conversation_id, message_id, author_id, rating, comment, submitted_at) ",
+        "VALUES (gen_random_uuid(), $1, $2, $3, $4, 'thumbs_up', $5, NOW())), ",
+        "summary AS (INSERT INTO conversation_rolling_summaries (tenant_id, conversation_id, ",
+        "summary, covered_through, incremental_updates, recomputed_at, updated_at) ",
+        "VALUES ($1, $2, $5, 1, 0, NOW(), NOW())), ",
+        "fact AS (INSERT INTO agent_memory_facts (id, tenant_id, backend_id, content, ",
+        "confidence_percent, embedding, source_conversation_id, created_at, reinforced_at) ",
+        "VALUES (gen_random_uuid(), $1, gen_random_uuid(), $5, 50, '{1.0}', $2, NOW(), NOW())), ",
+        "stream AS (INSERT INTO partial_messages (tenant_id, message_id, conversation_id, ",
+        "metadata, content, chunk_count, started_at, updated_at) ",
+        "VALUES ($1, gen_random_uuid(), $2, '{}', to_jsonb($5), 1, NOW(), NOW())) ",
+        "INSERT INTO operator_actions (id, tenant_id, kind, conversation_id, reason, actor_id, ",
+        "correlation_id, occurred_at) VALUES (gen_random_uuid(), $1, 'conversation_paused', ",
+        "$2, $5, $4, gen_random_uuid(), NOW())",
+    ))
+    .bind::<SqlUuid, _>(ctx.tenant_id().into_inner())
+    .bind::<SqlUuid, _>(conversation_id.into_inner())
+    .bind::<SqlUuid, _>(message_id.into_inner())
+    .bind::<SqlUuid, _>(ctx.user_id().into_inner())
+    .bind::<Text, _>(SECRET)
+    .execute(&mut conn)?;
+    Ok(())
+}
+
+fn count(pool: &PgPool, sql: &'static str, id: Uuid) -> Result<i64, BoxError> {
+    let mut conn = pool.get()?;
+    Ok(diesel::sql_query(sql)
+        .bind::<SqlUuid, _>(id)
+        .get_result::<Count>(&mut conn)?
+        .count)
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_erasure_cascades_through_the_subjects_data(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let subject = test_request_context;
+    let requester = colleague(&subject);
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let theirs = start_conversation(&pool, &subject).await?;
+    let others = start_conversation(&pool, &requester).await?;
+    let written = write_message(&prep, &subject, theirs).await?;
+    let reply = write_message(&prep, &requester, theirs).await?;
+    let elsewhere = write_message(&prep, &subject, others).await?;
+    let their_snapshot = start_session(&pool, &subject, theirs).await?;
+    let other_snapshot = start_session(&pool, &requester, others).await?;
+    let event = record_event(&pool, &subject, theirs)?;
+    record_derived(&pool, &subject, theirs, reply)?;
+    let service = ErasureService::new(
+        Arc::new(PostgresErasureRepository::new(pool.clone())),
+        Arc::new(DefaultClock),
+    );
+
+    let certificate = service
+        .erase_subject(&requester, subject.user_id(), reason()?)
+        .await?;
+
+    assert_eq!(certificate.erased.messages, 2);
+    assert_eq!(certificate.erased.events, 1);
+    assert_eq!(certificate.erased.sessions, 1);
+    assert_eq!(certificate.erased.snapshots, 1);
+    assert!(certificate.erased.audit_logs > 0);
+    assert_eq!(certificate.erased.feedback, 1);
+    assert_eq!(certificate.erased.partial_messages, 1);
+    assert_eq!(certificate.erased.memory_facts, 1);
+    assert_eq!(certificate.erased.operator_actions, 1);
+    assert_eq!(certificate.erased.summaries, 1);
+    assert_eq!(
+        count(
+            &pool,
+            concat!(
+                "SELECT count(*) AS count FROM operator_actions ",
+                "WHERE conversation_id = $1 AND reason = 'erasure request'",
+            ),
+            theirs.into_inner(),
+        )?,
+        1
+    );
+    for id in [written, elsewhere] {
+        let message = prep.repo.find_by_id(&subject, id).await?.ok_or("kept")?;
+        assert!(message.is_redacted());
+    }
+    let kept = prep
+        .repo
+        .find_by_id(&subject, reply)
+        .await?
+        .ok_or("reply")?;
+    assert!(!kept.is_redacted());
+    let snapshots = PostgresContextSnapshotAdapter::new(pool.clone());
+    assert!(
+        snapshots
+            .find_by_id(&subject, their_snapshot)
+            .await?
+            .is_none()
+    );
+    assert!(
+        snapshots
+            .find_by_id(&subject, other_snapshot)
+            .await?
+            .is_some()
+    );
+    assert_eq!(
+        count(
+            &pool,
+            concat!(
+                "SELECT count(*) AS count FROM domain_events ",
+                "WHERE id = $1 AND event_data = '{}'::jsonb AND user_id IS NULL",
+            ),
+            event,
+        )?,
+        1
+    );
+    assert_eq!(
+        count(
+            &pool,
+            concat!(
+                "SELECT count(*) AS count FROM audit_logs WHERE user_id = $1 ",
+                "AND (old_values IS NOT NULL OR new_values IS NOT NULL OR session_id IS NOT NULL)",
+            ),
+            subject.user_id().into_inner(),
+        )?,
+        0,
+        "audit rows still hold the subject's data"
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_erasure_certificates_round_trip(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let subject = test_request_context;
+    let requester = colleague(&subject);
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let conversation_id = start_conversation(&pool, &subject).await?;
+    write_message(&prep, &subject, conversation_id).await?;
+    let service = ErasureService::new(
+        Arc::new(PostgresErasureRepository::new(pool)),
+        Arc::new(DefaultClock),
+    );
+
+    let first = service
+        .erase_subject(&requester, subject.user_id(), reason()?)
+        .await?;
+    let second = service
+        .erase_subject(&requester, subject.user_id(), reason()?)
+        .await?;
+
+    assert!(!first.erased.is_empty());
+    assert!(second.erased.is_empty());
+    let found = service
+        .certificate(&requester, first.id)
+        .await?
+        .ok_or("certificate")?;
+    assert_eq!(found.erased, first.erased);
+    assert_eq!(found.requested_by, requester.user_id());
+    let listed = service
+        .certificates_for(&requester, subject.user_id(), PageRequest::default())
+        .await?
+        .items()
+        .iter()
+        .map(|certificate| certificate.id)
+        .collect::<Vec<_>>();
+    assert_eq!(listed, vec![first.id, second.id]);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_erasure_deletes_blobs_no_remaining_message_refers_to(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let subject = test_request_context;
+    let requester = colleague(&subject);
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let conversation_id = start_conversation(&pool, &requester).await?;
+    let blobs = Arc::new(ObjectStoreBlobStore::in_memory());
+    let (shared, theirs) = ("iVBORw0KGgo".repeat(10), "R0lGODlh".repeat(10));
+    write_attachments(
+        &prep,
+        &subject,
+        conversation_id,
+        (blobs.as_ref(), &[&shared, &theirs]),
+    )
+    .await?;
+    write_attachments(
+        &prep,
+        &requester,
+        conversation_id,
+        (blobs.as_ref(), &[&shared]),
+    )
+    .await?;
+    let service = ErasureService::new(
+        Arc::new(
+            PostgresErasureRepository::new(pool)
+                .with_blob_store(Arc::clone(&blobs) as Arc<dyn BlobStore>),
+        ),
+        Arc::new(DefaultClock),
+    );
+
+    let certificate = service
+        .erase_subject(&requester, subject.user_id(), reason()?)
+        .await?;
+
+    let their_hash = ContentHash::of(theirs.as_bytes());
+    let shared_hash = ContentHash::of(shared.as_bytes());
+    assert_eq!(certificate.erased.blobs, 1);
+    let found = service
+        .certificate(&requester, certificate.id)
+        .await?
+        .ok_or("certificate")?;
+    assert_eq!(found.erased.blobs, 1);
+    assert!(blobs.get(&subject, &their_hash).await?.is_none());
+    assert!(blobs.get(&subject, &shared_hash).await?.is_some());
+    Ok(())
+}
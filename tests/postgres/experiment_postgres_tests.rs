@@ -0,0 +1,144 @@
+//! `PostgreSQL` integration tests for backend experiment persistence.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use corbusier::agent_backend::{
+    adapters::postgres::PostgresExperimentRepository,
+    domain::{
+        BackendExperiment, BackendId, ExperimentArm, ExperimentArmKey, ExperimentId,
+        ExperimentSignal, ExperimentStatus, HumanFeedback,
+    },
+    ports::{ExperimentRepository, ExperimentRepositoryError},
+    services::{BackendExperimentService, ExperimentAssignment, ExperimentServiceError},
+};
+use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+fn experiment() -> Result<BackendExperiment, BoxError> {
+    Ok(BackendExperiment::new(
+        "codex-vs-sdk",
+        ExperimentArm::new(BackendId::new()),
+        ExperimentArm::new(BackendId::new()).with_prompt_variant("Answer briefly."),
+        &DefaultClock,
+    )?
+    .with_treatment_percent(25)?)
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_experiment_round_trips_and_reports_per_arm(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repository = Arc::new(PostgresExperimentRepository::new(build_pool(
+        prep.temp_db.url(),
+        1,
+    )?));
+    let service = BackendExperimentService::new(repository.clone(), Arc::new(DefaultClock));
+    let experiment = experiment()?;
+    service.start_experiment(&ctx, &experiment).await?;
+
+    let stored = repository.find_by_id(&ctx, experiment.id()).await?;
+    assert_eq!(stored.as_ref(), Some(&experiment));
+
+    let conversation_id = Uuid::new_v4();
+    let assignment = service
+        .assignment(&ctx, experiment.id(), conversation_id)
+        .await?;
+    for signal in [
+        ExperimentSignal::Turn {
+            succeeded: true,
+            latency: Duration::from_millis(420),
+        },
+        ExperimentSignal::Cost { cost_micros: 800 },
+        ExperimentSignal::Feedback {
+            feedback: HumanFeedback::Positive,
+        },
+    ] {
+        service.record_signal(&ctx, &assignment, signal).await?;
+    }
+
+    let report = service.report(&ctx, experiment.id()).await?;
+    let (assigned, other) = match assignment.arm {
+        ExperimentArmKey::Control => (report.control, report.treatment),
+        ExperimentArmKey::Treatment => (report.treatment, report.control),
+    };
+    assert_eq!(assigned.turns, 1);
+    assert_eq!(assigned.p95_latency, Some(Duration::from_millis(420)));
+    assert_eq!(assigned.total_cost_micros, 800);
+    assert_eq!(assigned.positive_feedback, 1);
+    assert_eq!(other.conversations, 0);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_concluded_experiments_leave_the_running_list(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repository = Arc::new(PostgresExperimentRepository::new(build_pool(
+        prep.temp_db.url(),
+        1,
+    )?));
+    let service = BackendExperimentService::new(repository.clone(), Arc::new(DefaultClock));
+    let concluded = experiment()?;
+    let running = experiment()?;
+    service.start_experiment(&ctx, &concluded).await?;
+    service.start_experiment(&ctx, &running).await?;
+
+    let updated = service.conclude_experiment(&ctx, concluded.id()).await?;
+
+    assert_eq!(updated.status(), ExperimentStatus::Concluded);
+    let listed = repository
+        .list_running(&ctx, PageRequest::default())
+        .await?;
+    assert_eq!(
+        listed.iter().map(BackendExperiment::id).collect::<Vec<_>>(),
+        vec![running.id()]
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_observations_require_an_existing_experiment(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let service = BackendExperimentService::new(
+        Arc::new(PostgresExperimentRepository::new(build_pool(
+            prep.temp_db.url(),
+            1,
+        )?)),
+        Arc::new(DefaultClock),
+    );
+    let assignment = ExperimentAssignment {
+        experiment_id: ExperimentId::new(),
+        arm: ExperimentArmKey::Control,
+        conversation_id: Uuid::new_v4(),
+    };
+
+    let result = service
+        .record_signal(&ctx, &assignment, ExperimentSignal::Cost { cost_micros: 1 })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ExperimentServiceError::Repository(
+            ExperimentRepositoryError::NotFound(id)
+        )) if id == assignment.experiment_id
+    ));
+    Ok(())
+}
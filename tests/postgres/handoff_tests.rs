@@ -13,6 +13,7 @@ use corbusier::message::{
         handoff::{AgentHandoffPort, InitiateHandoffParams},
     },
 };
+use corbusier::pagination::PageRequest;
 use mockable::DefaultClock;
 use rstest::{fixture, rstest};
 
@@ -90,7 +91,11 @@ async fn initiate_and_list_handoffs_for_conversation(
 
     let handoffs = scenario
         .handoff_adapter
-        .list_handoffs_for_conversation(&scenario.ctx, scenario.conversation_id)
+        .list_handoffs_for_conversation(
+            &scenario.ctx,
+            scenario.conversation_id,
+            PageRequest::default(),
+        )
         .await?;
 
     assert_eq!(handoffs.len(), 1);
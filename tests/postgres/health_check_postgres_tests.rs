@@ -0,0 +1,66 @@
+//! `PostgreSQL` integration tests for adapter health checks.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo};
+use corbusier::health::{ComponentHealthCheck, HealthCheck, HealthStatus, ReadinessProbe};
+use corbusier::message::adapters::postgres::{
+    PostgresConversationRepository, PostgresMessageRepository,
+};
+use diesel::PgConnection;
+use diesel::r2d2::{ConnectionManager, Pool};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_adapter_reports_healthy_with_pool_statistics(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let repository = PostgresMessageRepository::new(build_pool(prep.temp_db.url(), 2)?);
+
+    let health = repository.check_health().await;
+
+    assert_eq!(health.component, "message_repository");
+    assert_eq!(health.status, HealthStatus::Healthy);
+    assert_eq!(health.detail, None);
+    let pool = health.pool.ok_or("pool statistics missing")?;
+    assert_eq!(pool.max_size, 2);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn readiness_probe_is_ready_while_postgres_answers(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let pool = build_pool(prep.temp_db.url(), 2)?;
+    let probe = ReadinessProbe::new(Arc::new(DefaultClock))
+        .with_check(Arc::new(PostgresConversationRepository::new(pool.clone())))
+        .with_check(Arc::new(PostgresMessageRepository::new(pool)));
+
+    let report = probe.report().await;
+
+    assert!(report.is_ready(), "{report:?}");
+    assert_eq!(report.components().len(), 2);
+    assert_eq!(probe.readiness().await, HealthStatus::Healthy);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn unreachable_database_is_unhealthy() {
+    let manager = ConnectionManager::<PgConnection>::new("postgres://nobody@127.0.0.1:1/missing");
+    let pool = Pool::builder()
+        .max_size(1)
+        .connection_timeout(Duration::from_millis(200))
+        .build_unchecked(manager);
+    let repository = PostgresMessageRepository::new(pool);
+
+    let health = repository.check_health().await;
+
+    assert_eq!(health.status, HealthStatus::Unhealthy);
+    assert!(health.detail.is_some());
+}
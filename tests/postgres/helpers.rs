@@ -22,7 +22,7 @@ use uuid::Uuid;
 ///
 /// Bump the version suffix whenever a new migration is added so that stale
 /// template databases created by earlier test runs are not reused.
-pub const TEMPLATE_DB: &str = "corbusier_test_template_v20";
+pub const TEMPLATE_DB: &str = "corbusier_test_template_v36";
 
 /// Provides a [`DefaultClock`] for test fixtures.
 #[fixture]
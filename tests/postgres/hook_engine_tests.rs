@@ -14,6 +14,7 @@ use corbusier::hook_engine::ports::{
     HookEngine, HookExecutionLogRepository, HookPolicyAuditRepository,
 };
 use corbusier::hook_engine::services::{HookEngineService, HookEngineServiceDeps};
+use corbusier::pagination::PageRequest;
 use corbusier::test_support::other_tenant_ctx;
 use corbusier::test_support::test_request_ctx;
 use corbusier::{message::domain::ConversationId, task::domain::TaskId};
@@ -307,7 +308,7 @@ async fn postgres_policy_audit_is_queryable_and_tenant_scoped(
 
     assert_eq!(
         ctx.policy_audit
-            .find_by_task(&tenant_a, task_id)
+            .find_by_task(&tenant_a, task_id, PageRequest::default())
             .await
             .expect("query by task succeeds")
             .len(),
@@ -315,7 +316,7 @@ async fn postgres_policy_audit_is_queryable_and_tenant_scoped(
     );
     assert_eq!(
         ctx.policy_audit
-            .find_by_conversation(&tenant_a, conversation_id)
+            .find_by_conversation(&tenant_a, conversation_id, PageRequest::default())
             .await
             .expect("query by conversation succeeds")
             .len(),
@@ -323,7 +324,7 @@ async fn postgres_policy_audit_is_queryable_and_tenant_scoped(
     );
     assert_eq!(
         ctx.policy_audit
-            .find_by_trigger_context(&tenant_a, trigger_context_id)
+            .find_by_trigger_context(&tenant_a, trigger_context_id, PageRequest::default())
             .await
             .expect("query by trigger succeeds")
             .len(),
@@ -331,21 +332,21 @@ async fn postgres_policy_audit_is_queryable_and_tenant_scoped(
     );
     assert!(
         ctx.policy_audit
-            .find_by_task(&tenant_b, task_id)
+            .find_by_task(&tenant_b, task_id, PageRequest::default())
             .await
             .expect("cross-tenant query succeeds")
             .is_empty()
     );
     assert!(
         ctx.policy_audit
-            .find_by_conversation(&tenant_b, conversation_id)
+            .find_by_conversation(&tenant_b, conversation_id, PageRequest::default())
             .await
             .expect("cross-tenant conversation query succeeds")
             .is_empty()
     );
     assert!(
         ctx.policy_audit
-            .find_by_trigger_context(&tenant_b, trigger_context_id)
+            .find_by_trigger_context(&tenant_b, trigger_context_id, PageRequest::default())
             .await
             .expect("cross-tenant trigger query succeeds")
             .is_empty()
@@ -0,0 +1,75 @@
+//! `PostgreSQL` integration tests for the issue notification queue.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use chrono::TimeDelta;
+use corbusier::context::RequestContext;
+use corbusier::task::{
+    adapters::postgres::{PostgresIssueNotificationQueue, PostgresTaskRepository},
+    domain::{IssueStatusUpdate, PendingIssueNotification, TaskState},
+    ports::IssueNotificationQueue,
+    services::{CreateTaskFromIssueRequest, TaskLifecycleService},
+};
+use mockable::{Clock, DefaultClock};
+use rstest::rstest;
+use std::sync::Arc;
+
+/// Reduces queued notifications to their states and attempt counts, as
+/// stored timestamps lose sub-microsecond precision.
+fn summarise(pending: &[PendingIssueNotification]) -> Vec<(TaskState, u32)> {
+    pending
+        .iter()
+        .map(|queued| (queued.update().state(), queued.attempts()))
+        .collect()
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_issue_notifications_keep_each_tasks_latest_update(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let tasks = TaskLifecycleService::new(
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+        Arc::new(DefaultClock),
+    );
+    let queue = PostgresIssueNotificationQueue::new(pool);
+    let task = tasks
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "corbusier/core", 1826, "Status sync"),
+        )
+        .await?;
+    let update =
+        |state| IssueStatusUpdate::new(task.id(), task.origin().issue_ref().clone(), state);
+    let now = DefaultClock.utc();
+
+    let mut started = PendingIssueNotification::new(update(TaskState::InProgress), now);
+    queue.enqueue(&ctx, &started).await?;
+    started.record_failure("timed out", now + TimeDelta::minutes(1));
+    queue.reschedule(&ctx, &started).await?;
+    assert!(queue.due(&ctx, now, 10).await?.is_empty());
+    let retried = queue.due(&ctx, now + TimeDelta::minutes(1), 10).await?;
+    assert_eq!(summarise(&retried), [(TaskState::InProgress, 1)]);
+    assert_eq!(
+        retried
+            .first()
+            .and_then(PendingIssueNotification::last_error),
+        Some("timed out")
+    );
+
+    let reviewing = PendingIssueNotification::new(update(TaskState::InReview), now);
+    queue.enqueue(&ctx, &reviewing).await?;
+    queue.remove(&ctx, started.update()).await?;
+    assert_eq!(
+        summarise(&queue.due(&ctx, now, 10).await?),
+        [(TaskState::InReview, 0)]
+    );
+
+    queue.remove(&ctx, reviewing.update()).await?;
+    assert!(queue.due(&ctx, now, 10).await?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,207 @@
+//! `PostgreSQL` integration tests for encryption key rotation.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::key_rotation::{
+    adapters::PostgresKeyRotationRepository, domain::KeyRotationStatus,
+    services::KeyRotationService,
+};
+use corbusier::message::{
+    adapters::{encryption::AesGcmContentCipher, postgres::PostgresPartialMessageRepository},
+    domain::{
+        ContentPart, ConversationId, EncryptionKeyId, Message, MessageMetadata, PartialMessage,
+        Role, SequenceNumber, StreamChunk, TextPart,
+    },
+    ports::{repository::MessageRepository, streaming::PartialMessageRepository},
+};
+use diesel::prelude::*;
+use diesel::sql_types::{Int8, Text};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+const OLD_KEY: &str = "content-2026-10";
+const NEW_KEY: &str = "content-2026-11";
+
+#[derive(QueryableByName)]
+struct Count {
+    #[diesel(sql_type = Int8)]
+    count: i64,
+}
+
+fn old_cipher() -> Result<Arc<AesGcmContentCipher>, BoxError> {
+    Ok(Arc::new(AesGcmContentCipher::new(
+        EncryptionKeyId::new(OLD_KEY)?,
+        [7; 32],
+    )))
+}
+
+fn new_cipher() -> Result<Arc<AesGcmContentCipher>, BoxError> {
+    Ok(Arc::new(
+        AesGcmContentCipher::new(EncryptionKeyId::new(NEW_KEY)?, [9; 32])
+            .with_key(EncryptionKeyId::new(OLD_KEY)?, [7; 32]),
+    ))
+}
+
+fn message(conversation_id: ConversationId, sequence: u64) -> Result<Message, BoxError> {
+    Ok(Message::new(
+        conversation_id,
+        Role::User,
+        vec![ContentPart::Text(TextPart::new(format!(
+            "message {sequence}"
+        )))],
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )?)
+}
+
+/// Counts the messages whose content is sealed under `key_id`.
+fn sealed_under(prep: &PreparedRepo, key_id: &str) -> Result<i64, BoxError> {
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    Ok(diesel::sql_query(concat!(
+        "SELECT count(*) AS count FROM messages ",
+        "WHERE content->'$sealed'->>'key_id' = $1 AND metadata->'$sealed'->>'key_id' = $1",
+    ))
+    .bind::<Text, _>(key_id)
+    .get_result::<Count>(&mut pool.get()?)?
+    .count)
+}
+
+/// Stores one unencrypted message and `sealed` messages under the old key.
+async fn store_messages(
+    prep: &PreparedRepo,
+    ctx: &RequestContext,
+    sealed: u64,
+) -> Result<Vec<Message>, BoxError> {
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, ctx).await?;
+    let encrypted = prep.repo.clone().with_cipher(old_cipher()?);
+    let plain = message(conversation_id, 1)?;
+    prep.repo.store(ctx, &plain).await?;
+    let mut stored = vec![plain];
+    for sequence in 2..=sealed + 1 {
+        let next = message(conversation_id, sequence)?;
+        encrypted.store(ctx, &next).await?;
+        stored.push(next);
+    }
+    Ok(stored)
+}
+
+fn service(prep: &PreparedRepo, batch_size: usize) -> Result<KeyRotationService, BoxError> {
+    let pool = build_pool(prep.temp_db.url(), 2)?;
+    let repository = Arc::new(PostgresKeyRotationRepository::new(pool, new_cipher()?));
+    Ok(
+        KeyRotationService::new(repository.clone(), repository, Arc::new(DefaultClock))
+            .with_batch_size(batch_size),
+    )
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_rotation_reseals_every_message_under_the_new_key(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let stored = store_messages(&prep, &ctx, 4).await?;
+    let service = service(&prep, 2)?;
+
+    let started = service.start(&ctx).await?;
+    let finished = service.run(&ctx, started.id).await?;
+
+    assert_eq!(started.remaining, 5);
+    assert_eq!(finished.status, KeyRotationStatus::Completed);
+    assert_eq!(finished.resealed, 5);
+    assert_eq!(finished.batches, 3);
+    assert_eq!(sealed_under(&prep, NEW_KEY)?, 5);
+    assert_eq!(sealed_under(&prep, OLD_KEY)?, 0);
+    let reader = prep.repo.clone().with_cipher(new_cipher()?);
+    for message in &stored {
+        let found = reader
+            .find_by_id(&ctx, message.id())
+            .await?
+            .ok_or("resealed message")?;
+        assert_eq!(found.content(), message.content());
+    }
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_rotation_resumes_and_reports_what_remains(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    store_messages(&prep, &ctx, 4).await?;
+    let first = service(&prep, 2)?;
+    let started = first.start(&ctx).await?;
+    first.run_batch(&ctx, started.id).await?;
+
+    let restarted = service(&prep, 2)?;
+    let metrics = restarted.metrics(&ctx).await?;
+    let resumed = restarted.start(&ctx).await?;
+    let finished = restarted.run(&ctx, resumed.id).await?;
+
+    assert_eq!(metrics.remaining, 3);
+    assert_eq!(
+        metrics
+            .rotation
+            .map(|rotation| (rotation.id, rotation.resealed)),
+        Some((started.id, 2))
+    );
+    assert_eq!(resumed.id, started.id);
+    assert_eq!(finished.status, KeyRotationStatus::Completed);
+    assert_eq!(finished.resealed, 5);
+    assert_eq!(restarted.metrics(&ctx).await?.remaining, 0);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_rotation_reseals_staged_streams(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let mut partial = PartialMessage::start(
+        conversation_id,
+        MessageMetadata::with_agent_backend("claude_code_sdk"),
+        &DefaultClock,
+    );
+    partial.append(&StreamChunk::new(0, "Deploying "), &DefaultClock)?;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    PostgresPartialMessageRepository::new(pool.clone())
+        .with_cipher(old_cipher()?)
+        .start(&ctx, &partial)
+        .await?;
+    let service = service(&prep, 2)?;
+
+    let started = service.start(&ctx).await?;
+    let finished = service.run(&ctx, started.id).await?;
+
+    assert_eq!(started.remaining, 1);
+    assert_eq!(finished.status, KeyRotationStatus::Completed);
+    let new_key_only = Arc::new(AesGcmContentCipher::new(
+        EncryptionKeyId::new(NEW_KEY)?,
+        [9; 32],
+    ));
+    let staged = PostgresPartialMessageRepository::new(pool)
+        .with_cipher(new_key_only)
+        .find(&ctx, partial.message_id)
+        .await?
+        .ok_or("resealed stream")?;
+    assert_eq!(
+        (staged.content, staged.chunk_count),
+        (partial.content, partial.chunk_count)
+    );
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! `PostgreSQL` integration tests for the maintenance mode flag.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo};
+use corbusier::context::UserId;
+use corbusier::maintenance::{
+    adapters::PostgresMaintenanceStateRepository, domain::MaintenanceState,
+    ports::MaintenanceStateRepository, services::MaintenanceGate,
+};
+use corbusier::operator::domain::OperatorReason;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_maintenance_starts_inactive(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let repository = PostgresMaintenanceStateRepository::new(build_pool(prep.temp_db.url(), 1)?);
+
+    let state = repository.load().await?;
+
+    assert_eq!(state, MaintenanceState::default());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_maintenance_round_trips_toggles(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let repository = PostgresMaintenanceStateRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let actor = UserId::new();
+
+    repository
+        .save(&MaintenanceState::enabled(
+            OperatorReason::new("failover to eu-west")?,
+            Some(actor),
+            &DefaultClock,
+        ))
+        .await?;
+    let enabled = repository.load().await?;
+    repository
+        .save(&MaintenanceState::disabled(None, &DefaultClock))
+        .await?;
+    let disabled = repository.load().await?;
+
+    assert!(enabled.enabled);
+    assert_eq!(
+        enabled.reason.as_ref().map(OperatorReason::as_str),
+        Some("failover to eu-west")
+    );
+    assert_eq!(enabled.changed_by, Some(actor));
+    assert!(!disabled.enabled);
+    assert_eq!(disabled.reason, None);
+    assert_eq!(disabled.changed_by, None);
+    assert!(disabled.changed_at >= enabled.changed_at);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_maintenance_is_shared_between_instances(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let pool = build_pool(prep.temp_db.url(), 2)?;
+    let gate = |refresh: Duration| {
+        MaintenanceGate::new(
+            Arc::new(PostgresMaintenanceStateRepository::new(pool.clone())),
+            Arc::new(DefaultClock),
+        )
+        .with_refresh_interval(refresh)
+    };
+    let operator = gate(Duration::ZERO);
+    let worker = gate(Duration::ZERO);
+
+    operator
+        .enable(OperatorReason::new("schema migration")?, None)
+        .await?;
+
+    assert!(worker.is_active().await);
+    Ok(())
+}
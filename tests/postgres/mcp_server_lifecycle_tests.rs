@@ -2,6 +2,7 @@
 
 use std::sync::Arc;
 
+use corbusier::pagination::PageRequest;
 use corbusier::tool_registry::{
     adapters::{
         InMemoryMcpServerHost,
@@ -238,8 +239,20 @@ async fn postgres_duplicate_name_is_scoped_per_tenant(
         .expect("second tenant registration should succeed");
 
     assert_ne!(first.id(), second.id());
-    assert_eq!(ctx.service.list_all(&tenant_a).await?.len(), 1);
-    assert_eq!(ctx.service.list_all(&tenant_b).await?.len(), 1);
+    assert_eq!(
+        ctx.service
+            .list_all(&tenant_a, PageRequest::default())
+            .await?
+            .len(),
+        1
+    );
+    assert_eq!(
+        ctx.service
+            .list_all(&tenant_b, PageRequest::default())
+            .await?
+            .len(),
+        1
+    );
     let found = ctx
         .service
         .find_by_name(&tenant_b, "workspace_tools")
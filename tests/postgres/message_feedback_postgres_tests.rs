@@ -0,0 +1,91 @@
+//! `PostgreSQL` integration tests for message feedback persistence.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use chrono::Utc;
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::{PostgresMessageFeedbackRepository, PostgresMessageRepository},
+    domain::{
+        AgentResponseAudit, AgentResponseStatus, ContentPart, ConversationId, FeedbackCategory,
+        FeedbackRating, FeedbackSubmission, Message, MessageId, MessageMetadata, Role,
+        SequenceNumber, TextPart,
+    },
+    ports::MessageRepository,
+    services::{MessageFeedbackService, SubmitFeedbackRequest},
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+fn reply(conversation_id: ConversationId) -> Result<Message, BoxError> {
+    Ok(Message::from_persisted(
+        MessageId::new(),
+        conversation_id,
+        Role::Assistant,
+        vec![ContentPart::Text(TextPart::new("Here is the fix."))],
+        MessageMetadata::with_agent_backend("codex_cli").with_agent_response_audit(
+            AgentResponseAudit::new(AgentResponseStatus::Completed)
+                .with_instruction_set_version("2026.04"),
+        ),
+        Utc::now(),
+        SequenceNumber::new(1),
+    )?)
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_feedback_upserts_per_author_and_summarises(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let messages = PostgresMessageRepository::new(pool.clone());
+    let service = MessageFeedbackService::new(
+        Arc::new(messages.clone()),
+        Arc::new(PostgresMessageFeedbackRepository::new(pool)),
+        Arc::new(DefaultClock),
+    );
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let message = reply(conversation_id)?;
+    messages.store(&ctx, &message).await?;
+    let submit = |submission| SubmitFeedbackRequest::new(conversation_id, message.id(), submission);
+
+    let first = service
+        .submit(
+            &ctx,
+            submit(FeedbackSubmission::new(FeedbackRating::ThumbsDown)),
+        )
+        .await?;
+    let replaced = service
+        .submit(
+            &ctx,
+            submit(
+                FeedbackSubmission::new(FeedbackRating::Stars(5))
+                    .with_comment("Fixed on retry")
+                    .with_category(FeedbackCategory::Accuracy),
+            ),
+        )
+        .await?;
+    assert_eq!(replaced.id(), first.id());
+
+    let stored = service
+        .feedback_for_message(&ctx, conversation_id, message.id())
+        .await?;
+    assert_eq!(stored, vec![replaced]);
+
+    let summaries = service.summaries(&ctx).await?;
+    let [summary] = summaries.as_slice() else {
+        return Err(format!("expected one summary, got {}", summaries.len()).into());
+    };
+    assert_eq!(summary.agent_backend.as_deref(), Some("codex_cli"));
+    assert_eq!(summary.instruction_set_version.as_deref(), Some("2026.04"));
+    assert_eq!((summary.total, summary.positive), (1, 1));
+    assert_eq!(summary.average_stars_centi(), Some(500));
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//! `PostgreSQL` integration tests for message processing status persistence.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use chrono::{TimeDelta, Utc};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::{PostgresMessageProcessingRepository, PostgresMessageRepository},
+    domain::{
+        ContentPart, ConversationId, Message, ProcessingStage, Role, SequenceNumber, StageState,
+        StuckMessagesQuery, TextPart,
+    },
+    ports::MessageRepository,
+    services::{MessageProcessingService, ReprocessRequest, StageReport},
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_processing_tracks_stuck_stages_and_reprocesses(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let messages = PostgresMessageRepository::new(pool.clone());
+    let service = MessageProcessingService::new(
+        Arc::new(messages.clone()),
+        Arc::new(PostgresMessageProcessingRepository::new(pool)),
+        Arc::new(DefaultClock),
+    );
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let message = Message::new(
+        conversation_id,
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("Deploy?"))],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )?;
+    messages.store(&ctx, &message).await?;
+
+    for stage in [ProcessingStage::Validation, ProcessingStage::Moderation] {
+        service.begin_stage(&ctx, &message, stage).await?;
+        service
+            .finish_stage(&ctx, StageReport::succeeded(message.id(), stage))
+            .await?;
+    }
+    service
+        .begin_stage(&ctx, &message, ProcessingStage::Embedding)
+        .await?;
+
+    let cutoff = Utc::now() + TimeDelta::seconds(1);
+    let stuck = service
+        .stuck_messages(
+            &ctx,
+            StuckMessagesQuery::new(ProcessingStage::Embedding, cutoff),
+        )
+        .await?;
+    assert_eq!(
+        stuck
+            .iter()
+            .map(|status| status.message_id)
+            .collect::<Vec<_>>(),
+        vec![message.id()]
+    );
+    let settled = service
+        .stuck_messages(
+            &ctx,
+            StuckMessagesQuery::new(ProcessingStage::Moderation, cutoff),
+        )
+        .await?;
+    assert!(settled.is_empty());
+
+    let status = service
+        .reprocess(
+            &ctx,
+            ReprocessRequest::new(conversation_id, message.id(), ProcessingStage::Moderation),
+        )
+        .await?;
+    assert_eq!(status.current_stage(), Some(ProcessingStage::Moderation));
+    assert_eq!(status.stage(ProcessingStage::Embedding), None);
+    assert_eq!(
+        status
+            .stage(ProcessingStage::Moderation)
+            .map(|stage| (stage.state, stage.attempts)),
+        Some((StageState::InProgress, 2))
+    );
+    Ok(())
+}
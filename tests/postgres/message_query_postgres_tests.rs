@@ -0,0 +1,154 @@
+//! `PostgreSQL` integration tests for filtered message queries.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, clock, insert_conversation, prepared_repo, test_request_context,
+};
+use chrono::Duration;
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    domain::{
+        ContentPart, ContentPartKind, ConversationId, Message, MessageMetadata, MessageQuery, Role,
+        SequenceNumber, TextPart, ToolCallPart, ToolResultPart,
+    },
+    ports::repository::MessageRepository,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+
+/// Builds a user prompt, an assistant tool call, the tool's result, and an
+/// assistant reply.
+fn replay_messages(
+    clock: &DefaultClock,
+    conversation_id: ConversationId,
+) -> Result<Vec<Message>, BoxError> {
+    let parts = [
+        (
+            Role::User,
+            ContentPart::Text(TextPart::new("Run the tests")),
+        ),
+        (
+            Role::Assistant,
+            ContentPart::ToolCall(ToolCallPart::new("call-1", "run_tests", json!({}))),
+        ),
+        (
+            Role::Tool,
+            ContentPart::ToolResult(ToolResultPart::success("call-1", json!("ok"))),
+        ),
+        (
+            Role::Assistant,
+            ContentPart::Text(TextPart::new("All green")),
+        ),
+    ];
+    let mut messages = Vec::new();
+    for (sequence, (role, part)) in (1..).zip(parts) {
+        let metadata = match role {
+            Role::Assistant => MessageMetadata::with_agent_backend("codex_cli"),
+            Role::Tool => MessageMetadata::empty().with_extension("replay.source", json!("ci"))?,
+            Role::User | Role::System => MessageMetadata::empty(),
+        };
+        messages.push(
+            Message::builder(conversation_id, role, SequenceNumber::new(sequence))
+                .with_content(part)
+                .with_metadata(metadata)
+                .build(clock)?,
+        );
+    }
+    Ok(messages)
+}
+
+async fn seeded(
+    prep: &PreparedRepo,
+    ctx: &RequestContext,
+    clock: &DefaultClock,
+) -> Result<(ConversationId, Vec<Message>), BoxError> {
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, ctx).await?;
+    let messages = replay_messages(clock, conversation_id)?;
+    for message in &messages {
+        prep.repo.store(ctx, message).await?;
+    }
+    Ok((conversation_id, messages))
+}
+
+fn sequences(messages: &[Message]) -> Vec<u64> {
+    messages
+        .iter()
+        .map(|message| message.sequence_number().value())
+        .collect()
+}
+
+#[rstest]
+#[case::one_role(MessageQuery::new().with_role(Role::Assistant), vec![2, 4])]
+#[case::any_of_roles(MessageQuery::new().with_role(Role::User).with_role(Role::Tool), vec![1, 3])]
+#[case::tool_calls(MessageQuery::new().with_tool_calls(), vec![2])]
+#[case::tool_results(MessageQuery::new().with_content_part(ContentPartKind::ToolResult), vec![3])]
+#[case::known_metadata_field(MessageQuery::new().with_metadata_key("agent_backend"), vec![2, 4])]
+#[case::extension_key(MessageQuery::new().with_metadata_key("replay.source"), vec![3])]
+#[case::combined(
+    MessageQuery::new().with_role(Role::Assistant).with_content_part(ContentPartKind::Text),
+    vec![4]
+)]
+#[tokio::test]
+async fn postgres_query_filters_by_role_content_and_metadata(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+    #[case] query: MessageQuery,
+    #[case] expected: Vec<u64>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let (conversation_id, _) = seeded(&prep, &ctx, &clock).await?;
+
+    let page = prep.repo.query(&ctx, conversation_id, &query).await?;
+
+    assert_eq!(sequences(page.items()), expected);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn postgres_query_filters_by_creation_time(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let (conversation_id, messages) = seeded(&prep, &ctx, &clock).await?;
+    let first_created = messages
+        .first()
+        .map(Message::created_at)
+        .ok_or("seeded messages")?;
+
+    let before = MessageQuery::new().with_created_until(first_created);
+    let after = MessageQuery::new().with_created_from(first_created + Duration::hours(1));
+    let spanning = MessageQuery::new()
+        .with_created_from(first_created)
+        .with_created_until(first_created + Duration::hours(1));
+
+    assert!(
+        prep.repo
+            .query(&ctx, conversation_id, &before)
+            .await?
+            .is_empty()
+    );
+    assert!(
+        prep.repo
+            .query(&ctx, conversation_id, &after)
+            .await?
+            .is_empty()
+    );
+    assert_eq!(
+        sequences(
+            prep.repo
+                .query(&ctx, conversation_id, &spanning)
+                .await?
+                .items()
+        ),
+        vec![1, 2, 3, 4]
+    );
+    Ok(())
+}
@@ -1,5 +1,17 @@
 //! Migration SQL fixtures for `PostgreSQL` integration tests.
 
+mod message;
+mod task;
+
+use message::{
+    ADD_HANDOFF_CONTEXT_PACKAGES_SQL, ADD_HANDOFF_PACKAGE_CONVERSATIONS_SQL,
+    ADD_INCREMENTAL_SNAPSHOTS_SQL, ADD_PERIODIC_SNAPSHOTS_SQL, ADD_SESSION_PAUSED_SNAPSHOTS_SQL,
+    ADD_SLASH_COMMAND_DEFINITIONS_SQL, ADD_SLASH_COMMAND_EXECUTIONS_SQL, ADD_TURNS_SQL,
+};
+use task::{
+    ADD_ISSUE_STATUS_NOTIFICATIONS_SQL, ADD_TASK_SCHEDULING_SQL, ADD_WEBHOOK_DELIVERIES_SQL,
+};
+
 /// SQL to create the base schema for tests.
 pub const CREATE_SCHEMA_SQL: &str =
     include_str!("../../migrations/2026-01-15-000000_create_base_tables/up.sql");
@@ -54,6 +66,130 @@ pub const ENFORCE_TENANT_SCOPE_FOR_CONVERSATIONS_AND_MESSAGES_SQL: &str = includ
     "../../migrations/2026-04-01-000001_enforce_tenant_scope_for_conversations_and_messages/up.sql"
 );
 
+/// SQL to add time-ordered indexes for conversation activity statistics.
+pub const ADD_ACTIVITY_STATISTICS_INDEXES_SQL: &str =
+    include_str!("../../migrations/2026-04-10-000000_add_activity_statistics_indexes/up.sql");
+
+/// SQL to add usage records and conversation-to-task attribution links.
+pub const ADD_TASK_COST_ATTRIBUTION_SQL: &str =
+    include_str!("../../migrations/2026-04-12-000000_add_task_cost_attribution/up.sql");
+
+/// SQL to add the trigger-maintained conversation summary projection.
+pub const ADD_CONVERSATION_SUMMARIES_SQL: &str =
+    include_str!("../../migrations/2026-04-14-000000_add_conversation_summaries/up.sql");
+
+/// SQL to add backend experiments and their outcome observations.
+pub const ADD_BACKEND_EXPERIMENTS_SQL: &str =
+    include_str!("../../migrations/2026-04-16-000000_add_backend_experiments/up.sql");
+
+/// SQL to add human feedback on assistant messages.
+pub const ADD_MESSAGE_FEEDBACK_SQL: &str =
+    include_str!("../../migrations/2026-04-18-000000_add_message_feedback/up.sql");
+
+/// SQL to add per-message processing pipeline status.
+pub const ADD_MESSAGE_PROCESSING_STAGES_SQL: &str =
+    include_str!("../../migrations/2026-04-20-000000_add_message_processing_stages/up.sql");
+
+/// SQL to add conversation budgets for delegated sub-tasks.
+pub const ADD_CONVERSATION_BUDGETS_SQL: &str =
+    include_str!("../../migrations/2026-04-22-000000_add_conversation_budgets/up.sql");
+
+/// SQL to index delegated budgets by parent conversation.
+pub const INDEX_DELEGATED_BUDGETS_SQL: &str =
+    include_str!("../../migrations/2026-04-24-000000_index_delegated_budgets/up.sql");
+
+/// SQL to add long-term agent memory facts.
+pub const ADD_AGENT_MEMORY_FACTS_SQL: &str =
+    include_str!("../../migrations/2026-04-26-000000_add_agent_memory_facts/up.sql");
+
+/// SQL to add rolling conversation summaries.
+pub const ADD_CONVERSATION_ROLLING_SUMMARIES_SQL: &str =
+    include_str!("../../migrations/2026-04-28-000000_add_conversation_rolling_summaries/up.sql");
+
+/// SQL to add message redaction tombstones.
+pub const ADD_MESSAGE_REDACTIONS_SQL: &str =
+    include_str!("../../migrations/2026-04-30-000000_add_message_redactions/up.sql");
+
+/// SQL to add the operator action audit table.
+pub const ADD_OPERATOR_ACTIONS_SQL: &str =
+    include_str!("../../migrations/2026-05-02-000000_add_operator_actions/up.sql");
+
+/// SQL to add the agent turn outcome table.
+pub const ADD_AGENT_TURN_OUTCOMES_SQL: &str =
+    include_str!("../../migrations/2026-05-04-000000_add_agent_turn_outcomes/up.sql");
+
+/// SQL to add optimistic concurrency versions to conversations and sessions.
+pub const ADD_AGGREGATE_VERSIONS_SQL: &str =
+    include_str!("../../migrations/2026-05-06-000000_add_aggregate_versions/up.sql");
+
+/// SQL to add the cluster-wide maintenance mode flag.
+pub const ADD_MAINTENANCE_MODE_SQL: &str =
+    include_str!("../../migrations/2026-05-08-000000_add_maintenance_mode/up.sql");
+
+/// SQL to make conversation foreign keys deferrable for tenant transfers.
+pub const ADD_CONVERSATION_TRANSFERS_SQL: &str =
+    include_str!("../../migrations/2026-05-10-000000_add_conversation_transfers/up.sql");
+
+/// SQL to add domain event stream positions and projection checkpoints.
+pub const ADD_PROJECTION_CHECKPOINTS_SQL: &str =
+    include_str!("../../migrations/2026-05-12-000000_add_projection_checkpoints/up.sql");
+
+/// SQL to add per-turn context assembly reports.
+pub const ADD_CONTEXT_ASSEMBLY_REPORTS_SQL: &str =
+    include_str!("../../migrations/2026-05-14-000000_add_context_assembly_reports/up.sql");
+
+/// SQL to add staged partial messages for streamed responses.
+pub const ADD_PARTIAL_MESSAGES_SQL: &str =
+    include_str!("../../migrations/2026-05-16-000000_add_partial_messages/up.sql");
+
+/// SQL to add one-shot turn completion callbacks.
+pub const ADD_TURN_CALLBACKS_SQL: &str =
+    include_str!("../../migrations/2026-05-18-000000_add_turn_callbacks/up.sql");
+
+/// SQL to add compaction snapshots and their summary text.
+pub const ADD_COMPACTION_SNAPSHOTS_SQL: &str =
+    include_str!("../../migrations/2026-05-20-000000_add_compaction_snapshots/up.sql");
+
+/// SQL to add conversation fork provenance.
+pub const ADD_CONVERSATION_FORKS_SQL: &str =
+    include_str!("../../migrations/2026-05-22-000000_add_conversation_forks/up.sql");
+
+/// SQL to add conversation labels and their audit history.
+pub const ADD_CONVERSATION_LABELS_SQL: &str =
+    include_str!("../../migrations/2026-05-24-000000_add_conversation_labels/up.sql");
+
+/// SQL to add conversation retention policies.
+pub const ADD_RETENTION_POLICIES_SQL: &str =
+    include_str!("../../migrations/2026-05-26-000000_add_retention_policies/up.sql");
+
+/// SQL to add subject erasure certificates.
+pub const ADD_ERASURE_CERTIFICATES_SQL: &str =
+    include_str!("../../migrations/2026-05-28-000000_add_erasure_certificates/up.sql");
+
+/// SQL to add encryption key rotation progress.
+pub const ADD_ENCRYPTION_KEY_ROTATIONS_SQL: &str =
+    include_str!("../../migrations/2026-05-30-000000_add_encryption_key_rotations/up.sql");
+
+/// SQL to store staged stream content as JSONB so it can be sealed.
+pub const SEAL_PARTIAL_MESSAGE_CONTENT_SQL: &str =
+    include_str!("../../migrations/2026-06-01-000000_seal_partial_message_content/up.sql");
+
+/// SQL to index the attachment blobs messages refer to.
+pub const INDEX_ATTACHMENT_BLOB_REFERENCES_SQL: &str =
+    include_str!("../../migrations/2026-06-02-000000_index_attachment_blob_references/up.sql");
+
+/// SQL to count everything subject erasures reach on their certificates.
+pub const WIDEN_ERASURE_CERTIFICATES_SQL: &str =
+    include_str!("../../migrations/2026-06-04-000000_widen_erasure_certificates/up.sql");
+
+/// SQL to let experiment observations move with their conversation.
+pub const MOVE_EXPERIMENT_OBSERVATIONS_SQL: &str =
+    include_str!("../../migrations/2026-06-06-000000_move_experiment_observations/up.sql");
+
+/// SQL to add the secret injection audit trail.
+pub const ADD_SECRET_INJECTION_AUDITS_SQL: &str =
+    include_str!("../../migrations/2026-06-08-000000_add_secret_injection_audits/up.sql");
+
 /// SQL to add hook execution log table for roadmap 2.3.1.
 pub const ADD_HOOK_EXECUTIONS_SQL: &str =
     include_str!("../../migrations/2026-03-03-000000_add_hook_executions_table/up.sql");
@@ -153,4 +289,109 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
         "ENFORCE_TENANT_SCOPE_FOR_CONVERSATIONS_AND_MESSAGES_SQL",
         ENFORCE_TENANT_SCOPE_FOR_CONVERSATIONS_AND_MESSAGES_SQL,
     ),
+    (
+        "ADD_ACTIVITY_STATISTICS_INDEXES_SQL",
+        ADD_ACTIVITY_STATISTICS_INDEXES_SQL,
+    ),
+    (
+        "ADD_TASK_COST_ATTRIBUTION_SQL",
+        ADD_TASK_COST_ATTRIBUTION_SQL,
+    ),
+    (
+        "ADD_CONVERSATION_SUMMARIES_SQL",
+        ADD_CONVERSATION_SUMMARIES_SQL,
+    ),
+    ("ADD_BACKEND_EXPERIMENTS_SQL", ADD_BACKEND_EXPERIMENTS_SQL),
+    ("ADD_MESSAGE_FEEDBACK_SQL", ADD_MESSAGE_FEEDBACK_SQL),
+    (
+        "ADD_MESSAGE_PROCESSING_STAGES_SQL",
+        ADD_MESSAGE_PROCESSING_STAGES_SQL,
+    ),
+    ("ADD_CONVERSATION_BUDGETS_SQL", ADD_CONVERSATION_BUDGETS_SQL),
+    ("INDEX_DELEGATED_BUDGETS_SQL", INDEX_DELEGATED_BUDGETS_SQL),
+    ("ADD_AGENT_MEMORY_FACTS_SQL", ADD_AGENT_MEMORY_FACTS_SQL),
+    (
+        "ADD_CONVERSATION_ROLLING_SUMMARIES_SQL",
+        ADD_CONVERSATION_ROLLING_SUMMARIES_SQL,
+    ),
+    ("ADD_MESSAGE_REDACTIONS_SQL", ADD_MESSAGE_REDACTIONS_SQL),
+    ("ADD_OPERATOR_ACTIONS_SQL", ADD_OPERATOR_ACTIONS_SQL),
+    ("ADD_AGENT_TURN_OUTCOMES_SQL", ADD_AGENT_TURN_OUTCOMES_SQL),
+    ("ADD_AGGREGATE_VERSIONS_SQL", ADD_AGGREGATE_VERSIONS_SQL),
+    ("ADD_MAINTENANCE_MODE_SQL", ADD_MAINTENANCE_MODE_SQL),
+    (
+        "ADD_CONVERSATION_TRANSFERS_SQL",
+        ADD_CONVERSATION_TRANSFERS_SQL,
+    ),
+    (
+        "ADD_PROJECTION_CHECKPOINTS_SQL",
+        ADD_PROJECTION_CHECKPOINTS_SQL,
+    ),
+    (
+        "ADD_CONTEXT_ASSEMBLY_REPORTS_SQL",
+        ADD_CONTEXT_ASSEMBLY_REPORTS_SQL,
+    ),
+    ("ADD_PARTIAL_MESSAGES_SQL", ADD_PARTIAL_MESSAGES_SQL),
+    ("ADD_TURN_CALLBACKS_SQL", ADD_TURN_CALLBACKS_SQL),
+    ("ADD_COMPACTION_SNAPSHOTS_SQL", ADD_COMPACTION_SNAPSHOTS_SQL),
+    ("ADD_CONVERSATION_FORKS_SQL", ADD_CONVERSATION_FORKS_SQL),
+    ("ADD_CONVERSATION_LABELS_SQL", ADD_CONVERSATION_LABELS_SQL),
+    ("ADD_RETENTION_POLICIES_SQL", ADD_RETENTION_POLICIES_SQL),
+    ("ADD_ERASURE_CERTIFICATES_SQL", ADD_ERASURE_CERTIFICATES_SQL),
+    (
+        "ADD_ENCRYPTION_KEY_ROTATIONS_SQL",
+        ADD_ENCRYPTION_KEY_ROTATIONS_SQL,
+    ),
+    (
+        "SEAL_PARTIAL_MESSAGE_CONTENT_SQL",
+        SEAL_PARTIAL_MESSAGE_CONTENT_SQL,
+    ),
+    (
+        "INDEX_ATTACHMENT_BLOB_REFERENCES_SQL",
+        INDEX_ATTACHMENT_BLOB_REFERENCES_SQL,
+    ),
+    (
+        "WIDEN_ERASURE_CERTIFICATES_SQL",
+        WIDEN_ERASURE_CERTIFICATES_SQL,
+    ),
+    (
+        "MOVE_EXPERIMENT_OBSERVATIONS_SQL",
+        MOVE_EXPERIMENT_OBSERVATIONS_SQL,
+    ),
+    (
+        "ADD_SECRET_INJECTION_AUDITS_SQL",
+        ADD_SECRET_INJECTION_AUDITS_SQL,
+    ),
+    (
+        "ADD_HANDOFF_CONTEXT_PACKAGES_SQL",
+        ADD_HANDOFF_CONTEXT_PACKAGES_SQL,
+    ),
+    (
+        "ADD_SESSION_PAUSED_SNAPSHOTS_SQL",
+        ADD_SESSION_PAUSED_SNAPSHOTS_SQL,
+    ),
+    ("ADD_TURNS_SQL", ADD_TURNS_SQL),
+    (
+        "ADD_INCREMENTAL_SNAPSHOTS_SQL",
+        ADD_INCREMENTAL_SNAPSHOTS_SQL,
+    ),
+    ("ADD_PERIODIC_SNAPSHOTS_SQL", ADD_PERIODIC_SNAPSHOTS_SQL),
+    (
+        "ADD_SLASH_COMMAND_DEFINITIONS_SQL",
+        ADD_SLASH_COMMAND_DEFINITIONS_SQL,
+    ),
+    (
+        "ADD_SLASH_COMMAND_EXECUTIONS_SQL",
+        ADD_SLASH_COMMAND_EXECUTIONS_SQL,
+    ),
+    ("ADD_WEBHOOK_DELIVERIES_SQL", ADD_WEBHOOK_DELIVERIES_SQL),
+    (
+        "ADD_ISSUE_STATUS_NOTIFICATIONS_SQL",
+        ADD_ISSUE_STATUS_NOTIFICATIONS_SQL,
+    ),
+    ("ADD_TASK_SCHEDULING_SQL", ADD_TASK_SCHEDULING_SQL),
+    (
+        "ADD_HANDOFF_PACKAGE_CONVERSATIONS_SQL",
+        ADD_HANDOFF_PACKAGE_CONVERSATIONS_SQL,
+    ),
 ];
@@ -0,0 +1,34 @@
+//! Migration SQL fixtures for conversation session, turn, and slash-command
+//! tables.
+
+/// SQL to add context packages prepared for handoff target sessions.
+pub const ADD_HANDOFF_CONTEXT_PACKAGES_SQL: &str =
+    include_str!("../../../migrations/2026-06-10-000000_add_handoff_context_packages/up.sql");
+
+/// SQL to record the context window of paused agent sessions.
+pub const ADD_SESSION_PAUSED_SNAPSHOTS_SQL: &str =
+    include_str!("../../../migrations/2026-06-12-000000_add_session_paused_snapshots/up.sql");
+
+/// SQL to add turns and the messages and tool calls written in them.
+pub const ADD_TURNS_SQL: &str =
+    include_str!("../../../migrations/2026-06-14-000000_add_turns/up.sql");
+
+/// SQL to let snapshots extend a parent snapshot.
+pub const ADD_INCREMENTAL_SNAPSHOTS_SQL: &str =
+    include_str!("../../../migrations/2026-06-16-000000_add_incremental_snapshots/up.sql");
+
+/// SQL to add per-conversation periodic snapshot policies.
+pub const ADD_PERIODIC_SNAPSHOTS_SQL: &str =
+    include_str!("../../../migrations/2026-06-18-000000_add_periodic_snapshots/up.sql");
+
+/// SQL to add versioned slash-command definitions.
+pub const ADD_SLASH_COMMAND_DEFINITIONS_SQL: &str =
+    include_str!("../../../migrations/2026-06-20-000000_add_slash_command_definitions/up.sql");
+
+/// SQL to add the slash-command execution audit trail.
+pub const ADD_SLASH_COMMAND_EXECUTIONS_SQL: &str =
+    include_str!("../../../migrations/2026-06-22-000000_add_slash_command_executions/up.sql");
+
+/// SQL to key handoff context packages by conversation.
+pub const ADD_HANDOFF_PACKAGE_CONVERSATIONS_SQL: &str =
+    include_str!("../../../migrations/2026-06-30-000000_add_handoff_package_conversations/up.sql");
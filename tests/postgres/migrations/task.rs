@@ -0,0 +1,14 @@
+//! Migration SQL fixtures for task webhook, notification, and scheduling
+//! tables.
+
+/// SQL to add claimed webhook deliveries.
+pub const ADD_WEBHOOK_DELIVERIES_SQL: &str =
+    include_str!("../../../migrations/2026-06-24-000000_add_webhook_deliveries/up.sql");
+
+/// SQL to queue task status updates for their originating issues.
+pub const ADD_ISSUE_STATUS_NOTIFICATIONS_SQL: &str =
+    include_str!("../../../migrations/2026-06-26-000000_add_issue_status_notifications/up.sql");
+
+/// SQL to add task priorities, due dates, and required capabilities.
+pub const ADD_TASK_SCHEDULING_SQL: &str =
+    include_str!("../../../migrations/2026-06-28-000000_add_task_scheduling/up.sql");
@@ -0,0 +1,151 @@
+//! `PostgreSQL` integration tests for operator action audit records.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use chrono::Duration;
+use corbusier::context::{RequestContext, UserId};
+use corbusier::message::domain::{ConversationId, HandoffId};
+use corbusier::operator::{
+    adapters::PostgresOperatorActionRepository,
+    domain::{
+        OperatorAction, OperatorActionDetails, OperatorActionId, OperatorActionKind,
+        OperatorActionQuery, OperatorReason, OperatorSubject, OperatorTransition,
+    },
+    ports::OperatorActionRepository,
+};
+use corbusier::pagination::PageRequest;
+use corbusier::task::domain::TaskId;
+use mockable::DefaultClock;
+use rstest::rstest;
+
+fn action(
+    ctx: &RequestContext,
+    kind: OperatorActionKind,
+    subject: OperatorSubject,
+    transition: Option<OperatorTransition>,
+) -> Result<OperatorAction, BoxError> {
+    let details = OperatorActionDetails {
+        kind,
+        subject,
+        reason: OperatorReason::new("stuck in a tool loop")?,
+        transition,
+    };
+    Ok(OperatorAction::new(ctx, details, &DefaultClock))
+}
+
+async fn timeline_ids(
+    repository: &PostgresOperatorActionRepository,
+    ctx: &RequestContext,
+    query: OperatorActionQuery,
+) -> Result<Vec<OperatorActionId>, BoxError> {
+    let page = repository.query(ctx, query, PageRequest::default()).await?;
+    Ok(page.iter().map(|recorded| recorded.id).collect())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_operator_actions_round_trip(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repository = PostgresOperatorActionRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let paused = action(
+        &ctx,
+        OperatorActionKind::ConversationPaused,
+        OperatorSubject::Conversation {
+            conversation_id: ConversationId::new(),
+            task_id: Some(TaskId::new()),
+        },
+        Some(OperatorTransition::new("active", "paused")),
+    )?;
+    repository.record(&ctx, &paused).await?;
+
+    let page = repository
+        .query(&ctx, OperatorActionQuery::default(), PageRequest::default())
+        .await?;
+
+    let [stored] = page.items() else {
+        return Err(format!("expected one action, got {}", page.len()).into());
+    };
+    assert_eq!(stored.id, paused.id);
+    assert_eq!(stored.kind, paused.kind);
+    assert_eq!(stored.subject, paused.subject);
+    assert_eq!(stored.reason, paused.reason);
+    assert_eq!(stored.actor, paused.actor);
+    assert_eq!(stored.correlation_id, paused.correlation_id);
+    assert_eq!(stored.transition, paused.transition);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_operator_action_timelines_filter_by_subject_actor_and_kind(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repository = PostgresOperatorActionRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let conversation_id = ConversationId::new();
+    let task_id = TaskId::new();
+    let paused = action(
+        &ctx,
+        OperatorActionKind::ConversationPaused,
+        OperatorSubject::Conversation {
+            conversation_id,
+            task_id: Some(task_id),
+        },
+        Some(OperatorTransition::new("active", "paused")),
+    )?;
+    let mut cancelled = action(
+        &ctx,
+        OperatorActionKind::HandoffCancelled,
+        OperatorSubject::Handoff {
+            handoff_id: HandoffId::new(),
+            conversation_id: Some(conversation_id),
+        },
+        Some(OperatorTransition::new("initiated", "cancelled")),
+    )?;
+    let mut forced = action(
+        &ctx,
+        OperatorActionKind::TaskForceTransitioned,
+        OperatorSubject::Task { task_id },
+        Some(OperatorTransition::new("abandoned", "in_progress")),
+    )?;
+    cancelled.occurred_at = paused.occurred_at + Duration::seconds(1);
+    forced.occurred_at = paused.occurred_at + Duration::seconds(2);
+    for recorded in [&paused, &cancelled, &forced] {
+        repository.record(&ctx, recorded).await?;
+    }
+
+    let conversation_query = OperatorActionQuery::for_conversation(conversation_id);
+    assert_eq!(
+        timeline_ids(&repository, &ctx, conversation_query).await?,
+        vec![paused.id, cancelled.id]
+    );
+    assert_eq!(
+        timeline_ids(&repository, &ctx, OperatorActionQuery::for_task(task_id)).await?,
+        vec![paused.id, forced.id]
+    );
+    assert_eq!(
+        timeline_ids(
+            &repository,
+            &ctx,
+            conversation_query.with_kind(OperatorActionKind::HandoffCancelled),
+        )
+        .await?,
+        vec![cancelled.id]
+    );
+    assert!(
+        timeline_ids(
+            &repository,
+            &ctx,
+            conversation_query.with_actor(UserId::new())
+        )
+        .await?
+        .is_empty()
+    );
+    Ok(())
+}
@@ -0,0 +1,99 @@
+//! `PostgreSQL` integration tests for optimistic concurrency on
+//! conversations and agent sessions.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::{PostgresAgentSessionRepository, PostgresConversationRepository},
+    domain::{AgentSession, AgentSessionState, Conversation, ConversationState, SequenceNumber},
+    ports::{
+        AgentSessionRepository, ConversationRepository, ConversationRepositoryError, SessionError,
+    },
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_rejects_stale_conversation_updates(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversations = PostgresConversationRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let conversation = Conversation::new(&DefaultClock);
+    conversations.store(&ctx, &conversation).await?;
+    let mut first = conversation.clone();
+    let mut second = conversation.clone();
+
+    first.pause(&DefaultClock)?;
+    conversations.update(&ctx, &first).await?;
+    second.complete(&DefaultClock)?;
+    let result = conversations.update(&ctx, &second).await;
+
+    assert!(matches!(
+        result,
+        Err(ConversationRepositoryError::ConcurrentModification {
+            conversation_id,
+            expected: 0,
+            actual: 1,
+        }) if conversation_id == conversation.id()
+    ));
+    let mut persisted = conversations
+        .find_by_id(&ctx, conversation.id())
+        .await?
+        .ok_or("conversation should exist")?;
+    assert_eq!(persisted.state(), ConversationState::Paused);
+    assert_eq!(persisted.version(), 1);
+
+    persisted.complete(&DefaultClock)?;
+    conversations.update(&ctx, &persisted).await?;
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_rejects_stale_session_updates(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let conversations = PostgresConversationRepository::new(pool.clone());
+    let sessions = PostgresAgentSessionRepository::new(pool);
+    let conversation = Conversation::new(&DefaultClock);
+    conversations.store(&ctx, &conversation).await?;
+    let session = AgentSession::new(
+        conversation.id(),
+        "codex_cli",
+        SequenceNumber::new(1),
+        &DefaultClock,
+    );
+    sessions.store(&ctx, &session).await?;
+    let mut first = session.clone();
+    let mut second = session.clone();
+
+    assert!(first.pause());
+    sessions.update(&ctx, &first).await?;
+    second.complete(SequenceNumber::new(5), &DefaultClock);
+    let result = sessions.update(&ctx, &second).await;
+
+    assert!(matches!(
+        result,
+        Err(SessionError::ConcurrentModification {
+            session_id,
+            expected: 0,
+            actual: 1,
+        }) if session_id == session.session_id
+    ));
+    let persisted = sessions
+        .find_by_id(&ctx, session.session_id)
+        .await?
+        .ok_or("session should exist")?;
+    assert_eq!(persisted.state, AgentSessionState::Paused);
+    assert_eq!(persisted.version, 1);
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! `PostgreSQL` integration tests for pinned messages.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    domain::{ContentPart, ConversationId, Message, MessageId, Role, SequenceNumber, TextPart},
+    error::RepositoryError,
+    ports::repository::MessageRepository,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+fn message(conversation_id: ConversationId, sequence: u64) -> Result<Message, BoxError> {
+    Ok(Message::new(
+        conversation_id,
+        Role::System,
+        vec![ContentPart::Text(TextPart::new(format!(
+            "Instruction {sequence}"
+        )))],
+        SequenceNumber::new(sequence),
+        &DefaultClock,
+    )?)
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_pins_persist_until_unpinned(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let first = message(conversation_id, 1)?;
+    let second = message(conversation_id, 2)?;
+    prep.repo.store(&ctx, &first).await?;
+    prep.repo.store(&ctx, &second).await?;
+
+    let pinned = prep.repo.set_pinned(&ctx, second.id(), true).await?;
+
+    assert!(pinned.is_pinned());
+    assert_eq!(pinned.content(), second.content());
+    let found = prep.repo.find_pinned(&ctx, conversation_id).await?;
+    assert_eq!(
+        found.iter().map(Message::id).collect::<Vec<_>>(),
+        vec![second.id()]
+    );
+    let stored = prep
+        .repo
+        .find_by_id(&ctx, second.id())
+        .await?
+        .ok_or("pinned message")?;
+    assert!(stored.is_pinned());
+
+    let unpinned = prep.repo.set_pinned(&ctx, second.id(), false).await?;
+
+    assert!(!unpinned.is_pinned());
+    assert!(
+        prep.repo
+            .find_pinned(&ctx, conversation_id)
+            .await?
+            .is_empty()
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_pinning_a_missing_message_fails(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let missing = MessageId::new();
+
+    let result = prep
+        .repo
+        .set_pinned(&test_request_context, missing, true)
+        .await;
+
+    assert!(matches!(result, Err(RepositoryError::NotFound(id)) if id == missing));
+    Ok(())
+}
@@ -0,0 +1,168 @@
+//! `PostgreSQL` integration tests for the domain event stream and projection
+//! checkpoints.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo};
+use chrono::Utc;
+use corbusier::message::adapters::{models::NewDomainEvent, schema::domain_events};
+use corbusier::message::versioning::UpgraderRegistry;
+use corbusier::projection::{
+    adapters::{
+        AggregateActivityProjection, PostgresDomainEventSource, PostgresProjectionCheckpointStore,
+    },
+    domain::{EventPosition, ProjectionName},
+    ports::{DomainEventSource, ProjectionCheckpointStore},
+    services::ProjectionRunner,
+};
+use diesel::prelude::*;
+use mockable::DefaultClock;
+use rstest::rstest;
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn new_event(aggregate_id: Uuid, event_type: &str) -> NewDomainEvent {
+    NewDomainEvent {
+        id: Uuid::new_v4(),
+        aggregate_id,
+        aggregate_type: "Conversation".to_owned(),
+        event_type: event_type.to_owned(),
+        event_data: json!({}),
+        event_version: 1,
+        occurred_at: Utc::now(),
+        correlation_id: None,
+        causation_id: None,
+        user_id: None,
+        session_id: None,
+    }
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_domain_events_are_read_in_position_order(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let aggregate_id = Uuid::new_v4();
+    let inserted = ["ConversationCreated", "MessageAppended", "MessageAppended"]
+        .map(|event_type| new_event(aggregate_id, event_type));
+    {
+        let mut conn = pool.get()?;
+        for event in &inserted {
+            diesel::insert_into(domain_events::table)
+                .values(event)
+                .execute(&mut conn)?;
+        }
+    }
+    let source = PostgresDomainEventSource::new(pool);
+
+    let all = source.read_after(EventPosition::START, 10).await?;
+    let rest = source
+        .read_after(
+            all.first()
+                .map_or(EventPosition::START, |event| event.position),
+            10,
+        )
+        .await?;
+
+    let ids: Vec<_> = all.iter().map(|event| event.id).collect();
+    assert_eq!(
+        ids,
+        inserted.iter().map(|event| event.id).collect::<Vec<_>>()
+    );
+    assert!(all.windows(2).all(|pair| match pair {
+        [earlier, later] => later.position == earlier.position.next(),
+        _ => false,
+    }));
+    assert_eq!(rest.len(), 2);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_projection_checkpoints_persist_runner_progress(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let aggregate_id = Uuid::new_v4();
+    {
+        let mut conn = pool.get()?;
+        for event_type in ["ConversationCreated", "MessageAppended"] {
+            diesel::insert_into(domain_events::table)
+                .values(&new_event(aggregate_id, event_type))
+                .execute(&mut conn)?;
+        }
+    }
+    let checkpoints = PostgresProjectionCheckpointStore::new(pool.clone());
+    let name = ProjectionName::new("aggregate_activity")?;
+    let activity = Arc::new(AggregateActivityProjection::new(name.clone()));
+    let runner = ProjectionRunner::new(
+        Arc::new(PostgresDomainEventSource::new(pool)),
+        Arc::new(checkpoints.clone()),
+        Arc::new(DefaultClock),
+    )
+    .with_projection(activity.clone());
+
+    runner.catch_up().await?;
+    let repeated = runner.catch_up().await?;
+
+    let stored = checkpoints
+        .load(&name)
+        .await?
+        .ok_or("checkpoint should be stored")?;
+    assert_eq!(stored.events_applied, 2);
+    assert_eq!(repeated.iter().map(|report| report.applied).sum::<u64>(), 0);
+    assert_eq!(checkpoints.list().await?, [stored]);
+    assert_eq!(
+        activity
+            .get("Conversation", aggregate_id)?
+            .map(|summary| summary.event_count),
+        Some(2)
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_domain_events_are_upgraded_on_read_and_written_back(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let mut stale = new_event(Uuid::new_v4(), "MessageCreated");
+    stale.event_data = json!({ "id": "1", "content": [] });
+    let current = new_event(Uuid::new_v4(), "ConversationCreated");
+    {
+        let mut conn = pool.get()?;
+        for event in [&stale, &current] {
+            diesel::insert_into(domain_events::table)
+                .values(event)
+                .execute(&mut conn)?;
+        }
+    }
+    let source = PostgresDomainEventSource::new(pool.clone())
+        .with_upgrades(Arc::new(UpgraderRegistry::new()));
+
+    let read = source.read_after(EventPosition::START, 10).await?;
+
+    let versions: Vec<(&str, u32)> = read
+        .iter()
+        .map(|event| (event.event_type.as_str(), event.event_version))
+        .collect();
+    assert_eq!(
+        versions,
+        [("MessageCreated", 3), ("ConversationCreated", 1)]
+    );
+    let (stored_version, stored_data) = {
+        let mut conn = pool.get()?;
+        domain_events::table
+            .find(stale.id)
+            .select((domain_events::event_version, domain_events::event_data))
+            .first::<(i32, serde_json::Value)>(&mut conn)?
+    };
+    assert_eq!(stored_version, 3);
+    assert_eq!(stored_data.get("metadata"), Some(&json!({})));
+    Ok(())
+}
@@ -0,0 +1,109 @@
+//! `PostgreSQL` integration tests for routing reads to a replica pool.
+//!
+//! The suite runs a single server, so the "replica" is a second pool on the
+//! same database: it reports no replay lag, and reads through it see every
+//! write made through the primary.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, clock, create_test_message, insert_conversation, prepared_repo,
+    test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::{PostgresMessageRepository, ReadReplica},
+    domain::ConversationId,
+    ports::repository::MessageRepository,
+};
+use corbusier::pagination::PageRequest;
+use diesel::PgConnection;
+use diesel::r2d2::{ConnectionManager, Pool};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::time::Duration;
+
+/// Stores two messages in a new conversation through the primary.
+async fn seed_conversation(
+    prep: &PreparedRepo,
+    clock: &DefaultClock,
+    ctx: &RequestContext,
+) -> Result<ConversationId, BoxError> {
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, ctx).await?;
+    for sequence in 1..=2 {
+        let message = create_test_message(clock, conversation_id, sequence)?;
+        prep.repo.store(ctx, &message).await?;
+    }
+    Ok(conversation_id)
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn history_reads_through_a_fresh_replica(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = seed_conversation(&prep, &clock, &ctx).await?;
+    let replica = ReadReplica::new(build_pool(prep.temp_db.url(), 1)?, Duration::ZERO);
+    let repository = PostgresMessageRepository::new(build_pool(prep.temp_db.url(), 1)?)
+        .with_read_replica(replica);
+
+    let history = repository
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
+        .await?;
+
+    assert_eq!(history.items().len(), 2);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn an_exhausted_replica_falls_back_to_the_primary(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = seed_conversation(&prep, &clock, &ctx).await?;
+    let replica_pool = build_pool(prep.temp_db.url(), 1)?;
+    let held = replica_pool.get()?;
+    let repository = PostgresMessageRepository::new(build_pool(prep.temp_db.url(), 1)?)
+        .with_read_replica(ReadReplica::new(replica_pool, Duration::from_secs(5)));
+
+    let history = repository
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
+        .await?;
+
+    assert_eq!(history.items().len(), 2);
+    drop(held);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn an_unreachable_replica_falls_back_to_the_primary(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = seed_conversation(&prep, &clock, &ctx).await?;
+    let manager = ConnectionManager::<PgConnection>::new("postgres://nobody@127.0.0.1:1/missing");
+    let unreachable = Pool::builder()
+        .max_size(1)
+        .connection_timeout(Duration::from_millis(200))
+        .build_unchecked(manager);
+    let repository = PostgresMessageRepository::new(build_pool(prep.temp_db.url(), 1)?)
+        .with_read_replica(ReadReplica::new(unreachable, Duration::from_secs(5)));
+
+    let usage = repository.token_usage(&ctx, conversation_id).await?;
+
+    assert_eq!(usage.message_count, 2);
+    assert_eq!(usage.counted_messages, 0);
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! `PostgreSQL` integration tests for message redaction.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    domain::{
+        ContentPart, ConversationId, Message, MessageRedaction, RedactionReason, Role,
+        SequenceNumber, TextPart,
+    },
+    error::RepositoryError,
+    ports::repository::MessageRepository,
+};
+use diesel::prelude::*;
+use mockable::DefaultClock;
+use rstest::rstest;
+
+const SECRET: &str = "sk-live-0123456789";
+
+#[derive(QueryableByName)]
+struct Tombstone {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    reason: String,
+    #[diesel(sql_type = diesel::sql_types::Int8)]
+    sequence_number: i64,
+    #[diesel(sql_type = diesel::sql_types::Uuid)]
+    redacted_by: uuid::Uuid,
+}
+
+#[derive(QueryableByName)]
+struct Count {
+    #[diesel(sql_type = diesel::sql_types::Int8)]
+    count: i64,
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_redaction_writes_tombstone_and_scrubs_audit_rows(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let message = Message::new(
+        conversation_id,
+        Role::User,
+        vec![ContentPart::Text(TextPart::new(format!(
+            "my key is {SECRET}"
+        )))],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )?;
+    prep.repo.store_with_audit(&ctx, &message).await?;
+    let redaction = MessageRedaction::new(
+        message.id(),
+        RedactionReason::new("pasted API key")?,
+        ctx.user_id(),
+        &DefaultClock,
+    );
+
+    let redacted = prep.repo.redact(&ctx, &redaction).await?;
+    let repeated = prep.repo.redact(&ctx, &redaction).await;
+
+    assert!(redacted.is_redacted());
+    assert_eq!(redacted.sequence_number(), SequenceNumber::new(1));
+    assert_eq!(
+        prep.repo.find_by_id(&ctx, message.id()).await?,
+        Some(redacted)
+    );
+    assert!(matches!(repeated, Err(RepositoryError::AlreadyRedacted(_))));
+
+    let url = prep.cluster.connection().database_url(prep.temp_db.name());
+    let mut conn = PgConnection::establish(&url).map_err(|err| Box::new(err) as BoxError)?;
+    let tombstone = diesel::sql_query(
+        "SELECT reason, sequence_number, redacted_by FROM message_redactions WHERE message_id = $1",
+    )
+    .bind::<diesel::sql_types::Uuid, _>(message.id().into_inner())
+    .get_result::<Tombstone>(&mut conn)
+    .map_err(|err| Box::new(err) as BoxError)?;
+    assert_eq!(tombstone.reason, "pasted API key");
+    assert_eq!(tombstone.sequence_number, 1);
+    assert_eq!(tombstone.redacted_by, ctx.user_id().into_inner());
+
+    let audit_rows = diesel::sql_query(concat!(
+        "SELECT count(*) AS count FROM audit_logs WHERE row_id = $1 ",
+        "AND (coalesce(old_values::text, '') LIKE $2 OR coalesce(new_values::text, '') LIKE $2)",
+    ))
+    .bind::<diesel::sql_types::Uuid, _>(message.id().into_inner())
+    .bind::<diesel::sql_types::Text, _>(format!("%{SECRET}%"))
+    .get_result::<Count>(&mut conn)
+    .map_err(|err| Box::new(err) as BoxError)?;
+    assert_eq!(audit_rows.count, 0, "audit rows still hold the secret");
+    Ok(())
+}
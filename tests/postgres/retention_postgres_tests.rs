@@ -0,0 +1,316 @@
+//! `PostgreSQL` integration tests for retention policies and purges.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::{
+        blob_store::ObjectStoreBlobStore, externalising::ExternalisingMessageRepository,
+        postgres::PgPool, schema::conversation_summaries,
+    },
+    domain::{
+        AttachmentPart, ContentHash, ContentPart, ConversationId, Message, MessageId,
+        RedactionReason, Role, SequenceNumber, TextPart,
+    },
+    ports::{BlobStore, repository::MessageRepository},
+};
+use corbusier::pagination::PageRequest;
+use corbusier::retention::{
+    adapters::PostgresRetentionRepository,
+    domain::{PurgeCounts, PurgeMode, PurgeOptions, RetentionPolicy},
+    ports::{RetentionError, RetentionPolicyRepository},
+    services::PurgeService,
+};
+use diesel::prelude::*;
+use diesel::sql_types::{Int8, Text, Uuid as SqlUuid};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const EVENT_COUNT_SQL: &str = "SELECT count(*) AS count FROM domain_events WHERE aggregate_id = $1 AND event_data::text LIKE $2";
+
+const SECRET: &str = "date of birth 1970-01-01";
+
+#[derive(QueryableByName)]
+struct Count {
+    #[diesel(sql_type = Int8)]
+    count: i64,
+}
+
+fn service(pool: &PgPool) -> PurgeService {
+    let repository = Arc::new(PostgresRetentionRepository::new(pool.clone()));
+    PurgeService::new(repository.clone(), repository, Arc::new(DefaultClock))
+}
+
+fn options() -> Result<PurgeOptions, BoxError> {
+    Ok(PurgeOptions::new(RedactionReason::new(
+        "retention expired",
+    )?))
+}
+
+/// Stores a message `age_days` old, with an event recorded against it.
+async fn store_aged_message(
+    prep: &PreparedRepo,
+    ctx: &RequestContext,
+    conversation_id: ConversationId,
+    age_days: i32,
+) -> Result<MessageId, BoxError> {
+    let sequence = prep.repo.next_sequence_number(ctx, conversation_id).await?;
+    let message = Message::new(
+        conversation_id,
+        Role::User,
+        vec![ContentPart::Text(TextPart::new(SECRET))],
+        sequence,
+        &DefaultClock,
+    )?;
+    prep.repo.store_with_audit(ctx, &message).await?;
+    let mut conn = PgConnection::establish(prep.temp_db.url())?;
+    diesel::sql_query(
+        "UPDATE messages SET created_at = created_at - make_interval(days => $2) WHERE id = $1",
+    )
+    .bind::<SqlUuid, _>(message.id().into_inner())
+    .bind::<diesel::sql_types::Int4, _>(age_days)
+    .execute(&mut conn)?;
+    diesel::sql_query(concat!(
+        "INSERT INTO domain_events (id, aggregate_id, aggregate_type, event_type, event_data, ",
+        "event_version, occurred_at, user_id) ",
+        "VALUES ($1, $2, 'Message', 'MessageCreated', jsonb_build_object('text', $3::text), ",
+        "1, NOW(), $4)",
+    ))
+    .bind::<SqlUuid, _>(Uuid::new_v4())
+    .bind::<SqlUuid, _>(message.id().into_inner())
+    .bind::<Text, _>(SECRET)
+    .bind::<SqlUuid, _>(ctx.user_id().into_inner())
+    .execute(&mut conn)?;
+    Ok(message.id())
+}
+
+fn count(pool: &PgPool, sql: &'static str, id: Uuid) -> Result<i64, BoxError> {
+    let mut conn = pool.get()?;
+    Ok(diesel::sql_query(sql)
+        .bind::<SqlUuid, _>(id)
+        .bind::<Text, _>(format!("%{SECRET}%"))
+        .get_result::<Count>(&mut conn)?
+        .count)
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_purge_deletes_expired_messages_events_and_audit_content(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let expired = store_aged_message(&prep, &ctx, conversation_id, 40).await?;
+    let recent = store_aged_message(&prep, &ctx, conversation_id, 1).await?;
+    let service = service(&pool).with_default_policy(RetentionPolicy::new(30, PurgeMode::Delete)?);
+
+    let rehearsal = service.purge(&ctx, &options()?.dry_run()).await?;
+    assert_eq!(
+        rehearsal.purged,
+        PurgeCounts {
+            messages: 1,
+            snapshots: 0,
+            events: 1,
+        }
+    );
+    assert!(prep.repo.find_by_id(&ctx, expired).await?.is_some());
+
+    let report = service.purge(&ctx, &options()?).await?;
+
+    assert_eq!(report.purged, rehearsal.purged);
+    assert!(prep.repo.find_by_id(&ctx, expired).await?.is_none());
+    assert!(prep.repo.find_by_id(&ctx, recent).await?.is_some());
+    assert_eq!(count(&pool, EVENT_COUNT_SQL, expired.into_inner(),)?, 0);
+    assert_eq!(
+        count(
+            &pool,
+            concat!(
+                "SELECT count(*) AS count FROM audit_logs WHERE row_id = $1 ",
+                "AND (coalesce(old_values::text, '') LIKE $2 ",
+                "OR coalesce(new_values::text, '') LIKE $2)",
+            ),
+            expired.into_inner(),
+        )?,
+        0,
+        "audit rows still hold the purged content"
+    );
+    let message_count = {
+        let mut conn = pool.get()?;
+        conversation_summaries::table
+            .filter(conversation_summaries::conversation_id.eq(conversation_id.into_inner()))
+            .select(conversation_summaries::message_count)
+            .first::<i64>(&mut conn)?
+    };
+    assert_eq!(message_count, 1);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_conversation_policies_override_the_default(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let governed = ConversationId::new();
+    let defaulted = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), governed, &ctx).await?;
+    insert_conversation(prep.cluster, prep.temp_db.name(), defaulted, &ctx).await?;
+    let governed_message = store_aged_message(&prep, &ctx, governed, 10).await?;
+    let defaulted_message = store_aged_message(&prep, &ctx, defaulted, 10).await?;
+    let own = RetentionPolicy::new(7, PurgeMode::Anonymise)?;
+    let service = service(&pool).with_default_policy(RetentionPolicy::new(365, PurgeMode::Delete)?);
+    service.set_policy(&ctx, governed, own).await?;
+
+    let report = service.purge(&ctx, &options()?.with_batch_size(1)).await?;
+
+    assert_eq!(report.policies, 2);
+    assert_eq!(report.purged.messages, 1);
+    assert_eq!(report.purged.events, 1);
+    let anonymised = prep
+        .repo
+        .find_by_id(&ctx, governed_message)
+        .await?
+        .ok_or("anonymised message is kept")?;
+    assert!(anonymised.is_redacted());
+    assert_eq!(
+        count(&pool, EVENT_COUNT_SQL, governed_message.into_inner(),)?,
+        0
+    );
+    let defaulted_kept = prep.repo.find_by_id(&ctx, defaulted_message).await?;
+    assert!(defaulted_kept.is_some_and(|message| !message.is_redacted()));
+    let rerun = service.purge(&ctx, &options()?).await?;
+    assert!(rerun.purged.is_empty());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_retention_policies_round_trip(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let repository = PostgresRetentionRepository::new(pool.clone());
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let service = service(&pool);
+
+    let retention = service
+        .set_policy(
+            &ctx,
+            conversation_id,
+            RetentionPolicy::new(90, PurgeMode::Delete)?,
+        )
+        .await?;
+    let replaced = service
+        .set_policy(
+            &ctx,
+            conversation_id,
+            RetentionPolicy::new(30, PurgeMode::Anonymise)?,
+        )
+        .await?;
+    let missing = service
+        .set_policy(
+            &ctx,
+            ConversationId::new(),
+            RetentionPolicy::new(30, PurgeMode::Delete)?,
+        )
+        .await;
+
+    assert_ne!(retention.policy, replaced.policy);
+    let found = repository
+        .find_policy(&ctx, conversation_id)
+        .await?
+        .ok_or("policy")?;
+    assert_eq!(found.policy, replaced.policy);
+    assert_eq!(found.set_by, ctx.user_id());
+    let listed = repository
+        .list_policies(&ctx, PageRequest::default())
+        .await?;
+    assert_eq!(listed.items().len(), 1);
+    assert!(matches!(
+        missing,
+        Err(RetentionError::ConversationNotFound(_))
+    ));
+    assert!(service.clear_policy(&ctx, conversation_id).await?);
+    assert!(
+        repository
+            .find_policy(&ctx, conversation_id)
+            .await?
+            .is_none()
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_purge_deletes_blobs_no_remaining_message_refers_to(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let blobs = Arc::new(ObjectStoreBlobStore::in_memory());
+    let messages = ExternalisingMessageRepository::new(
+        Arc::new(prep.repo.clone()),
+        Arc::clone(&blobs) as Arc<dyn BlobStore>,
+        1024,
+    );
+    let (shared, unique) = ("iVBORw0KGgo".repeat(500), "R0lGODlh".repeat(500));
+    let attachment = |data: &str| ContentPart::Attachment(AttachmentPart::new("image/png", data));
+    let expired = Message::new(
+        conversation_id,
+        Role::User,
+        vec![attachment(&shared), attachment(&unique)],
+        SequenceNumber::new(1),
+        &DefaultClock,
+    )?;
+    let recent = Message::new(
+        conversation_id,
+        Role::User,
+        vec![attachment(&shared)],
+        SequenceNumber::new(2),
+        &DefaultClock,
+    )?;
+    messages.store(&ctx, &expired).await?;
+    messages.store(&ctx, &recent).await?;
+    let mut conn = pool.get()?;
+    diesel::sql_query(
+        "UPDATE messages SET created_at = created_at - interval '40 days' WHERE id = $1",
+    )
+    .bind::<SqlUuid, _>(expired.id().into_inner())
+    .execute(&mut conn)?;
+    let repository = Arc::new(
+        PostgresRetentionRepository::new(pool.clone())
+            .with_blob_store(Arc::clone(&blobs) as Arc<dyn BlobStore>),
+    );
+    let service = PurgeService::new(repository.clone(), repository, Arc::new(DefaultClock))
+        .with_default_policy(RetentionPolicy::new(30, PurgeMode::Delete)?);
+
+    service.purge(&ctx, &options()?).await?;
+
+    let unique_hash = ContentHash::of(unique.as_bytes());
+    assert!(blobs.get(&ctx, &unique_hash).await?.is_none());
+    let found = messages
+        .find_by_id(&ctx, recent.id())
+        .await?
+        .ok_or("recent message")?;
+    assert_eq!(found, recent);
+    Ok(())
+}
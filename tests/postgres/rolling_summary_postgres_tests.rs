@@ -0,0 +1,51 @@
+//! `PostgreSQL` integration tests for rolling conversation summaries.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::PostgresRollingSummaryRepository,
+    domain::{ConversationId, RollingSummary, SequenceNumber},
+    ports::RollingSummaryRepository,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_rolling_summaries_replace_in_place(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repo = PostgresRollingSummaryRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    assert!(repo.find(&ctx, conversation_id).await?.is_none());
+
+    let mut summary = RollingSummary::recomputed(
+        conversation_id,
+        "The user reported a failing build.",
+        SequenceNumber::new(2),
+        &DefaultClock,
+    );
+    repo.store(&ctx, &summary).await?;
+    summary.extend(
+        "The user reported a failing build; the agent fixed it.",
+        SequenceNumber::new(4),
+        &DefaultClock,
+    );
+    repo.store(&ctx, &summary).await?;
+
+    let stored = repo
+        .find(&ctx, conversation_id)
+        .await?
+        .ok_or("summary should be stored")?;
+    assert_eq!(stored.text(), summary.text());
+    assert_eq!(stored.covered_through(), SequenceNumber::new(4));
+    assert_eq!(stored.incremental_updates(), 1);
+    Ok(())
+}
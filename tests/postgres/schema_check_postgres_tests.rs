@@ -0,0 +1,106 @@
+//! `PostgreSQL` integration tests for the startup schema compatibility check.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo};
+use corbusier::schema_check::{
+    EXPECTED_SCHEMA, SchemaCheckError, SchemaMismatch, verify_schema_compatibility,
+};
+use diesel::prelude::*;
+use diesel::sql_types::Text;
+use rstest::rstest;
+
+/// Records migration history the way `diesel migration run` does, omitting
+/// the migrations named in `skip`.
+fn record_history(url: &str, skip: &[&str]) -> Result<(), BoxError> {
+    let mut connection = PgConnection::establish(url)?;
+    diesel::sql_query(concat!(
+        "CREATE TABLE __diesel_schema_migrations (",
+        "version VARCHAR(50) PRIMARY KEY NOT NULL, ",
+        "run_on TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP)",
+    ))
+    .execute(&mut connection)?;
+    for migration in EXPECTED_SCHEMA.migrations() {
+        if skip.contains(&migration.name()) {
+            continue;
+        }
+        diesel::sql_query(
+            "INSERT INTO __diesel_schema_migrations (version) VALUES ($1) ON CONFLICT DO NOTHING",
+        )
+        .bind::<Text, _>(migration.version())
+        .execute(&mut connection)?;
+    }
+    Ok(())
+}
+
+async fn mismatches(prep: &PreparedRepo) -> Result<Vec<SchemaMismatch>, BoxError> {
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let result = tokio::task::spawn_blocking(move || verify_schema_compatibility(&pool)).await?;
+    match result {
+        Ok(()) => Ok(Vec::new()),
+        Err(SchemaCheckError::Incompatible(error)) => Ok(error.mismatches().to_vec()),
+        Err(other) => Err(other.into()),
+    }
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn migrated_schema_without_history_reports_only_missing_history(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+
+    assert_eq!(
+        mismatches(&prep).await?,
+        [SchemaMismatch::MigrationHistoryMissing]
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn fully_migrated_schema_is_compatible(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let url = prep.temp_db.url().to_owned();
+    tokio::task::spawn_blocking(move || record_history(&url, &[])).await??;
+
+    assert!(mismatches(&prep).await?.is_empty());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn missing_migrations_and_extra_columns_are_reported(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let latest = EXPECTED_SCHEMA
+        .migrations()
+        .last()
+        .ok_or("expected at least one migration")?
+        .name();
+    let url = prep.temp_db.url().to_owned();
+    tokio::task::spawn_blocking(move || -> Result<(), BoxError> {
+        record_history(&url, &[latest])?;
+        let mut connection = PgConnection::establish(&url)?;
+        diesel::sql_query("ALTER TABLE messages ADD COLUMN legacy_body TEXT")
+            .execute(&mut connection)?;
+        Ok(())
+    })
+    .await??;
+
+    assert_eq!(
+        mismatches(&prep).await?,
+        [
+            SchemaMismatch::MissingMigration {
+                name: latest.to_owned(),
+            },
+            SchemaMismatch::UnexpectedColumn {
+                table: "messages".to_owned(),
+                column: "legacy_body".to_owned(),
+            },
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! `PostgreSQL` integration tests for the secret injection audit log.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use chrono::{Duration, Utc};
+use corbusier::context::RequestContext;
+use corbusier::pagination::{Limit, PageRequest};
+use corbusier::tool_registry::{
+    adapters::postgres::PostgresSecretInjectionAudit,
+    domain::{McpServerId, McpServerName, SecretInjectionEvent, SecretReference},
+    ports::SecretInjectionAudit,
+};
+use rstest::rstest;
+
+fn event(
+    server_id: McpServerId,
+    variable: &str,
+    offset: Duration,
+) -> Result<SecretInjectionEvent, BoxError> {
+    Ok(SecretInjectionEvent {
+        server_id,
+        server_name: McpServerName::new("github")?,
+        variables: vec![(variable.to_owned(), SecretReference::new("github/token")?)],
+        injected_at: Utc::now() + offset,
+    })
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_secret_injections_are_listed_per_server_oldest_first(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let audit = PostgresSecretInjectionAudit::new(build_pool(prep.temp_db.url(), 1)?);
+    let server_id = McpServerId::new();
+    let first = event(server_id, "GITHUB_TOKEN", Duration::zero())?;
+    let second = event(server_id, "GH_TOKEN", Duration::seconds(1))?;
+    let elsewhere = event(McpServerId::new(), "GITHUB_TOKEN", Duration::zero())?;
+    for recorded in [&first, &second, &elsewhere] {
+        audit.record(&ctx, recorded).await?;
+    }
+
+    let page = audit
+        .events_for_server(&ctx, server_id, PageRequest::new(Limit::new(1)?))
+        .await?;
+    let cursor = page.next_cursor().expect("second page follows");
+    let next = PageRequest::new(Limit::new(1)?).with_cursor(cursor);
+    let rest = audit.events_for_server(&ctx, server_id, next).await?;
+
+    assert_eq!(page.items(), std::slice::from_ref(&first));
+    assert_eq!(rest.items(), std::slice::from_ref(&second));
+    assert!(rest.is_last());
+    Ok(())
+}
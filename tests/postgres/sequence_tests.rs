@@ -77,3 +77,59 @@ async fn next_sequence_number_returns_max_plus_one(
     assert_eq!(next.value(), 6);
     Ok(())
 }
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn concurrent_appends_allocate_distinct_sequence_numbers(
+    clock: DefaultClock,
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let cluster = postgres_cluster?;
+    ensure_template(cluster).await?;
+    let (temp_db, repo) = setup_repository(cluster).await?;
+
+    let ctx = test_request_context;
+    let conv_id = ConversationId::new();
+    insert_conversation(cluster, temp_db.name(), conv_id, &ctx).await?;
+    let drafts = (0..8)
+        .map(|_| create_test_message(&clock, conv_id, 1))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let appended =
+        futures::future::try_join_all(drafts.iter().map(|draft| repo.append(&ctx, draft))).await?;
+
+    let mut sequences: Vec<u64> = appended
+        .iter()
+        .map(|message| message.sequence_number().value())
+        .collect();
+    sequences.sort_unstable();
+    assert_eq!(sequences, (1..=8).collect::<Vec<_>>());
+    assert_eq!(repo.next_sequence_number(&ctx, conv_id).await?.value(), 9);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn append_rejects_missing_conversation(
+    clock: DefaultClock,
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let cluster = postgres_cluster?;
+    ensure_template(cluster).await?;
+    let (_temp_db, repo) = setup_repository(cluster).await?;
+
+    let ctx = test_request_context;
+    let conv_id = ConversationId::new();
+    let result = repo
+        .append(&ctx, &create_test_message(&clock, conv_id, 1)?)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(corbusier::message::error::RepositoryError::ConversationNotFound(id))
+            if id == conv_id
+    ));
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! Shared fixture for the serialization round-trip tests.
+
+use corbusier::context::RequestContext;
+use corbusier::message::adapters::postgres::PostgresMessageRepository;
+use mockable::DefaultClock;
+use rstest::fixture;
+
+use crate::postgres::cluster::TemporaryDatabase;
+use crate::postgres::helpers::{
+    BoxError, PostgresCluster, clock, ensure_template, postgres_cluster, setup_repository,
+    test_request_context,
+};
+
+/// Composite fixture bundling the common test infrastructure so that
+/// individual test functions stay below the clippy `too_many_arguments`
+/// threshold (project limit is 4).
+pub struct TestEnv {
+    pub cluster: PostgresCluster,
+    pub temp_db: TemporaryDatabase,
+    pub repo: PostgresMessageRepository,
+    pub clock: DefaultClock,
+    pub ctx: RequestContext,
+}
+
+#[fixture]
+pub async fn test_env(
+    postgres_cluster: Result<PostgresCluster, BoxError>,
+    clock: DefaultClock,
+    test_request_context: RequestContext,
+) -> Result<TestEnv, BoxError> {
+    let cluster = postgres_cluster?;
+    ensure_template(cluster).await?;
+    let (temp_db, repo) = setup_repository(cluster).await?;
+    Ok(TestEnv {
+        cluster,
+        temp_db,
+        repo,
+        clock,
+        ctx: test_request_context,
+    })
+}
@@ -0,0 +1,104 @@
+//! Domain invariants and identifiers preserved through `PostgreSQL`.
+
+use corbusier::message::{
+    domain::{ContentPart, ConversationId, Message, MessageId, Role, SequenceNumber, TextPart},
+    ports::repository::MessageRepository,
+};
+use rstest::rstest;
+
+use super::common::{TestEnv, test_env};
+use crate::postgres::helpers::{BoxError, insert_conversation};
+
+// ============================================================================
+// Domain Invariant Tests
+// ============================================================================
+
+#[rstest]
+#[tokio::test]
+async fn from_persisted_preserves_all_domain_invariants(
+    #[future] test_env: Result<TestEnv, BoxError>,
+) -> Result<(), BoxError> {
+    let env = test_env.await?;
+
+    let conv_id = ConversationId::new();
+    insert_conversation(env.cluster, env.temp_db.name(), conv_id, &env.ctx).await?;
+
+    let original = Message::new(
+        conv_id,
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("Test content"))],
+        SequenceNumber::new(42),
+        &env.clock,
+    )?;
+
+    env.repo.store(&env.ctx, &original).await?;
+
+    let retrieved = env
+        .repo
+        .find_by_id(&env.ctx, original.id())
+        .await?
+        .expect("message should exist");
+
+    assert!(
+        !retrieved.id().into_inner().is_nil(),
+        "ID should not be nil"
+    );
+    assert!(
+        !retrieved.conversation_id().into_inner().is_nil(),
+        "Conversation ID should not be nil"
+    );
+    assert!(
+        !retrieved.content().is_empty(),
+        "Content should not be empty"
+    );
+    assert_eq!(retrieved.sequence_number().value(), 42);
+
+    // Tighter tolerance for local database operations
+    let time_diff = (original.created_at() - retrieved.created_at())
+        .num_milliseconds()
+        .abs();
+    assert!(
+        time_diff < 100,
+        "Timestamp should be preserved within 100ms, diff was {time_diff}ms"
+    );
+    Ok(())
+}
+
+// ============================================================================
+// UUID Handling Tests
+// ============================================================================
+
+#[rstest]
+#[tokio::test]
+async fn uuid_round_trip_preserves_values(
+    #[future] test_env: Result<TestEnv, BoxError>,
+) -> Result<(), BoxError> {
+    let env = test_env.await?;
+
+    let conv_id = ConversationId::new();
+    insert_conversation(env.cluster, env.temp_db.name(), conv_id, &env.ctx).await?;
+
+    let specific_msg_id = MessageId::from_uuid(uuid::Uuid::parse_str(
+        "550e8400-e29b-41d4-a716-446655440000",
+    )?);
+
+    let message = Message::builder(conv_id, Role::User, SequenceNumber::new(1))
+        .with_id(specific_msg_id)
+        .with_content(ContentPart::Text(TextPart::new("UUID test")))
+        .build(&env.clock)?;
+
+    env.repo.store(&env.ctx, &message).await?;
+
+    let retrieved = env
+        .repo
+        .find_by_id(&env.ctx, specific_msg_id)
+        .await?
+        .expect("message should exist");
+
+    assert_eq!(
+        retrieved.id().into_inner().to_string(),
+        "550e8400-e29b-41d4-a716-446655440000"
+    );
+    assert_eq!(retrieved.conversation_id(), conv_id);
+    Ok(())
+}
@@ -0,0 +1,7 @@
+//! Serialization round-trip tests for `PostgreSQL` message repository.
+//!
+//! Tests role parsing, JSONB content, metadata, and UUID handling.
+
+mod common;
+mod identity_tests;
+mod round_trip_tests;
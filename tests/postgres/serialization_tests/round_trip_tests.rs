@@ -1,55 +1,18 @@
-//! Serialization round-trip tests for `PostgreSQL` message repository.
-//!
-//! Tests role parsing, JSONB content, metadata, and UUID handling.
+//! Role, JSONB content, and metadata round-trips through `PostgreSQL`.
 
-use crate::postgres::helpers::{
-    BoxError, PostgresCluster, RoleResult, clock, ensure_template, insert_conversation,
-    postgres_cluster, setup_repository, test_request_context,
-};
-use corbusier::context::RequestContext;
 use corbusier::message::{
-    adapters::postgres::PostgresMessageRepository,
     domain::{
-        AgentResponseAudit, AgentResponseStatus, AttachmentPart, ContentPart, ConversationId,
-        Message, MessageId, MessageMetadata, Role, SequenceNumber, TextPart, ToolCallAudit,
-        ToolCallPart, ToolCallStatus, ToolResultPart,
+        AgentResponseAudit, AgentResponseStatus, AttachmentPart, CitationPart, CitationSource,
+        CitationSpan, ContentPart, ConversationId, Message, MessageId, MessageMetadata, Role,
+        SequenceNumber, TextPart, ToolCallAudit, ToolCallPart, ToolCallStatus, ToolResultPart,
     },
     ports::repository::MessageRepository,
 };
 use diesel::prelude::*;
-use mockable::DefaultClock;
-use rstest::{fixture, rstest};
-
-use super::cluster::TemporaryDatabase;
-
-/// Composite fixture bundling the common test infrastructure so that
-/// individual test functions stay below the clippy `too_many_arguments`
-/// threshold (project limit is 4).
-struct TestEnv {
-    cluster: PostgresCluster,
-    temp_db: TemporaryDatabase,
-    repo: PostgresMessageRepository,
-    clock: DefaultClock,
-    ctx: RequestContext,
-}
+use rstest::rstest;
 
-#[fixture]
-async fn test_env(
-    postgres_cluster: Result<PostgresCluster, BoxError>,
-    clock: DefaultClock,
-    test_request_context: RequestContext,
-) -> Result<TestEnv, BoxError> {
-    let cluster = postgres_cluster?;
-    ensure_template(cluster).await?;
-    let (temp_db, repo) = setup_repository(cluster).await?;
-    Ok(TestEnv {
-        cluster,
-        temp_db,
-        repo,
-        clock,
-        ctx: test_request_context,
-    })
-}
+use super::common::{TestEnv, test_env};
+use crate::postgres::helpers::{BoxError, RoleResult, insert_conversation};
 
 // ============================================================================
 // Role Round-Trip Tests
@@ -235,6 +198,50 @@ async fn tool_result_jsonb_round_trip(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn citation_jsonb_round_trip(
+    #[future] test_env: Result<TestEnv, BoxError>,
+) -> Result<(), BoxError> {
+    let env = test_env.await?;
+
+    let conv_id = ConversationId::new();
+    insert_conversation(env.cluster, env.temp_db.name(), conv_id, &env.ctx).await?;
+
+    let content = vec![
+        ContentPart::Text(TextPart::new("Postgres stores JSONB in a binary format.")),
+        ContentPart::Citation(
+            CitationPart::new(
+                CitationSource::uri("https://www.postgresql.org/docs/current/datatype-json.html"),
+                vec![CitationSpan::new(0, 0, 41)],
+            )
+            .with_title("JSON Types"),
+        ),
+        ContentPart::Citation(CitationPart::new(
+            CitationSource::message(MessageId::new()),
+            vec![CitationSpan::new(0, 16, 21)],
+        )),
+    ];
+    let message = Message::new(
+        conv_id,
+        Role::Assistant,
+        content.clone(),
+        SequenceNumber::new(1),
+        &env.clock,
+    )?;
+
+    env.repo.store(&env.ctx, &message).await?;
+
+    let retrieved = env
+        .repo
+        .find_by_id(&env.ctx, message.id())
+        .await?
+        .expect("message should exist");
+
+    assert_eq!(retrieved.content(), content.as_slice());
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn metadata_jsonb_round_trip(
@@ -290,97 +297,3 @@ async fn metadata_jsonb_round_trip(
     );
     Ok(())
 }
-
-// ============================================================================
-// Domain Invariant Tests
-// ============================================================================
-
-#[rstest]
-#[tokio::test]
-async fn from_persisted_preserves_all_domain_invariants(
-    #[future] test_env: Result<TestEnv, BoxError>,
-) -> Result<(), BoxError> {
-    let env = test_env.await?;
-
-    let conv_id = ConversationId::new();
-    insert_conversation(env.cluster, env.temp_db.name(), conv_id, &env.ctx).await?;
-
-    let original = Message::new(
-        conv_id,
-        Role::User,
-        vec![ContentPart::Text(TextPart::new("Test content"))],
-        SequenceNumber::new(42),
-        &env.clock,
-    )?;
-
-    env.repo.store(&env.ctx, &original).await?;
-
-    let retrieved = env
-        .repo
-        .find_by_id(&env.ctx, original.id())
-        .await?
-        .expect("message should exist");
-
-    assert!(
-        !retrieved.id().into_inner().is_nil(),
-        "ID should not be nil"
-    );
-    assert!(
-        !retrieved.conversation_id().into_inner().is_nil(),
-        "Conversation ID should not be nil"
-    );
-    assert!(
-        !retrieved.content().is_empty(),
-        "Content should not be empty"
-    );
-    assert_eq!(retrieved.sequence_number().value(), 42);
-
-    // Tighter tolerance for local database operations
-    let time_diff = (original.created_at() - retrieved.created_at())
-        .num_milliseconds()
-        .abs();
-    assert!(
-        time_diff < 100,
-        "Timestamp should be preserved within 100ms, diff was {time_diff}ms"
-    );
-    Ok(())
-}
-
-// ============================================================================
-// UUID Handling Tests
-// ============================================================================
-
-#[rstest]
-#[tokio::test]
-async fn uuid_round_trip_preserves_values(
-    #[future] test_env: Result<TestEnv, BoxError>,
-) -> Result<(), BoxError> {
-    let env = test_env.await?;
-
-    let conv_id = ConversationId::new();
-    insert_conversation(env.cluster, env.temp_db.name(), conv_id, &env.ctx).await?;
-
-    let specific_msg_id = MessageId::from_uuid(uuid::Uuid::parse_str(
-        "550e8400-e29b-41d4-a716-446655440000",
-    )?);
-
-    let message = Message::builder(conv_id, Role::User, SequenceNumber::new(1))
-        .with_id(specific_msg_id)
-        .with_content(ContentPart::Text(TextPart::new("UUID test")))
-        .build(&env.clock)?;
-
-    env.repo.store(&env.ctx, &message).await?;
-
-    let retrieved = env
-        .repo
-        .find_by_id(&env.ctx, specific_msg_id)
-        .await?
-        .expect("message should exist");
-
-    assert_eq!(
-        retrieved.id().into_inner().to_string(),
-        "550e8400-e29b-41d4-a716-446655440000"
-    );
-    assert_eq!(retrieved.conversation_id(), conv_id);
-    Ok(())
-}
@@ -0,0 +1,97 @@
+//! `PostgreSQL` integration tests for versioned slash-command definitions.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::PostgresSlashCommandDefinitionRepository,
+    domain::{
+        CommandParameterSpec, CommandParameterType, SlashCommandDefinition, ToolCallTemplate,
+    },
+    ports::{SlashCommandDefinitionError, SlashCommandDefinitionRepository},
+    services::SlashCommandCatalog,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+fn deploy(description: &str) -> SlashCommandDefinition {
+    SlashCommandDefinition::new("deploy", description, "Deploy {{ env }}.")
+        .with_parameter(
+            CommandParameterSpec::new("env", CommandParameterType::Select, true)
+                .with_options(["staging", "production"]),
+        )
+        .with_tool_call(ToolCallTemplate::new(
+            "deployer",
+            "{\"env\":{{ env | json_string }}}",
+        ))
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_slash_command_versions_round_trip(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repo = Arc::new(PostgresSlashCommandDefinitionRepository::new(build_pool(
+        prep.temp_db.url(),
+        1,
+    )?));
+    let catalog = SlashCommandCatalog::new(Arc::clone(&repo), Arc::new(DefaultClock));
+    let review = SlashCommandDefinition::new("review", "Review", "Review.");
+
+    let first = catalog.register(&ctx, deploy("first")).await?;
+    let second = catalog.register(&ctx, deploy("second")).await?;
+    catalog.register(&ctx, review).await?;
+
+    let version = NonZeroU32::new(1).ok_or("zero version")?;
+    let stored_first = repo.find_version(&ctx, "deploy", version).await?;
+    let latest = repo.find_latest(&ctx, "Deploy").await?;
+    let listed: Vec<_> = repo
+        .list_latest(&ctx)
+        .await?
+        .into_iter()
+        .map(|command| (command.definition.command, command.version.get()))
+        .collect();
+    assert_eq!(
+        stored_first.map(|command| command.definition),
+        Some(first.definition)
+    );
+    assert_eq!(
+        latest.map(|command| command.definition),
+        Some(second.definition)
+    );
+    assert_eq!(listed, [("deploy".to_owned(), 2), ("review".to_owned(), 1)]);
+    assert_eq!(repo.history(&ctx, "deploy").await?.len(), 2);
+    assert_eq!(repo.remove(&ctx, "deploy").await?, 2);
+    assert!(repo.find_latest(&ctx, "deploy").await?.is_none());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_slash_command_versions_must_follow_the_latest(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repo = Arc::new(PostgresSlashCommandDefinitionRepository::new(build_pool(
+        prep.temp_db.url(),
+        1,
+    )?));
+    let catalog = SlashCommandCatalog::new(Arc::clone(&repo), Arc::new(DefaultClock));
+    let first = catalog.register(&ctx, deploy("first")).await?;
+
+    let result = repo.append(&ctx, &first).await;
+
+    assert!(matches!(
+        result,
+        Err(SlashCommandDefinitionError::VersionConflict { version, .. })
+            if version == first.version
+    ));
+    Ok(())
+}
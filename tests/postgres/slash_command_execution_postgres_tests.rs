@@ -0,0 +1,66 @@
+//! `PostgreSQL` integration tests for the slash-command audit trail.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::{
+        memory::InMemorySlashCommandRegistry, postgres::PostgresSlashCommandExecutionRepository,
+    },
+    domain::{ConversationId, SlashCommandDefinition},
+    ports::{SlashCommandExecutionError, SlashCommandExecutionRepository},
+    services::{SlashCommandAuditTrail, SlashCommandService},
+};
+use corbusier::pagination::PageRequest;
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_slash_command_executions_round_trip_per_conversation(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let repository = Arc::new(PostgresSlashCommandExecutionRepository::new(build_pool(
+        prep.temp_db.url(),
+        1,
+    )?));
+    let audit = SlashCommandAuditTrail::new(Arc::clone(&repository), Arc::new(DefaultClock));
+    let registry = InMemorySlashCommandRegistry::with_commands(vec![
+        SlashCommandDefinition::new("review", "Review", "Review the branch.").with_alias("r"),
+    ])?;
+    let commands = SlashCommandService::new(Arc::new(registry));
+
+    let first = audit
+        .record(&ctx, conversation_id, commands.execute("/review")?)
+        .await?;
+    let second = audit
+        .record(&ctx, conversation_id, commands.execute("/r")?)
+        .await?;
+    let duplicate = repository.store(&ctx, &first).await;
+    let history = audit
+        .for_conversation(&ctx, conversation_id, PageRequest::default())
+        .await?;
+    let linked = second
+        .expansion()
+        .execution_id
+        .ok_or("expansion not linked")?;
+
+    let found = audit.find(&ctx, linked).await?.ok_or("record not found")?;
+    let ids: Vec<_> = history.items().iter().map(|record| record.id()).collect();
+    assert_eq!(ids, [first.id(), second.id()]);
+    assert_eq!(found.execution(), second.execution());
+    assert_eq!(found.invoked_by(), ctx.user_id());
+    assert!(matches!(
+        duplicate,
+        Err(SlashCommandExecutionError::Duplicate(id)) if id == first.id()
+    ));
+    Ok(())
+}
@@ -16,6 +16,7 @@ use corbusier::message::{
     ports::repository::MessageRepository,
     services::SlashCommandService,
 };
+use corbusier::pagination::PageRequest;
 use mockable::DefaultClock;
 use rstest::rstest;
 
@@ -56,7 +57,7 @@ async fn slash_command_metadata_round_trip_postgres(
     repo.store(&ctx, &message).await?;
 
     let stored = repo
-        .find_by_conversation(&ctx, conversation_id)
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
         .await?
         .first()
         .cloned()
@@ -128,7 +129,9 @@ async fn slash_command_unknown_command_returns_error(
         if command == "nonexistent"
     ));
 
-    let stored_messages = repo.find_by_conversation(&ctx, conversation_id).await?;
+    let stored_messages = repo
+        .find_by_conversation(&ctx, conversation_id, PageRequest::default())
+        .await?;
     assert!(stored_messages.is_empty());
     Ok(())
 }
@@ -0,0 +1,72 @@
+//! `PostgreSQL` integration tests for periodic snapshot policies.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::PostgresSnapshotPolicyRepository,
+    domain::{ConversationId, PeriodicSnapshotPolicy, SnapshotInterval},
+    ports::{SnapshotPolicyError, SnapshotPolicyRepository},
+};
+use rstest::rstest;
+use std::num::NonZeroU32;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_snapshot_policies_replace_and_clear(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repo = PostgresSnapshotPolicyRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    let every = |value| NonZeroU32::new(value).ok_or("zero interval");
+
+    let first = SnapshotInterval::new()
+        .every_messages(every(20)?)
+        .every_minutes(every(15)?);
+    repo.set_policy(&ctx, &PeriodicSnapshotPolicy::new(conversation_id, first))
+        .await?;
+    let second = SnapshotInterval::new().every_minutes(every(5)?);
+    repo.set_policy(&ctx, &PeriodicSnapshotPolicy::new(conversation_id, second))
+        .await?;
+
+    let stored = repo
+        .find_policy(&ctx, conversation_id)
+        .await?
+        .ok_or("policy should be stored")?;
+    assert_eq!(stored.interval, second);
+    assert!(repo.clear_policy(&ctx, conversation_id).await?);
+    assert!(!repo.clear_policy(&ctx, conversation_id).await?);
+    assert!(repo.find_policy(&ctx, conversation_id).await?.is_none());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_snapshot_policies_need_a_conversation(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let repo = PostgresSnapshotPolicyRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let conversation_id = ConversationId::new();
+    let interval = SnapshotInterval::new().every_minutes(NonZeroU32::MIN);
+
+    let result = repo
+        .set_policy(
+            &test_request_context,
+            &PeriodicSnapshotPolicy::new(conversation_id, interval),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SnapshotPolicyError::ConversationNotFound(id)) if id == conversation_id
+    ));
+    Ok(())
+}
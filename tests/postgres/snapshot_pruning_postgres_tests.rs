@@ -0,0 +1,135 @@
+//! `PostgreSQL` integration tests for deleting and pruning context snapshots.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, clock, insert_conversation, prepared_repo, test_request_context,
+};
+use chrono::{Duration, Utc};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::{PostgresAgentSessionRepository, PostgresContextSnapshotAdapter},
+    domain::{
+        AgentSession, ContextWindowSnapshot, ConversationId, MessageSummary, SequenceNumber,
+        SequenceRange, SnapshotParams, SnapshotType,
+    },
+    ports::{
+        agent_session::AgentSessionRepository,
+        context_snapshot::{ContextSnapshotPort, SnapshotError},
+    },
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+/// A snapshot of `session` captured `days_ago` days ago.
+fn captured(
+    clock: &DefaultClock,
+    session: &AgentSession,
+    snapshot_type: SnapshotType,
+    days_ago: i64,
+) -> ContextWindowSnapshot {
+    let snapshot = ContextWindowSnapshot::new(
+        SnapshotParams {
+            conversation_id: session.conversation_id,
+            session_id: session.session_id,
+            sequence_range: SequenceRange::new(SequenceNumber::new(1), SequenceNumber::new(4)),
+            message_summary: MessageSummary::default(),
+            snapshot_type,
+        },
+        clock,
+    );
+    ContextWindowSnapshot {
+        captured_at: snapshot.captured_at - Duration::days(days_ago),
+        ..snapshot
+    }
+}
+
+async fn stored_session(
+    prep: &PreparedRepo,
+    ctx: &RequestContext,
+    clock: &DefaultClock,
+) -> Result<(PostgresContextSnapshotAdapter, AgentSession), BoxError> {
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, ctx).await?;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let session = AgentSession::new(conversation_id, "claude", SequenceNumber::new(1), clock);
+    PostgresAgentSessionRepository::new(pool.clone())
+        .store(ctx, &session)
+        .await?;
+    Ok((PostgresContextSnapshotAdapter::new(pool), session))
+}
+
+#[rstest]
+#[tokio::test]
+async fn postgres_deleting_a_snapshot_deletes_its_increments(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let (snapshots, session) = stored_session(&prep, &ctx, &clock).await?;
+    let root = captured(&clock, &session, SnapshotType::Checkpoint, 2);
+    let increment =
+        captured(&clock, &session, SnapshotType::Checkpoint, 1).with_parent(root.snapshot_id);
+    snapshots.store_snapshot(&ctx, &root).await?;
+    snapshots.store_snapshot(&ctx, &increment).await?;
+
+    snapshots.delete_snapshot(&ctx, root.snapshot_id).await?;
+
+    assert!(
+        snapshots
+            .find_by_id(&ctx, increment.snapshot_id)
+            .await?
+            .is_none()
+    );
+    let again = snapshots.delete_snapshot(&ctx, root.snapshot_id).await;
+    assert!(matches!(again, Err(SnapshotError::NotFound(id)) if id == root.snapshot_id));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn postgres_pruning_keeps_the_ancestors_of_remaining_snapshots(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let (snapshots, session) = stored_session(&prep, &ctx, &clock).await?;
+    let expired = captured(&clock, &session, SnapshotType::Periodic, 40);
+    let ancestor = captured(&clock, &session, SnapshotType::Periodic, 39);
+    let extended =
+        captured(&clock, &session, SnapshotType::Periodic, 38).with_parent(ancestor.snapshot_id);
+    let handoff = captured(&clock, &session, SnapshotType::HandoffInitiated, 37)
+        .with_parent(extended.snapshot_id);
+    let recent = captured(&clock, &session, SnapshotType::Periodic, 1);
+    for snapshot in [&expired, &ancestor, &extended, &handoff, &recent] {
+        snapshots.store_snapshot(&ctx, snapshot).await?;
+    }
+
+    let deleted = snapshots
+        .prune_before(
+            &ctx,
+            SnapshotType::Periodic,
+            Utc::now() - Duration::days(30),
+        )
+        .await?;
+
+    assert_eq!(deleted, 1);
+    assert!(
+        snapshots
+            .find_by_id(&ctx, expired.snapshot_id)
+            .await?
+            .is_none()
+    );
+    for kept in [&ancestor, &extended, &handoff, &recent] {
+        assert!(
+            snapshots
+                .find_by_id(&ctx, kept.snapshot_id)
+                .await?
+                .is_some()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! `PostgreSQL` integration tests for streamed assistant message staging.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use chrono::{TimeDelta, Utc};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    adapters::postgres::{PostgresMessageRepository, PostgresPartialMessageRepository},
+    domain::{ConversationId, MessageMetadata, Role, SequenceNumber, StreamChunk},
+    ports::{
+        MessageRepository,
+        streaming::{PartialMessageRepository, StreamingError},
+    },
+    services::StreamingMessageBuilder,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_streams_are_staged_and_finalised_atomically(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let messages = PostgresMessageRepository::new(pool.clone());
+    let streams = Arc::new(PostgresPartialMessageRepository::new(pool));
+    let builder = StreamingMessageBuilder::new(Arc::clone(&streams), Arc::new(DefaultClock));
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+
+    let started = builder
+        .start(&ctx, conversation_id, MessageMetadata::empty())
+        .await?;
+    builder
+        .push(&ctx, started.message_id, &StreamChunk::new(0, "Deploying "))
+        .await?;
+    let partial = builder
+        .push(&ctx, started.message_id, &StreamChunk::new(1, "now."))
+        .await?;
+    let staged = streams.find(&ctx, started.message_id).await?;
+    assert_eq!(
+        staged.map(|stream| (stream.content, stream.chunk_count)),
+        Some((partial.content, partial.chunk_count))
+    );
+    let stale = streams.update(&ctx, &started, 0).await;
+    assert!(matches!(stale, Err(StreamingError::Conflict(id)) if id == started.message_id));
+    let stalled = streams
+        .find_stalled(&ctx, Utc::now() + TimeDelta::seconds(1), 10)
+        .await?;
+    assert_eq!(stalled.len(), 1);
+
+    let message = builder.finish(&ctx, started.message_id).await?;
+
+    assert_eq!(message.role(), Role::Assistant);
+    assert_eq!(message.sequence_number(), SequenceNumber::new(1));
+    let stored = messages.find_by_id(&ctx, message.id()).await?;
+    assert_eq!(
+        stored.map(|stored| stored.content().to_vec()),
+        Some(message.content().to_vec())
+    );
+    assert_eq!(streams.find(&ctx, started.message_id).await?, None);
+    let abandoned = builder
+        .start(&ctx, conversation_id, MessageMetadata::empty())
+        .await?;
+    builder.abandon(&ctx, abandoned.message_id).await?;
+    assert_eq!(streams.find(&ctx, abandoned.message_id).await?, None);
+    Ok(())
+}
@@ -1,6 +1,7 @@
 //! `PostgreSQL` integration tests for branch and PR association.
 
 use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
 use corbusier::task::{
     adapters::postgres::{PostgresTaskRepository, TaskPgPool},
     domain::{BranchRef, PullRequestRef, TaskId, TaskState},
@@ -173,7 +174,7 @@ async fn postgres_find_by_branch_ref_returns_matching_tasks(
     let branch_ref = BranchRef::from_parts("github", "corbusier/core", "feature/find-test")
         .expect("valid branch ref");
     let found = service
-        .find_by_branch_ref(&ctx, &branch_ref)
+        .find_by_branch_ref(&ctx, &branch_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_eq!(found.len(), 1);
@@ -202,7 +203,7 @@ async fn postgres_find_by_pr_ref_returns_matching_tasks(
 
     let pr_ref = PullRequestRef::from_parts("github", "corbusier/core", 88).expect("valid PR ref");
     let found = service
-        .find_by_pull_request_ref(&ctx, &pr_ref)
+        .find_by_pull_request_ref(&ctx, &pr_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_eq!(found.len(), 1);
@@ -268,7 +269,7 @@ async fn postgres_two_tasks_sharing_branch_both_returned(
     let branch_ref = BranchRef::from_parts("github", "corbusier/core", "shared/pg-branch")
         .expect("valid branch ref");
     let found = service
-        .find_by_branch_ref(&ctx, &branch_ref)
+        .find_by_branch_ref(&ctx, &branch_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_eq!(found.len(), 2);
@@ -311,7 +312,7 @@ async fn postgres_two_tasks_sharing_pull_request_both_returned(
 
     let pr_ref = PullRequestRef::from_parts("github", "corbusier/core", 99).expect("valid PR ref");
     let found = service
-        .find_by_pull_request_ref(&ctx, &pr_ref)
+        .find_by_pull_request_ref(&ctx, &pr_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_eq!(found.len(), 2);
@@ -337,7 +338,7 @@ async fn postgres_find_by_branch_ref_returns_empty_when_none_match(
     let branch_ref = BranchRef::from_parts("github", "corbusier/core", "no-such/branch")
         .expect("valid branch ref");
     let found = service
-        .find_by_branch_ref(&ctx, &branch_ref)
+        .find_by_branch_ref(&ctx, &branch_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert!(
@@ -360,7 +361,7 @@ async fn postgres_find_by_pr_ref_returns_empty_when_none_match(
     let pr_ref =
         PullRequestRef::from_parts("github", "corbusier/core", 9999).expect("valid PR ref");
     let found = service
-        .find_by_pull_request_ref(&ctx, &pr_ref)
+        .find_by_pull_request_ref(&ctx, &pr_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert!(
@@ -0,0 +1,131 @@
+//! `PostgreSQL` integration tests for turn-level task cost attribution.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::domain::{ConversationId, TurnId};
+use corbusier::task::{
+    adapters::postgres::{PostgresTaskCostLedger, PostgresTaskRepository},
+    domain::{ConversationLinkKind, CostMicros, TokenUsage, UsageRecordParams},
+    ports::TaskCostError,
+    services::{
+        CreateTaskFromIssueRequest, LinkSubConversationRequest, TaskCostService,
+        TaskLifecycleService,
+    },
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+fn usage(conversation_id: ConversationId, tokens: u64, micros: u64) -> UsageRecordParams {
+    UsageRecordParams {
+        conversation_id,
+        turn_id: TurnId::new(),
+        agent_backend: "claude_code_sdk".to_owned(),
+        usage: TokenUsage::new(tokens, tokens),
+        cost: CostMicros::new(micros),
+    }
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_task_cost_report_rolls_up_sub_conversations(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let clock = Arc::new(DefaultClock);
+    let tasks = TaskLifecycleService::new(
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+        clock.clone(),
+    );
+    let costs = TaskCostService::new(Arc::new(PostgresTaskCostLedger::new(pool)), clock);
+
+    let task = tasks
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "corbusier/core", 1731, "Cost attribution"),
+        )
+        .await?;
+    let primary = ConversationId::new();
+    let delegated = ConversationId::new();
+    for conversation_id in [primary, delegated] {
+        insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    }
+
+    costs
+        .record_turn_usage(&ctx, usage(primary, 1_000, 3_000_000))
+        .await?;
+    costs
+        .link_primary_conversation(&ctx, task.id(), primary)
+        .await?;
+    costs
+        .link_sub_conversation(
+            &ctx,
+            LinkSubConversationRequest::new(primary, delegated, ConversationLinkKind::Delegation),
+        )
+        .await?;
+    costs
+        .record_turn_usage(&ctx, usage(delegated, 500, 1_320_000))
+        .await?;
+
+    let report = costs.task_cost_report(&ctx, task.id()).await?;
+
+    assert_eq!(report.conversations.len(), 2);
+    assert_eq!(report.total_turns, 2);
+    assert_eq!(report.total_usage, TokenUsage::new(1_500, 1_500));
+    assert_eq!(report.total_cost.to_string(), "$4.32");
+    let delegated_line = report.conversations.get(1).expect("delegated line");
+    assert_eq!(delegated_line.link.kind, ConversationLinkKind::Delegation);
+    assert_eq!(delegated_line.link.parent_conversation_id, Some(primary));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_task_cost_rejects_relinking_to_another_task(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let clock = Arc::new(DefaultClock);
+    let tasks = TaskLifecycleService::new(
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+        clock.clone(),
+    );
+    let costs = TaskCostService::new(Arc::new(PostgresTaskCostLedger::new(pool)), clock);
+
+    let first = tasks
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "corbusier/core", 1, "First"),
+        )
+        .await?;
+    let second = tasks
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "corbusier/core", 2, "Second"),
+        )
+        .await?;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+
+    costs
+        .link_primary_conversation(&ctx, first.id(), conversation_id)
+        .await?;
+    let result = costs
+        .link_primary_conversation(&ctx, second.id(), conversation_id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(TaskCostError::ConversationAlreadyLinked { task_id, .. }) if task_id == first.id()
+    ));
+    Ok(())
+}
@@ -0,0 +1,110 @@
+//! `PostgreSQL` integration tests for claiming queued tasks.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use chrono::TimeDelta;
+use corbusier::context::RequestContext;
+use corbusier::task::{
+    adapters::postgres::PostgresTaskRepository,
+    domain::{Task, TaskId, TaskPriority, TaskState},
+    services::{CreateTaskFromIssueRequest, ScheduleTaskRequest, TaskLifecycleService},
+};
+use mockable::{Clock, DefaultClock};
+use rstest::rstest;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+type Lifecycle = TaskLifecycleService<PostgresTaskRepository, DefaultClock>;
+
+fn lifecycle(prep: &PreparedRepo, max_size: u32) -> Result<Lifecycle, BoxError> {
+    let repository = Arc::new(PostgresTaskRepository::new(build_pool(
+        prep.temp_db.url(),
+        max_size,
+    )?));
+    Ok(
+        TaskLifecycleService::new(repository.clone(), Arc::new(DefaultClock))
+            .with_task_queue(repository),
+    )
+}
+
+async fn queued(
+    tasks: &Lifecycle,
+    ctx: &RequestContext,
+    issue_number: u64,
+    request: impl FnOnce(TaskId) -> ScheduleTaskRequest,
+) -> Result<Task, BoxError> {
+    let task = tasks
+        .create_from_issue(
+            ctx,
+            CreateTaskFromIssueRequest::new("github", "corbusier/core", issue_number, "Queued"),
+        )
+        .await?;
+    Ok(tasks.schedule_task(ctx, request(task.id())).await?)
+}
+
+fn claimed_id(claimed: Option<Task>) -> Option<TaskId> {
+    claimed.map(|task| task.id())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_task_queue_claims_by_priority_and_due_date(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let tasks = lifecycle(&prep, 1)?;
+    let due = DefaultClock.utc() + TimeDelta::days(1);
+
+    let routine = queued(&tasks, &ctx, 1, |id| ScheduleTaskRequest::new(id, "normal")).await?;
+    let undated = queued(&tasks, &ctx, 2, |id| ScheduleTaskRequest::new(id, "high")).await?;
+    let dated = queued(&tasks, &ctx, 3, |id| {
+        ScheduleTaskRequest::new(id, "high").with_due_at(due)
+    })
+    .await?;
+    let gpu = queued(&tasks, &ctx, 4, |id| {
+        ScheduleTaskRequest::new(id, "urgent").with_required_capabilities(vec!["gpu".to_owned()])
+    })
+    .await?;
+
+    let reloaded = tasks.get_by_id(&ctx, gpu.id()).await?;
+    assert_eq!(reloaded.schedule().priority(), TaskPriority::Urgent);
+    assert_eq!(reloaded.schedule().required_capabilities(), ["gpu"]);
+
+    let mut order = Vec::new();
+    while let Some(task) = tasks.claim_next(&ctx, &["rust".to_owned()]).await? {
+        assert_eq!(task.state(), TaskState::InProgress);
+        order.push(task.id());
+    }
+    assert_eq!(order, [dated.id(), undated.id(), routine.id()]);
+    assert_eq!(
+        claimed_id(tasks.claim_next(&ctx, &["GPU".to_owned()]).await?),
+        Some(gpu.id())
+    );
+    assert_eq!(
+        tasks.get_by_id(&ctx, gpu.id()).await?.state(),
+        TaskState::InProgress
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_task_queue_never_hands_a_task_to_two_workers(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let tasks = lifecycle(&prep, 4)?;
+    let first = queued(&tasks, &ctx, 10, |id| ScheduleTaskRequest::new(id, "high")).await?;
+    let second = queued(&tasks, &ctx, 11, |id| ScheduleTaskRequest::new(id, "low")).await?;
+
+    let (left, right) = tokio::join!(tasks.claim_next(&ctx, &[]), tasks.claim_next(&ctx, &[]));
+    let claimed: HashSet<Option<TaskId>> = [claimed_id(left?), claimed_id(right?)].into();
+
+    assert_eq!(claimed, [Some(first.id()), Some(second.id())].into());
+    assert!(tasks.claim_next(&ctx, &[]).await?.is_none());
+    Ok(())
+}
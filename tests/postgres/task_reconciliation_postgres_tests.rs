@@ -0,0 +1,92 @@
+//! `PostgreSQL` integration tests for stale branch and PR reconciliation.
+
+use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
+use corbusier::task::{
+    adapters::{memory::InMemoryVcsStatus, postgres::PostgresTaskRepository},
+    domain::{PullRequestStatus, Task, TaskState},
+    ports::TaskRepository,
+    services::{
+        AssociateBranchRequest, AssociatePullRequestRequest, CreateTaskFromIssueRequest,
+        TaskLifecycleService, TaskReconciliationService,
+    },
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+use crate::postgres::helpers::{
+    BoxError, PreparedRepo, build_pool, prepared_repo, test_request_context,
+};
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_reconciliation_completes_merged_tasks_only(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repository = Arc::new(PostgresTaskRepository::new(build_pool(
+        prep.temp_db.url(),
+        1,
+    )?));
+    let vcs = Arc::new(InMemoryVcsStatus::new());
+    let clock = Arc::new(DefaultClock);
+    let lifecycle = TaskLifecycleService::new(repository.clone(), clock.clone());
+    let reconciliation = TaskReconciliationService::new(repository.clone(), vcs.clone(), clock);
+
+    let unassociated = lifecycle
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "corbusier/core", 900, "Untouched"),
+        )
+        .await?;
+    let branch_only = lifecycle
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "corbusier/core", 901, "Branch only"),
+        )
+        .await?;
+    lifecycle
+        .associate_branch(
+            &ctx,
+            AssociateBranchRequest::new(branch_only.id(), "github", "corbusier/core", "feat/901"),
+        )
+        .await?;
+    let created = lifecycle
+        .create_from_issue(
+            &ctx,
+            CreateTaskFromIssueRequest::new("github", "corbusier/core", 902, "Merged"),
+        )
+        .await?;
+    let merged = lifecycle
+        .associate_pull_request(
+            &ctx,
+            AssociatePullRequestRequest::new(created.id(), "github", "corbusier/core", 42),
+        )
+        .await?;
+    vcs.set_pull_request_status(
+        merged.pull_request_ref().expect("pull request associated"),
+        PullRequestStatus::Merged,
+    );
+
+    let report = reconciliation.reconcile(&ctx, None).await?;
+
+    assert_eq!(report.tasks_checked, 2);
+    assert_eq!(report.reconciliations.len(), 1);
+    let stored = repository
+        .find_by_id(&ctx, merged.id())
+        .await?
+        .expect("merged task exists");
+    assert_eq!(stored.state(), TaskState::Done);
+    let remaining = repository
+        .find_reconcilable(&ctx, PageRequest::default())
+        .await?;
+    assert_eq!(
+        remaining.iter().map(Task::id).collect::<Vec<_>>(),
+        vec![branch_only.id()]
+    );
+    assert!(!remaining.iter().any(|task| task.id() == unassociated.id()));
+    Ok(())
+}
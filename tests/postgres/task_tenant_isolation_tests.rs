@@ -5,6 +5,7 @@
 //! preparing the connection for Row-Level Security (RLS) policies.
 
 use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
 use corbusier::task::{
     adapters::postgres::{PostgresTaskRepository, TaskPgPool},
     domain::{BranchRef, IssueRef, PullRequestRef, TaskId},
@@ -302,7 +303,7 @@ async fn find_by_branch_ref_uses_tenant_scoped_transaction(
     let branch_ref = BranchRef::from_parts("github", "corbusier/core", "feature/tenant-branch")
         .expect("valid branch ref");
     let found = service
-        .find_by_branch_ref(&ctx, &branch_ref)
+        .find_by_branch_ref(&ctx, &branch_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_eq!(found.len(), 1);
@@ -344,7 +345,7 @@ async fn find_by_pull_request_ref_uses_tenant_scoped_transaction(
 
     let pr_ref = PullRequestRef::from_parts("github", "corbusier/core", 55).expect("valid PR ref");
     let found = service
-        .find_by_pull_request_ref(&ctx, &pr_ref)
+        .find_by_pull_request_ref(&ctx, &pr_ref, PageRequest::default())
         .await
         .expect("lookup should succeed");
     assert_eq!(found.len(), 1);
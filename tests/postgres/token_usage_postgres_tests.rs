@@ -0,0 +1,85 @@
+//! `PostgreSQL` integration tests for per-conversation token usage.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, clock, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::{
+    domain::{
+        ContentPart, ConversationId, ConversationTokenUsage, Message, MessageTokenCount, Role,
+        SequenceNumber, TextPart,
+    },
+    ports::repository::MessageRepository,
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+
+fn text_message(
+    clock: &DefaultClock,
+    conversation_id: ConversationId,
+    sequence: u64,
+) -> Result<Message, BoxError> {
+    Ok(Message::new(
+        conversation_id,
+        Role::User,
+        vec![ContentPart::Text(TextPart::new("How many tokens is this?"))],
+        SequenceNumber::new(sequence),
+        clock,
+    )?)
+}
+
+#[rstest]
+#[tokio::test]
+async fn postgres_token_usage_sums_counted_messages(
+    clock: DefaultClock,
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let conversation_id = ConversationId::new();
+    insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    for (sequence, tokens) in [(1, 7), (2, 11)] {
+        let message = text_message(&clock, conversation_id, sequence)?
+            .with_token_count(MessageTokenCount::new(tokens, "cl100k_base"));
+        prep.repo.store(&ctx, &message).await?;
+    }
+    let uncounted = text_message(&clock, conversation_id, 3)?;
+    prep.repo.store(&ctx, &uncounted).await?;
+
+    let usage = prep.repo.token_usage(&ctx, conversation_id).await?;
+    let stored = prep
+        .repo
+        .find_by_id(&ctx, uncounted.id())
+        .await?
+        .ok_or("stored message")?;
+
+    assert_eq!(
+        usage,
+        ConversationTokenUsage {
+            message_count: 3,
+            counted_messages: 2,
+            tokens: 18,
+        }
+    );
+    assert!(stored.metadata().token_count.is_none());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn postgres_token_usage_of_empty_conversation_is_zero(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+
+    let usage = prep
+        .repo
+        .token_usage(&test_request_context, ConversationId::new())
+        .await?;
+
+    assert_eq!(usage, ConversationTokenUsage::default());
+    Ok(())
+}
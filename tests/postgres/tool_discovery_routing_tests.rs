@@ -3,12 +3,17 @@
 
 use std::sync::Arc;
 
+use corbusier::pagination::PageRequest;
 use corbusier::tool_registry::{
     adapters::{
-        InMemoryMcpServerHost, ObjectStoreLogAdapter, StubGovernance,
+        BufferedAuditCatalog, InMemoryMcpServerHost, ObjectStoreLogAdapter, StubGovernance,
         postgres::{McpServerPgPool, PostgresMcpServerRegistry, PostgresToolCatalog},
     },
-    domain::{LogRetentionPolicy, McpServerName, McpToolDefinition, McpTransport, ToolCallRequest},
+    domain::{
+        AuditBufferPolicy, LogRetentionPolicy, McpServerName, McpToolDefinition, McpTransport,
+        ToolCallAuditRecord, ToolCallRequest,
+    },
+    ports::ToolCatalogRepository,
     services::{
         McpServerLifecycleService, RegisterMcpServerRequest, ServicePorts,
         ToolDiscoveryRoutingService,
@@ -16,7 +21,7 @@ use corbusier::tool_registry::{
 };
 use diesel::PgConnection;
 use diesel::r2d2::ConnectionManager;
-use mockable::DefaultClock;
+use mockable::{Clock, DefaultClock};
 use rstest::{fixture, rstest};
 use serde_json::json;
 use uuid::Uuid;
@@ -152,7 +157,7 @@ async fn catalog_round_trip(
     // Verify via catalog list.
     let catalog_entries = ctx
         .discovery
-        .list_catalog(&request_ctx)
+        .list_catalog(&request_ctx, PageRequest::default())
         .await
         .expect("catalog list should succeed");
     assert_eq!(catalog_entries.len(), 1);
@@ -168,7 +173,7 @@ async fn catalog_round_trip(
 
     let after_mark = ctx
         .discovery
-        .list_catalog(&request_ctx)
+        .list_catalog(&request_ctx, PageRequest::default())
         .await
         .expect("catalog list should succeed");
     let updated = after_mark.first().expect("first entry should exist");
@@ -246,6 +251,52 @@ async fn audit_log_persisted(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn buffered_audit_records_are_written_in_one_batch(
+    #[future] context: Result<PgTestContext, BoxError>,
+) -> Result<(), BoxError> {
+    let ctx = context.await?;
+    let request_ctx = test_request_ctx();
+    let registered = ctx
+        .lifecycle
+        .register(&request_ctx, stdio_request("audit_tools")?)
+        .await?;
+    let catalog = BufferedAuditCatalog::new(
+        Arc::new(PostgresToolCatalog::new(ctx.pool.clone())),
+        AuditBufferPolicy::default(),
+    );
+    for _ in 0..3 {
+        let request = ToolCallRequest::new("batched_tool", json!({}), &DefaultClock);
+        let record = ToolCallAuditRecord::for_rejection(
+            &request,
+            registered.id(),
+            &"denied",
+            DefaultClock.utc(),
+        );
+        catalog.record_audit(&request_ctx, &record).await?;
+    }
+
+    let written = catalog.flush().await?;
+
+    let audit_pool = ctx.pool.clone();
+    let audit_count: i64 = tokio::task::spawn_blocking(move || -> Result<i64, BoxError> {
+        use diesel::prelude::*;
+        let mut conn = audit_pool.get()?;
+        let row = diesel::sql_query(
+            "SELECT COUNT(*) AS count FROM tool_call_audit_log WHERE tool_name = 'batched_tool'",
+        )
+        .get_result::<CountResult>(&mut conn)?;
+        Ok(row.count)
+    })
+    .await
+    .expect("spawn_blocking should not panic")?;
+
+    assert_eq!((written, audit_count), (3, 3));
+    assert_eq!(catalog.metrics().batches_written, 1);
+    Ok(())
+}
+
 /// Helper struct for querying count from `PostgreSQL`.
 #[derive(diesel::QueryableByName, Debug)]
 struct CountResult {
@@ -303,7 +354,7 @@ async fn catalog_survives_service_reconstruction(
 
     // The new service instance should see the catalog entries persisted by the first.
     let entries = discovery2
-        .list_catalog(&request_ctx)
+        .list_catalog(&request_ctx, PageRequest::default())
         .await
         .expect("catalog list should succeed");
     assert_eq!(entries.len(), 1);
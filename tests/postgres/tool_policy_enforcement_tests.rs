@@ -13,6 +13,7 @@ use corbusier::hook_engine::domain::{
 };
 use corbusier::hook_engine::ports::HookPolicyAuditRepository;
 use corbusier::hook_engine::services::{HookEngineService, HookEngineServiceDeps};
+use corbusier::pagination::PageRequest;
 use corbusier::task::domain::TaskId;
 use corbusier::tool_registry::{
     adapters::{
@@ -223,7 +224,7 @@ async fn postgres_denied_tool_call_persists_policy_audit(
     ));
     assert_eq!(
         ctx.policy_audit
-            .find_by_task(&request_ctx, task_id)
+            .find_by_task(&request_ctx, task_id, PageRequest::default())
             .await
             .expect("query by task succeeds")
             .len(),
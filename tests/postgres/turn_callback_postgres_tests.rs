@@ -0,0 +1,75 @@
+//! `PostgreSQL` integration tests for turn completion callbacks.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use chrono::{Duration, Utc};
+use corbusier::agent_backend::{
+    adapters::postgres::PostgresTurnCallbackRepository,
+    domain::{TurnCallback, TurnCallbackId, TurnCallbackTarget},
+    ports::TurnCallbackRepository,
+};
+use corbusier::context::RequestContext;
+use corbusier::pagination::Limit;
+use rstest::rstest;
+use uuid::Uuid;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_turn_callbacks_are_taken_once_per_conversation(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repository = PostgresTurnCallbackRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let conversation_id = Uuid::new_v4();
+    let registered_at = Utc::now();
+    let webhook = TurnCallback::from_persisted(
+        TurnCallbackId::new(),
+        conversation_id,
+        TurnCallbackTarget::url("https://client.example/turns")?,
+        registered_at,
+    );
+    let subject = TurnCallback::from_persisted(
+        TurnCallbackId::new(),
+        conversation_id,
+        TurnCallbackTarget::reply_subject("client.turns.reply")?,
+        registered_at + Duration::seconds(1),
+    );
+    let elsewhere = TurnCallback::from_persisted(
+        TurnCallbackId::new(),
+        Uuid::new_v4(),
+        TurnCallbackTarget::url("https://client.example/other")?,
+        registered_at,
+    );
+    for callback in [&subject, &webhook, &elsewhere] {
+        repository.register(&ctx, callback).await?;
+    }
+
+    let oldest = repository
+        .take_for_conversation(&ctx, conversation_id, Limit::new(1)?)
+        .await?;
+    let rest = repository
+        .take_for_conversation(&ctx, conversation_id, Limit::default())
+        .await?;
+    let retaken = repository
+        .take_for_conversation(&ctx, conversation_id, Limit::default())
+        .await?;
+
+    let targets: Vec<_> = oldest
+        .iter()
+        .chain(&rest)
+        .map(|callback| callback.target().clone())
+        .collect();
+    assert_eq!(
+        targets,
+        vec![webhook.target().clone(), subject.target().clone()]
+    );
+    assert_eq!(oldest.len(), 1);
+    assert!(retaken.is_empty());
+    let remaining = repository
+        .take_for_conversation(&ctx, elsewhere.conversation_id(), Limit::default())
+        .await?;
+    assert_eq!(remaining.len(), 1);
+    Ok(())
+}
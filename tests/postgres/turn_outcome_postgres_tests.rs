@@ -0,0 +1,73 @@
+//! `PostgreSQL` integration tests for turn outcome persistence.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use chrono::{Duration, Utc};
+use corbusier::agent_backend::{
+    adapters::postgres::PostgresTurnOutcomeRepository,
+    domain::{BackendId, TurnOutcome, TurnOutcomeRecord, TurnSessionId},
+    ports::TurnOutcomeRepository,
+};
+use corbusier::context::RequestContext;
+use corbusier::pagination::PageRequest;
+use rstest::rstest;
+use uuid::Uuid;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_turn_outcomes_round_trip_per_conversation(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let repository = PostgresTurnOutcomeRepository::new(build_pool(prep.temp_db.url(), 1)?);
+    let backend_id = BackendId::new();
+    let conversation_id = Uuid::new_v4();
+    let recorded_at = Utc::now();
+    let completed = TurnOutcomeRecord {
+        backend_id,
+        conversation_id,
+        session_id: Some(TurnSessionId::new()),
+        outcome: TurnOutcome::Completed,
+        recorded_at,
+    };
+    let blocked = TurnOutcomeRecord {
+        backend_id,
+        conversation_id,
+        session_id: None,
+        outcome: TurnOutcome::ToolBlocked {
+            call_id: "call-1".to_owned(),
+            tool_name: "deploy".to_owned(),
+            reason: "tool not found: deploy".to_owned(),
+        },
+        recorded_at: recorded_at + Duration::seconds(1),
+    };
+    let elsewhere = TurnOutcomeRecord {
+        conversation_id: Uuid::new_v4(),
+        outcome: TurnOutcome::HandedOff {
+            target_agent: "reviewer".to_owned(),
+        },
+        ..completed.clone()
+    };
+    for record in [&blocked, &completed, &elsewhere] {
+        repository.record(&ctx, record).await?;
+    }
+
+    let stored = repository
+        .list_for_conversation(&ctx, conversation_id, PageRequest::default())
+        .await?;
+
+    let outcomes: Vec<_> = stored
+        .iter()
+        .map(|record| (record.session_id, record.outcome.clone()))
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            (completed.session_id, completed.outcome),
+            (blocked.session_id, blocked.outcome),
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! `PostgreSQL` integration tests for delegated conversation budgets.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{
+    PreparedRepo, build_pool, insert_conversation, prepared_repo, test_request_context,
+};
+use corbusier::context::RequestContext;
+use corbusier::message::domain::{ConversationId, TurnId};
+use corbusier::pagination::PageRequest;
+use corbusier::task::{
+    adapters::postgres::{PostgresTaskCostLedger, PostgresUsageBudgetRepository},
+    domain::{BudgetShare, CostMicros, TokenUsage, UsageAllowance, UsageRecordParams},
+    ports::UsageBudgetRepository,
+    services::{DelegateBudgetRequest, TaskCostService, UsageBudgetService},
+};
+use mockable::DefaultClock;
+use rstest::rstest;
+use std::sync::Arc;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_budgets_split_and_reconcile(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let pool = build_pool(prep.temp_db.url(), 1)?;
+    let ledger = Arc::new(PostgresTaskCostLedger::new(pool.clone()));
+    let clock = Arc::new(DefaultClock);
+    let budget_repo = Arc::new(PostgresUsageBudgetRepository::new(pool));
+    let costs = TaskCostService::new(ledger.clone(), clock.clone());
+    let budgets = UsageBudgetService::new(ledger, budget_repo.clone(), clock);
+    let parent = ConversationId::new();
+    let child = ConversationId::new();
+    for conversation_id in [parent, child] {
+        insert_conversation(prep.cluster, prep.temp_db.name(), conversation_id, &ctx).await?;
+    }
+
+    budgets
+        .allocate(
+            &ctx,
+            parent,
+            UsageAllowance::new(100_000, CostMicros::new(1_000_000)),
+        )
+        .await?;
+    let delegated = budgets
+        .delegate(
+            &ctx,
+            DelegateBudgetRequest::new(parent, child, BudgetShare::percent(25)?),
+        )
+        .await?;
+    assert_eq!(
+        delegated.limit,
+        UsageAllowance::new(25_000, CostMicros::new(250_000))
+    );
+    costs
+        .record_turn_usage(
+            &ctx,
+            UsageRecordParams {
+                conversation_id: child,
+                turn_id: TurnId::new(),
+                agent_backend: "claude_code_sdk".to_owned(),
+                usage: TokenUsage::new(8_000, 2_000),
+                cost: CostMicros::new(60_000),
+            },
+        )
+        .await?;
+    let child_status = budgets.ensure_within_budget(&ctx, child).await?;
+    assert_eq!(
+        child_status.map(|status| status.remaining),
+        Some(UsageAllowance::new(15_000, CostMicros::new(190_000)))
+    );
+
+    let parent_status = budgets.reconcile(&ctx, child).await?;
+    assert_eq!(parent_status.reserved, UsageAllowance::default());
+    assert_eq!(
+        parent_status.remaining,
+        UsageAllowance::new(90_000, CostMicros::new(940_000))
+    );
+    let delegated_children = budget_repo
+        .find_delegated(&ctx, parent, PageRequest::default())
+        .await?;
+    assert_eq!(
+        delegated_children
+            .iter()
+            .map(|budget| budget.conversation_id)
+            .collect::<Vec<_>>(),
+        vec![child]
+    );
+    let closed = budgets.status(&ctx, child).await?;
+    assert_eq!(
+        closed.consumed,
+        UsageAllowance::new(10_000, CostMicros::new(60_000))
+    );
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! `PostgreSQL` integration tests for the webhook delivery log.
+
+use crate::postgres::cluster::BoxError;
+use crate::postgres::helpers::{PreparedRepo, build_pool, prepared_repo, test_request_context};
+use corbusier::context::RequestContext;
+use corbusier::task::{
+    adapters::postgres::PostgresWebhookDeliveryLog,
+    domain::{IssueProvider, WebhookDelivery},
+    ports::WebhookDeliveryLog,
+};
+use mockable::{Clock, DefaultClock};
+use rstest::rstest;
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn postgres_webhook_deliveries_are_claimed_once_until_released(
+    #[future] prepared_repo: Result<PreparedRepo, BoxError>,
+    test_request_context: RequestContext,
+) -> Result<(), BoxError> {
+    let prep = prepared_repo.await?;
+    let ctx = test_request_context;
+    let log = PostgresWebhookDeliveryLog::new(build_pool(prep.temp_db.url(), 1)?);
+    let delivery = WebhookDelivery::new(IssueProvider::GitHub, "72d3162e-cc78-11e3")?;
+    let gitlab = WebhookDelivery::new(IssueProvider::GitLab, "72d3162e-cc78-11e3")?;
+    let now = DefaultClock.utc();
+
+    assert!(log.claim(&ctx, &delivery, now).await?);
+    assert!(!log.claim(&ctx, &delivery, now).await?);
+    assert!(log.claim(&ctx, &gitlab, now).await?);
+
+    log.release(&ctx, &delivery).await?;
+    assert!(log.claim(&ctx, &delivery, now).await?);
+    Ok(())
+}
@@ -220,6 +220,7 @@ fn invalid_boolean_parameter_failure(world: &SlashCommandWorld) -> Result<()> {
         command,
         parameter,
         reason,
+        ..
     } = error
     else {
         return Err(eyre!("expected invalid parameter value error"));
@@ -1,6 +1,7 @@
 //! Then steps for branch and pull request association BDD scenarios.
 
 use super::world::{TaskBranchPrWorld, run_async};
+use corbusier::pagination::PageRequest;
 use corbusier::task::{
     domain::{TaskDomainError, TaskState},
     services::TaskLifecycleError,
@@ -31,8 +32,12 @@ fn task_retrievable_by_branch_ref(world: &TaskBranchPrWorld) -> Result<(), eyre:
     let branch_ref = task
         .branch_ref()
         .ok_or_else(|| eyre::eyre!("task should have a branch reference"))?;
-    let found = run_async(world.service.find_by_branch_ref(&world.ctx, branch_ref))
-        .map_err(|err| eyre::eyre!("branch ref lookup failed: {err}"))?;
+    let found = run_async(world.service.find_by_branch_ref(
+        &world.ctx,
+        branch_ref,
+        PageRequest::default(),
+    ))
+    .map_err(|err| eyre::eyre!("branch ref lookup failed: {err}"))?;
     if found.is_empty() {
         return Err(eyre::eyre!("expected at least one task for branch ref"));
     }
@@ -1,5 +1,6 @@
 //! Then steps for tool discovery and routing BDD scenarios.
 
+use corbusier::pagination::PageRequest;
 use corbusier::tool_registry::domain::ToolRegistryDomainError;
 use corbusier::tool_registry::services::ToolDiscoveryRoutingServiceError;
 use eyre::{WrapErr, eyre};
@@ -48,8 +49,12 @@ fn catalog_contains_count(
     world: &mut ToolDiscoveryWorld,
     count: usize,
 ) -> Result<(), eyre::Report> {
-    let entries = run_async(world.discovery()?.list_catalog(&world.request_ctx))
-        .wrap_err("catalogue listing should succeed")?;
+    let entries = run_async(
+        world
+            .discovery()?
+            .list_catalog(&world.request_ctx, PageRequest::default()),
+    )
+    .wrap_err("catalogue listing should succeed")?;
     if entries.len() != count {
         return Err(eyre!(
             "expected {count} catalogue entries, got {}",
@@ -64,8 +69,12 @@ fn tool_is_available(
     world: &mut ToolDiscoveryWorld,
     tool_name: String,
 ) -> Result<(), eyre::Report> {
-    let entries = run_async(world.discovery()?.list_catalog(&world.request_ctx))
-        .wrap_err("catalogue listing should succeed")?;
+    let entries = run_async(
+        world
+            .discovery()?
+            .list_catalog(&world.request_ctx, PageRequest::default()),
+    )
+    .wrap_err("catalogue listing should succeed")?;
     let entry = entries
         .iter()
         .find(|e| e.tool().name() == tool_name)